@@ -0,0 +1,1485 @@
+/* The C ABI of the `cga3d` geometric algebra, generated together with its Rust code.
+ * All values are passed by value, and the structs have the same layout as in Rust. */
+
+#ifndef CGA3D_H
+#define CGA3D_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct cga3d_Vec3 {
+	double x; /* E1 */
+	double y; /* E2 */
+	double z; /* E3 */
+} cga3d_Vec3;
+
+typedef struct cga3d_Point {
+	double x; /* E1 */
+	double y; /* E2 */
+	double z; /* E3 */
+	double o; /* Eo */
+	double inf; /* Ei */
+} cga3d_Point;
+
+typedef struct cga3d_FlatPoint {
+	double e1i; /* E1i */
+	double e2i; /* E2i */
+	double e3i; /* E3i */
+	double eoi; /* Eoi */
+} cga3d_FlatPoint;
+
+typedef struct cga3d_PointPair {
+	double e12; /* E12 */
+	double e13; /* E13 */
+	double e1o; /* E1o */
+	double e1i; /* E1i */
+	double e23; /* E23 */
+	double e2o; /* E2o */
+	double e2i; /* E2i */
+	double e3o; /* E3o */
+	double e3i; /* E3i */
+	double eoi; /* Eoi */
+} cga3d_PointPair;
+
+typedef struct cga3d_Line {
+	double e12i; /* E12i */
+	double e13i; /* E13i */
+	double e1oi; /* E1oi */
+	double e23i; /* E23i */
+	double e2oi; /* E2oi */
+	double e3oi; /* E3oi */
+} cga3d_Line;
+
+typedef struct cga3d_Circle {
+	double e123; /* E123 */
+	double e12o; /* E12o */
+	double e12i; /* E12i */
+	double e13o; /* E13o */
+	double e13i; /* E13i */
+	double e1oi; /* E1oi */
+	double e23o; /* E23o */
+	double e23i; /* E23i */
+	double e2oi; /* E2oi */
+	double e3oi; /* E3oi */
+} cga3d_Circle;
+
+typedef struct cga3d_Plane {
+	double e123i; /* E123i */
+	double e12oi; /* E12oi */
+	double e13oi; /* E13oi */
+	double e23oi; /* E23oi */
+} cga3d_Plane;
+
+typedef struct cga3d_Sphere {
+	double e123o; /* E123o */
+	double e123i; /* E123i */
+	double e12oi; /* E12oi */
+	double e13oi; /* E13oi */
+	double e23oi; /* E23oi */
+} cga3d_Sphere;
+
+typedef struct cga3d_Rotor {
+	double s; /* S */
+	double e12; /* E12 */
+	double e13; /* E13 */
+	double e23; /* E23 */
+} cga3d_Rotor;
+
+typedef struct cga3d_Translator {
+	double s; /* S */
+	double e1i; /* E1i */
+	double e2i; /* E2i */
+	double e3i; /* E3i */
+} cga3d_Translator;
+
+typedef struct cga3d_Versor {
+	double s; /* S */
+	double e12; /* E12 */
+	double e13; /* E13 */
+	double e1o; /* E1o */
+	double e1i; /* E1i */
+	double e23; /* E23 */
+	double e2o; /* E2o */
+	double e2i; /* E2i */
+	double e3o; /* E3o */
+	double e3i; /* E3i */
+	double eoi; /* Eoi */
+	double e123o; /* E123o */
+	double e123i; /* E123i */
+	double e12oi; /* E12oi */
+	double e13oi; /* E13oi */
+	double e23oi; /* E23oi */
+} cga3d_Versor;
+
+typedef struct cga3d_Multivector {
+	double s; /* S */
+	double e1; /* E1 */
+	double e2; /* E2 */
+	double e3; /* E3 */
+	double eo; /* Eo */
+	double ei; /* Ei */
+	double e12; /* E12 */
+	double e13; /* E13 */
+	double e1o; /* E1o */
+	double e1i; /* E1i */
+	double e23; /* E23 */
+	double e2o; /* E2o */
+	double e2i; /* E2i */
+	double e3o; /* E3o */
+	double e3i; /* E3i */
+	double eoi; /* Eoi */
+	double e123; /* E123 */
+	double e12o; /* E12o */
+	double e12i; /* E12i */
+	double e13o; /* E13o */
+	double e13i; /* E13i */
+	double e1oi; /* E1oi */
+	double e23o; /* E23o */
+	double e23i; /* E23i */
+	double e2oi; /* E2oi */
+	double e3oi; /* E3oi */
+	double e123o; /* E123o */
+	double e123i; /* E123i */
+	double e12oi; /* E12oi */
+	double e13oi; /* E13oi */
+	double e23oi; /* E23oi */
+	double e123oi; /* E123oi */
+} cga3d_Multivector;
+
+cga3d_Plane cga3d_vec3_rcompl(cga3d_Vec3 value);
+cga3d_Plane cga3d_vec3_lcompl(cga3d_Vec3 value);
+cga3d_Vec3 cga3d_vec3_rev(cga3d_Vec3 value);
+cga3d_Vec3 cga3d_vec3_arev(cga3d_Vec3 value);
+cga3d_Vec3 cga3d_vec3_involute(cga3d_Vec3 value);
+cga3d_Vec3 cga3d_vec3_conjugate(cga3d_Vec3 value);
+cga3d_Plane cga3d_vec3_dual(cga3d_Vec3 value);
+cga3d_Rotor cga3d_vec3_geometric_vec3(cga3d_Vec3 lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_vec3_anti_geometric_vec3(cga3d_Vec3 lhs, cga3d_Vec3 rhs);
+double cga3d_vec3_dot_vec3(cga3d_Vec3 lhs, cga3d_Vec3 rhs);
+cga3d_PointPair cga3d_vec3_wedge_vec3(cga3d_Vec3 lhs, cga3d_Vec3 rhs);
+double cga3d_vec3_left_contraction_vec3(cga3d_Vec3 lhs, cga3d_Vec3 rhs);
+double cga3d_vec3_right_contraction_vec3(cga3d_Vec3 lhs, cga3d_Vec3 rhs);
+double cga3d_vec3_scalar_product_vec3(cga3d_Vec3 lhs, cga3d_Vec3 rhs);
+cga3d_PointPair cga3d_vec3_commutator_vec3(cga3d_Vec3 lhs, cga3d_Vec3 rhs);
+double cga3d_vec3_anti_dot_vec3(cga3d_Vec3 lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_vec3_geometric_point(cga3d_Vec3 lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_vec3_anti_geometric_point(cga3d_Vec3 lhs, cga3d_Point rhs);
+double cga3d_vec3_dot_point(cga3d_Vec3 lhs, cga3d_Point rhs);
+cga3d_PointPair cga3d_vec3_wedge_point(cga3d_Vec3 lhs, cga3d_Point rhs);
+double cga3d_vec3_left_contraction_point(cga3d_Vec3 lhs, cga3d_Point rhs);
+double cga3d_vec3_right_contraction_point(cga3d_Vec3 lhs, cga3d_Point rhs);
+double cga3d_vec3_scalar_product_point(cga3d_Vec3 lhs, cga3d_Point rhs);
+cga3d_PointPair cga3d_vec3_commutator_point(cga3d_Vec3 lhs, cga3d_Point rhs);
+double cga3d_vec3_anti_dot_point(cga3d_Vec3 lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_vec3_geometric_flatpoint(cga3d_Vec3 lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_vec3_anti_geometric_flatpoint(cga3d_Vec3 lhs, cga3d_FlatPoint rhs);
+double cga3d_vec3_dot_flatpoint(cga3d_Vec3 lhs, cga3d_FlatPoint rhs);
+cga3d_Line cga3d_vec3_wedge_flatpoint(cga3d_Vec3 lhs, cga3d_FlatPoint rhs);
+double cga3d_vec3_left_contraction_flatpoint(cga3d_Vec3 lhs, cga3d_FlatPoint rhs);
+double cga3d_vec3_commutator_flatpoint(cga3d_Vec3 lhs, cga3d_FlatPoint rhs);
+double cga3d_vec3_anti_dot_flatpoint(cga3d_Vec3 lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_vec3_geometric_pointpair(cga3d_Vec3 lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_vec3_anti_geometric_pointpair(cga3d_Vec3 lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_vec3_dot_pointpair(cga3d_Vec3 lhs, cga3d_PointPair rhs);
+cga3d_Circle cga3d_vec3_wedge_pointpair(cga3d_Vec3 lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_vec3_left_contraction_pointpair(cga3d_Vec3 lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_vec3_commutator_pointpair(cga3d_Vec3 lhs, cga3d_PointPair rhs);
+cga3d_Sphere cga3d_vec3_anti_dot_pointpair(cga3d_Vec3 lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_vec3_geometric_line(cga3d_Vec3 lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_vec3_anti_geometric_line(cga3d_Vec3 lhs, cga3d_Line rhs);
+cga3d_FlatPoint cga3d_vec3_dot_line(cga3d_Vec3 lhs, cga3d_Line rhs);
+cga3d_Plane cga3d_vec3_wedge_line(cga3d_Vec3 lhs, cga3d_Line rhs);
+cga3d_FlatPoint cga3d_vec3_left_contraction_line(cga3d_Vec3 lhs, cga3d_Line rhs);
+cga3d_Plane cga3d_vec3_commutator_line(cga3d_Vec3 lhs, cga3d_Line rhs);
+cga3d_Circle cga3d_vec3_anti_dot_line(cga3d_Vec3 lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_vec3_geometric_circle(cga3d_Vec3 lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_vec3_anti_geometric_circle(cga3d_Vec3 lhs, cga3d_Circle rhs);
+cga3d_PointPair cga3d_vec3_dot_circle(cga3d_Vec3 lhs, cga3d_Circle rhs);
+cga3d_Sphere cga3d_vec3_wedge_circle(cga3d_Vec3 lhs, cga3d_Circle rhs);
+cga3d_PointPair cga3d_vec3_left_contraction_circle(cga3d_Vec3 lhs, cga3d_Circle rhs);
+cga3d_Sphere cga3d_vec3_commutator_circle(cga3d_Vec3 lhs, cga3d_Circle rhs);
+cga3d_Circle cga3d_vec3_anti_dot_circle(cga3d_Vec3 lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_vec3_geometric_plane(cga3d_Vec3 lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_vec3_anti_geometric_plane(cga3d_Vec3 lhs, cga3d_Plane rhs);
+cga3d_Line cga3d_vec3_dot_plane(cga3d_Vec3 lhs, cga3d_Plane rhs);
+double cga3d_vec3_wedge_plane(cga3d_Vec3 lhs, cga3d_Plane rhs);
+double cga3d_vec3_anti_wedge_plane(cga3d_Vec3 lhs, cga3d_Plane rhs);
+cga3d_Line cga3d_vec3_left_contraction_plane(cga3d_Vec3 lhs, cga3d_Plane rhs);
+cga3d_Line cga3d_vec3_commutator_plane(cga3d_Vec3 lhs, cga3d_Plane rhs);
+cga3d_PointPair cga3d_vec3_anti_dot_plane(cga3d_Vec3 lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_vec3_geometric_sphere(cga3d_Vec3 lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_vec3_anti_geometric_sphere(cga3d_Vec3 lhs, cga3d_Sphere rhs);
+cga3d_Circle cga3d_vec3_dot_sphere(cga3d_Vec3 lhs, cga3d_Sphere rhs);
+double cga3d_vec3_wedge_sphere(cga3d_Vec3 lhs, cga3d_Sphere rhs);
+double cga3d_vec3_anti_wedge_sphere(cga3d_Vec3 lhs, cga3d_Sphere rhs);
+cga3d_Circle cga3d_vec3_left_contraction_sphere(cga3d_Vec3 lhs, cga3d_Sphere rhs);
+cga3d_Circle cga3d_vec3_commutator_sphere(cga3d_Vec3 lhs, cga3d_Sphere rhs);
+cga3d_PointPair cga3d_vec3_anti_dot_sphere(cga3d_Vec3 lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_vec3_geometric_rotor(cga3d_Vec3 lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_vec3_anti_geometric_rotor(cga3d_Vec3 lhs, cga3d_Rotor rhs);
+cga3d_Vec3 cga3d_vec3_dot_rotor(cga3d_Vec3 lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_vec3_wedge_rotor(cga3d_Vec3 lhs, cga3d_Rotor rhs);
+cga3d_Vec3 cga3d_vec3_left_contraction_rotor(cga3d_Vec3 lhs, cga3d_Rotor rhs);
+cga3d_Vec3 cga3d_vec3_right_contraction_rotor(cga3d_Vec3 lhs, cga3d_Rotor rhs);
+cga3d_Vec3 cga3d_vec3_commutator_rotor(cga3d_Vec3 lhs, cga3d_Rotor rhs);
+cga3d_Plane cga3d_vec3_anti_dot_rotor(cga3d_Vec3 lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_vec3_geometric_translator(cga3d_Vec3 lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_vec3_anti_geometric_translator(cga3d_Vec3 lhs, cga3d_Translator rhs);
+cga3d_Point cga3d_vec3_dot_translator(cga3d_Vec3 lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_vec3_wedge_translator(cga3d_Vec3 lhs, cga3d_Translator rhs);
+double cga3d_vec3_left_contraction_translator(cga3d_Vec3 lhs, cga3d_Translator rhs);
+cga3d_Vec3 cga3d_vec3_right_contraction_translator(cga3d_Vec3 lhs, cga3d_Translator rhs);
+double cga3d_vec3_commutator_translator(cga3d_Vec3 lhs, cga3d_Translator rhs);
+cga3d_Plane cga3d_vec3_anti_dot_translator(cga3d_Vec3 lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_vec3_geometric_versor(cga3d_Vec3 lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_vec3_anti_geometric_versor(cga3d_Vec3 lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_vec3_dot_versor(cga3d_Vec3 lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_vec3_wedge_versor(cga3d_Vec3 lhs, cga3d_Versor rhs);
+double cga3d_vec3_anti_wedge_versor(cga3d_Vec3 lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_vec3_left_contraction_versor(cga3d_Vec3 lhs, cga3d_Versor rhs);
+cga3d_Vec3 cga3d_vec3_right_contraction_versor(cga3d_Vec3 lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_vec3_commutator_versor(cga3d_Vec3 lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_vec3_anti_dot_versor(cga3d_Vec3 lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_vec3_geometric_multivector(cga3d_Vec3 lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_vec3_anti_geometric_multivector(cga3d_Vec3 lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_vec3_dot_multivector(cga3d_Vec3 lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_vec3_wedge_multivector(cga3d_Vec3 lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_vec3_anti_wedge_multivector(cga3d_Vec3 lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_vec3_left_contraction_multivector(cga3d_Vec3 lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_vec3_right_contraction_multivector(cga3d_Vec3 lhs, cga3d_Multivector rhs);
+double cga3d_vec3_scalar_product_multivector(cga3d_Vec3 lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_vec3_commutator_multivector(cga3d_Vec3 lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_vec3_anti_dot_multivector(cga3d_Vec3 lhs, cga3d_Multivector rhs);
+cga3d_Sphere cga3d_point_rcompl(cga3d_Point value);
+cga3d_Sphere cga3d_point_lcompl(cga3d_Point value);
+cga3d_Point cga3d_point_rev(cga3d_Point value);
+cga3d_Point cga3d_point_arev(cga3d_Point value);
+cga3d_Point cga3d_point_involute(cga3d_Point value);
+cga3d_Point cga3d_point_conjugate(cga3d_Point value);
+cga3d_Sphere cga3d_point_dual(cga3d_Point value);
+cga3d_Versor cga3d_point_geometric_vec3(cga3d_Point lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_point_anti_geometric_vec3(cga3d_Point lhs, cga3d_Vec3 rhs);
+double cga3d_point_dot_vec3(cga3d_Point lhs, cga3d_Vec3 rhs);
+cga3d_PointPair cga3d_point_wedge_vec3(cga3d_Point lhs, cga3d_Vec3 rhs);
+double cga3d_point_left_contraction_vec3(cga3d_Point lhs, cga3d_Vec3 rhs);
+double cga3d_point_right_contraction_vec3(cga3d_Point lhs, cga3d_Vec3 rhs);
+double cga3d_point_scalar_product_vec3(cga3d_Point lhs, cga3d_Vec3 rhs);
+cga3d_PointPair cga3d_point_commutator_vec3(cga3d_Point lhs, cga3d_Vec3 rhs);
+double cga3d_point_anti_dot_vec3(cga3d_Point lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_point_geometric_point(cga3d_Point lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_point_anti_geometric_point(cga3d_Point lhs, cga3d_Point rhs);
+double cga3d_point_dot_point(cga3d_Point lhs, cga3d_Point rhs);
+cga3d_PointPair cga3d_point_wedge_point(cga3d_Point lhs, cga3d_Point rhs);
+double cga3d_point_left_contraction_point(cga3d_Point lhs, cga3d_Point rhs);
+double cga3d_point_right_contraction_point(cga3d_Point lhs, cga3d_Point rhs);
+double cga3d_point_scalar_product_point(cga3d_Point lhs, cga3d_Point rhs);
+cga3d_PointPair cga3d_point_commutator_point(cga3d_Point lhs, cga3d_Point rhs);
+double cga3d_point_anti_dot_point(cga3d_Point lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_point_geometric_flatpoint(cga3d_Point lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_point_anti_geometric_flatpoint(cga3d_Point lhs, cga3d_FlatPoint rhs);
+cga3d_Point cga3d_point_dot_flatpoint(cga3d_Point lhs, cga3d_FlatPoint rhs);
+cga3d_Line cga3d_point_wedge_flatpoint(cga3d_Point lhs, cga3d_FlatPoint rhs);
+cga3d_Point cga3d_point_left_contraction_flatpoint(cga3d_Point lhs, cga3d_FlatPoint rhs);
+cga3d_Point cga3d_point_commutator_flatpoint(cga3d_Point lhs, cga3d_FlatPoint rhs);
+cga3d_Sphere cga3d_point_anti_dot_flatpoint(cga3d_Point lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_point_geometric_pointpair(cga3d_Point lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_point_anti_geometric_pointpair(cga3d_Point lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_point_dot_pointpair(cga3d_Point lhs, cga3d_PointPair rhs);
+cga3d_Circle cga3d_point_wedge_pointpair(cga3d_Point lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_point_left_contraction_pointpair(cga3d_Point lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_point_commutator_pointpair(cga3d_Point lhs, cga3d_PointPair rhs);
+cga3d_Sphere cga3d_point_anti_dot_pointpair(cga3d_Point lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_point_geometric_line(cga3d_Point lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_point_anti_geometric_line(cga3d_Point lhs, cga3d_Line rhs);
+cga3d_PointPair cga3d_point_dot_line(cga3d_Point lhs, cga3d_Line rhs);
+cga3d_Plane cga3d_point_wedge_line(cga3d_Point lhs, cga3d_Line rhs);
+cga3d_PointPair cga3d_point_left_contraction_line(cga3d_Point lhs, cga3d_Line rhs);
+cga3d_Plane cga3d_point_commutator_line(cga3d_Point lhs, cga3d_Line rhs);
+cga3d_Circle cga3d_point_anti_dot_line(cga3d_Point lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_point_geometric_circle(cga3d_Point lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_point_anti_geometric_circle(cga3d_Point lhs, cga3d_Circle rhs);
+cga3d_PointPair cga3d_point_dot_circle(cga3d_Point lhs, cga3d_Circle rhs);
+cga3d_Sphere cga3d_point_wedge_circle(cga3d_Point lhs, cga3d_Circle rhs);
+cga3d_PointPair cga3d_point_left_contraction_circle(cga3d_Point lhs, cga3d_Circle rhs);
+cga3d_Sphere cga3d_point_commutator_circle(cga3d_Point lhs, cga3d_Circle rhs);
+cga3d_Circle cga3d_point_anti_dot_circle(cga3d_Point lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_point_geometric_plane(cga3d_Point lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_point_anti_geometric_plane(cga3d_Point lhs, cga3d_Plane rhs);
+cga3d_Circle cga3d_point_dot_plane(cga3d_Point lhs, cga3d_Plane rhs);
+double cga3d_point_wedge_plane(cga3d_Point lhs, cga3d_Plane rhs);
+double cga3d_point_anti_wedge_plane(cga3d_Point lhs, cga3d_Plane rhs);
+cga3d_Circle cga3d_point_left_contraction_plane(cga3d_Point lhs, cga3d_Plane rhs);
+cga3d_Circle cga3d_point_commutator_plane(cga3d_Point lhs, cga3d_Plane rhs);
+cga3d_PointPair cga3d_point_anti_dot_plane(cga3d_Point lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_point_geometric_sphere(cga3d_Point lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_point_anti_geometric_sphere(cga3d_Point lhs, cga3d_Sphere rhs);
+cga3d_Circle cga3d_point_dot_sphere(cga3d_Point lhs, cga3d_Sphere rhs);
+double cga3d_point_wedge_sphere(cga3d_Point lhs, cga3d_Sphere rhs);
+double cga3d_point_anti_wedge_sphere(cga3d_Point lhs, cga3d_Sphere rhs);
+cga3d_Circle cga3d_point_left_contraction_sphere(cga3d_Point lhs, cga3d_Sphere rhs);
+cga3d_Circle cga3d_point_commutator_sphere(cga3d_Point lhs, cga3d_Sphere rhs);
+cga3d_PointPair cga3d_point_anti_dot_sphere(cga3d_Point lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_point_geometric_rotor(cga3d_Point lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_point_anti_geometric_rotor(cga3d_Point lhs, cga3d_Rotor rhs);
+cga3d_Point cga3d_point_dot_rotor(cga3d_Point lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_point_wedge_rotor(cga3d_Point lhs, cga3d_Rotor rhs);
+cga3d_Vec3 cga3d_point_left_contraction_rotor(cga3d_Point lhs, cga3d_Rotor rhs);
+cga3d_Point cga3d_point_right_contraction_rotor(cga3d_Point lhs, cga3d_Rotor rhs);
+cga3d_Vec3 cga3d_point_commutator_rotor(cga3d_Point lhs, cga3d_Rotor rhs);
+cga3d_Sphere cga3d_point_anti_dot_rotor(cga3d_Point lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_point_geometric_translator(cga3d_Point lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_point_anti_geometric_translator(cga3d_Point lhs, cga3d_Translator rhs);
+cga3d_Point cga3d_point_dot_translator(cga3d_Point lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_point_wedge_translator(cga3d_Point lhs, cga3d_Translator rhs);
+cga3d_Point cga3d_point_left_contraction_translator(cga3d_Point lhs, cga3d_Translator rhs);
+cga3d_Point cga3d_point_right_contraction_translator(cga3d_Point lhs, cga3d_Translator rhs);
+cga3d_Point cga3d_point_commutator_translator(cga3d_Point lhs, cga3d_Translator rhs);
+cga3d_Sphere cga3d_point_anti_dot_translator(cga3d_Point lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_point_geometric_versor(cga3d_Point lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_point_anti_geometric_versor(cga3d_Point lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_point_dot_versor(cga3d_Point lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_point_wedge_versor(cga3d_Point lhs, cga3d_Versor rhs);
+double cga3d_point_anti_wedge_versor(cga3d_Point lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_point_left_contraction_versor(cga3d_Point lhs, cga3d_Versor rhs);
+cga3d_Point cga3d_point_right_contraction_versor(cga3d_Point lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_point_commutator_versor(cga3d_Point lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_point_anti_dot_versor(cga3d_Point lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_point_geometric_multivector(cga3d_Point lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_point_anti_geometric_multivector(cga3d_Point lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_point_dot_multivector(cga3d_Point lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_point_wedge_multivector(cga3d_Point lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_point_anti_wedge_multivector(cga3d_Point lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_point_left_contraction_multivector(cga3d_Point lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_point_right_contraction_multivector(cga3d_Point lhs, cga3d_Multivector rhs);
+double cga3d_point_scalar_product_multivector(cga3d_Point lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_point_commutator_multivector(cga3d_Point lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_point_anti_dot_multivector(cga3d_Point lhs, cga3d_Multivector rhs);
+cga3d_Circle cga3d_flatpoint_rcompl(cga3d_FlatPoint value);
+cga3d_Circle cga3d_flatpoint_lcompl(cga3d_FlatPoint value);
+cga3d_FlatPoint cga3d_flatpoint_rev(cga3d_FlatPoint value);
+cga3d_FlatPoint cga3d_flatpoint_arev(cga3d_FlatPoint value);
+cga3d_FlatPoint cga3d_flatpoint_involute(cga3d_FlatPoint value);
+cga3d_FlatPoint cga3d_flatpoint_conjugate(cga3d_FlatPoint value);
+cga3d_Circle cga3d_flatpoint_dual(cga3d_FlatPoint value);
+cga3d_Multivector cga3d_flatpoint_geometric_vec3(cga3d_FlatPoint lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_flatpoint_anti_geometric_vec3(cga3d_FlatPoint lhs, cga3d_Vec3 rhs);
+double cga3d_flatpoint_dot_vec3(cga3d_FlatPoint lhs, cga3d_Vec3 rhs);
+cga3d_Line cga3d_flatpoint_wedge_vec3(cga3d_FlatPoint lhs, cga3d_Vec3 rhs);
+double cga3d_flatpoint_right_contraction_vec3(cga3d_FlatPoint lhs, cga3d_Vec3 rhs);
+double cga3d_flatpoint_commutator_vec3(cga3d_FlatPoint lhs, cga3d_Vec3 rhs);
+double cga3d_flatpoint_anti_dot_vec3(cga3d_FlatPoint lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_flatpoint_geometric_point(cga3d_FlatPoint lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_flatpoint_anti_geometric_point(cga3d_FlatPoint lhs, cga3d_Point rhs);
+cga3d_Point cga3d_flatpoint_dot_point(cga3d_FlatPoint lhs, cga3d_Point rhs);
+cga3d_Line cga3d_flatpoint_wedge_point(cga3d_FlatPoint lhs, cga3d_Point rhs);
+cga3d_Point cga3d_flatpoint_right_contraction_point(cga3d_FlatPoint lhs, cga3d_Point rhs);
+cga3d_Point cga3d_flatpoint_commutator_point(cga3d_FlatPoint lhs, cga3d_Point rhs);
+cga3d_Sphere cga3d_flatpoint_anti_dot_point(cga3d_FlatPoint lhs, cga3d_Point rhs);
+cga3d_Translator cga3d_flatpoint_geometric_flatpoint(cga3d_FlatPoint lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_flatpoint_anti_geometric_flatpoint(cga3d_FlatPoint lhs, cga3d_FlatPoint rhs);
+double cga3d_flatpoint_dot_flatpoint(cga3d_FlatPoint lhs, cga3d_FlatPoint rhs);
+double cga3d_flatpoint_left_contraction_flatpoint(cga3d_FlatPoint lhs, cga3d_FlatPoint rhs);
+double cga3d_flatpoint_right_contraction_flatpoint(cga3d_FlatPoint lhs, cga3d_FlatPoint rhs);
+double cga3d_flatpoint_scalar_product_flatpoint(cga3d_FlatPoint lhs, cga3d_FlatPoint rhs);
+cga3d_FlatPoint cga3d_flatpoint_commutator_flatpoint(cga3d_FlatPoint lhs, cga3d_FlatPoint rhs);
+double cga3d_flatpoint_anti_dot_flatpoint(cga3d_FlatPoint lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_flatpoint_geometric_pointpair(cga3d_FlatPoint lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_flatpoint_anti_geometric_pointpair(cga3d_FlatPoint lhs, cga3d_PointPair rhs);
+double cga3d_flatpoint_dot_pointpair(cga3d_FlatPoint lhs, cga3d_PointPair rhs);
+cga3d_Plane cga3d_flatpoint_wedge_pointpair(cga3d_FlatPoint lhs, cga3d_PointPair rhs);
+double cga3d_flatpoint_left_contraction_pointpair(cga3d_FlatPoint lhs, cga3d_PointPair rhs);
+double cga3d_flatpoint_right_contraction_pointpair(cga3d_FlatPoint lhs, cga3d_PointPair rhs);
+double cga3d_flatpoint_scalar_product_pointpair(cga3d_FlatPoint lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_flatpoint_commutator_pointpair(cga3d_FlatPoint lhs, cga3d_PointPair rhs);
+double cga3d_flatpoint_anti_dot_pointpair(cga3d_FlatPoint lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_flatpoint_geometric_line(cga3d_FlatPoint lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_flatpoint_anti_geometric_line(cga3d_FlatPoint lhs, cga3d_Line rhs);
+cga3d_Point cga3d_flatpoint_dot_line(cga3d_FlatPoint lhs, cga3d_Line rhs);
+cga3d_Point cga3d_flatpoint_left_contraction_line(cga3d_FlatPoint lhs, cga3d_Line rhs);
+cga3d_Line cga3d_flatpoint_commutator_line(cga3d_FlatPoint lhs, cga3d_Line rhs);
+cga3d_Plane cga3d_flatpoint_anti_dot_line(cga3d_FlatPoint lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_flatpoint_geometric_circle(cga3d_FlatPoint lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_flatpoint_anti_geometric_circle(cga3d_FlatPoint lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_flatpoint_dot_circle(cga3d_FlatPoint lhs, cga3d_Circle rhs);
+double cga3d_flatpoint_wedge_circle(cga3d_FlatPoint lhs, cga3d_Circle rhs);
+double cga3d_flatpoint_anti_wedge_circle(cga3d_FlatPoint lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_flatpoint_left_contraction_circle(cga3d_FlatPoint lhs, cga3d_Circle rhs);
+cga3d_Circle cga3d_flatpoint_commutator_circle(cga3d_FlatPoint lhs, cga3d_Circle rhs);
+cga3d_Plane cga3d_flatpoint_anti_dot_circle(cga3d_FlatPoint lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_flatpoint_geometric_plane(cga3d_FlatPoint lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_flatpoint_anti_geometric_plane(cga3d_FlatPoint lhs, cga3d_Plane rhs);
+cga3d_PointPair cga3d_flatpoint_dot_plane(cga3d_FlatPoint lhs, cga3d_Plane rhs);
+double cga3d_flatpoint_anti_wedge_plane(cga3d_FlatPoint lhs, cga3d_Plane rhs);
+cga3d_PointPair cga3d_flatpoint_left_contraction_plane(cga3d_FlatPoint lhs, cga3d_Plane rhs);
+double cga3d_flatpoint_commutator_plane(cga3d_FlatPoint lhs, cga3d_Plane rhs);
+cga3d_Line cga3d_flatpoint_anti_dot_plane(cga3d_FlatPoint lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_flatpoint_geometric_sphere(cga3d_FlatPoint lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_flatpoint_anti_geometric_sphere(cga3d_FlatPoint lhs, cga3d_Sphere rhs);
+cga3d_PointPair cga3d_flatpoint_dot_sphere(cga3d_FlatPoint lhs, cga3d_Sphere rhs);
+cga3d_Point cga3d_flatpoint_anti_wedge_sphere(cga3d_FlatPoint lhs, cga3d_Sphere rhs);
+cga3d_PointPair cga3d_flatpoint_left_contraction_sphere(cga3d_FlatPoint lhs, cga3d_Sphere rhs);
+cga3d_Sphere cga3d_flatpoint_commutator_sphere(cga3d_FlatPoint lhs, cga3d_Sphere rhs);
+cga3d_Line cga3d_flatpoint_anti_dot_sphere(cga3d_FlatPoint lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_flatpoint_geometric_rotor(cga3d_FlatPoint lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_flatpoint_anti_geometric_rotor(cga3d_FlatPoint lhs, cga3d_Rotor rhs);
+cga3d_FlatPoint cga3d_flatpoint_dot_rotor(cga3d_FlatPoint lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_flatpoint_wedge_rotor(cga3d_FlatPoint lhs, cga3d_Rotor rhs);
+cga3d_FlatPoint cga3d_flatpoint_right_contraction_rotor(cga3d_FlatPoint lhs, cga3d_Rotor rhs);
+cga3d_FlatPoint cga3d_flatpoint_commutator_rotor(cga3d_FlatPoint lhs, cga3d_Rotor rhs);
+cga3d_Circle cga3d_flatpoint_anti_dot_rotor(cga3d_FlatPoint lhs, cga3d_Rotor rhs);
+cga3d_FlatPoint cga3d_flatpoint_geometric_translator(cga3d_FlatPoint lhs, cga3d_Translator rhs);
+cga3d_Circle cga3d_flatpoint_anti_geometric_translator(cga3d_FlatPoint lhs, cga3d_Translator rhs);
+cga3d_FlatPoint cga3d_flatpoint_dot_translator(cga3d_FlatPoint lhs, cga3d_Translator rhs);
+cga3d_FlatPoint cga3d_flatpoint_wedge_translator(cga3d_FlatPoint lhs, cga3d_Translator rhs);
+cga3d_FlatPoint cga3d_flatpoint_right_contraction_translator(cga3d_FlatPoint lhs, cga3d_Translator rhs);
+cga3d_FlatPoint cga3d_flatpoint_commutator_translator(cga3d_FlatPoint lhs, cga3d_Translator rhs);
+cga3d_Circle cga3d_flatpoint_anti_dot_translator(cga3d_FlatPoint lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_flatpoint_geometric_versor(cga3d_FlatPoint lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_flatpoint_anti_geometric_versor(cga3d_FlatPoint lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_flatpoint_dot_versor(cga3d_FlatPoint lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_flatpoint_wedge_versor(cga3d_FlatPoint lhs, cga3d_Versor rhs);
+cga3d_Point cga3d_flatpoint_anti_wedge_versor(cga3d_FlatPoint lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_flatpoint_left_contraction_versor(cga3d_FlatPoint lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_flatpoint_right_contraction_versor(cga3d_FlatPoint lhs, cga3d_Versor rhs);
+double cga3d_flatpoint_scalar_product_versor(cga3d_FlatPoint lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_flatpoint_commutator_versor(cga3d_FlatPoint lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_flatpoint_anti_dot_versor(cga3d_FlatPoint lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_flatpoint_geometric_multivector(cga3d_FlatPoint lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_flatpoint_anti_geometric_multivector(cga3d_FlatPoint lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_flatpoint_dot_multivector(cga3d_FlatPoint lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_flatpoint_wedge_multivector(cga3d_FlatPoint lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_flatpoint_anti_wedge_multivector(cga3d_FlatPoint lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_flatpoint_left_contraction_multivector(cga3d_FlatPoint lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_flatpoint_right_contraction_multivector(cga3d_FlatPoint lhs, cga3d_Multivector rhs);
+double cga3d_flatpoint_scalar_product_multivector(cga3d_FlatPoint lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_flatpoint_commutator_multivector(cga3d_FlatPoint lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_flatpoint_anti_dot_multivector(cga3d_FlatPoint lhs, cga3d_Multivector rhs);
+cga3d_Circle cga3d_pointpair_rcompl(cga3d_PointPair value);
+cga3d_Circle cga3d_pointpair_lcompl(cga3d_PointPair value);
+cga3d_PointPair cga3d_pointpair_rev(cga3d_PointPair value);
+cga3d_PointPair cga3d_pointpair_arev(cga3d_PointPair value);
+cga3d_PointPair cga3d_pointpair_involute(cga3d_PointPair value);
+cga3d_PointPair cga3d_pointpair_conjugate(cga3d_PointPair value);
+cga3d_Circle cga3d_pointpair_dual(cga3d_PointPair value);
+cga3d_Multivector cga3d_pointpair_geometric_vec3(cga3d_PointPair lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_pointpair_anti_geometric_vec3(cga3d_PointPair lhs, cga3d_Vec3 rhs);
+cga3d_Point cga3d_pointpair_dot_vec3(cga3d_PointPair lhs, cga3d_Vec3 rhs);
+cga3d_Circle cga3d_pointpair_wedge_vec3(cga3d_PointPair lhs, cga3d_Vec3 rhs);
+cga3d_Point cga3d_pointpair_right_contraction_vec3(cga3d_PointPair lhs, cga3d_Vec3 rhs);
+cga3d_Point cga3d_pointpair_commutator_vec3(cga3d_PointPair lhs, cga3d_Vec3 rhs);
+cga3d_Sphere cga3d_pointpair_anti_dot_vec3(cga3d_PointPair lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_pointpair_geometric_point(cga3d_PointPair lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_pointpair_anti_geometric_point(cga3d_PointPair lhs, cga3d_Point rhs);
+cga3d_Point cga3d_pointpair_dot_point(cga3d_PointPair lhs, cga3d_Point rhs);
+cga3d_Circle cga3d_pointpair_wedge_point(cga3d_PointPair lhs, cga3d_Point rhs);
+cga3d_Point cga3d_pointpair_right_contraction_point(cga3d_PointPair lhs, cga3d_Point rhs);
+cga3d_Point cga3d_pointpair_commutator_point(cga3d_PointPair lhs, cga3d_Point rhs);
+cga3d_Sphere cga3d_pointpair_anti_dot_point(cga3d_PointPair lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_pointpair_geometric_flatpoint(cga3d_PointPair lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_pointpair_anti_geometric_flatpoint(cga3d_PointPair lhs, cga3d_FlatPoint rhs);
+double cga3d_pointpair_dot_flatpoint(cga3d_PointPair lhs, cga3d_FlatPoint rhs);
+cga3d_Plane cga3d_pointpair_wedge_flatpoint(cga3d_PointPair lhs, cga3d_FlatPoint rhs);
+double cga3d_pointpair_left_contraction_flatpoint(cga3d_PointPair lhs, cga3d_FlatPoint rhs);
+double cga3d_pointpair_right_contraction_flatpoint(cga3d_PointPair lhs, cga3d_FlatPoint rhs);
+double cga3d_pointpair_scalar_product_flatpoint(cga3d_PointPair lhs, cga3d_FlatPoint rhs);
+cga3d_PointPair cga3d_pointpair_commutator_flatpoint(cga3d_PointPair lhs, cga3d_FlatPoint rhs);
+double cga3d_pointpair_anti_dot_flatpoint(cga3d_PointPair lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_pointpair_geometric_pointpair(cga3d_PointPair lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_pointpair_anti_geometric_pointpair(cga3d_PointPair lhs, cga3d_PointPair rhs);
+double cga3d_pointpair_dot_pointpair(cga3d_PointPair lhs, cga3d_PointPair rhs);
+cga3d_Sphere cga3d_pointpair_wedge_pointpair(cga3d_PointPair lhs, cga3d_PointPair rhs);
+double cga3d_pointpair_left_contraction_pointpair(cga3d_PointPair lhs, cga3d_PointPair rhs);
+double cga3d_pointpair_right_contraction_pointpair(cga3d_PointPair lhs, cga3d_PointPair rhs);
+double cga3d_pointpair_scalar_product_pointpair(cga3d_PointPair lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_pointpair_commutator_pointpair(cga3d_PointPair lhs, cga3d_PointPair rhs);
+double cga3d_pointpair_anti_dot_pointpair(cga3d_PointPair lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_pointpair_geometric_line(cga3d_PointPair lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_pointpair_anti_geometric_line(cga3d_PointPair lhs, cga3d_Line rhs);
+cga3d_Point cga3d_pointpair_dot_line(cga3d_PointPair lhs, cga3d_Line rhs);
+double cga3d_pointpair_wedge_line(cga3d_PointPair lhs, cga3d_Line rhs);
+double cga3d_pointpair_anti_wedge_line(cga3d_PointPair lhs, cga3d_Line rhs);
+cga3d_Point cga3d_pointpair_left_contraction_line(cga3d_PointPair lhs, cga3d_Line rhs);
+cga3d_Circle cga3d_pointpair_commutator_line(cga3d_PointPair lhs, cga3d_Line rhs);
+cga3d_Sphere cga3d_pointpair_anti_dot_line(cga3d_PointPair lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_pointpair_geometric_circle(cga3d_PointPair lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_pointpair_anti_geometric_circle(cga3d_PointPair lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_pointpair_dot_circle(cga3d_PointPair lhs, cga3d_Circle rhs);
+double cga3d_pointpair_wedge_circle(cga3d_PointPair lhs, cga3d_Circle rhs);
+double cga3d_pointpair_anti_wedge_circle(cga3d_PointPair lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_pointpair_left_contraction_circle(cga3d_PointPair lhs, cga3d_Circle rhs);
+cga3d_Circle cga3d_pointpair_commutator_circle(cga3d_PointPair lhs, cga3d_Circle rhs);
+cga3d_Sphere cga3d_pointpair_anti_dot_circle(cga3d_PointPair lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_pointpair_geometric_plane(cga3d_PointPair lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_pointpair_anti_geometric_plane(cga3d_PointPair lhs, cga3d_Plane rhs);
+cga3d_PointPair cga3d_pointpair_dot_plane(cga3d_PointPair lhs, cga3d_Plane rhs);
+cga3d_Point cga3d_pointpair_anti_wedge_plane(cga3d_PointPair lhs, cga3d_Plane rhs);
+cga3d_PointPair cga3d_pointpair_left_contraction_plane(cga3d_PointPair lhs, cga3d_Plane rhs);
+cga3d_Sphere cga3d_pointpair_commutator_plane(cga3d_PointPair lhs, cga3d_Plane rhs);
+cga3d_Circle cga3d_pointpair_anti_dot_plane(cga3d_PointPair lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_pointpair_geometric_sphere(cga3d_PointPair lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_pointpair_anti_geometric_sphere(cga3d_PointPair lhs, cga3d_Sphere rhs);
+cga3d_PointPair cga3d_pointpair_dot_sphere(cga3d_PointPair lhs, cga3d_Sphere rhs);
+cga3d_Point cga3d_pointpair_anti_wedge_sphere(cga3d_PointPair lhs, cga3d_Sphere rhs);
+cga3d_PointPair cga3d_pointpair_left_contraction_sphere(cga3d_PointPair lhs, cga3d_Sphere rhs);
+cga3d_Sphere cga3d_pointpair_commutator_sphere(cga3d_PointPair lhs, cga3d_Sphere rhs);
+cga3d_Circle cga3d_pointpair_anti_dot_sphere(cga3d_PointPair lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_pointpair_geometric_rotor(cga3d_PointPair lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_pointpair_anti_geometric_rotor(cga3d_PointPair lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_pointpair_dot_rotor(cga3d_PointPair lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_pointpair_wedge_rotor(cga3d_PointPair lhs, cga3d_Rotor rhs);
+double cga3d_pointpair_left_contraction_rotor(cga3d_PointPair lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_pointpair_right_contraction_rotor(cga3d_PointPair lhs, cga3d_Rotor rhs);
+double cga3d_pointpair_scalar_product_rotor(cga3d_PointPair lhs, cga3d_Rotor rhs);
+cga3d_PointPair cga3d_pointpair_commutator_rotor(cga3d_PointPair lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_pointpair_anti_dot_rotor(cga3d_PointPair lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_pointpair_geometric_translator(cga3d_PointPair lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_pointpair_anti_geometric_translator(cga3d_PointPair lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_pointpair_dot_translator(cga3d_PointPair lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_pointpair_wedge_translator(cga3d_PointPair lhs, cga3d_Translator rhs);
+double cga3d_pointpair_left_contraction_translator(cga3d_PointPair lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_pointpair_right_contraction_translator(cga3d_PointPair lhs, cga3d_Translator rhs);
+double cga3d_pointpair_scalar_product_translator(cga3d_PointPair lhs, cga3d_Translator rhs);
+cga3d_PointPair cga3d_pointpair_commutator_translator(cga3d_PointPair lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_pointpair_anti_dot_translator(cga3d_PointPair lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_pointpair_geometric_versor(cga3d_PointPair lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_pointpair_anti_geometric_versor(cga3d_PointPair lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_pointpair_dot_versor(cga3d_PointPair lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_pointpair_wedge_versor(cga3d_PointPair lhs, cga3d_Versor rhs);
+cga3d_Point cga3d_pointpair_anti_wedge_versor(cga3d_PointPair lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_pointpair_left_contraction_versor(cga3d_PointPair lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_pointpair_right_contraction_versor(cga3d_PointPair lhs, cga3d_Versor rhs);
+double cga3d_pointpair_scalar_product_versor(cga3d_PointPair lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_pointpair_commutator_versor(cga3d_PointPair lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_pointpair_anti_dot_versor(cga3d_PointPair lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_pointpair_geometric_multivector(cga3d_PointPair lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_pointpair_anti_geometric_multivector(cga3d_PointPair lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_pointpair_dot_multivector(cga3d_PointPair lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_pointpair_wedge_multivector(cga3d_PointPair lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_pointpair_anti_wedge_multivector(cga3d_PointPair lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_pointpair_left_contraction_multivector(cga3d_PointPair lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_pointpair_right_contraction_multivector(cga3d_PointPair lhs, cga3d_Multivector rhs);
+double cga3d_pointpair_scalar_product_multivector(cga3d_PointPair lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_pointpair_commutator_multivector(cga3d_PointPair lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_pointpair_anti_dot_multivector(cga3d_PointPair lhs, cga3d_Multivector rhs);
+cga3d_PointPair cga3d_line_rcompl(cga3d_Line value);
+cga3d_PointPair cga3d_line_lcompl(cga3d_Line value);
+cga3d_Line cga3d_line_rev(cga3d_Line value);
+cga3d_Line cga3d_line_arev(cga3d_Line value);
+cga3d_Line cga3d_line_involute(cga3d_Line value);
+cga3d_Line cga3d_line_conjugate(cga3d_Line value);
+cga3d_PointPair cga3d_line_dual(cga3d_Line value);
+cga3d_Versor cga3d_line_geometric_vec3(cga3d_Line lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_line_anti_geometric_vec3(cga3d_Line lhs, cga3d_Vec3 rhs);
+cga3d_FlatPoint cga3d_line_dot_vec3(cga3d_Line lhs, cga3d_Vec3 rhs);
+cga3d_Plane cga3d_line_wedge_vec3(cga3d_Line lhs, cga3d_Vec3 rhs);
+cga3d_FlatPoint cga3d_line_right_contraction_vec3(cga3d_Line lhs, cga3d_Vec3 rhs);
+cga3d_Plane cga3d_line_commutator_vec3(cga3d_Line lhs, cga3d_Vec3 rhs);
+cga3d_Circle cga3d_line_anti_dot_vec3(cga3d_Line lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_line_geometric_point(cga3d_Line lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_line_anti_geometric_point(cga3d_Line lhs, cga3d_Point rhs);
+cga3d_PointPair cga3d_line_dot_point(cga3d_Line lhs, cga3d_Point rhs);
+cga3d_Plane cga3d_line_wedge_point(cga3d_Line lhs, cga3d_Point rhs);
+cga3d_PointPair cga3d_line_right_contraction_point(cga3d_Line lhs, cga3d_Point rhs);
+cga3d_Plane cga3d_line_commutator_point(cga3d_Line lhs, cga3d_Point rhs);
+cga3d_Circle cga3d_line_anti_dot_point(cga3d_Line lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_line_geometric_flatpoint(cga3d_Line lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_line_anti_geometric_flatpoint(cga3d_Line lhs, cga3d_FlatPoint rhs);
+cga3d_Point cga3d_line_dot_flatpoint(cga3d_Line lhs, cga3d_FlatPoint rhs);
+cga3d_Point cga3d_line_right_contraction_flatpoint(cga3d_Line lhs, cga3d_FlatPoint rhs);
+cga3d_Line cga3d_line_commutator_flatpoint(cga3d_Line lhs, cga3d_FlatPoint rhs);
+cga3d_Plane cga3d_line_anti_dot_flatpoint(cga3d_Line lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_line_geometric_pointpair(cga3d_Line lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_line_anti_geometric_pointpair(cga3d_Line lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_line_dot_pointpair(cga3d_Line lhs, cga3d_PointPair rhs);
+double cga3d_line_wedge_pointpair(cga3d_Line lhs, cga3d_PointPair rhs);
+double cga3d_line_anti_wedge_pointpair(cga3d_Line lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_line_right_contraction_pointpair(cga3d_Line lhs, cga3d_PointPair rhs);
+cga3d_Circle cga3d_line_commutator_pointpair(cga3d_Line lhs, cga3d_PointPair rhs);
+cga3d_Sphere cga3d_line_anti_dot_pointpair(cga3d_Line lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_line_geometric_line(cga3d_Line lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_line_anti_geometric_line(cga3d_Line lhs, cga3d_Line rhs);
+double cga3d_line_dot_line(cga3d_Line lhs, cga3d_Line rhs);
+double cga3d_line_anti_wedge_line(cga3d_Line lhs, cga3d_Line rhs);
+double cga3d_line_left_contraction_line(cga3d_Line lhs, cga3d_Line rhs);
+double cga3d_line_right_contraction_line(cga3d_Line lhs, cga3d_Line rhs);
+double cga3d_line_scalar_product_line(cga3d_Line lhs, cga3d_Line rhs);
+cga3d_PointPair cga3d_line_commutator_line(cga3d_Line lhs, cga3d_Line rhs);
+double cga3d_line_anti_dot_line(cga3d_Line lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_line_geometric_circle(cga3d_Line lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_line_anti_geometric_circle(cga3d_Line lhs, cga3d_Circle rhs);
+double cga3d_line_dot_circle(cga3d_Line lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_line_anti_wedge_circle(cga3d_Line lhs, cga3d_Circle rhs);
+double cga3d_line_left_contraction_circle(cga3d_Line lhs, cga3d_Circle rhs);
+double cga3d_line_right_contraction_circle(cga3d_Line lhs, cga3d_Circle rhs);
+double cga3d_line_scalar_product_circle(cga3d_Line lhs, cga3d_Circle rhs);
+cga3d_PointPair cga3d_line_commutator_circle(cga3d_Line lhs, cga3d_Circle rhs);
+double cga3d_line_anti_dot_circle(cga3d_Line lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_line_geometric_plane(cga3d_Line lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_line_anti_geometric_plane(cga3d_Line lhs, cga3d_Plane rhs);
+cga3d_Point cga3d_line_dot_plane(cga3d_Line lhs, cga3d_Plane rhs);
+cga3d_FlatPoint cga3d_line_anti_wedge_plane(cga3d_Line lhs, cga3d_Plane rhs);
+cga3d_Point cga3d_line_left_contraction_plane(cga3d_Line lhs, cga3d_Plane rhs);
+cga3d_Point cga3d_line_commutator_plane(cga3d_Line lhs, cga3d_Plane rhs);
+cga3d_Plane cga3d_line_anti_dot_plane(cga3d_Line lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_line_geometric_sphere(cga3d_Line lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_line_anti_geometric_sphere(cga3d_Line lhs, cga3d_Sphere rhs);
+cga3d_Point cga3d_line_dot_sphere(cga3d_Line lhs, cga3d_Sphere rhs);
+cga3d_PointPair cga3d_line_anti_wedge_sphere(cga3d_Line lhs, cga3d_Sphere rhs);
+cga3d_Point cga3d_line_left_contraction_sphere(cga3d_Line lhs, cga3d_Sphere rhs);
+cga3d_Point cga3d_line_commutator_sphere(cga3d_Line lhs, cga3d_Sphere rhs);
+cga3d_Plane cga3d_line_anti_dot_sphere(cga3d_Line lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_line_geometric_rotor(cga3d_Line lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_line_anti_geometric_rotor(cga3d_Line lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_line_dot_rotor(cga3d_Line lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_line_wedge_rotor(cga3d_Line lhs, cga3d_Rotor rhs);
+double cga3d_line_anti_wedge_rotor(cga3d_Line lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_line_right_contraction_rotor(cga3d_Line lhs, cga3d_Rotor rhs);
+cga3d_Line cga3d_line_commutator_rotor(cga3d_Line lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_line_anti_dot_rotor(cga3d_Line lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_line_geometric_translator(cga3d_Line lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_line_anti_geometric_translator(cga3d_Line lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_line_dot_translator(cga3d_Line lhs, cga3d_Translator rhs);
+cga3d_Line cga3d_line_wedge_translator(cga3d_Line lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_line_right_contraction_translator(cga3d_Line lhs, cga3d_Translator rhs);
+cga3d_Line cga3d_line_commutator_translator(cga3d_Line lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_line_anti_dot_translator(cga3d_Line lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_line_geometric_versor(cga3d_Line lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_line_anti_geometric_versor(cga3d_Line lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_line_dot_versor(cga3d_Line lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_line_wedge_versor(cga3d_Line lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_line_anti_wedge_versor(cga3d_Line lhs, cga3d_Versor rhs);
+cga3d_Point cga3d_line_left_contraction_versor(cga3d_Line lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_line_right_contraction_versor(cga3d_Line lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_line_commutator_versor(cga3d_Line lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_line_anti_dot_versor(cga3d_Line lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_line_geometric_multivector(cga3d_Line lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_line_anti_geometric_multivector(cga3d_Line lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_line_dot_multivector(cga3d_Line lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_line_wedge_multivector(cga3d_Line lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_line_anti_wedge_multivector(cga3d_Line lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_line_left_contraction_multivector(cga3d_Line lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_line_right_contraction_multivector(cga3d_Line lhs, cga3d_Multivector rhs);
+double cga3d_line_scalar_product_multivector(cga3d_Line lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_line_commutator_multivector(cga3d_Line lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_line_anti_dot_multivector(cga3d_Line lhs, cga3d_Multivector rhs);
+cga3d_PointPair cga3d_circle_rcompl(cga3d_Circle value);
+cga3d_PointPair cga3d_circle_lcompl(cga3d_Circle value);
+cga3d_Circle cga3d_circle_rev(cga3d_Circle value);
+cga3d_Circle cga3d_circle_arev(cga3d_Circle value);
+cga3d_Circle cga3d_circle_involute(cga3d_Circle value);
+cga3d_Circle cga3d_circle_conjugate(cga3d_Circle value);
+cga3d_PointPair cga3d_circle_dual(cga3d_Circle value);
+cga3d_Versor cga3d_circle_geometric_vec3(cga3d_Circle lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_circle_anti_geometric_vec3(cga3d_Circle lhs, cga3d_Vec3 rhs);
+cga3d_PointPair cga3d_circle_dot_vec3(cga3d_Circle lhs, cga3d_Vec3 rhs);
+cga3d_Sphere cga3d_circle_wedge_vec3(cga3d_Circle lhs, cga3d_Vec3 rhs);
+cga3d_PointPair cga3d_circle_right_contraction_vec3(cga3d_Circle lhs, cga3d_Vec3 rhs);
+cga3d_Sphere cga3d_circle_commutator_vec3(cga3d_Circle lhs, cga3d_Vec3 rhs);
+cga3d_Circle cga3d_circle_anti_dot_vec3(cga3d_Circle lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_circle_geometric_point(cga3d_Circle lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_circle_anti_geometric_point(cga3d_Circle lhs, cga3d_Point rhs);
+cga3d_PointPair cga3d_circle_dot_point(cga3d_Circle lhs, cga3d_Point rhs);
+cga3d_Sphere cga3d_circle_wedge_point(cga3d_Circle lhs, cga3d_Point rhs);
+cga3d_PointPair cga3d_circle_right_contraction_point(cga3d_Circle lhs, cga3d_Point rhs);
+cga3d_Sphere cga3d_circle_commutator_point(cga3d_Circle lhs, cga3d_Point rhs);
+cga3d_Circle cga3d_circle_anti_dot_point(cga3d_Circle lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_circle_geometric_flatpoint(cga3d_Circle lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_circle_anti_geometric_flatpoint(cga3d_Circle lhs, cga3d_FlatPoint rhs);
+cga3d_Point cga3d_circle_dot_flatpoint(cga3d_Circle lhs, cga3d_FlatPoint rhs);
+double cga3d_circle_wedge_flatpoint(cga3d_Circle lhs, cga3d_FlatPoint rhs);
+double cga3d_circle_anti_wedge_flatpoint(cga3d_Circle lhs, cga3d_FlatPoint rhs);
+cga3d_Point cga3d_circle_right_contraction_flatpoint(cga3d_Circle lhs, cga3d_FlatPoint rhs);
+cga3d_Circle cga3d_circle_commutator_flatpoint(cga3d_Circle lhs, cga3d_FlatPoint rhs);
+cga3d_Plane cga3d_circle_anti_dot_flatpoint(cga3d_Circle lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_circle_geometric_pointpair(cga3d_Circle lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_circle_anti_geometric_pointpair(cga3d_Circle lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_circle_dot_pointpair(cga3d_Circle lhs, cga3d_PointPair rhs);
+double cga3d_circle_wedge_pointpair(cga3d_Circle lhs, cga3d_PointPair rhs);
+double cga3d_circle_anti_wedge_pointpair(cga3d_Circle lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_circle_right_contraction_pointpair(cga3d_Circle lhs, cga3d_PointPair rhs);
+cga3d_Circle cga3d_circle_commutator_pointpair(cga3d_Circle lhs, cga3d_PointPair rhs);
+cga3d_Sphere cga3d_circle_anti_dot_pointpair(cga3d_Circle lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_circle_geometric_line(cga3d_Circle lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_circle_anti_geometric_line(cga3d_Circle lhs, cga3d_Line rhs);
+double cga3d_circle_dot_line(cga3d_Circle lhs, cga3d_Line rhs);
+cga3d_Point cga3d_circle_anti_wedge_line(cga3d_Circle lhs, cga3d_Line rhs);
+double cga3d_circle_left_contraction_line(cga3d_Circle lhs, cga3d_Line rhs);
+double cga3d_circle_right_contraction_line(cga3d_Circle lhs, cga3d_Line rhs);
+double cga3d_circle_scalar_product_line(cga3d_Circle lhs, cga3d_Line rhs);
+cga3d_PointPair cga3d_circle_commutator_line(cga3d_Circle lhs, cga3d_Line rhs);
+double cga3d_circle_anti_dot_line(cga3d_Circle lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_circle_geometric_circle(cga3d_Circle lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_circle_anti_geometric_circle(cga3d_Circle lhs, cga3d_Circle rhs);
+double cga3d_circle_dot_circle(cga3d_Circle lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_circle_anti_wedge_circle(cga3d_Circle lhs, cga3d_Circle rhs);
+double cga3d_circle_left_contraction_circle(cga3d_Circle lhs, cga3d_Circle rhs);
+double cga3d_circle_right_contraction_circle(cga3d_Circle lhs, cga3d_Circle rhs);
+double cga3d_circle_scalar_product_circle(cga3d_Circle lhs, cga3d_Circle rhs);
+cga3d_PointPair cga3d_circle_commutator_circle(cga3d_Circle lhs, cga3d_Circle rhs);
+double cga3d_circle_anti_dot_circle(cga3d_Circle lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_circle_geometric_plane(cga3d_Circle lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_circle_anti_geometric_plane(cga3d_Circle lhs, cga3d_Plane rhs);
+cga3d_Point cga3d_circle_dot_plane(cga3d_Circle lhs, cga3d_Plane rhs);
+cga3d_PointPair cga3d_circle_anti_wedge_plane(cga3d_Circle lhs, cga3d_Plane rhs);
+cga3d_Point cga3d_circle_left_contraction_plane(cga3d_Circle lhs, cga3d_Plane rhs);
+cga3d_Point cga3d_circle_commutator_plane(cga3d_Circle lhs, cga3d_Plane rhs);
+cga3d_Sphere cga3d_circle_anti_dot_plane(cga3d_Circle lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_circle_geometric_sphere(cga3d_Circle lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_circle_anti_geometric_sphere(cga3d_Circle lhs, cga3d_Sphere rhs);
+cga3d_Point cga3d_circle_dot_sphere(cga3d_Circle lhs, cga3d_Sphere rhs);
+cga3d_PointPair cga3d_circle_anti_wedge_sphere(cga3d_Circle lhs, cga3d_Sphere rhs);
+cga3d_Point cga3d_circle_left_contraction_sphere(cga3d_Circle lhs, cga3d_Sphere rhs);
+cga3d_Point cga3d_circle_commutator_sphere(cga3d_Circle lhs, cga3d_Sphere rhs);
+cga3d_Sphere cga3d_circle_anti_dot_sphere(cga3d_Circle lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_circle_geometric_rotor(cga3d_Circle lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_circle_anti_geometric_rotor(cga3d_Circle lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_circle_dot_rotor(cga3d_Circle lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_circle_wedge_rotor(cga3d_Circle lhs, cga3d_Rotor rhs);
+double cga3d_circle_anti_wedge_rotor(cga3d_Circle lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_circle_right_contraction_rotor(cga3d_Circle lhs, cga3d_Rotor rhs);
+cga3d_Circle cga3d_circle_commutator_rotor(cga3d_Circle lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_circle_anti_dot_rotor(cga3d_Circle lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_circle_geometric_translator(cga3d_Circle lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_circle_anti_geometric_translator(cga3d_Circle lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_circle_dot_translator(cga3d_Circle lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_circle_wedge_translator(cga3d_Circle lhs, cga3d_Translator rhs);
+double cga3d_circle_anti_wedge_translator(cga3d_Circle lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_circle_right_contraction_translator(cga3d_Circle lhs, cga3d_Translator rhs);
+cga3d_Circle cga3d_circle_commutator_translator(cga3d_Circle lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_circle_anti_dot_translator(cga3d_Circle lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_circle_geometric_versor(cga3d_Circle lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_circle_anti_geometric_versor(cga3d_Circle lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_circle_dot_versor(cga3d_Circle lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_circle_wedge_versor(cga3d_Circle lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_circle_anti_wedge_versor(cga3d_Circle lhs, cga3d_Versor rhs);
+cga3d_Point cga3d_circle_left_contraction_versor(cga3d_Circle lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_circle_right_contraction_versor(cga3d_Circle lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_circle_commutator_versor(cga3d_Circle lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_circle_anti_dot_versor(cga3d_Circle lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_circle_geometric_multivector(cga3d_Circle lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_circle_anti_geometric_multivector(cga3d_Circle lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_circle_dot_multivector(cga3d_Circle lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_circle_wedge_multivector(cga3d_Circle lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_circle_anti_wedge_multivector(cga3d_Circle lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_circle_left_contraction_multivector(cga3d_Circle lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_circle_right_contraction_multivector(cga3d_Circle lhs, cga3d_Multivector rhs);
+double cga3d_circle_scalar_product_multivector(cga3d_Circle lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_circle_commutator_multivector(cga3d_Circle lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_circle_anti_dot_multivector(cga3d_Circle lhs, cga3d_Multivector rhs);
+cga3d_Point cga3d_plane_rcompl(cga3d_Plane value);
+cga3d_Point cga3d_plane_lcompl(cga3d_Plane value);
+cga3d_Plane cga3d_plane_rev(cga3d_Plane value);
+cga3d_Plane cga3d_plane_arev(cga3d_Plane value);
+cga3d_Plane cga3d_plane_involute(cga3d_Plane value);
+cga3d_Plane cga3d_plane_conjugate(cga3d_Plane value);
+cga3d_Point cga3d_plane_dual(cga3d_Plane value);
+cga3d_Multivector cga3d_plane_geometric_vec3(cga3d_Plane lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_plane_anti_geometric_vec3(cga3d_Plane lhs, cga3d_Vec3 rhs);
+cga3d_Line cga3d_plane_dot_vec3(cga3d_Plane lhs, cga3d_Vec3 rhs);
+double cga3d_plane_wedge_vec3(cga3d_Plane lhs, cga3d_Vec3 rhs);
+double cga3d_plane_anti_wedge_vec3(cga3d_Plane lhs, cga3d_Vec3 rhs);
+cga3d_Line cga3d_plane_right_contraction_vec3(cga3d_Plane lhs, cga3d_Vec3 rhs);
+cga3d_Line cga3d_plane_commutator_vec3(cga3d_Plane lhs, cga3d_Vec3 rhs);
+cga3d_PointPair cga3d_plane_anti_dot_vec3(cga3d_Plane lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_plane_geometric_point(cga3d_Plane lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_plane_anti_geometric_point(cga3d_Plane lhs, cga3d_Point rhs);
+cga3d_Circle cga3d_plane_dot_point(cga3d_Plane lhs, cga3d_Point rhs);
+double cga3d_plane_wedge_point(cga3d_Plane lhs, cga3d_Point rhs);
+double cga3d_plane_anti_wedge_point(cga3d_Plane lhs, cga3d_Point rhs);
+cga3d_Circle cga3d_plane_right_contraction_point(cga3d_Plane lhs, cga3d_Point rhs);
+cga3d_Circle cga3d_plane_commutator_point(cga3d_Plane lhs, cga3d_Point rhs);
+cga3d_PointPair cga3d_plane_anti_dot_point(cga3d_Plane lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_plane_geometric_flatpoint(cga3d_Plane lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_plane_anti_geometric_flatpoint(cga3d_Plane lhs, cga3d_FlatPoint rhs);
+cga3d_PointPair cga3d_plane_dot_flatpoint(cga3d_Plane lhs, cga3d_FlatPoint rhs);
+double cga3d_plane_anti_wedge_flatpoint(cga3d_Plane lhs, cga3d_FlatPoint rhs);
+cga3d_PointPair cga3d_plane_right_contraction_flatpoint(cga3d_Plane lhs, cga3d_FlatPoint rhs);
+double cga3d_plane_commutator_flatpoint(cga3d_Plane lhs, cga3d_FlatPoint rhs);
+cga3d_Line cga3d_plane_anti_dot_flatpoint(cga3d_Plane lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_plane_geometric_pointpair(cga3d_Plane lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_plane_anti_geometric_pointpair(cga3d_Plane lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_plane_dot_pointpair(cga3d_Plane lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_plane_anti_wedge_pointpair(cga3d_Plane lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_plane_right_contraction_pointpair(cga3d_Plane lhs, cga3d_PointPair rhs);
+cga3d_Sphere cga3d_plane_commutator_pointpair(cga3d_Plane lhs, cga3d_PointPair rhs);
+cga3d_Circle cga3d_plane_anti_dot_pointpair(cga3d_Plane lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_plane_geometric_line(cga3d_Plane lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_plane_anti_geometric_line(cga3d_Plane lhs, cga3d_Line rhs);
+cga3d_Point cga3d_plane_dot_line(cga3d_Plane lhs, cga3d_Line rhs);
+cga3d_FlatPoint cga3d_plane_anti_wedge_line(cga3d_Plane lhs, cga3d_Line rhs);
+cga3d_Point cga3d_plane_right_contraction_line(cga3d_Plane lhs, cga3d_Line rhs);
+cga3d_Point cga3d_plane_commutator_line(cga3d_Plane lhs, cga3d_Line rhs);
+cga3d_Plane cga3d_plane_anti_dot_line(cga3d_Plane lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_plane_geometric_circle(cga3d_Plane lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_plane_anti_geometric_circle(cga3d_Plane lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_plane_dot_circle(cga3d_Plane lhs, cga3d_Circle rhs);
+cga3d_PointPair cga3d_plane_anti_wedge_circle(cga3d_Plane lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_plane_right_contraction_circle(cga3d_Plane lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_plane_commutator_circle(cga3d_Plane lhs, cga3d_Circle rhs);
+cga3d_Sphere cga3d_plane_anti_dot_circle(cga3d_Plane lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_plane_geometric_plane(cga3d_Plane lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_plane_anti_geometric_plane(cga3d_Plane lhs, cga3d_Plane rhs);
+double cga3d_plane_dot_plane(cga3d_Plane lhs, cga3d_Plane rhs);
+cga3d_Line cga3d_plane_anti_wedge_plane(cga3d_Plane lhs, cga3d_Plane rhs);
+double cga3d_plane_left_contraction_plane(cga3d_Plane lhs, cga3d_Plane rhs);
+double cga3d_plane_right_contraction_plane(cga3d_Plane lhs, cga3d_Plane rhs);
+double cga3d_plane_scalar_product_plane(cga3d_Plane lhs, cga3d_Plane rhs);
+cga3d_PointPair cga3d_plane_commutator_plane(cga3d_Plane lhs, cga3d_Plane rhs);
+double cga3d_plane_anti_dot_plane(cga3d_Plane lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_plane_geometric_sphere(cga3d_Plane lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_plane_anti_geometric_sphere(cga3d_Plane lhs, cga3d_Sphere rhs);
+double cga3d_plane_dot_sphere(cga3d_Plane lhs, cga3d_Sphere rhs);
+cga3d_Circle cga3d_plane_anti_wedge_sphere(cga3d_Plane lhs, cga3d_Sphere rhs);
+double cga3d_plane_left_contraction_sphere(cga3d_Plane lhs, cga3d_Sphere rhs);
+double cga3d_plane_right_contraction_sphere(cga3d_Plane lhs, cga3d_Sphere rhs);
+double cga3d_plane_scalar_product_sphere(cga3d_Plane lhs, cga3d_Sphere rhs);
+cga3d_PointPair cga3d_plane_commutator_sphere(cga3d_Plane lhs, cga3d_Sphere rhs);
+double cga3d_plane_anti_dot_sphere(cga3d_Plane lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_plane_geometric_rotor(cga3d_Plane lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_plane_anti_geometric_rotor(cga3d_Plane lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_plane_dot_rotor(cga3d_Plane lhs, cga3d_Rotor rhs);
+cga3d_Plane cga3d_plane_wedge_rotor(cga3d_Plane lhs, cga3d_Rotor rhs);
+cga3d_Vec3 cga3d_plane_anti_wedge_rotor(cga3d_Plane lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_plane_right_contraction_rotor(cga3d_Plane lhs, cga3d_Rotor rhs);
+cga3d_Plane cga3d_plane_commutator_rotor(cga3d_Plane lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_plane_anti_dot_rotor(cga3d_Plane lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_plane_geometric_translator(cga3d_Plane lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_plane_anti_geometric_translator(cga3d_Plane lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_plane_dot_translator(cga3d_Plane lhs, cga3d_Translator rhs);
+cga3d_Plane cga3d_plane_wedge_translator(cga3d_Plane lhs, cga3d_Translator rhs);
+double cga3d_plane_anti_wedge_translator(cga3d_Plane lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_plane_right_contraction_translator(cga3d_Plane lhs, cga3d_Translator rhs);
+double cga3d_plane_commutator_translator(cga3d_Plane lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_plane_anti_dot_translator(cga3d_Plane lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_plane_geometric_versor(cga3d_Plane lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_plane_anti_geometric_versor(cga3d_Plane lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_plane_dot_versor(cga3d_Plane lhs, cga3d_Versor rhs);
+cga3d_Plane cga3d_plane_wedge_versor(cga3d_Plane lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_plane_anti_wedge_versor(cga3d_Plane lhs, cga3d_Versor rhs);
+double cga3d_plane_left_contraction_versor(cga3d_Plane lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_plane_right_contraction_versor(cga3d_Plane lhs, cga3d_Versor rhs);
+double cga3d_plane_scalar_product_versor(cga3d_Plane lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_plane_commutator_versor(cga3d_Plane lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_plane_anti_dot_versor(cga3d_Plane lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_plane_geometric_multivector(cga3d_Plane lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_plane_anti_geometric_multivector(cga3d_Plane lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_plane_dot_multivector(cga3d_Plane lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_plane_wedge_multivector(cga3d_Plane lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_plane_anti_wedge_multivector(cga3d_Plane lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_plane_left_contraction_multivector(cga3d_Plane lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_plane_right_contraction_multivector(cga3d_Plane lhs, cga3d_Multivector rhs);
+double cga3d_plane_scalar_product_multivector(cga3d_Plane lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_plane_commutator_multivector(cga3d_Plane lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_plane_anti_dot_multivector(cga3d_Plane lhs, cga3d_Multivector rhs);
+cga3d_Point cga3d_sphere_rcompl(cga3d_Sphere value);
+cga3d_Point cga3d_sphere_lcompl(cga3d_Sphere value);
+cga3d_Sphere cga3d_sphere_rev(cga3d_Sphere value);
+cga3d_Sphere cga3d_sphere_arev(cga3d_Sphere value);
+cga3d_Sphere cga3d_sphere_involute(cga3d_Sphere value);
+cga3d_Sphere cga3d_sphere_conjugate(cga3d_Sphere value);
+cga3d_Point cga3d_sphere_dual(cga3d_Sphere value);
+cga3d_Multivector cga3d_sphere_geometric_vec3(cga3d_Sphere lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_sphere_anti_geometric_vec3(cga3d_Sphere lhs, cga3d_Vec3 rhs);
+cga3d_Circle cga3d_sphere_dot_vec3(cga3d_Sphere lhs, cga3d_Vec3 rhs);
+double cga3d_sphere_wedge_vec3(cga3d_Sphere lhs, cga3d_Vec3 rhs);
+double cga3d_sphere_anti_wedge_vec3(cga3d_Sphere lhs, cga3d_Vec3 rhs);
+cga3d_Circle cga3d_sphere_right_contraction_vec3(cga3d_Sphere lhs, cga3d_Vec3 rhs);
+cga3d_Circle cga3d_sphere_commutator_vec3(cga3d_Sphere lhs, cga3d_Vec3 rhs);
+cga3d_PointPair cga3d_sphere_anti_dot_vec3(cga3d_Sphere lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_sphere_geometric_point(cga3d_Sphere lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_sphere_anti_geometric_point(cga3d_Sphere lhs, cga3d_Point rhs);
+cga3d_Circle cga3d_sphere_dot_point(cga3d_Sphere lhs, cga3d_Point rhs);
+double cga3d_sphere_wedge_point(cga3d_Sphere lhs, cga3d_Point rhs);
+double cga3d_sphere_anti_wedge_point(cga3d_Sphere lhs, cga3d_Point rhs);
+cga3d_Circle cga3d_sphere_right_contraction_point(cga3d_Sphere lhs, cga3d_Point rhs);
+cga3d_Circle cga3d_sphere_commutator_point(cga3d_Sphere lhs, cga3d_Point rhs);
+cga3d_PointPair cga3d_sphere_anti_dot_point(cga3d_Sphere lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_sphere_geometric_flatpoint(cga3d_Sphere lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_sphere_anti_geometric_flatpoint(cga3d_Sphere lhs, cga3d_FlatPoint rhs);
+cga3d_PointPair cga3d_sphere_dot_flatpoint(cga3d_Sphere lhs, cga3d_FlatPoint rhs);
+cga3d_Point cga3d_sphere_anti_wedge_flatpoint(cga3d_Sphere lhs, cga3d_FlatPoint rhs);
+cga3d_PointPair cga3d_sphere_right_contraction_flatpoint(cga3d_Sphere lhs, cga3d_FlatPoint rhs);
+cga3d_Sphere cga3d_sphere_commutator_flatpoint(cga3d_Sphere lhs, cga3d_FlatPoint rhs);
+cga3d_Line cga3d_sphere_anti_dot_flatpoint(cga3d_Sphere lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_sphere_geometric_pointpair(cga3d_Sphere lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_sphere_anti_geometric_pointpair(cga3d_Sphere lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_sphere_dot_pointpair(cga3d_Sphere lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_sphere_anti_wedge_pointpair(cga3d_Sphere lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_sphere_right_contraction_pointpair(cga3d_Sphere lhs, cga3d_PointPair rhs);
+cga3d_Sphere cga3d_sphere_commutator_pointpair(cga3d_Sphere lhs, cga3d_PointPair rhs);
+cga3d_Circle cga3d_sphere_anti_dot_pointpair(cga3d_Sphere lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_sphere_geometric_line(cga3d_Sphere lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_sphere_anti_geometric_line(cga3d_Sphere lhs, cga3d_Line rhs);
+cga3d_Point cga3d_sphere_dot_line(cga3d_Sphere lhs, cga3d_Line rhs);
+cga3d_PointPair cga3d_sphere_anti_wedge_line(cga3d_Sphere lhs, cga3d_Line rhs);
+cga3d_Point cga3d_sphere_right_contraction_line(cga3d_Sphere lhs, cga3d_Line rhs);
+cga3d_Point cga3d_sphere_commutator_line(cga3d_Sphere lhs, cga3d_Line rhs);
+cga3d_Plane cga3d_sphere_anti_dot_line(cga3d_Sphere lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_sphere_geometric_circle(cga3d_Sphere lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_sphere_anti_geometric_circle(cga3d_Sphere lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_sphere_dot_circle(cga3d_Sphere lhs, cga3d_Circle rhs);
+cga3d_PointPair cga3d_sphere_anti_wedge_circle(cga3d_Sphere lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_sphere_right_contraction_circle(cga3d_Sphere lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_sphere_commutator_circle(cga3d_Sphere lhs, cga3d_Circle rhs);
+cga3d_Sphere cga3d_sphere_anti_dot_circle(cga3d_Sphere lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_sphere_geometric_plane(cga3d_Sphere lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_sphere_anti_geometric_plane(cga3d_Sphere lhs, cga3d_Plane rhs);
+double cga3d_sphere_dot_plane(cga3d_Sphere lhs, cga3d_Plane rhs);
+cga3d_Circle cga3d_sphere_anti_wedge_plane(cga3d_Sphere lhs, cga3d_Plane rhs);
+double cga3d_sphere_left_contraction_plane(cga3d_Sphere lhs, cga3d_Plane rhs);
+double cga3d_sphere_right_contraction_plane(cga3d_Sphere lhs, cga3d_Plane rhs);
+double cga3d_sphere_scalar_product_plane(cga3d_Sphere lhs, cga3d_Plane rhs);
+cga3d_PointPair cga3d_sphere_commutator_plane(cga3d_Sphere lhs, cga3d_Plane rhs);
+double cga3d_sphere_anti_dot_plane(cga3d_Sphere lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_sphere_geometric_sphere(cga3d_Sphere lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_sphere_anti_geometric_sphere(cga3d_Sphere lhs, cga3d_Sphere rhs);
+double cga3d_sphere_dot_sphere(cga3d_Sphere lhs, cga3d_Sphere rhs);
+cga3d_Circle cga3d_sphere_anti_wedge_sphere(cga3d_Sphere lhs, cga3d_Sphere rhs);
+double cga3d_sphere_left_contraction_sphere(cga3d_Sphere lhs, cga3d_Sphere rhs);
+double cga3d_sphere_right_contraction_sphere(cga3d_Sphere lhs, cga3d_Sphere rhs);
+double cga3d_sphere_scalar_product_sphere(cga3d_Sphere lhs, cga3d_Sphere rhs);
+cga3d_PointPair cga3d_sphere_commutator_sphere(cga3d_Sphere lhs, cga3d_Sphere rhs);
+double cga3d_sphere_anti_dot_sphere(cga3d_Sphere lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_sphere_geometric_rotor(cga3d_Sphere lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_sphere_anti_geometric_rotor(cga3d_Sphere lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_sphere_dot_rotor(cga3d_Sphere lhs, cga3d_Rotor rhs);
+cga3d_Sphere cga3d_sphere_wedge_rotor(cga3d_Sphere lhs, cga3d_Rotor rhs);
+cga3d_Vec3 cga3d_sphere_anti_wedge_rotor(cga3d_Sphere lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_sphere_right_contraction_rotor(cga3d_Sphere lhs, cga3d_Rotor rhs);
+cga3d_Plane cga3d_sphere_commutator_rotor(cga3d_Sphere lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_sphere_anti_dot_rotor(cga3d_Sphere lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_sphere_geometric_translator(cga3d_Sphere lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_sphere_anti_geometric_translator(cga3d_Sphere lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_sphere_dot_translator(cga3d_Sphere lhs, cga3d_Translator rhs);
+cga3d_Sphere cga3d_sphere_wedge_translator(cga3d_Sphere lhs, cga3d_Translator rhs);
+cga3d_Point cga3d_sphere_anti_wedge_translator(cga3d_Sphere lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_sphere_right_contraction_translator(cga3d_Sphere lhs, cga3d_Translator rhs);
+cga3d_Plane cga3d_sphere_commutator_translator(cga3d_Sphere lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_sphere_anti_dot_translator(cga3d_Sphere lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_sphere_geometric_versor(cga3d_Sphere lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_sphere_anti_geometric_versor(cga3d_Sphere lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_sphere_dot_versor(cga3d_Sphere lhs, cga3d_Versor rhs);
+cga3d_Sphere cga3d_sphere_wedge_versor(cga3d_Sphere lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_sphere_anti_wedge_versor(cga3d_Sphere lhs, cga3d_Versor rhs);
+double cga3d_sphere_left_contraction_versor(cga3d_Sphere lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_sphere_right_contraction_versor(cga3d_Sphere lhs, cga3d_Versor rhs);
+double cga3d_sphere_scalar_product_versor(cga3d_Sphere lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_sphere_commutator_versor(cga3d_Sphere lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_sphere_anti_dot_versor(cga3d_Sphere lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_sphere_geometric_multivector(cga3d_Sphere lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_sphere_anti_geometric_multivector(cga3d_Sphere lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_sphere_dot_multivector(cga3d_Sphere lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_sphere_wedge_multivector(cga3d_Sphere lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_sphere_anti_wedge_multivector(cga3d_Sphere lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_sphere_left_contraction_multivector(cga3d_Sphere lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_sphere_right_contraction_multivector(cga3d_Sphere lhs, cga3d_Multivector rhs);
+double cga3d_sphere_scalar_product_multivector(cga3d_Sphere lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_sphere_commutator_multivector(cga3d_Sphere lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_sphere_anti_dot_multivector(cga3d_Sphere lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_rotor_rcompl(cga3d_Rotor value);
+cga3d_Multivector cga3d_rotor_lcompl(cga3d_Rotor value);
+cga3d_Rotor cga3d_rotor_rev(cga3d_Rotor value);
+cga3d_Rotor cga3d_rotor_arev(cga3d_Rotor value);
+cga3d_Rotor cga3d_rotor_involute(cga3d_Rotor value);
+cga3d_Rotor cga3d_rotor_conjugate(cga3d_Rotor value);
+cga3d_Multivector cga3d_rotor_dual(cga3d_Rotor value);
+cga3d_Multivector cga3d_rotor_geometric_vec3(cga3d_Rotor lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_rotor_anti_geometric_vec3(cga3d_Rotor lhs, cga3d_Vec3 rhs);
+cga3d_Vec3 cga3d_rotor_dot_vec3(cga3d_Rotor lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_rotor_wedge_vec3(cga3d_Rotor lhs, cga3d_Vec3 rhs);
+cga3d_Vec3 cga3d_rotor_left_contraction_vec3(cga3d_Rotor lhs, cga3d_Vec3 rhs);
+cga3d_Vec3 cga3d_rotor_right_contraction_vec3(cga3d_Rotor lhs, cga3d_Vec3 rhs);
+cga3d_Vec3 cga3d_rotor_commutator_vec3(cga3d_Rotor lhs, cga3d_Vec3 rhs);
+cga3d_Plane cga3d_rotor_anti_dot_vec3(cga3d_Rotor lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_rotor_geometric_point(cga3d_Rotor lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_rotor_anti_geometric_point(cga3d_Rotor lhs, cga3d_Point rhs);
+cga3d_Point cga3d_rotor_dot_point(cga3d_Rotor lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_rotor_wedge_point(cga3d_Rotor lhs, cga3d_Point rhs);
+cga3d_Point cga3d_rotor_left_contraction_point(cga3d_Rotor lhs, cga3d_Point rhs);
+cga3d_Vec3 cga3d_rotor_right_contraction_point(cga3d_Rotor lhs, cga3d_Point rhs);
+cga3d_Vec3 cga3d_rotor_commutator_point(cga3d_Rotor lhs, cga3d_Point rhs);
+cga3d_Sphere cga3d_rotor_anti_dot_point(cga3d_Rotor lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_rotor_geometric_flatpoint(cga3d_Rotor lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_rotor_anti_geometric_flatpoint(cga3d_Rotor lhs, cga3d_FlatPoint rhs);
+cga3d_FlatPoint cga3d_rotor_dot_flatpoint(cga3d_Rotor lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_rotor_wedge_flatpoint(cga3d_Rotor lhs, cga3d_FlatPoint rhs);
+cga3d_FlatPoint cga3d_rotor_left_contraction_flatpoint(cga3d_Rotor lhs, cga3d_FlatPoint rhs);
+cga3d_FlatPoint cga3d_rotor_commutator_flatpoint(cga3d_Rotor lhs, cga3d_FlatPoint rhs);
+cga3d_Circle cga3d_rotor_anti_dot_flatpoint(cga3d_Rotor lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_rotor_geometric_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_rotor_anti_geometric_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_rotor_dot_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_rotor_wedge_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_rotor_left_contraction_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+double cga3d_rotor_right_contraction_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+double cga3d_rotor_scalar_product_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_rotor_commutator_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_rotor_anti_dot_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_rotor_geometric_line(cga3d_Rotor lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_rotor_anti_geometric_line(cga3d_Rotor lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_rotor_dot_line(cga3d_Rotor lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_rotor_wedge_line(cga3d_Rotor lhs, cga3d_Line rhs);
+double cga3d_rotor_anti_wedge_line(cga3d_Rotor lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_rotor_left_contraction_line(cga3d_Rotor lhs, cga3d_Line rhs);
+cga3d_Line cga3d_rotor_commutator_line(cga3d_Rotor lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_rotor_anti_dot_line(cga3d_Rotor lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_rotor_geometric_circle(cga3d_Rotor lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_rotor_anti_geometric_circle(cga3d_Rotor lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_rotor_dot_circle(cga3d_Rotor lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_rotor_wedge_circle(cga3d_Rotor lhs, cga3d_Circle rhs);
+double cga3d_rotor_anti_wedge_circle(cga3d_Rotor lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_rotor_left_contraction_circle(cga3d_Rotor lhs, cga3d_Circle rhs);
+cga3d_Circle cga3d_rotor_commutator_circle(cga3d_Rotor lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_rotor_anti_dot_circle(cga3d_Rotor lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_rotor_geometric_plane(cga3d_Rotor lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_rotor_anti_geometric_plane(cga3d_Rotor lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_rotor_dot_plane(cga3d_Rotor lhs, cga3d_Plane rhs);
+cga3d_Plane cga3d_rotor_wedge_plane(cga3d_Rotor lhs, cga3d_Plane rhs);
+cga3d_Vec3 cga3d_rotor_anti_wedge_plane(cga3d_Rotor lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_rotor_left_contraction_plane(cga3d_Rotor lhs, cga3d_Plane rhs);
+cga3d_Plane cga3d_rotor_commutator_plane(cga3d_Rotor lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_rotor_anti_dot_plane(cga3d_Rotor lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_rotor_geometric_sphere(cga3d_Rotor lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_rotor_anti_geometric_sphere(cga3d_Rotor lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_rotor_dot_sphere(cga3d_Rotor lhs, cga3d_Sphere rhs);
+cga3d_Sphere cga3d_rotor_wedge_sphere(cga3d_Rotor lhs, cga3d_Sphere rhs);
+cga3d_Vec3 cga3d_rotor_anti_wedge_sphere(cga3d_Rotor lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_rotor_left_contraction_sphere(cga3d_Rotor lhs, cga3d_Sphere rhs);
+cga3d_Plane cga3d_rotor_commutator_sphere(cga3d_Rotor lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_rotor_anti_dot_sphere(cga3d_Rotor lhs, cga3d_Sphere rhs);
+cga3d_Rotor cga3d_rotor_geometric_rotor(cga3d_Rotor lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_rotor_anti_geometric_rotor(cga3d_Rotor lhs, cga3d_Rotor rhs);
+cga3d_Rotor cga3d_rotor_dot_rotor(cga3d_Rotor lhs, cga3d_Rotor rhs);
+cga3d_Rotor cga3d_rotor_wedge_rotor(cga3d_Rotor lhs, cga3d_Rotor rhs);
+cga3d_Rotor cga3d_rotor_left_contraction_rotor(cga3d_Rotor lhs, cga3d_Rotor rhs);
+cga3d_Rotor cga3d_rotor_right_contraction_rotor(cga3d_Rotor lhs, cga3d_Rotor rhs);
+double cga3d_rotor_scalar_product_rotor(cga3d_Rotor lhs, cga3d_Rotor rhs);
+cga3d_PointPair cga3d_rotor_commutator_rotor(cga3d_Rotor lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_rotor_anti_dot_rotor(cga3d_Rotor lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_rotor_geometric_translator(cga3d_Rotor lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_rotor_anti_geometric_translator(cga3d_Rotor lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_rotor_dot_translator(cga3d_Rotor lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_rotor_wedge_translator(cga3d_Rotor lhs, cga3d_Translator rhs);
+cga3d_Translator cga3d_rotor_left_contraction_translator(cga3d_Rotor lhs, cga3d_Translator rhs);
+cga3d_Rotor cga3d_rotor_right_contraction_translator(cga3d_Rotor lhs, cga3d_Translator rhs);
+double cga3d_rotor_scalar_product_translator(cga3d_Rotor lhs, cga3d_Translator rhs);
+cga3d_FlatPoint cga3d_rotor_commutator_translator(cga3d_Rotor lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_rotor_anti_dot_translator(cga3d_Rotor lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_rotor_geometric_versor(cga3d_Rotor lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_rotor_anti_geometric_versor(cga3d_Rotor lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_rotor_dot_versor(cga3d_Rotor lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_rotor_wedge_versor(cga3d_Rotor lhs, cga3d_Versor rhs);
+cga3d_Vec3 cga3d_rotor_anti_wedge_versor(cga3d_Rotor lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_rotor_left_contraction_versor(cga3d_Rotor lhs, cga3d_Versor rhs);
+cga3d_Rotor cga3d_rotor_right_contraction_versor(cga3d_Rotor lhs, cga3d_Versor rhs);
+double cga3d_rotor_scalar_product_versor(cga3d_Rotor lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_rotor_commutator_versor(cga3d_Rotor lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_rotor_anti_dot_versor(cga3d_Rotor lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_rotor_geometric_multivector(cga3d_Rotor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_rotor_anti_geometric_multivector(cga3d_Rotor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_rotor_dot_multivector(cga3d_Rotor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_rotor_wedge_multivector(cga3d_Rotor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_rotor_anti_wedge_multivector(cga3d_Rotor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_rotor_left_contraction_multivector(cga3d_Rotor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_rotor_right_contraction_multivector(cga3d_Rotor lhs, cga3d_Multivector rhs);
+double cga3d_rotor_scalar_product_multivector(cga3d_Rotor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_rotor_commutator_multivector(cga3d_Rotor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_rotor_anti_dot_multivector(cga3d_Rotor lhs, cga3d_Multivector rhs);
+cga3d_Vec3 cga3d_rotor_sandwich_vec3(cga3d_Rotor lhs, cga3d_Vec3 rhs);
+cga3d_Vec3 cga3d_rotor_anti_sandwich_vec3(cga3d_Rotor lhs, cga3d_Vec3 rhs);
+cga3d_Point cga3d_rotor_sandwich_point(cga3d_Rotor lhs, cga3d_Point rhs);
+cga3d_Point cga3d_rotor_anti_sandwich_point(cga3d_Rotor lhs, cga3d_Point rhs);
+cga3d_FlatPoint cga3d_rotor_sandwich_flatpoint(cga3d_Rotor lhs, cga3d_FlatPoint rhs);
+cga3d_FlatPoint cga3d_rotor_anti_sandwich_flatpoint(cga3d_Rotor lhs, cga3d_FlatPoint rhs);
+cga3d_PointPair cga3d_rotor_sandwich_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_rotor_anti_sandwich_pointpair(cga3d_Rotor lhs, cga3d_PointPair rhs);
+cga3d_Line cga3d_rotor_sandwich_line(cga3d_Rotor lhs, cga3d_Line rhs);
+cga3d_Line cga3d_rotor_anti_sandwich_line(cga3d_Rotor lhs, cga3d_Line rhs);
+cga3d_Circle cga3d_rotor_sandwich_circle(cga3d_Rotor lhs, cga3d_Circle rhs);
+cga3d_Circle cga3d_rotor_anti_sandwich_circle(cga3d_Rotor lhs, cga3d_Circle rhs);
+cga3d_Plane cga3d_rotor_sandwich_plane(cga3d_Rotor lhs, cga3d_Plane rhs);
+cga3d_Plane cga3d_rotor_anti_sandwich_plane(cga3d_Rotor lhs, cga3d_Plane rhs);
+cga3d_Sphere cga3d_rotor_sandwich_sphere(cga3d_Rotor lhs, cga3d_Sphere rhs);
+cga3d_Sphere cga3d_rotor_anti_sandwich_sphere(cga3d_Rotor lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_translator_rcompl(cga3d_Translator value);
+cga3d_Multivector cga3d_translator_lcompl(cga3d_Translator value);
+cga3d_Translator cga3d_translator_rev(cga3d_Translator value);
+cga3d_Translator cga3d_translator_arev(cga3d_Translator value);
+cga3d_Translator cga3d_translator_involute(cga3d_Translator value);
+cga3d_Translator cga3d_translator_conjugate(cga3d_Translator value);
+cga3d_Multivector cga3d_translator_dual(cga3d_Translator value);
+cga3d_Multivector cga3d_translator_geometric_vec3(cga3d_Translator lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_translator_anti_geometric_vec3(cga3d_Translator lhs, cga3d_Vec3 rhs);
+cga3d_Point cga3d_translator_dot_vec3(cga3d_Translator lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_translator_wedge_vec3(cga3d_Translator lhs, cga3d_Vec3 rhs);
+cga3d_Vec3 cga3d_translator_left_contraction_vec3(cga3d_Translator lhs, cga3d_Vec3 rhs);
+double cga3d_translator_right_contraction_vec3(cga3d_Translator lhs, cga3d_Vec3 rhs);
+double cga3d_translator_commutator_vec3(cga3d_Translator lhs, cga3d_Vec3 rhs);
+cga3d_Plane cga3d_translator_anti_dot_vec3(cga3d_Translator lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_translator_geometric_point(cga3d_Translator lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_translator_anti_geometric_point(cga3d_Translator lhs, cga3d_Point rhs);
+cga3d_Point cga3d_translator_dot_point(cga3d_Translator lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_translator_wedge_point(cga3d_Translator lhs, cga3d_Point rhs);
+cga3d_Point cga3d_translator_left_contraction_point(cga3d_Translator lhs, cga3d_Point rhs);
+cga3d_Point cga3d_translator_right_contraction_point(cga3d_Translator lhs, cga3d_Point rhs);
+cga3d_Point cga3d_translator_commutator_point(cga3d_Translator lhs, cga3d_Point rhs);
+cga3d_Sphere cga3d_translator_anti_dot_point(cga3d_Translator lhs, cga3d_Point rhs);
+cga3d_FlatPoint cga3d_translator_geometric_flatpoint(cga3d_Translator lhs, cga3d_FlatPoint rhs);
+cga3d_Circle cga3d_translator_anti_geometric_flatpoint(cga3d_Translator lhs, cga3d_FlatPoint rhs);
+cga3d_FlatPoint cga3d_translator_dot_flatpoint(cga3d_Translator lhs, cga3d_FlatPoint rhs);
+cga3d_FlatPoint cga3d_translator_wedge_flatpoint(cga3d_Translator lhs, cga3d_FlatPoint rhs);
+cga3d_FlatPoint cga3d_translator_left_contraction_flatpoint(cga3d_Translator lhs, cga3d_FlatPoint rhs);
+cga3d_FlatPoint cga3d_translator_commutator_flatpoint(cga3d_Translator lhs, cga3d_FlatPoint rhs);
+cga3d_Circle cga3d_translator_anti_dot_flatpoint(cga3d_Translator lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_translator_geometric_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_translator_anti_geometric_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_translator_dot_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_translator_wedge_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_translator_left_contraction_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+double cga3d_translator_right_contraction_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+double cga3d_translator_scalar_product_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_translator_commutator_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_translator_anti_dot_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_translator_geometric_line(cga3d_Translator lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_translator_anti_geometric_line(cga3d_Translator lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_translator_dot_line(cga3d_Translator lhs, cga3d_Line rhs);
+cga3d_Line cga3d_translator_wedge_line(cga3d_Translator lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_translator_left_contraction_line(cga3d_Translator lhs, cga3d_Line rhs);
+cga3d_Line cga3d_translator_commutator_line(cga3d_Translator lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_translator_anti_dot_line(cga3d_Translator lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_translator_geometric_circle(cga3d_Translator lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_translator_anti_geometric_circle(cga3d_Translator lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_translator_dot_circle(cga3d_Translator lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_translator_wedge_circle(cga3d_Translator lhs, cga3d_Circle rhs);
+double cga3d_translator_anti_wedge_circle(cga3d_Translator lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_translator_left_contraction_circle(cga3d_Translator lhs, cga3d_Circle rhs);
+cga3d_Circle cga3d_translator_commutator_circle(cga3d_Translator lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_translator_anti_dot_circle(cga3d_Translator lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_translator_geometric_plane(cga3d_Translator lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_translator_anti_geometric_plane(cga3d_Translator lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_translator_dot_plane(cga3d_Translator lhs, cga3d_Plane rhs);
+cga3d_Plane cga3d_translator_wedge_plane(cga3d_Translator lhs, cga3d_Plane rhs);
+double cga3d_translator_anti_wedge_plane(cga3d_Translator lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_translator_left_contraction_plane(cga3d_Translator lhs, cga3d_Plane rhs);
+double cga3d_translator_commutator_plane(cga3d_Translator lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_translator_anti_dot_plane(cga3d_Translator lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_translator_geometric_sphere(cga3d_Translator lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_translator_anti_geometric_sphere(cga3d_Translator lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_translator_dot_sphere(cga3d_Translator lhs, cga3d_Sphere rhs);
+cga3d_Sphere cga3d_translator_wedge_sphere(cga3d_Translator lhs, cga3d_Sphere rhs);
+cga3d_Point cga3d_translator_anti_wedge_sphere(cga3d_Translator lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_translator_left_contraction_sphere(cga3d_Translator lhs, cga3d_Sphere rhs);
+cga3d_Plane cga3d_translator_commutator_sphere(cga3d_Translator lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_translator_anti_dot_sphere(cga3d_Translator lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_translator_geometric_rotor(cga3d_Translator lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_translator_anti_geometric_rotor(cga3d_Translator lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_translator_dot_rotor(cga3d_Translator lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_translator_wedge_rotor(cga3d_Translator lhs, cga3d_Rotor rhs);
+cga3d_Rotor cga3d_translator_left_contraction_rotor(cga3d_Translator lhs, cga3d_Rotor rhs);
+cga3d_Translator cga3d_translator_right_contraction_rotor(cga3d_Translator lhs, cga3d_Rotor rhs);
+double cga3d_translator_scalar_product_rotor(cga3d_Translator lhs, cga3d_Rotor rhs);
+cga3d_FlatPoint cga3d_translator_commutator_rotor(cga3d_Translator lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_translator_anti_dot_rotor(cga3d_Translator lhs, cga3d_Rotor rhs);
+cga3d_Translator cga3d_translator_geometric_translator(cga3d_Translator lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_translator_anti_geometric_translator(cga3d_Translator lhs, cga3d_Translator rhs);
+cga3d_Translator cga3d_translator_dot_translator(cga3d_Translator lhs, cga3d_Translator rhs);
+cga3d_Translator cga3d_translator_wedge_translator(cga3d_Translator lhs, cga3d_Translator rhs);
+cga3d_Translator cga3d_translator_left_contraction_translator(cga3d_Translator lhs, cga3d_Translator rhs);
+cga3d_Translator cga3d_translator_right_contraction_translator(cga3d_Translator lhs, cga3d_Translator rhs);
+double cga3d_translator_scalar_product_translator(cga3d_Translator lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_translator_anti_dot_translator(cga3d_Translator lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_translator_geometric_versor(cga3d_Translator lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_translator_anti_geometric_versor(cga3d_Translator lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_translator_dot_versor(cga3d_Translator lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_translator_wedge_versor(cga3d_Translator lhs, cga3d_Versor rhs);
+cga3d_Point cga3d_translator_anti_wedge_versor(cga3d_Translator lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_translator_left_contraction_versor(cga3d_Translator lhs, cga3d_Versor rhs);
+cga3d_Translator cga3d_translator_right_contraction_versor(cga3d_Translator lhs, cga3d_Versor rhs);
+double cga3d_translator_scalar_product_versor(cga3d_Translator lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_translator_commutator_versor(cga3d_Translator lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_translator_anti_dot_versor(cga3d_Translator lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_translator_geometric_multivector(cga3d_Translator lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_translator_anti_geometric_multivector(cga3d_Translator lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_translator_dot_multivector(cga3d_Translator lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_translator_wedge_multivector(cga3d_Translator lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_translator_anti_wedge_multivector(cga3d_Translator lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_translator_left_contraction_multivector(cga3d_Translator lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_translator_right_contraction_multivector(cga3d_Translator lhs, cga3d_Multivector rhs);
+double cga3d_translator_scalar_product_multivector(cga3d_Translator lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_translator_commutator_multivector(cga3d_Translator lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_translator_anti_dot_multivector(cga3d_Translator lhs, cga3d_Multivector rhs);
+cga3d_Point cga3d_translator_sandwich_point(cga3d_Translator lhs, cga3d_Point rhs);
+cga3d_Point cga3d_translator_anti_sandwich_point(cga3d_Translator lhs, cga3d_Point rhs);
+cga3d_FlatPoint cga3d_translator_sandwich_flatpoint(cga3d_Translator lhs, cga3d_FlatPoint rhs);
+cga3d_FlatPoint cga3d_translator_anti_sandwich_flatpoint(cga3d_Translator lhs, cga3d_FlatPoint rhs);
+cga3d_PointPair cga3d_translator_sandwich_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_translator_anti_sandwich_pointpair(cga3d_Translator lhs, cga3d_PointPair rhs);
+cga3d_Line cga3d_translator_sandwich_line(cga3d_Translator lhs, cga3d_Line rhs);
+cga3d_Line cga3d_translator_anti_sandwich_line(cga3d_Translator lhs, cga3d_Line rhs);
+cga3d_Circle cga3d_translator_sandwich_circle(cga3d_Translator lhs, cga3d_Circle rhs);
+cga3d_Circle cga3d_translator_anti_sandwich_circle(cga3d_Translator lhs, cga3d_Circle rhs);
+cga3d_Plane cga3d_translator_sandwich_plane(cga3d_Translator lhs, cga3d_Plane rhs);
+cga3d_Plane cga3d_translator_anti_sandwich_plane(cga3d_Translator lhs, cga3d_Plane rhs);
+cga3d_Sphere cga3d_translator_sandwich_sphere(cga3d_Translator lhs, cga3d_Sphere rhs);
+cga3d_Sphere cga3d_translator_anti_sandwich_sphere(cga3d_Translator lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_versor_rcompl(cga3d_Versor value);
+cga3d_Multivector cga3d_versor_lcompl(cga3d_Versor value);
+cga3d_Versor cga3d_versor_rev(cga3d_Versor value);
+cga3d_Versor cga3d_versor_arev(cga3d_Versor value);
+cga3d_Versor cga3d_versor_involute(cga3d_Versor value);
+cga3d_Versor cga3d_versor_conjugate(cga3d_Versor value);
+cga3d_Multivector cga3d_versor_dual(cga3d_Versor value);
+cga3d_Multivector cga3d_versor_geometric_vec3(cga3d_Versor lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_versor_anti_geometric_vec3(cga3d_Versor lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_versor_dot_vec3(cga3d_Versor lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_versor_wedge_vec3(cga3d_Versor lhs, cga3d_Vec3 rhs);
+double cga3d_versor_anti_wedge_vec3(cga3d_Versor lhs, cga3d_Vec3 rhs);
+cga3d_Vec3 cga3d_versor_left_contraction_vec3(cga3d_Versor lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_versor_right_contraction_vec3(cga3d_Versor lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_versor_commutator_vec3(cga3d_Versor lhs, cga3d_Vec3 rhs);
+cga3d_Versor cga3d_versor_anti_dot_vec3(cga3d_Versor lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_versor_geometric_point(cga3d_Versor lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_versor_anti_geometric_point(cga3d_Versor lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_versor_dot_point(cga3d_Versor lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_versor_wedge_point(cga3d_Versor lhs, cga3d_Point rhs);
+double cga3d_versor_anti_wedge_point(cga3d_Versor lhs, cga3d_Point rhs);
+cga3d_Point cga3d_versor_left_contraction_point(cga3d_Versor lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_versor_right_contraction_point(cga3d_Versor lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_versor_commutator_point(cga3d_Versor lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_versor_anti_dot_point(cga3d_Versor lhs, cga3d_Point rhs);
+cga3d_Versor cga3d_versor_geometric_flatpoint(cga3d_Versor lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_versor_anti_geometric_flatpoint(cga3d_Versor lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_versor_dot_flatpoint(cga3d_Versor lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_versor_wedge_flatpoint(cga3d_Versor lhs, cga3d_FlatPoint rhs);
+cga3d_Point cga3d_versor_anti_wedge_flatpoint(cga3d_Versor lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_versor_left_contraction_flatpoint(cga3d_Versor lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_versor_right_contraction_flatpoint(cga3d_Versor lhs, cga3d_FlatPoint rhs);
+double cga3d_versor_scalar_product_flatpoint(cga3d_Versor lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_versor_commutator_flatpoint(cga3d_Versor lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_versor_anti_dot_flatpoint(cga3d_Versor lhs, cga3d_FlatPoint rhs);
+cga3d_Versor cga3d_versor_geometric_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_versor_anti_geometric_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_versor_dot_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_versor_wedge_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_Point cga3d_versor_anti_wedge_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_versor_left_contraction_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_versor_right_contraction_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+double cga3d_versor_scalar_product_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_Versor cga3d_versor_commutator_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_versor_anti_dot_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_versor_geometric_line(cga3d_Versor lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_versor_anti_geometric_line(cga3d_Versor lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_versor_dot_line(cga3d_Versor lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_versor_wedge_line(cga3d_Versor lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_versor_anti_wedge_line(cga3d_Versor lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_versor_left_contraction_line(cga3d_Versor lhs, cga3d_Line rhs);
+cga3d_Point cga3d_versor_right_contraction_line(cga3d_Versor lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_versor_commutator_line(cga3d_Versor lhs, cga3d_Line rhs);
+cga3d_Versor cga3d_versor_anti_dot_line(cga3d_Versor lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_versor_geometric_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_versor_anti_geometric_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_versor_dot_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_versor_wedge_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_versor_anti_wedge_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_versor_left_contraction_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Point cga3d_versor_right_contraction_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_versor_commutator_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_versor_anti_dot_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Versor cga3d_versor_geometric_plane(cga3d_Versor lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_versor_anti_geometric_plane(cga3d_Versor lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_versor_dot_plane(cga3d_Versor lhs, cga3d_Plane rhs);
+cga3d_Plane cga3d_versor_wedge_plane(cga3d_Versor lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_versor_anti_wedge_plane(cga3d_Versor lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_versor_left_contraction_plane(cga3d_Versor lhs, cga3d_Plane rhs);
+double cga3d_versor_right_contraction_plane(cga3d_Versor lhs, cga3d_Plane rhs);
+double cga3d_versor_scalar_product_plane(cga3d_Versor lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_versor_commutator_plane(cga3d_Versor lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_versor_anti_dot_plane(cga3d_Versor lhs, cga3d_Plane rhs);
+cga3d_Versor cga3d_versor_geometric_sphere(cga3d_Versor lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_versor_anti_geometric_sphere(cga3d_Versor lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_versor_dot_sphere(cga3d_Versor lhs, cga3d_Sphere rhs);
+cga3d_Sphere cga3d_versor_wedge_sphere(cga3d_Versor lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_versor_anti_wedge_sphere(cga3d_Versor lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_versor_left_contraction_sphere(cga3d_Versor lhs, cga3d_Sphere rhs);
+double cga3d_versor_right_contraction_sphere(cga3d_Versor lhs, cga3d_Sphere rhs);
+double cga3d_versor_scalar_product_sphere(cga3d_Versor lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_versor_commutator_sphere(cga3d_Versor lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_versor_anti_dot_sphere(cga3d_Versor lhs, cga3d_Sphere rhs);
+cga3d_Versor cga3d_versor_geometric_rotor(cga3d_Versor lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_versor_anti_geometric_rotor(cga3d_Versor lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_versor_dot_rotor(cga3d_Versor lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_versor_wedge_rotor(cga3d_Versor lhs, cga3d_Rotor rhs);
+cga3d_Vec3 cga3d_versor_anti_wedge_rotor(cga3d_Versor lhs, cga3d_Rotor rhs);
+cga3d_Rotor cga3d_versor_left_contraction_rotor(cga3d_Versor lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_versor_right_contraction_rotor(cga3d_Versor lhs, cga3d_Rotor rhs);
+double cga3d_versor_scalar_product_rotor(cga3d_Versor lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_versor_commutator_rotor(cga3d_Versor lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_versor_anti_dot_rotor(cga3d_Versor lhs, cga3d_Rotor rhs);
+cga3d_Versor cga3d_versor_geometric_translator(cga3d_Versor lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_versor_anti_geometric_translator(cga3d_Versor lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_versor_dot_translator(cga3d_Versor lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_versor_wedge_translator(cga3d_Versor lhs, cga3d_Translator rhs);
+cga3d_Point cga3d_versor_anti_wedge_translator(cga3d_Versor lhs, cga3d_Translator rhs);
+cga3d_Translator cga3d_versor_left_contraction_translator(cga3d_Versor lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_versor_right_contraction_translator(cga3d_Versor lhs, cga3d_Translator rhs);
+double cga3d_versor_scalar_product_translator(cga3d_Versor lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_versor_commutator_translator(cga3d_Versor lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_versor_anti_dot_translator(cga3d_Versor lhs, cga3d_Translator rhs);
+cga3d_Versor cga3d_versor_geometric_versor(cga3d_Versor lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_versor_anti_geometric_versor(cga3d_Versor lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_versor_dot_versor(cga3d_Versor lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_versor_wedge_versor(cga3d_Versor lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_versor_anti_wedge_versor(cga3d_Versor lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_versor_left_contraction_versor(cga3d_Versor lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_versor_right_contraction_versor(cga3d_Versor lhs, cga3d_Versor rhs);
+double cga3d_versor_scalar_product_versor(cga3d_Versor lhs, cga3d_Versor rhs);
+cga3d_Versor cga3d_versor_commutator_versor(cga3d_Versor lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_versor_anti_dot_versor(cga3d_Versor lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_versor_geometric_multivector(cga3d_Versor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_versor_anti_geometric_multivector(cga3d_Versor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_versor_dot_multivector(cga3d_Versor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_versor_wedge_multivector(cga3d_Versor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_versor_anti_wedge_multivector(cga3d_Versor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_versor_left_contraction_multivector(cga3d_Versor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_versor_right_contraction_multivector(cga3d_Versor lhs, cga3d_Multivector rhs);
+double cga3d_versor_scalar_product_multivector(cga3d_Versor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_versor_commutator_multivector(cga3d_Versor lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_versor_anti_dot_multivector(cga3d_Versor lhs, cga3d_Multivector rhs);
+cga3d_PointPair cga3d_versor_sandwich_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_PointPair cga3d_versor_anti_sandwich_pointpair(cga3d_Versor lhs, cga3d_PointPair rhs);
+cga3d_Circle cga3d_versor_sandwich_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Circle cga3d_versor_anti_sandwich_circle(cga3d_Versor lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_multivector_rcompl(cga3d_Multivector value);
+cga3d_Multivector cga3d_multivector_lcompl(cga3d_Multivector value);
+cga3d_Multivector cga3d_multivector_rev(cga3d_Multivector value);
+cga3d_Multivector cga3d_multivector_arev(cga3d_Multivector value);
+cga3d_Multivector cga3d_multivector_involute(cga3d_Multivector value);
+cga3d_Multivector cga3d_multivector_conjugate(cga3d_Multivector value);
+cga3d_Multivector cga3d_multivector_dual(cga3d_Multivector value);
+cga3d_Multivector cga3d_multivector_geometric_vec3(cga3d_Multivector lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_vec3(cga3d_Multivector lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_multivector_dot_vec3(cga3d_Multivector lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_multivector_wedge_vec3(cga3d_Multivector lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_vec3(cga3d_Multivector lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_vec3(cga3d_Multivector lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_vec3(cga3d_Multivector lhs, cga3d_Vec3 rhs);
+double cga3d_multivector_scalar_product_vec3(cga3d_Multivector lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_multivector_commutator_vec3(cga3d_Multivector lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_vec3(cga3d_Multivector lhs, cga3d_Vec3 rhs);
+cga3d_Multivector cga3d_multivector_geometric_point(cga3d_Multivector lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_point(cga3d_Multivector lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_multivector_dot_point(cga3d_Multivector lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_multivector_wedge_point(cga3d_Multivector lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_point(cga3d_Multivector lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_point(cga3d_Multivector lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_point(cga3d_Multivector lhs, cga3d_Point rhs);
+double cga3d_multivector_scalar_product_point(cga3d_Multivector lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_multivector_commutator_point(cga3d_Multivector lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_point(cga3d_Multivector lhs, cga3d_Point rhs);
+cga3d_Multivector cga3d_multivector_geometric_flatpoint(cga3d_Multivector lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_flatpoint(cga3d_Multivector lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_multivector_dot_flatpoint(cga3d_Multivector lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_multivector_wedge_flatpoint(cga3d_Multivector lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_flatpoint(cga3d_Multivector lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_flatpoint(cga3d_Multivector lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_flatpoint(cga3d_Multivector lhs, cga3d_FlatPoint rhs);
+double cga3d_multivector_scalar_product_flatpoint(cga3d_Multivector lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_multivector_commutator_flatpoint(cga3d_Multivector lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_flatpoint(cga3d_Multivector lhs, cga3d_FlatPoint rhs);
+cga3d_Multivector cga3d_multivector_geometric_pointpair(cga3d_Multivector lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_pointpair(cga3d_Multivector lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_multivector_dot_pointpair(cga3d_Multivector lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_multivector_wedge_pointpair(cga3d_Multivector lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_pointpair(cga3d_Multivector lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_pointpair(cga3d_Multivector lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_pointpair(cga3d_Multivector lhs, cga3d_PointPair rhs);
+double cga3d_multivector_scalar_product_pointpair(cga3d_Multivector lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_multivector_commutator_pointpair(cga3d_Multivector lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_pointpair(cga3d_Multivector lhs, cga3d_PointPair rhs);
+cga3d_Multivector cga3d_multivector_geometric_line(cga3d_Multivector lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_line(cga3d_Multivector lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_multivector_dot_line(cga3d_Multivector lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_multivector_wedge_line(cga3d_Multivector lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_line(cga3d_Multivector lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_line(cga3d_Multivector lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_line(cga3d_Multivector lhs, cga3d_Line rhs);
+double cga3d_multivector_scalar_product_line(cga3d_Multivector lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_multivector_commutator_line(cga3d_Multivector lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_line(cga3d_Multivector lhs, cga3d_Line rhs);
+cga3d_Multivector cga3d_multivector_geometric_circle(cga3d_Multivector lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_circle(cga3d_Multivector lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_multivector_dot_circle(cga3d_Multivector lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_multivector_wedge_circle(cga3d_Multivector lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_circle(cga3d_Multivector lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_circle(cga3d_Multivector lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_circle(cga3d_Multivector lhs, cga3d_Circle rhs);
+double cga3d_multivector_scalar_product_circle(cga3d_Multivector lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_multivector_commutator_circle(cga3d_Multivector lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_circle(cga3d_Multivector lhs, cga3d_Circle rhs);
+cga3d_Multivector cga3d_multivector_geometric_plane(cga3d_Multivector lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_plane(cga3d_Multivector lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_multivector_dot_plane(cga3d_Multivector lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_multivector_wedge_plane(cga3d_Multivector lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_plane(cga3d_Multivector lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_plane(cga3d_Multivector lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_plane(cga3d_Multivector lhs, cga3d_Plane rhs);
+double cga3d_multivector_scalar_product_plane(cga3d_Multivector lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_multivector_commutator_plane(cga3d_Multivector lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_plane(cga3d_Multivector lhs, cga3d_Plane rhs);
+cga3d_Multivector cga3d_multivector_geometric_sphere(cga3d_Multivector lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_sphere(cga3d_Multivector lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_multivector_dot_sphere(cga3d_Multivector lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_multivector_wedge_sphere(cga3d_Multivector lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_sphere(cga3d_Multivector lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_sphere(cga3d_Multivector lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_sphere(cga3d_Multivector lhs, cga3d_Sphere rhs);
+double cga3d_multivector_scalar_product_sphere(cga3d_Multivector lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_multivector_commutator_sphere(cga3d_Multivector lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_sphere(cga3d_Multivector lhs, cga3d_Sphere rhs);
+cga3d_Multivector cga3d_multivector_geometric_rotor(cga3d_Multivector lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_rotor(cga3d_Multivector lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_multivector_dot_rotor(cga3d_Multivector lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_multivector_wedge_rotor(cga3d_Multivector lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_rotor(cga3d_Multivector lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_rotor(cga3d_Multivector lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_rotor(cga3d_Multivector lhs, cga3d_Rotor rhs);
+double cga3d_multivector_scalar_product_rotor(cga3d_Multivector lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_multivector_commutator_rotor(cga3d_Multivector lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_rotor(cga3d_Multivector lhs, cga3d_Rotor rhs);
+cga3d_Multivector cga3d_multivector_geometric_translator(cga3d_Multivector lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_translator(cga3d_Multivector lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_multivector_dot_translator(cga3d_Multivector lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_multivector_wedge_translator(cga3d_Multivector lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_translator(cga3d_Multivector lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_translator(cga3d_Multivector lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_translator(cga3d_Multivector lhs, cga3d_Translator rhs);
+double cga3d_multivector_scalar_product_translator(cga3d_Multivector lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_multivector_commutator_translator(cga3d_Multivector lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_translator(cga3d_Multivector lhs, cga3d_Translator rhs);
+cga3d_Multivector cga3d_multivector_geometric_versor(cga3d_Multivector lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_versor(cga3d_Multivector lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_multivector_dot_versor(cga3d_Multivector lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_multivector_wedge_versor(cga3d_Multivector lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_versor(cga3d_Multivector lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_versor(cga3d_Multivector lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_versor(cga3d_Multivector lhs, cga3d_Versor rhs);
+double cga3d_multivector_scalar_product_versor(cga3d_Multivector lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_multivector_commutator_versor(cga3d_Multivector lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_versor(cga3d_Multivector lhs, cga3d_Versor rhs);
+cga3d_Multivector cga3d_multivector_geometric_multivector(cga3d_Multivector lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_multivector_anti_geometric_multivector(cga3d_Multivector lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_multivector_dot_multivector(cga3d_Multivector lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_multivector_wedge_multivector(cga3d_Multivector lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_multivector_anti_wedge_multivector(cga3d_Multivector lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_multivector_left_contraction_multivector(cga3d_Multivector lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_multivector_right_contraction_multivector(cga3d_Multivector lhs, cga3d_Multivector rhs);
+double cga3d_multivector_scalar_product_multivector(cga3d_Multivector lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_multivector_commutator_multivector(cga3d_Multivector lhs, cga3d_Multivector rhs);
+cga3d_Multivector cga3d_multivector_anti_dot_multivector(cga3d_Multivector lhs, cga3d_Multivector rhs);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CGA3D_H */
@@ -0,0 +1,6922 @@
+//! # Circle
+//!
+//! ## Operations
+//! ```text
+//! Circle.geometric(Circle) -> Versor
+//! Circle.anti_geometric(Circle) -> Multivector
+//! Circle.dot(Circle) -> S
+//! Circle.anti_wedge(Circle) -> Point
+//! Circle.left_contraction(Circle) -> S
+//! Circle.right_contraction(Circle) -> S
+//! Circle.scalar_product(Circle) -> S
+//! Circle.commutator(Circle) -> PointPair
+//! Circle.anti_dot(Circle) -> E123oi
+//! Circle.geometric(Vec3) -> Versor
+//! Vec3.geometric(Circle) -> Versor
+//! Circle.anti_geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Circle) -> Multivector
+//! Circle.dot(Vec3) -> PointPair
+//! Vec3.dot(Circle) -> PointPair
+//! Circle.wedge(Vec3) -> Sphere
+//! Vec3.wedge(Circle) -> Sphere
+//! Vec3.left_contraction(Circle) -> PointPair
+//! Circle.right_contraction(Vec3) -> PointPair
+//! Circle.commutator(Vec3) -> Sphere
+//! Vec3.commutator(Circle) -> Sphere
+//! Circle.anti_dot(Vec3) -> Circle
+//! Vec3.anti_dot(Circle) -> Circle
+//! Circle.geometric(Point) -> Versor
+//! Point.geometric(Circle) -> Versor
+//! Circle.anti_geometric(Point) -> Multivector
+//! Point.anti_geometric(Circle) -> Multivector
+//! Circle.dot(Point) -> PointPair
+//! Point.dot(Circle) -> PointPair
+//! Circle.wedge(Point) -> Sphere
+//! Point.wedge(Circle) -> Sphere
+//! Point.left_contraction(Circle) -> PointPair
+//! Circle.right_contraction(Point) -> PointPair
+//! Circle.commutator(Point) -> Sphere
+//! Point.commutator(Circle) -> Sphere
+//! Circle.anti_dot(Point) -> Circle
+//! Point.anti_dot(Circle) -> Circle
+//! Circle.geometric(FlatPoint) -> Multivector
+//! FlatPoint.geometric(Circle) -> Multivector
+//! Circle.anti_geometric(FlatPoint) -> Versor
+//! FlatPoint.anti_geometric(Circle) -> Versor
+//! Circle.dot(FlatPoint) -> Point
+//! FlatPoint.dot(Circle) -> Point
+//! Circle.wedge(FlatPoint) -> E123oi
+//! FlatPoint.wedge(Circle) -> E123oi
+//! Circle.anti_wedge(FlatPoint) -> S
+//! FlatPoint.anti_wedge(Circle) -> S
+//! FlatPoint.left_contraction(Circle) -> Point
+//! Circle.right_contraction(FlatPoint) -> Point
+//! Circle.commutator(FlatPoint) -> Circle
+//! FlatPoint.commutator(Circle) -> Circle
+//! Circle.anti_dot(FlatPoint) -> Plane
+//! FlatPoint.anti_dot(Circle) -> Plane
+//! Circle.geometric(PointPair) -> Multivector
+//! PointPair.geometric(Circle) -> Multivector
+//! Circle.anti_geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(Circle) -> Versor
+//! Circle.dot(PointPair) -> Point
+//! PointPair.dot(Circle) -> Point
+//! Circle.wedge(PointPair) -> E123oi
+//! PointPair.wedge(Circle) -> E123oi
+//! Circle.anti_wedge(PointPair) -> S
+//! PointPair.anti_wedge(Circle) -> S
+//! PointPair.left_contraction(Circle) -> Point
+//! Circle.right_contraction(PointPair) -> Point
+//! Circle.commutator(PointPair) -> Circle
+//! PointPair.commutator(Circle) -> Circle
+//! Circle.anti_dot(PointPair) -> Sphere
+//! PointPair.anti_dot(Circle) -> Sphere
+//! Circle.geometric(Line) -> Versor
+//! Line.geometric(Circle) -> Versor
+//! Circle.anti_geometric(Line) -> Multivector
+//! Line.anti_geometric(Circle) -> Multivector
+//! Circle.dot(Line) -> S
+//! Line.dot(Circle) -> S
+//! Circle.anti_wedge(Line) -> Point
+//! Line.anti_wedge(Circle) -> Point
+//! Circle.left_contraction(Line) -> S
+//! Line.left_contraction(Circle) -> S
+//! Circle.right_contraction(Line) -> S
+//! Line.right_contraction(Circle) -> S
+//! Circle.scalar_product(Line) -> S
+//! Line.scalar_product(Circle) -> S
+//! Circle.commutator(Line) -> PointPair
+//! Line.commutator(Circle) -> PointPair
+//! Circle.anti_dot(Line) -> E123oi
+//! Line.anti_dot(Circle) -> E123oi
+//! Circle.geometric(Plane) -> Multivector
+//! Plane.geometric(Circle) -> Multivector
+//! Circle.anti_geometric(Plane) -> Versor
+//! Plane.anti_geometric(Circle) -> Versor
+//! Circle.dot(Plane) -> Point
+//! Plane.dot(Circle) -> Point
+//! Circle.anti_wedge(Plane) -> PointPair
+//! Plane.anti_wedge(Circle) -> PointPair
+//! Circle.left_contraction(Plane) -> Point
+//! Plane.right_contraction(Circle) -> Point
+//! Circle.commutator(Plane) -> Point
+//! Plane.commutator(Circle) -> Point
+//! Circle.anti_dot(Plane) -> Sphere
+//! Plane.anti_dot(Circle) -> Sphere
+//! Circle.geometric(Sphere) -> Multivector
+//! Sphere.geometric(Circle) -> Multivector
+//! Circle.anti_geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(Circle) -> Versor
+//! Circle.dot(Sphere) -> Point
+//! Sphere.dot(Circle) -> Point
+//! Circle.anti_wedge(Sphere) -> PointPair
+//! Sphere.anti_wedge(Circle) -> PointPair
+//! Circle.left_contraction(Sphere) -> Point
+//! Sphere.right_contraction(Circle) -> Point
+//! Circle.commutator(Sphere) -> Point
+//! Sphere.commutator(Circle) -> Point
+//! Circle.anti_dot(Sphere) -> Sphere
+//! Sphere.anti_dot(Circle) -> Sphere
+//! Circle.geometric(Rotor) -> Multivector
+//! Rotor.geometric(Circle) -> Multivector
+//! Circle.anti_geometric(Rotor) -> Versor
+//! Rotor.anti_geometric(Circle) -> Versor
+//! Circle.dot(Rotor) -> Multivector
+//! Rotor.dot(Circle) -> Multivector
+//! Circle.wedge(Rotor) -> Multivector
+//! Rotor.wedge(Circle) -> Multivector
+//! Circle.anti_wedge(Rotor) -> S
+//! Rotor.anti_wedge(Circle) -> S
+//! Rotor.left_contraction(Circle) -> Multivector
+//! Circle.right_contraction(Rotor) -> Multivector
+//! Circle.commutator(Rotor) -> Circle
+//! Rotor.commutator(Circle) -> Circle
+//! Circle.anti_dot(Rotor) -> Versor
+//! Rotor.anti_dot(Circle) -> Versor
+//! Circle.geometric(Translator) -> Multivector
+//! Translator.geometric(Circle) -> Multivector
+//! Circle.anti_geometric(Translator) -> Versor
+//! Translator.anti_geometric(Circle) -> Versor
+//! Circle.dot(Translator) -> Multivector
+//! Translator.dot(Circle) -> Multivector
+//! Circle.wedge(Translator) -> Multivector
+//! Translator.wedge(Circle) -> Multivector
+//! Circle.anti_wedge(Translator) -> S
+//! Translator.anti_wedge(Circle) -> S
+//! Translator.left_contraction(Circle) -> Multivector
+//! Circle.right_contraction(Translator) -> Multivector
+//! Circle.commutator(Translator) -> Circle
+//! Translator.commutator(Circle) -> Circle
+//! Circle.anti_dot(Translator) -> Versor
+//! Translator.anti_dot(Circle) -> Versor
+//! Circle.geometric(Versor) -> Multivector
+//! Versor.geometric(Circle) -> Multivector
+//! Circle.anti_geometric(Versor) -> Versor
+//! Versor.anti_geometric(Circle) -> Versor
+//! Circle.dot(Versor) -> Multivector
+//! Versor.dot(Circle) -> Multivector
+//! Circle.wedge(Versor) -> Multivector
+//! Versor.wedge(Circle) -> Multivector
+//! Circle.anti_wedge(Versor) -> Versor
+//! Versor.anti_wedge(Circle) -> Versor
+//! Circle.left_contraction(Versor) -> Point
+//! Versor.left_contraction(Circle) -> Multivector
+//! Circle.right_contraction(Versor) -> Multivector
+//! Versor.right_contraction(Circle) -> Point
+//! Circle.commutator(Versor) -> Multivector
+//! Versor.commutator(Circle) -> Multivector
+//! Circle.anti_dot(Versor) -> Versor
+//! Versor.anti_dot(Circle) -> Versor
+//! Circle.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Circle) -> Multivector
+//! Circle.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Circle) -> Multivector
+//! Circle.dot(Multivector) -> Multivector
+//! Multivector.dot(Circle) -> Multivector
+//! Circle.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Circle) -> Multivector
+//! Circle.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Circle) -> Multivector
+//! Circle.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Circle) -> Multivector
+//! Circle.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Circle) -> Multivector
+//! Circle.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Circle) -> S
+//! Circle.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Circle) -> Multivector
+//! Circle.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Circle) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Circle {
+	pub e123: E123,
+	pub e12o: E12o,
+	pub e12i: E12i,
+	pub e13o: E13o,
+	pub e13i: E13i,
+	pub e1oi: E1oi,
+	pub e23o: E23o,
+	pub e23i: E23i,
+	pub e2oi: E2oi,
+	pub e3oi: E3oi,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Circle {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Circle {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Circle {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.e123.abs_diff_eq(&other.e123, epsilon)
+			&& self.e12o.abs_diff_eq(&other.e12o, epsilon)
+			&& self.e12i.abs_diff_eq(&other.e12i, epsilon)
+			&& self.e13o.abs_diff_eq(&other.e13o, epsilon)
+			&& self.e13i.abs_diff_eq(&other.e13i, epsilon)
+			&& self.e1oi.abs_diff_eq(&other.e1oi, epsilon)
+			&& self.e23o.abs_diff_eq(&other.e23o, epsilon)
+			&& self.e23i.abs_diff_eq(&other.e23i, epsilon)
+			&& self.e2oi.abs_diff_eq(&other.e2oi, epsilon)
+			&& self.e3oi.abs_diff_eq(&other.e3oi, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Circle {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.e123.relative_eq(&other.e123, epsilon, max_relative)
+			&& self.e12o.relative_eq(&other.e12o, epsilon, max_relative)
+			&& self.e12i.relative_eq(&other.e12i, epsilon, max_relative)
+			&& self.e13o.relative_eq(&other.e13o, epsilon, max_relative)
+			&& self.e13i.relative_eq(&other.e13i, epsilon, max_relative)
+			&& self.e1oi.relative_eq(&other.e1oi, epsilon, max_relative)
+			&& self.e23o.relative_eq(&other.e23o, epsilon, max_relative)
+			&& self.e23i.relative_eq(&other.e23i, epsilon, max_relative)
+			&& self.e2oi.relative_eq(&other.e2oi, epsilon, max_relative)
+			&& self.e3oi.relative_eq(&other.e3oi, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Circle {
+	type Output = PointPair;
+	fn rcompl(self) -> Self::Output {
+		PointPair {
+			e12: self.e3oi.rcompl(),
+			e13: self.e2oi.rcompl(),
+			e1o: self.e23i.rcompl(),
+			e1i: self.e23o.rcompl(),
+			e23: self.e1oi.rcompl(),
+			e2o: self.e13i.rcompl(),
+			e2i: self.e13o.rcompl(),
+			e3o: self.e12i.rcompl(),
+			e3i: self.e12o.rcompl(),
+			eoi: self.e123.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Circle {
+	type Output = PointPair;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Circle {
+	type Output = PointPair;
+	fn lcompl(self) -> Self::Output {
+		PointPair {
+			e12: self.e3oi.lcompl(),
+			e13: self.e2oi.lcompl(),
+			e1o: self.e23i.lcompl(),
+			e1i: self.e23o.lcompl(),
+			e23: self.e1oi.lcompl(),
+			e2o: self.e13i.lcompl(),
+			e2i: self.e13o.lcompl(),
+			e3o: self.e12i.lcompl(),
+			e3i: self.e12o.lcompl(),
+			eoi: self.e123.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Circle {
+	fn rev(self) -> Self {
+		Circle {
+			e123: -self.e123,
+			e12o: -self.e12o,
+			e12i: -self.e12i,
+			e13o: -self.e13o,
+			e13i: -self.e13i,
+			e1oi: -self.e1oi,
+			e23o: -self.e23o,
+			e23i: -self.e23i,
+			e2oi: -self.e2oi,
+			e3oi: -self.e3oi,
+		}
+	}
+}
+
+impl AntiReverse for Circle {
+	fn arev(self) -> Self {
+		Circle {
+			e123: -self.e123,
+			e12o: -self.e12o,
+			e12i: -self.e12i,
+			e13o: -self.e13o,
+			e13i: -self.e13i,
+			e1oi: -self.e1oi,
+			e23o: -self.e23o,
+			e23i: -self.e23i,
+			e2oi: -self.e2oi,
+			e3oi: -self.e3oi,
+		}
+	}
+}
+
+impl Involute for Circle {
+	fn involute(self) -> Self {
+		Circle {
+			e123: -self.e123,
+			e12o: -self.e12o,
+			e12i: -self.e12i,
+			e13o: -self.e13o,
+			e13i: -self.e13i,
+			e1oi: -self.e1oi,
+			e23o: -self.e23o,
+			e23i: -self.e23i,
+			e2oi: -self.e2oi,
+			e3oi: -self.e3oi,
+		}
+	}
+}
+
+impl Conjugate for Circle {
+	fn conjugate(self) -> Self {
+		Circle {
+			e123: self.e123,
+			e12o: self.e12o,
+			e12i: self.e12i,
+			e13o: self.e13o,
+			e13i: self.e13i,
+			e1oi: self.e1oi,
+			e23o: self.e23o,
+			e23i: self.e23i,
+			e2oi: self.e2oi,
+			e3oi: self.e3oi,
+		}
+	}
+}
+
+impl Dual for Circle {
+	type Output = PointPair;
+	fn dual(self) -> Self::Output {
+		PointPair {
+			e12: self.e3oi.dual(),
+			e13: self.e2oi.dual(),
+			e1o: self.e23i.dual(),
+			e1i: self.e23o.dual(),
+			e23: self.e1oi.dual(),
+			e2o: self.e13i.dual(),
+			e2i: self.e13o.dual(),
+			e3o: self.e12i.dual(),
+			e3i: self.e12o.dual(),
+			eoi: self.e123.dual(),
+		}
+	}
+}
+
+// Omitted: Circle.square()  (not a scalar)
+// Omitted: Norm for Circle  (the bulk and weight need a diagonal metric)
+
+impl Circle {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 10] {
+		[
+			self.e123.0,
+			self.e12o.0,
+			self.e12i.0,
+			self.e13o.0,
+			self.e13i.0,
+			self.e1oi.0,
+			self.e23o.0,
+			self.e23i.0,
+			self.e2oi.0,
+			self.e3oi.0,
+		]
+	}
+}
+
+impl From<[f64; 10]> for Circle {
+	fn from(a: [f64; 10]) -> Self {
+		Circle {
+			e123: E123(a[0]),
+			e12o: E12o(a[1]),
+			e12i: E12i(a[2]),
+			e13o: E13o(a[3]),
+			e13i: E13i(a[4]),
+			e1oi: E1oi(a[5]),
+			e23o: E23o(a[6]),
+			e23i: E23i(a[7]),
+			e2oi: E2oi(a[8]),
+			e3oi: E3oi(a[9]),
+		}
+	}
+}
+
+impl From<Circle> for [f64; 10] {
+	fn from(v: Circle) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Circle> for Multivector {
+	fn from(v: Circle) -> Self {
+		Multivector {
+			e123: v.e123,
+			e12o: v.e12o,
+			e12i: v.e12i,
+			e13o: v.e13o,
+			e13i: v.e13i,
+			e1oi: v.e1oi,
+			e23o: v.e23o,
+			e23i: v.e23i,
+			e2oi: v.e2oi,
+			e3oi: v.e3oi,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Circle {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Circle` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.eo.0 == 0.0
+			&& mv.ei.0 == 0.0
+			&& mv.e12.0 == 0.0
+			&& mv.e13.0 == 0.0
+			&& mv.e1o.0 == 0.0
+			&& mv.e1i.0 == 0.0
+			&& mv.e23.0 == 0.0
+			&& mv.e2o.0 == 0.0
+			&& mv.e2i.0 == 0.0
+			&& mv.e3o.0 == 0.0
+			&& mv.e3i.0 == 0.0
+			&& mv.eoi.0 == 0.0
+			&& mv.e123o.0 == 0.0
+			&& mv.e123i.0 == 0.0
+			&& mv.e12oi.0 == 0.0
+			&& mv.e13oi.0 == 0.0
+			&& mv.e23oi.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(Circle {
+				e123: mv.e123,
+				e12o: mv.e12o,
+				e12i: mv.e12i,
+				e13o: mv.e13o,
+				e13i: mv.e13i,
+				e1oi: mv.e1oi,
+				e23o: mv.e23o,
+				e23i: mv.e23i,
+				e2oi: mv.e2oi,
+				e3oi: mv.e3oi,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP Vec3:
+
+// Circle.geometric(Vec3) -> Versor
+impl Geometric<Vec3> for Circle {
+	type Output = Versor;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123.0 * rhs.z.0),
+		//     e13  : -E13(self.e123.0 * rhs.y.0),
+		//     e1o  : -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0),
+		//     e1i  : -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+		//     e23  : E23(self.e123.0 * rhs.x.0),
+		//     e2o  : E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+		//     e3o  : E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0),
+		//     e3i  : E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+		//     eoi  : Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		//     e123o: -E123o(self.e12o.0 * rhs.z.0) + E123o(self.e13o.0 * rhs.y.0) - E123o(self.e23o.0 * rhs.x.0),
+		//     e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123.0 * rhs.z.0),
+			e13: -E13(self.e123.0 * rhs.y.0),
+			e1o: -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0),
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+			e23: E23(self.e123.0 * rhs.x.0),
+			e2o: E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+			e3o: E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+			e123o: -E123o(self.e12o.0 * rhs.z.0) + E123o(self.e13o.0 * rhs.y.0) - E123o(self.e23o.0 * rhs.x.0),
+			e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+			e12oi: E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+			e13oi: E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+			e23oi: E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Circle {
+	type Output = Versor;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Circle {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e2oi.0 * rhs.z.0) + E1(self.e3oi.0 * rhs.y.0),
+		//     e2    : E2(self.e1oi.0 * rhs.z.0) - E2(self.e3oi.0 * rhs.x.0),
+		//     e3    : -E3(self.e1oi.0 * rhs.y.0) + E3(self.e2oi.0 * rhs.x.0),
+		//     eo    : -Eo(self.e12o.0 * rhs.z.0) + Eo(self.e13o.0 * rhs.y.0) - Eo(self.e23o.0 * rhs.x.0),
+		//     ei    : Ei(self.e12i.0 * rhs.z.0) - Ei(self.e13i.0 * rhs.y.0) + Ei(self.e23i.0 * rhs.x.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+		//     e12o  : E12o(self.e13o.0 * rhs.x.0) + E12o(self.e23o.0 * rhs.y.0),
+		//     e12i  : -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0),
+		//     e13o  : -E13o(self.e12o.0 * rhs.x.0) + E13o(self.e23o.0 * rhs.z.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0),
+		//     e1oi  : E1oi(self.e123.0 * rhs.x.0),
+		//     e23o  : -E23o(self.e12o.0 * rhs.y.0) - E23o(self.e13o.0 * rhs.z.0),
+		//     e23i  : E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0),
+		//     e2oi  : E2oi(self.e123.0 * rhs.y.0),
+		//     e3oi  : E3oi(self.e123.0 * rhs.z.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e2oi.0 * rhs.z.0) + E1(self.e3oi.0 * rhs.y.0),
+			e2: E2(self.e1oi.0 * rhs.z.0) - E2(self.e3oi.0 * rhs.x.0),
+			e3: -E3(self.e1oi.0 * rhs.y.0) + E3(self.e2oi.0 * rhs.x.0),
+			eo: -Eo(self.e12o.0 * rhs.z.0) + Eo(self.e13o.0 * rhs.y.0) - Eo(self.e23o.0 * rhs.x.0),
+			ei: Ei(self.e12i.0 * rhs.z.0) - Ei(self.e13i.0 * rhs.y.0) + Ei(self.e23i.0 * rhs.x.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+			e12o: E12o(self.e13o.0 * rhs.x.0) + E12o(self.e23o.0 * rhs.y.0),
+			e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0),
+			e13o: -E13o(self.e12o.0 * rhs.x.0) + E13o(self.e23o.0 * rhs.z.0),
+			e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0),
+			e1oi: E1oi(self.e123.0 * rhs.x.0),
+			e23o: -E23o(self.e12o.0 * rhs.y.0) - E23o(self.e13o.0 * rhs.z.0),
+			e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0),
+			e2oi: E2oi(self.e123.0 * rhs.y.0),
+			e3oi: E3oi(self.e123.0 * rhs.z.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Circle.dot(Vec3) -> PointPair
+impl Dot<Vec3> for Circle {
+	type Output = PointPair;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123.0 * rhs.z.0),
+		//     e13: -E13(self.e123.0 * rhs.y.0),
+		//     e1o: -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0),
+		//     e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+		//     e23: E23(self.e123.0 * rhs.x.0),
+		//     e2o: E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0),
+		//     e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+		//     e3o: E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0),
+		//     e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		// }
+		PointPair {
+			e12: E12(self.e123.0 * rhs.z.0),
+			e13: -E13(self.e123.0 * rhs.y.0),
+			e1o: -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0),
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+			e23: E23(self.e123.0 * rhs.x.0),
+			e2o: E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+			e3o: E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Circle {
+	type Output = PointPair;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Circle.wedge(Vec3) -> Sphere
+impl Wedge<Vec3> for Circle {
+	type Output = Sphere;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.e12o.0 * rhs.z.0) + E123o(self.e13o.0 * rhs.y.0) - E123o(self.e23o.0 * rhs.x.0),
+		//     e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Sphere {
+			e123o: self.e12o.wedge(rhs.z) + self.e13o.wedge(rhs.y) + self.e23o.wedge(rhs.x),
+			e123i: self.e12i.wedge(rhs.z) + self.e13i.wedge(rhs.y) + self.e23i.wedge(rhs.x),
+			e12oi: self.e1oi.wedge(rhs.y) + self.e2oi.wedge(rhs.x),
+			e13oi: self.e1oi.wedge(rhs.z) + self.e3oi.wedge(rhs.x),
+			e23oi: self.e2oi.wedge(rhs.z) + self.e3oi.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Circle {
+	type Output = Sphere;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for Circle {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Circle {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for Circle {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.right_contraction(Vec3) -> PointPair
+impl RightContraction<Vec3> for Circle {
+	type Output = PointPair;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123.0 * rhs.z.0),
+		//     e13: -E13(self.e123.0 * rhs.y.0),
+		//     e1o: -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0),
+		//     e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+		//     e23: E23(self.e123.0 * rhs.x.0),
+		//     e2o: E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0),
+		//     e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+		//     e3o: E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0),
+		//     e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		// }
+		PointPair {
+			e12: E12(self.e123.0 * rhs.z.0),
+			e13: -E13(self.e123.0 * rhs.y.0),
+			e1o: -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0),
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+			e23: E23(self.e123.0 * rhs.x.0),
+			e2o: E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+			e3o: E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Circle {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.commutator(Vec3) -> Sphere
+impl Commutator<Vec3> for Circle {
+	type Output = Sphere;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.e12o.0 * rhs.z.0) + E123o(self.e13o.0 * rhs.y.0) - E123o(self.e23o.0 * rhs.x.0),
+		//     e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.e12o.0 * rhs.z.0) + E123o(self.e13o.0 * rhs.y.0) - E123o(self.e23o.0 * rhs.x.0),
+			e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+			e12oi: E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+			e13oi: E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+			e23oi: E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		}
+	}
+}
+
+// Circle.anti_dot(Vec3) -> Circle
+impl AntiDot<Vec3> for Circle {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+		//     e12o: E12o(self.e13o.0 * rhs.x.0) + E12o(self.e23o.0 * rhs.y.0),
+		//     e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0),
+		//     e13o: -E13o(self.e12o.0 * rhs.x.0) + E13o(self.e23o.0 * rhs.z.0),
+		//     e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0),
+		//     e1oi: E1oi(self.e123.0 * rhs.x.0),
+		//     e23o: -E23o(self.e12o.0 * rhs.y.0) - E23o(self.e13o.0 * rhs.z.0),
+		//     e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0),
+		//     e2oi: E2oi(self.e123.0 * rhs.y.0),
+		//     e3oi: E3oi(self.e123.0 * rhs.z.0),
+		// }
+		Circle {
+			e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+			e12o: E12o(self.e13o.0 * rhs.x.0) + E12o(self.e23o.0 * rhs.y.0),
+			e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0),
+			e13o: -E13o(self.e12o.0 * rhs.x.0) + E13o(self.e23o.0 * rhs.z.0),
+			e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0),
+			e1oi: E1oi(self.e123.0 * rhs.x.0),
+			e23o: -E23o(self.e12o.0 * rhs.y.0) - E23o(self.e13o.0 * rhs.z.0),
+			e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0),
+			e2oi: E2oi(self.e123.0 * rhs.y.0),
+			e3oi: E3oi(self.e123.0 * rhs.z.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP Point:
+
+// Circle.geometric(Point) -> Versor
+impl Geometric<Point> for Circle {
+	type Output = Versor;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123.0 * rhs.z.0) - E12(self.e12i.0 * rhs.o.0) - E12(self.e12o.0 * rhs.inf.0),
+		//     e13  : -E13(self.e123.0 * rhs.y.0) - E13(self.e13i.0 * rhs.o.0) - E13(self.e13o.0 * rhs.inf.0),
+		//     e1o  : -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0) - E1o(self.e1oi.0 * rhs.o.0),
+		//     e1i  : -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+		//     e23  : E23(self.e123.0 * rhs.x.0) - E23(self.e23i.0 * rhs.o.0) - E23(self.e23o.0 * rhs.inf.0),
+		//     e2o  : E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0) - E2o(self.e2oi.0 * rhs.o.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+		//     e3o  : E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0) - E3o(self.e3oi.0 * rhs.o.0),
+		//     e3i  : E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+		//     eoi  : Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		//     e123o: E123o(self.e123.0 * rhs.o.0) - E123o(self.e12o.0 * rhs.z.0) + E123o(self.e13o.0 * rhs.y.0) - E123o(self.e23o.0 * rhs.x.0),
+		//     e123i: E123i(self.e123.0 * rhs.inf.0) - E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.o.0) + E12oi(self.e12o.0 * rhs.inf.0) + E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: -E13oi(self.e13i.0 * rhs.o.0) + E13oi(self.e13o.0 * rhs.inf.0) + E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: -E23oi(self.e23i.0 * rhs.o.0) + E23oi(self.e23o.0 * rhs.inf.0) + E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123.0 * rhs.z.0) - E12(self.e12i.0 * rhs.o.0) - E12(self.e12o.0 * rhs.inf.0),
+			e13: -E13(self.e123.0 * rhs.y.0) - E13(self.e13i.0 * rhs.o.0) - E13(self.e13o.0 * rhs.inf.0),
+			e1o: -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0) - E1o(self.e1oi.0 * rhs.o.0),
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+			e23: E23(self.e123.0 * rhs.x.0) - E23(self.e23i.0 * rhs.o.0) - E23(self.e23o.0 * rhs.inf.0),
+			e2o: E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0) - E2o(self.e2oi.0 * rhs.o.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+			e3o: E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0) - E3o(self.e3oi.0 * rhs.o.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+			e123o: E123o(self.e123.0 * rhs.o.0) - E123o(self.e12o.0 * rhs.z.0) + E123o(self.e13o.0 * rhs.y.0)
+				- E123o(self.e23o.0 * rhs.x.0),
+			e123i: E123i(self.e123.0 * rhs.inf.0) - E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0)
+				- E123i(self.e23i.0 * rhs.x.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.o.0) + E12oi(self.e12o.0 * rhs.inf.0) + E12oi(self.e1oi.0 * rhs.y.0)
+				- E12oi(self.e2oi.0 * rhs.x.0),
+			e13oi: -E13oi(self.e13i.0 * rhs.o.0) + E13oi(self.e13o.0 * rhs.inf.0) + E13oi(self.e1oi.0 * rhs.z.0)
+				- E13oi(self.e3oi.0 * rhs.x.0),
+			e23oi: -E23oi(self.e23i.0 * rhs.o.0) + E23oi(self.e23o.0 * rhs.inf.0) + E23oi(self.e2oi.0 * rhs.z.0)
+				- E23oi(self.e3oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for Circle {
+	type Output = Versor;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(Point) -> Multivector
+impl AntiGeometric<Point> for Circle {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23i.0 * rhs.o.0) - E1(self.e23o.0 * rhs.inf.0) - E1(self.e2oi.0 * rhs.z.0) + E1(self.e3oi.0 * rhs.y.0),
+		//     e2    : -E2(self.e13i.0 * rhs.o.0) + E2(self.e13o.0 * rhs.inf.0) + E2(self.e1oi.0 * rhs.z.0) - E2(self.e3oi.0 * rhs.x.0),
+		//     e3    : E3(self.e12i.0 * rhs.o.0) - E3(self.e12o.0 * rhs.inf.0) - E3(self.e1oi.0 * rhs.y.0) + E3(self.e2oi.0 * rhs.x.0),
+		//     eo    : Eo(self.e123.0 * rhs.o.0) - Eo(self.e12o.0 * rhs.z.0) + Eo(self.e13o.0 * rhs.y.0) - Eo(self.e23o.0 * rhs.x.0),
+		//     ei    : -Ei(self.e123.0 * rhs.inf.0) + Ei(self.e12i.0 * rhs.z.0) - Ei(self.e13i.0 * rhs.y.0) + Ei(self.e23i.0 * rhs.x.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+		//     e12o  : E12o(self.e13o.0 * rhs.x.0) + E12o(self.e23o.0 * rhs.y.0) - E12o(self.e3oi.0 * rhs.o.0),
+		//     e12i  : -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0) - E12i(self.e3oi.0 * rhs.inf.0),
+		//     e13o  : -E13o(self.e12o.0 * rhs.x.0) + E13o(self.e23o.0 * rhs.z.0) + E13o(self.e2oi.0 * rhs.o.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0) + E13i(self.e2oi.0 * rhs.inf.0),
+		//     e1oi  : E1oi(self.e123.0 * rhs.x.0) - E1oi(self.e23i.0 * rhs.o.0) - E1oi(self.e23o.0 * rhs.inf.0),
+		//     e23o  : -E23o(self.e12o.0 * rhs.y.0) - E23o(self.e13o.0 * rhs.z.0) - E23o(self.e1oi.0 * rhs.o.0),
+		//     e23i  : E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0) - E23i(self.e1oi.0 * rhs.inf.0),
+		//     e2oi  : E2oi(self.e123.0 * rhs.y.0) + E2oi(self.e13i.0 * rhs.o.0) + E2oi(self.e13o.0 * rhs.inf.0),
+		//     e3oi  : E3oi(self.e123.0 * rhs.z.0) - E3oi(self.e12i.0 * rhs.o.0) - E3oi(self.e12o.0 * rhs.inf.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23i.0 * rhs.o.0) - E1(self.e23o.0 * rhs.inf.0) - E1(self.e2oi.0 * rhs.z.0)
+				+ E1(self.e3oi.0 * rhs.y.0),
+			e2: -E2(self.e13i.0 * rhs.o.0) + E2(self.e13o.0 * rhs.inf.0) + E2(self.e1oi.0 * rhs.z.0)
+				- E2(self.e3oi.0 * rhs.x.0),
+			e3: E3(self.e12i.0 * rhs.o.0) - E3(self.e12o.0 * rhs.inf.0) - E3(self.e1oi.0 * rhs.y.0)
+				+ E3(self.e2oi.0 * rhs.x.0),
+			eo: Eo(self.e123.0 * rhs.o.0) - Eo(self.e12o.0 * rhs.z.0) + Eo(self.e13o.0 * rhs.y.0)
+				- Eo(self.e23o.0 * rhs.x.0),
+			ei: -Ei(self.e123.0 * rhs.inf.0) + Ei(self.e12i.0 * rhs.z.0) - Ei(self.e13i.0 * rhs.y.0)
+				+ Ei(self.e23i.0 * rhs.x.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+			e12o: E12o(self.e13o.0 * rhs.x.0) + E12o(self.e23o.0 * rhs.y.0) - E12o(self.e3oi.0 * rhs.o.0),
+			e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0) - E12i(self.e3oi.0 * rhs.inf.0),
+			e13o: -E13o(self.e12o.0 * rhs.x.0) + E13o(self.e23o.0 * rhs.z.0) + E13o(self.e2oi.0 * rhs.o.0),
+			e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0) + E13i(self.e2oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e123.0 * rhs.x.0) - E1oi(self.e23i.0 * rhs.o.0) - E1oi(self.e23o.0 * rhs.inf.0),
+			e23o: -E23o(self.e12o.0 * rhs.y.0) - E23o(self.e13o.0 * rhs.z.0) - E23o(self.e1oi.0 * rhs.o.0),
+			e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0) - E23i(self.e1oi.0 * rhs.inf.0),
+			e2oi: E2oi(self.e123.0 * rhs.y.0) + E2oi(self.e13i.0 * rhs.o.0) + E2oi(self.e13o.0 * rhs.inf.0),
+			e3oi: E3oi(self.e123.0 * rhs.z.0) - E3oi(self.e12i.0 * rhs.o.0) - E3oi(self.e12o.0 * rhs.inf.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Circle.dot(Point) -> PointPair
+impl Dot<Point> for Circle {
+	type Output = PointPair;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123.0 * rhs.z.0) - E12(self.e12i.0 * rhs.o.0) - E12(self.e12o.0 * rhs.inf.0),
+		//     e13: -E13(self.e123.0 * rhs.y.0) - E13(self.e13i.0 * rhs.o.0) - E13(self.e13o.0 * rhs.inf.0),
+		//     e1o: -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0) - E1o(self.e1oi.0 * rhs.o.0),
+		//     e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+		//     e23: E23(self.e123.0 * rhs.x.0) - E23(self.e23i.0 * rhs.o.0) - E23(self.e23o.0 * rhs.inf.0),
+		//     e2o: E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0) - E2o(self.e2oi.0 * rhs.o.0),
+		//     e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+		//     e3o: E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0) - E3o(self.e3oi.0 * rhs.o.0),
+		//     e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		// }
+		PointPair {
+			e12: E12(self.e123.0 * rhs.z.0) - E12(self.e12i.0 * rhs.o.0) - E12(self.e12o.0 * rhs.inf.0),
+			e13: -E13(self.e123.0 * rhs.y.0) - E13(self.e13i.0 * rhs.o.0) - E13(self.e13o.0 * rhs.inf.0),
+			e1o: -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0) - E1o(self.e1oi.0 * rhs.o.0),
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+			e23: E23(self.e123.0 * rhs.x.0) - E23(self.e23i.0 * rhs.o.0) - E23(self.e23o.0 * rhs.inf.0),
+			e2o: E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0) - E2o(self.e2oi.0 * rhs.o.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+			e3o: E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0) - E3o(self.e3oi.0 * rhs.o.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point> for Circle {
+	type Output = PointPair;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Circle.wedge(Point) -> Sphere
+impl Wedge<Point> for Circle {
+	type Output = Sphere;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e123.0 * rhs.o.0) - E123o(self.e12o.0 * rhs.z.0) + E123o(self.e13o.0 * rhs.y.0) - E123o(self.e23o.0 * rhs.x.0),
+		//     e123i: E123i(self.e123.0 * rhs.inf.0) - E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.o.0) + E12oi(self.e12o.0 * rhs.inf.0) + E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: -E13oi(self.e13i.0 * rhs.o.0) + E13oi(self.e13o.0 * rhs.inf.0) + E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: -E23oi(self.e23i.0 * rhs.o.0) + E23oi(self.e23o.0 * rhs.inf.0) + E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Sphere {
+			e123o: self.e123.wedge(rhs.o) + self.e12o.wedge(rhs.z) + self.e13o.wedge(rhs.y) + self.e23o.wedge(rhs.x),
+			e123i: self.e123.wedge(rhs.inf) + self.e12i.wedge(rhs.z) + self.e13i.wedge(rhs.y) + self.e23i.wedge(rhs.x),
+			e12oi: self.e12i.wedge(rhs.o) + self.e12o.wedge(rhs.inf) + self.e1oi.wedge(rhs.y) + self.e2oi.wedge(rhs.x),
+			e13oi: self.e13i.wedge(rhs.o) + self.e13o.wedge(rhs.inf) + self.e1oi.wedge(rhs.z) + self.e3oi.wedge(rhs.x),
+			e23oi: self.e23i.wedge(rhs.o) + self.e23o.wedge(rhs.inf) + self.e2oi.wedge(rhs.z) + self.e3oi.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point> for Circle {
+	type Output = Sphere;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point> for Circle {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point> for Circle {
+	type Output = Zero;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point> for Circle {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.right_contraction(Point) -> PointPair
+impl RightContraction<Point> for Circle {
+	type Output = PointPair;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123.0 * rhs.z.0) - E12(self.e12i.0 * rhs.o.0) - E12(self.e12o.0 * rhs.inf.0),
+		//     e13: -E13(self.e123.0 * rhs.y.0) - E13(self.e13i.0 * rhs.o.0) - E13(self.e13o.0 * rhs.inf.0),
+		//     e1o: -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0) - E1o(self.e1oi.0 * rhs.o.0),
+		//     e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+		//     e23: E23(self.e123.0 * rhs.x.0) - E23(self.e23i.0 * rhs.o.0) - E23(self.e23o.0 * rhs.inf.0),
+		//     e2o: E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0) - E2o(self.e2oi.0 * rhs.o.0),
+		//     e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+		//     e3o: E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0) - E3o(self.e3oi.0 * rhs.o.0),
+		//     e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		// }
+		PointPair {
+			e12: E12(self.e123.0 * rhs.z.0) - E12(self.e12i.0 * rhs.o.0) - E12(self.e12o.0 * rhs.inf.0),
+			e13: -E13(self.e123.0 * rhs.y.0) - E13(self.e13i.0 * rhs.o.0) - E13(self.e13o.0 * rhs.inf.0),
+			e1o: -E1o(self.e12o.0 * rhs.y.0) - E1o(self.e13o.0 * rhs.z.0) - E1o(self.e1oi.0 * rhs.o.0),
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+			e23: E23(self.e123.0 * rhs.x.0) - E23(self.e23i.0 * rhs.o.0) - E23(self.e23o.0 * rhs.inf.0),
+			e2o: E2o(self.e12o.0 * rhs.x.0) - E2o(self.e23o.0 * rhs.z.0) - E2o(self.e2oi.0 * rhs.o.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+			e3o: E3o(self.e13o.0 * rhs.x.0) + E3o(self.e23o.0 * rhs.y.0) - E3o(self.e3oi.0 * rhs.o.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		}
+	}
+}
+
+impl ScalarProduct<Point> for Circle {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.commutator(Point) -> Sphere
+impl Commutator<Point> for Circle {
+	type Output = Sphere;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e123.0 * rhs.o.0) - E123o(self.e12o.0 * rhs.z.0) + E123o(self.e13o.0 * rhs.y.0) - E123o(self.e23o.0 * rhs.x.0),
+		//     e123i: E123i(self.e123.0 * rhs.inf.0) - E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.o.0) + E12oi(self.e12o.0 * rhs.inf.0) + E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: -E13oi(self.e13i.0 * rhs.o.0) + E13oi(self.e13o.0 * rhs.inf.0) + E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: -E23oi(self.e23i.0 * rhs.o.0) + E23oi(self.e23o.0 * rhs.inf.0) + E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Sphere {
+			e123o: E123o(self.e123.0 * rhs.o.0) - E123o(self.e12o.0 * rhs.z.0) + E123o(self.e13o.0 * rhs.y.0)
+				- E123o(self.e23o.0 * rhs.x.0),
+			e123i: E123i(self.e123.0 * rhs.inf.0) - E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0)
+				- E123i(self.e23i.0 * rhs.x.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.o.0) + E12oi(self.e12o.0 * rhs.inf.0) + E12oi(self.e1oi.0 * rhs.y.0)
+				- E12oi(self.e2oi.0 * rhs.x.0),
+			e13oi: -E13oi(self.e13i.0 * rhs.o.0) + E13oi(self.e13o.0 * rhs.inf.0) + E13oi(self.e1oi.0 * rhs.z.0)
+				- E13oi(self.e3oi.0 * rhs.x.0),
+			e23oi: -E23oi(self.e23i.0 * rhs.o.0) + E23oi(self.e23o.0 * rhs.inf.0) + E23oi(self.e2oi.0 * rhs.z.0)
+				- E23oi(self.e3oi.0 * rhs.y.0),
+		}
+	}
+}
+
+// Circle.anti_dot(Point) -> Circle
+impl AntiDot<Point> for Circle {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+		//     e12o: E12o(self.e13o.0 * rhs.x.0) + E12o(self.e23o.0 * rhs.y.0) - E12o(self.e3oi.0 * rhs.o.0),
+		//     e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0) - E12i(self.e3oi.0 * rhs.inf.0),
+		//     e13o: -E13o(self.e12o.0 * rhs.x.0) + E13o(self.e23o.0 * rhs.z.0) + E13o(self.e2oi.0 * rhs.o.0),
+		//     e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0) + E13i(self.e2oi.0 * rhs.inf.0),
+		//     e1oi: E1oi(self.e123.0 * rhs.x.0) - E1oi(self.e23i.0 * rhs.o.0) - E1oi(self.e23o.0 * rhs.inf.0),
+		//     e23o: -E23o(self.e12o.0 * rhs.y.0) - E23o(self.e13o.0 * rhs.z.0) - E23o(self.e1oi.0 * rhs.o.0),
+		//     e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0) - E23i(self.e1oi.0 * rhs.inf.0),
+		//     e2oi: E2oi(self.e123.0 * rhs.y.0) + E2oi(self.e13i.0 * rhs.o.0) + E2oi(self.e13o.0 * rhs.inf.0),
+		//     e3oi: E3oi(self.e123.0 * rhs.z.0) - E3oi(self.e12i.0 * rhs.o.0) - E3oi(self.e12o.0 * rhs.inf.0),
+		// }
+		Circle {
+			e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+			e12o: E12o(self.e13o.0 * rhs.x.0) + E12o(self.e23o.0 * rhs.y.0) - E12o(self.e3oi.0 * rhs.o.0),
+			e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0) - E12i(self.e3oi.0 * rhs.inf.0),
+			e13o: -E13o(self.e12o.0 * rhs.x.0) + E13o(self.e23o.0 * rhs.z.0) + E13o(self.e2oi.0 * rhs.o.0),
+			e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0) + E13i(self.e2oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e123.0 * rhs.x.0) - E1oi(self.e23i.0 * rhs.o.0) - E1oi(self.e23o.0 * rhs.inf.0),
+			e23o: -E23o(self.e12o.0 * rhs.y.0) - E23o(self.e13o.0 * rhs.z.0) - E23o(self.e1oi.0 * rhs.o.0),
+			e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0) - E23i(self.e1oi.0 * rhs.inf.0),
+			e2oi: E2oi(self.e123.0 * rhs.y.0) + E2oi(self.e13i.0 * rhs.o.0) + E2oi(self.e13o.0 * rhs.inf.0),
+			e3oi: E3oi(self.e123.0 * rhs.z.0) - E3oi(self.e12i.0 * rhs.o.0) - E3oi(self.e12o.0 * rhs.inf.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP FlatPoint:
+
+// Circle.geometric(FlatPoint) -> Multivector
+impl Geometric<FlatPoint> for Circle {
+	type Output = Multivector;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     e2    : -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     e3    : -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23o.0 * rhs.e1i.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.eoi.0),
+		//     e12i  : E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.eoi.0),
+		//     e13i  : -E13i(self.e123.0 * rhs.e2i.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : -E1oi(self.e12o.0 * rhs.e2i.0) - E1oi(self.e13o.0 * rhs.e3i.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.eoi.0),
+		//     e23i  : E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.e12o.0 * rhs.e1i.0) - E2oi(self.e23o.0 * rhs.e3i.0),
+		//     e3oi  : E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e23o.0 * rhs.e2i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123.0 * rhs.eoi.0) - E123oi(self.e12o.0 * rhs.e3i.0) + E123oi(self.e13o.0 * rhs.e2i.0) - E123oi(self.e23o.0 * rhs.e1i.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+			e2: -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+			e3: -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+			eo: Default::default(),
+			ei: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23o.0 * rhs.e1i.0),
+			e12o: E12o(self.e12o.0 * rhs.eoi.0),
+			e12i: E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e1oi.0 * rhs.e2i.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: E13o(self.e13o.0 * rhs.eoi.0),
+			e13i: -E13i(self.e123.0 * rhs.e2i.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: -E1oi(self.e12o.0 * rhs.e2i.0) - E1oi(self.e13o.0 * rhs.e3i.0),
+			e23o: E23o(self.e23o.0 * rhs.eoi.0),
+			e23i: E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: E2oi(self.e12o.0 * rhs.e1i.0) - E2oi(self.e23o.0 * rhs.e3i.0),
+			e3oi: E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e23o.0 * rhs.e2i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123.0 * rhs.eoi.0) - E123oi(self.e12o.0 * rhs.e3i.0) + E123oi(self.e13o.0 * rhs.e2i.0)
+				- E123oi(self.e23o.0 * rhs.e1i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for Circle {
+	type Output = Multivector;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(FlatPoint) -> Versor
+impl AntiGeometric<FlatPoint> for Circle {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e123.0 * rhs.eoi.0) - S(self.e12o.0 * rhs.e3i.0) + S(self.e13o.0 * rhs.e2i.0) - S(self.e23o.0 * rhs.e1i.0),
+		//     e12  : E12(self.e13o.0 * rhs.e1i.0) + E12(self.e23o.0 * rhs.e2i.0),
+		//     e13  : -E13(self.e12o.0 * rhs.e1i.0) + E13(self.e23o.0 * rhs.e3i.0),
+		//     e1o  : E1o(self.e23o.0 * rhs.eoi.0),
+		//     e1i  : -E1i(self.e123.0 * rhs.e1i.0) + E1i(self.e23i.0 * rhs.eoi.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+		//     e23  : -E23(self.e12o.0 * rhs.e2i.0) - E23(self.e13o.0 * rhs.e3i.0),
+		//     e2o  : -E2o(self.e13o.0 * rhs.eoi.0),
+		//     e2i  : -E2i(self.e123.0 * rhs.e2i.0) - E2i(self.e13i.0 * rhs.eoi.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+		//     e3o  : E3o(self.e12o.0 * rhs.eoi.0),
+		//     e3i  : -E3i(self.e123.0 * rhs.e3i.0) + E3i(self.e12i.0 * rhs.eoi.0) - E3i(self.e1oi.0 * rhs.e2i.0) + E3i(self.e2oi.0 * rhs.e1i.0),
+		//     eoi  : -Eoi(self.e12o.0 * rhs.e3i.0) + Eoi(self.e13o.0 * rhs.e2i.0) - Eoi(self.e23o.0 * rhs.e1i.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e23o.0 * rhs.e2i.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: -E13oi(self.e12o.0 * rhs.e1i.0) + E13oi(self.e23o.0 * rhs.e3i.0) + E13oi(self.e2oi.0 * rhs.eoi.0),
+		//     e23oi: -E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13o.0 * rhs.e3i.0) - E23oi(self.e1oi.0 * rhs.eoi.0),
+		// }
+		Versor {
+			s: S(self.e123.0 * rhs.eoi.0) - S(self.e12o.0 * rhs.e3i.0) + S(self.e13o.0 * rhs.e2i.0)
+				- S(self.e23o.0 * rhs.e1i.0),
+			e12: E12(self.e13o.0 * rhs.e1i.0) + E12(self.e23o.0 * rhs.e2i.0),
+			e13: -E13(self.e12o.0 * rhs.e1i.0) + E13(self.e23o.0 * rhs.e3i.0),
+			e1o: E1o(self.e23o.0 * rhs.eoi.0),
+			e1i: -E1i(self.e123.0 * rhs.e1i.0) + E1i(self.e23i.0 * rhs.eoi.0) - E1i(self.e2oi.0 * rhs.e3i.0)
+				+ E1i(self.e3oi.0 * rhs.e2i.0),
+			e23: -E23(self.e12o.0 * rhs.e2i.0) - E23(self.e13o.0 * rhs.e3i.0),
+			e2o: -E2o(self.e13o.0 * rhs.eoi.0),
+			e2i: -E2i(self.e123.0 * rhs.e2i.0) - E2i(self.e13i.0 * rhs.eoi.0) + E2i(self.e1oi.0 * rhs.e3i.0)
+				- E2i(self.e3oi.0 * rhs.e1i.0),
+			e3o: E3o(self.e12o.0 * rhs.eoi.0),
+			e3i: -E3i(self.e123.0 * rhs.e3i.0) + E3i(self.e12i.0 * rhs.eoi.0) - E3i(self.e1oi.0 * rhs.e2i.0)
+				+ E3i(self.e2oi.0 * rhs.e1i.0),
+			eoi: -Eoi(self.e12o.0 * rhs.e3i.0) + Eoi(self.e13o.0 * rhs.e2i.0) - Eoi(self.e23o.0 * rhs.e1i.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e23o.0 * rhs.e2i.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: -E13oi(self.e12o.0 * rhs.e1i.0) + E13oi(self.e23o.0 * rhs.e3i.0) + E13oi(self.e2oi.0 * rhs.eoi.0),
+			e23oi: -E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13o.0 * rhs.e3i.0) - E23oi(self.e1oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Circle.dot(FlatPoint) -> Point
+impl Dot<FlatPoint> for Circle {
+	type Output = Point;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     y  : -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     z  : -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+			y: -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+			z: -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+			o: Default::default(),
+			inf: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for Circle {
+	type Output = Point;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Circle.wedge(FlatPoint) -> E123oi
+impl Wedge<FlatPoint> for Circle {
+	type Output = E123oi;
+	fn wedge(self, rhs: FlatPoint) -> Self::Output {
+		// E123oi(self.e123.0 * rhs.eoi.0) - E123oi(self.e12o.0 * rhs.e3i.0) + E123oi(self.e13o.0 * rhs.e2i.0) - E123oi(self.e23o.0 * rhs.e1i.0)
+		self.e123.wedge(rhs.eoi) + self.e12o.wedge(rhs.e3i) + self.e13o.wedge(rhs.e2i) + self.e23o.wedge(rhs.e1i)
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for Circle {
+	type Output = E123oi;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Circle.anti_wedge(FlatPoint) -> S
+impl AntiWedge<FlatPoint> for Circle {
+	type Output = S;
+	fn anti_wedge(self, rhs: FlatPoint) -> Self::Output {
+		// S(self.e123.0 * rhs.eoi.0) - S(self.e12o.0 * rhs.e3i.0) + S(self.e13o.0 * rhs.e2i.0) - S(self.e23o.0 * rhs.e1i.0)
+		self.e123.anti_wedge(rhs.eoi)
+			+ self.e12o.anti_wedge(rhs.e3i)
+			+ self.e13o.anti_wedge(rhs.e2i)
+			+ self.e23o.anti_wedge(rhs.e1i)
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for Circle {
+	type Output = S;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<FlatPoint> for Circle {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.right_contraction(FlatPoint) -> Point
+impl RightContraction<FlatPoint> for Circle {
+	type Output = Point;
+	fn right_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     y  : -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     z  : -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+			y: -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+			z: -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+			o: Default::default(),
+			inf: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl ScalarProduct<FlatPoint> for Circle {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.commutator(FlatPoint) -> Circle
+impl Commutator<FlatPoint> for Circle {
+	type Output = Circle;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23o.0 * rhs.e1i.0),
+		//     e12o: E12o(self.e12o.0 * rhs.eoi.0),
+		//     e12i: E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o: E13o(self.e13o.0 * rhs.eoi.0),
+		//     e13i: -E13i(self.e123.0 * rhs.e2i.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi: -E1oi(self.e12o.0 * rhs.e2i.0) - E1oi(self.e13o.0 * rhs.e3i.0),
+		//     e23o: E23o(self.e23o.0 * rhs.eoi.0),
+		//     e23i: E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi: E2oi(self.e12o.0 * rhs.e1i.0) - E2oi(self.e23o.0 * rhs.e3i.0),
+		//     e3oi: E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e23o.0 * rhs.e2i.0),
+		// }
+		Circle {
+			e123: E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23o.0 * rhs.e1i.0),
+			e12o: E12o(self.e12o.0 * rhs.eoi.0),
+			e12i: E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e1oi.0 * rhs.e2i.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: E13o(self.e13o.0 * rhs.eoi.0),
+			e13i: -E13i(self.e123.0 * rhs.e2i.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: -E1oi(self.e12o.0 * rhs.e2i.0) - E1oi(self.e13o.0 * rhs.e3i.0),
+			e23o: E23o(self.e23o.0 * rhs.eoi.0),
+			e23i: E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: E2oi(self.e12o.0 * rhs.e1i.0) - E2oi(self.e23o.0 * rhs.e3i.0),
+			e3oi: E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e23o.0 * rhs.e2i.0),
+		}
+	}
+}
+
+// Circle.anti_dot(FlatPoint) -> Plane
+impl AntiDot<FlatPoint> for Circle {
+	type Output = Plane;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e23o.0 * rhs.e2i.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: -E13oi(self.e12o.0 * rhs.e1i.0) + E13oi(self.e23o.0 * rhs.e3i.0) + E13oi(self.e2oi.0 * rhs.eoi.0),
+		//     e23oi: -E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13o.0 * rhs.e3i.0) - E23oi(self.e1oi.0 * rhs.eoi.0),
+		// }
+		Plane {
+			e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e23o.0 * rhs.e2i.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: -E13oi(self.e12o.0 * rhs.e1i.0) + E13oi(self.e23o.0 * rhs.e3i.0) + E13oi(self.e2oi.0 * rhs.eoi.0),
+			e23oi: -E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13o.0 * rhs.e3i.0) - E23oi(self.e1oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP PointPair:
+
+// Circle.geometric(PointPair) -> Multivector
+impl Geometric<PointPair> for Circle {
+	type Output = Multivector;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123.0 * rhs.e23.0) + E1(self.e12i.0 * rhs.e2o.0) + E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     e2    : E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     e3    : -E3(self.e123.0 * rhs.e12.0) - E3(self.e13i.0 * rhs.e1o.0) - E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23i.0 * rhs.e2o.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e23o.0 * rhs.e23.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13i.0 * rhs.e2o.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23i.0 * rhs.e1o.0) + E123(self.e23o.0 * rhs.e1i.0),
+		//     e12o  : E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0) - E12o(self.e13o.0 * rhs.e23.0) - E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e23o.0 * rhs.e13.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+		//     e12i  : E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o  : -E13o(self.e123.0 * rhs.e2o.0) + E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e13o.0 * rhs.eoi.0) - E13o(self.e1oi.0 * rhs.e3o.0) - E13o(self.e23o.0 * rhs.e12.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i  : -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0) + E1oi(self.e13i.0 * rhs.e3o.0) - E1oi(self.e13o.0 * rhs.e3i.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0) + E23o(self.e13o.0 * rhs.e12.0) + E23o(self.e23o.0 * rhs.eoi.0) - E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e12o.0 * rhs.e1i.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) - E2oi(self.e23o.0 * rhs.e3i.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e23o.0 * rhs.e2i.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123.0 * rhs.eoi.0) + E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e12o.0 * rhs.e3i.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e13o.0 * rhs.e2i.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e23o.0 * rhs.e1i.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123.0 * rhs.e23.0)
+				+ E1(self.e12i.0 * rhs.e2o.0)
+				+ E1(self.e12o.0 * rhs.e2i.0)
+				+ E1(self.e13i.0 * rhs.e3o.0)
+				+ E1(self.e13o.0 * rhs.e3i.0)
+				+ E1(self.e1oi.0 * rhs.eoi.0),
+			e2: E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e23o.0 * rhs.e3i.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0),
+			e3: -E3(self.e123.0 * rhs.e12.0)
+				- E3(self.e13i.0 * rhs.e1o.0)
+				- E3(self.e13o.0 * rhs.e1i.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				- E3(self.e23o.0 * rhs.e2i.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e12o.0 * rhs.e12.0)
+				- Eo(self.e13o.0 * rhs.e13.0)
+				- Eo(self.e1oi.0 * rhs.e1o.0)
+				- Eo(self.e23o.0 * rhs.e23.0)
+				- Eo(self.e2oi.0 * rhs.e2o.0)
+				- Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0)
+				- E123(self.e13i.0 * rhs.e2o.0)
+				- E123(self.e13o.0 * rhs.e2i.0)
+				+ E123(self.e23i.0 * rhs.e1o.0)
+				+ E123(self.e23o.0 * rhs.e1i.0),
+			e12o: E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0)
+				- E12o(self.e13o.0 * rhs.e23.0)
+				- E12o(self.e1oi.0 * rhs.e2o.0)
+				+ E12o(self.e23o.0 * rhs.e13.0)
+				+ E12o(self.e2oi.0 * rhs.e1o.0),
+			e12i: E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: -E13o(self.e123.0 * rhs.e2o.0) + E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e13o.0 * rhs.eoi.0)
+				- E13o(self.e1oi.0 * rhs.e3o.0)
+				- E13o(self.e23o.0 * rhs.e12.0)
+				+ E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0)
+				+ E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0) + E1oi(self.e13i.0 * rhs.e3o.0)
+				- E1oi(self.e13o.0 * rhs.e3i.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0)
+				+ E23o(self.e13o.0 * rhs.e12.0)
+				+ E23o(self.e23o.0 * rhs.eoi.0)
+				- E23o(self.e2oi.0 * rhs.e3o.0)
+				+ E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0)
+				- E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e1o.0)
+				+ E2oi(self.e12o.0 * rhs.e1i.0)
+				+ E2oi(self.e1oi.0 * rhs.e12.0)
+				+ E2oi(self.e23i.0 * rhs.e3o.0)
+				- E2oi(self.e23o.0 * rhs.e3i.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e1oi.0 * rhs.e13.0)
+				- E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e23o.0 * rhs.e2i.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123.0 * rhs.eoi.0) + E123oi(self.e12i.0 * rhs.e3o.0)
+				- E123oi(self.e12o.0 * rhs.e3i.0)
+				- E123oi(self.e13i.0 * rhs.e2o.0)
+				+ E123oi(self.e13o.0 * rhs.e2i.0)
+				+ E123oi(self.e1oi.0 * rhs.e23.0)
+				+ E123oi(self.e23i.0 * rhs.e1o.0)
+				- E123oi(self.e23o.0 * rhs.e1i.0)
+				- E123oi(self.e2oi.0 * rhs.e13.0)
+				+ E123oi(self.e3oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for Circle {
+	type Output = Multivector;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(PointPair) -> Versor
+impl AntiGeometric<PointPair> for Circle {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e123.0 * rhs.eoi.0) + S(self.e12i.0 * rhs.e3o.0) - S(self.e12o.0 * rhs.e3i.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e13o.0 * rhs.e2i.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e23o.0 * rhs.e1i.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e12  : -E12(self.e13i.0 * rhs.e1o.0) + E12(self.e13o.0 * rhs.e1i.0) + E12(self.e1oi.0 * rhs.e13.0) - E12(self.e23i.0 * rhs.e2o.0) + E12(self.e23o.0 * rhs.e2i.0) + E12(self.e2oi.0 * rhs.e23.0),
+		//     e13  : E13(self.e12i.0 * rhs.e1o.0) - E13(self.e12o.0 * rhs.e1i.0) - E13(self.e1oi.0 * rhs.e12.0) - E13(self.e23i.0 * rhs.e3o.0) + E13(self.e23o.0 * rhs.e3i.0) + E13(self.e3oi.0 * rhs.e23.0),
+		//     e1o  : E1o(self.e123.0 * rhs.e1o.0) - E1o(self.e12o.0 * rhs.e13.0) + E1o(self.e13o.0 * rhs.e12.0) + E1o(self.e23o.0 * rhs.eoi.0) - E1o(self.e2oi.0 * rhs.e3o.0) + E1o(self.e3oi.0 * rhs.e2o.0),
+		//     e1i  : -E1i(self.e123.0 * rhs.e1i.0) + E1i(self.e12i.0 * rhs.e13.0) - E1i(self.e13i.0 * rhs.e12.0) + E1i(self.e23i.0 * rhs.eoi.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+		//     e23  : E23(self.e12i.0 * rhs.e2o.0) - E23(self.e12o.0 * rhs.e2i.0) + E23(self.e13i.0 * rhs.e3o.0) - E23(self.e13o.0 * rhs.e3i.0) - E23(self.e2oi.0 * rhs.e12.0) - E23(self.e3oi.0 * rhs.e13.0),
+		//     e2o  : E2o(self.e123.0 * rhs.e2o.0) - E2o(self.e12o.0 * rhs.e23.0) - E2o(self.e13o.0 * rhs.eoi.0) + E2o(self.e1oi.0 * rhs.e3o.0) + E2o(self.e23o.0 * rhs.e12.0) - E2o(self.e3oi.0 * rhs.e1o.0),
+		//     e2i  : -E2i(self.e123.0 * rhs.e2i.0) + E2i(self.e12i.0 * rhs.e23.0) - E2i(self.e13i.0 * rhs.eoi.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e23i.0 * rhs.e12.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+		//     e3o  : E3o(self.e123.0 * rhs.e3o.0) + E3o(self.e12o.0 * rhs.eoi.0) - E3o(self.e13o.0 * rhs.e23.0) - E3o(self.e1oi.0 * rhs.e2o.0) + E3o(self.e23o.0 * rhs.e13.0) + E3o(self.e2oi.0 * rhs.e1o.0),
+		//     e3i  : -E3i(self.e123.0 * rhs.e3i.0) + E3i(self.e12i.0 * rhs.eoi.0) + E3i(self.e13i.0 * rhs.e23.0) - E3i(self.e1oi.0 * rhs.e2i.0) - E3i(self.e23i.0 * rhs.e13.0) + E3i(self.e2oi.0 * rhs.e1i.0),
+		//     eoi  : -Eoi(self.e12i.0 * rhs.e3o.0) - Eoi(self.e12o.0 * rhs.e3i.0) + Eoi(self.e13i.0 * rhs.e2o.0) + Eoi(self.e13o.0 * rhs.e2i.0) - Eoi(self.e23i.0 * rhs.e1o.0) - Eoi(self.e23o.0 * rhs.e1i.0),
+		//     e123o: -E123o(self.e12o.0 * rhs.e12.0) - E123o(self.e13o.0 * rhs.e13.0) - E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e23o.0 * rhs.e23.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.e123.0 * rhs.e12.0) + E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e23i.0 * rhs.e2o.0) + E12oi(self.e23o.0 * rhs.e2i.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: E13oi(self.e123.0 * rhs.e13.0) - E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e12o.0 * rhs.e1i.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e23o.0 * rhs.e3i.0) + E13oi(self.e2oi.0 * rhs.eoi.0),
+		//     e23oi: E23oi(self.e123.0 * rhs.e23.0) - E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e13o.0 * rhs.e3i.0) - E23oi(self.e1oi.0 * rhs.eoi.0),
+		// }
+		Versor {
+			s: S(self.e123.0 * rhs.eoi.0) + S(self.e12i.0 * rhs.e3o.0)
+				- S(self.e12o.0 * rhs.e3i.0)
+				- S(self.e13i.0 * rhs.e2o.0)
+				+ S(self.e13o.0 * rhs.e2i.0)
+				+ S(self.e1oi.0 * rhs.e23.0)
+				+ S(self.e23i.0 * rhs.e1o.0)
+				- S(self.e23o.0 * rhs.e1i.0)
+				- S(self.e2oi.0 * rhs.e13.0)
+				+ S(self.e3oi.0 * rhs.e12.0),
+			e12: -E12(self.e13i.0 * rhs.e1o.0) + E12(self.e13o.0 * rhs.e1i.0) + E12(self.e1oi.0 * rhs.e13.0)
+				- E12(self.e23i.0 * rhs.e2o.0)
+				+ E12(self.e23o.0 * rhs.e2i.0)
+				+ E12(self.e2oi.0 * rhs.e23.0),
+			e13: E13(self.e12i.0 * rhs.e1o.0)
+				- E13(self.e12o.0 * rhs.e1i.0)
+				- E13(self.e1oi.0 * rhs.e12.0)
+				- E13(self.e23i.0 * rhs.e3o.0)
+				+ E13(self.e23o.0 * rhs.e3i.0)
+				+ E13(self.e3oi.0 * rhs.e23.0),
+			e1o: E1o(self.e123.0 * rhs.e1o.0) - E1o(self.e12o.0 * rhs.e13.0)
+				+ E1o(self.e13o.0 * rhs.e12.0)
+				+ E1o(self.e23o.0 * rhs.eoi.0)
+				- E1o(self.e2oi.0 * rhs.e3o.0)
+				+ E1o(self.e3oi.0 * rhs.e2o.0),
+			e1i: -E1i(self.e123.0 * rhs.e1i.0) + E1i(self.e12i.0 * rhs.e13.0) - E1i(self.e13i.0 * rhs.e12.0)
+				+ E1i(self.e23i.0 * rhs.eoi.0)
+				- E1i(self.e2oi.0 * rhs.e3i.0)
+				+ E1i(self.e3oi.0 * rhs.e2i.0),
+			e23: E23(self.e12i.0 * rhs.e2o.0) - E23(self.e12o.0 * rhs.e2i.0) + E23(self.e13i.0 * rhs.e3o.0)
+				- E23(self.e13o.0 * rhs.e3i.0)
+				- E23(self.e2oi.0 * rhs.e12.0)
+				- E23(self.e3oi.0 * rhs.e13.0),
+			e2o: E2o(self.e123.0 * rhs.e2o.0) - E2o(self.e12o.0 * rhs.e23.0) - E2o(self.e13o.0 * rhs.eoi.0)
+				+ E2o(self.e1oi.0 * rhs.e3o.0)
+				+ E2o(self.e23o.0 * rhs.e12.0)
+				- E2o(self.e3oi.0 * rhs.e1o.0),
+			e2i: -E2i(self.e123.0 * rhs.e2i.0) + E2i(self.e12i.0 * rhs.e23.0) - E2i(self.e13i.0 * rhs.eoi.0)
+				+ E2i(self.e1oi.0 * rhs.e3i.0)
+				- E2i(self.e23i.0 * rhs.e12.0)
+				- E2i(self.e3oi.0 * rhs.e1i.0),
+			e3o: E3o(self.e123.0 * rhs.e3o.0) + E3o(self.e12o.0 * rhs.eoi.0)
+				- E3o(self.e13o.0 * rhs.e23.0)
+				- E3o(self.e1oi.0 * rhs.e2o.0)
+				+ E3o(self.e23o.0 * rhs.e13.0)
+				+ E3o(self.e2oi.0 * rhs.e1o.0),
+			e3i: -E3i(self.e123.0 * rhs.e3i.0) + E3i(self.e12i.0 * rhs.eoi.0) + E3i(self.e13i.0 * rhs.e23.0)
+				- E3i(self.e1oi.0 * rhs.e2i.0)
+				- E3i(self.e23i.0 * rhs.e13.0)
+				+ E3i(self.e2oi.0 * rhs.e1i.0),
+			eoi: -Eoi(self.e12i.0 * rhs.e3o.0) - Eoi(self.e12o.0 * rhs.e3i.0)
+				+ Eoi(self.e13i.0 * rhs.e2o.0)
+				+ Eoi(self.e13o.0 * rhs.e2i.0)
+				- Eoi(self.e23i.0 * rhs.e1o.0)
+				- Eoi(self.e23o.0 * rhs.e1i.0),
+			e123o: -E123o(self.e12o.0 * rhs.e12.0)
+				- E123o(self.e13o.0 * rhs.e13.0)
+				- E123o(self.e1oi.0 * rhs.e1o.0)
+				- E123o(self.e23o.0 * rhs.e23.0)
+				- E123o(self.e2oi.0 * rhs.e2o.0)
+				- E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) - E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e123.0 * rhs.e12.0)
+				+ E12oi(self.e13i.0 * rhs.e1o.0)
+				+ E12oi(self.e13o.0 * rhs.e1i.0)
+				+ E12oi(self.e23i.0 * rhs.e2o.0)
+				+ E12oi(self.e23o.0 * rhs.e2i.0)
+				- E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: E13oi(self.e123.0 * rhs.e13.0) - E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e12o.0 * rhs.e1i.0)
+				+ E13oi(self.e23i.0 * rhs.e3o.0)
+				+ E13oi(self.e23o.0 * rhs.e3i.0)
+				+ E13oi(self.e2oi.0 * rhs.eoi.0),
+			e23oi: E23oi(self.e123.0 * rhs.e23.0)
+				- E23oi(self.e12i.0 * rhs.e2o.0)
+				- E23oi(self.e12o.0 * rhs.e2i.0)
+				- E23oi(self.e13i.0 * rhs.e3o.0)
+				- E23oi(self.e13o.0 * rhs.e3i.0)
+				- E23oi(self.e1oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Circle.dot(PointPair) -> Point
+impl Dot<PointPair> for Circle {
+	type Output = Point;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e123.0 * rhs.e23.0) + E1(self.e12i.0 * rhs.e2o.0) + E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     y  : E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     z  : -E3(self.e123.0 * rhs.e12.0) - E3(self.e13i.0 * rhs.e1o.0) - E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23i.0 * rhs.e2o.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     o  : -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e23o.0 * rhs.e23.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     inf: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: -E1(self.e123.0 * rhs.e23.0)
+				+ E1(self.e12i.0 * rhs.e2o.0)
+				+ E1(self.e12o.0 * rhs.e2i.0)
+				+ E1(self.e13i.0 * rhs.e3o.0)
+				+ E1(self.e13o.0 * rhs.e3i.0)
+				+ E1(self.e1oi.0 * rhs.eoi.0),
+			y: E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e23o.0 * rhs.e3i.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0),
+			z: -E3(self.e123.0 * rhs.e12.0)
+				- E3(self.e13i.0 * rhs.e1o.0)
+				- E3(self.e13o.0 * rhs.e1i.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				- E3(self.e23o.0 * rhs.e2i.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			o: -Eo(self.e12o.0 * rhs.e12.0)
+				- Eo(self.e13o.0 * rhs.e13.0)
+				- Eo(self.e1oi.0 * rhs.e1o.0)
+				- Eo(self.e23o.0 * rhs.e23.0)
+				- Eo(self.e2oi.0 * rhs.e2o.0)
+				- Eo(self.e3oi.0 * rhs.e3o.0),
+			inf: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<PointPair> for Circle {
+	type Output = Point;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Circle.wedge(PointPair) -> E123oi
+impl Wedge<PointPair> for Circle {
+	type Output = E123oi;
+	fn wedge(self, rhs: PointPair) -> Self::Output {
+		// E123oi(self.e123.0 * rhs.eoi.0) + E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e12o.0 * rhs.e3i.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e13o.0 * rhs.e2i.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e23o.0 * rhs.e1i.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0)
+		self.e123.wedge(rhs.eoi)
+			+ self.e12i.wedge(rhs.e3o)
+			+ self.e12o.wedge(rhs.e3i)
+			+ self.e13i.wedge(rhs.e2o)
+			+ self.e13o.wedge(rhs.e2i)
+			+ self.e1oi.wedge(rhs.e23)
+			+ self.e23i.wedge(rhs.e1o)
+			+ self.e23o.wedge(rhs.e1i)
+			+ self.e2oi.wedge(rhs.e13)
+			+ self.e3oi.wedge(rhs.e12)
+	}
+}
+
+impl std::ops::BitXor<PointPair> for Circle {
+	type Output = E123oi;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Circle.anti_wedge(PointPair) -> S
+impl AntiWedge<PointPair> for Circle {
+	type Output = S;
+	fn anti_wedge(self, rhs: PointPair) -> Self::Output {
+		// S(self.e123.0 * rhs.eoi.0) + S(self.e12i.0 * rhs.e3o.0) - S(self.e12o.0 * rhs.e3i.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e13o.0 * rhs.e2i.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e23o.0 * rhs.e1i.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0)
+		self.e123.anti_wedge(rhs.eoi)
+			+ self.e12i.anti_wedge(rhs.e3o)
+			+ self.e12o.anti_wedge(rhs.e3i)
+			+ self.e13i.anti_wedge(rhs.e2o)
+			+ self.e13o.anti_wedge(rhs.e2i)
+			+ self.e1oi.anti_wedge(rhs.e23)
+			+ self.e23i.anti_wedge(rhs.e1o)
+			+ self.e23o.anti_wedge(rhs.e1i)
+			+ self.e2oi.anti_wedge(rhs.e13)
+			+ self.e3oi.anti_wedge(rhs.e12)
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for Circle {
+	type Output = S;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<PointPair> for Circle {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.right_contraction(PointPair) -> Point
+impl RightContraction<PointPair> for Circle {
+	type Output = Point;
+	fn right_contraction(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e123.0 * rhs.e23.0) + E1(self.e12i.0 * rhs.e2o.0) + E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     y  : E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     z  : -E3(self.e123.0 * rhs.e12.0) - E3(self.e13i.0 * rhs.e1o.0) - E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23i.0 * rhs.e2o.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     o  : -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e23o.0 * rhs.e23.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     inf: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: -E1(self.e123.0 * rhs.e23.0)
+				+ E1(self.e12i.0 * rhs.e2o.0)
+				+ E1(self.e12o.0 * rhs.e2i.0)
+				+ E1(self.e13i.0 * rhs.e3o.0)
+				+ E1(self.e13o.0 * rhs.e3i.0)
+				+ E1(self.e1oi.0 * rhs.eoi.0),
+			y: E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e23o.0 * rhs.e3i.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0),
+			z: -E3(self.e123.0 * rhs.e12.0)
+				- E3(self.e13i.0 * rhs.e1o.0)
+				- E3(self.e13o.0 * rhs.e1i.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				- E3(self.e23o.0 * rhs.e2i.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			o: -Eo(self.e12o.0 * rhs.e12.0)
+				- Eo(self.e13o.0 * rhs.e13.0)
+				- Eo(self.e1oi.0 * rhs.e1o.0)
+				- Eo(self.e23o.0 * rhs.e23.0)
+				- Eo(self.e2oi.0 * rhs.e2o.0)
+				- Eo(self.e3oi.0 * rhs.e3o.0),
+			inf: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl ScalarProduct<PointPair> for Circle {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.commutator(PointPair) -> Circle
+impl Commutator<PointPair> for Circle {
+	type Output = Circle;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13i.0 * rhs.e2o.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23i.0 * rhs.e1o.0) + E123(self.e23o.0 * rhs.e1i.0),
+		//     e12o: E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0) - E12o(self.e13o.0 * rhs.e23.0) - E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e23o.0 * rhs.e13.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+		//     e12i: E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o: -E13o(self.e123.0 * rhs.e2o.0) + E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e13o.0 * rhs.eoi.0) - E13o(self.e1oi.0 * rhs.e3o.0) - E13o(self.e23o.0 * rhs.e12.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i: -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi: E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0) + E1oi(self.e13i.0 * rhs.e3o.0) - E1oi(self.e13o.0 * rhs.e3i.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o: E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0) + E23o(self.e13o.0 * rhs.e12.0) + E23o(self.e23o.0 * rhs.eoi.0) - E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i: E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi: -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e12o.0 * rhs.e1i.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) - E2oi(self.e23o.0 * rhs.e3i.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi: -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e23o.0 * rhs.e2i.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		// }
+		Circle {
+			e123: E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0)
+				- E123(self.e13i.0 * rhs.e2o.0)
+				- E123(self.e13o.0 * rhs.e2i.0)
+				+ E123(self.e23i.0 * rhs.e1o.0)
+				+ E123(self.e23o.0 * rhs.e1i.0),
+			e12o: E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0)
+				- E12o(self.e13o.0 * rhs.e23.0)
+				- E12o(self.e1oi.0 * rhs.e2o.0)
+				+ E12o(self.e23o.0 * rhs.e13.0)
+				+ E12o(self.e2oi.0 * rhs.e1o.0),
+			e12i: E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: -E13o(self.e123.0 * rhs.e2o.0) + E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e13o.0 * rhs.eoi.0)
+				- E13o(self.e1oi.0 * rhs.e3o.0)
+				- E13o(self.e23o.0 * rhs.e12.0)
+				+ E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0)
+				+ E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0) + E1oi(self.e13i.0 * rhs.e3o.0)
+				- E1oi(self.e13o.0 * rhs.e3i.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0)
+				+ E23o(self.e13o.0 * rhs.e12.0)
+				+ E23o(self.e23o.0 * rhs.eoi.0)
+				- E23o(self.e2oi.0 * rhs.e3o.0)
+				+ E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0)
+				- E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e1o.0)
+				+ E2oi(self.e12o.0 * rhs.e1i.0)
+				+ E2oi(self.e1oi.0 * rhs.e12.0)
+				+ E2oi(self.e23i.0 * rhs.e3o.0)
+				- E2oi(self.e23o.0 * rhs.e3i.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e1oi.0 * rhs.e13.0)
+				- E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e23o.0 * rhs.e2i.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Circle.anti_dot(PointPair) -> Sphere
+impl AntiDot<PointPair> for Circle {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.e12o.0 * rhs.e12.0) - E123o(self.e13o.0 * rhs.e13.0) - E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e23o.0 * rhs.e23.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.e123.0 * rhs.e12.0) + E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e23i.0 * rhs.e2o.0) + E12oi(self.e23o.0 * rhs.e2i.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: E13oi(self.e123.0 * rhs.e13.0) - E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e12o.0 * rhs.e1i.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e23o.0 * rhs.e3i.0) + E13oi(self.e2oi.0 * rhs.eoi.0),
+		//     e23oi: E23oi(self.e123.0 * rhs.e23.0) - E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e13o.0 * rhs.e3i.0) - E23oi(self.e1oi.0 * rhs.eoi.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.e12o.0 * rhs.e12.0)
+				- E123o(self.e13o.0 * rhs.e13.0)
+				- E123o(self.e1oi.0 * rhs.e1o.0)
+				- E123o(self.e23o.0 * rhs.e23.0)
+				- E123o(self.e2oi.0 * rhs.e2o.0)
+				- E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) - E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e123.0 * rhs.e12.0)
+				+ E12oi(self.e13i.0 * rhs.e1o.0)
+				+ E12oi(self.e13o.0 * rhs.e1i.0)
+				+ E12oi(self.e23i.0 * rhs.e2o.0)
+				+ E12oi(self.e23o.0 * rhs.e2i.0)
+				- E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: E13oi(self.e123.0 * rhs.e13.0) - E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e12o.0 * rhs.e1i.0)
+				+ E13oi(self.e23i.0 * rhs.e3o.0)
+				+ E13oi(self.e23o.0 * rhs.e3i.0)
+				+ E13oi(self.e2oi.0 * rhs.eoi.0),
+			e23oi: E23oi(self.e123.0 * rhs.e23.0)
+				- E23oi(self.e12i.0 * rhs.e2o.0)
+				- E23oi(self.e12o.0 * rhs.e2i.0)
+				- E23oi(self.e13i.0 * rhs.e3o.0)
+				- E23oi(self.e13o.0 * rhs.e3i.0)
+				- E23oi(self.e1oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP Line:
+
+// Circle.geometric(Line) -> Versor
+impl Geometric<Line> for Circle {
+	type Output = Versor;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12o.0 * rhs.e12i.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e12  : E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e23o.0 * rhs.e13i.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+		//     e13  : -E13(self.e12o.0 * rhs.e23i.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23o.0 * rhs.e12i.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o  : -E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3oi.0),
+		//     e1i  : -E1i(self.e123.0 * rhs.e23i.0) + E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23  : E23(self.e12o.0 * rhs.e13i.0) - E23(self.e13o.0 * rhs.e12i.0) + E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o  : E2o(self.e12o.0 * rhs.e1oi.0) - E2o(self.e23o.0 * rhs.e3oi.0),
+		//     e2i  : E2i(self.e123.0 * rhs.e13i.0) - E2i(self.e12i.0 * rhs.e1oi.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o  : E3o(self.e13o.0 * rhs.e1oi.0) + E3o(self.e23o.0 * rhs.e2oi.0),
+		//     e3i  : -E3i(self.e123.0 * rhs.e12i.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0),
+		//     eoi  : -Eoi(self.e12o.0 * rhs.e12i.0) - Eoi(self.e13o.0 * rhs.e13i.0) - Eoi(self.e23o.0 * rhs.e23i.0),
+		//     e123o: -E123o(self.e12o.0 * rhs.e3oi.0) + E123o(self.e13o.0 * rhs.e2oi.0) - E123o(self.e23o.0 * rhs.e1oi.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e3oi.0) - E123i(self.e13i.0 * rhs.e2oi.0) + E123i(self.e1oi.0 * rhs.e23i.0) + E123i(self.e23i.0 * rhs.e1oi.0) - E123i(self.e2oi.0 * rhs.e13i.0) + E123i(self.e3oi.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.e123.0 * rhs.e3oi.0) - E12oi(self.e13o.0 * rhs.e23i.0) + E12oi(self.e23o.0 * rhs.e13i.0),
+		//     e13oi: -E13oi(self.e123.0 * rhs.e2oi.0) + E13oi(self.e12o.0 * rhs.e23i.0) - E13oi(self.e23o.0 * rhs.e12i.0),
+		//     e23oi: E23oi(self.e123.0 * rhs.e1oi.0) - E23oi(self.e12o.0 * rhs.e13i.0) + E23oi(self.e13o.0 * rhs.e12i.0),
+		// }
+		Versor {
+			s: S(self.e12o.0 * rhs.e12i.0)
+				+ S(self.e13o.0 * rhs.e13i.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23o.0 * rhs.e23i.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e12: E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0)
+				- E12(self.e23o.0 * rhs.e13i.0)
+				- E12(self.e2oi.0 * rhs.e1oi.0),
+			e13: -E13(self.e12o.0 * rhs.e23i.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23o.0 * rhs.e12i.0)
+				- E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: -E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3oi.0),
+			e1i: -E1i(self.e123.0 * rhs.e23i.0) + E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e12o.0 * rhs.e13i.0) - E23(self.e13o.0 * rhs.e12i.0) + E23(self.e2oi.0 * rhs.e3oi.0)
+				- E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: E2o(self.e12o.0 * rhs.e1oi.0) - E2o(self.e23o.0 * rhs.e3oi.0),
+			e2i: E2i(self.e123.0 * rhs.e13i.0) - E2i(self.e12i.0 * rhs.e1oi.0)
+				+ E2i(self.e1oi.0 * rhs.e12i.0)
+				+ E2i(self.e23i.0 * rhs.e3oi.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: E3o(self.e13o.0 * rhs.e1oi.0) + E3o(self.e23o.0 * rhs.e2oi.0),
+			e3i: -E3i(self.e123.0 * rhs.e12i.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0)
+				- E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0),
+			eoi: -Eoi(self.e12o.0 * rhs.e12i.0) - Eoi(self.e13o.0 * rhs.e13i.0) - Eoi(self.e23o.0 * rhs.e23i.0),
+			e123o: -E123o(self.e12o.0 * rhs.e3oi.0) + E123o(self.e13o.0 * rhs.e2oi.0) - E123o(self.e23o.0 * rhs.e1oi.0),
+			e123i: E123i(self.e12i.0 * rhs.e3oi.0) - E123i(self.e13i.0 * rhs.e2oi.0)
+				+ E123i(self.e1oi.0 * rhs.e23i.0)
+				+ E123i(self.e23i.0 * rhs.e1oi.0)
+				- E123i(self.e2oi.0 * rhs.e13i.0)
+				+ E123i(self.e3oi.0 * rhs.e12i.0),
+			e12oi: E12oi(self.e123.0 * rhs.e3oi.0) - E12oi(self.e13o.0 * rhs.e23i.0) + E12oi(self.e23o.0 * rhs.e13i.0),
+			e13oi: -E13oi(self.e123.0 * rhs.e2oi.0) + E13oi(self.e12o.0 * rhs.e23i.0) - E13oi(self.e23o.0 * rhs.e12i.0),
+			e23oi: E23oi(self.e123.0 * rhs.e1oi.0) - E23oi(self.e12o.0 * rhs.e13i.0) + E23oi(self.e13o.0 * rhs.e12i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Circle {
+	type Output = Versor;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(Line) -> Multivector
+impl AntiGeometric<Line> for Circle {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123.0 * rhs.e1oi.0) - E1(self.e12o.0 * rhs.e13i.0) + E1(self.e13o.0 * rhs.e12i.0),
+		//     e2    : E2(self.e123.0 * rhs.e2oi.0) - E2(self.e12o.0 * rhs.e23i.0) + E2(self.e23o.0 * rhs.e12i.0),
+		//     e3    : E3(self.e123.0 * rhs.e3oi.0) - E3(self.e13o.0 * rhs.e23i.0) + E3(self.e23o.0 * rhs.e13i.0),
+		//     eo    : Eo(self.e12o.0 * rhs.e3oi.0) - Eo(self.e13o.0 * rhs.e2oi.0) + Eo(self.e23o.0 * rhs.e1oi.0),
+		//     ei    : Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e12o.0 * rhs.e12i.0) - E123(self.e13o.0 * rhs.e13i.0) - E123(self.e23o.0 * rhs.e23i.0),
+		//     e12o  : -E12o(self.e13o.0 * rhs.e1oi.0) - E12o(self.e23o.0 * rhs.e2oi.0),
+		//     e12i  : -E12i(self.e123.0 * rhs.e12i.0) - E12i(self.e13i.0 * rhs.e1oi.0) + E12i(self.e1oi.0 * rhs.e13i.0) - E12i(self.e23i.0 * rhs.e2oi.0) + E12i(self.e2oi.0 * rhs.e23i.0),
+		//     e13o  : E13o(self.e12o.0 * rhs.e1oi.0) - E13o(self.e23o.0 * rhs.e3oi.0),
+		//     e13i  : -E13i(self.e123.0 * rhs.e13i.0) + E13i(self.e12i.0 * rhs.e1oi.0) - E13i(self.e1oi.0 * rhs.e12i.0) - E13i(self.e23i.0 * rhs.e3oi.0) + E13i(self.e3oi.0 * rhs.e23i.0),
+		//     e1oi  : -E1oi(self.e12o.0 * rhs.e13i.0) + E1oi(self.e13o.0 * rhs.e12i.0) - E1oi(self.e2oi.0 * rhs.e3oi.0) + E1oi(self.e3oi.0 * rhs.e2oi.0),
+		//     e23o  : E23o(self.e12o.0 * rhs.e2oi.0) + E23o(self.e13o.0 * rhs.e3oi.0),
+		//     e23i  : -E23i(self.e123.0 * rhs.e23i.0) + E23i(self.e12i.0 * rhs.e2oi.0) + E23i(self.e13i.0 * rhs.e3oi.0) - E23i(self.e2oi.0 * rhs.e12i.0) - E23i(self.e3oi.0 * rhs.e13i.0),
+		//     e2oi  : -E2oi(self.e12o.0 * rhs.e23i.0) + E2oi(self.e1oi.0 * rhs.e3oi.0) + E2oi(self.e23o.0 * rhs.e12i.0) - E2oi(self.e3oi.0 * rhs.e1oi.0),
+		//     e3oi  : -E3oi(self.e13o.0 * rhs.e23i.0) - E3oi(self.e1oi.0 * rhs.e2oi.0) + E3oi(self.e23o.0 * rhs.e13i.0) + E3oi(self.e2oi.0 * rhs.e1oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e12o.0 * rhs.e12i.0) - E123oi(self.e13o.0 * rhs.e13i.0) - E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e23o.0 * rhs.e23i.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123.0 * rhs.e1oi.0) - E1(self.e12o.0 * rhs.e13i.0) + E1(self.e13o.0 * rhs.e12i.0),
+			e2: E2(self.e123.0 * rhs.e2oi.0) - E2(self.e12o.0 * rhs.e23i.0) + E2(self.e23o.0 * rhs.e12i.0),
+			e3: E3(self.e123.0 * rhs.e3oi.0) - E3(self.e13o.0 * rhs.e23i.0) + E3(self.e23o.0 * rhs.e13i.0),
+			eo: Eo(self.e12o.0 * rhs.e3oi.0) - Eo(self.e13o.0 * rhs.e2oi.0) + Eo(self.e23o.0 * rhs.e1oi.0),
+			ei: Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0)
+				+ Ei(self.e1oi.0 * rhs.e23i.0)
+				+ Ei(self.e23i.0 * rhs.e1oi.0)
+				- Ei(self.e2oi.0 * rhs.e13i.0)
+				+ Ei(self.e3oi.0 * rhs.e12i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e12o.0 * rhs.e12i.0) - E123(self.e13o.0 * rhs.e13i.0) - E123(self.e23o.0 * rhs.e23i.0),
+			e12o: -E12o(self.e13o.0 * rhs.e1oi.0) - E12o(self.e23o.0 * rhs.e2oi.0),
+			e12i: -E12i(self.e123.0 * rhs.e12i.0) - E12i(self.e13i.0 * rhs.e1oi.0) + E12i(self.e1oi.0 * rhs.e13i.0)
+				- E12i(self.e23i.0 * rhs.e2oi.0)
+				+ E12i(self.e2oi.0 * rhs.e23i.0),
+			e13o: E13o(self.e12o.0 * rhs.e1oi.0) - E13o(self.e23o.0 * rhs.e3oi.0),
+			e13i: -E13i(self.e123.0 * rhs.e13i.0) + E13i(self.e12i.0 * rhs.e1oi.0)
+				- E13i(self.e1oi.0 * rhs.e12i.0)
+				- E13i(self.e23i.0 * rhs.e3oi.0)
+				+ E13i(self.e3oi.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.e12o.0 * rhs.e13i.0) + E1oi(self.e13o.0 * rhs.e12i.0) - E1oi(self.e2oi.0 * rhs.e3oi.0)
+				+ E1oi(self.e3oi.0 * rhs.e2oi.0),
+			e23o: E23o(self.e12o.0 * rhs.e2oi.0) + E23o(self.e13o.0 * rhs.e3oi.0),
+			e23i: -E23i(self.e123.0 * rhs.e23i.0) + E23i(self.e12i.0 * rhs.e2oi.0) + E23i(self.e13i.0 * rhs.e3oi.0)
+				- E23i(self.e2oi.0 * rhs.e12i.0)
+				- E23i(self.e3oi.0 * rhs.e13i.0),
+			e2oi: -E2oi(self.e12o.0 * rhs.e23i.0) + E2oi(self.e1oi.0 * rhs.e3oi.0) + E2oi(self.e23o.0 * rhs.e12i.0)
+				- E2oi(self.e3oi.0 * rhs.e1oi.0),
+			e3oi: -E3oi(self.e13o.0 * rhs.e23i.0) - E3oi(self.e1oi.0 * rhs.e2oi.0)
+				+ E3oi(self.e23o.0 * rhs.e13i.0)
+				+ E3oi(self.e2oi.0 * rhs.e1oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e12o.0 * rhs.e12i.0)
+				- E123oi(self.e13o.0 * rhs.e13i.0)
+				- E123oi(self.e1oi.0 * rhs.e1oi.0)
+				- E123oi(self.e23o.0 * rhs.e23i.0)
+				- E123oi(self.e2oi.0 * rhs.e2oi.0)
+				- E123oi(self.e3oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Circle.dot(Line) -> S
+impl Dot<Line> for Circle {
+	type Output = S;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// S(self.e12o.0 * rhs.e12i.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e12o.0 * rhs.e12i.0)
+			+ S(self.e13o.0 * rhs.e13i.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23o.0 * rhs.e23i.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+impl std::ops::BitOr<Line> for Circle {
+	type Output = S;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Line> for Circle {
+	type Output = Zero;
+	fn wedge(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Line> for Circle {
+	type Output = Zero;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Circle.anti_wedge(Line) -> Point
+impl AntiWedge<Line> for Circle {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123.0 * rhs.e1oi.0) - E1(self.e12o.0 * rhs.e13i.0) + E1(self.e13o.0 * rhs.e12i.0),
+		//     y  : E2(self.e123.0 * rhs.e2oi.0) - E2(self.e12o.0 * rhs.e23i.0) + E2(self.e23o.0 * rhs.e12i.0),
+		//     z  : E3(self.e123.0 * rhs.e3oi.0) - E3(self.e13o.0 * rhs.e23i.0) + E3(self.e23o.0 * rhs.e13i.0),
+		//     o  : Eo(self.e12o.0 * rhs.e3oi.0) - Eo(self.e13o.0 * rhs.e2oi.0) + Eo(self.e23o.0 * rhs.e1oi.0),
+		//     inf: Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		// }
+		Point {
+			x: self.e123.anti_wedge(rhs.e1oi) + self.e12o.anti_wedge(rhs.e13i) + self.e13o.anti_wedge(rhs.e12i),
+			y: self.e123.anti_wedge(rhs.e2oi) + self.e12o.anti_wedge(rhs.e23i) + self.e23o.anti_wedge(rhs.e12i),
+			z: self.e123.anti_wedge(rhs.e3oi) + self.e13o.anti_wedge(rhs.e23i) + self.e23o.anti_wedge(rhs.e13i),
+			o: self.e12o.anti_wedge(rhs.e3oi) + self.e13o.anti_wedge(rhs.e2oi) + self.e23o.anti_wedge(rhs.e1oi),
+			inf: self.e12i.anti_wedge(rhs.e3oi)
+				+ self.e13i.anti_wedge(rhs.e2oi)
+				+ self.e1oi.anti_wedge(rhs.e23i)
+				+ self.e23i.anti_wedge(rhs.e1oi)
+				+ self.e2oi.anti_wedge(rhs.e13i)
+				+ self.e3oi.anti_wedge(rhs.e12i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Circle {
+	type Output = Point;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Circle.left_contraction(Line) -> S
+impl LeftContraction<Line> for Circle {
+	type Output = S;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// S(self.e12o.0 * rhs.e12i.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e12o.0 * rhs.e12i.0)
+			+ S(self.e13o.0 * rhs.e13i.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23o.0 * rhs.e23i.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Circle.right_contraction(Line) -> S
+impl RightContraction<Line> for Circle {
+	type Output = S;
+	fn right_contraction(self, rhs: Line) -> Self::Output {
+		// S(self.e12o.0 * rhs.e12i.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e12o.0 * rhs.e12i.0)
+			+ S(self.e13o.0 * rhs.e13i.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23o.0 * rhs.e23i.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Circle.scalar_product(Line) -> S
+impl ScalarProduct<Line> for Circle {
+	type Output = S;
+	fn scalar_product(self, rhs: Line) -> Self::Output {
+		// S(self.e12o.0 * rhs.e12i.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e12o.0 * rhs.e12i.0)
+			+ S(self.e13o.0 * rhs.e13i.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23o.0 * rhs.e23i.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Circle.commutator(Line) -> PointPair
+impl Commutator<Line> for Circle {
+	type Output = PointPair;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e23o.0 * rhs.e13i.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+		//     e13: -E13(self.e12o.0 * rhs.e23i.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23o.0 * rhs.e12i.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o: -E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3oi.0),
+		//     e1i: -E1i(self.e123.0 * rhs.e23i.0) + E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23: E23(self.e12o.0 * rhs.e13i.0) - E23(self.e13o.0 * rhs.e12i.0) + E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o: E2o(self.e12o.0 * rhs.e1oi.0) - E2o(self.e23o.0 * rhs.e3oi.0),
+		//     e2i: E2i(self.e123.0 * rhs.e13i.0) - E2i(self.e12i.0 * rhs.e1oi.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o: E3o(self.e13o.0 * rhs.e1oi.0) + E3o(self.e23o.0 * rhs.e2oi.0),
+		//     e3i: -E3i(self.e123.0 * rhs.e12i.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0),
+		//     eoi: -Eoi(self.e12o.0 * rhs.e12i.0) - Eoi(self.e13o.0 * rhs.e13i.0) - Eoi(self.e23o.0 * rhs.e23i.0),
+		// }
+		PointPair {
+			e12: E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0)
+				- E12(self.e23o.0 * rhs.e13i.0)
+				- E12(self.e2oi.0 * rhs.e1oi.0),
+			e13: -E13(self.e12o.0 * rhs.e23i.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23o.0 * rhs.e12i.0)
+				- E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: -E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3oi.0),
+			e1i: -E1i(self.e123.0 * rhs.e23i.0) + E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e12o.0 * rhs.e13i.0) - E23(self.e13o.0 * rhs.e12i.0) + E23(self.e2oi.0 * rhs.e3oi.0)
+				- E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: E2o(self.e12o.0 * rhs.e1oi.0) - E2o(self.e23o.0 * rhs.e3oi.0),
+			e2i: E2i(self.e123.0 * rhs.e13i.0) - E2i(self.e12i.0 * rhs.e1oi.0)
+				+ E2i(self.e1oi.0 * rhs.e12i.0)
+				+ E2i(self.e23i.0 * rhs.e3oi.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: E3o(self.e13o.0 * rhs.e1oi.0) + E3o(self.e23o.0 * rhs.e2oi.0),
+			e3i: -E3i(self.e123.0 * rhs.e12i.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0)
+				- E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0),
+			eoi: -Eoi(self.e12o.0 * rhs.e12i.0) - Eoi(self.e13o.0 * rhs.e13i.0) - Eoi(self.e23o.0 * rhs.e23i.0),
+		}
+	}
+}
+
+// Circle.anti_dot(Line) -> E123oi
+impl AntiDot<Line> for Circle {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// -E123oi(self.e12o.0 * rhs.e12i.0) - E123oi(self.e13o.0 * rhs.e13i.0) - E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e23o.0 * rhs.e23i.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0)
+		-E123oi(self.e12o.0 * rhs.e12i.0)
+			- E123oi(self.e13o.0 * rhs.e13i.0)
+			- E123oi(self.e1oi.0 * rhs.e1oi.0)
+			- E123oi(self.e23o.0 * rhs.e23i.0)
+			- E123oi(self.e2oi.0 * rhs.e2oi.0)
+			- E123oi(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP Circle:
+
+// Circle.geometric(Circle) -> Versor
+impl Geometric<Circle> for Circle {
+	type Output = Versor;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123.0 * rhs.e123.0) + S(self.e12i.0 * rhs.e12o.0) + S(self.e12o.0 * rhs.e12i.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e12  : E12(self.e13i.0 * rhs.e23o.0) + E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e23i.0 * rhs.e13o.0) - E12(self.e23o.0 * rhs.e13i.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+		//     e13  : -E13(self.e12i.0 * rhs.e23o.0) - E13(self.e12o.0 * rhs.e23i.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0) + E13(self.e23o.0 * rhs.e12i.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o  : -E1o(self.e123.0 * rhs.e23o.0) - E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3oi.0) + E1o(self.e23o.0 * rhs.e123.0) + E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+		//     e1i  : -E1i(self.e123.0 * rhs.e23i.0) + E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e23i.0 * rhs.e123.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23  : E23(self.e12i.0 * rhs.e13o.0) + E23(self.e12o.0 * rhs.e13i.0) - E23(self.e13i.0 * rhs.e12o.0) - E23(self.e13o.0 * rhs.e12i.0) + E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o  : E2o(self.e123.0 * rhs.e13o.0) + E2o(self.e12o.0 * rhs.e1oi.0) - E2o(self.e13o.0 * rhs.e123.0) - E2o(self.e1oi.0 * rhs.e12o.0) - E2o(self.e23o.0 * rhs.e3oi.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+		//     e2i  : E2i(self.e123.0 * rhs.e13i.0) - E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o  : -E3o(self.e123.0 * rhs.e12o.0) + E3o(self.e12o.0 * rhs.e123.0) + E3o(self.e13o.0 * rhs.e1oi.0) - E3o(self.e1oi.0 * rhs.e13o.0) + E3o(self.e23o.0 * rhs.e2oi.0) - E3o(self.e2oi.0 * rhs.e23o.0),
+		//     e3i  : -E3i(self.e123.0 * rhs.e12i.0) + E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0),
+		//     eoi  : Eoi(self.e12i.0 * rhs.e12o.0) - Eoi(self.e12o.0 * rhs.e12i.0) + Eoi(self.e13i.0 * rhs.e13o.0) - Eoi(self.e13o.0 * rhs.e13i.0) + Eoi(self.e23i.0 * rhs.e23o.0) - Eoi(self.e23o.0 * rhs.e23i.0),
+		//     e123o: -E123o(self.e12o.0 * rhs.e3oi.0) + E123o(self.e13o.0 * rhs.e2oi.0) - E123o(self.e1oi.0 * rhs.e23o.0) - E123o(self.e23o.0 * rhs.e1oi.0) + E123o(self.e2oi.0 * rhs.e13o.0) - E123o(self.e3oi.0 * rhs.e12o.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e3oi.0) - E123i(self.e13i.0 * rhs.e2oi.0) + E123i(self.e1oi.0 * rhs.e23i.0) + E123i(self.e23i.0 * rhs.e1oi.0) - E123i(self.e2oi.0 * rhs.e13i.0) + E123i(self.e3oi.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.e123.0 * rhs.e3oi.0) + E12oi(self.e13i.0 * rhs.e23o.0) - E12oi(self.e13o.0 * rhs.e23i.0) - E12oi(self.e23i.0 * rhs.e13o.0) + E12oi(self.e23o.0 * rhs.e13i.0) + E12oi(self.e3oi.0 * rhs.e123.0),
+		//     e13oi: -E13oi(self.e123.0 * rhs.e2oi.0) - E13oi(self.e12i.0 * rhs.e23o.0) + E13oi(self.e12o.0 * rhs.e23i.0) + E13oi(self.e23i.0 * rhs.e12o.0) - E13oi(self.e23o.0 * rhs.e12i.0) - E13oi(self.e2oi.0 * rhs.e123.0),
+		//     e23oi: E23oi(self.e123.0 * rhs.e1oi.0) + E23oi(self.e12i.0 * rhs.e13o.0) - E23oi(self.e12o.0 * rhs.e13i.0) - E23oi(self.e13i.0 * rhs.e12o.0) + E23oi(self.e13o.0 * rhs.e12i.0) + E23oi(self.e1oi.0 * rhs.e123.0),
+		// }
+		Versor {
+			s: -S(self.e123.0 * rhs.e123.0)
+				+ S(self.e12i.0 * rhs.e12o.0)
+				+ S(self.e12o.0 * rhs.e12i.0)
+				+ S(self.e13i.0 * rhs.e13o.0)
+				+ S(self.e13o.0 * rhs.e13i.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23i.0 * rhs.e23o.0)
+				+ S(self.e23o.0 * rhs.e23i.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e12: E12(self.e13i.0 * rhs.e23o.0) + E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0)
+				- E12(self.e23i.0 * rhs.e13o.0)
+				- E12(self.e23o.0 * rhs.e13i.0)
+				- E12(self.e2oi.0 * rhs.e1oi.0),
+			e13: -E13(self.e12i.0 * rhs.e23o.0) - E13(self.e12o.0 * rhs.e23i.0)
+				+ E13(self.e1oi.0 * rhs.e3oi.0)
+				+ E13(self.e23i.0 * rhs.e12o.0)
+				+ E13(self.e23o.0 * rhs.e12i.0)
+				- E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: -E1o(self.e123.0 * rhs.e23o.0) - E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3oi.0)
+				+ E1o(self.e23o.0 * rhs.e123.0)
+				+ E1o(self.e2oi.0 * rhs.e12o.0)
+				+ E1o(self.e3oi.0 * rhs.e13o.0),
+			e1i: -E1i(self.e123.0 * rhs.e23i.0)
+				+ E1i(self.e12i.0 * rhs.e2oi.0)
+				+ E1i(self.e13i.0 * rhs.e3oi.0)
+				+ E1i(self.e23i.0 * rhs.e123.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e12i.0 * rhs.e13o.0) + E23(self.e12o.0 * rhs.e13i.0)
+				- E23(self.e13i.0 * rhs.e12o.0)
+				- E23(self.e13o.0 * rhs.e12i.0)
+				+ E23(self.e2oi.0 * rhs.e3oi.0)
+				- E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: E2o(self.e123.0 * rhs.e13o.0) + E2o(self.e12o.0 * rhs.e1oi.0)
+				- E2o(self.e13o.0 * rhs.e123.0)
+				- E2o(self.e1oi.0 * rhs.e12o.0)
+				- E2o(self.e23o.0 * rhs.e3oi.0)
+				+ E2o(self.e3oi.0 * rhs.e23o.0),
+			e2i: E2i(self.e123.0 * rhs.e13i.0) - E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0)
+				+ E2i(self.e1oi.0 * rhs.e12i.0)
+				+ E2i(self.e23i.0 * rhs.e3oi.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: -E3o(self.e123.0 * rhs.e12o.0) + E3o(self.e12o.0 * rhs.e123.0) + E3o(self.e13o.0 * rhs.e1oi.0)
+				- E3o(self.e1oi.0 * rhs.e13o.0)
+				+ E3o(self.e23o.0 * rhs.e2oi.0)
+				- E3o(self.e2oi.0 * rhs.e23o.0),
+			e3i: -E3i(self.e123.0 * rhs.e12i.0) + E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0)
+				+ E3i(self.e1oi.0 * rhs.e13i.0)
+				- E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0),
+			eoi: Eoi(self.e12i.0 * rhs.e12o.0) - Eoi(self.e12o.0 * rhs.e12i.0) + Eoi(self.e13i.0 * rhs.e13o.0)
+				- Eoi(self.e13o.0 * rhs.e13i.0)
+				+ Eoi(self.e23i.0 * rhs.e23o.0)
+				- Eoi(self.e23o.0 * rhs.e23i.0),
+			e123o: -E123o(self.e12o.0 * rhs.e3oi.0) + E123o(self.e13o.0 * rhs.e2oi.0)
+				- E123o(self.e1oi.0 * rhs.e23o.0)
+				- E123o(self.e23o.0 * rhs.e1oi.0)
+				+ E123o(self.e2oi.0 * rhs.e13o.0)
+				- E123o(self.e3oi.0 * rhs.e12o.0),
+			e123i: E123i(self.e12i.0 * rhs.e3oi.0) - E123i(self.e13i.0 * rhs.e2oi.0)
+				+ E123i(self.e1oi.0 * rhs.e23i.0)
+				+ E123i(self.e23i.0 * rhs.e1oi.0)
+				- E123i(self.e2oi.0 * rhs.e13i.0)
+				+ E123i(self.e3oi.0 * rhs.e12i.0),
+			e12oi: E12oi(self.e123.0 * rhs.e3oi.0) + E12oi(self.e13i.0 * rhs.e23o.0)
+				- E12oi(self.e13o.0 * rhs.e23i.0)
+				- E12oi(self.e23i.0 * rhs.e13o.0)
+				+ E12oi(self.e23o.0 * rhs.e13i.0)
+				+ E12oi(self.e3oi.0 * rhs.e123.0),
+			e13oi: -E13oi(self.e123.0 * rhs.e2oi.0) - E13oi(self.e12i.0 * rhs.e23o.0)
+				+ E13oi(self.e12o.0 * rhs.e23i.0)
+				+ E13oi(self.e23i.0 * rhs.e12o.0)
+				- E13oi(self.e23o.0 * rhs.e12i.0)
+				- E13oi(self.e2oi.0 * rhs.e123.0),
+			e23oi: E23oi(self.e123.0 * rhs.e1oi.0) + E23oi(self.e12i.0 * rhs.e13o.0)
+				- E23oi(self.e12o.0 * rhs.e13i.0)
+				- E23oi(self.e13i.0 * rhs.e12o.0)
+				+ E23oi(self.e13o.0 * rhs.e12i.0)
+				+ E23oi(self.e1oi.0 * rhs.e123.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for Circle {
+	type Output = Versor;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(Circle) -> Multivector
+impl AntiGeometric<Circle> for Circle {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123.0 * rhs.e1oi.0) + E1(self.e12i.0 * rhs.e13o.0) - E1(self.e12o.0 * rhs.e13i.0) - E1(self.e13i.0 * rhs.e12o.0) + E1(self.e13o.0 * rhs.e12i.0) + E1(self.e1oi.0 * rhs.e123.0),
+		//     e2    : E2(self.e123.0 * rhs.e2oi.0) + E2(self.e12i.0 * rhs.e23o.0) - E2(self.e12o.0 * rhs.e23i.0) - E2(self.e23i.0 * rhs.e12o.0) + E2(self.e23o.0 * rhs.e12i.0) + E2(self.e2oi.0 * rhs.e123.0),
+		//     e3    : E3(self.e123.0 * rhs.e3oi.0) + E3(self.e13i.0 * rhs.e23o.0) - E3(self.e13o.0 * rhs.e23i.0) - E3(self.e23i.0 * rhs.e13o.0) + E3(self.e23o.0 * rhs.e13i.0) + E3(self.e3oi.0 * rhs.e123.0),
+		//     eo    : Eo(self.e12o.0 * rhs.e3oi.0) - Eo(self.e13o.0 * rhs.e2oi.0) + Eo(self.e1oi.0 * rhs.e23o.0) + Eo(self.e23o.0 * rhs.e1oi.0) - Eo(self.e2oi.0 * rhs.e13o.0) + Eo(self.e3oi.0 * rhs.e12o.0),
+		//     ei    : Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12i.0 * rhs.e12o.0) - E123(self.e12o.0 * rhs.e12i.0) + E123(self.e13i.0 * rhs.e13o.0) - E123(self.e13o.0 * rhs.e13i.0) + E123(self.e23i.0 * rhs.e23o.0) - E123(self.e23o.0 * rhs.e23i.0),
+		//     e12o  : E12o(self.e123.0 * rhs.e12o.0) - E12o(self.e12o.0 * rhs.e123.0) - E12o(self.e13o.0 * rhs.e1oi.0) + E12o(self.e1oi.0 * rhs.e13o.0) - E12o(self.e23o.0 * rhs.e2oi.0) + E12o(self.e2oi.0 * rhs.e23o.0),
+		//     e12i  : -E12i(self.e123.0 * rhs.e12i.0) + E12i(self.e12i.0 * rhs.e123.0) - E12i(self.e13i.0 * rhs.e1oi.0) + E12i(self.e1oi.0 * rhs.e13i.0) - E12i(self.e23i.0 * rhs.e2oi.0) + E12i(self.e2oi.0 * rhs.e23i.0),
+		//     e13o  : E13o(self.e123.0 * rhs.e13o.0) + E13o(self.e12o.0 * rhs.e1oi.0) - E13o(self.e13o.0 * rhs.e123.0) - E13o(self.e1oi.0 * rhs.e12o.0) - E13o(self.e23o.0 * rhs.e3oi.0) + E13o(self.e3oi.0 * rhs.e23o.0),
+		//     e13i  : -E13i(self.e123.0 * rhs.e13i.0) + E13i(self.e12i.0 * rhs.e1oi.0) + E13i(self.e13i.0 * rhs.e123.0) - E13i(self.e1oi.0 * rhs.e12i.0) - E13i(self.e23i.0 * rhs.e3oi.0) + E13i(self.e3oi.0 * rhs.e23i.0),
+		//     e1oi  : -E1oi(self.e12i.0 * rhs.e13o.0) - E1oi(self.e12o.0 * rhs.e13i.0) + E1oi(self.e13i.0 * rhs.e12o.0) + E1oi(self.e13o.0 * rhs.e12i.0) - E1oi(self.e2oi.0 * rhs.e3oi.0) + E1oi(self.e3oi.0 * rhs.e2oi.0),
+		//     e23o  : E23o(self.e123.0 * rhs.e23o.0) + E23o(self.e12o.0 * rhs.e2oi.0) + E23o(self.e13o.0 * rhs.e3oi.0) - E23o(self.e23o.0 * rhs.e123.0) - E23o(self.e2oi.0 * rhs.e12o.0) - E23o(self.e3oi.0 * rhs.e13o.0),
+		//     e23i  : -E23i(self.e123.0 * rhs.e23i.0) + E23i(self.e12i.0 * rhs.e2oi.0) + E23i(self.e13i.0 * rhs.e3oi.0) + E23i(self.e23i.0 * rhs.e123.0) - E23i(self.e2oi.0 * rhs.e12i.0) - E23i(self.e3oi.0 * rhs.e13i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e23o.0) - E2oi(self.e12o.0 * rhs.e23i.0) + E2oi(self.e1oi.0 * rhs.e3oi.0) + E2oi(self.e23i.0 * rhs.e12o.0) + E2oi(self.e23o.0 * rhs.e12i.0) - E2oi(self.e3oi.0 * rhs.e1oi.0),
+		//     e3oi  : -E3oi(self.e13i.0 * rhs.e23o.0) - E3oi(self.e13o.0 * rhs.e23i.0) - E3oi(self.e1oi.0 * rhs.e2oi.0) + E3oi(self.e23i.0 * rhs.e13o.0) + E3oi(self.e23o.0 * rhs.e13i.0) + E3oi(self.e2oi.0 * rhs.e1oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123.0 * rhs.e123.0) - E123oi(self.e12i.0 * rhs.e12o.0) - E123oi(self.e12o.0 * rhs.e12i.0) - E123oi(self.e13i.0 * rhs.e13o.0) - E123oi(self.e13o.0 * rhs.e13i.0) - E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e23i.0 * rhs.e23o.0) - E123oi(self.e23o.0 * rhs.e23i.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123.0 * rhs.e1oi.0) + E1(self.e12i.0 * rhs.e13o.0)
+				- E1(self.e12o.0 * rhs.e13i.0)
+				- E1(self.e13i.0 * rhs.e12o.0)
+				+ E1(self.e13o.0 * rhs.e12i.0)
+				+ E1(self.e1oi.0 * rhs.e123.0),
+			e2: E2(self.e123.0 * rhs.e2oi.0) + E2(self.e12i.0 * rhs.e23o.0)
+				- E2(self.e12o.0 * rhs.e23i.0)
+				- E2(self.e23i.0 * rhs.e12o.0)
+				+ E2(self.e23o.0 * rhs.e12i.0)
+				+ E2(self.e2oi.0 * rhs.e123.0),
+			e3: E3(self.e123.0 * rhs.e3oi.0) + E3(self.e13i.0 * rhs.e23o.0)
+				- E3(self.e13o.0 * rhs.e23i.0)
+				- E3(self.e23i.0 * rhs.e13o.0)
+				+ E3(self.e23o.0 * rhs.e13i.0)
+				+ E3(self.e3oi.0 * rhs.e123.0),
+			eo: Eo(self.e12o.0 * rhs.e3oi.0) - Eo(self.e13o.0 * rhs.e2oi.0)
+				+ Eo(self.e1oi.0 * rhs.e23o.0)
+				+ Eo(self.e23o.0 * rhs.e1oi.0)
+				- Eo(self.e2oi.0 * rhs.e13o.0)
+				+ Eo(self.e3oi.0 * rhs.e12o.0),
+			ei: Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0)
+				+ Ei(self.e1oi.0 * rhs.e23i.0)
+				+ Ei(self.e23i.0 * rhs.e1oi.0)
+				- Ei(self.e2oi.0 * rhs.e13i.0)
+				+ Ei(self.e3oi.0 * rhs.e12i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12i.0 * rhs.e12o.0) - E123(self.e12o.0 * rhs.e12i.0) + E123(self.e13i.0 * rhs.e13o.0)
+				- E123(self.e13o.0 * rhs.e13i.0)
+				+ E123(self.e23i.0 * rhs.e23o.0)
+				- E123(self.e23o.0 * rhs.e23i.0),
+			e12o: E12o(self.e123.0 * rhs.e12o.0) - E12o(self.e12o.0 * rhs.e123.0) - E12o(self.e13o.0 * rhs.e1oi.0)
+				+ E12o(self.e1oi.0 * rhs.e13o.0)
+				- E12o(self.e23o.0 * rhs.e2oi.0)
+				+ E12o(self.e2oi.0 * rhs.e23o.0),
+			e12i: -E12i(self.e123.0 * rhs.e12i.0) + E12i(self.e12i.0 * rhs.e123.0) - E12i(self.e13i.0 * rhs.e1oi.0)
+				+ E12i(self.e1oi.0 * rhs.e13i.0)
+				- E12i(self.e23i.0 * rhs.e2oi.0)
+				+ E12i(self.e2oi.0 * rhs.e23i.0),
+			e13o: E13o(self.e123.0 * rhs.e13o.0) + E13o(self.e12o.0 * rhs.e1oi.0)
+				- E13o(self.e13o.0 * rhs.e123.0)
+				- E13o(self.e1oi.0 * rhs.e12o.0)
+				- E13o(self.e23o.0 * rhs.e3oi.0)
+				+ E13o(self.e3oi.0 * rhs.e23o.0),
+			e13i: -E13i(self.e123.0 * rhs.e13i.0) + E13i(self.e12i.0 * rhs.e1oi.0) + E13i(self.e13i.0 * rhs.e123.0)
+				- E13i(self.e1oi.0 * rhs.e12i.0)
+				- E13i(self.e23i.0 * rhs.e3oi.0)
+				+ E13i(self.e3oi.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.e12i.0 * rhs.e13o.0) - E1oi(self.e12o.0 * rhs.e13i.0)
+				+ E1oi(self.e13i.0 * rhs.e12o.0)
+				+ E1oi(self.e13o.0 * rhs.e12i.0)
+				- E1oi(self.e2oi.0 * rhs.e3oi.0)
+				+ E1oi(self.e3oi.0 * rhs.e2oi.0),
+			e23o: E23o(self.e123.0 * rhs.e23o.0) + E23o(self.e12o.0 * rhs.e2oi.0) + E23o(self.e13o.0 * rhs.e3oi.0)
+				- E23o(self.e23o.0 * rhs.e123.0)
+				- E23o(self.e2oi.0 * rhs.e12o.0)
+				- E23o(self.e3oi.0 * rhs.e13o.0),
+			e23i: -E23i(self.e123.0 * rhs.e23i.0)
+				+ E23i(self.e12i.0 * rhs.e2oi.0)
+				+ E23i(self.e13i.0 * rhs.e3oi.0)
+				+ E23i(self.e23i.0 * rhs.e123.0)
+				- E23i(self.e2oi.0 * rhs.e12i.0)
+				- E23i(self.e3oi.0 * rhs.e13i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e23o.0) - E2oi(self.e12o.0 * rhs.e23i.0)
+				+ E2oi(self.e1oi.0 * rhs.e3oi.0)
+				+ E2oi(self.e23i.0 * rhs.e12o.0)
+				+ E2oi(self.e23o.0 * rhs.e12i.0)
+				- E2oi(self.e3oi.0 * rhs.e1oi.0),
+			e3oi: -E3oi(self.e13i.0 * rhs.e23o.0) - E3oi(self.e13o.0 * rhs.e23i.0) - E3oi(self.e1oi.0 * rhs.e2oi.0)
+				+ E3oi(self.e23i.0 * rhs.e13o.0)
+				+ E3oi(self.e23o.0 * rhs.e13i.0)
+				+ E3oi(self.e2oi.0 * rhs.e1oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123.0 * rhs.e123.0)
+				- E123oi(self.e12i.0 * rhs.e12o.0)
+				- E123oi(self.e12o.0 * rhs.e12i.0)
+				- E123oi(self.e13i.0 * rhs.e13o.0)
+				- E123oi(self.e13o.0 * rhs.e13i.0)
+				- E123oi(self.e1oi.0 * rhs.e1oi.0)
+				- E123oi(self.e23i.0 * rhs.e23o.0)
+				- E123oi(self.e23o.0 * rhs.e23i.0)
+				- E123oi(self.e2oi.0 * rhs.e2oi.0)
+				- E123oi(self.e3oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Circle.dot(Circle) -> S
+impl Dot<Circle> for Circle {
+	type Output = S;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// -S(self.e123.0 * rhs.e123.0) + S(self.e12i.0 * rhs.e12o.0) + S(self.e12o.0 * rhs.e12i.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		-S(self.e123.0 * rhs.e123.0)
+			+ S(self.e12i.0 * rhs.e12o.0)
+			+ S(self.e12o.0 * rhs.e12i.0)
+			+ S(self.e13i.0 * rhs.e13o.0)
+			+ S(self.e13o.0 * rhs.e13i.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23i.0 * rhs.e23o.0)
+			+ S(self.e23o.0 * rhs.e23i.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+impl std::ops::BitOr<Circle> for Circle {
+	type Output = S;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Circle> for Circle {
+	type Output = Zero;
+	fn wedge(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Circle> for Circle {
+	type Output = Zero;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Circle.anti_wedge(Circle) -> Point
+impl AntiWedge<Circle> for Circle {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123.0 * rhs.e1oi.0) + E1(self.e12i.0 * rhs.e13o.0) - E1(self.e12o.0 * rhs.e13i.0) - E1(self.e13i.0 * rhs.e12o.0) + E1(self.e13o.0 * rhs.e12i.0) + E1(self.e1oi.0 * rhs.e123.0),
+		//     y  : E2(self.e123.0 * rhs.e2oi.0) + E2(self.e12i.0 * rhs.e23o.0) - E2(self.e12o.0 * rhs.e23i.0) - E2(self.e23i.0 * rhs.e12o.0) + E2(self.e23o.0 * rhs.e12i.0) + E2(self.e2oi.0 * rhs.e123.0),
+		//     z  : E3(self.e123.0 * rhs.e3oi.0) + E3(self.e13i.0 * rhs.e23o.0) - E3(self.e13o.0 * rhs.e23i.0) - E3(self.e23i.0 * rhs.e13o.0) + E3(self.e23o.0 * rhs.e13i.0) + E3(self.e3oi.0 * rhs.e123.0),
+		//     o  : Eo(self.e12o.0 * rhs.e3oi.0) - Eo(self.e13o.0 * rhs.e2oi.0) + Eo(self.e1oi.0 * rhs.e23o.0) + Eo(self.e23o.0 * rhs.e1oi.0) - Eo(self.e2oi.0 * rhs.e13o.0) + Eo(self.e3oi.0 * rhs.e12o.0),
+		//     inf: Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		// }
+		Point {
+			x: self.e123.anti_wedge(rhs.e1oi)
+				+ self.e12i.anti_wedge(rhs.e13o)
+				+ self.e12o.anti_wedge(rhs.e13i)
+				+ self.e13i.anti_wedge(rhs.e12o)
+				+ self.e13o.anti_wedge(rhs.e12i)
+				+ self.e1oi.anti_wedge(rhs.e123),
+			y: self.e123.anti_wedge(rhs.e2oi)
+				+ self.e12i.anti_wedge(rhs.e23o)
+				+ self.e12o.anti_wedge(rhs.e23i)
+				+ self.e23i.anti_wedge(rhs.e12o)
+				+ self.e23o.anti_wedge(rhs.e12i)
+				+ self.e2oi.anti_wedge(rhs.e123),
+			z: self.e123.anti_wedge(rhs.e3oi)
+				+ self.e13i.anti_wedge(rhs.e23o)
+				+ self.e13o.anti_wedge(rhs.e23i)
+				+ self.e23i.anti_wedge(rhs.e13o)
+				+ self.e23o.anti_wedge(rhs.e13i)
+				+ self.e3oi.anti_wedge(rhs.e123),
+			o: self.e12o.anti_wedge(rhs.e3oi)
+				+ self.e13o.anti_wedge(rhs.e2oi)
+				+ self.e1oi.anti_wedge(rhs.e23o)
+				+ self.e23o.anti_wedge(rhs.e1oi)
+				+ self.e2oi.anti_wedge(rhs.e13o)
+				+ self.e3oi.anti_wedge(rhs.e12o),
+			inf: self.e12i.anti_wedge(rhs.e3oi)
+				+ self.e13i.anti_wedge(rhs.e2oi)
+				+ self.e1oi.anti_wedge(rhs.e23i)
+				+ self.e23i.anti_wedge(rhs.e1oi)
+				+ self.e2oi.anti_wedge(rhs.e13i)
+				+ self.e3oi.anti_wedge(rhs.e12i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Circle> for Circle {
+	type Output = Point;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Circle.left_contraction(Circle) -> S
+impl LeftContraction<Circle> for Circle {
+	type Output = S;
+	fn left_contraction(self, rhs: Circle) -> Self::Output {
+		// -S(self.e123.0 * rhs.e123.0) + S(self.e12i.0 * rhs.e12o.0) + S(self.e12o.0 * rhs.e12i.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		-S(self.e123.0 * rhs.e123.0)
+			+ S(self.e12i.0 * rhs.e12o.0)
+			+ S(self.e12o.0 * rhs.e12i.0)
+			+ S(self.e13i.0 * rhs.e13o.0)
+			+ S(self.e13o.0 * rhs.e13i.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23i.0 * rhs.e23o.0)
+			+ S(self.e23o.0 * rhs.e23i.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Circle.right_contraction(Circle) -> S
+impl RightContraction<Circle> for Circle {
+	type Output = S;
+	fn right_contraction(self, rhs: Circle) -> Self::Output {
+		// -S(self.e123.0 * rhs.e123.0) + S(self.e12i.0 * rhs.e12o.0) + S(self.e12o.0 * rhs.e12i.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		-S(self.e123.0 * rhs.e123.0)
+			+ S(self.e12i.0 * rhs.e12o.0)
+			+ S(self.e12o.0 * rhs.e12i.0)
+			+ S(self.e13i.0 * rhs.e13o.0)
+			+ S(self.e13o.0 * rhs.e13i.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23i.0 * rhs.e23o.0)
+			+ S(self.e23o.0 * rhs.e23i.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Circle.scalar_product(Circle) -> S
+impl ScalarProduct<Circle> for Circle {
+	type Output = S;
+	fn scalar_product(self, rhs: Circle) -> Self::Output {
+		// -S(self.e123.0 * rhs.e123.0) + S(self.e12i.0 * rhs.e12o.0) + S(self.e12o.0 * rhs.e12i.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		-S(self.e123.0 * rhs.e123.0)
+			+ S(self.e12i.0 * rhs.e12o.0)
+			+ S(self.e12o.0 * rhs.e12i.0)
+			+ S(self.e13i.0 * rhs.e13o.0)
+			+ S(self.e13o.0 * rhs.e13i.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23i.0 * rhs.e23o.0)
+			+ S(self.e23o.0 * rhs.e23i.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Circle.commutator(Circle) -> PointPair
+impl Commutator<Circle> for Circle {
+	type Output = PointPair;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e13i.0 * rhs.e23o.0) + E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e23i.0 * rhs.e13o.0) - E12(self.e23o.0 * rhs.e13i.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+		//     e13: -E13(self.e12i.0 * rhs.e23o.0) - E13(self.e12o.0 * rhs.e23i.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0) + E13(self.e23o.0 * rhs.e12i.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o: -E1o(self.e123.0 * rhs.e23o.0) - E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3oi.0) + E1o(self.e23o.0 * rhs.e123.0) + E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+		//     e1i: -E1i(self.e123.0 * rhs.e23i.0) + E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e23i.0 * rhs.e123.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23: E23(self.e12i.0 * rhs.e13o.0) + E23(self.e12o.0 * rhs.e13i.0) - E23(self.e13i.0 * rhs.e12o.0) - E23(self.e13o.0 * rhs.e12i.0) + E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o: E2o(self.e123.0 * rhs.e13o.0) + E2o(self.e12o.0 * rhs.e1oi.0) - E2o(self.e13o.0 * rhs.e123.0) - E2o(self.e1oi.0 * rhs.e12o.0) - E2o(self.e23o.0 * rhs.e3oi.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+		//     e2i: E2i(self.e123.0 * rhs.e13i.0) - E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o: -E3o(self.e123.0 * rhs.e12o.0) + E3o(self.e12o.0 * rhs.e123.0) + E3o(self.e13o.0 * rhs.e1oi.0) - E3o(self.e1oi.0 * rhs.e13o.0) + E3o(self.e23o.0 * rhs.e2oi.0) - E3o(self.e2oi.0 * rhs.e23o.0),
+		//     e3i: -E3i(self.e123.0 * rhs.e12i.0) + E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0),
+		//     eoi: Eoi(self.e12i.0 * rhs.e12o.0) - Eoi(self.e12o.0 * rhs.e12i.0) + Eoi(self.e13i.0 * rhs.e13o.0) - Eoi(self.e13o.0 * rhs.e13i.0) + Eoi(self.e23i.0 * rhs.e23o.0) - Eoi(self.e23o.0 * rhs.e23i.0),
+		// }
+		PointPair {
+			e12: E12(self.e13i.0 * rhs.e23o.0) + E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0)
+				- E12(self.e23i.0 * rhs.e13o.0)
+				- E12(self.e23o.0 * rhs.e13i.0)
+				- E12(self.e2oi.0 * rhs.e1oi.0),
+			e13: -E13(self.e12i.0 * rhs.e23o.0) - E13(self.e12o.0 * rhs.e23i.0)
+				+ E13(self.e1oi.0 * rhs.e3oi.0)
+				+ E13(self.e23i.0 * rhs.e12o.0)
+				+ E13(self.e23o.0 * rhs.e12i.0)
+				- E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: -E1o(self.e123.0 * rhs.e23o.0) - E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3oi.0)
+				+ E1o(self.e23o.0 * rhs.e123.0)
+				+ E1o(self.e2oi.0 * rhs.e12o.0)
+				+ E1o(self.e3oi.0 * rhs.e13o.0),
+			e1i: -E1i(self.e123.0 * rhs.e23i.0)
+				+ E1i(self.e12i.0 * rhs.e2oi.0)
+				+ E1i(self.e13i.0 * rhs.e3oi.0)
+				+ E1i(self.e23i.0 * rhs.e123.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e12i.0 * rhs.e13o.0) + E23(self.e12o.0 * rhs.e13i.0)
+				- E23(self.e13i.0 * rhs.e12o.0)
+				- E23(self.e13o.0 * rhs.e12i.0)
+				+ E23(self.e2oi.0 * rhs.e3oi.0)
+				- E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: E2o(self.e123.0 * rhs.e13o.0) + E2o(self.e12o.0 * rhs.e1oi.0)
+				- E2o(self.e13o.0 * rhs.e123.0)
+				- E2o(self.e1oi.0 * rhs.e12o.0)
+				- E2o(self.e23o.0 * rhs.e3oi.0)
+				+ E2o(self.e3oi.0 * rhs.e23o.0),
+			e2i: E2i(self.e123.0 * rhs.e13i.0) - E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0)
+				+ E2i(self.e1oi.0 * rhs.e12i.0)
+				+ E2i(self.e23i.0 * rhs.e3oi.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: -E3o(self.e123.0 * rhs.e12o.0) + E3o(self.e12o.0 * rhs.e123.0) + E3o(self.e13o.0 * rhs.e1oi.0)
+				- E3o(self.e1oi.0 * rhs.e13o.0)
+				+ E3o(self.e23o.0 * rhs.e2oi.0)
+				- E3o(self.e2oi.0 * rhs.e23o.0),
+			e3i: -E3i(self.e123.0 * rhs.e12i.0) + E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0)
+				+ E3i(self.e1oi.0 * rhs.e13i.0)
+				- E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0),
+			eoi: Eoi(self.e12i.0 * rhs.e12o.0) - Eoi(self.e12o.0 * rhs.e12i.0) + Eoi(self.e13i.0 * rhs.e13o.0)
+				- Eoi(self.e13o.0 * rhs.e13i.0)
+				+ Eoi(self.e23i.0 * rhs.e23o.0)
+				- Eoi(self.e23o.0 * rhs.e23i.0),
+		}
+	}
+}
+
+// Circle.anti_dot(Circle) -> E123oi
+impl AntiDot<Circle> for Circle {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// E123oi(self.e123.0 * rhs.e123.0) - E123oi(self.e12i.0 * rhs.e12o.0) - E123oi(self.e12o.0 * rhs.e12i.0) - E123oi(self.e13i.0 * rhs.e13o.0) - E123oi(self.e13o.0 * rhs.e13i.0) - E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e23i.0 * rhs.e23o.0) - E123oi(self.e23o.0 * rhs.e23i.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0)
+		E123oi(self.e123.0 * rhs.e123.0)
+			- E123oi(self.e12i.0 * rhs.e12o.0)
+			- E123oi(self.e12o.0 * rhs.e12i.0)
+			- E123oi(self.e13i.0 * rhs.e13o.0)
+			- E123oi(self.e13o.0 * rhs.e13i.0)
+			- E123oi(self.e1oi.0 * rhs.e1oi.0)
+			- E123oi(self.e23i.0 * rhs.e23o.0)
+			- E123oi(self.e23o.0 * rhs.e23i.0)
+			- E123oi(self.e2oi.0 * rhs.e2oi.0)
+			- E123oi(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP Plane:
+
+// Circle.geometric(Plane) -> Multivector
+impl Geometric<Plane> for Circle {
+	type Output = Multivector;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     eo    : -Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     ei    : -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e1oi.0 * rhs.e23oi.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+		//     e12o  : -E12o(self.e13o.0 * rhs.e23oi.0) + E12o(self.e23o.0 * rhs.e13oi.0),
+		//     e12i  : E12i(self.e13i.0 * rhs.e23oi.0) - E12i(self.e23i.0 * rhs.e13oi.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e12o.0 * rhs.e23oi.0) - E13o(self.e23o.0 * rhs.e12oi.0),
+		//     e13i  : -E13i(self.e12i.0 * rhs.e23oi.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.e123.0 * rhs.e23oi.0) + E1oi(self.e23o.0 * rhs.e123i.0),
+		//     e23o  : -E23o(self.e12o.0 * rhs.e13oi.0) + E23o(self.e13o.0 * rhs.e12oi.0),
+		//     e23i  : E23i(self.e12i.0 * rhs.e13oi.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e123.0 * rhs.e13oi.0) - E2oi(self.e13o.0 * rhs.e123i.0),
+		//     e3oi  : -E3oi(self.e123.0 * rhs.e12oi.0) + E3oi(self.e12o.0 * rhs.e123i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			eo: -Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+			ei: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e1oi.0 * rhs.e23oi.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+			e12o: -E12o(self.e13o.0 * rhs.e23oi.0) + E12o(self.e23o.0 * rhs.e13oi.0),
+			e12i: E12i(self.e13i.0 * rhs.e23oi.0) - E12i(self.e23i.0 * rhs.e13oi.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+			e13o: E13o(self.e12o.0 * rhs.e23oi.0) - E13o(self.e23o.0 * rhs.e12oi.0),
+			e13i: -E13i(self.e12i.0 * rhs.e23oi.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.e123.0 * rhs.e23oi.0) + E1oi(self.e23o.0 * rhs.e123i.0),
+			e23o: -E23o(self.e12o.0 * rhs.e13oi.0) + E23o(self.e13o.0 * rhs.e12oi.0),
+			e23i: E23i(self.e12i.0 * rhs.e13oi.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e123.0 * rhs.e13oi.0) - E2oi(self.e13o.0 * rhs.e123i.0),
+			e3oi: -E3oi(self.e123.0 * rhs.e12oi.0) + E3oi(self.e12o.0 * rhs.e123i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Circle {
+	type Output = Multivector;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(Plane) -> Versor
+impl AntiGeometric<Plane> for Circle {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123.0 * rhs.e12oi.0) - E12(self.e12o.0 * rhs.e123i.0),
+		//     e13  : E13(self.e123.0 * rhs.e13oi.0) - E13(self.e13o.0 * rhs.e123i.0),
+		//     e1o  : E1o(self.e12o.0 * rhs.e13oi.0) - E1o(self.e13o.0 * rhs.e12oi.0),
+		//     e1i  : E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23  : E23(self.e123.0 * rhs.e23oi.0) - E23(self.e23o.0 * rhs.e123i.0),
+		//     e2o  : E2o(self.e12o.0 * rhs.e23oi.0) - E2o(self.e23o.0 * rhs.e12oi.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.e13o.0 * rhs.e23oi.0) - E3o(self.e23o.0 * rhs.e13oi.0),
+		//     e3i  : E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi  : Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123o: E123o(self.e12o.0 * rhs.e12oi.0) + E123o(self.e13o.0 * rhs.e13oi.0) + E123o(self.e23o.0 * rhs.e23oi.0),
+		//     e123i: -E123i(self.e123.0 * rhs.e123i.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13oi.0) + E123i(self.e23i.0 * rhs.e23oi.0),
+		//     e12oi: -E12oi(self.e12o.0 * rhs.e123i.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e2oi.0 * rhs.e23oi.0),
+		//     e13oi: -E13oi(self.e13o.0 * rhs.e123i.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: -E23oi(self.e23o.0 * rhs.e123i.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123.0 * rhs.e12oi.0) - E12(self.e12o.0 * rhs.e123i.0),
+			e13: E13(self.e123.0 * rhs.e13oi.0) - E13(self.e13o.0 * rhs.e123i.0),
+			e1o: E1o(self.e12o.0 * rhs.e13oi.0) - E1o(self.e13o.0 * rhs.e12oi.0),
+			e1i: E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+			e23: E23(self.e123.0 * rhs.e23oi.0) - E23(self.e23o.0 * rhs.e123i.0),
+			e2o: E2o(self.e12o.0 * rhs.e23oi.0) - E2o(self.e23o.0 * rhs.e12oi.0),
+			e2i: E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+			e3o: E3o(self.e13o.0 * rhs.e23oi.0) - E3o(self.e23o.0 * rhs.e13oi.0),
+			e3i: E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+			eoi: Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+			e123o: E123o(self.e12o.0 * rhs.e12oi.0)
+				+ E123o(self.e13o.0 * rhs.e13oi.0)
+				+ E123o(self.e23o.0 * rhs.e23oi.0),
+			e123i: -E123i(self.e123.0 * rhs.e123i.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0),
+			e12oi: -E12oi(self.e12o.0 * rhs.e123i.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0),
+			e13oi: -E13oi(self.e13o.0 * rhs.e123i.0) - E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e23o.0 * rhs.e123i.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// Circle.dot(Plane) -> Point
+impl Dot<Plane> for Circle {
+	type Output = Point;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : -Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     inf: -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			o: -Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+			inf: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Circle {
+	type Output = Point;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for Circle {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Circle {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Circle.anti_wedge(Plane) -> PointPair
+impl AntiWedge<Plane> for Circle {
+	type Output = PointPair;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123.0 * rhs.e12oi.0) - E12(self.e12o.0 * rhs.e123i.0),
+		//     e13: E13(self.e123.0 * rhs.e13oi.0) - E13(self.e13o.0 * rhs.e123i.0),
+		//     e1o: E1o(self.e12o.0 * rhs.e13oi.0) - E1o(self.e13o.0 * rhs.e12oi.0),
+		//     e1i: E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23: E23(self.e123.0 * rhs.e23oi.0) - E23(self.e23o.0 * rhs.e123i.0),
+		//     e2o: E2o(self.e12o.0 * rhs.e23oi.0) - E2o(self.e23o.0 * rhs.e12oi.0),
+		//     e2i: E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o: E3o(self.e13o.0 * rhs.e23oi.0) - E3o(self.e23o.0 * rhs.e13oi.0),
+		//     e3i: E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		// }
+		PointPair {
+			e12: self.e123.anti_wedge(rhs.e12oi) + self.e12o.anti_wedge(rhs.e123i),
+			e13: self.e123.anti_wedge(rhs.e13oi) + self.e13o.anti_wedge(rhs.e123i),
+			e1o: self.e12o.anti_wedge(rhs.e13oi) + self.e13o.anti_wedge(rhs.e12oi),
+			e1i: self.e12i.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123i),
+			e23: self.e123.anti_wedge(rhs.e23oi) + self.e23o.anti_wedge(rhs.e123i),
+			e2o: self.e12o.anti_wedge(rhs.e23oi) + self.e23o.anti_wedge(rhs.e12oi),
+			e2i: self.e12i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123i),
+			e3o: self.e13o.anti_wedge(rhs.e23oi) + self.e23o.anti_wedge(rhs.e13oi),
+			e3i: self.e13i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123i),
+			eoi: self.e1oi.anti_wedge(rhs.e23oi) + self.e2oi.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e12oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Circle {
+	type Output = PointPair;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Circle.left_contraction(Plane) -> Point
+impl LeftContraction<Plane> for Circle {
+	type Output = Point;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : -Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     inf: -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			o: -Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+			inf: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Plane> for Circle {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for Circle {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.commutator(Plane) -> Point
+impl Commutator<Plane> for Circle {
+	type Output = Point;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : -Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     inf: -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			o: -Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+			inf: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Circle.anti_dot(Plane) -> Sphere
+impl AntiDot<Plane> for Circle {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e12o.0 * rhs.e12oi.0) + E123o(self.e13o.0 * rhs.e13oi.0) + E123o(self.e23o.0 * rhs.e23oi.0),
+		//     e123i: -E123i(self.e123.0 * rhs.e123i.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13oi.0) + E123i(self.e23i.0 * rhs.e23oi.0),
+		//     e12oi: -E12oi(self.e12o.0 * rhs.e123i.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e2oi.0 * rhs.e23oi.0),
+		//     e13oi: -E13oi(self.e13o.0 * rhs.e123i.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: -E23oi(self.e23o.0 * rhs.e123i.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Sphere {
+			e123o: E123o(self.e12o.0 * rhs.e12oi.0)
+				+ E123o(self.e13o.0 * rhs.e13oi.0)
+				+ E123o(self.e23o.0 * rhs.e23oi.0),
+			e123i: -E123i(self.e123.0 * rhs.e123i.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0),
+			e12oi: -E12oi(self.e12o.0 * rhs.e123i.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0),
+			e13oi: -E13oi(self.e13o.0 * rhs.e123i.0) - E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e23o.0 * rhs.e123i.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP Sphere:
+
+// Circle.geometric(Sphere) -> Multivector
+impl Geometric<Sphere> for Circle {
+	type Output = Multivector;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     eo    : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     ei    : -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e1oi.0 * rhs.e23oi.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+		//     e12o  : -E12o(self.e13o.0 * rhs.e23oi.0) + E12o(self.e23o.0 * rhs.e13oi.0) - E12o(self.e3oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e13i.0 * rhs.e23oi.0) - E12i(self.e23i.0 * rhs.e13oi.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e12o.0 * rhs.e23oi.0) - E13o(self.e23o.0 * rhs.e12oi.0) + E13o(self.e2oi.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.e12i.0 * rhs.e23oi.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.e123.0 * rhs.e23oi.0) - E1oi(self.e23i.0 * rhs.e123o.0) + E1oi(self.e23o.0 * rhs.e123i.0),
+		//     e23o  : -E23o(self.e12o.0 * rhs.e13oi.0) + E23o(self.e13o.0 * rhs.e12oi.0) - E23o(self.e1oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e12i.0 * rhs.e13oi.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e123.0 * rhs.e13oi.0) + E2oi(self.e13i.0 * rhs.e123o.0) - E2oi(self.e13o.0 * rhs.e123i.0),
+		//     e3oi  : -E3oi(self.e123.0 * rhs.e12oi.0) - E3oi(self.e12i.0 * rhs.e123o.0) + E3oi(self.e12o.0 * rhs.e123i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0),
+			eo: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0),
+			ei: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e1oi.0 * rhs.e23oi.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+			e12o: -E12o(self.e13o.0 * rhs.e23oi.0) + E12o(self.e23o.0 * rhs.e13oi.0) - E12o(self.e3oi.0 * rhs.e123o.0),
+			e12i: E12i(self.e13i.0 * rhs.e23oi.0) - E12i(self.e23i.0 * rhs.e13oi.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+			e13o: E13o(self.e12o.0 * rhs.e23oi.0) - E13o(self.e23o.0 * rhs.e12oi.0) + E13o(self.e2oi.0 * rhs.e123o.0),
+			e13i: -E13i(self.e12i.0 * rhs.e23oi.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.e123.0 * rhs.e23oi.0) - E1oi(self.e23i.0 * rhs.e123o.0) + E1oi(self.e23o.0 * rhs.e123i.0),
+			e23o: -E23o(self.e12o.0 * rhs.e13oi.0) + E23o(self.e13o.0 * rhs.e12oi.0) - E23o(self.e1oi.0 * rhs.e123o.0),
+			e23i: E23i(self.e12i.0 * rhs.e13oi.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e123.0 * rhs.e13oi.0) + E2oi(self.e13i.0 * rhs.e123o.0) - E2oi(self.e13o.0 * rhs.e123i.0),
+			e3oi: -E3oi(self.e123.0 * rhs.e12oi.0) - E3oi(self.e12i.0 * rhs.e123o.0) + E3oi(self.e12o.0 * rhs.e123i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for Circle {
+	type Output = Multivector;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(Sphere) -> Versor
+impl AntiGeometric<Sphere> for Circle {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123.0 * rhs.e12oi.0) + E12(self.e12i.0 * rhs.e123o.0) - E12(self.e12o.0 * rhs.e123i.0),
+		//     e13  : E13(self.e123.0 * rhs.e13oi.0) + E13(self.e13i.0 * rhs.e123o.0) - E13(self.e13o.0 * rhs.e123i.0),
+		//     e1o  : E1o(self.e12o.0 * rhs.e13oi.0) - E1o(self.e13o.0 * rhs.e12oi.0) + E1o(self.e1oi.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23  : E23(self.e123.0 * rhs.e23oi.0) + E23(self.e23i.0 * rhs.e123o.0) - E23(self.e23o.0 * rhs.e123i.0),
+		//     e2o  : E2o(self.e12o.0 * rhs.e23oi.0) - E2o(self.e23o.0 * rhs.e12oi.0) + E2o(self.e2oi.0 * rhs.e123o.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.e13o.0 * rhs.e23oi.0) - E3o(self.e23o.0 * rhs.e13oi.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi  : Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123o: E123o(self.e123.0 * rhs.e123o.0) + E123o(self.e12o.0 * rhs.e12oi.0) + E123o(self.e13o.0 * rhs.e13oi.0) + E123o(self.e23o.0 * rhs.e23oi.0),
+		//     e123i: -E123i(self.e123.0 * rhs.e123i.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13oi.0) + E123i(self.e23i.0 * rhs.e23oi.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e2oi.0 * rhs.e23oi.0),
+		//     e13oi: -E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e13o.0 * rhs.e123i.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: -E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e23o.0 * rhs.e123i.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123.0 * rhs.e12oi.0) + E12(self.e12i.0 * rhs.e123o.0) - E12(self.e12o.0 * rhs.e123i.0),
+			e13: E13(self.e123.0 * rhs.e13oi.0) + E13(self.e13i.0 * rhs.e123o.0) - E13(self.e13o.0 * rhs.e123i.0),
+			e1o: E1o(self.e12o.0 * rhs.e13oi.0) - E1o(self.e13o.0 * rhs.e12oi.0) + E1o(self.e1oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+			e23: E23(self.e123.0 * rhs.e23oi.0) + E23(self.e23i.0 * rhs.e123o.0) - E23(self.e23o.0 * rhs.e123i.0),
+			e2o: E2o(self.e12o.0 * rhs.e23oi.0) - E2o(self.e23o.0 * rhs.e12oi.0) + E2o(self.e2oi.0 * rhs.e123o.0),
+			e2i: E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+			e3o: E3o(self.e13o.0 * rhs.e23oi.0) - E3o(self.e23o.0 * rhs.e13oi.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+			eoi: Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+			e123o: E123o(self.e123.0 * rhs.e123o.0)
+				+ E123o(self.e12o.0 * rhs.e12oi.0)
+				+ E123o(self.e13o.0 * rhs.e13oi.0)
+				+ E123o(self.e23o.0 * rhs.e23oi.0),
+			e123i: -E123i(self.e123.0 * rhs.e123i.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0),
+			e13oi: -E13oi(self.e13i.0 * rhs.e123o.0)
+				- E13oi(self.e13o.0 * rhs.e123i.0)
+				- E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e23o.0 * rhs.e123i.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// Circle.dot(Sphere) -> Point
+impl Dot<Sphere> for Circle {
+	type Output = Point;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     inf: -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0),
+			o: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0),
+			inf: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Sphere> for Circle {
+	type Output = Point;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Sphere> for Circle {
+	type Output = Zero;
+	fn wedge(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Sphere> for Circle {
+	type Output = Zero;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Circle.anti_wedge(Sphere) -> PointPair
+impl AntiWedge<Sphere> for Circle {
+	type Output = PointPair;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123.0 * rhs.e12oi.0) + E12(self.e12i.0 * rhs.e123o.0) - E12(self.e12o.0 * rhs.e123i.0),
+		//     e13: E13(self.e123.0 * rhs.e13oi.0) + E13(self.e13i.0 * rhs.e123o.0) - E13(self.e13o.0 * rhs.e123i.0),
+		//     e1o: E1o(self.e12o.0 * rhs.e13oi.0) - E1o(self.e13o.0 * rhs.e12oi.0) + E1o(self.e1oi.0 * rhs.e123o.0),
+		//     e1i: E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23: E23(self.e123.0 * rhs.e23oi.0) + E23(self.e23i.0 * rhs.e123o.0) - E23(self.e23o.0 * rhs.e123i.0),
+		//     e2o: E2o(self.e12o.0 * rhs.e23oi.0) - E2o(self.e23o.0 * rhs.e12oi.0) + E2o(self.e2oi.0 * rhs.e123o.0),
+		//     e2i: E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o: E3o(self.e13o.0 * rhs.e23oi.0) - E3o(self.e23o.0 * rhs.e13oi.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i: E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		// }
+		PointPair {
+			e12: self.e123.anti_wedge(rhs.e12oi) + self.e12i.anti_wedge(rhs.e123o) + self.e12o.anti_wedge(rhs.e123i),
+			e13: self.e123.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e123o) + self.e13o.anti_wedge(rhs.e123i),
+			e1o: self.e12o.anti_wedge(rhs.e13oi) + self.e13o.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123o),
+			e1i: self.e12i.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123i),
+			e23: self.e123.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e123o) + self.e23o.anti_wedge(rhs.e123i),
+			e2o: self.e12o.anti_wedge(rhs.e23oi) + self.e23o.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123o),
+			e2i: self.e12i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123i),
+			e3o: self.e13o.anti_wedge(rhs.e23oi) + self.e23o.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123o),
+			e3i: self.e13i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123i),
+			eoi: self.e1oi.anti_wedge(rhs.e23oi) + self.e2oi.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e12oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for Circle {
+	type Output = PointPair;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Circle.left_contraction(Sphere) -> Point
+impl LeftContraction<Sphere> for Circle {
+	type Output = Point;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     inf: -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0),
+			o: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0),
+			inf: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Sphere> for Circle {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Sphere> for Circle {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.commutator(Sphere) -> Point
+impl Commutator<Sphere> for Circle {
+	type Output = Point;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     inf: -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0),
+			o: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0),
+			inf: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Circle.anti_dot(Sphere) -> Sphere
+impl AntiDot<Sphere> for Circle {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e123.0 * rhs.e123o.0) + E123o(self.e12o.0 * rhs.e12oi.0) + E123o(self.e13o.0 * rhs.e13oi.0) + E123o(self.e23o.0 * rhs.e23oi.0),
+		//     e123i: -E123i(self.e123.0 * rhs.e123i.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13oi.0) + E123i(self.e23i.0 * rhs.e23oi.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e2oi.0 * rhs.e23oi.0),
+		//     e13oi: -E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e13o.0 * rhs.e123i.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: -E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e23o.0 * rhs.e123i.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Sphere {
+			e123o: E123o(self.e123.0 * rhs.e123o.0)
+				+ E123o(self.e12o.0 * rhs.e12oi.0)
+				+ E123o(self.e13o.0 * rhs.e13oi.0)
+				+ E123o(self.e23o.0 * rhs.e23oi.0),
+			e123i: -E123i(self.e123.0 * rhs.e123i.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0),
+			e13oi: -E13oi(self.e13i.0 * rhs.e123o.0)
+				- E13oi(self.e13o.0 * rhs.e123i.0)
+				- E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e23o.0 * rhs.e123i.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP Rotor:
+
+// Circle.geometric(Rotor) -> Multivector
+impl Geometric<Rotor> for Circle {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123.0 * rhs.e23.0),
+		//     e2    : E2(self.e123.0 * rhs.e13.0),
+		//     e3    : -E3(self.e123.0 * rhs.e12.0),
+		//     eo    : -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e23o.0 * rhs.e23.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0) - E12o(self.e13o.0 * rhs.e23.0) + E12o(self.e23o.0 * rhs.e13.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e23i.0 * rhs.e13.0),
+		//     e13o  : E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e13o.0 * rhs.s.0) - E13o(self.e23o.0 * rhs.e12.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.e23.0) + E13i(self.e13i.0 * rhs.s.0) - E13i(self.e23i.0 * rhs.e12.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : -E23o(self.e12o.0 * rhs.e13.0) + E23o(self.e13o.0 * rhs.e12.0) + E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) + E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e2oi.0 * rhs.s.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : E3oi(self.e1oi.0 * rhs.e13.0) + E3oi(self.e2oi.0 * rhs.e23.0) + E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e1oi.0 * rhs.e23.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123.0 * rhs.e23.0),
+			e2: E2(self.e123.0 * rhs.e13.0),
+			e3: -E3(self.e123.0 * rhs.e12.0),
+			eo: -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e23o.0 * rhs.e23.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e123.0 * rhs.s.0),
+			e12o: E12o(self.e12o.0 * rhs.s.0) - E12o(self.e13o.0 * rhs.e23.0) + E12o(self.e23o.0 * rhs.e13.0),
+			e12i: E12i(self.e12i.0 * rhs.s.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e23i.0 * rhs.e13.0),
+			e13o: E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e13o.0 * rhs.s.0) - E13o(self.e23o.0 * rhs.e12.0),
+			e13i: E13i(self.e12i.0 * rhs.e23.0) + E13i(self.e13i.0 * rhs.s.0) - E13i(self.e23i.0 * rhs.e12.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: -E23o(self.e12o.0 * rhs.e13.0) + E23o(self.e13o.0 * rhs.e12.0) + E23o(self.e23o.0 * rhs.s.0),
+			e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) + E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e2oi.0 * rhs.s.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: E3oi(self.e1oi.0 * rhs.e13.0) + E3oi(self.e2oi.0 * rhs.e23.0) + E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e1oi.0 * rhs.e23.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Circle {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(Rotor) -> Versor
+impl AntiGeometric<Rotor> for Circle {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1oi.0 * rhs.e23.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e12  : E12(self.e1oi.0 * rhs.e13.0) + E12(self.e2oi.0 * rhs.e23.0) - E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : -E13(self.e1oi.0 * rhs.e12.0) + E13(self.e2oi.0 * rhs.s.0) + E13(self.e3oi.0 * rhs.e23.0),
+		//     e1o  : -E1o(self.e12o.0 * rhs.e13.0) + E1o(self.e13o.0 * rhs.e12.0) - E1o(self.e23o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e12i.0 * rhs.e13.0) - E1i(self.e13i.0 * rhs.e12.0) + E1i(self.e23i.0 * rhs.s.0),
+		//     e23  : -E23(self.e1oi.0 * rhs.s.0) - E23(self.e2oi.0 * rhs.e12.0) - E23(self.e3oi.0 * rhs.e13.0),
+		//     e2o  : -E2o(self.e12o.0 * rhs.e23.0) + E2o(self.e13o.0 * rhs.s.0) + E2o(self.e23o.0 * rhs.e12.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.e23.0) - E2i(self.e13i.0 * rhs.s.0) - E2i(self.e23i.0 * rhs.e12.0),
+		//     e3o  : -E3o(self.e12o.0 * rhs.s.0) - E3o(self.e13o.0 * rhs.e23.0) + E3o(self.e23o.0 * rhs.e13.0),
+		//     e3i  : E3i(self.e12i.0 * rhs.s.0) + E3i(self.e13i.0 * rhs.e23.0) - E3i(self.e23i.0 * rhs.e13.0),
+		//     eoi  : Eoi(self.e123.0 * rhs.s.0),
+		//     e123o: -E123o(self.e12o.0 * rhs.e12.0) - E123o(self.e13o.0 * rhs.e13.0) - E123o(self.e23o.0 * rhs.e23.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e23i.0 * rhs.e23.0),
+		//     e12oi: E12oi(self.e123.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e123.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e123.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: S(self.e1oi.0 * rhs.e23.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+			e12: E12(self.e1oi.0 * rhs.e13.0) + E12(self.e2oi.0 * rhs.e23.0) - E12(self.e3oi.0 * rhs.s.0),
+			e13: -E13(self.e1oi.0 * rhs.e12.0) + E13(self.e2oi.0 * rhs.s.0) + E13(self.e3oi.0 * rhs.e23.0),
+			e1o: -E1o(self.e12o.0 * rhs.e13.0) + E1o(self.e13o.0 * rhs.e12.0) - E1o(self.e23o.0 * rhs.s.0),
+			e1i: E1i(self.e12i.0 * rhs.e13.0) - E1i(self.e13i.0 * rhs.e12.0) + E1i(self.e23i.0 * rhs.s.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0) - E23(self.e2oi.0 * rhs.e12.0) - E23(self.e3oi.0 * rhs.e13.0),
+			e2o: -E2o(self.e12o.0 * rhs.e23.0) + E2o(self.e13o.0 * rhs.s.0) + E2o(self.e23o.0 * rhs.e12.0),
+			e2i: E2i(self.e12i.0 * rhs.e23.0) - E2i(self.e13i.0 * rhs.s.0) - E2i(self.e23i.0 * rhs.e12.0),
+			e3o: -E3o(self.e12o.0 * rhs.s.0) - E3o(self.e13o.0 * rhs.e23.0) + E3o(self.e23o.0 * rhs.e13.0),
+			e3i: E3i(self.e12i.0 * rhs.s.0) + E3i(self.e13i.0 * rhs.e23.0) - E3i(self.e23i.0 * rhs.e13.0),
+			eoi: Eoi(self.e123.0 * rhs.s.0),
+			e123o: -E123o(self.e12o.0 * rhs.e12.0) - E123o(self.e13o.0 * rhs.e13.0) - E123o(self.e23o.0 * rhs.e23.0),
+			e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e23i.0 * rhs.e23.0),
+			e12oi: E12oi(self.e123.0 * rhs.e12.0),
+			e13oi: E13oi(self.e123.0 * rhs.e13.0),
+			e23oi: E23oi(self.e123.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Circle.dot(Rotor) -> Multivector
+impl Dot<Rotor> for Circle {
+	type Output = Multivector;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123.0 * rhs.e23.0),
+		//     e2    : E2(self.e123.0 * rhs.e13.0),
+		//     e3    : -E3(self.e123.0 * rhs.e12.0),
+		//     eo    : -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e23o.0 * rhs.e23.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123.0 * rhs.e23.0),
+			e2: E2(self.e123.0 * rhs.e13.0),
+			e3: -E3(self.e123.0 * rhs.e12.0),
+			eo: -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e23o.0 * rhs.e23.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e123.0 * rhs.s.0),
+			e12o: E12o(self.e12o.0 * rhs.s.0),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: E13o(self.e13o.0 * rhs.s.0),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: E23o(self.e23o.0 * rhs.s.0),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Circle {
+	type Output = Multivector;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Circle.wedge(Rotor) -> Multivector
+impl Wedge<Rotor> for Circle {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e1oi.0 * rhs.e23.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e123.wedge(rhs.s),
+			e12o: self.e12o.wedge(rhs.s),
+			e12i: self.e12i.wedge(rhs.s),
+			e13o: self.e13o.wedge(rhs.s),
+			e13i: self.e13i.wedge(rhs.s),
+			e1oi: self.e1oi.wedge(rhs.s),
+			e23o: self.e23o.wedge(rhs.s),
+			e23i: self.e23i.wedge(rhs.s),
+			e2oi: self.e2oi.wedge(rhs.s),
+			e3oi: self.e3oi.wedge(rhs.s),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e1oi.wedge(rhs.e23) + self.e2oi.wedge(rhs.e13) + self.e3oi.wedge(rhs.e12),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Circle {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Circle.anti_wedge(Rotor) -> S
+impl AntiWedge<Rotor> for Circle {
+	type Output = S;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// S(self.e1oi.0 * rhs.e23.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0)
+		self.e1oi.anti_wedge(rhs.e23) + self.e2oi.anti_wedge(rhs.e13) + self.e3oi.anti_wedge(rhs.e12)
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Circle {
+	type Output = S;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Rotor> for Circle {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.right_contraction(Rotor) -> Multivector
+impl RightContraction<Rotor> for Circle {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123.0 * rhs.e23.0),
+		//     e2    : E2(self.e123.0 * rhs.e13.0),
+		//     e3    : -E3(self.e123.0 * rhs.e12.0),
+		//     eo    : -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e23o.0 * rhs.e23.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123.0 * rhs.e23.0),
+			e2: E2(self.e123.0 * rhs.e13.0),
+			e3: -E3(self.e123.0 * rhs.e12.0),
+			eo: -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e23o.0 * rhs.e23.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e123.0 * rhs.s.0),
+			e12o: E12o(self.e12o.0 * rhs.s.0),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: E13o(self.e13o.0 * rhs.s.0),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: E23o(self.e23o.0 * rhs.s.0),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for Circle {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.commutator(Rotor) -> Circle
+impl Commutator<Rotor> for Circle {
+	type Output = Circle;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Circle {
+		//     e123: Default::default(),
+		//     e12o: -E12o(self.e13o.0 * rhs.e23.0) + E12o(self.e23o.0 * rhs.e13.0),
+		//     e12i: -E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e23i.0 * rhs.e13.0),
+		//     e13o: E13o(self.e12o.0 * rhs.e23.0) - E13o(self.e23o.0 * rhs.e12.0),
+		//     e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e23i.0 * rhs.e12.0),
+		//     e1oi: -E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o: -E23o(self.e12o.0 * rhs.e13.0) + E23o(self.e13o.0 * rhs.e12.0),
+		//     e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0),
+		//     e2oi: E2oi(self.e1oi.0 * rhs.e12.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi: E3oi(self.e1oi.0 * rhs.e13.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		// }
+		Circle {
+			e123: Default::default(),
+			e12o: -E12o(self.e13o.0 * rhs.e23.0) + E12o(self.e23o.0 * rhs.e13.0),
+			e12i: -E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e23i.0 * rhs.e13.0),
+			e13o: E13o(self.e12o.0 * rhs.e23.0) - E13o(self.e23o.0 * rhs.e12.0),
+			e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e23i.0 * rhs.e12.0),
+			e1oi: -E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: -E23o(self.e12o.0 * rhs.e13.0) + E23o(self.e13o.0 * rhs.e12.0),
+			e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0),
+			e2oi: E2oi(self.e1oi.0 * rhs.e12.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: E3oi(self.e1oi.0 * rhs.e13.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Circle.anti_dot(Rotor) -> Versor
+impl AntiDot<Rotor> for Circle {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : E13(self.e2oi.0 * rhs.s.0),
+		//     e1o  : -E1o(self.e23o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e23i.0 * rhs.s.0),
+		//     e23  : -E23(self.e1oi.0 * rhs.s.0),
+		//     e2o  : E2o(self.e13o.0 * rhs.s.0),
+		//     e2i  : -E2i(self.e13i.0 * rhs.s.0),
+		//     e3o  : -E3o(self.e12o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e12i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.e123.0 * rhs.s.0),
+		//     e123o: -E123o(self.e12o.0 * rhs.e12.0) - E123o(self.e13o.0 * rhs.e13.0) - E123o(self.e23o.0 * rhs.e23.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e23i.0 * rhs.e23.0),
+		//     e12oi: E12oi(self.e123.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e123.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e123.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e2oi.0 * rhs.s.0),
+			e1o: -E1o(self.e23o.0 * rhs.s.0),
+			e1i: E1i(self.e23i.0 * rhs.s.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0),
+			e2o: E2o(self.e13o.0 * rhs.s.0),
+			e2i: -E2i(self.e13i.0 * rhs.s.0),
+			e3o: -E3o(self.e12o.0 * rhs.s.0),
+			e3i: E3i(self.e12i.0 * rhs.s.0),
+			eoi: Eoi(self.e123.0 * rhs.s.0),
+			e123o: -E123o(self.e12o.0 * rhs.e12.0) - E123o(self.e13o.0 * rhs.e13.0) - E123o(self.e23o.0 * rhs.e23.0),
+			e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e23i.0 * rhs.e23.0),
+			e12oi: E12oi(self.e123.0 * rhs.e12.0),
+			e13oi: E13oi(self.e123.0 * rhs.e13.0),
+			e23oi: E23oi(self.e123.0 * rhs.e23.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP Translator:
+
+// Circle.geometric(Translator) -> Multivector
+impl Geometric<Translator> for Circle {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0),
+		//     e2    : -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0),
+		//     e3    : -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0) + E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23o.0 * rhs.e1i.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e123.0 * rhs.e3i.0) + E12i(self.e12i.0 * rhs.s.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e13i.0 * rhs.s.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : -E1oi(self.e12o.0 * rhs.e2i.0) - E1oi(self.e13o.0 * rhs.e3i.0) + E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e123.0 * rhs.e1i.0) + E23i(self.e23i.0 * rhs.s.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.e12o.0 * rhs.e1i.0) - E2oi(self.e23o.0 * rhs.e3i.0) + E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e23o.0 * rhs.e2i.0) + E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e12o.0 * rhs.e3i.0) + E123oi(self.e13o.0 * rhs.e2i.0) - E123oi(self.e23o.0 * rhs.e1i.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0),
+			e2: -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0),
+			e3: -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0),
+			eo: Default::default(),
+			ei: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e123.0 * rhs.s.0) + E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13o.0 * rhs.e2i.0)
+				+ E123(self.e23o.0 * rhs.e1i.0),
+			e12o: E12o(self.e12o.0 * rhs.s.0),
+			e12i: E12i(self.e123.0 * rhs.e3i.0) + E12i(self.e12i.0 * rhs.s.0) + E12i(self.e1oi.0 * rhs.e2i.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: E13o(self.e13o.0 * rhs.s.0),
+			e13i: -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e13i.0 * rhs.s.0) + E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: -E1oi(self.e12o.0 * rhs.e2i.0) - E1oi(self.e13o.0 * rhs.e3i.0) + E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: E23o(self.e23o.0 * rhs.s.0),
+			e23i: E23i(self.e123.0 * rhs.e1i.0) + E23i(self.e23i.0 * rhs.s.0) + E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: E2oi(self.e12o.0 * rhs.e1i.0) - E2oi(self.e23o.0 * rhs.e3i.0) + E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e23o.0 * rhs.e2i.0) + E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e12o.0 * rhs.e3i.0) + E123oi(self.e13o.0 * rhs.e2i.0)
+				- E123oi(self.e23o.0 * rhs.e1i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Circle {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(Translator) -> Versor
+impl AntiGeometric<Translator> for Circle {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12o.0 * rhs.e3i.0) + S(self.e13o.0 * rhs.e2i.0) - S(self.e23o.0 * rhs.e1i.0),
+		//     e12  : E12(self.e13o.0 * rhs.e1i.0) + E12(self.e23o.0 * rhs.e2i.0) - E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : -E13(self.e12o.0 * rhs.e1i.0) + E13(self.e23o.0 * rhs.e3i.0) + E13(self.e2oi.0 * rhs.s.0),
+		//     e1o  : -E1o(self.e23o.0 * rhs.s.0),
+		//     e1i  : -E1i(self.e123.0 * rhs.e1i.0) + E1i(self.e23i.0 * rhs.s.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+		//     e23  : -E23(self.e12o.0 * rhs.e2i.0) - E23(self.e13o.0 * rhs.e3i.0) - E23(self.e1oi.0 * rhs.s.0),
+		//     e2o  : E2o(self.e13o.0 * rhs.s.0),
+		//     e2i  : -E2i(self.e123.0 * rhs.e2i.0) - E2i(self.e13i.0 * rhs.s.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+		//     e3o  : -E3o(self.e12o.0 * rhs.s.0),
+		//     e3i  : -E3i(self.e123.0 * rhs.e3i.0) + E3i(self.e12i.0 * rhs.s.0) - E3i(self.e1oi.0 * rhs.e2i.0) + E3i(self.e2oi.0 * rhs.e1i.0),
+		//     eoi  : Eoi(self.e123.0 * rhs.s.0) - Eoi(self.e12o.0 * rhs.e3i.0) + Eoi(self.e13o.0 * rhs.e2i.0) - Eoi(self.e23o.0 * rhs.e1i.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e23o.0 * rhs.e2i.0),
+		//     e13oi: -E13oi(self.e12o.0 * rhs.e1i.0) + E13oi(self.e23o.0 * rhs.e3i.0),
+		//     e23oi: -E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13o.0 * rhs.e3i.0),
+		// }
+		Versor {
+			s: -S(self.e12o.0 * rhs.e3i.0) + S(self.e13o.0 * rhs.e2i.0) - S(self.e23o.0 * rhs.e1i.0),
+			e12: E12(self.e13o.0 * rhs.e1i.0) + E12(self.e23o.0 * rhs.e2i.0) - E12(self.e3oi.0 * rhs.s.0),
+			e13: -E13(self.e12o.0 * rhs.e1i.0) + E13(self.e23o.0 * rhs.e3i.0) + E13(self.e2oi.0 * rhs.s.0),
+			e1o: -E1o(self.e23o.0 * rhs.s.0),
+			e1i: -E1i(self.e123.0 * rhs.e1i.0) + E1i(self.e23i.0 * rhs.s.0) - E1i(self.e2oi.0 * rhs.e3i.0)
+				+ E1i(self.e3oi.0 * rhs.e2i.0),
+			e23: -E23(self.e12o.0 * rhs.e2i.0) - E23(self.e13o.0 * rhs.e3i.0) - E23(self.e1oi.0 * rhs.s.0),
+			e2o: E2o(self.e13o.0 * rhs.s.0),
+			e2i: -E2i(self.e123.0 * rhs.e2i.0) - E2i(self.e13i.0 * rhs.s.0) + E2i(self.e1oi.0 * rhs.e3i.0)
+				- E2i(self.e3oi.0 * rhs.e1i.0),
+			e3o: -E3o(self.e12o.0 * rhs.s.0),
+			e3i: -E3i(self.e123.0 * rhs.e3i.0) + E3i(self.e12i.0 * rhs.s.0) - E3i(self.e1oi.0 * rhs.e2i.0)
+				+ E3i(self.e2oi.0 * rhs.e1i.0),
+			eoi: Eoi(self.e123.0 * rhs.s.0) - Eoi(self.e12o.0 * rhs.e3i.0) + Eoi(self.e13o.0 * rhs.e2i.0)
+				- Eoi(self.e23o.0 * rhs.e1i.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e23o.0 * rhs.e2i.0),
+			e13oi: -E13oi(self.e12o.0 * rhs.e1i.0) + E13oi(self.e23o.0 * rhs.e3i.0),
+			e23oi: -E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13o.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// Circle.dot(Translator) -> Multivector
+impl Dot<Translator> for Circle {
+	type Output = Multivector;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0),
+		//     e2    : -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0),
+		//     e3    : -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0),
+			e2: -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0),
+			e3: -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0),
+			eo: Default::default(),
+			ei: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e123.0 * rhs.s.0),
+			e12o: E12o(self.e12o.0 * rhs.s.0),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: E13o(self.e13o.0 * rhs.s.0),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: E23o(self.e23o.0 * rhs.s.0),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Circle {
+	type Output = Multivector;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Circle.wedge(Translator) -> Multivector
+impl Wedge<Translator> for Circle {
+	type Output = Multivector;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e12o.0 * rhs.e3i.0) + E123oi(self.e13o.0 * rhs.e2i.0) - E123oi(self.e23o.0 * rhs.e1i.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e123.wedge(rhs.s),
+			e12o: self.e12o.wedge(rhs.s),
+			e12i: self.e12i.wedge(rhs.s),
+			e13o: self.e13o.wedge(rhs.s),
+			e13i: self.e13i.wedge(rhs.s),
+			e1oi: self.e1oi.wedge(rhs.s),
+			e23o: self.e23o.wedge(rhs.s),
+			e23i: self.e23i.wedge(rhs.s),
+			e2oi: self.e2oi.wedge(rhs.s),
+			e3oi: self.e3oi.wedge(rhs.s),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e12o.wedge(rhs.e3i) + self.e13o.wedge(rhs.e2i) + self.e23o.wedge(rhs.e1i),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Circle {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Circle.anti_wedge(Translator) -> S
+impl AntiWedge<Translator> for Circle {
+	type Output = S;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// -S(self.e12o.0 * rhs.e3i.0) + S(self.e13o.0 * rhs.e2i.0) - S(self.e23o.0 * rhs.e1i.0)
+		self.e12o.anti_wedge(rhs.e3i) + self.e13o.anti_wedge(rhs.e2i) + self.e23o.anti_wedge(rhs.e1i)
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Circle {
+	type Output = S;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Translator> for Circle {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.right_contraction(Translator) -> Multivector
+impl RightContraction<Translator> for Circle {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0),
+		//     e2    : -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0),
+		//     e3    : -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13o.0 * rhs.e3i.0),
+			e2: -E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23o.0 * rhs.e3i.0),
+			e3: -E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23o.0 * rhs.e2i.0),
+			eo: Default::default(),
+			ei: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e123.0 * rhs.s.0),
+			e12o: E12o(self.e12o.0 * rhs.s.0),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: E13o(self.e13o.0 * rhs.s.0),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: E23o(self.e23o.0 * rhs.s.0),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for Circle {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.commutator(Translator) -> Circle
+impl Commutator<Translator> for Circle {
+	type Output = Circle;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23o.0 * rhs.e1i.0),
+		//     e12o: Default::default(),
+		//     e12i: E12i(self.e123.0 * rhs.e3i.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o: Default::default(),
+		//     e13i: -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi: -E1oi(self.e12o.0 * rhs.e2i.0) - E1oi(self.e13o.0 * rhs.e3i.0),
+		//     e23o: Default::default(),
+		//     e23i: E23i(self.e123.0 * rhs.e1i.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi: E2oi(self.e12o.0 * rhs.e1i.0) - E2oi(self.e23o.0 * rhs.e3i.0),
+		//     e3oi: E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e23o.0 * rhs.e2i.0),
+		// }
+		Circle {
+			e123: E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23o.0 * rhs.e1i.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e123.0 * rhs.e3i.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: -E1oi(self.e12o.0 * rhs.e2i.0) - E1oi(self.e13o.0 * rhs.e3i.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e123.0 * rhs.e1i.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: E2oi(self.e12o.0 * rhs.e1i.0) - E2oi(self.e23o.0 * rhs.e3i.0),
+			e3oi: E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e23o.0 * rhs.e2i.0),
+		}
+	}
+}
+
+// Circle.anti_dot(Translator) -> Versor
+impl AntiDot<Translator> for Circle {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : E13(self.e2oi.0 * rhs.s.0),
+		//     e1o  : -E1o(self.e23o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e23i.0 * rhs.s.0),
+		//     e23  : -E23(self.e1oi.0 * rhs.s.0),
+		//     e2o  : E2o(self.e13o.0 * rhs.s.0),
+		//     e2i  : -E2i(self.e13i.0 * rhs.s.0),
+		//     e3o  : -E3o(self.e12o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e12i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.e123.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e23o.0 * rhs.e2i.0),
+		//     e13oi: -E13oi(self.e12o.0 * rhs.e1i.0) + E13oi(self.e23o.0 * rhs.e3i.0),
+		//     e23oi: -E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13o.0 * rhs.e3i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e2oi.0 * rhs.s.0),
+			e1o: -E1o(self.e23o.0 * rhs.s.0),
+			e1i: E1i(self.e23i.0 * rhs.s.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0),
+			e2o: E2o(self.e13o.0 * rhs.s.0),
+			e2i: -E2i(self.e13i.0 * rhs.s.0),
+			e3o: -E3o(self.e12o.0 * rhs.s.0),
+			e3i: E3i(self.e12i.0 * rhs.s.0),
+			eoi: Eoi(self.e123.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e23o.0 * rhs.e2i.0),
+			e13oi: -E13oi(self.e12o.0 * rhs.e1i.0) + E13oi(self.e23o.0 * rhs.e3i.0),
+			e23oi: -E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13o.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP Versor:
+
+// Circle.geometric(Versor) -> Multivector
+impl Geometric<Versor> for Circle {
+	type Output = Multivector;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123.0 * rhs.e23.0) + E1(self.e12i.0 * rhs.e2o.0) + E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0) - E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0) + E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e123.0 * rhs.e12.0) - E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) - E3(self.e13i.0 * rhs.e1o.0) - E3(self.e13o.0 * rhs.e1i.0) + E3(self.e1oi.0 * rhs.e13oi.0) - E3(self.e23i.0 * rhs.e2o.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e2oi.0 * rhs.e23oi.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e23o.0 * rhs.e23.0) - Eo(self.e23o.0 * rhs.e23oi.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e123.0 * rhs.e123i.0) - Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e23i.0 * rhs.e23oi.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0) + E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13i.0 * rhs.e2o.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e1oi.0 * rhs.e23oi.0) + E123(self.e23i.0 * rhs.e1o.0) + E123(self.e23o.0 * rhs.e1i.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+		//     e12o  : E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0) + E12o(self.e12o.0 * rhs.s.0) - E12o(self.e13o.0 * rhs.e23.0) - E12o(self.e13o.0 * rhs.e23oi.0) - E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e23o.0 * rhs.e13.0) + E12o(self.e23o.0 * rhs.e13oi.0) + E12o(self.e2oi.0 * rhs.e1o.0) - E12o(self.e3oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e12i.0 * rhs.s.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e13i.0 * rhs.e23oi.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e23i.0 * rhs.e13oi.0) - E12i(self.e2oi.0 * rhs.e1i.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.e123.0 * rhs.e2o.0) + E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e12o.0 * rhs.e23oi.0) + E13o(self.e13o.0 * rhs.eoi.0) + E13o(self.e13o.0 * rhs.s.0) - E13o(self.e1oi.0 * rhs.e3o.0) - E13o(self.e23o.0 * rhs.e12.0) - E13o(self.e23o.0 * rhs.e12oi.0) + E13o(self.e2oi.0 * rhs.e123o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i  : -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e12i.0 * rhs.e23oi.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e13i.0 * rhs.s.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : -E1oi(self.e123.0 * rhs.e23oi.0) + E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0) + E1oi(self.e13i.0 * rhs.e3o.0) - E1oi(self.e13o.0 * rhs.e3i.0) + E1oi(self.e1oi.0 * rhs.s.0) - E1oi(self.e23i.0 * rhs.e123o.0) + E1oi(self.e23o.0 * rhs.e123i.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0) - E23o(self.e12o.0 * rhs.e13oi.0) + E23o(self.e13o.0 * rhs.e12.0) + E23o(self.e13o.0 * rhs.e12oi.0) - E23o(self.e1oi.0 * rhs.e123o.0) + E23o(self.e23o.0 * rhs.eoi.0) + E23o(self.e23o.0 * rhs.s.0) - E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e12i.0 * rhs.e13oi.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e23i.0 * rhs.s.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.e123.0 * rhs.e13oi.0) - E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e12o.0 * rhs.e1i.0) + E2oi(self.e13i.0 * rhs.e123o.0) - E2oi(self.e13o.0 * rhs.e123i.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) - E2oi(self.e23o.0 * rhs.e3i.0) + E2oi(self.e2oi.0 * rhs.s.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : -E3oi(self.e123.0 * rhs.e12oi.0) - E3oi(self.e12i.0 * rhs.e123o.0) + E3oi(self.e12o.0 * rhs.e123i.0) - E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e23o.0 * rhs.e2i.0) + E3oi(self.e2oi.0 * rhs.e23.0) + E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123.0 * rhs.eoi.0) + E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e12o.0 * rhs.e3i.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e13o.0 * rhs.e2i.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e23o.0 * rhs.e1i.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123.0 * rhs.e23.0)
+				+ E1(self.e12i.0 * rhs.e2o.0)
+				+ E1(self.e12o.0 * rhs.e2i.0)
+				+ E1(self.e13i.0 * rhs.e3o.0)
+				+ E1(self.e13o.0 * rhs.e3i.0)
+				+ E1(self.e1oi.0 * rhs.eoi.0)
+				- E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0)
+				+ E2(self.e13i.0 * rhs.e123o.0)
+				+ E2(self.e13o.0 * rhs.e123i.0)
+				+ E2(self.e1oi.0 * rhs.e12oi.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e23o.0 * rhs.e3i.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e123.0 * rhs.e12.0)
+				- E3(self.e12i.0 * rhs.e123o.0)
+				- E3(self.e12o.0 * rhs.e123i.0)
+				- E3(self.e13i.0 * rhs.e1o.0)
+				- E3(self.e13o.0 * rhs.e1i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				- E3(self.e23o.0 * rhs.e2i.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e1oi.0 * rhs.e1o.0)
+				- Eo(self.e23o.0 * rhs.e23.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0)
+				- Eo(self.e2oi.0 * rhs.e2o.0)
+				- Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e123.0 * rhs.e123i.0) - Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0)
+				- Ei(self.e13i.0 * rhs.e13.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e123.0 * rhs.s.0) + E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0)
+				- E123(self.e13i.0 * rhs.e2o.0)
+				- E123(self.e13o.0 * rhs.e2i.0)
+				+ E123(self.e1oi.0 * rhs.e23oi.0)
+				+ E123(self.e23i.0 * rhs.e1o.0)
+				+ E123(self.e23o.0 * rhs.e1i.0)
+				- E123(self.e2oi.0 * rhs.e13oi.0)
+				+ E123(self.e3oi.0 * rhs.e12oi.0),
+			e12o: E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0) + E12o(self.e12o.0 * rhs.s.0)
+				- E12o(self.e13o.0 * rhs.e23.0)
+				- E12o(self.e13o.0 * rhs.e23oi.0)
+				- E12o(self.e1oi.0 * rhs.e2o.0)
+				+ E12o(self.e23o.0 * rhs.e13.0)
+				+ E12o(self.e23o.0 * rhs.e13oi.0)
+				+ E12o(self.e2oi.0 * rhs.e1o.0)
+				- E12o(self.e3oi.0 * rhs.e123o.0),
+			e12i: E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e12i.0 * rhs.s.0)
+				- E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e13i.0 * rhs.e23oi.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e23i.0 * rhs.e13oi.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0)
+				+ E12i(self.e3oi.0 * rhs.e123i.0),
+			e13o: -E13o(self.e123.0 * rhs.e2o.0)
+				+ E13o(self.e12o.0 * rhs.e23.0)
+				+ E13o(self.e12o.0 * rhs.e23oi.0)
+				+ E13o(self.e13o.0 * rhs.eoi.0)
+				+ E13o(self.e13o.0 * rhs.s.0)
+				- E13o(self.e1oi.0 * rhs.e3o.0)
+				- E13o(self.e23o.0 * rhs.e12.0)
+				- E13o(self.e23o.0 * rhs.e12oi.0)
+				+ E13o(self.e2oi.0 * rhs.e123o.0)
+				+ E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0)
+				- E13i(self.e12i.0 * rhs.e23oi.0)
+				- E13i(self.e13i.0 * rhs.eoi.0)
+				+ E13i(self.e13i.0 * rhs.s.0)
+				+ E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				+ E13i(self.e23i.0 * rhs.e12oi.0)
+				- E13i(self.e2oi.0 * rhs.e123i.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: -E1oi(self.e123.0 * rhs.e23oi.0) + E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0)
+				+ E1oi(self.e13i.0 * rhs.e3o.0)
+				- E1oi(self.e13o.0 * rhs.e3i.0)
+				+ E1oi(self.e1oi.0 * rhs.s.0)
+				- E1oi(self.e23i.0 * rhs.e123o.0)
+				+ E1oi(self.e23o.0 * rhs.e123i.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0) - E23o(self.e12o.0 * rhs.e13oi.0)
+				+ E23o(self.e13o.0 * rhs.e12.0)
+				+ E23o(self.e13o.0 * rhs.e12oi.0)
+				- E23o(self.e1oi.0 * rhs.e123o.0)
+				+ E23o(self.e23o.0 * rhs.eoi.0)
+				+ E23o(self.e23o.0 * rhs.s.0)
+				- E23o(self.e2oi.0 * rhs.e3o.0)
+				+ E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0)
+				+ E23i(self.e12i.0 * rhs.e13oi.0)
+				+ E23i(self.e13i.0 * rhs.e12.0)
+				- E23i(self.e13i.0 * rhs.e12oi.0)
+				+ E23i(self.e1oi.0 * rhs.e123i.0)
+				- E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e23i.0 * rhs.s.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: E2oi(self.e123.0 * rhs.e13oi.0) - E2oi(self.e12i.0 * rhs.e1o.0)
+				+ E2oi(self.e12o.0 * rhs.e1i.0)
+				+ E2oi(self.e13i.0 * rhs.e123o.0)
+				- E2oi(self.e13o.0 * rhs.e123i.0)
+				+ E2oi(self.e1oi.0 * rhs.e12.0)
+				+ E2oi(self.e23i.0 * rhs.e3o.0)
+				- E2oi(self.e23o.0 * rhs.e3i.0)
+				+ E2oi(self.e2oi.0 * rhs.s.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e123.0 * rhs.e12oi.0) - E3oi(self.e12i.0 * rhs.e123o.0) + E3oi(self.e12o.0 * rhs.e123i.0)
+				- E3oi(self.e13i.0 * rhs.e1o.0)
+				+ E3oi(self.e13o.0 * rhs.e1i.0)
+				+ E3oi(self.e1oi.0 * rhs.e13.0)
+				- E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e23o.0 * rhs.e2i.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0)
+				+ E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123.0 * rhs.eoi.0) + E123oi(self.e12i.0 * rhs.e3o.0)
+				- E123oi(self.e12o.0 * rhs.e3i.0)
+				- E123oi(self.e13i.0 * rhs.e2o.0)
+				+ E123oi(self.e13o.0 * rhs.e2i.0)
+				+ E123oi(self.e1oi.0 * rhs.e23.0)
+				+ E123oi(self.e23i.0 * rhs.e1o.0)
+				- E123oi(self.e23o.0 * rhs.e1i.0)
+				- E123oi(self.e2oi.0 * rhs.e13.0)
+				+ E123oi(self.e3oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for Circle {
+	type Output = Multivector;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(Versor) -> Versor
+impl AntiGeometric<Versor> for Circle {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e123.0 * rhs.eoi.0) + S(self.e12i.0 * rhs.e3o.0) - S(self.e12o.0 * rhs.e3i.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e13o.0 * rhs.e2i.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e23o.0 * rhs.e1i.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e12  : E12(self.e123.0 * rhs.e12oi.0) + E12(self.e12i.0 * rhs.e123o.0) - E12(self.e12o.0 * rhs.e123i.0) - E12(self.e13i.0 * rhs.e1o.0) + E12(self.e13o.0 * rhs.e1i.0) + E12(self.e1oi.0 * rhs.e13.0) - E12(self.e23i.0 * rhs.e2o.0) + E12(self.e23o.0 * rhs.e2i.0) + E12(self.e2oi.0 * rhs.e23.0) - E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : E13(self.e123.0 * rhs.e13oi.0) + E13(self.e12i.0 * rhs.e1o.0) - E13(self.e12o.0 * rhs.e1i.0) + E13(self.e13i.0 * rhs.e123o.0) - E13(self.e13o.0 * rhs.e123i.0) - E13(self.e1oi.0 * rhs.e12.0) - E13(self.e23i.0 * rhs.e3o.0) + E13(self.e23o.0 * rhs.e3i.0) + E13(self.e2oi.0 * rhs.s.0) + E13(self.e3oi.0 * rhs.e23.0),
+		//     e1o  : E1o(self.e123.0 * rhs.e1o.0) - E1o(self.e12o.0 * rhs.e13.0) + E1o(self.e12o.0 * rhs.e13oi.0) + E1o(self.e13o.0 * rhs.e12.0) - E1o(self.e13o.0 * rhs.e12oi.0) + E1o(self.e1oi.0 * rhs.e123o.0) + E1o(self.e23o.0 * rhs.eoi.0) - E1o(self.e23o.0 * rhs.s.0) - E1o(self.e2oi.0 * rhs.e3o.0) + E1o(self.e3oi.0 * rhs.e2o.0),
+		//     e1i  : -E1i(self.e123.0 * rhs.e1i.0) + E1i(self.e12i.0 * rhs.e13.0) + E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0) + E1i(self.e23i.0 * rhs.eoi.0) + E1i(self.e23i.0 * rhs.s.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+		//     e23  : E23(self.e123.0 * rhs.e23oi.0) + E23(self.e12i.0 * rhs.e2o.0) - E23(self.e12o.0 * rhs.e2i.0) + E23(self.e13i.0 * rhs.e3o.0) - E23(self.e13o.0 * rhs.e3i.0) - E23(self.e1oi.0 * rhs.s.0) + E23(self.e23i.0 * rhs.e123o.0) - E23(self.e23o.0 * rhs.e123i.0) - E23(self.e2oi.0 * rhs.e12.0) - E23(self.e3oi.0 * rhs.e13.0),
+		//     e2o  : E2o(self.e123.0 * rhs.e2o.0) - E2o(self.e12o.0 * rhs.e23.0) + E2o(self.e12o.0 * rhs.e23oi.0) - E2o(self.e13o.0 * rhs.eoi.0) + E2o(self.e13o.0 * rhs.s.0) + E2o(self.e1oi.0 * rhs.e3o.0) + E2o(self.e23o.0 * rhs.e12.0) - E2o(self.e23o.0 * rhs.e12oi.0) + E2o(self.e2oi.0 * rhs.e123o.0) - E2o(self.e3oi.0 * rhs.e1o.0),
+		//     e2i  : -E2i(self.e123.0 * rhs.e2i.0) + E2i(self.e12i.0 * rhs.e23.0) + E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e13i.0 * rhs.eoi.0) - E2i(self.e13i.0 * rhs.s.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e23i.0 * rhs.e12.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+		//     e3o  : E3o(self.e123.0 * rhs.e3o.0) + E3o(self.e12o.0 * rhs.eoi.0) - E3o(self.e12o.0 * rhs.s.0) - E3o(self.e13o.0 * rhs.e23.0) + E3o(self.e13o.0 * rhs.e23oi.0) - E3o(self.e1oi.0 * rhs.e2o.0) + E3o(self.e23o.0 * rhs.e13.0) - E3o(self.e23o.0 * rhs.e13oi.0) + E3o(self.e2oi.0 * rhs.e1o.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i  : -E3i(self.e123.0 * rhs.e3i.0) + E3i(self.e12i.0 * rhs.eoi.0) + E3i(self.e12i.0 * rhs.s.0) + E3i(self.e13i.0 * rhs.e23.0) + E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e1oi.0 * rhs.e2i.0) - E3i(self.e23i.0 * rhs.e13.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e2oi.0 * rhs.e1i.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi  : Eoi(self.e123.0 * rhs.s.0) - Eoi(self.e12i.0 * rhs.e3o.0) - Eoi(self.e12o.0 * rhs.e3i.0) + Eoi(self.e13i.0 * rhs.e2o.0) + Eoi(self.e13o.0 * rhs.e2i.0) + Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e23i.0 * rhs.e1o.0) - Eoi(self.e23o.0 * rhs.e1i.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123o: E123o(self.e123.0 * rhs.e123o.0) - E123o(self.e12o.0 * rhs.e12.0) + E123o(self.e12o.0 * rhs.e12oi.0) - E123o(self.e13o.0 * rhs.e13.0) + E123o(self.e13o.0 * rhs.e13oi.0) - E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e23o.0 * rhs.e23.0) + E123o(self.e23o.0 * rhs.e23oi.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i: -E123i(self.e123.0 * rhs.e123i.0) + E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e13i.0 * rhs.e13oi.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) + E123i(self.e23i.0 * rhs.e23oi.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.e123.0 * rhs.e12.0) - E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0) + E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e23i.0 * rhs.e2o.0) + E12oi(self.e23o.0 * rhs.e2i.0) + E12oi(self.e2oi.0 * rhs.e23oi.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: E13oi(self.e123.0 * rhs.e13.0) - E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e12o.0 * rhs.e1i.0) - E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e13o.0 * rhs.e123i.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e23o.0 * rhs.e3i.0) + E13oi(self.e2oi.0 * rhs.eoi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: E23oi(self.e123.0 * rhs.e23.0) - E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e13o.0 * rhs.e3i.0) - E23oi(self.e1oi.0 * rhs.eoi.0) - E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e23o.0 * rhs.e123i.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Versor {
+			s: S(self.e123.0 * rhs.eoi.0) + S(self.e12i.0 * rhs.e3o.0)
+				- S(self.e12o.0 * rhs.e3i.0)
+				- S(self.e13i.0 * rhs.e2o.0)
+				+ S(self.e13o.0 * rhs.e2i.0)
+				+ S(self.e1oi.0 * rhs.e23.0)
+				+ S(self.e23i.0 * rhs.e1o.0)
+				- S(self.e23o.0 * rhs.e1i.0)
+				- S(self.e2oi.0 * rhs.e13.0)
+				+ S(self.e3oi.0 * rhs.e12.0),
+			e12: E12(self.e123.0 * rhs.e12oi.0) + E12(self.e12i.0 * rhs.e123o.0)
+				- E12(self.e12o.0 * rhs.e123i.0)
+				- E12(self.e13i.0 * rhs.e1o.0)
+				+ E12(self.e13o.0 * rhs.e1i.0)
+				+ E12(self.e1oi.0 * rhs.e13.0)
+				- E12(self.e23i.0 * rhs.e2o.0)
+				+ E12(self.e23o.0 * rhs.e2i.0)
+				+ E12(self.e2oi.0 * rhs.e23.0)
+				- E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e123.0 * rhs.e13oi.0) + E13(self.e12i.0 * rhs.e1o.0) - E13(self.e12o.0 * rhs.e1i.0)
+				+ E13(self.e13i.0 * rhs.e123o.0)
+				- E13(self.e13o.0 * rhs.e123i.0)
+				- E13(self.e1oi.0 * rhs.e12.0)
+				- E13(self.e23i.0 * rhs.e3o.0)
+				+ E13(self.e23o.0 * rhs.e3i.0)
+				+ E13(self.e2oi.0 * rhs.s.0)
+				+ E13(self.e3oi.0 * rhs.e23.0),
+			e1o: E1o(self.e123.0 * rhs.e1o.0) - E1o(self.e12o.0 * rhs.e13.0)
+				+ E1o(self.e12o.0 * rhs.e13oi.0)
+				+ E1o(self.e13o.0 * rhs.e12.0)
+				- E1o(self.e13o.0 * rhs.e12oi.0)
+				+ E1o(self.e1oi.0 * rhs.e123o.0)
+				+ E1o(self.e23o.0 * rhs.eoi.0)
+				- E1o(self.e23o.0 * rhs.s.0)
+				- E1o(self.e2oi.0 * rhs.e3o.0)
+				+ E1o(self.e3oi.0 * rhs.e2o.0),
+			e1i: -E1i(self.e123.0 * rhs.e1i.0) + E1i(self.e12i.0 * rhs.e13.0) + E1i(self.e12i.0 * rhs.e13oi.0)
+				- E1i(self.e13i.0 * rhs.e12.0)
+				- E1i(self.e13i.0 * rhs.e12oi.0)
+				+ E1i(self.e1oi.0 * rhs.e123i.0)
+				+ E1i(self.e23i.0 * rhs.eoi.0)
+				+ E1i(self.e23i.0 * rhs.s.0)
+				- E1i(self.e2oi.0 * rhs.e3i.0)
+				+ E1i(self.e3oi.0 * rhs.e2i.0),
+			e23: E23(self.e123.0 * rhs.e23oi.0) + E23(self.e12i.0 * rhs.e2o.0) - E23(self.e12o.0 * rhs.e2i.0)
+				+ E23(self.e13i.0 * rhs.e3o.0)
+				- E23(self.e13o.0 * rhs.e3i.0)
+				- E23(self.e1oi.0 * rhs.s.0)
+				+ E23(self.e23i.0 * rhs.e123o.0)
+				- E23(self.e23o.0 * rhs.e123i.0)
+				- E23(self.e2oi.0 * rhs.e12.0)
+				- E23(self.e3oi.0 * rhs.e13.0),
+			e2o: E2o(self.e123.0 * rhs.e2o.0) - E2o(self.e12o.0 * rhs.e23.0) + E2o(self.e12o.0 * rhs.e23oi.0)
+				- E2o(self.e13o.0 * rhs.eoi.0)
+				+ E2o(self.e13o.0 * rhs.s.0)
+				+ E2o(self.e1oi.0 * rhs.e3o.0)
+				+ E2o(self.e23o.0 * rhs.e12.0)
+				- E2o(self.e23o.0 * rhs.e12oi.0)
+				+ E2o(self.e2oi.0 * rhs.e123o.0)
+				- E2o(self.e3oi.0 * rhs.e1o.0),
+			e2i: -E2i(self.e123.0 * rhs.e2i.0) + E2i(self.e12i.0 * rhs.e23.0) + E2i(self.e12i.0 * rhs.e23oi.0)
+				- E2i(self.e13i.0 * rhs.eoi.0)
+				- E2i(self.e13i.0 * rhs.s.0)
+				+ E2i(self.e1oi.0 * rhs.e3i.0)
+				- E2i(self.e23i.0 * rhs.e12.0)
+				- E2i(self.e23i.0 * rhs.e12oi.0)
+				+ E2i(self.e2oi.0 * rhs.e123i.0)
+				- E2i(self.e3oi.0 * rhs.e1i.0),
+			e3o: E3o(self.e123.0 * rhs.e3o.0) + E3o(self.e12o.0 * rhs.eoi.0)
+				- E3o(self.e12o.0 * rhs.s.0)
+				- E3o(self.e13o.0 * rhs.e23.0)
+				+ E3o(self.e13o.0 * rhs.e23oi.0)
+				- E3o(self.e1oi.0 * rhs.e2o.0)
+				+ E3o(self.e23o.0 * rhs.e13.0)
+				- E3o(self.e23o.0 * rhs.e13oi.0)
+				+ E3o(self.e2oi.0 * rhs.e1o.0)
+				+ E3o(self.e3oi.0 * rhs.e123o.0),
+			e3i: -E3i(self.e123.0 * rhs.e3i.0)
+				+ E3i(self.e12i.0 * rhs.eoi.0)
+				+ E3i(self.e12i.0 * rhs.s.0)
+				+ E3i(self.e13i.0 * rhs.e23.0)
+				+ E3i(self.e13i.0 * rhs.e23oi.0)
+				- E3i(self.e1oi.0 * rhs.e2i.0)
+				- E3i(self.e23i.0 * rhs.e13.0)
+				- E3i(self.e23i.0 * rhs.e13oi.0)
+				+ E3i(self.e2oi.0 * rhs.e1i.0)
+				+ E3i(self.e3oi.0 * rhs.e123i.0),
+			eoi: Eoi(self.e123.0 * rhs.s.0) - Eoi(self.e12i.0 * rhs.e3o.0) - Eoi(self.e12o.0 * rhs.e3i.0)
+				+ Eoi(self.e13i.0 * rhs.e2o.0)
+				+ Eoi(self.e13o.0 * rhs.e2i.0)
+				+ Eoi(self.e1oi.0 * rhs.e23oi.0)
+				- Eoi(self.e23i.0 * rhs.e1o.0)
+				- Eoi(self.e23o.0 * rhs.e1i.0)
+				- Eoi(self.e2oi.0 * rhs.e13oi.0)
+				+ Eoi(self.e3oi.0 * rhs.e12oi.0),
+			e123o: E123o(self.e123.0 * rhs.e123o.0) - E123o(self.e12o.0 * rhs.e12.0) + E123o(self.e12o.0 * rhs.e12oi.0)
+				- E123o(self.e13o.0 * rhs.e13.0)
+				+ E123o(self.e13o.0 * rhs.e13oi.0)
+				- E123o(self.e1oi.0 * rhs.e1o.0)
+				- E123o(self.e23o.0 * rhs.e23.0)
+				+ E123o(self.e23o.0 * rhs.e23oi.0)
+				- E123o(self.e2oi.0 * rhs.e2o.0)
+				- E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: -E123i(self.e123.0 * rhs.e123i.0)
+				+ E123i(self.e12i.0 * rhs.e12.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				- E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e123.0 * rhs.e12.0) - E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0)
+				+ E12oi(self.e13i.0 * rhs.e1o.0)
+				+ E12oi(self.e13o.0 * rhs.e1i.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e23i.0 * rhs.e2o.0)
+				+ E12oi(self.e23o.0 * rhs.e2i.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0)
+				- E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: E13oi(self.e123.0 * rhs.e13.0)
+				- E13oi(self.e12i.0 * rhs.e1o.0)
+				- E13oi(self.e12o.0 * rhs.e1i.0)
+				- E13oi(self.e13i.0 * rhs.e123o.0)
+				- E13oi(self.e13o.0 * rhs.e123i.0)
+				- E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e23i.0 * rhs.e3o.0)
+				+ E13oi(self.e23o.0 * rhs.e3i.0)
+				+ E13oi(self.e2oi.0 * rhs.eoi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: E23oi(self.e123.0 * rhs.e23.0)
+				- E23oi(self.e12i.0 * rhs.e2o.0)
+				- E23oi(self.e12o.0 * rhs.e2i.0)
+				- E23oi(self.e13i.0 * rhs.e3o.0)
+				- E23oi(self.e13o.0 * rhs.e3i.0)
+				- E23oi(self.e1oi.0 * rhs.eoi.0)
+				- E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e23o.0 * rhs.e123i.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// Circle.dot(Versor) -> Multivector
+impl Dot<Versor> for Circle {
+	type Output = Multivector;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123.0 * rhs.e23.0) + E1(self.e12i.0 * rhs.e2o.0) + E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0) - E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0) + E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e123.0 * rhs.e12.0) - E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) - E3(self.e13i.0 * rhs.e1o.0) - E3(self.e13o.0 * rhs.e1i.0) + E3(self.e1oi.0 * rhs.e13oi.0) - E3(self.e23i.0 * rhs.e2o.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e2oi.0 * rhs.e23oi.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e23o.0 * rhs.e23.0) - Eo(self.e23o.0 * rhs.e23oi.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e123.0 * rhs.e123i.0) - Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e23i.0 * rhs.e23oi.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123.0 * rhs.e23.0)
+				+ E1(self.e12i.0 * rhs.e2o.0)
+				+ E1(self.e12o.0 * rhs.e2i.0)
+				+ E1(self.e13i.0 * rhs.e3o.0)
+				+ E1(self.e13o.0 * rhs.e3i.0)
+				+ E1(self.e1oi.0 * rhs.eoi.0)
+				- E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0)
+				+ E2(self.e13i.0 * rhs.e123o.0)
+				+ E2(self.e13o.0 * rhs.e123i.0)
+				+ E2(self.e1oi.0 * rhs.e12oi.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e23o.0 * rhs.e3i.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e123.0 * rhs.e12.0)
+				- E3(self.e12i.0 * rhs.e123o.0)
+				- E3(self.e12o.0 * rhs.e123i.0)
+				- E3(self.e13i.0 * rhs.e1o.0)
+				- E3(self.e13o.0 * rhs.e1i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				- E3(self.e23o.0 * rhs.e2i.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e1oi.0 * rhs.e1o.0)
+				- Eo(self.e23o.0 * rhs.e23.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0)
+				- Eo(self.e2oi.0 * rhs.e2o.0)
+				- Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e123.0 * rhs.e123i.0) - Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0)
+				- Ei(self.e13i.0 * rhs.e13.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e123.0 * rhs.s.0),
+			e12o: E12o(self.e12o.0 * rhs.s.0),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: E13o(self.e13o.0 * rhs.s.0),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: E23o(self.e23o.0 * rhs.s.0),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for Circle {
+	type Output = Multivector;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Circle.wedge(Versor) -> Multivector
+impl Wedge<Versor> for Circle {
+	type Output = Multivector;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123.0 * rhs.eoi.0) + E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e12o.0 * rhs.e3i.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e13o.0 * rhs.e2i.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e23o.0 * rhs.e1i.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e123.wedge(rhs.s),
+			e12o: self.e12o.wedge(rhs.s),
+			e12i: self.e12i.wedge(rhs.s),
+			e13o: self.e13o.wedge(rhs.s),
+			e13i: self.e13i.wedge(rhs.s),
+			e1oi: self.e1oi.wedge(rhs.s),
+			e23o: self.e23o.wedge(rhs.s),
+			e23i: self.e23i.wedge(rhs.s),
+			e2oi: self.e2oi.wedge(rhs.s),
+			e3oi: self.e3oi.wedge(rhs.s),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e123.wedge(rhs.eoi)
+				+ self.e12i.wedge(rhs.e3o)
+				+ self.e12o.wedge(rhs.e3i)
+				+ self.e13i.wedge(rhs.e2o)
+				+ self.e13o.wedge(rhs.e2i)
+				+ self.e1oi.wedge(rhs.e23)
+				+ self.e23i.wedge(rhs.e1o)
+				+ self.e23o.wedge(rhs.e1i)
+				+ self.e2oi.wedge(rhs.e13)
+				+ self.e3oi.wedge(rhs.e12),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for Circle {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Circle.anti_wedge(Versor) -> Versor
+impl AntiWedge<Versor> for Circle {
+	type Output = Versor;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e123.0 * rhs.eoi.0) + S(self.e12i.0 * rhs.e3o.0) - S(self.e12o.0 * rhs.e3i.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e13o.0 * rhs.e2i.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e23o.0 * rhs.e1i.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e12  : E12(self.e123.0 * rhs.e12oi.0) + E12(self.e12i.0 * rhs.e123o.0) - E12(self.e12o.0 * rhs.e123i.0),
+		//     e13  : E13(self.e123.0 * rhs.e13oi.0) + E13(self.e13i.0 * rhs.e123o.0) - E13(self.e13o.0 * rhs.e123i.0),
+		//     e1o  : E1o(self.e12o.0 * rhs.e13oi.0) - E1o(self.e13o.0 * rhs.e12oi.0) + E1o(self.e1oi.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23  : E23(self.e123.0 * rhs.e23oi.0) + E23(self.e23i.0 * rhs.e123o.0) - E23(self.e23o.0 * rhs.e123i.0),
+		//     e2o  : E2o(self.e12o.0 * rhs.e23oi.0) - E2o(self.e23o.0 * rhs.e12oi.0) + E2o(self.e2oi.0 * rhs.e123o.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.e13o.0 * rhs.e23oi.0) - E3o(self.e23o.0 * rhs.e13oi.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi  : Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: self.e123.anti_wedge(rhs.eoi)
+				+ self.e12i.anti_wedge(rhs.e3o)
+				+ self.e12o.anti_wedge(rhs.e3i)
+				+ self.e13i.anti_wedge(rhs.e2o)
+				+ self.e13o.anti_wedge(rhs.e2i)
+				+ self.e1oi.anti_wedge(rhs.e23)
+				+ self.e23i.anti_wedge(rhs.e1o)
+				+ self.e23o.anti_wedge(rhs.e1i)
+				+ self.e2oi.anti_wedge(rhs.e13)
+				+ self.e3oi.anti_wedge(rhs.e12),
+			e12: self.e123.anti_wedge(rhs.e12oi) + self.e12i.anti_wedge(rhs.e123o) + self.e12o.anti_wedge(rhs.e123i),
+			e13: self.e123.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e123o) + self.e13o.anti_wedge(rhs.e123i),
+			e1o: self.e12o.anti_wedge(rhs.e13oi) + self.e13o.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123o),
+			e1i: self.e12i.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123i),
+			e23: self.e123.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e123o) + self.e23o.anti_wedge(rhs.e123i),
+			e2o: self.e12o.anti_wedge(rhs.e23oi) + self.e23o.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123o),
+			e2i: self.e12i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123i),
+			e3o: self.e13o.anti_wedge(rhs.e23oi) + self.e23o.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123o),
+			e3i: self.e13i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123i),
+			eoi: self.e1oi.anti_wedge(rhs.e23oi) + self.e2oi.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e12oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Versor> for Circle {
+	type Output = Versor;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Circle.left_contraction(Versor) -> Point
+impl LeftContraction<Versor> for Circle {
+	type Output = Point;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     inf: -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0),
+			o: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0),
+			inf: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Circle.right_contraction(Versor) -> Multivector
+impl RightContraction<Versor> for Circle {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123.0 * rhs.e23.0) + E1(self.e12i.0 * rhs.e2o.0) + E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     e2    : E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     e3    : -E3(self.e123.0 * rhs.e12.0) - E3(self.e13i.0 * rhs.e1o.0) - E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23i.0 * rhs.e2o.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e23o.0 * rhs.e23.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123.0 * rhs.e23.0)
+				+ E1(self.e12i.0 * rhs.e2o.0)
+				+ E1(self.e12o.0 * rhs.e2i.0)
+				+ E1(self.e13i.0 * rhs.e3o.0)
+				+ E1(self.e13o.0 * rhs.e3i.0)
+				+ E1(self.e1oi.0 * rhs.eoi.0),
+			e2: E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e23o.0 * rhs.e3i.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0),
+			e3: -E3(self.e123.0 * rhs.e12.0)
+				- E3(self.e13i.0 * rhs.e1o.0)
+				- E3(self.e13o.0 * rhs.e1i.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				- E3(self.e23o.0 * rhs.e2i.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e12o.0 * rhs.e12.0)
+				- Eo(self.e13o.0 * rhs.e13.0)
+				- Eo(self.e1oi.0 * rhs.e1o.0)
+				- Eo(self.e23o.0 * rhs.e23.0)
+				- Eo(self.e2oi.0 * rhs.e2o.0)
+				- Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e123.0 * rhs.s.0),
+			e12o: E12o(self.e12o.0 * rhs.s.0),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: E13o(self.e13o.0 * rhs.s.0),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: E23o(self.e23o.0 * rhs.s.0),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Versor> for Circle {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Versor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Circle.commutator(Versor) -> Multivector
+impl Commutator<Versor> for Circle {
+	type Output = Multivector;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     eo    : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     ei    : -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13i.0 * rhs.e2o.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23i.0 * rhs.e1o.0) + E123(self.e23o.0 * rhs.e1i.0),
+		//     e12o  : E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0) - E12o(self.e13o.0 * rhs.e23.0) - E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e23o.0 * rhs.e13.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+		//     e12i  : E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o  : -E13o(self.e123.0 * rhs.e2o.0) + E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e13o.0 * rhs.eoi.0) - E13o(self.e1oi.0 * rhs.e3o.0) - E13o(self.e23o.0 * rhs.e12.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i  : -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0) + E1oi(self.e13i.0 * rhs.e3o.0) - E1oi(self.e13o.0 * rhs.e3i.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0) + E23o(self.e13o.0 * rhs.e12.0) + E23o(self.e23o.0 * rhs.eoi.0) - E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e12o.0 * rhs.e1i.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) - E2oi(self.e23o.0 * rhs.e3i.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e23o.0 * rhs.e2i.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0),
+			eo: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0),
+			ei: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0)
+				- E123(self.e13i.0 * rhs.e2o.0)
+				- E123(self.e13o.0 * rhs.e2i.0)
+				+ E123(self.e23i.0 * rhs.e1o.0)
+				+ E123(self.e23o.0 * rhs.e1i.0),
+			e12o: E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0)
+				- E12o(self.e13o.0 * rhs.e23.0)
+				- E12o(self.e1oi.0 * rhs.e2o.0)
+				+ E12o(self.e23o.0 * rhs.e13.0)
+				+ E12o(self.e2oi.0 * rhs.e1o.0),
+			e12i: E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: -E13o(self.e123.0 * rhs.e2o.0) + E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e13o.0 * rhs.eoi.0)
+				- E13o(self.e1oi.0 * rhs.e3o.0)
+				- E13o(self.e23o.0 * rhs.e12.0)
+				+ E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0)
+				+ E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0) + E1oi(self.e13i.0 * rhs.e3o.0)
+				- E1oi(self.e13o.0 * rhs.e3i.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0)
+				+ E23o(self.e13o.0 * rhs.e12.0)
+				+ E23o(self.e23o.0 * rhs.eoi.0)
+				- E23o(self.e2oi.0 * rhs.e3o.0)
+				+ E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0)
+				- E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e1o.0)
+				+ E2oi(self.e12o.0 * rhs.e1i.0)
+				+ E2oi(self.e1oi.0 * rhs.e12.0)
+				+ E2oi(self.e23i.0 * rhs.e3o.0)
+				- E2oi(self.e23o.0 * rhs.e3i.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e1oi.0 * rhs.e13.0)
+				- E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e23o.0 * rhs.e2i.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Circle.anti_dot(Versor) -> Versor
+impl AntiDot<Versor> for Circle {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : E13(self.e2oi.0 * rhs.s.0),
+		//     e1o  : -E1o(self.e23o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e23i.0 * rhs.s.0),
+		//     e23  : -E23(self.e1oi.0 * rhs.s.0),
+		//     e2o  : E2o(self.e13o.0 * rhs.s.0),
+		//     e2i  : -E2i(self.e13i.0 * rhs.s.0),
+		//     e3o  : -E3o(self.e12o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e12i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.e123.0 * rhs.s.0),
+		//     e123o: E123o(self.e123.0 * rhs.e123o.0) - E123o(self.e12o.0 * rhs.e12.0) + E123o(self.e12o.0 * rhs.e12oi.0) - E123o(self.e13o.0 * rhs.e13.0) + E123o(self.e13o.0 * rhs.e13oi.0) - E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e23o.0 * rhs.e23.0) + E123o(self.e23o.0 * rhs.e23oi.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i: -E123i(self.e123.0 * rhs.e123i.0) + E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e13i.0 * rhs.e13oi.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) + E123i(self.e23i.0 * rhs.e23oi.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.e123.0 * rhs.e12.0) - E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0) + E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e23i.0 * rhs.e2o.0) + E12oi(self.e23o.0 * rhs.e2i.0) + E12oi(self.e2oi.0 * rhs.e23oi.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: E13oi(self.e123.0 * rhs.e13.0) - E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e12o.0 * rhs.e1i.0) - E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e13o.0 * rhs.e123i.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e23o.0 * rhs.e3i.0) + E13oi(self.e2oi.0 * rhs.eoi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: E23oi(self.e123.0 * rhs.e23.0) - E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e13o.0 * rhs.e3i.0) - E23oi(self.e1oi.0 * rhs.eoi.0) - E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e23o.0 * rhs.e123i.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e2oi.0 * rhs.s.0),
+			e1o: -E1o(self.e23o.0 * rhs.s.0),
+			e1i: E1i(self.e23i.0 * rhs.s.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0),
+			e2o: E2o(self.e13o.0 * rhs.s.0),
+			e2i: -E2i(self.e13i.0 * rhs.s.0),
+			e3o: -E3o(self.e12o.0 * rhs.s.0),
+			e3i: E3i(self.e12i.0 * rhs.s.0),
+			eoi: Eoi(self.e123.0 * rhs.s.0),
+			e123o: E123o(self.e123.0 * rhs.e123o.0) - E123o(self.e12o.0 * rhs.e12.0) + E123o(self.e12o.0 * rhs.e12oi.0)
+				- E123o(self.e13o.0 * rhs.e13.0)
+				+ E123o(self.e13o.0 * rhs.e13oi.0)
+				- E123o(self.e1oi.0 * rhs.e1o.0)
+				- E123o(self.e23o.0 * rhs.e23.0)
+				+ E123o(self.e23o.0 * rhs.e23oi.0)
+				- E123o(self.e2oi.0 * rhs.e2o.0)
+				- E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: -E123i(self.e123.0 * rhs.e123i.0)
+				+ E123i(self.e12i.0 * rhs.e12.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				- E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e123.0 * rhs.e12.0) - E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0)
+				+ E12oi(self.e13i.0 * rhs.e1o.0)
+				+ E12oi(self.e13o.0 * rhs.e1i.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e23i.0 * rhs.e2o.0)
+				+ E12oi(self.e23o.0 * rhs.e2i.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0)
+				- E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: E13oi(self.e123.0 * rhs.e13.0)
+				- E13oi(self.e12i.0 * rhs.e1o.0)
+				- E13oi(self.e12o.0 * rhs.e1i.0)
+				- E13oi(self.e13i.0 * rhs.e123o.0)
+				- E13oi(self.e13o.0 * rhs.e123i.0)
+				- E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e23i.0 * rhs.e3o.0)
+				+ E13oi(self.e23o.0 * rhs.e3i.0)
+				+ E13oi(self.e2oi.0 * rhs.eoi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: E23oi(self.e123.0 * rhs.e23.0)
+				- E23oi(self.e12i.0 * rhs.e2o.0)
+				- E23oi(self.e12o.0 * rhs.e2i.0)
+				- E23oi(self.e13i.0 * rhs.e3o.0)
+				- E23oi(self.e13o.0 * rhs.e3i.0)
+				- E23oi(self.e1oi.0 * rhs.eoi.0)
+				- E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e23o.0 * rhs.e123i.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Circle OP Multivector:
+
+// Circle.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Circle {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123.0 * rhs.e123.0) + S(self.e12i.0 * rhs.e12o.0) + S(self.e12o.0 * rhs.e12i.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e1    : -E1(self.e123.0 * rhs.e23.0) + E1(self.e12i.0 * rhs.e2o.0) + E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0) - E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0) + E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e123.0 * rhs.e12.0) - E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) - E3(self.e13i.0 * rhs.e1o.0) - E3(self.e13o.0 * rhs.e1i.0) + E3(self.e1oi.0 * rhs.e13oi.0) - E3(self.e23i.0 * rhs.e2o.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e2oi.0 * rhs.e23oi.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e23o.0 * rhs.e23.0) - Eo(self.e23o.0 * rhs.e23oi.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e123.0 * rhs.e123i.0) - Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e23i.0 * rhs.e23oi.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : E12(self.e123.0 * rhs.e3.0) - E12(self.e12i.0 * rhs.eo.0) - E12(self.e12o.0 * rhs.ei.0) + E12(self.e13i.0 * rhs.e23o.0) + E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e23i.0 * rhs.e13o.0) - E12(self.e23o.0 * rhs.e13i.0) - E12(self.e2oi.0 * rhs.e1oi.0) + E12(self.e3oi.0 * rhs.e123oi.0),
+		//     e13   : -E13(self.e123.0 * rhs.e2.0) - E13(self.e12i.0 * rhs.e23o.0) - E13(self.e12o.0 * rhs.e23i.0) - E13(self.e13i.0 * rhs.eo.0) - E13(self.e13o.0 * rhs.ei.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0) + E13(self.e23o.0 * rhs.e12i.0) - E13(self.e2oi.0 * rhs.e123oi.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o   : -E1o(self.e123.0 * rhs.e23o.0) - E1o(self.e12o.0 * rhs.e2.0) - E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3.0) - E1o(self.e13o.0 * rhs.e3oi.0) - E1o(self.e1oi.0 * rhs.eo.0) + E1o(self.e23o.0 * rhs.e123.0) + E1o(self.e23o.0 * rhs.e123oi.0) + E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+		//     e1i   : -E1i(self.e123.0 * rhs.e23i.0) - E1i(self.e12i.0 * rhs.e2.0) + E1i(self.e12i.0 * rhs.e2oi.0) - E1i(self.e13i.0 * rhs.e3.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e1oi.0 * rhs.ei.0) + E1i(self.e23i.0 * rhs.e123.0) - E1i(self.e23i.0 * rhs.e123oi.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23   : E23(self.e123.0 * rhs.e1.0) + E23(self.e12i.0 * rhs.e13o.0) + E23(self.e12o.0 * rhs.e13i.0) - E23(self.e13i.0 * rhs.e12o.0) - E23(self.e13o.0 * rhs.e12i.0) + E23(self.e1oi.0 * rhs.e123oi.0) - E23(self.e23i.0 * rhs.eo.0) - E23(self.e23o.0 * rhs.ei.0) + E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o   : E2o(self.e123.0 * rhs.e13o.0) + E2o(self.e12o.0 * rhs.e1.0) + E2o(self.e12o.0 * rhs.e1oi.0) - E2o(self.e13o.0 * rhs.e123.0) - E2o(self.e13o.0 * rhs.e123oi.0) - E2o(self.e1oi.0 * rhs.e12o.0) - E2o(self.e23o.0 * rhs.e3.0) - E2o(self.e23o.0 * rhs.e3oi.0) - E2o(self.e2oi.0 * rhs.eo.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+		//     e2i   : E2i(self.e123.0 * rhs.e13i.0) + E2i(self.e12i.0 * rhs.e1.0) - E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0) + E2i(self.e13i.0 * rhs.e123oi.0) + E2i(self.e1oi.0 * rhs.e12i.0) - E2i(self.e23i.0 * rhs.e3.0) + E2i(self.e23i.0 * rhs.e3oi.0) + E2i(self.e2oi.0 * rhs.ei.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o   : -E3o(self.e123.0 * rhs.e12o.0) + E3o(self.e12o.0 * rhs.e123.0) + E3o(self.e12o.0 * rhs.e123oi.0) + E3o(self.e13o.0 * rhs.e1.0) + E3o(self.e13o.0 * rhs.e1oi.0) - E3o(self.e1oi.0 * rhs.e13o.0) + E3o(self.e23o.0 * rhs.e2.0) + E3o(self.e23o.0 * rhs.e2oi.0) - E3o(self.e2oi.0 * rhs.e23o.0) - E3o(self.e3oi.0 * rhs.eo.0),
+		//     e3i   : -E3i(self.e123.0 * rhs.e12i.0) + E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e12i.0 * rhs.e123oi.0) + E3i(self.e13i.0 * rhs.e1.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) + E3i(self.e23i.0 * rhs.e2.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0) + E3i(self.e3oi.0 * rhs.ei.0),
+		//     eoi   : -Eoi(self.e123.0 * rhs.e123oi.0) + Eoi(self.e12i.0 * rhs.e12o.0) - Eoi(self.e12o.0 * rhs.e12i.0) + Eoi(self.e13i.0 * rhs.e13o.0) - Eoi(self.e13o.0 * rhs.e13i.0) + Eoi(self.e1oi.0 * rhs.e1.0) + Eoi(self.e23i.0 * rhs.e23o.0) - Eoi(self.e23o.0 * rhs.e23i.0) + Eoi(self.e2oi.0 * rhs.e2.0) + Eoi(self.e3oi.0 * rhs.e3.0),
+		//     e123  : E123(self.e123.0 * rhs.s.0) + E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13i.0 * rhs.e2o.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e1oi.0 * rhs.e23oi.0) + E123(self.e23i.0 * rhs.e1o.0) + E123(self.e23o.0 * rhs.e1i.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+		//     e12o  : E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0) + E12o(self.e12o.0 * rhs.s.0) - E12o(self.e13o.0 * rhs.e23.0) - E12o(self.e13o.0 * rhs.e23oi.0) - E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e23o.0 * rhs.e13.0) + E12o(self.e23o.0 * rhs.e13oi.0) + E12o(self.e2oi.0 * rhs.e1o.0) - E12o(self.e3oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e12i.0 * rhs.s.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e13i.0 * rhs.e23oi.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e23i.0 * rhs.e13oi.0) - E12i(self.e2oi.0 * rhs.e1i.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.e123.0 * rhs.e2o.0) + E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e12o.0 * rhs.e23oi.0) + E13o(self.e13o.0 * rhs.eoi.0) + E13o(self.e13o.0 * rhs.s.0) - E13o(self.e1oi.0 * rhs.e3o.0) - E13o(self.e23o.0 * rhs.e12.0) - E13o(self.e23o.0 * rhs.e12oi.0) + E13o(self.e2oi.0 * rhs.e123o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i  : -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e12i.0 * rhs.e23oi.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e13i.0 * rhs.s.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : -E1oi(self.e123.0 * rhs.e23oi.0) + E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0) + E1oi(self.e13i.0 * rhs.e3o.0) - E1oi(self.e13o.0 * rhs.e3i.0) + E1oi(self.e1oi.0 * rhs.s.0) - E1oi(self.e23i.0 * rhs.e123o.0) + E1oi(self.e23o.0 * rhs.e123i.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0) - E23o(self.e12o.0 * rhs.e13oi.0) + E23o(self.e13o.0 * rhs.e12.0) + E23o(self.e13o.0 * rhs.e12oi.0) - E23o(self.e1oi.0 * rhs.e123o.0) + E23o(self.e23o.0 * rhs.eoi.0) + E23o(self.e23o.0 * rhs.s.0) - E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e12i.0 * rhs.e13oi.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e23i.0 * rhs.s.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.e123.0 * rhs.e13oi.0) - E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e12o.0 * rhs.e1i.0) + E2oi(self.e13i.0 * rhs.e123o.0) - E2oi(self.e13o.0 * rhs.e123i.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) - E2oi(self.e23o.0 * rhs.e3i.0) + E2oi(self.e2oi.0 * rhs.s.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : -E3oi(self.e123.0 * rhs.e12oi.0) - E3oi(self.e12i.0 * rhs.e123o.0) + E3oi(self.e12o.0 * rhs.e123i.0) - E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e23o.0 * rhs.e2i.0) + E3oi(self.e2oi.0 * rhs.e23.0) + E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : E123o(self.e123.0 * rhs.eo.0) - E123o(self.e12o.0 * rhs.e3.0) - E123o(self.e12o.0 * rhs.e3oi.0) + E123o(self.e13o.0 * rhs.e2.0) + E123o(self.e13o.0 * rhs.e2oi.0) - E123o(self.e1oi.0 * rhs.e23o.0) - E123o(self.e23o.0 * rhs.e1.0) - E123o(self.e23o.0 * rhs.e1oi.0) + E123o(self.e2oi.0 * rhs.e13o.0) - E123o(self.e3oi.0 * rhs.e12o.0),
+		//     e123i : E123i(self.e123.0 * rhs.ei.0) - E123i(self.e12i.0 * rhs.e3.0) + E123i(self.e12i.0 * rhs.e3oi.0) + E123i(self.e13i.0 * rhs.e2.0) - E123i(self.e13i.0 * rhs.e2oi.0) + E123i(self.e1oi.0 * rhs.e23i.0) - E123i(self.e23i.0 * rhs.e1.0) + E123i(self.e23i.0 * rhs.e1oi.0) - E123i(self.e2oi.0 * rhs.e13i.0) + E123i(self.e3oi.0 * rhs.e12i.0),
+		//     e12oi : E12oi(self.e123.0 * rhs.e3oi.0) - E12oi(self.e12i.0 * rhs.eo.0) + E12oi(self.e12o.0 * rhs.ei.0) + E12oi(self.e13i.0 * rhs.e23o.0) - E12oi(self.e13o.0 * rhs.e23i.0) + E12oi(self.e1oi.0 * rhs.e2.0) - E12oi(self.e23i.0 * rhs.e13o.0) + E12oi(self.e23o.0 * rhs.e13i.0) - E12oi(self.e2oi.0 * rhs.e1.0) + E12oi(self.e3oi.0 * rhs.e123.0),
+		//     e13oi : -E13oi(self.e123.0 * rhs.e2oi.0) - E13oi(self.e12i.0 * rhs.e23o.0) + E13oi(self.e12o.0 * rhs.e23i.0) - E13oi(self.e13i.0 * rhs.eo.0) + E13oi(self.e13o.0 * rhs.ei.0) + E13oi(self.e1oi.0 * rhs.e3.0) + E13oi(self.e23i.0 * rhs.e12o.0) - E13oi(self.e23o.0 * rhs.e12i.0) - E13oi(self.e2oi.0 * rhs.e123.0) - E13oi(self.e3oi.0 * rhs.e1.0),
+		//     e23oi : E23oi(self.e123.0 * rhs.e1oi.0) + E23oi(self.e12i.0 * rhs.e13o.0) - E23oi(self.e12o.0 * rhs.e13i.0) - E23oi(self.e13i.0 * rhs.e12o.0) + E23oi(self.e13o.0 * rhs.e12i.0) + E23oi(self.e1oi.0 * rhs.e123.0) - E23oi(self.e23i.0 * rhs.eo.0) + E23oi(self.e23o.0 * rhs.ei.0) + E23oi(self.e2oi.0 * rhs.e3.0) - E23oi(self.e3oi.0 * rhs.e2.0),
+		//     e123oi: E123oi(self.e123.0 * rhs.eoi.0) + E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e12o.0 * rhs.e3i.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e13o.0 * rhs.e2i.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e23o.0 * rhs.e1i.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: -S(self.e123.0 * rhs.e123.0)
+				+ S(self.e12i.0 * rhs.e12o.0)
+				+ S(self.e12o.0 * rhs.e12i.0)
+				+ S(self.e13i.0 * rhs.e13o.0)
+				+ S(self.e13o.0 * rhs.e13i.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23i.0 * rhs.e23o.0)
+				+ S(self.e23o.0 * rhs.e23i.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e1: -E1(self.e123.0 * rhs.e23.0)
+				+ E1(self.e12i.0 * rhs.e2o.0)
+				+ E1(self.e12o.0 * rhs.e2i.0)
+				+ E1(self.e13i.0 * rhs.e3o.0)
+				+ E1(self.e13o.0 * rhs.e3i.0)
+				+ E1(self.e1oi.0 * rhs.eoi.0)
+				- E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0)
+				+ E2(self.e13i.0 * rhs.e123o.0)
+				+ E2(self.e13o.0 * rhs.e123i.0)
+				+ E2(self.e1oi.0 * rhs.e12oi.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e23o.0 * rhs.e3i.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e123.0 * rhs.e12.0)
+				- E3(self.e12i.0 * rhs.e123o.0)
+				- E3(self.e12o.0 * rhs.e123i.0)
+				- E3(self.e13i.0 * rhs.e1o.0)
+				- E3(self.e13o.0 * rhs.e1i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				- E3(self.e23o.0 * rhs.e2i.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e1oi.0 * rhs.e1o.0)
+				- Eo(self.e23o.0 * rhs.e23.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0)
+				- Eo(self.e2oi.0 * rhs.e2o.0)
+				- Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e123.0 * rhs.e123i.0) - Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0)
+				- Ei(self.e13i.0 * rhs.e13.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: E12(self.e123.0 * rhs.e3.0) - E12(self.e12i.0 * rhs.eo.0) - E12(self.e12o.0 * rhs.ei.0)
+				+ E12(self.e13i.0 * rhs.e23o.0)
+				+ E12(self.e13o.0 * rhs.e23i.0)
+				+ E12(self.e1oi.0 * rhs.e2oi.0)
+				- E12(self.e23i.0 * rhs.e13o.0)
+				- E12(self.e23o.0 * rhs.e13i.0)
+				- E12(self.e2oi.0 * rhs.e1oi.0)
+				+ E12(self.e3oi.0 * rhs.e123oi.0),
+			e13: -E13(self.e123.0 * rhs.e2.0)
+				- E13(self.e12i.0 * rhs.e23o.0)
+				- E13(self.e12o.0 * rhs.e23i.0)
+				- E13(self.e13i.0 * rhs.eo.0)
+				- E13(self.e13o.0 * rhs.ei.0)
+				+ E13(self.e1oi.0 * rhs.e3oi.0)
+				+ E13(self.e23i.0 * rhs.e12o.0)
+				+ E13(self.e23o.0 * rhs.e12i.0)
+				- E13(self.e2oi.0 * rhs.e123oi.0)
+				- E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: -E1o(self.e123.0 * rhs.e23o.0)
+				- E1o(self.e12o.0 * rhs.e2.0)
+				- E1o(self.e12o.0 * rhs.e2oi.0)
+				- E1o(self.e13o.0 * rhs.e3.0)
+				- E1o(self.e13o.0 * rhs.e3oi.0)
+				- E1o(self.e1oi.0 * rhs.eo.0)
+				+ E1o(self.e23o.0 * rhs.e123.0)
+				+ E1o(self.e23o.0 * rhs.e123oi.0)
+				+ E1o(self.e2oi.0 * rhs.e12o.0)
+				+ E1o(self.e3oi.0 * rhs.e13o.0),
+			e1i: -E1i(self.e123.0 * rhs.e23i.0) - E1i(self.e12i.0 * rhs.e2.0) + E1i(self.e12i.0 * rhs.e2oi.0)
+				- E1i(self.e13i.0 * rhs.e3.0)
+				+ E1i(self.e13i.0 * rhs.e3oi.0)
+				+ E1i(self.e1oi.0 * rhs.ei.0)
+				+ E1i(self.e23i.0 * rhs.e123.0)
+				- E1i(self.e23i.0 * rhs.e123oi.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e123.0 * rhs.e1.0) + E23(self.e12i.0 * rhs.e13o.0) + E23(self.e12o.0 * rhs.e13i.0)
+				- E23(self.e13i.0 * rhs.e12o.0)
+				- E23(self.e13o.0 * rhs.e12i.0)
+				+ E23(self.e1oi.0 * rhs.e123oi.0)
+				- E23(self.e23i.0 * rhs.eo.0)
+				- E23(self.e23o.0 * rhs.ei.0)
+				+ E23(self.e2oi.0 * rhs.e3oi.0)
+				- E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: E2o(self.e123.0 * rhs.e13o.0) + E2o(self.e12o.0 * rhs.e1.0) + E2o(self.e12o.0 * rhs.e1oi.0)
+				- E2o(self.e13o.0 * rhs.e123.0)
+				- E2o(self.e13o.0 * rhs.e123oi.0)
+				- E2o(self.e1oi.0 * rhs.e12o.0)
+				- E2o(self.e23o.0 * rhs.e3.0)
+				- E2o(self.e23o.0 * rhs.e3oi.0)
+				- E2o(self.e2oi.0 * rhs.eo.0)
+				+ E2o(self.e3oi.0 * rhs.e23o.0),
+			e2i: E2i(self.e123.0 * rhs.e13i.0) + E2i(self.e12i.0 * rhs.e1.0)
+				- E2i(self.e12i.0 * rhs.e1oi.0)
+				- E2i(self.e13i.0 * rhs.e123.0)
+				+ E2i(self.e13i.0 * rhs.e123oi.0)
+				+ E2i(self.e1oi.0 * rhs.e12i.0)
+				- E2i(self.e23i.0 * rhs.e3.0)
+				+ E2i(self.e23i.0 * rhs.e3oi.0)
+				+ E2i(self.e2oi.0 * rhs.ei.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: -E3o(self.e123.0 * rhs.e12o.0)
+				+ E3o(self.e12o.0 * rhs.e123.0)
+				+ E3o(self.e12o.0 * rhs.e123oi.0)
+				+ E3o(self.e13o.0 * rhs.e1.0)
+				+ E3o(self.e13o.0 * rhs.e1oi.0)
+				- E3o(self.e1oi.0 * rhs.e13o.0)
+				+ E3o(self.e23o.0 * rhs.e2.0)
+				+ E3o(self.e23o.0 * rhs.e2oi.0)
+				- E3o(self.e2oi.0 * rhs.e23o.0)
+				- E3o(self.e3oi.0 * rhs.eo.0),
+			e3i: -E3i(self.e123.0 * rhs.e12i.0) + E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e12i.0 * rhs.e123oi.0)
+				+ E3i(self.e13i.0 * rhs.e1.0)
+				- E3i(self.e13i.0 * rhs.e1oi.0)
+				+ E3i(self.e1oi.0 * rhs.e13i.0)
+				+ E3i(self.e23i.0 * rhs.e2.0)
+				- E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0)
+				+ E3i(self.e3oi.0 * rhs.ei.0),
+			eoi: -Eoi(self.e123.0 * rhs.e123oi.0) + Eoi(self.e12i.0 * rhs.e12o.0) - Eoi(self.e12o.0 * rhs.e12i.0)
+				+ Eoi(self.e13i.0 * rhs.e13o.0)
+				- Eoi(self.e13o.0 * rhs.e13i.0)
+				+ Eoi(self.e1oi.0 * rhs.e1.0)
+				+ Eoi(self.e23i.0 * rhs.e23o.0)
+				- Eoi(self.e23o.0 * rhs.e23i.0)
+				+ Eoi(self.e2oi.0 * rhs.e2.0)
+				+ Eoi(self.e3oi.0 * rhs.e3.0),
+			e123: E123(self.e123.0 * rhs.s.0) + E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0)
+				- E123(self.e13i.0 * rhs.e2o.0)
+				- E123(self.e13o.0 * rhs.e2i.0)
+				+ E123(self.e1oi.0 * rhs.e23oi.0)
+				+ E123(self.e23i.0 * rhs.e1o.0)
+				+ E123(self.e23o.0 * rhs.e1i.0)
+				- E123(self.e2oi.0 * rhs.e13oi.0)
+				+ E123(self.e3oi.0 * rhs.e12oi.0),
+			e12o: E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0) + E12o(self.e12o.0 * rhs.s.0)
+				- E12o(self.e13o.0 * rhs.e23.0)
+				- E12o(self.e13o.0 * rhs.e23oi.0)
+				- E12o(self.e1oi.0 * rhs.e2o.0)
+				+ E12o(self.e23o.0 * rhs.e13.0)
+				+ E12o(self.e23o.0 * rhs.e13oi.0)
+				+ E12o(self.e2oi.0 * rhs.e1o.0)
+				- E12o(self.e3oi.0 * rhs.e123o.0),
+			e12i: E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e12i.0 * rhs.s.0)
+				- E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e13i.0 * rhs.e23oi.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e23i.0 * rhs.e13oi.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0)
+				+ E12i(self.e3oi.0 * rhs.e123i.0),
+			e13o: -E13o(self.e123.0 * rhs.e2o.0)
+				+ E13o(self.e12o.0 * rhs.e23.0)
+				+ E13o(self.e12o.0 * rhs.e23oi.0)
+				+ E13o(self.e13o.0 * rhs.eoi.0)
+				+ E13o(self.e13o.0 * rhs.s.0)
+				- E13o(self.e1oi.0 * rhs.e3o.0)
+				- E13o(self.e23o.0 * rhs.e12.0)
+				- E13o(self.e23o.0 * rhs.e12oi.0)
+				+ E13o(self.e2oi.0 * rhs.e123o.0)
+				+ E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0)
+				- E13i(self.e12i.0 * rhs.e23oi.0)
+				- E13i(self.e13i.0 * rhs.eoi.0)
+				+ E13i(self.e13i.0 * rhs.s.0)
+				+ E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				+ E13i(self.e23i.0 * rhs.e12oi.0)
+				- E13i(self.e2oi.0 * rhs.e123i.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: -E1oi(self.e123.0 * rhs.e23oi.0) + E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0)
+				+ E1oi(self.e13i.0 * rhs.e3o.0)
+				- E1oi(self.e13o.0 * rhs.e3i.0)
+				+ E1oi(self.e1oi.0 * rhs.s.0)
+				- E1oi(self.e23i.0 * rhs.e123o.0)
+				+ E1oi(self.e23o.0 * rhs.e123i.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0) - E23o(self.e12o.0 * rhs.e13oi.0)
+				+ E23o(self.e13o.0 * rhs.e12.0)
+				+ E23o(self.e13o.0 * rhs.e12oi.0)
+				- E23o(self.e1oi.0 * rhs.e123o.0)
+				+ E23o(self.e23o.0 * rhs.eoi.0)
+				+ E23o(self.e23o.0 * rhs.s.0)
+				- E23o(self.e2oi.0 * rhs.e3o.0)
+				+ E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0)
+				+ E23i(self.e12i.0 * rhs.e13oi.0)
+				+ E23i(self.e13i.0 * rhs.e12.0)
+				- E23i(self.e13i.0 * rhs.e12oi.0)
+				+ E23i(self.e1oi.0 * rhs.e123i.0)
+				- E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e23i.0 * rhs.s.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: E2oi(self.e123.0 * rhs.e13oi.0) - E2oi(self.e12i.0 * rhs.e1o.0)
+				+ E2oi(self.e12o.0 * rhs.e1i.0)
+				+ E2oi(self.e13i.0 * rhs.e123o.0)
+				- E2oi(self.e13o.0 * rhs.e123i.0)
+				+ E2oi(self.e1oi.0 * rhs.e12.0)
+				+ E2oi(self.e23i.0 * rhs.e3o.0)
+				- E2oi(self.e23o.0 * rhs.e3i.0)
+				+ E2oi(self.e2oi.0 * rhs.s.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e123.0 * rhs.e12oi.0) - E3oi(self.e12i.0 * rhs.e123o.0) + E3oi(self.e12o.0 * rhs.e123i.0)
+				- E3oi(self.e13i.0 * rhs.e1o.0)
+				+ E3oi(self.e13o.0 * rhs.e1i.0)
+				+ E3oi(self.e1oi.0 * rhs.e13.0)
+				- E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e23o.0 * rhs.e2i.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0)
+				+ E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: E123o(self.e123.0 * rhs.eo.0) - E123o(self.e12o.0 * rhs.e3.0) - E123o(self.e12o.0 * rhs.e3oi.0)
+				+ E123o(self.e13o.0 * rhs.e2.0)
+				+ E123o(self.e13o.0 * rhs.e2oi.0)
+				- E123o(self.e1oi.0 * rhs.e23o.0)
+				- E123o(self.e23o.0 * rhs.e1.0)
+				- E123o(self.e23o.0 * rhs.e1oi.0)
+				+ E123o(self.e2oi.0 * rhs.e13o.0)
+				- E123o(self.e3oi.0 * rhs.e12o.0),
+			e123i: E123i(self.e123.0 * rhs.ei.0) - E123i(self.e12i.0 * rhs.e3.0)
+				+ E123i(self.e12i.0 * rhs.e3oi.0)
+				+ E123i(self.e13i.0 * rhs.e2.0)
+				- E123i(self.e13i.0 * rhs.e2oi.0)
+				+ E123i(self.e1oi.0 * rhs.e23i.0)
+				- E123i(self.e23i.0 * rhs.e1.0)
+				+ E123i(self.e23i.0 * rhs.e1oi.0)
+				- E123i(self.e2oi.0 * rhs.e13i.0)
+				+ E123i(self.e3oi.0 * rhs.e12i.0),
+			e12oi: E12oi(self.e123.0 * rhs.e3oi.0) - E12oi(self.e12i.0 * rhs.eo.0)
+				+ E12oi(self.e12o.0 * rhs.ei.0)
+				+ E12oi(self.e13i.0 * rhs.e23o.0)
+				- E12oi(self.e13o.0 * rhs.e23i.0)
+				+ E12oi(self.e1oi.0 * rhs.e2.0)
+				- E12oi(self.e23i.0 * rhs.e13o.0)
+				+ E12oi(self.e23o.0 * rhs.e13i.0)
+				- E12oi(self.e2oi.0 * rhs.e1.0)
+				+ E12oi(self.e3oi.0 * rhs.e123.0),
+			e13oi: -E13oi(self.e123.0 * rhs.e2oi.0) - E13oi(self.e12i.0 * rhs.e23o.0) + E13oi(self.e12o.0 * rhs.e23i.0)
+				- E13oi(self.e13i.0 * rhs.eo.0)
+				+ E13oi(self.e13o.0 * rhs.ei.0)
+				+ E13oi(self.e1oi.0 * rhs.e3.0)
+				+ E13oi(self.e23i.0 * rhs.e12o.0)
+				- E13oi(self.e23o.0 * rhs.e12i.0)
+				- E13oi(self.e2oi.0 * rhs.e123.0)
+				- E13oi(self.e3oi.0 * rhs.e1.0),
+			e23oi: E23oi(self.e123.0 * rhs.e1oi.0) + E23oi(self.e12i.0 * rhs.e13o.0)
+				- E23oi(self.e12o.0 * rhs.e13i.0)
+				- E23oi(self.e13i.0 * rhs.e12o.0)
+				+ E23oi(self.e13o.0 * rhs.e12i.0)
+				+ E23oi(self.e1oi.0 * rhs.e123.0)
+				- E23oi(self.e23i.0 * rhs.eo.0)
+				+ E23oi(self.e23o.0 * rhs.ei.0)
+				+ E23oi(self.e2oi.0 * rhs.e3.0)
+				- E23oi(self.e3oi.0 * rhs.e2.0),
+			e123oi: E123oi(self.e123.0 * rhs.eoi.0) + E123oi(self.e12i.0 * rhs.e3o.0)
+				- E123oi(self.e12o.0 * rhs.e3i.0)
+				- E123oi(self.e13i.0 * rhs.e2o.0)
+				+ E123oi(self.e13o.0 * rhs.e2i.0)
+				+ E123oi(self.e1oi.0 * rhs.e23.0)
+				+ E123oi(self.e23i.0 * rhs.e1o.0)
+				- E123oi(self.e23o.0 * rhs.e1i.0)
+				- E123oi(self.e2oi.0 * rhs.e13.0)
+				+ E123oi(self.e3oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Circle {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Circle.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Circle {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e123.0 * rhs.eoi.0) + S(self.e12i.0 * rhs.e3o.0) - S(self.e12o.0 * rhs.e3i.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e13o.0 * rhs.e2i.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e23o.0 * rhs.e1i.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e1    : E1(self.e123.0 * rhs.e1oi.0) + E1(self.e12i.0 * rhs.e13o.0) - E1(self.e12o.0 * rhs.e13i.0) - E1(self.e13i.0 * rhs.e12o.0) + E1(self.e13o.0 * rhs.e12i.0) + E1(self.e1oi.0 * rhs.e123.0) + E1(self.e23i.0 * rhs.eo.0) - E1(self.e23o.0 * rhs.ei.0) - E1(self.e2oi.0 * rhs.e3.0) + E1(self.e3oi.0 * rhs.e2.0),
+		//     e2    : E2(self.e123.0 * rhs.e2oi.0) + E2(self.e12i.0 * rhs.e23o.0) - E2(self.e12o.0 * rhs.e23i.0) - E2(self.e13i.0 * rhs.eo.0) + E2(self.e13o.0 * rhs.ei.0) + E2(self.e1oi.0 * rhs.e3.0) - E2(self.e23i.0 * rhs.e12o.0) + E2(self.e23o.0 * rhs.e12i.0) + E2(self.e2oi.0 * rhs.e123.0) - E2(self.e3oi.0 * rhs.e1.0),
+		//     e3    : E3(self.e123.0 * rhs.e3oi.0) + E3(self.e12i.0 * rhs.eo.0) - E3(self.e12o.0 * rhs.ei.0) + E3(self.e13i.0 * rhs.e23o.0) - E3(self.e13o.0 * rhs.e23i.0) - E3(self.e1oi.0 * rhs.e2.0) - E3(self.e23i.0 * rhs.e13o.0) + E3(self.e23o.0 * rhs.e13i.0) + E3(self.e2oi.0 * rhs.e1.0) + E3(self.e3oi.0 * rhs.e123.0),
+		//     eo    : Eo(self.e123.0 * rhs.eo.0) - Eo(self.e12o.0 * rhs.e3.0) + Eo(self.e12o.0 * rhs.e3oi.0) + Eo(self.e13o.0 * rhs.e2.0) - Eo(self.e13o.0 * rhs.e2oi.0) + Eo(self.e1oi.0 * rhs.e23o.0) - Eo(self.e23o.0 * rhs.e1.0) + Eo(self.e23o.0 * rhs.e1oi.0) - Eo(self.e2oi.0 * rhs.e13o.0) + Eo(self.e3oi.0 * rhs.e12o.0),
+		//     ei    : -Ei(self.e123.0 * rhs.ei.0) + Ei(self.e12i.0 * rhs.e3.0) + Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		//     e12   : E12(self.e123.0 * rhs.e12oi.0) + E12(self.e12i.0 * rhs.e123o.0) - E12(self.e12o.0 * rhs.e123i.0) - E12(self.e13i.0 * rhs.e1o.0) + E12(self.e13o.0 * rhs.e1i.0) + E12(self.e1oi.0 * rhs.e13.0) - E12(self.e23i.0 * rhs.e2o.0) + E12(self.e23o.0 * rhs.e2i.0) + E12(self.e2oi.0 * rhs.e23.0) - E12(self.e3oi.0 * rhs.s.0),
+		//     e13   : E13(self.e123.0 * rhs.e13oi.0) + E13(self.e12i.0 * rhs.e1o.0) - E13(self.e12o.0 * rhs.e1i.0) + E13(self.e13i.0 * rhs.e123o.0) - E13(self.e13o.0 * rhs.e123i.0) - E13(self.e1oi.0 * rhs.e12.0) - E13(self.e23i.0 * rhs.e3o.0) + E13(self.e23o.0 * rhs.e3i.0) + E13(self.e2oi.0 * rhs.s.0) + E13(self.e3oi.0 * rhs.e23.0),
+		//     e1o   : E1o(self.e123.0 * rhs.e1o.0) - E1o(self.e12o.0 * rhs.e13.0) + E1o(self.e12o.0 * rhs.e13oi.0) + E1o(self.e13o.0 * rhs.e12.0) - E1o(self.e13o.0 * rhs.e12oi.0) + E1o(self.e1oi.0 * rhs.e123o.0) + E1o(self.e23o.0 * rhs.eoi.0) - E1o(self.e23o.0 * rhs.s.0) - E1o(self.e2oi.0 * rhs.e3o.0) + E1o(self.e3oi.0 * rhs.e2o.0),
+		//     e1i   : -E1i(self.e123.0 * rhs.e1i.0) + E1i(self.e12i.0 * rhs.e13.0) + E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0) + E1i(self.e23i.0 * rhs.eoi.0) + E1i(self.e23i.0 * rhs.s.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+		//     e23   : E23(self.e123.0 * rhs.e23oi.0) + E23(self.e12i.0 * rhs.e2o.0) - E23(self.e12o.0 * rhs.e2i.0) + E23(self.e13i.0 * rhs.e3o.0) - E23(self.e13o.0 * rhs.e3i.0) - E23(self.e1oi.0 * rhs.s.0) + E23(self.e23i.0 * rhs.e123o.0) - E23(self.e23o.0 * rhs.e123i.0) - E23(self.e2oi.0 * rhs.e12.0) - E23(self.e3oi.0 * rhs.e13.0),
+		//     e2o   : E2o(self.e123.0 * rhs.e2o.0) - E2o(self.e12o.0 * rhs.e23.0) + E2o(self.e12o.0 * rhs.e23oi.0) - E2o(self.e13o.0 * rhs.eoi.0) + E2o(self.e13o.0 * rhs.s.0) + E2o(self.e1oi.0 * rhs.e3o.0) + E2o(self.e23o.0 * rhs.e12.0) - E2o(self.e23o.0 * rhs.e12oi.0) + E2o(self.e2oi.0 * rhs.e123o.0) - E2o(self.e3oi.0 * rhs.e1o.0),
+		//     e2i   : -E2i(self.e123.0 * rhs.e2i.0) + E2i(self.e12i.0 * rhs.e23.0) + E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e13i.0 * rhs.eoi.0) - E2i(self.e13i.0 * rhs.s.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e23i.0 * rhs.e12.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+		//     e3o   : E3o(self.e123.0 * rhs.e3o.0) + E3o(self.e12o.0 * rhs.eoi.0) - E3o(self.e12o.0 * rhs.s.0) - E3o(self.e13o.0 * rhs.e23.0) + E3o(self.e13o.0 * rhs.e23oi.0) - E3o(self.e1oi.0 * rhs.e2o.0) + E3o(self.e23o.0 * rhs.e13.0) - E3o(self.e23o.0 * rhs.e13oi.0) + E3o(self.e2oi.0 * rhs.e1o.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i   : -E3i(self.e123.0 * rhs.e3i.0) + E3i(self.e12i.0 * rhs.eoi.0) + E3i(self.e12i.0 * rhs.s.0) + E3i(self.e13i.0 * rhs.e23.0) + E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e1oi.0 * rhs.e2i.0) - E3i(self.e23i.0 * rhs.e13.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e2oi.0 * rhs.e1i.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi   : Eoi(self.e123.0 * rhs.s.0) - Eoi(self.e12i.0 * rhs.e3o.0) - Eoi(self.e12o.0 * rhs.e3i.0) + Eoi(self.e13i.0 * rhs.e2o.0) + Eoi(self.e13o.0 * rhs.e2i.0) + Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e23i.0 * rhs.e1o.0) - Eoi(self.e23o.0 * rhs.e1i.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123  : E123(self.e123.0 * rhs.e123oi.0) + E123(self.e12i.0 * rhs.e12o.0) - E123(self.e12o.0 * rhs.e12i.0) + E123(self.e13i.0 * rhs.e13o.0) - E123(self.e13o.0 * rhs.e13i.0) - E123(self.e1oi.0 * rhs.e1.0) + E123(self.e23i.0 * rhs.e23o.0) - E123(self.e23o.0 * rhs.e23i.0) - E123(self.e2oi.0 * rhs.e2.0) - E123(self.e3oi.0 * rhs.e3.0),
+		//     e12o  : E12o(self.e123.0 * rhs.e12o.0) - E12o(self.e12o.0 * rhs.e123.0) + E12o(self.e12o.0 * rhs.e123oi.0) + E12o(self.e13o.0 * rhs.e1.0) - E12o(self.e13o.0 * rhs.e1oi.0) + E12o(self.e1oi.0 * rhs.e13o.0) + E12o(self.e23o.0 * rhs.e2.0) - E12o(self.e23o.0 * rhs.e2oi.0) + E12o(self.e2oi.0 * rhs.e23o.0) - E12o(self.e3oi.0 * rhs.eo.0),
+		//     e12i  : -E12i(self.e123.0 * rhs.e12i.0) + E12i(self.e12i.0 * rhs.e123.0) + E12i(self.e12i.0 * rhs.e123oi.0) - E12i(self.e13i.0 * rhs.e1.0) - E12i(self.e13i.0 * rhs.e1oi.0) + E12i(self.e1oi.0 * rhs.e13i.0) - E12i(self.e23i.0 * rhs.e2.0) - E12i(self.e23i.0 * rhs.e2oi.0) + E12i(self.e2oi.0 * rhs.e23i.0) - E12i(self.e3oi.0 * rhs.ei.0),
+		//     e13o  : E13o(self.e123.0 * rhs.e13o.0) - E13o(self.e12o.0 * rhs.e1.0) + E13o(self.e12o.0 * rhs.e1oi.0) - E13o(self.e13o.0 * rhs.e123.0) + E13o(self.e13o.0 * rhs.e123oi.0) - E13o(self.e1oi.0 * rhs.e12o.0) + E13o(self.e23o.0 * rhs.e3.0) - E13o(self.e23o.0 * rhs.e3oi.0) + E13o(self.e2oi.0 * rhs.eo.0) + E13o(self.e3oi.0 * rhs.e23o.0),
+		//     e13i  : -E13i(self.e123.0 * rhs.e13i.0) + E13i(self.e12i.0 * rhs.e1.0) + E13i(self.e12i.0 * rhs.e1oi.0) + E13i(self.e13i.0 * rhs.e123.0) + E13i(self.e13i.0 * rhs.e123oi.0) - E13i(self.e1oi.0 * rhs.e12i.0) - E13i(self.e23i.0 * rhs.e3.0) - E13i(self.e23i.0 * rhs.e3oi.0) + E13i(self.e2oi.0 * rhs.ei.0) + E13i(self.e3oi.0 * rhs.e23i.0),
+		//     e1oi  : E1oi(self.e123.0 * rhs.e1.0) - E1oi(self.e12i.0 * rhs.e13o.0) - E1oi(self.e12o.0 * rhs.e13i.0) + E1oi(self.e13i.0 * rhs.e12o.0) + E1oi(self.e13o.0 * rhs.e12i.0) + E1oi(self.e1oi.0 * rhs.e123oi.0) - E1oi(self.e23i.0 * rhs.eo.0) - E1oi(self.e23o.0 * rhs.ei.0) - E1oi(self.e2oi.0 * rhs.e3oi.0) + E1oi(self.e3oi.0 * rhs.e2oi.0),
+		//     e23o  : E23o(self.e123.0 * rhs.e23o.0) - E23o(self.e12o.0 * rhs.e2.0) + E23o(self.e12o.0 * rhs.e2oi.0) - E23o(self.e13o.0 * rhs.e3.0) + E23o(self.e13o.0 * rhs.e3oi.0) - E23o(self.e1oi.0 * rhs.eo.0) - E23o(self.e23o.0 * rhs.e123.0) + E23o(self.e23o.0 * rhs.e123oi.0) - E23o(self.e2oi.0 * rhs.e12o.0) - E23o(self.e3oi.0 * rhs.e13o.0),
+		//     e23i  : -E23i(self.e123.0 * rhs.e23i.0) + E23i(self.e12i.0 * rhs.e2.0) + E23i(self.e12i.0 * rhs.e2oi.0) + E23i(self.e13i.0 * rhs.e3.0) + E23i(self.e13i.0 * rhs.e3oi.0) - E23i(self.e1oi.0 * rhs.ei.0) + E23i(self.e23i.0 * rhs.e123.0) + E23i(self.e23i.0 * rhs.e123oi.0) - E23i(self.e2oi.0 * rhs.e12i.0) - E23i(self.e3oi.0 * rhs.e13i.0),
+		//     e2oi  : E2oi(self.e123.0 * rhs.e2.0) - E2oi(self.e12i.0 * rhs.e23o.0) - E2oi(self.e12o.0 * rhs.e23i.0) + E2oi(self.e13i.0 * rhs.eo.0) + E2oi(self.e13o.0 * rhs.ei.0) + E2oi(self.e1oi.0 * rhs.e3oi.0) + E2oi(self.e23i.0 * rhs.e12o.0) + E2oi(self.e23o.0 * rhs.e12i.0) + E2oi(self.e2oi.0 * rhs.e123oi.0) - E2oi(self.e3oi.0 * rhs.e1oi.0),
+		//     e3oi  : E3oi(self.e123.0 * rhs.e3.0) - E3oi(self.e12i.0 * rhs.eo.0) - E3oi(self.e12o.0 * rhs.ei.0) - E3oi(self.e13i.0 * rhs.e23o.0) - E3oi(self.e13o.0 * rhs.e23i.0) - E3oi(self.e1oi.0 * rhs.e2oi.0) + E3oi(self.e23i.0 * rhs.e13o.0) + E3oi(self.e23o.0 * rhs.e13i.0) + E3oi(self.e2oi.0 * rhs.e1oi.0) + E3oi(self.e3oi.0 * rhs.e123oi.0),
+		//     e123o : E123o(self.e123.0 * rhs.e123o.0) - E123o(self.e12o.0 * rhs.e12.0) + E123o(self.e12o.0 * rhs.e12oi.0) - E123o(self.e13o.0 * rhs.e13.0) + E123o(self.e13o.0 * rhs.e13oi.0) - E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e23o.0 * rhs.e23.0) + E123o(self.e23o.0 * rhs.e23oi.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i : -E123i(self.e123.0 * rhs.e123i.0) + E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e13i.0 * rhs.e13oi.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) + E123i(self.e23i.0 * rhs.e23oi.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi : E12oi(self.e123.0 * rhs.e12.0) - E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0) + E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e23i.0 * rhs.e2o.0) + E12oi(self.e23o.0 * rhs.e2i.0) + E12oi(self.e2oi.0 * rhs.e23oi.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi : E13oi(self.e123.0 * rhs.e13.0) - E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e12o.0 * rhs.e1i.0) - E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e13o.0 * rhs.e123i.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e23o.0 * rhs.e3i.0) + E13oi(self.e2oi.0 * rhs.eoi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi : E23oi(self.e123.0 * rhs.e23.0) - E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e13o.0 * rhs.e3i.0) - E23oi(self.e1oi.0 * rhs.eoi.0) - E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e23o.0 * rhs.e123i.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		//     e123oi: E123oi(self.e123.0 * rhs.e123.0) - E123oi(self.e12i.0 * rhs.e12o.0) - E123oi(self.e12o.0 * rhs.e12i.0) - E123oi(self.e13i.0 * rhs.e13o.0) - E123oi(self.e13o.0 * rhs.e13i.0) - E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e23i.0 * rhs.e23o.0) - E123oi(self.e23o.0 * rhs.e23i.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0),
+		// }
+		Multivector {
+			s: S(self.e123.0 * rhs.eoi.0) + S(self.e12i.0 * rhs.e3o.0)
+				- S(self.e12o.0 * rhs.e3i.0)
+				- S(self.e13i.0 * rhs.e2o.0)
+				+ S(self.e13o.0 * rhs.e2i.0)
+				+ S(self.e1oi.0 * rhs.e23.0)
+				+ S(self.e23i.0 * rhs.e1o.0)
+				- S(self.e23o.0 * rhs.e1i.0)
+				- S(self.e2oi.0 * rhs.e13.0)
+				+ S(self.e3oi.0 * rhs.e12.0),
+			e1: E1(self.e123.0 * rhs.e1oi.0) + E1(self.e12i.0 * rhs.e13o.0)
+				- E1(self.e12o.0 * rhs.e13i.0)
+				- E1(self.e13i.0 * rhs.e12o.0)
+				+ E1(self.e13o.0 * rhs.e12i.0)
+				+ E1(self.e1oi.0 * rhs.e123.0)
+				+ E1(self.e23i.0 * rhs.eo.0)
+				- E1(self.e23o.0 * rhs.ei.0)
+				- E1(self.e2oi.0 * rhs.e3.0)
+				+ E1(self.e3oi.0 * rhs.e2.0),
+			e2: E2(self.e123.0 * rhs.e2oi.0) + E2(self.e12i.0 * rhs.e23o.0)
+				- E2(self.e12o.0 * rhs.e23i.0)
+				- E2(self.e13i.0 * rhs.eo.0)
+				+ E2(self.e13o.0 * rhs.ei.0)
+				+ E2(self.e1oi.0 * rhs.e3.0)
+				- E2(self.e23i.0 * rhs.e12o.0)
+				+ E2(self.e23o.0 * rhs.e12i.0)
+				+ E2(self.e2oi.0 * rhs.e123.0)
+				- E2(self.e3oi.0 * rhs.e1.0),
+			e3: E3(self.e123.0 * rhs.e3oi.0) + E3(self.e12i.0 * rhs.eo.0) - E3(self.e12o.0 * rhs.ei.0)
+				+ E3(self.e13i.0 * rhs.e23o.0)
+				- E3(self.e13o.0 * rhs.e23i.0)
+				- E3(self.e1oi.0 * rhs.e2.0)
+				- E3(self.e23i.0 * rhs.e13o.0)
+				+ E3(self.e23o.0 * rhs.e13i.0)
+				+ E3(self.e2oi.0 * rhs.e1.0)
+				+ E3(self.e3oi.0 * rhs.e123.0),
+			eo: Eo(self.e123.0 * rhs.eo.0) - Eo(self.e12o.0 * rhs.e3.0)
+				+ Eo(self.e12o.0 * rhs.e3oi.0)
+				+ Eo(self.e13o.0 * rhs.e2.0)
+				- Eo(self.e13o.0 * rhs.e2oi.0)
+				+ Eo(self.e1oi.0 * rhs.e23o.0)
+				- Eo(self.e23o.0 * rhs.e1.0)
+				+ Eo(self.e23o.0 * rhs.e1oi.0)
+				- Eo(self.e2oi.0 * rhs.e13o.0)
+				+ Eo(self.e3oi.0 * rhs.e12o.0),
+			ei: -Ei(self.e123.0 * rhs.ei.0) + Ei(self.e12i.0 * rhs.e3.0) + Ei(self.e12i.0 * rhs.e3oi.0)
+				- Ei(self.e13i.0 * rhs.e2.0)
+				- Ei(self.e13i.0 * rhs.e2oi.0)
+				+ Ei(self.e1oi.0 * rhs.e23i.0)
+				+ Ei(self.e23i.0 * rhs.e1.0)
+				+ Ei(self.e23i.0 * rhs.e1oi.0)
+				- Ei(self.e2oi.0 * rhs.e13i.0)
+				+ Ei(self.e3oi.0 * rhs.e12i.0),
+			e12: E12(self.e123.0 * rhs.e12oi.0) + E12(self.e12i.0 * rhs.e123o.0)
+				- E12(self.e12o.0 * rhs.e123i.0)
+				- E12(self.e13i.0 * rhs.e1o.0)
+				+ E12(self.e13o.0 * rhs.e1i.0)
+				+ E12(self.e1oi.0 * rhs.e13.0)
+				- E12(self.e23i.0 * rhs.e2o.0)
+				+ E12(self.e23o.0 * rhs.e2i.0)
+				+ E12(self.e2oi.0 * rhs.e23.0)
+				- E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e123.0 * rhs.e13oi.0) + E13(self.e12i.0 * rhs.e1o.0) - E13(self.e12o.0 * rhs.e1i.0)
+				+ E13(self.e13i.0 * rhs.e123o.0)
+				- E13(self.e13o.0 * rhs.e123i.0)
+				- E13(self.e1oi.0 * rhs.e12.0)
+				- E13(self.e23i.0 * rhs.e3o.0)
+				+ E13(self.e23o.0 * rhs.e3i.0)
+				+ E13(self.e2oi.0 * rhs.s.0)
+				+ E13(self.e3oi.0 * rhs.e23.0),
+			e1o: E1o(self.e123.0 * rhs.e1o.0) - E1o(self.e12o.0 * rhs.e13.0)
+				+ E1o(self.e12o.0 * rhs.e13oi.0)
+				+ E1o(self.e13o.0 * rhs.e12.0)
+				- E1o(self.e13o.0 * rhs.e12oi.0)
+				+ E1o(self.e1oi.0 * rhs.e123o.0)
+				+ E1o(self.e23o.0 * rhs.eoi.0)
+				- E1o(self.e23o.0 * rhs.s.0)
+				- E1o(self.e2oi.0 * rhs.e3o.0)
+				+ E1o(self.e3oi.0 * rhs.e2o.0),
+			e1i: -E1i(self.e123.0 * rhs.e1i.0) + E1i(self.e12i.0 * rhs.e13.0) + E1i(self.e12i.0 * rhs.e13oi.0)
+				- E1i(self.e13i.0 * rhs.e12.0)
+				- E1i(self.e13i.0 * rhs.e12oi.0)
+				+ E1i(self.e1oi.0 * rhs.e123i.0)
+				+ E1i(self.e23i.0 * rhs.eoi.0)
+				+ E1i(self.e23i.0 * rhs.s.0)
+				- E1i(self.e2oi.0 * rhs.e3i.0)
+				+ E1i(self.e3oi.0 * rhs.e2i.0),
+			e23: E23(self.e123.0 * rhs.e23oi.0) + E23(self.e12i.0 * rhs.e2o.0) - E23(self.e12o.0 * rhs.e2i.0)
+				+ E23(self.e13i.0 * rhs.e3o.0)
+				- E23(self.e13o.0 * rhs.e3i.0)
+				- E23(self.e1oi.0 * rhs.s.0)
+				+ E23(self.e23i.0 * rhs.e123o.0)
+				- E23(self.e23o.0 * rhs.e123i.0)
+				- E23(self.e2oi.0 * rhs.e12.0)
+				- E23(self.e3oi.0 * rhs.e13.0),
+			e2o: E2o(self.e123.0 * rhs.e2o.0) - E2o(self.e12o.0 * rhs.e23.0) + E2o(self.e12o.0 * rhs.e23oi.0)
+				- E2o(self.e13o.0 * rhs.eoi.0)
+				+ E2o(self.e13o.0 * rhs.s.0)
+				+ E2o(self.e1oi.0 * rhs.e3o.0)
+				+ E2o(self.e23o.0 * rhs.e12.0)
+				- E2o(self.e23o.0 * rhs.e12oi.0)
+				+ E2o(self.e2oi.0 * rhs.e123o.0)
+				- E2o(self.e3oi.0 * rhs.e1o.0),
+			e2i: -E2i(self.e123.0 * rhs.e2i.0) + E2i(self.e12i.0 * rhs.e23.0) + E2i(self.e12i.0 * rhs.e23oi.0)
+				- E2i(self.e13i.0 * rhs.eoi.0)
+				- E2i(self.e13i.0 * rhs.s.0)
+				+ E2i(self.e1oi.0 * rhs.e3i.0)
+				- E2i(self.e23i.0 * rhs.e12.0)
+				- E2i(self.e23i.0 * rhs.e12oi.0)
+				+ E2i(self.e2oi.0 * rhs.e123i.0)
+				- E2i(self.e3oi.0 * rhs.e1i.0),
+			e3o: E3o(self.e123.0 * rhs.e3o.0) + E3o(self.e12o.0 * rhs.eoi.0)
+				- E3o(self.e12o.0 * rhs.s.0)
+				- E3o(self.e13o.0 * rhs.e23.0)
+				+ E3o(self.e13o.0 * rhs.e23oi.0)
+				- E3o(self.e1oi.0 * rhs.e2o.0)
+				+ E3o(self.e23o.0 * rhs.e13.0)
+				- E3o(self.e23o.0 * rhs.e13oi.0)
+				+ E3o(self.e2oi.0 * rhs.e1o.0)
+				+ E3o(self.e3oi.0 * rhs.e123o.0),
+			e3i: -E3i(self.e123.0 * rhs.e3i.0)
+				+ E3i(self.e12i.0 * rhs.eoi.0)
+				+ E3i(self.e12i.0 * rhs.s.0)
+				+ E3i(self.e13i.0 * rhs.e23.0)
+				+ E3i(self.e13i.0 * rhs.e23oi.0)
+				- E3i(self.e1oi.0 * rhs.e2i.0)
+				- E3i(self.e23i.0 * rhs.e13.0)
+				- E3i(self.e23i.0 * rhs.e13oi.0)
+				+ E3i(self.e2oi.0 * rhs.e1i.0)
+				+ E3i(self.e3oi.0 * rhs.e123i.0),
+			eoi: Eoi(self.e123.0 * rhs.s.0) - Eoi(self.e12i.0 * rhs.e3o.0) - Eoi(self.e12o.0 * rhs.e3i.0)
+				+ Eoi(self.e13i.0 * rhs.e2o.0)
+				+ Eoi(self.e13o.0 * rhs.e2i.0)
+				+ Eoi(self.e1oi.0 * rhs.e23oi.0)
+				- Eoi(self.e23i.0 * rhs.e1o.0)
+				- Eoi(self.e23o.0 * rhs.e1i.0)
+				- Eoi(self.e2oi.0 * rhs.e13oi.0)
+				+ Eoi(self.e3oi.0 * rhs.e12oi.0),
+			e123: E123(self.e123.0 * rhs.e123oi.0) + E123(self.e12i.0 * rhs.e12o.0) - E123(self.e12o.0 * rhs.e12i.0)
+				+ E123(self.e13i.0 * rhs.e13o.0)
+				- E123(self.e13o.0 * rhs.e13i.0)
+				- E123(self.e1oi.0 * rhs.e1.0)
+				+ E123(self.e23i.0 * rhs.e23o.0)
+				- E123(self.e23o.0 * rhs.e23i.0)
+				- E123(self.e2oi.0 * rhs.e2.0)
+				- E123(self.e3oi.0 * rhs.e3.0),
+			e12o: E12o(self.e123.0 * rhs.e12o.0) - E12o(self.e12o.0 * rhs.e123.0)
+				+ E12o(self.e12o.0 * rhs.e123oi.0)
+				+ E12o(self.e13o.0 * rhs.e1.0)
+				- E12o(self.e13o.0 * rhs.e1oi.0)
+				+ E12o(self.e1oi.0 * rhs.e13o.0)
+				+ E12o(self.e23o.0 * rhs.e2.0)
+				- E12o(self.e23o.0 * rhs.e2oi.0)
+				+ E12o(self.e2oi.0 * rhs.e23o.0)
+				- E12o(self.e3oi.0 * rhs.eo.0),
+			e12i: -E12i(self.e123.0 * rhs.e12i.0) + E12i(self.e12i.0 * rhs.e123.0) + E12i(self.e12i.0 * rhs.e123oi.0)
+				- E12i(self.e13i.0 * rhs.e1.0)
+				- E12i(self.e13i.0 * rhs.e1oi.0)
+				+ E12i(self.e1oi.0 * rhs.e13i.0)
+				- E12i(self.e23i.0 * rhs.e2.0)
+				- E12i(self.e23i.0 * rhs.e2oi.0)
+				+ E12i(self.e2oi.0 * rhs.e23i.0)
+				- E12i(self.e3oi.0 * rhs.ei.0),
+			e13o: E13o(self.e123.0 * rhs.e13o.0) - E13o(self.e12o.0 * rhs.e1.0) + E13o(self.e12o.0 * rhs.e1oi.0)
+				- E13o(self.e13o.0 * rhs.e123.0)
+				+ E13o(self.e13o.0 * rhs.e123oi.0)
+				- E13o(self.e1oi.0 * rhs.e12o.0)
+				+ E13o(self.e23o.0 * rhs.e3.0)
+				- E13o(self.e23o.0 * rhs.e3oi.0)
+				+ E13o(self.e2oi.0 * rhs.eo.0)
+				+ E13o(self.e3oi.0 * rhs.e23o.0),
+			e13i: -E13i(self.e123.0 * rhs.e13i.0)
+				+ E13i(self.e12i.0 * rhs.e1.0)
+				+ E13i(self.e12i.0 * rhs.e1oi.0)
+				+ E13i(self.e13i.0 * rhs.e123.0)
+				+ E13i(self.e13i.0 * rhs.e123oi.0)
+				- E13i(self.e1oi.0 * rhs.e12i.0)
+				- E13i(self.e23i.0 * rhs.e3.0)
+				- E13i(self.e23i.0 * rhs.e3oi.0)
+				+ E13i(self.e2oi.0 * rhs.ei.0)
+				+ E13i(self.e3oi.0 * rhs.e23i.0),
+			e1oi: E1oi(self.e123.0 * rhs.e1.0) - E1oi(self.e12i.0 * rhs.e13o.0) - E1oi(self.e12o.0 * rhs.e13i.0)
+				+ E1oi(self.e13i.0 * rhs.e12o.0)
+				+ E1oi(self.e13o.0 * rhs.e12i.0)
+				+ E1oi(self.e1oi.0 * rhs.e123oi.0)
+				- E1oi(self.e23i.0 * rhs.eo.0)
+				- E1oi(self.e23o.0 * rhs.ei.0)
+				- E1oi(self.e2oi.0 * rhs.e3oi.0)
+				+ E1oi(self.e3oi.0 * rhs.e2oi.0),
+			e23o: E23o(self.e123.0 * rhs.e23o.0) - E23o(self.e12o.0 * rhs.e2.0) + E23o(self.e12o.0 * rhs.e2oi.0)
+				- E23o(self.e13o.0 * rhs.e3.0)
+				+ E23o(self.e13o.0 * rhs.e3oi.0)
+				- E23o(self.e1oi.0 * rhs.eo.0)
+				- E23o(self.e23o.0 * rhs.e123.0)
+				+ E23o(self.e23o.0 * rhs.e123oi.0)
+				- E23o(self.e2oi.0 * rhs.e12o.0)
+				- E23o(self.e3oi.0 * rhs.e13o.0),
+			e23i: -E23i(self.e123.0 * rhs.e23i.0)
+				+ E23i(self.e12i.0 * rhs.e2.0)
+				+ E23i(self.e12i.0 * rhs.e2oi.0)
+				+ E23i(self.e13i.0 * rhs.e3.0)
+				+ E23i(self.e13i.0 * rhs.e3oi.0)
+				- E23i(self.e1oi.0 * rhs.ei.0)
+				+ E23i(self.e23i.0 * rhs.e123.0)
+				+ E23i(self.e23i.0 * rhs.e123oi.0)
+				- E23i(self.e2oi.0 * rhs.e12i.0)
+				- E23i(self.e3oi.0 * rhs.e13i.0),
+			e2oi: E2oi(self.e123.0 * rhs.e2.0) - E2oi(self.e12i.0 * rhs.e23o.0) - E2oi(self.e12o.0 * rhs.e23i.0)
+				+ E2oi(self.e13i.0 * rhs.eo.0)
+				+ E2oi(self.e13o.0 * rhs.ei.0)
+				+ E2oi(self.e1oi.0 * rhs.e3oi.0)
+				+ E2oi(self.e23i.0 * rhs.e12o.0)
+				+ E2oi(self.e23o.0 * rhs.e12i.0)
+				+ E2oi(self.e2oi.0 * rhs.e123oi.0)
+				- E2oi(self.e3oi.0 * rhs.e1oi.0),
+			e3oi: E3oi(self.e123.0 * rhs.e3.0)
+				- E3oi(self.e12i.0 * rhs.eo.0)
+				- E3oi(self.e12o.0 * rhs.ei.0)
+				- E3oi(self.e13i.0 * rhs.e23o.0)
+				- E3oi(self.e13o.0 * rhs.e23i.0)
+				- E3oi(self.e1oi.0 * rhs.e2oi.0)
+				+ E3oi(self.e23i.0 * rhs.e13o.0)
+				+ E3oi(self.e23o.0 * rhs.e13i.0)
+				+ E3oi(self.e2oi.0 * rhs.e1oi.0)
+				+ E3oi(self.e3oi.0 * rhs.e123oi.0),
+			e123o: E123o(self.e123.0 * rhs.e123o.0) - E123o(self.e12o.0 * rhs.e12.0) + E123o(self.e12o.0 * rhs.e12oi.0)
+				- E123o(self.e13o.0 * rhs.e13.0)
+				+ E123o(self.e13o.0 * rhs.e13oi.0)
+				- E123o(self.e1oi.0 * rhs.e1o.0)
+				- E123o(self.e23o.0 * rhs.e23.0)
+				+ E123o(self.e23o.0 * rhs.e23oi.0)
+				- E123o(self.e2oi.0 * rhs.e2o.0)
+				- E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: -E123i(self.e123.0 * rhs.e123i.0)
+				+ E123i(self.e12i.0 * rhs.e12.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				- E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e123.0 * rhs.e12.0) - E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0)
+				+ E12oi(self.e13i.0 * rhs.e1o.0)
+				+ E12oi(self.e13o.0 * rhs.e1i.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e23i.0 * rhs.e2o.0)
+				+ E12oi(self.e23o.0 * rhs.e2i.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0)
+				- E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: E13oi(self.e123.0 * rhs.e13.0)
+				- E13oi(self.e12i.0 * rhs.e1o.0)
+				- E13oi(self.e12o.0 * rhs.e1i.0)
+				- E13oi(self.e13i.0 * rhs.e123o.0)
+				- E13oi(self.e13o.0 * rhs.e123i.0)
+				- E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e23i.0 * rhs.e3o.0)
+				+ E13oi(self.e23o.0 * rhs.e3i.0)
+				+ E13oi(self.e2oi.0 * rhs.eoi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: E23oi(self.e123.0 * rhs.e23.0)
+				- E23oi(self.e12i.0 * rhs.e2o.0)
+				- E23oi(self.e12o.0 * rhs.e2i.0)
+				- E23oi(self.e13i.0 * rhs.e3o.0)
+				- E23oi(self.e13o.0 * rhs.e3i.0)
+				- E23oi(self.e1oi.0 * rhs.eoi.0)
+				- E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e23o.0 * rhs.e123i.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+			e123oi: E123oi(self.e123.0 * rhs.e123.0)
+				- E123oi(self.e12i.0 * rhs.e12o.0)
+				- E123oi(self.e12o.0 * rhs.e12i.0)
+				- E123oi(self.e13i.0 * rhs.e13o.0)
+				- E123oi(self.e13o.0 * rhs.e13i.0)
+				- E123oi(self.e1oi.0 * rhs.e1oi.0)
+				- E123oi(self.e23i.0 * rhs.e23o.0)
+				- E123oi(self.e23o.0 * rhs.e23i.0)
+				- E123oi(self.e2oi.0 * rhs.e2oi.0)
+				- E123oi(self.e3oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Circle.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Circle {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123.0 * rhs.e123.0) + S(self.e12i.0 * rhs.e12o.0) + S(self.e12o.0 * rhs.e12i.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e1    : -E1(self.e123.0 * rhs.e23.0) + E1(self.e12i.0 * rhs.e2o.0) + E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0) - E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0) + E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e123.0 * rhs.e12.0) - E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) - E3(self.e13i.0 * rhs.e1o.0) - E3(self.e13o.0 * rhs.e1i.0) + E3(self.e1oi.0 * rhs.e13oi.0) - E3(self.e23i.0 * rhs.e2o.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e2oi.0 * rhs.e23oi.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e23o.0 * rhs.e23.0) - Eo(self.e23o.0 * rhs.e23oi.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e123.0 * rhs.e123i.0) - Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e23i.0 * rhs.e23oi.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : E12(self.e123.0 * rhs.e3.0) - E12(self.e12i.0 * rhs.eo.0) - E12(self.e12o.0 * rhs.ei.0) + E12(self.e3oi.0 * rhs.e123oi.0),
+		//     e13   : -E13(self.e123.0 * rhs.e2.0) - E13(self.e13i.0 * rhs.eo.0) - E13(self.e13o.0 * rhs.ei.0) - E13(self.e2oi.0 * rhs.e123oi.0),
+		//     e1o   : -E1o(self.e12o.0 * rhs.e2.0) - E1o(self.e13o.0 * rhs.e3.0) - E1o(self.e1oi.0 * rhs.eo.0) + E1o(self.e23o.0 * rhs.e123oi.0),
+		//     e1i   : -E1i(self.e12i.0 * rhs.e2.0) - E1i(self.e13i.0 * rhs.e3.0) + E1i(self.e1oi.0 * rhs.ei.0) - E1i(self.e23i.0 * rhs.e123oi.0),
+		//     e23   : E23(self.e123.0 * rhs.e1.0) + E23(self.e1oi.0 * rhs.e123oi.0) - E23(self.e23i.0 * rhs.eo.0) - E23(self.e23o.0 * rhs.ei.0),
+		//     e2o   : E2o(self.e12o.0 * rhs.e1.0) - E2o(self.e13o.0 * rhs.e123oi.0) - E2o(self.e23o.0 * rhs.e3.0) - E2o(self.e2oi.0 * rhs.eo.0),
+		//     e2i   : E2i(self.e12i.0 * rhs.e1.0) + E2i(self.e13i.0 * rhs.e123oi.0) - E2i(self.e23i.0 * rhs.e3.0) + E2i(self.e2oi.0 * rhs.ei.0),
+		//     e3o   : E3o(self.e12o.0 * rhs.e123oi.0) + E3o(self.e13o.0 * rhs.e1.0) + E3o(self.e23o.0 * rhs.e2.0) - E3o(self.e3oi.0 * rhs.eo.0),
+		//     e3i   : -E3i(self.e12i.0 * rhs.e123oi.0) + E3i(self.e13i.0 * rhs.e1.0) + E3i(self.e23i.0 * rhs.e2.0) + E3i(self.e3oi.0 * rhs.ei.0),
+		//     eoi   : -Eoi(self.e123.0 * rhs.e123oi.0) + Eoi(self.e1oi.0 * rhs.e1.0) + Eoi(self.e2oi.0 * rhs.e2.0) + Eoi(self.e3oi.0 * rhs.e3.0),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e123.0 * rhs.e123.0)
+				+ S(self.e12i.0 * rhs.e12o.0)
+				+ S(self.e12o.0 * rhs.e12i.0)
+				+ S(self.e13i.0 * rhs.e13o.0)
+				+ S(self.e13o.0 * rhs.e13i.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23i.0 * rhs.e23o.0)
+				+ S(self.e23o.0 * rhs.e23i.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e1: -E1(self.e123.0 * rhs.e23.0)
+				+ E1(self.e12i.0 * rhs.e2o.0)
+				+ E1(self.e12o.0 * rhs.e2i.0)
+				+ E1(self.e13i.0 * rhs.e3o.0)
+				+ E1(self.e13o.0 * rhs.e3i.0)
+				+ E1(self.e1oi.0 * rhs.eoi.0)
+				- E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0)
+				+ E2(self.e13i.0 * rhs.e123o.0)
+				+ E2(self.e13o.0 * rhs.e123i.0)
+				+ E2(self.e1oi.0 * rhs.e12oi.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e23o.0 * rhs.e3i.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e123.0 * rhs.e12.0)
+				- E3(self.e12i.0 * rhs.e123o.0)
+				- E3(self.e12o.0 * rhs.e123i.0)
+				- E3(self.e13i.0 * rhs.e1o.0)
+				- E3(self.e13o.0 * rhs.e1i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				- E3(self.e23o.0 * rhs.e2i.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e1oi.0 * rhs.e1o.0)
+				- Eo(self.e23o.0 * rhs.e23.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0)
+				- Eo(self.e2oi.0 * rhs.e2o.0)
+				- Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e123.0 * rhs.e123i.0) - Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0)
+				- Ei(self.e13i.0 * rhs.e13.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: E12(self.e123.0 * rhs.e3.0) - E12(self.e12i.0 * rhs.eo.0) - E12(self.e12o.0 * rhs.ei.0)
+				+ E12(self.e3oi.0 * rhs.e123oi.0),
+			e13: -E13(self.e123.0 * rhs.e2.0)
+				- E13(self.e13i.0 * rhs.eo.0)
+				- E13(self.e13o.0 * rhs.ei.0)
+				- E13(self.e2oi.0 * rhs.e123oi.0),
+			e1o: -E1o(self.e12o.0 * rhs.e2.0) - E1o(self.e13o.0 * rhs.e3.0) - E1o(self.e1oi.0 * rhs.eo.0)
+				+ E1o(self.e23o.0 * rhs.e123oi.0),
+			e1i: -E1i(self.e12i.0 * rhs.e2.0) - E1i(self.e13i.0 * rhs.e3.0) + E1i(self.e1oi.0 * rhs.ei.0)
+				- E1i(self.e23i.0 * rhs.e123oi.0),
+			e23: E23(self.e123.0 * rhs.e1.0) + E23(self.e1oi.0 * rhs.e123oi.0)
+				- E23(self.e23i.0 * rhs.eo.0)
+				- E23(self.e23o.0 * rhs.ei.0),
+			e2o: E2o(self.e12o.0 * rhs.e1.0)
+				- E2o(self.e13o.0 * rhs.e123oi.0)
+				- E2o(self.e23o.0 * rhs.e3.0)
+				- E2o(self.e2oi.0 * rhs.eo.0),
+			e2i: E2i(self.e12i.0 * rhs.e1.0) + E2i(self.e13i.0 * rhs.e123oi.0) - E2i(self.e23i.0 * rhs.e3.0)
+				+ E2i(self.e2oi.0 * rhs.ei.0),
+			e3o: E3o(self.e12o.0 * rhs.e123oi.0) + E3o(self.e13o.0 * rhs.e1.0) + E3o(self.e23o.0 * rhs.e2.0)
+				- E3o(self.e3oi.0 * rhs.eo.0),
+			e3i: -E3i(self.e12i.0 * rhs.e123oi.0)
+				+ E3i(self.e13i.0 * rhs.e1.0)
+				+ E3i(self.e23i.0 * rhs.e2.0)
+				+ E3i(self.e3oi.0 * rhs.ei.0),
+			eoi: -Eoi(self.e123.0 * rhs.e123oi.0)
+				+ Eoi(self.e1oi.0 * rhs.e1.0)
+				+ Eoi(self.e2oi.0 * rhs.e2.0)
+				+ Eoi(self.e3oi.0 * rhs.e3.0),
+			e123: E123(self.e123.0 * rhs.s.0),
+			e12o: E12o(self.e12o.0 * rhs.s.0),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: E13o(self.e13o.0 * rhs.s.0),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: E23o(self.e23o.0 * rhs.s.0),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Circle {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Circle.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Circle {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : E123o(self.e123.0 * rhs.eo.0) - E123o(self.e12o.0 * rhs.e3.0) + E123o(self.e13o.0 * rhs.e2.0) - E123o(self.e23o.0 * rhs.e1.0),
+		//     e123i : E123i(self.e123.0 * rhs.ei.0) - E123i(self.e12i.0 * rhs.e3.0) + E123i(self.e13i.0 * rhs.e2.0) - E123i(self.e23i.0 * rhs.e1.0),
+		//     e12oi : -E12oi(self.e12i.0 * rhs.eo.0) + E12oi(self.e12o.0 * rhs.ei.0) + E12oi(self.e1oi.0 * rhs.e2.0) - E12oi(self.e2oi.0 * rhs.e1.0),
+		//     e13oi : -E13oi(self.e13i.0 * rhs.eo.0) + E13oi(self.e13o.0 * rhs.ei.0) + E13oi(self.e1oi.0 * rhs.e3.0) - E13oi(self.e3oi.0 * rhs.e1.0),
+		//     e23oi : -E23oi(self.e23i.0 * rhs.eo.0) + E23oi(self.e23o.0 * rhs.ei.0) + E23oi(self.e2oi.0 * rhs.e3.0) - E23oi(self.e3oi.0 * rhs.e2.0),
+		//     e123oi: E123oi(self.e123.0 * rhs.eoi.0) + E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e12o.0 * rhs.e3i.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e13o.0 * rhs.e2i.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e23o.0 * rhs.e1i.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e123.wedge(rhs.s),
+			e12o: self.e12o.wedge(rhs.s),
+			e12i: self.e12i.wedge(rhs.s),
+			e13o: self.e13o.wedge(rhs.s),
+			e13i: self.e13i.wedge(rhs.s),
+			e1oi: self.e1oi.wedge(rhs.s),
+			e23o: self.e23o.wedge(rhs.s),
+			e23i: self.e23i.wedge(rhs.s),
+			e2oi: self.e2oi.wedge(rhs.s),
+			e3oi: self.e3oi.wedge(rhs.s),
+			e123o: self.e123.wedge(rhs.eo)
+				+ self.e12o.wedge(rhs.e3)
+				+ self.e13o.wedge(rhs.e2)
+				+ self.e23o.wedge(rhs.e1),
+			e123i: self.e123.wedge(rhs.ei)
+				+ self.e12i.wedge(rhs.e3)
+				+ self.e13i.wedge(rhs.e2)
+				+ self.e23i.wedge(rhs.e1),
+			e12oi: self.e12i.wedge(rhs.eo)
+				+ self.e12o.wedge(rhs.ei)
+				+ self.e1oi.wedge(rhs.e2)
+				+ self.e2oi.wedge(rhs.e1),
+			e13oi: self.e13i.wedge(rhs.eo)
+				+ self.e13o.wedge(rhs.ei)
+				+ self.e1oi.wedge(rhs.e3)
+				+ self.e3oi.wedge(rhs.e1),
+			e23oi: self.e23i.wedge(rhs.eo)
+				+ self.e23o.wedge(rhs.ei)
+				+ self.e2oi.wedge(rhs.e3)
+				+ self.e3oi.wedge(rhs.e2),
+			e123oi: self.e123.wedge(rhs.eoi)
+				+ self.e12i.wedge(rhs.e3o)
+				+ self.e12o.wedge(rhs.e3i)
+				+ self.e13i.wedge(rhs.e2o)
+				+ self.e13o.wedge(rhs.e2i)
+				+ self.e1oi.wedge(rhs.e23)
+				+ self.e23i.wedge(rhs.e1o)
+				+ self.e23o.wedge(rhs.e1i)
+				+ self.e2oi.wedge(rhs.e13)
+				+ self.e3oi.wedge(rhs.e12),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Circle {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Circle.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Circle {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e123.0 * rhs.eoi.0) + S(self.e12i.0 * rhs.e3o.0) - S(self.e12o.0 * rhs.e3i.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e13o.0 * rhs.e2i.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e23o.0 * rhs.e1i.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e1    : E1(self.e123.0 * rhs.e1oi.0) + E1(self.e12i.0 * rhs.e13o.0) - E1(self.e12o.0 * rhs.e13i.0) - E1(self.e13i.0 * rhs.e12o.0) + E1(self.e13o.0 * rhs.e12i.0) + E1(self.e1oi.0 * rhs.e123.0),
+		//     e2    : E2(self.e123.0 * rhs.e2oi.0) + E2(self.e12i.0 * rhs.e23o.0) - E2(self.e12o.0 * rhs.e23i.0) - E2(self.e23i.0 * rhs.e12o.0) + E2(self.e23o.0 * rhs.e12i.0) + E2(self.e2oi.0 * rhs.e123.0),
+		//     e3    : E3(self.e123.0 * rhs.e3oi.0) + E3(self.e13i.0 * rhs.e23o.0) - E3(self.e13o.0 * rhs.e23i.0) - E3(self.e23i.0 * rhs.e13o.0) + E3(self.e23o.0 * rhs.e13i.0) + E3(self.e3oi.0 * rhs.e123.0),
+		//     eo    : Eo(self.e12o.0 * rhs.e3oi.0) - Eo(self.e13o.0 * rhs.e2oi.0) + Eo(self.e1oi.0 * rhs.e23o.0) + Eo(self.e23o.0 * rhs.e1oi.0) - Eo(self.e2oi.0 * rhs.e13o.0) + Eo(self.e3oi.0 * rhs.e12o.0),
+		//     ei    : Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		//     e12   : E12(self.e123.0 * rhs.e12oi.0) + E12(self.e12i.0 * rhs.e123o.0) - E12(self.e12o.0 * rhs.e123i.0),
+		//     e13   : E13(self.e123.0 * rhs.e13oi.0) + E13(self.e13i.0 * rhs.e123o.0) - E13(self.e13o.0 * rhs.e123i.0),
+		//     e1o   : E1o(self.e12o.0 * rhs.e13oi.0) - E1o(self.e13o.0 * rhs.e12oi.0) + E1o(self.e1oi.0 * rhs.e123o.0),
+		//     e1i   : E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23   : E23(self.e123.0 * rhs.e23oi.0) + E23(self.e23i.0 * rhs.e123o.0) - E23(self.e23o.0 * rhs.e123i.0),
+		//     e2o   : E2o(self.e12o.0 * rhs.e23oi.0) - E2o(self.e23o.0 * rhs.e12oi.0) + E2o(self.e2oi.0 * rhs.e123o.0),
+		//     e2i   : E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o   : E3o(self.e13o.0 * rhs.e23oi.0) - E3o(self.e23o.0 * rhs.e13oi.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i   : E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi   : Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123  : E123(self.e123.0 * rhs.e123oi.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.e123oi.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.e123oi.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.e123oi.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.e123oi.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.e123oi.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.e123oi.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.e123oi.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.e123oi.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.e123oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.e123.anti_wedge(rhs.eoi)
+				+ self.e12i.anti_wedge(rhs.e3o)
+				+ self.e12o.anti_wedge(rhs.e3i)
+				+ self.e13i.anti_wedge(rhs.e2o)
+				+ self.e13o.anti_wedge(rhs.e2i)
+				+ self.e1oi.anti_wedge(rhs.e23)
+				+ self.e23i.anti_wedge(rhs.e1o)
+				+ self.e23o.anti_wedge(rhs.e1i)
+				+ self.e2oi.anti_wedge(rhs.e13)
+				+ self.e3oi.anti_wedge(rhs.e12),
+			e1: self.e123.anti_wedge(rhs.e1oi)
+				+ self.e12i.anti_wedge(rhs.e13o)
+				+ self.e12o.anti_wedge(rhs.e13i)
+				+ self.e13i.anti_wedge(rhs.e12o)
+				+ self.e13o.anti_wedge(rhs.e12i)
+				+ self.e1oi.anti_wedge(rhs.e123),
+			e2: self.e123.anti_wedge(rhs.e2oi)
+				+ self.e12i.anti_wedge(rhs.e23o)
+				+ self.e12o.anti_wedge(rhs.e23i)
+				+ self.e23i.anti_wedge(rhs.e12o)
+				+ self.e23o.anti_wedge(rhs.e12i)
+				+ self.e2oi.anti_wedge(rhs.e123),
+			e3: self.e123.anti_wedge(rhs.e3oi)
+				+ self.e13i.anti_wedge(rhs.e23o)
+				+ self.e13o.anti_wedge(rhs.e23i)
+				+ self.e23i.anti_wedge(rhs.e13o)
+				+ self.e23o.anti_wedge(rhs.e13i)
+				+ self.e3oi.anti_wedge(rhs.e123),
+			eo: self.e12o.anti_wedge(rhs.e3oi)
+				+ self.e13o.anti_wedge(rhs.e2oi)
+				+ self.e1oi.anti_wedge(rhs.e23o)
+				+ self.e23o.anti_wedge(rhs.e1oi)
+				+ self.e2oi.anti_wedge(rhs.e13o)
+				+ self.e3oi.anti_wedge(rhs.e12o),
+			ei: self.e12i.anti_wedge(rhs.e3oi)
+				+ self.e13i.anti_wedge(rhs.e2oi)
+				+ self.e1oi.anti_wedge(rhs.e23i)
+				+ self.e23i.anti_wedge(rhs.e1oi)
+				+ self.e2oi.anti_wedge(rhs.e13i)
+				+ self.e3oi.anti_wedge(rhs.e12i),
+			e12: self.e123.anti_wedge(rhs.e12oi) + self.e12i.anti_wedge(rhs.e123o) + self.e12o.anti_wedge(rhs.e123i),
+			e13: self.e123.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e123o) + self.e13o.anti_wedge(rhs.e123i),
+			e1o: self.e12o.anti_wedge(rhs.e13oi) + self.e13o.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123o),
+			e1i: self.e12i.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123i),
+			e23: self.e123.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e123o) + self.e23o.anti_wedge(rhs.e123i),
+			e2o: self.e12o.anti_wedge(rhs.e23oi) + self.e23o.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123o),
+			e2i: self.e12i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123i),
+			e3o: self.e13o.anti_wedge(rhs.e23oi) + self.e23o.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123o),
+			e3i: self.e13i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123i),
+			eoi: self.e1oi.anti_wedge(rhs.e23oi) + self.e2oi.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e12oi),
+			e123: self.e123.anti_wedge(rhs.e123oi),
+			e12o: self.e12o.anti_wedge(rhs.e123oi),
+			e12i: self.e12i.anti_wedge(rhs.e123oi),
+			e13o: self.e13o.anti_wedge(rhs.e123oi),
+			e13i: self.e13i.anti_wedge(rhs.e123oi),
+			e1oi: self.e1oi.anti_wedge(rhs.e123oi),
+			e23o: self.e23o.anti_wedge(rhs.e123oi),
+			e23i: self.e23i.anti_wedge(rhs.e123oi),
+			e2oi: self.e2oi.anti_wedge(rhs.e123oi),
+			e3oi: self.e3oi.anti_wedge(rhs.e123oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Circle {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Circle.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Circle {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123.0 * rhs.e123.0) + S(self.e12i.0 * rhs.e12o.0) + S(self.e12o.0 * rhs.e12i.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e1    : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     eo    : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     ei    : -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		//     e12   : E12(self.e3oi.0 * rhs.e123oi.0),
+		//     e13   : -E13(self.e2oi.0 * rhs.e123oi.0),
+		//     e1o   : E1o(self.e23o.0 * rhs.e123oi.0),
+		//     e1i   : -E1i(self.e23i.0 * rhs.e123oi.0),
+		//     e23   : E23(self.e1oi.0 * rhs.e123oi.0),
+		//     e2o   : -E2o(self.e13o.0 * rhs.e123oi.0),
+		//     e2i   : E2i(self.e13i.0 * rhs.e123oi.0),
+		//     e3o   : E3o(self.e12o.0 * rhs.e123oi.0),
+		//     e3i   : -E3i(self.e12i.0 * rhs.e123oi.0),
+		//     eoi   : -Eoi(self.e123.0 * rhs.e123oi.0),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e123.0 * rhs.e123.0)
+				+ S(self.e12i.0 * rhs.e12o.0)
+				+ S(self.e12o.0 * rhs.e12i.0)
+				+ S(self.e13i.0 * rhs.e13o.0)
+				+ S(self.e13o.0 * rhs.e13i.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23i.0 * rhs.e23o.0)
+				+ S(self.e23o.0 * rhs.e23i.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e1: -E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0),
+			eo: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0),
+			ei: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+			e12: E12(self.e3oi.0 * rhs.e123oi.0),
+			e13: -E13(self.e2oi.0 * rhs.e123oi.0),
+			e1o: E1o(self.e23o.0 * rhs.e123oi.0),
+			e1i: -E1i(self.e23i.0 * rhs.e123oi.0),
+			e23: E23(self.e1oi.0 * rhs.e123oi.0),
+			e2o: -E2o(self.e13o.0 * rhs.e123oi.0),
+			e2i: E2i(self.e13i.0 * rhs.e123oi.0),
+			e3o: E3o(self.e12o.0 * rhs.e123oi.0),
+			e3i: -E3i(self.e12i.0 * rhs.e123oi.0),
+			eoi: -Eoi(self.e123.0 * rhs.e123oi.0),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Circle.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Circle {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123.0 * rhs.e123.0) + S(self.e12i.0 * rhs.e12o.0) + S(self.e12o.0 * rhs.e12i.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e1    : -E1(self.e123.0 * rhs.e23.0) + E1(self.e12i.0 * rhs.e2o.0) + E1(self.e12o.0 * rhs.e2i.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e13o.0 * rhs.e3i.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     e2    : E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e23o.0 * rhs.e3i.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     e3    : -E3(self.e123.0 * rhs.e12.0) - E3(self.e13i.0 * rhs.e1o.0) - E3(self.e13o.0 * rhs.e1i.0) - E3(self.e23i.0 * rhs.e2o.0) - E3(self.e23o.0 * rhs.e2i.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e12o.0 * rhs.e12.0) - Eo(self.e13o.0 * rhs.e13.0) - Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e23o.0 * rhs.e23.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : E12(self.e123.0 * rhs.e3.0) - E12(self.e12i.0 * rhs.eo.0) - E12(self.e12o.0 * rhs.ei.0),
+		//     e13   : -E13(self.e123.0 * rhs.e2.0) - E13(self.e13i.0 * rhs.eo.0) - E13(self.e13o.0 * rhs.ei.0),
+		//     e1o   : -E1o(self.e12o.0 * rhs.e2.0) - E1o(self.e13o.0 * rhs.e3.0) - E1o(self.e1oi.0 * rhs.eo.0),
+		//     e1i   : -E1i(self.e12i.0 * rhs.e2.0) - E1i(self.e13i.0 * rhs.e3.0) + E1i(self.e1oi.0 * rhs.ei.0),
+		//     e23   : E23(self.e123.0 * rhs.e1.0) - E23(self.e23i.0 * rhs.eo.0) - E23(self.e23o.0 * rhs.ei.0),
+		//     e2o   : E2o(self.e12o.0 * rhs.e1.0) - E2o(self.e23o.0 * rhs.e3.0) - E2o(self.e2oi.0 * rhs.eo.0),
+		//     e2i   : E2i(self.e12i.0 * rhs.e1.0) - E2i(self.e23i.0 * rhs.e3.0) + E2i(self.e2oi.0 * rhs.ei.0),
+		//     e3o   : E3o(self.e13o.0 * rhs.e1.0) + E3o(self.e23o.0 * rhs.e2.0) - E3o(self.e3oi.0 * rhs.eo.0),
+		//     e3i   : E3i(self.e13i.0 * rhs.e1.0) + E3i(self.e23i.0 * rhs.e2.0) + E3i(self.e3oi.0 * rhs.ei.0),
+		//     eoi   : Eoi(self.e1oi.0 * rhs.e1.0) + Eoi(self.e2oi.0 * rhs.e2.0) + Eoi(self.e3oi.0 * rhs.e3.0),
+		//     e123  : E123(self.e123.0 * rhs.s.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e13o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : E23o(self.e23o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e123.0 * rhs.e123.0)
+				+ S(self.e12i.0 * rhs.e12o.0)
+				+ S(self.e12o.0 * rhs.e12i.0)
+				+ S(self.e13i.0 * rhs.e13o.0)
+				+ S(self.e13o.0 * rhs.e13i.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23i.0 * rhs.e23o.0)
+				+ S(self.e23o.0 * rhs.e23i.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e1: -E1(self.e123.0 * rhs.e23.0)
+				+ E1(self.e12i.0 * rhs.e2o.0)
+				+ E1(self.e12o.0 * rhs.e2i.0)
+				+ E1(self.e13i.0 * rhs.e3o.0)
+				+ E1(self.e13o.0 * rhs.e3i.0)
+				+ E1(self.e1oi.0 * rhs.eoi.0),
+			e2: E2(self.e123.0 * rhs.e13.0) - E2(self.e12i.0 * rhs.e1o.0) - E2(self.e12o.0 * rhs.e1i.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e23o.0 * rhs.e3i.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0),
+			e3: -E3(self.e123.0 * rhs.e12.0)
+				- E3(self.e13i.0 * rhs.e1o.0)
+				- E3(self.e13o.0 * rhs.e1i.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				- E3(self.e23o.0 * rhs.e2i.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e12o.0 * rhs.e12.0)
+				- Eo(self.e13o.0 * rhs.e13.0)
+				- Eo(self.e1oi.0 * rhs.e1o.0)
+				- Eo(self.e23o.0 * rhs.e23.0)
+				- Eo(self.e2oi.0 * rhs.e2o.0)
+				- Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: E12(self.e123.0 * rhs.e3.0) - E12(self.e12i.0 * rhs.eo.0) - E12(self.e12o.0 * rhs.ei.0),
+			e13: -E13(self.e123.0 * rhs.e2.0) - E13(self.e13i.0 * rhs.eo.0) - E13(self.e13o.0 * rhs.ei.0),
+			e1o: -E1o(self.e12o.0 * rhs.e2.0) - E1o(self.e13o.0 * rhs.e3.0) - E1o(self.e1oi.0 * rhs.eo.0),
+			e1i: -E1i(self.e12i.0 * rhs.e2.0) - E1i(self.e13i.0 * rhs.e3.0) + E1i(self.e1oi.0 * rhs.ei.0),
+			e23: E23(self.e123.0 * rhs.e1.0) - E23(self.e23i.0 * rhs.eo.0) - E23(self.e23o.0 * rhs.ei.0),
+			e2o: E2o(self.e12o.0 * rhs.e1.0) - E2o(self.e23o.0 * rhs.e3.0) - E2o(self.e2oi.0 * rhs.eo.0),
+			e2i: E2i(self.e12i.0 * rhs.e1.0) - E2i(self.e23i.0 * rhs.e3.0) + E2i(self.e2oi.0 * rhs.ei.0),
+			e3o: E3o(self.e13o.0 * rhs.e1.0) + E3o(self.e23o.0 * rhs.e2.0) - E3o(self.e3oi.0 * rhs.eo.0),
+			e3i: E3i(self.e13i.0 * rhs.e1.0) + E3i(self.e23i.0 * rhs.e2.0) + E3i(self.e3oi.0 * rhs.ei.0),
+			eoi: Eoi(self.e1oi.0 * rhs.e1.0) + Eoi(self.e2oi.0 * rhs.e2.0) + Eoi(self.e3oi.0 * rhs.e3.0),
+			e123: E123(self.e123.0 * rhs.s.0),
+			e12o: E12o(self.e12o.0 * rhs.s.0),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: E13o(self.e13o.0 * rhs.s.0),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: E23o(self.e23o.0 * rhs.s.0),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Circle.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Circle {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.e123.0 * rhs.e123.0) + S(self.e12i.0 * rhs.e12o.0) + S(self.e12o.0 * rhs.e12i.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e13o.0 * rhs.e13i.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e23o.0 * rhs.e23i.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		-S(self.e123.0 * rhs.e123.0)
+			+ S(self.e12i.0 * rhs.e12o.0)
+			+ S(self.e12o.0 * rhs.e12i.0)
+			+ S(self.e13i.0 * rhs.e13o.0)
+			+ S(self.e13o.0 * rhs.e13i.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23i.0 * rhs.e23o.0)
+			+ S(self.e23o.0 * rhs.e23i.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Circle.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Circle {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e23o.0 * rhs.e123i.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     eo    : -Eo(self.e123.0 * rhs.e123o.0) - Eo(self.e12o.0 * rhs.e12oi.0) - Eo(self.e13o.0 * rhs.e13oi.0) - Eo(self.e23o.0 * rhs.e23oi.0),
+		//     ei    : -Ei(self.e123.0 * rhs.e123i.0) + Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		//     e12   : E12(self.e13i.0 * rhs.e23o.0) + E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e23i.0 * rhs.e13o.0) - E12(self.e23o.0 * rhs.e13i.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+		//     e13   : -E13(self.e12i.0 * rhs.e23o.0) - E13(self.e12o.0 * rhs.e23i.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0) + E13(self.e23o.0 * rhs.e12i.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o   : -E1o(self.e123.0 * rhs.e23o.0) - E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3oi.0) + E1o(self.e23o.0 * rhs.e123.0) + E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+		//     e1i   : -E1i(self.e123.0 * rhs.e23i.0) + E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e23i.0 * rhs.e123.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23   : E23(self.e12i.0 * rhs.e13o.0) + E23(self.e12o.0 * rhs.e13i.0) - E23(self.e13i.0 * rhs.e12o.0) - E23(self.e13o.0 * rhs.e12i.0) + E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o   : E2o(self.e123.0 * rhs.e13o.0) + E2o(self.e12o.0 * rhs.e1oi.0) - E2o(self.e13o.0 * rhs.e123.0) - E2o(self.e1oi.0 * rhs.e12o.0) - E2o(self.e23o.0 * rhs.e3oi.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+		//     e2i   : E2i(self.e123.0 * rhs.e13i.0) - E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o   : -E3o(self.e123.0 * rhs.e12o.0) + E3o(self.e12o.0 * rhs.e123.0) + E3o(self.e13o.0 * rhs.e1oi.0) - E3o(self.e1oi.0 * rhs.e13o.0) + E3o(self.e23o.0 * rhs.e2oi.0) - E3o(self.e2oi.0 * rhs.e23o.0),
+		//     e3i   : -E3i(self.e123.0 * rhs.e12i.0) + E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0),
+		//     eoi   : Eoi(self.e12i.0 * rhs.e12o.0) - Eoi(self.e12o.0 * rhs.e12i.0) + Eoi(self.e13i.0 * rhs.e13o.0) - Eoi(self.e13o.0 * rhs.e13i.0) + Eoi(self.e23i.0 * rhs.e23o.0) - Eoi(self.e23o.0 * rhs.e23i.0),
+		//     e123  : E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0) - E123(self.e13i.0 * rhs.e2o.0) - E123(self.e13o.0 * rhs.e2i.0) + E123(self.e23i.0 * rhs.e1o.0) + E123(self.e23o.0 * rhs.e1i.0),
+		//     e12o  : E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0) - E12o(self.e13o.0 * rhs.e23.0) - E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e23o.0 * rhs.e13.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+		//     e12i  : E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o  : -E13o(self.e123.0 * rhs.e2o.0) + E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e13o.0 * rhs.eoi.0) - E13o(self.e1oi.0 * rhs.e3o.0) - E13o(self.e23o.0 * rhs.e12.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i  : -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0) + E1oi(self.e13i.0 * rhs.e3o.0) - E1oi(self.e13o.0 * rhs.e3i.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0) + E23o(self.e13o.0 * rhs.e12.0) + E23o(self.e23o.0 * rhs.eoi.0) - E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e12o.0 * rhs.e1i.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) - E2oi(self.e23o.0 * rhs.e3i.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e23o.0 * rhs.e2i.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		//     e123o : E123o(self.e123.0 * rhs.eo.0) - E123o(self.e12o.0 * rhs.e3.0) + E123o(self.e13o.0 * rhs.e2.0) - E123o(self.e23o.0 * rhs.e1.0),
+		//     e123i : E123i(self.e123.0 * rhs.ei.0) - E123i(self.e12i.0 * rhs.e3.0) + E123i(self.e13i.0 * rhs.e2.0) - E123i(self.e23i.0 * rhs.e1.0),
+		//     e12oi : -E12oi(self.e12i.0 * rhs.eo.0) + E12oi(self.e12o.0 * rhs.ei.0) + E12oi(self.e1oi.0 * rhs.e2.0) - E12oi(self.e2oi.0 * rhs.e1.0),
+		//     e13oi : -E13oi(self.e13i.0 * rhs.eo.0) + E13oi(self.e13o.0 * rhs.ei.0) + E13oi(self.e1oi.0 * rhs.e3.0) - E13oi(self.e3oi.0 * rhs.e1.0),
+		//     e23oi : -E23oi(self.e23i.0 * rhs.eo.0) + E23oi(self.e23o.0 * rhs.ei.0) + E23oi(self.e2oi.0 * rhs.e3.0) - E23oi(self.e3oi.0 * rhs.e2.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e23o.0 * rhs.e123i.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e13o.0 * rhs.e123i.0) + E2(self.e1oi.0 * rhs.e12oi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e12o.0 * rhs.e123i.0)
+				+ E3(self.e1oi.0 * rhs.e13oi.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0),
+			eo: -Eo(self.e123.0 * rhs.e123o.0)
+				- Eo(self.e12o.0 * rhs.e12oi.0)
+				- Eo(self.e13o.0 * rhs.e13oi.0)
+				- Eo(self.e23o.0 * rhs.e23oi.0),
+			ei: -Ei(self.e123.0 * rhs.e123i.0)
+				+ Ei(self.e12i.0 * rhs.e12oi.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0),
+			e12: E12(self.e13i.0 * rhs.e23o.0) + E12(self.e13o.0 * rhs.e23i.0) + E12(self.e1oi.0 * rhs.e2oi.0)
+				- E12(self.e23i.0 * rhs.e13o.0)
+				- E12(self.e23o.0 * rhs.e13i.0)
+				- E12(self.e2oi.0 * rhs.e1oi.0),
+			e13: -E13(self.e12i.0 * rhs.e23o.0) - E13(self.e12o.0 * rhs.e23i.0)
+				+ E13(self.e1oi.0 * rhs.e3oi.0)
+				+ E13(self.e23i.0 * rhs.e12o.0)
+				+ E13(self.e23o.0 * rhs.e12i.0)
+				- E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: -E1o(self.e123.0 * rhs.e23o.0) - E1o(self.e12o.0 * rhs.e2oi.0) - E1o(self.e13o.0 * rhs.e3oi.0)
+				+ E1o(self.e23o.0 * rhs.e123.0)
+				+ E1o(self.e2oi.0 * rhs.e12o.0)
+				+ E1o(self.e3oi.0 * rhs.e13o.0),
+			e1i: -E1i(self.e123.0 * rhs.e23i.0)
+				+ E1i(self.e12i.0 * rhs.e2oi.0)
+				+ E1i(self.e13i.0 * rhs.e3oi.0)
+				+ E1i(self.e23i.0 * rhs.e123.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e12i.0 * rhs.e13o.0) + E23(self.e12o.0 * rhs.e13i.0)
+				- E23(self.e13i.0 * rhs.e12o.0)
+				- E23(self.e13o.0 * rhs.e12i.0)
+				+ E23(self.e2oi.0 * rhs.e3oi.0)
+				- E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: E2o(self.e123.0 * rhs.e13o.0) + E2o(self.e12o.0 * rhs.e1oi.0)
+				- E2o(self.e13o.0 * rhs.e123.0)
+				- E2o(self.e1oi.0 * rhs.e12o.0)
+				- E2o(self.e23o.0 * rhs.e3oi.0)
+				+ E2o(self.e3oi.0 * rhs.e23o.0),
+			e2i: E2i(self.e123.0 * rhs.e13i.0) - E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0)
+				+ E2i(self.e1oi.0 * rhs.e12i.0)
+				+ E2i(self.e23i.0 * rhs.e3oi.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: -E3o(self.e123.0 * rhs.e12o.0) + E3o(self.e12o.0 * rhs.e123.0) + E3o(self.e13o.0 * rhs.e1oi.0)
+				- E3o(self.e1oi.0 * rhs.e13o.0)
+				+ E3o(self.e23o.0 * rhs.e2oi.0)
+				- E3o(self.e2oi.0 * rhs.e23o.0),
+			e3i: -E3i(self.e123.0 * rhs.e12i.0) + E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0)
+				+ E3i(self.e1oi.0 * rhs.e13i.0)
+				- E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0),
+			eoi: Eoi(self.e12i.0 * rhs.e12o.0) - Eoi(self.e12o.0 * rhs.e12i.0) + Eoi(self.e13i.0 * rhs.e13o.0)
+				- Eoi(self.e13o.0 * rhs.e13i.0)
+				+ Eoi(self.e23i.0 * rhs.e23o.0)
+				- Eoi(self.e23o.0 * rhs.e23i.0),
+			e123: E123(self.e12i.0 * rhs.e3o.0) + E123(self.e12o.0 * rhs.e3i.0)
+				- E123(self.e13i.0 * rhs.e2o.0)
+				- E123(self.e13o.0 * rhs.e2i.0)
+				+ E123(self.e23i.0 * rhs.e1o.0)
+				+ E123(self.e23o.0 * rhs.e1i.0),
+			e12o: E12o(self.e123.0 * rhs.e3o.0) + E12o(self.e12o.0 * rhs.eoi.0)
+				- E12o(self.e13o.0 * rhs.e23.0)
+				- E12o(self.e1oi.0 * rhs.e2o.0)
+				+ E12o(self.e23o.0 * rhs.e13.0)
+				+ E12o(self.e2oi.0 * rhs.e1o.0),
+			e12i: E12i(self.e123.0 * rhs.e3i.0) - E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: -E13o(self.e123.0 * rhs.e2o.0) + E13o(self.e12o.0 * rhs.e23.0) + E13o(self.e13o.0 * rhs.eoi.0)
+				- E13o(self.e1oi.0 * rhs.e3o.0)
+				- E13o(self.e23o.0 * rhs.e12.0)
+				+ E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: -E13i(self.e123.0 * rhs.e2i.0) + E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0)
+				+ E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e12i.0 * rhs.e2o.0) - E1oi(self.e12o.0 * rhs.e2i.0) + E1oi(self.e13i.0 * rhs.e3o.0)
+				- E1oi(self.e13o.0 * rhs.e3i.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: E23o(self.e123.0 * rhs.e1o.0) - E23o(self.e12o.0 * rhs.e13.0)
+				+ E23o(self.e13o.0 * rhs.e12.0)
+				+ E23o(self.e23o.0 * rhs.eoi.0)
+				- E23o(self.e2oi.0 * rhs.e3o.0)
+				+ E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: E23i(self.e123.0 * rhs.e1i.0) - E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0)
+				- E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e1o.0)
+				+ E2oi(self.e12o.0 * rhs.e1i.0)
+				+ E2oi(self.e1oi.0 * rhs.e12.0)
+				+ E2oi(self.e23i.0 * rhs.e3o.0)
+				- E2oi(self.e23o.0 * rhs.e3i.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e13o.0 * rhs.e1i.0) + E3oi(self.e1oi.0 * rhs.e13.0)
+				- E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e23o.0 * rhs.e2i.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0),
+			e123o: E123o(self.e123.0 * rhs.eo.0) - E123o(self.e12o.0 * rhs.e3.0) + E123o(self.e13o.0 * rhs.e2.0)
+				- E123o(self.e23o.0 * rhs.e1.0),
+			e123i: E123i(self.e123.0 * rhs.ei.0) - E123i(self.e12i.0 * rhs.e3.0) + E123i(self.e13i.0 * rhs.e2.0)
+				- E123i(self.e23i.0 * rhs.e1.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.eo.0) + E12oi(self.e12o.0 * rhs.ei.0) + E12oi(self.e1oi.0 * rhs.e2.0)
+				- E12oi(self.e2oi.0 * rhs.e1.0),
+			e13oi: -E13oi(self.e13i.0 * rhs.eo.0) + E13oi(self.e13o.0 * rhs.ei.0) + E13oi(self.e1oi.0 * rhs.e3.0)
+				- E13oi(self.e3oi.0 * rhs.e1.0),
+			e23oi: -E23oi(self.e23i.0 * rhs.eo.0) + E23oi(self.e23o.0 * rhs.ei.0) + E23oi(self.e2oi.0 * rhs.e3.0)
+				- E23oi(self.e3oi.0 * rhs.e2.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Circle.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Circle {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : -E12(self.e3oi.0 * rhs.s.0),
+		//     e13   : E13(self.e2oi.0 * rhs.s.0),
+		//     e1o   : -E1o(self.e23o.0 * rhs.s.0),
+		//     e1i   : E1i(self.e23i.0 * rhs.s.0),
+		//     e23   : -E23(self.e1oi.0 * rhs.s.0),
+		//     e2o   : E2o(self.e13o.0 * rhs.s.0),
+		//     e2i   : -E2i(self.e13i.0 * rhs.s.0),
+		//     e3o   : -E3o(self.e12o.0 * rhs.s.0),
+		//     e3i   : E3i(self.e12i.0 * rhs.s.0),
+		//     eoi   : Eoi(self.e123.0 * rhs.s.0),
+		//     e123  : E123(self.e123.0 * rhs.e123oi.0) - E123(self.e1oi.0 * rhs.e1.0) - E123(self.e2oi.0 * rhs.e2.0) - E123(self.e3oi.0 * rhs.e3.0),
+		//     e12o  : E12o(self.e12o.0 * rhs.e123oi.0) + E12o(self.e13o.0 * rhs.e1.0) + E12o(self.e23o.0 * rhs.e2.0) - E12o(self.e3oi.0 * rhs.eo.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.e123oi.0) - E12i(self.e13i.0 * rhs.e1.0) - E12i(self.e23i.0 * rhs.e2.0) - E12i(self.e3oi.0 * rhs.ei.0),
+		//     e13o  : -E13o(self.e12o.0 * rhs.e1.0) + E13o(self.e13o.0 * rhs.e123oi.0) + E13o(self.e23o.0 * rhs.e3.0) + E13o(self.e2oi.0 * rhs.eo.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.e1.0) + E13i(self.e13i.0 * rhs.e123oi.0) - E13i(self.e23i.0 * rhs.e3.0) + E13i(self.e2oi.0 * rhs.ei.0),
+		//     e1oi  : E1oi(self.e123.0 * rhs.e1.0) + E1oi(self.e1oi.0 * rhs.e123oi.0) - E1oi(self.e23i.0 * rhs.eo.0) - E1oi(self.e23o.0 * rhs.ei.0),
+		//     e23o  : -E23o(self.e12o.0 * rhs.e2.0) - E23o(self.e13o.0 * rhs.e3.0) - E23o(self.e1oi.0 * rhs.eo.0) + E23o(self.e23o.0 * rhs.e123oi.0),
+		//     e23i  : E23i(self.e12i.0 * rhs.e2.0) + E23i(self.e13i.0 * rhs.e3.0) - E23i(self.e1oi.0 * rhs.ei.0) + E23i(self.e23i.0 * rhs.e123oi.0),
+		//     e2oi  : E2oi(self.e123.0 * rhs.e2.0) + E2oi(self.e13i.0 * rhs.eo.0) + E2oi(self.e13o.0 * rhs.ei.0) + E2oi(self.e2oi.0 * rhs.e123oi.0),
+		//     e3oi  : E3oi(self.e123.0 * rhs.e3.0) - E3oi(self.e12i.0 * rhs.eo.0) - E3oi(self.e12o.0 * rhs.ei.0) + E3oi(self.e3oi.0 * rhs.e123oi.0),
+		//     e123o : E123o(self.e123.0 * rhs.e123o.0) - E123o(self.e12o.0 * rhs.e12.0) + E123o(self.e12o.0 * rhs.e12oi.0) - E123o(self.e13o.0 * rhs.e13.0) + E123o(self.e13o.0 * rhs.e13oi.0) - E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e23o.0 * rhs.e23.0) + E123o(self.e23o.0 * rhs.e23oi.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i : -E123i(self.e123.0 * rhs.e123i.0) + E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e13i.0 * rhs.e13oi.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) + E123i(self.e23i.0 * rhs.e23oi.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi : E12oi(self.e123.0 * rhs.e12.0) - E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0) + E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e13o.0 * rhs.e1i.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e23i.0 * rhs.e2o.0) + E12oi(self.e23o.0 * rhs.e2i.0) + E12oi(self.e2oi.0 * rhs.e23oi.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi : E13oi(self.e123.0 * rhs.e13.0) - E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e12o.0 * rhs.e1i.0) - E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e13o.0 * rhs.e123i.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e23o.0 * rhs.e3i.0) + E13oi(self.e2oi.0 * rhs.eoi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi : E23oi(self.e123.0 * rhs.e23.0) - E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e12o.0 * rhs.e2i.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e13o.0 * rhs.e3i.0) - E23oi(self.e1oi.0 * rhs.eoi.0) - E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e23o.0 * rhs.e123i.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		//     e123oi: E123oi(self.e123.0 * rhs.e123.0) - E123oi(self.e12i.0 * rhs.e12o.0) - E123oi(self.e12o.0 * rhs.e12i.0) - E123oi(self.e13i.0 * rhs.e13o.0) - E123oi(self.e13o.0 * rhs.e13i.0) - E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e23i.0 * rhs.e23o.0) - E123oi(self.e23o.0 * rhs.e23i.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: -E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e2oi.0 * rhs.s.0),
+			e1o: -E1o(self.e23o.0 * rhs.s.0),
+			e1i: E1i(self.e23i.0 * rhs.s.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0),
+			e2o: E2o(self.e13o.0 * rhs.s.0),
+			e2i: -E2i(self.e13i.0 * rhs.s.0),
+			e3o: -E3o(self.e12o.0 * rhs.s.0),
+			e3i: E3i(self.e12i.0 * rhs.s.0),
+			eoi: Eoi(self.e123.0 * rhs.s.0),
+			e123: E123(self.e123.0 * rhs.e123oi.0)
+				- E123(self.e1oi.0 * rhs.e1.0)
+				- E123(self.e2oi.0 * rhs.e2.0)
+				- E123(self.e3oi.0 * rhs.e3.0),
+			e12o: E12o(self.e12o.0 * rhs.e123oi.0) + E12o(self.e13o.0 * rhs.e1.0) + E12o(self.e23o.0 * rhs.e2.0)
+				- E12o(self.e3oi.0 * rhs.eo.0),
+			e12i: E12i(self.e12i.0 * rhs.e123oi.0)
+				- E12i(self.e13i.0 * rhs.e1.0)
+				- E12i(self.e23i.0 * rhs.e2.0)
+				- E12i(self.e3oi.0 * rhs.ei.0),
+			e13o: -E13o(self.e12o.0 * rhs.e1.0)
+				+ E13o(self.e13o.0 * rhs.e123oi.0)
+				+ E13o(self.e23o.0 * rhs.e3.0)
+				+ E13o(self.e2oi.0 * rhs.eo.0),
+			e13i: E13i(self.e12i.0 * rhs.e1.0) + E13i(self.e13i.0 * rhs.e123oi.0) - E13i(self.e23i.0 * rhs.e3.0)
+				+ E13i(self.e2oi.0 * rhs.ei.0),
+			e1oi: E1oi(self.e123.0 * rhs.e1.0) + E1oi(self.e1oi.0 * rhs.e123oi.0)
+				- E1oi(self.e23i.0 * rhs.eo.0)
+				- E1oi(self.e23o.0 * rhs.ei.0),
+			e23o: -E23o(self.e12o.0 * rhs.e2.0) - E23o(self.e13o.0 * rhs.e3.0) - E23o(self.e1oi.0 * rhs.eo.0)
+				+ E23o(self.e23o.0 * rhs.e123oi.0),
+			e23i: E23i(self.e12i.0 * rhs.e2.0) + E23i(self.e13i.0 * rhs.e3.0) - E23i(self.e1oi.0 * rhs.ei.0)
+				+ E23i(self.e23i.0 * rhs.e123oi.0),
+			e2oi: E2oi(self.e123.0 * rhs.e2.0)
+				+ E2oi(self.e13i.0 * rhs.eo.0)
+				+ E2oi(self.e13o.0 * rhs.ei.0)
+				+ E2oi(self.e2oi.0 * rhs.e123oi.0),
+			e3oi: E3oi(self.e123.0 * rhs.e3.0) - E3oi(self.e12i.0 * rhs.eo.0) - E3oi(self.e12o.0 * rhs.ei.0)
+				+ E3oi(self.e3oi.0 * rhs.e123oi.0),
+			e123o: E123o(self.e123.0 * rhs.e123o.0) - E123o(self.e12o.0 * rhs.e12.0) + E123o(self.e12o.0 * rhs.e12oi.0)
+				- E123o(self.e13o.0 * rhs.e13.0)
+				+ E123o(self.e13o.0 * rhs.e13oi.0)
+				- E123o(self.e1oi.0 * rhs.e1o.0)
+				- E123o(self.e23o.0 * rhs.e23.0)
+				+ E123o(self.e23o.0 * rhs.e23oi.0)
+				- E123o(self.e2oi.0 * rhs.e2o.0)
+				- E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: -E123i(self.e123.0 * rhs.e123i.0)
+				+ E123i(self.e12i.0 * rhs.e12.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				- E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e123.0 * rhs.e12.0) - E12oi(self.e12i.0 * rhs.e123o.0) - E12oi(self.e12o.0 * rhs.e123i.0)
+				+ E12oi(self.e13i.0 * rhs.e1o.0)
+				+ E12oi(self.e13o.0 * rhs.e1i.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e23i.0 * rhs.e2o.0)
+				+ E12oi(self.e23o.0 * rhs.e2i.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0)
+				- E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: E13oi(self.e123.0 * rhs.e13.0)
+				- E13oi(self.e12i.0 * rhs.e1o.0)
+				- E13oi(self.e12o.0 * rhs.e1i.0)
+				- E13oi(self.e13i.0 * rhs.e123o.0)
+				- E13oi(self.e13o.0 * rhs.e123i.0)
+				- E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e23i.0 * rhs.e3o.0)
+				+ E13oi(self.e23o.0 * rhs.e3i.0)
+				+ E13oi(self.e2oi.0 * rhs.eoi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: E23oi(self.e123.0 * rhs.e23.0)
+				- E23oi(self.e12i.0 * rhs.e2o.0)
+				- E23oi(self.e12o.0 * rhs.e2i.0)
+				- E23oi(self.e13i.0 * rhs.e3o.0)
+				- E23oi(self.e13o.0 * rhs.e3i.0)
+				- E23oi(self.e1oi.0 * rhs.eoi.0)
+				- E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e23o.0 * rhs.e123i.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+			e123oi: E123oi(self.e123.0 * rhs.e123.0)
+				- E123oi(self.e12i.0 * rhs.e12o.0)
+				- E123oi(self.e12o.0 * rhs.e12i.0)
+				- E123oi(self.e13i.0 * rhs.e13o.0)
+				- E123oi(self.e13o.0 * rhs.e13i.0)
+				- E123oi(self.e1oi.0 * rhs.e1oi.0)
+				- E123oi(self.e23i.0 * rhs.e23o.0)
+				- E123oi(self.e23o.0 * rhs.e23i.0)
+				- E123oi(self.e2oi.0 * rhs.e2oi.0)
+				- E123oi(self.e3oi.0 * rhs.e3oi.0),
+		}
+	}
+}
@@ -0,0 +1,6653 @@
+//! The C ABI of this algebra, declared in `cga3d.h`.
+
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_rcompl(value: Vec3) -> Plane {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_lcompl(value: Vec3) -> Plane {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_rev(value: Vec3) -> Vec3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_arev(value: Vec3) -> Vec3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_involute(value: Vec3) -> Vec3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_conjugate(value: Vec3) -> Vec3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dual(value: Vec3) -> Plane {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_vec3(lhs: Vec3, rhs: Vec3) -> Rotor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_vec3(lhs: Vec3, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_vec3(lhs: Vec3, rhs: Vec3) -> PointPair {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_right_contraction_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_scalar_product_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_vec3(lhs: Vec3, rhs: Vec3) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_vec3(lhs: Vec3, rhs: Vec3) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_point(lhs: Vec3, rhs: Point) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_point(lhs: Vec3, rhs: Point) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_point(lhs: Vec3, rhs: Point) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_point(lhs: Vec3, rhs: Point) -> PointPair {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_point(lhs: Vec3, rhs: Point) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_right_contraction_point(lhs: Vec3, rhs: Point) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_scalar_product_point(lhs: Vec3, rhs: Point) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_point(lhs: Vec3, rhs: Point) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_point(lhs: Vec3, rhs: Point) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_flatpoint(lhs: Vec3, rhs: FlatPoint) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_flatpoint(lhs: Vec3, rhs: FlatPoint) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_flatpoint(lhs: Vec3, rhs: FlatPoint) -> Ei {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_flatpoint(lhs: Vec3, rhs: FlatPoint) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_flatpoint(lhs: Vec3, rhs: FlatPoint) -> Ei {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_flatpoint(lhs: Vec3, rhs: FlatPoint) -> Ei {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_flatpoint(lhs: Vec3, rhs: FlatPoint) -> E123i {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_pointpair(lhs: Vec3, rhs: PointPair) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_pointpair(lhs: Vec3, rhs: PointPair) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_pointpair(lhs: Vec3, rhs: PointPair) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_pointpair(lhs: Vec3, rhs: PointPair) -> Circle {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_pointpair(lhs: Vec3, rhs: PointPair) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_pointpair(lhs: Vec3, rhs: PointPair) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_pointpair(lhs: Vec3, rhs: PointPair) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_line(lhs: Vec3, rhs: Line) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_line(lhs: Vec3, rhs: Line) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_line(lhs: Vec3, rhs: Line) -> FlatPoint {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_line(lhs: Vec3, rhs: Line) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_line(lhs: Vec3, rhs: Line) -> FlatPoint {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_line(lhs: Vec3, rhs: Line) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_line(lhs: Vec3, rhs: Line) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_circle(lhs: Vec3, rhs: Circle) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_circle(lhs: Vec3, rhs: Circle) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_circle(lhs: Vec3, rhs: Circle) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_circle(lhs: Vec3, rhs: Circle) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_circle(lhs: Vec3, rhs: Circle) -> PointPair {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_circle(lhs: Vec3, rhs: Circle) -> Sphere {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_circle(lhs: Vec3, rhs: Circle) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_plane(lhs: Vec3, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_plane(lhs: Vec3, rhs: Plane) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_plane(lhs: Vec3, rhs: Plane) -> Line {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_plane(lhs: Vec3, rhs: Plane) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_wedge_plane(lhs: Vec3, rhs: Plane) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_plane(lhs: Vec3, rhs: Plane) -> Line {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_plane(lhs: Vec3, rhs: Plane) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_plane(lhs: Vec3, rhs: Plane) -> PointPair {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_sphere(lhs: Vec3, rhs: Sphere) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_sphere(lhs: Vec3, rhs: Sphere) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_sphere(lhs: Vec3, rhs: Sphere) -> Circle {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_sphere(lhs: Vec3, rhs: Sphere) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_wedge_sphere(lhs: Vec3, rhs: Sphere) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_sphere(lhs: Vec3, rhs: Sphere) -> Circle {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_sphere(lhs: Vec3, rhs: Sphere) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_sphere(lhs: Vec3, rhs: Sphere) -> PointPair {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_rotor(lhs: Vec3, rhs: Rotor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_rotor(lhs: Vec3, rhs: Rotor) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_rotor(lhs: Vec3, rhs: Rotor) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_rotor(lhs: Vec3, rhs: Rotor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_rotor(lhs: Vec3, rhs: Rotor) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_right_contraction_rotor(lhs: Vec3, rhs: Rotor) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_rotor(lhs: Vec3, rhs: Rotor) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_rotor(lhs: Vec3, rhs: Rotor) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_translator(lhs: Vec3, rhs: Translator) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_translator(lhs: Vec3, rhs: Translator) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_translator(lhs: Vec3, rhs: Translator) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_translator(lhs: Vec3, rhs: Translator) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_translator(lhs: Vec3, rhs: Translator) -> Ei {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_right_contraction_translator(lhs: Vec3, rhs: Translator) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_translator(lhs: Vec3, rhs: Translator) -> Ei {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_translator(lhs: Vec3, rhs: Translator) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_versor(lhs: Vec3, rhs: Versor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_versor(lhs: Vec3, rhs: Versor) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_versor(lhs: Vec3, rhs: Versor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_versor(lhs: Vec3, rhs: Versor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_wedge_versor(lhs: Vec3, rhs: Versor) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_versor(lhs: Vec3, rhs: Versor) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_right_contraction_versor(lhs: Vec3, rhs: Versor) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_versor(lhs: Vec3, rhs: Versor) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_versor(lhs: Vec3, rhs: Versor) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_geometric_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_geometric_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_dot_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_wedge_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_wedge_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_left_contraction_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_right_contraction_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_scalar_product_multivector(lhs: Vec3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_commutator_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_vec3_anti_dot_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_rcompl(value: Point) -> Sphere {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_lcompl(value: Point) -> Sphere {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_rev(value: Point) -> Point {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_arev(value: Point) -> Point {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_involute(value: Point) -> Point {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_conjugate(value: Point) -> Point {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dual(value: Point) -> Sphere {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_vec3(lhs: Point, rhs: Vec3) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_vec3(lhs: Point, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_vec3(lhs: Point, rhs: Vec3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_vec3(lhs: Point, rhs: Vec3) -> PointPair {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_vec3(lhs: Point, rhs: Vec3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_right_contraction_vec3(lhs: Point, rhs: Vec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_scalar_product_vec3(lhs: Point, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_vec3(lhs: Point, rhs: Vec3) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_vec3(lhs: Point, rhs: Vec3) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_point(lhs: Point, rhs: Point) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_point(lhs: Point, rhs: Point) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_point(lhs: Point, rhs: Point) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_point(lhs: Point, rhs: Point) -> PointPair {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_point(lhs: Point, rhs: Point) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_right_contraction_point(lhs: Point, rhs: Point) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_scalar_product_point(lhs: Point, rhs: Point) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_point(lhs: Point, rhs: Point) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_point(lhs: Point, rhs: Point) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_flatpoint(lhs: Point, rhs: FlatPoint) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_flatpoint(lhs: Point, rhs: FlatPoint) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_flatpoint(lhs: Point, rhs: FlatPoint) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_flatpoint(lhs: Point, rhs: FlatPoint) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_flatpoint(lhs: Point, rhs: FlatPoint) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_flatpoint(lhs: Point, rhs: FlatPoint) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_flatpoint(lhs: Point, rhs: FlatPoint) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_pointpair(lhs: Point, rhs: PointPair) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_pointpair(lhs: Point, rhs: PointPair) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_pointpair(lhs: Point, rhs: PointPair) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_pointpair(lhs: Point, rhs: PointPair) -> Circle {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_pointpair(lhs: Point, rhs: PointPair) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_pointpair(lhs: Point, rhs: PointPair) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_pointpair(lhs: Point, rhs: PointPair) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_line(lhs: Point, rhs: Line) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_line(lhs: Point, rhs: Line) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_line(lhs: Point, rhs: Line) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_line(lhs: Point, rhs: Line) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_line(lhs: Point, rhs: Line) -> PointPair {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_line(lhs: Point, rhs: Line) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_line(lhs: Point, rhs: Line) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_circle(lhs: Point, rhs: Circle) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_circle(lhs: Point, rhs: Circle) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_circle(lhs: Point, rhs: Circle) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_circle(lhs: Point, rhs: Circle) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_circle(lhs: Point, rhs: Circle) -> PointPair {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_circle(lhs: Point, rhs: Circle) -> Sphere {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_circle(lhs: Point, rhs: Circle) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_plane(lhs: Point, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_plane(lhs: Point, rhs: Plane) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_plane(lhs: Point, rhs: Plane) -> Circle {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_plane(lhs: Point, rhs: Plane) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_wedge_plane(lhs: Point, rhs: Plane) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_plane(lhs: Point, rhs: Plane) -> Circle {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_plane(lhs: Point, rhs: Plane) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_plane(lhs: Point, rhs: Plane) -> PointPair {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_sphere(lhs: Point, rhs: Sphere) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_sphere(lhs: Point, rhs: Sphere) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_sphere(lhs: Point, rhs: Sphere) -> Circle {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_sphere(lhs: Point, rhs: Sphere) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_wedge_sphere(lhs: Point, rhs: Sphere) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_sphere(lhs: Point, rhs: Sphere) -> Circle {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_sphere(lhs: Point, rhs: Sphere) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_sphere(lhs: Point, rhs: Sphere) -> PointPair {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_rotor(lhs: Point, rhs: Rotor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_rotor(lhs: Point, rhs: Rotor) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_rotor(lhs: Point, rhs: Rotor) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_rotor(lhs: Point, rhs: Rotor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_rotor(lhs: Point, rhs: Rotor) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_right_contraction_rotor(lhs: Point, rhs: Rotor) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_rotor(lhs: Point, rhs: Rotor) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_rotor(lhs: Point, rhs: Rotor) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_translator(lhs: Point, rhs: Translator) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_translator(lhs: Point, rhs: Translator) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_translator(lhs: Point, rhs: Translator) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_translator(lhs: Point, rhs: Translator) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_translator(lhs: Point, rhs: Translator) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_right_contraction_translator(lhs: Point, rhs: Translator) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_translator(lhs: Point, rhs: Translator) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_translator(lhs: Point, rhs: Translator) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_versor(lhs: Point, rhs: Versor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_versor(lhs: Point, rhs: Versor) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_versor(lhs: Point, rhs: Versor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_versor(lhs: Point, rhs: Versor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_wedge_versor(lhs: Point, rhs: Versor) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_versor(lhs: Point, rhs: Versor) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_right_contraction_versor(lhs: Point, rhs: Versor) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_versor(lhs: Point, rhs: Versor) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_versor(lhs: Point, rhs: Versor) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_geometric_multivector(lhs: Point, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_geometric_multivector(lhs: Point, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_dot_multivector(lhs: Point, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_wedge_multivector(lhs: Point, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_wedge_multivector(lhs: Point, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_left_contraction_multivector(lhs: Point, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_right_contraction_multivector(lhs: Point, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_scalar_product_multivector(lhs: Point, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_commutator_multivector(lhs: Point, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_point_anti_dot_multivector(lhs: Point, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_rcompl(value: FlatPoint) -> Circle {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_lcompl(value: FlatPoint) -> Circle {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_rev(value: FlatPoint) -> FlatPoint {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_arev(value: FlatPoint) -> FlatPoint {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_involute(value: FlatPoint) -> FlatPoint {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_conjugate(value: FlatPoint) -> FlatPoint {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dual(value: FlatPoint) -> Circle {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_vec3(lhs: FlatPoint, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_vec3(lhs: FlatPoint, rhs: Vec3) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_vec3(lhs: FlatPoint, rhs: Vec3) -> Ei {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_wedge_vec3(lhs: FlatPoint, rhs: Vec3) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_right_contraction_vec3(lhs: FlatPoint, rhs: Vec3) -> Ei {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_vec3(lhs: FlatPoint, rhs: Vec3) -> Ei {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_vec3(lhs: FlatPoint, rhs: Vec3) -> E123i {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_point(lhs: FlatPoint, rhs: Point) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_point(lhs: FlatPoint, rhs: Point) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_point(lhs: FlatPoint, rhs: Point) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_wedge_point(lhs: FlatPoint, rhs: Point) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_right_contraction_point(lhs: FlatPoint, rhs: Point) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_point(lhs: FlatPoint, rhs: Point) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_point(lhs: FlatPoint, rhs: Point) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_flatpoint(lhs: FlatPoint, rhs: FlatPoint) -> Translator {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_flatpoint(lhs: FlatPoint, rhs: FlatPoint) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_flatpoint(lhs: FlatPoint, rhs: FlatPoint) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_left_contraction_flatpoint(lhs: FlatPoint, rhs: FlatPoint) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_right_contraction_flatpoint(lhs: FlatPoint, rhs: FlatPoint) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_scalar_product_flatpoint(lhs: FlatPoint, rhs: FlatPoint) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_flatpoint(lhs: FlatPoint, rhs: FlatPoint) -> FlatPoint {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_flatpoint(lhs: FlatPoint, rhs: FlatPoint) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_pointpair(lhs: FlatPoint, rhs: PointPair) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_pointpair(lhs: FlatPoint, rhs: PointPair) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_pointpair(lhs: FlatPoint, rhs: PointPair) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_wedge_pointpair(lhs: FlatPoint, rhs: PointPair) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_left_contraction_pointpair(lhs: FlatPoint, rhs: PointPair) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_right_contraction_pointpair(lhs: FlatPoint, rhs: PointPair) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_scalar_product_pointpair(lhs: FlatPoint, rhs: PointPair) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_pointpair(lhs: FlatPoint, rhs: PointPair) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_pointpair(lhs: FlatPoint, rhs: PointPair) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_line(lhs: FlatPoint, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_line(lhs: FlatPoint, rhs: Line) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_line(lhs: FlatPoint, rhs: Line) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_left_contraction_line(lhs: FlatPoint, rhs: Line) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_line(lhs: FlatPoint, rhs: Line) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_line(lhs: FlatPoint, rhs: Line) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_circle(lhs: FlatPoint, rhs: Circle) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_circle(lhs: FlatPoint, rhs: Circle) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_circle(lhs: FlatPoint, rhs: Circle) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_wedge_circle(lhs: FlatPoint, rhs: Circle) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_wedge_circle(lhs: FlatPoint, rhs: Circle) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_left_contraction_circle(lhs: FlatPoint, rhs: Circle) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_circle(lhs: FlatPoint, rhs: Circle) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_circle(lhs: FlatPoint, rhs: Circle) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_plane(lhs: FlatPoint, rhs: Plane) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_plane(lhs: FlatPoint, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_plane(lhs: FlatPoint, rhs: Plane) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_wedge_plane(lhs: FlatPoint, rhs: Plane) -> Ei {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_left_contraction_plane(lhs: FlatPoint, rhs: Plane) -> PointPair {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_plane(lhs: FlatPoint, rhs: Plane) -> E123i {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_plane(lhs: FlatPoint, rhs: Plane) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_sphere(lhs: FlatPoint, rhs: Sphere) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_sphere(lhs: FlatPoint, rhs: Sphere) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_sphere(lhs: FlatPoint, rhs: Sphere) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_wedge_sphere(lhs: FlatPoint, rhs: Sphere) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_left_contraction_sphere(lhs: FlatPoint, rhs: Sphere) -> PointPair {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_sphere(lhs: FlatPoint, rhs: Sphere) -> Sphere {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_sphere(lhs: FlatPoint, rhs: Sphere) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_rotor(lhs: FlatPoint, rhs: Rotor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_rotor(lhs: FlatPoint, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_rotor(lhs: FlatPoint, rhs: Rotor) -> FlatPoint {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_wedge_rotor(lhs: FlatPoint, rhs: Rotor) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_right_contraction_rotor(lhs: FlatPoint, rhs: Rotor) -> FlatPoint {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_rotor(lhs: FlatPoint, rhs: Rotor) -> FlatPoint {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_rotor(lhs: FlatPoint, rhs: Rotor) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_translator(lhs: FlatPoint, rhs: Translator) -> FlatPoint {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_translator(lhs: FlatPoint, rhs: Translator) -> Circle {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_translator(lhs: FlatPoint, rhs: Translator) -> FlatPoint {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_wedge_translator(lhs: FlatPoint, rhs: Translator) -> FlatPoint {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_right_contraction_translator(lhs: FlatPoint, rhs: Translator) -> FlatPoint {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_translator(lhs: FlatPoint, rhs: Translator) -> FlatPoint {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_translator(lhs: FlatPoint, rhs: Translator) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_versor(lhs: FlatPoint, rhs: Versor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_versor(lhs: FlatPoint, rhs: Versor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_versor(lhs: FlatPoint, rhs: Versor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_wedge_versor(lhs: FlatPoint, rhs: Versor) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_wedge_versor(lhs: FlatPoint, rhs: Versor) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_left_contraction_versor(lhs: FlatPoint, rhs: Versor) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_right_contraction_versor(lhs: FlatPoint, rhs: Versor) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_scalar_product_versor(lhs: FlatPoint, rhs: Versor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_versor(lhs: FlatPoint, rhs: Versor) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_versor(lhs: FlatPoint, rhs: Versor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_geometric_multivector(lhs: FlatPoint, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_geometric_multivector(lhs: FlatPoint, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_dot_multivector(lhs: FlatPoint, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_wedge_multivector(lhs: FlatPoint, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_wedge_multivector(lhs: FlatPoint, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_left_contraction_multivector(lhs: FlatPoint, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_right_contraction_multivector(lhs: FlatPoint, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_scalar_product_multivector(lhs: FlatPoint, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_commutator_multivector(lhs: FlatPoint, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_flatpoint_anti_dot_multivector(lhs: FlatPoint, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_rcompl(value: PointPair) -> Circle {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_lcompl(value: PointPair) -> Circle {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_rev(value: PointPair) -> PointPair {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_arev(value: PointPair) -> PointPair {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_involute(value: PointPair) -> PointPair {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_conjugate(value: PointPair) -> PointPair {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dual(value: PointPair) -> Circle {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_vec3(lhs: PointPair, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_vec3(lhs: PointPair, rhs: Vec3) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_vec3(lhs: PointPair, rhs: Vec3) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_wedge_vec3(lhs: PointPair, rhs: Vec3) -> Circle {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_right_contraction_vec3(lhs: PointPair, rhs: Vec3) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_vec3(lhs: PointPair, rhs: Vec3) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_vec3(lhs: PointPair, rhs: Vec3) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_point(lhs: PointPair, rhs: Point) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_point(lhs: PointPair, rhs: Point) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_point(lhs: PointPair, rhs: Point) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_wedge_point(lhs: PointPair, rhs: Point) -> Circle {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_right_contraction_point(lhs: PointPair, rhs: Point) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_point(lhs: PointPair, rhs: Point) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_point(lhs: PointPair, rhs: Point) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_flatpoint(lhs: PointPair, rhs: FlatPoint) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_flatpoint(lhs: PointPair, rhs: FlatPoint) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_flatpoint(lhs: PointPair, rhs: FlatPoint) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_wedge_flatpoint(lhs: PointPair, rhs: FlatPoint) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_left_contraction_flatpoint(lhs: PointPair, rhs: FlatPoint) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_right_contraction_flatpoint(lhs: PointPair, rhs: FlatPoint) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_scalar_product_flatpoint(lhs: PointPair, rhs: FlatPoint) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_flatpoint(lhs: PointPair, rhs: FlatPoint) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_flatpoint(lhs: PointPair, rhs: FlatPoint) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_pointpair(lhs: PointPair, rhs: PointPair) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_pointpair(lhs: PointPair, rhs: PointPair) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_pointpair(lhs: PointPair, rhs: PointPair) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_wedge_pointpair(lhs: PointPair, rhs: PointPair) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_left_contraction_pointpair(lhs: PointPair, rhs: PointPair) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_right_contraction_pointpair(lhs: PointPair, rhs: PointPair) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_scalar_product_pointpair(lhs: PointPair, rhs: PointPair) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_pointpair(lhs: PointPair, rhs: PointPair) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_pointpair(lhs: PointPair, rhs: PointPair) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_line(lhs: PointPair, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_line(lhs: PointPair, rhs: Line) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_line(lhs: PointPair, rhs: Line) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_wedge_line(lhs: PointPair, rhs: Line) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_wedge_line(lhs: PointPair, rhs: Line) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_left_contraction_line(lhs: PointPair, rhs: Line) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_line(lhs: PointPair, rhs: Line) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_line(lhs: PointPair, rhs: Line) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_circle(lhs: PointPair, rhs: Circle) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_circle(lhs: PointPair, rhs: Circle) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_circle(lhs: PointPair, rhs: Circle) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_wedge_circle(lhs: PointPair, rhs: Circle) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_wedge_circle(lhs: PointPair, rhs: Circle) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_left_contraction_circle(lhs: PointPair, rhs: Circle) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_circle(lhs: PointPair, rhs: Circle) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_circle(lhs: PointPair, rhs: Circle) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_plane(lhs: PointPair, rhs: Plane) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_plane(lhs: PointPair, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_plane(lhs: PointPair, rhs: Plane) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_wedge_plane(lhs: PointPair, rhs: Plane) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_left_contraction_plane(lhs: PointPair, rhs: Plane) -> PointPair {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_plane(lhs: PointPair, rhs: Plane) -> Sphere {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_plane(lhs: PointPair, rhs: Plane) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_sphere(lhs: PointPair, rhs: Sphere) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_sphere(lhs: PointPair, rhs: Sphere) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_sphere(lhs: PointPair, rhs: Sphere) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_wedge_sphere(lhs: PointPair, rhs: Sphere) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_left_contraction_sphere(lhs: PointPair, rhs: Sphere) -> PointPair {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_sphere(lhs: PointPair, rhs: Sphere) -> Sphere {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_sphere(lhs: PointPair, rhs: Sphere) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_rotor(lhs: PointPair, rhs: Rotor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_rotor(lhs: PointPair, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_rotor(lhs: PointPair, rhs: Rotor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_wedge_rotor(lhs: PointPair, rhs: Rotor) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_left_contraction_rotor(lhs: PointPair, rhs: Rotor) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_right_contraction_rotor(lhs: PointPair, rhs: Rotor) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_scalar_product_rotor(lhs: PointPair, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_rotor(lhs: PointPair, rhs: Rotor) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_rotor(lhs: PointPair, rhs: Rotor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_translator(lhs: PointPair, rhs: Translator) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_translator(lhs: PointPair, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_translator(lhs: PointPair, rhs: Translator) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_wedge_translator(lhs: PointPair, rhs: Translator) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_left_contraction_translator(lhs: PointPair, rhs: Translator) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_right_contraction_translator(lhs: PointPair, rhs: Translator) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_scalar_product_translator(lhs: PointPair, rhs: Translator) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_translator(lhs: PointPair, rhs: Translator) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_translator(lhs: PointPair, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_versor(lhs: PointPair, rhs: Versor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_versor(lhs: PointPair, rhs: Versor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_versor(lhs: PointPair, rhs: Versor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_wedge_versor(lhs: PointPair, rhs: Versor) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_wedge_versor(lhs: PointPair, rhs: Versor) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_left_contraction_versor(lhs: PointPair, rhs: Versor) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_right_contraction_versor(lhs: PointPair, rhs: Versor) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_scalar_product_versor(lhs: PointPair, rhs: Versor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_versor(lhs: PointPair, rhs: Versor) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_versor(lhs: PointPair, rhs: Versor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_geometric_multivector(lhs: PointPair, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_geometric_multivector(lhs: PointPair, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_dot_multivector(lhs: PointPair, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_wedge_multivector(lhs: PointPair, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_wedge_multivector(lhs: PointPair, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_left_contraction_multivector(lhs: PointPair, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_right_contraction_multivector(lhs: PointPair, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_scalar_product_multivector(lhs: PointPair, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_commutator_multivector(lhs: PointPair, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_pointpair_anti_dot_multivector(lhs: PointPair, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_rcompl(value: Line) -> PointPair {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_lcompl(value: Line) -> PointPair {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_rev(value: Line) -> Line {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_arev(value: Line) -> Line {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_involute(value: Line) -> Line {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_conjugate(value: Line) -> Line {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dual(value: Line) -> PointPair {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_vec3(lhs: Line, rhs: Vec3) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_vec3(lhs: Line, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_vec3(lhs: Line, rhs: Vec3) -> FlatPoint {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_wedge_vec3(lhs: Line, rhs: Vec3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_right_contraction_vec3(lhs: Line, rhs: Vec3) -> FlatPoint {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_vec3(lhs: Line, rhs: Vec3) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_vec3(lhs: Line, rhs: Vec3) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_point(lhs: Line, rhs: Point) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_point(lhs: Line, rhs: Point) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_point(lhs: Line, rhs: Point) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_wedge_point(lhs: Line, rhs: Point) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_right_contraction_point(lhs: Line, rhs: Point) -> PointPair {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_point(lhs: Line, rhs: Point) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_point(lhs: Line, rhs: Point) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_flatpoint(lhs: Line, rhs: FlatPoint) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_flatpoint(lhs: Line, rhs: FlatPoint) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_flatpoint(lhs: Line, rhs: FlatPoint) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_right_contraction_flatpoint(lhs: Line, rhs: FlatPoint) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_flatpoint(lhs: Line, rhs: FlatPoint) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_flatpoint(lhs: Line, rhs: FlatPoint) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_pointpair(lhs: Line, rhs: PointPair) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_pointpair(lhs: Line, rhs: PointPair) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_pointpair(lhs: Line, rhs: PointPair) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_wedge_pointpair(lhs: Line, rhs: PointPair) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_wedge_pointpair(lhs: Line, rhs: PointPair) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_right_contraction_pointpair(lhs: Line, rhs: PointPair) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_pointpair(lhs: Line, rhs: PointPair) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_pointpair(lhs: Line, rhs: PointPair) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_line(lhs: Line, rhs: Line) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_line(lhs: Line, rhs: Line) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_line(lhs: Line, rhs: Line) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_wedge_line(lhs: Line, rhs: Line) -> Ei {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_left_contraction_line(lhs: Line, rhs: Line) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_right_contraction_line(lhs: Line, rhs: Line) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_scalar_product_line(lhs: Line, rhs: Line) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_line(lhs: Line, rhs: Line) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_line(lhs: Line, rhs: Line) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_circle(lhs: Line, rhs: Circle) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_circle(lhs: Line, rhs: Circle) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_circle(lhs: Line, rhs: Circle) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_wedge_circle(lhs: Line, rhs: Circle) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_left_contraction_circle(lhs: Line, rhs: Circle) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_right_contraction_circle(lhs: Line, rhs: Circle) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_scalar_product_circle(lhs: Line, rhs: Circle) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_circle(lhs: Line, rhs: Circle) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_circle(lhs: Line, rhs: Circle) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_plane(lhs: Line, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_plane(lhs: Line, rhs: Plane) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_plane(lhs: Line, rhs: Plane) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_wedge_plane(lhs: Line, rhs: Plane) -> FlatPoint {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_left_contraction_plane(lhs: Line, rhs: Plane) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_plane(lhs: Line, rhs: Plane) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_plane(lhs: Line, rhs: Plane) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_sphere(lhs: Line, rhs: Sphere) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_sphere(lhs: Line, rhs: Sphere) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_sphere(lhs: Line, rhs: Sphere) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_wedge_sphere(lhs: Line, rhs: Sphere) -> PointPair {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_left_contraction_sphere(lhs: Line, rhs: Sphere) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_sphere(lhs: Line, rhs: Sphere) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_sphere(lhs: Line, rhs: Sphere) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_rotor(lhs: Line, rhs: Rotor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_rotor(lhs: Line, rhs: Rotor) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_rotor(lhs: Line, rhs: Rotor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_wedge_rotor(lhs: Line, rhs: Rotor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_wedge_rotor(lhs: Line, rhs: Rotor) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_right_contraction_rotor(lhs: Line, rhs: Rotor) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_rotor(lhs: Line, rhs: Rotor) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_rotor(lhs: Line, rhs: Rotor) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_translator(lhs: Line, rhs: Translator) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_translator(lhs: Line, rhs: Translator) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_translator(lhs: Line, rhs: Translator) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_wedge_translator(lhs: Line, rhs: Translator) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_right_contraction_translator(lhs: Line, rhs: Translator) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_translator(lhs: Line, rhs: Translator) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_translator(lhs: Line, rhs: Translator) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_versor(lhs: Line, rhs: Versor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_versor(lhs: Line, rhs: Versor) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_versor(lhs: Line, rhs: Versor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_wedge_versor(lhs: Line, rhs: Versor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_wedge_versor(lhs: Line, rhs: Versor) -> Versor {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_left_contraction_versor(lhs: Line, rhs: Versor) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_right_contraction_versor(lhs: Line, rhs: Versor) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_versor(lhs: Line, rhs: Versor) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_versor(lhs: Line, rhs: Versor) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_geometric_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_geometric_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_dot_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_wedge_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_wedge_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_left_contraction_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_right_contraction_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_scalar_product_multivector(lhs: Line, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_commutator_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_line_anti_dot_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_rcompl(value: Circle) -> PointPair {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_lcompl(value: Circle) -> PointPair {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_rev(value: Circle) -> Circle {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_arev(value: Circle) -> Circle {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_involute(value: Circle) -> Circle {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_conjugate(value: Circle) -> Circle {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dual(value: Circle) -> PointPair {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_vec3(lhs: Circle, rhs: Vec3) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_vec3(lhs: Circle, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_vec3(lhs: Circle, rhs: Vec3) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_wedge_vec3(lhs: Circle, rhs: Vec3) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_right_contraction_vec3(lhs: Circle, rhs: Vec3) -> PointPair {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_vec3(lhs: Circle, rhs: Vec3) -> Sphere {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_vec3(lhs: Circle, rhs: Vec3) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_point(lhs: Circle, rhs: Point) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_point(lhs: Circle, rhs: Point) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_point(lhs: Circle, rhs: Point) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_wedge_point(lhs: Circle, rhs: Point) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_right_contraction_point(lhs: Circle, rhs: Point) -> PointPair {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_point(lhs: Circle, rhs: Point) -> Sphere {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_point(lhs: Circle, rhs: Point) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_flatpoint(lhs: Circle, rhs: FlatPoint) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_flatpoint(lhs: Circle, rhs: FlatPoint) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_flatpoint(lhs: Circle, rhs: FlatPoint) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_wedge_flatpoint(lhs: Circle, rhs: FlatPoint) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_wedge_flatpoint(lhs: Circle, rhs: FlatPoint) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_right_contraction_flatpoint(lhs: Circle, rhs: FlatPoint) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_flatpoint(lhs: Circle, rhs: FlatPoint) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_flatpoint(lhs: Circle, rhs: FlatPoint) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_pointpair(lhs: Circle, rhs: PointPair) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_pointpair(lhs: Circle, rhs: PointPair) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_pointpair(lhs: Circle, rhs: PointPair) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_wedge_pointpair(lhs: Circle, rhs: PointPair) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_wedge_pointpair(lhs: Circle, rhs: PointPair) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_right_contraction_pointpair(lhs: Circle, rhs: PointPair) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_pointpair(lhs: Circle, rhs: PointPair) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_pointpair(lhs: Circle, rhs: PointPair) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_line(lhs: Circle, rhs: Line) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_line(lhs: Circle, rhs: Line) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_line(lhs: Circle, rhs: Line) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_wedge_line(lhs: Circle, rhs: Line) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_left_contraction_line(lhs: Circle, rhs: Line) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_right_contraction_line(lhs: Circle, rhs: Line) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_scalar_product_line(lhs: Circle, rhs: Line) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_line(lhs: Circle, rhs: Line) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_line(lhs: Circle, rhs: Line) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_circle(lhs: Circle, rhs: Circle) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_circle(lhs: Circle, rhs: Circle) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_circle(lhs: Circle, rhs: Circle) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_wedge_circle(lhs: Circle, rhs: Circle) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_left_contraction_circle(lhs: Circle, rhs: Circle) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_right_contraction_circle(lhs: Circle, rhs: Circle) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_scalar_product_circle(lhs: Circle, rhs: Circle) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_circle(lhs: Circle, rhs: Circle) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_circle(lhs: Circle, rhs: Circle) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_plane(lhs: Circle, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_plane(lhs: Circle, rhs: Plane) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_plane(lhs: Circle, rhs: Plane) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_wedge_plane(lhs: Circle, rhs: Plane) -> PointPair {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_left_contraction_plane(lhs: Circle, rhs: Plane) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_plane(lhs: Circle, rhs: Plane) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_plane(lhs: Circle, rhs: Plane) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_sphere(lhs: Circle, rhs: Sphere) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_sphere(lhs: Circle, rhs: Sphere) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_sphere(lhs: Circle, rhs: Sphere) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_wedge_sphere(lhs: Circle, rhs: Sphere) -> PointPair {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_left_contraction_sphere(lhs: Circle, rhs: Sphere) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_sphere(lhs: Circle, rhs: Sphere) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_sphere(lhs: Circle, rhs: Sphere) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_rotor(lhs: Circle, rhs: Rotor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_rotor(lhs: Circle, rhs: Rotor) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_rotor(lhs: Circle, rhs: Rotor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_wedge_rotor(lhs: Circle, rhs: Rotor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_wedge_rotor(lhs: Circle, rhs: Rotor) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_right_contraction_rotor(lhs: Circle, rhs: Rotor) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_rotor(lhs: Circle, rhs: Rotor) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_rotor(lhs: Circle, rhs: Rotor) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_translator(lhs: Circle, rhs: Translator) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_translator(lhs: Circle, rhs: Translator) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_translator(lhs: Circle, rhs: Translator) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_wedge_translator(lhs: Circle, rhs: Translator) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_wedge_translator(lhs: Circle, rhs: Translator) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_right_contraction_translator(lhs: Circle, rhs: Translator) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_translator(lhs: Circle, rhs: Translator) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_translator(lhs: Circle, rhs: Translator) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_versor(lhs: Circle, rhs: Versor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_versor(lhs: Circle, rhs: Versor) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_versor(lhs: Circle, rhs: Versor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_wedge_versor(lhs: Circle, rhs: Versor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_wedge_versor(lhs: Circle, rhs: Versor) -> Versor {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_left_contraction_versor(lhs: Circle, rhs: Versor) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_right_contraction_versor(lhs: Circle, rhs: Versor) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_versor(lhs: Circle, rhs: Versor) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_versor(lhs: Circle, rhs: Versor) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_geometric_multivector(lhs: Circle, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_geometric_multivector(lhs: Circle, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_dot_multivector(lhs: Circle, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_wedge_multivector(lhs: Circle, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_wedge_multivector(lhs: Circle, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_left_contraction_multivector(lhs: Circle, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_right_contraction_multivector(lhs: Circle, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_scalar_product_multivector(lhs: Circle, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_commutator_multivector(lhs: Circle, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_circle_anti_dot_multivector(lhs: Circle, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_rcompl(value: Plane) -> Point {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_lcompl(value: Plane) -> Point {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_rev(value: Plane) -> Plane {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_arev(value: Plane) -> Plane {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_involute(value: Plane) -> Plane {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_conjugate(value: Plane) -> Plane {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dual(value: Plane) -> Point {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_vec3(lhs: Plane, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_vec3(lhs: Plane, rhs: Vec3) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_vec3(lhs: Plane, rhs: Vec3) -> Line {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_wedge_vec3(lhs: Plane, rhs: Vec3) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_vec3(lhs: Plane, rhs: Vec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_vec3(lhs: Plane, rhs: Vec3) -> Line {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_vec3(lhs: Plane, rhs: Vec3) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_vec3(lhs: Plane, rhs: Vec3) -> PointPair {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_point(lhs: Plane, rhs: Point) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_point(lhs: Plane, rhs: Point) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_point(lhs: Plane, rhs: Point) -> Circle {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_wedge_point(lhs: Plane, rhs: Point) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_point(lhs: Plane, rhs: Point) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_point(lhs: Plane, rhs: Point) -> Circle {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_point(lhs: Plane, rhs: Point) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_point(lhs: Plane, rhs: Point) -> PointPair {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_flatpoint(lhs: Plane, rhs: FlatPoint) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_flatpoint(lhs: Plane, rhs: FlatPoint) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_flatpoint(lhs: Plane, rhs: FlatPoint) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_flatpoint(lhs: Plane, rhs: FlatPoint) -> Ei {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_flatpoint(lhs: Plane, rhs: FlatPoint) -> PointPair {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_flatpoint(lhs: Plane, rhs: FlatPoint) -> E123i {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_flatpoint(lhs: Plane, rhs: FlatPoint) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_pointpair(lhs: Plane, rhs: PointPair) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_pointpair(lhs: Plane, rhs: PointPair) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_pointpair(lhs: Plane, rhs: PointPair) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_pointpair(lhs: Plane, rhs: PointPair) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_pointpair(lhs: Plane, rhs: PointPair) -> PointPair {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_pointpair(lhs: Plane, rhs: PointPair) -> Sphere {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_pointpair(lhs: Plane, rhs: PointPair) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_line(lhs: Plane, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_line(lhs: Plane, rhs: Line) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_line(lhs: Plane, rhs: Line) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_line(lhs: Plane, rhs: Line) -> FlatPoint {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_line(lhs: Plane, rhs: Line) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_line(lhs: Plane, rhs: Line) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_line(lhs: Plane, rhs: Line) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_circle(lhs: Plane, rhs: Circle) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_circle(lhs: Plane, rhs: Circle) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_circle(lhs: Plane, rhs: Circle) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_circle(lhs: Plane, rhs: Circle) -> PointPair {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_circle(lhs: Plane, rhs: Circle) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_circle(lhs: Plane, rhs: Circle) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_circle(lhs: Plane, rhs: Circle) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_plane(lhs: Plane, rhs: Plane) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_plane(lhs: Plane, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_plane(lhs: Plane, rhs: Plane) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_plane(lhs: Plane, rhs: Plane) -> Line {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_left_contraction_plane(lhs: Plane, rhs: Plane) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_plane(lhs: Plane, rhs: Plane) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_scalar_product_plane(lhs: Plane, rhs: Plane) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_plane(lhs: Plane, rhs: Plane) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_plane(lhs: Plane, rhs: Plane) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_sphere(lhs: Plane, rhs: Sphere) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_sphere(lhs: Plane, rhs: Sphere) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_sphere(lhs: Plane, rhs: Sphere) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_sphere(lhs: Plane, rhs: Sphere) -> Circle {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_left_contraction_sphere(lhs: Plane, rhs: Sphere) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_sphere(lhs: Plane, rhs: Sphere) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_scalar_product_sphere(lhs: Plane, rhs: Sphere) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_sphere(lhs: Plane, rhs: Sphere) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_sphere(lhs: Plane, rhs: Sphere) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_rotor(lhs: Plane, rhs: Rotor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_rotor(lhs: Plane, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_rotor(lhs: Plane, rhs: Rotor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_wedge_rotor(lhs: Plane, rhs: Rotor) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_rotor(lhs: Plane, rhs: Rotor) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_rotor(lhs: Plane, rhs: Rotor) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_rotor(lhs: Plane, rhs: Rotor) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_rotor(lhs: Plane, rhs: Rotor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_translator(lhs: Plane, rhs: Translator) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_translator(lhs: Plane, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_translator(lhs: Plane, rhs: Translator) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_wedge_translator(lhs: Plane, rhs: Translator) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_translator(lhs: Plane, rhs: Translator) -> Ei {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_translator(lhs: Plane, rhs: Translator) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_translator(lhs: Plane, rhs: Translator) -> E123i {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_translator(lhs: Plane, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_versor(lhs: Plane, rhs: Versor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_versor(lhs: Plane, rhs: Versor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_versor(lhs: Plane, rhs: Versor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_wedge_versor(lhs: Plane, rhs: Versor) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_versor(lhs: Plane, rhs: Versor) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_left_contraction_versor(lhs: Plane, rhs: Versor) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_versor(lhs: Plane, rhs: Versor) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_scalar_product_versor(lhs: Plane, rhs: Versor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_versor(lhs: Plane, rhs: Versor) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_versor(lhs: Plane, rhs: Versor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_geometric_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_geometric_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_dot_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_wedge_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_wedge_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_left_contraction_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_right_contraction_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_scalar_product_multivector(lhs: Plane, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_commutator_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_plane_anti_dot_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_rcompl(value: Sphere) -> Point {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_lcompl(value: Sphere) -> Point {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_rev(value: Sphere) -> Sphere {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_arev(value: Sphere) -> Sphere {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_involute(value: Sphere) -> Sphere {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_conjugate(value: Sphere) -> Sphere {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dual(value: Sphere) -> Point {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_vec3(lhs: Sphere, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_vec3(lhs: Sphere, rhs: Vec3) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_vec3(lhs: Sphere, rhs: Vec3) -> Circle {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_wedge_vec3(lhs: Sphere, rhs: Vec3) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_vec3(lhs: Sphere, rhs: Vec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_vec3(lhs: Sphere, rhs: Vec3) -> Circle {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_vec3(lhs: Sphere, rhs: Vec3) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_vec3(lhs: Sphere, rhs: Vec3) -> PointPair {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_point(lhs: Sphere, rhs: Point) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_point(lhs: Sphere, rhs: Point) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_point(lhs: Sphere, rhs: Point) -> Circle {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_wedge_point(lhs: Sphere, rhs: Point) -> E123oi {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_point(lhs: Sphere, rhs: Point) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_point(lhs: Sphere, rhs: Point) -> Circle {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_point(lhs: Sphere, rhs: Point) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_point(lhs: Sphere, rhs: Point) -> PointPair {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_flatpoint(lhs: Sphere, rhs: FlatPoint) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_flatpoint(lhs: Sphere, rhs: FlatPoint) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_flatpoint(lhs: Sphere, rhs: FlatPoint) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_flatpoint(lhs: Sphere, rhs: FlatPoint) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_flatpoint(lhs: Sphere, rhs: FlatPoint) -> PointPair {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_flatpoint(lhs: Sphere, rhs: FlatPoint) -> Sphere {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_flatpoint(lhs: Sphere, rhs: FlatPoint) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_pointpair(lhs: Sphere, rhs: PointPair) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_pointpair(lhs: Sphere, rhs: PointPair) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_pointpair(lhs: Sphere, rhs: PointPair) -> PointPair {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_pointpair(lhs: Sphere, rhs: PointPair) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_pointpair(lhs: Sphere, rhs: PointPair) -> PointPair {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_pointpair(lhs: Sphere, rhs: PointPair) -> Sphere {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_pointpair(lhs: Sphere, rhs: PointPair) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_line(lhs: Sphere, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_line(lhs: Sphere, rhs: Line) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_line(lhs: Sphere, rhs: Line) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_line(lhs: Sphere, rhs: Line) -> PointPair {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_line(lhs: Sphere, rhs: Line) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_line(lhs: Sphere, rhs: Line) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_line(lhs: Sphere, rhs: Line) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_circle(lhs: Sphere, rhs: Circle) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_circle(lhs: Sphere, rhs: Circle) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_circle(lhs: Sphere, rhs: Circle) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_circle(lhs: Sphere, rhs: Circle) -> PointPair {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_circle(lhs: Sphere, rhs: Circle) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_circle(lhs: Sphere, rhs: Circle) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_circle(lhs: Sphere, rhs: Circle) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_plane(lhs: Sphere, rhs: Plane) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_plane(lhs: Sphere, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_plane(lhs: Sphere, rhs: Plane) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_plane(lhs: Sphere, rhs: Plane) -> Circle {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_left_contraction_plane(lhs: Sphere, rhs: Plane) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_plane(lhs: Sphere, rhs: Plane) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_scalar_product_plane(lhs: Sphere, rhs: Plane) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_plane(lhs: Sphere, rhs: Plane) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_plane(lhs: Sphere, rhs: Plane) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_sphere(lhs: Sphere, rhs: Sphere) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_sphere(lhs: Sphere, rhs: Sphere) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_sphere(lhs: Sphere, rhs: Sphere) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_sphere(lhs: Sphere, rhs: Sphere) -> Circle {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_left_contraction_sphere(lhs: Sphere, rhs: Sphere) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_sphere(lhs: Sphere, rhs: Sphere) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_scalar_product_sphere(lhs: Sphere, rhs: Sphere) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_sphere(lhs: Sphere, rhs: Sphere) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_sphere(lhs: Sphere, rhs: Sphere) -> E123oi {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_rotor(lhs: Sphere, rhs: Rotor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_rotor(lhs: Sphere, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_rotor(lhs: Sphere, rhs: Rotor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_wedge_rotor(lhs: Sphere, rhs: Rotor) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_rotor(lhs: Sphere, rhs: Rotor) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_rotor(lhs: Sphere, rhs: Rotor) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_rotor(lhs: Sphere, rhs: Rotor) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_rotor(lhs: Sphere, rhs: Rotor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_translator(lhs: Sphere, rhs: Translator) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_translator(lhs: Sphere, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_translator(lhs: Sphere, rhs: Translator) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_wedge_translator(lhs: Sphere, rhs: Translator) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_translator(lhs: Sphere, rhs: Translator) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_translator(lhs: Sphere, rhs: Translator) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_translator(lhs: Sphere, rhs: Translator) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_translator(lhs: Sphere, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_versor(lhs: Sphere, rhs: Versor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_versor(lhs: Sphere, rhs: Versor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_versor(lhs: Sphere, rhs: Versor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_wedge_versor(lhs: Sphere, rhs: Versor) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_versor(lhs: Sphere, rhs: Versor) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_left_contraction_versor(lhs: Sphere, rhs: Versor) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_versor(lhs: Sphere, rhs: Versor) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_scalar_product_versor(lhs: Sphere, rhs: Versor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_versor(lhs: Sphere, rhs: Versor) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_versor(lhs: Sphere, rhs: Versor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_geometric_multivector(lhs: Sphere, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_geometric_multivector(lhs: Sphere, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_dot_multivector(lhs: Sphere, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_wedge_multivector(lhs: Sphere, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_wedge_multivector(lhs: Sphere, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_left_contraction_multivector(lhs: Sphere, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_right_contraction_multivector(lhs: Sphere, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_scalar_product_multivector(lhs: Sphere, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_commutator_multivector(lhs: Sphere, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_sphere_anti_dot_multivector(lhs: Sphere, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_rcompl(value: Rotor) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_lcompl(value: Rotor) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_rev(value: Rotor) -> Rotor {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_arev(value: Rotor) -> Rotor {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_involute(value: Rotor) -> Rotor {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_conjugate(value: Rotor) -> Rotor {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dual(value: Rotor) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_vec3(lhs: Rotor, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_vec3(lhs: Rotor, rhs: Vec3) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_vec3(lhs: Rotor, rhs: Vec3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_vec3(lhs: Rotor, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_vec3(lhs: Rotor, rhs: Vec3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_right_contraction_vec3(lhs: Rotor, rhs: Vec3) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_vec3(lhs: Rotor, rhs: Vec3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_vec3(lhs: Rotor, rhs: Vec3) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_point(lhs: Rotor, rhs: Point) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_point(lhs: Rotor, rhs: Point) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_point(lhs: Rotor, rhs: Point) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_point(lhs: Rotor, rhs: Point) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_point(lhs: Rotor, rhs: Point) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_right_contraction_point(lhs: Rotor, rhs: Point) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_point(lhs: Rotor, rhs: Point) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_point(lhs: Rotor, rhs: Point) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_flatpoint(lhs: Rotor, rhs: FlatPoint) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_flatpoint(lhs: Rotor, rhs: FlatPoint) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_flatpoint(lhs: Rotor, rhs: FlatPoint) -> FlatPoint {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_flatpoint(lhs: Rotor, rhs: FlatPoint) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_flatpoint(lhs: Rotor, rhs: FlatPoint) -> FlatPoint {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_flatpoint(lhs: Rotor, rhs: FlatPoint) -> FlatPoint {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_flatpoint(lhs: Rotor, rhs: FlatPoint) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_pointpair(lhs: Rotor, rhs: PointPair) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_pointpair(lhs: Rotor, rhs: PointPair) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_pointpair(lhs: Rotor, rhs: PointPair) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_pointpair(lhs: Rotor, rhs: PointPair) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_pointpair(lhs: Rotor, rhs: PointPair) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_right_contraction_pointpair(lhs: Rotor, rhs: PointPair) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_scalar_product_pointpair(lhs: Rotor, rhs: PointPair) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_pointpair(lhs: Rotor, rhs: PointPair) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_pointpair(lhs: Rotor, rhs: PointPair) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_line(lhs: Rotor, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_line(lhs: Rotor, rhs: Line) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_line(lhs: Rotor, rhs: Line) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_line(lhs: Rotor, rhs: Line) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_wedge_line(lhs: Rotor, rhs: Line) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_line(lhs: Rotor, rhs: Line) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_line(lhs: Rotor, rhs: Line) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_line(lhs: Rotor, rhs: Line) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_circle(lhs: Rotor, rhs: Circle) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_circle(lhs: Rotor, rhs: Circle) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_circle(lhs: Rotor, rhs: Circle) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_circle(lhs: Rotor, rhs: Circle) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_wedge_circle(lhs: Rotor, rhs: Circle) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_circle(lhs: Rotor, rhs: Circle) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_circle(lhs: Rotor, rhs: Circle) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_circle(lhs: Rotor, rhs: Circle) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_plane(lhs: Rotor, rhs: Plane) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_plane(lhs: Rotor, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_plane(lhs: Rotor, rhs: Plane) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_plane(lhs: Rotor, rhs: Plane) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_wedge_plane(lhs: Rotor, rhs: Plane) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_plane(lhs: Rotor, rhs: Plane) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_plane(lhs: Rotor, rhs: Plane) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_plane(lhs: Rotor, rhs: Plane) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_sphere(lhs: Rotor, rhs: Sphere) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_sphere(lhs: Rotor, rhs: Sphere) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_sphere(lhs: Rotor, rhs: Sphere) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_sphere(lhs: Rotor, rhs: Sphere) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_wedge_sphere(lhs: Rotor, rhs: Sphere) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_sphere(lhs: Rotor, rhs: Sphere) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_sphere(lhs: Rotor, rhs: Sphere) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_sphere(lhs: Rotor, rhs: Sphere) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_rotor(lhs: Rotor, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_right_contraction_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_scalar_product_rotor(lhs: Rotor, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_rotor(lhs: Rotor, rhs: Rotor) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_rotor(lhs: Rotor, rhs: Rotor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_translator(lhs: Rotor, rhs: Translator) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_translator(lhs: Rotor, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_translator(lhs: Rotor, rhs: Translator) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_translator(lhs: Rotor, rhs: Translator) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_translator(lhs: Rotor, rhs: Translator) -> Translator {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_right_contraction_translator(lhs: Rotor, rhs: Translator) -> Rotor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_scalar_product_translator(lhs: Rotor, rhs: Translator) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_translator(lhs: Rotor, rhs: Translator) -> FlatPoint {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_translator(lhs: Rotor, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_versor(lhs: Rotor, rhs: Versor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_versor(lhs: Rotor, rhs: Versor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_versor(lhs: Rotor, rhs: Versor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_versor(lhs: Rotor, rhs: Versor) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_wedge_versor(lhs: Rotor, rhs: Versor) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_versor(lhs: Rotor, rhs: Versor) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_right_contraction_versor(lhs: Rotor, rhs: Versor) -> Rotor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_scalar_product_versor(lhs: Rotor, rhs: Versor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_versor(lhs: Rotor, rhs: Versor) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_versor(lhs: Rotor, rhs: Versor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_geometric_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_geometric_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_dot_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_wedge_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_wedge_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_left_contraction_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_right_contraction_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_scalar_product_multivector(lhs: Rotor, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_commutator_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_dot_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_sandwich_vec3(lhs: Rotor, rhs: Vec3) -> Vec3 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_sandwich_vec3(lhs: Rotor, rhs: Vec3) -> Vec3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_sandwich_point(lhs: Rotor, rhs: Point) -> Point {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_sandwich_point(lhs: Rotor, rhs: Point) -> Point {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_sandwich_flatpoint(lhs: Rotor, rhs: FlatPoint) -> FlatPoint {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_sandwich_flatpoint(lhs: Rotor, rhs: FlatPoint) -> FlatPoint {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_sandwich_pointpair(lhs: Rotor, rhs: PointPair) -> PointPair {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_sandwich_pointpair(lhs: Rotor, rhs: PointPair) -> PointPair {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_sandwich_line(lhs: Rotor, rhs: Line) -> Line {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_sandwich_line(lhs: Rotor, rhs: Line) -> Line {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_sandwich_circle(lhs: Rotor, rhs: Circle) -> Circle {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_sandwich_circle(lhs: Rotor, rhs: Circle) -> Circle {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_sandwich_plane(lhs: Rotor, rhs: Plane) -> Plane {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_sandwich_plane(lhs: Rotor, rhs: Plane) -> Plane {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_sandwich_sphere(lhs: Rotor, rhs: Sphere) -> Sphere {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_rotor_anti_sandwich_sphere(lhs: Rotor, rhs: Sphere) -> Sphere {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_rcompl(value: Translator) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_lcompl(value: Translator) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_rev(value: Translator) -> Translator {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_arev(value: Translator) -> Translator {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_involute(value: Translator) -> Translator {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_conjugate(value: Translator) -> Translator {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dual(value: Translator) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_vec3(lhs: Translator, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_vec3(lhs: Translator, rhs: Vec3) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_vec3(lhs: Translator, rhs: Vec3) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_vec3(lhs: Translator, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_vec3(lhs: Translator, rhs: Vec3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_right_contraction_vec3(lhs: Translator, rhs: Vec3) -> Ei {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_vec3(lhs: Translator, rhs: Vec3) -> Ei {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_vec3(lhs: Translator, rhs: Vec3) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_point(lhs: Translator, rhs: Point) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_point(lhs: Translator, rhs: Point) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_point(lhs: Translator, rhs: Point) -> Point {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_point(lhs: Translator, rhs: Point) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_point(lhs: Translator, rhs: Point) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_right_contraction_point(lhs: Translator, rhs: Point) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_point(lhs: Translator, rhs: Point) -> Point {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_point(lhs: Translator, rhs: Point) -> Sphere {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_flatpoint(lhs: Translator, rhs: FlatPoint) -> FlatPoint {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_flatpoint(lhs: Translator, rhs: FlatPoint) -> Circle {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_flatpoint(lhs: Translator, rhs: FlatPoint) -> FlatPoint {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_flatpoint(lhs: Translator, rhs: FlatPoint) -> FlatPoint {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_flatpoint(lhs: Translator, rhs: FlatPoint) -> FlatPoint {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_flatpoint(lhs: Translator, rhs: FlatPoint) -> FlatPoint {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_flatpoint(lhs: Translator, rhs: FlatPoint) -> Circle {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_pointpair(lhs: Translator, rhs: PointPair) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_pointpair(lhs: Translator, rhs: PointPair) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_pointpair(lhs: Translator, rhs: PointPair) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_pointpair(lhs: Translator, rhs: PointPair) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_pointpair(lhs: Translator, rhs: PointPair) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_right_contraction_pointpair(lhs: Translator, rhs: PointPair) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_scalar_product_pointpair(lhs: Translator, rhs: PointPair) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_pointpair(lhs: Translator, rhs: PointPair) -> PointPair {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_pointpair(lhs: Translator, rhs: PointPair) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_line(lhs: Translator, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_line(lhs: Translator, rhs: Line) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_line(lhs: Translator, rhs: Line) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_line(lhs: Translator, rhs: Line) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_line(lhs: Translator, rhs: Line) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_line(lhs: Translator, rhs: Line) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_line(lhs: Translator, rhs: Line) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_circle(lhs: Translator, rhs: Circle) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_circle(lhs: Translator, rhs: Circle) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_circle(lhs: Translator, rhs: Circle) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_circle(lhs: Translator, rhs: Circle) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_wedge_circle(lhs: Translator, rhs: Circle) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_circle(lhs: Translator, rhs: Circle) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_circle(lhs: Translator, rhs: Circle) -> Circle {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_circle(lhs: Translator, rhs: Circle) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_plane(lhs: Translator, rhs: Plane) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_plane(lhs: Translator, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_plane(lhs: Translator, rhs: Plane) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_plane(lhs: Translator, rhs: Plane) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_wedge_plane(lhs: Translator, rhs: Plane) -> Ei {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_plane(lhs: Translator, rhs: Plane) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_plane(lhs: Translator, rhs: Plane) -> E123i {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_plane(lhs: Translator, rhs: Plane) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_sphere(lhs: Translator, rhs: Sphere) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_sphere(lhs: Translator, rhs: Sphere) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_sphere(lhs: Translator, rhs: Sphere) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_sphere(lhs: Translator, rhs: Sphere) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_wedge_sphere(lhs: Translator, rhs: Sphere) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_sphere(lhs: Translator, rhs: Sphere) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_sphere(lhs: Translator, rhs: Sphere) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_sphere(lhs: Translator, rhs: Sphere) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_rotor(lhs: Translator, rhs: Rotor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_rotor(lhs: Translator, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_rotor(lhs: Translator, rhs: Rotor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_rotor(lhs: Translator, rhs: Rotor) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_rotor(lhs: Translator, rhs: Rotor) -> Rotor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_right_contraction_rotor(lhs: Translator, rhs: Rotor) -> Translator {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_scalar_product_rotor(lhs: Translator, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_rotor(lhs: Translator, rhs: Rotor) -> FlatPoint {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_rotor(lhs: Translator, rhs: Rotor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_translator(lhs: Translator, rhs: Translator) -> Translator {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_translator(lhs: Translator, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_translator(lhs: Translator, rhs: Translator) -> Translator {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_translator(lhs: Translator, rhs: Translator) -> Translator {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_translator(lhs: Translator, rhs: Translator) -> Translator {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_right_contraction_translator(lhs: Translator, rhs: Translator) -> Translator {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_scalar_product_translator(lhs: Translator, rhs: Translator) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_translator(lhs: Translator, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_versor(lhs: Translator, rhs: Versor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_versor(lhs: Translator, rhs: Versor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_versor(lhs: Translator, rhs: Versor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_versor(lhs: Translator, rhs: Versor) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_wedge_versor(lhs: Translator, rhs: Versor) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_versor(lhs: Translator, rhs: Versor) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_right_contraction_versor(lhs: Translator, rhs: Versor) -> Translator {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_scalar_product_versor(lhs: Translator, rhs: Versor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_versor(lhs: Translator, rhs: Versor) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_versor(lhs: Translator, rhs: Versor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_geometric_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_geometric_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_dot_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_wedge_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_wedge_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_left_contraction_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_right_contraction_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_scalar_product_multivector(lhs: Translator, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_commutator_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_dot_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_sandwich_point(lhs: Translator, rhs: Point) -> Point {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_sandwich_point(lhs: Translator, rhs: Point) -> Point {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_sandwich_flatpoint(lhs: Translator, rhs: FlatPoint) -> FlatPoint {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_sandwich_flatpoint(lhs: Translator, rhs: FlatPoint) -> FlatPoint {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_sandwich_pointpair(lhs: Translator, rhs: PointPair) -> PointPair {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_sandwich_pointpair(lhs: Translator, rhs: PointPair) -> PointPair {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_sandwich_line(lhs: Translator, rhs: Line) -> Line {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_sandwich_line(lhs: Translator, rhs: Line) -> Line {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_sandwich_circle(lhs: Translator, rhs: Circle) -> Circle {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_sandwich_circle(lhs: Translator, rhs: Circle) -> Circle {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_sandwich_plane(lhs: Translator, rhs: Plane) -> Plane {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_sandwich_plane(lhs: Translator, rhs: Plane) -> Plane {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_sandwich_sphere(lhs: Translator, rhs: Sphere) -> Sphere {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_translator_anti_sandwich_sphere(lhs: Translator, rhs: Sphere) -> Sphere {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_rcompl(value: Versor) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_lcompl(value: Versor) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_rev(value: Versor) -> Versor {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_arev(value: Versor) -> Versor {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_involute(value: Versor) -> Versor {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_conjugate(value: Versor) -> Versor {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dual(value: Versor) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_vec3(lhs: Versor, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_vec3(lhs: Versor, rhs: Vec3) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_vec3(lhs: Versor, rhs: Vec3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_vec3(lhs: Versor, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_vec3(lhs: Versor, rhs: Vec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_vec3(lhs: Versor, rhs: Vec3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_vec3(lhs: Versor, rhs: Vec3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_vec3(lhs: Versor, rhs: Vec3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_vec3(lhs: Versor, rhs: Vec3) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_point(lhs: Versor, rhs: Point) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_point(lhs: Versor, rhs: Point) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_point(lhs: Versor, rhs: Point) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_point(lhs: Versor, rhs: Point) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_point(lhs: Versor, rhs: Point) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_point(lhs: Versor, rhs: Point) -> Point {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_point(lhs: Versor, rhs: Point) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_point(lhs: Versor, rhs: Point) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_point(lhs: Versor, rhs: Point) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_flatpoint(lhs: Versor, rhs: FlatPoint) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_flatpoint(lhs: Versor, rhs: FlatPoint) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_flatpoint(lhs: Versor, rhs: FlatPoint) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_flatpoint(lhs: Versor, rhs: FlatPoint) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_flatpoint(lhs: Versor, rhs: FlatPoint) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_flatpoint(lhs: Versor, rhs: FlatPoint) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_flatpoint(lhs: Versor, rhs: FlatPoint) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_scalar_product_flatpoint(lhs: Versor, rhs: FlatPoint) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_flatpoint(lhs: Versor, rhs: FlatPoint) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_flatpoint(lhs: Versor, rhs: FlatPoint) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_pointpair(lhs: Versor, rhs: PointPair) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_pointpair(lhs: Versor, rhs: PointPair) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_pointpair(lhs: Versor, rhs: PointPair) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_pointpair(lhs: Versor, rhs: PointPair) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_pointpair(lhs: Versor, rhs: PointPair) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_pointpair(lhs: Versor, rhs: PointPair) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_pointpair(lhs: Versor, rhs: PointPair) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_scalar_product_pointpair(lhs: Versor, rhs: PointPair) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_pointpair(lhs: Versor, rhs: PointPair) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_pointpair(lhs: Versor, rhs: PointPair) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_line(lhs: Versor, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_line(lhs: Versor, rhs: Line) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_line(lhs: Versor, rhs: Line) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_line(lhs: Versor, rhs: Line) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_line(lhs: Versor, rhs: Line) -> Versor {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_line(lhs: Versor, rhs: Line) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_line(lhs: Versor, rhs: Line) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_line(lhs: Versor, rhs: Line) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_line(lhs: Versor, rhs: Line) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_circle(lhs: Versor, rhs: Circle) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_circle(lhs: Versor, rhs: Circle) -> Versor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_circle(lhs: Versor, rhs: Circle) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_circle(lhs: Versor, rhs: Circle) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_circle(lhs: Versor, rhs: Circle) -> Versor {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_circle(lhs: Versor, rhs: Circle) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_circle(lhs: Versor, rhs: Circle) -> Point {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_circle(lhs: Versor, rhs: Circle) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_circle(lhs: Versor, rhs: Circle) -> Versor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_plane(lhs: Versor, rhs: Plane) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_plane(lhs: Versor, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_plane(lhs: Versor, rhs: Plane) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_plane(lhs: Versor, rhs: Plane) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_plane(lhs: Versor, rhs: Plane) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_plane(lhs: Versor, rhs: Plane) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_plane(lhs: Versor, rhs: Plane) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_scalar_product_plane(lhs: Versor, rhs: Plane) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_plane(lhs: Versor, rhs: Plane) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_plane(lhs: Versor, rhs: Plane) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_sphere(lhs: Versor, rhs: Sphere) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_sphere(lhs: Versor, rhs: Sphere) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_sphere(lhs: Versor, rhs: Sphere) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_sphere(lhs: Versor, rhs: Sphere) -> Sphere {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_sphere(lhs: Versor, rhs: Sphere) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_sphere(lhs: Versor, rhs: Sphere) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_sphere(lhs: Versor, rhs: Sphere) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_scalar_product_sphere(lhs: Versor, rhs: Sphere) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_sphere(lhs: Versor, rhs: Sphere) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_sphere(lhs: Versor, rhs: Sphere) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_rotor(lhs: Versor, rhs: Rotor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_rotor(lhs: Versor, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_rotor(lhs: Versor, rhs: Rotor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_rotor(lhs: Versor, rhs: Rotor) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_rotor(lhs: Versor, rhs: Rotor) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_rotor(lhs: Versor, rhs: Rotor) -> Rotor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_rotor(lhs: Versor, rhs: Rotor) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_scalar_product_rotor(lhs: Versor, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_rotor(lhs: Versor, rhs: Rotor) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_rotor(lhs: Versor, rhs: Rotor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_translator(lhs: Versor, rhs: Translator) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_translator(lhs: Versor, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_translator(lhs: Versor, rhs: Translator) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_translator(lhs: Versor, rhs: Translator) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_translator(lhs: Versor, rhs: Translator) -> Point {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_translator(lhs: Versor, rhs: Translator) -> Translator {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_translator(lhs: Versor, rhs: Translator) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_scalar_product_translator(lhs: Versor, rhs: Translator) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_translator(lhs: Versor, rhs: Translator) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_translator(lhs: Versor, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_versor(lhs: Versor, rhs: Versor) -> Versor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_versor(lhs: Versor, rhs: Versor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_versor(lhs: Versor, rhs: Versor) -> Versor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_versor(lhs: Versor, rhs: Versor) -> Versor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_versor(lhs: Versor, rhs: Versor) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_versor(lhs: Versor, rhs: Versor) -> Versor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_versor(lhs: Versor, rhs: Versor) -> Versor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_scalar_product_versor(lhs: Versor, rhs: Versor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_versor(lhs: Versor, rhs: Versor) -> Versor {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_versor(lhs: Versor, rhs: Versor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_geometric_multivector(lhs: Versor, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_geometric_multivector(lhs: Versor, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_dot_multivector(lhs: Versor, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_wedge_multivector(lhs: Versor, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_wedge_multivector(lhs: Versor, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_left_contraction_multivector(lhs: Versor, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_right_contraction_multivector(lhs: Versor, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_scalar_product_multivector(lhs: Versor, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_commutator_multivector(lhs: Versor, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_dot_multivector(lhs: Versor, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_sandwich_pointpair(lhs: Versor, rhs: PointPair) -> PointPair {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_sandwich_pointpair(lhs: Versor, rhs: PointPair) -> PointPair {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_sandwich_circle(lhs: Versor, rhs: Circle) -> Circle {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_versor_anti_sandwich_circle(lhs: Versor, rhs: Circle) -> Circle {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_rcompl(value: Multivector) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_lcompl(value: Multivector) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_rev(value: Multivector) -> Multivector {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_arev(value: Multivector) -> Multivector {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_involute(value: Multivector) -> Multivector {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_conjugate(value: Multivector) -> Multivector {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dual(value: Multivector) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_vec3(lhs: Multivector, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_point(lhs: Multivector, rhs: Point) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_point(lhs: Multivector, rhs: Point) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_point(lhs: Multivector, rhs: Point) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_point(lhs: Multivector, rhs: Point) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_point(lhs: Multivector, rhs: Point) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_point(lhs: Multivector, rhs: Point) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_point(lhs: Multivector, rhs: Point) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_point(lhs: Multivector, rhs: Point) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_point(lhs: Multivector, rhs: Point) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_point(lhs: Multivector, rhs: Point) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_flatpoint(lhs: Multivector, rhs: FlatPoint) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_flatpoint(lhs: Multivector, rhs: FlatPoint) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_flatpoint(lhs: Multivector, rhs: FlatPoint) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_flatpoint(lhs: Multivector, rhs: FlatPoint) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_flatpoint(lhs: Multivector, rhs: FlatPoint) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_flatpoint(lhs: Multivector, rhs: FlatPoint) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_flatpoint(lhs: Multivector, rhs: FlatPoint) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_flatpoint(lhs: Multivector, rhs: FlatPoint) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_flatpoint(lhs: Multivector, rhs: FlatPoint) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_flatpoint(lhs: Multivector, rhs: FlatPoint) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_pointpair(lhs: Multivector, rhs: PointPair) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_pointpair(lhs: Multivector, rhs: PointPair) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_pointpair(lhs: Multivector, rhs: PointPair) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_pointpair(lhs: Multivector, rhs: PointPair) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_pointpair(lhs: Multivector, rhs: PointPair) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_pointpair(lhs: Multivector, rhs: PointPair) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_pointpair(lhs: Multivector, rhs: PointPair) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_pointpair(lhs: Multivector, rhs: PointPair) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_pointpair(lhs: Multivector, rhs: PointPair) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_pointpair(lhs: Multivector, rhs: PointPair) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_line(lhs: Multivector, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_line(lhs: Multivector, rhs: Line) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_line(lhs: Multivector, rhs: Line) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_line(lhs: Multivector, rhs: Line) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_line(lhs: Multivector, rhs: Line) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_line(lhs: Multivector, rhs: Line) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_line(lhs: Multivector, rhs: Line) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_line(lhs: Multivector, rhs: Line) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_line(lhs: Multivector, rhs: Line) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_line(lhs: Multivector, rhs: Line) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_circle(lhs: Multivector, rhs: Circle) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_circle(lhs: Multivector, rhs: Circle) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_circle(lhs: Multivector, rhs: Circle) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_circle(lhs: Multivector, rhs: Circle) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_circle(lhs: Multivector, rhs: Circle) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_circle(lhs: Multivector, rhs: Circle) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_circle(lhs: Multivector, rhs: Circle) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_circle(lhs: Multivector, rhs: Circle) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_circle(lhs: Multivector, rhs: Circle) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_circle(lhs: Multivector, rhs: Circle) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_plane(lhs: Multivector, rhs: Plane) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_sphere(lhs: Multivector, rhs: Sphere) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_sphere(lhs: Multivector, rhs: Sphere) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_sphere(lhs: Multivector, rhs: Sphere) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_sphere(lhs: Multivector, rhs: Sphere) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_sphere(lhs: Multivector, rhs: Sphere) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_sphere(lhs: Multivector, rhs: Sphere) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_sphere(lhs: Multivector, rhs: Sphere) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_sphere(lhs: Multivector, rhs: Sphere) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_sphere(lhs: Multivector, rhs: Sphere) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_sphere(lhs: Multivector, rhs: Sphere) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_rotor(lhs: Multivector, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_translator(lhs: Multivector, rhs: Translator) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_versor(lhs: Multivector, rhs: Versor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_versor(lhs: Multivector, rhs: Versor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_versor(lhs: Multivector, rhs: Versor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_versor(lhs: Multivector, rhs: Versor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_versor(lhs: Multivector, rhs: Versor) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_versor(lhs: Multivector, rhs: Versor) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_versor(lhs: Multivector, rhs: Versor) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_versor(lhs: Multivector, rhs: Versor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_versor(lhs: Multivector, rhs: Versor) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_versor(lhs: Multivector, rhs: Versor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_geometric_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_geometric_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_dot_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_wedge_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_wedge_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_left_contraction_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_right_contraction_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_scalar_product_multivector(lhs: Multivector, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_commutator_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn cga3d_multivector_anti_dot_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
@@ -0,0 +1,4512 @@
+//! # FlatPoint
+//!
+//! ## Operations
+//! ```text
+//! FlatPoint.geometric(FlatPoint) -> Translator
+//! FlatPoint.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.dot(FlatPoint) -> S
+//! FlatPoint.left_contraction(FlatPoint) -> S
+//! FlatPoint.right_contraction(FlatPoint) -> S
+//! FlatPoint.scalar_product(FlatPoint) -> S
+//! FlatPoint.commutator(FlatPoint) -> FlatPoint
+//! FlatPoint.anti_dot(FlatPoint) -> E123oi
+//! FlatPoint.geometric(Vec3) -> Multivector
+//! Vec3.geometric(FlatPoint) -> Multivector
+//! FlatPoint.anti_geometric(Vec3) -> Versor
+//! Vec3.anti_geometric(FlatPoint) -> Versor
+//! FlatPoint.dot(Vec3) -> Ei
+//! Vec3.dot(FlatPoint) -> Ei
+//! FlatPoint.wedge(Vec3) -> Line
+//! Vec3.wedge(FlatPoint) -> Line
+//! Vec3.left_contraction(FlatPoint) -> Ei
+//! FlatPoint.right_contraction(Vec3) -> Ei
+//! FlatPoint.commutator(Vec3) -> Ei
+//! Vec3.commutator(FlatPoint) -> Ei
+//! FlatPoint.anti_dot(Vec3) -> E123i
+//! Vec3.anti_dot(FlatPoint) -> E123i
+//! FlatPoint.geometric(Point) -> Multivector
+//! Point.geometric(FlatPoint) -> Multivector
+//! FlatPoint.anti_geometric(Point) -> Versor
+//! Point.anti_geometric(FlatPoint) -> Versor
+//! FlatPoint.dot(Point) -> Point
+//! Point.dot(FlatPoint) -> Point
+//! FlatPoint.wedge(Point) -> Line
+//! Point.wedge(FlatPoint) -> Line
+//! Point.left_contraction(FlatPoint) -> Point
+//! FlatPoint.right_contraction(Point) -> Point
+//! FlatPoint.commutator(Point) -> Point
+//! Point.commutator(FlatPoint) -> Point
+//! FlatPoint.anti_dot(Point) -> Sphere
+//! Point.anti_dot(FlatPoint) -> Sphere
+//! FlatPoint.geometric(PointPair) -> Versor
+//! PointPair.geometric(FlatPoint) -> Versor
+//! FlatPoint.anti_geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.dot(PointPair) -> S
+//! PointPair.dot(FlatPoint) -> S
+//! FlatPoint.wedge(PointPair) -> Plane
+//! PointPair.wedge(FlatPoint) -> Plane
+//! FlatPoint.left_contraction(PointPair) -> S
+//! PointPair.left_contraction(FlatPoint) -> S
+//! FlatPoint.right_contraction(PointPair) -> S
+//! PointPair.right_contraction(FlatPoint) -> S
+//! FlatPoint.scalar_product(PointPair) -> S
+//! PointPair.scalar_product(FlatPoint) -> S
+//! FlatPoint.commutator(PointPair) -> PointPair
+//! PointPair.commutator(FlatPoint) -> PointPair
+//! FlatPoint.anti_dot(PointPair) -> E123oi
+//! PointPair.anti_dot(FlatPoint) -> E123oi
+//! FlatPoint.geometric(Line) -> Multivector
+//! Line.geometric(FlatPoint) -> Multivector
+//! FlatPoint.anti_geometric(Line) -> Versor
+//! Line.anti_geometric(FlatPoint) -> Versor
+//! FlatPoint.dot(Line) -> Point
+//! Line.dot(FlatPoint) -> Point
+//! FlatPoint.left_contraction(Line) -> Point
+//! Line.right_contraction(FlatPoint) -> Point
+//! FlatPoint.commutator(Line) -> Line
+//! Line.commutator(FlatPoint) -> Line
+//! FlatPoint.anti_dot(Line) -> Plane
+//! Line.anti_dot(FlatPoint) -> Plane
+//! FlatPoint.geometric(Circle) -> Multivector
+//! Circle.geometric(FlatPoint) -> Multivector
+//! FlatPoint.anti_geometric(Circle) -> Versor
+//! Circle.anti_geometric(FlatPoint) -> Versor
+//! FlatPoint.dot(Circle) -> Point
+//! Circle.dot(FlatPoint) -> Point
+//! FlatPoint.wedge(Circle) -> E123oi
+//! Circle.wedge(FlatPoint) -> E123oi
+//! FlatPoint.anti_wedge(Circle) -> S
+//! Circle.anti_wedge(FlatPoint) -> S
+//! FlatPoint.left_contraction(Circle) -> Point
+//! Circle.right_contraction(FlatPoint) -> Point
+//! FlatPoint.commutator(Circle) -> Circle
+//! Circle.commutator(FlatPoint) -> Circle
+//! FlatPoint.anti_dot(Circle) -> Plane
+//! Circle.anti_dot(FlatPoint) -> Plane
+//! FlatPoint.geometric(Plane) -> Versor
+//! Plane.geometric(FlatPoint) -> Versor
+//! FlatPoint.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.dot(Plane) -> PointPair
+//! Plane.dot(FlatPoint) -> PointPair
+//! FlatPoint.anti_wedge(Plane) -> Ei
+//! Plane.anti_wedge(FlatPoint) -> Ei
+//! FlatPoint.left_contraction(Plane) -> PointPair
+//! Plane.right_contraction(FlatPoint) -> PointPair
+//! FlatPoint.commutator(Plane) -> E123i
+//! Plane.commutator(FlatPoint) -> E123i
+//! FlatPoint.anti_dot(Plane) -> Line
+//! Plane.anti_dot(FlatPoint) -> Line
+//! FlatPoint.geometric(Sphere) -> Versor
+//! Sphere.geometric(FlatPoint) -> Versor
+//! FlatPoint.anti_geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.dot(Sphere) -> PointPair
+//! Sphere.dot(FlatPoint) -> PointPair
+//! FlatPoint.anti_wedge(Sphere) -> Point
+//! Sphere.anti_wedge(FlatPoint) -> Point
+//! FlatPoint.left_contraction(Sphere) -> PointPair
+//! Sphere.right_contraction(FlatPoint) -> PointPair
+//! FlatPoint.commutator(Sphere) -> Sphere
+//! Sphere.commutator(FlatPoint) -> Sphere
+//! FlatPoint.anti_dot(Sphere) -> Line
+//! Sphere.anti_dot(FlatPoint) -> Line
+//! FlatPoint.geometric(Rotor) -> Versor
+//! Rotor.geometric(FlatPoint) -> Versor
+//! FlatPoint.anti_geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.dot(Rotor) -> FlatPoint
+//! Rotor.dot(FlatPoint) -> FlatPoint
+//! FlatPoint.wedge(Rotor) -> Versor
+//! Rotor.wedge(FlatPoint) -> Versor
+//! Rotor.left_contraction(FlatPoint) -> FlatPoint
+//! FlatPoint.right_contraction(Rotor) -> FlatPoint
+//! FlatPoint.commutator(Rotor) -> FlatPoint
+//! Rotor.commutator(FlatPoint) -> FlatPoint
+//! FlatPoint.anti_dot(Rotor) -> Circle
+//! Rotor.anti_dot(FlatPoint) -> Circle
+//! FlatPoint.geometric(Translator) -> FlatPoint
+//! Translator.geometric(FlatPoint) -> FlatPoint
+//! FlatPoint.anti_geometric(Translator) -> Circle
+//! Translator.anti_geometric(FlatPoint) -> Circle
+//! FlatPoint.dot(Translator) -> FlatPoint
+//! Translator.dot(FlatPoint) -> FlatPoint
+//! FlatPoint.wedge(Translator) -> FlatPoint
+//! Translator.wedge(FlatPoint) -> FlatPoint
+//! Translator.left_contraction(FlatPoint) -> FlatPoint
+//! FlatPoint.right_contraction(Translator) -> FlatPoint
+//! FlatPoint.commutator(Translator) -> FlatPoint
+//! Translator.commutator(FlatPoint) -> FlatPoint
+//! FlatPoint.anti_dot(Translator) -> Circle
+//! Translator.anti_dot(FlatPoint) -> Circle
+//! FlatPoint.geometric(Versor) -> Versor
+//! Versor.geometric(FlatPoint) -> Versor
+//! FlatPoint.anti_geometric(Versor) -> Multivector
+//! Versor.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.dot(Versor) -> Versor
+//! Versor.dot(FlatPoint) -> Versor
+//! FlatPoint.wedge(Versor) -> Versor
+//! Versor.wedge(FlatPoint) -> Versor
+//! FlatPoint.anti_wedge(Versor) -> Point
+//! Versor.anti_wedge(FlatPoint) -> Point
+//! FlatPoint.left_contraction(Versor) -> Versor
+//! Versor.left_contraction(FlatPoint) -> Versor
+//! FlatPoint.right_contraction(Versor) -> Versor
+//! Versor.right_contraction(FlatPoint) -> Versor
+//! FlatPoint.scalar_product(Versor) -> S
+//! Versor.scalar_product(FlatPoint) -> S
+//! FlatPoint.commutator(Versor) -> Versor
+//! Versor.commutator(FlatPoint) -> Versor
+//! FlatPoint.anti_dot(Versor) -> Multivector
+//! Versor.anti_dot(FlatPoint) -> Multivector
+//! FlatPoint.geometric(Multivector) -> Multivector
+//! Multivector.geometric(FlatPoint) -> Multivector
+//! FlatPoint.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.dot(Multivector) -> Multivector
+//! Multivector.dot(FlatPoint) -> Multivector
+//! FlatPoint.wedge(Multivector) -> Multivector
+//! Multivector.wedge(FlatPoint) -> Multivector
+//! FlatPoint.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(FlatPoint) -> Multivector
+//! FlatPoint.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(FlatPoint) -> Multivector
+//! FlatPoint.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(FlatPoint) -> Multivector
+//! FlatPoint.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(FlatPoint) -> S
+//! FlatPoint.commutator(Multivector) -> Multivector
+//! Multivector.commutator(FlatPoint) -> Multivector
+//! FlatPoint.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(FlatPoint) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlatPoint {
+	pub e1i: E1i,
+	pub e2i: E2i,
+	pub e3i: E3i,
+	pub eoi: Eoi,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for FlatPoint {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for FlatPoint {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for FlatPoint {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.e1i.abs_diff_eq(&other.e1i, epsilon)
+			&& self.e2i.abs_diff_eq(&other.e2i, epsilon)
+			&& self.e3i.abs_diff_eq(&other.e3i, epsilon)
+			&& self.eoi.abs_diff_eq(&other.eoi, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for FlatPoint {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.e1i.relative_eq(&other.e1i, epsilon, max_relative)
+			&& self.e2i.relative_eq(&other.e2i, epsilon, max_relative)
+			&& self.e3i.relative_eq(&other.e3i, epsilon, max_relative)
+			&& self.eoi.relative_eq(&other.eoi, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for FlatPoint {
+	type Output = Circle;
+	fn rcompl(self) -> Self::Output {
+		Circle {
+			e123: self.eoi.rcompl(),
+			e12o: self.e3i.rcompl(),
+			e12i: Default::default(),
+			e13o: self.e2i.rcompl(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: self.e1i.rcompl(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Not for FlatPoint {
+	type Output = Circle;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for FlatPoint {
+	type Output = Circle;
+	fn lcompl(self) -> Self::Output {
+		Circle {
+			e123: self.eoi.lcompl(),
+			e12o: self.e3i.lcompl(),
+			e12i: Default::default(),
+			e13o: self.e2i.lcompl(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: self.e1i.lcompl(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+impl Reverse for FlatPoint {
+	fn rev(self) -> Self {
+		FlatPoint {
+			e1i: -self.e1i,
+			e2i: -self.e2i,
+			e3i: -self.e3i,
+			eoi: -self.eoi,
+		}
+	}
+}
+
+impl AntiReverse for FlatPoint {
+	fn arev(self) -> Self {
+		FlatPoint {
+			e1i: -self.e1i,
+			e2i: -self.e2i,
+			e3i: -self.e3i,
+			eoi: -self.eoi,
+		}
+	}
+}
+
+impl Involute for FlatPoint {
+	fn involute(self) -> Self {
+		FlatPoint {
+			e1i: self.e1i,
+			e2i: self.e2i,
+			e3i: self.e3i,
+			eoi: self.eoi,
+		}
+	}
+}
+
+impl Conjugate for FlatPoint {
+	fn conjugate(self) -> Self {
+		FlatPoint {
+			e1i: -self.e1i,
+			e2i: -self.e2i,
+			e3i: -self.e3i,
+			eoi: -self.eoi,
+		}
+	}
+}
+
+impl Dual for FlatPoint {
+	type Output = Circle;
+	fn dual(self) -> Self::Output {
+		Circle {
+			e123: self.eoi.dual(),
+			e12o: self.e3i.dual(),
+			e12i: Default::default(),
+			e13o: self.e2i.dual(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: self.e1i.dual(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+impl Square for FlatPoint {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		S(self.eoi.0 * self.eoi.0).0
+	}
+}
+
+// Omitted: Norm for FlatPoint  (the bulk and weight need a diagonal metric)
+
+impl FlatPoint {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.e1i.0, self.e2i.0, self.e3i.0, self.eoi.0]
+	}
+}
+
+impl From<[f64; 4]> for FlatPoint {
+	fn from(a: [f64; 4]) -> Self {
+		FlatPoint {
+			e1i: E1i(a[0]),
+			e2i: E2i(a[1]),
+			e3i: E3i(a[2]),
+			eoi: Eoi(a[3]),
+		}
+	}
+}
+
+impl From<FlatPoint> for [f64; 4] {
+	fn from(v: FlatPoint) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<FlatPoint> for Multivector {
+	fn from(v: FlatPoint) -> Self {
+		Multivector {
+			e1i: v.e1i,
+			e2i: v.e2i,
+			e3i: v.e3i,
+			eoi: v.eoi,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for FlatPoint {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `FlatPoint` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.eo.0 == 0.0
+			&& mv.ei.0 == 0.0
+			&& mv.e12.0 == 0.0
+			&& mv.e13.0 == 0.0
+			&& mv.e1o.0 == 0.0
+			&& mv.e23.0 == 0.0
+			&& mv.e2o.0 == 0.0
+			&& mv.e3o.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e12o.0 == 0.0
+			&& mv.e12i.0 == 0.0
+			&& mv.e13o.0 == 0.0
+			&& mv.e13i.0 == 0.0
+			&& mv.e1oi.0 == 0.0
+			&& mv.e23o.0 == 0.0
+			&& mv.e23i.0 == 0.0
+			&& mv.e2oi.0 == 0.0
+			&& mv.e3oi.0 == 0.0
+			&& mv.e123o.0 == 0.0
+			&& mv.e123i.0 == 0.0
+			&& mv.e12oi.0 == 0.0
+			&& mv.e13oi.0 == 0.0
+			&& mv.e23oi.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(FlatPoint {
+				e1i: mv.e1i,
+				e2i: mv.e2i,
+				e3i: mv.e3i,
+				eoi: mv.eoi,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP Vec3:
+
+// FlatPoint.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for FlatPoint {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : E1oi(self.eoi.0 * rhs.x.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi  : E3oi(self.eoi.0 * rhs.z.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+			e1oi: E1oi(self.eoi.0 * rhs.x.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+			e2oi: E2oi(self.eoi.0 * rhs.y.0),
+			e3oi: E3oi(self.eoi.0 * rhs.z.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for FlatPoint {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(Vec3) -> Versor
+impl AntiGeometric<Vec3> for FlatPoint {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.eoi.0 * rhs.z.0),
+		//     e13  : E13(self.eoi.0 * rhs.y.0),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+		//     e23  : -E23(self.eoi.0 * rhs.x.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.eoi.0 * rhs.z.0),
+			e13: E13(self.eoi.0 * rhs.y.0),
+			e1o: Default::default(),
+			e1i: -E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+			e23: -E23(self.eoi.0 * rhs.x.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.dot(Vec3) -> Ei
+impl Dot<Vec3> for FlatPoint {
+	type Output = Ei;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+		-Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+	}
+}
+
+impl std::ops::BitOr<Vec3> for FlatPoint {
+	type Output = Ei;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// FlatPoint.wedge(Vec3) -> Line
+impl Wedge<Vec3> for FlatPoint {
+	type Output = Line;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     e12i: -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13i: -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi: E1oi(self.eoi.0 * rhs.x.0),
+		//     e23i: -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi: E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi: E3oi(self.eoi.0 * rhs.z.0),
+		// }
+		Line {
+			e12i: self.e1i.wedge(rhs.y) + self.e2i.wedge(rhs.x),
+			e13i: self.e1i.wedge(rhs.z) + self.e3i.wedge(rhs.x),
+			e1oi: self.eoi.wedge(rhs.x),
+			e23i: self.e2i.wedge(rhs.z) + self.e3i.wedge(rhs.y),
+			e2oi: self.eoi.wedge(rhs.y),
+			e3oi: self.eoi.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for FlatPoint {
+	type Output = Line;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for FlatPoint {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for FlatPoint {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for FlatPoint {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.right_contraction(Vec3) -> Ei
+impl RightContraction<Vec3> for FlatPoint {
+	type Output = Ei;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+		-Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+	}
+}
+
+impl ScalarProduct<Vec3> for FlatPoint {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.commutator(Vec3) -> Ei
+impl Commutator<Vec3> for FlatPoint {
+	type Output = Ei;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+		-Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+	}
+}
+
+// FlatPoint.anti_dot(Vec3) -> E123i
+impl AntiDot<Vec3> for FlatPoint {
+	type Output = E123i;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0)
+		-E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP Point:
+
+// FlatPoint.geometric(Point) -> Multivector
+impl Geometric<Point> for FlatPoint {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e1i.0 * rhs.o.0),
+		//     e2    : -E2(self.e2i.0 * rhs.o.0),
+		//     e3    : -E3(self.e3i.0 * rhs.o.0),
+		//     eo    : -Eo(self.eoi.0 * rhs.o.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.o.0) + E1oi(self.eoi.0 * rhs.x.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : -E2oi(self.e2i.0 * rhs.o.0) + E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi  : -E3oi(self.e3i.0 * rhs.o.0) + E3oi(self.eoi.0 * rhs.z.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e1i.0 * rhs.o.0),
+			e2: -E2(self.e2i.0 * rhs.o.0),
+			e3: -E3(self.e3i.0 * rhs.o.0),
+			eo: -Eo(self.eoi.0 * rhs.o.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+			e1oi: -E1oi(self.e1i.0 * rhs.o.0) + E1oi(self.eoi.0 * rhs.x.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+			e2oi: -E2oi(self.e2i.0 * rhs.o.0) + E2oi(self.eoi.0 * rhs.y.0),
+			e3oi: -E3oi(self.e3i.0 * rhs.o.0) + E3oi(self.eoi.0 * rhs.z.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for FlatPoint {
+	type Output = Multivector;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(Point) -> Versor
+impl AntiGeometric<Point> for FlatPoint {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e3i.0 * rhs.o.0) - E12(self.eoi.0 * rhs.z.0),
+		//     e13  : -E13(self.e2i.0 * rhs.o.0) + E13(self.eoi.0 * rhs.y.0),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+		//     e23  : E23(self.e1i.0 * rhs.o.0) - E23(self.eoi.0 * rhs.x.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.eoi.0 * rhs.o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0) + E123i(self.eoi.0 * rhs.inf.0),
+		//     e12oi: -E12oi(self.e3i.0 * rhs.o.0),
+		//     e13oi: E13oi(self.e2i.0 * rhs.o.0),
+		//     e23oi: -E23oi(self.e1i.0 * rhs.o.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e3i.0 * rhs.o.0) - E12(self.eoi.0 * rhs.z.0),
+			e13: -E13(self.e2i.0 * rhs.o.0) + E13(self.eoi.0 * rhs.y.0),
+			e1o: Default::default(),
+			e1i: -E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+			e23: E23(self.e1i.0 * rhs.o.0) - E23(self.eoi.0 * rhs.x.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+			eoi: Default::default(),
+			e123o: E123o(self.eoi.0 * rhs.o.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0)
+				+ E123i(self.eoi.0 * rhs.inf.0),
+			e12oi: -E12oi(self.e3i.0 * rhs.o.0),
+			e13oi: E13oi(self.e2i.0 * rhs.o.0),
+			e23oi: -E23oi(self.e1i.0 * rhs.o.0),
+		}
+	}
+}
+
+// FlatPoint.dot(Point) -> Point
+impl Dot<Point> for FlatPoint {
+	type Output = Point;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e1i.0 * rhs.o.0),
+		//     y  : -E2(self.e2i.0 * rhs.o.0),
+		//     z  : -E3(self.e3i.0 * rhs.o.0),
+		//     o  : -Eo(self.eoi.0 * rhs.o.0),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0),
+		// }
+		Point {
+			x: -E1(self.e1i.0 * rhs.o.0),
+			y: -E2(self.e2i.0 * rhs.o.0),
+			z: -E3(self.e3i.0 * rhs.o.0),
+			o: -Eo(self.eoi.0 * rhs.o.0),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point> for FlatPoint {
+	type Output = Point;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// FlatPoint.wedge(Point) -> Line
+impl Wedge<Point> for FlatPoint {
+	type Output = Line;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// Line {
+		//     e12i: -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13i: -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi: -E1oi(self.e1i.0 * rhs.o.0) + E1oi(self.eoi.0 * rhs.x.0),
+		//     e23i: -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi: -E2oi(self.e2i.0 * rhs.o.0) + E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi: -E3oi(self.e3i.0 * rhs.o.0) + E3oi(self.eoi.0 * rhs.z.0),
+		// }
+		Line {
+			e12i: self.e1i.wedge(rhs.y) + self.e2i.wedge(rhs.x),
+			e13i: self.e1i.wedge(rhs.z) + self.e3i.wedge(rhs.x),
+			e1oi: self.e1i.wedge(rhs.o) + self.eoi.wedge(rhs.x),
+			e23i: self.e2i.wedge(rhs.z) + self.e3i.wedge(rhs.y),
+			e2oi: self.e2i.wedge(rhs.o) + self.eoi.wedge(rhs.y),
+			e3oi: self.e3i.wedge(rhs.o) + self.eoi.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point> for FlatPoint {
+	type Output = Line;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point> for FlatPoint {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point> for FlatPoint {
+	type Output = Zero;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point> for FlatPoint {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.right_contraction(Point) -> Point
+impl RightContraction<Point> for FlatPoint {
+	type Output = Point;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e1i.0 * rhs.o.0),
+		//     y  : -E2(self.e2i.0 * rhs.o.0),
+		//     z  : -E3(self.e3i.0 * rhs.o.0),
+		//     o  : -Eo(self.eoi.0 * rhs.o.0),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0),
+		// }
+		Point {
+			x: -E1(self.e1i.0 * rhs.o.0),
+			y: -E2(self.e2i.0 * rhs.o.0),
+			z: -E3(self.e3i.0 * rhs.o.0),
+			o: -Eo(self.eoi.0 * rhs.o.0),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0),
+		}
+	}
+}
+
+impl ScalarProduct<Point> for FlatPoint {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.commutator(Point) -> Point
+impl Commutator<Point> for FlatPoint {
+	type Output = Point;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e1i.0 * rhs.o.0),
+		//     y  : -E2(self.e2i.0 * rhs.o.0),
+		//     z  : -E3(self.e3i.0 * rhs.o.0),
+		//     o  : -Eo(self.eoi.0 * rhs.o.0),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0),
+		// }
+		Point {
+			x: -E1(self.e1i.0 * rhs.o.0),
+			y: -E2(self.e2i.0 * rhs.o.0),
+			z: -E3(self.e3i.0 * rhs.o.0),
+			o: -Eo(self.eoi.0 * rhs.o.0),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0),
+		}
+	}
+}
+
+// FlatPoint.anti_dot(Point) -> Sphere
+impl AntiDot<Point> for FlatPoint {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.eoi.0 * rhs.o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0) + E123i(self.eoi.0 * rhs.inf.0),
+		//     e12oi: -E12oi(self.e3i.0 * rhs.o.0),
+		//     e13oi: E13oi(self.e2i.0 * rhs.o.0),
+		//     e23oi: -E23oi(self.e1i.0 * rhs.o.0),
+		// }
+		Sphere {
+			e123o: E123o(self.eoi.0 * rhs.o.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0)
+				+ E123i(self.eoi.0 * rhs.inf.0),
+			e12oi: -E12oi(self.e3i.0 * rhs.o.0),
+			e13oi: E13oi(self.e2i.0 * rhs.o.0),
+			e23oi: -E23oi(self.e1i.0 * rhs.o.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP FlatPoint:
+
+// FlatPoint.geometric(FlatPoint) -> Translator
+impl Geometric<FlatPoint> for FlatPoint {
+	type Output = Translator;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Translator {
+		//     s  : S(self.eoi.0 * rhs.eoi.0),
+		//     e1i: -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e2i: -E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3i: -E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		// }
+		Translator {
+			s: S(self.eoi.0 * rhs.eoi.0),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+			e2i: -E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+			e3i: -E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for FlatPoint {
+	type Output = Translator;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(FlatPoint) -> Multivector
+impl AntiGeometric<FlatPoint> for FlatPoint {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e3i.0 * rhs.eoi.0) + E12i(self.eoi.0 * rhs.e3i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e2i.0 * rhs.eoi.0) - E13i(self.eoi.0 * rhs.e2i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e1i.0 * rhs.eoi.0) + E23i(self.eoi.0 * rhs.e1i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e3i.0 * rhs.eoi.0) + E12i(self.eoi.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e2i.0 * rhs.eoi.0) - E13i(self.eoi.0 * rhs.e2i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.eoi.0) + E23i(self.eoi.0 * rhs.e1i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// FlatPoint.dot(FlatPoint) -> S
+impl Dot<FlatPoint> for FlatPoint {
+	type Output = S;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// S(self.eoi.0 * rhs.eoi.0)
+		S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for FlatPoint {
+	type Output = S;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<FlatPoint> for FlatPoint {
+	type Output = Zero;
+	fn wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for FlatPoint {
+	type Output = Zero;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<FlatPoint> for FlatPoint {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for FlatPoint {
+	type Output = Zero;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// FlatPoint.left_contraction(FlatPoint) -> S
+impl LeftContraction<FlatPoint> for FlatPoint {
+	type Output = S;
+	fn left_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// S(self.eoi.0 * rhs.eoi.0)
+		S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// FlatPoint.right_contraction(FlatPoint) -> S
+impl RightContraction<FlatPoint> for FlatPoint {
+	type Output = S;
+	fn right_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// S(self.eoi.0 * rhs.eoi.0)
+		S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// FlatPoint.scalar_product(FlatPoint) -> S
+impl ScalarProduct<FlatPoint> for FlatPoint {
+	type Output = S;
+	fn scalar_product(self, rhs: FlatPoint) -> Self::Output {
+		// S(self.eoi.0 * rhs.eoi.0)
+		S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// FlatPoint.commutator(FlatPoint) -> FlatPoint
+impl Commutator<FlatPoint> for FlatPoint {
+	type Output = FlatPoint;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// FlatPoint {
+		//     e1i: -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e2i: -E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3i: -E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi: Default::default(),
+		// }
+		FlatPoint {
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+			e2i: -E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+			e3i: -E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.anti_dot(FlatPoint) -> E123oi
+impl AntiDot<FlatPoint> for FlatPoint {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// -E123oi(self.eoi.0 * rhs.eoi.0)
+		-E123oi(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP PointPair:
+
+// FlatPoint.geometric(PointPair) -> Versor
+impl Geometric<PointPair> for FlatPoint {
+	type Output = Versor;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+		//     e13  : E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+		//     e1o  : -E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i  : -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+		//     e2o  : -E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+			e1o: -E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+			e2o: -E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.eoi.0 * rhs.e12.0),
+			e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.eoi.0 * rhs.e13.0),
+			e23oi: E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for FlatPoint {
+	type Output = Versor;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(PointPair) -> Multivector
+impl AntiGeometric<PointPair> for FlatPoint {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.eoi.0 * rhs.e23.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e3o.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.eoi.0 * rhs.e13.0),
+		//     e3    : E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0) + E3(self.eoi.0 * rhs.e12.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0),
+		//     e12o  : E12o(self.eoi.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.eoi.0) + E12i(self.eoi.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.eoi.0 * rhs.e2o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e3i.0 * rhs.e23.0) - E13i(self.eoi.0 * rhs.e2i.0),
+		//     e1oi  : -E1oi(self.e2i.0 * rhs.e3o.0) + E1oi(self.e3i.0 * rhs.e2o.0),
+		//     e23o  : E23o(self.eoi.0 * rhs.e1o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0) + E23i(self.eoi.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e1i.0 * rhs.e3o.0) - E2oi(self.e3i.0 * rhs.e1o.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e2o.0) + E3oi(self.e2i.0 * rhs.e1o.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.eoi.0 * rhs.e23.0),
+			e2: -E2(self.e1i.0 * rhs.e3o.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.eoi.0 * rhs.e13.0),
+			e3: E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0) + E3(self.eoi.0 * rhs.e12.0),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0),
+			e12o: E12o(self.eoi.0 * rhs.e3o.0),
+			e12i: E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.eoi.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0),
+			e13o: -E13o(self.eoi.0 * rhs.e2o.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e3i.0 * rhs.e23.0)
+				- E13i(self.eoi.0 * rhs.e2i.0),
+			e1oi: -E1oi(self.e2i.0 * rhs.e3o.0) + E1oi(self.e3i.0 * rhs.e2o.0),
+			e23o: E23o(self.eoi.0 * rhs.e1o.0),
+			e23i: -E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e1i.0 * rhs.e3o.0) - E2oi(self.e3i.0 * rhs.e1o.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e2o.0) + E3oi(self.e2i.0 * rhs.e1o.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// FlatPoint.dot(PointPair) -> S
+impl Dot<PointPair> for FlatPoint {
+	type Output = S;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+impl std::ops::BitOr<PointPair> for FlatPoint {
+	type Output = S;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// FlatPoint.wedge(PointPair) -> Plane
+impl Wedge<PointPair> for FlatPoint {
+	type Output = Plane;
+	fn wedge(self, rhs: PointPair) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Plane {
+			e123i: self.e1i.wedge(rhs.e23) + self.e2i.wedge(rhs.e13) + self.e3i.wedge(rhs.e12),
+			e12oi: self.e1i.wedge(rhs.e2o) + self.e2i.wedge(rhs.e1o) + self.eoi.wedge(rhs.e12),
+			e13oi: self.e1i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e1o) + self.eoi.wedge(rhs.e13),
+			e23oi: self.e2i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e2o) + self.eoi.wedge(rhs.e23),
+		}
+	}
+}
+
+impl std::ops::BitXor<PointPair> for FlatPoint {
+	type Output = Plane;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<PointPair> for FlatPoint {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for FlatPoint {
+	type Output = Zero;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// FlatPoint.left_contraction(PointPair) -> S
+impl LeftContraction<PointPair> for FlatPoint {
+	type Output = S;
+	fn left_contraction(self, rhs: PointPair) -> Self::Output {
+		// S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// FlatPoint.right_contraction(PointPair) -> S
+impl RightContraction<PointPair> for FlatPoint {
+	type Output = S;
+	fn right_contraction(self, rhs: PointPair) -> Self::Output {
+		// S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// FlatPoint.scalar_product(PointPair) -> S
+impl ScalarProduct<PointPair> for FlatPoint {
+	type Output = S;
+	fn scalar_product(self, rhs: PointPair) -> Self::Output {
+		// S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// FlatPoint.commutator(PointPair) -> PointPair
+impl Commutator<PointPair> for FlatPoint {
+	type Output = PointPair;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+		//     e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+		//     e1o: -E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i: -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23: E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+		//     e2o: -E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o: -E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi: Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+		// }
+		PointPair {
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+			e1o: -E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+			e2o: -E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+		}
+	}
+}
+
+// FlatPoint.anti_dot(PointPair) -> E123oi
+impl AntiDot<PointPair> for FlatPoint {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.eoi.0 * rhs.eoi.0)
+		-E123oi(self.e1i.0 * rhs.e1o.0)
+			- E123oi(self.e2i.0 * rhs.e2o.0)
+			- E123oi(self.e3i.0 * rhs.e3o.0)
+			- E123oi(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP Line:
+
+// FlatPoint.geometric(Line) -> Multivector
+impl Geometric<Line> for FlatPoint {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.eoi.0 * rhs.e1oi.0),
+			e2: E2(self.eoi.0 * rhs.e2oi.0),
+			e3: E3(self.eoi.0 * rhs.e3oi.0),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) + E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for FlatPoint {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(Line) -> Versor
+impl AntiGeometric<Line> for FlatPoint {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0) - E1i(self.eoi.0 * rhs.e23i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e3i.0 * rhs.e1oi.0) + E2i(self.eoi.0 * rhs.e13i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e2oi.0) + E3i(self.e2i.0 * rhs.e1oi.0) - E3i(self.eoi.0 * rhs.e12i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: -E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: -E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: -E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0) - E1i(self.eoi.0 * rhs.e23i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e3i.0 * rhs.e1oi.0) + E2i(self.eoi.0 * rhs.e13i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e2oi.0) + E3i(self.e2i.0 * rhs.e1oi.0) - E3i(self.eoi.0 * rhs.e12i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: -E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: -E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// FlatPoint.dot(Line) -> Point
+impl Dot<Line> for FlatPoint {
+	type Output = Point;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.eoi.0 * rhs.e1oi.0),
+		//     y  : E2(self.eoi.0 * rhs.e2oi.0),
+		//     z  : E3(self.eoi.0 * rhs.e3oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		// }
+		Point {
+			x: E1(self.eoi.0 * rhs.e1oi.0),
+			y: E2(self.eoi.0 * rhs.e2oi.0),
+			z: E3(self.eoi.0 * rhs.e3oi.0),
+			o: Default::default(),
+			inf: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for FlatPoint {
+	type Output = Point;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Line> for FlatPoint {
+	type Output = Zero;
+	fn wedge(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Line> for FlatPoint {
+	type Output = Zero;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Line> for FlatPoint {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Line> for FlatPoint {
+	type Output = Zero;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// FlatPoint.left_contraction(Line) -> Point
+impl LeftContraction<Line> for FlatPoint {
+	type Output = Point;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.eoi.0 * rhs.e1oi.0),
+		//     y  : E2(self.eoi.0 * rhs.e2oi.0),
+		//     z  : E3(self.eoi.0 * rhs.e3oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		// }
+		Point {
+			x: E1(self.eoi.0 * rhs.e1oi.0),
+			y: E2(self.eoi.0 * rhs.e2oi.0),
+			z: E3(self.eoi.0 * rhs.e3oi.0),
+			o: Default::default(),
+			inf: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl RightContraction<Line> for FlatPoint {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line> for FlatPoint {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.commutator(Line) -> Line
+impl Commutator<Line> for FlatPoint {
+	type Output = Line;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13i: E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi: Default::default(),
+		//     e23i: E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Line {
+			e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) + E12i(self.eoi.0 * rhs.e12i.0),
+			e13i: E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: Default::default(),
+			e23i: E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.anti_dot(Line) -> Plane
+impl AntiDot<Line> for FlatPoint {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: -E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: -E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Plane {
+			e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: -E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: -E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP Circle:
+
+// FlatPoint.geometric(Circle) -> Multivector
+impl Geometric<Circle> for FlatPoint {
+	type Output = Multivector;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0),
+		//     e12o  : -E12o(self.eoi.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : -E13o(self.eoi.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0),
+		//     e23o  : -E23o(self.eoi.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.eoi.0 * rhs.e123.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) + E3(self.eoi.0 * rhs.e3oi.0),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0),
+			e12o: -E12o(self.eoi.0 * rhs.e12o.0),
+			e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: -E13o(self.eoi.0 * rhs.e13o.0),
+			e13i: E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0),
+			e23o: -E23o(self.eoi.0 * rhs.e23o.0),
+			e23i: -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0)
+				- E123oi(self.e3i.0 * rhs.e12o.0)
+				+ E123oi(self.eoi.0 * rhs.e123.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for FlatPoint {
+	type Output = Multivector;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(Circle) -> Versor
+impl AntiGeometric<Circle> for FlatPoint {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.eoi.0 * rhs.e123.0),
+		//     e12  : -E12(self.e1i.0 * rhs.e13o.0) - E12(self.e2i.0 * rhs.e23o.0),
+		//     e13  : E13(self.e1i.0 * rhs.e12o.0) - E13(self.e3i.0 * rhs.e23o.0),
+		//     e1o  : -E1o(self.eoi.0 * rhs.e23o.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.e123.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0) - E1i(self.eoi.0 * rhs.e23i.0),
+		//     e23  : E23(self.e2i.0 * rhs.e12o.0) + E23(self.e3i.0 * rhs.e13o.0),
+		//     e2o  : E2o(self.eoi.0 * rhs.e13o.0),
+		//     e2i  : E2i(self.e1i.0 * rhs.e3oi.0) + E2i(self.e2i.0 * rhs.e123.0) - E2i(self.e3i.0 * rhs.e1oi.0) + E2i(self.eoi.0 * rhs.e13i.0),
+		//     e3o  : -E3o(self.eoi.0 * rhs.e12o.0),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e2oi.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.e3i.0 * rhs.e123.0) - E3i(self.eoi.0 * rhs.e12i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e23o.0) - Eoi(self.e2i.0 * rhs.e13o.0) + Eoi(self.e3i.0 * rhs.e12o.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: -E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Versor {
+			s: -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0)
+				+ S(self.eoi.0 * rhs.e123.0),
+			e12: -E12(self.e1i.0 * rhs.e13o.0) - E12(self.e2i.0 * rhs.e23o.0),
+			e13: E13(self.e1i.0 * rhs.e12o.0) - E13(self.e3i.0 * rhs.e23o.0),
+			e1o: -E1o(self.eoi.0 * rhs.e23o.0),
+			e1i: E1i(self.e1i.0 * rhs.e123.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0)
+				- E1i(self.eoi.0 * rhs.e23i.0),
+			e23: E23(self.e2i.0 * rhs.e12o.0) + E23(self.e3i.0 * rhs.e13o.0),
+			e2o: E2o(self.eoi.0 * rhs.e13o.0),
+			e2i: E2i(self.e1i.0 * rhs.e3oi.0) + E2i(self.e2i.0 * rhs.e123.0) - E2i(self.e3i.0 * rhs.e1oi.0)
+				+ E2i(self.eoi.0 * rhs.e13i.0),
+			e3o: -E3o(self.eoi.0 * rhs.e12o.0),
+			e3i: -E3i(self.e1i.0 * rhs.e2oi.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.e3i.0 * rhs.e123.0)
+				- E3i(self.eoi.0 * rhs.e12i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e23o.0) - Eoi(self.e2i.0 * rhs.e13o.0) + Eoi(self.e3i.0 * rhs.e12o.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: -E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// FlatPoint.dot(Circle) -> Point
+impl Dot<Circle> for FlatPoint {
+	type Output = Point;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     y  : -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     z  : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		// }
+		Point {
+			x: E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.eoi.0 * rhs.e1oi.0),
+			y: -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.eoi.0 * rhs.e2oi.0),
+			z: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) + E3(self.eoi.0 * rhs.e3oi.0),
+			o: Default::default(),
+			inf: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Circle> for FlatPoint {
+	type Output = Point;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// FlatPoint.wedge(Circle) -> E123oi
+impl Wedge<Circle> for FlatPoint {
+	type Output = E123oi;
+	fn wedge(self, rhs: Circle) -> Self::Output {
+		// -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.eoi.0 * rhs.e123.0)
+		self.e1i.wedge(rhs.e23o) + self.e2i.wedge(rhs.e13o) + self.e3i.wedge(rhs.e12o) + self.eoi.wedge(rhs.e123)
+	}
+}
+
+impl std::ops::BitXor<Circle> for FlatPoint {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// FlatPoint.anti_wedge(Circle) -> S
+impl AntiWedge<Circle> for FlatPoint {
+	type Output = S;
+	fn anti_wedge(self, rhs: Circle) -> Self::Output {
+		// -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.eoi.0 * rhs.e123.0)
+		self.e1i.anti_wedge(rhs.e23o)
+			+ self.e2i.anti_wedge(rhs.e13o)
+			+ self.e3i.anti_wedge(rhs.e12o)
+			+ self.eoi.anti_wedge(rhs.e123)
+	}
+}
+
+impl std::ops::BitAnd<Circle> for FlatPoint {
+	type Output = S;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// FlatPoint.left_contraction(Circle) -> Point
+impl LeftContraction<Circle> for FlatPoint {
+	type Output = Point;
+	fn left_contraction(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     y  : -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     z  : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		// }
+		Point {
+			x: E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.eoi.0 * rhs.e1oi.0),
+			y: -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.eoi.0 * rhs.e2oi.0),
+			z: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) + E3(self.eoi.0 * rhs.e3oi.0),
+			o: Default::default(),
+			inf: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl RightContraction<Circle> for FlatPoint {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Circle> for FlatPoint {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.commutator(Circle) -> Circle
+impl Commutator<Circle> for FlatPoint {
+	type Output = Circle;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0),
+		//     e12o: -E12o(self.eoi.0 * rhs.e12o.0),
+		//     e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o: -E13o(self.eoi.0 * rhs.e13o.0),
+		//     e13i: E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi: E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0),
+		//     e23o: -E23o(self.eoi.0 * rhs.e23o.0),
+		//     e23i: -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi: -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0),
+		//     e3oi: -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0),
+		// }
+		Circle {
+			e123: -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0),
+			e12o: -E12o(self.eoi.0 * rhs.e12o.0),
+			e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: -E13o(self.eoi.0 * rhs.e13o.0),
+			e13i: E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0),
+			e23o: -E23o(self.eoi.0 * rhs.e23o.0),
+			e23i: -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0),
+		}
+	}
+}
+
+// FlatPoint.anti_dot(Circle) -> Plane
+impl AntiDot<Circle> for FlatPoint {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: -E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Plane {
+			e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: -E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP Plane:
+
+// FlatPoint.geometric(Plane) -> Versor
+impl Geometric<Plane> for FlatPoint {
+	type Output = Versor;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for FlatPoint {
+	type Output = Versor;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for FlatPoint {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : -E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: -E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.dot(Plane) -> PointPair
+impl Dot<Plane> for FlatPoint {
+	type Output = PointPair;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13: E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23: E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for FlatPoint {
+	type Output = PointPair;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for FlatPoint {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for FlatPoint {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// FlatPoint.anti_wedge(Plane) -> Ei
+impl AntiWedge<Plane> for FlatPoint {
+	type Output = Ei;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0)
+		self.e1i.anti_wedge(rhs.e23oi)
+			+ self.e2i.anti_wedge(rhs.e13oi)
+			+ self.e3i.anti_wedge(rhs.e12oi)
+			+ self.eoi.anti_wedge(rhs.e123i)
+	}
+}
+
+impl std::ops::BitAnd<Plane> for FlatPoint {
+	type Output = Ei;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// FlatPoint.left_contraction(Plane) -> PointPair
+impl LeftContraction<Plane> for FlatPoint {
+	type Output = PointPair;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13: E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23: E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Plane> for FlatPoint {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for FlatPoint {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.commutator(Plane) -> E123i
+impl Commutator<Plane> for FlatPoint {
+	type Output = E123i;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0)
+		-E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+			+ E123i(self.eoi.0 * rhs.e123i.0)
+	}
+}
+
+// FlatPoint.anti_dot(Plane) -> Line
+impl AntiDot<Plane> for FlatPoint {
+	type Output = Line;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi: E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23i: -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi: -E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi: E3oi(self.eoi.0 * rhs.e12oi.0),
+		// }
+		Line {
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23i: -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: -E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.eoi.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP Sphere:
+
+// FlatPoint.geometric(Sphere) -> Versor
+impl Geometric<Sphere> for FlatPoint {
+	type Output = Versor;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : Default::default(),
+		//     e123o: -E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+		//     e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+		//     e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+			e123o: -E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+			e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for FlatPoint {
+	type Output = Versor;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(Sphere) -> Multivector
+impl AntiGeometric<Sphere> for FlatPoint {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e1i.0 * rhs.e123o.0),
+		//     e2    : -E2(self.e2i.0 * rhs.e123o.0),
+		//     e3    : -E3(self.e3i.0 * rhs.e123o.0),
+		//     eo    : -Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e1i.0 * rhs.e123o.0),
+			e2: -E2(self.e2i.0 * rhs.e123o.0),
+			e3: -E3(self.e3i.0 * rhs.e123o.0),
+			eo: -Eo(self.eoi.0 * rhs.e123o.0),
+			ei: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.dot(Sphere) -> PointPair
+impl Dot<Sphere> for FlatPoint {
+	type Output = PointPair;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Sphere> for FlatPoint {
+	type Output = PointPair;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Sphere> for FlatPoint {
+	type Output = Zero;
+	fn wedge(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Sphere> for FlatPoint {
+	type Output = Zero;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// FlatPoint.anti_wedge(Sphere) -> Point
+impl AntiWedge<Sphere> for FlatPoint {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e1i.0 * rhs.e123o.0),
+		//     y  : -E2(self.e2i.0 * rhs.e123o.0),
+		//     z  : -E3(self.e3i.0 * rhs.e123o.0),
+		//     o  : -Eo(self.eoi.0 * rhs.e123o.0),
+		//     inf: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		// }
+		Point {
+			x: self.e1i.anti_wedge(rhs.e123o),
+			y: self.e2i.anti_wedge(rhs.e123o),
+			z: self.e3i.anti_wedge(rhs.e123o),
+			o: self.eoi.anti_wedge(rhs.e123o),
+			inf: self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for FlatPoint {
+	type Output = Point;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// FlatPoint.left_contraction(Sphere) -> PointPair
+impl LeftContraction<Sphere> for FlatPoint {
+	type Output = PointPair;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Sphere> for FlatPoint {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Sphere> for FlatPoint {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.commutator(Sphere) -> Sphere
+impl Commutator<Sphere> for FlatPoint {
+	type Output = Sphere;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+		//     e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+		//     e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+			e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+		}
+	}
+}
+
+// FlatPoint.anti_dot(Sphere) -> Line
+impl AntiDot<Sphere> for FlatPoint {
+	type Output = Line;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23i: -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi: E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		// }
+		Line {
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23i: -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP Rotor:
+
+// FlatPoint.geometric(Rotor) -> Versor
+impl Geometric<Rotor> for FlatPoint {
+	type Output = Versor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) + E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+			e12oi: E12oi(self.eoi.0 * rhs.e12.0),
+			e13oi: E13oi(self.eoi.0 * rhs.e13.0),
+			e23oi: E23oi(self.eoi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for FlatPoint {
+	type Output = Versor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(Rotor) -> Multivector
+impl AntiGeometric<Rotor> for FlatPoint {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.eoi.0 * rhs.e23.0),
+		//     e2    : -E2(self.eoi.0 * rhs.e13.0),
+		//     e3    : E3(self.eoi.0 * rhs.e12.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.eoi.0 * rhs.e23.0),
+			e2: -E2(self.eoi.0 * rhs.e13.0),
+			e3: E3(self.eoi.0 * rhs.e12.0),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.eoi.0 * rhs.s.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.e12.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.dot(Rotor) -> FlatPoint
+impl Dot<Rotor> for FlatPoint {
+	type Output = FlatPoint;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.e1i.0 * rhs.s.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0),
+		//     eoi: Eoi(self.eoi.0 * rhs.s.0),
+		// }
+		FlatPoint {
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for FlatPoint {
+	type Output = FlatPoint;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// FlatPoint.wedge(Rotor) -> Versor
+impl Wedge<Rotor> for FlatPoint {
+	type Output = Versor;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: self.e1i.wedge(rhs.s),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: self.e2i.wedge(rhs.s),
+			e3o: Default::default(),
+			e3i: self.e3i.wedge(rhs.s),
+			eoi: self.eoi.wedge(rhs.s),
+			e123o: Default::default(),
+			e123i: self.e1i.wedge(rhs.e23) + self.e2i.wedge(rhs.e13) + self.e3i.wedge(rhs.e12),
+			e12oi: self.eoi.wedge(rhs.e12),
+			e13oi: self.eoi.wedge(rhs.e13),
+			e23oi: self.eoi.wedge(rhs.e23),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for FlatPoint {
+	type Output = Versor;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Rotor> for FlatPoint {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for FlatPoint {
+	type Output = Zero;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Rotor> for FlatPoint {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.right_contraction(Rotor) -> FlatPoint
+impl RightContraction<Rotor> for FlatPoint {
+	type Output = FlatPoint;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.e1i.0 * rhs.s.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0),
+		//     eoi: Eoi(self.eoi.0 * rhs.s.0),
+		// }
+		FlatPoint {
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for FlatPoint {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.commutator(Rotor) -> FlatPoint
+impl Commutator<Rotor> for FlatPoint {
+	type Output = FlatPoint;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// FlatPoint {
+		//     e1i: -E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0),
+		//     eoi: Default::default(),
+		// }
+		FlatPoint {
+			e1i: -E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e3i.0 * rhs.e23.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.anti_dot(Rotor) -> Circle
+impl AntiDot<Rotor> for FlatPoint {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.eoi.0 * rhs.s.0),
+		//     e12o: Default::default(),
+		//     e12i: -E12i(self.e3i.0 * rhs.s.0),
+		//     e13o: Default::default(),
+		//     e13i: E13i(self.e2i.0 * rhs.s.0),
+		//     e1oi: Default::default(),
+		//     e23o: Default::default(),
+		//     e23i: -E23i(self.e1i.0 * rhs.s.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Circle {
+			e123: -E123(self.eoi.0 * rhs.s.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.e3i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e2i.0 * rhs.s.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.s.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP Translator:
+
+// FlatPoint.geometric(Translator) -> FlatPoint
+impl Geometric<Translator> for FlatPoint {
+	type Output = FlatPoint;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.e1i.0 * rhs.s.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi: Eoi(self.eoi.0 * rhs.s.0),
+		// }
+		FlatPoint {
+			e1i: E1i(self.e1i.0 * rhs.s.0) + E1i(self.eoi.0 * rhs.e1i.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0) + E2i(self.eoi.0 * rhs.e2i.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0) + E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for FlatPoint {
+	type Output = FlatPoint;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(Translator) -> Circle
+impl AntiGeometric<Translator> for FlatPoint {
+	type Output = Circle;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.eoi.0 * rhs.s.0),
+		//     e12o: Default::default(),
+		//     e12i: -E12i(self.e3i.0 * rhs.s.0) + E12i(self.eoi.0 * rhs.e3i.0),
+		//     e13o: Default::default(),
+		//     e13i: E13i(self.e2i.0 * rhs.s.0) - E13i(self.eoi.0 * rhs.e2i.0),
+		//     e1oi: Default::default(),
+		//     e23o: Default::default(),
+		//     e23i: -E23i(self.e1i.0 * rhs.s.0) + E23i(self.eoi.0 * rhs.e1i.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Circle {
+			e123: -E123(self.eoi.0 * rhs.s.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.e3i.0 * rhs.s.0) + E12i(self.eoi.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e2i.0 * rhs.s.0) - E13i(self.eoi.0 * rhs.e2i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.s.0) + E23i(self.eoi.0 * rhs.e1i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.dot(Translator) -> FlatPoint
+impl Dot<Translator> for FlatPoint {
+	type Output = FlatPoint;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.e1i.0 * rhs.s.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0),
+		//     eoi: Eoi(self.eoi.0 * rhs.s.0),
+		// }
+		FlatPoint {
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for FlatPoint {
+	type Output = FlatPoint;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// FlatPoint.wedge(Translator) -> FlatPoint
+impl Wedge<Translator> for FlatPoint {
+	type Output = FlatPoint;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.e1i.0 * rhs.s.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0),
+		//     eoi: Eoi(self.eoi.0 * rhs.s.0),
+		// }
+		FlatPoint {
+			e1i: self.e1i.wedge(rhs.s),
+			e2i: self.e2i.wedge(rhs.s),
+			e3i: self.e3i.wedge(rhs.s),
+			eoi: self.eoi.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for FlatPoint {
+	type Output = FlatPoint;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Translator> for FlatPoint {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for FlatPoint {
+	type Output = Zero;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Translator> for FlatPoint {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.right_contraction(Translator) -> FlatPoint
+impl RightContraction<Translator> for FlatPoint {
+	type Output = FlatPoint;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.e1i.0 * rhs.s.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0),
+		//     eoi: Eoi(self.eoi.0 * rhs.s.0),
+		// }
+		FlatPoint {
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for FlatPoint {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// FlatPoint.commutator(Translator) -> FlatPoint
+impl Commutator<Translator> for FlatPoint {
+	type Output = FlatPoint;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.eoi.0 * rhs.e1i.0),
+		//     e2i: E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3i: E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi: Default::default(),
+		// }
+		FlatPoint {
+			e1i: E1i(self.eoi.0 * rhs.e1i.0),
+			e2i: E2i(self.eoi.0 * rhs.e2i.0),
+			e3i: E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.anti_dot(Translator) -> Circle
+impl AntiDot<Translator> for FlatPoint {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.eoi.0 * rhs.s.0),
+		//     e12o: Default::default(),
+		//     e12i: -E12i(self.e3i.0 * rhs.s.0),
+		//     e13o: Default::default(),
+		//     e13i: E13i(self.e2i.0 * rhs.s.0),
+		//     e1oi: Default::default(),
+		//     e23o: Default::default(),
+		//     e23i: -E23i(self.e1i.0 * rhs.s.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Circle {
+			e123: -E123(self.eoi.0 * rhs.s.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.e3i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e2i.0 * rhs.s.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.s.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP Versor:
+
+// FlatPoint.geometric(Versor) -> Versor
+impl Geometric<Versor> for FlatPoint {
+	type Output = Versor;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : E13(self.e1i.0 * rhs.e3o.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e3i.0 * rhs.e1o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : -E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i  : -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) + E1i(self.e2i.0 * rhs.e12oi.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : -E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e2i.0 * rhs.e23oi.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: -E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e2i.0 * rhs.e13oi.0) + E123i(self.e3i.0 * rhs.e12.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e3i.0 * rhs.e123o.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e2i.0 * rhs.e123o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e1i.0 * rhs.e123o.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e3i.0 * rhs.e1o.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: -E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) - E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) + E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123o: -E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) - E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e2i.0 * rhs.e13oi.0)
+				+ E123i(self.e3i.0 * rhs.e12.0)
+				- E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0)
+				+ E12oi(self.e3i.0 * rhs.e123o.0)
+				+ E12oi(self.eoi.0 * rhs.e12.0),
+			e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e2i.0 * rhs.e123o.0) - E13oi(self.e3i.0 * rhs.e1o.0)
+				+ E13oi(self.eoi.0 * rhs.e13.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0)
+				+ E23oi(self.eoi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for FlatPoint {
+	type Output = Versor;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(Versor) -> Multivector
+impl AntiGeometric<Versor> for FlatPoint {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e1i.0 * rhs.e123o.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.eoi.0 * rhs.e23.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e3o.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.eoi.0 * rhs.e13.0),
+		//     e3    : E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.eoi.0 * rhs.e12.0),
+		//     eo    : -Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : E12o(self.eoi.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.e3i.0 * rhs.s.0) + E12i(self.eoi.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.eoi.0 * rhs.e2o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0) + E13i(self.e3i.0 * rhs.e23oi.0) - E13i(self.eoi.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) - E1oi(self.e2i.0 * rhs.e3o.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : E23o(self.eoi.0 * rhs.e1o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13.0) - E23i(self.e3i.0 * rhs.e13oi.0) + E23i(self.eoi.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e2o.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e1i.0 * rhs.e123o.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0)
+				+ E1(self.eoi.0 * rhs.e23.0),
+			e2: -E2(self.e1i.0 * rhs.e3o.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e3i.0 * rhs.e1o.0)
+				- E2(self.eoi.0 * rhs.e13.0),
+			e3: E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0) - E3(self.e3i.0 * rhs.e123o.0)
+				+ E3(self.eoi.0 * rhs.e12.0),
+			eo: -Eo(self.eoi.0 * rhs.e123o.0),
+			ei: Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0)
+				- Ei(self.e2i.0 * rhs.e13.0)
+				- Ei(self.e2i.0 * rhs.e13oi.0)
+				+ Ei(self.e3i.0 * rhs.e12.0)
+				+ Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.eoi.0 * rhs.s.0),
+			e12o: E12o(self.eoi.0 * rhs.e3o.0),
+			e12i: E12i(self.e1i.0 * rhs.e13.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				+ E12i(self.e2i.0 * rhs.e23.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0),
+			e13o: -E13o(self.eoi.0 * rhs.e2o.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				- E13i(self.eoi.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) - E1oi(self.e2i.0 * rhs.e3o.0)
+				+ E1oi(self.e3i.0 * rhs.e2o.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: E23o(self.eoi.0 * rhs.e1o.0),
+			e23i: -E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e2i.0 * rhs.e123o.0)
+				- E2oi(self.e3i.0 * rhs.e1o.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e2o.0)
+				+ E3oi(self.e2i.0 * rhs.e1o.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// FlatPoint.dot(Versor) -> Versor
+impl Dot<Versor> for FlatPoint {
+	type Output = Versor;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e1i.0 * rhs.s.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for FlatPoint {
+	type Output = Versor;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// FlatPoint.wedge(Versor) -> Versor
+impl Wedge<Versor> for FlatPoint {
+	type Output = Versor;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: self.e1i.wedge(rhs.s),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: self.e2i.wedge(rhs.s),
+			e3o: Default::default(),
+			e3i: self.e3i.wedge(rhs.s),
+			eoi: self.eoi.wedge(rhs.s),
+			e123o: Default::default(),
+			e123i: self.e1i.wedge(rhs.e23) + self.e2i.wedge(rhs.e13) + self.e3i.wedge(rhs.e12),
+			e12oi: self.e1i.wedge(rhs.e2o) + self.e2i.wedge(rhs.e1o) + self.eoi.wedge(rhs.e12),
+			e13oi: self.e1i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e1o) + self.eoi.wedge(rhs.e13),
+			e23oi: self.e2i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e2o) + self.eoi.wedge(rhs.e23),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for FlatPoint {
+	type Output = Versor;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// FlatPoint.anti_wedge(Versor) -> Point
+impl AntiWedge<Versor> for FlatPoint {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e1i.0 * rhs.e123o.0),
+		//     y  : -E2(self.e2i.0 * rhs.e123o.0),
+		//     z  : -E3(self.e3i.0 * rhs.e123o.0),
+		//     o  : -Eo(self.eoi.0 * rhs.e123o.0),
+		//     inf: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		// }
+		Point {
+			x: self.e1i.anti_wedge(rhs.e123o),
+			y: self.e2i.anti_wedge(rhs.e123o),
+			z: self.e3i.anti_wedge(rhs.e123o),
+			o: self.eoi.anti_wedge(rhs.e123o),
+			inf: self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Versor> for FlatPoint {
+	type Output = Point;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// FlatPoint.left_contraction(Versor) -> Versor
+impl LeftContraction<Versor> for FlatPoint {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.right_contraction(Versor) -> Versor
+impl RightContraction<Versor> for FlatPoint {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.scalar_product(Versor) -> S
+impl ScalarProduct<Versor> for FlatPoint {
+	type Output = S;
+	fn scalar_product(self, rhs: Versor) -> Self::Output {
+		// S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// FlatPoint.commutator(Versor) -> Versor
+impl Commutator<Versor> for FlatPoint {
+	type Output = Versor;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+		//     e13  : E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+		//     e1o  : -E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i  : -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+		//     e2o  : -E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+		//     e123o: -E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+		//     e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+		//     e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+			e1o: -E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+			e2o: -E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+			e123o: -E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+			e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+		}
+	}
+}
+
+// FlatPoint.anti_dot(Versor) -> Multivector
+impl AntiDot<Versor> for FlatPoint {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.eoi.0 * rhs.s.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// FlatPoint OP Multivector:
+
+// FlatPoint.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e1    : -E1(self.e1i.0 * rhs.eo.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e12o.0) - E2(self.e2i.0 * rhs.eo.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e3i.0 * rhs.eo.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.eoi.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e2i.0 * rhs.e2.0) + Ei(self.e2i.0 * rhs.e2oi.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.e3i.0 * rhs.e3oi.0) + Ei(self.eoi.0 * rhs.ei.0),
+		//     e12   : E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13   : E13(self.e1i.0 * rhs.e3o.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e3i.0 * rhs.e1o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o   : -E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i   : -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) + E1i(self.e2i.0 * rhs.e12oi.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23   : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o   : -E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i   : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i   : E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e2i.0 * rhs.e23oi.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi   : Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123  : -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0) + E123(self.eoi.0 * rhs.e123oi.0),
+		//     e12o  : -E12o(self.eoi.0 * rhs.e12o.0),
+		//     e12i  : -E12i(self.e1i.0 * rhs.e2.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e2i.0 * rhs.e1.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : -E13o(self.eoi.0 * rhs.e13o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e3.0) + E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.e3i.0 * rhs.e1.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.eo.0) + E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0) + E1oi(self.eoi.0 * rhs.e1.0),
+		//     e23o  : -E23o(self.eoi.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e1i.0 * rhs.e123oi.0) - E23i(self.e2i.0 * rhs.e3.0) + E23i(self.e2i.0 * rhs.e3oi.0) + E23i(self.e3i.0 * rhs.e2.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e1i.0 * rhs.e12o.0) - E2oi(self.e2i.0 * rhs.eo.0) + E2oi(self.e3i.0 * rhs.e23o.0) + E2oi(self.eoi.0 * rhs.e2.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0) - E3oi(self.e3i.0 * rhs.eo.0) + E3oi(self.eoi.0 * rhs.e3.0),
+		//     e123o : -E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i : E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e2i.0 * rhs.e13oi.0) + E123i(self.e3i.0 * rhs.e12.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e3i.0 * rhs.e123o.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi : E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e2i.0 * rhs.e123o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi : E23oi(self.e1i.0 * rhs.e123o.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.eoi.0 * rhs.e123.0),
+		// }
+		Multivector {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e1: -E1(self.e1i.0 * rhs.eo.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e1i.0 * rhs.e12o.0) - E2(self.e2i.0 * rhs.eo.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e3i.0 * rhs.eo.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.eoi.0 * rhs.eo.0),
+			ei: -Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e2i.0 * rhs.e2.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				- Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0)
+				+ Ei(self.eoi.0 * rhs.ei.0),
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e3i.0 * rhs.e1o.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: -E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) - E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) + E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123: -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0)
+				+ E123(self.eoi.0 * rhs.e123oi.0),
+			e12o: -E12o(self.eoi.0 * rhs.e12o.0),
+			e12i: -E12i(self.e1i.0 * rhs.e2.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e2i.0 * rhs.e1.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				- E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.e3i.0 * rhs.e123oi.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: -E13o(self.eoi.0 * rhs.e13o.0),
+			e13i: -E13i(self.e1i.0 * rhs.e3.0) + E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0)
+				- E13i(self.e2i.0 * rhs.e123oi.0)
+				+ E13i(self.e3i.0 * rhs.e1.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: -E1oi(self.e1i.0 * rhs.eo.0)
+				+ E1oi(self.e2i.0 * rhs.e12o.0)
+				+ E1oi(self.e3i.0 * rhs.e13o.0)
+				+ E1oi(self.eoi.0 * rhs.e1.0),
+			e23o: -E23o(self.eoi.0 * rhs.e23o.0),
+			e23i: -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e1i.0 * rhs.e123oi.0) - E23i(self.e2i.0 * rhs.e3.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				+ E23i(self.e3i.0 * rhs.e2.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e1i.0 * rhs.e12o.0) - E2oi(self.e2i.0 * rhs.eo.0)
+				+ E2oi(self.e3i.0 * rhs.e23o.0)
+				+ E2oi(self.eoi.0 * rhs.e2.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0) - E3oi(self.e3i.0 * rhs.eo.0)
+				+ E3oi(self.eoi.0 * rhs.e3.0),
+			e123o: -E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) - E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e2i.0 * rhs.e13oi.0)
+				+ E123i(self.e3i.0 * rhs.e12.0)
+				- E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0)
+				+ E12oi(self.e3i.0 * rhs.e123o.0)
+				+ E12oi(self.eoi.0 * rhs.e12.0),
+			e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e2i.0 * rhs.e123o.0) - E13oi(self.e3i.0 * rhs.e1o.0)
+				+ E13oi(self.eoi.0 * rhs.e13.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0)
+				+ E23oi(self.eoi.0 * rhs.e23.0),
+			e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0)
+				- E123oi(self.e3i.0 * rhs.e12o.0)
+				+ E123oi(self.eoi.0 * rhs.e123.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// FlatPoint.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.eoi.0 * rhs.e123.0),
+		//     e1    : -E1(self.e1i.0 * rhs.e123o.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.eoi.0 * rhs.e23.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e3o.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.eoi.0 * rhs.e13.0),
+		//     e3    : E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.eoi.0 * rhs.e12.0),
+		//     eo    : -Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : -E12(self.e1i.0 * rhs.e13o.0) - E12(self.e2i.0 * rhs.e23o.0) + E12(self.e3i.0 * rhs.eo.0) - E12(self.eoi.0 * rhs.e3.0),
+		//     e13   : E13(self.e1i.0 * rhs.e12o.0) - E13(self.e2i.0 * rhs.eo.0) - E13(self.e3i.0 * rhs.e23o.0) + E13(self.eoi.0 * rhs.e2.0),
+		//     e1o   : -E1o(self.eoi.0 * rhs.e23o.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.e123.0) + E1i(self.e1i.0 * rhs.e123oi.0) - E1i(self.e2i.0 * rhs.e3.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2.0) + E1i(self.e3i.0 * rhs.e2oi.0) - E1i(self.eoi.0 * rhs.e23i.0),
+		//     e23   : E23(self.e1i.0 * rhs.eo.0) + E23(self.e2i.0 * rhs.e12o.0) + E23(self.e3i.0 * rhs.e13o.0) - E23(self.eoi.0 * rhs.e1.0),
+		//     e2o   : E2o(self.eoi.0 * rhs.e13o.0),
+		//     e2i   : E2i(self.e1i.0 * rhs.e3.0) + E2i(self.e1i.0 * rhs.e3oi.0) + E2i(self.e2i.0 * rhs.e123.0) + E2i(self.e2i.0 * rhs.e123oi.0) - E2i(self.e3i.0 * rhs.e1.0) - E2i(self.e3i.0 * rhs.e1oi.0) + E2i(self.eoi.0 * rhs.e13i.0),
+		//     e3o   : -E3o(self.eoi.0 * rhs.e12o.0),
+		//     e3i   : -E3i(self.e1i.0 * rhs.e2.0) - E3i(self.e1i.0 * rhs.e2oi.0) + E3i(self.e2i.0 * rhs.e1.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.e3i.0 * rhs.e123.0) + E3i(self.e3i.0 * rhs.e123oi.0) - E3i(self.eoi.0 * rhs.e12i.0),
+		//     eoi   : Eoi(self.e1i.0 * rhs.e23o.0) - Eoi(self.e2i.0 * rhs.e13o.0) + Eoi(self.e3i.0 * rhs.e12o.0) + Eoi(self.eoi.0 * rhs.e123oi.0),
+		//     e123  : E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : E12o(self.eoi.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.e3i.0 * rhs.s.0) + E12i(self.eoi.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.eoi.0 * rhs.e2o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0) + E13i(self.e3i.0 * rhs.e23oi.0) - E13i(self.eoi.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) - E1oi(self.e2i.0 * rhs.e3o.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : E23o(self.eoi.0 * rhs.e1o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13.0) - E23i(self.e3i.0 * rhs.e13oi.0) + E23i(self.eoi.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e2o.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : E123o(self.eoi.0 * rhs.eo.0),
+		//     e123i : -E123i(self.e1i.0 * rhs.e1.0) - E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3.0) - E123i(self.e3i.0 * rhs.e3oi.0) + E123i(self.eoi.0 * rhs.ei.0),
+		//     e12oi : E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0) - E12oi(self.e3i.0 * rhs.eo.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi : -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e2i.0 * rhs.eo.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi : -E23oi(self.e1i.0 * rhs.eo.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0)
+				+ S(self.eoi.0 * rhs.e123.0),
+			e1: -E1(self.e1i.0 * rhs.e123o.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0)
+				+ E1(self.eoi.0 * rhs.e23.0),
+			e2: -E2(self.e1i.0 * rhs.e3o.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e3i.0 * rhs.e1o.0)
+				- E2(self.eoi.0 * rhs.e13.0),
+			e3: E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0) - E3(self.e3i.0 * rhs.e123o.0)
+				+ E3(self.eoi.0 * rhs.e12.0),
+			eo: -Eo(self.eoi.0 * rhs.e123o.0),
+			ei: Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0)
+				- Ei(self.e2i.0 * rhs.e13.0)
+				- Ei(self.e2i.0 * rhs.e13oi.0)
+				+ Ei(self.e3i.0 * rhs.e12.0)
+				+ Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0),
+			e12: -E12(self.e1i.0 * rhs.e13o.0) - E12(self.e2i.0 * rhs.e23o.0) + E12(self.e3i.0 * rhs.eo.0)
+				- E12(self.eoi.0 * rhs.e3.0),
+			e13: E13(self.e1i.0 * rhs.e12o.0) - E13(self.e2i.0 * rhs.eo.0) - E13(self.e3i.0 * rhs.e23o.0)
+				+ E13(self.eoi.0 * rhs.e2.0),
+			e1o: -E1o(self.eoi.0 * rhs.e23o.0),
+			e1i: E1i(self.e1i.0 * rhs.e123.0) + E1i(self.e1i.0 * rhs.e123oi.0)
+				- E1i(self.e2i.0 * rhs.e3.0)
+				- E1i(self.e2i.0 * rhs.e3oi.0)
+				+ E1i(self.e3i.0 * rhs.e2.0)
+				+ E1i(self.e3i.0 * rhs.e2oi.0)
+				- E1i(self.eoi.0 * rhs.e23i.0),
+			e23: E23(self.e1i.0 * rhs.eo.0) + E23(self.e2i.0 * rhs.e12o.0) + E23(self.e3i.0 * rhs.e13o.0)
+				- E23(self.eoi.0 * rhs.e1.0),
+			e2o: E2o(self.eoi.0 * rhs.e13o.0),
+			e2i: E2i(self.e1i.0 * rhs.e3.0)
+				+ E2i(self.e1i.0 * rhs.e3oi.0)
+				+ E2i(self.e2i.0 * rhs.e123.0)
+				+ E2i(self.e2i.0 * rhs.e123oi.0)
+				- E2i(self.e3i.0 * rhs.e1.0)
+				- E2i(self.e3i.0 * rhs.e1oi.0)
+				+ E2i(self.eoi.0 * rhs.e13i.0),
+			e3o: -E3o(self.eoi.0 * rhs.e12o.0),
+			e3i: -E3i(self.e1i.0 * rhs.e2.0) - E3i(self.e1i.0 * rhs.e2oi.0)
+				+ E3i(self.e2i.0 * rhs.e1.0)
+				+ E3i(self.e2i.0 * rhs.e1oi.0)
+				+ E3i(self.e3i.0 * rhs.e123.0)
+				+ E3i(self.e3i.0 * rhs.e123oi.0)
+				- E3i(self.eoi.0 * rhs.e12i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e23o.0) - Eoi(self.e2i.0 * rhs.e13o.0)
+				+ Eoi(self.e3i.0 * rhs.e12o.0)
+				+ Eoi(self.eoi.0 * rhs.e123oi.0),
+			e123: E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.eoi.0 * rhs.s.0),
+			e12o: E12o(self.eoi.0 * rhs.e3o.0),
+			e12i: E12i(self.e1i.0 * rhs.e13.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				+ E12i(self.e2i.0 * rhs.e23.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0),
+			e13o: -E13o(self.eoi.0 * rhs.e2o.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				- E13i(self.eoi.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) - E1oi(self.e2i.0 * rhs.e3o.0)
+				+ E1oi(self.e3i.0 * rhs.e2o.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: E23o(self.eoi.0 * rhs.e1o.0),
+			e23i: -E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e2i.0 * rhs.e123o.0)
+				- E2oi(self.e3i.0 * rhs.e1o.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e2o.0)
+				+ E3oi(self.e2i.0 * rhs.e1o.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: E123o(self.eoi.0 * rhs.eo.0),
+			e123i: -E123i(self.e1i.0 * rhs.e1.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				- E123i(self.e2i.0 * rhs.e2.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0)
+				+ E123i(self.eoi.0 * rhs.ei.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0)
+				- E12oi(self.e3i.0 * rhs.eo.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: -E13oi(self.e1i.0 * rhs.e12o.0)
+				+ E13oi(self.e2i.0 * rhs.eo.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: -E23oi(self.e1i.0 * rhs.eo.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// FlatPoint.dot(Multivector) -> Multivector
+impl Dot<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e1    : -E1(self.e1i.0 * rhs.eo.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e12o.0) - E2(self.e2i.0 * rhs.eo.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e3i.0 * rhs.eo.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.eoi.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e2i.0 * rhs.e2.0) + Ei(self.e2i.0 * rhs.e2oi.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.e3i.0 * rhs.e3oi.0) + Ei(self.eoi.0 * rhs.ei.0),
+		//     e12   : E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13   : -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o   : Default::default(),
+		//     e1i   : E1i(self.e1i.0 * rhs.s.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23   : E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o   : Default::default(),
+		//     e2i   : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o   : Default::default(),
+		//     e3i   : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi   : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123  : E123(self.eoi.0 * rhs.e123oi.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e3i.0 * rhs.e123oi.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e2i.0 * rhs.e123oi.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e1i.0 * rhs.e123oi.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e1: -E1(self.e1i.0 * rhs.eo.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e1i.0 * rhs.e12o.0) - E2(self.e2i.0 * rhs.eo.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e3i.0 * rhs.eo.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.eoi.0 * rhs.eo.0),
+			ei: -Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e2i.0 * rhs.e2.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				- Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0)
+				+ Ei(self.eoi.0 * rhs.ei.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e1i.0 * rhs.s.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123: E123(self.eoi.0 * rhs.e123oi.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e3i.0 * rhs.e123oi.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e2i.0 * rhs.e123oi.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e1i.0 * rhs.e123oi.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// FlatPoint.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : E1i(self.e1i.0 * rhs.s.0),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o   : Default::default(),
+		//     e3i   : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi   : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e1i.0 * rhs.e2.0) + E12i(self.e2i.0 * rhs.e1.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e3.0) + E13i(self.e3i.0 * rhs.e1.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.eo.0) + E1oi(self.eoi.0 * rhs.e1.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.e3.0) + E23i(self.e3i.0 * rhs.e2.0),
+		//     e2oi  : -E2oi(self.e2i.0 * rhs.eo.0) + E2oi(self.eoi.0 * rhs.e2.0),
+		//     e3oi  : -E3oi(self.e3i.0 * rhs.eo.0) + E3oi(self.eoi.0 * rhs.e3.0),
+		//     e123o : Default::default(),
+		//     e123i : E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi : E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi : E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi : E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.eoi.0 * rhs.e123.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: self.e1i.wedge(rhs.s),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: self.e2i.wedge(rhs.s),
+			e3o: Default::default(),
+			e3i: self.e3i.wedge(rhs.s),
+			eoi: self.eoi.wedge(rhs.s),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.e1i.wedge(rhs.e2) + self.e2i.wedge(rhs.e1),
+			e13o: Default::default(),
+			e13i: self.e1i.wedge(rhs.e3) + self.e3i.wedge(rhs.e1),
+			e1oi: self.e1i.wedge(rhs.eo) + self.eoi.wedge(rhs.e1),
+			e23o: Default::default(),
+			e23i: self.e2i.wedge(rhs.e3) + self.e3i.wedge(rhs.e2),
+			e2oi: self.e2i.wedge(rhs.eo) + self.eoi.wedge(rhs.e2),
+			e3oi: self.e3i.wedge(rhs.eo) + self.eoi.wedge(rhs.e3),
+			e123o: Default::default(),
+			e123i: self.e1i.wedge(rhs.e23) + self.e2i.wedge(rhs.e13) + self.e3i.wedge(rhs.e12),
+			e12oi: self.e1i.wedge(rhs.e2o) + self.e2i.wedge(rhs.e1o) + self.eoi.wedge(rhs.e12),
+			e13oi: self.e1i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e1o) + self.eoi.wedge(rhs.e13),
+			e23oi: self.e2i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e2o) + self.eoi.wedge(rhs.e23),
+			e123oi: self.e1i.wedge(rhs.e23o)
+				+ self.e2i.wedge(rhs.e13o)
+				+ self.e3i.wedge(rhs.e12o)
+				+ self.eoi.wedge(rhs.e123),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// FlatPoint.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.eoi.0 * rhs.e123.0),
+		//     e1    : -E1(self.e1i.0 * rhs.e123o.0),
+		//     e2    : -E2(self.e2i.0 * rhs.e123o.0),
+		//     e3    : -E3(self.e3i.0 * rhs.e123o.0),
+		//     eo    : -Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : E1i(self.e1i.0 * rhs.e123oi.0),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : E2i(self.e2i.0 * rhs.e123oi.0),
+		//     e3o   : Default::default(),
+		//     e3i   : E3i(self.e3i.0 * rhs.e123oi.0),
+		//     eoi   : Eoi(self.eoi.0 * rhs.e123oi.0),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.e1i.anti_wedge(rhs.e23o)
+				+ self.e2i.anti_wedge(rhs.e13o)
+				+ self.e3i.anti_wedge(rhs.e12o)
+				+ self.eoi.anti_wedge(rhs.e123),
+			e1: self.e1i.anti_wedge(rhs.e123o),
+			e2: self.e2i.anti_wedge(rhs.e123o),
+			e3: self.e3i.anti_wedge(rhs.e123o),
+			eo: self.eoi.anti_wedge(rhs.e123o),
+			ei: self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: self.e1i.anti_wedge(rhs.e123oi),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: self.e2i.anti_wedge(rhs.e123oi),
+			e3o: Default::default(),
+			e3i: self.e3i.anti_wedge(rhs.e123oi),
+			eoi: self.eoi.anti_wedge(rhs.e123oi),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// FlatPoint.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e1    : E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13   : -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o   : Default::default(),
+		//     e1i   : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23   : E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o   : Default::default(),
+		//     e2i   : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o   : Default::default(),
+		//     e3i   : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.eoi.0 * rhs.e123oi.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e3i.0 * rhs.e123oi.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e2i.0 * rhs.e123oi.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e1i.0 * rhs.e123oi.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e1: E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) + E3(self.eoi.0 * rhs.e3oi.0),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+			e123: E123(self.eoi.0 * rhs.e123oi.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e3i.0 * rhs.e123oi.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e2i.0 * rhs.e123oi.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e1i.0 * rhs.e123oi.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e1    : -E1(self.e1i.0 * rhs.eo.0),
+		//     e2    : -E2(self.e2i.0 * rhs.eo.0),
+		//     e3    : -E3(self.e3i.0 * rhs.eo.0),
+		//     eo    : -Eo(self.eoi.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.eoi.0 * rhs.ei.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : E1i(self.e1i.0 * rhs.s.0),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o   : Default::default(),
+		//     e3i   : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi   : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e1: -E1(self.e1i.0 * rhs.eo.0),
+			e2: -E2(self.e2i.0 * rhs.eo.0),
+			e3: -E3(self.e3i.0 * rhs.eo.0),
+			eo: -Eo(self.eoi.0 * rhs.eo.0),
+			ei: -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.eoi.0 * rhs.ei.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for FlatPoint {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// FlatPoint.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e1i.0 * rhs.eo.0),
+		//     e2    : -E2(self.e2i.0 * rhs.eo.0),
+		//     e3    : -E3(self.e3i.0 * rhs.eo.0),
+		//     eo    : -Eo(self.eoi.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.eoi.0 * rhs.ei.0),
+		//     e12   : E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+		//     e13   : E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+		//     e1o   : -E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i   : -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23   : E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+		//     e2o   : -E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i   : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i   : E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi   : Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+		//     e123  : -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0),
+		//     e12o  : -E12o(self.eoi.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : -E13o(self.eoi.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0),
+		//     e23o  : -E23o(self.eoi.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0),
+		//     e123o : -E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i : -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e3i.0 * rhs.e123o.0),
+		//     e13oi : -E13oi(self.e2i.0 * rhs.e123o.0),
+		//     e23oi : E23oi(self.e1i.0 * rhs.e123o.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e1i.0 * rhs.eo.0),
+			e2: -E2(self.e2i.0 * rhs.eo.0),
+			e3: -E3(self.e3i.0 * rhs.eo.0),
+			eo: -Eo(self.eoi.0 * rhs.eo.0),
+			ei: -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.eoi.0 * rhs.ei.0),
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+			e1o: -E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+			e2o: -E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+			e123: -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0),
+			e12o: -E12o(self.eoi.0 * rhs.e12o.0),
+			e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: -E13o(self.eoi.0 * rhs.e13o.0),
+			e13i: E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0),
+			e23o: -E23o(self.eoi.0 * rhs.e23o.0),
+			e23i: -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0),
+			e123o: -E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+			e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// FlatPoint.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for FlatPoint {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : E1i(self.e1i.0 * rhs.e123oi.0),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : E2i(self.e2i.0 * rhs.e123oi.0),
+		//     e3o   : Default::default(),
+		//     e3i   : E3i(self.e3i.0 * rhs.e123oi.0),
+		//     eoi   : Eoi(self.eoi.0 * rhs.e123oi.0),
+		//     e123  : -E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : E123o(self.eoi.0 * rhs.eo.0),
+		//     e123i : -E123i(self.e1i.0 * rhs.e1.0) - E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3.0) - E123i(self.e3i.0 * rhs.e3oi.0) + E123i(self.eoi.0 * rhs.ei.0),
+		//     e12oi : E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0) - E12oi(self.e3i.0 * rhs.eo.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi : -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e2i.0 * rhs.eo.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi : -E23oi(self.e1i.0 * rhs.eo.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e1i.0 * rhs.e123oi.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e2i.0 * rhs.e123oi.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e3i.0 * rhs.e123oi.0),
+			eoi: Eoi(self.eoi.0 * rhs.e123oi.0),
+			e123: -E123(self.eoi.0 * rhs.s.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: E123o(self.eoi.0 * rhs.eo.0),
+			e123i: -E123i(self.e1i.0 * rhs.e1.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				- E123i(self.e2i.0 * rhs.e2.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0)
+				+ E123i(self.eoi.0 * rhs.ei.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0)
+				- E12oi(self.e3i.0 * rhs.eo.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: -E13oi(self.e1i.0 * rhs.e12o.0)
+				+ E13oi(self.e2i.0 * rhs.eo.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: -E23oi(self.e1i.0 * rhs.eo.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
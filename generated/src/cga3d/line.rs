@@ -0,0 +1,5480 @@
+//! # Line
+//!
+//! ## Operations
+//! ```text
+//! Line.geometric(Line) -> Versor
+//! Line.anti_geometric(Line) -> Multivector
+//! Line.dot(Line) -> S
+//! Line.anti_wedge(Line) -> Ei
+//! Line.left_contraction(Line) -> S
+//! Line.right_contraction(Line) -> S
+//! Line.scalar_product(Line) -> S
+//! Line.commutator(Line) -> PointPair
+//! Line.anti_dot(Line) -> E123oi
+//! Line.geometric(Vec3) -> Versor
+//! Vec3.geometric(Line) -> Versor
+//! Line.anti_geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Line) -> Multivector
+//! Line.dot(Vec3) -> FlatPoint
+//! Vec3.dot(Line) -> FlatPoint
+//! Line.wedge(Vec3) -> Plane
+//! Vec3.wedge(Line) -> Plane
+//! Vec3.left_contraction(Line) -> FlatPoint
+//! Line.right_contraction(Vec3) -> FlatPoint
+//! Line.commutator(Vec3) -> Plane
+//! Vec3.commutator(Line) -> Plane
+//! Line.anti_dot(Vec3) -> Circle
+//! Vec3.anti_dot(Line) -> Circle
+//! Line.geometric(Point) -> Versor
+//! Point.geometric(Line) -> Versor
+//! Line.anti_geometric(Point) -> Multivector
+//! Point.anti_geometric(Line) -> Multivector
+//! Line.dot(Point) -> PointPair
+//! Point.dot(Line) -> PointPair
+//! Line.wedge(Point) -> Plane
+//! Point.wedge(Line) -> Plane
+//! Point.left_contraction(Line) -> PointPair
+//! Line.right_contraction(Point) -> PointPair
+//! Line.commutator(Point) -> Plane
+//! Point.commutator(Line) -> Plane
+//! Line.anti_dot(Point) -> Circle
+//! Point.anti_dot(Line) -> Circle
+//! Line.geometric(FlatPoint) -> Multivector
+//! FlatPoint.geometric(Line) -> Multivector
+//! Line.anti_geometric(FlatPoint) -> Versor
+//! FlatPoint.anti_geometric(Line) -> Versor
+//! Line.dot(FlatPoint) -> Point
+//! FlatPoint.dot(Line) -> Point
+//! FlatPoint.left_contraction(Line) -> Point
+//! Line.right_contraction(FlatPoint) -> Point
+//! Line.commutator(FlatPoint) -> Line
+//! FlatPoint.commutator(Line) -> Line
+//! Line.anti_dot(FlatPoint) -> Plane
+//! FlatPoint.anti_dot(Line) -> Plane
+//! Line.geometric(PointPair) -> Multivector
+//! PointPair.geometric(Line) -> Multivector
+//! Line.anti_geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(Line) -> Versor
+//! Line.dot(PointPair) -> Point
+//! PointPair.dot(Line) -> Point
+//! Line.wedge(PointPair) -> E123oi
+//! PointPair.wedge(Line) -> E123oi
+//! Line.anti_wedge(PointPair) -> S
+//! PointPair.anti_wedge(Line) -> S
+//! PointPair.left_contraction(Line) -> Point
+//! Line.right_contraction(PointPair) -> Point
+//! Line.commutator(PointPair) -> Circle
+//! PointPair.commutator(Line) -> Circle
+//! Line.anti_dot(PointPair) -> Sphere
+//! PointPair.anti_dot(Line) -> Sphere
+//! Line.geometric(Circle) -> Versor
+//! Circle.geometric(Line) -> Versor
+//! Line.anti_geometric(Circle) -> Multivector
+//! Circle.anti_geometric(Line) -> Multivector
+//! Line.dot(Circle) -> S
+//! Circle.dot(Line) -> S
+//! Line.anti_wedge(Circle) -> Point
+//! Circle.anti_wedge(Line) -> Point
+//! Line.left_contraction(Circle) -> S
+//! Circle.left_contraction(Line) -> S
+//! Line.right_contraction(Circle) -> S
+//! Circle.right_contraction(Line) -> S
+//! Line.scalar_product(Circle) -> S
+//! Circle.scalar_product(Line) -> S
+//! Line.commutator(Circle) -> PointPair
+//! Circle.commutator(Line) -> PointPair
+//! Line.anti_dot(Circle) -> E123oi
+//! Circle.anti_dot(Line) -> E123oi
+//! Line.geometric(Plane) -> Multivector
+//! Plane.geometric(Line) -> Multivector
+//! Line.anti_geometric(Plane) -> Versor
+//! Plane.anti_geometric(Line) -> Versor
+//! Line.dot(Plane) -> Point
+//! Plane.dot(Line) -> Point
+//! Line.anti_wedge(Plane) -> FlatPoint
+//! Plane.anti_wedge(Line) -> FlatPoint
+//! Line.left_contraction(Plane) -> Point
+//! Plane.right_contraction(Line) -> Point
+//! Line.commutator(Plane) -> Point
+//! Plane.commutator(Line) -> Point
+//! Line.anti_dot(Plane) -> Plane
+//! Plane.anti_dot(Line) -> Plane
+//! Line.geometric(Sphere) -> Multivector
+//! Sphere.geometric(Line) -> Multivector
+//! Line.anti_geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(Line) -> Versor
+//! Line.dot(Sphere) -> Point
+//! Sphere.dot(Line) -> Point
+//! Line.anti_wedge(Sphere) -> PointPair
+//! Sphere.anti_wedge(Line) -> PointPair
+//! Line.left_contraction(Sphere) -> Point
+//! Sphere.right_contraction(Line) -> Point
+//! Line.commutator(Sphere) -> Point
+//! Sphere.commutator(Line) -> Point
+//! Line.anti_dot(Sphere) -> Plane
+//! Sphere.anti_dot(Line) -> Plane
+//! Line.geometric(Rotor) -> Multivector
+//! Rotor.geometric(Line) -> Multivector
+//! Line.anti_geometric(Rotor) -> Versor
+//! Rotor.anti_geometric(Line) -> Versor
+//! Line.dot(Rotor) -> Multivector
+//! Rotor.dot(Line) -> Multivector
+//! Line.wedge(Rotor) -> Multivector
+//! Rotor.wedge(Line) -> Multivector
+//! Line.anti_wedge(Rotor) -> S
+//! Rotor.anti_wedge(Line) -> S
+//! Rotor.left_contraction(Line) -> Multivector
+//! Line.right_contraction(Rotor) -> Multivector
+//! Line.commutator(Rotor) -> Line
+//! Rotor.commutator(Line) -> Line
+//! Line.anti_dot(Rotor) -> Versor
+//! Rotor.anti_dot(Line) -> Versor
+//! Line.geometric(Translator) -> Multivector
+//! Translator.geometric(Line) -> Multivector
+//! Line.anti_geometric(Translator) -> Versor
+//! Translator.anti_geometric(Line) -> Versor
+//! Line.dot(Translator) -> Multivector
+//! Translator.dot(Line) -> Multivector
+//! Line.wedge(Translator) -> Line
+//! Translator.wedge(Line) -> Line
+//! Translator.left_contraction(Line) -> Multivector
+//! Line.right_contraction(Translator) -> Multivector
+//! Line.commutator(Translator) -> Line
+//! Translator.commutator(Line) -> Line
+//! Line.anti_dot(Translator) -> Versor
+//! Translator.anti_dot(Line) -> Versor
+//! Line.geometric(Versor) -> Multivector
+//! Versor.geometric(Line) -> Multivector
+//! Line.anti_geometric(Versor) -> Versor
+//! Versor.anti_geometric(Line) -> Versor
+//! Line.dot(Versor) -> Multivector
+//! Versor.dot(Line) -> Multivector
+//! Line.wedge(Versor) -> Multivector
+//! Versor.wedge(Line) -> Multivector
+//! Line.anti_wedge(Versor) -> Versor
+//! Versor.anti_wedge(Line) -> Versor
+//! Line.left_contraction(Versor) -> Point
+//! Versor.left_contraction(Line) -> Multivector
+//! Line.right_contraction(Versor) -> Multivector
+//! Versor.right_contraction(Line) -> Point
+//! Line.commutator(Versor) -> Multivector
+//! Versor.commutator(Line) -> Multivector
+//! Line.anti_dot(Versor) -> Versor
+//! Versor.anti_dot(Line) -> Versor
+//! Line.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Line) -> Multivector
+//! Line.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Line) -> Multivector
+//! Line.dot(Multivector) -> Multivector
+//! Multivector.dot(Line) -> Multivector
+//! Line.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Line) -> Multivector
+//! Line.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Line) -> Multivector
+//! Line.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Line) -> Multivector
+//! Line.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Line) -> Multivector
+//! Line.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Line) -> S
+//! Line.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Line) -> Multivector
+//! Line.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Line) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line {
+	pub e12i: E12i,
+	pub e13i: E13i,
+	pub e1oi: E1oi,
+	pub e23i: E23i,
+	pub e2oi: E2oi,
+	pub e3oi: E3oi,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Line {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Line {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Line {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.e12i.abs_diff_eq(&other.e12i, epsilon)
+			&& self.e13i.abs_diff_eq(&other.e13i, epsilon)
+			&& self.e1oi.abs_diff_eq(&other.e1oi, epsilon)
+			&& self.e23i.abs_diff_eq(&other.e23i, epsilon)
+			&& self.e2oi.abs_diff_eq(&other.e2oi, epsilon)
+			&& self.e3oi.abs_diff_eq(&other.e3oi, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Line {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.e12i.relative_eq(&other.e12i, epsilon, max_relative)
+			&& self.e13i.relative_eq(&other.e13i, epsilon, max_relative)
+			&& self.e1oi.relative_eq(&other.e1oi, epsilon, max_relative)
+			&& self.e23i.relative_eq(&other.e23i, epsilon, max_relative)
+			&& self.e2oi.relative_eq(&other.e2oi, epsilon, max_relative)
+			&& self.e3oi.relative_eq(&other.e3oi, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Line {
+	type Output = PointPair;
+	fn rcompl(self) -> Self::Output {
+		PointPair {
+			e12: self.e3oi.rcompl(),
+			e13: self.e2oi.rcompl(),
+			e1o: self.e23i.rcompl(),
+			e1i: Default::default(),
+			e23: self.e1oi.rcompl(),
+			e2o: self.e13i.rcompl(),
+			e2i: Default::default(),
+			e3o: self.e12i.rcompl(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Not for Line {
+	type Output = PointPair;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Line {
+	type Output = PointPair;
+	fn lcompl(self) -> Self::Output {
+		PointPair {
+			e12: self.e3oi.lcompl(),
+			e13: self.e2oi.lcompl(),
+			e1o: self.e23i.lcompl(),
+			e1i: Default::default(),
+			e23: self.e1oi.lcompl(),
+			e2o: self.e13i.lcompl(),
+			e2i: Default::default(),
+			e3o: self.e12i.lcompl(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl Reverse for Line {
+	fn rev(self) -> Self {
+		Line {
+			e12i: -self.e12i,
+			e13i: -self.e13i,
+			e1oi: -self.e1oi,
+			e23i: -self.e23i,
+			e2oi: -self.e2oi,
+			e3oi: -self.e3oi,
+		}
+	}
+}
+
+impl AntiReverse for Line {
+	fn arev(self) -> Self {
+		Line {
+			e12i: -self.e12i,
+			e13i: -self.e13i,
+			e1oi: -self.e1oi,
+			e23i: -self.e23i,
+			e2oi: -self.e2oi,
+			e3oi: -self.e3oi,
+		}
+	}
+}
+
+impl Involute for Line {
+	fn involute(self) -> Self {
+		Line {
+			e12i: -self.e12i,
+			e13i: -self.e13i,
+			e1oi: -self.e1oi,
+			e23i: -self.e23i,
+			e2oi: -self.e2oi,
+			e3oi: -self.e3oi,
+		}
+	}
+}
+
+impl Conjugate for Line {
+	fn conjugate(self) -> Self {
+		Line {
+			e12i: self.e12i,
+			e13i: self.e13i,
+			e1oi: self.e1oi,
+			e23i: self.e23i,
+			e2oi: self.e2oi,
+			e3oi: self.e3oi,
+		}
+	}
+}
+
+impl Dual for Line {
+	type Output = PointPair;
+	fn dual(self) -> Self::Output {
+		PointPair {
+			e12: self.e3oi.dual(),
+			e13: self.e2oi.dual(),
+			e1o: self.e23i.dual(),
+			e1i: Default::default(),
+			e23: self.e1oi.dual(),
+			e2o: self.e13i.dual(),
+			e2i: Default::default(),
+			e3o: self.e12i.dual(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Omitted: Line.square()  (not a scalar)
+// Omitted: Norm for Line  (the bulk and weight need a diagonal metric)
+
+impl Line {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 6] {
+		[
+			self.e12i.0,
+			self.e13i.0,
+			self.e1oi.0,
+			self.e23i.0,
+			self.e2oi.0,
+			self.e3oi.0,
+		]
+	}
+}
+
+impl From<[f64; 6]> for Line {
+	fn from(a: [f64; 6]) -> Self {
+		Line {
+			e12i: E12i(a[0]),
+			e13i: E13i(a[1]),
+			e1oi: E1oi(a[2]),
+			e23i: E23i(a[3]),
+			e2oi: E2oi(a[4]),
+			e3oi: E3oi(a[5]),
+		}
+	}
+}
+
+impl From<Line> for [f64; 6] {
+	fn from(v: Line) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Line> for Multivector {
+	fn from(v: Line) -> Self {
+		Multivector {
+			e12i: v.e12i,
+			e13i: v.e13i,
+			e1oi: v.e1oi,
+			e23i: v.e23i,
+			e2oi: v.e2oi,
+			e3oi: v.e3oi,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Line {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Line` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.eo.0 == 0.0
+			&& mv.ei.0 == 0.0
+			&& mv.e12.0 == 0.0
+			&& mv.e13.0 == 0.0
+			&& mv.e1o.0 == 0.0
+			&& mv.e1i.0 == 0.0
+			&& mv.e23.0 == 0.0
+			&& mv.e2o.0 == 0.0
+			&& mv.e2i.0 == 0.0
+			&& mv.e3o.0 == 0.0
+			&& mv.e3i.0 == 0.0
+			&& mv.eoi.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e12o.0 == 0.0
+			&& mv.e13o.0 == 0.0
+			&& mv.e23o.0 == 0.0
+			&& mv.e123o.0 == 0.0
+			&& mv.e123i.0 == 0.0
+			&& mv.e12oi.0 == 0.0
+			&& mv.e13oi.0 == 0.0
+			&& mv.e23oi.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(Line {
+				e12i: mv.e12i,
+				e13i: mv.e13i,
+				e1oi: mv.e1oi,
+				e23i: mv.e23i,
+				e2oi: mv.e2oi,
+				e3oi: mv.e3oi,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Vec3:
+
+// Line.geometric(Vec3) -> Versor
+impl Geometric<Vec3> for Line {
+	type Output = Versor;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+		//     eoi  : Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+			e12oi: E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+			e13oi: E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+			e23oi: E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Line {
+	type Output = Versor;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Line {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e2oi.0 * rhs.z.0) + E1(self.e3oi.0 * rhs.y.0),
+		//     e2    : E2(self.e1oi.0 * rhs.z.0) - E2(self.e3oi.0 * rhs.x.0),
+		//     e3    : -E3(self.e1oi.0 * rhs.y.0) + E3(self.e2oi.0 * rhs.x.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12i.0 * rhs.z.0) - Ei(self.e13i.0 * rhs.y.0) + Ei(self.e23i.0 * rhs.x.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e2oi.0 * rhs.z.0) + E1(self.e3oi.0 * rhs.y.0),
+			e2: E2(self.e1oi.0 * rhs.z.0) - E2(self.e3oi.0 * rhs.x.0),
+			e3: -E3(self.e1oi.0 * rhs.y.0) + E3(self.e2oi.0 * rhs.x.0),
+			eo: Default::default(),
+			ei: Ei(self.e12i.0 * rhs.z.0) - Ei(self.e13i.0 * rhs.y.0) + Ei(self.e23i.0 * rhs.x.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Line.dot(Vec3) -> FlatPoint
+impl Dot<Vec3> for Line {
+	type Output = FlatPoint;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// FlatPoint {
+		//     e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+		//     e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+		//     e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		// }
+		FlatPoint {
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Line {
+	type Output = FlatPoint;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(Vec3) -> Plane
+impl Wedge<Vec3> for Line {
+	type Output = Plane;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Plane {
+			e123i: self.e12i.wedge(rhs.z) + self.e13i.wedge(rhs.y) + self.e23i.wedge(rhs.x),
+			e12oi: self.e1oi.wedge(rhs.y) + self.e2oi.wedge(rhs.x),
+			e13oi: self.e1oi.wedge(rhs.z) + self.e3oi.wedge(rhs.x),
+			e23oi: self.e2oi.wedge(rhs.z) + self.e3oi.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Line {
+	type Output = Plane;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for Line {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Line {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for Line {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.right_contraction(Vec3) -> FlatPoint
+impl RightContraction<Vec3> for Line {
+	type Output = FlatPoint;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// FlatPoint {
+		//     e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+		//     e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+		//     e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		// }
+		FlatPoint {
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(Vec3) -> Plane
+impl Commutator<Vec3> for Line {
+	type Output = Plane;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Plane {
+			e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+			e12oi: E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+			e13oi: E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+			e23oi: E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		}
+	}
+}
+
+// Line.anti_dot(Vec3) -> Circle
+impl AntiDot<Vec3> for Line {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+		//     e12o: Default::default(),
+		//     e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0),
+		//     e13o: Default::default(),
+		//     e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0),
+		//     e1oi: Default::default(),
+		//     e23o: Default::default(),
+		//     e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Circle {
+			e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Point:
+
+// Line.geometric(Point) -> Versor
+impl Geometric<Point> for Line {
+	type Output = Versor;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e12i.0 * rhs.o.0),
+		//     e13  : -E13(self.e13i.0 * rhs.o.0),
+		//     e1o  : -E1o(self.e1oi.0 * rhs.o.0),
+		//     e1i  : -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+		//     e23  : -E23(self.e23i.0 * rhs.o.0),
+		//     e2o  : -E2o(self.e2oi.0 * rhs.o.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+		//     e3o  : -E3o(self.e3oi.0 * rhs.o.0),
+		//     e3i  : E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+		//     eoi  : Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.o.0) + E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: -E13oi(self.e13i.0 * rhs.o.0) + E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: -E23oi(self.e23i.0 * rhs.o.0) + E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e12i.0 * rhs.o.0),
+			e13: -E13(self.e13i.0 * rhs.o.0),
+			e1o: -E1o(self.e1oi.0 * rhs.o.0),
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+			e23: -E23(self.e23i.0 * rhs.o.0),
+			e2o: -E2o(self.e2oi.0 * rhs.o.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+			e3o: -E3o(self.e3oi.0 * rhs.o.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.o.0) + E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+			e13oi: -E13oi(self.e13i.0 * rhs.o.0) + E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+			e23oi: -E23oi(self.e23i.0 * rhs.o.0) + E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for Line {
+	type Output = Versor;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Point) -> Multivector
+impl AntiGeometric<Point> for Line {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23i.0 * rhs.o.0) - E1(self.e2oi.0 * rhs.z.0) + E1(self.e3oi.0 * rhs.y.0),
+		//     e2    : -E2(self.e13i.0 * rhs.o.0) + E2(self.e1oi.0 * rhs.z.0) - E2(self.e3oi.0 * rhs.x.0),
+		//     e3    : E3(self.e12i.0 * rhs.o.0) - E3(self.e1oi.0 * rhs.y.0) + E3(self.e2oi.0 * rhs.x.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12i.0 * rhs.z.0) - Ei(self.e13i.0 * rhs.y.0) + Ei(self.e23i.0 * rhs.x.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+		//     e12o  : -E12o(self.e3oi.0 * rhs.o.0),
+		//     e12i  : -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0) - E12i(self.e3oi.0 * rhs.inf.0),
+		//     e13o  : E13o(self.e2oi.0 * rhs.o.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0) + E13i(self.e2oi.0 * rhs.inf.0),
+		//     e1oi  : -E1oi(self.e23i.0 * rhs.o.0),
+		//     e23o  : -E23o(self.e1oi.0 * rhs.o.0),
+		//     e23i  : E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0) - E23i(self.e1oi.0 * rhs.inf.0),
+		//     e2oi  : E2oi(self.e13i.0 * rhs.o.0),
+		//     e3oi  : -E3oi(self.e12i.0 * rhs.o.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23i.0 * rhs.o.0) - E1(self.e2oi.0 * rhs.z.0) + E1(self.e3oi.0 * rhs.y.0),
+			e2: -E2(self.e13i.0 * rhs.o.0) + E2(self.e1oi.0 * rhs.z.0) - E2(self.e3oi.0 * rhs.x.0),
+			e3: E3(self.e12i.0 * rhs.o.0) - E3(self.e1oi.0 * rhs.y.0) + E3(self.e2oi.0 * rhs.x.0),
+			eo: Default::default(),
+			ei: Ei(self.e12i.0 * rhs.z.0) - Ei(self.e13i.0 * rhs.y.0) + Ei(self.e23i.0 * rhs.x.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+			e12o: -E12o(self.e3oi.0 * rhs.o.0),
+			e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0) - E12i(self.e3oi.0 * rhs.inf.0),
+			e13o: E13o(self.e2oi.0 * rhs.o.0),
+			e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0) + E13i(self.e2oi.0 * rhs.inf.0),
+			e1oi: -E1oi(self.e23i.0 * rhs.o.0),
+			e23o: -E23o(self.e1oi.0 * rhs.o.0),
+			e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0) - E23i(self.e1oi.0 * rhs.inf.0),
+			e2oi: E2oi(self.e13i.0 * rhs.o.0),
+			e3oi: -E3oi(self.e12i.0 * rhs.o.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Line.dot(Point) -> PointPair
+impl Dot<Point> for Line {
+	type Output = PointPair;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e12i.0 * rhs.o.0),
+		//     e13: -E13(self.e13i.0 * rhs.o.0),
+		//     e1o: -E1o(self.e1oi.0 * rhs.o.0),
+		//     e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+		//     e23: -E23(self.e23i.0 * rhs.o.0),
+		//     e2o: -E2o(self.e2oi.0 * rhs.o.0),
+		//     e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+		//     e3o: -E3o(self.e3oi.0 * rhs.o.0),
+		//     e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		// }
+		PointPair {
+			e12: -E12(self.e12i.0 * rhs.o.0),
+			e13: -E13(self.e13i.0 * rhs.o.0),
+			e1o: -E1o(self.e1oi.0 * rhs.o.0),
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+			e23: -E23(self.e23i.0 * rhs.o.0),
+			e2o: -E2o(self.e2oi.0 * rhs.o.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+			e3o: -E3o(self.e3oi.0 * rhs.o.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point> for Line {
+	type Output = PointPair;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(Point) -> Plane
+impl Wedge<Point> for Line {
+	type Output = Plane;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.o.0) + E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: -E13oi(self.e13i.0 * rhs.o.0) + E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: -E23oi(self.e23i.0 * rhs.o.0) + E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Plane {
+			e123i: self.e12i.wedge(rhs.z) + self.e13i.wedge(rhs.y) + self.e23i.wedge(rhs.x),
+			e12oi: self.e12i.wedge(rhs.o) + self.e1oi.wedge(rhs.y) + self.e2oi.wedge(rhs.x),
+			e13oi: self.e13i.wedge(rhs.o) + self.e1oi.wedge(rhs.z) + self.e3oi.wedge(rhs.x),
+			e23oi: self.e23i.wedge(rhs.o) + self.e2oi.wedge(rhs.z) + self.e3oi.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point> for Line {
+	type Output = Plane;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point> for Line {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point> for Line {
+	type Output = Zero;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point> for Line {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.right_contraction(Point) -> PointPair
+impl RightContraction<Point> for Line {
+	type Output = PointPair;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e12i.0 * rhs.o.0),
+		//     e13: -E13(self.e13i.0 * rhs.o.0),
+		//     e1o: -E1o(self.e1oi.0 * rhs.o.0),
+		//     e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+		//     e23: -E23(self.e23i.0 * rhs.o.0),
+		//     e2o: -E2o(self.e2oi.0 * rhs.o.0),
+		//     e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+		//     e3o: -E3o(self.e3oi.0 * rhs.o.0),
+		//     e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		// }
+		PointPair {
+			e12: -E12(self.e12i.0 * rhs.o.0),
+			e13: -E13(self.e13i.0 * rhs.o.0),
+			e1o: -E1o(self.e1oi.0 * rhs.o.0),
+			e1i: -E1i(self.e12i.0 * rhs.y.0) - E1i(self.e13i.0 * rhs.z.0) + E1i(self.e1oi.0 * rhs.inf.0),
+			e23: -E23(self.e23i.0 * rhs.o.0),
+			e2o: -E2o(self.e2oi.0 * rhs.o.0),
+			e2i: E2i(self.e12i.0 * rhs.x.0) - E2i(self.e23i.0 * rhs.z.0) + E2i(self.e2oi.0 * rhs.inf.0),
+			e3o: -E3o(self.e3oi.0 * rhs.o.0),
+			e3i: E3i(self.e13i.0 * rhs.x.0) + E3i(self.e23i.0 * rhs.y.0) + E3i(self.e3oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e1oi.0 * rhs.x.0) + Eoi(self.e2oi.0 * rhs.y.0) + Eoi(self.e3oi.0 * rhs.z.0),
+		}
+	}
+}
+
+impl ScalarProduct<Point> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(Point) -> Plane
+impl Commutator<Point> for Line {
+	type Output = Plane;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.o.0) + E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+		//     e13oi: -E13oi(self.e13i.0 * rhs.o.0) + E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+		//     e23oi: -E23oi(self.e23i.0 * rhs.o.0) + E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		// }
+		Plane {
+			e123i: -E123i(self.e12i.0 * rhs.z.0) + E123i(self.e13i.0 * rhs.y.0) - E123i(self.e23i.0 * rhs.x.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.o.0) + E12oi(self.e1oi.0 * rhs.y.0) - E12oi(self.e2oi.0 * rhs.x.0),
+			e13oi: -E13oi(self.e13i.0 * rhs.o.0) + E13oi(self.e1oi.0 * rhs.z.0) - E13oi(self.e3oi.0 * rhs.x.0),
+			e23oi: -E23oi(self.e23i.0 * rhs.o.0) + E23oi(self.e2oi.0 * rhs.z.0) - E23oi(self.e3oi.0 * rhs.y.0),
+		}
+	}
+}
+
+// Line.anti_dot(Point) -> Circle
+impl AntiDot<Point> for Line {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+		//     e12o: -E12o(self.e3oi.0 * rhs.o.0),
+		//     e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0) - E12i(self.e3oi.0 * rhs.inf.0),
+		//     e13o: E13o(self.e2oi.0 * rhs.o.0),
+		//     e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0) + E13i(self.e2oi.0 * rhs.inf.0),
+		//     e1oi: -E1oi(self.e23i.0 * rhs.o.0),
+		//     e23o: -E23o(self.e1oi.0 * rhs.o.0),
+		//     e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0) - E23i(self.e1oi.0 * rhs.inf.0),
+		//     e2oi: E2oi(self.e13i.0 * rhs.o.0),
+		//     e3oi: -E3oi(self.e12i.0 * rhs.o.0),
+		// }
+		Circle {
+			e123: -E123(self.e1oi.0 * rhs.x.0) - E123(self.e2oi.0 * rhs.y.0) - E123(self.e3oi.0 * rhs.z.0),
+			e12o: -E12o(self.e3oi.0 * rhs.o.0),
+			e12i: -E12i(self.e13i.0 * rhs.x.0) - E12i(self.e23i.0 * rhs.y.0) - E12i(self.e3oi.0 * rhs.inf.0),
+			e13o: E13o(self.e2oi.0 * rhs.o.0),
+			e13i: E13i(self.e12i.0 * rhs.x.0) - E13i(self.e23i.0 * rhs.z.0) + E13i(self.e2oi.0 * rhs.inf.0),
+			e1oi: -E1oi(self.e23i.0 * rhs.o.0),
+			e23o: -E23o(self.e1oi.0 * rhs.o.0),
+			e23i: E23i(self.e12i.0 * rhs.y.0) + E23i(self.e13i.0 * rhs.z.0) - E23i(self.e1oi.0 * rhs.inf.0),
+			e2oi: E2oi(self.e13i.0 * rhs.o.0),
+			e3oi: -E3oi(self.e12i.0 * rhs.o.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP FlatPoint:
+
+// Line.geometric(FlatPoint) -> Multivector
+impl Geometric<FlatPoint> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e1oi.0 * rhs.eoi.0),
+		//     e2    : E2(self.e2oi.0 * rhs.eoi.0),
+		//     e3    : E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e1oi.0 * rhs.eoi.0),
+			e2: E2(self.e2oi.0 * rhs.eoi.0),
+			e3: E3(self.e3oi.0 * rhs.eoi.0),
+			eo: Default::default(),
+			ei: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(FlatPoint) -> Versor
+impl AntiGeometric<FlatPoint> for Line {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e23i.0 * rhs.eoi.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e13i.0 * rhs.eoi.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e12i.0 * rhs.eoi.0) - E3i(self.e1oi.0 * rhs.e2i.0) + E3i(self.e2oi.0 * rhs.e1i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: -E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: E13oi(self.e2oi.0 * rhs.eoi.0),
+		//     e23oi: -E23oi(self.e1oi.0 * rhs.eoi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e23i.0 * rhs.eoi.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: -E2i(self.e13i.0 * rhs.eoi.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e12i.0 * rhs.eoi.0) - E3i(self.e1oi.0 * rhs.e2i.0) + E3i(self.e2oi.0 * rhs.e1i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: -E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: E13oi(self.e2oi.0 * rhs.eoi.0),
+			e23oi: -E23oi(self.e1oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Line.dot(FlatPoint) -> Point
+impl Dot<FlatPoint> for Line {
+	type Output = Point;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e1oi.0 * rhs.eoi.0),
+		//     y  : E2(self.e2oi.0 * rhs.eoi.0),
+		//     z  : E3(self.e3oi.0 * rhs.eoi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.e1oi.0 * rhs.eoi.0),
+			y: E2(self.e2oi.0 * rhs.eoi.0),
+			z: E3(self.e3oi.0 * rhs.eoi.0),
+			o: Default::default(),
+			inf: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for Line {
+	type Output = Point;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<FlatPoint> for Line {
+	type Output = Zero;
+	fn wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for Line {
+	type Output = Zero;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<FlatPoint> for Line {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for Line {
+	type Output = Zero;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<FlatPoint> for Line {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.right_contraction(FlatPoint) -> Point
+impl RightContraction<FlatPoint> for Line {
+	type Output = Point;
+	fn right_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e1oi.0 * rhs.eoi.0),
+		//     y  : E2(self.e2oi.0 * rhs.eoi.0),
+		//     z  : E3(self.e3oi.0 * rhs.eoi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.e1oi.0 * rhs.eoi.0),
+			y: E2(self.e2oi.0 * rhs.eoi.0),
+			z: E3(self.e3oi.0 * rhs.eoi.0),
+			o: Default::default(),
+			inf: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl ScalarProduct<FlatPoint> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(FlatPoint) -> Line
+impl Commutator<FlatPoint> for Line {
+	type Output = Line;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// Line {
+		//     e12i: -E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13i: -E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi: Default::default(),
+		//     e23i: -E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Line {
+			e12i: -E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+			e13i: -E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: Default::default(),
+			e23i: -E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// Line.anti_dot(FlatPoint) -> Plane
+impl AntiDot<FlatPoint> for Line {
+	type Output = Plane;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: -E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: E13oi(self.e2oi.0 * rhs.eoi.0),
+		//     e23oi: -E23oi(self.e1oi.0 * rhs.eoi.0),
+		// }
+		Plane {
+			e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: -E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: E13oi(self.e2oi.0 * rhs.eoi.0),
+			e23oi: -E23oi(self.e1oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP PointPair:
+
+// Line.geometric(PointPair) -> Multivector
+impl Geometric<PointPair> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     e2    : -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     e3    : -E3(self.e13i.0 * rhs.e1o.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0) + E123(self.e23i.0 * rhs.e1o.0),
+		//     e12o  : -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+		//     e12i  : -E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o  : -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : -E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i  : -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0),
+			e2: -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0),
+			e3: -E3(self.e13i.0 * rhs.e1o.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0) + E123(self.e23i.0 * rhs.e1o.0),
+			e12o: -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+			e12i: -E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: -E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e13i.0 * rhs.e2o.0)
+				+ E123oi(self.e1oi.0 * rhs.e23.0)
+				+ E123oi(self.e23i.0 * rhs.e1o.0)
+				- E123oi(self.e2oi.0 * rhs.e13.0)
+				+ E123oi(self.e3oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(PointPair) -> Versor
+impl AntiGeometric<PointPair> for Line {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12i.0 * rhs.e3o.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e12  : -E12(self.e13i.0 * rhs.e1o.0) + E12(self.e1oi.0 * rhs.e13.0) - E12(self.e23i.0 * rhs.e2o.0) + E12(self.e2oi.0 * rhs.e23.0),
+		//     e13  : E13(self.e12i.0 * rhs.e1o.0) - E13(self.e1oi.0 * rhs.e12.0) - E13(self.e23i.0 * rhs.e3o.0) + E13(self.e3oi.0 * rhs.e23.0),
+		//     e1o  : -E1o(self.e2oi.0 * rhs.e3o.0) + E1o(self.e3oi.0 * rhs.e2o.0),
+		//     e1i  : E1i(self.e12i.0 * rhs.e13.0) - E1i(self.e13i.0 * rhs.e12.0) + E1i(self.e23i.0 * rhs.eoi.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+		//     e23  : E23(self.e12i.0 * rhs.e2o.0) + E23(self.e13i.0 * rhs.e3o.0) - E23(self.e2oi.0 * rhs.e12.0) - E23(self.e3oi.0 * rhs.e13.0),
+		//     e2o  : E2o(self.e1oi.0 * rhs.e3o.0) - E2o(self.e3oi.0 * rhs.e1o.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.e23.0) - E2i(self.e13i.0 * rhs.eoi.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e23i.0 * rhs.e12.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+		//     e3o  : -E3o(self.e1oi.0 * rhs.e2o.0) + E3o(self.e2oi.0 * rhs.e1o.0),
+		//     e3i  : E3i(self.e12i.0 * rhs.eoi.0) + E3i(self.e13i.0 * rhs.e23.0) - E3i(self.e1oi.0 * rhs.e2i.0) - E3i(self.e23i.0 * rhs.e13.0) + E3i(self.e2oi.0 * rhs.e1i.0),
+		//     eoi  : -Eoi(self.e12i.0 * rhs.e3o.0) + Eoi(self.e13i.0 * rhs.e2o.0) - Eoi(self.e23i.0 * rhs.e1o.0),
+		//     e123o: -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e23i.0 * rhs.e2o.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: -E13oi(self.e12i.0 * rhs.e1o.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e2oi.0 * rhs.eoi.0),
+		//     e23oi: -E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e1oi.0 * rhs.eoi.0),
+		// }
+		Versor {
+			s: S(self.e12i.0 * rhs.e3o.0) - S(self.e13i.0 * rhs.e2o.0)
+				+ S(self.e1oi.0 * rhs.e23.0)
+				+ S(self.e23i.0 * rhs.e1o.0)
+				- S(self.e2oi.0 * rhs.e13.0)
+				+ S(self.e3oi.0 * rhs.e12.0),
+			e12: -E12(self.e13i.0 * rhs.e1o.0) + E12(self.e1oi.0 * rhs.e13.0) - E12(self.e23i.0 * rhs.e2o.0)
+				+ E12(self.e2oi.0 * rhs.e23.0),
+			e13: E13(self.e12i.0 * rhs.e1o.0) - E13(self.e1oi.0 * rhs.e12.0) - E13(self.e23i.0 * rhs.e3o.0)
+				+ E13(self.e3oi.0 * rhs.e23.0),
+			e1o: -E1o(self.e2oi.0 * rhs.e3o.0) + E1o(self.e3oi.0 * rhs.e2o.0),
+			e1i: E1i(self.e12i.0 * rhs.e13.0) - E1i(self.e13i.0 * rhs.e12.0) + E1i(self.e23i.0 * rhs.eoi.0)
+				- E1i(self.e2oi.0 * rhs.e3i.0)
+				+ E1i(self.e3oi.0 * rhs.e2i.0),
+			e23: E23(self.e12i.0 * rhs.e2o.0) + E23(self.e13i.0 * rhs.e3o.0)
+				- E23(self.e2oi.0 * rhs.e12.0)
+				- E23(self.e3oi.0 * rhs.e13.0),
+			e2o: E2o(self.e1oi.0 * rhs.e3o.0) - E2o(self.e3oi.0 * rhs.e1o.0),
+			e2i: E2i(self.e12i.0 * rhs.e23.0) - E2i(self.e13i.0 * rhs.eoi.0) + E2i(self.e1oi.0 * rhs.e3i.0)
+				- E2i(self.e23i.0 * rhs.e12.0)
+				- E2i(self.e3oi.0 * rhs.e1i.0),
+			e3o: -E3o(self.e1oi.0 * rhs.e2o.0) + E3o(self.e2oi.0 * rhs.e1o.0),
+			e3i: E3i(self.e12i.0 * rhs.eoi.0) + E3i(self.e13i.0 * rhs.e23.0)
+				- E3i(self.e1oi.0 * rhs.e2i.0)
+				- E3i(self.e23i.0 * rhs.e13.0)
+				+ E3i(self.e2oi.0 * rhs.e1i.0),
+			eoi: -Eoi(self.e12i.0 * rhs.e3o.0) + Eoi(self.e13i.0 * rhs.e2o.0) - Eoi(self.e23i.0 * rhs.e1o.0),
+			e123o: -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) - E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e23i.0 * rhs.e2o.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: -E13oi(self.e12i.0 * rhs.e1o.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e2oi.0 * rhs.eoi.0),
+			e23oi: -E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e1oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Line.dot(PointPair) -> Point
+impl Dot<PointPair> for Line {
+	type Output = Point;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     y  : -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     z  : -E3(self.e13i.0 * rhs.e1o.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     o  : -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     inf: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0),
+			y: -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0),
+			z: -E3(self.e13i.0 * rhs.e1o.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e3oi.0 * rhs.eoi.0),
+			o: -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+			inf: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<PointPair> for Line {
+	type Output = Point;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(PointPair) -> E123oi
+impl Wedge<PointPair> for Line {
+	type Output = E123oi;
+	fn wedge(self, rhs: PointPair) -> Self::Output {
+		// E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0)
+		self.e12i.wedge(rhs.e3o)
+			+ self.e13i.wedge(rhs.e2o)
+			+ self.e1oi.wedge(rhs.e23)
+			+ self.e23i.wedge(rhs.e1o)
+			+ self.e2oi.wedge(rhs.e13)
+			+ self.e3oi.wedge(rhs.e12)
+	}
+}
+
+impl std::ops::BitXor<PointPair> for Line {
+	type Output = E123oi;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(PointPair) -> S
+impl AntiWedge<PointPair> for Line {
+	type Output = S;
+	fn anti_wedge(self, rhs: PointPair) -> Self::Output {
+		// S(self.e12i.0 * rhs.e3o.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0)
+		self.e12i.anti_wedge(rhs.e3o)
+			+ self.e13i.anti_wedge(rhs.e2o)
+			+ self.e1oi.anti_wedge(rhs.e23)
+			+ self.e23i.anti_wedge(rhs.e1o)
+			+ self.e2oi.anti_wedge(rhs.e13)
+			+ self.e3oi.anti_wedge(rhs.e12)
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for Line {
+	type Output = S;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<PointPair> for Line {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.right_contraction(PointPair) -> Point
+impl RightContraction<PointPair> for Line {
+	type Output = Point;
+	fn right_contraction(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     y  : -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     z  : -E3(self.e13i.0 * rhs.e1o.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     o  : -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     inf: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0),
+			y: -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0),
+			z: -E3(self.e13i.0 * rhs.e1o.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e3oi.0 * rhs.eoi.0),
+			o: -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+			inf: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl ScalarProduct<PointPair> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(PointPair) -> Circle
+impl Commutator<PointPair> for Line {
+	type Output = Circle;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0) + E123(self.e23i.0 * rhs.e1o.0),
+		//     e12o: -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+		//     e12i: -E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o: -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi: E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o: -E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi: -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi: -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		// }
+		Circle {
+			e123: E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0) + E123(self.e23i.0 * rhs.e1o.0),
+			e12o: -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+			e12i: -E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: -E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Line.anti_dot(PointPair) -> Sphere
+impl AntiDot<PointPair> for Line {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e23i.0 * rhs.e2o.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: -E13oi(self.e12i.0 * rhs.e1o.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e2oi.0 * rhs.eoi.0),
+		//     e23oi: -E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e1oi.0 * rhs.eoi.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) - E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e23i.0 * rhs.e2o.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: -E13oi(self.e12i.0 * rhs.e1o.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e2oi.0 * rhs.eoi.0),
+			e23oi: -E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e1oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Line:
+
+// Line.geometric(Line) -> Versor
+impl Geometric<Line> for Line {
+	type Output = Versor;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1oi.0 * rhs.e1oi.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e12  : E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+		//     e13  : E13(self.e1oi.0 * rhs.e3oi.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23  : E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12i.0 * rhs.e1oi.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12i.0 * rhs.e3oi.0) - E123i(self.e13i.0 * rhs.e2oi.0) + E123i(self.e1oi.0 * rhs.e23i.0) + E123i(self.e23i.0 * rhs.e1oi.0) - E123i(self.e2oi.0 * rhs.e13i.0) + E123i(self.e3oi.0 * rhs.e12i.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1oi.0 * rhs.e1oi.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+			e12: E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+			e13: E13(self.e1oi.0 * rhs.e3oi.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12i.0 * rhs.e1oi.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.e12i.0 * rhs.e3oi.0) - E123i(self.e13i.0 * rhs.e2oi.0)
+				+ E123i(self.e1oi.0 * rhs.e23i.0)
+				+ E123i(self.e23i.0 * rhs.e1oi.0)
+				- E123i(self.e2oi.0 * rhs.e13i.0)
+				+ E123i(self.e3oi.0 * rhs.e12i.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Line {
+	type Output = Versor;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Line) -> Multivector
+impl AntiGeometric<Line> for Line {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e13i.0 * rhs.e1oi.0) + E12i(self.e1oi.0 * rhs.e13i.0) - E12i(self.e23i.0 * rhs.e2oi.0) + E12i(self.e2oi.0 * rhs.e23i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e12i.0 * rhs.e1oi.0) - E13i(self.e1oi.0 * rhs.e12i.0) - E13i(self.e23i.0 * rhs.e3oi.0) + E13i(self.e3oi.0 * rhs.e23i.0),
+		//     e1oi  : -E1oi(self.e2oi.0 * rhs.e3oi.0) + E1oi(self.e3oi.0 * rhs.e2oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e12i.0 * rhs.e2oi.0) + E23i(self.e13i.0 * rhs.e3oi.0) - E23i(self.e2oi.0 * rhs.e12i.0) - E23i(self.e3oi.0 * rhs.e13i.0),
+		//     e2oi  : E2oi(self.e1oi.0 * rhs.e3oi.0) - E2oi(self.e3oi.0 * rhs.e1oi.0),
+		//     e3oi  : -E3oi(self.e1oi.0 * rhs.e2oi.0) + E3oi(self.e2oi.0 * rhs.e1oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0)
+				+ Ei(self.e1oi.0 * rhs.e23i.0)
+				+ Ei(self.e23i.0 * rhs.e1oi.0)
+				- Ei(self.e2oi.0 * rhs.e13i.0)
+				+ Ei(self.e3oi.0 * rhs.e12i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e13i.0 * rhs.e1oi.0) + E12i(self.e1oi.0 * rhs.e13i.0) - E12i(self.e23i.0 * rhs.e2oi.0)
+				+ E12i(self.e2oi.0 * rhs.e23i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e12i.0 * rhs.e1oi.0) - E13i(self.e1oi.0 * rhs.e12i.0) - E13i(self.e23i.0 * rhs.e3oi.0)
+				+ E13i(self.e3oi.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.e2oi.0 * rhs.e3oi.0) + E1oi(self.e3oi.0 * rhs.e2oi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e12i.0 * rhs.e2oi.0) + E23i(self.e13i.0 * rhs.e3oi.0)
+				- E23i(self.e2oi.0 * rhs.e12i.0)
+				- E23i(self.e3oi.0 * rhs.e13i.0),
+			e2oi: E2oi(self.e1oi.0 * rhs.e3oi.0) - E2oi(self.e3oi.0 * rhs.e1oi.0),
+			e3oi: -E3oi(self.e1oi.0 * rhs.e2oi.0) + E3oi(self.e2oi.0 * rhs.e1oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1oi.0 * rhs.e1oi.0)
+				- E123oi(self.e2oi.0 * rhs.e2oi.0)
+				- E123oi(self.e3oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Line.dot(Line) -> S
+impl Dot<Line> for Line {
+	type Output = S;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// S(self.e1oi.0 * rhs.e1oi.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e1oi.0 * rhs.e1oi.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+impl std::ops::BitOr<Line> for Line {
+	type Output = S;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Line> for Line {
+	type Output = Zero;
+	fn wedge(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Line> for Line {
+	type Output = Zero;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Line) -> Ei
+impl AntiWedge<Line> for Line {
+	type Output = Ei;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0)
+		self.e12i.anti_wedge(rhs.e3oi)
+			+ self.e13i.anti_wedge(rhs.e2oi)
+			+ self.e1oi.anti_wedge(rhs.e23i)
+			+ self.e23i.anti_wedge(rhs.e1oi)
+			+ self.e2oi.anti_wedge(rhs.e13i)
+			+ self.e3oi.anti_wedge(rhs.e12i)
+	}
+}
+
+impl std::ops::BitAnd<Line> for Line {
+	type Output = Ei;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line.left_contraction(Line) -> S
+impl LeftContraction<Line> for Line {
+	type Output = S;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// S(self.e1oi.0 * rhs.e1oi.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e1oi.0 * rhs.e1oi.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Line.right_contraction(Line) -> S
+impl RightContraction<Line> for Line {
+	type Output = S;
+	fn right_contraction(self, rhs: Line) -> Self::Output {
+		// S(self.e1oi.0 * rhs.e1oi.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e1oi.0 * rhs.e1oi.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Line.scalar_product(Line) -> S
+impl ScalarProduct<Line> for Line {
+	type Output = S;
+	fn scalar_product(self, rhs: Line) -> Self::Output {
+		// S(self.e1oi.0 * rhs.e1oi.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e1oi.0 * rhs.e1oi.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Line.commutator(Line) -> PointPair
+impl Commutator<Line> for Line {
+	type Output = PointPair;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+		//     e13: E13(self.e1oi.0 * rhs.e3oi.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23: E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e12i.0 * rhs.e1oi.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+			e13: E13(self.e1oi.0 * rhs.e3oi.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12i.0 * rhs.e1oi.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Line.anti_dot(Line) -> E123oi
+impl AntiDot<Line> for Line {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// -E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0)
+		-E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Circle:
+
+// Line.geometric(Circle) -> Versor
+impl Geometric<Circle> for Line {
+	type Output = Versor;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12i.0 * rhs.e12o.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e12  : E12(self.e13i.0 * rhs.e23o.0) + E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e23i.0 * rhs.e13o.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+		//     e13  : -E13(self.e12i.0 * rhs.e23o.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o  : E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+		//     e1i  : E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e23i.0 * rhs.e123.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23  : E23(self.e12i.0 * rhs.e13o.0) - E23(self.e13i.0 * rhs.e12o.0) + E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o  : -E2o(self.e1oi.0 * rhs.e12o.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+		//     e2i  : -E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o  : -E3o(self.e1oi.0 * rhs.e13o.0) - E3o(self.e2oi.0 * rhs.e23o.0),
+		//     e3i  : E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0),
+		//     eoi  : Eoi(self.e12i.0 * rhs.e12o.0) + Eoi(self.e13i.0 * rhs.e13o.0) + Eoi(self.e23i.0 * rhs.e23o.0),
+		//     e123o: -E123o(self.e1oi.0 * rhs.e23o.0) + E123o(self.e2oi.0 * rhs.e13o.0) - E123o(self.e3oi.0 * rhs.e12o.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e3oi.0) - E123i(self.e13i.0 * rhs.e2oi.0) + E123i(self.e1oi.0 * rhs.e23i.0) + E123i(self.e23i.0 * rhs.e1oi.0) - E123i(self.e2oi.0 * rhs.e13i.0) + E123i(self.e3oi.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.e13i.0 * rhs.e23o.0) - E12oi(self.e23i.0 * rhs.e13o.0) + E12oi(self.e3oi.0 * rhs.e123.0),
+		//     e13oi: -E13oi(self.e12i.0 * rhs.e23o.0) + E13oi(self.e23i.0 * rhs.e12o.0) - E13oi(self.e2oi.0 * rhs.e123.0),
+		//     e23oi: E23oi(self.e12i.0 * rhs.e13o.0) - E23oi(self.e13i.0 * rhs.e12o.0) + E23oi(self.e1oi.0 * rhs.e123.0),
+		// }
+		Versor {
+			s: S(self.e12i.0 * rhs.e12o.0)
+				+ S(self.e13i.0 * rhs.e13o.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23i.0 * rhs.e23o.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e12: E12(self.e13i.0 * rhs.e23o.0) + E12(self.e1oi.0 * rhs.e2oi.0)
+				- E12(self.e23i.0 * rhs.e13o.0)
+				- E12(self.e2oi.0 * rhs.e1oi.0),
+			e13: -E13(self.e12i.0 * rhs.e23o.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0)
+				- E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+			e1i: E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e23i.0 * rhs.e123.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e12i.0 * rhs.e13o.0) - E23(self.e13i.0 * rhs.e12o.0) + E23(self.e2oi.0 * rhs.e3oi.0)
+				- E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: -E2o(self.e1oi.0 * rhs.e12o.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+			e2i: -E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0)
+				+ E2i(self.e1oi.0 * rhs.e12i.0)
+				+ E2i(self.e23i.0 * rhs.e3oi.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: -E3o(self.e1oi.0 * rhs.e13o.0) - E3o(self.e2oi.0 * rhs.e23o.0),
+			e3i: E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0)
+				- E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0),
+			eoi: Eoi(self.e12i.0 * rhs.e12o.0) + Eoi(self.e13i.0 * rhs.e13o.0) + Eoi(self.e23i.0 * rhs.e23o.0),
+			e123o: -E123o(self.e1oi.0 * rhs.e23o.0) + E123o(self.e2oi.0 * rhs.e13o.0) - E123o(self.e3oi.0 * rhs.e12o.0),
+			e123i: E123i(self.e12i.0 * rhs.e3oi.0) - E123i(self.e13i.0 * rhs.e2oi.0)
+				+ E123i(self.e1oi.0 * rhs.e23i.0)
+				+ E123i(self.e23i.0 * rhs.e1oi.0)
+				- E123i(self.e2oi.0 * rhs.e13i.0)
+				+ E123i(self.e3oi.0 * rhs.e12i.0),
+			e12oi: E12oi(self.e13i.0 * rhs.e23o.0) - E12oi(self.e23i.0 * rhs.e13o.0) + E12oi(self.e3oi.0 * rhs.e123.0),
+			e13oi: -E13oi(self.e12i.0 * rhs.e23o.0) + E13oi(self.e23i.0 * rhs.e12o.0) - E13oi(self.e2oi.0 * rhs.e123.0),
+			e23oi: E23oi(self.e12i.0 * rhs.e13o.0) - E23oi(self.e13i.0 * rhs.e12o.0) + E23oi(self.e1oi.0 * rhs.e123.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for Line {
+	type Output = Versor;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Circle) -> Multivector
+impl AntiGeometric<Circle> for Line {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12i.0 * rhs.e13o.0) - E1(self.e13i.0 * rhs.e12o.0) + E1(self.e1oi.0 * rhs.e123.0),
+		//     e2    : E2(self.e12i.0 * rhs.e23o.0) - E2(self.e23i.0 * rhs.e12o.0) + E2(self.e2oi.0 * rhs.e123.0),
+		//     e3    : E3(self.e13i.0 * rhs.e23o.0) - E3(self.e23i.0 * rhs.e13o.0) + E3(self.e3oi.0 * rhs.e123.0),
+		//     eo    : Eo(self.e1oi.0 * rhs.e23o.0) - Eo(self.e2oi.0 * rhs.e13o.0) + Eo(self.e3oi.0 * rhs.e12o.0),
+		//     ei    : Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12i.0 * rhs.e12o.0) + E123(self.e13i.0 * rhs.e13o.0) + E123(self.e23i.0 * rhs.e23o.0),
+		//     e12o  : E12o(self.e1oi.0 * rhs.e13o.0) + E12o(self.e2oi.0 * rhs.e23o.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.e123.0) - E12i(self.e13i.0 * rhs.e1oi.0) + E12i(self.e1oi.0 * rhs.e13i.0) - E12i(self.e23i.0 * rhs.e2oi.0) + E12i(self.e2oi.0 * rhs.e23i.0),
+		//     e13o  : -E13o(self.e1oi.0 * rhs.e12o.0) + E13o(self.e3oi.0 * rhs.e23o.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.e1oi.0) + E13i(self.e13i.0 * rhs.e123.0) - E13i(self.e1oi.0 * rhs.e12i.0) - E13i(self.e23i.0 * rhs.e3oi.0) + E13i(self.e3oi.0 * rhs.e23i.0),
+		//     e1oi  : -E1oi(self.e12i.0 * rhs.e13o.0) + E1oi(self.e13i.0 * rhs.e12o.0) - E1oi(self.e2oi.0 * rhs.e3oi.0) + E1oi(self.e3oi.0 * rhs.e2oi.0),
+		//     e23o  : -E23o(self.e2oi.0 * rhs.e12o.0) - E23o(self.e3oi.0 * rhs.e13o.0),
+		//     e23i  : E23i(self.e12i.0 * rhs.e2oi.0) + E23i(self.e13i.0 * rhs.e3oi.0) + E23i(self.e23i.0 * rhs.e123.0) - E23i(self.e2oi.0 * rhs.e12i.0) - E23i(self.e3oi.0 * rhs.e13i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e23o.0) + E2oi(self.e1oi.0 * rhs.e3oi.0) + E2oi(self.e23i.0 * rhs.e12o.0) - E2oi(self.e3oi.0 * rhs.e1oi.0),
+		//     e3oi  : -E3oi(self.e13i.0 * rhs.e23o.0) - E3oi(self.e1oi.0 * rhs.e2oi.0) + E3oi(self.e23i.0 * rhs.e13o.0) + E3oi(self.e2oi.0 * rhs.e1oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e12i.0 * rhs.e12o.0) - E123oi(self.e13i.0 * rhs.e13o.0) - E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e23i.0 * rhs.e23o.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12i.0 * rhs.e13o.0) - E1(self.e13i.0 * rhs.e12o.0) + E1(self.e1oi.0 * rhs.e123.0),
+			e2: E2(self.e12i.0 * rhs.e23o.0) - E2(self.e23i.0 * rhs.e12o.0) + E2(self.e2oi.0 * rhs.e123.0),
+			e3: E3(self.e13i.0 * rhs.e23o.0) - E3(self.e23i.0 * rhs.e13o.0) + E3(self.e3oi.0 * rhs.e123.0),
+			eo: Eo(self.e1oi.0 * rhs.e23o.0) - Eo(self.e2oi.0 * rhs.e13o.0) + Eo(self.e3oi.0 * rhs.e12o.0),
+			ei: Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0)
+				+ Ei(self.e1oi.0 * rhs.e23i.0)
+				+ Ei(self.e23i.0 * rhs.e1oi.0)
+				- Ei(self.e2oi.0 * rhs.e13i.0)
+				+ Ei(self.e3oi.0 * rhs.e12i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12i.0 * rhs.e12o.0) + E123(self.e13i.0 * rhs.e13o.0) + E123(self.e23i.0 * rhs.e23o.0),
+			e12o: E12o(self.e1oi.0 * rhs.e13o.0) + E12o(self.e2oi.0 * rhs.e23o.0),
+			e12i: E12i(self.e12i.0 * rhs.e123.0) - E12i(self.e13i.0 * rhs.e1oi.0) + E12i(self.e1oi.0 * rhs.e13i.0)
+				- E12i(self.e23i.0 * rhs.e2oi.0)
+				+ E12i(self.e2oi.0 * rhs.e23i.0),
+			e13o: -E13o(self.e1oi.0 * rhs.e12o.0) + E13o(self.e3oi.0 * rhs.e23o.0),
+			e13i: E13i(self.e12i.0 * rhs.e1oi.0) + E13i(self.e13i.0 * rhs.e123.0)
+				- E13i(self.e1oi.0 * rhs.e12i.0)
+				- E13i(self.e23i.0 * rhs.e3oi.0)
+				+ E13i(self.e3oi.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.e12i.0 * rhs.e13o.0) + E1oi(self.e13i.0 * rhs.e12o.0) - E1oi(self.e2oi.0 * rhs.e3oi.0)
+				+ E1oi(self.e3oi.0 * rhs.e2oi.0),
+			e23o: -E23o(self.e2oi.0 * rhs.e12o.0) - E23o(self.e3oi.0 * rhs.e13o.0),
+			e23i: E23i(self.e12i.0 * rhs.e2oi.0) + E23i(self.e13i.0 * rhs.e3oi.0) + E23i(self.e23i.0 * rhs.e123.0)
+				- E23i(self.e2oi.0 * rhs.e12i.0)
+				- E23i(self.e3oi.0 * rhs.e13i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e23o.0) + E2oi(self.e1oi.0 * rhs.e3oi.0) + E2oi(self.e23i.0 * rhs.e12o.0)
+				- E2oi(self.e3oi.0 * rhs.e1oi.0),
+			e3oi: -E3oi(self.e13i.0 * rhs.e23o.0) - E3oi(self.e1oi.0 * rhs.e2oi.0)
+				+ E3oi(self.e23i.0 * rhs.e13o.0)
+				+ E3oi(self.e2oi.0 * rhs.e1oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e12i.0 * rhs.e12o.0)
+				- E123oi(self.e13i.0 * rhs.e13o.0)
+				- E123oi(self.e1oi.0 * rhs.e1oi.0)
+				- E123oi(self.e23i.0 * rhs.e23o.0)
+				- E123oi(self.e2oi.0 * rhs.e2oi.0)
+				- E123oi(self.e3oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Line.dot(Circle) -> S
+impl Dot<Circle> for Line {
+	type Output = S;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// S(self.e12i.0 * rhs.e12o.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e12i.0 * rhs.e12o.0)
+			+ S(self.e13i.0 * rhs.e13o.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23i.0 * rhs.e23o.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+impl std::ops::BitOr<Circle> for Line {
+	type Output = S;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Circle> for Line {
+	type Output = Zero;
+	fn wedge(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Circle> for Line {
+	type Output = Zero;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Circle) -> Point
+impl AntiWedge<Circle> for Line {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12i.0 * rhs.e13o.0) - E1(self.e13i.0 * rhs.e12o.0) + E1(self.e1oi.0 * rhs.e123.0),
+		//     y  : E2(self.e12i.0 * rhs.e23o.0) - E2(self.e23i.0 * rhs.e12o.0) + E2(self.e2oi.0 * rhs.e123.0),
+		//     z  : E3(self.e13i.0 * rhs.e23o.0) - E3(self.e23i.0 * rhs.e13o.0) + E3(self.e3oi.0 * rhs.e123.0),
+		//     o  : Eo(self.e1oi.0 * rhs.e23o.0) - Eo(self.e2oi.0 * rhs.e13o.0) + Eo(self.e3oi.0 * rhs.e12o.0),
+		//     inf: Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		// }
+		Point {
+			x: self.e12i.anti_wedge(rhs.e13o) + self.e13i.anti_wedge(rhs.e12o) + self.e1oi.anti_wedge(rhs.e123),
+			y: self.e12i.anti_wedge(rhs.e23o) + self.e23i.anti_wedge(rhs.e12o) + self.e2oi.anti_wedge(rhs.e123),
+			z: self.e13i.anti_wedge(rhs.e23o) + self.e23i.anti_wedge(rhs.e13o) + self.e3oi.anti_wedge(rhs.e123),
+			o: self.e1oi.anti_wedge(rhs.e23o) + self.e2oi.anti_wedge(rhs.e13o) + self.e3oi.anti_wedge(rhs.e12o),
+			inf: self.e12i.anti_wedge(rhs.e3oi)
+				+ self.e13i.anti_wedge(rhs.e2oi)
+				+ self.e1oi.anti_wedge(rhs.e23i)
+				+ self.e23i.anti_wedge(rhs.e1oi)
+				+ self.e2oi.anti_wedge(rhs.e13i)
+				+ self.e3oi.anti_wedge(rhs.e12i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Circle> for Line {
+	type Output = Point;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line.left_contraction(Circle) -> S
+impl LeftContraction<Circle> for Line {
+	type Output = S;
+	fn left_contraction(self, rhs: Circle) -> Self::Output {
+		// S(self.e12i.0 * rhs.e12o.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e12i.0 * rhs.e12o.0)
+			+ S(self.e13i.0 * rhs.e13o.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23i.0 * rhs.e23o.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Line.right_contraction(Circle) -> S
+impl RightContraction<Circle> for Line {
+	type Output = S;
+	fn right_contraction(self, rhs: Circle) -> Self::Output {
+		// S(self.e12i.0 * rhs.e12o.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e12i.0 * rhs.e12o.0)
+			+ S(self.e13i.0 * rhs.e13o.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23i.0 * rhs.e23o.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Line.scalar_product(Circle) -> S
+impl ScalarProduct<Circle> for Line {
+	type Output = S;
+	fn scalar_product(self, rhs: Circle) -> Self::Output {
+		// S(self.e12i.0 * rhs.e12o.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e12i.0 * rhs.e12o.0)
+			+ S(self.e13i.0 * rhs.e13o.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23i.0 * rhs.e23o.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Line.commutator(Circle) -> PointPair
+impl Commutator<Circle> for Line {
+	type Output = PointPair;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e13i.0 * rhs.e23o.0) + E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e23i.0 * rhs.e13o.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+		//     e13: -E13(self.e12i.0 * rhs.e23o.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o: E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+		//     e1i: E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e23i.0 * rhs.e123.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23: E23(self.e12i.0 * rhs.e13o.0) - E23(self.e13i.0 * rhs.e12o.0) + E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o: -E2o(self.e1oi.0 * rhs.e12o.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+		//     e2i: -E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o: -E3o(self.e1oi.0 * rhs.e13o.0) - E3o(self.e2oi.0 * rhs.e23o.0),
+		//     e3i: E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0),
+		//     eoi: Eoi(self.e12i.0 * rhs.e12o.0) + Eoi(self.e13i.0 * rhs.e13o.0) + Eoi(self.e23i.0 * rhs.e23o.0),
+		// }
+		PointPair {
+			e12: E12(self.e13i.0 * rhs.e23o.0) + E12(self.e1oi.0 * rhs.e2oi.0)
+				- E12(self.e23i.0 * rhs.e13o.0)
+				- E12(self.e2oi.0 * rhs.e1oi.0),
+			e13: -E13(self.e12i.0 * rhs.e23o.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0)
+				- E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+			e1i: E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e23i.0 * rhs.e123.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e12i.0 * rhs.e13o.0) - E23(self.e13i.0 * rhs.e12o.0) + E23(self.e2oi.0 * rhs.e3oi.0)
+				- E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: -E2o(self.e1oi.0 * rhs.e12o.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+			e2i: -E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0)
+				+ E2i(self.e1oi.0 * rhs.e12i.0)
+				+ E2i(self.e23i.0 * rhs.e3oi.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: -E3o(self.e1oi.0 * rhs.e13o.0) - E3o(self.e2oi.0 * rhs.e23o.0),
+			e3i: E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0)
+				- E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0),
+			eoi: Eoi(self.e12i.0 * rhs.e12o.0) + Eoi(self.e13i.0 * rhs.e13o.0) + Eoi(self.e23i.0 * rhs.e23o.0),
+		}
+	}
+}
+
+// Line.anti_dot(Circle) -> E123oi
+impl AntiDot<Circle> for Line {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// -E123oi(self.e12i.0 * rhs.e12o.0) - E123oi(self.e13i.0 * rhs.e13o.0) - E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e23i.0 * rhs.e23o.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0)
+		-E123oi(self.e12i.0 * rhs.e12o.0)
+			- E123oi(self.e13i.0 * rhs.e13o.0)
+			- E123oi(self.e1oi.0 * rhs.e1oi.0)
+			- E123oi(self.e23i.0 * rhs.e23o.0)
+			- E123oi(self.e2oi.0 * rhs.e2oi.0)
+			- E123oi(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Plane:
+
+// Line.geometric(Plane) -> Multivector
+impl Geometric<Plane> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e1oi.0 * rhs.e23oi.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e13i.0 * rhs.e23oi.0) - E12i(self.e23i.0 * rhs.e13oi.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e12i.0 * rhs.e23oi.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e12i.0 * rhs.e13oi.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			eo: Default::default(),
+			ei: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e1oi.0 * rhs.e23oi.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e13i.0 * rhs.e23oi.0) - E12i(self.e23i.0 * rhs.e13oi.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e12i.0 * rhs.e23oi.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e12i.0 * rhs.e13oi.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Plane) -> Versor
+impl AntiGeometric<Plane> for Line {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi  : Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13oi.0) + E123i(self.e23i.0 * rhs.e23oi.0),
+		//     e12oi: E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e2oi.0 * rhs.e23oi.0),
+		//     e13oi: -E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: -E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+			eoi: Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0),
+			e12oi: E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e2oi.0 * rhs.e23oi.0),
+			e13oi: -E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// Line.dot(Plane) -> Point
+impl Dot<Plane> for Line {
+	type Output = Point;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			z: E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			o: Default::default(),
+			inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Line {
+	type Output = Point;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for Line {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Line {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Plane) -> FlatPoint
+impl AntiWedge<Plane> for Line {
+	type Output = FlatPoint;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e2i: E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3i: E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		// }
+		FlatPoint {
+			e1i: self.e12i.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123i),
+			e2i: self.e12i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123i),
+			e3i: self.e13i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123i),
+			eoi: self.e1oi.anti_wedge(rhs.e23oi) + self.e2oi.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e12oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Line {
+	type Output = FlatPoint;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line.left_contraction(Plane) -> Point
+impl LeftContraction<Plane> for Line {
+	type Output = Point;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			z: E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			o: Default::default(),
+			inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Plane> for Line {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(Plane) -> Point
+impl Commutator<Plane> for Line {
+	type Output = Point;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			z: E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			o: Default::default(),
+			inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Line.anti_dot(Plane) -> Plane
+impl AntiDot<Plane> for Line {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13oi.0) + E123i(self.e23i.0 * rhs.e23oi.0),
+		//     e12oi: E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e2oi.0 * rhs.e23oi.0),
+		//     e13oi: -E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: -E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Plane {
+			e123i: E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0),
+			e12oi: E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e2oi.0 * rhs.e23oi.0),
+			e13oi: -E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Sphere:
+
+// Line.geometric(Sphere) -> Multivector
+impl Geometric<Sphere> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e1oi.0 * rhs.e23oi.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+		//     e12o  : -E12o(self.e3oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e13i.0 * rhs.e23oi.0) - E12i(self.e23i.0 * rhs.e13oi.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e2oi.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.e12i.0 * rhs.e23oi.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.e23i.0 * rhs.e123o.0),
+		//     e23o  : -E23o(self.e1oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e12i.0 * rhs.e13oi.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e13i.0 * rhs.e123o.0),
+		//     e3oi  : -E3oi(self.e12i.0 * rhs.e123o.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			eo: Default::default(),
+			ei: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e1oi.0 * rhs.e23oi.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+			e12o: -E12o(self.e3oi.0 * rhs.e123o.0),
+			e12i: E12i(self.e13i.0 * rhs.e23oi.0) - E12i(self.e23i.0 * rhs.e13oi.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+			e13o: E13o(self.e2oi.0 * rhs.e123o.0),
+			e13i: -E13i(self.e12i.0 * rhs.e23oi.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.e23i.0 * rhs.e123o.0),
+			e23o: -E23o(self.e1oi.0 * rhs.e123o.0),
+			e23i: E23i(self.e12i.0 * rhs.e13oi.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e13i.0 * rhs.e123o.0),
+			e3oi: -E3oi(self.e12i.0 * rhs.e123o.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Sphere) -> Versor
+impl AntiGeometric<Sphere> for Line {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e12i.0 * rhs.e123o.0),
+		//     e13  : E13(self.e13i.0 * rhs.e123o.0),
+		//     e1o  : E1o(self.e1oi.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23  : E23(self.e23i.0 * rhs.e123o.0),
+		//     e2o  : E2o(self.e2oi.0 * rhs.e123o.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi  : Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13oi.0) + E123i(self.e23i.0 * rhs.e23oi.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.e123o.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e2oi.0 * rhs.e23oi.0),
+		//     e13oi: -E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: -E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e12i.0 * rhs.e123o.0),
+			e13: E13(self.e13i.0 * rhs.e123o.0),
+			e1o: E1o(self.e1oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+			e23: E23(self.e23i.0 * rhs.e123o.0),
+			e2o: E2o(self.e2oi.0 * rhs.e123o.0),
+			e2i: E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+			e3o: E3o(self.e3oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+			eoi: Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.e123o.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0),
+			e13oi: -E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// Line.dot(Sphere) -> Point
+impl Dot<Sphere> for Line {
+	type Output = Point;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			o: Default::default(),
+			inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Sphere> for Line {
+	type Output = Point;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Sphere> for Line {
+	type Output = Zero;
+	fn wedge(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Sphere> for Line {
+	type Output = Zero;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Sphere) -> PointPair
+impl AntiWedge<Sphere> for Line {
+	type Output = PointPair;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e12i.0 * rhs.e123o.0),
+		//     e13: E13(self.e13i.0 * rhs.e123o.0),
+		//     e1o: E1o(self.e1oi.0 * rhs.e123o.0),
+		//     e1i: E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23: E23(self.e23i.0 * rhs.e123o.0),
+		//     e2o: E2o(self.e2oi.0 * rhs.e123o.0),
+		//     e2i: E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o: E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i: E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi: Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		// }
+		PointPair {
+			e12: self.e12i.anti_wedge(rhs.e123o),
+			e13: self.e13i.anti_wedge(rhs.e123o),
+			e1o: self.e1oi.anti_wedge(rhs.e123o),
+			e1i: self.e12i.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123i),
+			e23: self.e23i.anti_wedge(rhs.e123o),
+			e2o: self.e2oi.anti_wedge(rhs.e123o),
+			e2i: self.e12i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123i),
+			e3o: self.e3oi.anti_wedge(rhs.e123o),
+			e3i: self.e13i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123i),
+			eoi: self.e1oi.anti_wedge(rhs.e23oi) + self.e2oi.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e12oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for Line {
+	type Output = PointPair;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line.left_contraction(Sphere) -> Point
+impl LeftContraction<Sphere> for Line {
+	type Output = Point;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			o: Default::default(),
+			inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Sphere> for Line {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Sphere> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(Sphere) -> Point
+impl Commutator<Sphere> for Line {
+	type Output = Point;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			o: Default::default(),
+			inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Line.anti_dot(Sphere) -> Plane
+impl AntiDot<Sphere> for Line {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13oi.0) + E123i(self.e23i.0 * rhs.e23oi.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.e123o.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e2oi.0 * rhs.e23oi.0),
+		//     e13oi: -E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: -E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Plane {
+			e123i: E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.e123o.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0),
+			e13oi: -E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Rotor:
+
+// Line.geometric(Rotor) -> Multivector
+impl Geometric<Rotor> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e23i.0 * rhs.e13.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e12i.0 * rhs.e23.0) + E13i(self.e13i.0 * rhs.s.0) - E13i(self.e23i.0 * rhs.e12.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) + E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e2oi.0 * rhs.s.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : E3oi(self.e1oi.0 * rhs.e13.0) + E3oi(self.e2oi.0 * rhs.e23.0) + E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e1oi.0 * rhs.e23.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e12i.0 * rhs.s.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e23i.0 * rhs.e13.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e12i.0 * rhs.e23.0) + E13i(self.e13i.0 * rhs.s.0) - E13i(self.e23i.0 * rhs.e12.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) + E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e2oi.0 * rhs.s.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: E3oi(self.e1oi.0 * rhs.e13.0) + E3oi(self.e2oi.0 * rhs.e23.0) + E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e1oi.0 * rhs.e23.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Rotor) -> Versor
+impl AntiGeometric<Rotor> for Line {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1oi.0 * rhs.e23.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e12  : E12(self.e1oi.0 * rhs.e13.0) + E12(self.e2oi.0 * rhs.e23.0) - E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : -E13(self.e1oi.0 * rhs.e12.0) + E13(self.e2oi.0 * rhs.s.0) + E13(self.e3oi.0 * rhs.e23.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12i.0 * rhs.e13.0) - E1i(self.e13i.0 * rhs.e12.0) + E1i(self.e23i.0 * rhs.s.0),
+		//     e23  : -E23(self.e1oi.0 * rhs.s.0) - E23(self.e2oi.0 * rhs.e12.0) - E23(self.e3oi.0 * rhs.e13.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e12i.0 * rhs.e23.0) - E2i(self.e13i.0 * rhs.s.0) - E2i(self.e23i.0 * rhs.e12.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e12i.0 * rhs.s.0) + E3i(self.e13i.0 * rhs.e23.0) - E3i(self.e23i.0 * rhs.e13.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e23i.0 * rhs.e23.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1oi.0 * rhs.e23.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+			e12: E12(self.e1oi.0 * rhs.e13.0) + E12(self.e2oi.0 * rhs.e23.0) - E12(self.e3oi.0 * rhs.s.0),
+			e13: -E13(self.e1oi.0 * rhs.e12.0) + E13(self.e2oi.0 * rhs.s.0) + E13(self.e3oi.0 * rhs.e23.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12i.0 * rhs.e13.0) - E1i(self.e13i.0 * rhs.e12.0) + E1i(self.e23i.0 * rhs.s.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0) - E23(self.e2oi.0 * rhs.e12.0) - E23(self.e3oi.0 * rhs.e13.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e12i.0 * rhs.e23.0) - E2i(self.e13i.0 * rhs.s.0) - E2i(self.e23i.0 * rhs.e12.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e12i.0 * rhs.s.0) + E3i(self.e13i.0 * rhs.e23.0) - E3i(self.e23i.0 * rhs.e13.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e23i.0 * rhs.e23.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Line.dot(Rotor) -> Multivector
+impl Dot<Rotor> for Line {
+	type Output = Multivector;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Line {
+	type Output = Multivector;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(Rotor) -> Multivector
+impl Wedge<Rotor> for Line {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e1oi.0 * rhs.e23.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.e12i.wedge(rhs.s),
+			e13o: Default::default(),
+			e13i: self.e13i.wedge(rhs.s),
+			e1oi: self.e1oi.wedge(rhs.s),
+			e23o: Default::default(),
+			e23i: self.e23i.wedge(rhs.s),
+			e2oi: self.e2oi.wedge(rhs.s),
+			e3oi: self.e3oi.wedge(rhs.s),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e1oi.wedge(rhs.e23) + self.e2oi.wedge(rhs.e13) + self.e3oi.wedge(rhs.e12),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Line {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Rotor) -> S
+impl AntiWedge<Rotor> for Line {
+	type Output = S;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// S(self.e1oi.0 * rhs.e23.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0)
+		self.e1oi.anti_wedge(rhs.e23) + self.e2oi.anti_wedge(rhs.e13) + self.e3oi.anti_wedge(rhs.e12)
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Line {
+	type Output = S;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Rotor> for Line {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.right_contraction(Rotor) -> Multivector
+impl RightContraction<Rotor> for Line {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) - Ei(self.e23i.0 * rhs.e23.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(Rotor) -> Line
+impl Commutator<Rotor> for Line {
+	type Output = Line;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Line {
+		//     e12i: -E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e23i.0 * rhs.e13.0),
+		//     e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e23i.0 * rhs.e12.0),
+		//     e1oi: -E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0),
+		//     e2oi: E2oi(self.e1oi.0 * rhs.e12.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi: E3oi(self.e1oi.0 * rhs.e13.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		// }
+		Line {
+			e12i: -E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e23i.0 * rhs.e13.0),
+			e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e23i.0 * rhs.e12.0),
+			e1oi: -E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+			e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0),
+			e2oi: E2oi(self.e1oi.0 * rhs.e12.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: E3oi(self.e1oi.0 * rhs.e13.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Line.anti_dot(Rotor) -> Versor
+impl AntiDot<Rotor> for Line {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : E13(self.e2oi.0 * rhs.s.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e23i.0 * rhs.s.0),
+		//     e23  : -E23(self.e1oi.0 * rhs.s.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e13i.0 * rhs.s.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e12i.0 * rhs.s.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e23i.0 * rhs.e23.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e2oi.0 * rhs.s.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e23i.0 * rhs.s.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e13i.0 * rhs.s.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e12i.0 * rhs.s.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e23i.0 * rhs.e23.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Translator:
+
+// Line.geometric(Translator) -> Multivector
+impl Geometric<Translator> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e12i.0 * rhs.s.0) + E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13i.0 * rhs.s.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e23i.0 * rhs.s.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Translator) -> Versor
+impl AntiGeometric<Translator> for Line {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : E13(self.e2oi.0 * rhs.s.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e23i.0 * rhs.s.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+		//     e23  : -E23(self.e1oi.0 * rhs.s.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e13i.0 * rhs.s.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e12i.0 * rhs.s.0) - E3i(self.e1oi.0 * rhs.e2i.0) + E3i(self.e2oi.0 * rhs.e1i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e2oi.0 * rhs.s.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e23i.0 * rhs.s.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e13i.0 * rhs.s.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e12i.0 * rhs.s.0) - E3i(self.e1oi.0 * rhs.e2i.0) + E3i(self.e2oi.0 * rhs.e1i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Line.dot(Translator) -> Multivector
+impl Dot<Translator> for Line {
+	type Output = Multivector;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Line {
+	type Output = Multivector;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(Translator) -> Line
+impl Wedge<Translator> for Line {
+	type Output = Line;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.e12i.0 * rhs.s.0),
+		//     e13i: E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23i: E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+		// }
+		Line {
+			e12i: self.e12i.wedge(rhs.s),
+			e13i: self.e13i.wedge(rhs.s),
+			e1oi: self.e1oi.wedge(rhs.s),
+			e23i: self.e23i.wedge(rhs.s),
+			e2oi: self.e2oi.wedge(rhs.s),
+			e3oi: self.e3oi.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Line {
+	type Output = Line;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Translator> for Line {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Line {
+	type Output = Zero;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Translator> for Line {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.right_contraction(Translator) -> Multivector
+impl RightContraction<Translator> for Line {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e1oi.0 * rhs.e1i.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(Translator) -> Line
+impl Commutator<Translator> for Line {
+	type Output = Line;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13i: E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi: Default::default(),
+		//     e23i: E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Line {
+			e12i: E12i(self.e1oi.0 * rhs.e2i.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+			e13i: E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: Default::default(),
+			e23i: E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// Line.anti_dot(Translator) -> Versor
+impl AntiDot<Translator> for Line {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : E13(self.e2oi.0 * rhs.s.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e23i.0 * rhs.s.0),
+		//     e23  : -E23(self.e1oi.0 * rhs.s.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e13i.0 * rhs.s.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e12i.0 * rhs.s.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e2oi.0 * rhs.s.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e23i.0 * rhs.s.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e13i.0 * rhs.s.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e12i.0 * rhs.s.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1oi.0 * rhs.e1i.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Versor:
+
+// Line.geometric(Versor) -> Multivector
+impl Geometric<Versor> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0) - E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e13i.0 * rhs.e1o.0) + E3(self.e1oi.0 * rhs.e13oi.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e2oi.0 * rhs.e23oi.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e23i.0 * rhs.e23oi.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0) + E123(self.e1oi.0 * rhs.e23oi.0) + E123(self.e23i.0 * rhs.e1o.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+		//     e12o  : -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0) - E12o(self.e3oi.0 * rhs.e123o.0),
+		//     e12i  : -E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e12i.0 * rhs.s.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e13i.0 * rhs.e23oi.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e23i.0 * rhs.e13oi.0) - E12i(self.e2oi.0 * rhs.e1i.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e2oi.0 * rhs.e123o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e12i.0 * rhs.e23oi.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e13i.0 * rhs.s.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0) + E1oi(self.e1oi.0 * rhs.s.0) - E1oi(self.e23i.0 * rhs.e123o.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : -E23o(self.e1oi.0 * rhs.e123o.0) - E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i  : -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e12i.0 * rhs.e13oi.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e23i.0 * rhs.s.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e13i.0 * rhs.e123o.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) + E2oi(self.e2oi.0 * rhs.s.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : -E3oi(self.e12i.0 * rhs.e123o.0) - E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e2oi.0 * rhs.e23.0) + E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0)
+				- E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: -E2(self.e12i.0 * rhs.e1o.0)
+				+ E2(self.e13i.0 * rhs.e123o.0)
+				+ E2(self.e1oi.0 * rhs.e12oi.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e13i.0 * rhs.e1o.0) + E3(self.e1oi.0 * rhs.e13oi.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0)
+				+ E123(self.e1oi.0 * rhs.e23oi.0)
+				+ E123(self.e23i.0 * rhs.e1o.0)
+				- E123(self.e2oi.0 * rhs.e13oi.0)
+				+ E123(self.e3oi.0 * rhs.e12oi.0),
+			e12o: -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0) - E12o(self.e3oi.0 * rhs.e123o.0),
+			e12i: -E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e12i.0 * rhs.s.0) - E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e13i.0 * rhs.e23oi.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e23i.0 * rhs.e13oi.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0)
+				+ E12i(self.e3oi.0 * rhs.e123i.0),
+			e13o: -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e2oi.0 * rhs.e123o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e12i.0 * rhs.e23oi.0) - E13i(self.e13i.0 * rhs.eoi.0)
+				+ E13i(self.e13i.0 * rhs.s.0)
+				+ E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				+ E13i(self.e23i.0 * rhs.e12oi.0)
+				- E13i(self.e2oi.0 * rhs.e123i.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0) + E1oi(self.e1oi.0 * rhs.s.0)
+				- E1oi(self.e23i.0 * rhs.e123o.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: -E23o(self.e1oi.0 * rhs.e123o.0) - E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e12i.0 * rhs.e13oi.0) + E23i(self.e13i.0 * rhs.e12.0)
+				- E23i(self.e13i.0 * rhs.e12oi.0)
+				+ E23i(self.e1oi.0 * rhs.e123i.0)
+				- E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e23i.0 * rhs.s.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e1o.0)
+				+ E2oi(self.e13i.0 * rhs.e123o.0)
+				+ E2oi(self.e1oi.0 * rhs.e12.0)
+				+ E2oi(self.e23i.0 * rhs.e3o.0)
+				+ E2oi(self.e2oi.0 * rhs.s.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e12i.0 * rhs.e123o.0) - E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0)
+				- E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0)
+				+ E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e13i.0 * rhs.e2o.0)
+				+ E123oi(self.e1oi.0 * rhs.e23.0)
+				+ E123oi(self.e23i.0 * rhs.e1o.0)
+				- E123oi(self.e2oi.0 * rhs.e13.0)
+				+ E123oi(self.e3oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Versor) -> Versor
+impl AntiGeometric<Versor> for Line {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12i.0 * rhs.e3o.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e12  : E12(self.e12i.0 * rhs.e123o.0) - E12(self.e13i.0 * rhs.e1o.0) + E12(self.e1oi.0 * rhs.e13.0) - E12(self.e23i.0 * rhs.e2o.0) + E12(self.e2oi.0 * rhs.e23.0) - E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : E13(self.e12i.0 * rhs.e1o.0) + E13(self.e13i.0 * rhs.e123o.0) - E13(self.e1oi.0 * rhs.e12.0) - E13(self.e23i.0 * rhs.e3o.0) + E13(self.e2oi.0 * rhs.s.0) + E13(self.e3oi.0 * rhs.e23.0),
+		//     e1o  : E1o(self.e1oi.0 * rhs.e123o.0) - E1o(self.e2oi.0 * rhs.e3o.0) + E1o(self.e3oi.0 * rhs.e2o.0),
+		//     e1i  : E1i(self.e12i.0 * rhs.e13.0) + E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0) + E1i(self.e23i.0 * rhs.eoi.0) + E1i(self.e23i.0 * rhs.s.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+		//     e23  : E23(self.e12i.0 * rhs.e2o.0) + E23(self.e13i.0 * rhs.e3o.0) - E23(self.e1oi.0 * rhs.s.0) + E23(self.e23i.0 * rhs.e123o.0) - E23(self.e2oi.0 * rhs.e12.0) - E23(self.e3oi.0 * rhs.e13.0),
+		//     e2o  : E2o(self.e1oi.0 * rhs.e3o.0) + E2o(self.e2oi.0 * rhs.e123o.0) - E2o(self.e3oi.0 * rhs.e1o.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.e23.0) + E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e13i.0 * rhs.eoi.0) - E2i(self.e13i.0 * rhs.s.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e23i.0 * rhs.e12.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+		//     e3o  : -E3o(self.e1oi.0 * rhs.e2o.0) + E3o(self.e2oi.0 * rhs.e1o.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.e12i.0 * rhs.eoi.0) + E3i(self.e12i.0 * rhs.s.0) + E3i(self.e13i.0 * rhs.e23.0) + E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e1oi.0 * rhs.e2i.0) - E3i(self.e23i.0 * rhs.e13.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e2oi.0 * rhs.e1i.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e12i.0 * rhs.e3o.0) + Eoi(self.e13i.0 * rhs.e2o.0) + Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e23i.0 * rhs.e1o.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123o: -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e13i.0 * rhs.e13oi.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) + E123i(self.e23i.0 * rhs.e23oi.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.e123o.0) + E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e23i.0 * rhs.e2o.0) + E12oi(self.e2oi.0 * rhs.e23oi.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: -E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e2oi.0 * rhs.eoi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: -E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e1oi.0 * rhs.eoi.0) - E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Versor {
+			s: S(self.e12i.0 * rhs.e3o.0) - S(self.e13i.0 * rhs.e2o.0)
+				+ S(self.e1oi.0 * rhs.e23.0)
+				+ S(self.e23i.0 * rhs.e1o.0)
+				- S(self.e2oi.0 * rhs.e13.0)
+				+ S(self.e3oi.0 * rhs.e12.0),
+			e12: E12(self.e12i.0 * rhs.e123o.0) - E12(self.e13i.0 * rhs.e1o.0) + E12(self.e1oi.0 * rhs.e13.0)
+				- E12(self.e23i.0 * rhs.e2o.0)
+				+ E12(self.e2oi.0 * rhs.e23.0)
+				- E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e12i.0 * rhs.e1o.0) + E13(self.e13i.0 * rhs.e123o.0)
+				- E13(self.e1oi.0 * rhs.e12.0)
+				- E13(self.e23i.0 * rhs.e3o.0)
+				+ E13(self.e2oi.0 * rhs.s.0)
+				+ E13(self.e3oi.0 * rhs.e23.0),
+			e1o: E1o(self.e1oi.0 * rhs.e123o.0) - E1o(self.e2oi.0 * rhs.e3o.0) + E1o(self.e3oi.0 * rhs.e2o.0),
+			e1i: E1i(self.e12i.0 * rhs.e13.0) + E1i(self.e12i.0 * rhs.e13oi.0)
+				- E1i(self.e13i.0 * rhs.e12.0)
+				- E1i(self.e13i.0 * rhs.e12oi.0)
+				+ E1i(self.e1oi.0 * rhs.e123i.0)
+				+ E1i(self.e23i.0 * rhs.eoi.0)
+				+ E1i(self.e23i.0 * rhs.s.0)
+				- E1i(self.e2oi.0 * rhs.e3i.0)
+				+ E1i(self.e3oi.0 * rhs.e2i.0),
+			e23: E23(self.e12i.0 * rhs.e2o.0) + E23(self.e13i.0 * rhs.e3o.0) - E23(self.e1oi.0 * rhs.s.0)
+				+ E23(self.e23i.0 * rhs.e123o.0)
+				- E23(self.e2oi.0 * rhs.e12.0)
+				- E23(self.e3oi.0 * rhs.e13.0),
+			e2o: E2o(self.e1oi.0 * rhs.e3o.0) + E2o(self.e2oi.0 * rhs.e123o.0) - E2o(self.e3oi.0 * rhs.e1o.0),
+			e2i: E2i(self.e12i.0 * rhs.e23.0) + E2i(self.e12i.0 * rhs.e23oi.0)
+				- E2i(self.e13i.0 * rhs.eoi.0)
+				- E2i(self.e13i.0 * rhs.s.0)
+				+ E2i(self.e1oi.0 * rhs.e3i.0)
+				- E2i(self.e23i.0 * rhs.e12.0)
+				- E2i(self.e23i.0 * rhs.e12oi.0)
+				+ E2i(self.e2oi.0 * rhs.e123i.0)
+				- E2i(self.e3oi.0 * rhs.e1i.0),
+			e3o: -E3o(self.e1oi.0 * rhs.e2o.0) + E3o(self.e2oi.0 * rhs.e1o.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e12i.0 * rhs.eoi.0)
+				+ E3i(self.e12i.0 * rhs.s.0)
+				+ E3i(self.e13i.0 * rhs.e23.0)
+				+ E3i(self.e13i.0 * rhs.e23oi.0)
+				- E3i(self.e1oi.0 * rhs.e2i.0)
+				- E3i(self.e23i.0 * rhs.e13.0)
+				- E3i(self.e23i.0 * rhs.e13oi.0)
+				+ E3i(self.e2oi.0 * rhs.e1i.0)
+				+ E3i(self.e3oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e12i.0 * rhs.e3o.0) + Eoi(self.e13i.0 * rhs.e2o.0) + Eoi(self.e1oi.0 * rhs.e23oi.0)
+				- Eoi(self.e23i.0 * rhs.e1o.0)
+				- Eoi(self.e2oi.0 * rhs.e13oi.0)
+				+ Eoi(self.e3oi.0 * rhs.e12oi.0),
+			e123o: -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: E123i(self.e12i.0 * rhs.e12.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				- E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.e123o.0)
+				+ E12oi(self.e13i.0 * rhs.e1o.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e23i.0 * rhs.e2o.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0)
+				- E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: -E13oi(self.e12i.0 * rhs.e1o.0)
+				- E13oi(self.e13i.0 * rhs.e123o.0)
+				- E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e23i.0 * rhs.e3o.0)
+				+ E13oi(self.e2oi.0 * rhs.eoi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e12i.0 * rhs.e2o.0)
+				- E23oi(self.e13i.0 * rhs.e3o.0)
+				- E23oi(self.e1oi.0 * rhs.eoi.0)
+				- E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// Line.dot(Versor) -> Multivector
+impl Dot<Versor> for Line {
+	type Output = Multivector;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0) - E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e13i.0 * rhs.e1o.0) + E3(self.e1oi.0 * rhs.e13oi.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e2oi.0 * rhs.e23oi.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e23i.0 * rhs.e23oi.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0)
+				- E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: -E2(self.e12i.0 * rhs.e1o.0)
+				+ E2(self.e13i.0 * rhs.e123o.0)
+				+ E2(self.e1oi.0 * rhs.e12oi.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e13i.0 * rhs.e1o.0) + E3(self.e1oi.0 * rhs.e13oi.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for Line {
+	type Output = Multivector;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(Versor) -> Multivector
+impl Wedge<Versor> for Line {
+	type Output = Multivector;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.e12i.wedge(rhs.s),
+			e13o: Default::default(),
+			e13i: self.e13i.wedge(rhs.s),
+			e1oi: self.e1oi.wedge(rhs.s),
+			e23o: Default::default(),
+			e23i: self.e23i.wedge(rhs.s),
+			e2oi: self.e2oi.wedge(rhs.s),
+			e3oi: self.e3oi.wedge(rhs.s),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e12i.wedge(rhs.e3o)
+				+ self.e13i.wedge(rhs.e2o)
+				+ self.e1oi.wedge(rhs.e23)
+				+ self.e23i.wedge(rhs.e1o)
+				+ self.e2oi.wedge(rhs.e13)
+				+ self.e3oi.wedge(rhs.e12),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for Line {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Versor) -> Versor
+impl AntiWedge<Versor> for Line {
+	type Output = Versor;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12i.0 * rhs.e3o.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e12  : E12(self.e12i.0 * rhs.e123o.0),
+		//     e13  : E13(self.e13i.0 * rhs.e123o.0),
+		//     e1o  : E1o(self.e1oi.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23  : E23(self.e23i.0 * rhs.e123o.0),
+		//     e2o  : E2o(self.e2oi.0 * rhs.e123o.0),
+		//     e2i  : E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi  : Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: self.e12i.anti_wedge(rhs.e3o)
+				+ self.e13i.anti_wedge(rhs.e2o)
+				+ self.e1oi.anti_wedge(rhs.e23)
+				+ self.e23i.anti_wedge(rhs.e1o)
+				+ self.e2oi.anti_wedge(rhs.e13)
+				+ self.e3oi.anti_wedge(rhs.e12),
+			e12: self.e12i.anti_wedge(rhs.e123o),
+			e13: self.e13i.anti_wedge(rhs.e123o),
+			e1o: self.e1oi.anti_wedge(rhs.e123o),
+			e1i: self.e12i.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123i),
+			e23: self.e23i.anti_wedge(rhs.e123o),
+			e2o: self.e2oi.anti_wedge(rhs.e123o),
+			e2i: self.e12i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123i),
+			e3o: self.e3oi.anti_wedge(rhs.e123o),
+			e3i: self.e13i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123i),
+			eoi: self.e1oi.anti_wedge(rhs.e23oi) + self.e2oi.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e12oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Versor> for Line {
+	type Output = Versor;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line.left_contraction(Versor) -> Point
+impl LeftContraction<Versor> for Line {
+	type Output = Point;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     y  : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     z  : -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		// }
+		Point {
+			x: -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			y: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			z: -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			o: Default::default(),
+			inf: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Line.right_contraction(Versor) -> Multivector
+impl RightContraction<Versor> for Line {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     e2    : -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     e3    : -E3(self.e13i.0 * rhs.e1o.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0),
+			e2: -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0),
+			e3: -E3(self.e13i.0 * rhs.e1o.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Versor> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Versor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(Versor) -> Multivector
+impl Commutator<Versor> for Line {
+	type Output = Multivector;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0) + E123(self.e23i.0 * rhs.e1o.0),
+		//     e12o  : -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+		//     e12i  : -E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o  : -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : -E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i  : -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			eo: Default::default(),
+			ei: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0) + E123(self.e23i.0 * rhs.e1o.0),
+			e12o: -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+			e12i: -E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: -E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Line.anti_dot(Versor) -> Versor
+impl AntiDot<Versor> for Line {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e3oi.0 * rhs.s.0),
+		//     e13  : E13(self.e2oi.0 * rhs.s.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e23i.0 * rhs.s.0),
+		//     e23  : -E23(self.e1oi.0 * rhs.s.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e13i.0 * rhs.s.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e12i.0 * rhs.s.0),
+		//     eoi  : Default::default(),
+		//     e123o: -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i: E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e13i.0 * rhs.e13oi.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) + E123i(self.e23i.0 * rhs.e23oi.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi: -E12oi(self.e12i.0 * rhs.e123o.0) + E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e23i.0 * rhs.e2o.0) + E12oi(self.e2oi.0 * rhs.e23oi.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi: -E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e2oi.0 * rhs.eoi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi: -E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e1oi.0 * rhs.eoi.0) - E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e2oi.0 * rhs.s.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e23i.0 * rhs.s.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e13i.0 * rhs.s.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e12i.0 * rhs.s.0),
+			eoi: Default::default(),
+			e123o: -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: E123i(self.e12i.0 * rhs.e12.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				- E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.e123o.0)
+				+ E12oi(self.e13i.0 * rhs.e1o.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e23i.0 * rhs.e2o.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0)
+				- E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: -E13oi(self.e12i.0 * rhs.e1o.0)
+				- E13oi(self.e13i.0 * rhs.e123o.0)
+				- E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e23i.0 * rhs.e3o.0)
+				+ E13oi(self.e2oi.0 * rhs.eoi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e12i.0 * rhs.e2o.0)
+				- E23oi(self.e13i.0 * rhs.e3o.0)
+				- E23oi(self.e1oi.0 * rhs.eoi.0)
+				- E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Multivector:
+
+// Line.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12i.0 * rhs.e12o.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e1    : E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0) - E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e13i.0 * rhs.e1o.0) + E3(self.e1oi.0 * rhs.e13oi.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e2oi.0 * rhs.e23oi.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e23i.0 * rhs.e23oi.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : -E12(self.e12i.0 * rhs.eo.0) + E12(self.e13i.0 * rhs.e23o.0) + E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e23i.0 * rhs.e13o.0) - E12(self.e2oi.0 * rhs.e1oi.0) + E12(self.e3oi.0 * rhs.e123oi.0),
+		//     e13   : -E13(self.e12i.0 * rhs.e23o.0) - E13(self.e13i.0 * rhs.eo.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0) - E13(self.e2oi.0 * rhs.e123oi.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o   : -E1o(self.e1oi.0 * rhs.eo.0) + E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+		//     e1i   : -E1i(self.e12i.0 * rhs.e2.0) + E1i(self.e12i.0 * rhs.e2oi.0) - E1i(self.e13i.0 * rhs.e3.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e1oi.0 * rhs.ei.0) + E1i(self.e23i.0 * rhs.e123.0) - E1i(self.e23i.0 * rhs.e123oi.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23   : E23(self.e12i.0 * rhs.e13o.0) - E23(self.e13i.0 * rhs.e12o.0) + E23(self.e1oi.0 * rhs.e123oi.0) - E23(self.e23i.0 * rhs.eo.0) + E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o   : -E2o(self.e1oi.0 * rhs.e12o.0) - E2o(self.e2oi.0 * rhs.eo.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+		//     e2i   : E2i(self.e12i.0 * rhs.e1.0) - E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0) + E2i(self.e13i.0 * rhs.e123oi.0) + E2i(self.e1oi.0 * rhs.e12i.0) - E2i(self.e23i.0 * rhs.e3.0) + E2i(self.e23i.0 * rhs.e3oi.0) + E2i(self.e2oi.0 * rhs.ei.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o   : -E3o(self.e1oi.0 * rhs.e13o.0) - E3o(self.e2oi.0 * rhs.e23o.0) - E3o(self.e3oi.0 * rhs.eo.0),
+		//     e3i   : E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e12i.0 * rhs.e123oi.0) + E3i(self.e13i.0 * rhs.e1.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) + E3i(self.e23i.0 * rhs.e2.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0) + E3i(self.e3oi.0 * rhs.ei.0),
+		//     eoi   : Eoi(self.e12i.0 * rhs.e12o.0) + Eoi(self.e13i.0 * rhs.e13o.0) + Eoi(self.e1oi.0 * rhs.e1.0) + Eoi(self.e23i.0 * rhs.e23o.0) + Eoi(self.e2oi.0 * rhs.e2.0) + Eoi(self.e3oi.0 * rhs.e3.0),
+		//     e123  : E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0) + E123(self.e1oi.0 * rhs.e23oi.0) + E123(self.e23i.0 * rhs.e1o.0) - E123(self.e2oi.0 * rhs.e13oi.0) + E123(self.e3oi.0 * rhs.e12oi.0),
+		//     e12o  : -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0) - E12o(self.e3oi.0 * rhs.e123o.0),
+		//     e12i  : -E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e12i.0 * rhs.s.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e13i.0 * rhs.e23oi.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e23i.0 * rhs.e13oi.0) - E12i(self.e2oi.0 * rhs.e1i.0) + E12i(self.e3oi.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e2oi.0 * rhs.e123o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e12i.0 * rhs.e23oi.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e13i.0 * rhs.s.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) + E13i(self.e23i.0 * rhs.e12oi.0) - E13i(self.e2oi.0 * rhs.e123i.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0) + E1oi(self.e1oi.0 * rhs.s.0) - E1oi(self.e23i.0 * rhs.e123o.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : -E23o(self.e1oi.0 * rhs.e123o.0) - E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i  : -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e12i.0 * rhs.e13oi.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e13i.0 * rhs.e12oi.0) + E23i(self.e1oi.0 * rhs.e123i.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e23i.0 * rhs.s.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e13i.0 * rhs.e123o.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) + E2oi(self.e2oi.0 * rhs.s.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : -E3oi(self.e12i.0 * rhs.e123o.0) - E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e2oi.0 * rhs.e23.0) + E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : -E123o(self.e1oi.0 * rhs.e23o.0) + E123o(self.e2oi.0 * rhs.e13o.0) - E123o(self.e3oi.0 * rhs.e12o.0),
+		//     e123i : -E123i(self.e12i.0 * rhs.e3.0) + E123i(self.e12i.0 * rhs.e3oi.0) + E123i(self.e13i.0 * rhs.e2.0) - E123i(self.e13i.0 * rhs.e2oi.0) + E123i(self.e1oi.0 * rhs.e23i.0) - E123i(self.e23i.0 * rhs.e1.0) + E123i(self.e23i.0 * rhs.e1oi.0) - E123i(self.e2oi.0 * rhs.e13i.0) + E123i(self.e3oi.0 * rhs.e12i.0),
+		//     e12oi : -E12oi(self.e12i.0 * rhs.eo.0) + E12oi(self.e13i.0 * rhs.e23o.0) + E12oi(self.e1oi.0 * rhs.e2.0) - E12oi(self.e23i.0 * rhs.e13o.0) - E12oi(self.e2oi.0 * rhs.e1.0) + E12oi(self.e3oi.0 * rhs.e123.0),
+		//     e13oi : -E13oi(self.e12i.0 * rhs.e23o.0) - E13oi(self.e13i.0 * rhs.eo.0) + E13oi(self.e1oi.0 * rhs.e3.0) + E13oi(self.e23i.0 * rhs.e12o.0) - E13oi(self.e2oi.0 * rhs.e123.0) - E13oi(self.e3oi.0 * rhs.e1.0),
+		//     e23oi : E23oi(self.e12i.0 * rhs.e13o.0) - E23oi(self.e13i.0 * rhs.e12o.0) + E23oi(self.e1oi.0 * rhs.e123.0) - E23oi(self.e23i.0 * rhs.eo.0) + E23oi(self.e2oi.0 * rhs.e3.0) - E23oi(self.e3oi.0 * rhs.e2.0),
+		//     e123oi: E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: S(self.e12i.0 * rhs.e12o.0)
+				+ S(self.e13i.0 * rhs.e13o.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23i.0 * rhs.e23o.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e1: E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0)
+				- E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: -E2(self.e12i.0 * rhs.e1o.0)
+				+ E2(self.e13i.0 * rhs.e123o.0)
+				+ E2(self.e1oi.0 * rhs.e12oi.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e13i.0 * rhs.e1o.0) + E3(self.e1oi.0 * rhs.e13oi.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: -E12(self.e12i.0 * rhs.eo.0) + E12(self.e13i.0 * rhs.e23o.0) + E12(self.e1oi.0 * rhs.e2oi.0)
+				- E12(self.e23i.0 * rhs.e13o.0)
+				- E12(self.e2oi.0 * rhs.e1oi.0)
+				+ E12(self.e3oi.0 * rhs.e123oi.0),
+			e13: -E13(self.e12i.0 * rhs.e23o.0) - E13(self.e13i.0 * rhs.eo.0)
+				+ E13(self.e1oi.0 * rhs.e3oi.0)
+				+ E13(self.e23i.0 * rhs.e12o.0)
+				- E13(self.e2oi.0 * rhs.e123oi.0)
+				- E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: -E1o(self.e1oi.0 * rhs.eo.0) + E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+			e1i: -E1i(self.e12i.0 * rhs.e2.0) + E1i(self.e12i.0 * rhs.e2oi.0) - E1i(self.e13i.0 * rhs.e3.0)
+				+ E1i(self.e13i.0 * rhs.e3oi.0)
+				+ E1i(self.e1oi.0 * rhs.ei.0)
+				+ E1i(self.e23i.0 * rhs.e123.0)
+				- E1i(self.e23i.0 * rhs.e123oi.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e12i.0 * rhs.e13o.0) - E23(self.e13i.0 * rhs.e12o.0) + E23(self.e1oi.0 * rhs.e123oi.0)
+				- E23(self.e23i.0 * rhs.eo.0)
+				+ E23(self.e2oi.0 * rhs.e3oi.0)
+				- E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: -E2o(self.e1oi.0 * rhs.e12o.0) - E2o(self.e2oi.0 * rhs.eo.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+			e2i: E2i(self.e12i.0 * rhs.e1.0) - E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0)
+				+ E2i(self.e13i.0 * rhs.e123oi.0)
+				+ E2i(self.e1oi.0 * rhs.e12i.0)
+				- E2i(self.e23i.0 * rhs.e3.0)
+				+ E2i(self.e23i.0 * rhs.e3oi.0)
+				+ E2i(self.e2oi.0 * rhs.ei.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: -E3o(self.e1oi.0 * rhs.e13o.0) - E3o(self.e2oi.0 * rhs.e23o.0) - E3o(self.e3oi.0 * rhs.eo.0),
+			e3i: E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e12i.0 * rhs.e123oi.0) + E3i(self.e13i.0 * rhs.e1.0)
+				- E3i(self.e13i.0 * rhs.e1oi.0)
+				+ E3i(self.e1oi.0 * rhs.e13i.0)
+				+ E3i(self.e23i.0 * rhs.e2.0)
+				- E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0)
+				+ E3i(self.e3oi.0 * rhs.ei.0),
+			eoi: Eoi(self.e12i.0 * rhs.e12o.0)
+				+ Eoi(self.e13i.0 * rhs.e13o.0)
+				+ Eoi(self.e1oi.0 * rhs.e1.0)
+				+ Eoi(self.e23i.0 * rhs.e23o.0)
+				+ Eoi(self.e2oi.0 * rhs.e2.0)
+				+ Eoi(self.e3oi.0 * rhs.e3.0),
+			e123: E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0)
+				+ E123(self.e1oi.0 * rhs.e23oi.0)
+				+ E123(self.e23i.0 * rhs.e1o.0)
+				- E123(self.e2oi.0 * rhs.e13oi.0)
+				+ E123(self.e3oi.0 * rhs.e12oi.0),
+			e12o: -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0) - E12o(self.e3oi.0 * rhs.e123o.0),
+			e12i: -E12i(self.e12i.0 * rhs.eoi.0) + E12i(self.e12i.0 * rhs.s.0) - E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e13i.0 * rhs.e23oi.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e23i.0 * rhs.e13oi.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0)
+				+ E12i(self.e3oi.0 * rhs.e123i.0),
+			e13o: -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e2oi.0 * rhs.e123o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e12i.0 * rhs.e23oi.0) - E13i(self.e13i.0 * rhs.eoi.0)
+				+ E13i(self.e13i.0 * rhs.s.0)
+				+ E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				+ E13i(self.e23i.0 * rhs.e12oi.0)
+				- E13i(self.e2oi.0 * rhs.e123i.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0) + E1oi(self.e1oi.0 * rhs.s.0)
+				- E1oi(self.e23i.0 * rhs.e123o.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: -E23o(self.e1oi.0 * rhs.e123o.0) - E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e12i.0 * rhs.e13oi.0) + E23i(self.e13i.0 * rhs.e12.0)
+				- E23i(self.e13i.0 * rhs.e12oi.0)
+				+ E23i(self.e1oi.0 * rhs.e123i.0)
+				- E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e23i.0 * rhs.s.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e1o.0)
+				+ E2oi(self.e13i.0 * rhs.e123o.0)
+				+ E2oi(self.e1oi.0 * rhs.e12.0)
+				+ E2oi(self.e23i.0 * rhs.e3o.0)
+				+ E2oi(self.e2oi.0 * rhs.s.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e12i.0 * rhs.e123o.0) - E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0)
+				- E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0)
+				+ E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: -E123o(self.e1oi.0 * rhs.e23o.0) + E123o(self.e2oi.0 * rhs.e13o.0) - E123o(self.e3oi.0 * rhs.e12o.0),
+			e123i: -E123i(self.e12i.0 * rhs.e3.0) + E123i(self.e12i.0 * rhs.e3oi.0) + E123i(self.e13i.0 * rhs.e2.0)
+				- E123i(self.e13i.0 * rhs.e2oi.0)
+				+ E123i(self.e1oi.0 * rhs.e23i.0)
+				- E123i(self.e23i.0 * rhs.e1.0)
+				+ E123i(self.e23i.0 * rhs.e1oi.0)
+				- E123i(self.e2oi.0 * rhs.e13i.0)
+				+ E123i(self.e3oi.0 * rhs.e12i.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.eo.0) + E12oi(self.e13i.0 * rhs.e23o.0) + E12oi(self.e1oi.0 * rhs.e2.0)
+				- E12oi(self.e23i.0 * rhs.e13o.0)
+				- E12oi(self.e2oi.0 * rhs.e1.0)
+				+ E12oi(self.e3oi.0 * rhs.e123.0),
+			e13oi: -E13oi(self.e12i.0 * rhs.e23o.0) - E13oi(self.e13i.0 * rhs.eo.0)
+				+ E13oi(self.e1oi.0 * rhs.e3.0)
+				+ E13oi(self.e23i.0 * rhs.e12o.0)
+				- E13oi(self.e2oi.0 * rhs.e123.0)
+				- E13oi(self.e3oi.0 * rhs.e1.0),
+			e23oi: E23oi(self.e12i.0 * rhs.e13o.0) - E23oi(self.e13i.0 * rhs.e12o.0) + E23oi(self.e1oi.0 * rhs.e123.0)
+				- E23oi(self.e23i.0 * rhs.eo.0)
+				+ E23oi(self.e2oi.0 * rhs.e3.0)
+				- E23oi(self.e3oi.0 * rhs.e2.0),
+			e123oi: E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e13i.0 * rhs.e2o.0)
+				+ E123oi(self.e1oi.0 * rhs.e23.0)
+				+ E123oi(self.e23i.0 * rhs.e1o.0)
+				- E123oi(self.e2oi.0 * rhs.e13.0)
+				+ E123oi(self.e3oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Line {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12i.0 * rhs.e3o.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e1    : E1(self.e12i.0 * rhs.e13o.0) - E1(self.e13i.0 * rhs.e12o.0) + E1(self.e1oi.0 * rhs.e123.0) + E1(self.e23i.0 * rhs.eo.0) - E1(self.e2oi.0 * rhs.e3.0) + E1(self.e3oi.0 * rhs.e2.0),
+		//     e2    : E2(self.e12i.0 * rhs.e23o.0) - E2(self.e13i.0 * rhs.eo.0) + E2(self.e1oi.0 * rhs.e3.0) - E2(self.e23i.0 * rhs.e12o.0) + E2(self.e2oi.0 * rhs.e123.0) - E2(self.e3oi.0 * rhs.e1.0),
+		//     e3    : E3(self.e12i.0 * rhs.eo.0) + E3(self.e13i.0 * rhs.e23o.0) - E3(self.e1oi.0 * rhs.e2.0) - E3(self.e23i.0 * rhs.e13o.0) + E3(self.e2oi.0 * rhs.e1.0) + E3(self.e3oi.0 * rhs.e123.0),
+		//     eo    : Eo(self.e1oi.0 * rhs.e23o.0) - Eo(self.e2oi.0 * rhs.e13o.0) + Eo(self.e3oi.0 * rhs.e12o.0),
+		//     ei    : Ei(self.e12i.0 * rhs.e3.0) + Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		//     e12   : E12(self.e12i.0 * rhs.e123o.0) - E12(self.e13i.0 * rhs.e1o.0) + E12(self.e1oi.0 * rhs.e13.0) - E12(self.e23i.0 * rhs.e2o.0) + E12(self.e2oi.0 * rhs.e23.0) - E12(self.e3oi.0 * rhs.s.0),
+		//     e13   : E13(self.e12i.0 * rhs.e1o.0) + E13(self.e13i.0 * rhs.e123o.0) - E13(self.e1oi.0 * rhs.e12.0) - E13(self.e23i.0 * rhs.e3o.0) + E13(self.e2oi.0 * rhs.s.0) + E13(self.e3oi.0 * rhs.e23.0),
+		//     e1o   : E1o(self.e1oi.0 * rhs.e123o.0) - E1o(self.e2oi.0 * rhs.e3o.0) + E1o(self.e3oi.0 * rhs.e2o.0),
+		//     e1i   : E1i(self.e12i.0 * rhs.e13.0) + E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0) + E1i(self.e23i.0 * rhs.eoi.0) + E1i(self.e23i.0 * rhs.s.0) - E1i(self.e2oi.0 * rhs.e3i.0) + E1i(self.e3oi.0 * rhs.e2i.0),
+		//     e23   : E23(self.e12i.0 * rhs.e2o.0) + E23(self.e13i.0 * rhs.e3o.0) - E23(self.e1oi.0 * rhs.s.0) + E23(self.e23i.0 * rhs.e123o.0) - E23(self.e2oi.0 * rhs.e12.0) - E23(self.e3oi.0 * rhs.e13.0),
+		//     e2o   : E2o(self.e1oi.0 * rhs.e3o.0) + E2o(self.e2oi.0 * rhs.e123o.0) - E2o(self.e3oi.0 * rhs.e1o.0),
+		//     e2i   : E2i(self.e12i.0 * rhs.e23.0) + E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e13i.0 * rhs.eoi.0) - E2i(self.e13i.0 * rhs.s.0) + E2i(self.e1oi.0 * rhs.e3i.0) - E2i(self.e23i.0 * rhs.e12.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0) - E2i(self.e3oi.0 * rhs.e1i.0),
+		//     e3o   : -E3o(self.e1oi.0 * rhs.e2o.0) + E3o(self.e2oi.0 * rhs.e1o.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i   : E3i(self.e12i.0 * rhs.eoi.0) + E3i(self.e12i.0 * rhs.s.0) + E3i(self.e13i.0 * rhs.e23.0) + E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e1oi.0 * rhs.e2i.0) - E3i(self.e23i.0 * rhs.e13.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e2oi.0 * rhs.e1i.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi   : -Eoi(self.e12i.0 * rhs.e3o.0) + Eoi(self.e13i.0 * rhs.e2o.0) + Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e23i.0 * rhs.e1o.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123  : E123(self.e12i.0 * rhs.e12o.0) + E123(self.e13i.0 * rhs.e13o.0) - E123(self.e1oi.0 * rhs.e1.0) + E123(self.e23i.0 * rhs.e23o.0) - E123(self.e2oi.0 * rhs.e2.0) - E123(self.e3oi.0 * rhs.e3.0),
+		//     e12o  : E12o(self.e1oi.0 * rhs.e13o.0) + E12o(self.e2oi.0 * rhs.e23o.0) - E12o(self.e3oi.0 * rhs.eo.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.e123.0) + E12i(self.e12i.0 * rhs.e123oi.0) - E12i(self.e13i.0 * rhs.e1.0) - E12i(self.e13i.0 * rhs.e1oi.0) + E12i(self.e1oi.0 * rhs.e13i.0) - E12i(self.e23i.0 * rhs.e2.0) - E12i(self.e23i.0 * rhs.e2oi.0) + E12i(self.e2oi.0 * rhs.e23i.0) - E12i(self.e3oi.0 * rhs.ei.0),
+		//     e13o  : -E13o(self.e1oi.0 * rhs.e12o.0) + E13o(self.e2oi.0 * rhs.eo.0) + E13o(self.e3oi.0 * rhs.e23o.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.e1.0) + E13i(self.e12i.0 * rhs.e1oi.0) + E13i(self.e13i.0 * rhs.e123.0) + E13i(self.e13i.0 * rhs.e123oi.0) - E13i(self.e1oi.0 * rhs.e12i.0) - E13i(self.e23i.0 * rhs.e3.0) - E13i(self.e23i.0 * rhs.e3oi.0) + E13i(self.e2oi.0 * rhs.ei.0) + E13i(self.e3oi.0 * rhs.e23i.0),
+		//     e1oi  : -E1oi(self.e12i.0 * rhs.e13o.0) + E1oi(self.e13i.0 * rhs.e12o.0) + E1oi(self.e1oi.0 * rhs.e123oi.0) - E1oi(self.e23i.0 * rhs.eo.0) - E1oi(self.e2oi.0 * rhs.e3oi.0) + E1oi(self.e3oi.0 * rhs.e2oi.0),
+		//     e23o  : -E23o(self.e1oi.0 * rhs.eo.0) - E23o(self.e2oi.0 * rhs.e12o.0) - E23o(self.e3oi.0 * rhs.e13o.0),
+		//     e23i  : E23i(self.e12i.0 * rhs.e2.0) + E23i(self.e12i.0 * rhs.e2oi.0) + E23i(self.e13i.0 * rhs.e3.0) + E23i(self.e13i.0 * rhs.e3oi.0) - E23i(self.e1oi.0 * rhs.ei.0) + E23i(self.e23i.0 * rhs.e123.0) + E23i(self.e23i.0 * rhs.e123oi.0) - E23i(self.e2oi.0 * rhs.e12i.0) - E23i(self.e3oi.0 * rhs.e13i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e23o.0) + E2oi(self.e13i.0 * rhs.eo.0) + E2oi(self.e1oi.0 * rhs.e3oi.0) + E2oi(self.e23i.0 * rhs.e12o.0) + E2oi(self.e2oi.0 * rhs.e123oi.0) - E2oi(self.e3oi.0 * rhs.e1oi.0),
+		//     e3oi  : -E3oi(self.e12i.0 * rhs.eo.0) - E3oi(self.e13i.0 * rhs.e23o.0) - E3oi(self.e1oi.0 * rhs.e2oi.0) + E3oi(self.e23i.0 * rhs.e13o.0) + E3oi(self.e2oi.0 * rhs.e1oi.0) + E3oi(self.e3oi.0 * rhs.e123oi.0),
+		//     e123o : -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i : E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e13i.0 * rhs.e13oi.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) + E123i(self.e23i.0 * rhs.e23oi.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi : -E12oi(self.e12i.0 * rhs.e123o.0) + E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e23i.0 * rhs.e2o.0) + E12oi(self.e2oi.0 * rhs.e23oi.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi : -E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e2oi.0 * rhs.eoi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi : -E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e1oi.0 * rhs.eoi.0) - E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		//     e123oi: -E123oi(self.e12i.0 * rhs.e12o.0) - E123oi(self.e13i.0 * rhs.e13o.0) - E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e23i.0 * rhs.e23o.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0),
+		// }
+		Multivector {
+			s: S(self.e12i.0 * rhs.e3o.0) - S(self.e13i.0 * rhs.e2o.0)
+				+ S(self.e1oi.0 * rhs.e23.0)
+				+ S(self.e23i.0 * rhs.e1o.0)
+				- S(self.e2oi.0 * rhs.e13.0)
+				+ S(self.e3oi.0 * rhs.e12.0),
+			e1: E1(self.e12i.0 * rhs.e13o.0) - E1(self.e13i.0 * rhs.e12o.0)
+				+ E1(self.e1oi.0 * rhs.e123.0)
+				+ E1(self.e23i.0 * rhs.eo.0)
+				- E1(self.e2oi.0 * rhs.e3.0)
+				+ E1(self.e3oi.0 * rhs.e2.0),
+			e2: E2(self.e12i.0 * rhs.e23o.0) - E2(self.e13i.0 * rhs.eo.0) + E2(self.e1oi.0 * rhs.e3.0)
+				- E2(self.e23i.0 * rhs.e12o.0)
+				+ E2(self.e2oi.0 * rhs.e123.0)
+				- E2(self.e3oi.0 * rhs.e1.0),
+			e3: E3(self.e12i.0 * rhs.eo.0) + E3(self.e13i.0 * rhs.e23o.0)
+				- E3(self.e1oi.0 * rhs.e2.0)
+				- E3(self.e23i.0 * rhs.e13o.0)
+				+ E3(self.e2oi.0 * rhs.e1.0)
+				+ E3(self.e3oi.0 * rhs.e123.0),
+			eo: Eo(self.e1oi.0 * rhs.e23o.0) - Eo(self.e2oi.0 * rhs.e13o.0) + Eo(self.e3oi.0 * rhs.e12o.0),
+			ei: Ei(self.e12i.0 * rhs.e3.0) + Ei(self.e12i.0 * rhs.e3oi.0)
+				- Ei(self.e13i.0 * rhs.e2.0)
+				- Ei(self.e13i.0 * rhs.e2oi.0)
+				+ Ei(self.e1oi.0 * rhs.e23i.0)
+				+ Ei(self.e23i.0 * rhs.e1.0)
+				+ Ei(self.e23i.0 * rhs.e1oi.0)
+				- Ei(self.e2oi.0 * rhs.e13i.0)
+				+ Ei(self.e3oi.0 * rhs.e12i.0),
+			e12: E12(self.e12i.0 * rhs.e123o.0) - E12(self.e13i.0 * rhs.e1o.0) + E12(self.e1oi.0 * rhs.e13.0)
+				- E12(self.e23i.0 * rhs.e2o.0)
+				+ E12(self.e2oi.0 * rhs.e23.0)
+				- E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e12i.0 * rhs.e1o.0) + E13(self.e13i.0 * rhs.e123o.0)
+				- E13(self.e1oi.0 * rhs.e12.0)
+				- E13(self.e23i.0 * rhs.e3o.0)
+				+ E13(self.e2oi.0 * rhs.s.0)
+				+ E13(self.e3oi.0 * rhs.e23.0),
+			e1o: E1o(self.e1oi.0 * rhs.e123o.0) - E1o(self.e2oi.0 * rhs.e3o.0) + E1o(self.e3oi.0 * rhs.e2o.0),
+			e1i: E1i(self.e12i.0 * rhs.e13.0) + E1i(self.e12i.0 * rhs.e13oi.0)
+				- E1i(self.e13i.0 * rhs.e12.0)
+				- E1i(self.e13i.0 * rhs.e12oi.0)
+				+ E1i(self.e1oi.0 * rhs.e123i.0)
+				+ E1i(self.e23i.0 * rhs.eoi.0)
+				+ E1i(self.e23i.0 * rhs.s.0)
+				- E1i(self.e2oi.0 * rhs.e3i.0)
+				+ E1i(self.e3oi.0 * rhs.e2i.0),
+			e23: E23(self.e12i.0 * rhs.e2o.0) + E23(self.e13i.0 * rhs.e3o.0) - E23(self.e1oi.0 * rhs.s.0)
+				+ E23(self.e23i.0 * rhs.e123o.0)
+				- E23(self.e2oi.0 * rhs.e12.0)
+				- E23(self.e3oi.0 * rhs.e13.0),
+			e2o: E2o(self.e1oi.0 * rhs.e3o.0) + E2o(self.e2oi.0 * rhs.e123o.0) - E2o(self.e3oi.0 * rhs.e1o.0),
+			e2i: E2i(self.e12i.0 * rhs.e23.0) + E2i(self.e12i.0 * rhs.e23oi.0)
+				- E2i(self.e13i.0 * rhs.eoi.0)
+				- E2i(self.e13i.0 * rhs.s.0)
+				+ E2i(self.e1oi.0 * rhs.e3i.0)
+				- E2i(self.e23i.0 * rhs.e12.0)
+				- E2i(self.e23i.0 * rhs.e12oi.0)
+				+ E2i(self.e2oi.0 * rhs.e123i.0)
+				- E2i(self.e3oi.0 * rhs.e1i.0),
+			e3o: -E3o(self.e1oi.0 * rhs.e2o.0) + E3o(self.e2oi.0 * rhs.e1o.0) + E3o(self.e3oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e12i.0 * rhs.eoi.0)
+				+ E3i(self.e12i.0 * rhs.s.0)
+				+ E3i(self.e13i.0 * rhs.e23.0)
+				+ E3i(self.e13i.0 * rhs.e23oi.0)
+				- E3i(self.e1oi.0 * rhs.e2i.0)
+				- E3i(self.e23i.0 * rhs.e13.0)
+				- E3i(self.e23i.0 * rhs.e13oi.0)
+				+ E3i(self.e2oi.0 * rhs.e1i.0)
+				+ E3i(self.e3oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e12i.0 * rhs.e3o.0) + Eoi(self.e13i.0 * rhs.e2o.0) + Eoi(self.e1oi.0 * rhs.e23oi.0)
+				- Eoi(self.e23i.0 * rhs.e1o.0)
+				- Eoi(self.e2oi.0 * rhs.e13oi.0)
+				+ Eoi(self.e3oi.0 * rhs.e12oi.0),
+			e123: E123(self.e12i.0 * rhs.e12o.0) + E123(self.e13i.0 * rhs.e13o.0) - E123(self.e1oi.0 * rhs.e1.0)
+				+ E123(self.e23i.0 * rhs.e23o.0)
+				- E123(self.e2oi.0 * rhs.e2.0)
+				- E123(self.e3oi.0 * rhs.e3.0),
+			e12o: E12o(self.e1oi.0 * rhs.e13o.0) + E12o(self.e2oi.0 * rhs.e23o.0) - E12o(self.e3oi.0 * rhs.eo.0),
+			e12i: E12i(self.e12i.0 * rhs.e123.0) + E12i(self.e12i.0 * rhs.e123oi.0)
+				- E12i(self.e13i.0 * rhs.e1.0)
+				- E12i(self.e13i.0 * rhs.e1oi.0)
+				+ E12i(self.e1oi.0 * rhs.e13i.0)
+				- E12i(self.e23i.0 * rhs.e2.0)
+				- E12i(self.e23i.0 * rhs.e2oi.0)
+				+ E12i(self.e2oi.0 * rhs.e23i.0)
+				- E12i(self.e3oi.0 * rhs.ei.0),
+			e13o: -E13o(self.e1oi.0 * rhs.e12o.0) + E13o(self.e2oi.0 * rhs.eo.0) + E13o(self.e3oi.0 * rhs.e23o.0),
+			e13i: E13i(self.e12i.0 * rhs.e1.0)
+				+ E13i(self.e12i.0 * rhs.e1oi.0)
+				+ E13i(self.e13i.0 * rhs.e123.0)
+				+ E13i(self.e13i.0 * rhs.e123oi.0)
+				- E13i(self.e1oi.0 * rhs.e12i.0)
+				- E13i(self.e23i.0 * rhs.e3.0)
+				- E13i(self.e23i.0 * rhs.e3oi.0)
+				+ E13i(self.e2oi.0 * rhs.ei.0)
+				+ E13i(self.e3oi.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.e12i.0 * rhs.e13o.0) + E1oi(self.e13i.0 * rhs.e12o.0) + E1oi(self.e1oi.0 * rhs.e123oi.0)
+				- E1oi(self.e23i.0 * rhs.eo.0)
+				- E1oi(self.e2oi.0 * rhs.e3oi.0)
+				+ E1oi(self.e3oi.0 * rhs.e2oi.0),
+			e23o: -E23o(self.e1oi.0 * rhs.eo.0) - E23o(self.e2oi.0 * rhs.e12o.0) - E23o(self.e3oi.0 * rhs.e13o.0),
+			e23i: E23i(self.e12i.0 * rhs.e2.0)
+				+ E23i(self.e12i.0 * rhs.e2oi.0)
+				+ E23i(self.e13i.0 * rhs.e3.0)
+				+ E23i(self.e13i.0 * rhs.e3oi.0)
+				- E23i(self.e1oi.0 * rhs.ei.0)
+				+ E23i(self.e23i.0 * rhs.e123.0)
+				+ E23i(self.e23i.0 * rhs.e123oi.0)
+				- E23i(self.e2oi.0 * rhs.e12i.0)
+				- E23i(self.e3oi.0 * rhs.e13i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e23o.0)
+				+ E2oi(self.e13i.0 * rhs.eo.0)
+				+ E2oi(self.e1oi.0 * rhs.e3oi.0)
+				+ E2oi(self.e23i.0 * rhs.e12o.0)
+				+ E2oi(self.e2oi.0 * rhs.e123oi.0)
+				- E2oi(self.e3oi.0 * rhs.e1oi.0),
+			e3oi: -E3oi(self.e12i.0 * rhs.eo.0) - E3oi(self.e13i.0 * rhs.e23o.0) - E3oi(self.e1oi.0 * rhs.e2oi.0)
+				+ E3oi(self.e23i.0 * rhs.e13o.0)
+				+ E3oi(self.e2oi.0 * rhs.e1oi.0)
+				+ E3oi(self.e3oi.0 * rhs.e123oi.0),
+			e123o: -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: E123i(self.e12i.0 * rhs.e12.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				- E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.e123o.0)
+				+ E12oi(self.e13i.0 * rhs.e1o.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e23i.0 * rhs.e2o.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0)
+				- E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: -E13oi(self.e12i.0 * rhs.e1o.0)
+				- E13oi(self.e13i.0 * rhs.e123o.0)
+				- E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e23i.0 * rhs.e3o.0)
+				+ E13oi(self.e2oi.0 * rhs.eoi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e12i.0 * rhs.e2o.0)
+				- E23oi(self.e13i.0 * rhs.e3o.0)
+				- E23oi(self.e1oi.0 * rhs.eoi.0)
+				- E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+			e123oi: -E123oi(self.e12i.0 * rhs.e12o.0)
+				- E123oi(self.e13i.0 * rhs.e13o.0)
+				- E123oi(self.e1oi.0 * rhs.e1oi.0)
+				- E123oi(self.e23i.0 * rhs.e23o.0)
+				- E123oi(self.e2oi.0 * rhs.e2oi.0)
+				- E123oi(self.e3oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Line.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Line {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12i.0 * rhs.e12o.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e1    : E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0) - E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e13i.0 * rhs.e1o.0) + E3(self.e1oi.0 * rhs.e13oi.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e2oi.0 * rhs.e23oi.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e23i.0 * rhs.e23oi.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : -E12(self.e12i.0 * rhs.eo.0) + E12(self.e3oi.0 * rhs.e123oi.0),
+		//     e13   : -E13(self.e13i.0 * rhs.eo.0) - E13(self.e2oi.0 * rhs.e123oi.0),
+		//     e1o   : -E1o(self.e1oi.0 * rhs.eo.0),
+		//     e1i   : -E1i(self.e12i.0 * rhs.e2.0) - E1i(self.e13i.0 * rhs.e3.0) + E1i(self.e1oi.0 * rhs.ei.0) - E1i(self.e23i.0 * rhs.e123oi.0),
+		//     e23   : E23(self.e1oi.0 * rhs.e123oi.0) - E23(self.e23i.0 * rhs.eo.0),
+		//     e2o   : -E2o(self.e2oi.0 * rhs.eo.0),
+		//     e2i   : E2i(self.e12i.0 * rhs.e1.0) + E2i(self.e13i.0 * rhs.e123oi.0) - E2i(self.e23i.0 * rhs.e3.0) + E2i(self.e2oi.0 * rhs.ei.0),
+		//     e3o   : -E3o(self.e3oi.0 * rhs.eo.0),
+		//     e3i   : -E3i(self.e12i.0 * rhs.e123oi.0) + E3i(self.e13i.0 * rhs.e1.0) + E3i(self.e23i.0 * rhs.e2.0) + E3i(self.e3oi.0 * rhs.ei.0),
+		//     eoi   : Eoi(self.e1oi.0 * rhs.e1.0) + Eoi(self.e2oi.0 * rhs.e2.0) + Eoi(self.e3oi.0 * rhs.e3.0),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: S(self.e12i.0 * rhs.e12o.0)
+				+ S(self.e13i.0 * rhs.e13o.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23i.0 * rhs.e23o.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e1: E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0)
+				- E1(self.e23i.0 * rhs.e123o.0)
+				- E1(self.e2oi.0 * rhs.e12oi.0)
+				- E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: -E2(self.e12i.0 * rhs.e1o.0)
+				+ E2(self.e13i.0 * rhs.e123o.0)
+				+ E2(self.e1oi.0 * rhs.e12oi.0)
+				+ E2(self.e23i.0 * rhs.e3o.0)
+				+ E2(self.e2oi.0 * rhs.eoi.0)
+				- E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) - E3(self.e13i.0 * rhs.e1o.0) + E3(self.e1oi.0 * rhs.e13oi.0)
+				- E3(self.e23i.0 * rhs.e2o.0)
+				+ E3(self.e2oi.0 * rhs.e23oi.0)
+				+ E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) + Ei(self.e12i.0 * rhs.e12oi.0) - Ei(self.e13i.0 * rhs.e13.0)
+				+ Ei(self.e13i.0 * rhs.e13oi.0)
+				+ Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e23i.0 * rhs.e23oi.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: -E12(self.e12i.0 * rhs.eo.0) + E12(self.e3oi.0 * rhs.e123oi.0),
+			e13: -E13(self.e13i.0 * rhs.eo.0) - E13(self.e2oi.0 * rhs.e123oi.0),
+			e1o: -E1o(self.e1oi.0 * rhs.eo.0),
+			e1i: -E1i(self.e12i.0 * rhs.e2.0) - E1i(self.e13i.0 * rhs.e3.0) + E1i(self.e1oi.0 * rhs.ei.0)
+				- E1i(self.e23i.0 * rhs.e123oi.0),
+			e23: E23(self.e1oi.0 * rhs.e123oi.0) - E23(self.e23i.0 * rhs.eo.0),
+			e2o: -E2o(self.e2oi.0 * rhs.eo.0),
+			e2i: E2i(self.e12i.0 * rhs.e1.0) + E2i(self.e13i.0 * rhs.e123oi.0) - E2i(self.e23i.0 * rhs.e3.0)
+				+ E2i(self.e2oi.0 * rhs.ei.0),
+			e3o: -E3o(self.e3oi.0 * rhs.eo.0),
+			e3i: -E3i(self.e12i.0 * rhs.e123oi.0)
+				+ E3i(self.e13i.0 * rhs.e1.0)
+				+ E3i(self.e23i.0 * rhs.e2.0)
+				+ E3i(self.e3oi.0 * rhs.ei.0),
+			eoi: Eoi(self.e1oi.0 * rhs.e1.0) + Eoi(self.e2oi.0 * rhs.e2.0) + Eoi(self.e3oi.0 * rhs.e3.0),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Line {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Line {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : -E123i(self.e12i.0 * rhs.e3.0) + E123i(self.e13i.0 * rhs.e2.0) - E123i(self.e23i.0 * rhs.e1.0),
+		//     e12oi : -E12oi(self.e12i.0 * rhs.eo.0) + E12oi(self.e1oi.0 * rhs.e2.0) - E12oi(self.e2oi.0 * rhs.e1.0),
+		//     e13oi : -E13oi(self.e13i.0 * rhs.eo.0) + E13oi(self.e1oi.0 * rhs.e3.0) - E13oi(self.e3oi.0 * rhs.e1.0),
+		//     e23oi : -E23oi(self.e23i.0 * rhs.eo.0) + E23oi(self.e2oi.0 * rhs.e3.0) - E23oi(self.e3oi.0 * rhs.e2.0),
+		//     e123oi: E123oi(self.e12i.0 * rhs.e3o.0) - E123oi(self.e13i.0 * rhs.e2o.0) + E123oi(self.e1oi.0 * rhs.e23.0) + E123oi(self.e23i.0 * rhs.e1o.0) - E123oi(self.e2oi.0 * rhs.e13.0) + E123oi(self.e3oi.0 * rhs.e12.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.e12i.wedge(rhs.s),
+			e13o: Default::default(),
+			e13i: self.e13i.wedge(rhs.s),
+			e1oi: self.e1oi.wedge(rhs.s),
+			e23o: Default::default(),
+			e23i: self.e23i.wedge(rhs.s),
+			e2oi: self.e2oi.wedge(rhs.s),
+			e3oi: self.e3oi.wedge(rhs.s),
+			e123o: Default::default(),
+			e123i: self.e12i.wedge(rhs.e3) + self.e13i.wedge(rhs.e2) + self.e23i.wedge(rhs.e1),
+			e12oi: self.e12i.wedge(rhs.eo) + self.e1oi.wedge(rhs.e2) + self.e2oi.wedge(rhs.e1),
+			e13oi: self.e13i.wedge(rhs.eo) + self.e1oi.wedge(rhs.e3) + self.e3oi.wedge(rhs.e1),
+			e23oi: self.e23i.wedge(rhs.eo) + self.e2oi.wedge(rhs.e3) + self.e3oi.wedge(rhs.e2),
+			e123oi: self.e12i.wedge(rhs.e3o)
+				+ self.e13i.wedge(rhs.e2o)
+				+ self.e1oi.wedge(rhs.e23)
+				+ self.e23i.wedge(rhs.e1o)
+				+ self.e2oi.wedge(rhs.e13)
+				+ self.e3oi.wedge(rhs.e12),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Line {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Line {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12i.0 * rhs.e3o.0) - S(self.e13i.0 * rhs.e2o.0) + S(self.e1oi.0 * rhs.e23.0) + S(self.e23i.0 * rhs.e1o.0) - S(self.e2oi.0 * rhs.e13.0) + S(self.e3oi.0 * rhs.e12.0),
+		//     e1    : E1(self.e12i.0 * rhs.e13o.0) - E1(self.e13i.0 * rhs.e12o.0) + E1(self.e1oi.0 * rhs.e123.0),
+		//     e2    : E2(self.e12i.0 * rhs.e23o.0) - E2(self.e23i.0 * rhs.e12o.0) + E2(self.e2oi.0 * rhs.e123.0),
+		//     e3    : E3(self.e13i.0 * rhs.e23o.0) - E3(self.e23i.0 * rhs.e13o.0) + E3(self.e3oi.0 * rhs.e123.0),
+		//     eo    : Eo(self.e1oi.0 * rhs.e23o.0) - Eo(self.e2oi.0 * rhs.e13o.0) + Eo(self.e3oi.0 * rhs.e12o.0),
+		//     ei    : Ei(self.e12i.0 * rhs.e3oi.0) - Ei(self.e13i.0 * rhs.e2oi.0) + Ei(self.e1oi.0 * rhs.e23i.0) + Ei(self.e23i.0 * rhs.e1oi.0) - Ei(self.e2oi.0 * rhs.e13i.0) + Ei(self.e3oi.0 * rhs.e12i.0),
+		//     e12   : E12(self.e12i.0 * rhs.e123o.0),
+		//     e13   : E13(self.e13i.0 * rhs.e123o.0),
+		//     e1o   : E1o(self.e1oi.0 * rhs.e123o.0),
+		//     e1i   : E1i(self.e12i.0 * rhs.e13oi.0) - E1i(self.e13i.0 * rhs.e12oi.0) + E1i(self.e1oi.0 * rhs.e123i.0),
+		//     e23   : E23(self.e23i.0 * rhs.e123o.0),
+		//     e2o   : E2o(self.e2oi.0 * rhs.e123o.0),
+		//     e2i   : E2i(self.e12i.0 * rhs.e23oi.0) - E2i(self.e23i.0 * rhs.e12oi.0) + E2i(self.e2oi.0 * rhs.e123i.0),
+		//     e3o   : E3o(self.e3oi.0 * rhs.e123o.0),
+		//     e3i   : E3i(self.e13i.0 * rhs.e23oi.0) - E3i(self.e23i.0 * rhs.e13oi.0) + E3i(self.e3oi.0 * rhs.e123i.0),
+		//     eoi   : Eoi(self.e1oi.0 * rhs.e23oi.0) - Eoi(self.e2oi.0 * rhs.e13oi.0) + Eoi(self.e3oi.0 * rhs.e12oi.0),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.e123oi.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.e123oi.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.e123oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.e123oi.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.e123oi.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.e123oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.e12i.anti_wedge(rhs.e3o)
+				+ self.e13i.anti_wedge(rhs.e2o)
+				+ self.e1oi.anti_wedge(rhs.e23)
+				+ self.e23i.anti_wedge(rhs.e1o)
+				+ self.e2oi.anti_wedge(rhs.e13)
+				+ self.e3oi.anti_wedge(rhs.e12),
+			e1: self.e12i.anti_wedge(rhs.e13o) + self.e13i.anti_wedge(rhs.e12o) + self.e1oi.anti_wedge(rhs.e123),
+			e2: self.e12i.anti_wedge(rhs.e23o) + self.e23i.anti_wedge(rhs.e12o) + self.e2oi.anti_wedge(rhs.e123),
+			e3: self.e13i.anti_wedge(rhs.e23o) + self.e23i.anti_wedge(rhs.e13o) + self.e3oi.anti_wedge(rhs.e123),
+			eo: self.e1oi.anti_wedge(rhs.e23o) + self.e2oi.anti_wedge(rhs.e13o) + self.e3oi.anti_wedge(rhs.e12o),
+			ei: self.e12i.anti_wedge(rhs.e3oi)
+				+ self.e13i.anti_wedge(rhs.e2oi)
+				+ self.e1oi.anti_wedge(rhs.e23i)
+				+ self.e23i.anti_wedge(rhs.e1oi)
+				+ self.e2oi.anti_wedge(rhs.e13i)
+				+ self.e3oi.anti_wedge(rhs.e12i),
+			e12: self.e12i.anti_wedge(rhs.e123o),
+			e13: self.e13i.anti_wedge(rhs.e123o),
+			e1o: self.e1oi.anti_wedge(rhs.e123o),
+			e1i: self.e12i.anti_wedge(rhs.e13oi) + self.e13i.anti_wedge(rhs.e12oi) + self.e1oi.anti_wedge(rhs.e123i),
+			e23: self.e23i.anti_wedge(rhs.e123o),
+			e2o: self.e2oi.anti_wedge(rhs.e123o),
+			e2i: self.e12i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e12oi) + self.e2oi.anti_wedge(rhs.e123i),
+			e3o: self.e3oi.anti_wedge(rhs.e123o),
+			e3i: self.e13i.anti_wedge(rhs.e23oi) + self.e23i.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e123i),
+			eoi: self.e1oi.anti_wedge(rhs.e23oi) + self.e2oi.anti_wedge(rhs.e13oi) + self.e3oi.anti_wedge(rhs.e12oi),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.e12i.anti_wedge(rhs.e123oi),
+			e13o: Default::default(),
+			e13i: self.e13i.anti_wedge(rhs.e123oi),
+			e1oi: self.e1oi.anti_wedge(rhs.e123oi),
+			e23o: Default::default(),
+			e23i: self.e23i.anti_wedge(rhs.e123oi),
+			e2oi: self.e2oi.anti_wedge(rhs.e123oi),
+			e3oi: self.e3oi.anti_wedge(rhs.e123oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Line {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Line {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12i.0 * rhs.e12o.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e1    : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		//     e12   : E12(self.e3oi.0 * rhs.e123oi.0),
+		//     e13   : -E13(self.e2oi.0 * rhs.e123oi.0),
+		//     e1o   : Default::default(),
+		//     e1i   : -E1i(self.e23i.0 * rhs.e123oi.0),
+		//     e23   : E23(self.e1oi.0 * rhs.e123oi.0),
+		//     e2o   : Default::default(),
+		//     e2i   : E2i(self.e13i.0 * rhs.e123oi.0),
+		//     e3o   : Default::default(),
+		//     e3i   : -E3i(self.e12i.0 * rhs.e123oi.0),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: S(self.e12i.0 * rhs.e12o.0)
+				+ S(self.e13i.0 * rhs.e13o.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23i.0 * rhs.e23o.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e1: -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			eo: Default::default(),
+			ei: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+			e12: E12(self.e3oi.0 * rhs.e123oi.0),
+			e13: -E13(self.e2oi.0 * rhs.e123oi.0),
+			e1o: Default::default(),
+			e1i: -E1i(self.e23i.0 * rhs.e123oi.0),
+			e23: E23(self.e1oi.0 * rhs.e123oi.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e13i.0 * rhs.e123oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e12i.0 * rhs.e123oi.0),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Line.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Line {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12i.0 * rhs.e12o.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0),
+		//     e1    : E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0),
+		//     e2    : -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0),
+		//     e3    : -E3(self.e13i.0 * rhs.e1o.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e3oi.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0) - Ei(self.e23i.0 * rhs.e23.0) + Ei(self.e2oi.0 * rhs.e2i.0) + Ei(self.e3oi.0 * rhs.e3i.0),
+		//     e12   : -E12(self.e12i.0 * rhs.eo.0),
+		//     e13   : -E13(self.e13i.0 * rhs.eo.0),
+		//     e1o   : -E1o(self.e1oi.0 * rhs.eo.0),
+		//     e1i   : -E1i(self.e12i.0 * rhs.e2.0) - E1i(self.e13i.0 * rhs.e3.0) + E1i(self.e1oi.0 * rhs.ei.0),
+		//     e23   : -E23(self.e23i.0 * rhs.eo.0),
+		//     e2o   : -E2o(self.e2oi.0 * rhs.eo.0),
+		//     e2i   : E2i(self.e12i.0 * rhs.e1.0) - E2i(self.e23i.0 * rhs.e3.0) + E2i(self.e2oi.0 * rhs.ei.0),
+		//     e3o   : -E3o(self.e3oi.0 * rhs.eo.0),
+		//     e3i   : E3i(self.e13i.0 * rhs.e1.0) + E3i(self.e23i.0 * rhs.e2.0) + E3i(self.e3oi.0 * rhs.ei.0),
+		//     eoi   : Eoi(self.e1oi.0 * rhs.e1.0) + Eoi(self.e2oi.0 * rhs.e2.0) + Eoi(self.e3oi.0 * rhs.e3.0),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23i.0 * rhs.s.0),
+		//     e2oi  : E2oi(self.e2oi.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e3oi.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: S(self.e12i.0 * rhs.e12o.0)
+				+ S(self.e13i.0 * rhs.e13o.0)
+				+ S(self.e1oi.0 * rhs.e1oi.0)
+				+ S(self.e23i.0 * rhs.e23o.0)
+				+ S(self.e2oi.0 * rhs.e2oi.0)
+				+ S(self.e3oi.0 * rhs.e3oi.0),
+			e1: E1(self.e12i.0 * rhs.e2o.0) + E1(self.e13i.0 * rhs.e3o.0) + E1(self.e1oi.0 * rhs.eoi.0),
+			e2: -E2(self.e12i.0 * rhs.e1o.0) + E2(self.e23i.0 * rhs.e3o.0) + E2(self.e2oi.0 * rhs.eoi.0),
+			e3: -E3(self.e13i.0 * rhs.e1o.0) - E3(self.e23i.0 * rhs.e2o.0) + E3(self.e3oi.0 * rhs.eoi.0),
+			eo: -Eo(self.e1oi.0 * rhs.e1o.0) - Eo(self.e2oi.0 * rhs.e2o.0) - Eo(self.e3oi.0 * rhs.e3o.0),
+			ei: -Ei(self.e12i.0 * rhs.e12.0) - Ei(self.e13i.0 * rhs.e13.0) + Ei(self.e1oi.0 * rhs.e1i.0)
+				- Ei(self.e23i.0 * rhs.e23.0)
+				+ Ei(self.e2oi.0 * rhs.e2i.0)
+				+ Ei(self.e3oi.0 * rhs.e3i.0),
+			e12: -E12(self.e12i.0 * rhs.eo.0),
+			e13: -E13(self.e13i.0 * rhs.eo.0),
+			e1o: -E1o(self.e1oi.0 * rhs.eo.0),
+			e1i: -E1i(self.e12i.0 * rhs.e2.0) - E1i(self.e13i.0 * rhs.e3.0) + E1i(self.e1oi.0 * rhs.ei.0),
+			e23: -E23(self.e23i.0 * rhs.eo.0),
+			e2o: -E2o(self.e2oi.0 * rhs.eo.0),
+			e2i: E2i(self.e12i.0 * rhs.e1.0) - E2i(self.e23i.0 * rhs.e3.0) + E2i(self.e2oi.0 * rhs.ei.0),
+			e3o: -E3o(self.e3oi.0 * rhs.eo.0),
+			e3i: E3i(self.e13i.0 * rhs.e1.0) + E3i(self.e23i.0 * rhs.e2.0) + E3i(self.e3oi.0 * rhs.ei.0),
+			eoi: Eoi(self.e1oi.0 * rhs.e1.0) + Eoi(self.e2oi.0 * rhs.e2.0) + Eoi(self.e3oi.0 * rhs.e3.0),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e12i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13i.0 * rhs.s.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e23i.0 * rhs.s.0),
+			e2oi: E2oi(self.e2oi.0 * rhs.s.0),
+			e3oi: E3oi(self.e3oi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Line.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Line {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.e12i.0 * rhs.e12o.0) + S(self.e13i.0 * rhs.e13o.0) + S(self.e1oi.0 * rhs.e1oi.0) + S(self.e23i.0 * rhs.e23o.0) + S(self.e2oi.0 * rhs.e2oi.0) + S(self.e3oi.0 * rhs.e3oi.0)
+		S(self.e12i.0 * rhs.e12o.0)
+			+ S(self.e13i.0 * rhs.e13o.0)
+			+ S(self.e1oi.0 * rhs.e1oi.0)
+			+ S(self.e23i.0 * rhs.e23o.0)
+			+ S(self.e2oi.0 * rhs.e2oi.0)
+			+ S(self.e3oi.0 * rhs.e3oi.0)
+	}
+}
+
+// Line.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Line {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+		//     e2    : E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+		//     e3    : -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+		//     e12   : E12(self.e13i.0 * rhs.e23o.0) + E12(self.e1oi.0 * rhs.e2oi.0) - E12(self.e23i.0 * rhs.e13o.0) - E12(self.e2oi.0 * rhs.e1oi.0),
+		//     e13   : -E13(self.e12i.0 * rhs.e23o.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0) - E13(self.e3oi.0 * rhs.e1oi.0),
+		//     e1o   : E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+		//     e1i   : E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e23i.0 * rhs.e123.0) - E1i(self.e2oi.0 * rhs.e12i.0) - E1i(self.e3oi.0 * rhs.e13i.0),
+		//     e23   : E23(self.e12i.0 * rhs.e13o.0) - E23(self.e13i.0 * rhs.e12o.0) + E23(self.e2oi.0 * rhs.e3oi.0) - E23(self.e3oi.0 * rhs.e2oi.0),
+		//     e2o   : -E2o(self.e1oi.0 * rhs.e12o.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+		//     e2i   : -E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0) + E2i(self.e1oi.0 * rhs.e12i.0) + E2i(self.e23i.0 * rhs.e3oi.0) - E2i(self.e3oi.0 * rhs.e23i.0),
+		//     e3o   : -E3o(self.e1oi.0 * rhs.e13o.0) - E3o(self.e2oi.0 * rhs.e23o.0),
+		//     e3i   : E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0) - E3i(self.e23i.0 * rhs.e2oi.0) + E3i(self.e2oi.0 * rhs.e23i.0),
+		//     eoi   : Eoi(self.e12i.0 * rhs.e12o.0) + Eoi(self.e13i.0 * rhs.e13o.0) + Eoi(self.e23i.0 * rhs.e23o.0),
+		//     e123  : E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0) + E123(self.e23i.0 * rhs.e1o.0),
+		//     e12o  : -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+		//     e12i  : -E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0) + E12i(self.e1oi.0 * rhs.e2i.0) + E12i(self.e23i.0 * rhs.e13.0) - E12i(self.e2oi.0 * rhs.e1i.0),
+		//     e13o  : -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0) - E13i(self.e23i.0 * rhs.e12.0) - E13i(self.e3oi.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0) - E1oi(self.e2oi.0 * rhs.e12.0) - E1oi(self.e3oi.0 * rhs.e13.0),
+		//     e23o  : -E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+		//     e23i  : -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0) + E23i(self.e2oi.0 * rhs.e3i.0) - E23i(self.e3oi.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0) - E2oi(self.e3oi.0 * rhs.e23.0),
+		//     e3oi  : -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0) + E3oi(self.e2oi.0 * rhs.e23.0),
+		//     e123o : Default::default(),
+		//     e123i : -E123i(self.e12i.0 * rhs.e3.0) + E123i(self.e13i.0 * rhs.e2.0) - E123i(self.e23i.0 * rhs.e1.0),
+		//     e12oi : -E12oi(self.e12i.0 * rhs.eo.0) + E12oi(self.e1oi.0 * rhs.e2.0) - E12oi(self.e2oi.0 * rhs.e1.0),
+		//     e13oi : -E13oi(self.e13i.0 * rhs.eo.0) + E13oi(self.e1oi.0 * rhs.e3.0) - E13oi(self.e3oi.0 * rhs.e1.0),
+		//     e23oi : -E23oi(self.e23i.0 * rhs.eo.0) + E23oi(self.e2oi.0 * rhs.e3.0) - E23oi(self.e3oi.0 * rhs.e2.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23i.0 * rhs.e123o.0) - E1(self.e2oi.0 * rhs.e12oi.0) - E1(self.e3oi.0 * rhs.e13oi.0),
+			e2: E2(self.e13i.0 * rhs.e123o.0) + E2(self.e1oi.0 * rhs.e12oi.0) - E2(self.e3oi.0 * rhs.e23oi.0),
+			e3: -E3(self.e12i.0 * rhs.e123o.0) + E3(self.e1oi.0 * rhs.e13oi.0) + E3(self.e2oi.0 * rhs.e23oi.0),
+			eo: Default::default(),
+			ei: Ei(self.e12i.0 * rhs.e12oi.0) + Ei(self.e13i.0 * rhs.e13oi.0) + Ei(self.e23i.0 * rhs.e23oi.0),
+			e12: E12(self.e13i.0 * rhs.e23o.0) + E12(self.e1oi.0 * rhs.e2oi.0)
+				- E12(self.e23i.0 * rhs.e13o.0)
+				- E12(self.e2oi.0 * rhs.e1oi.0),
+			e13: -E13(self.e12i.0 * rhs.e23o.0) + E13(self.e1oi.0 * rhs.e3oi.0) + E13(self.e23i.0 * rhs.e12o.0)
+				- E13(self.e3oi.0 * rhs.e1oi.0),
+			e1o: E1o(self.e2oi.0 * rhs.e12o.0) + E1o(self.e3oi.0 * rhs.e13o.0),
+			e1i: E1i(self.e12i.0 * rhs.e2oi.0) + E1i(self.e13i.0 * rhs.e3oi.0) + E1i(self.e23i.0 * rhs.e123.0)
+				- E1i(self.e2oi.0 * rhs.e12i.0)
+				- E1i(self.e3oi.0 * rhs.e13i.0),
+			e23: E23(self.e12i.0 * rhs.e13o.0) - E23(self.e13i.0 * rhs.e12o.0) + E23(self.e2oi.0 * rhs.e3oi.0)
+				- E23(self.e3oi.0 * rhs.e2oi.0),
+			e2o: -E2o(self.e1oi.0 * rhs.e12o.0) + E2o(self.e3oi.0 * rhs.e23o.0),
+			e2i: -E2i(self.e12i.0 * rhs.e1oi.0) - E2i(self.e13i.0 * rhs.e123.0)
+				+ E2i(self.e1oi.0 * rhs.e12i.0)
+				+ E2i(self.e23i.0 * rhs.e3oi.0)
+				- E2i(self.e3oi.0 * rhs.e23i.0),
+			e3o: -E3o(self.e1oi.0 * rhs.e13o.0) - E3o(self.e2oi.0 * rhs.e23o.0),
+			e3i: E3i(self.e12i.0 * rhs.e123.0) - E3i(self.e13i.0 * rhs.e1oi.0) + E3i(self.e1oi.0 * rhs.e13i.0)
+				- E3i(self.e23i.0 * rhs.e2oi.0)
+				+ E3i(self.e2oi.0 * rhs.e23i.0),
+			eoi: Eoi(self.e12i.0 * rhs.e12o.0) + Eoi(self.e13i.0 * rhs.e13o.0) + Eoi(self.e23i.0 * rhs.e23o.0),
+			e123: E123(self.e12i.0 * rhs.e3o.0) - E123(self.e13i.0 * rhs.e2o.0) + E123(self.e23i.0 * rhs.e1o.0),
+			e12o: -E12o(self.e1oi.0 * rhs.e2o.0) + E12o(self.e2oi.0 * rhs.e1o.0),
+			e12i: -E12i(self.e12i.0 * rhs.eoi.0) - E12i(self.e13i.0 * rhs.e23.0)
+				+ E12i(self.e1oi.0 * rhs.e2i.0)
+				+ E12i(self.e23i.0 * rhs.e13.0)
+				- E12i(self.e2oi.0 * rhs.e1i.0),
+			e13o: -E13o(self.e1oi.0 * rhs.e3o.0) + E13o(self.e3oi.0 * rhs.e1o.0),
+			e13i: E13i(self.e12i.0 * rhs.e23.0) - E13i(self.e13i.0 * rhs.eoi.0) + E13i(self.e1oi.0 * rhs.e3i.0)
+				- E13i(self.e23i.0 * rhs.e12.0)
+				- E13i(self.e3oi.0 * rhs.e1i.0),
+			e1oi: E1oi(self.e12i.0 * rhs.e2o.0) + E1oi(self.e13i.0 * rhs.e3o.0)
+				- E1oi(self.e2oi.0 * rhs.e12.0)
+				- E1oi(self.e3oi.0 * rhs.e13.0),
+			e23o: -E23o(self.e2oi.0 * rhs.e3o.0) + E23o(self.e3oi.0 * rhs.e2o.0),
+			e23i: -E23i(self.e12i.0 * rhs.e13.0) + E23i(self.e13i.0 * rhs.e12.0) - E23i(self.e23i.0 * rhs.eoi.0)
+				+ E23i(self.e2oi.0 * rhs.e3i.0)
+				- E23i(self.e3oi.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.e12i.0 * rhs.e1o.0) + E2oi(self.e1oi.0 * rhs.e12.0) + E2oi(self.e23i.0 * rhs.e3o.0)
+				- E2oi(self.e3oi.0 * rhs.e23.0),
+			e3oi: -E3oi(self.e13i.0 * rhs.e1o.0) + E3oi(self.e1oi.0 * rhs.e13.0) - E3oi(self.e23i.0 * rhs.e2o.0)
+				+ E3oi(self.e2oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e12i.0 * rhs.e3.0) + E123i(self.e13i.0 * rhs.e2.0) - E123i(self.e23i.0 * rhs.e1.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.eo.0) + E12oi(self.e1oi.0 * rhs.e2.0) - E12oi(self.e2oi.0 * rhs.e1.0),
+			e13oi: -E13oi(self.e13i.0 * rhs.eo.0) + E13oi(self.e1oi.0 * rhs.e3.0) - E13oi(self.e3oi.0 * rhs.e1.0),
+			e23oi: -E23oi(self.e23i.0 * rhs.eo.0) + E23oi(self.e2oi.0 * rhs.e3.0) - E23oi(self.e3oi.0 * rhs.e2.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Line.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Line {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : -E12(self.e3oi.0 * rhs.s.0),
+		//     e13   : E13(self.e2oi.0 * rhs.s.0),
+		//     e1o   : Default::default(),
+		//     e1i   : E1i(self.e23i.0 * rhs.s.0),
+		//     e23   : -E23(self.e1oi.0 * rhs.s.0),
+		//     e2o   : Default::default(),
+		//     e2i   : -E2i(self.e13i.0 * rhs.s.0),
+		//     e3o   : Default::default(),
+		//     e3i   : E3i(self.e12i.0 * rhs.s.0),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1oi.0 * rhs.e1.0) - E123(self.e2oi.0 * rhs.e2.0) - E123(self.e3oi.0 * rhs.e3.0),
+		//     e12o  : -E12o(self.e3oi.0 * rhs.eo.0),
+		//     e12i  : E12i(self.e12i.0 * rhs.e123oi.0) - E12i(self.e13i.0 * rhs.e1.0) - E12i(self.e23i.0 * rhs.e2.0) - E12i(self.e3oi.0 * rhs.ei.0),
+		//     e13o  : E13o(self.e2oi.0 * rhs.eo.0),
+		//     e13i  : E13i(self.e12i.0 * rhs.e1.0) + E13i(self.e13i.0 * rhs.e123oi.0) - E13i(self.e23i.0 * rhs.e3.0) + E13i(self.e2oi.0 * rhs.ei.0),
+		//     e1oi  : E1oi(self.e1oi.0 * rhs.e123oi.0) - E1oi(self.e23i.0 * rhs.eo.0),
+		//     e23o  : -E23o(self.e1oi.0 * rhs.eo.0),
+		//     e23i  : E23i(self.e12i.0 * rhs.e2.0) + E23i(self.e13i.0 * rhs.e3.0) - E23i(self.e1oi.0 * rhs.ei.0) + E23i(self.e23i.0 * rhs.e123oi.0),
+		//     e2oi  : E2oi(self.e13i.0 * rhs.eo.0) + E2oi(self.e2oi.0 * rhs.e123oi.0),
+		//     e3oi  : -E3oi(self.e12i.0 * rhs.eo.0) + E3oi(self.e3oi.0 * rhs.e123oi.0),
+		//     e123o : -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+		//     e123i : E123i(self.e12i.0 * rhs.e12.0) + E123i(self.e12i.0 * rhs.e12oi.0) + E123i(self.e13i.0 * rhs.e13.0) + E123i(self.e13i.0 * rhs.e13oi.0) - E123i(self.e1oi.0 * rhs.e1i.0) + E123i(self.e23i.0 * rhs.e23.0) + E123i(self.e23i.0 * rhs.e23oi.0) - E123i(self.e2oi.0 * rhs.e2i.0) - E123i(self.e3oi.0 * rhs.e3i.0),
+		//     e12oi : -E12oi(self.e12i.0 * rhs.e123o.0) + E12oi(self.e13i.0 * rhs.e1o.0) + E12oi(self.e1oi.0 * rhs.e13oi.0) + E12oi(self.e23i.0 * rhs.e2o.0) + E12oi(self.e2oi.0 * rhs.e23oi.0) - E12oi(self.e3oi.0 * rhs.eoi.0),
+		//     e13oi : -E13oi(self.e12i.0 * rhs.e1o.0) - E13oi(self.e13i.0 * rhs.e123o.0) - E13oi(self.e1oi.0 * rhs.e12oi.0) + E13oi(self.e23i.0 * rhs.e3o.0) + E13oi(self.e2oi.0 * rhs.eoi.0) + E13oi(self.e3oi.0 * rhs.e23oi.0),
+		//     e23oi : -E23oi(self.e12i.0 * rhs.e2o.0) - E23oi(self.e13i.0 * rhs.e3o.0) - E23oi(self.e1oi.0 * rhs.eoi.0) - E23oi(self.e23i.0 * rhs.e123o.0) - E23oi(self.e2oi.0 * rhs.e12oi.0) - E23oi(self.e3oi.0 * rhs.e13oi.0),
+		//     e123oi: -E123oi(self.e12i.0 * rhs.e12o.0) - E123oi(self.e13i.0 * rhs.e13o.0) - E123oi(self.e1oi.0 * rhs.e1oi.0) - E123oi(self.e23i.0 * rhs.e23o.0) - E123oi(self.e2oi.0 * rhs.e2oi.0) - E123oi(self.e3oi.0 * rhs.e3oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: -E12(self.e3oi.0 * rhs.s.0),
+			e13: E13(self.e2oi.0 * rhs.s.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e23i.0 * rhs.s.0),
+			e23: -E23(self.e1oi.0 * rhs.s.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e13i.0 * rhs.s.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e12i.0 * rhs.s.0),
+			eoi: Default::default(),
+			e123: -E123(self.e1oi.0 * rhs.e1.0) - E123(self.e2oi.0 * rhs.e2.0) - E123(self.e3oi.0 * rhs.e3.0),
+			e12o: -E12o(self.e3oi.0 * rhs.eo.0),
+			e12i: E12i(self.e12i.0 * rhs.e123oi.0)
+				- E12i(self.e13i.0 * rhs.e1.0)
+				- E12i(self.e23i.0 * rhs.e2.0)
+				- E12i(self.e3oi.0 * rhs.ei.0),
+			e13o: E13o(self.e2oi.0 * rhs.eo.0),
+			e13i: E13i(self.e12i.0 * rhs.e1.0) + E13i(self.e13i.0 * rhs.e123oi.0) - E13i(self.e23i.0 * rhs.e3.0)
+				+ E13i(self.e2oi.0 * rhs.ei.0),
+			e1oi: E1oi(self.e1oi.0 * rhs.e123oi.0) - E1oi(self.e23i.0 * rhs.eo.0),
+			e23o: -E23o(self.e1oi.0 * rhs.eo.0),
+			e23i: E23i(self.e12i.0 * rhs.e2.0) + E23i(self.e13i.0 * rhs.e3.0) - E23i(self.e1oi.0 * rhs.ei.0)
+				+ E23i(self.e23i.0 * rhs.e123oi.0),
+			e2oi: E2oi(self.e13i.0 * rhs.eo.0) + E2oi(self.e2oi.0 * rhs.e123oi.0),
+			e3oi: -E3oi(self.e12i.0 * rhs.eo.0) + E3oi(self.e3oi.0 * rhs.e123oi.0),
+			e123o: -E123o(self.e1oi.0 * rhs.e1o.0) - E123o(self.e2oi.0 * rhs.e2o.0) - E123o(self.e3oi.0 * rhs.e3o.0),
+			e123i: E123i(self.e12i.0 * rhs.e12.0)
+				+ E123i(self.e12i.0 * rhs.e12oi.0)
+				+ E123i(self.e13i.0 * rhs.e13.0)
+				+ E123i(self.e13i.0 * rhs.e13oi.0)
+				- E123i(self.e1oi.0 * rhs.e1i.0)
+				+ E123i(self.e23i.0 * rhs.e23.0)
+				+ E123i(self.e23i.0 * rhs.e23oi.0)
+				- E123i(self.e2oi.0 * rhs.e2i.0)
+				- E123i(self.e3oi.0 * rhs.e3i.0),
+			e12oi: -E12oi(self.e12i.0 * rhs.e123o.0)
+				+ E12oi(self.e13i.0 * rhs.e1o.0)
+				+ E12oi(self.e1oi.0 * rhs.e13oi.0)
+				+ E12oi(self.e23i.0 * rhs.e2o.0)
+				+ E12oi(self.e2oi.0 * rhs.e23oi.0)
+				- E12oi(self.e3oi.0 * rhs.eoi.0),
+			e13oi: -E13oi(self.e12i.0 * rhs.e1o.0)
+				- E13oi(self.e13i.0 * rhs.e123o.0)
+				- E13oi(self.e1oi.0 * rhs.e12oi.0)
+				+ E13oi(self.e23i.0 * rhs.e3o.0)
+				+ E13oi(self.e2oi.0 * rhs.eoi.0)
+				+ E13oi(self.e3oi.0 * rhs.e23oi.0),
+			e23oi: -E23oi(self.e12i.0 * rhs.e2o.0)
+				- E23oi(self.e13i.0 * rhs.e3o.0)
+				- E23oi(self.e1oi.0 * rhs.eoi.0)
+				- E23oi(self.e23i.0 * rhs.e123o.0)
+				- E23oi(self.e2oi.0 * rhs.e12oi.0)
+				- E23oi(self.e3oi.0 * rhs.e13oi.0),
+			e123oi: -E123oi(self.e12i.0 * rhs.e12o.0)
+				- E123oi(self.e13i.0 * rhs.e13o.0)
+				- E123oi(self.e1oi.0 * rhs.e1oi.0)
+				- E123oi(self.e23i.0 * rhs.e23o.0)
+				- E123oi(self.e2oi.0 * rhs.e2oi.0)
+				- E123oi(self.e3oi.0 * rhs.e3oi.0),
+		}
+	}
+}
@@ -0,0 +1,33 @@
+pub mod blades;
+pub mod traits;
+
+// ----------------------------------------------------------------------------
+// Geometric Algebra definition helpers:
+
+/// Special zero type for completeness, and better error messages.
+/// If you get this in an error message, it is because you multiplied
+/// two dimensions that always results in zero.
+pub struct Zero {}
+
+// ----------------------------------------------------------------------------
+
+// Types:
+pub mod circle;
+pub mod flatpoint;
+pub mod line;
+pub mod multivector;
+pub mod plane;
+pub mod point;
+pub mod pointpair;
+pub mod rotor;
+pub mod sphere;
+pub mod translator;
+pub mod vec3;
+pub mod versor;
+
+pub mod ffi;
+
+pub use self::{
+	blades::*, circle::*, flatpoint::*, line::*, multivector::*, plane::*, point::*, pointpair::*, rotor::*, sphere::*,
+	traits::*, translator::*, vec3::*, versor::*,
+};
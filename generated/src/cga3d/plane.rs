@@ -0,0 +1,4886 @@
+//! # Plane
+//!
+//! ## Operations
+//! ```text
+//! Plane.geometric(Plane) -> Versor
+//! Plane.anti_geometric(Plane) -> Multivector
+//! Plane.dot(Plane) -> S
+//! Plane.anti_wedge(Plane) -> Line
+//! Plane.left_contraction(Plane) -> S
+//! Plane.right_contraction(Plane) -> S
+//! Plane.scalar_product(Plane) -> S
+//! Plane.commutator(Plane) -> PointPair
+//! Plane.anti_dot(Plane) -> E123oi
+//! Plane.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Vec3) -> Versor
+//! Vec3.anti_geometric(Plane) -> Versor
+//! Plane.dot(Vec3) -> Line
+//! Vec3.dot(Plane) -> Line
+//! Plane.wedge(Vec3) -> E123oi
+//! Vec3.wedge(Plane) -> E123oi
+//! Plane.anti_wedge(Vec3) -> S
+//! Vec3.anti_wedge(Plane) -> S
+//! Vec3.left_contraction(Plane) -> Line
+//! Plane.right_contraction(Vec3) -> Line
+//! Plane.commutator(Vec3) -> Line
+//! Vec3.commutator(Plane) -> Line
+//! Plane.anti_dot(Vec3) -> PointPair
+//! Vec3.anti_dot(Plane) -> PointPair
+//! Plane.geometric(Point) -> Multivector
+//! Point.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Point) -> Versor
+//! Point.anti_geometric(Plane) -> Versor
+//! Plane.dot(Point) -> Circle
+//! Point.dot(Plane) -> Circle
+//! Plane.wedge(Point) -> E123oi
+//! Point.wedge(Plane) -> E123oi
+//! Plane.anti_wedge(Point) -> S
+//! Point.anti_wedge(Plane) -> S
+//! Point.left_contraction(Plane) -> Circle
+//! Plane.right_contraction(Point) -> Circle
+//! Plane.commutator(Point) -> Circle
+//! Point.commutator(Plane) -> Circle
+//! Plane.anti_dot(Point) -> PointPair
+//! Point.anti_dot(Plane) -> PointPair
+//! Plane.geometric(FlatPoint) -> Versor
+//! FlatPoint.geometric(Plane) -> Versor
+//! Plane.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.anti_geometric(Plane) -> Multivector
+//! Plane.dot(FlatPoint) -> PointPair
+//! FlatPoint.dot(Plane) -> PointPair
+//! Plane.anti_wedge(FlatPoint) -> Ei
+//! FlatPoint.anti_wedge(Plane) -> Ei
+//! FlatPoint.left_contraction(Plane) -> PointPair
+//! Plane.right_contraction(FlatPoint) -> PointPair
+//! Plane.commutator(FlatPoint) -> E123i
+//! FlatPoint.commutator(Plane) -> E123i
+//! Plane.anti_dot(FlatPoint) -> Line
+//! FlatPoint.anti_dot(Plane) -> Line
+//! Plane.geometric(PointPair) -> Versor
+//! PointPair.geometric(Plane) -> Versor
+//! Plane.anti_geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(Plane) -> Multivector
+//! Plane.dot(PointPair) -> PointPair
+//! PointPair.dot(Plane) -> PointPair
+//! Plane.anti_wedge(PointPair) -> Point
+//! PointPair.anti_wedge(Plane) -> Point
+//! PointPair.left_contraction(Plane) -> PointPair
+//! Plane.right_contraction(PointPair) -> PointPair
+//! Plane.commutator(PointPair) -> Sphere
+//! PointPair.commutator(Plane) -> Sphere
+//! Plane.anti_dot(PointPair) -> Circle
+//! PointPair.anti_dot(Plane) -> Circle
+//! Plane.geometric(Line) -> Multivector
+//! Line.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Line) -> Versor
+//! Line.anti_geometric(Plane) -> Versor
+//! Plane.dot(Line) -> Point
+//! Line.dot(Plane) -> Point
+//! Plane.anti_wedge(Line) -> FlatPoint
+//! Line.anti_wedge(Plane) -> FlatPoint
+//! Line.left_contraction(Plane) -> Point
+//! Plane.right_contraction(Line) -> Point
+//! Plane.commutator(Line) -> Point
+//! Line.commutator(Plane) -> Point
+//! Plane.anti_dot(Line) -> Plane
+//! Line.anti_dot(Plane) -> Plane
+//! Plane.geometric(Circle) -> Multivector
+//! Circle.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Circle) -> Versor
+//! Circle.anti_geometric(Plane) -> Versor
+//! Plane.dot(Circle) -> Point
+//! Circle.dot(Plane) -> Point
+//! Plane.anti_wedge(Circle) -> PointPair
+//! Circle.anti_wedge(Plane) -> PointPair
+//! Circle.left_contraction(Plane) -> Point
+//! Plane.right_contraction(Circle) -> Point
+//! Plane.commutator(Circle) -> Point
+//! Circle.commutator(Plane) -> Point
+//! Plane.anti_dot(Circle) -> Sphere
+//! Circle.anti_dot(Plane) -> Sphere
+//! Plane.geometric(Sphere) -> Versor
+//! Sphere.geometric(Plane) -> Versor
+//! Plane.anti_geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(Plane) -> Multivector
+//! Plane.dot(Sphere) -> S
+//! Sphere.dot(Plane) -> S
+//! Plane.anti_wedge(Sphere) -> Circle
+//! Sphere.anti_wedge(Plane) -> Circle
+//! Plane.left_contraction(Sphere) -> S
+//! Sphere.left_contraction(Plane) -> S
+//! Plane.right_contraction(Sphere) -> S
+//! Sphere.right_contraction(Plane) -> S
+//! Plane.scalar_product(Sphere) -> S
+//! Sphere.scalar_product(Plane) -> S
+//! Plane.commutator(Sphere) -> PointPair
+//! Sphere.commutator(Plane) -> PointPair
+//! Plane.anti_dot(Sphere) -> E123oi
+//! Sphere.anti_dot(Plane) -> E123oi
+//! Plane.geometric(Rotor) -> Versor
+//! Rotor.geometric(Plane) -> Versor
+//! Plane.anti_geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Plane) -> Multivector
+//! Plane.dot(Rotor) -> Versor
+//! Rotor.dot(Plane) -> Versor
+//! Plane.wedge(Rotor) -> Plane
+//! Rotor.wedge(Plane) -> Plane
+//! Plane.anti_wedge(Rotor) -> Vec3
+//! Rotor.anti_wedge(Plane) -> Vec3
+//! Rotor.left_contraction(Plane) -> Versor
+//! Plane.right_contraction(Rotor) -> Versor
+//! Plane.commutator(Rotor) -> Plane
+//! Rotor.commutator(Plane) -> Plane
+//! Plane.anti_dot(Rotor) -> Multivector
+//! Rotor.anti_dot(Plane) -> Multivector
+//! Plane.geometric(Translator) -> Versor
+//! Translator.geometric(Plane) -> Versor
+//! Plane.anti_geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Plane) -> Multivector
+//! Plane.dot(Translator) -> Versor
+//! Translator.dot(Plane) -> Versor
+//! Plane.wedge(Translator) -> Plane
+//! Translator.wedge(Plane) -> Plane
+//! Plane.anti_wedge(Translator) -> Ei
+//! Translator.anti_wedge(Plane) -> Ei
+//! Translator.left_contraction(Plane) -> Versor
+//! Plane.right_contraction(Translator) -> Versor
+//! Plane.commutator(Translator) -> E123i
+//! Translator.commutator(Plane) -> E123i
+//! Plane.anti_dot(Translator) -> Multivector
+//! Translator.anti_dot(Plane) -> Multivector
+//! Plane.geometric(Versor) -> Versor
+//! Versor.geometric(Plane) -> Versor
+//! Plane.anti_geometric(Versor) -> Multivector
+//! Versor.anti_geometric(Plane) -> Multivector
+//! Plane.dot(Versor) -> Versor
+//! Versor.dot(Plane) -> Versor
+//! Plane.wedge(Versor) -> Plane
+//! Versor.wedge(Plane) -> Plane
+//! Plane.anti_wedge(Versor) -> Multivector
+//! Versor.anti_wedge(Plane) -> Multivector
+//! Plane.left_contraction(Versor) -> S
+//! Versor.left_contraction(Plane) -> Versor
+//! Plane.right_contraction(Versor) -> Versor
+//! Versor.right_contraction(Plane) -> S
+//! Plane.scalar_product(Versor) -> S
+//! Versor.scalar_product(Plane) -> S
+//! Plane.commutator(Versor) -> Versor
+//! Versor.commutator(Plane) -> Versor
+//! Plane.anti_dot(Versor) -> Multivector
+//! Versor.anti_dot(Plane) -> Multivector
+//! Plane.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Plane) -> Multivector
+//! Plane.dot(Multivector) -> Multivector
+//! Multivector.dot(Plane) -> Multivector
+//! Plane.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Plane) -> Multivector
+//! Plane.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Plane) -> Multivector
+//! Plane.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Plane) -> Multivector
+//! Plane.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Plane) -> Multivector
+//! Plane.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Plane) -> S
+//! Plane.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Plane) -> Multivector
+//! Plane.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Plane) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane {
+	pub e123i: E123i,
+	pub e12oi: E12oi,
+	pub e13oi: E13oi,
+	pub e23oi: E23oi,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Plane {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Plane {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Plane {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.e123i.abs_diff_eq(&other.e123i, epsilon)
+			&& self.e12oi.abs_diff_eq(&other.e12oi, epsilon)
+			&& self.e13oi.abs_diff_eq(&other.e13oi, epsilon)
+			&& self.e23oi.abs_diff_eq(&other.e23oi, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Plane {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.e123i.relative_eq(&other.e123i, epsilon, max_relative)
+			&& self.e12oi.relative_eq(&other.e12oi, epsilon, max_relative)
+			&& self.e13oi.relative_eq(&other.e13oi, epsilon, max_relative)
+			&& self.e23oi.relative_eq(&other.e23oi, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Plane {
+	type Output = Point;
+	fn rcompl(self) -> Self::Output {
+		Point {
+			x: self.e23oi.rcompl(),
+			y: self.e13oi.rcompl(),
+			z: self.e12oi.rcompl(),
+			o: self.e123i.rcompl(),
+			inf: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Not for Plane {
+	type Output = Point;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Plane {
+	type Output = Point;
+	fn lcompl(self) -> Self::Output {
+		Point {
+			x: self.e23oi.lcompl(),
+			y: self.e13oi.lcompl(),
+			z: self.e12oi.lcompl(),
+			o: self.e123i.lcompl(),
+			inf: Default::default(),
+		}
+	}
+}
+
+impl Reverse for Plane {
+	fn rev(self) -> Self {
+		Plane {
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl AntiReverse for Plane {
+	fn arev(self) -> Self {
+		Plane {
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl Involute for Plane {
+	fn involute(self) -> Self {
+		Plane {
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl Conjugate for Plane {
+	fn conjugate(self) -> Self {
+		Plane {
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl Dual for Plane {
+	type Output = Point;
+	fn dual(self) -> Self::Output {
+		Point {
+			x: self.e23oi.dual(),
+			y: self.e13oi.dual(),
+			z: self.e12oi.dual(),
+			o: self.e123i.dual(),
+			inf: Default::default(),
+		}
+	}
+}
+
+impl Square for Plane {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(-S(self.e12oi.0 * self.e12oi.0) - S(self.e13oi.0 * self.e13oi.0) - S(self.e23oi.0 * self.e23oi.0)).0
+	}
+}
+
+// Omitted: Norm for Plane  (the bulk and weight need a diagonal metric)
+
+impl Plane {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.e123i.0, self.e12oi.0, self.e13oi.0, self.e23oi.0]
+	}
+}
+
+impl From<[f64; 4]> for Plane {
+	fn from(a: [f64; 4]) -> Self {
+		Plane {
+			e123i: E123i(a[0]),
+			e12oi: E12oi(a[1]),
+			e13oi: E13oi(a[2]),
+			e23oi: E23oi(a[3]),
+		}
+	}
+}
+
+impl From<Plane> for [f64; 4] {
+	fn from(v: Plane) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Plane> for Multivector {
+	fn from(v: Plane) -> Self {
+		Multivector {
+			e123i: v.e123i,
+			e12oi: v.e12oi,
+			e13oi: v.e13oi,
+			e23oi: v.e23oi,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Plane {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Plane` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.eo.0 == 0.0
+			&& mv.ei.0 == 0.0
+			&& mv.e12.0 == 0.0
+			&& mv.e13.0 == 0.0
+			&& mv.e1o.0 == 0.0
+			&& mv.e1i.0 == 0.0
+			&& mv.e23.0 == 0.0
+			&& mv.e2o.0 == 0.0
+			&& mv.e2i.0 == 0.0
+			&& mv.e3o.0 == 0.0
+			&& mv.e3i.0 == 0.0
+			&& mv.eoi.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e12o.0 == 0.0
+			&& mv.e12i.0 == 0.0
+			&& mv.e13o.0 == 0.0
+			&& mv.e13i.0 == 0.0
+			&& mv.e1oi.0 == 0.0
+			&& mv.e23o.0 == 0.0
+			&& mv.e23i.0 == 0.0
+			&& mv.e2oi.0 == 0.0
+			&& mv.e3oi.0 == 0.0
+			&& mv.e123o.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(Plane {
+				e123i: mv.e123i,
+				e12oi: mv.e12oi,
+				e13oi: mv.e13oi,
+				e23oi: mv.e23oi,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Vec3:
+
+// Plane.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Vec3) -> Versor
+impl AntiGeometric<Vec3> for Plane {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+		//     e13  : E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e123i.0 * rhs.x.0),
+		//     e23  : E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e123i.0 * rhs.y.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e123i.0 * rhs.z.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0),
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e123i.0 * rhs.x.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e123i.0 * rhs.y.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e123i.0 * rhs.z.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(Vec3) -> Line
+impl Dot<Vec3> for Plane {
+	type Output = Line;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Line {
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Plane {
+	type Output = Line;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Plane.wedge(Vec3) -> E123oi
+impl Wedge<Vec3> for Plane {
+	type Output = E123oi;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0)
+		self.e12oi.wedge(rhs.z) + self.e13oi.wedge(rhs.y) + self.e23oi.wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Plane {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Vec3) -> S
+impl AntiWedge<Vec3> for Plane {
+	type Output = S;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0)
+		self.e12oi.anti_wedge(rhs.z) + self.e13oi.anti_wedge(rhs.y) + self.e23oi.anti_wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Plane {
+	type Output = S;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(Vec3) -> Line
+impl RightContraction<Vec3> for Plane {
+	type Output = Line;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Line {
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Vec3) -> Line
+impl Commutator<Vec3> for Plane {
+	type Output = Line;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Line {
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+// Plane.anti_dot(Vec3) -> PointPair
+impl AntiDot<Vec3> for Plane {
+	type Output = PointPair;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+		//     e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e123i.0 * rhs.x.0),
+		//     e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+		//     e2o: Default::default(),
+		//     e2i: E2i(self.e123i.0 * rhs.y.0),
+		//     e3o: Default::default(),
+		//     e3i: E3i(self.e123i.0 * rhs.z.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e123i.0 * rhs.x.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e123i.0 * rhs.y.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e123i.0 * rhs.z.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Point:
+
+// Plane.geometric(Point) -> Multivector
+impl Geometric<Point> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.o.0),
+		//     e12o  : -E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o  : -E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o  : -E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e123i.0 * rhs.o.0) + E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e123i.0 * rhs.o.0),
+			e12o: -E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: -E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e123i.0 * rhs.o.0) + E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0)
+				+ E123oi(self.e23oi.0 * rhs.x.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Point) -> Versor
+impl AntiGeometric<Point> for Plane {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.o.0) + S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+		//     e13  : E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+		//     e1o  : -E1o(self.e23oi.0 * rhs.o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.x.0) - E1i(self.e23oi.0 * rhs.inf.0),
+		//     e23  : E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+		//     e2o  : E2o(self.e13oi.0 * rhs.o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.y.0) + E2i(self.e13oi.0 * rhs.inf.0),
+		//     e3o  : -E3o(self.e12oi.0 * rhs.o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0),
+		//     eoi  : Eoi(self.e123i.0 * rhs.o.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.o.0) + S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0)
+				+ S(self.e23oi.0 * rhs.x.0),
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+			e1o: -E1o(self.e23oi.0 * rhs.o.0),
+			e1i: E1i(self.e123i.0 * rhs.x.0) - E1i(self.e23oi.0 * rhs.inf.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+			e2o: E2o(self.e13oi.0 * rhs.o.0),
+			e2i: E2i(self.e123i.0 * rhs.y.0) + E2i(self.e13oi.0 * rhs.inf.0),
+			e3o: -E3o(self.e12oi.0 * rhs.o.0),
+			e3i: E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e123i.0 * rhs.o.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(Point) -> Circle
+impl Dot<Point> for Plane {
+	type Output = Circle;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e123i.0 * rhs.o.0),
+		//     e12o: -E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o: -E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o: -E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Circle {
+			e123: -E123(self.e123i.0 * rhs.o.0),
+			e12o: -E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: -E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point> for Plane {
+	type Output = Circle;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Plane.wedge(Point) -> E123oi
+impl Wedge<Point> for Plane {
+	type Output = E123oi;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// -E123oi(self.e123i.0 * rhs.o.0) + E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0)
+		self.e123i.wedge(rhs.o) + self.e12oi.wedge(rhs.z) + self.e13oi.wedge(rhs.y) + self.e23oi.wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitXor<Point> for Plane {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Point) -> S
+impl AntiWedge<Point> for Plane {
+	type Output = S;
+	fn anti_wedge(self, rhs: Point) -> Self::Output {
+		// -S(self.e123i.0 * rhs.o.0) + S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0)
+		self.e123i.anti_wedge(rhs.o)
+			+ self.e12oi.anti_wedge(rhs.z)
+			+ self.e13oi.anti_wedge(rhs.y)
+			+ self.e23oi.anti_wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitAnd<Point> for Plane {
+	type Output = S;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(Point) -> Circle
+impl RightContraction<Point> for Plane {
+	type Output = Circle;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e123i.0 * rhs.o.0),
+		//     e12o: -E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o: -E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o: -E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Circle {
+			e123: -E123(self.e123i.0 * rhs.o.0),
+			e12o: -E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: -E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl ScalarProduct<Point> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Point) -> Circle
+impl Commutator<Point> for Plane {
+	type Output = Circle;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e123i.0 * rhs.o.0),
+		//     e12o: -E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o: -E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o: -E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Circle {
+			e123: -E123(self.e123i.0 * rhs.o.0),
+			e12o: -E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: -E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+// Plane.anti_dot(Point) -> PointPair
+impl AntiDot<Point> for Plane {
+	type Output = PointPair;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+		//     e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+		//     e1o: -E1o(self.e23oi.0 * rhs.o.0),
+		//     e1i: E1i(self.e123i.0 * rhs.x.0) - E1i(self.e23oi.0 * rhs.inf.0),
+		//     e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+		//     e2o: E2o(self.e13oi.0 * rhs.o.0),
+		//     e2i: E2i(self.e123i.0 * rhs.y.0) + E2i(self.e13oi.0 * rhs.inf.0),
+		//     e3o: -E3o(self.e12oi.0 * rhs.o.0),
+		//     e3i: E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0),
+		//     eoi: Eoi(self.e123i.0 * rhs.o.0),
+		// }
+		PointPair {
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+			e1o: -E1o(self.e23oi.0 * rhs.o.0),
+			e1i: E1i(self.e123i.0 * rhs.x.0) - E1i(self.e23oi.0 * rhs.inf.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+			e2o: E2o(self.e13oi.0 * rhs.o.0),
+			e2i: E2i(self.e123i.0 * rhs.y.0) + E2i(self.e13oi.0 * rhs.inf.0),
+			e3o: -E3o(self.e12oi.0 * rhs.o.0),
+			e3i: E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e123i.0 * rhs.o.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP FlatPoint:
+
+// Plane.geometric(FlatPoint) -> Versor
+impl Geometric<FlatPoint> for Plane {
+	type Output = Versor;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e12oi.0 * rhs.eoi.0),
+			e13: E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for Plane {
+	type Output = Versor;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(FlatPoint) -> Multivector
+impl AntiGeometric<FlatPoint> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : -E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.e12oi.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: -E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e12oi.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(FlatPoint) -> PointPair
+impl Dot<FlatPoint> for Plane {
+	type Output = PointPair;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13: E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23: E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.e12oi.0 * rhs.eoi.0),
+			e13: E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for Plane {
+	type Output = PointPair;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<FlatPoint> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(FlatPoint) -> Ei
+impl AntiWedge<FlatPoint> for Plane {
+	type Output = Ei;
+	fn anti_wedge(self, rhs: FlatPoint) -> Self::Output {
+		// Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0)
+		self.e123i.anti_wedge(rhs.eoi)
+			+ self.e12oi.anti_wedge(rhs.e3i)
+			+ self.e13oi.anti_wedge(rhs.e2i)
+			+ self.e23oi.anti_wedge(rhs.e1i)
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for Plane {
+	type Output = Ei;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<FlatPoint> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(FlatPoint) -> PointPair
+impl RightContraction<FlatPoint> for Plane {
+	type Output = PointPair;
+	fn right_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13: E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23: E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.e12oi.0 * rhs.eoi.0),
+			e13: E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<FlatPoint> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(FlatPoint) -> E123i
+impl Commutator<FlatPoint> for Plane {
+	type Output = E123i;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0)
+		-E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+			+ E123i(self.e23oi.0 * rhs.e1i.0)
+	}
+}
+
+// Plane.anti_dot(FlatPoint) -> Line
+impl AntiDot<FlatPoint> for Plane {
+	type Output = Line;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi: E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi: -E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi: E3oi(self.e12oi.0 * rhs.eoi.0),
+		// }
+		Line {
+			e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: -E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e12oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP PointPair:
+
+// Plane.geometric(PointPair) -> Versor
+impl Geometric<PointPair> for Plane {
+	type Output = Versor;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for Plane {
+	type Output = Versor;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(PointPair) -> Multivector
+impl AntiGeometric<PointPair> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : -Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : -E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+			e2: -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+			e3: -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+			eo: -Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+			ei: Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(PointPair) -> PointPair
+impl Dot<PointPair> for Plane {
+	type Output = PointPair;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o: E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		// }
+		PointPair {
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<PointPair> for Plane {
+	type Output = PointPair;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<PointPair> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<PointPair> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(PointPair) -> Point
+impl AntiWedge<PointPair> for Plane {
+	type Output = Point;
+	fn anti_wedge(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     y  : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     z  : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     o  : -Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     inf: Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		// }
+		Point {
+			x: self.e123i.anti_wedge(rhs.e1o) + self.e12oi.anti_wedge(rhs.e13) + self.e13oi.anti_wedge(rhs.e12),
+			y: self.e123i.anti_wedge(rhs.e2o) + self.e12oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e12),
+			z: self.e123i.anti_wedge(rhs.e3o) + self.e13oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e13),
+			o: self.e12oi.anti_wedge(rhs.e3o) + self.e13oi.anti_wedge(rhs.e2o) + self.e23oi.anti_wedge(rhs.e1o),
+			inf: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e23oi.anti_wedge(rhs.e1i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for Plane {
+	type Output = Point;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<PointPair> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(PointPair) -> PointPair
+impl RightContraction<PointPair> for Plane {
+	type Output = PointPair;
+	fn right_contraction(self, rhs: PointPair) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o: E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		// }
+		PointPair {
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl ScalarProduct<PointPair> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(PointPair) -> Sphere
+impl Commutator<PointPair> for Plane {
+	type Output = Sphere;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+// Plane.anti_dot(PointPair) -> Circle
+impl AntiDot<PointPair> for Plane {
+	type Output = Circle;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o: E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o: -E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o: -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+		//     e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi: E2oi(self.e123i.0 * rhs.e2o.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		// }
+		Circle {
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Line:
+
+// Plane.geometric(Line) -> Multivector
+impl Geometric<Line> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     e2    : -E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : -E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) + E123(self.e23oi.0 * rhs.e1oi.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e23oi.0 * rhs.e13i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) - E13i(self.e23oi.0 * rhs.e12i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13oi.0 * rhs.e12i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			e2: -E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: -E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Default::default(),
+			ei: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) + E123(self.e23oi.0 * rhs.e1oi.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e23oi.0 * rhs.e13i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) - E13i(self.e23oi.0 * rhs.e12i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13oi.0 * rhs.e12i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Line) -> Versor
+impl AntiGeometric<Line> for Plane {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi  : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi: -E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi: E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi: E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+			eoi: Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: -E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Plane.dot(Line) -> Point
+impl Dot<Line> for Plane {
+	type Output = Point;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : -E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			z: -E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Default::default(),
+			inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Plane {
+	type Output = Point;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Line> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Line> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Line) -> FlatPoint
+impl AntiWedge<Line> for Plane {
+	type Output = FlatPoint;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e2i: E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3i: E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi: Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		// }
+		FlatPoint {
+			e1i: self.e123i.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e12i),
+			e2i: self.e123i.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e12i),
+			e3i: self.e123i.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e13i),
+			eoi: self.e12oi.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e2oi) + self.e23oi.anti_wedge(rhs.e1oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Plane {
+	type Output = FlatPoint;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Line> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(Line) -> Point
+impl RightContraction<Line> for Plane {
+	type Output = Point;
+	fn right_contraction(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : -E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			z: -E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Default::default(),
+			inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+impl ScalarProduct<Line> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Line) -> Point
+impl Commutator<Line> for Plane {
+	type Output = Point;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : -E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			z: -E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Default::default(),
+			inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+// Plane.anti_dot(Line) -> Plane
+impl AntiDot<Line> for Plane {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi: -E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi: E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi: E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0),
+		// }
+		Plane {
+			e123i: -E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: -E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Circle:
+
+// Plane.geometric(Circle) -> Multivector
+impl Geometric<Circle> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) + E123(self.e23oi.0 * rhs.e1oi.0),
+		//     e12o  : E12o(self.e13oi.0 * rhs.e23o.0) - E12o(self.e23oi.0 * rhs.e13o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e23oi.0 * rhs.e13i.0),
+		//     e13o  : -E13o(self.e12oi.0 * rhs.e23o.0) + E13o(self.e23oi.0 * rhs.e12o.0),
+		//     e13i  : -E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) - E13i(self.e23oi.0 * rhs.e12i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e23o.0) - E1oi(self.e23oi.0 * rhs.e123.0),
+		//     e23o  : E23o(self.e12oi.0 * rhs.e13o.0) - E23o(self.e13oi.0 * rhs.e12o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13oi.0 * rhs.e12i.0),
+		//     e2oi  : -E2oi(self.e123i.0 * rhs.e13o.0) + E2oi(self.e13oi.0 * rhs.e123.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e12o.0) - E3oi(self.e12oi.0 * rhs.e123.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) + E123(self.e23oi.0 * rhs.e1oi.0),
+			e12o: E12o(self.e13oi.0 * rhs.e23o.0) - E12o(self.e23oi.0 * rhs.e13o.0),
+			e12i: E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e23oi.0 * rhs.e13i.0),
+			e13o: -E13o(self.e12oi.0 * rhs.e23o.0) + E13o(self.e23oi.0 * rhs.e12o.0),
+			e13i: -E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) - E13i(self.e23oi.0 * rhs.e12i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e23o.0) - E1oi(self.e23oi.0 * rhs.e123.0),
+			e23o: E23o(self.e12oi.0 * rhs.e13o.0) - E23o(self.e13oi.0 * rhs.e12o.0),
+			e23i: E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13oi.0 * rhs.e12i.0),
+			e2oi: -E2oi(self.e123i.0 * rhs.e13o.0) + E2oi(self.e13oi.0 * rhs.e123.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e12o.0) - E3oi(self.e12oi.0 * rhs.e123.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Circle) -> Versor
+impl AntiGeometric<Circle> for Plane {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e12oi.0 * rhs.e123.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e13oi.0 * rhs.e123.0),
+		//     e1o  : -E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23  : -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e23oi.0 * rhs.e123.0),
+		//     e2o  : -E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o  : -E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi  : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		//     e123o: -E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13oi.0 * rhs.e13o.0) - E123o(self.e23oi.0 * rhs.e23o.0),
+		//     e123i: E123i(self.e123i.0 * rhs.e123.0) - E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e12o.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi: E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e12oi.0 * rhs.e123.0),
+			e13: -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e13oi.0 * rhs.e123.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0),
+			e1i: E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+			e23: -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e23oi.0 * rhs.e123.0),
+			e2o: -E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+			e2i: E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+			e3o: -E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+			e3i: E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+			eoi: Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+			e123o: -E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0),
+			e123i: E123i(self.e123i.0 * rhs.e123.0)
+				- E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e12o.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e12oi.0 * rhs.e1oi.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Plane.dot(Circle) -> Point
+impl Dot<Circle> for Plane {
+	type Output = Point;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     inf: Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+			inf: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Circle> for Plane {
+	type Output = Point;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Circle> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Circle> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Circle) -> PointPair
+impl AntiWedge<Circle> for Plane {
+	type Output = PointPair;
+	fn anti_wedge(self, rhs: Circle) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e12oi.0 * rhs.e123.0),
+		//     e13: -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e13oi.0 * rhs.e123.0),
+		//     e1o: -E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0),
+		//     e1i: E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23: -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e23oi.0 * rhs.e123.0),
+		//     e2o: -E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+		//     e2i: E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o: -E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+		//     e3i: E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi: Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		// }
+		PointPair {
+			e12: self.e123i.anti_wedge(rhs.e12o) + self.e12oi.anti_wedge(rhs.e123),
+			e13: self.e123i.anti_wedge(rhs.e13o) + self.e13oi.anti_wedge(rhs.e123),
+			e1o: self.e12oi.anti_wedge(rhs.e13o) + self.e13oi.anti_wedge(rhs.e12o),
+			e1i: self.e123i.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e12i),
+			e23: self.e123i.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e123),
+			e2o: self.e12oi.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e12o),
+			e2i: self.e123i.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e12i),
+			e3o: self.e13oi.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e13o),
+			e3i: self.e123i.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e13i),
+			eoi: self.e12oi.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e2oi) + self.e23oi.anti_wedge(rhs.e1oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Circle> for Plane {
+	type Output = PointPair;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Circle> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(Circle) -> Point
+impl RightContraction<Circle> for Plane {
+	type Output = Point;
+	fn right_contraction(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     inf: Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+			inf: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+impl ScalarProduct<Circle> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Circle) -> Point
+impl Commutator<Circle> for Plane {
+	type Output = Point;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     inf: Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+			inf: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+// Plane.anti_dot(Circle) -> Sphere
+impl AntiDot<Circle> for Plane {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13oi.0 * rhs.e13o.0) - E123o(self.e23oi.0 * rhs.e23o.0),
+		//     e123i: E123i(self.e123i.0 * rhs.e123.0) - E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e12o.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi: E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0),
+			e123i: E123i(self.e123i.0 * rhs.e123.0)
+				- E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e12o.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e12oi.0 * rhs.e1oi.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Plane:
+
+// Plane.geometric(Plane) -> Versor
+impl Geometric<Plane> for Plane {
+	type Output = Versor;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13  : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23  : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Plane {
+	type Output = Versor;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+			e3oi: E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Plane.dot(Plane) -> S
+impl Dot<Plane> for Plane {
+	type Output = S;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// -S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+impl std::ops::BitOr<Plane> for Plane {
+	type Output = S;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Plane) -> Line
+impl AntiWedge<Plane> for Plane {
+	type Output = Line;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13i: E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23i: E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi: E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		// }
+		Line {
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Plane {
+	type Output = Line;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Plane.left_contraction(Plane) -> S
+impl LeftContraction<Plane> for Plane {
+	type Output = S;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// -S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Plane.right_contraction(Plane) -> S
+impl RightContraction<Plane> for Plane {
+	type Output = S;
+	fn right_contraction(self, rhs: Plane) -> Self::Output {
+		// -S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Plane.scalar_product(Plane) -> S
+impl ScalarProduct<Plane> for Plane {
+	type Output = S;
+	fn scalar_product(self, rhs: Plane) -> Self::Output {
+		// -S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Plane.commutator(Plane) -> PointPair
+impl Commutator<Plane> for Plane {
+	type Output = PointPair;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o: Default::default(),
+		//     e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Plane.anti_dot(Plane) -> E123oi
+impl AntiDot<Plane> for Plane {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0)
+		E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Sphere:
+
+// Plane.geometric(Sphere) -> Versor
+impl Geometric<Sphere> for Plane {
+	type Output = Versor;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13  : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o  : E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23  : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o  : -E2o(self.e13oi.0 * rhs.e123o.0),
+		//     e2i  : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.e12oi.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e123i.0 * rhs.e123o.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: -E2o(self.e13oi.0 * rhs.e123o.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: E3o(self.e12oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for Plane {
+	type Output = Versor;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Sphere) -> Multivector
+impl AntiGeometric<Sphere> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0),
+		//     e12o  : -E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : -E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e123i.0 * rhs.e123o.0),
+			e12o: -E12o(self.e12oi.0 * rhs.e123o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+			e13o: -E13o(self.e13oi.0 * rhs.e123o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+			e23o: -E23o(self.e23oi.0 * rhs.e123o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+			e3oi: E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Plane.dot(Sphere) -> S
+impl Dot<Sphere> for Plane {
+	type Output = S;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+impl std::ops::BitOr<Sphere> for Plane {
+	type Output = S;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Sphere> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Sphere> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Sphere) -> Circle
+impl AntiWedge<Sphere> for Plane {
+	type Output = Circle;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e123i.0 * rhs.e123o.0),
+		//     e12o: -E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i: E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o: -E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i: E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o: -E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i: E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi: E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		// }
+		Circle {
+			e123: self.e123i.anti_wedge(rhs.e123o),
+			e12o: self.e12oi.anti_wedge(rhs.e123o),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e13oi.anti_wedge(rhs.e123o),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e23oi.anti_wedge(rhs.e123o),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for Plane {
+	type Output = Circle;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Plane.left_contraction(Sphere) -> S
+impl LeftContraction<Sphere> for Plane {
+	type Output = S;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Plane.right_contraction(Sphere) -> S
+impl RightContraction<Sphere> for Plane {
+	type Output = S;
+	fn right_contraction(self, rhs: Sphere) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Plane.scalar_product(Sphere) -> S
+impl ScalarProduct<Sphere> for Plane {
+	type Output = S;
+	fn scalar_product(self, rhs: Sphere) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Plane.commutator(Sphere) -> PointPair
+impl Commutator<Sphere> for Plane {
+	type Output = PointPair;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o: E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o: -E2o(self.e13oi.0 * rhs.e123o.0),
+		//     e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o: E3o(self.e12oi.0 * rhs.e123o.0),
+		//     e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi: -Eoi(self.e123i.0 * rhs.e123o.0),
+		// }
+		PointPair {
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: -E2o(self.e13oi.0 * rhs.e123o.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: E3o(self.e12oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0),
+		}
+	}
+}
+
+// Plane.anti_dot(Sphere) -> E123oi
+impl AntiDot<Sphere> for Plane {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0)
+		E123oi(self.e123i.0 * rhs.e123o.0)
+			+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+			+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+			+ E123oi(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Rotor:
+
+// Plane.geometric(Rotor) -> Versor
+impl Geometric<Rotor> for Plane {
+	type Output = Versor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e123i.0 * rhs.e13.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Plane {
+	type Output = Versor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Rotor) -> Multivector
+impl AntiGeometric<Rotor> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0) + E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0) + E1(self.e23oi.0 * rhs.s.0),
+			e2: E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e23oi.0 * rhs.e12.0),
+			e3: E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+			eo: Default::default(),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e123i.0 * rhs.e12.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e123i.0 * rhs.e13.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e123i.0 * rhs.e23.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(Rotor) -> Versor
+impl Dot<Rotor> for Plane {
+	type Output = Versor;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e123i.0 * rhs.e13.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Plane {
+	type Output = Versor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Plane.wedge(Rotor) -> Plane
+impl Wedge<Rotor> for Plane {
+	type Output = Plane;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Plane {
+			e123i: self.e123i.wedge(rhs.s),
+			e12oi: self.e12oi.wedge(rhs.s),
+			e13oi: self.e13oi.wedge(rhs.s),
+			e23oi: self.e23oi.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Plane {
+	type Output = Plane;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Rotor) -> Vec3
+impl AntiWedge<Rotor> for Plane {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     y: E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     z: E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		// }
+		Vec3 {
+			x: self.e12oi.anti_wedge(rhs.e13) + self.e13oi.anti_wedge(rhs.e12),
+			y: self.e12oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e12),
+			z: self.e13oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e13),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Plane {
+	type Output = Vec3;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Rotor> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(Rotor) -> Versor
+impl RightContraction<Rotor> for Plane {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e123i.0 * rhs.e13.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Rotor) -> Plane
+impl Commutator<Rotor> for Plane {
+	type Output = Plane;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Plane {
+		//     e123i: Default::default(),
+		//     e12oi: -E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		// }
+		Plane {
+			e123i: Default::default(),
+			e12oi: -E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+// Plane.anti_dot(Rotor) -> Multivector
+impl AntiDot<Rotor> for Plane {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: Default::default(),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e123i.0 * rhs.e12.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e123i.0 * rhs.e13.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e123i.0 * rhs.e23.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Translator:
+
+// Plane.geometric(Translator) -> Versor
+impl Geometric<Translator> for Plane {
+	type Output = Versor;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Plane {
+	type Output = Versor;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Translator) -> Multivector
+impl AntiGeometric<Translator> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: Default::default(),
+			ei: Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(Translator) -> Versor
+impl Dot<Translator> for Plane {
+	type Output = Versor;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Plane {
+	type Output = Versor;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Plane.wedge(Translator) -> Plane
+impl Wedge<Translator> for Plane {
+	type Output = Plane;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Plane {
+			e123i: self.e123i.wedge(rhs.s),
+			e12oi: self.e12oi.wedge(rhs.s),
+			e13oi: self.e13oi.wedge(rhs.s),
+			e23oi: self.e23oi.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Plane {
+	type Output = Plane;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Translator) -> Ei
+impl AntiWedge<Translator> for Plane {
+	type Output = Ei;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// -Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0)
+		self.e12oi.anti_wedge(rhs.e3i) + self.e13oi.anti_wedge(rhs.e2i) + self.e23oi.anti_wedge(rhs.e1i)
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Plane {
+	type Output = Ei;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Translator> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(Translator) -> Versor
+impl RightContraction<Translator> for Plane {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Translator) -> E123i
+impl Commutator<Translator> for Plane {
+	type Output = E123i;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0)
+		E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0)
+	}
+}
+
+// Plane.anti_dot(Translator) -> Multivector
+impl AntiDot<Translator> for Plane {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: Default::default(),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Versor:
+
+// Plane.geometric(Versor) -> Versor
+impl Geometric<Versor> for Plane {
+	type Output = Versor;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0) - E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e12oi.0 * rhs.e23oi.0) + E13(self.e13oi.0 * rhs.eoi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o  : -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e123i.0 * rhs.e23oi.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) - E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e13oi.0 * rhs.e123o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e123i.0 * rhs.e13oi.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e13oi.0 * rhs.e123i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : E3o(self.e12oi.0 * rhs.e123o.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : -Eoi(self.e123i.0 * rhs.e123o.0) - Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e3o.0) + E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0) - E12(self.e13oi.0 * rhs.e23oi.0)
+				+ E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e12oi.0 * rhs.e23oi.0) + E13(self.e13oi.0 * rhs.eoi.0)
+				- E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e123i.0 * rhs.e23oi.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				- E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) - E23(self.e12oi.0 * rhs.e13oi.0)
+				+ E23(self.e13oi.0 * rhs.e12oi.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e13oi.0 * rhs.e123o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e123i.0 * rhs.e13oi.0) - E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e13oi.0 * rhs.e123i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: E3o(self.e12oi.0 * rhs.e123o.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0)
+				- E3i(self.e12oi.0 * rhs.e123i.0)
+				- E3i(self.e13oi.0 * rhs.e1i.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0)
+				- Eoi(self.e12oi.0 * rhs.e12.0)
+				- Eoi(self.e13oi.0 * rhs.e13.0)
+				- Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) + E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0)
+				+ E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for Plane {
+	type Output = Versor;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Versor) -> Multivector
+impl AntiGeometric<Versor> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0) + E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : -Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : -E12o(self.e12oi.0 * rhs.e123o.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : -E13o(self.e12oi.0 * rhs.e1o.0) - E13o(self.e13oi.0 * rhs.e123o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e12oi.0 * rhs.e1i.0) - E13i(self.e13oi.0 * rhs.e123i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e13oi.0 * rhs.eoi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0)
+				+ E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e12oi.0 * rhs.e23.0)
+				- E2(self.e13oi.0 * rhs.s.0)
+				- E2(self.e23oi.0 * rhs.e12.0),
+			e3: -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0)
+				- E3(self.e23oi.0 * rhs.e13.0),
+			eo: -Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+			ei: Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0)
+				+ Ei(self.e13oi.0 * rhs.e2i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e123i.0 * rhs.e123o.0)
+				+ E123(self.e12oi.0 * rhs.e12.0)
+				+ E123(self.e13oi.0 * rhs.e13.0)
+				+ E123(self.e23oi.0 * rhs.e23.0),
+			e12o: -E12o(self.e12oi.0 * rhs.e123o.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0)
+				+ E12i(self.e13oi.0 * rhs.e1i.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e12oi.0 * rhs.e1o.0) - E13o(self.e13oi.0 * rhs.e123o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0)
+				- E13i(self.e12oi.0 * rhs.e1i.0)
+				- E13i(self.e13oi.0 * rhs.e123i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0)
+				+ E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0)
+				- E23i(self.e12oi.0 * rhs.e2i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e23oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e12oi.0 * rhs.e23oi.0)
+				- E2oi(self.e13oi.0 * rhs.eoi.0)
+				- E2oi(self.e23oi.0 * rhs.e12oi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e13oi.0 * rhs.e23oi.0)
+				- E3oi(self.e23oi.0 * rhs.e13oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Plane.dot(Versor) -> Versor
+impl Dot<Versor> for Plane {
+	type Output = Versor;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for Plane {
+	type Output = Versor;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Plane.wedge(Versor) -> Plane
+impl Wedge<Versor> for Plane {
+	type Output = Plane;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Plane {
+			e123i: self.e123i.wedge(rhs.s),
+			e12oi: self.e12oi.wedge(rhs.s),
+			e13oi: self.e13oi.wedge(rhs.s),
+			e23oi: self.e23oi.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for Plane {
+	type Output = Plane;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Versor) -> Multivector
+impl AntiWedge<Versor> for Plane {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : -Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0),
+		//     e12o  : -E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : -E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.e123i.anti_wedge(rhs.e1o) + self.e12oi.anti_wedge(rhs.e13) + self.e13oi.anti_wedge(rhs.e12),
+			e2: self.e123i.anti_wedge(rhs.e2o) + self.e12oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e12),
+			e3: self.e123i.anti_wedge(rhs.e3o) + self.e13oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e13),
+			eo: self.e12oi.anti_wedge(rhs.e3o) + self.e13oi.anti_wedge(rhs.e2o) + self.e23oi.anti_wedge(rhs.e1o),
+			ei: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e23oi.anti_wedge(rhs.e1i),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e123i.anti_wedge(rhs.e123o),
+			e12o: self.e12oi.anti_wedge(rhs.e123o),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e13oi.anti_wedge(rhs.e123o),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e23oi.anti_wedge(rhs.e123o),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Versor> for Plane {
+	type Output = Multivector;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Plane.left_contraction(Versor) -> S
+impl LeftContraction<Versor> for Plane {
+	type Output = S;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Plane.right_contraction(Versor) -> Versor
+impl RightContraction<Versor> for Plane {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+// Plane.scalar_product(Versor) -> S
+impl ScalarProduct<Versor> for Plane {
+	type Output = S;
+	fn scalar_product(self, rhs: Versor) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Plane.commutator(Versor) -> Versor
+impl Commutator<Versor> for Plane {
+	type Output = Versor;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13  : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o  : E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23  : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o  : -E2o(self.e13oi.0 * rhs.e123o.0),
+		//     e2i  : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.e12oi.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e123i.0 * rhs.e123o.0),
+		//     e123o: -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: -E2o(self.e13oi.0 * rhs.e123o.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: E3o(self.e12oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0),
+			e123o: -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+// Plane.anti_dot(Versor) -> Multivector
+impl AntiDot<Versor> for Plane {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : -E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: Default::default(),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Multivector:
+
+// Plane.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0) - E1(self.e23oi.0 * rhs.e123oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e13oi.0 * rhs.e123oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) - E3(self.e12oi.0 * rhs.e123oi.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e123i.0 * rhs.e123oi.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0) - E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e12oi.0 * rhs.e23oi.0) + E13(self.e13oi.0 * rhs.eoi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o   : -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i   : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e123i.0 * rhs.e23oi.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23   : E23(self.e123i.0 * rhs.e1o.0) - E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o   : E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e13oi.0 * rhs.e123o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e123i.0 * rhs.e13oi.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e13oi.0 * rhs.e123i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o   : E3o(self.e12oi.0 * rhs.e123o.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i   : -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi   : -Eoi(self.e123i.0 * rhs.e123o.0) - Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0) + E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) + E123(self.e23oi.0 * rhs.e1oi.0),
+		//     e12o  : -E12o(self.e12oi.0 * rhs.eo.0) + E12o(self.e13oi.0 * rhs.e23o.0) - E12o(self.e23oi.0 * rhs.e13o.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e123i.0 * rhs.e3oi.0) + E12i(self.e12oi.0 * rhs.ei.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e23oi.0 * rhs.e13i.0),
+		//     e13o  : -E13o(self.e12oi.0 * rhs.e23o.0) - E13o(self.e13oi.0 * rhs.eo.0) + E13o(self.e23oi.0 * rhs.e12o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e2.0) - E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) + E13i(self.e13oi.0 * rhs.ei.0) - E13i(self.e23oi.0 * rhs.e12i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e23o.0) + E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0) - E1oi(self.e23oi.0 * rhs.e123.0),
+		//     e23o  : E23o(self.e12oi.0 * rhs.e13o.0) - E23o(self.e13oi.0 * rhs.e12o.0) - E23o(self.e23oi.0 * rhs.eo.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13oi.0 * rhs.e12i.0) + E23i(self.e23oi.0 * rhs.ei.0),
+		//     e2oi  : -E2oi(self.e123i.0 * rhs.e13o.0) - E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e13oi.0 * rhs.e123.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e12o.0) - E3oi(self.e12oi.0 * rhs.e123.0) - E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+		//     e123o : -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i : -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi : E12oi(self.e123i.0 * rhs.e3o.0) + E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi : -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi : E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e23oi.0 * rhs.s.0),
+		//     e123oi: -E123oi(self.e123i.0 * rhs.eo.0) + E123oi(self.e12oi.0 * rhs.e3.0) - E123oi(self.e13oi.0 * rhs.e2.0) + E123oi(self.e23oi.0 * rhs.e1.0),
+		// }
+		Multivector {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e1: E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0)
+				- E1(self.e23oi.0 * rhs.e123oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e13oi.0 * rhs.e123oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0)
+				- E3(self.e12oi.0 * rhs.e123oi.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e123i.0 * rhs.e123oi.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0) - E12(self.e13oi.0 * rhs.e23oi.0)
+				+ E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e12oi.0 * rhs.e23oi.0) + E13(self.e13oi.0 * rhs.eoi.0)
+				- E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e123i.0 * rhs.e23oi.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				- E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) - E23(self.e12oi.0 * rhs.e13oi.0)
+				+ E23(self.e13oi.0 * rhs.e12oi.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e13oi.0 * rhs.e123o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e123i.0 * rhs.e13oi.0) - E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e13oi.0 * rhs.e123i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: E3o(self.e12oi.0 * rhs.e123o.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0)
+				- E3i(self.e12oi.0 * rhs.e123i.0)
+				- E3i(self.e13oi.0 * rhs.e1i.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0)
+				- Eoi(self.e12oi.0 * rhs.e12.0)
+				- Eoi(self.e13oi.0 * rhs.e13.0)
+				- Eoi(self.e23oi.0 * rhs.e23.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0) + E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0)
+				+ E123(self.e23oi.0 * rhs.e1oi.0),
+			e12o: -E12o(self.e12oi.0 * rhs.eo.0) + E12o(self.e13oi.0 * rhs.e23o.0) - E12o(self.e23oi.0 * rhs.e13o.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e123i.0 * rhs.e3oi.0) + E12i(self.e12oi.0 * rhs.ei.0)
+				- E12i(self.e13oi.0 * rhs.e23i.0)
+				+ E12i(self.e23oi.0 * rhs.e13i.0),
+			e13o: -E13o(self.e12oi.0 * rhs.e23o.0) - E13o(self.e13oi.0 * rhs.eo.0) + E13o(self.e23oi.0 * rhs.e12o.0),
+			e13i: E13i(self.e123i.0 * rhs.e2.0) - E13i(self.e123i.0 * rhs.e2oi.0)
+				+ E13i(self.e12oi.0 * rhs.e23i.0)
+				+ E13i(self.e13oi.0 * rhs.ei.0)
+				- E13i(self.e23oi.0 * rhs.e12i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e23o.0) + E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0)
+				- E1oi(self.e23oi.0 * rhs.e123.0),
+			e23o: E23o(self.e12oi.0 * rhs.e13o.0) - E23o(self.e13oi.0 * rhs.e12o.0) - E23o(self.e23oi.0 * rhs.eo.0),
+			e23i: -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0)
+				+ E23i(self.e13oi.0 * rhs.e12i.0)
+				+ E23i(self.e23oi.0 * rhs.ei.0),
+			e2oi: -E2oi(self.e123i.0 * rhs.e13o.0) - E2oi(self.e12oi.0 * rhs.e1.0)
+				+ E2oi(self.e13oi.0 * rhs.e123.0)
+				+ E2oi(self.e23oi.0 * rhs.e3.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e12o.0)
+				- E3oi(self.e12oi.0 * rhs.e123.0)
+				- E3oi(self.e13oi.0 * rhs.e1.0)
+				- E3oi(self.e23oi.0 * rhs.e2.0),
+			e123o: -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) + E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0)
+				+ E23oi(self.e23oi.0 * rhs.s.0),
+			e123oi: -E123oi(self.e123i.0 * rhs.eo.0) + E123oi(self.e12oi.0 * rhs.e3.0)
+				- E123oi(self.e13oi.0 * rhs.e2.0)
+				+ E123oi(self.e23oi.0 * rhs.e1.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.eo.0) + S(self.e12oi.0 * rhs.e3.0) - S(self.e13oi.0 * rhs.e2.0) + S(self.e23oi.0 * rhs.e1.0),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0) + E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : -Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e12oi.0 * rhs.e123.0) - E12(self.e13oi.0 * rhs.e1.0) - E12(self.e23oi.0 * rhs.e2.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e12oi.0 * rhs.e1.0) + E13(self.e13oi.0 * rhs.e123.0) - E13(self.e23oi.0 * rhs.e3.0),
+		//     e1o   : -E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0) - E1o(self.e23oi.0 * rhs.eo.0),
+		//     e1i   : E1i(self.e123i.0 * rhs.e1.0) + E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0) - E1i(self.e23oi.0 * rhs.ei.0),
+		//     e23   : -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e12oi.0 * rhs.e2.0) + E23(self.e13oi.0 * rhs.e3.0) + E23(self.e23oi.0 * rhs.e123.0),
+		//     e2o   : -E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e13oi.0 * rhs.eo.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e13oi.0 * rhs.ei.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o   : -E3o(self.e12oi.0 * rhs.eo.0) - E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e3.0) + E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e12oi.0 * rhs.ei.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi   : Eoi(self.e123i.0 * rhs.eo.0) + Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : -E12o(self.e12oi.0 * rhs.e123o.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : -E13o(self.e12oi.0 * rhs.e1o.0) - E13o(self.e13oi.0 * rhs.e123o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e12oi.0 * rhs.e1i.0) - E13i(self.e13oi.0 * rhs.e123i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e13oi.0 * rhs.eoi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : -E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13oi.0 * rhs.e13o.0) - E123o(self.e23oi.0 * rhs.e23o.0),
+		//     e123i : E123i(self.e123i.0 * rhs.e123.0) + E123i(self.e123i.0 * rhs.e123oi.0) - E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi : E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e12oi.0 * rhs.e123oi.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi : E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) + E13oi(self.e13oi.0 * rhs.e123oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi : E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0) + E23oi(self.e23oi.0 * rhs.e123oi.0),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: -S(self.e123i.0 * rhs.eo.0) + S(self.e12oi.0 * rhs.e3.0) - S(self.e13oi.0 * rhs.e2.0)
+				+ S(self.e23oi.0 * rhs.e1.0),
+			e1: -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0)
+				+ E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e12oi.0 * rhs.e23.0)
+				- E2(self.e13oi.0 * rhs.s.0)
+				- E2(self.e23oi.0 * rhs.e12.0),
+			e3: -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0)
+				- E3(self.e23oi.0 * rhs.e13.0),
+			eo: -Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+			ei: Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0)
+				+ Ei(self.e13oi.0 * rhs.e2i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e12oi.0 * rhs.e123.0)
+				- E12(self.e13oi.0 * rhs.e1.0)
+				- E12(self.e23oi.0 * rhs.e2.0),
+			e13: -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e12oi.0 * rhs.e1.0) + E13(self.e13oi.0 * rhs.e123.0)
+				- E13(self.e23oi.0 * rhs.e3.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0) - E1o(self.e23oi.0 * rhs.eo.0),
+			e1i: E1i(self.e123i.0 * rhs.e1.0) + E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0)
+				+ E1i(self.e13oi.0 * rhs.e12i.0)
+				- E1i(self.e23oi.0 * rhs.ei.0),
+			e23: -E23(self.e123i.0 * rhs.e23o.0)
+				+ E23(self.e12oi.0 * rhs.e2.0)
+				+ E23(self.e13oi.0 * rhs.e3.0)
+				+ E23(self.e23oi.0 * rhs.e123.0),
+			e2o: -E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e13oi.0 * rhs.eo.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+			e2i: E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0)
+				+ E2i(self.e13oi.0 * rhs.ei.0)
+				+ E2i(self.e23oi.0 * rhs.e12i.0),
+			e3o: -E3o(self.e12oi.0 * rhs.eo.0) - E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+			e3i: E3i(self.e123i.0 * rhs.e3.0) + E3i(self.e123i.0 * rhs.e3oi.0)
+				- E3i(self.e12oi.0 * rhs.ei.0)
+				- E3i(self.e13oi.0 * rhs.e23i.0)
+				+ E3i(self.e23oi.0 * rhs.e13i.0),
+			eoi: Eoi(self.e123i.0 * rhs.eo.0) + Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0)
+				+ Eoi(self.e23oi.0 * rhs.e1oi.0),
+			e123: -E123(self.e123i.0 * rhs.e123o.0)
+				+ E123(self.e12oi.0 * rhs.e12.0)
+				+ E123(self.e13oi.0 * rhs.e13.0)
+				+ E123(self.e23oi.0 * rhs.e23.0),
+			e12o: -E12o(self.e12oi.0 * rhs.e123o.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0)
+				+ E12i(self.e13oi.0 * rhs.e1i.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e12oi.0 * rhs.e1o.0) - E13o(self.e13oi.0 * rhs.e123o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0)
+				- E13i(self.e12oi.0 * rhs.e1i.0)
+				- E13i(self.e13oi.0 * rhs.e123i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0)
+				+ E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0)
+				- E23i(self.e12oi.0 * rhs.e2i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e23oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e12oi.0 * rhs.e23oi.0)
+				- E2oi(self.e13oi.0 * rhs.eoi.0)
+				- E2oi(self.e23oi.0 * rhs.e12oi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e13oi.0 * rhs.e23oi.0)
+				- E3oi(self.e23oi.0 * rhs.e13oi.0),
+			e123o: -E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0),
+			e123i: E123i(self.e123i.0 * rhs.e123.0) + E123i(self.e123i.0 * rhs.e123oi.0)
+				- E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e12oi.0 * rhs.e123oi.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e123i.0 * rhs.e13o.0)
+				+ E13oi(self.e12oi.0 * rhs.e1oi.0)
+				+ E13oi(self.e13oi.0 * rhs.e123oi.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0)
+				+ E23oi(self.e23oi.0 * rhs.e123oi.0),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Plane.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Plane {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0) - E1(self.e23oi.0 * rhs.e123oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e13oi.0 * rhs.e123oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) - E3(self.e12oi.0 * rhs.e123oi.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e123i.0 * rhs.e123oi.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o   : -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i   : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23   : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o   : E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o   : E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i   : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi   : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0),
+		//     e12o  : -E12o(self.e12oi.0 * rhs.eo.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+		//     e13o  : -E13o(self.e13oi.0 * rhs.eo.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+		//     e23o  : -E23o(self.e23oi.0 * rhs.eo.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+		//     e123o : Default::default(),
+		//     e123i : E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.s.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e1: E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0)
+				- E1(self.e23oi.0 * rhs.e123oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e13oi.0 * rhs.e123oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0)
+				- E3(self.e12oi.0 * rhs.e123oi.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e123i.0 * rhs.e123oi.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0),
+			e12o: -E12o(self.e12oi.0 * rhs.eo.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+			e13o: -E13o(self.e13oi.0 * rhs.eo.0),
+			e13i: E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+			e23o: -E23o(self.e23oi.0 * rhs.eo.0),
+			e23i: -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Plane {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Plane.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Plane {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.s.0),
+		//     e123oi: -E123oi(self.e123i.0 * rhs.eo.0) + E123oi(self.e12oi.0 * rhs.e3.0) - E123oi(self.e13oi.0 * rhs.e2.0) + E123oi(self.e23oi.0 * rhs.e1.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: self.e123i.wedge(rhs.s),
+			e12oi: self.e12oi.wedge(rhs.s),
+			e13oi: self.e13oi.wedge(rhs.s),
+			e23oi: self.e23oi.wedge(rhs.s),
+			e123oi: self.e123i.wedge(rhs.eo)
+				+ self.e12oi.wedge(rhs.e3)
+				+ self.e13oi.wedge(rhs.e2)
+				+ self.e23oi.wedge(rhs.e1),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Plane {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Plane {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.eo.0) + S(self.e12oi.0 * rhs.e3.0) - S(self.e13oi.0 * rhs.e2.0) + S(self.e23oi.0 * rhs.e1.0),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : -Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e12oi.0 * rhs.e123.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e13oi.0 * rhs.e123.0),
+		//     e1o   : -E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0),
+		//     e1i   : E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23   : -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e23oi.0 * rhs.e123.0),
+		//     e2o   : -E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o   : -E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi   : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0),
+		//     e12o  : -E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : -E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : E123i(self.e123i.0 * rhs.e123oi.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.e123oi.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.e123oi.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.e123oi.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.e123i.anti_wedge(rhs.eo)
+				+ self.e12oi.anti_wedge(rhs.e3)
+				+ self.e13oi.anti_wedge(rhs.e2)
+				+ self.e23oi.anti_wedge(rhs.e1),
+			e1: self.e123i.anti_wedge(rhs.e1o) + self.e12oi.anti_wedge(rhs.e13) + self.e13oi.anti_wedge(rhs.e12),
+			e2: self.e123i.anti_wedge(rhs.e2o) + self.e12oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e12),
+			e3: self.e123i.anti_wedge(rhs.e3o) + self.e13oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e13),
+			eo: self.e12oi.anti_wedge(rhs.e3o) + self.e13oi.anti_wedge(rhs.e2o) + self.e23oi.anti_wedge(rhs.e1o),
+			ei: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e23oi.anti_wedge(rhs.e1i),
+			e12: self.e123i.anti_wedge(rhs.e12o) + self.e12oi.anti_wedge(rhs.e123),
+			e13: self.e123i.anti_wedge(rhs.e13o) + self.e13oi.anti_wedge(rhs.e123),
+			e1o: self.e12oi.anti_wedge(rhs.e13o) + self.e13oi.anti_wedge(rhs.e12o),
+			e1i: self.e123i.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e12i),
+			e23: self.e123i.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e123),
+			e2o: self.e12oi.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e12o),
+			e2i: self.e123i.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e12i),
+			e3o: self.e13oi.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e13o),
+			e3i: self.e123i.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e13i),
+			eoi: self.e12oi.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e2oi) + self.e23oi.anti_wedge(rhs.e1oi),
+			e123: self.e123i.anti_wedge(rhs.e123o),
+			e12o: self.e12oi.anti_wedge(rhs.e123o),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e13oi.anti_wedge(rhs.e123o),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e23oi.anti_wedge(rhs.e123o),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+			e123o: Default::default(),
+			e123i: self.e123i.anti_wedge(rhs.e123oi),
+			e12oi: self.e12oi.anti_wedge(rhs.e123oi),
+			e13oi: self.e13oi.anti_wedge(rhs.e123oi),
+			e23oi: self.e23oi.anti_wedge(rhs.e123oi),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Plane {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Plane.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Plane {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e1    : -E1(self.e23oi.0 * rhs.e123oi.0),
+		//     e2    : E2(self.e13oi.0 * rhs.e123oi.0),
+		//     e3    : -E3(self.e12oi.0 * rhs.e123oi.0),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e123i.0 * rhs.e123oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e1: -E1(self.e23oi.0 * rhs.e123oi.0),
+			e2: E2(self.e13oi.0 * rhs.e123oi.0),
+			e3: -E3(self.e12oi.0 * rhs.e123oi.0),
+			eo: Default::default(),
+			ei: -Ei(self.e123i.0 * rhs.e123oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Plane.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Plane {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o   : -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i   : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23   : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o   : E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o   : E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i   : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi   : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0),
+		//     e12o  : -E12o(self.e12oi.0 * rhs.eo.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+		//     e13o  : -E13o(self.e13oi.0 * rhs.eo.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+		//     e23o  : -E23o(self.e23oi.0 * rhs.eo.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+		//     e123o : Default::default(),
+		//     e123i : E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.s.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e1: E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0),
+			e12o: -E12o(self.e12oi.0 * rhs.eo.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+			e13o: -E13o(self.e13oi.0 * rhs.eo.0),
+			e13i: E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+			e23o: -E23o(self.e23oi.0 * rhs.eo.0),
+			e23i: -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Plane.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Plane {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Plane.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Plane {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13   : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o   : E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i   : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23   : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o   : -E2o(self.e13oi.0 * rhs.e123o.0),
+		//     e2i   : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o   : E3o(self.e12oi.0 * rhs.e123o.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi   : -Eoi(self.e123i.0 * rhs.e123o.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0),
+		//     e12o  : -E12o(self.e12oi.0 * rhs.eo.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+		//     e13o  : -E13o(self.e13oi.0 * rhs.eo.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+		//     e23o  : -E23o(self.e23oi.0 * rhs.eo.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+		//     e123o : -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i : -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi : E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi : -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi : E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123i.0 * rhs.e23o.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: -E2o(self.e13oi.0 * rhs.e123o.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: E3o(self.e12oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0),
+			e12o: -E12o(self.e12oi.0 * rhs.eo.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+			e13o: -E13o(self.e13oi.0 * rhs.eo.0),
+			e13i: E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+			e23o: -E23o(self.e23oi.0 * rhs.eo.0),
+			e23i: -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+			e123o: -E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Plane.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Plane {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : -E12(self.e13oi.0 * rhs.e1.0) - E12(self.e23oi.0 * rhs.e2.0),
+		//     e13   : E13(self.e12oi.0 * rhs.e1.0) - E13(self.e23oi.0 * rhs.e3.0),
+		//     e1o   : -E1o(self.e23oi.0 * rhs.eo.0),
+		//     e1i   : E1i(self.e123i.0 * rhs.e1.0) - E1i(self.e23oi.0 * rhs.ei.0),
+		//     e23   : E23(self.e12oi.0 * rhs.e2.0) + E23(self.e13oi.0 * rhs.e3.0),
+		//     e2o   : E2o(self.e13oi.0 * rhs.eo.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e13oi.0 * rhs.ei.0),
+		//     e3o   : -E3o(self.e12oi.0 * rhs.eo.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e3.0) - E3i(self.e12oi.0 * rhs.ei.0),
+		//     eoi   : Eoi(self.e123i.0 * rhs.eo.0),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : -E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		//     e123o : -E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13oi.0 * rhs.e13o.0) - E123o(self.e23oi.0 * rhs.e23o.0),
+		//     e123i : E123i(self.e123i.0 * rhs.e123.0) + E123i(self.e123i.0 * rhs.e123oi.0) - E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi : E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e12oi.0 * rhs.e123oi.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi : E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) + E13oi(self.e13oi.0 * rhs.e123oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi : E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0) + E23oi(self.e23oi.0 * rhs.e123oi.0),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: Default::default(),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: -E12(self.e13oi.0 * rhs.e1.0) - E12(self.e23oi.0 * rhs.e2.0),
+			e13: E13(self.e12oi.0 * rhs.e1.0) - E13(self.e23oi.0 * rhs.e3.0),
+			e1o: -E1o(self.e23oi.0 * rhs.eo.0),
+			e1i: E1i(self.e123i.0 * rhs.e1.0) - E1i(self.e23oi.0 * rhs.ei.0),
+			e23: E23(self.e12oi.0 * rhs.e2.0) + E23(self.e13oi.0 * rhs.e3.0),
+			e2o: E2o(self.e13oi.0 * rhs.eo.0),
+			e2i: E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e13oi.0 * rhs.ei.0),
+			e3o: -E3o(self.e12oi.0 * rhs.eo.0),
+			e3i: E3i(self.e123i.0 * rhs.e3.0) - E3i(self.e12oi.0 * rhs.ei.0),
+			eoi: Eoi(self.e123i.0 * rhs.eo.0),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+			e123o: -E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0),
+			e123i: E123i(self.e123i.0 * rhs.e123.0) + E123i(self.e123i.0 * rhs.e123oi.0)
+				- E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e12oi.0 * rhs.e123oi.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e123i.0 * rhs.e13o.0)
+				+ E13oi(self.e12oi.0 * rhs.e1oi.0)
+				+ E13oi(self.e13oi.0 * rhs.e123oi.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0)
+				+ E23oi(self.e23oi.0 * rhs.e123oi.0),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
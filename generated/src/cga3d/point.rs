@@ -0,0 +1,5227 @@
+//! # Point
+//!
+//! ## Operations
+//! ```text
+//! Point.geometric(Point) -> Versor
+//! Point.anti_geometric(Point) -> Multivector
+//! Point.dot(Point) -> S
+//! Point.wedge(Point) -> PointPair
+//! Point.left_contraction(Point) -> S
+//! Point.right_contraction(Point) -> S
+//! Point.scalar_product(Point) -> S
+//! Point.commutator(Point) -> PointPair
+//! Point.anti_dot(Point) -> E123oi
+//! Point.geometric(Vec3) -> Versor
+//! Vec3.geometric(Point) -> Versor
+//! Point.anti_geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Point) -> Multivector
+//! Point.dot(Vec3) -> S
+//! Vec3.dot(Point) -> S
+//! Point.wedge(Vec3) -> PointPair
+//! Vec3.wedge(Point) -> PointPair
+//! Point.left_contraction(Vec3) -> S
+//! Vec3.left_contraction(Point) -> S
+//! Point.right_contraction(Vec3) -> S
+//! Vec3.right_contraction(Point) -> S
+//! Point.scalar_product(Vec3) -> S
+//! Vec3.scalar_product(Point) -> S
+//! Point.commutator(Vec3) -> PointPair
+//! Vec3.commutator(Point) -> PointPair
+//! Point.anti_dot(Vec3) -> E123oi
+//! Vec3.anti_dot(Point) -> E123oi
+//! Point.geometric(FlatPoint) -> Multivector
+//! FlatPoint.geometric(Point) -> Multivector
+//! Point.anti_geometric(FlatPoint) -> Versor
+//! FlatPoint.anti_geometric(Point) -> Versor
+//! Point.dot(FlatPoint) -> Point
+//! FlatPoint.dot(Point) -> Point
+//! Point.wedge(FlatPoint) -> Line
+//! FlatPoint.wedge(Point) -> Line
+//! Point.left_contraction(FlatPoint) -> Point
+//! FlatPoint.right_contraction(Point) -> Point
+//! Point.commutator(FlatPoint) -> Point
+//! FlatPoint.commutator(Point) -> Point
+//! Point.anti_dot(FlatPoint) -> Sphere
+//! FlatPoint.anti_dot(Point) -> Sphere
+//! Point.geometric(PointPair) -> Multivector
+//! PointPair.geometric(Point) -> Multivector
+//! Point.anti_geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(Point) -> Versor
+//! Point.dot(PointPair) -> Point
+//! PointPair.dot(Point) -> Point
+//! Point.wedge(PointPair) -> Circle
+//! PointPair.wedge(Point) -> Circle
+//! Point.left_contraction(PointPair) -> Point
+//! PointPair.right_contraction(Point) -> Point
+//! Point.commutator(PointPair) -> Point
+//! PointPair.commutator(Point) -> Point
+//! Point.anti_dot(PointPair) -> Sphere
+//! PointPair.anti_dot(Point) -> Sphere
+//! Point.geometric(Line) -> Versor
+//! Line.geometric(Point) -> Versor
+//! Point.anti_geometric(Line) -> Multivector
+//! Line.anti_geometric(Point) -> Multivector
+//! Point.dot(Line) -> PointPair
+//! Line.dot(Point) -> PointPair
+//! Point.wedge(Line) -> Plane
+//! Line.wedge(Point) -> Plane
+//! Point.left_contraction(Line) -> PointPair
+//! Line.right_contraction(Point) -> PointPair
+//! Point.commutator(Line) -> Plane
+//! Line.commutator(Point) -> Plane
+//! Point.anti_dot(Line) -> Circle
+//! Line.anti_dot(Point) -> Circle
+//! Point.geometric(Circle) -> Versor
+//! Circle.geometric(Point) -> Versor
+//! Point.anti_geometric(Circle) -> Multivector
+//! Circle.anti_geometric(Point) -> Multivector
+//! Point.dot(Circle) -> PointPair
+//! Circle.dot(Point) -> PointPair
+//! Point.wedge(Circle) -> Sphere
+//! Circle.wedge(Point) -> Sphere
+//! Point.left_contraction(Circle) -> PointPair
+//! Circle.right_contraction(Point) -> PointPair
+//! Point.commutator(Circle) -> Sphere
+//! Circle.commutator(Point) -> Sphere
+//! Point.anti_dot(Circle) -> Circle
+//! Circle.anti_dot(Point) -> Circle
+//! Point.geometric(Plane) -> Multivector
+//! Plane.geometric(Point) -> Multivector
+//! Point.anti_geometric(Plane) -> Versor
+//! Plane.anti_geometric(Point) -> Versor
+//! Point.dot(Plane) -> Circle
+//! Plane.dot(Point) -> Circle
+//! Point.wedge(Plane) -> E123oi
+//! Plane.wedge(Point) -> E123oi
+//! Point.anti_wedge(Plane) -> S
+//! Plane.anti_wedge(Point) -> S
+//! Point.left_contraction(Plane) -> Circle
+//! Plane.right_contraction(Point) -> Circle
+//! Point.commutator(Plane) -> Circle
+//! Plane.commutator(Point) -> Circle
+//! Point.anti_dot(Plane) -> PointPair
+//! Plane.anti_dot(Point) -> PointPair
+//! Point.geometric(Sphere) -> Multivector
+//! Sphere.geometric(Point) -> Multivector
+//! Point.anti_geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(Point) -> Versor
+//! Point.dot(Sphere) -> Circle
+//! Sphere.dot(Point) -> Circle
+//! Point.wedge(Sphere) -> E123oi
+//! Sphere.wedge(Point) -> E123oi
+//! Point.anti_wedge(Sphere) -> S
+//! Sphere.anti_wedge(Point) -> S
+//! Point.left_contraction(Sphere) -> Circle
+//! Sphere.right_contraction(Point) -> Circle
+//! Point.commutator(Sphere) -> Circle
+//! Sphere.commutator(Point) -> Circle
+//! Point.anti_dot(Sphere) -> PointPair
+//! Sphere.anti_dot(Point) -> PointPair
+//! Point.geometric(Rotor) -> Multivector
+//! Rotor.geometric(Point) -> Multivector
+//! Point.anti_geometric(Rotor) -> Versor
+//! Rotor.anti_geometric(Point) -> Versor
+//! Point.dot(Rotor) -> Point
+//! Rotor.dot(Point) -> Point
+//! Point.wedge(Rotor) -> Multivector
+//! Rotor.wedge(Point) -> Multivector
+//! Point.left_contraction(Rotor) -> Vec3
+//! Rotor.left_contraction(Point) -> Point
+//! Point.right_contraction(Rotor) -> Point
+//! Rotor.right_contraction(Point) -> Vec3
+//! Point.commutator(Rotor) -> Vec3
+//! Rotor.commutator(Point) -> Vec3
+//! Point.anti_dot(Rotor) -> Sphere
+//! Rotor.anti_dot(Point) -> Sphere
+//! Point.geometric(Translator) -> Multivector
+//! Translator.geometric(Point) -> Multivector
+//! Point.anti_geometric(Translator) -> Versor
+//! Translator.anti_geometric(Point) -> Versor
+//! Point.dot(Translator) -> Point
+//! Translator.dot(Point) -> Point
+//! Point.wedge(Translator) -> Multivector
+//! Translator.wedge(Point) -> Multivector
+//! Point.left_contraction(Translator) -> Point
+//! Translator.left_contraction(Point) -> Point
+//! Point.right_contraction(Translator) -> Point
+//! Translator.right_contraction(Point) -> Point
+//! Point.commutator(Translator) -> Point
+//! Translator.commutator(Point) -> Point
+//! Point.anti_dot(Translator) -> Sphere
+//! Translator.anti_dot(Point) -> Sphere
+//! Point.geometric(Versor) -> Multivector
+//! Versor.geometric(Point) -> Multivector
+//! Point.anti_geometric(Versor) -> Versor
+//! Versor.anti_geometric(Point) -> Versor
+//! Point.dot(Versor) -> Multivector
+//! Versor.dot(Point) -> Multivector
+//! Point.wedge(Versor) -> Multivector
+//! Versor.wedge(Point) -> Multivector
+//! Point.anti_wedge(Versor) -> S
+//! Versor.anti_wedge(Point) -> S
+//! Point.left_contraction(Versor) -> Multivector
+//! Versor.left_contraction(Point) -> Point
+//! Point.right_contraction(Versor) -> Point
+//! Versor.right_contraction(Point) -> Multivector
+//! Point.commutator(Versor) -> Multivector
+//! Versor.commutator(Point) -> Multivector
+//! Point.anti_dot(Versor) -> Versor
+//! Versor.anti_dot(Point) -> Versor
+//! Point.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Point) -> Multivector
+//! Point.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Point) -> Multivector
+//! Point.dot(Multivector) -> Multivector
+//! Multivector.dot(Point) -> Multivector
+//! Point.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Point) -> Multivector
+//! Point.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Point) -> Multivector
+//! Point.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Point) -> Multivector
+//! Point.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Point) -> Multivector
+//! Point.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Point) -> S
+//! Point.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Point) -> Multivector
+//! Point.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Point) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point {
+	pub x: E1,
+	pub y: E2,
+	pub z: E3,
+	pub o: Eo,
+	pub inf: Ei,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Point {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Point {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Point {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.z.abs_diff_eq(&other.z, epsilon)
+			&& self.o.abs_diff_eq(&other.o, epsilon)
+			&& self.inf.abs_diff_eq(&other.inf, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Point {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.z.relative_eq(&other.z, epsilon, max_relative)
+			&& self.o.relative_eq(&other.o, epsilon, max_relative)
+			&& self.inf.relative_eq(&other.inf, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Point {
+	type Output = Sphere;
+	fn rcompl(self) -> Self::Output {
+		Sphere {
+			e123o: self.inf.rcompl(),
+			e123i: self.o.rcompl(),
+			e12oi: self.z.rcompl(),
+			e13oi: self.y.rcompl(),
+			e23oi: self.x.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Point {
+	type Output = Sphere;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Point {
+	type Output = Sphere;
+	fn lcompl(self) -> Self::Output {
+		Sphere {
+			e123o: self.inf.lcompl(),
+			e123i: self.o.lcompl(),
+			e12oi: self.z.lcompl(),
+			e13oi: self.y.lcompl(),
+			e23oi: self.x.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Point {
+	fn rev(self) -> Self {
+		Point {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+			o: self.o,
+			inf: self.inf,
+		}
+	}
+}
+
+impl AntiReverse for Point {
+	fn arev(self) -> Self {
+		Point {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+			o: self.o,
+			inf: self.inf,
+		}
+	}
+}
+
+impl Involute for Point {
+	fn involute(self) -> Self {
+		Point {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			o: -self.o,
+			inf: -self.inf,
+		}
+	}
+}
+
+impl Conjugate for Point {
+	fn conjugate(self) -> Self {
+		Point {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			o: -self.o,
+			inf: -self.inf,
+		}
+	}
+}
+
+impl Dual for Point {
+	type Output = Sphere;
+	fn dual(self) -> Self::Output {
+		Sphere {
+			e123o: self.inf.dual(),
+			e123i: self.o.dual(),
+			e12oi: self.z.dual(),
+			e13oi: self.y.dual(),
+			e23oi: self.x.dual(),
+		}
+	}
+}
+
+impl Square for Point {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(S(self.inf.0 * self.o.0) * -2.0 + S(self.x.0 * self.x.0) + S(self.y.0 * self.y.0) + S(self.z.0 * self.z.0)).0
+	}
+}
+
+// Omitted: Norm for Point  (the bulk and weight need a diagonal metric)
+
+impl Point {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 5] {
+		[self.x.0, self.y.0, self.z.0, self.o.0, self.inf.0]
+	}
+}
+
+impl From<[f64; 5]> for Point {
+	fn from(a: [f64; 5]) -> Self {
+		Point {
+			x: E1(a[0]),
+			y: E2(a[1]),
+			z: E3(a[2]),
+			o: Eo(a[3]),
+			inf: Ei(a[4]),
+		}
+	}
+}
+
+impl From<Point> for [f64; 5] {
+	fn from(v: Point) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Point> for Multivector {
+	fn from(v: Point) -> Self {
+		Multivector {
+			e1: v.x,
+			e2: v.y,
+			e3: v.z,
+			eo: v.o,
+			ei: v.inf,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Point {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Point` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.e12.0 == 0.0
+			&& mv.e13.0 == 0.0
+			&& mv.e1o.0 == 0.0
+			&& mv.e1i.0 == 0.0
+			&& mv.e23.0 == 0.0
+			&& mv.e2o.0 == 0.0
+			&& mv.e2i.0 == 0.0
+			&& mv.e3o.0 == 0.0
+			&& mv.e3i.0 == 0.0
+			&& mv.eoi.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e12o.0 == 0.0
+			&& mv.e12i.0 == 0.0
+			&& mv.e13o.0 == 0.0
+			&& mv.e13i.0 == 0.0
+			&& mv.e1oi.0 == 0.0
+			&& mv.e23o.0 == 0.0
+			&& mv.e23i.0 == 0.0
+			&& mv.e2oi.0 == 0.0
+			&& mv.e3oi.0 == 0.0
+			&& mv.e123o.0 == 0.0
+			&& mv.e123i.0 == 0.0
+			&& mv.e12oi.0 == 0.0
+			&& mv.e13oi.0 == 0.0
+			&& mv.e23oi.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(Point {
+				x: mv.e1,
+				y: mv.e2,
+				z: mv.e3,
+				o: mv.eo,
+				inf: mv.ei,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP Vec3:
+
+// Point.geometric(Vec3) -> Versor
+impl Geometric<Vec3> for Point {
+	type Output = Versor;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     e12  : E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13  : E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o  : -E1o(self.o.0 * rhs.x.0),
+		//     e1i  : -E1i(self.inf.0 * rhs.x.0),
+		//     e23  : E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o  : -E2o(self.o.0 * rhs.y.0),
+		//     e2i  : -E2i(self.inf.0 * rhs.y.0),
+		//     e3o  : -E3o(self.o.0 * rhs.z.0),
+		//     e3i  : -E3i(self.inf.0 * rhs.z.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+			e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+			e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+			e1o: -E1o(self.o.0 * rhs.x.0),
+			e1i: -E1i(self.inf.0 * rhs.x.0),
+			e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+			e2o: -E2o(self.o.0 * rhs.y.0),
+			e2i: -E2i(self.inf.0 * rhs.y.0),
+			e3o: -E3o(self.o.0 * rhs.z.0),
+			e3i: -E3i(self.inf.0 * rhs.z.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Point {
+	type Output = Versor;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Point {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : E12o(self.o.0 * rhs.z.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.z.0),
+		//     e13o  : -E13o(self.o.0 * rhs.y.0),
+		//     e13i  : E13i(self.inf.0 * rhs.y.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.z.0) + E1oi(self.z.0 * rhs.y.0),
+		//     e23o  : E23o(self.o.0 * rhs.x.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.x.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.z.0) - E2oi(self.z.0 * rhs.x.0),
+		//     e3oi  : -E3oi(self.x.0 * rhs.y.0) + E3oi(self.y.0 * rhs.x.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: E12o(self.o.0 * rhs.z.0),
+			e12i: -E12i(self.inf.0 * rhs.z.0),
+			e13o: -E13o(self.o.0 * rhs.y.0),
+			e13i: E13i(self.inf.0 * rhs.y.0),
+			e1oi: -E1oi(self.y.0 * rhs.z.0) + E1oi(self.z.0 * rhs.y.0),
+			e23o: E23o(self.o.0 * rhs.x.0),
+			e23i: -E23i(self.inf.0 * rhs.x.0),
+			e2oi: E2oi(self.x.0 * rhs.z.0) - E2oi(self.z.0 * rhs.x.0),
+			e3oi: -E3oi(self.x.0 * rhs.y.0) + E3oi(self.y.0 * rhs.x.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0),
+		}
+	}
+}
+
+// Point.dot(Vec3) -> S
+impl Dot<Vec3> for Point {
+	type Output = S;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Point {
+	type Output = S;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(Vec3) -> PointPair
+impl Wedge<Vec3> for Point {
+	type Output = PointPair;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o: -E1o(self.o.0 * rhs.x.0),
+		//     e1i: -E1i(self.inf.0 * rhs.x.0),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o: -E2o(self.o.0 * rhs.y.0),
+		//     e2i: -E2i(self.inf.0 * rhs.y.0),
+		//     e3o: -E3o(self.o.0 * rhs.z.0),
+		//     e3i: -E3i(self.inf.0 * rhs.z.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			e13: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			e1o: self.o.wedge(rhs.x),
+			e1i: self.inf.wedge(rhs.x),
+			e23: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			e2o: self.o.wedge(rhs.y),
+			e2i: self.inf.wedge(rhs.y),
+			e3o: self.o.wedge(rhs.z),
+			e3i: self.inf.wedge(rhs.z),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Point {
+	type Output = PointPair;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for Point {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Point {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(Vec3) -> S
+impl LeftContraction<Vec3> for Point {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Point.right_contraction(Vec3) -> S
+impl RightContraction<Vec3> for Point {
+	type Output = S;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Point.scalar_product(Vec3) -> S
+impl ScalarProduct<Vec3> for Point {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Point.commutator(Vec3) -> PointPair
+impl Commutator<Vec3> for Point {
+	type Output = PointPair;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o: -E1o(self.o.0 * rhs.x.0),
+		//     e1i: -E1i(self.inf.0 * rhs.x.0),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o: -E2o(self.o.0 * rhs.y.0),
+		//     e2i: -E2i(self.inf.0 * rhs.y.0),
+		//     e3o: -E3o(self.o.0 * rhs.z.0),
+		//     e3i: -E3i(self.inf.0 * rhs.z.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+			e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+			e1o: -E1o(self.o.0 * rhs.x.0),
+			e1i: -E1i(self.inf.0 * rhs.x.0),
+			e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+			e2o: -E2o(self.o.0 * rhs.y.0),
+			e2i: -E2i(self.inf.0 * rhs.y.0),
+			e3o: -E3o(self.o.0 * rhs.z.0),
+			e3i: -E3i(self.inf.0 * rhs.z.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Point.anti_dot(Vec3) -> E123oi
+impl AntiDot<Vec3> for Point {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// -E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0)
+		-E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP Point:
+
+// Point.geometric(Point) -> Versor
+impl Geometric<Point> for Point {
+	type Output = Versor;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.inf.0 * rhs.o.0) - S(self.o.0 * rhs.inf.0) + S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     e12  : E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13  : E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o  : -E1o(self.o.0 * rhs.x.0) + E1o(self.x.0 * rhs.o.0),
+		//     e1i  : -E1i(self.inf.0 * rhs.x.0) + E1i(self.x.0 * rhs.inf.0),
+		//     e23  : E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o  : -E2o(self.o.0 * rhs.y.0) + E2o(self.y.0 * rhs.o.0),
+		//     e2i  : -E2i(self.inf.0 * rhs.y.0) + E2i(self.y.0 * rhs.inf.0),
+		//     e3o  : -E3o(self.o.0 * rhs.z.0) + E3o(self.z.0 * rhs.o.0),
+		//     e3i  : -E3i(self.inf.0 * rhs.z.0) + E3i(self.z.0 * rhs.inf.0),
+		//     eoi  : -Eoi(self.inf.0 * rhs.o.0) + Eoi(self.o.0 * rhs.inf.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.inf.0 * rhs.o.0) - S(self.o.0 * rhs.inf.0)
+				+ S(self.x.0 * rhs.x.0)
+				+ S(self.y.0 * rhs.y.0)
+				+ S(self.z.0 * rhs.z.0),
+			e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+			e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+			e1o: -E1o(self.o.0 * rhs.x.0) + E1o(self.x.0 * rhs.o.0),
+			e1i: -E1i(self.inf.0 * rhs.x.0) + E1i(self.x.0 * rhs.inf.0),
+			e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+			e2o: -E2o(self.o.0 * rhs.y.0) + E2o(self.y.0 * rhs.o.0),
+			e2i: -E2i(self.inf.0 * rhs.y.0) + E2i(self.y.0 * rhs.inf.0),
+			e3o: -E3o(self.o.0 * rhs.z.0) + E3o(self.z.0 * rhs.o.0),
+			e3i: -E3i(self.inf.0 * rhs.z.0) + E3i(self.z.0 * rhs.inf.0),
+			eoi: -Eoi(self.inf.0 * rhs.o.0) + Eoi(self.o.0 * rhs.inf.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for Point {
+	type Output = Versor;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(Point) -> Multivector
+impl AntiGeometric<Point> for Point {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.inf.0 * rhs.o.0) + E123(self.o.0 * rhs.inf.0),
+		//     e12o  : E12o(self.o.0 * rhs.z.0) - E12o(self.z.0 * rhs.o.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.z.0) + E12i(self.z.0 * rhs.inf.0),
+		//     e13o  : -E13o(self.o.0 * rhs.y.0) + E13o(self.y.0 * rhs.o.0),
+		//     e13i  : E13i(self.inf.0 * rhs.y.0) - E13i(self.y.0 * rhs.inf.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.z.0) + E1oi(self.z.0 * rhs.y.0),
+		//     e23o  : E23o(self.o.0 * rhs.x.0) - E23o(self.x.0 * rhs.o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.x.0) + E23i(self.x.0 * rhs.inf.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.z.0) - E2oi(self.z.0 * rhs.x.0),
+		//     e3oi  : -E3oi(self.x.0 * rhs.y.0) + E3oi(self.y.0 * rhs.x.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.inf.0 * rhs.o.0) + E123oi(self.o.0 * rhs.inf.0) - E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.inf.0 * rhs.o.0) + E123(self.o.0 * rhs.inf.0),
+			e12o: E12o(self.o.0 * rhs.z.0) - E12o(self.z.0 * rhs.o.0),
+			e12i: -E12i(self.inf.0 * rhs.z.0) + E12i(self.z.0 * rhs.inf.0),
+			e13o: -E13o(self.o.0 * rhs.y.0) + E13o(self.y.0 * rhs.o.0),
+			e13i: E13i(self.inf.0 * rhs.y.0) - E13i(self.y.0 * rhs.inf.0),
+			e1oi: -E1oi(self.y.0 * rhs.z.0) + E1oi(self.z.0 * rhs.y.0),
+			e23o: E23o(self.o.0 * rhs.x.0) - E23o(self.x.0 * rhs.o.0),
+			e23i: -E23i(self.inf.0 * rhs.x.0) + E23i(self.x.0 * rhs.inf.0),
+			e2oi: E2oi(self.x.0 * rhs.z.0) - E2oi(self.z.0 * rhs.x.0),
+			e3oi: -E3oi(self.x.0 * rhs.y.0) + E3oi(self.y.0 * rhs.x.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.inf.0 * rhs.o.0) + E123oi(self.o.0 * rhs.inf.0)
+				- E123oi(self.x.0 * rhs.x.0)
+				- E123oi(self.y.0 * rhs.y.0)
+				- E123oi(self.z.0 * rhs.z.0),
+		}
+	}
+}
+
+// Point.dot(Point) -> S
+impl Dot<Point> for Point {
+	type Output = S;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// -S(self.inf.0 * rhs.o.0) - S(self.o.0 * rhs.inf.0) + S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		-S(self.inf.0 * rhs.o.0) - S(self.o.0 * rhs.inf.0)
+			+ S(self.x.0 * rhs.x.0)
+			+ S(self.y.0 * rhs.y.0)
+			+ S(self.z.0 * rhs.z.0)
+	}
+}
+
+impl std::ops::BitOr<Point> for Point {
+	type Output = S;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(Point) -> PointPair
+impl Wedge<Point> for Point {
+	type Output = PointPair;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o: -E1o(self.o.0 * rhs.x.0) + E1o(self.x.0 * rhs.o.0),
+		//     e1i: -E1i(self.inf.0 * rhs.x.0) + E1i(self.x.0 * rhs.inf.0),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o: -E2o(self.o.0 * rhs.y.0) + E2o(self.y.0 * rhs.o.0),
+		//     e2i: -E2i(self.inf.0 * rhs.y.0) + E2i(self.y.0 * rhs.inf.0),
+		//     e3o: -E3o(self.o.0 * rhs.z.0) + E3o(self.z.0 * rhs.o.0),
+		//     e3i: -E3i(self.inf.0 * rhs.z.0) + E3i(self.z.0 * rhs.inf.0),
+		//     eoi: -Eoi(self.inf.0 * rhs.o.0) + Eoi(self.o.0 * rhs.inf.0),
+		// }
+		PointPair {
+			e12: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			e13: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			e1o: self.o.wedge(rhs.x) + self.x.wedge(rhs.o),
+			e1i: self.inf.wedge(rhs.x) + self.x.wedge(rhs.inf),
+			e23: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			e2o: self.o.wedge(rhs.y) + self.y.wedge(rhs.o),
+			e2i: self.inf.wedge(rhs.y) + self.y.wedge(rhs.inf),
+			e3o: self.o.wedge(rhs.z) + self.z.wedge(rhs.o),
+			e3i: self.inf.wedge(rhs.z) + self.z.wedge(rhs.inf),
+			eoi: self.inf.wedge(rhs.o) + self.o.wedge(rhs.inf),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point> for Point {
+	type Output = PointPair;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point> for Point {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point> for Point {
+	type Output = Zero;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(Point) -> S
+impl LeftContraction<Point> for Point {
+	type Output = S;
+	fn left_contraction(self, rhs: Point) -> Self::Output {
+		// -S(self.inf.0 * rhs.o.0) - S(self.o.0 * rhs.inf.0) + S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		-S(self.inf.0 * rhs.o.0) - S(self.o.0 * rhs.inf.0)
+			+ S(self.x.0 * rhs.x.0)
+			+ S(self.y.0 * rhs.y.0)
+			+ S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Point.right_contraction(Point) -> S
+impl RightContraction<Point> for Point {
+	type Output = S;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// -S(self.inf.0 * rhs.o.0) - S(self.o.0 * rhs.inf.0) + S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		-S(self.inf.0 * rhs.o.0) - S(self.o.0 * rhs.inf.0)
+			+ S(self.x.0 * rhs.x.0)
+			+ S(self.y.0 * rhs.y.0)
+			+ S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Point.scalar_product(Point) -> S
+impl ScalarProduct<Point> for Point {
+	type Output = S;
+	fn scalar_product(self, rhs: Point) -> Self::Output {
+		// -S(self.inf.0 * rhs.o.0) - S(self.o.0 * rhs.inf.0) + S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		-S(self.inf.0 * rhs.o.0) - S(self.o.0 * rhs.inf.0)
+			+ S(self.x.0 * rhs.x.0)
+			+ S(self.y.0 * rhs.y.0)
+			+ S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Point.commutator(Point) -> PointPair
+impl Commutator<Point> for Point {
+	type Output = PointPair;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o: -E1o(self.o.0 * rhs.x.0) + E1o(self.x.0 * rhs.o.0),
+		//     e1i: -E1i(self.inf.0 * rhs.x.0) + E1i(self.x.0 * rhs.inf.0),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o: -E2o(self.o.0 * rhs.y.0) + E2o(self.y.0 * rhs.o.0),
+		//     e2i: -E2i(self.inf.0 * rhs.y.0) + E2i(self.y.0 * rhs.inf.0),
+		//     e3o: -E3o(self.o.0 * rhs.z.0) + E3o(self.z.0 * rhs.o.0),
+		//     e3i: -E3i(self.inf.0 * rhs.z.0) + E3i(self.z.0 * rhs.inf.0),
+		//     eoi: -Eoi(self.inf.0 * rhs.o.0) + Eoi(self.o.0 * rhs.inf.0),
+		// }
+		PointPair {
+			e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+			e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+			e1o: -E1o(self.o.0 * rhs.x.0) + E1o(self.x.0 * rhs.o.0),
+			e1i: -E1i(self.inf.0 * rhs.x.0) + E1i(self.x.0 * rhs.inf.0),
+			e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+			e2o: -E2o(self.o.0 * rhs.y.0) + E2o(self.y.0 * rhs.o.0),
+			e2i: -E2i(self.inf.0 * rhs.y.0) + E2i(self.y.0 * rhs.inf.0),
+			e3o: -E3o(self.o.0 * rhs.z.0) + E3o(self.z.0 * rhs.o.0),
+			e3i: -E3i(self.inf.0 * rhs.z.0) + E3i(self.z.0 * rhs.inf.0),
+			eoi: -Eoi(self.inf.0 * rhs.o.0) + Eoi(self.o.0 * rhs.inf.0),
+		}
+	}
+}
+
+// Point.anti_dot(Point) -> E123oi
+impl AntiDot<Point> for Point {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// E123oi(self.inf.0 * rhs.o.0) + E123oi(self.o.0 * rhs.inf.0) - E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0)
+		E123oi(self.inf.0 * rhs.o.0) + E123oi(self.o.0 * rhs.inf.0)
+			- E123oi(self.x.0 * rhs.x.0)
+			- E123oi(self.y.0 * rhs.y.0)
+			- E123oi(self.z.0 * rhs.z.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP FlatPoint:
+
+// Point.geometric(FlatPoint) -> Multivector
+impl Geometric<FlatPoint> for Point {
+	type Output = Multivector;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.o.0 * rhs.e1i.0),
+		//     e2    : E2(self.o.0 * rhs.e2i.0),
+		//     e3    : E3(self.o.0 * rhs.e3i.0),
+		//     eo    : Eo(self.o.0 * rhs.eoi.0),
+		//     ei    : -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : -E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.o.0 * rhs.e2i.0) + E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi  : -E3oi(self.o.0 * rhs.e3i.0) + E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.o.0 * rhs.e1i.0),
+			e2: E2(self.o.0 * rhs.e2i.0),
+			e3: E3(self.o.0 * rhs.e3i.0),
+			eo: Eo(self.o.0 * rhs.eoi.0),
+			ei: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+			e1oi: -E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.o.0 * rhs.e2i.0) + E2oi(self.y.0 * rhs.eoi.0),
+			e3oi: -E3oi(self.o.0 * rhs.e3i.0) + E3oi(self.z.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for Point {
+	type Output = Multivector;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(FlatPoint) -> Versor
+impl AntiGeometric<FlatPoint> for Point {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.o.0 * rhs.e3i.0) - E12(self.z.0 * rhs.eoi.0),
+		//     e13  : -E13(self.o.0 * rhs.e2i.0) + E13(self.y.0 * rhs.eoi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+		//     e23  : E23(self.o.0 * rhs.e1i.0) - E23(self.x.0 * rhs.eoi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+		//     eoi  : Default::default(),
+		//     e123o: -E123o(self.o.0 * rhs.eoi.0),
+		//     e123i: -E123i(self.inf.0 * rhs.eoi.0) + E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.o.0 * rhs.e3i.0),
+		//     e13oi: -E13oi(self.o.0 * rhs.e2i.0),
+		//     e23oi: E23oi(self.o.0 * rhs.e1i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.o.0 * rhs.e3i.0) - E12(self.z.0 * rhs.eoi.0),
+			e13: -E13(self.o.0 * rhs.e2i.0) + E13(self.y.0 * rhs.eoi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+			e23: E23(self.o.0 * rhs.e1i.0) - E23(self.x.0 * rhs.eoi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+			eoi: Default::default(),
+			e123o: -E123o(self.o.0 * rhs.eoi.0),
+			e123i: -E123i(self.inf.0 * rhs.eoi.0)
+				+ E123i(self.x.0 * rhs.e1i.0)
+				+ E123i(self.y.0 * rhs.e2i.0)
+				+ E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.o.0 * rhs.e3i.0),
+			e13oi: -E13oi(self.o.0 * rhs.e2i.0),
+			e23oi: E23oi(self.o.0 * rhs.e1i.0),
+		}
+	}
+}
+
+// Point.dot(FlatPoint) -> Point
+impl Dot<FlatPoint> for Point {
+	type Output = Point;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// Point {
+		//     x  : E1(self.o.0 * rhs.e1i.0),
+		//     y  : E2(self.o.0 * rhs.e2i.0),
+		//     z  : E3(self.o.0 * rhs.e3i.0),
+		//     o  : Eo(self.o.0 * rhs.eoi.0),
+		//     inf: -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.o.0 * rhs.e1i.0),
+			y: E2(self.o.0 * rhs.e2i.0),
+			z: E3(self.o.0 * rhs.e3i.0),
+			o: Eo(self.o.0 * rhs.eoi.0),
+			inf: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for Point {
+	type Output = Point;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(FlatPoint) -> Line
+impl Wedge<FlatPoint> for Point {
+	type Output = Line;
+	fn wedge(self, rhs: FlatPoint) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13i: E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi: -E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0),
+		//     e23i: E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi: -E2oi(self.o.0 * rhs.e2i.0) + E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi: -E3oi(self.o.0 * rhs.e3i.0) + E3oi(self.z.0 * rhs.eoi.0),
+		// }
+		Line {
+			e12i: self.x.wedge(rhs.e2i) + self.y.wedge(rhs.e1i),
+			e13i: self.x.wedge(rhs.e3i) + self.z.wedge(rhs.e1i),
+			e1oi: self.o.wedge(rhs.e1i) + self.x.wedge(rhs.eoi),
+			e23i: self.y.wedge(rhs.e3i) + self.z.wedge(rhs.e2i),
+			e2oi: self.o.wedge(rhs.e2i) + self.y.wedge(rhs.eoi),
+			e3oi: self.o.wedge(rhs.e3i) + self.z.wedge(rhs.eoi),
+		}
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for Point {
+	type Output = Line;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<FlatPoint> for Point {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for Point {
+	type Output = Zero;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(FlatPoint) -> Point
+impl LeftContraction<FlatPoint> for Point {
+	type Output = Point;
+	fn left_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// Point {
+		//     x  : E1(self.o.0 * rhs.e1i.0),
+		//     y  : E2(self.o.0 * rhs.e2i.0),
+		//     z  : E3(self.o.0 * rhs.e3i.0),
+		//     o  : Eo(self.o.0 * rhs.eoi.0),
+		//     inf: -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.o.0 * rhs.e1i.0),
+			y: E2(self.o.0 * rhs.e2i.0),
+			z: E3(self.o.0 * rhs.e3i.0),
+			o: Eo(self.o.0 * rhs.eoi.0),
+			inf: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl RightContraction<FlatPoint> for Point {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<FlatPoint> for Point {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point.commutator(FlatPoint) -> Point
+impl Commutator<FlatPoint> for Point {
+	type Output = Point;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// Point {
+		//     x  : E1(self.o.0 * rhs.e1i.0),
+		//     y  : E2(self.o.0 * rhs.e2i.0),
+		//     z  : E3(self.o.0 * rhs.e3i.0),
+		//     o  : Eo(self.o.0 * rhs.eoi.0),
+		//     inf: -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.o.0 * rhs.e1i.0),
+			y: E2(self.o.0 * rhs.e2i.0),
+			z: E3(self.o.0 * rhs.e3i.0),
+			o: Eo(self.o.0 * rhs.eoi.0),
+			inf: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// Point.anti_dot(FlatPoint) -> Sphere
+impl AntiDot<FlatPoint> for Point {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.o.0 * rhs.eoi.0),
+		//     e123i: -E123i(self.inf.0 * rhs.eoi.0) + E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.o.0 * rhs.e3i.0),
+		//     e13oi: -E13oi(self.o.0 * rhs.e2i.0),
+		//     e23oi: E23oi(self.o.0 * rhs.e1i.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.o.0 * rhs.eoi.0),
+			e123i: -E123i(self.inf.0 * rhs.eoi.0)
+				+ E123i(self.x.0 * rhs.e1i.0)
+				+ E123i(self.y.0 * rhs.e2i.0)
+				+ E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.o.0 * rhs.e3i.0),
+			e13oi: -E13oi(self.o.0 * rhs.e2i.0),
+			e23oi: E23oi(self.o.0 * rhs.e1i.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP PointPair:
+
+// Point.geometric(PointPair) -> Multivector
+impl Geometric<PointPair> for Point {
+	type Output = Multivector;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     eo    : Eo(self.o.0 * rhs.eoi.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12.0) + E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0),
+		//     e12i  : E12i(self.inf.0 * rhs.e12.0) + E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13.0) + E13o(self.x.0 * rhs.e3o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e13.0) + E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.inf.0 * rhs.e1o.0) - E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.inf.0 * rhs.e23.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.inf.0 * rhs.e2o.0) - E2oi(self.o.0 * rhs.e2i.0) + E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.inf.0 * rhs.e3o.0) - E3oi(self.o.0 * rhs.e3i.0) + E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0)
+				- E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0),
+			eo: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			ei: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+			e12o: E12o(self.o.0 * rhs.e12.0) + E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0),
+			e12i: E12i(self.inf.0 * rhs.e12.0) + E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+			e13o: E13o(self.o.0 * rhs.e13.0) + E13o(self.x.0 * rhs.e3o.0) - E13o(self.z.0 * rhs.e1o.0),
+			e13i: E13i(self.inf.0 * rhs.e13.0) + E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+			e1oi: E1oi(self.inf.0 * rhs.e1o.0) - E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0),
+			e23o: E23o(self.o.0 * rhs.e23.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+			e23i: E23i(self.inf.0 * rhs.e23.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+			e2oi: E2oi(self.inf.0 * rhs.e2o.0) - E2oi(self.o.0 * rhs.e2i.0) + E2oi(self.y.0 * rhs.eoi.0),
+			e3oi: E3oi(self.inf.0 * rhs.e3o.0) - E3oi(self.o.0 * rhs.e3i.0) + E3oi(self.z.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for Point {
+	type Output = Multivector;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(PointPair) -> Versor
+impl AntiGeometric<PointPair> for Point {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.inf.0 * rhs.e3o.0) + E12(self.o.0 * rhs.e3i.0) - E12(self.z.0 * rhs.eoi.0),
+		//     e13  : E13(self.inf.0 * rhs.e2o.0) - E13(self.o.0 * rhs.e2i.0) + E13(self.y.0 * rhs.eoi.0),
+		//     e1o  : -E1o(self.o.0 * rhs.e23.0) - E1o(self.y.0 * rhs.e3o.0) + E1o(self.z.0 * rhs.e2o.0),
+		//     e1i  : E1i(self.inf.0 * rhs.e23.0) + E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+		//     e23  : -E23(self.inf.0 * rhs.e1o.0) + E23(self.o.0 * rhs.e1i.0) - E23(self.x.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.o.0 * rhs.e13.0) + E2o(self.x.0 * rhs.e3o.0) - E2o(self.z.0 * rhs.e1o.0),
+		//     e2i  : -E2i(self.inf.0 * rhs.e13.0) - E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+		//     e3o  : -E3o(self.o.0 * rhs.e12.0) - E3o(self.x.0 * rhs.e2o.0) + E3o(self.y.0 * rhs.e1o.0),
+		//     e3i  : E3i(self.inf.0 * rhs.e12.0) + E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+		//     eoi  : Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+		//     e123o: -E123o(self.o.0 * rhs.eoi.0) - E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i: -E123i(self.inf.0 * rhs.eoi.0) + E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.inf.0 * rhs.e3o.0) + E12oi(self.o.0 * rhs.e3i.0) + E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0),
+		//     e13oi: -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.inf.0 * rhs.e3o.0) + E12(self.o.0 * rhs.e3i.0) - E12(self.z.0 * rhs.eoi.0),
+			e13: E13(self.inf.0 * rhs.e2o.0) - E13(self.o.0 * rhs.e2i.0) + E13(self.y.0 * rhs.eoi.0),
+			e1o: -E1o(self.o.0 * rhs.e23.0) - E1o(self.y.0 * rhs.e3o.0) + E1o(self.z.0 * rhs.e2o.0),
+			e1i: E1i(self.inf.0 * rhs.e23.0) + E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+			e23: -E23(self.inf.0 * rhs.e1o.0) + E23(self.o.0 * rhs.e1i.0) - E23(self.x.0 * rhs.eoi.0),
+			e2o: E2o(self.o.0 * rhs.e13.0) + E2o(self.x.0 * rhs.e3o.0) - E2o(self.z.0 * rhs.e1o.0),
+			e2i: -E2i(self.inf.0 * rhs.e13.0) - E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+			e3o: -E3o(self.o.0 * rhs.e12.0) - E3o(self.x.0 * rhs.e2o.0) + E3o(self.y.0 * rhs.e1o.0),
+			e3i: E3i(self.inf.0 * rhs.e12.0) + E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+			eoi: Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+			e123o: -E123o(self.o.0 * rhs.eoi.0)
+				- E123o(self.x.0 * rhs.e1o.0)
+				- E123o(self.y.0 * rhs.e2o.0)
+				- E123o(self.z.0 * rhs.e3o.0),
+			e123i: -E123i(self.inf.0 * rhs.eoi.0)
+				+ E123i(self.x.0 * rhs.e1i.0)
+				+ E123i(self.y.0 * rhs.e2i.0)
+				+ E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.inf.0 * rhs.e3o.0)
+				+ E12oi(self.o.0 * rhs.e3i.0)
+				+ E12oi(self.x.0 * rhs.e13.0)
+				+ E12oi(self.y.0 * rhs.e23.0),
+			e13oi: -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0)
+				+ E13oi(self.z.0 * rhs.e23.0),
+			e23oi: E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0)
+				- E23oi(self.y.0 * rhs.e12.0)
+				- E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// Point.dot(PointPair) -> Point
+impl Dot<PointPair> for Point {
+	type Output = Point;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y  : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     z  : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     o  : Eo(self.o.0 * rhs.eoi.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     inf: -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			y: E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0)
+				- E2(self.z.0 * rhs.e23.0),
+			z: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0),
+			o: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			inf: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<PointPair> for Point {
+	type Output = Point;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(PointPair) -> Circle
+impl Wedge<PointPair> for Point {
+	type Output = Circle;
+	fn wedge(self, rhs: PointPair) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o: E12o(self.o.0 * rhs.e12.0) + E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0),
+		//     e12i: E12i(self.inf.0 * rhs.e12.0) + E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o: E13o(self.o.0 * rhs.e13.0) + E13o(self.x.0 * rhs.e3o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i: E13i(self.inf.0 * rhs.e13.0) + E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi: E1oi(self.inf.0 * rhs.e1o.0) - E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0),
+		//     e23o: E23o(self.o.0 * rhs.e23.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i: E23i(self.inf.0 * rhs.e23.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi: E2oi(self.inf.0 * rhs.e2o.0) - E2oi(self.o.0 * rhs.e2i.0) + E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi: E3oi(self.inf.0 * rhs.e3o.0) - E3oi(self.o.0 * rhs.e3i.0) + E3oi(self.z.0 * rhs.eoi.0),
+		// }
+		Circle {
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e12o: self.o.wedge(rhs.e12) + self.x.wedge(rhs.e2o) + self.y.wedge(rhs.e1o),
+			e12i: self.inf.wedge(rhs.e12) + self.x.wedge(rhs.e2i) + self.y.wedge(rhs.e1i),
+			e13o: self.o.wedge(rhs.e13) + self.x.wedge(rhs.e3o) + self.z.wedge(rhs.e1o),
+			e13i: self.inf.wedge(rhs.e13) + self.x.wedge(rhs.e3i) + self.z.wedge(rhs.e1i),
+			e1oi: self.inf.wedge(rhs.e1o) + self.o.wedge(rhs.e1i) + self.x.wedge(rhs.eoi),
+			e23o: self.o.wedge(rhs.e23) + self.y.wedge(rhs.e3o) + self.z.wedge(rhs.e2o),
+			e23i: self.inf.wedge(rhs.e23) + self.y.wedge(rhs.e3i) + self.z.wedge(rhs.e2i),
+			e2oi: self.inf.wedge(rhs.e2o) + self.o.wedge(rhs.e2i) + self.y.wedge(rhs.eoi),
+			e3oi: self.inf.wedge(rhs.e3o) + self.o.wedge(rhs.e3i) + self.z.wedge(rhs.eoi),
+		}
+	}
+}
+
+impl std::ops::BitXor<PointPair> for Point {
+	type Output = Circle;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<PointPair> for Point {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for Point {
+	type Output = Zero;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(PointPair) -> Point
+impl LeftContraction<PointPair> for Point {
+	type Output = Point;
+	fn left_contraction(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y  : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     z  : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     o  : Eo(self.o.0 * rhs.eoi.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     inf: -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			y: E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0)
+				- E2(self.z.0 * rhs.e23.0),
+			z: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0),
+			o: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			inf: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl RightContraction<PointPair> for Point {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<PointPair> for Point {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point.commutator(PointPair) -> Point
+impl Commutator<PointPair> for Point {
+	type Output = Point;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y  : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     z  : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     o  : Eo(self.o.0 * rhs.eoi.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     inf: -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			y: E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0)
+				- E2(self.z.0 * rhs.e23.0),
+			z: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0),
+			o: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			inf: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// Point.anti_dot(PointPair) -> Sphere
+impl AntiDot<PointPair> for Point {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.o.0 * rhs.eoi.0) - E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i: -E123i(self.inf.0 * rhs.eoi.0) + E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.inf.0 * rhs.e3o.0) + E12oi(self.o.0 * rhs.e3i.0) + E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0),
+		//     e13oi: -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.o.0 * rhs.eoi.0)
+				- E123o(self.x.0 * rhs.e1o.0)
+				- E123o(self.y.0 * rhs.e2o.0)
+				- E123o(self.z.0 * rhs.e3o.0),
+			e123i: -E123i(self.inf.0 * rhs.eoi.0)
+				+ E123i(self.x.0 * rhs.e1i.0)
+				+ E123i(self.y.0 * rhs.e2i.0)
+				+ E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.inf.0 * rhs.e3o.0)
+				+ E12oi(self.o.0 * rhs.e3i.0)
+				+ E12oi(self.x.0 * rhs.e13.0)
+				+ E12oi(self.y.0 * rhs.e23.0),
+			e13oi: -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0)
+				+ E13oi(self.z.0 * rhs.e23.0),
+			e23oi: E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0)
+				- E23oi(self.y.0 * rhs.e12.0)
+				- E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP Line:
+
+// Point.geometric(Line) -> Versor
+impl Geometric<Line> for Point {
+	type Output = Versor;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.o.0 * rhs.e12i.0),
+		//     e13  : -E13(self.o.0 * rhs.e13i.0),
+		//     e1o  : -E1o(self.o.0 * rhs.e1oi.0),
+		//     e1i  : E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23  : -E23(self.o.0 * rhs.e23i.0),
+		//     e2o  : -E2o(self.o.0 * rhs.e2oi.0),
+		//     e2i  : E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o  : -E3o(self.o.0 * rhs.e3oi.0),
+		//     e3i  : E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi  : Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.o.0 * rhs.e12i.0),
+			e13: -E13(self.o.0 * rhs.e13i.0),
+			e1o: -E1o(self.o.0 * rhs.e1oi.0),
+			e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: -E23(self.o.0 * rhs.e23i.0),
+			e2o: -E2o(self.o.0 * rhs.e2oi.0),
+			e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: -E3o(self.o.0 * rhs.e3oi.0),
+			e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+			e12oi: E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+			e13oi: E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Point {
+	type Output = Versor;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(Line) -> Multivector
+impl AntiGeometric<Line> for Point {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.o.0 * rhs.e23i.0) - E1(self.y.0 * rhs.e3oi.0) + E1(self.z.0 * rhs.e2oi.0),
+		//     e2    : E2(self.o.0 * rhs.e13i.0) + E2(self.x.0 * rhs.e3oi.0) - E2(self.z.0 * rhs.e1oi.0),
+		//     e3    : -E3(self.o.0 * rhs.e12i.0) - E3(self.x.0 * rhs.e2oi.0) + E3(self.y.0 * rhs.e1oi.0),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0) - Ei(self.z.0 * rhs.e12i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o  : -E12o(self.o.0 * rhs.e3oi.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e2oi.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi  : -E1oi(self.o.0 * rhs.e23i.0),
+		//     e23o  : -E23o(self.o.0 * rhs.e1oi.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi  : E2oi(self.o.0 * rhs.e13i.0),
+		//     e3oi  : -E3oi(self.o.0 * rhs.e12i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.o.0 * rhs.e23i.0) - E1(self.y.0 * rhs.e3oi.0) + E1(self.z.0 * rhs.e2oi.0),
+			e2: E2(self.o.0 * rhs.e13i.0) + E2(self.x.0 * rhs.e3oi.0) - E2(self.z.0 * rhs.e1oi.0),
+			e3: -E3(self.o.0 * rhs.e12i.0) - E3(self.x.0 * rhs.e2oi.0) + E3(self.y.0 * rhs.e1oi.0),
+			eo: Default::default(),
+			ei: -Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0) - Ei(self.z.0 * rhs.e12i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: -E12o(self.o.0 * rhs.e3oi.0),
+			e12i: -E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+			e13o: E13o(self.o.0 * rhs.e2oi.0),
+			e13i: E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.o.0 * rhs.e23i.0),
+			e23o: -E23o(self.o.0 * rhs.e1oi.0),
+			e23i: -E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: E2oi(self.o.0 * rhs.e13i.0),
+			e3oi: -E3oi(self.o.0 * rhs.e12i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Point.dot(Line) -> PointPair
+impl Dot<Line> for Point {
+	type Output = PointPair;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.o.0 * rhs.e12i.0),
+		//     e13: -E13(self.o.0 * rhs.e13i.0),
+		//     e1o: -E1o(self.o.0 * rhs.e1oi.0),
+		//     e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23: -E23(self.o.0 * rhs.e23i.0),
+		//     e2o: -E2o(self.o.0 * rhs.e2oi.0),
+		//     e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o: -E3o(self.o.0 * rhs.e3oi.0),
+		//     e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		// }
+		PointPair {
+			e12: -E12(self.o.0 * rhs.e12i.0),
+			e13: -E13(self.o.0 * rhs.e13i.0),
+			e1o: -E1o(self.o.0 * rhs.e1oi.0),
+			e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: -E23(self.o.0 * rhs.e23i.0),
+			e2o: -E2o(self.o.0 * rhs.e2oi.0),
+			e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: -E3o(self.o.0 * rhs.e3oi.0),
+			e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Point {
+	type Output = PointPair;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(Line) -> Plane
+impl Wedge<Line> for Point {
+	type Output = Plane;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Plane {
+			e123i: self.x.wedge(rhs.e23i) + self.y.wedge(rhs.e13i) + self.z.wedge(rhs.e12i),
+			e12oi: self.o.wedge(rhs.e12i) + self.x.wedge(rhs.e2oi) + self.y.wedge(rhs.e1oi),
+			e13oi: self.o.wedge(rhs.e13i) + self.x.wedge(rhs.e3oi) + self.z.wedge(rhs.e1oi),
+			e23oi: self.o.wedge(rhs.e23i) + self.y.wedge(rhs.e3oi) + self.z.wedge(rhs.e2oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line> for Point {
+	type Output = Plane;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Line> for Point {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Point {
+	type Output = Zero;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(Line) -> PointPair
+impl LeftContraction<Line> for Point {
+	type Output = PointPair;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.o.0 * rhs.e12i.0),
+		//     e13: -E13(self.o.0 * rhs.e13i.0),
+		//     e1o: -E1o(self.o.0 * rhs.e1oi.0),
+		//     e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23: -E23(self.o.0 * rhs.e23i.0),
+		//     e2o: -E2o(self.o.0 * rhs.e2oi.0),
+		//     e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o: -E3o(self.o.0 * rhs.e3oi.0),
+		//     e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		// }
+		PointPair {
+			e12: -E12(self.o.0 * rhs.e12i.0),
+			e13: -E13(self.o.0 * rhs.e13i.0),
+			e1o: -E1o(self.o.0 * rhs.e1oi.0),
+			e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: -E23(self.o.0 * rhs.e23i.0),
+			e2o: -E2o(self.o.0 * rhs.e2oi.0),
+			e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: -E3o(self.o.0 * rhs.e3oi.0),
+			e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl RightContraction<Line> for Point {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line> for Point {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point.commutator(Line) -> Plane
+impl Commutator<Line> for Point {
+	type Output = Plane;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Plane {
+			e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+			e12oi: E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+			e13oi: E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		}
+	}
+}
+
+// Point.anti_dot(Line) -> Circle
+impl AntiDot<Line> for Point {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o: -E12o(self.o.0 * rhs.e3oi.0),
+		//     e12i: -E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+		//     e13o: E13o(self.o.0 * rhs.e2oi.0),
+		//     e13i: E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi: -E1oi(self.o.0 * rhs.e23i.0),
+		//     e23o: -E23o(self.o.0 * rhs.e1oi.0),
+		//     e23i: -E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi: E2oi(self.o.0 * rhs.e13i.0),
+		//     e3oi: -E3oi(self.o.0 * rhs.e12i.0),
+		// }
+		Circle {
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: -E12o(self.o.0 * rhs.e3oi.0),
+			e12i: -E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+			e13o: E13o(self.o.0 * rhs.e2oi.0),
+			e13i: E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.o.0 * rhs.e23i.0),
+			e23o: -E23o(self.o.0 * rhs.e1oi.0),
+			e23i: -E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: E2oi(self.o.0 * rhs.e13i.0),
+			e3oi: -E3oi(self.o.0 * rhs.e12i.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP Circle:
+
+// Point.geometric(Circle) -> Versor
+impl Geometric<Circle> for Point {
+	type Output = Versor;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.z.0 * rhs.e123.0),
+		//     e13  : -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) - E13(self.y.0 * rhs.e123.0),
+		//     e1o  : -E1o(self.o.0 * rhs.e1oi.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i  : E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23  : -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0),
+		//     e2o  : -E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i  : E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o  : -E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+		//     e3i  : E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi  : Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		//     e123o: -E123o(self.o.0 * rhs.e123.0) + E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i: -E123i(self.inf.0 * rhs.e123.0) + E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: -E12oi(self.inf.0 * rhs.e12o.0) + E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: -E13oi(self.inf.0 * rhs.e13o.0) + E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: -E23oi(self.inf.0 * rhs.e23o.0) + E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) - E13(self.y.0 * rhs.e123.0),
+			e1o: -E1o(self.o.0 * rhs.e1oi.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0),
+			e2o: -E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: -E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+			e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+			e123o: -E123o(self.o.0 * rhs.e123.0) + E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0)
+				+ E123o(self.z.0 * rhs.e12o.0),
+			e123i: -E123i(self.inf.0 * rhs.e123.0) + E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0)
+				+ E123i(self.z.0 * rhs.e12i.0),
+			e12oi: -E12oi(self.inf.0 * rhs.e12o.0) + E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0)
+				- E12oi(self.y.0 * rhs.e1oi.0),
+			e13oi: -E13oi(self.inf.0 * rhs.e13o.0) + E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0)
+				- E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: -E23oi(self.inf.0 * rhs.e23o.0) + E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0)
+				- E23oi(self.z.0 * rhs.e2oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for Point {
+	type Output = Versor;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(Circle) -> Multivector
+impl AntiGeometric<Circle> for Point {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.inf.0 * rhs.e23o.0) - E1(self.o.0 * rhs.e23i.0) - E1(self.y.0 * rhs.e3oi.0) + E1(self.z.0 * rhs.e2oi.0),
+		//     e2    : -E2(self.inf.0 * rhs.e13o.0) + E2(self.o.0 * rhs.e13i.0) + E2(self.x.0 * rhs.e3oi.0) - E2(self.z.0 * rhs.e1oi.0),
+		//     e3    : E3(self.inf.0 * rhs.e12o.0) - E3(self.o.0 * rhs.e12i.0) - E3(self.x.0 * rhs.e2oi.0) + E3(self.y.0 * rhs.e1oi.0),
+		//     eo    : -Eo(self.o.0 * rhs.e123.0) + Eo(self.x.0 * rhs.e23o.0) - Eo(self.y.0 * rhs.e13o.0) + Eo(self.z.0 * rhs.e12o.0),
+		//     ei    : Ei(self.inf.0 * rhs.e123.0) - Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0) - Ei(self.z.0 * rhs.e12i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o  : -E12o(self.o.0 * rhs.e3oi.0) + E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e2oi.0) - E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi  : -E1oi(self.inf.0 * rhs.e23o.0) - E1oi(self.o.0 * rhs.e23i.0) + E1oi(self.x.0 * rhs.e123.0),
+		//     e23o  : -E23o(self.o.0 * rhs.e1oi.0) - E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi  : E2oi(self.inf.0 * rhs.e13o.0) + E2oi(self.o.0 * rhs.e13i.0) + E2oi(self.y.0 * rhs.e123.0),
+		//     e3oi  : -E3oi(self.inf.0 * rhs.e12o.0) - E3oi(self.o.0 * rhs.e12i.0) + E3oi(self.z.0 * rhs.e123.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.inf.0 * rhs.e23o.0) - E1(self.o.0 * rhs.e23i.0) - E1(self.y.0 * rhs.e3oi.0)
+				+ E1(self.z.0 * rhs.e2oi.0),
+			e2: -E2(self.inf.0 * rhs.e13o.0) + E2(self.o.0 * rhs.e13i.0) + E2(self.x.0 * rhs.e3oi.0)
+				- E2(self.z.0 * rhs.e1oi.0),
+			e3: E3(self.inf.0 * rhs.e12o.0) - E3(self.o.0 * rhs.e12i.0) - E3(self.x.0 * rhs.e2oi.0)
+				+ E3(self.y.0 * rhs.e1oi.0),
+			eo: -Eo(self.o.0 * rhs.e123.0) + Eo(self.x.0 * rhs.e23o.0) - Eo(self.y.0 * rhs.e13o.0)
+				+ Eo(self.z.0 * rhs.e12o.0),
+			ei: Ei(self.inf.0 * rhs.e123.0) - Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0)
+				- Ei(self.z.0 * rhs.e12i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: -E12o(self.o.0 * rhs.e3oi.0) + E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+			e12i: -E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+			e13o: E13o(self.o.0 * rhs.e2oi.0) - E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+			e13i: E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.inf.0 * rhs.e23o.0) - E1oi(self.o.0 * rhs.e23i.0) + E1oi(self.x.0 * rhs.e123.0),
+			e23o: -E23o(self.o.0 * rhs.e1oi.0) - E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+			e23i: -E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: E2oi(self.inf.0 * rhs.e13o.0) + E2oi(self.o.0 * rhs.e13i.0) + E2oi(self.y.0 * rhs.e123.0),
+			e3oi: -E3oi(self.inf.0 * rhs.e12o.0) - E3oi(self.o.0 * rhs.e12i.0) + E3oi(self.z.0 * rhs.e123.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Point.dot(Circle) -> PointPair
+impl Dot<Circle> for Point {
+	type Output = PointPair;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.z.0 * rhs.e123.0),
+		//     e13: -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) - E13(self.y.0 * rhs.e123.0),
+		//     e1o: -E1o(self.o.0 * rhs.e1oi.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23: -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0),
+		//     e2o: -E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o: -E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+		//     e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		// }
+		PointPair {
+			e12: -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) - E13(self.y.0 * rhs.e123.0),
+			e1o: -E1o(self.o.0 * rhs.e1oi.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0),
+			e2o: -E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: -E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+			e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Circle> for Point {
+	type Output = PointPair;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(Circle) -> Sphere
+impl Wedge<Circle> for Point {
+	type Output = Sphere;
+	fn wedge(self, rhs: Circle) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.o.0 * rhs.e123.0) + E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i: -E123i(self.inf.0 * rhs.e123.0) + E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: -E12oi(self.inf.0 * rhs.e12o.0) + E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: -E13oi(self.inf.0 * rhs.e13o.0) + E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: -E23oi(self.inf.0 * rhs.e23o.0) + E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Sphere {
+			e123o: self.o.wedge(rhs.e123) + self.x.wedge(rhs.e23o) + self.y.wedge(rhs.e13o) + self.z.wedge(rhs.e12o),
+			e123i: self.inf.wedge(rhs.e123) + self.x.wedge(rhs.e23i) + self.y.wedge(rhs.e13i) + self.z.wedge(rhs.e12i),
+			e12oi: self.inf.wedge(rhs.e12o) + self.o.wedge(rhs.e12i) + self.x.wedge(rhs.e2oi) + self.y.wedge(rhs.e1oi),
+			e13oi: self.inf.wedge(rhs.e13o) + self.o.wedge(rhs.e13i) + self.x.wedge(rhs.e3oi) + self.z.wedge(rhs.e1oi),
+			e23oi: self.inf.wedge(rhs.e23o) + self.o.wedge(rhs.e23i) + self.y.wedge(rhs.e3oi) + self.z.wedge(rhs.e2oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Circle> for Point {
+	type Output = Sphere;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Circle> for Point {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Circle> for Point {
+	type Output = Zero;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(Circle) -> PointPair
+impl LeftContraction<Circle> for Point {
+	type Output = PointPair;
+	fn left_contraction(self, rhs: Circle) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.z.0 * rhs.e123.0),
+		//     e13: -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) - E13(self.y.0 * rhs.e123.0),
+		//     e1o: -E1o(self.o.0 * rhs.e1oi.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23: -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0),
+		//     e2o: -E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o: -E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+		//     e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		// }
+		PointPair {
+			e12: -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) - E13(self.y.0 * rhs.e123.0),
+			e1o: -E1o(self.o.0 * rhs.e1oi.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0),
+			e2o: -E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: -E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+			e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl RightContraction<Circle> for Point {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Circle> for Point {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point.commutator(Circle) -> Sphere
+impl Commutator<Circle> for Point {
+	type Output = Sphere;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.o.0 * rhs.e123.0) + E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i: -E123i(self.inf.0 * rhs.e123.0) + E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: -E12oi(self.inf.0 * rhs.e12o.0) + E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: -E13oi(self.inf.0 * rhs.e13o.0) + E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: -E23oi(self.inf.0 * rhs.e23o.0) + E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.o.0 * rhs.e123.0) + E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0)
+				+ E123o(self.z.0 * rhs.e12o.0),
+			e123i: -E123i(self.inf.0 * rhs.e123.0) + E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0)
+				+ E123i(self.z.0 * rhs.e12i.0),
+			e12oi: -E12oi(self.inf.0 * rhs.e12o.0) + E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0)
+				- E12oi(self.y.0 * rhs.e1oi.0),
+			e13oi: -E13oi(self.inf.0 * rhs.e13o.0) + E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0)
+				- E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: -E23oi(self.inf.0 * rhs.e23o.0) + E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0)
+				- E23oi(self.z.0 * rhs.e2oi.0),
+		}
+	}
+}
+
+// Point.anti_dot(Circle) -> Circle
+impl AntiDot<Circle> for Point {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o: -E12o(self.o.0 * rhs.e3oi.0) + E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+		//     e12i: -E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+		//     e13o: E13o(self.o.0 * rhs.e2oi.0) - E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+		//     e13i: E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi: -E1oi(self.inf.0 * rhs.e23o.0) - E1oi(self.o.0 * rhs.e23i.0) + E1oi(self.x.0 * rhs.e123.0),
+		//     e23o: -E23o(self.o.0 * rhs.e1oi.0) - E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+		//     e23i: -E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi: E2oi(self.inf.0 * rhs.e13o.0) + E2oi(self.o.0 * rhs.e13i.0) + E2oi(self.y.0 * rhs.e123.0),
+		//     e3oi: -E3oi(self.inf.0 * rhs.e12o.0) - E3oi(self.o.0 * rhs.e12i.0) + E3oi(self.z.0 * rhs.e123.0),
+		// }
+		Circle {
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: -E12o(self.o.0 * rhs.e3oi.0) + E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+			e12i: -E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+			e13o: E13o(self.o.0 * rhs.e2oi.0) - E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+			e13i: E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.inf.0 * rhs.e23o.0) - E1oi(self.o.0 * rhs.e23i.0) + E1oi(self.x.0 * rhs.e123.0),
+			e23o: -E23o(self.o.0 * rhs.e1oi.0) - E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+			e23i: -E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: E2oi(self.inf.0 * rhs.e13o.0) + E2oi(self.o.0 * rhs.e13i.0) + E2oi(self.y.0 * rhs.e123.0),
+			e3oi: -E3oi(self.inf.0 * rhs.e12o.0) - E3oi(self.o.0 * rhs.e12i.0) + E3oi(self.z.0 * rhs.e123.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP Plane:
+
+// Point.geometric(Plane) -> Multivector
+impl Geometric<Plane> for Point {
+	type Output = Multivector;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12oi.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13oi.0),
+		//     e13i  : -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23oi.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0)
+				+ E123oi(self.z.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Point {
+	type Output = Multivector;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(Plane) -> Versor
+impl AntiGeometric<Plane> for Point {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+		//     e12  : E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13  : -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o  : E1o(self.o.0 * rhs.e23oi.0),
+		//     e1i  : E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+		//     e23  : -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o  : -E2o(self.o.0 * rhs.e13oi.0),
+		//     e2i  : -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.o.0 * rhs.e12oi.0),
+		//     e3i  : E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.o.0 * rhs.e123i.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0)
+				+ S(self.z.0 * rhs.e12oi.0),
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.o.0 * rhs.e23oi.0),
+			e1i: E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: -E2o(self.o.0 * rhs.e13oi.0),
+			e2i: -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+			e3o: E3o(self.o.0 * rhs.e12oi.0),
+			e3i: E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+			eoi: -Eoi(self.o.0 * rhs.e123i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Point.dot(Plane) -> Circle
+impl Dot<Plane> for Point {
+	type Output = Circle;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.o.0 * rhs.e123i.0),
+		//     e12o: E12o(self.o.0 * rhs.e12oi.0),
+		//     e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o: E13o(self.o.0 * rhs.e13oi.0),
+		//     e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o: E23o(self.o.0 * rhs.e23oi.0),
+		//     e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Circle {
+			e123: E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Point {
+	type Output = Circle;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(Plane) -> E123oi
+impl Wedge<Plane> for Point {
+	type Output = E123oi;
+	fn wedge(self, rhs: Plane) -> Self::Output {
+		// -E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0)
+		self.o.wedge(rhs.e123i) + self.x.wedge(rhs.e23oi) + self.y.wedge(rhs.e13oi) + self.z.wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitXor<Plane> for Point {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Point.anti_wedge(Plane) -> S
+impl AntiWedge<Plane> for Point {
+	type Output = S;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// -S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0)
+		self.o.anti_wedge(rhs.e123i)
+			+ self.x.anti_wedge(rhs.e23oi)
+			+ self.y.anti_wedge(rhs.e13oi)
+			+ self.z.anti_wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Point {
+	type Output = S;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(Plane) -> Circle
+impl LeftContraction<Plane> for Point {
+	type Output = Circle;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.o.0 * rhs.e123i.0),
+		//     e12o: E12o(self.o.0 * rhs.e12oi.0),
+		//     e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o: E13o(self.o.0 * rhs.e13oi.0),
+		//     e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o: E23o(self.o.0 * rhs.e23oi.0),
+		//     e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Circle {
+			e123: E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Plane> for Point {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for Point {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point.commutator(Plane) -> Circle
+impl Commutator<Plane> for Point {
+	type Output = Circle;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.o.0 * rhs.e123i.0),
+		//     e12o: E12o(self.o.0 * rhs.e12oi.0),
+		//     e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o: E13o(self.o.0 * rhs.e13oi.0),
+		//     e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o: E23o(self.o.0 * rhs.e23oi.0),
+		//     e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Circle {
+			e123: E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Point.anti_dot(Plane) -> PointPair
+impl AntiDot<Plane> for Point {
+	type Output = PointPair;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o: E1o(self.o.0 * rhs.e23oi.0),
+		//     e1i: E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+		//     e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o: -E2o(self.o.0 * rhs.e13oi.0),
+		//     e2i: -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+		//     e3o: E3o(self.o.0 * rhs.e12oi.0),
+		//     e3i: E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+		//     eoi: -Eoi(self.o.0 * rhs.e123i.0),
+		// }
+		PointPair {
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.o.0 * rhs.e23oi.0),
+			e1i: E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: -E2o(self.o.0 * rhs.e13oi.0),
+			e2i: -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+			e3o: E3o(self.o.0 * rhs.e12oi.0),
+			e3i: E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+			eoi: -Eoi(self.o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP Sphere:
+
+// Point.geometric(Sphere) -> Multivector
+impl Geometric<Sphere> for Point {
+	type Output = Multivector;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.inf.0 * rhs.e123o.0) - E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.inf.0 * rhs.e123o.0) - E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0)
+				- E123oi(self.y.0 * rhs.e13oi.0)
+				+ E123oi(self.z.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for Point {
+	type Output = Multivector;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(Sphere) -> Versor
+impl AntiGeometric<Sphere> for Point {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : S(self.inf.0 * rhs.e123o.0) - S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+		//     e12  : E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13  : -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o  : E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+		//     e23  : -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o  : -E2o(self.o.0 * rhs.e13oi.0) + E2o(self.y.0 * rhs.e123o.0),
+		//     e2i  : -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.o.0 * rhs.e12oi.0) + E3o(self.z.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.inf.0 * rhs.e123o.0) - S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0)
+				- S(self.y.0 * rhs.e13oi.0)
+				+ S(self.z.0 * rhs.e12oi.0),
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0),
+			e1i: E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: -E2o(self.o.0 * rhs.e13oi.0) + E2o(self.y.0 * rhs.e123o.0),
+			e2i: -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+			e3o: E3o(self.o.0 * rhs.e12oi.0) + E3o(self.z.0 * rhs.e123o.0),
+			e3i: E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+			eoi: -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Point.dot(Sphere) -> Circle
+impl Dot<Sphere> for Point {
+	type Output = Circle;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+		//     e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+		//     e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+		//     e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Circle {
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Sphere> for Point {
+	type Output = Circle;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(Sphere) -> E123oi
+impl Wedge<Sphere> for Point {
+	type Output = E123oi;
+	fn wedge(self, rhs: Sphere) -> Self::Output {
+		// E123oi(self.inf.0 * rhs.e123o.0) - E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0)
+		self.inf.wedge(rhs.e123o)
+			+ self.o.wedge(rhs.e123i)
+			+ self.x.wedge(rhs.e23oi)
+			+ self.y.wedge(rhs.e13oi)
+			+ self.z.wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitXor<Sphere> for Point {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Point.anti_wedge(Sphere) -> S
+impl AntiWedge<Sphere> for Point {
+	type Output = S;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// S(self.inf.0 * rhs.e123o.0) - S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0)
+		self.inf.anti_wedge(rhs.e123o)
+			+ self.o.anti_wedge(rhs.e123i)
+			+ self.x.anti_wedge(rhs.e23oi)
+			+ self.y.anti_wedge(rhs.e13oi)
+			+ self.z.anti_wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for Point {
+	type Output = S;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(Sphere) -> Circle
+impl LeftContraction<Sphere> for Point {
+	type Output = Circle;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+		//     e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+		//     e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+		//     e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Circle {
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Sphere> for Point {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Sphere> for Point {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point.commutator(Sphere) -> Circle
+impl Commutator<Sphere> for Point {
+	type Output = Circle;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+		//     e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+		//     e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+		//     e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Circle {
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Point.anti_dot(Sphere) -> PointPair
+impl AntiDot<Sphere> for Point {
+	type Output = PointPair;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o: E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0),
+		//     e1i: E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+		//     e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o: -E2o(self.o.0 * rhs.e13oi.0) + E2o(self.y.0 * rhs.e123o.0),
+		//     e2i: -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+		//     e3o: E3o(self.o.0 * rhs.e12oi.0) + E3o(self.z.0 * rhs.e123o.0),
+		//     e3i: E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+		//     eoi: -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0),
+		// }
+		PointPair {
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0),
+			e1i: E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: -E2o(self.o.0 * rhs.e13oi.0) + E2o(self.y.0 * rhs.e123o.0),
+			e2i: -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+			e3o: E3o(self.o.0 * rhs.e12oi.0) + E3o(self.z.0 * rhs.e123o.0),
+			e3i: E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+			eoi: -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP Rotor:
+
+// Point.geometric(Rotor) -> Multivector
+impl Geometric<Rotor> for Point {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.s.0),
+		//     ei    : Ei(self.inf.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12.0),
+		//     e12i  : E12i(self.inf.0 * rhs.e12.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e13.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : E23o(self.o.0 * rhs.e23.0),
+		//     e23i  : E23i(self.inf.0 * rhs.e23.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.o.0 * rhs.s.0),
+			ei: Ei(self.inf.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+			e12o: E12o(self.o.0 * rhs.e12.0),
+			e12i: E12i(self.inf.0 * rhs.e12.0),
+			e13o: E13o(self.o.0 * rhs.e13.0),
+			e13i: E13i(self.inf.0 * rhs.e13.0),
+			e1oi: Default::default(),
+			e23o: E23o(self.o.0 * rhs.e23.0),
+			e23i: E23i(self.inf.0 * rhs.e23.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Point {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(Rotor) -> Versor
+impl AntiGeometric<Rotor> for Point {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : -E1o(self.o.0 * rhs.e23.0),
+		//     e1i  : E1i(self.inf.0 * rhs.e23.0),
+		//     e23  : Default::default(),
+		//     e2o  : E2o(self.o.0 * rhs.e13.0),
+		//     e2i  : -E2i(self.inf.0 * rhs.e13.0),
+		//     e3o  : -E3o(self.o.0 * rhs.e12.0),
+		//     e3i  : E3i(self.inf.0 * rhs.e12.0),
+		//     eoi  : Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+		//     e123o: E123o(self.o.0 * rhs.s.0),
+		//     e123i: -E123i(self.inf.0 * rhs.s.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: -E1o(self.o.0 * rhs.e23.0),
+			e1i: E1i(self.inf.0 * rhs.e23.0),
+			e23: Default::default(),
+			e2o: E2o(self.o.0 * rhs.e13.0),
+			e2i: -E2i(self.inf.0 * rhs.e13.0),
+			e3o: -E3o(self.o.0 * rhs.e12.0),
+			e3i: E3i(self.inf.0 * rhs.e12.0),
+			eoi: Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+			e123o: E123o(self.o.0 * rhs.s.0),
+			e123i: -E123i(self.inf.0 * rhs.s.0),
+			e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+			e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// Point.dot(Rotor) -> Point
+impl Dot<Rotor> for Point {
+	type Output = Point;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Point {
+		//     x  : E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y  : E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     z  : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     o  : Eo(self.o.0 * rhs.s.0),
+		//     inf: Ei(self.inf.0 * rhs.s.0),
+		// }
+		Point {
+			x: E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			y: E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+			z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+			o: Eo(self.o.0 * rhs.s.0),
+			inf: Ei(self.inf.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Point {
+	type Output = Point;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(Rotor) -> Multivector
+impl Wedge<Rotor> for Point {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.s.0),
+		//     ei    : Ei(self.inf.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12.0),
+		//     e12i  : E12i(self.inf.0 * rhs.e12.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e13.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : E23o(self.o.0 * rhs.e23.0),
+		//     e23i  : E23i(self.inf.0 * rhs.e23.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.x.wedge(rhs.s),
+			e2: self.y.wedge(rhs.s),
+			e3: self.z.wedge(rhs.s),
+			eo: self.o.wedge(rhs.s),
+			ei: self.inf.wedge(rhs.s),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e12o: self.o.wedge(rhs.e12),
+			e12i: self.inf.wedge(rhs.e12),
+			e13o: self.o.wedge(rhs.e13),
+			e13i: self.inf.wedge(rhs.e13),
+			e1oi: Default::default(),
+			e23o: self.o.wedge(rhs.e23),
+			e23i: self.inf.wedge(rhs.e23),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Point {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Rotor> for Point {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Point {
+	type Output = Zero;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(Rotor) -> Vec3
+impl LeftContraction<Rotor> for Point {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		// }
+		Vec3 {
+			x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Point.right_contraction(Rotor) -> Point
+impl RightContraction<Rotor> for Point {
+	type Output = Point;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Point {
+		//     x  : E1(self.x.0 * rhs.s.0),
+		//     y  : E2(self.y.0 * rhs.s.0),
+		//     z  : E3(self.z.0 * rhs.s.0),
+		//     o  : Eo(self.o.0 * rhs.s.0),
+		//     inf: Ei(self.inf.0 * rhs.s.0),
+		// }
+		Point {
+			x: E1(self.x.0 * rhs.s.0),
+			y: E2(self.y.0 * rhs.s.0),
+			z: E3(self.z.0 * rhs.s.0),
+			o: Eo(self.o.0 * rhs.s.0),
+			inf: Ei(self.inf.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for Point {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point.commutator(Rotor) -> Vec3
+impl Commutator<Rotor> for Point {
+	type Output = Vec3;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		// }
+		Vec3 {
+			x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Point.anti_dot(Rotor) -> Sphere
+impl AntiDot<Rotor> for Point {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.o.0 * rhs.s.0),
+		//     e123i: -E123i(self.inf.0 * rhs.s.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Sphere {
+			e123o: E123o(self.o.0 * rhs.s.0),
+			e123i: -E123i(self.inf.0 * rhs.s.0),
+			e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+			e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP Translator:
+
+// Point.geometric(Translator) -> Multivector
+impl Geometric<Translator> for Point {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.o.0 * rhs.e2i.0) + E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.o.0 * rhs.e3i.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.s.0),
+		//     ei    : Ei(self.inf.0 * rhs.s.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : -E1oi(self.o.0 * rhs.e1i.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.o.0 * rhs.e2i.0),
+		//     e3oi  : -E3oi(self.o.0 * rhs.e3i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0),
+			e2: E2(self.o.0 * rhs.e2i.0) + E2(self.y.0 * rhs.s.0),
+			e3: E3(self.o.0 * rhs.e3i.0) + E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.o.0 * rhs.s.0),
+			ei: Ei(self.inf.0 * rhs.s.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+			e1oi: -E1oi(self.o.0 * rhs.e1i.0),
+			e23o: Default::default(),
+			e23i: E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+			e2oi: -E2oi(self.o.0 * rhs.e2i.0),
+			e3oi: -E3oi(self.o.0 * rhs.e3i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Point {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(Translator) -> Versor
+impl AntiGeometric<Translator> for Point {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.o.0 * rhs.e3i.0),
+		//     e13  : -E13(self.o.0 * rhs.e2i.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+		//     e23  : E23(self.o.0 * rhs.e1i.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.o.0 * rhs.s.0),
+		//     e123i: -E123i(self.inf.0 * rhs.s.0) + E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.o.0 * rhs.e3i.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: -E13oi(self.o.0 * rhs.e2i.0) + E13oi(self.y.0 * rhs.s.0),
+		//     e23oi: E23oi(self.o.0 * rhs.e1i.0) - E23oi(self.x.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.o.0 * rhs.e3i.0),
+			e13: -E13(self.o.0 * rhs.e2i.0),
+			e1o: Default::default(),
+			e1i: E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+			e23: E23(self.o.0 * rhs.e1i.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+			eoi: Default::default(),
+			e123o: E123o(self.o.0 * rhs.s.0),
+			e123i: -E123i(self.inf.0 * rhs.s.0)
+				+ E123i(self.x.0 * rhs.e1i.0)
+				+ E123i(self.y.0 * rhs.e2i.0)
+				+ E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.o.0 * rhs.e3i.0) - E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.o.0 * rhs.e2i.0) + E13oi(self.y.0 * rhs.s.0),
+			e23oi: E23oi(self.o.0 * rhs.e1i.0) - E23oi(self.x.0 * rhs.s.0),
+		}
+	}
+}
+
+// Point.dot(Translator) -> Point
+impl Dot<Translator> for Point {
+	type Output = Point;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Point {
+		//     x  : E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0),
+		//     y  : E2(self.o.0 * rhs.e2i.0) + E2(self.y.0 * rhs.s.0),
+		//     z  : E3(self.o.0 * rhs.e3i.0) + E3(self.z.0 * rhs.s.0),
+		//     o  : Eo(self.o.0 * rhs.s.0),
+		//     inf: Ei(self.inf.0 * rhs.s.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0),
+			y: E2(self.o.0 * rhs.e2i.0) + E2(self.y.0 * rhs.s.0),
+			z: E3(self.o.0 * rhs.e3i.0) + E3(self.z.0 * rhs.s.0),
+			o: Eo(self.o.0 * rhs.s.0),
+			inf: Ei(self.inf.0 * rhs.s.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Point {
+	type Output = Point;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(Translator) -> Multivector
+impl Wedge<Translator> for Point {
+	type Output = Multivector;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.s.0),
+		//     ei    : Ei(self.inf.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : -E1oi(self.o.0 * rhs.e1i.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : -E2oi(self.o.0 * rhs.e2i.0),
+		//     e3oi  : -E3oi(self.o.0 * rhs.e3i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.x.wedge(rhs.s),
+			e2: self.y.wedge(rhs.s),
+			e3: self.z.wedge(rhs.s),
+			eo: self.o.wedge(rhs.s),
+			ei: self.inf.wedge(rhs.s),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.x.wedge(rhs.e2i) + self.y.wedge(rhs.e1i),
+			e13o: Default::default(),
+			e13i: self.x.wedge(rhs.e3i) + self.z.wedge(rhs.e1i),
+			e1oi: self.o.wedge(rhs.e1i),
+			e23o: Default::default(),
+			e23i: self.y.wedge(rhs.e3i) + self.z.wedge(rhs.e2i),
+			e2oi: self.o.wedge(rhs.e2i),
+			e3oi: self.o.wedge(rhs.e3i),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Point {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Translator> for Point {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Point {
+	type Output = Zero;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(Translator) -> Point
+impl LeftContraction<Translator> for Point {
+	type Output = Point;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// Point {
+		//     x  : E1(self.o.0 * rhs.e1i.0),
+		//     y  : E2(self.o.0 * rhs.e2i.0),
+		//     z  : E3(self.o.0 * rhs.e3i.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.o.0 * rhs.e1i.0),
+			y: E2(self.o.0 * rhs.e2i.0),
+			z: E3(self.o.0 * rhs.e3i.0),
+			o: Default::default(),
+			inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// Point.right_contraction(Translator) -> Point
+impl RightContraction<Translator> for Point {
+	type Output = Point;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Point {
+		//     x  : E1(self.x.0 * rhs.s.0),
+		//     y  : E2(self.y.0 * rhs.s.0),
+		//     z  : E3(self.z.0 * rhs.s.0),
+		//     o  : Eo(self.o.0 * rhs.s.0),
+		//     inf: Ei(self.inf.0 * rhs.s.0),
+		// }
+		Point {
+			x: E1(self.x.0 * rhs.s.0),
+			y: E2(self.y.0 * rhs.s.0),
+			z: E3(self.z.0 * rhs.s.0),
+			o: Eo(self.o.0 * rhs.s.0),
+			inf: Ei(self.inf.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for Point {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point.commutator(Translator) -> Point
+impl Commutator<Translator> for Point {
+	type Output = Point;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// Point {
+		//     x  : E1(self.o.0 * rhs.e1i.0),
+		//     y  : E2(self.o.0 * rhs.e2i.0),
+		//     z  : E3(self.o.0 * rhs.e3i.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.o.0 * rhs.e1i.0),
+			y: E2(self.o.0 * rhs.e2i.0),
+			z: E3(self.o.0 * rhs.e3i.0),
+			o: Default::default(),
+			inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// Point.anti_dot(Translator) -> Sphere
+impl AntiDot<Translator> for Point {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.o.0 * rhs.s.0),
+		//     e123i: -E123i(self.inf.0 * rhs.s.0) + E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.o.0 * rhs.e3i.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: -E13oi(self.o.0 * rhs.e2i.0) + E13oi(self.y.0 * rhs.s.0),
+		//     e23oi: E23oi(self.o.0 * rhs.e1i.0) - E23oi(self.x.0 * rhs.s.0),
+		// }
+		Sphere {
+			e123o: E123o(self.o.0 * rhs.s.0),
+			e123i: -E123i(self.inf.0 * rhs.s.0)
+				+ E123i(self.x.0 * rhs.e1i.0)
+				+ E123i(self.y.0 * rhs.e2i.0)
+				+ E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.o.0 * rhs.e3i.0) - E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.o.0 * rhs.e2i.0) + E13oi(self.y.0 * rhs.s.0),
+			e23oi: E23oi(self.o.0 * rhs.e1i.0) - E23oi(self.x.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP Versor:
+
+// Point.geometric(Versor) -> Multivector
+impl Geometric<Versor> for Point {
+	type Output = Multivector;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.eoi.0) + Eo(self.o.0 * rhs.s.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.inf.0 * rhs.s.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0) + E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12.0) + E12o(self.o.0 * rhs.e12oi.0) + E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.inf.0 * rhs.e12.0) - E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13.0) + E13o(self.o.0 * rhs.e13oi.0) + E13o(self.x.0 * rhs.e3o.0) - E13o(self.y.0 * rhs.e123o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e13.0) - E13i(self.inf.0 * rhs.e13oi.0) + E13i(self.x.0 * rhs.e3i.0) - E13i(self.y.0 * rhs.e123i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.inf.0 * rhs.e1o.0) - E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0) - E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23.0) + E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.inf.0 * rhs.e23.0) - E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.inf.0 * rhs.e2o.0) - E2oi(self.o.0 * rhs.e2i.0) + E2oi(self.x.0 * rhs.e12oi.0) + E2oi(self.y.0 * rhs.eoi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.inf.0 * rhs.e3o.0) - E3oi(self.o.0 * rhs.e3i.0) + E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0) + E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.inf.0 * rhs.e123o.0) - E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.inf.0 * rhs.e2o.0)
+				+ E2(self.o.0 * rhs.e2i.0)
+				+ E2(self.x.0 * rhs.e12.0)
+				+ E2(self.y.0 * rhs.s.0)
+				- E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0)
+				+ E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.o.0 * rhs.s.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			ei: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.inf.0 * rhs.s.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0) + E123(self.x.0 * rhs.e23.0)
+				- E123(self.y.0 * rhs.e13.0)
+				+ E123(self.z.0 * rhs.e12.0),
+			e12o: E12o(self.o.0 * rhs.e12.0) + E12o(self.o.0 * rhs.e12oi.0) + E12o(self.x.0 * rhs.e2o.0)
+				- E12o(self.y.0 * rhs.e1o.0)
+				+ E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.inf.0 * rhs.e12.0) - E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.x.0 * rhs.e2i.0)
+				- E12i(self.y.0 * rhs.e1i.0)
+				+ E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13.0) + E13o(self.o.0 * rhs.e13oi.0) + E13o(self.x.0 * rhs.e3o.0)
+				- E13o(self.y.0 * rhs.e123o.0)
+				- E13o(self.z.0 * rhs.e1o.0),
+			e13i: E13i(self.inf.0 * rhs.e13.0) - E13i(self.inf.0 * rhs.e13oi.0) + E13i(self.x.0 * rhs.e3i.0)
+				- E13i(self.y.0 * rhs.e123i.0)
+				- E13i(self.z.0 * rhs.e1i.0),
+			e1oi: E1oi(self.inf.0 * rhs.e1o.0) - E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0)
+				- E1oi(self.y.0 * rhs.e12oi.0)
+				- E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23.0)
+				+ E23o(self.o.0 * rhs.e23oi.0)
+				+ E23o(self.x.0 * rhs.e123o.0)
+				+ E23o(self.y.0 * rhs.e3o.0)
+				- E23o(self.z.0 * rhs.e2o.0),
+			e23i: E23i(self.inf.0 * rhs.e23.0) - E23i(self.inf.0 * rhs.e23oi.0)
+				+ E23i(self.x.0 * rhs.e123i.0)
+				+ E23i(self.y.0 * rhs.e3i.0)
+				- E23i(self.z.0 * rhs.e2i.0),
+			e2oi: E2oi(self.inf.0 * rhs.e2o.0) - E2oi(self.o.0 * rhs.e2i.0)
+				+ E2oi(self.x.0 * rhs.e12oi.0)
+				+ E2oi(self.y.0 * rhs.eoi.0)
+				- E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.inf.0 * rhs.e3o.0) - E3oi(self.o.0 * rhs.e3i.0)
+				+ E3oi(self.x.0 * rhs.e13oi.0)
+				+ E3oi(self.y.0 * rhs.e23oi.0)
+				+ E3oi(self.z.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.inf.0 * rhs.e123o.0) - E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0)
+				- E123oi(self.y.0 * rhs.e13oi.0)
+				+ E123oi(self.z.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for Point {
+	type Output = Multivector;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(Versor) -> Versor
+impl AntiGeometric<Versor> for Point {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.inf.0 * rhs.e123o.0) - S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+		//     e12  : -E12(self.inf.0 * rhs.e3o.0) + E12(self.o.0 * rhs.e3i.0) + E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0) - E12(self.z.0 * rhs.eoi.0),
+		//     e13  : E13(self.inf.0 * rhs.e2o.0) - E13(self.o.0 * rhs.e2i.0) - E13(self.x.0 * rhs.e12oi.0) + E13(self.y.0 * rhs.eoi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o  : -E1o(self.o.0 * rhs.e23.0) + E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0) - E1o(self.y.0 * rhs.e3o.0) + E1o(self.z.0 * rhs.e2o.0),
+		//     e1i  : E1i(self.inf.0 * rhs.e23.0) + E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0) + E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+		//     e23  : -E23(self.inf.0 * rhs.e1o.0) + E23(self.o.0 * rhs.e1i.0) - E23(self.x.0 * rhs.eoi.0) - E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o  : E2o(self.o.0 * rhs.e13.0) - E2o(self.o.0 * rhs.e13oi.0) + E2o(self.x.0 * rhs.e3o.0) + E2o(self.y.0 * rhs.e123o.0) - E2o(self.z.0 * rhs.e1o.0),
+		//     e2i  : -E2i(self.inf.0 * rhs.e13.0) - E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.x.0 * rhs.e3i.0) - E2i(self.y.0 * rhs.e123i.0) + E2i(self.z.0 * rhs.e1i.0),
+		//     e3o  : -E3o(self.o.0 * rhs.e12.0) + E3o(self.o.0 * rhs.e12oi.0) - E3o(self.x.0 * rhs.e2o.0) + E3o(self.y.0 * rhs.e1o.0) + E3o(self.z.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.inf.0 * rhs.e12.0) + E3i(self.inf.0 * rhs.e12oi.0) + E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0) - E3i(self.z.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0) + Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+		//     e123o: -E123o(self.o.0 * rhs.eoi.0) + E123o(self.o.0 * rhs.s.0) - E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i: -E123i(self.inf.0 * rhs.eoi.0) - E123i(self.inf.0 * rhs.s.0) + E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.inf.0 * rhs.e3o.0) + E12oi(self.o.0 * rhs.e3i.0) + E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0) - E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Versor {
+			s: S(self.inf.0 * rhs.e123o.0) - S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0)
+				- S(self.y.0 * rhs.e13oi.0)
+				+ S(self.z.0 * rhs.e12oi.0),
+			e12: -E12(self.inf.0 * rhs.e3o.0)
+				+ E12(self.o.0 * rhs.e3i.0)
+				+ E12(self.x.0 * rhs.e13oi.0)
+				+ E12(self.y.0 * rhs.e23oi.0)
+				- E12(self.z.0 * rhs.eoi.0),
+			e13: E13(self.inf.0 * rhs.e2o.0) - E13(self.o.0 * rhs.e2i.0) - E13(self.x.0 * rhs.e12oi.0)
+				+ E13(self.y.0 * rhs.eoi.0)
+				+ E13(self.z.0 * rhs.e23oi.0),
+			e1o: -E1o(self.o.0 * rhs.e23.0) + E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0)
+				- E1o(self.y.0 * rhs.e3o.0)
+				+ E1o(self.z.0 * rhs.e2o.0),
+			e1i: E1i(self.inf.0 * rhs.e23.0) + E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0)
+				+ E1i(self.y.0 * rhs.e3i.0)
+				- E1i(self.z.0 * rhs.e2i.0),
+			e23: -E23(self.inf.0 * rhs.e1o.0) + E23(self.o.0 * rhs.e1i.0)
+				- E23(self.x.0 * rhs.eoi.0)
+				- E23(self.y.0 * rhs.e12oi.0)
+				- E23(self.z.0 * rhs.e13oi.0),
+			e2o: E2o(self.o.0 * rhs.e13.0) - E2o(self.o.0 * rhs.e13oi.0)
+				+ E2o(self.x.0 * rhs.e3o.0)
+				+ E2o(self.y.0 * rhs.e123o.0)
+				- E2o(self.z.0 * rhs.e1o.0),
+			e2i: -E2i(self.inf.0 * rhs.e13.0)
+				- E2i(self.inf.0 * rhs.e13oi.0)
+				- E2i(self.x.0 * rhs.e3i.0)
+				- E2i(self.y.0 * rhs.e123i.0)
+				+ E2i(self.z.0 * rhs.e1i.0),
+			e3o: -E3o(self.o.0 * rhs.e12.0) + E3o(self.o.0 * rhs.e12oi.0) - E3o(self.x.0 * rhs.e2o.0)
+				+ E3o(self.y.0 * rhs.e1o.0)
+				+ E3o(self.z.0 * rhs.e123o.0),
+			e3i: E3i(self.inf.0 * rhs.e12.0) + E3i(self.inf.0 * rhs.e12oi.0) + E3i(self.x.0 * rhs.e2i.0)
+				- E3i(self.y.0 * rhs.e1i.0)
+				- E3i(self.z.0 * rhs.e123i.0),
+			eoi: -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0) + Eoi(self.x.0 * rhs.e23.0)
+				- Eoi(self.y.0 * rhs.e13.0)
+				+ Eoi(self.z.0 * rhs.e12.0),
+			e123o: -E123o(self.o.0 * rhs.eoi.0) + E123o(self.o.0 * rhs.s.0)
+				- E123o(self.x.0 * rhs.e1o.0)
+				- E123o(self.y.0 * rhs.e2o.0)
+				- E123o(self.z.0 * rhs.e3o.0),
+			e123i: -E123i(self.inf.0 * rhs.eoi.0) - E123i(self.inf.0 * rhs.s.0)
+				+ E123i(self.x.0 * rhs.e1i.0)
+				+ E123i(self.y.0 * rhs.e2i.0)
+				+ E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.inf.0 * rhs.e3o.0)
+				+ E12oi(self.o.0 * rhs.e3i.0)
+				+ E12oi(self.x.0 * rhs.e13.0)
+				+ E12oi(self.y.0 * rhs.e23.0)
+				- E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0)
+				+ E13oi(self.y.0 * rhs.s.0)
+				+ E13oi(self.z.0 * rhs.e23.0),
+			e23oi: E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0)
+				- E23oi(self.x.0 * rhs.s.0)
+				- E23oi(self.y.0 * rhs.e12.0)
+				- E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// Point.dot(Versor) -> Multivector
+impl Dot<Versor> for Point {
+	type Output = Multivector;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.eoi.0) + Eo(self.o.0 * rhs.s.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.inf.0 * rhs.s.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.inf.0 * rhs.e2o.0)
+				+ E2(self.o.0 * rhs.e2i.0)
+				+ E2(self.x.0 * rhs.e12.0)
+				+ E2(self.y.0 * rhs.s.0)
+				- E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0)
+				+ E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.o.0 * rhs.s.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			ei: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.inf.0 * rhs.s.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for Point {
+	type Output = Multivector;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(Versor) -> Multivector
+impl Wedge<Versor> for Point {
+	type Output = Multivector;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.s.0),
+		//     ei    : Ei(self.inf.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12.0) + E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0),
+		//     e12i  : E12i(self.inf.0 * rhs.e12.0) + E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13.0) + E13o(self.x.0 * rhs.e3o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e13.0) + E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.inf.0 * rhs.e1o.0) - E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.inf.0 * rhs.e23.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.inf.0 * rhs.e2o.0) - E2oi(self.o.0 * rhs.e2i.0) + E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.inf.0 * rhs.e3o.0) - E3oi(self.o.0 * rhs.e3i.0) + E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.inf.0 * rhs.e123o.0) - E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.x.wedge(rhs.s),
+			e2: self.y.wedge(rhs.s),
+			e3: self.z.wedge(rhs.s),
+			eo: self.o.wedge(rhs.s),
+			ei: self.inf.wedge(rhs.s),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e12o: self.o.wedge(rhs.e12) + self.x.wedge(rhs.e2o) + self.y.wedge(rhs.e1o),
+			e12i: self.inf.wedge(rhs.e12) + self.x.wedge(rhs.e2i) + self.y.wedge(rhs.e1i),
+			e13o: self.o.wedge(rhs.e13) + self.x.wedge(rhs.e3o) + self.z.wedge(rhs.e1o),
+			e13i: self.inf.wedge(rhs.e13) + self.x.wedge(rhs.e3i) + self.z.wedge(rhs.e1i),
+			e1oi: self.inf.wedge(rhs.e1o) + self.o.wedge(rhs.e1i) + self.x.wedge(rhs.eoi),
+			e23o: self.o.wedge(rhs.e23) + self.y.wedge(rhs.e3o) + self.z.wedge(rhs.e2o),
+			e23i: self.inf.wedge(rhs.e23) + self.y.wedge(rhs.e3i) + self.z.wedge(rhs.e2i),
+			e2oi: self.inf.wedge(rhs.e2o) + self.o.wedge(rhs.e2i) + self.y.wedge(rhs.eoi),
+			e3oi: self.inf.wedge(rhs.e3o) + self.o.wedge(rhs.e3i) + self.z.wedge(rhs.eoi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.inf.wedge(rhs.e123o)
+				+ self.o.wedge(rhs.e123i)
+				+ self.x.wedge(rhs.e23oi)
+				+ self.y.wedge(rhs.e13oi)
+				+ self.z.wedge(rhs.e12oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for Point {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Point.anti_wedge(Versor) -> S
+impl AntiWedge<Versor> for Point {
+	type Output = S;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// S(self.inf.0 * rhs.e123o.0) - S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0)
+		self.inf.anti_wedge(rhs.e123o)
+			+ self.o.anti_wedge(rhs.e123i)
+			+ self.x.anti_wedge(rhs.e23oi)
+			+ self.y.anti_wedge(rhs.e13oi)
+			+ self.z.anti_wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitAnd<Versor> for Point {
+	type Output = S;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(Versor) -> Multivector
+impl LeftContraction<Versor> for Point {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     eo    : Eo(self.o.0 * rhs.eoi.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0)
+				- E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0),
+			eo: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			ei: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Point.right_contraction(Versor) -> Point
+impl RightContraction<Versor> for Point {
+	type Output = Point;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Point {
+		//     x  : E1(self.x.0 * rhs.s.0),
+		//     y  : E2(self.y.0 * rhs.s.0),
+		//     z  : E3(self.z.0 * rhs.s.0),
+		//     o  : Eo(self.o.0 * rhs.s.0),
+		//     inf: Ei(self.inf.0 * rhs.s.0),
+		// }
+		Point {
+			x: E1(self.x.0 * rhs.s.0),
+			y: E2(self.y.0 * rhs.s.0),
+			z: E3(self.z.0 * rhs.s.0),
+			o: Eo(self.o.0 * rhs.s.0),
+			inf: Ei(self.inf.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Versor> for Point {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Versor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point.commutator(Versor) -> Multivector
+impl Commutator<Versor> for Point {
+	type Output = Multivector;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     eo    : Eo(self.o.0 * rhs.eoi.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0)
+				- E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0),
+			eo: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			ei: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Point.anti_dot(Versor) -> Versor
+impl AntiDot<Versor> for Point {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13  : -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o  : E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+		//     e23  : -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o  : -E2o(self.o.0 * rhs.e13oi.0) + E2o(self.y.0 * rhs.e123o.0),
+		//     e2i  : -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.o.0 * rhs.e12oi.0) + E3o(self.z.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0),
+		//     e123o: -E123o(self.o.0 * rhs.eoi.0) + E123o(self.o.0 * rhs.s.0) - E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i: -E123i(self.inf.0 * rhs.eoi.0) - E123i(self.inf.0 * rhs.s.0) + E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.inf.0 * rhs.e3o.0) + E12oi(self.o.0 * rhs.e3i.0) + E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0) - E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0),
+			e1i: E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: -E2o(self.o.0 * rhs.e13oi.0) + E2o(self.y.0 * rhs.e123o.0),
+			e2i: -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+			e3o: E3o(self.o.0 * rhs.e12oi.0) + E3o(self.z.0 * rhs.e123o.0),
+			e3i: E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+			eoi: -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0),
+			e123o: -E123o(self.o.0 * rhs.eoi.0) + E123o(self.o.0 * rhs.s.0)
+				- E123o(self.x.0 * rhs.e1o.0)
+				- E123o(self.y.0 * rhs.e2o.0)
+				- E123o(self.z.0 * rhs.e3o.0),
+			e123i: -E123i(self.inf.0 * rhs.eoi.0) - E123i(self.inf.0 * rhs.s.0)
+				+ E123i(self.x.0 * rhs.e1i.0)
+				+ E123i(self.y.0 * rhs.e2i.0)
+				+ E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.inf.0 * rhs.e3o.0)
+				+ E12oi(self.o.0 * rhs.e3i.0)
+				+ E12oi(self.x.0 * rhs.e13.0)
+				+ E12oi(self.y.0 * rhs.e23.0)
+				- E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0)
+				+ E13oi(self.y.0 * rhs.s.0)
+				+ E13oi(self.z.0 * rhs.e23.0),
+			e23oi: E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0)
+				- E23oi(self.x.0 * rhs.s.0)
+				- E23oi(self.y.0 * rhs.e12.0)
+				- E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point OP Multivector:
+
+// Point.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Point {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.inf.0 * rhs.eo.0) - S(self.o.0 * rhs.ei.0) + S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+		//     e1    : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.eoi.0) + Eo(self.o.0 * rhs.s.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.inf.0 * rhs.s.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0) + E12(self.z.0 * rhs.e123.0),
+		//     e13   : -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) + E13(self.x.0 * rhs.e3.0) - E13(self.y.0 * rhs.e123.0) - E13(self.z.0 * rhs.e1.0),
+		//     e1o   : -E1o(self.o.0 * rhs.e1.0) - E1o(self.o.0 * rhs.e1oi.0) + E1o(self.x.0 * rhs.eo.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i   : -E1i(self.inf.0 * rhs.e1.0) + E1i(self.inf.0 * rhs.e1oi.0) + E1i(self.x.0 * rhs.ei.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23   : -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0) + E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+		//     e2o   : -E2o(self.o.0 * rhs.e2.0) - E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) + E2o(self.y.0 * rhs.eo.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i   : -E2i(self.inf.0 * rhs.e2.0) + E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) + E2i(self.y.0 * rhs.ei.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o   : -E3o(self.o.0 * rhs.e3.0) - E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0) + E3o(self.z.0 * rhs.eo.0),
+		//     e3i   : -E3i(self.inf.0 * rhs.e3.0) + E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0) + E3i(self.z.0 * rhs.ei.0),
+		//     eoi   : -Eoi(self.inf.0 * rhs.eo.0) + Eoi(self.o.0 * rhs.ei.0) + Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		//     e123  : E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0) + E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12.0) + E12o(self.o.0 * rhs.e12oi.0) + E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.inf.0 * rhs.e12.0) - E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13.0) + E13o(self.o.0 * rhs.e13oi.0) + E13o(self.x.0 * rhs.e3o.0) - E13o(self.y.0 * rhs.e123o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e13.0) - E13i(self.inf.0 * rhs.e13oi.0) + E13i(self.x.0 * rhs.e3i.0) - E13i(self.y.0 * rhs.e123i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.inf.0 * rhs.e1o.0) - E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0) - E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23.0) + E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.inf.0 * rhs.e23.0) - E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.inf.0 * rhs.e2o.0) - E2oi(self.o.0 * rhs.e2i.0) + E2oi(self.x.0 * rhs.e12oi.0) + E2oi(self.y.0 * rhs.eoi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.inf.0 * rhs.e3o.0) - E3oi(self.o.0 * rhs.e3i.0) + E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0) + E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : -E123o(self.o.0 * rhs.e123.0) - E123o(self.o.0 * rhs.e123oi.0) + E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i : -E123i(self.inf.0 * rhs.e123.0) + E123i(self.inf.0 * rhs.e123oi.0) + E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi : -E12oi(self.inf.0 * rhs.e12o.0) + E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0) + E12oi(self.z.0 * rhs.e123oi.0),
+		//     e13oi : -E13oi(self.inf.0 * rhs.e13o.0) + E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.y.0 * rhs.e123oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi : -E23oi(self.inf.0 * rhs.e23o.0) + E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.x.0 * rhs.e123oi.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		//     e123oi: E123oi(self.inf.0 * rhs.e123o.0) - E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: -S(self.inf.0 * rhs.eo.0) - S(self.o.0 * rhs.ei.0)
+				+ S(self.x.0 * rhs.e1.0)
+				+ S(self.y.0 * rhs.e2.0)
+				+ S(self.z.0 * rhs.e3.0),
+			e1: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.inf.0 * rhs.e2o.0)
+				+ E2(self.o.0 * rhs.e2i.0)
+				+ E2(self.x.0 * rhs.e12.0)
+				+ E2(self.y.0 * rhs.s.0)
+				- E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0)
+				+ E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.o.0 * rhs.s.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			ei: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.inf.0 * rhs.s.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.x.0 * rhs.e2.0)
+				- E12(self.y.0 * rhs.e1.0)
+				+ E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) + E13(self.x.0 * rhs.e3.0)
+				- E13(self.y.0 * rhs.e123.0)
+				- E13(self.z.0 * rhs.e1.0),
+			e1o: -E1o(self.o.0 * rhs.e1.0) - E1o(self.o.0 * rhs.e1oi.0) + E1o(self.x.0 * rhs.eo.0)
+				- E1o(self.y.0 * rhs.e12o.0)
+				- E1o(self.z.0 * rhs.e13o.0),
+			e1i: -E1i(self.inf.0 * rhs.e1.0) + E1i(self.inf.0 * rhs.e1oi.0) + E1i(self.x.0 * rhs.ei.0)
+				- E1i(self.y.0 * rhs.e12i.0)
+				- E1i(self.z.0 * rhs.e13i.0),
+			e23: -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0)
+				+ E23(self.x.0 * rhs.e123.0)
+				+ E23(self.y.0 * rhs.e3.0)
+				- E23(self.z.0 * rhs.e2.0),
+			e2o: -E2o(self.o.0 * rhs.e2.0) - E2o(self.o.0 * rhs.e2oi.0)
+				+ E2o(self.x.0 * rhs.e12o.0)
+				+ E2o(self.y.0 * rhs.eo.0)
+				- E2o(self.z.0 * rhs.e23o.0),
+			e2i: -E2i(self.inf.0 * rhs.e2.0)
+				+ E2i(self.inf.0 * rhs.e2oi.0)
+				+ E2i(self.x.0 * rhs.e12i.0)
+				+ E2i(self.y.0 * rhs.ei.0)
+				- E2i(self.z.0 * rhs.e23i.0),
+			e3o: -E3o(self.o.0 * rhs.e3.0) - E3o(self.o.0 * rhs.e3oi.0)
+				+ E3o(self.x.0 * rhs.e13o.0)
+				+ E3o(self.y.0 * rhs.e23o.0)
+				+ E3o(self.z.0 * rhs.eo.0),
+			e3i: -E3i(self.inf.0 * rhs.e3.0)
+				+ E3i(self.inf.0 * rhs.e3oi.0)
+				+ E3i(self.x.0 * rhs.e13i.0)
+				+ E3i(self.y.0 * rhs.e23i.0)
+				+ E3i(self.z.0 * rhs.ei.0),
+			eoi: -Eoi(self.inf.0 * rhs.eo.0)
+				+ Eoi(self.o.0 * rhs.ei.0)
+				+ Eoi(self.x.0 * rhs.e1oi.0)
+				+ Eoi(self.y.0 * rhs.e2oi.0)
+				+ Eoi(self.z.0 * rhs.e3oi.0),
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0) + E123(self.x.0 * rhs.e23.0)
+				- E123(self.y.0 * rhs.e13.0)
+				+ E123(self.z.0 * rhs.e12.0),
+			e12o: E12o(self.o.0 * rhs.e12.0) + E12o(self.o.0 * rhs.e12oi.0) + E12o(self.x.0 * rhs.e2o.0)
+				- E12o(self.y.0 * rhs.e1o.0)
+				+ E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.inf.0 * rhs.e12.0) - E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.x.0 * rhs.e2i.0)
+				- E12i(self.y.0 * rhs.e1i.0)
+				+ E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13.0) + E13o(self.o.0 * rhs.e13oi.0) + E13o(self.x.0 * rhs.e3o.0)
+				- E13o(self.y.0 * rhs.e123o.0)
+				- E13o(self.z.0 * rhs.e1o.0),
+			e13i: E13i(self.inf.0 * rhs.e13.0) - E13i(self.inf.0 * rhs.e13oi.0) + E13i(self.x.0 * rhs.e3i.0)
+				- E13i(self.y.0 * rhs.e123i.0)
+				- E13i(self.z.0 * rhs.e1i.0),
+			e1oi: E1oi(self.inf.0 * rhs.e1o.0) - E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0)
+				- E1oi(self.y.0 * rhs.e12oi.0)
+				- E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23.0)
+				+ E23o(self.o.0 * rhs.e23oi.0)
+				+ E23o(self.x.0 * rhs.e123o.0)
+				+ E23o(self.y.0 * rhs.e3o.0)
+				- E23o(self.z.0 * rhs.e2o.0),
+			e23i: E23i(self.inf.0 * rhs.e23.0) - E23i(self.inf.0 * rhs.e23oi.0)
+				+ E23i(self.x.0 * rhs.e123i.0)
+				+ E23i(self.y.0 * rhs.e3i.0)
+				- E23i(self.z.0 * rhs.e2i.0),
+			e2oi: E2oi(self.inf.0 * rhs.e2o.0) - E2oi(self.o.0 * rhs.e2i.0)
+				+ E2oi(self.x.0 * rhs.e12oi.0)
+				+ E2oi(self.y.0 * rhs.eoi.0)
+				- E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.inf.0 * rhs.e3o.0) - E3oi(self.o.0 * rhs.e3i.0)
+				+ E3oi(self.x.0 * rhs.e13oi.0)
+				+ E3oi(self.y.0 * rhs.e23oi.0)
+				+ E3oi(self.z.0 * rhs.eoi.0),
+			e123o: -E123o(self.o.0 * rhs.e123.0) - E123o(self.o.0 * rhs.e123oi.0) + E123o(self.x.0 * rhs.e23o.0)
+				- E123o(self.y.0 * rhs.e13o.0)
+				+ E123o(self.z.0 * rhs.e12o.0),
+			e123i: -E123i(self.inf.0 * rhs.e123.0) + E123i(self.inf.0 * rhs.e123oi.0) + E123i(self.x.0 * rhs.e23i.0)
+				- E123i(self.y.0 * rhs.e13i.0)
+				+ E123i(self.z.0 * rhs.e12i.0),
+			e12oi: -E12oi(self.inf.0 * rhs.e12o.0) + E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0)
+				- E12oi(self.y.0 * rhs.e1oi.0)
+				+ E12oi(self.z.0 * rhs.e123oi.0),
+			e13oi: -E13oi(self.inf.0 * rhs.e13o.0) + E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0)
+				- E13oi(self.y.0 * rhs.e123oi.0)
+				- E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: -E23oi(self.inf.0 * rhs.e23o.0)
+				+ E23oi(self.o.0 * rhs.e23i.0)
+				+ E23oi(self.x.0 * rhs.e123oi.0)
+				+ E23oi(self.y.0 * rhs.e3oi.0)
+				- E23oi(self.z.0 * rhs.e2oi.0),
+			e123oi: E123oi(self.inf.0 * rhs.e123o.0) - E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0)
+				- E123oi(self.y.0 * rhs.e13oi.0)
+				+ E123oi(self.z.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Point {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Point {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.inf.0 * rhs.e123o.0) - S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+		//     e1    : E1(self.inf.0 * rhs.e23o.0) - E1(self.o.0 * rhs.e23i.0) + E1(self.x.0 * rhs.e123oi.0) - E1(self.y.0 * rhs.e3oi.0) + E1(self.z.0 * rhs.e2oi.0),
+		//     e2    : -E2(self.inf.0 * rhs.e13o.0) + E2(self.o.0 * rhs.e13i.0) + E2(self.x.0 * rhs.e3oi.0) + E2(self.y.0 * rhs.e123oi.0) - E2(self.z.0 * rhs.e1oi.0),
+		//     e3    : E3(self.inf.0 * rhs.e12o.0) - E3(self.o.0 * rhs.e12i.0) - E3(self.x.0 * rhs.e2oi.0) + E3(self.y.0 * rhs.e1oi.0) + E3(self.z.0 * rhs.e123oi.0),
+		//     eo    : -Eo(self.o.0 * rhs.e123.0) + Eo(self.o.0 * rhs.e123oi.0) + Eo(self.x.0 * rhs.e23o.0) - Eo(self.y.0 * rhs.e13o.0) + Eo(self.z.0 * rhs.e12o.0),
+		//     ei    : Ei(self.inf.0 * rhs.e123.0) + Ei(self.inf.0 * rhs.e123oi.0) - Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0) - Ei(self.z.0 * rhs.e12i.0),
+		//     e12   : -E12(self.inf.0 * rhs.e3o.0) + E12(self.o.0 * rhs.e3i.0) + E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0) - E12(self.z.0 * rhs.eoi.0),
+		//     e13   : E13(self.inf.0 * rhs.e2o.0) - E13(self.o.0 * rhs.e2i.0) - E13(self.x.0 * rhs.e12oi.0) + E13(self.y.0 * rhs.eoi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o   : -E1o(self.o.0 * rhs.e23.0) + E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0) - E1o(self.y.0 * rhs.e3o.0) + E1o(self.z.0 * rhs.e2o.0),
+		//     e1i   : E1i(self.inf.0 * rhs.e23.0) + E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0) + E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+		//     e23   : -E23(self.inf.0 * rhs.e1o.0) + E23(self.o.0 * rhs.e1i.0) - E23(self.x.0 * rhs.eoi.0) - E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o   : E2o(self.o.0 * rhs.e13.0) - E2o(self.o.0 * rhs.e13oi.0) + E2o(self.x.0 * rhs.e3o.0) + E2o(self.y.0 * rhs.e123o.0) - E2o(self.z.0 * rhs.e1o.0),
+		//     e2i   : -E2i(self.inf.0 * rhs.e13.0) - E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.x.0 * rhs.e3i.0) - E2i(self.y.0 * rhs.e123i.0) + E2i(self.z.0 * rhs.e1i.0),
+		//     e3o   : -E3o(self.o.0 * rhs.e12.0) + E3o(self.o.0 * rhs.e12oi.0) - E3o(self.x.0 * rhs.e2o.0) + E3o(self.y.0 * rhs.e1o.0) + E3o(self.z.0 * rhs.e123o.0),
+		//     e3i   : E3i(self.inf.0 * rhs.e12.0) + E3i(self.inf.0 * rhs.e12oi.0) + E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0) - E3i(self.z.0 * rhs.e123i.0),
+		//     eoi   : -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0) + Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+		//     e123  : -E123(self.inf.0 * rhs.eo.0) + E123(self.o.0 * rhs.ei.0) - E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o  : E12o(self.o.0 * rhs.e3.0) - E12o(self.o.0 * rhs.e3oi.0) + E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0) - E12o(self.z.0 * rhs.eo.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e3.0) - E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0) + E12i(self.z.0 * rhs.ei.0),
+		//     e13o  : -E13o(self.o.0 * rhs.e2.0) + E13o(self.o.0 * rhs.e2oi.0) - E13o(self.x.0 * rhs.e12o.0) + E13o(self.y.0 * rhs.eo.0) + E13o(self.z.0 * rhs.e23o.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e2.0) + E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.y.0 * rhs.ei.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi  : -E1oi(self.inf.0 * rhs.e23o.0) - E1oi(self.o.0 * rhs.e23i.0) + E1oi(self.x.0 * rhs.e123.0) - E1oi(self.y.0 * rhs.e3.0) + E1oi(self.z.0 * rhs.e2.0),
+		//     e23o  : E23o(self.o.0 * rhs.e1.0) - E23o(self.o.0 * rhs.e1oi.0) - E23o(self.x.0 * rhs.eo.0) - E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e1.0) - E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.x.0 * rhs.ei.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi  : E2oi(self.inf.0 * rhs.e13o.0) + E2oi(self.o.0 * rhs.e13i.0) + E2oi(self.x.0 * rhs.e3.0) + E2oi(self.y.0 * rhs.e123.0) - E2oi(self.z.0 * rhs.e1.0),
+		//     e3oi  : -E3oi(self.inf.0 * rhs.e12o.0) - E3oi(self.o.0 * rhs.e12i.0) - E3oi(self.x.0 * rhs.e2.0) + E3oi(self.y.0 * rhs.e1.0) + E3oi(self.z.0 * rhs.e123.0),
+		//     e123o : -E123o(self.o.0 * rhs.eoi.0) + E123o(self.o.0 * rhs.s.0) - E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i : -E123i(self.inf.0 * rhs.eoi.0) - E123i(self.inf.0 * rhs.s.0) + E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi : E12oi(self.inf.0 * rhs.e3o.0) + E12oi(self.o.0 * rhs.e3i.0) + E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi : -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi : E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0) - E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		//     e123oi: E123oi(self.inf.0 * rhs.eo.0) + E123oi(self.o.0 * rhs.ei.0) - E123oi(self.x.0 * rhs.e1.0) - E123oi(self.y.0 * rhs.e2.0) - E123oi(self.z.0 * rhs.e3.0),
+		// }
+		Multivector {
+			s: S(self.inf.0 * rhs.e123o.0) - S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0)
+				- S(self.y.0 * rhs.e13oi.0)
+				+ S(self.z.0 * rhs.e12oi.0),
+			e1: E1(self.inf.0 * rhs.e23o.0) - E1(self.o.0 * rhs.e23i.0) + E1(self.x.0 * rhs.e123oi.0)
+				- E1(self.y.0 * rhs.e3oi.0)
+				+ E1(self.z.0 * rhs.e2oi.0),
+			e2: -E2(self.inf.0 * rhs.e13o.0)
+				+ E2(self.o.0 * rhs.e13i.0)
+				+ E2(self.x.0 * rhs.e3oi.0)
+				+ E2(self.y.0 * rhs.e123oi.0)
+				- E2(self.z.0 * rhs.e1oi.0),
+			e3: E3(self.inf.0 * rhs.e12o.0) - E3(self.o.0 * rhs.e12i.0) - E3(self.x.0 * rhs.e2oi.0)
+				+ E3(self.y.0 * rhs.e1oi.0)
+				+ E3(self.z.0 * rhs.e123oi.0),
+			eo: -Eo(self.o.0 * rhs.e123.0) + Eo(self.o.0 * rhs.e123oi.0) + Eo(self.x.0 * rhs.e23o.0)
+				- Eo(self.y.0 * rhs.e13o.0)
+				+ Eo(self.z.0 * rhs.e12o.0),
+			ei: Ei(self.inf.0 * rhs.e123.0) + Ei(self.inf.0 * rhs.e123oi.0) - Ei(self.x.0 * rhs.e23i.0)
+				+ Ei(self.y.0 * rhs.e13i.0)
+				- Ei(self.z.0 * rhs.e12i.0),
+			e12: -E12(self.inf.0 * rhs.e3o.0)
+				+ E12(self.o.0 * rhs.e3i.0)
+				+ E12(self.x.0 * rhs.e13oi.0)
+				+ E12(self.y.0 * rhs.e23oi.0)
+				- E12(self.z.0 * rhs.eoi.0),
+			e13: E13(self.inf.0 * rhs.e2o.0) - E13(self.o.0 * rhs.e2i.0) - E13(self.x.0 * rhs.e12oi.0)
+				+ E13(self.y.0 * rhs.eoi.0)
+				+ E13(self.z.0 * rhs.e23oi.0),
+			e1o: -E1o(self.o.0 * rhs.e23.0) + E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0)
+				- E1o(self.y.0 * rhs.e3o.0)
+				+ E1o(self.z.0 * rhs.e2o.0),
+			e1i: E1i(self.inf.0 * rhs.e23.0) + E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0)
+				+ E1i(self.y.0 * rhs.e3i.0)
+				- E1i(self.z.0 * rhs.e2i.0),
+			e23: -E23(self.inf.0 * rhs.e1o.0) + E23(self.o.0 * rhs.e1i.0)
+				- E23(self.x.0 * rhs.eoi.0)
+				- E23(self.y.0 * rhs.e12oi.0)
+				- E23(self.z.0 * rhs.e13oi.0),
+			e2o: E2o(self.o.0 * rhs.e13.0) - E2o(self.o.0 * rhs.e13oi.0)
+				+ E2o(self.x.0 * rhs.e3o.0)
+				+ E2o(self.y.0 * rhs.e123o.0)
+				- E2o(self.z.0 * rhs.e1o.0),
+			e2i: -E2i(self.inf.0 * rhs.e13.0)
+				- E2i(self.inf.0 * rhs.e13oi.0)
+				- E2i(self.x.0 * rhs.e3i.0)
+				- E2i(self.y.0 * rhs.e123i.0)
+				+ E2i(self.z.0 * rhs.e1i.0),
+			e3o: -E3o(self.o.0 * rhs.e12.0) + E3o(self.o.0 * rhs.e12oi.0) - E3o(self.x.0 * rhs.e2o.0)
+				+ E3o(self.y.0 * rhs.e1o.0)
+				+ E3o(self.z.0 * rhs.e123o.0),
+			e3i: E3i(self.inf.0 * rhs.e12.0) + E3i(self.inf.0 * rhs.e12oi.0) + E3i(self.x.0 * rhs.e2i.0)
+				- E3i(self.y.0 * rhs.e1i.0)
+				- E3i(self.z.0 * rhs.e123i.0),
+			eoi: -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0) + Eoi(self.x.0 * rhs.e23.0)
+				- Eoi(self.y.0 * rhs.e13.0)
+				+ Eoi(self.z.0 * rhs.e12.0),
+			e123: -E123(self.inf.0 * rhs.eo.0) + E123(self.o.0 * rhs.ei.0)
+				- E123(self.x.0 * rhs.e1oi.0)
+				- E123(self.y.0 * rhs.e2oi.0)
+				- E123(self.z.0 * rhs.e3oi.0),
+			e12o: E12o(self.o.0 * rhs.e3.0) - E12o(self.o.0 * rhs.e3oi.0)
+				+ E12o(self.x.0 * rhs.e13o.0)
+				+ E12o(self.y.0 * rhs.e23o.0)
+				- E12o(self.z.0 * rhs.eo.0),
+			e12i: -E12i(self.inf.0 * rhs.e3.0)
+				- E12i(self.inf.0 * rhs.e3oi.0)
+				- E12i(self.x.0 * rhs.e13i.0)
+				- E12i(self.y.0 * rhs.e23i.0)
+				+ E12i(self.z.0 * rhs.ei.0),
+			e13o: -E13o(self.o.0 * rhs.e2.0) + E13o(self.o.0 * rhs.e2oi.0) - E13o(self.x.0 * rhs.e12o.0)
+				+ E13o(self.y.0 * rhs.eo.0)
+				+ E13o(self.z.0 * rhs.e23o.0),
+			e13i: E13i(self.inf.0 * rhs.e2.0) + E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0)
+				- E13i(self.y.0 * rhs.ei.0)
+				- E13i(self.z.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.inf.0 * rhs.e23o.0) - E1oi(self.o.0 * rhs.e23i.0) + E1oi(self.x.0 * rhs.e123.0)
+				- E1oi(self.y.0 * rhs.e3.0)
+				+ E1oi(self.z.0 * rhs.e2.0),
+			e23o: E23o(self.o.0 * rhs.e1.0)
+				- E23o(self.o.0 * rhs.e1oi.0)
+				- E23o(self.x.0 * rhs.eo.0)
+				- E23o(self.y.0 * rhs.e12o.0)
+				- E23o(self.z.0 * rhs.e13o.0),
+			e23i: -E23i(self.inf.0 * rhs.e1.0) - E23i(self.inf.0 * rhs.e1oi.0)
+				+ E23i(self.x.0 * rhs.ei.0)
+				+ E23i(self.y.0 * rhs.e12i.0)
+				+ E23i(self.z.0 * rhs.e13i.0),
+			e2oi: E2oi(self.inf.0 * rhs.e13o.0)
+				+ E2oi(self.o.0 * rhs.e13i.0)
+				+ E2oi(self.x.0 * rhs.e3.0)
+				+ E2oi(self.y.0 * rhs.e123.0)
+				- E2oi(self.z.0 * rhs.e1.0),
+			e3oi: -E3oi(self.inf.0 * rhs.e12o.0) - E3oi(self.o.0 * rhs.e12i.0) - E3oi(self.x.0 * rhs.e2.0)
+				+ E3oi(self.y.0 * rhs.e1.0)
+				+ E3oi(self.z.0 * rhs.e123.0),
+			e123o: -E123o(self.o.0 * rhs.eoi.0) + E123o(self.o.0 * rhs.s.0)
+				- E123o(self.x.0 * rhs.e1o.0)
+				- E123o(self.y.0 * rhs.e2o.0)
+				- E123o(self.z.0 * rhs.e3o.0),
+			e123i: -E123i(self.inf.0 * rhs.eoi.0) - E123i(self.inf.0 * rhs.s.0)
+				+ E123i(self.x.0 * rhs.e1i.0)
+				+ E123i(self.y.0 * rhs.e2i.0)
+				+ E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.inf.0 * rhs.e3o.0)
+				+ E12oi(self.o.0 * rhs.e3i.0)
+				+ E12oi(self.x.0 * rhs.e13.0)
+				+ E12oi(self.y.0 * rhs.e23.0)
+				- E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0)
+				+ E13oi(self.y.0 * rhs.s.0)
+				+ E13oi(self.z.0 * rhs.e23.0),
+			e23oi: E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0)
+				- E23oi(self.x.0 * rhs.s.0)
+				- E23oi(self.y.0 * rhs.e12.0)
+				- E23oi(self.z.0 * rhs.e13.0),
+			e123oi: E123oi(self.inf.0 * rhs.eo.0) + E123oi(self.o.0 * rhs.ei.0)
+				- E123oi(self.x.0 * rhs.e1.0)
+				- E123oi(self.y.0 * rhs.e2.0)
+				- E123oi(self.z.0 * rhs.e3.0),
+		}
+	}
+}
+
+// Point.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Point {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.inf.0 * rhs.eo.0) - S(self.o.0 * rhs.ei.0) + S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+		//     e1    : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.eoi.0) + Eo(self.o.0 * rhs.s.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.inf.0 * rhs.s.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.z.0 * rhs.e123.0),
+		//     e13   : -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) - E13(self.y.0 * rhs.e123.0),
+		//     e1o   : -E1o(self.o.0 * rhs.e1oi.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i   : E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23   : -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0),
+		//     e2o   : -E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i   : E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o   : -E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+		//     e3i   : E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi   : Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		//     e123  : E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : -E123o(self.o.0 * rhs.e123oi.0),
+		//     e123i : E123i(self.inf.0 * rhs.e123oi.0),
+		//     e12oi : E12oi(self.z.0 * rhs.e123oi.0),
+		//     e13oi : -E13oi(self.y.0 * rhs.e123oi.0),
+		//     e23oi : E23oi(self.x.0 * rhs.e123oi.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.inf.0 * rhs.eo.0) - S(self.o.0 * rhs.ei.0)
+				+ S(self.x.0 * rhs.e1.0)
+				+ S(self.y.0 * rhs.e2.0)
+				+ S(self.z.0 * rhs.e3.0),
+			e1: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) + E1(self.x.0 * rhs.s.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.inf.0 * rhs.e2o.0)
+				+ E2(self.o.0 * rhs.e2i.0)
+				+ E2(self.x.0 * rhs.e12.0)
+				+ E2(self.y.0 * rhs.s.0)
+				- E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0)
+				+ E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.o.0 * rhs.s.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			ei: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.inf.0 * rhs.s.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) - E13(self.y.0 * rhs.e123.0),
+			e1o: -E1o(self.o.0 * rhs.e1oi.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0),
+			e2o: -E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: -E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+			e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: -E123o(self.o.0 * rhs.e123oi.0),
+			e123i: E123i(self.inf.0 * rhs.e123oi.0),
+			e12oi: E12oi(self.z.0 * rhs.e123oi.0),
+			e13oi: -E13oi(self.y.0 * rhs.e123oi.0),
+			e23oi: E23oi(self.x.0 * rhs.e123oi.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Point {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Point {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.s.0),
+		//     ei    : Ei(self.inf.0 * rhs.s.0),
+		//     e12   : E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0),
+		//     e13   : E13(self.x.0 * rhs.e3.0) - E13(self.z.0 * rhs.e1.0),
+		//     e1o   : -E1o(self.o.0 * rhs.e1.0) + E1o(self.x.0 * rhs.eo.0),
+		//     e1i   : -E1i(self.inf.0 * rhs.e1.0) + E1i(self.x.0 * rhs.ei.0),
+		//     e23   : E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+		//     e2o   : -E2o(self.o.0 * rhs.e2.0) + E2o(self.y.0 * rhs.eo.0),
+		//     e2i   : -E2i(self.inf.0 * rhs.e2.0) + E2i(self.y.0 * rhs.ei.0),
+		//     e3o   : -E3o(self.o.0 * rhs.e3.0) + E3o(self.z.0 * rhs.eo.0),
+		//     e3i   : -E3i(self.inf.0 * rhs.e3.0) + E3i(self.z.0 * rhs.ei.0),
+		//     eoi   : -Eoi(self.inf.0 * rhs.eo.0) + Eoi(self.o.0 * rhs.ei.0),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12.0) + E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0),
+		//     e12i  : E12i(self.inf.0 * rhs.e12.0) + E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13.0) + E13o(self.x.0 * rhs.e3o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e13.0) + E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.inf.0 * rhs.e1o.0) - E1oi(self.o.0 * rhs.e1i.0) + E1oi(self.x.0 * rhs.eoi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.inf.0 * rhs.e23.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.inf.0 * rhs.e2o.0) - E2oi(self.o.0 * rhs.e2i.0) + E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.inf.0 * rhs.e3o.0) - E3oi(self.o.0 * rhs.e3i.0) + E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : -E123o(self.o.0 * rhs.e123.0) + E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i : -E123i(self.inf.0 * rhs.e123.0) + E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi : -E12oi(self.inf.0 * rhs.e12o.0) + E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi : -E13oi(self.inf.0 * rhs.e13o.0) + E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi : -E23oi(self.inf.0 * rhs.e23o.0) + E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		//     e123oi: E123oi(self.inf.0 * rhs.e123o.0) - E123oi(self.o.0 * rhs.e123i.0) + E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.x.wedge(rhs.s),
+			e2: self.y.wedge(rhs.s),
+			e3: self.z.wedge(rhs.s),
+			eo: self.o.wedge(rhs.s),
+			ei: self.inf.wedge(rhs.s),
+			e12: self.x.wedge(rhs.e2) + self.y.wedge(rhs.e1),
+			e13: self.x.wedge(rhs.e3) + self.z.wedge(rhs.e1),
+			e1o: self.o.wedge(rhs.e1) + self.x.wedge(rhs.eo),
+			e1i: self.inf.wedge(rhs.e1) + self.x.wedge(rhs.ei),
+			e23: self.y.wedge(rhs.e3) + self.z.wedge(rhs.e2),
+			e2o: self.o.wedge(rhs.e2) + self.y.wedge(rhs.eo),
+			e2i: self.inf.wedge(rhs.e2) + self.y.wedge(rhs.ei),
+			e3o: self.o.wedge(rhs.e3) + self.z.wedge(rhs.eo),
+			e3i: self.inf.wedge(rhs.e3) + self.z.wedge(rhs.ei),
+			eoi: self.inf.wedge(rhs.eo) + self.o.wedge(rhs.ei),
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e12o: self.o.wedge(rhs.e12) + self.x.wedge(rhs.e2o) + self.y.wedge(rhs.e1o),
+			e12i: self.inf.wedge(rhs.e12) + self.x.wedge(rhs.e2i) + self.y.wedge(rhs.e1i),
+			e13o: self.o.wedge(rhs.e13) + self.x.wedge(rhs.e3o) + self.z.wedge(rhs.e1o),
+			e13i: self.inf.wedge(rhs.e13) + self.x.wedge(rhs.e3i) + self.z.wedge(rhs.e1i),
+			e1oi: self.inf.wedge(rhs.e1o) + self.o.wedge(rhs.e1i) + self.x.wedge(rhs.eoi),
+			e23o: self.o.wedge(rhs.e23) + self.y.wedge(rhs.e3o) + self.z.wedge(rhs.e2o),
+			e23i: self.inf.wedge(rhs.e23) + self.y.wedge(rhs.e3i) + self.z.wedge(rhs.e2i),
+			e2oi: self.inf.wedge(rhs.e2o) + self.o.wedge(rhs.e2i) + self.y.wedge(rhs.eoi),
+			e3oi: self.inf.wedge(rhs.e3o) + self.o.wedge(rhs.e3i) + self.z.wedge(rhs.eoi),
+			e123o: self.o.wedge(rhs.e123) + self.x.wedge(rhs.e23o) + self.y.wedge(rhs.e13o) + self.z.wedge(rhs.e12o),
+			e123i: self.inf.wedge(rhs.e123) + self.x.wedge(rhs.e23i) + self.y.wedge(rhs.e13i) + self.z.wedge(rhs.e12i),
+			e12oi: self.inf.wedge(rhs.e12o) + self.o.wedge(rhs.e12i) + self.x.wedge(rhs.e2oi) + self.y.wedge(rhs.e1oi),
+			e13oi: self.inf.wedge(rhs.e13o) + self.o.wedge(rhs.e13i) + self.x.wedge(rhs.e3oi) + self.z.wedge(rhs.e1oi),
+			e23oi: self.inf.wedge(rhs.e23o) + self.o.wedge(rhs.e23i) + self.y.wedge(rhs.e3oi) + self.z.wedge(rhs.e2oi),
+			e123oi: self.inf.wedge(rhs.e123o)
+				+ self.o.wedge(rhs.e123i)
+				+ self.x.wedge(rhs.e23oi)
+				+ self.y.wedge(rhs.e13oi)
+				+ self.z.wedge(rhs.e12oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Point {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Point.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Point {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.inf.0 * rhs.e123o.0) - S(self.o.0 * rhs.e123i.0) + S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+		//     e1    : E1(self.x.0 * rhs.e123oi.0),
+		//     e2    : E2(self.y.0 * rhs.e123oi.0),
+		//     e3    : E3(self.z.0 * rhs.e123oi.0),
+		//     eo    : Eo(self.o.0 * rhs.e123oi.0),
+		//     ei    : Ei(self.inf.0 * rhs.e123oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.inf.anti_wedge(rhs.e123o)
+				+ self.o.anti_wedge(rhs.e123i)
+				+ self.x.anti_wedge(rhs.e23oi)
+				+ self.y.anti_wedge(rhs.e13oi)
+				+ self.z.anti_wedge(rhs.e12oi),
+			e1: self.x.anti_wedge(rhs.e123oi),
+			e2: self.y.anti_wedge(rhs.e123oi),
+			e3: self.z.anti_wedge(rhs.e123oi),
+			eo: self.o.anti_wedge(rhs.e123oi),
+			ei: self.inf.anti_wedge(rhs.e123oi),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Point {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Point {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.inf.0 * rhs.eo.0) - S(self.o.0 * rhs.ei.0) + S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+		//     e1    : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     eo    : Eo(self.o.0 * rhs.eoi.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.z.0 * rhs.e123.0),
+		//     e13   : -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) - E13(self.y.0 * rhs.e123.0),
+		//     e1o   : -E1o(self.o.0 * rhs.e1oi.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i   : E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23   : -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0),
+		//     e2o   : -E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i   : E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o   : -E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+		//     e3i   : E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi   : Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		//     e123  : E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : -E123o(self.o.0 * rhs.e123oi.0),
+		//     e123i : E123i(self.inf.0 * rhs.e123oi.0),
+		//     e12oi : E12oi(self.z.0 * rhs.e123oi.0),
+		//     e13oi : -E13oi(self.y.0 * rhs.e123oi.0),
+		//     e23oi : E23oi(self.x.0 * rhs.e123oi.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.inf.0 * rhs.eo.0) - S(self.o.0 * rhs.ei.0)
+				+ S(self.x.0 * rhs.e1.0)
+				+ S(self.y.0 * rhs.e2.0)
+				+ S(self.z.0 * rhs.e3.0),
+			e1: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0)
+				- E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0),
+			eo: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			ei: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: -E12(self.inf.0 * rhs.e12o.0) - E12(self.o.0 * rhs.e12i.0) + E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.inf.0 * rhs.e13o.0) - E13(self.o.0 * rhs.e13i.0) - E13(self.y.0 * rhs.e123.0),
+			e1o: -E1o(self.o.0 * rhs.e1oi.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: E1i(self.inf.0 * rhs.e1oi.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: -E23(self.inf.0 * rhs.e23o.0) - E23(self.o.0 * rhs.e23i.0) + E23(self.x.0 * rhs.e123.0),
+			e2o: -E2o(self.o.0 * rhs.e2oi.0) + E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.inf.0 * rhs.e2oi.0) + E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: -E3o(self.o.0 * rhs.e3oi.0) + E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+			e3i: E3i(self.inf.0 * rhs.e3oi.0) + E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: -E123o(self.o.0 * rhs.e123oi.0),
+			e123i: E123i(self.inf.0 * rhs.e123oi.0),
+			e12oi: E12oi(self.z.0 * rhs.e123oi.0),
+			e13oi: -E13oi(self.y.0 * rhs.e123oi.0),
+			e23oi: E23oi(self.x.0 * rhs.e123oi.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Point.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Point {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.inf.0 * rhs.eo.0) - S(self.o.0 * rhs.ei.0) + S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.o.0 * rhs.s.0),
+		//     ei    : Ei(self.inf.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.inf.0 * rhs.eo.0) - S(self.o.0 * rhs.ei.0)
+				+ S(self.x.0 * rhs.e1.0)
+				+ S(self.y.0 * rhs.e2.0)
+				+ S(self.z.0 * rhs.e3.0),
+			e1: E1(self.x.0 * rhs.s.0),
+			e2: E2(self.y.0 * rhs.s.0),
+			e3: E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.o.0 * rhs.s.0),
+			ei: Ei(self.inf.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Point.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Point {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.inf.0 * rhs.eo.0) - S(self.o.0 * rhs.ei.0) + S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0)
+		-S(self.inf.0 * rhs.eo.0) - S(self.o.0 * rhs.ei.0)
+			+ S(self.x.0 * rhs.e1.0)
+			+ S(self.y.0 * rhs.e2.0)
+			+ S(self.z.0 * rhs.e3.0)
+	}
+}
+
+// Point.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Point {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.inf.0 * rhs.e3o.0) + E3(self.o.0 * rhs.e3i.0) + E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     eo    : Eo(self.o.0 * rhs.eoi.0) + Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : -Ei(self.inf.0 * rhs.eoi.0) + Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0),
+		//     e13   : E13(self.x.0 * rhs.e3.0) - E13(self.z.0 * rhs.e1.0),
+		//     e1o   : -E1o(self.o.0 * rhs.e1.0) + E1o(self.x.0 * rhs.eo.0),
+		//     e1i   : -E1i(self.inf.0 * rhs.e1.0) + E1i(self.x.0 * rhs.ei.0),
+		//     e23   : E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+		//     e2o   : -E2o(self.o.0 * rhs.e2.0) + E2o(self.y.0 * rhs.eo.0),
+		//     e2i   : -E2i(self.inf.0 * rhs.e2.0) + E2i(self.y.0 * rhs.ei.0),
+		//     e3o   : -E3o(self.o.0 * rhs.e3.0) + E3o(self.z.0 * rhs.eo.0),
+		//     e3i   : -E3i(self.inf.0 * rhs.e3.0) + E3i(self.z.0 * rhs.ei.0),
+		//     eoi   : -Eoi(self.inf.0 * rhs.eo.0) + Eoi(self.o.0 * rhs.ei.0),
+		//     e123  : E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : -E123o(self.o.0 * rhs.e123.0) + E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i : -E123i(self.inf.0 * rhs.e123.0) + E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi : -E12oi(self.inf.0 * rhs.e12o.0) + E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi : -E13oi(self.inf.0 * rhs.e13o.0) + E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi : -E23oi(self.inf.0 * rhs.e23o.0) + E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.inf.0 * rhs.e1o.0) + E1(self.o.0 * rhs.e1i.0)
+				- E1(self.y.0 * rhs.e12.0)
+				- E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.inf.0 * rhs.e2o.0) + E2(self.o.0 * rhs.e2i.0) + E2(self.x.0 * rhs.e12.0)
+				- E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.inf.0 * rhs.e3o.0)
+				+ E3(self.o.0 * rhs.e3i.0)
+				+ E3(self.x.0 * rhs.e13.0)
+				+ E3(self.y.0 * rhs.e23.0),
+			eo: Eo(self.o.0 * rhs.eoi.0)
+				+ Eo(self.x.0 * rhs.e1o.0)
+				+ Eo(self.y.0 * rhs.e2o.0)
+				+ Eo(self.z.0 * rhs.e3o.0),
+			ei: -Ei(self.inf.0 * rhs.eoi.0)
+				+ Ei(self.x.0 * rhs.e1i.0)
+				+ Ei(self.y.0 * rhs.e2i.0)
+				+ Ei(self.z.0 * rhs.e3i.0),
+			e12: E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0),
+			e13: E13(self.x.0 * rhs.e3.0) - E13(self.z.0 * rhs.e1.0),
+			e1o: -E1o(self.o.0 * rhs.e1.0) + E1o(self.x.0 * rhs.eo.0),
+			e1i: -E1i(self.inf.0 * rhs.e1.0) + E1i(self.x.0 * rhs.ei.0),
+			e23: E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+			e2o: -E2o(self.o.0 * rhs.e2.0) + E2o(self.y.0 * rhs.eo.0),
+			e2i: -E2i(self.inf.0 * rhs.e2.0) + E2i(self.y.0 * rhs.ei.0),
+			e3o: -E3o(self.o.0 * rhs.e3.0) + E3o(self.z.0 * rhs.eo.0),
+			e3i: -E3i(self.inf.0 * rhs.e3.0) + E3i(self.z.0 * rhs.ei.0),
+			eoi: -Eoi(self.inf.0 * rhs.eo.0) + Eoi(self.o.0 * rhs.ei.0),
+			e123: E123(self.inf.0 * rhs.e123o.0) + E123(self.o.0 * rhs.e123i.0),
+			e12o: E12o(self.o.0 * rhs.e12oi.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: -E12i(self.inf.0 * rhs.e12oi.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.o.0 * rhs.e13oi.0) - E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.inf.0 * rhs.e13oi.0) - E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.o.0 * rhs.e23oi.0) + E23o(self.x.0 * rhs.e123o.0),
+			e23i: -E23i(self.inf.0 * rhs.e23oi.0) + E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: -E123o(self.o.0 * rhs.e123.0) + E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0)
+				+ E123o(self.z.0 * rhs.e12o.0),
+			e123i: -E123i(self.inf.0 * rhs.e123.0) + E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0)
+				+ E123i(self.z.0 * rhs.e12i.0),
+			e12oi: -E12oi(self.inf.0 * rhs.e12o.0) + E12oi(self.o.0 * rhs.e12i.0) + E12oi(self.x.0 * rhs.e2oi.0)
+				- E12oi(self.y.0 * rhs.e1oi.0),
+			e13oi: -E13oi(self.inf.0 * rhs.e13o.0) + E13oi(self.o.0 * rhs.e13i.0) + E13oi(self.x.0 * rhs.e3oi.0)
+				- E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: -E23oi(self.inf.0 * rhs.e23o.0) + E23oi(self.o.0 * rhs.e23i.0) + E23oi(self.y.0 * rhs.e3oi.0)
+				- E23oi(self.z.0 * rhs.e2oi.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Point.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Point {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.e123oi.0),
+		//     e2    : E2(self.y.0 * rhs.e123oi.0),
+		//     e3    : E3(self.z.0 * rhs.e123oi.0),
+		//     eo    : Eo(self.o.0 * rhs.e123oi.0),
+		//     ei    : Ei(self.inf.0 * rhs.e123oi.0),
+		//     e12   : E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13   : -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o   : E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0),
+		//     e1i   : E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+		//     e23   : -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o   : -E2o(self.o.0 * rhs.e13oi.0) + E2o(self.y.0 * rhs.e123o.0),
+		//     e2i   : -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+		//     e3o   : E3o(self.o.0 * rhs.e12oi.0) + E3o(self.z.0 * rhs.e123o.0),
+		//     e3i   : E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+		//     eoi   : -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0),
+		//     e123  : -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o  : -E12o(self.o.0 * rhs.e3oi.0) + E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+		//     e12i  : -E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+		//     e13o  : E13o(self.o.0 * rhs.e2oi.0) - E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+		//     e13i  : E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi  : -E1oi(self.inf.0 * rhs.e23o.0) - E1oi(self.o.0 * rhs.e23i.0) + E1oi(self.x.0 * rhs.e123.0),
+		//     e23o  : -E23o(self.o.0 * rhs.e1oi.0) - E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+		//     e23i  : -E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi  : E2oi(self.inf.0 * rhs.e13o.0) + E2oi(self.o.0 * rhs.e13i.0) + E2oi(self.y.0 * rhs.e123.0),
+		//     e3oi  : -E3oi(self.inf.0 * rhs.e12o.0) - E3oi(self.o.0 * rhs.e12i.0) + E3oi(self.z.0 * rhs.e123.0),
+		//     e123o : -E123o(self.o.0 * rhs.eoi.0) + E123o(self.o.0 * rhs.s.0) - E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i : -E123i(self.inf.0 * rhs.eoi.0) - E123i(self.inf.0 * rhs.s.0) + E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi : E12oi(self.inf.0 * rhs.e3o.0) + E12oi(self.o.0 * rhs.e3i.0) + E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi : -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi : E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0) - E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		//     e123oi: E123oi(self.inf.0 * rhs.eo.0) + E123oi(self.o.0 * rhs.ei.0) - E123oi(self.x.0 * rhs.e1.0) - E123oi(self.y.0 * rhs.e2.0) - E123oi(self.z.0 * rhs.e3.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.x.0 * rhs.e123oi.0),
+			e2: E2(self.y.0 * rhs.e123oi.0),
+			e3: E3(self.z.0 * rhs.e123oi.0),
+			eo: Eo(self.o.0 * rhs.e123oi.0),
+			ei: Ei(self.inf.0 * rhs.e123oi.0),
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.o.0 * rhs.e23oi.0) + E1o(self.x.0 * rhs.e123o.0),
+			e1i: E1i(self.inf.0 * rhs.e23oi.0) - E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: -E2o(self.o.0 * rhs.e13oi.0) + E2o(self.y.0 * rhs.e123o.0),
+			e2i: -E2i(self.inf.0 * rhs.e13oi.0) - E2i(self.y.0 * rhs.e123i.0),
+			e3o: E3o(self.o.0 * rhs.e12oi.0) + E3o(self.z.0 * rhs.e123o.0),
+			e3i: E3i(self.inf.0 * rhs.e12oi.0) - E3i(self.z.0 * rhs.e123i.0),
+			eoi: -Eoi(self.inf.0 * rhs.e123o.0) - Eoi(self.o.0 * rhs.e123i.0),
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: -E12o(self.o.0 * rhs.e3oi.0) + E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+			e12i: -E12i(self.inf.0 * rhs.e3oi.0) - E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+			e13o: E13o(self.o.0 * rhs.e2oi.0) - E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+			e13i: E13i(self.inf.0 * rhs.e2oi.0) + E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: -E1oi(self.inf.0 * rhs.e23o.0) - E1oi(self.o.0 * rhs.e23i.0) + E1oi(self.x.0 * rhs.e123.0),
+			e23o: -E23o(self.o.0 * rhs.e1oi.0) - E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+			e23i: -E23i(self.inf.0 * rhs.e1oi.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: E2oi(self.inf.0 * rhs.e13o.0) + E2oi(self.o.0 * rhs.e13i.0) + E2oi(self.y.0 * rhs.e123.0),
+			e3oi: -E3oi(self.inf.0 * rhs.e12o.0) - E3oi(self.o.0 * rhs.e12i.0) + E3oi(self.z.0 * rhs.e123.0),
+			e123o: -E123o(self.o.0 * rhs.eoi.0) + E123o(self.o.0 * rhs.s.0)
+				- E123o(self.x.0 * rhs.e1o.0)
+				- E123o(self.y.0 * rhs.e2o.0)
+				- E123o(self.z.0 * rhs.e3o.0),
+			e123i: -E123i(self.inf.0 * rhs.eoi.0) - E123i(self.inf.0 * rhs.s.0)
+				+ E123i(self.x.0 * rhs.e1i.0)
+				+ E123i(self.y.0 * rhs.e2i.0)
+				+ E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.inf.0 * rhs.e3o.0)
+				+ E12oi(self.o.0 * rhs.e3i.0)
+				+ E12oi(self.x.0 * rhs.e13.0)
+				+ E12oi(self.y.0 * rhs.e23.0)
+				- E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.inf.0 * rhs.e2o.0) - E13oi(self.o.0 * rhs.e2i.0) - E13oi(self.x.0 * rhs.e12.0)
+				+ E13oi(self.y.0 * rhs.s.0)
+				+ E13oi(self.z.0 * rhs.e23.0),
+			e23oi: E23oi(self.inf.0 * rhs.e1o.0) + E23oi(self.o.0 * rhs.e1i.0)
+				- E23oi(self.x.0 * rhs.s.0)
+				- E23oi(self.y.0 * rhs.e12.0)
+				- E23oi(self.z.0 * rhs.e13.0),
+			e123oi: E123oi(self.inf.0 * rhs.eo.0) + E123oi(self.o.0 * rhs.ei.0)
+				- E123oi(self.x.0 * rhs.e1.0)
+				- E123oi(self.y.0 * rhs.e2.0)
+				- E123oi(self.z.0 * rhs.e3.0),
+		}
+	}
+}
@@ -0,0 +1,6622 @@
+//! # PointPair
+//!
+//! ## Operations
+//! ```text
+//! PointPair.geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(PointPair) -> Multivector
+//! PointPair.dot(PointPair) -> S
+//! PointPair.wedge(PointPair) -> Sphere
+//! PointPair.left_contraction(PointPair) -> S
+//! PointPair.right_contraction(PointPair) -> S
+//! PointPair.scalar_product(PointPair) -> S
+//! PointPair.commutator(PointPair) -> PointPair
+//! PointPair.anti_dot(PointPair) -> E123oi
+//! PointPair.geometric(Vec3) -> Multivector
+//! Vec3.geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(Vec3) -> Versor
+//! Vec3.anti_geometric(PointPair) -> Versor
+//! PointPair.dot(Vec3) -> Point
+//! Vec3.dot(PointPair) -> Point
+//! PointPair.wedge(Vec3) -> Circle
+//! Vec3.wedge(PointPair) -> Circle
+//! Vec3.left_contraction(PointPair) -> Point
+//! PointPair.right_contraction(Vec3) -> Point
+//! PointPair.commutator(Vec3) -> Point
+//! Vec3.commutator(PointPair) -> Point
+//! PointPair.anti_dot(Vec3) -> Sphere
+//! Vec3.anti_dot(PointPair) -> Sphere
+//! PointPair.geometric(Point) -> Multivector
+//! Point.geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(Point) -> Versor
+//! Point.anti_geometric(PointPair) -> Versor
+//! PointPair.dot(Point) -> Point
+//! Point.dot(PointPair) -> Point
+//! PointPair.wedge(Point) -> Circle
+//! Point.wedge(PointPair) -> Circle
+//! Point.left_contraction(PointPair) -> Point
+//! PointPair.right_contraction(Point) -> Point
+//! PointPair.commutator(Point) -> Point
+//! Point.commutator(PointPair) -> Point
+//! PointPair.anti_dot(Point) -> Sphere
+//! Point.anti_dot(PointPair) -> Sphere
+//! PointPair.geometric(FlatPoint) -> Versor
+//! FlatPoint.geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.anti_geometric(PointPair) -> Multivector
+//! PointPair.dot(FlatPoint) -> S
+//! FlatPoint.dot(PointPair) -> S
+//! PointPair.wedge(FlatPoint) -> Plane
+//! FlatPoint.wedge(PointPair) -> Plane
+//! PointPair.left_contraction(FlatPoint) -> S
+//! FlatPoint.left_contraction(PointPair) -> S
+//! PointPair.right_contraction(FlatPoint) -> S
+//! FlatPoint.right_contraction(PointPair) -> S
+//! PointPair.scalar_product(FlatPoint) -> S
+//! FlatPoint.scalar_product(PointPair) -> S
+//! PointPair.commutator(FlatPoint) -> PointPair
+//! FlatPoint.commutator(PointPair) -> PointPair
+//! PointPair.anti_dot(FlatPoint) -> E123oi
+//! FlatPoint.anti_dot(PointPair) -> E123oi
+//! PointPair.geometric(Line) -> Multivector
+//! Line.geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(Line) -> Versor
+//! Line.anti_geometric(PointPair) -> Versor
+//! PointPair.dot(Line) -> Point
+//! Line.dot(PointPair) -> Point
+//! PointPair.wedge(Line) -> E123oi
+//! Line.wedge(PointPair) -> E123oi
+//! PointPair.anti_wedge(Line) -> S
+//! Line.anti_wedge(PointPair) -> S
+//! PointPair.left_contraction(Line) -> Point
+//! Line.right_contraction(PointPair) -> Point
+//! PointPair.commutator(Line) -> Circle
+//! Line.commutator(PointPair) -> Circle
+//! PointPair.anti_dot(Line) -> Sphere
+//! Line.anti_dot(PointPair) -> Sphere
+//! PointPair.geometric(Circle) -> Multivector
+//! Circle.geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(Circle) -> Versor
+//! Circle.anti_geometric(PointPair) -> Versor
+//! PointPair.dot(Circle) -> Point
+//! Circle.dot(PointPair) -> Point
+//! PointPair.wedge(Circle) -> E123oi
+//! Circle.wedge(PointPair) -> E123oi
+//! PointPair.anti_wedge(Circle) -> S
+//! Circle.anti_wedge(PointPair) -> S
+//! PointPair.left_contraction(Circle) -> Point
+//! Circle.right_contraction(PointPair) -> Point
+//! PointPair.commutator(Circle) -> Circle
+//! Circle.commutator(PointPair) -> Circle
+//! PointPair.anti_dot(Circle) -> Sphere
+//! Circle.anti_dot(PointPair) -> Sphere
+//! PointPair.geometric(Plane) -> Versor
+//! Plane.geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(PointPair) -> Multivector
+//! PointPair.dot(Plane) -> PointPair
+//! Plane.dot(PointPair) -> PointPair
+//! PointPair.anti_wedge(Plane) -> Point
+//! Plane.anti_wedge(PointPair) -> Point
+//! PointPair.left_contraction(Plane) -> PointPair
+//! Plane.right_contraction(PointPair) -> PointPair
+//! PointPair.commutator(Plane) -> Sphere
+//! Plane.commutator(PointPair) -> Sphere
+//! PointPair.anti_dot(Plane) -> Circle
+//! Plane.anti_dot(PointPair) -> Circle
+//! PointPair.geometric(Sphere) -> Versor
+//! Sphere.geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(PointPair) -> Multivector
+//! PointPair.dot(Sphere) -> PointPair
+//! Sphere.dot(PointPair) -> PointPair
+//! PointPair.anti_wedge(Sphere) -> Point
+//! Sphere.anti_wedge(PointPair) -> Point
+//! PointPair.left_contraction(Sphere) -> PointPair
+//! Sphere.right_contraction(PointPair) -> PointPair
+//! PointPair.commutator(Sphere) -> Sphere
+//! Sphere.commutator(PointPair) -> Sphere
+//! PointPair.anti_dot(Sphere) -> Circle
+//! Sphere.anti_dot(PointPair) -> Circle
+//! PointPair.geometric(Rotor) -> Versor
+//! Rotor.geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(PointPair) -> Multivector
+//! PointPair.dot(Rotor) -> Versor
+//! Rotor.dot(PointPair) -> Versor
+//! PointPair.wedge(Rotor) -> Versor
+//! Rotor.wedge(PointPair) -> Versor
+//! PointPair.left_contraction(Rotor) -> S
+//! Rotor.left_contraction(PointPair) -> Versor
+//! PointPair.right_contraction(Rotor) -> Versor
+//! Rotor.right_contraction(PointPair) -> S
+//! PointPair.scalar_product(Rotor) -> S
+//! Rotor.scalar_product(PointPair) -> S
+//! PointPair.commutator(Rotor) -> PointPair
+//! Rotor.commutator(PointPair) -> PointPair
+//! PointPair.anti_dot(Rotor) -> Multivector
+//! Rotor.anti_dot(PointPair) -> Multivector
+//! PointPair.geometric(Translator) -> Versor
+//! Translator.geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(Translator) -> Multivector
+//! Translator.anti_geometric(PointPair) -> Multivector
+//! PointPair.dot(Translator) -> Versor
+//! Translator.dot(PointPair) -> Versor
+//! PointPair.wedge(Translator) -> Versor
+//! Translator.wedge(PointPair) -> Versor
+//! PointPair.left_contraction(Translator) -> S
+//! Translator.left_contraction(PointPair) -> Versor
+//! PointPair.right_contraction(Translator) -> Versor
+//! Translator.right_contraction(PointPair) -> S
+//! PointPair.scalar_product(Translator) -> S
+//! Translator.scalar_product(PointPair) -> S
+//! PointPair.commutator(Translator) -> PointPair
+//! Translator.commutator(PointPair) -> PointPair
+//! PointPair.anti_dot(Translator) -> Multivector
+//! Translator.anti_dot(PointPair) -> Multivector
+//! PointPair.geometric(Versor) -> Versor
+//! Versor.geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(Versor) -> Multivector
+//! Versor.anti_geometric(PointPair) -> Multivector
+//! PointPair.dot(Versor) -> Versor
+//! Versor.dot(PointPair) -> Versor
+//! PointPair.wedge(Versor) -> Versor
+//! Versor.wedge(PointPair) -> Versor
+//! PointPair.anti_wedge(Versor) -> Point
+//! Versor.anti_wedge(PointPair) -> Point
+//! PointPair.left_contraction(Versor) -> Versor
+//! Versor.left_contraction(PointPair) -> Versor
+//! PointPair.right_contraction(Versor) -> Versor
+//! Versor.right_contraction(PointPair) -> Versor
+//! PointPair.scalar_product(Versor) -> S
+//! Versor.scalar_product(PointPair) -> S
+//! PointPair.commutator(Versor) -> Versor
+//! Versor.commutator(PointPair) -> Versor
+//! PointPair.anti_dot(Versor) -> Multivector
+//! Versor.anti_dot(PointPair) -> Multivector
+//! PointPair.geometric(Multivector) -> Multivector
+//! Multivector.geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(PointPair) -> Multivector
+//! PointPair.dot(Multivector) -> Multivector
+//! Multivector.dot(PointPair) -> Multivector
+//! PointPair.wedge(Multivector) -> Multivector
+//! Multivector.wedge(PointPair) -> Multivector
+//! PointPair.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(PointPair) -> Multivector
+//! PointPair.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(PointPair) -> Multivector
+//! PointPair.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(PointPair) -> Multivector
+//! PointPair.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(PointPair) -> S
+//! PointPair.commutator(Multivector) -> Multivector
+//! Multivector.commutator(PointPair) -> Multivector
+//! PointPair.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(PointPair) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointPair {
+	pub e12: E12,
+	pub e13: E13,
+	pub e1o: E1o,
+	pub e1i: E1i,
+	pub e23: E23,
+	pub e2o: E2o,
+	pub e2i: E2i,
+	pub e3o: E3o,
+	pub e3i: E3i,
+	pub eoi: Eoi,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for PointPair {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for PointPair {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for PointPair {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.e12.abs_diff_eq(&other.e12, epsilon)
+			&& self.e13.abs_diff_eq(&other.e13, epsilon)
+			&& self.e1o.abs_diff_eq(&other.e1o, epsilon)
+			&& self.e1i.abs_diff_eq(&other.e1i, epsilon)
+			&& self.e23.abs_diff_eq(&other.e23, epsilon)
+			&& self.e2o.abs_diff_eq(&other.e2o, epsilon)
+			&& self.e2i.abs_diff_eq(&other.e2i, epsilon)
+			&& self.e3o.abs_diff_eq(&other.e3o, epsilon)
+			&& self.e3i.abs_diff_eq(&other.e3i, epsilon)
+			&& self.eoi.abs_diff_eq(&other.eoi, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for PointPair {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.e12.relative_eq(&other.e12, epsilon, max_relative)
+			&& self.e13.relative_eq(&other.e13, epsilon, max_relative)
+			&& self.e1o.relative_eq(&other.e1o, epsilon, max_relative)
+			&& self.e1i.relative_eq(&other.e1i, epsilon, max_relative)
+			&& self.e23.relative_eq(&other.e23, epsilon, max_relative)
+			&& self.e2o.relative_eq(&other.e2o, epsilon, max_relative)
+			&& self.e2i.relative_eq(&other.e2i, epsilon, max_relative)
+			&& self.e3o.relative_eq(&other.e3o, epsilon, max_relative)
+			&& self.e3i.relative_eq(&other.e3i, epsilon, max_relative)
+			&& self.eoi.relative_eq(&other.eoi, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for PointPair {
+	type Output = Circle;
+	fn rcompl(self) -> Self::Output {
+		Circle {
+			e123: self.eoi.rcompl(),
+			e12o: self.e3i.rcompl(),
+			e12i: self.e3o.rcompl(),
+			e13o: self.e2i.rcompl(),
+			e13i: self.e2o.rcompl(),
+			e1oi: self.e23.rcompl(),
+			e23o: self.e1i.rcompl(),
+			e23i: self.e1o.rcompl(),
+			e2oi: self.e13.rcompl(),
+			e3oi: self.e12.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for PointPair {
+	type Output = Circle;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for PointPair {
+	type Output = Circle;
+	fn lcompl(self) -> Self::Output {
+		Circle {
+			e123: self.eoi.lcompl(),
+			e12o: self.e3i.lcompl(),
+			e12i: self.e3o.lcompl(),
+			e13o: self.e2i.lcompl(),
+			e13i: self.e2o.lcompl(),
+			e1oi: self.e23.lcompl(),
+			e23o: self.e1i.lcompl(),
+			e23i: self.e1o.lcompl(),
+			e2oi: self.e13.lcompl(),
+			e3oi: self.e12.lcompl(),
+		}
+	}
+}
+
+impl Reverse for PointPair {
+	fn rev(self) -> Self {
+		PointPair {
+			e12: -self.e12,
+			e13: -self.e13,
+			e1o: -self.e1o,
+			e1i: -self.e1i,
+			e23: -self.e23,
+			e2o: -self.e2o,
+			e2i: -self.e2i,
+			e3o: -self.e3o,
+			e3i: -self.e3i,
+			eoi: -self.eoi,
+		}
+	}
+}
+
+impl AntiReverse for PointPair {
+	fn arev(self) -> Self {
+		PointPair {
+			e12: -self.e12,
+			e13: -self.e13,
+			e1o: -self.e1o,
+			e1i: -self.e1i,
+			e23: -self.e23,
+			e2o: -self.e2o,
+			e2i: -self.e2i,
+			e3o: -self.e3o,
+			e3i: -self.e3i,
+			eoi: -self.eoi,
+		}
+	}
+}
+
+impl Involute for PointPair {
+	fn involute(self) -> Self {
+		PointPair {
+			e12: self.e12,
+			e13: self.e13,
+			e1o: self.e1o,
+			e1i: self.e1i,
+			e23: self.e23,
+			e2o: self.e2o,
+			e2i: self.e2i,
+			e3o: self.e3o,
+			e3i: self.e3i,
+			eoi: self.eoi,
+		}
+	}
+}
+
+impl Conjugate for PointPair {
+	fn conjugate(self) -> Self {
+		PointPair {
+			e12: -self.e12,
+			e13: -self.e13,
+			e1o: -self.e1o,
+			e1i: -self.e1i,
+			e23: -self.e23,
+			e2o: -self.e2o,
+			e2i: -self.e2i,
+			e3o: -self.e3o,
+			e3i: -self.e3i,
+			eoi: -self.eoi,
+		}
+	}
+}
+
+impl Dual for PointPair {
+	type Output = Circle;
+	fn dual(self) -> Self::Output {
+		Circle {
+			e123: self.eoi.dual(),
+			e12o: self.e3i.dual(),
+			e12i: self.e3o.dual(),
+			e13o: self.e2i.dual(),
+			e13i: self.e2o.dual(),
+			e1oi: self.e23.dual(),
+			e23o: self.e1i.dual(),
+			e23i: self.e1o.dual(),
+			e2oi: self.e13.dual(),
+			e3oi: self.e12.dual(),
+		}
+	}
+}
+
+// Omitted: PointPair.square()  (not a scalar)
+// Omitted: Norm for PointPair  (the bulk and weight need a diagonal metric)
+
+impl PointPair {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 10] {
+		[
+			self.e12.0, self.e13.0, self.e1o.0, self.e1i.0, self.e23.0, self.e2o.0, self.e2i.0, self.e3o.0, self.e3i.0,
+			self.eoi.0,
+		]
+	}
+}
+
+impl From<[f64; 10]> for PointPair {
+	fn from(a: [f64; 10]) -> Self {
+		PointPair {
+			e12: E12(a[0]),
+			e13: E13(a[1]),
+			e1o: E1o(a[2]),
+			e1i: E1i(a[3]),
+			e23: E23(a[4]),
+			e2o: E2o(a[5]),
+			e2i: E2i(a[6]),
+			e3o: E3o(a[7]),
+			e3i: E3i(a[8]),
+			eoi: Eoi(a[9]),
+		}
+	}
+}
+
+impl From<PointPair> for [f64; 10] {
+	fn from(v: PointPair) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<PointPair> for Multivector {
+	fn from(v: PointPair) -> Self {
+		Multivector {
+			e12: v.e12,
+			e13: v.e13,
+			e1o: v.e1o,
+			e1i: v.e1i,
+			e23: v.e23,
+			e2o: v.e2o,
+			e2i: v.e2i,
+			e3o: v.e3o,
+			e3i: v.e3i,
+			eoi: v.eoi,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for PointPair {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `PointPair` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.eo.0 == 0.0
+			&& mv.ei.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e12o.0 == 0.0
+			&& mv.e12i.0 == 0.0
+			&& mv.e13o.0 == 0.0
+			&& mv.e13i.0 == 0.0
+			&& mv.e1oi.0 == 0.0
+			&& mv.e23o.0 == 0.0
+			&& mv.e23i.0 == 0.0
+			&& mv.e2oi.0 == 0.0
+			&& mv.e3oi.0 == 0.0
+			&& mv.e123o.0 == 0.0
+			&& mv.e123i.0 == 0.0
+			&& mv.e12oi.0 == 0.0
+			&& mv.e13oi.0 == 0.0
+			&& mv.e23oi.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(PointPair {
+				e12: mv.e12,
+				e13: mv.e13,
+				e1o: mv.e1o,
+				e1i: mv.e1i,
+				e23: mv.e23,
+				e2o: mv.e2o,
+				e2i: mv.e2i,
+				e3o: mv.e3o,
+				e3i: mv.e3i,
+				eoi: mv.eoi,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP Vec3:
+
+// PointPair.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for PointPair {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o  : -E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+		//     e12i  : -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : -E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : E1oi(self.eoi.0 * rhs.x.0),
+		//     e23o  : -E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+		//     e23i  : -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi  : E3oi(self.eoi.0 * rhs.z.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+			e3: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+			eo: -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+			e12o: -E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+			e12i: -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+			e13o: -E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+			e13i: -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+			e1oi: E1oi(self.eoi.0 * rhs.x.0),
+			e23o: -E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+			e23i: -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+			e2oi: E2oi(self.eoi.0 * rhs.y.0),
+			e3oi: E3oi(self.eoi.0 * rhs.z.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for PointPair {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(Vec3) -> Versor
+impl AntiGeometric<Vec3> for PointPair {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.eoi.0 * rhs.z.0),
+		//     e13  : E13(self.eoi.0 * rhs.y.0),
+		//     e1o  : E1o(self.e2o.0 * rhs.z.0) - E1o(self.e3o.0 * rhs.y.0),
+		//     e1i  : -E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+		//     e23  : -E23(self.eoi.0 * rhs.x.0),
+		//     e2o  : -E2o(self.e1o.0 * rhs.z.0) + E2o(self.e3o.0 * rhs.x.0),
+		//     e2i  : E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+		//     e3o  : E3o(self.e1o.0 * rhs.y.0) - E3o(self.e2o.0 * rhs.x.0),
+		//     e3i  : -E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+		//     eoi  : Eoi(self.e12.0 * rhs.z.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+		//     e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.eoi.0 * rhs.z.0),
+			e13: E13(self.eoi.0 * rhs.y.0),
+			e1o: E1o(self.e2o.0 * rhs.z.0) - E1o(self.e3o.0 * rhs.y.0),
+			e1i: -E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+			e23: -E23(self.eoi.0 * rhs.x.0),
+			e2o: -E2o(self.e1o.0 * rhs.z.0) + E2o(self.e3o.0 * rhs.x.0),
+			e2i: E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+			e3o: E3o(self.e1o.0 * rhs.y.0) - E3o(self.e2o.0 * rhs.x.0),
+			e3i: -E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+			eoi: Eoi(self.e12.0 * rhs.z.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+			e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0),
+		}
+	}
+}
+
+// PointPair.dot(Vec3) -> Point
+impl Dot<Vec3> for PointPair {
+	type Output = Point;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+		//     y  : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+		//     z  : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		//     o  : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		// }
+		Point {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+			z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+			o: -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for PointPair {
+	type Output = Point;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// PointPair.wedge(Vec3) -> Circle
+impl Wedge<Vec3> for PointPair {
+	type Output = Circle;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o: -E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+		//     e12i: -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o: -E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+		//     e13i: -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi: E1oi(self.eoi.0 * rhs.x.0),
+		//     e23o: -E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+		//     e23i: -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi: E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi: E3oi(self.eoi.0 * rhs.z.0),
+		// }
+		Circle {
+			e123: self.e12.wedge(rhs.z) + self.e13.wedge(rhs.y) + self.e23.wedge(rhs.x),
+			e12o: self.e1o.wedge(rhs.y) + self.e2o.wedge(rhs.x),
+			e12i: self.e1i.wedge(rhs.y) + self.e2i.wedge(rhs.x),
+			e13o: self.e1o.wedge(rhs.z) + self.e3o.wedge(rhs.x),
+			e13i: self.e1i.wedge(rhs.z) + self.e3i.wedge(rhs.x),
+			e1oi: self.eoi.wedge(rhs.x),
+			e23o: self.e2o.wedge(rhs.z) + self.e3o.wedge(rhs.y),
+			e23i: self.e2i.wedge(rhs.z) + self.e3i.wedge(rhs.y),
+			e2oi: self.eoi.wedge(rhs.y),
+			e3oi: self.eoi.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for PointPair {
+	type Output = Circle;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for PointPair {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for PointPair {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for PointPair {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// PointPair.right_contraction(Vec3) -> Point
+impl RightContraction<Vec3> for PointPair {
+	type Output = Point;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+		//     y  : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+		//     z  : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		//     o  : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		// }
+		Point {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+			z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+			o: -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for PointPair {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// PointPair.commutator(Vec3) -> Point
+impl Commutator<Vec3> for PointPair {
+	type Output = Point;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+		//     y  : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+		//     z  : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		//     o  : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		// }
+		Point {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+			z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+			o: -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		}
+	}
+}
+
+// PointPair.anti_dot(Vec3) -> Sphere
+impl AntiDot<Vec3> for PointPair {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0),
+		// }
+		Sphere {
+			e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP Point:
+
+// PointPair.geometric(Point) -> Multivector
+impl Geometric<Point> for PointPair {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) - E1(self.e1i.0 * rhs.o.0) - E1(self.e1o.0 * rhs.inf.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) - E2(self.e2i.0 * rhs.o.0) - E2(self.e2o.0 * rhs.inf.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) - E3(self.e3i.0 * rhs.o.0) - E3(self.e3o.0 * rhs.inf.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0) - Eo(self.eoi.0 * rhs.o.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o  : E12o(self.e12.0 * rhs.o.0) - E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+		//     e12i  : E12i(self.e12.0 * rhs.inf.0) - E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : E13o(self.e13.0 * rhs.o.0) - E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+		//     e13i  : E13i(self.e13.0 * rhs.inf.0) - E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.o.0) + E1oi(self.e1o.0 * rhs.inf.0) + E1oi(self.eoi.0 * rhs.x.0),
+		//     e23o  : E23o(self.e23.0 * rhs.o.0) - E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+		//     e23i  : E23i(self.e23.0 * rhs.inf.0) - E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : -E2oi(self.e2i.0 * rhs.o.0) + E2oi(self.e2o.0 * rhs.inf.0) + E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi  : -E3oi(self.e3i.0 * rhs.o.0) + E3oi(self.e3o.0 * rhs.inf.0) + E3oi(self.eoi.0 * rhs.z.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0)
+				- E1(self.e1i.0 * rhs.o.0)
+				- E1(self.e1o.0 * rhs.inf.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0)
+				- E2(self.e2i.0 * rhs.o.0)
+				- E2(self.e2o.0 * rhs.inf.0),
+			e3: -E3(self.e13.0 * rhs.x.0)
+				- E3(self.e23.0 * rhs.y.0)
+				- E3(self.e3i.0 * rhs.o.0)
+				- E3(self.e3o.0 * rhs.inf.0),
+			eo: -Eo(self.e1o.0 * rhs.x.0)
+				- Eo(self.e2o.0 * rhs.y.0)
+				- Eo(self.e3o.0 * rhs.z.0)
+				- Eo(self.eoi.0 * rhs.o.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+			e12o: E12o(self.e12.0 * rhs.o.0) - E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+			e12i: E12i(self.e12.0 * rhs.inf.0) - E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+			e13o: E13o(self.e13.0 * rhs.o.0) - E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+			e13i: E13i(self.e13.0 * rhs.inf.0) - E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+			e1oi: -E1oi(self.e1i.0 * rhs.o.0) + E1oi(self.e1o.0 * rhs.inf.0) + E1oi(self.eoi.0 * rhs.x.0),
+			e23o: E23o(self.e23.0 * rhs.o.0) - E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+			e23i: E23i(self.e23.0 * rhs.inf.0) - E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+			e2oi: -E2oi(self.e2i.0 * rhs.o.0) + E2oi(self.e2o.0 * rhs.inf.0) + E2oi(self.eoi.0 * rhs.y.0),
+			e3oi: -E3oi(self.e3i.0 * rhs.o.0) + E3oi(self.e3o.0 * rhs.inf.0) + E3oi(self.eoi.0 * rhs.z.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for PointPair {
+	type Output = Multivector;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(Point) -> Versor
+impl AntiGeometric<Point> for PointPair {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e3i.0 * rhs.o.0) - E12(self.e3o.0 * rhs.inf.0) - E12(self.eoi.0 * rhs.z.0),
+		//     e13  : -E13(self.e2i.0 * rhs.o.0) + E13(self.e2o.0 * rhs.inf.0) + E13(self.eoi.0 * rhs.y.0),
+		//     e1o  : -E1o(self.e23.0 * rhs.o.0) + E1o(self.e2o.0 * rhs.z.0) - E1o(self.e3o.0 * rhs.y.0),
+		//     e1i  : E1i(self.e23.0 * rhs.inf.0) - E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+		//     e23  : E23(self.e1i.0 * rhs.o.0) - E23(self.e1o.0 * rhs.inf.0) - E23(self.eoi.0 * rhs.x.0),
+		//     e2o  : E2o(self.e13.0 * rhs.o.0) - E2o(self.e1o.0 * rhs.z.0) + E2o(self.e3o.0 * rhs.x.0),
+		//     e2i  : -E2i(self.e13.0 * rhs.inf.0) + E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.o.0) + E3o(self.e1o.0 * rhs.y.0) - E3o(self.e2o.0 * rhs.x.0),
+		//     e3i  : E3i(self.e12.0 * rhs.inf.0) - E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+		//     eoi  : Eoi(self.e12.0 * rhs.z.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+		//     e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0) + E123o(self.eoi.0 * rhs.o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0) + E123i(self.eoi.0 * rhs.inf.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.e3i.0 * rhs.o.0) - E12oi(self.e3o.0 * rhs.inf.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.e2i.0 * rhs.o.0) + E13oi(self.e2o.0 * rhs.inf.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.e1i.0 * rhs.o.0) - E23oi(self.e1o.0 * rhs.inf.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e3i.0 * rhs.o.0) - E12(self.e3o.0 * rhs.inf.0) - E12(self.eoi.0 * rhs.z.0),
+			e13: -E13(self.e2i.0 * rhs.o.0) + E13(self.e2o.0 * rhs.inf.0) + E13(self.eoi.0 * rhs.y.0),
+			e1o: -E1o(self.e23.0 * rhs.o.0) + E1o(self.e2o.0 * rhs.z.0) - E1o(self.e3o.0 * rhs.y.0),
+			e1i: E1i(self.e23.0 * rhs.inf.0) - E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+			e23: E23(self.e1i.0 * rhs.o.0) - E23(self.e1o.0 * rhs.inf.0) - E23(self.eoi.0 * rhs.x.0),
+			e2o: E2o(self.e13.0 * rhs.o.0) - E2o(self.e1o.0 * rhs.z.0) + E2o(self.e3o.0 * rhs.x.0),
+			e2i: -E2i(self.e13.0 * rhs.inf.0) + E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+			e3o: -E3o(self.e12.0 * rhs.o.0) + E3o(self.e1o.0 * rhs.y.0) - E3o(self.e2o.0 * rhs.x.0),
+			e3i: E3i(self.e12.0 * rhs.inf.0) - E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+			eoi: Eoi(self.e12.0 * rhs.z.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+			e123o: E123o(self.e1o.0 * rhs.x.0)
+				+ E123o(self.e2o.0 * rhs.y.0)
+				+ E123o(self.e3o.0 * rhs.z.0)
+				+ E123o(self.eoi.0 * rhs.o.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0)
+				+ E123i(self.eoi.0 * rhs.inf.0),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0)
+				- E12oi(self.e23.0 * rhs.y.0)
+				- E12oi(self.e3i.0 * rhs.o.0)
+				- E12oi(self.e3o.0 * rhs.inf.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0)
+				+ E13oi(self.e2i.0 * rhs.o.0)
+				+ E13oi(self.e2o.0 * rhs.inf.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0)
+				- E23oi(self.e1i.0 * rhs.o.0)
+				- E23oi(self.e1o.0 * rhs.inf.0),
+		}
+	}
+}
+
+// PointPair.dot(Point) -> Point
+impl Dot<Point> for PointPair {
+	type Output = Point;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) - E1(self.e1i.0 * rhs.o.0) - E1(self.e1o.0 * rhs.inf.0),
+		//     y  : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) - E2(self.e2i.0 * rhs.o.0) - E2(self.e2o.0 * rhs.inf.0),
+		//     z  : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) - E3(self.e3i.0 * rhs.o.0) - E3(self.e3o.0 * rhs.inf.0),
+		//     o  : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0) - Eo(self.eoi.0 * rhs.o.0),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0),
+		// }
+		Point {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0)
+				- E1(self.e1i.0 * rhs.o.0)
+				- E1(self.e1o.0 * rhs.inf.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0)
+				- E2(self.e2i.0 * rhs.o.0)
+				- E2(self.e2o.0 * rhs.inf.0),
+			z: -E3(self.e13.0 * rhs.x.0)
+				- E3(self.e23.0 * rhs.y.0)
+				- E3(self.e3i.0 * rhs.o.0)
+				- E3(self.e3o.0 * rhs.inf.0),
+			o: -Eo(self.e1o.0 * rhs.x.0)
+				- Eo(self.e2o.0 * rhs.y.0)
+				- Eo(self.e3o.0 * rhs.z.0)
+				- Eo(self.eoi.0 * rhs.o.0),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point> for PointPair {
+	type Output = Point;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// PointPair.wedge(Point) -> Circle
+impl Wedge<Point> for PointPair {
+	type Output = Circle;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o: E12o(self.e12.0 * rhs.o.0) - E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+		//     e12i: E12i(self.e12.0 * rhs.inf.0) - E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o: E13o(self.e13.0 * rhs.o.0) - E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+		//     e13i: E13i(self.e13.0 * rhs.inf.0) - E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi: -E1oi(self.e1i.0 * rhs.o.0) + E1oi(self.e1o.0 * rhs.inf.0) + E1oi(self.eoi.0 * rhs.x.0),
+		//     e23o: E23o(self.e23.0 * rhs.o.0) - E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+		//     e23i: E23i(self.e23.0 * rhs.inf.0) - E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi: -E2oi(self.e2i.0 * rhs.o.0) + E2oi(self.e2o.0 * rhs.inf.0) + E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi: -E3oi(self.e3i.0 * rhs.o.0) + E3oi(self.e3o.0 * rhs.inf.0) + E3oi(self.eoi.0 * rhs.z.0),
+		// }
+		Circle {
+			e123: self.e12.wedge(rhs.z) + self.e13.wedge(rhs.y) + self.e23.wedge(rhs.x),
+			e12o: self.e12.wedge(rhs.o) + self.e1o.wedge(rhs.y) + self.e2o.wedge(rhs.x),
+			e12i: self.e12.wedge(rhs.inf) + self.e1i.wedge(rhs.y) + self.e2i.wedge(rhs.x),
+			e13o: self.e13.wedge(rhs.o) + self.e1o.wedge(rhs.z) + self.e3o.wedge(rhs.x),
+			e13i: self.e13.wedge(rhs.inf) + self.e1i.wedge(rhs.z) + self.e3i.wedge(rhs.x),
+			e1oi: self.e1i.wedge(rhs.o) + self.e1o.wedge(rhs.inf) + self.eoi.wedge(rhs.x),
+			e23o: self.e23.wedge(rhs.o) + self.e2o.wedge(rhs.z) + self.e3o.wedge(rhs.y),
+			e23i: self.e23.wedge(rhs.inf) + self.e2i.wedge(rhs.z) + self.e3i.wedge(rhs.y),
+			e2oi: self.e2i.wedge(rhs.o) + self.e2o.wedge(rhs.inf) + self.eoi.wedge(rhs.y),
+			e3oi: self.e3i.wedge(rhs.o) + self.e3o.wedge(rhs.inf) + self.eoi.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point> for PointPair {
+	type Output = Circle;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point> for PointPair {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point> for PointPair {
+	type Output = Zero;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point> for PointPair {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// PointPair.right_contraction(Point) -> Point
+impl RightContraction<Point> for PointPair {
+	type Output = Point;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) - E1(self.e1i.0 * rhs.o.0) - E1(self.e1o.0 * rhs.inf.0),
+		//     y  : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) - E2(self.e2i.0 * rhs.o.0) - E2(self.e2o.0 * rhs.inf.0),
+		//     z  : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) - E3(self.e3i.0 * rhs.o.0) - E3(self.e3o.0 * rhs.inf.0),
+		//     o  : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0) - Eo(self.eoi.0 * rhs.o.0),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0),
+		// }
+		Point {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0)
+				- E1(self.e1i.0 * rhs.o.0)
+				- E1(self.e1o.0 * rhs.inf.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0)
+				- E2(self.e2i.0 * rhs.o.0)
+				- E2(self.e2o.0 * rhs.inf.0),
+			z: -E3(self.e13.0 * rhs.x.0)
+				- E3(self.e23.0 * rhs.y.0)
+				- E3(self.e3i.0 * rhs.o.0)
+				- E3(self.e3o.0 * rhs.inf.0),
+			o: -Eo(self.e1o.0 * rhs.x.0)
+				- Eo(self.e2o.0 * rhs.y.0)
+				- Eo(self.e3o.0 * rhs.z.0)
+				- Eo(self.eoi.0 * rhs.o.0),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0),
+		}
+	}
+}
+
+impl ScalarProduct<Point> for PointPair {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// PointPair.commutator(Point) -> Point
+impl Commutator<Point> for PointPair {
+	type Output = Point;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) - E1(self.e1i.0 * rhs.o.0) - E1(self.e1o.0 * rhs.inf.0),
+		//     y  : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) - E2(self.e2i.0 * rhs.o.0) - E2(self.e2o.0 * rhs.inf.0),
+		//     z  : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) - E3(self.e3i.0 * rhs.o.0) - E3(self.e3o.0 * rhs.inf.0),
+		//     o  : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0) - Eo(self.eoi.0 * rhs.o.0),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0),
+		// }
+		Point {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0)
+				- E1(self.e1i.0 * rhs.o.0)
+				- E1(self.e1o.0 * rhs.inf.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0)
+				- E2(self.e2i.0 * rhs.o.0)
+				- E2(self.e2o.0 * rhs.inf.0),
+			z: -E3(self.e13.0 * rhs.x.0)
+				- E3(self.e23.0 * rhs.y.0)
+				- E3(self.e3i.0 * rhs.o.0)
+				- E3(self.e3o.0 * rhs.inf.0),
+			o: -Eo(self.e1o.0 * rhs.x.0)
+				- Eo(self.e2o.0 * rhs.y.0)
+				- Eo(self.e3o.0 * rhs.z.0)
+				- Eo(self.eoi.0 * rhs.o.0),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0),
+		}
+	}
+}
+
+// PointPair.anti_dot(Point) -> Sphere
+impl AntiDot<Point> for PointPair {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0) + E123o(self.eoi.0 * rhs.o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0) + E123i(self.eoi.0 * rhs.inf.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.e3i.0 * rhs.o.0) - E12oi(self.e3o.0 * rhs.inf.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.e2i.0 * rhs.o.0) + E13oi(self.e2o.0 * rhs.inf.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.e1i.0 * rhs.o.0) - E23oi(self.e1o.0 * rhs.inf.0),
+		// }
+		Sphere {
+			e123o: E123o(self.e1o.0 * rhs.x.0)
+				+ E123o(self.e2o.0 * rhs.y.0)
+				+ E123o(self.e3o.0 * rhs.z.0)
+				+ E123o(self.eoi.0 * rhs.o.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0)
+				+ E123i(self.eoi.0 * rhs.inf.0),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0)
+				- E12oi(self.e23.0 * rhs.y.0)
+				- E12oi(self.e3i.0 * rhs.o.0)
+				- E12oi(self.e3o.0 * rhs.inf.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0)
+				+ E13oi(self.e2i.0 * rhs.o.0)
+				+ E13oi(self.e2o.0 * rhs.inf.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0)
+				- E23oi(self.e1i.0 * rhs.o.0)
+				- E23oi(self.e1o.0 * rhs.inf.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP FlatPoint:
+
+// PointPair.geometric(FlatPoint) -> Versor
+impl Geometric<FlatPoint> for PointPair {
+	type Output = Versor;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13  : E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.eoi.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.eoi.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.eoi.0),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e2o.0 * rhs.e1i.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0) - E13oi(self.e1o.0 * rhs.e3i.0) + E13oi(self.e3o.0 * rhs.e1i.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0) - E23oi(self.e2o.0 * rhs.e3i.0) + E23oi(self.e3o.0 * rhs.e2i.0),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e1o.0 * rhs.eoi.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+			e2o: E2o(self.e2o.0 * rhs.eoi.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: E3o(self.e3o.0 * rhs.eoi.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e2o.0 * rhs.e1i.0),
+			e13oi: E13oi(self.e13.0 * rhs.eoi.0) - E13oi(self.e1o.0 * rhs.e3i.0) + E13oi(self.e3o.0 * rhs.e1i.0),
+			e23oi: E23oi(self.e23.0 * rhs.eoi.0) - E23oi(self.e2o.0 * rhs.e3i.0) + E23oi(self.e3o.0 * rhs.e2i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for PointPair {
+	type Output = Versor;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(FlatPoint) -> Multivector
+impl AntiGeometric<FlatPoint> for PointPair {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23.0 * rhs.eoi.0) - E1(self.e2o.0 * rhs.e3i.0) + E1(self.e3o.0 * rhs.e2i.0),
+		//     e2    : -E2(self.e13.0 * rhs.eoi.0) + E2(self.e1o.0 * rhs.e3i.0) - E2(self.e3o.0 * rhs.e1i.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0) - E3(self.e1o.0 * rhs.e2i.0) + E3(self.e2o.0 * rhs.e1i.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1o.0 * rhs.e1i.0) - E123(self.e2o.0 * rhs.e2i.0) - E123(self.e3o.0 * rhs.e3i.0),
+		//     e12o  : -E12o(self.e3o.0 * rhs.eoi.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.e3i.0 * rhs.eoi.0) + E12i(self.eoi.0 * rhs.e3i.0),
+		//     e13o  : E13o(self.e2o.0 * rhs.eoi.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.eoi.0) - E13i(self.eoi.0 * rhs.e2i.0),
+		//     e1oi  : -E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3o.0 * rhs.e2i.0),
+		//     e23o  : -E23o(self.e1o.0 * rhs.eoi.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.eoi.0) + E23i(self.eoi.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e3o.0 * rhs.e1i.0),
+		//     e3oi  : -E3oi(self.e1o.0 * rhs.e2i.0) + E3oi(self.e2o.0 * rhs.e1i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1o.0 * rhs.e1i.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23.0 * rhs.eoi.0) - E1(self.e2o.0 * rhs.e3i.0) + E1(self.e3o.0 * rhs.e2i.0),
+			e2: -E2(self.e13.0 * rhs.eoi.0) + E2(self.e1o.0 * rhs.e3i.0) - E2(self.e3o.0 * rhs.e1i.0),
+			e3: E3(self.e12.0 * rhs.eoi.0) - E3(self.e1o.0 * rhs.e2i.0) + E3(self.e2o.0 * rhs.e1i.0),
+			eo: Default::default(),
+			ei: Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1o.0 * rhs.e1i.0) - E123(self.e2o.0 * rhs.e2i.0) - E123(self.e3o.0 * rhs.e3i.0),
+			e12o: -E12o(self.e3o.0 * rhs.eoi.0),
+			e12i: -E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.e3i.0 * rhs.eoi.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0),
+			e13o: E13o(self.e2o.0 * rhs.eoi.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.eoi.0)
+				- E13i(self.eoi.0 * rhs.e2i.0),
+			e1oi: -E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3o.0 * rhs.e2i.0),
+			e23o: -E23o(self.e1o.0 * rhs.eoi.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.eoi.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e3o.0 * rhs.e1i.0),
+			e3oi: -E3oi(self.e1o.0 * rhs.e2i.0) + E3oi(self.e2o.0 * rhs.e1i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1o.0 * rhs.e1i.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// PointPair.dot(FlatPoint) -> S
+impl Dot<FlatPoint> for PointPair {
+	type Output = S;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for PointPair {
+	type Output = S;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// PointPair.wedge(FlatPoint) -> Plane
+impl Wedge<FlatPoint> for PointPair {
+	type Output = Plane;
+	fn wedge(self, rhs: FlatPoint) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e2o.0 * rhs.e1i.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0) - E13oi(self.e1o.0 * rhs.e3i.0) + E13oi(self.e3o.0 * rhs.e1i.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0) - E23oi(self.e2o.0 * rhs.e3i.0) + E23oi(self.e3o.0 * rhs.e2i.0),
+		// }
+		Plane {
+			e123i: self.e12.wedge(rhs.e3i) + self.e13.wedge(rhs.e2i) + self.e23.wedge(rhs.e1i),
+			e12oi: self.e12.wedge(rhs.eoi) + self.e1o.wedge(rhs.e2i) + self.e2o.wedge(rhs.e1i),
+			e13oi: self.e13.wedge(rhs.eoi) + self.e1o.wedge(rhs.e3i) + self.e3o.wedge(rhs.e1i),
+			e23oi: self.e23.wedge(rhs.eoi) + self.e2o.wedge(rhs.e3i) + self.e3o.wedge(rhs.e2i),
+		}
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for PointPair {
+	type Output = Plane;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<FlatPoint> for PointPair {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for PointPair {
+	type Output = Zero;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// PointPair.left_contraction(FlatPoint) -> S
+impl LeftContraction<FlatPoint> for PointPair {
+	type Output = S;
+	fn left_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// PointPair.right_contraction(FlatPoint) -> S
+impl RightContraction<FlatPoint> for PointPair {
+	type Output = S;
+	fn right_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// PointPair.scalar_product(FlatPoint) -> S
+impl ScalarProduct<FlatPoint> for PointPair {
+	type Output = S;
+	fn scalar_product(self, rhs: FlatPoint) -> Self::Output {
+		// S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// PointPair.commutator(FlatPoint) -> PointPair
+impl Commutator<FlatPoint> for PointPair {
+	type Output = PointPair;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13: E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o: E1o(self.e1o.0 * rhs.eoi.0),
+		//     e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23: E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o: E2o(self.e2o.0 * rhs.eoi.0),
+		//     e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o: E3o(self.e3o.0 * rhs.eoi.0),
+		//     e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi: -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		// }
+		PointPair {
+			e12: E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e1o.0 * rhs.eoi.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+			e2o: E2o(self.e2o.0 * rhs.eoi.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: E3o(self.e3o.0 * rhs.eoi.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// PointPair.anti_dot(FlatPoint) -> E123oi
+impl AntiDot<FlatPoint> for PointPair {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// -E123oi(self.e1o.0 * rhs.e1i.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0)
+		-E123oi(self.e1o.0 * rhs.e1i.0)
+			- E123oi(self.e2o.0 * rhs.e2i.0)
+			- E123oi(self.e3o.0 * rhs.e3i.0)
+			- E123oi(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP PointPair:
+
+// PointPair.geometric(PointPair) -> Versor
+impl Geometric<PointPair> for PointPair {
+	type Output = Versor;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : -E12(self.e13.0 * rhs.e23.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o  : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.eoi.0) - E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) + E123i(self.e23.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: -E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				- E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0),
+			e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0)
+				+ E123o(self.e1o.0 * rhs.e23.0)
+				+ E123o(self.e23.0 * rhs.e1o.0)
+				- E123o(self.e2o.0 * rhs.e13.0)
+				+ E123o(self.e3o.0 * rhs.e12.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0)
+				+ E123i(self.e1i.0 * rhs.e23.0)
+				+ E123i(self.e23.0 * rhs.e1i.0)
+				- E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e3i.0 * rhs.e12.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e1i.0 * rhs.e2o.0)
+				- E12oi(self.e1o.0 * rhs.e2i.0)
+				- E12oi(self.e2i.0 * rhs.e1o.0)
+				+ E12oi(self.e2o.0 * rhs.e1i.0)
+				+ E12oi(self.eoi.0 * rhs.e12.0),
+			e13oi: E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0)
+				- E13oi(self.e1o.0 * rhs.e3i.0)
+				- E13oi(self.e3i.0 * rhs.e1o.0)
+				+ E13oi(self.e3o.0 * rhs.e1i.0)
+				+ E13oi(self.eoi.0 * rhs.e13.0),
+			e23oi: E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e2i.0 * rhs.e3o.0)
+				- E23oi(self.e2o.0 * rhs.e3i.0)
+				- E23oi(self.e3i.0 * rhs.e2o.0)
+				+ E23oi(self.e3o.0 * rhs.e2i.0)
+				+ E23oi(self.eoi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for PointPair {
+	type Output = Versor;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(PointPair) -> Multivector
+impl AntiGeometric<PointPair> for PointPair {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23.0 * rhs.eoi.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e2o.0 * rhs.e3i.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.e3o.0 * rhs.e2i.0) + E1(self.eoi.0 * rhs.e23.0),
+		//     e2    : -E2(self.e13.0 * rhs.eoi.0) - E2(self.e1i.0 * rhs.e3o.0) + E2(self.e1o.0 * rhs.e3i.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.e3o.0 * rhs.e1i.0) - E2(self.eoi.0 * rhs.e13.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0) + E3(self.e1i.0 * rhs.e2o.0) - E3(self.e1o.0 * rhs.e2i.0) - E3(self.e2i.0 * rhs.e1o.0) + E3(self.e2o.0 * rhs.e1i.0) + E3(self.eoi.0 * rhs.e12.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) - Eo(self.e1o.0 * rhs.e23.0) - Eo(self.e23.0 * rhs.e1o.0) + Eo(self.e2o.0 * rhs.e13.0) - Eo(self.e3o.0 * rhs.e12.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e23.0 * rhs.e1i.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e1i.0 * rhs.e1o.0) - E123(self.e1o.0 * rhs.e1i.0) + E123(self.e2i.0 * rhs.e2o.0) - E123(self.e2o.0 * rhs.e2i.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.e3o.0 * rhs.e3i.0),
+		//     e12o  : E12o(self.e13.0 * rhs.e1o.0) - E12o(self.e1o.0 * rhs.e13.0) + E12o(self.e23.0 * rhs.e2o.0) - E12o(self.e2o.0 * rhs.e23.0) - E12o(self.e3o.0 * rhs.eoi.0) + E12o(self.eoi.0 * rhs.e3o.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e1i.0 * rhs.e13.0) - E12i(self.e23.0 * rhs.e2i.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.eoi.0) + E12i(self.eoi.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e12.0 * rhs.e1o.0) + E13o(self.e1o.0 * rhs.e12.0) + E13o(self.e23.0 * rhs.e3o.0) + E13o(self.e2o.0 * rhs.eoi.0) - E13o(self.e3o.0 * rhs.e23.0) - E13o(self.eoi.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e3i.0 * rhs.e23.0) - E13i(self.eoi.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) - E1oi(self.e2i.0 * rhs.e3o.0) - E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.e3o.0 * rhs.e2i.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e1o.0 * rhs.eoi.0) + E23o(self.e2o.0 * rhs.e12.0) + E23o(self.e3o.0 * rhs.e13.0) + E23o(self.eoi.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0) + E23i(self.eoi.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) + E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e23.0 * rhs.e12.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.e3o.0 * rhs.e1i.0),
+		//     e3oi  : E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e1i.0 * rhs.e2o.0) - E3oi(self.e1o.0 * rhs.e2i.0) - E3oi(self.e23.0 * rhs.e13.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.e2o.0 * rhs.e1i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23.0 * rhs.eoi.0) + E1(self.e2i.0 * rhs.e3o.0)
+				- E1(self.e2o.0 * rhs.e3i.0)
+				- E1(self.e3i.0 * rhs.e2o.0)
+				+ E1(self.e3o.0 * rhs.e2i.0)
+				+ E1(self.eoi.0 * rhs.e23.0),
+			e2: -E2(self.e13.0 * rhs.eoi.0) - E2(self.e1i.0 * rhs.e3o.0)
+				+ E2(self.e1o.0 * rhs.e3i.0)
+				+ E2(self.e3i.0 * rhs.e1o.0)
+				- E2(self.e3o.0 * rhs.e1i.0)
+				- E2(self.eoi.0 * rhs.e13.0),
+			e3: E3(self.e12.0 * rhs.eoi.0) + E3(self.e1i.0 * rhs.e2o.0)
+				- E3(self.e1o.0 * rhs.e2i.0)
+				- E3(self.e2i.0 * rhs.e1o.0)
+				+ E3(self.e2o.0 * rhs.e1i.0)
+				+ E3(self.eoi.0 * rhs.e12.0),
+			eo: -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0)
+				- Eo(self.e1o.0 * rhs.e23.0)
+				- Eo(self.e23.0 * rhs.e1o.0)
+				+ Eo(self.e2o.0 * rhs.e13.0)
+				- Eo(self.e3o.0 * rhs.e12.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0)
+				+ Ei(self.e1i.0 * rhs.e23.0)
+				+ Ei(self.e23.0 * rhs.e1i.0)
+				- Ei(self.e2i.0 * rhs.e13.0)
+				+ Ei(self.e3i.0 * rhs.e12.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e1i.0 * rhs.e1o.0) - E123(self.e1o.0 * rhs.e1i.0) + E123(self.e2i.0 * rhs.e2o.0)
+				- E123(self.e2o.0 * rhs.e2i.0)
+				+ E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.e3o.0 * rhs.e3i.0),
+			e12o: E12o(self.e13.0 * rhs.e1o.0) - E12o(self.e1o.0 * rhs.e13.0) + E12o(self.e23.0 * rhs.e2o.0)
+				- E12o(self.e2o.0 * rhs.e23.0)
+				- E12o(self.e3o.0 * rhs.eoi.0)
+				+ E12o(self.eoi.0 * rhs.e3o.0),
+			e12i: -E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e1i.0 * rhs.e13.0) - E12i(self.e23.0 * rhs.e2i.0)
+				+ E12i(self.e2i.0 * rhs.e23.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0),
+			e13o: -E13o(self.e12.0 * rhs.e1o.0)
+				+ E13o(self.e1o.0 * rhs.e12.0)
+				+ E13o(self.e23.0 * rhs.e3o.0)
+				+ E13o(self.e2o.0 * rhs.eoi.0)
+				- E13o(self.e3o.0 * rhs.e23.0)
+				- E13o(self.eoi.0 * rhs.e2o.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e23.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				- E13i(self.eoi.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0)
+				- E1oi(self.e13.0 * rhs.e12.0)
+				- E1oi(self.e2i.0 * rhs.e3o.0)
+				- E1oi(self.e2o.0 * rhs.e3i.0)
+				+ E1oi(self.e3i.0 * rhs.e2o.0)
+				+ E1oi(self.e3o.0 * rhs.e2i.0),
+			e23o: -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e1o.0 * rhs.eoi.0)
+				+ E23o(self.e2o.0 * rhs.e12.0)
+				+ E23o(self.e3o.0 * rhs.e13.0)
+				+ E23o(self.eoi.0 * rhs.e1o.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0) + E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e1o.0 * rhs.e3i.0)
+				- E2oi(self.e23.0 * rhs.e12.0)
+				- E2oi(self.e3i.0 * rhs.e1o.0)
+				- E2oi(self.e3o.0 * rhs.e1i.0),
+			e3oi: E3oi(self.e13.0 * rhs.e23.0)
+				- E3oi(self.e1i.0 * rhs.e2o.0)
+				- E3oi(self.e1o.0 * rhs.e2i.0)
+				- E3oi(self.e23.0 * rhs.e13.0)
+				+ E3oi(self.e2i.0 * rhs.e1o.0)
+				+ E3oi(self.e2o.0 * rhs.e1i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// PointPair.dot(PointPair) -> S
+impl Dot<PointPair> for PointPair {
+	type Output = S;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0)
+			- S(self.e23.0 * rhs.e23.0)
+			+ S(self.e2i.0 * rhs.e2o.0)
+			+ S(self.e2o.0 * rhs.e2i.0)
+			+ S(self.e3i.0 * rhs.e3o.0)
+			+ S(self.e3o.0 * rhs.e3i.0)
+			+ S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+impl std::ops::BitOr<PointPair> for PointPair {
+	type Output = S;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// PointPair.wedge(PointPair) -> Sphere
+impl Wedge<PointPair> for PointPair {
+	type Output = Sphere;
+	fn wedge(self, rhs: PointPair) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) + E123i(self.e23.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Sphere {
+			e123o: self.e12.wedge(rhs.e3o)
+				+ self.e13.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e13)
+				+ self.e3o.wedge(rhs.e12),
+			e123i: self.e12.wedge(rhs.e3i)
+				+ self.e13.wedge(rhs.e2i)
+				+ self.e1i.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1i)
+				+ self.e2i.wedge(rhs.e13)
+				+ self.e3i.wedge(rhs.e12),
+			e12oi: self.e12.wedge(rhs.eoi)
+				+ self.e1i.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e2i)
+				+ self.e2i.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e12),
+			e13oi: self.e13.wedge(rhs.eoi)
+				+ self.e1i.wedge(rhs.e3o)
+				+ self.e1o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e1o)
+				+ self.e3o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e13),
+			e23oi: self.e23.wedge(rhs.eoi)
+				+ self.e2i.wedge(rhs.e3o)
+				+ self.e2o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e2o)
+				+ self.e3o.wedge(rhs.e2i)
+				+ self.eoi.wedge(rhs.e23),
+		}
+	}
+}
+
+impl std::ops::BitXor<PointPair> for PointPair {
+	type Output = Sphere;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<PointPair> for PointPair {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for PointPair {
+	type Output = Zero;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// PointPair.left_contraction(PointPair) -> S
+impl LeftContraction<PointPair> for PointPair {
+	type Output = S;
+	fn left_contraction(self, rhs: PointPair) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0)
+			- S(self.e23.0 * rhs.e23.0)
+			+ S(self.e2i.0 * rhs.e2o.0)
+			+ S(self.e2o.0 * rhs.e2i.0)
+			+ S(self.e3i.0 * rhs.e3o.0)
+			+ S(self.e3o.0 * rhs.e3i.0)
+			+ S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// PointPair.right_contraction(PointPair) -> S
+impl RightContraction<PointPair> for PointPair {
+	type Output = S;
+	fn right_contraction(self, rhs: PointPair) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0)
+			- S(self.e23.0 * rhs.e23.0)
+			+ S(self.e2i.0 * rhs.e2o.0)
+			+ S(self.e2o.0 * rhs.e2i.0)
+			+ S(self.e3i.0 * rhs.e3o.0)
+			+ S(self.e3o.0 * rhs.e3i.0)
+			+ S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// PointPair.scalar_product(PointPair) -> S
+impl ScalarProduct<PointPair> for PointPair {
+	type Output = S;
+	fn scalar_product(self, rhs: PointPair) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0)
+			- S(self.e23.0 * rhs.e23.0)
+			+ S(self.e2i.0 * rhs.e2o.0)
+			+ S(self.e2o.0 * rhs.e2i.0)
+			+ S(self.e3i.0 * rhs.e3o.0)
+			+ S(self.e3o.0 * rhs.e3i.0)
+			+ S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// PointPair.commutator(PointPair) -> PointPair
+impl Commutator<PointPair> for PointPair {
+	type Output = PointPair;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o: -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o: -E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.eoi.0) - E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i: -E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi: Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		// }
+		PointPair {
+			e12: -E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				- E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// PointPair.anti_dot(PointPair) -> E123oi
+impl AntiDot<PointPair> for PointPair {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0)
+		E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0)
+			- E123oi(self.e1i.0 * rhs.e1o.0)
+			- E123oi(self.e1o.0 * rhs.e1i.0)
+			+ E123oi(self.e23.0 * rhs.e23.0)
+			- E123oi(self.e2i.0 * rhs.e2o.0)
+			- E123oi(self.e2o.0 * rhs.e2i.0)
+			- E123oi(self.e3i.0 * rhs.e3o.0)
+			- E123oi(self.e3o.0 * rhs.e3i.0)
+			- E123oi(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP Line:
+
+// PointPair.geometric(Line) -> Multivector
+impl Geometric<Line> for PointPair {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3o.0 * rhs.e12i.0),
+		//     e12o  : -E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e2o.0 * rhs.e1oi.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) - E12i(self.e2i.0 * rhs.e1oi.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : -E13o(self.e1o.0 * rhs.e3oi.0) + E13o(self.e3o.0 * rhs.e1oi.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) - E1oi(self.e2o.0 * rhs.e12i.0) - E1oi(self.e3o.0 * rhs.e13i.0),
+		//     e23o  : -E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) - E2oi(self.e3o.0 * rhs.e23i.0),
+		//     e3oi  : -E3oi(self.e13.0 * rhs.e1oi.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) + E3oi(self.e2o.0 * rhs.e23i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) - E123oi(self.e2o.0 * rhs.e13i.0) + E123oi(self.e3o.0 * rhs.e12i.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3o.0 * rhs.e12i.0),
+			e12o: -E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e2o.0 * rhs.e1oi.0),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: -E13o(self.e1o.0 * rhs.e3oi.0) + E13o(self.e3o.0 * rhs.e1oi.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0)
+				- E13i(self.e23.0 * rhs.e12i.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0),
+			e23o: -E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.e2i.0 * rhs.e3oi.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0)
+				+ E123oi(self.e1o.0 * rhs.e23i.0)
+				+ E123oi(self.e23.0 * rhs.e1oi.0)
+				- E123oi(self.e2o.0 * rhs.e13i.0)
+				+ E123oi(self.e3o.0 * rhs.e12i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for PointPair {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(Line) -> Versor
+impl AntiGeometric<Line> for PointPair {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) - S(self.e2o.0 * rhs.e13i.0) + S(self.e3o.0 * rhs.e12i.0),
+		//     e12  : -E12(self.e13.0 * rhs.e1oi.0) + E12(self.e1o.0 * rhs.e13i.0) - E12(self.e23.0 * rhs.e2oi.0) + E12(self.e2o.0 * rhs.e23i.0),
+		//     e13  : E13(self.e12.0 * rhs.e1oi.0) - E13(self.e1o.0 * rhs.e12i.0) - E13(self.e23.0 * rhs.e3oi.0) + E13(self.e3o.0 * rhs.e23i.0),
+		//     e1o  : -E1o(self.e2o.0 * rhs.e3oi.0) + E1o(self.e3o.0 * rhs.e2oi.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0) - E1i(self.eoi.0 * rhs.e23i.0),
+		//     e23  : E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) - E23(self.e2o.0 * rhs.e12i.0) - E23(self.e3o.0 * rhs.e13i.0),
+		//     e2o  : E2o(self.e1o.0 * rhs.e3oi.0) - E2o(self.e3o.0 * rhs.e1oi.0),
+		//     e2i  : E2i(self.e12.0 * rhs.e23i.0) + E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e23.0 * rhs.e12i.0) - E2i(self.e3i.0 * rhs.e1oi.0) + E2i(self.eoi.0 * rhs.e13i.0),
+		//     e3o  : -E3o(self.e1o.0 * rhs.e2oi.0) + E3o(self.e2o.0 * rhs.e1oi.0),
+		//     e3i  : E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e1i.0 * rhs.e2oi.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.e2i.0 * rhs.e1oi.0) - E3i(self.eoi.0 * rhs.e12i.0),
+		//     eoi  : Eoi(self.e1o.0 * rhs.e23i.0) - Eoi(self.e2o.0 * rhs.e13i.0) + Eoi(self.e3o.0 * rhs.e12i.0),
+		//     e123o: -E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e1o.0 * rhs.e13i.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: -E13oi(self.e1o.0 * rhs.e12i.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: -E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Versor {
+			s: S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0)
+				+ S(self.e1o.0 * rhs.e23i.0)
+				+ S(self.e23.0 * rhs.e1oi.0)
+				- S(self.e2o.0 * rhs.e13i.0)
+				+ S(self.e3o.0 * rhs.e12i.0),
+			e12: -E12(self.e13.0 * rhs.e1oi.0) + E12(self.e1o.0 * rhs.e13i.0) - E12(self.e23.0 * rhs.e2oi.0)
+				+ E12(self.e2o.0 * rhs.e23i.0),
+			e13: E13(self.e12.0 * rhs.e1oi.0) - E13(self.e1o.0 * rhs.e12i.0) - E13(self.e23.0 * rhs.e3oi.0)
+				+ E13(self.e3o.0 * rhs.e23i.0),
+			e1o: -E1o(self.e2o.0 * rhs.e3oi.0) + E1o(self.e3o.0 * rhs.e2oi.0),
+			e1i: E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) - E1i(self.e2i.0 * rhs.e3oi.0)
+				+ E1i(self.e3i.0 * rhs.e2oi.0)
+				- E1i(self.eoi.0 * rhs.e23i.0),
+			e23: E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0)
+				- E23(self.e2o.0 * rhs.e12i.0)
+				- E23(self.e3o.0 * rhs.e13i.0),
+			e2o: E2o(self.e1o.0 * rhs.e3oi.0) - E2o(self.e3o.0 * rhs.e1oi.0),
+			e2i: E2i(self.e12.0 * rhs.e23i.0) + E2i(self.e1i.0 * rhs.e3oi.0)
+				- E2i(self.e23.0 * rhs.e12i.0)
+				- E2i(self.e3i.0 * rhs.e1oi.0)
+				+ E2i(self.eoi.0 * rhs.e13i.0),
+			e3o: -E3o(self.e1o.0 * rhs.e2oi.0) + E3o(self.e2o.0 * rhs.e1oi.0),
+			e3i: E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e1i.0 * rhs.e2oi.0) - E3i(self.e23.0 * rhs.e13i.0)
+				+ E3i(self.e2i.0 * rhs.e1oi.0)
+				- E3i(self.eoi.0 * rhs.e12i.0),
+			eoi: Eoi(self.e1o.0 * rhs.e23i.0) - Eoi(self.e2o.0 * rhs.e13i.0) + Eoi(self.e3o.0 * rhs.e12i.0),
+			e123o: -E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e1o.0 * rhs.e13i.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: -E13oi(self.e1o.0 * rhs.e12i.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: -E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// PointPair.dot(Line) -> Point
+impl Dot<Line> for PointPair {
+	type Output = Point;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     y  : -E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     z  : -E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     o  : -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     inf: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		// }
+		Point {
+			x: E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+			y: -E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+			z: -E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+			o: -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+			inf: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for PointPair {
+	type Output = Point;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// PointPair.wedge(Line) -> E123oi
+impl Wedge<Line> for PointPair {
+	type Output = E123oi;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) - E123oi(self.e2o.0 * rhs.e13i.0) + E123oi(self.e3o.0 * rhs.e12i.0)
+		self.e12.wedge(rhs.e3oi)
+			+ self.e13.wedge(rhs.e2oi)
+			+ self.e1o.wedge(rhs.e23i)
+			+ self.e23.wedge(rhs.e1oi)
+			+ self.e2o.wedge(rhs.e13i)
+			+ self.e3o.wedge(rhs.e12i)
+	}
+}
+
+impl std::ops::BitXor<Line> for PointPair {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// PointPair.anti_wedge(Line) -> S
+impl AntiWedge<Line> for PointPair {
+	type Output = S;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) - S(self.e2o.0 * rhs.e13i.0) + S(self.e3o.0 * rhs.e12i.0)
+		self.e12.anti_wedge(rhs.e3oi)
+			+ self.e13.anti_wedge(rhs.e2oi)
+			+ self.e1o.anti_wedge(rhs.e23i)
+			+ self.e23.anti_wedge(rhs.e1oi)
+			+ self.e2o.anti_wedge(rhs.e13i)
+			+ self.e3o.anti_wedge(rhs.e12i)
+	}
+}
+
+impl std::ops::BitAnd<Line> for PointPair {
+	type Output = S;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// PointPair.left_contraction(Line) -> Point
+impl LeftContraction<Line> for PointPair {
+	type Output = Point;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     y  : -E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     z  : -E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     o  : -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     inf: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		// }
+		Point {
+			x: E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+			y: -E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+			z: -E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+			o: -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+			inf: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl RightContraction<Line> for PointPair {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line> for PointPair {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// PointPair.commutator(Line) -> Circle
+impl Commutator<Line> for PointPair {
+	type Output = Circle;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3o.0 * rhs.e12i.0),
+		//     e12o: -E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e2o.0 * rhs.e1oi.0),
+		//     e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) - E12i(self.e2i.0 * rhs.e1oi.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o: -E13o(self.e1o.0 * rhs.e3oi.0) + E13o(self.e3o.0 * rhs.e1oi.0),
+		//     e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) - E1oi(self.e2o.0 * rhs.e12i.0) - E1oi(self.e3o.0 * rhs.e13i.0),
+		//     e23o: -E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0),
+		//     e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) - E2oi(self.e3o.0 * rhs.e23i.0),
+		//     e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) + E3oi(self.e2o.0 * rhs.e23i.0),
+		// }
+		Circle {
+			e123: -E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3o.0 * rhs.e12i.0),
+			e12o: -E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e2o.0 * rhs.e1oi.0),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: -E13o(self.e1o.0 * rhs.e3oi.0) + E13o(self.e3o.0 * rhs.e1oi.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0)
+				- E13i(self.e23.0 * rhs.e12i.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0),
+			e23o: -E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.e2i.0 * rhs.e3oi.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0),
+		}
+	}
+}
+
+// PointPair.anti_dot(Line) -> Sphere
+impl AntiDot<Line> for PointPair {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e1o.0 * rhs.e13i.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: -E13oi(self.e1o.0 * rhs.e12i.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: -E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e1o.0 * rhs.e13i.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: -E13oi(self.e1o.0 * rhs.e12i.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: -E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP Circle:
+
+// PointPair.geometric(Circle) -> Multivector
+impl Geometric<Circle> for PointPair {
+	type Output = Multivector;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23.0 * rhs.e123.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2i.0 * rhs.e13o.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3i.0 * rhs.e12o.0) - E123(self.e3o.0 * rhs.e12i.0),
+		//     e12o  : -E12o(self.e13.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e23.0 * rhs.e13o.0) + E12o(self.e2o.0 * rhs.e1oi.0) - E12o(self.e3o.0 * rhs.e123.0) - E12o(self.eoi.0 * rhs.e12o.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.e2o.0 * rhs.e123.0) + E13o(self.e3o.0 * rhs.e1oi.0) - E13o(self.eoi.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.e2i.0 * rhs.e12o.0) - E1oi(self.e2o.0 * rhs.e12i.0) + E1oi(self.e3i.0 * rhs.e13o.0) - E1oi(self.e3o.0 * rhs.e13i.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0) - E23o(self.e1o.0 * rhs.e123.0) - E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0) - E23o(self.eoi.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.e3i.0 * rhs.e23o.0) - E2oi(self.e3o.0 * rhs.e23i.0),
+		//     e3oi  : -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) - E3oi(self.e2i.0 * rhs.e23o.0) + E3oi(self.e2o.0 * rhs.e23i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e2o.0 * rhs.e13i.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.e3o.0 * rhs.e12i.0) + E123oi(self.eoi.0 * rhs.e123.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e12.0 * rhs.e123.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0)
+				+ E123(self.e2i.0 * rhs.e13o.0)
+				+ E123(self.e2o.0 * rhs.e13i.0)
+				- E123(self.e3i.0 * rhs.e12o.0)
+				- E123(self.e3o.0 * rhs.e12i.0),
+			e12o: -E12o(self.e13.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2oi.0)
+				+ E12o(self.e23.0 * rhs.e13o.0)
+				+ E12o(self.e2o.0 * rhs.e1oi.0)
+				- E12o(self.e3o.0 * rhs.e123.0)
+				- E12o(self.eoi.0 * rhs.e12o.0),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				- E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0)
+				+ E13o(self.e2o.0 * rhs.e123.0)
+				+ E13o(self.e3o.0 * rhs.e1oi.0)
+				- E13o(self.eoi.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0)
+				+ E13i(self.e2i.0 * rhs.e123.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.e2i.0 * rhs.e12o.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				+ E1oi(self.e3i.0 * rhs.e13o.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0)
+				- E23o(self.e1o.0 * rhs.e123.0)
+				- E23o(self.e2o.0 * rhs.e3oi.0)
+				+ E23o(self.e3o.0 * rhs.e2oi.0)
+				- E23o(self.eoi.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e1i.0 * rhs.e12o.0)
+				+ E2oi(self.e1o.0 * rhs.e12i.0)
+				+ E2oi(self.e23.0 * rhs.e3oi.0)
+				+ E2oi(self.e3i.0 * rhs.e23o.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0)
+				- E3oi(self.e23.0 * rhs.e2oi.0)
+				- E3oi(self.e2i.0 * rhs.e23o.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e1i.0 * rhs.e23o.0)
+				+ E123oi(self.e1o.0 * rhs.e23i.0)
+				+ E123oi(self.e23.0 * rhs.e1oi.0)
+				+ E123oi(self.e2i.0 * rhs.e13o.0)
+				- E123oi(self.e2o.0 * rhs.e13i.0)
+				- E123oi(self.e3i.0 * rhs.e12o.0)
+				+ E123oi(self.e3o.0 * rhs.e12i.0)
+				+ E123oi(self.eoi.0 * rhs.e123.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for PointPair {
+	type Output = Multivector;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(Circle) -> Versor
+impl AntiGeometric<Circle> for PointPair {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e1i.0 * rhs.e23o.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e2o.0 * rhs.e13i.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.e3o.0 * rhs.e12i.0) + S(self.eoi.0 * rhs.e123.0),
+		//     e12  : -E12(self.e13.0 * rhs.e1oi.0) - E12(self.e1i.0 * rhs.e13o.0) + E12(self.e1o.0 * rhs.e13i.0) - E12(self.e23.0 * rhs.e2oi.0) - E12(self.e2i.0 * rhs.e23o.0) + E12(self.e2o.0 * rhs.e23i.0),
+		//     e13  : E13(self.e12.0 * rhs.e1oi.0) + E13(self.e1i.0 * rhs.e12o.0) - E13(self.e1o.0 * rhs.e12i.0) - E13(self.e23.0 * rhs.e3oi.0) - E13(self.e3i.0 * rhs.e23o.0) + E13(self.e3o.0 * rhs.e23i.0),
+		//     e1o  : -E1o(self.e12.0 * rhs.e13o.0) + E1o(self.e13.0 * rhs.e12o.0) - E1o(self.e1o.0 * rhs.e123.0) - E1o(self.e2o.0 * rhs.e3oi.0) + E1o(self.e3o.0 * rhs.e2oi.0) - E1o(self.eoi.0 * rhs.e23o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.e1i.0 * rhs.e123.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0) - E1i(self.eoi.0 * rhs.e23i.0),
+		//     e23  : E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) + E23(self.e2i.0 * rhs.e12o.0) - E23(self.e2o.0 * rhs.e12i.0) + E23(self.e3i.0 * rhs.e13o.0) - E23(self.e3o.0 * rhs.e13i.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e23o.0) + E2o(self.e1o.0 * rhs.e3oi.0) + E2o(self.e23.0 * rhs.e12o.0) - E2o(self.e2o.0 * rhs.e123.0) - E2o(self.e3o.0 * rhs.e1oi.0) + E2o(self.eoi.0 * rhs.e13o.0),
+		//     e2i  : E2i(self.e12.0 * rhs.e23i.0) + E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e23.0 * rhs.e12i.0) + E2i(self.e2i.0 * rhs.e123.0) - E2i(self.e3i.0 * rhs.e1oi.0) + E2i(self.eoi.0 * rhs.e13i.0),
+		//     e3o  : -E3o(self.e13.0 * rhs.e23o.0) - E3o(self.e1o.0 * rhs.e2oi.0) + E3o(self.e23.0 * rhs.e13o.0) + E3o(self.e2o.0 * rhs.e1oi.0) - E3o(self.e3o.0 * rhs.e123.0) - E3o(self.eoi.0 * rhs.e12o.0),
+		//     e3i  : E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e1i.0 * rhs.e2oi.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.e3i.0 * rhs.e123.0) - E3i(self.eoi.0 * rhs.e12i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e23o.0) + Eoi(self.e1o.0 * rhs.e23i.0) - Eoi(self.e2i.0 * rhs.e13o.0) - Eoi(self.e2o.0 * rhs.e13i.0) + Eoi(self.e3i.0 * rhs.e12o.0) + Eoi(self.e3o.0 * rhs.e12i.0),
+		//     e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e23.0 * rhs.e23o.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.e123.0) + E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e1o.0 * rhs.e13i.0) + E12oi(self.e2i.0 * rhs.e23o.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e1i.0 * rhs.e12o.0) - E13oi(self.e1o.0 * rhs.e12i.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.e123.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Versor {
+			s: S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e1i.0 * rhs.e23o.0)
+				+ S(self.e1o.0 * rhs.e23i.0)
+				+ S(self.e23.0 * rhs.e1oi.0)
+				+ S(self.e2i.0 * rhs.e13o.0)
+				- S(self.e2o.0 * rhs.e13i.0)
+				- S(self.e3i.0 * rhs.e12o.0)
+				+ S(self.e3o.0 * rhs.e12i.0)
+				+ S(self.eoi.0 * rhs.e123.0),
+			e12: -E12(self.e13.0 * rhs.e1oi.0) - E12(self.e1i.0 * rhs.e13o.0) + E12(self.e1o.0 * rhs.e13i.0)
+				- E12(self.e23.0 * rhs.e2oi.0)
+				- E12(self.e2i.0 * rhs.e23o.0)
+				+ E12(self.e2o.0 * rhs.e23i.0),
+			e13: E13(self.e12.0 * rhs.e1oi.0) + E13(self.e1i.0 * rhs.e12o.0)
+				- E13(self.e1o.0 * rhs.e12i.0)
+				- E13(self.e23.0 * rhs.e3oi.0)
+				- E13(self.e3i.0 * rhs.e23o.0)
+				+ E13(self.e3o.0 * rhs.e23i.0),
+			e1o: -E1o(self.e12.0 * rhs.e13o.0) + E1o(self.e13.0 * rhs.e12o.0)
+				- E1o(self.e1o.0 * rhs.e123.0)
+				- E1o(self.e2o.0 * rhs.e3oi.0)
+				+ E1o(self.e3o.0 * rhs.e2oi.0)
+				- E1o(self.eoi.0 * rhs.e23o.0),
+			e1i: E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.e1i.0 * rhs.e123.0)
+				- E1i(self.e2i.0 * rhs.e3oi.0)
+				+ E1i(self.e3i.0 * rhs.e2oi.0)
+				- E1i(self.eoi.0 * rhs.e23i.0),
+			e23: E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) + E23(self.e2i.0 * rhs.e12o.0)
+				- E23(self.e2o.0 * rhs.e12i.0)
+				+ E23(self.e3i.0 * rhs.e13o.0)
+				- E23(self.e3o.0 * rhs.e13i.0),
+			e2o: -E2o(self.e12.0 * rhs.e23o.0) + E2o(self.e1o.0 * rhs.e3oi.0) + E2o(self.e23.0 * rhs.e12o.0)
+				- E2o(self.e2o.0 * rhs.e123.0)
+				- E2o(self.e3o.0 * rhs.e1oi.0)
+				+ E2o(self.eoi.0 * rhs.e13o.0),
+			e2i: E2i(self.e12.0 * rhs.e23i.0) + E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e23.0 * rhs.e12i.0)
+				+ E2i(self.e2i.0 * rhs.e123.0)
+				- E2i(self.e3i.0 * rhs.e1oi.0)
+				+ E2i(self.eoi.0 * rhs.e13i.0),
+			e3o: -E3o(self.e13.0 * rhs.e23o.0) - E3o(self.e1o.0 * rhs.e2oi.0)
+				+ E3o(self.e23.0 * rhs.e13o.0)
+				+ E3o(self.e2o.0 * rhs.e1oi.0)
+				- E3o(self.e3o.0 * rhs.e123.0)
+				- E3o(self.eoi.0 * rhs.e12o.0),
+			e3i: E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e1i.0 * rhs.e2oi.0) - E3i(self.e23.0 * rhs.e13i.0)
+				+ E3i(self.e2i.0 * rhs.e1oi.0)
+				+ E3i(self.e3i.0 * rhs.e123.0)
+				- E3i(self.eoi.0 * rhs.e12i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e23o.0) + Eoi(self.e1o.0 * rhs.e23i.0)
+				- Eoi(self.e2i.0 * rhs.e13o.0)
+				- Eoi(self.e2o.0 * rhs.e13i.0)
+				+ Eoi(self.e3i.0 * rhs.e12o.0)
+				+ Eoi(self.e3o.0 * rhs.e12i.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0)
+				- E123o(self.e13.0 * rhs.e13o.0)
+				- E123o(self.e1o.0 * rhs.e1oi.0)
+				- E123o(self.e23.0 * rhs.e23o.0)
+				- E123o(self.e2o.0 * rhs.e2oi.0)
+				- E123o(self.e3o.0 * rhs.e3oi.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0)
+				+ E12oi(self.e1i.0 * rhs.e13o.0)
+				+ E12oi(self.e1o.0 * rhs.e13i.0)
+				+ E12oi(self.e2i.0 * rhs.e23o.0)
+				+ E12oi(self.e2o.0 * rhs.e23i.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e1i.0 * rhs.e12o.0) - E13oi(self.e1o.0 * rhs.e12i.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.e3o.0 * rhs.e23i.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: E23oi(self.e23.0 * rhs.e123.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e2o.0 * rhs.e12i.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.e3o.0 * rhs.e13i.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// PointPair.dot(Circle) -> Point
+impl Dot<Circle> for PointPair {
+	type Output = Point;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23.0 * rhs.e123.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     y  : E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     z  : -E3(self.e12.0 * rhs.e123.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     o  : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     inf: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		// }
+		Point {
+			x: -E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			y: E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			z: -E3(self.e12.0 * rhs.e123.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			o: -Eo(self.e12.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0),
+			inf: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Circle> for PointPair {
+	type Output = Point;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// PointPair.wedge(Circle) -> E123oi
+impl Wedge<Circle> for PointPair {
+	type Output = E123oi;
+	fn wedge(self, rhs: Circle) -> Self::Output {
+		// E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e2o.0 * rhs.e13i.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.e3o.0 * rhs.e12i.0) + E123oi(self.eoi.0 * rhs.e123.0)
+		self.e12.wedge(rhs.e3oi)
+			+ self.e13.wedge(rhs.e2oi)
+			+ self.e1i.wedge(rhs.e23o)
+			+ self.e1o.wedge(rhs.e23i)
+			+ self.e23.wedge(rhs.e1oi)
+			+ self.e2i.wedge(rhs.e13o)
+			+ self.e2o.wedge(rhs.e13i)
+			+ self.e3i.wedge(rhs.e12o)
+			+ self.e3o.wedge(rhs.e12i)
+			+ self.eoi.wedge(rhs.e123)
+	}
+}
+
+impl std::ops::BitXor<Circle> for PointPair {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// PointPair.anti_wedge(Circle) -> S
+impl AntiWedge<Circle> for PointPair {
+	type Output = S;
+	fn anti_wedge(self, rhs: Circle) -> Self::Output {
+		// S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e1i.0 * rhs.e23o.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e2o.0 * rhs.e13i.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.e3o.0 * rhs.e12i.0) + S(self.eoi.0 * rhs.e123.0)
+		self.e12.anti_wedge(rhs.e3oi)
+			+ self.e13.anti_wedge(rhs.e2oi)
+			+ self.e1i.anti_wedge(rhs.e23o)
+			+ self.e1o.anti_wedge(rhs.e23i)
+			+ self.e23.anti_wedge(rhs.e1oi)
+			+ self.e2i.anti_wedge(rhs.e13o)
+			+ self.e2o.anti_wedge(rhs.e13i)
+			+ self.e3i.anti_wedge(rhs.e12o)
+			+ self.e3o.anti_wedge(rhs.e12i)
+			+ self.eoi.anti_wedge(rhs.e123)
+	}
+}
+
+impl std::ops::BitAnd<Circle> for PointPair {
+	type Output = S;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// PointPair.left_contraction(Circle) -> Point
+impl LeftContraction<Circle> for PointPair {
+	type Output = Point;
+	fn left_contraction(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e23.0 * rhs.e123.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     y  : E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     z  : -E3(self.e12.0 * rhs.e123.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     o  : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     inf: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		// }
+		Point {
+			x: -E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			y: E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			z: -E3(self.e12.0 * rhs.e123.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			o: -Eo(self.e12.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0),
+			inf: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl RightContraction<Circle> for PointPair {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Circle> for PointPair {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// PointPair.commutator(Circle) -> Circle
+impl Commutator<Circle> for PointPair {
+	type Output = Circle;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2i.0 * rhs.e13o.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3i.0 * rhs.e12o.0) - E123(self.e3o.0 * rhs.e12i.0),
+		//     e12o: -E12o(self.e13.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e23.0 * rhs.e13o.0) + E12o(self.e2o.0 * rhs.e1oi.0) - E12o(self.e3o.0 * rhs.e123.0) - E12o(self.eoi.0 * rhs.e12o.0),
+		//     e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o: E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.e2o.0 * rhs.e123.0) + E13o(self.e3o.0 * rhs.e1oi.0) - E13o(self.eoi.0 * rhs.e13o.0),
+		//     e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.e2i.0 * rhs.e12o.0) - E1oi(self.e2o.0 * rhs.e12i.0) + E1oi(self.e3i.0 * rhs.e13o.0) - E1oi(self.e3o.0 * rhs.e13i.0),
+		//     e23o: -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0) - E23o(self.e1o.0 * rhs.e123.0) - E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0) - E23o(self.eoi.0 * rhs.e23o.0),
+		//     e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.e3i.0 * rhs.e23o.0) - E2oi(self.e3o.0 * rhs.e23i.0),
+		//     e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) - E3oi(self.e2i.0 * rhs.e23o.0) + E3oi(self.e2o.0 * rhs.e23i.0),
+		// }
+		Circle {
+			e123: -E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0)
+				+ E123(self.e2i.0 * rhs.e13o.0)
+				+ E123(self.e2o.0 * rhs.e13i.0)
+				- E123(self.e3i.0 * rhs.e12o.0)
+				- E123(self.e3o.0 * rhs.e12i.0),
+			e12o: -E12o(self.e13.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2oi.0)
+				+ E12o(self.e23.0 * rhs.e13o.0)
+				+ E12o(self.e2o.0 * rhs.e1oi.0)
+				- E12o(self.e3o.0 * rhs.e123.0)
+				- E12o(self.eoi.0 * rhs.e12o.0),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				- E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0)
+				+ E13o(self.e2o.0 * rhs.e123.0)
+				+ E13o(self.e3o.0 * rhs.e1oi.0)
+				- E13o(self.eoi.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0)
+				+ E13i(self.e2i.0 * rhs.e123.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.e2i.0 * rhs.e12o.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				+ E1oi(self.e3i.0 * rhs.e13o.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0)
+				- E23o(self.e1o.0 * rhs.e123.0)
+				- E23o(self.e2o.0 * rhs.e3oi.0)
+				+ E23o(self.e3o.0 * rhs.e2oi.0)
+				- E23o(self.eoi.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e1i.0 * rhs.e12o.0)
+				+ E2oi(self.e1o.0 * rhs.e12i.0)
+				+ E2oi(self.e23.0 * rhs.e3oi.0)
+				+ E2oi(self.e3i.0 * rhs.e23o.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0)
+				- E3oi(self.e23.0 * rhs.e2oi.0)
+				- E3oi(self.e2i.0 * rhs.e23o.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0),
+		}
+	}
+}
+
+// PointPair.anti_dot(Circle) -> Sphere
+impl AntiDot<Circle> for PointPair {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e23.0 * rhs.e23o.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.e123.0) + E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e1o.0 * rhs.e13i.0) + E12oi(self.e2i.0 * rhs.e23o.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e1i.0 * rhs.e12o.0) - E13oi(self.e1o.0 * rhs.e12i.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.e123.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.e12.0 * rhs.e12o.0)
+				- E123o(self.e13.0 * rhs.e13o.0)
+				- E123o(self.e1o.0 * rhs.e1oi.0)
+				- E123o(self.e23.0 * rhs.e23o.0)
+				- E123o(self.e2o.0 * rhs.e2oi.0)
+				- E123o(self.e3o.0 * rhs.e3oi.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0)
+				+ E12oi(self.e1i.0 * rhs.e13o.0)
+				+ E12oi(self.e1o.0 * rhs.e13i.0)
+				+ E12oi(self.e2i.0 * rhs.e23o.0)
+				+ E12oi(self.e2o.0 * rhs.e23i.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e1i.0 * rhs.e12o.0) - E13oi(self.e1o.0 * rhs.e12i.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.e3o.0 * rhs.e23i.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: E23oi(self.e23.0 * rhs.e123.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e2o.0 * rhs.e12i.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.e3o.0 * rhs.e13i.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP Plane:
+
+// PointPair.geometric(Plane) -> Versor
+impl Geometric<Plane> for PointPair {
+	type Output = Versor;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : -E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : -E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for PointPair {
+	type Output = Versor;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for PointPair {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.e1o.0 * rhs.e123i.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) + E2(self.e2o.0 * rhs.e123i.0),
+		//     e3    : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.e3o.0 * rhs.e123i.0),
+		//     eo    : Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o  : E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : -E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : -E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+		//     e23i  : E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.e1o.0 * rhs.e123i.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) + E2(self.e2o.0 * rhs.e123i.0),
+			e3: E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.e3o.0 * rhs.e123i.0),
+			eo: Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0),
+			ei: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+			e12o: E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: -E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: -E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+			e23i: E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// PointPair.dot(Plane) -> PointPair
+impl Dot<Plane> for PointPair {
+	type Output = PointPair;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13: -E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o: -E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23: E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o: E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o: E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		// }
+		PointPair {
+			e12: E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for PointPair {
+	type Output = PointPair;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for PointPair {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for PointPair {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// PointPair.anti_wedge(Plane) -> Point
+impl AntiWedge<Plane> for PointPair {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.e1o.0 * rhs.e123i.0),
+		//     y  : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) + E2(self.e2o.0 * rhs.e123i.0),
+		//     z  : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.e3o.0 * rhs.e123i.0),
+		//     o  : Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0),
+		//     inf: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		// }
+		Point {
+			x: self.e12.anti_wedge(rhs.e13oi) + self.e13.anti_wedge(rhs.e12oi) + self.e1o.anti_wedge(rhs.e123i),
+			y: self.e12.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e12oi) + self.e2o.anti_wedge(rhs.e123i),
+			z: self.e13.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e13oi) + self.e3o.anti_wedge(rhs.e123i),
+			o: self.e1o.anti_wedge(rhs.e23oi) + self.e2o.anti_wedge(rhs.e13oi) + self.e3o.anti_wedge(rhs.e12oi),
+			inf: self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for PointPair {
+	type Output = Point;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// PointPair.left_contraction(Plane) -> PointPair
+impl LeftContraction<Plane> for PointPair {
+	type Output = PointPair;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13: -E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o: -E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23: E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o: E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o: E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		// }
+		PointPair {
+			e12: E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Plane> for PointPair {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for PointPair {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// PointPair.commutator(Plane) -> Sphere
+impl Commutator<Plane> for PointPair {
+	type Output = Sphere;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		// }
+		Sphere {
+			e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+// PointPair.anti_dot(Plane) -> Circle
+impl AntiDot<Plane> for PointPair {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o: E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+		//     e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o: -E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+		//     e13i: E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi: E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o: -E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+		//     e23i: E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi: E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi: E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		// }
+		Circle {
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+			e12o: E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: -E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: -E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+			e23i: E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP Sphere:
+
+// PointPair.geometric(Sphere) -> Versor
+impl Geometric<Sphere> for PointPair {
+	type Output = Versor;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for PointPair {
+	type Output = Versor;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(Sphere) -> Multivector
+impl AntiGeometric<Sphere> for PointPair {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0),
+		//     e3    : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0),
+		//     eo    : Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : -E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+		//     e23i  : E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0)
+				+ E1(self.e1o.0 * rhs.e123i.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e2i.0 * rhs.e123o.0)
+				+ E2(self.e2o.0 * rhs.e123i.0),
+			e3: E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e3i.0 * rhs.e123o.0)
+				+ E3(self.e3o.0 * rhs.e123i.0),
+			eo: Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0)
+				- Eo(self.eoi.0 * rhs.e123o.0),
+			ei: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: -E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+			e23i: E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// PointPair.dot(Sphere) -> PointPair
+impl Dot<Sphere> for PointPair {
+	type Output = PointPair;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		// }
+		PointPair {
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Sphere> for PointPair {
+	type Output = PointPair;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Sphere> for PointPair {
+	type Output = Zero;
+	fn wedge(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Sphere> for PointPair {
+	type Output = Zero;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// PointPair.anti_wedge(Sphere) -> Point
+impl AntiWedge<Sphere> for PointPair {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0),
+		//     y  : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0),
+		//     z  : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0),
+		//     o  : Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0),
+		//     inf: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		// }
+		Point {
+			x: self.e12.anti_wedge(rhs.e13oi)
+				+ self.e13.anti_wedge(rhs.e12oi)
+				+ self.e1i.anti_wedge(rhs.e123o)
+				+ self.e1o.anti_wedge(rhs.e123i),
+			y: self.e12.anti_wedge(rhs.e23oi)
+				+ self.e23.anti_wedge(rhs.e12oi)
+				+ self.e2i.anti_wedge(rhs.e123o)
+				+ self.e2o.anti_wedge(rhs.e123i),
+			z: self.e13.anti_wedge(rhs.e23oi)
+				+ self.e23.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e123o)
+				+ self.e3o.anti_wedge(rhs.e123i),
+			o: self.e1o.anti_wedge(rhs.e23oi)
+				+ self.e2o.anti_wedge(rhs.e13oi)
+				+ self.e3o.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123o),
+			inf: self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for PointPair {
+	type Output = Point;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// PointPair.left_contraction(Sphere) -> PointPair
+impl LeftContraction<Sphere> for PointPair {
+	type Output = PointPair;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		// }
+		PointPair {
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Sphere> for PointPair {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Sphere> for PointPair {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+// PointPair.commutator(Sphere) -> Sphere
+impl Commutator<Sphere> for PointPair {
+	type Output = Sphere;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		// }
+		Sphere {
+			e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+// PointPair.anti_dot(Sphere) -> Circle
+impl AntiDot<Sphere> for PointPair {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o: -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+		//     e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o: -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+		//     e13i: E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o: -E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+		//     e23i: E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi: E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		// }
+		Circle {
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: -E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+			e23i: E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP Rotor:
+
+// PointPair.geometric(Rotor) -> Versor
+impl Geometric<Rotor> for PointPair {
+	type Output = Versor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e23.0 * rhs.e12.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23.0),
+		//     e2i  : E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3o  : E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e1o.0 * rhs.e23.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+			e12: E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+			e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e23.0 * rhs.e12.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0),
+			e2o: E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0),
+			e3o: E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.s.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) + E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: E123o(self.e1o.0 * rhs.e23.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+			e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+			e12oi: E12oi(self.eoi.0 * rhs.e12.0),
+			e13oi: E13oi(self.eoi.0 * rhs.e13.0),
+			e23oi: E23oi(self.eoi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for PointPair {
+	type Output = Versor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(Rotor) -> Multivector
+impl AntiGeometric<Rotor> for PointPair {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.eoi.0 * rhs.e23.0),
+		//     e2    : -E2(self.eoi.0 * rhs.e13.0),
+		//     e3    : E3(self.eoi.0 * rhs.e12.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.e23.0) + Eo(self.e2o.0 * rhs.e13.0) - Eo(self.e3o.0 * rhs.e12.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : -E12o(self.e1o.0 * rhs.e13.0) - E12o(self.e2o.0 * rhs.e23.0) + E12o(self.e3o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : E13o(self.e1o.0 * rhs.e12.0) - E13o(self.e2o.0 * rhs.s.0) - E13o(self.e3o.0 * rhs.e23.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.e23.0 * rhs.s.0),
+		//     e23o  : E23o(self.e1o.0 * rhs.s.0) + E23o(self.e2o.0 * rhs.e12.0) + E23o(self.e3o.0 * rhs.e13.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e13.0 * rhs.s.0) - E2oi(self.e23.0 * rhs.e12.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.eoi.0 * rhs.e23.0),
+			e2: -E2(self.eoi.0 * rhs.e13.0),
+			e3: E3(self.eoi.0 * rhs.e12.0),
+			eo: -Eo(self.e1o.0 * rhs.e23.0) + Eo(self.e2o.0 * rhs.e13.0) - Eo(self.e3o.0 * rhs.e12.0),
+			ei: Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.eoi.0 * rhs.s.0),
+			e12o: -E12o(self.e1o.0 * rhs.e13.0) - E12o(self.e2o.0 * rhs.e23.0) + E12o(self.e3o.0 * rhs.s.0),
+			e12i: E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.s.0),
+			e13o: E13o(self.e1o.0 * rhs.e12.0) - E13o(self.e2o.0 * rhs.s.0) - E13o(self.e3o.0 * rhs.e23.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.e23.0 * rhs.s.0),
+			e23o: E23o(self.e1o.0 * rhs.s.0) + E23o(self.e2o.0 * rhs.e12.0) + E23o(self.e3o.0 * rhs.e13.0),
+			e23i: -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e13.0 * rhs.s.0) - E2oi(self.e23.0 * rhs.e12.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0),
+		}
+	}
+}
+
+// PointPair.dot(Rotor) -> Versor
+impl Dot<Rotor> for PointPair {
+	type Output = Versor;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+			e2o: E2o(self.e2o.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3o: E3o(self.e3o.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for PointPair {
+	type Output = Versor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// PointPair.wedge(Rotor) -> Versor
+impl Wedge<Rotor> for PointPair {
+	type Output = Versor;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e1o.0 * rhs.e23.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: self.e12.wedge(rhs.s),
+			e13: self.e13.wedge(rhs.s),
+			e1o: self.e1o.wedge(rhs.s),
+			e1i: self.e1i.wedge(rhs.s),
+			e23: self.e23.wedge(rhs.s),
+			e2o: self.e2o.wedge(rhs.s),
+			e2i: self.e2i.wedge(rhs.s),
+			e3o: self.e3o.wedge(rhs.s),
+			e3i: self.e3i.wedge(rhs.s),
+			eoi: self.eoi.wedge(rhs.s),
+			e123o: self.e1o.wedge(rhs.e23) + self.e2o.wedge(rhs.e13) + self.e3o.wedge(rhs.e12),
+			e123i: self.e1i.wedge(rhs.e23) + self.e2i.wedge(rhs.e13) + self.e3i.wedge(rhs.e12),
+			e12oi: self.eoi.wedge(rhs.e12),
+			e13oi: self.eoi.wedge(rhs.e13),
+			e23oi: self.eoi.wedge(rhs.e23),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for PointPair {
+	type Output = Versor;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Rotor> for PointPair {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for PointPair {
+	type Output = Zero;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// PointPair.left_contraction(Rotor) -> S
+impl LeftContraction<Rotor> for PointPair {
+	type Output = S;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+	}
+}
+
+// PointPair.right_contraction(Rotor) -> Versor
+impl RightContraction<Rotor> for PointPair {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+			e2o: E2o(self.e2o.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3o: E3o(self.e3o.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// PointPair.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for PointPair {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+	}
+}
+
+// PointPair.commutator(Rotor) -> PointPair
+impl Commutator<Rotor> for PointPair {
+	type Output = PointPair;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+		//     e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+		//     e1o: -E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0),
+		//     e1i: -E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+		//     e2o: E2o(self.e1o.0 * rhs.e12.0) - E2o(self.e3o.0 * rhs.e23.0),
+		//     e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3o: E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e2o.0 * rhs.e23.0),
+		//     e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+			e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+			e1o: -E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0),
+			e1i: -E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+			e2o: E2o(self.e1o.0 * rhs.e12.0) - E2o(self.e3o.0 * rhs.e23.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e3i.0 * rhs.e23.0),
+			e3o: E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e2o.0 * rhs.e23.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// PointPair.anti_dot(Rotor) -> Multivector
+impl AntiDot<Rotor> for PointPair {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : E12o(self.e3o.0 * rhs.s.0),
+		//     e12i  : -E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : -E13o(self.e2o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e2i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e23.0 * rhs.s.0),
+		//     e23o  : E23o(self.e1o.0 * rhs.s.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.eoi.0 * rhs.s.0),
+			e12o: E12o(self.e3o.0 * rhs.s.0),
+			e12i: -E12i(self.e3i.0 * rhs.s.0),
+			e13o: -E13o(self.e2o.0 * rhs.s.0),
+			e13i: E13i(self.e2i.0 * rhs.s.0),
+			e1oi: E1oi(self.e23.0 * rhs.s.0),
+			e23o: E23o(self.e1o.0 * rhs.s.0),
+			e23i: -E23i(self.e1i.0 * rhs.s.0),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP Translator:
+
+// PointPair.geometric(Translator) -> Versor
+impl Geometric<Translator> for PointPair {
+	type Output = Versor;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.e1i.0 * rhs.s.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: -E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e2o.0 * rhs.e1i.0),
+		//     e13oi: -E13oi(self.e1o.0 * rhs.e3i.0) + E13oi(self.e3o.0 * rhs.e1i.0),
+		//     e23oi: -E23oi(self.e2o.0 * rhs.e3i.0) + E23oi(self.e3o.0 * rhs.e2i.0),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0),
+			e12: E12(self.e12.0 * rhs.s.0) + E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e13.0 * rhs.s.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0)
+				+ E1i(self.e13.0 * rhs.e3i.0)
+				+ E1i(self.e1i.0 * rhs.s.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e23.0 * rhs.s.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+			e2o: E2o(self.e2o.0 * rhs.s.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+			e12oi: -E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e2o.0 * rhs.e1i.0),
+			e13oi: -E13oi(self.e1o.0 * rhs.e3i.0) + E13oi(self.e3o.0 * rhs.e1i.0),
+			e23oi: -E23oi(self.e2o.0 * rhs.e3i.0) + E23oi(self.e3o.0 * rhs.e2i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for PointPair {
+	type Output = Versor;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(Translator) -> Multivector
+impl AntiGeometric<Translator> for PointPair {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e2o.0 * rhs.e3i.0) + E1(self.e3o.0 * rhs.e2i.0),
+		//     e2    : E2(self.e1o.0 * rhs.e3i.0) - E2(self.e3o.0 * rhs.e1i.0),
+		//     e3    : -E3(self.e1o.0 * rhs.e2i.0) + E3(self.e2o.0 * rhs.e1i.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1o.0 * rhs.e1i.0) - E123(self.e2o.0 * rhs.e2i.0) - E123(self.e3o.0 * rhs.e3i.0) - E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : E12o(self.e3o.0 * rhs.s.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.e3i.0 * rhs.s.0) + E12i(self.eoi.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e2o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.s.0) - E13i(self.eoi.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e23.0 * rhs.s.0) - E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3o.0 * rhs.e2i.0),
+		//     e23o  : E23o(self.e1o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.s.0) + E23i(self.eoi.0 * rhs.e1i.0),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0) + E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e3o.0 * rhs.e1i.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) - E3oi(self.e1o.0 * rhs.e2i.0) + E3oi(self.e2o.0 * rhs.e1i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1o.0 * rhs.e1i.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3o.0 * rhs.e3i.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e2o.0 * rhs.e3i.0) + E1(self.e3o.0 * rhs.e2i.0),
+			e2: E2(self.e1o.0 * rhs.e3i.0) - E2(self.e3o.0 * rhs.e1i.0),
+			e3: -E3(self.e1o.0 * rhs.e2i.0) + E3(self.e2o.0 * rhs.e1i.0),
+			eo: Default::default(),
+			ei: Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1o.0 * rhs.e1i.0)
+				- E123(self.e2o.0 * rhs.e2i.0)
+				- E123(self.e3o.0 * rhs.e3i.0)
+				- E123(self.eoi.0 * rhs.s.0),
+			e12o: E12o(self.e3o.0 * rhs.s.0),
+			e12i: -E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.e3i.0 * rhs.s.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0),
+			e13o: -E13o(self.e2o.0 * rhs.s.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.s.0)
+				- E13i(self.eoi.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e23.0 * rhs.s.0) - E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3o.0 * rhs.e2i.0),
+			e23o: E23o(self.e1o.0 * rhs.s.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.s.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0) + E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e3o.0 * rhs.e1i.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) - E3oi(self.e1o.0 * rhs.e2i.0) + E3oi(self.e2o.0 * rhs.e1i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1o.0 * rhs.e1i.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3o.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// PointPair.dot(Translator) -> Versor
+impl Dot<Translator> for PointPair {
+	type Output = Versor;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+			e2o: E2o(self.e2o.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3o: E3o(self.e3o.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for PointPair {
+	type Output = Versor;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// PointPair.wedge(Translator) -> Versor
+impl Wedge<Translator> for PointPair {
+	type Output = Versor;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: -E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e2o.0 * rhs.e1i.0),
+		//     e13oi: -E13oi(self.e1o.0 * rhs.e3i.0) + E13oi(self.e3o.0 * rhs.e1i.0),
+		//     e23oi: -E23oi(self.e2o.0 * rhs.e3i.0) + E23oi(self.e3o.0 * rhs.e2i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: self.e12.wedge(rhs.s),
+			e13: self.e13.wedge(rhs.s),
+			e1o: self.e1o.wedge(rhs.s),
+			e1i: self.e1i.wedge(rhs.s),
+			e23: self.e23.wedge(rhs.s),
+			e2o: self.e2o.wedge(rhs.s),
+			e2i: self.e2i.wedge(rhs.s),
+			e3o: self.e3o.wedge(rhs.s),
+			e3i: self.e3i.wedge(rhs.s),
+			eoi: self.eoi.wedge(rhs.s),
+			e123o: Default::default(),
+			e123i: self.e12.wedge(rhs.e3i) + self.e13.wedge(rhs.e2i) + self.e23.wedge(rhs.e1i),
+			e12oi: self.e1o.wedge(rhs.e2i) + self.e2o.wedge(rhs.e1i),
+			e13oi: self.e1o.wedge(rhs.e3i) + self.e3o.wedge(rhs.e1i),
+			e23oi: self.e2o.wedge(rhs.e3i) + self.e3o.wedge(rhs.e2i),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for PointPair {
+	type Output = Versor;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Translator> for PointPair {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for PointPair {
+	type Output = Zero;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// PointPair.left_contraction(Translator) -> S
+impl LeftContraction<Translator> for PointPair {
+	type Output = S;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0)
+		S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0)
+	}
+}
+
+// PointPair.right_contraction(Translator) -> Versor
+impl RightContraction<Translator> for PointPair {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+			e2o: E2o(self.e2o.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3o: E3o(self.e3o.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// PointPair.scalar_product(Translator) -> S
+impl ScalarProduct<Translator> for PointPair {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator) -> Self::Output {
+		// S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0)
+		S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0)
+	}
+}
+
+// PointPair.commutator(Translator) -> PointPair
+impl Commutator<Translator> for PointPair {
+	type Output = PointPair;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13: E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23: E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi: -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		// }
+		PointPair {
+			e12: E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// PointPair.anti_dot(Translator) -> Multivector
+impl AntiDot<Translator> for PointPair {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : E12o(self.e3o.0 * rhs.s.0),
+		//     e12i  : -E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : -E13o(self.e2o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e2i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e23.0 * rhs.s.0),
+		//     e23o  : E23o(self.e1o.0 * rhs.s.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1o.0 * rhs.e1i.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3o.0 * rhs.e3i.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.eoi.0 * rhs.s.0),
+			e12o: E12o(self.e3o.0 * rhs.s.0),
+			e12i: -E12i(self.e3i.0 * rhs.s.0),
+			e13o: -E13o(self.e2o.0 * rhs.s.0),
+			e13i: E13i(self.e2i.0 * rhs.s.0),
+			e1oi: E1oi(self.e23.0 * rhs.s.0),
+			e23o: E23o(self.e1o.0 * rhs.s.0),
+			e23i: -E23i(self.e1i.0 * rhs.s.0),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1o.0 * rhs.e1i.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3o.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP Versor:
+
+// PointPair.geometric(Versor) -> Versor
+impl Geometric<Versor> for PointPair {
+	type Output = Versor;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) + E1o(self.e1o.0 * rhs.s.0) - E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.e3o.0 * rhs.e13oi.0) - E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e23.0 * rhs.e123i.0) - E1i(self.e2i.0 * rhs.e12.0) + E1i(self.e2i.0 * rhs.e12oi.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e1o.0 * rhs.e12oi.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.e3o.0 * rhs.e23oi.0) - E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e1o.0 * rhs.e13oi.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e2o.0 * rhs.e23oi.0) + E3o(self.e3o.0 * rhs.eoi.0) + E3o(self.e3o.0 * rhs.s.0) - E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e2i.0 * rhs.e23oi.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) + Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e1o.0 * rhs.e23oi.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e23.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e2i.0 * rhs.e13oi.0) + E123i(self.e3i.0 * rhs.e12.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e23.0 * rhs.e13oi.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) + E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0) + E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.e12.0 * rhs.e23.0)
+				+ E13(self.e13.0 * rhs.s.0)
+				+ E13(self.e1i.0 * rhs.e3o.0)
+				+ E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e2i.0 * rhs.e123o.0)
+				- E13(self.e2o.0 * rhs.e123i.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0)
+				+ E1o(self.e13.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.eoi.0)
+				+ E1o(self.e1o.0 * rhs.s.0)
+				- E1o(self.e23.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e2o.0 * rhs.e12oi.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.e3o.0 * rhs.e13oi.0)
+				- E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0)
+				+ E1i(self.e1i.0 * rhs.s.0)
+				- E1i(self.e23.0 * rhs.e123i.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e13.0 * rhs.e123o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e1o.0 * rhs.e12oi.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				+ E2o(self.e2o.0 * rhs.s.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.e3o.0 * rhs.e23oi.0)
+				- E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e1i.0 * rhs.e12.0)
+				- E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0)
+				+ E3o(self.e1o.0 * rhs.e13.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				- E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				+ E3o(self.e3o.0 * rhs.s.0)
+				- E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0)
+				- E3i(self.e1i.0 * rhs.e13oi.0)
+				- E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) + Eoi(self.e1i.0 * rhs.e1o.0)
+				- Eoi(self.e1o.0 * rhs.e1i.0)
+				- Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0)
+				+ E123o(self.e1o.0 * rhs.e23.0)
+				+ E123o(self.e1o.0 * rhs.e23oi.0)
+				+ E123o(self.e23.0 * rhs.e1o.0)
+				- E123o(self.e2o.0 * rhs.e13.0)
+				- E123o(self.e2o.0 * rhs.e13oi.0)
+				+ E123o(self.e3o.0 * rhs.e12.0)
+				+ E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0)
+				- E123i(self.e1i.0 * rhs.e23oi.0)
+				+ E123i(self.e23.0 * rhs.e1i.0)
+				- E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e2i.0 * rhs.e13oi.0)
+				+ E123i(self.e3i.0 * rhs.e12.0)
+				- E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e1i.0 * rhs.e2o.0)
+				- E12oi(self.e1o.0 * rhs.e2i.0)
+				+ E12oi(self.e23.0 * rhs.e13oi.0)
+				- E12oi(self.e2i.0 * rhs.e1o.0)
+				+ E12oi(self.e2o.0 * rhs.e1i.0)
+				+ E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0)
+				+ E12oi(self.eoi.0 * rhs.e12.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) + E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0)
+				- E13oi(self.e1o.0 * rhs.e3i.0)
+				- E13oi(self.e23.0 * rhs.e12oi.0)
+				- E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0)
+				- E13oi(self.e3i.0 * rhs.e1o.0)
+				+ E13oi(self.e3o.0 * rhs.e1i.0)
+				+ E13oi(self.eoi.0 * rhs.e13.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0)
+				+ E23oi(self.e23.0 * rhs.eoi.0)
+				+ E23oi(self.e2i.0 * rhs.e3o.0)
+				- E23oi(self.e2o.0 * rhs.e3i.0)
+				- E23oi(self.e3i.0 * rhs.e2o.0)
+				+ E23oi(self.e3o.0 * rhs.e2i.0)
+				+ E23oi(self.eoi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for PointPair {
+	type Output = Versor;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(Versor) -> Multivector
+impl AntiGeometric<Versor> for PointPair {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0) + E1(self.e23.0 * rhs.eoi.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e2o.0 * rhs.e3i.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.e3o.0 * rhs.e2i.0) + E1(self.eoi.0 * rhs.e23.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e13.0 * rhs.eoi.0) - E2(self.e1i.0 * rhs.e3o.0) + E2(self.e1o.0 * rhs.e3i.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.e3o.0 * rhs.e1i.0) - E2(self.eoi.0 * rhs.e13.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0) + E3(self.e13.0 * rhs.e23oi.0) + E3(self.e1i.0 * rhs.e2o.0) - E3(self.e1o.0 * rhs.e2i.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e2i.0 * rhs.e1o.0) + E3(self.e2o.0 * rhs.e1i.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0) + E3(self.eoi.0 * rhs.e12.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) - Eo(self.e1o.0 * rhs.e23.0) + Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e23.0 * rhs.e1o.0) + Eo(self.e2o.0 * rhs.e13.0) - Eo(self.e2o.0 * rhs.e13oi.0) - Eo(self.e3o.0 * rhs.e12.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0) + Ei(self.e23.0 * rhs.e1i.0) - Ei(self.e2i.0 * rhs.e13.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e1i.0 * rhs.e1o.0) - E123(self.e1o.0 * rhs.e1i.0) + E123(self.e23.0 * rhs.e23oi.0) + E123(self.e2i.0 * rhs.e2o.0) - E123(self.e2o.0 * rhs.e2i.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.e3o.0 * rhs.e3i.0) - E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e13.0 * rhs.e1o.0) - E12o(self.e1o.0 * rhs.e13.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e23.0 * rhs.e2o.0) - E12o(self.e2o.0 * rhs.e23.0) + E12o(self.e2o.0 * rhs.e23oi.0) - E12o(self.e3o.0 * rhs.eoi.0) + E12o(self.e3o.0 * rhs.s.0) + E12o(self.eoi.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) - E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e1i.0 * rhs.e13oi.0) - E12i(self.e23.0 * rhs.e2i.0) + E12i(self.e2i.0 * rhs.e23.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.e3i.0 * rhs.s.0) + E12i(self.eoi.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) + E13o(self.e1o.0 * rhs.e12.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e23.0 * rhs.e3o.0) + E13o(self.e2o.0 * rhs.eoi.0) - E13o(self.e2o.0 * rhs.s.0) - E13o(self.e3o.0 * rhs.e23.0) + E13o(self.e3o.0 * rhs.e23oi.0) - E13o(self.eoi.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0) + E13i(self.e3i.0 * rhs.e23oi.0) - E13i(self.eoi.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.e23.0 * rhs.s.0) - E1oi(self.e2i.0 * rhs.e3o.0) - E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.e3o.0 * rhs.e2i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e1o.0 * rhs.eoi.0) + E23o(self.e1o.0 * rhs.s.0) - E23o(self.e23.0 * rhs.e123o.0) + E23o(self.e2o.0 * rhs.e12.0) - E23o(self.e2o.0 * rhs.e12oi.0) + E23o(self.e3o.0 * rhs.e13.0) - E23o(self.e3o.0 * rhs.e13oi.0) + E23o(self.eoi.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e1i.0 * rhs.s.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13.0) - E23i(self.e3i.0 * rhs.e13oi.0) + E23i(self.eoi.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e13.0 * rhs.s.0) + E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e23.0 * rhs.e12.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.e3o.0 * rhs.e1i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e1i.0 * rhs.e2o.0) - E3oi(self.e1o.0 * rhs.e2i.0) - E3oi(self.e23.0 * rhs.e13.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.e2o.0 * rhs.e1i.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0)
+				+ E1(self.e1o.0 * rhs.e123i.0)
+				+ E1(self.e23.0 * rhs.eoi.0)
+				+ E1(self.e2i.0 * rhs.e3o.0)
+				- E1(self.e2o.0 * rhs.e3i.0)
+				- E1(self.e3i.0 * rhs.e2o.0)
+				+ E1(self.e3o.0 * rhs.e2i.0)
+				+ E1(self.eoi.0 * rhs.e23.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e13.0 * rhs.eoi.0) - E2(self.e1i.0 * rhs.e3o.0)
+				+ E2(self.e1o.0 * rhs.e3i.0)
+				- E2(self.e23.0 * rhs.e12oi.0)
+				- E2(self.e2i.0 * rhs.e123o.0)
+				+ E2(self.e2o.0 * rhs.e123i.0)
+				+ E2(self.e3i.0 * rhs.e1o.0)
+				- E2(self.e3o.0 * rhs.e1i.0)
+				- E2(self.eoi.0 * rhs.e13.0),
+			e3: E3(self.e12.0 * rhs.eoi.0) + E3(self.e13.0 * rhs.e23oi.0) + E3(self.e1i.0 * rhs.e2o.0)
+				- E3(self.e1o.0 * rhs.e2i.0)
+				- E3(self.e23.0 * rhs.e13oi.0)
+				- E3(self.e2i.0 * rhs.e1o.0)
+				+ E3(self.e2o.0 * rhs.e1i.0)
+				- E3(self.e3i.0 * rhs.e123o.0)
+				+ E3(self.e3o.0 * rhs.e123i.0)
+				+ E3(self.eoi.0 * rhs.e12.0),
+			eo: -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) - Eo(self.e1o.0 * rhs.e23.0)
+				+ Eo(self.e1o.0 * rhs.e23oi.0)
+				- Eo(self.e23.0 * rhs.e1o.0)
+				+ Eo(self.e2o.0 * rhs.e13.0)
+				- Eo(self.e2o.0 * rhs.e13oi.0)
+				- Eo(self.e3o.0 * rhs.e12.0)
+				+ Eo(self.e3o.0 * rhs.e12oi.0)
+				- Eo(self.eoi.0 * rhs.e123o.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0)
+				+ Ei(self.e1i.0 * rhs.e23.0)
+				+ Ei(self.e1i.0 * rhs.e23oi.0)
+				+ Ei(self.e23.0 * rhs.e1i.0)
+				- Ei(self.e2i.0 * rhs.e13.0)
+				- Ei(self.e2i.0 * rhs.e13oi.0)
+				+ Ei(self.e3i.0 * rhs.e12.0)
+				+ Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e1i.0 * rhs.e1o.0)
+				- E123(self.e1o.0 * rhs.e1i.0)
+				+ E123(self.e23.0 * rhs.e23oi.0)
+				+ E123(self.e2i.0 * rhs.e2o.0)
+				- E123(self.e2o.0 * rhs.e2i.0)
+				+ E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.e3o.0 * rhs.e3i.0)
+				- E123(self.eoi.0 * rhs.s.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e13.0 * rhs.e1o.0) - E12o(self.e1o.0 * rhs.e13.0)
+				+ E12o(self.e1o.0 * rhs.e13oi.0)
+				+ E12o(self.e23.0 * rhs.e2o.0)
+				- E12o(self.e2o.0 * rhs.e23.0)
+				+ E12o(self.e2o.0 * rhs.e23oi.0)
+				- E12o(self.e3o.0 * rhs.eoi.0)
+				+ E12o(self.e3o.0 * rhs.s.0)
+				+ E12o(self.eoi.0 * rhs.e3o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) - E12i(self.e13.0 * rhs.e1i.0)
+				+ E12i(self.e1i.0 * rhs.e13.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				- E12i(self.e23.0 * rhs.e2i.0)
+				+ E12i(self.e2i.0 * rhs.e23.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0),
+			e13o: -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) + E13o(self.e1o.0 * rhs.e12.0)
+				- E13o(self.e1o.0 * rhs.e12oi.0)
+				+ E13o(self.e23.0 * rhs.e3o.0)
+				+ E13o(self.e2o.0 * rhs.eoi.0)
+				- E13o(self.e2o.0 * rhs.s.0)
+				- E13o(self.e3o.0 * rhs.e23.0)
+				+ E13o(self.e3o.0 * rhs.e23oi.0)
+				- E13o(self.eoi.0 * rhs.e2o.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0)
+				- E13i(self.e1i.0 * rhs.e12.0)
+				- E13i(self.e1i.0 * rhs.e12oi.0)
+				- E13i(self.e23.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				- E13i(self.eoi.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0)
+				+ E1oi(self.e1i.0 * rhs.e123o.0)
+				+ E1oi(self.e1o.0 * rhs.e123i.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				- E1oi(self.e2i.0 * rhs.e3o.0)
+				- E1oi(self.e2o.0 * rhs.e3i.0)
+				+ E1oi(self.e3i.0 * rhs.e2o.0)
+				+ E1oi(self.e3o.0 * rhs.e2i.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e1o.0 * rhs.eoi.0)
+				+ E23o(self.e1o.0 * rhs.s.0)
+				- E23o(self.e23.0 * rhs.e123o.0)
+				+ E23o(self.e2o.0 * rhs.e12.0)
+				- E23o(self.e2o.0 * rhs.e12oi.0)
+				+ E23o(self.e3o.0 * rhs.e13.0)
+				- E23o(self.e3o.0 * rhs.e13oi.0)
+				+ E23o(self.eoi.0 * rhs.e1o.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				+ E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e13.0 * rhs.s.0)
+				+ E2oi(self.e1i.0 * rhs.e3o.0)
+				+ E2oi(self.e1o.0 * rhs.e3i.0)
+				- E2oi(self.e23.0 * rhs.e12.0)
+				+ E2oi(self.e2i.0 * rhs.e123o.0)
+				+ E2oi(self.e2o.0 * rhs.e123i.0)
+				- E2oi(self.e3i.0 * rhs.e1o.0)
+				- E2oi(self.e3o.0 * rhs.e1i.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0)
+				- E3oi(self.e1i.0 * rhs.e2o.0)
+				- E3oi(self.e1o.0 * rhs.e2i.0)
+				- E3oi(self.e23.0 * rhs.e13.0)
+				+ E3oi(self.e2i.0 * rhs.e1o.0)
+				+ E3oi(self.e2o.0 * rhs.e1i.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.e3o.0 * rhs.e123i.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// PointPair.dot(Versor) -> Versor
+impl Dot<Versor> for PointPair {
+	type Output = Versor;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0) - E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0) - E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0) + E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e12.0 * rhs.s.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.e13.0 * rhs.s.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0)
+				- E1o(self.e23.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12oi.0)
+				- E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0) - E1i(self.e23.0 * rhs.e123i.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) + E2o(self.e2o.0 * rhs.s.0)
+				- E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0)
+				+ E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0)
+				+ E3i(self.e3i.0 * rhs.s.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for PointPair {
+	type Output = Versor;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// PointPair.wedge(Versor) -> Versor
+impl Wedge<Versor> for PointPair {
+	type Output = Versor;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) + E123i(self.e23.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: self.e12.wedge(rhs.s),
+			e13: self.e13.wedge(rhs.s),
+			e1o: self.e1o.wedge(rhs.s),
+			e1i: self.e1i.wedge(rhs.s),
+			e23: self.e23.wedge(rhs.s),
+			e2o: self.e2o.wedge(rhs.s),
+			e2i: self.e2i.wedge(rhs.s),
+			e3o: self.e3o.wedge(rhs.s),
+			e3i: self.e3i.wedge(rhs.s),
+			eoi: self.eoi.wedge(rhs.s),
+			e123o: self.e12.wedge(rhs.e3o)
+				+ self.e13.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e13)
+				+ self.e3o.wedge(rhs.e12),
+			e123i: self.e12.wedge(rhs.e3i)
+				+ self.e13.wedge(rhs.e2i)
+				+ self.e1i.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1i)
+				+ self.e2i.wedge(rhs.e13)
+				+ self.e3i.wedge(rhs.e12),
+			e12oi: self.e12.wedge(rhs.eoi)
+				+ self.e1i.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e2i)
+				+ self.e2i.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e12),
+			e13oi: self.e13.wedge(rhs.eoi)
+				+ self.e1i.wedge(rhs.e3o)
+				+ self.e1o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e1o)
+				+ self.e3o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e13),
+			e23oi: self.e23.wedge(rhs.eoi)
+				+ self.e2i.wedge(rhs.e3o)
+				+ self.e2o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e2o)
+				+ self.e3o.wedge(rhs.e2i)
+				+ self.eoi.wedge(rhs.e23),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for PointPair {
+	type Output = Versor;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// PointPair.anti_wedge(Versor) -> Point
+impl AntiWedge<Versor> for PointPair {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0),
+		//     y  : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0),
+		//     z  : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0),
+		//     o  : Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0),
+		//     inf: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		// }
+		Point {
+			x: self.e12.anti_wedge(rhs.e13oi)
+				+ self.e13.anti_wedge(rhs.e12oi)
+				+ self.e1i.anti_wedge(rhs.e123o)
+				+ self.e1o.anti_wedge(rhs.e123i),
+			y: self.e12.anti_wedge(rhs.e23oi)
+				+ self.e23.anti_wedge(rhs.e12oi)
+				+ self.e2i.anti_wedge(rhs.e123o)
+				+ self.e2o.anti_wedge(rhs.e123i),
+			z: self.e13.anti_wedge(rhs.e23oi)
+				+ self.e23.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e123o)
+				+ self.e3o.anti_wedge(rhs.e123i),
+			o: self.e1o.anti_wedge(rhs.e23oi)
+				+ self.e2o.anti_wedge(rhs.e13oi)
+				+ self.e3o.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123o),
+			inf: self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Versor> for PointPair {
+	type Output = Point;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// PointPair.left_contraction(Versor) -> Versor
+impl LeftContraction<Versor> for PointPair {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// PointPair.right_contraction(Versor) -> Versor
+impl RightContraction<Versor> for PointPair {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+			e2o: E2o(self.e2o.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3o: E3o(self.e3o.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// PointPair.scalar_product(Versor) -> S
+impl ScalarProduct<Versor> for PointPair {
+	type Output = S;
+	fn scalar_product(self, rhs: Versor) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0)
+			- S(self.e23.0 * rhs.e23.0)
+			+ S(self.e2i.0 * rhs.e2o.0)
+			+ S(self.e2o.0 * rhs.e2i.0)
+			+ S(self.e3i.0 * rhs.e3o.0)
+			+ S(self.e3o.0 * rhs.e3i.0)
+			+ S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// PointPair.commutator(Versor) -> Versor
+impl Commutator<Versor> for PointPair {
+	type Output = Versor;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13.0 * rhs.e23.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o  : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.eoi.0) - E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		//     e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				- E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0),
+			e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+// PointPair.anti_dot(Versor) -> Multivector
+impl AntiDot<Versor> for PointPair {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0) - E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0) + E12o(self.e3o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) - E13o(self.e2o.0 * rhs.s.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.e23.0 * rhs.s.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : E23o(self.e1o.0 * rhs.s.0) - E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0)
+				- E123(self.eoi.0 * rhs.s.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0)
+				+ E12o(self.e1o.0 * rhs.e13oi.0)
+				+ E12o(self.e2o.0 * rhs.e23oi.0)
+				+ E12o(self.e3o.0 * rhs.s.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.s.0),
+			e13o: -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) - E13o(self.e2o.0 * rhs.s.0)
+				+ E13o(self.e3o.0 * rhs.e23oi.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0)
+				+ E1oi(self.e1o.0 * rhs.e123i.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: E23o(self.e1o.0 * rhs.s.0)
+				- E23o(self.e23.0 * rhs.e123o.0)
+				- E23o(self.e2o.0 * rhs.e12oi.0)
+				- E23o(self.e3o.0 * rhs.e13oi.0),
+			e23i: -E23i(self.e1i.0 * rhs.s.0) + E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.e3o.0 * rhs.e123i.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// PointPair OP Multivector:
+
+// PointPair.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for PointPair {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0) - E1(self.e1i.0 * rhs.eo.0) - E1(self.e1o.0 * rhs.ei.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) + E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e23.0 * rhs.e3.0) - E2(self.e2i.0 * rhs.eo.0) - E2(self.e2o.0 * rhs.ei.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) - E3(self.e13.0 * rhs.e1.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e23.0 * rhs.e2.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) - E3(self.e3i.0 * rhs.eo.0) - E3(self.e3o.0 * rhs.ei.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3.0) - Eo(self.e3o.0 * rhs.e3oi.0) - Eo(self.eoi.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) - Ei(self.e2i.0 * rhs.e2.0) + Ei(self.e2i.0 * rhs.e2oi.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.e3i.0 * rhs.e3oi.0) + Ei(self.eoi.0 * rhs.ei.0),
+		//     e12   : E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13   : E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o   : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) + E1o(self.e1o.0 * rhs.s.0) - E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.e3o.0 * rhs.e13oi.0) - E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i   : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e23.0 * rhs.e123i.0) - E1i(self.e2i.0 * rhs.e12.0) + E1i(self.e2i.0 * rhs.e12oi.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23   : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o   : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e1o.0 * rhs.e12oi.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.e3o.0 * rhs.e23oi.0) - E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i   : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e1o.0 * rhs.e13oi.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e2o.0 * rhs.e23oi.0) + E3o(self.e3o.0 * rhs.eoi.0) + E3o(self.e3o.0 * rhs.s.0) - E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i   : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e2i.0 * rhs.e23oi.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi   : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) + Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123  : E123(self.e12.0 * rhs.e3.0) - E123(self.e13.0 * rhs.e2.0) - E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0) + E123(self.e23.0 * rhs.e1.0) + E123(self.e2i.0 * rhs.e13o.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3i.0 * rhs.e12o.0) - E123(self.e3o.0 * rhs.e12i.0) + E123(self.eoi.0 * rhs.e123oi.0),
+		//     e12o  : E12o(self.e12.0 * rhs.eo.0) - E12o(self.e13.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2.0) - E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e23.0 * rhs.e13o.0) + E12o(self.e2o.0 * rhs.e1.0) + E12o(self.e2o.0 * rhs.e1oi.0) - E12o(self.e3o.0 * rhs.e123.0) - E12o(self.e3o.0 * rhs.e123oi.0) - E12o(self.eoi.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.ei.0) - E12i(self.e13.0 * rhs.e23i.0) - E12i(self.e1i.0 * rhs.e2.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) + E12i(self.e2i.0 * rhs.e1.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e12.0 * rhs.e23o.0) + E13o(self.e13.0 * rhs.eo.0) - E13o(self.e1o.0 * rhs.e3.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.e2o.0 * rhs.e123.0) + E13o(self.e2o.0 * rhs.e123oi.0) + E13o(self.e3o.0 * rhs.e1.0) + E13o(self.e3o.0 * rhs.e1oi.0) - E13o(self.eoi.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e13.0 * rhs.ei.0) - E13i(self.e1i.0 * rhs.e3.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.e3i.0 * rhs.e1.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) - E1oi(self.e1i.0 * rhs.eo.0) + E1oi(self.e1o.0 * rhs.ei.0) - E1oi(self.e23.0 * rhs.e123oi.0) + E1oi(self.e2i.0 * rhs.e12o.0) - E1oi(self.e2o.0 * rhs.e12i.0) + E1oi(self.e3i.0 * rhs.e13o.0) - E1oi(self.e3o.0 * rhs.e13i.0) + E1oi(self.eoi.0 * rhs.e1.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0) - E23o(self.e1o.0 * rhs.e123.0) - E23o(self.e1o.0 * rhs.e123oi.0) + E23o(self.e23.0 * rhs.eo.0) - E23o(self.e2o.0 * rhs.e3.0) - E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2.0) + E23o(self.e3o.0 * rhs.e2oi.0) - E23o(self.eoi.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e1i.0 * rhs.e123oi.0) + E23i(self.e23.0 * rhs.ei.0) - E23i(self.e2i.0 * rhs.e3.0) + E23i(self.e2i.0 * rhs.e3oi.0) + E23i(self.e3i.0 * rhs.e2.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e13.0 * rhs.e123oi.0) - E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) - E2oi(self.e2i.0 * rhs.eo.0) + E2oi(self.e2o.0 * rhs.ei.0) + E2oi(self.e3i.0 * rhs.e23o.0) - E2oi(self.e3o.0 * rhs.e23i.0) + E2oi(self.eoi.0 * rhs.e2.0),
+		//     e3oi  : -E3oi(self.e12.0 * rhs.e123oi.0) - E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) - E3oi(self.e2i.0 * rhs.e23o.0) + E3oi(self.e2o.0 * rhs.e23i.0) - E3oi(self.e3i.0 * rhs.eo.0) + E3oi(self.e3o.0 * rhs.ei.0) + E3oi(self.eoi.0 * rhs.e3.0),
+		//     e123o : E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e1o.0 * rhs.e23oi.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i : E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e23.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e2i.0 * rhs.e13oi.0) + E123i(self.e3i.0 * rhs.e12.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e23.0 * rhs.e13oi.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e23oi.0) + E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi : -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0) + E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e2o.0 * rhs.e13i.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.e3o.0 * rhs.e12i.0) + E123oi(self.eoi.0 * rhs.e123.0),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e1: E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0)
+				- E1(self.e1i.0 * rhs.eo.0)
+				- E1(self.e1o.0 * rhs.ei.0)
+				- E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e12.0 * rhs.e1.0) + E2(self.e13.0 * rhs.e123.0)
+				- E2(self.e1i.0 * rhs.e12o.0)
+				- E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e23.0 * rhs.e3.0)
+				- E2(self.e2i.0 * rhs.eo.0)
+				- E2(self.e2o.0 * rhs.ei.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e12.0 * rhs.e123.0)
+				- E3(self.e13.0 * rhs.e1.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e23.0 * rhs.e2.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				- E3(self.e3i.0 * rhs.eo.0)
+				- E3(self.e3o.0 * rhs.ei.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0)
+				- Eo(self.eoi.0 * rhs.eo.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e1i.0 * rhs.e1.0)
+				+ Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				- Ei(self.e2i.0 * rhs.e2.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				- Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0)
+				+ Ei(self.eoi.0 * rhs.ei.0),
+			e12: E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.e12.0 * rhs.e23.0)
+				+ E13(self.e13.0 * rhs.s.0)
+				+ E13(self.e1i.0 * rhs.e3o.0)
+				+ E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e2i.0 * rhs.e123o.0)
+				- E13(self.e2o.0 * rhs.e123i.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0)
+				+ E1o(self.e13.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.eoi.0)
+				+ E1o(self.e1o.0 * rhs.s.0)
+				- E1o(self.e23.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e2o.0 * rhs.e12oi.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.e3o.0 * rhs.e13oi.0)
+				- E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0)
+				+ E1i(self.e1i.0 * rhs.s.0)
+				- E1i(self.e23.0 * rhs.e123i.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e13.0 * rhs.e123o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e1o.0 * rhs.e12oi.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				+ E2o(self.e2o.0 * rhs.s.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.e3o.0 * rhs.e23oi.0)
+				- E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e1i.0 * rhs.e12.0)
+				- E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0)
+				+ E3o(self.e1o.0 * rhs.e13.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				- E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				+ E3o(self.e3o.0 * rhs.s.0)
+				- E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0)
+				- E3i(self.e1i.0 * rhs.e13oi.0)
+				- E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) + Eoi(self.e1i.0 * rhs.e1o.0)
+				- Eoi(self.e1o.0 * rhs.e1i.0)
+				- Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123: E123(self.e12.0 * rhs.e3.0)
+				- E123(self.e13.0 * rhs.e2.0)
+				- E123(self.e1i.0 * rhs.e23o.0)
+				- E123(self.e1o.0 * rhs.e23i.0)
+				+ E123(self.e23.0 * rhs.e1.0)
+				+ E123(self.e2i.0 * rhs.e13o.0)
+				+ E123(self.e2o.0 * rhs.e13i.0)
+				- E123(self.e3i.0 * rhs.e12o.0)
+				- E123(self.e3o.0 * rhs.e12i.0)
+				+ E123(self.eoi.0 * rhs.e123oi.0),
+			e12o: E12o(self.e12.0 * rhs.eo.0)
+				- E12o(self.e13.0 * rhs.e23o.0)
+				- E12o(self.e1o.0 * rhs.e2.0)
+				- E12o(self.e1o.0 * rhs.e2oi.0)
+				+ E12o(self.e23.0 * rhs.e13o.0)
+				+ E12o(self.e2o.0 * rhs.e1.0)
+				+ E12o(self.e2o.0 * rhs.e1oi.0)
+				- E12o(self.e3o.0 * rhs.e123.0)
+				- E12o(self.e3o.0 * rhs.e123oi.0)
+				- E12o(self.eoi.0 * rhs.e12o.0),
+			e12i: E12i(self.e12.0 * rhs.ei.0) - E12i(self.e13.0 * rhs.e23i.0) - E12i(self.e1i.0 * rhs.e2.0)
+				+ E12i(self.e1i.0 * rhs.e2oi.0)
+				+ E12i(self.e23.0 * rhs.e13i.0)
+				+ E12i(self.e2i.0 * rhs.e1.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				- E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.e3i.0 * rhs.e123oi.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) + E13o(self.e13.0 * rhs.eo.0)
+				- E13o(self.e1o.0 * rhs.e3.0)
+				- E13o(self.e1o.0 * rhs.e3oi.0)
+				- E13o(self.e23.0 * rhs.e12o.0)
+				+ E13o(self.e2o.0 * rhs.e123.0)
+				+ E13o(self.e2o.0 * rhs.e123oi.0)
+				+ E13o(self.e3o.0 * rhs.e1.0)
+				+ E13o(self.e3o.0 * rhs.e1oi.0)
+				- E13o(self.eoi.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e13.0 * rhs.ei.0) - E13i(self.e1i.0 * rhs.e3.0)
+				+ E13i(self.e1i.0 * rhs.e3oi.0)
+				- E13i(self.e23.0 * rhs.e12i.0)
+				+ E13i(self.e2i.0 * rhs.e123.0)
+				- E13i(self.e2i.0 * rhs.e123oi.0)
+				+ E13i(self.e3i.0 * rhs.e1.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) - E1oi(self.e1i.0 * rhs.eo.0)
+				+ E1oi(self.e1o.0 * rhs.ei.0)
+				- E1oi(self.e23.0 * rhs.e123oi.0)
+				+ E1oi(self.e2i.0 * rhs.e12o.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				+ E1oi(self.e3i.0 * rhs.e13o.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0)
+				+ E1oi(self.eoi.0 * rhs.e1.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0)
+				- E23o(self.e1o.0 * rhs.e123.0)
+				- E23o(self.e1o.0 * rhs.e123oi.0)
+				+ E23o(self.e23.0 * rhs.eo.0)
+				- E23o(self.e2o.0 * rhs.e3.0)
+				- E23o(self.e2o.0 * rhs.e3oi.0)
+				+ E23o(self.e3o.0 * rhs.e2.0)
+				+ E23o(self.e3o.0 * rhs.e2oi.0)
+				- E23o(self.eoi.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0)
+				+ E23i(self.e1i.0 * rhs.e123oi.0)
+				+ E23i(self.e23.0 * rhs.ei.0)
+				- E23i(self.e2i.0 * rhs.e3.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				+ E23i(self.e3i.0 * rhs.e2.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e13.0 * rhs.e123oi.0) - E2oi(self.e1i.0 * rhs.e12o.0)
+				+ E2oi(self.e1o.0 * rhs.e12i.0)
+				+ E2oi(self.e23.0 * rhs.e3oi.0)
+				- E2oi(self.e2i.0 * rhs.eo.0)
+				+ E2oi(self.e2o.0 * rhs.ei.0)
+				+ E2oi(self.e3i.0 * rhs.e23o.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0)
+				+ E2oi(self.eoi.0 * rhs.e2.0),
+			e3oi: -E3oi(self.e12.0 * rhs.e123oi.0) - E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0)
+				+ E3oi(self.e1o.0 * rhs.e13i.0)
+				- E3oi(self.e23.0 * rhs.e2oi.0)
+				- E3oi(self.e2i.0 * rhs.e23o.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0)
+				- E3oi(self.e3i.0 * rhs.eo.0)
+				+ E3oi(self.e3o.0 * rhs.ei.0)
+				+ E3oi(self.eoi.0 * rhs.e3.0),
+			e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0)
+				+ E123o(self.e1o.0 * rhs.e23.0)
+				+ E123o(self.e1o.0 * rhs.e23oi.0)
+				+ E123o(self.e23.0 * rhs.e1o.0)
+				- E123o(self.e2o.0 * rhs.e13.0)
+				- E123o(self.e2o.0 * rhs.e13oi.0)
+				+ E123o(self.e3o.0 * rhs.e12.0)
+				+ E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0)
+				- E123i(self.e1i.0 * rhs.e23oi.0)
+				+ E123i(self.e23.0 * rhs.e1i.0)
+				- E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e2i.0 * rhs.e13oi.0)
+				+ E123i(self.e3i.0 * rhs.e12.0)
+				- E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e1i.0 * rhs.e2o.0)
+				- E12oi(self.e1o.0 * rhs.e2i.0)
+				+ E12oi(self.e23.0 * rhs.e13oi.0)
+				- E12oi(self.e2i.0 * rhs.e1o.0)
+				+ E12oi(self.e2o.0 * rhs.e1i.0)
+				+ E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0)
+				+ E12oi(self.eoi.0 * rhs.e12.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) + E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0)
+				- E13oi(self.e1o.0 * rhs.e3i.0)
+				- E13oi(self.e23.0 * rhs.e12oi.0)
+				- E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0)
+				- E13oi(self.e3i.0 * rhs.e1o.0)
+				+ E13oi(self.e3o.0 * rhs.e1i.0)
+				+ E13oi(self.eoi.0 * rhs.e13.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0)
+				+ E23oi(self.e23.0 * rhs.eoi.0)
+				+ E23oi(self.e2i.0 * rhs.e3o.0)
+				- E23oi(self.e2o.0 * rhs.e3i.0)
+				- E23oi(self.e3i.0 * rhs.e2o.0)
+				+ E23oi(self.e3o.0 * rhs.e2i.0)
+				+ E23oi(self.eoi.0 * rhs.e23.0),
+			e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e1i.0 * rhs.e23o.0)
+				+ E123oi(self.e1o.0 * rhs.e23i.0)
+				+ E123oi(self.e23.0 * rhs.e1oi.0)
+				+ E123oi(self.e2i.0 * rhs.e13o.0)
+				- E123oi(self.e2o.0 * rhs.e13i.0)
+				- E123oi(self.e3i.0 * rhs.e12o.0)
+				+ E123oi(self.e3o.0 * rhs.e12i.0)
+				+ E123oi(self.eoi.0 * rhs.e123.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for PointPair {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// PointPair.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for PointPair {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e1i.0 * rhs.e23o.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e2o.0 * rhs.e13i.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.e3o.0 * rhs.e12i.0) + S(self.eoi.0 * rhs.e123.0),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0) + E1(self.e23.0 * rhs.eoi.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e2o.0 * rhs.e3i.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.e3o.0 * rhs.e2i.0) + E1(self.eoi.0 * rhs.e23.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e13.0 * rhs.eoi.0) - E2(self.e1i.0 * rhs.e3o.0) + E2(self.e1o.0 * rhs.e3i.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.e3o.0 * rhs.e1i.0) - E2(self.eoi.0 * rhs.e13.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0) + E3(self.e13.0 * rhs.e23oi.0) + E3(self.e1i.0 * rhs.e2o.0) - E3(self.e1o.0 * rhs.e2i.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e2i.0 * rhs.e1o.0) + E3(self.e2o.0 * rhs.e1i.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0) + E3(self.eoi.0 * rhs.e12.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) - Eo(self.e1o.0 * rhs.e23.0) + Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e23.0 * rhs.e1o.0) + Eo(self.e2o.0 * rhs.e13.0) - Eo(self.e2o.0 * rhs.e13oi.0) - Eo(self.e3o.0 * rhs.e12.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0) + Ei(self.e23.0 * rhs.e1i.0) - Ei(self.e2i.0 * rhs.e13.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : E12(self.e12.0 * rhs.e123oi.0) - E12(self.e13.0 * rhs.e1oi.0) - E12(self.e1i.0 * rhs.e13o.0) + E12(self.e1o.0 * rhs.e13i.0) - E12(self.e23.0 * rhs.e2oi.0) - E12(self.e2i.0 * rhs.e23o.0) + E12(self.e2o.0 * rhs.e23i.0) + E12(self.e3i.0 * rhs.eo.0) - E12(self.e3o.0 * rhs.ei.0) - E12(self.eoi.0 * rhs.e3.0),
+		//     e13   : E13(self.e12.0 * rhs.e1oi.0) + E13(self.e13.0 * rhs.e123oi.0) + E13(self.e1i.0 * rhs.e12o.0) - E13(self.e1o.0 * rhs.e12i.0) - E13(self.e23.0 * rhs.e3oi.0) - E13(self.e2i.0 * rhs.eo.0) + E13(self.e2o.0 * rhs.ei.0) - E13(self.e3i.0 * rhs.e23o.0) + E13(self.e3o.0 * rhs.e23i.0) + E13(self.eoi.0 * rhs.e2.0),
+		//     e1o   : -E1o(self.e12.0 * rhs.e13o.0) + E1o(self.e13.0 * rhs.e12o.0) - E1o(self.e1o.0 * rhs.e123.0) + E1o(self.e1o.0 * rhs.e123oi.0) - E1o(self.e23.0 * rhs.eo.0) + E1o(self.e2o.0 * rhs.e3.0) - E1o(self.e2o.0 * rhs.e3oi.0) - E1o(self.e3o.0 * rhs.e2.0) + E1o(self.e3o.0 * rhs.e2oi.0) - E1o(self.eoi.0 * rhs.e23o.0),
+		//     e1i   : E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.e1i.0 * rhs.e123.0) + E1i(self.e1i.0 * rhs.e123oi.0) + E1i(self.e23.0 * rhs.ei.0) - E1i(self.e2i.0 * rhs.e3.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2.0) + E1i(self.e3i.0 * rhs.e2oi.0) - E1i(self.eoi.0 * rhs.e23i.0),
+		//     e23   : E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) + E23(self.e1i.0 * rhs.eo.0) - E23(self.e1o.0 * rhs.ei.0) + E23(self.e23.0 * rhs.e123oi.0) + E23(self.e2i.0 * rhs.e12o.0) - E23(self.e2o.0 * rhs.e12i.0) + E23(self.e3i.0 * rhs.e13o.0) - E23(self.e3o.0 * rhs.e13i.0) - E23(self.eoi.0 * rhs.e1.0),
+		//     e2o   : -E2o(self.e12.0 * rhs.e23o.0) + E2o(self.e13.0 * rhs.eo.0) - E2o(self.e1o.0 * rhs.e3.0) + E2o(self.e1o.0 * rhs.e3oi.0) + E2o(self.e23.0 * rhs.e12o.0) - E2o(self.e2o.0 * rhs.e123.0) + E2o(self.e2o.0 * rhs.e123oi.0) + E2o(self.e3o.0 * rhs.e1.0) - E2o(self.e3o.0 * rhs.e1oi.0) + E2o(self.eoi.0 * rhs.e13o.0),
+		//     e2i   : E2i(self.e12.0 * rhs.e23i.0) - E2i(self.e13.0 * rhs.ei.0) + E2i(self.e1i.0 * rhs.e3.0) + E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e23.0 * rhs.e12i.0) + E2i(self.e2i.0 * rhs.e123.0) + E2i(self.e2i.0 * rhs.e123oi.0) - E2i(self.e3i.0 * rhs.e1.0) - E2i(self.e3i.0 * rhs.e1oi.0) + E2i(self.eoi.0 * rhs.e13i.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.eo.0) - E3o(self.e13.0 * rhs.e23o.0) + E3o(self.e1o.0 * rhs.e2.0) - E3o(self.e1o.0 * rhs.e2oi.0) + E3o(self.e23.0 * rhs.e13o.0) - E3o(self.e2o.0 * rhs.e1.0) + E3o(self.e2o.0 * rhs.e1oi.0) - E3o(self.e3o.0 * rhs.e123.0) + E3o(self.e3o.0 * rhs.e123oi.0) - E3o(self.eoi.0 * rhs.e12o.0),
+		//     e3i   : E3i(self.e12.0 * rhs.ei.0) + E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e1i.0 * rhs.e2.0) - E3i(self.e1i.0 * rhs.e2oi.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.e2i.0 * rhs.e1.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.e3i.0 * rhs.e123.0) + E3i(self.e3i.0 * rhs.e123oi.0) - E3i(self.eoi.0 * rhs.e12i.0),
+		//     eoi   : Eoi(self.e12.0 * rhs.e3.0) - Eoi(self.e13.0 * rhs.e2.0) + Eoi(self.e1i.0 * rhs.e23o.0) + Eoi(self.e1o.0 * rhs.e23i.0) + Eoi(self.e23.0 * rhs.e1.0) - Eoi(self.e2i.0 * rhs.e13o.0) - Eoi(self.e2o.0 * rhs.e13i.0) + Eoi(self.e3i.0 * rhs.e12o.0) + Eoi(self.e3o.0 * rhs.e12i.0) + Eoi(self.eoi.0 * rhs.e123oi.0),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e1i.0 * rhs.e1o.0) - E123(self.e1o.0 * rhs.e1i.0) + E123(self.e23.0 * rhs.e23oi.0) + E123(self.e2i.0 * rhs.e2o.0) - E123(self.e2o.0 * rhs.e2i.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.e3o.0 * rhs.e3i.0) - E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e13.0 * rhs.e1o.0) - E12o(self.e1o.0 * rhs.e13.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e23.0 * rhs.e2o.0) - E12o(self.e2o.0 * rhs.e23.0) + E12o(self.e2o.0 * rhs.e23oi.0) - E12o(self.e3o.0 * rhs.eoi.0) + E12o(self.e3o.0 * rhs.s.0) + E12o(self.eoi.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) - E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e1i.0 * rhs.e13oi.0) - E12i(self.e23.0 * rhs.e2i.0) + E12i(self.e2i.0 * rhs.e23.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.e3i.0 * rhs.s.0) + E12i(self.eoi.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) + E13o(self.e1o.0 * rhs.e12.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e23.0 * rhs.e3o.0) + E13o(self.e2o.0 * rhs.eoi.0) - E13o(self.e2o.0 * rhs.s.0) - E13o(self.e3o.0 * rhs.e23.0) + E13o(self.e3o.0 * rhs.e23oi.0) - E13o(self.eoi.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0) + E13i(self.e3i.0 * rhs.e23oi.0) - E13i(self.eoi.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.e23.0 * rhs.s.0) - E1oi(self.e2i.0 * rhs.e3o.0) - E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.e3o.0 * rhs.e2i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e1o.0 * rhs.eoi.0) + E23o(self.e1o.0 * rhs.s.0) - E23o(self.e23.0 * rhs.e123o.0) + E23o(self.e2o.0 * rhs.e12.0) - E23o(self.e2o.0 * rhs.e12oi.0) + E23o(self.e3o.0 * rhs.e13.0) - E23o(self.e3o.0 * rhs.e13oi.0) + E23o(self.eoi.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e1i.0 * rhs.s.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13.0) - E23i(self.e3i.0 * rhs.e13oi.0) + E23i(self.eoi.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e13.0 * rhs.s.0) + E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e23.0 * rhs.e12.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.e3o.0 * rhs.e1i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e1i.0 * rhs.e2o.0) - E3oi(self.e1o.0 * rhs.e2i.0) - E3oi(self.e23.0 * rhs.e13.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.e2o.0 * rhs.e1i.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) + E123o(self.e1o.0 * rhs.e1.0) - E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e23.0 * rhs.e23o.0) + E123o(self.e2o.0 * rhs.e2.0) - E123o(self.e2o.0 * rhs.e2oi.0) + E123o(self.e3o.0 * rhs.e3.0) - E123o(self.e3o.0 * rhs.e3oi.0) + E123o(self.eoi.0 * rhs.eo.0),
+		//     e123i : E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3.0) - E123i(self.e3i.0 * rhs.e3oi.0) + E123i(self.eoi.0 * rhs.ei.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.e123.0) - E12oi(self.e13.0 * rhs.e1.0) + E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e1o.0 * rhs.e13i.0) - E12oi(self.e23.0 * rhs.e2.0) + E12oi(self.e2i.0 * rhs.e23o.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.e3i.0 * rhs.eo.0) - E12oi(self.e3o.0 * rhs.ei.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e1.0) + E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e1i.0 * rhs.e12o.0) - E13oi(self.e1o.0 * rhs.e12i.0) - E13oi(self.e23.0 * rhs.e3.0) + E13oi(self.e2i.0 * rhs.eo.0) + E13oi(self.e2o.0 * rhs.ei.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi : E23oi(self.e12.0 * rhs.e2.0) + E23oi(self.e13.0 * rhs.e3.0) - E23oi(self.e1i.0 * rhs.eo.0) - E23oi(self.e1o.0 * rhs.ei.0) + E23oi(self.e23.0 * rhs.e123.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e1i.0 * rhs.e23o.0)
+				+ S(self.e1o.0 * rhs.e23i.0)
+				+ S(self.e23.0 * rhs.e1oi.0)
+				+ S(self.e2i.0 * rhs.e13o.0)
+				- S(self.e2o.0 * rhs.e13i.0)
+				- S(self.e3i.0 * rhs.e12o.0)
+				+ S(self.e3o.0 * rhs.e12i.0)
+				+ S(self.eoi.0 * rhs.e123.0),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0)
+				+ E1(self.e1o.0 * rhs.e123i.0)
+				+ E1(self.e23.0 * rhs.eoi.0)
+				+ E1(self.e2i.0 * rhs.e3o.0)
+				- E1(self.e2o.0 * rhs.e3i.0)
+				- E1(self.e3i.0 * rhs.e2o.0)
+				+ E1(self.e3o.0 * rhs.e2i.0)
+				+ E1(self.eoi.0 * rhs.e23.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e13.0 * rhs.eoi.0) - E2(self.e1i.0 * rhs.e3o.0)
+				+ E2(self.e1o.0 * rhs.e3i.0)
+				- E2(self.e23.0 * rhs.e12oi.0)
+				- E2(self.e2i.0 * rhs.e123o.0)
+				+ E2(self.e2o.0 * rhs.e123i.0)
+				+ E2(self.e3i.0 * rhs.e1o.0)
+				- E2(self.e3o.0 * rhs.e1i.0)
+				- E2(self.eoi.0 * rhs.e13.0),
+			e3: E3(self.e12.0 * rhs.eoi.0) + E3(self.e13.0 * rhs.e23oi.0) + E3(self.e1i.0 * rhs.e2o.0)
+				- E3(self.e1o.0 * rhs.e2i.0)
+				- E3(self.e23.0 * rhs.e13oi.0)
+				- E3(self.e2i.0 * rhs.e1o.0)
+				+ E3(self.e2o.0 * rhs.e1i.0)
+				- E3(self.e3i.0 * rhs.e123o.0)
+				+ E3(self.e3o.0 * rhs.e123i.0)
+				+ E3(self.eoi.0 * rhs.e12.0),
+			eo: -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) - Eo(self.e1o.0 * rhs.e23.0)
+				+ Eo(self.e1o.0 * rhs.e23oi.0)
+				- Eo(self.e23.0 * rhs.e1o.0)
+				+ Eo(self.e2o.0 * rhs.e13.0)
+				- Eo(self.e2o.0 * rhs.e13oi.0)
+				- Eo(self.e3o.0 * rhs.e12.0)
+				+ Eo(self.e3o.0 * rhs.e12oi.0)
+				- Eo(self.eoi.0 * rhs.e123o.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0)
+				+ Ei(self.e1i.0 * rhs.e23.0)
+				+ Ei(self.e1i.0 * rhs.e23oi.0)
+				+ Ei(self.e23.0 * rhs.e1i.0)
+				- Ei(self.e2i.0 * rhs.e13.0)
+				- Ei(self.e2i.0 * rhs.e13oi.0)
+				+ Ei(self.e3i.0 * rhs.e12.0)
+				+ Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0),
+			e12: E12(self.e12.0 * rhs.e123oi.0) - E12(self.e13.0 * rhs.e1oi.0) - E12(self.e1i.0 * rhs.e13o.0)
+				+ E12(self.e1o.0 * rhs.e13i.0)
+				- E12(self.e23.0 * rhs.e2oi.0)
+				- E12(self.e2i.0 * rhs.e23o.0)
+				+ E12(self.e2o.0 * rhs.e23i.0)
+				+ E12(self.e3i.0 * rhs.eo.0)
+				- E12(self.e3o.0 * rhs.ei.0)
+				- E12(self.eoi.0 * rhs.e3.0),
+			e13: E13(self.e12.0 * rhs.e1oi.0) + E13(self.e13.0 * rhs.e123oi.0) + E13(self.e1i.0 * rhs.e12o.0)
+				- E13(self.e1o.0 * rhs.e12i.0)
+				- E13(self.e23.0 * rhs.e3oi.0)
+				- E13(self.e2i.0 * rhs.eo.0)
+				+ E13(self.e2o.0 * rhs.ei.0)
+				- E13(self.e3i.0 * rhs.e23o.0)
+				+ E13(self.e3o.0 * rhs.e23i.0)
+				+ E13(self.eoi.0 * rhs.e2.0),
+			e1o: -E1o(self.e12.0 * rhs.e13o.0) + E1o(self.e13.0 * rhs.e12o.0) - E1o(self.e1o.0 * rhs.e123.0)
+				+ E1o(self.e1o.0 * rhs.e123oi.0)
+				- E1o(self.e23.0 * rhs.eo.0)
+				+ E1o(self.e2o.0 * rhs.e3.0)
+				- E1o(self.e2o.0 * rhs.e3oi.0)
+				- E1o(self.e3o.0 * rhs.e2.0)
+				+ E1o(self.e3o.0 * rhs.e2oi.0)
+				- E1o(self.eoi.0 * rhs.e23o.0),
+			e1i: E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0)
+				+ E1i(self.e1i.0 * rhs.e123.0)
+				+ E1i(self.e1i.0 * rhs.e123oi.0)
+				+ E1i(self.e23.0 * rhs.ei.0)
+				- E1i(self.e2i.0 * rhs.e3.0)
+				- E1i(self.e2i.0 * rhs.e3oi.0)
+				+ E1i(self.e3i.0 * rhs.e2.0)
+				+ E1i(self.e3i.0 * rhs.e2oi.0)
+				- E1i(self.eoi.0 * rhs.e23i.0),
+			e23: E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) + E23(self.e1i.0 * rhs.eo.0)
+				- E23(self.e1o.0 * rhs.ei.0)
+				+ E23(self.e23.0 * rhs.e123oi.0)
+				+ E23(self.e2i.0 * rhs.e12o.0)
+				- E23(self.e2o.0 * rhs.e12i.0)
+				+ E23(self.e3i.0 * rhs.e13o.0)
+				- E23(self.e3o.0 * rhs.e13i.0)
+				- E23(self.eoi.0 * rhs.e1.0),
+			e2o: -E2o(self.e12.0 * rhs.e23o.0) + E2o(self.e13.0 * rhs.eo.0) - E2o(self.e1o.0 * rhs.e3.0)
+				+ E2o(self.e1o.0 * rhs.e3oi.0)
+				+ E2o(self.e23.0 * rhs.e12o.0)
+				- E2o(self.e2o.0 * rhs.e123.0)
+				+ E2o(self.e2o.0 * rhs.e123oi.0)
+				+ E2o(self.e3o.0 * rhs.e1.0)
+				- E2o(self.e3o.0 * rhs.e1oi.0)
+				+ E2o(self.eoi.0 * rhs.e13o.0),
+			e2i: E2i(self.e12.0 * rhs.e23i.0) - E2i(self.e13.0 * rhs.ei.0)
+				+ E2i(self.e1i.0 * rhs.e3.0)
+				+ E2i(self.e1i.0 * rhs.e3oi.0)
+				- E2i(self.e23.0 * rhs.e12i.0)
+				+ E2i(self.e2i.0 * rhs.e123.0)
+				+ E2i(self.e2i.0 * rhs.e123oi.0)
+				- E2i(self.e3i.0 * rhs.e1.0)
+				- E2i(self.e3i.0 * rhs.e1oi.0)
+				+ E2i(self.eoi.0 * rhs.e13i.0),
+			e3o: -E3o(self.e12.0 * rhs.eo.0) - E3o(self.e13.0 * rhs.e23o.0) + E3o(self.e1o.0 * rhs.e2.0)
+				- E3o(self.e1o.0 * rhs.e2oi.0)
+				+ E3o(self.e23.0 * rhs.e13o.0)
+				- E3o(self.e2o.0 * rhs.e1.0)
+				+ E3o(self.e2o.0 * rhs.e1oi.0)
+				- E3o(self.e3o.0 * rhs.e123.0)
+				+ E3o(self.e3o.0 * rhs.e123oi.0)
+				- E3o(self.eoi.0 * rhs.e12o.0),
+			e3i: E3i(self.e12.0 * rhs.ei.0) + E3i(self.e13.0 * rhs.e23i.0)
+				- E3i(self.e1i.0 * rhs.e2.0)
+				- E3i(self.e1i.0 * rhs.e2oi.0)
+				- E3i(self.e23.0 * rhs.e13i.0)
+				+ E3i(self.e2i.0 * rhs.e1.0)
+				+ E3i(self.e2i.0 * rhs.e1oi.0)
+				+ E3i(self.e3i.0 * rhs.e123.0)
+				+ E3i(self.e3i.0 * rhs.e123oi.0)
+				- E3i(self.eoi.0 * rhs.e12i.0),
+			eoi: Eoi(self.e12.0 * rhs.e3.0) - Eoi(self.e13.0 * rhs.e2.0)
+				+ Eoi(self.e1i.0 * rhs.e23o.0)
+				+ Eoi(self.e1o.0 * rhs.e23i.0)
+				+ Eoi(self.e23.0 * rhs.e1.0)
+				- Eoi(self.e2i.0 * rhs.e13o.0)
+				- Eoi(self.e2o.0 * rhs.e13i.0)
+				+ Eoi(self.e3i.0 * rhs.e12o.0)
+				+ Eoi(self.e3o.0 * rhs.e12i.0)
+				+ Eoi(self.eoi.0 * rhs.e123oi.0),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e1i.0 * rhs.e1o.0)
+				- E123(self.e1o.0 * rhs.e1i.0)
+				+ E123(self.e23.0 * rhs.e23oi.0)
+				+ E123(self.e2i.0 * rhs.e2o.0)
+				- E123(self.e2o.0 * rhs.e2i.0)
+				+ E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.e3o.0 * rhs.e3i.0)
+				- E123(self.eoi.0 * rhs.s.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e13.0 * rhs.e1o.0) - E12o(self.e1o.0 * rhs.e13.0)
+				+ E12o(self.e1o.0 * rhs.e13oi.0)
+				+ E12o(self.e23.0 * rhs.e2o.0)
+				- E12o(self.e2o.0 * rhs.e23.0)
+				+ E12o(self.e2o.0 * rhs.e23oi.0)
+				- E12o(self.e3o.0 * rhs.eoi.0)
+				+ E12o(self.e3o.0 * rhs.s.0)
+				+ E12o(self.eoi.0 * rhs.e3o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) - E12i(self.e13.0 * rhs.e1i.0)
+				+ E12i(self.e1i.0 * rhs.e13.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				- E12i(self.e23.0 * rhs.e2i.0)
+				+ E12i(self.e2i.0 * rhs.e23.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0),
+			e13o: -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) + E13o(self.e1o.0 * rhs.e12.0)
+				- E13o(self.e1o.0 * rhs.e12oi.0)
+				+ E13o(self.e23.0 * rhs.e3o.0)
+				+ E13o(self.e2o.0 * rhs.eoi.0)
+				- E13o(self.e2o.0 * rhs.s.0)
+				- E13o(self.e3o.0 * rhs.e23.0)
+				+ E13o(self.e3o.0 * rhs.e23oi.0)
+				- E13o(self.eoi.0 * rhs.e2o.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0)
+				- E13i(self.e1i.0 * rhs.e12.0)
+				- E13i(self.e1i.0 * rhs.e12oi.0)
+				- E13i(self.e23.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				- E13i(self.eoi.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0)
+				+ E1oi(self.e1i.0 * rhs.e123o.0)
+				+ E1oi(self.e1o.0 * rhs.e123i.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				- E1oi(self.e2i.0 * rhs.e3o.0)
+				- E1oi(self.e2o.0 * rhs.e3i.0)
+				+ E1oi(self.e3i.0 * rhs.e2o.0)
+				+ E1oi(self.e3o.0 * rhs.e2i.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e1o.0 * rhs.eoi.0)
+				+ E23o(self.e1o.0 * rhs.s.0)
+				- E23o(self.e23.0 * rhs.e123o.0)
+				+ E23o(self.e2o.0 * rhs.e12.0)
+				- E23o(self.e2o.0 * rhs.e12oi.0)
+				+ E23o(self.e3o.0 * rhs.e13.0)
+				- E23o(self.e3o.0 * rhs.e13oi.0)
+				+ E23o(self.eoi.0 * rhs.e1o.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				+ E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e13.0 * rhs.s.0)
+				+ E2oi(self.e1i.0 * rhs.e3o.0)
+				+ E2oi(self.e1o.0 * rhs.e3i.0)
+				- E2oi(self.e23.0 * rhs.e12.0)
+				+ E2oi(self.e2i.0 * rhs.e123o.0)
+				+ E2oi(self.e2o.0 * rhs.e123i.0)
+				- E2oi(self.e3i.0 * rhs.e1o.0)
+				- E2oi(self.e3o.0 * rhs.e1i.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0)
+				- E3oi(self.e1i.0 * rhs.e2o.0)
+				- E3oi(self.e1o.0 * rhs.e2i.0)
+				- E3oi(self.e23.0 * rhs.e13.0)
+				+ E3oi(self.e2i.0 * rhs.e1o.0)
+				+ E3oi(self.e2o.0 * rhs.e1i.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.e3o.0 * rhs.e123i.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) + E123o(self.e1o.0 * rhs.e1.0)
+				- E123o(self.e1o.0 * rhs.e1oi.0)
+				- E123o(self.e23.0 * rhs.e23o.0)
+				+ E123o(self.e2o.0 * rhs.e2.0)
+				- E123o(self.e2o.0 * rhs.e2oi.0)
+				+ E123o(self.e3o.0 * rhs.e3.0)
+				- E123o(self.e3o.0 * rhs.e3oi.0)
+				+ E123o(self.eoi.0 * rhs.eo.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0)
+				- E123i(self.e1i.0 * rhs.e1.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0)
+				+ E123i(self.eoi.0 * rhs.ei.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0) - E12oi(self.e13.0 * rhs.e1.0)
+				+ E12oi(self.e1i.0 * rhs.e13o.0)
+				+ E12oi(self.e1o.0 * rhs.e13i.0)
+				- E12oi(self.e23.0 * rhs.e2.0)
+				+ E12oi(self.e2i.0 * rhs.e23o.0)
+				+ E12oi(self.e2o.0 * rhs.e23i.0)
+				- E12oi(self.e3i.0 * rhs.eo.0)
+				- E12oi(self.e3o.0 * rhs.ei.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e1.0) + E13oi(self.e13.0 * rhs.e123.0)
+				- E13oi(self.e1i.0 * rhs.e12o.0)
+				- E13oi(self.e1o.0 * rhs.e12i.0)
+				- E13oi(self.e23.0 * rhs.e3.0)
+				+ E13oi(self.e2i.0 * rhs.eo.0)
+				+ E13oi(self.e2o.0 * rhs.ei.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.e3o.0 * rhs.e23i.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: E23oi(self.e12.0 * rhs.e2.0) + E23oi(self.e13.0 * rhs.e3.0)
+				- E23oi(self.e1i.0 * rhs.eo.0)
+				- E23oi(self.e1o.0 * rhs.ei.0)
+				+ E23oi(self.e23.0 * rhs.e123.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e2o.0 * rhs.e12i.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.e3o.0 * rhs.e13i.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// PointPair.dot(Multivector) -> Multivector
+impl Dot<Multivector> for PointPair {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0) - E1(self.e1i.0 * rhs.eo.0) - E1(self.e1o.0 * rhs.ei.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) + E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e23.0 * rhs.e3.0) - E2(self.e2i.0 * rhs.eo.0) - E2(self.e2o.0 * rhs.ei.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) - E3(self.e13.0 * rhs.e1.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e23.0 * rhs.e2.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) - E3(self.e3i.0 * rhs.eo.0) - E3(self.e3o.0 * rhs.ei.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3.0) - Eo(self.e3o.0 * rhs.e3oi.0) - Eo(self.eoi.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) - Ei(self.e2i.0 * rhs.e2.0) + Ei(self.e2i.0 * rhs.e2oi.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.e3i.0 * rhs.e3oi.0) + Ei(self.eoi.0 * rhs.ei.0),
+		//     e12   : E12(self.e12.0 * rhs.s.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13   : E13(self.e13.0 * rhs.s.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o   : E1o(self.e1o.0 * rhs.s.0) - E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.s.0) - E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23   : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o   : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i   : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0) + E3o(self.e3o.0 * rhs.s.0),
+		//     e3i   : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi   : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123  : E123(self.eoi.0 * rhs.e123oi.0),
+		//     e12o  : -E12o(self.e3o.0 * rhs.e123oi.0),
+		//     e12i  : E12i(self.e3i.0 * rhs.e123oi.0),
+		//     e13o  : E13o(self.e2o.0 * rhs.e123oi.0),
+		//     e13i  : -E13i(self.e2i.0 * rhs.e123oi.0),
+		//     e1oi  : -E1oi(self.e23.0 * rhs.e123oi.0),
+		//     e23o  : -E23o(self.e1o.0 * rhs.e123oi.0),
+		//     e23i  : E23i(self.e1i.0 * rhs.e123oi.0),
+		//     e2oi  : E2oi(self.e13.0 * rhs.e123oi.0),
+		//     e3oi  : -E3oi(self.e12.0 * rhs.e123oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e1: E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0)
+				- E1(self.e1i.0 * rhs.eo.0)
+				- E1(self.e1o.0 * rhs.ei.0)
+				- E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e12.0 * rhs.e1.0) + E2(self.e13.0 * rhs.e123.0)
+				- E2(self.e1i.0 * rhs.e12o.0)
+				- E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e23.0 * rhs.e3.0)
+				- E2(self.e2i.0 * rhs.eo.0)
+				- E2(self.e2o.0 * rhs.ei.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e12.0 * rhs.e123.0)
+				- E3(self.e13.0 * rhs.e1.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e23.0 * rhs.e2.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				- E3(self.e3i.0 * rhs.eo.0)
+				- E3(self.e3o.0 * rhs.ei.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0)
+				- Eo(self.eoi.0 * rhs.eo.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e1i.0 * rhs.e1.0)
+				+ Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				- Ei(self.e2i.0 * rhs.e2.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				- Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0)
+				+ Ei(self.eoi.0 * rhs.ei.0),
+			e12: E12(self.e12.0 * rhs.s.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.e13.0 * rhs.s.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0)
+				- E1o(self.e23.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12oi.0)
+				- E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0) - E1i(self.e23.0 * rhs.e123i.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) + E2o(self.e2o.0 * rhs.s.0)
+				- E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0)
+				+ E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0)
+				+ E3i(self.e3i.0 * rhs.s.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123: E123(self.eoi.0 * rhs.e123oi.0),
+			e12o: -E12o(self.e3o.0 * rhs.e123oi.0),
+			e12i: E12i(self.e3i.0 * rhs.e123oi.0),
+			e13o: E13o(self.e2o.0 * rhs.e123oi.0),
+			e13i: -E13i(self.e2i.0 * rhs.e123oi.0),
+			e1oi: -E1oi(self.e23.0 * rhs.e123oi.0),
+			e23o: -E23o(self.e1o.0 * rhs.e123oi.0),
+			e23i: E23i(self.e1i.0 * rhs.e123oi.0),
+			e2oi: E2oi(self.e13.0 * rhs.e123oi.0),
+			e3oi: -E3oi(self.e12.0 * rhs.e123oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for PointPair {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// PointPair.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for PointPair {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : E12(self.e12.0 * rhs.s.0),
+		//     e13   : E13(self.e13.0 * rhs.s.0),
+		//     e1o   : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.s.0),
+		//     e23   : E23(self.e23.0 * rhs.s.0),
+		//     e2o   : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i   : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o   : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i   : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi   : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123  : E123(self.e12.0 * rhs.e3.0) - E123(self.e13.0 * rhs.e2.0) + E123(self.e23.0 * rhs.e1.0),
+		//     e12o  : E12o(self.e12.0 * rhs.eo.0) - E12o(self.e1o.0 * rhs.e2.0) + E12o(self.e2o.0 * rhs.e1.0),
+		//     e12i  : E12i(self.e12.0 * rhs.ei.0) - E12i(self.e1i.0 * rhs.e2.0) + E12i(self.e2i.0 * rhs.e1.0),
+		//     e13o  : E13o(self.e13.0 * rhs.eo.0) - E13o(self.e1o.0 * rhs.e3.0) + E13o(self.e3o.0 * rhs.e1.0),
+		//     e13i  : E13i(self.e13.0 * rhs.ei.0) - E13i(self.e1i.0 * rhs.e3.0) + E13i(self.e3i.0 * rhs.e1.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.eo.0) + E1oi(self.e1o.0 * rhs.ei.0) + E1oi(self.eoi.0 * rhs.e1.0),
+		//     e23o  : E23o(self.e23.0 * rhs.eo.0) - E23o(self.e2o.0 * rhs.e3.0) + E23o(self.e3o.0 * rhs.e2.0),
+		//     e23i  : E23i(self.e23.0 * rhs.ei.0) - E23i(self.e2i.0 * rhs.e3.0) + E23i(self.e3i.0 * rhs.e2.0),
+		//     e2oi  : -E2oi(self.e2i.0 * rhs.eo.0) + E2oi(self.e2o.0 * rhs.ei.0) + E2oi(self.eoi.0 * rhs.e2.0),
+		//     e3oi  : -E3oi(self.e3i.0 * rhs.eo.0) + E3oi(self.e3o.0 * rhs.ei.0) + E3oi(self.eoi.0 * rhs.e3.0),
+		//     e123o : E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+		//     e123i : E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) + E123i(self.e23.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi : E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi : E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e2o.0 * rhs.e13i.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.e3o.0 * rhs.e12i.0) + E123oi(self.eoi.0 * rhs.e123.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: self.e12.wedge(rhs.s),
+			e13: self.e13.wedge(rhs.s),
+			e1o: self.e1o.wedge(rhs.s),
+			e1i: self.e1i.wedge(rhs.s),
+			e23: self.e23.wedge(rhs.s),
+			e2o: self.e2o.wedge(rhs.s),
+			e2i: self.e2i.wedge(rhs.s),
+			e3o: self.e3o.wedge(rhs.s),
+			e3i: self.e3i.wedge(rhs.s),
+			eoi: self.eoi.wedge(rhs.s),
+			e123: self.e12.wedge(rhs.e3) + self.e13.wedge(rhs.e2) + self.e23.wedge(rhs.e1),
+			e12o: self.e12.wedge(rhs.eo) + self.e1o.wedge(rhs.e2) + self.e2o.wedge(rhs.e1),
+			e12i: self.e12.wedge(rhs.ei) + self.e1i.wedge(rhs.e2) + self.e2i.wedge(rhs.e1),
+			e13o: self.e13.wedge(rhs.eo) + self.e1o.wedge(rhs.e3) + self.e3o.wedge(rhs.e1),
+			e13i: self.e13.wedge(rhs.ei) + self.e1i.wedge(rhs.e3) + self.e3i.wedge(rhs.e1),
+			e1oi: self.e1i.wedge(rhs.eo) + self.e1o.wedge(rhs.ei) + self.eoi.wedge(rhs.e1),
+			e23o: self.e23.wedge(rhs.eo) + self.e2o.wedge(rhs.e3) + self.e3o.wedge(rhs.e2),
+			e23i: self.e23.wedge(rhs.ei) + self.e2i.wedge(rhs.e3) + self.e3i.wedge(rhs.e2),
+			e2oi: self.e2i.wedge(rhs.eo) + self.e2o.wedge(rhs.ei) + self.eoi.wedge(rhs.e2),
+			e3oi: self.e3i.wedge(rhs.eo) + self.e3o.wedge(rhs.ei) + self.eoi.wedge(rhs.e3),
+			e123o: self.e12.wedge(rhs.e3o)
+				+ self.e13.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e13)
+				+ self.e3o.wedge(rhs.e12),
+			e123i: self.e12.wedge(rhs.e3i)
+				+ self.e13.wedge(rhs.e2i)
+				+ self.e1i.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1i)
+				+ self.e2i.wedge(rhs.e13)
+				+ self.e3i.wedge(rhs.e12),
+			e12oi: self.e12.wedge(rhs.eoi)
+				+ self.e1i.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e2i)
+				+ self.e2i.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e12),
+			e13oi: self.e13.wedge(rhs.eoi)
+				+ self.e1i.wedge(rhs.e3o)
+				+ self.e1o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e1o)
+				+ self.e3o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e13),
+			e23oi: self.e23.wedge(rhs.eoi)
+				+ self.e2i.wedge(rhs.e3o)
+				+ self.e2o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e2o)
+				+ self.e3o.wedge(rhs.e2i)
+				+ self.eoi.wedge(rhs.e23),
+			e123oi: self.e12.wedge(rhs.e3oi)
+				+ self.e13.wedge(rhs.e2oi)
+				+ self.e1i.wedge(rhs.e23o)
+				+ self.e1o.wedge(rhs.e23i)
+				+ self.e23.wedge(rhs.e1oi)
+				+ self.e2i.wedge(rhs.e13o)
+				+ self.e2o.wedge(rhs.e13i)
+				+ self.e3i.wedge(rhs.e12o)
+				+ self.e3o.wedge(rhs.e12i)
+				+ self.eoi.wedge(rhs.e123),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for PointPair {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// PointPair.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for PointPair {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e1i.0 * rhs.e23o.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e2o.0 * rhs.e13i.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.e3o.0 * rhs.e12i.0) + S(self.eoi.0 * rhs.e123.0),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0),
+		//     e3    : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0),
+		//     eo    : Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : E12(self.e12.0 * rhs.e123oi.0),
+		//     e13   : E13(self.e13.0 * rhs.e123oi.0),
+		//     e1o   : E1o(self.e1o.0 * rhs.e123oi.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.e123oi.0),
+		//     e23   : E23(self.e23.0 * rhs.e123oi.0),
+		//     e2o   : E2o(self.e2o.0 * rhs.e123oi.0),
+		//     e2i   : E2i(self.e2i.0 * rhs.e123oi.0),
+		//     e3o   : E3o(self.e3o.0 * rhs.e123oi.0),
+		//     e3i   : E3i(self.e3i.0 * rhs.e123oi.0),
+		//     eoi   : Eoi(self.eoi.0 * rhs.e123oi.0),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.e12.anti_wedge(rhs.e3oi)
+				+ self.e13.anti_wedge(rhs.e2oi)
+				+ self.e1i.anti_wedge(rhs.e23o)
+				+ self.e1o.anti_wedge(rhs.e23i)
+				+ self.e23.anti_wedge(rhs.e1oi)
+				+ self.e2i.anti_wedge(rhs.e13o)
+				+ self.e2o.anti_wedge(rhs.e13i)
+				+ self.e3i.anti_wedge(rhs.e12o)
+				+ self.e3o.anti_wedge(rhs.e12i)
+				+ self.eoi.anti_wedge(rhs.e123),
+			e1: self.e12.anti_wedge(rhs.e13oi)
+				+ self.e13.anti_wedge(rhs.e12oi)
+				+ self.e1i.anti_wedge(rhs.e123o)
+				+ self.e1o.anti_wedge(rhs.e123i),
+			e2: self.e12.anti_wedge(rhs.e23oi)
+				+ self.e23.anti_wedge(rhs.e12oi)
+				+ self.e2i.anti_wedge(rhs.e123o)
+				+ self.e2o.anti_wedge(rhs.e123i),
+			e3: self.e13.anti_wedge(rhs.e23oi)
+				+ self.e23.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e123o)
+				+ self.e3o.anti_wedge(rhs.e123i),
+			eo: self.e1o.anti_wedge(rhs.e23oi)
+				+ self.e2o.anti_wedge(rhs.e13oi)
+				+ self.e3o.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123o),
+			ei: self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+			e12: self.e12.anti_wedge(rhs.e123oi),
+			e13: self.e13.anti_wedge(rhs.e123oi),
+			e1o: self.e1o.anti_wedge(rhs.e123oi),
+			e1i: self.e1i.anti_wedge(rhs.e123oi),
+			e23: self.e23.anti_wedge(rhs.e123oi),
+			e2o: self.e2o.anti_wedge(rhs.e123oi),
+			e2i: self.e2i.anti_wedge(rhs.e123oi),
+			e3o: self.e3o.anti_wedge(rhs.e123oi),
+			e3i: self.e3i.anti_wedge(rhs.e123oi),
+			eoi: self.eoi.anti_wedge(rhs.e123oi),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for PointPair {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// PointPair.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for PointPair {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e1    : -E1(self.e23.0 * rhs.e123.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13   : -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o   : -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i   : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23   : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o   : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i   : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i   : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi   : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123  : E123(self.eoi.0 * rhs.e123oi.0),
+		//     e12o  : -E12o(self.e3o.0 * rhs.e123oi.0),
+		//     e12i  : E12i(self.e3i.0 * rhs.e123oi.0),
+		//     e13o  : E13o(self.e2o.0 * rhs.e123oi.0),
+		//     e13i  : -E13i(self.e2i.0 * rhs.e123oi.0),
+		//     e1oi  : -E1oi(self.e23.0 * rhs.e123oi.0),
+		//     e23o  : -E23o(self.e1o.0 * rhs.e123oi.0),
+		//     e23i  : E23i(self.e1i.0 * rhs.e123oi.0),
+		//     e2oi  : E2oi(self.e13.0 * rhs.e123oi.0),
+		//     e3oi  : -E3oi(self.e12.0 * rhs.e123oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e1: -E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e12.0 * rhs.e123.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123: E123(self.eoi.0 * rhs.e123oi.0),
+			e12o: -E12o(self.e3o.0 * rhs.e123oi.0),
+			e12i: E12i(self.e3i.0 * rhs.e123oi.0),
+			e13o: E13o(self.e2o.0 * rhs.e123oi.0),
+			e13i: -E13i(self.e2i.0 * rhs.e123oi.0),
+			e1oi: -E1oi(self.e23.0 * rhs.e123oi.0),
+			e23o: -E23o(self.e1o.0 * rhs.e123oi.0),
+			e23i: E23i(self.e1i.0 * rhs.e123oi.0),
+			e2oi: E2oi(self.e13.0 * rhs.e123oi.0),
+			e3oi: -E3oi(self.e12.0 * rhs.e123oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// PointPair.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for PointPair {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0) - E1(self.e1i.0 * rhs.eo.0) - E1(self.e1o.0 * rhs.ei.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) + E2(self.e23.0 * rhs.e3.0) - E2(self.e2i.0 * rhs.eo.0) - E2(self.e2o.0 * rhs.ei.0),
+		//     e3    : -E3(self.e13.0 * rhs.e1.0) - E3(self.e23.0 * rhs.e2.0) - E3(self.e3i.0 * rhs.eo.0) - E3(self.e3o.0 * rhs.ei.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.e1.0) - Eo(self.e2o.0 * rhs.e2.0) - Eo(self.e3o.0 * rhs.e3.0) - Eo(self.eoi.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.eoi.0 * rhs.ei.0),
+		//     e12   : E12(self.e12.0 * rhs.s.0),
+		//     e13   : E13(self.e13.0 * rhs.s.0),
+		//     e1o   : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.s.0),
+		//     e23   : E23(self.e23.0 * rhs.s.0),
+		//     e2o   : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i   : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o   : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i   : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi   : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e1: E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0)
+				- E1(self.e1i.0 * rhs.eo.0)
+				- E1(self.e1o.0 * rhs.ei.0),
+			e2: -E2(self.e12.0 * rhs.e1.0) + E2(self.e23.0 * rhs.e3.0)
+				- E2(self.e2i.0 * rhs.eo.0)
+				- E2(self.e2o.0 * rhs.ei.0),
+			e3: -E3(self.e13.0 * rhs.e1.0)
+				- E3(self.e23.0 * rhs.e2.0)
+				- E3(self.e3i.0 * rhs.eo.0)
+				- E3(self.e3o.0 * rhs.ei.0),
+			eo: -Eo(self.e1o.0 * rhs.e1.0)
+				- Eo(self.e2o.0 * rhs.e2.0)
+				- Eo(self.e3o.0 * rhs.e3.0)
+				- Eo(self.eoi.0 * rhs.eo.0),
+			ei: -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.eoi.0 * rhs.ei.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+			e2o: E2o(self.e2o.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3o: E3o(self.e3o.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// PointPair.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for PointPair {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0)
+			- S(self.e23.0 * rhs.e23.0)
+			+ S(self.e2i.0 * rhs.e2o.0)
+			+ S(self.e2o.0 * rhs.e2i.0)
+			+ S(self.e3i.0 * rhs.e3o.0)
+			+ S(self.e3o.0 * rhs.e3i.0)
+			+ S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// PointPair.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for PointPair {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0) - E1(self.e1i.0 * rhs.eo.0) - E1(self.e1o.0 * rhs.ei.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) + E2(self.e23.0 * rhs.e3.0) - E2(self.e2i.0 * rhs.eo.0) - E2(self.e2o.0 * rhs.ei.0),
+		//     e3    : -E3(self.e13.0 * rhs.e1.0) - E3(self.e23.0 * rhs.e2.0) - E3(self.e3i.0 * rhs.eo.0) - E3(self.e3o.0 * rhs.ei.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.e1.0) - Eo(self.e2o.0 * rhs.e2.0) - Eo(self.e3o.0 * rhs.e3.0) - Eo(self.eoi.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.eoi.0 * rhs.ei.0),
+		//     e12   : -E12(self.e13.0 * rhs.e23.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13   : E13(self.e12.0 * rhs.e23.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o   : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i   : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23   : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o   : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i   : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.eoi.0) - E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i   : -E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi   : Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		//     e123  : -E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2i.0 * rhs.e13o.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3i.0 * rhs.e12o.0) - E123(self.e3o.0 * rhs.e12i.0),
+		//     e12o  : -E12o(self.e13.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e23.0 * rhs.e13o.0) + E12o(self.e2o.0 * rhs.e1oi.0) - E12o(self.e3o.0 * rhs.e123.0) - E12o(self.eoi.0 * rhs.e12o.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.e2o.0 * rhs.e123.0) + E13o(self.e3o.0 * rhs.e1oi.0) - E13o(self.eoi.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.e2i.0 * rhs.e12o.0) - E1oi(self.e2o.0 * rhs.e12i.0) + E1oi(self.e3i.0 * rhs.e13o.0) - E1oi(self.e3o.0 * rhs.e13i.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0) - E23o(self.e1o.0 * rhs.e123.0) - E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0) - E23o(self.eoi.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.e3i.0 * rhs.e23o.0) - E2oi(self.e3o.0 * rhs.e23i.0),
+		//     e3oi  : -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) - E3oi(self.e2i.0 * rhs.e23o.0) + E3oi(self.e2o.0 * rhs.e23i.0),
+		//     e123o : E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i : -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi : -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+		//     e23oi : -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0)
+				- E1(self.e1i.0 * rhs.eo.0)
+				- E1(self.e1o.0 * rhs.ei.0),
+			e2: -E2(self.e12.0 * rhs.e1.0) + E2(self.e23.0 * rhs.e3.0)
+				- E2(self.e2i.0 * rhs.eo.0)
+				- E2(self.e2o.0 * rhs.ei.0),
+			e3: -E3(self.e13.0 * rhs.e1.0)
+				- E3(self.e23.0 * rhs.e2.0)
+				- E3(self.e3i.0 * rhs.eo.0)
+				- E3(self.e3o.0 * rhs.ei.0),
+			eo: -Eo(self.e1o.0 * rhs.e1.0)
+				- Eo(self.e2o.0 * rhs.e2.0)
+				- Eo(self.e3o.0 * rhs.e3.0)
+				- Eo(self.eoi.0 * rhs.eo.0),
+			ei: -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.eoi.0 * rhs.ei.0),
+			e12: -E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				- E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0),
+			e123: -E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0)
+				+ E123(self.e2i.0 * rhs.e13o.0)
+				+ E123(self.e2o.0 * rhs.e13i.0)
+				- E123(self.e3i.0 * rhs.e12o.0)
+				- E123(self.e3o.0 * rhs.e12i.0),
+			e12o: -E12o(self.e13.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2oi.0)
+				+ E12o(self.e23.0 * rhs.e13o.0)
+				+ E12o(self.e2o.0 * rhs.e1oi.0)
+				- E12o(self.e3o.0 * rhs.e123.0)
+				- E12o(self.eoi.0 * rhs.e12o.0),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				- E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0)
+				+ E13o(self.e2o.0 * rhs.e123.0)
+				+ E13o(self.e3o.0 * rhs.e1oi.0)
+				- E13o(self.eoi.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0)
+				+ E13i(self.e2i.0 * rhs.e123.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.e2i.0 * rhs.e12o.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				+ E1oi(self.e3i.0 * rhs.e13o.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0)
+				- E23o(self.e1o.0 * rhs.e123.0)
+				- E23o(self.e2o.0 * rhs.e3oi.0)
+				+ E23o(self.e3o.0 * rhs.e2oi.0)
+				- E23o(self.eoi.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e1i.0 * rhs.e12o.0)
+				+ E2oi(self.e1o.0 * rhs.e12i.0)
+				+ E2oi(self.e23.0 * rhs.e3oi.0)
+				+ E2oi(self.e3i.0 * rhs.e23o.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0)
+				- E3oi(self.e23.0 * rhs.e2oi.0)
+				- E3oi(self.e2i.0 * rhs.e23o.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0),
+			e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// PointPair.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for PointPair {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : E12(self.e12.0 * rhs.e123oi.0),
+		//     e13   : E13(self.e13.0 * rhs.e123oi.0),
+		//     e1o   : E1o(self.e1o.0 * rhs.e123oi.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.e123oi.0),
+		//     e23   : E23(self.e23.0 * rhs.e123oi.0),
+		//     e2o   : E2o(self.e2o.0 * rhs.e123oi.0),
+		//     e2i   : E2i(self.e2i.0 * rhs.e123oi.0),
+		//     e3o   : E3o(self.e3o.0 * rhs.e123oi.0),
+		//     e3i   : E3i(self.e3i.0 * rhs.e123oi.0),
+		//     eoi   : Eoi(self.eoi.0 * rhs.e123oi.0),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0) - E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0) + E12o(self.e3o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) - E13o(self.e2o.0 * rhs.s.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.e23.0 * rhs.s.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : E23o(self.e1o.0 * rhs.s.0) - E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) + E123o(self.e1o.0 * rhs.e1.0) - E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e23.0 * rhs.e23o.0) + E123o(self.e2o.0 * rhs.e2.0) - E123o(self.e2o.0 * rhs.e2oi.0) + E123o(self.e3o.0 * rhs.e3.0) - E123o(self.e3o.0 * rhs.e3oi.0) + E123o(self.eoi.0 * rhs.eo.0),
+		//     e123i : E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3.0) - E123i(self.e3i.0 * rhs.e3oi.0) + E123i(self.eoi.0 * rhs.ei.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.e123.0) - E12oi(self.e13.0 * rhs.e1.0) + E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e1o.0 * rhs.e13i.0) - E12oi(self.e23.0 * rhs.e2.0) + E12oi(self.e2i.0 * rhs.e23o.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.e3i.0 * rhs.eo.0) - E12oi(self.e3o.0 * rhs.ei.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e1.0) + E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e1i.0 * rhs.e12o.0) - E13oi(self.e1o.0 * rhs.e12i.0) - E13oi(self.e23.0 * rhs.e3.0) + E13oi(self.e2i.0 * rhs.eo.0) + E13oi(self.e2o.0 * rhs.ei.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi : E23oi(self.e12.0 * rhs.e2.0) + E23oi(self.e13.0 * rhs.e3.0) - E23oi(self.e1i.0 * rhs.eo.0) - E23oi(self.e1o.0 * rhs.ei.0) + E23oi(self.e23.0 * rhs.e123.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: E12(self.e12.0 * rhs.e123oi.0),
+			e13: E13(self.e13.0 * rhs.e123oi.0),
+			e1o: E1o(self.e1o.0 * rhs.e123oi.0),
+			e1i: E1i(self.e1i.0 * rhs.e123oi.0),
+			e23: E23(self.e23.0 * rhs.e123oi.0),
+			e2o: E2o(self.e2o.0 * rhs.e123oi.0),
+			e2i: E2i(self.e2i.0 * rhs.e123oi.0),
+			e3o: E3o(self.e3o.0 * rhs.e123oi.0),
+			e3i: E3i(self.e3i.0 * rhs.e123oi.0),
+			eoi: Eoi(self.eoi.0 * rhs.e123oi.0),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0)
+				- E123(self.eoi.0 * rhs.s.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0)
+				+ E12o(self.e1o.0 * rhs.e13oi.0)
+				+ E12o(self.e2o.0 * rhs.e23oi.0)
+				+ E12o(self.e3o.0 * rhs.s.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.s.0),
+			e13o: -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) - E13o(self.e2o.0 * rhs.s.0)
+				+ E13o(self.e3o.0 * rhs.e23oi.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0)
+				+ E1oi(self.e1o.0 * rhs.e123i.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: E23o(self.e1o.0 * rhs.s.0)
+				- E23o(self.e23.0 * rhs.e123o.0)
+				- E23o(self.e2o.0 * rhs.e12oi.0)
+				- E23o(self.e3o.0 * rhs.e13oi.0),
+			e23i: -E23i(self.e1i.0 * rhs.s.0) + E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.e3o.0 * rhs.e123i.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) + E123o(self.e1o.0 * rhs.e1.0)
+				- E123o(self.e1o.0 * rhs.e1oi.0)
+				- E123o(self.e23.0 * rhs.e23o.0)
+				+ E123o(self.e2o.0 * rhs.e2.0)
+				- E123o(self.e2o.0 * rhs.e2oi.0)
+				+ E123o(self.e3o.0 * rhs.e3.0)
+				- E123o(self.e3o.0 * rhs.e3oi.0)
+				+ E123o(self.eoi.0 * rhs.eo.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0)
+				- E123i(self.e1i.0 * rhs.e1.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0)
+				+ E123i(self.eoi.0 * rhs.ei.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0) - E12oi(self.e13.0 * rhs.e1.0)
+				+ E12oi(self.e1i.0 * rhs.e13o.0)
+				+ E12oi(self.e1o.0 * rhs.e13i.0)
+				- E12oi(self.e23.0 * rhs.e2.0)
+				+ E12oi(self.e2i.0 * rhs.e23o.0)
+				+ E12oi(self.e2o.0 * rhs.e23i.0)
+				- E12oi(self.e3i.0 * rhs.eo.0)
+				- E12oi(self.e3o.0 * rhs.ei.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e1.0) + E13oi(self.e13.0 * rhs.e123.0)
+				- E13oi(self.e1i.0 * rhs.e12o.0)
+				- E13oi(self.e1o.0 * rhs.e12i.0)
+				- E13oi(self.e23.0 * rhs.e3.0)
+				+ E13oi(self.e2i.0 * rhs.eo.0)
+				+ E13oi(self.e2o.0 * rhs.ei.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.e3o.0 * rhs.e23i.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: E23oi(self.e12.0 * rhs.e2.0) + E23oi(self.e13.0 * rhs.e3.0)
+				- E23oi(self.e1i.0 * rhs.eo.0)
+				- E23oi(self.e1o.0 * rhs.ei.0)
+				+ E23oi(self.e23.0 * rhs.e123.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e2o.0 * rhs.e12i.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.e3o.0 * rhs.e13i.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
@@ -0,0 +1,6533 @@
+//! # Rotor
+//!
+//! ## Operations
+//! ```text
+//! Rotor.geometric(Rotor) -> Rotor
+//! Rotor.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(Rotor) -> Rotor
+//! Rotor.wedge(Rotor) -> Rotor
+//! Rotor.left_contraction(Rotor) -> Rotor
+//! Rotor.right_contraction(Rotor) -> Rotor
+//! Rotor.scalar_product(Rotor) -> S
+//! Rotor.commutator(Rotor) -> PointPair
+//! Rotor.anti_dot(Rotor) -> Multivector
+//! Rotor.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Vec3) -> Versor
+//! Vec3.anti_geometric(Rotor) -> Versor
+//! Rotor.dot(Vec3) -> Vec3
+//! Vec3.dot(Rotor) -> Vec3
+//! Rotor.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Rotor) -> Multivector
+//! Rotor.left_contraction(Vec3) -> Vec3
+//! Vec3.left_contraction(Rotor) -> Vec3
+//! Rotor.right_contraction(Vec3) -> Vec3
+//! Vec3.right_contraction(Rotor) -> Vec3
+//! Rotor.commutator(Vec3) -> Vec3
+//! Vec3.commutator(Rotor) -> Vec3
+//! Rotor.anti_dot(Vec3) -> Plane
+//! Vec3.anti_dot(Rotor) -> Plane
+//! Rotor.geometric(Point) -> Multivector
+//! Point.geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Point) -> Versor
+//! Point.anti_geometric(Rotor) -> Versor
+//! Rotor.dot(Point) -> Point
+//! Point.dot(Rotor) -> Point
+//! Rotor.wedge(Point) -> Multivector
+//! Point.wedge(Rotor) -> Multivector
+//! Rotor.left_contraction(Point) -> Point
+//! Point.left_contraction(Rotor) -> Vec3
+//! Rotor.right_contraction(Point) -> Vec3
+//! Point.right_contraction(Rotor) -> Point
+//! Rotor.commutator(Point) -> Vec3
+//! Point.commutator(Rotor) -> Vec3
+//! Rotor.anti_dot(Point) -> Sphere
+//! Point.anti_dot(Rotor) -> Sphere
+//! Rotor.geometric(FlatPoint) -> Versor
+//! FlatPoint.geometric(Rotor) -> Versor
+//! Rotor.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(FlatPoint) -> FlatPoint
+//! FlatPoint.dot(Rotor) -> FlatPoint
+//! Rotor.wedge(FlatPoint) -> Versor
+//! FlatPoint.wedge(Rotor) -> Versor
+//! Rotor.left_contraction(FlatPoint) -> FlatPoint
+//! FlatPoint.right_contraction(Rotor) -> FlatPoint
+//! Rotor.commutator(FlatPoint) -> FlatPoint
+//! FlatPoint.commutator(Rotor) -> FlatPoint
+//! Rotor.anti_dot(FlatPoint) -> Circle
+//! FlatPoint.anti_dot(Rotor) -> Circle
+//! Rotor.geometric(PointPair) -> Versor
+//! PointPair.geometric(Rotor) -> Versor
+//! Rotor.anti_geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(PointPair) -> Versor
+//! PointPair.dot(Rotor) -> Versor
+//! Rotor.wedge(PointPair) -> Versor
+//! PointPair.wedge(Rotor) -> Versor
+//! Rotor.left_contraction(PointPair) -> Versor
+//! PointPair.left_contraction(Rotor) -> S
+//! Rotor.right_contraction(PointPair) -> S
+//! PointPair.right_contraction(Rotor) -> Versor
+//! Rotor.scalar_product(PointPair) -> S
+//! PointPair.scalar_product(Rotor) -> S
+//! Rotor.commutator(PointPair) -> PointPair
+//! PointPair.commutator(Rotor) -> PointPair
+//! Rotor.anti_dot(PointPair) -> Multivector
+//! PointPair.anti_dot(Rotor) -> Multivector
+//! Rotor.geometric(Line) -> Multivector
+//! Line.geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Line) -> Versor
+//! Line.anti_geometric(Rotor) -> Versor
+//! Rotor.dot(Line) -> Multivector
+//! Line.dot(Rotor) -> Multivector
+//! Rotor.wedge(Line) -> Multivector
+//! Line.wedge(Rotor) -> Multivector
+//! Rotor.anti_wedge(Line) -> S
+//! Line.anti_wedge(Rotor) -> S
+//! Rotor.left_contraction(Line) -> Multivector
+//! Line.right_contraction(Rotor) -> Multivector
+//! Rotor.commutator(Line) -> Line
+//! Line.commutator(Rotor) -> Line
+//! Rotor.anti_dot(Line) -> Versor
+//! Line.anti_dot(Rotor) -> Versor
+//! Rotor.geometric(Circle) -> Multivector
+//! Circle.geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Circle) -> Versor
+//! Circle.anti_geometric(Rotor) -> Versor
+//! Rotor.dot(Circle) -> Multivector
+//! Circle.dot(Rotor) -> Multivector
+//! Rotor.wedge(Circle) -> Multivector
+//! Circle.wedge(Rotor) -> Multivector
+//! Rotor.anti_wedge(Circle) -> S
+//! Circle.anti_wedge(Rotor) -> S
+//! Rotor.left_contraction(Circle) -> Multivector
+//! Circle.right_contraction(Rotor) -> Multivector
+//! Rotor.commutator(Circle) -> Circle
+//! Circle.commutator(Rotor) -> Circle
+//! Rotor.anti_dot(Circle) -> Versor
+//! Circle.anti_dot(Rotor) -> Versor
+//! Rotor.geometric(Plane) -> Versor
+//! Plane.geometric(Rotor) -> Versor
+//! Rotor.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(Plane) -> Versor
+//! Plane.dot(Rotor) -> Versor
+//! Rotor.wedge(Plane) -> Plane
+//! Plane.wedge(Rotor) -> Plane
+//! Rotor.anti_wedge(Plane) -> Vec3
+//! Plane.anti_wedge(Rotor) -> Vec3
+//! Rotor.left_contraction(Plane) -> Versor
+//! Plane.right_contraction(Rotor) -> Versor
+//! Rotor.commutator(Plane) -> Plane
+//! Plane.commutator(Rotor) -> Plane
+//! Rotor.anti_dot(Plane) -> Multivector
+//! Plane.anti_dot(Rotor) -> Multivector
+//! Rotor.geometric(Sphere) -> Versor
+//! Sphere.geometric(Rotor) -> Versor
+//! Rotor.anti_geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(Sphere) -> Versor
+//! Sphere.dot(Rotor) -> Versor
+//! Rotor.wedge(Sphere) -> Sphere
+//! Sphere.wedge(Rotor) -> Sphere
+//! Rotor.anti_wedge(Sphere) -> Vec3
+//! Sphere.anti_wedge(Rotor) -> Vec3
+//! Rotor.left_contraction(Sphere) -> Versor
+//! Sphere.right_contraction(Rotor) -> Versor
+//! Rotor.commutator(Sphere) -> Plane
+//! Sphere.commutator(Rotor) -> Plane
+//! Rotor.anti_dot(Sphere) -> Multivector
+//! Sphere.anti_dot(Rotor) -> Multivector
+//! Rotor.geometric(Translator) -> Versor
+//! Translator.geometric(Rotor) -> Versor
+//! Rotor.anti_geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(Translator) -> Versor
+//! Translator.dot(Rotor) -> Versor
+//! Rotor.wedge(Translator) -> Versor
+//! Translator.wedge(Rotor) -> Versor
+//! Rotor.left_contraction(Translator) -> Translator
+//! Translator.left_contraction(Rotor) -> Rotor
+//! Rotor.right_contraction(Translator) -> Rotor
+//! Translator.right_contraction(Rotor) -> Translator
+//! Rotor.scalar_product(Translator) -> S
+//! Translator.scalar_product(Rotor) -> S
+//! Rotor.commutator(Translator) -> FlatPoint
+//! Translator.commutator(Rotor) -> FlatPoint
+//! Rotor.anti_dot(Translator) -> Multivector
+//! Translator.anti_dot(Rotor) -> Multivector
+//! Rotor.geometric(Versor) -> Versor
+//! Versor.geometric(Rotor) -> Versor
+//! Rotor.anti_geometric(Versor) -> Multivector
+//! Versor.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(Versor) -> Versor
+//! Versor.dot(Rotor) -> Versor
+//! Rotor.wedge(Versor) -> Versor
+//! Versor.wedge(Rotor) -> Versor
+//! Rotor.anti_wedge(Versor) -> Vec3
+//! Versor.anti_wedge(Rotor) -> Vec3
+//! Rotor.left_contraction(Versor) -> Versor
+//! Versor.left_contraction(Rotor) -> Rotor
+//! Rotor.right_contraction(Versor) -> Rotor
+//! Versor.right_contraction(Rotor) -> Versor
+//! Rotor.scalar_product(Versor) -> S
+//! Versor.scalar_product(Rotor) -> S
+//! Rotor.commutator(Versor) -> Versor
+//! Versor.commutator(Rotor) -> Versor
+//! Rotor.anti_dot(Versor) -> Multivector
+//! Versor.anti_dot(Rotor) -> Multivector
+//! Rotor.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(Multivector) -> Multivector
+//! Multivector.dot(Rotor) -> Multivector
+//! Rotor.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Rotor) -> Multivector
+//! Rotor.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Rotor) -> Multivector
+//! Rotor.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Rotor) -> Multivector
+//! Rotor.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Rotor) -> Multivector
+//! Rotor.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Rotor) -> S
+//! Rotor.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Rotor) -> Multivector
+//! Rotor.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Rotor) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rotor {
+	pub s: S,
+	pub e12: E12,
+	pub e13: E13,
+	pub e23: E23,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Rotor {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Rotor {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Rotor {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.e12.abs_diff_eq(&other.e12, epsilon)
+			&& self.e13.abs_diff_eq(&other.e13, epsilon)
+			&& self.e23.abs_diff_eq(&other.e23, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Rotor {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.e12.relative_eq(&other.e12, epsilon, max_relative)
+			&& self.e13.relative_eq(&other.e13, epsilon, max_relative)
+			&& self.e23.relative_eq(&other.e23, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Rotor {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: self.e23.rcompl(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: self.e13.rcompl(),
+			e3oi: self.e12.rcompl(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Rotor {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Rotor {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: self.e23.lcompl(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: self.e13.lcompl(),
+			e3oi: self.e12.lcompl(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.s.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Rotor {
+	fn rev(self) -> Self {
+		Rotor {
+			s: self.s,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+		}
+	}
+}
+
+impl AntiReverse for Rotor {
+	fn arev(self) -> Self {
+		Rotor {
+			s: self.s,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+		}
+	}
+}
+
+impl Involute for Rotor {
+	fn involute(self) -> Self {
+		Rotor {
+			s: self.s,
+			e12: self.e12,
+			e13: self.e13,
+			e23: self.e23,
+		}
+	}
+}
+
+impl Conjugate for Rotor {
+	fn conjugate(self) -> Self {
+		Rotor {
+			s: self.s,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+		}
+	}
+}
+
+impl Dual for Rotor {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: self.e23.dual(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: self.e13.dual(),
+			e3oi: self.e12.dual(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.s.dual(),
+		}
+	}
+}
+
+// Omitted: Rotor.square()  (not a scalar)
+// Omitted: Norm for Rotor  (the bulk and weight need a diagonal metric)
+
+impl Rotor {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.s.0, self.e12.0, self.e13.0, self.e23.0]
+	}
+}
+
+impl From<[f64; 4]> for Rotor {
+	fn from(a: [f64; 4]) -> Self {
+		Rotor {
+			s: S(a[0]),
+			e12: E12(a[1]),
+			e13: E13(a[2]),
+			e23: E23(a[3]),
+		}
+	}
+}
+
+impl From<Rotor> for [f64; 4] {
+	fn from(v: Rotor) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Rotor> for Multivector {
+	fn from(v: Rotor) -> Self {
+		Multivector {
+			s: v.s,
+			e12: v.e12,
+			e13: v.e13,
+			e23: v.e23,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Rotor {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Rotor` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.eo.0 == 0.0
+			&& mv.ei.0 == 0.0
+			&& mv.e1o.0 == 0.0
+			&& mv.e1i.0 == 0.0
+			&& mv.e2o.0 == 0.0
+			&& mv.e2i.0 == 0.0
+			&& mv.e3o.0 == 0.0
+			&& mv.e3i.0 == 0.0
+			&& mv.eoi.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e12o.0 == 0.0
+			&& mv.e12i.0 == 0.0
+			&& mv.e13o.0 == 0.0
+			&& mv.e13i.0 == 0.0
+			&& mv.e1oi.0 == 0.0
+			&& mv.e23o.0 == 0.0
+			&& mv.e23i.0 == 0.0
+			&& mv.e2oi.0 == 0.0
+			&& mv.e3oi.0 == 0.0
+			&& mv.e123o.0 == 0.0
+			&& mv.e123i.0 == 0.0
+			&& mv.e12oi.0 == 0.0
+			&& mv.e13oi.0 == 0.0
+			&& mv.e23oi.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(Rotor {
+				s: mv.s,
+				e12: mv.e12,
+				e13: mv.e13,
+				e23: mv.e23,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Rotor {
+	/// The matrix that transforms `[x, y, z, o, inf]` like `self.sandwich(v)` for a `Point` `v`,
+	/// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+	pub fn to_matrix(self) -> [[f64; 5]; 5] {
+		[
+			[
+				-self.e12.0 * self.e12.0 - self.e13.0 * self.e13.0 + self.e23.0 * self.e23.0 + self.s.0 * self.s.0,
+				2.0 * self.e12.0 * self.s.0 - 2.0 * self.e13.0 * self.e23.0,
+				2.0 * self.e12.0 * self.e23.0 + 2.0 * self.e13.0 * self.s.0,
+				0.0,
+				0.0,
+			],
+			[
+				-2.0 * self.e12.0 * self.s.0 - 2.0 * self.e13.0 * self.e23.0,
+				-self.e12.0 * self.e12.0 + self.e13.0 * self.e13.0 - self.e23.0 * self.e23.0 + self.s.0 * self.s.0,
+				-2.0 * self.e12.0 * self.e13.0 + 2.0 * self.e23.0 * self.s.0,
+				0.0,
+				0.0,
+			],
+			[
+				2.0 * self.e12.0 * self.e23.0 - 2.0 * self.e13.0 * self.s.0,
+				-2.0 * self.e12.0 * self.e13.0 - 2.0 * self.e23.0 * self.s.0,
+				self.e12.0 * self.e12.0 - self.e13.0 * self.e13.0 - self.e23.0 * self.e23.0 + self.s.0 * self.s.0,
+				0.0,
+				0.0,
+			],
+			[
+				0.0,
+				0.0,
+				0.0,
+				self.e12.0 * self.e12.0 + self.e13.0 * self.e13.0 + self.e23.0 * self.e23.0 + self.s.0 * self.s.0,
+				0.0,
+			],
+			[
+				0.0,
+				0.0,
+				0.0,
+				0.0,
+				self.e12.0 * self.e12.0 + self.e13.0 * self.e13.0 + self.e23.0 * self.e23.0 + self.s.0 * self.s.0,
+			],
+		]
+	}
+}
+
+impl Rotor {
+	/// The versor that moves nothing, whose matrix is the identity matrix.
+	pub fn identity() -> Self {
+		Rotor {
+			s: S(1.0),
+			e12: E12(0.0),
+			e13: E13(0.0),
+			e23: E23(0.0),
+		}
+	}
+}
+
+impl Rotor {
+	/// The normalized versor with this matrix, e.g. from [`Self::to_matrix`].
+	/// Both `v` and `-v` have the same matrix, and this returns the one with a non-negative `s`.
+	pub fn from_matrix(m: [[f64; 5]; 5]) -> Self {
+		let e12_e12 = (-2.0 * m[0][0] - 2.0 * m[1][1] + 2.0 * m[2][2] + m[3][3] + m[4][4]) / 8.0;
+		let e12_e13 = (-m[1][2] - m[2][1]) / 4.0;
+		let e12_e23 = (m[0][2] + m[2][0]) / 4.0;
+		let e12_s = (m[0][1] - m[1][0]) / 4.0;
+		let e13_e13 = (-2.0 * m[0][0] + 2.0 * m[1][1] - 2.0 * m[2][2] + m[3][3] + m[4][4]) / 8.0;
+		let e13_e23 = (-m[0][1] - m[1][0]) / 4.0;
+		let e13_s = (m[0][2] - m[2][0]) / 4.0;
+		let e23_e23 = (2.0 * m[0][0] - 2.0 * m[1][1] - 2.0 * m[2][2] + m[3][3] + m[4][4]) / 8.0;
+		let e23_s = (m[1][2] - m[2][1]) / 4.0;
+		let s_s = (2.0 * m[0][0] + 2.0 * m[1][1] + 2.0 * m[2][2] + m[3][3] + m[4][4]) / 8.0;
+		let s = s_s.max(0.0).sqrt();
+		let e12 = e12_e12.max(0.0).sqrt().copysign(s * e12_s);
+		let e13 = e13_e13.max(0.0).sqrt().copysign(s * e13_s + e12 * e12_e13);
+		let e23 = e23_e23
+			.max(0.0)
+			.sqrt()
+			.copysign(s * e23_s + e12 * e12_e23 + e13 * e13_e23);
+		Rotor {
+			s: S(s * s_s + e12 * e12_s + e13 * e13_s + e23 * e23_s),
+			e12: E12(s * e12_s + e12 * e12_e12 + e13 * e12_e13 + e23 * e12_e23),
+			e13: E13(s * e13_s + e12 * e12_e13 + e13 * e13_e13 + e23 * e13_e23),
+			e23: E23(s * e23_s + e12 * e12_e23 + e13 * e13_e23 + e23 * e23_e23),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Vec3:
+
+// Rotor.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Rotor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+			e3: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Rotor {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Vec3) -> Versor
+impl AntiGeometric<Vec3> for Rotor {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : Default::default(),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : Default::default(),
+		//     e3o  : Default::default(),
+		//     e3i  : Default::default(),
+		//     eoi  : Eoi(self.e12.0 * rhs.z.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Eoi(self.e12.0 * rhs.z.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// Rotor.dot(Vec3) -> Vec3
+impl Dot<Vec3> for Rotor {
+	type Output = Vec3;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+		//     y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+		//     z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		// }
+		Vec3 {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+			z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Rotor {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Rotor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.x.0),
+		//     e2    : E2(self.s.0 * rhs.y.0),
+		//     e3    : E3(self.s.0 * rhs.z.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.s.wedge(rhs.x),
+			e2: self.s.wedge(rhs.y),
+			e3: self.s.wedge(rhs.z),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e12.wedge(rhs.z) + self.e13.wedge(rhs.y) + self.e23.wedge(rhs.x),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Rotor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for Rotor {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Rotor {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Vec3) -> Vec3
+impl LeftContraction<Vec3> for Rotor {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.s.0 * rhs.x.0),
+		//     y: E2(self.s.0 * rhs.y.0),
+		//     z: E3(self.s.0 * rhs.z.0),
+		// }
+		Vec3 {
+			x: E1(self.s.0 * rhs.x.0),
+			y: E2(self.s.0 * rhs.y.0),
+			z: E3(self.s.0 * rhs.z.0),
+		}
+	}
+}
+
+// Rotor.right_contraction(Vec3) -> Vec3
+impl RightContraction<Vec3> for Rotor {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+		//     y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+		//     z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+			z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(Vec3) -> Vec3
+impl Commutator<Vec3> for Rotor {
+	type Output = Vec3;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+		//     y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+		//     z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+			z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		}
+	}
+}
+
+// Rotor.anti_dot(Vec3) -> Plane
+impl AntiDot<Vec3> for Rotor {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Plane {
+		//     e123i: Default::default(),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		// }
+		Plane {
+			e123i: Default::default(),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Point:
+
+// Rotor.geometric(Point) -> Multivector
+impl Geometric<Point> for Rotor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		//     eo    : Eo(self.s.0 * rhs.o.0),
+		//     ei    : Ei(self.s.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o  : E12o(self.e12.0 * rhs.o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.inf.0),
+		//     e13o  : E13o(self.e13.0 * rhs.o.0),
+		//     e13i  : E13i(self.e13.0 * rhs.inf.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : E23o(self.e23.0 * rhs.o.0),
+		//     e23i  : E23i(self.e23.0 * rhs.inf.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+			e3: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+			eo: Eo(self.s.0 * rhs.o.0),
+			ei: Ei(self.s.0 * rhs.inf.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+			e12o: E12o(self.e12.0 * rhs.o.0),
+			e12i: E12i(self.e12.0 * rhs.inf.0),
+			e13o: E13o(self.e13.0 * rhs.o.0),
+			e13i: E13i(self.e13.0 * rhs.inf.0),
+			e1oi: Default::default(),
+			e23o: E23o(self.e23.0 * rhs.o.0),
+			e23i: E23i(self.e23.0 * rhs.inf.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for Rotor {
+	type Output = Multivector;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Point) -> Versor
+impl AntiGeometric<Point> for Rotor {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : -E1o(self.e23.0 * rhs.o.0),
+		//     e1i  : E1i(self.e23.0 * rhs.inf.0),
+		//     e23  : Default::default(),
+		//     e2o  : E2o(self.e13.0 * rhs.o.0),
+		//     e2i  : -E2i(self.e13.0 * rhs.inf.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.o.0),
+		//     e3i  : E3i(self.e12.0 * rhs.inf.0),
+		//     eoi  : Eoi(self.e12.0 * rhs.z.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+		//     e123o: E123o(self.s.0 * rhs.o.0),
+		//     e123i: -E123i(self.s.0 * rhs.inf.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: -E1o(self.e23.0 * rhs.o.0),
+			e1i: E1i(self.e23.0 * rhs.inf.0),
+			e23: Default::default(),
+			e2o: E2o(self.e13.0 * rhs.o.0),
+			e2i: -E2i(self.e13.0 * rhs.inf.0),
+			e3o: -E3o(self.e12.0 * rhs.o.0),
+			e3i: E3i(self.e12.0 * rhs.inf.0),
+			eoi: Eoi(self.e12.0 * rhs.z.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+			e123o: E123o(self.s.0 * rhs.o.0),
+			e123i: -E123i(self.s.0 * rhs.inf.0),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// Rotor.dot(Point) -> Point
+impl Dot<Point> for Rotor {
+	type Output = Point;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+		//     y  : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+		//     z  : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		//     o  : Eo(self.s.0 * rhs.o.0),
+		//     inf: Ei(self.s.0 * rhs.inf.0),
+		// }
+		Point {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+			z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+			o: Eo(self.s.0 * rhs.o.0),
+			inf: Ei(self.s.0 * rhs.inf.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point> for Rotor {
+	type Output = Point;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Point) -> Multivector
+impl Wedge<Point> for Rotor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.x.0),
+		//     e2    : E2(self.s.0 * rhs.y.0),
+		//     e3    : E3(self.s.0 * rhs.z.0),
+		//     eo    : Eo(self.s.0 * rhs.o.0),
+		//     ei    : Ei(self.s.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o  : E12o(self.e12.0 * rhs.o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.inf.0),
+		//     e13o  : E13o(self.e13.0 * rhs.o.0),
+		//     e13i  : E13i(self.e13.0 * rhs.inf.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : E23o(self.e23.0 * rhs.o.0),
+		//     e23i  : E23i(self.e23.0 * rhs.inf.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.s.wedge(rhs.x),
+			e2: self.s.wedge(rhs.y),
+			e3: self.s.wedge(rhs.z),
+			eo: self.s.wedge(rhs.o),
+			ei: self.s.wedge(rhs.inf),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e12.wedge(rhs.z) + self.e13.wedge(rhs.y) + self.e23.wedge(rhs.x),
+			e12o: self.e12.wedge(rhs.o),
+			e12i: self.e12.wedge(rhs.inf),
+			e13o: self.e13.wedge(rhs.o),
+			e13i: self.e13.wedge(rhs.inf),
+			e1oi: Default::default(),
+			e23o: self.e23.wedge(rhs.o),
+			e23i: self.e23.wedge(rhs.inf),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point> for Rotor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point> for Rotor {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point> for Rotor {
+	type Output = Zero;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Point) -> Point
+impl LeftContraction<Point> for Rotor {
+	type Output = Point;
+	fn left_contraction(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : E1(self.s.0 * rhs.x.0),
+		//     y  : E2(self.s.0 * rhs.y.0),
+		//     z  : E3(self.s.0 * rhs.z.0),
+		//     o  : Eo(self.s.0 * rhs.o.0),
+		//     inf: Ei(self.s.0 * rhs.inf.0),
+		// }
+		Point {
+			x: E1(self.s.0 * rhs.x.0),
+			y: E2(self.s.0 * rhs.y.0),
+			z: E3(self.s.0 * rhs.z.0),
+			o: Eo(self.s.0 * rhs.o.0),
+			inf: Ei(self.s.0 * rhs.inf.0),
+		}
+	}
+}
+
+// Rotor.right_contraction(Point) -> Vec3
+impl RightContraction<Point> for Rotor {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+		//     y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+		//     z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+			z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		}
+	}
+}
+
+impl ScalarProduct<Point> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(Point) -> Vec3
+impl Commutator<Point> for Rotor {
+	type Output = Vec3;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+		//     y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+		//     z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+			y: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+			z: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		}
+	}
+}
+
+// Rotor.anti_dot(Point) -> Sphere
+impl AntiDot<Point> for Rotor {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.s.0 * rhs.o.0),
+		//     e123i: -E123i(self.s.0 * rhs.inf.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		// }
+		Sphere {
+			e123o: E123o(self.s.0 * rhs.o.0),
+			e123i: -E123i(self.s.0 * rhs.inf.0),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP FlatPoint:
+
+// Rotor.geometric(FlatPoint) -> Versor
+impl Geometric<FlatPoint> for Rotor {
+	type Output = Versor;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.s.0 * rhs.e1i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0),
+			e13oi: E13oi(self.e13.0 * rhs.eoi.0),
+			e23oi: E23oi(self.e23.0 * rhs.eoi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for Rotor {
+	type Output = Versor;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(FlatPoint) -> Multivector
+impl AntiGeometric<FlatPoint> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23.0 * rhs.eoi.0),
+		//     e2    : -E2(self.e13.0 * rhs.eoi.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23.0 * rhs.eoi.0),
+			e2: -E2(self.e13.0 * rhs.eoi.0),
+			e3: E3(self.e12.0 * rhs.eoi.0),
+			eo: Default::default(),
+			ei: Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.s.0 * rhs.eoi.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.s.0 * rhs.e1i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Rotor.dot(FlatPoint) -> FlatPoint
+impl Dot<FlatPoint> for Rotor {
+	type Output = FlatPoint;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.s.0 * rhs.e3i.0),
+		//     eoi: Eoi(self.s.0 * rhs.eoi.0),
+		// }
+		FlatPoint {
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for Rotor {
+	type Output = FlatPoint;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(FlatPoint) -> Versor
+impl Wedge<FlatPoint> for Rotor {
+	type Output = Versor;
+	fn wedge(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: self.s.wedge(rhs.e1i),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: self.s.wedge(rhs.e2i),
+			e3o: Default::default(),
+			e3i: self.s.wedge(rhs.e3i),
+			eoi: self.s.wedge(rhs.eoi),
+			e123o: Default::default(),
+			e123i: self.e12.wedge(rhs.e3i) + self.e13.wedge(rhs.e2i) + self.e23.wedge(rhs.e1i),
+			e12oi: self.e12.wedge(rhs.eoi),
+			e13oi: self.e13.wedge(rhs.eoi),
+			e23oi: self.e23.wedge(rhs.eoi),
+		}
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for Rotor {
+	type Output = Versor;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<FlatPoint> for Rotor {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for Rotor {
+	type Output = Zero;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(FlatPoint) -> FlatPoint
+impl LeftContraction<FlatPoint> for Rotor {
+	type Output = FlatPoint;
+	fn left_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.s.0 * rhs.e3i.0),
+		//     eoi: Eoi(self.s.0 * rhs.eoi.0),
+		// }
+		FlatPoint {
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+impl RightContraction<FlatPoint> for Rotor {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<FlatPoint> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(FlatPoint) -> FlatPoint
+impl Commutator<FlatPoint> for Rotor {
+	type Output = FlatPoint;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0),
+		//     e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0),
+		//     e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0),
+		//     eoi: Default::default(),
+		// }
+		FlatPoint {
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Rotor.anti_dot(FlatPoint) -> Circle
+impl AntiDot<FlatPoint> for Rotor {
+	type Output = Circle;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.s.0 * rhs.eoi.0),
+		//     e12o: Default::default(),
+		//     e12i: -E12i(self.s.0 * rhs.e3i.0),
+		//     e13o: Default::default(),
+		//     e13i: E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi: Default::default(),
+		//     e23o: Default::default(),
+		//     e23i: -E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Circle {
+			e123: -E123(self.s.0 * rhs.eoi.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.s.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.s.0 * rhs.e2i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.s.0 * rhs.e1i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP PointPair:
+
+// Rotor.geometric(PointPair) -> Versor
+impl Geometric<PointPair> for Rotor {
+	type Output = Versor;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e12  : -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e23.0 * rhs.e1o.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+			e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.s.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.s.0 * rhs.e23.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e23.0 * rhs.e1o.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0),
+			e13oi: E13oi(self.e13.0 * rhs.eoi.0),
+			e23oi: E23oi(self.e23.0 * rhs.eoi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for Rotor {
+	type Output = Versor;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(PointPair) -> Multivector
+impl AntiGeometric<PointPair> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23.0 * rhs.eoi.0),
+		//     e2    : -E2(self.e13.0 * rhs.eoi.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) - Eo(self.e23.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : E12o(self.e13.0 * rhs.e1o.0) + E12o(self.e23.0 * rhs.e2o.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e12.0 * rhs.e1o.0) + E13o(self.e23.0 * rhs.e3o.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e23.0 * rhs.e12.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23.0 * rhs.eoi.0),
+			e2: -E2(self.e13.0 * rhs.eoi.0),
+			e3: E3(self.e12.0 * rhs.eoi.0),
+			eo: -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) - Eo(self.e23.0 * rhs.e1o.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.s.0 * rhs.eoi.0),
+			e12o: E12o(self.e13.0 * rhs.e1o.0) + E12o(self.e23.0 * rhs.e2o.0) + E12o(self.s.0 * rhs.e3o.0),
+			e12i: -E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e12.0 * rhs.e1o.0) + E13o(self.e23.0 * rhs.e3o.0) - E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) + E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e23.0 * rhs.e12.0) - E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0) + E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Rotor.dot(PointPair) -> Versor
+impl Dot<PointPair> for Rotor {
+	type Output = Versor;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<PointPair> for Rotor {
+	type Output = Versor;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(PointPair) -> Versor
+impl Wedge<PointPair> for Rotor {
+	type Output = Versor;
+	fn wedge(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e23.0 * rhs.e1o.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: self.s.wedge(rhs.e12),
+			e13: self.s.wedge(rhs.e13),
+			e1o: self.s.wedge(rhs.e1o),
+			e1i: self.s.wedge(rhs.e1i),
+			e23: self.s.wedge(rhs.e23),
+			e2o: self.s.wedge(rhs.e2o),
+			e2i: self.s.wedge(rhs.e2i),
+			e3o: self.s.wedge(rhs.e3o),
+			e3i: self.s.wedge(rhs.e3i),
+			eoi: self.s.wedge(rhs.eoi),
+			e123o: self.e12.wedge(rhs.e3o) + self.e13.wedge(rhs.e2o) + self.e23.wedge(rhs.e1o),
+			e123i: self.e12.wedge(rhs.e3i) + self.e13.wedge(rhs.e2i) + self.e23.wedge(rhs.e1i),
+			e12oi: self.e12.wedge(rhs.eoi),
+			e13oi: self.e13.wedge(rhs.eoi),
+			e23oi: self.e23.wedge(rhs.eoi),
+		}
+	}
+}
+
+impl std::ops::BitXor<PointPair> for Rotor {
+	type Output = Versor;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<PointPair> for Rotor {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for Rotor {
+	type Output = Zero;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(PointPair) -> Versor
+impl LeftContraction<PointPair> for Rotor {
+	type Output = Versor;
+	fn left_contraction(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Rotor.right_contraction(PointPair) -> S
+impl RightContraction<PointPair> for Rotor {
+	type Output = S;
+	fn right_contraction(self, rhs: PointPair) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+	}
+}
+
+// Rotor.scalar_product(PointPair) -> S
+impl ScalarProduct<PointPair> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: PointPair) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+	}
+}
+
+// Rotor.commutator(PointPair) -> PointPair
+impl Commutator<PointPair> for Rotor {
+	type Output = PointPair;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+		//     e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+		//     e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0),
+		//     e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0),
+		//     e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+		//     e2o: -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e23.0 * rhs.e3o.0),
+		//     e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0),
+		//     e3o: -E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0),
+		//     e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+			e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e23.0 * rhs.e3o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0),
+			e3o: -E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Rotor.anti_dot(PointPair) -> Multivector
+impl AntiDot<PointPair> for Rotor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : -E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : -E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : -E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.s.0 * rhs.eoi.0),
+			e12o: E12o(self.s.0 * rhs.e3o.0),
+			e12i: -E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.s.0 * rhs.e23.0),
+			e23o: E23o(self.s.0 * rhs.e1o.0),
+			e23i: -E23i(self.s.0 * rhs.e1i.0),
+			e2oi: -E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Line:
+
+// Rotor.geometric(Line) -> Multivector
+impl Geometric<Line> for Rotor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e23.0 * rhs.e1oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0) + E12i(self.s.0 * rhs.e12i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e23.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Rotor {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Line) -> Versor
+impl AntiGeometric<Line> for Rotor {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e23.0 * rhs.e1oi.0),
+		//     e12  : -E12(self.e13.0 * rhs.e1oi.0) - E12(self.e23.0 * rhs.e2oi.0) - E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.e12.0 * rhs.e1oi.0) - E13(self.e23.0 * rhs.e3oi.0) + E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) - E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e12.0 * rhs.e23i.0) - E2i(self.e23.0 * rhs.e12i.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e23.0 * rhs.e1oi.0),
+			e12: -E12(self.e13.0 * rhs.e1oi.0) - E12(self.e23.0 * rhs.e2oi.0) - E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.e12.0 * rhs.e1oi.0) - E13(self.e23.0 * rhs.e3oi.0) + E13(self.s.0 * rhs.e2oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.s.0 * rhs.e23i.0),
+			e23: E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) - E23(self.s.0 * rhs.e1oi.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e12.0 * rhs.e23i.0) - E2i(self.e23.0 * rhs.e12i.0) - E2i(self.s.0 * rhs.e13i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.s.0 * rhs.e12i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Rotor.dot(Line) -> Multivector
+impl Dot<Line> for Rotor {
+	type Output = Multivector;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Rotor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Line) -> Multivector
+impl Wedge<Line> for Rotor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e23.0 * rhs.e1oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.s.wedge(rhs.e12i),
+			e13o: Default::default(),
+			e13i: self.s.wedge(rhs.e13i),
+			e1oi: self.s.wedge(rhs.e1oi),
+			e23o: Default::default(),
+			e23i: self.s.wedge(rhs.e23i),
+			e2oi: self.s.wedge(rhs.e2oi),
+			e3oi: self.s.wedge(rhs.e3oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e12.wedge(rhs.e3oi) + self.e13.wedge(rhs.e2oi) + self.e23.wedge(rhs.e1oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line> for Rotor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Line) -> S
+impl AntiWedge<Line> for Rotor {
+	type Output = S;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e23.0 * rhs.e1oi.0)
+		self.e12.anti_wedge(rhs.e3oi) + self.e13.anti_wedge(rhs.e2oi) + self.e23.anti_wedge(rhs.e1oi)
+	}
+}
+
+impl std::ops::BitAnd<Line> for Rotor {
+	type Output = S;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Line) -> Multivector
+impl LeftContraction<Line> for Rotor {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Line> for Rotor {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(Line) -> Line
+impl Commutator<Line> for Rotor {
+	type Output = Line;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0),
+		//     e13i: E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e23.0 * rhs.e12i.0),
+		//     e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0),
+		//     e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0),
+		//     e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e23.0 * rhs.e3oi.0),
+		//     e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0),
+		// }
+		Line {
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e23.0 * rhs.e12i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e23.0 * rhs.e3oi.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0),
+		}
+	}
+}
+
+// Rotor.anti_dot(Line) -> Versor
+impl AntiDot<Line> for Rotor {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : -E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.s.0 * rhs.e2oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.s.0 * rhs.e23i.0),
+			e23: -E23(self.s.0 * rhs.e1oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.s.0 * rhs.e13i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.s.0 * rhs.e12i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Circle:
+
+// Rotor.geometric(Circle) -> Multivector
+impl Geometric<Circle> for Rotor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23.0 * rhs.e123.0),
+		//     e2    : E2(self.e13.0 * rhs.e123.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : -E12o(self.e13.0 * rhs.e23o.0) + E12o(self.e23.0 * rhs.e13o.0) + E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0) + E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e23.0 * rhs.e1oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23.0 * rhs.e123.0),
+			e2: E2(self.e13.0 * rhs.e123.0),
+			e3: -E3(self.e12.0 * rhs.e123.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: -E12o(self.e13.0 * rhs.e23o.0) + E12o(self.e23.0 * rhs.e13o.0) + E12o(self.s.0 * rhs.e12o.0),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0) + E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0) + E23o(self.s.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e23.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for Rotor {
+	type Output = Multivector;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Circle) -> Versor
+impl AntiGeometric<Circle> for Rotor {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e23.0 * rhs.e1oi.0),
+		//     e12  : -E12(self.e13.0 * rhs.e1oi.0) - E12(self.e23.0 * rhs.e2oi.0) - E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.e12.0 * rhs.e1oi.0) - E13(self.e23.0 * rhs.e3oi.0) + E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : -E1o(self.e12.0 * rhs.e13o.0) + E1o(self.e13.0 * rhs.e12o.0) - E1o(self.s.0 * rhs.e23o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) - E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e23o.0) + E2o(self.e23.0 * rhs.e12o.0) + E2o(self.s.0 * rhs.e13o.0),
+		//     e2i  : E2i(self.e12.0 * rhs.e23i.0) - E2i(self.e23.0 * rhs.e12i.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : -E3o(self.e13.0 * rhs.e23o.0) + E3o(self.e23.0 * rhs.e13o.0) - E3o(self.s.0 * rhs.e12o.0),
+		//     e3i  : E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.e123.0),
+		//     e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e23.0 * rhs.e23o.0),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.e123.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.e123.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.e123.0),
+		// }
+		Versor {
+			s: S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e23.0 * rhs.e1oi.0),
+			e12: -E12(self.e13.0 * rhs.e1oi.0) - E12(self.e23.0 * rhs.e2oi.0) - E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.e12.0 * rhs.e1oi.0) - E13(self.e23.0 * rhs.e3oi.0) + E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.e12.0 * rhs.e13o.0) + E1o(self.e13.0 * rhs.e12o.0) - E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.s.0 * rhs.e23i.0),
+			e23: E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) - E23(self.s.0 * rhs.e1oi.0),
+			e2o: -E2o(self.e12.0 * rhs.e23o.0) + E2o(self.e23.0 * rhs.e12o.0) + E2o(self.s.0 * rhs.e13o.0),
+			e2i: E2i(self.e12.0 * rhs.e23i.0) - E2i(self.e23.0 * rhs.e12i.0) - E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.e13.0 * rhs.e23o.0) + E3o(self.e23.0 * rhs.e13o.0) - E3o(self.s.0 * rhs.e12o.0),
+			e3i: E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.s.0 * rhs.e123.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e23.0 * rhs.e23o.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0),
+			e13oi: E13oi(self.e13.0 * rhs.e123.0),
+			e23oi: E23oi(self.e23.0 * rhs.e123.0),
+		}
+	}
+}
+
+// Rotor.dot(Circle) -> Multivector
+impl Dot<Circle> for Rotor {
+	type Output = Multivector;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23.0 * rhs.e123.0),
+		//     e2    : E2(self.e13.0 * rhs.e123.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23.0 * rhs.e123.0),
+			e2: E2(self.e13.0 * rhs.e123.0),
+			e3: -E3(self.e12.0 * rhs.e123.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Circle> for Rotor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Circle) -> Multivector
+impl Wedge<Circle> for Rotor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e23.0 * rhs.e1oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.s.wedge(rhs.e123),
+			e12o: self.s.wedge(rhs.e12o),
+			e12i: self.s.wedge(rhs.e12i),
+			e13o: self.s.wedge(rhs.e13o),
+			e13i: self.s.wedge(rhs.e13i),
+			e1oi: self.s.wedge(rhs.e1oi),
+			e23o: self.s.wedge(rhs.e23o),
+			e23i: self.s.wedge(rhs.e23i),
+			e2oi: self.s.wedge(rhs.e2oi),
+			e3oi: self.s.wedge(rhs.e3oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e12.wedge(rhs.e3oi) + self.e13.wedge(rhs.e2oi) + self.e23.wedge(rhs.e1oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Circle> for Rotor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Circle) -> S
+impl AntiWedge<Circle> for Rotor {
+	type Output = S;
+	fn anti_wedge(self, rhs: Circle) -> Self::Output {
+		// S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e23.0 * rhs.e1oi.0)
+		self.e12.anti_wedge(rhs.e3oi) + self.e13.anti_wedge(rhs.e2oi) + self.e23.anti_wedge(rhs.e1oi)
+	}
+}
+
+impl std::ops::BitAnd<Circle> for Rotor {
+	type Output = S;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Circle) -> Multivector
+impl LeftContraction<Circle> for Rotor {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23.0 * rhs.e123.0),
+		//     e2    : E2(self.e13.0 * rhs.e123.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23.0 * rhs.e123.0),
+			e2: E2(self.e13.0 * rhs.e123.0),
+			e3: -E3(self.e12.0 * rhs.e123.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Circle> for Rotor {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Circle> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(Circle) -> Circle
+impl Commutator<Circle> for Rotor {
+	type Output = Circle;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// Circle {
+		//     e123: Default::default(),
+		//     e12o: -E12o(self.e13.0 * rhs.e23o.0) + E12o(self.e23.0 * rhs.e13o.0),
+		//     e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0),
+		//     e13o: E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e23.0 * rhs.e12o.0),
+		//     e13i: E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e23.0 * rhs.e12i.0),
+		//     e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0),
+		//     e23o: -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0),
+		//     e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0),
+		//     e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e23.0 * rhs.e3oi.0),
+		//     e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0),
+		// }
+		Circle {
+			e123: Default::default(),
+			e12o: -E12o(self.e13.0 * rhs.e23o.0) + E12o(self.e23.0 * rhs.e13o.0),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e23.0 * rhs.e12o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e23.0 * rhs.e12i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e23.0 * rhs.e3oi.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0),
+		}
+	}
+}
+
+// Rotor.anti_dot(Circle) -> Versor
+impl AntiDot<Circle> for Rotor {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : -E1o(self.s.0 * rhs.e23o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : -E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : E2o(self.s.0 * rhs.e13o.0),
+		//     e2i  : -E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : -E3o(self.s.0 * rhs.e12o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.e123.0),
+		//     e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e23.0 * rhs.e23o.0),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.e123.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.e123.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.e123.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.s.0 * rhs.e23i.0),
+			e23: -E23(self.s.0 * rhs.e1oi.0),
+			e2o: E2o(self.s.0 * rhs.e13o.0),
+			e2i: -E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.s.0 * rhs.e12o.0),
+			e3i: E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.s.0 * rhs.e123.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e23.0 * rhs.e23o.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0),
+			e13oi: E13oi(self.e13.0 * rhs.e123.0),
+			e23oi: E23oi(self.e23.0 * rhs.e123.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Plane:
+
+// Rotor.geometric(Plane) -> Versor
+impl Geometric<Plane> for Rotor {
+	type Output = Versor;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e13.0 * rhs.e123i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Rotor {
+	type Output = Versor;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23.0 * rhs.e123i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.s.0 * rhs.e23oi.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.s.0 * rhs.e12oi.0),
+			eo: Default::default(),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e12.0 * rhs.e123i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13.0 * rhs.e123i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e23.0 * rhs.e123i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Rotor.dot(Plane) -> Versor
+impl Dot<Plane> for Rotor {
+	type Output = Versor;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e13.0 * rhs.e123i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Rotor {
+	type Output = Versor;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Plane) -> Plane
+impl Wedge<Plane> for Rotor {
+	type Output = Plane;
+	fn wedge(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Plane {
+			e123i: self.s.wedge(rhs.e123i),
+			e12oi: self.s.wedge(rhs.e12oi),
+			e13oi: self.s.wedge(rhs.e13oi),
+			e23oi: self.s.wedge(rhs.e23oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Rotor {
+	type Output = Plane;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Plane) -> Vec3
+impl AntiWedge<Plane> for Rotor {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0),
+		//     y: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0),
+		//     z: E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0),
+		// }
+		Vec3 {
+			x: self.e12.anti_wedge(rhs.e13oi) + self.e13.anti_wedge(rhs.e12oi),
+			y: self.e12.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e12oi),
+			z: self.e13.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e13oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Rotor {
+	type Output = Vec3;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Plane) -> Versor
+impl LeftContraction<Plane> for Rotor {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e13.0 * rhs.e123i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Plane> for Rotor {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(Plane) -> Plane
+impl Commutator<Plane> for Rotor {
+	type Output = Plane;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     e123i: Default::default(),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0),
+		// }
+		Plane {
+			e123i: Default::default(),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+// Rotor.anti_dot(Plane) -> Multivector
+impl AntiDot<Plane> for Rotor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e23.0 * rhs.e123i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: Default::default(),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e12.0 * rhs.e123i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e13.0 * rhs.e123i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.e23.0 * rhs.e123i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Sphere:
+
+// Rotor.geometric(Sphere) -> Versor
+impl Geometric<Sphere> for Rotor {
+	type Output = Versor;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : -E1o(self.e23.0 * rhs.e123o.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0),
+		//     e23  : Default::default(),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0),
+			e23: Default::default(),
+			e2o: E2o(self.e13.0 * rhs.e123o.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for Rotor {
+	type Output = Versor;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Sphere) -> Multivector
+impl AntiGeometric<Sphere> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.e13.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : -E23o(self.e23.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e23.0 * rhs.e123i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.s.0 * rhs.e23oi.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0),
+			e13o: -E13o(self.e13.0 * rhs.e123o.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0),
+			e1oi: Default::default(),
+			e23o: -E23o(self.e23.0 * rhs.e123o.0),
+			e23i: E23i(self.e23.0 * rhs.e123i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Rotor.dot(Sphere) -> Versor
+impl Dot<Sphere> for Rotor {
+	type Output = Versor;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : -E1o(self.e23.0 * rhs.e123o.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0),
+		//     e23  : Default::default(),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0),
+			e23: Default::default(),
+			e2o: E2o(self.e13.0 * rhs.e123o.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Sphere> for Rotor {
+	type Output = Versor;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Sphere) -> Sphere
+impl Wedge<Sphere> for Rotor {
+	type Output = Sphere;
+	fn wedge(self, rhs: Sphere) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Sphere {
+			e123o: self.s.wedge(rhs.e123o),
+			e123i: self.s.wedge(rhs.e123i),
+			e12oi: self.s.wedge(rhs.e12oi),
+			e13oi: self.s.wedge(rhs.e13oi),
+			e23oi: self.s.wedge(rhs.e23oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Sphere> for Rotor {
+	type Output = Sphere;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Sphere) -> Vec3
+impl AntiWedge<Sphere> for Rotor {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0),
+		//     y: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0),
+		//     z: E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0),
+		// }
+		Vec3 {
+			x: self.e12.anti_wedge(rhs.e13oi) + self.e13.anti_wedge(rhs.e12oi),
+			y: self.e12.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e12oi),
+			z: self.e13.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e13oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for Rotor {
+	type Output = Vec3;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Sphere) -> Versor
+impl LeftContraction<Sphere> for Rotor {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : -E1o(self.e23.0 * rhs.e123o.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0),
+		//     e23  : Default::default(),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0),
+			e23: Default::default(),
+			e2o: E2o(self.e13.0 * rhs.e123o.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Sphere> for Rotor {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Sphere> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(Sphere) -> Plane
+impl Commutator<Sphere> for Rotor {
+	type Output = Plane;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// Plane {
+		//     e123i: Default::default(),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0),
+		// }
+		Plane {
+			e123i: Default::default(),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+// Rotor.anti_dot(Sphere) -> Multivector
+impl AntiDot<Sphere> for Rotor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.e13.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : -E23o(self.e23.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e23.0 * rhs.e123i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0),
+			e13o: -E13o(self.e13.0 * rhs.e123o.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0),
+			e1oi: Default::default(),
+			e23o: -E23o(self.e23.0 * rhs.e123o.0),
+			e23i: E23i(self.e23.0 * rhs.e123i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Rotor:
+
+// Rotor.geometric(Rotor) -> Rotor
+impl Geometric<Rotor> for Rotor {
+	type Output = Rotor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s  : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12: E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e23.0 * rhs.e12.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.s.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Rotor {
+	type Output = Rotor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Rotor) -> Multivector
+impl AntiGeometric<Rotor> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e13.0 * rhs.s.0) - E2oi(self.e23.0 * rhs.e12.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0)
+				- E2oi(self.e13.0 * rhs.s.0)
+				- E2oi(self.e23.0 * rhs.e12.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Rotor.dot(Rotor) -> Rotor
+impl Dot<Rotor> for Rotor {
+	type Output = Rotor;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s  : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12: E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13: E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23: E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+			e23: E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Rotor {
+	type Output = Rotor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Rotor) -> Rotor
+impl Wedge<Rotor> for Rotor {
+	type Output = Rotor;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     e12: E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13: E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23: E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: self.s.wedge(rhs.s),
+			e12: self.e12.wedge(rhs.s) + self.s.wedge(rhs.e12),
+			e13: self.e13.wedge(rhs.s) + self.s.wedge(rhs.e13),
+			e23: self.e23.wedge(rhs.s) + self.s.wedge(rhs.e23),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Rotor {
+	type Output = Rotor;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Rotor> for Rotor {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Rotor {
+	type Output = Zero;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Rotor) -> Rotor
+impl LeftContraction<Rotor> for Rotor {
+	type Output = Rotor;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s  : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12: E12(self.s.0 * rhs.e12.0),
+		//     e13: E13(self.s.0 * rhs.e13.0),
+		//     e23: E23(self.s.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Rotor.right_contraction(Rotor) -> Rotor
+impl RightContraction<Rotor> for Rotor {
+	type Output = Rotor;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s  : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12: E12(self.e12.0 * rhs.s.0),
+		//     e13: E13(self.e13.0 * rhs.s.0),
+		//     e23: E23(self.e23.0 * rhs.s.0),
+		// }
+		Rotor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+		}
+	}
+}
+
+// Rotor.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Rotor.commutator(Rotor) -> PointPair
+impl Commutator<Rotor> for Rotor {
+	type Output = PointPair;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+		//     e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+		//     e1o: Default::default(),
+		//     e1i: Default::default(),
+		//     e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+		//     e2o: Default::default(),
+		//     e2i: Default::default(),
+		//     e3o: Default::default(),
+		//     e3i: Default::default(),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+			e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Rotor.anti_dot(Rotor) -> Multivector
+impl AntiDot<Rotor> for Rotor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0) - E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Translator:
+
+// Rotor.geometric(Translator) -> Versor
+impl Geometric<Translator> for Rotor {
+	type Output = Versor;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Rotor {
+	type Output = Versor;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Translator) -> Multivector
+impl AntiGeometric<Translator> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e23.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e23.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.s.0 * rhs.e1i.0),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Rotor.dot(Translator) -> Versor
+impl Dot<Translator> for Rotor {
+	type Output = Versor;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e1o: Default::default(),
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+			e2o: Default::default(),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Rotor {
+	type Output = Versor;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Translator) -> Versor
+impl Wedge<Translator> for Rotor {
+	type Output = Versor;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: self.s.wedge(rhs.s),
+			e12: self.e12.wedge(rhs.s),
+			e13: self.e13.wedge(rhs.s),
+			e1o: Default::default(),
+			e1i: self.s.wedge(rhs.e1i),
+			e23: self.e23.wedge(rhs.s),
+			e2o: Default::default(),
+			e2i: self.s.wedge(rhs.e2i),
+			e3o: Default::default(),
+			e3i: self.s.wedge(rhs.e3i),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: self.e12.wedge(rhs.e3i) + self.e13.wedge(rhs.e2i) + self.e23.wedge(rhs.e1i),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Rotor {
+	type Output = Versor;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Translator> for Rotor {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Rotor {
+	type Output = Zero;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Translator) -> Translator
+impl LeftContraction<Translator> for Rotor {
+	type Output = Translator;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     e1i: E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.s.0 * rhs.e3i.0),
+		// }
+		Translator {
+			s: S(self.s.0 * rhs.s.0),
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// Rotor.right_contraction(Translator) -> Rotor
+impl RightContraction<Translator> for Rotor {
+	type Output = Rotor;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Rotor {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     e12: E12(self.e12.0 * rhs.s.0),
+		//     e13: E13(self.e13.0 * rhs.s.0),
+		//     e23: E23(self.e23.0 * rhs.s.0),
+		// }
+		Rotor {
+			s: S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+		}
+	}
+}
+
+// Rotor.scalar_product(Translator) -> S
+impl ScalarProduct<Translator> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator) -> Self::Output {
+		// S(self.s.0 * rhs.s.0)
+		S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Rotor.commutator(Translator) -> FlatPoint
+impl Commutator<Translator> for Rotor {
+	type Output = FlatPoint;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0),
+		//     e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0),
+		//     e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0),
+		//     eoi: Default::default(),
+		// }
+		FlatPoint {
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Rotor.anti_dot(Translator) -> Multivector
+impl AntiDot<Translator> for Rotor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e23.0 * rhs.s.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.s.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e23.0 * rhs.s.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.s.0 * rhs.e1i.0),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Versor:
+
+// Rotor.geometric(Versor) -> Versor
+impl Geometric<Versor> for Rotor {
+	type Output = Versor;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) - E1o(self.e23.0 * rhs.e123o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e23.0 * rhs.e123i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e23.0 * rhs.e1o.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) + E13oi(self.e13.0 * rhs.eoi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e23.0 * rhs.e12.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) - E1o(self.e23.0 * rhs.e123o.0)
+				+ E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e23.0 * rhs.e123i.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e13.0 * rhs.e123o.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0)
+				+ E2i(self.e13.0 * rhs.e123i.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0)
+				+ E123o(self.e23.0 * rhs.e1o.0)
+				+ E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0)
+				+ E123i(self.e23.0 * rhs.e1i.0)
+				+ E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e13.0 * rhs.e23oi.0)
+				+ E12oi(self.e23.0 * rhs.e13oi.0)
+				+ E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) + E13oi(self.e13.0 * rhs.eoi.0) - E13oi(self.e23.0 * rhs.e12oi.0)
+				+ E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0)
+				+ E23oi(self.e13.0 * rhs.e12oi.0)
+				+ E23oi(self.e23.0 * rhs.eoi.0)
+				+ E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for Rotor {
+	type Output = Versor;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Versor) -> Multivector
+impl AntiGeometric<Versor> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.e23.0 * rhs.eoi.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e13.0 * rhs.eoi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0) + E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) - Eo(self.e23.0 * rhs.e1o.0) - Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e13.0 * rhs.e1o.0) + E12o(self.e23.0 * rhs.e2o.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) - E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) + E13o(self.e23.0 * rhs.e3o.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e23.0 * rhs.e123o.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e13.0 * rhs.s.0) - E2oi(self.e23.0 * rhs.e12.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0)
+				+ E1(self.e23.0 * rhs.eoi.0)
+				+ E1(self.s.0 * rhs.e23oi.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0)
+				- E2(self.e13.0 * rhs.eoi.0)
+				- E2(self.e23.0 * rhs.e12oi.0)
+				- E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e12.0 * rhs.eoi.0) + E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0)
+				+ E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0)
+				- Eo(self.e23.0 * rhs.e1o.0)
+				- Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0)
+				+ Ei(self.e23.0 * rhs.e1i.0)
+				+ Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0)
+				+ E12o(self.e13.0 * rhs.e1o.0)
+				+ E12o(self.e23.0 * rhs.e2o.0)
+				+ E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0)
+				- E12i(self.e13.0 * rhs.e1i.0)
+				- E12i(self.e23.0 * rhs.e2i.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) + E13o(self.e23.0 * rhs.e3o.0)
+				- E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e23.0 * rhs.e3i.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e23.0 * rhs.e123o.0)
+				+ E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) + E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0)
+				- E2oi(self.e13.0 * rhs.s.0)
+				- E2oi(self.e23.0 * rhs.e12.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Rotor.dot(Versor) -> Versor
+impl Dot<Versor> for Rotor {
+	type Output = Versor;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : -E1o(self.e23.0 * rhs.e123o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) + E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for Rotor {
+	type Output = Versor;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Versor) -> Versor
+impl Wedge<Versor> for Rotor {
+	type Output = Versor;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e23.0 * rhs.e1o.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: self.s.wedge(rhs.s),
+			e12: self.e12.wedge(rhs.s) + self.s.wedge(rhs.e12),
+			e13: self.e13.wedge(rhs.s) + self.s.wedge(rhs.e13),
+			e1o: self.s.wedge(rhs.e1o),
+			e1i: self.s.wedge(rhs.e1i),
+			e23: self.e23.wedge(rhs.s) + self.s.wedge(rhs.e23),
+			e2o: self.s.wedge(rhs.e2o),
+			e2i: self.s.wedge(rhs.e2i),
+			e3o: self.s.wedge(rhs.e3o),
+			e3i: self.s.wedge(rhs.e3i),
+			eoi: self.s.wedge(rhs.eoi),
+			e123o: self.e12.wedge(rhs.e3o)
+				+ self.e13.wedge(rhs.e2o)
+				+ self.e23.wedge(rhs.e1o)
+				+ self.s.wedge(rhs.e123o),
+			e123i: self.e12.wedge(rhs.e3i)
+				+ self.e13.wedge(rhs.e2i)
+				+ self.e23.wedge(rhs.e1i)
+				+ self.s.wedge(rhs.e123i),
+			e12oi: self.e12.wedge(rhs.eoi) + self.s.wedge(rhs.e12oi),
+			e13oi: self.e13.wedge(rhs.eoi) + self.s.wedge(rhs.e13oi),
+			e23oi: self.e23.wedge(rhs.eoi) + self.s.wedge(rhs.e23oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for Rotor {
+	type Output = Versor;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Versor) -> Vec3
+impl AntiWedge<Versor> for Rotor {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0),
+		//     y: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0),
+		//     z: E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0),
+		// }
+		Vec3 {
+			x: self.e12.anti_wedge(rhs.e13oi) + self.e13.anti_wedge(rhs.e12oi),
+			y: self.e12.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e12oi),
+			z: self.e13.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e13oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Versor> for Rotor {
+	type Output = Vec3;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Versor) -> Versor
+impl LeftContraction<Versor> for Rotor {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : -E1o(self.e23.0 * rhs.e123o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) + E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Rotor.right_contraction(Versor) -> Rotor
+impl RightContraction<Versor> for Rotor {
+	type Output = Rotor;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Rotor {
+		//     s  : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12: E12(self.e12.0 * rhs.s.0),
+		//     e13: E13(self.e13.0 * rhs.s.0),
+		//     e23: E23(self.e23.0 * rhs.s.0),
+		// }
+		Rotor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+		}
+	}
+}
+
+// Rotor.scalar_product(Versor) -> S
+impl ScalarProduct<Versor> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Versor) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Rotor.commutator(Versor) -> Versor
+impl Commutator<Versor> for Rotor {
+	type Output = Versor;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+		//     e1o  : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e23.0 * rhs.e3o.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0),
+		//     e3o  : -E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+			e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e23.0 * rhs.e3o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0),
+			e3o: -E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+// Rotor.anti_dot(Versor) -> Multivector
+impl AntiDot<Versor> for Rotor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e23.0 * rhs.e123o.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e23.0 * rhs.e123i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) - E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0) + E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e23.0 * rhs.e123o.0) + E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e23.0 * rhs.e123i.0) - E23i(self.s.0 * rhs.e1i.0),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0) - E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Multivector:
+
+// Rotor.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Rotor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) + E2(self.e13.0 * rhs.e123.0) + E2(self.e23.0 * rhs.e3.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) - E3(self.e13.0 * rhs.e1.0) - E3(self.e23.0 * rhs.e2.0) + E3(self.s.0 * rhs.e3.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0) + Eo(self.s.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13   : E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o   : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) - E1o(self.e23.0 * rhs.e123o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e23.0 * rhs.e123i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o   : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : E123(self.e12.0 * rhs.e3.0) - E123(self.e13.0 * rhs.e2.0) + E123(self.e23.0 * rhs.e1.0) + E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.e12.0 * rhs.eo.0) - E12o(self.e13.0 * rhs.e23o.0) + E12o(self.e23.0 * rhs.e13o.0) + E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.ei.0) - E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e12.0 * rhs.e23o.0) + E13o(self.e13.0 * rhs.eo.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e13.0 * rhs.ei.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) - E1oi(self.e23.0 * rhs.e123oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0) + E23o(self.e23.0 * rhs.eo.0) + E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.e23.0 * rhs.ei.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e13.0 * rhs.e123oi.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e12.0 * rhs.e123oi.0) - E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e23.0 * rhs.e1o.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e23oi.0) + E13oi(self.e13.0 * rhs.eoi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e23.0 * rhs.e1oi.0) + E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0) - E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.s.0 * rhs.e1.0),
+			e2: -E2(self.e12.0 * rhs.e1.0)
+				+ E2(self.e13.0 * rhs.e123.0)
+				+ E2(self.e23.0 * rhs.e3.0)
+				+ E2(self.s.0 * rhs.e2.0),
+			e3: -E3(self.e12.0 * rhs.e123.0) - E3(self.e13.0 * rhs.e1.0) - E3(self.e23.0 * rhs.e2.0)
+				+ E3(self.s.0 * rhs.e3.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0)
+				+ Eo(self.s.0 * rhs.eo.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.s.0 * rhs.ei.0),
+			e12: E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e23.0 * rhs.e12.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) - E1o(self.e23.0 * rhs.e123o.0)
+				+ E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e23.0 * rhs.e123i.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e13.0 * rhs.e123o.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0)
+				+ E2i(self.e13.0 * rhs.e123i.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123: E123(self.e12.0 * rhs.e3.0) - E123(self.e13.0 * rhs.e2.0)
+				+ E123(self.e23.0 * rhs.e1.0)
+				+ E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.e12.0 * rhs.eo.0) - E12o(self.e13.0 * rhs.e23o.0)
+				+ E12o(self.e23.0 * rhs.e13o.0)
+				+ E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e12.0 * rhs.ei.0) - E12i(self.e13.0 * rhs.e23i.0)
+				+ E12i(self.e23.0 * rhs.e13i.0)
+				+ E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) + E13o(self.e13.0 * rhs.eo.0) - E13o(self.e23.0 * rhs.e12o.0)
+				+ E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e13.0 * rhs.ei.0) - E13i(self.e23.0 * rhs.e12i.0)
+				+ E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) - E1oi(self.e23.0 * rhs.e123oi.0)
+				+ E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0)
+				+ E23o(self.e13.0 * rhs.e12o.0)
+				+ E23o(self.e23.0 * rhs.eo.0)
+				+ E23o(self.s.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0)
+				+ E23i(self.e13.0 * rhs.e12i.0)
+				+ E23i(self.e23.0 * rhs.ei.0)
+				+ E23i(self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0)
+				+ E2oi(self.e13.0 * rhs.e123oi.0)
+				+ E2oi(self.e23.0 * rhs.e3oi.0)
+				+ E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e12.0 * rhs.e123oi.0) - E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0)
+				+ E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0)
+				+ E123o(self.e23.0 * rhs.e1o.0)
+				+ E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0)
+				+ E123i(self.e23.0 * rhs.e1i.0)
+				+ E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e13.0 * rhs.e23oi.0)
+				+ E12oi(self.e23.0 * rhs.e13oi.0)
+				+ E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) + E13oi(self.e13.0 * rhs.eoi.0) - E13oi(self.e23.0 * rhs.e12oi.0)
+				+ E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0)
+				+ E23oi(self.e13.0 * rhs.e12oi.0)
+				+ E23oi(self.e23.0 * rhs.eoi.0)
+				+ E23oi(self.s.0 * rhs.e23oi.0),
+			e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0)
+				+ E123oi(self.e23.0 * rhs.e1oi.0)
+				+ E123oi(self.s.0 * rhs.e123oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Rotor {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e23.0 * rhs.e1oi.0) + S(self.s.0 * rhs.e123oi.0),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.e23.0 * rhs.eoi.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e13.0 * rhs.eoi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0) + E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) - Eo(self.e23.0 * rhs.e1o.0) - Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : E12(self.e12.0 * rhs.e123oi.0) - E12(self.e13.0 * rhs.e1oi.0) - E12(self.e23.0 * rhs.e2oi.0) - E12(self.s.0 * rhs.e3oi.0),
+		//     e13   : E13(self.e12.0 * rhs.e1oi.0) + E13(self.e13.0 * rhs.e123oi.0) - E13(self.e23.0 * rhs.e3oi.0) + E13(self.s.0 * rhs.e2oi.0),
+		//     e1o   : -E1o(self.e12.0 * rhs.e13o.0) + E1o(self.e13.0 * rhs.e12o.0) - E1o(self.e23.0 * rhs.eo.0) - E1o(self.s.0 * rhs.e23o.0),
+		//     e1i   : E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.e23.0 * rhs.ei.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23   : E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) + E23(self.e23.0 * rhs.e123oi.0) - E23(self.s.0 * rhs.e1oi.0),
+		//     e2o   : -E2o(self.e12.0 * rhs.e23o.0) + E2o(self.e13.0 * rhs.eo.0) + E2o(self.e23.0 * rhs.e12o.0) + E2o(self.s.0 * rhs.e13o.0),
+		//     e2i   : E2i(self.e12.0 * rhs.e23i.0) - E2i(self.e13.0 * rhs.ei.0) - E2i(self.e23.0 * rhs.e12i.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.eo.0) - E3o(self.e13.0 * rhs.e23o.0) + E3o(self.e23.0 * rhs.e13o.0) - E3o(self.s.0 * rhs.e12o.0),
+		//     e3i   : E3i(self.e12.0 * rhs.ei.0) + E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi   : Eoi(self.e12.0 * rhs.e3.0) - Eoi(self.e13.0 * rhs.e2.0) + Eoi(self.e23.0 * rhs.e1.0) + Eoi(self.s.0 * rhs.e123.0),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e13.0 * rhs.e1o.0) + E12o(self.e23.0 * rhs.e2o.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) - E12i(self.e13.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) + E13o(self.e23.0 * rhs.e3o.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e23.0 * rhs.e123o.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e13.0 * rhs.s.0) - E2oi(self.e23.0 * rhs.e12.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e23.0 * rhs.e23o.0) + E123o(self.s.0 * rhs.eo.0),
+		//     e123i : E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.s.0 * rhs.ei.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.e123.0) - E12oi(self.e13.0 * rhs.e1.0) - E12oi(self.e23.0 * rhs.e2.0) - E12oi(self.s.0 * rhs.e3.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e1.0) + E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e23.0 * rhs.e3.0) + E13oi(self.s.0 * rhs.e2.0),
+		//     e23oi : E23oi(self.e12.0 * rhs.e2.0) + E23oi(self.e13.0 * rhs.e3.0) + E23oi(self.e23.0 * rhs.e123.0) - E23oi(self.s.0 * rhs.e1.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0)
+				+ S(self.e23.0 * rhs.e1oi.0)
+				+ S(self.s.0 * rhs.e123oi.0),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0)
+				+ E1(self.e23.0 * rhs.eoi.0)
+				+ E1(self.s.0 * rhs.e23oi.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0)
+				- E2(self.e13.0 * rhs.eoi.0)
+				- E2(self.e23.0 * rhs.e12oi.0)
+				- E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e12.0 * rhs.eoi.0) + E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0)
+				+ E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0)
+				- Eo(self.e23.0 * rhs.e1o.0)
+				- Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0)
+				+ Ei(self.e23.0 * rhs.e1i.0)
+				+ Ei(self.s.0 * rhs.e123i.0),
+			e12: E12(self.e12.0 * rhs.e123oi.0)
+				- E12(self.e13.0 * rhs.e1oi.0)
+				- E12(self.e23.0 * rhs.e2oi.0)
+				- E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.e12.0 * rhs.e1oi.0) + E13(self.e13.0 * rhs.e123oi.0) - E13(self.e23.0 * rhs.e3oi.0)
+				+ E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.e12.0 * rhs.e13o.0) + E1o(self.e13.0 * rhs.e12o.0)
+				- E1o(self.e23.0 * rhs.eo.0)
+				- E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.e12.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0)
+				+ E1i(self.e23.0 * rhs.ei.0)
+				+ E1i(self.s.0 * rhs.e23i.0),
+			e23: E23(self.e12.0 * rhs.e2oi.0) + E23(self.e13.0 * rhs.e3oi.0) + E23(self.e23.0 * rhs.e123oi.0)
+				- E23(self.s.0 * rhs.e1oi.0),
+			e2o: -E2o(self.e12.0 * rhs.e23o.0)
+				+ E2o(self.e13.0 * rhs.eo.0)
+				+ E2o(self.e23.0 * rhs.e12o.0)
+				+ E2o(self.s.0 * rhs.e13o.0),
+			e2i: E2i(self.e12.0 * rhs.e23i.0)
+				- E2i(self.e13.0 * rhs.ei.0)
+				- E2i(self.e23.0 * rhs.e12i.0)
+				- E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.e12.0 * rhs.eo.0) - E3o(self.e13.0 * rhs.e23o.0) + E3o(self.e23.0 * rhs.e13o.0)
+				- E3o(self.s.0 * rhs.e12o.0),
+			e3i: E3i(self.e12.0 * rhs.ei.0) + E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e23.0 * rhs.e13i.0)
+				+ E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.e12.0 * rhs.e3.0) - Eoi(self.e13.0 * rhs.e2.0)
+				+ Eoi(self.e23.0 * rhs.e1.0)
+				+ Eoi(self.s.0 * rhs.e123.0),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0)
+				+ E12o(self.e13.0 * rhs.e1o.0)
+				+ E12o(self.e23.0 * rhs.e2o.0)
+				+ E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0)
+				- E12i(self.e13.0 * rhs.e1i.0)
+				- E12i(self.e23.0 * rhs.e2i.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) + E13o(self.e23.0 * rhs.e3o.0)
+				- E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e23.0 * rhs.e3i.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e23.0 * rhs.e123o.0)
+				+ E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) + E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0)
+				- E2oi(self.e13.0 * rhs.s.0)
+				- E2oi(self.e23.0 * rhs.e12.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e23.0 * rhs.e23o.0)
+				+ E123o(self.s.0 * rhs.eo.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.s.0 * rhs.ei.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0)
+				- E12oi(self.e13.0 * rhs.e1.0)
+				- E12oi(self.e23.0 * rhs.e2.0)
+				- E12oi(self.s.0 * rhs.e3.0),
+			e13oi: E13oi(self.e12.0 * rhs.e1.0) + E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e23.0 * rhs.e3.0)
+				+ E13oi(self.s.0 * rhs.e2.0),
+			e23oi: E23oi(self.e12.0 * rhs.e2.0) + E23oi(self.e13.0 * rhs.e3.0) + E23oi(self.e23.0 * rhs.e123.0)
+				- E23oi(self.s.0 * rhs.e1.0),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Rotor.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Rotor {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) + E2(self.e13.0 * rhs.e123.0) + E2(self.e23.0 * rhs.e3.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) - E3(self.e13.0 * rhs.e1.0) - E3(self.e23.0 * rhs.e2.0) + E3(self.s.0 * rhs.e3.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0) + Eo(self.s.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13   : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o   : -E1o(self.e23.0 * rhs.e123o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o   : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : E2i(self.e13.0 * rhs.e123i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : -E1oi(self.e23.0 * rhs.e123oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.e13.0 * rhs.e123oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e12.0 * rhs.e123oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0) - E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.s.0 * rhs.e1.0),
+			e2: -E2(self.e12.0 * rhs.e1.0)
+				+ E2(self.e13.0 * rhs.e123.0)
+				+ E2(self.e23.0 * rhs.e3.0)
+				+ E2(self.s.0 * rhs.e2.0),
+			e3: -E3(self.e12.0 * rhs.e123.0) - E3(self.e13.0 * rhs.e1.0) - E3(self.e23.0 * rhs.e2.0)
+				+ E3(self.s.0 * rhs.e3.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0)
+				+ Eo(self.s.0 * rhs.eo.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.s.0 * rhs.ei.0),
+			e12: E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) + E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: -E1oi(self.e23.0 * rhs.e123oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.e13.0 * rhs.e123oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e12.0 * rhs.e123oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+			e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Rotor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Rotor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.s.0 * rhs.s.0),
+		//     e1    : E1(self.s.0 * rhs.e1.0),
+		//     e2    : E2(self.s.0 * rhs.e2.0),
+		//     e3    : E3(self.s.0 * rhs.e3.0),
+		//     eo    : Eo(self.s.0 * rhs.eo.0),
+		//     ei    : Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13   : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o   : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o   : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : E123(self.e12.0 * rhs.e3.0) - E123(self.e13.0 * rhs.e2.0) + E123(self.e23.0 * rhs.e1.0) + E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.e12.0 * rhs.eo.0) + E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.ei.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e13.0 * rhs.eo.0) + E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e13.0 * rhs.ei.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.e23.0 * rhs.eo.0) + E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.e23.0 * rhs.ei.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e23.0 * rhs.e1o.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e23.0 * rhs.e1oi.0) + E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			e1: self.s.wedge(rhs.e1),
+			e2: self.s.wedge(rhs.e2),
+			e3: self.s.wedge(rhs.e3),
+			eo: self.s.wedge(rhs.eo),
+			ei: self.s.wedge(rhs.ei),
+			e12: self.e12.wedge(rhs.s) + self.s.wedge(rhs.e12),
+			e13: self.e13.wedge(rhs.s) + self.s.wedge(rhs.e13),
+			e1o: self.s.wedge(rhs.e1o),
+			e1i: self.s.wedge(rhs.e1i),
+			e23: self.e23.wedge(rhs.s) + self.s.wedge(rhs.e23),
+			e2o: self.s.wedge(rhs.e2o),
+			e2i: self.s.wedge(rhs.e2i),
+			e3o: self.s.wedge(rhs.e3o),
+			e3i: self.s.wedge(rhs.e3i),
+			eoi: self.s.wedge(rhs.eoi),
+			e123: self.e12.wedge(rhs.e3) + self.e13.wedge(rhs.e2) + self.e23.wedge(rhs.e1) + self.s.wedge(rhs.e123),
+			e12o: self.e12.wedge(rhs.eo) + self.s.wedge(rhs.e12o),
+			e12i: self.e12.wedge(rhs.ei) + self.s.wedge(rhs.e12i),
+			e13o: self.e13.wedge(rhs.eo) + self.s.wedge(rhs.e13o),
+			e13i: self.e13.wedge(rhs.ei) + self.s.wedge(rhs.e13i),
+			e1oi: self.s.wedge(rhs.e1oi),
+			e23o: self.e23.wedge(rhs.eo) + self.s.wedge(rhs.e23o),
+			e23i: self.e23.wedge(rhs.ei) + self.s.wedge(rhs.e23i),
+			e2oi: self.s.wedge(rhs.e2oi),
+			e3oi: self.s.wedge(rhs.e3oi),
+			e123o: self.e12.wedge(rhs.e3o)
+				+ self.e13.wedge(rhs.e2o)
+				+ self.e23.wedge(rhs.e1o)
+				+ self.s.wedge(rhs.e123o),
+			e123i: self.e12.wedge(rhs.e3i)
+				+ self.e13.wedge(rhs.e2i)
+				+ self.e23.wedge(rhs.e1i)
+				+ self.s.wedge(rhs.e123i),
+			e12oi: self.e12.wedge(rhs.eoi) + self.s.wedge(rhs.e12oi),
+			e13oi: self.e13.wedge(rhs.eoi) + self.s.wedge(rhs.e13oi),
+			e23oi: self.e23.wedge(rhs.eoi) + self.s.wedge(rhs.e23oi),
+			e123oi: self.e12.wedge(rhs.e3oi)
+				+ self.e13.wedge(rhs.e2oi)
+				+ self.e23.wedge(rhs.e1oi)
+				+ self.s.wedge(rhs.e123oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Rotor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Rotor {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e23.0 * rhs.e1oi.0) + S(self.s.0 * rhs.e123oi.0),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0),
+		//     e3    : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : E12(self.e12.0 * rhs.e123oi.0),
+		//     e13   : E13(self.e13.0 * rhs.e123oi.0),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : E23(self.e23.0 * rhs.e123oi.0),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.e12.anti_wedge(rhs.e3oi)
+				+ self.e13.anti_wedge(rhs.e2oi)
+				+ self.e23.anti_wedge(rhs.e1oi)
+				+ self.s.anti_wedge(rhs.e123oi),
+			e1: self.e12.anti_wedge(rhs.e13oi) + self.e13.anti_wedge(rhs.e12oi),
+			e2: self.e12.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e12oi),
+			e3: self.e13.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e13oi),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: self.e12.anti_wedge(rhs.e123oi),
+			e13: self.e13.anti_wedge(rhs.e123oi),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: self.e23.anti_wedge(rhs.e123oi),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Rotor {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Rotor {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : -E1(self.e23.0 * rhs.e123.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2    : E2(self.e13.0 * rhs.e123.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) + E3(self.s.0 * rhs.e3.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0) + Eo(self.s.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.s.0 * rhs.e12.0),
+		//     e13   : E13(self.s.0 * rhs.e13.0),
+		//     e1o   : -E1o(self.e23.0 * rhs.e123o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : E23(self.s.0 * rhs.e23.0),
+		//     e2o   : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : E2i(self.e13.0 * rhs.e123i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : -E1oi(self.e23.0 * rhs.e123oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.e13.0 * rhs.e123oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e12.0 * rhs.e123oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: -E1(self.e23.0 * rhs.e123.0) + E1(self.s.0 * rhs.e1.0),
+			e2: E2(self.e13.0 * rhs.e123.0) + E2(self.s.0 * rhs.e2.0),
+			e3: -E3(self.e12.0 * rhs.e123.0) + E3(self.s.0 * rhs.e3.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e23.0 * rhs.e23o.0)
+				+ Eo(self.s.0 * rhs.eo.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.s.0 * rhs.ei.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) + E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: -E1oi(self.e23.0 * rhs.e123oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.e13.0 * rhs.e123oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e12.0 * rhs.e123oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+			e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		}
+	}
+}
+
+// Rotor.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Rotor {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) + E2(self.e23.0 * rhs.e3.0),
+		//     e3    : -E3(self.e13.0 * rhs.e1.0) - E3(self.e23.0 * rhs.e2.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : E12(self.e12.0 * rhs.s.0),
+		//     e13   : E13(self.e13.0 * rhs.s.0),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : E23(self.e23.0 * rhs.s.0),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0),
+			e2: -E2(self.e12.0 * rhs.e1.0) + E2(self.e23.0 * rhs.e3.0),
+			e3: -E3(self.e13.0 * rhs.e1.0) - E3(self.e23.0 * rhs.e2.0),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: E23(self.e23.0 * rhs.s.0),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Rotor.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Rotor.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Rotor {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) + E2(self.e23.0 * rhs.e3.0),
+		//     e3    : -E3(self.e13.0 * rhs.e1.0) - E3(self.e23.0 * rhs.e2.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+		//     e13   : E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+		//     e1o   : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0),
+		//     e1i   : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0),
+		//     e23   : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+		//     e2o   : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e23.0 * rhs.e3o.0),
+		//     e2i   : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0),
+		//     e3o   : -E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0),
+		//     e3i   : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : -E12o(self.e13.0 * rhs.e23o.0) + E12o(self.e23.0 * rhs.e13o.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0),
+		//     e13o  : E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e23.0 * rhs.e12o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e23.0 * rhs.e12i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e23.0 * rhs.e3oi.0),
+		//     e3oi  : -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0),
+		//     e23oi : -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e2.0) + E1(self.e13.0 * rhs.e3.0),
+			e2: -E2(self.e12.0 * rhs.e1.0) + E2(self.e23.0 * rhs.e3.0),
+			e3: -E3(self.e13.0 * rhs.e1.0) - E3(self.e23.0 * rhs.e2.0),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+			e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e23.0 * rhs.e3o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0),
+			e3o: -E3o(self.e13.0 * rhs.e1o.0) - E3o(self.e23.0 * rhs.e2o.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: -E12o(self.e13.0 * rhs.e23o.0) + E12o(self.e23.0 * rhs.e13o.0),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e23.0 * rhs.e13i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e23.0 * rhs.e12o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e23.0 * rhs.e12i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e23.0 * rhs.e3oi.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e23.0 * rhs.e2oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Rotor.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Rotor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.s.0 * rhs.e123oi.0),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : E12(self.e12.0 * rhs.e123oi.0) - E12(self.s.0 * rhs.e3oi.0),
+		//     e13   : E13(self.e13.0 * rhs.e123oi.0) + E13(self.s.0 * rhs.e2oi.0),
+		//     e1o   : -E1o(self.s.0 * rhs.e23o.0),
+		//     e1i   : E1i(self.s.0 * rhs.e23i.0),
+		//     e23   : E23(self.e23.0 * rhs.e123oi.0) - E23(self.s.0 * rhs.e1oi.0),
+		//     e2o   : E2o(self.s.0 * rhs.e13o.0),
+		//     e2i   : -E2i(self.s.0 * rhs.e13i.0),
+		//     e3o   : -E3o(self.s.0 * rhs.e12o.0),
+		//     e3i   : E3i(self.s.0 * rhs.e12i.0),
+		//     eoi   : Eoi(self.s.0 * rhs.e123.0),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e23.0 * rhs.e123o.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e23.0 * rhs.e123i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e23.0 * rhs.e23o.0) + E123o(self.s.0 * rhs.eo.0),
+		//     e123i : E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.s.0 * rhs.ei.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.e123.0) - E12oi(self.e13.0 * rhs.e1.0) - E12oi(self.e23.0 * rhs.e2.0) - E12oi(self.s.0 * rhs.e3.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e1.0) + E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e23.0 * rhs.e3.0) + E13oi(self.s.0 * rhs.e2.0),
+		//     e23oi : E23oi(self.e12.0 * rhs.e2.0) + E23oi(self.e13.0 * rhs.e3.0) + E23oi(self.e23.0 * rhs.e123.0) - E23oi(self.s.0 * rhs.e1.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: S(self.s.0 * rhs.e123oi.0),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: E12(self.e12.0 * rhs.e123oi.0) - E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.e13.0 * rhs.e123oi.0) + E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.s.0 * rhs.e23i.0),
+			e23: E23(self.e23.0 * rhs.e123oi.0) - E23(self.s.0 * rhs.e1oi.0),
+			e2o: E2o(self.s.0 * rhs.e13o.0),
+			e2i: -E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.s.0 * rhs.e12o.0),
+			e3i: E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.s.0 * rhs.e123.0),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) - E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0) + E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e23.0 * rhs.e123o.0) + E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e23.0 * rhs.e123i.0) - E23i(self.s.0 * rhs.e1i.0),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0) - E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.s.0 * rhs.e12.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e23.0 * rhs.e23o.0)
+				+ E123o(self.s.0 * rhs.eo.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) + E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.s.0 * rhs.ei.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0)
+				- E12oi(self.e13.0 * rhs.e1.0)
+				- E12oi(self.e23.0 * rhs.e2.0)
+				- E12oi(self.s.0 * rhs.e3.0),
+			e13oi: E13oi(self.e12.0 * rhs.e1.0) + E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e23.0 * rhs.e3.0)
+				+ E13oi(self.s.0 * rhs.e2.0),
+			e23oi: E23oi(self.e12.0 * rhs.e2.0) + E23oi(self.e13.0 * rhs.e3.0) + E23oi(self.e23.0 * rhs.e123.0)
+				- E23oi(self.s.0 * rhs.e1.0),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Rotor.sandwich(Vec3) -> Vec3
+impl Sandwich<Vec3> for Rotor {
+	fn sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: -E1(self.e12.0 * self.e12.0 * rhs.x.0)
+				+ E1(self.e12.0 * self.e23.0 * rhs.z.0) * 2.0
+				+ E1(self.e12.0 * self.s.0 * rhs.y.0) * 2.0
+				- E1(self.e13.0 * self.e13.0 * rhs.x.0)
+				- E1(self.e13.0 * self.e23.0 * rhs.y.0) * 2.0
+				+ E1(self.e13.0 * self.s.0 * rhs.z.0) * 2.0
+				+ E1(self.e23.0 * self.e23.0 * rhs.x.0)
+				+ E1(self.s.0 * self.s.0 * rhs.x.0),
+			y: -E2(self.e12.0 * self.e12.0 * rhs.y.0)
+				- E2(self.e12.0 * self.e13.0 * rhs.z.0) * 2.0
+				- E2(self.e12.0 * self.s.0 * rhs.x.0) * 2.0
+				+ E2(self.e13.0 * self.e13.0 * rhs.y.0)
+				- E2(self.e13.0 * self.e23.0 * rhs.x.0) * 2.0
+				- E2(self.e23.0 * self.e23.0 * rhs.y.0)
+				+ E2(self.e23.0 * self.s.0 * rhs.z.0) * 2.0
+				+ E2(self.s.0 * self.s.0 * rhs.y.0),
+			z: E3(self.e12.0 * self.e12.0 * rhs.z.0) - E3(self.e12.0 * self.e13.0 * rhs.y.0) * 2.0
+				+ E3(self.e12.0 * self.e23.0 * rhs.x.0) * 2.0
+				- E3(self.e13.0 * self.e13.0 * rhs.z.0)
+				- E3(self.e13.0 * self.s.0 * rhs.x.0) * 2.0
+				- E3(self.e23.0 * self.e23.0 * rhs.z.0)
+				- E3(self.e23.0 * self.s.0 * rhs.y.0) * 2.0
+				+ E3(self.s.0 * self.s.0 * rhs.z.0),
+		}
+	}
+}
+
+// Rotor.anti_sandwich(Vec3) -> Vec3
+impl AntiSandwich<Vec3> for Rotor {
+	fn anti_sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: E1(self.e12.0 * self.e12.0 * rhs.x.0) - E1(self.e12.0 * self.e23.0 * rhs.z.0) * 2.0
+				+ E1(self.e12.0 * self.s.0 * rhs.y.0) * 2.0
+				+ E1(self.e13.0 * self.e13.0 * rhs.x.0)
+				+ E1(self.e13.0 * self.e23.0 * rhs.y.0) * 2.0
+				+ E1(self.e13.0 * self.s.0 * rhs.z.0) * 2.0
+				- E1(self.e23.0 * self.e23.0 * rhs.x.0)
+				- E1(self.s.0 * self.s.0 * rhs.x.0),
+			y: E2(self.e12.0 * self.e12.0 * rhs.y.0) + E2(self.e12.0 * self.e13.0 * rhs.z.0) * 2.0
+				- E2(self.e12.0 * self.s.0 * rhs.x.0) * 2.0
+				- E2(self.e13.0 * self.e13.0 * rhs.y.0)
+				+ E2(self.e13.0 * self.e23.0 * rhs.x.0) * 2.0
+				+ E2(self.e23.0 * self.e23.0 * rhs.y.0)
+				+ E2(self.e23.0 * self.s.0 * rhs.z.0) * 2.0
+				- E2(self.s.0 * self.s.0 * rhs.y.0),
+			z: -E3(self.e12.0 * self.e12.0 * rhs.z.0) + E3(self.e12.0 * self.e13.0 * rhs.y.0) * 2.0
+				- E3(self.e12.0 * self.e23.0 * rhs.x.0) * 2.0
+				+ E3(self.e13.0 * self.e13.0 * rhs.z.0)
+				- E3(self.e13.0 * self.s.0 * rhs.x.0) * 2.0
+				+ E3(self.e23.0 * self.e23.0 * rhs.z.0)
+				- E3(self.e23.0 * self.s.0 * rhs.y.0) * 2.0
+				- E3(self.s.0 * self.s.0 * rhs.z.0),
+		}
+	}
+}
+
+// Rotor.sandwich(Point) -> Point
+impl Sandwich<Point> for Rotor {
+	fn sandwich(self, rhs: Point) -> Point {
+		Point {
+			x: -E1(self.e12.0 * self.e12.0 * rhs.x.0)
+				+ E1(self.e12.0 * self.e23.0 * rhs.z.0) * 2.0
+				+ E1(self.e12.0 * self.s.0 * rhs.y.0) * 2.0
+				- E1(self.e13.0 * self.e13.0 * rhs.x.0)
+				- E1(self.e13.0 * self.e23.0 * rhs.y.0) * 2.0
+				+ E1(self.e13.0 * self.s.0 * rhs.z.0) * 2.0
+				+ E1(self.e23.0 * self.e23.0 * rhs.x.0)
+				+ E1(self.s.0 * self.s.0 * rhs.x.0),
+			y: -E2(self.e12.0 * self.e12.0 * rhs.y.0)
+				- E2(self.e12.0 * self.e13.0 * rhs.z.0) * 2.0
+				- E2(self.e12.0 * self.s.0 * rhs.x.0) * 2.0
+				+ E2(self.e13.0 * self.e13.0 * rhs.y.0)
+				- E2(self.e13.0 * self.e23.0 * rhs.x.0) * 2.0
+				- E2(self.e23.0 * self.e23.0 * rhs.y.0)
+				+ E2(self.e23.0 * self.s.0 * rhs.z.0) * 2.0
+				+ E2(self.s.0 * self.s.0 * rhs.y.0),
+			z: E3(self.e12.0 * self.e12.0 * rhs.z.0) - E3(self.e12.0 * self.e13.0 * rhs.y.0) * 2.0
+				+ E3(self.e12.0 * self.e23.0 * rhs.x.0) * 2.0
+				- E3(self.e13.0 * self.e13.0 * rhs.z.0)
+				- E3(self.e13.0 * self.s.0 * rhs.x.0) * 2.0
+				- E3(self.e23.0 * self.e23.0 * rhs.z.0)
+				- E3(self.e23.0 * self.s.0 * rhs.y.0) * 2.0
+				+ E3(self.s.0 * self.s.0 * rhs.z.0),
+			o: Eo(self.e12.0 * self.e12.0 * rhs.o.0)
+				+ Eo(self.e13.0 * self.e13.0 * rhs.o.0)
+				+ Eo(self.e23.0 * self.e23.0 * rhs.o.0)
+				+ Eo(self.s.0 * self.s.0 * rhs.o.0),
+			inf: Ei(self.e12.0 * self.e12.0 * rhs.inf.0)
+				+ Ei(self.e13.0 * self.e13.0 * rhs.inf.0)
+				+ Ei(self.e23.0 * self.e23.0 * rhs.inf.0)
+				+ Ei(self.s.0 * self.s.0 * rhs.inf.0),
+		}
+	}
+}
+
+// Rotor.anti_sandwich(Point) -> Point
+impl AntiSandwich<Point> for Rotor {
+	fn anti_sandwich(self, rhs: Point) -> Point {
+		Point {
+			x: E1(self.e12.0 * self.e12.0 * rhs.x.0) - E1(self.e12.0 * self.e23.0 * rhs.z.0) * 2.0
+				+ E1(self.e12.0 * self.s.0 * rhs.y.0) * 2.0
+				+ E1(self.e13.0 * self.e13.0 * rhs.x.0)
+				+ E1(self.e13.0 * self.e23.0 * rhs.y.0) * 2.0
+				+ E1(self.e13.0 * self.s.0 * rhs.z.0) * 2.0
+				- E1(self.e23.0 * self.e23.0 * rhs.x.0)
+				- E1(self.s.0 * self.s.0 * rhs.x.0),
+			y: E2(self.e12.0 * self.e12.0 * rhs.y.0) + E2(self.e12.0 * self.e13.0 * rhs.z.0) * 2.0
+				- E2(self.e12.0 * self.s.0 * rhs.x.0) * 2.0
+				- E2(self.e13.0 * self.e13.0 * rhs.y.0)
+				+ E2(self.e13.0 * self.e23.0 * rhs.x.0) * 2.0
+				+ E2(self.e23.0 * self.e23.0 * rhs.y.0)
+				+ E2(self.e23.0 * self.s.0 * rhs.z.0) * 2.0
+				- E2(self.s.0 * self.s.0 * rhs.y.0),
+			z: -E3(self.e12.0 * self.e12.0 * rhs.z.0) + E3(self.e12.0 * self.e13.0 * rhs.y.0) * 2.0
+				- E3(self.e12.0 * self.e23.0 * rhs.x.0) * 2.0
+				+ E3(self.e13.0 * self.e13.0 * rhs.z.0)
+				- E3(self.e13.0 * self.s.0 * rhs.x.0) * 2.0
+				+ E3(self.e23.0 * self.e23.0 * rhs.z.0)
+				- E3(self.e23.0 * self.s.0 * rhs.y.0) * 2.0
+				- E3(self.s.0 * self.s.0 * rhs.z.0),
+			o: -Eo(self.e12.0 * self.e12.0 * rhs.o.0)
+				- Eo(self.e13.0 * self.e13.0 * rhs.o.0)
+				- Eo(self.e23.0 * self.e23.0 * rhs.o.0)
+				- Eo(self.s.0 * self.s.0 * rhs.o.0),
+			inf: -Ei(self.e12.0 * self.e12.0 * rhs.inf.0)
+				- Ei(self.e13.0 * self.e13.0 * rhs.inf.0)
+				- Ei(self.e23.0 * self.e23.0 * rhs.inf.0)
+				- Ei(self.s.0 * self.s.0 * rhs.inf.0),
+		}
+	}
+}
+
+// Rotor.sandwich(FlatPoint) -> FlatPoint
+impl Sandwich<FlatPoint> for Rotor {
+	fn sandwich(self, rhs: FlatPoint) -> FlatPoint {
+		FlatPoint {
+			e1i: -E1i(self.e12.0 * self.e12.0 * rhs.e1i.0)
+				+ E1i(self.e12.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e12.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E1i(self.e13.0 * self.e13.0 * rhs.e1i.0)
+				- E1i(self.e13.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e23.0 * self.e23.0 * rhs.e1i.0)
+				+ E1i(self.s.0 * self.s.0 * rhs.e1i.0),
+			e2i: -E2i(self.e12.0 * self.e12.0 * rhs.e2i.0)
+				- E2i(self.e12.0 * self.e13.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e12.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e13.0 * self.e13.0 * rhs.e2i.0)
+				- E2i(self.e13.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e23.0 * self.e23.0 * rhs.e2i.0)
+				+ E2i(self.e23.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.s.0 * self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.e12.0 * self.e12.0 * rhs.e3i.0) - E3i(self.e12.0 * self.e13.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.e12.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e13.0 * self.e13.0 * rhs.e3i.0)
+				- E3i(self.e13.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e23.0 * self.e23.0 * rhs.e3i.0)
+				- E3i(self.e23.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.s.0 * self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.e12.0 * self.e12.0 * rhs.eoi.0)
+				+ Eoi(self.e13.0 * self.e13.0 * rhs.eoi.0)
+				+ Eoi(self.e23.0 * self.e23.0 * rhs.eoi.0)
+				+ Eoi(self.s.0 * self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Rotor.anti_sandwich(FlatPoint) -> FlatPoint
+impl AntiSandwich<FlatPoint> for Rotor {
+	fn anti_sandwich(self, rhs: FlatPoint) -> FlatPoint {
+		FlatPoint {
+			e1i: E1i(self.e12.0 * self.e12.0 * rhs.e1i.0) - E1i(self.e12.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e12.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13.0 * self.e13.0 * rhs.e1i.0)
+				+ E1i(self.e13.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E1i(self.e23.0 * self.e23.0 * rhs.e1i.0)
+				- E1i(self.s.0 * self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.e12.0 * self.e12.0 * rhs.e2i.0) + E2i(self.e12.0 * self.e13.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e12.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e13.0 * self.e13.0 * rhs.e2i.0)
+				+ E2i(self.e13.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e23.0 * self.e23.0 * rhs.e2i.0)
+				+ E2i(self.e23.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E2i(self.s.0 * self.s.0 * rhs.e2i.0),
+			e3i: -E3i(self.e12.0 * self.e12.0 * rhs.e3i.0) + E3i(self.e12.0 * self.e13.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e12.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e13.0 * self.e13.0 * rhs.e3i.0)
+				- E3i(self.e13.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e23.0 * self.e23.0 * rhs.e3i.0)
+				- E3i(self.e23.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E3i(self.s.0 * self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * self.e12.0 * rhs.eoi.0)
+				- Eoi(self.e13.0 * self.e13.0 * rhs.eoi.0)
+				- Eoi(self.e23.0 * self.e23.0 * rhs.eoi.0)
+				- Eoi(self.s.0 * self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Rotor.sandwich(PointPair) -> PointPair
+impl Sandwich<PointPair> for Rotor {
+	fn sandwich(self, rhs: PointPair) -> PointPair {
+		PointPair {
+			e12: E12(self.e12.0 * self.e12.0 * rhs.e12.0)
+				+ E12(self.e12.0 * self.e13.0 * rhs.e13.0) * 2.0
+				+ E12(self.e12.0 * self.e23.0 * rhs.e23.0) * 2.0
+				- E12(self.e13.0 * self.e13.0 * rhs.e12.0)
+				- E12(self.e13.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E12(self.e23.0 * self.e23.0 * rhs.e12.0)
+				+ E12(self.e23.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E12(self.s.0 * self.s.0 * rhs.e12.0),
+			e13: -E13(self.e12.0 * self.e12.0 * rhs.e13.0)
+				+ E13(self.e12.0 * self.e13.0 * rhs.e12.0) * 2.0
+				+ E13(self.e12.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E13(self.e13.0 * self.e13.0 * rhs.e13.0)
+				+ E13(self.e13.0 * self.e23.0 * rhs.e23.0) * 2.0
+				- E13(self.e23.0 * self.e23.0 * rhs.e13.0)
+				- E13(self.e23.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E13(self.s.0 * self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e12.0 * self.e12.0 * rhs.e1o.0)
+				+ E1o(self.e12.0 * self.e23.0 * rhs.e3o.0) * 2.0
+				+ E1o(self.e12.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E1o(self.e13.0 * self.e13.0 * rhs.e1o.0)
+				- E1o(self.e13.0 * self.e23.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e13.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ E1o(self.e23.0 * self.e23.0 * rhs.e1o.0)
+				+ E1o(self.s.0 * self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e12.0 * self.e12.0 * rhs.e1i.0)
+				+ E1i(self.e12.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e12.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E1i(self.e13.0 * self.e13.0 * rhs.e1i.0)
+				- E1i(self.e13.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e23.0 * self.e23.0 * rhs.e1i.0)
+				+ E1i(self.s.0 * self.s.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * self.e12.0 * rhs.e23.0) + E23(self.e12.0 * self.e23.0 * rhs.e12.0) * 2.0
+				- E23(self.e12.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E23(self.e13.0 * self.e13.0 * rhs.e23.0)
+				+ E23(self.e13.0 * self.e23.0 * rhs.e13.0) * 2.0
+				+ E23(self.e13.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E23(self.e23.0 * self.e23.0 * rhs.e23.0)
+				+ E23(self.s.0 * self.s.0 * rhs.e23.0),
+			e2o: -E2o(self.e12.0 * self.e12.0 * rhs.e2o.0)
+				- E2o(self.e12.0 * self.e13.0 * rhs.e3o.0) * 2.0
+				- E2o(self.e12.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E2o(self.e13.0 * self.e13.0 * rhs.e2o.0)
+				- E2o(self.e13.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e23.0 * self.e23.0 * rhs.e2o.0)
+				+ E2o(self.e23.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.s.0 * self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * self.e12.0 * rhs.e2i.0)
+				- E2i(self.e12.0 * self.e13.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e12.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e13.0 * self.e13.0 * rhs.e2i.0)
+				- E2i(self.e13.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e23.0 * self.e23.0 * rhs.e2i.0)
+				+ E2i(self.e23.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.s.0 * self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.e12.0 * self.e12.0 * rhs.e3o.0) - E3o(self.e12.0 * self.e13.0 * rhs.e2o.0) * 2.0
+				+ E3o(self.e12.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e13.0 * self.e13.0 * rhs.e3o.0)
+				- E3o(self.e13.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e23.0 * self.e23.0 * rhs.e3o.0)
+				- E3o(self.e23.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E3o(self.s.0 * self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.e12.0 * self.e12.0 * rhs.e3i.0) - E3i(self.e12.0 * self.e13.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.e12.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e13.0 * self.e13.0 * rhs.e3i.0)
+				- E3i(self.e13.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e23.0 * self.e23.0 * rhs.e3i.0)
+				- E3i(self.e23.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.s.0 * self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.e12.0 * self.e12.0 * rhs.eoi.0)
+				+ Eoi(self.e13.0 * self.e13.0 * rhs.eoi.0)
+				+ Eoi(self.e23.0 * self.e23.0 * rhs.eoi.0)
+				+ Eoi(self.s.0 * self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Rotor.anti_sandwich(PointPair) -> PointPair
+impl AntiSandwich<PointPair> for Rotor {
+	fn anti_sandwich(self, rhs: PointPair) -> PointPair {
+		PointPair {
+			e12: -E12(self.e12.0 * self.e12.0 * rhs.e12.0)
+				- E12(self.e12.0 * self.e13.0 * rhs.e13.0) * 2.0
+				- E12(self.e12.0 * self.e23.0 * rhs.e23.0) * 2.0
+				+ E12(self.e13.0 * self.e13.0 * rhs.e12.0)
+				- E12(self.e13.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E12(self.e23.0 * self.e23.0 * rhs.e12.0)
+				+ E12(self.e23.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E12(self.s.0 * self.s.0 * rhs.e12.0),
+			e13: E13(self.e12.0 * self.e12.0 * rhs.e13.0) - E13(self.e12.0 * self.e13.0 * rhs.e12.0) * 2.0
+				+ E13(self.e12.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E13(self.e13.0 * self.e13.0 * rhs.e13.0)
+				- E13(self.e13.0 * self.e23.0 * rhs.e23.0) * 2.0
+				+ E13(self.e23.0 * self.e23.0 * rhs.e13.0)
+				- E13(self.e23.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E13(self.s.0 * self.s.0 * rhs.e13.0),
+			e1o: E1o(self.e12.0 * self.e12.0 * rhs.e1o.0) - E1o(self.e12.0 * self.e23.0 * rhs.e3o.0) * 2.0
+				+ E1o(self.e12.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e13.0 * self.e13.0 * rhs.e1o.0)
+				+ E1o(self.e13.0 * self.e23.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e13.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E1o(self.e23.0 * self.e23.0 * rhs.e1o.0)
+				- E1o(self.s.0 * self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * self.e12.0 * rhs.e1i.0) - E1i(self.e12.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e12.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13.0 * self.e13.0 * rhs.e1i.0)
+				+ E1i(self.e13.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E1i(self.e23.0 * self.e23.0 * rhs.e1i.0)
+				- E1i(self.s.0 * self.s.0 * rhs.e1i.0),
+			e23: E23(self.e12.0 * self.e12.0 * rhs.e23.0)
+				- E23(self.e12.0 * self.e23.0 * rhs.e12.0) * 2.0
+				- E23(self.e12.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E23(self.e13.0 * self.e13.0 * rhs.e23.0)
+				- E23(self.e13.0 * self.e23.0 * rhs.e13.0) * 2.0
+				+ E23(self.e13.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E23(self.e23.0 * self.e23.0 * rhs.e23.0)
+				- E23(self.s.0 * self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e12.0 * self.e12.0 * rhs.e2o.0) + E2o(self.e12.0 * self.e13.0 * rhs.e3o.0) * 2.0
+				- E2o(self.e12.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e13.0 * self.e13.0 * rhs.e2o.0)
+				+ E2o(self.e13.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				+ E2o(self.e23.0 * self.e23.0 * rhs.e2o.0)
+				+ E2o(self.e23.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E2o(self.s.0 * self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e12.0 * self.e12.0 * rhs.e2i.0) + E2i(self.e12.0 * self.e13.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e12.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e13.0 * self.e13.0 * rhs.e2i.0)
+				+ E2i(self.e13.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e23.0 * self.e23.0 * rhs.e2i.0)
+				+ E2i(self.e23.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E2i(self.s.0 * self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * self.e12.0 * rhs.e3o.0) + E3o(self.e12.0 * self.e13.0 * rhs.e2o.0) * 2.0
+				- E3o(self.e12.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				+ E3o(self.e13.0 * self.e13.0 * rhs.e3o.0)
+				- E3o(self.e13.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E3o(self.e23.0 * self.e23.0 * rhs.e3o.0)
+				- E3o(self.e23.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E3o(self.s.0 * self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * self.e12.0 * rhs.e3i.0) + E3i(self.e12.0 * self.e13.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e12.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e13.0 * self.e13.0 * rhs.e3i.0)
+				- E3i(self.e13.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e23.0 * self.e23.0 * rhs.e3i.0)
+				- E3i(self.e23.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E3i(self.s.0 * self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * self.e12.0 * rhs.eoi.0)
+				- Eoi(self.e13.0 * self.e13.0 * rhs.eoi.0)
+				- Eoi(self.e23.0 * self.e23.0 * rhs.eoi.0)
+				- Eoi(self.s.0 * self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Rotor.sandwich(Line) -> Line
+impl Sandwich<Line> for Rotor {
+	fn sandwich(self, rhs: Line) -> Line {
+		Line {
+			e12i: E12i(self.e12.0 * self.e12.0 * rhs.e12i.0)
+				+ E12i(self.e12.0 * self.e13.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e12.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e13.0 * self.e13.0 * rhs.e12i.0)
+				- E12i(self.e13.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e23.0 * self.e23.0 * rhs.e12i.0)
+				+ E12i(self.e23.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.s.0 * self.s.0 * rhs.e12i.0),
+			e13i: -E13i(self.e12.0 * self.e12.0 * rhs.e13i.0)
+				+ E13i(self.e12.0 * self.e13.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e12.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e13.0 * self.e13.0 * rhs.e13i.0)
+				+ E13i(self.e13.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e23.0 * self.e23.0 * rhs.e13i.0)
+				- E13i(self.e23.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.s.0 * self.s.0 * rhs.e13i.0),
+			e1oi: -E1oi(self.e12.0 * self.e12.0 * rhs.e1oi.0)
+				+ E1oi(self.e12.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e12.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e13.0 * self.e13.0 * rhs.e1oi.0)
+				- E1oi(self.e13.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e13.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e23.0 * self.e23.0 * rhs.e1oi.0)
+				+ E1oi(self.s.0 * self.s.0 * rhs.e1oi.0),
+			e23i: -E23i(self.e12.0 * self.e12.0 * rhs.e23i.0) + E23i(self.e12.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e12.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e13.0 * self.e13.0 * rhs.e23i.0)
+				+ E23i(self.e13.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e23.0 * self.e23.0 * rhs.e23i.0)
+				+ E23i(self.s.0 * self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * self.e12.0 * rhs.e2oi.0)
+				- E2oi(self.e12.0 * self.e13.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e12.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e13.0 * self.e13.0 * rhs.e2oi.0)
+				- E2oi(self.e13.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				- E2oi(self.e23.0 * self.e23.0 * rhs.e2oi.0)
+				+ E2oi(self.e23.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E2oi(self.s.0 * self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.e12.0 * self.e12.0 * rhs.e3oi.0) - E3oi(self.e12.0 * self.e13.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e12.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e13.0 * self.e13.0 * rhs.e3oi.0)
+				- E3oi(self.e13.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e23.0 * self.e23.0 * rhs.e3oi.0)
+				- E3oi(self.e23.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.s.0 * self.s.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Rotor.anti_sandwich(Line) -> Line
+impl AntiSandwich<Line> for Rotor {
+	fn anti_sandwich(self, rhs: Line) -> Line {
+		Line {
+			e12i: -E12i(self.e12.0 * self.e12.0 * rhs.e12i.0)
+				- E12i(self.e12.0 * self.e13.0 * rhs.e13i.0) * 2.0
+				- E12i(self.e12.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e13.0 * self.e13.0 * rhs.e12i.0)
+				- E12i(self.e13.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e23.0 * self.e23.0 * rhs.e12i.0)
+				+ E12i(self.e23.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E12i(self.s.0 * self.s.0 * rhs.e12i.0),
+			e13i: E13i(self.e12.0 * self.e12.0 * rhs.e13i.0) - E13i(self.e12.0 * self.e13.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e12.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e13.0 * self.e13.0 * rhs.e13i.0)
+				- E13i(self.e13.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e23.0 * self.e23.0 * rhs.e13i.0)
+				- E13i(self.e23.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E13i(self.s.0 * self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * self.e12.0 * rhs.e1oi.0) - E1oi(self.e12.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e12.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e13.0 * self.e13.0 * rhs.e1oi.0)
+				+ E1oi(self.e13.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e13.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E1oi(self.e23.0 * self.e23.0 * rhs.e1oi.0)
+				- E1oi(self.s.0 * self.s.0 * rhs.e1oi.0),
+			e23i: E23i(self.e12.0 * self.e12.0 * rhs.e23i.0)
+				- E23i(self.e12.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e12.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13.0 * self.e13.0 * rhs.e23i.0)
+				- E23i(self.e13.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e23.0 * self.e23.0 * rhs.e23i.0)
+				- E23i(self.s.0 * self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.e12.0 * self.e12.0 * rhs.e2oi.0) + E2oi(self.e12.0 * self.e13.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e12.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E2oi(self.e13.0 * self.e13.0 * rhs.e2oi.0)
+				+ E2oi(self.e13.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e23.0 * self.e23.0 * rhs.e2oi.0)
+				+ E2oi(self.e23.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.s.0 * self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e12.0 * self.e12.0 * rhs.e3oi.0) + E3oi(self.e12.0 * self.e13.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.e12.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e13.0 * self.e13.0 * rhs.e3oi.0)
+				- E3oi(self.e13.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e23.0 * self.e23.0 * rhs.e3oi.0)
+				- E3oi(self.e23.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.s.0 * self.s.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Rotor.sandwich(Circle) -> Circle
+impl Sandwich<Circle> for Rotor {
+	fn sandwich(self, rhs: Circle) -> Circle {
+		Circle {
+			e123: E123(self.e12.0 * self.e12.0 * rhs.e123.0)
+				+ E123(self.e13.0 * self.e13.0 * rhs.e123.0)
+				+ E123(self.e23.0 * self.e23.0 * rhs.e123.0)
+				+ E123(self.s.0 * self.s.0 * rhs.e123.0),
+			e12o: E12o(self.e12.0 * self.e12.0 * rhs.e12o.0)
+				+ E12o(self.e12.0 * self.e13.0 * rhs.e13o.0) * 2.0
+				+ E12o(self.e12.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e13.0 * self.e13.0 * rhs.e12o.0)
+				- E12o(self.e13.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e23.0 * self.e23.0 * rhs.e12o.0)
+				+ E12o(self.e23.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E12o(self.s.0 * self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e12.0 * self.e12.0 * rhs.e12i.0)
+				+ E12i(self.e12.0 * self.e13.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e12.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e13.0 * self.e13.0 * rhs.e12i.0)
+				- E12i(self.e13.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e23.0 * self.e23.0 * rhs.e12i.0)
+				+ E12i(self.e23.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.s.0 * self.s.0 * rhs.e12i.0),
+			e13o: -E13o(self.e12.0 * self.e12.0 * rhs.e13o.0)
+				+ E13o(self.e12.0 * self.e13.0 * rhs.e12o.0) * 2.0
+				+ E13o(self.e12.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E13o(self.e13.0 * self.e13.0 * rhs.e13o.0)
+				+ E13o(self.e13.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e23.0 * self.e23.0 * rhs.e13o.0)
+				- E13o(self.e23.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E13o(self.s.0 * self.s.0 * rhs.e13o.0),
+			e13i: -E13i(self.e12.0 * self.e12.0 * rhs.e13i.0)
+				+ E13i(self.e12.0 * self.e13.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e12.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e13.0 * self.e13.0 * rhs.e13i.0)
+				+ E13i(self.e13.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e23.0 * self.e23.0 * rhs.e13i.0)
+				- E13i(self.e23.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.s.0 * self.s.0 * rhs.e13i.0),
+			e1oi: -E1oi(self.e12.0 * self.e12.0 * rhs.e1oi.0)
+				+ E1oi(self.e12.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e12.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e13.0 * self.e13.0 * rhs.e1oi.0)
+				- E1oi(self.e13.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e13.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e23.0 * self.e23.0 * rhs.e1oi.0)
+				+ E1oi(self.s.0 * self.s.0 * rhs.e1oi.0),
+			e23o: -E23o(self.e12.0 * self.e12.0 * rhs.e23o.0) + E23o(self.e12.0 * self.e23.0 * rhs.e12o.0) * 2.0
+				- E23o(self.e12.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e13.0 * self.e13.0 * rhs.e23o.0)
+				+ E23o(self.e13.0 * self.e23.0 * rhs.e13o.0) * 2.0
+				+ E23o(self.e13.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E23o(self.e23.0 * self.e23.0 * rhs.e23o.0)
+				+ E23o(self.s.0 * self.s.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * self.e12.0 * rhs.e23i.0) + E23i(self.e12.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e12.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e13.0 * self.e13.0 * rhs.e23i.0)
+				+ E23i(self.e13.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e23.0 * self.e23.0 * rhs.e23i.0)
+				+ E23i(self.s.0 * self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * self.e12.0 * rhs.e2oi.0)
+				- E2oi(self.e12.0 * self.e13.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e12.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e13.0 * self.e13.0 * rhs.e2oi.0)
+				- E2oi(self.e13.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				- E2oi(self.e23.0 * self.e23.0 * rhs.e2oi.0)
+				+ E2oi(self.e23.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E2oi(self.s.0 * self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.e12.0 * self.e12.0 * rhs.e3oi.0) - E3oi(self.e12.0 * self.e13.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e12.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e13.0 * self.e13.0 * rhs.e3oi.0)
+				- E3oi(self.e13.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e23.0 * self.e23.0 * rhs.e3oi.0)
+				- E3oi(self.e23.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.s.0 * self.s.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Rotor.anti_sandwich(Circle) -> Circle
+impl AntiSandwich<Circle> for Rotor {
+	fn anti_sandwich(self, rhs: Circle) -> Circle {
+		Circle {
+			e123: -E123(self.e12.0 * self.e12.0 * rhs.e123.0)
+				- E123(self.e13.0 * self.e13.0 * rhs.e123.0)
+				- E123(self.e23.0 * self.e23.0 * rhs.e123.0)
+				- E123(self.s.0 * self.s.0 * rhs.e123.0),
+			e12o: -E12o(self.e12.0 * self.e12.0 * rhs.e12o.0)
+				- E12o(self.e12.0 * self.e13.0 * rhs.e13o.0) * 2.0
+				- E12o(self.e12.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				+ E12o(self.e13.0 * self.e13.0 * rhs.e12o.0)
+				- E12o(self.e13.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E12o(self.e23.0 * self.e23.0 * rhs.e12o.0)
+				+ E12o(self.e23.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E12o(self.s.0 * self.s.0 * rhs.e12o.0),
+			e12i: -E12i(self.e12.0 * self.e12.0 * rhs.e12i.0)
+				- E12i(self.e12.0 * self.e13.0 * rhs.e13i.0) * 2.0
+				- E12i(self.e12.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e13.0 * self.e13.0 * rhs.e12i.0)
+				- E12i(self.e13.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e23.0 * self.e23.0 * rhs.e12i.0)
+				+ E12i(self.e23.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E12i(self.s.0 * self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * self.e12.0 * rhs.e13o.0) - E13o(self.e12.0 * self.e13.0 * rhs.e12o.0) * 2.0
+				+ E13o(self.e12.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e13.0 * self.e13.0 * rhs.e13o.0)
+				- E13o(self.e13.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				+ E13o(self.e23.0 * self.e23.0 * rhs.e13o.0)
+				- E13o(self.e23.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E13o(self.s.0 * self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * self.e12.0 * rhs.e13i.0) - E13i(self.e12.0 * self.e13.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e12.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e13.0 * self.e13.0 * rhs.e13i.0)
+				- E13i(self.e13.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e23.0 * self.e23.0 * rhs.e13i.0)
+				- E13i(self.e23.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E13i(self.s.0 * self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * self.e12.0 * rhs.e1oi.0) - E1oi(self.e12.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e12.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e13.0 * self.e13.0 * rhs.e1oi.0)
+				+ E1oi(self.e13.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e13.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E1oi(self.e23.0 * self.e23.0 * rhs.e1oi.0)
+				- E1oi(self.s.0 * self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.e12.0 * self.e12.0 * rhs.e23o.0)
+				- E23o(self.e12.0 * self.e23.0 * rhs.e12o.0) * 2.0
+				- E23o(self.e12.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E23o(self.e13.0 * self.e13.0 * rhs.e23o.0)
+				- E23o(self.e13.0 * self.e23.0 * rhs.e13o.0) * 2.0
+				+ E23o(self.e13.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E23o(self.e23.0 * self.e23.0 * rhs.e23o.0)
+				- E23o(self.s.0 * self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.e12.0 * self.e12.0 * rhs.e23i.0)
+				- E23i(self.e12.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e12.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13.0 * self.e13.0 * rhs.e23i.0)
+				- E23i(self.e13.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e23.0 * self.e23.0 * rhs.e23i.0)
+				- E23i(self.s.0 * self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.e12.0 * self.e12.0 * rhs.e2oi.0) + E2oi(self.e12.0 * self.e13.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e12.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E2oi(self.e13.0 * self.e13.0 * rhs.e2oi.0)
+				+ E2oi(self.e13.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e23.0 * self.e23.0 * rhs.e2oi.0)
+				+ E2oi(self.e23.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.s.0 * self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e12.0 * self.e12.0 * rhs.e3oi.0) + E3oi(self.e12.0 * self.e13.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.e12.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e13.0 * self.e13.0 * rhs.e3oi.0)
+				- E3oi(self.e13.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e23.0 * self.e23.0 * rhs.e3oi.0)
+				- E3oi(self.e23.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.s.0 * self.s.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Rotor.sandwich(Plane) -> Plane
+impl Sandwich<Plane> for Rotor {
+	fn sandwich(self, rhs: Plane) -> Plane {
+		Plane {
+			e123i: E123i(self.e12.0 * self.e12.0 * rhs.e123i.0)
+				+ E123i(self.e13.0 * self.e13.0 * rhs.e123i.0)
+				+ E123i(self.e23.0 * self.e23.0 * rhs.e123i.0)
+				+ E123i(self.s.0 * self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e12.0 * self.e12.0 * rhs.e12oi.0)
+				+ E12oi(self.e12.0 * self.e13.0 * rhs.e13oi.0) * 2.0
+				+ E12oi(self.e12.0 * self.e23.0 * rhs.e23oi.0) * 2.0
+				- E12oi(self.e13.0 * self.e13.0 * rhs.e12oi.0)
+				- E12oi(self.e13.0 * self.s.0 * rhs.e23oi.0) * 2.0
+				- E12oi(self.e23.0 * self.e23.0 * rhs.e12oi.0)
+				+ E12oi(self.e23.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				+ E12oi(self.s.0 * self.s.0 * rhs.e12oi.0),
+			e13oi: -E13oi(self.e12.0 * self.e12.0 * rhs.e13oi.0)
+				+ E13oi(self.e12.0 * self.e13.0 * rhs.e12oi.0) * 2.0
+				+ E13oi(self.e12.0 * self.s.0 * rhs.e23oi.0) * 2.0
+				+ E13oi(self.e13.0 * self.e13.0 * rhs.e13oi.0)
+				+ E13oi(self.e13.0 * self.e23.0 * rhs.e23oi.0) * 2.0
+				- E13oi(self.e23.0 * self.e23.0 * rhs.e13oi.0)
+				- E13oi(self.e23.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				+ E13oi(self.s.0 * self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.e12.0 * self.e12.0 * rhs.e23oi.0) + E23oi(self.e12.0 * self.e23.0 * rhs.e12oi.0) * 2.0
+				- E23oi(self.e12.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				- E23oi(self.e13.0 * self.e13.0 * rhs.e23oi.0)
+				+ E23oi(self.e13.0 * self.e23.0 * rhs.e13oi.0) * 2.0
+				+ E23oi(self.e13.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				+ E23oi(self.e23.0 * self.e23.0 * rhs.e23oi.0)
+				+ E23oi(self.s.0 * self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Rotor.anti_sandwich(Plane) -> Plane
+impl AntiSandwich<Plane> for Rotor {
+	fn anti_sandwich(self, rhs: Plane) -> Plane {
+		Plane {
+			e123i: -E123i(self.e12.0 * self.e12.0 * rhs.e123i.0)
+				- E123i(self.e13.0 * self.e13.0 * rhs.e123i.0)
+				- E123i(self.e23.0 * self.e23.0 * rhs.e123i.0)
+				- E123i(self.s.0 * self.s.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e12.0 * self.e12.0 * rhs.e12oi.0)
+				- E12oi(self.e12.0 * self.e13.0 * rhs.e13oi.0) * 2.0
+				- E12oi(self.e12.0 * self.e23.0 * rhs.e23oi.0) * 2.0
+				+ E12oi(self.e13.0 * self.e13.0 * rhs.e12oi.0)
+				- E12oi(self.e13.0 * self.s.0 * rhs.e23oi.0) * 2.0
+				+ E12oi(self.e23.0 * self.e23.0 * rhs.e12oi.0)
+				+ E12oi(self.e23.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				- E12oi(self.s.0 * self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e12.0 * self.e12.0 * rhs.e13oi.0) - E13oi(self.e12.0 * self.e13.0 * rhs.e12oi.0) * 2.0
+				+ E13oi(self.e12.0 * self.s.0 * rhs.e23oi.0) * 2.0
+				- E13oi(self.e13.0 * self.e13.0 * rhs.e13oi.0)
+				- E13oi(self.e13.0 * self.e23.0 * rhs.e23oi.0) * 2.0
+				+ E13oi(self.e23.0 * self.e23.0 * rhs.e13oi.0)
+				- E13oi(self.e23.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				- E13oi(self.s.0 * self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.e12.0 * self.e12.0 * rhs.e23oi.0)
+				- E23oi(self.e12.0 * self.e23.0 * rhs.e12oi.0) * 2.0
+				- E23oi(self.e12.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				+ E23oi(self.e13.0 * self.e13.0 * rhs.e23oi.0)
+				- E23oi(self.e13.0 * self.e23.0 * rhs.e13oi.0) * 2.0
+				+ E23oi(self.e13.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				- E23oi(self.e23.0 * self.e23.0 * rhs.e23oi.0)
+				- E23oi(self.s.0 * self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Rotor.sandwich(Sphere) -> Sphere
+impl Sandwich<Sphere> for Rotor {
+	fn sandwich(self, rhs: Sphere) -> Sphere {
+		Sphere {
+			e123o: E123o(self.e12.0 * self.e12.0 * rhs.e123o.0)
+				+ E123o(self.e13.0 * self.e13.0 * rhs.e123o.0)
+				+ E123o(self.e23.0 * self.e23.0 * rhs.e123o.0)
+				+ E123o(self.s.0 * self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e12.0 * self.e12.0 * rhs.e123i.0)
+				+ E123i(self.e13.0 * self.e13.0 * rhs.e123i.0)
+				+ E123i(self.e23.0 * self.e23.0 * rhs.e123i.0)
+				+ E123i(self.s.0 * self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e12.0 * self.e12.0 * rhs.e12oi.0)
+				+ E12oi(self.e12.0 * self.e13.0 * rhs.e13oi.0) * 2.0
+				+ E12oi(self.e12.0 * self.e23.0 * rhs.e23oi.0) * 2.0
+				- E12oi(self.e13.0 * self.e13.0 * rhs.e12oi.0)
+				- E12oi(self.e13.0 * self.s.0 * rhs.e23oi.0) * 2.0
+				- E12oi(self.e23.0 * self.e23.0 * rhs.e12oi.0)
+				+ E12oi(self.e23.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				+ E12oi(self.s.0 * self.s.0 * rhs.e12oi.0),
+			e13oi: -E13oi(self.e12.0 * self.e12.0 * rhs.e13oi.0)
+				+ E13oi(self.e12.0 * self.e13.0 * rhs.e12oi.0) * 2.0
+				+ E13oi(self.e12.0 * self.s.0 * rhs.e23oi.0) * 2.0
+				+ E13oi(self.e13.0 * self.e13.0 * rhs.e13oi.0)
+				+ E13oi(self.e13.0 * self.e23.0 * rhs.e23oi.0) * 2.0
+				- E13oi(self.e23.0 * self.e23.0 * rhs.e13oi.0)
+				- E13oi(self.e23.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				+ E13oi(self.s.0 * self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.e12.0 * self.e12.0 * rhs.e23oi.0) + E23oi(self.e12.0 * self.e23.0 * rhs.e12oi.0) * 2.0
+				- E23oi(self.e12.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				- E23oi(self.e13.0 * self.e13.0 * rhs.e23oi.0)
+				+ E23oi(self.e13.0 * self.e23.0 * rhs.e13oi.0) * 2.0
+				+ E23oi(self.e13.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				+ E23oi(self.e23.0 * self.e23.0 * rhs.e23oi.0)
+				+ E23oi(self.s.0 * self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Rotor.anti_sandwich(Sphere) -> Sphere
+impl AntiSandwich<Sphere> for Rotor {
+	fn anti_sandwich(self, rhs: Sphere) -> Sphere {
+		Sphere {
+			e123o: -E123o(self.e12.0 * self.e12.0 * rhs.e123o.0)
+				- E123o(self.e13.0 * self.e13.0 * rhs.e123o.0)
+				- E123o(self.e23.0 * self.e23.0 * rhs.e123o.0)
+				- E123o(self.s.0 * self.s.0 * rhs.e123o.0),
+			e123i: -E123i(self.e12.0 * self.e12.0 * rhs.e123i.0)
+				- E123i(self.e13.0 * self.e13.0 * rhs.e123i.0)
+				- E123i(self.e23.0 * self.e23.0 * rhs.e123i.0)
+				- E123i(self.s.0 * self.s.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e12.0 * self.e12.0 * rhs.e12oi.0)
+				- E12oi(self.e12.0 * self.e13.0 * rhs.e13oi.0) * 2.0
+				- E12oi(self.e12.0 * self.e23.0 * rhs.e23oi.0) * 2.0
+				+ E12oi(self.e13.0 * self.e13.0 * rhs.e12oi.0)
+				- E12oi(self.e13.0 * self.s.0 * rhs.e23oi.0) * 2.0
+				+ E12oi(self.e23.0 * self.e23.0 * rhs.e12oi.0)
+				+ E12oi(self.e23.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				- E12oi(self.s.0 * self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e12.0 * self.e12.0 * rhs.e13oi.0) - E13oi(self.e12.0 * self.e13.0 * rhs.e12oi.0) * 2.0
+				+ E13oi(self.e12.0 * self.s.0 * rhs.e23oi.0) * 2.0
+				- E13oi(self.e13.0 * self.e13.0 * rhs.e13oi.0)
+				- E13oi(self.e13.0 * self.e23.0 * rhs.e23oi.0) * 2.0
+				+ E13oi(self.e23.0 * self.e23.0 * rhs.e13oi.0)
+				- E13oi(self.e23.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				- E13oi(self.s.0 * self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.e12.0 * self.e12.0 * rhs.e23oi.0)
+				- E23oi(self.e12.0 * self.e23.0 * rhs.e12oi.0) * 2.0
+				- E23oi(self.e12.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				+ E23oi(self.e13.0 * self.e13.0 * rhs.e23oi.0)
+				- E23oi(self.e13.0 * self.e23.0 * rhs.e13oi.0) * 2.0
+				+ E23oi(self.e13.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				- E23oi(self.e23.0 * self.e23.0 * rhs.e23oi.0)
+				- E23oi(self.s.0 * self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
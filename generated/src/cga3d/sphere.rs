@@ -0,0 +1,5296 @@
+//! # Sphere
+//!
+//! ## Operations
+//! ```text
+//! Sphere.geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(Sphere) -> Multivector
+//! Sphere.dot(Sphere) -> S
+//! Sphere.anti_wedge(Sphere) -> Circle
+//! Sphere.left_contraction(Sphere) -> S
+//! Sphere.right_contraction(Sphere) -> S
+//! Sphere.scalar_product(Sphere) -> S
+//! Sphere.commutator(Sphere) -> PointPair
+//! Sphere.anti_dot(Sphere) -> E123oi
+//! Sphere.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(Vec3) -> Versor
+//! Vec3.anti_geometric(Sphere) -> Versor
+//! Sphere.dot(Vec3) -> Circle
+//! Vec3.dot(Sphere) -> Circle
+//! Sphere.wedge(Vec3) -> E123oi
+//! Vec3.wedge(Sphere) -> E123oi
+//! Sphere.anti_wedge(Vec3) -> S
+//! Vec3.anti_wedge(Sphere) -> S
+//! Vec3.left_contraction(Sphere) -> Circle
+//! Sphere.right_contraction(Vec3) -> Circle
+//! Sphere.commutator(Vec3) -> Circle
+//! Vec3.commutator(Sphere) -> Circle
+//! Sphere.anti_dot(Vec3) -> PointPair
+//! Vec3.anti_dot(Sphere) -> PointPair
+//! Sphere.geometric(Point) -> Multivector
+//! Point.geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(Point) -> Versor
+//! Point.anti_geometric(Sphere) -> Versor
+//! Sphere.dot(Point) -> Circle
+//! Point.dot(Sphere) -> Circle
+//! Sphere.wedge(Point) -> E123oi
+//! Point.wedge(Sphere) -> E123oi
+//! Sphere.anti_wedge(Point) -> S
+//! Point.anti_wedge(Sphere) -> S
+//! Point.left_contraction(Sphere) -> Circle
+//! Sphere.right_contraction(Point) -> Circle
+//! Sphere.commutator(Point) -> Circle
+//! Point.commutator(Sphere) -> Circle
+//! Sphere.anti_dot(Point) -> PointPair
+//! Point.anti_dot(Sphere) -> PointPair
+//! Sphere.geometric(FlatPoint) -> Versor
+//! FlatPoint.geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.anti_geometric(Sphere) -> Multivector
+//! Sphere.dot(FlatPoint) -> PointPair
+//! FlatPoint.dot(Sphere) -> PointPair
+//! Sphere.anti_wedge(FlatPoint) -> Point
+//! FlatPoint.anti_wedge(Sphere) -> Point
+//! FlatPoint.left_contraction(Sphere) -> PointPair
+//! Sphere.right_contraction(FlatPoint) -> PointPair
+//! Sphere.commutator(FlatPoint) -> Sphere
+//! FlatPoint.commutator(Sphere) -> Sphere
+//! Sphere.anti_dot(FlatPoint) -> Line
+//! FlatPoint.anti_dot(Sphere) -> Line
+//! Sphere.geometric(PointPair) -> Versor
+//! PointPair.geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(Sphere) -> Multivector
+//! Sphere.dot(PointPair) -> PointPair
+//! PointPair.dot(Sphere) -> PointPair
+//! Sphere.anti_wedge(PointPair) -> Point
+//! PointPair.anti_wedge(Sphere) -> Point
+//! PointPair.left_contraction(Sphere) -> PointPair
+//! Sphere.right_contraction(PointPair) -> PointPair
+//! Sphere.commutator(PointPair) -> Sphere
+//! PointPair.commutator(Sphere) -> Sphere
+//! Sphere.anti_dot(PointPair) -> Circle
+//! PointPair.anti_dot(Sphere) -> Circle
+//! Sphere.geometric(Line) -> Multivector
+//! Line.geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(Line) -> Versor
+//! Line.anti_geometric(Sphere) -> Versor
+//! Sphere.dot(Line) -> Point
+//! Line.dot(Sphere) -> Point
+//! Sphere.anti_wedge(Line) -> PointPair
+//! Line.anti_wedge(Sphere) -> PointPair
+//! Line.left_contraction(Sphere) -> Point
+//! Sphere.right_contraction(Line) -> Point
+//! Sphere.commutator(Line) -> Point
+//! Line.commutator(Sphere) -> Point
+//! Sphere.anti_dot(Line) -> Plane
+//! Line.anti_dot(Sphere) -> Plane
+//! Sphere.geometric(Circle) -> Multivector
+//! Circle.geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(Circle) -> Versor
+//! Circle.anti_geometric(Sphere) -> Versor
+//! Sphere.dot(Circle) -> Point
+//! Circle.dot(Sphere) -> Point
+//! Sphere.anti_wedge(Circle) -> PointPair
+//! Circle.anti_wedge(Sphere) -> PointPair
+//! Circle.left_contraction(Sphere) -> Point
+//! Sphere.right_contraction(Circle) -> Point
+//! Sphere.commutator(Circle) -> Point
+//! Circle.commutator(Sphere) -> Point
+//! Sphere.anti_dot(Circle) -> Sphere
+//! Circle.anti_dot(Sphere) -> Sphere
+//! Sphere.geometric(Plane) -> Versor
+//! Plane.geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Sphere) -> Multivector
+//! Sphere.dot(Plane) -> S
+//! Plane.dot(Sphere) -> S
+//! Sphere.anti_wedge(Plane) -> Circle
+//! Plane.anti_wedge(Sphere) -> Circle
+//! Sphere.left_contraction(Plane) -> S
+//! Plane.left_contraction(Sphere) -> S
+//! Sphere.right_contraction(Plane) -> S
+//! Plane.right_contraction(Sphere) -> S
+//! Sphere.scalar_product(Plane) -> S
+//! Plane.scalar_product(Sphere) -> S
+//! Sphere.commutator(Plane) -> PointPair
+//! Plane.commutator(Sphere) -> PointPair
+//! Sphere.anti_dot(Plane) -> E123oi
+//! Plane.anti_dot(Sphere) -> E123oi
+//! Sphere.geometric(Rotor) -> Versor
+//! Rotor.geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Sphere) -> Multivector
+//! Sphere.dot(Rotor) -> Versor
+//! Rotor.dot(Sphere) -> Versor
+//! Sphere.wedge(Rotor) -> Sphere
+//! Rotor.wedge(Sphere) -> Sphere
+//! Sphere.anti_wedge(Rotor) -> Vec3
+//! Rotor.anti_wedge(Sphere) -> Vec3
+//! Rotor.left_contraction(Sphere) -> Versor
+//! Sphere.right_contraction(Rotor) -> Versor
+//! Sphere.commutator(Rotor) -> Plane
+//! Rotor.commutator(Sphere) -> Plane
+//! Sphere.anti_dot(Rotor) -> Multivector
+//! Rotor.anti_dot(Sphere) -> Multivector
+//! Sphere.geometric(Translator) -> Versor
+//! Translator.geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Sphere) -> Multivector
+//! Sphere.dot(Translator) -> Versor
+//! Translator.dot(Sphere) -> Versor
+//! Sphere.wedge(Translator) -> Sphere
+//! Translator.wedge(Sphere) -> Sphere
+//! Sphere.anti_wedge(Translator) -> Point
+//! Translator.anti_wedge(Sphere) -> Point
+//! Translator.left_contraction(Sphere) -> Versor
+//! Sphere.right_contraction(Translator) -> Versor
+//! Sphere.commutator(Translator) -> Plane
+//! Translator.commutator(Sphere) -> Plane
+//! Sphere.anti_dot(Translator) -> Multivector
+//! Translator.anti_dot(Sphere) -> Multivector
+//! Sphere.geometric(Versor) -> Versor
+//! Versor.geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(Versor) -> Multivector
+//! Versor.anti_geometric(Sphere) -> Multivector
+//! Sphere.dot(Versor) -> Versor
+//! Versor.dot(Sphere) -> Versor
+//! Sphere.wedge(Versor) -> Sphere
+//! Versor.wedge(Sphere) -> Sphere
+//! Sphere.anti_wedge(Versor) -> Multivector
+//! Versor.anti_wedge(Sphere) -> Multivector
+//! Sphere.left_contraction(Versor) -> S
+//! Versor.left_contraction(Sphere) -> Versor
+//! Sphere.right_contraction(Versor) -> Versor
+//! Versor.right_contraction(Sphere) -> S
+//! Sphere.scalar_product(Versor) -> S
+//! Versor.scalar_product(Sphere) -> S
+//! Sphere.commutator(Versor) -> Versor
+//! Versor.commutator(Sphere) -> Versor
+//! Sphere.anti_dot(Versor) -> Multivector
+//! Versor.anti_dot(Sphere) -> Multivector
+//! Sphere.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Sphere) -> Multivector
+//! Sphere.dot(Multivector) -> Multivector
+//! Multivector.dot(Sphere) -> Multivector
+//! Sphere.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Sphere) -> Multivector
+//! Sphere.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Sphere) -> Multivector
+//! Sphere.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Sphere) -> Multivector
+//! Sphere.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Sphere) -> Multivector
+//! Sphere.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Sphere) -> S
+//! Sphere.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Sphere) -> Multivector
+//! Sphere.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Sphere) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere {
+	pub e123o: E123o,
+	pub e123i: E123i,
+	pub e12oi: E12oi,
+	pub e13oi: E13oi,
+	pub e23oi: E23oi,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Sphere {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Sphere {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Sphere {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.e123o.abs_diff_eq(&other.e123o, epsilon)
+			&& self.e123i.abs_diff_eq(&other.e123i, epsilon)
+			&& self.e12oi.abs_diff_eq(&other.e12oi, epsilon)
+			&& self.e13oi.abs_diff_eq(&other.e13oi, epsilon)
+			&& self.e23oi.abs_diff_eq(&other.e23oi, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Sphere {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.e123o.relative_eq(&other.e123o, epsilon, max_relative)
+			&& self.e123i.relative_eq(&other.e123i, epsilon, max_relative)
+			&& self.e12oi.relative_eq(&other.e12oi, epsilon, max_relative)
+			&& self.e13oi.relative_eq(&other.e13oi, epsilon, max_relative)
+			&& self.e23oi.relative_eq(&other.e23oi, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Sphere {
+	type Output = Point;
+	fn rcompl(self) -> Self::Output {
+		Point {
+			x: self.e23oi.rcompl(),
+			y: self.e13oi.rcompl(),
+			z: self.e12oi.rcompl(),
+			o: self.e123i.rcompl(),
+			inf: self.e123o.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Sphere {
+	type Output = Point;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Sphere {
+	type Output = Point;
+	fn lcompl(self) -> Self::Output {
+		Point {
+			x: self.e23oi.lcompl(),
+			y: self.e13oi.lcompl(),
+			z: self.e12oi.lcompl(),
+			o: self.e123i.lcompl(),
+			inf: self.e123o.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Sphere {
+	fn rev(self) -> Self {
+		Sphere {
+			e123o: self.e123o,
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl AntiReverse for Sphere {
+	fn arev(self) -> Self {
+		Sphere {
+			e123o: self.e123o,
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl Involute for Sphere {
+	fn involute(self) -> Self {
+		Sphere {
+			e123o: self.e123o,
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl Conjugate for Sphere {
+	fn conjugate(self) -> Self {
+		Sphere {
+			e123o: self.e123o,
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl Dual for Sphere {
+	type Output = Point;
+	fn dual(self) -> Self::Output {
+		Point {
+			x: self.e23oi.dual(),
+			y: self.e13oi.dual(),
+			z: self.e12oi.dual(),
+			o: self.e123i.dual(),
+			inf: self.e123o.dual(),
+		}
+	}
+}
+
+impl Square for Sphere {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(S(self.e123i.0 * self.e123o.0) * -2.0
+			- S(self.e12oi.0 * self.e12oi.0)
+			- S(self.e13oi.0 * self.e13oi.0)
+			- S(self.e23oi.0 * self.e23oi.0))
+		.0
+	}
+}
+
+// Omitted: Norm for Sphere  (the bulk and weight need a diagonal metric)
+
+impl Sphere {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 5] {
+		[self.e123o.0, self.e123i.0, self.e12oi.0, self.e13oi.0, self.e23oi.0]
+	}
+}
+
+impl From<[f64; 5]> for Sphere {
+	fn from(a: [f64; 5]) -> Self {
+		Sphere {
+			e123o: E123o(a[0]),
+			e123i: E123i(a[1]),
+			e12oi: E12oi(a[2]),
+			e13oi: E13oi(a[3]),
+			e23oi: E23oi(a[4]),
+		}
+	}
+}
+
+impl From<Sphere> for [f64; 5] {
+	fn from(v: Sphere) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Sphere> for Multivector {
+	fn from(v: Sphere) -> Self {
+		Multivector {
+			e123o: v.e123o,
+			e123i: v.e123i,
+			e12oi: v.e12oi,
+			e13oi: v.e13oi,
+			e23oi: v.e23oi,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Sphere {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Sphere` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.eo.0 == 0.0
+			&& mv.ei.0 == 0.0
+			&& mv.e12.0 == 0.0
+			&& mv.e13.0 == 0.0
+			&& mv.e1o.0 == 0.0
+			&& mv.e1i.0 == 0.0
+			&& mv.e23.0 == 0.0
+			&& mv.e2o.0 == 0.0
+			&& mv.e2i.0 == 0.0
+			&& mv.e3o.0 == 0.0
+			&& mv.e3i.0 == 0.0
+			&& mv.eoi.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e12o.0 == 0.0
+			&& mv.e12i.0 == 0.0
+			&& mv.e13o.0 == 0.0
+			&& mv.e13i.0 == 0.0
+			&& mv.e1oi.0 == 0.0
+			&& mv.e23o.0 == 0.0
+			&& mv.e23i.0 == 0.0
+			&& mv.e2oi.0 == 0.0
+			&& mv.e3oi.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(Sphere {
+				e123o: mv.e123o,
+				e123i: mv.e123i,
+				e12oi: mv.e12oi,
+				e13oi: mv.e13oi,
+				e23oi: mv.e23oi,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP Vec3:
+
+// Sphere.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Sphere {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : -E12o(self.e123o.0 * rhs.z.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.y.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.x.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: -E12o(self.e123o.0 * rhs.z.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Sphere {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(Vec3) -> Versor
+impl AntiGeometric<Vec3> for Sphere {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+		//     e13  : E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.x.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.x.0),
+		//     e23  : E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+		//     e2o  : -E2o(self.e123o.0 * rhs.y.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.y.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.z.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.z.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0),
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+			e1o: -E1o(self.e123o.0 * rhs.x.0),
+			e1i: E1i(self.e123i.0 * rhs.x.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+			e2o: -E2o(self.e123o.0 * rhs.y.0),
+			e2i: E2i(self.e123i.0 * rhs.y.0),
+			e3o: -E3o(self.e123o.0 * rhs.z.0),
+			e3i: E3i(self.e123i.0 * rhs.z.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Sphere.dot(Vec3) -> Circle
+impl Dot<Vec3> for Sphere {
+	type Output = Circle;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Circle {
+		//     e123: Default::default(),
+		//     e12o: -E12o(self.e123o.0 * rhs.z.0),
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0),
+		//     e13o: E13o(self.e123o.0 * rhs.y.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o: -E23o(self.e123o.0 * rhs.x.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Circle {
+			e123: Default::default(),
+			e12o: -E12o(self.e123o.0 * rhs.z.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Sphere {
+	type Output = Circle;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Sphere.wedge(Vec3) -> E123oi
+impl Wedge<Vec3> for Sphere {
+	type Output = E123oi;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0)
+		self.e12oi.wedge(rhs.z) + self.e13oi.wedge(rhs.y) + self.e23oi.wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Sphere {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(Vec3) -> S
+impl AntiWedge<Vec3> for Sphere {
+	type Output = S;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0)
+		self.e12oi.anti_wedge(rhs.z) + self.e13oi.anti_wedge(rhs.y) + self.e23oi.anti_wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Sphere {
+	type Output = S;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for Sphere {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.right_contraction(Vec3) -> Circle
+impl RightContraction<Vec3> for Sphere {
+	type Output = Circle;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Circle {
+		//     e123: Default::default(),
+		//     e12o: -E12o(self.e123o.0 * rhs.z.0),
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0),
+		//     e13o: E13o(self.e123o.0 * rhs.y.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o: -E23o(self.e123o.0 * rhs.x.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Circle {
+			e123: Default::default(),
+			e12o: -E12o(self.e123o.0 * rhs.z.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Sphere {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.commutator(Vec3) -> Circle
+impl Commutator<Vec3> for Sphere {
+	type Output = Circle;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Circle {
+		//     e123: Default::default(),
+		//     e12o: -E12o(self.e123o.0 * rhs.z.0),
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0),
+		//     e13o: E13o(self.e123o.0 * rhs.y.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o: -E23o(self.e123o.0 * rhs.x.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Circle {
+			e123: Default::default(),
+			e12o: -E12o(self.e123o.0 * rhs.z.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(Vec3) -> PointPair
+impl AntiDot<Vec3> for Sphere {
+	type Output = PointPair;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+		//     e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+		//     e1o: -E1o(self.e123o.0 * rhs.x.0),
+		//     e1i: E1i(self.e123i.0 * rhs.x.0),
+		//     e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+		//     e2o: -E2o(self.e123o.0 * rhs.y.0),
+		//     e2i: E2i(self.e123i.0 * rhs.y.0),
+		//     e3o: -E3o(self.e123o.0 * rhs.z.0),
+		//     e3i: E3i(self.e123i.0 * rhs.z.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+			e1o: -E1o(self.e123o.0 * rhs.x.0),
+			e1i: E1i(self.e123i.0 * rhs.x.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+			e2o: -E2o(self.e123o.0 * rhs.y.0),
+			e2i: E2i(self.e123i.0 * rhs.y.0),
+			e3o: -E3o(self.e123o.0 * rhs.z.0),
+			e3i: E3i(self.e123i.0 * rhs.z.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP Point:
+
+// Sphere.geometric(Point) -> Multivector
+impl Geometric<Point> for Sphere {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e123i.0 * rhs.o.0) + E123oi(self.e123o.0 * rhs.inf.0) + E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+			e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e123i.0 * rhs.o.0) + E123oi(self.e123o.0 * rhs.inf.0) + E123oi(self.e12oi.0 * rhs.z.0)
+				- E123oi(self.e13oi.0 * rhs.y.0)
+				+ E123oi(self.e23oi.0 * rhs.x.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for Sphere {
+	type Output = Multivector;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(Point) -> Versor
+impl AntiGeometric<Point> for Sphere {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.o.0) + S(self.e123o.0 * rhs.inf.0) + S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+		//     e13  : E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.x.0) - E1o(self.e23oi.0 * rhs.o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.x.0) - E1i(self.e23oi.0 * rhs.inf.0),
+		//     e23  : E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+		//     e2o  : -E2o(self.e123o.0 * rhs.y.0) + E2o(self.e13oi.0 * rhs.o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.y.0) + E2i(self.e13oi.0 * rhs.inf.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.z.0) - E3o(self.e12oi.0 * rhs.o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0),
+		//     eoi  : Eoi(self.e123i.0 * rhs.o.0) + Eoi(self.e123o.0 * rhs.inf.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.o.0) + S(self.e123o.0 * rhs.inf.0) + S(self.e12oi.0 * rhs.z.0)
+				- S(self.e13oi.0 * rhs.y.0)
+				+ S(self.e23oi.0 * rhs.x.0),
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+			e1o: -E1o(self.e123o.0 * rhs.x.0) - E1o(self.e23oi.0 * rhs.o.0),
+			e1i: E1i(self.e123i.0 * rhs.x.0) - E1i(self.e23oi.0 * rhs.inf.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+			e2o: -E2o(self.e123o.0 * rhs.y.0) + E2o(self.e13oi.0 * rhs.o.0),
+			e2i: E2i(self.e123i.0 * rhs.y.0) + E2i(self.e13oi.0 * rhs.inf.0),
+			e3o: -E3o(self.e123o.0 * rhs.z.0) - E3o(self.e12oi.0 * rhs.o.0),
+			e3i: E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e123i.0 * rhs.o.0) + Eoi(self.e123o.0 * rhs.inf.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Sphere.dot(Point) -> Circle
+impl Dot<Point> for Sphere {
+	type Output = Circle;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+		//     e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Circle {
+			e123: -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+			e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point> for Sphere {
+	type Output = Circle;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Sphere.wedge(Point) -> E123oi
+impl Wedge<Point> for Sphere {
+	type Output = E123oi;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// -E123oi(self.e123i.0 * rhs.o.0) + E123oi(self.e123o.0 * rhs.inf.0) + E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0)
+		self.e123i.wedge(rhs.o)
+			+ self.e123o.wedge(rhs.inf)
+			+ self.e12oi.wedge(rhs.z)
+			+ self.e13oi.wedge(rhs.y)
+			+ self.e23oi.wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitXor<Point> for Sphere {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(Point) -> S
+impl AntiWedge<Point> for Sphere {
+	type Output = S;
+	fn anti_wedge(self, rhs: Point) -> Self::Output {
+		// -S(self.e123i.0 * rhs.o.0) + S(self.e123o.0 * rhs.inf.0) + S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0)
+		self.e123i.anti_wedge(rhs.o)
+			+ self.e123o.anti_wedge(rhs.inf)
+			+ self.e12oi.anti_wedge(rhs.z)
+			+ self.e13oi.anti_wedge(rhs.y)
+			+ self.e23oi.anti_wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitAnd<Point> for Sphere {
+	type Output = S;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point> for Sphere {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.right_contraction(Point) -> Circle
+impl RightContraction<Point> for Sphere {
+	type Output = Circle;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+		//     e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Circle {
+			e123: -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+			e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+impl ScalarProduct<Point> for Sphere {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.commutator(Point) -> Circle
+impl Commutator<Point> for Sphere {
+	type Output = Circle;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+		//     e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		// }
+		Circle {
+			e123: -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+			e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(Point) -> PointPair
+impl AntiDot<Point> for Sphere {
+	type Output = PointPair;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+		//     e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+		//     e1o: -E1o(self.e123o.0 * rhs.x.0) - E1o(self.e23oi.0 * rhs.o.0),
+		//     e1i: E1i(self.e123i.0 * rhs.x.0) - E1i(self.e23oi.0 * rhs.inf.0),
+		//     e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+		//     e2o: -E2o(self.e123o.0 * rhs.y.0) + E2o(self.e13oi.0 * rhs.o.0),
+		//     e2i: E2i(self.e123i.0 * rhs.y.0) + E2i(self.e13oi.0 * rhs.inf.0),
+		//     e3o: -E3o(self.e123o.0 * rhs.z.0) - E3o(self.e12oi.0 * rhs.o.0),
+		//     e3i: E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0),
+		//     eoi: Eoi(self.e123i.0 * rhs.o.0) + Eoi(self.e123o.0 * rhs.inf.0),
+		// }
+		PointPair {
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+			e1o: -E1o(self.e123o.0 * rhs.x.0) - E1o(self.e23oi.0 * rhs.o.0),
+			e1i: E1i(self.e123i.0 * rhs.x.0) - E1i(self.e23oi.0 * rhs.inf.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+			e2o: -E2o(self.e123o.0 * rhs.y.0) + E2o(self.e13oi.0 * rhs.o.0),
+			e2i: E2i(self.e123i.0 * rhs.y.0) + E2i(self.e13oi.0 * rhs.inf.0),
+			e3o: -E3o(self.e123o.0 * rhs.z.0) - E3o(self.e12oi.0 * rhs.o.0),
+			e3i: E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e123i.0 * rhs.o.0) + Eoi(self.e123o.0 * rhs.inf.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP FlatPoint:
+
+// Sphere.geometric(FlatPoint) -> Versor
+impl Geometric<FlatPoint> for Sphere {
+	type Output = Versor;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.e123o.0 * rhs.eoi.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: -E12oi(self.e123o.0 * rhs.e3i.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e2i.0),
+		//     e23oi: -E23oi(self.e123o.0 * rhs.e1i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: -E12oi(self.e123o.0 * rhs.e3i.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e2i.0),
+			e23oi: -E23oi(self.e123o.0 * rhs.e1i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for Sphere {
+	type Output = Versor;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(FlatPoint) -> Multivector
+impl AntiGeometric<FlatPoint> for Sphere {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123o.0 * rhs.e1i.0),
+		//     e2    : E2(self.e123o.0 * rhs.e2i.0),
+		//     e3    : E3(self.e123o.0 * rhs.e3i.0),
+		//     eo    : Eo(self.e123o.0 * rhs.eoi.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123o.0 * rhs.e1i.0),
+			e2: E2(self.e123o.0 * rhs.e2i.0),
+			e3: E3(self.e123o.0 * rhs.e3i.0),
+			eo: Eo(self.e123o.0 * rhs.eoi.0),
+			ei: Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Sphere.dot(FlatPoint) -> PointPair
+impl Dot<FlatPoint> for Sphere {
+	type Output = PointPair;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for Sphere {
+	type Output = PointPair;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<FlatPoint> for Sphere {
+	type Output = Zero;
+	fn wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for Sphere {
+	type Output = Zero;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(FlatPoint) -> Point
+impl AntiWedge<FlatPoint> for Sphere {
+	type Output = Point;
+	fn anti_wedge(self, rhs: FlatPoint) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123o.0 * rhs.e1i.0),
+		//     y  : E2(self.e123o.0 * rhs.e2i.0),
+		//     z  : E3(self.e123o.0 * rhs.e3i.0),
+		//     o  : Eo(self.e123o.0 * rhs.eoi.0),
+		//     inf: Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		// }
+		Point {
+			x: self.e123o.anti_wedge(rhs.e1i),
+			y: self.e123o.anti_wedge(rhs.e2i),
+			z: self.e123o.anti_wedge(rhs.e3i),
+			o: self.e123o.anti_wedge(rhs.eoi),
+			inf: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e23oi.anti_wedge(rhs.e1i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for Sphere {
+	type Output = Point;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<FlatPoint> for Sphere {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.right_contraction(FlatPoint) -> PointPair
+impl RightContraction<FlatPoint> for Sphere {
+	type Output = PointPair;
+	fn right_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o: Default::default(),
+		//     e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<FlatPoint> for Sphere {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.commutator(FlatPoint) -> Sphere
+impl Commutator<FlatPoint> for Sphere {
+	type Output = Sphere;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e123o.0 * rhs.eoi.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: -E12oi(self.e123o.0 * rhs.e3i.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e2i.0),
+		//     e23oi: -E23oi(self.e123o.0 * rhs.e1i.0),
+		// }
+		Sphere {
+			e123o: E123o(self.e123o.0 * rhs.eoi.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: -E12oi(self.e123o.0 * rhs.e3i.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e2i.0),
+			e23oi: -E23oi(self.e123o.0 * rhs.e1i.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(FlatPoint) -> Line
+impl AntiDot<FlatPoint> for Sphere {
+	type Output = Line;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi: E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi: E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi: E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		// }
+		Line {
+			e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP PointPair:
+
+// Sphere.geometric(PointPair) -> Versor
+impl Geometric<PointPair> for Sphere {
+	type Output = Versor;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for Sphere {
+	type Output = Versor;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(PointPair) -> Multivector
+impl AntiGeometric<PointPair> for Sphere {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0)
+				- E1(self.e13oi.0 * rhs.e12.0),
+			e2: -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0)
+				- E2(self.e23oi.0 * rhs.e12.0),
+			e3: -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e13oi.0 * rhs.e23.0)
+				- E3(self.e23oi.0 * rhs.e13.0),
+			eo: Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0)
+				- Eo(self.e23oi.0 * rhs.e1o.0),
+			ei: Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Sphere.dot(PointPair) -> PointPair
+impl Dot<PointPair> for Sphere {
+	type Output = PointPair;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		// }
+		PointPair {
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<PointPair> for Sphere {
+	type Output = PointPair;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<PointPair> for Sphere {
+	type Output = Zero;
+	fn wedge(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<PointPair> for Sphere {
+	type Output = Zero;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(PointPair) -> Point
+impl AntiWedge<PointPair> for Sphere {
+	type Output = Point;
+	fn anti_wedge(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     y  : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     z  : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     o  : Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     inf: Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		// }
+		Point {
+			x: self.e123i.anti_wedge(rhs.e1o)
+				+ self.e123o.anti_wedge(rhs.e1i)
+				+ self.e12oi.anti_wedge(rhs.e13)
+				+ self.e13oi.anti_wedge(rhs.e12),
+			y: self.e123i.anti_wedge(rhs.e2o)
+				+ self.e123o.anti_wedge(rhs.e2i)
+				+ self.e12oi.anti_wedge(rhs.e23)
+				+ self.e23oi.anti_wedge(rhs.e12),
+			z: self.e123i.anti_wedge(rhs.e3o)
+				+ self.e123o.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e23)
+				+ self.e23oi.anti_wedge(rhs.e13),
+			o: self.e123o.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3o)
+				+ self.e13oi.anti_wedge(rhs.e2o)
+				+ self.e23oi.anti_wedge(rhs.e1o),
+			inf: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e23oi.anti_wedge(rhs.e1i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for Sphere {
+	type Output = Point;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<PointPair> for Sphere {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.right_contraction(PointPair) -> PointPair
+impl RightContraction<PointPair> for Sphere {
+	type Output = PointPair;
+	fn right_contraction(self, rhs: PointPair) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		// }
+		PointPair {
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl ScalarProduct<PointPair> for Sphere {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.commutator(PointPair) -> Sphere
+impl Commutator<PointPair> for Sphere {
+	type Output = Sphere;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		// }
+		Sphere {
+			e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(PointPair) -> Circle
+impl AntiDot<PointPair> for Sphere {
+	type Output = Circle;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o: -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o: -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o: -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+		//     e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		// }
+		Circle {
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP Line:
+
+// Sphere.geometric(Line) -> Multivector
+impl Geometric<Line> for Sphere {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     e2    : -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) + E123(self.e23oi.0 * rhs.e1oi.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3oi.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e23oi.0 * rhs.e13i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e2oi.0),
+		//     e13i  : -E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) - E13i(self.e23oi.0 * rhs.e12i.0),
+		//     e1oi  : -E1oi(self.e123o.0 * rhs.e23i.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e1oi.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13oi.0 * rhs.e12i.0),
+		//     e2oi  : E2oi(self.e123o.0 * rhs.e13i.0),
+		//     e3oi  : -E3oi(self.e123o.0 * rhs.e12i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			e2: -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Default::default(),
+			ei: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) + E123(self.e23oi.0 * rhs.e1oi.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3oi.0),
+			e12i: E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e23oi.0 * rhs.e13i.0),
+			e13o: E13o(self.e123o.0 * rhs.e2oi.0),
+			e13i: -E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) - E13i(self.e23oi.0 * rhs.e12i.0),
+			e1oi: -E1oi(self.e123o.0 * rhs.e23i.0),
+			e23o: -E23o(self.e123o.0 * rhs.e1oi.0),
+			e23i: E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13oi.0 * rhs.e12i.0),
+			e2oi: E2oi(self.e123o.0 * rhs.e13i.0),
+			e3oi: -E3oi(self.e123o.0 * rhs.e12i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Sphere {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(Line) -> Versor
+impl AntiGeometric<Line> for Sphere {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123o.0 * rhs.e12i.0),
+		//     e13  : E13(self.e123o.0 * rhs.e13i.0),
+		//     e1o  : E1o(self.e123o.0 * rhs.e1oi.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e23i.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e2oi.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o  : E3o(self.e123o.0 * rhs.e3oi.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi  : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi: E12oi(self.e123o.0 * rhs.e12i.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi: E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123o.0 * rhs.e12i.0),
+			e13: E13(self.e123o.0 * rhs.e13i.0),
+			e1o: E1o(self.e123o.0 * rhs.e1oi.0),
+			e1i: E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+			e23: E23(self.e123o.0 * rhs.e23i.0),
+			e2o: E2o(self.e123o.0 * rhs.e2oi.0),
+			e2i: E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+			e3o: E3o(self.e123o.0 * rhs.e3oi.0),
+			e3i: E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+			eoi: Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e123o.0 * rhs.e12i.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Sphere.dot(Line) -> Point
+impl Dot<Line> for Sphere {
+	type Output = Point;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Default::default(),
+			inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Sphere {
+	type Output = Point;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Line> for Sphere {
+	type Output = Zero;
+	fn wedge(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Line> for Sphere {
+	type Output = Zero;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(Line) -> PointPair
+impl AntiWedge<Line> for Sphere {
+	type Output = PointPair;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e123o.0 * rhs.e12i.0),
+		//     e13: E13(self.e123o.0 * rhs.e13i.0),
+		//     e1o: E1o(self.e123o.0 * rhs.e1oi.0),
+		//     e1i: E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23: E23(self.e123o.0 * rhs.e23i.0),
+		//     e2o: E2o(self.e123o.0 * rhs.e2oi.0),
+		//     e2i: E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o: E3o(self.e123o.0 * rhs.e3oi.0),
+		//     e3i: E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi: Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		// }
+		PointPair {
+			e12: self.e123o.anti_wedge(rhs.e12i),
+			e13: self.e123o.anti_wedge(rhs.e13i),
+			e1o: self.e123o.anti_wedge(rhs.e1oi),
+			e1i: self.e123i.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e12i),
+			e23: self.e123o.anti_wedge(rhs.e23i),
+			e2o: self.e123o.anti_wedge(rhs.e2oi),
+			e2i: self.e123i.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e12i),
+			e3o: self.e123o.anti_wedge(rhs.e3oi),
+			e3i: self.e123i.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e13i),
+			eoi: self.e12oi.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e2oi) + self.e23oi.anti_wedge(rhs.e1oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Sphere {
+	type Output = PointPair;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Line> for Sphere {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.right_contraction(Line) -> Point
+impl RightContraction<Line> for Sphere {
+	type Output = Point;
+	fn right_contraction(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Default::default(),
+			inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+impl ScalarProduct<Line> for Sphere {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.commutator(Line) -> Point
+impl Commutator<Line> for Sphere {
+	type Output = Point;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Default::default(),
+			inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(Line) -> Plane
+impl AntiDot<Line> for Sphere {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi: E12oi(self.e123o.0 * rhs.e12i.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi: E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0),
+		// }
+		Plane {
+			e123i: -E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e123o.0 * rhs.e12i.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP Circle:
+
+// Sphere.geometric(Circle) -> Multivector
+impl Geometric<Circle> for Sphere {
+	type Output = Multivector;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) + E123(self.e23oi.0 * rhs.e1oi.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3oi.0) + E12o(self.e13oi.0 * rhs.e23o.0) - E12o(self.e23oi.0 * rhs.e13o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e23oi.0 * rhs.e13i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e2oi.0) - E13o(self.e12oi.0 * rhs.e23o.0) + E13o(self.e23oi.0 * rhs.e12o.0),
+		//     e13i  : -E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) - E13i(self.e23oi.0 * rhs.e12i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e23o.0) - E1oi(self.e123o.0 * rhs.e23i.0) - E1oi(self.e23oi.0 * rhs.e123.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e1oi.0) + E23o(self.e12oi.0 * rhs.e13o.0) - E23o(self.e13oi.0 * rhs.e12o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13oi.0 * rhs.e12i.0),
+		//     e2oi  : -E2oi(self.e123i.0 * rhs.e13o.0) + E2oi(self.e123o.0 * rhs.e13i.0) + E2oi(self.e13oi.0 * rhs.e123.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e12o.0) - E3oi(self.e123o.0 * rhs.e12i.0) - E3oi(self.e12oi.0 * rhs.e123.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) + E123(self.e23oi.0 * rhs.e1oi.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3oi.0) + E12o(self.e13oi.0 * rhs.e23o.0) - E12o(self.e23oi.0 * rhs.e13o.0),
+			e12i: E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e23oi.0 * rhs.e13i.0),
+			e13o: E13o(self.e123o.0 * rhs.e2oi.0) - E13o(self.e12oi.0 * rhs.e23o.0) + E13o(self.e23oi.0 * rhs.e12o.0),
+			e13i: -E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) - E13i(self.e23oi.0 * rhs.e12i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e23o.0) - E1oi(self.e123o.0 * rhs.e23i.0) - E1oi(self.e23oi.0 * rhs.e123.0),
+			e23o: -E23o(self.e123o.0 * rhs.e1oi.0) + E23o(self.e12oi.0 * rhs.e13o.0) - E23o(self.e13oi.0 * rhs.e12o.0),
+			e23i: E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13oi.0 * rhs.e12i.0),
+			e2oi: -E2oi(self.e123i.0 * rhs.e13o.0) + E2oi(self.e123o.0 * rhs.e13i.0) + E2oi(self.e13oi.0 * rhs.e123.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e12o.0) - E3oi(self.e123o.0 * rhs.e12i.0) - E3oi(self.e12oi.0 * rhs.e123.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for Sphere {
+	type Output = Multivector;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(Circle) -> Versor
+impl AntiGeometric<Circle> for Sphere {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e123o.0 * rhs.e13i.0) + E13(self.e13oi.0 * rhs.e123.0),
+		//     e1o  : E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23  : -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e23oi.0 * rhs.e123.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o  : E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi  : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		//     e123o: -E123o(self.e123o.0 * rhs.e123.0) - E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13oi.0 * rhs.e13o.0) - E123o(self.e23oi.0 * rhs.e23o.0),
+		//     e123i: E123i(self.e123i.0 * rhs.e123.0) - E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi: E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0),
+			e13: -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e123o.0 * rhs.e13i.0) + E13(self.e13oi.0 * rhs.e123.0),
+			e1o: E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0),
+			e1i: E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+			e23: -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e23oi.0 * rhs.e123.0),
+			e2o: E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+			e2i: E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+			e3o: E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+			e3i: E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+			eoi: Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+			e123o: -E123o(self.e123o.0 * rhs.e123.0)
+				- E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0),
+			e123i: E123i(self.e123i.0 * rhs.e123.0)
+				- E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e123i.0 * rhs.e13o.0)
+				+ E13oi(self.e123o.0 * rhs.e13i.0)
+				+ E13oi(self.e12oi.0 * rhs.e1oi.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Sphere.dot(Circle) -> Point
+impl Dot<Circle> for Sphere {
+	type Output = Point;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     inf: Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0),
+			inf: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Circle> for Sphere {
+	type Output = Point;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Circle> for Sphere {
+	type Output = Zero;
+	fn wedge(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Circle> for Sphere {
+	type Output = Zero;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(Circle) -> PointPair
+impl AntiWedge<Circle> for Sphere {
+	type Output = PointPair;
+	fn anti_wedge(self, rhs: Circle) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0),
+		//     e13: -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e123o.0 * rhs.e13i.0) + E13(self.e13oi.0 * rhs.e123.0),
+		//     e1o: E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0),
+		//     e1i: E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23: -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e23oi.0 * rhs.e123.0),
+		//     e2o: E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+		//     e2i: E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o: E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+		//     e3i: E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi: Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		// }
+		PointPair {
+			e12: self.e123i.anti_wedge(rhs.e12o) + self.e123o.anti_wedge(rhs.e12i) + self.e12oi.anti_wedge(rhs.e123),
+			e13: self.e123i.anti_wedge(rhs.e13o) + self.e123o.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e123),
+			e1o: self.e123o.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13o) + self.e13oi.anti_wedge(rhs.e12o),
+			e1i: self.e123i.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e12i),
+			e23: self.e123i.anti_wedge(rhs.e23o) + self.e123o.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e123),
+			e2o: self.e123o.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e12o),
+			e2i: self.e123i.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e12i),
+			e3o: self.e123o.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e13o),
+			e3i: self.e123i.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e13i),
+			eoi: self.e12oi.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e2oi) + self.e23oi.anti_wedge(rhs.e1oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Circle> for Sphere {
+	type Output = PointPair;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Circle> for Sphere {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.right_contraction(Circle) -> Point
+impl RightContraction<Circle> for Sphere {
+	type Output = Point;
+	fn right_contraction(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     inf: Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0),
+			inf: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+impl ScalarProduct<Circle> for Sphere {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.commutator(Circle) -> Point
+impl Commutator<Circle> for Sphere {
+	type Output = Point;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     inf: Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0),
+			inf: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(Circle) -> Sphere
+impl AntiDot<Circle> for Sphere {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.e123o.0 * rhs.e123.0) - E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13oi.0 * rhs.e13o.0) - E123o(self.e23oi.0 * rhs.e23o.0),
+		//     e123i: E123i(self.e123i.0 * rhs.e123.0) - E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi: E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.e123o.0 * rhs.e123.0)
+				- E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0),
+			e123i: E123i(self.e123i.0 * rhs.e123.0)
+				- E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e123i.0 * rhs.e13o.0)
+				+ E13oi(self.e123o.0 * rhs.e13i.0)
+				+ E13oi(self.e12oi.0 * rhs.e1oi.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP Plane:
+
+// Sphere.geometric(Plane) -> Versor
+impl Geometric<Plane> for Sphere {
+	type Output = Versor;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13  : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23oi.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23  : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13oi.0),
+		//     e2i  : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12oi.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi  : Eoi(self.e123o.0 * rhs.e123i.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23oi.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13oi.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: Eoi(self.e123o.0 * rhs.e123i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Sphere {
+	type Output = Versor;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Sphere {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.e123o.0 * rhs.e12oi.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e13oi.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : E23o(self.e123o.0 * rhs.e23oi.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e123o.0 * rhs.e123i.0),
+			e12o: E12o(self.e123o.0 * rhs.e12oi.0),
+			e12i: E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+			e13o: E13o(self.e123o.0 * rhs.e13oi.0),
+			e13i: E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+			e23o: E23o(self.e123o.0 * rhs.e23oi.0),
+			e23i: E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+			e3oi: E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Sphere.dot(Plane) -> S
+impl Dot<Plane> for Sphere {
+	type Output = S;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// -S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+impl std::ops::BitOr<Plane> for Sphere {
+	type Output = S;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for Sphere {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Sphere {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(Plane) -> Circle
+impl AntiWedge<Plane> for Sphere {
+	type Output = Circle;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.e123o.0 * rhs.e123i.0),
+		//     e12o: E12o(self.e123o.0 * rhs.e12oi.0),
+		//     e12i: E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o: E13o(self.e123o.0 * rhs.e13oi.0),
+		//     e13i: E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o: E23o(self.e123o.0 * rhs.e23oi.0),
+		//     e23i: E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi: E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		// }
+		Circle {
+			e123: self.e123o.anti_wedge(rhs.e123i),
+			e12o: self.e123o.anti_wedge(rhs.e12oi),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e123o.anti_wedge(rhs.e13oi),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e123o.anti_wedge(rhs.e23oi),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Sphere {
+	type Output = Circle;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Sphere.left_contraction(Plane) -> S
+impl LeftContraction<Plane> for Sphere {
+	type Output = S;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// -S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Sphere.right_contraction(Plane) -> S
+impl RightContraction<Plane> for Sphere {
+	type Output = S;
+	fn right_contraction(self, rhs: Plane) -> Self::Output {
+		// -S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Sphere.scalar_product(Plane) -> S
+impl ScalarProduct<Plane> for Sphere {
+	type Output = S;
+	fn scalar_product(self, rhs: Plane) -> Self::Output {
+		// -S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Sphere.commutator(Plane) -> PointPair
+impl Commutator<Plane> for Sphere {
+	type Output = PointPair;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o: -E1o(self.e123o.0 * rhs.e23oi.0),
+		//     e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o: E2o(self.e123o.0 * rhs.e13oi.0),
+		//     e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o: -E3o(self.e123o.0 * rhs.e12oi.0),
+		//     e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi: Eoi(self.e123o.0 * rhs.e123i.0),
+		// }
+		PointPair {
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23oi.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13oi.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: Eoi(self.e123o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(Plane) -> E123oi
+impl AntiDot<Plane> for Sphere {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0)
+		E123oi(self.e123o.0 * rhs.e123i.0)
+			+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+			+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+			+ E123oi(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP Sphere:
+
+// Sphere.geometric(Sphere) -> Versor
+impl Geometric<Sphere> for Sphere {
+	type Output = Versor;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13  : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23  : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0),
+		//     e2i  : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for Sphere {
+	type Output = Versor;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(Sphere) -> Multivector
+impl AntiGeometric<Sphere> for Sphere {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0),
+			e12o: E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+			e13o: E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e13oi.0 * rhs.e123o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+			e23o: E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+			e3oi: E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Sphere.dot(Sphere) -> S
+impl Dot<Sphere> for Sphere {
+	type Output = S;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+impl std::ops::BitOr<Sphere> for Sphere {
+	type Output = S;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Sphere> for Sphere {
+	type Output = Zero;
+	fn wedge(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Sphere> for Sphere {
+	type Output = Zero;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(Sphere) -> Circle
+impl AntiWedge<Sphere> for Sphere {
+	type Output = Circle;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0),
+		//     e12o: E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i: E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o: E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i: E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi: E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o: E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i: E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi: E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		// }
+		Circle {
+			e123: self.e123i.anti_wedge(rhs.e123o) + self.e123o.anti_wedge(rhs.e123i),
+			e12o: self.e123o.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123o),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e123o.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123o),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e123o.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123o),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for Sphere {
+	type Output = Circle;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Sphere.left_contraction(Sphere) -> S
+impl LeftContraction<Sphere> for Sphere {
+	type Output = S;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Sphere.right_contraction(Sphere) -> S
+impl RightContraction<Sphere> for Sphere {
+	type Output = S;
+	fn right_contraction(self, rhs: Sphere) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Sphere.scalar_product(Sphere) -> S
+impl ScalarProduct<Sphere> for Sphere {
+	type Output = S;
+	fn scalar_product(self, rhs: Sphere) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Sphere.commutator(Sphere) -> PointPair
+impl Commutator<Sphere> for Sphere {
+	type Output = PointPair;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// PointPair {
+		//     e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o: -E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o: E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0),
+		//     e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o: -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0),
+		//     e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi: -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0),
+		// }
+		PointPair {
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(Sphere) -> E123oi
+impl AntiDot<Sphere> for Sphere {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0)
+		E123oi(self.e123i.0 * rhs.e123o.0)
+			+ E123oi(self.e123o.0 * rhs.e123i.0)
+			+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+			+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+			+ E123oi(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP Rotor:
+
+// Sphere.geometric(Rotor) -> Versor
+impl Geometric<Rotor> for Sphere {
+	type Output = Versor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0),
+		//     e23  : Default::default(),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0),
+			e23: Default::default(),
+			e2o: E2o(self.e123o.0 * rhs.e13.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Sphere {
+	type Output = Versor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(Rotor) -> Multivector
+impl AntiGeometric<Rotor> for Sphere {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0) + E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0) + E1(self.e23oi.0 * rhs.s.0),
+			e2: E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e23oi.0 * rhs.e12.0),
+			e3: E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0),
+			e1oi: Default::default(),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Sphere.dot(Rotor) -> Versor
+impl Dot<Rotor> for Sphere {
+	type Output = Versor;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0),
+		//     e23  : Default::default(),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0),
+			e23: Default::default(),
+			e2o: E2o(self.e123o.0 * rhs.e13.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Sphere {
+	type Output = Versor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Sphere.wedge(Rotor) -> Sphere
+impl Wedge<Rotor> for Sphere {
+	type Output = Sphere;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Sphere {
+			e123o: self.e123o.wedge(rhs.s),
+			e123i: self.e123i.wedge(rhs.s),
+			e12oi: self.e12oi.wedge(rhs.s),
+			e13oi: self.e13oi.wedge(rhs.s),
+			e23oi: self.e23oi.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Sphere {
+	type Output = Sphere;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(Rotor) -> Vec3
+impl AntiWedge<Rotor> for Sphere {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     y: E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     z: E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		// }
+		Vec3 {
+			x: self.e12oi.anti_wedge(rhs.e13) + self.e13oi.anti_wedge(rhs.e12),
+			y: self.e12oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e12),
+			z: self.e13oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e13),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Sphere {
+	type Output = Vec3;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Rotor> for Sphere {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.right_contraction(Rotor) -> Versor
+impl RightContraction<Rotor> for Sphere {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0),
+		//     e23  : Default::default(),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0),
+			e23: Default::default(),
+			e2o: E2o(self.e123o.0 * rhs.e13.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for Sphere {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.commutator(Rotor) -> Plane
+impl Commutator<Rotor> for Sphere {
+	type Output = Plane;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Plane {
+		//     e123i: Default::default(),
+		//     e12oi: -E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		// }
+		Plane {
+			e123i: Default::default(),
+			e12oi: -E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(Rotor) -> Multivector
+impl AntiDot<Rotor> for Sphere {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0),
+			e1oi: Default::default(),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP Translator:
+
+// Sphere.geometric(Translator) -> Versor
+impl Geometric<Translator> for Sphere {
+	type Output = Versor;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123o.0 * rhs.e3i.0),
+		//     e13  : -E13(self.e123o.0 * rhs.e2i.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e1i.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: -E12oi(self.e123o.0 * rhs.e3i.0) + E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: -E23oi(self.e123o.0 * rhs.e1i.0) + E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123o.0 * rhs.e3i.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: -E12oi(self.e123o.0 * rhs.e3i.0) + E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: -E23oi(self.e123o.0 * rhs.e1i.0) + E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Sphere {
+	type Output = Versor;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(Translator) -> Multivector
+impl AntiGeometric<Translator> for Sphere {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123o.0 * rhs.e1i.0) + E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : E2(self.e123o.0 * rhs.e2i.0) - E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e123o.0 * rhs.e3i.0) + E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123o.0 * rhs.e1i.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : E2oi(self.e123o.0 * rhs.e2i.0),
+		//     e3oi  : E3oi(self.e123o.0 * rhs.e3i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123o.0 * rhs.e1i.0) + E1(self.e23oi.0 * rhs.s.0),
+			e2: E2(self.e123o.0 * rhs.e2i.0) - E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e123o.0 * rhs.e3i.0) + E3(self.e12oi.0 * rhs.s.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0),
+			ei: Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123o.0 * rhs.e1i.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123o.0 * rhs.e2i.0),
+			e3oi: E3oi(self.e123o.0 * rhs.e3i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Sphere.dot(Translator) -> Versor
+impl Dot<Translator> for Sphere {
+	type Output = Versor;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123o.0 * rhs.e3i.0),
+		//     e13  : -E13(self.e123o.0 * rhs.e2i.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e1i.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123o.0 * rhs.e3i.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Sphere {
+	type Output = Versor;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Sphere.wedge(Translator) -> Sphere
+impl Wedge<Translator> for Sphere {
+	type Output = Sphere;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Sphere {
+			e123o: self.e123o.wedge(rhs.s),
+			e123i: self.e123i.wedge(rhs.s),
+			e12oi: self.e12oi.wedge(rhs.s),
+			e13oi: self.e13oi.wedge(rhs.s),
+			e23oi: self.e23oi.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Sphere {
+	type Output = Sphere;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(Translator) -> Point
+impl AntiWedge<Translator> for Sphere {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123o.0 * rhs.e1i.0),
+		//     y  : E2(self.e123o.0 * rhs.e2i.0),
+		//     z  : E3(self.e123o.0 * rhs.e3i.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		// }
+		Point {
+			x: self.e123o.anti_wedge(rhs.e1i),
+			y: self.e123o.anti_wedge(rhs.e2i),
+			z: self.e123o.anti_wedge(rhs.e3i),
+			o: Default::default(),
+			inf: self.e12oi.anti_wedge(rhs.e3i) + self.e13oi.anti_wedge(rhs.e2i) + self.e23oi.anti_wedge(rhs.e1i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Sphere {
+	type Output = Point;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Translator> for Sphere {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.right_contraction(Translator) -> Versor
+impl RightContraction<Translator> for Sphere {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e123o.0 * rhs.e3i.0),
+		//     e13  : -E13(self.e123o.0 * rhs.e2i.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e1i.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e123o.0 * rhs.e3i.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for Sphere {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Sphere.commutator(Translator) -> Plane
+impl Commutator<Translator> for Sphere {
+	type Output = Plane;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: -E12oi(self.e123o.0 * rhs.e3i.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e2i.0),
+		//     e23oi: -E23oi(self.e123o.0 * rhs.e1i.0),
+		// }
+		Plane {
+			e123i: E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: -E12oi(self.e123o.0 * rhs.e3i.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e2i.0),
+			e23oi: -E23oi(self.e123o.0 * rhs.e1i.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(Translator) -> Multivector
+impl AntiDot<Translator> for Sphere {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123o.0 * rhs.e1i.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : E2oi(self.e123o.0 * rhs.e2i.0),
+		//     e3oi  : E3oi(self.e123o.0 * rhs.e3i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123o.0 * rhs.e1i.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123o.0 * rhs.e2i.0),
+			e3oi: E3oi(self.e123o.0 * rhs.e3i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP Versor:
+
+// Sphere.geometric(Versor) -> Versor
+impl Geometric<Versor> for Sphere {
+	type Output = Versor;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0) - E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e12oi.0 * rhs.e23oi.0) + E13(self.e13oi.0 * rhs.eoi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e123o.0 * rhs.e23oi.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e123i.0 * rhs.e23oi.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) - E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e123o.0 * rhs.e13oi.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e13oi.0 * rhs.e123o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e123i.0 * rhs.e13oi.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e13oi.0 * rhs.e123i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) - E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0) - Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: E123o(self.e123o.0 * rhs.eoi.0) + E123o(self.e123o.0 * rhs.s.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) + E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0)
+				- E12(self.e13oi.0 * rhs.e23oi.0)
+				+ E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0)
+				+ E13(self.e12oi.0 * rhs.e23oi.0)
+				+ E13(self.e13oi.0 * rhs.eoi.0)
+				- E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0)
+				- E1o(self.e123o.0 * rhs.e23oi.0)
+				- E1o(self.e12oi.0 * rhs.e2o.0)
+				- E1o(self.e13oi.0 * rhs.e3o.0)
+				+ E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e123i.0 * rhs.e23oi.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				- E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) - E23(self.e12oi.0 * rhs.e13oi.0)
+				+ E23(self.e13oi.0 * rhs.e12oi.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e123o.0 * rhs.e13oi.0) + E2o(self.e12oi.0 * rhs.e1o.0)
+				- E2o(self.e13oi.0 * rhs.e123o.0)
+				- E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e123i.0 * rhs.e13oi.0) - E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e13oi.0 * rhs.e123i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) - E3o(self.e123o.0 * rhs.e12oi.0)
+				+ E3o(self.e12oi.0 * rhs.e123o.0)
+				+ E3o(self.e13oi.0 * rhs.e1o.0)
+				+ E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0)
+				- E3i(self.e12oi.0 * rhs.e123i.0)
+				- E3i(self.e13oi.0 * rhs.e1i.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0)
+				- Eoi(self.e12oi.0 * rhs.e12.0)
+				- Eoi(self.e13oi.0 * rhs.e13.0)
+				- Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0) + E123o(self.e123o.0 * rhs.s.0) - E123o(self.e12oi.0 * rhs.e3o.0)
+				+ E123o(self.e13oi.0 * rhs.e2o.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) + E12oi(self.e12oi.0 * rhs.s.0)
+				- E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0)
+				+ E13oi(self.e123o.0 * rhs.e2i.0)
+				+ E13oi(self.e12oi.0 * rhs.e23.0)
+				+ E13oi(self.e13oi.0 * rhs.s.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0)
+				+ E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for Sphere {
+	type Output = Versor;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(Versor) -> Multivector
+impl AntiGeometric<Versor> for Sphere {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0) + E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e123o.0 * rhs.s.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0) + E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0) + E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e12oi.0 * rhs.e1o.0) - E13o(self.e13oi.0 * rhs.e123o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e12oi.0 * rhs.e1i.0) - E13i(self.e13oi.0 * rhs.e123i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) + E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e13oi.0 * rhs.eoi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0)
+				- E1(self.e13oi.0 * rhs.e12.0)
+				+ E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0)
+				- E2(self.e13oi.0 * rhs.s.0)
+				- E2(self.e23oi.0 * rhs.e12.0),
+			e3: -E3(self.e123i.0 * rhs.e3o.0)
+				+ E3(self.e123o.0 * rhs.e3i.0)
+				+ E3(self.e12oi.0 * rhs.s.0)
+				+ E3(self.e13oi.0 * rhs.e23.0)
+				- E3(self.e23oi.0 * rhs.e13.0),
+			eo: Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e123o.0 * rhs.s.0) - Eo(self.e12oi.0 * rhs.e3o.0)
+				+ Eo(self.e13oi.0 * rhs.e2o.0)
+				- Eo(self.e23oi.0 * rhs.e1o.0),
+			ei: Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0)
+				+ Ei(self.e13oi.0 * rhs.e2i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e123i.0 * rhs.e123o.0)
+				+ E123(self.e123o.0 * rhs.e123i.0)
+				+ E123(self.e12oi.0 * rhs.e12.0)
+				+ E123(self.e13oi.0 * rhs.e13.0)
+				+ E123(self.e23oi.0 * rhs.e23.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0)
+				+ E12o(self.e13oi.0 * rhs.e1o.0)
+				+ E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0)
+				+ E12i(self.e13oi.0 * rhs.e1i.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0) + E13o(self.e123o.0 * rhs.e13oi.0)
+				- E13o(self.e12oi.0 * rhs.e1o.0)
+				- E13o(self.e13oi.0 * rhs.e123o.0)
+				+ E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0)
+				- E13i(self.e12oi.0 * rhs.e1i.0)
+				- E13i(self.e13oi.0 * rhs.e123i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e12oi.0 * rhs.e13oi.0)
+				- E1oi(self.e13oi.0 * rhs.e12oi.0)
+				+ E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e123o.0 * rhs.e23oi.0)
+				- E23o(self.e12oi.0 * rhs.e2o.0)
+				- E23o(self.e13oi.0 * rhs.e3o.0)
+				- E23o(self.e23oi.0 * rhs.e123o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0)
+				- E23i(self.e12oi.0 * rhs.e2i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e23oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) + E2oi(self.e12oi.0 * rhs.e23oi.0)
+				- E2oi(self.e13oi.0 * rhs.eoi.0)
+				- E2oi(self.e23oi.0 * rhs.e12oi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0)
+				+ E3oi(self.e123o.0 * rhs.e3i.0)
+				+ E3oi(self.e12oi.0 * rhs.eoi.0)
+				+ E3oi(self.e13oi.0 * rhs.e23oi.0)
+				- E3oi(self.e23oi.0 * rhs.e13oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Sphere.dot(Versor) -> Versor
+impl Dot<Versor> for Sphere {
+	type Output = Versor;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for Sphere {
+	type Output = Versor;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Sphere.wedge(Versor) -> Sphere
+impl Wedge<Versor> for Sphere {
+	type Output = Sphere;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Sphere {
+			e123o: self.e123o.wedge(rhs.s),
+			e123i: self.e123i.wedge(rhs.s),
+			e12oi: self.e12oi.wedge(rhs.s),
+			e13oi: self.e13oi.wedge(rhs.s),
+			e23oi: self.e23oi.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for Sphere {
+	type Output = Sphere;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(Versor) -> Multivector
+impl AntiWedge<Versor> for Sphere {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.e123i.anti_wedge(rhs.e1o)
+				+ self.e123o.anti_wedge(rhs.e1i)
+				+ self.e12oi.anti_wedge(rhs.e13)
+				+ self.e13oi.anti_wedge(rhs.e12),
+			e2: self.e123i.anti_wedge(rhs.e2o)
+				+ self.e123o.anti_wedge(rhs.e2i)
+				+ self.e12oi.anti_wedge(rhs.e23)
+				+ self.e23oi.anti_wedge(rhs.e12),
+			e3: self.e123i.anti_wedge(rhs.e3o)
+				+ self.e123o.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e23)
+				+ self.e23oi.anti_wedge(rhs.e13),
+			eo: self.e123o.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3o)
+				+ self.e13oi.anti_wedge(rhs.e2o)
+				+ self.e23oi.anti_wedge(rhs.e1o),
+			ei: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e23oi.anti_wedge(rhs.e1i),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e123i.anti_wedge(rhs.e123o) + self.e123o.anti_wedge(rhs.e123i),
+			e12o: self.e123o.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123o),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e123o.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123o),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e123o.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123o),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Versor> for Sphere {
+	type Output = Multivector;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Sphere.left_contraction(Versor) -> S
+impl LeftContraction<Versor> for Sphere {
+	type Output = S;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Sphere.right_contraction(Versor) -> Versor
+impl RightContraction<Versor> for Sphere {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+// Sphere.scalar_product(Versor) -> S
+impl ScalarProduct<Versor> for Sphere {
+	type Output = S;
+	fn scalar_product(self, rhs: Versor) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Sphere.commutator(Versor) -> Versor
+impl Commutator<Versor> for Sphere {
+	type Output = Versor;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13  : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23  : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0),
+		//     e2i  : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0),
+		//     e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+// Sphere.anti_dot(Versor) -> Multivector
+impl AntiDot<Versor> for Sphere {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sphere OP Multivector:
+
+// Sphere.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Sphere {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0) - E1(self.e23oi.0 * rhs.e123oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e13oi.0 * rhs.e123oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e12oi.0 * rhs.e123oi.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e123o.0 * rhs.e123oi.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e123i.0 * rhs.e123oi.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0) - E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e12oi.0 * rhs.e23oi.0) + E13(self.e13oi.0 * rhs.eoi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o   : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e123o.0 * rhs.e23oi.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i   : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e123i.0 * rhs.e23oi.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23   : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) - E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o   : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e123o.0 * rhs.e13oi.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e13oi.0 * rhs.e123o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e123i.0 * rhs.e13oi.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e13oi.0 * rhs.e123i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o   : -E3o(self.e123o.0 * rhs.e12.0) - E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i   : -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi   : -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0) - Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0) + E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) + E123(self.e23oi.0 * rhs.e1oi.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e123o.0 * rhs.e3oi.0) - E12o(self.e12oi.0 * rhs.eo.0) + E12o(self.e13oi.0 * rhs.e23o.0) - E12o(self.e23oi.0 * rhs.e13o.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e123i.0 * rhs.e3oi.0) + E12i(self.e12oi.0 * rhs.ei.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e23oi.0 * rhs.e13i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e2.0) + E13o(self.e123o.0 * rhs.e2oi.0) - E13o(self.e12oi.0 * rhs.e23o.0) - E13o(self.e13oi.0 * rhs.eo.0) + E13o(self.e23oi.0 * rhs.e12o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e2.0) - E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) + E13i(self.e13oi.0 * rhs.ei.0) - E13i(self.e23oi.0 * rhs.e12i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e23o.0) - E1oi(self.e123o.0 * rhs.e23i.0) + E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0) - E1oi(self.e23oi.0 * rhs.e123.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e123o.0 * rhs.e1oi.0) + E23o(self.e12oi.0 * rhs.e13o.0) - E23o(self.e13oi.0 * rhs.e12o.0) - E23o(self.e23oi.0 * rhs.eo.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13oi.0 * rhs.e12i.0) + E23i(self.e23oi.0 * rhs.ei.0),
+		//     e2oi  : -E2oi(self.e123i.0 * rhs.e13o.0) + E2oi(self.e123o.0 * rhs.e13i.0) - E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e13oi.0 * rhs.e123.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e12o.0) - E3oi(self.e123o.0 * rhs.e12i.0) - E3oi(self.e12oi.0 * rhs.e123.0) - E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+		//     e123o : E123o(self.e123o.0 * rhs.eoi.0) + E123o(self.e123o.0 * rhs.s.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i : -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi : E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) + E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi : -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi : E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e23oi.0 * rhs.s.0),
+		//     e123oi: -E123oi(self.e123i.0 * rhs.eo.0) + E123oi(self.e123o.0 * rhs.ei.0) + E123oi(self.e12oi.0 * rhs.e3.0) - E123oi(self.e13oi.0 * rhs.e2.0) + E123oi(self.e23oi.0 * rhs.e1.0),
+		// }
+		Multivector {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e1: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0)
+				- E1(self.e23oi.0 * rhs.e123oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e13oi.0 * rhs.e123oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e12oi.0 * rhs.e123oi.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e123o.0 * rhs.e123oi.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e123i.0 * rhs.e123oi.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0)
+				- E12(self.e13oi.0 * rhs.e23oi.0)
+				+ E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0)
+				+ E13(self.e12oi.0 * rhs.e23oi.0)
+				+ E13(self.e13oi.0 * rhs.eoi.0)
+				- E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0)
+				- E1o(self.e123o.0 * rhs.e23oi.0)
+				- E1o(self.e12oi.0 * rhs.e2o.0)
+				- E1o(self.e13oi.0 * rhs.e3o.0)
+				+ E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e123i.0 * rhs.e23oi.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				- E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) - E23(self.e12oi.0 * rhs.e13oi.0)
+				+ E23(self.e13oi.0 * rhs.e12oi.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e123o.0 * rhs.e13oi.0) + E2o(self.e12oi.0 * rhs.e1o.0)
+				- E2o(self.e13oi.0 * rhs.e123o.0)
+				- E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e123i.0 * rhs.e13oi.0) - E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e13oi.0 * rhs.e123i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) - E3o(self.e123o.0 * rhs.e12oi.0)
+				+ E3o(self.e12oi.0 * rhs.e123o.0)
+				+ E3o(self.e13oi.0 * rhs.e1o.0)
+				+ E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0)
+				- E3i(self.e12oi.0 * rhs.e123i.0)
+				- E3i(self.e13oi.0 * rhs.e1i.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0)
+				- Eoi(self.e12oi.0 * rhs.e12.0)
+				- Eoi(self.e13oi.0 * rhs.e13.0)
+				- Eoi(self.e23oi.0 * rhs.e23.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0) + E123(self.e12oi.0 * rhs.e3oi.0)
+				- E123(self.e13oi.0 * rhs.e2oi.0)
+				+ E123(self.e23oi.0 * rhs.e1oi.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e123o.0 * rhs.e3oi.0) - E12o(self.e12oi.0 * rhs.eo.0)
+				+ E12o(self.e13oi.0 * rhs.e23o.0)
+				- E12o(self.e23oi.0 * rhs.e13o.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e123i.0 * rhs.e3oi.0) + E12i(self.e12oi.0 * rhs.ei.0)
+				- E12i(self.e13oi.0 * rhs.e23i.0)
+				+ E12i(self.e23oi.0 * rhs.e13i.0),
+			e13o: E13o(self.e123o.0 * rhs.e2.0) + E13o(self.e123o.0 * rhs.e2oi.0)
+				- E13o(self.e12oi.0 * rhs.e23o.0)
+				- E13o(self.e13oi.0 * rhs.eo.0)
+				+ E13o(self.e23oi.0 * rhs.e12o.0),
+			e13i: E13i(self.e123i.0 * rhs.e2.0) - E13i(self.e123i.0 * rhs.e2oi.0)
+				+ E13i(self.e12oi.0 * rhs.e23i.0)
+				+ E13i(self.e13oi.0 * rhs.ei.0)
+				- E13i(self.e23oi.0 * rhs.e12i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e23o.0) - E1oi(self.e123o.0 * rhs.e23i.0)
+				+ E1oi(self.e12oi.0 * rhs.e2.0)
+				+ E1oi(self.e13oi.0 * rhs.e3.0)
+				- E1oi(self.e23oi.0 * rhs.e123.0),
+			e23o: -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e123o.0 * rhs.e1oi.0) + E23o(self.e12oi.0 * rhs.e13o.0)
+				- E23o(self.e13oi.0 * rhs.e12o.0)
+				- E23o(self.e23oi.0 * rhs.eo.0),
+			e23i: -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0)
+				+ E23i(self.e13oi.0 * rhs.e12i.0)
+				+ E23i(self.e23oi.0 * rhs.ei.0),
+			e2oi: -E2oi(self.e123i.0 * rhs.e13o.0) + E2oi(self.e123o.0 * rhs.e13i.0) - E2oi(self.e12oi.0 * rhs.e1.0)
+				+ E2oi(self.e13oi.0 * rhs.e123.0)
+				+ E2oi(self.e23oi.0 * rhs.e3.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e12o.0)
+				- E3oi(self.e123o.0 * rhs.e12i.0)
+				- E3oi(self.e12oi.0 * rhs.e123.0)
+				- E3oi(self.e13oi.0 * rhs.e1.0)
+				- E3oi(self.e23oi.0 * rhs.e2.0),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0) + E123o(self.e123o.0 * rhs.s.0) - E123o(self.e12oi.0 * rhs.e3o.0)
+				+ E123o(self.e13oi.0 * rhs.e2o.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) + E12oi(self.e12oi.0 * rhs.s.0)
+				- E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0)
+				+ E13oi(self.e123o.0 * rhs.e2i.0)
+				+ E13oi(self.e12oi.0 * rhs.e23.0)
+				+ E13oi(self.e13oi.0 * rhs.s.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0)
+				+ E23oi(self.e23oi.0 * rhs.s.0),
+			e123oi: -E123oi(self.e123i.0 * rhs.eo.0)
+				+ E123oi(self.e123o.0 * rhs.ei.0)
+				+ E123oi(self.e12oi.0 * rhs.e3.0)
+				- E123oi(self.e13oi.0 * rhs.e2.0)
+				+ E123oi(self.e23oi.0 * rhs.e1.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Sphere {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Sphere.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Sphere {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.eo.0) + S(self.e123o.0 * rhs.ei.0) + S(self.e12oi.0 * rhs.e3.0) - S(self.e13oi.0 * rhs.e2.0) + S(self.e23oi.0 * rhs.e1.0),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0) + E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e123o.0 * rhs.s.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0) - E12(self.e13oi.0 * rhs.e1.0) - E12(self.e23oi.0 * rhs.e2.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e123o.0 * rhs.e13i.0) + E13(self.e12oi.0 * rhs.e1.0) + E13(self.e13oi.0 * rhs.e123.0) - E13(self.e23oi.0 * rhs.e3.0),
+		//     e1o   : -E1o(self.e123o.0 * rhs.e1.0) + E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0) - E1o(self.e23oi.0 * rhs.eo.0),
+		//     e1i   : E1i(self.e123i.0 * rhs.e1.0) + E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0) - E1i(self.e23oi.0 * rhs.ei.0),
+		//     e23   : -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e12oi.0 * rhs.e2.0) + E23(self.e13oi.0 * rhs.e3.0) + E23(self.e23oi.0 * rhs.e123.0),
+		//     e2o   : -E2o(self.e123o.0 * rhs.e2.0) + E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e13oi.0 * rhs.eo.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e13oi.0 * rhs.ei.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o   : -E3o(self.e123o.0 * rhs.e3.0) + E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e12oi.0 * rhs.eo.0) - E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e3.0) + E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e12oi.0 * rhs.ei.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi   : Eoi(self.e123i.0 * rhs.eo.0) + Eoi(self.e123o.0 * rhs.ei.0) + Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0) + E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0) + E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e12oi.0 * rhs.e1o.0) - E13o(self.e13oi.0 * rhs.e123o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e12oi.0 * rhs.e1i.0) - E13i(self.e13oi.0 * rhs.e123i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) + E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e13oi.0 * rhs.eoi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : -E123o(self.e123o.0 * rhs.e123.0) + E123o(self.e123o.0 * rhs.e123oi.0) - E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13oi.0 * rhs.e13o.0) - E123o(self.e23oi.0 * rhs.e23o.0),
+		//     e123i : E123i(self.e123i.0 * rhs.e123.0) + E123i(self.e123i.0 * rhs.e123oi.0) - E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi : E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0) + E12oi(self.e12oi.0 * rhs.e123oi.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi : E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) + E13oi(self.e13oi.0 * rhs.e123oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi : E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0) + E23oi(self.e23oi.0 * rhs.e123oi.0),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: -S(self.e123i.0 * rhs.eo.0) + S(self.e123o.0 * rhs.ei.0) + S(self.e12oi.0 * rhs.e3.0)
+				- S(self.e13oi.0 * rhs.e2.0)
+				+ S(self.e23oi.0 * rhs.e1.0),
+			e1: -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0)
+				- E1(self.e13oi.0 * rhs.e12.0)
+				+ E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0)
+				- E2(self.e13oi.0 * rhs.s.0)
+				- E2(self.e23oi.0 * rhs.e12.0),
+			e3: -E3(self.e123i.0 * rhs.e3o.0)
+				+ E3(self.e123o.0 * rhs.e3i.0)
+				+ E3(self.e12oi.0 * rhs.s.0)
+				+ E3(self.e13oi.0 * rhs.e23.0)
+				- E3(self.e23oi.0 * rhs.e13.0),
+			eo: Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e123o.0 * rhs.s.0) - Eo(self.e12oi.0 * rhs.e3o.0)
+				+ Eo(self.e13oi.0 * rhs.e2o.0)
+				- Eo(self.e23oi.0 * rhs.e1o.0),
+			ei: Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0)
+				+ Ei(self.e13oi.0 * rhs.e2i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0)
+				- E12(self.e13oi.0 * rhs.e1.0)
+				- E12(self.e23oi.0 * rhs.e2.0),
+			e13: -E13(self.e123i.0 * rhs.e13o.0)
+				+ E13(self.e123o.0 * rhs.e13i.0)
+				+ E13(self.e12oi.0 * rhs.e1.0)
+				+ E13(self.e13oi.0 * rhs.e123.0)
+				- E13(self.e23oi.0 * rhs.e3.0),
+			e1o: -E1o(self.e123o.0 * rhs.e1.0) + E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0)
+				+ E1o(self.e13oi.0 * rhs.e12o.0)
+				- E1o(self.e23oi.0 * rhs.eo.0),
+			e1i: E1i(self.e123i.0 * rhs.e1.0) + E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0)
+				+ E1i(self.e13oi.0 * rhs.e12i.0)
+				- E1i(self.e23oi.0 * rhs.ei.0),
+			e23: -E23(self.e123i.0 * rhs.e23o.0)
+				+ E23(self.e123o.0 * rhs.e23i.0)
+				+ E23(self.e12oi.0 * rhs.e2.0)
+				+ E23(self.e13oi.0 * rhs.e3.0)
+				+ E23(self.e23oi.0 * rhs.e123.0),
+			e2o: -E2o(self.e123o.0 * rhs.e2.0) + E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0)
+				+ E2o(self.e13oi.0 * rhs.eo.0)
+				+ E2o(self.e23oi.0 * rhs.e12o.0),
+			e2i: E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0)
+				+ E2i(self.e13oi.0 * rhs.ei.0)
+				+ E2i(self.e23oi.0 * rhs.e12i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e3.0) + E3o(self.e123o.0 * rhs.e3oi.0)
+				- E3o(self.e12oi.0 * rhs.eo.0)
+				- E3o(self.e13oi.0 * rhs.e23o.0)
+				+ E3o(self.e23oi.0 * rhs.e13o.0),
+			e3i: E3i(self.e123i.0 * rhs.e3.0) + E3i(self.e123i.0 * rhs.e3oi.0)
+				- E3i(self.e12oi.0 * rhs.ei.0)
+				- E3i(self.e13oi.0 * rhs.e23i.0)
+				+ E3i(self.e23oi.0 * rhs.e13i.0),
+			eoi: Eoi(self.e123i.0 * rhs.eo.0) + Eoi(self.e123o.0 * rhs.ei.0) + Eoi(self.e12oi.0 * rhs.e3oi.0)
+				- Eoi(self.e13oi.0 * rhs.e2oi.0)
+				+ Eoi(self.e23oi.0 * rhs.e1oi.0),
+			e123: -E123(self.e123i.0 * rhs.e123o.0)
+				+ E123(self.e123o.0 * rhs.e123i.0)
+				+ E123(self.e12oi.0 * rhs.e12.0)
+				+ E123(self.e13oi.0 * rhs.e13.0)
+				+ E123(self.e23oi.0 * rhs.e23.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0)
+				+ E12o(self.e13oi.0 * rhs.e1o.0)
+				+ E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0)
+				+ E12i(self.e13oi.0 * rhs.e1i.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0) + E13o(self.e123o.0 * rhs.e13oi.0)
+				- E13o(self.e12oi.0 * rhs.e1o.0)
+				- E13o(self.e13oi.0 * rhs.e123o.0)
+				+ E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0)
+				- E13i(self.e12oi.0 * rhs.e1i.0)
+				- E13i(self.e13oi.0 * rhs.e123i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e12oi.0 * rhs.e13oi.0)
+				- E1oi(self.e13oi.0 * rhs.e12oi.0)
+				+ E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e123o.0 * rhs.e23oi.0)
+				- E23o(self.e12oi.0 * rhs.e2o.0)
+				- E23o(self.e13oi.0 * rhs.e3o.0)
+				- E23o(self.e23oi.0 * rhs.e123o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0)
+				- E23i(self.e12oi.0 * rhs.e2i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e23oi.0 * rhs.e123i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) + E2oi(self.e12oi.0 * rhs.e23oi.0)
+				- E2oi(self.e13oi.0 * rhs.eoi.0)
+				- E2oi(self.e23oi.0 * rhs.e12oi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0)
+				+ E3oi(self.e123o.0 * rhs.e3i.0)
+				+ E3oi(self.e12oi.0 * rhs.eoi.0)
+				+ E3oi(self.e13oi.0 * rhs.e23oi.0)
+				- E3oi(self.e23oi.0 * rhs.e13oi.0),
+			e123o: -E123o(self.e123o.0 * rhs.e123.0) + E123o(self.e123o.0 * rhs.e123oi.0)
+				- E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0),
+			e123i: E123i(self.e123i.0 * rhs.e123.0) + E123i(self.e123i.0 * rhs.e123oi.0)
+				- E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e12o.0)
+				+ E12oi(self.e123o.0 * rhs.e12i.0)
+				+ E12oi(self.e12oi.0 * rhs.e123oi.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e123i.0 * rhs.e13o.0)
+				+ E13oi(self.e123o.0 * rhs.e13i.0)
+				+ E13oi(self.e12oi.0 * rhs.e1oi.0)
+				+ E13oi(self.e13oi.0 * rhs.e123oi.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0)
+				+ E23oi(self.e23oi.0 * rhs.e123oi.0),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Sphere.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Sphere {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0) - E1(self.e23oi.0 * rhs.e123oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e13oi.0 * rhs.e123oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e12oi.0 * rhs.e123oi.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e123o.0 * rhs.e123oi.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e123i.0 * rhs.e123oi.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o   : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i   : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23   : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o   : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o   : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i   : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi   : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e23oi.0 * rhs.eo.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+		//     e123o : E123o(self.e123o.0 * rhs.s.0),
+		//     e123i : E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.s.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e1: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0)
+				- E1(self.e23oi.0 * rhs.e123oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e13oi.0 * rhs.e123oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e12oi.0 * rhs.e123oi.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e123o.0 * rhs.e123oi.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e123i.0 * rhs.e123oi.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+			e13o: E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0),
+			e13i: E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+			e23o: -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e23oi.0 * rhs.eo.0),
+			e23i: -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Sphere {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Sphere.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Sphere {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : E123o(self.e123o.0 * rhs.s.0),
+		//     e123i : E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.s.0),
+		//     e123oi: -E123oi(self.e123i.0 * rhs.eo.0) + E123oi(self.e123o.0 * rhs.ei.0) + E123oi(self.e12oi.0 * rhs.e3.0) - E123oi(self.e13oi.0 * rhs.e2.0) + E123oi(self.e23oi.0 * rhs.e1.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: self.e123o.wedge(rhs.s),
+			e123i: self.e123i.wedge(rhs.s),
+			e12oi: self.e12oi.wedge(rhs.s),
+			e13oi: self.e13oi.wedge(rhs.s),
+			e23oi: self.e23oi.wedge(rhs.s),
+			e123oi: self.e123i.wedge(rhs.eo)
+				+ self.e123o.wedge(rhs.ei)
+				+ self.e12oi.wedge(rhs.e3)
+				+ self.e13oi.wedge(rhs.e2)
+				+ self.e23oi.wedge(rhs.e1),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Sphere {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Sphere.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Sphere {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.eo.0) + S(self.e123o.0 * rhs.ei.0) + S(self.e12oi.0 * rhs.e3.0) - S(self.e13oi.0 * rhs.e2.0) + S(self.e23oi.0 * rhs.e1.0),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     eo    : Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e123o.0 * rhs.e13i.0) + E13(self.e13oi.0 * rhs.e123.0),
+		//     e1o   : E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0),
+		//     e1i   : E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23   : -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e23oi.0 * rhs.e123.0),
+		//     e2o   : E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o   : E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi   : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : E123o(self.e123o.0 * rhs.e123oi.0),
+		//     e123i : E123i(self.e123i.0 * rhs.e123oi.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.e123oi.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.e123oi.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.e123oi.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.e123i.anti_wedge(rhs.eo)
+				+ self.e123o.anti_wedge(rhs.ei)
+				+ self.e12oi.anti_wedge(rhs.e3)
+				+ self.e13oi.anti_wedge(rhs.e2)
+				+ self.e23oi.anti_wedge(rhs.e1),
+			e1: self.e123i.anti_wedge(rhs.e1o)
+				+ self.e123o.anti_wedge(rhs.e1i)
+				+ self.e12oi.anti_wedge(rhs.e13)
+				+ self.e13oi.anti_wedge(rhs.e12),
+			e2: self.e123i.anti_wedge(rhs.e2o)
+				+ self.e123o.anti_wedge(rhs.e2i)
+				+ self.e12oi.anti_wedge(rhs.e23)
+				+ self.e23oi.anti_wedge(rhs.e12),
+			e3: self.e123i.anti_wedge(rhs.e3o)
+				+ self.e123o.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e23)
+				+ self.e23oi.anti_wedge(rhs.e13),
+			eo: self.e123o.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3o)
+				+ self.e13oi.anti_wedge(rhs.e2o)
+				+ self.e23oi.anti_wedge(rhs.e1o),
+			ei: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e23oi.anti_wedge(rhs.e1i),
+			e12: self.e123i.anti_wedge(rhs.e12o) + self.e123o.anti_wedge(rhs.e12i) + self.e12oi.anti_wedge(rhs.e123),
+			e13: self.e123i.anti_wedge(rhs.e13o) + self.e123o.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e123),
+			e1o: self.e123o.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13o) + self.e13oi.anti_wedge(rhs.e12o),
+			e1i: self.e123i.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e12i),
+			e23: self.e123i.anti_wedge(rhs.e23o) + self.e123o.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e123),
+			e2o: self.e123o.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e12o),
+			e2i: self.e123i.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e12i),
+			e3o: self.e123o.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e13o),
+			e3i: self.e123i.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e13i),
+			eoi: self.e12oi.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e2oi) + self.e23oi.anti_wedge(rhs.e1oi),
+			e123: self.e123i.anti_wedge(rhs.e123o) + self.e123o.anti_wedge(rhs.e123i),
+			e12o: self.e123o.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123o),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e123o.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123o),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e123o.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123o),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+			e123o: self.e123o.anti_wedge(rhs.e123oi),
+			e123i: self.e123i.anti_wedge(rhs.e123oi),
+			e12oi: self.e12oi.anti_wedge(rhs.e123oi),
+			e13oi: self.e13oi.anti_wedge(rhs.e123oi),
+			e23oi: self.e23oi.anti_wedge(rhs.e123oi),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Sphere {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Sphere.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Sphere {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e1    : -E1(self.e23oi.0 * rhs.e123oi.0),
+		//     e2    : E2(self.e13oi.0 * rhs.e123oi.0),
+		//     e3    : -E3(self.e12oi.0 * rhs.e123oi.0),
+		//     eo    : Eo(self.e123o.0 * rhs.e123oi.0),
+		//     ei    : -Ei(self.e123i.0 * rhs.e123oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e1: -E1(self.e23oi.0 * rhs.e123oi.0),
+			e2: E2(self.e13oi.0 * rhs.e123oi.0),
+			e3: -E3(self.e12oi.0 * rhs.e123oi.0),
+			eo: Eo(self.e123o.0 * rhs.e123oi.0),
+			ei: -Ei(self.e123i.0 * rhs.e123oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Sphere.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Sphere {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o   : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i   : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23   : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o   : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o   : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i   : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi   : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e23oi.0 * rhs.eo.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+		//     e123o : E123o(self.e123o.0 * rhs.s.0),
+		//     e123i : E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.s.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e1: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+			e13o: E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0),
+			e13i: E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+			e23o: -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e23oi.0 * rhs.eo.0),
+			e23i: -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Sphere.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Sphere {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Sphere.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Sphere {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13   : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o   : -E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i   : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23   : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o   : E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0),
+		//     e2i   : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o   : -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi   : -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e23oi.0 * rhs.eo.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+		//     e123o : E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i : -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi : E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi : -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi : E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+			e13o: E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0),
+			e13i: E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+			e23o: -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e23oi.0 * rhs.eo.0),
+			e23i: -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Sphere.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Sphere {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : -E12(self.e13oi.0 * rhs.e1.0) - E12(self.e23oi.0 * rhs.e2.0),
+		//     e13   : E13(self.e12oi.0 * rhs.e1.0) - E13(self.e23oi.0 * rhs.e3.0),
+		//     e1o   : -E1o(self.e123o.0 * rhs.e1.0) - E1o(self.e23oi.0 * rhs.eo.0),
+		//     e1i   : E1i(self.e123i.0 * rhs.e1.0) - E1i(self.e23oi.0 * rhs.ei.0),
+		//     e23   : E23(self.e12oi.0 * rhs.e2.0) + E23(self.e13oi.0 * rhs.e3.0),
+		//     e2o   : -E2o(self.e123o.0 * rhs.e2.0) + E2o(self.e13oi.0 * rhs.eo.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e13oi.0 * rhs.ei.0),
+		//     e3o   : -E3o(self.e123o.0 * rhs.e3.0) - E3o(self.e12oi.0 * rhs.eo.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e3.0) - E3i(self.e12oi.0 * rhs.ei.0),
+		//     eoi   : Eoi(self.e123i.0 * rhs.eo.0) + Eoi(self.e123o.0 * rhs.ei.0),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		//     e123o : -E123o(self.e123o.0 * rhs.e123.0) + E123o(self.e123o.0 * rhs.e123oi.0) - E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13oi.0 * rhs.e13o.0) - E123o(self.e23oi.0 * rhs.e23o.0),
+		//     e123i : E123i(self.e123i.0 * rhs.e123.0) + E123i(self.e123i.0 * rhs.e123oi.0) - E123i(self.e12oi.0 * rhs.e12i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e23oi.0 * rhs.e23i.0),
+		//     e12oi : E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0) + E12oi(self.e12oi.0 * rhs.e123oi.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) - E12oi(self.e23oi.0 * rhs.e2oi.0),
+		//     e13oi : E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) + E13oi(self.e13oi.0 * rhs.e123oi.0) - E13oi(self.e23oi.0 * rhs.e3oi.0),
+		//     e23oi : E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0) + E23oi(self.e23oi.0 * rhs.e123oi.0),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: -E12(self.e13oi.0 * rhs.e1.0) - E12(self.e23oi.0 * rhs.e2.0),
+			e13: E13(self.e12oi.0 * rhs.e1.0) - E13(self.e23oi.0 * rhs.e3.0),
+			e1o: -E1o(self.e123o.0 * rhs.e1.0) - E1o(self.e23oi.0 * rhs.eo.0),
+			e1i: E1i(self.e123i.0 * rhs.e1.0) - E1i(self.e23oi.0 * rhs.ei.0),
+			e23: E23(self.e12oi.0 * rhs.e2.0) + E23(self.e13oi.0 * rhs.e3.0),
+			e2o: -E2o(self.e123o.0 * rhs.e2.0) + E2o(self.e13oi.0 * rhs.eo.0),
+			e2i: E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e13oi.0 * rhs.ei.0),
+			e3o: -E3o(self.e123o.0 * rhs.e3.0) - E3o(self.e12oi.0 * rhs.eo.0),
+			e3i: E3i(self.e123i.0 * rhs.e3.0) - E3i(self.e12oi.0 * rhs.ei.0),
+			eoi: Eoi(self.e123i.0 * rhs.eo.0) + Eoi(self.e123o.0 * rhs.ei.0),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+			e123o: -E123o(self.e123o.0 * rhs.e123.0) + E123o(self.e123o.0 * rhs.e123oi.0)
+				- E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0),
+			e123i: E123i(self.e123i.0 * rhs.e123.0) + E123i(self.e123i.0 * rhs.e123oi.0)
+				- E123i(self.e12oi.0 * rhs.e12i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e12o.0)
+				+ E12oi(self.e123o.0 * rhs.e12i.0)
+				+ E12oi(self.e12oi.0 * rhs.e123oi.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0),
+			e13oi: E13oi(self.e123i.0 * rhs.e13o.0)
+				+ E13oi(self.e123o.0 * rhs.e13i.0)
+				+ E13oi(self.e12oi.0 * rhs.e1oi.0)
+				+ E13oi(self.e13oi.0 * rhs.e123oi.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0)
+				+ E23oi(self.e23oi.0 * rhs.e123oi.0),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
@@ -0,0 +1,5976 @@
+//! # Translator
+//!
+//! ## Operations
+//! ```text
+//! Translator.geometric(Translator) -> Translator
+//! Translator.anti_geometric(Translator) -> Multivector
+//! Translator.dot(Translator) -> Translator
+//! Translator.wedge(Translator) -> Translator
+//! Translator.left_contraction(Translator) -> Translator
+//! Translator.right_contraction(Translator) -> Translator
+//! Translator.scalar_product(Translator) -> S
+//! Translator.anti_dot(Translator) -> Multivector
+//! Translator.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Vec3) -> Versor
+//! Vec3.anti_geometric(Translator) -> Versor
+//! Translator.dot(Vec3) -> Point
+//! Vec3.dot(Translator) -> Point
+//! Translator.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Translator) -> Multivector
+//! Translator.left_contraction(Vec3) -> Vec3
+//! Vec3.left_contraction(Translator) -> Ei
+//! Translator.right_contraction(Vec3) -> Ei
+//! Vec3.right_contraction(Translator) -> Vec3
+//! Translator.commutator(Vec3) -> Ei
+//! Vec3.commutator(Translator) -> Ei
+//! Translator.anti_dot(Vec3) -> Plane
+//! Vec3.anti_dot(Translator) -> Plane
+//! Translator.geometric(Point) -> Multivector
+//! Point.geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Point) -> Versor
+//! Point.anti_geometric(Translator) -> Versor
+//! Translator.dot(Point) -> Point
+//! Point.dot(Translator) -> Point
+//! Translator.wedge(Point) -> Multivector
+//! Point.wedge(Translator) -> Multivector
+//! Translator.left_contraction(Point) -> Point
+//! Point.left_contraction(Translator) -> Point
+//! Translator.right_contraction(Point) -> Point
+//! Point.right_contraction(Translator) -> Point
+//! Translator.commutator(Point) -> Point
+//! Point.commutator(Translator) -> Point
+//! Translator.anti_dot(Point) -> Sphere
+//! Point.anti_dot(Translator) -> Sphere
+//! Translator.geometric(FlatPoint) -> FlatPoint
+//! FlatPoint.geometric(Translator) -> FlatPoint
+//! Translator.anti_geometric(FlatPoint) -> Circle
+//! FlatPoint.anti_geometric(Translator) -> Circle
+//! Translator.dot(FlatPoint) -> FlatPoint
+//! FlatPoint.dot(Translator) -> FlatPoint
+//! Translator.wedge(FlatPoint) -> FlatPoint
+//! FlatPoint.wedge(Translator) -> FlatPoint
+//! Translator.left_contraction(FlatPoint) -> FlatPoint
+//! FlatPoint.right_contraction(Translator) -> FlatPoint
+//! Translator.commutator(FlatPoint) -> FlatPoint
+//! FlatPoint.commutator(Translator) -> FlatPoint
+//! Translator.anti_dot(FlatPoint) -> Circle
+//! FlatPoint.anti_dot(Translator) -> Circle
+//! Translator.geometric(PointPair) -> Versor
+//! PointPair.geometric(Translator) -> Versor
+//! Translator.anti_geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(Translator) -> Multivector
+//! Translator.dot(PointPair) -> Versor
+//! PointPair.dot(Translator) -> Versor
+//! Translator.wedge(PointPair) -> Versor
+//! PointPair.wedge(Translator) -> Versor
+//! Translator.left_contraction(PointPair) -> Versor
+//! PointPair.left_contraction(Translator) -> S
+//! Translator.right_contraction(PointPair) -> S
+//! PointPair.right_contraction(Translator) -> Versor
+//! Translator.scalar_product(PointPair) -> S
+//! PointPair.scalar_product(Translator) -> S
+//! Translator.commutator(PointPair) -> PointPair
+//! PointPair.commutator(Translator) -> PointPair
+//! Translator.anti_dot(PointPair) -> Multivector
+//! PointPair.anti_dot(Translator) -> Multivector
+//! Translator.geometric(Line) -> Multivector
+//! Line.geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Line) -> Versor
+//! Line.anti_geometric(Translator) -> Versor
+//! Translator.dot(Line) -> Multivector
+//! Line.dot(Translator) -> Multivector
+//! Translator.wedge(Line) -> Line
+//! Line.wedge(Translator) -> Line
+//! Translator.left_contraction(Line) -> Multivector
+//! Line.right_contraction(Translator) -> Multivector
+//! Translator.commutator(Line) -> Line
+//! Line.commutator(Translator) -> Line
+//! Translator.anti_dot(Line) -> Versor
+//! Line.anti_dot(Translator) -> Versor
+//! Translator.geometric(Circle) -> Multivector
+//! Circle.geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Circle) -> Versor
+//! Circle.anti_geometric(Translator) -> Versor
+//! Translator.dot(Circle) -> Multivector
+//! Circle.dot(Translator) -> Multivector
+//! Translator.wedge(Circle) -> Multivector
+//! Circle.wedge(Translator) -> Multivector
+//! Translator.anti_wedge(Circle) -> S
+//! Circle.anti_wedge(Translator) -> S
+//! Translator.left_contraction(Circle) -> Multivector
+//! Circle.right_contraction(Translator) -> Multivector
+//! Translator.commutator(Circle) -> Circle
+//! Circle.commutator(Translator) -> Circle
+//! Translator.anti_dot(Circle) -> Versor
+//! Circle.anti_dot(Translator) -> Versor
+//! Translator.geometric(Plane) -> Versor
+//! Plane.geometric(Translator) -> Versor
+//! Translator.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Translator) -> Multivector
+//! Translator.dot(Plane) -> Versor
+//! Plane.dot(Translator) -> Versor
+//! Translator.wedge(Plane) -> Plane
+//! Plane.wedge(Translator) -> Plane
+//! Translator.anti_wedge(Plane) -> Ei
+//! Plane.anti_wedge(Translator) -> Ei
+//! Translator.left_contraction(Plane) -> Versor
+//! Plane.right_contraction(Translator) -> Versor
+//! Translator.commutator(Plane) -> E123i
+//! Plane.commutator(Translator) -> E123i
+//! Translator.anti_dot(Plane) -> Multivector
+//! Plane.anti_dot(Translator) -> Multivector
+//! Translator.geometric(Sphere) -> Versor
+//! Sphere.geometric(Translator) -> Versor
+//! Translator.anti_geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(Translator) -> Multivector
+//! Translator.dot(Sphere) -> Versor
+//! Sphere.dot(Translator) -> Versor
+//! Translator.wedge(Sphere) -> Sphere
+//! Sphere.wedge(Translator) -> Sphere
+//! Translator.anti_wedge(Sphere) -> Point
+//! Sphere.anti_wedge(Translator) -> Point
+//! Translator.left_contraction(Sphere) -> Versor
+//! Sphere.right_contraction(Translator) -> Versor
+//! Translator.commutator(Sphere) -> Plane
+//! Sphere.commutator(Translator) -> Plane
+//! Translator.anti_dot(Sphere) -> Multivector
+//! Sphere.anti_dot(Translator) -> Multivector
+//! Translator.geometric(Rotor) -> Versor
+//! Rotor.geometric(Translator) -> Versor
+//! Translator.anti_geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Translator) -> Multivector
+//! Translator.dot(Rotor) -> Versor
+//! Rotor.dot(Translator) -> Versor
+//! Translator.wedge(Rotor) -> Versor
+//! Rotor.wedge(Translator) -> Versor
+//! Translator.left_contraction(Rotor) -> Rotor
+//! Rotor.left_contraction(Translator) -> Translator
+//! Translator.right_contraction(Rotor) -> Translator
+//! Rotor.right_contraction(Translator) -> Rotor
+//! Translator.scalar_product(Rotor) -> S
+//! Rotor.scalar_product(Translator) -> S
+//! Translator.commutator(Rotor) -> FlatPoint
+//! Rotor.commutator(Translator) -> FlatPoint
+//! Translator.anti_dot(Rotor) -> Multivector
+//! Rotor.anti_dot(Translator) -> Multivector
+//! Translator.geometric(Versor) -> Versor
+//! Versor.geometric(Translator) -> Versor
+//! Translator.anti_geometric(Versor) -> Multivector
+//! Versor.anti_geometric(Translator) -> Multivector
+//! Translator.dot(Versor) -> Versor
+//! Versor.dot(Translator) -> Versor
+//! Translator.wedge(Versor) -> Versor
+//! Versor.wedge(Translator) -> Versor
+//! Translator.anti_wedge(Versor) -> Point
+//! Versor.anti_wedge(Translator) -> Point
+//! Translator.left_contraction(Versor) -> Versor
+//! Versor.left_contraction(Translator) -> Translator
+//! Translator.right_contraction(Versor) -> Translator
+//! Versor.right_contraction(Translator) -> Versor
+//! Translator.scalar_product(Versor) -> S
+//! Versor.scalar_product(Translator) -> S
+//! Translator.commutator(Versor) -> Versor
+//! Versor.commutator(Translator) -> Versor
+//! Translator.anti_dot(Versor) -> Multivector
+//! Versor.anti_dot(Translator) -> Multivector
+//! Translator.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Translator) -> Multivector
+//! Translator.dot(Multivector) -> Multivector
+//! Multivector.dot(Translator) -> Multivector
+//! Translator.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Translator) -> Multivector
+//! Translator.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Translator) -> Multivector
+//! Translator.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Translator) -> Multivector
+//! Translator.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Translator) -> Multivector
+//! Translator.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Translator) -> S
+//! Translator.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Translator) -> Multivector
+//! Translator.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Translator) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Translator {
+	pub s: S,
+	pub e1i: E1i,
+	pub e2i: E2i,
+	pub e3i: E3i,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Translator {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Translator {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Translator {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.e1i.abs_diff_eq(&other.e1i, epsilon)
+			&& self.e2i.abs_diff_eq(&other.e2i, epsilon)
+			&& self.e3i.abs_diff_eq(&other.e3i, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Translator {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.e1i.relative_eq(&other.e1i, epsilon, max_relative)
+			&& self.e2i.relative_eq(&other.e2i, epsilon, max_relative)
+			&& self.e3i.relative_eq(&other.e3i, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Translator {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: self.e3i.rcompl(),
+			e12i: Default::default(),
+			e13o: self.e2i.rcompl(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: self.e1i.rcompl(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Translator {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Translator {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: self.e3i.lcompl(),
+			e12i: Default::default(),
+			e13o: self.e2i.lcompl(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: self.e1i.lcompl(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.s.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Translator {
+	fn rev(self) -> Self {
+		Translator {
+			s: self.s,
+			e1i: -self.e1i,
+			e2i: -self.e2i,
+			e3i: -self.e3i,
+		}
+	}
+}
+
+impl AntiReverse for Translator {
+	fn arev(self) -> Self {
+		Translator {
+			s: self.s,
+			e1i: -self.e1i,
+			e2i: -self.e2i,
+			e3i: -self.e3i,
+		}
+	}
+}
+
+impl Involute for Translator {
+	fn involute(self) -> Self {
+		Translator {
+			s: self.s,
+			e1i: self.e1i,
+			e2i: self.e2i,
+			e3i: self.e3i,
+		}
+	}
+}
+
+impl Conjugate for Translator {
+	fn conjugate(self) -> Self {
+		Translator {
+			s: self.s,
+			e1i: -self.e1i,
+			e2i: -self.e2i,
+			e3i: -self.e3i,
+		}
+	}
+}
+
+impl Dual for Translator {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: self.e3i.dual(),
+			e12i: Default::default(),
+			e13o: self.e2i.dual(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: self.e1i.dual(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.s.dual(),
+		}
+	}
+}
+
+// Omitted: Translator.square()  (not a scalar)
+// Omitted: Norm for Translator  (the bulk and weight need a diagonal metric)
+
+impl Translator {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.s.0, self.e1i.0, self.e2i.0, self.e3i.0]
+	}
+}
+
+impl From<[f64; 4]> for Translator {
+	fn from(a: [f64; 4]) -> Self {
+		Translator {
+			s: S(a[0]),
+			e1i: E1i(a[1]),
+			e2i: E2i(a[2]),
+			e3i: E3i(a[3]),
+		}
+	}
+}
+
+impl From<Translator> for [f64; 4] {
+	fn from(v: Translator) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Translator> for Multivector {
+	fn from(v: Translator) -> Self {
+		Multivector {
+			s: v.s,
+			e1i: v.e1i,
+			e2i: v.e2i,
+			e3i: v.e3i,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Translator {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Translator` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.eo.0 == 0.0
+			&& mv.ei.0 == 0.0
+			&& mv.e12.0 == 0.0
+			&& mv.e13.0 == 0.0
+			&& mv.e1o.0 == 0.0
+			&& mv.e23.0 == 0.0
+			&& mv.e2o.0 == 0.0
+			&& mv.e3o.0 == 0.0
+			&& mv.eoi.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e12o.0 == 0.0
+			&& mv.e12i.0 == 0.0
+			&& mv.e13o.0 == 0.0
+			&& mv.e13i.0 == 0.0
+			&& mv.e1oi.0 == 0.0
+			&& mv.e23o.0 == 0.0
+			&& mv.e23i.0 == 0.0
+			&& mv.e2oi.0 == 0.0
+			&& mv.e3oi.0 == 0.0
+			&& mv.e123o.0 == 0.0
+			&& mv.e123i.0 == 0.0
+			&& mv.e12oi.0 == 0.0
+			&& mv.e13oi.0 == 0.0
+			&& mv.e23oi.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(Translator {
+				s: mv.s,
+				e1i: mv.e1i,
+				e2i: mv.e2i,
+				e3i: mv.e3i,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Translator {
+	/// The matrix that transforms `[x, y, z, o, inf]` like `self.sandwich(v)` for a `Point` `v`,
+	/// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+	pub fn to_matrix(self) -> [[f64; 5]; 5] {
+		[
+			[self.s.0 * self.s.0, 0.0, 0.0, -2.0 * self.e1i.0 * self.s.0, 0.0],
+			[0.0, self.s.0 * self.s.0, 0.0, -2.0 * self.e2i.0 * self.s.0, 0.0],
+			[0.0, 0.0, self.s.0 * self.s.0, -2.0 * self.e3i.0 * self.s.0, 0.0],
+			[0.0, 0.0, 0.0, self.s.0 * self.s.0, 0.0],
+			[
+				-2.0 * self.e1i.0 * self.s.0,
+				-2.0 * self.e2i.0 * self.s.0,
+				-2.0 * self.e3i.0 * self.s.0,
+				2.0 * self.e1i.0 * self.e1i.0 + 2.0 * self.e2i.0 * self.e2i.0 + 2.0 * self.e3i.0 * self.e3i.0,
+				self.s.0 * self.s.0,
+			],
+		]
+	}
+}
+
+impl Translator {
+	/// The versor that moves nothing, whose matrix is the identity matrix.
+	pub fn identity() -> Self {
+		Translator {
+			s: S(1.0),
+			e1i: E1i(0.0),
+			e2i: E2i(0.0),
+			e3i: E3i(0.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Vec3:
+
+// Translator.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Translator {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.x.0),
+		//     e2    : E2(self.s.0 * rhs.y.0),
+		//     e3    : E3(self.s.0 * rhs.z.0),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.s.0 * rhs.x.0),
+			e2: E2(self.s.0 * rhs.y.0),
+			e3: E3(self.s.0 * rhs.z.0),
+			eo: Default::default(),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Translator {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Vec3) -> Versor
+impl AntiGeometric<Vec3> for Translator {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+		//     e12oi: -E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: -E23oi(self.s.0 * rhs.x.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: -E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+			e12oi: -E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.s.0 * rhs.y.0),
+			e23oi: -E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// Translator.dot(Vec3) -> Point
+impl Dot<Vec3> for Translator {
+	type Output = Point;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Point {
+		//     x  : E1(self.s.0 * rhs.x.0),
+		//     y  : E2(self.s.0 * rhs.y.0),
+		//     z  : E3(self.s.0 * rhs.z.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		// }
+		Point {
+			x: E1(self.s.0 * rhs.x.0),
+			y: E2(self.s.0 * rhs.y.0),
+			z: E3(self.s.0 * rhs.z.0),
+			o: Default::default(),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Translator {
+	type Output = Point;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Translator {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.x.0),
+		//     e2    : E2(self.s.0 * rhs.y.0),
+		//     e3    : E3(self.s.0 * rhs.z.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.s.wedge(rhs.x),
+			e2: self.s.wedge(rhs.y),
+			e3: self.s.wedge(rhs.z),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.e1i.wedge(rhs.y) + self.e2i.wedge(rhs.x),
+			e13o: Default::default(),
+			e13i: self.e1i.wedge(rhs.z) + self.e3i.wedge(rhs.x),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: self.e2i.wedge(rhs.z) + self.e3i.wedge(rhs.y),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Translator {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for Translator {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Translator {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Vec3) -> Vec3
+impl LeftContraction<Vec3> for Translator {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.s.0 * rhs.x.0),
+		//     y: E2(self.s.0 * rhs.y.0),
+		//     z: E3(self.s.0 * rhs.z.0),
+		// }
+		Vec3 {
+			x: E1(self.s.0 * rhs.x.0),
+			y: E2(self.s.0 * rhs.y.0),
+			z: E3(self.s.0 * rhs.z.0),
+		}
+	}
+}
+
+// Translator.right_contraction(Vec3) -> Ei
+impl RightContraction<Vec3> for Translator {
+	type Output = Ei;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+		-Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+	}
+}
+
+impl ScalarProduct<Vec3> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(Vec3) -> Ei
+impl Commutator<Vec3> for Translator {
+	type Output = Ei;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+		-Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+	}
+}
+
+// Translator.anti_dot(Vec3) -> Plane
+impl AntiDot<Vec3> for Translator {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+		//     e12oi: -E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: -E23oi(self.s.0 * rhs.x.0),
+		// }
+		Plane {
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+			e12oi: -E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.s.0 * rhs.y.0),
+			e23oi: -E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Point:
+
+// Translator.geometric(Point) -> Multivector
+impl Geometric<Point> for Translator {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e1i.0 * rhs.o.0) + E1(self.s.0 * rhs.x.0),
+		//     e2    : -E2(self.e2i.0 * rhs.o.0) + E2(self.s.0 * rhs.y.0),
+		//     e3    : -E3(self.e3i.0 * rhs.o.0) + E3(self.s.0 * rhs.z.0),
+		//     eo    : Eo(self.s.0 * rhs.o.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.s.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.o.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : -E2oi(self.e2i.0 * rhs.o.0),
+		//     e3oi  : -E3oi(self.e3i.0 * rhs.o.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e1i.0 * rhs.o.0) + E1(self.s.0 * rhs.x.0),
+			e2: -E2(self.e2i.0 * rhs.o.0) + E2(self.s.0 * rhs.y.0),
+			e3: -E3(self.e3i.0 * rhs.o.0) + E3(self.s.0 * rhs.z.0),
+			eo: Eo(self.s.0 * rhs.o.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.s.0 * rhs.inf.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+			e1oi: -E1oi(self.e1i.0 * rhs.o.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+			e2oi: -E2oi(self.e2i.0 * rhs.o.0),
+			e3oi: -E3oi(self.e3i.0 * rhs.o.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for Translator {
+	type Output = Multivector;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Point) -> Versor
+impl AntiGeometric<Point> for Translator {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e3i.0 * rhs.o.0),
+		//     e13  : -E13(self.e2i.0 * rhs.o.0),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+		//     e23  : E23(self.e1i.0 * rhs.o.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.s.0 * rhs.o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0) - E123i(self.s.0 * rhs.inf.0),
+		//     e12oi: -E12oi(self.e3i.0 * rhs.o.0) - E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.e2i.0 * rhs.o.0) + E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: -E23oi(self.e1i.0 * rhs.o.0) - E23oi(self.s.0 * rhs.x.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e3i.0 * rhs.o.0),
+			e13: -E13(self.e2i.0 * rhs.o.0),
+			e1o: Default::default(),
+			e1i: -E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+			e23: E23(self.e1i.0 * rhs.o.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+			eoi: Default::default(),
+			e123o: E123o(self.s.0 * rhs.o.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0)
+				- E123i(self.e2i.0 * rhs.y.0)
+				- E123i(self.e3i.0 * rhs.z.0)
+				- E123i(self.s.0 * rhs.inf.0),
+			e12oi: -E12oi(self.e3i.0 * rhs.o.0) - E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.e2i.0 * rhs.o.0) + E13oi(self.s.0 * rhs.y.0),
+			e23oi: -E23oi(self.e1i.0 * rhs.o.0) - E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// Translator.dot(Point) -> Point
+impl Dot<Point> for Translator {
+	type Output = Point;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e1i.0 * rhs.o.0) + E1(self.s.0 * rhs.x.0),
+		//     y  : -E2(self.e2i.0 * rhs.o.0) + E2(self.s.0 * rhs.y.0),
+		//     z  : -E3(self.e3i.0 * rhs.o.0) + E3(self.s.0 * rhs.z.0),
+		//     o  : Eo(self.s.0 * rhs.o.0),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.s.0 * rhs.inf.0),
+		// }
+		Point {
+			x: -E1(self.e1i.0 * rhs.o.0) + E1(self.s.0 * rhs.x.0),
+			y: -E2(self.e2i.0 * rhs.o.0) + E2(self.s.0 * rhs.y.0),
+			z: -E3(self.e3i.0 * rhs.o.0) + E3(self.s.0 * rhs.z.0),
+			o: Eo(self.s.0 * rhs.o.0),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.s.0 * rhs.inf.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point> for Translator {
+	type Output = Point;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Point) -> Multivector
+impl Wedge<Point> for Translator {
+	type Output = Multivector;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.x.0),
+		//     e2    : E2(self.s.0 * rhs.y.0),
+		//     e3    : E3(self.s.0 * rhs.z.0),
+		//     eo    : Eo(self.s.0 * rhs.o.0),
+		//     ei    : Ei(self.s.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.o.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : -E2oi(self.e2i.0 * rhs.o.0),
+		//     e3oi  : -E3oi(self.e3i.0 * rhs.o.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.s.wedge(rhs.x),
+			e2: self.s.wedge(rhs.y),
+			e3: self.s.wedge(rhs.z),
+			eo: self.s.wedge(rhs.o),
+			ei: self.s.wedge(rhs.inf),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.e1i.wedge(rhs.y) + self.e2i.wedge(rhs.x),
+			e13o: Default::default(),
+			e13i: self.e1i.wedge(rhs.z) + self.e3i.wedge(rhs.x),
+			e1oi: self.e1i.wedge(rhs.o),
+			e23o: Default::default(),
+			e23i: self.e2i.wedge(rhs.z) + self.e3i.wedge(rhs.y),
+			e2oi: self.e2i.wedge(rhs.o),
+			e3oi: self.e3i.wedge(rhs.o),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point> for Translator {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point> for Translator {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point> for Translator {
+	type Output = Zero;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Point) -> Point
+impl LeftContraction<Point> for Translator {
+	type Output = Point;
+	fn left_contraction(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : E1(self.s.0 * rhs.x.0),
+		//     y  : E2(self.s.0 * rhs.y.0),
+		//     z  : E3(self.s.0 * rhs.z.0),
+		//     o  : Eo(self.s.0 * rhs.o.0),
+		//     inf: Ei(self.s.0 * rhs.inf.0),
+		// }
+		Point {
+			x: E1(self.s.0 * rhs.x.0),
+			y: E2(self.s.0 * rhs.y.0),
+			z: E3(self.s.0 * rhs.z.0),
+			o: Eo(self.s.0 * rhs.o.0),
+			inf: Ei(self.s.0 * rhs.inf.0),
+		}
+	}
+}
+
+// Translator.right_contraction(Point) -> Point
+impl RightContraction<Point> for Translator {
+	type Output = Point;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e1i.0 * rhs.o.0),
+		//     y  : -E2(self.e2i.0 * rhs.o.0),
+		//     z  : -E3(self.e3i.0 * rhs.o.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		// }
+		Point {
+			x: -E1(self.e1i.0 * rhs.o.0),
+			y: -E2(self.e2i.0 * rhs.o.0),
+			z: -E3(self.e3i.0 * rhs.o.0),
+			o: Default::default(),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		}
+	}
+}
+
+impl ScalarProduct<Point> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(Point) -> Point
+impl Commutator<Point> for Translator {
+	type Output = Point;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e1i.0 * rhs.o.0),
+		//     y  : -E2(self.e2i.0 * rhs.o.0),
+		//     z  : -E3(self.e3i.0 * rhs.o.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		// }
+		Point {
+			x: -E1(self.e1i.0 * rhs.o.0),
+			y: -E2(self.e2i.0 * rhs.o.0),
+			z: -E3(self.e3i.0 * rhs.o.0),
+			o: Default::default(),
+			inf: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		}
+	}
+}
+
+// Translator.anti_dot(Point) -> Sphere
+impl AntiDot<Point> for Translator {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.s.0 * rhs.o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0) - E123i(self.s.0 * rhs.inf.0),
+		//     e12oi: -E12oi(self.e3i.0 * rhs.o.0) - E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.e2i.0 * rhs.o.0) + E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: -E23oi(self.e1i.0 * rhs.o.0) - E23oi(self.s.0 * rhs.x.0),
+		// }
+		Sphere {
+			e123o: E123o(self.s.0 * rhs.o.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0)
+				- E123i(self.e2i.0 * rhs.y.0)
+				- E123i(self.e3i.0 * rhs.z.0)
+				- E123i(self.s.0 * rhs.inf.0),
+			e12oi: -E12oi(self.e3i.0 * rhs.o.0) - E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.e2i.0 * rhs.o.0) + E13oi(self.s.0 * rhs.y.0),
+			e23oi: -E23oi(self.e1i.0 * rhs.o.0) - E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP FlatPoint:
+
+// Translator.geometric(FlatPoint) -> FlatPoint
+impl Geometric<FlatPoint> for Translator {
+	type Output = FlatPoint;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// FlatPoint {
+		//     e1i: -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: -E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: -E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi: Eoi(self.s.0 * rhs.eoi.0),
+		// }
+		FlatPoint {
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.s.0 * rhs.e1i.0),
+			e2i: -E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3i: -E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for Translator {
+	type Output = FlatPoint;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(FlatPoint) -> Circle
+impl AntiGeometric<FlatPoint> for Translator {
+	type Output = Circle;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.s.0 * rhs.eoi.0),
+		//     e12o: Default::default(),
+		//     e12i: -E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o: Default::default(),
+		//     e13i: E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi: Default::default(),
+		//     e23o: Default::default(),
+		//     e23i: -E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Circle {
+			e123: -E123(self.s.0 * rhs.eoi.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.s.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.s.0 * rhs.e2i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.s.0 * rhs.e1i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// Translator.dot(FlatPoint) -> FlatPoint
+impl Dot<FlatPoint> for Translator {
+	type Output = FlatPoint;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.s.0 * rhs.e3i.0),
+		//     eoi: Eoi(self.s.0 * rhs.eoi.0),
+		// }
+		FlatPoint {
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for Translator {
+	type Output = FlatPoint;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(FlatPoint) -> FlatPoint
+impl Wedge<FlatPoint> for Translator {
+	type Output = FlatPoint;
+	fn wedge(self, rhs: FlatPoint) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.s.0 * rhs.e3i.0),
+		//     eoi: Eoi(self.s.0 * rhs.eoi.0),
+		// }
+		FlatPoint {
+			e1i: self.s.wedge(rhs.e1i),
+			e2i: self.s.wedge(rhs.e2i),
+			e3i: self.s.wedge(rhs.e3i),
+			eoi: self.s.wedge(rhs.eoi),
+		}
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for Translator {
+	type Output = FlatPoint;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<FlatPoint> for Translator {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for Translator {
+	type Output = Zero;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(FlatPoint) -> FlatPoint
+impl LeftContraction<FlatPoint> for Translator {
+	type Output = FlatPoint;
+	fn left_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// FlatPoint {
+		//     e1i: E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.s.0 * rhs.e3i.0),
+		//     eoi: Eoi(self.s.0 * rhs.eoi.0),
+		// }
+		FlatPoint {
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+impl RightContraction<FlatPoint> for Translator {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<FlatPoint> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(FlatPoint) -> FlatPoint
+impl Commutator<FlatPoint> for Translator {
+	type Output = FlatPoint;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// FlatPoint {
+		//     e1i: -E1i(self.e1i.0 * rhs.eoi.0),
+		//     e2i: -E2i(self.e2i.0 * rhs.eoi.0),
+		//     e3i: -E3i(self.e3i.0 * rhs.eoi.0),
+		//     eoi: Default::default(),
+		// }
+		FlatPoint {
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0),
+			e2i: -E2i(self.e2i.0 * rhs.eoi.0),
+			e3i: -E3i(self.e3i.0 * rhs.eoi.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Translator.anti_dot(FlatPoint) -> Circle
+impl AntiDot<FlatPoint> for Translator {
+	type Output = Circle;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.s.0 * rhs.eoi.0),
+		//     e12o: Default::default(),
+		//     e12i: -E12i(self.s.0 * rhs.e3i.0),
+		//     e13o: Default::default(),
+		//     e13i: E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi: Default::default(),
+		//     e23o: Default::default(),
+		//     e23i: -E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Circle {
+			e123: -E123(self.s.0 * rhs.eoi.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.s.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.s.0 * rhs.e2i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.s.0 * rhs.e1i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP PointPair:
+
+// Translator.geometric(PointPair) -> Versor
+impl Geometric<PointPair> for Translator {
+	type Output = Versor;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0),
+		//     e12  : E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0),
+		//     e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e3i.0 * rhs.e1o.0),
+		//     e23oi: E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0),
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0) + E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0) + E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0) + E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0),
+			e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e3i.0 * rhs.e1o.0),
+			e23oi: E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for Translator {
+	type Output = Versor;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(PointPair) -> Multivector
+impl AntiGeometric<PointPair> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e3o.0) + E2(self.e3i.0 * rhs.e1o.0),
+		//     e3    : E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e3i.0 * rhs.e23.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : -E1oi(self.e2i.0 * rhs.e3o.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e1i.0 * rhs.e3o.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e2o.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0),
+			e2: -E2(self.e1i.0 * rhs.e3o.0) + E2(self.e3i.0 * rhs.e1o.0),
+			e3: E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.s.0 * rhs.e2o.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: -E1oi(self.e2i.0 * rhs.e3o.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.s.0 * rhs.e23.0),
+			e23o: E23o(self.s.0 * rhs.e1o.0),
+			e23i: -E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e1i.0 * rhs.e3o.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.s.0 * rhs.e13.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e2o.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0),
+		}
+	}
+}
+
+// Translator.dot(PointPair) -> Versor
+impl Dot<PointPair> for Translator {
+	type Output = Versor;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<PointPair> for Translator {
+	type Output = Versor;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(PointPair) -> Versor
+impl Wedge<PointPair> for Translator {
+	type Output = Versor;
+	fn wedge(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0),
+		//     e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e3i.0 * rhs.e1o.0),
+		//     e23oi: E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: self.s.wedge(rhs.e12),
+			e13: self.s.wedge(rhs.e13),
+			e1o: self.s.wedge(rhs.e1o),
+			e1i: self.s.wedge(rhs.e1i),
+			e23: self.s.wedge(rhs.e23),
+			e2o: self.s.wedge(rhs.e2o),
+			e2i: self.s.wedge(rhs.e2i),
+			e3o: self.s.wedge(rhs.e3o),
+			e3i: self.s.wedge(rhs.e3i),
+			eoi: self.s.wedge(rhs.eoi),
+			e123o: Default::default(),
+			e123i: self.e1i.wedge(rhs.e23) + self.e2i.wedge(rhs.e13) + self.e3i.wedge(rhs.e12),
+			e12oi: self.e1i.wedge(rhs.e2o) + self.e2i.wedge(rhs.e1o),
+			e13oi: self.e1i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e1o),
+			e23oi: self.e2i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e2o),
+		}
+	}
+}
+
+impl std::ops::BitXor<PointPair> for Translator {
+	type Output = Versor;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<PointPair> for Translator {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for Translator {
+	type Output = Zero;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(PointPair) -> Versor
+impl LeftContraction<PointPair> for Translator {
+	type Output = Versor;
+	fn left_contraction(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Translator.right_contraction(PointPair) -> S
+impl RightContraction<PointPair> for Translator {
+	type Output = S;
+	fn right_contraction(self, rhs: PointPair) -> Self::Output {
+		// S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0)
+		S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0)
+	}
+}
+
+// Translator.scalar_product(PointPair) -> S
+impl ScalarProduct<PointPair> for Translator {
+	type Output = S;
+	fn scalar_product(self, rhs: PointPair) -> Self::Output {
+		// S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0)
+		S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0)
+	}
+}
+
+// Translator.commutator(PointPair) -> PointPair
+impl Commutator<PointPair> for Translator {
+	type Output = PointPair;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+		//     e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+		//     e1o: Default::default(),
+		//     e1i: -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e23: E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+		//     e2o: Default::default(),
+		//     e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3o: Default::default(),
+		//     e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0),
+		//     eoi: Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+		// }
+		PointPair {
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+			e1o: Default::default(),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+			e23: E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+		}
+	}
+}
+
+// Translator.anti_dot(PointPair) -> Multivector
+impl AntiDot<PointPair> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : -E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : -E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : -E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.s.0 * rhs.eoi.0),
+			e12o: E12o(self.s.0 * rhs.e3o.0),
+			e12i: -E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.s.0 * rhs.e23.0),
+			e23o: E23o(self.s.0 * rhs.e1o.0),
+			e23i: -E23i(self.s.0 * rhs.e1i.0),
+			e2oi: -E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Line:
+
+// Translator.geometric(Line) -> Multivector
+impl Geometric<Line> for Translator {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) + E12i(self.s.0 * rhs.e12i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Translator {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Line) -> Versor
+impl AntiGeometric<Line> for Translator {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : -E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e3i.0 * rhs.e1oi.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e2oi.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.s.0 * rhs.e2oi.0),
+			e1o: Default::default(),
+			e1i: -E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0) + E1i(self.s.0 * rhs.e23i.0),
+			e23: -E23(self.s.0 * rhs.e1oi.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e3i.0 * rhs.e1oi.0) - E2i(self.s.0 * rhs.e13i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e2oi.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.s.0 * rhs.e12i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Translator.dot(Line) -> Multivector
+impl Dot<Line> for Translator {
+	type Output = Multivector;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Translator {
+	type Output = Multivector;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Line) -> Line
+impl Wedge<Line> for Translator {
+	type Output = Line;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.s.0 * rhs.e12i.0),
+		//     e13i: E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23i: E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+		// }
+		Line {
+			e12i: self.s.wedge(rhs.e12i),
+			e13i: self.s.wedge(rhs.e13i),
+			e1oi: self.s.wedge(rhs.e1oi),
+			e23i: self.s.wedge(rhs.e23i),
+			e2oi: self.s.wedge(rhs.e2oi),
+			e3oi: self.s.wedge(rhs.e3oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line> for Translator {
+	type Output = Line;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Line> for Translator {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Translator {
+	type Output = Zero;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Line) -> Multivector
+impl LeftContraction<Line> for Translator {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Line> for Translator {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(Line) -> Line
+impl Commutator<Line> for Translator {
+	type Output = Line;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0),
+		//     e13i: E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e3i.0 * rhs.e1oi.0),
+		//     e1oi: Default::default(),
+		//     e23i: E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Line {
+			e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0),
+			e13i: E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e3i.0 * rhs.e1oi.0),
+			e1oi: Default::default(),
+			e23i: E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// Translator.anti_dot(Line) -> Versor
+impl AntiDot<Line> for Translator {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : -E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.s.0 * rhs.e2oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.s.0 * rhs.e23i.0),
+			e23: -E23(self.s.0 * rhs.e1oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.s.0 * rhs.e13i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.s.0 * rhs.e12i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Circle:
+
+// Translator.geometric(Circle) -> Multivector
+impl Geometric<Circle> for Translator {
+	type Output = Multivector;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0),
+		//     e3    : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0) + E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e3i.0 * rhs.e12o.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0),
+			e2: -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0),
+			e3: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0)
+				+ E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0) + E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0) + E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0) + E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0)
+				- E123oi(self.e3i.0 * rhs.e12o.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for Translator {
+	type Output = Multivector;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Circle) -> Versor
+impl AntiGeometric<Circle> for Translator {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0),
+		//     e12  : -E12(self.e1i.0 * rhs.e13o.0) - E12(self.e2i.0 * rhs.e23o.0) - E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.e1i.0 * rhs.e12o.0) - E13(self.e3i.0 * rhs.e23o.0) + E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : -E1o(self.s.0 * rhs.e23o.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.e123.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : E23(self.e2i.0 * rhs.e12o.0) + E23(self.e3i.0 * rhs.e13o.0) - E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : E2o(self.s.0 * rhs.e13o.0),
+		//     e2i  : E2i(self.e1i.0 * rhs.e3oi.0) + E2i(self.e2i.0 * rhs.e123.0) - E2i(self.e3i.0 * rhs.e1oi.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : -E3o(self.s.0 * rhs.e12o.0),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e2oi.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.e3i.0 * rhs.e123.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e23o.0) - Eoi(self.e2i.0 * rhs.e13o.0) + Eoi(self.e3i.0 * rhs.e12o.0) + Eoi(self.s.0 * rhs.e123.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0),
+		//     e13oi: -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e3i.0 * rhs.e23o.0),
+		//     e23oi: -E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0),
+		// }
+		Versor {
+			s: -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0),
+			e12: -E12(self.e1i.0 * rhs.e13o.0) - E12(self.e2i.0 * rhs.e23o.0) - E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.e1i.0 * rhs.e12o.0) - E13(self.e3i.0 * rhs.e23o.0) + E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.e1i.0 * rhs.e123.0) - E1i(self.e2i.0 * rhs.e3oi.0)
+				+ E1i(self.e3i.0 * rhs.e2oi.0)
+				+ E1i(self.s.0 * rhs.e23i.0),
+			e23: E23(self.e2i.0 * rhs.e12o.0) + E23(self.e3i.0 * rhs.e13o.0) - E23(self.s.0 * rhs.e1oi.0),
+			e2o: E2o(self.s.0 * rhs.e13o.0),
+			e2i: E2i(self.e1i.0 * rhs.e3oi.0) + E2i(self.e2i.0 * rhs.e123.0)
+				- E2i(self.e3i.0 * rhs.e1oi.0)
+				- E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.s.0 * rhs.e12o.0),
+			e3i: -E3i(self.e1i.0 * rhs.e2oi.0)
+				+ E3i(self.e2i.0 * rhs.e1oi.0)
+				+ E3i(self.e3i.0 * rhs.e123.0)
+				+ E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e23o.0) - Eoi(self.e2i.0 * rhs.e13o.0)
+				+ Eoi(self.e3i.0 * rhs.e12o.0)
+				+ Eoi(self.s.0 * rhs.e123.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0),
+			e13oi: -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e3i.0 * rhs.e23o.0),
+			e23oi: -E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0),
+		}
+	}
+}
+
+// Translator.dot(Circle) -> Multivector
+impl Dot<Circle> for Translator {
+	type Output = Multivector;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0),
+		//     e3    : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0),
+			e2: -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0),
+			e3: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Circle> for Translator {
+	type Output = Multivector;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Circle) -> Multivector
+impl Wedge<Circle> for Translator {
+	type Output = Multivector;
+	fn wedge(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e3i.0 * rhs.e12o.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.s.wedge(rhs.e123),
+			e12o: self.s.wedge(rhs.e12o),
+			e12i: self.s.wedge(rhs.e12i),
+			e13o: self.s.wedge(rhs.e13o),
+			e13i: self.s.wedge(rhs.e13i),
+			e1oi: self.s.wedge(rhs.e1oi),
+			e23o: self.s.wedge(rhs.e23o),
+			e23i: self.s.wedge(rhs.e23i),
+			e2oi: self.s.wedge(rhs.e2oi),
+			e3oi: self.s.wedge(rhs.e3oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e1i.wedge(rhs.e23o) + self.e2i.wedge(rhs.e13o) + self.e3i.wedge(rhs.e12o),
+		}
+	}
+}
+
+impl std::ops::BitXor<Circle> for Translator {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Circle) -> S
+impl AntiWedge<Circle> for Translator {
+	type Output = S;
+	fn anti_wedge(self, rhs: Circle) -> Self::Output {
+		// -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0)
+		self.e1i.anti_wedge(rhs.e23o) + self.e2i.anti_wedge(rhs.e13o) + self.e3i.anti_wedge(rhs.e12o)
+	}
+}
+
+impl std::ops::BitAnd<Circle> for Translator {
+	type Output = S;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Circle) -> Multivector
+impl LeftContraction<Circle> for Translator {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0),
+		//     e3    : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0),
+			e2: -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0),
+			e3: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Circle> for Translator {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Circle> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(Circle) -> Circle
+impl Commutator<Circle> for Translator {
+	type Output = Circle;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0),
+		//     e12o: Default::default(),
+		//     e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0),
+		//     e13o: Default::default(),
+		//     e13i: E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0),
+		//     e1oi: E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0),
+		//     e23o: Default::default(),
+		//     e23i: -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0),
+		//     e2oi: -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0),
+		//     e3oi: -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0),
+		// }
+		Circle {
+			e123: -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0),
+			e1oi: E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0),
+			e2oi: -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0),
+		}
+	}
+}
+
+// Translator.anti_dot(Circle) -> Versor
+impl AntiDot<Circle> for Translator {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : -E1o(self.s.0 * rhs.e23o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : -E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : E2o(self.s.0 * rhs.e13o.0),
+		//     e2i  : -E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : -E3o(self.s.0 * rhs.e12o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.e123.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0),
+		//     e13oi: -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e3i.0 * rhs.e23o.0),
+		//     e23oi: -E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.s.0 * rhs.e23i.0),
+			e23: -E23(self.s.0 * rhs.e1oi.0),
+			e2o: E2o(self.s.0 * rhs.e13o.0),
+			e2i: -E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.s.0 * rhs.e12o.0),
+			e3i: E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.s.0 * rhs.e123.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0),
+			e13oi: -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e3i.0 * rhs.e23o.0),
+			e23oi: -E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Plane:
+
+// Translator.geometric(Plane) -> Versor
+impl Geometric<Plane> for Translator {
+	type Output = Versor;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Translator {
+	type Output = Versor;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0)
+				+ Ei(self.e3i.0 * rhs.e12oi.0)
+				+ Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Translator.dot(Plane) -> Versor
+impl Dot<Plane> for Translator {
+	type Output = Versor;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Translator {
+	type Output = Versor;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Plane) -> Plane
+impl Wedge<Plane> for Translator {
+	type Output = Plane;
+	fn wedge(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Plane {
+			e123i: self.s.wedge(rhs.e123i),
+			e12oi: self.s.wedge(rhs.e12oi),
+			e13oi: self.s.wedge(rhs.e13oi),
+			e23oi: self.s.wedge(rhs.e23oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Translator {
+	type Output = Plane;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Plane) -> Ei
+impl AntiWedge<Plane> for Translator {
+	type Output = Ei;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0)
+		self.e1i.anti_wedge(rhs.e23oi) + self.e2i.anti_wedge(rhs.e13oi) + self.e3i.anti_wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Translator {
+	type Output = Ei;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Plane) -> Versor
+impl LeftContraction<Plane> for Translator {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Plane> for Translator {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(Plane) -> E123i
+impl Commutator<Plane> for Translator {
+	type Output = E123i;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+		-E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+	}
+}
+
+// Translator.anti_dot(Plane) -> Multivector
+impl AntiDot<Plane> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: Default::default(),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Sphere:
+
+// Translator.geometric(Sphere) -> Versor
+impl Geometric<Sphere> for Translator {
+	type Output = Versor;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e3i.0 * rhs.e123o.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: -E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.e1i.0 * rhs.e123o.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e3i.0 * rhs.e123o.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e3i.0 * rhs.e123o.0) + E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: -E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for Translator {
+	type Output = Versor;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Sphere) -> Multivector
+impl AntiGeometric<Sphere> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e1i.0 * rhs.e123o.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.e2i.0 * rhs.e123o.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : -E3(self.e3i.0 * rhs.e123o.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e2i.0 * rhs.e123o.0),
+		//     e3oi  : E3oi(self.e3i.0 * rhs.e123o.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e1i.0 * rhs.e123o.0) + E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.e2i.0 * rhs.e123o.0) - E2(self.s.0 * rhs.e13oi.0),
+			e3: -E3(self.e3i.0 * rhs.e123o.0) + E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0)
+				+ Ei(self.e3i.0 * rhs.e12oi.0)
+				+ Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Translator.dot(Sphere) -> Versor
+impl Dot<Sphere> for Translator {
+	type Output = Versor;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e3i.0 * rhs.e123o.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Sphere> for Translator {
+	type Output = Versor;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Sphere) -> Sphere
+impl Wedge<Sphere> for Translator {
+	type Output = Sphere;
+	fn wedge(self, rhs: Sphere) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Sphere {
+			e123o: self.s.wedge(rhs.e123o),
+			e123i: self.s.wedge(rhs.e123i),
+			e12oi: self.s.wedge(rhs.e12oi),
+			e13oi: self.s.wedge(rhs.e13oi),
+			e23oi: self.s.wedge(rhs.e23oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Sphere> for Translator {
+	type Output = Sphere;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Sphere) -> Point
+impl AntiWedge<Sphere> for Translator {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e1i.0 * rhs.e123o.0),
+		//     y  : -E2(self.e2i.0 * rhs.e123o.0),
+		//     z  : -E3(self.e3i.0 * rhs.e123o.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0),
+		// }
+		Point {
+			x: self.e1i.anti_wedge(rhs.e123o),
+			y: self.e2i.anti_wedge(rhs.e123o),
+			z: self.e3i.anti_wedge(rhs.e123o),
+			o: Default::default(),
+			inf: self.e1i.anti_wedge(rhs.e23oi) + self.e2i.anti_wedge(rhs.e13oi) + self.e3i.anti_wedge(rhs.e12oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for Translator {
+	type Output = Point;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Sphere) -> Versor
+impl LeftContraction<Sphere> for Translator {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e3i.0 * rhs.e123o.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: Default::default(),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Sphere> for Translator {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Sphere> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(Sphere) -> Plane
+impl Commutator<Sphere> for Translator {
+	type Output = Plane;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// Plane {
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0),
+		//     e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+		//     e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+		//     e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+		// }
+		Plane {
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0),
+			e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+			e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+		}
+	}
+}
+
+// Translator.anti_dot(Sphere) -> Multivector
+impl AntiDot<Sphere> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e2i.0 * rhs.e123o.0),
+		//     e3oi  : E3oi(self.e3i.0 * rhs.e123o.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Rotor:
+
+// Translator.geometric(Rotor) -> Versor
+impl Geometric<Rotor> for Translator {
+	type Output = Versor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.s.0 * rhs.s.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) + E3i(self.e3i.0 * rhs.s.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Translator {
+	type Output = Versor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Rotor) -> Multivector
+impl AntiGeometric<Rotor> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0),
+		//     e2oi  : -E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e1i.0 * rhs.e12.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0),
+			e1oi: E1oi(self.s.0 * rhs.e23.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0),
+			e2oi: -E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Translator.dot(Rotor) -> Versor
+impl Dot<Rotor> for Translator {
+	type Output = Versor;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.s.0 * rhs.s.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Translator {
+	type Output = Versor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Rotor) -> Versor
+impl Wedge<Rotor> for Translator {
+	type Output = Versor;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: self.s.wedge(rhs.s),
+			e12: self.s.wedge(rhs.e12),
+			e13: self.s.wedge(rhs.e13),
+			e1o: Default::default(),
+			e1i: self.e1i.wedge(rhs.s),
+			e23: self.s.wedge(rhs.e23),
+			e2o: Default::default(),
+			e2i: self.e2i.wedge(rhs.s),
+			e3o: Default::default(),
+			e3i: self.e3i.wedge(rhs.s),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: self.e1i.wedge(rhs.e23) + self.e2i.wedge(rhs.e13) + self.e3i.wedge(rhs.e12),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Translator {
+	type Output = Versor;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Rotor> for Translator {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Translator {
+	type Output = Zero;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Rotor) -> Rotor
+impl LeftContraction<Rotor> for Translator {
+	type Output = Rotor;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     e12: E12(self.s.0 * rhs.e12.0),
+		//     e13: E13(self.s.0 * rhs.e13.0),
+		//     e23: E23(self.s.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: S(self.s.0 * rhs.s.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Translator.right_contraction(Rotor) -> Translator
+impl RightContraction<Rotor> for Translator {
+	type Output = Translator;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Translator {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     e1i: E1i(self.e1i.0 * rhs.s.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0),
+		// }
+		Translator {
+			s: S(self.s.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+		}
+	}
+}
+
+// Translator.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Translator {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// S(self.s.0 * rhs.s.0)
+		S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Translator.commutator(Rotor) -> FlatPoint
+impl Commutator<Rotor> for Translator {
+	type Output = FlatPoint;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// FlatPoint {
+		//     e1i: -E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0),
+		//     eoi: Default::default(),
+		// }
+		FlatPoint {
+			e1i: -E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e3i.0 * rhs.e23.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Translator.anti_dot(Rotor) -> Multivector
+impl AntiDot<Rotor> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e2i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0),
+		//     e2oi  : -E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e3i.0 * rhs.s.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e2i.0 * rhs.s.0),
+			e1oi: E1oi(self.s.0 * rhs.e23.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.s.0),
+			e2oi: -E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Translator:
+
+// Translator.geometric(Translator) -> Translator
+impl Geometric<Translator> for Translator {
+	type Output = Translator;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     e1i: E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		// }
+		Translator {
+			s: S(self.s.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Translator {
+	type Output = Translator;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Translator) -> Multivector
+impl AntiGeometric<Translator> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e2i.0 * rhs.s.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e2i.0 * rhs.s.0) + E13i(self.s.0 * rhs.e2i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.s.0) - E23i(self.s.0 * rhs.e1i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Translator.dot(Translator) -> Translator
+impl Dot<Translator> for Translator {
+	type Output = Translator;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     e1i: E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		// }
+		Translator {
+			s: S(self.s.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Translator {
+	type Output = Translator;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Translator) -> Translator
+impl Wedge<Translator> for Translator {
+	type Output = Translator;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     e1i: E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		// }
+		Translator {
+			s: self.s.wedge(rhs.s),
+			e1i: self.e1i.wedge(rhs.s) + self.s.wedge(rhs.e1i),
+			e2i: self.e2i.wedge(rhs.s) + self.s.wedge(rhs.e2i),
+			e3i: self.e3i.wedge(rhs.s) + self.s.wedge(rhs.e3i),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Translator {
+	type Output = Translator;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Translator> for Translator {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Translator {
+	type Output = Zero;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Translator) -> Translator
+impl LeftContraction<Translator> for Translator {
+	type Output = Translator;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     e1i: E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.s.0 * rhs.e3i.0),
+		// }
+		Translator {
+			s: S(self.s.0 * rhs.s.0),
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// Translator.right_contraction(Translator) -> Translator
+impl RightContraction<Translator> for Translator {
+	type Output = Translator;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     e1i: E1i(self.e1i.0 * rhs.s.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0),
+		// }
+		Translator {
+			s: S(self.s.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+		}
+	}
+}
+
+// Translator.scalar_product(Translator) -> S
+impl ScalarProduct<Translator> for Translator {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator) -> Self::Output {
+		// S(self.s.0 * rhs.s.0)
+		S(self.s.0 * rhs.s.0)
+	}
+}
+
+impl Commutator<Translator> for Translator {
+	type Output = Zero;
+	fn commutator(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.anti_dot(Translator) -> Multivector
+impl AntiDot<Translator> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e2i.0 * rhs.s.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: -E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e2i.0 * rhs.s.0) + E13i(self.s.0 * rhs.e2i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.s.0) - E23i(self.s.0 * rhs.e1i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Versor:
+
+// Translator.geometric(Versor) -> Versor
+impl Geometric<Versor> for Translator {
+	type Output = Versor;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e1i.0 * rhs.e3o.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e3i.0 * rhs.e1o.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) + E1i(self.e2i.0 * rhs.e12oi.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e2i.0 * rhs.e23oi.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e2i.0 * rhs.e13oi.0) + E123i(self.e3i.0 * rhs.e12.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e3i.0 * rhs.e123o.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e2i.0 * rhs.e123o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.e1i.0 * rhs.e123o.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e3i.0 * rhs.e1o.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) - E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) + E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) - E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e2i.0 * rhs.e13oi.0)
+				+ E123i(self.e3i.0 * rhs.e12.0)
+				- E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0)
+				+ E12oi(self.e3i.0 * rhs.e123o.0)
+				+ E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e2i.0 * rhs.e123o.0) - E13oi(self.e3i.0 * rhs.e1o.0)
+				+ E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0)
+				+ E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for Translator {
+	type Output = Versor;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Versor) -> Multivector
+impl AntiGeometric<Versor> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e1i.0 * rhs.e123o.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e3o.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12.0) + Ei(self.e3i.0 * rhs.e12oi.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0) + E13i(self.e3i.0 * rhs.e23oi.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) - E1oi(self.e2i.0 * rhs.e3o.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13.0) - E23i(self.e3i.0 * rhs.e13oi.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e2o.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e1i.0 * rhs.e123o.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0)
+				+ E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.e1i.0 * rhs.e3o.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e3i.0 * rhs.e1o.0)
+				- E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0) - E3(self.e3i.0 * rhs.e123o.0)
+				+ E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0)
+				- Ei(self.e2i.0 * rhs.e13.0)
+				- Ei(self.e2i.0 * rhs.e13oi.0)
+				+ Ei(self.e3i.0 * rhs.e12.0)
+				+ Ei(self.e3i.0 * rhs.e12oi.0)
+				+ Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e1i.0 * rhs.e13.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				+ E12i(self.e2i.0 * rhs.e23.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.s.0 * rhs.e2o.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) - E1oi(self.e2i.0 * rhs.e3o.0)
+				+ E1oi(self.e3i.0 * rhs.e2o.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: E23o(self.s.0 * rhs.e1o.0),
+			e23i: -E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e2i.0 * rhs.e123o.0)
+				- E2oi(self.e3i.0 * rhs.e1o.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e2o.0)
+				+ E3oi(self.e2i.0 * rhs.e1o.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Translator.dot(Versor) -> Versor
+impl Dot<Versor> for Translator {
+	type Output = Versor;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.s.0 * rhs.e12.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for Translator {
+	type Output = Versor;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Versor) -> Versor
+impl Wedge<Versor> for Translator {
+	type Output = Versor;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: self.s.wedge(rhs.s),
+			e12: self.s.wedge(rhs.e12),
+			e13: self.s.wedge(rhs.e13),
+			e1o: self.s.wedge(rhs.e1o),
+			e1i: self.e1i.wedge(rhs.s) + self.s.wedge(rhs.e1i),
+			e23: self.s.wedge(rhs.e23),
+			e2o: self.s.wedge(rhs.e2o),
+			e2i: self.e2i.wedge(rhs.s) + self.s.wedge(rhs.e2i),
+			e3o: self.s.wedge(rhs.e3o),
+			e3i: self.e3i.wedge(rhs.s) + self.s.wedge(rhs.e3i),
+			eoi: self.s.wedge(rhs.eoi),
+			e123o: self.s.wedge(rhs.e123o),
+			e123i: self.e1i.wedge(rhs.e23)
+				+ self.e2i.wedge(rhs.e13)
+				+ self.e3i.wedge(rhs.e12)
+				+ self.s.wedge(rhs.e123i),
+			e12oi: self.e1i.wedge(rhs.e2o) + self.e2i.wedge(rhs.e1o) + self.s.wedge(rhs.e12oi),
+			e13oi: self.e1i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e1o) + self.s.wedge(rhs.e13oi),
+			e23oi: self.e2i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e2o) + self.s.wedge(rhs.e23oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for Translator {
+	type Output = Versor;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Versor) -> Point
+impl AntiWedge<Versor> for Translator {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e1i.0 * rhs.e123o.0),
+		//     y  : -E2(self.e2i.0 * rhs.e123o.0),
+		//     z  : -E3(self.e3i.0 * rhs.e123o.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0),
+		// }
+		Point {
+			x: self.e1i.anti_wedge(rhs.e123o),
+			y: self.e2i.anti_wedge(rhs.e123o),
+			z: self.e3i.anti_wedge(rhs.e123o),
+			o: Default::default(),
+			inf: self.e1i.anti_wedge(rhs.e23oi) + self.e2i.anti_wedge(rhs.e13oi) + self.e3i.anti_wedge(rhs.e12oi),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Versor> for Translator {
+	type Output = Point;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Versor) -> Versor
+impl LeftContraction<Versor> for Translator {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.s.0 * rhs.e12.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Translator.right_contraction(Versor) -> Translator
+impl RightContraction<Versor> for Translator {
+	type Output = Translator;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Translator {
+		//     s  : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0),
+		//     e1i: E1i(self.e1i.0 * rhs.s.0),
+		//     e2i: E2i(self.e2i.0 * rhs.s.0),
+		//     e3i: E3i(self.e3i.0 * rhs.s.0),
+		// }
+		Translator {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+		}
+	}
+}
+
+// Translator.scalar_product(Versor) -> S
+impl ScalarProduct<Versor> for Translator {
+	type Output = S;
+	fn scalar_product(self, rhs: Versor) -> Self::Output {
+		// S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0)
+		S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Translator.commutator(Versor) -> Versor
+impl Commutator<Versor> for Translator {
+	type Output = Versor;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+		//     e13  : E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e23  : E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+		//     e123o: Default::default(),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0),
+		//     e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+		//     e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+		//     e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+			e1o: Default::default(),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+			e23: E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0),
+			e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+			e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+		}
+	}
+}
+
+// Translator.anti_dot(Versor) -> Multivector
+impl AntiDot<Versor> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23oi.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.s.0 * rhs.eoi.0),
+			e12o: E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.s.0 * rhs.e2o.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.s.0 * rhs.e23.0),
+			e23o: E23o(self.s.0 * rhs.e1o.0),
+			e23i: -E23i(self.e1i.0 * rhs.s.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Multivector:
+
+// Translator.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Translator {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : -E1(self.e1i.0 * rhs.eo.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e12o.0) - E2(self.e2i.0 * rhs.eo.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3    : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e3i.0 * rhs.eo.0) + E3(self.s.0 * rhs.e3.0),
+		//     eo    : Eo(self.s.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e2i.0 * rhs.e2.0) + Ei(self.e2i.0 * rhs.e2oi.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.e3i.0 * rhs.e3oi.0) + Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13   : E13(self.e1i.0 * rhs.e3o.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e3i.0 * rhs.e1o.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o   : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) + E1i(self.e2i.0 * rhs.e12oi.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o   : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e2i.0 * rhs.e23oi.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0) + E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : -E12i(self.e1i.0 * rhs.e2.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e2i.0 * rhs.e1.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e3.0) + E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.e3i.0 * rhs.e1.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.eo.0) + E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e1i.0 * rhs.e123oi.0) - E23i(self.e2i.0 * rhs.e3.0) + E23i(self.e2i.0 * rhs.e3oi.0) + E23i(self.e3i.0 * rhs.e2.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e1i.0 * rhs.e12o.0) - E2oi(self.e2i.0 * rhs.eo.0) + E2oi(self.e3i.0 * rhs.e23o.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0) - E3oi(self.e3i.0 * rhs.eo.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e2i.0 * rhs.e13oi.0) + E123i(self.e3i.0 * rhs.e12.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e3i.0 * rhs.e123o.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e2i.0 * rhs.e123o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : E23oi(self.e1i.0 * rhs.e123o.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: -E1(self.e1i.0 * rhs.eo.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.s.0 * rhs.e1.0),
+			e2: -E2(self.e1i.0 * rhs.e12o.0) - E2(self.e2i.0 * rhs.eo.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.s.0 * rhs.e2.0),
+			e3: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e3i.0 * rhs.eo.0)
+				+ E3(self.s.0 * rhs.e3.0),
+			eo: Eo(self.s.0 * rhs.eo.0),
+			ei: -Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e2i.0 * rhs.e2.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				- Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0)
+				+ Ei(self.s.0 * rhs.ei.0),
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e3i.0 * rhs.e1o.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) - E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) + E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123: -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0)
+				+ E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: -E12i(self.e1i.0 * rhs.e2.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e2i.0 * rhs.e1.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				- E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.e3i.0 * rhs.e123oi.0)
+				+ E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: -E13i(self.e1i.0 * rhs.e3.0) + E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0)
+				- E13i(self.e2i.0 * rhs.e123oi.0)
+				+ E13i(self.e3i.0 * rhs.e1.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.s.0 * rhs.e13i.0),
+			e1oi: -E1oi(self.e1i.0 * rhs.eo.0)
+				+ E1oi(self.e2i.0 * rhs.e12o.0)
+				+ E1oi(self.e3i.0 * rhs.e13o.0)
+				+ E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e1i.0 * rhs.e123oi.0) - E23i(self.e2i.0 * rhs.e3.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				+ E23i(self.e3i.0 * rhs.e2.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e1i.0 * rhs.e12o.0) - E2oi(self.e2i.0 * rhs.eo.0)
+				+ E2oi(self.e3i.0 * rhs.e23o.0)
+				+ E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0) - E3oi(self.e3i.0 * rhs.eo.0)
+				+ E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) - E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e2i.0 * rhs.e13oi.0)
+				+ E123i(self.e3i.0 * rhs.e12.0)
+				- E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0)
+				+ E12oi(self.e3i.0 * rhs.e123o.0)
+				+ E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e2i.0 * rhs.e123o.0) - E13oi(self.e3i.0 * rhs.e1o.0)
+				+ E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0)
+				+ E23oi(self.s.0 * rhs.e23oi.0),
+			e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0)
+				- E123oi(self.e3i.0 * rhs.e12o.0)
+				+ E123oi(self.s.0 * rhs.e123oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Translator {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.s.0 * rhs.e123oi.0),
+		//     e1    : -E1(self.e1i.0 * rhs.e123o.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e3o.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12.0) + Ei(self.e3i.0 * rhs.e12oi.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : -E12(self.e1i.0 * rhs.e13o.0) - E12(self.e2i.0 * rhs.e23o.0) + E12(self.e3i.0 * rhs.eo.0) - E12(self.s.0 * rhs.e3oi.0),
+		//     e13   : E13(self.e1i.0 * rhs.e12o.0) - E13(self.e2i.0 * rhs.eo.0) - E13(self.e3i.0 * rhs.e23o.0) + E13(self.s.0 * rhs.e2oi.0),
+		//     e1o   : -E1o(self.s.0 * rhs.e23o.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.e123.0) + E1i(self.e1i.0 * rhs.e123oi.0) - E1i(self.e2i.0 * rhs.e3.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2.0) + E1i(self.e3i.0 * rhs.e2oi.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23   : E23(self.e1i.0 * rhs.eo.0) + E23(self.e2i.0 * rhs.e12o.0) + E23(self.e3i.0 * rhs.e13o.0) - E23(self.s.0 * rhs.e1oi.0),
+		//     e2o   : E2o(self.s.0 * rhs.e13o.0),
+		//     e2i   : E2i(self.e1i.0 * rhs.e3.0) + E2i(self.e1i.0 * rhs.e3oi.0) + E2i(self.e2i.0 * rhs.e123.0) + E2i(self.e2i.0 * rhs.e123oi.0) - E2i(self.e3i.0 * rhs.e1.0) - E2i(self.e3i.0 * rhs.e1oi.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o   : -E3o(self.s.0 * rhs.e12o.0),
+		//     e3i   : -E3i(self.e1i.0 * rhs.e2.0) - E3i(self.e1i.0 * rhs.e2oi.0) + E3i(self.e2i.0 * rhs.e1.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.e3i.0 * rhs.e123.0) + E3i(self.e3i.0 * rhs.e123oi.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi   : Eoi(self.e1i.0 * rhs.e23o.0) - Eoi(self.e2i.0 * rhs.e13o.0) + Eoi(self.e3i.0 * rhs.e12o.0) + Eoi(self.s.0 * rhs.e123.0),
+		//     e123  : E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0) + E13i(self.e3i.0 * rhs.e23oi.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) - E1oi(self.e2i.0 * rhs.e3o.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13.0) - E23i(self.e3i.0 * rhs.e13oi.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e2o.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : E123o(self.s.0 * rhs.eo.0),
+		//     e123i : -E123i(self.e1i.0 * rhs.e1.0) - E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3.0) - E123i(self.e3i.0 * rhs.e3oi.0) - E123i(self.s.0 * rhs.ei.0),
+		//     e12oi : E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0) - E12oi(self.e3i.0 * rhs.eo.0) - E12oi(self.s.0 * rhs.e3.0),
+		//     e13oi : -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e2i.0 * rhs.eo.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.s.0 * rhs.e2.0),
+		//     e23oi : -E23oi(self.e1i.0 * rhs.eo.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.s.0 * rhs.e1.0),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0)
+				+ S(self.s.0 * rhs.e123oi.0),
+			e1: -E1(self.e1i.0 * rhs.e123o.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e3i.0 * rhs.e2o.0)
+				+ E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.e1i.0 * rhs.e3o.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e3i.0 * rhs.e1o.0)
+				- E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e1i.0 * rhs.e2o.0) - E3(self.e2i.0 * rhs.e1o.0) - E3(self.e3i.0 * rhs.e123o.0)
+				+ E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0)
+				- Ei(self.e2i.0 * rhs.e13.0)
+				- Ei(self.e2i.0 * rhs.e13oi.0)
+				+ Ei(self.e3i.0 * rhs.e12.0)
+				+ Ei(self.e3i.0 * rhs.e12oi.0)
+				+ Ei(self.s.0 * rhs.e123i.0),
+			e12: -E12(self.e1i.0 * rhs.e13o.0) - E12(self.e2i.0 * rhs.e23o.0) + E12(self.e3i.0 * rhs.eo.0)
+				- E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.e1i.0 * rhs.e12o.0) - E13(self.e2i.0 * rhs.eo.0) - E13(self.e3i.0 * rhs.e23o.0)
+				+ E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.e1i.0 * rhs.e123.0) + E1i(self.e1i.0 * rhs.e123oi.0)
+				- E1i(self.e2i.0 * rhs.e3.0)
+				- E1i(self.e2i.0 * rhs.e3oi.0)
+				+ E1i(self.e3i.0 * rhs.e2.0)
+				+ E1i(self.e3i.0 * rhs.e2oi.0)
+				+ E1i(self.s.0 * rhs.e23i.0),
+			e23: E23(self.e1i.0 * rhs.eo.0) + E23(self.e2i.0 * rhs.e12o.0) + E23(self.e3i.0 * rhs.e13o.0)
+				- E23(self.s.0 * rhs.e1oi.0),
+			e2o: E2o(self.s.0 * rhs.e13o.0),
+			e2i: E2i(self.e1i.0 * rhs.e3.0)
+				+ E2i(self.e1i.0 * rhs.e3oi.0)
+				+ E2i(self.e2i.0 * rhs.e123.0)
+				+ E2i(self.e2i.0 * rhs.e123oi.0)
+				- E2i(self.e3i.0 * rhs.e1.0)
+				- E2i(self.e3i.0 * rhs.e1oi.0)
+				- E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.s.0 * rhs.e12o.0),
+			e3i: -E3i(self.e1i.0 * rhs.e2.0) - E3i(self.e1i.0 * rhs.e2oi.0)
+				+ E3i(self.e2i.0 * rhs.e1.0)
+				+ E3i(self.e2i.0 * rhs.e1oi.0)
+				+ E3i(self.e3i.0 * rhs.e123.0)
+				+ E3i(self.e3i.0 * rhs.e123oi.0)
+				+ E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e23o.0) - Eoi(self.e2i.0 * rhs.e13o.0)
+				+ Eoi(self.e3i.0 * rhs.e12o.0)
+				+ Eoi(self.s.0 * rhs.e123.0),
+			e123: E123(self.e1i.0 * rhs.e1o.0) + E123(self.e2i.0 * rhs.e2o.0) + E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e1i.0 * rhs.e13.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				+ E12i(self.e2i.0 * rhs.e23.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.s.0 * rhs.e2o.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) - E1oi(self.e2i.0 * rhs.e3o.0)
+				+ E1oi(self.e3i.0 * rhs.e2o.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: E23o(self.s.0 * rhs.e1o.0),
+			e23i: -E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e2i.0 * rhs.e123o.0)
+				- E2oi(self.e3i.0 * rhs.e1o.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e2o.0)
+				+ E3oi(self.e2i.0 * rhs.e1o.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: E123o(self.s.0 * rhs.eo.0),
+			e123i: -E123i(self.e1i.0 * rhs.e1.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				- E123i(self.e2i.0 * rhs.e2.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0)
+				- E123i(self.s.0 * rhs.ei.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0)
+				- E12oi(self.e3i.0 * rhs.eo.0)
+				- E12oi(self.s.0 * rhs.e3.0),
+			e13oi: -E13oi(self.e1i.0 * rhs.e12o.0)
+				+ E13oi(self.e2i.0 * rhs.eo.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.s.0 * rhs.e2.0),
+			e23oi: -E23oi(self.e1i.0 * rhs.eo.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.s.0 * rhs.e1.0),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Translator.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Translator {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : -E1(self.e1i.0 * rhs.eo.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e12o.0) - E2(self.e2i.0 * rhs.eo.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3    : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e3i.0 * rhs.eo.0) + E3(self.s.0 * rhs.e3.0),
+		//     eo    : Eo(self.s.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e2i.0 * rhs.e2.0) + Ei(self.e2i.0 * rhs.e2oi.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.e3i.0 * rhs.e3oi.0) + Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.e3i.0 * rhs.e123o.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13   : -E13(self.e2i.0 * rhs.e123o.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o   : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.s.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : E23(self.e1i.0 * rhs.e123o.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o   : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : -E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.e1i.0 * rhs.e123oi.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: -E1(self.e1i.0 * rhs.eo.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.s.0 * rhs.e1.0),
+			e2: -E2(self.e1i.0 * rhs.e12o.0) - E2(self.e2i.0 * rhs.eo.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.s.0 * rhs.e2.0),
+			e3: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e3i.0 * rhs.eo.0)
+				+ E3(self.s.0 * rhs.e3.0),
+			eo: Eo(self.s.0 * rhs.eo.0),
+			ei: -Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e2i.0 * rhs.e2.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				- Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0)
+				+ Ei(self.s.0 * rhs.ei.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.s.0 * rhs.e12.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e1i.0 * rhs.s.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: -E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.e1i.0 * rhs.e123oi.0) + E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+			e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Translator {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Translator {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.s.0 * rhs.s.0),
+		//     e1    : E1(self.s.0 * rhs.e1.0),
+		//     e2    : E2(self.s.0 * rhs.e2.0),
+		//     e3    : E3(self.s.0 * rhs.e3.0),
+		//     eo    : Eo(self.s.0 * rhs.eo.0),
+		//     ei    : Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.s.0 * rhs.e12.0),
+		//     e13   : E13(self.s.0 * rhs.e13.0),
+		//     e1o   : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : E23(self.s.0 * rhs.e23.0),
+		//     e2o   : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : -E12i(self.e1i.0 * rhs.e2.0) + E12i(self.e2i.0 * rhs.e1.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e3.0) + E13i(self.e3i.0 * rhs.e1.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.eo.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e2i.0 * rhs.e3.0) + E23i(self.e3i.0 * rhs.e2.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e2i.0 * rhs.eo.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e3i.0 * rhs.eo.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			e1: self.s.wedge(rhs.e1),
+			e2: self.s.wedge(rhs.e2),
+			e3: self.s.wedge(rhs.e3),
+			eo: self.s.wedge(rhs.eo),
+			ei: self.s.wedge(rhs.ei),
+			e12: self.s.wedge(rhs.e12),
+			e13: self.s.wedge(rhs.e13),
+			e1o: self.s.wedge(rhs.e1o),
+			e1i: self.e1i.wedge(rhs.s) + self.s.wedge(rhs.e1i),
+			e23: self.s.wedge(rhs.e23),
+			e2o: self.s.wedge(rhs.e2o),
+			e2i: self.e2i.wedge(rhs.s) + self.s.wedge(rhs.e2i),
+			e3o: self.s.wedge(rhs.e3o),
+			e3i: self.e3i.wedge(rhs.s) + self.s.wedge(rhs.e3i),
+			eoi: self.s.wedge(rhs.eoi),
+			e123: self.s.wedge(rhs.e123),
+			e12o: self.s.wedge(rhs.e12o),
+			e12i: self.e1i.wedge(rhs.e2) + self.e2i.wedge(rhs.e1) + self.s.wedge(rhs.e12i),
+			e13o: self.s.wedge(rhs.e13o),
+			e13i: self.e1i.wedge(rhs.e3) + self.e3i.wedge(rhs.e1) + self.s.wedge(rhs.e13i),
+			e1oi: self.e1i.wedge(rhs.eo) + self.s.wedge(rhs.e1oi),
+			e23o: self.s.wedge(rhs.e23o),
+			e23i: self.e2i.wedge(rhs.e3) + self.e3i.wedge(rhs.e2) + self.s.wedge(rhs.e23i),
+			e2oi: self.e2i.wedge(rhs.eo) + self.s.wedge(rhs.e2oi),
+			e3oi: self.e3i.wedge(rhs.eo) + self.s.wedge(rhs.e3oi),
+			e123o: self.s.wedge(rhs.e123o),
+			e123i: self.e1i.wedge(rhs.e23)
+				+ self.e2i.wedge(rhs.e13)
+				+ self.e3i.wedge(rhs.e12)
+				+ self.s.wedge(rhs.e123i),
+			e12oi: self.e1i.wedge(rhs.e2o) + self.e2i.wedge(rhs.e1o) + self.s.wedge(rhs.e12oi),
+			e13oi: self.e1i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e1o) + self.s.wedge(rhs.e13oi),
+			e23oi: self.e2i.wedge(rhs.e3o) + self.e3i.wedge(rhs.e2o) + self.s.wedge(rhs.e23oi),
+			e123oi: self.e1i.wedge(rhs.e23o)
+				+ self.e2i.wedge(rhs.e13o)
+				+ self.e3i.wedge(rhs.e12o)
+				+ self.s.wedge(rhs.e123oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Translator {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Translator {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e1i.0 * rhs.e23o.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.s.0 * rhs.e123oi.0),
+		//     e1    : -E1(self.e1i.0 * rhs.e123o.0),
+		//     e2    : -E2(self.e2i.0 * rhs.e123o.0),
+		//     e3    : -E3(self.e3i.0 * rhs.e123o.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : E1i(self.e1i.0 * rhs.e123oi.0),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : E2i(self.e2i.0 * rhs.e123oi.0),
+		//     e3o   : Default::default(),
+		//     e3i   : E3i(self.e3i.0 * rhs.e123oi.0),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.e1i.anti_wedge(rhs.e23o)
+				+ self.e2i.anti_wedge(rhs.e13o)
+				+ self.e3i.anti_wedge(rhs.e12o)
+				+ self.s.anti_wedge(rhs.e123oi),
+			e1: self.e1i.anti_wedge(rhs.e123o),
+			e2: self.e2i.anti_wedge(rhs.e123o),
+			e3: self.e3i.anti_wedge(rhs.e123o),
+			eo: Default::default(),
+			ei: self.e1i.anti_wedge(rhs.e23oi) + self.e2i.anti_wedge(rhs.e13oi) + self.e3i.anti_wedge(rhs.e12oi),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: self.e1i.anti_wedge(rhs.e123oi),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: self.e2i.anti_wedge(rhs.e123oi),
+			e3o: Default::default(),
+			e3i: self.e3i.anti_wedge(rhs.e123oi),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Translator {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Translator {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2    : -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3    : -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) + E3(self.s.0 * rhs.e3.0),
+		//     eo    : Eo(self.s.0 * rhs.eo.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e1oi.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0) + Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.e3i.0 * rhs.e123o.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13   : -E13(self.e2i.0 * rhs.e123o.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o   : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : E23(self.e1i.0 * rhs.e123o.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o   : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : -E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.e1i.0 * rhs.e123oi.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: E1(self.e2i.0 * rhs.e12o.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.s.0 * rhs.e1.0),
+			e2: -E2(self.e1i.0 * rhs.e12o.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.s.0 * rhs.e2.0),
+			e3: -E3(self.e1i.0 * rhs.e13o.0) - E3(self.e2i.0 * rhs.e23o.0) + E3(self.s.0 * rhs.e3.0),
+			eo: Eo(self.s.0 * rhs.eo.0),
+			ei: Ei(self.e1i.0 * rhs.e1oi.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0)
+				+ Ei(self.s.0 * rhs.ei.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.s.0 * rhs.e12.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) + E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: -E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.e1i.0 * rhs.e123oi.0) + E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+			e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		}
+	}
+}
+
+// Translator.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Translator {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : -E1(self.e1i.0 * rhs.eo.0),
+		//     e2    : -E2(self.e2i.0 * rhs.eo.0),
+		//     e3    : -E3(self.e3i.0 * rhs.eo.0),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : E1i(self.e1i.0 * rhs.s.0),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o   : Default::default(),
+		//     e3i   : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: -E1(self.e1i.0 * rhs.eo.0),
+			e2: -E2(self.e2i.0 * rhs.eo.0),
+			e3: -E3(self.e3i.0 * rhs.eo.0),
+			eo: Default::default(),
+			ei: -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.e1i.0 * rhs.s.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.e2i.0 * rhs.s.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e3i.0 * rhs.s.0),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Translator.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Translator {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0)
+		S(self.e1i.0 * rhs.e1o.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Translator.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Translator {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e1i.0 * rhs.eo.0),
+		//     e2    : -E2(self.e2i.0 * rhs.eo.0),
+		//     e3    : -E3(self.e3i.0 * rhs.eo.0),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0),
+		//     e12   : E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+		//     e13   : E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+		//     e1o   : Default::default(),
+		//     e1i   : -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e23   : E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+		//     e2o   : Default::default(),
+		//     e2i   : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3o   : Default::default(),
+		//     e3i   : E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0),
+		//     eoi   : Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+		//     e123  : -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0),
+		//     e1oi  : E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0),
+		//     e2oi  : -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0),
+		//     e3oi  : -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0),
+		//     e123o : Default::default(),
+		//     e123i : -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0),
+		//     e12oi : E12oi(self.e3i.0 * rhs.e123o.0),
+		//     e13oi : -E13oi(self.e2i.0 * rhs.e123o.0),
+		//     e23oi : E23oi(self.e1i.0 * rhs.e123o.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e1i.0 * rhs.eo.0),
+			e2: -E2(self.e2i.0 * rhs.eo.0),
+			e3: -E3(self.e3i.0 * rhs.eo.0),
+			eo: Default::default(),
+			ei: -Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0),
+			e12: E12(self.e1i.0 * rhs.e2o.0) - E12(self.e2i.0 * rhs.e1o.0),
+			e13: E13(self.e1i.0 * rhs.e3o.0) - E13(self.e3i.0 * rhs.e1o.0),
+			e1o: Default::default(),
+			e1i: -E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+			e23: E23(self.e2i.0 * rhs.e3o.0) - E23(self.e3i.0 * rhs.e2o.0),
+			e2o: Default::default(),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0),
+			e3o: Default::default(),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) + Eoi(self.e2i.0 * rhs.e2o.0) + Eoi(self.e3i.0 * rhs.e3o.0),
+			e123: -E123(self.e1i.0 * rhs.e23o.0) + E123(self.e2i.0 * rhs.e13o.0) - E123(self.e3i.0 * rhs.e12o.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e1i.0 * rhs.e2oi.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0),
+			e13o: Default::default(),
+			e13i: E13i(self.e1i.0 * rhs.e3oi.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0),
+			e1oi: E1oi(self.e2i.0 * rhs.e12o.0) + E1oi(self.e3i.0 * rhs.e13o.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0),
+			e2oi: -E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e3i.0 * rhs.e23o.0),
+			e3oi: -E3oi(self.e1i.0 * rhs.e13o.0) - E3oi(self.e2i.0 * rhs.e23o.0),
+			e123o: Default::default(),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0),
+			e12oi: E12oi(self.e3i.0 * rhs.e123o.0),
+			e13oi: -E13oi(self.e2i.0 * rhs.e123o.0),
+			e23oi: E23oi(self.e1i.0 * rhs.e123o.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Translator.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.s.0 * rhs.e123oi.0),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : -E12(self.s.0 * rhs.e3oi.0),
+		//     e13   : E13(self.s.0 * rhs.e2oi.0),
+		//     e1o   : -E1o(self.s.0 * rhs.e23o.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.e123oi.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23   : -E23(self.s.0 * rhs.e1oi.0),
+		//     e2o   : E2o(self.s.0 * rhs.e13o.0),
+		//     e2i   : E2i(self.e2i.0 * rhs.e123oi.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o   : -E3o(self.s.0 * rhs.e12o.0),
+		//     e3i   : E3i(self.e3i.0 * rhs.e123oi.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi   : Eoi(self.s.0 * rhs.e123.0),
+		//     e123  : -E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23oi.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : -E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : E123o(self.s.0 * rhs.eo.0),
+		//     e123i : -E123i(self.e1i.0 * rhs.e1.0) - E123i(self.e1i.0 * rhs.e1oi.0) - E123i(self.e2i.0 * rhs.e2.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3.0) - E123i(self.e3i.0 * rhs.e3oi.0) - E123i(self.s.0 * rhs.ei.0),
+		//     e12oi : E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0) - E12oi(self.e3i.0 * rhs.eo.0) - E12oi(self.s.0 * rhs.e3.0),
+		//     e13oi : -E13oi(self.e1i.0 * rhs.e12o.0) + E13oi(self.e2i.0 * rhs.eo.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.s.0 * rhs.e2.0),
+		//     e23oi : -E23oi(self.e1i.0 * rhs.eo.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.s.0 * rhs.e1.0),
+		//     e123oi: -E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: S(self.s.0 * rhs.e123oi.0),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: -E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.e1i.0 * rhs.e123oi.0) + E1i(self.s.0 * rhs.e23i.0),
+			e23: -E23(self.s.0 * rhs.e1oi.0),
+			e2o: E2o(self.s.0 * rhs.e13o.0),
+			e2i: E2i(self.e2i.0 * rhs.e123oi.0) - E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.s.0 * rhs.e12o.0),
+			e3i: E3i(self.e3i.0 * rhs.e123oi.0) + E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.s.0 * rhs.e123.0),
+			e123: -E123(self.s.0 * rhs.eoi.0),
+			e12o: E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.s.0 * rhs.e2o.0),
+			e13i: -E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.s.0 * rhs.e23.0),
+			e23o: E23o(self.s.0 * rhs.e1o.0),
+			e23i: -E23i(self.e1i.0 * rhs.s.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0) - E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.s.0 * rhs.e12.0),
+			e123o: E123o(self.s.0 * rhs.eo.0),
+			e123i: -E123i(self.e1i.0 * rhs.e1.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				- E123i(self.e2i.0 * rhs.e2.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0)
+				- E123i(self.s.0 * rhs.ei.0),
+			e12oi: E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e2i.0 * rhs.e23o.0)
+				- E12oi(self.e3i.0 * rhs.eo.0)
+				- E12oi(self.s.0 * rhs.e3.0),
+			e13oi: -E13oi(self.e1i.0 * rhs.e12o.0)
+				+ E13oi(self.e2i.0 * rhs.eo.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.s.0 * rhs.e2.0),
+			e23oi: -E23oi(self.e1i.0 * rhs.eo.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.s.0 * rhs.e1.0),
+			e123oi: -E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Translator.sandwich(Vec3)  (not a Vec3)
+// Omitted: Translator.anti_sandwich(Vec3)  (not a Vec3)
+
+// Translator.sandwich(Point) -> Point
+impl Sandwich<Point> for Translator {
+	fn sandwich(self, rhs: Point) -> Point {
+		Point {
+			x: E1(self.e1i.0 * self.s.0 * rhs.o.0) * -2.0 + E1(self.s.0 * self.s.0 * rhs.x.0),
+			y: E2(self.e2i.0 * self.s.0 * rhs.o.0) * -2.0 + E2(self.s.0 * self.s.0 * rhs.y.0),
+			z: E3(self.e3i.0 * self.s.0 * rhs.o.0) * -2.0 + E3(self.s.0 * self.s.0 * rhs.z.0),
+			o: Eo(self.s.0 * self.s.0 * rhs.o.0),
+			inf: Ei(self.e1i.0 * self.e1i.0 * rhs.o.0) * 2.0 - Ei(self.e1i.0 * self.s.0 * rhs.x.0) * 2.0
+				+ Ei(self.e2i.0 * self.e2i.0 * rhs.o.0) * 2.0
+				- Ei(self.e2i.0 * self.s.0 * rhs.y.0) * 2.0
+				+ Ei(self.e3i.0 * self.e3i.0 * rhs.o.0) * 2.0
+				- Ei(self.e3i.0 * self.s.0 * rhs.z.0) * 2.0
+				+ Ei(self.s.0 * self.s.0 * rhs.inf.0),
+		}
+	}
+}
+
+// Translator.anti_sandwich(Point) -> Point
+impl AntiSandwich<Point> for Translator {
+	fn anti_sandwich(self, rhs: Point) -> Point {
+		Point {
+			x: E1(self.e1i.0 * self.s.0 * rhs.o.0) * -2.0 - E1(self.s.0 * self.s.0 * rhs.x.0),
+			y: E2(self.e2i.0 * self.s.0 * rhs.o.0) * -2.0 - E2(self.s.0 * self.s.0 * rhs.y.0),
+			z: E3(self.e3i.0 * self.s.0 * rhs.o.0) * -2.0 - E3(self.s.0 * self.s.0 * rhs.z.0),
+			o: -Eo(self.s.0 * self.s.0 * rhs.o.0),
+			inf: Ei(self.e1i.0 * self.e1i.0 * rhs.o.0) * -2.0
+				- Ei(self.e1i.0 * self.s.0 * rhs.x.0) * 2.0
+				- Ei(self.e2i.0 * self.e2i.0 * rhs.o.0) * 2.0
+				- Ei(self.e2i.0 * self.s.0 * rhs.y.0) * 2.0
+				- Ei(self.e3i.0 * self.e3i.0 * rhs.o.0) * 2.0
+				- Ei(self.e3i.0 * self.s.0 * rhs.z.0) * 2.0
+				- Ei(self.s.0 * self.s.0 * rhs.inf.0),
+		}
+	}
+}
+
+// Translator.sandwich(FlatPoint) -> FlatPoint
+impl Sandwich<FlatPoint> for Translator {
+	fn sandwich(self, rhs: FlatPoint) -> FlatPoint {
+		FlatPoint {
+			e1i: E1i(self.e1i.0 * self.s.0 * rhs.eoi.0) * -2.0 + E1i(self.s.0 * self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.e2i.0 * self.s.0 * rhs.eoi.0) * -2.0 + E2i(self.s.0 * self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.e3i.0 * self.s.0 * rhs.eoi.0) * -2.0 + E3i(self.s.0 * self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Translator.anti_sandwich(FlatPoint) -> FlatPoint
+impl AntiSandwich<FlatPoint> for Translator {
+	fn anti_sandwich(self, rhs: FlatPoint) -> FlatPoint {
+		FlatPoint {
+			e1i: E1i(self.e1i.0 * self.s.0 * rhs.eoi.0) * -2.0 - E1i(self.s.0 * self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.e2i.0 * self.s.0 * rhs.eoi.0) * -2.0 - E2i(self.s.0 * self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.e3i.0 * self.s.0 * rhs.eoi.0) * -2.0 - E3i(self.s.0 * self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.s.0 * self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Translator.sandwich(PointPair) -> PointPair
+impl Sandwich<PointPair> for Translator {
+	fn sandwich(self, rhs: PointPair) -> PointPair {
+		PointPair {
+			e12: E12(self.e1i.0 * self.s.0 * rhs.e2o.0) * 2.0 - E12(self.e2i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E12(self.s.0 * self.s.0 * rhs.e12.0),
+			e13: E13(self.e1i.0 * self.s.0 * rhs.e3o.0) * 2.0 - E13(self.e3i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E13(self.s.0 * self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e1i.0 * self.e1i.0 * rhs.e1o.0) * -2.0
+				- E1i(self.e1i.0 * self.e2i.0 * rhs.e2o.0) * 4.0
+				- E1i(self.e1i.0 * self.e3i.0 * rhs.e3o.0) * 4.0
+				- E1i(self.e1i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E1i(self.e2i.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				- E1i(self.e2i.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E1i(self.e3i.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				- E1i(self.e3i.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E1i(self.s.0 * self.s.0 * rhs.e1i.0),
+			e23: E23(self.e2i.0 * self.s.0 * rhs.e3o.0) * 2.0 - E23(self.e3i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E23(self.s.0 * self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * self.e1i.0 * rhs.e2o.0) * 2.0 - E2i(self.e1i.0 * self.e2i.0 * rhs.e1o.0) * 4.0
+				+ E2i(self.e1i.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E2i(self.e2i.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E2i(self.e2i.0 * self.e3i.0 * rhs.e3o.0) * 4.0
+				- E2i(self.e2i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e3i.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				- E2i(self.e3i.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E2i(self.s.0 * self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * self.e1i.0 * rhs.e3o.0) * 2.0 - E3i(self.e1i.0 * self.e3i.0 * rhs.e1o.0) * 4.0
+				+ E3i(self.e1i.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e2i.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				- E3i(self.e2i.0 * self.e3i.0 * rhs.e2o.0) * 4.0
+				+ E3i(self.e2i.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E3i(self.e3i.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E3i(self.e3i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.s.0 * self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e2i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e3i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ Eoi(self.s.0 * self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Translator.anti_sandwich(PointPair) -> PointPair
+impl AntiSandwich<PointPair> for Translator {
+	fn anti_sandwich(self, rhs: PointPair) -> PointPair {
+		PointPair {
+			e12: E12(self.e1i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E12(self.e2i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E12(self.s.0 * self.s.0 * rhs.e12.0),
+			e13: E13(self.e1i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E13(self.e3i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E13(self.s.0 * self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.s.0 * self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e1i.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				+ E1i(self.e1i.0 * self.e2i.0 * rhs.e2o.0) * 4.0
+				+ E1i(self.e1i.0 * self.e3i.0 * rhs.e3o.0) * 4.0
+				- E1i(self.e1i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e2i.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				- E1i(self.e2i.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E1i(self.e3i.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				- E1i(self.e3i.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E1i(self.s.0 * self.s.0 * rhs.e1i.0),
+			e23: E23(self.e2i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E23(self.e3i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E23(self.s.0 * self.s.0 * rhs.e23.0),
+			e2o: -E2o(self.s.0 * self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e1i.0 * self.e1i.0 * rhs.e2o.0) * -2.0
+				+ E2i(self.e1i.0 * self.e2i.0 * rhs.e1o.0) * 4.0
+				+ E2i(self.e1i.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E2i(self.e2i.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				+ E2i(self.e2i.0 * self.e3i.0 * rhs.e3o.0) * 4.0
+				- E2i(self.e2i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E2i(self.e3i.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				- E2i(self.e3i.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E2i(self.s.0 * self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.s.0 * self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.e1i.0 * self.e1i.0 * rhs.e3o.0) * -2.0
+				+ E3i(self.e1i.0 * self.e3i.0 * rhs.e1o.0) * 4.0
+				+ E3i(self.e1i.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E3i(self.e2i.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				+ E3i(self.e2i.0 * self.e3i.0 * rhs.e2o.0) * 4.0
+				+ E3i(self.e2i.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E3i(self.e3i.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E3i(self.e3i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E3i(self.s.0 * self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e2i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e3i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.s.0 * self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Translator.sandwich(Line) -> Line
+impl Sandwich<Line> for Translator {
+	fn sandwich(self, rhs: Line) -> Line {
+		Line {
+			e12i: E12i(self.e1i.0 * self.s.0 * rhs.e2oi.0) * 2.0 - E12i(self.e2i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E12i(self.s.0 * self.s.0 * rhs.e12i.0),
+			e13i: E13i(self.e1i.0 * self.s.0 * rhs.e3oi.0) * 2.0 - E13i(self.e3i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E13i(self.s.0 * self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * self.s.0 * rhs.e1oi.0),
+			e23i: E23i(self.e2i.0 * self.s.0 * rhs.e3oi.0) * 2.0 - E23i(self.e3i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E23i(self.s.0 * self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * self.s.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Translator.anti_sandwich(Line) -> Line
+impl AntiSandwich<Line> for Translator {
+	fn anti_sandwich(self, rhs: Line) -> Line {
+		Line {
+			e12i: E12i(self.e1i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e2i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E12i(self.s.0 * self.s.0 * rhs.e12i.0),
+			e13i: E13i(self.e1i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e3i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.s.0 * self.s.0 * rhs.e13i.0),
+			e1oi: -E1oi(self.s.0 * self.s.0 * rhs.e1oi.0),
+			e23i: E23i(self.e2i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E23i(self.e3i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.s.0 * self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.s.0 * self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.s.0 * self.s.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Translator.sandwich(Circle) -> Circle
+impl Sandwich<Circle> for Translator {
+	fn sandwich(self, rhs: Circle) -> Circle {
+		Circle {
+			e123: E123(self.e1i.0 * self.s.0 * rhs.e23o.0) * -2.0 + E123(self.e2i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E123(self.e3i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E123(self.s.0 * self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e1i.0 * self.e1i.0 * rhs.e12o.0) * -2.0
+				+ E12i(self.e1i.0 * self.e3i.0 * rhs.e23o.0) * 4.0
+				+ E12i(self.e1i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e2i.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				- E12i(self.e2i.0 * self.e3i.0 * rhs.e13o.0) * 4.0
+				- E12i(self.e2i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E12i(self.e3i.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				- E12i(self.e3i.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E12i(self.s.0 * self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.e1i.0 * self.e1i.0 * rhs.e13o.0) * -2.0 - E13i(self.e1i.0 * self.e2i.0 * rhs.e23o.0) * 4.0
+				+ E13i(self.e1i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E13i(self.e2i.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				- E13i(self.e2i.0 * self.e3i.0 * rhs.e12o.0) * 4.0
+				+ E13i(self.e2i.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E13i(self.e3i.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				- E13i(self.e3i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E13i(self.s.0 * self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e2i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e3i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.s.0 * self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.e1i.0 * self.e1i.0 * rhs.e23o.0) * 2.0 - E23i(self.e1i.0 * self.e2i.0 * rhs.e13o.0) * 4.0
+				+ E23i(self.e1i.0 * self.e3i.0 * rhs.e12o.0) * 4.0
+				- E23i(self.e1i.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E23i(self.e2i.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E23i(self.e2i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E23i(self.e3i.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				- E23i(self.e3i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E23i(self.s.0 * self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.e1i.0 * self.s.0 * rhs.e12o.0) * -2.0
+				+ E2oi(self.e3i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.s.0 * self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.e1i.0 * self.s.0 * rhs.e13o.0) * -2.0 - E3oi(self.e2i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E3oi(self.s.0 * self.s.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Translator.anti_sandwich(Circle) -> Circle
+impl AntiSandwich<Circle> for Translator {
+	fn anti_sandwich(self, rhs: Circle) -> Circle {
+		Circle {
+			e123: E123(self.e1i.0 * self.s.0 * rhs.e23o.0) * -2.0 + E123(self.e2i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E123(self.e3i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E123(self.s.0 * self.s.0 * rhs.e123.0),
+			e12o: -E12o(self.s.0 * self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e1i.0 * self.e1i.0 * rhs.e12o.0) * 2.0 - E12i(self.e1i.0 * self.e3i.0 * rhs.e23o.0) * 4.0
+				+ E12i(self.e1i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E12i(self.e2i.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				+ E12i(self.e2i.0 * self.e3i.0 * rhs.e13o.0) * 4.0
+				- E12i(self.e2i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E12i(self.e3i.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				- E12i(self.e3i.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E12i(self.s.0 * self.s.0 * rhs.e12i.0),
+			e13o: -E13o(self.s.0 * self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.e1i.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				+ E13i(self.e1i.0 * self.e2i.0 * rhs.e23o.0) * 4.0
+				+ E13i(self.e1i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e2i.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				+ E13i(self.e2i.0 * self.e3i.0 * rhs.e12o.0) * 4.0
+				+ E13i(self.e2i.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e3i.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				- E13i(self.e3i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.s.0 * self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e2i.0 * self.s.0 * rhs.e12o.0) * 2.0 + E1oi(self.e3i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E1oi(self.s.0 * self.s.0 * rhs.e1oi.0),
+			e23o: -E23o(self.s.0 * self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.e1i.0 * self.e1i.0 * rhs.e23o.0) * -2.0 + E23i(self.e1i.0 * self.e2i.0 * rhs.e13o.0) * 4.0
+				- E23i(self.e1i.0 * self.e3i.0 * rhs.e12o.0) * 4.0
+				- E23i(self.e1i.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e2i.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E23i(self.e2i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E23i(self.e3i.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				- E23i(self.e3i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.s.0 * self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.e1i.0 * self.s.0 * rhs.e12o.0) * -2.0 + E2oi(self.e3i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E2oi(self.s.0 * self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.e1i.0 * self.s.0 * rhs.e13o.0) * -2.0
+				- E3oi(self.e2i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E3oi(self.s.0 * self.s.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Translator.sandwich(Plane) -> Plane
+impl Sandwich<Plane> for Translator {
+	fn sandwich(self, rhs: Plane) -> Plane {
+		Plane {
+			e123i: E123i(self.e1i.0 * self.s.0 * rhs.e23oi.0) * -2.0 + E123i(self.e2i.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				- E123i(self.e3i.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				+ E123i(self.s.0 * self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Translator.anti_sandwich(Plane) -> Plane
+impl AntiSandwich<Plane> for Translator {
+	fn anti_sandwich(self, rhs: Plane) -> Plane {
+		Plane {
+			e123i: E123i(self.e1i.0 * self.s.0 * rhs.e23oi.0) * -2.0 + E123i(self.e2i.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				- E123i(self.e3i.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				- E123i(self.s.0 * self.s.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.s.0 * self.s.0 * rhs.e12oi.0),
+			e13oi: -E13oi(self.s.0 * self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.s.0 * self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Translator.sandwich(Sphere) -> Sphere
+impl Sandwich<Sphere> for Translator {
+	fn sandwich(self, rhs: Sphere) -> Sphere {
+		Sphere {
+			e123o: E123o(self.s.0 * self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e1i.0 * self.e1i.0 * rhs.e123o.0) * -2.0
+				- E123i(self.e1i.0 * self.s.0 * rhs.e23oi.0) * 2.0
+				- E123i(self.e2i.0 * self.e2i.0 * rhs.e123o.0) * 2.0
+				+ E123i(self.e2i.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				- E123i(self.e3i.0 * self.e3i.0 * rhs.e123o.0) * 2.0
+				- E123i(self.e3i.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				+ E123i(self.s.0 * self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e3i.0 * self.s.0 * rhs.e123o.0) * 2.0 + E12oi(self.s.0 * self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e2i.0 * self.s.0 * rhs.e123o.0) * -2.0 + E13oi(self.s.0 * self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.e1i.0 * self.s.0 * rhs.e123o.0) * 2.0 + E23oi(self.s.0 * self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Translator.anti_sandwich(Sphere) -> Sphere
+impl AntiSandwich<Sphere> for Translator {
+	fn anti_sandwich(self, rhs: Sphere) -> Sphere {
+		Sphere {
+			e123o: -E123o(self.s.0 * self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e1i.0 * self.e1i.0 * rhs.e123o.0) * 2.0
+				- E123i(self.e1i.0 * self.s.0 * rhs.e23oi.0) * 2.0
+				+ E123i(self.e2i.0 * self.e2i.0 * rhs.e123o.0) * 2.0
+				+ E123i(self.e2i.0 * self.s.0 * rhs.e13oi.0) * 2.0
+				+ E123i(self.e3i.0 * self.e3i.0 * rhs.e123o.0) * 2.0
+				- E123i(self.e3i.0 * self.s.0 * rhs.e12oi.0) * 2.0
+				- E123i(self.s.0 * self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e3i.0 * self.s.0 * rhs.e123o.0) * 2.0 - E12oi(self.s.0 * self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e2i.0 * self.s.0 * rhs.e123o.0) * -2.0 - E13oi(self.s.0 * self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.e1i.0 * self.s.0 * rhs.e123o.0) * 2.0 - E23oi(self.s.0 * self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
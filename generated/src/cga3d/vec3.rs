@@ -0,0 +1,4572 @@
+//! # Vec3
+//!
+//! ## Operations
+//! ```text
+//! Vec3.geometric(Vec3) -> Rotor
+//! Vec3.anti_geometric(Vec3) -> Multivector
+//! Vec3.dot(Vec3) -> S
+//! Vec3.wedge(Vec3) -> PointPair
+//! Vec3.left_contraction(Vec3) -> S
+//! Vec3.right_contraction(Vec3) -> S
+//! Vec3.scalar_product(Vec3) -> S
+//! Vec3.commutator(Vec3) -> PointPair
+//! Vec3.anti_dot(Vec3) -> E123oi
+//! Vec3.geometric(Point) -> Versor
+//! Point.geometric(Vec3) -> Versor
+//! Vec3.anti_geometric(Point) -> Multivector
+//! Point.anti_geometric(Vec3) -> Multivector
+//! Vec3.dot(Point) -> S
+//! Point.dot(Vec3) -> S
+//! Vec3.wedge(Point) -> PointPair
+//! Point.wedge(Vec3) -> PointPair
+//! Vec3.left_contraction(Point) -> S
+//! Point.left_contraction(Vec3) -> S
+//! Vec3.right_contraction(Point) -> S
+//! Point.right_contraction(Vec3) -> S
+//! Vec3.scalar_product(Point) -> S
+//! Point.scalar_product(Vec3) -> S
+//! Vec3.commutator(Point) -> PointPair
+//! Point.commutator(Vec3) -> PointPair
+//! Vec3.anti_dot(Point) -> E123oi
+//! Point.anti_dot(Vec3) -> E123oi
+//! Vec3.geometric(FlatPoint) -> Multivector
+//! FlatPoint.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(FlatPoint) -> Versor
+//! FlatPoint.anti_geometric(Vec3) -> Versor
+//! Vec3.dot(FlatPoint) -> Ei
+//! FlatPoint.dot(Vec3) -> Ei
+//! Vec3.wedge(FlatPoint) -> Line
+//! FlatPoint.wedge(Vec3) -> Line
+//! Vec3.left_contraction(FlatPoint) -> Ei
+//! FlatPoint.right_contraction(Vec3) -> Ei
+//! Vec3.commutator(FlatPoint) -> Ei
+//! FlatPoint.commutator(Vec3) -> Ei
+//! Vec3.anti_dot(FlatPoint) -> E123i
+//! FlatPoint.anti_dot(Vec3) -> E123i
+//! Vec3.geometric(PointPair) -> Multivector
+//! PointPair.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(PointPair) -> Versor
+//! PointPair.anti_geometric(Vec3) -> Versor
+//! Vec3.dot(PointPair) -> Point
+//! PointPair.dot(Vec3) -> Point
+//! Vec3.wedge(PointPair) -> Circle
+//! PointPair.wedge(Vec3) -> Circle
+//! Vec3.left_contraction(PointPair) -> Point
+//! PointPair.right_contraction(Vec3) -> Point
+//! Vec3.commutator(PointPair) -> Point
+//! PointPair.commutator(Vec3) -> Point
+//! Vec3.anti_dot(PointPair) -> Sphere
+//! PointPair.anti_dot(Vec3) -> Sphere
+//! Vec3.geometric(Line) -> Versor
+//! Line.geometric(Vec3) -> Versor
+//! Vec3.anti_geometric(Line) -> Multivector
+//! Line.anti_geometric(Vec3) -> Multivector
+//! Vec3.dot(Line) -> FlatPoint
+//! Line.dot(Vec3) -> FlatPoint
+//! Vec3.wedge(Line) -> Plane
+//! Line.wedge(Vec3) -> Plane
+//! Vec3.left_contraction(Line) -> FlatPoint
+//! Line.right_contraction(Vec3) -> FlatPoint
+//! Vec3.commutator(Line) -> Plane
+//! Line.commutator(Vec3) -> Plane
+//! Vec3.anti_dot(Line) -> Circle
+//! Line.anti_dot(Vec3) -> Circle
+//! Vec3.geometric(Circle) -> Versor
+//! Circle.geometric(Vec3) -> Versor
+//! Vec3.anti_geometric(Circle) -> Multivector
+//! Circle.anti_geometric(Vec3) -> Multivector
+//! Vec3.dot(Circle) -> PointPair
+//! Circle.dot(Vec3) -> PointPair
+//! Vec3.wedge(Circle) -> Sphere
+//! Circle.wedge(Vec3) -> Sphere
+//! Vec3.left_contraction(Circle) -> PointPair
+//! Circle.right_contraction(Vec3) -> PointPair
+//! Vec3.commutator(Circle) -> Sphere
+//! Circle.commutator(Vec3) -> Sphere
+//! Vec3.anti_dot(Circle) -> Circle
+//! Circle.anti_dot(Vec3) -> Circle
+//! Vec3.geometric(Plane) -> Multivector
+//! Plane.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Plane) -> Versor
+//! Plane.anti_geometric(Vec3) -> Versor
+//! Vec3.dot(Plane) -> Line
+//! Plane.dot(Vec3) -> Line
+//! Vec3.wedge(Plane) -> E123oi
+//! Plane.wedge(Vec3) -> E123oi
+//! Vec3.anti_wedge(Plane) -> S
+//! Plane.anti_wedge(Vec3) -> S
+//! Vec3.left_contraction(Plane) -> Line
+//! Plane.right_contraction(Vec3) -> Line
+//! Vec3.commutator(Plane) -> Line
+//! Plane.commutator(Vec3) -> Line
+//! Vec3.anti_dot(Plane) -> PointPair
+//! Plane.anti_dot(Vec3) -> PointPair
+//! Vec3.geometric(Sphere) -> Multivector
+//! Sphere.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Sphere) -> Versor
+//! Sphere.anti_geometric(Vec3) -> Versor
+//! Vec3.dot(Sphere) -> Circle
+//! Sphere.dot(Vec3) -> Circle
+//! Vec3.wedge(Sphere) -> E123oi
+//! Sphere.wedge(Vec3) -> E123oi
+//! Vec3.anti_wedge(Sphere) -> S
+//! Sphere.anti_wedge(Vec3) -> S
+//! Vec3.left_contraction(Sphere) -> Circle
+//! Sphere.right_contraction(Vec3) -> Circle
+//! Vec3.commutator(Sphere) -> Circle
+//! Sphere.commutator(Vec3) -> Circle
+//! Vec3.anti_dot(Sphere) -> PointPair
+//! Sphere.anti_dot(Vec3) -> PointPair
+//! Vec3.geometric(Rotor) -> Multivector
+//! Rotor.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Rotor) -> Versor
+//! Rotor.anti_geometric(Vec3) -> Versor
+//! Vec3.dot(Rotor) -> Vec3
+//! Rotor.dot(Vec3) -> Vec3
+//! Vec3.wedge(Rotor) -> Multivector
+//! Rotor.wedge(Vec3) -> Multivector
+//! Vec3.left_contraction(Rotor) -> Vec3
+//! Rotor.left_contraction(Vec3) -> Vec3
+//! Vec3.right_contraction(Rotor) -> Vec3
+//! Rotor.right_contraction(Vec3) -> Vec3
+//! Vec3.commutator(Rotor) -> Vec3
+//! Rotor.commutator(Vec3) -> Vec3
+//! Vec3.anti_dot(Rotor) -> Plane
+//! Rotor.anti_dot(Vec3) -> Plane
+//! Vec3.geometric(Translator) -> Multivector
+//! Translator.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Translator) -> Versor
+//! Translator.anti_geometric(Vec3) -> Versor
+//! Vec3.dot(Translator) -> Point
+//! Translator.dot(Vec3) -> Point
+//! Vec3.wedge(Translator) -> Multivector
+//! Translator.wedge(Vec3) -> Multivector
+//! Vec3.left_contraction(Translator) -> Ei
+//! Translator.left_contraction(Vec3) -> Vec3
+//! Vec3.right_contraction(Translator) -> Vec3
+//! Translator.right_contraction(Vec3) -> Ei
+//! Vec3.commutator(Translator) -> Ei
+//! Translator.commutator(Vec3) -> Ei
+//! Vec3.anti_dot(Translator) -> Plane
+//! Translator.anti_dot(Vec3) -> Plane
+//! Vec3.geometric(Versor) -> Multivector
+//! Versor.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Versor) -> Versor
+//! Versor.anti_geometric(Vec3) -> Versor
+//! Vec3.dot(Versor) -> Multivector
+//! Versor.dot(Vec3) -> Multivector
+//! Vec3.wedge(Versor) -> Multivector
+//! Versor.wedge(Vec3) -> Multivector
+//! Vec3.anti_wedge(Versor) -> S
+//! Versor.anti_wedge(Vec3) -> S
+//! Vec3.left_contraction(Versor) -> Multivector
+//! Versor.left_contraction(Vec3) -> Vec3
+//! Vec3.right_contraction(Versor) -> Vec3
+//! Versor.right_contraction(Vec3) -> Multivector
+//! Vec3.commutator(Versor) -> Multivector
+//! Versor.commutator(Vec3) -> Multivector
+//! Vec3.anti_dot(Versor) -> Versor
+//! Versor.anti_dot(Vec3) -> Versor
+//! Vec3.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec3) -> Multivector
+//! Vec3.dot(Multivector) -> Multivector
+//! Multivector.dot(Vec3) -> Multivector
+//! Vec3.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Vec3) -> Multivector
+//! Vec3.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec3) -> Multivector
+//! Vec3.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec3) -> Multivector
+//! Vec3.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec3) -> Multivector
+//! Vec3.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Vec3) -> S
+//! Vec3.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Vec3) -> Multivector
+//! Vec3.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec3) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec3 {
+	pub x: E1,
+	pub y: E2,
+	pub z: E3,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Vec3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.z.abs_diff_eq(&other.z, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Vec3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.z.relative_eq(&other.z, epsilon, max_relative)
+	}
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector3<f64>> for Vec3 {
+	fn from(v: mint::Vector3<f64>) -> Self {
+		Vec3 {
+			x: E1(v.x),
+			y: E2(v.y),
+			z: E3(v.z),
+		}
+	}
+}
+
+#[cfg(feature = "mint")]
+impl From<Vec3> for mint::Vector3<f64> {
+	fn from(v: Vec3) -> Self {
+		mint::Vector3 {
+			x: v.x.0,
+			y: v.y.0,
+			z: v.z.0,
+		}
+	}
+}
+
+#[cfg(feature = "mint")]
+impl mint::IntoMint for Vec3 {
+	type MintType = mint::Vector3<f64>;
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Vec3 {
+	type Output = Plane;
+	fn rcompl(self) -> Self::Output {
+		Plane {
+			e123i: Default::default(),
+			e12oi: self.z.rcompl(),
+			e13oi: self.y.rcompl(),
+			e23oi: self.x.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Vec3 {
+	type Output = Plane;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Vec3 {
+	type Output = Plane;
+	fn lcompl(self) -> Self::Output {
+		Plane {
+			e123i: Default::default(),
+			e12oi: self.z.lcompl(),
+			e13oi: self.y.lcompl(),
+			e23oi: self.x.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Vec3 {
+	fn rev(self) -> Self {
+		Vec3 {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+		}
+	}
+}
+
+impl AntiReverse for Vec3 {
+	fn arev(self) -> Self {
+		Vec3 {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+		}
+	}
+}
+
+impl Involute for Vec3 {
+	fn involute(self) -> Self {
+		Vec3 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+		}
+	}
+}
+
+impl Conjugate for Vec3 {
+	fn conjugate(self) -> Self {
+		Vec3 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+		}
+	}
+}
+
+impl Dual for Vec3 {
+	type Output = Plane;
+	fn dual(self) -> Self::Output {
+		Plane {
+			e123i: Default::default(),
+			e12oi: self.z.dual(),
+			e13oi: self.y.dual(),
+			e23oi: self.x.dual(),
+		}
+	}
+}
+
+impl Square for Vec3 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(S(self.x.0 * self.x.0) + S(self.y.0 * self.y.0) + S(self.z.0 * self.z.0)).0
+	}
+}
+
+// Omitted: Norm for Vec3  (the bulk and weight need a diagonal metric)
+
+impl Vec3 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 3] {
+		[self.x.0, self.y.0, self.z.0]
+	}
+}
+
+impl From<[f64; 3]> for Vec3 {
+	fn from(a: [f64; 3]) -> Self {
+		Vec3 {
+			x: E1(a[0]),
+			y: E2(a[1]),
+			z: E3(a[2]),
+		}
+	}
+}
+
+impl From<Vec3> for [f64; 3] {
+	fn from(v: Vec3) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Vec3> for Multivector {
+	fn from(v: Vec3) -> Self {
+		Multivector {
+			e1: v.x,
+			e2: v.y,
+			e3: v.z,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Vec3 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Vec3` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.eo.0 == 0.0
+			&& mv.ei.0 == 0.0
+			&& mv.e12.0 == 0.0
+			&& mv.e13.0 == 0.0
+			&& mv.e1o.0 == 0.0
+			&& mv.e1i.0 == 0.0
+			&& mv.e23.0 == 0.0
+			&& mv.e2o.0 == 0.0
+			&& mv.e2i.0 == 0.0
+			&& mv.e3o.0 == 0.0
+			&& mv.e3i.0 == 0.0
+			&& mv.eoi.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e12o.0 == 0.0
+			&& mv.e12i.0 == 0.0
+			&& mv.e13o.0 == 0.0
+			&& mv.e13i.0 == 0.0
+			&& mv.e1oi.0 == 0.0
+			&& mv.e23o.0 == 0.0
+			&& mv.e23i.0 == 0.0
+			&& mv.e2oi.0 == 0.0
+			&& mv.e3oi.0 == 0.0
+			&& mv.e123o.0 == 0.0
+			&& mv.e123i.0 == 0.0
+			&& mv.e12oi.0 == 0.0
+			&& mv.e13oi.0 == 0.0
+			&& mv.e23oi.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(Vec3 {
+				x: mv.e1,
+				y: mv.e2,
+				z: mv.e3,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec3> for glam::DVec3 {
+	fn from(v: Vec3) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0)
+	}
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec3> for Vec3 {
+	fn from(v: glam::DVec3) -> Self {
+		Vec3 {
+			x: E1(v.x),
+			y: E2(v.y),
+			z: E3(v.z),
+		}
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec3> for nalgebra::Vector3<f64> {
+	fn from(v: Vec3) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0)
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f64>> for Vec3 {
+	fn from(v: nalgebra::Vector3<f64>) -> Self {
+		Vec3 {
+			x: E1(v.x),
+			y: E2(v.y),
+			z: E3(v.z),
+		}
+	}
+}
+
+#[cfg(feature = "cgmath")]
+impl From<Vec3> for cgmath::Vector3<f64> {
+	fn from(v: Vec3) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0)
+	}
+}
+
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Vector3<f64>> for Vec3 {
+	fn from(v: cgmath::Vector3<f64>) -> Self {
+		Vec3 {
+			x: E1(v.x),
+			y: E2(v.y),
+			z: E3(v.z),
+		}
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec3> for nalgebra::Point3<f64> {
+	fn from(v: Vec3) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0)
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Point3<f64>> for Vec3 {
+	fn from(v: nalgebra::Point3<f64>) -> Self {
+		Vec3 {
+			x: E1(v.x),
+			y: E2(v.y),
+			z: E3(v.z),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Vec3:
+
+// Vec3.geometric(Vec3) -> Rotor
+impl Geometric<Vec3> for Vec3 {
+	type Output = Rotor;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Rotor {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		// }
+		Rotor {
+			s: S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+			e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+			e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+			e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Vec3 {
+	type Output = Rotor;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Vec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : -E1oi(self.y.0 * rhs.z.0) + E1oi(self.z.0 * rhs.y.0),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : E2oi(self.x.0 * rhs.z.0) - E2oi(self.z.0 * rhs.x.0),
+		//     e3oi  : -E3oi(self.x.0 * rhs.y.0) + E3oi(self.y.0 * rhs.x.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: -E1oi(self.y.0 * rhs.z.0) + E1oi(self.z.0 * rhs.y.0),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: E2oi(self.x.0 * rhs.z.0) - E2oi(self.z.0 * rhs.x.0),
+			e3oi: -E3oi(self.x.0 * rhs.y.0) + E3oi(self.y.0 * rhs.x.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0),
+		}
+	}
+}
+
+// Vec3.dot(Vec3) -> S
+impl Dot<Vec3> for Vec3 {
+	type Output = S;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Vec3 {
+	type Output = S;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Vec3) -> PointPair
+impl Wedge<Vec3> for Vec3 {
+	type Output = PointPair;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o: Default::default(),
+		//     e1i: Default::default(),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o: Default::default(),
+		//     e2i: Default::default(),
+		//     e3o: Default::default(),
+		//     e3i: Default::default(),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			e13: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Vec3 {
+	type Output = PointPair;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Vec3) -> S
+impl LeftContraction<Vec3> for Vec3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Vec3.right_contraction(Vec3) -> S
+impl RightContraction<Vec3> for Vec3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Vec3.scalar_product(Vec3) -> S
+impl ScalarProduct<Vec3> for Vec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Vec3.commutator(Vec3) -> PointPair
+impl Commutator<Vec3> for Vec3 {
+	type Output = PointPair;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o: Default::default(),
+		//     e1i: Default::default(),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o: Default::default(),
+		//     e2i: Default::default(),
+		//     e3o: Default::default(),
+		//     e3i: Default::default(),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+			e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Vec3.anti_dot(Vec3) -> E123oi
+impl AntiDot<Vec3> for Vec3 {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// -E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0)
+		-E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Point:
+
+// Vec3.geometric(Point) -> Versor
+impl Geometric<Point> for Vec3 {
+	type Output = Versor;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     e12  : E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13  : E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o  : E1o(self.x.0 * rhs.o.0),
+		//     e1i  : E1i(self.x.0 * rhs.inf.0),
+		//     e23  : E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o  : E2o(self.y.0 * rhs.o.0),
+		//     e2i  : E2i(self.y.0 * rhs.inf.0),
+		//     e3o  : E3o(self.z.0 * rhs.o.0),
+		//     e3i  : E3i(self.z.0 * rhs.inf.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+			e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+			e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+			e1o: E1o(self.x.0 * rhs.o.0),
+			e1i: E1i(self.x.0 * rhs.inf.0),
+			e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+			e2o: E2o(self.y.0 * rhs.o.0),
+			e2i: E2i(self.y.0 * rhs.inf.0),
+			e3o: E3o(self.z.0 * rhs.o.0),
+			e3i: E3i(self.z.0 * rhs.inf.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for Vec3 {
+	type Output = Versor;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Point) -> Multivector
+impl AntiGeometric<Point> for Vec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : -E12o(self.z.0 * rhs.o.0),
+		//     e12i  : E12i(self.z.0 * rhs.inf.0),
+		//     e13o  : E13o(self.y.0 * rhs.o.0),
+		//     e13i  : -E13i(self.y.0 * rhs.inf.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.z.0) + E1oi(self.z.0 * rhs.y.0),
+		//     e23o  : -E23o(self.x.0 * rhs.o.0),
+		//     e23i  : E23i(self.x.0 * rhs.inf.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.z.0) - E2oi(self.z.0 * rhs.x.0),
+		//     e3oi  : -E3oi(self.x.0 * rhs.y.0) + E3oi(self.y.0 * rhs.x.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: -E12o(self.z.0 * rhs.o.0),
+			e12i: E12i(self.z.0 * rhs.inf.0),
+			e13o: E13o(self.y.0 * rhs.o.0),
+			e13i: -E13i(self.y.0 * rhs.inf.0),
+			e1oi: -E1oi(self.y.0 * rhs.z.0) + E1oi(self.z.0 * rhs.y.0),
+			e23o: -E23o(self.x.0 * rhs.o.0),
+			e23i: E23i(self.x.0 * rhs.inf.0),
+			e2oi: E2oi(self.x.0 * rhs.z.0) - E2oi(self.z.0 * rhs.x.0),
+			e3oi: -E3oi(self.x.0 * rhs.y.0) + E3oi(self.y.0 * rhs.x.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0),
+		}
+	}
+}
+
+// Vec3.dot(Point) -> S
+impl Dot<Point> for Vec3 {
+	type Output = S;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+impl std::ops::BitOr<Point> for Vec3 {
+	type Output = S;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Point) -> PointPair
+impl Wedge<Point> for Vec3 {
+	type Output = PointPair;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o: E1o(self.x.0 * rhs.o.0),
+		//     e1i: E1i(self.x.0 * rhs.inf.0),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o: E2o(self.y.0 * rhs.o.0),
+		//     e2i: E2i(self.y.0 * rhs.inf.0),
+		//     e3o: E3o(self.z.0 * rhs.o.0),
+		//     e3i: E3i(self.z.0 * rhs.inf.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			e13: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			e1o: self.x.wedge(rhs.o),
+			e1i: self.x.wedge(rhs.inf),
+			e23: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			e2o: self.y.wedge(rhs.o),
+			e2i: self.y.wedge(rhs.inf),
+			e3o: self.z.wedge(rhs.o),
+			e3i: self.z.wedge(rhs.inf),
+			eoi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point> for Vec3 {
+	type Output = PointPair;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Point) -> S
+impl LeftContraction<Point> for Vec3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Point) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Vec3.right_contraction(Point) -> S
+impl RightContraction<Point> for Vec3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Vec3.scalar_product(Point) -> S
+impl ScalarProduct<Point> for Vec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Point) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+	}
+}
+
+// Vec3.commutator(Point) -> PointPair
+impl Commutator<Point> for Vec3 {
+	type Output = PointPair;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e1o: E1o(self.x.0 * rhs.o.0),
+		//     e1i: E1i(self.x.0 * rhs.inf.0),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e2o: E2o(self.y.0 * rhs.o.0),
+		//     e2i: E2i(self.y.0 * rhs.inf.0),
+		//     e3o: E3o(self.z.0 * rhs.o.0),
+		//     e3i: E3i(self.z.0 * rhs.inf.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+			e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+			e1o: E1o(self.x.0 * rhs.o.0),
+			e1i: E1i(self.x.0 * rhs.inf.0),
+			e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+			e2o: E2o(self.y.0 * rhs.o.0),
+			e2i: E2i(self.y.0 * rhs.inf.0),
+			e3o: E3o(self.z.0 * rhs.o.0),
+			e3i: E3i(self.z.0 * rhs.inf.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// Vec3.anti_dot(Point) -> E123oi
+impl AntiDot<Point> for Vec3 {
+	type Output = E123oi;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// -E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0)
+		-E123oi(self.x.0 * rhs.x.0) - E123oi(self.y.0 * rhs.y.0) - E123oi(self.z.0 * rhs.z.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP FlatPoint:
+
+// Vec3.geometric(FlatPoint) -> Multivector
+impl Geometric<FlatPoint> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.x.0 * rhs.eoi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+			e1oi: E1oi(self.x.0 * rhs.eoi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+			e2oi: E2oi(self.y.0 * rhs.eoi.0),
+			e3oi: E3oi(self.z.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(FlatPoint) -> Versor
+impl AntiGeometric<FlatPoint> for Vec3 {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.z.0 * rhs.eoi.0),
+		//     e13  : E13(self.y.0 * rhs.eoi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+		//     e23  : -E23(self.x.0 * rhs.eoi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.z.0 * rhs.eoi.0),
+			e13: E13(self.y.0 * rhs.eoi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+			e23: -E23(self.x.0 * rhs.eoi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Vec3.dot(FlatPoint) -> Ei
+impl Dot<FlatPoint> for Vec3 {
+	type Output = Ei;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0)
+		Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0)
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for Vec3 {
+	type Output = Ei;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(FlatPoint) -> Line
+impl Wedge<FlatPoint> for Vec3 {
+	type Output = Line;
+	fn wedge(self, rhs: FlatPoint) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13i: E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi: E1oi(self.x.0 * rhs.eoi.0),
+		//     e23i: E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi: E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi: E3oi(self.z.0 * rhs.eoi.0),
+		// }
+		Line {
+			e12i: self.x.wedge(rhs.e2i) + self.y.wedge(rhs.e1i),
+			e13i: self.x.wedge(rhs.e3i) + self.z.wedge(rhs.e1i),
+			e1oi: self.x.wedge(rhs.eoi),
+			e23i: self.y.wedge(rhs.e3i) + self.z.wedge(rhs.e2i),
+			e2oi: self.y.wedge(rhs.eoi),
+			e3oi: self.z.wedge(rhs.eoi),
+		}
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for Vec3 {
+	type Output = Line;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<FlatPoint> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(FlatPoint) -> Ei
+impl LeftContraction<FlatPoint> for Vec3 {
+	type Output = Ei;
+	fn left_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0)
+		Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0)
+	}
+}
+
+impl RightContraction<FlatPoint> for Vec3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<FlatPoint> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: FlatPoint) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(FlatPoint) -> Ei
+impl Commutator<FlatPoint> for Vec3 {
+	type Output = Ei;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0)
+		Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0)
+	}
+}
+
+// Vec3.anti_dot(FlatPoint) -> E123i
+impl AntiDot<FlatPoint> for Vec3 {
+	type Output = E123i;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0)
+		E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP PointPair:
+
+// Vec3.geometric(PointPair) -> Multivector
+impl Geometric<PointPair> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     eo    : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : E13o(self.x.0 * rhs.e3o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.x.0 * rhs.eoi.0),
+		//     e23o  : E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+			eo: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+			e12o: E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0),
+			e12i: E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+			e13o: E13o(self.x.0 * rhs.e3o.0) - E13o(self.z.0 * rhs.e1o.0),
+			e13i: E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+			e1oi: E1oi(self.x.0 * rhs.eoi.0),
+			e23o: E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+			e23i: E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+			e2oi: E2oi(self.y.0 * rhs.eoi.0),
+			e3oi: E3oi(self.z.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(PointPair) -> Versor
+impl AntiGeometric<PointPair> for Vec3 {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.z.0 * rhs.eoi.0),
+		//     e13  : E13(self.y.0 * rhs.eoi.0),
+		//     e1o  : -E1o(self.y.0 * rhs.e3o.0) + E1o(self.z.0 * rhs.e2o.0),
+		//     e1i  : E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+		//     e23  : -E23(self.x.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.x.0 * rhs.e3o.0) - E2o(self.z.0 * rhs.e1o.0),
+		//     e2i  : -E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+		//     e3o  : -E3o(self.x.0 * rhs.e2o.0) + E3o(self.y.0 * rhs.e1o.0),
+		//     e3i  : E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+		//     eoi  : Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+		//     e123o: -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0),
+		//     e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: -E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.z.0 * rhs.eoi.0),
+			e13: E13(self.y.0 * rhs.eoi.0),
+			e1o: -E1o(self.y.0 * rhs.e3o.0) + E1o(self.z.0 * rhs.e2o.0),
+			e1i: E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+			e23: -E23(self.x.0 * rhs.eoi.0),
+			e2o: E2o(self.x.0 * rhs.e3o.0) - E2o(self.z.0 * rhs.e1o.0),
+			e2i: -E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+			e3o: -E3o(self.x.0 * rhs.e2o.0) + E3o(self.y.0 * rhs.e1o.0),
+			e3i: E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+			eoi: Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+			e123o: -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+			e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0),
+			e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.z.0 * rhs.e23.0),
+			e23oi: -E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// Vec3.dot(PointPair) -> Point
+impl Dot<PointPair> for Vec3 {
+	type Output = Point;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y  : E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     z  : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     o  : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+			o: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<PointPair> for Vec3 {
+	type Output = Point;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(PointPair) -> Circle
+impl Wedge<PointPair> for Vec3 {
+	type Output = Circle;
+	fn wedge(self, rhs: PointPair) -> Self::Output {
+		// Circle {
+		//     e123: E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o: E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0),
+		//     e12i: E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o: E13o(self.x.0 * rhs.e3o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i: E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi: E1oi(self.x.0 * rhs.eoi.0),
+		//     e23o: E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i: E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi: E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi: E3oi(self.z.0 * rhs.eoi.0),
+		// }
+		Circle {
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e12o: self.x.wedge(rhs.e2o) + self.y.wedge(rhs.e1o),
+			e12i: self.x.wedge(rhs.e2i) + self.y.wedge(rhs.e1i),
+			e13o: self.x.wedge(rhs.e3o) + self.z.wedge(rhs.e1o),
+			e13i: self.x.wedge(rhs.e3i) + self.z.wedge(rhs.e1i),
+			e1oi: self.x.wedge(rhs.eoi),
+			e23o: self.y.wedge(rhs.e3o) + self.z.wedge(rhs.e2o),
+			e23i: self.y.wedge(rhs.e3i) + self.z.wedge(rhs.e2i),
+			e2oi: self.y.wedge(rhs.eoi),
+			e3oi: self.z.wedge(rhs.eoi),
+		}
+	}
+}
+
+impl std::ops::BitXor<PointPair> for Vec3 {
+	type Output = Circle;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<PointPair> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(PointPair) -> Point
+impl LeftContraction<PointPair> for Vec3 {
+	type Output = Point;
+	fn left_contraction(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y  : E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     z  : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     o  : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+			o: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl RightContraction<PointPair> for Vec3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<PointPair> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: PointPair) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(PointPair) -> Point
+impl Commutator<PointPair> for Vec3 {
+	type Output = Point;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y  : E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     z  : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     o  : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+			o: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// Vec3.anti_dot(PointPair) -> Sphere
+impl AntiDot<PointPair> for Vec3 {
+	type Output = Sphere;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// Sphere {
+		//     e123o: -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0),
+		//     e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: -E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Sphere {
+			e123o: -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+			e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0),
+			e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.z.0 * rhs.e23.0),
+			e23oi: -E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Line:
+
+// Vec3.geometric(Line) -> Versor
+impl Geometric<Line> for Vec3 {
+	type Output = Versor;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi  : Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+			e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+			e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Vec3 {
+	type Output = Versor;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Line) -> Multivector
+impl AntiGeometric<Line> for Vec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.y.0 * rhs.e3oi.0) + E1(self.z.0 * rhs.e2oi.0),
+		//     e2    : E2(self.x.0 * rhs.e3oi.0) - E2(self.z.0 * rhs.e1oi.0),
+		//     e3    : -E3(self.x.0 * rhs.e2oi.0) + E3(self.y.0 * rhs.e1oi.0),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0) - Ei(self.z.0 * rhs.e12i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o  : Default::default(),
+		//     e12i  : -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.y.0 * rhs.e3oi.0) + E1(self.z.0 * rhs.e2oi.0),
+			e2: E2(self.x.0 * rhs.e3oi.0) - E2(self.z.0 * rhs.e1oi.0),
+			e3: -E3(self.x.0 * rhs.e2oi.0) + E3(self.y.0 * rhs.e1oi.0),
+			eo: Default::default(),
+			ei: -Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0) - Ei(self.z.0 * rhs.e12i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Vec3.dot(Line) -> FlatPoint
+impl Dot<Line> for Vec3 {
+	type Output = FlatPoint;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// FlatPoint {
+		//     e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		// }
+		FlatPoint {
+			e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Vec3 {
+	type Output = FlatPoint;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Line) -> Plane
+impl Wedge<Line> for Vec3 {
+	type Output = Plane;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Plane {
+			e123i: self.x.wedge(rhs.e23i) + self.y.wedge(rhs.e13i) + self.z.wedge(rhs.e12i),
+			e12oi: self.x.wedge(rhs.e2oi) + self.y.wedge(rhs.e1oi),
+			e13oi: self.x.wedge(rhs.e3oi) + self.z.wedge(rhs.e1oi),
+			e23oi: self.y.wedge(rhs.e3oi) + self.z.wedge(rhs.e2oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line> for Vec3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Line> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Line) -> FlatPoint
+impl LeftContraction<Line> for Vec3 {
+	type Output = FlatPoint;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// FlatPoint {
+		//     e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		// }
+		FlatPoint {
+			e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl RightContraction<Line> for Vec3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Line) -> Plane
+impl Commutator<Line> for Vec3 {
+	type Output = Plane;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Plane {
+			e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+			e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+			e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		}
+	}
+}
+
+// Vec3.anti_dot(Line) -> Circle
+impl AntiDot<Line> for Vec3 {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o: Default::default(),
+		//     e12i: -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+		//     e13o: Default::default(),
+		//     e13i: E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi: Default::default(),
+		//     e23o: Default::default(),
+		//     e23i: E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi: Default::default(),
+		//     e3oi: Default::default(),
+		// }
+		Circle {
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: Default::default(),
+			e12i: -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Circle:
+
+// Vec3.geometric(Circle) -> Versor
+impl Geometric<Circle> for Vec3 {
+	type Output = Versor;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.z.0 * rhs.e123.0),
+		//     e13  : -E13(self.y.0 * rhs.e123.0),
+		//     e1o  : -E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i  : -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23  : E23(self.x.0 * rhs.e123.0),
+		//     e2o  : E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i  : E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o  : E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+		//     e3i  : E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi  : Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		//     e123o: E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.y.0 * rhs.e123.0),
+			e1o: -E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: E23(self.x.0 * rhs.e123.0),
+			e2o: E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+			e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+			e123o: E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+			e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+			e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+			e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for Vec3 {
+	type Output = Versor;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Circle) -> Multivector
+impl AntiGeometric<Circle> for Vec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.y.0 * rhs.e3oi.0) + E1(self.z.0 * rhs.e2oi.0),
+		//     e2    : E2(self.x.0 * rhs.e3oi.0) - E2(self.z.0 * rhs.e1oi.0),
+		//     e3    : -E3(self.x.0 * rhs.e2oi.0) + E3(self.y.0 * rhs.e1oi.0),
+		//     eo    : Eo(self.x.0 * rhs.e23o.0) - Eo(self.y.0 * rhs.e13o.0) + Eo(self.z.0 * rhs.e12o.0),
+		//     ei    : -Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0) - Ei(self.z.0 * rhs.e12i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o  : E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+		//     e12i  : -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+		//     e13o  : -E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+		//     e13i  : E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi  : E1oi(self.x.0 * rhs.e123.0),
+		//     e23o  : -E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+		//     e23i  : E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi  : E2oi(self.y.0 * rhs.e123.0),
+		//     e3oi  : E3oi(self.z.0 * rhs.e123.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.y.0 * rhs.e3oi.0) + E1(self.z.0 * rhs.e2oi.0),
+			e2: E2(self.x.0 * rhs.e3oi.0) - E2(self.z.0 * rhs.e1oi.0),
+			e3: -E3(self.x.0 * rhs.e2oi.0) + E3(self.y.0 * rhs.e1oi.0),
+			eo: Eo(self.x.0 * rhs.e23o.0) - Eo(self.y.0 * rhs.e13o.0) + Eo(self.z.0 * rhs.e12o.0),
+			ei: -Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0) - Ei(self.z.0 * rhs.e12i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+			e12i: -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+			e13o: -E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+			e13i: E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: E1oi(self.x.0 * rhs.e123.0),
+			e23o: -E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+			e23i: E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: E2oi(self.y.0 * rhs.e123.0),
+			e3oi: E3oi(self.z.0 * rhs.e123.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Vec3.dot(Circle) -> PointPair
+impl Dot<Circle> for Vec3 {
+	type Output = PointPair;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.z.0 * rhs.e123.0),
+		//     e13: -E13(self.y.0 * rhs.e123.0),
+		//     e1o: -E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23: E23(self.x.0 * rhs.e123.0),
+		//     e2o: E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o: E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+		//     e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		// }
+		PointPair {
+			e12: E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.y.0 * rhs.e123.0),
+			e1o: -E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: E23(self.x.0 * rhs.e123.0),
+			e2o: E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+			e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Circle> for Vec3 {
+	type Output = PointPair;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Circle) -> Sphere
+impl Wedge<Circle> for Vec3 {
+	type Output = Sphere;
+	fn wedge(self, rhs: Circle) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Sphere {
+			e123o: self.x.wedge(rhs.e23o) + self.y.wedge(rhs.e13o) + self.z.wedge(rhs.e12o),
+			e123i: self.x.wedge(rhs.e23i) + self.y.wedge(rhs.e13i) + self.z.wedge(rhs.e12i),
+			e12oi: self.x.wedge(rhs.e2oi) + self.y.wedge(rhs.e1oi),
+			e13oi: self.x.wedge(rhs.e3oi) + self.z.wedge(rhs.e1oi),
+			e23oi: self.y.wedge(rhs.e3oi) + self.z.wedge(rhs.e2oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Circle> for Vec3 {
+	type Output = Sphere;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Circle> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Circle> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Circle) -> PointPair
+impl LeftContraction<Circle> for Vec3 {
+	type Output = PointPair;
+	fn left_contraction(self, rhs: Circle) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.z.0 * rhs.e123.0),
+		//     e13: -E13(self.y.0 * rhs.e123.0),
+		//     e1o: -E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23: E23(self.x.0 * rhs.e123.0),
+		//     e2o: E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o: E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+		//     e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		// }
+		PointPair {
+			e12: E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.y.0 * rhs.e123.0),
+			e1o: -E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: E23(self.x.0 * rhs.e123.0),
+			e2o: E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+			e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+impl RightContraction<Circle> for Vec3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Circle> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Circle) -> Sphere
+impl Commutator<Circle> for Vec3 {
+	type Output = Sphere;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		// }
+		Sphere {
+			e123o: E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+			e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+			e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+			e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		}
+	}
+}
+
+// Vec3.anti_dot(Circle) -> Circle
+impl AntiDot<Circle> for Vec3 {
+	type Output = Circle;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// Circle {
+		//     e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o: E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+		//     e12i: -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+		//     e13o: -E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+		//     e13i: E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi: E1oi(self.x.0 * rhs.e123.0),
+		//     e23o: -E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+		//     e23i: E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi: E2oi(self.y.0 * rhs.e123.0),
+		//     e3oi: E3oi(self.z.0 * rhs.e123.0),
+		// }
+		Circle {
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+			e12i: -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+			e13o: -E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+			e13i: E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: E1oi(self.x.0 * rhs.e123.0),
+			e23o: -E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+			e23i: E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: E2oi(self.y.0 * rhs.e123.0),
+			e3oi: E3oi(self.z.0 * rhs.e123.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Plane:
+
+// Vec3.geometric(Plane) -> Multivector
+impl Geometric<Plane> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Plane) -> Versor
+impl AntiGeometric<Plane> for Vec3 {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+		//     e12  : E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13  : -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : -E1i(self.x.0 * rhs.e123i.0),
+		//     e23  : -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.y.0 * rhs.e123i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.z.0 * rhs.e123i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: Default::default(),
+			e1i: -E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.y.0 * rhs.e123i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.z.0 * rhs.e123i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Vec3.dot(Plane) -> Line
+impl Dot<Plane> for Vec3 {
+	type Output = Line;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.z.0 * rhs.e123i.0),
+		//     e13i: -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23i: E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Line {
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Vec3 {
+	type Output = Line;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Plane) -> E123oi
+impl Wedge<Plane> for Vec3 {
+	type Output = E123oi;
+	fn wedge(self, rhs: Plane) -> Self::Output {
+		// E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0)
+		self.x.wedge(rhs.e23oi) + self.y.wedge(rhs.e13oi) + self.z.wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitXor<Plane> for Vec3 {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Plane) -> S
+impl AntiWedge<Plane> for Vec3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0)
+		self.x.anti_wedge(rhs.e23oi) + self.y.anti_wedge(rhs.e13oi) + self.z.anti_wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Vec3 {
+	type Output = S;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Plane) -> Line
+impl LeftContraction<Plane> for Vec3 {
+	type Output = Line;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.z.0 * rhs.e123i.0),
+		//     e13i: -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23i: E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Line {
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Plane> for Vec3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Plane) -> Line
+impl Commutator<Plane> for Vec3 {
+	type Output = Line;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Line {
+		//     e12i: E12i(self.z.0 * rhs.e123i.0),
+		//     e13i: -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23i: E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Line {
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Vec3.anti_dot(Plane) -> PointPair
+impl AntiDot<Plane> for Vec3 {
+	type Output = PointPair;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o: Default::default(),
+		//     e1i: -E1i(self.x.0 * rhs.e123i.0),
+		//     e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o: Default::default(),
+		//     e2i: -E2i(self.y.0 * rhs.e123i.0),
+		//     e3o: Default::default(),
+		//     e3i: -E3i(self.z.0 * rhs.e123i.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: Default::default(),
+			e1i: -E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.y.0 * rhs.e123i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.z.0 * rhs.e123i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Sphere:
+
+// Vec3.geometric(Sphere) -> Multivector
+impl Geometric<Sphere> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: -E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Sphere) -> Versor
+impl AntiGeometric<Sphere> for Vec3 {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+		//     e12  : E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13  : -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o  : E1o(self.x.0 * rhs.e123o.0),
+		//     e1i  : -E1i(self.x.0 * rhs.e123i.0),
+		//     e23  : -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o  : E2o(self.y.0 * rhs.e123o.0),
+		//     e2i  : -E2i(self.y.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.z.0 * rhs.e123o.0),
+		//     e3i  : -E3i(self.z.0 * rhs.e123i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.x.0 * rhs.e123o.0),
+			e1i: -E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: E2o(self.y.0 * rhs.e123o.0),
+			e2i: -E2i(self.y.0 * rhs.e123i.0),
+			e3o: E3o(self.z.0 * rhs.e123o.0),
+			e3i: -E3i(self.z.0 * rhs.e123i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Vec3.dot(Sphere) -> Circle
+impl Dot<Sphere> for Vec3 {
+	type Output = Circle;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// Circle {
+		//     e123: Default::default(),
+		//     e12o: E12o(self.z.0 * rhs.e123o.0),
+		//     e12i: E12i(self.z.0 * rhs.e123i.0),
+		//     e13o: -E13o(self.y.0 * rhs.e123o.0),
+		//     e13i: -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o: E23o(self.x.0 * rhs.e123o.0),
+		//     e23i: E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Circle {
+			e123: Default::default(),
+			e12o: E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: -E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Sphere> for Vec3 {
+	type Output = Circle;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Sphere) -> E123oi
+impl Wedge<Sphere> for Vec3 {
+	type Output = E123oi;
+	fn wedge(self, rhs: Sphere) -> Self::Output {
+		// E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0)
+		self.x.wedge(rhs.e23oi) + self.y.wedge(rhs.e13oi) + self.z.wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitXor<Sphere> for Vec3 {
+	type Output = E123oi;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Sphere) -> S
+impl AntiWedge<Sphere> for Vec3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0)
+		self.x.anti_wedge(rhs.e23oi) + self.y.anti_wedge(rhs.e13oi) + self.z.anti_wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for Vec3 {
+	type Output = S;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Sphere) -> Circle
+impl LeftContraction<Sphere> for Vec3 {
+	type Output = Circle;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// Circle {
+		//     e123: Default::default(),
+		//     e12o: E12o(self.z.0 * rhs.e123o.0),
+		//     e12i: E12i(self.z.0 * rhs.e123i.0),
+		//     e13o: -E13o(self.y.0 * rhs.e123o.0),
+		//     e13i: -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o: E23o(self.x.0 * rhs.e123o.0),
+		//     e23i: E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Circle {
+			e123: Default::default(),
+			e12o: E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: -E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl RightContraction<Sphere> for Vec3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Sphere> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Sphere) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Sphere) -> Circle
+impl Commutator<Sphere> for Vec3 {
+	type Output = Circle;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// Circle {
+		//     e123: Default::default(),
+		//     e12o: E12o(self.z.0 * rhs.e123o.0),
+		//     e12i: E12i(self.z.0 * rhs.e123i.0),
+		//     e13o: -E13o(self.y.0 * rhs.e123o.0),
+		//     e13i: -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o: E23o(self.x.0 * rhs.e123o.0),
+		//     e23i: E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		// }
+		Circle {
+			e123: Default::default(),
+			e12o: E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: -E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Vec3.anti_dot(Sphere) -> PointPair
+impl AntiDot<Sphere> for Vec3 {
+	type Output = PointPair;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// PointPair {
+		//     e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o: E1o(self.x.0 * rhs.e123o.0),
+		//     e1i: -E1i(self.x.0 * rhs.e123i.0),
+		//     e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o: E2o(self.y.0 * rhs.e123o.0),
+		//     e2i: -E2i(self.y.0 * rhs.e123i.0),
+		//     e3o: E3o(self.z.0 * rhs.e123o.0),
+		//     e3i: -E3i(self.z.0 * rhs.e123i.0),
+		//     eoi: Default::default(),
+		// }
+		PointPair {
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.x.0 * rhs.e123o.0),
+			e1i: -E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: E2o(self.y.0 * rhs.e123o.0),
+			e2i: -E2i(self.y.0 * rhs.e123i.0),
+			e3o: E3o(self.z.0 * rhs.e123o.0),
+			e3i: -E3i(self.z.0 * rhs.e123i.0),
+			eoi: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Rotor:
+
+// Vec3.geometric(Rotor) -> Multivector
+impl Geometric<Rotor> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Rotor) -> Versor
+impl AntiGeometric<Rotor> for Vec3 {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : Default::default(),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : Default::default(),
+		//     e3o  : Default::default(),
+		//     e3i  : Default::default(),
+		//     eoi  : Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+			e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// Vec3.dot(Rotor) -> Vec3
+impl Dot<Rotor> for Vec3 {
+	type Output = Vec3;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y: E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		// }
+		Vec3 {
+			x: E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			y: E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+			z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Vec3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Rotor) -> Multivector
+impl Wedge<Rotor> for Vec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.x.wedge(rhs.s),
+			e2: self.y.wedge(rhs.s),
+			e3: self.z.wedge(rhs.s),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Vec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Rotor> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Rotor) -> Vec3
+impl LeftContraction<Rotor> for Vec3 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		// }
+		Vec3 {
+			x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Vec3.right_contraction(Rotor) -> Vec3
+impl RightContraction<Rotor> for Vec3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.x.0 * rhs.s.0),
+		//     y: E2(self.y.0 * rhs.s.0),
+		//     z: E3(self.z.0 * rhs.s.0),
+		// }
+		Vec3 {
+			x: E1(self.x.0 * rhs.s.0),
+			y: E2(self.y.0 * rhs.s.0),
+			z: E3(self.z.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Rotor) -> Vec3
+impl Commutator<Rotor> for Vec3 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		// }
+		Vec3 {
+			x: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			y: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			z: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Vec3.anti_dot(Rotor) -> Plane
+impl AntiDot<Rotor> for Vec3 {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Plane {
+		//     e123i: Default::default(),
+		//     e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Plane {
+			e123i: Default::default(),
+			e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+			e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Translator:
+
+// Vec3.geometric(Translator) -> Multivector
+impl Geometric<Translator> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.x.0 * rhs.s.0),
+			e2: E2(self.y.0 * rhs.s.0),
+			e3: E3(self.z.0 * rhs.s.0),
+			eo: Default::default(),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Translator) -> Versor
+impl AntiGeometric<Translator> for Vec3 {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: -E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: E13oi(self.y.0 * rhs.s.0),
+		//     e23oi: -E23oi(self.x.0 * rhs.s.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: -E2i(self.x.0 * rhs.e3i.0) + E2i(self.z.0 * rhs.e1i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+			e12oi: -E12oi(self.z.0 * rhs.s.0),
+			e13oi: E13oi(self.y.0 * rhs.s.0),
+			e23oi: -E23oi(self.x.0 * rhs.s.0),
+		}
+	}
+}
+
+// Vec3.dot(Translator) -> Point
+impl Dot<Translator> for Vec3 {
+	type Output = Point;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Point {
+		//     x  : E1(self.x.0 * rhs.s.0),
+		//     y  : E2(self.y.0 * rhs.s.0),
+		//     z  : E3(self.z.0 * rhs.s.0),
+		//     o  : Default::default(),
+		//     inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		// }
+		Point {
+			x: E1(self.x.0 * rhs.s.0),
+			y: E2(self.y.0 * rhs.s.0),
+			z: E3(self.z.0 * rhs.s.0),
+			o: Default::default(),
+			inf: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Vec3 {
+	type Output = Point;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Translator) -> Multivector
+impl Wedge<Translator> for Vec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.x.wedge(rhs.s),
+			e2: self.y.wedge(rhs.s),
+			e3: self.z.wedge(rhs.s),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.x.wedge(rhs.e2i) + self.y.wedge(rhs.e1i),
+			e13o: Default::default(),
+			e13i: self.x.wedge(rhs.e3i) + self.z.wedge(rhs.e1i),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: self.y.wedge(rhs.e3i) + self.z.wedge(rhs.e2i),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Vec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Translator> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Translator) -> Ei
+impl LeftContraction<Translator> for Vec3 {
+	type Output = Ei;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0)
+		Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0)
+	}
+}
+
+// Vec3.right_contraction(Translator) -> Vec3
+impl RightContraction<Translator> for Vec3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.x.0 * rhs.s.0),
+		//     y: E2(self.y.0 * rhs.s.0),
+		//     z: E3(self.z.0 * rhs.s.0),
+		// }
+		Vec3 {
+			x: E1(self.x.0 * rhs.s.0),
+			y: E2(self.y.0 * rhs.s.0),
+			z: E3(self.z.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Translator) -> Ei
+impl Commutator<Translator> for Vec3 {
+	type Output = Ei;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0)
+		Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0)
+	}
+}
+
+// Vec3.anti_dot(Translator) -> Plane
+impl AntiDot<Translator> for Vec3 {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: -E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: E13oi(self.y.0 * rhs.s.0),
+		//     e23oi: -E23oi(self.x.0 * rhs.s.0),
+		// }
+		Plane {
+			e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+			e12oi: -E12oi(self.z.0 * rhs.s.0),
+			e13oi: E13oi(self.y.0 * rhs.s.0),
+			e23oi: -E23oi(self.x.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Versor:
+
+// Vec3.geometric(Versor) -> Multivector
+impl Geometric<Versor> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.x.0 * rhs.e3o.0) - E13o(self.y.0 * rhs.e123o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.y.0 * rhs.e123i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.x.0 * rhs.eoi.0) - E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.x.0 * rhs.e123o.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.x.0 * rhs.e123i.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) + E2oi(self.y.0 * rhs.eoi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0) + E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+			e12o: E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.x.0 * rhs.e3o.0) - E13o(self.y.0 * rhs.e123o.0) - E13o(self.z.0 * rhs.e1o.0),
+			e13i: E13i(self.x.0 * rhs.e3i.0) - E13i(self.y.0 * rhs.e123i.0) - E13i(self.z.0 * rhs.e1i.0),
+			e1oi: E1oi(self.x.0 * rhs.eoi.0) - E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) + E2oi(self.y.0 * rhs.eoi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0) + E3oi(self.z.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Versor) -> Versor
+impl AntiGeometric<Versor> for Vec3 {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+		//     e12  : E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0) - E12(self.z.0 * rhs.eoi.0),
+		//     e13  : -E13(self.x.0 * rhs.e12oi.0) + E13(self.y.0 * rhs.eoi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o  : E1o(self.x.0 * rhs.e123o.0) - E1o(self.y.0 * rhs.e3o.0) + E1o(self.z.0 * rhs.e2o.0),
+		//     e1i  : -E1i(self.x.0 * rhs.e123i.0) + E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+		//     e23  : -E23(self.x.0 * rhs.eoi.0) - E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o  : E2o(self.x.0 * rhs.e3o.0) + E2o(self.y.0 * rhs.e123o.0) - E2o(self.z.0 * rhs.e1o.0),
+		//     e2i  : -E2i(self.x.0 * rhs.e3i.0) - E2i(self.y.0 * rhs.e123i.0) + E2i(self.z.0 * rhs.e1i.0),
+		//     e3o  : -E3o(self.x.0 * rhs.e2o.0) + E3o(self.y.0 * rhs.e1o.0) + E3o(self.z.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0) - E3i(self.z.0 * rhs.e123i.0),
+		//     eoi  : Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+		//     e123o: -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Versor {
+			s: S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0) - E12(self.z.0 * rhs.eoi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.y.0 * rhs.eoi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.x.0 * rhs.e123o.0) - E1o(self.y.0 * rhs.e3o.0) + E1o(self.z.0 * rhs.e2o.0),
+			e1i: -E1i(self.x.0 * rhs.e123i.0) + E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+			e23: -E23(self.x.0 * rhs.eoi.0) - E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: E2o(self.x.0 * rhs.e3o.0) + E2o(self.y.0 * rhs.e123o.0) - E2o(self.z.0 * rhs.e1o.0),
+			e2i: -E2i(self.x.0 * rhs.e3i.0) - E2i(self.y.0 * rhs.e123i.0) + E2i(self.z.0 * rhs.e1i.0),
+			e3o: -E3o(self.x.0 * rhs.e2o.0) + E3o(self.y.0 * rhs.e1o.0) + E3o(self.z.0 * rhs.e123o.0),
+			e3i: E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0) - E3i(self.z.0 * rhs.e123i.0),
+			eoi: Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+			e123o: -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+			e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+			e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// Vec3.dot(Versor) -> Multivector
+impl Dot<Versor> for Vec3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: -E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for Vec3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Versor) -> Multivector
+impl Wedge<Versor> for Vec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : E13o(self.x.0 * rhs.e3o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.x.0 * rhs.eoi.0),
+		//     e23o  : E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.x.wedge(rhs.s),
+			e2: self.y.wedge(rhs.s),
+			e3: self.z.wedge(rhs.s),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e12o: self.x.wedge(rhs.e2o) + self.y.wedge(rhs.e1o),
+			e12i: self.x.wedge(rhs.e2i) + self.y.wedge(rhs.e1i),
+			e13o: self.x.wedge(rhs.e3o) + self.z.wedge(rhs.e1o),
+			e13i: self.x.wedge(rhs.e3i) + self.z.wedge(rhs.e1i),
+			e1oi: self.x.wedge(rhs.eoi),
+			e23o: self.y.wedge(rhs.e3o) + self.z.wedge(rhs.e2o),
+			e23i: self.y.wedge(rhs.e3i) + self.z.wedge(rhs.e2i),
+			e2oi: self.y.wedge(rhs.eoi),
+			e3oi: self.z.wedge(rhs.eoi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.x.wedge(rhs.e23oi) + self.y.wedge(rhs.e13oi) + self.z.wedge(rhs.e12oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for Vec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Versor) -> S
+impl AntiWedge<Versor> for Vec3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0)
+		self.x.anti_wedge(rhs.e23oi) + self.y.anti_wedge(rhs.e13oi) + self.z.anti_wedge(rhs.e12oi)
+	}
+}
+
+impl std::ops::BitAnd<Versor> for Vec3 {
+	type Output = S;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Versor) -> Multivector
+impl LeftContraction<Versor> for Vec3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     eo    : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+			eo: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: -E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Vec3.right_contraction(Versor) -> Vec3
+impl RightContraction<Versor> for Vec3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.x.0 * rhs.s.0),
+		//     y: E2(self.y.0 * rhs.s.0),
+		//     z: E3(self.z.0 * rhs.s.0),
+		// }
+		Vec3 {
+			x: E1(self.x.0 * rhs.s.0),
+			y: E2(self.y.0 * rhs.s.0),
+			z: E3(self.z.0 * rhs.s.0),
+		}
+	}
+}
+
+impl ScalarProduct<Versor> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Versor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Versor) -> Multivector
+impl Commutator<Versor> for Vec3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     eo    : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+			eo: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: -E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Vec3.anti_dot(Versor) -> Versor
+impl AntiDot<Versor> for Vec3 {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13  : -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o  : E1o(self.x.0 * rhs.e123o.0),
+		//     e1i  : -E1i(self.x.0 * rhs.e123i.0),
+		//     e23  : -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o  : E2o(self.y.0 * rhs.e123o.0),
+		//     e2i  : -E2i(self.y.0 * rhs.e123i.0),
+		//     e3o  : E3o(self.z.0 * rhs.e123o.0),
+		//     e3i  : -E3i(self.z.0 * rhs.e123i.0),
+		//     eoi  : Default::default(),
+		//     e123o: -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.x.0 * rhs.e123o.0),
+			e1i: -E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: E2o(self.y.0 * rhs.e123o.0),
+			e2i: -E2i(self.y.0 * rhs.e123i.0),
+			e3o: E3o(self.z.0 * rhs.e123o.0),
+			e3i: -E3i(self.z.0 * rhs.e123i.0),
+			eoi: Default::default(),
+			e123o: -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+			e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+			e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Multivector:
+
+// Vec3.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+		//     e1    : E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0) + E12(self.z.0 * rhs.e123.0),
+		//     e13   : E13(self.x.0 * rhs.e3.0) - E13(self.y.0 * rhs.e123.0) - E13(self.z.0 * rhs.e1.0),
+		//     e1o   : E1o(self.x.0 * rhs.eo.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i   : E1i(self.x.0 * rhs.ei.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23   : E23(self.x.0 * rhs.e123.0) + E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+		//     e2o   : E2o(self.x.0 * rhs.e12o.0) + E2o(self.y.0 * rhs.eo.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i   : E2i(self.x.0 * rhs.e12i.0) + E2i(self.y.0 * rhs.ei.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o   : E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0) + E3o(self.z.0 * rhs.eo.0),
+		//     e3i   : E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0) + E3i(self.z.0 * rhs.ei.0),
+		//     eoi   : Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0) + E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0) + E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.x.0 * rhs.e3o.0) - E13o(self.y.0 * rhs.e123o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.y.0 * rhs.e123i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.x.0 * rhs.eoi.0) - E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.x.0 * rhs.e123o.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.x.0 * rhs.e123i.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) + E2oi(self.y.0 * rhs.eoi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0) + E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i : E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi : E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0) + E12oi(self.z.0 * rhs.e123oi.0),
+		//     e13oi : E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.y.0 * rhs.e123oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi : E23oi(self.x.0 * rhs.e123oi.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		//     e123oi: E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+			e1: E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0) + E12(self.z.0 * rhs.e123.0),
+			e13: E13(self.x.0 * rhs.e3.0) - E13(self.y.0 * rhs.e123.0) - E13(self.z.0 * rhs.e1.0),
+			e1o: E1o(self.x.0 * rhs.eo.0) - E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: E1i(self.x.0 * rhs.ei.0) - E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: E23(self.x.0 * rhs.e123.0) + E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+			e2o: E2o(self.x.0 * rhs.e12o.0) + E2o(self.y.0 * rhs.eo.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.x.0 * rhs.e12i.0) + E2i(self.y.0 * rhs.ei.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0) + E3o(self.z.0 * rhs.eo.0),
+			e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0) + E3i(self.z.0 * rhs.ei.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+			e123: E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+			e12o: E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0) + E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0) + E12i(self.z.0 * rhs.e123i.0),
+			e13o: E13o(self.x.0 * rhs.e3o.0) - E13o(self.y.0 * rhs.e123o.0) - E13o(self.z.0 * rhs.e1o.0),
+			e13i: E13i(self.x.0 * rhs.e3i.0) - E13i(self.y.0 * rhs.e123i.0) - E13i(self.z.0 * rhs.e1i.0),
+			e1oi: E1oi(self.x.0 * rhs.eoi.0) - E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0) + E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0) + E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) + E2oi(self.y.0 * rhs.eoi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0) + E3oi(self.z.0 * rhs.eoi.0),
+			e123o: E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+			e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+			e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0) + E12oi(self.z.0 * rhs.e123oi.0),
+			e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.y.0 * rhs.e123oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: E23oi(self.x.0 * rhs.e123oi.0) + E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+			e123oi: E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+		//     e1    : E1(self.x.0 * rhs.e123oi.0) - E1(self.y.0 * rhs.e3oi.0) + E1(self.z.0 * rhs.e2oi.0),
+		//     e2    : E2(self.x.0 * rhs.e3oi.0) + E2(self.y.0 * rhs.e123oi.0) - E2(self.z.0 * rhs.e1oi.0),
+		//     e3    : -E3(self.x.0 * rhs.e2oi.0) + E3(self.y.0 * rhs.e1oi.0) + E3(self.z.0 * rhs.e123oi.0),
+		//     eo    : Eo(self.x.0 * rhs.e23o.0) - Eo(self.y.0 * rhs.e13o.0) + Eo(self.z.0 * rhs.e12o.0),
+		//     ei    : -Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0) - Ei(self.z.0 * rhs.e12i.0),
+		//     e12   : E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0) - E12(self.z.0 * rhs.eoi.0),
+		//     e13   : -E13(self.x.0 * rhs.e12oi.0) + E13(self.y.0 * rhs.eoi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o   : E1o(self.x.0 * rhs.e123o.0) - E1o(self.y.0 * rhs.e3o.0) + E1o(self.z.0 * rhs.e2o.0),
+		//     e1i   : -E1i(self.x.0 * rhs.e123i.0) + E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+		//     e23   : -E23(self.x.0 * rhs.eoi.0) - E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o   : E2o(self.x.0 * rhs.e3o.0) + E2o(self.y.0 * rhs.e123o.0) - E2o(self.z.0 * rhs.e1o.0),
+		//     e2i   : -E2i(self.x.0 * rhs.e3i.0) - E2i(self.y.0 * rhs.e123i.0) + E2i(self.z.0 * rhs.e1i.0),
+		//     e3o   : -E3o(self.x.0 * rhs.e2o.0) + E3o(self.y.0 * rhs.e1o.0) + E3o(self.z.0 * rhs.e123o.0),
+		//     e3i   : E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0) - E3i(self.z.0 * rhs.e123i.0),
+		//     eoi   : Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+		//     e123  : -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o  : E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0) - E12o(self.z.0 * rhs.eo.0),
+		//     e12i  : -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0) + E12i(self.z.0 * rhs.ei.0),
+		//     e13o  : -E13o(self.x.0 * rhs.e12o.0) + E13o(self.y.0 * rhs.eo.0) + E13o(self.z.0 * rhs.e23o.0),
+		//     e13i  : E13i(self.x.0 * rhs.e12i.0) - E13i(self.y.0 * rhs.ei.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi  : E1oi(self.x.0 * rhs.e123.0) - E1oi(self.y.0 * rhs.e3.0) + E1oi(self.z.0 * rhs.e2.0),
+		//     e23o  : -E23o(self.x.0 * rhs.eo.0) - E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+		//     e23i  : E23i(self.x.0 * rhs.ei.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e3.0) + E2oi(self.y.0 * rhs.e123.0) - E2oi(self.z.0 * rhs.e1.0),
+		//     e3oi  : -E3oi(self.x.0 * rhs.e2.0) + E3oi(self.y.0 * rhs.e1.0) + E3oi(self.z.0 * rhs.e123.0),
+		//     e123o : -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i : E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi : E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi : -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi : -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		//     e123oi: -E123oi(self.x.0 * rhs.e1.0) - E123oi(self.y.0 * rhs.e2.0) - E123oi(self.z.0 * rhs.e3.0),
+		// }
+		Multivector {
+			s: S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+			e1: E1(self.x.0 * rhs.e123oi.0) - E1(self.y.0 * rhs.e3oi.0) + E1(self.z.0 * rhs.e2oi.0),
+			e2: E2(self.x.0 * rhs.e3oi.0) + E2(self.y.0 * rhs.e123oi.0) - E2(self.z.0 * rhs.e1oi.0),
+			e3: -E3(self.x.0 * rhs.e2oi.0) + E3(self.y.0 * rhs.e1oi.0) + E3(self.z.0 * rhs.e123oi.0),
+			eo: Eo(self.x.0 * rhs.e23o.0) - Eo(self.y.0 * rhs.e13o.0) + Eo(self.z.0 * rhs.e12o.0),
+			ei: -Ei(self.x.0 * rhs.e23i.0) + Ei(self.y.0 * rhs.e13i.0) - Ei(self.z.0 * rhs.e12i.0),
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0) - E12(self.z.0 * rhs.eoi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.y.0 * rhs.eoi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.x.0 * rhs.e123o.0) - E1o(self.y.0 * rhs.e3o.0) + E1o(self.z.0 * rhs.e2o.0),
+			e1i: -E1i(self.x.0 * rhs.e123i.0) + E1i(self.y.0 * rhs.e3i.0) - E1i(self.z.0 * rhs.e2i.0),
+			e23: -E23(self.x.0 * rhs.eoi.0) - E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: E2o(self.x.0 * rhs.e3o.0) + E2o(self.y.0 * rhs.e123o.0) - E2o(self.z.0 * rhs.e1o.0),
+			e2i: -E2i(self.x.0 * rhs.e3i.0) - E2i(self.y.0 * rhs.e123i.0) + E2i(self.z.0 * rhs.e1i.0),
+			e3o: -E3o(self.x.0 * rhs.e2o.0) + E3o(self.y.0 * rhs.e1o.0) + E3o(self.z.0 * rhs.e123o.0),
+			e3i: E3i(self.x.0 * rhs.e2i.0) - E3i(self.y.0 * rhs.e1i.0) - E3i(self.z.0 * rhs.e123i.0),
+			eoi: Eoi(self.x.0 * rhs.e23.0) - Eoi(self.y.0 * rhs.e13.0) + Eoi(self.z.0 * rhs.e12.0),
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0) - E12o(self.z.0 * rhs.eo.0),
+			e12i: -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0) + E12i(self.z.0 * rhs.ei.0),
+			e13o: -E13o(self.x.0 * rhs.e12o.0) + E13o(self.y.0 * rhs.eo.0) + E13o(self.z.0 * rhs.e23o.0),
+			e13i: E13i(self.x.0 * rhs.e12i.0) - E13i(self.y.0 * rhs.ei.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: E1oi(self.x.0 * rhs.e123.0) - E1oi(self.y.0 * rhs.e3.0) + E1oi(self.z.0 * rhs.e2.0),
+			e23o: -E23o(self.x.0 * rhs.eo.0) - E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+			e23i: E23i(self.x.0 * rhs.ei.0) + E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: E2oi(self.x.0 * rhs.e3.0) + E2oi(self.y.0 * rhs.e123.0) - E2oi(self.z.0 * rhs.e1.0),
+			e3oi: -E3oi(self.x.0 * rhs.e2.0) + E3oi(self.y.0 * rhs.e1.0) + E3oi(self.z.0 * rhs.e123.0),
+			e123o: -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+			e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+			e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+			e123oi: -E123oi(self.x.0 * rhs.e1.0) - E123oi(self.y.0 * rhs.e2.0) - E123oi(self.z.0 * rhs.e3.0),
+		}
+	}
+}
+
+// Vec3.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+		//     e1    : E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     eo    : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : E12(self.z.0 * rhs.e123.0),
+		//     e13   : -E13(self.y.0 * rhs.e123.0),
+		//     e1o   : -E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i   : -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23   : E23(self.x.0 * rhs.e123.0),
+		//     e2o   : E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i   : E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o   : E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+		//     e3i   : E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi   : Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		//     e123  : Default::default(),
+		//     e12o  : E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : E12oi(self.z.0 * rhs.e123oi.0),
+		//     e13oi : -E13oi(self.y.0 * rhs.e123oi.0),
+		//     e23oi : E23oi(self.x.0 * rhs.e123oi.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+			e1: E1(self.x.0 * rhs.s.0) - E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+			eo: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.y.0 * rhs.e123.0),
+			e1o: -E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: E23(self.x.0 * rhs.e123.0),
+			e2o: E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+			e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+			e123: Default::default(),
+			e12o: E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: -E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: E12oi(self.z.0 * rhs.e123oi.0),
+			e13oi: -E13oi(self.y.0 * rhs.e123oi.0),
+			e23oi: E23oi(self.x.0 * rhs.e123oi.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0),
+		//     e13   : E13(self.x.0 * rhs.e3.0) - E13(self.z.0 * rhs.e1.0),
+		//     e1o   : E1o(self.x.0 * rhs.eo.0),
+		//     e1i   : E1i(self.x.0 * rhs.ei.0),
+		//     e23   : E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+		//     e2o   : E2o(self.y.0 * rhs.eo.0),
+		//     e2i   : E2i(self.y.0 * rhs.ei.0),
+		//     e3o   : E3o(self.z.0 * rhs.eo.0),
+		//     e3i   : E3i(self.z.0 * rhs.ei.0),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e12o  : E12o(self.x.0 * rhs.e2o.0) - E12o(self.y.0 * rhs.e1o.0),
+		//     e12i  : E12i(self.x.0 * rhs.e2i.0) - E12i(self.y.0 * rhs.e1i.0),
+		//     e13o  : E13o(self.x.0 * rhs.e3o.0) - E13o(self.z.0 * rhs.e1o.0),
+		//     e13i  : E13i(self.x.0 * rhs.e3i.0) - E13i(self.z.0 * rhs.e1i.0),
+		//     e1oi  : E1oi(self.x.0 * rhs.eoi.0),
+		//     e23o  : E23o(self.y.0 * rhs.e3o.0) - E23o(self.z.0 * rhs.e2o.0),
+		//     e23i  : E23i(self.y.0 * rhs.e3i.0) - E23i(self.z.0 * rhs.e2i.0),
+		//     e2oi  : E2oi(self.y.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.z.0 * rhs.eoi.0),
+		//     e123o : E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i : E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi : E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi : E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi : E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		//     e123oi: E123oi(self.x.0 * rhs.e23oi.0) - E123oi(self.y.0 * rhs.e13oi.0) + E123oi(self.z.0 * rhs.e12oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.x.wedge(rhs.s),
+			e2: self.y.wedge(rhs.s),
+			e3: self.z.wedge(rhs.s),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: self.x.wedge(rhs.e2) + self.y.wedge(rhs.e1),
+			e13: self.x.wedge(rhs.e3) + self.z.wedge(rhs.e1),
+			e1o: self.x.wedge(rhs.eo),
+			e1i: self.x.wedge(rhs.ei),
+			e23: self.y.wedge(rhs.e3) + self.z.wedge(rhs.e2),
+			e2o: self.y.wedge(rhs.eo),
+			e2i: self.y.wedge(rhs.ei),
+			e3o: self.z.wedge(rhs.eo),
+			e3i: self.z.wedge(rhs.ei),
+			eoi: Default::default(),
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e12o: self.x.wedge(rhs.e2o) + self.y.wedge(rhs.e1o),
+			e12i: self.x.wedge(rhs.e2i) + self.y.wedge(rhs.e1i),
+			e13o: self.x.wedge(rhs.e3o) + self.z.wedge(rhs.e1o),
+			e13i: self.x.wedge(rhs.e3i) + self.z.wedge(rhs.e1i),
+			e1oi: self.x.wedge(rhs.eoi),
+			e23o: self.y.wedge(rhs.e3o) + self.z.wedge(rhs.e2o),
+			e23i: self.y.wedge(rhs.e3i) + self.z.wedge(rhs.e2i),
+			e2oi: self.y.wedge(rhs.eoi),
+			e3oi: self.z.wedge(rhs.eoi),
+			e123o: self.x.wedge(rhs.e23o) + self.y.wedge(rhs.e13o) + self.z.wedge(rhs.e12o),
+			e123i: self.x.wedge(rhs.e23i) + self.y.wedge(rhs.e13i) + self.z.wedge(rhs.e12i),
+			e12oi: self.x.wedge(rhs.e2oi) + self.y.wedge(rhs.e1oi),
+			e13oi: self.x.wedge(rhs.e3oi) + self.z.wedge(rhs.e1oi),
+			e23oi: self.y.wedge(rhs.e3oi) + self.z.wedge(rhs.e2oi),
+			e123oi: self.x.wedge(rhs.e23oi) + self.y.wedge(rhs.e13oi) + self.z.wedge(rhs.e12oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.x.0 * rhs.e23oi.0) - S(self.y.0 * rhs.e13oi.0) + S(self.z.0 * rhs.e12oi.0),
+		//     e1    : E1(self.x.0 * rhs.e123oi.0),
+		//     e2    : E2(self.y.0 * rhs.e123oi.0),
+		//     e3    : E3(self.z.0 * rhs.e123oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.x.anti_wedge(rhs.e23oi) + self.y.anti_wedge(rhs.e13oi) + self.z.anti_wedge(rhs.e12oi),
+			e1: self.x.anti_wedge(rhs.e123oi),
+			e2: self.y.anti_wedge(rhs.e123oi),
+			e3: self.z.anti_wedge(rhs.e123oi),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+		//     e1    : -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     eo    : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : E12(self.z.0 * rhs.e123.0),
+		//     e13   : -E13(self.y.0 * rhs.e123.0),
+		//     e1o   : -E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+		//     e1i   : -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+		//     e23   : E23(self.x.0 * rhs.e123.0),
+		//     e2o   : E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+		//     e2i   : E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+		//     e3o   : E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+		//     e3i   : E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+		//     eoi   : Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+		//     e123  : Default::default(),
+		//     e12o  : E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : E12oi(self.z.0 * rhs.e123oi.0),
+		//     e13oi : -E13oi(self.y.0 * rhs.e123oi.0),
+		//     e23oi : E23oi(self.x.0 * rhs.e123oi.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+			e1: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+			eo: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: E12(self.z.0 * rhs.e123.0),
+			e13: -E13(self.y.0 * rhs.e123.0),
+			e1o: -E1o(self.y.0 * rhs.e12o.0) - E1o(self.z.0 * rhs.e13o.0),
+			e1i: -E1i(self.y.0 * rhs.e12i.0) - E1i(self.z.0 * rhs.e13i.0),
+			e23: E23(self.x.0 * rhs.e123.0),
+			e2o: E2o(self.x.0 * rhs.e12o.0) - E2o(self.z.0 * rhs.e23o.0),
+			e2i: E2i(self.x.0 * rhs.e12i.0) - E2i(self.z.0 * rhs.e23i.0),
+			e3o: E3o(self.x.0 * rhs.e13o.0) + E3o(self.y.0 * rhs.e23o.0),
+			e3i: E3i(self.x.0 * rhs.e13i.0) + E3i(self.y.0 * rhs.e23i.0),
+			eoi: Eoi(self.x.0 * rhs.e1oi.0) + Eoi(self.y.0 * rhs.e2oi.0) + Eoi(self.z.0 * rhs.e3oi.0),
+			e123: Default::default(),
+			e12o: E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: -E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: E12oi(self.z.0 * rhs.e123oi.0),
+			e13oi: -E13oi(self.y.0 * rhs.e123oi.0),
+			e23oi: E23oi(self.x.0 * rhs.e123oi.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Vec3.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+		//     e1    : E1(self.x.0 * rhs.s.0),
+		//     e2    : E2(self.y.0 * rhs.s.0),
+		//     e3    : E3(self.z.0 * rhs.s.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : Default::default(),
+		//     e13o  : Default::default(),
+		//     e13i  : Default::default(),
+		//     e1oi  : Default::default(),
+		//     e23o  : Default::default(),
+		//     e23i  : Default::default(),
+		//     e2oi  : Default::default(),
+		//     e3oi  : Default::default(),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0),
+			e1: E1(self.x.0 * rhs.s.0),
+			e2: E2(self.y.0 * rhs.s.0),
+			e3: E3(self.z.0 * rhs.s.0),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: Default::default(),
+			e13o: Default::default(),
+			e13i: Default::default(),
+			e1oi: Default::default(),
+			e23o: Default::default(),
+			e23i: Default::default(),
+			e2oi: Default::default(),
+			e3oi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Vec3.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Vec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0)
+		S(self.x.0 * rhs.e1.0) + S(self.y.0 * rhs.e2.0) + S(self.z.0 * rhs.e3.0)
+	}
+}
+
+// Vec3.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+		//     e2    : E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+		//     e3    : E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+		//     eo    : Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+		//     ei    : Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+		//     e12   : E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0),
+		//     e13   : E13(self.x.0 * rhs.e3.0) - E13(self.z.0 * rhs.e1.0),
+		//     e1o   : E1o(self.x.0 * rhs.eo.0),
+		//     e1i   : E1i(self.x.0 * rhs.ei.0),
+		//     e23   : E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+		//     e2o   : E2o(self.y.0 * rhs.eo.0),
+		//     e2i   : E2i(self.y.0 * rhs.ei.0),
+		//     e3o   : E3o(self.z.0 * rhs.eo.0),
+		//     e3i   : E3i(self.z.0 * rhs.ei.0),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : E12o(self.z.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.z.0 * rhs.e123i.0),
+		//     e13o  : -E13o(self.y.0 * rhs.e123o.0),
+		//     e13i  : -E13i(self.y.0 * rhs.e123i.0),
+		//     e1oi  : -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+		//     e23o  : E23o(self.x.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.x.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+		//     e3oi  : E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+		//     e123o : E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+		//     e123i : E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+		//     e12oi : E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+		//     e13oi : E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+		//     e23oi : E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.y.0 * rhs.e12.0) - E1(self.z.0 * rhs.e13.0),
+			e2: E2(self.x.0 * rhs.e12.0) - E2(self.z.0 * rhs.e23.0),
+			e3: E3(self.x.0 * rhs.e13.0) + E3(self.y.0 * rhs.e23.0),
+			eo: Eo(self.x.0 * rhs.e1o.0) + Eo(self.y.0 * rhs.e2o.0) + Eo(self.z.0 * rhs.e3o.0),
+			ei: Ei(self.x.0 * rhs.e1i.0) + Ei(self.y.0 * rhs.e2i.0) + Ei(self.z.0 * rhs.e3i.0),
+			e12: E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0),
+			e13: E13(self.x.0 * rhs.e3.0) - E13(self.z.0 * rhs.e1.0),
+			e1o: E1o(self.x.0 * rhs.eo.0),
+			e1i: E1i(self.x.0 * rhs.ei.0),
+			e23: E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+			e2o: E2o(self.y.0 * rhs.eo.0),
+			e2i: E2i(self.y.0 * rhs.ei.0),
+			e3o: E3o(self.z.0 * rhs.eo.0),
+			e3i: E3i(self.z.0 * rhs.ei.0),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: E12o(self.z.0 * rhs.e123o.0),
+			e12i: E12i(self.z.0 * rhs.e123i.0),
+			e13o: -E13o(self.y.0 * rhs.e123o.0),
+			e13i: -E13i(self.y.0 * rhs.e123i.0),
+			e1oi: -E1oi(self.y.0 * rhs.e12oi.0) - E1oi(self.z.0 * rhs.e13oi.0),
+			e23o: E23o(self.x.0 * rhs.e123o.0),
+			e23i: E23i(self.x.0 * rhs.e123i.0),
+			e2oi: E2oi(self.x.0 * rhs.e12oi.0) - E2oi(self.z.0 * rhs.e23oi.0),
+			e3oi: E3oi(self.x.0 * rhs.e13oi.0) + E3oi(self.y.0 * rhs.e23oi.0),
+			e123o: E123o(self.x.0 * rhs.e23o.0) - E123o(self.y.0 * rhs.e13o.0) + E123o(self.z.0 * rhs.e12o.0),
+			e123i: E123i(self.x.0 * rhs.e23i.0) - E123i(self.y.0 * rhs.e13i.0) + E123i(self.z.0 * rhs.e12i.0),
+			e12oi: E12oi(self.x.0 * rhs.e2oi.0) - E12oi(self.y.0 * rhs.e1oi.0),
+			e13oi: E13oi(self.x.0 * rhs.e3oi.0) - E13oi(self.z.0 * rhs.e1oi.0),
+			e23oi: E23oi(self.y.0 * rhs.e3oi.0) - E23oi(self.z.0 * rhs.e2oi.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Vec3.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.x.0 * rhs.e123oi.0),
+		//     e2    : E2(self.y.0 * rhs.e123oi.0),
+		//     e3    : E3(self.z.0 * rhs.e123oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+		//     e13   : -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+		//     e1o   : E1o(self.x.0 * rhs.e123o.0),
+		//     e1i   : -E1i(self.x.0 * rhs.e123i.0),
+		//     e23   : -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+		//     e2o   : E2o(self.y.0 * rhs.e123o.0),
+		//     e2i   : -E2i(self.y.0 * rhs.e123i.0),
+		//     e3o   : E3o(self.z.0 * rhs.e123o.0),
+		//     e3i   : -E3i(self.z.0 * rhs.e123i.0),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+		//     e12o  : E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+		//     e12i  : -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+		//     e13o  : -E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+		//     e13i  : E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+		//     e1oi  : E1oi(self.x.0 * rhs.e123.0),
+		//     e23o  : -E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+		//     e23i  : E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+		//     e2oi  : E2oi(self.y.0 * rhs.e123.0),
+		//     e3oi  : E3oi(self.z.0 * rhs.e123.0),
+		//     e123o : -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+		//     e123i : E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+		//     e12oi : E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+		//     e13oi : -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+		//     e23oi : -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+		//     e123oi: -E123oi(self.x.0 * rhs.e1.0) - E123oi(self.y.0 * rhs.e2.0) - E123oi(self.z.0 * rhs.e3.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.x.0 * rhs.e123oi.0),
+			e2: E2(self.y.0 * rhs.e123oi.0),
+			e3: E3(self.z.0 * rhs.e123oi.0),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: E12(self.x.0 * rhs.e13oi.0) + E12(self.y.0 * rhs.e23oi.0),
+			e13: -E13(self.x.0 * rhs.e12oi.0) + E13(self.z.0 * rhs.e23oi.0),
+			e1o: E1o(self.x.0 * rhs.e123o.0),
+			e1i: -E1i(self.x.0 * rhs.e123i.0),
+			e23: -E23(self.y.0 * rhs.e12oi.0) - E23(self.z.0 * rhs.e13oi.0),
+			e2o: E2o(self.y.0 * rhs.e123o.0),
+			e2i: -E2i(self.y.0 * rhs.e123i.0),
+			e3o: E3o(self.z.0 * rhs.e123o.0),
+			e3i: -E3i(self.z.0 * rhs.e123i.0),
+			eoi: Default::default(),
+			e123: -E123(self.x.0 * rhs.e1oi.0) - E123(self.y.0 * rhs.e2oi.0) - E123(self.z.0 * rhs.e3oi.0),
+			e12o: E12o(self.x.0 * rhs.e13o.0) + E12o(self.y.0 * rhs.e23o.0),
+			e12i: -E12i(self.x.0 * rhs.e13i.0) - E12i(self.y.0 * rhs.e23i.0),
+			e13o: -E13o(self.x.0 * rhs.e12o.0) + E13o(self.z.0 * rhs.e23o.0),
+			e13i: E13i(self.x.0 * rhs.e12i.0) - E13i(self.z.0 * rhs.e23i.0),
+			e1oi: E1oi(self.x.0 * rhs.e123.0),
+			e23o: -E23o(self.y.0 * rhs.e12o.0) - E23o(self.z.0 * rhs.e13o.0),
+			e23i: E23i(self.y.0 * rhs.e12i.0) + E23i(self.z.0 * rhs.e13i.0),
+			e2oi: E2oi(self.y.0 * rhs.e123.0),
+			e3oi: E3oi(self.z.0 * rhs.e123.0),
+			e123o: -E123o(self.x.0 * rhs.e1o.0) - E123o(self.y.0 * rhs.e2o.0) - E123o(self.z.0 * rhs.e3o.0),
+			e123i: E123i(self.x.0 * rhs.e1i.0) + E123i(self.y.0 * rhs.e2i.0) + E123i(self.z.0 * rhs.e3i.0),
+			e12oi: E12oi(self.x.0 * rhs.e13.0) + E12oi(self.y.0 * rhs.e23.0) - E12oi(self.z.0 * rhs.s.0),
+			e13oi: -E13oi(self.x.0 * rhs.e12.0) + E13oi(self.y.0 * rhs.s.0) + E13oi(self.z.0 * rhs.e23.0),
+			e23oi: -E23oi(self.x.0 * rhs.s.0) - E23oi(self.y.0 * rhs.e12.0) - E23oi(self.z.0 * rhs.e13.0),
+			e123oi: -E123oi(self.x.0 * rhs.e1.0) - E123oi(self.y.0 * rhs.e2.0) - E123oi(self.z.0 * rhs.e3.0),
+		}
+	}
+}
@@ -0,0 +1,13943 @@
+//! # Versor
+//!
+//! ## Operations
+//! ```text
+//! Versor.geometric(Versor) -> Versor
+//! Versor.anti_geometric(Versor) -> Multivector
+//! Versor.dot(Versor) -> Versor
+//! Versor.wedge(Versor) -> Versor
+//! Versor.anti_wedge(Versor) -> Multivector
+//! Versor.left_contraction(Versor) -> Versor
+//! Versor.right_contraction(Versor) -> Versor
+//! Versor.scalar_product(Versor) -> S
+//! Versor.commutator(Versor) -> Versor
+//! Versor.anti_dot(Versor) -> Multivector
+//! Versor.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Versor) -> Multivector
+//! Versor.anti_geometric(Vec3) -> Versor
+//! Vec3.anti_geometric(Versor) -> Versor
+//! Versor.dot(Vec3) -> Multivector
+//! Vec3.dot(Versor) -> Multivector
+//! Versor.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Versor) -> Multivector
+//! Versor.anti_wedge(Vec3) -> S
+//! Vec3.anti_wedge(Versor) -> S
+//! Versor.left_contraction(Vec3) -> Vec3
+//! Vec3.left_contraction(Versor) -> Multivector
+//! Versor.right_contraction(Vec3) -> Multivector
+//! Vec3.right_contraction(Versor) -> Vec3
+//! Versor.commutator(Vec3) -> Multivector
+//! Vec3.commutator(Versor) -> Multivector
+//! Versor.anti_dot(Vec3) -> Versor
+//! Vec3.anti_dot(Versor) -> Versor
+//! Versor.geometric(Point) -> Multivector
+//! Point.geometric(Versor) -> Multivector
+//! Versor.anti_geometric(Point) -> Versor
+//! Point.anti_geometric(Versor) -> Versor
+//! Versor.dot(Point) -> Multivector
+//! Point.dot(Versor) -> Multivector
+//! Versor.wedge(Point) -> Multivector
+//! Point.wedge(Versor) -> Multivector
+//! Versor.anti_wedge(Point) -> S
+//! Point.anti_wedge(Versor) -> S
+//! Versor.left_contraction(Point) -> Point
+//! Point.left_contraction(Versor) -> Multivector
+//! Versor.right_contraction(Point) -> Multivector
+//! Point.right_contraction(Versor) -> Point
+//! Versor.commutator(Point) -> Multivector
+//! Point.commutator(Versor) -> Multivector
+//! Versor.anti_dot(Point) -> Versor
+//! Point.anti_dot(Versor) -> Versor
+//! Versor.geometric(FlatPoint) -> Versor
+//! FlatPoint.geometric(Versor) -> Versor
+//! Versor.anti_geometric(FlatPoint) -> Multivector
+//! FlatPoint.anti_geometric(Versor) -> Multivector
+//! Versor.dot(FlatPoint) -> Versor
+//! FlatPoint.dot(Versor) -> Versor
+//! Versor.wedge(FlatPoint) -> Versor
+//! FlatPoint.wedge(Versor) -> Versor
+//! Versor.anti_wedge(FlatPoint) -> Point
+//! FlatPoint.anti_wedge(Versor) -> Point
+//! Versor.left_contraction(FlatPoint) -> Versor
+//! FlatPoint.left_contraction(Versor) -> Versor
+//! Versor.right_contraction(FlatPoint) -> Versor
+//! FlatPoint.right_contraction(Versor) -> Versor
+//! Versor.scalar_product(FlatPoint) -> S
+//! FlatPoint.scalar_product(Versor) -> S
+//! Versor.commutator(FlatPoint) -> Versor
+//! FlatPoint.commutator(Versor) -> Versor
+//! Versor.anti_dot(FlatPoint) -> Multivector
+//! FlatPoint.anti_dot(Versor) -> Multivector
+//! Versor.geometric(PointPair) -> Versor
+//! PointPair.geometric(Versor) -> Versor
+//! Versor.anti_geometric(PointPair) -> Multivector
+//! PointPair.anti_geometric(Versor) -> Multivector
+//! Versor.dot(PointPair) -> Versor
+//! PointPair.dot(Versor) -> Versor
+//! Versor.wedge(PointPair) -> Versor
+//! PointPair.wedge(Versor) -> Versor
+//! Versor.anti_wedge(PointPair) -> Point
+//! PointPair.anti_wedge(Versor) -> Point
+//! Versor.left_contraction(PointPair) -> Versor
+//! PointPair.left_contraction(Versor) -> Versor
+//! Versor.right_contraction(PointPair) -> Versor
+//! PointPair.right_contraction(Versor) -> Versor
+//! Versor.scalar_product(PointPair) -> S
+//! PointPair.scalar_product(Versor) -> S
+//! Versor.commutator(PointPair) -> Versor
+//! PointPair.commutator(Versor) -> Versor
+//! Versor.anti_dot(PointPair) -> Multivector
+//! PointPair.anti_dot(Versor) -> Multivector
+//! Versor.geometric(Line) -> Multivector
+//! Line.geometric(Versor) -> Multivector
+//! Versor.anti_geometric(Line) -> Versor
+//! Line.anti_geometric(Versor) -> Versor
+//! Versor.dot(Line) -> Multivector
+//! Line.dot(Versor) -> Multivector
+//! Versor.wedge(Line) -> Multivector
+//! Line.wedge(Versor) -> Multivector
+//! Versor.anti_wedge(Line) -> Versor
+//! Line.anti_wedge(Versor) -> Versor
+//! Versor.left_contraction(Line) -> Multivector
+//! Line.left_contraction(Versor) -> Point
+//! Versor.right_contraction(Line) -> Point
+//! Line.right_contraction(Versor) -> Multivector
+//! Versor.commutator(Line) -> Multivector
+//! Line.commutator(Versor) -> Multivector
+//! Versor.anti_dot(Line) -> Versor
+//! Line.anti_dot(Versor) -> Versor
+//! Versor.geometric(Circle) -> Multivector
+//! Circle.geometric(Versor) -> Multivector
+//! Versor.anti_geometric(Circle) -> Versor
+//! Circle.anti_geometric(Versor) -> Versor
+//! Versor.dot(Circle) -> Multivector
+//! Circle.dot(Versor) -> Multivector
+//! Versor.wedge(Circle) -> Multivector
+//! Circle.wedge(Versor) -> Multivector
+//! Versor.anti_wedge(Circle) -> Versor
+//! Circle.anti_wedge(Versor) -> Versor
+//! Versor.left_contraction(Circle) -> Multivector
+//! Circle.left_contraction(Versor) -> Point
+//! Versor.right_contraction(Circle) -> Point
+//! Circle.right_contraction(Versor) -> Multivector
+//! Versor.commutator(Circle) -> Multivector
+//! Circle.commutator(Versor) -> Multivector
+//! Versor.anti_dot(Circle) -> Versor
+//! Circle.anti_dot(Versor) -> Versor
+//! Versor.geometric(Plane) -> Versor
+//! Plane.geometric(Versor) -> Versor
+//! Versor.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Versor) -> Multivector
+//! Versor.dot(Plane) -> Versor
+//! Plane.dot(Versor) -> Versor
+//! Versor.wedge(Plane) -> Plane
+//! Plane.wedge(Versor) -> Plane
+//! Versor.anti_wedge(Plane) -> Multivector
+//! Plane.anti_wedge(Versor) -> Multivector
+//! Versor.left_contraction(Plane) -> Versor
+//! Plane.left_contraction(Versor) -> S
+//! Versor.right_contraction(Plane) -> S
+//! Plane.right_contraction(Versor) -> Versor
+//! Versor.scalar_product(Plane) -> S
+//! Plane.scalar_product(Versor) -> S
+//! Versor.commutator(Plane) -> Versor
+//! Plane.commutator(Versor) -> Versor
+//! Versor.anti_dot(Plane) -> Multivector
+//! Plane.anti_dot(Versor) -> Multivector
+//! Versor.geometric(Sphere) -> Versor
+//! Sphere.geometric(Versor) -> Versor
+//! Versor.anti_geometric(Sphere) -> Multivector
+//! Sphere.anti_geometric(Versor) -> Multivector
+//! Versor.dot(Sphere) -> Versor
+//! Sphere.dot(Versor) -> Versor
+//! Versor.wedge(Sphere) -> Sphere
+//! Sphere.wedge(Versor) -> Sphere
+//! Versor.anti_wedge(Sphere) -> Multivector
+//! Sphere.anti_wedge(Versor) -> Multivector
+//! Versor.left_contraction(Sphere) -> Versor
+//! Sphere.left_contraction(Versor) -> S
+//! Versor.right_contraction(Sphere) -> S
+//! Sphere.right_contraction(Versor) -> Versor
+//! Versor.scalar_product(Sphere) -> S
+//! Sphere.scalar_product(Versor) -> S
+//! Versor.commutator(Sphere) -> Versor
+//! Sphere.commutator(Versor) -> Versor
+//! Versor.anti_dot(Sphere) -> Multivector
+//! Sphere.anti_dot(Versor) -> Multivector
+//! Versor.geometric(Rotor) -> Versor
+//! Rotor.geometric(Versor) -> Versor
+//! Versor.anti_geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Versor) -> Multivector
+//! Versor.dot(Rotor) -> Versor
+//! Rotor.dot(Versor) -> Versor
+//! Versor.wedge(Rotor) -> Versor
+//! Rotor.wedge(Versor) -> Versor
+//! Versor.anti_wedge(Rotor) -> Vec3
+//! Rotor.anti_wedge(Versor) -> Vec3
+//! Versor.left_contraction(Rotor) -> Rotor
+//! Rotor.left_contraction(Versor) -> Versor
+//! Versor.right_contraction(Rotor) -> Versor
+//! Rotor.right_contraction(Versor) -> Rotor
+//! Versor.scalar_product(Rotor) -> S
+//! Rotor.scalar_product(Versor) -> S
+//! Versor.commutator(Rotor) -> Versor
+//! Rotor.commutator(Versor) -> Versor
+//! Versor.anti_dot(Rotor) -> Multivector
+//! Rotor.anti_dot(Versor) -> Multivector
+//! Versor.geometric(Translator) -> Versor
+//! Translator.geometric(Versor) -> Versor
+//! Versor.anti_geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Versor) -> Multivector
+//! Versor.dot(Translator) -> Versor
+//! Translator.dot(Versor) -> Versor
+//! Versor.wedge(Translator) -> Versor
+//! Translator.wedge(Versor) -> Versor
+//! Versor.anti_wedge(Translator) -> Point
+//! Translator.anti_wedge(Versor) -> Point
+//! Versor.left_contraction(Translator) -> Translator
+//! Translator.left_contraction(Versor) -> Versor
+//! Versor.right_contraction(Translator) -> Versor
+//! Translator.right_contraction(Versor) -> Translator
+//! Versor.scalar_product(Translator) -> S
+//! Translator.scalar_product(Versor) -> S
+//! Versor.commutator(Translator) -> Versor
+//! Translator.commutator(Versor) -> Versor
+//! Versor.anti_dot(Translator) -> Multivector
+//! Translator.anti_dot(Versor) -> Multivector
+//! Versor.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Versor) -> Multivector
+//! Versor.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Versor) -> Multivector
+//! Versor.dot(Multivector) -> Multivector
+//! Multivector.dot(Versor) -> Multivector
+//! Versor.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Versor) -> Multivector
+//! Versor.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Versor) -> Multivector
+//! Versor.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Versor) -> Multivector
+//! Versor.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Versor) -> Multivector
+//! Versor.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Versor) -> S
+//! Versor.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Versor) -> Multivector
+//! Versor.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Versor) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Versor {
+	pub s: S,
+	pub e12: E12,
+	pub e13: E13,
+	pub e1o: E1o,
+	pub e1i: E1i,
+	pub e23: E23,
+	pub e2o: E2o,
+	pub e2i: E2i,
+	pub e3o: E3o,
+	pub e3i: E3i,
+	pub eoi: Eoi,
+	pub e123o: E123o,
+	pub e123i: E123i,
+	pub e12oi: E12oi,
+	pub e13oi: E13oi,
+	pub e23oi: E23oi,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Versor {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Versor {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Versor {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.e12.abs_diff_eq(&other.e12, epsilon)
+			&& self.e13.abs_diff_eq(&other.e13, epsilon)
+			&& self.e1o.abs_diff_eq(&other.e1o, epsilon)
+			&& self.e1i.abs_diff_eq(&other.e1i, epsilon)
+			&& self.e23.abs_diff_eq(&other.e23, epsilon)
+			&& self.e2o.abs_diff_eq(&other.e2o, epsilon)
+			&& self.e2i.abs_diff_eq(&other.e2i, epsilon)
+			&& self.e3o.abs_diff_eq(&other.e3o, epsilon)
+			&& self.e3i.abs_diff_eq(&other.e3i, epsilon)
+			&& self.eoi.abs_diff_eq(&other.eoi, epsilon)
+			&& self.e123o.abs_diff_eq(&other.e123o, epsilon)
+			&& self.e123i.abs_diff_eq(&other.e123i, epsilon)
+			&& self.e12oi.abs_diff_eq(&other.e12oi, epsilon)
+			&& self.e13oi.abs_diff_eq(&other.e13oi, epsilon)
+			&& self.e23oi.abs_diff_eq(&other.e23oi, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Versor {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.e12.relative_eq(&other.e12, epsilon, max_relative)
+			&& self.e13.relative_eq(&other.e13, epsilon, max_relative)
+			&& self.e1o.relative_eq(&other.e1o, epsilon, max_relative)
+			&& self.e1i.relative_eq(&other.e1i, epsilon, max_relative)
+			&& self.e23.relative_eq(&other.e23, epsilon, max_relative)
+			&& self.e2o.relative_eq(&other.e2o, epsilon, max_relative)
+			&& self.e2i.relative_eq(&other.e2i, epsilon, max_relative)
+			&& self.e3o.relative_eq(&other.e3o, epsilon, max_relative)
+			&& self.e3i.relative_eq(&other.e3i, epsilon, max_relative)
+			&& self.eoi.relative_eq(&other.eoi, epsilon, max_relative)
+			&& self.e123o.relative_eq(&other.e123o, epsilon, max_relative)
+			&& self.e123i.relative_eq(&other.e123i, epsilon, max_relative)
+			&& self.e12oi.relative_eq(&other.e12oi, epsilon, max_relative)
+			&& self.e13oi.relative_eq(&other.e13oi, epsilon, max_relative)
+			&& self.e23oi.relative_eq(&other.e23oi, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Versor {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e1: self.e23oi.rcompl(),
+			e2: self.e13oi.rcompl(),
+			e3: self.e12oi.rcompl(),
+			eo: self.e123i.rcompl(),
+			ei: self.e123o.rcompl(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.eoi.rcompl(),
+			e12o: self.e3i.rcompl(),
+			e12i: self.e3o.rcompl(),
+			e13o: self.e2i.rcompl(),
+			e13i: self.e2o.rcompl(),
+			e1oi: self.e23.rcompl(),
+			e23o: self.e1i.rcompl(),
+			e23i: self.e1o.rcompl(),
+			e2oi: self.e13.rcompl(),
+			e3oi: self.e12.rcompl(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Versor {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Versor {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e1: self.e23oi.lcompl(),
+			e2: self.e13oi.lcompl(),
+			e3: self.e12oi.lcompl(),
+			eo: self.e123i.lcompl(),
+			ei: self.e123o.lcompl(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.eoi.lcompl(),
+			e12o: self.e3i.lcompl(),
+			e12i: self.e3o.lcompl(),
+			e13o: self.e2i.lcompl(),
+			e13i: self.e2o.lcompl(),
+			e1oi: self.e23.lcompl(),
+			e23o: self.e1i.lcompl(),
+			e23i: self.e1o.lcompl(),
+			e2oi: self.e13.lcompl(),
+			e3oi: self.e12.lcompl(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.s.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Versor {
+	fn rev(self) -> Self {
+		Versor {
+			s: self.s,
+			e12: -self.e12,
+			e13: -self.e13,
+			e1o: -self.e1o,
+			e1i: -self.e1i,
+			e23: -self.e23,
+			e2o: -self.e2o,
+			e2i: -self.e2i,
+			e3o: -self.e3o,
+			e3i: -self.e3i,
+			eoi: -self.eoi,
+			e123o: self.e123o,
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl AntiReverse for Versor {
+	fn arev(self) -> Self {
+		Versor {
+			s: self.s,
+			e12: -self.e12,
+			e13: -self.e13,
+			e1o: -self.e1o,
+			e1i: -self.e1i,
+			e23: -self.e23,
+			e2o: -self.e2o,
+			e2i: -self.e2i,
+			e3o: -self.e3o,
+			e3i: -self.e3i,
+			eoi: -self.eoi,
+			e123o: self.e123o,
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl Involute for Versor {
+	fn involute(self) -> Self {
+		Versor {
+			s: self.s,
+			e12: self.e12,
+			e13: self.e13,
+			e1o: self.e1o,
+			e1i: self.e1i,
+			e23: self.e23,
+			e2o: self.e2o,
+			e2i: self.e2i,
+			e3o: self.e3o,
+			e3i: self.e3i,
+			eoi: self.eoi,
+			e123o: self.e123o,
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl Conjugate for Versor {
+	fn conjugate(self) -> Self {
+		Versor {
+			s: self.s,
+			e12: -self.e12,
+			e13: -self.e13,
+			e1o: -self.e1o,
+			e1i: -self.e1i,
+			e23: -self.e23,
+			e2o: -self.e2o,
+			e2i: -self.e2i,
+			e3o: -self.e3o,
+			e3i: -self.e3i,
+			eoi: -self.eoi,
+			e123o: self.e123o,
+			e123i: self.e123i,
+			e12oi: self.e12oi,
+			e13oi: self.e13oi,
+			e23oi: self.e23oi,
+		}
+	}
+}
+
+impl Dual for Versor {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e1: self.e23oi.dual(),
+			e2: self.e13oi.dual(),
+			e3: self.e12oi.dual(),
+			eo: self.e123i.dual(),
+			ei: self.e123o.dual(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.eoi.dual(),
+			e12o: self.e3i.dual(),
+			e12i: self.e3o.dual(),
+			e13o: self.e2i.dual(),
+			e13i: self.e2o.dual(),
+			e1oi: self.e23.dual(),
+			e23o: self.e1i.dual(),
+			e23i: self.e1o.dual(),
+			e2oi: self.e13.dual(),
+			e3oi: self.e12.dual(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.s.dual(),
+		}
+	}
+}
+
+// Omitted: Versor.square()  (not a scalar)
+// Omitted: Norm for Versor  (the bulk and weight need a diagonal metric)
+
+impl Versor {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 16] {
+		[
+			self.s.0,
+			self.e12.0,
+			self.e13.0,
+			self.e1o.0,
+			self.e1i.0,
+			self.e23.0,
+			self.e2o.0,
+			self.e2i.0,
+			self.e3o.0,
+			self.e3i.0,
+			self.eoi.0,
+			self.e123o.0,
+			self.e123i.0,
+			self.e12oi.0,
+			self.e13oi.0,
+			self.e23oi.0,
+		]
+	}
+}
+
+impl From<[f64; 16]> for Versor {
+	fn from(a: [f64; 16]) -> Self {
+		Versor {
+			s: S(a[0]),
+			e12: E12(a[1]),
+			e13: E13(a[2]),
+			e1o: E1o(a[3]),
+			e1i: E1i(a[4]),
+			e23: E23(a[5]),
+			e2o: E2o(a[6]),
+			e2i: E2i(a[7]),
+			e3o: E3o(a[8]),
+			e3i: E3i(a[9]),
+			eoi: Eoi(a[10]),
+			e123o: E123o(a[11]),
+			e123i: E123i(a[12]),
+			e12oi: E12oi(a[13]),
+			e13oi: E13oi(a[14]),
+			e23oi: E23oi(a[15]),
+		}
+	}
+}
+
+impl From<Versor> for [f64; 16] {
+	fn from(v: Versor) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Versor> for Multivector {
+	fn from(v: Versor) -> Self {
+		Multivector {
+			s: v.s,
+			e12: v.e12,
+			e13: v.e13,
+			e1o: v.e1o,
+			e1i: v.e1i,
+			e23: v.e23,
+			e2o: v.e2o,
+			e2i: v.e2i,
+			e3o: v.e3o,
+			e3i: v.e3i,
+			eoi: v.eoi,
+			e123o: v.e123o,
+			e123i: v.e123i,
+			e12oi: v.e12oi,
+			e13oi: v.e13oi,
+			e23oi: v.e23oi,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Versor {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Versor` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.eo.0 == 0.0
+			&& mv.ei.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e12o.0 == 0.0
+			&& mv.e12i.0 == 0.0
+			&& mv.e13o.0 == 0.0
+			&& mv.e13i.0 == 0.0
+			&& mv.e1oi.0 == 0.0
+			&& mv.e23o.0 == 0.0
+			&& mv.e23i.0 == 0.0
+			&& mv.e2oi.0 == 0.0
+			&& mv.e3oi.0 == 0.0
+			&& mv.e123oi.0 == 0.0
+		{
+			Ok(Versor {
+				s: mv.s,
+				e12: mv.e12,
+				e13: mv.e13,
+				e1o: mv.e1o,
+				e1i: mv.e1i,
+				e23: mv.e23,
+				e2o: mv.e2o,
+				e2i: mv.e2i,
+				e3o: mv.e3o,
+				e3i: mv.e3i,
+				eoi: mv.eoi,
+				e123o: mv.e123o,
+				e123i: mv.e123i,
+				e12oi: mv.e12oi,
+				e13oi: mv.e13oi,
+				e23oi: mv.e23oi,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP Vec3:
+
+// Versor.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Versor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0) - E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.y.0) - E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0) - E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0) + E1oi(self.eoi.0 * rhs.x.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0) - E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0) + E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0) + E3oi(self.eoi.0 * rhs.z.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+			e3: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+			eo: -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+			e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) - E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) - E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0) + E1oi(self.eoi.0 * rhs.x.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) - E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0) + E2oi(self.eoi.0 * rhs.y.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0) + E3oi(self.eoi.0 * rhs.z.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Versor {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(Vec3) -> Versor
+impl AntiGeometric<Vec3> for Versor {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0) - E12(self.eoi.0 * rhs.z.0),
+		//     e13  : E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0) + E13(self.eoi.0 * rhs.y.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.x.0) + E1o(self.e2o.0 * rhs.z.0) - E1o(self.e3o.0 * rhs.y.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.x.0) - E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+		//     e23  : E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0) - E23(self.eoi.0 * rhs.x.0),
+		//     e2o  : -E2o(self.e123o.0 * rhs.y.0) - E2o(self.e1o.0 * rhs.z.0) + E2o(self.e3o.0 * rhs.x.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.y.0) + E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.z.0) + E3o(self.e1o.0 * rhs.y.0) - E3o(self.e2o.0 * rhs.x.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.z.0) - E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+		//     eoi  : Eoi(self.e12.0 * rhs.z.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+		//     e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		// }
+		Versor {
+			s: S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0),
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0) - E12(self.eoi.0 * rhs.z.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0) + E13(self.eoi.0 * rhs.y.0),
+			e1o: -E1o(self.e123o.0 * rhs.x.0) + E1o(self.e2o.0 * rhs.z.0) - E1o(self.e3o.0 * rhs.y.0),
+			e1i: E1i(self.e123i.0 * rhs.x.0) - E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0) - E23(self.eoi.0 * rhs.x.0),
+			e2o: -E2o(self.e123o.0 * rhs.y.0) - E2o(self.e1o.0 * rhs.z.0) + E2o(self.e3o.0 * rhs.x.0),
+			e2i: E2i(self.e123i.0 * rhs.y.0) + E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+			e3o: -E3o(self.e123o.0 * rhs.z.0) + E3o(self.e1o.0 * rhs.y.0) - E3o(self.e2o.0 * rhs.x.0),
+			e3i: E3i(self.e123i.0 * rhs.z.0) - E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+			eoi: Eoi(self.e12.0 * rhs.z.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+			e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// Versor.dot(Vec3) -> Multivector
+impl Dot<Vec3> for Versor {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : -E12o(self.e123o.0 * rhs.z.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.y.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.x.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+			e3: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+			eo: -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: -E12o(self.e123o.0 * rhs.z.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Versor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Versor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.x.0),
+		//     e2    : E2(self.s.0 * rhs.y.0),
+		//     e3    : E3(self.s.0 * rhs.z.0),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o  : -E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+		//     e12i  : -E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : -E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+		//     e13i  : -E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : E1oi(self.eoi.0 * rhs.x.0),
+		//     e23o  : -E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+		//     e23i  : -E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi  : E3oi(self.eoi.0 * rhs.z.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.s.wedge(rhs.x),
+			e2: self.s.wedge(rhs.y),
+			e3: self.s.wedge(rhs.z),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e12.wedge(rhs.z) + self.e13.wedge(rhs.y) + self.e23.wedge(rhs.x),
+			e12o: self.e1o.wedge(rhs.y) + self.e2o.wedge(rhs.x),
+			e12i: self.e1i.wedge(rhs.y) + self.e2i.wedge(rhs.x),
+			e13o: self.e1o.wedge(rhs.z) + self.e3o.wedge(rhs.x),
+			e13i: self.e1i.wedge(rhs.z) + self.e3i.wedge(rhs.x),
+			e1oi: self.eoi.wedge(rhs.x),
+			e23o: self.e2o.wedge(rhs.z) + self.e3o.wedge(rhs.y),
+			e23i: self.e2i.wedge(rhs.z) + self.e3i.wedge(rhs.y),
+			e2oi: self.eoi.wedge(rhs.y),
+			e3oi: self.eoi.wedge(rhs.z),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e12oi.wedge(rhs.z) + self.e13oi.wedge(rhs.y) + self.e23oi.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Versor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(Vec3) -> S
+impl AntiWedge<Vec3> for Versor {
+	type Output = S;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0)
+		self.e12oi.anti_wedge(rhs.z) + self.e13oi.anti_wedge(rhs.y) + self.e23oi.anti_wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Versor {
+	type Output = S;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(Vec3) -> Vec3
+impl LeftContraction<Vec3> for Versor {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.s.0 * rhs.x.0),
+		//     y: E2(self.s.0 * rhs.y.0),
+		//     z: E3(self.s.0 * rhs.z.0),
+		// }
+		Vec3 {
+			x: E1(self.s.0 * rhs.x.0),
+			y: E2(self.s.0 * rhs.y.0),
+			z: E3(self.s.0 * rhs.z.0),
+		}
+	}
+}
+
+// Versor.right_contraction(Vec3) -> Multivector
+impl RightContraction<Vec3> for Versor {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : -E12o(self.e123o.0 * rhs.z.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.y.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.x.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+			e3: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+			eo: -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: -E12o(self.e123o.0 * rhs.z.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Versor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Versor.commutator(Vec3) -> Multivector
+impl Commutator<Vec3> for Versor {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : -E12o(self.e123o.0 * rhs.z.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.y.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.x.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0),
+			e3: -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0),
+			eo: -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: -E12o(self.e123o.0 * rhs.z.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Versor.anti_dot(Vec3) -> Versor
+impl AntiDot<Vec3> for Versor {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+		//     e13  : E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.x.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.x.0),
+		//     e23  : E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+		//     e2o  : -E2o(self.e123o.0 * rhs.y.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.y.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.z.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.z.0),
+		//     eoi  : Default::default(),
+		//     e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+			e1o: -E1o(self.e123o.0 * rhs.x.0),
+			e1i: E1i(self.e123i.0 * rhs.x.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+			e2o: -E2o(self.e123o.0 * rhs.y.0),
+			e2i: E2i(self.e123i.0 * rhs.y.0),
+			e3o: -E3o(self.e123o.0 * rhs.z.0),
+			e3i: E3i(self.e123i.0 * rhs.z.0),
+			eoi: Default::default(),
+			e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.s.0 * rhs.y.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP Point:
+
+// Versor.geometric(Point) -> Multivector
+impl Geometric<Point> for Versor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) - E1(self.e1i.0 * rhs.o.0) - E1(self.e1o.0 * rhs.inf.0) + E1(self.s.0 * rhs.x.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) - E2(self.e2i.0 * rhs.o.0) - E2(self.e2o.0 * rhs.inf.0) + E2(self.s.0 * rhs.y.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) - E3(self.e3i.0 * rhs.o.0) - E3(self.e3o.0 * rhs.inf.0) + E3(self.s.0 * rhs.z.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0) - Eo(self.eoi.0 * rhs.o.0) + Eo(self.s.0 * rhs.o.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0) + Ei(self.s.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.z.0) - E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o  : E12o(self.e12.0 * rhs.o.0) - E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0) - E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+		//     e12i  : E12i(self.e12.0 * rhs.inf.0) - E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0) - E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.y.0) + E13o(self.e13.0 * rhs.o.0) - E13o(self.e13oi.0 * rhs.o.0) - E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13.0 * rhs.inf.0) + E13i(self.e13oi.0 * rhs.inf.0) - E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0) - E1oi(self.e1i.0 * rhs.o.0) + E1oi(self.e1o.0 * rhs.inf.0) + E1oi(self.eoi.0 * rhs.x.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.x.0) + E23o(self.e23.0 * rhs.o.0) - E23o(self.e23oi.0 * rhs.o.0) - E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23.0 * rhs.inf.0) + E23i(self.e23oi.0 * rhs.inf.0) - E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0) - E2oi(self.e2i.0 * rhs.o.0) + E2oi(self.e2o.0 * rhs.inf.0) + E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0) - E3oi(self.e3i.0 * rhs.o.0) + E3oi(self.e3o.0 * rhs.inf.0) + E3oi(self.eoi.0 * rhs.z.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e123i.0 * rhs.o.0) + E123oi(self.e123o.0 * rhs.inf.0) + E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0)
+				- E1(self.e1i.0 * rhs.o.0)
+				- E1(self.e1o.0 * rhs.inf.0)
+				+ E1(self.s.0 * rhs.x.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0)
+				- E2(self.e2i.0 * rhs.o.0)
+				- E2(self.e2o.0 * rhs.inf.0)
+				+ E2(self.s.0 * rhs.y.0),
+			e3: -E3(self.e13.0 * rhs.x.0)
+				- E3(self.e23.0 * rhs.y.0)
+				- E3(self.e3i.0 * rhs.o.0)
+				- E3(self.e3o.0 * rhs.inf.0)
+				+ E3(self.s.0 * rhs.z.0),
+			eo: -Eo(self.e1o.0 * rhs.x.0)
+				- Eo(self.e2o.0 * rhs.y.0)
+				- Eo(self.e3o.0 * rhs.z.0)
+				- Eo(self.eoi.0 * rhs.o.0)
+				+ Eo(self.s.0 * rhs.o.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0)
+				+ Ei(self.s.0 * rhs.inf.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.z.0)
+				- E123(self.e123i.0 * rhs.o.0)
+				- E123(self.e123o.0 * rhs.inf.0)
+				- E123(self.e13.0 * rhs.y.0)
+				+ E123(self.e23.0 * rhs.x.0),
+			e12o: E12o(self.e12.0 * rhs.o.0)
+				- E12o(self.e123o.0 * rhs.z.0)
+				- E12o(self.e12oi.0 * rhs.o.0)
+				- E12o(self.e1o.0 * rhs.y.0)
+				+ E12o(self.e2o.0 * rhs.x.0),
+			e12i: E12i(self.e12.0 * rhs.inf.0) - E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0)
+				- E12i(self.e1i.0 * rhs.y.0)
+				+ E12i(self.e2i.0 * rhs.x.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0) + E13o(self.e13.0 * rhs.o.0)
+				- E13o(self.e13oi.0 * rhs.o.0)
+				- E13o(self.e1o.0 * rhs.z.0)
+				+ E13o(self.e3o.0 * rhs.x.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13.0 * rhs.inf.0) + E13i(self.e13oi.0 * rhs.inf.0)
+				- E13i(self.e1i.0 * rhs.z.0)
+				+ E13i(self.e3i.0 * rhs.x.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0) - E1oi(self.e1i.0 * rhs.o.0)
+				+ E1oi(self.e1o.0 * rhs.inf.0)
+				+ E1oi(self.eoi.0 * rhs.x.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0) + E23o(self.e23.0 * rhs.o.0)
+				- E23o(self.e23oi.0 * rhs.o.0)
+				- E23o(self.e2o.0 * rhs.z.0)
+				+ E23o(self.e3o.0 * rhs.y.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23.0 * rhs.inf.0) + E23i(self.e23oi.0 * rhs.inf.0)
+				- E23i(self.e2i.0 * rhs.z.0)
+				+ E23i(self.e3i.0 * rhs.y.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0) - E2oi(self.e2i.0 * rhs.o.0)
+				+ E2oi(self.e2o.0 * rhs.inf.0)
+				+ E2oi(self.eoi.0 * rhs.y.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0) - E3oi(self.e3i.0 * rhs.o.0)
+				+ E3oi(self.e3o.0 * rhs.inf.0)
+				+ E3oi(self.eoi.0 * rhs.z.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e123i.0 * rhs.o.0) + E123oi(self.e123o.0 * rhs.inf.0) + E123oi(self.e12oi.0 * rhs.z.0)
+				- E123oi(self.e13oi.0 * rhs.y.0)
+				+ E123oi(self.e23oi.0 * rhs.x.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Point> for Versor {
+	type Output = Multivector;
+	fn mul(self, rhs: Point) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(Point) -> Versor
+impl AntiGeometric<Point> for Versor {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.o.0) + S(self.e123o.0 * rhs.inf.0) + S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0) + E12(self.e3i.0 * rhs.o.0) - E12(self.e3o.0 * rhs.inf.0) - E12(self.eoi.0 * rhs.z.0),
+		//     e13  : E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0) - E13(self.e2i.0 * rhs.o.0) + E13(self.e2o.0 * rhs.inf.0) + E13(self.eoi.0 * rhs.y.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.x.0) - E1o(self.e23.0 * rhs.o.0) - E1o(self.e23oi.0 * rhs.o.0) + E1o(self.e2o.0 * rhs.z.0) - E1o(self.e3o.0 * rhs.y.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.x.0) + E1i(self.e23.0 * rhs.inf.0) - E1i(self.e23oi.0 * rhs.inf.0) - E1i(self.e2i.0 * rhs.z.0) + E1i(self.e3i.0 * rhs.y.0),
+		//     e23  : E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0) + E23(self.e1i.0 * rhs.o.0) - E23(self.e1o.0 * rhs.inf.0) - E23(self.eoi.0 * rhs.x.0),
+		//     e2o  : -E2o(self.e123o.0 * rhs.y.0) + E2o(self.e13.0 * rhs.o.0) + E2o(self.e13oi.0 * rhs.o.0) - E2o(self.e1o.0 * rhs.z.0) + E2o(self.e3o.0 * rhs.x.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.y.0) - E2i(self.e13.0 * rhs.inf.0) + E2i(self.e13oi.0 * rhs.inf.0) + E2i(self.e1i.0 * rhs.z.0) - E2i(self.e3i.0 * rhs.x.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.o.0) - E3o(self.e123o.0 * rhs.z.0) - E3o(self.e12oi.0 * rhs.o.0) + E3o(self.e1o.0 * rhs.y.0) - E3o(self.e2o.0 * rhs.x.0),
+		//     e3i  : E3i(self.e12.0 * rhs.inf.0) + E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0) - E3i(self.e1i.0 * rhs.y.0) + E3i(self.e2i.0 * rhs.x.0),
+		//     eoi  : Eoi(self.e12.0 * rhs.z.0) + Eoi(self.e123i.0 * rhs.o.0) + Eoi(self.e123o.0 * rhs.inf.0) - Eoi(self.e13.0 * rhs.y.0) + Eoi(self.e23.0 * rhs.x.0),
+		//     e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0) + E123o(self.eoi.0 * rhs.o.0) + E123o(self.s.0 * rhs.o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0) + E123i(self.eoi.0 * rhs.inf.0) - E123i(self.s.0 * rhs.inf.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.e3i.0 * rhs.o.0) - E12oi(self.e3o.0 * rhs.inf.0) - E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.e2i.0 * rhs.o.0) + E13oi(self.e2o.0 * rhs.inf.0) + E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.e1i.0 * rhs.o.0) - E23oi(self.e1o.0 * rhs.inf.0) - E23oi(self.s.0 * rhs.x.0),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.o.0) + S(self.e123o.0 * rhs.inf.0) + S(self.e12oi.0 * rhs.z.0)
+				- S(self.e13oi.0 * rhs.y.0)
+				+ S(self.e23oi.0 * rhs.x.0),
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0) + E12(self.e3i.0 * rhs.o.0)
+				- E12(self.e3o.0 * rhs.inf.0)
+				- E12(self.eoi.0 * rhs.z.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0) - E13(self.e2i.0 * rhs.o.0)
+				+ E13(self.e2o.0 * rhs.inf.0)
+				+ E13(self.eoi.0 * rhs.y.0),
+			e1o: -E1o(self.e123o.0 * rhs.x.0) - E1o(self.e23.0 * rhs.o.0) - E1o(self.e23oi.0 * rhs.o.0)
+				+ E1o(self.e2o.0 * rhs.z.0)
+				- E1o(self.e3o.0 * rhs.y.0),
+			e1i: E1i(self.e123i.0 * rhs.x.0) + E1i(self.e23.0 * rhs.inf.0)
+				- E1i(self.e23oi.0 * rhs.inf.0)
+				- E1i(self.e2i.0 * rhs.z.0)
+				+ E1i(self.e3i.0 * rhs.y.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0) + E23(self.e1i.0 * rhs.o.0)
+				- E23(self.e1o.0 * rhs.inf.0)
+				- E23(self.eoi.0 * rhs.x.0),
+			e2o: -E2o(self.e123o.0 * rhs.y.0) + E2o(self.e13.0 * rhs.o.0) + E2o(self.e13oi.0 * rhs.o.0)
+				- E2o(self.e1o.0 * rhs.z.0)
+				+ E2o(self.e3o.0 * rhs.x.0),
+			e2i: E2i(self.e123i.0 * rhs.y.0) - E2i(self.e13.0 * rhs.inf.0)
+				+ E2i(self.e13oi.0 * rhs.inf.0)
+				+ E2i(self.e1i.0 * rhs.z.0)
+				- E2i(self.e3i.0 * rhs.x.0),
+			e3o: -E3o(self.e12.0 * rhs.o.0) - E3o(self.e123o.0 * rhs.z.0) - E3o(self.e12oi.0 * rhs.o.0)
+				+ E3o(self.e1o.0 * rhs.y.0)
+				- E3o(self.e2o.0 * rhs.x.0),
+			e3i: E3i(self.e12.0 * rhs.inf.0) + E3i(self.e123i.0 * rhs.z.0)
+				- E3i(self.e12oi.0 * rhs.inf.0)
+				- E3i(self.e1i.0 * rhs.y.0)
+				+ E3i(self.e2i.0 * rhs.x.0),
+			eoi: Eoi(self.e12.0 * rhs.z.0) + Eoi(self.e123i.0 * rhs.o.0) + Eoi(self.e123o.0 * rhs.inf.0)
+				- Eoi(self.e13.0 * rhs.y.0)
+				+ Eoi(self.e23.0 * rhs.x.0),
+			e123o: E123o(self.e1o.0 * rhs.x.0)
+				+ E123o(self.e2o.0 * rhs.y.0)
+				+ E123o(self.e3o.0 * rhs.z.0)
+				+ E123o(self.eoi.0 * rhs.o.0)
+				+ E123o(self.s.0 * rhs.o.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0)
+				+ E123i(self.eoi.0 * rhs.inf.0)
+				- E123i(self.s.0 * rhs.inf.0),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0)
+				- E12oi(self.e23.0 * rhs.y.0)
+				- E12oi(self.e3i.0 * rhs.o.0)
+				- E12oi(self.e3o.0 * rhs.inf.0)
+				- E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0)
+				+ E13oi(self.e2i.0 * rhs.o.0)
+				+ E13oi(self.e2o.0 * rhs.inf.0)
+				+ E13oi(self.s.0 * rhs.y.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0)
+				- E23oi(self.e1i.0 * rhs.o.0)
+				- E23oi(self.e1o.0 * rhs.inf.0)
+				- E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// Versor.dot(Point) -> Multivector
+impl Dot<Point> for Versor {
+	type Output = Multivector;
+	fn dot(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) - E1(self.e1i.0 * rhs.o.0) - E1(self.e1o.0 * rhs.inf.0) + E1(self.s.0 * rhs.x.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) - E2(self.e2i.0 * rhs.o.0) - E2(self.e2o.0 * rhs.inf.0) + E2(self.s.0 * rhs.y.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) - E3(self.e3i.0 * rhs.o.0) - E3(self.e3o.0 * rhs.inf.0) + E3(self.s.0 * rhs.z.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0) - Eo(self.eoi.0 * rhs.o.0) + Eo(self.s.0 * rhs.o.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0) + Ei(self.s.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0)
+				- E1(self.e1i.0 * rhs.o.0)
+				- E1(self.e1o.0 * rhs.inf.0)
+				+ E1(self.s.0 * rhs.x.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0)
+				- E2(self.e2i.0 * rhs.o.0)
+				- E2(self.e2o.0 * rhs.inf.0)
+				+ E2(self.s.0 * rhs.y.0),
+			e3: -E3(self.e13.0 * rhs.x.0)
+				- E3(self.e23.0 * rhs.y.0)
+				- E3(self.e3i.0 * rhs.o.0)
+				- E3(self.e3o.0 * rhs.inf.0)
+				+ E3(self.s.0 * rhs.z.0),
+			eo: -Eo(self.e1o.0 * rhs.x.0)
+				- Eo(self.e2o.0 * rhs.y.0)
+				- Eo(self.e3o.0 * rhs.z.0)
+				- Eo(self.eoi.0 * rhs.o.0)
+				+ Eo(self.s.0 * rhs.o.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0)
+				+ Ei(self.s.0 * rhs.inf.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+			e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point> for Versor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Point) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(Point) -> Multivector
+impl Wedge<Point> for Versor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.x.0),
+		//     e2    : E2(self.s.0 * rhs.y.0),
+		//     e3    : E3(self.s.0 * rhs.z.0),
+		//     eo    : Eo(self.s.0 * rhs.o.0),
+		//     ei    : Ei(self.s.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e12o  : E12o(self.e12.0 * rhs.o.0) - E12o(self.e1o.0 * rhs.y.0) + E12o(self.e2o.0 * rhs.x.0),
+		//     e12i  : E12i(self.e12.0 * rhs.inf.0) - E12i(self.e1i.0 * rhs.y.0) + E12i(self.e2i.0 * rhs.x.0),
+		//     e13o  : E13o(self.e13.0 * rhs.o.0) - E13o(self.e1o.0 * rhs.z.0) + E13o(self.e3o.0 * rhs.x.0),
+		//     e13i  : E13i(self.e13.0 * rhs.inf.0) - E13i(self.e1i.0 * rhs.z.0) + E13i(self.e3i.0 * rhs.x.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.o.0) + E1oi(self.e1o.0 * rhs.inf.0) + E1oi(self.eoi.0 * rhs.x.0),
+		//     e23o  : E23o(self.e23.0 * rhs.o.0) - E23o(self.e2o.0 * rhs.z.0) + E23o(self.e3o.0 * rhs.y.0),
+		//     e23i  : E23i(self.e23.0 * rhs.inf.0) - E23i(self.e2i.0 * rhs.z.0) + E23i(self.e3i.0 * rhs.y.0),
+		//     e2oi  : -E2oi(self.e2i.0 * rhs.o.0) + E2oi(self.e2o.0 * rhs.inf.0) + E2oi(self.eoi.0 * rhs.y.0),
+		//     e3oi  : -E3oi(self.e3i.0 * rhs.o.0) + E3oi(self.e3o.0 * rhs.inf.0) + E3oi(self.eoi.0 * rhs.z.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e123i.0 * rhs.o.0) + E123oi(self.e123o.0 * rhs.inf.0) + E123oi(self.e12oi.0 * rhs.z.0) - E123oi(self.e13oi.0 * rhs.y.0) + E123oi(self.e23oi.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.s.wedge(rhs.x),
+			e2: self.s.wedge(rhs.y),
+			e3: self.s.wedge(rhs.z),
+			eo: self.s.wedge(rhs.o),
+			ei: self.s.wedge(rhs.inf),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e12.wedge(rhs.z) + self.e13.wedge(rhs.y) + self.e23.wedge(rhs.x),
+			e12o: self.e12.wedge(rhs.o) + self.e1o.wedge(rhs.y) + self.e2o.wedge(rhs.x),
+			e12i: self.e12.wedge(rhs.inf) + self.e1i.wedge(rhs.y) + self.e2i.wedge(rhs.x),
+			e13o: self.e13.wedge(rhs.o) + self.e1o.wedge(rhs.z) + self.e3o.wedge(rhs.x),
+			e13i: self.e13.wedge(rhs.inf) + self.e1i.wedge(rhs.z) + self.e3i.wedge(rhs.x),
+			e1oi: self.e1i.wedge(rhs.o) + self.e1o.wedge(rhs.inf) + self.eoi.wedge(rhs.x),
+			e23o: self.e23.wedge(rhs.o) + self.e2o.wedge(rhs.z) + self.e3o.wedge(rhs.y),
+			e23i: self.e23.wedge(rhs.inf) + self.e2i.wedge(rhs.z) + self.e3i.wedge(rhs.y),
+			e2oi: self.e2i.wedge(rhs.o) + self.e2o.wedge(rhs.inf) + self.eoi.wedge(rhs.y),
+			e3oi: self.e3i.wedge(rhs.o) + self.e3o.wedge(rhs.inf) + self.eoi.wedge(rhs.z),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e123i.wedge(rhs.o)
+				+ self.e123o.wedge(rhs.inf)
+				+ self.e12oi.wedge(rhs.z)
+				+ self.e13oi.wedge(rhs.y)
+				+ self.e23oi.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point> for Versor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Point) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(Point) -> S
+impl AntiWedge<Point> for Versor {
+	type Output = S;
+	fn anti_wedge(self, rhs: Point) -> Self::Output {
+		// -S(self.e123i.0 * rhs.o.0) + S(self.e123o.0 * rhs.inf.0) + S(self.e12oi.0 * rhs.z.0) - S(self.e13oi.0 * rhs.y.0) + S(self.e23oi.0 * rhs.x.0)
+		self.e123i.anti_wedge(rhs.o)
+			+ self.e123o.anti_wedge(rhs.inf)
+			+ self.e12oi.anti_wedge(rhs.z)
+			+ self.e13oi.anti_wedge(rhs.y)
+			+ self.e23oi.anti_wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitAnd<Point> for Versor {
+	type Output = S;
+	fn bitand(self, rhs: Point) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(Point) -> Point
+impl LeftContraction<Point> for Versor {
+	type Output = Point;
+	fn left_contraction(self, rhs: Point) -> Self::Output {
+		// Point {
+		//     x  : E1(self.s.0 * rhs.x.0),
+		//     y  : E2(self.s.0 * rhs.y.0),
+		//     z  : E3(self.s.0 * rhs.z.0),
+		//     o  : Eo(self.s.0 * rhs.o.0),
+		//     inf: Ei(self.s.0 * rhs.inf.0),
+		// }
+		Point {
+			x: E1(self.s.0 * rhs.x.0),
+			y: E2(self.s.0 * rhs.y.0),
+			z: E3(self.s.0 * rhs.z.0),
+			o: Eo(self.s.0 * rhs.o.0),
+			inf: Ei(self.s.0 * rhs.inf.0),
+		}
+	}
+}
+
+// Versor.right_contraction(Point) -> Multivector
+impl RightContraction<Point> for Versor {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) - E1(self.e1i.0 * rhs.o.0) - E1(self.e1o.0 * rhs.inf.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) - E2(self.e2i.0 * rhs.o.0) - E2(self.e2o.0 * rhs.inf.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) - E3(self.e3i.0 * rhs.o.0) - E3(self.e3o.0 * rhs.inf.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0) - Eo(self.eoi.0 * rhs.o.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0)
+				- E1(self.e1i.0 * rhs.o.0)
+				- E1(self.e1o.0 * rhs.inf.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0)
+				- E2(self.e2i.0 * rhs.o.0)
+				- E2(self.e2o.0 * rhs.inf.0),
+			e3: -E3(self.e13.0 * rhs.x.0)
+				- E3(self.e23.0 * rhs.y.0)
+				- E3(self.e3i.0 * rhs.o.0)
+				- E3(self.e3o.0 * rhs.inf.0),
+			eo: -Eo(self.e1o.0 * rhs.x.0)
+				- Eo(self.e2o.0 * rhs.y.0)
+				- Eo(self.e3o.0 * rhs.z.0)
+				- Eo(self.eoi.0 * rhs.o.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+			e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Point> for Versor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Versor.commutator(Point) -> Multivector
+impl Commutator<Point> for Versor {
+	type Output = Multivector;
+	fn commutator(self, rhs: Point) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0) - E1(self.e1i.0 * rhs.o.0) - E1(self.e1o.0 * rhs.inf.0),
+		//     e2    : -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0) - E2(self.e2i.0 * rhs.o.0) - E2(self.e2o.0 * rhs.inf.0),
+		//     e3    : -E3(self.e13.0 * rhs.x.0) - E3(self.e23.0 * rhs.y.0) - E3(self.e3i.0 * rhs.o.0) - E3(self.e3o.0 * rhs.inf.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.x.0) - Eo(self.e2o.0 * rhs.y.0) - Eo(self.e3o.0 * rhs.z.0) - Eo(self.eoi.0 * rhs.o.0),
+		//     ei    : -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0) + Ei(self.eoi.0 * rhs.inf.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.y.0) + E1(self.e13.0 * rhs.z.0)
+				- E1(self.e1i.0 * rhs.o.0)
+				- E1(self.e1o.0 * rhs.inf.0),
+			e2: -E2(self.e12.0 * rhs.x.0) + E2(self.e23.0 * rhs.z.0)
+				- E2(self.e2i.0 * rhs.o.0)
+				- E2(self.e2o.0 * rhs.inf.0),
+			e3: -E3(self.e13.0 * rhs.x.0)
+				- E3(self.e23.0 * rhs.y.0)
+				- E3(self.e3i.0 * rhs.o.0)
+				- E3(self.e3o.0 * rhs.inf.0),
+			eo: -Eo(self.e1o.0 * rhs.x.0)
+				- Eo(self.e2o.0 * rhs.y.0)
+				- Eo(self.e3o.0 * rhs.z.0)
+				- Eo(self.eoi.0 * rhs.o.0),
+			ei: -Ei(self.e1i.0 * rhs.x.0) - Ei(self.e2i.0 * rhs.y.0) - Ei(self.e3i.0 * rhs.z.0)
+				+ Ei(self.eoi.0 * rhs.inf.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e123i.0 * rhs.o.0) - E123(self.e123o.0 * rhs.inf.0),
+			e12o: -E12o(self.e123o.0 * rhs.z.0) - E12o(self.e12oi.0 * rhs.o.0),
+			e12i: -E12i(self.e123i.0 * rhs.z.0) + E12i(self.e12oi.0 * rhs.inf.0),
+			e13o: E13o(self.e123o.0 * rhs.y.0) - E13o(self.e13oi.0 * rhs.o.0),
+			e13i: E13i(self.e123i.0 * rhs.y.0) + E13i(self.e13oi.0 * rhs.inf.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.y.0) + E1oi(self.e13oi.0 * rhs.z.0),
+			e23o: -E23o(self.e123o.0 * rhs.x.0) - E23o(self.e23oi.0 * rhs.o.0),
+			e23i: -E23i(self.e123i.0 * rhs.x.0) + E23i(self.e23oi.0 * rhs.inf.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.x.0) + E2oi(self.e23oi.0 * rhs.z.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.x.0) - E3oi(self.e23oi.0 * rhs.y.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Versor.anti_dot(Point) -> Versor
+impl AntiDot<Point> for Versor {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Point) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+		//     e13  : E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.x.0) - E1o(self.e23oi.0 * rhs.o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.x.0) - E1i(self.e23oi.0 * rhs.inf.0),
+		//     e23  : E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+		//     e2o  : -E2o(self.e123o.0 * rhs.y.0) + E2o(self.e13oi.0 * rhs.o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.y.0) + E2i(self.e13oi.0 * rhs.inf.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.z.0) - E3o(self.e12oi.0 * rhs.o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0),
+		//     eoi  : Eoi(self.e123i.0 * rhs.o.0) + Eoi(self.e123o.0 * rhs.inf.0),
+		//     e123o: E123o(self.e1o.0 * rhs.x.0) + E123o(self.e2o.0 * rhs.y.0) + E123o(self.e3o.0 * rhs.z.0) + E123o(self.eoi.0 * rhs.o.0) + E123o(self.s.0 * rhs.o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0) + E123i(self.eoi.0 * rhs.inf.0) - E123i(self.s.0 * rhs.inf.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.x.0) - E12oi(self.e23.0 * rhs.y.0) - E12oi(self.e3i.0 * rhs.o.0) - E12oi(self.e3o.0 * rhs.inf.0) - E12oi(self.s.0 * rhs.z.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0) + E13oi(self.e2i.0 * rhs.o.0) + E13oi(self.e2o.0 * rhs.inf.0) + E13oi(self.s.0 * rhs.y.0),
+		//     e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0) - E23oi(self.e1i.0 * rhs.o.0) - E23oi(self.e1o.0 * rhs.inf.0) - E23oi(self.s.0 * rhs.x.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13oi.0 * rhs.x.0) - E12(self.e23oi.0 * rhs.y.0),
+			e13: E13(self.e12oi.0 * rhs.x.0) - E13(self.e23oi.0 * rhs.z.0),
+			e1o: -E1o(self.e123o.0 * rhs.x.0) - E1o(self.e23oi.0 * rhs.o.0),
+			e1i: E1i(self.e123i.0 * rhs.x.0) - E1i(self.e23oi.0 * rhs.inf.0),
+			e23: E23(self.e12oi.0 * rhs.y.0) + E23(self.e13oi.0 * rhs.z.0),
+			e2o: -E2o(self.e123o.0 * rhs.y.0) + E2o(self.e13oi.0 * rhs.o.0),
+			e2i: E2i(self.e123i.0 * rhs.y.0) + E2i(self.e13oi.0 * rhs.inf.0),
+			e3o: -E3o(self.e123o.0 * rhs.z.0) - E3o(self.e12oi.0 * rhs.o.0),
+			e3i: E3i(self.e123i.0 * rhs.z.0) - E3i(self.e12oi.0 * rhs.inf.0),
+			eoi: Eoi(self.e123i.0 * rhs.o.0) + Eoi(self.e123o.0 * rhs.inf.0),
+			e123o: E123o(self.e1o.0 * rhs.x.0)
+				+ E123o(self.e2o.0 * rhs.y.0)
+				+ E123o(self.e3o.0 * rhs.z.0)
+				+ E123o(self.eoi.0 * rhs.o.0)
+				+ E123o(self.s.0 * rhs.o.0),
+			e123i: -E123i(self.e1i.0 * rhs.x.0) - E123i(self.e2i.0 * rhs.y.0) - E123i(self.e3i.0 * rhs.z.0)
+				+ E123i(self.eoi.0 * rhs.inf.0)
+				- E123i(self.s.0 * rhs.inf.0),
+			e12oi: -E12oi(self.e13.0 * rhs.x.0)
+				- E12oi(self.e23.0 * rhs.y.0)
+				- E12oi(self.e3i.0 * rhs.o.0)
+				- E12oi(self.e3o.0 * rhs.inf.0)
+				- E12oi(self.s.0 * rhs.z.0),
+			e13oi: E13oi(self.e12.0 * rhs.x.0) - E13oi(self.e23.0 * rhs.z.0)
+				+ E13oi(self.e2i.0 * rhs.o.0)
+				+ E13oi(self.e2o.0 * rhs.inf.0)
+				+ E13oi(self.s.0 * rhs.y.0),
+			e23oi: E23oi(self.e12.0 * rhs.y.0) + E23oi(self.e13.0 * rhs.z.0)
+				- E23oi(self.e1i.0 * rhs.o.0)
+				- E23oi(self.e1o.0 * rhs.inf.0)
+				- E23oi(self.s.0 * rhs.x.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP FlatPoint:
+
+// Versor.geometric(FlatPoint) -> Versor
+impl Geometric<FlatPoint> for Versor {
+	type Output = Versor;
+	fn geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0) + E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13  : -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.eoi.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.e13oi.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.eoi.0 * rhs.e1i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.eoi.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.e23oi.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.eoi.0 * rhs.e2i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.eoi.0),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e23oi.0 * rhs.e2i.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.e123o.0 * rhs.eoi.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e2o.0 * rhs.e1i.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e13.0 * rhs.eoi.0) - E13oi(self.e1o.0 * rhs.e3i.0) + E13oi(self.e3o.0 * rhs.e1i.0),
+		//     e23oi: -E23oi(self.e123o.0 * rhs.e1i.0) + E23oi(self.e23.0 * rhs.eoi.0) - E23oi(self.e2o.0 * rhs.e3i.0) + E23oi(self.e3o.0 * rhs.e2i.0),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0) + E12(self.e1o.0 * rhs.e2i.0)
+				- E12(self.e2o.0 * rhs.e1i.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0) + E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e1o.0 * rhs.eoi.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13.0 * rhs.e3i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0) + E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3o.0 * rhs.e2i.0),
+			e2o: E2o(self.e2o.0 * rhs.eoi.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) - E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.e3o.0 * rhs.eoi.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0)
+				- E3i(self.e13oi.0 * rhs.e1i.0)
+				- E3i(self.e23.0 * rhs.e2i.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13.0 * rhs.e2i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23.0 * rhs.e1i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e1o.0 * rhs.e2i.0)
+				+ E12oi(self.e2o.0 * rhs.e1i.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e13.0 * rhs.eoi.0) - E13oi(self.e1o.0 * rhs.e3i.0)
+				+ E13oi(self.e3o.0 * rhs.e1i.0),
+			e23oi: -E23oi(self.e123o.0 * rhs.e1i.0) + E23oi(self.e23.0 * rhs.eoi.0) - E23oi(self.e2o.0 * rhs.e3i.0)
+				+ E23oi(self.e3o.0 * rhs.e2i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<FlatPoint> for Versor {
+	type Output = Versor;
+	fn mul(self, rhs: FlatPoint) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(FlatPoint) -> Multivector
+impl AntiGeometric<FlatPoint> for Versor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123o.0 * rhs.e1i.0) + E1(self.e23.0 * rhs.eoi.0) - E1(self.e2o.0 * rhs.e3i.0) + E1(self.e3o.0 * rhs.e2i.0),
+		//     e2    : E2(self.e123o.0 * rhs.e2i.0) - E2(self.e13.0 * rhs.eoi.0) + E2(self.e1o.0 * rhs.e3i.0) - E2(self.e3o.0 * rhs.e1i.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0) + E3(self.e123o.0 * rhs.e3i.0) - E3(self.e1o.0 * rhs.e2i.0) + E3(self.e2o.0 * rhs.e1i.0),
+		//     eo    : Eo(self.e123o.0 * rhs.eoi.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) + Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e13oi.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1o.0 * rhs.e1i.0) - E123(self.e2o.0 * rhs.e2i.0) - E123(self.e3o.0 * rhs.e3i.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e3o.0 * rhs.eoi.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e13oi.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) + E12i(self.e23oi.0 * rhs.e2i.0) - E12i(self.e3i.0 * rhs.eoi.0) + E12i(self.eoi.0 * rhs.e3i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : E13o(self.e2o.0 * rhs.eoi.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e12oi.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.eoi.0) - E13i(self.eoi.0 * rhs.e2i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0) - E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3o.0 * rhs.e2i.0),
+		//     e23o  : -E23o(self.e1o.0 * rhs.eoi.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) - E23i(self.e12oi.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.eoi.0) + E23i(self.eoi.0 * rhs.e1i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0) + E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e3o.0 * rhs.e1i.0),
+		//     e3oi  : E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0) - E3oi(self.e1o.0 * rhs.e2i.0) + E3oi(self.e2o.0 * rhs.e1i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1o.0 * rhs.e1i.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123o.0 * rhs.e1i.0) + E1(self.e23.0 * rhs.eoi.0) - E1(self.e2o.0 * rhs.e3i.0)
+				+ E1(self.e3o.0 * rhs.e2i.0),
+			e2: E2(self.e123o.0 * rhs.e2i.0) - E2(self.e13.0 * rhs.eoi.0) + E2(self.e1o.0 * rhs.e3i.0)
+				- E2(self.e3o.0 * rhs.e1i.0),
+			e3: E3(self.e12.0 * rhs.eoi.0) + E3(self.e123o.0 * rhs.e3i.0) - E3(self.e1o.0 * rhs.e2i.0)
+				+ E3(self.e2o.0 * rhs.e1i.0),
+			eo: Eo(self.e123o.0 * rhs.eoi.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) + Ei(self.e123i.0 * rhs.eoi.0)
+				- Ei(self.e12oi.0 * rhs.e3i.0)
+				- Ei(self.e13.0 * rhs.e2i.0)
+				+ Ei(self.e13oi.0 * rhs.e2i.0)
+				+ Ei(self.e23.0 * rhs.e1i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1o.0 * rhs.e1i.0)
+				- E123(self.e2o.0 * rhs.e2i.0)
+				- E123(self.e3o.0 * rhs.e3i.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e3o.0 * rhs.eoi.0),
+			e12i: -E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e13oi.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: E13o(self.e2o.0 * rhs.eoi.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e12oi.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				- E13i(self.eoi.0 * rhs.e2i.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0) - E1oi(self.e2o.0 * rhs.e3i.0)
+				+ E1oi(self.e3o.0 * rhs.e2i.0),
+			e23o: -E23o(self.e1o.0 * rhs.eoi.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) - E23i(self.e12oi.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.eoi.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0) + E2oi(self.e1o.0 * rhs.e3i.0)
+				- E2oi(self.e3o.0 * rhs.e1i.0),
+			e3oi: E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0) - E3oi(self.e1o.0 * rhs.e2i.0)
+				+ E3oi(self.e2o.0 * rhs.e1i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1o.0 * rhs.e1i.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Versor.dot(FlatPoint) -> Versor
+impl Dot<FlatPoint> for Versor {
+	type Output = Versor;
+	fn dot(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<FlatPoint> for Versor {
+	type Output = Versor;
+	fn bitor(self, rhs: FlatPoint) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(FlatPoint) -> Versor
+impl Wedge<FlatPoint> for Versor {
+	type Output = Versor;
+	fn wedge(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e2o.0 * rhs.e1i.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0) - E13oi(self.e1o.0 * rhs.e3i.0) + E13oi(self.e3o.0 * rhs.e1i.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0) - E23oi(self.e2o.0 * rhs.e3i.0) + E23oi(self.e3o.0 * rhs.e2i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: self.s.wedge(rhs.e1i),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: self.s.wedge(rhs.e2i),
+			e3o: Default::default(),
+			e3i: self.s.wedge(rhs.e3i),
+			eoi: self.s.wedge(rhs.eoi),
+			e123o: Default::default(),
+			e123i: self.e12.wedge(rhs.e3i) + self.e13.wedge(rhs.e2i) + self.e23.wedge(rhs.e1i),
+			e12oi: self.e12.wedge(rhs.eoi) + self.e1o.wedge(rhs.e2i) + self.e2o.wedge(rhs.e1i),
+			e13oi: self.e13.wedge(rhs.eoi) + self.e1o.wedge(rhs.e3i) + self.e3o.wedge(rhs.e1i),
+			e23oi: self.e23.wedge(rhs.eoi) + self.e2o.wedge(rhs.e3i) + self.e3o.wedge(rhs.e2i),
+		}
+	}
+}
+
+impl std::ops::BitXor<FlatPoint> for Versor {
+	type Output = Versor;
+	fn bitxor(self, rhs: FlatPoint) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(FlatPoint) -> Point
+impl AntiWedge<FlatPoint> for Versor {
+	type Output = Point;
+	fn anti_wedge(self, rhs: FlatPoint) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123o.0 * rhs.e1i.0),
+		//     y  : E2(self.e123o.0 * rhs.e2i.0),
+		//     z  : E3(self.e123o.0 * rhs.e3i.0),
+		//     o  : Eo(self.e123o.0 * rhs.eoi.0),
+		//     inf: Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		// }
+		Point {
+			x: self.e123o.anti_wedge(rhs.e1i),
+			y: self.e123o.anti_wedge(rhs.e2i),
+			z: self.e123o.anti_wedge(rhs.e3i),
+			o: self.e123o.anti_wedge(rhs.eoi),
+			inf: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e23oi.anti_wedge(rhs.e1i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<FlatPoint> for Versor {
+	type Output = Point;
+	fn bitand(self, rhs: FlatPoint) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(FlatPoint) -> Versor
+impl LeftContraction<FlatPoint> for Versor {
+	type Output = Versor;
+	fn left_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : Default::default(),
+		//     e2o  : Default::default(),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Versor.right_contraction(FlatPoint) -> Versor
+impl RightContraction<FlatPoint> for Versor {
+	type Output = Versor;
+	fn right_contraction(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Versor.scalar_product(FlatPoint) -> S
+impl ScalarProduct<FlatPoint> for Versor {
+	type Output = S;
+	fn scalar_product(self, rhs: FlatPoint) -> Self::Output {
+		// S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// Versor.commutator(FlatPoint) -> Versor
+impl Commutator<FlatPoint> for Versor {
+	type Output = Versor;
+	fn commutator(self, rhs: FlatPoint) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13  : E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.eoi.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.eoi.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.eoi.0),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		//     e123o: E123o(self.e123o.0 * rhs.eoi.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: -E12oi(self.e123o.0 * rhs.e3i.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e2i.0),
+		//     e23oi: -E23oi(self.e123o.0 * rhs.e1i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e1o.0 * rhs.eoi.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+			e2o: E2o(self.e2o.0 * rhs.eoi.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: E3o(self.e3o.0 * rhs.eoi.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: -E12oi(self.e123o.0 * rhs.e3i.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e2i.0),
+			e23oi: -E23oi(self.e123o.0 * rhs.e1i.0),
+		}
+	}
+}
+
+// Versor.anti_dot(FlatPoint) -> Multivector
+impl AntiDot<FlatPoint> for Versor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: FlatPoint) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+		//     e3oi  : E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1o.0 * rhs.e1i.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.s.0 * rhs.eoi.0),
+			e12o: Default::default(),
+			e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+			e13o: Default::default(),
+			e13i: -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0),
+			e23o: Default::default(),
+			e23i: -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0),
+			e3oi: E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1o.0 * rhs.e1i.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP PointPair:
+
+// Versor.geometric(PointPair) -> Versor
+impl Geometric<PointPair> for Versor {
+	type Output = Versor;
+	fn geometric(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0) - E12(self.e13.0 * rhs.e23.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) - E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.e12.0 * rhs.e2o.0) - E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.eoi.0 * rhs.e1o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) - E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.e13oi.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e23oi.0 * rhs.eoi.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e23.0 * rhs.e3o.0) - E2o(self.e23oi.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.eoi.0 * rhs.e2o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.e23oi.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) - E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e23oi.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.eoi.0) - E3o(self.eoi.0 * rhs.e3o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e13oi.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) + Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) + E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e13oi.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e23oi.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) + E123i(self.e23.0 * rhs.e1i.0) + E123i(self.e23oi.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e23oi.0 * rhs.e13.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e23oi.0 * rhs.e12.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0)
+				- E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0)
+				+ E13(self.e13oi.0 * rhs.eoi.0)
+				+ E13(self.e1i.0 * rhs.e3o.0)
+				+ E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) - E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0)
+				+ E1o(self.e13.0 * rhs.e3o.0)
+				- E1o(self.e13oi.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.eoi.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.eoi.0 * rhs.e1o.0)
+				+ E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) - E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13.0 * rhs.e3i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e123i.0 * rhs.e1o.0)
+				+ E23(self.e123o.0 * rhs.e1i.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e123o.0 * rhs.e13.0)
+				+ E2o(self.e12oi.0 * rhs.e1o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				- E2o(self.e23oi.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.eoi.0 * rhs.e2o.0)
+				+ E2o(self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e1i.0 * rhs.e12.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) - E3o(self.e13.0 * rhs.e1o.0)
+				+ E3o(self.e13oi.0 * rhs.e1o.0)
+				+ E3o(self.e1o.0 * rhs.e13.0)
+				- E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e23oi.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				- E3o(self.eoi.0 * rhs.e3o.0)
+				+ E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e13oi.0 * rhs.e1i.0)
+				+ E3i(self.e1i.0 * rhs.e13.0)
+				- E3i(self.e23.0 * rhs.e2i.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) + Eoi(self.e1i.0 * rhs.e1o.0)
+				- Eoi(self.e1o.0 * rhs.e1i.0)
+				- Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.e12.0 * rhs.e3o.0) + E123o(self.e123o.0 * rhs.eoi.0)
+				- E123o(self.e12oi.0 * rhs.e3o.0)
+				- E123o(self.e13.0 * rhs.e2o.0)
+				+ E123o(self.e13oi.0 * rhs.e2o.0)
+				+ E123o(self.e1o.0 * rhs.e23.0)
+				+ E123o(self.e23.0 * rhs.e1o.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0)
+				- E123o(self.e2o.0 * rhs.e13.0)
+				+ E123o(self.e3o.0 * rhs.e12.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13.0 * rhs.e2i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e1i.0 * rhs.e23.0)
+				+ E123i(self.e23.0 * rhs.e1i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0)
+				- E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e3i.0 * rhs.e12.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e123i.0 * rhs.e3o.0)
+				- E12oi(self.e123o.0 * rhs.e3i.0)
+				- E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e1i.0 * rhs.e2o.0)
+				- E12oi(self.e1o.0 * rhs.e2i.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0)
+				- E12oi(self.e2i.0 * rhs.e1o.0)
+				+ E12oi(self.e2o.0 * rhs.e1i.0)
+				+ E12oi(self.eoi.0 * rhs.e12.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0)
+				+ E13oi(self.e123o.0 * rhs.e2i.0)
+				+ E13oi(self.e12oi.0 * rhs.e23.0)
+				+ E13oi(self.e13.0 * rhs.eoi.0)
+				+ E13oi(self.e1i.0 * rhs.e3o.0)
+				- E13oi(self.e1o.0 * rhs.e3i.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0)
+				- E13oi(self.e3i.0 * rhs.e1o.0)
+				+ E13oi(self.e3o.0 * rhs.e1i.0)
+				+ E13oi(self.eoi.0 * rhs.e13.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0)
+				+ E23oi(self.e23.0 * rhs.eoi.0)
+				+ E23oi(self.e2i.0 * rhs.e3o.0)
+				- E23oi(self.e2o.0 * rhs.e3i.0)
+				- E23oi(self.e3i.0 * rhs.e2o.0)
+				+ E23oi(self.e3o.0 * rhs.e2i.0)
+				+ E23oi(self.eoi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::Mul<PointPair> for Versor {
+	type Output = Versor;
+	fn mul(self, rhs: PointPair) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(PointPair) -> Multivector
+impl AntiGeometric<PointPair> for Versor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0) + E1(self.e23.0 * rhs.eoi.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e2o.0 * rhs.e3i.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.e3o.0 * rhs.e2i.0) + E1(self.eoi.0 * rhs.e23.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13.0 * rhs.eoi.0) - E2(self.e1i.0 * rhs.e3o.0) + E2(self.e1o.0 * rhs.e3i.0) - E2(self.e23oi.0 * rhs.e12.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.e3o.0 * rhs.e1i.0) - E2(self.eoi.0 * rhs.e13.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0) - E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e13oi.0 * rhs.e23.0) + E3(self.e1i.0 * rhs.e2o.0) - E3(self.e1o.0 * rhs.e2i.0) - E3(self.e23oi.0 * rhs.e13.0) - E3(self.e2i.0 * rhs.e1o.0) + E3(self.e2o.0 * rhs.e1i.0) + E3(self.eoi.0 * rhs.e12.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e1o.0 * rhs.e23.0) - Eo(self.e23.0 * rhs.e1o.0) - Eo(self.e23oi.0 * rhs.e1o.0) + Eo(self.e2o.0 * rhs.e13.0) - Eo(self.e3o.0 * rhs.e12.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) + Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e13oi.0 * rhs.e2i.0) + Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e23.0 * rhs.e1i.0) - Ei(self.e23oi.0 * rhs.e1i.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e1i.0 * rhs.e1o.0) - E123(self.e1o.0 * rhs.e1i.0) + E123(self.e23oi.0 * rhs.e23.0) + E123(self.e2i.0 * rhs.e2o.0) - E123(self.e2o.0 * rhs.e2i.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.e3o.0 * rhs.e3i.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13.0 * rhs.e1o.0) + E12o(self.e13oi.0 * rhs.e1o.0) - E12o(self.e1o.0 * rhs.e13.0) + E12o(self.e23.0 * rhs.e2o.0) + E12o(self.e23oi.0 * rhs.e2o.0) - E12o(self.e2o.0 * rhs.e23.0) - E12o(self.e3o.0 * rhs.eoi.0) + E12o(self.eoi.0 * rhs.e3o.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) - E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e1i.0 * rhs.e13.0) - E12i(self.e23.0 * rhs.e2i.0) + E12i(self.e23oi.0 * rhs.e2i.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.eoi.0) + E12i(self.eoi.0 * rhs.e3i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e1o.0 * rhs.e12.0) + E13o(self.e23.0 * rhs.e3o.0) + E13o(self.e23oi.0 * rhs.e3o.0) + E13o(self.e2o.0 * rhs.eoi.0) - E13o(self.e3o.0 * rhs.e23.0) - E13o(self.eoi.0 * rhs.e2o.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) - E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e3i.0 * rhs.e23.0) - E13i(self.eoi.0 * rhs.e2i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) + E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.e23oi.0 * rhs.eoi.0) - E1oi(self.e2i.0 * rhs.e3o.0) - E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.e3o.0 * rhs.e2i.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e13oi.0 * rhs.e3o.0) - E23o(self.e1o.0 * rhs.eoi.0) + E23o(self.e2o.0 * rhs.e12.0) + E23o(self.e3o.0 * rhs.e13.0) + E23o(self.eoi.0 * rhs.e1o.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0) + E23i(self.eoi.0 * rhs.e1i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) + E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0) + E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e23.0 * rhs.e12.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.e3o.0 * rhs.e1i.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e1i.0 * rhs.e2o.0) - E3oi(self.e1o.0 * rhs.e2i.0) - E3oi(self.e23.0 * rhs.e13.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.e2o.0 * rhs.e1i.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0)
+				- E1(self.e13oi.0 * rhs.e12.0)
+				+ E1(self.e23.0 * rhs.eoi.0)
+				+ E1(self.e2i.0 * rhs.e3o.0)
+				- E1(self.e2o.0 * rhs.e3i.0)
+				- E1(self.e3i.0 * rhs.e2o.0)
+				+ E1(self.e3o.0 * rhs.e2i.0)
+				+ E1(self.eoi.0 * rhs.e23.0),
+			e2: -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0)
+				- E2(self.e13.0 * rhs.eoi.0)
+				- E2(self.e1i.0 * rhs.e3o.0)
+				+ E2(self.e1o.0 * rhs.e3i.0)
+				- E2(self.e23oi.0 * rhs.e12.0)
+				+ E2(self.e3i.0 * rhs.e1o.0)
+				- E2(self.e3o.0 * rhs.e1i.0)
+				- E2(self.eoi.0 * rhs.e13.0),
+			e3: E3(self.e12.0 * rhs.eoi.0) - E3(self.e123i.0 * rhs.e3o.0)
+				+ E3(self.e123o.0 * rhs.e3i.0)
+				+ E3(self.e13oi.0 * rhs.e23.0)
+				+ E3(self.e1i.0 * rhs.e2o.0)
+				- E3(self.e1o.0 * rhs.e2i.0)
+				- E3(self.e23oi.0 * rhs.e13.0)
+				- E3(self.e2i.0 * rhs.e1o.0)
+				+ E3(self.e2o.0 * rhs.e1i.0)
+				+ E3(self.eoi.0 * rhs.e12.0),
+			eo: -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e12oi.0 * rhs.e3o.0)
+				+ Eo(self.e13.0 * rhs.e2o.0)
+				+ Eo(self.e13oi.0 * rhs.e2o.0)
+				- Eo(self.e1o.0 * rhs.e23.0)
+				- Eo(self.e23.0 * rhs.e1o.0)
+				- Eo(self.e23oi.0 * rhs.e1o.0)
+				+ Eo(self.e2o.0 * rhs.e13.0)
+				- Eo(self.e3o.0 * rhs.e12.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) + Ei(self.e123i.0 * rhs.eoi.0)
+				- Ei(self.e12oi.0 * rhs.e3i.0)
+				- Ei(self.e13.0 * rhs.e2i.0)
+				+ Ei(self.e13oi.0 * rhs.e2i.0)
+				+ Ei(self.e1i.0 * rhs.e23.0)
+				+ Ei(self.e23.0 * rhs.e1i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0)
+				- Ei(self.e2i.0 * rhs.e13.0)
+				+ Ei(self.e3i.0 * rhs.e12.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e1i.0 * rhs.e1o.0)
+				- E123(self.e1o.0 * rhs.e1i.0)
+				+ E123(self.e23oi.0 * rhs.e23.0)
+				+ E123(self.e2i.0 * rhs.e2o.0)
+				- E123(self.e2o.0 * rhs.e2i.0)
+				+ E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.e3o.0 * rhs.e3i.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13.0 * rhs.e1o.0) + E12o(self.e13oi.0 * rhs.e1o.0)
+				- E12o(self.e1o.0 * rhs.e13.0)
+				+ E12o(self.e23.0 * rhs.e2o.0)
+				+ E12o(self.e23oi.0 * rhs.e2o.0)
+				- E12o(self.e2o.0 * rhs.e23.0)
+				- E12o(self.e3o.0 * rhs.eoi.0)
+				+ E12o(self.eoi.0 * rhs.e3o.0)
+				+ E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) - E12i(self.e13.0 * rhs.e1i.0)
+				+ E12i(self.e13oi.0 * rhs.e1i.0)
+				+ E12i(self.e1i.0 * rhs.e13.0)
+				- E12i(self.e23.0 * rhs.e2i.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0)
+				+ E12i(self.e2i.0 * rhs.e23.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0)
+				+ E13o(self.e1o.0 * rhs.e12.0)
+				+ E13o(self.e23.0 * rhs.e3o.0)
+				+ E13o(self.e23oi.0 * rhs.e3o.0)
+				+ E13o(self.e2o.0 * rhs.eoi.0)
+				- E13o(self.e3o.0 * rhs.e23.0)
+				- E13o(self.eoi.0 * rhs.e2o.0)
+				- E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e123i.0 * rhs.e13.0)
+				- E13i(self.e12oi.0 * rhs.e1i.0)
+				- E13i(self.e1i.0 * rhs.e12.0)
+				- E13i(self.e23.0 * rhs.e3i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				- E13i(self.eoi.0 * rhs.e2i.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0) + E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0)
+				- E1oi(self.e13.0 * rhs.e12.0)
+				+ E1oi(self.e23oi.0 * rhs.eoi.0)
+				- E1oi(self.e2i.0 * rhs.e3o.0)
+				- E1oi(self.e2o.0 * rhs.e3i.0)
+				+ E1oi(self.e3i.0 * rhs.e2o.0)
+				+ E1oi(self.e3o.0 * rhs.e2i.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e12.0 * rhs.e2o.0)
+				- E23o(self.e123o.0 * rhs.e23.0)
+				- E23o(self.e12oi.0 * rhs.e2o.0)
+				- E23o(self.e13.0 * rhs.e3o.0)
+				- E23o(self.e13oi.0 * rhs.e3o.0)
+				- E23o(self.e1o.0 * rhs.eoi.0)
+				+ E23o(self.e2o.0 * rhs.e12.0)
+				+ E23o(self.e3o.0 * rhs.e13.0)
+				+ E23o(self.eoi.0 * rhs.e1o.0)
+				+ E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0)
+				+ E23i(self.e13.0 * rhs.e3i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0) + E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0)
+				- E2oi(self.e13oi.0 * rhs.eoi.0)
+				+ E2oi(self.e1i.0 * rhs.e3o.0)
+				+ E2oi(self.e1o.0 * rhs.e3i.0)
+				- E2oi(self.e23.0 * rhs.e12.0)
+				- E2oi(self.e3i.0 * rhs.e1o.0)
+				- E2oi(self.e3o.0 * rhs.e1i.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0)
+				+ E3oi(self.e123o.0 * rhs.e3i.0)
+				+ E3oi(self.e12oi.0 * rhs.eoi.0)
+				+ E3oi(self.e13.0 * rhs.e23.0)
+				- E3oi(self.e1i.0 * rhs.e2o.0)
+				- E3oi(self.e1o.0 * rhs.e2i.0)
+				- E3oi(self.e23.0 * rhs.e13.0)
+				+ E3oi(self.e2i.0 * rhs.e1o.0)
+				+ E3oi(self.e2o.0 * rhs.e1i.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Versor.dot(PointPair) -> Versor
+impl Dot<PointPair> for Versor {
+	type Output = Versor;
+	fn dot(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0)
+				+ E12(self.e123o.0 * rhs.e3i.0)
+				+ E12(self.e12oi.0 * rhs.eoi.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0)
+				+ E13(self.e13oi.0 * rhs.eoi.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0)
+				+ E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0)
+				+ E23(self.e123o.0 * rhs.e1i.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0)
+				+ E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0)
+				+ E3o(self.e13oi.0 * rhs.e1o.0)
+				+ E3o(self.e23oi.0 * rhs.e2o.0)
+				+ E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<PointPair> for Versor {
+	type Output = Versor;
+	fn bitor(self, rhs: PointPair) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(PointPair) -> Versor
+impl Wedge<PointPair> for Versor {
+	type Output = Versor;
+	fn wedge(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) + E123i(self.e23.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: self.s.wedge(rhs.e12),
+			e13: self.s.wedge(rhs.e13),
+			e1o: self.s.wedge(rhs.e1o),
+			e1i: self.s.wedge(rhs.e1i),
+			e23: self.s.wedge(rhs.e23),
+			e2o: self.s.wedge(rhs.e2o),
+			e2i: self.s.wedge(rhs.e2i),
+			e3o: self.s.wedge(rhs.e3o),
+			e3i: self.s.wedge(rhs.e3i),
+			eoi: self.s.wedge(rhs.eoi),
+			e123o: self.e12.wedge(rhs.e3o)
+				+ self.e13.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e13)
+				+ self.e3o.wedge(rhs.e12),
+			e123i: self.e12.wedge(rhs.e3i)
+				+ self.e13.wedge(rhs.e2i)
+				+ self.e1i.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1i)
+				+ self.e2i.wedge(rhs.e13)
+				+ self.e3i.wedge(rhs.e12),
+			e12oi: self.e12.wedge(rhs.eoi)
+				+ self.e1i.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e2i)
+				+ self.e2i.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e12),
+			e13oi: self.e13.wedge(rhs.eoi)
+				+ self.e1i.wedge(rhs.e3o)
+				+ self.e1o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e1o)
+				+ self.e3o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e13),
+			e23oi: self.e23.wedge(rhs.eoi)
+				+ self.e2i.wedge(rhs.e3o)
+				+ self.e2o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e2o)
+				+ self.e3o.wedge(rhs.e2i)
+				+ self.eoi.wedge(rhs.e23),
+		}
+	}
+}
+
+impl std::ops::BitXor<PointPair> for Versor {
+	type Output = Versor;
+	fn bitxor(self, rhs: PointPair) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(PointPair) -> Point
+impl AntiWedge<PointPair> for Versor {
+	type Output = Point;
+	fn anti_wedge(self, rhs: PointPair) -> Self::Output {
+		// Point {
+		//     x  : -E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     y  : -E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     z  : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		//     o  : Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e23oi.0 * rhs.e1o.0),
+		//     inf: Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		// }
+		Point {
+			x: self.e123i.anti_wedge(rhs.e1o)
+				+ self.e123o.anti_wedge(rhs.e1i)
+				+ self.e12oi.anti_wedge(rhs.e13)
+				+ self.e13oi.anti_wedge(rhs.e12),
+			y: self.e123i.anti_wedge(rhs.e2o)
+				+ self.e123o.anti_wedge(rhs.e2i)
+				+ self.e12oi.anti_wedge(rhs.e23)
+				+ self.e23oi.anti_wedge(rhs.e12),
+			z: self.e123i.anti_wedge(rhs.e3o)
+				+ self.e123o.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e23)
+				+ self.e23oi.anti_wedge(rhs.e13),
+			o: self.e123o.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3o)
+				+ self.e13oi.anti_wedge(rhs.e2o)
+				+ self.e23oi.anti_wedge(rhs.e1o),
+			inf: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e23oi.anti_wedge(rhs.e1i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<PointPair> for Versor {
+	type Output = Point;
+	fn bitand(self, rhs: PointPair) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(PointPair) -> Versor
+impl LeftContraction<PointPair> for Versor {
+	type Output = Versor;
+	fn left_contraction(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.s.0 * rhs.eoi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Versor.right_contraction(PointPair) -> Versor
+impl RightContraction<PointPair> for Versor {
+	type Output = Versor;
+	fn right_contraction(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0),
+		//     e12  : E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0),
+			e12: E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+// Versor.scalar_product(PointPair) -> S
+impl ScalarProduct<PointPair> for Versor {
+	type Output = S;
+	fn scalar_product(self, rhs: PointPair) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0)
+			- S(self.e23.0 * rhs.e23.0)
+			+ S(self.e2i.0 * rhs.e2o.0)
+			+ S(self.e2o.0 * rhs.e2i.0)
+			+ S(self.e3i.0 * rhs.e3o.0)
+			+ S(self.e3o.0 * rhs.e3i.0)
+			+ S(self.eoi.0 * rhs.eoi.0)
+	}
+}
+
+// Versor.commutator(PointPair) -> Versor
+impl Commutator<PointPair> for Versor {
+	type Output = Versor;
+	fn commutator(self, rhs: PointPair) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13.0 * rhs.e23.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o  : E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.eoi.0) - E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		//     e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) - E123o(self.e23oi.0 * rhs.e1o.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				- E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: -E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+// Versor.anti_dot(PointPair) -> Multivector
+impl AntiDot<PointPair> for Versor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: PointPair) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e23oi.0 * rhs.e2o.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23oi.0 * rhs.eoi.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13oi.0 * rhs.eoi.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0)
+				+ E12o(self.e13oi.0 * rhs.e1o.0)
+				+ E12o(self.e23oi.0 * rhs.e2o.0)
+				+ E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) + E13o(self.e23oi.0 * rhs.e3o.0)
+				- E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0)
+				+ E1oi(self.e123o.0 * rhs.e1i.0)
+				+ E1oi(self.e23oi.0 * rhs.eoi.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0)
+				+ E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0)
+				- E23i(self.e12oi.0 * rhs.e2i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0)
+				- E2oi(self.e13oi.0 * rhs.eoi.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e3o.0)
+				+ E3oi(self.e123o.0 * rhs.e3i.0)
+				+ E3oi(self.e12oi.0 * rhs.eoi.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP Line:
+
+// Versor.geometric(Line) -> Multivector
+impl Geometric<Line> for Versor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e23oi.0 * rhs.e3oi.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e23oi.0 * rhs.e2oi.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e13oi.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) - Ei(self.e23oi.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) - E123(self.e1o.0 * rhs.e23i.0) + E123(self.e23oi.0 * rhs.e1oi.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3o.0 * rhs.e12i.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3oi.0) - E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e2o.0 * rhs.e1oi.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13.0 * rhs.e23i.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) + E12i(self.e23oi.0 * rhs.e13i.0) - E12i(self.e2i.0 * rhs.e1oi.0) + E12i(self.eoi.0 * rhs.e12i.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e2oi.0) - E13o(self.e1o.0 * rhs.e3oi.0) + E13o(self.e3o.0 * rhs.e1oi.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) - E13i(self.e23oi.0 * rhs.e12i.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) - E1oi(self.e123o.0 * rhs.e23i.0) + E1oi(self.e13.0 * rhs.e3oi.0) - E1oi(self.e2o.0 * rhs.e12i.0) - E1oi(self.e3o.0 * rhs.e13i.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e1oi.0) - E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.e13oi.0 * rhs.e12i.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e123o.0 * rhs.e13i.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) - E2oi(self.e3o.0 * rhs.e23i.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e123o.0 * rhs.e12i.0) - E3oi(self.e13.0 * rhs.e1oi.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) + E3oi(self.e2o.0 * rhs.e23i.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) - E123oi(self.e2o.0 * rhs.e13i.0) + E123oi(self.e3o.0 * rhs.e12i.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) - E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13.0 * rhs.e13i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				+ Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) - E123(self.e1o.0 * rhs.e23i.0)
+				+ E123(self.e23oi.0 * rhs.e1oi.0)
+				+ E123(self.e2o.0 * rhs.e13i.0)
+				- E123(self.e3o.0 * rhs.e12i.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3oi.0) - E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e2o.0 * rhs.e1oi.0),
+			e12i: E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13.0 * rhs.e23i.0) - E12i(self.e13oi.0 * rhs.e23i.0)
+				+ E12i(self.e1i.0 * rhs.e2oi.0)
+				+ E12i(self.e23.0 * rhs.e13i.0)
+				+ E12i(self.e23oi.0 * rhs.e13i.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0)
+				+ E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.e123o.0 * rhs.e2oi.0) - E13o(self.e1o.0 * rhs.e3oi.0) + E13o(self.e3o.0 * rhs.e1oi.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e123i.0 * rhs.e2oi.0)
+				+ E13i(self.e12oi.0 * rhs.e23i.0)
+				+ E13i(self.e1i.0 * rhs.e3oi.0)
+				- E13i(self.e23.0 * rhs.e12i.0)
+				- E13i(self.e23oi.0 * rhs.e12i.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0)
+				+ E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) - E1oi(self.e123o.0 * rhs.e23i.0) + E1oi(self.e13.0 * rhs.e3oi.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0)
+				+ E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: -E23o(self.e123o.0 * rhs.e1oi.0) - E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0)
+				+ E23i(self.e13.0 * rhs.e12i.0)
+				+ E23i(self.e13oi.0 * rhs.e12i.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0)
+				+ E23i(self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0)
+				+ E2oi(self.e123o.0 * rhs.e13i.0)
+				+ E2oi(self.e1o.0 * rhs.e12i.0)
+				+ E2oi(self.e23.0 * rhs.e3oi.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0)
+				+ E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e123o.0 * rhs.e12i.0) - E3oi(self.e13.0 * rhs.e1oi.0) + E3oi(self.e1o.0 * rhs.e13i.0)
+				- E3oi(self.e23.0 * rhs.e2oi.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0)
+				+ E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0)
+				+ E123oi(self.e1o.0 * rhs.e23i.0)
+				+ E123oi(self.e23.0 * rhs.e1oi.0)
+				- E123oi(self.e2o.0 * rhs.e13i.0)
+				+ E123oi(self.e3o.0 * rhs.e12i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Versor {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(Line) -> Versor
+impl AntiGeometric<Line> for Versor {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) - S(self.e2o.0 * rhs.e13i.0) + S(self.e3o.0 * rhs.e12i.0),
+		//     e12  : E12(self.e123o.0 * rhs.e12i.0) - E12(self.e13.0 * rhs.e1oi.0) + E12(self.e1o.0 * rhs.e13i.0) - E12(self.e23.0 * rhs.e2oi.0) + E12(self.e2o.0 * rhs.e23i.0) - E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.e12.0 * rhs.e1oi.0) + E13(self.e123o.0 * rhs.e13i.0) - E13(self.e1o.0 * rhs.e12i.0) - E13(self.e23.0 * rhs.e3oi.0) + E13(self.e3o.0 * rhs.e23i.0) + E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e2o.0 * rhs.e3oi.0) + E1o(self.e3o.0 * rhs.e2oi.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e13i.0) + E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.e13oi.0 * rhs.e12i.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0) - E1i(self.eoi.0 * rhs.e23i.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : E23(self.e12.0 * rhs.e2oi.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e13.0 * rhs.e3oi.0) - E23(self.e2o.0 * rhs.e12i.0) - E23(self.e3o.0 * rhs.e13i.0) - E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e2oi.0) + E2o(self.e1o.0 * rhs.e3oi.0) - E2o(self.e3o.0 * rhs.e1oi.0),
+		//     e2i  : E2i(self.e12.0 * rhs.e23i.0) + E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e23.0 * rhs.e12i.0) + E2i(self.e23oi.0 * rhs.e12i.0) - E2i(self.e3i.0 * rhs.e1oi.0) + E2i(self.eoi.0 * rhs.e13i.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e1o.0 * rhs.e2oi.0) + E3o(self.e2o.0 * rhs.e1oi.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e3oi.0) + E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e13oi.0 * rhs.e23i.0) - E3i(self.e1i.0 * rhs.e2oi.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.e23oi.0 * rhs.e13i.0) + E3i(self.e2i.0 * rhs.e1oi.0) - E3i(self.eoi.0 * rhs.e12i.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e1o.0 * rhs.e23i.0) + Eoi(self.e23oi.0 * rhs.e1oi.0) - Eoi(self.e2o.0 * rhs.e13i.0) + Eoi(self.e3o.0 * rhs.e12i.0),
+		//     e123o: -E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) - E123i(self.e12oi.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e23oi.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e123o.0 * rhs.e12i.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) + E12oi(self.e1o.0 * rhs.e13i.0) - E12oi(self.e23oi.0 * rhs.e2oi.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e1o.0 * rhs.e12i.0) - E13oi(self.e23oi.0 * rhs.e3oi.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0) - E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Versor {
+			s: S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0)
+				+ S(self.e1o.0 * rhs.e23i.0)
+				+ S(self.e23.0 * rhs.e1oi.0)
+				- S(self.e2o.0 * rhs.e13i.0)
+				+ S(self.e3o.0 * rhs.e12i.0),
+			e12: E12(self.e123o.0 * rhs.e12i.0) - E12(self.e13.0 * rhs.e1oi.0) + E12(self.e1o.0 * rhs.e13i.0)
+				- E12(self.e23.0 * rhs.e2oi.0)
+				+ E12(self.e2o.0 * rhs.e23i.0)
+				- E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.e12.0 * rhs.e1oi.0) + E13(self.e123o.0 * rhs.e13i.0)
+				- E13(self.e1o.0 * rhs.e12i.0)
+				- E13(self.e23.0 * rhs.e3oi.0)
+				+ E13(self.e3o.0 * rhs.e23i.0)
+				+ E13(self.s.0 * rhs.e2oi.0),
+			e1o: E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e2o.0 * rhs.e3oi.0) + E1o(self.e3o.0 * rhs.e2oi.0),
+			e1i: E1i(self.e12.0 * rhs.e13i.0) + E1i(self.e123i.0 * rhs.e1oi.0)
+				- E1i(self.e12oi.0 * rhs.e13i.0)
+				- E1i(self.e13.0 * rhs.e12i.0)
+				+ E1i(self.e13oi.0 * rhs.e12i.0)
+				- E1i(self.e2i.0 * rhs.e3oi.0)
+				+ E1i(self.e3i.0 * rhs.e2oi.0)
+				- E1i(self.eoi.0 * rhs.e23i.0)
+				+ E1i(self.s.0 * rhs.e23i.0),
+			e23: E23(self.e12.0 * rhs.e2oi.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e13.0 * rhs.e3oi.0)
+				- E23(self.e2o.0 * rhs.e12i.0)
+				- E23(self.e3o.0 * rhs.e13i.0)
+				- E23(self.s.0 * rhs.e1oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e2oi.0) + E2o(self.e1o.0 * rhs.e3oi.0) - E2o(self.e3o.0 * rhs.e1oi.0),
+			e2i: E2i(self.e12.0 * rhs.e23i.0) + E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0)
+				+ E2i(self.e1i.0 * rhs.e3oi.0)
+				- E2i(self.e23.0 * rhs.e12i.0)
+				+ E2i(self.e23oi.0 * rhs.e12i.0)
+				- E2i(self.e3i.0 * rhs.e1oi.0)
+				+ E2i(self.eoi.0 * rhs.e13i.0)
+				- E2i(self.s.0 * rhs.e13i.0),
+			e3o: E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e1o.0 * rhs.e2oi.0) + E3o(self.e2o.0 * rhs.e1oi.0),
+			e3i: E3i(self.e123i.0 * rhs.e3oi.0) + E3i(self.e13.0 * rhs.e23i.0)
+				- E3i(self.e13oi.0 * rhs.e23i.0)
+				- E3i(self.e1i.0 * rhs.e2oi.0)
+				- E3i(self.e23.0 * rhs.e13i.0)
+				+ E3i(self.e23oi.0 * rhs.e13i.0)
+				+ E3i(self.e2i.0 * rhs.e1oi.0)
+				- E3i(self.eoi.0 * rhs.e12i.0)
+				+ E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0)
+				+ Eoi(self.e1o.0 * rhs.e23i.0)
+				+ Eoi(self.e23oi.0 * rhs.e1oi.0)
+				- Eoi(self.e2o.0 * rhs.e13i.0)
+				+ Eoi(self.e3o.0 * rhs.e12i.0),
+			e123o: -E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) - E123i(self.e12oi.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e123o.0 * rhs.e12i.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) + E12oi(self.e1o.0 * rhs.e13i.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0)
+				+ E12oi(self.e2o.0 * rhs.e23i.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0)
+				- E13oi(self.e1o.0 * rhs.e12i.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0)
+				+ E13oi(self.e3o.0 * rhs.e23i.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0)
+				- E23oi(self.e2o.0 * rhs.e12i.0)
+				- E23oi(self.e3o.0 * rhs.e13i.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// Versor.dot(Line) -> Multivector
+impl Dot<Line> for Versor {
+	type Output = Multivector;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e23oi.0 * rhs.e3oi.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e23oi.0 * rhs.e2oi.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e13oi.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) - Ei(self.e23oi.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) - E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13.0 * rhs.e13i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				+ Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Versor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(Line) -> Multivector
+impl Wedge<Line> for Versor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) - E123oi(self.e2o.0 * rhs.e13i.0) + E123oi(self.e3o.0 * rhs.e12i.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: self.s.wedge(rhs.e12i),
+			e13o: Default::default(),
+			e13i: self.s.wedge(rhs.e13i),
+			e1oi: self.s.wedge(rhs.e1oi),
+			e23o: Default::default(),
+			e23i: self.s.wedge(rhs.e23i),
+			e2oi: self.s.wedge(rhs.e2oi),
+			e3oi: self.s.wedge(rhs.e3oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e12.wedge(rhs.e3oi)
+				+ self.e13.wedge(rhs.e2oi)
+				+ self.e1o.wedge(rhs.e23i)
+				+ self.e23.wedge(rhs.e1oi)
+				+ self.e2o.wedge(rhs.e13i)
+				+ self.e3o.wedge(rhs.e12i),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line> for Versor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(Line) -> Versor
+impl AntiWedge<Line> for Versor {
+	type Output = Versor;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) - S(self.e2o.0 * rhs.e13i.0) + S(self.e3o.0 * rhs.e12i.0),
+		//     e12  : E12(self.e123o.0 * rhs.e12i.0),
+		//     e13  : E13(self.e123o.0 * rhs.e13i.0),
+		//     e1o  : E1o(self.e123o.0 * rhs.e1oi.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e23i.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e2oi.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o  : E3o(self.e123o.0 * rhs.e3oi.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi  : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: self.e12.anti_wedge(rhs.e3oi)
+				+ self.e13.anti_wedge(rhs.e2oi)
+				+ self.e1o.anti_wedge(rhs.e23i)
+				+ self.e23.anti_wedge(rhs.e1oi)
+				+ self.e2o.anti_wedge(rhs.e13i)
+				+ self.e3o.anti_wedge(rhs.e12i),
+			e12: self.e123o.anti_wedge(rhs.e12i),
+			e13: self.e123o.anti_wedge(rhs.e13i),
+			e1o: self.e123o.anti_wedge(rhs.e1oi),
+			e1i: self.e123i.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e12i),
+			e23: self.e123o.anti_wedge(rhs.e23i),
+			e2o: self.e123o.anti_wedge(rhs.e2oi),
+			e2i: self.e123i.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e12i),
+			e3o: self.e123o.anti_wedge(rhs.e3oi),
+			e3i: self.e123i.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e13i),
+			eoi: self.e12oi.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e2oi) + self.e23oi.anti_wedge(rhs.e1oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Versor {
+	type Output = Versor;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(Line) -> Multivector
+impl LeftContraction<Line> for Versor {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : Default::default(),
+		//     e12o  : Default::default(),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : Default::default(),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : Default::default(),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: Default::default(),
+			e12o: Default::default(),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: Default::default(),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: Default::default(),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Versor.right_contraction(Line) -> Point
+impl RightContraction<Line> for Versor {
+	type Output = Point;
+	fn right_contraction(self, rhs: Line) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Default::default(),
+			inf: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+impl ScalarProduct<Line> for Versor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Versor.commutator(Line) -> Multivector
+impl Commutator<Line> for Versor {
+	type Output = Multivector;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     e2    : -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Default::default(),
+		//     ei    : -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3o.0 * rhs.e12i.0),
+		//     e12o  : -E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e2o.0 * rhs.e1oi.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) - E12i(self.e2i.0 * rhs.e1oi.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : -E13o(self.e1o.0 * rhs.e3oi.0) + E13o(self.e3o.0 * rhs.e1oi.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) - E1oi(self.e2o.0 * rhs.e12i.0) - E1oi(self.e3o.0 * rhs.e13i.0),
+		//     e23o  : -E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) - E2oi(self.e3o.0 * rhs.e23i.0),
+		//     e3oi  : -E3oi(self.e13.0 * rhs.e1oi.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) + E3oi(self.e2o.0 * rhs.e23i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+			e2: -E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Default::default(),
+			ei: -Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3o.0 * rhs.e12i.0),
+			e12o: -E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e2o.0 * rhs.e1oi.0),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: -E13o(self.e1o.0 * rhs.e3oi.0) + E13o(self.e3o.0 * rhs.e1oi.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0)
+				- E13i(self.e23.0 * rhs.e12i.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0),
+			e23o: -E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.e2i.0 * rhs.e3oi.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Versor.anti_dot(Line) -> Versor
+impl AntiDot<Line> for Versor {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : -E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Default::default(),
+		//     e123o: -E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) - E123i(self.e12oi.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e23oi.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e123o.0 * rhs.e12i.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) + E12oi(self.e1o.0 * rhs.e13i.0) - E12oi(self.e23oi.0 * rhs.e2oi.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) - E13oi(self.e1o.0 * rhs.e12i.0) - E13oi(self.e23oi.0 * rhs.e3oi.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0) - E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.s.0 * rhs.e2oi.0),
+			e1o: Default::default(),
+			e1i: E1i(self.s.0 * rhs.e23i.0),
+			e23: -E23(self.s.0 * rhs.e1oi.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.s.0 * rhs.e13i.0),
+			e3o: Default::default(),
+			e3i: E3i(self.s.0 * rhs.e12i.0),
+			eoi: Default::default(),
+			e123o: -E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) - E123i(self.e12oi.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e123o.0 * rhs.e12i.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) + E12oi(self.e1o.0 * rhs.e13i.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0)
+				+ E12oi(self.e2o.0 * rhs.e23i.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0)
+				- E13oi(self.e1o.0 * rhs.e12i.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0)
+				+ E13oi(self.e3o.0 * rhs.e23i.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0)
+				- E23oi(self.e2o.0 * rhs.e12i.0)
+				- E23oi(self.e3o.0 * rhs.e13i.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP Circle:
+
+// Versor.geometric(Circle) -> Multivector
+impl Geometric<Circle> for Versor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e23oi.0 * rhs.e3oi.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) + E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e23oi.0 * rhs.e2oi.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) + Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) + Eo(self.e13oi.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) + Eo(self.e23oi.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) + Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e13oi.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) - Ei(self.e23oi.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13oi.0 * rhs.e2oi.0) - E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0) + E123(self.e23oi.0 * rhs.e1oi.0) + E123(self.e2i.0 * rhs.e13o.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3i.0 * rhs.e12o.0) - E123(self.e3o.0 * rhs.e12i.0) + E123(self.s.0 * rhs.e123.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3oi.0) - E12o(self.e13.0 * rhs.e23o.0) + E12o(self.e13oi.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e23.0 * rhs.e13o.0) - E12o(self.e23oi.0 * rhs.e13o.0) + E12o(self.e2o.0 * rhs.e1oi.0) - E12o(self.e3o.0 * rhs.e123.0) - E12o(self.eoi.0 * rhs.e12o.0) + E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13.0 * rhs.e23i.0) - E12i(self.e13oi.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) + E12i(self.e23oi.0 * rhs.e13i.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.eoi.0 * rhs.e12i.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e12.0 * rhs.e23o.0) + E13o(self.e123o.0 * rhs.e2oi.0) - E13o(self.e12oi.0 * rhs.e23o.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.e23oi.0 * rhs.e12o.0) + E13o(self.e2o.0 * rhs.e123.0) + E13o(self.e3o.0 * rhs.e1oi.0) - E13o(self.eoi.0 * rhs.e13o.0) + E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) - E13i(self.e23oi.0 * rhs.e12i.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e123i.0 * rhs.e23o.0) - E1oi(self.e123o.0 * rhs.e23i.0) + E1oi(self.e13.0 * rhs.e3oi.0) - E1oi(self.e23oi.0 * rhs.e123.0) + E1oi(self.e2i.0 * rhs.e12o.0) - E1oi(self.e2o.0 * rhs.e12i.0) + E1oi(self.e3i.0 * rhs.e13o.0) - E1oi(self.e3o.0 * rhs.e13i.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e13o.0) - E23o(self.e123o.0 * rhs.e1oi.0) + E23o(self.e12oi.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0) - E23o(self.e13oi.0 * rhs.e12o.0) - E23o(self.e1o.0 * rhs.e123.0) - E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0) - E23o(self.eoi.0 * rhs.e23o.0) + E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.e13oi.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e123i.0 * rhs.e13o.0) + E2oi(self.e123o.0 * rhs.e13i.0) + E2oi(self.e13oi.0 * rhs.e123.0) - E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.e3i.0 * rhs.e23o.0) - E2oi(self.e3o.0 * rhs.e23i.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.e123i.0 * rhs.e12o.0) - E3oi(self.e123o.0 * rhs.e12i.0) - E3oi(self.e12oi.0 * rhs.e123.0) - E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) - E3oi(self.e2i.0 * rhs.e23o.0) + E3oi(self.e2o.0 * rhs.e23i.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e2o.0 * rhs.e13i.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.e3o.0 * rhs.e12i.0) + E123oi(self.eoi.0 * rhs.e123.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0)
+				- E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e13.0 * rhs.e123.0)
+				- E2(self.e1i.0 * rhs.e12o.0)
+				- E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e12.0 * rhs.e123.0) + E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0) + Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) + Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13.0 * rhs.e13i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				+ Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e3oi.0)
+				- E123(self.e13oi.0 * rhs.e2oi.0)
+				- E123(self.e1i.0 * rhs.e23o.0)
+				- E123(self.e1o.0 * rhs.e23i.0)
+				+ E123(self.e23oi.0 * rhs.e1oi.0)
+				+ E123(self.e2i.0 * rhs.e13o.0)
+				+ E123(self.e2o.0 * rhs.e13i.0)
+				- E123(self.e3i.0 * rhs.e12o.0)
+				- E123(self.e3o.0 * rhs.e12i.0)
+				+ E123(self.s.0 * rhs.e123.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3oi.0) - E12o(self.e13.0 * rhs.e23o.0) + E12o(self.e13oi.0 * rhs.e23o.0)
+				- E12o(self.e1o.0 * rhs.e2oi.0)
+				+ E12o(self.e23.0 * rhs.e13o.0)
+				- E12o(self.e23oi.0 * rhs.e13o.0)
+				+ E12o(self.e2o.0 * rhs.e1oi.0)
+				- E12o(self.e3o.0 * rhs.e123.0)
+				- E12o(self.eoi.0 * rhs.e12o.0)
+				+ E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e123i.0 * rhs.e3oi.0) - E12i(self.e13.0 * rhs.e23i.0) - E12i(self.e13oi.0 * rhs.e23i.0)
+				+ E12i(self.e1i.0 * rhs.e2oi.0)
+				+ E12i(self.e23.0 * rhs.e13i.0)
+				+ E12i(self.e23oi.0 * rhs.e13i.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				- E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0)
+				+ E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) + E13o(self.e123o.0 * rhs.e2oi.0)
+				- E13o(self.e12oi.0 * rhs.e23o.0)
+				- E13o(self.e1o.0 * rhs.e3oi.0)
+				- E13o(self.e23.0 * rhs.e12o.0)
+				+ E13o(self.e23oi.0 * rhs.e12o.0)
+				+ E13o(self.e2o.0 * rhs.e123.0)
+				+ E13o(self.e3o.0 * rhs.e1oi.0)
+				- E13o(self.eoi.0 * rhs.e13o.0)
+				+ E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) - E13i(self.e123i.0 * rhs.e2oi.0)
+				+ E13i(self.e12oi.0 * rhs.e23i.0)
+				+ E13i(self.e1i.0 * rhs.e3oi.0)
+				- E13i(self.e23.0 * rhs.e12i.0)
+				- E13i(self.e23oi.0 * rhs.e12i.0)
+				+ E13i(self.e2i.0 * rhs.e123.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0)
+				+ E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e123i.0 * rhs.e23o.0) - E1oi(self.e123o.0 * rhs.e23i.0)
+				+ E1oi(self.e13.0 * rhs.e3oi.0)
+				- E1oi(self.e23oi.0 * rhs.e123.0)
+				+ E1oi(self.e2i.0 * rhs.e12o.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				+ E1oi(self.e3i.0 * rhs.e13o.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0)
+				+ E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) - E23o(self.e123o.0 * rhs.e1oi.0)
+				+ E23o(self.e12oi.0 * rhs.e13o.0)
+				+ E23o(self.e13.0 * rhs.e12o.0)
+				- E23o(self.e13oi.0 * rhs.e12o.0)
+				- E23o(self.e1o.0 * rhs.e123.0)
+				- E23o(self.e2o.0 * rhs.e3oi.0)
+				+ E23o(self.e3o.0 * rhs.e2oi.0)
+				- E23o(self.eoi.0 * rhs.e23o.0)
+				+ E23o(self.s.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0)
+				+ E23i(self.e13.0 * rhs.e12i.0)
+				+ E23i(self.e13oi.0 * rhs.e12i.0)
+				- E23i(self.e1i.0 * rhs.e123.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0)
+				+ E23i(self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e123i.0 * rhs.e13o.0)
+				+ E2oi(self.e123o.0 * rhs.e13i.0)
+				+ E2oi(self.e13oi.0 * rhs.e123.0)
+				- E2oi(self.e1i.0 * rhs.e12o.0)
+				+ E2oi(self.e1o.0 * rhs.e12i.0)
+				+ E2oi(self.e23.0 * rhs.e3oi.0)
+				+ E2oi(self.e3i.0 * rhs.e23o.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0)
+				+ E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.e123i.0 * rhs.e12o.0)
+				- E3oi(self.e123o.0 * rhs.e12i.0)
+				- E3oi(self.e12oi.0 * rhs.e123.0)
+				- E3oi(self.e13.0 * rhs.e1oi.0)
+				- E3oi(self.e1i.0 * rhs.e13o.0)
+				+ E3oi(self.e1o.0 * rhs.e13i.0)
+				- E3oi(self.e23.0 * rhs.e2oi.0)
+				- E3oi(self.e2i.0 * rhs.e23o.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0)
+				+ E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e1i.0 * rhs.e23o.0)
+				+ E123oi(self.e1o.0 * rhs.e23i.0)
+				+ E123oi(self.e23.0 * rhs.e1oi.0)
+				+ E123oi(self.e2i.0 * rhs.e13o.0)
+				- E123oi(self.e2o.0 * rhs.e13i.0)
+				- E123oi(self.e3i.0 * rhs.e12o.0)
+				+ E123oi(self.e3o.0 * rhs.e12i.0)
+				+ E123oi(self.eoi.0 * rhs.e123.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Circle> for Versor {
+	type Output = Multivector;
+	fn mul(self, rhs: Circle) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(Circle) -> Versor
+impl AntiGeometric<Circle> for Versor {
+	type Output = Versor;
+	fn anti_geometric(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e1i.0 * rhs.e23o.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e2o.0 * rhs.e13i.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.e3o.0 * rhs.e12i.0) + S(self.eoi.0 * rhs.e123.0),
+		//     e12  : -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0) - E12(self.e13.0 * rhs.e1oi.0) - E12(self.e1i.0 * rhs.e13o.0) + E12(self.e1o.0 * rhs.e13i.0) - E12(self.e23.0 * rhs.e2oi.0) - E12(self.e2i.0 * rhs.e23o.0) + E12(self.e2o.0 * rhs.e23i.0) - E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.e12.0 * rhs.e1oi.0) - E13(self.e123i.0 * rhs.e13o.0) + E13(self.e123o.0 * rhs.e13i.0) + E13(self.e13oi.0 * rhs.e123.0) + E13(self.e1i.0 * rhs.e12o.0) - E13(self.e1o.0 * rhs.e12i.0) - E13(self.e23.0 * rhs.e3oi.0) - E13(self.e3i.0 * rhs.e23o.0) + E13(self.e3o.0 * rhs.e23i.0) + E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : -E1o(self.e12.0 * rhs.e13o.0) + E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13.0 * rhs.e12o.0) + E1o(self.e13oi.0 * rhs.e12o.0) - E1o(self.e1o.0 * rhs.e123.0) - E1o(self.e2o.0 * rhs.e3oi.0) + E1o(self.e3o.0 * rhs.e2oi.0) - E1o(self.eoi.0 * rhs.e23o.0) - E1o(self.s.0 * rhs.e23o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e13i.0) + E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.e13oi.0 * rhs.e12i.0) + E1i(self.e1i.0 * rhs.e123.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2oi.0) - E1i(self.eoi.0 * rhs.e23i.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : E23(self.e12.0 * rhs.e2oi.0) - E23(self.e123i.0 * rhs.e23o.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e13.0 * rhs.e3oi.0) + E23(self.e23oi.0 * rhs.e123.0) + E23(self.e2i.0 * rhs.e12o.0) - E23(self.e2o.0 * rhs.e12i.0) + E23(self.e3i.0 * rhs.e13o.0) - E23(self.e3o.0 * rhs.e13i.0) - E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e23o.0) + E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e1o.0 * rhs.e3oi.0) + E2o(self.e23.0 * rhs.e12o.0) + E2o(self.e23oi.0 * rhs.e12o.0) - E2o(self.e2o.0 * rhs.e123.0) - E2o(self.e3o.0 * rhs.e1oi.0) + E2o(self.eoi.0 * rhs.e13o.0) + E2o(self.s.0 * rhs.e13o.0),
+		//     e2i  : E2i(self.e12.0 * rhs.e23i.0) + E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e23.0 * rhs.e12i.0) + E2i(self.e23oi.0 * rhs.e12i.0) + E2i(self.e2i.0 * rhs.e123.0) - E2i(self.e3i.0 * rhs.e1oi.0) + E2i(self.eoi.0 * rhs.e13i.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e13.0 * rhs.e23o.0) - E3o(self.e13oi.0 * rhs.e23o.0) - E3o(self.e1o.0 * rhs.e2oi.0) + E3o(self.e23.0 * rhs.e13o.0) + E3o(self.e23oi.0 * rhs.e13o.0) + E3o(self.e2o.0 * rhs.e1oi.0) - E3o(self.e3o.0 * rhs.e123.0) - E3o(self.eoi.0 * rhs.e12o.0) - E3o(self.s.0 * rhs.e12o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e3oi.0) + E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e13oi.0 * rhs.e23i.0) - E3i(self.e1i.0 * rhs.e2oi.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.e23oi.0 * rhs.e13i.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.e3i.0 * rhs.e123.0) - E3i(self.eoi.0 * rhs.e12i.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e1i.0 * rhs.e23o.0) + Eoi(self.e1o.0 * rhs.e23i.0) + Eoi(self.e23oi.0 * rhs.e1oi.0) - Eoi(self.e2i.0 * rhs.e13o.0) - Eoi(self.e2o.0 * rhs.e13i.0) + Eoi(self.e3i.0 * rhs.e12o.0) + Eoi(self.e3o.0 * rhs.e12i.0) + Eoi(self.s.0 * rhs.e123.0),
+		//     e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e123o.0 * rhs.e123.0) - E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e13oi.0 * rhs.e13o.0) - E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e23.0 * rhs.e23o.0) - E123o(self.e23oi.0 * rhs.e23o.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e123i.0 * rhs.e123.0) - E123i(self.e12oi.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e23oi.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.e123.0) + E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) + E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e1o.0 * rhs.e13i.0) - E12oi(self.e23oi.0 * rhs.e2oi.0) + E12oi(self.e2i.0 * rhs.e23o.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) + E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e1i.0 * rhs.e12o.0) - E13oi(self.e1o.0 * rhs.e12i.0) - E13oi(self.e23oi.0 * rhs.e3oi.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0) + E23oi(self.e23.0 * rhs.e123.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Versor {
+			s: S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e1i.0 * rhs.e23o.0)
+				+ S(self.e1o.0 * rhs.e23i.0)
+				+ S(self.e23.0 * rhs.e1oi.0)
+				+ S(self.e2i.0 * rhs.e13o.0)
+				- S(self.e2o.0 * rhs.e13i.0)
+				- S(self.e3i.0 * rhs.e12o.0)
+				+ S(self.e3o.0 * rhs.e12i.0)
+				+ S(self.eoi.0 * rhs.e123.0),
+			e12: -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0)
+				- E12(self.e13.0 * rhs.e1oi.0)
+				- E12(self.e1i.0 * rhs.e13o.0)
+				+ E12(self.e1o.0 * rhs.e13i.0)
+				- E12(self.e23.0 * rhs.e2oi.0)
+				- E12(self.e2i.0 * rhs.e23o.0)
+				+ E12(self.e2o.0 * rhs.e23i.0)
+				- E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.e12.0 * rhs.e1oi.0) - E13(self.e123i.0 * rhs.e13o.0)
+				+ E13(self.e123o.0 * rhs.e13i.0)
+				+ E13(self.e13oi.0 * rhs.e123.0)
+				+ E13(self.e1i.0 * rhs.e12o.0)
+				- E13(self.e1o.0 * rhs.e12i.0)
+				- E13(self.e23.0 * rhs.e3oi.0)
+				- E13(self.e3i.0 * rhs.e23o.0)
+				+ E13(self.e3o.0 * rhs.e23i.0)
+				+ E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.e12.0 * rhs.e13o.0) + E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0)
+				+ E1o(self.e13.0 * rhs.e12o.0)
+				+ E1o(self.e13oi.0 * rhs.e12o.0)
+				- E1o(self.e1o.0 * rhs.e123.0)
+				- E1o(self.e2o.0 * rhs.e3oi.0)
+				+ E1o(self.e3o.0 * rhs.e2oi.0)
+				- E1o(self.eoi.0 * rhs.e23o.0)
+				- E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.e12.0 * rhs.e13i.0) + E1i(self.e123i.0 * rhs.e1oi.0)
+				- E1i(self.e12oi.0 * rhs.e13i.0)
+				- E1i(self.e13.0 * rhs.e12i.0)
+				+ E1i(self.e13oi.0 * rhs.e12i.0)
+				+ E1i(self.e1i.0 * rhs.e123.0)
+				- E1i(self.e2i.0 * rhs.e3oi.0)
+				+ E1i(self.e3i.0 * rhs.e2oi.0)
+				- E1i(self.eoi.0 * rhs.e23i.0)
+				+ E1i(self.s.0 * rhs.e23i.0),
+			e23: E23(self.e12.0 * rhs.e2oi.0) - E23(self.e123i.0 * rhs.e23o.0)
+				+ E23(self.e123o.0 * rhs.e23i.0)
+				+ E23(self.e13.0 * rhs.e3oi.0)
+				+ E23(self.e23oi.0 * rhs.e123.0)
+				+ E23(self.e2i.0 * rhs.e12o.0)
+				- E23(self.e2o.0 * rhs.e12i.0)
+				+ E23(self.e3i.0 * rhs.e13o.0)
+				- E23(self.e3o.0 * rhs.e13i.0)
+				- E23(self.s.0 * rhs.e1oi.0),
+			e2o: -E2o(self.e12.0 * rhs.e23o.0) + E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0)
+				+ E2o(self.e1o.0 * rhs.e3oi.0)
+				+ E2o(self.e23.0 * rhs.e12o.0)
+				+ E2o(self.e23oi.0 * rhs.e12o.0)
+				- E2o(self.e2o.0 * rhs.e123.0)
+				- E2o(self.e3o.0 * rhs.e1oi.0)
+				+ E2o(self.eoi.0 * rhs.e13o.0)
+				+ E2o(self.s.0 * rhs.e13o.0),
+			e2i: E2i(self.e12.0 * rhs.e23i.0) + E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0)
+				+ E2i(self.e1i.0 * rhs.e3oi.0)
+				- E2i(self.e23.0 * rhs.e12i.0)
+				+ E2i(self.e23oi.0 * rhs.e12i.0)
+				+ E2i(self.e2i.0 * rhs.e123.0)
+				- E2i(self.e3i.0 * rhs.e1oi.0)
+				+ E2i(self.eoi.0 * rhs.e13i.0)
+				- E2i(self.s.0 * rhs.e13i.0),
+			e3o: E3o(self.e123o.0 * rhs.e3oi.0)
+				- E3o(self.e13.0 * rhs.e23o.0)
+				- E3o(self.e13oi.0 * rhs.e23o.0)
+				- E3o(self.e1o.0 * rhs.e2oi.0)
+				+ E3o(self.e23.0 * rhs.e13o.0)
+				+ E3o(self.e23oi.0 * rhs.e13o.0)
+				+ E3o(self.e2o.0 * rhs.e1oi.0)
+				- E3o(self.e3o.0 * rhs.e123.0)
+				- E3o(self.eoi.0 * rhs.e12o.0)
+				- E3o(self.s.0 * rhs.e12o.0),
+			e3i: E3i(self.e123i.0 * rhs.e3oi.0) + E3i(self.e13.0 * rhs.e23i.0)
+				- E3i(self.e13oi.0 * rhs.e23i.0)
+				- E3i(self.e1i.0 * rhs.e2oi.0)
+				- E3i(self.e23.0 * rhs.e13i.0)
+				+ E3i(self.e23oi.0 * rhs.e13i.0)
+				+ E3i(self.e2i.0 * rhs.e1oi.0)
+				+ E3i(self.e3i.0 * rhs.e123.0)
+				- E3i(self.eoi.0 * rhs.e12i.0)
+				+ E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0)
+				+ Eoi(self.e1i.0 * rhs.e23o.0)
+				+ Eoi(self.e1o.0 * rhs.e23i.0)
+				+ Eoi(self.e23oi.0 * rhs.e1oi.0)
+				- Eoi(self.e2i.0 * rhs.e13o.0)
+				- Eoi(self.e2o.0 * rhs.e13i.0)
+				+ Eoi(self.e3i.0 * rhs.e12o.0)
+				+ Eoi(self.e3o.0 * rhs.e12i.0)
+				+ Eoi(self.s.0 * rhs.e123.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0)
+				- E123o(self.e123o.0 * rhs.e123.0)
+				- E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13.0 * rhs.e13o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				- E123o(self.e1o.0 * rhs.e1oi.0)
+				- E123o(self.e23.0 * rhs.e23o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0)
+				- E123o(self.e2o.0 * rhs.e2oi.0)
+				- E123o(self.e3o.0 * rhs.e3oi.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e123i.0 * rhs.e123.0) - E123i(self.e12oi.0 * rhs.e12i.0)
+				+ E123i(self.e13.0 * rhs.e13i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0) + E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				+ E12oi(self.e1i.0 * rhs.e13o.0)
+				+ E12oi(self.e1o.0 * rhs.e13i.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0)
+				+ E12oi(self.e2i.0 * rhs.e23o.0)
+				+ E12oi(self.e2o.0 * rhs.e23i.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: E13oi(self.e123i.0 * rhs.e13o.0)
+				+ E13oi(self.e123o.0 * rhs.e13i.0)
+				+ E13oi(self.e12oi.0 * rhs.e1oi.0)
+				+ E13oi(self.e13.0 * rhs.e123.0)
+				- E13oi(self.e1i.0 * rhs.e12o.0)
+				- E13oi(self.e1o.0 * rhs.e12i.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.e3o.0 * rhs.e23i.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0)
+				+ E23oi(self.e23.0 * rhs.e123.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e2o.0 * rhs.e12i.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.e3o.0 * rhs.e13i.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// Versor.dot(Circle) -> Multivector
+impl Dot<Circle> for Versor {
+	type Output = Multivector;
+	fn dot(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e23oi.0 * rhs.e3oi.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) + E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e23oi.0 * rhs.e2oi.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) + Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) + Eo(self.e13oi.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) + Eo(self.e23oi.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) + Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e13oi.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) - Ei(self.e23oi.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0)
+				- E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e13.0 * rhs.e123.0)
+				- E2(self.e1i.0 * rhs.e12o.0)
+				- E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e12.0 * rhs.e123.0) + E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0) + Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) + Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13.0 * rhs.e13i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				+ Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Circle> for Versor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Circle) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(Circle) -> Multivector
+impl Wedge<Circle> for Versor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : Default::default(),
+		//     e2    : Default::default(),
+		//     e3    : Default::default(),
+		//     eo    : Default::default(),
+		//     ei    : Default::default(),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e2o.0 * rhs.e13i.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.e3o.0 * rhs.e12i.0) + E123oi(self.eoi.0 * rhs.e123.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			eo: Default::default(),
+			ei: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.s.wedge(rhs.e123),
+			e12o: self.s.wedge(rhs.e12o),
+			e12i: self.s.wedge(rhs.e12i),
+			e13o: self.s.wedge(rhs.e13o),
+			e13i: self.s.wedge(rhs.e13i),
+			e1oi: self.s.wedge(rhs.e1oi),
+			e23o: self.s.wedge(rhs.e23o),
+			e23i: self.s.wedge(rhs.e23i),
+			e2oi: self.s.wedge(rhs.e2oi),
+			e3oi: self.s.wedge(rhs.e3oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: self.e12.wedge(rhs.e3oi)
+				+ self.e13.wedge(rhs.e2oi)
+				+ self.e1i.wedge(rhs.e23o)
+				+ self.e1o.wedge(rhs.e23i)
+				+ self.e23.wedge(rhs.e1oi)
+				+ self.e2i.wedge(rhs.e13o)
+				+ self.e2o.wedge(rhs.e13i)
+				+ self.e3i.wedge(rhs.e12o)
+				+ self.e3o.wedge(rhs.e12i)
+				+ self.eoi.wedge(rhs.e123),
+		}
+	}
+}
+
+impl std::ops::BitXor<Circle> for Versor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Circle) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(Circle) -> Versor
+impl AntiWedge<Circle> for Versor {
+	type Output = Versor;
+	fn anti_wedge(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e12.0 * rhs.e3oi.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e1i.0 * rhs.e23o.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e2o.0 * rhs.e13i.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.e3o.0 * rhs.e12i.0) + S(self.eoi.0 * rhs.e123.0),
+		//     e12  : -E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e123o.0 * rhs.e13i.0) + E13(self.e13oi.0 * rhs.e123.0),
+		//     e1o  : E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0),
+		//     e23  : -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e23oi.0 * rhs.e123.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e23oi.0 * rhs.e12o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0),
+		//     e3o  : E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0),
+		//     eoi  : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: Default::default(),
+		//     e13oi: Default::default(),
+		//     e23oi: Default::default(),
+		// }
+		Versor {
+			s: self.e12.anti_wedge(rhs.e3oi)
+				+ self.e13.anti_wedge(rhs.e2oi)
+				+ self.e1i.anti_wedge(rhs.e23o)
+				+ self.e1o.anti_wedge(rhs.e23i)
+				+ self.e23.anti_wedge(rhs.e1oi)
+				+ self.e2i.anti_wedge(rhs.e13o)
+				+ self.e2o.anti_wedge(rhs.e13i)
+				+ self.e3i.anti_wedge(rhs.e12o)
+				+ self.e3o.anti_wedge(rhs.e12i)
+				+ self.eoi.anti_wedge(rhs.e123),
+			e12: self.e123i.anti_wedge(rhs.e12o) + self.e123o.anti_wedge(rhs.e12i) + self.e12oi.anti_wedge(rhs.e123),
+			e13: self.e123i.anti_wedge(rhs.e13o) + self.e123o.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e123),
+			e1o: self.e123o.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13o) + self.e13oi.anti_wedge(rhs.e12o),
+			e1i: self.e123i.anti_wedge(rhs.e1oi) + self.e12oi.anti_wedge(rhs.e13i) + self.e13oi.anti_wedge(rhs.e12i),
+			e23: self.e123i.anti_wedge(rhs.e23o) + self.e123o.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e123),
+			e2o: self.e123o.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e12o),
+			e2i: self.e123i.anti_wedge(rhs.e2oi) + self.e12oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e12i),
+			e3o: self.e123o.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23o) + self.e23oi.anti_wedge(rhs.e13o),
+			e3i: self.e123i.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e23i) + self.e23oi.anti_wedge(rhs.e13i),
+			eoi: self.e12oi.anti_wedge(rhs.e3oi) + self.e13oi.anti_wedge(rhs.e2oi) + self.e23oi.anti_wedge(rhs.e1oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Circle> for Versor {
+	type Output = Versor;
+	fn bitand(self, rhs: Circle) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(Circle) -> Multivector
+impl LeftContraction<Circle> for Versor {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : -E1(self.e23.0 * rhs.e123.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0),
+		//     e2    : E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: -E1(self.e23.0 * rhs.e123.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0),
+			e2: E2(self.e13.0 * rhs.e123.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0),
+			e3: -E3(self.e12.0 * rhs.e123.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Versor.right_contraction(Circle) -> Point
+impl RightContraction<Circle> for Versor {
+	type Output = Point;
+	fn right_contraction(self, rhs: Circle) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     y  : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     z  : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     o  : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     inf: Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		// }
+		Point {
+			x: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0),
+			y: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			z: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			o: Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0),
+			inf: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+		}
+	}
+}
+
+impl ScalarProduct<Circle> for Versor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Circle) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Versor.commutator(Circle) -> Multivector
+impl Commutator<Circle> for Versor {
+	type Output = Multivector;
+	fn commutator(self, rhs: Circle) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13oi.0 * rhs.e3oi.0),
+		//     e2    : -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23oi.0 * rhs.e3oi.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23oi.0 * rhs.e2oi.0),
+		//     eo    : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) + Eo(self.e23oi.0 * rhs.e23o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e23oi.0 * rhs.e23i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2i.0 * rhs.e13o.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3i.0 * rhs.e12o.0) - E123(self.e3o.0 * rhs.e12i.0),
+		//     e12o  : -E12o(self.e13.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e23.0 * rhs.e13o.0) + E12o(self.e2o.0 * rhs.e1oi.0) - E12o(self.e3o.0 * rhs.e123.0) - E12o(self.eoi.0 * rhs.e12o.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.e2o.0 * rhs.e123.0) + E13o(self.e3o.0 * rhs.e1oi.0) - E13o(self.eoi.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.e2i.0 * rhs.e12o.0) - E1oi(self.e2o.0 * rhs.e12i.0) + E1oi(self.e3i.0 * rhs.e13o.0) - E1oi(self.e3o.0 * rhs.e13i.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0) - E23o(self.e1o.0 * rhs.e123.0) - E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0) - E23o(self.eoi.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.e3i.0 * rhs.e23o.0) - E2oi(self.e3o.0 * rhs.e23i.0),
+		//     e3oi  : -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) - E3oi(self.e2i.0 * rhs.e23o.0) + E3oi(self.e2o.0 * rhs.e23i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0),
+			e2: -E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0),
+			eo: Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0)
+				+ E123(self.e2i.0 * rhs.e13o.0)
+				+ E123(self.e2o.0 * rhs.e13i.0)
+				- E123(self.e3i.0 * rhs.e12o.0)
+				- E123(self.e3o.0 * rhs.e12i.0),
+			e12o: -E12o(self.e13.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2oi.0)
+				+ E12o(self.e23.0 * rhs.e13o.0)
+				+ E12o(self.e2o.0 * rhs.e1oi.0)
+				- E12o(self.e3o.0 * rhs.e123.0)
+				- E12o(self.eoi.0 * rhs.e12o.0),
+			e12i: -E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				- E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0)
+				+ E13o(self.e2o.0 * rhs.e123.0)
+				+ E13o(self.e3o.0 * rhs.e1oi.0)
+				- E13o(self.eoi.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0)
+				+ E13i(self.e2i.0 * rhs.e123.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.e2i.0 * rhs.e12o.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				+ E1oi(self.e3i.0 * rhs.e13o.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0)
+				- E23o(self.e1o.0 * rhs.e123.0)
+				- E23o(self.e2o.0 * rhs.e3oi.0)
+				+ E23o(self.e3o.0 * rhs.e2oi.0)
+				- E23o(self.eoi.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e1i.0 * rhs.e12o.0)
+				+ E2oi(self.e1o.0 * rhs.e12i.0)
+				+ E2oi(self.e23.0 * rhs.e3oi.0)
+				+ E2oi(self.e3i.0 * rhs.e23o.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0)
+				- E3oi(self.e23.0 * rhs.e2oi.0)
+				- E3oi(self.e2i.0 * rhs.e23o.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Versor.anti_dot(Circle) -> Versor
+impl AntiDot<Circle> for Versor {
+	type Output = Versor;
+	fn anti_dot(self, rhs: Circle) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.s.0 * rhs.e3oi.0),
+		//     e13  : E13(self.s.0 * rhs.e2oi.0),
+		//     e1o  : -E1o(self.s.0 * rhs.e23o.0),
+		//     e1i  : E1i(self.s.0 * rhs.e23i.0),
+		//     e23  : -E23(self.s.0 * rhs.e1oi.0),
+		//     e2o  : E2o(self.s.0 * rhs.e13o.0),
+		//     e2i  : -E2i(self.s.0 * rhs.e13i.0),
+		//     e3o  : -E3o(self.s.0 * rhs.e12o.0),
+		//     e3i  : E3i(self.s.0 * rhs.e12i.0),
+		//     eoi  : Eoi(self.s.0 * rhs.e123.0),
+		//     e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e123o.0 * rhs.e123.0) - E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e13oi.0 * rhs.e13o.0) - E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e23.0 * rhs.e23o.0) - E123o(self.e23oi.0 * rhs.e23o.0) - E123o(self.e2o.0 * rhs.e2oi.0) - E123o(self.e3o.0 * rhs.e3oi.0),
+		//     e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e123i.0 * rhs.e123.0) - E123i(self.e12oi.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e23oi.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3oi.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.e123.0) + E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) + E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e1o.0 * rhs.e13i.0) - E12oi(self.e23oi.0 * rhs.e2oi.0) + E12oi(self.e2i.0 * rhs.e23o.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.eoi.0 * rhs.e3oi.0),
+		//     e13oi: E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) + E13oi(self.e13.0 * rhs.e123.0) - E13oi(self.e1i.0 * rhs.e12o.0) - E13oi(self.e1o.0 * rhs.e12i.0) - E13oi(self.e23oi.0 * rhs.e3oi.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0),
+		//     e23oi: E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13oi.0 * rhs.e3oi.0) + E23oi(self.e23.0 * rhs.e123.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.s.0 * rhs.e23i.0),
+			e23: -E23(self.s.0 * rhs.e1oi.0),
+			e2o: E2o(self.s.0 * rhs.e13o.0),
+			e2i: -E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.s.0 * rhs.e12o.0),
+			e3i: E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.s.0 * rhs.e123.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0)
+				- E123o(self.e123o.0 * rhs.e123.0)
+				- E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13.0 * rhs.e13o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				- E123o(self.e1o.0 * rhs.e1oi.0)
+				- E123o(self.e23.0 * rhs.e23o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0)
+				- E123o(self.e2o.0 * rhs.e2oi.0)
+				- E123o(self.e3o.0 * rhs.e3oi.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e123i.0 * rhs.e123.0) - E123i(self.e12oi.0 * rhs.e12i.0)
+				+ E123i(self.e13.0 * rhs.e13i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0) + E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				+ E12oi(self.e1i.0 * rhs.e13o.0)
+				+ E12oi(self.e1o.0 * rhs.e13i.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0)
+				+ E12oi(self.e2i.0 * rhs.e23o.0)
+				+ E12oi(self.e2o.0 * rhs.e23i.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0),
+			e13oi: E13oi(self.e123i.0 * rhs.e13o.0)
+				+ E13oi(self.e123o.0 * rhs.e13i.0)
+				+ E13oi(self.e12oi.0 * rhs.e1oi.0)
+				+ E13oi(self.e13.0 * rhs.e123.0)
+				- E13oi(self.e1i.0 * rhs.e12o.0)
+				- E13oi(self.e1o.0 * rhs.e12i.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.e3o.0 * rhs.e23i.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0),
+			e23oi: E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0)
+				+ E23oi(self.e23.0 * rhs.e123.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e2o.0 * rhs.e12i.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.e3o.0 * rhs.e13i.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP Plane:
+
+// Versor.geometric(Plane) -> Versor
+impl Geometric<Plane> for Versor {
+	type Output = Versor;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23oi.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23.0 * rhs.e123i.0) - E1i(self.e23oi.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13oi.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i  : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e13oi.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) + Eoi(self.e123o.0 * rhs.e123i.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) - E12oi(self.e3o.0 * rhs.e123i.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.e2o.0 * rhs.e123i.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) - E23oi(self.e1o.0 * rhs.e123i.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0)
+				+ E12(self.e23oi.0 * rhs.e13oi.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0) - E13(self.e2o.0 * rhs.e123i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23oi.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23.0 * rhs.e123i.0) - E1i(self.e23oi.0 * rhs.e123i.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0)
+				+ E23(self.e13oi.0 * rhs.e12oi.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13oi.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e13oi.0 * rhs.e123i.0)
+				- E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.e123i.0 * rhs.e12oi.0)
+				- E3i(self.e12oi.0 * rhs.e123i.0)
+				- E3i(self.e1i.0 * rhs.e13oi.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) + Eoi(self.e123o.0 * rhs.e123i.0)
+				- Eoi(self.e13.0 * rhs.e13oi.0)
+				- Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0)
+				+ E123i(self.s.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) - E12oi(self.e3o.0 * rhs.e123i.0)
+				+ E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0)
+				+ E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) - E23oi(self.e1o.0 * rhs.e123i.0)
+				+ E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Versor {
+	type Output = Versor;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Versor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.e1o.0 * rhs.e123i.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) + E2(self.e2o.0 * rhs.e123i.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.e3o.0 * rhs.e123i.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e123o.0 * rhs.e123i.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o  : E12o(self.e123o.0 * rhs.e12oi.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e13oi.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e23oi.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0)
+				+ E1(self.e1o.0 * rhs.e123i.0)
+				+ E1(self.s.0 * rhs.e23oi.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) + E2(self.e2o.0 * rhs.e123i.0)
+				- E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0)
+				+ E3(self.e3o.0 * rhs.e123i.0)
+				+ E3(self.s.0 * rhs.e12oi.0),
+			eo: Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0),
+			ei: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0)
+				+ Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0)
+				+ E123(self.e123o.0 * rhs.e123i.0)
+				+ E123(self.e13.0 * rhs.e13oi.0)
+				+ E123(self.e23.0 * rhs.e23oi.0),
+			e12o: E12o(self.e123o.0 * rhs.e12oi.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+			e13i: E13i(self.e123i.0 * rhs.e13oi.0) + E13i(self.e13.0 * rhs.e123i.0)
+				- E13i(self.e13oi.0 * rhs.e123i.0)
+				- E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0)
+				+ E1oi(self.e1o.0 * rhs.e123i.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+			e23i: E23i(self.e123i.0 * rhs.e23oi.0) + E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.e23oi.0 * rhs.e123i.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0) + E2oi(self.e2o.0 * rhs.e123i.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0)
+				+ E3oi(self.e3o.0 * rhs.e123i.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Versor.dot(Plane) -> Versor
+impl Dot<Plane> for Versor {
+	type Output = Versor;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : -E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : -E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Versor {
+	type Output = Versor;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(Plane) -> Plane
+impl Wedge<Plane> for Versor {
+	type Output = Plane;
+	fn wedge(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Plane {
+			e123i: self.s.wedge(rhs.e123i),
+			e12oi: self.s.wedge(rhs.e12oi),
+			e13oi: self.s.wedge(rhs.e13oi),
+			e23oi: self.s.wedge(rhs.e23oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Versor {
+	type Output = Plane;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(Plane) -> Multivector
+impl AntiWedge<Plane> for Versor {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) + E1(self.e1o.0 * rhs.e123i.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) + E2(self.e2o.0 * rhs.e123i.0),
+		//     e3    : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) + E3(self.e3o.0 * rhs.e123i.0),
+		//     eo    : Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e123o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.e123o.0 * rhs.e12oi.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e13oi.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : E23o(self.e123o.0 * rhs.e23oi.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.e12.anti_wedge(rhs.e13oi) + self.e13.anti_wedge(rhs.e12oi) + self.e1o.anti_wedge(rhs.e123i),
+			e2: self.e12.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e12oi) + self.e2o.anti_wedge(rhs.e123i),
+			e3: self.e13.anti_wedge(rhs.e23oi) + self.e23.anti_wedge(rhs.e13oi) + self.e3o.anti_wedge(rhs.e123i),
+			eo: self.e1o.anti_wedge(rhs.e23oi) + self.e2o.anti_wedge(rhs.e13oi) + self.e3o.anti_wedge(rhs.e12oi),
+			ei: self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e123o.anti_wedge(rhs.e123i),
+			e12o: self.e123o.anti_wedge(rhs.e12oi),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e123o.anti_wedge(rhs.e13oi),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e123o.anti_wedge(rhs.e23oi),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Versor {
+	type Output = Multivector;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(Plane) -> Versor
+impl LeftContraction<Plane> for Versor {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : -E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : -E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: Default::default(),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Versor.right_contraction(Plane) -> S
+impl RightContraction<Plane> for Versor {
+	type Output = S;
+	fn right_contraction(self, rhs: Plane) -> Self::Output {
+		// -S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Versor.scalar_product(Plane) -> S
+impl ScalarProduct<Plane> for Versor {
+	type Output = S;
+	fn scalar_product(self, rhs: Plane) -> Self::Output {
+		// -S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Versor.commutator(Plane) -> Versor
+impl Commutator<Plane> for Versor {
+	type Output = Versor;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13  : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23oi.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23  : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13oi.0),
+		//     e2i  : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12oi.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi  : Eoi(self.e123o.0 * rhs.e123i.0),
+		//     e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23oi.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13oi.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: Eoi(self.e123o.0 * rhs.e123i.0),
+			e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+// Versor.anti_dot(Plane) -> Multivector
+impl AntiDot<Plane> for Versor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : Default::default(),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o  : E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : -E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : -E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+		//     e23i  : E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: Default::default(),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+			e12o: E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: -E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: -E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+			e23i: E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP Sphere:
+
+// Versor.geometric(Sphere) -> Versor
+impl Geometric<Sphere> for Versor {
+	type Output = Versor;
+	fn geometric(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23oi.0) - E1o(self.e23.0 * rhs.e123o.0) + E1o(self.e23oi.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23.0 * rhs.e123i.0) - E1i(self.e23oi.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0) + E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13oi.0) + E2o(self.e13.0 * rhs.e123o.0) - E2o(self.e13oi.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i  : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e13oi.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0)
+				+ E12(self.e23oi.0 * rhs.e13oi.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0)
+				- E13(self.e23oi.0 * rhs.e12oi.0)
+				- E13(self.e2i.0 * rhs.e123o.0)
+				- E13(self.e2o.0 * rhs.e123i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23oi.0) - E1o(self.e23.0 * rhs.e123o.0) + E1o(self.e23oi.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12oi.0)
+				- E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23.0 * rhs.e123i.0) - E1i(self.e23oi.0 * rhs.e123i.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0)
+				+ E23(self.e13oi.0 * rhs.e12oi.0)
+				+ E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13oi.0) + E2o(self.e13.0 * rhs.e123o.0) - E2o(self.e13oi.0 * rhs.e123o.0)
+				+ E2o(self.e1o.0 * rhs.e12oi.0)
+				- E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e13oi.0 * rhs.e123i.0)
+				- E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e123o.0 * rhs.e12oi.0)
+				+ E3o(self.e12oi.0 * rhs.e123o.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) + E3i(self.e123i.0 * rhs.e12oi.0)
+				- E3i(self.e12oi.0 * rhs.e123i.0)
+				- E3i(self.e1i.0 * rhs.e13oi.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0)
+				- Eoi(self.e13.0 * rhs.e13oi.0)
+				- Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0)
+				+ E123o(self.s.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0)
+				+ E123i(self.s.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0)
+				+ E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0)
+				+ E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0)
+				+ E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Sphere> for Versor {
+	type Output = Versor;
+	fn mul(self, rhs: Sphere) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(Sphere) -> Multivector
+impl AntiGeometric<Sphere> for Versor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0) - Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) - E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e13oi.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e13oi.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0) + E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e23oi.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e23oi.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0)
+				+ E1(self.e1o.0 * rhs.e123i.0)
+				+ E1(self.s.0 * rhs.e23oi.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e2i.0 * rhs.e123o.0)
+				+ E2(self.e2o.0 * rhs.e123i.0)
+				- E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e3i.0 * rhs.e123o.0)
+				+ E3(self.e3o.0 * rhs.e123i.0)
+				+ E3(self.s.0 * rhs.e12oi.0),
+			eo: Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0)
+				- Eo(self.eoi.0 * rhs.e123o.0)
+				- Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0)
+				+ Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) - E123(self.e123i.0 * rhs.e123o.0)
+				+ E123(self.e123o.0 * rhs.e123i.0)
+				+ E123(self.e13.0 * rhs.e13oi.0)
+				+ E123(self.e23.0 * rhs.e23oi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0)
+				+ E12o(self.e1o.0 * rhs.e13oi.0)
+				+ E12o(self.e2o.0 * rhs.e23oi.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: E13o(self.e123o.0 * rhs.e13oi.0)
+				- E13o(self.e13.0 * rhs.e123o.0)
+				- E13o(self.e13oi.0 * rhs.e123o.0)
+				- E13o(self.e1o.0 * rhs.e12oi.0)
+				+ E13o(self.e3o.0 * rhs.e23oi.0),
+			e13i: E13i(self.e123i.0 * rhs.e13oi.0) + E13i(self.e13.0 * rhs.e123i.0)
+				- E13i(self.e13oi.0 * rhs.e123i.0)
+				- E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0)
+				+ E1oi(self.e1i.0 * rhs.e123o.0)
+				+ E1oi(self.e1o.0 * rhs.e123i.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: E23o(self.e123o.0 * rhs.e23oi.0)
+				- E23o(self.e23.0 * rhs.e123o.0)
+				- E23o(self.e23oi.0 * rhs.e123o.0)
+				- E23o(self.e2o.0 * rhs.e12oi.0)
+				- E23o(self.e3o.0 * rhs.e13oi.0),
+			e23i: E23i(self.e123i.0 * rhs.e23oi.0) + E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.e23oi.0 * rhs.e123i.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0)
+				+ E2oi(self.e2i.0 * rhs.e123o.0)
+				+ E2oi(self.e2o.0 * rhs.e123i.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.e3o.0 * rhs.e123i.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Versor.dot(Sphere) -> Versor
+impl Dot<Sphere> for Versor {
+	type Output = Versor;
+	fn dot(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Sphere> for Versor {
+	type Output = Versor;
+	fn bitor(self, rhs: Sphere) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(Sphere) -> Sphere
+impl Wedge<Sphere> for Versor {
+	type Output = Sphere;
+	fn wedge(self, rhs: Sphere) -> Self::Output {
+		// Sphere {
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Sphere {
+			e123o: self.s.wedge(rhs.e123o),
+			e123i: self.s.wedge(rhs.e123i),
+			e12oi: self.s.wedge(rhs.e12oi),
+			e13oi: self.s.wedge(rhs.e13oi),
+			e23oi: self.s.wedge(rhs.e23oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Sphere> for Versor {
+	type Output = Sphere;
+	fn bitxor(self, rhs: Sphere) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(Sphere) -> Multivector
+impl AntiWedge<Sphere> for Versor {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0),
+		//     e3    : E3(self.e13.0 * rhs.e23oi.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0),
+		//     eo    : Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.e12.anti_wedge(rhs.e13oi)
+				+ self.e13.anti_wedge(rhs.e12oi)
+				+ self.e1i.anti_wedge(rhs.e123o)
+				+ self.e1o.anti_wedge(rhs.e123i),
+			e2: self.e12.anti_wedge(rhs.e23oi)
+				+ self.e23.anti_wedge(rhs.e12oi)
+				+ self.e2i.anti_wedge(rhs.e123o)
+				+ self.e2o.anti_wedge(rhs.e123i),
+			e3: self.e13.anti_wedge(rhs.e23oi)
+				+ self.e23.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e123o)
+				+ self.e3o.anti_wedge(rhs.e123i),
+			eo: self.e1o.anti_wedge(rhs.e23oi)
+				+ self.e2o.anti_wedge(rhs.e13oi)
+				+ self.e3o.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123o),
+			ei: self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e123i.anti_wedge(rhs.e123o) + self.e123o.anti_wedge(rhs.e123i),
+			e12o: self.e123o.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123o),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e123o.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123o),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e123o.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123o),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Sphere> for Versor {
+	type Output = Multivector;
+	fn bitand(self, rhs: Sphere) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(Sphere) -> Versor
+impl LeftContraction<Sphere> for Versor {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+		//     e1o  : -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				- S(self.e23oi.0 * rhs.e23oi.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Versor.right_contraction(Sphere) -> S
+impl RightContraction<Sphere> for Versor {
+	type Output = S;
+	fn right_contraction(self, rhs: Sphere) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Versor.scalar_product(Sphere) -> S
+impl ScalarProduct<Sphere> for Versor {
+	type Output = S;
+	fn scalar_product(self, rhs: Sphere) -> Self::Output {
+		// -S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13oi.0 * rhs.e13oi.0) - S(self.e23oi.0 * rhs.e23oi.0)
+		-S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+	}
+}
+
+// Versor.commutator(Sphere) -> Versor
+impl Commutator<Sphere> for Versor {
+	type Output = Versor;
+	fn commutator(self, rhs: Sphere) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+		//     e13  : E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+		//     e1i  : E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+		//     e23  : -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0),
+		//     e2i  : -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+		//     eoi  : -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0),
+		//     e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e23oi.0 * rhs.e13oi.0),
+			e13: E13(self.e12oi.0 * rhs.e23oi.0) - E13(self.e23oi.0 * rhs.e12oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e23oi.0 * rhs.e123o.0),
+			e1i: E1i(self.e123i.0 * rhs.e23oi.0) - E1i(self.e23oi.0 * rhs.e123i.0),
+			e23: -E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13oi.0 * rhs.e12oi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0),
+			e2i: -E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0),
+			e123o: E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: -E12oi(self.e13.0 * rhs.e23oi.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+// Versor.anti_dot(Sphere) -> Multivector
+impl AntiDot<Sphere> for Versor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Sphere) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+		//     e13o  : -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+		//     e13i  : E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+		//     e1oi  : E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+		//     e23o  : -E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+		//     e23i  : E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+		//     e2oi  : E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+		//     e3oi  : E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) + E123oi(self.e23oi.0 * rhs.e23oi.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e23.0 * rhs.e23oi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e2o.0 * rhs.e23oi.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e2i.0 * rhs.e23oi.0),
+			e13o: -E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e3o.0 * rhs.e23oi.0),
+			e13i: E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e3i.0 * rhs.e23oi.0),
+			e1oi: E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.eoi.0 * rhs.e23oi.0),
+			e23o: -E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0),
+			e23i: E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0),
+			e2oi: E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0),
+			e3oi: E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP Rotor:
+
+// Versor.geometric(Rotor) -> Versor
+impl Geometric<Rotor> for Versor {
+	type Output = Versor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) + E1o(self.e1o.0 * rhs.s.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0) + E123o(self.e1o.0 * rhs.e23.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0) + E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e23oi.0 * rhs.e12.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e23oi.0 * rhs.s.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0) - E12(self.e13.0 * rhs.e23.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e23.0 * rhs.e12.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) + E1o(self.e1o.0 * rhs.s.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e3o.0 * rhs.e13.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e1i.0 * rhs.s.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				- E1i(self.e3i.0 * rhs.e13.0),
+			e23: -E23(self.e12.0 * rhs.e13.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e2o.0 * rhs.s.0)
+				- E2o(self.e3o.0 * rhs.e23.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e2i.0 * rhs.s.0)
+				- E2i(self.e3i.0 * rhs.e23.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0)
+				+ E3o(self.e1o.0 * rhs.e13.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0)
+				+ E3i(self.e1i.0 * rhs.e13.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				+ E3i(self.e3i.0 * rhs.s.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0) + E123o(self.e1o.0 * rhs.e23.0) - E123o(self.e2o.0 * rhs.e13.0)
+				+ E123o(self.e3o.0 * rhs.e12.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0) + E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e3i.0 * rhs.e12.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0)
+				+ E12oi(self.eoi.0 * rhs.e12.0),
+			e13oi: E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e23oi.0 * rhs.e12.0)
+				+ E13oi(self.eoi.0 * rhs.e13.0),
+			e23oi: -E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0)
+				+ E23oi(self.e23oi.0 * rhs.s.0)
+				+ E23oi(self.eoi.0 * rhs.e23.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Versor {
+	type Output = Versor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(Rotor) -> Multivector
+impl AntiGeometric<Rotor> for Versor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0) + E1(self.e23oi.0 * rhs.s.0) + E1(self.eoi.0 * rhs.e23.0),
+		//     e2    : E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e23oi.0 * rhs.e12.0) - E2(self.eoi.0 * rhs.e13.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0) + E3(self.eoi.0 * rhs.e12.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0) - Eo(self.e1o.0 * rhs.e23.0) + Eo(self.e2o.0 * rhs.e13.0) - Eo(self.e3o.0 * rhs.e12.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0) + Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0) + Ei(self.e3i.0 * rhs.e12.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0) - E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0) - E12o(self.e1o.0 * rhs.e13.0) - E12o(self.e2o.0 * rhs.e23.0) + E12o(self.e3o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0) - E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0) + E13o(self.e1o.0 * rhs.e12.0) - E13o(self.e2o.0 * rhs.s.0) - E13o(self.e3o.0 * rhs.e23.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e1i.0 * rhs.e12.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0) + E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e1o.0 * rhs.s.0) + E23o(self.e2o.0 * rhs.e12.0) + E23o(self.e3o.0 * rhs.e13.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e1i.0 * rhs.s.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e3i.0 * rhs.e13.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) - E2oi(self.e13.0 * rhs.s.0) - E2oi(self.e23.0 * rhs.e12.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0)
+				+ E1(self.e23oi.0 * rhs.s.0)
+				+ E1(self.eoi.0 * rhs.e23.0),
+			e2: E2(self.e12oi.0 * rhs.e23.0)
+				- E2(self.e13oi.0 * rhs.s.0)
+				- E2(self.e23oi.0 * rhs.e12.0)
+				- E2(self.eoi.0 * rhs.e13.0),
+			e3: E3(self.e12oi.0 * rhs.s.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0)
+				+ E3(self.eoi.0 * rhs.e12.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0) - Eo(self.e1o.0 * rhs.e23.0) + Eo(self.e2o.0 * rhs.e13.0)
+				- Eo(self.e3o.0 * rhs.e12.0),
+			ei: Ei(self.e123i.0 * rhs.s.0) + Ei(self.e1i.0 * rhs.e23.0) - Ei(self.e2i.0 * rhs.e13.0)
+				+ Ei(self.e3i.0 * rhs.e12.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0)
+				- E123(self.eoi.0 * rhs.s.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0) - E12o(self.e1o.0 * rhs.e13.0) - E12o(self.e2o.0 * rhs.e23.0)
+				+ E12o(self.e3o.0 * rhs.s.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e2i.0 * rhs.e23.0)
+				- E12i(self.e3i.0 * rhs.s.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0) + E13o(self.e1o.0 * rhs.e12.0)
+				- E13o(self.e2o.0 * rhs.s.0)
+				- E13o(self.e3o.0 * rhs.e23.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e1i.0 * rhs.e12.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0) - E1oi(self.e13.0 * rhs.e12.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0)
+				+ E23o(self.e1o.0 * rhs.s.0)
+				+ E23o(self.e2o.0 * rhs.e12.0)
+				+ E23o(self.e3o.0 * rhs.e13.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e3i.0 * rhs.e13.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0)
+				- E2oi(self.e13.0 * rhs.s.0)
+				- E2oi(self.e23.0 * rhs.e12.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e13.0 * rhs.e23.0) - E3oi(self.e23.0 * rhs.e13.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Versor.dot(Rotor) -> Versor
+impl Dot<Rotor> for Versor {
+	type Output = Versor;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) + E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) + E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) + E1o(self.e1o.0 * rhs.s.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e2o.0 * rhs.s.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) + E2i(self.e2i.0 * rhs.s.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e3i.0 * rhs.s.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Versor {
+	type Output = Versor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(Rotor) -> Versor
+impl Wedge<Rotor> for Versor {
+	type Output = Versor;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0) + E123o(self.e1o.0 * rhs.e23.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0) + E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0) + E12oi(self.eoi.0 * rhs.e12.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0) + E13oi(self.eoi.0 * rhs.e13.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0) + E23oi(self.eoi.0 * rhs.e23.0),
+		// }
+		Versor {
+			s: self.s.wedge(rhs.s),
+			e12: self.e12.wedge(rhs.s) + self.s.wedge(rhs.e12),
+			e13: self.e13.wedge(rhs.s) + self.s.wedge(rhs.e13),
+			e1o: self.e1o.wedge(rhs.s),
+			e1i: self.e1i.wedge(rhs.s),
+			e23: self.e23.wedge(rhs.s) + self.s.wedge(rhs.e23),
+			e2o: self.e2o.wedge(rhs.s),
+			e2i: self.e2i.wedge(rhs.s),
+			e3o: self.e3o.wedge(rhs.s),
+			e3i: self.e3i.wedge(rhs.s),
+			eoi: self.eoi.wedge(rhs.s),
+			e123o: self.e123o.wedge(rhs.s)
+				+ self.e1o.wedge(rhs.e23)
+				+ self.e2o.wedge(rhs.e13)
+				+ self.e3o.wedge(rhs.e12),
+			e123i: self.e123i.wedge(rhs.s)
+				+ self.e1i.wedge(rhs.e23)
+				+ self.e2i.wedge(rhs.e13)
+				+ self.e3i.wedge(rhs.e12),
+			e12oi: self.e12oi.wedge(rhs.s) + self.eoi.wedge(rhs.e12),
+			e13oi: self.e13oi.wedge(rhs.s) + self.eoi.wedge(rhs.e13),
+			e23oi: self.e23oi.wedge(rhs.s) + self.eoi.wedge(rhs.e23),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Versor {
+	type Output = Versor;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(Rotor) -> Vec3
+impl AntiWedge<Rotor> for Versor {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13oi.0 * rhs.e12.0),
+		//     y: E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23oi.0 * rhs.e12.0),
+		//     z: E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23oi.0 * rhs.e13.0),
+		// }
+		Vec3 {
+			x: self.e12oi.anti_wedge(rhs.e13) + self.e13oi.anti_wedge(rhs.e12),
+			y: self.e12oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e12),
+			z: self.e13oi.anti_wedge(rhs.e23) + self.e23oi.anti_wedge(rhs.e13),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Versor {
+	type Output = Vec3;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(Rotor) -> Rotor
+impl LeftContraction<Rotor> for Versor {
+	type Output = Rotor;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s  : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12: E12(self.s.0 * rhs.e12.0),
+		//     e13: E13(self.s.0 * rhs.e13.0),
+		//     e23: E23(self.s.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.s.0 * rhs.e13.0),
+			e23: E23(self.s.0 * rhs.e23.0),
+		}
+	}
+}
+
+// Versor.right_contraction(Rotor) -> Versor
+impl RightContraction<Rotor> for Versor {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) + E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) + E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0),
+			e13: E13(self.e13.0 * rhs.s.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) + E1o(self.e1o.0 * rhs.s.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e23.0 * rhs.s.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e2o.0 * rhs.s.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) + E2i(self.e2i.0 * rhs.s.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e3i.0 * rhs.s.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+// Versor.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Versor {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+		-S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Versor.commutator(Rotor) -> Versor
+impl Commutator<Rotor> for Versor {
+	type Output = Versor;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+		//     e1o  : -E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0),
+		//     e1i  : -E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+		//     e2o  : E2o(self.e1o.0 * rhs.e12.0) - E2o(self.e3o.0 * rhs.e23.0),
+		//     e2i  : E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e3i.0 * rhs.e23.0),
+		//     e3o  : E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e2o.0 * rhs.e23.0),
+		//     e3i  : E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0),
+		//     eoi  : Default::default(),
+		//     e123o: Default::default(),
+		//     e123i: Default::default(),
+		//     e12oi: -E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+		//     e13oi: E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+		//     e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13.0 * rhs.e23.0) + E12(self.e23.0 * rhs.e13.0),
+			e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e23.0 * rhs.e12.0),
+			e1o: -E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0),
+			e1i: -E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e13.0 * rhs.e12.0),
+			e2o: E2o(self.e1o.0 * rhs.e12.0) - E2o(self.e3o.0 * rhs.e23.0),
+			e2i: E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e3i.0 * rhs.e23.0),
+			e3o: E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e2o.0 * rhs.e23.0),
+			e3i: E3i(self.e1i.0 * rhs.e13.0) + E3i(self.e2i.0 * rhs.e23.0),
+			eoi: Default::default(),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: -E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23oi.0 * rhs.e13.0),
+			e13oi: E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23oi.0 * rhs.e12.0),
+			e23oi: -E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13oi.0 * rhs.e12.0),
+		}
+	}
+}
+
+// Versor.anti_dot(Rotor) -> Multivector
+impl AntiDot<Rotor> for Versor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0) - E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e3o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12.0) - E12i(self.e3i.0 * rhs.s.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e2o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e2i.0 * rhs.s.0),
+		//     e1oi  : E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e1o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e1i.0 * rhs.s.0),
+		//     e2oi  : -E2oi(self.e13.0 * rhs.s.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23oi.0 * rhs.e23.0)
+				- E123(self.eoi.0 * rhs.s.0),
+			e12o: -E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e3o.0 * rhs.s.0),
+			e12i: E12i(self.e123i.0 * rhs.e12.0) - E12i(self.e3i.0 * rhs.s.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e2o.0 * rhs.s.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e2i.0 * rhs.s.0),
+			e1oi: E1oi(self.e23.0 * rhs.s.0) + E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e1o.0 * rhs.s.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e1i.0 * rhs.s.0),
+			e2oi: -E2oi(self.e13.0 * rhs.s.0) - E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e23.0 * rhs.e23.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP Translator:
+
+// Versor.geometric(Translator) -> Versor
+impl Geometric<Translator> for Versor {
+	type Output = Versor;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13  : -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.e1i.0 * rhs.s.0) + E1i(self.eoi.0 * rhs.e1i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.eoi.0 * rhs.e2i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.eoi.0 * rhs.e3i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: -E12oi(self.e123o.0 * rhs.e3i.0) + E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e2o.0 * rhs.e1i.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e1o.0 * rhs.e3i.0) + E13oi(self.e3o.0 * rhs.e1i.0),
+		//     e23oi: -E23oi(self.e123o.0 * rhs.e1i.0) + E23oi(self.e23oi.0 * rhs.s.0) - E23oi(self.e2o.0 * rhs.e3i.0) + E23oi(self.e3o.0 * rhs.e2i.0),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e1o.0 * rhs.e2i.0)
+				- E12(self.e2o.0 * rhs.e1i.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13.0 * rhs.e3i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				+ E1i(self.e1i.0 * rhs.s.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3o.0 * rhs.e2i.0),
+			e2o: E2o(self.e2o.0 * rhs.s.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) - E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0)
+				- E3i(self.e13oi.0 * rhs.e1i.0)
+				- E3i(self.e23.0 * rhs.e2i.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13.0 * rhs.e2i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e23.0 * rhs.e1i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: -E12oi(self.e123o.0 * rhs.e3i.0) + E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e1o.0 * rhs.e2i.0)
+				+ E12oi(self.e2o.0 * rhs.e1i.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e1o.0 * rhs.e3i.0)
+				+ E13oi(self.e3o.0 * rhs.e1i.0),
+			e23oi: -E23oi(self.e123o.0 * rhs.e1i.0) + E23oi(self.e23oi.0 * rhs.s.0) - E23oi(self.e2o.0 * rhs.e3i.0)
+				+ E23oi(self.e3o.0 * rhs.e2i.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Versor {
+	type Output = Versor;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(Translator) -> Multivector
+impl AntiGeometric<Translator> for Versor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e123o.0 * rhs.e1i.0) + E1(self.e23oi.0 * rhs.s.0) - E1(self.e2o.0 * rhs.e3i.0) + E1(self.e3o.0 * rhs.e2i.0),
+		//     e2    : E2(self.e123o.0 * rhs.e2i.0) - E2(self.e13oi.0 * rhs.s.0) + E2(self.e1o.0 * rhs.e3i.0) - E2(self.e3o.0 * rhs.e1i.0),
+		//     e3    : E3(self.e123o.0 * rhs.e3i.0) + E3(self.e12oi.0 * rhs.s.0) - E3(self.e1o.0 * rhs.e2i.0) + E3(self.e2o.0 * rhs.e1i.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e13oi.0 * rhs.e2i.0) + Ei(self.e23.0 * rhs.e1i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e1o.0 * rhs.e1i.0) - E123(self.e2o.0 * rhs.e2i.0) - E123(self.e3o.0 * rhs.e3i.0) - E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : E12o(self.e3o.0 * rhs.s.0),
+		//     e12i  : -E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e13oi.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0) + E12i(self.e23oi.0 * rhs.e2i.0) - E12i(self.e3i.0 * rhs.s.0) + E12i(self.eoi.0 * rhs.e3i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e2o.0 * rhs.s.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e12oi.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.s.0) - E13i(self.eoi.0 * rhs.e2i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23.0 * rhs.s.0) - E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3o.0 * rhs.e2i.0),
+		//     e23o  : E23o(self.e1o.0 * rhs.s.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) - E23i(self.e12oi.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.s.0) + E23i(self.eoi.0 * rhs.e1i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13.0 * rhs.s.0) + E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e3o.0 * rhs.e1i.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e123o.0 * rhs.e3i.0) - E3oi(self.e1o.0 * rhs.e2i.0) + E3oi(self.e2o.0 * rhs.e1i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1o.0 * rhs.e1i.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e123o.0 * rhs.e1i.0) + E1(self.e23oi.0 * rhs.s.0) - E1(self.e2o.0 * rhs.e3i.0)
+				+ E1(self.e3o.0 * rhs.e2i.0),
+			e2: E2(self.e123o.0 * rhs.e2i.0) - E2(self.e13oi.0 * rhs.s.0) + E2(self.e1o.0 * rhs.e3i.0)
+				- E2(self.e3o.0 * rhs.e1i.0),
+			e3: E3(self.e123o.0 * rhs.e3i.0) + E3(self.e12oi.0 * rhs.s.0) - E3(self.e1o.0 * rhs.e2i.0)
+				+ E3(self.e2o.0 * rhs.e1i.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) + Ei(self.e123i.0 * rhs.s.0)
+				- Ei(self.e12oi.0 * rhs.e3i.0)
+				- Ei(self.e13.0 * rhs.e2i.0)
+				+ Ei(self.e13oi.0 * rhs.e2i.0)
+				+ Ei(self.e23.0 * rhs.e1i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.e1o.0 * rhs.e1i.0)
+				- E123(self.e2o.0 * rhs.e2i.0)
+				- E123(self.e3o.0 * rhs.e3i.0)
+				- E123(self.eoi.0 * rhs.s.0),
+			e12o: E12o(self.e3o.0 * rhs.s.0),
+			e12i: -E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e13oi.0 * rhs.e1i.0) - E12i(self.e23.0 * rhs.e2i.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e2o.0 * rhs.s.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) - E13i(self.e12oi.0 * rhs.e1i.0) - E13i(self.e23.0 * rhs.e3i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				- E13i(self.eoi.0 * rhs.e2i.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23.0 * rhs.s.0) - E1oi(self.e2o.0 * rhs.e3i.0)
+				+ E1oi(self.e3o.0 * rhs.e2i.0),
+			e23o: E23o(self.e1o.0 * rhs.s.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) - E23i(self.e12oi.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13.0 * rhs.s.0) + E2oi(self.e1o.0 * rhs.e3i.0)
+				- E2oi(self.e3o.0 * rhs.e1i.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e123o.0 * rhs.e3i.0) - E3oi(self.e1o.0 * rhs.e2i.0)
+				+ E3oi(self.e2o.0 * rhs.e1i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1o.0 * rhs.e1i.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Versor.dot(Translator) -> Versor
+impl Dot<Translator> for Versor {
+	type Output = Versor;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.e123o.0 * rhs.e3i.0),
+		//     e13  : -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0) + E12(self.e123o.0 * rhs.e3i.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13.0 * rhs.s.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				+ E1i(self.e1i.0 * rhs.s.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23.0 * rhs.s.0),
+			e2o: E2o(self.e2o.0 * rhs.s.0),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Versor {
+	type Output = Versor;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(Translator) -> Versor
+impl Wedge<Translator> for Versor {
+	type Output = Versor;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) + E123i(self.e123i.0 * rhs.s.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e23.0 * rhs.e1i.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e2o.0 * rhs.e1i.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0) - E13oi(self.e1o.0 * rhs.e3i.0) + E13oi(self.e3o.0 * rhs.e1i.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0) - E23oi(self.e2o.0 * rhs.e3i.0) + E23oi(self.e3o.0 * rhs.e2i.0),
+		// }
+		Versor {
+			s: self.s.wedge(rhs.s),
+			e12: self.e12.wedge(rhs.s),
+			e13: self.e13.wedge(rhs.s),
+			e1o: self.e1o.wedge(rhs.s),
+			e1i: self.e1i.wedge(rhs.s) + self.s.wedge(rhs.e1i),
+			e23: self.e23.wedge(rhs.s),
+			e2o: self.e2o.wedge(rhs.s),
+			e2i: self.e2i.wedge(rhs.s) + self.s.wedge(rhs.e2i),
+			e3o: self.e3o.wedge(rhs.s),
+			e3i: self.e3i.wedge(rhs.s) + self.s.wedge(rhs.e3i),
+			eoi: self.eoi.wedge(rhs.s),
+			e123o: self.e123o.wedge(rhs.s),
+			e123i: self.e12.wedge(rhs.e3i)
+				+ self.e123i.wedge(rhs.s)
+				+ self.e13.wedge(rhs.e2i)
+				+ self.e23.wedge(rhs.e1i),
+			e12oi: self.e12oi.wedge(rhs.s) + self.e1o.wedge(rhs.e2i) + self.e2o.wedge(rhs.e1i),
+			e13oi: self.e13oi.wedge(rhs.s) + self.e1o.wedge(rhs.e3i) + self.e3o.wedge(rhs.e1i),
+			e23oi: self.e23oi.wedge(rhs.s) + self.e2o.wedge(rhs.e3i) + self.e3o.wedge(rhs.e2i),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Versor {
+	type Output = Versor;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(Translator) -> Point
+impl AntiWedge<Translator> for Versor {
+	type Output = Point;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// Point {
+		//     x  : E1(self.e123o.0 * rhs.e1i.0),
+		//     y  : E2(self.e123o.0 * rhs.e2i.0),
+		//     z  : E3(self.e123o.0 * rhs.e3i.0),
+		//     o  : Default::default(),
+		//     inf: -Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) - Ei(self.e23oi.0 * rhs.e1i.0),
+		// }
+		Point {
+			x: self.e123o.anti_wedge(rhs.e1i),
+			y: self.e123o.anti_wedge(rhs.e2i),
+			z: self.e123o.anti_wedge(rhs.e3i),
+			o: Default::default(),
+			inf: self.e12oi.anti_wedge(rhs.e3i) + self.e13oi.anti_wedge(rhs.e2i) + self.e23oi.anti_wedge(rhs.e1i),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Versor {
+	type Output = Point;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(Translator) -> Translator
+impl LeftContraction<Translator> for Versor {
+	type Output = Translator;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s  : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.s.0 * rhs.s.0),
+		//     e1i: E1i(self.s.0 * rhs.e1i.0),
+		//     e2i: E2i(self.s.0 * rhs.e2i.0),
+		//     e3i: E3i(self.s.0 * rhs.e3i.0),
+		// }
+		Translator {
+			s: S(self.e1o.0 * rhs.e1i.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1i: E1i(self.s.0 * rhs.e1i.0),
+			e2i: E2i(self.s.0 * rhs.e2i.0),
+			e3i: E3i(self.s.0 * rhs.e3i.0),
+		}
+	}
+}
+
+// Versor.right_contraction(Translator) -> Versor
+impl RightContraction<Translator> for Versor {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.e123o.0 * rhs.e3i.0),
+		//     e13  : -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13.0 * rhs.s.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23.0 * rhs.s.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: S(self.e1o.0 * rhs.e1i.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0) + E12(self.e123o.0 * rhs.e3i.0),
+			e13: -E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13.0 * rhs.s.0),
+			e1o: E1o(self.e1o.0 * rhs.s.0),
+			e1i: E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23.0 * rhs.s.0),
+			e2o: E2o(self.e2o.0 * rhs.s.0),
+			e2i: -E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.e2i.0 * rhs.s.0),
+			e3o: E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.e3i.0 * rhs.s.0),
+			eoi: Eoi(self.eoi.0 * rhs.s.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+// Versor.scalar_product(Translator) -> S
+impl ScalarProduct<Translator> for Versor {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator) -> Self::Output {
+		// S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.s.0 * rhs.s.0)
+		S(self.e1o.0 * rhs.e1i.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Versor.commutator(Translator) -> Versor
+impl Commutator<Translator> for Versor {
+	type Output = Versor;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13  : E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o  : Default::default(),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o  : Default::default(),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : Default::default(),
+		//     e3i  : -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		//     e123o: Default::default(),
+		//     e123i: E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+		//     e12oi: -E12oi(self.e123o.0 * rhs.e3i.0),
+		//     e13oi: E13oi(self.e123o.0 * rhs.e2i.0),
+		//     e23oi: -E23oi(self.e123o.0 * rhs.e1i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: E12(self.e1o.0 * rhs.e2i.0) - E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e1o.0 * rhs.e3i.0) - E13(self.e3o.0 * rhs.e1i.0),
+			e1o: Default::default(),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.eoi.0 * rhs.e1i.0),
+			e23: E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3o.0 * rhs.e2i.0),
+			e2o: Default::default(),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: Default::default(),
+			e3i: -E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e2o.0 * rhs.e2i.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+			e123o: Default::default(),
+			e123i: E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e23oi.0 * rhs.e1i.0),
+			e12oi: -E12oi(self.e123o.0 * rhs.e3i.0),
+			e13oi: E13oi(self.e123o.0 * rhs.e2i.0),
+			e23oi: -E23oi(self.e123o.0 * rhs.e1i.0),
+		}
+	}
+}
+
+// Versor.anti_dot(Translator) -> Multivector
+impl AntiDot<Translator> for Versor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.eoi.0 * rhs.s.0),
+		//     e12o  : E12o(self.e3o.0 * rhs.s.0),
+		//     e12i  : E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0) - E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e2o.0 * rhs.s.0),
+		//     e13i  : -E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23.0 * rhs.s.0),
+		//     e23o  : E23o(self.e1o.0 * rhs.s.0),
+		//     e23i  : -E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.s.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13.0 * rhs.s.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e123o.0 * rhs.e3i.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: -E123oi(self.e1o.0 * rhs.e1i.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0),
+			e3: E3(self.e12oi.0 * rhs.s.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0),
+			ei: Ei(self.e123i.0 * rhs.s.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: -E123(self.eoi.0 * rhs.s.0),
+			e12o: E12o(self.e3o.0 * rhs.s.0),
+			e12i: E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e23oi.0 * rhs.e2i.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e2o.0 * rhs.s.0),
+			e13i: -E13i(self.e12oi.0 * rhs.e1i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e23.0 * rhs.s.0),
+			e23o: E23o(self.e1o.0 * rhs.s.0),
+			e23i: -E23i(self.e12oi.0 * rhs.e2i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13.0 * rhs.s.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e123o.0 * rhs.e3i.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: -E123oi(self.e1o.0 * rhs.e1i.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP Versor:
+
+// Versor.geometric(Versor) -> Versor
+impl Geometric<Versor> for Versor {
+	type Output = Versor;
+	fn geometric(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13.0 * rhs.e13.0) - S(self.e13oi.0 * rhs.e13oi.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) - S(self.e23oi.0 * rhs.e23oi.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0) - E12(self.e13.0 * rhs.e23.0) - E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) + E12(self.e23oi.0 * rhs.e13oi.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) - E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e12oi.0 * rhs.e23oi.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e13oi.0 * rhs.eoi.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e23oi.0 * rhs.e12oi.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0) + E13(self.eoi.0 * rhs.e13oi.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.e12.0 * rhs.e2o.0) - E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e123o.0 * rhs.e23oi.0) - E1o(self.e12oi.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) + E1o(self.e1o.0 * rhs.s.0) - E1o(self.e23.0 * rhs.e123o.0) + E1o(self.e23oi.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.e3o.0 * rhs.e13oi.0) - E1o(self.eoi.0 * rhs.e1o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) - E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e123i.0 * rhs.e23oi.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.e13oi.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e23.0 * rhs.e123i.0) - E1i(self.e23oi.0 * rhs.e123i.0) - E1i(self.e2i.0 * rhs.e12.0) + E1i(self.e2i.0 * rhs.e12oi.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.eoi.0 * rhs.e1i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) + E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) - E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e13oi.0 * rhs.e12oi.0) + E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.e23oi.0 * rhs.eoi.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0) + E23(self.eoi.0 * rhs.e23oi.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e123o.0 * rhs.e13oi.0) + E2o(self.e12oi.0 * rhs.e1o.0) + E2o(self.e13.0 * rhs.e123o.0) - E2o(self.e13oi.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e1o.0 * rhs.e12oi.0) + E2o(self.e23.0 * rhs.e3o.0) - E2o(self.e23oi.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.e3o.0 * rhs.e23oi.0) - E2o(self.eoi.0 * rhs.e2o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e123i.0 * rhs.e13oi.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e13oi.0 * rhs.e123i.0) + E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.e23oi.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.eoi.0 * rhs.e2i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e123o.0 * rhs.e12.0) - E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e1o.0 * rhs.e13oi.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e23oi.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e2o.0 * rhs.e23oi.0) + E3o(self.e3o.0 * rhs.eoi.0) + E3o(self.e3o.0 * rhs.s.0) - E3o(self.eoi.0 * rhs.e3o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e13oi.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e2i.0 * rhs.e23oi.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.eoi.0 * rhs.e3i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0) - Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e13oi.0 * rhs.e13.0) + Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e23.0 * rhs.e23oi.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0) + Eoi(self.eoi.0 * rhs.s.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) + E123o(self.e123o.0 * rhs.eoi.0) + E123o(self.e123o.0 * rhs.s.0) - E123o(self.e12oi.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e13oi.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e1o.0 * rhs.e23oi.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e23oi.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e23.0 * rhs.e1i.0) + E123i(self.e23oi.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e2i.0 * rhs.e13oi.0) + E123i(self.e3i.0 * rhs.e12.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) + E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13.0 * rhs.e23oi.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e23oi.0 * rhs.e13.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0) + E12oi(self.eoi.0 * rhs.e12.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e13oi.0 * rhs.s.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e23oi.0 * rhs.e12.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0) + E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e23oi.0 * rhs.s.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0)
+				- S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13.0 * rhs.e13.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				- S(self.e23oi.0 * rhs.e23oi.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0)
+				+ E12(self.e123i.0 * rhs.e3o.0)
+				+ E12(self.e123o.0 * rhs.e3i.0)
+				+ E12(self.e12oi.0 * rhs.eoi.0)
+				- E12(self.e13.0 * rhs.e23.0)
+				- E12(self.e13oi.0 * rhs.e23oi.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				+ E12(self.e23oi.0 * rhs.e13oi.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0)
+				+ E13(self.e12oi.0 * rhs.e23oi.0)
+				+ E13(self.e13.0 * rhs.s.0)
+				+ E13(self.e13oi.0 * rhs.eoi.0)
+				+ E13(self.e1i.0 * rhs.e3o.0)
+				+ E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e23oi.0 * rhs.e12oi.0)
+				- E13(self.e2i.0 * rhs.e123o.0)
+				- E13(self.e2o.0 * rhs.e123i.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0)
+				- E1o(self.e123o.0 * rhs.e23.0)
+				- E1o(self.e123o.0 * rhs.e23oi.0)
+				- E1o(self.e12oi.0 * rhs.e2o.0)
+				+ E1o(self.e13.0 * rhs.e3o.0)
+				- E1o(self.e13oi.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.eoi.0)
+				+ E1o(self.e1o.0 * rhs.s.0)
+				- E1o(self.e23.0 * rhs.e123o.0)
+				+ E1o(self.e23oi.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e2o.0 * rhs.e12oi.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.e3o.0 * rhs.e13oi.0)
+				- E1o(self.eoi.0 * rhs.e1o.0)
+				+ E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) - E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e123i.0 * rhs.e23oi.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13.0 * rhs.e3i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				+ E1i(self.e1i.0 * rhs.s.0)
+				- E1i(self.e23.0 * rhs.e123i.0)
+				- E1i(self.e23oi.0 * rhs.e123i.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0)
+				- E23(self.e12oi.0 * rhs.e13oi.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e13oi.0 * rhs.e12oi.0)
+				+ E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e123o.0 * rhs.e13.0)
+				+ E2o(self.e123o.0 * rhs.e13oi.0)
+				+ E2o(self.e12oi.0 * rhs.e1o.0)
+				+ E2o(self.e13.0 * rhs.e123o.0)
+				- E2o(self.e13oi.0 * rhs.e123o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e1o.0 * rhs.e12oi.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				- E2o(self.e23oi.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				+ E2o(self.e2o.0 * rhs.s.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.e3o.0 * rhs.e23oi.0)
+				- E2o(self.eoi.0 * rhs.e2o.0)
+				+ E2o(self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e123i.0 * rhs.e13.0)
+				- E2i(self.e123i.0 * rhs.e13oi.0)
+				- E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e13.0 * rhs.e123i.0)
+				+ E2i(self.e13oi.0 * rhs.e123i.0)
+				+ E2i(self.e1i.0 * rhs.e12.0)
+				- E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e123o.0 * rhs.e12.0) - E3o(self.e123o.0 * rhs.e12oi.0)
+				+ E3o(self.e12oi.0 * rhs.e123o.0)
+				- E3o(self.e13.0 * rhs.e1o.0)
+				+ E3o(self.e13oi.0 * rhs.e1o.0)
+				+ E3o(self.e1o.0 * rhs.e13.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				- E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e23oi.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				+ E3o(self.e3o.0 * rhs.s.0)
+				- E3o(self.eoi.0 * rhs.e3o.0)
+				+ E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0)
+				- E3i(self.e12oi.0 * rhs.e123i.0)
+				- E3i(self.e13.0 * rhs.e1i.0)
+				- E3i(self.e13oi.0 * rhs.e1i.0)
+				+ E3i(self.e1i.0 * rhs.e13.0)
+				- E3i(self.e1i.0 * rhs.e13oi.0)
+				- E3i(self.e23.0 * rhs.e2i.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0)
+				- Eoi(self.e12oi.0 * rhs.e12.0)
+				- Eoi(self.e13.0 * rhs.e13oi.0)
+				- Eoi(self.e13oi.0 * rhs.e13.0)
+				+ Eoi(self.e1i.0 * rhs.e1o.0)
+				- Eoi(self.e1o.0 * rhs.e1i.0)
+				- Eoi(self.e23.0 * rhs.e23oi.0)
+				- Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0)
+				+ Eoi(self.eoi.0 * rhs.s.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.e12.0 * rhs.e3o.0) + E123o(self.e123o.0 * rhs.eoi.0) + E123o(self.e123o.0 * rhs.s.0)
+				- E123o(self.e12oi.0 * rhs.e3o.0)
+				- E123o(self.e13.0 * rhs.e2o.0)
+				+ E123o(self.e13oi.0 * rhs.e2o.0)
+				+ E123o(self.e1o.0 * rhs.e23.0)
+				+ E123o(self.e1o.0 * rhs.e23oi.0)
+				+ E123o(self.e23.0 * rhs.e1o.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0)
+				- E123o(self.e2o.0 * rhs.e13.0)
+				- E123o(self.e2o.0 * rhs.e13oi.0)
+				+ E123o(self.e3o.0 * rhs.e12.0)
+				+ E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0)
+				+ E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e123i.0 * rhs.eoi.0)
+				+ E123i(self.e123i.0 * rhs.s.0)
+				+ E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13.0 * rhs.e2i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e1i.0 * rhs.e23.0)
+				- E123i(self.e1i.0 * rhs.e23oi.0)
+				+ E123i(self.e23.0 * rhs.e1i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0)
+				- E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e2i.0 * rhs.e13oi.0)
+				+ E123i(self.e3i.0 * rhs.e12.0)
+				- E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0)
+				+ E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0)
+				+ E12oi(self.e12oi.0 * rhs.s.0)
+				- E12oi(self.e13.0 * rhs.e23oi.0)
+				- E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e1i.0 * rhs.e2o.0)
+				- E12oi(self.e1o.0 * rhs.e2i.0)
+				+ E12oi(self.e23.0 * rhs.e13oi.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0)
+				- E12oi(self.e2i.0 * rhs.e1o.0)
+				+ E12oi(self.e2o.0 * rhs.e1i.0)
+				+ E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0)
+				+ E12oi(self.eoi.0 * rhs.e12.0)
+				+ E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e123i.0 * rhs.e2o.0)
+				+ E13oi(self.e123o.0 * rhs.e2i.0)
+				+ E13oi(self.e12oi.0 * rhs.e23.0)
+				+ E13oi(self.e13.0 * rhs.eoi.0)
+				+ E13oi(self.e13oi.0 * rhs.s.0)
+				+ E13oi(self.e1i.0 * rhs.e3o.0)
+				- E13oi(self.e1o.0 * rhs.e3i.0)
+				- E13oi(self.e23.0 * rhs.e12oi.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0)
+				- E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0)
+				- E13oi(self.e3i.0 * rhs.e1o.0)
+				+ E13oi(self.e3o.0 * rhs.e1i.0)
+				+ E13oi(self.eoi.0 * rhs.e13.0)
+				+ E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e123i.0 * rhs.e1o.0)
+				- E23oi(self.e123o.0 * rhs.e1i.0)
+				- E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13.0 * rhs.e12oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0)
+				+ E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0)
+				+ E23oi(self.e23.0 * rhs.eoi.0)
+				+ E23oi(self.e23oi.0 * rhs.s.0)
+				+ E23oi(self.e2i.0 * rhs.e3o.0)
+				- E23oi(self.e2o.0 * rhs.e3i.0)
+				- E23oi(self.e3i.0 * rhs.e2o.0)
+				+ E23oi(self.e3o.0 * rhs.e2i.0)
+				+ E23oi(self.eoi.0 * rhs.e23.0)
+				+ E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Versor> for Versor {
+	type Output = Versor;
+	fn mul(self, rhs: Versor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(Versor) -> Multivector
+impl AntiGeometric<Versor> for Versor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e13oi.0 * rhs.e12.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0) + E1(self.e23.0 * rhs.eoi.0) + E1(self.e23oi.0 * rhs.s.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e2o.0 * rhs.e3i.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.e3o.0 * rhs.e2i.0) + E1(self.eoi.0 * rhs.e23.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13.0 * rhs.eoi.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e1i.0 * rhs.e3o.0) + E2(self.e1o.0 * rhs.e3i.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e23oi.0 * rhs.e12.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.e3o.0 * rhs.e1i.0) - E2(self.eoi.0 * rhs.e13.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0) - E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e12oi.0 * rhs.s.0) + E3(self.e13.0 * rhs.e23oi.0) + E3(self.e13oi.0 * rhs.e23.0) + E3(self.e1i.0 * rhs.e2o.0) - E3(self.e1o.0 * rhs.e2i.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e23oi.0 * rhs.e13.0) - E3(self.e2i.0 * rhs.e1o.0) + E3(self.e2o.0 * rhs.e1i.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0) + E3(self.eoi.0 * rhs.e12.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e123o.0 * rhs.s.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e1o.0 * rhs.e23.0) + Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e23.0 * rhs.e1o.0) - Eo(self.e23oi.0 * rhs.e1o.0) + Eo(self.e2o.0 * rhs.e13.0) - Eo(self.e2o.0 * rhs.e13oi.0) - Eo(self.e3o.0 * rhs.e12.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0) - Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) + Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e13oi.0 * rhs.e2i.0) + Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0) + Ei(self.e23.0 * rhs.e1i.0) - Ei(self.e23oi.0 * rhs.e1i.0) - Ei(self.e2i.0 * rhs.e13.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) - E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0) + E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e1i.0 * rhs.e1o.0) - E123(self.e1o.0 * rhs.e1i.0) + E123(self.e23.0 * rhs.e23oi.0) + E123(self.e23oi.0 * rhs.e23.0) + E123(self.e2i.0 * rhs.e2o.0) - E123(self.e2o.0 * rhs.e2i.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.e3o.0 * rhs.e3i.0) - E123(self.eoi.0 * rhs.s.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) - E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0) + E12o(self.e13.0 * rhs.e1o.0) + E12o(self.e13oi.0 * rhs.e1o.0) - E12o(self.e1o.0 * rhs.e13.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e23.0 * rhs.e2o.0) + E12o(self.e23oi.0 * rhs.e2o.0) - E12o(self.e2o.0 * rhs.e23.0) + E12o(self.e2o.0 * rhs.e23oi.0) - E12o(self.e3o.0 * rhs.eoi.0) + E12o(self.e3o.0 * rhs.s.0) + E12o(self.eoi.0 * rhs.e3o.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0) - E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e1i.0 * rhs.e13oi.0) - E12i(self.e23.0 * rhs.e2i.0) + E12i(self.e23oi.0 * rhs.e2i.0) + E12i(self.e2i.0 * rhs.e23.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.e3i.0 * rhs.s.0) + E12i(self.eoi.0 * rhs.e3i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e123o.0 * rhs.e13.0) + E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e12oi.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e13oi.0 * rhs.e123o.0) + E13o(self.e1o.0 * rhs.e12.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e23.0 * rhs.e3o.0) + E13o(self.e23oi.0 * rhs.e3o.0) + E13o(self.e2o.0 * rhs.eoi.0) - E13o(self.e2o.0 * rhs.s.0) - E13o(self.e3o.0 * rhs.e23.0) + E13o(self.e3o.0 * rhs.e23oi.0) - E13o(self.eoi.0 * rhs.e2o.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e13oi.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0) + E13i(self.e3i.0 * rhs.e23oi.0) - E13i(self.eoi.0 * rhs.e2i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) + E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13.0 * rhs.e12.0) - E1oi(self.e13oi.0 * rhs.e12oi.0) + E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.e23.0 * rhs.s.0) + E1oi(self.e23oi.0 * rhs.eoi.0) - E1oi(self.e2i.0 * rhs.e3o.0) - E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.e3o.0 * rhs.e2i.0) + E1oi(self.eoi.0 * rhs.e23oi.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e13oi.0 * rhs.e3o.0) - E23o(self.e1o.0 * rhs.eoi.0) + E23o(self.e1o.0 * rhs.s.0) - E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e23oi.0 * rhs.e123o.0) + E23o(self.e2o.0 * rhs.e12.0) - E23o(self.e2o.0 * rhs.e12oi.0) + E23o(self.e3o.0 * rhs.e13.0) - E23o(self.e3o.0 * rhs.e13oi.0) + E23o(self.eoi.0 * rhs.e1o.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e12oi.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e1i.0 * rhs.s.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e23oi.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13.0) - E23i(self.e3i.0 * rhs.e13oi.0) + E23i(self.eoi.0 * rhs.e1i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) + E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) + E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e13.0 * rhs.s.0) - E2oi(self.e13oi.0 * rhs.eoi.0) + E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e23.0 * rhs.e12.0) - E2oi(self.e23oi.0 * rhs.e12oi.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.e3o.0 * rhs.e1i.0) - E2oi(self.eoi.0 * rhs.e13oi.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e13.0 * rhs.e23.0) + E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e1i.0 * rhs.e2o.0) - E3oi(self.e1o.0 * rhs.e2i.0) - E3oi(self.e23.0 * rhs.e13.0) - E3oi(self.e23oi.0 * rhs.e13oi.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.e2o.0 * rhs.e1i.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) + E123oi(self.e23oi.0 * rhs.e23oi.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e123i.0 * rhs.e1o.0)
+				+ E1(self.e123o.0 * rhs.e1i.0)
+				+ E1(self.e12oi.0 * rhs.e13.0)
+				- E1(self.e13.0 * rhs.e12oi.0)
+				- E1(self.e13oi.0 * rhs.e12.0)
+				- E1(self.e1i.0 * rhs.e123o.0)
+				+ E1(self.e1o.0 * rhs.e123i.0)
+				+ E1(self.e23.0 * rhs.eoi.0)
+				+ E1(self.e23oi.0 * rhs.s.0)
+				+ E1(self.e2i.0 * rhs.e3o.0)
+				- E1(self.e2o.0 * rhs.e3i.0)
+				- E1(self.e3i.0 * rhs.e2o.0)
+				+ E1(self.e3o.0 * rhs.e2i.0)
+				+ E1(self.eoi.0 * rhs.e23.0)
+				+ E1(self.s.0 * rhs.e23oi.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e123i.0 * rhs.e2o.0)
+				+ E2(self.e123o.0 * rhs.e2i.0)
+				+ E2(self.e12oi.0 * rhs.e23.0)
+				- E2(self.e13.0 * rhs.eoi.0)
+				- E2(self.e13oi.0 * rhs.s.0)
+				- E2(self.e1i.0 * rhs.e3o.0)
+				+ E2(self.e1o.0 * rhs.e3i.0)
+				- E2(self.e23.0 * rhs.e12oi.0)
+				- E2(self.e23oi.0 * rhs.e12.0)
+				- E2(self.e2i.0 * rhs.e123o.0)
+				+ E2(self.e2o.0 * rhs.e123i.0)
+				+ E2(self.e3i.0 * rhs.e1o.0)
+				- E2(self.e3o.0 * rhs.e1i.0)
+				- E2(self.eoi.0 * rhs.e13.0)
+				- E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e12.0 * rhs.eoi.0) - E3(self.e123i.0 * rhs.e3o.0)
+				+ E3(self.e123o.0 * rhs.e3i.0)
+				+ E3(self.e12oi.0 * rhs.s.0)
+				+ E3(self.e13.0 * rhs.e23oi.0)
+				+ E3(self.e13oi.0 * rhs.e23.0)
+				+ E3(self.e1i.0 * rhs.e2o.0)
+				- E3(self.e1o.0 * rhs.e2i.0)
+				- E3(self.e23.0 * rhs.e13oi.0)
+				- E3(self.e23oi.0 * rhs.e13.0)
+				- E3(self.e2i.0 * rhs.e1o.0)
+				+ E3(self.e2o.0 * rhs.e1i.0)
+				- E3(self.e3i.0 * rhs.e123o.0)
+				+ E3(self.e3o.0 * rhs.e123i.0)
+				+ E3(self.eoi.0 * rhs.e12.0)
+				+ E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e123o.0 * rhs.eoi.0)
+				- Eo(self.e123o.0 * rhs.s.0)
+				- Eo(self.e12oi.0 * rhs.e3o.0)
+				+ Eo(self.e13.0 * rhs.e2o.0)
+				+ Eo(self.e13oi.0 * rhs.e2o.0)
+				- Eo(self.e1o.0 * rhs.e23.0)
+				+ Eo(self.e1o.0 * rhs.e23oi.0)
+				- Eo(self.e23.0 * rhs.e1o.0)
+				- Eo(self.e23oi.0 * rhs.e1o.0)
+				+ Eo(self.e2o.0 * rhs.e13.0)
+				- Eo(self.e2o.0 * rhs.e13oi.0)
+				- Eo(self.e3o.0 * rhs.e12.0)
+				+ Eo(self.e3o.0 * rhs.e12oi.0)
+				- Eo(self.eoi.0 * rhs.e123o.0)
+				- Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) + Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0)
+				- Ei(self.e12oi.0 * rhs.e3i.0)
+				- Ei(self.e13.0 * rhs.e2i.0)
+				+ Ei(self.e13oi.0 * rhs.e2i.0)
+				+ Ei(self.e1i.0 * rhs.e23.0)
+				+ Ei(self.e1i.0 * rhs.e23oi.0)
+				+ Ei(self.e23.0 * rhs.e1i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0)
+				- Ei(self.e2i.0 * rhs.e13.0)
+				- Ei(self.e2i.0 * rhs.e13oi.0)
+				+ Ei(self.e3i.0 * rhs.e12.0)
+				+ Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0)
+				+ Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0) - E123(self.e123i.0 * rhs.e123o.0)
+				+ E123(self.e123o.0 * rhs.e123i.0)
+				+ E123(self.e12oi.0 * rhs.e12.0)
+				+ E123(self.e13.0 * rhs.e13oi.0)
+				+ E123(self.e13oi.0 * rhs.e13.0)
+				+ E123(self.e1i.0 * rhs.e1o.0)
+				- E123(self.e1o.0 * rhs.e1i.0)
+				+ E123(self.e23.0 * rhs.e23oi.0)
+				+ E123(self.e23oi.0 * rhs.e23.0)
+				+ E123(self.e2i.0 * rhs.e2o.0)
+				- E123(self.e2o.0 * rhs.e2i.0)
+				+ E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.e3o.0 * rhs.e3i.0)
+				- E123(self.eoi.0 * rhs.s.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) - E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e123o.0 * rhs.e12oi.0)
+				- E12o(self.e12oi.0 * rhs.e123o.0)
+				+ E12o(self.e13.0 * rhs.e1o.0)
+				+ E12o(self.e13oi.0 * rhs.e1o.0)
+				- E12o(self.e1o.0 * rhs.e13.0)
+				+ E12o(self.e1o.0 * rhs.e13oi.0)
+				+ E12o(self.e23.0 * rhs.e2o.0)
+				+ E12o(self.e23oi.0 * rhs.e2o.0)
+				- E12o(self.e2o.0 * rhs.e23.0)
+				+ E12o(self.e2o.0 * rhs.e23oi.0)
+				- E12o(self.e3o.0 * rhs.eoi.0)
+				+ E12o(self.e3o.0 * rhs.s.0)
+				+ E12o(self.eoi.0 * rhs.e3o.0)
+				+ E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0)
+				- E12i(self.e12oi.0 * rhs.e123i.0)
+				- E12i(self.e13.0 * rhs.e1i.0)
+				+ E12i(self.e13oi.0 * rhs.e1i.0)
+				+ E12i(self.e1i.0 * rhs.e13.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				- E12i(self.e23.0 * rhs.e2i.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0)
+				+ E12i(self.e2i.0 * rhs.e23.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e123o.0 * rhs.e13.0) + E13o(self.e123o.0 * rhs.e13oi.0)
+				- E13o(self.e12oi.0 * rhs.e1o.0)
+				- E13o(self.e13.0 * rhs.e123o.0)
+				- E13o(self.e13oi.0 * rhs.e123o.0)
+				+ E13o(self.e1o.0 * rhs.e12.0)
+				- E13o(self.e1o.0 * rhs.e12oi.0)
+				+ E13o(self.e23.0 * rhs.e3o.0)
+				+ E13o(self.e23oi.0 * rhs.e3o.0)
+				+ E13o(self.e2o.0 * rhs.eoi.0)
+				- E13o(self.e2o.0 * rhs.s.0)
+				- E13o(self.e3o.0 * rhs.e23.0)
+				+ E13o(self.e3o.0 * rhs.e23oi.0)
+				- E13o(self.eoi.0 * rhs.e2o.0)
+				- E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0)
+				- E13i(self.e12oi.0 * rhs.e1i.0)
+				+ E13i(self.e13.0 * rhs.e123i.0)
+				- E13i(self.e13oi.0 * rhs.e123i.0)
+				- E13i(self.e1i.0 * rhs.e12.0)
+				- E13i(self.e1i.0 * rhs.e12oi.0)
+				- E13i(self.e23.0 * rhs.e3i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				- E13i(self.eoi.0 * rhs.e2i.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0)
+				+ E1oi(self.e123i.0 * rhs.e1o.0)
+				+ E1oi(self.e123o.0 * rhs.e1i.0)
+				+ E1oi(self.e12oi.0 * rhs.e13oi.0)
+				- E1oi(self.e13.0 * rhs.e12.0)
+				- E1oi(self.e13oi.0 * rhs.e12oi.0)
+				+ E1oi(self.e1i.0 * rhs.e123o.0)
+				+ E1oi(self.e1o.0 * rhs.e123i.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				+ E1oi(self.e23oi.0 * rhs.eoi.0)
+				- E1oi(self.e2i.0 * rhs.e3o.0)
+				- E1oi(self.e2o.0 * rhs.e3i.0)
+				+ E1oi(self.e3i.0 * rhs.e2o.0)
+				+ E1oi(self.e3o.0 * rhs.e2i.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e123o.0 * rhs.e23oi.0)
+				- E23o(self.e12oi.0 * rhs.e2o.0)
+				- E23o(self.e13.0 * rhs.e3o.0)
+				- E23o(self.e13oi.0 * rhs.e3o.0)
+				- E23o(self.e1o.0 * rhs.eoi.0)
+				+ E23o(self.e1o.0 * rhs.s.0)
+				- E23o(self.e23.0 * rhs.e123o.0)
+				- E23o(self.e23oi.0 * rhs.e123o.0)
+				+ E23o(self.e2o.0 * rhs.e12.0)
+				- E23o(self.e2o.0 * rhs.e12oi.0)
+				+ E23o(self.e3o.0 * rhs.e13.0)
+				- E23o(self.e3o.0 * rhs.e13oi.0)
+				+ E23o(self.eoi.0 * rhs.e1o.0)
+				+ E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0)
+				- E23i(self.e12oi.0 * rhs.e2i.0)
+				+ E23i(self.e13.0 * rhs.e3i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				+ E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.e23oi.0 * rhs.e123i.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0)
+				+ E2oi(self.e123i.0 * rhs.e2o.0)
+				+ E2oi(self.e123o.0 * rhs.e2i.0)
+				+ E2oi(self.e12oi.0 * rhs.e23oi.0)
+				- E2oi(self.e13.0 * rhs.s.0)
+				- E2oi(self.e13oi.0 * rhs.eoi.0)
+				+ E2oi(self.e1i.0 * rhs.e3o.0)
+				+ E2oi(self.e1o.0 * rhs.e3i.0)
+				- E2oi(self.e23.0 * rhs.e12.0)
+				- E2oi(self.e23oi.0 * rhs.e12oi.0)
+				+ E2oi(self.e2i.0 * rhs.e123o.0)
+				+ E2oi(self.e2o.0 * rhs.e123i.0)
+				- E2oi(self.e3i.0 * rhs.e1o.0)
+				- E2oi(self.e3o.0 * rhs.e1i.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0)
+				+ E3oi(self.e123i.0 * rhs.e3o.0)
+				+ E3oi(self.e123o.0 * rhs.e3i.0)
+				+ E3oi(self.e12oi.0 * rhs.eoi.0)
+				+ E3oi(self.e13.0 * rhs.e23.0)
+				+ E3oi(self.e13oi.0 * rhs.e23oi.0)
+				- E3oi(self.e1i.0 * rhs.e2o.0)
+				- E3oi(self.e1o.0 * rhs.e2i.0)
+				- E3oi(self.e23.0 * rhs.e13.0)
+				- E3oi(self.e23oi.0 * rhs.e13oi.0)
+				+ E3oi(self.e2i.0 * rhs.e1o.0)
+				+ E3oi(self.e2o.0 * rhs.e1i.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.e3o.0 * rhs.e123i.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0)
+				+ E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13.0 * rhs.e13.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Versor.dot(Versor) -> Versor
+impl Dot<Versor> for Versor {
+	type Output = Versor;
+	fn dot(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13.0 * rhs.e13.0) - S(self.e13oi.0 * rhs.e13oi.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) - S(self.e23oi.0 * rhs.e23oi.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e13oi.0 * rhs.eoi.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.s.0) - E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.e23oi.0 * rhs.eoi.0) + E23(self.eoi.0 * rhs.e23oi.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) + E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e23oi.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23oi.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e23oi.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23oi.0) + E3o(self.e3o.0 * rhs.s.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e23oi.0 * rhs.e2i.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23.0 * rhs.e23oi.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.eoi.0 * rhs.s.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0)
+				- S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13.0 * rhs.e13.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				- S(self.e23oi.0 * rhs.e23oi.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0)
+				+ E12(self.e123i.0 * rhs.e3o.0)
+				+ E12(self.e123o.0 * rhs.e3i.0)
+				+ E12(self.e12oi.0 * rhs.eoi.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0)
+				+ E13(self.e13.0 * rhs.s.0)
+				+ E13(self.e13oi.0 * rhs.eoi.0)
+				- E13(self.e2i.0 * rhs.e123o.0)
+				- E13(self.e2o.0 * rhs.e123i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.s.0)
+				- E1o(self.e23.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12oi.0)
+				- E1o(self.e3o.0 * rhs.e13oi.0)
+				+ E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				+ E1i(self.e1i.0 * rhs.s.0)
+				- E1i(self.e23.0 * rhs.e123i.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0)
+				+ E23(self.e123o.0 * rhs.e1i.0)
+				+ E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0)
+				+ E2o(self.e12oi.0 * rhs.e1o.0)
+				+ E2o(self.e13.0 * rhs.e123o.0)
+				+ E2o(self.e1o.0 * rhs.e12oi.0)
+				- E2o(self.e23oi.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.s.0)
+				- E2o(self.e3o.0 * rhs.e23oi.0)
+				+ E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0)
+				- E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e123o.0 * rhs.e12.0)
+				+ E3o(self.e13oi.0 * rhs.e1o.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				+ E3o(self.e23oi.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0)
+				+ E3o(self.e3o.0 * rhs.s.0)
+				+ E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0)
+				- E3i(self.e123i.0 * rhs.e12.0)
+				- E3i(self.e13oi.0 * rhs.e1i.0)
+				- E3i(self.e1i.0 * rhs.e13oi.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0)
+				- Eoi(self.e12oi.0 * rhs.e12.0)
+				- Eoi(self.e13.0 * rhs.e13oi.0)
+				- Eoi(self.e13oi.0 * rhs.e13.0)
+				- Eoi(self.e23.0 * rhs.e23oi.0)
+				- Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.eoi.0 * rhs.s.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0) + E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0) + E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0) + E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0) + E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Versor> for Versor {
+	type Output = Versor;
+	fn bitor(self, rhs: Versor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(Versor) -> Versor
+impl Wedge<Versor> for Versor {
+	type Output = Versor;
+	fn wedge(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : E1o(self.e1o.0 * rhs.s.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.e2o.0 * rhs.s.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : E3o(self.e3o.0 * rhs.s.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : Eoi(self.eoi.0 * rhs.s.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.e12.0 * rhs.e3o.0) + E123o(self.e123o.0 * rhs.s.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.e12.0 * rhs.e3i.0) + E123i(self.e123i.0 * rhs.s.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) + E123i(self.e23.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e12oi.0 * rhs.s.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.eoi.0 * rhs.e12.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e13oi.0 * rhs.s.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e23oi.0 * rhs.s.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: self.s.wedge(rhs.s),
+			e12: self.e12.wedge(rhs.s) + self.s.wedge(rhs.e12),
+			e13: self.e13.wedge(rhs.s) + self.s.wedge(rhs.e13),
+			e1o: self.e1o.wedge(rhs.s) + self.s.wedge(rhs.e1o),
+			e1i: self.e1i.wedge(rhs.s) + self.s.wedge(rhs.e1i),
+			e23: self.e23.wedge(rhs.s) + self.s.wedge(rhs.e23),
+			e2o: self.e2o.wedge(rhs.s) + self.s.wedge(rhs.e2o),
+			e2i: self.e2i.wedge(rhs.s) + self.s.wedge(rhs.e2i),
+			e3o: self.e3o.wedge(rhs.s) + self.s.wedge(rhs.e3o),
+			e3i: self.e3i.wedge(rhs.s) + self.s.wedge(rhs.e3i),
+			eoi: self.eoi.wedge(rhs.s) + self.s.wedge(rhs.eoi),
+			e123o: self.e12.wedge(rhs.e3o)
+				+ self.e123o.wedge(rhs.s)
+				+ self.e13.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e13)
+				+ self.e3o.wedge(rhs.e12)
+				+ self.s.wedge(rhs.e123o),
+			e123i: self.e12.wedge(rhs.e3i)
+				+ self.e123i.wedge(rhs.s)
+				+ self.e13.wedge(rhs.e2i)
+				+ self.e1i.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1i)
+				+ self.e2i.wedge(rhs.e13)
+				+ self.e3i.wedge(rhs.e12)
+				+ self.s.wedge(rhs.e123i),
+			e12oi: self.e12.wedge(rhs.eoi)
+				+ self.e12oi.wedge(rhs.s)
+				+ self.e1i.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e2i)
+				+ self.e2i.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e12)
+				+ self.s.wedge(rhs.e12oi),
+			e13oi: self.e13.wedge(rhs.eoi)
+				+ self.e13oi.wedge(rhs.s)
+				+ self.e1i.wedge(rhs.e3o)
+				+ self.e1o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e1o)
+				+ self.e3o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e13)
+				+ self.s.wedge(rhs.e13oi),
+			e23oi: self.e23.wedge(rhs.eoi)
+				+ self.e23oi.wedge(rhs.s)
+				+ self.e2i.wedge(rhs.e3o)
+				+ self.e2o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e2o)
+				+ self.e3o.wedge(rhs.e2i)
+				+ self.eoi.wedge(rhs.e23)
+				+ self.s.wedge(rhs.e23oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Versor> for Versor {
+	type Output = Versor;
+	fn bitxor(self, rhs: Versor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(Versor) -> Multivector
+impl AntiWedge<Versor> for Versor {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e13oi.0 * rhs.e12.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e23oi.0 * rhs.e12.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e13.0 * rhs.e23oi.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e23oi.0 * rhs.e13.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0),
+		//     eo    : Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) + Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e23oi.0 * rhs.e1o.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) + Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e23oi.0 * rhs.e1i.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: self.e12.anti_wedge(rhs.e13oi)
+				+ self.e123i.anti_wedge(rhs.e1o)
+				+ self.e123o.anti_wedge(rhs.e1i)
+				+ self.e12oi.anti_wedge(rhs.e13)
+				+ self.e13.anti_wedge(rhs.e12oi)
+				+ self.e13oi.anti_wedge(rhs.e12)
+				+ self.e1i.anti_wedge(rhs.e123o)
+				+ self.e1o.anti_wedge(rhs.e123i),
+			e2: self.e12.anti_wedge(rhs.e23oi)
+				+ self.e123i.anti_wedge(rhs.e2o)
+				+ self.e123o.anti_wedge(rhs.e2i)
+				+ self.e12oi.anti_wedge(rhs.e23)
+				+ self.e23.anti_wedge(rhs.e12oi)
+				+ self.e23oi.anti_wedge(rhs.e12)
+				+ self.e2i.anti_wedge(rhs.e123o)
+				+ self.e2o.anti_wedge(rhs.e123i),
+			e3: self.e123i.anti_wedge(rhs.e3o)
+				+ self.e123o.anti_wedge(rhs.e3i)
+				+ self.e13.anti_wedge(rhs.e23oi)
+				+ self.e13oi.anti_wedge(rhs.e23)
+				+ self.e23.anti_wedge(rhs.e13oi)
+				+ self.e23oi.anti_wedge(rhs.e13)
+				+ self.e3i.anti_wedge(rhs.e123o)
+				+ self.e3o.anti_wedge(rhs.e123i),
+			eo: self.e123o.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3o)
+				+ self.e13oi.anti_wedge(rhs.e2o)
+				+ self.e1o.anti_wedge(rhs.e23oi)
+				+ self.e23oi.anti_wedge(rhs.e1o)
+				+ self.e2o.anti_wedge(rhs.e13oi)
+				+ self.e3o.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123o),
+			ei: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e23oi.anti_wedge(rhs.e1i)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: self.e123i.anti_wedge(rhs.e123o) + self.e123o.anti_wedge(rhs.e123i),
+			e12o: self.e123o.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123o),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e123o.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123o),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e123o.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123o),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Versor> for Versor {
+	type Output = Multivector;
+	fn bitand(self, rhs: Versor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(Versor) -> Versor
+impl LeftContraction<Versor> for Versor {
+	type Output = Versor;
+	fn left_contraction(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13.0 * rhs.e13.0) - S(self.e13oi.0 * rhs.e13oi.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) - S(self.e23oi.0 * rhs.e23oi.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o  : -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i  : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23  : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o  : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i  : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i  : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123o: E123o(self.s.0 * rhs.e123o.0),
+		//     e123i: E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0)
+				- S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13.0 * rhs.e13.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				- S(self.e23oi.0 * rhs.e23oi.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0)
+				+ E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0)
+				+ E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0)
+				+ E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+		}
+	}
+}
+
+// Versor.right_contraction(Versor) -> Versor
+impl RightContraction<Versor> for Versor {
+	type Output = Versor;
+	fn right_contraction(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : -S(self.e12.0 * rhs.e12.0) - S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13.0 * rhs.e13.0) - S(self.e13oi.0 * rhs.e13oi.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) - S(self.e23oi.0 * rhs.e23oi.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0) + S(self.s.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13  : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o  : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.s.0),
+		//     e1i  : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.e1i.0 * rhs.s.0),
+		//     e23  : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o  : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.s.0),
+		//     e2i  : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.e2i.0 * rhs.s.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0) + E3o(self.e3o.0 * rhs.s.0),
+		//     e3i  : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi  : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123o: E123o(self.e123o.0 * rhs.s.0),
+		//     e123i: E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		// }
+		Versor {
+			s: -S(self.e12.0 * rhs.e12.0)
+				- S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13.0 * rhs.e13.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				- S(self.e23oi.0 * rhs.e23oi.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0)
+				+ S(self.s.0 * rhs.s.0),
+			e12: E12(self.e12.0 * rhs.s.0)
+				+ E12(self.e123i.0 * rhs.e3o.0)
+				+ E12(self.e123o.0 * rhs.e3i.0)
+				+ E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0)
+				+ E13(self.e13.0 * rhs.s.0)
+				+ E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.s.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				+ E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0)
+				+ E23(self.e123o.0 * rhs.e1i.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.s.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				+ E2i(self.e2i.0 * rhs.s.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0)
+				+ E3o(self.e13oi.0 * rhs.e1o.0)
+				+ E3o(self.e23oi.0 * rhs.e2o.0)
+				+ E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0)
+				+ E3i(self.e3i.0 * rhs.s.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+		}
+	}
+}
+
+// Versor.scalar_product(Versor) -> S
+impl ScalarProduct<Versor> for Versor {
+	type Output = S;
+	fn scalar_product(self, rhs: Versor) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13.0 * rhs.e13.0) - S(self.e13oi.0 * rhs.e13oi.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) - S(self.e23oi.0 * rhs.e23oi.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0) + S(self.s.0 * rhs.s.0)
+		-S(self.e12.0 * rhs.e12.0)
+			- S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13.0 * rhs.e13.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			+ S(self.e1i.0 * rhs.e1o.0)
+			+ S(self.e1o.0 * rhs.e1i.0)
+			- S(self.e23.0 * rhs.e23.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+			+ S(self.e2i.0 * rhs.e2o.0)
+			+ S(self.e2o.0 * rhs.e2i.0)
+			+ S(self.e3i.0 * rhs.e3o.0)
+			+ S(self.e3o.0 * rhs.e3i.0)
+			+ S(self.eoi.0 * rhs.eoi.0)
+			+ S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Versor.commutator(Versor) -> Versor
+impl Commutator<Versor> for Versor {
+	type Output = Versor;
+	fn commutator(self, rhs: Versor) -> Self::Output {
+		// Versor {
+		//     s    : Default::default(),
+		//     e12  : -E12(self.e13.0 * rhs.e23.0) - E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) + E12(self.e23oi.0 * rhs.e13oi.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13  : E13(self.e12.0 * rhs.e23.0) + E13(self.e12oi.0 * rhs.e23oi.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e23oi.0 * rhs.e12oi.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o  : E1o(self.e12.0 * rhs.e2o.0) - E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) + E1o(self.e23oi.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i  : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e123i.0 * rhs.e23oi.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e23oi.0 * rhs.e123i.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23  : -E23(self.e12.0 * rhs.e13.0) - E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e13oi.0 * rhs.e12oi.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o  : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i  : -E2i(self.e12.0 * rhs.e1i.0) - E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o  : -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.eoi.0) - E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i  : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi  : -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0) + Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		//     e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e23oi.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) - E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e23oi.0 * rhs.e1i.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi: E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13.0 * rhs.e23oi.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e23oi.0 * rhs.e13.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+		//     e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e23oi.0 * rhs.e12.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+		//     e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		// }
+		Versor {
+			s: Default::default(),
+			e12: -E12(self.e13.0 * rhs.e23.0) - E12(self.e13oi.0 * rhs.e23oi.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				+ E12(self.e23oi.0 * rhs.e13oi.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e12.0 * rhs.e23.0)
+				+ E13(self.e12oi.0 * rhs.e23oi.0)
+				+ E13(self.e1i.0 * rhs.e3o.0)
+				+ E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e23oi.0 * rhs.e12oi.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) - E1o(self.e123o.0 * rhs.e23oi.0)
+				+ E1o(self.e13.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.eoi.0)
+				+ E1o(self.e23oi.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e123i.0 * rhs.e23oi.0) + E1i(self.e13.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				- E1i(self.e23oi.0 * rhs.e123i.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) - E23(self.e12oi.0 * rhs.e13oi.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e13oi.0 * rhs.e12oi.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) - E2i(self.e123i.0 * rhs.e13oi.0)
+				+ E2i(self.e13oi.0 * rhs.e123i.0)
+				+ E2i(self.e1i.0 * rhs.e12.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0)
+				+ E3o(self.e1o.0 * rhs.e13.0)
+				- E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				- E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0)
+				+ E3i(self.e1i.0 * rhs.e13.0)
+				- E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0) + Eoi(self.e1i.0 * rhs.e1o.0)
+				- Eoi(self.e1o.0 * rhs.e1i.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0)
+				+ E123o(self.e13oi.0 * rhs.e2o.0)
+				+ E123o(self.e1o.0 * rhs.e23oi.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0)
+				- E123o(self.e2o.0 * rhs.e13oi.0)
+				+ E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				- E123i(self.e1i.0 * rhs.e23oi.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0)
+				+ E123i(self.e2i.0 * rhs.e13oi.0)
+				- E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0)
+				- E12oi(self.e123o.0 * rhs.e3i.0)
+				- E12oi(self.e13.0 * rhs.e23oi.0)
+				- E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23.0 * rhs.e13oi.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0)
+				+ E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e123i.0 * rhs.e2o.0)
+				+ E13oi(self.e123o.0 * rhs.e2i.0)
+				+ E13oi(self.e12oi.0 * rhs.e23.0)
+				- E13oi(self.e23.0 * rhs.e12oi.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0)
+				- E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e123i.0 * rhs.e1o.0)
+				- E23oi(self.e123o.0 * rhs.e1i.0)
+				- E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13.0 * rhs.e12oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0)
+				+ E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0),
+		}
+	}
+}
+
+// Versor.anti_dot(Versor) -> Multivector
+impl AntiDot<Versor> for Versor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Versor) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0) - Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : Default::default(),
+		//     e13   : Default::default(),
+		//     e1o   : Default::default(),
+		//     e1i   : Default::default(),
+		//     e23   : Default::default(),
+		//     e2o   : Default::default(),
+		//     e2i   : Default::default(),
+		//     e3o   : Default::default(),
+		//     e3i   : Default::default(),
+		//     eoi   : Default::default(),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23.0 * rhs.e23oi.0) + E123(self.e23oi.0 * rhs.e23.0) - E123(self.eoi.0 * rhs.s.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) - E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e23oi.0 * rhs.e2o.0) + E12o(self.e2o.0 * rhs.e23oi.0) + E12o(self.e3o.0 * rhs.s.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e23oi.0 * rhs.e2i.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e23oi.0 * rhs.e3o.0) - E13o(self.e2o.0 * rhs.s.0) + E13o(self.e3o.0 * rhs.e23oi.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23oi.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.e23.0 * rhs.s.0) + E1oi(self.e23oi.0 * rhs.eoi.0) + E1oi(self.eoi.0 * rhs.e23oi.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0) + E23o(self.e1o.0 * rhs.s.0) - E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.s.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13.0 * rhs.s.0) - E2oi(self.e13oi.0 * rhs.eoi.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : Default::default(),
+		//     e123i : Default::default(),
+		//     e12oi : Default::default(),
+		//     e13oi : Default::default(),
+		//     e23oi : Default::default(),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) + E123oi(self.e23oi.0 * rhs.e23oi.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e23oi.0 * rhs.s.0) + E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0) - E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e12oi.0 * rhs.s.0) + E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0) - Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.e123i.0 * rhs.s.0) + Ei(self.s.0 * rhs.e123i.0),
+			e12: Default::default(),
+			e13: Default::default(),
+			e1o: Default::default(),
+			e1i: Default::default(),
+			e23: Default::default(),
+			e2o: Default::default(),
+			e2i: Default::default(),
+			e3o: Default::default(),
+			e3i: Default::default(),
+			eoi: Default::default(),
+			e123: E123(self.e12.0 * rhs.e12oi.0)
+				+ E123(self.e12oi.0 * rhs.e12.0)
+				+ E123(self.e13.0 * rhs.e13oi.0)
+				+ E123(self.e13oi.0 * rhs.e13.0)
+				+ E123(self.e23.0 * rhs.e23oi.0)
+				+ E123(self.e23oi.0 * rhs.e23.0)
+				- E123(self.eoi.0 * rhs.s.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) - E12o(self.e123o.0 * rhs.e12.0)
+				+ E12o(self.e13oi.0 * rhs.e1o.0)
+				+ E12o(self.e1o.0 * rhs.e13oi.0)
+				+ E12o(self.e23oi.0 * rhs.e2o.0)
+				+ E12o(self.e2o.0 * rhs.e23oi.0)
+				+ E12o(self.e3o.0 * rhs.s.0)
+				+ E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0)
+				+ E12i(self.e123i.0 * rhs.e12.0)
+				+ E12i(self.e13oi.0 * rhs.e1i.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0)
+				- E13o(self.e12oi.0 * rhs.e1o.0)
+				- E13o(self.e13.0 * rhs.e123o.0)
+				- E13o(self.e1o.0 * rhs.e12oi.0)
+				+ E13o(self.e23oi.0 * rhs.e3o.0)
+				- E13o(self.e2o.0 * rhs.s.0)
+				+ E13o(self.e3o.0 * rhs.e23oi.0)
+				- E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0)
+				- E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0)
+				+ E1oi(self.e123o.0 * rhs.e1i.0)
+				+ E1oi(self.e1i.0 * rhs.e123o.0)
+				+ E1oi(self.e1o.0 * rhs.e123i.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				+ E1oi(self.e23oi.0 * rhs.eoi.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0)
+				+ E23o(self.e1o.0 * rhs.s.0)
+				- E23o(self.e23.0 * rhs.e123o.0)
+				- E23o(self.e2o.0 * rhs.e12oi.0)
+				- E23o(self.e3o.0 * rhs.e13oi.0)
+				+ E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0)
+				- E23i(self.e12oi.0 * rhs.e2i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				+ E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0)
+				- E2oi(self.e13.0 * rhs.s.0)
+				- E2oi(self.e13oi.0 * rhs.eoi.0)
+				+ E2oi(self.e2i.0 * rhs.e123o.0)
+				+ E2oi(self.e2o.0 * rhs.e123i.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0)
+				+ E3oi(self.e123i.0 * rhs.e3o.0)
+				+ E3oi(self.e123o.0 * rhs.e3i.0)
+				+ E3oi(self.e12oi.0 * rhs.eoi.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.e3o.0 * rhs.e123i.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: Default::default(),
+			e123i: Default::default(),
+			e12oi: Default::default(),
+			e13oi: Default::default(),
+			e23oi: Default::default(),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0)
+				+ E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13.0 * rhs.e13.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Versor OP Multivector:
+
+// Versor.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Versor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13.0 * rhs.e13.0) - S(self.e13oi.0 * rhs.e13oi.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) - S(self.e23oi.0 * rhs.e23oi.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13.0 * rhs.e3.0) + E1(self.e13oi.0 * rhs.e3oi.0) - E1(self.e1i.0 * rhs.eo.0) - E1(self.e1o.0 * rhs.ei.0) - E1(self.e23.0 * rhs.e123.0) - E1(self.e23oi.0 * rhs.e123oi.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) - E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e13.0 * rhs.e123.0) + E2(self.e13oi.0 * rhs.e123oi.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e23.0 * rhs.e3.0) + E2(self.e23oi.0 * rhs.e3oi.0) - E2(self.e2i.0 * rhs.eo.0) - E2(self.e2o.0 * rhs.ei.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) + E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e12oi.0 * rhs.e123oi.0) - E3(self.e13.0 * rhs.e1.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e23.0 * rhs.e2.0) - E3(self.e23oi.0 * rhs.e2oi.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) - E3(self.e3i.0 * rhs.eo.0) - E3(self.e3o.0 * rhs.ei.0) + E3(self.eoi.0 * rhs.e3oi.0) + E3(self.s.0 * rhs.e3.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) + Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e123o.0 * rhs.e123oi.0) + Eo(self.e12oi.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) + Eo(self.e13oi.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) + Eo(self.e23oi.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3.0) - Eo(self.e3o.0 * rhs.e3oi.0) - Eo(self.eoi.0 * rhs.eo.0) + Eo(self.s.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) + Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e123i.0 * rhs.e123oi.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) - Ei(self.e23oi.0 * rhs.e23i.0) - Ei(self.e2i.0 * rhs.e2.0) + Ei(self.e2i.0 * rhs.e2oi.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.e3i.0 * rhs.e3oi.0) + Ei(self.eoi.0 * rhs.ei.0) + Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.e12.0 * rhs.s.0) + E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0) - E12(self.e13.0 * rhs.e23.0) - E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) + E12(self.e23oi.0 * rhs.e13oi.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13   : E13(self.e12.0 * rhs.e23.0) - E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e12oi.0 * rhs.e23oi.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e13oi.0 * rhs.eoi.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e23oi.0 * rhs.e12oi.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0) + E13(self.eoi.0 * rhs.e13oi.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o   : E1o(self.e12.0 * rhs.e2o.0) - E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e123o.0 * rhs.e23oi.0) - E1o(self.e12oi.0 * rhs.e2o.0) + E1o(self.e13.0 * rhs.e3o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) + E1o(self.e1o.0 * rhs.s.0) - E1o(self.e23.0 * rhs.e123o.0) + E1o(self.e23oi.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.e3o.0 * rhs.e13oi.0) - E1o(self.eoi.0 * rhs.e1o.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : E1i(self.e12.0 * rhs.e2i.0) - E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e123i.0 * rhs.e23oi.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13.0 * rhs.e3i.0) + E1i(self.e13oi.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e23.0 * rhs.e123i.0) - E1i(self.e23oi.0 * rhs.e123i.0) - E1i(self.e2i.0 * rhs.e12.0) + E1i(self.e2i.0 * rhs.e12oi.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.eoi.0 * rhs.e1i.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : -E23(self.e12.0 * rhs.e13.0) + E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) - E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e13oi.0 * rhs.e12oi.0) + E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.e23oi.0 * rhs.eoi.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0) + E23(self.eoi.0 * rhs.e23oi.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o   : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e123o.0 * rhs.e13oi.0) + E2o(self.e12oi.0 * rhs.e1o.0) + E2o(self.e13.0 * rhs.e123o.0) - E2o(self.e13oi.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e1o.0 * rhs.e12oi.0) + E2o(self.e23.0 * rhs.e3o.0) - E2o(self.e23oi.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.e3o.0 * rhs.e23oi.0) - E2o(self.eoi.0 * rhs.e2o.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e123i.0 * rhs.e13oi.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0) + E2i(self.e13oi.0 * rhs.e123i.0) + E2i(self.e1i.0 * rhs.e12.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e23.0 * rhs.e3i.0) + E2i(self.e23oi.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) + E2i(self.e2i.0 * rhs.s.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.eoi.0 * rhs.e2i.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e123o.0 * rhs.e12.0) - E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) + E3o(self.e1o.0 * rhs.e13oi.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e23oi.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e2o.0 * rhs.e23oi.0) + E3o(self.e3o.0 * rhs.eoi.0) + E3o(self.e3o.0 * rhs.s.0) - E3o(self.eoi.0 * rhs.e3o.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) - E3i(self.e13oi.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e23.0 * rhs.e2i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e2i.0 * rhs.e23oi.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.eoi.0 * rhs.e3i.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0) - Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e13oi.0 * rhs.e13.0) + Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) - Eoi(self.e23.0 * rhs.e23oi.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0) + Eoi(self.eoi.0 * rhs.s.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : E123(self.e12.0 * rhs.e3.0) - E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0) + E123(self.e12oi.0 * rhs.e3oi.0) - E123(self.e13.0 * rhs.e2.0) - E123(self.e13oi.0 * rhs.e2oi.0) - E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0) + E123(self.e23.0 * rhs.e1.0) + E123(self.e23oi.0 * rhs.e1oi.0) + E123(self.e2i.0 * rhs.e13o.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3i.0 * rhs.e12o.0) - E123(self.e3o.0 * rhs.e12i.0) + E123(self.eoi.0 * rhs.e123oi.0) + E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.e12.0 * rhs.eo.0) - E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e123o.0 * rhs.e3oi.0) - E12o(self.e12oi.0 * rhs.eo.0) - E12o(self.e13.0 * rhs.e23o.0) + E12o(self.e13oi.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2.0) - E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e23.0 * rhs.e13o.0) - E12o(self.e23oi.0 * rhs.e13o.0) + E12o(self.e2o.0 * rhs.e1.0) + E12o(self.e2o.0 * rhs.e1oi.0) - E12o(self.e3o.0 * rhs.e123.0) - E12o(self.e3o.0 * rhs.e123oi.0) - E12o(self.eoi.0 * rhs.e12o.0) + E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.ei.0) - E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e123i.0 * rhs.e3oi.0) + E12i(self.e12oi.0 * rhs.ei.0) - E12i(self.e13.0 * rhs.e23i.0) - E12i(self.e13oi.0 * rhs.e23i.0) - E12i(self.e1i.0 * rhs.e2.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) + E12i(self.e23oi.0 * rhs.e13i.0) + E12i(self.e2i.0 * rhs.e1.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.eoi.0 * rhs.e12i.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e12.0 * rhs.e23o.0) + E13o(self.e123o.0 * rhs.e2.0) + E13o(self.e123o.0 * rhs.e2oi.0) - E13o(self.e12oi.0 * rhs.e23o.0) + E13o(self.e13.0 * rhs.eo.0) - E13o(self.e13oi.0 * rhs.eo.0) - E13o(self.e1o.0 * rhs.e3.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.e23oi.0 * rhs.e12o.0) + E13o(self.e2o.0 * rhs.e123.0) + E13o(self.e2o.0 * rhs.e123oi.0) + E13o(self.e3o.0 * rhs.e1.0) + E13o(self.e3o.0 * rhs.e1oi.0) - E13o(self.eoi.0 * rhs.e13o.0) + E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e123i.0 * rhs.e2.0) - E13i(self.e123i.0 * rhs.e2oi.0) + E13i(self.e12oi.0 * rhs.e23i.0) + E13i(self.e13.0 * rhs.ei.0) + E13i(self.e13oi.0 * rhs.ei.0) - E13i(self.e1i.0 * rhs.e3.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) - E13i(self.e23oi.0 * rhs.e12i.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.e3i.0 * rhs.e1.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e123i.0 * rhs.e23o.0) - E1oi(self.e123o.0 * rhs.e23i.0) + E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.e13oi.0 * rhs.e3.0) - E1oi(self.e1i.0 * rhs.eo.0) + E1oi(self.e1o.0 * rhs.ei.0) - E1oi(self.e23.0 * rhs.e123oi.0) - E1oi(self.e23oi.0 * rhs.e123.0) + E1oi(self.e2i.0 * rhs.e12o.0) - E1oi(self.e2o.0 * rhs.e12i.0) + E1oi(self.e3i.0 * rhs.e13o.0) - E1oi(self.e3o.0 * rhs.e13i.0) + E1oi(self.eoi.0 * rhs.e1.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e13o.0) - E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e123o.0 * rhs.e1oi.0) + E23o(self.e12oi.0 * rhs.e13o.0) + E23o(self.e13.0 * rhs.e12o.0) - E23o(self.e13oi.0 * rhs.e12o.0) - E23o(self.e1o.0 * rhs.e123.0) - E23o(self.e1o.0 * rhs.e123oi.0) + E23o(self.e23.0 * rhs.eo.0) - E23o(self.e23oi.0 * rhs.eo.0) - E23o(self.e2o.0 * rhs.e3.0) - E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2.0) + E23o(self.e3o.0 * rhs.e2oi.0) - E23o(self.eoi.0 * rhs.e23o.0) + E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) - E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e123i.0 * rhs.e1oi.0) - E23i(self.e12oi.0 * rhs.e13i.0) + E23i(self.e13.0 * rhs.e12i.0) + E23i(self.e13oi.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e1i.0 * rhs.e123oi.0) + E23i(self.e23.0 * rhs.ei.0) + E23i(self.e23oi.0 * rhs.ei.0) - E23i(self.e2i.0 * rhs.e3.0) + E23i(self.e2i.0 * rhs.e3oi.0) + E23i(self.e3i.0 * rhs.e2.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e123i.0 * rhs.e13o.0) + E2oi(self.e123o.0 * rhs.e13i.0) - E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e13.0 * rhs.e123oi.0) + E2oi(self.e13oi.0 * rhs.e123.0) - E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.e23oi.0 * rhs.e3.0) - E2oi(self.e2i.0 * rhs.eo.0) + E2oi(self.e2o.0 * rhs.ei.0) + E2oi(self.e3i.0 * rhs.e23o.0) - E2oi(self.e3o.0 * rhs.e23i.0) + E2oi(self.eoi.0 * rhs.e2.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e12.0 * rhs.e123oi.0) + E3oi(self.e123i.0 * rhs.e12o.0) - E3oi(self.e123o.0 * rhs.e12i.0) - E3oi(self.e12oi.0 * rhs.e123.0) - E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) - E3oi(self.e23oi.0 * rhs.e2.0) - E3oi(self.e2i.0 * rhs.e23o.0) + E3oi(self.e2o.0 * rhs.e23i.0) - E3oi(self.e3i.0 * rhs.eo.0) + E3oi(self.e3o.0 * rhs.ei.0) + E3oi(self.eoi.0 * rhs.e3.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.e12.0 * rhs.e3o.0) + E123o(self.e123o.0 * rhs.eoi.0) + E123o(self.e123o.0 * rhs.s.0) - E123o(self.e12oi.0 * rhs.e3o.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e13oi.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e1o.0 * rhs.e23oi.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e23oi.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e123i.0 * rhs.s.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13.0 * rhs.e2i.0) - E123i(self.e13oi.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) - E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e23.0 * rhs.e1i.0) + E123i(self.e23oi.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e2i.0 * rhs.e13oi.0) + E123i(self.e3i.0 * rhs.e12.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) + E12oi(self.e12oi.0 * rhs.s.0) - E12oi(self.e13.0 * rhs.e23oi.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e23oi.0 * rhs.e13.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0) + E12oi(self.eoi.0 * rhs.e12.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) + E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e13oi.0 * rhs.s.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e23oi.0 * rhs.e12.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0) + E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e23oi.0 * rhs.s.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e123i.0 * rhs.eo.0) + E123oi(self.e123o.0 * rhs.ei.0) + E123oi(self.e12oi.0 * rhs.e3.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e13oi.0 * rhs.e2.0) - E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) + E123oi(self.e23oi.0 * rhs.e1.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e2o.0 * rhs.e13i.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.e3o.0 * rhs.e12i.0) + E123oi(self.eoi.0 * rhs.e123.0) + E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0)
+				- S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13.0 * rhs.e13.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				- S(self.e23oi.0 * rhs.e23oi.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: E1(self.e12.0 * rhs.e2.0)
+				+ E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13.0 * rhs.e3.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0)
+				- E1(self.e1i.0 * rhs.eo.0)
+				- E1(self.e1o.0 * rhs.ei.0)
+				- E1(self.e23.0 * rhs.e123.0)
+				- E1(self.e23oi.0 * rhs.e123oi.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0)
+				+ E1(self.s.0 * rhs.e1.0),
+			e2: -E2(self.e12.0 * rhs.e1.0)
+				- E2(self.e123i.0 * rhs.e13o.0)
+				- E2(self.e123o.0 * rhs.e13i.0)
+				- E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e13.0 * rhs.e123.0)
+				+ E2(self.e13oi.0 * rhs.e123oi.0)
+				- E2(self.e1i.0 * rhs.e12o.0)
+				- E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e23.0 * rhs.e3.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0)
+				- E2(self.e2i.0 * rhs.eo.0)
+				- E2(self.e2o.0 * rhs.ei.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0)
+				+ E2(self.s.0 * rhs.e2.0),
+			e3: -E3(self.e12.0 * rhs.e123.0) + E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e12oi.0 * rhs.e123oi.0)
+				- E3(self.e13.0 * rhs.e1.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e23.0 * rhs.e2.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				- E3(self.e3i.0 * rhs.eo.0)
+				- E3(self.e3o.0 * rhs.ei.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0)
+				+ E3(self.s.0 * rhs.e3.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0)
+				+ Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e123o.0 * rhs.e123oi.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0)
+				- Eo(self.eoi.0 * rhs.eo.0)
+				+ Eo(self.s.0 * rhs.eo.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) + Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e123i.0 * rhs.e123oi.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13.0 * rhs.e13i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e1i.0 * rhs.e1.0)
+				+ Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0)
+				- Ei(self.e2i.0 * rhs.e2.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				- Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0)
+				+ Ei(self.eoi.0 * rhs.ei.0)
+				+ Ei(self.s.0 * rhs.ei.0),
+			e12: E12(self.e12.0 * rhs.s.0)
+				+ E12(self.e123i.0 * rhs.e3o.0)
+				+ E12(self.e123o.0 * rhs.e3i.0)
+				+ E12(self.e12oi.0 * rhs.eoi.0)
+				- E12(self.e13.0 * rhs.e23.0)
+				- E12(self.e13oi.0 * rhs.e23oi.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				+ E12(self.e23oi.0 * rhs.e13oi.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: E13(self.e12.0 * rhs.e23.0) - E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0)
+				+ E13(self.e12oi.0 * rhs.e23oi.0)
+				+ E13(self.e13.0 * rhs.s.0)
+				+ E13(self.e13oi.0 * rhs.eoi.0)
+				+ E13(self.e1i.0 * rhs.e3o.0)
+				+ E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e23oi.0 * rhs.e12oi.0)
+				- E13(self.e2i.0 * rhs.e123o.0)
+				- E13(self.e2o.0 * rhs.e123i.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0)
+				- E1o(self.e123o.0 * rhs.e23.0)
+				- E1o(self.e123o.0 * rhs.e23oi.0)
+				- E1o(self.e12oi.0 * rhs.e2o.0)
+				+ E1o(self.e13.0 * rhs.e3o.0)
+				- E1o(self.e13oi.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.eoi.0)
+				+ E1o(self.e1o.0 * rhs.s.0)
+				- E1o(self.e23.0 * rhs.e123o.0)
+				+ E1o(self.e23oi.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e2o.0 * rhs.e12oi.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.e3o.0 * rhs.e13oi.0)
+				- E1o(self.eoi.0 * rhs.e1o.0)
+				+ E1o(self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) - E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e123i.0 * rhs.e23oi.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13.0 * rhs.e3i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				+ E1i(self.e1i.0 * rhs.s.0)
+				- E1i(self.e23.0 * rhs.e123i.0)
+				- E1i(self.e23oi.0 * rhs.e123i.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) + E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0)
+				- E23(self.e12oi.0 * rhs.e13oi.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e13oi.0 * rhs.e12oi.0)
+				+ E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0)
+				+ E2o(self.e123o.0 * rhs.e13.0)
+				+ E2o(self.e123o.0 * rhs.e13oi.0)
+				+ E2o(self.e12oi.0 * rhs.e1o.0)
+				+ E2o(self.e13.0 * rhs.e123o.0)
+				- E2o(self.e13oi.0 * rhs.e123o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e1o.0 * rhs.e12oi.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				- E2o(self.e23oi.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				+ E2o(self.e2o.0 * rhs.s.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.e3o.0 * rhs.e23oi.0)
+				- E2o(self.eoi.0 * rhs.e2o.0)
+				+ E2o(self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) + E2i(self.e123i.0 * rhs.e13.0)
+				- E2i(self.e123i.0 * rhs.e13oi.0)
+				- E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e13.0 * rhs.e123i.0)
+				+ E2i(self.e13oi.0 * rhs.e123i.0)
+				+ E2i(self.e1i.0 * rhs.e12.0)
+				- E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e123o.0 * rhs.e12.0) - E3o(self.e123o.0 * rhs.e12oi.0)
+				+ E3o(self.e12oi.0 * rhs.e123o.0)
+				- E3o(self.e13.0 * rhs.e1o.0)
+				+ E3o(self.e13oi.0 * rhs.e1o.0)
+				+ E3o(self.e1o.0 * rhs.e13.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				- E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e23oi.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				+ E3o(self.e3o.0 * rhs.s.0)
+				- E3o(self.eoi.0 * rhs.e3o.0)
+				+ E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e123i.0 * rhs.e12.0) + E3i(self.e123i.0 * rhs.e12oi.0)
+				- E3i(self.e12oi.0 * rhs.e123i.0)
+				- E3i(self.e13.0 * rhs.e1i.0)
+				- E3i(self.e13oi.0 * rhs.e1i.0)
+				+ E3i(self.e1i.0 * rhs.e13.0)
+				- E3i(self.e1i.0 * rhs.e13oi.0)
+				- E3i(self.e23.0 * rhs.e2i.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0)
+				- Eoi(self.e12oi.0 * rhs.e12.0)
+				- Eoi(self.e13.0 * rhs.e13oi.0)
+				- Eoi(self.e13oi.0 * rhs.e13.0)
+				+ Eoi(self.e1i.0 * rhs.e1o.0)
+				- Eoi(self.e1o.0 * rhs.e1i.0)
+				- Eoi(self.e23.0 * rhs.e23oi.0)
+				- Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0)
+				+ Eoi(self.eoi.0 * rhs.s.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123: E123(self.e12.0 * rhs.e3.0) - E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0)
+				+ E123(self.e12oi.0 * rhs.e3oi.0)
+				- E123(self.e13.0 * rhs.e2.0)
+				- E123(self.e13oi.0 * rhs.e2oi.0)
+				- E123(self.e1i.0 * rhs.e23o.0)
+				- E123(self.e1o.0 * rhs.e23i.0)
+				+ E123(self.e23.0 * rhs.e1.0)
+				+ E123(self.e23oi.0 * rhs.e1oi.0)
+				+ E123(self.e2i.0 * rhs.e13o.0)
+				+ E123(self.e2o.0 * rhs.e13i.0)
+				- E123(self.e3i.0 * rhs.e12o.0)
+				- E123(self.e3o.0 * rhs.e12i.0)
+				+ E123(self.eoi.0 * rhs.e123oi.0)
+				+ E123(self.s.0 * rhs.e123.0),
+			e12o: E12o(self.e12.0 * rhs.eo.0)
+				- E12o(self.e123o.0 * rhs.e3.0)
+				- E12o(self.e123o.0 * rhs.e3oi.0)
+				- E12o(self.e12oi.0 * rhs.eo.0)
+				- E12o(self.e13.0 * rhs.e23o.0)
+				+ E12o(self.e13oi.0 * rhs.e23o.0)
+				- E12o(self.e1o.0 * rhs.e2.0)
+				- E12o(self.e1o.0 * rhs.e2oi.0)
+				+ E12o(self.e23.0 * rhs.e13o.0)
+				- E12o(self.e23oi.0 * rhs.e13o.0)
+				+ E12o(self.e2o.0 * rhs.e1.0)
+				+ E12o(self.e2o.0 * rhs.e1oi.0)
+				- E12o(self.e3o.0 * rhs.e123.0)
+				- E12o(self.e3o.0 * rhs.e123oi.0)
+				- E12o(self.eoi.0 * rhs.e12o.0)
+				+ E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e12.0 * rhs.ei.0) - E12i(self.e123i.0 * rhs.e3.0)
+				+ E12i(self.e123i.0 * rhs.e3oi.0)
+				+ E12i(self.e12oi.0 * rhs.ei.0)
+				- E12i(self.e13.0 * rhs.e23i.0)
+				- E12i(self.e13oi.0 * rhs.e23i.0)
+				- E12i(self.e1i.0 * rhs.e2.0)
+				+ E12i(self.e1i.0 * rhs.e2oi.0)
+				+ E12i(self.e23.0 * rhs.e13i.0)
+				+ E12i(self.e23oi.0 * rhs.e13i.0)
+				+ E12i(self.e2i.0 * rhs.e1.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				- E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.e3i.0 * rhs.e123oi.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0)
+				+ E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) + E13o(self.e123o.0 * rhs.e2.0) + E13o(self.e123o.0 * rhs.e2oi.0)
+				- E13o(self.e12oi.0 * rhs.e23o.0)
+				+ E13o(self.e13.0 * rhs.eo.0)
+				- E13o(self.e13oi.0 * rhs.eo.0)
+				- E13o(self.e1o.0 * rhs.e3.0)
+				- E13o(self.e1o.0 * rhs.e3oi.0)
+				- E13o(self.e23.0 * rhs.e12o.0)
+				+ E13o(self.e23oi.0 * rhs.e12o.0)
+				+ E13o(self.e2o.0 * rhs.e123.0)
+				+ E13o(self.e2o.0 * rhs.e123oi.0)
+				+ E13o(self.e3o.0 * rhs.e1.0)
+				+ E13o(self.e3o.0 * rhs.e1oi.0)
+				- E13o(self.eoi.0 * rhs.e13o.0)
+				+ E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e123i.0 * rhs.e2.0) - E13i(self.e123i.0 * rhs.e2oi.0)
+				+ E13i(self.e12oi.0 * rhs.e23i.0)
+				+ E13i(self.e13.0 * rhs.ei.0)
+				+ E13i(self.e13oi.0 * rhs.ei.0)
+				- E13i(self.e1i.0 * rhs.e3.0)
+				+ E13i(self.e1i.0 * rhs.e3oi.0)
+				- E13i(self.e23.0 * rhs.e12i.0)
+				- E13i(self.e23oi.0 * rhs.e12i.0)
+				+ E13i(self.e2i.0 * rhs.e123.0)
+				- E13i(self.e2i.0 * rhs.e123oi.0)
+				+ E13i(self.e3i.0 * rhs.e1.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0)
+				+ E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e123i.0 * rhs.e23o.0) - E1oi(self.e123o.0 * rhs.e23i.0)
+				+ E1oi(self.e12oi.0 * rhs.e2.0)
+				+ E1oi(self.e13.0 * rhs.e3oi.0)
+				+ E1oi(self.e13oi.0 * rhs.e3.0)
+				- E1oi(self.e1i.0 * rhs.eo.0)
+				+ E1oi(self.e1o.0 * rhs.ei.0)
+				- E1oi(self.e23.0 * rhs.e123oi.0)
+				- E1oi(self.e23oi.0 * rhs.e123.0)
+				+ E1oi(self.e2i.0 * rhs.e12o.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				+ E1oi(self.e3i.0 * rhs.e13o.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0)
+				+ E1oi(self.eoi.0 * rhs.e1.0)
+				+ E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) - E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e123o.0 * rhs.e1oi.0)
+				+ E23o(self.e12oi.0 * rhs.e13o.0)
+				+ E23o(self.e13.0 * rhs.e12o.0)
+				- E23o(self.e13oi.0 * rhs.e12o.0)
+				- E23o(self.e1o.0 * rhs.e123.0)
+				- E23o(self.e1o.0 * rhs.e123oi.0)
+				+ E23o(self.e23.0 * rhs.eo.0)
+				- E23o(self.e23oi.0 * rhs.eo.0)
+				- E23o(self.e2o.0 * rhs.e3.0)
+				- E23o(self.e2o.0 * rhs.e3oi.0)
+				+ E23o(self.e3o.0 * rhs.e2.0)
+				+ E23o(self.e3o.0 * rhs.e2oi.0)
+				- E23o(self.eoi.0 * rhs.e23o.0)
+				+ E23o(self.s.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) - E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e123i.0 * rhs.e1oi.0)
+				- E23i(self.e12oi.0 * rhs.e13i.0)
+				+ E23i(self.e13.0 * rhs.e12i.0)
+				+ E23i(self.e13oi.0 * rhs.e12i.0)
+				- E23i(self.e1i.0 * rhs.e123.0)
+				+ E23i(self.e1i.0 * rhs.e123oi.0)
+				+ E23i(self.e23.0 * rhs.ei.0)
+				+ E23i(self.e23oi.0 * rhs.ei.0)
+				- E23i(self.e2i.0 * rhs.e3.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				+ E23i(self.e3i.0 * rhs.e2.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0)
+				+ E23i(self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e123i.0 * rhs.e13o.0) + E2oi(self.e123o.0 * rhs.e13i.0)
+				- E2oi(self.e12oi.0 * rhs.e1.0)
+				+ E2oi(self.e13.0 * rhs.e123oi.0)
+				+ E2oi(self.e13oi.0 * rhs.e123.0)
+				- E2oi(self.e1i.0 * rhs.e12o.0)
+				+ E2oi(self.e1o.0 * rhs.e12i.0)
+				+ E2oi(self.e23.0 * rhs.e3oi.0)
+				+ E2oi(self.e23oi.0 * rhs.e3.0)
+				- E2oi(self.e2i.0 * rhs.eo.0)
+				+ E2oi(self.e2o.0 * rhs.ei.0)
+				+ E2oi(self.e3i.0 * rhs.e23o.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0)
+				+ E2oi(self.eoi.0 * rhs.e2.0)
+				+ E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e12.0 * rhs.e123oi.0) + E3oi(self.e123i.0 * rhs.e12o.0)
+				- E3oi(self.e123o.0 * rhs.e12i.0)
+				- E3oi(self.e12oi.0 * rhs.e123.0)
+				- E3oi(self.e13.0 * rhs.e1oi.0)
+				- E3oi(self.e13oi.0 * rhs.e1.0)
+				- E3oi(self.e1i.0 * rhs.e13o.0)
+				+ E3oi(self.e1o.0 * rhs.e13i.0)
+				- E3oi(self.e23.0 * rhs.e2oi.0)
+				- E3oi(self.e23oi.0 * rhs.e2.0)
+				- E3oi(self.e2i.0 * rhs.e23o.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0)
+				- E3oi(self.e3i.0 * rhs.eo.0)
+				+ E3oi(self.e3o.0 * rhs.ei.0)
+				+ E3oi(self.eoi.0 * rhs.e3.0)
+				+ E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: E123o(self.e12.0 * rhs.e3o.0) + E123o(self.e123o.0 * rhs.eoi.0) + E123o(self.e123o.0 * rhs.s.0)
+				- E123o(self.e12oi.0 * rhs.e3o.0)
+				- E123o(self.e13.0 * rhs.e2o.0)
+				+ E123o(self.e13oi.0 * rhs.e2o.0)
+				+ E123o(self.e1o.0 * rhs.e23.0)
+				+ E123o(self.e1o.0 * rhs.e23oi.0)
+				+ E123o(self.e23.0 * rhs.e1o.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0)
+				- E123o(self.e2o.0 * rhs.e13.0)
+				- E123o(self.e2o.0 * rhs.e13oi.0)
+				+ E123o(self.e3o.0 * rhs.e12.0)
+				+ E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0)
+				+ E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e12.0 * rhs.e3i.0) - E123i(self.e123i.0 * rhs.eoi.0)
+				+ E123i(self.e123i.0 * rhs.s.0)
+				+ E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13.0 * rhs.e2i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				+ E123i(self.e1i.0 * rhs.e23.0)
+				- E123i(self.e1i.0 * rhs.e23oi.0)
+				+ E123i(self.e23.0 * rhs.e1i.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0)
+				- E123i(self.e2i.0 * rhs.e13.0)
+				+ E123i(self.e2i.0 * rhs.e13oi.0)
+				+ E123i(self.e3i.0 * rhs.e12.0)
+				- E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0)
+				+ E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0)
+				+ E12oi(self.e12oi.0 * rhs.s.0)
+				- E12oi(self.e13.0 * rhs.e23oi.0)
+				- E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e1i.0 * rhs.e2o.0)
+				- E12oi(self.e1o.0 * rhs.e2i.0)
+				+ E12oi(self.e23.0 * rhs.e13oi.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0)
+				- E12oi(self.e2i.0 * rhs.e1o.0)
+				+ E12oi(self.e2o.0 * rhs.e1i.0)
+				+ E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0)
+				+ E12oi(self.eoi.0 * rhs.e12.0)
+				+ E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e123i.0 * rhs.e2o.0)
+				+ E13oi(self.e123o.0 * rhs.e2i.0)
+				+ E13oi(self.e12oi.0 * rhs.e23.0)
+				+ E13oi(self.e13.0 * rhs.eoi.0)
+				+ E13oi(self.e13oi.0 * rhs.s.0)
+				+ E13oi(self.e1i.0 * rhs.e3o.0)
+				- E13oi(self.e1o.0 * rhs.e3i.0)
+				- E13oi(self.e23.0 * rhs.e12oi.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0)
+				- E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0)
+				- E13oi(self.e3i.0 * rhs.e1o.0)
+				+ E13oi(self.e3o.0 * rhs.e1i.0)
+				+ E13oi(self.eoi.0 * rhs.e13.0)
+				+ E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e123i.0 * rhs.e1o.0)
+				- E23oi(self.e123o.0 * rhs.e1i.0)
+				- E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13.0 * rhs.e12oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0)
+				+ E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0)
+				+ E23oi(self.e23.0 * rhs.eoi.0)
+				+ E23oi(self.e23oi.0 * rhs.s.0)
+				+ E23oi(self.e2i.0 * rhs.e3o.0)
+				- E23oi(self.e2o.0 * rhs.e3i.0)
+				- E23oi(self.e3i.0 * rhs.e2o.0)
+				+ E23oi(self.e3o.0 * rhs.e2i.0)
+				+ E23oi(self.eoi.0 * rhs.e23.0)
+				+ E23oi(self.s.0 * rhs.e23oi.0),
+			e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e123i.0 * rhs.eo.0)
+				+ E123oi(self.e123o.0 * rhs.ei.0)
+				+ E123oi(self.e12oi.0 * rhs.e3.0)
+				- E123oi(self.e13.0 * rhs.e2oi.0)
+				- E123oi(self.e13oi.0 * rhs.e2.0)
+				- E123oi(self.e1i.0 * rhs.e23o.0)
+				+ E123oi(self.e1o.0 * rhs.e23i.0)
+				+ E123oi(self.e23.0 * rhs.e1oi.0)
+				+ E123oi(self.e23oi.0 * rhs.e1.0)
+				+ E123oi(self.e2i.0 * rhs.e13o.0)
+				- E123oi(self.e2o.0 * rhs.e13i.0)
+				- E123oi(self.e3i.0 * rhs.e12o.0)
+				+ E123oi(self.e3o.0 * rhs.e12i.0)
+				+ E123oi(self.eoi.0 * rhs.e123.0)
+				+ E123oi(self.s.0 * rhs.e123oi.0),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Versor {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Versor.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Versor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12.0 * rhs.e3oi.0) - S(self.e123i.0 * rhs.eo.0) + S(self.e123o.0 * rhs.ei.0) + S(self.e12oi.0 * rhs.e3.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e13oi.0 * rhs.e2.0) - S(self.e1i.0 * rhs.e23o.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) + S(self.e23oi.0 * rhs.e1.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e2o.0 * rhs.e13i.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.e3o.0 * rhs.e12i.0) + S(self.eoi.0 * rhs.e123.0) + S(self.s.0 * rhs.e123oi.0),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e13oi.0 * rhs.e12.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0) + E1(self.e23.0 * rhs.eoi.0) + E1(self.e23oi.0 * rhs.s.0) + E1(self.e2i.0 * rhs.e3o.0) - E1(self.e2o.0 * rhs.e3i.0) - E1(self.e3i.0 * rhs.e2o.0) + E1(self.e3o.0 * rhs.e2i.0) + E1(self.eoi.0 * rhs.e23.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e13.0 * rhs.eoi.0) - E2(self.e13oi.0 * rhs.s.0) - E2(self.e1i.0 * rhs.e3o.0) + E2(self.e1o.0 * rhs.e3i.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e23oi.0 * rhs.e12.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0) + E2(self.e3i.0 * rhs.e1o.0) - E2(self.e3o.0 * rhs.e1i.0) - E2(self.eoi.0 * rhs.e13.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e12.0 * rhs.eoi.0) - E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e12oi.0 * rhs.s.0) + E3(self.e13.0 * rhs.e23oi.0) + E3(self.e13oi.0 * rhs.e23.0) + E3(self.e1i.0 * rhs.e2o.0) - E3(self.e1o.0 * rhs.e2i.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e23oi.0 * rhs.e13.0) - E3(self.e2i.0 * rhs.e1o.0) + E3(self.e2o.0 * rhs.e1i.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0) + E3(self.eoi.0 * rhs.e12.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e123o.0 * rhs.s.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13.0 * rhs.e2o.0) + Eo(self.e13oi.0 * rhs.e2o.0) - Eo(self.e1o.0 * rhs.e23.0) + Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e23.0 * rhs.e1o.0) - Eo(self.e23oi.0 * rhs.e1o.0) + Eo(self.e2o.0 * rhs.e13.0) - Eo(self.e2o.0 * rhs.e13oi.0) - Eo(self.e3o.0 * rhs.e12.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0) - Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e12.0 * rhs.e3i.0) + Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0) - Ei(self.e12oi.0 * rhs.e3i.0) - Ei(self.e13.0 * rhs.e2i.0) + Ei(self.e13oi.0 * rhs.e2i.0) + Ei(self.e1i.0 * rhs.e23.0) + Ei(self.e1i.0 * rhs.e23oi.0) + Ei(self.e23.0 * rhs.e1i.0) - Ei(self.e23oi.0 * rhs.e1i.0) - Ei(self.e2i.0 * rhs.e13.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : E12(self.e12.0 * rhs.e123oi.0) - E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0) - E12(self.e13.0 * rhs.e1oi.0) - E12(self.e13oi.0 * rhs.e1.0) - E12(self.e1i.0 * rhs.e13o.0) + E12(self.e1o.0 * rhs.e13i.0) - E12(self.e23.0 * rhs.e2oi.0) - E12(self.e23oi.0 * rhs.e2.0) - E12(self.e2i.0 * rhs.e23o.0) + E12(self.e2o.0 * rhs.e23i.0) + E12(self.e3i.0 * rhs.eo.0) - E12(self.e3o.0 * rhs.ei.0) - E12(self.eoi.0 * rhs.e3.0) - E12(self.s.0 * rhs.e3oi.0),
+		//     e13   : E13(self.e12.0 * rhs.e1oi.0) - E13(self.e123i.0 * rhs.e13o.0) + E13(self.e123o.0 * rhs.e13i.0) + E13(self.e12oi.0 * rhs.e1.0) + E13(self.e13.0 * rhs.e123oi.0) + E13(self.e13oi.0 * rhs.e123.0) + E13(self.e1i.0 * rhs.e12o.0) - E13(self.e1o.0 * rhs.e12i.0) - E13(self.e23.0 * rhs.e3oi.0) - E13(self.e23oi.0 * rhs.e3.0) - E13(self.e2i.0 * rhs.eo.0) + E13(self.e2o.0 * rhs.ei.0) - E13(self.e3i.0 * rhs.e23o.0) + E13(self.e3o.0 * rhs.e23i.0) + E13(self.eoi.0 * rhs.e2.0) + E13(self.s.0 * rhs.e2oi.0),
+		//     e1o   : -E1o(self.e12.0 * rhs.e13o.0) - E1o(self.e123o.0 * rhs.e1.0) + E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13.0 * rhs.e12o.0) + E1o(self.e13oi.0 * rhs.e12o.0) - E1o(self.e1o.0 * rhs.e123.0) + E1o(self.e1o.0 * rhs.e123oi.0) - E1o(self.e23.0 * rhs.eo.0) - E1o(self.e23oi.0 * rhs.eo.0) + E1o(self.e2o.0 * rhs.e3.0) - E1o(self.e2o.0 * rhs.e3oi.0) - E1o(self.e3o.0 * rhs.e2.0) + E1o(self.e3o.0 * rhs.e2oi.0) - E1o(self.eoi.0 * rhs.e23o.0) - E1o(self.s.0 * rhs.e23o.0),
+		//     e1i   : E1i(self.e12.0 * rhs.e13i.0) + E1i(self.e123i.0 * rhs.e1.0) + E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) - E1i(self.e13.0 * rhs.e12i.0) + E1i(self.e13oi.0 * rhs.e12i.0) + E1i(self.e1i.0 * rhs.e123.0) + E1i(self.e1i.0 * rhs.e123oi.0) + E1i(self.e23.0 * rhs.ei.0) - E1i(self.e23oi.0 * rhs.ei.0) - E1i(self.e2i.0 * rhs.e3.0) - E1i(self.e2i.0 * rhs.e3oi.0) + E1i(self.e3i.0 * rhs.e2.0) + E1i(self.e3i.0 * rhs.e2oi.0) - E1i(self.eoi.0 * rhs.e23i.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23   : E23(self.e12.0 * rhs.e2oi.0) - E23(self.e123i.0 * rhs.e23o.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e12oi.0 * rhs.e2.0) + E23(self.e13.0 * rhs.e3oi.0) + E23(self.e13oi.0 * rhs.e3.0) + E23(self.e1i.0 * rhs.eo.0) - E23(self.e1o.0 * rhs.ei.0) + E23(self.e23.0 * rhs.e123oi.0) + E23(self.e23oi.0 * rhs.e123.0) + E23(self.e2i.0 * rhs.e12o.0) - E23(self.e2o.0 * rhs.e12i.0) + E23(self.e3i.0 * rhs.e13o.0) - E23(self.e3o.0 * rhs.e13i.0) - E23(self.eoi.0 * rhs.e1.0) - E23(self.s.0 * rhs.e1oi.0),
+		//     e2o   : -E2o(self.e12.0 * rhs.e23o.0) - E2o(self.e123o.0 * rhs.e2.0) + E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e13.0 * rhs.eo.0) + E2o(self.e13oi.0 * rhs.eo.0) - E2o(self.e1o.0 * rhs.e3.0) + E2o(self.e1o.0 * rhs.e3oi.0) + E2o(self.e23.0 * rhs.e12o.0) + E2o(self.e23oi.0 * rhs.e12o.0) - E2o(self.e2o.0 * rhs.e123.0) + E2o(self.e2o.0 * rhs.e123oi.0) + E2o(self.e3o.0 * rhs.e1.0) - E2o(self.e3o.0 * rhs.e1oi.0) + E2o(self.eoi.0 * rhs.e13o.0) + E2o(self.s.0 * rhs.e13o.0),
+		//     e2i   : E2i(self.e12.0 * rhs.e23i.0) + E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) - E2i(self.e13.0 * rhs.ei.0) + E2i(self.e13oi.0 * rhs.ei.0) + E2i(self.e1i.0 * rhs.e3.0) + E2i(self.e1i.0 * rhs.e3oi.0) - E2i(self.e23.0 * rhs.e12i.0) + E2i(self.e23oi.0 * rhs.e12i.0) + E2i(self.e2i.0 * rhs.e123.0) + E2i(self.e2i.0 * rhs.e123oi.0) - E2i(self.e3i.0 * rhs.e1.0) - E2i(self.e3i.0 * rhs.e1oi.0) + E2i(self.eoi.0 * rhs.e13i.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.eo.0) - E3o(self.e123o.0 * rhs.e3.0) + E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e12oi.0 * rhs.eo.0) - E3o(self.e13.0 * rhs.e23o.0) - E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e1o.0 * rhs.e2.0) - E3o(self.e1o.0 * rhs.e2oi.0) + E3o(self.e23.0 * rhs.e13o.0) + E3o(self.e23oi.0 * rhs.e13o.0) - E3o(self.e2o.0 * rhs.e1.0) + E3o(self.e2o.0 * rhs.e1oi.0) - E3o(self.e3o.0 * rhs.e123.0) + E3o(self.e3o.0 * rhs.e123oi.0) - E3o(self.eoi.0 * rhs.e12o.0) - E3o(self.s.0 * rhs.e12o.0),
+		//     e3i   : E3i(self.e12.0 * rhs.ei.0) + E3i(self.e123i.0 * rhs.e3.0) + E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e12oi.0 * rhs.ei.0) + E3i(self.e13.0 * rhs.e23i.0) - E3i(self.e13oi.0 * rhs.e23i.0) - E3i(self.e1i.0 * rhs.e2.0) - E3i(self.e1i.0 * rhs.e2oi.0) - E3i(self.e23.0 * rhs.e13i.0) + E3i(self.e23oi.0 * rhs.e13i.0) + E3i(self.e2i.0 * rhs.e1.0) + E3i(self.e2i.0 * rhs.e1oi.0) + E3i(self.e3i.0 * rhs.e123.0) + E3i(self.e3i.0 * rhs.e123oi.0) - E3i(self.eoi.0 * rhs.e12i.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi   : Eoi(self.e12.0 * rhs.e3.0) + Eoi(self.e123i.0 * rhs.eo.0) + Eoi(self.e123o.0 * rhs.ei.0) + Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13.0 * rhs.e2.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e1i.0 * rhs.e23o.0) + Eoi(self.e1o.0 * rhs.e23i.0) + Eoi(self.e23.0 * rhs.e1.0) + Eoi(self.e23oi.0 * rhs.e1oi.0) - Eoi(self.e2i.0 * rhs.e13o.0) - Eoi(self.e2o.0 * rhs.e13i.0) + Eoi(self.e3i.0 * rhs.e12o.0) + Eoi(self.e3o.0 * rhs.e12i.0) + Eoi(self.eoi.0 * rhs.e123oi.0) + Eoi(self.s.0 * rhs.e123.0),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) - E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0) + E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e1i.0 * rhs.e1o.0) - E123(self.e1o.0 * rhs.e1i.0) + E123(self.e23.0 * rhs.e23oi.0) + E123(self.e23oi.0 * rhs.e23.0) + E123(self.e2i.0 * rhs.e2o.0) - E123(self.e2o.0 * rhs.e2i.0) + E123(self.e3i.0 * rhs.e3o.0) - E123(self.e3o.0 * rhs.e3i.0) - E123(self.eoi.0 * rhs.s.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) - E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0) + E12o(self.e13.0 * rhs.e1o.0) + E12o(self.e13oi.0 * rhs.e1o.0) - E12o(self.e1o.0 * rhs.e13.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e23.0 * rhs.e2o.0) + E12o(self.e23oi.0 * rhs.e2o.0) - E12o(self.e2o.0 * rhs.e23.0) + E12o(self.e2o.0 * rhs.e23oi.0) - E12o(self.e3o.0 * rhs.eoi.0) + E12o(self.e3o.0 * rhs.s.0) + E12o(self.eoi.0 * rhs.e3o.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0) - E12i(self.e13.0 * rhs.e1i.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e1i.0 * rhs.e13.0) + E12i(self.e1i.0 * rhs.e13oi.0) - E12i(self.e23.0 * rhs.e2i.0) + E12i(self.e23oi.0 * rhs.e2i.0) + E12i(self.e2i.0 * rhs.e23.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.eoi.0) - E12i(self.e3i.0 * rhs.s.0) + E12i(self.eoi.0 * rhs.e3i.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e123o.0 * rhs.e13.0) + E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e12oi.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e13oi.0 * rhs.e123o.0) + E13o(self.e1o.0 * rhs.e12.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e23.0 * rhs.e3o.0) + E13o(self.e23oi.0 * rhs.e3o.0) + E13o(self.e2o.0 * rhs.eoi.0) - E13o(self.e2o.0 * rhs.s.0) - E13o(self.e3o.0 * rhs.e23.0) + E13o(self.e3o.0 * rhs.e23oi.0) - E13o(self.eoi.0 * rhs.e2o.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e13oi.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12.0) - E13i(self.e1i.0 * rhs.e12oi.0) - E13i(self.e23.0 * rhs.e3i.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.eoi.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23.0) + E13i(self.e3i.0 * rhs.e23oi.0) - E13i(self.eoi.0 * rhs.e2i.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e13.0) + E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13.0 * rhs.e12.0) - E1oi(self.e13oi.0 * rhs.e12oi.0) + E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.e23.0 * rhs.s.0) + E1oi(self.e23oi.0 * rhs.eoi.0) - E1oi(self.e2i.0 * rhs.e3o.0) - E1oi(self.e2o.0 * rhs.e3i.0) + E1oi(self.e3i.0 * rhs.e2o.0) + E1oi(self.e3o.0 * rhs.e2i.0) + E1oi(self.eoi.0 * rhs.e23oi.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13.0 * rhs.e3o.0) - E23o(self.e13oi.0 * rhs.e3o.0) - E23o(self.e1o.0 * rhs.eoi.0) + E23o(self.e1o.0 * rhs.s.0) - E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e23oi.0 * rhs.e123o.0) + E23o(self.e2o.0 * rhs.e12.0) - E23o(self.e2o.0 * rhs.e12oi.0) + E23o(self.e3o.0 * rhs.e13.0) - E23o(self.e3o.0 * rhs.e13oi.0) + E23o(self.eoi.0 * rhs.e1o.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e12oi.0 * rhs.e2i.0) + E23i(self.e13.0 * rhs.e3i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.eoi.0) - E23i(self.e1i.0 * rhs.s.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e23oi.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13.0) - E23i(self.e3i.0 * rhs.e13oi.0) + E23i(self.eoi.0 * rhs.e1i.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e12.0 * rhs.e23.0) + E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) + E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e13.0 * rhs.s.0) - E2oi(self.e13oi.0 * rhs.eoi.0) + E2oi(self.e1i.0 * rhs.e3o.0) + E2oi(self.e1o.0 * rhs.e3i.0) - E2oi(self.e23.0 * rhs.e12.0) - E2oi(self.e23oi.0 * rhs.e12oi.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.e3i.0 * rhs.e1o.0) - E2oi(self.e3o.0 * rhs.e1i.0) - E2oi(self.eoi.0 * rhs.e13oi.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e13.0 * rhs.e23.0) + E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e1i.0 * rhs.e2o.0) - E3oi(self.e1o.0 * rhs.e2i.0) - E3oi(self.e23.0 * rhs.e13.0) - E3oi(self.e23oi.0 * rhs.e13oi.0) + E3oi(self.e2i.0 * rhs.e1o.0) + E3oi(self.e2o.0 * rhs.e1i.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e123o.0 * rhs.e123.0) + E123o(self.e123o.0 * rhs.e123oi.0) - E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e13oi.0 * rhs.e13o.0) + E123o(self.e1o.0 * rhs.e1.0) - E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e23.0 * rhs.e23o.0) - E123o(self.e23oi.0 * rhs.e23o.0) + E123o(self.e2o.0 * rhs.e2.0) - E123o(self.e2o.0 * rhs.e2oi.0) + E123o(self.e3o.0 * rhs.e3.0) - E123o(self.e3o.0 * rhs.e3oi.0) + E123o(self.eoi.0 * rhs.eo.0) + E123o(self.s.0 * rhs.eo.0),
+		//     e123i : E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e123i.0 * rhs.e123.0) + E123i(self.e123i.0 * rhs.e123oi.0) - E123i(self.e12oi.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e23oi.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3.0) - E123i(self.e3i.0 * rhs.e3oi.0) + E123i(self.eoi.0 * rhs.ei.0) - E123i(self.s.0 * rhs.ei.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.e123.0) + E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0) + E12oi(self.e12oi.0 * rhs.e123oi.0) - E12oi(self.e13.0 * rhs.e1.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) + E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e1o.0 * rhs.e13i.0) - E12oi(self.e23.0 * rhs.e2.0) - E12oi(self.e23oi.0 * rhs.e2oi.0) + E12oi(self.e2i.0 * rhs.e23o.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.e3i.0 * rhs.eo.0) - E12oi(self.e3o.0 * rhs.ei.0) - E12oi(self.eoi.0 * rhs.e3oi.0) - E12oi(self.s.0 * rhs.e3.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e1.0) + E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) + E13oi(self.e13.0 * rhs.e123.0) + E13oi(self.e13oi.0 * rhs.e123oi.0) - E13oi(self.e1i.0 * rhs.e12o.0) - E13oi(self.e1o.0 * rhs.e12i.0) - E13oi(self.e23.0 * rhs.e3.0) - E13oi(self.e23oi.0 * rhs.e3oi.0) + E13oi(self.e2i.0 * rhs.eo.0) + E13oi(self.e2o.0 * rhs.ei.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0) + E13oi(self.s.0 * rhs.e2.0),
+		//     e23oi : E23oi(self.e12.0 * rhs.e2.0) + E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13.0 * rhs.e3.0) + E23oi(self.e13oi.0 * rhs.e3oi.0) - E23oi(self.e1i.0 * rhs.eo.0) - E23oi(self.e1o.0 * rhs.ei.0) + E23oi(self.e23.0 * rhs.e123.0) + E23oi(self.e23oi.0 * rhs.e123oi.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0) - E23oi(self.s.0 * rhs.e1.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) + E123oi(self.e23oi.0 * rhs.e23oi.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: S(self.e12.0 * rhs.e3oi.0) - S(self.e123i.0 * rhs.eo.0)
+				+ S(self.e123o.0 * rhs.ei.0)
+				+ S(self.e12oi.0 * rhs.e3.0)
+				- S(self.e13.0 * rhs.e2oi.0)
+				- S(self.e13oi.0 * rhs.e2.0)
+				- S(self.e1i.0 * rhs.e23o.0)
+				+ S(self.e1o.0 * rhs.e23i.0)
+				+ S(self.e23.0 * rhs.e1oi.0)
+				+ S(self.e23oi.0 * rhs.e1.0)
+				+ S(self.e2i.0 * rhs.e13o.0)
+				- S(self.e2o.0 * rhs.e13i.0)
+				- S(self.e3i.0 * rhs.e12o.0)
+				+ S(self.e3o.0 * rhs.e12i.0)
+				+ S(self.eoi.0 * rhs.e123.0)
+				+ S(self.s.0 * rhs.e123oi.0),
+			e1: E1(self.e12.0 * rhs.e13oi.0) - E1(self.e123i.0 * rhs.e1o.0)
+				+ E1(self.e123o.0 * rhs.e1i.0)
+				+ E1(self.e12oi.0 * rhs.e13.0)
+				- E1(self.e13.0 * rhs.e12oi.0)
+				- E1(self.e13oi.0 * rhs.e12.0)
+				- E1(self.e1i.0 * rhs.e123o.0)
+				+ E1(self.e1o.0 * rhs.e123i.0)
+				+ E1(self.e23.0 * rhs.eoi.0)
+				+ E1(self.e23oi.0 * rhs.s.0)
+				+ E1(self.e2i.0 * rhs.e3o.0)
+				- E1(self.e2o.0 * rhs.e3i.0)
+				- E1(self.e3i.0 * rhs.e2o.0)
+				+ E1(self.e3o.0 * rhs.e2i.0)
+				+ E1(self.eoi.0 * rhs.e23.0)
+				+ E1(self.s.0 * rhs.e23oi.0),
+			e2: E2(self.e12.0 * rhs.e23oi.0) - E2(self.e123i.0 * rhs.e2o.0)
+				+ E2(self.e123o.0 * rhs.e2i.0)
+				+ E2(self.e12oi.0 * rhs.e23.0)
+				- E2(self.e13.0 * rhs.eoi.0)
+				- E2(self.e13oi.0 * rhs.s.0)
+				- E2(self.e1i.0 * rhs.e3o.0)
+				+ E2(self.e1o.0 * rhs.e3i.0)
+				- E2(self.e23.0 * rhs.e12oi.0)
+				- E2(self.e23oi.0 * rhs.e12.0)
+				- E2(self.e2i.0 * rhs.e123o.0)
+				+ E2(self.e2o.0 * rhs.e123i.0)
+				+ E2(self.e3i.0 * rhs.e1o.0)
+				- E2(self.e3o.0 * rhs.e1i.0)
+				- E2(self.eoi.0 * rhs.e13.0)
+				- E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e12.0 * rhs.eoi.0) - E3(self.e123i.0 * rhs.e3o.0)
+				+ E3(self.e123o.0 * rhs.e3i.0)
+				+ E3(self.e12oi.0 * rhs.s.0)
+				+ E3(self.e13.0 * rhs.e23oi.0)
+				+ E3(self.e13oi.0 * rhs.e23.0)
+				+ E3(self.e1i.0 * rhs.e2o.0)
+				- E3(self.e1o.0 * rhs.e2i.0)
+				- E3(self.e23.0 * rhs.e13oi.0)
+				- E3(self.e23oi.0 * rhs.e13.0)
+				- E3(self.e2i.0 * rhs.e1o.0)
+				+ E3(self.e2o.0 * rhs.e1i.0)
+				- E3(self.e3i.0 * rhs.e123o.0)
+				+ E3(self.e3o.0 * rhs.e123i.0)
+				+ E3(self.eoi.0 * rhs.e12.0)
+				+ E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.e12.0 * rhs.e3o.0) + Eo(self.e123o.0 * rhs.eoi.0)
+				- Eo(self.e123o.0 * rhs.s.0)
+				- Eo(self.e12oi.0 * rhs.e3o.0)
+				+ Eo(self.e13.0 * rhs.e2o.0)
+				+ Eo(self.e13oi.0 * rhs.e2o.0)
+				- Eo(self.e1o.0 * rhs.e23.0)
+				+ Eo(self.e1o.0 * rhs.e23oi.0)
+				- Eo(self.e23.0 * rhs.e1o.0)
+				- Eo(self.e23oi.0 * rhs.e1o.0)
+				+ Eo(self.e2o.0 * rhs.e13.0)
+				- Eo(self.e2o.0 * rhs.e13oi.0)
+				- Eo(self.e3o.0 * rhs.e12.0)
+				+ Eo(self.e3o.0 * rhs.e12oi.0)
+				- Eo(self.eoi.0 * rhs.e123o.0)
+				- Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.e12.0 * rhs.e3i.0) + Ei(self.e123i.0 * rhs.eoi.0) + Ei(self.e123i.0 * rhs.s.0)
+				- Ei(self.e12oi.0 * rhs.e3i.0)
+				- Ei(self.e13.0 * rhs.e2i.0)
+				+ Ei(self.e13oi.0 * rhs.e2i.0)
+				+ Ei(self.e1i.0 * rhs.e23.0)
+				+ Ei(self.e1i.0 * rhs.e23oi.0)
+				+ Ei(self.e23.0 * rhs.e1i.0)
+				- Ei(self.e23oi.0 * rhs.e1i.0)
+				- Ei(self.e2i.0 * rhs.e13.0)
+				- Ei(self.e2i.0 * rhs.e13oi.0)
+				+ Ei(self.e3i.0 * rhs.e12.0)
+				+ Ei(self.e3i.0 * rhs.e12oi.0)
+				- Ei(self.eoi.0 * rhs.e123i.0)
+				+ Ei(self.s.0 * rhs.e123i.0),
+			e12: E12(self.e12.0 * rhs.e123oi.0) - E12(self.e123i.0 * rhs.e12o.0)
+				+ E12(self.e123o.0 * rhs.e12i.0)
+				+ E12(self.e12oi.0 * rhs.e123.0)
+				- E12(self.e13.0 * rhs.e1oi.0)
+				- E12(self.e13oi.0 * rhs.e1.0)
+				- E12(self.e1i.0 * rhs.e13o.0)
+				+ E12(self.e1o.0 * rhs.e13i.0)
+				- E12(self.e23.0 * rhs.e2oi.0)
+				- E12(self.e23oi.0 * rhs.e2.0)
+				- E12(self.e2i.0 * rhs.e23o.0)
+				+ E12(self.e2o.0 * rhs.e23i.0)
+				+ E12(self.e3i.0 * rhs.eo.0)
+				- E12(self.e3o.0 * rhs.ei.0)
+				- E12(self.eoi.0 * rhs.e3.0)
+				- E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.e12.0 * rhs.e1oi.0) - E13(self.e123i.0 * rhs.e13o.0)
+				+ E13(self.e123o.0 * rhs.e13i.0)
+				+ E13(self.e12oi.0 * rhs.e1.0)
+				+ E13(self.e13.0 * rhs.e123oi.0)
+				+ E13(self.e13oi.0 * rhs.e123.0)
+				+ E13(self.e1i.0 * rhs.e12o.0)
+				- E13(self.e1o.0 * rhs.e12i.0)
+				- E13(self.e23.0 * rhs.e3oi.0)
+				- E13(self.e23oi.0 * rhs.e3.0)
+				- E13(self.e2i.0 * rhs.eo.0)
+				+ E13(self.e2o.0 * rhs.ei.0)
+				- E13(self.e3i.0 * rhs.e23o.0)
+				+ E13(self.e3o.0 * rhs.e23i.0)
+				+ E13(self.eoi.0 * rhs.e2.0)
+				+ E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.e12.0 * rhs.e13o.0) - E1o(self.e123o.0 * rhs.e1.0) + E1o(self.e123o.0 * rhs.e1oi.0)
+				- E1o(self.e12oi.0 * rhs.e13o.0)
+				+ E1o(self.e13.0 * rhs.e12o.0)
+				+ E1o(self.e13oi.0 * rhs.e12o.0)
+				- E1o(self.e1o.0 * rhs.e123.0)
+				+ E1o(self.e1o.0 * rhs.e123oi.0)
+				- E1o(self.e23.0 * rhs.eo.0)
+				- E1o(self.e23oi.0 * rhs.eo.0)
+				+ E1o(self.e2o.0 * rhs.e3.0)
+				- E1o(self.e2o.0 * rhs.e3oi.0)
+				- E1o(self.e3o.0 * rhs.e2.0)
+				+ E1o(self.e3o.0 * rhs.e2oi.0)
+				- E1o(self.eoi.0 * rhs.e23o.0)
+				- E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.e12.0 * rhs.e13i.0) + E1i(self.e123i.0 * rhs.e1.0) + E1i(self.e123i.0 * rhs.e1oi.0)
+				- E1i(self.e12oi.0 * rhs.e13i.0)
+				- E1i(self.e13.0 * rhs.e12i.0)
+				+ E1i(self.e13oi.0 * rhs.e12i.0)
+				+ E1i(self.e1i.0 * rhs.e123.0)
+				+ E1i(self.e1i.0 * rhs.e123oi.0)
+				+ E1i(self.e23.0 * rhs.ei.0)
+				- E1i(self.e23oi.0 * rhs.ei.0)
+				- E1i(self.e2i.0 * rhs.e3.0)
+				- E1i(self.e2i.0 * rhs.e3oi.0)
+				+ E1i(self.e3i.0 * rhs.e2.0)
+				+ E1i(self.e3i.0 * rhs.e2oi.0)
+				- E1i(self.eoi.0 * rhs.e23i.0)
+				+ E1i(self.s.0 * rhs.e23i.0),
+			e23: E23(self.e12.0 * rhs.e2oi.0) - E23(self.e123i.0 * rhs.e23o.0)
+				+ E23(self.e123o.0 * rhs.e23i.0)
+				+ E23(self.e12oi.0 * rhs.e2.0)
+				+ E23(self.e13.0 * rhs.e3oi.0)
+				+ E23(self.e13oi.0 * rhs.e3.0)
+				+ E23(self.e1i.0 * rhs.eo.0)
+				- E23(self.e1o.0 * rhs.ei.0)
+				+ E23(self.e23.0 * rhs.e123oi.0)
+				+ E23(self.e23oi.0 * rhs.e123.0)
+				+ E23(self.e2i.0 * rhs.e12o.0)
+				- E23(self.e2o.0 * rhs.e12i.0)
+				+ E23(self.e3i.0 * rhs.e13o.0)
+				- E23(self.e3o.0 * rhs.e13i.0)
+				- E23(self.eoi.0 * rhs.e1.0)
+				- E23(self.s.0 * rhs.e1oi.0),
+			e2o: -E2o(self.e12.0 * rhs.e23o.0) - E2o(self.e123o.0 * rhs.e2.0) + E2o(self.e123o.0 * rhs.e2oi.0)
+				- E2o(self.e12oi.0 * rhs.e23o.0)
+				+ E2o(self.e13.0 * rhs.eo.0)
+				+ E2o(self.e13oi.0 * rhs.eo.0)
+				- E2o(self.e1o.0 * rhs.e3.0)
+				+ E2o(self.e1o.0 * rhs.e3oi.0)
+				+ E2o(self.e23.0 * rhs.e12o.0)
+				+ E2o(self.e23oi.0 * rhs.e12o.0)
+				- E2o(self.e2o.0 * rhs.e123.0)
+				+ E2o(self.e2o.0 * rhs.e123oi.0)
+				+ E2o(self.e3o.0 * rhs.e1.0)
+				- E2o(self.e3o.0 * rhs.e1oi.0)
+				+ E2o(self.eoi.0 * rhs.e13o.0)
+				+ E2o(self.s.0 * rhs.e13o.0),
+			e2i: E2i(self.e12.0 * rhs.e23i.0) + E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e123i.0 * rhs.e2oi.0)
+				- E2i(self.e12oi.0 * rhs.e23i.0)
+				- E2i(self.e13.0 * rhs.ei.0)
+				+ E2i(self.e13oi.0 * rhs.ei.0)
+				+ E2i(self.e1i.0 * rhs.e3.0)
+				+ E2i(self.e1i.0 * rhs.e3oi.0)
+				- E2i(self.e23.0 * rhs.e12i.0)
+				+ E2i(self.e23oi.0 * rhs.e12i.0)
+				+ E2i(self.e2i.0 * rhs.e123.0)
+				+ E2i(self.e2i.0 * rhs.e123oi.0)
+				- E2i(self.e3i.0 * rhs.e1.0)
+				- E2i(self.e3i.0 * rhs.e1oi.0)
+				+ E2i(self.eoi.0 * rhs.e13i.0)
+				- E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.e12.0 * rhs.eo.0) - E3o(self.e123o.0 * rhs.e3.0) + E3o(self.e123o.0 * rhs.e3oi.0)
+				- E3o(self.e12oi.0 * rhs.eo.0)
+				- E3o(self.e13.0 * rhs.e23o.0)
+				- E3o(self.e13oi.0 * rhs.e23o.0)
+				+ E3o(self.e1o.0 * rhs.e2.0)
+				- E3o(self.e1o.0 * rhs.e2oi.0)
+				+ E3o(self.e23.0 * rhs.e13o.0)
+				+ E3o(self.e23oi.0 * rhs.e13o.0)
+				- E3o(self.e2o.0 * rhs.e1.0)
+				+ E3o(self.e2o.0 * rhs.e1oi.0)
+				- E3o(self.e3o.0 * rhs.e123.0)
+				+ E3o(self.e3o.0 * rhs.e123oi.0)
+				- E3o(self.eoi.0 * rhs.e12o.0)
+				- E3o(self.s.0 * rhs.e12o.0),
+			e3i: E3i(self.e12.0 * rhs.ei.0) + E3i(self.e123i.0 * rhs.e3.0) + E3i(self.e123i.0 * rhs.e3oi.0)
+				- E3i(self.e12oi.0 * rhs.ei.0)
+				+ E3i(self.e13.0 * rhs.e23i.0)
+				- E3i(self.e13oi.0 * rhs.e23i.0)
+				- E3i(self.e1i.0 * rhs.e2.0)
+				- E3i(self.e1i.0 * rhs.e2oi.0)
+				- E3i(self.e23.0 * rhs.e13i.0)
+				+ E3i(self.e23oi.0 * rhs.e13i.0)
+				+ E3i(self.e2i.0 * rhs.e1.0)
+				+ E3i(self.e2i.0 * rhs.e1oi.0)
+				+ E3i(self.e3i.0 * rhs.e123.0)
+				+ E3i(self.e3i.0 * rhs.e123oi.0)
+				- E3i(self.eoi.0 * rhs.e12i.0)
+				+ E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.e12.0 * rhs.e3.0)
+				+ Eoi(self.e123i.0 * rhs.eo.0)
+				+ Eoi(self.e123o.0 * rhs.ei.0)
+				+ Eoi(self.e12oi.0 * rhs.e3oi.0)
+				- Eoi(self.e13.0 * rhs.e2.0)
+				- Eoi(self.e13oi.0 * rhs.e2oi.0)
+				+ Eoi(self.e1i.0 * rhs.e23o.0)
+				+ Eoi(self.e1o.0 * rhs.e23i.0)
+				+ Eoi(self.e23.0 * rhs.e1.0)
+				+ Eoi(self.e23oi.0 * rhs.e1oi.0)
+				- Eoi(self.e2i.0 * rhs.e13o.0)
+				- Eoi(self.e2o.0 * rhs.e13i.0)
+				+ Eoi(self.e3i.0 * rhs.e12o.0)
+				+ Eoi(self.e3o.0 * rhs.e12i.0)
+				+ Eoi(self.eoi.0 * rhs.e123oi.0)
+				+ Eoi(self.s.0 * rhs.e123.0),
+			e123: E123(self.e12.0 * rhs.e12oi.0) - E123(self.e123i.0 * rhs.e123o.0)
+				+ E123(self.e123o.0 * rhs.e123i.0)
+				+ E123(self.e12oi.0 * rhs.e12.0)
+				+ E123(self.e13.0 * rhs.e13oi.0)
+				+ E123(self.e13oi.0 * rhs.e13.0)
+				+ E123(self.e1i.0 * rhs.e1o.0)
+				- E123(self.e1o.0 * rhs.e1i.0)
+				+ E123(self.e23.0 * rhs.e23oi.0)
+				+ E123(self.e23oi.0 * rhs.e23.0)
+				+ E123(self.e2i.0 * rhs.e2o.0)
+				- E123(self.e2o.0 * rhs.e2i.0)
+				+ E123(self.e3i.0 * rhs.e3o.0)
+				- E123(self.e3o.0 * rhs.e3i.0)
+				- E123(self.eoi.0 * rhs.s.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) - E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e123o.0 * rhs.e12oi.0)
+				- E12o(self.e12oi.0 * rhs.e123o.0)
+				+ E12o(self.e13.0 * rhs.e1o.0)
+				+ E12o(self.e13oi.0 * rhs.e1o.0)
+				- E12o(self.e1o.0 * rhs.e13.0)
+				+ E12o(self.e1o.0 * rhs.e13oi.0)
+				+ E12o(self.e23.0 * rhs.e2o.0)
+				+ E12o(self.e23oi.0 * rhs.e2o.0)
+				- E12o(self.e2o.0 * rhs.e23.0)
+				+ E12o(self.e2o.0 * rhs.e23oi.0)
+				- E12o(self.e3o.0 * rhs.eoi.0)
+				+ E12o(self.e3o.0 * rhs.s.0)
+				+ E12o(self.eoi.0 * rhs.e3o.0)
+				+ E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e123i.0 * rhs.e12oi.0)
+				- E12i(self.e12oi.0 * rhs.e123i.0)
+				- E12i(self.e13.0 * rhs.e1i.0)
+				+ E12i(self.e13oi.0 * rhs.e1i.0)
+				+ E12i(self.e1i.0 * rhs.e13.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				- E12i(self.e23.0 * rhs.e2i.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0)
+				+ E12i(self.e2i.0 * rhs.e23.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.eoi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				+ E12i(self.eoi.0 * rhs.e3i.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e12.0 * rhs.e1o.0) - E13o(self.e123o.0 * rhs.e13.0) + E13o(self.e123o.0 * rhs.e13oi.0)
+				- E13o(self.e12oi.0 * rhs.e1o.0)
+				- E13o(self.e13.0 * rhs.e123o.0)
+				- E13o(self.e13oi.0 * rhs.e123o.0)
+				+ E13o(self.e1o.0 * rhs.e12.0)
+				- E13o(self.e1o.0 * rhs.e12oi.0)
+				+ E13o(self.e23.0 * rhs.e3o.0)
+				+ E13o(self.e23oi.0 * rhs.e3o.0)
+				+ E13o(self.e2o.0 * rhs.eoi.0)
+				- E13o(self.e2o.0 * rhs.s.0)
+				- E13o(self.e3o.0 * rhs.e23.0)
+				+ E13o(self.e3o.0 * rhs.e23oi.0)
+				- E13o(self.eoi.0 * rhs.e2o.0)
+				- E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e12.0 * rhs.e1i.0) + E13i(self.e123i.0 * rhs.e13.0) + E13i(self.e123i.0 * rhs.e13oi.0)
+				- E13i(self.e12oi.0 * rhs.e1i.0)
+				+ E13i(self.e13.0 * rhs.e123i.0)
+				- E13i(self.e13oi.0 * rhs.e123i.0)
+				- E13i(self.e1i.0 * rhs.e12.0)
+				- E13i(self.e1i.0 * rhs.e12oi.0)
+				- E13i(self.e23.0 * rhs.e3i.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.eoi.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				- E13i(self.eoi.0 * rhs.e2i.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e13.0)
+				+ E1oi(self.e123i.0 * rhs.e1o.0)
+				+ E1oi(self.e123o.0 * rhs.e1i.0)
+				+ E1oi(self.e12oi.0 * rhs.e13oi.0)
+				- E1oi(self.e13.0 * rhs.e12.0)
+				- E1oi(self.e13oi.0 * rhs.e12oi.0)
+				+ E1oi(self.e1i.0 * rhs.e123o.0)
+				+ E1oi(self.e1o.0 * rhs.e123i.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				+ E1oi(self.e23oi.0 * rhs.eoi.0)
+				- E1oi(self.e2i.0 * rhs.e3o.0)
+				- E1oi(self.e2o.0 * rhs.e3i.0)
+				+ E1oi(self.e3i.0 * rhs.e2o.0)
+				+ E1oi(self.e3o.0 * rhs.e2i.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e12.0 * rhs.e2o.0) - E23o(self.e123o.0 * rhs.e23.0) + E23o(self.e123o.0 * rhs.e23oi.0)
+				- E23o(self.e12oi.0 * rhs.e2o.0)
+				- E23o(self.e13.0 * rhs.e3o.0)
+				- E23o(self.e13oi.0 * rhs.e3o.0)
+				- E23o(self.e1o.0 * rhs.eoi.0)
+				+ E23o(self.e1o.0 * rhs.s.0)
+				- E23o(self.e23.0 * rhs.e123o.0)
+				- E23o(self.e23oi.0 * rhs.e123o.0)
+				+ E23o(self.e2o.0 * rhs.e12.0)
+				- E23o(self.e2o.0 * rhs.e12oi.0)
+				+ E23o(self.e3o.0 * rhs.e13.0)
+				- E23o(self.e3o.0 * rhs.e13oi.0)
+				+ E23o(self.eoi.0 * rhs.e1o.0)
+				+ E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e12.0 * rhs.e2i.0) + E23i(self.e123i.0 * rhs.e23.0) + E23i(self.e123i.0 * rhs.e23oi.0)
+				- E23i(self.e12oi.0 * rhs.e2i.0)
+				+ E23i(self.e13.0 * rhs.e3i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.eoi.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				+ E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.e23oi.0 * rhs.e123i.0)
+				- E23i(self.e2i.0 * rhs.e12.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				+ E23i(self.eoi.0 * rhs.e1i.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e12.0 * rhs.e23.0)
+				+ E2oi(self.e123i.0 * rhs.e2o.0)
+				+ E2oi(self.e123o.0 * rhs.e2i.0)
+				+ E2oi(self.e12oi.0 * rhs.e23oi.0)
+				- E2oi(self.e13.0 * rhs.s.0)
+				- E2oi(self.e13oi.0 * rhs.eoi.0)
+				+ E2oi(self.e1i.0 * rhs.e3o.0)
+				+ E2oi(self.e1o.0 * rhs.e3i.0)
+				- E2oi(self.e23.0 * rhs.e12.0)
+				- E2oi(self.e23oi.0 * rhs.e12oi.0)
+				+ E2oi(self.e2i.0 * rhs.e123o.0)
+				+ E2oi(self.e2o.0 * rhs.e123i.0)
+				- E2oi(self.e3i.0 * rhs.e1o.0)
+				- E2oi(self.e3o.0 * rhs.e1i.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0)
+				+ E3oi(self.e123i.0 * rhs.e3o.0)
+				+ E3oi(self.e123o.0 * rhs.e3i.0)
+				+ E3oi(self.e12oi.0 * rhs.eoi.0)
+				+ E3oi(self.e13.0 * rhs.e23.0)
+				+ E3oi(self.e13oi.0 * rhs.e23oi.0)
+				- E3oi(self.e1i.0 * rhs.e2o.0)
+				- E3oi(self.e1o.0 * rhs.e2i.0)
+				- E3oi(self.e23.0 * rhs.e13.0)
+				- E3oi(self.e23oi.0 * rhs.e13oi.0)
+				+ E3oi(self.e2i.0 * rhs.e1o.0)
+				+ E3oi(self.e2o.0 * rhs.e1i.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.e3o.0 * rhs.e123i.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e123o.0 * rhs.e123.0)
+				+ E123o(self.e123o.0 * rhs.e123oi.0)
+				- E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13.0 * rhs.e13o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				+ E123o(self.e1o.0 * rhs.e1.0)
+				- E123o(self.e1o.0 * rhs.e1oi.0)
+				- E123o(self.e23.0 * rhs.e23o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0)
+				+ E123o(self.e2o.0 * rhs.e2.0)
+				- E123o(self.e2o.0 * rhs.e2oi.0)
+				+ E123o(self.e3o.0 * rhs.e3.0)
+				- E123o(self.e3o.0 * rhs.e3oi.0)
+				+ E123o(self.eoi.0 * rhs.eo.0)
+				+ E123o(self.s.0 * rhs.eo.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0)
+				+ E123i(self.e123i.0 * rhs.e123.0)
+				+ E123i(self.e123i.0 * rhs.e123oi.0)
+				- E123i(self.e12oi.0 * rhs.e12i.0)
+				+ E123i(self.e13.0 * rhs.e13i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e1i.0 * rhs.e1.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0)
+				+ E123i(self.eoi.0 * rhs.ei.0)
+				- E123i(self.s.0 * rhs.ei.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0)
+				+ E12oi(self.e123i.0 * rhs.e12o.0)
+				+ E12oi(self.e123o.0 * rhs.e12i.0)
+				+ E12oi(self.e12oi.0 * rhs.e123oi.0)
+				- E12oi(self.e13.0 * rhs.e1.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				+ E12oi(self.e1i.0 * rhs.e13o.0)
+				+ E12oi(self.e1o.0 * rhs.e13i.0)
+				- E12oi(self.e23.0 * rhs.e2.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0)
+				+ E12oi(self.e2i.0 * rhs.e23o.0)
+				+ E12oi(self.e2o.0 * rhs.e23i.0)
+				- E12oi(self.e3i.0 * rhs.eo.0)
+				- E12oi(self.e3o.0 * rhs.ei.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0)
+				- E12oi(self.s.0 * rhs.e3.0),
+			e13oi: E13oi(self.e12.0 * rhs.e1.0)
+				+ E13oi(self.e123i.0 * rhs.e13o.0)
+				+ E13oi(self.e123o.0 * rhs.e13i.0)
+				+ E13oi(self.e12oi.0 * rhs.e1oi.0)
+				+ E13oi(self.e13.0 * rhs.e123.0)
+				+ E13oi(self.e13oi.0 * rhs.e123oi.0)
+				- E13oi(self.e1i.0 * rhs.e12o.0)
+				- E13oi(self.e1o.0 * rhs.e12i.0)
+				- E13oi(self.e23.0 * rhs.e3.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0)
+				+ E13oi(self.e2i.0 * rhs.eo.0)
+				+ E13oi(self.e2o.0 * rhs.ei.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.e3o.0 * rhs.e23i.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0)
+				+ E13oi(self.s.0 * rhs.e2.0),
+			e23oi: E23oi(self.e12.0 * rhs.e2.0)
+				+ E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13.0 * rhs.e3.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0)
+				- E23oi(self.e1i.0 * rhs.eo.0)
+				- E23oi(self.e1o.0 * rhs.ei.0)
+				+ E23oi(self.e23.0 * rhs.e123.0)
+				+ E23oi(self.e23oi.0 * rhs.e123oi.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e2o.0 * rhs.e12i.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.e3o.0 * rhs.e13i.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0)
+				- E23oi(self.s.0 * rhs.e1.0),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0)
+				+ E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13.0 * rhs.e13.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// Versor.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Versor {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13.0 * rhs.e13.0) - S(self.e13oi.0 * rhs.e13oi.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) - S(self.e23oi.0 * rhs.e23oi.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13.0 * rhs.e3.0) + E1(self.e13oi.0 * rhs.e3oi.0) - E1(self.e1i.0 * rhs.eo.0) - E1(self.e1o.0 * rhs.ei.0) - E1(self.e23.0 * rhs.e123.0) - E1(self.e23oi.0 * rhs.e123oi.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) - E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e13.0 * rhs.e123.0) + E2(self.e13oi.0 * rhs.e123oi.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e23.0 * rhs.e3.0) + E2(self.e23oi.0 * rhs.e3oi.0) - E2(self.e2i.0 * rhs.eo.0) - E2(self.e2o.0 * rhs.ei.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) + E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e12oi.0 * rhs.e123oi.0) - E3(self.e13.0 * rhs.e1.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e23.0 * rhs.e2.0) - E3(self.e23oi.0 * rhs.e2oi.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) - E3(self.e3i.0 * rhs.eo.0) - E3(self.e3o.0 * rhs.ei.0) + E3(self.eoi.0 * rhs.e3oi.0) + E3(self.s.0 * rhs.e3.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) + Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e123o.0 * rhs.e123oi.0) + Eo(self.e12oi.0 * rhs.e12o.0) - Eo(self.e13.0 * rhs.e13o.0) + Eo(self.e13oi.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) + Eo(self.e23oi.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3.0) - Eo(self.e3o.0 * rhs.e3oi.0) - Eo(self.eoi.0 * rhs.eo.0) + Eo(self.s.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) + Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e123i.0 * rhs.e123oi.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13.0 * rhs.e13i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e1i.0 * rhs.e1.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) - Ei(self.e23oi.0 * rhs.e23i.0) - Ei(self.e2i.0 * rhs.e2.0) + Ei(self.e2i.0 * rhs.e2oi.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.e3i.0 * rhs.e3oi.0) + Ei(self.eoi.0 * rhs.ei.0) + Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.e12.0 * rhs.s.0) + E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0) + E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e13oi.0 * rhs.eoi.0) - E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o   : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.s.0) - E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.e1i.0 * rhs.s.0) - E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.e23oi.0 * rhs.eoi.0) + E23(self.eoi.0 * rhs.e23oi.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o   : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) + E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e23oi.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.s.0) - E2o(self.e3o.0 * rhs.e23oi.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.e2i.0 * rhs.s.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e23oi.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23oi.0) + E3o(self.e3o.0 * rhs.s.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e23oi.0 * rhs.e2i.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23.0 * rhs.e23oi.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.eoi.0 * rhs.s.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0) + E123(self.eoi.0 * rhs.e123oi.0) + E123(self.s.0 * rhs.e123.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0) - E12o(self.e3o.0 * rhs.e123oi.0) + E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0) + E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0) + E13o(self.e2o.0 * rhs.e123oi.0) + E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0) - E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0) - E1oi(self.e23.0 * rhs.e123oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e1o.0 * rhs.e123oi.0) - E23o(self.e23oi.0 * rhs.eo.0) + E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e1i.0 * rhs.e123oi.0) + E23i(self.e23oi.0 * rhs.ei.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e13.0 * rhs.e123oi.0) + E2oi(self.e23oi.0 * rhs.e3.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e12.0 * rhs.e123oi.0) - E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.e123o.0 * rhs.s.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.e123i.0 * rhs.s.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.s.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.s.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.s.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0)
+				- S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13.0 * rhs.e13.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				- S(self.e23oi.0 * rhs.e23oi.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: E1(self.e12.0 * rhs.e2.0)
+				+ E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13.0 * rhs.e3.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0)
+				- E1(self.e1i.0 * rhs.eo.0)
+				- E1(self.e1o.0 * rhs.ei.0)
+				- E1(self.e23.0 * rhs.e123.0)
+				- E1(self.e23oi.0 * rhs.e123oi.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0)
+				+ E1(self.s.0 * rhs.e1.0),
+			e2: -E2(self.e12.0 * rhs.e1.0)
+				- E2(self.e123i.0 * rhs.e13o.0)
+				- E2(self.e123o.0 * rhs.e13i.0)
+				- E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e13.0 * rhs.e123.0)
+				+ E2(self.e13oi.0 * rhs.e123oi.0)
+				- E2(self.e1i.0 * rhs.e12o.0)
+				- E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e23.0 * rhs.e3.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0)
+				- E2(self.e2i.0 * rhs.eo.0)
+				- E2(self.e2o.0 * rhs.ei.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0)
+				+ E2(self.s.0 * rhs.e2.0),
+			e3: -E3(self.e12.0 * rhs.e123.0) + E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e12oi.0 * rhs.e123oi.0)
+				- E3(self.e13.0 * rhs.e1.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e23.0 * rhs.e2.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				- E3(self.e3i.0 * rhs.eo.0)
+				- E3(self.e3o.0 * rhs.ei.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0)
+				+ E3(self.s.0 * rhs.e3.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0)
+				+ Eo(self.e123o.0 * rhs.e123.0)
+				+ Eo(self.e123o.0 * rhs.e123oi.0)
+				+ Eo(self.e12oi.0 * rhs.e12o.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				+ Eo(self.e13oi.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0)
+				- Eo(self.eoi.0 * rhs.eo.0)
+				+ Eo(self.s.0 * rhs.eo.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) + Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e123i.0 * rhs.e123oi.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13.0 * rhs.e13i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e1i.0 * rhs.e1.0)
+				+ Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0)
+				- Ei(self.e2i.0 * rhs.e2.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				- Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0)
+				+ Ei(self.eoi.0 * rhs.ei.0)
+				+ Ei(self.s.0 * rhs.ei.0),
+			e12: E12(self.e12.0 * rhs.s.0)
+				+ E12(self.e123i.0 * rhs.e3o.0)
+				+ E12(self.e123o.0 * rhs.e3i.0)
+				+ E12(self.e12oi.0 * rhs.eoi.0)
+				+ E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0)
+				+ E13(self.e13.0 * rhs.s.0)
+				+ E13(self.e13oi.0 * rhs.eoi.0)
+				- E13(self.e2i.0 * rhs.e123o.0)
+				- E13(self.e2o.0 * rhs.e123i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.s.0)
+				- E1o(self.e23.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12oi.0)
+				- E1o(self.e3o.0 * rhs.e13oi.0)
+				+ E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				+ E1i(self.e1i.0 * rhs.s.0)
+				- E1i(self.e23.0 * rhs.e123i.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0)
+				+ E23(self.e123o.0 * rhs.e1i.0)
+				+ E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0)
+				+ E2o(self.e12oi.0 * rhs.e1o.0)
+				+ E2o(self.e13.0 * rhs.e123o.0)
+				+ E2o(self.e1o.0 * rhs.e12oi.0)
+				- E2o(self.e23oi.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.s.0)
+				- E2o(self.e3o.0 * rhs.e23oi.0)
+				+ E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e13.0 * rhs.e123i.0)
+				- E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				+ E2i(self.e2i.0 * rhs.s.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0) - E3o(self.e123o.0 * rhs.e12.0)
+				+ E3o(self.e13oi.0 * rhs.e1o.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				+ E3o(self.e23oi.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0)
+				+ E3o(self.e3o.0 * rhs.s.0)
+				+ E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0)
+				- E3i(self.e123i.0 * rhs.e12.0)
+				- E3i(self.e13oi.0 * rhs.e1i.0)
+				- E3i(self.e1i.0 * rhs.e13oi.0)
+				- E3i(self.e23oi.0 * rhs.e2i.0)
+				- E3i(self.e2i.0 * rhs.e23oi.0)
+				+ E3i(self.e3i.0 * rhs.s.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0)
+				- Eoi(self.e12oi.0 * rhs.e12.0)
+				- Eoi(self.e13.0 * rhs.e13oi.0)
+				- Eoi(self.e13oi.0 * rhs.e13.0)
+				- Eoi(self.e23.0 * rhs.e23oi.0)
+				- Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.eoi.0 * rhs.s.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0)
+				+ E123(self.eoi.0 * rhs.e123oi.0)
+				+ E123(self.s.0 * rhs.e123.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0) - E12o(self.e3o.0 * rhs.e123oi.0)
+				+ E12o(self.s.0 * rhs.e12o.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0)
+				+ E12i(self.e12oi.0 * rhs.ei.0)
+				+ E12i(self.e3i.0 * rhs.e123oi.0)
+				+ E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0)
+				+ E13o(self.e2o.0 * rhs.e123oi.0)
+				+ E13o(self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0) - E13i(self.e2i.0 * rhs.e123oi.0)
+				+ E13i(self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0) - E1oi(self.e23.0 * rhs.e123oi.0)
+				+ E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e1o.0 * rhs.e123oi.0) - E23o(self.e23oi.0 * rhs.eo.0)
+				+ E23o(self.s.0 * rhs.e23o.0),
+			e23i: -E23i(self.e123i.0 * rhs.e1.0)
+				+ E23i(self.e1i.0 * rhs.e123oi.0)
+				+ E23i(self.e23oi.0 * rhs.ei.0)
+				+ E23i(self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.e1.0)
+				+ E2oi(self.e13.0 * rhs.e123oi.0)
+				+ E2oi(self.e23oi.0 * rhs.e3.0)
+				+ E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e12.0 * rhs.e123oi.0) - E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0)
+				+ E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0) + E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0) + E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0) + E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0) + E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0) + E23oi(self.s.0 * rhs.e23oi.0),
+			e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Versor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Versor.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Versor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.s.0 * rhs.s.0),
+		//     e1    : E1(self.s.0 * rhs.e1.0),
+		//     e2    : E2(self.s.0 * rhs.e2.0),
+		//     e3    : E3(self.s.0 * rhs.e3.0),
+		//     eo    : Eo(self.s.0 * rhs.eo.0),
+		//     ei    : Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13   : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o   : E1o(self.e1o.0 * rhs.s.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : E1i(self.e1i.0 * rhs.s.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o   : E2o(self.e2o.0 * rhs.s.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : E2i(self.e2i.0 * rhs.s.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : E3o(self.e3o.0 * rhs.s.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : E3i(self.e3i.0 * rhs.s.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : Eoi(self.eoi.0 * rhs.s.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : E123(self.e12.0 * rhs.e3.0) - E123(self.e13.0 * rhs.e2.0) + E123(self.e23.0 * rhs.e1.0) + E123(self.s.0 * rhs.e123.0),
+		//     e12o  : E12o(self.e12.0 * rhs.eo.0) - E12o(self.e1o.0 * rhs.e2.0) + E12o(self.e2o.0 * rhs.e1.0) + E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.ei.0) - E12i(self.e1i.0 * rhs.e2.0) + E12i(self.e2i.0 * rhs.e1.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e13.0 * rhs.eo.0) - E13o(self.e1o.0 * rhs.e3.0) + E13o(self.e3o.0 * rhs.e1.0) + E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e13.0 * rhs.ei.0) - E13i(self.e1i.0 * rhs.e3.0) + E13i(self.e3i.0 * rhs.e1.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : -E1oi(self.e1i.0 * rhs.eo.0) + E1oi(self.e1o.0 * rhs.ei.0) + E1oi(self.eoi.0 * rhs.e1.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : E23o(self.e23.0 * rhs.eo.0) - E23o(self.e2o.0 * rhs.e3.0) + E23o(self.e3o.0 * rhs.e2.0) + E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.e23.0 * rhs.ei.0) - E23i(self.e2i.0 * rhs.e3.0) + E23i(self.e3i.0 * rhs.e2.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e2i.0 * rhs.eo.0) + E2oi(self.e2o.0 * rhs.ei.0) + E2oi(self.eoi.0 * rhs.e2.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e3i.0 * rhs.eo.0) + E3oi(self.e3o.0 * rhs.ei.0) + E3oi(self.eoi.0 * rhs.e3.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.e12.0 * rhs.e3o.0) + E123o(self.e123o.0 * rhs.s.0) - E123o(self.e13.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23.0) + E123o(self.e23.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13.0) + E123o(self.e3o.0 * rhs.e12.0) + E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.e12.0 * rhs.e3i.0) + E123i(self.e123i.0 * rhs.s.0) - E123i(self.e13.0 * rhs.e2i.0) + E123i(self.e1i.0 * rhs.e23.0) + E123i(self.e23.0 * rhs.e1i.0) - E123i(self.e2i.0 * rhs.e13.0) + E123i(self.e3i.0 * rhs.e12.0) + E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.eoi.0) + E12oi(self.e12oi.0 * rhs.s.0) + E12oi(self.e1i.0 * rhs.e2o.0) - E12oi(self.e1o.0 * rhs.e2i.0) - E12oi(self.e2i.0 * rhs.e1o.0) + E12oi(self.e2o.0 * rhs.e1i.0) + E12oi(self.eoi.0 * rhs.e12.0) + E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.e13.0 * rhs.eoi.0) + E13oi(self.e13oi.0 * rhs.s.0) + E13oi(self.e1i.0 * rhs.e3o.0) - E13oi(self.e1o.0 * rhs.e3i.0) - E13oi(self.e3i.0 * rhs.e1o.0) + E13oi(self.e3o.0 * rhs.e1i.0) + E13oi(self.eoi.0 * rhs.e13.0) + E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : E23oi(self.e23.0 * rhs.eoi.0) + E23oi(self.e23oi.0 * rhs.s.0) + E23oi(self.e2i.0 * rhs.e3o.0) - E23oi(self.e2o.0 * rhs.e3i.0) - E23oi(self.e3i.0 * rhs.e2o.0) + E23oi(self.e3o.0 * rhs.e2i.0) + E23oi(self.eoi.0 * rhs.e23.0) + E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e3oi.0) - E123oi(self.e123i.0 * rhs.eo.0) + E123oi(self.e123o.0 * rhs.ei.0) + E123oi(self.e12oi.0 * rhs.e3.0) - E123oi(self.e13.0 * rhs.e2oi.0) - E123oi(self.e13oi.0 * rhs.e2.0) - E123oi(self.e1i.0 * rhs.e23o.0) + E123oi(self.e1o.0 * rhs.e23i.0) + E123oi(self.e23.0 * rhs.e1oi.0) + E123oi(self.e23oi.0 * rhs.e1.0) + E123oi(self.e2i.0 * rhs.e13o.0) - E123oi(self.e2o.0 * rhs.e13i.0) - E123oi(self.e3i.0 * rhs.e12o.0) + E123oi(self.e3o.0 * rhs.e12i.0) + E123oi(self.eoi.0 * rhs.e123.0) + E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			e1: self.s.wedge(rhs.e1),
+			e2: self.s.wedge(rhs.e2),
+			e3: self.s.wedge(rhs.e3),
+			eo: self.s.wedge(rhs.eo),
+			ei: self.s.wedge(rhs.ei),
+			e12: self.e12.wedge(rhs.s) + self.s.wedge(rhs.e12),
+			e13: self.e13.wedge(rhs.s) + self.s.wedge(rhs.e13),
+			e1o: self.e1o.wedge(rhs.s) + self.s.wedge(rhs.e1o),
+			e1i: self.e1i.wedge(rhs.s) + self.s.wedge(rhs.e1i),
+			e23: self.e23.wedge(rhs.s) + self.s.wedge(rhs.e23),
+			e2o: self.e2o.wedge(rhs.s) + self.s.wedge(rhs.e2o),
+			e2i: self.e2i.wedge(rhs.s) + self.s.wedge(rhs.e2i),
+			e3o: self.e3o.wedge(rhs.s) + self.s.wedge(rhs.e3o),
+			e3i: self.e3i.wedge(rhs.s) + self.s.wedge(rhs.e3i),
+			eoi: self.eoi.wedge(rhs.s) + self.s.wedge(rhs.eoi),
+			e123: self.e12.wedge(rhs.e3) + self.e13.wedge(rhs.e2) + self.e23.wedge(rhs.e1) + self.s.wedge(rhs.e123),
+			e12o: self.e12.wedge(rhs.eo) + self.e1o.wedge(rhs.e2) + self.e2o.wedge(rhs.e1) + self.s.wedge(rhs.e12o),
+			e12i: self.e12.wedge(rhs.ei) + self.e1i.wedge(rhs.e2) + self.e2i.wedge(rhs.e1) + self.s.wedge(rhs.e12i),
+			e13o: self.e13.wedge(rhs.eo) + self.e1o.wedge(rhs.e3) + self.e3o.wedge(rhs.e1) + self.s.wedge(rhs.e13o),
+			e13i: self.e13.wedge(rhs.ei) + self.e1i.wedge(rhs.e3) + self.e3i.wedge(rhs.e1) + self.s.wedge(rhs.e13i),
+			e1oi: self.e1i.wedge(rhs.eo) + self.e1o.wedge(rhs.ei) + self.eoi.wedge(rhs.e1) + self.s.wedge(rhs.e1oi),
+			e23o: self.e23.wedge(rhs.eo) + self.e2o.wedge(rhs.e3) + self.e3o.wedge(rhs.e2) + self.s.wedge(rhs.e23o),
+			e23i: self.e23.wedge(rhs.ei) + self.e2i.wedge(rhs.e3) + self.e3i.wedge(rhs.e2) + self.s.wedge(rhs.e23i),
+			e2oi: self.e2i.wedge(rhs.eo) + self.e2o.wedge(rhs.ei) + self.eoi.wedge(rhs.e2) + self.s.wedge(rhs.e2oi),
+			e3oi: self.e3i.wedge(rhs.eo) + self.e3o.wedge(rhs.ei) + self.eoi.wedge(rhs.e3) + self.s.wedge(rhs.e3oi),
+			e123o: self.e12.wedge(rhs.e3o)
+				+ self.e123o.wedge(rhs.s)
+				+ self.e13.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e13)
+				+ self.e3o.wedge(rhs.e12)
+				+ self.s.wedge(rhs.e123o),
+			e123i: self.e12.wedge(rhs.e3i)
+				+ self.e123i.wedge(rhs.s)
+				+ self.e13.wedge(rhs.e2i)
+				+ self.e1i.wedge(rhs.e23)
+				+ self.e23.wedge(rhs.e1i)
+				+ self.e2i.wedge(rhs.e13)
+				+ self.e3i.wedge(rhs.e12)
+				+ self.s.wedge(rhs.e123i),
+			e12oi: self.e12.wedge(rhs.eoi)
+				+ self.e12oi.wedge(rhs.s)
+				+ self.e1i.wedge(rhs.e2o)
+				+ self.e1o.wedge(rhs.e2i)
+				+ self.e2i.wedge(rhs.e1o)
+				+ self.e2o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e12)
+				+ self.s.wedge(rhs.e12oi),
+			e13oi: self.e13.wedge(rhs.eoi)
+				+ self.e13oi.wedge(rhs.s)
+				+ self.e1i.wedge(rhs.e3o)
+				+ self.e1o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e1o)
+				+ self.e3o.wedge(rhs.e1i)
+				+ self.eoi.wedge(rhs.e13)
+				+ self.s.wedge(rhs.e13oi),
+			e23oi: self.e23.wedge(rhs.eoi)
+				+ self.e23oi.wedge(rhs.s)
+				+ self.e2i.wedge(rhs.e3o)
+				+ self.e2o.wedge(rhs.e3i)
+				+ self.e3i.wedge(rhs.e2o)
+				+ self.e3o.wedge(rhs.e2i)
+				+ self.eoi.wedge(rhs.e23)
+				+ self.s.wedge(rhs.e23oi),
+			e123oi: self.e12.wedge(rhs.e3oi)
+				+ self.e123i.wedge(rhs.eo)
+				+ self.e123o.wedge(rhs.ei)
+				+ self.e12oi.wedge(rhs.e3)
+				+ self.e13.wedge(rhs.e2oi)
+				+ self.e13oi.wedge(rhs.e2)
+				+ self.e1i.wedge(rhs.e23o)
+				+ self.e1o.wedge(rhs.e23i)
+				+ self.e23.wedge(rhs.e1oi)
+				+ self.e23oi.wedge(rhs.e1)
+				+ self.e2i.wedge(rhs.e13o)
+				+ self.e2o.wedge(rhs.e13i)
+				+ self.e3i.wedge(rhs.e12o)
+				+ self.e3o.wedge(rhs.e12i)
+				+ self.eoi.wedge(rhs.e123)
+				+ self.s.wedge(rhs.e123oi),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Versor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Versor.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Versor {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.e12.0 * rhs.e3oi.0) - S(self.e123i.0 * rhs.eo.0) + S(self.e123o.0 * rhs.ei.0) + S(self.e12oi.0 * rhs.e3.0) - S(self.e13.0 * rhs.e2oi.0) - S(self.e13oi.0 * rhs.e2.0) - S(self.e1i.0 * rhs.e23o.0) + S(self.e1o.0 * rhs.e23i.0) + S(self.e23.0 * rhs.e1oi.0) + S(self.e23oi.0 * rhs.e1.0) + S(self.e2i.0 * rhs.e13o.0) - S(self.e2o.0 * rhs.e13i.0) - S(self.e3i.0 * rhs.e12o.0) + S(self.e3o.0 * rhs.e12i.0) + S(self.eoi.0 * rhs.e123.0) + S(self.s.0 * rhs.e123oi.0),
+		//     e1    : E1(self.e12.0 * rhs.e13oi.0) - E1(self.e123i.0 * rhs.e1o.0) + E1(self.e123o.0 * rhs.e1i.0) + E1(self.e12oi.0 * rhs.e13.0) - E1(self.e13.0 * rhs.e12oi.0) - E1(self.e13oi.0 * rhs.e12.0) - E1(self.e1i.0 * rhs.e123o.0) + E1(self.e1o.0 * rhs.e123i.0),
+		//     e2    : E2(self.e12.0 * rhs.e23oi.0) - E2(self.e123i.0 * rhs.e2o.0) + E2(self.e123o.0 * rhs.e2i.0) + E2(self.e12oi.0 * rhs.e23.0) - E2(self.e23.0 * rhs.e12oi.0) - E2(self.e23oi.0 * rhs.e12.0) - E2(self.e2i.0 * rhs.e123o.0) + E2(self.e2o.0 * rhs.e123i.0),
+		//     e3    : -E3(self.e123i.0 * rhs.e3o.0) + E3(self.e123o.0 * rhs.e3i.0) + E3(self.e13.0 * rhs.e23oi.0) + E3(self.e13oi.0 * rhs.e23.0) - E3(self.e23.0 * rhs.e13oi.0) - E3(self.e23oi.0 * rhs.e13.0) - E3(self.e3i.0 * rhs.e123o.0) + E3(self.e3o.0 * rhs.e123i.0),
+		//     eo    : Eo(self.e123o.0 * rhs.eoi.0) - Eo(self.e12oi.0 * rhs.e3o.0) + Eo(self.e13oi.0 * rhs.e2o.0) + Eo(self.e1o.0 * rhs.e23oi.0) - Eo(self.e23oi.0 * rhs.e1o.0) - Eo(self.e2o.0 * rhs.e13oi.0) + Eo(self.e3o.0 * rhs.e12oi.0) - Eo(self.eoi.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.eoi.0) - Ei(self.e12oi.0 * rhs.e3i.0) + Ei(self.e13oi.0 * rhs.e2i.0) + Ei(self.e1i.0 * rhs.e23oi.0) - Ei(self.e23oi.0 * rhs.e1i.0) - Ei(self.e2i.0 * rhs.e13oi.0) + Ei(self.e3i.0 * rhs.e12oi.0) - Ei(self.eoi.0 * rhs.e123i.0),
+		//     e12   : E12(self.e12.0 * rhs.e123oi.0) - E12(self.e123i.0 * rhs.e12o.0) + E12(self.e123o.0 * rhs.e12i.0) + E12(self.e12oi.0 * rhs.e123.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e13o.0) + E13(self.e123o.0 * rhs.e13i.0) + E13(self.e13.0 * rhs.e123oi.0) + E13(self.e13oi.0 * rhs.e123.0),
+		//     e1o   : E1o(self.e123o.0 * rhs.e1oi.0) - E1o(self.e12oi.0 * rhs.e13o.0) + E1o(self.e13oi.0 * rhs.e12o.0) + E1o(self.e1o.0 * rhs.e123oi.0),
+		//     e1i   : E1i(self.e123i.0 * rhs.e1oi.0) - E1i(self.e12oi.0 * rhs.e13i.0) + E1i(self.e13oi.0 * rhs.e12i.0) + E1i(self.e1i.0 * rhs.e123oi.0),
+		//     e23   : -E23(self.e123i.0 * rhs.e23o.0) + E23(self.e123o.0 * rhs.e23i.0) + E23(self.e23.0 * rhs.e123oi.0) + E23(self.e23oi.0 * rhs.e123.0),
+		//     e2o   : E2o(self.e123o.0 * rhs.e2oi.0) - E2o(self.e12oi.0 * rhs.e23o.0) + E2o(self.e23oi.0 * rhs.e12o.0) + E2o(self.e2o.0 * rhs.e123oi.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e2oi.0) - E2i(self.e12oi.0 * rhs.e23i.0) + E2i(self.e23oi.0 * rhs.e12i.0) + E2i(self.e2i.0 * rhs.e123oi.0),
+		//     e3o   : E3o(self.e123o.0 * rhs.e3oi.0) - E3o(self.e13oi.0 * rhs.e23o.0) + E3o(self.e23oi.0 * rhs.e13o.0) + E3o(self.e3o.0 * rhs.e123oi.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e3oi.0) - E3i(self.e13oi.0 * rhs.e23i.0) + E3i(self.e23oi.0 * rhs.e13i.0) + E3i(self.e3i.0 * rhs.e123oi.0),
+		//     eoi   : Eoi(self.e12oi.0 * rhs.e3oi.0) - Eoi(self.e13oi.0 * rhs.e2oi.0) + Eoi(self.e23oi.0 * rhs.e1oi.0) + Eoi(self.eoi.0 * rhs.e123oi.0),
+		//     e123  : -E123(self.e123i.0 * rhs.e123o.0) + E123(self.e123o.0 * rhs.e123i.0),
+		//     e12o  : E12o(self.e123o.0 * rhs.e12oi.0) - E12o(self.e12oi.0 * rhs.e123o.0),
+		//     e12i  : E12i(self.e123i.0 * rhs.e12oi.0) - E12i(self.e12oi.0 * rhs.e123i.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e13oi.0) - E13o(self.e13oi.0 * rhs.e123o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13oi.0) - E13i(self.e13oi.0 * rhs.e123i.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e13oi.0) - E1oi(self.e13oi.0 * rhs.e12oi.0),
+		//     e23o  : E23o(self.e123o.0 * rhs.e23oi.0) - E23o(self.e23oi.0 * rhs.e123o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23oi.0) - E23i(self.e23oi.0 * rhs.e123i.0),
+		//     e2oi  : E2oi(self.e12oi.0 * rhs.e23oi.0) - E2oi(self.e23oi.0 * rhs.e12oi.0),
+		//     e3oi  : E3oi(self.e13oi.0 * rhs.e23oi.0) - E3oi(self.e23oi.0 * rhs.e13oi.0),
+		//     e123o : E123o(self.e123o.0 * rhs.e123oi.0),
+		//     e123i : E123i(self.e123i.0 * rhs.e123oi.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.e123oi.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.e123oi.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.e123oi.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: self.e12.anti_wedge(rhs.e3oi)
+				+ self.e123i.anti_wedge(rhs.eo)
+				+ self.e123o.anti_wedge(rhs.ei)
+				+ self.e12oi.anti_wedge(rhs.e3)
+				+ self.e13.anti_wedge(rhs.e2oi)
+				+ self.e13oi.anti_wedge(rhs.e2)
+				+ self.e1i.anti_wedge(rhs.e23o)
+				+ self.e1o.anti_wedge(rhs.e23i)
+				+ self.e23.anti_wedge(rhs.e1oi)
+				+ self.e23oi.anti_wedge(rhs.e1)
+				+ self.e2i.anti_wedge(rhs.e13o)
+				+ self.e2o.anti_wedge(rhs.e13i)
+				+ self.e3i.anti_wedge(rhs.e12o)
+				+ self.e3o.anti_wedge(rhs.e12i)
+				+ self.eoi.anti_wedge(rhs.e123)
+				+ self.s.anti_wedge(rhs.e123oi),
+			e1: self.e12.anti_wedge(rhs.e13oi)
+				+ self.e123i.anti_wedge(rhs.e1o)
+				+ self.e123o.anti_wedge(rhs.e1i)
+				+ self.e12oi.anti_wedge(rhs.e13)
+				+ self.e13.anti_wedge(rhs.e12oi)
+				+ self.e13oi.anti_wedge(rhs.e12)
+				+ self.e1i.anti_wedge(rhs.e123o)
+				+ self.e1o.anti_wedge(rhs.e123i),
+			e2: self.e12.anti_wedge(rhs.e23oi)
+				+ self.e123i.anti_wedge(rhs.e2o)
+				+ self.e123o.anti_wedge(rhs.e2i)
+				+ self.e12oi.anti_wedge(rhs.e23)
+				+ self.e23.anti_wedge(rhs.e12oi)
+				+ self.e23oi.anti_wedge(rhs.e12)
+				+ self.e2i.anti_wedge(rhs.e123o)
+				+ self.e2o.anti_wedge(rhs.e123i),
+			e3: self.e123i.anti_wedge(rhs.e3o)
+				+ self.e123o.anti_wedge(rhs.e3i)
+				+ self.e13.anti_wedge(rhs.e23oi)
+				+ self.e13oi.anti_wedge(rhs.e23)
+				+ self.e23.anti_wedge(rhs.e13oi)
+				+ self.e23oi.anti_wedge(rhs.e13)
+				+ self.e3i.anti_wedge(rhs.e123o)
+				+ self.e3o.anti_wedge(rhs.e123i),
+			eo: self.e123o.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3o)
+				+ self.e13oi.anti_wedge(rhs.e2o)
+				+ self.e1o.anti_wedge(rhs.e23oi)
+				+ self.e23oi.anti_wedge(rhs.e1o)
+				+ self.e2o.anti_wedge(rhs.e13oi)
+				+ self.e3o.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123o),
+			ei: self.e123i.anti_wedge(rhs.eoi)
+				+ self.e12oi.anti_wedge(rhs.e3i)
+				+ self.e13oi.anti_wedge(rhs.e2i)
+				+ self.e1i.anti_wedge(rhs.e23oi)
+				+ self.e23oi.anti_wedge(rhs.e1i)
+				+ self.e2i.anti_wedge(rhs.e13oi)
+				+ self.e3i.anti_wedge(rhs.e12oi)
+				+ self.eoi.anti_wedge(rhs.e123i),
+			e12: self.e12.anti_wedge(rhs.e123oi)
+				+ self.e123i.anti_wedge(rhs.e12o)
+				+ self.e123o.anti_wedge(rhs.e12i)
+				+ self.e12oi.anti_wedge(rhs.e123),
+			e13: self.e123i.anti_wedge(rhs.e13o)
+				+ self.e123o.anti_wedge(rhs.e13i)
+				+ self.e13.anti_wedge(rhs.e123oi)
+				+ self.e13oi.anti_wedge(rhs.e123),
+			e1o: self.e123o.anti_wedge(rhs.e1oi)
+				+ self.e12oi.anti_wedge(rhs.e13o)
+				+ self.e13oi.anti_wedge(rhs.e12o)
+				+ self.e1o.anti_wedge(rhs.e123oi),
+			e1i: self.e123i.anti_wedge(rhs.e1oi)
+				+ self.e12oi.anti_wedge(rhs.e13i)
+				+ self.e13oi.anti_wedge(rhs.e12i)
+				+ self.e1i.anti_wedge(rhs.e123oi),
+			e23: self.e123i.anti_wedge(rhs.e23o)
+				+ self.e123o.anti_wedge(rhs.e23i)
+				+ self.e23.anti_wedge(rhs.e123oi)
+				+ self.e23oi.anti_wedge(rhs.e123),
+			e2o: self.e123o.anti_wedge(rhs.e2oi)
+				+ self.e12oi.anti_wedge(rhs.e23o)
+				+ self.e23oi.anti_wedge(rhs.e12o)
+				+ self.e2o.anti_wedge(rhs.e123oi),
+			e2i: self.e123i.anti_wedge(rhs.e2oi)
+				+ self.e12oi.anti_wedge(rhs.e23i)
+				+ self.e23oi.anti_wedge(rhs.e12i)
+				+ self.e2i.anti_wedge(rhs.e123oi),
+			e3o: self.e123o.anti_wedge(rhs.e3oi)
+				+ self.e13oi.anti_wedge(rhs.e23o)
+				+ self.e23oi.anti_wedge(rhs.e13o)
+				+ self.e3o.anti_wedge(rhs.e123oi),
+			e3i: self.e123i.anti_wedge(rhs.e3oi)
+				+ self.e13oi.anti_wedge(rhs.e23i)
+				+ self.e23oi.anti_wedge(rhs.e13i)
+				+ self.e3i.anti_wedge(rhs.e123oi),
+			eoi: self.e12oi.anti_wedge(rhs.e3oi)
+				+ self.e13oi.anti_wedge(rhs.e2oi)
+				+ self.e23oi.anti_wedge(rhs.e1oi)
+				+ self.eoi.anti_wedge(rhs.e123oi),
+			e123: self.e123i.anti_wedge(rhs.e123o) + self.e123o.anti_wedge(rhs.e123i),
+			e12o: self.e123o.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123o),
+			e12i: self.e123i.anti_wedge(rhs.e12oi) + self.e12oi.anti_wedge(rhs.e123i),
+			e13o: self.e123o.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123o),
+			e13i: self.e123i.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e123i),
+			e1oi: self.e12oi.anti_wedge(rhs.e13oi) + self.e13oi.anti_wedge(rhs.e12oi),
+			e23o: self.e123o.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123o),
+			e23i: self.e123i.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e123i),
+			e2oi: self.e12oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e12oi),
+			e3oi: self.e13oi.anti_wedge(rhs.e23oi) + self.e23oi.anti_wedge(rhs.e13oi),
+			e123o: self.e123o.anti_wedge(rhs.e123oi),
+			e123i: self.e123i.anti_wedge(rhs.e123oi),
+			e12oi: self.e12oi.anti_wedge(rhs.e123oi),
+			e13oi: self.e13oi.anti_wedge(rhs.e123oi),
+			e23oi: self.e23oi.anti_wedge(rhs.e123oi),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Versor {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Versor.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Versor {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13.0 * rhs.e13.0) - S(self.e13oi.0 * rhs.e13oi.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) - S(self.e23oi.0 * rhs.e23oi.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : -E1(self.e23.0 * rhs.e123.0) - E1(self.e23oi.0 * rhs.e123oi.0) + E1(self.e2i.0 * rhs.e12o.0) + E1(self.e2o.0 * rhs.e12i.0) + E1(self.e3i.0 * rhs.e13o.0) + E1(self.e3o.0 * rhs.e13i.0) + E1(self.eoi.0 * rhs.e1oi.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2    : E2(self.e13.0 * rhs.e123.0) + E2(self.e13oi.0 * rhs.e123oi.0) - E2(self.e1i.0 * rhs.e12o.0) - E2(self.e1o.0 * rhs.e12i.0) + E2(self.e3i.0 * rhs.e23o.0) + E2(self.e3o.0 * rhs.e23i.0) + E2(self.eoi.0 * rhs.e2oi.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3    : -E3(self.e12.0 * rhs.e123.0) - E3(self.e12oi.0 * rhs.e123oi.0) - E3(self.e1i.0 * rhs.e13o.0) - E3(self.e1o.0 * rhs.e13i.0) - E3(self.e2i.0 * rhs.e23o.0) - E3(self.e2o.0 * rhs.e23i.0) + E3(self.eoi.0 * rhs.e3oi.0) + E3(self.s.0 * rhs.e3.0),
+		//     eo    : -Eo(self.e12.0 * rhs.e12o.0) + Eo(self.e123o.0 * rhs.e123oi.0) - Eo(self.e13.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1oi.0) - Eo(self.e23.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2oi.0) - Eo(self.e3o.0 * rhs.e3oi.0) + Eo(self.s.0 * rhs.eo.0),
+		//     ei    : -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e123i.0 * rhs.e123oi.0) - Ei(self.e13.0 * rhs.e13i.0) + Ei(self.e1i.0 * rhs.e1oi.0) - Ei(self.e23.0 * rhs.e23i.0) + Ei(self.e2i.0 * rhs.e2oi.0) + Ei(self.e3i.0 * rhs.e3oi.0) + Ei(self.s.0 * rhs.ei.0),
+		//     e12   : E12(self.e3i.0 * rhs.e123o.0) + E12(self.e3o.0 * rhs.e123i.0) + E12(self.eoi.0 * rhs.e12oi.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13   : -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0) + E13(self.eoi.0 * rhs.e13oi.0) + E13(self.s.0 * rhs.e13.0),
+		//     e1o   : -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0) + E1o(self.s.0 * rhs.e1o.0),
+		//     e1i   : -E1i(self.e23.0 * rhs.e123i.0) + E1i(self.e2i.0 * rhs.e12oi.0) + E1i(self.e3i.0 * rhs.e13oi.0) + E1i(self.s.0 * rhs.e1i.0),
+		//     e23   : E23(self.e1i.0 * rhs.e123o.0) + E23(self.e1o.0 * rhs.e123i.0) + E23(self.eoi.0 * rhs.e23oi.0) + E23(self.s.0 * rhs.e23.0),
+		//     e2o   : E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0) + E2o(self.s.0 * rhs.e2o.0),
+		//     e2i   : E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0) + E2i(self.e3i.0 * rhs.e23oi.0) + E2i(self.s.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.e12.0 * rhs.e123o.0) + E3o(self.e1o.0 * rhs.e13oi.0) + E3o(self.e2o.0 * rhs.e23oi.0) + E3o(self.s.0 * rhs.e3o.0),
+		//     e3i   : -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0) + E3i(self.s.0 * rhs.e3i.0),
+		//     eoi   : -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0) + Eoi(self.s.0 * rhs.eoi.0),
+		//     e123  : E123(self.eoi.0 * rhs.e123oi.0) + E123(self.s.0 * rhs.e123.0),
+		//     e12o  : -E12o(self.e3o.0 * rhs.e123oi.0) + E12o(self.s.0 * rhs.e12o.0),
+		//     e12i  : E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.s.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e2o.0 * rhs.e123oi.0) + E13o(self.s.0 * rhs.e13o.0),
+		//     e13i  : -E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.s.0 * rhs.e13i.0),
+		//     e1oi  : -E1oi(self.e23.0 * rhs.e123oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+		//     e23o  : -E23o(self.e1o.0 * rhs.e123oi.0) + E23o(self.s.0 * rhs.e23o.0),
+		//     e23i  : E23i(self.e1i.0 * rhs.e123oi.0) + E23i(self.s.0 * rhs.e23i.0),
+		//     e2oi  : E2oi(self.e13.0 * rhs.e123oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+		//     e3oi  : -E3oi(self.e12.0 * rhs.e123oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+		//     e123o : E123o(self.s.0 * rhs.e123o.0),
+		//     e123i : E123i(self.s.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.s.0 * rhs.e12oi.0),
+		//     e13oi : E13oi(self.s.0 * rhs.e13oi.0),
+		//     e23oi : E23oi(self.s.0 * rhs.e23oi.0),
+		//     e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0)
+				- S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13.0 * rhs.e13.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				- S(self.e23oi.0 * rhs.e23oi.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: -E1(self.e23.0 * rhs.e123.0) - E1(self.e23oi.0 * rhs.e123oi.0)
+				+ E1(self.e2i.0 * rhs.e12o.0)
+				+ E1(self.e2o.0 * rhs.e12i.0)
+				+ E1(self.e3i.0 * rhs.e13o.0)
+				+ E1(self.e3o.0 * rhs.e13i.0)
+				+ E1(self.eoi.0 * rhs.e1oi.0)
+				+ E1(self.s.0 * rhs.e1.0),
+			e2: E2(self.e13.0 * rhs.e123.0) + E2(self.e13oi.0 * rhs.e123oi.0)
+				- E2(self.e1i.0 * rhs.e12o.0)
+				- E2(self.e1o.0 * rhs.e12i.0)
+				+ E2(self.e3i.0 * rhs.e23o.0)
+				+ E2(self.e3o.0 * rhs.e23i.0)
+				+ E2(self.eoi.0 * rhs.e2oi.0)
+				+ E2(self.s.0 * rhs.e2.0),
+			e3: -E3(self.e12.0 * rhs.e123.0)
+				- E3(self.e12oi.0 * rhs.e123oi.0)
+				- E3(self.e1i.0 * rhs.e13o.0)
+				- E3(self.e1o.0 * rhs.e13i.0)
+				- E3(self.e2i.0 * rhs.e23o.0)
+				- E3(self.e2o.0 * rhs.e23i.0)
+				+ E3(self.eoi.0 * rhs.e3oi.0)
+				+ E3(self.s.0 * rhs.e3.0),
+			eo: -Eo(self.e12.0 * rhs.e12o.0) + Eo(self.e123o.0 * rhs.e123oi.0)
+				- Eo(self.e13.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1oi.0)
+				- Eo(self.e23.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2oi.0)
+				- Eo(self.e3o.0 * rhs.e3oi.0)
+				+ Eo(self.s.0 * rhs.eo.0),
+			ei: -Ei(self.e12.0 * rhs.e12i.0) - Ei(self.e123i.0 * rhs.e123oi.0) - Ei(self.e13.0 * rhs.e13i.0)
+				+ Ei(self.e1i.0 * rhs.e1oi.0)
+				- Ei(self.e23.0 * rhs.e23i.0)
+				+ Ei(self.e2i.0 * rhs.e2oi.0)
+				+ Ei(self.e3i.0 * rhs.e3oi.0)
+				+ Ei(self.s.0 * rhs.ei.0),
+			e12: E12(self.e3i.0 * rhs.e123o.0)
+				+ E12(self.e3o.0 * rhs.e123i.0)
+				+ E12(self.eoi.0 * rhs.e12oi.0)
+				+ E12(self.s.0 * rhs.e12.0),
+			e13: -E13(self.e2i.0 * rhs.e123o.0) - E13(self.e2o.0 * rhs.e123i.0)
+				+ E13(self.eoi.0 * rhs.e13oi.0)
+				+ E13(self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e23.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12oi.0) - E1o(self.e3o.0 * rhs.e13oi.0)
+				+ E1o(self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e23.0 * rhs.e123i.0)
+				+ E1i(self.e2i.0 * rhs.e12oi.0)
+				+ E1i(self.e3i.0 * rhs.e13oi.0)
+				+ E1i(self.s.0 * rhs.e1i.0),
+			e23: E23(self.e1i.0 * rhs.e123o.0)
+				+ E23(self.e1o.0 * rhs.e123i.0)
+				+ E23(self.eoi.0 * rhs.e23oi.0)
+				+ E23(self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e13.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12oi.0) - E2o(self.e3o.0 * rhs.e23oi.0)
+				+ E2o(self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e13.0 * rhs.e123i.0) - E2i(self.e1i.0 * rhs.e12oi.0)
+				+ E2i(self.e3i.0 * rhs.e23oi.0)
+				+ E2i(self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * rhs.e123o.0)
+				+ E3o(self.e1o.0 * rhs.e13oi.0)
+				+ E3o(self.e2o.0 * rhs.e23oi.0)
+				+ E3o(self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * rhs.e123i.0) - E3i(self.e1i.0 * rhs.e13oi.0) - E3i(self.e2i.0 * rhs.e23oi.0)
+				+ E3i(self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * rhs.e12oi.0) - Eoi(self.e13.0 * rhs.e13oi.0) - Eoi(self.e23.0 * rhs.e23oi.0)
+				+ Eoi(self.s.0 * rhs.eoi.0),
+			e123: E123(self.eoi.0 * rhs.e123oi.0) + E123(self.s.0 * rhs.e123.0),
+			e12o: -E12o(self.e3o.0 * rhs.e123oi.0) + E12o(self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e3i.0 * rhs.e123oi.0) + E12i(self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.e2o.0 * rhs.e123oi.0) + E13o(self.s.0 * rhs.e13o.0),
+			e13i: -E13i(self.e2i.0 * rhs.e123oi.0) + E13i(self.s.0 * rhs.e13i.0),
+			e1oi: -E1oi(self.e23.0 * rhs.e123oi.0) + E1oi(self.s.0 * rhs.e1oi.0),
+			e23o: -E23o(self.e1o.0 * rhs.e123oi.0) + E23o(self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.e1i.0 * rhs.e123oi.0) + E23i(self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.e13.0 * rhs.e123oi.0) + E2oi(self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e12.0 * rhs.e123oi.0) + E3oi(self.s.0 * rhs.e3oi.0),
+			e123o: E123o(self.s.0 * rhs.e123o.0),
+			e123i: E123i(self.s.0 * rhs.e123i.0),
+			e12oi: E12oi(self.s.0 * rhs.e12oi.0),
+			e13oi: E13oi(self.s.0 * rhs.e13oi.0),
+			e23oi: E23oi(self.s.0 * rhs.e23oi.0),
+			e123oi: E123oi(self.s.0 * rhs.e123oi.0),
+		}
+	}
+}
+
+// Versor.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Versor {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : -S(self.e12.0 * rhs.e12.0) - S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13.0 * rhs.e13.0) - S(self.e13oi.0 * rhs.e13oi.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) - S(self.e23oi.0 * rhs.e23oi.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0) + S(self.s.0 * rhs.s.0),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13.0 * rhs.e3.0) + E1(self.e13oi.0 * rhs.e3oi.0) - E1(self.e1i.0 * rhs.eo.0) - E1(self.e1o.0 * rhs.ei.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) - E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23.0 * rhs.e3.0) + E2(self.e23oi.0 * rhs.e3oi.0) - E2(self.e2i.0 * rhs.eo.0) - E2(self.e2o.0 * rhs.ei.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13.0 * rhs.e1.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23.0 * rhs.e2.0) - E3(self.e23oi.0 * rhs.e2oi.0) - E3(self.e3i.0 * rhs.eo.0) - E3(self.e3o.0 * rhs.ei.0),
+		//     eo    : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1.0) + Eo(self.e23oi.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2.0) - Eo(self.e3o.0 * rhs.e3.0) - Eo(self.eoi.0 * rhs.eo.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e23oi.0 * rhs.e23i.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.eoi.0 * rhs.ei.0),
+		//     e12   : E12(self.e12.0 * rhs.s.0) + E12(self.e123i.0 * rhs.e3o.0) + E12(self.e123o.0 * rhs.e3i.0) + E12(self.e12oi.0 * rhs.eoi.0),
+		//     e13   : -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e13oi.0 * rhs.eoi.0),
+		//     e1o   : -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.s.0),
+		//     e1i   : -E1i(self.e123i.0 * rhs.e23.0) + E1i(self.e12oi.0 * rhs.e2i.0) + E1i(self.e13oi.0 * rhs.e3i.0) + E1i(self.e1i.0 * rhs.s.0),
+		//     e23   : E23(self.e123i.0 * rhs.e1o.0) + E23(self.e123o.0 * rhs.e1i.0) + E23(self.e23.0 * rhs.s.0) + E23(self.e23oi.0 * rhs.eoi.0),
+		//     e2o   : E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.s.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0) + E2i(self.e23oi.0 * rhs.e3i.0) + E2i(self.e2i.0 * rhs.s.0),
+		//     e3o   : -E3o(self.e123o.0 * rhs.e12.0) + E3o(self.e13oi.0 * rhs.e1o.0) + E3o(self.e23oi.0 * rhs.e2o.0) + E3o(self.e3o.0 * rhs.s.0),
+		//     e3i   : -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0) + E3i(self.e3i.0 * rhs.s.0),
+		//     eoi   : -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0) + Eoi(self.eoi.0 * rhs.s.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+		//     e13o  : E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+		//     e1oi  : E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e23oi.0 * rhs.eo.0),
+		//     e23i  : -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+		//     e2oi  : -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+		//     e3oi  : -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+		//     e123o : E123o(self.e123o.0 * rhs.s.0),
+		//     e123i : E123i(self.e123i.0 * rhs.s.0),
+		//     e12oi : E12oi(self.e12oi.0 * rhs.s.0),
+		//     e13oi : E13oi(self.e13oi.0 * rhs.s.0),
+		//     e23oi : E23oi(self.e23oi.0 * rhs.s.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: -S(self.e12.0 * rhs.e12.0)
+				- S(self.e123i.0 * rhs.e123o.0)
+				- S(self.e123o.0 * rhs.e123i.0)
+				- S(self.e12oi.0 * rhs.e12oi.0)
+				- S(self.e13.0 * rhs.e13.0)
+				- S(self.e13oi.0 * rhs.e13oi.0)
+				+ S(self.e1i.0 * rhs.e1o.0)
+				+ S(self.e1o.0 * rhs.e1i.0)
+				- S(self.e23.0 * rhs.e23.0)
+				- S(self.e23oi.0 * rhs.e23oi.0)
+				+ S(self.e2i.0 * rhs.e2o.0)
+				+ S(self.e2o.0 * rhs.e2i.0)
+				+ S(self.e3i.0 * rhs.e3o.0)
+				+ S(self.e3o.0 * rhs.e3i.0)
+				+ S(self.eoi.0 * rhs.eoi.0)
+				+ S(self.s.0 * rhs.s.0),
+			e1: E1(self.e12.0 * rhs.e2.0)
+				+ E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13.0 * rhs.e3.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0)
+				- E1(self.e1i.0 * rhs.eo.0)
+				- E1(self.e1o.0 * rhs.ei.0),
+			e2: -E2(self.e12.0 * rhs.e1.0)
+				- E2(self.e123i.0 * rhs.e13o.0)
+				- E2(self.e123o.0 * rhs.e13i.0)
+				- E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e23.0 * rhs.e3.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0)
+				- E2(self.e2i.0 * rhs.eo.0)
+				- E2(self.e2o.0 * rhs.ei.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13.0 * rhs.e1.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23.0 * rhs.e2.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0)
+				- E3(self.e3i.0 * rhs.eo.0)
+				- E3(self.e3o.0 * rhs.ei.0),
+			eo: Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2.0)
+				- Eo(self.e3o.0 * rhs.e3.0)
+				- Eo(self.eoi.0 * rhs.eo.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e1i.0 * rhs.e1.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0)
+				- Ei(self.e2i.0 * rhs.e2.0)
+				- Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.eoi.0 * rhs.ei.0),
+			e12: E12(self.e12.0 * rhs.s.0)
+				+ E12(self.e123i.0 * rhs.e3o.0)
+				+ E12(self.e123o.0 * rhs.e3i.0)
+				+ E12(self.e12oi.0 * rhs.eoi.0),
+			e13: -E13(self.e123i.0 * rhs.e2o.0) - E13(self.e123o.0 * rhs.e2i.0)
+				+ E13(self.e13.0 * rhs.s.0)
+				+ E13(self.e13oi.0 * rhs.eoi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e23.0) - E1o(self.e12oi.0 * rhs.e2o.0) - E1o(self.e13oi.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.s.0),
+			e1i: -E1i(self.e123i.0 * rhs.e23.0)
+				+ E1i(self.e12oi.0 * rhs.e2i.0)
+				+ E1i(self.e13oi.0 * rhs.e3i.0)
+				+ E1i(self.e1i.0 * rhs.s.0),
+			e23: E23(self.e123i.0 * rhs.e1o.0)
+				+ E23(self.e123o.0 * rhs.e1i.0)
+				+ E23(self.e23.0 * rhs.s.0)
+				+ E23(self.e23oi.0 * rhs.eoi.0),
+			e2o: E2o(self.e123o.0 * rhs.e13.0) + E2o(self.e12oi.0 * rhs.e1o.0) - E2o(self.e23oi.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.s.0),
+			e2i: E2i(self.e123i.0 * rhs.e13.0) - E2i(self.e12oi.0 * rhs.e1i.0)
+				+ E2i(self.e23oi.0 * rhs.e3i.0)
+				+ E2i(self.e2i.0 * rhs.s.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12.0)
+				+ E3o(self.e13oi.0 * rhs.e1o.0)
+				+ E3o(self.e23oi.0 * rhs.e2o.0)
+				+ E3o(self.e3o.0 * rhs.s.0),
+			e3i: -E3i(self.e123i.0 * rhs.e12.0) - E3i(self.e13oi.0 * rhs.e1i.0) - E3i(self.e23oi.0 * rhs.e2i.0)
+				+ E3i(self.e3i.0 * rhs.s.0),
+			eoi: -Eoi(self.e12oi.0 * rhs.e12.0) - Eoi(self.e13oi.0 * rhs.e13.0) - Eoi(self.e23oi.0 * rhs.e23.0)
+				+ Eoi(self.eoi.0 * rhs.s.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0),
+			e13o: E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0),
+			e13i: E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0),
+			e1oi: E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13oi.0 * rhs.e3.0),
+			e23o: -E23o(self.e123o.0 * rhs.e1.0) - E23o(self.e23oi.0 * rhs.eo.0),
+			e23i: -E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e23oi.0 * rhs.ei.0),
+			e2oi: -E2oi(self.e12oi.0 * rhs.e1.0) + E2oi(self.e23oi.0 * rhs.e3.0),
+			e3oi: -E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e23oi.0 * rhs.e2.0),
+			e123o: E123o(self.e123o.0 * rhs.s.0),
+			e123i: E123i(self.e123i.0 * rhs.s.0),
+			e12oi: E12oi(self.e12oi.0 * rhs.s.0),
+			e13oi: E13oi(self.e13oi.0 * rhs.s.0),
+			e23oi: E23oi(self.e23oi.0 * rhs.s.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Versor.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Versor {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.e12.0 * rhs.e12.0) - S(self.e123i.0 * rhs.e123o.0) - S(self.e123o.0 * rhs.e123i.0) - S(self.e12oi.0 * rhs.e12oi.0) - S(self.e13.0 * rhs.e13.0) - S(self.e13oi.0 * rhs.e13oi.0) + S(self.e1i.0 * rhs.e1o.0) + S(self.e1o.0 * rhs.e1i.0) - S(self.e23.0 * rhs.e23.0) - S(self.e23oi.0 * rhs.e23oi.0) + S(self.e2i.0 * rhs.e2o.0) + S(self.e2o.0 * rhs.e2i.0) + S(self.e3i.0 * rhs.e3o.0) + S(self.e3o.0 * rhs.e3i.0) + S(self.eoi.0 * rhs.eoi.0) + S(self.s.0 * rhs.s.0)
+		-S(self.e12.0 * rhs.e12.0)
+			- S(self.e123i.0 * rhs.e123o.0)
+			- S(self.e123o.0 * rhs.e123i.0)
+			- S(self.e12oi.0 * rhs.e12oi.0)
+			- S(self.e13.0 * rhs.e13.0)
+			- S(self.e13oi.0 * rhs.e13oi.0)
+			+ S(self.e1i.0 * rhs.e1o.0)
+			+ S(self.e1o.0 * rhs.e1i.0)
+			- S(self.e23.0 * rhs.e23.0)
+			- S(self.e23oi.0 * rhs.e23oi.0)
+			+ S(self.e2i.0 * rhs.e2o.0)
+			+ S(self.e2o.0 * rhs.e2i.0)
+			+ S(self.e3i.0 * rhs.e3o.0)
+			+ S(self.e3o.0 * rhs.e3i.0)
+			+ S(self.eoi.0 * rhs.eoi.0)
+			+ S(self.s.0 * rhs.s.0)
+	}
+}
+
+// Versor.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Versor {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : Default::default(),
+		//     e1    : E1(self.e12.0 * rhs.e2.0) + E1(self.e123i.0 * rhs.e23o.0) + E1(self.e123o.0 * rhs.e23i.0) + E1(self.e12oi.0 * rhs.e2oi.0) + E1(self.e13.0 * rhs.e3.0) + E1(self.e13oi.0 * rhs.e3oi.0) - E1(self.e1i.0 * rhs.eo.0) - E1(self.e1o.0 * rhs.ei.0),
+		//     e2    : -E2(self.e12.0 * rhs.e1.0) - E2(self.e123i.0 * rhs.e13o.0) - E2(self.e123o.0 * rhs.e13i.0) - E2(self.e12oi.0 * rhs.e1oi.0) + E2(self.e23.0 * rhs.e3.0) + E2(self.e23oi.0 * rhs.e3oi.0) - E2(self.e2i.0 * rhs.eo.0) - E2(self.e2o.0 * rhs.ei.0),
+		//     e3    : E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0) - E3(self.e13.0 * rhs.e1.0) - E3(self.e13oi.0 * rhs.e1oi.0) - E3(self.e23.0 * rhs.e2.0) - E3(self.e23oi.0 * rhs.e2oi.0) - E3(self.e3i.0 * rhs.eo.0) - E3(self.e3o.0 * rhs.ei.0),
+		//     eo    : Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0) - Eo(self.e1o.0 * rhs.e1.0) + Eo(self.e23oi.0 * rhs.e23o.0) - Eo(self.e2o.0 * rhs.e2.0) - Eo(self.e3o.0 * rhs.e3.0) - Eo(self.eoi.0 * rhs.eo.0),
+		//     ei    : Ei(self.e123i.0 * rhs.e123.0) - Ei(self.e12oi.0 * rhs.e12i.0) - Ei(self.e13oi.0 * rhs.e13i.0) - Ei(self.e1i.0 * rhs.e1.0) - Ei(self.e23oi.0 * rhs.e23i.0) - Ei(self.e2i.0 * rhs.e2.0) - Ei(self.e3i.0 * rhs.e3.0) + Ei(self.eoi.0 * rhs.ei.0),
+		//     e12   : -E12(self.e13.0 * rhs.e23.0) - E12(self.e13oi.0 * rhs.e23oi.0) + E12(self.e1i.0 * rhs.e2o.0) + E12(self.e1o.0 * rhs.e2i.0) + E12(self.e23.0 * rhs.e13.0) + E12(self.e23oi.0 * rhs.e13oi.0) - E12(self.e2i.0 * rhs.e1o.0) - E12(self.e2o.0 * rhs.e1i.0),
+		//     e13   : E13(self.e12.0 * rhs.e23.0) + E13(self.e12oi.0 * rhs.e23oi.0) + E13(self.e1i.0 * rhs.e3o.0) + E13(self.e1o.0 * rhs.e3i.0) - E13(self.e23.0 * rhs.e12.0) - E13(self.e23oi.0 * rhs.e12oi.0) - E13(self.e3i.0 * rhs.e1o.0) - E13(self.e3o.0 * rhs.e1i.0),
+		//     e1o   : E1o(self.e12.0 * rhs.e2o.0) - E1o(self.e123o.0 * rhs.e23oi.0) + E1o(self.e13.0 * rhs.e3o.0) + E1o(self.e1o.0 * rhs.eoi.0) + E1o(self.e23oi.0 * rhs.e123o.0) - E1o(self.e2o.0 * rhs.e12.0) - E1o(self.e3o.0 * rhs.e13.0) - E1o(self.eoi.0 * rhs.e1o.0),
+		//     e1i   : E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e123i.0 * rhs.e23oi.0) + E1i(self.e13.0 * rhs.e3i.0) - E1i(self.e1i.0 * rhs.eoi.0) - E1i(self.e23oi.0 * rhs.e123i.0) - E1i(self.e2i.0 * rhs.e12.0) - E1i(self.e3i.0 * rhs.e13.0) + E1i(self.eoi.0 * rhs.e1i.0),
+		//     e23   : -E23(self.e12.0 * rhs.e13.0) - E23(self.e12oi.0 * rhs.e13oi.0) + E23(self.e13.0 * rhs.e12.0) + E23(self.e13oi.0 * rhs.e12oi.0) + E23(self.e2i.0 * rhs.e3o.0) + E23(self.e2o.0 * rhs.e3i.0) - E23(self.e3i.0 * rhs.e2o.0) - E23(self.e3o.0 * rhs.e2i.0),
+		//     e2o   : -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0) + E2o(self.e1o.0 * rhs.e12.0) + E2o(self.e23.0 * rhs.e3o.0) + E2o(self.e2o.0 * rhs.eoi.0) - E2o(self.e3o.0 * rhs.e23.0) - E2o(self.eoi.0 * rhs.e2o.0),
+		//     e2i   : -E2i(self.e12.0 * rhs.e1i.0) - E2i(self.e123i.0 * rhs.e13oi.0) + E2i(self.e13oi.0 * rhs.e123i.0) + E2i(self.e1i.0 * rhs.e12.0) + E2i(self.e23.0 * rhs.e3i.0) - E2i(self.e2i.0 * rhs.eoi.0) - E2i(self.e3i.0 * rhs.e23.0) + E2i(self.eoi.0 * rhs.e2i.0),
+		//     e3o   : -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0) + E3o(self.e1o.0 * rhs.e13.0) - E3o(self.e23.0 * rhs.e2o.0) + E3o(self.e2o.0 * rhs.e23.0) + E3o(self.e3o.0 * rhs.eoi.0) - E3o(self.eoi.0 * rhs.e3o.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0) + E3i(self.e1i.0 * rhs.e13.0) - E3i(self.e23.0 * rhs.e2i.0) + E3i(self.e2i.0 * rhs.e23.0) - E3i(self.e3i.0 * rhs.eoi.0) + E3i(self.eoi.0 * rhs.e3i.0),
+		//     eoi   : -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0) + Eoi(self.e1i.0 * rhs.e1o.0) - Eoi(self.e1o.0 * rhs.e1i.0) + Eoi(self.e2i.0 * rhs.e2o.0) - Eoi(self.e2o.0 * rhs.e2i.0) + Eoi(self.e3i.0 * rhs.e3o.0) - Eoi(self.e3o.0 * rhs.e3i.0),
+		//     e123  : -E123(self.e123i.0 * rhs.eo.0) - E123(self.e123o.0 * rhs.ei.0) - E123(self.e1i.0 * rhs.e23o.0) - E123(self.e1o.0 * rhs.e23i.0) + E123(self.e2i.0 * rhs.e13o.0) + E123(self.e2o.0 * rhs.e13i.0) - E123(self.e3i.0 * rhs.e12o.0) - E123(self.e3o.0 * rhs.e12i.0),
+		//     e12o  : -E12o(self.e123o.0 * rhs.e3.0) - E12o(self.e12oi.0 * rhs.eo.0) - E12o(self.e13.0 * rhs.e23o.0) - E12o(self.e1o.0 * rhs.e2oi.0) + E12o(self.e23.0 * rhs.e13o.0) + E12o(self.e2o.0 * rhs.e1oi.0) - E12o(self.e3o.0 * rhs.e123.0) - E12o(self.eoi.0 * rhs.e12o.0),
+		//     e12i  : -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0) - E12i(self.e13.0 * rhs.e23i.0) + E12i(self.e1i.0 * rhs.e2oi.0) + E12i(self.e23.0 * rhs.e13i.0) - E12i(self.e2i.0 * rhs.e1oi.0) - E12i(self.e3i.0 * rhs.e123.0) + E12i(self.eoi.0 * rhs.e12i.0),
+		//     e13o  : E13o(self.e12.0 * rhs.e23o.0) + E13o(self.e123o.0 * rhs.e2.0) - E13o(self.e13oi.0 * rhs.eo.0) - E13o(self.e1o.0 * rhs.e3oi.0) - E13o(self.e23.0 * rhs.e12o.0) + E13o(self.e2o.0 * rhs.e123.0) + E13o(self.e3o.0 * rhs.e1oi.0) - E13o(self.eoi.0 * rhs.e13o.0),
+		//     e13i  : E13i(self.e12.0 * rhs.e23i.0) + E13i(self.e123i.0 * rhs.e2.0) + E13i(self.e13oi.0 * rhs.ei.0) + E13i(self.e1i.0 * rhs.e3oi.0) - E13i(self.e23.0 * rhs.e12i.0) + E13i(self.e2i.0 * rhs.e123.0) - E13i(self.e3i.0 * rhs.e1oi.0) + E13i(self.eoi.0 * rhs.e13i.0),
+		//     e1oi  : E1oi(self.e12.0 * rhs.e2oi.0) + E1oi(self.e12oi.0 * rhs.e2.0) + E1oi(self.e13.0 * rhs.e3oi.0) + E1oi(self.e13oi.0 * rhs.e3.0) + E1oi(self.e2i.0 * rhs.e12o.0) - E1oi(self.e2o.0 * rhs.e12i.0) + E1oi(self.e3i.0 * rhs.e13o.0) - E1oi(self.e3o.0 * rhs.e13i.0),
+		//     e23o  : -E23o(self.e12.0 * rhs.e13o.0) - E23o(self.e123o.0 * rhs.e1.0) + E23o(self.e13.0 * rhs.e12o.0) - E23o(self.e1o.0 * rhs.e123.0) - E23o(self.e23oi.0 * rhs.eo.0) - E23o(self.e2o.0 * rhs.e3oi.0) + E23o(self.e3o.0 * rhs.e2oi.0) - E23o(self.eoi.0 * rhs.e23o.0),
+		//     e23i  : -E23i(self.e12.0 * rhs.e13i.0) - E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e13.0 * rhs.e12i.0) - E23i(self.e1i.0 * rhs.e123.0) + E23i(self.e23oi.0 * rhs.ei.0) + E23i(self.e2i.0 * rhs.e3oi.0) - E23i(self.e3i.0 * rhs.e2oi.0) + E23i(self.eoi.0 * rhs.e23i.0),
+		//     e2oi  : -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e12oi.0 * rhs.e1.0) - E2oi(self.e1i.0 * rhs.e12o.0) + E2oi(self.e1o.0 * rhs.e12i.0) + E2oi(self.e23.0 * rhs.e3oi.0) + E2oi(self.e23oi.0 * rhs.e3.0) + E2oi(self.e3i.0 * rhs.e23o.0) - E2oi(self.e3o.0 * rhs.e23i.0),
+		//     e3oi  : -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e1i.0 * rhs.e13o.0) + E3oi(self.e1o.0 * rhs.e13i.0) - E3oi(self.e23.0 * rhs.e2oi.0) - E3oi(self.e23oi.0 * rhs.e2.0) - E3oi(self.e2i.0 * rhs.e23o.0) + E3oi(self.e2o.0 * rhs.e23i.0),
+		//     e123o : E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0) + E123o(self.e13oi.0 * rhs.e2o.0) + E123o(self.e1o.0 * rhs.e23oi.0) - E123o(self.e23oi.0 * rhs.e1o.0) - E123o(self.e2o.0 * rhs.e13oi.0) + E123o(self.e3o.0 * rhs.e12oi.0) - E123o(self.eoi.0 * rhs.e123o.0),
+		//     e123i : -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0) - E123i(self.e13oi.0 * rhs.e2i.0) - E123i(self.e1i.0 * rhs.e23oi.0) + E123i(self.e23oi.0 * rhs.e1i.0) + E123i(self.e2i.0 * rhs.e13oi.0) - E123i(self.e3i.0 * rhs.e12oi.0) + E123i(self.eoi.0 * rhs.e123i.0),
+		//     e12oi : E12oi(self.e123i.0 * rhs.e3o.0) - E12oi(self.e123o.0 * rhs.e3i.0) - E12oi(self.e13.0 * rhs.e23oi.0) - E12oi(self.e13oi.0 * rhs.e23.0) + E12oi(self.e23.0 * rhs.e13oi.0) + E12oi(self.e23oi.0 * rhs.e13.0) + E12oi(self.e3i.0 * rhs.e123o.0) - E12oi(self.e3o.0 * rhs.e123i.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e123i.0 * rhs.e2o.0) + E13oi(self.e123o.0 * rhs.e2i.0) + E13oi(self.e12oi.0 * rhs.e23.0) - E13oi(self.e23.0 * rhs.e12oi.0) - E13oi(self.e23oi.0 * rhs.e12.0) - E13oi(self.e2i.0 * rhs.e123o.0) + E13oi(self.e2o.0 * rhs.e123i.0),
+		//     e23oi : -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e123i.0 * rhs.e1o.0) - E23oi(self.e123o.0 * rhs.e1i.0) - E23oi(self.e12oi.0 * rhs.e13.0) + E23oi(self.e13.0 * rhs.e12oi.0) + E23oi(self.e13oi.0 * rhs.e12.0) + E23oi(self.e1i.0 * rhs.e123o.0) - E23oi(self.e1o.0 * rhs.e123i.0),
+		//     e123oi: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e1: E1(self.e12.0 * rhs.e2.0)
+				+ E1(self.e123i.0 * rhs.e23o.0)
+				+ E1(self.e123o.0 * rhs.e23i.0)
+				+ E1(self.e12oi.0 * rhs.e2oi.0)
+				+ E1(self.e13.0 * rhs.e3.0)
+				+ E1(self.e13oi.0 * rhs.e3oi.0)
+				- E1(self.e1i.0 * rhs.eo.0)
+				- E1(self.e1o.0 * rhs.ei.0),
+			e2: -E2(self.e12.0 * rhs.e1.0)
+				- E2(self.e123i.0 * rhs.e13o.0)
+				- E2(self.e123o.0 * rhs.e13i.0)
+				- E2(self.e12oi.0 * rhs.e1oi.0)
+				+ E2(self.e23.0 * rhs.e3.0)
+				+ E2(self.e23oi.0 * rhs.e3oi.0)
+				- E2(self.e2i.0 * rhs.eo.0)
+				- E2(self.e2o.0 * rhs.ei.0),
+			e3: E3(self.e123i.0 * rhs.e12o.0) + E3(self.e123o.0 * rhs.e12i.0)
+				- E3(self.e13.0 * rhs.e1.0)
+				- E3(self.e13oi.0 * rhs.e1oi.0)
+				- E3(self.e23.0 * rhs.e2.0)
+				- E3(self.e23oi.0 * rhs.e2oi.0)
+				- E3(self.e3i.0 * rhs.eo.0)
+				- E3(self.e3o.0 * rhs.ei.0),
+			eo: Eo(self.e123o.0 * rhs.e123.0) + Eo(self.e12oi.0 * rhs.e12o.0) + Eo(self.e13oi.0 * rhs.e13o.0)
+				- Eo(self.e1o.0 * rhs.e1.0)
+				+ Eo(self.e23oi.0 * rhs.e23o.0)
+				- Eo(self.e2o.0 * rhs.e2.0)
+				- Eo(self.e3o.0 * rhs.e3.0)
+				- Eo(self.eoi.0 * rhs.eo.0),
+			ei: Ei(self.e123i.0 * rhs.e123.0)
+				- Ei(self.e12oi.0 * rhs.e12i.0)
+				- Ei(self.e13oi.0 * rhs.e13i.0)
+				- Ei(self.e1i.0 * rhs.e1.0)
+				- Ei(self.e23oi.0 * rhs.e23i.0)
+				- Ei(self.e2i.0 * rhs.e2.0)
+				- Ei(self.e3i.0 * rhs.e3.0)
+				+ Ei(self.eoi.0 * rhs.ei.0),
+			e12: -E12(self.e13.0 * rhs.e23.0) - E12(self.e13oi.0 * rhs.e23oi.0)
+				+ E12(self.e1i.0 * rhs.e2o.0)
+				+ E12(self.e1o.0 * rhs.e2i.0)
+				+ E12(self.e23.0 * rhs.e13.0)
+				+ E12(self.e23oi.0 * rhs.e13oi.0)
+				- E12(self.e2i.0 * rhs.e1o.0)
+				- E12(self.e2o.0 * rhs.e1i.0),
+			e13: E13(self.e12.0 * rhs.e23.0)
+				+ E13(self.e12oi.0 * rhs.e23oi.0)
+				+ E13(self.e1i.0 * rhs.e3o.0)
+				+ E13(self.e1o.0 * rhs.e3i.0)
+				- E13(self.e23.0 * rhs.e12.0)
+				- E13(self.e23oi.0 * rhs.e12oi.0)
+				- E13(self.e3i.0 * rhs.e1o.0)
+				- E13(self.e3o.0 * rhs.e1i.0),
+			e1o: E1o(self.e12.0 * rhs.e2o.0) - E1o(self.e123o.0 * rhs.e23oi.0)
+				+ E1o(self.e13.0 * rhs.e3o.0)
+				+ E1o(self.e1o.0 * rhs.eoi.0)
+				+ E1o(self.e23oi.0 * rhs.e123o.0)
+				- E1o(self.e2o.0 * rhs.e12.0)
+				- E1o(self.e3o.0 * rhs.e13.0)
+				- E1o(self.eoi.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * rhs.e2i.0) + E1i(self.e123i.0 * rhs.e23oi.0) + E1i(self.e13.0 * rhs.e3i.0)
+				- E1i(self.e1i.0 * rhs.eoi.0)
+				- E1i(self.e23oi.0 * rhs.e123i.0)
+				- E1i(self.e2i.0 * rhs.e12.0)
+				- E1i(self.e3i.0 * rhs.e13.0)
+				+ E1i(self.eoi.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * rhs.e13.0) - E23(self.e12oi.0 * rhs.e13oi.0)
+				+ E23(self.e13.0 * rhs.e12.0)
+				+ E23(self.e13oi.0 * rhs.e12oi.0)
+				+ E23(self.e2i.0 * rhs.e3o.0)
+				+ E23(self.e2o.0 * rhs.e3i.0)
+				- E23(self.e3i.0 * rhs.e2o.0)
+				- E23(self.e3o.0 * rhs.e2i.0),
+			e2o: -E2o(self.e12.0 * rhs.e1o.0) + E2o(self.e123o.0 * rhs.e13oi.0) - E2o(self.e13oi.0 * rhs.e123o.0)
+				+ E2o(self.e1o.0 * rhs.e12.0)
+				+ E2o(self.e23.0 * rhs.e3o.0)
+				+ E2o(self.e2o.0 * rhs.eoi.0)
+				- E2o(self.e3o.0 * rhs.e23.0)
+				- E2o(self.eoi.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * rhs.e1i.0) - E2i(self.e123i.0 * rhs.e13oi.0)
+				+ E2i(self.e13oi.0 * rhs.e123i.0)
+				+ E2i(self.e1i.0 * rhs.e12.0)
+				+ E2i(self.e23.0 * rhs.e3i.0)
+				- E2i(self.e2i.0 * rhs.eoi.0)
+				- E2i(self.e3i.0 * rhs.e23.0)
+				+ E2i(self.eoi.0 * rhs.e2i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e12oi.0) + E3o(self.e12oi.0 * rhs.e123o.0) - E3o(self.e13.0 * rhs.e1o.0)
+				+ E3o(self.e1o.0 * rhs.e13.0)
+				- E3o(self.e23.0 * rhs.e2o.0)
+				+ E3o(self.e2o.0 * rhs.e23.0)
+				+ E3o(self.e3o.0 * rhs.eoi.0)
+				- E3o(self.eoi.0 * rhs.e3o.0),
+			e3i: E3i(self.e123i.0 * rhs.e12oi.0) - E3i(self.e12oi.0 * rhs.e123i.0) - E3i(self.e13.0 * rhs.e1i.0)
+				+ E3i(self.e1i.0 * rhs.e13.0)
+				- E3i(self.e23.0 * rhs.e2i.0)
+				+ E3i(self.e2i.0 * rhs.e23.0)
+				- E3i(self.e3i.0 * rhs.eoi.0)
+				+ E3i(self.eoi.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e123i.0 * rhs.e123o.0) + Eoi(self.e123o.0 * rhs.e123i.0) + Eoi(self.e1i.0 * rhs.e1o.0)
+				- Eoi(self.e1o.0 * rhs.e1i.0)
+				+ Eoi(self.e2i.0 * rhs.e2o.0)
+				- Eoi(self.e2o.0 * rhs.e2i.0)
+				+ Eoi(self.e3i.0 * rhs.e3o.0)
+				- Eoi(self.e3o.0 * rhs.e3i.0),
+			e123: -E123(self.e123i.0 * rhs.eo.0)
+				- E123(self.e123o.0 * rhs.ei.0)
+				- E123(self.e1i.0 * rhs.e23o.0)
+				- E123(self.e1o.0 * rhs.e23i.0)
+				+ E123(self.e2i.0 * rhs.e13o.0)
+				+ E123(self.e2o.0 * rhs.e13i.0)
+				- E123(self.e3i.0 * rhs.e12o.0)
+				- E123(self.e3o.0 * rhs.e12i.0),
+			e12o: -E12o(self.e123o.0 * rhs.e3.0)
+				- E12o(self.e12oi.0 * rhs.eo.0)
+				- E12o(self.e13.0 * rhs.e23o.0)
+				- E12o(self.e1o.0 * rhs.e2oi.0)
+				+ E12o(self.e23.0 * rhs.e13o.0)
+				+ E12o(self.e2o.0 * rhs.e1oi.0)
+				- E12o(self.e3o.0 * rhs.e123.0)
+				- E12o(self.eoi.0 * rhs.e12o.0),
+			e12i: -E12i(self.e123i.0 * rhs.e3.0) + E12i(self.e12oi.0 * rhs.ei.0) - E12i(self.e13.0 * rhs.e23i.0)
+				+ E12i(self.e1i.0 * rhs.e2oi.0)
+				+ E12i(self.e23.0 * rhs.e13i.0)
+				- E12i(self.e2i.0 * rhs.e1oi.0)
+				- E12i(self.e3i.0 * rhs.e123.0)
+				+ E12i(self.eoi.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * rhs.e23o.0) + E13o(self.e123o.0 * rhs.e2.0)
+				- E13o(self.e13oi.0 * rhs.eo.0)
+				- E13o(self.e1o.0 * rhs.e3oi.0)
+				- E13o(self.e23.0 * rhs.e12o.0)
+				+ E13o(self.e2o.0 * rhs.e123.0)
+				+ E13o(self.e3o.0 * rhs.e1oi.0)
+				- E13o(self.eoi.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * rhs.e23i.0)
+				+ E13i(self.e123i.0 * rhs.e2.0)
+				+ E13i(self.e13oi.0 * rhs.ei.0)
+				+ E13i(self.e1i.0 * rhs.e3oi.0)
+				- E13i(self.e23.0 * rhs.e12i.0)
+				+ E13i(self.e2i.0 * rhs.e123.0)
+				- E13i(self.e3i.0 * rhs.e1oi.0)
+				+ E13i(self.eoi.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * rhs.e2oi.0)
+				+ E1oi(self.e12oi.0 * rhs.e2.0)
+				+ E1oi(self.e13.0 * rhs.e3oi.0)
+				+ E1oi(self.e13oi.0 * rhs.e3.0)
+				+ E1oi(self.e2i.0 * rhs.e12o.0)
+				- E1oi(self.e2o.0 * rhs.e12i.0)
+				+ E1oi(self.e3i.0 * rhs.e13o.0)
+				- E1oi(self.e3o.0 * rhs.e13i.0),
+			e23o: -E23o(self.e12.0 * rhs.e13o.0) - E23o(self.e123o.0 * rhs.e1.0) + E23o(self.e13.0 * rhs.e12o.0)
+				- E23o(self.e1o.0 * rhs.e123.0)
+				- E23o(self.e23oi.0 * rhs.eo.0)
+				- E23o(self.e2o.0 * rhs.e3oi.0)
+				+ E23o(self.e3o.0 * rhs.e2oi.0)
+				- E23o(self.eoi.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * rhs.e13i.0) - E23i(self.e123i.0 * rhs.e1.0) + E23i(self.e13.0 * rhs.e12i.0)
+				- E23i(self.e1i.0 * rhs.e123.0)
+				+ E23i(self.e23oi.0 * rhs.ei.0)
+				+ E23i(self.e2i.0 * rhs.e3oi.0)
+				- E23i(self.e3i.0 * rhs.e2oi.0)
+				+ E23i(self.eoi.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * rhs.e1oi.0) - E2oi(self.e12oi.0 * rhs.e1.0) - E2oi(self.e1i.0 * rhs.e12o.0)
+				+ E2oi(self.e1o.0 * rhs.e12i.0)
+				+ E2oi(self.e23.0 * rhs.e3oi.0)
+				+ E2oi(self.e23oi.0 * rhs.e3.0)
+				+ E2oi(self.e3i.0 * rhs.e23o.0)
+				- E2oi(self.e3o.0 * rhs.e23i.0),
+			e3oi: -E3oi(self.e13.0 * rhs.e1oi.0) - E3oi(self.e13oi.0 * rhs.e1.0) - E3oi(self.e1i.0 * rhs.e13o.0)
+				+ E3oi(self.e1o.0 * rhs.e13i.0)
+				- E3oi(self.e23.0 * rhs.e2oi.0)
+				- E3oi(self.e23oi.0 * rhs.e2.0)
+				- E3oi(self.e2i.0 * rhs.e23o.0)
+				+ E3oi(self.e2o.0 * rhs.e23i.0),
+			e123o: E123o(self.e123o.0 * rhs.eoi.0) - E123o(self.e12oi.0 * rhs.e3o.0)
+				+ E123o(self.e13oi.0 * rhs.e2o.0)
+				+ E123o(self.e1o.0 * rhs.e23oi.0)
+				- E123o(self.e23oi.0 * rhs.e1o.0)
+				- E123o(self.e2o.0 * rhs.e13oi.0)
+				+ E123o(self.e3o.0 * rhs.e12oi.0)
+				- E123o(self.eoi.0 * rhs.e123o.0),
+			e123i: -E123i(self.e123i.0 * rhs.eoi.0) + E123i(self.e12oi.0 * rhs.e3i.0)
+				- E123i(self.e13oi.0 * rhs.e2i.0)
+				- E123i(self.e1i.0 * rhs.e23oi.0)
+				+ E123i(self.e23oi.0 * rhs.e1i.0)
+				+ E123i(self.e2i.0 * rhs.e13oi.0)
+				- E123i(self.e3i.0 * rhs.e12oi.0)
+				+ E123i(self.eoi.0 * rhs.e123i.0),
+			e12oi: E12oi(self.e123i.0 * rhs.e3o.0)
+				- E12oi(self.e123o.0 * rhs.e3i.0)
+				- E12oi(self.e13.0 * rhs.e23oi.0)
+				- E12oi(self.e13oi.0 * rhs.e23.0)
+				+ E12oi(self.e23.0 * rhs.e13oi.0)
+				+ E12oi(self.e23oi.0 * rhs.e13.0)
+				+ E12oi(self.e3i.0 * rhs.e123o.0)
+				- E12oi(self.e3o.0 * rhs.e123i.0),
+			e13oi: E13oi(self.e12.0 * rhs.e23oi.0) - E13oi(self.e123i.0 * rhs.e2o.0)
+				+ E13oi(self.e123o.0 * rhs.e2i.0)
+				+ E13oi(self.e12oi.0 * rhs.e23.0)
+				- E13oi(self.e23.0 * rhs.e12oi.0)
+				- E13oi(self.e23oi.0 * rhs.e12.0)
+				- E13oi(self.e2i.0 * rhs.e123o.0)
+				+ E13oi(self.e2o.0 * rhs.e123i.0),
+			e23oi: -E23oi(self.e12.0 * rhs.e13oi.0) + E23oi(self.e123i.0 * rhs.e1o.0)
+				- E23oi(self.e123o.0 * rhs.e1i.0)
+				- E23oi(self.e12oi.0 * rhs.e13.0)
+				+ E23oi(self.e13.0 * rhs.e12oi.0)
+				+ E23oi(self.e13oi.0 * rhs.e12.0)
+				+ E23oi(self.e1i.0 * rhs.e123o.0)
+				- E23oi(self.e1o.0 * rhs.e123i.0),
+			e123oi: Default::default(),
+		}
+	}
+}
+
+// Versor.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Versor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s     : S(self.s.0 * rhs.e123oi.0),
+		//     e1    : E1(self.e23oi.0 * rhs.s.0) + E1(self.s.0 * rhs.e23oi.0),
+		//     e2    : -E2(self.e13oi.0 * rhs.s.0) - E2(self.s.0 * rhs.e13oi.0),
+		//     e3    : E3(self.e12oi.0 * rhs.s.0) + E3(self.s.0 * rhs.e12oi.0),
+		//     eo    : -Eo(self.e123o.0 * rhs.s.0) - Eo(self.s.0 * rhs.e123o.0),
+		//     ei    : Ei(self.e123i.0 * rhs.s.0) + Ei(self.s.0 * rhs.e123i.0),
+		//     e12   : E12(self.e12.0 * rhs.e123oi.0) - E12(self.e13oi.0 * rhs.e1.0) - E12(self.e23oi.0 * rhs.e2.0) - E12(self.s.0 * rhs.e3oi.0),
+		//     e13   : E13(self.e12oi.0 * rhs.e1.0) + E13(self.e13.0 * rhs.e123oi.0) - E13(self.e23oi.0 * rhs.e3.0) + E13(self.s.0 * rhs.e2oi.0),
+		//     e1o   : -E1o(self.e123o.0 * rhs.e1.0) + E1o(self.e1o.0 * rhs.e123oi.0) - E1o(self.e23oi.0 * rhs.eo.0) - E1o(self.s.0 * rhs.e23o.0),
+		//     e1i   : E1i(self.e123i.0 * rhs.e1.0) + E1i(self.e1i.0 * rhs.e123oi.0) - E1i(self.e23oi.0 * rhs.ei.0) + E1i(self.s.0 * rhs.e23i.0),
+		//     e23   : E23(self.e12oi.0 * rhs.e2.0) + E23(self.e13oi.0 * rhs.e3.0) + E23(self.e23.0 * rhs.e123oi.0) - E23(self.s.0 * rhs.e1oi.0),
+		//     e2o   : -E2o(self.e123o.0 * rhs.e2.0) + E2o(self.e13oi.0 * rhs.eo.0) + E2o(self.e2o.0 * rhs.e123oi.0) + E2o(self.s.0 * rhs.e13o.0),
+		//     e2i   : E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e13oi.0 * rhs.ei.0) + E2i(self.e2i.0 * rhs.e123oi.0) - E2i(self.s.0 * rhs.e13i.0),
+		//     e3o   : -E3o(self.e123o.0 * rhs.e3.0) - E3o(self.e12oi.0 * rhs.eo.0) + E3o(self.e3o.0 * rhs.e123oi.0) - E3o(self.s.0 * rhs.e12o.0),
+		//     e3i   : E3i(self.e123i.0 * rhs.e3.0) - E3i(self.e12oi.0 * rhs.ei.0) + E3i(self.e3i.0 * rhs.e123oi.0) + E3i(self.s.0 * rhs.e12i.0),
+		//     eoi   : Eoi(self.e123i.0 * rhs.eo.0) + Eoi(self.e123o.0 * rhs.ei.0) + Eoi(self.eoi.0 * rhs.e123oi.0) + Eoi(self.s.0 * rhs.e123.0),
+		//     e123  : E123(self.e12.0 * rhs.e12oi.0) + E123(self.e12oi.0 * rhs.e12.0) + E123(self.e13.0 * rhs.e13oi.0) + E123(self.e13oi.0 * rhs.e13.0) + E123(self.e23.0 * rhs.e23oi.0) + E123(self.e23oi.0 * rhs.e23.0) - E123(self.eoi.0 * rhs.s.0) - E123(self.s.0 * rhs.eoi.0),
+		//     e12o  : -E12o(self.e12.0 * rhs.e123o.0) - E12o(self.e123o.0 * rhs.e12.0) + E12o(self.e13oi.0 * rhs.e1o.0) + E12o(self.e1o.0 * rhs.e13oi.0) + E12o(self.e23oi.0 * rhs.e2o.0) + E12o(self.e2o.0 * rhs.e23oi.0) + E12o(self.e3o.0 * rhs.s.0) + E12o(self.s.0 * rhs.e3o.0),
+		//     e12i  : E12i(self.e12.0 * rhs.e123i.0) + E12i(self.e123i.0 * rhs.e12.0) + E12i(self.e13oi.0 * rhs.e1i.0) + E12i(self.e1i.0 * rhs.e13oi.0) + E12i(self.e23oi.0 * rhs.e2i.0) + E12i(self.e2i.0 * rhs.e23oi.0) - E12i(self.e3i.0 * rhs.s.0) - E12i(self.s.0 * rhs.e3i.0),
+		//     e13o  : -E13o(self.e123o.0 * rhs.e13.0) - E13o(self.e12oi.0 * rhs.e1o.0) - E13o(self.e13.0 * rhs.e123o.0) - E13o(self.e1o.0 * rhs.e12oi.0) + E13o(self.e23oi.0 * rhs.e3o.0) - E13o(self.e2o.0 * rhs.s.0) + E13o(self.e3o.0 * rhs.e23oi.0) - E13o(self.s.0 * rhs.e2o.0),
+		//     e13i  : E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0) - E13i(self.e1i.0 * rhs.e12oi.0) + E13i(self.e23oi.0 * rhs.e3i.0) + E13i(self.e2i.0 * rhs.s.0) + E13i(self.e3i.0 * rhs.e23oi.0) + E13i(self.s.0 * rhs.e2i.0),
+		//     e1oi  : E1oi(self.e123i.0 * rhs.e1o.0) + E1oi(self.e123o.0 * rhs.e1i.0) + E1oi(self.e1i.0 * rhs.e123o.0) + E1oi(self.e1o.0 * rhs.e123i.0) + E1oi(self.e23.0 * rhs.s.0) + E1oi(self.e23oi.0 * rhs.eoi.0) + E1oi(self.eoi.0 * rhs.e23oi.0) + E1oi(self.s.0 * rhs.e23.0),
+		//     e23o  : -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0) + E23o(self.e1o.0 * rhs.s.0) - E23o(self.e23.0 * rhs.e123o.0) - E23o(self.e2o.0 * rhs.e12oi.0) - E23o(self.e3o.0 * rhs.e13oi.0) + E23o(self.s.0 * rhs.e1o.0),
+		//     e23i  : E23i(self.e123i.0 * rhs.e23.0) - E23i(self.e12oi.0 * rhs.e2i.0) - E23i(self.e13oi.0 * rhs.e3i.0) - E23i(self.e1i.0 * rhs.s.0) + E23i(self.e23.0 * rhs.e123i.0) - E23i(self.e2i.0 * rhs.e12oi.0) - E23i(self.e3i.0 * rhs.e13oi.0) - E23i(self.s.0 * rhs.e1i.0),
+		//     e2oi  : E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0) - E2oi(self.e13.0 * rhs.s.0) - E2oi(self.e13oi.0 * rhs.eoi.0) + E2oi(self.e2i.0 * rhs.e123o.0) + E2oi(self.e2o.0 * rhs.e123i.0) - E2oi(self.eoi.0 * rhs.e13oi.0) - E2oi(self.s.0 * rhs.e13.0),
+		//     e3oi  : E3oi(self.e12.0 * rhs.s.0) + E3oi(self.e123i.0 * rhs.e3o.0) + E3oi(self.e123o.0 * rhs.e3i.0) + E3oi(self.e12oi.0 * rhs.eoi.0) + E3oi(self.e3i.0 * rhs.e123o.0) + E3oi(self.e3o.0 * rhs.e123i.0) + E3oi(self.eoi.0 * rhs.e12oi.0) + E3oi(self.s.0 * rhs.e12.0),
+		//     e123o : -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e123o.0 * rhs.e123.0) + E123o(self.e123o.0 * rhs.e123oi.0) - E123o(self.e12oi.0 * rhs.e12o.0) - E123o(self.e13.0 * rhs.e13o.0) - E123o(self.e13oi.0 * rhs.e13o.0) + E123o(self.e1o.0 * rhs.e1.0) - E123o(self.e1o.0 * rhs.e1oi.0) - E123o(self.e23.0 * rhs.e23o.0) - E123o(self.e23oi.0 * rhs.e23o.0) + E123o(self.e2o.0 * rhs.e2.0) - E123o(self.e2o.0 * rhs.e2oi.0) + E123o(self.e3o.0 * rhs.e3.0) - E123o(self.e3o.0 * rhs.e3oi.0) + E123o(self.eoi.0 * rhs.eo.0) + E123o(self.s.0 * rhs.eo.0),
+		//     e123i : E123i(self.e12.0 * rhs.e12i.0) + E123i(self.e123i.0 * rhs.e123.0) + E123i(self.e123i.0 * rhs.e123oi.0) - E123i(self.e12oi.0 * rhs.e12i.0) + E123i(self.e13.0 * rhs.e13i.0) - E123i(self.e13oi.0 * rhs.e13i.0) - E123i(self.e1i.0 * rhs.e1.0) - E123i(self.e1i.0 * rhs.e1oi.0) + E123i(self.e23.0 * rhs.e23i.0) - E123i(self.e23oi.0 * rhs.e23i.0) - E123i(self.e2i.0 * rhs.e2.0) - E123i(self.e2i.0 * rhs.e2oi.0) - E123i(self.e3i.0 * rhs.e3.0) - E123i(self.e3i.0 * rhs.e3oi.0) + E123i(self.eoi.0 * rhs.ei.0) - E123i(self.s.0 * rhs.ei.0),
+		//     e12oi : E12oi(self.e12.0 * rhs.e123.0) + E12oi(self.e123i.0 * rhs.e12o.0) + E12oi(self.e123o.0 * rhs.e12i.0) + E12oi(self.e12oi.0 * rhs.e123oi.0) - E12oi(self.e13.0 * rhs.e1.0) - E12oi(self.e13oi.0 * rhs.e1oi.0) + E12oi(self.e1i.0 * rhs.e13o.0) + E12oi(self.e1o.0 * rhs.e13i.0) - E12oi(self.e23.0 * rhs.e2.0) - E12oi(self.e23oi.0 * rhs.e2oi.0) + E12oi(self.e2i.0 * rhs.e23o.0) + E12oi(self.e2o.0 * rhs.e23i.0) - E12oi(self.e3i.0 * rhs.eo.0) - E12oi(self.e3o.0 * rhs.ei.0) - E12oi(self.eoi.0 * rhs.e3oi.0) - E12oi(self.s.0 * rhs.e3.0),
+		//     e13oi : E13oi(self.e12.0 * rhs.e1.0) + E13oi(self.e123i.0 * rhs.e13o.0) + E13oi(self.e123o.0 * rhs.e13i.0) + E13oi(self.e12oi.0 * rhs.e1oi.0) + E13oi(self.e13.0 * rhs.e123.0) + E13oi(self.e13oi.0 * rhs.e123oi.0) - E13oi(self.e1i.0 * rhs.e12o.0) - E13oi(self.e1o.0 * rhs.e12i.0) - E13oi(self.e23.0 * rhs.e3.0) - E13oi(self.e23oi.0 * rhs.e3oi.0) + E13oi(self.e2i.0 * rhs.eo.0) + E13oi(self.e2o.0 * rhs.ei.0) + E13oi(self.e3i.0 * rhs.e23o.0) + E13oi(self.e3o.0 * rhs.e23i.0) + E13oi(self.eoi.0 * rhs.e2oi.0) + E13oi(self.s.0 * rhs.e2.0),
+		//     e23oi : E23oi(self.e12.0 * rhs.e2.0) + E23oi(self.e123i.0 * rhs.e23o.0) + E23oi(self.e123o.0 * rhs.e23i.0) + E23oi(self.e12oi.0 * rhs.e2oi.0) + E23oi(self.e13.0 * rhs.e3.0) + E23oi(self.e13oi.0 * rhs.e3oi.0) - E23oi(self.e1i.0 * rhs.eo.0) - E23oi(self.e1o.0 * rhs.ei.0) + E23oi(self.e23.0 * rhs.e123.0) + E23oi(self.e23oi.0 * rhs.e123oi.0) - E23oi(self.e2i.0 * rhs.e12o.0) - E23oi(self.e2o.0 * rhs.e12i.0) - E23oi(self.e3i.0 * rhs.e13o.0) - E23oi(self.e3o.0 * rhs.e13i.0) - E23oi(self.eoi.0 * rhs.e1oi.0) - E23oi(self.s.0 * rhs.e1.0),
+		//     e123oi: E123oi(self.e12.0 * rhs.e12.0) + E123oi(self.e123i.0 * rhs.e123o.0) + E123oi(self.e123o.0 * rhs.e123i.0) + E123oi(self.e12oi.0 * rhs.e12oi.0) + E123oi(self.e13.0 * rhs.e13.0) + E123oi(self.e13oi.0 * rhs.e13oi.0) - E123oi(self.e1i.0 * rhs.e1o.0) - E123oi(self.e1o.0 * rhs.e1i.0) + E123oi(self.e23.0 * rhs.e23.0) + E123oi(self.e23oi.0 * rhs.e23oi.0) - E123oi(self.e2i.0 * rhs.e2o.0) - E123oi(self.e2o.0 * rhs.e2i.0) - E123oi(self.e3i.0 * rhs.e3o.0) - E123oi(self.e3o.0 * rhs.e3i.0) - E123oi(self.eoi.0 * rhs.eoi.0) - E123oi(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: S(self.s.0 * rhs.e123oi.0),
+			e1: E1(self.e23oi.0 * rhs.s.0) + E1(self.s.0 * rhs.e23oi.0),
+			e2: -E2(self.e13oi.0 * rhs.s.0) - E2(self.s.0 * rhs.e13oi.0),
+			e3: E3(self.e12oi.0 * rhs.s.0) + E3(self.s.0 * rhs.e12oi.0),
+			eo: -Eo(self.e123o.0 * rhs.s.0) - Eo(self.s.0 * rhs.e123o.0),
+			ei: Ei(self.e123i.0 * rhs.s.0) + Ei(self.s.0 * rhs.e123i.0),
+			e12: E12(self.e12.0 * rhs.e123oi.0)
+				- E12(self.e13oi.0 * rhs.e1.0)
+				- E12(self.e23oi.0 * rhs.e2.0)
+				- E12(self.s.0 * rhs.e3oi.0),
+			e13: E13(self.e12oi.0 * rhs.e1.0) + E13(self.e13.0 * rhs.e123oi.0) - E13(self.e23oi.0 * rhs.e3.0)
+				+ E13(self.s.0 * rhs.e2oi.0),
+			e1o: -E1o(self.e123o.0 * rhs.e1.0) + E1o(self.e1o.0 * rhs.e123oi.0)
+				- E1o(self.e23oi.0 * rhs.eo.0)
+				- E1o(self.s.0 * rhs.e23o.0),
+			e1i: E1i(self.e123i.0 * rhs.e1.0) + E1i(self.e1i.0 * rhs.e123oi.0) - E1i(self.e23oi.0 * rhs.ei.0)
+				+ E1i(self.s.0 * rhs.e23i.0),
+			e23: E23(self.e12oi.0 * rhs.e2.0) + E23(self.e13oi.0 * rhs.e3.0) + E23(self.e23.0 * rhs.e123oi.0)
+				- E23(self.s.0 * rhs.e1oi.0),
+			e2o: -E2o(self.e123o.0 * rhs.e2.0)
+				+ E2o(self.e13oi.0 * rhs.eo.0)
+				+ E2o(self.e2o.0 * rhs.e123oi.0)
+				+ E2o(self.s.0 * rhs.e13o.0),
+			e2i: E2i(self.e123i.0 * rhs.e2.0) + E2i(self.e13oi.0 * rhs.ei.0) + E2i(self.e2i.0 * rhs.e123oi.0)
+				- E2i(self.s.0 * rhs.e13i.0),
+			e3o: -E3o(self.e123o.0 * rhs.e3.0) - E3o(self.e12oi.0 * rhs.eo.0) + E3o(self.e3o.0 * rhs.e123oi.0)
+				- E3o(self.s.0 * rhs.e12o.0),
+			e3i: E3i(self.e123i.0 * rhs.e3.0) - E3i(self.e12oi.0 * rhs.ei.0)
+				+ E3i(self.e3i.0 * rhs.e123oi.0)
+				+ E3i(self.s.0 * rhs.e12i.0),
+			eoi: Eoi(self.e123i.0 * rhs.eo.0)
+				+ Eoi(self.e123o.0 * rhs.ei.0)
+				+ Eoi(self.eoi.0 * rhs.e123oi.0)
+				+ Eoi(self.s.0 * rhs.e123.0),
+			e123: E123(self.e12.0 * rhs.e12oi.0)
+				+ E123(self.e12oi.0 * rhs.e12.0)
+				+ E123(self.e13.0 * rhs.e13oi.0)
+				+ E123(self.e13oi.0 * rhs.e13.0)
+				+ E123(self.e23.0 * rhs.e23oi.0)
+				+ E123(self.e23oi.0 * rhs.e23.0)
+				- E123(self.eoi.0 * rhs.s.0)
+				- E123(self.s.0 * rhs.eoi.0),
+			e12o: -E12o(self.e12.0 * rhs.e123o.0) - E12o(self.e123o.0 * rhs.e12.0)
+				+ E12o(self.e13oi.0 * rhs.e1o.0)
+				+ E12o(self.e1o.0 * rhs.e13oi.0)
+				+ E12o(self.e23oi.0 * rhs.e2o.0)
+				+ E12o(self.e2o.0 * rhs.e23oi.0)
+				+ E12o(self.e3o.0 * rhs.s.0)
+				+ E12o(self.s.0 * rhs.e3o.0),
+			e12i: E12i(self.e12.0 * rhs.e123i.0)
+				+ E12i(self.e123i.0 * rhs.e12.0)
+				+ E12i(self.e13oi.0 * rhs.e1i.0)
+				+ E12i(self.e1i.0 * rhs.e13oi.0)
+				+ E12i(self.e23oi.0 * rhs.e2i.0)
+				+ E12i(self.e2i.0 * rhs.e23oi.0)
+				- E12i(self.e3i.0 * rhs.s.0)
+				- E12i(self.s.0 * rhs.e3i.0),
+			e13o: -E13o(self.e123o.0 * rhs.e13.0)
+				- E13o(self.e12oi.0 * rhs.e1o.0)
+				- E13o(self.e13.0 * rhs.e123o.0)
+				- E13o(self.e1o.0 * rhs.e12oi.0)
+				+ E13o(self.e23oi.0 * rhs.e3o.0)
+				- E13o(self.e2o.0 * rhs.s.0)
+				+ E13o(self.e3o.0 * rhs.e23oi.0)
+				- E13o(self.s.0 * rhs.e2o.0),
+			e13i: E13i(self.e123i.0 * rhs.e13.0) - E13i(self.e12oi.0 * rhs.e1i.0) + E13i(self.e13.0 * rhs.e123i.0)
+				- E13i(self.e1i.0 * rhs.e12oi.0)
+				+ E13i(self.e23oi.0 * rhs.e3i.0)
+				+ E13i(self.e2i.0 * rhs.s.0)
+				+ E13i(self.e3i.0 * rhs.e23oi.0)
+				+ E13i(self.s.0 * rhs.e2i.0),
+			e1oi: E1oi(self.e123i.0 * rhs.e1o.0)
+				+ E1oi(self.e123o.0 * rhs.e1i.0)
+				+ E1oi(self.e1i.0 * rhs.e123o.0)
+				+ E1oi(self.e1o.0 * rhs.e123i.0)
+				+ E1oi(self.e23.0 * rhs.s.0)
+				+ E1oi(self.e23oi.0 * rhs.eoi.0)
+				+ E1oi(self.eoi.0 * rhs.e23oi.0)
+				+ E1oi(self.s.0 * rhs.e23.0),
+			e23o: -E23o(self.e123o.0 * rhs.e23.0) - E23o(self.e12oi.0 * rhs.e2o.0) - E23o(self.e13oi.0 * rhs.e3o.0)
+				+ E23o(self.e1o.0 * rhs.s.0)
+				- E23o(self.e23.0 * rhs.e123o.0)
+				- E23o(self.e2o.0 * rhs.e12oi.0)
+				- E23o(self.e3o.0 * rhs.e13oi.0)
+				+ E23o(self.s.0 * rhs.e1o.0),
+			e23i: E23i(self.e123i.0 * rhs.e23.0)
+				- E23i(self.e12oi.0 * rhs.e2i.0)
+				- E23i(self.e13oi.0 * rhs.e3i.0)
+				- E23i(self.e1i.0 * rhs.s.0)
+				+ E23i(self.e23.0 * rhs.e123i.0)
+				- E23i(self.e2i.0 * rhs.e12oi.0)
+				- E23i(self.e3i.0 * rhs.e13oi.0)
+				- E23i(self.s.0 * rhs.e1i.0),
+			e2oi: E2oi(self.e123i.0 * rhs.e2o.0) + E2oi(self.e123o.0 * rhs.e2i.0)
+				- E2oi(self.e13.0 * rhs.s.0)
+				- E2oi(self.e13oi.0 * rhs.eoi.0)
+				+ E2oi(self.e2i.0 * rhs.e123o.0)
+				+ E2oi(self.e2o.0 * rhs.e123i.0)
+				- E2oi(self.eoi.0 * rhs.e13oi.0)
+				- E2oi(self.s.0 * rhs.e13.0),
+			e3oi: E3oi(self.e12.0 * rhs.s.0)
+				+ E3oi(self.e123i.0 * rhs.e3o.0)
+				+ E3oi(self.e123o.0 * rhs.e3i.0)
+				+ E3oi(self.e12oi.0 * rhs.eoi.0)
+				+ E3oi(self.e3i.0 * rhs.e123o.0)
+				+ E3oi(self.e3o.0 * rhs.e123i.0)
+				+ E3oi(self.eoi.0 * rhs.e12oi.0)
+				+ E3oi(self.s.0 * rhs.e12.0),
+			e123o: -E123o(self.e12.0 * rhs.e12o.0) - E123o(self.e123o.0 * rhs.e123.0)
+				+ E123o(self.e123o.0 * rhs.e123oi.0)
+				- E123o(self.e12oi.0 * rhs.e12o.0)
+				- E123o(self.e13.0 * rhs.e13o.0)
+				- E123o(self.e13oi.0 * rhs.e13o.0)
+				+ E123o(self.e1o.0 * rhs.e1.0)
+				- E123o(self.e1o.0 * rhs.e1oi.0)
+				- E123o(self.e23.0 * rhs.e23o.0)
+				- E123o(self.e23oi.0 * rhs.e23o.0)
+				+ E123o(self.e2o.0 * rhs.e2.0)
+				- E123o(self.e2o.0 * rhs.e2oi.0)
+				+ E123o(self.e3o.0 * rhs.e3.0)
+				- E123o(self.e3o.0 * rhs.e3oi.0)
+				+ E123o(self.eoi.0 * rhs.eo.0)
+				+ E123o(self.s.0 * rhs.eo.0),
+			e123i: E123i(self.e12.0 * rhs.e12i.0)
+				+ E123i(self.e123i.0 * rhs.e123.0)
+				+ E123i(self.e123i.0 * rhs.e123oi.0)
+				- E123i(self.e12oi.0 * rhs.e12i.0)
+				+ E123i(self.e13.0 * rhs.e13i.0)
+				- E123i(self.e13oi.0 * rhs.e13i.0)
+				- E123i(self.e1i.0 * rhs.e1.0)
+				- E123i(self.e1i.0 * rhs.e1oi.0)
+				+ E123i(self.e23.0 * rhs.e23i.0)
+				- E123i(self.e23oi.0 * rhs.e23i.0)
+				- E123i(self.e2i.0 * rhs.e2.0)
+				- E123i(self.e2i.0 * rhs.e2oi.0)
+				- E123i(self.e3i.0 * rhs.e3.0)
+				- E123i(self.e3i.0 * rhs.e3oi.0)
+				+ E123i(self.eoi.0 * rhs.ei.0)
+				- E123i(self.s.0 * rhs.ei.0),
+			e12oi: E12oi(self.e12.0 * rhs.e123.0)
+				+ E12oi(self.e123i.0 * rhs.e12o.0)
+				+ E12oi(self.e123o.0 * rhs.e12i.0)
+				+ E12oi(self.e12oi.0 * rhs.e123oi.0)
+				- E12oi(self.e13.0 * rhs.e1.0)
+				- E12oi(self.e13oi.0 * rhs.e1oi.0)
+				+ E12oi(self.e1i.0 * rhs.e13o.0)
+				+ E12oi(self.e1o.0 * rhs.e13i.0)
+				- E12oi(self.e23.0 * rhs.e2.0)
+				- E12oi(self.e23oi.0 * rhs.e2oi.0)
+				+ E12oi(self.e2i.0 * rhs.e23o.0)
+				+ E12oi(self.e2o.0 * rhs.e23i.0)
+				- E12oi(self.e3i.0 * rhs.eo.0)
+				- E12oi(self.e3o.0 * rhs.ei.0)
+				- E12oi(self.eoi.0 * rhs.e3oi.0)
+				- E12oi(self.s.0 * rhs.e3.0),
+			e13oi: E13oi(self.e12.0 * rhs.e1.0)
+				+ E13oi(self.e123i.0 * rhs.e13o.0)
+				+ E13oi(self.e123o.0 * rhs.e13i.0)
+				+ E13oi(self.e12oi.0 * rhs.e1oi.0)
+				+ E13oi(self.e13.0 * rhs.e123.0)
+				+ E13oi(self.e13oi.0 * rhs.e123oi.0)
+				- E13oi(self.e1i.0 * rhs.e12o.0)
+				- E13oi(self.e1o.0 * rhs.e12i.0)
+				- E13oi(self.e23.0 * rhs.e3.0)
+				- E13oi(self.e23oi.0 * rhs.e3oi.0)
+				+ E13oi(self.e2i.0 * rhs.eo.0)
+				+ E13oi(self.e2o.0 * rhs.ei.0)
+				+ E13oi(self.e3i.0 * rhs.e23o.0)
+				+ E13oi(self.e3o.0 * rhs.e23i.0)
+				+ E13oi(self.eoi.0 * rhs.e2oi.0)
+				+ E13oi(self.s.0 * rhs.e2.0),
+			e23oi: E23oi(self.e12.0 * rhs.e2.0)
+				+ E23oi(self.e123i.0 * rhs.e23o.0)
+				+ E23oi(self.e123o.0 * rhs.e23i.0)
+				+ E23oi(self.e12oi.0 * rhs.e2oi.0)
+				+ E23oi(self.e13.0 * rhs.e3.0)
+				+ E23oi(self.e13oi.0 * rhs.e3oi.0)
+				- E23oi(self.e1i.0 * rhs.eo.0)
+				- E23oi(self.e1o.0 * rhs.ei.0)
+				+ E23oi(self.e23.0 * rhs.e123.0)
+				+ E23oi(self.e23oi.0 * rhs.e123oi.0)
+				- E23oi(self.e2i.0 * rhs.e12o.0)
+				- E23oi(self.e2o.0 * rhs.e12i.0)
+				- E23oi(self.e3i.0 * rhs.e13o.0)
+				- E23oi(self.e3o.0 * rhs.e13i.0)
+				- E23oi(self.eoi.0 * rhs.e1oi.0)
+				- E23oi(self.s.0 * rhs.e1.0),
+			e123oi: E123oi(self.e12.0 * rhs.e12.0)
+				+ E123oi(self.e123i.0 * rhs.e123o.0)
+				+ E123oi(self.e123o.0 * rhs.e123i.0)
+				+ E123oi(self.e12oi.0 * rhs.e12oi.0)
+				+ E123oi(self.e13.0 * rhs.e13.0)
+				+ E123oi(self.e13oi.0 * rhs.e13oi.0)
+				- E123oi(self.e1i.0 * rhs.e1o.0)
+				- E123oi(self.e1o.0 * rhs.e1i.0)
+				+ E123oi(self.e23.0 * rhs.e23.0)
+				+ E123oi(self.e23oi.0 * rhs.e23oi.0)
+				- E123oi(self.e2i.0 * rhs.e2o.0)
+				- E123oi(self.e2o.0 * rhs.e2i.0)
+				- E123oi(self.e3i.0 * rhs.e3o.0)
+				- E123oi(self.e3o.0 * rhs.e3i.0)
+				- E123oi(self.eoi.0 * rhs.eoi.0)
+				- E123oi(self.s.0 * rhs.s.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Versor.sandwich(Vec3)  (not a Vec3)
+// Omitted: Versor.anti_sandwich(Vec3)  (not a Vec3)
+// Omitted: Versor.sandwich(Point)  (not a Point)
+// Omitted: Versor.anti_sandwich(Point)  (not a Point)
+// Omitted: Versor.sandwich(FlatPoint)  (not a FlatPoint)
+// Omitted: Versor.anti_sandwich(FlatPoint)  (not a FlatPoint)
+
+// Versor.sandwich(PointPair) -> PointPair
+impl Sandwich<PointPair> for Versor {
+	fn sandwich(self, rhs: PointPair) -> PointPair {
+		PointPair {
+			e12: E12(self.e12.0 * self.e12.0 * rhs.e12.0) + E12(self.e12.0 * self.e13.0 * rhs.e13.0) * 2.0
+				- E12(self.e12.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				- E12(self.e12.0 * self.e1o.0 * rhs.e1i.0) * 2.0
+				+ E12(self.e12.0 * self.e23.0 * rhs.e23.0) * 2.0
+				- E12(self.e12.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E12(self.e12.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				- E12(self.e12.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E12(self.e12.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				- E12(self.e12.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				- E12(self.e123i.0 * self.e123o.0 * rhs.e12.0) * 2.0
+				- E12(self.e123i.0 * self.e13.0 * rhs.e1o.0) * 2.0
+				+ E12(self.e123i.0 * self.e13oi.0 * rhs.e1o.0) * 2.0
+				+ E12(self.e123i.0 * self.e1o.0 * rhs.e13.0) * 2.0
+				- E12(self.e123i.0 * self.e23.0 * rhs.e2o.0) * 2.0
+				+ E12(self.e123i.0 * self.e23oi.0 * rhs.e2o.0) * 2.0
+				+ E12(self.e123i.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				+ E12(self.e123i.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				- E12(self.e123i.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E12(self.e123i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E12(self.e123o.0 * self.e13.0 * rhs.e1i.0) * 2.0
+				- E12(self.e123o.0 * self.e13oi.0 * rhs.e1i.0) * 2.0
+				+ E12(self.e123o.0 * self.e1i.0 * rhs.e13.0) * 2.0
+				- E12(self.e123o.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				- E12(self.e123o.0 * self.e23oi.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e123o.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				- E12(self.e123o.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				+ E12(self.e123o.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E12(self.e123o.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E12(self.e12oi.0 * self.e12oi.0 * rhs.e12.0)
+				- E12(self.e12oi.0 * self.e13oi.0 * rhs.e13.0) * 2.0
+				+ E12(self.e12oi.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				- E12(self.e12oi.0 * self.e1o.0 * rhs.e1i.0) * 2.0
+				- E12(self.e12oi.0 * self.e23oi.0 * rhs.e23.0) * 2.0
+				+ E12(self.e12oi.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E12(self.e12oi.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e12oi.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E12(self.e12oi.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				+ E12(self.e12oi.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E12(self.e13.0 * self.e13.0 * rhs.e12.0)
+				- E12(self.e13.0 * self.e23oi.0 * rhs.eoi.0) * 2.0
+				- E12(self.e13.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				- E12(self.e13.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				+ E12(self.e13.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				+ E12(self.e13.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				- E12(self.e13.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E12(self.e13oi.0 * self.e13oi.0 * rhs.e12.0)
+				+ E12(self.e13oi.0 * self.e23.0 * rhs.eoi.0) * 2.0
+				+ E12(self.e13oi.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				- E12(self.e13oi.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				- E12(self.e13oi.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				+ E12(self.e13oi.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e13oi.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				+ E12(self.e1i.0 * self.e1o.0 * rhs.e12.0) * 2.0
+				+ E12(self.e1i.0 * self.e23.0 * rhs.e3o.0) * 2.0
+				- E12(self.e1i.0 * self.e23oi.0 * rhs.e3o.0) * 2.0
+				+ E12(self.e1i.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				- E12(self.e1i.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				- E12(self.e1i.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				+ E12(self.e1i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E12(self.e1o.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				+ E12(self.e1o.0 * self.e23oi.0 * rhs.e3i.0) * 2.0
+				- E12(self.e1o.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				- E12(self.e1o.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				+ E12(self.e1o.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e1o.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E12(self.e23.0 * self.e23.0 * rhs.e12.0)
+				- E12(self.e23.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				- E12(self.e23.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				+ E12(self.e23.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E12(self.e23oi.0 * self.e23oi.0 * rhs.e12.0)
+				+ E12(self.e23oi.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				- E12(self.e23oi.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				- E12(self.e23oi.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				+ E12(self.e2i.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				+ E12(self.e2i.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				+ E12(self.e2i.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E12(self.e2i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E12(self.e2o.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				- E12(self.e2o.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E12(self.e2o.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E12(self.e3i.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				- E12(self.eoi.0 * self.eoi.0 * rhs.e12.0)
+				+ E12(self.s.0 * self.s.0 * rhs.e12.0),
+			e13: -E13(self.e12.0 * self.e12.0 * rhs.e13.0)
+				+ E13(self.e12.0 * self.e123i.0 * rhs.e1o.0) * 2.0
+				+ E13(self.e12.0 * self.e123o.0 * rhs.e1i.0) * 2.0
+				+ E13(self.e12.0 * self.e13.0 * rhs.e12.0) * 2.0
+				+ E13(self.e12.0 * self.e23oi.0 * rhs.eoi.0) * 2.0
+				+ E13(self.e12.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				+ E13(self.e12.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				- E13(self.e12.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				- E13(self.e12.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				+ E13(self.e12.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E13(self.e123i.0 * self.e123o.0 * rhs.e13.0) * 2.0
+				- E13(self.e123i.0 * self.e12oi.0 * rhs.e1o.0) * 2.0
+				- E13(self.e123i.0 * self.e1o.0 * rhs.e12.0) * 2.0
+				- E13(self.e123i.0 * self.e23.0 * rhs.e3o.0) * 2.0
+				+ E13(self.e123i.0 * self.e23oi.0 * rhs.e3o.0) * 2.0
+				- E13(self.e123i.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				+ E13(self.e123i.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				+ E13(self.e123i.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				- E13(self.e123i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E13(self.e123o.0 * self.e12oi.0 * rhs.e1i.0) * 2.0
+				- E13(self.e123o.0 * self.e1i.0 * rhs.e12.0) * 2.0
+				- E13(self.e123o.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				- E13(self.e123o.0 * self.e23oi.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e123o.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				+ E13(self.e123o.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				- E13(self.e123o.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				- E13(self.e123o.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E13(self.e12oi.0 * self.e12oi.0 * rhs.e13.0)
+				- E13(self.e12oi.0 * self.e13oi.0 * rhs.e12.0) * 2.0
+				- E13(self.e12oi.0 * self.e23.0 * rhs.eoi.0) * 2.0
+				- E13(self.e12oi.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				+ E13(self.e12oi.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e12oi.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				- E13(self.e12oi.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				- E13(self.e12oi.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				+ E13(self.e13.0 * self.e13.0 * rhs.e13.0)
+				- E13(self.e13.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				- E13(self.e13.0 * self.e1o.0 * rhs.e1i.0) * 2.0
+				+ E13(self.e13.0 * self.e23.0 * rhs.e23.0) * 2.0
+				- E13(self.e13.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E13(self.e13.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				- E13(self.e13.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E13(self.e13.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				- E13(self.e13.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				- E13(self.e13oi.0 * self.e13oi.0 * rhs.e13.0)
+				+ E13(self.e13oi.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				- E13(self.e13oi.0 * self.e1o.0 * rhs.e1i.0) * 2.0
+				- E13(self.e13oi.0 * self.e23oi.0 * rhs.e23.0) * 2.0
+				+ E13(self.e13oi.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E13(self.e13oi.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				+ E13(self.e13oi.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E13(self.e13oi.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e13oi.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E13(self.e1i.0 * self.e1o.0 * rhs.e13.0) * 2.0
+				- E13(self.e1i.0 * self.e23.0 * rhs.e2o.0) * 2.0
+				+ E13(self.e1i.0 * self.e23oi.0 * rhs.e2o.0) * 2.0
+				+ E13(self.e1i.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				+ E13(self.e1i.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				- E13(self.e1i.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E13(self.e1i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E13(self.e1o.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				- E13(self.e1o.0 * self.e23oi.0 * rhs.e2i.0) * 2.0
+				+ E13(self.e1o.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				- E13(self.e1o.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				+ E13(self.e1o.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e1o.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E13(self.e23.0 * self.e23.0 * rhs.e13.0)
+				+ E13(self.e23.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				+ E13(self.e23.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				- E13(self.e23.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E13(self.e23oi.0 * self.e23oi.0 * rhs.e13.0)
+				- E13(self.e23oi.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				+ E13(self.e23oi.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				+ E13(self.e23oi.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				- E13(self.e2i.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				+ E13(self.e2i.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				+ E13(self.e2o.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				+ E13(self.e3i.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				+ E13(self.e3i.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E13(self.e3i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E13(self.e3o.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E13(self.e3o.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E13(self.eoi.0 * self.eoi.0 * rhs.e13.0)
+				+ E13(self.s.0 * self.s.0 * rhs.e13.0),
+			e1o: -E1o(self.e12.0 * self.e12.0 * rhs.e1o.0)
+				+ E1o(self.e12.0 * self.e123o.0 * rhs.e13.0) * 2.0
+				+ E1o(self.e12.0 * self.e12oi.0 * rhs.e1o.0) * 2.0
+				+ E1o(self.e12.0 * self.e1o.0 * rhs.e12.0) * 2.0
+				+ E1o(self.e12.0 * self.e23.0 * rhs.e3o.0) * 2.0
+				- E1o(self.e12.0 * self.e23oi.0 * rhs.e3o.0) * 2.0
+				+ E1o(self.e12.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				- E1o(self.e12.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				- E1o(self.e12.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e12.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E1o(self.e123o.0 * self.e123o.0 * rhs.e1i.0) * 2.0
+				- E1o(self.e123o.0 * self.e12oi.0 * rhs.e13.0) * 2.0
+				- E1o(self.e123o.0 * self.e13.0 * rhs.e12.0) * 2.0
+				+ E1o(self.e123o.0 * self.e13oi.0 * rhs.e12.0) * 2.0
+				+ E1o(self.e123o.0 * self.e23.0 * rhs.eoi.0) * 2.0
+				- E1o(self.e123o.0 * self.e23oi.0 * rhs.eoi.0) * 2.0
+				- E1o(self.e123o.0 * self.e2o.0 * rhs.e3i.0) * 4.0
+				+ E1o(self.e123o.0 * self.e3o.0 * rhs.e2i.0) * 4.0
+				+ E1o(self.e123o.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				- E1o(self.e123o.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E1o(self.e12oi.0 * self.e12oi.0 * rhs.e1o.0)
+				- E1o(self.e12oi.0 * self.e1o.0 * rhs.e12.0) * 2.0
+				- E1o(self.e12oi.0 * self.e23.0 * rhs.e3o.0) * 2.0
+				+ E1o(self.e12oi.0 * self.e23oi.0 * rhs.e3o.0) * 2.0
+				- E1o(self.e12oi.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				+ E1o(self.e12oi.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				+ E1o(self.e12oi.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				- E1o(self.e12oi.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E1o(self.e13.0 * self.e13.0 * rhs.e1o.0)
+				+ E1o(self.e13.0 * self.e13oi.0 * rhs.e1o.0) * 2.0
+				+ E1o(self.e13.0 * self.e1o.0 * rhs.e13.0) * 2.0
+				- E1o(self.e13.0 * self.e23.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e13.0 * self.e23oi.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e13.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				+ E1o(self.e13.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				- E1o(self.e13.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E1o(self.e13.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E1o(self.e13oi.0 * self.e13oi.0 * rhs.e1o.0)
+				- E1o(self.e13oi.0 * self.e1o.0 * rhs.e13.0) * 2.0
+				+ E1o(self.e13oi.0 * self.e23.0 * rhs.e2o.0) * 2.0
+				- E1o(self.e13oi.0 * self.e23oi.0 * rhs.e2o.0) * 2.0
+				- E1o(self.e13oi.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				- E1o(self.e13oi.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				+ E1o(self.e13oi.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				- E1o(self.e13oi.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E1o(self.e1o.0 * self.e1o.0 * rhs.e1i.0) * 2.0
+				+ E1o(self.e1o.0 * self.e23.0 * rhs.e23.0) * 2.0
+				- E1o(self.e1o.0 * self.e23oi.0 * rhs.e23.0) * 2.0
+				- E1o(self.e1o.0 * self.e2o.0 * rhs.e2i.0) * 4.0
+				- E1o(self.e1o.0 * self.e3o.0 * rhs.e3i.0) * 4.0
+				- E1o(self.e1o.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				+ E1o(self.e1o.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E1o(self.e23.0 * self.e23.0 * rhs.e1o.0)
+				- E1o(self.e23.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				- E1o(self.e23.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				+ E1o(self.e23.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				+ E1o(self.e23oi.0 * self.e23oi.0 * rhs.e1o.0)
+				+ E1o(self.e23oi.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				- E1o(self.e23oi.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				+ E1o(self.e2o.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				+ E1o(self.e2o.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				- E1o(self.e2o.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E1o(self.e3o.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				+ E1o(self.e3o.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				- E1o(self.e3o.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E1o(self.eoi.0 * self.eoi.0 * rhs.e1o.0)
+				- E1o(self.eoi.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E1o(self.s.0 * self.s.0 * rhs.e1o.0),
+			e1i: -E1i(self.e12.0 * self.e12.0 * rhs.e1i.0) + E1i(self.e12.0 * self.e123i.0 * rhs.e13.0) * 2.0
+				- E1i(self.e12.0 * self.e12oi.0 * rhs.e1i.0) * 2.0
+				+ E1i(self.e12.0 * self.e1i.0 * rhs.e12.0) * 2.0
+				+ E1i(self.e12.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e12.0 * self.e23oi.0 * rhs.e3i.0) * 2.0
+				- E1i(self.e12.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e12.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				+ E1i(self.e12.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e12.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E1i(self.e123i.0 * self.e123i.0 * rhs.e1o.0) * 2.0
+				+ E1i(self.e123i.0 * self.e12oi.0 * rhs.e13.0) * 2.0
+				- E1i(self.e123i.0 * self.e13.0 * rhs.e12.0) * 2.0
+				- E1i(self.e123i.0 * self.e13oi.0 * rhs.e12.0) * 2.0
+				- E1i(self.e123i.0 * self.e23.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e123i.0 * self.e23oi.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e123i.0 * self.e2i.0 * rhs.e3o.0) * 4.0
+				+ E1i(self.e123i.0 * self.e3i.0 * rhs.e2o.0) * 4.0
+				- E1i(self.e123i.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				- E1i(self.e123i.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E1i(self.e12oi.0 * self.e12oi.0 * rhs.e1i.0)
+				+ E1i(self.e12oi.0 * self.e1i.0 * rhs.e12.0) * 2.0
+				+ E1i(self.e12oi.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e12oi.0 * self.e23oi.0 * rhs.e3i.0) * 2.0
+				- E1i(self.e12oi.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e12oi.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				+ E1i(self.e12oi.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e12oi.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E1i(self.e13.0 * self.e13.0 * rhs.e1i.0)
+				- E1i(self.e13.0 * self.e13oi.0 * rhs.e1i.0) * 2.0
+				+ E1i(self.e13.0 * self.e1i.0 * rhs.e13.0) * 2.0
+				- E1i(self.e13.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				- E1i(self.e13.0 * self.e23oi.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				- E1i(self.e13.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				+ E1i(self.e13.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e13.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E1i(self.e13oi.0 * self.e13oi.0 * rhs.e1i.0)
+				+ E1i(self.e13oi.0 * self.e1i.0 * rhs.e13.0) * 2.0
+				- E1i(self.e13oi.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				- E1i(self.e13oi.0 * self.e23oi.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13oi.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				- E1i(self.e13oi.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				+ E1i(self.e13oi.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e13oi.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E1i(self.e1i.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				+ E1i(self.e1i.0 * self.e23.0 * rhs.e23.0) * 2.0
+				+ E1i(self.e1i.0 * self.e23oi.0 * rhs.e23.0) * 2.0
+				- E1i(self.e1i.0 * self.e2i.0 * rhs.e2o.0) * 4.0
+				- E1i(self.e1i.0 * self.e3i.0 * rhs.e3o.0) * 4.0
+				- E1i(self.e1i.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e1i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E1i(self.e23.0 * self.e23.0 * rhs.e1i.0)
+				+ E1i(self.e23.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				- E1i(self.e23.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				+ E1i(self.e23.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				+ E1i(self.e23oi.0 * self.e23oi.0 * rhs.e1i.0)
+				- E1i(self.e23oi.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				+ E1i(self.e23oi.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				+ E1i(self.e2i.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				- E1i(self.e2i.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				- E1i(self.e2i.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E1i(self.e3i.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				- E1i(self.e3i.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				- E1i(self.e3i.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E1i(self.eoi.0 * self.eoi.0 * rhs.e1i.0)
+				+ E1i(self.eoi.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E1i(self.s.0 * self.s.0 * rhs.e1i.0),
+			e23: -E23(self.e12.0 * self.e12.0 * rhs.e23.0)
+				+ E23(self.e12.0 * self.e123i.0 * rhs.e2o.0) * 2.0
+				+ E23(self.e12.0 * self.e123o.0 * rhs.e2i.0) * 2.0
+				- E23(self.e12.0 * self.e13oi.0 * rhs.eoi.0) * 2.0
+				- E23(self.e12.0 * self.e1i.0 * rhs.e3o.0) * 2.0
+				- E23(self.e12.0 * self.e1o.0 * rhs.e3i.0) * 2.0
+				+ E23(self.e12.0 * self.e23.0 * rhs.e12.0) * 2.0
+				+ E23(self.e12.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				+ E23(self.e12.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				- E23(self.e12.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E23(self.e123i.0 * self.e123o.0 * rhs.e23.0) * 2.0
+				- E23(self.e123i.0 * self.e12oi.0 * rhs.e2o.0) * 2.0
+				+ E23(self.e123i.0 * self.e13.0 * rhs.e3o.0) * 2.0
+				- E23(self.e123i.0 * self.e13oi.0 * rhs.e3o.0) * 2.0
+				+ E23(self.e123i.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				- E23(self.e123i.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				- E23(self.e123i.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- E23(self.e123i.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				+ E23(self.e123i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E23(self.e123o.0 * self.e12oi.0 * rhs.e2i.0) * 2.0
+				+ E23(self.e123o.0 * self.e13.0 * rhs.e3i.0) * 2.0
+				+ E23(self.e123o.0 * self.e13oi.0 * rhs.e3i.0) * 2.0
+				- E23(self.e123o.0 * self.e1i.0 * rhs.eoi.0) * 2.0
+				- E23(self.e123o.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				- E23(self.e123o.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				+ E23(self.e123o.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				+ E23(self.e123o.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E23(self.e12oi.0 * self.e12oi.0 * rhs.e23.0)
+				+ E23(self.e12oi.0 * self.e13.0 * rhs.eoi.0) * 2.0
+				+ E23(self.e12oi.0 * self.e1i.0 * rhs.e3o.0) * 2.0
+				- E23(self.e12oi.0 * self.e1o.0 * rhs.e3i.0) * 2.0
+				- E23(self.e12oi.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				- E23(self.e12oi.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				+ E23(self.e12oi.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				+ E23(self.e12oi.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				- E23(self.e13.0 * self.e13.0 * rhs.e23.0)
+				+ E23(self.e13.0 * self.e1i.0 * rhs.e2o.0) * 2.0
+				+ E23(self.e13.0 * self.e1o.0 * rhs.e2i.0) * 2.0
+				+ E23(self.e13.0 * self.e23.0 * rhs.e13.0) * 2.0
+				- E23(self.e13.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				- E23(self.e13.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				+ E23(self.e13.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E23(self.e13oi.0 * self.e13oi.0 * rhs.e23.0)
+				- E23(self.e13oi.0 * self.e1i.0 * rhs.e2o.0) * 2.0
+				+ E23(self.e13oi.0 * self.e1o.0 * rhs.e2i.0) * 2.0
+				- E23(self.e13oi.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				+ E23(self.e13oi.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				- E23(self.e13oi.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				- E23(self.e13oi.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				- E23(self.e1i.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				- E23(self.e1i.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				+ E23(self.e1i.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				+ E23(self.e1i.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				- E23(self.e1i.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				- E23(self.e1o.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- E23(self.e1o.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				+ E23(self.e1o.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				- E23(self.e1o.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				+ E23(self.e23.0 * self.e23.0 * rhs.e23.0)
+				- E23(self.e23.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E23(self.e23.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				- E23(self.e23.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E23(self.e23.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				- E23(self.e23.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				- E23(self.e23oi.0 * self.e23oi.0 * rhs.e23.0)
+				+ E23(self.e23oi.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E23(self.e23oi.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				+ E23(self.e23oi.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E23(self.e23oi.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				+ E23(self.e23oi.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E23(self.e2i.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				+ E23(self.e2i.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				- E23(self.e2i.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E23(self.e2i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E23(self.e2o.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				+ E23(self.e2o.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E23(self.e2o.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E23(self.e3i.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				+ E23(self.e3i.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				- E23(self.e3i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E23(self.e3o.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				- E23(self.e3o.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E23(self.eoi.0 * self.eoi.0 * rhs.e23.0)
+				+ E23(self.s.0 * self.s.0 * rhs.e23.0),
+			e2o: -E2o(self.e12.0 * self.e12.0 * rhs.e2o.0)
+				+ E2o(self.e12.0 * self.e123o.0 * rhs.e23.0) * 2.0
+				+ E2o(self.e12.0 * self.e12oi.0 * rhs.e2o.0) * 2.0
+				- E2o(self.e12.0 * self.e13.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e12.0 * self.e13oi.0 * rhs.e3o.0) * 2.0
+				- E2o(self.e12.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				+ E2o(self.e12.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				+ E2o(self.e12.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				+ E2o(self.e12.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e12.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e123o.0 * self.e123o.0 * rhs.e2i.0) * 2.0
+				- E2o(self.e123o.0 * self.e12oi.0 * rhs.e23.0) * 2.0
+				- E2o(self.e123o.0 * self.e13.0 * rhs.eoi.0) * 2.0
+				+ E2o(self.e123o.0 * self.e13oi.0 * rhs.eoi.0) * 2.0
+				+ E2o(self.e123o.0 * self.e1o.0 * rhs.e3i.0) * 4.0
+				- E2o(self.e123o.0 * self.e23.0 * rhs.e12.0) * 2.0
+				+ E2o(self.e123o.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				- E2o(self.e123o.0 * self.e3o.0 * rhs.e1i.0) * 4.0
+				- E2o(self.e123o.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				+ E2o(self.e123o.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E2o(self.e12oi.0 * self.e12oi.0 * rhs.e2o.0)
+				+ E2o(self.e12oi.0 * self.e13.0 * rhs.e3o.0) * 2.0
+				- E2o(self.e12oi.0 * self.e13oi.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e12oi.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				- E2o(self.e12oi.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				- E2o(self.e12oi.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- E2o(self.e12oi.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				+ E2o(self.e12oi.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E2o(self.e13.0 * self.e13.0 * rhs.e2o.0)
+				- E2o(self.e13.0 * self.e13oi.0 * rhs.e2o.0) * 2.0
+				- E2o(self.e13.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				- E2o(self.e13.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				+ E2o(self.e13.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				+ E2o(self.e13.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				- E2o(self.e13.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				+ E2o(self.e13oi.0 * self.e13oi.0 * rhs.e2o.0)
+				+ E2o(self.e13oi.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				+ E2o(self.e13oi.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e13oi.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e13oi.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				+ E2o(self.e13oi.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				+ E2o(self.e1o.0 * self.e1o.0 * rhs.e2i.0) * 2.0
+				+ E2o(self.e1o.0 * self.e23.0 * rhs.e13.0) * 2.0
+				- E2o(self.e1o.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				- E2o(self.e1o.0 * self.e2o.0 * rhs.e1i.0) * 4.0
+				- E2o(self.e1o.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				+ E2o(self.e1o.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E2o(self.e23.0 * self.e23.0 * rhs.e2o.0)
+				+ E2o(self.e23.0 * self.e23oi.0 * rhs.e2o.0) * 2.0
+				+ E2o(self.e23.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				+ E2o(self.e23.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				- E2o(self.e23.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e23.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E2o(self.e23oi.0 * self.e23oi.0 * rhs.e2o.0)
+				- E2o(self.e23oi.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				- E2o(self.e23oi.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				+ E2o(self.e23oi.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				- E2o(self.e23oi.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E2o(self.e2o.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				- E2o(self.e2o.0 * self.e3o.0 * rhs.e3i.0) * 4.0
+				- E2o(self.e2o.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				+ E2o(self.e2o.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E2o(self.e3o.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				+ E2o(self.e3o.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				- E2o(self.e3o.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E2o(self.eoi.0 * self.eoi.0 * rhs.e2o.0)
+				- E2o(self.eoi.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E2o(self.s.0 * self.s.0 * rhs.e2o.0),
+			e2i: -E2i(self.e12.0 * self.e12.0 * rhs.e2i.0) + E2i(self.e12.0 * self.e123i.0 * rhs.e23.0) * 2.0
+				- E2i(self.e12.0 * self.e12oi.0 * rhs.e2i.0) * 2.0
+				- E2i(self.e12.0 * self.e13.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e12.0 * self.e13oi.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.e12.0 * self.e1i.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e12.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				+ E2i(self.e12.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				- E2i(self.e12.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e12.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e123i.0 * self.e123i.0 * rhs.e2o.0) * 2.0
+				+ E2i(self.e123i.0 * self.e12oi.0 * rhs.e23.0) * 2.0
+				+ E2i(self.e123i.0 * self.e13.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e123i.0 * self.e13oi.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e123i.0 * self.e1i.0 * rhs.e3o.0) * 4.0
+				- E2i(self.e123i.0 * self.e23.0 * rhs.e12.0) * 2.0
+				- E2i(self.e123i.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				- E2i(self.e123i.0 * self.e3i.0 * rhs.e1o.0) * 4.0
+				+ E2i(self.e123i.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				+ E2i(self.e123i.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E2i(self.e12oi.0 * self.e12oi.0 * rhs.e2i.0)
+				- E2i(self.e12oi.0 * self.e13.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e12oi.0 * self.e13oi.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.e12oi.0 * self.e1i.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e12oi.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				+ E2i(self.e12oi.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				- E2i(self.e12oi.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e12oi.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e13.0 * self.e13.0 * rhs.e2i.0)
+				+ E2i(self.e13.0 * self.e13oi.0 * rhs.e2i.0) * 2.0
+				- E2i(self.e13.0 * self.e1i.0 * rhs.e23.0) * 2.0
+				- E2i(self.e13.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e13.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e13.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				- E2i(self.e13.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				+ E2i(self.e13oi.0 * self.e13oi.0 * rhs.e2i.0)
+				- E2i(self.e13oi.0 * self.e1i.0 * rhs.e23.0) * 2.0
+				- E2i(self.e13oi.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e13oi.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e13oi.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				- E2i(self.e13oi.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				+ E2i(self.e1i.0 * self.e1i.0 * rhs.e2o.0) * 2.0
+				+ E2i(self.e1i.0 * self.e23.0 * rhs.e13.0) * 2.0
+				+ E2i(self.e1i.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				- E2i(self.e1i.0 * self.e2i.0 * rhs.e1o.0) * 4.0
+				+ E2i(self.e1i.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				+ E2i(self.e1i.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E2i(self.e23.0 * self.e23.0 * rhs.e2i.0)
+				- E2i(self.e23.0 * self.e23oi.0 * rhs.e2i.0) * 2.0
+				+ E2i(self.e23.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				- E2i(self.e23.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e23.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.e23.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e23oi.0 * self.e23oi.0 * rhs.e2i.0)
+				+ E2i(self.e23oi.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				- E2i(self.e23oi.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e23oi.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.e23oi.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e2i.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E2i(self.e2i.0 * self.e3i.0 * rhs.e3o.0) * 4.0
+				- E2i(self.e2i.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				- E2i(self.e2i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e3i.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				- E2i(self.e3i.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				- E2i(self.e3i.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E2i(self.eoi.0 * self.eoi.0 * rhs.e2i.0)
+				+ E2i(self.eoi.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E2i(self.s.0 * self.s.0 * rhs.e2i.0),
+			e3o: E3o(self.e12.0 * self.e12.0 * rhs.e3o.0) + E3o(self.e12.0 * self.e123o.0 * rhs.eoi.0) * 2.0
+				- E3o(self.e12.0 * self.e12oi.0 * rhs.e3o.0) * 2.0
+				- E3o(self.e12.0 * self.e13.0 * rhs.e2o.0) * 2.0
+				+ E3o(self.e12.0 * self.e13oi.0 * rhs.e2o.0) * 2.0
+				+ E3o(self.e12.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				+ E3o(self.e12.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e12.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e12.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				+ E3o(self.e12.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				- E3o(self.e123o.0 * self.e123o.0 * rhs.e3i.0) * 2.0
+				- E3o(self.e123o.0 * self.e12oi.0 * rhs.eoi.0) * 2.0
+				+ E3o(self.e123o.0 * self.e13.0 * rhs.e23.0) * 2.0
+				- E3o(self.e123o.0 * self.e13oi.0 * rhs.e23.0) * 2.0
+				- E3o(self.e123o.0 * self.e1o.0 * rhs.e2i.0) * 4.0
+				- E3o(self.e123o.0 * self.e23.0 * rhs.e13.0) * 2.0
+				+ E3o(self.e123o.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				+ E3o(self.e123o.0 * self.e2o.0 * rhs.e1i.0) * 4.0
+				+ E3o(self.e123o.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				- E3o(self.e123o.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E3o(self.e12oi.0 * self.e12oi.0 * rhs.e3o.0)
+				+ E3o(self.e12oi.0 * self.e13.0 * rhs.e2o.0) * 2.0
+				- E3o(self.e12oi.0 * self.e13oi.0 * rhs.e2o.0) * 2.0
+				- E3o(self.e12oi.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				- E3o(self.e12oi.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				+ E3o(self.e12oi.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				+ E3o(self.e12oi.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				- E3o(self.e12oi.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				- E3o(self.e13.0 * self.e13.0 * rhs.e3o.0)
+				+ E3o(self.e13.0 * self.e13oi.0 * rhs.e3o.0) * 2.0
+				- E3o(self.e13.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				+ E3o(self.e13.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				+ E3o(self.e13.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				+ E3o(self.e13.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e13.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e13oi.0 * self.e13oi.0 * rhs.e3o.0)
+				+ E3o(self.e13oi.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				- E3o(self.e13oi.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				- E3o(self.e13oi.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- E3o(self.e13oi.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				+ E3o(self.e13oi.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E3o(self.e1o.0 * self.e1o.0 * rhs.e3i.0) * 2.0
+				- E3o(self.e1o.0 * self.e23.0 * rhs.e12.0) * 2.0
+				+ E3o(self.e1o.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				- E3o(self.e1o.0 * self.e3o.0 * rhs.e1i.0) * 4.0
+				- E3o(self.e1o.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				+ E3o(self.e1o.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E3o(self.e23.0 * self.e23.0 * rhs.e3o.0)
+				+ E3o(self.e23.0 * self.e23oi.0 * rhs.e3o.0) * 2.0
+				- E3o(self.e23.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				+ E3o(self.e23.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				+ E3o(self.e23.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				- E3o(self.e23.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E3o(self.e23oi.0 * self.e23oi.0 * rhs.e3o.0)
+				+ E3o(self.e23oi.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				- E3o(self.e23oi.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				- E3o(self.e23oi.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				+ E3o(self.e23oi.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E3o(self.e2o.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				- E3o(self.e2o.0 * self.e3o.0 * rhs.e2i.0) * 4.0
+				- E3o(self.e2o.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				+ E3o(self.e2o.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E3o(self.e3o.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				- E3o(self.e3o.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				+ E3o(self.e3o.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E3o(self.eoi.0 * self.eoi.0 * rhs.e3o.0)
+				- E3o(self.eoi.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ E3o(self.s.0 * self.s.0 * rhs.e3o.0),
+			e3i: E3i(self.e12.0 * self.e12.0 * rhs.e3i.0) - E3i(self.e12.0 * self.e123i.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.e12.0 * self.e12oi.0 * rhs.e3i.0) * 2.0
+				- E3i(self.e12.0 * self.e13.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e12.0 * self.e13oi.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.e12.0 * self.e1i.0 * rhs.e23.0) * 2.0
+				+ E3i(self.e12.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e12.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e12.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e12.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				- E3i(self.e123i.0 * self.e123i.0 * rhs.e3o.0) * 2.0
+				- E3i(self.e123i.0 * self.e12oi.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.e123i.0 * self.e13.0 * rhs.e23.0) * 2.0
+				+ E3i(self.e123i.0 * self.e13oi.0 * rhs.e23.0) * 2.0
+				- E3i(self.e123i.0 * self.e1i.0 * rhs.e2o.0) * 4.0
+				- E3i(self.e123i.0 * self.e23.0 * rhs.e13.0) * 2.0
+				- E3i(self.e123i.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e123i.0 * self.e2i.0 * rhs.e1o.0) * 4.0
+				- E3i(self.e123i.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				- E3i(self.e123i.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E3i(self.e12oi.0 * self.e12oi.0 * rhs.e3i.0)
+				- E3i(self.e12oi.0 * self.e13.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e12oi.0 * self.e13oi.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.e12oi.0 * self.e1i.0 * rhs.e23.0) * 2.0
+				+ E3i(self.e12oi.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e12oi.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e12oi.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e12oi.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				- E3i(self.e13.0 * self.e13.0 * rhs.e3i.0)
+				- E3i(self.e13.0 * self.e13oi.0 * rhs.e3i.0) * 2.0
+				+ E3i(self.e13.0 * self.e1i.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.e13.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				+ E3i(self.e13.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				- E3i(self.e13.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e13.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e13oi.0 * self.e13oi.0 * rhs.e3i.0)
+				+ E3i(self.e13oi.0 * self.e1i.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.e13oi.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				+ E3i(self.e13oi.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				- E3i(self.e13oi.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e13oi.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e1i.0 * self.e1i.0 * rhs.e3o.0) * 2.0
+				- E3i(self.e1i.0 * self.e23.0 * rhs.e12.0) * 2.0
+				- E3i(self.e1i.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				- E3i(self.e1i.0 * self.e3i.0 * rhs.e1o.0) * 4.0
+				+ E3i(self.e1i.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e1i.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E3i(self.e23.0 * self.e23.0 * rhs.e3i.0)
+				- E3i(self.e23.0 * self.e23oi.0 * rhs.e3i.0) * 2.0
+				+ E3i(self.e23.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.e23.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				- E3i(self.e23.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e23.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e23oi.0 * self.e23oi.0 * rhs.e3i.0)
+				+ E3i(self.e23oi.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.e23oi.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				- E3i(self.e23oi.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e23oi.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.e2i.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				- E3i(self.e2i.0 * self.e3i.0 * rhs.e2o.0) * 4.0
+				+ E3i(self.e2i.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				+ E3i(self.e2i.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E3i(self.e3i.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E3i(self.e3i.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				- E3i(self.e3i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.eoi.0 * self.eoi.0 * rhs.e3i.0)
+				+ E3i(self.eoi.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E3i(self.s.0 * self.s.0 * rhs.e3i.0),
+			eoi: Eoi(self.e12.0 * self.e12.0 * rhs.eoi.0) + Eoi(self.e12.0 * self.e123i.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e12.0 * self.e123o.0 * rhs.e3i.0) * 2.0
+				- Eoi(self.e12.0 * self.e13oi.0 * rhs.e23.0) * 2.0
+				+ Eoi(self.e12.0 * self.e1i.0 * rhs.e2o.0) * 2.0
+				- Eoi(self.e12.0 * self.e1o.0 * rhs.e2i.0) * 2.0
+				+ Eoi(self.e12.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				- Eoi(self.e12.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e12.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				+ Eoi(self.e12.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				+ Eoi(self.e123i.0 * self.e123o.0 * rhs.eoi.0) * 2.0
+				- Eoi(self.e123i.0 * self.e12oi.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e123i.0 * self.e13.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e123i.0 * self.e13oi.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e123i.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				+ Eoi(self.e123i.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				- Eoi(self.e123i.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				- Eoi(self.e123i.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				+ Eoi(self.e123i.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				- Eoi(self.e123o.0 * self.e12oi.0 * rhs.e3i.0) * 2.0
+				+ Eoi(self.e123o.0 * self.e13.0 * rhs.e2i.0) * 2.0
+				+ Eoi(self.e123o.0 * self.e13oi.0 * rhs.e2i.0) * 2.0
+				- Eoi(self.e123o.0 * self.e1i.0 * rhs.e23.0) * 2.0
+				- Eoi(self.e123o.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- Eoi(self.e123o.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				+ Eoi(self.e123o.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				- Eoi(self.e123o.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				- Eoi(self.e12oi.0 * self.e12oi.0 * rhs.eoi.0)
+				+ Eoi(self.e12oi.0 * self.e13.0 * rhs.e23.0) * 2.0
+				- Eoi(self.e12oi.0 * self.e1i.0 * rhs.e2o.0) * 2.0
+				- Eoi(self.e12oi.0 * self.e1o.0 * rhs.e2i.0) * 2.0
+				- Eoi(self.e12oi.0 * self.e23.0 * rhs.e13.0) * 2.0
+				+ Eoi(self.e12oi.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e12oi.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				- Eoi(self.e12oi.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ Eoi(self.e13.0 * self.e13.0 * rhs.eoi.0)
+				+ Eoi(self.e13.0 * self.e1i.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e13.0 * self.e1o.0 * rhs.e3i.0) * 2.0
+				- Eoi(self.e13.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				- Eoi(self.e13.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e13.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				+ Eoi(self.e13.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				- Eoi(self.e13oi.0 * self.e13oi.0 * rhs.eoi.0)
+				- Eoi(self.e13oi.0 * self.e1i.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e13oi.0 * self.e1o.0 * rhs.e3i.0) * 2.0
+				+ Eoi(self.e13oi.0 * self.e23.0 * rhs.e12.0) * 2.0
+				+ Eoi(self.e13oi.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e13oi.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				- Eoi(self.e13oi.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ Eoi(self.e1i.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				- Eoi(self.e1i.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				- Eoi(self.e1i.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- Eoi(self.e1i.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e1i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e1o.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				+ Eoi(self.e1o.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				- Eoi(self.e1o.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- Eoi(self.e1o.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ Eoi(self.e23.0 * self.e23.0 * rhs.eoi.0)
+				+ Eoi(self.e23.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e23.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				- Eoi(self.e23.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e23.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				+ Eoi(self.e23.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				- Eoi(self.e23oi.0 * self.e23oi.0 * rhs.eoi.0)
+				- Eoi(self.e23oi.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e23oi.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				+ Eoi(self.e23oi.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e23oi.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				- Eoi(self.e23oi.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ Eoi(self.e2i.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				- Eoi(self.e2i.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				- Eoi(self.e2i.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e2i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e2o.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				- Eoi(self.e2o.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				- Eoi(self.e2o.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ Eoi(self.e3i.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				- Eoi(self.e3i.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ Eoi(self.e3i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e3o.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				- Eoi(self.e3o.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- Eoi(self.eoi.0 * self.eoi.0 * rhs.eoi.0)
+				+ Eoi(self.s.0 * self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Versor.anti_sandwich(PointPair) -> PointPair
+impl AntiSandwich<PointPair> for Versor {
+	fn anti_sandwich(self, rhs: PointPair) -> PointPair {
+		PointPair {
+			e12: -E12(self.e12.0 * self.e12.0 * rhs.e12.0) - E12(self.e12.0 * self.e13.0 * rhs.e13.0) * 2.0
+				+ E12(self.e12.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				+ E12(self.e12.0 * self.e1o.0 * rhs.e1i.0) * 2.0
+				- E12(self.e12.0 * self.e23.0 * rhs.e23.0) * 2.0
+				+ E12(self.e12.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				+ E12(self.e12.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e12.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				+ E12(self.e12.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				+ E12(self.e12.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				+ E12(self.e123i.0 * self.e123o.0 * rhs.e12.0) * 2.0
+				- E12(self.e123i.0 * self.e13.0 * rhs.e1o.0) * 2.0
+				- E12(self.e123i.0 * self.e13oi.0 * rhs.e1o.0) * 2.0
+				+ E12(self.e123i.0 * self.e1o.0 * rhs.e13.0) * 2.0
+				- E12(self.e123i.0 * self.e23.0 * rhs.e2o.0) * 2.0
+				- E12(self.e123i.0 * self.e23oi.0 * rhs.e2o.0) * 2.0
+				+ E12(self.e123i.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				+ E12(self.e123i.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				- E12(self.e123i.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				- E12(self.e123i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E12(self.e123o.0 * self.e13.0 * rhs.e1i.0) * 2.0
+				+ E12(self.e123o.0 * self.e13oi.0 * rhs.e1i.0) * 2.0
+				+ E12(self.e123o.0 * self.e1i.0 * rhs.e13.0) * 2.0
+				- E12(self.e123o.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e123o.0 * self.e23oi.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e123o.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				- E12(self.e123o.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				+ E12(self.e123o.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				- E12(self.e123o.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E12(self.e12oi.0 * self.e12oi.0 * rhs.e12.0)
+				+ E12(self.e12oi.0 * self.e13oi.0 * rhs.e13.0) * 2.0
+				+ E12(self.e12oi.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				- E12(self.e12oi.0 * self.e1o.0 * rhs.e1i.0) * 2.0
+				+ E12(self.e12oi.0 * self.e23oi.0 * rhs.e23.0) * 2.0
+				+ E12(self.e12oi.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E12(self.e12oi.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e12oi.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E12(self.e12oi.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				- E12(self.e12oi.0 * self.s.0 * rhs.eoi.0) * 2.0
+				+ E12(self.e13.0 * self.e13.0 * rhs.e12.0)
+				- E12(self.e13.0 * self.e23oi.0 * rhs.eoi.0) * 2.0
+				+ E12(self.e13.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				+ E12(self.e13.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				- E12(self.e13.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				- E12(self.e13.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				- E12(self.e13.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E12(self.e13oi.0 * self.e13oi.0 * rhs.e12.0)
+				+ E12(self.e13oi.0 * self.e23.0 * rhs.eoi.0) * 2.0
+				+ E12(self.e13oi.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				- E12(self.e13oi.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				- E12(self.e13oi.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				+ E12(self.e13oi.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e13oi.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				- E12(self.e1i.0 * self.e1o.0 * rhs.e12.0) * 2.0
+				- E12(self.e1i.0 * self.e23.0 * rhs.e3o.0) * 2.0
+				- E12(self.e1i.0 * self.e23oi.0 * rhs.e3o.0) * 2.0
+				- E12(self.e1i.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				+ E12(self.e1i.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				+ E12(self.e1i.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				+ E12(self.e1i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E12(self.e1o.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				+ E12(self.e1o.0 * self.e23oi.0 * rhs.e3i.0) * 2.0
+				+ E12(self.e1o.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				+ E12(self.e1o.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				- E12(self.e1o.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e1o.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E12(self.e23.0 * self.e23.0 * rhs.e12.0)
+				+ E12(self.e23.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				+ E12(self.e23.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				+ E12(self.e23.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E12(self.e23oi.0 * self.e23oi.0 * rhs.e12.0)
+				+ E12(self.e23oi.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				- E12(self.e23oi.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				- E12(self.e23oi.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				- E12(self.e2i.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				- E12(self.e2i.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- E12(self.e2i.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E12(self.e2i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E12(self.e2o.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				+ E12(self.e2o.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E12(self.e2o.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E12(self.e3i.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				+ E12(self.eoi.0 * self.eoi.0 * rhs.e12.0)
+				- E12(self.s.0 * self.s.0 * rhs.e12.0),
+			e13: E13(self.e12.0 * self.e12.0 * rhs.e13.0)
+				+ E13(self.e12.0 * self.e123i.0 * rhs.e1o.0) * 2.0
+				+ E13(self.e12.0 * self.e123o.0 * rhs.e1i.0) * 2.0
+				- E13(self.e12.0 * self.e13.0 * rhs.e12.0) * 2.0
+				+ E13(self.e12.0 * self.e23oi.0 * rhs.eoi.0) * 2.0
+				- E13(self.e12.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				- E13(self.e12.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e12.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				+ E13(self.e12.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				+ E13(self.e12.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E13(self.e123i.0 * self.e123o.0 * rhs.e13.0) * 2.0
+				+ E13(self.e123i.0 * self.e12oi.0 * rhs.e1o.0) * 2.0
+				- E13(self.e123i.0 * self.e1o.0 * rhs.e12.0) * 2.0
+				- E13(self.e123i.0 * self.e23.0 * rhs.e3o.0) * 2.0
+				- E13(self.e123i.0 * self.e23oi.0 * rhs.e3o.0) * 2.0
+				- E13(self.e123i.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				+ E13(self.e123i.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				+ E13(self.e123i.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				+ E13(self.e123i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E13(self.e123o.0 * self.e12oi.0 * rhs.e1i.0) * 2.0
+				- E13(self.e123o.0 * self.e1i.0 * rhs.e12.0) * 2.0
+				- E13(self.e123o.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e123o.0 * self.e23oi.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e123o.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				+ E13(self.e123o.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				- E13(self.e123o.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				+ E13(self.e123o.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E13(self.e12oi.0 * self.e12oi.0 * rhs.e13.0)
+				+ E13(self.e12oi.0 * self.e13oi.0 * rhs.e12.0) * 2.0
+				- E13(self.e12oi.0 * self.e23.0 * rhs.eoi.0) * 2.0
+				- E13(self.e12oi.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				+ E13(self.e12oi.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e12oi.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				- E13(self.e12oi.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				- E13(self.e12oi.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				- E13(self.e13.0 * self.e13.0 * rhs.e13.0)
+				+ E13(self.e13.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				+ E13(self.e13.0 * self.e1o.0 * rhs.e1i.0) * 2.0
+				- E13(self.e13.0 * self.e23.0 * rhs.e23.0) * 2.0
+				+ E13(self.e13.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				+ E13(self.e13.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				+ E13(self.e13.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				+ E13(self.e13.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e13.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				+ E13(self.e13oi.0 * self.e13oi.0 * rhs.e13.0)
+				+ E13(self.e13oi.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				- E13(self.e13oi.0 * self.e1o.0 * rhs.e1i.0) * 2.0
+				+ E13(self.e13oi.0 * self.e23oi.0 * rhs.e23.0) * 2.0
+				+ E13(self.e13oi.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E13(self.e13oi.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				+ E13(self.e13oi.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E13(self.e13oi.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				- E13(self.e13oi.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E13(self.e1i.0 * self.e1o.0 * rhs.e13.0) * 2.0
+				+ E13(self.e1i.0 * self.e23.0 * rhs.e2o.0) * 2.0
+				+ E13(self.e1i.0 * self.e23oi.0 * rhs.e2o.0) * 2.0
+				- E13(self.e1i.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				- E13(self.e1i.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				+ E13(self.e1i.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E13(self.e1i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ E13(self.e1o.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				- E13(self.e1o.0 * self.e23oi.0 * rhs.e2i.0) * 2.0
+				- E13(self.e1o.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				+ E13(self.e1o.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				- E13(self.e1o.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e1o.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E13(self.e23.0 * self.e23.0 * rhs.e13.0)
+				- E13(self.e23.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				- E13(self.e23.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				- E13(self.e23.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E13(self.e23oi.0 * self.e23oi.0 * rhs.e13.0)
+				- E13(self.e23oi.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				+ E13(self.e23oi.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				+ E13(self.e23oi.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				+ E13(self.e2i.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				- E13(self.e2i.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				- E13(self.e2o.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				- E13(self.e3i.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- E13(self.e3i.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E13(self.e3i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E13(self.e3o.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E13(self.e3o.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E13(self.eoi.0 * self.eoi.0 * rhs.e13.0)
+				- E13(self.s.0 * self.s.0 * rhs.e13.0),
+			e1o: E1o(self.e12.0 * self.e12.0 * rhs.e1o.0)
+				+ E1o(self.e12.0 * self.e123o.0 * rhs.e13.0) * 2.0
+				+ E1o(self.e12.0 * self.e12oi.0 * rhs.e1o.0) * 2.0
+				- E1o(self.e12.0 * self.e1o.0 * rhs.e12.0) * 2.0
+				- E1o(self.e12.0 * self.e23.0 * rhs.e3o.0) * 2.0
+				- E1o(self.e12.0 * self.e23oi.0 * rhs.e3o.0) * 2.0
+				- E1o(self.e12.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				+ E1o(self.e12.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				+ E1o(self.e12.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e12.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e123o.0 * self.e123o.0 * rhs.e1i.0) * 2.0
+				+ E1o(self.e123o.0 * self.e12oi.0 * rhs.e13.0) * 2.0
+				- E1o(self.e123o.0 * self.e13.0 * rhs.e12.0) * 2.0
+				- E1o(self.e123o.0 * self.e13oi.0 * rhs.e12.0) * 2.0
+				+ E1o(self.e123o.0 * self.e23.0 * rhs.eoi.0) * 2.0
+				+ E1o(self.e123o.0 * self.e23oi.0 * rhs.eoi.0) * 2.0
+				- E1o(self.e123o.0 * self.e2o.0 * rhs.e3i.0) * 4.0
+				+ E1o(self.e123o.0 * self.e3o.0 * rhs.e2i.0) * 4.0
+				+ E1o(self.e123o.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				+ E1o(self.e123o.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E1o(self.e12oi.0 * self.e12oi.0 * rhs.e1o.0)
+				- E1o(self.e12oi.0 * self.e1o.0 * rhs.e12.0) * 2.0
+				- E1o(self.e12oi.0 * self.e23.0 * rhs.e3o.0) * 2.0
+				- E1o(self.e12oi.0 * self.e23oi.0 * rhs.e3o.0) * 2.0
+				- E1o(self.e12oi.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				+ E1o(self.e12oi.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				+ E1o(self.e12oi.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e12oi.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e13.0 * self.e13.0 * rhs.e1o.0)
+				+ E1o(self.e13.0 * self.e13oi.0 * rhs.e1o.0) * 2.0
+				- E1o(self.e13.0 * self.e1o.0 * rhs.e13.0) * 2.0
+				+ E1o(self.e13.0 * self.e23.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e13.0 * self.e23oi.0 * rhs.e2o.0) * 2.0
+				- E1o(self.e13.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				- E1o(self.e13.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				+ E1o(self.e13.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E1o(self.e13.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ E1o(self.e13oi.0 * self.e13oi.0 * rhs.e1o.0)
+				- E1o(self.e13oi.0 * self.e1o.0 * rhs.e13.0) * 2.0
+				+ E1o(self.e13oi.0 * self.e23.0 * rhs.e2o.0) * 2.0
+				+ E1o(self.e13oi.0 * self.e23oi.0 * rhs.e2o.0) * 2.0
+				- E1o(self.e13oi.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				- E1o(self.e13oi.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				+ E1o(self.e13oi.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E1o(self.e13oi.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ E1o(self.e1o.0 * self.e1o.0 * rhs.e1i.0) * 2.0
+				- E1o(self.e1o.0 * self.e23.0 * rhs.e23.0) * 2.0
+				- E1o(self.e1o.0 * self.e23oi.0 * rhs.e23.0) * 2.0
+				+ E1o(self.e1o.0 * self.e2o.0 * rhs.e2i.0) * 4.0
+				+ E1o(self.e1o.0 * self.e3o.0 * rhs.e3i.0) * 4.0
+				+ E1o(self.e1o.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				+ E1o(self.e1o.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E1o(self.e23.0 * self.e23.0 * rhs.e1o.0)
+				- E1o(self.e23.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				+ E1o(self.e23.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				- E1o(self.e23.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				- E1o(self.e23oi.0 * self.e23oi.0 * rhs.e1o.0)
+				+ E1o(self.e23oi.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				- E1o(self.e23oi.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				- E1o(self.e2o.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				- E1o(self.e2o.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				- E1o(self.e2o.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E1o(self.e3o.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				- E1o(self.e3o.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				- E1o(self.e3o.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E1o(self.eoi.0 * self.eoi.0 * rhs.e1o.0)
+				- E1o(self.eoi.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E1o(self.s.0 * self.s.0 * rhs.e1o.0),
+			e1i: E1i(self.e12.0 * self.e12.0 * rhs.e1i.0) + E1i(self.e12.0 * self.e123i.0 * rhs.e13.0) * 2.0
+				- E1i(self.e12.0 * self.e12oi.0 * rhs.e1i.0) * 2.0
+				- E1i(self.e12.0 * self.e1i.0 * rhs.e12.0) * 2.0
+				- E1i(self.e12.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e12.0 * self.e23oi.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e12.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				+ E1i(self.e12.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				- E1i(self.e12.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e12.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e123i.0 * self.e123i.0 * rhs.e1o.0) * 2.0
+				- E1i(self.e123i.0 * self.e12oi.0 * rhs.e13.0) * 2.0
+				- E1i(self.e123i.0 * self.e13.0 * rhs.e12.0) * 2.0
+				+ E1i(self.e123i.0 * self.e13oi.0 * rhs.e12.0) * 2.0
+				- E1i(self.e123i.0 * self.e23.0 * rhs.eoi.0) * 2.0
+				+ E1i(self.e123i.0 * self.e23oi.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e123i.0 * self.e2i.0 * rhs.e3o.0) * 4.0
+				+ E1i(self.e123i.0 * self.e3i.0 * rhs.e2o.0) * 4.0
+				- E1i(self.e123i.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				+ E1i(self.e123i.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E1i(self.e12oi.0 * self.e12oi.0 * rhs.e1i.0)
+				+ E1i(self.e12oi.0 * self.e1i.0 * rhs.e12.0) * 2.0
+				+ E1i(self.e12oi.0 * self.e23.0 * rhs.e3i.0) * 2.0
+				- E1i(self.e12oi.0 * self.e23oi.0 * rhs.e3i.0) * 2.0
+				- E1i(self.e12oi.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e12oi.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				+ E1i(self.e12oi.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				- E1i(self.e12oi.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13.0 * self.e13.0 * rhs.e1i.0)
+				- E1i(self.e13.0 * self.e13oi.0 * rhs.e1i.0) * 2.0
+				- E1i(self.e13.0 * self.e1i.0 * rhs.e13.0) * 2.0
+				+ E1i(self.e13.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				- E1i(self.e13.0 * self.e23oi.0 * rhs.e2i.0) * 2.0
+				- E1i(self.e13.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				+ E1i(self.e13.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e13.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e13.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e13oi.0 * self.e13oi.0 * rhs.e1i.0)
+				+ E1i(self.e13oi.0 * self.e1i.0 * rhs.e13.0) * 2.0
+				- E1i(self.e13oi.0 * self.e23.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13oi.0 * self.e23oi.0 * rhs.e2i.0) * 2.0
+				+ E1i(self.e13oi.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				- E1i(self.e13oi.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				+ E1i(self.e13oi.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				- E1i(self.e13oi.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E1i(self.e1i.0 * self.e1i.0 * rhs.e1o.0) * 2.0
+				- E1i(self.e1i.0 * self.e23.0 * rhs.e23.0) * 2.0
+				+ E1i(self.e1i.0 * self.e23oi.0 * rhs.e23.0) * 2.0
+				+ E1i(self.e1i.0 * self.e2i.0 * rhs.e2o.0) * 4.0
+				+ E1i(self.e1i.0 * self.e3i.0 * rhs.e3o.0) * 4.0
+				+ E1i(self.e1i.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e1i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E1i(self.e23.0 * self.e23.0 * rhs.e1i.0)
+				+ E1i(self.e23.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				+ E1i(self.e23.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				- E1i(self.e23.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				- E1i(self.e23oi.0 * self.e23oi.0 * rhs.e1i.0)
+				- E1i(self.e23oi.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				+ E1i(self.e23oi.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				- E1i(self.e2i.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				+ E1i(self.e2i.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				- E1i(self.e2i.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E1i(self.e3i.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				+ E1i(self.e3i.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				- E1i(self.e3i.0 * self.s.0 * rhs.e13.0) * 2.0
+				- E1i(self.eoi.0 * self.eoi.0 * rhs.e1i.0)
+				+ E1i(self.eoi.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E1i(self.s.0 * self.s.0 * rhs.e1i.0),
+			e23: E23(self.e12.0 * self.e12.0 * rhs.e23.0)
+				+ E23(self.e12.0 * self.e123i.0 * rhs.e2o.0) * 2.0
+				+ E23(self.e12.0 * self.e123o.0 * rhs.e2i.0) * 2.0
+				- E23(self.e12.0 * self.e13oi.0 * rhs.eoi.0) * 2.0
+				+ E23(self.e12.0 * self.e1i.0 * rhs.e3o.0) * 2.0
+				+ E23(self.e12.0 * self.e1o.0 * rhs.e3i.0) * 2.0
+				- E23(self.e12.0 * self.e23.0 * rhs.e12.0) * 2.0
+				- E23(self.e12.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				- E23(self.e12.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				- E23(self.e12.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E23(self.e123i.0 * self.e123o.0 * rhs.e23.0) * 2.0
+				+ E23(self.e123i.0 * self.e12oi.0 * rhs.e2o.0) * 2.0
+				+ E23(self.e123i.0 * self.e13.0 * rhs.e3o.0) * 2.0
+				+ E23(self.e123i.0 * self.e13oi.0 * rhs.e3o.0) * 2.0
+				+ E23(self.e123i.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				- E23(self.e123i.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				- E23(self.e123i.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- E23(self.e123i.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E23(self.e123i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E23(self.e123o.0 * self.e12oi.0 * rhs.e2i.0) * 2.0
+				+ E23(self.e123o.0 * self.e13.0 * rhs.e3i.0) * 2.0
+				- E23(self.e123o.0 * self.e13oi.0 * rhs.e3i.0) * 2.0
+				- E23(self.e123o.0 * self.e1i.0 * rhs.eoi.0) * 2.0
+				- E23(self.e123o.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				- E23(self.e123o.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				+ E23(self.e123o.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E23(self.e123o.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E23(self.e12oi.0 * self.e12oi.0 * rhs.e23.0)
+				+ E23(self.e12oi.0 * self.e13.0 * rhs.eoi.0) * 2.0
+				+ E23(self.e12oi.0 * self.e1i.0 * rhs.e3o.0) * 2.0
+				- E23(self.e12oi.0 * self.e1o.0 * rhs.e3i.0) * 2.0
+				+ E23(self.e12oi.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				- E23(self.e12oi.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				+ E23(self.e12oi.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				+ E23(self.e12oi.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				+ E23(self.e13.0 * self.e13.0 * rhs.e23.0)
+				- E23(self.e13.0 * self.e1i.0 * rhs.e2o.0) * 2.0
+				- E23(self.e13.0 * self.e1o.0 * rhs.e2i.0) * 2.0
+				- E23(self.e13.0 * self.e23.0 * rhs.e13.0) * 2.0
+				+ E23(self.e13.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				+ E23(self.e13.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				+ E23(self.e13.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E23(self.e13oi.0 * self.e13oi.0 * rhs.e23.0)
+				- E23(self.e13oi.0 * self.e1i.0 * rhs.e2o.0) * 2.0
+				+ E23(self.e13oi.0 * self.e1o.0 * rhs.e2i.0) * 2.0
+				+ E23(self.e13oi.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				+ E23(self.e13oi.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				- E23(self.e13oi.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				- E23(self.e13oi.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				+ E23(self.e1i.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				+ E23(self.e1i.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				+ E23(self.e1i.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				- E23(self.e1i.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				+ E23(self.e1i.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				+ E23(self.e1o.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- E23(self.e1o.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				- E23(self.e1o.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				+ E23(self.e1o.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				- E23(self.e23.0 * self.e23.0 * rhs.e23.0)
+				+ E23(self.e23.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				+ E23(self.e23.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				+ E23(self.e23.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				+ E23(self.e23.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				+ E23(self.e23.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				+ E23(self.e23oi.0 * self.e23oi.0 * rhs.e23.0)
+				+ E23(self.e23oi.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				- E23(self.e23oi.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				+ E23(self.e23oi.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				- E23(self.e23oi.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				- E23(self.e23oi.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E23(self.e2i.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				- E23(self.e2i.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				+ E23(self.e2i.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E23(self.e2i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ E23(self.e2o.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				- E23(self.e2o.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E23(self.e2o.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E23(self.e3i.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				- E23(self.e3i.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				- E23(self.e3i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E23(self.e3o.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				- E23(self.e3o.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E23(self.eoi.0 * self.eoi.0 * rhs.e23.0)
+				- E23(self.s.0 * self.s.0 * rhs.e23.0),
+			e2o: E2o(self.e12.0 * self.e12.0 * rhs.e2o.0)
+				+ E2o(self.e12.0 * self.e123o.0 * rhs.e23.0) * 2.0
+				+ E2o(self.e12.0 * self.e12oi.0 * rhs.e2o.0) * 2.0
+				+ E2o(self.e12.0 * self.e13.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e12.0 * self.e13oi.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e12.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				- E2o(self.e12.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				- E2o(self.e12.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- E2o(self.e12.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e12.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E2o(self.e123o.0 * self.e123o.0 * rhs.e2i.0) * 2.0
+				+ E2o(self.e123o.0 * self.e12oi.0 * rhs.e23.0) * 2.0
+				- E2o(self.e123o.0 * self.e13.0 * rhs.eoi.0) * 2.0
+				- E2o(self.e123o.0 * self.e13oi.0 * rhs.eoi.0) * 2.0
+				+ E2o(self.e123o.0 * self.e1o.0 * rhs.e3i.0) * 4.0
+				- E2o(self.e123o.0 * self.e23.0 * rhs.e12.0) * 2.0
+				- E2o(self.e123o.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				- E2o(self.e123o.0 * self.e3o.0 * rhs.e1i.0) * 4.0
+				- E2o(self.e123o.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				- E2o(self.e123o.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E2o(self.e12oi.0 * self.e12oi.0 * rhs.e2o.0)
+				+ E2o(self.e12oi.0 * self.e13.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e12oi.0 * self.e13oi.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e12oi.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				- E2o(self.e12oi.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				- E2o(self.e12oi.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- E2o(self.e12oi.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e12oi.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e13.0 * self.e13.0 * rhs.e2o.0)
+				- E2o(self.e13.0 * self.e13oi.0 * rhs.e2o.0) * 2.0
+				+ E2o(self.e13.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				+ E2o(self.e13.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				+ E2o(self.e13.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e13.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				+ E2o(self.e13.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				- E2o(self.e13oi.0 * self.e13oi.0 * rhs.e2o.0)
+				+ E2o(self.e13oi.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				+ E2o(self.e13oi.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				+ E2o(self.e13oi.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				- E2o(self.e13oi.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				+ E2o(self.e13oi.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				- E2o(self.e1o.0 * self.e1o.0 * rhs.e2i.0) * 2.0
+				- E2o(self.e1o.0 * self.e23.0 * rhs.e13.0) * 2.0
+				- E2o(self.e1o.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				+ E2o(self.e1o.0 * self.e2o.0 * rhs.e1i.0) * 4.0
+				+ E2o(self.e1o.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				+ E2o(self.e1o.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E2o(self.e23.0 * self.e23.0 * rhs.e2o.0)
+				+ E2o(self.e23.0 * self.e23oi.0 * rhs.e2o.0) * 2.0
+				- E2o(self.e23.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				- E2o(self.e23.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				+ E2o(self.e23.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e23.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e23oi.0 * self.e23oi.0 * rhs.e2o.0)
+				- E2o(self.e23oi.0 * self.e2o.0 * rhs.e23.0) * 2.0
+				- E2o(self.e23oi.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				+ E2o(self.e23oi.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e23oi.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ E2o(self.e2o.0 * self.e2o.0 * rhs.e2i.0) * 2.0
+				+ E2o(self.e2o.0 * self.e3o.0 * rhs.e3i.0) * 4.0
+				+ E2o(self.e2o.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				+ E2o(self.e2o.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E2o(self.e3o.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				- E2o(self.e3o.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				- E2o(self.e3o.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E2o(self.eoi.0 * self.eoi.0 * rhs.e2o.0)
+				- E2o(self.eoi.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E2o(self.s.0 * self.s.0 * rhs.e2o.0),
+			e2i: E2i(self.e12.0 * self.e12.0 * rhs.e2i.0) + E2i(self.e12.0 * self.e123i.0 * rhs.e23.0) * 2.0
+				- E2i(self.e12.0 * self.e12oi.0 * rhs.e2i.0) * 2.0
+				+ E2i(self.e12.0 * self.e13.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e12.0 * self.e13oi.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e12.0 * self.e1i.0 * rhs.eoi.0) * 2.0
+				- E2i(self.e12.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				- E2i(self.e12.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				+ E2i(self.e12.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e12.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e123i.0 * self.e123i.0 * rhs.e2o.0) * 2.0
+				- E2i(self.e123i.0 * self.e12oi.0 * rhs.e23.0) * 2.0
+				+ E2i(self.e123i.0 * self.e13.0 * rhs.eoi.0) * 2.0
+				- E2i(self.e123i.0 * self.e13oi.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e123i.0 * self.e1i.0 * rhs.e3o.0) * 4.0
+				- E2i(self.e123i.0 * self.e23.0 * rhs.e12.0) * 2.0
+				+ E2i(self.e123i.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				- E2i(self.e123i.0 * self.e3i.0 * rhs.e1o.0) * 4.0
+				+ E2i(self.e123i.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				- E2i(self.e123i.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E2i(self.e12oi.0 * self.e12oi.0 * rhs.e2i.0)
+				- E2i(self.e12oi.0 * self.e13.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.e12oi.0 * self.e13oi.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.e12oi.0 * self.e1i.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e12oi.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				+ E2i(self.e12oi.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				- E2i(self.e12oi.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e12oi.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e13.0 * self.e13.0 * rhs.e2i.0)
+				+ E2i(self.e13.0 * self.e13oi.0 * rhs.e2i.0) * 2.0
+				+ E2i(self.e13.0 * self.e1i.0 * rhs.e23.0) * 2.0
+				+ E2i(self.e13.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e13.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				- E2i(self.e13.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				+ E2i(self.e13.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				- E2i(self.e13oi.0 * self.e13oi.0 * rhs.e2i.0)
+				- E2i(self.e13oi.0 * self.e1i.0 * rhs.e23.0) * 2.0
+				- E2i(self.e13oi.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e13oi.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				+ E2i(self.e13oi.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				- E2i(self.e13oi.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				- E2i(self.e1i.0 * self.e1i.0 * rhs.e2o.0) * 2.0
+				- E2i(self.e1i.0 * self.e23.0 * rhs.e13.0) * 2.0
+				+ E2i(self.e1i.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				+ E2i(self.e1i.0 * self.e2i.0 * rhs.e1o.0) * 4.0
+				- E2i(self.e1i.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				+ E2i(self.e1i.0 * self.s.0 * rhs.e12.0) * 2.0
+				+ E2i(self.e23.0 * self.e23.0 * rhs.e2i.0)
+				- E2i(self.e23.0 * self.e23oi.0 * rhs.e2i.0) * 2.0
+				- E2i(self.e23.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				+ E2i(self.e23.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				- E2i(self.e23.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.e23.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.e23oi.0 * self.e23oi.0 * rhs.e2i.0)
+				+ E2i(self.e23oi.0 * self.e2i.0 * rhs.e23.0) * 2.0
+				- E2i(self.e23oi.0 * self.e3i.0 * rhs.eoi.0) * 2.0
+				+ E2i(self.e23oi.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				- E2i(self.e23oi.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ E2i(self.e2i.0 * self.e2i.0 * rhs.e2o.0) * 2.0
+				+ E2i(self.e2i.0 * self.e3i.0 * rhs.e3o.0) * 4.0
+				+ E2i(self.e2i.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				- E2i(self.e2i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E2i(self.e3i.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				+ E2i(self.e3i.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				- E2i(self.e3i.0 * self.s.0 * rhs.e23.0) * 2.0
+				- E2i(self.eoi.0 * self.eoi.0 * rhs.e2i.0)
+				+ E2i(self.eoi.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E2i(self.s.0 * self.s.0 * rhs.e2i.0),
+			e3o: -E3o(self.e12.0 * self.e12.0 * rhs.e3o.0) + E3o(self.e12.0 * self.e123o.0 * rhs.eoi.0) * 2.0
+				- E3o(self.e12.0 * self.e12oi.0 * rhs.e3o.0) * 2.0
+				+ E3o(self.e12.0 * self.e13.0 * rhs.e2o.0) * 2.0
+				+ E3o(self.e12.0 * self.e13oi.0 * rhs.e2o.0) * 2.0
+				- E3o(self.e12.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				- E3o(self.e12.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e12.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				+ E3o(self.e12.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				- E3o(self.e12.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				+ E3o(self.e123o.0 * self.e123o.0 * rhs.e3i.0) * 2.0
+				+ E3o(self.e123o.0 * self.e12oi.0 * rhs.eoi.0) * 2.0
+				+ E3o(self.e123o.0 * self.e13.0 * rhs.e23.0) * 2.0
+				+ E3o(self.e123o.0 * self.e13oi.0 * rhs.e23.0) * 2.0
+				- E3o(self.e123o.0 * self.e1o.0 * rhs.e2i.0) * 4.0
+				- E3o(self.e123o.0 * self.e23.0 * rhs.e13.0) * 2.0
+				- E3o(self.e123o.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				+ E3o(self.e123o.0 * self.e2o.0 * rhs.e1i.0) * 4.0
+				+ E3o(self.e123o.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				+ E3o(self.e123o.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E3o(self.e12oi.0 * self.e12oi.0 * rhs.e3o.0)
+				+ E3o(self.e12oi.0 * self.e13.0 * rhs.e2o.0) * 2.0
+				+ E3o(self.e12oi.0 * self.e13oi.0 * rhs.e2o.0) * 2.0
+				- E3o(self.e12oi.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				- E3o(self.e12oi.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e12oi.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				+ E3o(self.e12oi.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				- E3o(self.e12oi.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				+ E3o(self.e13.0 * self.e13.0 * rhs.e3o.0)
+				+ E3o(self.e13.0 * self.e13oi.0 * rhs.e3o.0) * 2.0
+				+ E3o(self.e13.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				- E3o(self.e13.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				- E3o(self.e13.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- E3o(self.e13.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e13.0 * self.s.0 * rhs.e1o.0) * 2.0
+				+ E3o(self.e13oi.0 * self.e13oi.0 * rhs.e3o.0)
+				+ E3o(self.e13oi.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				- E3o(self.e13oi.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				- E3o(self.e13oi.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				- E3o(self.e13oi.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e13oi.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- E3o(self.e1o.0 * self.e1o.0 * rhs.e3i.0) * 2.0
+				+ E3o(self.e1o.0 * self.e23.0 * rhs.e12.0) * 2.0
+				+ E3o(self.e1o.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				+ E3o(self.e1o.0 * self.e3o.0 * rhs.e1i.0) * 4.0
+				+ E3o(self.e1o.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				+ E3o(self.e1o.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E3o(self.e23.0 * self.e23.0 * rhs.e3o.0)
+				+ E3o(self.e23.0 * self.e23oi.0 * rhs.e3o.0) * 2.0
+				+ E3o(self.e23.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				- E3o(self.e23.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				- E3o(self.e23.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				- E3o(self.e23.0 * self.s.0 * rhs.e2o.0) * 2.0
+				+ E3o(self.e23oi.0 * self.e23oi.0 * rhs.e3o.0)
+				+ E3o(self.e23oi.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				- E3o(self.e23oi.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				- E3o(self.e23oi.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				- E3o(self.e23oi.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- E3o(self.e2o.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				+ E3o(self.e2o.0 * self.e3o.0 * rhs.e2i.0) * 4.0
+				+ E3o(self.e2o.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				+ E3o(self.e2o.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E3o(self.e3o.0 * self.e3o.0 * rhs.e3i.0) * 2.0
+				+ E3o(self.e3o.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				+ E3o(self.e3o.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E3o(self.eoi.0 * self.eoi.0 * rhs.e3o.0)
+				- E3o(self.eoi.0 * self.s.0 * rhs.e3o.0) * 2.0
+				- E3o(self.s.0 * self.s.0 * rhs.e3o.0),
+			e3i: -E3i(self.e12.0 * self.e12.0 * rhs.e3i.0) - E3i(self.e12.0 * self.e123i.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.e12.0 * self.e12oi.0 * rhs.e3i.0) * 2.0
+				+ E3i(self.e12.0 * self.e13.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e12.0 * self.e13oi.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e12.0 * self.e1i.0 * rhs.e23.0) * 2.0
+				- E3i(self.e12.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e12.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e12.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				- E3i(self.e12.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				+ E3i(self.e123i.0 * self.e123i.0 * rhs.e3o.0) * 2.0
+				+ E3i(self.e123i.0 * self.e12oi.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.e123i.0 * self.e13.0 * rhs.e23.0) * 2.0
+				- E3i(self.e123i.0 * self.e13oi.0 * rhs.e23.0) * 2.0
+				- E3i(self.e123i.0 * self.e1i.0 * rhs.e2o.0) * 4.0
+				- E3i(self.e123i.0 * self.e23.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e123i.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e123i.0 * self.e2i.0 * rhs.e1o.0) * 4.0
+				- E3i(self.e123i.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				+ E3i(self.e123i.0 * self.s.0 * rhs.e12.0) * 2.0
+				- E3i(self.e12oi.0 * self.e12oi.0 * rhs.e3i.0)
+				- E3i(self.e12oi.0 * self.e13.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.e12oi.0 * self.e13oi.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.e12oi.0 * self.e1i.0 * rhs.e23.0) * 2.0
+				+ E3i(self.e12oi.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e12oi.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e12oi.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e12oi.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				+ E3i(self.e13.0 * self.e13.0 * rhs.e3i.0)
+				- E3i(self.e13.0 * self.e13oi.0 * rhs.e3i.0) * 2.0
+				- E3i(self.e13.0 * self.e1i.0 * rhs.eoi.0) * 2.0
+				- E3i(self.e13.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				- E3i(self.e13.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e13.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e13.0 * self.s.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e13oi.0 * self.e13oi.0 * rhs.e3i.0)
+				+ E3i(self.e13oi.0 * self.e1i.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.e13oi.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				+ E3i(self.e13oi.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				- E3i(self.e13oi.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				+ E3i(self.e13oi.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- E3i(self.e1i.0 * self.e1i.0 * rhs.e3o.0) * 2.0
+				+ E3i(self.e1i.0 * self.e23.0 * rhs.e12.0) * 2.0
+				- E3i(self.e1i.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				+ E3i(self.e1i.0 * self.e3i.0 * rhs.e1o.0) * 4.0
+				- E3i(self.e1i.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e1i.0 * self.s.0 * rhs.e13.0) * 2.0
+				+ E3i(self.e23.0 * self.e23.0 * rhs.e3i.0)
+				- E3i(self.e23.0 * self.e23oi.0 * rhs.e3i.0) * 2.0
+				- E3i(self.e23.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				- E3i(self.e23.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				+ E3i(self.e23.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e23.0 * self.s.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.e23oi.0 * self.e23oi.0 * rhs.e3i.0)
+				+ E3i(self.e23oi.0 * self.e2i.0 * rhs.eoi.0) * 2.0
+				+ E3i(self.e23oi.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				- E3i(self.e23oi.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				+ E3i(self.e23oi.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- E3i(self.e2i.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				+ E3i(self.e2i.0 * self.e3i.0 * rhs.e2o.0) * 4.0
+				- E3i(self.e2i.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				+ E3i(self.e2i.0 * self.s.0 * rhs.e23.0) * 2.0
+				+ E3i(self.e3i.0 * self.e3i.0 * rhs.e3o.0) * 2.0
+				+ E3i(self.e3i.0 * self.eoi.0 * rhs.eoi.0) * 2.0
+				- E3i(self.e3i.0 * self.s.0 * rhs.eoi.0) * 2.0
+				- E3i(self.eoi.0 * self.eoi.0 * rhs.e3i.0)
+				+ E3i(self.eoi.0 * self.s.0 * rhs.e3i.0) * 2.0
+				- E3i(self.s.0 * self.s.0 * rhs.e3i.0),
+			eoi: -Eoi(self.e12.0 * self.e12.0 * rhs.eoi.0) + Eoi(self.e12.0 * self.e123i.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e12.0 * self.e123o.0 * rhs.e3i.0) * 2.0
+				- Eoi(self.e12.0 * self.e13oi.0 * rhs.e23.0) * 2.0
+				- Eoi(self.e12.0 * self.e1i.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e12.0 * self.e1o.0 * rhs.e2i.0) * 2.0
+				+ Eoi(self.e12.0 * self.e23oi.0 * rhs.e13.0) * 2.0
+				+ Eoi(self.e12.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				- Eoi(self.e12.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				- Eoi(self.e12.0 * self.eoi.0 * rhs.e12.0) * 2.0
+				- Eoi(self.e123i.0 * self.e123o.0 * rhs.eoi.0) * 2.0
+				+ Eoi(self.e123i.0 * self.e12oi.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e123i.0 * self.e13.0 * rhs.e2o.0) * 2.0
+				- Eoi(self.e123i.0 * self.e13oi.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e123i.0 * self.e1o.0 * rhs.e23.0) * 2.0
+				+ Eoi(self.e123i.0 * self.e23.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e123i.0 * self.e23oi.0 * rhs.e1o.0) * 2.0
+				- Eoi(self.e123i.0 * self.e2o.0 * rhs.e13.0) * 2.0
+				+ Eoi(self.e123i.0 * self.e3o.0 * rhs.e12.0) * 2.0
+				+ Eoi(self.e123o.0 * self.e12oi.0 * rhs.e3i.0) * 2.0
+				+ Eoi(self.e123o.0 * self.e13.0 * rhs.e2i.0) * 2.0
+				- Eoi(self.e123o.0 * self.e13oi.0 * rhs.e2i.0) * 2.0
+				- Eoi(self.e123o.0 * self.e1i.0 * rhs.e23.0) * 2.0
+				- Eoi(self.e123o.0 * self.e23.0 * rhs.e1i.0) * 2.0
+				+ Eoi(self.e123o.0 * self.e23oi.0 * rhs.e1i.0) * 2.0
+				+ Eoi(self.e123o.0 * self.e2i.0 * rhs.e13.0) * 2.0
+				- Eoi(self.e123o.0 * self.e3i.0 * rhs.e12.0) * 2.0
+				+ Eoi(self.e12oi.0 * self.e12oi.0 * rhs.eoi.0)
+				+ Eoi(self.e12oi.0 * self.e13.0 * rhs.e23.0) * 2.0
+				- Eoi(self.e12oi.0 * self.e1i.0 * rhs.e2o.0) * 2.0
+				- Eoi(self.e12oi.0 * self.e1o.0 * rhs.e2i.0) * 2.0
+				- Eoi(self.e12oi.0 * self.e23.0 * rhs.e13.0) * 2.0
+				+ Eoi(self.e12oi.0 * self.e2i.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e12oi.0 * self.e2o.0 * rhs.e1i.0) * 2.0
+				+ Eoi(self.e12oi.0 * self.s.0 * rhs.e12.0) * 2.0
+				- Eoi(self.e13.0 * self.e13.0 * rhs.eoi.0)
+				- Eoi(self.e13.0 * self.e1i.0 * rhs.e3o.0) * 2.0
+				+ Eoi(self.e13.0 * self.e1o.0 * rhs.e3i.0) * 2.0
+				- Eoi(self.e13.0 * self.e23oi.0 * rhs.e12.0) * 2.0
+				+ Eoi(self.e13.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				- Eoi(self.e13.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				- Eoi(self.e13.0 * self.eoi.0 * rhs.e13.0) * 2.0
+				+ Eoi(self.e13oi.0 * self.e13oi.0 * rhs.eoi.0)
+				- Eoi(self.e13oi.0 * self.e1i.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e13oi.0 * self.e1o.0 * rhs.e3i.0) * 2.0
+				+ Eoi(self.e13oi.0 * self.e23.0 * rhs.e12.0) * 2.0
+				+ Eoi(self.e13oi.0 * self.e3i.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e13oi.0 * self.e3o.0 * rhs.e1i.0) * 2.0
+				+ Eoi(self.e13oi.0 * self.s.0 * rhs.e13.0) * 2.0
+				- Eoi(self.e1i.0 * self.e1o.0 * rhs.eoi.0) * 2.0
+				+ Eoi(self.e1i.0 * self.e2o.0 * rhs.e12.0) * 2.0
+				+ Eoi(self.e1i.0 * self.e3o.0 * rhs.e13.0) * 2.0
+				+ Eoi(self.e1i.0 * self.eoi.0 * rhs.e1o.0) * 2.0
+				+ Eoi(self.e1i.0 * self.s.0 * rhs.e1o.0) * 2.0
+				- Eoi(self.e1o.0 * self.e2i.0 * rhs.e12.0) * 2.0
+				- Eoi(self.e1o.0 * self.e3i.0 * rhs.e13.0) * 2.0
+				+ Eoi(self.e1o.0 * self.eoi.0 * rhs.e1i.0) * 2.0
+				- Eoi(self.e1o.0 * self.s.0 * rhs.e1i.0) * 2.0
+				- Eoi(self.e23.0 * self.e23.0 * rhs.eoi.0)
+				- Eoi(self.e23.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				+ Eoi(self.e23.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				+ Eoi(self.e23.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				- Eoi(self.e23.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				- Eoi(self.e23.0 * self.eoi.0 * rhs.e23.0) * 2.0
+				+ Eoi(self.e23oi.0 * self.e23oi.0 * rhs.eoi.0)
+				- Eoi(self.e23oi.0 * self.e2i.0 * rhs.e3o.0) * 2.0
+				- Eoi(self.e23oi.0 * self.e2o.0 * rhs.e3i.0) * 2.0
+				+ Eoi(self.e23oi.0 * self.e3i.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e23oi.0 * self.e3o.0 * rhs.e2i.0) * 2.0
+				+ Eoi(self.e23oi.0 * self.s.0 * rhs.e23.0) * 2.0
+				- Eoi(self.e2i.0 * self.e2o.0 * rhs.eoi.0) * 2.0
+				+ Eoi(self.e2i.0 * self.e3o.0 * rhs.e23.0) * 2.0
+				+ Eoi(self.e2i.0 * self.eoi.0 * rhs.e2o.0) * 2.0
+				+ Eoi(self.e2i.0 * self.s.0 * rhs.e2o.0) * 2.0
+				- Eoi(self.e2o.0 * self.e3i.0 * rhs.e23.0) * 2.0
+				+ Eoi(self.e2o.0 * self.eoi.0 * rhs.e2i.0) * 2.0
+				- Eoi(self.e2o.0 * self.s.0 * rhs.e2i.0) * 2.0
+				- Eoi(self.e3i.0 * self.e3o.0 * rhs.eoi.0) * 2.0
+				+ Eoi(self.e3i.0 * self.eoi.0 * rhs.e3o.0) * 2.0
+				+ Eoi(self.e3i.0 * self.s.0 * rhs.e3o.0) * 2.0
+				+ Eoi(self.e3o.0 * self.eoi.0 * rhs.e3i.0) * 2.0
+				- Eoi(self.e3o.0 * self.s.0 * rhs.e3i.0) * 2.0
+				+ Eoi(self.eoi.0 * self.eoi.0 * rhs.eoi.0)
+				- Eoi(self.s.0 * self.s.0 * rhs.eoi.0),
+		}
+	}
+}
+
+// Omitted: Versor.sandwich(Line)  (not a Line)
+// Omitted: Versor.anti_sandwich(Line)  (not a Line)
+
+// Versor.sandwich(Circle) -> Circle
+impl Sandwich<Circle> for Versor {
+	fn sandwich(self, rhs: Circle) -> Circle {
+		Circle {
+			e123: E123(self.e12.0 * self.e12.0 * rhs.e123.0)
+				- E123(self.e12.0 * self.e123i.0 * rhs.e12o.0) * 2.0
+				- E123(self.e12.0 * self.e123o.0 * rhs.e12i.0) * 2.0
+				+ E123(self.e12.0 * self.e13oi.0 * rhs.e1oi.0) * 2.0
+				+ E123(self.e12.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				+ E123(self.e12.0 * self.e1o.0 * rhs.e13i.0) * 2.0
+				+ E123(self.e12.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				+ E123(self.e12.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E123(self.e12.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				- E123(self.e12.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				+ E123(self.e123i.0 * self.e123o.0 * rhs.e123.0) * 2.0
+				+ E123(self.e123i.0 * self.e12oi.0 * rhs.e12o.0) * 2.0
+				- E123(self.e123i.0 * self.e13.0 * rhs.e13o.0) * 2.0
+				+ E123(self.e123i.0 * self.e13oi.0 * rhs.e13o.0) * 2.0
+				- E123(self.e123i.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e123i.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				+ E123(self.e123i.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				- E123(self.e123i.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E123(self.e123i.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				- E123(self.e123o.0 * self.e12oi.0 * rhs.e12i.0) * 2.0
+				- E123(self.e123o.0 * self.e13.0 * rhs.e13i.0) * 2.0
+				- E123(self.e123o.0 * self.e13oi.0 * rhs.e13i.0) * 2.0
+				+ E123(self.e123o.0 * self.e1i.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e123o.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				- E123(self.e123o.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				+ E123(self.e123o.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				+ E123(self.e123o.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				- E123(self.e12oi.0 * self.e12oi.0 * rhs.e123.0)
+				- E123(self.e12oi.0 * self.e13.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e12oi.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				+ E123(self.e12oi.0 * self.e1o.0 * rhs.e13i.0) * 2.0
+				- E123(self.e12oi.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				- E123(self.e12oi.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E123(self.e12oi.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				+ E123(self.e12oi.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E123(self.e13.0 * self.e13.0 * rhs.e123.0)
+				- E123(self.e13.0 * self.e1i.0 * rhs.e12o.0) * 2.0
+				- E123(self.e13.0 * self.e1o.0 * rhs.e12i.0) * 2.0
+				+ E123(self.e13.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				+ E123(self.e13.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				+ E123(self.e13.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				+ E123(self.e13.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				- E123(self.e13oi.0 * self.e13oi.0 * rhs.e123.0)
+				+ E123(self.e13oi.0 * self.e1i.0 * rhs.e12o.0) * 2.0
+				- E123(self.e13oi.0 * self.e1o.0 * rhs.e12i.0) * 2.0
+				- E123(self.e13oi.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				- E123(self.e13oi.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				+ E123(self.e13oi.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				- E123(self.e13oi.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E123(self.e1i.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				+ E123(self.e1i.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				- E123(self.e1i.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				+ E123(self.e1i.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				- E123(self.e1i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E123(self.e1o.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				+ E123(self.e1o.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				- E123(self.e1o.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				- E123(self.e1o.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E123(self.e23.0 * self.e23.0 * rhs.e123.0)
+				- E123(self.e23.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				- E123(self.e23.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				- E123(self.e23.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				- E123(self.e23.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				- E123(self.e23.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e23oi.0 * self.e23oi.0 * rhs.e123.0)
+				+ E123(self.e23oi.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				- E123(self.e23oi.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				+ E123(self.e23oi.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				- E123(self.e23oi.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				+ E123(self.e23oi.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E123(self.e2i.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				+ E123(self.e2i.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e2i.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				+ E123(self.e2i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E123(self.e2o.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				+ E123(self.e2o.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				+ E123(self.e2o.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E123(self.e3i.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				+ E123(self.e3i.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				- E123(self.e3i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E123(self.e3o.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				- E123(self.e3o.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E123(self.eoi.0 * self.eoi.0 * rhs.e123.0)
+				+ E123(self.s.0 * self.s.0 * rhs.e123.0),
+			e12o: E12o(self.e12.0 * self.e12.0 * rhs.e12o.0)
+				- E12o(self.e12.0 * self.e123o.0 * rhs.e123.0) * 2.0
+				- E12o(self.e12.0 * self.e12oi.0 * rhs.e12o.0) * 2.0
+				+ E12o(self.e12.0 * self.e13.0 * rhs.e13o.0) * 2.0
+				- E12o(self.e12.0 * self.e13oi.0 * rhs.e13o.0) * 2.0
+				+ E12o(self.e12.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				+ E12o(self.e12.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e12.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				+ E12o(self.e12.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				+ E12o(self.e12.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				+ E12o(self.e123o.0 * self.e123o.0 * rhs.e12i.0) * 2.0
+				+ E12o(self.e123o.0 * self.e12oi.0 * rhs.e123.0) * 2.0
+				+ E12o(self.e123o.0 * self.e13.0 * rhs.e1oi.0) * 2.0
+				- E12o(self.e123o.0 * self.e13oi.0 * rhs.e1oi.0) * 2.0
+				- E12o(self.e123o.0 * self.e1o.0 * rhs.e13i.0) * 4.0
+				+ E12o(self.e123o.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e123o.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e123o.0 * self.e2o.0 * rhs.e23i.0) * 4.0
+				+ E12o(self.e123o.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				- E12o(self.e123o.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E12o(self.e12oi.0 * self.e12oi.0 * rhs.e12o.0)
+				- E12o(self.e12oi.0 * self.e13.0 * rhs.e13o.0) * 2.0
+				+ E12o(self.e12oi.0 * self.e13oi.0 * rhs.e13o.0) * 2.0
+				- E12o(self.e12oi.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				- E12o(self.e12oi.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				+ E12o(self.e12oi.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e12oi.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e12oi.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				- E12o(self.e13.0 * self.e13.0 * rhs.e12o.0)
+				+ E12o(self.e13.0 * self.e13oi.0 * rhs.e12o.0) * 2.0
+				+ E12o(self.e13.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				+ E12o(self.e13.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				- E12o(self.e13.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				+ E12o(self.e13.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e13.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e13oi.0 * self.e13oi.0 * rhs.e12o.0)
+				- E12o(self.e13oi.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				- E12o(self.e13oi.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				+ E12o(self.e13oi.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e13oi.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				+ E12o(self.e13oi.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e1o.0 * self.e1o.0 * rhs.e12i.0) * 2.0
+				- E12o(self.e1o.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E12o(self.e1o.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				+ E12o(self.e1o.0 * self.e3o.0 * rhs.e23i.0) * 4.0
+				+ E12o(self.e1o.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e1o.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e23.0 * self.e23.0 * rhs.e12o.0)
+				+ E12o(self.e23.0 * self.e23oi.0 * rhs.e12o.0) * 2.0
+				+ E12o(self.e23.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				+ E12o(self.e23.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				- E12o(self.e23.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				+ E12o(self.e23.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E12o(self.e23oi.0 * self.e23oi.0 * rhs.e12o.0)
+				- E12o(self.e23oi.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				- E12o(self.e23oi.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				+ E12o(self.e23oi.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				- E12o(self.e23oi.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E12o(self.e2o.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				- E12o(self.e2o.0 * self.e3o.0 * rhs.e13i.0) * 4.0
+				- E12o(self.e2o.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				+ E12o(self.e2o.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E12o(self.e3o.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				+ E12o(self.e3o.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E12o(self.e3o.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E12o(self.eoi.0 * self.eoi.0 * rhs.e12o.0)
+				- E12o(self.eoi.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E12o(self.s.0 * self.s.0 * rhs.e12o.0),
+			e12i: E12i(self.e12.0 * self.e12.0 * rhs.e12i.0) - E12i(self.e12.0 * self.e123i.0 * rhs.e123.0) * 2.0
+				+ E12i(self.e12.0 * self.e12oi.0 * rhs.e12i.0) * 2.0
+				+ E12i(self.e12.0 * self.e13.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e12.0 * self.e13oi.0 * rhs.e13i.0) * 2.0
+				- E12i(self.e12.0 * self.e1i.0 * rhs.e1oi.0) * 2.0
+				+ E12i(self.e12.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e12.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e12.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e12.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				+ E12i(self.e123i.0 * self.e123i.0 * rhs.e12o.0) * 2.0
+				- E12i(self.e123i.0 * self.e12oi.0 * rhs.e123.0) * 2.0
+				- E12i(self.e123i.0 * self.e13.0 * rhs.e1oi.0) * 2.0
+				- E12i(self.e123i.0 * self.e13oi.0 * rhs.e1oi.0) * 2.0
+				- E12i(self.e123i.0 * self.e1i.0 * rhs.e13o.0) * 4.0
+				- E12i(self.e123i.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e123i.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e123i.0 * self.e2i.0 * rhs.e23o.0) * 4.0
+				+ E12i(self.e123i.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				+ E12i(self.e123i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E12i(self.e12oi.0 * self.e12oi.0 * rhs.e12i.0)
+				+ E12i(self.e12oi.0 * self.e13.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e12oi.0 * self.e13oi.0 * rhs.e13i.0) * 2.0
+				- E12i(self.e12oi.0 * self.e1i.0 * rhs.e1oi.0) * 2.0
+				+ E12i(self.e12oi.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e12oi.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e12oi.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e12oi.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				- E12i(self.e13.0 * self.e13.0 * rhs.e12i.0)
+				- E12i(self.e13.0 * self.e13oi.0 * rhs.e12i.0) * 2.0
+				+ E12i(self.e13.0 * self.e1i.0 * rhs.e123.0) * 2.0
+				- E12i(self.e13.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				+ E12i(self.e13.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e13.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e13.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e13oi.0 * self.e13oi.0 * rhs.e12i.0)
+				+ E12i(self.e13oi.0 * self.e1i.0 * rhs.e123.0) * 2.0
+				- E12i(self.e13oi.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				+ E12i(self.e13oi.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e13oi.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e13oi.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e1i.0 * self.e1i.0 * rhs.e12o.0) * 2.0
+				+ E12i(self.e1i.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E12i(self.e1i.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				+ E12i(self.e1i.0 * self.e3i.0 * rhs.e23o.0) * 4.0
+				+ E12i(self.e1i.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				+ E12i(self.e1i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e23.0 * self.e23.0 * rhs.e12i.0)
+				- E12i(self.e23.0 * self.e23oi.0 * rhs.e12i.0) * 2.0
+				+ E12i(self.e23.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				- E12i(self.e23.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				+ E12i(self.e23.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e23.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E12i(self.e23oi.0 * self.e23oi.0 * rhs.e12i.0)
+				+ E12i(self.e23oi.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				- E12i(self.e23oi.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				+ E12i(self.e23oi.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e23oi.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E12i(self.e2i.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				- E12i(self.e2i.0 * self.e3i.0 * rhs.e13o.0) * 4.0
+				- E12i(self.e2i.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				- E12i(self.e2i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E12i(self.e3i.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				- E12i(self.e3i.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E12i(self.e3i.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E12i(self.eoi.0 * self.eoi.0 * rhs.e12i.0)
+				+ E12i(self.eoi.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E12i(self.s.0 * self.s.0 * rhs.e12i.0),
+			e13o: -E13o(self.e12.0 * self.e12.0 * rhs.e13o.0) - E13o(self.e12.0 * self.e123o.0 * rhs.e1oi.0) * 2.0
+				+ E13o(self.e12.0 * self.e12oi.0 * rhs.e13o.0) * 2.0
+				+ E13o(self.e12.0 * self.e13.0 * rhs.e12o.0) * 2.0
+				- E13o(self.e12.0 * self.e13oi.0 * rhs.e12o.0) * 2.0
+				- E13o(self.e12.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				- E13o(self.e12.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				+ E13o(self.e12.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				- E13o(self.e12.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				+ E13o(self.e12.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E13o(self.e123o.0 * self.e123o.0 * rhs.e13i.0) * 2.0
+				+ E13o(self.e123o.0 * self.e12oi.0 * rhs.e1oi.0) * 2.0
+				- E13o(self.e123o.0 * self.e13.0 * rhs.e123.0) * 2.0
+				+ E13o(self.e123o.0 * self.e13oi.0 * rhs.e123.0) * 2.0
+				+ E13o(self.e123o.0 * self.e1o.0 * rhs.e12i.0) * 4.0
+				+ E13o(self.e123o.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e123o.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e123o.0 * self.e3o.0 * rhs.e23i.0) * 4.0
+				- E13o(self.e123o.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				+ E13o(self.e123o.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E13o(self.e12oi.0 * self.e12oi.0 * rhs.e13o.0)
+				- E13o(self.e12oi.0 * self.e13.0 * rhs.e12o.0) * 2.0
+				+ E13o(self.e12oi.0 * self.e13oi.0 * rhs.e12o.0) * 2.0
+				+ E13o(self.e12oi.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				+ E13o(self.e12oi.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e12oi.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				+ E13o(self.e12oi.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e12oi.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E13o(self.e13.0 * self.e13.0 * rhs.e13o.0)
+				- E13o(self.e13.0 * self.e13oi.0 * rhs.e13o.0) * 2.0
+				+ E13o(self.e13.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				+ E13o(self.e13.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e13.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				+ E13o(self.e13.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				+ E13o(self.e13.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				+ E13o(self.e13oi.0 * self.e13oi.0 * rhs.e13o.0)
+				- E13o(self.e13oi.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				- E13o(self.e13oi.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				+ E13o(self.e13oi.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e13oi.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E13o(self.e13oi.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e1o.0 * self.e1o.0 * rhs.e13i.0) * 2.0
+				+ E13o(self.e1o.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				- E13o(self.e1o.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				- E13o(self.e1o.0 * self.e2o.0 * rhs.e23i.0) * 4.0
+				+ E13o(self.e1o.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e1o.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e23.0 * self.e23.0 * rhs.e13o.0)
+				+ E13o(self.e23.0 * self.e23oi.0 * rhs.e13o.0) * 2.0
+				- E13o(self.e23.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				+ E13o(self.e23.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				+ E13o(self.e23.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				- E13o(self.e23.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E13o(self.e23oi.0 * self.e23oi.0 * rhs.e13o.0)
+				+ E13o(self.e23oi.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				- E13o(self.e23oi.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				- E13o(self.e23oi.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				+ E13o(self.e23oi.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E13o(self.e2o.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				- E13o(self.e2o.0 * self.e3o.0 * rhs.e12i.0) * 4.0
+				- E13o(self.e2o.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				+ E13o(self.e2o.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E13o(self.e3o.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				- E13o(self.e3o.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				+ E13o(self.e3o.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E13o(self.eoi.0 * self.eoi.0 * rhs.e13o.0)
+				- E13o(self.eoi.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E13o(self.s.0 * self.s.0 * rhs.e13o.0),
+			e13i: -E13i(self.e12.0 * self.e12.0 * rhs.e13i.0) + E13i(self.e12.0 * self.e123i.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.e12.0 * self.e12oi.0 * rhs.e13i.0) * 2.0
+				+ E13i(self.e12.0 * self.e13.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e12.0 * self.e13oi.0 * rhs.e12i.0) * 2.0
+				- E13i(self.e12.0 * self.e1i.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e12.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e12.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				+ E13i(self.e12.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e12.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e123i.0 * self.e123i.0 * rhs.e13o.0) * 2.0
+				+ E13i(self.e123i.0 * self.e12oi.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.e123i.0 * self.e13.0 * rhs.e123.0) * 2.0
+				- E13i(self.e123i.0 * self.e13oi.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e123i.0 * self.e1i.0 * rhs.e12o.0) * 4.0
+				- E13i(self.e123i.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e123i.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e123i.0 * self.e3i.0 * rhs.e23o.0) * 4.0
+				- E13i(self.e123i.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				- E13i(self.e123i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E13i(self.e12oi.0 * self.e12oi.0 * rhs.e13i.0)
+				+ E13i(self.e12oi.0 * self.e13.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e12oi.0 * self.e13oi.0 * rhs.e12i.0) * 2.0
+				- E13i(self.e12oi.0 * self.e1i.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e12oi.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e12oi.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				+ E13i(self.e12oi.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e12oi.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e13.0 * self.e13.0 * rhs.e13i.0)
+				+ E13i(self.e13.0 * self.e13oi.0 * rhs.e13i.0) * 2.0
+				- E13i(self.e13.0 * self.e1i.0 * rhs.e1oi.0) * 2.0
+				+ E13i(self.e13.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e13.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e13.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				- E13i(self.e13.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				+ E13i(self.e13oi.0 * self.e13oi.0 * rhs.e13i.0)
+				- E13i(self.e13oi.0 * self.e1i.0 * rhs.e1oi.0) * 2.0
+				+ E13i(self.e13oi.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e13oi.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e13oi.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				- E13i(self.e13oi.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e1i.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				- E13i(self.e1i.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				- E13i(self.e1i.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				- E13i(self.e1i.0 * self.e2i.0 * rhs.e23o.0) * 4.0
+				+ E13i(self.e1i.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				+ E13i(self.e1i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e23.0 * self.e23.0 * rhs.e13i.0)
+				- E13i(self.e23.0 * self.e23oi.0 * rhs.e13i.0) * 2.0
+				+ E13i(self.e23.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				+ E13i(self.e23.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				- E13i(self.e23.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				- E13i(self.e23.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E13i(self.e23oi.0 * self.e23oi.0 * rhs.e13i.0)
+				+ E13i(self.e23oi.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				+ E13i(self.e23oi.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				- E13i(self.e23oi.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				- E13i(self.e23oi.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e2i.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				- E13i(self.e2i.0 * self.e3i.0 * rhs.e12o.0) * 4.0
+				+ E13i(self.e2i.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e2i.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E13i(self.e3i.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				- E13i(self.e3i.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.e3i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E13i(self.eoi.0 * self.eoi.0 * rhs.e13i.0)
+				+ E13i(self.eoi.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E13i(self.s.0 * self.s.0 * rhs.e13i.0),
+			e1oi: -E1oi(self.e12.0 * self.e12.0 * rhs.e1oi.0) - E1oi(self.e12.0 * self.e123i.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e12.0 * self.e123o.0 * rhs.e13i.0) * 2.0
+				+ E1oi(self.e12.0 * self.e13oi.0 * rhs.e123.0) * 2.0
+				- E1oi(self.e12.0 * self.e1i.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e12.0 * self.e1o.0 * rhs.e12i.0) * 2.0
+				+ E1oi(self.e12.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e12.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				- E1oi(self.e12.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				+ E1oi(self.e12.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e123i.0 * self.e123o.0 * rhs.e1oi.0) * 2.0
+				+ E1oi(self.e123i.0 * self.e12oi.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e123i.0 * self.e13.0 * rhs.e12o.0) * 2.0
+				- E1oi(self.e123i.0 * self.e13oi.0 * rhs.e12o.0) * 2.0
+				- E1oi(self.e123i.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				- E1oi(self.e123i.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e123i.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e123i.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				+ E1oi(self.e123i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E1oi(self.e123o.0 * self.e12oi.0 * rhs.e13i.0) * 2.0
+				- E1oi(self.e123o.0 * self.e13.0 * rhs.e12i.0) * 2.0
+				- E1oi(self.e123o.0 * self.e13oi.0 * rhs.e12i.0) * 2.0
+				+ E1oi(self.e123o.0 * self.e1i.0 * rhs.e123.0) * 2.0
+				- E1oi(self.e123o.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e123o.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e123o.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				- E1oi(self.e123o.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E1oi(self.e12oi.0 * self.e12oi.0 * rhs.e1oi.0)
+				- E1oi(self.e12oi.0 * self.e13.0 * rhs.e123.0) * 2.0
+				+ E1oi(self.e12oi.0 * self.e1i.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e12oi.0 * self.e1o.0 * rhs.e12i.0) * 2.0
+				- E1oi(self.e12oi.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				- E1oi(self.e12oi.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				- E1oi(self.e12oi.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				- E1oi(self.e12oi.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e13.0 * self.e13.0 * rhs.e1oi.0)
+				- E1oi(self.e13.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e13.0 * self.e1o.0 * rhs.e13i.0) * 2.0
+				- E1oi(self.e13.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e13.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E1oi(self.e13.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				+ E1oi(self.e13.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e13oi.0 * self.e13oi.0 * rhs.e1oi.0)
+				+ E1oi(self.e13oi.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e13oi.0 * self.e1o.0 * rhs.e13i.0) * 2.0
+				+ E1oi(self.e13oi.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e13oi.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E1oi(self.e13oi.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				- E1oi(self.e13oi.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				- E1oi(self.e1i.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				- E1oi(self.e1i.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				+ E1oi(self.e1i.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				- E1oi(self.e1i.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e1i.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e1o.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E1oi(self.e1o.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				- E1oi(self.e1o.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e1o.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e23.0 * self.e23.0 * rhs.e1oi.0)
+				+ E1oi(self.e23.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				- E1oi(self.e23.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				- E1oi(self.e23.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e23.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				+ E1oi(self.e23.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E1oi(self.e23oi.0 * self.e23oi.0 * rhs.e1oi.0)
+				- E1oi(self.e23oi.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				- E1oi(self.e23oi.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				+ E1oi(self.e23oi.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e23oi.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				- E1oi(self.e23oi.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E1oi(self.e2i.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				- E1oi(self.e2i.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				- E1oi(self.e2i.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e2i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e2o.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				- E1oi(self.e2o.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				- E1oi(self.e2o.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E1oi(self.e3i.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				- E1oi(self.e3i.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e3i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E1oi(self.e3o.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				- E1oi(self.e3o.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E1oi(self.eoi.0 * self.eoi.0 * rhs.e1oi.0)
+				+ E1oi(self.s.0 * self.s.0 * rhs.e1oi.0),
+			e23o: -E23o(self.e12.0 * self.e12.0 * rhs.e23o.0) - E23o(self.e12.0 * self.e123o.0 * rhs.e2oi.0) * 2.0
+				+ E23o(self.e12.0 * self.e12oi.0 * rhs.e23o.0) * 2.0
+				+ E23o(self.e12.0 * self.e1o.0 * rhs.e3oi.0) * 2.0
+				+ E23o(self.e12.0 * self.e23.0 * rhs.e12o.0) * 2.0
+				- E23o(self.e12.0 * self.e23oi.0 * rhs.e12o.0) * 2.0
+				- E23o(self.e12.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				- E23o(self.e12.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				+ E23o(self.e12.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e12.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E23o(self.e123o.0 * self.e123o.0 * rhs.e23i.0) * 2.0
+				+ E23o(self.e123o.0 * self.e12oi.0 * rhs.e2oi.0) * 2.0
+				- E23o(self.e123o.0 * self.e13.0 * rhs.e3oi.0) * 2.0
+				+ E23o(self.e123o.0 * self.e13oi.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e123o.0 * self.e23.0 * rhs.e123.0) * 2.0
+				+ E23o(self.e123o.0 * self.e23oi.0 * rhs.e123.0) * 2.0
+				+ E23o(self.e123o.0 * self.e2o.0 * rhs.e12i.0) * 4.0
+				+ E23o(self.e123o.0 * self.e3o.0 * rhs.e13i.0) * 4.0
+				+ E23o(self.e123o.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				- E23o(self.e123o.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E23o(self.e12oi.0 * self.e12oi.0 * rhs.e23o.0)
+				- E23o(self.e12oi.0 * self.e1o.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e12oi.0 * self.e23.0 * rhs.e12o.0) * 2.0
+				+ E23o(self.e12oi.0 * self.e23oi.0 * rhs.e12o.0) * 2.0
+				+ E23o(self.e12oi.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				+ E23o(self.e12oi.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				- E23o(self.e12oi.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				+ E23o(self.e12oi.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e13.0 * self.e13.0 * rhs.e23o.0)
+				+ E23o(self.e13.0 * self.e13oi.0 * rhs.e23o.0) * 2.0
+				- E23o(self.e13.0 * self.e1o.0 * rhs.e2oi.0) * 2.0
+				+ E23o(self.e13.0 * self.e23.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e13.0 * self.e23oi.0 * rhs.e13o.0) * 2.0
+				+ E23o(self.e13.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				- E23o(self.e13.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				- E23o(self.e13.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				+ E23o(self.e13.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E23o(self.e13oi.0 * self.e13oi.0 * rhs.e23o.0)
+				+ E23o(self.e13oi.0 * self.e1o.0 * rhs.e2oi.0) * 2.0
+				- E23o(self.e13oi.0 * self.e23.0 * rhs.e13o.0) * 2.0
+				+ E23o(self.e13oi.0 * self.e23oi.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e13oi.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				+ E23o(self.e13oi.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				+ E23o(self.e13oi.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				- E23o(self.e13oi.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E23o(self.e1o.0 * self.e1o.0 * rhs.e23i.0) * 2.0
+				+ E23o(self.e1o.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				- E23o(self.e1o.0 * self.e23oi.0 * rhs.e1oi.0) * 2.0
+				- E23o(self.e1o.0 * self.e2o.0 * rhs.e13i.0) * 4.0
+				+ E23o(self.e1o.0 * self.e3o.0 * rhs.e12i.0) * 4.0
+				+ E23o(self.e1o.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E23o(self.e1o.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E23o(self.e23.0 * self.e23.0 * rhs.e23o.0)
+				- E23o(self.e23.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				+ E23o(self.e23.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				+ E23o(self.e23.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				+ E23o(self.e23oi.0 * self.e23oi.0 * rhs.e23o.0)
+				- E23o(self.e23oi.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E23o(self.e23oi.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e2o.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				+ E23o(self.e2o.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e2o.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e3o.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				- E23o(self.e3o.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				+ E23o(self.e3o.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E23o(self.eoi.0 * self.eoi.0 * rhs.e23o.0)
+				- E23o(self.eoi.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E23o(self.s.0 * self.s.0 * rhs.e23o.0),
+			e23i: -E23i(self.e12.0 * self.e12.0 * rhs.e23i.0) + E23i(self.e12.0 * self.e123i.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.e12.0 * self.e12oi.0 * rhs.e23i.0) * 2.0
+				- E23i(self.e12.0 * self.e1i.0 * rhs.e3oi.0) * 2.0
+				+ E23i(self.e12.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e12.0 * self.e23oi.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e12.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e12.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e12.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e12.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e123i.0 * self.e123i.0 * rhs.e23o.0) * 2.0
+				+ E23i(self.e123i.0 * self.e12oi.0 * rhs.e2oi.0) * 2.0
+				+ E23i(self.e123i.0 * self.e13.0 * rhs.e3oi.0) * 2.0
+				+ E23i(self.e123i.0 * self.e13oi.0 * rhs.e3oi.0) * 2.0
+				- E23i(self.e123i.0 * self.e23.0 * rhs.e123.0) * 2.0
+				- E23i(self.e123i.0 * self.e23oi.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e123i.0 * self.e2i.0 * rhs.e12o.0) * 4.0
+				+ E23i(self.e123i.0 * self.e3i.0 * rhs.e13o.0) * 4.0
+				+ E23i(self.e123i.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				+ E23i(self.e123i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e12oi.0 * self.e12oi.0 * rhs.e23i.0)
+				- E23i(self.e12oi.0 * self.e1i.0 * rhs.e3oi.0) * 2.0
+				+ E23i(self.e12oi.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e12oi.0 * self.e23oi.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e12oi.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e12oi.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e12oi.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e12oi.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e13.0 * self.e13.0 * rhs.e23i.0)
+				- E23i(self.e13.0 * self.e13oi.0 * rhs.e23i.0) * 2.0
+				+ E23i(self.e13.0 * self.e1i.0 * rhs.e2oi.0) * 2.0
+				+ E23i(self.e13.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13.0 * self.e23oi.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e13.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e13.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e13.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e13.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e13oi.0 * self.e13oi.0 * rhs.e23i.0)
+				+ E23i(self.e13oi.0 * self.e1i.0 * rhs.e2oi.0) * 2.0
+				+ E23i(self.e13oi.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13oi.0 * self.e23oi.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e13oi.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e13oi.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e13oi.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e13oi.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e1i.0 * self.e1i.0 * rhs.e23o.0) * 2.0
+				- E23i(self.e1i.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e1i.0 * self.e23oi.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e1i.0 * self.e2i.0 * rhs.e13o.0) * 4.0
+				+ E23i(self.e1i.0 * self.e3i.0 * rhs.e12o.0) * 4.0
+				- E23i(self.e1i.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E23i(self.e1i.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e23.0 * self.e23.0 * rhs.e23i.0)
+				+ E23i(self.e23.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				- E23i(self.e23.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.e23.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				+ E23i(self.e23oi.0 * self.e23oi.0 * rhs.e23i.0)
+				- E23i(self.e23oi.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.e23oi.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				- E23i(self.e2i.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E23i(self.e2i.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				+ E23i(self.e2i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E23i(self.e3i.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				- E23i(self.e3i.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.e3i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E23i(self.eoi.0 * self.eoi.0 * rhs.e23i.0)
+				+ E23i(self.eoi.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E23i(self.s.0 * self.s.0 * rhs.e23i.0),
+			e2oi: -E2oi(self.e12.0 * self.e12.0 * rhs.e2oi.0) - E2oi(self.e12.0 * self.e123i.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e12.0 * self.e123o.0 * rhs.e23i.0) * 2.0
+				- E2oi(self.e12.0 * self.e13.0 * rhs.e3oi.0) * 2.0
+				+ E2oi(self.e12.0 * self.e23oi.0 * rhs.e123.0) * 2.0
+				- E2oi(self.e12.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				+ E2oi(self.e12.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				- E2oi(self.e12.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e12.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				- E2oi(self.e12.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E2oi(self.e123i.0 * self.e123o.0 * rhs.e2oi.0) * 2.0
+				+ E2oi(self.e123i.0 * self.e12oi.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e123i.0 * self.e1o.0 * rhs.e3oi.0) * 2.0
+				+ E2oi(self.e123i.0 * self.e23.0 * rhs.e12o.0) * 2.0
+				- E2oi(self.e123i.0 * self.e23oi.0 * rhs.e12o.0) * 2.0
+				- E2oi(self.e123i.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				- E2oi(self.e123i.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e123i.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				- E2oi(self.e123i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e123o.0 * self.e12oi.0 * rhs.e23i.0) * 2.0
+				+ E2oi(self.e123o.0 * self.e1i.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e123o.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				- E2oi(self.e123o.0 * self.e23oi.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e123o.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				- E2oi(self.e123o.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e123o.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				+ E2oi(self.e123o.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.e12oi.0 * rhs.e2oi.0)
+				+ E2oi(self.e12oi.0 * self.e13oi.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e12oi.0 * self.e23.0 * rhs.e123.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e13.0 * self.e13.0 * rhs.e2oi.0)
+				+ E2oi(self.e13.0 * self.e1i.0 * rhs.e23o.0) * 2.0
+				- E2oi(self.e13.0 * self.e1o.0 * rhs.e23i.0) * 2.0
+				- E2oi(self.e13.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				- E2oi(self.e13.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e13.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				+ E2oi(self.e13.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				- E2oi(self.e13.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				- E2oi(self.e13.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E2oi(self.e13oi.0 * self.e13oi.0 * rhs.e2oi.0)
+				- E2oi(self.e13oi.0 * self.e1i.0 * rhs.e23o.0) * 2.0
+				- E2oi(self.e13oi.0 * self.e1o.0 * rhs.e23i.0) * 2.0
+				+ E2oi(self.e13oi.0 * self.e23oi.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e13oi.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e13oi.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				- E2oi(self.e13oi.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				- E2oi(self.e13oi.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e13oi.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E2oi(self.e1i.0 * self.e1o.0 * rhs.e2oi.0) * 2.0
+				- E2oi(self.e1i.0 * self.e23.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e1i.0 * self.e23oi.0 * rhs.e13o.0) * 2.0
+				- E2oi(self.e1i.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e1i.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				+ E2oi(self.e1i.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				- E2oi(self.e1i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E2oi(self.e1o.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				+ E2oi(self.e1o.0 * self.e23oi.0 * rhs.e13i.0) * 2.0
+				- E2oi(self.e1o.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				- E2oi(self.e1o.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				+ E2oi(self.e1o.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e1o.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E2oi(self.e23.0 * self.e23.0 * rhs.e2oi.0)
+				- E2oi(self.e23.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e23.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				+ E2oi(self.e23.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E2oi(self.e23oi.0 * self.e23oi.0 * rhs.e2oi.0)
+				+ E2oi(self.e23oi.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e23oi.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				- E2oi(self.e23oi.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e2i.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E2oi(self.e2i.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e2o.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				+ E2oi(self.e3i.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				- E2oi(self.e3i.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e3i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E2oi(self.e3o.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				- E2oi(self.e3o.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E2oi(self.eoi.0 * self.eoi.0 * rhs.e2oi.0)
+				+ E2oi(self.s.0 * self.s.0 * rhs.e2oi.0),
+			e3oi: E3oi(self.e12.0 * self.e12.0 * rhs.e3oi.0)
+				- E3oi(self.e12.0 * self.e13.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.e12.0 * self.e1i.0 * rhs.e23o.0) * 2.0
+				+ E3oi(self.e12.0 * self.e1o.0 * rhs.e23i.0) * 2.0
+				+ E3oi(self.e12.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e12.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				- E3oi(self.e12.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				- E3oi(self.e12.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				+ E3oi(self.e12.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				+ E3oi(self.e12.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E3oi(self.e123i.0 * self.e123o.0 * rhs.e3oi.0) * 2.0
+				- E3oi(self.e123i.0 * self.e13.0 * rhs.e23o.0) * 2.0
+				+ E3oi(self.e123i.0 * self.e13oi.0 * rhs.e23o.0) * 2.0
+				- E3oi(self.e123i.0 * self.e1o.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e123i.0 * self.e23.0 * rhs.e13o.0) * 2.0
+				- E3oi(self.e123i.0 * self.e23oi.0 * rhs.e13o.0) * 2.0
+				+ E3oi(self.e123i.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e123i.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				- E3oi(self.e123i.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				+ E3oi(self.e123i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E3oi(self.e123o.0 * self.e13.0 * rhs.e23i.0) * 2.0
+				+ E3oi(self.e123o.0 * self.e13oi.0 * rhs.e23i.0) * 2.0
+				- E3oi(self.e123o.0 * self.e1i.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.e123o.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				- E3oi(self.e123o.0 * self.e23oi.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e123o.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e123o.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				- E3oi(self.e123o.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				- E3oi(self.e123o.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E3oi(self.e12oi.0 * self.e12oi.0 * rhs.e3oi.0)
+				+ E3oi(self.e12oi.0 * self.e13oi.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.e1i.0 * rhs.e23o.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.e1o.0 * rhs.e23i.0) * 2.0
+				- E3oi(self.e12oi.0 * self.e23oi.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e12oi.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				- E3oi(self.e12oi.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				- E3oi(self.e12oi.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E3oi(self.e13.0 * self.e13.0 * rhs.e3oi.0)
+				+ E3oi(self.e13.0 * self.e23oi.0 * rhs.e123.0) * 2.0
+				- E3oi(self.e13.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				+ E3oi(self.e13.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				- E3oi(self.e13.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				+ E3oi(self.e13.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				- E3oi(self.e13.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.e13oi.0 * rhs.e3oi.0)
+				- E3oi(self.e13oi.0 * self.e23.0 * rhs.e123.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e1i.0 * self.e1o.0 * rhs.e3oi.0) * 2.0
+				+ E3oi(self.e1i.0 * self.e23.0 * rhs.e12o.0) * 2.0
+				- E3oi(self.e1i.0 * self.e23oi.0 * rhs.e12o.0) * 2.0
+				- E3oi(self.e1i.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				- E3oi(self.e1i.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e1i.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				- E3oi(self.e1i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E3oi(self.e1o.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				- E3oi(self.e1o.0 * self.e23oi.0 * rhs.e12i.0) * 2.0
+				+ E3oi(self.e1o.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				- E3oi(self.e1o.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e1o.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e1o.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E3oi(self.e23.0 * self.e23.0 * rhs.e3oi.0)
+				- E3oi(self.e23.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				+ E3oi(self.e23.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				- E3oi(self.e23.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e23oi.0 * self.e23oi.0 * rhs.e3oi.0)
+				+ E3oi(self.e23oi.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				+ E3oi(self.e23oi.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				+ E3oi(self.e23oi.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e2i.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				- E3oi(self.e2i.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e2i.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				- E3oi(self.e2i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E3oi(self.e2o.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e2o.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				+ E3oi(self.e2o.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E3oi(self.e3i.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				- E3oi(self.eoi.0 * self.eoi.0 * rhs.e3oi.0)
+				+ E3oi(self.s.0 * self.s.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Versor.anti_sandwich(Circle) -> Circle
+impl AntiSandwich<Circle> for Versor {
+	fn anti_sandwich(self, rhs: Circle) -> Circle {
+		Circle {
+			e123: -E123(self.e12.0 * self.e12.0 * rhs.e123.0)
+				- E123(self.e12.0 * self.e123i.0 * rhs.e12o.0) * 2.0
+				- E123(self.e12.0 * self.e123o.0 * rhs.e12i.0) * 2.0
+				+ E123(self.e12.0 * self.e13oi.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e12.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				- E123(self.e12.0 * self.e1o.0 * rhs.e13i.0) * 2.0
+				+ E123(self.e12.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				- E123(self.e12.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				- E123(self.e12.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				+ E123(self.e12.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				- E123(self.e123i.0 * self.e123o.0 * rhs.e123.0) * 2.0
+				- E123(self.e123i.0 * self.e12oi.0 * rhs.e12o.0) * 2.0
+				- E123(self.e123i.0 * self.e13.0 * rhs.e13o.0) * 2.0
+				- E123(self.e123i.0 * self.e13oi.0 * rhs.e13o.0) * 2.0
+				- E123(self.e123i.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e123i.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				- E123(self.e123i.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				- E123(self.e123i.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E123(self.e123i.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				+ E123(self.e123o.0 * self.e12oi.0 * rhs.e12i.0) * 2.0
+				- E123(self.e123o.0 * self.e13.0 * rhs.e13i.0) * 2.0
+				+ E123(self.e123o.0 * self.e13oi.0 * rhs.e13i.0) * 2.0
+				+ E123(self.e123o.0 * self.e1i.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e123o.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E123(self.e123o.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				+ E123(self.e123o.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				+ E123(self.e123o.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				+ E123(self.e12oi.0 * self.e12oi.0 * rhs.e123.0)
+				- E123(self.e12oi.0 * self.e13.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e12oi.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				+ E123(self.e12oi.0 * self.e1o.0 * rhs.e13i.0) * 2.0
+				- E123(self.e12oi.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				- E123(self.e12oi.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E123(self.e12oi.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				- E123(self.e12oi.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E123(self.e13.0 * self.e13.0 * rhs.e123.0)
+				+ E123(self.e13.0 * self.e1i.0 * rhs.e12o.0) * 2.0
+				+ E123(self.e13.0 * self.e1o.0 * rhs.e12i.0) * 2.0
+				+ E123(self.e13.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				- E123(self.e13.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				- E123(self.e13.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				- E123(self.e13.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				+ E123(self.e13oi.0 * self.e13oi.0 * rhs.e123.0)
+				+ E123(self.e13oi.0 * self.e1i.0 * rhs.e12o.0) * 2.0
+				- E123(self.e13oi.0 * self.e1o.0 * rhs.e12i.0) * 2.0
+				- E123(self.e13oi.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				- E123(self.e13oi.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				+ E123(self.e13oi.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				+ E123(self.e13oi.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E123(self.e1i.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				- E123(self.e1i.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				+ E123(self.e1i.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				- E123(self.e1i.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				- E123(self.e1i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E123(self.e1o.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				- E123(self.e1o.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				+ E123(self.e1o.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				- E123(self.e1o.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E123(self.e23.0 * self.e23.0 * rhs.e123.0)
+				+ E123(self.e23.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				+ E123(self.e23.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				+ E123(self.e23.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				+ E123(self.e23.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				+ E123(self.e23.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				+ E123(self.e23oi.0 * self.e23oi.0 * rhs.e123.0)
+				+ E123(self.e23oi.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				- E123(self.e23oi.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				+ E123(self.e23oi.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				- E123(self.e23oi.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				- E123(self.e23oi.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e2i.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				- E123(self.e2i.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				+ E123(self.e2i.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				+ E123(self.e2i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E123(self.e2o.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				- E123(self.e2o.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				+ E123(self.e2o.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E123(self.e3i.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				- E123(self.e3i.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				- E123(self.e3i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E123(self.e3o.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				- E123(self.e3o.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E123(self.eoi.0 * self.eoi.0 * rhs.e123.0)
+				- E123(self.s.0 * self.s.0 * rhs.e123.0),
+			e12o: -E12o(self.e12.0 * self.e12.0 * rhs.e12o.0)
+				- E12o(self.e12.0 * self.e123o.0 * rhs.e123.0) * 2.0
+				- E12o(self.e12.0 * self.e12oi.0 * rhs.e12o.0) * 2.0
+				- E12o(self.e12.0 * self.e13.0 * rhs.e13o.0) * 2.0
+				- E12o(self.e12.0 * self.e13oi.0 * rhs.e13o.0) * 2.0
+				- E12o(self.e12.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				- E12o(self.e12.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e12.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e12.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e12.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				- E12o(self.e123o.0 * self.e123o.0 * rhs.e12i.0) * 2.0
+				- E12o(self.e123o.0 * self.e12oi.0 * rhs.e123.0) * 2.0
+				+ E12o(self.e123o.0 * self.e13.0 * rhs.e1oi.0) * 2.0
+				+ E12o(self.e123o.0 * self.e13oi.0 * rhs.e1oi.0) * 2.0
+				- E12o(self.e123o.0 * self.e1o.0 * rhs.e13i.0) * 4.0
+				+ E12o(self.e123o.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				+ E12o(self.e123o.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e123o.0 * self.e2o.0 * rhs.e23i.0) * 4.0
+				+ E12o(self.e123o.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				+ E12o(self.e123o.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E12o(self.e12oi.0 * self.e12oi.0 * rhs.e12o.0)
+				- E12o(self.e12oi.0 * self.e13.0 * rhs.e13o.0) * 2.0
+				- E12o(self.e12oi.0 * self.e13oi.0 * rhs.e13o.0) * 2.0
+				- E12o(self.e12oi.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				- E12o(self.e12oi.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e12oi.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e12oi.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e12oi.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				+ E12o(self.e13.0 * self.e13.0 * rhs.e12o.0)
+				+ E12o(self.e13.0 * self.e13oi.0 * rhs.e12o.0) * 2.0
+				- E12o(self.e13.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				- E12o(self.e13.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				+ E12o(self.e13.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e13.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e13.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E12o(self.e13oi.0 * self.e13oi.0 * rhs.e12o.0)
+				- E12o(self.e13oi.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				- E12o(self.e13oi.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				+ E12o(self.e13oi.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e13oi.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				- E12o(self.e13oi.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E12o(self.e1o.0 * self.e1o.0 * rhs.e12i.0) * 2.0
+				+ E12o(self.e1o.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E12o(self.e1o.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				- E12o(self.e1o.0 * self.e3o.0 * rhs.e23i.0) * 4.0
+				- E12o(self.e1o.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				- E12o(self.e1o.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E12o(self.e23.0 * self.e23.0 * rhs.e12o.0)
+				+ E12o(self.e23.0 * self.e23oi.0 * rhs.e12o.0) * 2.0
+				- E12o(self.e23.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				- E12o(self.e23.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				+ E12o(self.e23.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				+ E12o(self.e23.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E12o(self.e23oi.0 * self.e23oi.0 * rhs.e12o.0)
+				- E12o(self.e23oi.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				- E12o(self.e23oi.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				+ E12o(self.e23oi.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				+ E12o(self.e23oi.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E12o(self.e2o.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				+ E12o(self.e2o.0 * self.e3o.0 * rhs.e13i.0) * 4.0
+				+ E12o(self.e2o.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				+ E12o(self.e2o.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E12o(self.e3o.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				- E12o(self.e3o.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E12o(self.e3o.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E12o(self.eoi.0 * self.eoi.0 * rhs.e12o.0)
+				- E12o(self.eoi.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E12o(self.s.0 * self.s.0 * rhs.e12o.0),
+			e12i: -E12i(self.e12.0 * self.e12.0 * rhs.e12i.0) - E12i(self.e12.0 * self.e123i.0 * rhs.e123.0) * 2.0
+				+ E12i(self.e12.0 * self.e12oi.0 * rhs.e12i.0) * 2.0
+				- E12i(self.e12.0 * self.e13.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e12.0 * self.e13oi.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e12.0 * self.e1i.0 * rhs.e1oi.0) * 2.0
+				- E12i(self.e12.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e12.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e12.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				+ E12i(self.e12.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				- E12i(self.e123i.0 * self.e123i.0 * rhs.e12o.0) * 2.0
+				+ E12i(self.e123i.0 * self.e12oi.0 * rhs.e123.0) * 2.0
+				- E12i(self.e123i.0 * self.e13.0 * rhs.e1oi.0) * 2.0
+				+ E12i(self.e123i.0 * self.e13oi.0 * rhs.e1oi.0) * 2.0
+				- E12i(self.e123i.0 * self.e1i.0 * rhs.e13o.0) * 4.0
+				- E12i(self.e123i.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				+ E12i(self.e123i.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e123i.0 * self.e2i.0 * rhs.e23o.0) * 4.0
+				+ E12i(self.e123i.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				- E12i(self.e123i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E12i(self.e12oi.0 * self.e12oi.0 * rhs.e12i.0)
+				+ E12i(self.e12oi.0 * self.e13.0 * rhs.e13i.0) * 2.0
+				- E12i(self.e12oi.0 * self.e13oi.0 * rhs.e13i.0) * 2.0
+				- E12i(self.e12oi.0 * self.e1i.0 * rhs.e1oi.0) * 2.0
+				+ E12i(self.e12oi.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e12oi.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e12oi.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e12oi.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				+ E12i(self.e13.0 * self.e13.0 * rhs.e12i.0)
+				- E12i(self.e13.0 * self.e13oi.0 * rhs.e12i.0) * 2.0
+				- E12i(self.e13.0 * self.e1i.0 * rhs.e123.0) * 2.0
+				+ E12i(self.e13.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				- E12i(self.e13.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				+ E12i(self.e13.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				- E12i(self.e13.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e13oi.0 * self.e13oi.0 * rhs.e12i.0)
+				+ E12i(self.e13oi.0 * self.e1i.0 * rhs.e123.0) * 2.0
+				- E12i(self.e13oi.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				+ E12i(self.e13oi.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				- E12i(self.e13oi.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e13oi.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E12i(self.e1i.0 * self.e1i.0 * rhs.e12o.0) * 2.0
+				- E12i(self.e1i.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E12i(self.e1i.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				- E12i(self.e1i.0 * self.e3i.0 * rhs.e23o.0) * 4.0
+				- E12i(self.e1i.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				+ E12i(self.e1i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E12i(self.e23.0 * self.e23.0 * rhs.e12i.0)
+				- E12i(self.e23.0 * self.e23oi.0 * rhs.e12i.0) * 2.0
+				- E12i(self.e23.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				+ E12i(self.e23.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				- E12i(self.e23.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e23.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e23oi.0 * self.e23oi.0 * rhs.e12i.0)
+				+ E12i(self.e23oi.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				- E12i(self.e23oi.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				+ E12i(self.e23oi.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				- E12i(self.e23oi.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E12i(self.e2i.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				+ E12i(self.e2i.0 * self.e3i.0 * rhs.e13o.0) * 4.0
+				+ E12i(self.e2i.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				- E12i(self.e2i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E12i(self.e3i.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				+ E12i(self.e3i.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E12i(self.e3i.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E12i(self.eoi.0 * self.eoi.0 * rhs.e12i.0)
+				+ E12i(self.eoi.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E12i(self.s.0 * self.s.0 * rhs.e12i.0),
+			e13o: E13o(self.e12.0 * self.e12.0 * rhs.e13o.0) - E13o(self.e12.0 * self.e123o.0 * rhs.e1oi.0) * 2.0
+				+ E13o(self.e12.0 * self.e12oi.0 * rhs.e13o.0) * 2.0
+				- E13o(self.e12.0 * self.e13.0 * rhs.e12o.0) * 2.0
+				- E13o(self.e12.0 * self.e13oi.0 * rhs.e12o.0) * 2.0
+				+ E13o(self.e12.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				+ E13o(self.e12.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e12.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				+ E13o(self.e12.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				+ E13o(self.e12.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e123o.0 * self.e123o.0 * rhs.e13i.0) * 2.0
+				- E13o(self.e123o.0 * self.e12oi.0 * rhs.e1oi.0) * 2.0
+				- E13o(self.e123o.0 * self.e13.0 * rhs.e123.0) * 2.0
+				- E13o(self.e123o.0 * self.e13oi.0 * rhs.e123.0) * 2.0
+				+ E13o(self.e123o.0 * self.e1o.0 * rhs.e12i.0) * 4.0
+				+ E13o(self.e123o.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E13o(self.e123o.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e123o.0 * self.e3o.0 * rhs.e23i.0) * 4.0
+				- E13o(self.e123o.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				- E13o(self.e123o.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E13o(self.e12oi.0 * self.e12oi.0 * rhs.e13o.0)
+				- E13o(self.e12oi.0 * self.e13.0 * rhs.e12o.0) * 2.0
+				- E13o(self.e12oi.0 * self.e13oi.0 * rhs.e12o.0) * 2.0
+				+ E13o(self.e12oi.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				+ E13o(self.e12oi.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e12oi.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				+ E13o(self.e12oi.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				+ E13o(self.e12oi.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e13.0 * self.e13.0 * rhs.e13o.0)
+				- E13o(self.e13.0 * self.e13oi.0 * rhs.e13o.0) * 2.0
+				- E13o(self.e13.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				- E13o(self.e13.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e13.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e13.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E13o(self.e13.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e13oi.0 * self.e13oi.0 * rhs.e13o.0)
+				- E13o(self.e13oi.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				- E13o(self.e13oi.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e13oi.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				- E13o(self.e13oi.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E13o(self.e13oi.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				+ E13o(self.e1o.0 * self.e1o.0 * rhs.e13i.0) * 2.0
+				- E13o(self.e1o.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				- E13o(self.e1o.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				+ E13o(self.e1o.0 * self.e2o.0 * rhs.e23i.0) * 4.0
+				- E13o(self.e1o.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				- E13o(self.e1o.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E13o(self.e23.0 * self.e23.0 * rhs.e13o.0)
+				+ E13o(self.e23.0 * self.e23oi.0 * rhs.e13o.0) * 2.0
+				+ E13o(self.e23.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				- E13o(self.e23.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				- E13o(self.e23.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				- E13o(self.e23.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E13o(self.e23oi.0 * self.e23oi.0 * rhs.e13o.0)
+				+ E13o(self.e23oi.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				- E13o(self.e23oi.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				- E13o(self.e23oi.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				- E13o(self.e23oi.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E13o(self.e2o.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				+ E13o(self.e2o.0 * self.e3o.0 * rhs.e12i.0) * 4.0
+				+ E13o(self.e2o.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				+ E13o(self.e2o.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E13o(self.e3o.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				+ E13o(self.e3o.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				+ E13o(self.e3o.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E13o(self.eoi.0 * self.eoi.0 * rhs.e13o.0)
+				- E13o(self.eoi.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E13o(self.s.0 * self.s.0 * rhs.e13o.0),
+			e13i: E13i(self.e12.0 * self.e12.0 * rhs.e13i.0) + E13i(self.e12.0 * self.e123i.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.e12.0 * self.e12oi.0 * rhs.e13i.0) * 2.0
+				- E13i(self.e12.0 * self.e13.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e12.0 * self.e13oi.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e12.0 * self.e1i.0 * rhs.e123.0) * 2.0
+				- E13i(self.e12.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				+ E13i(self.e12.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				- E13i(self.e12.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e12.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e123i.0 * self.e123i.0 * rhs.e13o.0) * 2.0
+				- E13i(self.e123i.0 * self.e12oi.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.e123i.0 * self.e13.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e123i.0 * self.e13oi.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e123i.0 * self.e1i.0 * rhs.e12o.0) * 4.0
+				- E13i(self.e123i.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				+ E13i(self.e123i.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e123i.0 * self.e3i.0 * rhs.e23o.0) * 4.0
+				- E13i(self.e123i.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				+ E13i(self.e123i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E13i(self.e12oi.0 * self.e12oi.0 * rhs.e13i.0)
+				+ E13i(self.e12oi.0 * self.e13.0 * rhs.e12i.0) * 2.0
+				- E13i(self.e12oi.0 * self.e13oi.0 * rhs.e12i.0) * 2.0
+				- E13i(self.e12oi.0 * self.e1i.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e12oi.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e12oi.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				+ E13i(self.e12oi.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e12oi.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e13.0 * self.e13.0 * rhs.e13i.0)
+				+ E13i(self.e13.0 * self.e13oi.0 * rhs.e13i.0) * 2.0
+				+ E13i(self.e13.0 * self.e1i.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.e13.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e13.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				+ E13i(self.e13.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				+ E13i(self.e13.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				- E13i(self.e13oi.0 * self.e13oi.0 * rhs.e13i.0)
+				- E13i(self.e13oi.0 * self.e1i.0 * rhs.e1oi.0) * 2.0
+				+ E13i(self.e13oi.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e13oi.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				- E13i(self.e13oi.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				- E13i(self.e13oi.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				+ E13i(self.e1i.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				+ E13i(self.e1i.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				- E13i(self.e1i.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				+ E13i(self.e1i.0 * self.e2i.0 * rhs.e23o.0) * 4.0
+				- E13i(self.e1i.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				+ E13i(self.e1i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E13i(self.e23.0 * self.e23.0 * rhs.e13i.0)
+				- E13i(self.e23.0 * self.e23oi.0 * rhs.e13i.0) * 2.0
+				- E13i(self.e23.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.e23.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e23.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				- E13i(self.e23.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e23oi.0 * self.e23oi.0 * rhs.e13i.0)
+				+ E13i(self.e23oi.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				+ E13i(self.e23oi.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				- E13i(self.e23oi.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				+ E13i(self.e23oi.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E13i(self.e2i.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				+ E13i(self.e2i.0 * self.e3i.0 * rhs.e12o.0) * 4.0
+				- E13i(self.e2i.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e2i.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E13i(self.e3i.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				+ E13i(self.e3i.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.e3i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E13i(self.eoi.0 * self.eoi.0 * rhs.e13i.0)
+				+ E13i(self.eoi.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E13i(self.s.0 * self.s.0 * rhs.e13i.0),
+			e1oi: E1oi(self.e12.0 * self.e12.0 * rhs.e1oi.0) - E1oi(self.e12.0 * self.e123i.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e12.0 * self.e123o.0 * rhs.e13i.0) * 2.0
+				+ E1oi(self.e12.0 * self.e13oi.0 * rhs.e123.0) * 2.0
+				+ E1oi(self.e12.0 * self.e1i.0 * rhs.e12o.0) * 2.0
+				- E1oi(self.e12.0 * self.e1o.0 * rhs.e12i.0) * 2.0
+				- E1oi(self.e12.0 * self.e23.0 * rhs.e3oi.0) * 2.0
+				- E1oi(self.e12.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				+ E1oi(self.e12.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				+ E1oi(self.e12.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e123i.0 * self.e123o.0 * rhs.e1oi.0) * 2.0
+				- E1oi(self.e123i.0 * self.e12oi.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e123i.0 * self.e13.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e123i.0 * self.e13oi.0 * rhs.e12o.0) * 2.0
+				- E1oi(self.e123i.0 * self.e1o.0 * rhs.e123.0) * 2.0
+				- E1oi(self.e123i.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e123i.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e123i.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				- E1oi(self.e123i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E1oi(self.e123o.0 * self.e12oi.0 * rhs.e13i.0) * 2.0
+				- E1oi(self.e123o.0 * self.e13.0 * rhs.e12i.0) * 2.0
+				+ E1oi(self.e123o.0 * self.e13oi.0 * rhs.e12i.0) * 2.0
+				+ E1oi(self.e123o.0 * self.e1i.0 * rhs.e123.0) * 2.0
+				- E1oi(self.e123o.0 * self.e2i.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e123o.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				- E1oi(self.e123o.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				+ E1oi(self.e123o.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E1oi(self.e12oi.0 * self.e12oi.0 * rhs.e1oi.0)
+				- E1oi(self.e12oi.0 * self.e13.0 * rhs.e123.0) * 2.0
+				+ E1oi(self.e12oi.0 * self.e1i.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e12oi.0 * self.e1o.0 * rhs.e12i.0) * 2.0
+				+ E1oi(self.e12oi.0 * self.e23oi.0 * rhs.e3oi.0) * 2.0
+				- E1oi(self.e12oi.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				- E1oi(self.e12oi.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				- E1oi(self.e12oi.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e13.0 * self.e13.0 * rhs.e1oi.0)
+				+ E1oi(self.e13.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				- E1oi(self.e13.0 * self.e1o.0 * rhs.e13i.0) * 2.0
+				+ E1oi(self.e13.0 * self.e23.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e13.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				- E1oi(self.e13.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				+ E1oi(self.e13.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E1oi(self.e13oi.0 * self.e13oi.0 * rhs.e1oi.0)
+				+ E1oi(self.e13oi.0 * self.e1i.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e13oi.0 * self.e1o.0 * rhs.e13i.0) * 2.0
+				- E1oi(self.e13oi.0 * self.e23oi.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e13oi.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E1oi(self.e13oi.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				- E1oi(self.e13oi.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				+ E1oi(self.e1i.0 * self.e1o.0 * rhs.e1oi.0) * 2.0
+				+ E1oi(self.e1i.0 * self.e23.0 * rhs.e23o.0) * 2.0
+				+ E1oi(self.e1i.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				+ E1oi(self.e1i.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e1i.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				- E1oi(self.e1o.0 * self.e23.0 * rhs.e23i.0) * 2.0
+				+ E1oi(self.e1o.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				+ E1oi(self.e1o.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				+ E1oi(self.e1o.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				- E1oi(self.e23.0 * self.e23.0 * rhs.e1oi.0)
+				- E1oi(self.e23.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e23.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				+ E1oi(self.e23.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				- E1oi(self.e23.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				- E1oi(self.e23.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				+ E1oi(self.e23oi.0 * self.e23oi.0 * rhs.e1oi.0)
+				- E1oi(self.e23oi.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				- E1oi(self.e23oi.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				+ E1oi(self.e23oi.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e23oi.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				+ E1oi(self.e23oi.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E1oi(self.e2i.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				+ E1oi(self.e2i.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				+ E1oi(self.e2i.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				+ E1oi(self.e2i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E1oi(self.e2o.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				+ E1oi(self.e2o.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				- E1oi(self.e2o.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E1oi(self.e3i.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				+ E1oi(self.e3i.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e3i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E1oi(self.e3o.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				- E1oi(self.e3o.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E1oi(self.eoi.0 * self.eoi.0 * rhs.e1oi.0)
+				- E1oi(self.s.0 * self.s.0 * rhs.e1oi.0),
+			e23o: E23o(self.e12.0 * self.e12.0 * rhs.e23o.0) - E23o(self.e12.0 * self.e123o.0 * rhs.e2oi.0) * 2.0
+				+ E23o(self.e12.0 * self.e12oi.0 * rhs.e23o.0) * 2.0
+				- E23o(self.e12.0 * self.e1o.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e12.0 * self.e23.0 * rhs.e12o.0) * 2.0
+				- E23o(self.e12.0 * self.e23oi.0 * rhs.e12o.0) * 2.0
+				+ E23o(self.e12.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				+ E23o(self.e12.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				- E23o(self.e12.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e12.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e123o.0 * self.e123o.0 * rhs.e23i.0) * 2.0
+				- E23o(self.e123o.0 * self.e12oi.0 * rhs.e2oi.0) * 2.0
+				- E23o(self.e123o.0 * self.e13.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e123o.0 * self.e13oi.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e123o.0 * self.e23.0 * rhs.e123.0) * 2.0
+				- E23o(self.e123o.0 * self.e23oi.0 * rhs.e123.0) * 2.0
+				+ E23o(self.e123o.0 * self.e2o.0 * rhs.e12i.0) * 4.0
+				+ E23o(self.e123o.0 * self.e3o.0 * rhs.e13i.0) * 4.0
+				+ E23o(self.e123o.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				+ E23o(self.e123o.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E23o(self.e12oi.0 * self.e12oi.0 * rhs.e23o.0)
+				- E23o(self.e12oi.0 * self.e1o.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e12oi.0 * self.e23.0 * rhs.e12o.0) * 2.0
+				- E23o(self.e12oi.0 * self.e23oi.0 * rhs.e12o.0) * 2.0
+				+ E23o(self.e12oi.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				+ E23o(self.e12oi.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				- E23o(self.e12oi.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e12oi.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E23o(self.e13.0 * self.e13.0 * rhs.e23o.0)
+				+ E23o(self.e13.0 * self.e13oi.0 * rhs.e23o.0) * 2.0
+				+ E23o(self.e13.0 * self.e1o.0 * rhs.e2oi.0) * 2.0
+				- E23o(self.e13.0 * self.e23.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e13.0 * self.e23oi.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e13.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				+ E23o(self.e13.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				+ E23o(self.e13.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				+ E23o(self.e13.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E23o(self.e13oi.0 * self.e13oi.0 * rhs.e23o.0)
+				+ E23o(self.e13oi.0 * self.e1o.0 * rhs.e2oi.0) * 2.0
+				- E23o(self.e13oi.0 * self.e23.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e13oi.0 * self.e23oi.0 * rhs.e13o.0) * 2.0
+				- E23o(self.e13oi.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				+ E23o(self.e13oi.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				+ E23o(self.e13oi.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				+ E23o(self.e13oi.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E23o(self.e1o.0 * self.e1o.0 * rhs.e23i.0) * 2.0
+				- E23o(self.e1o.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				- E23o(self.e1o.0 * self.e23oi.0 * rhs.e1oi.0) * 2.0
+				+ E23o(self.e1o.0 * self.e2o.0 * rhs.e13i.0) * 4.0
+				- E23o(self.e1o.0 * self.e3o.0 * rhs.e12i.0) * 4.0
+				- E23o(self.e1o.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E23o(self.e1o.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E23o(self.e23.0 * self.e23.0 * rhs.e23o.0)
+				- E23o(self.e23.0 * self.e23oi.0 * rhs.e23o.0) * 2.0
+				- E23o(self.e23.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E23o(self.e23.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e23oi.0 * self.e23oi.0 * rhs.e23o.0)
+				- E23o(self.e23oi.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				- E23o(self.e23oi.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				+ E23o(self.e2o.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				- E23o(self.e2o.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				- E23o(self.e2o.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E23o(self.e3o.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				+ E23o(self.e3o.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				+ E23o(self.e3o.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E23o(self.eoi.0 * self.eoi.0 * rhs.e23o.0)
+				- E23o(self.eoi.0 * self.s.0 * rhs.e23o.0) * 2.0
+				- E23o(self.s.0 * self.s.0 * rhs.e23o.0),
+			e23i: E23i(self.e12.0 * self.e12.0 * rhs.e23i.0) + E23i(self.e12.0 * self.e123i.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.e12.0 * self.e12oi.0 * rhs.e23i.0) * 2.0
+				+ E23i(self.e12.0 * self.e1i.0 * rhs.e3oi.0) * 2.0
+				- E23i(self.e12.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e12.0 * self.e23oi.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e12.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				- E23i(self.e12.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				+ E23i(self.e12.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e12.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e123i.0 * self.e123i.0 * rhs.e23o.0) * 2.0
+				- E23i(self.e123i.0 * self.e12oi.0 * rhs.e2oi.0) * 2.0
+				+ E23i(self.e123i.0 * self.e13.0 * rhs.e3oi.0) * 2.0
+				- E23i(self.e123i.0 * self.e13oi.0 * rhs.e3oi.0) * 2.0
+				- E23i(self.e123i.0 * self.e23.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e123i.0 * self.e23oi.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e123i.0 * self.e2i.0 * rhs.e12o.0) * 4.0
+				+ E23i(self.e123i.0 * self.e3i.0 * rhs.e13o.0) * 4.0
+				+ E23i(self.e123i.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e123i.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E23i(self.e12oi.0 * self.e12oi.0 * rhs.e23i.0)
+				- E23i(self.e12oi.0 * self.e1i.0 * rhs.e3oi.0) * 2.0
+				+ E23i(self.e12oi.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e12oi.0 * self.e23oi.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e12oi.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e12oi.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e12oi.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e12oi.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13.0 * self.e13.0 * rhs.e23i.0)
+				- E23i(self.e13.0 * self.e13oi.0 * rhs.e23i.0) * 2.0
+				- E23i(self.e13.0 * self.e1i.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.e13.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13.0 * self.e23oi.0 * rhs.e13i.0) * 2.0
+				+ E23i(self.e13.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				+ E23i(self.e13.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				- E23i(self.e13.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e13.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E23i(self.e13oi.0 * self.e13oi.0 * rhs.e23i.0)
+				+ E23i(self.e13oi.0 * self.e1i.0 * rhs.e2oi.0) * 2.0
+				+ E23i(self.e13oi.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e13oi.0 * self.e23oi.0 * rhs.e13i.0) * 2.0
+				- E23i(self.e13oi.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e13oi.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				+ E23i(self.e13oi.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e13oi.0 * self.s.0 * rhs.e12i.0) * 2.0
+				- E23i(self.e1i.0 * self.e1i.0 * rhs.e23o.0) * 2.0
+				+ E23i(self.e1i.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				- E23i(self.e1i.0 * self.e23oi.0 * rhs.e1oi.0) * 2.0
+				+ E23i(self.e1i.0 * self.e2i.0 * rhs.e13o.0) * 4.0
+				- E23i(self.e1i.0 * self.e3i.0 * rhs.e12o.0) * 4.0
+				+ E23i(self.e1i.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				- E23i(self.e1i.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E23i(self.e23.0 * self.e23.0 * rhs.e23i.0)
+				+ E23i(self.e23.0 * self.e23oi.0 * rhs.e23i.0) * 2.0
+				+ E23i(self.e23.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				+ E23i(self.e23.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				- E23i(self.e23oi.0 * self.e23oi.0 * rhs.e23i.0)
+				- E23i(self.e23oi.0 * self.e2i.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.e23oi.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				+ E23i(self.e2i.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				- E23i(self.e2i.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				+ E23i(self.e2i.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				+ E23i(self.e3i.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				+ E23i(self.e3i.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.e3i.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E23i(self.eoi.0 * self.eoi.0 * rhs.e23i.0)
+				+ E23i(self.eoi.0 * self.s.0 * rhs.e23i.0) * 2.0
+				- E23i(self.s.0 * self.s.0 * rhs.e23i.0),
+			e2oi: E2oi(self.e12.0 * self.e12.0 * rhs.e2oi.0) - E2oi(self.e12.0 * self.e123i.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e12.0 * self.e123o.0 * rhs.e23i.0) * 2.0
+				+ E2oi(self.e12.0 * self.e13.0 * rhs.e3oi.0) * 2.0
+				+ E2oi(self.e12.0 * self.e23oi.0 * rhs.e123.0) * 2.0
+				+ E2oi(self.e12.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				- E2oi(self.e12.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e12.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				- E2oi(self.e12.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				- E2oi(self.e12.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e123i.0 * self.e123o.0 * rhs.e2oi.0) * 2.0
+				- E2oi(self.e123i.0 * self.e12oi.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e123i.0 * self.e1o.0 * rhs.e3oi.0) * 2.0
+				+ E2oi(self.e123i.0 * self.e23.0 * rhs.e12o.0) * 2.0
+				+ E2oi(self.e123i.0 * self.e23oi.0 * rhs.e12o.0) * 2.0
+				- E2oi(self.e123i.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				- E2oi(self.e123i.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e123i.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e123i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				- E2oi(self.e123o.0 * self.e12oi.0 * rhs.e23i.0) * 2.0
+				+ E2oi(self.e123o.0 * self.e1i.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e123o.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e123o.0 * self.e23oi.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e123o.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				- E2oi(self.e123o.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e123o.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				- E2oi(self.e123o.0 * self.s.0 * rhs.e13i.0) * 2.0
+				- E2oi(self.e12oi.0 * self.e12oi.0 * rhs.e2oi.0)
+				- E2oi(self.e12oi.0 * self.e13oi.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e12oi.0 * self.e23.0 * rhs.e123.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				+ E2oi(self.e12oi.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				- E2oi(self.e13.0 * self.e13.0 * rhs.e2oi.0)
+				- E2oi(self.e13.0 * self.e1i.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e13.0 * self.e1o.0 * rhs.e23i.0) * 2.0
+				+ E2oi(self.e13.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e13.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				- E2oi(self.e13.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				- E2oi(self.e13.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				+ E2oi(self.e13.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e13.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				+ E2oi(self.e13oi.0 * self.e13oi.0 * rhs.e2oi.0)
+				- E2oi(self.e13oi.0 * self.e1i.0 * rhs.e23o.0) * 2.0
+				- E2oi(self.e13oi.0 * self.e1o.0 * rhs.e23i.0) * 2.0
+				- E2oi(self.e13oi.0 * self.e23oi.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e13oi.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e13oi.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				- E2oi(self.e13oi.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				- E2oi(self.e13oi.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				- E2oi(self.e13oi.0 * self.s.0 * rhs.e123.0) * 2.0
+				- E2oi(self.e1i.0 * self.e1o.0 * rhs.e2oi.0) * 2.0
+				+ E2oi(self.e1i.0 * self.e23.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e1i.0 * self.e23oi.0 * rhs.e13o.0) * 2.0
+				+ E2oi(self.e1i.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				- E2oi(self.e1i.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				- E2oi(self.e1i.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				- E2oi(self.e1i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				- E2oi(self.e1o.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				+ E2oi(self.e1o.0 * self.e23oi.0 * rhs.e13i.0) * 2.0
+				+ E2oi(self.e1o.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				+ E2oi(self.e1o.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				- E2oi(self.e1o.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e1o.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E2oi(self.e23.0 * self.e23.0 * rhs.e2oi.0)
+				+ E2oi(self.e23.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				- E2oi(self.e23.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				+ E2oi(self.e23.0 * self.s.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e23oi.0 * self.e23oi.0 * rhs.e2oi.0)
+				+ E2oi(self.e23oi.0 * self.e2i.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e23oi.0 * self.e2o.0 * rhs.e23i.0) * 2.0
+				- E2oi(self.e23oi.0 * self.eoi.0 * rhs.e3oi.0) * 2.0
+				+ E2oi(self.e2i.0 * self.e2o.0 * rhs.e2oi.0) * 2.0
+				+ E2oi(self.e2i.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				+ E2oi(self.e2o.0 * self.e3i.0 * rhs.e3oi.0) * 2.0
+				- E2oi(self.e3i.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				+ E2oi(self.e3i.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e3i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E2oi(self.e3o.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				- E2oi(self.e3o.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E2oi(self.eoi.0 * self.eoi.0 * rhs.e2oi.0)
+				- E2oi(self.s.0 * self.s.0 * rhs.e2oi.0),
+			e3oi: -E3oi(self.e12.0 * self.e12.0 * rhs.e3oi.0)
+				+ E3oi(self.e12.0 * self.e13.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e12.0 * self.e1i.0 * rhs.e23o.0) * 2.0
+				- E3oi(self.e12.0 * self.e1o.0 * rhs.e23i.0) * 2.0
+				- E3oi(self.e12.0 * self.e23.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e12.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				+ E3oi(self.e12.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e12.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				- E3oi(self.e12.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				- E3oi(self.e12.0 * self.eoi.0 * rhs.e123.0) * 2.0
+				+ E3oi(self.e123i.0 * self.e123o.0 * rhs.e3oi.0) * 2.0
+				- E3oi(self.e123i.0 * self.e13.0 * rhs.e23o.0) * 2.0
+				- E3oi(self.e123i.0 * self.e13oi.0 * rhs.e23o.0) * 2.0
+				- E3oi(self.e123i.0 * self.e1o.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e123i.0 * self.e23.0 * rhs.e13o.0) * 2.0
+				+ E3oi(self.e123i.0 * self.e23oi.0 * rhs.e13o.0) * 2.0
+				+ E3oi(self.e123i.0 * self.e2o.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e123i.0 * self.e3o.0 * rhs.e123.0) * 2.0
+				- E3oi(self.e123i.0 * self.eoi.0 * rhs.e12o.0) * 2.0
+				- E3oi(self.e123i.0 * self.s.0 * rhs.e12o.0) * 2.0
+				+ E3oi(self.e123o.0 * self.e13.0 * rhs.e23i.0) * 2.0
+				- E3oi(self.e123o.0 * self.e13oi.0 * rhs.e23i.0) * 2.0
+				- E3oi(self.e123o.0 * self.e1i.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.e123o.0 * self.e23.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e123o.0 * self.e23oi.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e123o.0 * self.e2i.0 * rhs.e1oi.0) * 2.0
+				+ E3oi(self.e123o.0 * self.e3i.0 * rhs.e123.0) * 2.0
+				- E3oi(self.e123o.0 * self.eoi.0 * rhs.e12i.0) * 2.0
+				+ E3oi(self.e123o.0 * self.s.0 * rhs.e12i.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.e12oi.0 * rhs.e3oi.0)
+				- E3oi(self.e12oi.0 * self.e13oi.0 * rhs.e2oi.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.e1i.0 * rhs.e23o.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.e1o.0 * rhs.e23i.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.e23oi.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e12oi.0 * self.e2i.0 * rhs.e13o.0) * 2.0
+				- E3oi(self.e12oi.0 * self.e2o.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.e3i.0 * rhs.e12o.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.e3o.0 * rhs.e12i.0) * 2.0
+				+ E3oi(self.e12oi.0 * self.s.0 * rhs.e123.0) * 2.0
+				+ E3oi(self.e13.0 * self.e13.0 * rhs.e3oi.0)
+				+ E3oi(self.e13.0 * self.e23oi.0 * rhs.e123.0) * 2.0
+				+ E3oi(self.e13.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				- E3oi(self.e13.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				+ E3oi(self.e13.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				- E3oi(self.e13.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				- E3oi(self.e13.0 * self.s.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e13oi.0 * self.e13oi.0 * rhs.e3oi.0)
+				- E3oi(self.e13oi.0 * self.e23.0 * rhs.e123.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.e2i.0 * rhs.e12o.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.e2o.0 * rhs.e12i.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.e3i.0 * rhs.e13o.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.e3o.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e13oi.0 * self.eoi.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e1i.0 * self.e1o.0 * rhs.e3oi.0) * 2.0
+				- E3oi(self.e1i.0 * self.e23.0 * rhs.e12o.0) * 2.0
+				- E3oi(self.e1i.0 * self.e23oi.0 * rhs.e12o.0) * 2.0
+				+ E3oi(self.e1i.0 * self.e2o.0 * rhs.e123.0) * 2.0
+				+ E3oi(self.e1i.0 * self.e3o.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e1i.0 * self.eoi.0 * rhs.e13o.0) * 2.0
+				- E3oi(self.e1i.0 * self.s.0 * rhs.e13o.0) * 2.0
+				+ E3oi(self.e1o.0 * self.e23.0 * rhs.e12i.0) * 2.0
+				- E3oi(self.e1o.0 * self.e23oi.0 * rhs.e12i.0) * 2.0
+				- E3oi(self.e1o.0 * self.e2i.0 * rhs.e123.0) * 2.0
+				+ E3oi(self.e1o.0 * self.e3i.0 * rhs.e1oi.0) * 2.0
+				- E3oi(self.e1o.0 * self.eoi.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e1o.0 * self.s.0 * rhs.e13i.0) * 2.0
+				+ E3oi(self.e23.0 * self.e23.0 * rhs.e3oi.0)
+				+ E3oi(self.e23.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				- E3oi(self.e23.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				- E3oi(self.e23.0 * self.s.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.e23oi.0 * self.e23oi.0 * rhs.e3oi.0)
+				+ E3oi(self.e23oi.0 * self.e3i.0 * rhs.e23o.0) * 2.0
+				+ E3oi(self.e23oi.0 * self.e3o.0 * rhs.e23i.0) * 2.0
+				+ E3oi(self.e23oi.0 * self.eoi.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.e2i.0 * self.e2o.0 * rhs.e3oi.0) * 2.0
+				+ E3oi(self.e2i.0 * self.e3o.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.e2i.0 * self.eoi.0 * rhs.e23o.0) * 2.0
+				- E3oi(self.e2i.0 * self.s.0 * rhs.e23o.0) * 2.0
+				+ E3oi(self.e2o.0 * self.e3i.0 * rhs.e2oi.0) * 2.0
+				- E3oi(self.e2o.0 * self.eoi.0 * rhs.e23i.0) * 2.0
+				+ E3oi(self.e2o.0 * self.s.0 * rhs.e23i.0) * 2.0
+				+ E3oi(self.e3i.0 * self.e3o.0 * rhs.e3oi.0) * 2.0
+				+ E3oi(self.eoi.0 * self.eoi.0 * rhs.e3oi.0)
+				- E3oi(self.s.0 * self.s.0 * rhs.e3oi.0),
+		}
+	}
+}
+
+// Omitted: Versor.sandwich(Plane)  (not a Plane)
+// Omitted: Versor.anti_sandwich(Plane)  (not a Plane)
+// Omitted: Versor.sandwich(Sphere)  (not a Sphere)
+// Omitted: Versor.anti_sandwich(Sphere)  (not a Sphere)
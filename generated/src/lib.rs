@@ -1,2 +1,5 @@
+pub mod cga3d;
 pub mod pga2d;
 pub mod pga3d;
+pub mod sta;
+pub mod vga3d;
@@ -8,6 +8,13 @@
 //! | Left complement  | XYW | YW | WX | XY | X   | Y   | W   | 1    |
 //! | Reverse          | 1   | X  | Y  | W  | -YW | -WX | -XY | -XYW |
 //! | Anti-reverse     | -1  | -X | -Y | -W | YW  | WX  | XY  | XYW  |
+//! | Involute         | 1   | -X | -Y | -W | YW  | WX  | XY  | -XYW |
+//! | Conjugate        | 1   | -X | -Y | -W | -YW | -WX | -XY | XYW  |
+//! | Dual             | XYW | YW | WX | XY | X   | Y   | W   | 1    |
+//! | Bulk             | 1   | X  | Y  | 0  | 0   | 0   | XY  | 0    |
+//! | Weight           | 0   | 0  | 0  | W  | YW  | WX  | 0   | XYW  |
+//! | Bulk dual        | XYW | YW | WX | 0  | 0   | 0   | W   | 0    |
+//! | Weight dual      | 0   | 0  | 0  | XY | X   | Y   | 0   | 1    |
 //!
 //!
 //! ## Multiplication tables
@@ -27,30 +34,30 @@
 //!
 //! ### AntiGeometric multiplication table
 //!
-//! |     | 1   | X   | Y   | W   | YW  | WX  | XY  | XYW |
-//! | --- | --- | --- | --- | --- | --- | --- | --- | --- |
-//! | 1   | 1   | X   | Y   | W   | YW  | WX  | XY  | XYW |
-//! | X   | X   | 1   | XY  | -WX | XYW | -W  | Y   | YW  |
-//! | Y   | Y   | -XY | 1   | YW  | W   | XYW | -X  | WX  |
-//! | W   | W   | WX  | -YW | 0   | 0   | 0   | XYW | 0   |
-//! | YW  | YW  | XYW | -W  | 0   | 0   | 0   | WX  | 0   |
-//! | WX  | WX  | W   | XYW | 0   | 0   | 0   | -YW | 0   |
-//! | XY  | XY  | -Y  | X   | XYW | -WX | YW  | -1  | -W  |
-//! | XYW | XYW | YW  | WX  | 0   | 0   | 0   | -W  | 0   |
+//! |     | 1   | X  | Y   | W    | YW  | WX  | XY | XYW |
+//! | --- | --- | -- | --- | ---- | --- | --- | -- | --- |
+//! | 1   | 0   | 0  | 0   | -XY  | X   | Y   | 0  | 1   |
+//! | X   | 0   | 0  | 0   | Y    | 1   | -XY | 0  | X   |
+//! | Y   | 0   | 0  | 0   | -X   | XY  | 1   | 0  | Y   |
+//! | W   | -XY | -Y | X   | -XYW | -WX | YW  | 1  | W   |
+//! | YW  | X   | 1  | -XY | WX   | XYW | W   | -Y | YW  |
+//! | WX  | Y   | XY | 1   | -YW  | -W  | XYW | X  | WX  |
+//! | XY  | 0   | 0  | 0   | 1    | Y   | -X  | 0  | XY  |
+//! | XYW | 1   | X  | Y   | W    | YW  | WX  | XY | XYW |
 //!
 //!
 //! ### Dot multiplication table
 //!
-//! |     | 1   | X   | Y   | W   | YW  | WX  | XY  | XYW |
-//! | --- | --- | --- | --- | --- | --- | --- | --- | --- |
-//! | 1   | 1   | X   | Y   | W   | YW  | WX  | XY  | XYW |
-//! | X   | X   | 1   | XY  | -WX | XYW | -W  | Y   | YW  |
-//! | Y   | Y   | -XY | 1   | YW  | W   | XYW | -X  | WX  |
-//! | W   | W   | WX  | -YW | 0   | 0   | 0   | XYW | 0   |
-//! | YW  | YW  | XYW | -W  | 0   | 0   | 0   | WX  | 0   |
-//! | WX  | WX  | W   | XYW | 0   | 0   | 0   | -YW | 0   |
-//! | XY  | XY  | -Y  | X   | XYW | -WX | YW  | -1  | -W  |
-//! | XYW | XYW | YW  | WX  | 0   | 0   | 0   | -W  | 0   |
+//! |     | 1   | X  | Y  | W | YW | WX | XY | XYW |
+//! | --- | --- | -- | -- | - | -- | -- | -- | --- |
+//! | 1   | 1   | X  | Y  | W | YW | WX | XY | XYW |
+//! | X   | X   | 1  | 0  | 0 | 0  | -W | Y  | YW  |
+//! | Y   | Y   | 0  | 1  | 0 | W  | 0  | -X | WX  |
+//! | W   | W   | 0  | 0  | 0 | 0  | 0  | 0  | 0   |
+//! | YW  | YW  | 0  | -W | 0 | 0  | 0  | 0  | 0   |
+//! | WX  | WX  | W  | 0  | 0 | 0  | 0  | 0  | 0   |
+//! | XY  | XY  | -Y | X  | 0 | 0  | 0  | -1 | -W  |
+//! | XYW | XYW | YW | WX | 0 | 0  | 0  | -W | 0   |
 //!
 //!
 //! ### Wedge multiplication table
@@ -58,66 +65,520 @@
 //! |     | 1   | X   | Y   | W   | YW  | WX  | XY  | XYW |
 //! | --- | --- | --- | --- | --- | --- | --- | --- | --- |
 //! | 1   | 1   | X   | Y   | W   | YW  | WX  | XY  | XYW |
-//! | X   | X   | 1   | XY  | -WX | XYW | -W  | Y   | YW  |
-//! | Y   | Y   | -XY | 1   | YW  | W   | XYW | -X  | WX  |
+//! | X   | X   | 0   | XY  | -WX | XYW | 0   | 0   | 0   |
+//! | Y   | Y   | -XY | 0   | YW  | 0   | XYW | 0   | 0   |
 //! | W   | W   | WX  | -YW | 0   | 0   | 0   | XYW | 0   |
-//! | YW  | YW  | XYW | -W  | 0   | 0   | 0   | WX  | 0   |
-//! | WX  | WX  | W   | XYW | 0   | 0   | 0   | -YW | 0   |
-//! | XY  | XY  | -Y  | X   | XYW | -WX | YW  | -1  | -W  |
-//! | XYW | XYW | YW  | WX  | 0   | 0   | 0   | -W  | 0   |
+//! | YW  | YW  | XYW | 0   | 0   | 0   | 0   | 0   | 0   |
+//! | WX  | WX  | 0   | XYW | 0   | 0   | 0   | 0   | 0   |
+//! | XY  | XY  | 0   | 0   | XYW | 0   | 0   | 0   | 0   |
+//! | XYW | XYW | 0   | 0   | 0   | 0   | 0   | 0   | 0   |
 //!
 //!
 //! ### AntiWedge multiplication table
 //!
-//! |     | 1   | X   | Y   | W   | YW  | WX  | XY  | XYW |
-//! | --- | --- | --- | --- | --- | --- | --- | --- | --- |
-//! | 1   | 1   | X   | Y   | W   | YW  | WX  | XY  | XYW |
-//! | X   | X   | 1   | XY  | -WX | XYW | -W  | Y   | YW  |
-//! | Y   | Y   | -XY | 1   | YW  | W   | XYW | -X  | WX  |
-//! | W   | W   | WX  | -YW | 0   | 0   | 0   | XYW | 0   |
-//! | YW  | YW  | XYW | -W  | 0   | 0   | 0   | WX  | 0   |
-//! | WX  | WX  | W   | XYW | 0   | 0   | 0   | -YW | 0   |
-//! | XY  | XY  | -Y  | X   | XYW | -WX | YW  | -1  | -W  |
-//! | XYW | XYW | YW  | WX  | 0   | 0   | 0   | -W  | 0   |
-
-use derive_more::{Add, Mul, Neg, Sub};
+//! |     | 1 | X | Y | W | YW | WX | XY | XYW |
+//! | --- | - | - | - | - | -- | -- | -- | --- |
+//! | 1   | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 1   |
+//! | X   | 0 | 0 | 0 | 0 | 1  | 0  | 0  | X   |
+//! | Y   | 0 | 0 | 0 | 0 | 0  | 1  | 0  | Y   |
+//! | W   | 0 | 0 | 0 | 0 | 0  | 0  | 1  | W   |
+//! | YW  | 0 | 1 | 0 | 0 | 0  | W  | -Y | YW  |
+//! | WX  | 0 | 0 | 1 | 0 | -W | 0  | X  | WX  |
+//! | XY  | 0 | 0 | 0 | 1 | Y  | -X | 0  | XY  |
+//! | XYW | 1 | X | Y | W | YW | WX | XY | XYW |
+//!
+//!
+//! ### LeftContraction multiplication table
+//!
+//! |     | 1 | X | Y | W | YW | WX | XY | XYW |
+//! | --- | - | - | - | - | -- | -- | -- | --- |
+//! | 1   | 1 | X | Y | W | YW | WX | XY | XYW |
+//! | X   | 0 | 1 | 0 | 0 | 0  | -W | Y  | YW  |
+//! | Y   | 0 | 0 | 1 | 0 | W  | 0  | -X | WX  |
+//! | W   | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0   |
+//! | YW  | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0   |
+//! | WX  | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0   |
+//! | XY  | 0 | 0 | 0 | 0 | 0  | 0  | -1 | -W  |
+//! | XYW | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0   |
+//!
+//!
+//! ### RightContraction multiplication table
+//!
+//! |     | 1   | X  | Y  | W | YW | WX | XY | XYW |
+//! | --- | --- | -- | -- | - | -- | -- | -- | --- |
+//! | 1   | 1   | 0  | 0  | 0 | 0  | 0  | 0  | 0   |
+//! | X   | X   | 1  | 0  | 0 | 0  | 0  | 0  | 0   |
+//! | Y   | Y   | 0  | 1  | 0 | 0  | 0  | 0  | 0   |
+//! | W   | W   | 0  | 0  | 0 | 0  | 0  | 0  | 0   |
+//! | YW  | YW  | 0  | -W | 0 | 0  | 0  | 0  | 0   |
+//! | WX  | WX  | W  | 0  | 0 | 0  | 0  | 0  | 0   |
+//! | XY  | XY  | -Y | X  | 0 | 0  | 0  | -1 | 0   |
+//! | XYW | XYW | YW | WX | 0 | 0  | 0  | -W | 0   |
+//!
+//!
+//! ### ScalarProduct multiplication table
+//!
+//! |     | 1 | X | Y | W | YW | WX | XY | XYW |
+//! | --- | - | - | - | - | -- | -- | -- | --- |
+//! | 1   | 1 | 0 | 0 | 0 | 0  | 0  | 0  | 0   |
+//! | X   | 0 | 1 | 0 | 0 | 0  | 0  | 0  | 0   |
+//! | Y   | 0 | 0 | 1 | 0 | 0  | 0  | 0  | 0   |
+//! | W   | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0   |
+//! | YW  | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0   |
+//! | WX  | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0   |
+//! | XY  | 0 | 0 | 0 | 0 | 0  | 0  | -1 | 0   |
+//! | XYW | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0   |
+//!
+//!
+//! ### Commutator multiplication table
+//!
+//! |     | 1 | X   | Y   | W   | YW  | WX | XY  | XYW |
+//! | --- | - | --- | --- | --- | --- | -- | --- | --- |
+//! | 1   | 0 | 0   | 0   | 0   | 0   | 0  | 0   | 0   |
+//! | X   | 0 | 0   | XY  | -WX | 0   | -W | Y   | 0   |
+//! | Y   | 0 | -XY | 0   | YW  | W   | 0  | -X  | 0   |
+//! | W   | 0 | WX  | -YW | 0   | 0   | 0  | 0   | 0   |
+//! | YW  | 0 | 0   | -W  | 0   | 0   | 0  | WX  | 0   |
+//! | WX  | 0 | W   | 0   | 0   | 0   | 0  | -YW | 0   |
+//! | XY  | 0 | -Y  | X   | 0   | -WX | YW | 0   | 0   |
+//! | XYW | 0 | 0   | 0   | 0   | 0   | 0  | 0   | 0   |
+//!
+//!
+//! ### AntiDot multiplication table
+//!
+//! |     | 1   | X  | Y   | W    | YW  | WX  | XY | XYW |
+//! | --- | --- | -- | --- | ---- | --- | --- | -- | --- |
+//! | 1   | 0   | 0  | 0   | -XY  | X   | Y   | 0  | 1   |
+//! | X   | 0   | 0  | 0   | 0    | 0   | -XY | 0  | X   |
+//! | Y   | 0   | 0  | 0   | 0    | XY  | 0   | 0  | Y   |
+//! | W   | -XY | 0  | 0   | -XYW | -WX | YW  | 0  | W   |
+//! | YW  | X   | 0  | -XY | WX   | XYW | 0   | 0  | YW  |
+//! | WX  | Y   | XY | 0   | -YW  | 0   | XYW | 0  | WX  |
+//! | XY  | 0   | 0  | 0   | 0    | 0   | 0   | 0  | XY  |
+//! | XYW | 1   | X  | Y   | W    | YW  | WX  | XY | XYW |
+
+use derive_more::{Add, Neg, Sub};
 
 use super::*;
 
 /// The scalar type (real numbers).
 /// Squares to 1.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub, Mul)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct S(pub f64);
 
+impl std::ops::Mul<f64> for S {
+	type Output = S;
+	fn mul(self, rhs: f64) -> Self::Output {
+		S(self.0 * rhs)
+	}
+}
+
+impl Square for S {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for S {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for S {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for S {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for S {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 1.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct X(pub f64);
 
+impl std::ops::Mul<f64> for X {
+	type Output = X;
+	fn mul(self, rhs: f64) -> Self::Output {
+		X(self.0 * rhs)
+	}
+}
+
+impl Square for X {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for X {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for X {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for X {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for X {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 1.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Y(pub f64);
 
+impl std::ops::Mul<f64> for Y {
+	type Output = Y;
+	fn mul(self, rhs: f64) -> Self::Output {
+		Y(self.0 * rhs)
+	}
+}
+
+impl Square for Y {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Y {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Y {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Y {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Y {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 0.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct W(pub f64);
 
+impl std::ops::Mul<f64> for W {
+	type Output = W;
+	fn mul(self, rhs: f64) -> Self::Output {
+		W(self.0 * rhs)
+	}
+}
+
+impl Square for W {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for W {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for W {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for W {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for W {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 0.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct YW(pub f64);
 
+impl std::ops::Mul<f64> for YW {
+	type Output = YW;
+	fn mul(self, rhs: f64) -> Self::Output {
+		YW(self.0 * rhs)
+	}
+}
+
+impl Square for YW {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for YW {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for YW {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for YW {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for YW {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 0.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WX(pub f64);
 
+impl std::ops::Mul<f64> for WX {
+	type Output = WX;
+	fn mul(self, rhs: f64) -> Self::Output {
+		WX(self.0 * rhs)
+	}
+}
+
+impl Square for WX {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for WX {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for WX {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for WX {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for WX {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to -1.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XY(pub f64);
 
+impl std::ops::Mul<f64> for XY {
+	type Output = XY;
+	fn mul(self, rhs: f64) -> Self::Output {
+		XY(self.0 * rhs)
+	}
+}
+
+impl Square for XY {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for XY {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for XY {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for XY {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for XY {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// The pseudo-scalar.
 /// Squares to 0.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XYW(pub f64);
 
+impl std::ops::Mul<f64> for XYW {
+	type Output = XYW;
+	fn mul(self, rhs: f64) -> Self::Output {
+		XYW(self.0 * rhs)
+	}
+}
+
+impl Square for XYW {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for XYW {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for XYW {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for XYW {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for XYW {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 // ---------------------------------------------------------------------
 // impl RCompl for blades:
 
@@ -128,6 +589,13 @@ impl RCompl for S {
 	}
 }
 
+impl std::ops::Not for S {
+	type Output = XYW;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl RCompl for X {
 	type Output = YW;
 	fn rcompl(self) -> Self::Output {
@@ -135,6 +603,13 @@ impl RCompl for X {
 	}
 }
 
+impl std::ops::Not for X {
+	type Output = YW;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl RCompl for Y {
 	type Output = WX;
 	fn rcompl(self) -> Self::Output {
@@ -142,6 +617,13 @@ impl RCompl for Y {
 	}
 }
 
+impl std::ops::Not for Y {
+	type Output = WX;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl RCompl for W {
 	type Output = XY;
 	fn rcompl(self) -> Self::Output {
@@ -149,6 +631,13 @@ impl RCompl for W {
 	}
 }
 
+impl std::ops::Not for W {
+	type Output = XY;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl RCompl for YW {
 	type Output = X;
 	fn rcompl(self) -> Self::Output {
@@ -156,6 +645,13 @@ impl RCompl for YW {
 	}
 }
 
+impl std::ops::Not for YW {
+	type Output = X;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl RCompl for WX {
 	type Output = Y;
 	fn rcompl(self) -> Self::Output {
@@ -163,6 +659,13 @@ impl RCompl for WX {
 	}
 }
 
+impl std::ops::Not for WX {
+	type Output = Y;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl RCompl for XY {
 	type Output = W;
 	fn rcompl(self) -> Self::Output {
@@ -170,6 +673,13 @@ impl RCompl for XY {
 	}
 }
 
+impl std::ops::Not for XY {
+	type Output = W;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl RCompl for XYW {
 	type Output = S;
 	fn rcompl(self) -> Self::Output {
@@ -177,6 +687,13 @@ impl RCompl for XYW {
 	}
 }
 
+impl std::ops::Not for XYW {
+	type Output = S;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 // ---------------------------------------------------------------------
 // impl LCompl for blades:
 
@@ -339,19 +856,350 @@ impl AntiReverse for XYW {
 }
 
 // ---------------------------------------------------------------------
-// impl Geometric for blades:
+// impl Involute for blades:
 
-impl Geometric<S> for S {
-	type Output = S;
-	fn geometric(self, rhs: S) -> Self::Output {
-		S(self.0 * rhs.0)
+impl Involute for S {
+	fn involute(self) -> Self {
+		self
 	}
 }
 
-impl Geometric<X> for S {
-	type Output = X;
-	fn geometric(self, rhs: X) -> Self::Output {
-		X(self.0 * rhs.0)
+impl Involute for X {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for Y {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for W {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for YW {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for WX {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for XY {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for XYW {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Conjugate for blades:
+
+impl Conjugate for S {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for X {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for Y {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for W {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for YW {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for WX {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for XY {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for XYW {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Dual for blades:
+
+impl Dual for S {
+	type Output = XYW;
+	fn dual(self) -> Self::Output {
+		XYW(self.0)
+	}
+}
+
+impl Dual for X {
+	type Output = YW;
+	fn dual(self) -> Self::Output {
+		YW(self.0)
+	}
+}
+
+impl Dual for Y {
+	type Output = WX;
+	fn dual(self) -> Self::Output {
+		WX(self.0)
+	}
+}
+
+impl Dual for W {
+	type Output = XY;
+	fn dual(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+impl Dual for YW {
+	type Output = X;
+	fn dual(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl Dual for WX {
+	type Output = Y;
+	fn dual(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl Dual for XY {
+	type Output = W;
+	fn dual(self) -> Self::Output {
+		W(self.0)
+	}
+}
+
+impl Dual for XYW {
+	type Output = S;
+	fn dual(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Bulk for blades:
+
+impl Bulk for S {
+	type Output = S;
+	fn bulk(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+impl Bulk for X {
+	type Output = X;
+	fn bulk(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl Bulk for Y {
+	type Output = Y;
+	fn bulk(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+// Omitted: W.bulk() -> 0
+
+// Omitted: YW.bulk() -> 0
+
+// Omitted: WX.bulk() -> 0
+
+impl Bulk for XY {
+	type Output = XY;
+	fn bulk(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+// Omitted: XYW.bulk() -> 0
+
+// ---------------------------------------------------------------------
+// impl Weight for blades:
+
+// Omitted: S.weight() -> 0
+
+// Omitted: X.weight() -> 0
+
+// Omitted: Y.weight() -> 0
+
+impl Weight for W {
+	type Output = W;
+	fn weight(self) -> Self::Output {
+		W(self.0)
+	}
+}
+
+impl Weight for YW {
+	type Output = YW;
+	fn weight(self) -> Self::Output {
+		YW(self.0)
+	}
+}
+
+impl Weight for WX {
+	type Output = WX;
+	fn weight(self) -> Self::Output {
+		WX(self.0)
+	}
+}
+
+// Omitted: XY.weight() -> 0
+
+impl Weight for XYW {
+	type Output = XYW;
+	fn weight(self) -> Self::Output {
+		XYW(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl BulkDual for blades:
+
+impl BulkDual for S {
+	type Output = XYW;
+	fn bulk_dual(self) -> Self::Output {
+		XYW(self.0)
+	}
+}
+
+impl BulkDual for X {
+	type Output = YW;
+	fn bulk_dual(self) -> Self::Output {
+		YW(self.0)
+	}
+}
+
+impl BulkDual for Y {
+	type Output = WX;
+	fn bulk_dual(self) -> Self::Output {
+		WX(self.0)
+	}
+}
+
+// Omitted: W.bulk_dual() -> 0
+
+// Omitted: YW.bulk_dual() -> 0
+
+// Omitted: WX.bulk_dual() -> 0
+
+impl BulkDual for XY {
+	type Output = W;
+	fn bulk_dual(self) -> Self::Output {
+		W(self.0)
+	}
+}
+
+// Omitted: XYW.bulk_dual() -> 0
+
+// ---------------------------------------------------------------------
+// impl WeightDual for blades:
+
+// Omitted: S.weight_dual() -> 0
+
+// Omitted: X.weight_dual() -> 0
+
+// Omitted: Y.weight_dual() -> 0
+
+impl WeightDual for W {
+	type Output = XY;
+	fn weight_dual(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+impl WeightDual for YW {
+	type Output = X;
+	fn weight_dual(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl WeightDual for WX {
+	type Output = Y;
+	fn weight_dual(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+// Omitted: XY.weight_dual() -> 0
+
+impl WeightDual for XYW {
+	type Output = S;
+	fn weight_dual(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Geometric for blades:
+
+impl Geometric<S> for S {
+	type Output = S;
+	fn geometric(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for S {
+	type Output = S;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for S {
+	type Output = X;
+	fn geometric(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for S {
+	type Output = X;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
 	}
 }
 
@@ -362,6 +1210,13 @@ impl Geometric<Y> for S {
 	}
 }
 
+impl std::ops::Mul<Y> for S {
+	type Output = Y;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
 impl Geometric<W> for S {
 	type Output = W;
 	fn geometric(self, rhs: W) -> Self::Output {
@@ -369,6 +1224,13 @@ impl Geometric<W> for S {
 	}
 }
 
+impl std::ops::Mul<W> for S {
+	type Output = W;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
 impl Geometric<YW> for S {
 	type Output = YW;
 	fn geometric(self, rhs: YW) -> Self::Output {
@@ -376,6 +1238,13 @@ impl Geometric<YW> for S {
 	}
 }
 
+impl std::ops::Mul<YW> for S {
+	type Output = YW;
+	fn mul(self, rhs: YW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
 impl Geometric<WX> for S {
 	type Output = WX;
 	fn geometric(self, rhs: WX) -> Self::Output {
@@ -383,6 +1252,13 @@ impl Geometric<WX> for S {
 	}
 }
 
+impl std::ops::Mul<WX> for S {
+	type Output = WX;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
 impl Geometric<XY> for S {
 	type Output = XY;
 	fn geometric(self, rhs: XY) -> Self::Output {
@@ -390,6 +1266,13 @@ impl Geometric<XY> for S {
 	}
 }
 
+impl std::ops::Mul<XY> for S {
+	type Output = XY;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
 impl Geometric<XYW> for S {
 	type Output = XYW;
 	fn geometric(self, rhs: XYW) -> Self::Output {
@@ -397,6 +1280,13 @@ impl Geometric<XYW> for S {
 	}
 }
 
+impl std::ops::Mul<XYW> for S {
+	type Output = XYW;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
 impl Geometric<S> for X {
 	type Output = X;
 	fn geometric(self, rhs: S) -> Self::Output {
@@ -404,2191 +1294,6182 @@ impl Geometric<S> for X {
 	}
 }
 
-impl Geometric<X> for X {
+impl std::ops::Mul<S> for X {
+	type Output = X;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for X {
+	type Output = S;
+	fn geometric(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for X {
+	type Output = S;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for X {
+	type Output = XY;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for X {
+	type Output = XY;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for X {
+	type Output = WX;
+	fn geometric(self, rhs: W) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for X {
+	type Output = WX;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YW> for X {
+	type Output = XYW;
+	fn geometric(self, rhs: YW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YW> for X {
+	type Output = XYW;
+	fn mul(self, rhs: YW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for X {
+	type Output = W;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for X {
+	type Output = W;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for X {
+	type Output = Y;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for X {
+	type Output = Y;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for X {
+	type Output = YW;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for X {
+	type Output = YW;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for Y {
+	type Output = Y;
+	fn geometric(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for Y {
+	type Output = Y;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for Y {
+	type Output = XY;
+	fn geometric(self, rhs: X) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for Y {
+	type Output = XY;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for Y {
+	type Output = S;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for Y {
+	type Output = S;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for Y {
+	type Output = YW;
+	fn geometric(self, rhs: W) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for Y {
+	type Output = YW;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YW> for Y {
+	type Output = W;
+	fn geometric(self, rhs: YW) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YW> for Y {
+	type Output = W;
+	fn mul(self, rhs: YW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for Y {
+	type Output = XYW;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for Y {
+	type Output = XYW;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for Y {
+	type Output = X;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for Y {
+	type Output = X;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for Y {
+	type Output = WX;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for Y {
+	type Output = WX;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for W {
+	type Output = W;
+	fn geometric(self, rhs: S) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for W {
+	type Output = W;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for W {
+	type Output = WX;
+	fn geometric(self, rhs: X) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for W {
+	type Output = WX;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for W {
+	type Output = YW;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		YW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for W {
+	type Output = YW;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for W {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YW> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YW> for W {
+	type Output = Zero;
+	fn mul(self, rhs: YW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for W {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for W {
+	type Output = XYW;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for W {
+	type Output = XYW;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for W {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for YW {
+	type Output = YW;
+	fn geometric(self, rhs: S) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for YW {
+	type Output = YW;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for YW {
+	type Output = XYW;
+	fn geometric(self, rhs: X) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for YW {
+	type Output = XYW;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for YW {
+	type Output = W;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for YW {
+	type Output = W;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for YW {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for YW {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YW> for YW {
+	type Output = Zero;
+	fn geometric(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YW> for YW {
+	type Output = Zero;
+	fn mul(self, rhs: YW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for YW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for YW {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for YW {
+	type Output = WX;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for YW {
+	type Output = WX;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for YW {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for YW {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for WX {
+	type Output = WX;
+	fn geometric(self, rhs: S) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for WX {
+	type Output = WX;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for WX {
+	type Output = W;
+	fn geometric(self, rhs: X) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for WX {
+	type Output = W;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for WX {
+	type Output = XYW;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for WX {
+	type Output = XYW;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YW> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YW> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: YW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for WX {
+	type Output = YW;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		YW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for WX {
+	type Output = YW;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for XY {
+	type Output = XY;
+	fn geometric(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for XY {
+	type Output = XY;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for XY {
+	type Output = Y;
+	fn geometric(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for XY {
+	type Output = Y;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for XY {
+	type Output = X;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for XY {
+	type Output = X;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for XY {
+	type Output = XYW;
+	fn geometric(self, rhs: W) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for XY {
+	type Output = XYW;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YW> for XY {
+	type Output = WX;
+	fn geometric(self, rhs: YW) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YW> for XY {
+	type Output = WX;
+	fn mul(self, rhs: YW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for XY {
+	type Output = YW;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for XY {
+	type Output = YW;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for XY {
+	type Output = S;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for XY {
+	type Output = S;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for XY {
+	type Output = W;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for XY {
+	type Output = W;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for XYW {
+	type Output = XYW;
+	fn geometric(self, rhs: S) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for XYW {
+	type Output = XYW;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for XYW {
+	type Output = YW;
+	fn geometric(self, rhs: X) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for XYW {
+	type Output = YW;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for XYW {
+	type Output = WX;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for XYW {
+	type Output = WX;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YW> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YW> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: YW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for XYW {
+	type Output = W;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for XYW {
+	type Output = W;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiGeometric for blades:
+
+impl AntiGeometric<S> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for S {
+	type Output = XY;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YW> for S {
+	type Output = X;
+	fn anti_geometric(self, rhs: YW) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for S {
+	type Output = Y;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYW> for S {
+	type Output = S;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for X {
+	type Output = Y;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YW> for X {
+	type Output = S;
+	fn anti_geometric(self, rhs: YW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for X {
+	type Output = XY;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYW> for X {
+	type Output = X;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for Y {
+	type Output = X;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YW> for Y {
+	type Output = XY;
+	fn anti_geometric(self, rhs: YW) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for Y {
+	type Output = S;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYW> for Y {
+	type Output = Y;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for W {
+	type Output = XY;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for W {
+	type Output = Y;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for W {
+	type Output = X;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for W {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YW> for W {
+	type Output = WX;
+	fn anti_geometric(self, rhs: YW) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for W {
+	type Output = YW;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for W {
+	type Output = S;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for W {
+	type Output = W;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for YW {
+	type Output = X;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for YW {
+	type Output = S;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for YW {
+	type Output = XY;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for YW {
+	type Output = WX;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YW> for YW {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: YW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for YW {
+	type Output = W;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for YW {
+	type Output = Y;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for YW {
+	type Output = YW;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for WX {
+	type Output = Y;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for WX {
+	type Output = XY;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for WX {
+	type Output = S;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for WX {
+	type Output = YW;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		YW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YW> for WX {
+	type Output = W;
+	fn anti_geometric(self, rhs: YW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for WX {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for WX {
+	type Output = X;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for WX {
+	type Output = WX;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for XY {
+	type Output = S;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YW> for XY {
+	type Output = Y;
+	fn anti_geometric(self, rhs: YW) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for XY {
+	type Output = X;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYW> for XY {
+	type Output = XY;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for XYW {
+	type Output = S;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for XYW {
+	type Output = X;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for XYW {
+	type Output = Y;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for XYW {
+	type Output = W;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YW> for XYW {
+	type Output = YW;
+	fn anti_geometric(self, rhs: YW) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for XYW {
+	type Output = WX;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for XYW {
+	type Output = XY;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for XYW {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Dot for blades:
+
+impl Dot<S> for S {
+	type Output = S;
+	fn dot(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for S {
+	type Output = S;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for S {
+	type Output = X;
+	fn dot(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for S {
+	type Output = X;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for S {
+	type Output = Y;
+	fn dot(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for S {
+	type Output = Y;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for S {
+	type Output = W;
+	fn dot(self, rhs: W) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<W> for S {
+	type Output = W;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YW> for S {
+	type Output = YW;
+	fn dot(self, rhs: YW) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YW> for S {
+	type Output = YW;
+	fn bitor(self, rhs: YW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for S {
+	type Output = WX;
+	fn dot(self, rhs: WX) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<WX> for S {
+	type Output = WX;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for S {
+	type Output = XY;
+	fn dot(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for S {
+	type Output = XY;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for S {
+	type Output = XYW;
+	fn dot(self, rhs: XYW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYW> for S {
+	type Output = XYW;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for X {
+	type Output = X;
+	fn dot(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for X {
+	type Output = X;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for X {
+	type Output = S;
+	fn dot(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for X {
+	type Output = S;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YW> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YW> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: YW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for X {
+	type Output = W;
+	fn dot(self, rhs: WX) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<WX> for X {
+	type Output = W;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for X {
+	type Output = Y;
+	fn dot(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for X {
+	type Output = Y;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for X {
+	type Output = YW;
+	fn dot(self, rhs: XYW) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYW> for X {
+	type Output = YW;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for Y {
+	type Output = Y;
+	fn dot(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for Y {
+	type Output = Y;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for Y {
+	type Output = S;
+	fn dot(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for Y {
+	type Output = S;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YW> for Y {
+	type Output = W;
+	fn dot(self, rhs: YW) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YW> for Y {
+	type Output = W;
+	fn bitor(self, rhs: YW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for Y {
+	type Output = X;
+	fn dot(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for Y {
+	type Output = X;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for Y {
+	type Output = WX;
+	fn dot(self, rhs: XYW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYW> for Y {
+	type Output = WX;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for W {
+	type Output = W;
+	fn dot(self, rhs: S) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for W {
+	type Output = W;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YW> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YW> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: YW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for YW {
+	type Output = YW;
+	fn dot(self, rhs: S) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for YW {
+	type Output = YW;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for YW {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for YW {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for YW {
+	type Output = W;
+	fn dot(self, rhs: Y) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for YW {
+	type Output = W;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for YW {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for YW {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YW> for YW {
+	type Output = Zero;
+	fn dot(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YW> for YW {
+	type Output = Zero;
+	fn bitor(self, rhs: YW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for YW {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for YW {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for YW {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for YW {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for YW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for YW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for WX {
+	type Output = WX;
+	fn dot(self, rhs: S) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for WX {
+	type Output = WX;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for WX {
+	type Output = W;
+	fn dot(self, rhs: X) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for WX {
+	type Output = W;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YW> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YW> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: YW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for XY {
+	type Output = XY;
+	fn dot(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for XY {
+	type Output = XY;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for XY {
+	type Output = Y;
+	fn dot(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for XY {
+	type Output = Y;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for XY {
+	type Output = X;
+	fn dot(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for XY {
+	type Output = X;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YW> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YW> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: YW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for XY {
+	type Output = S;
+	fn dot(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for XY {
+	type Output = S;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for XY {
+	type Output = W;
+	fn dot(self, rhs: XYW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYW> for XY {
+	type Output = W;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for XYW {
+	type Output = XYW;
+	fn dot(self, rhs: S) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for XYW {
+	type Output = XYW;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for XYW {
+	type Output = YW;
+	fn dot(self, rhs: X) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for XYW {
+	type Output = YW;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for XYW {
+	type Output = WX;
+	fn dot(self, rhs: Y) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for XYW {
+	type Output = WX;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YW> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YW> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: YW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for XYW {
+	type Output = W;
+	fn dot(self, rhs: XY) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for XYW {
+	type Output = W;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Wedge for blades:
+
+impl Wedge<S> for S {
+	type Output = S;
+	fn wedge(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for S {
+	type Output = S;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for S {
+	type Output = X;
+	fn wedge(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for S {
+	type Output = X;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for S {
+	type Output = Y;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for S {
+	type Output = Y;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for S {
+	type Output = W;
+	fn wedge(self, rhs: W) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for S {
+	type Output = W;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YW> for S {
+	type Output = YW;
+	fn wedge(self, rhs: YW) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<YW> for S {
+	type Output = YW;
+	fn bitxor(self, rhs: YW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for S {
+	type Output = WX;
+	fn wedge(self, rhs: WX) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WX> for S {
+	type Output = WX;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for S {
+	type Output = XY;
+	fn wedge(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XY> for S {
+	type Output = XY;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for S {
+	type Output = XYW;
+	fn wedge(self, rhs: XYW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XYW> for S {
+	type Output = XYW;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for X {
+	type Output = X;
+	fn wedge(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for X {
+	type Output = X;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for X {
+	type Output = XY;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for X {
+	type Output = XY;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for X {
+	type Output = WX;
+	fn wedge(self, rhs: W) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for X {
+	type Output = WX;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YW> for X {
+	type Output = XYW;
+	fn wedge(self, rhs: YW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<YW> for X {
+	type Output = XYW;
+	fn bitxor(self, rhs: YW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for Y {
+	type Output = Y;
+	fn wedge(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for Y {
+	type Output = Y;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for Y {
+	type Output = XY;
+	fn wedge(self, rhs: X) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for Y {
+	type Output = XY;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for Y {
+	type Output = YW;
+	fn wedge(self, rhs: W) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for Y {
+	type Output = YW;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YW> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YW> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: YW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for Y {
+	type Output = XYW;
+	fn wedge(self, rhs: WX) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WX> for Y {
+	type Output = XYW;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for W {
+	type Output = W;
+	fn wedge(self, rhs: S) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for W {
+	type Output = W;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for W {
+	type Output = WX;
+	fn wedge(self, rhs: X) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for W {
+	type Output = WX;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for W {
+	type Output = YW;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		YW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for W {
+	type Output = YW;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YW> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YW> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: YW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for W {
+	type Output = XYW;
+	fn wedge(self, rhs: XY) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XY> for W {
+	type Output = XYW;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for YW {
+	type Output = YW;
+	fn wedge(self, rhs: S) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for YW {
+	type Output = YW;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for YW {
+	type Output = XYW;
+	fn wedge(self, rhs: X) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for YW {
+	type Output = XYW;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for YW {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for YW {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for YW {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for YW {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YW> for YW {
+	type Output = Zero;
+	fn wedge(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YW> for YW {
+	type Output = Zero;
+	fn bitxor(self, rhs: YW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for YW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for YW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for YW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for YW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for YW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for YW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for WX {
+	type Output = WX;
+	fn wedge(self, rhs: S) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for WX {
+	type Output = WX;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for WX {
+	type Output = XYW;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for WX {
+	type Output = XYW;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YW> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YW> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: YW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for XY {
+	type Output = XY;
+	fn wedge(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for XY {
+	type Output = XY;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for XY {
+	type Output = XYW;
+	fn wedge(self, rhs: W) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for XY {
+	type Output = XYW;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YW> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YW> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: YW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for XYW {
+	type Output = XYW;
+	fn wedge(self, rhs: S) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for XYW {
+	type Output = XYW;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YW> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YW> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: YW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiWedge for blades:
+
+impl AntiWedge<S> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YW> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YW> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: YW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for S {
+	type Output = S;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for S {
+	type Output = S;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YW> for X {
+	type Output = S;
+	fn anti_wedge(self, rhs: YW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YW> for X {
+	type Output = S;
+	fn bitand(self, rhs: YW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for X {
+	type Output = X;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for X {
+	type Output = X;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YW> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YW> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: YW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for Y {
+	type Output = S;
+	fn anti_wedge(self, rhs: WX) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<WX> for Y {
+	type Output = S;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for Y {
+	type Output = Y;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for Y {
+	type Output = Y;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YW> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YW> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: YW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for W {
+	type Output = S;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for W {
+	type Output = S;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for W {
+	type Output = W;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for W {
+	type Output = W;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for YW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for YW {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for YW {
+	type Output = S;
+	fn anti_wedge(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<X> for YW {
+	type Output = S;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for YW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for YW {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for YW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for YW {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YW> for YW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YW> for YW {
+	type Output = Zero;
+	fn bitand(self, rhs: YW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for YW {
+	type Output = W;
+	fn anti_wedge(self, rhs: WX) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<WX> for YW {
+	type Output = W;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for YW {
+	type Output = Y;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for YW {
+	type Output = Y;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for YW {
+	type Output = YW;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for YW {
+	type Output = YW;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for WX {
+	type Output = S;
+	fn anti_wedge(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<Y> for WX {
+	type Output = S;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YW> for WX {
+	type Output = W;
+	fn anti_wedge(self, rhs: YW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YW> for WX {
+	type Output = W;
+	fn bitand(self, rhs: YW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for WX {
+	type Output = X;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for WX {
+	type Output = X;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for WX {
+	type Output = WX;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for WX {
+	type Output = WX;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for XY {
+	type Output = S;
+	fn anti_wedge(self, rhs: W) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<W> for XY {
+	type Output = S;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YW> for XY {
+	type Output = Y;
+	fn anti_wedge(self, rhs: YW) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YW> for XY {
+	type Output = Y;
+	fn bitand(self, rhs: YW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for XY {
+	type Output = X;
+	fn anti_wedge(self, rhs: WX) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<WX> for XY {
+	type Output = X;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for XY {
+	type Output = XY;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for XY {
+	type Output = XY;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for XYW {
+	type Output = S;
+	fn anti_wedge(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<S> for XYW {
+	type Output = S;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for XYW {
+	type Output = X;
+	fn anti_wedge(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<X> for XYW {
+	type Output = X;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for XYW {
+	type Output = Y;
+	fn anti_wedge(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<Y> for XYW {
+	type Output = Y;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for XYW {
+	type Output = W;
+	fn anti_wedge(self, rhs: W) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<W> for XYW {
+	type Output = W;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YW> for XYW {
+	type Output = YW;
+	fn anti_wedge(self, rhs: YW) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YW> for XYW {
+	type Output = YW;
+	fn bitand(self, rhs: YW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for XYW {
+	type Output = WX;
+	fn anti_wedge(self, rhs: WX) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<WX> for XYW {
+	type Output = WX;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for XYW {
+	type Output = XY;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for XYW {
+	type Output = XY;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for XYW {
+	type Output = XYW;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for XYW {
+	type Output = XYW;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl LeftContraction for blades:
+
+impl LeftContraction<S> for S {
+	type Output = S;
+	fn left_contraction(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<X> for S {
+	type Output = X;
+	fn left_contraction(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<Y> for S {
+	type Output = Y;
+	fn left_contraction(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<W> for S {
+	type Output = W;
+	fn left_contraction(self, rhs: W) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<YW> for S {
+	type Output = YW;
+	fn left_contraction(self, rhs: YW) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<WX> for S {
+	type Output = WX;
+	fn left_contraction(self, rhs: WX) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<XY> for S {
+	type Output = XY;
+	fn left_contraction(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<XYW> for S {
+	type Output = XYW;
+	fn left_contraction(self, rhs: XYW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<X> for X {
 	type Output = S;
-	fn geometric(self, rhs: X) -> Self::Output {
+	fn left_contraction(self, rhs: X) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<Y> for X {
-	type Output = XY;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl LeftContraction<Y> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for X {
-	type Output = WX;
-	fn geometric(self, rhs: W) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl LeftContraction<W> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YW> for X {
-	type Output = XYW;
-	fn geometric(self, rhs: YW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<YW> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WX> for X {
+impl LeftContraction<WX> for X {
 	type Output = W;
-	fn geometric(self, rhs: WX) -> Self::Output {
+	fn left_contraction(self, rhs: WX) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XY> for X {
+impl LeftContraction<XY> for X {
 	type Output = Y;
-	fn geometric(self, rhs: XY) -> Self::Output {
+	fn left_contraction(self, rhs: XY) -> Self::Output {
 		Y(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYW> for X {
+impl LeftContraction<XYW> for X {
 	type Output = YW;
-	fn geometric(self, rhs: XYW) -> Self::Output {
+	fn left_contraction(self, rhs: XYW) -> Self::Output {
 		YW(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for Y {
-	type Output = Y;
-	fn geometric(self, rhs: S) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl LeftContraction<S> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for Y {
-	type Output = XY;
-	fn geometric(self, rhs: X) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl LeftContraction<X> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for Y {
+impl LeftContraction<Y> for Y {
 	type Output = S;
-	fn geometric(self, rhs: Y) -> Self::Output {
+	fn left_contraction(self, rhs: Y) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<W> for Y {
-	type Output = YW;
-	fn geometric(self, rhs: W) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl LeftContraction<W> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YW> for Y {
+impl LeftContraction<YW> for Y {
 	type Output = W;
-	fn geometric(self, rhs: YW) -> Self::Output {
+	fn left_contraction(self, rhs: YW) -> Self::Output {
 		W(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<WX> for Y {
-	type Output = XYW;
-	fn geometric(self, rhs: WX) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<WX> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for Y {
+impl LeftContraction<XY> for Y {
 	type Output = X;
-	fn geometric(self, rhs: XY) -> Self::Output {
+	fn left_contraction(self, rhs: XY) -> Self::Output {
 		X(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYW> for Y {
+impl LeftContraction<XYW> for Y {
 	type Output = WX;
-	fn geometric(self, rhs: XYW) -> Self::Output {
+	fn left_contraction(self, rhs: XYW) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for W {
-	type Output = W;
-	fn geometric(self, rhs: S) -> Self::Output {
-		W(self.0 * rhs.0)
+impl LeftContraction<S> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for W {
-	type Output = WX;
-	fn geometric(self, rhs: X) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl LeftContraction<X> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for W {
-	type Output = YW;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		YW(-self.0 * rhs.0)
+impl LeftContraction<Y> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for W {
+impl LeftContraction<W> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YW> for W {
+impl LeftContraction<YW> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: YW) -> Self::Output {
+	fn left_contraction(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for W {
+impl LeftContraction<WX> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XY> for W {
-	type Output = XYW;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<XY> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYW> for W {
+impl LeftContraction<XYW> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<S> for YW {
-	type Output = YW;
-	fn geometric(self, rhs: S) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl LeftContraction<S> for YW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for YW {
-	type Output = XYW;
-	fn geometric(self, rhs: X) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<X> for YW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for YW {
-	type Output = W;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl LeftContraction<Y> for YW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for YW {
+impl LeftContraction<W> for YW {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YW> for YW {
+impl LeftContraction<YW> for YW {
 	type Output = Zero;
-	fn geometric(self, _rhs: YW) -> Self::Output {
+	fn left_contraction(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for YW {
+impl LeftContraction<WX> for YW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XY> for YW {
-	type Output = WX;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl LeftContraction<XY> for YW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYW> for YW {
+impl LeftContraction<XYW> for YW {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<S> for WX {
-	type Output = WX;
-	fn geometric(self, rhs: S) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl LeftContraction<S> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for WX {
-	type Output = W;
-	fn geometric(self, rhs: X) -> Self::Output {
-		W(self.0 * rhs.0)
+impl LeftContraction<X> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for WX {
-	type Output = XYW;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<Y> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for WX {
+impl LeftContraction<W> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YW> for WX {
+impl LeftContraction<YW> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: YW) -> Self::Output {
+	fn left_contraction(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for WX {
+impl LeftContraction<WX> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XY> for WX {
-	type Output = YW;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		YW(-self.0 * rhs.0)
+impl LeftContraction<XY> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYW> for WX {
+impl LeftContraction<XYW> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<S> for XY {
-	type Output = XY;
-	fn geometric(self, rhs: S) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl LeftContraction<S> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for XY {
-	type Output = Y;
-	fn geometric(self, rhs: X) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl LeftContraction<X> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for XY {
-	type Output = X;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		X(self.0 * rhs.0)
+impl LeftContraction<Y> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for XY {
-	type Output = XYW;
-	fn geometric(self, rhs: W) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<W> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YW> for XY {
-	type Output = WX;
-	fn geometric(self, rhs: YW) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl LeftContraction<YW> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WX> for XY {
-	type Output = YW;
-	fn geometric(self, rhs: WX) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl LeftContraction<WX> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for XY {
+impl LeftContraction<XY> for XY {
 	type Output = S;
-	fn geometric(self, rhs: XY) -> Self::Output {
+	fn left_contraction(self, rhs: XY) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYW> for XY {
+impl LeftContraction<XYW> for XY {
 	type Output = W;
-	fn geometric(self, rhs: XYW) -> Self::Output {
+	fn left_contraction(self, rhs: XYW) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for XYW {
-	type Output = XYW;
-	fn geometric(self, rhs: S) -> Self::Output {
-		XYW(self.0 * rhs.0)
-	}
-}
-
-impl Geometric<X> for XYW {
-	type Output = YW;
-	fn geometric(self, rhs: X) -> Self::Output {
-		YW(self.0 * rhs.0)
-	}
-}
-
-impl Geometric<Y> for XYW {
-	type Output = WX;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		WX(self.0 * rhs.0)
-	}
-}
-
-impl Geometric<W> for XYW {
+impl LeftContraction<S> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YW> for XYW {
+impl LeftContraction<X> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: YW) -> Self::Output {
+	fn left_contraction(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for XYW {
+impl LeftContraction<Y> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XY> for XYW {
-	type Output = W;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl LeftContraction<W> for XYW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYW> for XYW {
+impl LeftContraction<YW> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-// ---------------------------------------------------------------------
-// impl AntiGeometric for blades:
-
-impl AntiGeometric<S> for S {
+impl LeftContraction<WX> for XYW {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for S {
+impl LeftContraction<XY> for XYW {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for S {
+impl LeftContraction<XYW> for XYW {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for S {
-	type Output = XY;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		XY(-self.0 * rhs.0)
+// ---------------------------------------------------------------------
+// impl RightContraction for blades:
+
+impl RightContraction<S> for S {
+	type Output = S;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YW> for S {
-	type Output = X;
-	fn anti_geometric(self, rhs: YW) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<X> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for S {
-	type Output = Y;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<Y> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for S {
+impl RightContraction<W> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for S {
-	type Output = S;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<YW> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for X {
+impl RightContraction<WX> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for X {
+impl RightContraction<XY> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for X {
+impl RightContraction<XYW> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for X {
-	type Output = Y;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<S> for X {
+	type Output = X;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YW> for X {
+impl RightContraction<X> for X {
 	type Output = S;
-	fn anti_geometric(self, rhs: YW) -> Self::Output {
+	fn right_contraction(self, rhs: X) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WX> for X {
-	type Output = XY;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl RightContraction<Y> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for X {
+impl RightContraction<W> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for X {
-	type Output = X;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<YW> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for Y {
+impl RightContraction<WX> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for Y {
+impl RightContraction<XY> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for Y {
+impl RightContraction<XYW> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for Y {
-	type Output = X;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl RightContraction<S> for Y {
+	type Output = Y;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YW> for Y {
-	type Output = XY;
-	fn anti_geometric(self, rhs: YW) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<X> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for Y {
+impl RightContraction<Y> for Y {
 	type Output = S;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
+	fn right_contraction(self, rhs: Y) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XY> for Y {
+impl RightContraction<W> for Y {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for Y {
-	type Output = Y;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<YW> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for W {
-	type Output = XY;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl RightContraction<WX> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for W {
-	type Output = Y;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl RightContraction<XY> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for W {
-	type Output = X;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<XYW> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for W {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl RightContraction<S> for W {
+	type Output = W;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YW> for W {
-	type Output = WX;
-	fn anti_geometric(self, rhs: YW) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl RightContraction<X> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for W {
-	type Output = YW;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl RightContraction<Y> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for W {
-	type Output = S;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<W> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for W {
-	type Output = W;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		W(self.0 * rhs.0)
+impl RightContraction<YW> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for YW {
-	type Output = X;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<WX> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for YW {
-	type Output = S;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<XY> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for YW {
-	type Output = XY;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl RightContraction<XYW> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for YW {
-	type Output = WX;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl RightContraction<S> for YW {
+	type Output = YW;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		YW(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YW> for YW {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: YW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl RightContraction<X> for YW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for YW {
+impl RightContraction<Y> for YW {
 	type Output = W;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		W(self.0 * rhs.0)
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		W(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XY> for YW {
-	type Output = Y;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl RightContraction<W> for YW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for YW {
-	type Output = YW;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl RightContraction<YW> for YW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for WX {
-	type Output = Y;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<WX> for YW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for WX {
-	type Output = XY;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<XY> for YW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for WX {
-	type Output = S;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<XYW> for YW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for WX {
-	type Output = YW;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		YW(-self.0 * rhs.0)
+impl RightContraction<S> for WX {
+	type Output = WX;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		WX(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YW> for WX {
+impl RightContraction<X> for WX {
 	type Output = W;
-	fn anti_geometric(self, rhs: YW) -> Self::Output {
-		W(-self.0 * rhs.0)
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WX> for WX {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl RightContraction<Y> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for WX {
-	type Output = X;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<W> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for WX {
-	type Output = WX;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl RightContraction<YW> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for XY {
+impl RightContraction<WX> for WX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for XY {
+impl RightContraction<XY> for WX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for XY {
+impl RightContraction<XYW> for WX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for XY {
-	type Output = S;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<S> for XY {
+	type Output = XY;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YW> for XY {
+impl RightContraction<X> for XY {
 	type Output = Y;
-	fn anti_geometric(self, rhs: YW) -> Self::Output {
-		Y(self.0 * rhs.0)
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WX> for XY {
+impl RightContraction<Y> for XY {
 	type Output = X;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		X(-self.0 * rhs.0)
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XY> for XY {
+impl RightContraction<W> for XY {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for XY {
-	type Output = XY;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<YW> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for XYW {
-	type Output = S;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<WX> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for XYW {
-	type Output = X;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<XY> for XY {
+	type Output = S;
+	fn right_contraction(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
 	}
-}
-
-impl AntiGeometric<Y> for XYW {
-	type Output = Y;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		Y(self.0 * rhs.0)
+}
+
+impl RightContraction<XYW> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for XYW {
-	type Output = W;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		W(self.0 * rhs.0)
+impl RightContraction<S> for XYW {
+	type Output = XYW;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YW> for XYW {
+impl RightContraction<X> for XYW {
 	type Output = YW;
-	fn anti_geometric(self, rhs: YW) -> Self::Output {
+	fn right_contraction(self, rhs: X) -> Self::Output {
 		YW(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WX> for XYW {
+impl RightContraction<Y> for XYW {
 	type Output = WX;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
+	fn right_contraction(self, rhs: Y) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XY> for XYW {
-	type Output = XY;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<W> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for XYW {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl RightContraction<YW> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<WX> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XY> for XYW {
+	type Output = W;
+	fn right_contraction(self, rhs: XY) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<XYW> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
 // ---------------------------------------------------------------------
-// impl Dot for blades:
+// impl ScalarProduct for blades:
 
-impl Dot<S> for S {
+impl ScalarProduct<S> for S {
 	type Output = S;
-	fn dot(self, rhs: S) -> Self::Output {
+	fn scalar_product(self, rhs: S) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Dot<X> for S {
-	type Output = X;
-	fn dot(self, rhs: X) -> Self::Output {
-		X(self.0 * rhs.0)
+impl ScalarProduct<X> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for S {
-	type Output = Y;
-	fn dot(self, rhs: Y) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl ScalarProduct<Y> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for S {
-	type Output = W;
-	fn dot(self, rhs: W) -> Self::Output {
-		W(self.0 * rhs.0)
+impl ScalarProduct<W> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<YW> for S {
-	type Output = YW;
-	fn dot(self, rhs: YW) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl ScalarProduct<YW> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<WX> for S {
-	type Output = WX;
-	fn dot(self, rhs: WX) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl ScalarProduct<WX> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XY> for S {
-	type Output = XY;
-	fn dot(self, rhs: XY) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl ScalarProduct<XY> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYW> for S {
-	type Output = XYW;
-	fn dot(self, rhs: XYW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl ScalarProduct<XYW> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for X {
-	type Output = X;
-	fn dot(self, rhs: S) -> Self::Output {
-		X(self.0 * rhs.0)
+impl ScalarProduct<S> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for X {
+impl ScalarProduct<X> for X {
 	type Output = S;
-	fn dot(self, rhs: X) -> Self::Output {
+	fn scalar_product(self, rhs: X) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Dot<Y> for X {
+impl ScalarProduct<Y> for X {
 	type Output = Zero;
-	fn dot(self, _rhs: Y) -> Self::Output {
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<W> for X {
+impl ScalarProduct<W> for X {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YW> for X {
+impl ScalarProduct<YW> for X {
 	type Output = Zero;
-	fn dot(self, _rhs: YW) -> Self::Output {
+	fn scalar_product(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for X {
-	type Output = W;
-	fn dot(self, rhs: WX) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<WX> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XY> for X {
-	type Output = Y;
-	fn dot(self, rhs: XY) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl ScalarProduct<XY> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYW> for X {
-	type Output = YW;
-	fn dot(self, rhs: XYW) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl ScalarProduct<XYW> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for Y {
-	type Output = Y;
-	fn dot(self, rhs: S) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl ScalarProduct<S> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for Y {
+impl ScalarProduct<X> for Y {
 	type Output = Zero;
-	fn dot(self, _rhs: X) -> Self::Output {
+	fn scalar_product(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Y> for Y {
+impl ScalarProduct<Y> for Y {
 	type Output = S;
-	fn dot(self, rhs: Y) -> Self::Output {
+	fn scalar_product(self, rhs: Y) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Dot<W> for Y {
+impl ScalarProduct<W> for Y {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YW> for Y {
-	type Output = W;
-	fn dot(self, rhs: YW) -> Self::Output {
-		W(self.0 * rhs.0)
+impl ScalarProduct<YW> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<WX> for Y {
+impl ScalarProduct<WX> for Y {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for Y {
-	type Output = X;
-	fn dot(self, rhs: XY) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl ScalarProduct<XY> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYW> for Y {
-	type Output = WX;
-	fn dot(self, rhs: XYW) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl ScalarProduct<XYW> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for W {
-	type Output = W;
-	fn dot(self, rhs: S) -> Self::Output {
-		W(self.0 * rhs.0)
+impl ScalarProduct<S> for W {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for W {
+impl ScalarProduct<X> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: X) -> Self::Output {
+	fn scalar_product(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Y> for W {
+impl ScalarProduct<Y> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: Y) -> Self::Output {
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<W> for W {
+impl ScalarProduct<W> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YW> for W {
+impl ScalarProduct<YW> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: YW) -> Self::Output {
+	fn scalar_product(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for W {
+impl ScalarProduct<WX> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for W {
+impl ScalarProduct<XY> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for W {
+impl ScalarProduct<XYW> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<S> for YW {
-	type Output = YW;
-	fn dot(self, rhs: S) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl ScalarProduct<S> for YW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for YW {
+impl ScalarProduct<X> for YW {
 	type Output = Zero;
-	fn dot(self, _rhs: X) -> Self::Output {
+	fn scalar_product(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Y> for YW {
-	type Output = W;
-	fn dot(self, rhs: Y) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<Y> for YW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for YW {
+impl ScalarProduct<W> for YW {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YW> for YW {
+impl ScalarProduct<YW> for YW {
 	type Output = Zero;
-	fn dot(self, _rhs: YW) -> Self::Output {
+	fn scalar_product(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for YW {
+impl ScalarProduct<WX> for YW {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for YW {
+impl ScalarProduct<XY> for YW {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for YW {
+impl ScalarProduct<XYW> for YW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<S> for WX {
-	type Output = WX;
-	fn dot(self, rhs: S) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl ScalarProduct<S> for WX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for WX {
-	type Output = W;
-	fn dot(self, rhs: X) -> Self::Output {
-		W(self.0 * rhs.0)
+impl ScalarProduct<X> for WX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for WX {
+impl ScalarProduct<Y> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: Y) -> Self::Output {
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<W> for WX {
+impl ScalarProduct<W> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YW> for WX {
+impl ScalarProduct<YW> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: YW) -> Self::Output {
+	fn scalar_product(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for WX {
+impl ScalarProduct<WX> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for WX {
+impl ScalarProduct<XY> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for WX {
+impl ScalarProduct<XYW> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<S> for XY {
-	type Output = XY;
-	fn dot(self, rhs: S) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl ScalarProduct<S> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for XY {
-	type Output = Y;
-	fn dot(self, rhs: X) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl ScalarProduct<X> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for XY {
-	type Output = X;
-	fn dot(self, rhs: Y) -> Self::Output {
-		X(self.0 * rhs.0)
+impl ScalarProduct<Y> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for XY {
+impl ScalarProduct<W> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YW> for XY {
+impl ScalarProduct<YW> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: YW) -> Self::Output {
+	fn scalar_product(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for XY {
+impl ScalarProduct<WX> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for XY {
+impl ScalarProduct<XY> for XY {
 	type Output = S;
-	fn dot(self, rhs: XY) -> Self::Output {
+	fn scalar_product(self, rhs: XY) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl Dot<XYW> for XY {
-	type Output = W;
-	fn dot(self, rhs: XYW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<XYW> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
-}
-
-impl Dot<S> for XYW {
-	type Output = XYW;
-	fn dot(self, rhs: S) -> Self::Output {
-		XYW(self.0 * rhs.0)
+}
+
+impl ScalarProduct<S> for XYW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for XYW {
-	type Output = YW;
-	fn dot(self, rhs: X) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl ScalarProduct<X> for XYW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for XYW {
-	type Output = WX;
-	fn dot(self, rhs: Y) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl ScalarProduct<Y> for XYW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for XYW {
+impl ScalarProduct<W> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YW> for XYW {
+impl ScalarProduct<YW> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: YW) -> Self::Output {
+	fn scalar_product(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for XYW {
+impl ScalarProduct<WX> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for XYW {
-	type Output = W;
-	fn dot(self, rhs: XY) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<XY> for XYW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYW> for XYW {
+impl ScalarProduct<XYW> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
 // ---------------------------------------------------------------------
-// impl Wedge for blades:
+// impl Commutator for blades:
 
-impl Wedge<S> for S {
-	type Output = S;
-	fn wedge(self, rhs: S) -> Self::Output {
-		S(self.0 * rhs.0)
+impl Commutator<S> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for S {
-	type Output = X;
-	fn wedge(self, rhs: X) -> Self::Output {
-		X(self.0 * rhs.0)
+impl Commutator<X> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<Y> for S {
-	type Output = Y;
-	fn wedge(self, rhs: Y) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl Commutator<Y> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<W> for S {
-	type Output = W;
-	fn wedge(self, rhs: W) -> Self::Output {
-		W(self.0 * rhs.0)
+impl Commutator<W> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<YW> for S {
-	type Output = YW;
-	fn wedge(self, rhs: YW) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl Commutator<YW> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<WX> for S {
-	type Output = WX;
-	fn wedge(self, rhs: WX) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl Commutator<WX> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<XY> for S {
-	type Output = XY;
-	fn wedge(self, rhs: XY) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl Commutator<XY> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<XYW> for S {
-	type Output = XYW;
-	fn wedge(self, rhs: XYW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl Commutator<XYW> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<S> for X {
-	type Output = X;
-	fn wedge(self, rhs: S) -> Self::Output {
-		X(self.0 * rhs.0)
+impl Commutator<S> for X {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for X {
+impl Commutator<X> for X {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for X {
+impl Commutator<Y> for X {
 	type Output = XY;
-	fn wedge(self, rhs: Y) -> Self::Output {
+	fn commutator(self, rhs: Y) -> Self::Output {
 		XY(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<W> for X {
+impl Commutator<W> for X {
 	type Output = WX;
-	fn wedge(self, rhs: W) -> Self::Output {
+	fn commutator(self, rhs: W) -> Self::Output {
 		WX(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YW> for X {
-	type Output = XYW;
-	fn wedge(self, rhs: YW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl Commutator<YW> for X {
+	type Output = Zero;
+	fn commutator(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<WX> for X {
-	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
+impl Commutator<WX> for X {
+	type Output = W;
+	fn commutator(self, rhs: WX) -> Self::Output {
+		W(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XY> for X {
-	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
-		Zero {}
+impl Commutator<XY> for X {
+	type Output = Y;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYW> for X {
+impl Commutator<XYW> for X {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for Y {
-	type Output = Y;
-	fn wedge(self, rhs: S) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl Commutator<S> for Y {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for Y {
+impl Commutator<X> for Y {
 	type Output = XY;
-	fn wedge(self, rhs: X) -> Self::Output {
+	fn commutator(self, rhs: X) -> Self::Output {
 		XY(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Y> for Y {
+impl Commutator<Y> for Y {
 	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
+	fn commutator(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for Y {
+impl Commutator<W> for Y {
 	type Output = YW;
-	fn wedge(self, rhs: W) -> Self::Output {
+	fn commutator(self, rhs: W) -> Self::Output {
 		YW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YW> for Y {
+impl Commutator<YW> for Y {
+	type Output = W;
+	fn commutator(self, rhs: YW) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<WX> for Y {
 	type Output = Zero;
-	fn wedge(self, _rhs: YW) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for Y {
-	type Output = XYW;
-	fn wedge(self, rhs: WX) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl Commutator<XY> for Y {
+	type Output = X;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XY> for Y {
+impl Commutator<XYW> for Y {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for Y {
+impl Commutator<S> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for W {
-	type Output = W;
-	fn wedge(self, rhs: S) -> Self::Output {
-		W(self.0 * rhs.0)
-	}
-}
-
-impl Wedge<X> for W {
+impl Commutator<X> for W {
 	type Output = WX;
-	fn wedge(self, rhs: X) -> Self::Output {
+	fn commutator(self, rhs: X) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Y> for W {
+impl Commutator<Y> for W {
 	type Output = YW;
-	fn wedge(self, rhs: Y) -> Self::Output {
+	fn commutator(self, rhs: Y) -> Self::Output {
 		YW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<W> for W {
+impl Commutator<W> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YW> for W {
+impl Commutator<YW> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: YW) -> Self::Output {
+	fn commutator(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for W {
+impl Commutator<WX> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for W {
-	type Output = XYW;
-	fn wedge(self, rhs: XY) -> Self::Output {
-		XYW(self.0 * rhs.0)
-	}
-}
-
-impl Wedge<XYW> for W {
+impl Commutator<XY> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for YW {
-	type Output = YW;
-	fn wedge(self, rhs: S) -> Self::Output {
-		YW(self.0 * rhs.0)
-	}
-}
-
-impl Wedge<X> for YW {
-	type Output = XYW;
-	fn wedge(self, rhs: X) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl Commutator<XYW> for W {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<Y> for YW {
+impl Commutator<S> for YW {
 	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
+	fn commutator(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for YW {
+impl Commutator<X> for YW {
 	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
+	fn commutator(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YW> for YW {
-	type Output = Zero;
-	fn wedge(self, _rhs: YW) -> Self::Output {
-		Zero {}
+impl Commutator<Y> for YW {
+	type Output = W;
+	fn commutator(self, rhs: Y) -> Self::Output {
+		W(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WX> for YW {
+impl Commutator<W> for YW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for YW {
+impl Commutator<YW> for YW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for YW {
+impl Commutator<WX> for YW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for WX {
+impl Commutator<XY> for YW {
 	type Output = WX;
-	fn wedge(self, rhs: S) -> Self::Output {
+	fn commutator(self, rhs: XY) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<X> for WX {
+impl Commutator<XYW> for YW {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for WX {
-	type Output = XYW;
-	fn wedge(self, rhs: Y) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl Commutator<S> for WX {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<W> for WX {
-	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl Commutator<X> for WX {
+	type Output = W;
+	fn commutator(self, rhs: X) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YW> for WX {
+impl Commutator<Y> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: YW) -> Self::Output {
+	fn commutator(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for WX {
+impl Commutator<W> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for WX {
+impl Commutator<YW> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for WX {
+impl Commutator<WX> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for XY {
-	type Output = XY;
-	fn wedge(self, rhs: S) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl Commutator<XY> for WX {
+	type Output = YW;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		YW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<X> for XY {
+impl Commutator<XYW> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for XY {
+impl Commutator<S> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
+	fn commutator(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for XY {
-	type Output = XYW;
-	fn wedge(self, rhs: W) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl Commutator<X> for XY {
+	type Output = Y;
+	fn commutator(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YW> for XY {
-	type Output = Zero;
-	fn wedge(self, _rhs: YW) -> Self::Output {
-		Zero {}
+impl Commutator<Y> for XY {
+	type Output = X;
+	fn commutator(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WX> for XY {
+impl Commutator<W> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for XY {
+impl Commutator<YW> for XY {
+	type Output = WX;
+	fn commutator(self, rhs: YW) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<WX> for XY {
+	type Output = YW;
+	fn commutator(self, rhs: WX) -> Self::Output {
+		YW(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XY> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for XY {
+impl Commutator<XYW> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for XYW {
-	type Output = XYW;
-	fn wedge(self, rhs: S) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl Commutator<S> for XYW {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for XYW {
+impl Commutator<X> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for XYW {
+impl Commutator<Y> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
+	fn commutator(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for XYW {
+impl Commutator<W> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YW> for XYW {
+impl Commutator<YW> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: YW) -> Self::Output {
+	fn commutator(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for XYW {
+impl Commutator<WX> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for XYW {
+impl Commutator<XY> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for XYW {
+impl Commutator<XYW> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
 // ---------------------------------------------------------------------
-// impl AntiWedge for blades:
+// impl AntiDot for blades:
 
-impl AntiWedge<S> for S {
+impl AntiDot<S> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for S {
+impl AntiDot<X> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for S {
+impl AntiDot<Y> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for S {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl AntiDot<W> for S {
+	type Output = XY;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		XY(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YW> for S {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: YW) -> Self::Output {
-		Zero {}
+impl AntiDot<YW> for S {
+	type Output = X;
+	fn anti_dot(self, rhs: YW) -> Self::Output {
+		X(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for S {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
+impl AntiDot<WX> for S {
+	type Output = Y;
+	fn anti_dot(self, rhs: WX) -> Self::Output {
+		Y(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XY> for S {
+impl AntiDot<XY> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for S {
+impl AntiDot<XYW> for S {
 	type Output = S;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for X {
+impl AntiDot<S> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for X {
+impl AntiDot<X> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for X {
+impl AntiDot<Y> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for X {
+impl AntiDot<W> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YW> for X {
-	type Output = S;
-	fn anti_wedge(self, rhs: YW) -> Self::Output {
-		S(self.0 * rhs.0)
+impl AntiDot<YW> for X {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for X {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
+impl AntiDot<WX> for X {
+	type Output = XY;
+	fn anti_dot(self, rhs: WX) -> Self::Output {
+		XY(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XY> for X {
+impl AntiDot<XY> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for X {
+impl AntiDot<XYW> for X {
 	type Output = X;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
 		X(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for Y {
+impl AntiDot<S> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for Y {
+impl AntiDot<X> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for Y {
+impl AntiDot<Y> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for Y {
+impl AntiDot<W> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YW> for Y {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: YW) -> Self::Output {
-		Zero {}
+impl AntiDot<YW> for Y {
+	type Output = XY;
+	fn anti_dot(self, rhs: YW) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for Y {
-	type Output = S;
-	fn anti_wedge(self, rhs: WX) -> Self::Output {
-		S(self.0 * rhs.0)
+impl AntiDot<WX> for Y {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for Y {
+impl AntiDot<XY> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for Y {
+impl AntiDot<XYW> for Y {
 	type Output = Y;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
 		Y(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
-		Zero {}
+impl AntiDot<S> for W {
+	type Output = XY;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		XY(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<X> for W {
+impl AntiDot<X> for W {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for W {
+impl AntiDot<Y> for W {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl AntiDot<W> for W {
+	type Output = XYW;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		XYW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YW> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: YW) -> Self::Output {
-		Zero {}
+impl AntiDot<YW> for W {
+	type Output = WX;
+	fn anti_dot(self, rhs: YW) -> Self::Output {
+		WX(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
+impl AntiDot<WX> for W {
+	type Output = YW;
+	fn anti_dot(self, rhs: WX) -> Self::Output {
+		YW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XY> for W {
-	type Output = S;
-	fn anti_wedge(self, rhs: XY) -> Self::Output {
-		S(self.0 * rhs.0)
+impl AntiDot<XY> for W {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for W {
+impl AntiDot<XYW> for W {
 	type Output = W;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
 		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for YW {
+impl AntiDot<S> for YW {
+	type Output = X;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<X> for YW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for YW {
-	type Output = S;
-	fn anti_wedge(self, rhs: X) -> Self::Output {
-		S(self.0 * rhs.0)
+impl AntiDot<Y> for YW {
+	type Output = XY;
+	fn anti_dot(self, rhs: Y) -> Self::Output {
+		XY(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<Y> for YW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
-		Zero {}
+impl AntiDot<W> for YW {
+	type Output = WX;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		WX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<W> for YW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl AntiDot<YW> for YW {
+	type Output = XYW;
+	fn anti_dot(self, rhs: YW) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YW> for YW {
+impl AntiDot<WX> for YW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YW) -> Self::Output {
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for YW {
-	type Output = W;
-	fn anti_wedge(self, rhs: WX) -> Self::Output {
-		W(self.0 * rhs.0)
+impl AntiDot<XY> for YW {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for YW {
-	type Output = Y;
-	fn anti_wedge(self, rhs: XY) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl AntiDot<XYW> for YW {
+	type Output = YW;
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
+		YW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for YW {
-	type Output = YW;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
-		YW(self.0 * rhs.0)
+impl AntiDot<S> for WX {
+	type Output = Y;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for WX {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
-		Zero {}
+impl AntiDot<X> for WX {
+	type Output = XY;
+	fn anti_dot(self, rhs: X) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<X> for WX {
+impl AntiDot<Y> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for WX {
-	type Output = S;
-	fn anti_wedge(self, rhs: Y) -> Self::Output {
-		S(self.0 * rhs.0)
+impl AntiDot<W> for WX {
+	type Output = YW;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		YW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<W> for WX {
+impl AntiDot<YW> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: YW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YW> for WX {
-	type Output = W;
-	fn anti_wedge(self, rhs: YW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl AntiDot<WX> for WX {
+	type Output = XYW;
+	fn anti_dot(self, rhs: WX) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for WX {
+impl AntiDot<XY> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for WX {
-	type Output = X;
-	fn anti_wedge(self, rhs: XY) -> Self::Output {
-		X(self.0 * rhs.0)
-	}
-}
-
-impl AntiWedge<XYW> for WX {
+impl AntiDot<XYW> for WX {
 	type Output = WX;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for XY {
+impl AntiDot<S> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for XY {
+impl AntiDot<X> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for XY {
+impl AntiDot<Y> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for XY {
-	type Output = S;
-	fn anti_wedge(self, rhs: W) -> Self::Output {
-		S(self.0 * rhs.0)
+impl AntiDot<W> for XY {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<YW> for XY {
-	type Output = Y;
-	fn anti_wedge(self, rhs: YW) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl AntiDot<YW> for XY {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for XY {
-	type Output = X;
-	fn anti_wedge(self, rhs: WX) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl AntiDot<WX> for XY {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for XY {
+impl AntiDot<XY> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for XY {
+impl AntiDot<XYW> for XY {
 	type Output = XY;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
 		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for XYW {
+impl AntiDot<S> for XYW {
 	type Output = S;
-	fn anti_wedge(self, rhs: S) -> Self::Output {
+	fn anti_dot(self, rhs: S) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<X> for XYW {
+impl AntiDot<X> for XYW {
 	type Output = X;
-	fn anti_wedge(self, rhs: X) -> Self::Output {
+	fn anti_dot(self, rhs: X) -> Self::Output {
 		X(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<Y> for XYW {
+impl AntiDot<Y> for XYW {
 	type Output = Y;
-	fn anti_wedge(self, rhs: Y) -> Self::Output {
+	fn anti_dot(self, rhs: Y) -> Self::Output {
 		Y(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<W> for XYW {
+impl AntiDot<W> for XYW {
 	type Output = W;
-	fn anti_wedge(self, rhs: W) -> Self::Output {
+	fn anti_dot(self, rhs: W) -> Self::Output {
 		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YW> for XYW {
+impl AntiDot<YW> for XYW {
 	type Output = YW;
-	fn anti_wedge(self, rhs: YW) -> Self::Output {
+	fn anti_dot(self, rhs: YW) -> Self::Output {
 		YW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for XYW {
+impl AntiDot<WX> for XYW {
 	type Output = WX;
-	fn anti_wedge(self, rhs: WX) -> Self::Output {
+	fn anti_dot(self, rhs: WX) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XY> for XYW {
+impl AntiDot<XY> for XYW {
 	type Output = XY;
-	fn anti_wedge(self, rhs: XY) -> Self::Output {
+	fn anti_dot(self, rhs: XY) -> Self::Output {
 		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for XYW {
+impl AntiDot<XYW> for XYW {
 	type Output = XYW;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
 		XYW(self.0 * rhs.0)
 	}
 }
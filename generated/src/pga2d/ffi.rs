@@ -0,0 +1,3363 @@
+//! The C ABI of this algebra, declared in `pga2d.h`.
+
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_rcompl(value: Vec2) -> Line {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_lcompl(value: Vec2) -> Line {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_rev(value: Vec2) -> Vec2 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_arev(value: Vec2) -> Vec2 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_involute(value: Vec2) -> Vec2 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_conjugate(value: Vec2) -> Vec2 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_dual(value: Vec2) -> Line {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_bulk(value: Vec2) -> Vec2 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_bulk_dual(value: Vec2) -> Line {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_geometric_vec2(lhs: Vec2, rhs: Vec2) -> Rotor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_dot_vec2(lhs: Vec2, rhs: Vec2) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_wedge_vec2(lhs: Vec2, rhs: Vec2) -> XY {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_left_contraction_vec2(lhs: Vec2, rhs: Vec2) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_right_contraction_vec2(lhs: Vec2, rhs: Vec2) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_scalar_product_vec2(lhs: Vec2, rhs: Vec2) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_commutator_vec2(lhs: Vec2, rhs: Vec2) -> XY {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_geometric_vec3(lhs: Vec2, rhs: Vec3) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_geometric_vec3(lhs: Vec2, rhs: Vec3) -> Vec2 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_dot_vec3(lhs: Vec2, rhs: Vec3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_wedge_vec3(lhs: Vec2, rhs: Vec3) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_left_contraction_vec3(lhs: Vec2, rhs: Vec3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_right_contraction_vec3(lhs: Vec2, rhs: Vec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_scalar_product_vec3(lhs: Vec2, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_commutator_vec3(lhs: Vec2, rhs: Vec3) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_geometric_point2(lhs: Vec2, rhs: Point2) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_geometric_point2(lhs: Vec2, rhs: Point2) -> Vec2 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_dot_point2(lhs: Vec2, rhs: Point2) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_wedge_point2(lhs: Vec2, rhs: Point2) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_left_contraction_point2(lhs: Vec2, rhs: Point2) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_right_contraction_point2(lhs: Vec2, rhs: Point2) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_scalar_product_point2(lhs: Vec2, rhs: Point2) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_commutator_point2(lhs: Vec2, rhs: Point2) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_geometric_line(lhs: Vec2, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_geometric_line(lhs: Vec2, rhs: Line) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_dot_line(lhs: Vec2, rhs: Line) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_wedge_line(lhs: Vec2, rhs: Line) -> XYW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_wedge_line(lhs: Vec2, rhs: Line) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_left_contraction_line(lhs: Vec2, rhs: Line) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_commutator_line(lhs: Vec2, rhs: Line) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_dot_line(lhs: Vec2, rhs: Line) -> XY {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_geometric_translator(lhs: Vec2, rhs: Translator) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_geometric_translator(lhs: Vec2, rhs: Translator) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_dot_translator(lhs: Vec2, rhs: Translator) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_wedge_translator(lhs: Vec2, rhs: Translator) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_wedge_translator(lhs: Vec2, rhs: Translator) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_left_contraction_translator(lhs: Vec2, rhs: Translator) -> W {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_right_contraction_translator(lhs: Vec2, rhs: Translator) -> Vec2 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_commutator_translator(lhs: Vec2, rhs: Translator) -> W {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_dot_translator(lhs: Vec2, rhs: Translator) -> XY {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_geometric_rotor(lhs: Vec2, rhs: Rotor) -> Vec2 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_dot_rotor(lhs: Vec2, rhs: Rotor) -> Vec2 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_wedge_rotor(lhs: Vec2, rhs: Rotor) -> Vec2 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_left_contraction_rotor(lhs: Vec2, rhs: Rotor) -> Vec2 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_right_contraction_rotor(lhs: Vec2, rhs: Rotor) -> Vec2 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_commutator_rotor(lhs: Vec2, rhs: Rotor) -> Vec2 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_geometric_motor(lhs: Vec2, rhs: Motor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_geometric_motor(lhs: Vec2, rhs: Motor) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_dot_motor(lhs: Vec2, rhs: Motor) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_wedge_motor(lhs: Vec2, rhs: Motor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_wedge_motor(lhs: Vec2, rhs: Motor) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_left_contraction_motor(lhs: Vec2, rhs: Motor) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_right_contraction_motor(lhs: Vec2, rhs: Motor) -> Vec2 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_commutator_motor(lhs: Vec2, rhs: Motor) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_dot_motor(lhs: Vec2, rhs: Motor) -> XY {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_geometric_multivector(lhs: Vec2, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_geometric_multivector(lhs: Vec2, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_dot_multivector(lhs: Vec2, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_wedge_multivector(lhs: Vec2, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_wedge_multivector(lhs: Vec2, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_left_contraction_multivector(lhs: Vec2, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_right_contraction_multivector(lhs: Vec2, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_scalar_product_multivector(lhs: Vec2, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_commutator_multivector(lhs: Vec2, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec2_anti_dot_multivector(lhs: Vec2, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_rcompl(value: Vec3) -> Line {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_lcompl(value: Vec3) -> Line {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_rev(value: Vec3) -> Vec3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_arev(value: Vec3) -> Vec3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_involute(value: Vec3) -> Vec3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_conjugate(value: Vec3) -> Vec3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_dual(value: Vec3) -> Line {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_bulk(value: Vec3) -> Vec2 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_weight(value: Vec3) -> W {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_bulk_dual(value: Vec3) -> Line {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_weight_dual(value: Vec3) -> XY {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_geometric_vec2(lhs: Vec3, rhs: Vec2) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_geometric_vec2(lhs: Vec3, rhs: Vec2) -> Vec2 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_dot_vec2(lhs: Vec3, rhs: Vec2) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_wedge_vec2(lhs: Vec3, rhs: Vec2) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_left_contraction_vec2(lhs: Vec3, rhs: Vec2) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_right_contraction_vec2(lhs: Vec3, rhs: Vec2) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_scalar_product_vec2(lhs: Vec3, rhs: Vec2) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_commutator_vec2(lhs: Vec3, rhs: Vec2) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_geometric_vec3(lhs: Vec3, rhs: Vec3) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_geometric_vec3(lhs: Vec3, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_dot_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_wedge_vec3(lhs: Vec3, rhs: Vec3) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_left_contraction_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_right_contraction_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_scalar_product_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_commutator_vec3(lhs: Vec3, rhs: Vec3) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_dot_vec3(lhs: Vec3, rhs: Vec3) -> XYW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_geometric_point2(lhs: Vec3, rhs: Point2) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_geometric_point2(lhs: Vec3, rhs: Point2) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_dot_point2(lhs: Vec3, rhs: Point2) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_wedge_point2(lhs: Vec3, rhs: Point2) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_left_contraction_point2(lhs: Vec3, rhs: Point2) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_right_contraction_point2(lhs: Vec3, rhs: Point2) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_scalar_product_point2(lhs: Vec3, rhs: Point2) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_commutator_point2(lhs: Vec3, rhs: Point2) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_dot_point2(lhs: Vec3, rhs: Point2) -> XYW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_geometric_line(lhs: Vec3, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_geometric_line(lhs: Vec3, rhs: Line) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_dot_line(lhs: Vec3, rhs: Line) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_wedge_line(lhs: Vec3, rhs: Line) -> XYW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_wedge_line(lhs: Vec3, rhs: Line) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_left_contraction_line(lhs: Vec3, rhs: Line) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_commutator_line(lhs: Vec3, rhs: Line) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_dot_line(lhs: Vec3, rhs: Line) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_geometric_translator(lhs: Vec3, rhs: Translator) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_geometric_translator(lhs: Vec3, rhs: Translator) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_dot_translator(lhs: Vec3, rhs: Translator) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_wedge_translator(lhs: Vec3, rhs: Translator) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_wedge_translator(lhs: Vec3, rhs: Translator) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_left_contraction_translator(lhs: Vec3, rhs: Translator) -> W {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_right_contraction_translator(lhs: Vec3, rhs: Translator) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_commutator_translator(lhs: Vec3, rhs: Translator) -> W {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_dot_translator(lhs: Vec3, rhs: Translator) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_geometric_rotor(lhs: Vec3, rhs: Rotor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_geometric_rotor(lhs: Vec3, rhs: Rotor) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_dot_rotor(lhs: Vec3, rhs: Rotor) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_wedge_rotor(lhs: Vec3, rhs: Rotor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_wedge_rotor(lhs: Vec3, rhs: Rotor) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_left_contraction_rotor(lhs: Vec3, rhs: Rotor) -> Vec2 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_right_contraction_rotor(lhs: Vec3, rhs: Rotor) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_commutator_rotor(lhs: Vec3, rhs: Rotor) -> Vec2 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_dot_rotor(lhs: Vec3, rhs: Rotor) -> XY {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_geometric_motor(lhs: Vec3, rhs: Motor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_geometric_motor(lhs: Vec3, rhs: Motor) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_dot_motor(lhs: Vec3, rhs: Motor) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_wedge_motor(lhs: Vec3, rhs: Motor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_wedge_motor(lhs: Vec3, rhs: Motor) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_left_contraction_motor(lhs: Vec3, rhs: Motor) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_right_contraction_motor(lhs: Vec3, rhs: Motor) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_commutator_motor(lhs: Vec3, rhs: Motor) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_dot_motor(lhs: Vec3, rhs: Motor) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_geometric_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_geometric_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_dot_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_wedge_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_wedge_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_left_contraction_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_right_contraction_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_scalar_product_multivector(lhs: Vec3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_commutator_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_vec3_anti_dot_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_rcompl(value: Point2) -> Line {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_lcompl(value: Point2) -> Line {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_dual(value: Point2) -> Line {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_bulk(value: Point2) -> Vec2 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_weight(value: Point2) -> W {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_bulk_dual(value: Point2) -> Line {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_weight_dual(value: Point2) -> XY {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_geometric_vec2(lhs: Point2, rhs: Vec2) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_geometric_vec2(lhs: Point2, rhs: Vec2) -> Vec2 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_dot_vec2(lhs: Point2, rhs: Vec2) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_wedge_vec2(lhs: Point2, rhs: Vec2) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_left_contraction_vec2(lhs: Point2, rhs: Vec2) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_right_contraction_vec2(lhs: Point2, rhs: Vec2) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_scalar_product_vec2(lhs: Point2, rhs: Vec2) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_commutator_vec2(lhs: Point2, rhs: Vec2) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_geometric_vec3(lhs: Point2, rhs: Vec3) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_geometric_vec3(lhs: Point2, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_dot_vec3(lhs: Point2, rhs: Vec3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_wedge_vec3(lhs: Point2, rhs: Vec3) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_left_contraction_vec3(lhs: Point2, rhs: Vec3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_right_contraction_vec3(lhs: Point2, rhs: Vec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_scalar_product_vec3(lhs: Point2, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_commutator_vec3(lhs: Point2, rhs: Vec3) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_dot_vec3(lhs: Point2, rhs: Vec3) -> XYW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_geometric_point2(lhs: Point2, rhs: Point2) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_geometric_point2(lhs: Point2, rhs: Point2) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_dot_point2(lhs: Point2, rhs: Point2) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_wedge_point2(lhs: Point2, rhs: Point2) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_left_contraction_point2(lhs: Point2, rhs: Point2) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_right_contraction_point2(lhs: Point2, rhs: Point2) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_scalar_product_point2(lhs: Point2, rhs: Point2) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_commutator_point2(lhs: Point2, rhs: Point2) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_dot_point2(lhs: Point2, rhs: Point2) -> XYW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_geometric_line(lhs: Point2, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_geometric_line(lhs: Point2, rhs: Line) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_dot_line(lhs: Point2, rhs: Line) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_wedge_line(lhs: Point2, rhs: Line) -> XYW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_wedge_line(lhs: Point2, rhs: Line) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_left_contraction_line(lhs: Point2, rhs: Line) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_commutator_line(lhs: Point2, rhs: Line) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_dot_line(lhs: Point2, rhs: Line) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_geometric_translator(lhs: Point2, rhs: Translator) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_geometric_translator(lhs: Point2, rhs: Translator) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_dot_translator(lhs: Point2, rhs: Translator) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_wedge_translator(lhs: Point2, rhs: Translator) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_wedge_translator(lhs: Point2, rhs: Translator) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_left_contraction_translator(lhs: Point2, rhs: Translator) -> W {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_right_contraction_translator(lhs: Point2, rhs: Translator) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_commutator_translator(lhs: Point2, rhs: Translator) -> W {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_dot_translator(lhs: Point2, rhs: Translator) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_geometric_rotor(lhs: Point2, rhs: Rotor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_geometric_rotor(lhs: Point2, rhs: Rotor) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_dot_rotor(lhs: Point2, rhs: Rotor) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_wedge_rotor(lhs: Point2, rhs: Rotor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_wedge_rotor(lhs: Point2, rhs: Rotor) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_left_contraction_rotor(lhs: Point2, rhs: Rotor) -> Vec2 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_right_contraction_rotor(lhs: Point2, rhs: Rotor) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_commutator_rotor(lhs: Point2, rhs: Rotor) -> Vec2 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_dot_rotor(lhs: Point2, rhs: Rotor) -> XY {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_geometric_motor(lhs: Point2, rhs: Motor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_geometric_motor(lhs: Point2, rhs: Motor) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_dot_motor(lhs: Point2, rhs: Motor) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_wedge_motor(lhs: Point2, rhs: Motor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_wedge_motor(lhs: Point2, rhs: Motor) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_left_contraction_motor(lhs: Point2, rhs: Motor) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_right_contraction_motor(lhs: Point2, rhs: Motor) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_commutator_motor(lhs: Point2, rhs: Motor) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_dot_motor(lhs: Point2, rhs: Motor) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_geometric_multivector(lhs: Point2, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_geometric_multivector(lhs: Point2, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_dot_multivector(lhs: Point2, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_wedge_multivector(lhs: Point2, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_wedge_multivector(lhs: Point2, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_left_contraction_multivector(lhs: Point2, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_right_contraction_multivector(lhs: Point2, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_scalar_product_multivector(lhs: Point2, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_commutator_multivector(lhs: Point2, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_point2_anti_dot_multivector(lhs: Point2, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_rcompl(value: Line) -> Vec3 {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_lcompl(value: Line) -> Vec3 {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_rev(value: Line) -> Line {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_arev(value: Line) -> Line {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_involute(value: Line) -> Line {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_conjugate(value: Line) -> Line {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_dual(value: Line) -> Vec3 {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_bulk(value: Line) -> XY {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_weight(value: Line) -> Line {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_bulk_dual(value: Line) -> W {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_weight_dual(value: Line) -> Vec2 {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_geometric_vec2(lhs: Line, rhs: Vec2) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_geometric_vec2(lhs: Line, rhs: Vec2) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_dot_vec2(lhs: Line, rhs: Vec2) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_wedge_vec2(lhs: Line, rhs: Vec2) -> XYW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_wedge_vec2(lhs: Line, rhs: Vec2) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_right_contraction_vec2(lhs: Line, rhs: Vec2) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_commutator_vec2(lhs: Line, rhs: Vec2) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_dot_vec2(lhs: Line, rhs: Vec2) -> XY {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_geometric_vec3(lhs: Line, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_geometric_vec3(lhs: Line, rhs: Vec3) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_dot_vec3(lhs: Line, rhs: Vec3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_wedge_vec3(lhs: Line, rhs: Vec3) -> XYW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_wedge_vec3(lhs: Line, rhs: Vec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_right_contraction_vec3(lhs: Line, rhs: Vec3) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_commutator_vec3(lhs: Line, rhs: Vec3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_dot_vec3(lhs: Line, rhs: Vec3) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_geometric_point2(lhs: Line, rhs: Point2) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_geometric_point2(lhs: Line, rhs: Point2) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_dot_point2(lhs: Line, rhs: Point2) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_wedge_point2(lhs: Line, rhs: Point2) -> XYW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_wedge_point2(lhs: Line, rhs: Point2) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_right_contraction_point2(lhs: Line, rhs: Point2) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_commutator_point2(lhs: Line, rhs: Point2) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_dot_point2(lhs: Line, rhs: Point2) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_geometric_line(lhs: Line, rhs: Line) -> Translator {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_geometric_line(lhs: Line, rhs: Line) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_dot_line(lhs: Line, rhs: Line) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_wedge_line(lhs: Line, rhs: Line) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_left_contraction_line(lhs: Line, rhs: Line) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_right_contraction_line(lhs: Line, rhs: Line) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_scalar_product_line(lhs: Line, rhs: Line) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_commutator_line(lhs: Line, rhs: Line) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_dot_line(lhs: Line, rhs: Line) -> XYW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_geometric_translator(lhs: Line, rhs: Translator) -> Line {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_geometric_translator(lhs: Line, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_dot_translator(lhs: Line, rhs: Translator) -> Line {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_wedge_translator(lhs: Line, rhs: Translator) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_wedge_translator(lhs: Line, rhs: Translator) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_right_contraction_translator(lhs: Line, rhs: Translator) -> Line {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_commutator_translator(lhs: Line, rhs: Translator) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_dot_translator(lhs: Line, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_geometric_rotor(lhs: Line, rhs: Rotor) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_geometric_rotor(lhs: Line, rhs: Rotor) -> Vec2 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_dot_rotor(lhs: Line, rhs: Rotor) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_wedge_rotor(lhs: Line, rhs: Rotor) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_wedge_rotor(lhs: Line, rhs: Rotor) -> Vec2 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_left_contraction_rotor(lhs: Line, rhs: Rotor) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_right_contraction_rotor(lhs: Line, rhs: Rotor) -> Motor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_scalar_product_rotor(lhs: Line, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_commutator_rotor(lhs: Line, rhs: Rotor) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_dot_rotor(lhs: Line, rhs: Rotor) -> Vec2 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_geometric_motor(lhs: Line, rhs: Motor) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_geometric_motor(lhs: Line, rhs: Motor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_dot_motor(lhs: Line, rhs: Motor) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_wedge_motor(lhs: Line, rhs: Motor) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_wedge_motor(lhs: Line, rhs: Motor) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_left_contraction_motor(lhs: Line, rhs: Motor) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_right_contraction_motor(lhs: Line, rhs: Motor) -> Motor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_scalar_product_motor(lhs: Line, rhs: Motor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_commutator_motor(lhs: Line, rhs: Motor) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_dot_motor(lhs: Line, rhs: Motor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_geometric_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_geometric_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_dot_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_wedge_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_wedge_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_left_contraction_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_right_contraction_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_scalar_product_multivector(lhs: Line, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_commutator_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_line_anti_dot_multivector(lhs: Line, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_rcompl(value: Translator) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_lcompl(value: Translator) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_rev(value: Translator) -> Translator {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_arev(value: Translator) -> Translator {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_involute(value: Translator) -> Translator {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_conjugate(value: Translator) -> Translator {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_dual(value: Translator) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_bulk(value: Translator) -> S {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_weight(value: Translator) -> Line {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_bulk_dual(value: Translator) -> XYW {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_weight_dual(value: Translator) -> Vec2 {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_geometric_vec2(lhs: Translator, rhs: Vec2) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_geometric_vec2(lhs: Translator, rhs: Vec2) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_dot_vec2(lhs: Translator, rhs: Vec2) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_wedge_vec2(lhs: Translator, rhs: Vec2) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_wedge_vec2(lhs: Translator, rhs: Vec2) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_left_contraction_vec2(lhs: Translator, rhs: Vec2) -> Vec2 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_right_contraction_vec2(lhs: Translator, rhs: Vec2) -> W {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_commutator_vec2(lhs: Translator, rhs: Vec2) -> W {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_dot_vec2(lhs: Translator, rhs: Vec2) -> XY {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_geometric_vec3(lhs: Translator, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_geometric_vec3(lhs: Translator, rhs: Vec3) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_dot_vec3(lhs: Translator, rhs: Vec3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_wedge_vec3(lhs: Translator, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_wedge_vec3(lhs: Translator, rhs: Vec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_left_contraction_vec3(lhs: Translator, rhs: Vec3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_right_contraction_vec3(lhs: Translator, rhs: Vec3) -> W {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_commutator_vec3(lhs: Translator, rhs: Vec3) -> W {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_dot_vec3(lhs: Translator, rhs: Vec3) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_geometric_point2(lhs: Translator, rhs: Point2) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_geometric_point2(lhs: Translator, rhs: Point2) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_dot_point2(lhs: Translator, rhs: Point2) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_wedge_point2(lhs: Translator, rhs: Point2) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_wedge_point2(lhs: Translator, rhs: Point2) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_left_contraction_point2(lhs: Translator, rhs: Point2) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_right_contraction_point2(lhs: Translator, rhs: Point2) -> W {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_commutator_point2(lhs: Translator, rhs: Point2) -> W {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_dot_point2(lhs: Translator, rhs: Point2) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_geometric_line(lhs: Translator, rhs: Line) -> Line {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_geometric_line(lhs: Translator, rhs: Line) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_dot_line(lhs: Translator, rhs: Line) -> Line {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_wedge_line(lhs: Translator, rhs: Line) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_wedge_line(lhs: Translator, rhs: Line) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_left_contraction_line(lhs: Translator, rhs: Line) -> Line {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_commutator_line(lhs: Translator, rhs: Line) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_dot_line(lhs: Translator, rhs: Line) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_geometric_translator(lhs: Translator, rhs: Translator) -> Translator {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_geometric_translator(lhs: Translator, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_dot_translator(lhs: Translator, rhs: Translator) -> Translator {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_wedge_translator(lhs: Translator, rhs: Translator) -> Translator {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_wedge_translator(lhs: Translator, rhs: Translator) -> W {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_left_contraction_translator(lhs: Translator, rhs: Translator) -> Translator {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_right_contraction_translator(lhs: Translator, rhs: Translator) -> Translator {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_scalar_product_translator(lhs: Translator, rhs: Translator) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_dot_translator(lhs: Translator, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_geometric_rotor(lhs: Translator, rhs: Rotor) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_geometric_rotor(lhs: Translator, rhs: Rotor) -> Vec2 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_dot_rotor(lhs: Translator, rhs: Rotor) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_wedge_rotor(lhs: Translator, rhs: Rotor) -> Motor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_wedge_rotor(lhs: Translator, rhs: Rotor) -> Vec2 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_left_contraction_rotor(lhs: Translator, rhs: Rotor) -> Rotor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_right_contraction_rotor(lhs: Translator, rhs: Rotor) -> Translator {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_scalar_product_rotor(lhs: Translator, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_commutator_rotor(lhs: Translator, rhs: Rotor) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_dot_rotor(lhs: Translator, rhs: Rotor) -> Vec2 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_geometric_motor(lhs: Translator, rhs: Motor) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_geometric_motor(lhs: Translator, rhs: Motor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_dot_motor(lhs: Translator, rhs: Motor) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_wedge_motor(lhs: Translator, rhs: Motor) -> Motor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_wedge_motor(lhs: Translator, rhs: Motor) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_left_contraction_motor(lhs: Translator, rhs: Motor) -> Motor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_right_contraction_motor(lhs: Translator, rhs: Motor) -> Translator {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_scalar_product_motor(lhs: Translator, rhs: Motor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_commutator_motor(lhs: Translator, rhs: Motor) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_dot_motor(lhs: Translator, rhs: Motor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_geometric_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_geometric_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_dot_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_wedge_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_wedge_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_left_contraction_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_right_contraction_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_scalar_product_multivector(lhs: Translator, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_commutator_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_dot_multivector(lhs: Translator, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_sandwich_vec2(lhs: Translator, rhs: Vec2) -> Vec2 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_sandwich_vec3(lhs: Translator, rhs: Vec3) -> Vec3 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_sandwich_vec3(lhs: Translator, rhs: Vec3) -> Vec3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_sandwich_line(lhs: Translator, rhs: Line) -> Line {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_translator_anti_sandwich_line(lhs: Translator, rhs: Line) -> Line {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_rcompl(value: Rotor) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_lcompl(value: Rotor) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_rev(value: Rotor) -> Rotor {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_arev(value: Rotor) -> Rotor {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_involute(value: Rotor) -> Rotor {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_conjugate(value: Rotor) -> Rotor {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_dual(value: Rotor) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_bulk(value: Rotor) -> Rotor {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_bulk_dual(value: Rotor) -> Multivector {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_geometric_vec2(lhs: Rotor, rhs: Vec2) -> Vec2 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_dot_vec2(lhs: Rotor, rhs: Vec2) -> Vec2 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_wedge_vec2(lhs: Rotor, rhs: Vec2) -> Vec2 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_left_contraction_vec2(lhs: Rotor, rhs: Vec2) -> Vec2 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_right_contraction_vec2(lhs: Rotor, rhs: Vec2) -> Vec2 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_commutator_vec2(lhs: Rotor, rhs: Vec2) -> Vec2 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_geometric_vec3(lhs: Rotor, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_geometric_vec3(lhs: Rotor, rhs: Vec3) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_dot_vec3(lhs: Rotor, rhs: Vec3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_wedge_vec3(lhs: Rotor, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_wedge_vec3(lhs: Rotor, rhs: Vec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_left_contraction_vec3(lhs: Rotor, rhs: Vec3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_right_contraction_vec3(lhs: Rotor, rhs: Vec3) -> Vec2 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_commutator_vec3(lhs: Rotor, rhs: Vec3) -> Vec2 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_dot_vec3(lhs: Rotor, rhs: Vec3) -> XY {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_geometric_point2(lhs: Rotor, rhs: Point2) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_geometric_point2(lhs: Rotor, rhs: Point2) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_dot_point2(lhs: Rotor, rhs: Point2) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_wedge_point2(lhs: Rotor, rhs: Point2) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_wedge_point2(lhs: Rotor, rhs: Point2) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_left_contraction_point2(lhs: Rotor, rhs: Point2) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_right_contraction_point2(lhs: Rotor, rhs: Point2) -> Vec2 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_commutator_point2(lhs: Rotor, rhs: Point2) -> Vec2 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_dot_point2(lhs: Rotor, rhs: Point2) -> XY {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_geometric_line(lhs: Rotor, rhs: Line) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_geometric_line(lhs: Rotor, rhs: Line) -> Vec2 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_dot_line(lhs: Rotor, rhs: Line) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_wedge_line(lhs: Rotor, rhs: Line) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_wedge_line(lhs: Rotor, rhs: Line) -> Vec2 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_left_contraction_line(lhs: Rotor, rhs: Line) -> Motor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_right_contraction_line(lhs: Rotor, rhs: Line) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_scalar_product_line(lhs: Rotor, rhs: Line) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_commutator_line(lhs: Rotor, rhs: Line) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_dot_line(lhs: Rotor, rhs: Line) -> Vec2 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_geometric_translator(lhs: Rotor, rhs: Translator) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_geometric_translator(lhs: Rotor, rhs: Translator) -> Vec2 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_dot_translator(lhs: Rotor, rhs: Translator) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_wedge_translator(lhs: Rotor, rhs: Translator) -> Motor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_wedge_translator(lhs: Rotor, rhs: Translator) -> Vec2 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_left_contraction_translator(lhs: Rotor, rhs: Translator) -> Translator {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_right_contraction_translator(lhs: Rotor, rhs: Translator) -> Rotor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_scalar_product_translator(lhs: Rotor, rhs: Translator) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_commutator_translator(lhs: Rotor, rhs: Translator) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_dot_translator(lhs: Rotor, rhs: Translator) -> Vec2 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_geometric_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_dot_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_wedge_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_left_contraction_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_right_contraction_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_scalar_product_rotor(lhs: Rotor, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_geometric_motor(lhs: Rotor, rhs: Motor) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_geometric_motor(lhs: Rotor, rhs: Motor) -> Vec2 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_dot_motor(lhs: Rotor, rhs: Motor) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_wedge_motor(lhs: Rotor, rhs: Motor) -> Motor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_wedge_motor(lhs: Rotor, rhs: Motor) -> Vec2 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_left_contraction_motor(lhs: Rotor, rhs: Motor) -> Motor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_right_contraction_motor(lhs: Rotor, rhs: Motor) -> Rotor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_scalar_product_motor(lhs: Rotor, rhs: Motor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_commutator_motor(lhs: Rotor, rhs: Motor) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_dot_motor(lhs: Rotor, rhs: Motor) -> Vec2 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_geometric_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_geometric_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_dot_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_wedge_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_wedge_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_left_contraction_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_right_contraction_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_scalar_product_multivector(lhs: Rotor, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_commutator_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_anti_dot_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_sandwich_vec2(lhs: Rotor, rhs: Vec2) -> Vec2 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_sandwich_vec3(lhs: Rotor, rhs: Vec3) -> Vec3 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_rotor_sandwich_line(lhs: Rotor, rhs: Line) -> Line {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_rcompl(value: Motor) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_lcompl(value: Motor) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_rev(value: Motor) -> Motor {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_arev(value: Motor) -> Motor {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_involute(value: Motor) -> Motor {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_conjugate(value: Motor) -> Motor {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_dual(value: Motor) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_bulk(value: Motor) -> Rotor {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_weight(value: Motor) -> Line {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_bulk_dual(value: Motor) -> Multivector {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_weight_dual(value: Motor) -> Vec2 {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_geometric_vec2(lhs: Motor, rhs: Vec2) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_geometric_vec2(lhs: Motor, rhs: Vec2) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_dot_vec2(lhs: Motor, rhs: Vec2) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_wedge_vec2(lhs: Motor, rhs: Vec2) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_wedge_vec2(lhs: Motor, rhs: Vec2) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_left_contraction_vec2(lhs: Motor, rhs: Vec2) -> Vec2 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_right_contraction_vec2(lhs: Motor, rhs: Vec2) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_commutator_vec2(lhs: Motor, rhs: Vec2) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_dot_vec2(lhs: Motor, rhs: Vec2) -> XY {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_geometric_vec3(lhs: Motor, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_geometric_vec3(lhs: Motor, rhs: Vec3) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_dot_vec3(lhs: Motor, rhs: Vec3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_wedge_vec3(lhs: Motor, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_wedge_vec3(lhs: Motor, rhs: Vec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_left_contraction_vec3(lhs: Motor, rhs: Vec3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_right_contraction_vec3(lhs: Motor, rhs: Vec3) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_commutator_vec3(lhs: Motor, rhs: Vec3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_dot_vec3(lhs: Motor, rhs: Vec3) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_geometric_point2(lhs: Motor, rhs: Point2) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_geometric_point2(lhs: Motor, rhs: Point2) -> Motor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_dot_point2(lhs: Motor, rhs: Point2) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_wedge_point2(lhs: Motor, rhs: Point2) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_wedge_point2(lhs: Motor, rhs: Point2) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_left_contraction_point2(lhs: Motor, rhs: Point2) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_right_contraction_point2(lhs: Motor, rhs: Point2) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_commutator_point2(lhs: Motor, rhs: Point2) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_dot_point2(lhs: Motor, rhs: Point2) -> Line {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_geometric_line(lhs: Motor, rhs: Line) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_geometric_line(lhs: Motor, rhs: Line) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_dot_line(lhs: Motor, rhs: Line) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_wedge_line(lhs: Motor, rhs: Line) -> Line {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_wedge_line(lhs: Motor, rhs: Line) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_left_contraction_line(lhs: Motor, rhs: Line) -> Motor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_right_contraction_line(lhs: Motor, rhs: Line) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_scalar_product_line(lhs: Motor, rhs: Line) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_commutator_line(lhs: Motor, rhs: Line) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_dot_line(lhs: Motor, rhs: Line) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_geometric_translator(lhs: Motor, rhs: Translator) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_geometric_translator(lhs: Motor, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_dot_translator(lhs: Motor, rhs: Translator) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_wedge_translator(lhs: Motor, rhs: Translator) -> Motor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_wedge_translator(lhs: Motor, rhs: Translator) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_left_contraction_translator(lhs: Motor, rhs: Translator) -> Translator {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_right_contraction_translator(lhs: Motor, rhs: Translator) -> Motor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_scalar_product_translator(lhs: Motor, rhs: Translator) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_commutator_translator(lhs: Motor, rhs: Translator) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_dot_translator(lhs: Motor, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_geometric_rotor(lhs: Motor, rhs: Rotor) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_geometric_rotor(lhs: Motor, rhs: Rotor) -> Vec2 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_dot_rotor(lhs: Motor, rhs: Rotor) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_wedge_rotor(lhs: Motor, rhs: Rotor) -> Motor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_wedge_rotor(lhs: Motor, rhs: Rotor) -> Vec2 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_left_contraction_rotor(lhs: Motor, rhs: Rotor) -> Rotor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_right_contraction_rotor(lhs: Motor, rhs: Rotor) -> Motor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_scalar_product_rotor(lhs: Motor, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_commutator_rotor(lhs: Motor, rhs: Rotor) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_dot_rotor(lhs: Motor, rhs: Rotor) -> Vec2 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_geometric_motor(lhs: Motor, rhs: Motor) -> Motor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_geometric_motor(lhs: Motor, rhs: Motor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_dot_motor(lhs: Motor, rhs: Motor) -> Motor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_wedge_motor(lhs: Motor, rhs: Motor) -> Motor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_wedge_motor(lhs: Motor, rhs: Motor) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_left_contraction_motor(lhs: Motor, rhs: Motor) -> Motor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_right_contraction_motor(lhs: Motor, rhs: Motor) -> Motor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_scalar_product_motor(lhs: Motor, rhs: Motor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_commutator_motor(lhs: Motor, rhs: Motor) -> Line {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_dot_motor(lhs: Motor, rhs: Motor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_geometric_multivector(lhs: Motor, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_geometric_multivector(lhs: Motor, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_dot_multivector(lhs: Motor, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_wedge_multivector(lhs: Motor, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_wedge_multivector(lhs: Motor, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_left_contraction_multivector(lhs: Motor, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_right_contraction_multivector(lhs: Motor, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_scalar_product_multivector(lhs: Motor, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_commutator_multivector(lhs: Motor, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_dot_multivector(lhs: Motor, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_sandwich_vec2(lhs: Motor, rhs: Vec2) -> Vec2 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_sandwich_vec3(lhs: Motor, rhs: Vec3) -> Vec3 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_sandwich_vec3(lhs: Motor, rhs: Vec3) -> Vec3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_sandwich_line(lhs: Motor, rhs: Line) -> Line {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_motor_anti_sandwich_line(lhs: Motor, rhs: Line) -> Line {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_rcompl(value: Multivector) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_lcompl(value: Multivector) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_rev(value: Multivector) -> Multivector {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_arev(value: Multivector) -> Multivector {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_involute(value: Multivector) -> Multivector {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_conjugate(value: Multivector) -> Multivector {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_dual(value: Multivector) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_bulk(value: Multivector) -> Multivector {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_weight(value: Multivector) -> Multivector {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_bulk_dual(value: Multivector) -> Multivector {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_weight_dual(value: Multivector) -> Multivector {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_geometric_vec2(lhs: Multivector, rhs: Vec2) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_geometric_vec2(lhs: Multivector, rhs: Vec2) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_dot_vec2(lhs: Multivector, rhs: Vec2) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_wedge_vec2(lhs: Multivector, rhs: Vec2) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_wedge_vec2(lhs: Multivector, rhs: Vec2) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_left_contraction_vec2(lhs: Multivector, rhs: Vec2) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_right_contraction_vec2(lhs: Multivector, rhs: Vec2) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_scalar_product_vec2(lhs: Multivector, rhs: Vec2) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_commutator_vec2(lhs: Multivector, rhs: Vec2) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_dot_vec2(lhs: Multivector, rhs: Vec2) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_geometric_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_geometric_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_dot_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_wedge_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_wedge_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_left_contraction_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_right_contraction_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_scalar_product_vec3(lhs: Multivector, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_commutator_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_dot_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_geometric_point2(lhs: Multivector, rhs: Point2) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_geometric_point2(lhs: Multivector, rhs: Point2) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_dot_point2(lhs: Multivector, rhs: Point2) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_wedge_point2(lhs: Multivector, rhs: Point2) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_wedge_point2(lhs: Multivector, rhs: Point2) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_left_contraction_point2(lhs: Multivector, rhs: Point2) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_right_contraction_point2(lhs: Multivector, rhs: Point2) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_scalar_product_point2(lhs: Multivector, rhs: Point2) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_commutator_point2(lhs: Multivector, rhs: Point2) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_dot_point2(lhs: Multivector, rhs: Point2) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_geometric_line(lhs: Multivector, rhs: Line) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_geometric_line(lhs: Multivector, rhs: Line) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_dot_line(lhs: Multivector, rhs: Line) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_wedge_line(lhs: Multivector, rhs: Line) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_wedge_line(lhs: Multivector, rhs: Line) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_left_contraction_line(lhs: Multivector, rhs: Line) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_right_contraction_line(lhs: Multivector, rhs: Line) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_scalar_product_line(lhs: Multivector, rhs: Line) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_commutator_line(lhs: Multivector, rhs: Line) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_dot_line(lhs: Multivector, rhs: Line) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_geometric_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_geometric_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_dot_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_wedge_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_wedge_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_left_contraction_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_right_contraction_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_scalar_product_translator(lhs: Multivector, rhs: Translator) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_commutator_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_dot_translator(lhs: Multivector, rhs: Translator) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_geometric_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_geometric_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_dot_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_wedge_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_wedge_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_left_contraction_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_right_contraction_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_scalar_product_rotor(lhs: Multivector, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_commutator_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_dot_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_geometric_motor(lhs: Multivector, rhs: Motor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_geometric_motor(lhs: Multivector, rhs: Motor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_dot_motor(lhs: Multivector, rhs: Motor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_wedge_motor(lhs: Multivector, rhs: Motor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_wedge_motor(lhs: Multivector, rhs: Motor) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_left_contraction_motor(lhs: Multivector, rhs: Motor) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_right_contraction_motor(lhs: Multivector, rhs: Motor) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_scalar_product_motor(lhs: Multivector, rhs: Motor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_commutator_motor(lhs: Multivector, rhs: Motor) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_dot_motor(lhs: Multivector, rhs: Motor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_geometric_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_geometric_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_dot_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_wedge_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_wedge_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_left_contraction_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_right_contraction_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_scalar_product_multivector(lhs: Multivector, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_commutator_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga2d_multivector_anti_dot_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
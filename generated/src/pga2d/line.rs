@@ -2,9 +2,17 @@
 //!
 //! ## Operations
 //! ```text
-//! Line.geometric(Line) -> Motor
+//! Line.geometric(Line) -> Translator
+//! Line.anti_geometric(Line) -> Multivector
 //! Line.dot(Line) -> S
 //! Line.anti_wedge(Line) -> Vec3
+//! Line.left_contraction(Line) -> S
+//! Line.right_contraction(Line) -> S
+//! Line.scalar_product(Line) -> S
+//! Line.commutator(Line) -> Line
+//! Line.anti_dot(Line) -> XYW
+//! Line.geometric(Vec2) -> Multivector
+//! Vec2.geometric(Line) -> Multivector
 //! Line.anti_geometric(Vec2) -> Rotor
 //! Vec2.anti_geometric(Line) -> Rotor
 //! Line.dot(Vec2) -> Vec3
@@ -13,6 +21,14 @@
 //! Vec2.wedge(Line) -> XYW
 //! Line.anti_wedge(Vec2) -> S
 //! Vec2.anti_wedge(Line) -> S
+//! Vec2.left_contraction(Line) -> Vec3
+//! Line.right_contraction(Vec2) -> Vec3
+//! Line.commutator(Vec2) -> Vec3
+//! Vec2.commutator(Line) -> Vec3
+//! Line.anti_dot(Vec2) -> XY
+//! Vec2.anti_dot(Line) -> XY
+//! Line.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Line) -> Multivector
 //! Line.anti_geometric(Vec3) -> Motor
 //! Vec3.anti_geometric(Line) -> Motor
 //! Line.dot(Vec3) -> Vec3
@@ -21,6 +37,44 @@
 //! Vec3.wedge(Line) -> XYW
 //! Line.anti_wedge(Vec3) -> S
 //! Vec3.anti_wedge(Line) -> S
+//! Vec3.left_contraction(Line) -> Vec3
+//! Line.right_contraction(Vec3) -> Vec3
+//! Line.commutator(Vec3) -> Vec3
+//! Vec3.commutator(Line) -> Vec3
+//! Line.anti_dot(Vec3) -> Line
+//! Vec3.anti_dot(Line) -> Line
+//! Line.geometric(Point2) -> Multivector
+//! Point2.geometric(Line) -> Multivector
+//! Line.anti_geometric(Point2) -> Motor
+//! Point2.anti_geometric(Line) -> Motor
+//! Line.dot(Point2) -> Vec3
+//! Point2.dot(Line) -> Vec3
+//! Line.wedge(Point2) -> XYW
+//! Point2.wedge(Line) -> XYW
+//! Line.anti_wedge(Point2) -> S
+//! Point2.anti_wedge(Line) -> S
+//! Point2.left_contraction(Line) -> Vec3
+//! Line.right_contraction(Point2) -> Vec3
+//! Line.commutator(Point2) -> Vec3
+//! Point2.commutator(Line) -> Vec3
+//! Line.anti_dot(Point2) -> Line
+//! Point2.anti_dot(Line) -> Line
+//! Line.geometric(Translator) -> Line
+//! Translator.geometric(Line) -> Line
+//! Line.anti_geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Line) -> Multivector
+//! Line.dot(Translator) -> Line
+//! Translator.dot(Line) -> Line
+//! Line.wedge(Translator) -> Line
+//! Translator.wedge(Line) -> Line
+//! Line.anti_wedge(Translator) -> Vec3
+//! Translator.anti_wedge(Line) -> Vec3
+//! Translator.left_contraction(Line) -> Line
+//! Line.right_contraction(Translator) -> Line
+//! Line.commutator(Translator) -> Line
+//! Translator.commutator(Line) -> Line
+//! Line.anti_dot(Translator) -> Multivector
+//! Translator.anti_dot(Line) -> Multivector
 //! Line.geometric(Rotor) -> Motor
 //! Rotor.geometric(Line) -> Motor
 //! Line.anti_geometric(Rotor) -> Vec2
@@ -31,25 +85,105 @@
 //! Rotor.wedge(Line) -> Line
 //! Line.anti_wedge(Rotor) -> Vec2
 //! Rotor.anti_wedge(Line) -> Vec2
+//! Line.left_contraction(Rotor) -> S
+//! Rotor.left_contraction(Line) -> Motor
+//! Line.right_contraction(Rotor) -> Motor
+//! Rotor.right_contraction(Line) -> S
+//! Line.scalar_product(Rotor) -> S
+//! Rotor.scalar_product(Line) -> S
+//! Line.commutator(Rotor) -> Line
+//! Rotor.commutator(Line) -> Line
+//! Line.anti_dot(Rotor) -> Vec2
+//! Rotor.anti_dot(Line) -> Vec2
 //! Line.geometric(Motor) -> Motor
 //! Motor.geometric(Line) -> Motor
+//! Line.anti_geometric(Motor) -> Multivector
+//! Motor.anti_geometric(Line) -> Multivector
 //! Line.dot(Motor) -> Motor
 //! Motor.dot(Line) -> Motor
 //! Line.wedge(Motor) -> Line
 //! Motor.wedge(Line) -> Line
 //! Line.anti_wedge(Motor) -> Vec3
 //! Motor.anti_wedge(Line) -> Vec3
+//! Line.left_contraction(Motor) -> S
+//! Motor.left_contraction(Line) -> Motor
+//! Line.right_contraction(Motor) -> Motor
+//! Motor.right_contraction(Line) -> S
+//! Line.scalar_product(Motor) -> S
+//! Motor.scalar_product(Line) -> S
+//! Line.commutator(Motor) -> Line
+//! Motor.commutator(Line) -> Line
+//! Line.anti_dot(Motor) -> Multivector
+//! Motor.anti_dot(Line) -> Multivector
+//! Line.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Line) -> Multivector
+//! Line.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Line) -> Multivector
+//! Line.dot(Multivector) -> Multivector
+//! Multivector.dot(Line) -> Multivector
+//! Line.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Line) -> Multivector
+//! Line.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Line) -> Multivector
+//! Line.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Line) -> Multivector
+//! Line.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Line) -> Multivector
+//! Line.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Line) -> S
+//! Line.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Line) -> Multivector
+//! Line.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Line) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line {
 	pub dx: YW,
 	pub dy: WX,
 	pub m: XY,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Line {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Line {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Line {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.dx.abs_diff_eq(&other.dx, epsilon)
+			&& self.dy.abs_diff_eq(&other.dy, epsilon)
+			&& self.m.abs_diff_eq(&other.m, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Line {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.dx.relative_eq(&other.dx, epsilon, max_relative)
+			&& self.dy.relative_eq(&other.dy, epsilon, max_relative)
+			&& self.m.relative_eq(&other.m, epsilon, max_relative)
+	}
+}
+
 // ---------------------------------------------------------------------
 
 impl RCompl for Line {
@@ -63,6 +197,13 @@ impl RCompl for Line {
 	}
 }
 
+impl std::ops::Not for Line {
+	type Output = Vec3;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl LCompl for Line {
 	type Output = Vec3;
 	fn lcompl(self) -> Self::Output {
@@ -94,10 +235,242 @@ impl AntiReverse for Line {
 	}
 }
 
+impl Involute for Line {
+	fn involute(self) -> Self {
+		Line {
+			dx: self.dx,
+			dy: self.dy,
+			m: self.m,
+		}
+	}
+}
+
+impl Conjugate for Line {
+	fn conjugate(self) -> Self {
+		Line {
+			dx: -self.dx,
+			dy: -self.dy,
+			m: -self.m,
+		}
+	}
+}
+
+impl Dual for Line {
+	type Output = Vec3;
+	fn dual(self) -> Self::Output {
+		Vec3 {
+			x: self.dx.dual(),
+			y: self.dy.dual(),
+			w: self.m.dual(),
+		}
+	}
+}
+
+impl Bulk for Line {
+	type Output = XY;
+	fn bulk(self) -> Self::Output {
+		self.m
+	}
+}
+
+impl Weight for Line {
+	type Output = Line;
+	fn weight(self) -> Self::Output {
+		Line {
+			dx: self.dx,
+			dy: self.dy,
+			m: Default::default(),
+		}
+	}
+}
+
+impl BulkDual for Line {
+	type Output = W;
+	fn bulk_dual(self) -> Self::Output {
+		self.m.bulk_dual()
+	}
+}
+
+impl WeightDual for Line {
+	type Output = Vec2;
+	fn weight_dual(self) -> Self::Output {
+		Vec2 {
+			x: self.dx.weight_dual(),
+			y: self.dy.weight_dual(),
+		}
+	}
+}
+
+impl Square for Line {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.m.geometric(self.m).0
+	}
+}
+
+impl Norm for Line {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.dx.0 * self.dx.0 + self.dy.0 * self.dy.0 + self.m.0 * self.m.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.m.0 * self.m.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.dx.0 * self.dx.0 + self.dy.0 * self.dy.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.weight_norm();
+		Line {
+			dx: self.dx * scale,
+			dy: self.dy * scale,
+			m: self.m * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Line {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 3] {
+		[self.dx.0, self.dy.0, self.m.0]
+	}
+}
+
+impl From<[f64; 3]> for Line {
+	fn from(a: [f64; 3]) -> Self {
+		Line {
+			dx: YW(a[0]),
+			dy: WX(a[1]),
+			m: XY(a[2]),
+		}
+	}
+}
+
+impl From<Line> for [f64; 3] {
+	fn from(v: Line) -> Self {
+		v.to_array()
+	}
+}
+
+impl Line {
+	/// The line through `a` and `b`, directed from `a` to `b`.
+	/// Same as `Vec3::point(a[0], a[1]) ^ Vec3::point(b[0], b[1])`.
+	pub fn from_points(a: [f64; 2], b: [f64; 2]) -> Self {
+		let dir = [b[0] - a[0], b[1] - a[1]];
+		Self::from_point_and_direction(a, dir)
+	}
+
+	/// The line through `point` with the given direction.
+	pub fn from_point_and_direction(point: [f64; 2], dir: [f64; 2]) -> Self {
+		let [x, y] = point;
+		Line {
+			dx: YW(-dir[1]),
+			dy: WX(dir[0]),
+			m: XY(x * dir[1] - y * dir[0]),
+		}
+	}
+}
+
+impl From<Line> for Multivector {
+	fn from(v: Line) -> Self {
+		Multivector {
+			yw: v.dx,
+			wx: v.dy,
+			xy: v.m,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Line {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Line` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0 && mv.x.0 == 0.0 && mv.y.0 == 0.0 && mv.w.0 == 0.0 && mv.xyw.0 == 0.0 {
+			Ok(Line {
+				dx: mv.yw,
+				dy: mv.wx,
+				m: mv.xy,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Exp for Line {
+	type Output = Motor;
+
+	/// The motor that rotates by `2 * self.m` around the line, or translates if `m` is zero.
+	fn exp(self) -> Self::Output {
+		let angle = self.m.0.abs();
+		let (sin_over_angle, cos) = if angle == 0.0 {
+			(1.0, 1.0)
+		} else {
+			(angle.sin() / angle, angle.cos())
+		};
+		Motor {
+			s: S(cos),
+			yw: self.dx * sin_over_angle,
+			wx: self.dy * sin_over_angle,
+			xy: self.m * sin_over_angle,
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Line OP Vec2:
 
-// Omitted: Line geometric Vec2 = self.dx * rhs.x + self.dx * rhs.y + self.dy * rhs.x + self.dy * rhs.y + self.m * rhs.x + self.m * rhs.y  (unnamed type)
+// Line.geometric(Vec2) -> Multivector
+impl Geometric<Vec2> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.m.0 * rhs.y.0),
+		//     y  : -Y(self.m.0 * rhs.x.0),
+		//     w  : -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.dx.0 * rhs.x.0) + XYW(self.dy.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.m.geometric(rhs.y),
+			y: self.m.geometric(rhs.x),
+			w: self.dx.geometric(rhs.y) + self.dy.geometric(rhs.x),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.dx.geometric(rhs.x) + self.dy.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec2> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
 // Line.anti_geometric(Vec2) -> Rotor
 impl AntiGeometric<Vec2> for Line {
@@ -105,11 +478,11 @@ impl AntiGeometric<Vec2> for Line {
 	fn anti_geometric(self, rhs: Vec2) -> Self::Output {
 		// Rotor {
 		//     s : S(self.dx.0 * rhs.x.0) + S(self.dy.0 * rhs.y.0),
-		//     xy: XY(self.dx.0 * rhs.y.0) + XY(self.dy.0 * rhs.x.0),
+		//     xy: -XY(self.dx.0 * rhs.y.0) + XY(self.dy.0 * rhs.x.0),
 		// }
 		Rotor {
 			s: self.dx.anti_geometric(rhs.x) + self.dy.anti_geometric(rhs.y),
-			xy: -self.dx.anti_geometric(rhs.y) + self.dy.anti_geometric(rhs.x),
+			xy: self.dx.anti_geometric(rhs.y) + self.dy.anti_geometric(rhs.x),
 		}
 	}
 }
@@ -120,17 +493,24 @@ impl Dot<Vec2> for Line {
 	fn dot(self, rhs: Vec2) -> Self::Output {
 		// Vec3 {
 		//     x: X(self.m.0 * rhs.y.0),
-		//     y: Y(self.m.0 * rhs.x.0),
-		//     w: W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		//     y: -Y(self.m.0 * rhs.x.0),
+		//     w: -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
 		// }
 		Vec3 {
 			x: self.m.dot(rhs.y),
-			y: -self.m.dot(rhs.x),
-			w: -self.dx.dot(rhs.y) + self.dy.dot(rhs.x),
+			y: self.m.dot(rhs.x),
+			w: self.dx.dot(rhs.y) + self.dy.dot(rhs.x),
 		}
 	}
 }
 
+impl std::ops::BitOr<Vec2> for Line {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Line.wedge(Vec2) -> XYW
 impl Wedge<Vec2> for Line {
 	type Output = XYW;
@@ -140,6 +520,13 @@ impl Wedge<Vec2> for Line {
 	}
 }
 
+impl std::ops::BitXor<Vec2> for Line {
+	type Output = XYW;
+	fn bitxor(self, rhs: Vec2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Line.anti_wedge(Vec2) -> S
 impl AntiWedge<Vec2> for Line {
 	type Output = S;
@@ -149,10 +536,106 @@ impl AntiWedge<Vec2> for Line {
 	}
 }
 
+impl std::ops::BitAnd<Vec2> for Line {
+	type Output = S;
+	fn bitand(self, rhs: Vec2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec2> for Line {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.right_contraction(Vec2) -> Vec3
+impl RightContraction<Vec2> for Line {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Vec2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.m.0 * rhs.y.0),
+		//     y: -Y(self.m.0 * rhs.x.0),
+		//     w: -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.m.right_contraction(rhs.y),
+			y: self.m.right_contraction(rhs.x),
+			w: self.dx.right_contraction(rhs.y) + self.dy.right_contraction(rhs.x),
+		}
+	}
+}
+
+impl ScalarProduct<Vec2> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(Vec2) -> Vec3
+impl Commutator<Vec2> for Line {
+	type Output = Vec3;
+	fn commutator(self, rhs: Vec2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.m.0 * rhs.y.0),
+		//     y: -Y(self.m.0 * rhs.x.0),
+		//     w: -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.m.commutator(rhs.y),
+			y: self.m.commutator(rhs.x),
+			w: self.dx.commutator(rhs.y) + self.dy.commutator(rhs.x),
+		}
+	}
+}
+
+// Line.anti_dot(Vec2) -> XY
+impl AntiDot<Vec2> for Line {
+	type Output = XY;
+	fn anti_dot(self, rhs: Vec2) -> Self::Output {
+		// -XY(self.dx.0 * rhs.y.0) + XY(self.dy.0 * rhs.x.0)
+		self.dx.anti_dot(rhs.y) + self.dy.anti_dot(rhs.x)
+	}
+}
+
 // ---------------------------------------------------------------------
 // Line OP Vec3:
 
-// Omitted: Line geometric Vec3 = self.dx * rhs.x + self.dx * rhs.y + self.dy * rhs.x + self.dy * rhs.y + self.m * rhs.w + self.m * rhs.x + self.m * rhs.y  (unnamed type)
+// Line.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.m.0 * rhs.y.0),
+		//     y  : -Y(self.m.0 * rhs.x.0),
+		//     w  : -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.dx.0 * rhs.x.0) + XYW(self.dy.0 * rhs.y.0) + XYW(self.m.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.m.geometric(rhs.y),
+			y: self.m.geometric(rhs.x),
+			w: self.dx.geometric(rhs.y) + self.dy.geometric(rhs.x),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.dx.geometric(rhs.x) + self.dy.geometric(rhs.y) + self.m.geometric(rhs.w),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
 // Line.anti_geometric(Vec3) -> Motor
 impl AntiGeometric<Vec3> for Line {
@@ -160,15 +643,15 @@ impl AntiGeometric<Vec3> for Line {
 	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
 		// Motor {
 		//     s : S(self.dx.0 * rhs.x.0) + S(self.dy.0 * rhs.y.0) + S(self.m.0 * rhs.w.0),
-		//     yw: YW(self.dy.0 * rhs.w.0),
+		//     yw: -YW(self.dy.0 * rhs.w.0),
 		//     wx: WX(self.dx.0 * rhs.w.0),
-		//     xy: XY(self.dx.0 * rhs.y.0) + XY(self.dy.0 * rhs.x.0),
+		//     xy: -XY(self.dx.0 * rhs.y.0) + XY(self.dy.0 * rhs.x.0),
 		// }
 		Motor {
 			s: self.dx.anti_geometric(rhs.x) + self.dy.anti_geometric(rhs.y) + self.m.anti_geometric(rhs.w),
-			yw: -self.dy.anti_geometric(rhs.w),
+			yw: self.dy.anti_geometric(rhs.w),
 			wx: self.dx.anti_geometric(rhs.w),
-			xy: -self.dx.anti_geometric(rhs.y) + self.dy.anti_geometric(rhs.x),
+			xy: self.dx.anti_geometric(rhs.y) + self.dy.anti_geometric(rhs.x),
 		}
 	}
 }
@@ -179,17 +662,24 @@ impl Dot<Vec3> for Line {
 	fn dot(self, rhs: Vec3) -> Self::Output {
 		// Vec3 {
 		//     x: X(self.m.0 * rhs.y.0),
-		//     y: Y(self.m.0 * rhs.x.0),
-		//     w: W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		//     y: -Y(self.m.0 * rhs.x.0),
+		//     w: -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
 		// }
 		Vec3 {
 			x: self.m.dot(rhs.y),
-			y: -self.m.dot(rhs.x),
-			w: -self.dx.dot(rhs.y) + self.dy.dot(rhs.x),
+			y: self.m.dot(rhs.x),
+			w: self.dx.dot(rhs.y) + self.dy.dot(rhs.x),
 		}
 	}
 }
 
+impl std::ops::BitOr<Vec3> for Line {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Line.wedge(Vec3) -> XYW
 impl Wedge<Vec3> for Line {
 	type Output = XYW;
@@ -199,6 +689,13 @@ impl Wedge<Vec3> for Line {
 	}
 }
 
+impl std::ops::BitXor<Vec3> for Line {
+	type Output = XYW;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Line.anti_wedge(Vec3) -> S
 impl AntiWedge<Vec3> for Line {
 	type Output = S;
@@ -208,124 +705,504 @@ impl AntiWedge<Vec3> for Line {
 	}
 }
 
-// ---------------------------------------------------------------------
-// Line OP Line:
+impl std::ops::BitAnd<Vec3> for Line {
+	type Output = S;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-// Line.geometric(Line) -> Motor
-impl Geometric<Line> for Line {
-	type Output = Motor;
-	fn geometric(self, rhs: Line) -> Self::Output {
-		// Motor {
-		//     s : S(self.m.0 * rhs.m.0),
-		//     yw: YW(self.dy.0 * rhs.m.0) + YW(self.m.0 * rhs.dy.0),
-		//     wx: WX(self.dx.0 * rhs.m.0) + WX(self.m.0 * rhs.dx.0),
-		//     xy: Default::default(),
+impl LeftContraction<Vec3> for Line {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.right_contraction(Vec3) -> Vec3
+impl RightContraction<Vec3> for Line {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.m.0 * rhs.y.0),
+		//     y: -Y(self.m.0 * rhs.x.0),
+		//     w: -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
 		// }
-		Motor {
-			s: -self.m.geometric(rhs.m),
-			yw: -self.dy.geometric(rhs.m) + self.m.geometric(rhs.dy),
-			wx: self.dx.geometric(rhs.m) - self.m.geometric(rhs.dx),
-			xy: Default::default(),
+		Vec3 {
+			x: self.m.right_contraction(rhs.y),
+			y: self.m.right_contraction(rhs.x),
+			w: self.dx.right_contraction(rhs.y) + self.dy.right_contraction(rhs.x),
 		}
 	}
 }
 
-// Omitted: Line anti_geometric Line = self.dx !* rhs.dx + self.dx !* rhs.dy + self.dx !* rhs.m + self.dy !* rhs.dx + self.dy !* rhs.dy + self.dy !* rhs.m + self.m !* rhs.dx + self.m !* rhs.dy  (unnamed type)
-
-// Line.dot(Line) -> S
-impl Dot<Line> for Line {
-	type Output = S;
-	fn dot(self, rhs: Line) -> Self::Output {
-		// -S(self.m.0 * rhs.m.0)
-		self.m.dot(rhs.m)
+impl ScalarProduct<Vec3> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
 	}
 }
 
-// Omitted: Line wedge Line = 0  (unnamed type)
-
-// Line.anti_wedge(Line) -> Vec3
-impl AntiWedge<Line> for Line {
+// Line.commutator(Vec3) -> Vec3
+impl Commutator<Vec3> for Line {
 	type Output = Vec3;
-	fn anti_wedge(self, rhs: Line) -> Self::Output {
+	fn commutator(self, rhs: Vec3) -> Self::Output {
 		// Vec3 {
-		//     x: X(self.dy.0 * rhs.m.0) + X(self.m.0 * rhs.dy.0),
-		//     y: Y(self.dx.0 * rhs.m.0) + Y(self.m.0 * rhs.dx.0),
-		//     w: W(self.dx.0 * rhs.dy.0) + W(self.dy.0 * rhs.dx.0),
+		//     x: X(self.m.0 * rhs.y.0),
+		//     y: -Y(self.m.0 * rhs.x.0),
+		//     w: -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
 		// }
 		Vec3 {
-			x: self.dy.anti_wedge(rhs.m) - self.m.anti_wedge(rhs.dy),
-			y: -self.dx.anti_wedge(rhs.m) + self.m.anti_wedge(rhs.dx),
-			w: self.dx.anti_wedge(rhs.dy) - self.dy.anti_wedge(rhs.dx),
+			x: self.m.commutator(rhs.y),
+			y: self.m.commutator(rhs.x),
+			w: self.dx.commutator(rhs.y) + self.dy.commutator(rhs.x),
 		}
 	}
 }
 
-// ---------------------------------------------------------------------
-// Line OP Rotor:
-
-// Line.geometric(Rotor) -> Motor
-impl Geometric<Rotor> for Line {
-	type Output = Motor;
-	fn geometric(self, rhs: Rotor) -> Self::Output {
-		// Motor {
-		//     s : S(self.m.0 * rhs.xy.0),
-		//     yw: YW(self.dx.0 * rhs.s.0) + YW(self.dy.0 * rhs.xy.0),
-		//     wx: WX(self.dx.0 * rhs.xy.0) + WX(self.dy.0 * rhs.s.0),
-		//     xy: XY(self.m.0 * rhs.s.0),
+// Line.anti_dot(Vec3) -> Line
+impl AntiDot<Vec3> for Line {
+	type Output = Line;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.dy.0 * rhs.w.0),
+		//     dy: WX(self.dx.0 * rhs.w.0),
+		//     m : -XY(self.dx.0 * rhs.y.0) + XY(self.dy.0 * rhs.x.0),
 		// }
-		Motor {
-			s: -self.m.geometric(rhs.xy),
-			yw: self.dx.geometric(rhs.s) - self.dy.geometric(rhs.xy),
-			wx: self.dx.geometric(rhs.xy) + self.dy.geometric(rhs.s),
-			xy: self.m.geometric(rhs.s),
+		Line {
+			dx: self.dy.anti_dot(rhs.w),
+			dy: self.dx.anti_dot(rhs.w),
+			m: self.dx.anti_dot(rhs.y) + self.dy.anti_dot(rhs.x),
 		}
 	}
 }
 
-// Line.anti_geometric(Rotor) -> Vec2
-impl AntiGeometric<Rotor> for Line {
-	type Output = Vec2;
-	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
-		// Vec2 {
-		//     x: X(self.dx.0 * rhs.s.0) + X(self.dy.0 * rhs.xy.0),
-		//     y: Y(self.dx.0 * rhs.xy.0) + Y(self.dy.0 * rhs.s.0),
+// ---------------------------------------------------------------------
+// Line OP Point2:
+
+// Line.geometric(Point2) -> Multivector
+impl Geometric<Point2> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.m.0 * rhs.y.0),
+		//     y  : -Y(self.m.0 * rhs.x.0),
+		//     w  : -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.dx.0 * rhs.x.0) + XYW(self.dy.0 * rhs.y.0) + XYW(self.m.0),
 		// }
-		Vec2 {
-			x: self.dx.anti_geometric(rhs.s) + self.dy.anti_geometric(rhs.xy),
-			y: -self.dx.anti_geometric(rhs.xy) + self.dy.anti_geometric(rhs.s),
+		Multivector {
+			s: Default::default(),
+			x: self.m.geometric(rhs.y),
+			y: self.m.geometric(rhs.x),
+			w: self.dx.geometric(rhs.y) + self.dy.geometric(rhs.x),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.dx.geometric(rhs.x) + self.dy.geometric(rhs.y) + self.m.geometric(W(1.0)),
 		}
 	}
 }
 
-// Line.dot(Rotor) -> Motor
-impl Dot<Rotor> for Line {
+impl std::ops::Mul<Point2> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: Point2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Point2) -> Motor
+impl AntiGeometric<Point2> for Line {
 	type Output = Motor;
-	fn dot(self, rhs: Rotor) -> Self::Output {
+	fn anti_geometric(self, rhs: Point2) -> Self::Output {
 		// Motor {
-		//     s : S(self.m.0 * rhs.xy.0),
-		//     yw: YW(self.dx.0 * rhs.s.0),
-		//     wx: WX(self.dy.0 * rhs.s.0),
-		//     xy: XY(self.m.0 * rhs.s.0),
+		//     s : S(self.dx.0 * rhs.x.0) + S(self.dy.0 * rhs.y.0) + S(self.m.0),
+		//     yw: -YW(self.dy.0),
+		//     wx: WX(self.dx.0),
+		//     xy: -XY(self.dx.0 * rhs.y.0) + XY(self.dy.0 * rhs.x.0),
 		// }
 		Motor {
-			s: -self.m.dot(rhs.xy),
-			yw: self.dx.dot(rhs.s),
-			wx: self.dy.dot(rhs.s),
-			xy: self.m.dot(rhs.s),
+			s: self.dx.anti_geometric(rhs.x) + self.dy.anti_geometric(rhs.y) + self.m.anti_geometric(W(1.0)),
+			yw: self.dy.anti_geometric(W(1.0)),
+			wx: self.dx.anti_geometric(W(1.0)),
+			xy: self.dx.anti_geometric(rhs.y) + self.dy.anti_geometric(rhs.x),
 		}
 	}
 }
 
-// Line.wedge(Rotor) -> Line
-impl Wedge<Rotor> for Line {
-	type Output = Line;
-	fn wedge(self, rhs: Rotor) -> Self::Output {
-		// Line {
-		//     dx: YW(self.dx.0 * rhs.s.0),
-		//     dy: WX(self.dy.0 * rhs.s.0),
-		//     m : XY(self.m.0 * rhs.s.0),
-		// }
-		Line {
+// Line.dot(Point2) -> Vec3
+impl Dot<Point2> for Line {
+	type Output = Vec3;
+	fn dot(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.m.0 * rhs.y.0),
+		//     y: -Y(self.m.0 * rhs.x.0),
+		//     w: -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.m.dot(rhs.y),
+			y: self.m.dot(rhs.x),
+			w: self.dx.dot(rhs.y) + self.dy.dot(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point2> for Line {
+	type Output = Vec3;
+	fn bitor(self, rhs: Point2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(Point2) -> XYW
+impl Wedge<Point2> for Line {
+	type Output = XYW;
+	fn wedge(self, rhs: Point2) -> Self::Output {
+		// XYW(self.dx.0 * rhs.x.0) + XYW(self.dy.0 * rhs.y.0) + XYW(self.m.0)
+		self.dx.wedge(rhs.x) + self.dy.wedge(rhs.y) + self.m.wedge(W(1.0))
+	}
+}
+
+impl std::ops::BitXor<Point2> for Line {
+	type Output = XYW;
+	fn bitxor(self, rhs: Point2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Point2) -> S
+impl AntiWedge<Point2> for Line {
+	type Output = S;
+	fn anti_wedge(self, rhs: Point2) -> Self::Output {
+		// S(self.dx.0 * rhs.x.0) + S(self.dy.0 * rhs.y.0) + S(self.m.0)
+		self.dx.anti_wedge(rhs.x) + self.dy.anti_wedge(rhs.y) + self.m.anti_wedge(W(1.0))
+	}
+}
+
+impl std::ops::BitAnd<Point2> for Line {
+	type Output = S;
+	fn bitand(self, rhs: Point2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point2> for Line {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.right_contraction(Point2) -> Vec3
+impl RightContraction<Point2> for Line {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.m.0 * rhs.y.0),
+		//     y: -Y(self.m.0 * rhs.x.0),
+		//     w: -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.m.right_contraction(rhs.y),
+			y: self.m.right_contraction(rhs.x),
+			w: self.dx.right_contraction(rhs.y) + self.dy.right_contraction(rhs.x),
+		}
+	}
+}
+
+impl ScalarProduct<Point2> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(Point2) -> Vec3
+impl Commutator<Point2> for Line {
+	type Output = Vec3;
+	fn commutator(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.m.0 * rhs.y.0),
+		//     y: -Y(self.m.0 * rhs.x.0),
+		//     w: -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.m.commutator(rhs.y),
+			y: self.m.commutator(rhs.x),
+			w: self.dx.commutator(rhs.y) + self.dy.commutator(rhs.x),
+		}
+	}
+}
+
+// Line.anti_dot(Point2) -> Line
+impl AntiDot<Point2> for Line {
+	type Output = Line;
+	fn anti_dot(self, rhs: Point2) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.dy.0),
+		//     dy: WX(self.dx.0),
+		//     m : -XY(self.dx.0 * rhs.y.0) + XY(self.dy.0 * rhs.x.0),
+		// }
+		Line {
+			dx: self.dy.anti_dot(W(1.0)),
+			dy: self.dx.anti_dot(W(1.0)),
+			m: self.dx.anti_dot(rhs.y) + self.dy.anti_dot(rhs.x),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Line:
+
+// Line.geometric(Line) -> Translator
+impl Geometric<Line> for Line {
+	type Output = Translator;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Translator {
+		//     s : -S(self.m.0 * rhs.m.0),
+		//     yw: -YW(self.dy.0 * rhs.m.0) + YW(self.m.0 * rhs.dy.0),
+		//     wx: WX(self.dx.0 * rhs.m.0) - WX(self.m.0 * rhs.dx.0),
+		// }
+		Translator {
+			s: self.m.geometric(rhs.m),
+			yw: self.dy.geometric(rhs.m) + self.m.geometric(rhs.dy),
+			wx: self.dx.geometric(rhs.m) + self.m.geometric(rhs.dx),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Line {
+	type Output = Translator;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Line) -> Multivector
+impl AntiGeometric<Line> for Line {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.dy.0 * rhs.m.0) - X(self.m.0 * rhs.dy.0),
+		//     y  : -Y(self.dx.0 * rhs.m.0) + Y(self.m.0 * rhs.dx.0),
+		//     w  : W(self.dx.0 * rhs.dy.0) - W(self.dy.0 * rhs.dx.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.dx.0 * rhs.dx.0) + XYW(self.dy.0 * rhs.dy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.dy.anti_geometric(rhs.m) + self.m.anti_geometric(rhs.dy),
+			y: self.dx.anti_geometric(rhs.m) + self.m.anti_geometric(rhs.dx),
+			w: self.dx.anti_geometric(rhs.dy) + self.dy.anti_geometric(rhs.dx),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.dx.anti_geometric(rhs.dx) + self.dy.anti_geometric(rhs.dy),
+		}
+	}
+}
+
+// Line.dot(Line) -> S
+impl Dot<Line> for Line {
+	type Output = S;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// -S(self.m.0 * rhs.m.0)
+		self.m.dot(rhs.m)
+	}
+}
+
+impl std::ops::BitOr<Line> for Line {
+	type Output = S;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Line> for Line {
+	type Output = Zero;
+	fn wedge(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Line> for Line {
+	type Output = Zero;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Line) -> Vec3
+impl AntiWedge<Line> for Line {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.dy.0 * rhs.m.0) - X(self.m.0 * rhs.dy.0),
+		//     y: -Y(self.dx.0 * rhs.m.0) + Y(self.m.0 * rhs.dx.0),
+		//     w: W(self.dx.0 * rhs.dy.0) - W(self.dy.0 * rhs.dx.0),
+		// }
+		Vec3 {
+			x: self.dy.anti_wedge(rhs.m) + self.m.anti_wedge(rhs.dy),
+			y: self.dx.anti_wedge(rhs.m) + self.m.anti_wedge(rhs.dx),
+			w: self.dx.anti_wedge(rhs.dy) + self.dy.anti_wedge(rhs.dx),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Line {
+	type Output = Vec3;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line.left_contraction(Line) -> S
+impl LeftContraction<Line> for Line {
+	type Output = S;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// -S(self.m.0 * rhs.m.0)
+		self.m.left_contraction(rhs.m)
+	}
+}
+
+// Line.right_contraction(Line) -> S
+impl RightContraction<Line> for Line {
+	type Output = S;
+	fn right_contraction(self, rhs: Line) -> Self::Output {
+		// -S(self.m.0 * rhs.m.0)
+		self.m.right_contraction(rhs.m)
+	}
+}
+
+// Line.scalar_product(Line) -> S
+impl ScalarProduct<Line> for Line {
+	type Output = S;
+	fn scalar_product(self, rhs: Line) -> Self::Output {
+		// -S(self.m.0 * rhs.m.0)
+		self.m.scalar_product(rhs.m)
+	}
+}
+
+// Line.commutator(Line) -> Line
+impl Commutator<Line> for Line {
+	type Output = Line;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.dy.0 * rhs.m.0) + YW(self.m.0 * rhs.dy.0),
+		//     dy: WX(self.dx.0 * rhs.m.0) - WX(self.m.0 * rhs.dx.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.dy.commutator(rhs.m) + self.m.commutator(rhs.dy),
+			dy: self.dx.commutator(rhs.m) + self.m.commutator(rhs.dx),
+			m: Default::default(),
+		}
+	}
+}
+
+// Line.anti_dot(Line) -> XYW
+impl AntiDot<Line> for Line {
+	type Output = XYW;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// XYW(self.dx.0 * rhs.dx.0) + XYW(self.dy.0 * rhs.dy.0)
+		self.dx.anti_dot(rhs.dx) + self.dy.anti_dot(rhs.dy)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Translator:
+
+// Line.geometric(Translator) -> Line
+impl Geometric<Translator> for Line {
+	type Output = Line;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Line {
+		//     dx: YW(self.dx.0 * rhs.s.0) + YW(self.m.0 * rhs.wx.0),
+		//     dy: WX(self.dy.0 * rhs.s.0) - WX(self.m.0 * rhs.yw.0),
+		//     m : XY(self.m.0 * rhs.s.0),
+		// }
+		Line {
+			dx: self.dx.geometric(rhs.s) + self.m.geometric(rhs.wx),
+			dy: self.dy.geometric(rhs.s) + self.m.geometric(rhs.yw),
+			m: self.m.geometric(rhs.s),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Line {
+	type Output = Line;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Translator) -> Multivector
+impl AntiGeometric<Translator> for Line {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.dx.0 * rhs.s.0) - X(self.m.0 * rhs.wx.0),
+		//     y  : Y(self.dy.0 * rhs.s.0) + Y(self.m.0 * rhs.yw.0),
+		//     w  : W(self.dx.0 * rhs.wx.0) - W(self.dy.0 * rhs.yw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.dx.0 * rhs.yw.0) + XYW(self.dy.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.dx.anti_geometric(rhs.s) + self.m.anti_geometric(rhs.wx),
+			y: self.dy.anti_geometric(rhs.s) + self.m.anti_geometric(rhs.yw),
+			w: self.dx.anti_geometric(rhs.wx) + self.dy.anti_geometric(rhs.yw),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.dx.anti_geometric(rhs.yw) + self.dy.anti_geometric(rhs.wx),
+		}
+	}
+}
+
+// Line.dot(Translator) -> Line
+impl Dot<Translator> for Line {
+	type Output = Line;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Line {
+		//     dx: YW(self.dx.0 * rhs.s.0),
+		//     dy: WX(self.dy.0 * rhs.s.0),
+		//     m : XY(self.m.0 * rhs.s.0),
+		// }
+		Line {
+			dx: self.dx.dot(rhs.s),
+			dy: self.dy.dot(rhs.s),
+			m: self.m.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Line {
+	type Output = Line;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(Translator) -> Line
+impl Wedge<Translator> for Line {
+	type Output = Line;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Line {
+		//     dx: YW(self.dx.0 * rhs.s.0),
+		//     dy: WX(self.dy.0 * rhs.s.0),
+		//     m : XY(self.m.0 * rhs.s.0),
+		// }
+		Line {
 			dx: self.dx.wedge(rhs.s),
 			dy: self.dy.wedge(rhs.s),
 			m: self.m.wedge(rhs.s),
@@ -333,17 +1210,293 @@ impl Wedge<Rotor> for Line {
 	}
 }
 
+impl std::ops::BitXor<Translator> for Line {
+	type Output = Line;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Translator) -> Vec3
+impl AntiWedge<Translator> for Line {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.m.0 * rhs.wx.0),
+		//     y: Y(self.m.0 * rhs.yw.0),
+		//     w: W(self.dx.0 * rhs.wx.0) - W(self.dy.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.m.anti_wedge(rhs.wx),
+			y: self.m.anti_wedge(rhs.yw),
+			w: self.dx.anti_wedge(rhs.wx) + self.dy.anti_wedge(rhs.yw),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Line {
+	type Output = Vec3;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Translator> for Line {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.right_contraction(Translator) -> Line
+impl RightContraction<Translator> for Line {
+	type Output = Line;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Line {
+		//     dx: YW(self.dx.0 * rhs.s.0),
+		//     dy: WX(self.dy.0 * rhs.s.0),
+		//     m : XY(self.m.0 * rhs.s.0),
+		// }
+		Line {
+			dx: self.dx.right_contraction(rhs.s),
+			dy: self.dy.right_contraction(rhs.s),
+			m: self.m.right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for Line {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line.commutator(Translator) -> Line
+impl Commutator<Translator> for Line {
+	type Output = Line;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// Line {
+		//     dx: YW(self.m.0 * rhs.wx.0),
+		//     dy: -WX(self.m.0 * rhs.yw.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.m.commutator(rhs.wx),
+			dy: self.m.commutator(rhs.yw),
+			m: Default::default(),
+		}
+	}
+}
+
+// Line.anti_dot(Translator) -> Multivector
+impl AntiDot<Translator> for Line {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.dx.0 * rhs.s.0),
+		//     y  : Y(self.dy.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.dx.0 * rhs.yw.0) + XYW(self.dy.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.dx.anti_dot(rhs.s),
+			y: self.dy.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.dx.anti_dot(rhs.yw) + self.dy.anti_dot(rhs.wx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Rotor:
+
+// Line.geometric(Rotor) -> Motor
+impl Geometric<Rotor> for Line {
+	type Output = Motor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Motor {
+		//     s : -S(self.m.0 * rhs.xy.0),
+		//     yw: YW(self.dx.0 * rhs.s.0) - YW(self.dy.0 * rhs.xy.0),
+		//     wx: WX(self.dx.0 * rhs.xy.0) + WX(self.dy.0 * rhs.s.0),
+		//     xy: XY(self.m.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.m.geometric(rhs.xy),
+			yw: self.dx.geometric(rhs.s) + self.dy.geometric(rhs.xy),
+			wx: self.dx.geometric(rhs.xy) + self.dy.geometric(rhs.s),
+			xy: self.m.geometric(rhs.s),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Line {
+	type Output = Motor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Rotor) -> Vec2
+impl AntiGeometric<Rotor> for Line {
+	type Output = Vec2;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.dx.0 * rhs.s.0) + X(self.dy.0 * rhs.xy.0),
+		//     y: -Y(self.dx.0 * rhs.xy.0) + Y(self.dy.0 * rhs.s.0),
+		// }
+		Vec2 {
+			x: self.dx.anti_geometric(rhs.s) + self.dy.anti_geometric(rhs.xy),
+			y: self.dx.anti_geometric(rhs.xy) + self.dy.anti_geometric(rhs.s),
+		}
+	}
+}
+
+// Line.dot(Rotor) -> Motor
+impl Dot<Rotor> for Line {
+	type Output = Motor;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Motor {
+		//     s : -S(self.m.0 * rhs.xy.0),
+		//     yw: YW(self.dx.0 * rhs.s.0),
+		//     wx: WX(self.dy.0 * rhs.s.0),
+		//     xy: XY(self.m.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.m.dot(rhs.xy),
+			yw: self.dx.dot(rhs.s),
+			wx: self.dy.dot(rhs.s),
+			xy: self.m.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Line {
+	type Output = Motor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(Rotor) -> Line
+impl Wedge<Rotor> for Line {
+	type Output = Line;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Line {
+		//     dx: YW(self.dx.0 * rhs.s.0),
+		//     dy: WX(self.dy.0 * rhs.s.0),
+		//     m : XY(self.m.0 * rhs.s.0),
+		// }
+		Line {
+			dx: self.dx.wedge(rhs.s),
+			dy: self.dy.wedge(rhs.s),
+			m: self.m.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Line {
+	type Output = Line;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Line.anti_wedge(Rotor) -> Vec2
 impl AntiWedge<Rotor> for Line {
 	type Output = Vec2;
 	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
 		// Vec2 {
 		//     x: X(self.dy.0 * rhs.xy.0),
-		//     y: Y(self.dx.0 * rhs.xy.0),
+		//     y: -Y(self.dx.0 * rhs.xy.0),
 		// }
 		Vec2 {
 			x: self.dy.anti_wedge(rhs.xy),
-			y: -self.dx.anti_wedge(rhs.xy),
+			y: self.dx.anti_wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Line {
+	type Output = Vec2;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line.left_contraction(Rotor) -> S
+impl LeftContraction<Rotor> for Line {
+	type Output = S;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// -S(self.m.0 * rhs.xy.0)
+		self.m.left_contraction(rhs.xy)
+	}
+}
+
+// Line.right_contraction(Rotor) -> Motor
+impl RightContraction<Rotor> for Line {
+	type Output = Motor;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Motor {
+		//     s : -S(self.m.0 * rhs.xy.0),
+		//     yw: YW(self.dx.0 * rhs.s.0),
+		//     wx: WX(self.dy.0 * rhs.s.0),
+		//     xy: XY(self.m.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.m.right_contraction(rhs.xy),
+			yw: self.dx.right_contraction(rhs.s),
+			wx: self.dy.right_contraction(rhs.s),
+			xy: self.m.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Line.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Line {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// -S(self.m.0 * rhs.xy.0)
+		self.m.scalar_product(rhs.xy)
+	}
+}
+
+// Line.commutator(Rotor) -> Line
+impl Commutator<Rotor> for Line {
+	type Output = Line;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.dy.0 * rhs.xy.0),
+		//     dy: WX(self.dx.0 * rhs.xy.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.dy.commutator(rhs.xy),
+			dy: self.dx.commutator(rhs.xy),
+			m: Default::default(),
+		}
+	}
+}
+
+// Line.anti_dot(Rotor) -> Vec2
+impl AntiDot<Rotor> for Line {
+	type Output = Vec2;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.dx.0 * rhs.s.0),
+		//     y: Y(self.dy.0 * rhs.s.0),
+		// }
+		Vec2 {
+			x: self.dx.anti_dot(rhs.s),
+			y: self.dy.anti_dot(rhs.s),
 		}
 	}
 }
@@ -356,34 +1509,66 @@ impl Geometric<Motor> for Line {
 	type Output = Motor;
 	fn geometric(self, rhs: Motor) -> Self::Output {
 		// Motor {
-		//     s : S(self.m.0 * rhs.xy.0),
-		//     yw: YW(self.dx.0 * rhs.s.0) + YW(self.dy.0 * rhs.xy.0) + YW(self.m.0 * rhs.wx.0),
-		//     wx: WX(self.dx.0 * rhs.xy.0) + WX(self.dy.0 * rhs.s.0) + WX(self.m.0 * rhs.yw.0),
+		//     s : -S(self.m.0 * rhs.xy.0),
+		//     yw: YW(self.dx.0 * rhs.s.0) - YW(self.dy.0 * rhs.xy.0) + YW(self.m.0 * rhs.wx.0),
+		//     wx: WX(self.dx.0 * rhs.xy.0) + WX(self.dy.0 * rhs.s.0) - WX(self.m.0 * rhs.yw.0),
 		//     xy: XY(self.m.0 * rhs.s.0),
 		// }
 		Motor {
-			s: -self.m.geometric(rhs.xy),
-			yw: self.dx.geometric(rhs.s) - self.dy.geometric(rhs.xy) + self.m.geometric(rhs.wx),
-			wx: self.dx.geometric(rhs.xy) + self.dy.geometric(rhs.s) - self.m.geometric(rhs.yw),
+			s: self.m.geometric(rhs.xy),
+			yw: self.dx.geometric(rhs.s) + self.dy.geometric(rhs.xy) + self.m.geometric(rhs.wx),
+			wx: self.dx.geometric(rhs.xy) + self.dy.geometric(rhs.s) + self.m.geometric(rhs.yw),
 			xy: self.m.geometric(rhs.s),
 		}
 	}
 }
 
-// Omitted: Line anti_geometric Motor = self.dx !* rhs.s + self.dx !* rhs.wx + self.dx !* rhs.xy + self.dx !* rhs.yw + self.dy !* rhs.s + self.dy !* rhs.wx + self.dy !* rhs.xy + self.dy !* rhs.yw + self.m !* rhs.wx + self.m !* rhs.yw  (unnamed type)
+impl std::ops::Mul<Motor> for Line {
+	type Output = Motor;
+	fn mul(self, rhs: Motor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Motor) -> Multivector
+impl AntiGeometric<Motor> for Line {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.dx.0 * rhs.s.0) + X(self.dy.0 * rhs.xy.0) - X(self.m.0 * rhs.wx.0),
+		//     y  : -Y(self.dx.0 * rhs.xy.0) + Y(self.dy.0 * rhs.s.0) + Y(self.m.0 * rhs.yw.0),
+		//     w  : W(self.dx.0 * rhs.wx.0) - W(self.dy.0 * rhs.yw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.dx.0 * rhs.yw.0) + XYW(self.dy.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.dx.anti_geometric(rhs.s) + self.dy.anti_geometric(rhs.xy) + self.m.anti_geometric(rhs.wx),
+			y: self.dx.anti_geometric(rhs.xy) + self.dy.anti_geometric(rhs.s) + self.m.anti_geometric(rhs.yw),
+			w: self.dx.anti_geometric(rhs.wx) + self.dy.anti_geometric(rhs.yw),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.dx.anti_geometric(rhs.yw) + self.dy.anti_geometric(rhs.wx),
+		}
+	}
+}
 
 // Line.dot(Motor) -> Motor
 impl Dot<Motor> for Line {
 	type Output = Motor;
 	fn dot(self, rhs: Motor) -> Self::Output {
 		// Motor {
-		//     s : S(self.m.0 * rhs.xy.0),
+		//     s : -S(self.m.0 * rhs.xy.0),
 		//     yw: YW(self.dx.0 * rhs.s.0),
 		//     wx: WX(self.dy.0 * rhs.s.0),
 		//     xy: XY(self.m.0 * rhs.s.0),
 		// }
 		Motor {
-			s: -self.m.dot(rhs.xy),
+			s: self.m.dot(rhs.xy),
 			yw: self.dx.dot(rhs.s),
 			wx: self.dy.dot(rhs.s),
 			xy: self.m.dot(rhs.s),
@@ -391,6 +1576,13 @@ impl Dot<Motor> for Line {
 	}
 }
 
+impl std::ops::BitOr<Motor> for Line {
+	type Output = Motor;
+	fn bitor(self, rhs: Motor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Line.wedge(Motor) -> Line
 impl Wedge<Motor> for Line {
 	type Output = Line;
@@ -408,19 +1600,397 @@ impl Wedge<Motor> for Line {
 	}
 }
 
+impl std::ops::BitXor<Motor> for Line {
+	type Output = Line;
+	fn bitxor(self, rhs: Motor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Line.anti_wedge(Motor) -> Vec3
 impl AntiWedge<Motor> for Line {
 	type Output = Vec3;
 	fn anti_wedge(self, rhs: Motor) -> Self::Output {
 		// Vec3 {
-		//     x: X(self.dy.0 * rhs.xy.0) + X(self.m.0 * rhs.wx.0),
-		//     y: Y(self.dx.0 * rhs.xy.0) + Y(self.m.0 * rhs.yw.0),
-		//     w: W(self.dx.0 * rhs.wx.0) + W(self.dy.0 * rhs.yw.0),
+		//     x: X(self.dy.0 * rhs.xy.0) - X(self.m.0 * rhs.wx.0),
+		//     y: -Y(self.dx.0 * rhs.xy.0) + Y(self.m.0 * rhs.yw.0),
+		//     w: W(self.dx.0 * rhs.wx.0) - W(self.dy.0 * rhs.yw.0),
 		// }
 		Vec3 {
-			x: self.dy.anti_wedge(rhs.xy) - self.m.anti_wedge(rhs.wx),
-			y: -self.dx.anti_wedge(rhs.xy) + self.m.anti_wedge(rhs.yw),
-			w: self.dx.anti_wedge(rhs.wx) - self.dy.anti_wedge(rhs.yw),
+			x: self.dy.anti_wedge(rhs.xy) + self.m.anti_wedge(rhs.wx),
+			y: self.dx.anti_wedge(rhs.xy) + self.m.anti_wedge(rhs.yw),
+			w: self.dx.anti_wedge(rhs.wx) + self.dy.anti_wedge(rhs.yw),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor> for Line {
+	type Output = Vec3;
+	fn bitand(self, rhs: Motor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line.left_contraction(Motor) -> S
+impl LeftContraction<Motor> for Line {
+	type Output = S;
+	fn left_contraction(self, rhs: Motor) -> Self::Output {
+		// -S(self.m.0 * rhs.xy.0)
+		self.m.left_contraction(rhs.xy)
+	}
+}
+
+// Line.right_contraction(Motor) -> Motor
+impl RightContraction<Motor> for Line {
+	type Output = Motor;
+	fn right_contraction(self, rhs: Motor) -> Self::Output {
+		// Motor {
+		//     s : -S(self.m.0 * rhs.xy.0),
+		//     yw: YW(self.dx.0 * rhs.s.0),
+		//     wx: WX(self.dy.0 * rhs.s.0),
+		//     xy: XY(self.m.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.m.right_contraction(rhs.xy),
+			yw: self.dx.right_contraction(rhs.s),
+			wx: self.dy.right_contraction(rhs.s),
+			xy: self.m.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Line.scalar_product(Motor) -> S
+impl ScalarProduct<Motor> for Line {
+	type Output = S;
+	fn scalar_product(self, rhs: Motor) -> Self::Output {
+		// -S(self.m.0 * rhs.xy.0)
+		self.m.scalar_product(rhs.xy)
+	}
+}
+
+// Line.commutator(Motor) -> Line
+impl Commutator<Motor> for Line {
+	type Output = Line;
+	fn commutator(self, rhs: Motor) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.dy.0 * rhs.xy.0) + YW(self.m.0 * rhs.wx.0),
+		//     dy: WX(self.dx.0 * rhs.xy.0) - WX(self.m.0 * rhs.yw.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.dy.commutator(rhs.xy) + self.m.commutator(rhs.wx),
+			dy: self.dx.commutator(rhs.xy) + self.m.commutator(rhs.yw),
+			m: Default::default(),
+		}
+	}
+}
+
+// Line.anti_dot(Motor) -> Multivector
+impl AntiDot<Motor> for Line {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.dx.0 * rhs.s.0),
+		//     y  : Y(self.dy.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.dx.0 * rhs.yw.0) + XYW(self.dy.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.dx.anti_dot(rhs.s),
+			y: self.dy.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.dx.anti_dot(rhs.yw) + self.dy.anti_dot(rhs.wx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line OP Multivector:
+
+// Line.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Line {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.m.0 * rhs.xy.0),
+		//     x  : X(self.m.0 * rhs.y.0),
+		//     y  : -Y(self.m.0 * rhs.x.0),
+		//     w  : -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0) - W(self.m.0 * rhs.xyw.0),
+		//     yw : YW(self.dx.0 * rhs.s.0) - YW(self.dy.0 * rhs.xy.0) + YW(self.m.0 * rhs.wx.0),
+		//     wx : WX(self.dx.0 * rhs.xy.0) + WX(self.dy.0 * rhs.s.0) - WX(self.m.0 * rhs.yw.0),
+		//     xy : XY(self.m.0 * rhs.s.0),
+		//     xyw: XYW(self.dx.0 * rhs.x.0) + XYW(self.dy.0 * rhs.y.0) + XYW(self.m.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.m.geometric(rhs.xy),
+			x: self.m.geometric(rhs.y),
+			y: self.m.geometric(rhs.x),
+			w: self.dx.geometric(rhs.y) + self.dy.geometric(rhs.x) + self.m.geometric(rhs.xyw),
+			yw: self.dx.geometric(rhs.s) + self.dy.geometric(rhs.xy) + self.m.geometric(rhs.wx),
+			wx: self.dx.geometric(rhs.xy) + self.dy.geometric(rhs.s) + self.m.geometric(rhs.yw),
+			xy: self.m.geometric(rhs.s),
+			xyw: self.dx.geometric(rhs.x) + self.dy.geometric(rhs.y) + self.m.geometric(rhs.w),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Line {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Line {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.dx.0 * rhs.x.0) + S(self.dy.0 * rhs.y.0) + S(self.m.0 * rhs.w.0),
+		//     x  : X(self.dx.0 * rhs.s.0) + X(self.dy.0 * rhs.xy.0) - X(self.m.0 * rhs.wx.0),
+		//     y  : -Y(self.dx.0 * rhs.xy.0) + Y(self.dy.0 * rhs.s.0) + Y(self.m.0 * rhs.yw.0),
+		//     w  : W(self.dx.0 * rhs.wx.0) - W(self.dy.0 * rhs.yw.0),
+		//     yw : YW(self.dx.0 * rhs.xyw.0) - YW(self.dy.0 * rhs.w.0),
+		//     wx : WX(self.dx.0 * rhs.w.0) + WX(self.dy.0 * rhs.xyw.0),
+		//     xy : -XY(self.dx.0 * rhs.y.0) + XY(self.dy.0 * rhs.x.0) + XY(self.m.0 * rhs.xyw.0),
+		//     xyw: XYW(self.dx.0 * rhs.yw.0) + XYW(self.dy.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: self.dx.anti_geometric(rhs.x) + self.dy.anti_geometric(rhs.y) + self.m.anti_geometric(rhs.w),
+			x: self.dx.anti_geometric(rhs.s) + self.dy.anti_geometric(rhs.xy) + self.m.anti_geometric(rhs.wx),
+			y: self.dx.anti_geometric(rhs.xy) + self.dy.anti_geometric(rhs.s) + self.m.anti_geometric(rhs.yw),
+			w: self.dx.anti_geometric(rhs.wx) + self.dy.anti_geometric(rhs.yw),
+			yw: self.dx.anti_geometric(rhs.xyw) + self.dy.anti_geometric(rhs.w),
+			wx: self.dx.anti_geometric(rhs.w) + self.dy.anti_geometric(rhs.xyw),
+			xy: self.dx.anti_geometric(rhs.y) + self.dy.anti_geometric(rhs.x) + self.m.anti_geometric(rhs.xyw),
+			xyw: self.dx.anti_geometric(rhs.yw) + self.dy.anti_geometric(rhs.wx),
+		}
+	}
+}
+
+// Line.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Line {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.m.0 * rhs.xy.0),
+		//     x  : X(self.m.0 * rhs.y.0),
+		//     y  : -Y(self.m.0 * rhs.x.0),
+		//     w  : -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0) - W(self.m.0 * rhs.xyw.0),
+		//     yw : YW(self.dx.0 * rhs.s.0),
+		//     wx : WX(self.dy.0 * rhs.s.0),
+		//     xy : XY(self.m.0 * rhs.s.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.m.dot(rhs.xy),
+			x: self.m.dot(rhs.y),
+			y: self.m.dot(rhs.x),
+			w: self.dx.dot(rhs.y) + self.dy.dot(rhs.x) + self.m.dot(rhs.xyw),
+			yw: self.dx.dot(rhs.s),
+			wx: self.dy.dot(rhs.s),
+			xy: self.m.dot(rhs.s),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Line {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Line {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : Default::default(),
+		//     y  : Default::default(),
+		//     w  : Default::default(),
+		//     yw : YW(self.dx.0 * rhs.s.0),
+		//     wx : WX(self.dy.0 * rhs.s.0),
+		//     xy : XY(self.m.0 * rhs.s.0),
+		//     xyw: XYW(self.dx.0 * rhs.x.0) + XYW(self.dy.0 * rhs.y.0) + XYW(self.m.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: Default::default(),
+			yw: self.dx.wedge(rhs.s),
+			wx: self.dy.wedge(rhs.s),
+			xy: self.m.wedge(rhs.s),
+			xyw: self.dx.wedge(rhs.x) + self.dy.wedge(rhs.y) + self.m.wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Line {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Line {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.dx.0 * rhs.x.0) + S(self.dy.0 * rhs.y.0) + S(self.m.0 * rhs.w.0),
+		//     x  : X(self.dy.0 * rhs.xy.0) - X(self.m.0 * rhs.wx.0),
+		//     y  : -Y(self.dx.0 * rhs.xy.0) + Y(self.m.0 * rhs.yw.0),
+		//     w  : W(self.dx.0 * rhs.wx.0) - W(self.dy.0 * rhs.yw.0),
+		//     yw : YW(self.dx.0 * rhs.xyw.0),
+		//     wx : WX(self.dy.0 * rhs.xyw.0),
+		//     xy : XY(self.m.0 * rhs.xyw.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.dx.anti_wedge(rhs.x) + self.dy.anti_wedge(rhs.y) + self.m.anti_wedge(rhs.w),
+			x: self.dy.anti_wedge(rhs.xy) + self.m.anti_wedge(rhs.wx),
+			y: self.dx.anti_wedge(rhs.xy) + self.m.anti_wedge(rhs.yw),
+			w: self.dx.anti_wedge(rhs.wx) + self.dy.anti_wedge(rhs.yw),
+			yw: self.dx.anti_wedge(rhs.xyw),
+			wx: self.dy.anti_wedge(rhs.xyw),
+			xy: self.m.anti_wedge(rhs.xyw),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Line {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Line {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.m.0 * rhs.xy.0),
+		//     x  : Default::default(),
+		//     y  : Default::default(),
+		//     w  : -W(self.m.0 * rhs.xyw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.m.left_contraction(rhs.xy),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.m.left_contraction(rhs.xyw),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Line.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Line {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.m.0 * rhs.xy.0),
+		//     x  : X(self.m.0 * rhs.y.0),
+		//     y  : -Y(self.m.0 * rhs.x.0),
+		//     w  : -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		//     yw : YW(self.dx.0 * rhs.s.0),
+		//     wx : WX(self.dy.0 * rhs.s.0),
+		//     xy : XY(self.m.0 * rhs.s.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.m.right_contraction(rhs.xy),
+			x: self.m.right_contraction(rhs.y),
+			y: self.m.right_contraction(rhs.x),
+			w: self.dx.right_contraction(rhs.y) + self.dy.right_contraction(rhs.x),
+			yw: self.dx.right_contraction(rhs.s),
+			wx: self.dy.right_contraction(rhs.s),
+			xy: self.m.right_contraction(rhs.s),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Line.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Line {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.m.0 * rhs.xy.0)
+		self.m.scalar_product(rhs.xy)
+	}
+}
+
+// Line.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Line {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.m.0 * rhs.y.0),
+		//     y  : -Y(self.m.0 * rhs.x.0),
+		//     w  : -W(self.dx.0 * rhs.y.0) + W(self.dy.0 * rhs.x.0),
+		//     yw : -YW(self.dy.0 * rhs.xy.0) + YW(self.m.0 * rhs.wx.0),
+		//     wx : WX(self.dx.0 * rhs.xy.0) - WX(self.m.0 * rhs.yw.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.m.commutator(rhs.y),
+			y: self.m.commutator(rhs.x),
+			w: self.dx.commutator(rhs.y) + self.dy.commutator(rhs.x),
+			yw: self.dy.commutator(rhs.xy) + self.m.commutator(rhs.wx),
+			wx: self.dx.commutator(rhs.xy) + self.m.commutator(rhs.yw),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Line.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Line {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.dx.0 * rhs.s.0),
+		//     y  : Y(self.dy.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : YW(self.dx.0 * rhs.xyw.0) - YW(self.dy.0 * rhs.w.0),
+		//     wx : WX(self.dx.0 * rhs.w.0) + WX(self.dy.0 * rhs.xyw.0),
+		//     xy : -XY(self.dx.0 * rhs.y.0) + XY(self.dy.0 * rhs.x.0) + XY(self.m.0 * rhs.xyw.0),
+		//     xyw: XYW(self.dx.0 * rhs.yw.0) + XYW(self.dy.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.dx.anti_dot(rhs.s),
+			y: self.dy.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: self.dx.anti_dot(rhs.xyw) + self.dy.anti_dot(rhs.w),
+			wx: self.dx.anti_dot(rhs.w) + self.dy.anti_dot(rhs.xyw),
+			xy: self.dx.anti_dot(rhs.y) + self.dy.anti_dot(rhs.x) + self.m.anti_dot(rhs.xyw),
+			xyw: self.dx.anti_dot(rhs.yw) + self.dy.anti_dot(rhs.wx),
 		}
 	}
 }
@@ -7,7 +7,6 @@ pub mod traits;
 /// Special zero type for completeness, and better error messages.
 /// If you get this in an error message, it is because you multiplied
 /// two dimensions that always results in zero.
-
 pub struct Zero {}
 
 // ----------------------------------------------------------------------------
@@ -15,8 +14,17 @@ pub struct Zero {}
 // Types:
 pub mod line;
 pub mod motor;
+pub mod multivector;
+pub mod point2;
 pub mod rotor;
+pub mod translator;
 pub mod vec2;
 pub mod vec3;
 
-pub use self::{blades::*, line::*, motor::*, rotor::*, traits::*, vec2::*, vec3::*};
+pub mod queries;
+
+pub mod ffi;
+
+pub use self::{
+	blades::*, line::*, motor::*, multivector::*, point2::*, rotor::*, traits::*, translator::*, vec2::*, vec3::*,
+};
@@ -3,29 +3,109 @@
 //! ## Operations
 //! ```text
 //! Motor.geometric(Motor) -> Motor
+//! Motor.anti_geometric(Motor) -> Multivector
 //! Motor.dot(Motor) -> Motor
 //! Motor.wedge(Motor) -> Motor
 //! Motor.anti_wedge(Motor) -> Vec3
+//! Motor.left_contraction(Motor) -> Motor
+//! Motor.right_contraction(Motor) -> Motor
+//! Motor.scalar_product(Motor) -> S
+//! Motor.commutator(Motor) -> Line
+//! Motor.anti_dot(Motor) -> Multivector
+//! Motor.geometric(Vec2) -> Multivector
+//! Vec2.geometric(Motor) -> Multivector
 //! Motor.anti_geometric(Vec2) -> Rotor
 //! Vec2.anti_geometric(Motor) -> Rotor
 //! Motor.dot(Vec2) -> Vec3
 //! Vec2.dot(Motor) -> Vec3
+//! Motor.wedge(Vec2) -> Multivector
+//! Vec2.wedge(Motor) -> Multivector
 //! Motor.anti_wedge(Vec2) -> S
 //! Vec2.anti_wedge(Motor) -> S
+//! Motor.left_contraction(Vec2) -> Vec2
+//! Vec2.left_contraction(Motor) -> Vec3
+//! Motor.right_contraction(Vec2) -> Vec3
+//! Vec2.right_contraction(Motor) -> Vec2
+//! Motor.commutator(Vec2) -> Vec3
+//! Vec2.commutator(Motor) -> Vec3
+//! Motor.anti_dot(Vec2) -> XY
+//! Vec2.anti_dot(Motor) -> XY
+//! Motor.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Motor) -> Multivector
 //! Motor.anti_geometric(Vec3) -> Motor
 //! Vec3.anti_geometric(Motor) -> Motor
 //! Motor.dot(Vec3) -> Vec3
 //! Vec3.dot(Motor) -> Vec3
+//! Motor.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Motor) -> Multivector
 //! Motor.anti_wedge(Vec3) -> S
 //! Vec3.anti_wedge(Motor) -> S
+//! Motor.left_contraction(Vec3) -> Vec3
+//! Vec3.left_contraction(Motor) -> Vec3
+//! Motor.right_contraction(Vec3) -> Vec3
+//! Vec3.right_contraction(Motor) -> Vec3
+//! Motor.commutator(Vec3) -> Vec3
+//! Vec3.commutator(Motor) -> Vec3
+//! Motor.anti_dot(Vec3) -> Line
+//! Vec3.anti_dot(Motor) -> Line
+//! Motor.geometric(Point2) -> Multivector
+//! Point2.geometric(Motor) -> Multivector
+//! Motor.anti_geometric(Point2) -> Motor
+//! Point2.anti_geometric(Motor) -> Motor
+//! Motor.dot(Point2) -> Vec3
+//! Point2.dot(Motor) -> Vec3
+//! Motor.wedge(Point2) -> Multivector
+//! Point2.wedge(Motor) -> Multivector
+//! Motor.anti_wedge(Point2) -> S
+//! Point2.anti_wedge(Motor) -> S
+//! Motor.left_contraction(Point2) -> Vec3
+//! Point2.left_contraction(Motor) -> Vec3
+//! Motor.right_contraction(Point2) -> Vec3
+//! Point2.right_contraction(Motor) -> Vec3
+//! Motor.commutator(Point2) -> Vec3
+//! Point2.commutator(Motor) -> Vec3
+//! Motor.anti_dot(Point2) -> Line
+//! Point2.anti_dot(Motor) -> Line
 //! Motor.geometric(Line) -> Motor
 //! Line.geometric(Motor) -> Motor
+//! Motor.anti_geometric(Line) -> Multivector
+//! Line.anti_geometric(Motor) -> Multivector
 //! Motor.dot(Line) -> Motor
 //! Line.dot(Motor) -> Motor
 //! Motor.wedge(Line) -> Line
 //! Line.wedge(Motor) -> Line
 //! Motor.anti_wedge(Line) -> Vec3
 //! Line.anti_wedge(Motor) -> Vec3
+//! Motor.left_contraction(Line) -> Motor
+//! Line.left_contraction(Motor) -> S
+//! Motor.right_contraction(Line) -> S
+//! Line.right_contraction(Motor) -> Motor
+//! Motor.scalar_product(Line) -> S
+//! Line.scalar_product(Motor) -> S
+//! Motor.commutator(Line) -> Line
+//! Line.commutator(Motor) -> Line
+//! Motor.anti_dot(Line) -> Multivector
+//! Line.anti_dot(Motor) -> Multivector
+//! Motor.geometric(Translator) -> Motor
+//! Translator.geometric(Motor) -> Motor
+//! Motor.anti_geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Motor) -> Multivector
+//! Motor.dot(Translator) -> Motor
+//! Translator.dot(Motor) -> Motor
+//! Motor.wedge(Translator) -> Motor
+//! Translator.wedge(Motor) -> Motor
+//! Motor.anti_wedge(Translator) -> Vec3
+//! Translator.anti_wedge(Motor) -> Vec3
+//! Motor.left_contraction(Translator) -> Translator
+//! Translator.left_contraction(Motor) -> Motor
+//! Motor.right_contraction(Translator) -> Motor
+//! Translator.right_contraction(Motor) -> Translator
+//! Motor.scalar_product(Translator) -> S
+//! Translator.scalar_product(Motor) -> S
+//! Motor.commutator(Translator) -> Line
+//! Translator.commutator(Motor) -> Line
+//! Motor.anti_dot(Translator) -> Multivector
+//! Translator.anti_dot(Motor) -> Multivector
 //! Motor.geometric(Rotor) -> Motor
 //! Rotor.geometric(Motor) -> Motor
 //! Motor.anti_geometric(Rotor) -> Vec2
@@ -36,11 +116,43 @@
 //! Rotor.wedge(Motor) -> Motor
 //! Motor.anti_wedge(Rotor) -> Vec2
 //! Rotor.anti_wedge(Motor) -> Vec2
+//! Motor.left_contraction(Rotor) -> Rotor
+//! Rotor.left_contraction(Motor) -> Motor
+//! Motor.right_contraction(Rotor) -> Motor
+//! Rotor.right_contraction(Motor) -> Rotor
+//! Motor.scalar_product(Rotor) -> S
+//! Rotor.scalar_product(Motor) -> S
+//! Motor.commutator(Rotor) -> Line
+//! Rotor.commutator(Motor) -> Line
+//! Motor.anti_dot(Rotor) -> Vec2
+//! Rotor.anti_dot(Motor) -> Vec2
+//! Motor.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Motor) -> Multivector
+//! Motor.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Motor) -> Multivector
+//! Motor.dot(Multivector) -> Multivector
+//! Multivector.dot(Motor) -> Multivector
+//! Motor.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Motor) -> Multivector
+//! Motor.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Motor) -> Multivector
+//! Motor.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Motor) -> Multivector
+//! Motor.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Motor) -> Multivector
+//! Motor.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Motor) -> S
+//! Motor.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Motor) -> Multivector
+//! Motor.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Motor) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Motor {
 	pub s: S,
 	pub yw: YW,
@@ -48,9 +160,84 @@ pub struct Motor {
 	pub xy: XY,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Motor {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Motor {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Motor {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.yw.abs_diff_eq(&other.yw, epsilon)
+			&& self.wx.abs_diff_eq(&other.wx, epsilon)
+			&& self.xy.abs_diff_eq(&other.xy, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Motor {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.yw.relative_eq(&other.yw, epsilon, max_relative)
+			&& self.wx.relative_eq(&other.wx, epsilon, max_relative)
+			&& self.xy.relative_eq(&other.xy, epsilon, max_relative)
+	}
+}
+
 // ---------------------------------------------------------------------
-// Omitted: Motor.rcompl() -> self.s.rcompl() + self.wx.rcompl() + self.xy.rcompl() + self.yw.rcompl()
-// Omitted: Motor.lcompl() -> self.s.lcompl() + self.wx.lcompl() + self.xy.lcompl() + self.yw.lcompl()
+
+impl RCompl for Motor {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yw.rcompl(),
+			y: self.wx.rcompl(),
+			w: self.xy.rcompl(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Motor {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Motor {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yw.lcompl(),
+			y: self.wx.lcompl(),
+			w: self.xy.lcompl(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.lcompl(),
+		}
+	}
+}
 
 impl Reverse for Motor {
 	fn rev(self) -> Self {
@@ -74,10 +261,345 @@ impl AntiReverse for Motor {
 	}
 }
 
+impl Involute for Motor {
+	fn involute(self) -> Self {
+		Motor {
+			s: self.s,
+			yw: self.yw,
+			wx: self.wx,
+			xy: self.xy,
+		}
+	}
+}
+
+impl Conjugate for Motor {
+	fn conjugate(self) -> Self {
+		Motor {
+			s: self.s,
+			yw: -self.yw,
+			wx: -self.wx,
+			xy: -self.xy,
+		}
+	}
+}
+
+impl Dual for Motor {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yw.dual(),
+			y: self.wx.dual(),
+			w: self.xy.dual(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.dual(),
+		}
+	}
+}
+
+impl Bulk for Motor {
+	type Output = Rotor;
+	fn bulk(self) -> Self::Output {
+		Rotor { s: self.s, xy: self.xy }
+	}
+}
+
+impl Weight for Motor {
+	type Output = Line;
+	fn weight(self) -> Self::Output {
+		Line {
+			dx: self.yw,
+			dy: self.wx,
+			m: Default::default(),
+		}
+	}
+}
+
+impl BulkDual for Motor {
+	type Output = Multivector;
+	fn bulk_dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.xy.bulk_dual(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Motor {
+	type Output = Vec2;
+	fn weight_dual(self) -> Self::Output {
+		Vec2 {
+			x: self.yw.weight_dual(),
+			y: self.wx.weight_dual(),
+		}
+	}
+}
+
+// Omitted: Motor.square()  (not a scalar)
+
+impl Norm for Motor {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0 + self.yw.0 * self.yw.0 + self.wx.0 * self.wx.0 + self.xy.0 * self.xy.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0 + self.xy.0 * self.xy.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.yw.0 * self.yw.0 + self.wx.0 * self.wx.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Motor {
+			s: self.s * scale,
+			yw: self.yw * scale,
+			wx: self.wx * scale,
+			xy: self.xy * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Motor {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.s.0, self.yw.0, self.wx.0, self.xy.0]
+	}
+}
+
+impl From<[f64; 4]> for Motor {
+	fn from(a: [f64; 4]) -> Self {
+		Motor {
+			s: S(a[0]),
+			yw: YW(a[1]),
+			wx: WX(a[2]),
+			xy: XY(a[3]),
+		}
+	}
+}
+
+impl From<Motor> for [f64; 4] {
+	fn from(v: Motor) -> Self {
+		v.to_array()
+	}
+}
+
+impl Motor {
+	/// A pure translation.
+	/// A point `p` is moved by `motor.sandwich(p.rcompl()).rcompl()`.
+	pub fn from_translation(t: [f64; 2]) -> Self {
+		let half = 1.0 / 2.0;
+		Motor {
+			s: S(1.0),
+			yw: YW(half * t[1]),
+			wx: WX(-half * t[0]),
+			xy: XY(0.0),
+		}
+	}
+}
+
+impl From<Motor> for Multivector {
+	fn from(v: Motor) -> Self {
+		Multivector {
+			s: v.s,
+			yw: v.yw,
+			wx: v.wx,
+			xy: v.xy,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Motor {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Motor` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.x.0 == 0.0 && mv.y.0 == 0.0 && mv.w.0 == 0.0 && mv.xyw.0 == 0.0 {
+			Ok(Motor {
+				s: mv.s,
+				yw: mv.yw,
+				wx: mv.wx,
+				xy: mv.xy,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Ln for Motor {
+	type Output = Line;
+
+	/// The inverse of [`Exp`] for a normalized motor.
+	fn ln(self) -> Self::Output {
+		// `self` and `-self` are the same motion, so pick the shortest path:
+		let motor = if self.s.0 < 0.0 { -self } else { self };
+		let angle = motor.xy.0.atan2(motor.s.0);
+		let scale = if motor.xy.0 == 0.0 {
+			1.0 / motor.s.0
+		} else {
+			angle / motor.xy.0
+		};
+		Line {
+			dx: motor.yw * scale,
+			dy: motor.wx * scale,
+			m: motor.xy * scale,
+		}
+	}
+}
+
+impl Motor {
+	/// Interpolate between two normalized motors along the screw motion between them,
+	/// so that `t=0` gives `self` and `t=1` gives `other`.
+	pub fn lerp_log(self, other: Motor, t: f64) -> Motor {
+		let delta = other.geometric(self.rev()).ln();
+		let delta = Line {
+			dx: delta.dx * t,
+			dy: delta.dy * t,
+			m: delta.m * t,
+		};
+		delta.exp().geometric(self)
+	}
+}
+
+impl Motor {
+	/// The matrix that transforms `[x, y, w]` like `self.sandwich(v.rcompl()).rcompl()` for a `Vec3` `v`,
+	/// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+	pub fn to_matrix(self) -> [[f64; 3]; 3] {
+		[
+			[
+				self.s.0 * self.s.0 - self.xy.0 * self.xy.0,
+				2.0 * self.s.0 * self.xy.0,
+				-2.0 * self.s.0 * self.wx.0 + 2.0 * self.xy.0 * self.yw.0,
+			],
+			[
+				-2.0 * self.s.0 * self.xy.0,
+				self.s.0 * self.s.0 - self.xy.0 * self.xy.0,
+				2.0 * self.s.0 * self.yw.0 + 2.0 * self.wx.0 * self.xy.0,
+			],
+			[0.0, 0.0, self.s.0 * self.s.0 + self.xy.0 * self.xy.0],
+		]
+	}
+
+	/// The first 2 rows of [`Self::to_matrix`], i.e. without the last row (which is `[0, …, 0, 1]` when normalized).
+	pub fn to_matrix_2x3(self) -> [[f64; 3]; 2] {
+		let m = self.to_matrix();
+		[m[0], m[1]]
+	}
+}
+
+impl Motor {
+	/// The versor that moves nothing, whose matrix is the identity matrix.
+	pub fn identity() -> Self {
+		Motor {
+			s: S(1.0),
+			yw: YW(0.0),
+			wx: WX(0.0),
+			xy: XY(0.0),
+		}
+	}
+}
+
+impl Motor {
+	/// Rotate by `rotor` and then translate by `translation`.
+	pub fn from_rotor_and_translation(rotor: Rotor, translation: [f64; 2]) -> Self {
+		let translator = Translator {
+			s: S(1.0),
+			yw: YW(translation[1] / 2.0),
+			wx: WX(-translation[0] / 2.0),
+		};
+		translator.geometric(rotor)
+	}
+
+	/// The rotation of a normalized motor, i.e. `self` without the translation.
+	pub fn rotor(self) -> Rotor {
+		Rotor {
+			s: S(self.s.0),
+			xy: XY(self.xy.0),
+		}
+	}
+
+	/// Where a normalized motor moves the origin.
+	pub fn translation(self) -> [f64; 2] {
+		[
+			-2.0 * self.s.0 * self.wx.0 + 2.0 * self.xy.0 * self.yw.0,
+			2.0 * self.s.0 * self.yw.0 + 2.0 * self.wx.0 * self.xy.0,
+		]
+	}
+}
+
+impl Motor {
+	/// The motor of a rigid transform, e.g. from [`Self::to_matrix`]:
+	/// the rotation of [`Rotor::from_matrix`], then the translation of [`Translator::from_matrix`].
+	pub fn from_matrix(m: [[f64; 3]; 3]) -> Self {
+		let rotor = Rotor::from_matrix(m);
+		// Undo the rotation, whose inverse is its transpose:
+		let r = rotor.to_matrix();
+		let t = m.map(|row| r.map(|r_row| row.iter().zip(r_row).fold(0.0, |sum, (a, b)| sum + *a * b)));
+		Translator::from_matrix(t).geometric(rotor)
+	}
+}
+
 // ---------------------------------------------------------------------
 // Motor OP Vec2:
 
-// Omitted: Motor geometric Vec2 = self.s * rhs.x + self.s * rhs.y + self.wx * rhs.x + self.wx * rhs.y + self.xy * rhs.x + self.xy * rhs.y + self.yw * rhs.x + self.yw * rhs.y  (unnamed type)
+// Motor.geometric(Vec2) -> Multivector
+impl Geometric<Vec2> for Motor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
+			w: self.wx.geometric(rhs.x) + self.yw.geometric(rhs.y),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.geometric(rhs.y) + self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec2> for Motor {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
 // Motor.anti_geometric(Vec2) -> Rotor
 impl AntiGeometric<Vec2> for Motor {
@@ -85,11 +607,11 @@ impl AntiGeometric<Vec2> for Motor {
 	fn anti_geometric(self, rhs: Vec2) -> Self::Output {
 		// Rotor {
 		//     s : S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0),
-		//     xy: XY(self.wx.0 * rhs.x.0) + XY(self.yw.0 * rhs.y.0),
+		//     xy: XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
 		// }
 		Rotor {
 			s: self.wx.anti_geometric(rhs.y) + self.yw.anti_geometric(rhs.x),
-			xy: self.wx.anti_geometric(rhs.x) - self.yw.anti_geometric(rhs.y),
+			xy: self.wx.anti_geometric(rhs.x) + self.yw.anti_geometric(rhs.y),
 		}
 	}
 }
@@ -100,18 +622,57 @@ impl Dot<Vec2> for Motor {
 	fn dot(self, rhs: Vec2) -> Self::Output {
 		// Vec3 {
 		//     x: X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
-		//     y: Y(self.s.0 * rhs.y.0) + Y(self.xy.0 * rhs.x.0),
-		//     w: W(self.wx.0 * rhs.x.0) + W(self.yw.0 * rhs.y.0),
+		//     y: Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w: W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
 		// }
 		Vec3 {
 			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
-			y: self.s.dot(rhs.y) - self.xy.dot(rhs.x),
-			w: self.wx.dot(rhs.x) - self.yw.dot(rhs.y),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
+			w: self.wx.dot(rhs.x) + self.yw.dot(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec2> for Motor {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor.wedge(Vec2) -> Multivector
+impl Wedge<Vec2> for Motor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.wedge(rhs.y) + self.yw.wedge(rhs.x),
 		}
 	}
 }
 
-// Omitted: Motor wedge Vec2 = self.s ^ rhs.x + self.s ^ rhs.y + self.wx ^ rhs.y + self.yw ^ rhs.x  (unnamed type)
+impl std::ops::BitXor<Vec2> for Motor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
 // Motor.anti_wedge(Vec2) -> S
 impl AntiWedge<Vec2> for Motor {
@@ -122,10 +683,114 @@ impl AntiWedge<Vec2> for Motor {
 	}
 }
 
+impl std::ops::BitAnd<Vec2> for Motor {
+	type Output = S;
+	fn bitand(self, rhs: Vec2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor.left_contraction(Vec2) -> Vec2
+impl LeftContraction<Vec2> for Motor {
+	type Output = Vec2;
+	fn left_contraction(self, rhs: Vec2) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		// }
+		Vec2 {
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+		}
+	}
+}
+
+// Motor.right_contraction(Vec2) -> Vec3
+impl RightContraction<Vec2> for Motor {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Vec2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		//     w: W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+			w: self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y),
+		}
+	}
+}
+
+impl ScalarProduct<Vec2> for Motor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Motor.commutator(Vec2) -> Vec3
+impl Commutator<Vec2> for Motor {
+	type Output = Vec3;
+	fn commutator(self, rhs: Vec2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		//     w: W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y),
+		}
+	}
+}
+
+// Motor.anti_dot(Vec2) -> XY
+impl AntiDot<Vec2> for Motor {
+	type Output = XY;
+	fn anti_dot(self, rhs: Vec2) -> Self::Output {
+		// XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0)
+		self.wx.anti_dot(rhs.x) + self.yw.anti_dot(rhs.y)
+	}
+}
+
 // ---------------------------------------------------------------------
 // Motor OP Vec3:
 
-// Omitted: Motor geometric Vec3 = self.s * rhs.w + self.s * rhs.x + self.s * rhs.y + self.wx * rhs.x + self.wx * rhs.y + self.xy * rhs.w + self.xy * rhs.x + self.xy * rhs.y + self.yw * rhs.x + self.yw * rhs.y  (unnamed type)
+// Motor.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Motor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.xy.0 * rhs.w.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
+			w: self.s.geometric(rhs.w) + self.wx.geometric(rhs.x) + self.yw.geometric(rhs.y),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.geometric(rhs.y) + self.xy.geometric(rhs.w) + self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Motor {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
 // Motor.anti_geometric(Vec3) -> Motor
 impl AntiGeometric<Vec3> for Motor {
@@ -133,15 +798,15 @@ impl AntiGeometric<Vec3> for Motor {
 	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
 		// Motor {
 		//     s : S(self.wx.0 * rhs.y.0) + S(self.xy.0 * rhs.w.0) + S(self.yw.0 * rhs.x.0),
-		//     yw: YW(self.wx.0 * rhs.w.0),
+		//     yw: -YW(self.wx.0 * rhs.w.0),
 		//     wx: WX(self.yw.0 * rhs.w.0),
-		//     xy: XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) + XY(self.yw.0 * rhs.y.0),
+		//     xy: -XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
 		// }
 		Motor {
 			s: self.wx.anti_geometric(rhs.y) + self.xy.anti_geometric(rhs.w) + self.yw.anti_geometric(rhs.x),
-			yw: -self.wx.anti_geometric(rhs.w),
+			yw: self.wx.anti_geometric(rhs.w),
 			wx: self.yw.anti_geometric(rhs.w),
-			xy: -self.s.anti_geometric(rhs.w) + self.wx.anti_geometric(rhs.x) - self.yw.anti_geometric(rhs.y),
+			xy: self.s.anti_geometric(rhs.w) + self.wx.anti_geometric(rhs.x) + self.yw.anti_geometric(rhs.y),
 		}
 	}
 }
@@ -152,18 +817,57 @@ impl Dot<Vec3> for Motor {
 	fn dot(self, rhs: Vec3) -> Self::Output {
 		// Vec3 {
 		//     x: X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
-		//     y: Y(self.s.0 * rhs.y.0) + Y(self.xy.0 * rhs.x.0),
-		//     w: W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) + W(self.yw.0 * rhs.y.0),
+		//     y: Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w: W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
 		// }
 		Vec3 {
 			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
-			y: self.s.dot(rhs.y) - self.xy.dot(rhs.x),
-			w: self.s.dot(rhs.w) + self.wx.dot(rhs.x) - self.yw.dot(rhs.y),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
+			w: self.s.dot(rhs.w) + self.wx.dot(rhs.x) + self.yw.dot(rhs.y),
 		}
 	}
 }
 
-// Omitted: Motor wedge Vec3 = self.s ^ rhs.w + self.s ^ rhs.x + self.s ^ rhs.y + self.wx ^ rhs.y + self.xy ^ rhs.w + self.yw ^ rhs.x  (unnamed type)
+impl std::ops::BitOr<Vec3> for Motor {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Motor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.xy.0 * rhs.w.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(rhs.w),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.wedge(rhs.y) + self.xy.wedge(rhs.w) + self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Motor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
 // Motor.anti_wedge(Vec3) -> S
 impl AntiWedge<Vec3> for Motor {
@@ -174,6 +878,293 @@ impl AntiWedge<Vec3> for Motor {
 	}
 }
 
+impl std::ops::BitAnd<Vec3> for Motor {
+	type Output = S;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor.left_contraction(Vec3) -> Vec3
+impl LeftContraction<Vec3> for Motor {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		//     w: W(self.s.0 * rhs.w.0),
+		// }
+		Vec3 {
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(rhs.w),
+		}
+	}
+}
+
+// Motor.right_contraction(Vec3) -> Vec3
+impl RightContraction<Vec3> for Motor {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		//     w: W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+			w: self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Motor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Motor.commutator(Vec3) -> Vec3
+impl Commutator<Vec3> for Motor {
+	type Output = Vec3;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		//     w: W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y),
+		}
+	}
+}
+
+// Motor.anti_dot(Vec3) -> Line
+impl AntiDot<Vec3> for Motor {
+	type Output = Line;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.wx.0 * rhs.w.0),
+		//     dy: WX(self.yw.0 * rhs.w.0),
+		//     m : -XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		// }
+		Line {
+			dx: self.wx.anti_dot(rhs.w),
+			dy: self.yw.anti_dot(rhs.w),
+			m: self.s.anti_dot(rhs.w) + self.wx.anti_dot(rhs.x) + self.yw.anti_dot(rhs.y),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Motor OP Point2:
+
+// Motor.geometric(Point2) -> Multivector
+impl Geometric<Point2> for Motor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.xy.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
+			w: self.s.geometric(W(1.0)) + self.wx.geometric(rhs.x) + self.yw.geometric(rhs.y),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.geometric(rhs.y) + self.xy.geometric(W(1.0)) + self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Point2> for Motor {
+	type Output = Multivector;
+	fn mul(self, rhs: Point2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor.anti_geometric(Point2) -> Motor
+impl AntiGeometric<Point2> for Motor {
+	type Output = Motor;
+	fn anti_geometric(self, rhs: Point2) -> Self::Output {
+		// Motor {
+		//     s : S(self.wx.0 * rhs.y.0) + S(self.xy.0) + S(self.yw.0 * rhs.x.0),
+		//     yw: -YW(self.wx.0),
+		//     wx: WX(self.yw.0),
+		//     xy: -XY(self.s.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		// }
+		Motor {
+			s: self.wx.anti_geometric(rhs.y) + self.xy.anti_geometric(W(1.0)) + self.yw.anti_geometric(rhs.x),
+			yw: self.wx.anti_geometric(W(1.0)),
+			wx: self.yw.anti_geometric(W(1.0)),
+			xy: self.s.anti_geometric(W(1.0)) + self.wx.anti_geometric(rhs.x) + self.yw.anti_geometric(rhs.y),
+		}
+	}
+}
+
+// Motor.dot(Point2) -> Vec3
+impl Dot<Point2> for Motor {
+	type Output = Vec3;
+	fn dot(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y: Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w: W(self.s.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
+			w: self.s.dot(W(1.0)) + self.wx.dot(rhs.x) + self.yw.dot(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point2> for Motor {
+	type Output = Vec3;
+	fn bitor(self, rhs: Point2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor.wedge(Point2) -> Multivector
+impl Wedge<Point2> for Motor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.xy.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.wedge(rhs.y) + self.xy.wedge(W(1.0)) + self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point2> for Motor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Point2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor.anti_wedge(Point2) -> S
+impl AntiWedge<Point2> for Motor {
+	type Output = S;
+	fn anti_wedge(self, rhs: Point2) -> Self::Output {
+		// S(self.wx.0 * rhs.y.0) + S(self.xy.0) + S(self.yw.0 * rhs.x.0)
+		self.wx.anti_wedge(rhs.y) + self.xy.anti_wedge(W(1.0)) + self.yw.anti_wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitAnd<Point2> for Motor {
+	type Output = S;
+	fn bitand(self, rhs: Point2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor.left_contraction(Point2) -> Vec3
+impl LeftContraction<Point2> for Motor {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		//     w: W(self.s.0),
+		// }
+		Vec3 {
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(W(1.0)),
+		}
+	}
+}
+
+// Motor.right_contraction(Point2) -> Vec3
+impl RightContraction<Point2> for Motor {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		//     w: W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+			w: self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y),
+		}
+	}
+}
+
+impl ScalarProduct<Point2> for Motor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Motor.commutator(Point2) -> Vec3
+impl Commutator<Point2> for Motor {
+	type Output = Vec3;
+	fn commutator(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		//     w: W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y),
+		}
+	}
+}
+
+// Motor.anti_dot(Point2) -> Line
+impl AntiDot<Point2> for Motor {
+	type Output = Line;
+	fn anti_dot(self, rhs: Point2) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.wx.0),
+		//     dy: WX(self.yw.0),
+		//     m : -XY(self.s.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		// }
+		Line {
+			dx: self.wx.anti_dot(W(1.0)),
+			dy: self.yw.anti_dot(W(1.0)),
+			m: self.s.anti_dot(W(1.0)) + self.wx.anti_dot(rhs.x) + self.yw.anti_dot(rhs.y),
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Motor OP Line:
 
@@ -182,71 +1173,426 @@ impl Geometric<Line> for Motor {
 	type Output = Motor;
 	fn geometric(self, rhs: Line) -> Self::Output {
 		// Motor {
-		//     s : S(self.xy.0 * rhs.m.0),
-		//     yw: YW(self.s.0 * rhs.dx.0) + YW(self.wx.0 * rhs.m.0) + YW(self.xy.0 * rhs.dy.0),
-		//     wx: WX(self.s.0 * rhs.dy.0) + WX(self.xy.0 * rhs.dx.0) + WX(self.yw.0 * rhs.m.0),
+		//     s : -S(self.xy.0 * rhs.m.0),
+		//     yw: YW(self.s.0 * rhs.dx.0) - YW(self.wx.0 * rhs.m.0) + YW(self.xy.0 * rhs.dy.0),
+		//     wx: WX(self.s.0 * rhs.dy.0) - WX(self.xy.0 * rhs.dx.0) + WX(self.yw.0 * rhs.m.0),
+		//     xy: XY(self.s.0 * rhs.m.0),
+		// }
+		Motor {
+			s: self.xy.geometric(rhs.m),
+			yw: self.s.geometric(rhs.dx) + self.wx.geometric(rhs.m) + self.xy.geometric(rhs.dy),
+			wx: self.s.geometric(rhs.dy) + self.xy.geometric(rhs.dx) + self.yw.geometric(rhs.m),
+			xy: self.s.geometric(rhs.m),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Motor {
+	type Output = Motor;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor.anti_geometric(Line) -> Multivector
+impl AntiGeometric<Line> for Motor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.dx.0) + X(self.wx.0 * rhs.m.0) - X(self.xy.0 * rhs.dy.0),
+		//     y  : Y(self.s.0 * rhs.dy.0) + Y(self.xy.0 * rhs.dx.0) - Y(self.yw.0 * rhs.m.0),
+		//     w  : -W(self.wx.0 * rhs.dx.0) + W(self.yw.0 * rhs.dy.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.dy.0) + XYW(self.yw.0 * rhs.dx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_geometric(rhs.dx) + self.wx.anti_geometric(rhs.m) + self.xy.anti_geometric(rhs.dy),
+			y: self.s.anti_geometric(rhs.dy) + self.xy.anti_geometric(rhs.dx) + self.yw.anti_geometric(rhs.m),
+			w: self.wx.anti_geometric(rhs.dx) + self.yw.anti_geometric(rhs.dy),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_geometric(rhs.dy) + self.yw.anti_geometric(rhs.dx),
+		}
+	}
+}
+
+// Motor.dot(Line) -> Motor
+impl Dot<Line> for Motor {
+	type Output = Motor;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Motor {
+		//     s : -S(self.xy.0 * rhs.m.0),
+		//     yw: YW(self.s.0 * rhs.dx.0),
+		//     wx: WX(self.s.0 * rhs.dy.0),
+		//     xy: XY(self.s.0 * rhs.m.0),
+		// }
+		Motor {
+			s: self.xy.dot(rhs.m),
+			yw: self.s.dot(rhs.dx),
+			wx: self.s.dot(rhs.dy),
+			xy: self.s.dot(rhs.m),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Motor {
+	type Output = Motor;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor.wedge(Line) -> Line
+impl Wedge<Line> for Motor {
+	type Output = Line;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: YW(self.s.0 * rhs.dx.0),
+		//     dy: WX(self.s.0 * rhs.dy.0),
+		//     m : XY(self.s.0 * rhs.m.0),
+		// }
+		Line {
+			dx: self.s.wedge(rhs.dx),
+			dy: self.s.wedge(rhs.dy),
+			m: self.s.wedge(rhs.m),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line> for Motor {
+	type Output = Line;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor.anti_wedge(Line) -> Vec3
+impl AntiWedge<Line> for Motor {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.wx.0 * rhs.m.0) - X(self.xy.0 * rhs.dy.0),
+		//     y: Y(self.xy.0 * rhs.dx.0) - Y(self.yw.0 * rhs.m.0),
+		//     w: -W(self.wx.0 * rhs.dx.0) + W(self.yw.0 * rhs.dy.0),
+		// }
+		Vec3 {
+			x: self.wx.anti_wedge(rhs.m) + self.xy.anti_wedge(rhs.dy),
+			y: self.xy.anti_wedge(rhs.dx) + self.yw.anti_wedge(rhs.m),
+			w: self.wx.anti_wedge(rhs.dx) + self.yw.anti_wedge(rhs.dy),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Motor {
+	type Output = Vec3;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor.left_contraction(Line) -> Motor
+impl LeftContraction<Line> for Motor {
+	type Output = Motor;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Motor {
+		//     s : -S(self.xy.0 * rhs.m.0),
+		//     yw: YW(self.s.0 * rhs.dx.0),
+		//     wx: WX(self.s.0 * rhs.dy.0),
 		//     xy: XY(self.s.0 * rhs.m.0),
 		// }
-		Motor {
-			s: -self.xy.geometric(rhs.m),
-			yw: self.s.geometric(rhs.dx) - self.wx.geometric(rhs.m) + self.xy.geometric(rhs.dy),
-			wx: self.s.geometric(rhs.dy) - self.xy.geometric(rhs.dx) + self.yw.geometric(rhs.m),
-			xy: self.s.geometric(rhs.m),
+		Motor {
+			s: self.xy.left_contraction(rhs.m),
+			yw: self.s.left_contraction(rhs.dx),
+			wx: self.s.left_contraction(rhs.dy),
+			xy: self.s.left_contraction(rhs.m),
+		}
+	}
+}
+
+// Motor.right_contraction(Line) -> S
+impl RightContraction<Line> for Motor {
+	type Output = S;
+	fn right_contraction(self, rhs: Line) -> Self::Output {
+		// -S(self.xy.0 * rhs.m.0)
+		self.xy.right_contraction(rhs.m)
+	}
+}
+
+// Motor.scalar_product(Line) -> S
+impl ScalarProduct<Line> for Motor {
+	type Output = S;
+	fn scalar_product(self, rhs: Line) -> Self::Output {
+		// -S(self.xy.0 * rhs.m.0)
+		self.xy.scalar_product(rhs.m)
+	}
+}
+
+// Motor.commutator(Line) -> Line
+impl Commutator<Line> for Motor {
+	type Output = Line;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.wx.0 * rhs.m.0) + YW(self.xy.0 * rhs.dy.0),
+		//     dy: -WX(self.xy.0 * rhs.dx.0) + WX(self.yw.0 * rhs.m.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.wx.commutator(rhs.m) + self.xy.commutator(rhs.dy),
+			dy: self.xy.commutator(rhs.dx) + self.yw.commutator(rhs.m),
+			m: Default::default(),
+		}
+	}
+}
+
+// Motor.anti_dot(Line) -> Multivector
+impl AntiDot<Line> for Motor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.dx.0),
+		//     y  : Y(self.s.0 * rhs.dy.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.dy.0) + XYW(self.yw.0 * rhs.dx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.dx),
+			y: self.s.anti_dot(rhs.dy),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_dot(rhs.dy) + self.yw.anti_dot(rhs.dx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Motor OP Translator:
+
+// Motor.geometric(Translator) -> Motor
+impl Geometric<Translator> for Motor {
+	type Output = Motor;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.xy.0 * rhs.wx.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0) - WX(self.xy.0 * rhs.yw.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.s.geometric(rhs.s),
+			yw: self.s.geometric(rhs.yw) + self.xy.geometric(rhs.wx) + self.yw.geometric(rhs.s),
+			wx: self.s.geometric(rhs.wx) + self.wx.geometric(rhs.s) + self.xy.geometric(rhs.yw),
+			xy: self.xy.geometric(rhs.s),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Motor {
+	type Output = Motor;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor.anti_geometric(Translator) -> Multivector
+impl AntiGeometric<Translator> for Motor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yw.0) - X(self.xy.0 * rhs.wx.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0) + Y(self.xy.0 * rhs.yw.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_geometric(rhs.yw) + self.xy.anti_geometric(rhs.wx) + self.yw.anti_geometric(rhs.s),
+			y: self.s.anti_geometric(rhs.wx) + self.wx.anti_geometric(rhs.s) + self.xy.anti_geometric(rhs.yw),
+			w: self.wx.anti_geometric(rhs.yw) + self.yw.anti_geometric(rhs.wx),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_geometric(rhs.wx) + self.yw.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Motor.dot(Translator) -> Motor
+impl Dot<Translator> for Motor {
+	type Output = Motor;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.s.dot(rhs.s),
+			yw: self.s.dot(rhs.yw) + self.yw.dot(rhs.s),
+			wx: self.s.dot(rhs.wx) + self.wx.dot(rhs.s),
+			xy: self.xy.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Motor {
+	type Output = Motor;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor.wedge(Translator) -> Motor
+impl Wedge<Translator> for Motor {
+	type Output = Motor;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.s.wedge(rhs.s),
+			yw: self.s.wedge(rhs.yw) + self.yw.wedge(rhs.s),
+			wx: self.s.wedge(rhs.wx) + self.wx.wedge(rhs.s),
+			xy: self.xy.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Motor {
+	type Output = Motor;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor.anti_wedge(Translator) -> Vec3
+impl AntiWedge<Translator> for Motor {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.xy.0 * rhs.wx.0),
+		//     y: Y(self.xy.0 * rhs.yw.0),
+		//     w: -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		// }
+		Vec3 {
+			x: self.xy.anti_wedge(rhs.wx),
+			y: self.xy.anti_wedge(rhs.yw),
+			w: self.wx.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.wx),
 		}
 	}
 }
 
-// Omitted: Motor anti_geometric Line = self.s !* rhs.dx + self.s !* rhs.dy + self.wx !* rhs.dx + self.wx !* rhs.dy + self.wx !* rhs.m + self.xy !* rhs.dx + self.xy !* rhs.dy + self.yw !* rhs.dx + self.yw !* rhs.dy + self.yw !* rhs.m  (unnamed type)
+impl std::ops::BitAnd<Translator> for Motor {
+	type Output = Vec3;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-// Motor.dot(Line) -> Motor
-impl Dot<Line> for Motor {
+// Motor.left_contraction(Translator) -> Translator
+impl LeftContraction<Translator> for Motor {
+	type Output = Translator;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0),
+		//     wx: WX(self.s.0 * rhs.wx.0),
+		// }
+		Translator {
+			s: self.s.left_contraction(rhs.s),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
+		}
+	}
+}
+
+// Motor.right_contraction(Translator) -> Motor
+impl RightContraction<Translator> for Motor {
 	type Output = Motor;
-	fn dot(self, rhs: Line) -> Self::Output {
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
 		// Motor {
-		//     s : S(self.xy.0 * rhs.m.0),
-		//     yw: YW(self.s.0 * rhs.dx.0),
-		//     wx: WX(self.s.0 * rhs.dy.0),
-		//     xy: XY(self.s.0 * rhs.m.0),
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.wx.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
 		// }
 		Motor {
-			s: -self.xy.dot(rhs.m),
-			yw: self.s.dot(rhs.dx),
-			wx: self.s.dot(rhs.dy),
-			xy: self.s.dot(rhs.m),
+			s: self.s.right_contraction(rhs.s),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
 		}
 	}
 }
 
-// Motor.wedge(Line) -> Line
-impl Wedge<Line> for Motor {
+// Motor.scalar_product(Translator) -> S
+impl ScalarProduct<Translator> for Motor {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator) -> Self::Output {
+		// S(self.s.0 * rhs.s.0)
+		self.s.scalar_product(rhs.s)
+	}
+}
+
+// Motor.commutator(Translator) -> Line
+impl Commutator<Translator> for Motor {
 	type Output = Line;
-	fn wedge(self, rhs: Line) -> Self::Output {
+	fn commutator(self, rhs: Translator) -> Self::Output {
 		// Line {
-		//     dx: YW(self.s.0 * rhs.dx.0),
-		//     dy: WX(self.s.0 * rhs.dy.0),
-		//     m : XY(self.s.0 * rhs.m.0),
+		//     dx: YW(self.xy.0 * rhs.wx.0),
+		//     dy: -WX(self.xy.0 * rhs.yw.0),
+		//     m : Default::default(),
 		// }
 		Line {
-			dx: self.s.wedge(rhs.dx),
-			dy: self.s.wedge(rhs.dy),
-			m: self.s.wedge(rhs.m),
+			dx: self.xy.commutator(rhs.wx),
+			dy: self.xy.commutator(rhs.yw),
+			m: Default::default(),
 		}
 	}
 }
 
-// Motor.anti_wedge(Line) -> Vec3
-impl AntiWedge<Line> for Motor {
-	type Output = Vec3;
-	fn anti_wedge(self, rhs: Line) -> Self::Output {
-		// Vec3 {
-		//     x: X(self.wx.0 * rhs.m.0) + X(self.xy.0 * rhs.dy.0),
-		//     y: Y(self.xy.0 * rhs.dx.0) + Y(self.yw.0 * rhs.m.0),
-		//     w: W(self.wx.0 * rhs.dx.0) + W(self.yw.0 * rhs.dy.0),
+// Motor.anti_dot(Translator) -> Multivector
+impl AntiDot<Translator> for Motor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
 		// }
-		Vec3 {
-			x: self.wx.anti_wedge(rhs.m) - self.xy.anti_wedge(rhs.dy),
-			y: self.xy.anti_wedge(rhs.dx) - self.yw.anti_wedge(rhs.m),
-			w: -self.wx.anti_wedge(rhs.dx) + self.yw.anti_wedge(rhs.dy),
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.yw) + self.yw.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.wx) + self.wx.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_dot(rhs.wx) + self.yw.anti_dot(rhs.yw),
 		}
 	}
 }
@@ -259,31 +1605,38 @@ impl Geometric<Rotor> for Motor {
 	type Output = Motor;
 	fn geometric(self, rhs: Rotor) -> Self::Output {
 		// Motor {
-		//     s : S(self.s.0 * rhs.s.0) + S(self.xy.0 * rhs.xy.0),
-		//     yw: YW(self.wx.0 * rhs.xy.0) + YW(self.yw.0 * rhs.s.0),
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     yw: -YW(self.wx.0 * rhs.xy.0) + YW(self.yw.0 * rhs.s.0),
 		//     wx: WX(self.wx.0 * rhs.s.0) + WX(self.yw.0 * rhs.xy.0),
 		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
 		// }
 		Motor {
-			s: self.s.geometric(rhs.s) - self.xy.geometric(rhs.xy),
-			yw: -self.wx.geometric(rhs.xy) + self.yw.geometric(rhs.s),
+			s: self.s.geometric(rhs.s) + self.xy.geometric(rhs.xy),
+			yw: self.wx.geometric(rhs.xy) + self.yw.geometric(rhs.s),
 			wx: self.wx.geometric(rhs.s) + self.yw.geometric(rhs.xy),
 			xy: self.s.geometric(rhs.xy) + self.xy.geometric(rhs.s),
 		}
 	}
 }
 
+impl std::ops::Mul<Rotor> for Motor {
+	type Output = Motor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
 // Motor.anti_geometric(Rotor) -> Vec2
 impl AntiGeometric<Rotor> for Motor {
 	type Output = Vec2;
 	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
 		// Vec2 {
 		//     x: X(self.wx.0 * rhs.xy.0) + X(self.yw.0 * rhs.s.0),
-		//     y: Y(self.wx.0 * rhs.s.0) + Y(self.yw.0 * rhs.xy.0),
+		//     y: Y(self.wx.0 * rhs.s.0) - Y(self.yw.0 * rhs.xy.0),
 		// }
 		Vec2 {
 			x: self.wx.anti_geometric(rhs.xy) + self.yw.anti_geometric(rhs.s),
-			y: self.wx.anti_geometric(rhs.s) - self.yw.anti_geometric(rhs.xy),
+			y: self.wx.anti_geometric(rhs.s) + self.yw.anti_geometric(rhs.xy),
 		}
 	}
 }
@@ -293,13 +1646,13 @@ impl Dot<Rotor> for Motor {
 	type Output = Motor;
 	fn dot(self, rhs: Rotor) -> Self::Output {
 		// Motor {
-		//     s : S(self.s.0 * rhs.s.0) + S(self.xy.0 * rhs.xy.0),
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
 		//     yw: YW(self.yw.0 * rhs.s.0),
 		//     wx: WX(self.wx.0 * rhs.s.0),
 		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
 		// }
 		Motor {
-			s: self.s.dot(rhs.s) - self.xy.dot(rhs.xy),
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy),
 			yw: self.yw.dot(rhs.s),
 			wx: self.wx.dot(rhs.s),
 			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
@@ -307,6 +1660,13 @@ impl Dot<Rotor> for Motor {
 	}
 }
 
+impl std::ops::BitOr<Rotor> for Motor {
+	type Output = Motor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Motor.wedge(Rotor) -> Motor
 impl Wedge<Rotor> for Motor {
 	type Output = Motor;
@@ -326,17 +1686,106 @@ impl Wedge<Rotor> for Motor {
 	}
 }
 
+impl std::ops::BitXor<Rotor> for Motor {
+	type Output = Motor;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Motor.anti_wedge(Rotor) -> Vec2
 impl AntiWedge<Rotor> for Motor {
 	type Output = Vec2;
 	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
 		// Vec2 {
 		//     x: X(self.wx.0 * rhs.xy.0),
-		//     y: Y(self.yw.0 * rhs.xy.0),
+		//     y: -Y(self.yw.0 * rhs.xy.0),
 		// }
 		Vec2 {
 			x: self.wx.anti_wedge(rhs.xy),
-			y: -self.yw.anti_wedge(rhs.xy),
+			y: self.yw.anti_wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Motor {
+	type Output = Vec2;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor.left_contraction(Rotor) -> Rotor
+impl LeftContraction<Rotor> for Motor {
+	type Output = Rotor;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Rotor {
+			s: self.s.left_contraction(rhs.s) + self.xy.left_contraction(rhs.xy),
+			xy: self.s.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Motor.right_contraction(Rotor) -> Motor
+impl RightContraction<Rotor> for Motor {
+	type Output = Motor;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     yw: YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.wx.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.s.right_contraction(rhs.s) + self.xy.right_contraction(rhs.xy),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Motor.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Motor {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0)
+		self.s.scalar_product(rhs.s) + self.xy.scalar_product(rhs.xy)
+	}
+}
+
+// Motor.commutator(Rotor) -> Line
+impl Commutator<Rotor> for Motor {
+	type Output = Line;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.wx.0 * rhs.xy.0),
+		//     dy: WX(self.yw.0 * rhs.xy.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.wx.commutator(rhs.xy),
+			dy: self.yw.commutator(rhs.xy),
+			m: Default::default(),
+		}
+	}
+}
+
+// Motor.anti_dot(Rotor) -> Vec2
+impl AntiDot<Rotor> for Motor {
+	type Output = Vec2;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.yw.0 * rhs.s.0),
+		//     y: Y(self.wx.0 * rhs.s.0),
+		// }
+		Vec2 {
+			x: self.yw.anti_dot(rhs.s),
+			y: self.wx.anti_dot(rhs.s),
 		}
 	}
 }
@@ -349,37 +1798,78 @@ impl Geometric<Motor> for Motor {
 	type Output = Motor;
 	fn geometric(self, rhs: Motor) -> Self::Output {
 		// Motor {
-		//     s : S(self.s.0 * rhs.s.0) + S(self.xy.0 * rhs.xy.0),
-		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.wx.0 * rhs.xy.0) + YW(self.xy.0 * rhs.wx.0) + YW(self.yw.0 * rhs.s.0),
-		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0) + WX(self.xy.0 * rhs.yw.0) + WX(self.yw.0 * rhs.xy.0),
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) - YW(self.wx.0 * rhs.xy.0) + YW(self.xy.0 * rhs.wx.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0) - WX(self.xy.0 * rhs.yw.0) + WX(self.yw.0 * rhs.xy.0),
 		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
 		// }
 		Motor {
-			s: self.s.geometric(rhs.s) - self.xy.geometric(rhs.xy),
-			yw: self.s.geometric(rhs.yw) - self.wx.geometric(rhs.xy)
+			s: self.s.geometric(rhs.s) + self.xy.geometric(rhs.xy),
+			yw: self.s.geometric(rhs.yw)
+				+ self.wx.geometric(rhs.xy)
 				+ self.xy.geometric(rhs.wx)
 				+ self.yw.geometric(rhs.s),
-			wx: self.s.geometric(rhs.wx) + self.wx.geometric(rhs.s) - self.xy.geometric(rhs.yw)
+			wx: self.s.geometric(rhs.wx)
+				+ self.wx.geometric(rhs.s)
+				+ self.xy.geometric(rhs.yw)
 				+ self.yw.geometric(rhs.xy),
 			xy: self.s.geometric(rhs.xy) + self.xy.geometric(rhs.s),
 		}
 	}
 }
 
-// Omitted: Motor anti_geometric Motor = self.s !* rhs.wx + self.s !* rhs.yw + self.wx !* rhs.s + self.wx !* rhs.wx + self.wx !* rhs.xy + self.wx !* rhs.yw + self.xy !* rhs.wx + self.xy !* rhs.yw + self.yw !* rhs.s + self.yw !* rhs.wx + self.yw !* rhs.xy + self.yw !* rhs.yw  (unnamed type)
+impl std::ops::Mul<Motor> for Motor {
+	type Output = Motor;
+	fn mul(self, rhs: Motor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor.anti_geometric(Motor) -> Multivector
+impl AntiGeometric<Motor> for Motor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.wx.0 * rhs.xy.0) - X(self.xy.0 * rhs.wx.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0) + Y(self.xy.0 * rhs.yw.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_geometric(rhs.yw)
+				+ self.wx.anti_geometric(rhs.xy)
+				+ self.xy.anti_geometric(rhs.wx)
+				+ self.yw.anti_geometric(rhs.s),
+			y: self.s.anti_geometric(rhs.wx)
+				+ self.wx.anti_geometric(rhs.s)
+				+ self.xy.anti_geometric(rhs.yw)
+				+ self.yw.anti_geometric(rhs.xy),
+			w: self.wx.anti_geometric(rhs.yw) + self.yw.anti_geometric(rhs.wx),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_geometric(rhs.wx) + self.yw.anti_geometric(rhs.yw),
+		}
+	}
+}
 
 // Motor.dot(Motor) -> Motor
 impl Dot<Motor> for Motor {
 	type Output = Motor;
 	fn dot(self, rhs: Motor) -> Self::Output {
 		// Motor {
-		//     s : S(self.s.0 * rhs.s.0) + S(self.xy.0 * rhs.xy.0),
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
 		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
 		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
 		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
 		// }
 		Motor {
-			s: self.s.dot(rhs.s) - self.xy.dot(rhs.xy),
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy),
 			yw: self.s.dot(rhs.yw) + self.yw.dot(rhs.s),
 			wx: self.s.dot(rhs.wx) + self.wx.dot(rhs.s),
 			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
@@ -387,6 +1877,13 @@ impl Dot<Motor> for Motor {
 	}
 }
 
+impl std::ops::BitOr<Motor> for Motor {
+	type Output = Motor;
+	fn bitor(self, rhs: Motor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Motor.wedge(Motor) -> Motor
 impl Wedge<Motor> for Motor {
 	type Output = Motor;
@@ -406,19 +1903,535 @@ impl Wedge<Motor> for Motor {
 	}
 }
 
+impl std::ops::BitXor<Motor> for Motor {
+	type Output = Motor;
+	fn bitxor(self, rhs: Motor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Motor.anti_wedge(Motor) -> Vec3
 impl AntiWedge<Motor> for Motor {
 	type Output = Vec3;
 	fn anti_wedge(self, rhs: Motor) -> Self::Output {
 		// Vec3 {
-		//     x: X(self.wx.0 * rhs.xy.0) + X(self.xy.0 * rhs.wx.0),
-		//     y: Y(self.xy.0 * rhs.yw.0) + Y(self.yw.0 * rhs.xy.0),
-		//     w: W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     x: X(self.wx.0 * rhs.xy.0) - X(self.xy.0 * rhs.wx.0),
+		//     y: Y(self.xy.0 * rhs.yw.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w: -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		// }
+		Vec3 {
+			x: self.wx.anti_wedge(rhs.xy) + self.xy.anti_wedge(rhs.wx),
+			y: self.xy.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.xy),
+			w: self.wx.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor> for Motor {
+	type Output = Vec3;
+	fn bitand(self, rhs: Motor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor.left_contraction(Motor) -> Motor
+impl LeftContraction<Motor> for Motor {
+	type Output = Motor;
+	fn left_contraction(self, rhs: Motor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     yw: YW(self.s.0 * rhs.yw.0),
+		//     wx: WX(self.s.0 * rhs.wx.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Motor {
+			s: self.s.left_contraction(rhs.s) + self.xy.left_contraction(rhs.xy),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
+			xy: self.s.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Motor.right_contraction(Motor) -> Motor
+impl RightContraction<Motor> for Motor {
+	type Output = Motor;
+	fn right_contraction(self, rhs: Motor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     yw: YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.wx.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.s.right_contraction(rhs.s) + self.xy.right_contraction(rhs.xy),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Motor.scalar_product(Motor) -> S
+impl ScalarProduct<Motor> for Motor {
+	type Output = S;
+	fn scalar_product(self, rhs: Motor) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0)
+		self.s.scalar_product(rhs.s) + self.xy.scalar_product(rhs.xy)
+	}
+}
+
+// Motor.commutator(Motor) -> Line
+impl Commutator<Motor> for Motor {
+	type Output = Line;
+	fn commutator(self, rhs: Motor) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.wx.0 * rhs.xy.0) + YW(self.xy.0 * rhs.wx.0),
+		//     dy: -WX(self.xy.0 * rhs.yw.0) + WX(self.yw.0 * rhs.xy.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.wx.commutator(rhs.xy) + self.xy.commutator(rhs.wx),
+			dy: self.xy.commutator(rhs.yw) + self.yw.commutator(rhs.xy),
+			m: Default::default(),
+		}
+	}
+}
+
+// Motor.anti_dot(Motor) -> Multivector
+impl AntiDot<Motor> for Motor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.yw) + self.yw.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.wx) + self.wx.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_dot(rhs.wx) + self.yw.anti_dot(rhs.yw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Motor OP Multivector:
+
+// Motor.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Motor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) - W(self.xy.0 * rhs.xyw.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) - YW(self.wx.0 * rhs.xy.0) + YW(self.xy.0 * rhs.wx.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0) - WX(self.xy.0 * rhs.yw.0) + WX(self.yw.0 * rhs.xy.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0) + XYW(self.wx.0 * rhs.y.0) + XYW(self.xy.0 * rhs.w.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s) + self.xy.geometric(rhs.xy),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
+			w: self.s.geometric(rhs.w)
+				+ self.wx.geometric(rhs.x)
+				+ self.xy.geometric(rhs.xyw)
+				+ self.yw.geometric(rhs.y),
+			yw: self.s.geometric(rhs.yw)
+				+ self.wx.geometric(rhs.xy)
+				+ self.xy.geometric(rhs.wx)
+				+ self.yw.geometric(rhs.s),
+			wx: self.s.geometric(rhs.wx)
+				+ self.wx.geometric(rhs.s)
+				+ self.xy.geometric(rhs.yw)
+				+ self.yw.geometric(rhs.xy),
+			xy: self.s.geometric(rhs.xy) + self.xy.geometric(rhs.s),
+			xyw: self.s.geometric(rhs.xyw)
+				+ self.wx.geometric(rhs.y)
+				+ self.xy.geometric(rhs.w)
+				+ self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Motor {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Motor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0) + S(self.wx.0 * rhs.y.0) + S(self.xy.0 * rhs.w.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.wx.0 * rhs.xy.0) - X(self.xy.0 * rhs.wx.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0) + Y(self.xy.0 * rhs.yw.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : -YW(self.wx.0 * rhs.w.0) + YW(self.yw.0 * rhs.xyw.0),
+		//     wx : WX(self.wx.0 * rhs.xyw.0) + WX(self.yw.0 * rhs.w.0),
+		//     xy : -XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) + XY(self.xy.0 * rhs.xyw.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: self.s.anti_geometric(rhs.xyw)
+				+ self.wx.anti_geometric(rhs.y)
+				+ self.xy.anti_geometric(rhs.w)
+				+ self.yw.anti_geometric(rhs.x),
+			x: self.s.anti_geometric(rhs.yw)
+				+ self.wx.anti_geometric(rhs.xy)
+				+ self.xy.anti_geometric(rhs.wx)
+				+ self.yw.anti_geometric(rhs.s),
+			y: self.s.anti_geometric(rhs.wx)
+				+ self.wx.anti_geometric(rhs.s)
+				+ self.xy.anti_geometric(rhs.yw)
+				+ self.yw.anti_geometric(rhs.xy),
+			w: self.wx.anti_geometric(rhs.yw) + self.yw.anti_geometric(rhs.wx),
+			yw: self.wx.anti_geometric(rhs.w) + self.yw.anti_geometric(rhs.xyw),
+			wx: self.wx.anti_geometric(rhs.xyw) + self.yw.anti_geometric(rhs.w),
+			xy: self.s.anti_geometric(rhs.w)
+				+ self.wx.anti_geometric(rhs.x)
+				+ self.xy.anti_geometric(rhs.xyw)
+				+ self.yw.anti_geometric(rhs.y),
+			xyw: self.wx.anti_geometric(rhs.wx) + self.yw.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Motor.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Motor {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) - W(self.xy.0 * rhs.xyw.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy),
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
+			w: self.s.dot(rhs.w) + self.wx.dot(rhs.x) + self.xy.dot(rhs.xyw) + self.yw.dot(rhs.y),
+			yw: self.s.dot(rhs.yw) + self.yw.dot(rhs.s),
+			wx: self.s.dot(rhs.wx) + self.wx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
+			xyw: self.s.dot(rhs.xyw),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Motor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Motor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0) + XYW(self.wx.0 * rhs.y.0) + XYW(self.xy.0 * rhs.w.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(rhs.w),
+			yw: self.s.wedge(rhs.yw) + self.yw.wedge(rhs.s),
+			wx: self.s.wedge(rhs.wx) + self.wx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy) + self.xy.wedge(rhs.s),
+			xyw: self.s.wedge(rhs.xyw) + self.wx.wedge(rhs.y) + self.xy.wedge(rhs.w) + self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Motor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Motor {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0) + S(self.wx.0 * rhs.y.0) + S(self.xy.0 * rhs.w.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.wx.0 * rhs.xy.0) - X(self.xy.0 * rhs.wx.0),
+		//     y  : Y(self.xy.0 * rhs.yw.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : YW(self.yw.0 * rhs.xyw.0),
+		//     wx : WX(self.wx.0 * rhs.xyw.0),
+		//     xy : XY(self.xy.0 * rhs.xyw.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.anti_wedge(rhs.xyw)
+				+ self.wx.anti_wedge(rhs.y)
+				+ self.xy.anti_wedge(rhs.w)
+				+ self.yw.anti_wedge(rhs.x),
+			x: self.wx.anti_wedge(rhs.xy) + self.xy.anti_wedge(rhs.wx),
+			y: self.xy.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.xy),
+			w: self.wx.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.wx),
+			yw: self.yw.anti_wedge(rhs.xyw),
+			wx: self.wx.anti_wedge(rhs.xyw),
+			xy: self.xy.anti_wedge(rhs.xyw),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Motor {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Motor {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0) - W(self.xy.0 * rhs.xyw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0),
+		//     wx : WX(self.s.0 * rhs.wx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s) + self.xy.left_contraction(rhs.xy),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(rhs.w) + self.xy.left_contraction(rhs.xyw),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
+			xy: self.s.left_contraction(rhs.xy),
+			xyw: self.s.left_contraction(rhs.xyw),
+		}
+	}
+}
+
+// Motor.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Motor {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s) + self.xy.right_contraction(rhs.xy),
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+			w: self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Motor.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Motor {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0)
+		self.s.scalar_product(rhs.s) + self.xy.scalar_product(rhs.xy)
+	}
+}
+
+// Motor.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Motor {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : -YW(self.wx.0 * rhs.xy.0) + YW(self.xy.0 * rhs.wx.0),
+		//     wx : -WX(self.xy.0 * rhs.yw.0) + WX(self.yw.0 * rhs.xy.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y),
+			yw: self.wx.commutator(rhs.xy) + self.xy.commutator(rhs.wx),
+			wx: self.xy.commutator(rhs.yw) + self.yw.commutator(rhs.xy),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Motor.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Motor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : -YW(self.wx.0 * rhs.w.0) + YW(self.yw.0 * rhs.xyw.0),
+		//     wx : WX(self.wx.0 * rhs.xyw.0) + WX(self.yw.0 * rhs.w.0),
+		//     xy : -XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) + XY(self.xy.0 * rhs.xyw.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
 		// }
+		Multivector {
+			s: self.s.anti_dot(rhs.xyw),
+			x: self.s.anti_dot(rhs.yw) + self.yw.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.wx) + self.wx.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: self.wx.anti_dot(rhs.w) + self.yw.anti_dot(rhs.xyw),
+			wx: self.wx.anti_dot(rhs.xyw) + self.yw.anti_dot(rhs.w),
+			xy: self.s.anti_dot(rhs.w) + self.wx.anti_dot(rhs.x) + self.xy.anti_dot(rhs.xyw) + self.yw.anti_dot(rhs.y),
+			xyw: self.wx.anti_dot(rhs.wx) + self.yw.anti_dot(rhs.yw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Motor.sandwich(Vec2)  (not a Vec2)
+
+// Motor.anti_sandwich(Vec2) -> Vec2
+impl AntiSandwich<Vec2> for Motor {
+	fn anti_sandwich(self, rhs: Vec2) -> Vec2 {
+		Vec2 {
+			x: -self.wx.anti_geometric(self.wx).anti_geometric(rhs.x)
+				- self.wx.anti_geometric(self.yw).anti_geometric(rhs.y) * 2.0
+				+ self.yw.anti_geometric(self.yw).anti_geometric(rhs.x),
+			y: self.wx.anti_geometric(self.wx).anti_geometric(rhs.y)
+				+ self.wx.anti_geometric(self.yw).anti_geometric(rhs.x) * 2.0
+				- self.yw.anti_geometric(self.yw).anti_geometric(rhs.y),
+		}
+	}
+}
+
+// Motor.sandwich(Vec3) -> Vec3
+impl Sandwich<Vec3> for Motor {
+	fn sandwich(self, rhs: Vec3) -> Vec3 {
 		Vec3 {
-			x: self.wx.anti_wedge(rhs.xy) - self.xy.anti_wedge(rhs.wx),
-			y: self.xy.anti_wedge(rhs.yw) - self.yw.anti_wedge(rhs.xy),
-			w: -self.wx.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.wx),
+			x: self.s.geometric(self.s).geometric(rhs.x)
+				+ self.s.geometric(self.xy).geometric(rhs.y) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.x),
+			y: self.s.geometric(self.s).geometric(rhs.y)
+				+ self.s.geometric(self.xy).geometric(rhs.x) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.y),
+			w: self.s.geometric(self.s).geometric(rhs.w)
+				+ self.s.geometric(self.wx).geometric(rhs.x) * 2.0
+				+ self.s.geometric(self.yw).geometric(rhs.y) * 2.0
+				+ self.wx.geometric(self.xy).geometric(rhs.y) * 2.0
+				- self.xy.geometric(self.xy).geometric(rhs.w)
+				- self.xy.geometric(self.yw).geometric(rhs.x) * 2.0,
+		}
+	}
+}
+
+// Motor.anti_sandwich(Vec3) -> Vec3
+impl AntiSandwich<Vec3> for Motor {
+	fn anti_sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: self.s.anti_geometric(self.wx).anti_geometric(rhs.w) * -2.0
+				- self.wx.anti_geometric(self.wx).anti_geometric(rhs.x)
+				- self.wx.anti_geometric(self.yw).anti_geometric(rhs.y) * 2.0
+				- self.xy.anti_geometric(self.yw).anti_geometric(rhs.w) * 2.0
+				+ self.yw.anti_geometric(self.yw).anti_geometric(rhs.x),
+			y: self.s.anti_geometric(self.yw).anti_geometric(rhs.w) * -2.0
+				+ self.wx.anti_geometric(self.wx).anti_geometric(rhs.y)
+				+ self.wx.anti_geometric(self.xy).anti_geometric(rhs.w) * 2.0
+				+ self.wx.anti_geometric(self.yw).anti_geometric(rhs.x) * 2.0
+				- self.yw.anti_geometric(self.yw).anti_geometric(rhs.y),
+			w: -self.wx.anti_geometric(self.wx).anti_geometric(rhs.w)
+				- self.yw.anti_geometric(self.yw).anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Omitted: Motor.sandwich(Point2)  (not a Point2)
+// Omitted: Motor.anti_sandwich(Point2)  (not a Point2)
+
+// Motor.sandwich(Line) -> Line
+impl Sandwich<Line> for Motor {
+	fn sandwich(self, rhs: Line) -> Line {
+		Line {
+			dx: self.s.geometric(self.s).geometric(rhs.dx)
+				+ self.s.geometric(self.wx).geometric(rhs.m) * 2.0
+				+ self.s.geometric(self.xy).geometric(rhs.dy) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.dx)
+				+ self.xy.geometric(self.yw).geometric(rhs.m) * 2.0,
+			dy: self.s.geometric(self.s).geometric(rhs.dy)
+				+ self.s.geometric(self.xy).geometric(rhs.dx) * 2.0
+				+ self.s.geometric(self.yw).geometric(rhs.m) * 2.0
+				- self.wx.geometric(self.xy).geometric(rhs.m) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.dy),
+			m: self.s.geometric(self.s).geometric(rhs.m) - self.xy.geometric(self.xy).geometric(rhs.m),
+		}
+	}
+}
+
+// Motor.anti_sandwich(Line) -> Line
+impl AntiSandwich<Line> for Motor {
+	fn anti_sandwich(self, rhs: Line) -> Line {
+		Line {
+			dx: -self.wx.anti_geometric(self.wx).anti_geometric(rhs.dx)
+				- self.wx.anti_geometric(self.yw).anti_geometric(rhs.dy) * 2.0
+				+ self.yw.anti_geometric(self.yw).anti_geometric(rhs.dx),
+			dy: self.wx.anti_geometric(self.wx).anti_geometric(rhs.dy)
+				+ self.wx.anti_geometric(self.yw).anti_geometric(rhs.dx) * 2.0
+				- self.yw.anti_geometric(self.yw).anti_geometric(rhs.dy),
+			m: self.s.anti_geometric(self.wx).anti_geometric(rhs.dx) * -2.0
+				- self.s.anti_geometric(self.yw).anti_geometric(rhs.dy) * 2.0
+				- self.wx.anti_geometric(self.wx).anti_geometric(rhs.m)
+				- self.wx.anti_geometric(self.xy).anti_geometric(rhs.dy) * 2.0
+				+ self.xy.anti_geometric(self.yw).anti_geometric(rhs.dx) * 2.0
+				- self.yw.anti_geometric(self.yw).anti_geometric(rhs.m),
 		}
 	}
 }
@@ -0,0 +1,2920 @@
+//! # Multivector
+//!
+//! ## Operations
+//! ```text
+//! Multivector.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Multivector) -> Multivector
+//! Multivector.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Multivector) -> S
+//! Multivector.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Vec2) -> Multivector
+//! Vec2.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec2) -> Multivector
+//! Vec2.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Vec2) -> Multivector
+//! Vec2.dot(Multivector) -> Multivector
+//! Multivector.wedge(Vec2) -> Multivector
+//! Vec2.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec2) -> Multivector
+//! Vec2.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec2) -> Multivector
+//! Vec2.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec2) -> Multivector
+//! Vec2.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Vec2) -> S
+//! Vec2.scalar_product(Multivector) -> S
+//! Multivector.commutator(Vec2) -> Multivector
+//! Vec2.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec2) -> Multivector
+//! Vec2.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Vec3) -> Multivector
+//! Vec3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec3) -> Multivector
+//! Vec3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec3) -> Multivector
+//! Vec3.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec3) -> Multivector
+//! Vec3.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Vec3) -> S
+//! Vec3.scalar_product(Multivector) -> S
+//! Multivector.commutator(Vec3) -> Multivector
+//! Vec3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec3) -> Multivector
+//! Vec3.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Point2) -> Multivector
+//! Point2.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Point2) -> Multivector
+//! Point2.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Point2) -> Multivector
+//! Point2.dot(Multivector) -> Multivector
+//! Multivector.wedge(Point2) -> Multivector
+//! Point2.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Point2) -> Multivector
+//! Point2.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Point2) -> Multivector
+//! Point2.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Point2) -> Multivector
+//! Point2.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Point2) -> S
+//! Point2.scalar_product(Multivector) -> S
+//! Multivector.commutator(Point2) -> Multivector
+//! Point2.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Point2) -> Multivector
+//! Point2.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Line) -> Multivector
+//! Line.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Line) -> Multivector
+//! Line.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Line) -> Multivector
+//! Line.dot(Multivector) -> Multivector
+//! Multivector.wedge(Line) -> Multivector
+//! Line.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Line) -> Multivector
+//! Line.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Line) -> Multivector
+//! Line.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Line) -> Multivector
+//! Line.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Line) -> S
+//! Line.scalar_product(Multivector) -> S
+//! Multivector.commutator(Line) -> Multivector
+//! Line.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Line) -> Multivector
+//! Line.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Translator) -> Multivector
+//! Translator.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Translator) -> Multivector
+//! Translator.dot(Multivector) -> Multivector
+//! Multivector.wedge(Translator) -> Multivector
+//! Translator.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Translator) -> Multivector
+//! Translator.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Translator) -> Multivector
+//! Translator.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Translator) -> Multivector
+//! Translator.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Translator) -> S
+//! Translator.scalar_product(Multivector) -> S
+//! Multivector.commutator(Translator) -> Multivector
+//! Translator.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Translator) -> Multivector
+//! Translator.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Rotor) -> Multivector
+//! Rotor.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Rotor) -> Multivector
+//! Rotor.dot(Multivector) -> Multivector
+//! Multivector.wedge(Rotor) -> Multivector
+//! Rotor.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Rotor) -> Multivector
+//! Rotor.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Rotor) -> Multivector
+//! Rotor.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Rotor) -> Multivector
+//! Rotor.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Rotor) -> S
+//! Rotor.scalar_product(Multivector) -> S
+//! Multivector.commutator(Rotor) -> Multivector
+//! Rotor.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Rotor) -> Multivector
+//! Rotor.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Motor) -> Multivector
+//! Motor.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Motor) -> Multivector
+//! Motor.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Motor) -> Multivector
+//! Motor.dot(Multivector) -> Multivector
+//! Multivector.wedge(Motor) -> Multivector
+//! Motor.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Motor) -> Multivector
+//! Motor.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Motor) -> Multivector
+//! Motor.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Motor) -> Multivector
+//! Motor.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Motor) -> S
+//! Motor.scalar_product(Multivector) -> S
+//! Multivector.commutator(Motor) -> Multivector
+//! Motor.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Motor) -> Multivector
+//! Motor.anti_dot(Multivector) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Multivector {
+	pub s: S,
+	pub x: X,
+	pub y: Y,
+	pub w: W,
+	pub yw: YW,
+	pub wx: WX,
+	pub xy: XY,
+	pub xyw: XYW,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Multivector {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Multivector {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Multivector {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.w.abs_diff_eq(&other.w, epsilon)
+			&& self.yw.abs_diff_eq(&other.yw, epsilon)
+			&& self.wx.abs_diff_eq(&other.wx, epsilon)
+			&& self.xy.abs_diff_eq(&other.xy, epsilon)
+			&& self.xyw.abs_diff_eq(&other.xyw, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Multivector {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.w.relative_eq(&other.w, epsilon, max_relative)
+			&& self.yw.relative_eq(&other.yw, epsilon, max_relative)
+			&& self.wx.relative_eq(&other.wx, epsilon, max_relative)
+			&& self.xy.relative_eq(&other.xy, epsilon, max_relative)
+			&& self.xyw.relative_eq(&other.xyw, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Multivector {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: self.xyw.rcompl(),
+			x: self.yw.rcompl(),
+			y: self.wx.rcompl(),
+			w: self.xy.rcompl(),
+			yw: self.x.rcompl(),
+			wx: self.y.rcompl(),
+			xy: self.w.rcompl(),
+			xyw: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Multivector {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Multivector {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: self.xyw.lcompl(),
+			x: self.yw.lcompl(),
+			y: self.wx.lcompl(),
+			w: self.xy.lcompl(),
+			yw: self.x.lcompl(),
+			wx: self.y.lcompl(),
+			xy: self.w.lcompl(),
+			xyw: self.s.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Multivector {
+	fn rev(self) -> Self {
+		Multivector {
+			s: self.s,
+			x: self.x,
+			y: self.y,
+			w: self.w,
+			yw: -self.yw,
+			wx: -self.wx,
+			xy: -self.xy,
+			xyw: -self.xyw,
+		}
+	}
+}
+
+impl AntiReverse for Multivector {
+	fn arev(self) -> Self {
+		Multivector {
+			s: -self.s,
+			x: -self.x,
+			y: -self.y,
+			w: -self.w,
+			yw: self.yw,
+			wx: self.wx,
+			xy: self.xy,
+			xyw: self.xyw,
+		}
+	}
+}
+
+impl Involute for Multivector {
+	fn involute(self) -> Self {
+		Multivector {
+			s: self.s,
+			x: -self.x,
+			y: -self.y,
+			w: -self.w,
+			yw: self.yw,
+			wx: self.wx,
+			xy: self.xy,
+			xyw: -self.xyw,
+		}
+	}
+}
+
+impl Conjugate for Multivector {
+	fn conjugate(self) -> Self {
+		Multivector {
+			s: self.s,
+			x: -self.x,
+			y: -self.y,
+			w: -self.w,
+			yw: -self.yw,
+			wx: -self.wx,
+			xy: -self.xy,
+			xyw: self.xyw,
+		}
+	}
+}
+
+impl Dual for Multivector {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: self.xyw.dual(),
+			x: self.yw.dual(),
+			y: self.wx.dual(),
+			w: self.xy.dual(),
+			yw: self.x.dual(),
+			wx: self.y.dual(),
+			xy: self.w.dual(),
+			xyw: self.s.dual(),
+		}
+	}
+}
+
+impl Bulk for Multivector {
+	type Output = Multivector;
+	fn bulk(self) -> Self::Output {
+		Multivector {
+			s: self.s,
+			x: self.x,
+			y: self.y,
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.xy,
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl Weight for Multivector {
+	type Output = Multivector;
+	fn weight(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.w,
+			yw: self.yw,
+			wx: self.wx,
+			xy: Default::default(),
+			xyw: self.xyw,
+		}
+	}
+}
+
+impl BulkDual for Multivector {
+	type Output = Multivector;
+	fn bulk_dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.xy.bulk_dual(),
+			yw: self.x.bulk_dual(),
+			wx: self.y.bulk_dual(),
+			xy: Default::default(),
+			xyw: self.s.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Multivector {
+	type Output = Multivector;
+	fn weight_dual(self) -> Self::Output {
+		Multivector {
+			s: self.xyw.weight_dual(),
+			x: self.yw.weight_dual(),
+			y: self.wx.weight_dual(),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.w.weight_dual(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Omitted: Multivector.square()  (not a scalar)
+
+impl Norm for Multivector {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0
+			+ self.x.0 * self.x.0
+			+ self.y.0 * self.y.0
+			+ self.w.0 * self.w.0
+			+ self.yw.0 * self.yw.0
+			+ self.wx.0 * self.wx.0
+			+ self.xy.0 * self.xy.0
+			+ self.xyw.0 * self.xyw.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0 + self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.xy.0 * self.xy.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.w.0 * self.w.0 + self.yw.0 * self.yw.0 + self.wx.0 * self.wx.0 + self.xyw.0 * self.xyw.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.weight_norm();
+		Multivector {
+			s: self.s * scale,
+			x: self.x * scale,
+			y: self.y * scale,
+			w: self.w * scale,
+			yw: self.yw * scale,
+			wx: self.wx * scale,
+			xy: self.xy * scale,
+			xyw: self.xyw * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Multivector {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 8] {
+		[
+			self.s.0, self.x.0, self.y.0, self.w.0, self.yw.0, self.wx.0, self.xy.0, self.xyw.0,
+		]
+	}
+}
+
+impl From<[f64; 8]> for Multivector {
+	fn from(a: [f64; 8]) -> Self {
+		Multivector {
+			s: S(a[0]),
+			x: X(a[1]),
+			y: Y(a[2]),
+			w: W(a[3]),
+			yw: YW(a[4]),
+			wx: WX(a[5]),
+			xy: XY(a[6]),
+			xyw: XYW(a[7]),
+		}
+	}
+}
+
+impl From<Multivector> for [f64; 8] {
+	fn from(v: Multivector) -> Self {
+		v.to_array()
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Vec2:
+
+// Multivector.geometric(Vec2) -> Multivector
+impl Geometric<Vec2> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) + YW(self.xyw.0 * rhs.x.0),
+		//     wx : WX(self.w.0 * rhs.x.0) + WX(self.xyw.0 * rhs.y.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
+			w: self.wx.geometric(rhs.x) + self.yw.geometric(rhs.y),
+			yw: self.w.geometric(rhs.y) + self.xyw.geometric(rhs.x),
+			wx: self.w.geometric(rhs.x) + self.xyw.geometric(rhs.y),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			xyw: self.wx.geometric(rhs.y) + self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec2> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Vec2) -> Multivector
+impl AntiGeometric<Vec2> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.w.0 * rhs.y.0) + X(self.xyw.0 * rhs.x.0),
+		//     y  : -Y(self.w.0 * rhs.x.0) + Y(self.xyw.0 * rhs.y.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.wx.anti_geometric(rhs.y) + self.yw.anti_geometric(rhs.x),
+			x: self.w.anti_geometric(rhs.y) + self.xyw.anti_geometric(rhs.x),
+			y: self.w.anti_geometric(rhs.x) + self.xyw.anti_geometric(rhs.y),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.wx.anti_geometric(rhs.x) + self.yw.anti_geometric(rhs.y),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.dot(Vec2) -> Multivector
+impl Dot<Vec2> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.xyw.0 * rhs.x.0),
+		//     wx : WX(self.xyw.0 * rhs.y.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y),
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
+			w: self.wx.dot(rhs.x) + self.yw.dot(rhs.y),
+			yw: self.xyw.dot(rhs.x),
+			wx: self.xyw.dot(rhs.y),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec2> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Vec2) -> Multivector
+impl Wedge<Vec2> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : Default::default(),
+		//     yw : -YW(self.w.0 * rhs.y.0),
+		//     wx : WX(self.w.0 * rhs.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: Default::default(),
+			yw: self.w.wedge(rhs.y),
+			wx: self.w.wedge(rhs.x),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			xyw: self.wx.wedge(rhs.y) + self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec2> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Vec2) -> Multivector
+impl AntiWedge<Vec2> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.xyw.0 * rhs.x.0),
+		//     y  : Y(self.xyw.0 * rhs.y.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.wx.anti_wedge(rhs.y) + self.yw.anti_wedge(rhs.x),
+			x: self.xyw.anti_wedge(rhs.x),
+			y: self.xyw.anti_wedge(rhs.y),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec2> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Vec2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Vec2) -> Multivector
+impl LeftContraction<Vec2> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Vec2) -> Multivector
+impl RightContraction<Vec2> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.xyw.0 * rhs.x.0),
+		//     wx : WX(self.xyw.0 * rhs.y.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y),
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+			w: self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y),
+			yw: self.xyw.right_contraction(rhs.x),
+			wx: self.xyw.right_contraction(rhs.y),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Vec2) -> S
+impl ScalarProduct<Vec2> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Multivector.commutator(Vec2) -> Multivector
+impl Commutator<Vec2> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : -YW(self.w.0 * rhs.y.0),
+		//     wx : WX(self.w.0 * rhs.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y),
+			yw: self.w.commutator(rhs.y),
+			wx: self.w.commutator(rhs.x),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Vec2) -> Multivector
+impl AntiDot<Vec2> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xyw.0 * rhs.x.0),
+		//     y  : Y(self.xyw.0 * rhs.y.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xyw.anti_dot(rhs.x),
+			y: self.xyw.anti_dot(rhs.y),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.wx.anti_dot(rhs.x) + self.yw.anti_dot(rhs.y),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Vec3:
+
+// Multivector.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) + YW(self.xyw.0 * rhs.x.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0) + WX(self.xyw.0 * rhs.y.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.xy.0 * rhs.w.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
+			w: self.s.geometric(rhs.w) + self.wx.geometric(rhs.x) + self.yw.geometric(rhs.y),
+			yw: self.w.geometric(rhs.y) + self.xyw.geometric(rhs.x) + self.y.geometric(rhs.w),
+			wx: self.w.geometric(rhs.x) + self.x.geometric(rhs.w) + self.xyw.geometric(rhs.y),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			xyw: self.wx.geometric(rhs.y) + self.xy.geometric(rhs.w) + self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.wx.0 * rhs.y.0) + S(self.xy.0 * rhs.w.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.w.0 * rhs.y.0) + X(self.xyw.0 * rhs.x.0) - X(self.y.0 * rhs.w.0),
+		//     y  : -Y(self.w.0 * rhs.x.0) + Y(self.x.0 * rhs.w.0) + Y(self.xyw.0 * rhs.y.0),
+		//     w  : W(self.xyw.0 * rhs.w.0),
+		//     yw : -YW(self.wx.0 * rhs.w.0),
+		//     wx : WX(self.yw.0 * rhs.w.0),
+		//     xy : -XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: -XYW(self.w.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.wx.anti_geometric(rhs.y) + self.xy.anti_geometric(rhs.w) + self.yw.anti_geometric(rhs.x),
+			x: self.w.anti_geometric(rhs.y) + self.xyw.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.w),
+			y: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(rhs.w) + self.xyw.anti_geometric(rhs.y),
+			w: self.xyw.anti_geometric(rhs.w),
+			yw: self.wx.anti_geometric(rhs.w),
+			wx: self.yw.anti_geometric(rhs.w),
+			xy: self.s.anti_geometric(rhs.w) + self.wx.anti_geometric(rhs.x) + self.yw.anti_geometric(rhs.y),
+			xyw: self.w.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Multivector.dot(Vec3) -> Multivector
+impl Dot<Vec3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.xyw.0 * rhs.x.0),
+		//     wx : WX(self.xyw.0 * rhs.y.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y),
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
+			w: self.s.dot(rhs.w) + self.wx.dot(rhs.x) + self.yw.dot(rhs.y),
+			yw: self.xyw.dot(rhs.x),
+			wx: self.xyw.dot(rhs.y),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.xy.0 * rhs.w.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(rhs.w),
+			yw: self.w.wedge(rhs.y) + self.y.wedge(rhs.w),
+			wx: self.w.wedge(rhs.x) + self.x.wedge(rhs.w),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			xyw: self.wx.wedge(rhs.y) + self.xy.wedge(rhs.w) + self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Vec3) -> Multivector
+impl AntiWedge<Vec3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.wx.0 * rhs.y.0) + S(self.xy.0 * rhs.w.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.xyw.0 * rhs.x.0),
+		//     y  : Y(self.xyw.0 * rhs.y.0),
+		//     w  : W(self.xyw.0 * rhs.w.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.wx.anti_wedge(rhs.y) + self.xy.anti_wedge(rhs.w) + self.yw.anti_wedge(rhs.x),
+			x: self.xyw.anti_wedge(rhs.x),
+			y: self.xyw.anti_wedge(rhs.y),
+			w: self.xyw.anti_wedge(rhs.w),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Vec3) -> Multivector
+impl LeftContraction<Vec3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(rhs.w),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Vec3) -> Multivector
+impl RightContraction<Vec3> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.xyw.0 * rhs.x.0),
+		//     wx : WX(self.xyw.0 * rhs.y.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y),
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+			w: self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y),
+			yw: self.xyw.right_contraction(rhs.x),
+			wx: self.xyw.right_contraction(rhs.y),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Vec3) -> S
+impl ScalarProduct<Vec3> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Multivector.commutator(Vec3) -> Multivector
+impl Commutator<Vec3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y),
+			yw: self.w.commutator(rhs.y) + self.y.commutator(rhs.w),
+			wx: self.w.commutator(rhs.x) + self.x.commutator(rhs.w),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Vec3) -> Multivector
+impl AntiDot<Vec3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xyw.0 * rhs.x.0),
+		//     y  : Y(self.xyw.0 * rhs.y.0),
+		//     w  : W(self.xyw.0 * rhs.w.0),
+		//     yw : -YW(self.wx.0 * rhs.w.0),
+		//     wx : WX(self.yw.0 * rhs.w.0),
+		//     xy : -XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: -XYW(self.w.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xyw.anti_dot(rhs.x),
+			y: self.xyw.anti_dot(rhs.y),
+			w: self.xyw.anti_dot(rhs.w),
+			yw: self.wx.anti_dot(rhs.w),
+			wx: self.yw.anti_dot(rhs.w),
+			xy: self.s.anti_dot(rhs.w) + self.wx.anti_dot(rhs.x) + self.yw.anti_dot(rhs.y),
+			xyw: self.w.anti_dot(rhs.w),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Point2:
+
+// Multivector.geometric(Point2) -> Multivector
+impl Geometric<Point2> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) + YW(self.xyw.0 * rhs.x.0) + YW(self.y.0),
+		//     wx : WX(self.w.0 * rhs.x.0) - WX(self.x.0) + WX(self.xyw.0 * rhs.y.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.xy.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
+			w: self.s.geometric(W(1.0)) + self.wx.geometric(rhs.x) + self.yw.geometric(rhs.y),
+			yw: self.w.geometric(rhs.y) + self.xyw.geometric(rhs.x) + self.y.geometric(W(1.0)),
+			wx: self.w.geometric(rhs.x) + self.x.geometric(W(1.0)) + self.xyw.geometric(rhs.y),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			xyw: self.wx.geometric(rhs.y) + self.xy.geometric(W(1.0)) + self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Point2> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Point2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Point2) -> Multivector
+impl AntiGeometric<Point2> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.wx.0 * rhs.y.0) + S(self.xy.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.w.0 * rhs.y.0) + X(self.xyw.0 * rhs.x.0) - X(self.y.0),
+		//     y  : -Y(self.w.0 * rhs.x.0) + Y(self.x.0) + Y(self.xyw.0 * rhs.y.0),
+		//     w  : W(self.xyw.0),
+		//     yw : -YW(self.wx.0),
+		//     wx : WX(self.yw.0),
+		//     xy : -XY(self.s.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: -XYW(self.w.0),
+		// }
+		Multivector {
+			s: self.wx.anti_geometric(rhs.y) + self.xy.anti_geometric(W(1.0)) + self.yw.anti_geometric(rhs.x),
+			x: self.w.anti_geometric(rhs.y) + self.xyw.anti_geometric(rhs.x) + self.y.anti_geometric(W(1.0)),
+			y: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(W(1.0)) + self.xyw.anti_geometric(rhs.y),
+			w: self.xyw.anti_geometric(W(1.0)),
+			yw: self.wx.anti_geometric(W(1.0)),
+			wx: self.yw.anti_geometric(W(1.0)),
+			xy: self.s.anti_geometric(W(1.0)) + self.wx.anti_geometric(rhs.x) + self.yw.anti_geometric(rhs.y),
+			xyw: self.w.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Multivector.dot(Point2) -> Multivector
+impl Dot<Point2> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.xyw.0 * rhs.x.0),
+		//     wx : WX(self.xyw.0 * rhs.y.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y),
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
+			w: self.s.dot(W(1.0)) + self.wx.dot(rhs.x) + self.yw.dot(rhs.y),
+			yw: self.xyw.dot(rhs.x),
+			wx: self.xyw.dot(rhs.y),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point2> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Point2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Point2) -> Multivector
+impl Wedge<Point2> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) + YW(self.y.0),
+		//     wx : WX(self.w.0 * rhs.x.0) - WX(self.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.xy.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(W(1.0)),
+			yw: self.w.wedge(rhs.y) + self.y.wedge(W(1.0)),
+			wx: self.w.wedge(rhs.x) + self.x.wedge(W(1.0)),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			xyw: self.wx.wedge(rhs.y) + self.xy.wedge(W(1.0)) + self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point2> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Point2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Point2) -> Multivector
+impl AntiWedge<Point2> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.wx.0 * rhs.y.0) + S(self.xy.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.xyw.0 * rhs.x.0),
+		//     y  : Y(self.xyw.0 * rhs.y.0),
+		//     w  : W(self.xyw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.wx.anti_wedge(rhs.y) + self.xy.anti_wedge(W(1.0)) + self.yw.anti_wedge(rhs.x),
+			x: self.xyw.anti_wedge(rhs.x),
+			y: self.xyw.anti_wedge(rhs.y),
+			w: self.xyw.anti_wedge(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Point2> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Point2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Point2) -> Multivector
+impl LeftContraction<Point2> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Point2) -> Multivector
+impl RightContraction<Point2> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.xyw.0 * rhs.x.0),
+		//     wx : WX(self.xyw.0 * rhs.y.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y),
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+			w: self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y),
+			yw: self.xyw.right_contraction(rhs.x),
+			wx: self.xyw.right_contraction(rhs.y),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Point2) -> S
+impl ScalarProduct<Point2> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Multivector.commutator(Point2) -> Multivector
+impl Commutator<Point2> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) + YW(self.y.0),
+		//     wx : WX(self.w.0 * rhs.x.0) - WX(self.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y),
+			yw: self.w.commutator(rhs.y) + self.y.commutator(W(1.0)),
+			wx: self.w.commutator(rhs.x) + self.x.commutator(W(1.0)),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Point2) -> Multivector
+impl AntiDot<Point2> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xyw.0 * rhs.x.0),
+		//     y  : Y(self.xyw.0 * rhs.y.0),
+		//     w  : W(self.xyw.0),
+		//     yw : -YW(self.wx.0),
+		//     wx : WX(self.yw.0),
+		//     xy : -XY(self.s.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: -XYW(self.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xyw.anti_dot(rhs.x),
+			y: self.xyw.anti_dot(rhs.y),
+			w: self.xyw.anti_dot(W(1.0)),
+			yw: self.wx.anti_dot(W(1.0)),
+			wx: self.yw.anti_dot(W(1.0)),
+			xy: self.s.anti_dot(W(1.0)) + self.wx.anti_dot(rhs.x) + self.yw.anti_dot(rhs.y),
+			xyw: self.w.anti_dot(W(1.0)),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Line:
+
+// Multivector.geometric(Line) -> Multivector
+impl Geometric<Line> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.m.0),
+		//     x  : -X(self.y.0 * rhs.m.0),
+		//     y  : Y(self.x.0 * rhs.m.0),
+		//     w  : -W(self.x.0 * rhs.dy.0) - W(self.xyw.0 * rhs.m.0) + W(self.y.0 * rhs.dx.0),
+		//     yw : YW(self.s.0 * rhs.dx.0) - YW(self.wx.0 * rhs.m.0) + YW(self.xy.0 * rhs.dy.0),
+		//     wx : WX(self.s.0 * rhs.dy.0) - WX(self.xy.0 * rhs.dx.0) + WX(self.yw.0 * rhs.m.0),
+		//     xy : XY(self.s.0 * rhs.m.0),
+		//     xyw: XYW(self.w.0 * rhs.m.0) + XYW(self.x.0 * rhs.dx.0) + XYW(self.y.0 * rhs.dy.0),
+		// }
+		Multivector {
+			s: self.xy.geometric(rhs.m),
+			x: self.y.geometric(rhs.m),
+			y: self.x.geometric(rhs.m),
+			w: self.x.geometric(rhs.dy) + self.xyw.geometric(rhs.m) + self.y.geometric(rhs.dx),
+			yw: self.s.geometric(rhs.dx) + self.wx.geometric(rhs.m) + self.xy.geometric(rhs.dy),
+			wx: self.s.geometric(rhs.dy) + self.xy.geometric(rhs.dx) + self.yw.geometric(rhs.m),
+			xy: self.s.geometric(rhs.m),
+			xyw: self.w.geometric(rhs.m) + self.x.geometric(rhs.dx) + self.y.geometric(rhs.dy),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Line) -> Multivector
+impl AntiGeometric<Line> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.w.0 * rhs.m.0) + S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0),
+		//     x  : X(self.s.0 * rhs.dx.0) + X(self.wx.0 * rhs.m.0) - X(self.xy.0 * rhs.dy.0),
+		//     y  : Y(self.s.0 * rhs.dy.0) + Y(self.xy.0 * rhs.dx.0) - Y(self.yw.0 * rhs.m.0),
+		//     w  : -W(self.wx.0 * rhs.dx.0) + W(self.yw.0 * rhs.dy.0),
+		//     yw : YW(self.w.0 * rhs.dy.0) + YW(self.xyw.0 * rhs.dx.0),
+		//     wx : -WX(self.w.0 * rhs.dx.0) + WX(self.xyw.0 * rhs.dy.0),
+		//     xy : -XY(self.x.0 * rhs.dy.0) + XY(self.xyw.0 * rhs.m.0) + XY(self.y.0 * rhs.dx.0),
+		//     xyw: XYW(self.wx.0 * rhs.dy.0) + XYW(self.yw.0 * rhs.dx.0),
+		// }
+		Multivector {
+			s: self.w.anti_geometric(rhs.m) + self.x.anti_geometric(rhs.dx) + self.y.anti_geometric(rhs.dy),
+			x: self.s.anti_geometric(rhs.dx) + self.wx.anti_geometric(rhs.m) + self.xy.anti_geometric(rhs.dy),
+			y: self.s.anti_geometric(rhs.dy) + self.xy.anti_geometric(rhs.dx) + self.yw.anti_geometric(rhs.m),
+			w: self.wx.anti_geometric(rhs.dx) + self.yw.anti_geometric(rhs.dy),
+			yw: self.w.anti_geometric(rhs.dy) + self.xyw.anti_geometric(rhs.dx),
+			wx: self.w.anti_geometric(rhs.dx) + self.xyw.anti_geometric(rhs.dy),
+			xy: self.x.anti_geometric(rhs.dy) + self.xyw.anti_geometric(rhs.m) + self.y.anti_geometric(rhs.dx),
+			xyw: self.wx.anti_geometric(rhs.dy) + self.yw.anti_geometric(rhs.dx),
+		}
+	}
+}
+
+// Multivector.dot(Line) -> Multivector
+impl Dot<Line> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.m.0),
+		//     x  : -X(self.y.0 * rhs.m.0),
+		//     y  : Y(self.x.0 * rhs.m.0),
+		//     w  : -W(self.x.0 * rhs.dy.0) - W(self.xyw.0 * rhs.m.0) + W(self.y.0 * rhs.dx.0),
+		//     yw : YW(self.s.0 * rhs.dx.0),
+		//     wx : WX(self.s.0 * rhs.dy.0),
+		//     xy : XY(self.s.0 * rhs.m.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.dot(rhs.m),
+			x: self.y.dot(rhs.m),
+			y: self.x.dot(rhs.m),
+			w: self.x.dot(rhs.dy) + self.xyw.dot(rhs.m) + self.y.dot(rhs.dx),
+			yw: self.s.dot(rhs.dx),
+			wx: self.s.dot(rhs.dy),
+			xy: self.s.dot(rhs.m),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Line) -> Multivector
+impl Wedge<Line> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : Default::default(),
+		//     y  : Default::default(),
+		//     w  : Default::default(),
+		//     yw : YW(self.s.0 * rhs.dx.0),
+		//     wx : WX(self.s.0 * rhs.dy.0),
+		//     xy : XY(self.s.0 * rhs.m.0),
+		//     xyw: XYW(self.w.0 * rhs.m.0) + XYW(self.x.0 * rhs.dx.0) + XYW(self.y.0 * rhs.dy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: Default::default(),
+			yw: self.s.wedge(rhs.dx),
+			wx: self.s.wedge(rhs.dy),
+			xy: self.s.wedge(rhs.m),
+			xyw: self.w.wedge(rhs.m) + self.x.wedge(rhs.dx) + self.y.wedge(rhs.dy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Line) -> Multivector
+impl AntiWedge<Line> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.w.0 * rhs.m.0) + S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0),
+		//     x  : X(self.wx.0 * rhs.m.0) - X(self.xy.0 * rhs.dy.0),
+		//     y  : Y(self.xy.0 * rhs.dx.0) - Y(self.yw.0 * rhs.m.0),
+		//     w  : -W(self.wx.0 * rhs.dx.0) + W(self.yw.0 * rhs.dy.0),
+		//     yw : YW(self.xyw.0 * rhs.dx.0),
+		//     wx : WX(self.xyw.0 * rhs.dy.0),
+		//     xy : XY(self.xyw.0 * rhs.m.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.w.anti_wedge(rhs.m) + self.x.anti_wedge(rhs.dx) + self.y.anti_wedge(rhs.dy),
+			x: self.wx.anti_wedge(rhs.m) + self.xy.anti_wedge(rhs.dy),
+			y: self.xy.anti_wedge(rhs.dx) + self.yw.anti_wedge(rhs.m),
+			w: self.wx.anti_wedge(rhs.dx) + self.yw.anti_wedge(rhs.dy),
+			yw: self.xyw.anti_wedge(rhs.dx),
+			wx: self.xyw.anti_wedge(rhs.dy),
+			xy: self.xyw.anti_wedge(rhs.m),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Line) -> Multivector
+impl LeftContraction<Line> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.m.0),
+		//     x  : -X(self.y.0 * rhs.m.0),
+		//     y  : Y(self.x.0 * rhs.m.0),
+		//     w  : -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		//     yw : YW(self.s.0 * rhs.dx.0),
+		//     wx : WX(self.s.0 * rhs.dy.0),
+		//     xy : XY(self.s.0 * rhs.m.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.left_contraction(rhs.m),
+			x: self.y.left_contraction(rhs.m),
+			y: self.x.left_contraction(rhs.m),
+			w: self.x.left_contraction(rhs.dy) + self.y.left_contraction(rhs.dx),
+			yw: self.s.left_contraction(rhs.dx),
+			wx: self.s.left_contraction(rhs.dy),
+			xy: self.s.left_contraction(rhs.m),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Line) -> Multivector
+impl RightContraction<Line> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.m.0),
+		//     x  : Default::default(),
+		//     y  : Default::default(),
+		//     w  : -W(self.xyw.0 * rhs.m.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.right_contraction(rhs.m),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.xyw.right_contraction(rhs.m),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Line) -> S
+impl ScalarProduct<Line> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Line) -> Self::Output {
+		// -S(self.xy.0 * rhs.m.0)
+		self.xy.scalar_product(rhs.m)
+	}
+}
+
+// Multivector.commutator(Line) -> Multivector
+impl Commutator<Line> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.m.0),
+		//     y  : Y(self.x.0 * rhs.m.0),
+		//     w  : -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		//     yw : -YW(self.wx.0 * rhs.m.0) + YW(self.xy.0 * rhs.dy.0),
+		//     wx : -WX(self.xy.0 * rhs.dx.0) + WX(self.yw.0 * rhs.m.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.m),
+			y: self.x.commutator(rhs.m),
+			w: self.x.commutator(rhs.dy) + self.y.commutator(rhs.dx),
+			yw: self.wx.commutator(rhs.m) + self.xy.commutator(rhs.dy),
+			wx: self.xy.commutator(rhs.dx) + self.yw.commutator(rhs.m),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Line) -> Multivector
+impl AntiDot<Line> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.dx.0),
+		//     y  : Y(self.s.0 * rhs.dy.0),
+		//     w  : Default::default(),
+		//     yw : YW(self.w.0 * rhs.dy.0) + YW(self.xyw.0 * rhs.dx.0),
+		//     wx : -WX(self.w.0 * rhs.dx.0) + WX(self.xyw.0 * rhs.dy.0),
+		//     xy : -XY(self.x.0 * rhs.dy.0) + XY(self.xyw.0 * rhs.m.0) + XY(self.y.0 * rhs.dx.0),
+		//     xyw: XYW(self.wx.0 * rhs.dy.0) + XYW(self.yw.0 * rhs.dx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.dx),
+			y: self.s.anti_dot(rhs.dy),
+			w: Default::default(),
+			yw: self.w.anti_dot(rhs.dy) + self.xyw.anti_dot(rhs.dx),
+			wx: self.w.anti_dot(rhs.dx) + self.xyw.anti_dot(rhs.dy),
+			xy: self.x.anti_dot(rhs.dy) + self.xyw.anti_dot(rhs.m) + self.y.anti_dot(rhs.dx),
+			xyw: self.wx.anti_dot(rhs.dy) + self.yw.anti_dot(rhs.dx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Translator:
+
+// Multivector.geometric(Translator) -> Multivector
+impl Geometric<Translator> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.xy.0 * rhs.wx.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0) - WX(self.xy.0 * rhs.yw.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.xyw.0 * rhs.s.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s),
+			x: self.x.geometric(rhs.s),
+			y: self.y.geometric(rhs.s),
+			w: self.w.geometric(rhs.s) + self.x.geometric(rhs.wx) + self.y.geometric(rhs.yw),
+			yw: self.s.geometric(rhs.yw) + self.xy.geometric(rhs.wx) + self.yw.geometric(rhs.s),
+			wx: self.s.geometric(rhs.wx) + self.wx.geometric(rhs.s) + self.xy.geometric(rhs.yw),
+			xy: self.xy.geometric(rhs.s),
+			xyw: self.x.geometric(rhs.yw) + self.xyw.geometric(rhs.s) + self.y.geometric(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Translator) -> Multivector
+impl AntiGeometric<Translator> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.yw.0) + S(self.xyw.0 * rhs.s.0) + S(self.y.0 * rhs.wx.0),
+		//     x  : X(self.s.0 * rhs.yw.0) - X(self.xy.0 * rhs.wx.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0) + Y(self.xy.0 * rhs.yw.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : YW(self.w.0 * rhs.wx.0) + YW(self.xyw.0 * rhs.yw.0),
+		//     wx : -WX(self.w.0 * rhs.yw.0) + WX(self.xyw.0 * rhs.wx.0),
+		//     xy : -XY(self.w.0 * rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: self.x.anti_geometric(rhs.yw) + self.xyw.anti_geometric(rhs.s) + self.y.anti_geometric(rhs.wx),
+			x: self.s.anti_geometric(rhs.yw) + self.xy.anti_geometric(rhs.wx) + self.yw.anti_geometric(rhs.s),
+			y: self.s.anti_geometric(rhs.wx) + self.wx.anti_geometric(rhs.s) + self.xy.anti_geometric(rhs.yw),
+			w: self.wx.anti_geometric(rhs.yw) + self.yw.anti_geometric(rhs.wx),
+			yw: self.w.anti_geometric(rhs.wx) + self.xyw.anti_geometric(rhs.yw),
+			wx: self.w.anti_geometric(rhs.yw) + self.xyw.anti_geometric(rhs.wx),
+			xy: self.w.anti_geometric(rhs.s) + self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw),
+			xyw: self.wx.anti_geometric(rhs.wx) + self.yw.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Multivector.dot(Translator) -> Multivector
+impl Dot<Translator> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.xyw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s),
+			x: self.x.dot(rhs.s),
+			y: self.y.dot(rhs.s),
+			w: self.w.dot(rhs.s) + self.x.dot(rhs.wx) + self.y.dot(rhs.yw),
+			yw: self.s.dot(rhs.yw) + self.yw.dot(rhs.s),
+			wx: self.s.dot(rhs.wx) + self.wx.dot(rhs.s),
+			xy: self.xy.dot(rhs.s),
+			xyw: self.xyw.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Translator) -> Multivector
+impl Wedge<Translator> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.xyw.0 * rhs.s.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: self.w.wedge(rhs.s),
+			yw: self.s.wedge(rhs.yw) + self.yw.wedge(rhs.s),
+			wx: self.s.wedge(rhs.wx) + self.wx.wedge(rhs.s),
+			xy: self.xy.wedge(rhs.s),
+			xyw: self.x.wedge(rhs.yw) + self.xyw.wedge(rhs.s) + self.y.wedge(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Translator) -> Multivector
+impl AntiWedge<Translator> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.yw.0) + S(self.xyw.0 * rhs.s.0) + S(self.y.0 * rhs.wx.0),
+		//     x  : -X(self.xy.0 * rhs.wx.0),
+		//     y  : Y(self.xy.0 * rhs.yw.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : YW(self.xyw.0 * rhs.yw.0),
+		//     wx : WX(self.xyw.0 * rhs.wx.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.anti_wedge(rhs.yw) + self.xyw.anti_wedge(rhs.s) + self.y.anti_wedge(rhs.wx),
+			x: self.xy.anti_wedge(rhs.wx),
+			y: self.xy.anti_wedge(rhs.yw),
+			w: self.wx.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.wx),
+			yw: self.xyw.anti_wedge(rhs.yw),
+			wx: self.xyw.anti_wedge(rhs.wx),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Translator) -> Multivector
+impl LeftContraction<Translator> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : Default::default(),
+		//     y  : Default::default(),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0),
+		//     wx : WX(self.s.0 * rhs.wx.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Translator) -> Multivector
+impl RightContraction<Translator> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0),
+		//     yw : YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.xyw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s),
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: self.w.right_contraction(rhs.s),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+			xyw: self.xyw.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Multivector.scalar_product(Translator) -> S
+impl ScalarProduct<Translator> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator) -> Self::Output {
+		// S(self.s.0 * rhs.s.0)
+		self.s.scalar_product(rhs.s)
+	}
+}
+
+// Multivector.commutator(Translator) -> Multivector
+impl Commutator<Translator> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : Default::default(),
+		//     y  : Default::default(),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.xy.0 * rhs.wx.0),
+		//     wx : -WX(self.xy.0 * rhs.yw.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw),
+			yw: self.xy.commutator(rhs.wx),
+			wx: self.xy.commutator(rhs.yw),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Translator) -> Multivector
+impl AntiDot<Translator> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.xyw.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : YW(self.w.0 * rhs.wx.0) + YW(self.xyw.0 * rhs.yw.0),
+		//     wx : -WX(self.w.0 * rhs.yw.0) + WX(self.xyw.0 * rhs.wx.0),
+		//     xy : -XY(self.w.0 * rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: self.xyw.anti_dot(rhs.s),
+			x: self.s.anti_dot(rhs.yw) + self.yw.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.wx) + self.wx.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: self.w.anti_dot(rhs.wx) + self.xyw.anti_dot(rhs.yw),
+			wx: self.w.anti_dot(rhs.yw) + self.xyw.anti_dot(rhs.wx),
+			xy: self.w.anti_dot(rhs.s) + self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.yw),
+			xyw: self.wx.anti_dot(rhs.wx) + self.yw.anti_dot(rhs.yw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Rotor:
+
+// Multivector.geometric(Rotor) -> Multivector
+impl Geometric<Rotor> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.xyw.0 * rhs.xy.0),
+		//     yw : -YW(self.wx.0 * rhs.xy.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.wx.0 * rhs.s.0) + WX(self.yw.0 * rhs.xy.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.w.0 * rhs.xy.0) + XYW(self.xyw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s) + self.xy.geometric(rhs.xy),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+			w: self.w.geometric(rhs.s) + self.xyw.geometric(rhs.xy),
+			yw: self.wx.geometric(rhs.xy) + self.yw.geometric(rhs.s),
+			wx: self.wx.geometric(rhs.s) + self.yw.geometric(rhs.xy),
+			xy: self.s.geometric(rhs.xy) + self.xy.geometric(rhs.s),
+			xyw: self.w.geometric(rhs.xy) + self.xyw.geometric(rhs.s),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Rotor) -> Multivector
+impl AntiGeometric<Rotor> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.w.0 * rhs.xy.0) + S(self.xyw.0 * rhs.s.0),
+		//     x  : X(self.wx.0 * rhs.xy.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.wx.0 * rhs.s.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : -XY(self.w.0 * rhs.s.0) + XY(self.xyw.0 * rhs.xy.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.w.anti_geometric(rhs.xy) + self.xyw.anti_geometric(rhs.s),
+			x: self.wx.anti_geometric(rhs.xy) + self.yw.anti_geometric(rhs.s),
+			y: self.wx.anti_geometric(rhs.s) + self.yw.anti_geometric(rhs.xy),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.w.anti_geometric(rhs.s) + self.xyw.anti_geometric(rhs.xy),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.dot(Rotor) -> Multivector
+impl Dot<Rotor> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.xyw.0 * rhs.xy.0),
+		//     yw : YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.xyw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy),
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
+			w: self.w.dot(rhs.s) + self.xyw.dot(rhs.xy),
+			yw: self.yw.dot(rhs.s),
+			wx: self.wx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
+			xyw: self.xyw.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Rotor) -> Multivector
+impl Wedge<Rotor> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0),
+		//     yw : YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.w.0 * rhs.xy.0) + XYW(self.xyw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: self.w.wedge(rhs.s),
+			yw: self.yw.wedge(rhs.s),
+			wx: self.wx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy) + self.xy.wedge(rhs.s),
+			xyw: self.w.wedge(rhs.xy) + self.xyw.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Rotor) -> Multivector
+impl AntiWedge<Rotor> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.w.0 * rhs.xy.0) + S(self.xyw.0 * rhs.s.0),
+		//     x  : X(self.wx.0 * rhs.xy.0),
+		//     y  : -Y(self.yw.0 * rhs.xy.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : XY(self.xyw.0 * rhs.xy.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.w.anti_wedge(rhs.xy) + self.xyw.anti_wedge(rhs.s),
+			x: self.wx.anti_wedge(rhs.xy),
+			y: self.yw.anti_wedge(rhs.xy),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.xyw.anti_wedge(rhs.xy),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Rotor) -> Multivector
+impl LeftContraction<Rotor> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : -X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s) + self.xy.left_contraction(rhs.xy),
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.s.left_contraction(rhs.xy),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Rotor) -> Multivector
+impl RightContraction<Rotor> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.xyw.0 * rhs.xy.0),
+		//     yw : YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.xyw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s) + self.xy.right_contraction(rhs.xy),
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: self.w.right_contraction(rhs.s) + self.xyw.right_contraction(rhs.xy),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+			xyw: self.xyw.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Multivector.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0)
+		self.s.scalar_product(rhs.s) + self.xy.scalar_product(rhs.xy)
+	}
+}
+
+// Multivector.commutator(Rotor) -> Multivector
+impl Commutator<Rotor> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0),
+		//     w  : Default::default(),
+		//     yw : -YW(self.wx.0 * rhs.xy.0),
+		//     wx : WX(self.yw.0 * rhs.xy.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+			w: Default::default(),
+			yw: self.wx.commutator(rhs.xy),
+			wx: self.yw.commutator(rhs.xy),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Rotor) -> Multivector
+impl AntiDot<Rotor> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.xyw.0 * rhs.s.0),
+		//     x  : X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.wx.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : -XY(self.w.0 * rhs.s.0) + XY(self.xyw.0 * rhs.xy.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.xyw.anti_dot(rhs.s),
+			x: self.yw.anti_dot(rhs.s),
+			y: self.wx.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.w.anti_dot(rhs.s) + self.xyw.anti_dot(rhs.xy),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Motor:
+
+// Multivector.geometric(Motor) -> Multivector
+impl Geometric<Motor> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) - W(self.xyw.0 * rhs.xy.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) - YW(self.wx.0 * rhs.xy.0) + YW(self.xy.0 * rhs.wx.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0) - WX(self.xy.0 * rhs.yw.0) + WX(self.yw.0 * rhs.xy.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.w.0 * rhs.xy.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.xyw.0 * rhs.s.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s) + self.xy.geometric(rhs.xy),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+			w: self.w.geometric(rhs.s)
+				+ self.x.geometric(rhs.wx)
+				+ self.xyw.geometric(rhs.xy)
+				+ self.y.geometric(rhs.yw),
+			yw: self.s.geometric(rhs.yw)
+				+ self.wx.geometric(rhs.xy)
+				+ self.xy.geometric(rhs.wx)
+				+ self.yw.geometric(rhs.s),
+			wx: self.s.geometric(rhs.wx)
+				+ self.wx.geometric(rhs.s)
+				+ self.xy.geometric(rhs.yw)
+				+ self.yw.geometric(rhs.xy),
+			xy: self.s.geometric(rhs.xy) + self.xy.geometric(rhs.s),
+			xyw: self.w.geometric(rhs.xy)
+				+ self.x.geometric(rhs.yw)
+				+ self.xyw.geometric(rhs.s)
+				+ self.y.geometric(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Motor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Motor) -> Multivector
+impl AntiGeometric<Motor> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.w.0 * rhs.xy.0) + S(self.x.0 * rhs.yw.0) + S(self.xyw.0 * rhs.s.0) + S(self.y.0 * rhs.wx.0),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.wx.0 * rhs.xy.0) - X(self.xy.0 * rhs.wx.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0) + Y(self.xy.0 * rhs.yw.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : YW(self.w.0 * rhs.wx.0) + YW(self.xyw.0 * rhs.yw.0),
+		//     wx : -WX(self.w.0 * rhs.yw.0) + WX(self.xyw.0 * rhs.wx.0),
+		//     xy : -XY(self.w.0 * rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.xyw.0 * rhs.xy.0) + XY(self.y.0 * rhs.yw.0),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: self.w.anti_geometric(rhs.xy)
+				+ self.x.anti_geometric(rhs.yw)
+				+ self.xyw.anti_geometric(rhs.s)
+				+ self.y.anti_geometric(rhs.wx),
+			x: self.s.anti_geometric(rhs.yw)
+				+ self.wx.anti_geometric(rhs.xy)
+				+ self.xy.anti_geometric(rhs.wx)
+				+ self.yw.anti_geometric(rhs.s),
+			y: self.s.anti_geometric(rhs.wx)
+				+ self.wx.anti_geometric(rhs.s)
+				+ self.xy.anti_geometric(rhs.yw)
+				+ self.yw.anti_geometric(rhs.xy),
+			w: self.wx.anti_geometric(rhs.yw) + self.yw.anti_geometric(rhs.wx),
+			yw: self.w.anti_geometric(rhs.wx) + self.xyw.anti_geometric(rhs.yw),
+			wx: self.w.anti_geometric(rhs.yw) + self.xyw.anti_geometric(rhs.wx),
+			xy: self.w.anti_geometric(rhs.s)
+				+ self.x.anti_geometric(rhs.wx)
+				+ self.xyw.anti_geometric(rhs.xy)
+				+ self.y.anti_geometric(rhs.yw),
+			xyw: self.wx.anti_geometric(rhs.wx) + self.yw.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Multivector.dot(Motor) -> Multivector
+impl Dot<Motor> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) - W(self.xyw.0 * rhs.xy.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.xyw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy),
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
+			w: self.w.dot(rhs.s) + self.x.dot(rhs.wx) + self.xyw.dot(rhs.xy) + self.y.dot(rhs.yw),
+			yw: self.s.dot(rhs.yw) + self.yw.dot(rhs.s),
+			wx: self.s.dot(rhs.wx) + self.wx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
+			xyw: self.xyw.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Motor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Motor) -> Multivector
+impl Wedge<Motor> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.w.0 * rhs.xy.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.xyw.0 * rhs.s.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: self.w.wedge(rhs.s),
+			yw: self.s.wedge(rhs.yw) + self.yw.wedge(rhs.s),
+			wx: self.s.wedge(rhs.wx) + self.wx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy) + self.xy.wedge(rhs.s),
+			xyw: self.w.wedge(rhs.xy) + self.x.wedge(rhs.yw) + self.xyw.wedge(rhs.s) + self.y.wedge(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::BitXor<Motor> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Motor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Motor) -> Multivector
+impl AntiWedge<Motor> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.w.0 * rhs.xy.0) + S(self.x.0 * rhs.yw.0) + S(self.xyw.0 * rhs.s.0) + S(self.y.0 * rhs.wx.0),
+		//     x  : X(self.wx.0 * rhs.xy.0) - X(self.xy.0 * rhs.wx.0),
+		//     y  : Y(self.xy.0 * rhs.yw.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : YW(self.xyw.0 * rhs.yw.0),
+		//     wx : WX(self.xyw.0 * rhs.wx.0),
+		//     xy : XY(self.xyw.0 * rhs.xy.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.w.anti_wedge(rhs.xy)
+				+ self.x.anti_wedge(rhs.yw)
+				+ self.xyw.anti_wedge(rhs.s)
+				+ self.y.anti_wedge(rhs.wx),
+			x: self.wx.anti_wedge(rhs.xy) + self.xy.anti_wedge(rhs.wx),
+			y: self.xy.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.xy),
+			w: self.wx.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.wx),
+			yw: self.xyw.anti_wedge(rhs.yw),
+			wx: self.xyw.anti_wedge(rhs.wx),
+			xy: self.xyw.anti_wedge(rhs.xy),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Motor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Motor) -> Multivector
+impl LeftContraction<Motor> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : -X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0),
+		//     wx : WX(self.s.0 * rhs.wx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s) + self.xy.left_contraction(rhs.xy),
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+			w: self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
+			xy: self.s.left_contraction(rhs.xy),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Motor) -> Multivector
+impl RightContraction<Motor> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.xyw.0 * rhs.xy.0),
+		//     yw : YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.xyw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s) + self.xy.right_contraction(rhs.xy),
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: self.w.right_contraction(rhs.s) + self.xyw.right_contraction(rhs.xy),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+			xyw: self.xyw.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Multivector.scalar_product(Motor) -> S
+impl ScalarProduct<Motor> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Motor) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0)
+		self.s.scalar_product(rhs.s) + self.xy.scalar_product(rhs.xy)
+	}
+}
+
+// Multivector.commutator(Motor) -> Multivector
+impl Commutator<Motor> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : -YW(self.wx.0 * rhs.xy.0) + YW(self.xy.0 * rhs.wx.0),
+		//     wx : -WX(self.xy.0 * rhs.yw.0) + WX(self.yw.0 * rhs.xy.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+			w: self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw),
+			yw: self.wx.commutator(rhs.xy) + self.xy.commutator(rhs.wx),
+			wx: self.xy.commutator(rhs.yw) + self.yw.commutator(rhs.xy),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Motor) -> Multivector
+impl AntiDot<Motor> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.xyw.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : YW(self.w.0 * rhs.wx.0) + YW(self.xyw.0 * rhs.yw.0),
+		//     wx : -WX(self.w.0 * rhs.yw.0) + WX(self.xyw.0 * rhs.wx.0),
+		//     xy : -XY(self.w.0 * rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.xyw.0 * rhs.xy.0) + XY(self.y.0 * rhs.yw.0),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: self.xyw.anti_dot(rhs.s),
+			x: self.s.anti_dot(rhs.yw) + self.yw.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.wx) + self.wx.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: self.w.anti_dot(rhs.wx) + self.xyw.anti_dot(rhs.yw),
+			wx: self.w.anti_dot(rhs.yw) + self.xyw.anti_dot(rhs.wx),
+			xy: self.w.anti_dot(rhs.s) + self.x.anti_dot(rhs.wx) + self.xyw.anti_dot(rhs.xy) + self.y.anti_dot(rhs.yw),
+			xyw: self.wx.anti_dot(rhs.wx) + self.yw.anti_dot(rhs.yw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Multivector:
+
+// Multivector.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.x.0 * rhs.s.0) + X(self.xy.0 * rhs.y.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.s.0 * rhs.y.0) + Y(self.x.0 * rhs.xy.0) - Y(self.xy.0 * rhs.x.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.w.0 * rhs.s.0) + W(self.wx.0 * rhs.x.0) - W(self.x.0 * rhs.wx.0) - W(self.xy.0 * rhs.xyw.0) - W(self.xyw.0 * rhs.xy.0) + W(self.y.0 * rhs.yw.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) - YW(self.w.0 * rhs.y.0) - YW(self.wx.0 * rhs.xy.0) + YW(self.x.0 * rhs.xyw.0) + YW(self.xy.0 * rhs.wx.0) + YW(self.xyw.0 * rhs.x.0) + YW(self.y.0 * rhs.w.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.w.0 * rhs.x.0) + WX(self.wx.0 * rhs.s.0) - WX(self.x.0 * rhs.w.0) - WX(self.xy.0 * rhs.yw.0) + WX(self.xyw.0 * rhs.y.0) + WX(self.y.0 * rhs.xyw.0) + WX(self.yw.0 * rhs.xy.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.x.0 * rhs.y.0) + XY(self.xy.0 * rhs.s.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0) + XYW(self.w.0 * rhs.xy.0) + XYW(self.wx.0 * rhs.y.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.xy.0 * rhs.w.0) + XYW(self.xyw.0 * rhs.s.0) + XYW(self.y.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s) + self.x.geometric(rhs.x) + self.xy.geometric(rhs.xy) + self.y.geometric(rhs.y),
+			x: self.s.geometric(rhs.x) + self.x.geometric(rhs.s) + self.xy.geometric(rhs.y) + self.y.geometric(rhs.xy),
+			y: self.s.geometric(rhs.y) + self.x.geometric(rhs.xy) + self.xy.geometric(rhs.x) + self.y.geometric(rhs.s),
+			w: self.s.geometric(rhs.w)
+				+ self.w.geometric(rhs.s)
+				+ self.wx.geometric(rhs.x)
+				+ self.x.geometric(rhs.wx)
+				+ self.xy.geometric(rhs.xyw)
+				+ self.xyw.geometric(rhs.xy)
+				+ self.y.geometric(rhs.yw)
+				+ self.yw.geometric(rhs.y),
+			yw: self.s.geometric(rhs.yw)
+				+ self.w.geometric(rhs.y)
+				+ self.wx.geometric(rhs.xy)
+				+ self.x.geometric(rhs.xyw)
+				+ self.xy.geometric(rhs.wx)
+				+ self.xyw.geometric(rhs.x)
+				+ self.y.geometric(rhs.w)
+				+ self.yw.geometric(rhs.s),
+			wx: self.s.geometric(rhs.wx)
+				+ self.w.geometric(rhs.x)
+				+ self.wx.geometric(rhs.s)
+				+ self.x.geometric(rhs.w)
+				+ self.xy.geometric(rhs.yw)
+				+ self.xyw.geometric(rhs.y)
+				+ self.y.geometric(rhs.xyw)
+				+ self.yw.geometric(rhs.xy),
+			xy: self.s.geometric(rhs.xy) + self.x.geometric(rhs.y) + self.xy.geometric(rhs.s) + self.y.geometric(rhs.x),
+			xyw: self.s.geometric(rhs.xyw)
+				+ self.w.geometric(rhs.xy)
+				+ self.wx.geometric(rhs.y)
+				+ self.x.geometric(rhs.yw)
+				+ self.xy.geometric(rhs.w)
+				+ self.xyw.geometric(rhs.s)
+				+ self.y.geometric(rhs.wx)
+				+ self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0) + S(self.w.0 * rhs.xy.0) + S(self.wx.0 * rhs.y.0) + S(self.x.0 * rhs.yw.0) + S(self.xy.0 * rhs.w.0) + S(self.xyw.0 * rhs.s.0) + S(self.y.0 * rhs.wx.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.w.0 * rhs.y.0) + X(self.wx.0 * rhs.xy.0) + X(self.x.0 * rhs.xyw.0) - X(self.xy.0 * rhs.wx.0) + X(self.xyw.0 * rhs.x.0) - X(self.y.0 * rhs.w.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) - Y(self.w.0 * rhs.x.0) + Y(self.wx.0 * rhs.s.0) + Y(self.x.0 * rhs.w.0) + Y(self.xy.0 * rhs.yw.0) + Y(self.xyw.0 * rhs.y.0) + Y(self.y.0 * rhs.xyw.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w  : W(self.w.0 * rhs.xyw.0) - W(self.wx.0 * rhs.yw.0) + W(self.xyw.0 * rhs.w.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : YW(self.w.0 * rhs.wx.0) - YW(self.wx.0 * rhs.w.0) + YW(self.xyw.0 * rhs.yw.0) + YW(self.yw.0 * rhs.xyw.0),
+		//     wx : -WX(self.w.0 * rhs.yw.0) + WX(self.wx.0 * rhs.xyw.0) + WX(self.xyw.0 * rhs.wx.0) + WX(self.yw.0 * rhs.w.0),
+		//     xy : -XY(self.s.0 * rhs.w.0) - XY(self.w.0 * rhs.s.0) + XY(self.wx.0 * rhs.x.0) - XY(self.x.0 * rhs.wx.0) + XY(self.xy.0 * rhs.xyw.0) + XY(self.xyw.0 * rhs.xy.0) + XY(self.y.0 * rhs.yw.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: -XYW(self.w.0 * rhs.w.0) + XYW(self.wx.0 * rhs.wx.0) + XYW(self.xyw.0 * rhs.xyw.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: self.s.anti_geometric(rhs.xyw)
+				+ self.w.anti_geometric(rhs.xy)
+				+ self.wx.anti_geometric(rhs.y)
+				+ self.x.anti_geometric(rhs.yw)
+				+ self.xy.anti_geometric(rhs.w)
+				+ self.xyw.anti_geometric(rhs.s)
+				+ self.y.anti_geometric(rhs.wx)
+				+ self.yw.anti_geometric(rhs.x),
+			x: self.s.anti_geometric(rhs.yw)
+				+ self.w.anti_geometric(rhs.y)
+				+ self.wx.anti_geometric(rhs.xy)
+				+ self.x.anti_geometric(rhs.xyw)
+				+ self.xy.anti_geometric(rhs.wx)
+				+ self.xyw.anti_geometric(rhs.x)
+				+ self.y.anti_geometric(rhs.w)
+				+ self.yw.anti_geometric(rhs.s),
+			y: self.s.anti_geometric(rhs.wx)
+				+ self.w.anti_geometric(rhs.x)
+				+ self.wx.anti_geometric(rhs.s)
+				+ self.x.anti_geometric(rhs.w)
+				+ self.xy.anti_geometric(rhs.yw)
+				+ self.xyw.anti_geometric(rhs.y)
+				+ self.y.anti_geometric(rhs.xyw)
+				+ self.yw.anti_geometric(rhs.xy),
+			w: self.w.anti_geometric(rhs.xyw)
+				+ self.wx.anti_geometric(rhs.yw)
+				+ self.xyw.anti_geometric(rhs.w)
+				+ self.yw.anti_geometric(rhs.wx),
+			yw: self.w.anti_geometric(rhs.wx)
+				+ self.wx.anti_geometric(rhs.w)
+				+ self.xyw.anti_geometric(rhs.yw)
+				+ self.yw.anti_geometric(rhs.xyw),
+			wx: self.w.anti_geometric(rhs.yw)
+				+ self.wx.anti_geometric(rhs.xyw)
+				+ self.xyw.anti_geometric(rhs.wx)
+				+ self.yw.anti_geometric(rhs.w),
+			xy: self.s.anti_geometric(rhs.w)
+				+ self.w.anti_geometric(rhs.s)
+				+ self.wx.anti_geometric(rhs.x)
+				+ self.x.anti_geometric(rhs.wx)
+				+ self.xy.anti_geometric(rhs.xyw)
+				+ self.xyw.anti_geometric(rhs.xy)
+				+ self.y.anti_geometric(rhs.yw)
+				+ self.yw.anti_geometric(rhs.y),
+			xyw: self.w.anti_geometric(rhs.w)
+				+ self.wx.anti_geometric(rhs.wx)
+				+ self.xyw.anti_geometric(rhs.xyw)
+				+ self.yw.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Multivector.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.x.0 * rhs.s.0) + X(self.xy.0 * rhs.y.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.s.0 * rhs.y.0) + Y(self.x.0 * rhs.xy.0) - Y(self.xy.0 * rhs.x.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.w.0 * rhs.s.0) + W(self.wx.0 * rhs.x.0) - W(self.x.0 * rhs.wx.0) - W(self.xy.0 * rhs.xyw.0) - W(self.xyw.0 * rhs.xy.0) + W(self.y.0 * rhs.yw.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.x.0 * rhs.xyw.0) + YW(self.xyw.0 * rhs.x.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0) + WX(self.xyw.0 * rhs.y.0) + WX(self.y.0 * rhs.xyw.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0) + XYW(self.xyw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s) + self.x.dot(rhs.x) + self.xy.dot(rhs.xy) + self.y.dot(rhs.y),
+			x: self.s.dot(rhs.x) + self.x.dot(rhs.s) + self.xy.dot(rhs.y) + self.y.dot(rhs.xy),
+			y: self.s.dot(rhs.y) + self.x.dot(rhs.xy) + self.xy.dot(rhs.x) + self.y.dot(rhs.s),
+			w: self.s.dot(rhs.w)
+				+ self.w.dot(rhs.s)
+				+ self.wx.dot(rhs.x)
+				+ self.x.dot(rhs.wx)
+				+ self.xy.dot(rhs.xyw)
+				+ self.xyw.dot(rhs.xy)
+				+ self.y.dot(rhs.yw)
+				+ self.yw.dot(rhs.y),
+			yw: self.s.dot(rhs.yw) + self.x.dot(rhs.xyw) + self.xyw.dot(rhs.x) + self.yw.dot(rhs.s),
+			wx: self.s.dot(rhs.wx) + self.wx.dot(rhs.s) + self.xyw.dot(rhs.y) + self.y.dot(rhs.xyw),
+			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
+			xyw: self.s.dot(rhs.xyw) + self.xyw.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.y.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.w.0 * rhs.s.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) - YW(self.w.0 * rhs.y.0) + YW(self.y.0 * rhs.w.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.w.0 * rhs.x.0) + WX(self.wx.0 * rhs.s.0) - WX(self.x.0 * rhs.w.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.x.0 * rhs.y.0) + XY(self.xy.0 * rhs.s.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0) + XYW(self.w.0 * rhs.xy.0) + XYW(self.wx.0 * rhs.y.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.xy.0 * rhs.w.0) + XYW(self.xyw.0 * rhs.s.0) + XYW(self.y.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.s.wedge(rhs.x) + self.x.wedge(rhs.s),
+			y: self.s.wedge(rhs.y) + self.y.wedge(rhs.s),
+			w: self.s.wedge(rhs.w) + self.w.wedge(rhs.s),
+			yw: self.s.wedge(rhs.yw) + self.w.wedge(rhs.y) + self.y.wedge(rhs.w) + self.yw.wedge(rhs.s),
+			wx: self.s.wedge(rhs.wx) + self.w.wedge(rhs.x) + self.wx.wedge(rhs.s) + self.x.wedge(rhs.w),
+			xy: self.s.wedge(rhs.xy) + self.x.wedge(rhs.y) + self.xy.wedge(rhs.s) + self.y.wedge(rhs.x),
+			xyw: self.s.wedge(rhs.xyw)
+				+ self.w.wedge(rhs.xy)
+				+ self.wx.wedge(rhs.y)
+				+ self.x.wedge(rhs.yw)
+				+ self.xy.wedge(rhs.w)
+				+ self.xyw.wedge(rhs.s)
+				+ self.y.wedge(rhs.wx)
+				+ self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0) + S(self.w.0 * rhs.xy.0) + S(self.wx.0 * rhs.y.0) + S(self.x.0 * rhs.yw.0) + S(self.xy.0 * rhs.w.0) + S(self.xyw.0 * rhs.s.0) + S(self.y.0 * rhs.wx.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.wx.0 * rhs.xy.0) + X(self.x.0 * rhs.xyw.0) - X(self.xy.0 * rhs.wx.0) + X(self.xyw.0 * rhs.x.0),
+		//     y  : Y(self.xy.0 * rhs.yw.0) + Y(self.xyw.0 * rhs.y.0) + Y(self.y.0 * rhs.xyw.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w  : W(self.w.0 * rhs.xyw.0) - W(self.wx.0 * rhs.yw.0) + W(self.xyw.0 * rhs.w.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : YW(self.xyw.0 * rhs.yw.0) + YW(self.yw.0 * rhs.xyw.0),
+		//     wx : WX(self.wx.0 * rhs.xyw.0) + WX(self.xyw.0 * rhs.wx.0),
+		//     xy : XY(self.xy.0 * rhs.xyw.0) + XY(self.xyw.0 * rhs.xy.0),
+		//     xyw: XYW(self.xyw.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: self.s.anti_wedge(rhs.xyw)
+				+ self.w.anti_wedge(rhs.xy)
+				+ self.wx.anti_wedge(rhs.y)
+				+ self.x.anti_wedge(rhs.yw)
+				+ self.xy.anti_wedge(rhs.w)
+				+ self.xyw.anti_wedge(rhs.s)
+				+ self.y.anti_wedge(rhs.wx)
+				+ self.yw.anti_wedge(rhs.x),
+			x: self.wx.anti_wedge(rhs.xy)
+				+ self.x.anti_wedge(rhs.xyw)
+				+ self.xy.anti_wedge(rhs.wx)
+				+ self.xyw.anti_wedge(rhs.x),
+			y: self.xy.anti_wedge(rhs.yw)
+				+ self.xyw.anti_wedge(rhs.y)
+				+ self.y.anti_wedge(rhs.xyw)
+				+ self.yw.anti_wedge(rhs.xy),
+			w: self.w.anti_wedge(rhs.xyw)
+				+ self.wx.anti_wedge(rhs.yw)
+				+ self.xyw.anti_wedge(rhs.w)
+				+ self.yw.anti_wedge(rhs.wx),
+			yw: self.xyw.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.xyw),
+			wx: self.wx.anti_wedge(rhs.xyw) + self.xyw.anti_wedge(rhs.wx),
+			xy: self.xy.anti_wedge(rhs.xyw) + self.xyw.anti_wedge(rhs.xy),
+			xyw: self.xyw.anti_wedge(rhs.xyw),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.x.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.s.0 * rhs.y.0) + Y(self.x.0 * rhs.xy.0),
+		//     w  : W(self.s.0 * rhs.w.0) - W(self.x.0 * rhs.wx.0) - W(self.xy.0 * rhs.xyw.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.x.0 * rhs.xyw.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.y.0 * rhs.xyw.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s)
+				+ self.x.left_contraction(rhs.x)
+				+ self.xy.left_contraction(rhs.xy)
+				+ self.y.left_contraction(rhs.y),
+			x: self.s.left_contraction(rhs.x) + self.y.left_contraction(rhs.xy),
+			y: self.s.left_contraction(rhs.y) + self.x.left_contraction(rhs.xy),
+			w: self.s.left_contraction(rhs.w)
+				+ self.x.left_contraction(rhs.wx)
+				+ self.xy.left_contraction(rhs.xyw)
+				+ self.y.left_contraction(rhs.yw),
+			yw: self.s.left_contraction(rhs.yw) + self.x.left_contraction(rhs.xyw),
+			wx: self.s.left_contraction(rhs.wx) + self.y.left_contraction(rhs.xyw),
+			xy: self.s.left_contraction(rhs.xy),
+			xyw: self.s.left_contraction(rhs.xyw),
+		}
+	}
+}
+
+// Multivector.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.s.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) + W(self.wx.0 * rhs.x.0) - W(self.xyw.0 * rhs.xy.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.xyw.0 * rhs.x.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.wx.0 * rhs.s.0) + WX(self.xyw.0 * rhs.y.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.xyw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s)
+				+ self.x.right_contraction(rhs.x)
+				+ self.xy.right_contraction(rhs.xy)
+				+ self.y.right_contraction(rhs.y),
+			x: self.x.right_contraction(rhs.s) + self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x) + self.y.right_contraction(rhs.s),
+			w: self.w.right_contraction(rhs.s)
+				+ self.wx.right_contraction(rhs.x)
+				+ self.xyw.right_contraction(rhs.xy)
+				+ self.yw.right_contraction(rhs.y),
+			yw: self.xyw.right_contraction(rhs.x) + self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s) + self.xyw.right_contraction(rhs.y),
+			xy: self.xy.right_contraction(rhs.s),
+			xyw: self.xyw.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Multivector.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) + S(self.y.0 * rhs.y.0)
+		self.s.scalar_product(rhs.s)
+			+ self.x.scalar_product(rhs.x)
+			+ self.xy.scalar_product(rhs.xy)
+			+ self.y.scalar_product(rhs.y)
+	}
+}
+
+// Multivector.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) - YW(self.wx.0 * rhs.xy.0) + YW(self.xy.0 * rhs.wx.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0) - WX(self.xy.0 * rhs.yw.0) + WX(self.yw.0 * rhs.xy.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y) + self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy) + self.xy.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x)
+				+ self.x.commutator(rhs.wx)
+				+ self.y.commutator(rhs.yw)
+				+ self.yw.commutator(rhs.y),
+			yw: self.w.commutator(rhs.y)
+				+ self.wx.commutator(rhs.xy)
+				+ self.xy.commutator(rhs.wx)
+				+ self.y.commutator(rhs.w),
+			wx: self.w.commutator(rhs.x)
+				+ self.x.commutator(rhs.w)
+				+ self.xy.commutator(rhs.yw)
+				+ self.yw.commutator(rhs.xy),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0) + S(self.xyw.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.x.0 * rhs.xyw.0) + X(self.xyw.0 * rhs.x.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0) + Y(self.xyw.0 * rhs.y.0) + Y(self.y.0 * rhs.xyw.0),
+		//     w  : W(self.w.0 * rhs.xyw.0) + W(self.xyw.0 * rhs.w.0),
+		//     yw : YW(self.w.0 * rhs.wx.0) - YW(self.wx.0 * rhs.w.0) + YW(self.xyw.0 * rhs.yw.0) + YW(self.yw.0 * rhs.xyw.0),
+		//     wx : -WX(self.w.0 * rhs.yw.0) + WX(self.wx.0 * rhs.xyw.0) + WX(self.xyw.0 * rhs.wx.0) + WX(self.yw.0 * rhs.w.0),
+		//     xy : -XY(self.s.0 * rhs.w.0) - XY(self.w.0 * rhs.s.0) + XY(self.wx.0 * rhs.x.0) - XY(self.x.0 * rhs.wx.0) + XY(self.xy.0 * rhs.xyw.0) + XY(self.xyw.0 * rhs.xy.0) + XY(self.y.0 * rhs.yw.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: -XYW(self.w.0 * rhs.w.0) + XYW(self.wx.0 * rhs.wx.0) + XYW(self.xyw.0 * rhs.xyw.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: self.s.anti_dot(rhs.xyw) + self.xyw.anti_dot(rhs.s),
+			x: self.s.anti_dot(rhs.yw) + self.x.anti_dot(rhs.xyw) + self.xyw.anti_dot(rhs.x) + self.yw.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.wx) + self.wx.anti_dot(rhs.s) + self.xyw.anti_dot(rhs.y) + self.y.anti_dot(rhs.xyw),
+			w: self.w.anti_dot(rhs.xyw) + self.xyw.anti_dot(rhs.w),
+			yw: self.w.anti_dot(rhs.wx)
+				+ self.wx.anti_dot(rhs.w)
+				+ self.xyw.anti_dot(rhs.yw)
+				+ self.yw.anti_dot(rhs.xyw),
+			wx: self.w.anti_dot(rhs.yw)
+				+ self.wx.anti_dot(rhs.xyw)
+				+ self.xyw.anti_dot(rhs.wx)
+				+ self.yw.anti_dot(rhs.w),
+			xy: self.s.anti_dot(rhs.w)
+				+ self.w.anti_dot(rhs.s)
+				+ self.wx.anti_dot(rhs.x)
+				+ self.x.anti_dot(rhs.wx)
+				+ self.xy.anti_dot(rhs.xyw)
+				+ self.xyw.anti_dot(rhs.xy)
+				+ self.y.anti_dot(rhs.yw)
+				+ self.yw.anti_dot(rhs.y),
+			xyw: self.w.anti_dot(rhs.w)
+				+ self.wx.anti_dot(rhs.wx)
+				+ self.xyw.anti_dot(rhs.xyw)
+				+ self.yw.anti_dot(rhs.yw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Multivector.sandwich(Vec2)  (not a Vec2)
+// Omitted: Multivector.anti_sandwich(Vec2)  (not a Vec2)
+// Omitted: Multivector.sandwich(Vec3)  (not a Vec3)
+// Omitted: Multivector.anti_sandwich(Vec3)  (not a Vec3)
+// Omitted: Multivector.sandwich(Point2)  (not a Point2)
+// Omitted: Multivector.anti_sandwich(Point2)  (not a Point2)
+// Omitted: Multivector.sandwich(Line)  (not a Line)
+// Omitted: Multivector.anti_sandwich(Line)  (not a Line)
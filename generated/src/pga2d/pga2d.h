@@ -0,0 +1,740 @@
+/* The C ABI of the `pga2d` geometric algebra, generated together with its Rust code.
+ * All values are passed by value, and the structs have the same layout as in Rust. */
+
+#ifndef PGA2D_H
+#define PGA2D_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct pga2d_Vec2 {
+	double x; /* X */
+	double y; /* Y */
+} pga2d_Vec2;
+
+typedef struct pga2d_Vec3 {
+	double x; /* X */
+	double y; /* Y */
+	double w; /* W */
+} pga2d_Vec3;
+
+typedef struct pga2d_Point2 {
+	double x; /* X */
+	double y; /* Y */
+	/* w: W = 1 */
+} pga2d_Point2;
+
+typedef struct pga2d_Line {
+	double dx; /* YW */
+	double dy; /* WX */
+	double m; /* XY */
+} pga2d_Line;
+
+typedef struct pga2d_Translator {
+	double s; /* S */
+	double yw; /* YW */
+	double wx; /* WX */
+} pga2d_Translator;
+
+typedef struct pga2d_Rotor {
+	double s; /* S */
+	double xy; /* XY */
+} pga2d_Rotor;
+
+typedef struct pga2d_Motor {
+	double s; /* S */
+	double yw; /* YW */
+	double wx; /* WX */
+	double xy; /* XY */
+} pga2d_Motor;
+
+typedef struct pga2d_Multivector {
+	double s; /* S */
+	double x; /* X */
+	double y; /* Y */
+	double w; /* W */
+	double yw; /* YW */
+	double wx; /* WX */
+	double xy; /* XY */
+	double xyw; /* XYW */
+} pga2d_Multivector;
+
+pga2d_Line pga2d_vec2_rcompl(pga2d_Vec2 value);
+pga2d_Line pga2d_vec2_lcompl(pga2d_Vec2 value);
+pga2d_Vec2 pga2d_vec2_rev(pga2d_Vec2 value);
+pga2d_Vec2 pga2d_vec2_arev(pga2d_Vec2 value);
+pga2d_Vec2 pga2d_vec2_involute(pga2d_Vec2 value);
+pga2d_Vec2 pga2d_vec2_conjugate(pga2d_Vec2 value);
+pga2d_Line pga2d_vec2_dual(pga2d_Vec2 value);
+pga2d_Vec2 pga2d_vec2_bulk(pga2d_Vec2 value);
+pga2d_Line pga2d_vec2_bulk_dual(pga2d_Vec2 value);
+pga2d_Rotor pga2d_vec2_geometric_vec2(pga2d_Vec2 lhs, pga2d_Vec2 rhs);
+double pga2d_vec2_dot_vec2(pga2d_Vec2 lhs, pga2d_Vec2 rhs);
+double pga2d_vec2_wedge_vec2(pga2d_Vec2 lhs, pga2d_Vec2 rhs);
+double pga2d_vec2_left_contraction_vec2(pga2d_Vec2 lhs, pga2d_Vec2 rhs);
+double pga2d_vec2_right_contraction_vec2(pga2d_Vec2 lhs, pga2d_Vec2 rhs);
+double pga2d_vec2_scalar_product_vec2(pga2d_Vec2 lhs, pga2d_Vec2 rhs);
+double pga2d_vec2_commutator_vec2(pga2d_Vec2 lhs, pga2d_Vec2 rhs);
+pga2d_Motor pga2d_vec2_geometric_vec3(pga2d_Vec2 lhs, pga2d_Vec3 rhs);
+pga2d_Vec2 pga2d_vec2_anti_geometric_vec3(pga2d_Vec2 lhs, pga2d_Vec3 rhs);
+double pga2d_vec2_dot_vec3(pga2d_Vec2 lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_vec2_wedge_vec3(pga2d_Vec2 lhs, pga2d_Vec3 rhs);
+double pga2d_vec2_left_contraction_vec3(pga2d_Vec2 lhs, pga2d_Vec3 rhs);
+double pga2d_vec2_right_contraction_vec3(pga2d_Vec2 lhs, pga2d_Vec3 rhs);
+double pga2d_vec2_scalar_product_vec3(pga2d_Vec2 lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_vec2_commutator_vec3(pga2d_Vec2 lhs, pga2d_Vec3 rhs);
+pga2d_Motor pga2d_vec2_geometric_point2(pga2d_Vec2 lhs, pga2d_Point2 rhs);
+pga2d_Vec2 pga2d_vec2_anti_geometric_point2(pga2d_Vec2 lhs, pga2d_Point2 rhs);
+double pga2d_vec2_dot_point2(pga2d_Vec2 lhs, pga2d_Point2 rhs);
+pga2d_Line pga2d_vec2_wedge_point2(pga2d_Vec2 lhs, pga2d_Point2 rhs);
+double pga2d_vec2_left_contraction_point2(pga2d_Vec2 lhs, pga2d_Point2 rhs);
+double pga2d_vec2_right_contraction_point2(pga2d_Vec2 lhs, pga2d_Point2 rhs);
+double pga2d_vec2_scalar_product_point2(pga2d_Vec2 lhs, pga2d_Point2 rhs);
+pga2d_Line pga2d_vec2_commutator_point2(pga2d_Vec2 lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_vec2_geometric_line(pga2d_Vec2 lhs, pga2d_Line rhs);
+pga2d_Rotor pga2d_vec2_anti_geometric_line(pga2d_Vec2 lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_vec2_dot_line(pga2d_Vec2 lhs, pga2d_Line rhs);
+double pga2d_vec2_wedge_line(pga2d_Vec2 lhs, pga2d_Line rhs);
+double pga2d_vec2_anti_wedge_line(pga2d_Vec2 lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_vec2_left_contraction_line(pga2d_Vec2 lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_vec2_commutator_line(pga2d_Vec2 lhs, pga2d_Line rhs);
+double pga2d_vec2_anti_dot_line(pga2d_Vec2 lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_vec2_geometric_translator(pga2d_Vec2 lhs, pga2d_Translator rhs);
+pga2d_Rotor pga2d_vec2_anti_geometric_translator(pga2d_Vec2 lhs, pga2d_Translator rhs);
+pga2d_Vec3 pga2d_vec2_dot_translator(pga2d_Vec2 lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_vec2_wedge_translator(pga2d_Vec2 lhs, pga2d_Translator rhs);
+double pga2d_vec2_anti_wedge_translator(pga2d_Vec2 lhs, pga2d_Translator rhs);
+double pga2d_vec2_left_contraction_translator(pga2d_Vec2 lhs, pga2d_Translator rhs);
+pga2d_Vec2 pga2d_vec2_right_contraction_translator(pga2d_Vec2 lhs, pga2d_Translator rhs);
+double pga2d_vec2_commutator_translator(pga2d_Vec2 lhs, pga2d_Translator rhs);
+double pga2d_vec2_anti_dot_translator(pga2d_Vec2 lhs, pga2d_Translator rhs);
+pga2d_Vec2 pga2d_vec2_geometric_rotor(pga2d_Vec2 lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_vec2_dot_rotor(pga2d_Vec2 lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_vec2_wedge_rotor(pga2d_Vec2 lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_vec2_left_contraction_rotor(pga2d_Vec2 lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_vec2_right_contraction_rotor(pga2d_Vec2 lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_vec2_commutator_rotor(pga2d_Vec2 lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_vec2_geometric_motor(pga2d_Vec2 lhs, pga2d_Motor rhs);
+pga2d_Rotor pga2d_vec2_anti_geometric_motor(pga2d_Vec2 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_vec2_dot_motor(pga2d_Vec2 lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_vec2_wedge_motor(pga2d_Vec2 lhs, pga2d_Motor rhs);
+double pga2d_vec2_anti_wedge_motor(pga2d_Vec2 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_vec2_left_contraction_motor(pga2d_Vec2 lhs, pga2d_Motor rhs);
+pga2d_Vec2 pga2d_vec2_right_contraction_motor(pga2d_Vec2 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_vec2_commutator_motor(pga2d_Vec2 lhs, pga2d_Motor rhs);
+double pga2d_vec2_anti_dot_motor(pga2d_Vec2 lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_vec2_geometric_multivector(pga2d_Vec2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec2_anti_geometric_multivector(pga2d_Vec2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec2_dot_multivector(pga2d_Vec2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec2_wedge_multivector(pga2d_Vec2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec2_anti_wedge_multivector(pga2d_Vec2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec2_left_contraction_multivector(pga2d_Vec2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec2_right_contraction_multivector(pga2d_Vec2 lhs, pga2d_Multivector rhs);
+double pga2d_vec2_scalar_product_multivector(pga2d_Vec2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec2_commutator_multivector(pga2d_Vec2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec2_anti_dot_multivector(pga2d_Vec2 lhs, pga2d_Multivector rhs);
+pga2d_Line pga2d_vec3_rcompl(pga2d_Vec3 value);
+pga2d_Line pga2d_vec3_lcompl(pga2d_Vec3 value);
+pga2d_Vec3 pga2d_vec3_rev(pga2d_Vec3 value);
+pga2d_Vec3 pga2d_vec3_arev(pga2d_Vec3 value);
+pga2d_Vec3 pga2d_vec3_involute(pga2d_Vec3 value);
+pga2d_Vec3 pga2d_vec3_conjugate(pga2d_Vec3 value);
+pga2d_Line pga2d_vec3_dual(pga2d_Vec3 value);
+pga2d_Vec2 pga2d_vec3_bulk(pga2d_Vec3 value);
+double pga2d_vec3_weight(pga2d_Vec3 value);
+pga2d_Line pga2d_vec3_bulk_dual(pga2d_Vec3 value);
+double pga2d_vec3_weight_dual(pga2d_Vec3 value);
+pga2d_Motor pga2d_vec3_geometric_vec2(pga2d_Vec3 lhs, pga2d_Vec2 rhs);
+pga2d_Vec2 pga2d_vec3_anti_geometric_vec2(pga2d_Vec3 lhs, pga2d_Vec2 rhs);
+double pga2d_vec3_dot_vec2(pga2d_Vec3 lhs, pga2d_Vec2 rhs);
+pga2d_Line pga2d_vec3_wedge_vec2(pga2d_Vec3 lhs, pga2d_Vec2 rhs);
+double pga2d_vec3_left_contraction_vec2(pga2d_Vec3 lhs, pga2d_Vec2 rhs);
+double pga2d_vec3_right_contraction_vec2(pga2d_Vec3 lhs, pga2d_Vec2 rhs);
+double pga2d_vec3_scalar_product_vec2(pga2d_Vec3 lhs, pga2d_Vec2 rhs);
+pga2d_Line pga2d_vec3_commutator_vec2(pga2d_Vec3 lhs, pga2d_Vec2 rhs);
+pga2d_Motor pga2d_vec3_geometric_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_vec3_anti_geometric_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);
+double pga2d_vec3_dot_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_vec3_wedge_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);
+double pga2d_vec3_left_contraction_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);
+double pga2d_vec3_right_contraction_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);
+double pga2d_vec3_scalar_product_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_vec3_commutator_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);
+double pga2d_vec3_anti_dot_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);
+pga2d_Motor pga2d_vec3_geometric_point2(pga2d_Vec3 lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_vec3_anti_geometric_point2(pga2d_Vec3 lhs, pga2d_Point2 rhs);
+double pga2d_vec3_dot_point2(pga2d_Vec3 lhs, pga2d_Point2 rhs);
+pga2d_Line pga2d_vec3_wedge_point2(pga2d_Vec3 lhs, pga2d_Point2 rhs);
+double pga2d_vec3_left_contraction_point2(pga2d_Vec3 lhs, pga2d_Point2 rhs);
+double pga2d_vec3_right_contraction_point2(pga2d_Vec3 lhs, pga2d_Point2 rhs);
+double pga2d_vec3_scalar_product_point2(pga2d_Vec3 lhs, pga2d_Point2 rhs);
+pga2d_Line pga2d_vec3_commutator_point2(pga2d_Vec3 lhs, pga2d_Point2 rhs);
+double pga2d_vec3_anti_dot_point2(pga2d_Vec3 lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_vec3_geometric_line(pga2d_Vec3 lhs, pga2d_Line rhs);
+pga2d_Motor pga2d_vec3_anti_geometric_line(pga2d_Vec3 lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_vec3_dot_line(pga2d_Vec3 lhs, pga2d_Line rhs);
+double pga2d_vec3_wedge_line(pga2d_Vec3 lhs, pga2d_Line rhs);
+double pga2d_vec3_anti_wedge_line(pga2d_Vec3 lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_vec3_left_contraction_line(pga2d_Vec3 lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_vec3_commutator_line(pga2d_Vec3 lhs, pga2d_Line rhs);
+pga2d_Line pga2d_vec3_anti_dot_line(pga2d_Vec3 lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_vec3_geometric_translator(pga2d_Vec3 lhs, pga2d_Translator rhs);
+pga2d_Motor pga2d_vec3_anti_geometric_translator(pga2d_Vec3 lhs, pga2d_Translator rhs);
+pga2d_Vec3 pga2d_vec3_dot_translator(pga2d_Vec3 lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_vec3_wedge_translator(pga2d_Vec3 lhs, pga2d_Translator rhs);
+double pga2d_vec3_anti_wedge_translator(pga2d_Vec3 lhs, pga2d_Translator rhs);
+double pga2d_vec3_left_contraction_translator(pga2d_Vec3 lhs, pga2d_Translator rhs);
+pga2d_Vec3 pga2d_vec3_right_contraction_translator(pga2d_Vec3 lhs, pga2d_Translator rhs);
+double pga2d_vec3_commutator_translator(pga2d_Vec3 lhs, pga2d_Translator rhs);
+pga2d_Line pga2d_vec3_anti_dot_translator(pga2d_Vec3 lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_vec3_geometric_rotor(pga2d_Vec3 lhs, pga2d_Rotor rhs);
+pga2d_Rotor pga2d_vec3_anti_geometric_rotor(pga2d_Vec3 lhs, pga2d_Rotor rhs);
+pga2d_Vec3 pga2d_vec3_dot_rotor(pga2d_Vec3 lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_vec3_wedge_rotor(pga2d_Vec3 lhs, pga2d_Rotor rhs);
+double pga2d_vec3_anti_wedge_rotor(pga2d_Vec3 lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_vec3_left_contraction_rotor(pga2d_Vec3 lhs, pga2d_Rotor rhs);
+pga2d_Vec3 pga2d_vec3_right_contraction_rotor(pga2d_Vec3 lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_vec3_commutator_rotor(pga2d_Vec3 lhs, pga2d_Rotor rhs);
+double pga2d_vec3_anti_dot_rotor(pga2d_Vec3 lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_vec3_geometric_motor(pga2d_Vec3 lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_vec3_anti_geometric_motor(pga2d_Vec3 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_vec3_dot_motor(pga2d_Vec3 lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_vec3_wedge_motor(pga2d_Vec3 lhs, pga2d_Motor rhs);
+double pga2d_vec3_anti_wedge_motor(pga2d_Vec3 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_vec3_left_contraction_motor(pga2d_Vec3 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_vec3_right_contraction_motor(pga2d_Vec3 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_vec3_commutator_motor(pga2d_Vec3 lhs, pga2d_Motor rhs);
+pga2d_Line pga2d_vec3_anti_dot_motor(pga2d_Vec3 lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_vec3_geometric_multivector(pga2d_Vec3 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec3_anti_geometric_multivector(pga2d_Vec3 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec3_dot_multivector(pga2d_Vec3 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec3_wedge_multivector(pga2d_Vec3 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec3_anti_wedge_multivector(pga2d_Vec3 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec3_left_contraction_multivector(pga2d_Vec3 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec3_right_contraction_multivector(pga2d_Vec3 lhs, pga2d_Multivector rhs);
+double pga2d_vec3_scalar_product_multivector(pga2d_Vec3 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec3_commutator_multivector(pga2d_Vec3 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_vec3_anti_dot_multivector(pga2d_Vec3 lhs, pga2d_Multivector rhs);
+pga2d_Line pga2d_point2_rcompl(pga2d_Point2 value);
+pga2d_Line pga2d_point2_lcompl(pga2d_Point2 value);
+pga2d_Line pga2d_point2_dual(pga2d_Point2 value);
+pga2d_Vec2 pga2d_point2_bulk(pga2d_Point2 value);
+double pga2d_point2_weight(pga2d_Point2 value);
+pga2d_Line pga2d_point2_bulk_dual(pga2d_Point2 value);
+double pga2d_point2_weight_dual(pga2d_Point2 value);
+pga2d_Motor pga2d_point2_geometric_vec2(pga2d_Point2 lhs, pga2d_Vec2 rhs);
+pga2d_Vec2 pga2d_point2_anti_geometric_vec2(pga2d_Point2 lhs, pga2d_Vec2 rhs);
+double pga2d_point2_dot_vec2(pga2d_Point2 lhs, pga2d_Vec2 rhs);
+pga2d_Line pga2d_point2_wedge_vec2(pga2d_Point2 lhs, pga2d_Vec2 rhs);
+double pga2d_point2_left_contraction_vec2(pga2d_Point2 lhs, pga2d_Vec2 rhs);
+double pga2d_point2_right_contraction_vec2(pga2d_Point2 lhs, pga2d_Vec2 rhs);
+double pga2d_point2_scalar_product_vec2(pga2d_Point2 lhs, pga2d_Vec2 rhs);
+pga2d_Line pga2d_point2_commutator_vec2(pga2d_Point2 lhs, pga2d_Vec2 rhs);
+pga2d_Motor pga2d_point2_geometric_vec3(pga2d_Point2 lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_point2_anti_geometric_vec3(pga2d_Point2 lhs, pga2d_Vec3 rhs);
+double pga2d_point2_dot_vec3(pga2d_Point2 lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_point2_wedge_vec3(pga2d_Point2 lhs, pga2d_Vec3 rhs);
+double pga2d_point2_left_contraction_vec3(pga2d_Point2 lhs, pga2d_Vec3 rhs);
+double pga2d_point2_right_contraction_vec3(pga2d_Point2 lhs, pga2d_Vec3 rhs);
+double pga2d_point2_scalar_product_vec3(pga2d_Point2 lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_point2_commutator_vec3(pga2d_Point2 lhs, pga2d_Vec3 rhs);
+double pga2d_point2_anti_dot_vec3(pga2d_Point2 lhs, pga2d_Vec3 rhs);
+pga2d_Motor pga2d_point2_geometric_point2(pga2d_Point2 lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_point2_anti_geometric_point2(pga2d_Point2 lhs, pga2d_Point2 rhs);
+double pga2d_point2_dot_point2(pga2d_Point2 lhs, pga2d_Point2 rhs);
+pga2d_Line pga2d_point2_wedge_point2(pga2d_Point2 lhs, pga2d_Point2 rhs);
+double pga2d_point2_left_contraction_point2(pga2d_Point2 lhs, pga2d_Point2 rhs);
+double pga2d_point2_right_contraction_point2(pga2d_Point2 lhs, pga2d_Point2 rhs);
+double pga2d_point2_scalar_product_point2(pga2d_Point2 lhs, pga2d_Point2 rhs);
+pga2d_Line pga2d_point2_commutator_point2(pga2d_Point2 lhs, pga2d_Point2 rhs);
+double pga2d_point2_anti_dot_point2(pga2d_Point2 lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_point2_geometric_line(pga2d_Point2 lhs, pga2d_Line rhs);
+pga2d_Motor pga2d_point2_anti_geometric_line(pga2d_Point2 lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_point2_dot_line(pga2d_Point2 lhs, pga2d_Line rhs);
+double pga2d_point2_wedge_line(pga2d_Point2 lhs, pga2d_Line rhs);
+double pga2d_point2_anti_wedge_line(pga2d_Point2 lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_point2_left_contraction_line(pga2d_Point2 lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_point2_commutator_line(pga2d_Point2 lhs, pga2d_Line rhs);
+pga2d_Line pga2d_point2_anti_dot_line(pga2d_Point2 lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_point2_geometric_translator(pga2d_Point2 lhs, pga2d_Translator rhs);
+pga2d_Motor pga2d_point2_anti_geometric_translator(pga2d_Point2 lhs, pga2d_Translator rhs);
+pga2d_Vec3 pga2d_point2_dot_translator(pga2d_Point2 lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_point2_wedge_translator(pga2d_Point2 lhs, pga2d_Translator rhs);
+double pga2d_point2_anti_wedge_translator(pga2d_Point2 lhs, pga2d_Translator rhs);
+double pga2d_point2_left_contraction_translator(pga2d_Point2 lhs, pga2d_Translator rhs);
+pga2d_Vec3 pga2d_point2_right_contraction_translator(pga2d_Point2 lhs, pga2d_Translator rhs);
+double pga2d_point2_commutator_translator(pga2d_Point2 lhs, pga2d_Translator rhs);
+pga2d_Line pga2d_point2_anti_dot_translator(pga2d_Point2 lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_point2_geometric_rotor(pga2d_Point2 lhs, pga2d_Rotor rhs);
+pga2d_Rotor pga2d_point2_anti_geometric_rotor(pga2d_Point2 lhs, pga2d_Rotor rhs);
+pga2d_Vec3 pga2d_point2_dot_rotor(pga2d_Point2 lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_point2_wedge_rotor(pga2d_Point2 lhs, pga2d_Rotor rhs);
+double pga2d_point2_anti_wedge_rotor(pga2d_Point2 lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_point2_left_contraction_rotor(pga2d_Point2 lhs, pga2d_Rotor rhs);
+pga2d_Vec3 pga2d_point2_right_contraction_rotor(pga2d_Point2 lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_point2_commutator_rotor(pga2d_Point2 lhs, pga2d_Rotor rhs);
+double pga2d_point2_anti_dot_rotor(pga2d_Point2 lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_point2_geometric_motor(pga2d_Point2 lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_point2_anti_geometric_motor(pga2d_Point2 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_point2_dot_motor(pga2d_Point2 lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_point2_wedge_motor(pga2d_Point2 lhs, pga2d_Motor rhs);
+double pga2d_point2_anti_wedge_motor(pga2d_Point2 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_point2_left_contraction_motor(pga2d_Point2 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_point2_right_contraction_motor(pga2d_Point2 lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_point2_commutator_motor(pga2d_Point2 lhs, pga2d_Motor rhs);
+pga2d_Line pga2d_point2_anti_dot_motor(pga2d_Point2 lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_point2_geometric_multivector(pga2d_Point2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_point2_anti_geometric_multivector(pga2d_Point2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_point2_dot_multivector(pga2d_Point2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_point2_wedge_multivector(pga2d_Point2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_point2_anti_wedge_multivector(pga2d_Point2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_point2_left_contraction_multivector(pga2d_Point2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_point2_right_contraction_multivector(pga2d_Point2 lhs, pga2d_Multivector rhs);
+double pga2d_point2_scalar_product_multivector(pga2d_Point2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_point2_commutator_multivector(pga2d_Point2 lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_point2_anti_dot_multivector(pga2d_Point2 lhs, pga2d_Multivector rhs);
+pga2d_Vec3 pga2d_line_rcompl(pga2d_Line value);
+pga2d_Vec3 pga2d_line_lcompl(pga2d_Line value);
+pga2d_Line pga2d_line_rev(pga2d_Line value);
+pga2d_Line pga2d_line_arev(pga2d_Line value);
+pga2d_Line pga2d_line_involute(pga2d_Line value);
+pga2d_Line pga2d_line_conjugate(pga2d_Line value);
+pga2d_Vec3 pga2d_line_dual(pga2d_Line value);
+double pga2d_line_bulk(pga2d_Line value);
+pga2d_Line pga2d_line_weight(pga2d_Line value);
+double pga2d_line_bulk_dual(pga2d_Line value);
+pga2d_Vec2 pga2d_line_weight_dual(pga2d_Line value);
+pga2d_Multivector pga2d_line_geometric_vec2(pga2d_Line lhs, pga2d_Vec2 rhs);
+pga2d_Rotor pga2d_line_anti_geometric_vec2(pga2d_Line lhs, pga2d_Vec2 rhs);
+pga2d_Vec3 pga2d_line_dot_vec2(pga2d_Line lhs, pga2d_Vec2 rhs);
+double pga2d_line_wedge_vec2(pga2d_Line lhs, pga2d_Vec2 rhs);
+double pga2d_line_anti_wedge_vec2(pga2d_Line lhs, pga2d_Vec2 rhs);
+pga2d_Vec3 pga2d_line_right_contraction_vec2(pga2d_Line lhs, pga2d_Vec2 rhs);
+pga2d_Vec3 pga2d_line_commutator_vec2(pga2d_Line lhs, pga2d_Vec2 rhs);
+double pga2d_line_anti_dot_vec2(pga2d_Line lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_line_geometric_vec3(pga2d_Line lhs, pga2d_Vec3 rhs);
+pga2d_Motor pga2d_line_anti_geometric_vec3(pga2d_Line lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_line_dot_vec3(pga2d_Line lhs, pga2d_Vec3 rhs);
+double pga2d_line_wedge_vec3(pga2d_Line lhs, pga2d_Vec3 rhs);
+double pga2d_line_anti_wedge_vec3(pga2d_Line lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_line_right_contraction_vec3(pga2d_Line lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_line_commutator_vec3(pga2d_Line lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_line_anti_dot_vec3(pga2d_Line lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_line_geometric_point2(pga2d_Line lhs, pga2d_Point2 rhs);
+pga2d_Motor pga2d_line_anti_geometric_point2(pga2d_Line lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_line_dot_point2(pga2d_Line lhs, pga2d_Point2 rhs);
+double pga2d_line_wedge_point2(pga2d_Line lhs, pga2d_Point2 rhs);
+double pga2d_line_anti_wedge_point2(pga2d_Line lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_line_right_contraction_point2(pga2d_Line lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_line_commutator_point2(pga2d_Line lhs, pga2d_Point2 rhs);
+pga2d_Line pga2d_line_anti_dot_point2(pga2d_Line lhs, pga2d_Point2 rhs);
+pga2d_Translator pga2d_line_geometric_line(pga2d_Line lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_line_anti_geometric_line(pga2d_Line lhs, pga2d_Line rhs);
+double pga2d_line_dot_line(pga2d_Line lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_line_anti_wedge_line(pga2d_Line lhs, pga2d_Line rhs);
+double pga2d_line_left_contraction_line(pga2d_Line lhs, pga2d_Line rhs);
+double pga2d_line_right_contraction_line(pga2d_Line lhs, pga2d_Line rhs);
+double pga2d_line_scalar_product_line(pga2d_Line lhs, pga2d_Line rhs);
+pga2d_Line pga2d_line_commutator_line(pga2d_Line lhs, pga2d_Line rhs);
+double pga2d_line_anti_dot_line(pga2d_Line lhs, pga2d_Line rhs);
+pga2d_Line pga2d_line_geometric_translator(pga2d_Line lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_line_anti_geometric_translator(pga2d_Line lhs, pga2d_Translator rhs);
+pga2d_Line pga2d_line_dot_translator(pga2d_Line lhs, pga2d_Translator rhs);
+pga2d_Line pga2d_line_wedge_translator(pga2d_Line lhs, pga2d_Translator rhs);
+pga2d_Vec3 pga2d_line_anti_wedge_translator(pga2d_Line lhs, pga2d_Translator rhs);
+pga2d_Line pga2d_line_right_contraction_translator(pga2d_Line lhs, pga2d_Translator rhs);
+pga2d_Line pga2d_line_commutator_translator(pga2d_Line lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_line_anti_dot_translator(pga2d_Line lhs, pga2d_Translator rhs);
+pga2d_Motor pga2d_line_geometric_rotor(pga2d_Line lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_line_anti_geometric_rotor(pga2d_Line lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_line_dot_rotor(pga2d_Line lhs, pga2d_Rotor rhs);
+pga2d_Line pga2d_line_wedge_rotor(pga2d_Line lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_line_anti_wedge_rotor(pga2d_Line lhs, pga2d_Rotor rhs);
+double pga2d_line_left_contraction_rotor(pga2d_Line lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_line_right_contraction_rotor(pga2d_Line lhs, pga2d_Rotor rhs);
+double pga2d_line_scalar_product_rotor(pga2d_Line lhs, pga2d_Rotor rhs);
+pga2d_Line pga2d_line_commutator_rotor(pga2d_Line lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_line_anti_dot_rotor(pga2d_Line lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_line_geometric_motor(pga2d_Line lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_line_anti_geometric_motor(pga2d_Line lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_line_dot_motor(pga2d_Line lhs, pga2d_Motor rhs);
+pga2d_Line pga2d_line_wedge_motor(pga2d_Line lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_line_anti_wedge_motor(pga2d_Line lhs, pga2d_Motor rhs);
+double pga2d_line_left_contraction_motor(pga2d_Line lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_line_right_contraction_motor(pga2d_Line lhs, pga2d_Motor rhs);
+double pga2d_line_scalar_product_motor(pga2d_Line lhs, pga2d_Motor rhs);
+pga2d_Line pga2d_line_commutator_motor(pga2d_Line lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_line_anti_dot_motor(pga2d_Line lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_line_geometric_multivector(pga2d_Line lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_line_anti_geometric_multivector(pga2d_Line lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_line_dot_multivector(pga2d_Line lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_line_wedge_multivector(pga2d_Line lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_line_anti_wedge_multivector(pga2d_Line lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_line_left_contraction_multivector(pga2d_Line lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_line_right_contraction_multivector(pga2d_Line lhs, pga2d_Multivector rhs);
+double pga2d_line_scalar_product_multivector(pga2d_Line lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_line_commutator_multivector(pga2d_Line lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_line_anti_dot_multivector(pga2d_Line lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_translator_rcompl(pga2d_Translator value);
+pga2d_Multivector pga2d_translator_lcompl(pga2d_Translator value);
+pga2d_Translator pga2d_translator_rev(pga2d_Translator value);
+pga2d_Translator pga2d_translator_arev(pga2d_Translator value);
+pga2d_Translator pga2d_translator_involute(pga2d_Translator value);
+pga2d_Translator pga2d_translator_conjugate(pga2d_Translator value);
+pga2d_Multivector pga2d_translator_dual(pga2d_Translator value);
+double pga2d_translator_bulk(pga2d_Translator value);
+pga2d_Line pga2d_translator_weight(pga2d_Translator value);
+double pga2d_translator_bulk_dual(pga2d_Translator value);
+pga2d_Vec2 pga2d_translator_weight_dual(pga2d_Translator value);
+pga2d_Multivector pga2d_translator_geometric_vec2(pga2d_Translator lhs, pga2d_Vec2 rhs);
+pga2d_Rotor pga2d_translator_anti_geometric_vec2(pga2d_Translator lhs, pga2d_Vec2 rhs);
+pga2d_Vec3 pga2d_translator_dot_vec2(pga2d_Translator lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_translator_wedge_vec2(pga2d_Translator lhs, pga2d_Vec2 rhs);
+double pga2d_translator_anti_wedge_vec2(pga2d_Translator lhs, pga2d_Vec2 rhs);
+pga2d_Vec2 pga2d_translator_left_contraction_vec2(pga2d_Translator lhs, pga2d_Vec2 rhs);
+double pga2d_translator_right_contraction_vec2(pga2d_Translator lhs, pga2d_Vec2 rhs);
+double pga2d_translator_commutator_vec2(pga2d_Translator lhs, pga2d_Vec2 rhs);
+double pga2d_translator_anti_dot_vec2(pga2d_Translator lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_translator_geometric_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+pga2d_Motor pga2d_translator_anti_geometric_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_translator_dot_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_translator_wedge_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+double pga2d_translator_anti_wedge_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_translator_left_contraction_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+double pga2d_translator_right_contraction_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+double pga2d_translator_commutator_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_translator_anti_dot_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_translator_geometric_point2(pga2d_Translator lhs, pga2d_Point2 rhs);
+pga2d_Motor pga2d_translator_anti_geometric_point2(pga2d_Translator lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_translator_dot_point2(pga2d_Translator lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_translator_wedge_point2(pga2d_Translator lhs, pga2d_Point2 rhs);
+double pga2d_translator_anti_wedge_point2(pga2d_Translator lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_translator_left_contraction_point2(pga2d_Translator lhs, pga2d_Point2 rhs);
+double pga2d_translator_right_contraction_point2(pga2d_Translator lhs, pga2d_Point2 rhs);
+double pga2d_translator_commutator_point2(pga2d_Translator lhs, pga2d_Point2 rhs);
+pga2d_Line pga2d_translator_anti_dot_point2(pga2d_Translator lhs, pga2d_Point2 rhs);
+pga2d_Line pga2d_translator_geometric_line(pga2d_Translator lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_translator_anti_geometric_line(pga2d_Translator lhs, pga2d_Line rhs);
+pga2d_Line pga2d_translator_dot_line(pga2d_Translator lhs, pga2d_Line rhs);
+pga2d_Line pga2d_translator_wedge_line(pga2d_Translator lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_translator_anti_wedge_line(pga2d_Translator lhs, pga2d_Line rhs);
+pga2d_Line pga2d_translator_left_contraction_line(pga2d_Translator lhs, pga2d_Line rhs);
+pga2d_Line pga2d_translator_commutator_line(pga2d_Translator lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_translator_anti_dot_line(pga2d_Translator lhs, pga2d_Line rhs);
+pga2d_Translator pga2d_translator_geometric_translator(pga2d_Translator lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_translator_anti_geometric_translator(pga2d_Translator lhs, pga2d_Translator rhs);
+pga2d_Translator pga2d_translator_dot_translator(pga2d_Translator lhs, pga2d_Translator rhs);
+pga2d_Translator pga2d_translator_wedge_translator(pga2d_Translator lhs, pga2d_Translator rhs);
+double pga2d_translator_anti_wedge_translator(pga2d_Translator lhs, pga2d_Translator rhs);
+pga2d_Translator pga2d_translator_left_contraction_translator(pga2d_Translator lhs, pga2d_Translator rhs);
+pga2d_Translator pga2d_translator_right_contraction_translator(pga2d_Translator lhs, pga2d_Translator rhs);
+double pga2d_translator_scalar_product_translator(pga2d_Translator lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_translator_anti_dot_translator(pga2d_Translator lhs, pga2d_Translator rhs);
+pga2d_Motor pga2d_translator_geometric_rotor(pga2d_Translator lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_translator_anti_geometric_rotor(pga2d_Translator lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_translator_dot_rotor(pga2d_Translator lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_translator_wedge_rotor(pga2d_Translator lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_translator_anti_wedge_rotor(pga2d_Translator lhs, pga2d_Rotor rhs);
+pga2d_Rotor pga2d_translator_left_contraction_rotor(pga2d_Translator lhs, pga2d_Rotor rhs);
+pga2d_Translator pga2d_translator_right_contraction_rotor(pga2d_Translator lhs, pga2d_Rotor rhs);
+double pga2d_translator_scalar_product_rotor(pga2d_Translator lhs, pga2d_Rotor rhs);
+pga2d_Line pga2d_translator_commutator_rotor(pga2d_Translator lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_translator_anti_dot_rotor(pga2d_Translator lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_translator_geometric_motor(pga2d_Translator lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_translator_anti_geometric_motor(pga2d_Translator lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_translator_dot_motor(pga2d_Translator lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_translator_wedge_motor(pga2d_Translator lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_translator_anti_wedge_motor(pga2d_Translator lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_translator_left_contraction_motor(pga2d_Translator lhs, pga2d_Motor rhs);
+pga2d_Translator pga2d_translator_right_contraction_motor(pga2d_Translator lhs, pga2d_Motor rhs);
+double pga2d_translator_scalar_product_motor(pga2d_Translator lhs, pga2d_Motor rhs);
+pga2d_Line pga2d_translator_commutator_motor(pga2d_Translator lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_translator_anti_dot_motor(pga2d_Translator lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_translator_geometric_multivector(pga2d_Translator lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_translator_anti_geometric_multivector(pga2d_Translator lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_translator_dot_multivector(pga2d_Translator lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_translator_wedge_multivector(pga2d_Translator lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_translator_anti_wedge_multivector(pga2d_Translator lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_translator_left_contraction_multivector(pga2d_Translator lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_translator_right_contraction_multivector(pga2d_Translator lhs, pga2d_Multivector rhs);
+double pga2d_translator_scalar_product_multivector(pga2d_Translator lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_translator_commutator_multivector(pga2d_Translator lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_translator_anti_dot_multivector(pga2d_Translator lhs, pga2d_Multivector rhs);
+pga2d_Vec2 pga2d_translator_anti_sandwich_vec2(pga2d_Translator lhs, pga2d_Vec2 rhs);
+pga2d_Vec3 pga2d_translator_sandwich_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_translator_anti_sandwich_vec3(pga2d_Translator lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_translator_sandwich_line(pga2d_Translator lhs, pga2d_Line rhs);
+pga2d_Line pga2d_translator_anti_sandwich_line(pga2d_Translator lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_rotor_rcompl(pga2d_Rotor value);
+pga2d_Multivector pga2d_rotor_lcompl(pga2d_Rotor value);
+pga2d_Rotor pga2d_rotor_rev(pga2d_Rotor value);
+pga2d_Rotor pga2d_rotor_arev(pga2d_Rotor value);
+pga2d_Rotor pga2d_rotor_involute(pga2d_Rotor value);
+pga2d_Rotor pga2d_rotor_conjugate(pga2d_Rotor value);
+pga2d_Multivector pga2d_rotor_dual(pga2d_Rotor value);
+pga2d_Rotor pga2d_rotor_bulk(pga2d_Rotor value);
+pga2d_Multivector pga2d_rotor_bulk_dual(pga2d_Rotor value);
+pga2d_Vec2 pga2d_rotor_geometric_vec2(pga2d_Rotor lhs, pga2d_Vec2 rhs);
+pga2d_Vec2 pga2d_rotor_dot_vec2(pga2d_Rotor lhs, pga2d_Vec2 rhs);
+pga2d_Vec2 pga2d_rotor_wedge_vec2(pga2d_Rotor lhs, pga2d_Vec2 rhs);
+pga2d_Vec2 pga2d_rotor_left_contraction_vec2(pga2d_Rotor lhs, pga2d_Vec2 rhs);
+pga2d_Vec2 pga2d_rotor_right_contraction_vec2(pga2d_Rotor lhs, pga2d_Vec2 rhs);
+pga2d_Vec2 pga2d_rotor_commutator_vec2(pga2d_Rotor lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_rotor_geometric_vec3(pga2d_Rotor lhs, pga2d_Vec3 rhs);
+pga2d_Rotor pga2d_rotor_anti_geometric_vec3(pga2d_Rotor lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_rotor_dot_vec3(pga2d_Rotor lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_rotor_wedge_vec3(pga2d_Rotor lhs, pga2d_Vec3 rhs);
+double pga2d_rotor_anti_wedge_vec3(pga2d_Rotor lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_rotor_left_contraction_vec3(pga2d_Rotor lhs, pga2d_Vec3 rhs);
+pga2d_Vec2 pga2d_rotor_right_contraction_vec3(pga2d_Rotor lhs, pga2d_Vec3 rhs);
+pga2d_Vec2 pga2d_rotor_commutator_vec3(pga2d_Rotor lhs, pga2d_Vec3 rhs);
+double pga2d_rotor_anti_dot_vec3(pga2d_Rotor lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_rotor_geometric_point2(pga2d_Rotor lhs, pga2d_Point2 rhs);
+pga2d_Rotor pga2d_rotor_anti_geometric_point2(pga2d_Rotor lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_rotor_dot_point2(pga2d_Rotor lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_rotor_wedge_point2(pga2d_Rotor lhs, pga2d_Point2 rhs);
+double pga2d_rotor_anti_wedge_point2(pga2d_Rotor lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_rotor_left_contraction_point2(pga2d_Rotor lhs, pga2d_Point2 rhs);
+pga2d_Vec2 pga2d_rotor_right_contraction_point2(pga2d_Rotor lhs, pga2d_Point2 rhs);
+pga2d_Vec2 pga2d_rotor_commutator_point2(pga2d_Rotor lhs, pga2d_Point2 rhs);
+double pga2d_rotor_anti_dot_point2(pga2d_Rotor lhs, pga2d_Point2 rhs);
+pga2d_Motor pga2d_rotor_geometric_line(pga2d_Rotor lhs, pga2d_Line rhs);
+pga2d_Vec2 pga2d_rotor_anti_geometric_line(pga2d_Rotor lhs, pga2d_Line rhs);
+pga2d_Motor pga2d_rotor_dot_line(pga2d_Rotor lhs, pga2d_Line rhs);
+pga2d_Line pga2d_rotor_wedge_line(pga2d_Rotor lhs, pga2d_Line rhs);
+pga2d_Vec2 pga2d_rotor_anti_wedge_line(pga2d_Rotor lhs, pga2d_Line rhs);
+pga2d_Motor pga2d_rotor_left_contraction_line(pga2d_Rotor lhs, pga2d_Line rhs);
+double pga2d_rotor_right_contraction_line(pga2d_Rotor lhs, pga2d_Line rhs);
+double pga2d_rotor_scalar_product_line(pga2d_Rotor lhs, pga2d_Line rhs);
+pga2d_Line pga2d_rotor_commutator_line(pga2d_Rotor lhs, pga2d_Line rhs);
+pga2d_Vec2 pga2d_rotor_anti_dot_line(pga2d_Rotor lhs, pga2d_Line rhs);
+pga2d_Motor pga2d_rotor_geometric_translator(pga2d_Rotor lhs, pga2d_Translator rhs);
+pga2d_Vec2 pga2d_rotor_anti_geometric_translator(pga2d_Rotor lhs, pga2d_Translator rhs);
+pga2d_Motor pga2d_rotor_dot_translator(pga2d_Rotor lhs, pga2d_Translator rhs);
+pga2d_Motor pga2d_rotor_wedge_translator(pga2d_Rotor lhs, pga2d_Translator rhs);
+pga2d_Vec2 pga2d_rotor_anti_wedge_translator(pga2d_Rotor lhs, pga2d_Translator rhs);
+pga2d_Translator pga2d_rotor_left_contraction_translator(pga2d_Rotor lhs, pga2d_Translator rhs);
+pga2d_Rotor pga2d_rotor_right_contraction_translator(pga2d_Rotor lhs, pga2d_Translator rhs);
+double pga2d_rotor_scalar_product_translator(pga2d_Rotor lhs, pga2d_Translator rhs);
+pga2d_Line pga2d_rotor_commutator_translator(pga2d_Rotor lhs, pga2d_Translator rhs);
+pga2d_Vec2 pga2d_rotor_anti_dot_translator(pga2d_Rotor lhs, pga2d_Translator rhs);
+pga2d_Rotor pga2d_rotor_geometric_rotor(pga2d_Rotor lhs, pga2d_Rotor rhs);
+pga2d_Rotor pga2d_rotor_dot_rotor(pga2d_Rotor lhs, pga2d_Rotor rhs);
+pga2d_Rotor pga2d_rotor_wedge_rotor(pga2d_Rotor lhs, pga2d_Rotor rhs);
+pga2d_Rotor pga2d_rotor_left_contraction_rotor(pga2d_Rotor lhs, pga2d_Rotor rhs);
+pga2d_Rotor pga2d_rotor_right_contraction_rotor(pga2d_Rotor lhs, pga2d_Rotor rhs);
+double pga2d_rotor_scalar_product_rotor(pga2d_Rotor lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_rotor_geometric_motor(pga2d_Rotor lhs, pga2d_Motor rhs);
+pga2d_Vec2 pga2d_rotor_anti_geometric_motor(pga2d_Rotor lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_rotor_dot_motor(pga2d_Rotor lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_rotor_wedge_motor(pga2d_Rotor lhs, pga2d_Motor rhs);
+pga2d_Vec2 pga2d_rotor_anti_wedge_motor(pga2d_Rotor lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_rotor_left_contraction_motor(pga2d_Rotor lhs, pga2d_Motor rhs);
+pga2d_Rotor pga2d_rotor_right_contraction_motor(pga2d_Rotor lhs, pga2d_Motor rhs);
+double pga2d_rotor_scalar_product_motor(pga2d_Rotor lhs, pga2d_Motor rhs);
+pga2d_Line pga2d_rotor_commutator_motor(pga2d_Rotor lhs, pga2d_Motor rhs);
+pga2d_Vec2 pga2d_rotor_anti_dot_motor(pga2d_Rotor lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_rotor_geometric_multivector(pga2d_Rotor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_rotor_anti_geometric_multivector(pga2d_Rotor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_rotor_dot_multivector(pga2d_Rotor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_rotor_wedge_multivector(pga2d_Rotor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_rotor_anti_wedge_multivector(pga2d_Rotor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_rotor_left_contraction_multivector(pga2d_Rotor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_rotor_right_contraction_multivector(pga2d_Rotor lhs, pga2d_Multivector rhs);
+double pga2d_rotor_scalar_product_multivector(pga2d_Rotor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_rotor_commutator_multivector(pga2d_Rotor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_rotor_anti_dot_multivector(pga2d_Rotor lhs, pga2d_Multivector rhs);
+pga2d_Vec2 pga2d_rotor_sandwich_vec2(pga2d_Rotor lhs, pga2d_Vec2 rhs);
+pga2d_Vec3 pga2d_rotor_sandwich_vec3(pga2d_Rotor lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_rotor_sandwich_line(pga2d_Rotor lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_motor_rcompl(pga2d_Motor value);
+pga2d_Multivector pga2d_motor_lcompl(pga2d_Motor value);
+pga2d_Motor pga2d_motor_rev(pga2d_Motor value);
+pga2d_Motor pga2d_motor_arev(pga2d_Motor value);
+pga2d_Motor pga2d_motor_involute(pga2d_Motor value);
+pga2d_Motor pga2d_motor_conjugate(pga2d_Motor value);
+pga2d_Multivector pga2d_motor_dual(pga2d_Motor value);
+pga2d_Rotor pga2d_motor_bulk(pga2d_Motor value);
+pga2d_Line pga2d_motor_weight(pga2d_Motor value);
+pga2d_Multivector pga2d_motor_bulk_dual(pga2d_Motor value);
+pga2d_Vec2 pga2d_motor_weight_dual(pga2d_Motor value);
+pga2d_Multivector pga2d_motor_geometric_vec2(pga2d_Motor lhs, pga2d_Vec2 rhs);
+pga2d_Rotor pga2d_motor_anti_geometric_vec2(pga2d_Motor lhs, pga2d_Vec2 rhs);
+pga2d_Vec3 pga2d_motor_dot_vec2(pga2d_Motor lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_motor_wedge_vec2(pga2d_Motor lhs, pga2d_Vec2 rhs);
+double pga2d_motor_anti_wedge_vec2(pga2d_Motor lhs, pga2d_Vec2 rhs);
+pga2d_Vec2 pga2d_motor_left_contraction_vec2(pga2d_Motor lhs, pga2d_Vec2 rhs);
+pga2d_Vec3 pga2d_motor_right_contraction_vec2(pga2d_Motor lhs, pga2d_Vec2 rhs);
+pga2d_Vec3 pga2d_motor_commutator_vec2(pga2d_Motor lhs, pga2d_Vec2 rhs);
+double pga2d_motor_anti_dot_vec2(pga2d_Motor lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_motor_geometric_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+pga2d_Motor pga2d_motor_anti_geometric_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_motor_dot_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_motor_wedge_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+double pga2d_motor_anti_wedge_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_motor_left_contraction_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_motor_right_contraction_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_motor_commutator_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_motor_anti_dot_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_motor_geometric_point2(pga2d_Motor lhs, pga2d_Point2 rhs);
+pga2d_Motor pga2d_motor_anti_geometric_point2(pga2d_Motor lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_motor_dot_point2(pga2d_Motor lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_motor_wedge_point2(pga2d_Motor lhs, pga2d_Point2 rhs);
+double pga2d_motor_anti_wedge_point2(pga2d_Motor lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_motor_left_contraction_point2(pga2d_Motor lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_motor_right_contraction_point2(pga2d_Motor lhs, pga2d_Point2 rhs);
+pga2d_Vec3 pga2d_motor_commutator_point2(pga2d_Motor lhs, pga2d_Point2 rhs);
+pga2d_Line pga2d_motor_anti_dot_point2(pga2d_Motor lhs, pga2d_Point2 rhs);
+pga2d_Motor pga2d_motor_geometric_line(pga2d_Motor lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_motor_anti_geometric_line(pga2d_Motor lhs, pga2d_Line rhs);
+pga2d_Motor pga2d_motor_dot_line(pga2d_Motor lhs, pga2d_Line rhs);
+pga2d_Line pga2d_motor_wedge_line(pga2d_Motor lhs, pga2d_Line rhs);
+pga2d_Vec3 pga2d_motor_anti_wedge_line(pga2d_Motor lhs, pga2d_Line rhs);
+pga2d_Motor pga2d_motor_left_contraction_line(pga2d_Motor lhs, pga2d_Line rhs);
+double pga2d_motor_right_contraction_line(pga2d_Motor lhs, pga2d_Line rhs);
+double pga2d_motor_scalar_product_line(pga2d_Motor lhs, pga2d_Line rhs);
+pga2d_Line pga2d_motor_commutator_line(pga2d_Motor lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_motor_anti_dot_line(pga2d_Motor lhs, pga2d_Line rhs);
+pga2d_Motor pga2d_motor_geometric_translator(pga2d_Motor lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_motor_anti_geometric_translator(pga2d_Motor lhs, pga2d_Translator rhs);
+pga2d_Motor pga2d_motor_dot_translator(pga2d_Motor lhs, pga2d_Translator rhs);
+pga2d_Motor pga2d_motor_wedge_translator(pga2d_Motor lhs, pga2d_Translator rhs);
+pga2d_Vec3 pga2d_motor_anti_wedge_translator(pga2d_Motor lhs, pga2d_Translator rhs);
+pga2d_Translator pga2d_motor_left_contraction_translator(pga2d_Motor lhs, pga2d_Translator rhs);
+pga2d_Motor pga2d_motor_right_contraction_translator(pga2d_Motor lhs, pga2d_Translator rhs);
+double pga2d_motor_scalar_product_translator(pga2d_Motor lhs, pga2d_Translator rhs);
+pga2d_Line pga2d_motor_commutator_translator(pga2d_Motor lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_motor_anti_dot_translator(pga2d_Motor lhs, pga2d_Translator rhs);
+pga2d_Motor pga2d_motor_geometric_rotor(pga2d_Motor lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_motor_anti_geometric_rotor(pga2d_Motor lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_motor_dot_rotor(pga2d_Motor lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_motor_wedge_rotor(pga2d_Motor lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_motor_anti_wedge_rotor(pga2d_Motor lhs, pga2d_Rotor rhs);
+pga2d_Rotor pga2d_motor_left_contraction_rotor(pga2d_Motor lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_motor_right_contraction_rotor(pga2d_Motor lhs, pga2d_Rotor rhs);
+double pga2d_motor_scalar_product_rotor(pga2d_Motor lhs, pga2d_Rotor rhs);
+pga2d_Line pga2d_motor_commutator_rotor(pga2d_Motor lhs, pga2d_Rotor rhs);
+pga2d_Vec2 pga2d_motor_anti_dot_rotor(pga2d_Motor lhs, pga2d_Rotor rhs);
+pga2d_Motor pga2d_motor_geometric_motor(pga2d_Motor lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_motor_anti_geometric_motor(pga2d_Motor lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_motor_dot_motor(pga2d_Motor lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_motor_wedge_motor(pga2d_Motor lhs, pga2d_Motor rhs);
+pga2d_Vec3 pga2d_motor_anti_wedge_motor(pga2d_Motor lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_motor_left_contraction_motor(pga2d_Motor lhs, pga2d_Motor rhs);
+pga2d_Motor pga2d_motor_right_contraction_motor(pga2d_Motor lhs, pga2d_Motor rhs);
+double pga2d_motor_scalar_product_motor(pga2d_Motor lhs, pga2d_Motor rhs);
+pga2d_Line pga2d_motor_commutator_motor(pga2d_Motor lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_motor_anti_dot_motor(pga2d_Motor lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_motor_geometric_multivector(pga2d_Motor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_motor_anti_geometric_multivector(pga2d_Motor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_motor_dot_multivector(pga2d_Motor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_motor_wedge_multivector(pga2d_Motor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_motor_anti_wedge_multivector(pga2d_Motor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_motor_left_contraction_multivector(pga2d_Motor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_motor_right_contraction_multivector(pga2d_Motor lhs, pga2d_Multivector rhs);
+double pga2d_motor_scalar_product_multivector(pga2d_Motor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_motor_commutator_multivector(pga2d_Motor lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_motor_anti_dot_multivector(pga2d_Motor lhs, pga2d_Multivector rhs);
+pga2d_Vec2 pga2d_motor_anti_sandwich_vec2(pga2d_Motor lhs, pga2d_Vec2 rhs);
+pga2d_Vec3 pga2d_motor_sandwich_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+pga2d_Vec3 pga2d_motor_anti_sandwich_vec3(pga2d_Motor lhs, pga2d_Vec3 rhs);
+pga2d_Line pga2d_motor_sandwich_line(pga2d_Motor lhs, pga2d_Line rhs);
+pga2d_Line pga2d_motor_anti_sandwich_line(pga2d_Motor lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_multivector_rcompl(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_lcompl(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_rev(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_arev(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_involute(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_conjugate(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_dual(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_bulk(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_weight(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_bulk_dual(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_weight_dual(pga2d_Multivector value);
+pga2d_Multivector pga2d_multivector_geometric_vec2(pga2d_Multivector lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_multivector_anti_geometric_vec2(pga2d_Multivector lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_multivector_dot_vec2(pga2d_Multivector lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_multivector_wedge_vec2(pga2d_Multivector lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_multivector_anti_wedge_vec2(pga2d_Multivector lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_multivector_left_contraction_vec2(pga2d_Multivector lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_multivector_right_contraction_vec2(pga2d_Multivector lhs, pga2d_Vec2 rhs);
+double pga2d_multivector_scalar_product_vec2(pga2d_Multivector lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_multivector_commutator_vec2(pga2d_Multivector lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_multivector_anti_dot_vec2(pga2d_Multivector lhs, pga2d_Vec2 rhs);
+pga2d_Multivector pga2d_multivector_geometric_vec3(pga2d_Multivector lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_multivector_anti_geometric_vec3(pga2d_Multivector lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_multivector_dot_vec3(pga2d_Multivector lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_multivector_wedge_vec3(pga2d_Multivector lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_multivector_anti_wedge_vec3(pga2d_Multivector lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_multivector_left_contraction_vec3(pga2d_Multivector lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_multivector_right_contraction_vec3(pga2d_Multivector lhs, pga2d_Vec3 rhs);
+double pga2d_multivector_scalar_product_vec3(pga2d_Multivector lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_multivector_commutator_vec3(pga2d_Multivector lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_multivector_anti_dot_vec3(pga2d_Multivector lhs, pga2d_Vec3 rhs);
+pga2d_Multivector pga2d_multivector_geometric_point2(pga2d_Multivector lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_multivector_anti_geometric_point2(pga2d_Multivector lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_multivector_dot_point2(pga2d_Multivector lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_multivector_wedge_point2(pga2d_Multivector lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_multivector_anti_wedge_point2(pga2d_Multivector lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_multivector_left_contraction_point2(pga2d_Multivector lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_multivector_right_contraction_point2(pga2d_Multivector lhs, pga2d_Point2 rhs);
+double pga2d_multivector_scalar_product_point2(pga2d_Multivector lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_multivector_commutator_point2(pga2d_Multivector lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_multivector_anti_dot_point2(pga2d_Multivector lhs, pga2d_Point2 rhs);
+pga2d_Multivector pga2d_multivector_geometric_line(pga2d_Multivector lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_multivector_anti_geometric_line(pga2d_Multivector lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_multivector_dot_line(pga2d_Multivector lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_multivector_wedge_line(pga2d_Multivector lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_multivector_anti_wedge_line(pga2d_Multivector lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_multivector_left_contraction_line(pga2d_Multivector lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_multivector_right_contraction_line(pga2d_Multivector lhs, pga2d_Line rhs);
+double pga2d_multivector_scalar_product_line(pga2d_Multivector lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_multivector_commutator_line(pga2d_Multivector lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_multivector_anti_dot_line(pga2d_Multivector lhs, pga2d_Line rhs);
+pga2d_Multivector pga2d_multivector_geometric_translator(pga2d_Multivector lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_multivector_anti_geometric_translator(pga2d_Multivector lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_multivector_dot_translator(pga2d_Multivector lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_multivector_wedge_translator(pga2d_Multivector lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_multivector_anti_wedge_translator(pga2d_Multivector lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_multivector_left_contraction_translator(pga2d_Multivector lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_multivector_right_contraction_translator(pga2d_Multivector lhs, pga2d_Translator rhs);
+double pga2d_multivector_scalar_product_translator(pga2d_Multivector lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_multivector_commutator_translator(pga2d_Multivector lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_multivector_anti_dot_translator(pga2d_Multivector lhs, pga2d_Translator rhs);
+pga2d_Multivector pga2d_multivector_geometric_rotor(pga2d_Multivector lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_multivector_anti_geometric_rotor(pga2d_Multivector lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_multivector_dot_rotor(pga2d_Multivector lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_multivector_wedge_rotor(pga2d_Multivector lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_multivector_anti_wedge_rotor(pga2d_Multivector lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_multivector_left_contraction_rotor(pga2d_Multivector lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_multivector_right_contraction_rotor(pga2d_Multivector lhs, pga2d_Rotor rhs);
+double pga2d_multivector_scalar_product_rotor(pga2d_Multivector lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_multivector_commutator_rotor(pga2d_Multivector lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_multivector_anti_dot_rotor(pga2d_Multivector lhs, pga2d_Rotor rhs);
+pga2d_Multivector pga2d_multivector_geometric_motor(pga2d_Multivector lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_multivector_anti_geometric_motor(pga2d_Multivector lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_multivector_dot_motor(pga2d_Multivector lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_multivector_wedge_motor(pga2d_Multivector lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_multivector_anti_wedge_motor(pga2d_Multivector lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_multivector_left_contraction_motor(pga2d_Multivector lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_multivector_right_contraction_motor(pga2d_Multivector lhs, pga2d_Motor rhs);
+double pga2d_multivector_scalar_product_motor(pga2d_Multivector lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_multivector_commutator_motor(pga2d_Multivector lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_multivector_anti_dot_motor(pga2d_Multivector lhs, pga2d_Motor rhs);
+pga2d_Multivector pga2d_multivector_geometric_multivector(pga2d_Multivector lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_multivector_anti_geometric_multivector(pga2d_Multivector lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_multivector_dot_multivector(pga2d_Multivector lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_multivector_wedge_multivector(pga2d_Multivector lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_multivector_anti_wedge_multivector(pga2d_Multivector lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_multivector_left_contraction_multivector(pga2d_Multivector lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_multivector_right_contraction_multivector(pga2d_Multivector lhs, pga2d_Multivector rhs);
+double pga2d_multivector_scalar_product_multivector(pga2d_Multivector lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_multivector_commutator_multivector(pga2d_Multivector lhs, pga2d_Multivector rhs);
+pga2d_Multivector pga2d_multivector_anti_dot_multivector(pga2d_Multivector lhs, pga2d_Multivector rhs);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PGA2D_H */
@@ -0,0 +1,1921 @@
+//! # Point2
+//!
+//! ## Operations
+//! ```text
+//! Point2.geometric(Point2) -> Motor
+//! Point2.anti_geometric(Point2) -> Multivector
+//! Point2.dot(Point2) -> S
+//! Point2.wedge(Point2) -> Line
+//! Point2.left_contraction(Point2) -> S
+//! Point2.right_contraction(Point2) -> S
+//! Point2.scalar_product(Point2) -> S
+//! Point2.commutator(Point2) -> Line
+//! Point2.anti_dot(Point2) -> XYW
+//! Point2.geometric(Vec2) -> Motor
+//! Vec2.geometric(Point2) -> Motor
+//! Point2.anti_geometric(Vec2) -> Vec2
+//! Vec2.anti_geometric(Point2) -> Vec2
+//! Point2.dot(Vec2) -> S
+//! Vec2.dot(Point2) -> S
+//! Point2.wedge(Vec2) -> Line
+//! Vec2.wedge(Point2) -> Line
+//! Point2.left_contraction(Vec2) -> S
+//! Vec2.left_contraction(Point2) -> S
+//! Point2.right_contraction(Vec2) -> S
+//! Vec2.right_contraction(Point2) -> S
+//! Point2.scalar_product(Vec2) -> S
+//! Vec2.scalar_product(Point2) -> S
+//! Point2.commutator(Vec2) -> Line
+//! Vec2.commutator(Point2) -> Line
+//! Point2.geometric(Vec3) -> Motor
+//! Vec3.geometric(Point2) -> Motor
+//! Point2.anti_geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Point2) -> Multivector
+//! Point2.dot(Vec3) -> S
+//! Vec3.dot(Point2) -> S
+//! Point2.wedge(Vec3) -> Line
+//! Vec3.wedge(Point2) -> Line
+//! Point2.left_contraction(Vec3) -> S
+//! Vec3.left_contraction(Point2) -> S
+//! Point2.right_contraction(Vec3) -> S
+//! Vec3.right_contraction(Point2) -> S
+//! Point2.scalar_product(Vec3) -> S
+//! Vec3.scalar_product(Point2) -> S
+//! Point2.commutator(Vec3) -> Line
+//! Vec3.commutator(Point2) -> Line
+//! Point2.anti_dot(Vec3) -> XYW
+//! Vec3.anti_dot(Point2) -> XYW
+//! Point2.geometric(Line) -> Multivector
+//! Line.geometric(Point2) -> Multivector
+//! Point2.anti_geometric(Line) -> Motor
+//! Line.anti_geometric(Point2) -> Motor
+//! Point2.dot(Line) -> Vec3
+//! Line.dot(Point2) -> Vec3
+//! Point2.wedge(Line) -> XYW
+//! Line.wedge(Point2) -> XYW
+//! Point2.anti_wedge(Line) -> S
+//! Line.anti_wedge(Point2) -> S
+//! Point2.left_contraction(Line) -> Vec3
+//! Line.right_contraction(Point2) -> Vec3
+//! Point2.commutator(Line) -> Vec3
+//! Line.commutator(Point2) -> Vec3
+//! Point2.anti_dot(Line) -> Line
+//! Line.anti_dot(Point2) -> Line
+//! Point2.geometric(Translator) -> Multivector
+//! Translator.geometric(Point2) -> Multivector
+//! Point2.anti_geometric(Translator) -> Motor
+//! Translator.anti_geometric(Point2) -> Motor
+//! Point2.dot(Translator) -> Vec3
+//! Translator.dot(Point2) -> Vec3
+//! Point2.wedge(Translator) -> Multivector
+//! Translator.wedge(Point2) -> Multivector
+//! Point2.anti_wedge(Translator) -> S
+//! Translator.anti_wedge(Point2) -> S
+//! Point2.left_contraction(Translator) -> W
+//! Translator.left_contraction(Point2) -> Vec3
+//! Point2.right_contraction(Translator) -> Vec3
+//! Translator.right_contraction(Point2) -> W
+//! Point2.commutator(Translator) -> W
+//! Translator.commutator(Point2) -> W
+//! Point2.anti_dot(Translator) -> Line
+//! Translator.anti_dot(Point2) -> Line
+//! Point2.geometric(Rotor) -> Multivector
+//! Rotor.geometric(Point2) -> Multivector
+//! Point2.anti_geometric(Rotor) -> Rotor
+//! Rotor.anti_geometric(Point2) -> Rotor
+//! Point2.dot(Rotor) -> Vec3
+//! Rotor.dot(Point2) -> Vec3
+//! Point2.wedge(Rotor) -> Multivector
+//! Rotor.wedge(Point2) -> Multivector
+//! Point2.anti_wedge(Rotor) -> S
+//! Rotor.anti_wedge(Point2) -> S
+//! Point2.left_contraction(Rotor) -> Vec2
+//! Rotor.left_contraction(Point2) -> Vec3
+//! Point2.right_contraction(Rotor) -> Vec3
+//! Rotor.right_contraction(Point2) -> Vec2
+//! Point2.commutator(Rotor) -> Vec2
+//! Rotor.commutator(Point2) -> Vec2
+//! Point2.anti_dot(Rotor) -> XY
+//! Rotor.anti_dot(Point2) -> XY
+//! Point2.geometric(Motor) -> Multivector
+//! Motor.geometric(Point2) -> Multivector
+//! Point2.anti_geometric(Motor) -> Motor
+//! Motor.anti_geometric(Point2) -> Motor
+//! Point2.dot(Motor) -> Vec3
+//! Motor.dot(Point2) -> Vec3
+//! Point2.wedge(Motor) -> Multivector
+//! Motor.wedge(Point2) -> Multivector
+//! Point2.anti_wedge(Motor) -> S
+//! Motor.anti_wedge(Point2) -> S
+//! Point2.left_contraction(Motor) -> Vec3
+//! Motor.left_contraction(Point2) -> Vec3
+//! Point2.right_contraction(Motor) -> Vec3
+//! Motor.right_contraction(Point2) -> Vec3
+//! Point2.commutator(Motor) -> Vec3
+//! Motor.commutator(Point2) -> Vec3
+//! Point2.anti_dot(Motor) -> Line
+//! Motor.anti_dot(Point2) -> Line
+//! Point2.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Point2) -> Multivector
+//! Point2.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Point2) -> Multivector
+//! Point2.dot(Multivector) -> Multivector
+//! Multivector.dot(Point2) -> Multivector
+//! Point2.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Point2) -> Multivector
+//! Point2.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Point2) -> Multivector
+//! Point2.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Point2) -> Multivector
+//! Point2.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Point2) -> Multivector
+//! Point2.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Point2) -> S
+//! Point2.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Point2) -> Multivector
+//! Point2.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Point2) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point2 {
+	pub x: X,
+	pub y: Y,
+	// w: W = 1
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Point2 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Point2 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Point2 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Point2 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.x.relative_eq(&other.x, epsilon, max_relative) && self.y.relative_eq(&other.y, epsilon, max_relative)
+	}
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Point2<f64>> for Point2 {
+	fn from(v: mint::Point2<f64>) -> Self {
+		Point2 { x: X(v.x), y: Y(v.y) }
+	}
+}
+
+#[cfg(feature = "mint")]
+impl From<Point2> for mint::Point2<f64> {
+	fn from(v: Point2) -> Self {
+		mint::Point2 { x: v.x.0, y: v.y.0 }
+	}
+}
+
+#[cfg(feature = "mint")]
+impl mint::IntoMint for Point2 {
+	type MintType = mint::Point2<f64>;
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Point2 {
+	type Output = Line;
+	fn rcompl(self) -> Self::Output {
+		Line {
+			dx: self.x.rcompl(),
+			dy: self.y.rcompl(),
+			m: XY(1.0),
+		}
+	}
+}
+
+impl std::ops::Not for Point2 {
+	type Output = Line;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Point2 {
+	type Output = Line;
+	fn lcompl(self) -> Self::Output {
+		Line {
+			dx: self.x.lcompl(),
+			dy: self.y.lcompl(),
+			m: XY(1.0),
+		}
+	}
+}
+
+// Omitted: Point2.rev() -> Vec3  (not a Point2)
+// Omitted: Point2.arev() -> Vec3  (not a Point2)
+// Omitted: Point2.involute() -> Vec3  (not a Point2)
+// Omitted: Point2.conjugate() -> Vec3  (not a Point2)
+
+impl Dual for Point2 {
+	type Output = Line;
+	fn dual(self) -> Self::Output {
+		Line {
+			dx: self.x.dual(),
+			dy: self.y.dual(),
+			m: XY(1.0),
+		}
+	}
+}
+
+impl Bulk for Point2 {
+	type Output = Vec2;
+	fn bulk(self) -> Self::Output {
+		Vec2 { x: self.x, y: self.y }
+	}
+}
+
+impl Weight for Point2 {
+	type Output = W;
+	fn weight(self) -> Self::Output {
+		W(1.0)
+	}
+}
+
+impl BulkDual for Point2 {
+	type Output = Line;
+	fn bulk_dual(self) -> Self::Output {
+		Line {
+			dx: self.x.bulk_dual(),
+			dy: self.y.bulk_dual(),
+			m: Default::default(),
+		}
+	}
+}
+
+impl WeightDual for Point2 {
+	type Output = XY;
+	fn weight_dual(self) -> Self::Output {
+		XY(1.0)
+	}
+}
+
+impl Square for Point2 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(self.x.geometric(self.x) + self.y.geometric(self.y)).0
+	}
+}
+
+impl Norm for Point2 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0 + 1.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		1.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		self
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Point2 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 2] {
+		[self.x.0, self.y.0]
+	}
+}
+
+impl From<[f64; 2]> for Point2 {
+	fn from(a: [f64; 2]) -> Self {
+		Point2 { x: X(a[0]), y: Y(a[1]) }
+	}
+}
+
+impl From<Point2> for [f64; 2] {
+	fn from(v: Point2) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Point2> for Multivector {
+	fn from(v: Point2) -> Self {
+		Multivector {
+			x: v.x,
+			y: v.y,
+			w: W(1.0),
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Point2 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Point2` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0 && mv.w.0 == 1.0 && mv.yw.0 == 0.0 && mv.wx.0 == 0.0 && mv.xy.0 == 0.0 && mv.xyw.0 == 0.0 {
+			Ok(Point2 { x: mv.x, y: mv.y })
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+#[cfg(feature = "glam")]
+impl From<Point2> for glam::DVec2 {
+	fn from(v: Point2) -> Self {
+		Self::new(v.x.0, v.y.0)
+	}
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec2> for Point2 {
+	fn from(v: glam::DVec2) -> Self {
+		Point2 { x: X(v.x), y: Y(v.y) }
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Point2> for nalgebra::Point2<f64> {
+	fn from(v: Point2) -> Self {
+		Self::new(v.x.0, v.y.0)
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Point2<f64>> for Point2 {
+	fn from(v: nalgebra::Point2<f64>) -> Self {
+		Point2 { x: X(v.x), y: Y(v.y) }
+	}
+}
+
+#[cfg(feature = "cgmath")]
+impl From<Point2> for cgmath::Point2<f64> {
+	fn from(v: Point2) -> Self {
+		Self::new(v.x.0, v.y.0)
+	}
+}
+
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Point2<f64>> for Point2 {
+	fn from(v: cgmath::Point2<f64>) -> Self {
+		Point2 { x: X(v.x), y: Y(v.y) }
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point2 OP Vec2:
+
+// Point2.geometric(Vec2) -> Motor
+impl Geometric<Vec2> for Point2 {
+	type Output = Motor;
+	fn geometric(self, rhs: Vec2) -> Self::Output {
+		// Motor {
+		//     s : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     yw: -YW(rhs.y.0),
+		//     wx: WX(rhs.x.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Motor {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			yw: -rhs.y.geometric(W(1.0)),
+			wx: -rhs.x.geometric(W(1.0)),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec2> for Point2 {
+	type Output = Motor;
+	fn mul(self, rhs: Vec2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point2.anti_geometric(Vec2) -> Vec2
+impl AntiGeometric<Vec2> for Point2 {
+	type Output = Vec2;
+	fn anti_geometric(self, rhs: Vec2) -> Self::Output {
+		// Vec2 {
+		//     x: X(rhs.y.0),
+		//     y: -Y(rhs.x.0),
+		// }
+		Vec2 {
+			x: -rhs.y.anti_geometric(W(1.0)),
+			y: -rhs.x.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Point2.dot(Vec2) -> S
+impl Dot<Vec2> for Point2 {
+	type Output = S;
+	fn dot(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.dot(rhs.x) + self.y.dot(rhs.y)
+	}
+}
+
+impl std::ops::BitOr<Vec2> for Point2 {
+	type Output = S;
+	fn bitor(self, rhs: Vec2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point2.wedge(Vec2) -> Line
+impl Wedge<Vec2> for Point2 {
+	type Output = Line;
+	fn wedge(self, rhs: Vec2) -> Self::Output {
+		// Line {
+		//     dx: -YW(rhs.y.0),
+		//     dy: WX(rhs.x.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: -rhs.y.wedge(W(1.0)),
+			dy: -rhs.x.wedge(W(1.0)),
+			m: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec2> for Point2 {
+	type Output = Line;
+	fn bitxor(self, rhs: Vec2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec2> for Point2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec2> for Point2 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point2.left_contraction(Vec2) -> S
+impl LeftContraction<Vec2> for Point2 {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y)
+	}
+}
+
+// Point2.right_contraction(Vec2) -> S
+impl RightContraction<Vec2> for Point2 {
+	type Output = S;
+	fn right_contraction(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y)
+	}
+}
+
+// Point2.scalar_product(Vec2) -> S
+impl ScalarProduct<Vec2> for Point2 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Point2.commutator(Vec2) -> Line
+impl Commutator<Vec2> for Point2 {
+	type Output = Line;
+	fn commutator(self, rhs: Vec2) -> Self::Output {
+		// Line {
+		//     dx: -YW(rhs.y.0),
+		//     dy: WX(rhs.x.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: -rhs.y.commutator(W(1.0)),
+			dy: -rhs.x.commutator(W(1.0)),
+			m: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+		}
+	}
+}
+
+impl AntiDot<Vec2> for Point2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point2 OP Vec3:
+
+// Point2.geometric(Vec3) -> Motor
+impl Geometric<Vec3> for Point2 {
+	type Output = Motor;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Motor {
+		//     s : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     yw: -YW(rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     wx: WX(rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Motor {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			yw: self.y.geometric(rhs.w) - rhs.y.geometric(W(1.0)),
+			wx: self.x.geometric(rhs.w) - rhs.x.geometric(W(1.0)),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Point2 {
+	type Output = Motor;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point2.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Point2 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(rhs.y.0) - X(self.y.0 * rhs.w.0),
+		//     y  : -Y(rhs.x.0) + Y(self.x.0 * rhs.w.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: -XYW(rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.anti_geometric(rhs.w) - rhs.y.anti_geometric(W(1.0)),
+			y: self.x.anti_geometric(rhs.w) - rhs.x.anti_geometric(W(1.0)),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: rhs.w.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Point2.dot(Vec3) -> S
+impl Dot<Vec3> for Point2 {
+	type Output = S;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.dot(rhs.x) + self.y.dot(rhs.y)
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Point2 {
+	type Output = S;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point2.wedge(Vec3) -> Line
+impl Wedge<Vec3> for Point2 {
+	type Output = Line;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     dx: -YW(rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     dy: WX(rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: self.y.wedge(rhs.w) - rhs.y.wedge(W(1.0)),
+			dy: self.x.wedge(rhs.w) - rhs.x.wedge(W(1.0)),
+			m: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Point2 {
+	type Output = Line;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for Point2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Point2 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point2.left_contraction(Vec3) -> S
+impl LeftContraction<Vec3> for Point2 {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y)
+	}
+}
+
+// Point2.right_contraction(Vec3) -> S
+impl RightContraction<Vec3> for Point2 {
+	type Output = S;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y)
+	}
+}
+
+// Point2.scalar_product(Vec3) -> S
+impl ScalarProduct<Vec3> for Point2 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Point2.commutator(Vec3) -> Line
+impl Commutator<Vec3> for Point2 {
+	type Output = Line;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     dx: -YW(rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     dy: WX(rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: self.y.commutator(rhs.w) - rhs.y.commutator(W(1.0)),
+			dy: self.x.commutator(rhs.w) - rhs.x.commutator(W(1.0)),
+			m: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+		}
+	}
+}
+
+// Point2.anti_dot(Vec3) -> XYW
+impl AntiDot<Vec3> for Point2 {
+	type Output = XYW;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// -XYW(rhs.w.0)
+		rhs.w.anti_dot(W(1.0))
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point2 OP Point2:
+
+// Point2.geometric(Point2) -> Motor
+impl Geometric<Point2> for Point2 {
+	type Output = Motor;
+	fn geometric(self, rhs: Point2) -> Self::Output {
+		// Motor {
+		//     s : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     yw: -YW(rhs.y.0) + YW(self.y.0),
+		//     wx: WX(rhs.x.0) - WX(self.x.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Motor {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			yw: self.y.geometric(W(1.0)) - rhs.y.geometric(W(1.0)),
+			wx: self.x.geometric(W(1.0)) - rhs.x.geometric(W(1.0)),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Point2> for Point2 {
+	type Output = Motor;
+	fn mul(self, rhs: Point2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point2.anti_geometric(Point2) -> Multivector
+impl AntiGeometric<Point2> for Point2 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(rhs.y.0) - X(self.y.0),
+		//     y  : -Y(rhs.x.0) + Y(self.x.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: -_e0.wedge(_e1).wedge(_e2),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.anti_geometric(W(1.0)) - rhs.y.anti_geometric(W(1.0)),
+			y: self.x.anti_geometric(W(1.0)) - rhs.x.anti_geometric(W(1.0)),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: -XYW(1.0),
+		}
+	}
+}
+
+// Point2.dot(Point2) -> S
+impl Dot<Point2> for Point2 {
+	type Output = S;
+	fn dot(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.dot(rhs.x) + self.y.dot(rhs.y)
+	}
+}
+
+impl std::ops::BitOr<Point2> for Point2 {
+	type Output = S;
+	fn bitor(self, rhs: Point2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point2.wedge(Point2) -> Line
+impl Wedge<Point2> for Point2 {
+	type Output = Line;
+	fn wedge(self, rhs: Point2) -> Self::Output {
+		// Line {
+		//     dx: -YW(rhs.y.0) + YW(self.y.0),
+		//     dy: WX(rhs.x.0) - WX(self.x.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: self.y.wedge(W(1.0)) - rhs.y.wedge(W(1.0)),
+			dy: self.x.wedge(W(1.0)) - rhs.x.wedge(W(1.0)),
+			m: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point2> for Point2 {
+	type Output = Line;
+	fn bitxor(self, rhs: Point2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point2> for Point2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point2> for Point2 {
+	type Output = Zero;
+	fn bitand(self, rhs: Point2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point2.left_contraction(Point2) -> S
+impl LeftContraction<Point2> for Point2 {
+	type Output = S;
+	fn left_contraction(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y)
+	}
+}
+
+// Point2.right_contraction(Point2) -> S
+impl RightContraction<Point2> for Point2 {
+	type Output = S;
+	fn right_contraction(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y)
+	}
+}
+
+// Point2.scalar_product(Point2) -> S
+impl ScalarProduct<Point2> for Point2 {
+	type Output = S;
+	fn scalar_product(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Point2.commutator(Point2) -> Line
+impl Commutator<Point2> for Point2 {
+	type Output = Line;
+	fn commutator(self, rhs: Point2) -> Self::Output {
+		// Line {
+		//     dx: -YW(rhs.y.0) + YW(self.y.0),
+		//     dy: WX(rhs.x.0) - WX(self.x.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: self.y.commutator(W(1.0)) - rhs.y.commutator(W(1.0)),
+			dy: self.x.commutator(W(1.0)) - rhs.x.commutator(W(1.0)),
+			m: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+		}
+	}
+}
+
+// Point2.anti_dot(Point2) -> XYW
+impl AntiDot<Point2> for Point2 {
+	type Output = XYW;
+	fn anti_dot(self, _rhs: Point2) -> Self::Output {
+		// -_e0.wedge(_e1).wedge(_e2)
+		-XYW(1.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point2 OP Line:
+
+// Point2.geometric(Line) -> Multivector
+impl Geometric<Line> for Point2 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.m.0),
+		//     y  : Y(self.x.0 * rhs.m.0),
+		//     w  : -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(rhs.m.0) + XYW(self.x.0 * rhs.dx.0) + XYW(self.y.0 * rhs.dy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.geometric(rhs.m),
+			y: self.x.geometric(rhs.m),
+			w: self.x.geometric(rhs.dy) + self.y.geometric(rhs.dx),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.geometric(rhs.dx) + self.y.geometric(rhs.dy) + rhs.m.geometric(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Point2 {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point2.anti_geometric(Line) -> Motor
+impl AntiGeometric<Line> for Point2 {
+	type Output = Motor;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Motor {
+		//     s : S(rhs.m.0) + S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0),
+		//     yw: YW(rhs.dy.0),
+		//     wx: -WX(rhs.dx.0),
+		//     xy: -XY(self.x.0 * rhs.dy.0) + XY(self.y.0 * rhs.dx.0),
+		// }
+		Motor {
+			s: self.x.anti_geometric(rhs.dx) + self.y.anti_geometric(rhs.dy) + rhs.m.anti_geometric(W(1.0)),
+			yw: -rhs.dy.anti_geometric(W(1.0)),
+			wx: -rhs.dx.anti_geometric(W(1.0)),
+			xy: self.x.anti_geometric(rhs.dy) + self.y.anti_geometric(rhs.dx),
+		}
+	}
+}
+
+// Point2.dot(Line) -> Vec3
+impl Dot<Line> for Point2 {
+	type Output = Vec3;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.m.0),
+		//     y: Y(self.x.0 * rhs.m.0),
+		//     w: -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		// }
+		Vec3 {
+			x: self.y.dot(rhs.m),
+			y: self.x.dot(rhs.m),
+			w: self.x.dot(rhs.dy) + self.y.dot(rhs.dx),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Point2 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point2.wedge(Line) -> XYW
+impl Wedge<Line> for Point2 {
+	type Output = XYW;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// XYW(rhs.m.0) + XYW(self.x.0 * rhs.dx.0) + XYW(self.y.0 * rhs.dy.0)
+		self.x.wedge(rhs.dx) + self.y.wedge(rhs.dy) + rhs.m.wedge(W(1.0))
+	}
+}
+
+impl std::ops::BitXor<Line> for Point2 {
+	type Output = XYW;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Point2.anti_wedge(Line) -> S
+impl AntiWedge<Line> for Point2 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// S(rhs.m.0) + S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0)
+		self.x.anti_wedge(rhs.dx) + self.y.anti_wedge(rhs.dy) + rhs.m.anti_wedge(W(1.0))
+	}
+}
+
+impl std::ops::BitAnd<Line> for Point2 {
+	type Output = S;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point2.left_contraction(Line) -> Vec3
+impl LeftContraction<Line> for Point2 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.m.0),
+		//     y: Y(self.x.0 * rhs.m.0),
+		//     w: -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		// }
+		Vec3 {
+			x: self.y.left_contraction(rhs.m),
+			y: self.x.left_contraction(rhs.m),
+			w: self.x.left_contraction(rhs.dy) + self.y.left_contraction(rhs.dx),
+		}
+	}
+}
+
+impl RightContraction<Line> for Point2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line> for Point2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point2.commutator(Line) -> Vec3
+impl Commutator<Line> for Point2 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.m.0),
+		//     y: Y(self.x.0 * rhs.m.0),
+		//     w: -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		// }
+		Vec3 {
+			x: self.y.commutator(rhs.m),
+			y: self.x.commutator(rhs.m),
+			w: self.x.commutator(rhs.dy) + self.y.commutator(rhs.dx),
+		}
+	}
+}
+
+// Point2.anti_dot(Line) -> Line
+impl AntiDot<Line> for Point2 {
+	type Output = Line;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: YW(rhs.dy.0),
+		//     dy: -WX(rhs.dx.0),
+		//     m : -XY(self.x.0 * rhs.dy.0) + XY(self.y.0 * rhs.dx.0),
+		// }
+		Line {
+			dx: -rhs.dy.anti_dot(W(1.0)),
+			dy: -rhs.dx.anti_dot(W(1.0)),
+			m: self.x.anti_dot(rhs.dy) + self.y.anti_dot(rhs.dx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point2 OP Translator:
+
+// Point2.geometric(Translator) -> Multivector
+impl Geometric<Translator> for Point2 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.s),
+			y: self.y.geometric(rhs.s),
+			w: self.x.geometric(rhs.wx) + self.y.geometric(rhs.yw) + rhs.s.geometric(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.geometric(rhs.yw) + self.y.geometric(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Point2 {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point2.anti_geometric(Translator) -> Motor
+impl AntiGeometric<Translator> for Point2 {
+	type Output = Motor;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Motor {
+		//     s : S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     yw: YW(rhs.wx.0),
+		//     wx: -WX(rhs.yw.0),
+		//     xy: -XY(rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		// }
+		Motor {
+			s: self.x.anti_geometric(rhs.yw) + self.y.anti_geometric(rhs.wx),
+			yw: -rhs.wx.anti_geometric(W(1.0)),
+			wx: -rhs.yw.anti_geometric(W(1.0)),
+			xy: self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw) + rhs.s.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Point2.dot(Translator) -> Vec3
+impl Dot<Translator> for Point2 {
+	type Output = Vec3;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		//     w: W(rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.x.dot(rhs.s),
+			y: self.y.dot(rhs.s),
+			w: self.x.dot(rhs.wx) + self.y.dot(rhs.yw) + rhs.s.dot(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Point2 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point2.wedge(Translator) -> Multivector
+impl Wedge<Translator> for Point2 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(rhs.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: rhs.s.wedge(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.wedge(rhs.yw) + self.y.wedge(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Point2 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Point2.anti_wedge(Translator) -> S
+impl AntiWedge<Translator> for Point2 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0)
+		self.x.anti_wedge(rhs.yw) + self.y.anti_wedge(rhs.wx)
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Point2 {
+	type Output = S;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point2.left_contraction(Translator) -> W
+impl LeftContraction<Translator> for Point2 {
+	type Output = W;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0)
+		self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw)
+	}
+}
+
+// Point2.right_contraction(Translator) -> Vec3
+impl RightContraction<Translator> for Point2 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		//     w: W(rhs.s.0),
+		// }
+		Vec3 {
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: W(1.0).right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for Point2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point2.commutator(Translator) -> W
+impl Commutator<Translator> for Point2 {
+	type Output = W;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0)
+		self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw)
+	}
+}
+
+// Point2.anti_dot(Translator) -> Line
+impl AntiDot<Translator> for Point2 {
+	type Output = Line;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Line {
+		//     dx: YW(rhs.wx.0),
+		//     dy: -WX(rhs.yw.0),
+		//     m : -XY(rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		// }
+		Line {
+			dx: -rhs.wx.anti_dot(W(1.0)),
+			dy: -rhs.yw.anti_dot(W(1.0)),
+			m: self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.yw) + rhs.s.anti_dot(W(1.0)),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point2 OP Rotor:
+
+// Point2.geometric(Rotor) -> Multivector
+impl Geometric<Rotor> for Point2 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(rhs.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+			w: rhs.s.geometric(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: rhs.xy.geometric(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Point2 {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point2.anti_geometric(Rotor) -> Rotor
+impl AntiGeometric<Rotor> for Point2 {
+	type Output = Rotor;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s : S(rhs.xy.0),
+		//     xy: -XY(rhs.s.0),
+		// }
+		Rotor {
+			s: rhs.xy.anti_geometric(W(1.0)),
+			xy: rhs.s.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Point2.dot(Rotor) -> Vec3
+impl Dot<Rotor> for Point2 {
+	type Output = Vec3;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w: W(rhs.s.0),
+		// }
+		Vec3 {
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
+			w: rhs.s.dot(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Point2 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point2.wedge(Rotor) -> Multivector
+impl Wedge<Rotor> for Point2 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(rhs.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: rhs.s.wedge(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: rhs.xy.wedge(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Point2 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Point2.anti_wedge(Rotor) -> S
+impl AntiWedge<Rotor> for Point2 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// S(rhs.xy.0)
+		rhs.xy.anti_wedge(W(1.0))
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Point2 {
+	type Output = S;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point2.left_contraction(Rotor) -> Vec2
+impl LeftContraction<Rotor> for Point2 {
+	type Output = Vec2;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		// }
+		Vec2 {
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Point2.right_contraction(Rotor) -> Vec3
+impl RightContraction<Rotor> for Point2 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		//     w: W(rhs.s.0),
+		// }
+		Vec3 {
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: W(1.0).right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for Point2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point2.commutator(Rotor) -> Vec2
+impl Commutator<Rotor> for Point2 {
+	type Output = Vec2;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		// }
+		Vec2 {
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+		}
+	}
+}
+
+// Point2.anti_dot(Rotor) -> XY
+impl AntiDot<Rotor> for Point2 {
+	type Output = XY;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// -XY(rhs.s.0)
+		rhs.s.anti_dot(W(1.0))
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point2 OP Motor:
+
+// Point2.geometric(Motor) -> Multivector
+impl Geometric<Motor> for Point2 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(rhs.xy.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+			w: self.x.geometric(rhs.wx) + self.y.geometric(rhs.yw) + rhs.s.geometric(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.geometric(rhs.yw) + self.y.geometric(rhs.wx) + rhs.xy.geometric(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor> for Point2 {
+	type Output = Multivector;
+	fn mul(self, rhs: Motor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point2.anti_geometric(Motor) -> Motor
+impl AntiGeometric<Motor> for Point2 {
+	type Output = Motor;
+	fn anti_geometric(self, rhs: Motor) -> Self::Output {
+		// Motor {
+		//     s : S(rhs.xy.0) + S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     yw: YW(rhs.wx.0),
+		//     wx: -WX(rhs.yw.0),
+		//     xy: -XY(rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		// }
+		Motor {
+			s: self.x.anti_geometric(rhs.yw) + self.y.anti_geometric(rhs.wx) + rhs.xy.anti_geometric(W(1.0)),
+			yw: -rhs.wx.anti_geometric(W(1.0)),
+			wx: -rhs.yw.anti_geometric(W(1.0)),
+			xy: self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw) + rhs.s.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Point2.dot(Motor) -> Vec3
+impl Dot<Motor> for Point2 {
+	type Output = Vec3;
+	fn dot(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w: W(rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
+			w: self.x.dot(rhs.wx) + self.y.dot(rhs.yw) + rhs.s.dot(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor> for Point2 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Motor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point2.wedge(Motor) -> Multivector
+impl Wedge<Motor> for Point2 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(rhs.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(rhs.xy.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: rhs.s.wedge(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.wedge(rhs.yw) + self.y.wedge(rhs.wx) + rhs.xy.wedge(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitXor<Motor> for Point2 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Motor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Point2.anti_wedge(Motor) -> S
+impl AntiWedge<Motor> for Point2 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Motor) -> Self::Output {
+		// S(rhs.xy.0) + S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0)
+		self.x.anti_wedge(rhs.yw) + self.y.anti_wedge(rhs.wx) + rhs.xy.anti_wedge(W(1.0))
+	}
+}
+
+impl std::ops::BitAnd<Motor> for Point2 {
+	type Output = S;
+	fn bitand(self, rhs: Motor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point2.left_contraction(Motor) -> Vec3
+impl LeftContraction<Motor> for Point2 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		//     w: -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+			w: self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw),
+		}
+	}
+}
+
+// Point2.right_contraction(Motor) -> Vec3
+impl RightContraction<Motor> for Point2 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		//     w: W(rhs.s.0),
+		// }
+		Vec3 {
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: W(1.0).right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Motor> for Point2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Motor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Point2.commutator(Motor) -> Vec3
+impl Commutator<Motor> for Point2 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		//     w: -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+			w: self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw),
+		}
+	}
+}
+
+// Point2.anti_dot(Motor) -> Line
+impl AntiDot<Motor> for Point2 {
+	type Output = Line;
+	fn anti_dot(self, rhs: Motor) -> Self::Output {
+		// Line {
+		//     dx: YW(rhs.wx.0),
+		//     dy: -WX(rhs.yw.0),
+		//     m : -XY(rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		// }
+		Line {
+			dx: -rhs.wx.anti_dot(W(1.0)),
+			dy: -rhs.yw.anti_dot(W(1.0)),
+			m: self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.yw) + rhs.s.anti_dot(W(1.0)),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Point2 OP Multivector:
+
+// Point2.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Point2 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : -YW(rhs.y.0) + YW(self.x.0 * rhs.xyw.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : WX(rhs.x.0) - WX(self.x.0 * rhs.w.0) + WX(self.y.0 * rhs.xyw.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(rhs.xy.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+			w: self.x.geometric(rhs.wx) + self.y.geometric(rhs.yw) + rhs.s.geometric(W(1.0)),
+			yw: self.x.geometric(rhs.xyw) + self.y.geometric(rhs.w) - rhs.y.geometric(W(1.0)),
+			wx: self.x.geometric(rhs.w) + self.y.geometric(rhs.xyw) - rhs.x.geometric(W(1.0)),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			xyw: self.x.geometric(rhs.yw) + self.y.geometric(rhs.wx) + rhs.xy.geometric(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Point2 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Point2.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Point2 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(rhs.xy.0) + S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     x  : X(rhs.y.0) + X(self.x.0 * rhs.xyw.0) - X(self.y.0 * rhs.w.0),
+		//     y  : -Y(rhs.x.0) + Y(self.x.0 * rhs.w.0) + Y(self.y.0 * rhs.xyw.0),
+		//     w  : W(rhs.xyw.0),
+		//     yw : YW(rhs.wx.0),
+		//     wx : -WX(rhs.yw.0),
+		//     xy : -XY(rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		//     xyw: -XYW(rhs.w.0),
+		// }
+		Multivector {
+			s: self.x.anti_geometric(rhs.yw) + self.y.anti_geometric(rhs.wx) + rhs.xy.anti_geometric(W(1.0)),
+			x: self.x.anti_geometric(rhs.xyw) + self.y.anti_geometric(rhs.w) - rhs.y.anti_geometric(W(1.0)),
+			y: self.x.anti_geometric(rhs.w) + self.y.anti_geometric(rhs.xyw) - rhs.x.anti_geometric(W(1.0)),
+			w: rhs.xyw.anti_geometric(W(1.0)),
+			yw: -rhs.wx.anti_geometric(W(1.0)),
+			wx: -rhs.yw.anti_geometric(W(1.0)),
+			xy: self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw) + rhs.s.anti_geometric(W(1.0)),
+			xyw: rhs.w.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Point2.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Point2 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.x.0 * rhs.xyw.0),
+		//     wx : WX(self.y.0 * rhs.xyw.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y),
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
+			w: self.x.dot(rhs.wx) + self.y.dot(rhs.yw) + rhs.s.dot(W(1.0)),
+			yw: self.x.dot(rhs.xyw),
+			wx: self.y.dot(rhs.xyw),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Point2 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Point2.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Point2 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(rhs.s.0),
+		//     yw : -YW(rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : WX(rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(rhs.xy.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: rhs.s.wedge(W(1.0)),
+			yw: self.y.wedge(rhs.w) - rhs.y.wedge(W(1.0)),
+			wx: self.x.wedge(rhs.w) - rhs.x.wedge(W(1.0)),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			xyw: self.x.wedge(rhs.yw) + self.y.wedge(rhs.wx) + rhs.xy.wedge(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Point2 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Point2.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Point2 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(rhs.xy.0) + S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     x  : X(self.x.0 * rhs.xyw.0),
+		//     y  : Y(self.y.0 * rhs.xyw.0),
+		//     w  : W(rhs.xyw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.anti_wedge(rhs.yw) + self.y.anti_wedge(rhs.wx) + rhs.xy.anti_wedge(W(1.0)),
+			x: self.x.anti_wedge(rhs.xyw),
+			y: self.y.anti_wedge(rhs.xyw),
+			w: rhs.xyw.anti_wedge(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Point2 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Point2.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Point2 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : -X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.x.0 * rhs.xyw.0),
+		//     wx : WX(self.y.0 * rhs.xyw.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y),
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+			w: self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw),
+			yw: self.x.left_contraction(rhs.xyw),
+			wx: self.y.left_contraction(rhs.xyw),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Point2.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Point2 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(rhs.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y),
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: W(1.0).right_contraction(rhs.s),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Point2.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Point2 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Point2.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Point2 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : -YW(rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : WX(rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+			w: self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw),
+			yw: self.y.commutator(rhs.w) - rhs.y.commutator(W(1.0)),
+			wx: self.x.commutator(rhs.w) - rhs.x.commutator(W(1.0)),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Point2.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Point2 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.xyw.0),
+		//     y  : Y(self.y.0 * rhs.xyw.0),
+		//     w  : W(rhs.xyw.0),
+		//     yw : YW(rhs.wx.0),
+		//     wx : -WX(rhs.yw.0),
+		//     xy : -XY(rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		//     xyw: -XYW(rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.anti_dot(rhs.xyw),
+			y: self.y.anti_dot(rhs.xyw),
+			w: rhs.xyw.anti_dot(W(1.0)),
+			yw: -rhs.wx.anti_dot(W(1.0)),
+			wx: -rhs.yw.anti_dot(W(1.0)),
+			xy: self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.yw) + rhs.s.anti_dot(W(1.0)),
+			xyw: rhs.w.anti_dot(W(1.0)),
+		}
+	}
+}
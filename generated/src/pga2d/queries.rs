@@ -0,0 +1,40 @@
+//! Distances, angles, projections and intersections.
+//!
+//! Points are homogeneous (any non-zero `w`) unless otherwise noted,
+//! and planes and lines need not be normalized.
+
+use super::*;
+
+/// Signed distance from `point` to `line`.
+/// Positive to the left of the line, e.g. of `a ^ b` when walking from `a` to `b`.
+pub fn point_line_distance(point: Vec3, line: Line) -> f64 {
+	point.wedge(line).0 / (line.weight_norm() * point.w.0)
+}
+
+/// Signed angle in radians from `a` to `b`, in `[-π, π]`.
+/// Positive if `b` points counter-clockwise from `a`.
+pub fn line_line_angle(a: Line, b: Line) -> f64 {
+	let cos = a.anti_dot(b).0;
+	let sin = a.anti_wedge(b).w.0;
+	sin.atan2(cos)
+}
+
+/// The point on `line` closest to `point`, normalized to `w=±1`.
+pub fn project_point_on_line(point: Vec3, line: Line) -> Vec3 {
+	line.anti_wedge(point.wedge(line.weight_dual())).normalized()
+}
+
+/// The intersection of two lines.
+/// Not normalized: `w` is zero if the lines are parallel.
+pub fn meet_lines(a: Line, b: Line) -> Vec3 {
+	a.anti_wedge(b)
+}
+
+/// The line through `point` with the same direction as `line`.
+pub fn parallel_line_through(point: Vec3, line: Line) -> Line {
+	let horizon = Line {
+		m: XY(1.0),
+		..Default::default()
+	};
+	point.wedge(line.anti_wedge(horizon))
+}
@@ -5,18 +5,57 @@
 //! Rotor.geometric(Rotor) -> Rotor
 //! Rotor.dot(Rotor) -> Rotor
 //! Rotor.wedge(Rotor) -> Rotor
+//! Rotor.left_contraction(Rotor) -> Rotor
+//! Rotor.right_contraction(Rotor) -> Rotor
+//! Rotor.scalar_product(Rotor) -> S
 //! Rotor.geometric(Vec2) -> Vec2
 //! Vec2.geometric(Rotor) -> Vec2
 //! Rotor.dot(Vec2) -> Vec2
 //! Vec2.dot(Rotor) -> Vec2
 //! Rotor.wedge(Vec2) -> Vec2
 //! Vec2.wedge(Rotor) -> Vec2
+//! Rotor.left_contraction(Vec2) -> Vec2
+//! Vec2.left_contraction(Rotor) -> Vec2
+//! Rotor.right_contraction(Vec2) -> Vec2
+//! Vec2.right_contraction(Rotor) -> Vec2
+//! Rotor.commutator(Vec2) -> Vec2
+//! Vec2.commutator(Rotor) -> Vec2
+//! Rotor.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Rotor) -> Multivector
 //! Rotor.anti_geometric(Vec3) -> Rotor
 //! Vec3.anti_geometric(Rotor) -> Rotor
 //! Rotor.dot(Vec3) -> Vec3
 //! Vec3.dot(Rotor) -> Vec3
+//! Rotor.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Rotor) -> Multivector
 //! Rotor.anti_wedge(Vec3) -> S
 //! Vec3.anti_wedge(Rotor) -> S
+//! Rotor.left_contraction(Vec3) -> Vec3
+//! Vec3.left_contraction(Rotor) -> Vec2
+//! Rotor.right_contraction(Vec3) -> Vec2
+//! Vec3.right_contraction(Rotor) -> Vec3
+//! Rotor.commutator(Vec3) -> Vec2
+//! Vec3.commutator(Rotor) -> Vec2
+//! Rotor.anti_dot(Vec3) -> XY
+//! Vec3.anti_dot(Rotor) -> XY
+//! Rotor.geometric(Point2) -> Multivector
+//! Point2.geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Point2) -> Rotor
+//! Point2.anti_geometric(Rotor) -> Rotor
+//! Rotor.dot(Point2) -> Vec3
+//! Point2.dot(Rotor) -> Vec3
+//! Rotor.wedge(Point2) -> Multivector
+//! Point2.wedge(Rotor) -> Multivector
+//! Rotor.anti_wedge(Point2) -> S
+//! Point2.anti_wedge(Rotor) -> S
+//! Rotor.left_contraction(Point2) -> Vec3
+//! Point2.left_contraction(Rotor) -> Vec2
+//! Rotor.right_contraction(Point2) -> Vec2
+//! Point2.right_contraction(Rotor) -> Vec3
+//! Rotor.commutator(Point2) -> Vec2
+//! Point2.commutator(Rotor) -> Vec2
+//! Rotor.anti_dot(Point2) -> XY
+//! Point2.anti_dot(Rotor) -> XY
 //! Rotor.geometric(Line) -> Motor
 //! Line.geometric(Rotor) -> Motor
 //! Rotor.anti_geometric(Line) -> Vec2
@@ -27,6 +66,36 @@
 //! Line.wedge(Rotor) -> Line
 //! Rotor.anti_wedge(Line) -> Vec2
 //! Line.anti_wedge(Rotor) -> Vec2
+//! Rotor.left_contraction(Line) -> Motor
+//! Line.left_contraction(Rotor) -> S
+//! Rotor.right_contraction(Line) -> S
+//! Line.right_contraction(Rotor) -> Motor
+//! Rotor.scalar_product(Line) -> S
+//! Line.scalar_product(Rotor) -> S
+//! Rotor.commutator(Line) -> Line
+//! Line.commutator(Rotor) -> Line
+//! Rotor.anti_dot(Line) -> Vec2
+//! Line.anti_dot(Rotor) -> Vec2
+//! Rotor.geometric(Translator) -> Motor
+//! Translator.geometric(Rotor) -> Motor
+//! Rotor.anti_geometric(Translator) -> Vec2
+//! Translator.anti_geometric(Rotor) -> Vec2
+//! Rotor.dot(Translator) -> Motor
+//! Translator.dot(Rotor) -> Motor
+//! Rotor.wedge(Translator) -> Motor
+//! Translator.wedge(Rotor) -> Motor
+//! Rotor.anti_wedge(Translator) -> Vec2
+//! Translator.anti_wedge(Rotor) -> Vec2
+//! Rotor.left_contraction(Translator) -> Translator
+//! Translator.left_contraction(Rotor) -> Rotor
+//! Rotor.right_contraction(Translator) -> Rotor
+//! Translator.right_contraction(Rotor) -> Translator
+//! Rotor.scalar_product(Translator) -> S
+//! Translator.scalar_product(Rotor) -> S
+//! Rotor.commutator(Translator) -> Line
+//! Translator.commutator(Rotor) -> Line
+//! Rotor.anti_dot(Translator) -> Vec2
+//! Translator.anti_dot(Rotor) -> Vec2
 //! Rotor.geometric(Motor) -> Motor
 //! Motor.geometric(Rotor) -> Motor
 //! Rotor.anti_geometric(Motor) -> Vec2
@@ -37,19 +106,120 @@
 //! Motor.wedge(Rotor) -> Motor
 //! Rotor.anti_wedge(Motor) -> Vec2
 //! Motor.anti_wedge(Rotor) -> Vec2
+//! Rotor.left_contraction(Motor) -> Motor
+//! Motor.left_contraction(Rotor) -> Rotor
+//! Rotor.right_contraction(Motor) -> Rotor
+//! Motor.right_contraction(Rotor) -> Motor
+//! Rotor.scalar_product(Motor) -> S
+//! Motor.scalar_product(Rotor) -> S
+//! Rotor.commutator(Motor) -> Line
+//! Motor.commutator(Rotor) -> Line
+//! Rotor.anti_dot(Motor) -> Vec2
+//! Motor.anti_dot(Rotor) -> Vec2
+//! Rotor.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(Multivector) -> Multivector
+//! Multivector.dot(Rotor) -> Multivector
+//! Rotor.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Rotor) -> Multivector
+//! Rotor.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Rotor) -> Multivector
+//! Rotor.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Rotor) -> Multivector
+//! Rotor.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Rotor) -> Multivector
+//! Rotor.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Rotor) -> S
+//! Rotor.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Rotor) -> Multivector
+//! Rotor.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Rotor) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rotor {
 	pub s: S,
 	pub xy: XY,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Rotor {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Rotor {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Rotor {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon) && self.xy.abs_diff_eq(&other.xy, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Rotor {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative) && self.xy.relative_eq(&other.xy, epsilon, max_relative)
+	}
+}
+
 // ---------------------------------------------------------------------
-// Omitted: Rotor.rcompl() -> self.s.rcompl() + self.xy.rcompl()
-// Omitted: Rotor.lcompl() -> self.s.lcompl() + self.xy.lcompl()
+
+impl RCompl for Rotor {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.xy.rcompl(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Rotor {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Rotor {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.xy.lcompl(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.lcompl(),
+		}
+	}
+}
 
 impl Reverse for Rotor {
 	fn rev(self) -> Self {
@@ -69,6 +239,207 @@ impl AntiReverse for Rotor {
 	}
 }
 
+impl Involute for Rotor {
+	fn involute(self) -> Self {
+		Rotor { s: self.s, xy: self.xy }
+	}
+}
+
+impl Conjugate for Rotor {
+	fn conjugate(self) -> Self {
+		Rotor {
+			s: self.s,
+			xy: -self.xy,
+		}
+	}
+}
+
+impl Dual for Rotor {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.xy.dual(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.dual(),
+		}
+	}
+}
+
+impl Bulk for Rotor {
+	type Output = Rotor;
+	fn bulk(self) -> Self::Output {
+		Rotor { s: self.s, xy: self.xy }
+	}
+}
+
+// Omitted: Rotor.weight() -> 0
+
+impl BulkDual for Rotor {
+	type Output = Multivector;
+	fn bulk_dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.xy.bulk_dual(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.bulk_dual(),
+		}
+	}
+}
+
+// Omitted: Rotor.weight_dual() -> 0
+// Omitted: Rotor.square()  (not a scalar)
+
+impl Norm for Rotor {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0 + self.xy.0 * self.xy.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0 + self.xy.0 * self.xy.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Rotor {
+			s: self.s * scale,
+			xy: self.xy * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Rotor {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 2] {
+		[self.s.0, self.xy.0]
+	}
+}
+
+impl From<[f64; 2]> for Rotor {
+	fn from(a: [f64; 2]) -> Self {
+		Rotor {
+			s: S(a[0]),
+			xy: XY(a[1]),
+		}
+	}
+}
+
+impl From<Rotor> for [f64; 2] {
+	fn from(v: Rotor) -> Self {
+		v.to_array()
+	}
+}
+
+impl Rotor {
+	/// Rotate counter-clockwise by `angle` (in radians) around the origin.
+	/// A point `p` is rotated by `rotor.sandwich(p.rcompl()).rcompl()`.
+	pub fn from_angle(angle: f64) -> Self {
+		let half_angle = angle * (1.0 / 2.0);
+		Rotor {
+			s: S(half_angle.cos()),
+			xy: XY(-half_angle.sin()),
+		}
+	}
+}
+
+impl From<Rotor> for Multivector {
+	fn from(v: Rotor) -> Self {
+		Multivector {
+			s: v.s,
+			xy: v.xy,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Rotor {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Rotor` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.x.0 == 0.0 && mv.y.0 == 0.0 && mv.w.0 == 0.0 && mv.yw.0 == 0.0 && mv.wx.0 == 0.0 && mv.xyw.0 == 0.0 {
+			Ok(Rotor { s: mv.s, xy: mv.xy })
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Rotor {
+	/// The matrix that transforms `[x, y, w]` like `self.sandwich(v.rcompl()).rcompl()` for a `Vec3` `v`,
+	/// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+	pub fn to_matrix(self) -> [[f64; 3]; 3] {
+		[
+			[
+				self.s.0 * self.s.0 - self.xy.0 * self.xy.0,
+				2.0 * self.s.0 * self.xy.0,
+				0.0,
+			],
+			[
+				-2.0 * self.s.0 * self.xy.0,
+				self.s.0 * self.s.0 - self.xy.0 * self.xy.0,
+				0.0,
+			],
+			[0.0, 0.0, self.s.0 * self.s.0 + self.xy.0 * self.xy.0],
+		]
+	}
+
+	/// The first 2 rows of [`Self::to_matrix`], i.e. without the last row (which is `[0, …, 0, 1]` when normalized).
+	pub fn to_matrix_2x3(self) -> [[f64; 3]; 2] {
+		let m = self.to_matrix();
+		[m[0], m[1]]
+	}
+}
+
+impl Rotor {
+	/// The versor that moves nothing, whose matrix is the identity matrix.
+	pub fn identity() -> Self {
+		Rotor { s: S(1.0), xy: XY(0.0) }
+	}
+}
+
+impl Rotor {
+	/// The normalized versor with this matrix, e.g. from [`Self::to_matrix`].
+	/// Both `v` and `-v` have the same matrix, and this returns the one with a non-negative `s`.
+	pub fn from_matrix(m: [[f64; 3]; 3]) -> Self {
+		let s_s = (m[0][0] + m[1][1] + 2.0 * m[2][2]) / 4.0;
+		let s_xy = (m[0][1] - m[1][0]) / 4.0;
+		let xy_xy = (-m[0][0] - m[1][1] + 2.0 * m[2][2]) / 4.0;
+		let s = s_s.max(0.0).sqrt();
+		let xy = xy_xy.max(0.0).sqrt().copysign(s * s_xy);
+		Rotor {
+			s: S(s * s_s + xy * s_xy),
+			xy: XY(s * s_xy + xy * xy_xy),
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Rotor OP Vec2:
 
@@ -78,16 +449,28 @@ impl Geometric<Vec2> for Rotor {
 	fn geometric(self, rhs: Vec2) -> Self::Output {
 		// Vec2 {
 		//     x: X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
-		//     y: Y(self.s.0 * rhs.y.0) + Y(self.xy.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
 		// }
 		Vec2 {
 			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
-			y: self.s.geometric(rhs.y) - self.xy.geometric(rhs.x),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
 		}
 	}
 }
 
-// Omitted: Rotor anti_geometric Vec2 = 0  (unnamed type)
+impl std::ops::Mul<Vec2> for Rotor {
+	type Output = Vec2;
+	fn mul(self, rhs: Vec2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl AntiGeometric<Vec2> for Rotor {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
 
 // Rotor.dot(Vec2) -> Vec2
 impl Dot<Vec2> for Rotor {
@@ -95,15 +478,22 @@ impl Dot<Vec2> for Rotor {
 	fn dot(self, rhs: Vec2) -> Self::Output {
 		// Vec2 {
 		//     x: X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
-		//     y: Y(self.s.0 * rhs.y.0) + Y(self.xy.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
 		// }
 		Vec2 {
 			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
-			y: self.s.dot(rhs.y) - self.xy.dot(rhs.x),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
 		}
 	}
 }
 
+impl std::ops::BitOr<Vec2> for Rotor {
+	type Output = Vec2;
+	fn bitor(self, rhs: Vec2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Rotor.wedge(Vec2) -> Vec2
 impl Wedge<Vec2> for Rotor {
 	type Output = Vec2;
@@ -119,12 +509,122 @@ impl Wedge<Vec2> for Rotor {
 	}
 }
 
-// Omitted: Rotor anti_wedge Vec2 = 0  (unnamed type)
+impl std::ops::BitXor<Vec2> for Rotor {
+	type Output = Vec2;
+	fn bitxor(self, rhs: Vec2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec2> for Rotor {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec2> for Rotor {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Vec2) -> Vec2
+impl LeftContraction<Vec2> for Rotor {
+	type Output = Vec2;
+	fn left_contraction(self, rhs: Vec2) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		// }
+		Vec2 {
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+		}
+	}
+}
+
+// Rotor.right_contraction(Vec2) -> Vec2
+impl RightContraction<Vec2> for Rotor {
+	type Output = Vec2;
+	fn right_contraction(self, rhs: Vec2) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		// }
+		Vec2 {
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+		}
+	}
+}
+
+impl ScalarProduct<Vec2> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(Vec2) -> Vec2
+impl Commutator<Vec2> for Rotor {
+	type Output = Vec2;
+	fn commutator(self, rhs: Vec2) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		// }
+		Vec2 {
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+		}
+	}
+}
+
+impl AntiDot<Vec2> for Rotor {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
 
 // ---------------------------------------------------------------------
 // Rotor OP Vec3:
 
-// Omitted: Rotor geometric Vec3 = self.s * rhs.w + self.s * rhs.x + self.s * rhs.y + self.xy * rhs.w + self.xy * rhs.x + self.xy * rhs.y  (unnamed type)
+// Rotor.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Rotor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0 * rhs.w.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.xy.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
+			w: self.s.geometric(rhs.w),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.xy.geometric(rhs.w),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Rotor {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
 // Rotor.anti_geometric(Vec3) -> Rotor
 impl AntiGeometric<Vec3> for Rotor {
@@ -132,11 +632,11 @@ impl AntiGeometric<Vec3> for Rotor {
 	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
 		// Rotor {
 		//     s : S(self.xy.0 * rhs.w.0),
-		//     xy: XY(self.s.0 * rhs.w.0),
+		//     xy: -XY(self.s.0 * rhs.w.0),
 		// }
 		Rotor {
 			s: self.xy.anti_geometric(rhs.w),
-			xy: -self.s.anti_geometric(rhs.w),
+			xy: self.s.anti_geometric(rhs.w),
 		}
 	}
 }
@@ -147,18 +647,57 @@ impl Dot<Vec3> for Rotor {
 	fn dot(self, rhs: Vec3) -> Self::Output {
 		// Vec3 {
 		//     x: X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
-		//     y: Y(self.s.0 * rhs.y.0) + Y(self.xy.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
 		//     w: W(self.s.0 * rhs.w.0),
 		// }
 		Vec3 {
 			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
-			y: self.s.dot(rhs.y) - self.xy.dot(rhs.x),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
 			w: self.s.dot(rhs.w),
 		}
 	}
 }
 
-// Omitted: Rotor wedge Vec3 = self.s ^ rhs.w + self.s ^ rhs.x + self.s ^ rhs.y + self.xy ^ rhs.w  (unnamed type)
+impl std::ops::BitOr<Vec3> for Rotor {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Rotor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.xy.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(rhs.w),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.xy.wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Rotor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
 // Rotor.anti_wedge(Vec3) -> S
 impl AntiWedge<Vec3> for Rotor {
@@ -169,6 +708,265 @@ impl AntiWedge<Vec3> for Rotor {
 	}
 }
 
+impl std::ops::BitAnd<Vec3> for Rotor {
+	type Output = S;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Vec3) -> Vec3
+impl LeftContraction<Vec3> for Rotor {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		//     w: W(self.s.0 * rhs.w.0),
+		// }
+		Vec3 {
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(rhs.w),
+		}
+	}
+}
+
+// Rotor.right_contraction(Vec3) -> Vec2
+impl RightContraction<Vec3> for Rotor {
+	type Output = Vec2;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		// }
+		Vec2 {
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(Vec3) -> Vec2
+impl Commutator<Vec3> for Rotor {
+	type Output = Vec2;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		// }
+		Vec2 {
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+		}
+	}
+}
+
+// Rotor.anti_dot(Vec3) -> XY
+impl AntiDot<Vec3> for Rotor {
+	type Output = XY;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// -XY(self.s.0 * rhs.w.0)
+		self.s.anti_dot(rhs.w)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Point2:
+
+// Rotor.geometric(Point2) -> Multivector
+impl Geometric<Point2> for Rotor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
+			w: self.s.geometric(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.xy.geometric(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::Mul<Point2> for Rotor {
+	type Output = Multivector;
+	fn mul(self, rhs: Point2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Point2) -> Rotor
+impl AntiGeometric<Point2> for Rotor {
+	type Output = Rotor;
+	fn anti_geometric(self, _rhs: Point2) -> Self::Output {
+		// Rotor {
+		//     s : S(self.xy.0),
+		//     xy: -XY(self.s.0),
+		// }
+		Rotor {
+			s: self.xy.anti_geometric(W(1.0)),
+			xy: self.s.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Rotor.dot(Point2) -> Vec3
+impl Dot<Point2> for Rotor {
+	type Output = Vec3;
+	fn dot(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y: Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w: W(self.s.0),
+		// }
+		Vec3 {
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
+			w: self.s.dot(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point2> for Rotor {
+	type Output = Vec3;
+	fn bitor(self, rhs: Point2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Point2) -> Multivector
+impl Wedge<Point2> for Rotor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.xy.wedge(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point2> for Rotor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Point2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Point2) -> S
+impl AntiWedge<Point2> for Rotor {
+	type Output = S;
+	fn anti_wedge(self, _rhs: Point2) -> Self::Output {
+		// S(self.xy.0)
+		self.xy.anti_wedge(W(1.0))
+	}
+}
+
+impl std::ops::BitAnd<Point2> for Rotor {
+	type Output = S;
+	fn bitand(self, rhs: Point2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Point2) -> Vec3
+impl LeftContraction<Point2> for Rotor {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		//     w: W(self.s.0),
+		// }
+		Vec3 {
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(W(1.0)),
+		}
+	}
+}
+
+// Rotor.right_contraction(Point2) -> Vec2
+impl RightContraction<Point2> for Rotor {
+	type Output = Vec2;
+	fn right_contraction(self, rhs: Point2) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		// }
+		Vec2 {
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+		}
+	}
+}
+
+impl ScalarProduct<Point2> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(Point2) -> Vec2
+impl Commutator<Point2> for Rotor {
+	type Output = Vec2;
+	fn commutator(self, rhs: Point2) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.xy.0 * rhs.y.0),
+		//     y: -Y(self.xy.0 * rhs.x.0),
+		// }
+		Vec2 {
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+		}
+	}
+}
+
+// Rotor.anti_dot(Point2) -> XY
+impl AntiDot<Point2> for Rotor {
+	type Output = XY;
+	fn anti_dot(self, _rhs: Point2) -> Self::Output {
+		// -XY(self.s.0)
+		self.s.anti_dot(W(1.0))
+	}
+}
+
 // ---------------------------------------------------------------------
 // Rotor OP Line:
 
@@ -177,82 +975,370 @@ impl Geometric<Line> for Rotor {
 	type Output = Motor;
 	fn geometric(self, rhs: Line) -> Self::Output {
 		// Motor {
-		//     s : S(self.xy.0 * rhs.m.0),
+		//     s : -S(self.xy.0 * rhs.m.0),
 		//     yw: YW(self.s.0 * rhs.dx.0) + YW(self.xy.0 * rhs.dy.0),
-		//     wx: WX(self.s.0 * rhs.dy.0) + WX(self.xy.0 * rhs.dx.0),
+		//     wx: WX(self.s.0 * rhs.dy.0) - WX(self.xy.0 * rhs.dx.0),
 		//     xy: XY(self.s.0 * rhs.m.0),
 		// }
 		Motor {
-			s: -self.xy.geometric(rhs.m),
+			s: self.xy.geometric(rhs.m),
 			yw: self.s.geometric(rhs.dx) + self.xy.geometric(rhs.dy),
-			wx: self.s.geometric(rhs.dy) - self.xy.geometric(rhs.dx),
+			wx: self.s.geometric(rhs.dy) + self.xy.geometric(rhs.dx),
 			xy: self.s.geometric(rhs.m),
 		}
 	}
 }
 
-// Rotor.anti_geometric(Line) -> Vec2
-impl AntiGeometric<Line> for Rotor {
+impl std::ops::Mul<Line> for Rotor {
+	type Output = Motor;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Line) -> Vec2
+impl AntiGeometric<Line> for Rotor {
+	type Output = Vec2;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.s.0 * rhs.dx.0) - X(self.xy.0 * rhs.dy.0),
+		//     y: Y(self.s.0 * rhs.dy.0) + Y(self.xy.0 * rhs.dx.0),
+		// }
+		Vec2 {
+			x: self.s.anti_geometric(rhs.dx) + self.xy.anti_geometric(rhs.dy),
+			y: self.s.anti_geometric(rhs.dy) + self.xy.anti_geometric(rhs.dx),
+		}
+	}
+}
+
+// Rotor.dot(Line) -> Motor
+impl Dot<Line> for Rotor {
+	type Output = Motor;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Motor {
+		//     s : -S(self.xy.0 * rhs.m.0),
+		//     yw: YW(self.s.0 * rhs.dx.0),
+		//     wx: WX(self.s.0 * rhs.dy.0),
+		//     xy: XY(self.s.0 * rhs.m.0),
+		// }
+		Motor {
+			s: self.xy.dot(rhs.m),
+			yw: self.s.dot(rhs.dx),
+			wx: self.s.dot(rhs.dy),
+			xy: self.s.dot(rhs.m),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Rotor {
+	type Output = Motor;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Line) -> Line
+impl Wedge<Line> for Rotor {
+	type Output = Line;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: YW(self.s.0 * rhs.dx.0),
+		//     dy: WX(self.s.0 * rhs.dy.0),
+		//     m : XY(self.s.0 * rhs.m.0),
+		// }
+		Line {
+			dx: self.s.wedge(rhs.dx),
+			dy: self.s.wedge(rhs.dy),
+			m: self.s.wedge(rhs.m),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line> for Rotor {
+	type Output = Line;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Line) -> Vec2
+impl AntiWedge<Line> for Rotor {
+	type Output = Vec2;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// Vec2 {
+		//     x: -X(self.xy.0 * rhs.dy.0),
+		//     y: Y(self.xy.0 * rhs.dx.0),
+		// }
+		Vec2 {
+			x: self.xy.anti_wedge(rhs.dy),
+			y: self.xy.anti_wedge(rhs.dx),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Rotor {
+	type Output = Vec2;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Line) -> Motor
+impl LeftContraction<Line> for Rotor {
+	type Output = Motor;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Motor {
+		//     s : -S(self.xy.0 * rhs.m.0),
+		//     yw: YW(self.s.0 * rhs.dx.0),
+		//     wx: WX(self.s.0 * rhs.dy.0),
+		//     xy: XY(self.s.0 * rhs.m.0),
+		// }
+		Motor {
+			s: self.xy.left_contraction(rhs.m),
+			yw: self.s.left_contraction(rhs.dx),
+			wx: self.s.left_contraction(rhs.dy),
+			xy: self.s.left_contraction(rhs.m),
+		}
+	}
+}
+
+// Rotor.right_contraction(Line) -> S
+impl RightContraction<Line> for Rotor {
+	type Output = S;
+	fn right_contraction(self, rhs: Line) -> Self::Output {
+		// -S(self.xy.0 * rhs.m.0)
+		self.xy.right_contraction(rhs.m)
+	}
+}
+
+// Rotor.scalar_product(Line) -> S
+impl ScalarProduct<Line> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Line) -> Self::Output {
+		// -S(self.xy.0 * rhs.m.0)
+		self.xy.scalar_product(rhs.m)
+	}
+}
+
+// Rotor.commutator(Line) -> Line
+impl Commutator<Line> for Rotor {
+	type Output = Line;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: YW(self.xy.0 * rhs.dy.0),
+		//     dy: -WX(self.xy.0 * rhs.dx.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.xy.commutator(rhs.dy),
+			dy: self.xy.commutator(rhs.dx),
+			m: Default::default(),
+		}
+	}
+}
+
+// Rotor.anti_dot(Line) -> Vec2
+impl AntiDot<Line> for Rotor {
+	type Output = Vec2;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.s.0 * rhs.dx.0),
+		//     y: Y(self.s.0 * rhs.dy.0),
+		// }
+		Vec2 {
+			x: self.s.anti_dot(rhs.dx),
+			y: self.s.anti_dot(rhs.dy),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Translator:
+
+// Rotor.geometric(Translator) -> Motor
+impl Geometric<Translator> for Rotor {
+	type Output = Motor;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.xy.0 * rhs.wx.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) - WX(self.xy.0 * rhs.yw.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.s.geometric(rhs.s),
+			yw: self.s.geometric(rhs.yw) + self.xy.geometric(rhs.wx),
+			wx: self.s.geometric(rhs.wx) + self.xy.geometric(rhs.yw),
+			xy: self.xy.geometric(rhs.s),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Rotor {
+	type Output = Motor;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Translator) -> Vec2
+impl AntiGeometric<Translator> for Rotor {
+	type Output = Vec2;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.s.0 * rhs.yw.0) - X(self.xy.0 * rhs.wx.0),
+		//     y: Y(self.s.0 * rhs.wx.0) + Y(self.xy.0 * rhs.yw.0),
+		// }
+		Vec2 {
+			x: self.s.anti_geometric(rhs.yw) + self.xy.anti_geometric(rhs.wx),
+			y: self.s.anti_geometric(rhs.wx) + self.xy.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Rotor.dot(Translator) -> Motor
+impl Dot<Translator> for Rotor {
+	type Output = Motor;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0),
+		//     wx: WX(self.s.0 * rhs.wx.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.s.dot(rhs.s),
+			yw: self.s.dot(rhs.yw),
+			wx: self.s.dot(rhs.wx),
+			xy: self.xy.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Rotor {
+	type Output = Motor;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Translator) -> Motor
+impl Wedge<Translator> for Rotor {
+	type Output = Motor;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0),
+		//     wx: WX(self.s.0 * rhs.wx.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Motor {
+			s: self.s.wedge(rhs.s),
+			yw: self.s.wedge(rhs.yw),
+			wx: self.s.wedge(rhs.wx),
+			xy: self.xy.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Rotor {
+	type Output = Motor;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Translator) -> Vec2
+impl AntiWedge<Translator> for Rotor {
 	type Output = Vec2;
-	fn anti_geometric(self, rhs: Line) -> Self::Output {
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
 		// Vec2 {
-		//     x: X(self.s.0 * rhs.dx.0) + X(self.xy.0 * rhs.dy.0),
-		//     y: Y(self.s.0 * rhs.dy.0) + Y(self.xy.0 * rhs.dx.0),
+		//     x: -X(self.xy.0 * rhs.wx.0),
+		//     y: Y(self.xy.0 * rhs.yw.0),
 		// }
 		Vec2 {
-			x: self.s.anti_geometric(rhs.dx) - self.xy.anti_geometric(rhs.dy),
-			y: self.s.anti_geometric(rhs.dy) + self.xy.anti_geometric(rhs.dx),
+			x: self.xy.anti_wedge(rhs.wx),
+			y: self.xy.anti_wedge(rhs.yw),
 		}
 	}
 }
 
-// Rotor.dot(Line) -> Motor
-impl Dot<Line> for Rotor {
-	type Output = Motor;
-	fn dot(self, rhs: Line) -> Self::Output {
-		// Motor {
-		//     s : S(self.xy.0 * rhs.m.0),
-		//     yw: YW(self.s.0 * rhs.dx.0),
-		//     wx: WX(self.s.0 * rhs.dy.0),
-		//     xy: XY(self.s.0 * rhs.m.0),
+impl std::ops::BitAnd<Translator> for Rotor {
+	type Output = Vec2;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Translator) -> Translator
+impl LeftContraction<Translator> for Rotor {
+	type Output = Translator;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0),
+		//     wx: WX(self.s.0 * rhs.wx.0),
 		// }
-		Motor {
-			s: -self.xy.dot(rhs.m),
-			yw: self.s.dot(rhs.dx),
-			wx: self.s.dot(rhs.dy),
-			xy: self.s.dot(rhs.m),
+		Translator {
+			s: self.s.left_contraction(rhs.s),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
 		}
 	}
 }
 
-// Rotor.wedge(Line) -> Line
-impl Wedge<Line> for Rotor {
+// Rotor.right_contraction(Translator) -> Rotor
+impl RightContraction<Translator> for Rotor {
+	type Output = Rotor;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Rotor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Rotor {
+			s: self.s.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Rotor.scalar_product(Translator) -> S
+impl ScalarProduct<Translator> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator) -> Self::Output {
+		// S(self.s.0 * rhs.s.0)
+		self.s.scalar_product(rhs.s)
+	}
+}
+
+// Rotor.commutator(Translator) -> Line
+impl Commutator<Translator> for Rotor {
 	type Output = Line;
-	fn wedge(self, rhs: Line) -> Self::Output {
+	fn commutator(self, rhs: Translator) -> Self::Output {
 		// Line {
-		//     dx: YW(self.s.0 * rhs.dx.0),
-		//     dy: WX(self.s.0 * rhs.dy.0),
-		//     m : XY(self.s.0 * rhs.m.0),
+		//     dx: YW(self.xy.0 * rhs.wx.0),
+		//     dy: -WX(self.xy.0 * rhs.yw.0),
+		//     m : Default::default(),
 		// }
 		Line {
-			dx: self.s.wedge(rhs.dx),
-			dy: self.s.wedge(rhs.dy),
-			m: self.s.wedge(rhs.m),
+			dx: self.xy.commutator(rhs.wx),
+			dy: self.xy.commutator(rhs.yw),
+			m: Default::default(),
 		}
 	}
 }
 
-// Rotor.anti_wedge(Line) -> Vec2
-impl AntiWedge<Line> for Rotor {
+// Rotor.anti_dot(Translator) -> Vec2
+impl AntiDot<Translator> for Rotor {
 	type Output = Vec2;
-	fn anti_wedge(self, rhs: Line) -> Self::Output {
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
 		// Vec2 {
-		//     x: X(self.xy.0 * rhs.dy.0),
-		//     y: Y(self.xy.0 * rhs.dx.0),
+		//     x: X(self.s.0 * rhs.yw.0),
+		//     y: Y(self.s.0 * rhs.wx.0),
 		// }
 		Vec2 {
-			x: -self.xy.anti_wedge(rhs.dy),
-			y: self.xy.anti_wedge(rhs.dx),
+			x: self.s.anti_dot(rhs.yw),
+			y: self.s.anti_dot(rhs.wx),
 		}
 	}
 }
@@ -265,33 +1351,52 @@ impl Geometric<Rotor> for Rotor {
 	type Output = Rotor;
 	fn geometric(self, rhs: Rotor) -> Self::Output {
 		// Rotor {
-		//     s : S(self.s.0 * rhs.s.0) + S(self.xy.0 * rhs.xy.0),
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
 		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
 		// }
 		Rotor {
-			s: self.s.geometric(rhs.s) - self.xy.geometric(rhs.xy),
+			s: self.s.geometric(rhs.s) + self.xy.geometric(rhs.xy),
 			xy: self.s.geometric(rhs.xy) + self.xy.geometric(rhs.s),
 		}
 	}
 }
 
-// Omitted: Rotor anti_geometric Rotor = 0  (unnamed type)
+impl std::ops::Mul<Rotor> for Rotor {
+	type Output = Rotor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl AntiGeometric<Rotor> for Rotor {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
 
 // Rotor.dot(Rotor) -> Rotor
 impl Dot<Rotor> for Rotor {
 	type Output = Rotor;
 	fn dot(self, rhs: Rotor) -> Self::Output {
 		// Rotor {
-		//     s : S(self.s.0 * rhs.s.0) + S(self.xy.0 * rhs.xy.0),
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
 		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
 		// }
 		Rotor {
-			s: self.s.dot(rhs.s) - self.xy.dot(rhs.xy),
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy),
 			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
 		}
 	}
 }
 
+impl std::ops::BitOr<Rotor> for Rotor {
+	type Output = Rotor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Rotor.wedge(Rotor) -> Rotor
 impl Wedge<Rotor> for Rotor {
 	type Output = Rotor;
@@ -307,7 +1412,79 @@ impl Wedge<Rotor> for Rotor {
 	}
 }
 
-// Omitted: Rotor anti_wedge Rotor = 0  (unnamed type)
+impl std::ops::BitXor<Rotor> for Rotor {
+	type Output = Rotor;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Rotor> for Rotor {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Rotor {
+	type Output = Zero;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Rotor) -> Rotor
+impl LeftContraction<Rotor> for Rotor {
+	type Output = Rotor;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Rotor {
+			s: self.s.left_contraction(rhs.s) + self.xy.left_contraction(rhs.xy),
+			xy: self.s.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Rotor.right_contraction(Rotor) -> Rotor
+impl RightContraction<Rotor> for Rotor {
+	type Output = Rotor;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Rotor {
+			s: self.s.right_contraction(rhs.s) + self.xy.right_contraction(rhs.xy),
+			xy: self.xy.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Rotor.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0)
+		self.s.scalar_product(rhs.s) + self.xy.scalar_product(rhs.xy)
+	}
+}
+
+impl Commutator<Rotor> for Rotor {
+	type Output = Zero;
+	fn commutator(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<Rotor> for Rotor {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
 
 // ---------------------------------------------------------------------
 // Rotor OP Motor:
@@ -317,30 +1494,37 @@ impl Geometric<Motor> for Rotor {
 	type Output = Motor;
 	fn geometric(self, rhs: Motor) -> Self::Output {
 		// Motor {
-		//     s : S(self.s.0 * rhs.s.0) + S(self.xy.0 * rhs.xy.0),
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
 		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.xy.0 * rhs.wx.0),
-		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.xy.0 * rhs.yw.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) - WX(self.xy.0 * rhs.yw.0),
 		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
 		// }
 		Motor {
-			s: self.s.geometric(rhs.s) - self.xy.geometric(rhs.xy),
+			s: self.s.geometric(rhs.s) + self.xy.geometric(rhs.xy),
 			yw: self.s.geometric(rhs.yw) + self.xy.geometric(rhs.wx),
-			wx: self.s.geometric(rhs.wx) - self.xy.geometric(rhs.yw),
+			wx: self.s.geometric(rhs.wx) + self.xy.geometric(rhs.yw),
 			xy: self.s.geometric(rhs.xy) + self.xy.geometric(rhs.s),
 		}
 	}
 }
 
+impl std::ops::Mul<Motor> for Rotor {
+	type Output = Motor;
+	fn mul(self, rhs: Motor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
 // Rotor.anti_geometric(Motor) -> Vec2
 impl AntiGeometric<Motor> for Rotor {
 	type Output = Vec2;
 	fn anti_geometric(self, rhs: Motor) -> Self::Output {
 		// Vec2 {
-		//     x: X(self.s.0 * rhs.yw.0) + X(self.xy.0 * rhs.wx.0),
+		//     x: X(self.s.0 * rhs.yw.0) - X(self.xy.0 * rhs.wx.0),
 		//     y: Y(self.s.0 * rhs.wx.0) + Y(self.xy.0 * rhs.yw.0),
 		// }
 		Vec2 {
-			x: self.s.anti_geometric(rhs.yw) - self.xy.anti_geometric(rhs.wx),
+			x: self.s.anti_geometric(rhs.yw) + self.xy.anti_geometric(rhs.wx),
 			y: self.s.anti_geometric(rhs.wx) + self.xy.anti_geometric(rhs.yw),
 		}
 	}
@@ -351,13 +1535,13 @@ impl Dot<Motor> for Rotor {
 	type Output = Motor;
 	fn dot(self, rhs: Motor) -> Self::Output {
 		// Motor {
-		//     s : S(self.s.0 * rhs.s.0) + S(self.xy.0 * rhs.xy.0),
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
 		//     yw: YW(self.s.0 * rhs.yw.0),
 		//     wx: WX(self.s.0 * rhs.wx.0),
 		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
 		// }
 		Motor {
-			s: self.s.dot(rhs.s) - self.xy.dot(rhs.xy),
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy),
 			yw: self.s.dot(rhs.yw),
 			wx: self.s.dot(rhs.wx),
 			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
@@ -365,6 +1549,13 @@ impl Dot<Motor> for Rotor {
 	}
 }
 
+impl std::ops::BitOr<Motor> for Rotor {
+	type Output = Motor;
+	fn bitor(self, rhs: Motor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Rotor.wedge(Motor) -> Motor
 impl Wedge<Motor> for Rotor {
 	type Output = Motor;
@@ -384,17 +1575,444 @@ impl Wedge<Motor> for Rotor {
 	}
 }
 
+impl std::ops::BitXor<Motor> for Rotor {
+	type Output = Motor;
+	fn bitxor(self, rhs: Motor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Rotor.anti_wedge(Motor) -> Vec2
 impl AntiWedge<Motor> for Rotor {
 	type Output = Vec2;
 	fn anti_wedge(self, rhs: Motor) -> Self::Output {
 		// Vec2 {
-		//     x: X(self.xy.0 * rhs.wx.0),
+		//     x: -X(self.xy.0 * rhs.wx.0),
 		//     y: Y(self.xy.0 * rhs.yw.0),
 		// }
 		Vec2 {
-			x: -self.xy.anti_wedge(rhs.wx),
+			x: self.xy.anti_wedge(rhs.wx),
+			y: self.xy.anti_wedge(rhs.yw),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor> for Rotor {
+	type Output = Vec2;
+	fn bitand(self, rhs: Motor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Motor) -> Motor
+impl LeftContraction<Motor> for Rotor {
+	type Output = Motor;
+	fn left_contraction(self, rhs: Motor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     yw: YW(self.s.0 * rhs.yw.0),
+		//     wx: WX(self.s.0 * rhs.wx.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Motor {
+			s: self.s.left_contraction(rhs.s) + self.xy.left_contraction(rhs.xy),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
+			xy: self.s.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Rotor.right_contraction(Motor) -> Rotor
+impl RightContraction<Motor> for Rotor {
+	type Output = Rotor;
+	fn right_contraction(self, rhs: Motor) -> Self::Output {
+		// Rotor {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Rotor {
+			s: self.s.right_contraction(rhs.s) + self.xy.right_contraction(rhs.xy),
+			xy: self.xy.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Rotor.scalar_product(Motor) -> S
+impl ScalarProduct<Motor> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Motor) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0)
+		self.s.scalar_product(rhs.s) + self.xy.scalar_product(rhs.xy)
+	}
+}
+
+// Rotor.commutator(Motor) -> Line
+impl Commutator<Motor> for Rotor {
+	type Output = Line;
+	fn commutator(self, rhs: Motor) -> Self::Output {
+		// Line {
+		//     dx: YW(self.xy.0 * rhs.wx.0),
+		//     dy: -WX(self.xy.0 * rhs.yw.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.xy.commutator(rhs.wx),
+			dy: self.xy.commutator(rhs.yw),
+			m: Default::default(),
+		}
+	}
+}
+
+// Rotor.anti_dot(Motor) -> Vec2
+impl AntiDot<Motor> for Rotor {
+	type Output = Vec2;
+	fn anti_dot(self, rhs: Motor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.s.0 * rhs.yw.0),
+		//     y: Y(self.s.0 * rhs.wx.0),
+		// }
+		Vec2 {
+			x: self.s.anti_dot(rhs.yw),
+			y: self.s.anti_dot(rhs.wx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Multivector:
+
+// Rotor.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Rotor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0 * rhs.w.0) - W(self.xy.0 * rhs.xyw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.xy.0 * rhs.wx.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) - WX(self.xy.0 * rhs.yw.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0) + XYW(self.xy.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s) + self.xy.geometric(rhs.xy),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x),
+			w: self.s.geometric(rhs.w) + self.xy.geometric(rhs.xyw),
+			yw: self.s.geometric(rhs.yw) + self.xy.geometric(rhs.wx),
+			wx: self.s.geometric(rhs.wx) + self.xy.geometric(rhs.yw),
+			xy: self.s.geometric(rhs.xy) + self.xy.geometric(rhs.s),
+			xyw: self.s.geometric(rhs.xyw) + self.xy.geometric(rhs.w),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Rotor {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0) + S(self.xy.0 * rhs.w.0),
+		//     x  : X(self.s.0 * rhs.yw.0) - X(self.xy.0 * rhs.wx.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.xy.0 * rhs.yw.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : -XY(self.s.0 * rhs.w.0) + XY(self.xy.0 * rhs.xyw.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.anti_geometric(rhs.xyw) + self.xy.anti_geometric(rhs.w),
+			x: self.s.anti_geometric(rhs.yw) + self.xy.anti_geometric(rhs.wx),
+			y: self.s.anti_geometric(rhs.wx) + self.xy.anti_geometric(rhs.yw),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.s.anti_geometric(rhs.w) + self.xy.anti_geometric(rhs.xyw),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Rotor.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Rotor {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0),
+		//     w  : W(self.s.0 * rhs.w.0) - W(self.xy.0 * rhs.xyw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0),
+		//     wx : WX(self.s.0 * rhs.wx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy),
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x),
+			w: self.s.dot(rhs.w) + self.xy.dot(rhs.xyw),
+			yw: self.s.dot(rhs.yw),
+			wx: self.s.dot(rhs.wx),
+			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
+			xyw: self.s.dot(rhs.xyw),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Rotor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Rotor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0),
+		//     yw : YW(self.s.0 * rhs.yw.0),
+		//     wx : WX(self.s.0 * rhs.wx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0) + XYW(self.xy.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(rhs.w),
+			yw: self.s.wedge(rhs.yw),
+			wx: self.s.wedge(rhs.wx),
+			xy: self.s.wedge(rhs.xy) + self.xy.wedge(rhs.s),
+			xyw: self.s.wedge(rhs.xyw) + self.xy.wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Rotor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Rotor {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0) + S(self.xy.0 * rhs.w.0),
+		//     x  : -X(self.xy.0 * rhs.wx.0),
+		//     y  : Y(self.xy.0 * rhs.yw.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : XY(self.xy.0 * rhs.xyw.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.anti_wedge(rhs.xyw) + self.xy.anti_wedge(rhs.w),
+			x: self.xy.anti_wedge(rhs.wx),
 			y: self.xy.anti_wedge(rhs.yw),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.xy.anti_wedge(rhs.xyw),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Rotor {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Rotor {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0) - W(self.xy.0 * rhs.xyw.0),
+		//     yw : YW(self.s.0 * rhs.yw.0),
+		//     wx : WX(self.s.0 * rhs.wx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s) + self.xy.left_contraction(rhs.xy),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(rhs.w) + self.xy.left_contraction(rhs.xyw),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
+			xy: self.s.left_contraction(rhs.xy),
+			xyw: self.s.left_contraction(rhs.xyw),
+		}
+	}
+}
+
+// Rotor.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Rotor {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0),
+		//     x  : X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s) + self.xy.right_contraction(rhs.xy),
+			x: self.xy.right_contraction(rhs.y),
+			y: self.xy.right_contraction(rhs.x),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.xy.right_contraction(rhs.s),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Rotor.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0)
+		self.s.scalar_product(rhs.s) + self.xy.scalar_product(rhs.xy)
+	}
+}
+
+// Rotor.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Rotor {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0),
+		//     w  : Default::default(),
+		//     yw : YW(self.xy.0 * rhs.wx.0),
+		//     wx : -WX(self.xy.0 * rhs.yw.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y),
+			y: self.xy.commutator(rhs.x),
+			w: Default::default(),
+			yw: self.xy.commutator(rhs.wx),
+			wx: self.xy.commutator(rhs.yw),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Rotor.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Rotor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0),
+		//     x  : X(self.s.0 * rhs.yw.0),
+		//     y  : Y(self.s.0 * rhs.wx.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : -XY(self.s.0 * rhs.w.0) + XY(self.xy.0 * rhs.xyw.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.anti_dot(rhs.xyw),
+			x: self.s.anti_dot(rhs.yw),
+			y: self.s.anti_dot(rhs.wx),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.s.anti_dot(rhs.w) + self.xy.anti_dot(rhs.xyw),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Rotor.sandwich(Vec2) -> Vec2
+impl Sandwich<Vec2> for Rotor {
+	fn sandwich(self, rhs: Vec2) -> Vec2 {
+		Vec2 {
+			x: self.s.geometric(self.s).geometric(rhs.x)
+				+ self.s.geometric(self.xy).geometric(rhs.y) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.x),
+			y: self.s.geometric(self.s).geometric(rhs.y)
+				+ self.s.geometric(self.xy).geometric(rhs.x) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.y),
+		}
+	}
+}
+
+// Omitted: Rotor.anti_sandwich(Vec2)  (zero)
+
+// Rotor.sandwich(Vec3) -> Vec3
+impl Sandwich<Vec3> for Rotor {
+	fn sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: self.s.geometric(self.s).geometric(rhs.x)
+				+ self.s.geometric(self.xy).geometric(rhs.y) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.x),
+			y: self.s.geometric(self.s).geometric(rhs.y)
+				+ self.s.geometric(self.xy).geometric(rhs.x) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.y),
+			w: self.s.geometric(self.s).geometric(rhs.w) - self.xy.geometric(self.xy).geometric(rhs.w),
+		}
+	}
+}
+
+// Omitted: Rotor.anti_sandwich(Vec3)  (zero)
+// Omitted: Rotor.sandwich(Point2)  (not a Point2)
+// Omitted: Rotor.anti_sandwich(Point2)  (zero)
+
+// Rotor.sandwich(Line) -> Line
+impl Sandwich<Line> for Rotor {
+	fn sandwich(self, rhs: Line) -> Line {
+		Line {
+			dx: self.s.geometric(self.s).geometric(rhs.dx)
+				+ self.s.geometric(self.xy).geometric(rhs.dy) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.dx),
+			dy: self.s.geometric(self.s).geometric(rhs.dy)
+				+ self.s.geometric(self.xy).geometric(rhs.dx) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.dy),
+			m: self.s.geometric(self.s).geometric(rhs.m) - self.xy.geometric(self.xy).geometric(rhs.m),
 		}
 	}
 }
+
+// Omitted: Rotor.anti_sandwich(Line)  (zero)
@@ -0,0 +1,2184 @@
+//! # Translator
+//!
+//! ## Operations
+//! ```text
+//! Translator.geometric(Translator) -> Translator
+//! Translator.anti_geometric(Translator) -> Multivector
+//! Translator.dot(Translator) -> Translator
+//! Translator.wedge(Translator) -> Translator
+//! Translator.anti_wedge(Translator) -> W
+//! Translator.left_contraction(Translator) -> Translator
+//! Translator.right_contraction(Translator) -> Translator
+//! Translator.scalar_product(Translator) -> S
+//! Translator.anti_dot(Translator) -> Multivector
+//! Translator.geometric(Vec2) -> Multivector
+//! Vec2.geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Vec2) -> Rotor
+//! Vec2.anti_geometric(Translator) -> Rotor
+//! Translator.dot(Vec2) -> Vec3
+//! Vec2.dot(Translator) -> Vec3
+//! Translator.wedge(Vec2) -> Multivector
+//! Vec2.wedge(Translator) -> Multivector
+//! Translator.anti_wedge(Vec2) -> S
+//! Vec2.anti_wedge(Translator) -> S
+//! Translator.left_contraction(Vec2) -> Vec2
+//! Vec2.left_contraction(Translator) -> W
+//! Translator.right_contraction(Vec2) -> W
+//! Vec2.right_contraction(Translator) -> Vec2
+//! Translator.commutator(Vec2) -> W
+//! Vec2.commutator(Translator) -> W
+//! Translator.anti_dot(Vec2) -> XY
+//! Vec2.anti_dot(Translator) -> XY
+//! Translator.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Vec3) -> Motor
+//! Vec3.anti_geometric(Translator) -> Motor
+//! Translator.dot(Vec3) -> Vec3
+//! Vec3.dot(Translator) -> Vec3
+//! Translator.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Translator) -> Multivector
+//! Translator.anti_wedge(Vec3) -> S
+//! Vec3.anti_wedge(Translator) -> S
+//! Translator.left_contraction(Vec3) -> Vec3
+//! Vec3.left_contraction(Translator) -> W
+//! Translator.right_contraction(Vec3) -> W
+//! Vec3.right_contraction(Translator) -> Vec3
+//! Translator.commutator(Vec3) -> W
+//! Vec3.commutator(Translator) -> W
+//! Translator.anti_dot(Vec3) -> Line
+//! Vec3.anti_dot(Translator) -> Line
+//! Translator.geometric(Point2) -> Multivector
+//! Point2.geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Point2) -> Motor
+//! Point2.anti_geometric(Translator) -> Motor
+//! Translator.dot(Point2) -> Vec3
+//! Point2.dot(Translator) -> Vec3
+//! Translator.wedge(Point2) -> Multivector
+//! Point2.wedge(Translator) -> Multivector
+//! Translator.anti_wedge(Point2) -> S
+//! Point2.anti_wedge(Translator) -> S
+//! Translator.left_contraction(Point2) -> Vec3
+//! Point2.left_contraction(Translator) -> W
+//! Translator.right_contraction(Point2) -> W
+//! Point2.right_contraction(Translator) -> Vec3
+//! Translator.commutator(Point2) -> W
+//! Point2.commutator(Translator) -> W
+//! Translator.anti_dot(Point2) -> Line
+//! Point2.anti_dot(Translator) -> Line
+//! Translator.geometric(Line) -> Line
+//! Line.geometric(Translator) -> Line
+//! Translator.anti_geometric(Line) -> Multivector
+//! Line.anti_geometric(Translator) -> Multivector
+//! Translator.dot(Line) -> Line
+//! Line.dot(Translator) -> Line
+//! Translator.wedge(Line) -> Line
+//! Line.wedge(Translator) -> Line
+//! Translator.anti_wedge(Line) -> Vec3
+//! Line.anti_wedge(Translator) -> Vec3
+//! Translator.left_contraction(Line) -> Line
+//! Line.right_contraction(Translator) -> Line
+//! Translator.commutator(Line) -> Line
+//! Line.commutator(Translator) -> Line
+//! Translator.anti_dot(Line) -> Multivector
+//! Line.anti_dot(Translator) -> Multivector
+//! Translator.geometric(Rotor) -> Motor
+//! Rotor.geometric(Translator) -> Motor
+//! Translator.anti_geometric(Rotor) -> Vec2
+//! Rotor.anti_geometric(Translator) -> Vec2
+//! Translator.dot(Rotor) -> Motor
+//! Rotor.dot(Translator) -> Motor
+//! Translator.wedge(Rotor) -> Motor
+//! Rotor.wedge(Translator) -> Motor
+//! Translator.anti_wedge(Rotor) -> Vec2
+//! Rotor.anti_wedge(Translator) -> Vec2
+//! Translator.left_contraction(Rotor) -> Rotor
+//! Rotor.left_contraction(Translator) -> Translator
+//! Translator.right_contraction(Rotor) -> Translator
+//! Rotor.right_contraction(Translator) -> Rotor
+//! Translator.scalar_product(Rotor) -> S
+//! Rotor.scalar_product(Translator) -> S
+//! Translator.commutator(Rotor) -> Line
+//! Rotor.commutator(Translator) -> Line
+//! Translator.anti_dot(Rotor) -> Vec2
+//! Rotor.anti_dot(Translator) -> Vec2
+//! Translator.geometric(Motor) -> Motor
+//! Motor.geometric(Translator) -> Motor
+//! Translator.anti_geometric(Motor) -> Multivector
+//! Motor.anti_geometric(Translator) -> Multivector
+//! Translator.dot(Motor) -> Motor
+//! Motor.dot(Translator) -> Motor
+//! Translator.wedge(Motor) -> Motor
+//! Motor.wedge(Translator) -> Motor
+//! Translator.anti_wedge(Motor) -> Vec3
+//! Motor.anti_wedge(Translator) -> Vec3
+//! Translator.left_contraction(Motor) -> Motor
+//! Motor.left_contraction(Translator) -> Translator
+//! Translator.right_contraction(Motor) -> Translator
+//! Motor.right_contraction(Translator) -> Motor
+//! Translator.scalar_product(Motor) -> S
+//! Motor.scalar_product(Translator) -> S
+//! Translator.commutator(Motor) -> Line
+//! Motor.commutator(Translator) -> Line
+//! Translator.anti_dot(Motor) -> Multivector
+//! Motor.anti_dot(Translator) -> Multivector
+//! Translator.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Translator) -> Multivector
+//! Translator.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Translator) -> Multivector
+//! Translator.dot(Multivector) -> Multivector
+//! Multivector.dot(Translator) -> Multivector
+//! Translator.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Translator) -> Multivector
+//! Translator.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Translator) -> Multivector
+//! Translator.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Translator) -> Multivector
+//! Translator.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Translator) -> Multivector
+//! Translator.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Translator) -> S
+//! Translator.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Translator) -> Multivector
+//! Translator.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Translator) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Translator {
+	pub s: S,
+	pub yw: YW,
+	pub wx: WX,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Translator {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Translator {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Translator {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.yw.abs_diff_eq(&other.yw, epsilon)
+			&& self.wx.abs_diff_eq(&other.wx, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Translator {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.yw.relative_eq(&other.yw, epsilon, max_relative)
+			&& self.wx.relative_eq(&other.wx, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Translator {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yw.rcompl(),
+			y: self.wx.rcompl(),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Translator {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Translator {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yw.lcompl(),
+			y: self.wx.lcompl(),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Translator {
+	fn rev(self) -> Self {
+		Translator {
+			s: self.s,
+			yw: -self.yw,
+			wx: -self.wx,
+		}
+	}
+}
+
+impl AntiReverse for Translator {
+	fn arev(self) -> Self {
+		Translator {
+			s: -self.s,
+			yw: self.yw,
+			wx: self.wx,
+		}
+	}
+}
+
+impl Involute for Translator {
+	fn involute(self) -> Self {
+		Translator {
+			s: self.s,
+			yw: self.yw,
+			wx: self.wx,
+		}
+	}
+}
+
+impl Conjugate for Translator {
+	fn conjugate(self) -> Self {
+		Translator {
+			s: self.s,
+			yw: -self.yw,
+			wx: -self.wx,
+		}
+	}
+}
+
+impl Dual for Translator {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yw.dual(),
+			y: self.wx.dual(),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.s.dual(),
+		}
+	}
+}
+
+impl Bulk for Translator {
+	type Output = S;
+	fn bulk(self) -> Self::Output {
+		self.s
+	}
+}
+
+impl Weight for Translator {
+	type Output = Line;
+	fn weight(self) -> Self::Output {
+		Line {
+			dx: self.yw,
+			dy: self.wx,
+			m: Default::default(),
+		}
+	}
+}
+
+impl BulkDual for Translator {
+	type Output = XYW;
+	fn bulk_dual(self) -> Self::Output {
+		self.s.bulk_dual()
+	}
+}
+
+impl WeightDual for Translator {
+	type Output = Vec2;
+	fn weight_dual(self) -> Self::Output {
+		Vec2 {
+			x: self.yw.weight_dual(),
+			y: self.wx.weight_dual(),
+		}
+	}
+}
+
+// Omitted: Translator.square()  (not a scalar)
+
+impl Norm for Translator {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0 + self.yw.0 * self.yw.0 + self.wx.0 * self.wx.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.yw.0 * self.yw.0 + self.wx.0 * self.wx.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.s.0;
+		Translator {
+			s: self.s * scale,
+			yw: self.yw * scale,
+			wx: self.wx * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Translator {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 3] {
+		[self.s.0, self.yw.0, self.wx.0]
+	}
+}
+
+impl From<[f64; 3]> for Translator {
+	fn from(a: [f64; 3]) -> Self {
+		Translator {
+			s: S(a[0]),
+			yw: YW(a[1]),
+			wx: WX(a[2]),
+		}
+	}
+}
+
+impl From<Translator> for [f64; 3] {
+	fn from(v: Translator) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Translator> for Multivector {
+	fn from(v: Translator) -> Self {
+		Multivector {
+			s: v.s,
+			yw: v.yw,
+			wx: v.wx,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Translator {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Translator` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.x.0 == 0.0 && mv.y.0 == 0.0 && mv.w.0 == 0.0 && mv.xy.0 == 0.0 && mv.xyw.0 == 0.0 {
+			Ok(Translator {
+				s: mv.s,
+				yw: mv.yw,
+				wx: mv.wx,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Translator {
+	/// The matrix that transforms `[x, y, w]` like `self.sandwich(v.rcompl()).rcompl()` for a `Vec3` `v`,
+	/// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+	pub fn to_matrix(self) -> [[f64; 3]; 3] {
+		[
+			[self.s.0 * self.s.0, 0.0, -2.0 * self.s.0 * self.wx.0],
+			[0.0, self.s.0 * self.s.0, 2.0 * self.s.0 * self.yw.0],
+			[0.0, 0.0, self.s.0 * self.s.0],
+		]
+	}
+
+	/// The first 2 rows of [`Self::to_matrix`], i.e. without the last row (which is `[0, …, 0, 1]` when normalized).
+	pub fn to_matrix_2x3(self) -> [[f64; 3]; 2] {
+		let m = self.to_matrix();
+		[m[0], m[1]]
+	}
+}
+
+impl Translator {
+	/// The versor that moves nothing, whose matrix is the identity matrix.
+	pub fn identity() -> Self {
+		Translator {
+			s: S(1.0),
+			yw: YW(0.0),
+			wx: WX(0.0),
+		}
+	}
+}
+
+impl Translator {
+	/// The normalized versor with this matrix, e.g. from [`Self::to_matrix`].
+	/// Both `v` and `-v` have the same matrix, and this returns the one with a positive `s`.
+	pub fn from_matrix(m: [[f64; 3]; 3]) -> Self {
+		let s_s = (m[0][0] + m[1][1] + m[2][2]) / 3.0;
+		let s_wx = -m[0][2] / 2.0;
+		let s_yw = m[1][2] / 2.0;
+		let pivot = s_s.sqrt();
+		Translator {
+			s: S(pivot),
+			yw: YW(s_yw / pivot),
+			wx: WX(s_wx / pivot),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Vec2:
+
+// Translator.geometric(Vec2) -> Multivector
+impl Geometric<Vec2> for Translator {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.geometric(rhs.x),
+			y: self.s.geometric(rhs.y),
+			w: self.wx.geometric(rhs.x) + self.yw.geometric(rhs.y),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.geometric(rhs.y) + self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec2> for Translator {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Vec2) -> Rotor
+impl AntiGeometric<Vec2> for Translator {
+	type Output = Rotor;
+	fn anti_geometric(self, rhs: Vec2) -> Self::Output {
+		// Rotor {
+		//     s : S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0),
+		//     xy: XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		// }
+		Rotor {
+			s: self.wx.anti_geometric(rhs.y) + self.yw.anti_geometric(rhs.x),
+			xy: self.wx.anti_geometric(rhs.x) + self.yw.anti_geometric(rhs.y),
+		}
+	}
+}
+
+// Translator.dot(Vec2) -> Vec3
+impl Dot<Vec2> for Translator {
+	type Output = Vec3;
+	fn dot(self, rhs: Vec2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		//     w: W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: self.s.dot(rhs.x),
+			y: self.s.dot(rhs.y),
+			w: self.wx.dot(rhs.x) + self.yw.dot(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec2> for Translator {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Vec2) -> Multivector
+impl Wedge<Vec2> for Translator {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.wedge(rhs.y) + self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec2> for Translator {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Vec2) -> S
+impl AntiWedge<Vec2> for Translator {
+	type Output = S;
+	fn anti_wedge(self, rhs: Vec2) -> Self::Output {
+		// S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0)
+		self.wx.anti_wedge(rhs.y) + self.yw.anti_wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitAnd<Vec2> for Translator {
+	type Output = S;
+	fn bitand(self, rhs: Vec2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Vec2) -> Vec2
+impl LeftContraction<Vec2> for Translator {
+	type Output = Vec2;
+	fn left_contraction(self, rhs: Vec2) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		// }
+		Vec2 {
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+		}
+	}
+}
+
+// Translator.right_contraction(Vec2) -> W
+impl RightContraction<Vec2> for Translator {
+	type Output = W;
+	fn right_contraction(self, rhs: Vec2) -> Self::Output {
+		// W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0)
+		self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y)
+	}
+}
+
+impl ScalarProduct<Vec2> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(Vec2) -> W
+impl Commutator<Vec2> for Translator {
+	type Output = W;
+	fn commutator(self, rhs: Vec2) -> Self::Output {
+		// W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0)
+		self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y)
+	}
+}
+
+// Translator.anti_dot(Vec2) -> XY
+impl AntiDot<Vec2> for Translator {
+	type Output = XY;
+	fn anti_dot(self, rhs: Vec2) -> Self::Output {
+		// XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0)
+		self.wx.anti_dot(rhs.x) + self.yw.anti_dot(rhs.y)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Vec3:
+
+// Translator.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Translator {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.geometric(rhs.x),
+			y: self.s.geometric(rhs.y),
+			w: self.s.geometric(rhs.w) + self.wx.geometric(rhs.x) + self.yw.geometric(rhs.y),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.geometric(rhs.y) + self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Translator {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Vec3) -> Motor
+impl AntiGeometric<Vec3> for Translator {
+	type Output = Motor;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Motor {
+		//     s : S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0),
+		//     yw: -YW(self.wx.0 * rhs.w.0),
+		//     wx: WX(self.yw.0 * rhs.w.0),
+		//     xy: -XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		// }
+		Motor {
+			s: self.wx.anti_geometric(rhs.y) + self.yw.anti_geometric(rhs.x),
+			yw: self.wx.anti_geometric(rhs.w),
+			wx: self.yw.anti_geometric(rhs.w),
+			xy: self.s.anti_geometric(rhs.w) + self.wx.anti_geometric(rhs.x) + self.yw.anti_geometric(rhs.y),
+		}
+	}
+}
+
+// Translator.dot(Vec3) -> Vec3
+impl Dot<Vec3> for Translator {
+	type Output = Vec3;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		//     w: W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: self.s.dot(rhs.x),
+			y: self.s.dot(rhs.y),
+			w: self.s.dot(rhs.w) + self.wx.dot(rhs.x) + self.yw.dot(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Translator {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Translator {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(rhs.w),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.wedge(rhs.y) + self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Translator {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Vec3) -> S
+impl AntiWedge<Vec3> for Translator {
+	type Output = S;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0)
+		self.wx.anti_wedge(rhs.y) + self.yw.anti_wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Translator {
+	type Output = S;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Vec3) -> Vec3
+impl LeftContraction<Vec3> for Translator {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		//     w: W(self.s.0 * rhs.w.0),
+		// }
+		Vec3 {
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(rhs.w),
+		}
+	}
+}
+
+// Translator.right_contraction(Vec3) -> W
+impl RightContraction<Vec3> for Translator {
+	type Output = W;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0)
+		self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y)
+	}
+}
+
+impl ScalarProduct<Vec3> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(Vec3) -> W
+impl Commutator<Vec3> for Translator {
+	type Output = W;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0)
+		self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y)
+	}
+}
+
+// Translator.anti_dot(Vec3) -> Line
+impl AntiDot<Vec3> for Translator {
+	type Output = Line;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.wx.0 * rhs.w.0),
+		//     dy: WX(self.yw.0 * rhs.w.0),
+		//     m : -XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		// }
+		Line {
+			dx: self.wx.anti_dot(rhs.w),
+			dy: self.yw.anti_dot(rhs.w),
+			m: self.s.anti_dot(rhs.w) + self.wx.anti_dot(rhs.x) + self.yw.anti_dot(rhs.y),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Point2:
+
+// Translator.geometric(Point2) -> Multivector
+impl Geometric<Point2> for Translator {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.geometric(rhs.x),
+			y: self.s.geometric(rhs.y),
+			w: self.s.geometric(W(1.0)) + self.wx.geometric(rhs.x) + self.yw.geometric(rhs.y),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.geometric(rhs.y) + self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Point2> for Translator {
+	type Output = Multivector;
+	fn mul(self, rhs: Point2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Point2) -> Motor
+impl AntiGeometric<Point2> for Translator {
+	type Output = Motor;
+	fn anti_geometric(self, rhs: Point2) -> Self::Output {
+		// Motor {
+		//     s : S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0),
+		//     yw: -YW(self.wx.0),
+		//     wx: WX(self.yw.0),
+		//     xy: -XY(self.s.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		// }
+		Motor {
+			s: self.wx.anti_geometric(rhs.y) + self.yw.anti_geometric(rhs.x),
+			yw: self.wx.anti_geometric(W(1.0)),
+			wx: self.yw.anti_geometric(W(1.0)),
+			xy: self.s.anti_geometric(W(1.0)) + self.wx.anti_geometric(rhs.x) + self.yw.anti_geometric(rhs.y),
+		}
+	}
+}
+
+// Translator.dot(Point2) -> Vec3
+impl Dot<Point2> for Translator {
+	type Output = Vec3;
+	fn dot(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		//     w: W(self.s.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		// }
+		Vec3 {
+			x: self.s.dot(rhs.x),
+			y: self.s.dot(rhs.y),
+			w: self.s.dot(W(1.0)) + self.wx.dot(rhs.x) + self.yw.dot(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point2> for Translator {
+	type Output = Vec3;
+	fn bitor(self, rhs: Point2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Point2) -> Multivector
+impl Wedge<Point2> for Translator {
+	type Output = Multivector;
+	fn wedge(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(W(1.0)),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.wedge(rhs.y) + self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point2> for Translator {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Point2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Point2) -> S
+impl AntiWedge<Point2> for Translator {
+	type Output = S;
+	fn anti_wedge(self, rhs: Point2) -> Self::Output {
+		// S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0)
+		self.wx.anti_wedge(rhs.y) + self.yw.anti_wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitAnd<Point2> for Translator {
+	type Output = S;
+	fn bitand(self, rhs: Point2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Point2) -> Vec3
+impl LeftContraction<Point2> for Translator {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Point2) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		//     w: W(self.s.0),
+		// }
+		Vec3 {
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(W(1.0)),
+		}
+	}
+}
+
+// Translator.right_contraction(Point2) -> W
+impl RightContraction<Point2> for Translator {
+	type Output = W;
+	fn right_contraction(self, rhs: Point2) -> Self::Output {
+		// W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0)
+		self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y)
+	}
+}
+
+impl ScalarProduct<Point2> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(Point2) -> W
+impl Commutator<Point2> for Translator {
+	type Output = W;
+	fn commutator(self, rhs: Point2) -> Self::Output {
+		// W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0)
+		self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y)
+	}
+}
+
+// Translator.anti_dot(Point2) -> Line
+impl AntiDot<Point2> for Translator {
+	type Output = Line;
+	fn anti_dot(self, rhs: Point2) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.wx.0),
+		//     dy: WX(self.yw.0),
+		//     m : -XY(self.s.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		// }
+		Line {
+			dx: self.wx.anti_dot(W(1.0)),
+			dy: self.yw.anti_dot(W(1.0)),
+			m: self.s.anti_dot(W(1.0)) + self.wx.anti_dot(rhs.x) + self.yw.anti_dot(rhs.y),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Line:
+
+// Translator.geometric(Line) -> Line
+impl Geometric<Line> for Translator {
+	type Output = Line;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: YW(self.s.0 * rhs.dx.0) - YW(self.wx.0 * rhs.m.0),
+		//     dy: WX(self.s.0 * rhs.dy.0) + WX(self.yw.0 * rhs.m.0),
+		//     m : XY(self.s.0 * rhs.m.0),
+		// }
+		Line {
+			dx: self.s.geometric(rhs.dx) + self.wx.geometric(rhs.m),
+			dy: self.s.geometric(rhs.dy) + self.yw.geometric(rhs.m),
+			m: self.s.geometric(rhs.m),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Translator {
+	type Output = Line;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Line) -> Multivector
+impl AntiGeometric<Line> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.dx.0) + X(self.wx.0 * rhs.m.0),
+		//     y  : Y(self.s.0 * rhs.dy.0) - Y(self.yw.0 * rhs.m.0),
+		//     w  : -W(self.wx.0 * rhs.dx.0) + W(self.yw.0 * rhs.dy.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.dy.0) + XYW(self.yw.0 * rhs.dx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_geometric(rhs.dx) + self.wx.anti_geometric(rhs.m),
+			y: self.s.anti_geometric(rhs.dy) + self.yw.anti_geometric(rhs.m),
+			w: self.wx.anti_geometric(rhs.dx) + self.yw.anti_geometric(rhs.dy),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_geometric(rhs.dy) + self.yw.anti_geometric(rhs.dx),
+		}
+	}
+}
+
+// Translator.dot(Line) -> Line
+impl Dot<Line> for Translator {
+	type Output = Line;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: YW(self.s.0 * rhs.dx.0),
+		//     dy: WX(self.s.0 * rhs.dy.0),
+		//     m : XY(self.s.0 * rhs.m.0),
+		// }
+		Line {
+			dx: self.s.dot(rhs.dx),
+			dy: self.s.dot(rhs.dy),
+			m: self.s.dot(rhs.m),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Translator {
+	type Output = Line;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Line) -> Line
+impl Wedge<Line> for Translator {
+	type Output = Line;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: YW(self.s.0 * rhs.dx.0),
+		//     dy: WX(self.s.0 * rhs.dy.0),
+		//     m : XY(self.s.0 * rhs.m.0),
+		// }
+		Line {
+			dx: self.s.wedge(rhs.dx),
+			dy: self.s.wedge(rhs.dy),
+			m: self.s.wedge(rhs.m),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line> for Translator {
+	type Output = Line;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Line) -> Vec3
+impl AntiWedge<Line> for Translator {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.wx.0 * rhs.m.0),
+		//     y: -Y(self.yw.0 * rhs.m.0),
+		//     w: -W(self.wx.0 * rhs.dx.0) + W(self.yw.0 * rhs.dy.0),
+		// }
+		Vec3 {
+			x: self.wx.anti_wedge(rhs.m),
+			y: self.yw.anti_wedge(rhs.m),
+			w: self.wx.anti_wedge(rhs.dx) + self.yw.anti_wedge(rhs.dy),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line> for Translator {
+	type Output = Vec3;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Line) -> Line
+impl LeftContraction<Line> for Translator {
+	type Output = Line;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: YW(self.s.0 * rhs.dx.0),
+		//     dy: WX(self.s.0 * rhs.dy.0),
+		//     m : XY(self.s.0 * rhs.m.0),
+		// }
+		Line {
+			dx: self.s.left_contraction(rhs.dx),
+			dy: self.s.left_contraction(rhs.dy),
+			m: self.s.left_contraction(rhs.m),
+		}
+	}
+}
+
+impl RightContraction<Line> for Translator {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line> for Translator {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.commutator(Line) -> Line
+impl Commutator<Line> for Translator {
+	type Output = Line;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.wx.0 * rhs.m.0),
+		//     dy: WX(self.yw.0 * rhs.m.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.wx.commutator(rhs.m),
+			dy: self.yw.commutator(rhs.m),
+			m: Default::default(),
+		}
+	}
+}
+
+// Translator.anti_dot(Line) -> Multivector
+impl AntiDot<Line> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.dx.0),
+		//     y  : Y(self.s.0 * rhs.dy.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.dy.0) + XYW(self.yw.0 * rhs.dx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.dx),
+			y: self.s.anti_dot(rhs.dy),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_dot(rhs.dy) + self.yw.anti_dot(rhs.dx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Translator:
+
+// Translator.geometric(Translator) -> Translator
+impl Geometric<Translator> for Translator {
+	type Output = Translator;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		// }
+		Translator {
+			s: self.s.geometric(rhs.s),
+			yw: self.s.geometric(rhs.yw) + self.yw.geometric(rhs.s),
+			wx: self.s.geometric(rhs.wx) + self.wx.geometric(rhs.s),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Translator {
+	type Output = Translator;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Translator) -> Multivector
+impl AntiGeometric<Translator> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_geometric(rhs.yw) + self.yw.anti_geometric(rhs.s),
+			y: self.s.anti_geometric(rhs.wx) + self.wx.anti_geometric(rhs.s),
+			w: self.wx.anti_geometric(rhs.yw) + self.yw.anti_geometric(rhs.wx),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_geometric(rhs.wx) + self.yw.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Translator.dot(Translator) -> Translator
+impl Dot<Translator> for Translator {
+	type Output = Translator;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		// }
+		Translator {
+			s: self.s.dot(rhs.s),
+			yw: self.s.dot(rhs.yw) + self.yw.dot(rhs.s),
+			wx: self.s.dot(rhs.wx) + self.wx.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Translator {
+	type Output = Translator;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Translator) -> Translator
+impl Wedge<Translator> for Translator {
+	type Output = Translator;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		// }
+		Translator {
+			s: self.s.wedge(rhs.s),
+			yw: self.s.wedge(rhs.yw) + self.yw.wedge(rhs.s),
+			wx: self.s.wedge(rhs.wx) + self.wx.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Translator {
+	type Output = Translator;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Translator) -> W
+impl AntiWedge<Translator> for Translator {
+	type Output = W;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0)
+		self.wx.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.wx)
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Translator {
+	type Output = W;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Translator) -> Translator
+impl LeftContraction<Translator> for Translator {
+	type Output = Translator;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0),
+		//     wx: WX(self.s.0 * rhs.wx.0),
+		// }
+		Translator {
+			s: self.s.left_contraction(rhs.s),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
+		}
+	}
+}
+
+// Translator.right_contraction(Translator) -> Translator
+impl RightContraction<Translator> for Translator {
+	type Output = Translator;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Translator {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.wx.0 * rhs.s.0),
+		// }
+		Translator {
+			s: self.s.right_contraction(rhs.s),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Translator.scalar_product(Translator) -> S
+impl ScalarProduct<Translator> for Translator {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator) -> Self::Output {
+		// S(self.s.0 * rhs.s.0)
+		self.s.scalar_product(rhs.s)
+	}
+}
+
+impl Commutator<Translator> for Translator {
+	type Output = Zero;
+	fn commutator(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator.anti_dot(Translator) -> Multivector
+impl AntiDot<Translator> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.yw) + self.yw.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.wx) + self.wx.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_dot(rhs.wx) + self.yw.anti_dot(rhs.yw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Rotor:
+
+// Translator.geometric(Rotor) -> Motor
+impl Geometric<Rotor> for Translator {
+	type Output = Motor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: -YW(self.wx.0 * rhs.xy.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.wx.0 * rhs.s.0) + WX(self.yw.0 * rhs.xy.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Motor {
+			s: self.s.geometric(rhs.s),
+			yw: self.wx.geometric(rhs.xy) + self.yw.geometric(rhs.s),
+			wx: self.wx.geometric(rhs.s) + self.yw.geometric(rhs.xy),
+			xy: self.s.geometric(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Translator {
+	type Output = Motor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Rotor) -> Vec2
+impl AntiGeometric<Rotor> for Translator {
+	type Output = Vec2;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.wx.0 * rhs.xy.0) + X(self.yw.0 * rhs.s.0),
+		//     y: Y(self.wx.0 * rhs.s.0) - Y(self.yw.0 * rhs.xy.0),
+		// }
+		Vec2 {
+			x: self.wx.anti_geometric(rhs.xy) + self.yw.anti_geometric(rhs.s),
+			y: self.wx.anti_geometric(rhs.s) + self.yw.anti_geometric(rhs.xy),
+		}
+	}
+}
+
+// Translator.dot(Rotor) -> Motor
+impl Dot<Rotor> for Translator {
+	type Output = Motor;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.wx.0 * rhs.s.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Motor {
+			s: self.s.dot(rhs.s),
+			yw: self.yw.dot(rhs.s),
+			wx: self.wx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Translator {
+	type Output = Motor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Rotor) -> Motor
+impl Wedge<Rotor> for Translator {
+	type Output = Motor;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.wx.0 * rhs.s.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Motor {
+			s: self.s.wedge(rhs.s),
+			yw: self.yw.wedge(rhs.s),
+			wx: self.wx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Translator {
+	type Output = Motor;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Rotor) -> Vec2
+impl AntiWedge<Rotor> for Translator {
+	type Output = Vec2;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.wx.0 * rhs.xy.0),
+		//     y: -Y(self.yw.0 * rhs.xy.0),
+		// }
+		Vec2 {
+			x: self.wx.anti_wedge(rhs.xy),
+			y: self.yw.anti_wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Translator {
+	type Output = Vec2;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Rotor) -> Rotor
+impl LeftContraction<Rotor> for Translator {
+	type Output = Rotor;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Rotor {
+			s: self.s.left_contraction(rhs.s),
+			xy: self.s.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Translator.right_contraction(Rotor) -> Translator
+impl RightContraction<Rotor> for Translator {
+	type Output = Translator;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Translator {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.wx.0 * rhs.s.0),
+		// }
+		Translator {
+			s: self.s.right_contraction(rhs.s),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Translator.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Translator {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// S(self.s.0 * rhs.s.0)
+		self.s.scalar_product(rhs.s)
+	}
+}
+
+// Translator.commutator(Rotor) -> Line
+impl Commutator<Rotor> for Translator {
+	type Output = Line;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.wx.0 * rhs.xy.0),
+		//     dy: WX(self.yw.0 * rhs.xy.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.wx.commutator(rhs.xy),
+			dy: self.yw.commutator(rhs.xy),
+			m: Default::default(),
+		}
+	}
+}
+
+// Translator.anti_dot(Rotor) -> Vec2
+impl AntiDot<Rotor> for Translator {
+	type Output = Vec2;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.yw.0 * rhs.s.0),
+		//     y: Y(self.wx.0 * rhs.s.0),
+		// }
+		Vec2 {
+			x: self.yw.anti_dot(rhs.s),
+			y: self.wx.anti_dot(rhs.s),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Motor:
+
+// Translator.geometric(Motor) -> Motor
+impl Geometric<Motor> for Translator {
+	type Output = Motor;
+	fn geometric(self, rhs: Motor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) - YW(self.wx.0 * rhs.xy.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0) + WX(self.yw.0 * rhs.xy.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Motor {
+			s: self.s.geometric(rhs.s),
+			yw: self.s.geometric(rhs.yw) + self.wx.geometric(rhs.xy) + self.yw.geometric(rhs.s),
+			wx: self.s.geometric(rhs.wx) + self.wx.geometric(rhs.s) + self.yw.geometric(rhs.xy),
+			xy: self.s.geometric(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor> for Translator {
+	type Output = Motor;
+	fn mul(self, rhs: Motor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Motor) -> Multivector
+impl AntiGeometric<Motor> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.wx.0 * rhs.xy.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_geometric(rhs.yw) + self.wx.anti_geometric(rhs.xy) + self.yw.anti_geometric(rhs.s),
+			y: self.s.anti_geometric(rhs.wx) + self.wx.anti_geometric(rhs.s) + self.yw.anti_geometric(rhs.xy),
+			w: self.wx.anti_geometric(rhs.yw) + self.yw.anti_geometric(rhs.wx),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_geometric(rhs.wx) + self.yw.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Translator.dot(Motor) -> Motor
+impl Dot<Motor> for Translator {
+	type Output = Motor;
+	fn dot(self, rhs: Motor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Motor {
+			s: self.s.dot(rhs.s),
+			yw: self.s.dot(rhs.yw) + self.yw.dot(rhs.s),
+			wx: self.s.dot(rhs.wx) + self.wx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor> for Translator {
+	type Output = Motor;
+	fn bitor(self, rhs: Motor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Motor) -> Motor
+impl Wedge<Motor> for Translator {
+	type Output = Motor;
+	fn wedge(self, rhs: Motor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Motor {
+			s: self.s.wedge(rhs.s),
+			yw: self.s.wedge(rhs.yw) + self.yw.wedge(rhs.s),
+			wx: self.s.wedge(rhs.wx) + self.wx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Motor> for Translator {
+	type Output = Motor;
+	fn bitxor(self, rhs: Motor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Motor) -> Vec3
+impl AntiWedge<Motor> for Translator {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.wx.0 * rhs.xy.0),
+		//     y: -Y(self.yw.0 * rhs.xy.0),
+		//     w: -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		// }
+		Vec3 {
+			x: self.wx.anti_wedge(rhs.xy),
+			y: self.yw.anti_wedge(rhs.xy),
+			w: self.wx.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor> for Translator {
+	type Output = Vec3;
+	fn bitand(self, rhs: Motor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Motor) -> Motor
+impl LeftContraction<Motor> for Translator {
+	type Output = Motor;
+	fn left_contraction(self, rhs: Motor) -> Self::Output {
+		// Motor {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.s.0 * rhs.yw.0),
+		//     wx: WX(self.s.0 * rhs.wx.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Motor {
+			s: self.s.left_contraction(rhs.s),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
+			xy: self.s.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Translator.right_contraction(Motor) -> Translator
+impl RightContraction<Motor> for Translator {
+	type Output = Translator;
+	fn right_contraction(self, rhs: Motor) -> Self::Output {
+		// Translator {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yw: YW(self.yw.0 * rhs.s.0),
+		//     wx: WX(self.wx.0 * rhs.s.0),
+		// }
+		Translator {
+			s: self.s.right_contraction(rhs.s),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Translator.scalar_product(Motor) -> S
+impl ScalarProduct<Motor> for Translator {
+	type Output = S;
+	fn scalar_product(self, rhs: Motor) -> Self::Output {
+		// S(self.s.0 * rhs.s.0)
+		self.s.scalar_product(rhs.s)
+	}
+}
+
+// Translator.commutator(Motor) -> Line
+impl Commutator<Motor> for Translator {
+	type Output = Line;
+	fn commutator(self, rhs: Motor) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.wx.0 * rhs.xy.0),
+		//     dy: WX(self.yw.0 * rhs.xy.0),
+		//     m : Default::default(),
+		// }
+		Line {
+			dx: self.wx.commutator(rhs.xy),
+			dy: self.yw.commutator(rhs.xy),
+			m: Default::default(),
+		}
+	}
+}
+
+// Translator.anti_dot(Motor) -> Multivector
+impl AntiDot<Motor> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.yw) + self.yw.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.wx) + self.wx.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.wx.anti_dot(rhs.wx) + self.yw.anti_dot(rhs.yw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator OP Multivector:
+
+// Translator.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Translator {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) - YW(self.wx.0 * rhs.xy.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0) + WX(self.yw.0 * rhs.xy.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0) + XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s),
+			x: self.s.geometric(rhs.x),
+			y: self.s.geometric(rhs.y),
+			w: self.s.geometric(rhs.w) + self.wx.geometric(rhs.x) + self.yw.geometric(rhs.y),
+			yw: self.s.geometric(rhs.yw) + self.wx.geometric(rhs.xy) + self.yw.geometric(rhs.s),
+			wx: self.s.geometric(rhs.wx) + self.wx.geometric(rhs.s) + self.yw.geometric(rhs.xy),
+			xy: self.s.geometric(rhs.xy),
+			xyw: self.s.geometric(rhs.xyw) + self.wx.geometric(rhs.y) + self.yw.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Translator {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Translator {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0) + S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.wx.0 * rhs.xy.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0) - Y(self.yw.0 * rhs.xy.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : -YW(self.wx.0 * rhs.w.0) + YW(self.yw.0 * rhs.xyw.0),
+		//     wx : WX(self.wx.0 * rhs.xyw.0) + WX(self.yw.0 * rhs.w.0),
+		//     xy : -XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: self.s.anti_geometric(rhs.xyw) + self.wx.anti_geometric(rhs.y) + self.yw.anti_geometric(rhs.x),
+			x: self.s.anti_geometric(rhs.yw) + self.wx.anti_geometric(rhs.xy) + self.yw.anti_geometric(rhs.s),
+			y: self.s.anti_geometric(rhs.wx) + self.wx.anti_geometric(rhs.s) + self.yw.anti_geometric(rhs.xy),
+			w: self.wx.anti_geometric(rhs.yw) + self.yw.anti_geometric(rhs.wx),
+			yw: self.wx.anti_geometric(rhs.w) + self.yw.anti_geometric(rhs.xyw),
+			wx: self.wx.anti_geometric(rhs.xyw) + self.yw.anti_geometric(rhs.w),
+			xy: self.s.anti_geometric(rhs.w) + self.wx.anti_geometric(rhs.x) + self.yw.anti_geometric(rhs.y),
+			xyw: self.wx.anti_geometric(rhs.wx) + self.yw.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Translator.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Translator {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s),
+			x: self.s.dot(rhs.x),
+			y: self.s.dot(rhs.y),
+			w: self.s.dot(rhs.w) + self.wx.dot(rhs.x) + self.yw.dot(rhs.y),
+			yw: self.s.dot(rhs.yw) + self.yw.dot(rhs.s),
+			wx: self.s.dot(rhs.wx) + self.wx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy),
+			xyw: self.s.dot(rhs.xyw),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Translator {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Translator {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0),
+		//     yw : YW(self.s.0 * rhs.yw.0) + YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0) + XYW(self.wx.0 * rhs.y.0) + XYW(self.yw.0 * rhs.x.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			w: self.s.wedge(rhs.w),
+			yw: self.s.wedge(rhs.yw) + self.yw.wedge(rhs.s),
+			wx: self.s.wedge(rhs.wx) + self.wx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy),
+			xyw: self.s.wedge(rhs.xyw) + self.wx.wedge(rhs.y) + self.yw.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Translator {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Translator {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0) + S(self.wx.0 * rhs.y.0) + S(self.yw.0 * rhs.x.0),
+		//     x  : X(self.wx.0 * rhs.xy.0),
+		//     y  : -Y(self.yw.0 * rhs.xy.0),
+		//     w  : -W(self.wx.0 * rhs.yw.0) + W(self.yw.0 * rhs.wx.0),
+		//     yw : YW(self.yw.0 * rhs.xyw.0),
+		//     wx : WX(self.wx.0 * rhs.xyw.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.anti_wedge(rhs.xyw) + self.wx.anti_wedge(rhs.y) + self.yw.anti_wedge(rhs.x),
+			x: self.wx.anti_wedge(rhs.xy),
+			y: self.yw.anti_wedge(rhs.xy),
+			w: self.wx.anti_wedge(rhs.yw) + self.yw.anti_wedge(rhs.wx),
+			yw: self.yw.anti_wedge(rhs.xyw),
+			wx: self.wx.anti_wedge(rhs.xyw),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Translator {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Translator {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     w  : W(self.s.0 * rhs.w.0),
+		//     yw : YW(self.s.0 * rhs.yw.0),
+		//     wx : WX(self.s.0 * rhs.wx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyw: XYW(self.s.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			w: self.s.left_contraction(rhs.w),
+			yw: self.s.left_contraction(rhs.yw),
+			wx: self.s.left_contraction(rhs.wx),
+			xy: self.s.left_contraction(rhs.xy),
+			xyw: self.s.left_contraction(rhs.xyw),
+		}
+	}
+}
+
+// Translator.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Translator {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : Default::default(),
+		//     y  : Default::default(),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : YW(self.yw.0 * rhs.s.0),
+		//     wx : WX(self.wx.0 * rhs.s.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.wx.right_contraction(rhs.x) + self.yw.right_contraction(rhs.y),
+			yw: self.yw.right_contraction(rhs.s),
+			wx: self.wx.right_contraction(rhs.s),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Translator.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Translator {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.s.0 * rhs.s.0)
+		self.s.scalar_product(rhs.s)
+	}
+}
+
+// Translator.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Translator {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : Default::default(),
+		//     y  : Default::default(),
+		//     w  : W(self.wx.0 * rhs.x.0) - W(self.yw.0 * rhs.y.0),
+		//     yw : -YW(self.wx.0 * rhs.xy.0),
+		//     wx : WX(self.yw.0 * rhs.xy.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			w: self.wx.commutator(rhs.x) + self.yw.commutator(rhs.y),
+			yw: self.wx.commutator(rhs.xy),
+			wx: self.yw.commutator(rhs.xy),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Translator.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Translator {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyw.0),
+		//     x  : X(self.s.0 * rhs.yw.0) + X(self.yw.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.wx.0) + Y(self.wx.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : -YW(self.wx.0 * rhs.w.0) + YW(self.yw.0 * rhs.xyw.0),
+		//     wx : WX(self.wx.0 * rhs.xyw.0) + WX(self.yw.0 * rhs.w.0),
+		//     xy : -XY(self.s.0 * rhs.w.0) + XY(self.wx.0 * rhs.x.0) - XY(self.yw.0 * rhs.y.0),
+		//     xyw: XYW(self.wx.0 * rhs.wx.0) + XYW(self.yw.0 * rhs.yw.0),
+		// }
+		Multivector {
+			s: self.s.anti_dot(rhs.xyw),
+			x: self.s.anti_dot(rhs.yw) + self.yw.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.wx) + self.wx.anti_dot(rhs.s),
+			w: Default::default(),
+			yw: self.wx.anti_dot(rhs.w) + self.yw.anti_dot(rhs.xyw),
+			wx: self.wx.anti_dot(rhs.xyw) + self.yw.anti_dot(rhs.w),
+			xy: self.s.anti_dot(rhs.w) + self.wx.anti_dot(rhs.x) + self.yw.anti_dot(rhs.y),
+			xyw: self.wx.anti_dot(rhs.wx) + self.yw.anti_dot(rhs.yw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Translator.sandwich(Vec2)  (not a Vec2)
+
+// Translator.anti_sandwich(Vec2) -> Vec2
+impl AntiSandwich<Vec2> for Translator {
+	fn anti_sandwich(self, rhs: Vec2) -> Vec2 {
+		Vec2 {
+			x: -self.wx.anti_geometric(self.wx).anti_geometric(rhs.x)
+				- self.wx.anti_geometric(self.yw).anti_geometric(rhs.y) * 2.0
+				+ self.yw.anti_geometric(self.yw).anti_geometric(rhs.x),
+			y: self.wx.anti_geometric(self.wx).anti_geometric(rhs.y)
+				+ self.wx.anti_geometric(self.yw).anti_geometric(rhs.x) * 2.0
+				- self.yw.anti_geometric(self.yw).anti_geometric(rhs.y),
+		}
+	}
+}
+
+// Translator.sandwich(Vec3) -> Vec3
+impl Sandwich<Vec3> for Translator {
+	fn sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: self.s.geometric(self.s).geometric(rhs.x),
+			y: self.s.geometric(self.s).geometric(rhs.y),
+			w: self.s.geometric(self.s).geometric(rhs.w)
+				+ self.s.geometric(self.wx).geometric(rhs.x) * 2.0
+				+ self.s.geometric(self.yw).geometric(rhs.y) * 2.0,
+		}
+	}
+}
+
+// Translator.anti_sandwich(Vec3) -> Vec3
+impl AntiSandwich<Vec3> for Translator {
+	fn anti_sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: self.s.anti_geometric(self.wx).anti_geometric(rhs.w) * -2.0
+				- self.wx.anti_geometric(self.wx).anti_geometric(rhs.x)
+				- self.wx.anti_geometric(self.yw).anti_geometric(rhs.y) * 2.0
+				+ self.yw.anti_geometric(self.yw).anti_geometric(rhs.x),
+			y: self.s.anti_geometric(self.yw).anti_geometric(rhs.w) * -2.0
+				+ self.wx.anti_geometric(self.wx).anti_geometric(rhs.y)
+				+ self.wx.anti_geometric(self.yw).anti_geometric(rhs.x) * 2.0
+				- self.yw.anti_geometric(self.yw).anti_geometric(rhs.y),
+			w: -self.wx.anti_geometric(self.wx).anti_geometric(rhs.w)
+				- self.yw.anti_geometric(self.yw).anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Omitted: Translator.sandwich(Point2)  (not a Point2)
+// Omitted: Translator.anti_sandwich(Point2)  (not a Point2)
+
+// Translator.sandwich(Line) -> Line
+impl Sandwich<Line> for Translator {
+	fn sandwich(self, rhs: Line) -> Line {
+		Line {
+			dx: self.s.geometric(self.s).geometric(rhs.dx) + self.s.geometric(self.wx).geometric(rhs.m) * 2.0,
+			dy: self.s.geometric(self.s).geometric(rhs.dy) + self.s.geometric(self.yw).geometric(rhs.m) * 2.0,
+			m: self.s.geometric(self.s).geometric(rhs.m),
+		}
+	}
+}
+
+// Translator.anti_sandwich(Line) -> Line
+impl AntiSandwich<Line> for Translator {
+	fn anti_sandwich(self, rhs: Line) -> Line {
+		Line {
+			dx: -self.wx.anti_geometric(self.wx).anti_geometric(rhs.dx)
+				- self.wx.anti_geometric(self.yw).anti_geometric(rhs.dy) * 2.0
+				+ self.yw.anti_geometric(self.yw).anti_geometric(rhs.dx),
+			dy: self.wx.anti_geometric(self.wx).anti_geometric(rhs.dy)
+				+ self.wx.anti_geometric(self.yw).anti_geometric(rhs.dx) * 2.0
+				- self.yw.anti_geometric(self.yw).anti_geometric(rhs.dy),
+			m: self.s.anti_geometric(self.wx).anti_geometric(rhs.dx) * -2.0
+				- self.s.anti_geometric(self.yw).anti_geometric(rhs.dy) * 2.0
+				- self.wx.anti_geometric(self.wx).anti_geometric(rhs.m)
+				- self.yw.anti_geometric(self.yw).anti_geometric(rhs.m),
+		}
+	}
+}
@@ -4,6 +4,11 @@
 //! ```text
 //! Vec2.geometric(Vec2) -> Rotor
 //! Vec2.dot(Vec2) -> S
+//! Vec2.wedge(Vec2) -> XY
+//! Vec2.left_contraction(Vec2) -> S
+//! Vec2.right_contraction(Vec2) -> S
+//! Vec2.scalar_product(Vec2) -> S
+//! Vec2.commutator(Vec2) -> XY
 //! Vec2.geometric(Vec3) -> Motor
 //! Vec3.geometric(Vec2) -> Motor
 //! Vec2.anti_geometric(Vec3) -> Vec2
@@ -12,6 +17,32 @@
 //! Vec3.dot(Vec2) -> S
 //! Vec2.wedge(Vec3) -> Line
 //! Vec3.wedge(Vec2) -> Line
+//! Vec2.left_contraction(Vec3) -> S
+//! Vec3.left_contraction(Vec2) -> S
+//! Vec2.right_contraction(Vec3) -> S
+//! Vec3.right_contraction(Vec2) -> S
+//! Vec2.scalar_product(Vec3) -> S
+//! Vec3.scalar_product(Vec2) -> S
+//! Vec2.commutator(Vec3) -> Line
+//! Vec3.commutator(Vec2) -> Line
+//! Vec2.geometric(Point2) -> Motor
+//! Point2.geometric(Vec2) -> Motor
+//! Vec2.anti_geometric(Point2) -> Vec2
+//! Point2.anti_geometric(Vec2) -> Vec2
+//! Vec2.dot(Point2) -> S
+//! Point2.dot(Vec2) -> S
+//! Vec2.wedge(Point2) -> Line
+//! Point2.wedge(Vec2) -> Line
+//! Vec2.left_contraction(Point2) -> S
+//! Point2.left_contraction(Vec2) -> S
+//! Vec2.right_contraction(Point2) -> S
+//! Point2.right_contraction(Vec2) -> S
+//! Vec2.scalar_product(Point2) -> S
+//! Point2.scalar_product(Vec2) -> S
+//! Vec2.commutator(Point2) -> Line
+//! Point2.commutator(Vec2) -> Line
+//! Vec2.geometric(Line) -> Multivector
+//! Line.geometric(Vec2) -> Multivector
 //! Vec2.anti_geometric(Line) -> Rotor
 //! Line.anti_geometric(Vec2) -> Rotor
 //! Vec2.dot(Line) -> Vec3
@@ -20,28 +51,143 @@
 //! Line.wedge(Vec2) -> XYW
 //! Vec2.anti_wedge(Line) -> S
 //! Line.anti_wedge(Vec2) -> S
+//! Vec2.left_contraction(Line) -> Vec3
+//! Line.right_contraction(Vec2) -> Vec3
+//! Vec2.commutator(Line) -> Vec3
+//! Line.commutator(Vec2) -> Vec3
+//! Vec2.anti_dot(Line) -> XY
+//! Line.anti_dot(Vec2) -> XY
+//! Vec2.geometric(Translator) -> Multivector
+//! Translator.geometric(Vec2) -> Multivector
+//! Vec2.anti_geometric(Translator) -> Rotor
+//! Translator.anti_geometric(Vec2) -> Rotor
+//! Vec2.dot(Translator) -> Vec3
+//! Translator.dot(Vec2) -> Vec3
+//! Vec2.wedge(Translator) -> Multivector
+//! Translator.wedge(Vec2) -> Multivector
+//! Vec2.anti_wedge(Translator) -> S
+//! Translator.anti_wedge(Vec2) -> S
+//! Vec2.left_contraction(Translator) -> W
+//! Translator.left_contraction(Vec2) -> Vec2
+//! Vec2.right_contraction(Translator) -> Vec2
+//! Translator.right_contraction(Vec2) -> W
+//! Vec2.commutator(Translator) -> W
+//! Translator.commutator(Vec2) -> W
+//! Vec2.anti_dot(Translator) -> XY
+//! Translator.anti_dot(Vec2) -> XY
 //! Vec2.geometric(Rotor) -> Vec2
 //! Rotor.geometric(Vec2) -> Vec2
 //! Vec2.dot(Rotor) -> Vec2
 //! Rotor.dot(Vec2) -> Vec2
 //! Vec2.wedge(Rotor) -> Vec2
 //! Rotor.wedge(Vec2) -> Vec2
+//! Vec2.left_contraction(Rotor) -> Vec2
+//! Rotor.left_contraction(Vec2) -> Vec2
+//! Vec2.right_contraction(Rotor) -> Vec2
+//! Rotor.right_contraction(Vec2) -> Vec2
+//! Vec2.commutator(Rotor) -> Vec2
+//! Rotor.commutator(Vec2) -> Vec2
+//! Vec2.geometric(Motor) -> Multivector
+//! Motor.geometric(Vec2) -> Multivector
 //! Vec2.anti_geometric(Motor) -> Rotor
 //! Motor.anti_geometric(Vec2) -> Rotor
 //! Vec2.dot(Motor) -> Vec3
 //! Motor.dot(Vec2) -> Vec3
+//! Vec2.wedge(Motor) -> Multivector
+//! Motor.wedge(Vec2) -> Multivector
 //! Vec2.anti_wedge(Motor) -> S
 //! Motor.anti_wedge(Vec2) -> S
+//! Vec2.left_contraction(Motor) -> Vec3
+//! Motor.left_contraction(Vec2) -> Vec2
+//! Vec2.right_contraction(Motor) -> Vec2
+//! Motor.right_contraction(Vec2) -> Vec3
+//! Vec2.commutator(Motor) -> Vec3
+//! Motor.commutator(Vec2) -> Vec3
+//! Vec2.anti_dot(Motor) -> XY
+//! Motor.anti_dot(Vec2) -> XY
+//! Vec2.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Vec2) -> Multivector
+//! Vec2.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec2) -> Multivector
+//! Vec2.dot(Multivector) -> Multivector
+//! Multivector.dot(Vec2) -> Multivector
+//! Vec2.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Vec2) -> Multivector
+//! Vec2.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec2) -> Multivector
+//! Vec2.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec2) -> Multivector
+//! Vec2.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec2) -> Multivector
+//! Vec2.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Vec2) -> S
+//! Vec2.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Vec2) -> Multivector
+//! Vec2.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec2) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec2 {
 	pub x: X,
 	pub y: Y,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec2 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec2 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Vec2 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Vec2 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.x.relative_eq(&other.x, epsilon, max_relative) && self.y.relative_eq(&other.y, epsilon, max_relative)
+	}
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector2<f64>> for Vec2 {
+	fn from(v: mint::Vector2<f64>) -> Self {
+		Vec2 { x: X(v.x), y: Y(v.y) }
+	}
+}
+
+#[cfg(feature = "mint")]
+impl From<Vec2> for mint::Vector2<f64> {
+	fn from(v: Vec2) -> Self {
+		mint::Vector2 { x: v.x.0, y: v.y.0 }
+	}
+}
+
+#[cfg(feature = "mint")]
+impl mint::IntoMint for Vec2 {
+	type MintType = mint::Vector2<f64>;
+}
+
 // ---------------------------------------------------------------------
 
 impl RCompl for Vec2 {
@@ -55,6 +201,13 @@ impl RCompl for Vec2 {
 	}
 }
 
+impl std::ops::Not for Vec2 {
+	type Output = Line;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl LCompl for Vec2 {
 	type Output = Line;
 	fn lcompl(self) -> Self::Output {
@@ -78,6 +231,178 @@ impl AntiReverse for Vec2 {
 	}
 }
 
+impl Involute for Vec2 {
+	fn involute(self) -> Self {
+		Vec2 { x: -self.x, y: -self.y }
+	}
+}
+
+impl Conjugate for Vec2 {
+	fn conjugate(self) -> Self {
+		Vec2 { x: -self.x, y: -self.y }
+	}
+}
+
+impl Dual for Vec2 {
+	type Output = Line;
+	fn dual(self) -> Self::Output {
+		Line {
+			dx: self.x.dual(),
+			dy: self.y.dual(),
+			m: Default::default(),
+		}
+	}
+}
+
+impl Bulk for Vec2 {
+	type Output = Vec2;
+	fn bulk(self) -> Self::Output {
+		Vec2 { x: self.x, y: self.y }
+	}
+}
+
+// Omitted: Vec2.weight() -> 0
+
+impl BulkDual for Vec2 {
+	type Output = Line;
+	fn bulk_dual(self) -> Self::Output {
+		Line {
+			dx: self.x.bulk_dual(),
+			dy: self.y.bulk_dual(),
+			m: Default::default(),
+		}
+	}
+}
+
+// Omitted: Vec2.weight_dual() -> 0
+
+impl Square for Vec2 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(self.x.geometric(self.x) + self.y.geometric(self.y)).0
+	}
+}
+
+impl Norm for Vec2 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Vec2 {
+			x: self.x * scale,
+			y: self.y * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Vec2 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 2] {
+		[self.x.0, self.y.0]
+	}
+}
+
+impl From<[f64; 2]> for Vec2 {
+	fn from(a: [f64; 2]) -> Self {
+		Vec2 { x: X(a[0]), y: Y(a[1]) }
+	}
+}
+
+impl From<Vec2> for [f64; 2] {
+	fn from(v: Vec2) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Vec2> for Multivector {
+	fn from(v: Vec2) -> Self {
+		Multivector {
+			x: v.x,
+			y: v.y,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Vec2 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Vec2` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0 && mv.w.0 == 0.0 && mv.yw.0 == 0.0 && mv.wx.0 == 0.0 && mv.xy.0 == 0.0 && mv.xyw.0 == 0.0 {
+			Ok(Vec2 { x: mv.x, y: mv.y })
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec2> for glam::DVec2 {
+	fn from(v: Vec2) -> Self {
+		Self::new(v.x.0, v.y.0)
+	}
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec2> for Vec2 {
+	fn from(v: glam::DVec2) -> Self {
+		Vec2 { x: X(v.x), y: Y(v.y) }
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec2> for nalgebra::Vector2<f64> {
+	fn from(v: Vec2) -> Self {
+		Self::new(v.x.0, v.y.0)
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector2<f64>> for Vec2 {
+	fn from(v: nalgebra::Vector2<f64>) -> Self {
+		Vec2 { x: X(v.x), y: Y(v.y) }
+	}
+}
+
+#[cfg(feature = "cgmath")]
+impl From<Vec2> for cgmath::Vector2<f64> {
+	fn from(v: Vec2) -> Self {
+		Self::new(v.x.0, v.y.0)
+	}
+}
+
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Vector2<f64>> for Vec2 {
+	fn from(v: cgmath::Vector2<f64>) -> Self {
+		Vec2 { x: X(v.x), y: Y(v.y) }
+	}
+}
+
 // ---------------------------------------------------------------------
 // Vec2 OP Vec2:
 
@@ -87,16 +412,28 @@ impl Geometric<Vec2> for Vec2 {
 	fn geometric(self, rhs: Vec2) -> Self::Output {
 		// Rotor {
 		//     s : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
-		//     xy: XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
 		Rotor {
 			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
-			xy: self.x.geometric(rhs.y) - self.y.geometric(rhs.x),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
 		}
 	}
 }
 
-// Omitted: Vec2 anti_geometric Vec2 = 0  (unnamed type)
+impl std::ops::Mul<Vec2> for Vec2 {
+	type Output = Rotor;
+	fn mul(self, rhs: Vec2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl AntiGeometric<Vec2> for Vec2 {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
 
 // Vec2.dot(Vec2) -> S
 impl Dot<Vec2> for Vec2 {
@@ -107,8 +444,85 @@ impl Dot<Vec2> for Vec2 {
 	}
 }
 
-// Omitted: Vec2 wedge Vec2 = self.x ^ rhs.y + self.y ^ rhs.x  (unnamed type)
-// Omitted: Vec2 anti_wedge Vec2 = 0  (unnamed type)
+impl std::ops::BitOr<Vec2> for Vec2 {
+	type Output = S;
+	fn bitor(self, rhs: Vec2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec2.wedge(Vec2) -> XY
+impl Wedge<Vec2> for Vec2 {
+	type Output = XY;
+	fn wedge(self, rhs: Vec2) -> Self::Output {
+		// XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0)
+		self.x.wedge(rhs.y) + self.y.wedge(rhs.x)
+	}
+}
+
+impl std::ops::BitXor<Vec2> for Vec2 {
+	type Output = XY;
+	fn bitxor(self, rhs: Vec2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec2> for Vec2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec2> for Vec2 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec2.left_contraction(Vec2) -> S
+impl LeftContraction<Vec2> for Vec2 {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y)
+	}
+}
+
+// Vec2.right_contraction(Vec2) -> S
+impl RightContraction<Vec2> for Vec2 {
+	type Output = S;
+	fn right_contraction(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y)
+	}
+}
+
+// Vec2.scalar_product(Vec2) -> S
+impl ScalarProduct<Vec2> for Vec2 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Vec2.commutator(Vec2) -> XY
+impl Commutator<Vec2> for Vec2 {
+	type Output = XY;
+	fn commutator(self, rhs: Vec2) -> Self::Output {
+		// XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0)
+		self.x.commutator(rhs.y) + self.y.commutator(rhs.x)
+	}
+}
+
+impl AntiDot<Vec2> for Vec2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
 
 // ---------------------------------------------------------------------
 // Vec2 OP Vec3:
@@ -120,28 +534,35 @@ impl Geometric<Vec3> for Vec2 {
 		// Motor {
 		//     s : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
 		//     yw: YW(self.y.0 * rhs.w.0),
-		//     wx: WX(self.x.0 * rhs.w.0),
-		//     xy: XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		//     wx: -WX(self.x.0 * rhs.w.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
 		Motor {
 			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
 			yw: self.y.geometric(rhs.w),
-			wx: -self.x.geometric(rhs.w),
-			xy: self.x.geometric(rhs.y) - self.y.geometric(rhs.x),
+			wx: self.x.geometric(rhs.w),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
 		}
 	}
 }
 
+impl std::ops::Mul<Vec3> for Vec2 {
+	type Output = Motor;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
 // Vec2.anti_geometric(Vec3) -> Vec2
 impl AntiGeometric<Vec3> for Vec2 {
 	type Output = Vec2;
 	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
 		// Vec2 {
-		//     x: X(self.y.0 * rhs.w.0),
+		//     x: -X(self.y.0 * rhs.w.0),
 		//     y: Y(self.x.0 * rhs.w.0),
 		// }
 		Vec2 {
-			x: -self.y.anti_geometric(rhs.w),
+			x: self.y.anti_geometric(rhs.w),
 			y: self.x.anti_geometric(rhs.w),
 		}
 	}
@@ -156,136 +577,776 @@ impl Dot<Vec3> for Vec2 {
 	}
 }
 
+impl std::ops::BitOr<Vec3> for Vec2 {
+	type Output = S;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Vec2.wedge(Vec3) -> Line
 impl Wedge<Vec3> for Vec2 {
 	type Output = Line;
 	fn wedge(self, rhs: Vec3) -> Self::Output {
 		// Line {
 		//     dx: YW(self.y.0 * rhs.w.0),
-		//     dy: WX(self.x.0 * rhs.w.0),
-		//     m : XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		//     dy: -WX(self.x.0 * rhs.w.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
 		Line {
 			dx: self.y.wedge(rhs.w),
-			dy: -self.x.wedge(rhs.w),
-			m: self.x.wedge(rhs.y) - self.y.wedge(rhs.x),
+			dy: self.x.wedge(rhs.w),
+			m: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
 		}
 	}
 }
 
-// Omitted: Vec2 anti_wedge Vec3 = 0  (unnamed type)
-
-// ---------------------------------------------------------------------
-// Vec2 OP Line:
-
-// Omitted: Vec2 geometric Line = self.x * rhs.dx + self.x * rhs.dy + self.x * rhs.m + self.y * rhs.dx + self.y * rhs.dy + self.y * rhs.m  (unnamed type)
+impl std::ops::BitXor<Vec3> for Vec2 {
+	type Output = Line;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
-// Vec2.anti_geometric(Line) -> Rotor
-impl AntiGeometric<Line> for Vec2 {
-	type Output = Rotor;
-	fn anti_geometric(self, rhs: Line) -> Self::Output {
-		// Rotor {
-		//     s : S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0),
-		//     xy: XY(self.x.0 * rhs.dy.0) + XY(self.y.0 * rhs.dx.0),
-		// }
-		Rotor {
-			s: self.x.anti_geometric(rhs.dx) + self.y.anti_geometric(rhs.dy),
-			xy: -self.x.anti_geometric(rhs.dy) + self.y.anti_geometric(rhs.dx),
-		}
+impl AntiWedge<Vec3> for Vec2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
 	}
 }
 
-// Vec2.dot(Line) -> Vec3
-impl Dot<Line> for Vec2 {
-	type Output = Vec3;
-	fn dot(self, rhs: Line) -> Self::Output {
-		// Vec3 {
-		//     x: X(self.y.0 * rhs.m.0),
-		//     y: Y(self.x.0 * rhs.m.0),
-		//     w: W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
-		// }
-		Vec3 {
-			x: -self.y.dot(rhs.m),
-			y: self.x.dot(rhs.m),
-			w: -self.x.dot(rhs.dy) + self.y.dot(rhs.dx),
-		}
+impl std::ops::BitAnd<Vec3> for Vec2 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-// Vec2.wedge(Line) -> XYW
-impl Wedge<Line> for Vec2 {
-	type Output = XYW;
-	fn wedge(self, rhs: Line) -> Self::Output {
-		// XYW(self.x.0 * rhs.dx.0) + XYW(self.y.0 * rhs.dy.0)
-		self.x.wedge(rhs.dx) + self.y.wedge(rhs.dy)
+// Vec2.left_contraction(Vec3) -> S
+impl LeftContraction<Vec3> for Vec2 {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y)
 	}
 }
 
-// Vec2.anti_wedge(Line) -> S
-impl AntiWedge<Line> for Vec2 {
+// Vec2.right_contraction(Vec3) -> S
+impl RightContraction<Vec3> for Vec2 {
 	type Output = S;
-	fn anti_wedge(self, rhs: Line) -> Self::Output {
-		// S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0)
-		self.x.anti_wedge(rhs.dx) + self.y.anti_wedge(rhs.dy)
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y)
 	}
 }
 
-// ---------------------------------------------------------------------
-// Vec2 OP Rotor:
+// Vec2.scalar_product(Vec3) -> S
+impl ScalarProduct<Vec3> for Vec2 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
 
-// Vec2.geometric(Rotor) -> Vec2
-impl Geometric<Rotor> for Vec2 {
-	type Output = Vec2;
-	fn geometric(self, rhs: Rotor) -> Self::Output {
-		// Vec2 {
-		//     x: X(self.x.0 * rhs.s.0) + X(self.y.0 * rhs.xy.0),
-		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+// Vec2.commutator(Vec3) -> Line
+impl Commutator<Vec3> for Vec2 {
+	type Output = Line;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     dx: YW(self.y.0 * rhs.w.0),
+		//     dy: -WX(self.x.0 * rhs.w.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
-		Vec2 {
-			x: self.x.geometric(rhs.s) - self.y.geometric(rhs.xy),
-			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+		Line {
+			dx: self.y.commutator(rhs.w),
+			dy: self.x.commutator(rhs.w),
+			m: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
 		}
 	}
 }
 
-// Omitted: Vec2 anti_geometric Rotor = 0  (unnamed type)
+impl AntiDot<Vec3> for Vec2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
 
-// Vec2.dot(Rotor) -> Vec2
-impl Dot<Rotor> for Vec2 {
-	type Output = Vec2;
-	fn dot(self, rhs: Rotor) -> Self::Output {
-		// Vec2 {
-		//     x: X(self.x.0 * rhs.s.0) + X(self.y.0 * rhs.xy.0),
-		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+// ---------------------------------------------------------------------
+// Vec2 OP Point2:
+
+// Vec2.geometric(Point2) -> Motor
+impl Geometric<Point2> for Vec2 {
+	type Output = Motor;
+	fn geometric(self, rhs: Point2) -> Self::Output {
+		// Motor {
+		//     s : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     yw: YW(self.y.0),
+		//     wx: -WX(self.x.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
-		Vec2 {
-			x: self.x.dot(rhs.s) - self.y.dot(rhs.xy),
-			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
+		Motor {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			yw: self.y.geometric(W(1.0)),
+			wx: self.x.geometric(W(1.0)),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
 		}
 	}
 }
 
-// Vec2.wedge(Rotor) -> Vec2
-impl Wedge<Rotor> for Vec2 {
+impl std::ops::Mul<Point2> for Vec2 {
+	type Output = Motor;
+	fn mul(self, rhs: Point2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec2.anti_geometric(Point2) -> Vec2
+impl AntiGeometric<Point2> for Vec2 {
 	type Output = Vec2;
-	fn wedge(self, rhs: Rotor) -> Self::Output {
+	fn anti_geometric(self, _rhs: Point2) -> Self::Output {
 		// Vec2 {
-		//     x: X(self.x.0 * rhs.s.0),
-		//     y: Y(self.y.0 * rhs.s.0),
+		//     x: -X(self.y.0),
+		//     y: Y(self.x.0),
 		// }
 		Vec2 {
-			x: self.x.wedge(rhs.s),
-			y: self.y.wedge(rhs.s),
+			x: self.y.anti_geometric(W(1.0)),
+			y: self.x.anti_geometric(W(1.0)),
 		}
 	}
 }
 
-// Omitted: Vec2 anti_wedge Rotor = 0  (unnamed type)
-
-// ---------------------------------------------------------------------
-// Vec2 OP Motor:
+// Vec2.dot(Point2) -> S
+impl Dot<Point2> for Vec2 {
+	type Output = S;
+	fn dot(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.dot(rhs.x) + self.y.dot(rhs.y)
+	}
+}
 
-// Omitted: Vec2 geometric Motor = self.x * rhs.s + self.x * rhs.wx + self.x * rhs.xy + self.x * rhs.yw + self.y * rhs.s + self.y * rhs.wx + self.y * rhs.xy + self.y * rhs.yw  (unnamed type)
+impl std::ops::BitOr<Point2> for Vec2 {
+	type Output = S;
+	fn bitor(self, rhs: Point2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec2.wedge(Point2) -> Line
+impl Wedge<Point2> for Vec2 {
+	type Output = Line;
+	fn wedge(self, rhs: Point2) -> Self::Output {
+		// Line {
+		//     dx: YW(self.y.0),
+		//     dy: -WX(self.x.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: self.y.wedge(W(1.0)),
+			dy: self.x.wedge(W(1.0)),
+			m: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point2> for Vec2 {
+	type Output = Line;
+	fn bitxor(self, rhs: Point2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point2> for Vec2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point2> for Vec2 {
+	type Output = Zero;
+	fn bitand(self, rhs: Point2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec2.left_contraction(Point2) -> S
+impl LeftContraction<Point2> for Vec2 {
+	type Output = S;
+	fn left_contraction(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y)
+	}
+}
+
+// Vec2.right_contraction(Point2) -> S
+impl RightContraction<Point2> for Vec2 {
+	type Output = S;
+	fn right_contraction(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y)
+	}
+}
+
+// Vec2.scalar_product(Point2) -> S
+impl ScalarProduct<Point2> for Vec2 {
+	type Output = S;
+	fn scalar_product(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Vec2.commutator(Point2) -> Line
+impl Commutator<Point2> for Vec2 {
+	type Output = Line;
+	fn commutator(self, rhs: Point2) -> Self::Output {
+		// Line {
+		//     dx: YW(self.y.0),
+		//     dy: -WX(self.x.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: self.y.commutator(W(1.0)),
+			dy: self.x.commutator(W(1.0)),
+			m: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+		}
+	}
+}
+
+impl AntiDot<Point2> for Vec2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Point2) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec2 OP Line:
+
+// Vec2.geometric(Line) -> Multivector
+impl Geometric<Line> for Vec2 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.m.0),
+		//     y  : Y(self.x.0 * rhs.m.0),
+		//     w  : -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.x.0 * rhs.dx.0) + XYW(self.y.0 * rhs.dy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.geometric(rhs.m),
+			y: self.x.geometric(rhs.m),
+			w: self.x.geometric(rhs.dy) + self.y.geometric(rhs.dx),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.geometric(rhs.dx) + self.y.geometric(rhs.dy),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Vec2 {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec2.anti_geometric(Line) -> Rotor
+impl AntiGeometric<Line> for Vec2 {
+	type Output = Rotor;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Rotor {
+		//     s : S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0),
+		//     xy: -XY(self.x.0 * rhs.dy.0) + XY(self.y.0 * rhs.dx.0),
+		// }
+		Rotor {
+			s: self.x.anti_geometric(rhs.dx) + self.y.anti_geometric(rhs.dy),
+			xy: self.x.anti_geometric(rhs.dy) + self.y.anti_geometric(rhs.dx),
+		}
+	}
+}
+
+// Vec2.dot(Line) -> Vec3
+impl Dot<Line> for Vec2 {
+	type Output = Vec3;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.m.0),
+		//     y: Y(self.x.0 * rhs.m.0),
+		//     w: -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		// }
+		Vec3 {
+			x: self.y.dot(rhs.m),
+			y: self.x.dot(rhs.m),
+			w: self.x.dot(rhs.dy) + self.y.dot(rhs.dx),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Vec2 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec2.wedge(Line) -> XYW
+impl Wedge<Line> for Vec2 {
+	type Output = XYW;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// XYW(self.x.0 * rhs.dx.0) + XYW(self.y.0 * rhs.dy.0)
+		self.x.wedge(rhs.dx) + self.y.wedge(rhs.dy)
+	}
+}
+
+impl std::ops::BitXor<Line> for Vec2 {
+	type Output = XYW;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec2.anti_wedge(Line) -> S
+impl AntiWedge<Line> for Vec2 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0)
+		self.x.anti_wedge(rhs.dx) + self.y.anti_wedge(rhs.dy)
+	}
+}
+
+impl std::ops::BitAnd<Line> for Vec2 {
+	type Output = S;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec2.left_contraction(Line) -> Vec3
+impl LeftContraction<Line> for Vec2 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.m.0),
+		//     y: Y(self.x.0 * rhs.m.0),
+		//     w: -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		// }
+		Vec3 {
+			x: self.y.left_contraction(rhs.m),
+			y: self.x.left_contraction(rhs.m),
+			w: self.x.left_contraction(rhs.dy) + self.y.left_contraction(rhs.dx),
+		}
+	}
+}
+
+impl RightContraction<Line> for Vec2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line> for Vec2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec2.commutator(Line) -> Vec3
+impl Commutator<Line> for Vec2 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.m.0),
+		//     y: Y(self.x.0 * rhs.m.0),
+		//     w: -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		// }
+		Vec3 {
+			x: self.y.commutator(rhs.m),
+			y: self.x.commutator(rhs.m),
+			w: self.x.commutator(rhs.dy) + self.y.commutator(rhs.dx),
+		}
+	}
+}
+
+// Vec2.anti_dot(Line) -> XY
+impl AntiDot<Line> for Vec2 {
+	type Output = XY;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// -XY(self.x.0 * rhs.dy.0) + XY(self.y.0 * rhs.dx.0)
+		self.x.anti_dot(rhs.dy) + self.y.anti_dot(rhs.dx)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec2 OP Translator:
+
+// Vec2.geometric(Translator) -> Multivector
+impl Geometric<Translator> for Vec2 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.s),
+			y: self.y.geometric(rhs.s),
+			w: self.x.geometric(rhs.wx) + self.y.geometric(rhs.yw),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.geometric(rhs.yw) + self.y.geometric(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Vec2 {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec2.anti_geometric(Translator) -> Rotor
+impl AntiGeometric<Translator> for Vec2 {
+	type Output = Rotor;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Rotor {
+		//     s : S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     xy: -XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		// }
+		Rotor {
+			s: self.x.anti_geometric(rhs.yw) + self.y.anti_geometric(rhs.wx),
+			xy: self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Vec2.dot(Translator) -> Vec3
+impl Dot<Translator> for Vec2 {
+	type Output = Vec3;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		//     w: -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.x.dot(rhs.s),
+			y: self.y.dot(rhs.s),
+			w: self.x.dot(rhs.wx) + self.y.dot(rhs.yw),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Vec2 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec2.wedge(Translator) -> Multivector
+impl Wedge<Translator> for Vec2 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.wedge(rhs.yw) + self.y.wedge(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Vec2 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec2.anti_wedge(Translator) -> S
+impl AntiWedge<Translator> for Vec2 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0)
+		self.x.anti_wedge(rhs.yw) + self.y.anti_wedge(rhs.wx)
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Vec2 {
+	type Output = S;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec2.left_contraction(Translator) -> W
+impl LeftContraction<Translator> for Vec2 {
+	type Output = W;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0)
+		self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw)
+	}
+}
+
+// Vec2.right_contraction(Translator) -> Vec2
+impl RightContraction<Translator> for Vec2 {
+	type Output = Vec2;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		// }
+		Vec2 {
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for Vec2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec2.commutator(Translator) -> W
+impl Commutator<Translator> for Vec2 {
+	type Output = W;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0)
+		self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw)
+	}
+}
+
+// Vec2.anti_dot(Translator) -> XY
+impl AntiDot<Translator> for Vec2 {
+	type Output = XY;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// -XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0)
+		self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.yw)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec2 OP Rotor:
+
+// Vec2.geometric(Rotor) -> Vec2
+impl Geometric<Rotor> for Vec2 {
+	type Output = Vec2;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		// }
+		Vec2 {
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Vec2 {
+	type Output = Vec2;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl AntiGeometric<Rotor> for Vec2 {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec2.dot(Rotor) -> Vec2
+impl Dot<Rotor> for Vec2 {
+	type Output = Vec2;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		// }
+		Vec2 {
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Vec2 {
+	type Output = Vec2;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec2.wedge(Rotor) -> Vec2
+impl Wedge<Rotor> for Vec2 {
+	type Output = Vec2;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		// }
+		Vec2 {
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Vec2 {
+	type Output = Vec2;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Rotor> for Vec2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Vec2 {
+	type Output = Zero;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec2.left_contraction(Rotor) -> Vec2
+impl LeftContraction<Rotor> for Vec2 {
+	type Output = Vec2;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		// }
+		Vec2 {
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Vec2.right_contraction(Rotor) -> Vec2
+impl RightContraction<Rotor> for Vec2 {
+	type Output = Vec2;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		// }
+		Vec2 {
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for Vec2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec2.commutator(Rotor) -> Vec2
+impl Commutator<Rotor> for Vec2 {
+	type Output = Vec2;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		// }
+		Vec2 {
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+		}
+	}
+}
+
+impl AntiDot<Rotor> for Vec2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec2 OP Motor:
+
+// Vec2.geometric(Motor) -> Multivector
+impl Geometric<Motor> for Vec2 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+			w: self.x.geometric(rhs.wx) + self.y.geometric(rhs.yw),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.geometric(rhs.yw) + self.y.geometric(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor> for Vec2 {
+	type Output = Multivector;
+	fn mul(self, rhs: Motor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
 // Vec2.anti_geometric(Motor) -> Rotor
 impl AntiGeometric<Motor> for Vec2 {
@@ -293,11 +1354,11 @@ impl AntiGeometric<Motor> for Vec2 {
 	fn anti_geometric(self, rhs: Motor) -> Self::Output {
 		// Rotor {
 		//     s : S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
-		//     xy: XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		//     xy: -XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
 		// }
 		Rotor {
 			s: self.x.anti_geometric(rhs.yw) + self.y.anti_geometric(rhs.wx),
-			xy: -self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw),
+			xy: self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw),
 		}
 	}
 }
@@ -307,19 +1368,58 @@ impl Dot<Motor> for Vec2 {
 	type Output = Vec3;
 	fn dot(self, rhs: Motor) -> Self::Output {
 		// Vec3 {
-		//     x: X(self.x.0 * rhs.s.0) + X(self.y.0 * rhs.xy.0),
+		//     x: X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
 		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
-		//     w: W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     w: -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
 		// }
 		Vec3 {
-			x: self.x.dot(rhs.s) - self.y.dot(rhs.xy),
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
 			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
-			w: -self.x.dot(rhs.wx) + self.y.dot(rhs.yw),
+			w: self.x.dot(rhs.wx) + self.y.dot(rhs.yw),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor> for Vec2 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Motor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec2.wedge(Motor) -> Multivector
+impl Wedge<Motor> for Vec2 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.wedge(rhs.yw) + self.y.wedge(rhs.wx),
 		}
 	}
 }
 
-// Omitted: Vec2 wedge Motor = self.x ^ rhs.s + self.x ^ rhs.yw + self.y ^ rhs.s + self.y ^ rhs.wx  (unnamed type)
+impl std::ops::BitXor<Motor> for Vec2 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Motor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
 // Vec2.anti_wedge(Motor) -> S
 impl AntiWedge<Motor> for Vec2 {
@@ -329,3 +1429,358 @@ impl AntiWedge<Motor> for Vec2 {
 		self.x.anti_wedge(rhs.yw) + self.y.anti_wedge(rhs.wx)
 	}
 }
+
+impl std::ops::BitAnd<Motor> for Vec2 {
+	type Output = S;
+	fn bitand(self, rhs: Motor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec2.left_contraction(Motor) -> Vec3
+impl LeftContraction<Motor> for Vec2 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		//     w: -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+			w: self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw),
+		}
+	}
+}
+
+// Vec2.right_contraction(Motor) -> Vec2
+impl RightContraction<Motor> for Vec2 {
+	type Output = Vec2;
+	fn right_contraction(self, rhs: Motor) -> Self::Output {
+		// Vec2 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		// }
+		Vec2 {
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Motor> for Vec2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Motor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec2.commutator(Motor) -> Vec3
+impl Commutator<Motor> for Vec2 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		//     w: -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+			w: self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw),
+		}
+	}
+}
+
+// Vec2.anti_dot(Motor) -> XY
+impl AntiDot<Motor> for Vec2 {
+	type Output = XY;
+	fn anti_dot(self, rhs: Motor) -> Self::Output {
+		// -XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0)
+		self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.yw)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec2 OP Multivector:
+
+// Vec2.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.x.0 * rhs.xyw.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : -WX(self.x.0 * rhs.w.0) + WX(self.y.0 * rhs.xyw.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+			w: self.x.geometric(rhs.wx) + self.y.geometric(rhs.yw),
+			yw: self.x.geometric(rhs.xyw) + self.y.geometric(rhs.w),
+			wx: self.x.geometric(rhs.w) + self.y.geometric(rhs.xyw),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			xyw: self.x.geometric(rhs.yw) + self.y.geometric(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec2.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     x  : X(self.x.0 * rhs.xyw.0) - X(self.y.0 * rhs.w.0),
+		//     y  : Y(self.x.0 * rhs.w.0) + Y(self.y.0 * rhs.xyw.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : -XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.anti_geometric(rhs.yw) + self.y.anti_geometric(rhs.wx),
+			x: self.x.anti_geometric(rhs.xyw) + self.y.anti_geometric(rhs.w),
+			y: self.x.anti_geometric(rhs.w) + self.y.anti_geometric(rhs.xyw),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Vec2.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.x.0 * rhs.xyw.0),
+		//     wx : WX(self.y.0 * rhs.xyw.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y),
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
+			w: self.x.dot(rhs.wx) + self.y.dot(rhs.yw),
+			yw: self.x.dot(rhs.xyw),
+			wx: self.y.dot(rhs.xyw),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec2.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : YW(self.y.0 * rhs.w.0),
+		//     wx : -WX(self.x.0 * rhs.w.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: Default::default(),
+			yw: self.y.wedge(rhs.w),
+			wx: self.x.wedge(rhs.w),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			xyw: self.x.wedge(rhs.yw) + self.y.wedge(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec2.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     x  : X(self.x.0 * rhs.xyw.0),
+		//     y  : Y(self.y.0 * rhs.xyw.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.anti_wedge(rhs.yw) + self.y.anti_wedge(rhs.wx),
+			x: self.x.anti_wedge(rhs.xyw),
+			y: self.y.anti_wedge(rhs.xyw),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec2.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : -X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.x.0 * rhs.xyw.0),
+		//     wx : WX(self.y.0 * rhs.xyw.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y),
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+			w: self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw),
+			yw: self.x.left_contraction(rhs.xyw),
+			wx: self.y.left_contraction(rhs.xyw),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Vec2.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y),
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Vec2.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Vec2 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Vec2.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.y.0 * rhs.w.0),
+		//     wx : -WX(self.x.0 * rhs.w.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+			w: self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw),
+			yw: self.y.commutator(rhs.w),
+			wx: self.x.commutator(rhs.w),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Vec2.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Vec2 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.xyw.0),
+		//     y  : Y(self.y.0 * rhs.xyw.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : -XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.anti_dot(rhs.xyw),
+			y: self.y.anti_dot(rhs.xyw),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.yw),
+			xyw: Default::default(),
+		}
+	}
+}
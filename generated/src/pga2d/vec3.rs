@@ -3,8 +3,14 @@
 //! ## Operations
 //! ```text
 //! Vec3.geometric(Vec3) -> Motor
+//! Vec3.anti_geometric(Vec3) -> Multivector
 //! Vec3.dot(Vec3) -> S
 //! Vec3.wedge(Vec3) -> Line
+//! Vec3.left_contraction(Vec3) -> S
+//! Vec3.right_contraction(Vec3) -> S
+//! Vec3.scalar_product(Vec3) -> S
+//! Vec3.commutator(Vec3) -> Line
+//! Vec3.anti_dot(Vec3) -> XYW
 //! Vec3.geometric(Vec2) -> Motor
 //! Vec2.geometric(Vec3) -> Motor
 //! Vec3.anti_geometric(Vec2) -> Vec2
@@ -13,6 +19,34 @@
 //! Vec2.dot(Vec3) -> S
 //! Vec3.wedge(Vec2) -> Line
 //! Vec2.wedge(Vec3) -> Line
+//! Vec3.left_contraction(Vec2) -> S
+//! Vec2.left_contraction(Vec3) -> S
+//! Vec3.right_contraction(Vec2) -> S
+//! Vec2.right_contraction(Vec3) -> S
+//! Vec3.scalar_product(Vec2) -> S
+//! Vec2.scalar_product(Vec3) -> S
+//! Vec3.commutator(Vec2) -> Line
+//! Vec2.commutator(Vec3) -> Line
+//! Vec3.geometric(Point2) -> Motor
+//! Point2.geometric(Vec3) -> Motor
+//! Vec3.anti_geometric(Point2) -> Multivector
+//! Point2.anti_geometric(Vec3) -> Multivector
+//! Vec3.dot(Point2) -> S
+//! Point2.dot(Vec3) -> S
+//! Vec3.wedge(Point2) -> Line
+//! Point2.wedge(Vec3) -> Line
+//! Vec3.left_contraction(Point2) -> S
+//! Point2.left_contraction(Vec3) -> S
+//! Vec3.right_contraction(Point2) -> S
+//! Point2.right_contraction(Vec3) -> S
+//! Vec3.scalar_product(Point2) -> S
+//! Point2.scalar_product(Vec3) -> S
+//! Vec3.commutator(Point2) -> Line
+//! Point2.commutator(Vec3) -> Line
+//! Vec3.anti_dot(Point2) -> XYW
+//! Point2.anti_dot(Vec3) -> XYW
+//! Vec3.geometric(Line) -> Multivector
+//! Line.geometric(Vec3) -> Multivector
 //! Vec3.anti_geometric(Line) -> Motor
 //! Line.anti_geometric(Vec3) -> Motor
 //! Vec3.dot(Line) -> Vec3
@@ -21,29 +55,135 @@
 //! Line.wedge(Vec3) -> XYW
 //! Vec3.anti_wedge(Line) -> S
 //! Line.anti_wedge(Vec3) -> S
+//! Vec3.left_contraction(Line) -> Vec3
+//! Line.right_contraction(Vec3) -> Vec3
+//! Vec3.commutator(Line) -> Vec3
+//! Line.commutator(Vec3) -> Vec3
+//! Vec3.anti_dot(Line) -> Line
+//! Line.anti_dot(Vec3) -> Line
+//! Vec3.geometric(Translator) -> Multivector
+//! Translator.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Translator) -> Motor
+//! Translator.anti_geometric(Vec3) -> Motor
+//! Vec3.dot(Translator) -> Vec3
+//! Translator.dot(Vec3) -> Vec3
+//! Vec3.wedge(Translator) -> Multivector
+//! Translator.wedge(Vec3) -> Multivector
+//! Vec3.anti_wedge(Translator) -> S
+//! Translator.anti_wedge(Vec3) -> S
+//! Vec3.left_contraction(Translator) -> W
+//! Translator.left_contraction(Vec3) -> Vec3
+//! Vec3.right_contraction(Translator) -> Vec3
+//! Translator.right_contraction(Vec3) -> W
+//! Vec3.commutator(Translator) -> W
+//! Translator.commutator(Vec3) -> W
+//! Vec3.anti_dot(Translator) -> Line
+//! Translator.anti_dot(Vec3) -> Line
+//! Vec3.geometric(Rotor) -> Multivector
+//! Rotor.geometric(Vec3) -> Multivector
 //! Vec3.anti_geometric(Rotor) -> Rotor
 //! Rotor.anti_geometric(Vec3) -> Rotor
 //! Vec3.dot(Rotor) -> Vec3
 //! Rotor.dot(Vec3) -> Vec3
+//! Vec3.wedge(Rotor) -> Multivector
+//! Rotor.wedge(Vec3) -> Multivector
 //! Vec3.anti_wedge(Rotor) -> S
 //! Rotor.anti_wedge(Vec3) -> S
+//! Vec3.left_contraction(Rotor) -> Vec2
+//! Rotor.left_contraction(Vec3) -> Vec3
+//! Vec3.right_contraction(Rotor) -> Vec3
+//! Rotor.right_contraction(Vec3) -> Vec2
+//! Vec3.commutator(Rotor) -> Vec2
+//! Rotor.commutator(Vec3) -> Vec2
+//! Vec3.anti_dot(Rotor) -> XY
+//! Rotor.anti_dot(Vec3) -> XY
+//! Vec3.geometric(Motor) -> Multivector
+//! Motor.geometric(Vec3) -> Multivector
 //! Vec3.anti_geometric(Motor) -> Motor
 //! Motor.anti_geometric(Vec3) -> Motor
 //! Vec3.dot(Motor) -> Vec3
 //! Motor.dot(Vec3) -> Vec3
+//! Vec3.wedge(Motor) -> Multivector
+//! Motor.wedge(Vec3) -> Multivector
 //! Vec3.anti_wedge(Motor) -> S
 //! Motor.anti_wedge(Vec3) -> S
+//! Vec3.left_contraction(Motor) -> Vec3
+//! Motor.left_contraction(Vec3) -> Vec3
+//! Vec3.right_contraction(Motor) -> Vec3
+//! Motor.right_contraction(Vec3) -> Vec3
+//! Vec3.commutator(Motor) -> Vec3
+//! Motor.commutator(Vec3) -> Vec3
+//! Vec3.anti_dot(Motor) -> Line
+//! Motor.anti_dot(Vec3) -> Line
+//! Vec3.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec3) -> Multivector
+//! Vec3.dot(Multivector) -> Multivector
+//! Multivector.dot(Vec3) -> Multivector
+//! Vec3.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Vec3) -> Multivector
+//! Vec3.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec3) -> Multivector
+//! Vec3.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec3) -> Multivector
+//! Vec3.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec3) -> Multivector
+//! Vec3.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Vec3) -> S
+//! Vec3.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Vec3) -> Multivector
+//! Vec3.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec3) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec3 {
 	pub x: X,
 	pub y: Y,
 	pub w: W,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Vec3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.w.abs_diff_eq(&other.w, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Vec3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.w.relative_eq(&other.w, epsilon, max_relative)
+	}
+}
+
 // ---------------------------------------------------------------------
 
 impl RCompl for Vec3 {
@@ -57,6 +197,13 @@ impl RCompl for Vec3 {
 	}
 }
 
+impl std::ops::Not for Vec3 {
+	type Output = Line;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl LCompl for Vec3 {
 	type Output = Line;
 	fn lcompl(self) -> Self::Output {
@@ -88,6 +235,184 @@ impl AntiReverse for Vec3 {
 	}
 }
 
+impl Involute for Vec3 {
+	fn involute(self) -> Self {
+		Vec3 {
+			x: -self.x,
+			y: -self.y,
+			w: -self.w,
+		}
+	}
+}
+
+impl Conjugate for Vec3 {
+	fn conjugate(self) -> Self {
+		Vec3 {
+			x: -self.x,
+			y: -self.y,
+			w: -self.w,
+		}
+	}
+}
+
+impl Dual for Vec3 {
+	type Output = Line;
+	fn dual(self) -> Self::Output {
+		Line {
+			dx: self.x.dual(),
+			dy: self.y.dual(),
+			m: self.w.dual(),
+		}
+	}
+}
+
+impl Bulk for Vec3 {
+	type Output = Vec2;
+	fn bulk(self) -> Self::Output {
+		Vec2 { x: self.x, y: self.y }
+	}
+}
+
+impl Weight for Vec3 {
+	type Output = W;
+	fn weight(self) -> Self::Output {
+		self.w
+	}
+}
+
+impl BulkDual for Vec3 {
+	type Output = Line;
+	fn bulk_dual(self) -> Self::Output {
+		Line {
+			dx: self.x.bulk_dual(),
+			dy: self.y.bulk_dual(),
+			m: Default::default(),
+		}
+	}
+}
+
+impl WeightDual for Vec3 {
+	type Output = XY;
+	fn weight_dual(self) -> Self::Output {
+		self.w.weight_dual()
+	}
+}
+
+impl Square for Vec3 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(self.x.geometric(self.x) + self.y.geometric(self.y)).0
+	}
+}
+
+impl Norm for Vec3 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.w.0 * self.w.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.w.0 * self.w.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.w.0;
+		Vec3 {
+			x: self.x * scale,
+			y: self.y * scale,
+			w: self.w * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Vec3 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 3] {
+		[self.x.0, self.y.0, self.w.0]
+	}
+}
+
+impl From<[f64; 3]> for Vec3 {
+	fn from(a: [f64; 3]) -> Self {
+		Vec3 {
+			x: X(a[0]),
+			y: Y(a[1]),
+			w: W(a[2]),
+		}
+	}
+}
+
+impl From<Vec3> for [f64; 3] {
+	fn from(v: Vec3) -> Self {
+		v.to_array()
+	}
+}
+
+impl Vec3 {
+	/// A normalized point, i.e. with `w=1`.
+	pub fn point(x: f64, y: f64) -> Self {
+		Vec3 {
+			x: X(x),
+			y: Y(y),
+			w: W(1.0),
+		}
+	}
+
+	/// A direction, a.k.a. a point at infinity, i.e. with `w=0`.
+	pub fn direction(x: f64, y: f64) -> Self {
+		Vec3 {
+			x: X(x),
+			y: Y(y),
+			w: W(0.0),
+		}
+	}
+}
+
+impl From<Vec3> for Multivector {
+	fn from(v: Vec3) -> Self {
+		Multivector {
+			x: v.x,
+			y: v.y,
+			w: v.w,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Vec3 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Vec3` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0 && mv.yw.0 == 0.0 && mv.wx.0 == 0.0 && mv.xy.0 == 0.0 && mv.xyw.0 == 0.0 {
+			Ok(Vec3 {
+				x: mv.x,
+				y: mv.y,
+				w: mv.w,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Vec3 OP Vec2:
 
@@ -97,30 +422,37 @@ impl Geometric<Vec2> for Vec3 {
 	fn geometric(self, rhs: Vec2) -> Self::Output {
 		// Motor {
 		//     s : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
-		//     yw: YW(self.w.0 * rhs.y.0),
+		//     yw: -YW(self.w.0 * rhs.y.0),
 		//     wx: WX(self.w.0 * rhs.x.0),
-		//     xy: XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
 		Motor {
 			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
-			yw: -self.w.geometric(rhs.y),
+			yw: self.w.geometric(rhs.y),
 			wx: self.w.geometric(rhs.x),
-			xy: self.x.geometric(rhs.y) - self.y.geometric(rhs.x),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
 		}
 	}
 }
 
+impl std::ops::Mul<Vec2> for Vec3 {
+	type Output = Motor;
+	fn mul(self, rhs: Vec2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
 // Vec3.anti_geometric(Vec2) -> Vec2
 impl AntiGeometric<Vec2> for Vec3 {
 	type Output = Vec2;
 	fn anti_geometric(self, rhs: Vec2) -> Self::Output {
 		// Vec2 {
 		//     x: X(self.w.0 * rhs.y.0),
-		//     y: Y(self.w.0 * rhs.x.0),
+		//     y: -Y(self.w.0 * rhs.x.0),
 		// }
 		Vec2 {
 			x: self.w.anti_geometric(rhs.y),
-			y: -self.w.anti_geometric(rhs.x),
+			y: self.w.anti_geometric(rhs.x),
 		}
 	}
 }
@@ -134,24 +466,101 @@ impl Dot<Vec2> for Vec3 {
 	}
 }
 
+impl std::ops::BitOr<Vec2> for Vec3 {
+	type Output = S;
+	fn bitor(self, rhs: Vec2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Vec3.wedge(Vec2) -> Line
 impl Wedge<Vec2> for Vec3 {
 	type Output = Line;
 	fn wedge(self, rhs: Vec2) -> Self::Output {
 		// Line {
-		//     dx: YW(self.w.0 * rhs.y.0),
+		//     dx: -YW(self.w.0 * rhs.y.0),
 		//     dy: WX(self.w.0 * rhs.x.0),
-		//     m : XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
 		Line {
-			dx: -self.w.wedge(rhs.y),
+			dx: self.w.wedge(rhs.y),
 			dy: self.w.wedge(rhs.x),
-			m: self.x.wedge(rhs.y) - self.y.wedge(rhs.x),
+			m: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec2> for Vec3 {
+	type Output = Line;
+	fn bitxor(self, rhs: Vec2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec2> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec2> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Vec2) -> S
+impl LeftContraction<Vec2> for Vec3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y)
+	}
+}
+
+// Vec3.right_contraction(Vec2) -> S
+impl RightContraction<Vec2> for Vec3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y)
+	}
+}
+
+// Vec3.scalar_product(Vec2) -> S
+impl ScalarProduct<Vec2> for Vec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Vec3.commutator(Vec2) -> Line
+impl Commutator<Vec2> for Vec3 {
+	type Output = Line;
+	fn commutator(self, rhs: Vec2) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.w.0 * rhs.y.0),
+		//     dy: WX(self.w.0 * rhs.x.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: self.w.commutator(rhs.y),
+			dy: self.w.commutator(rhs.x),
+			m: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
 		}
 	}
 }
 
-// Omitted: Vec3 anti_wedge Vec2 = 0  (unnamed type)
+impl AntiDot<Vec2> for Vec3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Vec2) -> Self::Output {
+		Zero {}
+	}
+}
 
 // ---------------------------------------------------------------------
 // Vec3 OP Vec3:
@@ -162,20 +571,52 @@ impl Geometric<Vec3> for Vec3 {
 	fn geometric(self, rhs: Vec3) -> Self::Output {
 		// Motor {
 		//     s : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
-		//     yw: YW(self.w.0 * rhs.y.0) + YW(self.y.0 * rhs.w.0),
-		//     wx: WX(self.w.0 * rhs.x.0) + WX(self.x.0 * rhs.w.0),
-		//     xy: XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		//     yw: -YW(self.w.0 * rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     wx: WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
 		Motor {
 			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
-			yw: -self.w.geometric(rhs.y) + self.y.geometric(rhs.w),
-			wx: self.w.geometric(rhs.x) - self.x.geometric(rhs.w),
-			xy: self.x.geometric(rhs.y) - self.y.geometric(rhs.x),
+			yw: self.w.geometric(rhs.y) + self.y.geometric(rhs.w),
+			wx: self.w.geometric(rhs.x) + self.x.geometric(rhs.w),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
 		}
 	}
 }
 
-// Omitted: Vec3 anti_geometric Vec3 = self.w !* rhs.w + self.w !* rhs.x + self.w !* rhs.y + self.x !* rhs.w + self.y !* rhs.w  (unnamed type)
+impl std::ops::Mul<Vec3> for Vec3 {
+	type Output = Motor;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Vec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.w.0 * rhs.y.0) - X(self.y.0 * rhs.w.0),
+		//     y  : -Y(self.w.0 * rhs.x.0) + Y(self.x.0 * rhs.w.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: -XYW(self.w.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.w.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.w),
+			y: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(rhs.w),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.w.anti_geometric(rhs.w),
+		}
+	}
+}
 
 // Vec3.dot(Vec3) -> S
 impl Dot<Vec3> for Vec3 {
@@ -186,174 +627,935 @@ impl Dot<Vec3> for Vec3 {
 	}
 }
 
+impl std::ops::BitOr<Vec3> for Vec3 {
+	type Output = S;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Vec3.wedge(Vec3) -> Line
 impl Wedge<Vec3> for Vec3 {
 	type Output = Line;
 	fn wedge(self, rhs: Vec3) -> Self::Output {
 		// Line {
-		//     dx: YW(self.w.0 * rhs.y.0) + YW(self.y.0 * rhs.w.0),
-		//     dy: WX(self.w.0 * rhs.x.0) + WX(self.x.0 * rhs.w.0),
-		//     m : XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		//     dx: -YW(self.w.0 * rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     dy: WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
 		Line {
-			dx: -self.w.wedge(rhs.y) + self.y.wedge(rhs.w),
-			dy: self.w.wedge(rhs.x) - self.x.wedge(rhs.w),
-			m: self.x.wedge(rhs.y) - self.y.wedge(rhs.x),
+			dx: self.w.wedge(rhs.y) + self.y.wedge(rhs.w),
+			dy: self.w.wedge(rhs.x) + self.x.wedge(rhs.w),
+			m: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
 		}
 	}
 }
 
-// Omitted: Vec3 anti_wedge Vec3 = 0  (unnamed type)
-
-// ---------------------------------------------------------------------
-// Vec3 OP Line:
-
-// Omitted: Vec3 geometric Line = self.w * rhs.m + self.x * rhs.dx + self.x * rhs.dy + self.x * rhs.m + self.y * rhs.dx + self.y * rhs.dy + self.y * rhs.m  (unnamed type)
-
-// Vec3.anti_geometric(Line) -> Motor
-impl AntiGeometric<Line> for Vec3 {
-	type Output = Motor;
-	fn anti_geometric(self, rhs: Line) -> Self::Output {
-		// Motor {
-		//     s : S(self.w.0 * rhs.m.0) + S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0),
-		//     yw: YW(self.w.0 * rhs.dy.0),
-		//     wx: WX(self.w.0 * rhs.dx.0),
-		//     xy: XY(self.x.0 * rhs.dy.0) + XY(self.y.0 * rhs.dx.0),
-		// }
-		Motor {
-			s: self.w.anti_geometric(rhs.m) + self.x.anti_geometric(rhs.dx) + self.y.anti_geometric(rhs.dy),
-			yw: self.w.anti_geometric(rhs.dy),
-			wx: -self.w.anti_geometric(rhs.dx),
-			xy: -self.x.anti_geometric(rhs.dy) + self.y.anti_geometric(rhs.dx),
-		}
+impl std::ops::BitXor<Vec3> for Vec3 {
+	type Output = Line;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
 	}
 }
 
-// Vec3.dot(Line) -> Vec3
-impl Dot<Line> for Vec3 {
-	type Output = Vec3;
-	fn dot(self, rhs: Line) -> Self::Output {
-		// Vec3 {
-		//     x: X(self.y.0 * rhs.m.0),
-		//     y: Y(self.x.0 * rhs.m.0),
-		//     w: W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
-		// }
-		Vec3 {
-			x: -self.y.dot(rhs.m),
-			y: self.x.dot(rhs.m),
-			w: -self.x.dot(rhs.dy) + self.y.dot(rhs.dx),
-		}
+impl AntiWedge<Vec3> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
 	}
 }
 
-// Vec3.wedge(Line) -> XYW
-impl Wedge<Line> for Vec3 {
-	type Output = XYW;
-	fn wedge(self, rhs: Line) -> Self::Output {
-		// XYW(self.w.0 * rhs.m.0) + XYW(self.x.0 * rhs.dx.0) + XYW(self.y.0 * rhs.dy.0)
-		self.w.wedge(rhs.m) + self.x.wedge(rhs.dx) + self.y.wedge(rhs.dy)
+impl std::ops::BitAnd<Vec3> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-// Vec3.anti_wedge(Line) -> S
-impl AntiWedge<Line> for Vec3 {
+// Vec3.left_contraction(Vec3) -> S
+impl LeftContraction<Vec3> for Vec3 {
 	type Output = S;
-	fn anti_wedge(self, rhs: Line) -> Self::Output {
-		// S(self.w.0 * rhs.m.0) + S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0)
-		self.w.anti_wedge(rhs.m) + self.x.anti_wedge(rhs.dx) + self.y.anti_wedge(rhs.dy)
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y)
 	}
 }
 
-// ---------------------------------------------------------------------
-// Vec3 OP Rotor:
-
-// Omitted: Vec3 geometric Rotor = self.w * rhs.s + self.w * rhs.xy + self.x * rhs.s + self.x * rhs.xy + self.y * rhs.s + self.y * rhs.xy  (unnamed type)
+// Vec3.right_contraction(Vec3) -> S
+impl RightContraction<Vec3> for Vec3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y)
+	}
+}
 
-// Vec3.anti_geometric(Rotor) -> Rotor
-impl AntiGeometric<Rotor> for Vec3 {
-	type Output = Rotor;
-	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
-		// Rotor {
-		//     s : S(self.w.0 * rhs.xy.0),
-		//     xy: XY(self.w.0 * rhs.s.0),
-		// }
-		Rotor {
-			s: self.w.anti_geometric(rhs.xy),
-			xy: -self.w.anti_geometric(rhs.s),
-		}
+// Vec3.scalar_product(Vec3) -> S
+impl ScalarProduct<Vec3> for Vec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
 	}
 }
 
-// Vec3.dot(Rotor) -> Vec3
-impl Dot<Rotor> for Vec3 {
-	type Output = Vec3;
-	fn dot(self, rhs: Rotor) -> Self::Output {
-		// Vec3 {
-		//     x: X(self.x.0 * rhs.s.0) + X(self.y.0 * rhs.xy.0),
-		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
-		//     w: W(self.w.0 * rhs.s.0),
+// Vec3.commutator(Vec3) -> Line
+impl Commutator<Vec3> for Vec3 {
+	type Output = Line;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.w.0 * rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     dy: WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
-		Vec3 {
-			x: self.x.dot(rhs.s) - self.y.dot(rhs.xy),
-			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
-			w: self.w.dot(rhs.s),
+		Line {
+			dx: self.w.commutator(rhs.y) + self.y.commutator(rhs.w),
+			dy: self.w.commutator(rhs.x) + self.x.commutator(rhs.w),
+			m: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
 		}
 	}
 }
 
-// Omitted: Vec3 wedge Rotor = self.w ^ rhs.s + self.w ^ rhs.xy + self.x ^ rhs.s + self.y ^ rhs.s  (unnamed type)
-
-// Vec3.anti_wedge(Rotor) -> S
-impl AntiWedge<Rotor> for Vec3 {
-	type Output = S;
-	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
-		// S(self.w.0 * rhs.xy.0)
-		self.w.anti_wedge(rhs.xy)
+// Vec3.anti_dot(Vec3) -> XYW
+impl AntiDot<Vec3> for Vec3 {
+	type Output = XYW;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// -XYW(self.w.0 * rhs.w.0)
+		self.w.anti_dot(rhs.w)
 	}
 }
 
 // ---------------------------------------------------------------------
-// Vec3 OP Motor:
+// Vec3 OP Point2:
 
-// Omitted: Vec3 geometric Motor = self.w * rhs.s + self.w * rhs.xy + self.x * rhs.s + self.x * rhs.wx + self.x * rhs.xy + self.x * rhs.yw + self.y * rhs.s + self.y * rhs.wx + self.y * rhs.xy + self.y * rhs.yw  (unnamed type)
-
-// Vec3.anti_geometric(Motor) -> Motor
-impl AntiGeometric<Motor> for Vec3 {
+// Vec3.geometric(Point2) -> Motor
+impl Geometric<Point2> for Vec3 {
 	type Output = Motor;
-	fn anti_geometric(self, rhs: Motor) -> Self::Output {
+	fn geometric(self, rhs: Point2) -> Self::Output {
 		// Motor {
-		//     s : S(self.w.0 * rhs.xy.0) + S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
-		//     yw: YW(self.w.0 * rhs.wx.0),
-		//     wx: WX(self.w.0 * rhs.yw.0),
-		//     xy: XY(self.w.0 * rhs.s.0) + XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		//     s : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     yw: -YW(self.w.0 * rhs.y.0) + YW(self.y.0),
+		//     wx: WX(self.w.0 * rhs.x.0) - WX(self.x.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
 		Motor {
-			s: self.w.anti_geometric(rhs.xy) + self.x.anti_geometric(rhs.yw) + self.y.anti_geometric(rhs.wx),
-			yw: self.w.anti_geometric(rhs.wx),
-			wx: -self.w.anti_geometric(rhs.yw),
-			xy: -self.w.anti_geometric(rhs.s) - self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw),
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			yw: self.w.geometric(rhs.y) + self.y.geometric(W(1.0)),
+			wx: self.w.geometric(rhs.x) + self.x.geometric(W(1.0)),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
 		}
 	}
 }
 
-// Vec3.dot(Motor) -> Vec3
-impl Dot<Motor> for Vec3 {
-	type Output = Vec3;
-	fn dot(self, rhs: Motor) -> Self::Output {
+impl std::ops::Mul<Point2> for Vec3 {
+	type Output = Motor;
+	fn mul(self, rhs: Point2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Point2) -> Multivector
+impl AntiGeometric<Point2> for Vec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point2) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.w.0 * rhs.y.0) - X(self.y.0),
+		//     y  : -Y(self.w.0 * rhs.x.0) + Y(self.x.0),
+		//     w  : Default::default(),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: -XYW(self.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.w.anti_geometric(rhs.y) + self.y.anti_geometric(W(1.0)),
+			y: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(W(1.0)),
+			w: Default::default(),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.w.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Vec3.dot(Point2) -> S
+impl Dot<Point2> for Vec3 {
+	type Output = S;
+	fn dot(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.dot(rhs.x) + self.y.dot(rhs.y)
+	}
+}
+
+impl std::ops::BitOr<Point2> for Vec3 {
+	type Output = S;
+	fn bitor(self, rhs: Point2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Point2) -> Line
+impl Wedge<Point2> for Vec3 {
+	type Output = Line;
+	fn wedge(self, rhs: Point2) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.w.0 * rhs.y.0) + YW(self.y.0),
+		//     dy: WX(self.w.0 * rhs.x.0) - WX(self.x.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: self.w.wedge(rhs.y) + self.y.wedge(W(1.0)),
+			dy: self.w.wedge(rhs.x) + self.x.wedge(W(1.0)),
+			m: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point2> for Vec3 {
+	type Output = Line;
+	fn bitxor(self, rhs: Point2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point2> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point2> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Point2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Point2) -> S
+impl LeftContraction<Point2> for Vec3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y)
+	}
+}
+
+// Vec3.right_contraction(Point2) -> S
+impl RightContraction<Point2> for Vec3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y)
+	}
+}
+
+// Vec3.scalar_product(Point2) -> S
+impl ScalarProduct<Point2> for Vec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Point2) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Vec3.commutator(Point2) -> Line
+impl Commutator<Point2> for Vec3 {
+	type Output = Line;
+	fn commutator(self, rhs: Point2) -> Self::Output {
+		// Line {
+		//     dx: -YW(self.w.0 * rhs.y.0) + YW(self.y.0),
+		//     dy: WX(self.w.0 * rhs.x.0) - WX(self.x.0),
+		//     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line {
+			dx: self.w.commutator(rhs.y) + self.y.commutator(W(1.0)),
+			dy: self.w.commutator(rhs.x) + self.x.commutator(W(1.0)),
+			m: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+		}
+	}
+}
+
+// Vec3.anti_dot(Point2) -> XYW
+impl AntiDot<Point2> for Vec3 {
+	type Output = XYW;
+	fn anti_dot(self, _rhs: Point2) -> Self::Output {
+		// -XYW(self.w.0)
+		self.w.anti_dot(W(1.0))
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Line:
+
+// Vec3.geometric(Line) -> Multivector
+impl Geometric<Line> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.m.0),
+		//     y  : Y(self.x.0 * rhs.m.0),
+		//     w  : -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.w.0 * rhs.m.0) + XYW(self.x.0 * rhs.dx.0) + XYW(self.y.0 * rhs.dy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.geometric(rhs.m),
+			y: self.x.geometric(rhs.m),
+			w: self.x.geometric(rhs.dy) + self.y.geometric(rhs.dx),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.w.geometric(rhs.m) + self.x.geometric(rhs.dx) + self.y.geometric(rhs.dy),
+		}
+	}
+}
+
+impl std::ops::Mul<Line> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Line) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Line) -> Motor
+impl AntiGeometric<Line> for Vec3 {
+	type Output = Motor;
+	fn anti_geometric(self, rhs: Line) -> Self::Output {
+		// Motor {
+		//     s : S(self.w.0 * rhs.m.0) + S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0),
+		//     yw: YW(self.w.0 * rhs.dy.0),
+		//     wx: -WX(self.w.0 * rhs.dx.0),
+		//     xy: -XY(self.x.0 * rhs.dy.0) + XY(self.y.0 * rhs.dx.0),
+		// }
+		Motor {
+			s: self.w.anti_geometric(rhs.m) + self.x.anti_geometric(rhs.dx) + self.y.anti_geometric(rhs.dy),
+			yw: self.w.anti_geometric(rhs.dy),
+			wx: self.w.anti_geometric(rhs.dx),
+			xy: self.x.anti_geometric(rhs.dy) + self.y.anti_geometric(rhs.dx),
+		}
+	}
+}
+
+// Vec3.dot(Line) -> Vec3
+impl Dot<Line> for Vec3 {
+	type Output = Vec3;
+	fn dot(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.m.0),
+		//     y: Y(self.x.0 * rhs.m.0),
+		//     w: -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		// }
+		Vec3 {
+			x: self.y.dot(rhs.m),
+			y: self.x.dot(rhs.m),
+			w: self.x.dot(rhs.dy) + self.y.dot(rhs.dx),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line> for Vec3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Line) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Line) -> XYW
+impl Wedge<Line> for Vec3 {
+	type Output = XYW;
+	fn wedge(self, rhs: Line) -> Self::Output {
+		// XYW(self.w.0 * rhs.m.0) + XYW(self.x.0 * rhs.dx.0) + XYW(self.y.0 * rhs.dy.0)
+		self.w.wedge(rhs.m) + self.x.wedge(rhs.dx) + self.y.wedge(rhs.dy)
+	}
+}
+
+impl std::ops::BitXor<Line> for Vec3 {
+	type Output = XYW;
+	fn bitxor(self, rhs: Line) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Line) -> S
+impl AntiWedge<Line> for Vec3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Line) -> Self::Output {
+		// S(self.w.0 * rhs.m.0) + S(self.x.0 * rhs.dx.0) + S(self.y.0 * rhs.dy.0)
+		self.w.anti_wedge(rhs.m) + self.x.anti_wedge(rhs.dx) + self.y.anti_wedge(rhs.dy)
+	}
+}
+
+impl std::ops::BitAnd<Line> for Vec3 {
+	type Output = S;
+	fn bitand(self, rhs: Line) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Line) -> Vec3
+impl LeftContraction<Line> for Vec3 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Line) -> Self::Output {
 		// Vec3 {
-		//     x: X(self.x.0 * rhs.s.0) + X(self.y.0 * rhs.xy.0),
+		//     x: -X(self.y.0 * rhs.m.0),
+		//     y: Y(self.x.0 * rhs.m.0),
+		//     w: -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		// }
+		Vec3 {
+			x: self.y.left_contraction(rhs.m),
+			y: self.x.left_contraction(rhs.m),
+			w: self.x.left_contraction(rhs.dy) + self.y.left_contraction(rhs.dx),
+		}
+	}
+}
+
+impl RightContraction<Line> for Vec3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Line) -> Vec3
+impl Commutator<Line> for Vec3 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Line) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.m.0),
+		//     y: Y(self.x.0 * rhs.m.0),
+		//     w: -W(self.x.0 * rhs.dy.0) + W(self.y.0 * rhs.dx.0),
+		// }
+		Vec3 {
+			x: self.y.commutator(rhs.m),
+			y: self.x.commutator(rhs.m),
+			w: self.x.commutator(rhs.dy) + self.y.commutator(rhs.dx),
+		}
+	}
+}
+
+// Vec3.anti_dot(Line) -> Line
+impl AntiDot<Line> for Vec3 {
+	type Output = Line;
+	fn anti_dot(self, rhs: Line) -> Self::Output {
+		// Line {
+		//     dx: YW(self.w.0 * rhs.dy.0),
+		//     dy: -WX(self.w.0 * rhs.dx.0),
+		//     m : -XY(self.x.0 * rhs.dy.0) + XY(self.y.0 * rhs.dx.0),
+		// }
+		Line {
+			dx: self.w.anti_dot(rhs.dy),
+			dy: self.w.anti_dot(rhs.dx),
+			m: self.x.anti_dot(rhs.dy) + self.y.anti_dot(rhs.dx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Translator:
+
+// Vec3.geometric(Translator) -> Multivector
+impl Geometric<Translator> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.s),
+			y: self.y.geometric(rhs.s),
+			w: self.w.geometric(rhs.s) + self.x.geometric(rhs.wx) + self.y.geometric(rhs.yw),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.geometric(rhs.yw) + self.y.geometric(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Translator) -> Motor
+impl AntiGeometric<Translator> for Vec3 {
+	type Output = Motor;
+	fn anti_geometric(self, rhs: Translator) -> Self::Output {
+		// Motor {
+		//     s : S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     yw: YW(self.w.0 * rhs.wx.0),
+		//     wx: -WX(self.w.0 * rhs.yw.0),
+		//     xy: -XY(self.w.0 * rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		// }
+		Motor {
+			s: self.x.anti_geometric(rhs.yw) + self.y.anti_geometric(rhs.wx),
+			yw: self.w.anti_geometric(rhs.wx),
+			wx: self.w.anti_geometric(rhs.yw),
+			xy: self.w.anti_geometric(rhs.s) + self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Vec3.dot(Translator) -> Vec3
+impl Dot<Translator> for Vec3 {
+	type Output = Vec3;
+	fn dot(self, rhs: Translator) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		//     w: W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.x.dot(rhs.s),
+			y: self.y.dot(rhs.s),
+			w: self.w.dot(rhs.s) + self.x.dot(rhs.wx) + self.y.dot(rhs.yw),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator> for Vec3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Translator) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Translator) -> Multivector
+impl Wedge<Translator> for Vec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Translator) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: self.w.wedge(rhs.s),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.x.wedge(rhs.yw) + self.y.wedge(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator> for Vec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Translator) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Translator) -> S
+impl AntiWedge<Translator> for Vec3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Translator) -> Self::Output {
+		// S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0)
+		self.x.anti_wedge(rhs.yw) + self.y.anti_wedge(rhs.wx)
+	}
+}
+
+impl std::ops::BitAnd<Translator> for Vec3 {
+	type Output = S;
+	fn bitand(self, rhs: Translator) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Translator) -> W
+impl LeftContraction<Translator> for Vec3 {
+	type Output = W;
+	fn left_contraction(self, rhs: Translator) -> Self::Output {
+		// -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0)
+		self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw)
+	}
+}
+
+// Vec3.right_contraction(Translator) -> Vec3
+impl RightContraction<Translator> for Vec3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Translator) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		//     w: W(self.w.0 * rhs.s.0),
+		// }
+		Vec3 {
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: self.w.right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Translator> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Translator) -> W
+impl Commutator<Translator> for Vec3 {
+	type Output = W;
+	fn commutator(self, rhs: Translator) -> Self::Output {
+		// -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0)
+		self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw)
+	}
+}
+
+// Vec3.anti_dot(Translator) -> Line
+impl AntiDot<Translator> for Vec3 {
+	type Output = Line;
+	fn anti_dot(self, rhs: Translator) -> Self::Output {
+		// Line {
+		//     dx: YW(self.w.0 * rhs.wx.0),
+		//     dy: -WX(self.w.0 * rhs.yw.0),
+		//     m : -XY(self.w.0 * rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		// }
+		Line {
+			dx: self.w.anti_dot(rhs.wx),
+			dy: self.w.anti_dot(rhs.yw),
+			m: self.w.anti_dot(rhs.s) + self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.yw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Rotor:
+
+// Vec3.geometric(Rotor) -> Multivector
+impl Geometric<Rotor> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.w.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+			w: self.w.geometric(rhs.s),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.w.geometric(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Rotor) -> Rotor
+impl AntiGeometric<Rotor> for Vec3 {
+	type Output = Rotor;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s : S(self.w.0 * rhs.xy.0),
+		//     xy: -XY(self.w.0 * rhs.s.0),
+		// }
+		Rotor {
+			s: self.w.anti_geometric(rhs.xy),
+			xy: self.w.anti_geometric(rhs.s),
+		}
+	}
+}
+
+// Vec3.dot(Rotor) -> Vec3
+impl Dot<Rotor> for Vec3 {
+	type Output = Vec3;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
 		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
-		//     w: W(self.w.0 * rhs.s.0) + W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     w: W(self.w.0 * rhs.s.0),
 		// }
 		Vec3 {
-			x: self.x.dot(rhs.s) - self.y.dot(rhs.xy),
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
 			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
-			w: self.w.dot(rhs.s) - self.x.dot(rhs.wx) + self.y.dot(rhs.yw),
+			w: self.w.dot(rhs.s),
 		}
 	}
 }
 
-// Omitted: Vec3 wedge Motor = self.w ^ rhs.s + self.w ^ rhs.xy + self.x ^ rhs.s + self.x ^ rhs.yw + self.y ^ rhs.s + self.y ^ rhs.wx  (unnamed type)
+impl std::ops::BitOr<Rotor> for Vec3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Rotor) -> Multivector
+impl Wedge<Rotor> for Vec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.w.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: self.w.wedge(rhs.s),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.w.wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Vec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Rotor) -> S
+impl AntiWedge<Rotor> for Vec3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// S(self.w.0 * rhs.xy.0)
+		self.w.anti_wedge(rhs.xy)
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Vec3 {
+	type Output = S;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Rotor) -> Vec2
+impl LeftContraction<Rotor> for Vec3 {
+	type Output = Vec2;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		// }
+		Vec2 {
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Vec3.right_contraction(Rotor) -> Vec3
+impl RightContraction<Rotor> for Vec3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		//     w: W(self.w.0 * rhs.s.0),
+		// }
+		Vec3 {
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: self.w.right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Rotor) -> Vec2
+impl Commutator<Rotor> for Vec3 {
+	type Output = Vec2;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Vec2 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		// }
+		Vec2 {
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+		}
+	}
+}
+
+// Vec3.anti_dot(Rotor) -> XY
+impl AntiDot<Rotor> for Vec3 {
+	type Output = XY;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// -XY(self.w.0 * rhs.s.0)
+		self.w.anti_dot(rhs.s)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Motor:
+
+// Vec3.geometric(Motor) -> Multivector
+impl Geometric<Motor> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.w.0 * rhs.xy.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+			w: self.w.geometric(rhs.s) + self.x.geometric(rhs.wx) + self.y.geometric(rhs.yw),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.w.geometric(rhs.xy) + self.x.geometric(rhs.yw) + self.y.geometric(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Motor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Motor) -> Motor
+impl AntiGeometric<Motor> for Vec3 {
+	type Output = Motor;
+	fn anti_geometric(self, rhs: Motor) -> Self::Output {
+		// Motor {
+		//     s : S(self.w.0 * rhs.xy.0) + S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     yw: YW(self.w.0 * rhs.wx.0),
+		//     wx: -WX(self.w.0 * rhs.yw.0),
+		//     xy: -XY(self.w.0 * rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		// }
+		Motor {
+			s: self.w.anti_geometric(rhs.xy) + self.x.anti_geometric(rhs.yw) + self.y.anti_geometric(rhs.wx),
+			yw: self.w.anti_geometric(rhs.wx),
+			wx: self.w.anti_geometric(rhs.yw),
+			xy: self.w.anti_geometric(rhs.s) + self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw),
+		}
+	}
+}
+
+// Vec3.dot(Motor) -> Vec3
+impl Dot<Motor> for Vec3 {
+	type Output = Vec3;
+	fn dot(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w: W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
+			w: self.w.dot(rhs.s) + self.x.dot(rhs.wx) + self.y.dot(rhs.yw),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor> for Vec3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Motor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Motor) -> Multivector
+impl Wedge<Motor> for Vec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Motor) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: XYW(self.w.0 * rhs.xy.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: self.w.wedge(rhs.s),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: self.w.wedge(rhs.xy) + self.x.wedge(rhs.yw) + self.y.wedge(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::BitXor<Motor> for Vec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Motor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
 // Vec3.anti_wedge(Motor) -> S
 impl AntiWedge<Motor> for Vec3 {
@@ -363,3 +1565,368 @@ impl AntiWedge<Motor> for Vec3 {
 		self.w.anti_wedge(rhs.xy) + self.x.anti_wedge(rhs.yw) + self.y.anti_wedge(rhs.wx)
 	}
 }
+
+impl std::ops::BitAnd<Motor> for Vec3 {
+	type Output = S;
+	fn bitand(self, rhs: Motor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Motor) -> Vec3
+impl LeftContraction<Motor> for Vec3 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		//     w: -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+			w: self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw),
+		}
+	}
+}
+
+// Vec3.right_contraction(Motor) -> Vec3
+impl RightContraction<Motor> for Vec3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		//     w: W(self.w.0 * rhs.s.0),
+		// }
+		Vec3 {
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: self.w.right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Motor> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Motor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Motor) -> Vec3
+impl Commutator<Motor> for Vec3 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Motor) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0),
+		//     y: Y(self.x.0 * rhs.xy.0),
+		//     w: -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		// }
+		Vec3 {
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+			w: self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw),
+		}
+	}
+}
+
+// Vec3.anti_dot(Motor) -> Line
+impl AntiDot<Motor> for Vec3 {
+	type Output = Line;
+	fn anti_dot(self, rhs: Motor) -> Self::Output {
+		// Line {
+		//     dx: YW(self.w.0 * rhs.wx.0),
+		//     dy: -WX(self.w.0 * rhs.yw.0),
+		//     m : -XY(self.w.0 * rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		// }
+		Line {
+			dx: self.w.anti_dot(rhs.wx),
+			dy: self.w.anti_dot(rhs.yw),
+			m: self.w.anti_dot(rhs.s) + self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.yw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Multivector:
+
+// Vec3.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) + YW(self.x.0 * rhs.xyw.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0) + WX(self.y.0 * rhs.xyw.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.w.0 * rhs.xy.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s),
+			w: self.w.geometric(rhs.s) + self.x.geometric(rhs.wx) + self.y.geometric(rhs.yw),
+			yw: self.w.geometric(rhs.y) + self.x.geometric(rhs.xyw) + self.y.geometric(rhs.w),
+			wx: self.w.geometric(rhs.x) + self.x.geometric(rhs.w) + self.y.geometric(rhs.xyw),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			xyw: self.w.geometric(rhs.xy) + self.x.geometric(rhs.yw) + self.y.geometric(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.w.0 * rhs.xy.0) + S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     x  : X(self.w.0 * rhs.y.0) + X(self.x.0 * rhs.xyw.0) - X(self.y.0 * rhs.w.0),
+		//     y  : -Y(self.w.0 * rhs.x.0) + Y(self.x.0 * rhs.w.0) + Y(self.y.0 * rhs.xyw.0),
+		//     w  : W(self.w.0 * rhs.xyw.0),
+		//     yw : YW(self.w.0 * rhs.wx.0),
+		//     wx : -WX(self.w.0 * rhs.yw.0),
+		//     xy : -XY(self.w.0 * rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		//     xyw: -XYW(self.w.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.w.anti_geometric(rhs.xy) + self.x.anti_geometric(rhs.yw) + self.y.anti_geometric(rhs.wx),
+			x: self.w.anti_geometric(rhs.y) + self.x.anti_geometric(rhs.xyw) + self.y.anti_geometric(rhs.w),
+			y: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(rhs.w) + self.y.anti_geometric(rhs.xyw),
+			w: self.w.anti_geometric(rhs.xyw),
+			yw: self.w.anti_geometric(rhs.wx),
+			wx: self.w.anti_geometric(rhs.yw),
+			xy: self.w.anti_geometric(rhs.s) + self.x.anti_geometric(rhs.wx) + self.y.anti_geometric(rhs.yw),
+			xyw: self.w.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Vec3.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.x.0 * rhs.xyw.0),
+		//     wx : WX(self.y.0 * rhs.xyw.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y),
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s),
+			w: self.w.dot(rhs.s) + self.x.dot(rhs.wx) + self.y.dot(rhs.yw),
+			yw: self.x.dot(rhs.xyw),
+			wx: self.y.dot(rhs.xyw),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: XYW(self.w.0 * rhs.xy.0) + XYW(self.x.0 * rhs.yw.0) + XYW(self.y.0 * rhs.wx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			w: self.w.wedge(rhs.s),
+			yw: self.w.wedge(rhs.y) + self.y.wedge(rhs.w),
+			wx: self.w.wedge(rhs.x) + self.x.wedge(rhs.w),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			xyw: self.w.wedge(rhs.xy) + self.x.wedge(rhs.yw) + self.y.wedge(rhs.wx),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.w.0 * rhs.xy.0) + S(self.x.0 * rhs.yw.0) + S(self.y.0 * rhs.wx.0),
+		//     x  : X(self.x.0 * rhs.xyw.0),
+		//     y  : Y(self.y.0 * rhs.xyw.0),
+		//     w  : W(self.w.0 * rhs.xyw.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.w.anti_wedge(rhs.xy) + self.x.anti_wedge(rhs.yw) + self.y.anti_wedge(rhs.wx),
+			x: self.x.anti_wedge(rhs.xyw),
+			y: self.y.anti_wedge(rhs.xyw),
+			w: self.w.anti_wedge(rhs.xyw),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : -X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : YW(self.x.0 * rhs.xyw.0),
+		//     wx : WX(self.y.0 * rhs.xyw.0),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y),
+			x: self.y.left_contraction(rhs.xy),
+			y: self.x.left_contraction(rhs.xy),
+			w: self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.yw),
+			yw: self.x.left_contraction(rhs.xyw),
+			wx: self.y.left_contraction(rhs.xyw),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Vec3.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     w  : W(self.w.0 * rhs.s.0),
+		//     yw : Default::default(),
+		//     wx : Default::default(),
+		//     xy : Default::default(),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y),
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			w: self.w.right_contraction(rhs.s),
+			yw: Default::default(),
+			wx: Default::default(),
+			xy: Default::default(),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Vec3.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Vec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y)
+	}
+}
+
+// Vec3.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.xy.0),
+		//     y  : Y(self.x.0 * rhs.xy.0),
+		//     w  : -W(self.x.0 * rhs.wx.0) + W(self.y.0 * rhs.yw.0),
+		//     yw : -YW(self.w.0 * rhs.y.0) + YW(self.y.0 * rhs.w.0),
+		//     wx : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.xy),
+			y: self.x.commutator(rhs.xy),
+			w: self.x.commutator(rhs.wx) + self.y.commutator(rhs.yw),
+			yw: self.w.commutator(rhs.y) + self.y.commutator(rhs.w),
+			wx: self.w.commutator(rhs.x) + self.x.commutator(rhs.w),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			xyw: Default::default(),
+		}
+	}
+}
+
+// Vec3.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.xyw.0),
+		//     y  : Y(self.y.0 * rhs.xyw.0),
+		//     w  : W(self.w.0 * rhs.xyw.0),
+		//     yw : YW(self.w.0 * rhs.wx.0),
+		//     wx : -WX(self.w.0 * rhs.yw.0),
+		//     xy : -XY(self.w.0 * rhs.s.0) - XY(self.x.0 * rhs.wx.0) + XY(self.y.0 * rhs.yw.0),
+		//     xyw: -XYW(self.w.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.anti_dot(rhs.xyw),
+			y: self.y.anti_dot(rhs.xyw),
+			w: self.w.anti_dot(rhs.xyw),
+			yw: self.w.anti_dot(rhs.wx),
+			wx: self.w.anti_dot(rhs.yw),
+			xy: self.w.anti_dot(rhs.s) + self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.yw),
+			xyw: self.w.anti_dot(rhs.w),
+		}
+	}
+}
@@ -8,6 +8,13 @@
 //! | Left complement  | XYZW | -YZW | -ZXW | -XYW | XYZ  | -YZ | -ZX | -XY | -WX | -WY | -WZ | X    | Y    | Z    | -W   | 1    |
 //! | Reverse          | 1    | X    | Y    | Z    | W    | -WX | -WY | -WZ | -YZ | -ZX | -XY | -YZW | -ZXW | -XYW | -XYZ | XYZW |
 //! | Anti-reverse     | 1    | -X   | -Y   | -Z   | -W   | -WX | -WY | -WZ | -YZ | -ZX | -XY | YZW  | ZXW  | XYW  | XYZ  | XYZW |
+//! | Involute         | 1    | -X   | -Y   | -Z   | -W   | WX  | WY  | WZ  | YZ  | ZX  | XY  | -YZW | -ZXW | -XYW | -XYZ | XYZW |
+//! | Conjugate        | 1    | -X   | -Y   | -Z   | -W   | -WX | -WY | -WZ | -YZ | -ZX | -XY | YZW  | ZXW  | XYW  | XYZ  | XYZW |
+//! | Dual             | XYZW | YZW  | ZXW  | XYW  | -XYZ | -YZ | -ZX | -XY | -WX | -WY | -WZ | X    | Y    | Z    | -W   | 1    |
+//! | Bulk             | 1    | X    | Y    | Z    | 0    | 0   | 0   | 0   | YZ  | ZX  | XY  | 0    | 0    | 0    | XYZ  | 0    |
+//! | Weight           | 0    | 0    | 0    | 0    | W    | WX  | WY  | WZ  | 0   | 0   | 0   | YZW  | ZXW  | XYW  | 0    | XYZW |
+//! | Bulk dual        | XYZW | YZW  | ZXW  | XYW  | 0    | 0   | 0   | 0   | -WX | -WY | -WZ | 0    | 0    | 0    | W    | 0    |
+//! | Weight dual      | 0    | 0    | 0    | 0    | -XYZ | -YZ | -ZX | -XY | 0   | 0   | 0   | -X   | -Y   | -Z   | 0    | 1    |
 //!
 //!
 //! ## Multiplication tables
@@ -35,46 +42,46 @@
 //!
 //! ### AntiGeometric multiplication table
 //!
-//! |      | 1    | X     | Y     | Z     | W    | WX    | WY    | WZ    | YZ    | ZX    | XY    | YZW  | ZXW  | XYW  | XYZ   | XYZW |
-//! | ---- | ---- | ----- | ----- | ----- | ---- | ----- | ----- | ----- | ----- | ----- | ----- | ---- | ---- | ---- | ----- | ---- |
-//! | 1    | 1    | X     | Y     | Z     | W    | WX    | WY    | WZ    | YZ    | ZX    | XY    | YZW  | ZXW  | XYW  | XYZ   | XYZW |
-//! | X    | X    | 1     | XY    | -ZX   | -WX  | -W    | -XYW  | ZXW   | XYZ   | -Z    | Y     | XYZW | WZ   | -WY  | YZ    | YZW  |
-//! | Y    | Y    | -XY   | 1     | YZ    | -WY  | XYW   | -W    | -YZW  | Z     | XYZ   | -X    | -WZ  | XYZW | WX   | ZX    | ZXW  |
-//! | Z    | Z    | ZX    | -YZ   | 1     | -WZ  | -ZXW  | YZW   | -W    | -Y    | X     | XYZ   | WY   | -WX  | XYZW | XY    | XYW  |
-//! | W    | W    | WX    | WY    | WZ    | 0    | 0     | 0     | 0     | YZW   | ZXW   | XYW   | 0    | 0    | 0    | -XYZW | 0    |
-//! | WX   | WX   | W     | XYW   | -ZXW  | 0    | 0     | 0     | 0     | -XYZW | -WZ   | WY    | 0    | 0    | 0    | YZW   | 0    |
-//! | WY   | WY   | -XYW  | W     | YZW   | 0    | 0     | 0     | 0     | WZ    | -XYZW | -WX   | 0    | 0    | 0    | ZXW   | 0    |
-//! | WZ   | WZ   | ZXW   | -YZW  | W     | 0    | 0     | 0     | 0     | -WY   | WX    | -XYZW | 0    | 0    | 0    | XYW   | 0    |
-//! | YZ   | YZ   | XYZ   | -Z    | Y     | YZW  | -XYZW | -WZ   | WY    | -1    | -XY   | ZX    | -W   | -XYW | ZXW  | -X    | WX   |
-//! | ZX   | ZX   | Z     | XYZ   | -X    | ZXW  | WZ    | -XYZW | -WX   | XY    | -1    | -YZ   | XYW  | -W   | -YZW | -Y    | WY   |
-//! | XY   | XY   | -Y    | X     | XYZ   | XYW  | -WY   | WX    | -XYZW | -ZX   | YZ    | -1    | -ZXW | YZW  | -W   | -Z    | WZ   |
-//! | YZW  | YZW  | -XYZW | -WZ   | WY    | 0    | 0     | 0     | 0     | -W    | -XYW  | ZXW   | 0    | 0    | 0    | -WX   | 0    |
-//! | ZXW  | ZXW  | WZ    | -XYZW | -WX   | 0    | 0     | 0     | 0     | XYW   | -W    | -YZW  | 0    | 0    | 0    | -WY   | 0    |
-//! | XYW  | XYW  | -WY   | WX    | -XYZW | 0    | 0     | 0     | 0     | -ZXW  | YZW   | -W    | 0    | 0    | 0    | -WZ   | 0    |
-//! | XYZ  | XYZ  | YZ    | ZX    | XY    | XYZW | -YZW  | -ZXW  | -XYW  | -X    | -Y    | -Z    | WX   | WY   | WZ   | -1    | -W   |
-//! | XYZW | XYZW | -YZW  | -ZXW  | -XYW  | 0    | 0     | 0     | 0     | WX    | WY    | WZ    | 0    | 0    | 0    | W     | 0    |
+//! |      | 1   | X   | Y   | Z   | W     | WX    | WY    | WZ    | YZ   | ZX   | XY   | YZW  | ZXW  | XYW  | XYZ | XYZW |
+//! | ---- | --- | --- | --- | --- | ----- | ----- | ----- | ----- | ---- | ---- | ---- | ---- | ---- | ---- | --- | ---- |
+//! | 1    | 0   | 0   | 0   | 0   | -XYZ  | YZ    | ZX    | XY    | 0    | 0    | 0    | X    | Y    | Z    | 0   | 1    |
+//! | X    | 0   | 0   | 0   | 0   | -YZ   | XYZ   | Z     | -Y    | 0    | 0    | 0    | 1    | -XY  | ZX   | 0   | X    |
+//! | Y    | 0   | 0   | 0   | 0   | -ZX   | -Z    | XYZ   | X     | 0    | 0    | 0    | XY   | 1    | -YZ  | 0   | Y    |
+//! | Z    | 0   | 0   | 0   | 0   | -XY   | Y     | -X    | XYZ   | 0    | 0    | 0    | -ZX  | YZ   | 1    | 0   | Z    |
+//! | W    | XYZ | YZ  | ZX  | XY  | -XYZW | YZW   | ZXW   | XYW   | -X   | -Y   | -Z   | -WX  | -WY  | -WZ  | -1  | W    |
+//! | WX   | YZ  | XYZ | Z   | -Y  | YZW   | -XYZW | WZ    | -WY   | -1   | XY   | -ZX  | -W   | XYW  | -ZXW | -X  | WX   |
+//! | WY   | ZX  | -Z  | XYZ | X   | ZXW   | -WZ   | -XYZW | WX    | -XY  | -1   | YZ   | -XYW | -W   | YZW  | -Y  | WY   |
+//! | WZ   | XY  | Y   | -X  | XYZ | XYW   | WY    | -WX   | -XYZW | ZX   | -YZ  | -1   | ZXW  | -YZW | -W   | -Z  | WZ   |
+//! | YZ   | 0   | 0   | 0   | 0   | X     | -1    | XY    | -ZX   | 0    | 0    | 0    | XYZ  | Z    | -Y   | 0   | YZ   |
+//! | ZX   | 0   | 0   | 0   | 0   | Y     | -XY   | -1    | YZ    | 0    | 0    | 0    | -Z   | XYZ  | X    | 0   | ZX   |
+//! | XY   | 0   | 0   | 0   | 0   | Z     | ZX    | -YZ   | -1    | 0    | 0    | 0    | Y    | -X   | XYZ  | 0   | XY   |
+//! | YZW  | -X  | -1  | XY  | -ZX | -WX   | -W    | XYW   | -ZXW  | -XYZ | -Z   | Y    | XYZW | -WZ  | WY   | -YZ | YZW  |
+//! | ZXW  | -Y  | -XY | -1  | YZ  | -WY   | -XYW  | -W    | YZW   | Z    | -XYZ | -X   | WZ   | XYZW | -WX  | -ZX | ZXW  |
+//! | XYW  | -Z  | ZX  | -YZ | -1  | -WZ   | ZXW   | -YZW  | -W    | -Y   | X    | -XYZ | -WY  | WX   | XYZW | -XY | XYW  |
+//! | XYZ  | 0   | 0   | 0   | 0   | 1     | -X    | -Y    | -Z    | 0    | 0    | 0    | YZ   | ZX   | XY   | 0   | XYZ  |
+//! | XYZW | 1   | X   | Y   | Z   | W     | WX    | WY    | WZ    | YZ   | ZX   | XY   | YZW  | ZXW  | XYW  | XYZ | XYZW |
 //!
 //!
 //! ### Dot multiplication table
 //!
-//! |      | 1    | X     | Y     | Z     | W    | WX    | WY    | WZ    | YZ    | ZX    | XY    | YZW  | ZXW  | XYW  | XYZ   | XYZW |
-//! | ---- | ---- | ----- | ----- | ----- | ---- | ----- | ----- | ----- | ----- | ----- | ----- | ---- | ---- | ---- | ----- | ---- |
-//! | 1    | 1    | X     | Y     | Z     | W    | WX    | WY    | WZ    | YZ    | ZX    | XY    | YZW  | ZXW  | XYW  | XYZ   | XYZW |
-//! | X    | X    | 1     | XY    | -ZX   | -WX  | -W    | -XYW  | ZXW   | XYZ   | -Z    | Y     | XYZW | WZ   | -WY  | YZ    | YZW  |
-//! | Y    | Y    | -XY   | 1     | YZ    | -WY  | XYW   | -W    | -YZW  | Z     | XYZ   | -X    | -WZ  | XYZW | WX   | ZX    | ZXW  |
-//! | Z    | Z    | ZX    | -YZ   | 1     | -WZ  | -ZXW  | YZW   | -W    | -Y    | X     | XYZ   | WY   | -WX  | XYZW | XY    | XYW  |
-//! | W    | W    | WX    | WY    | WZ    | 0    | 0     | 0     | 0     | YZW   | ZXW   | XYW   | 0    | 0    | 0    | -XYZW | 0    |
-//! | WX   | WX   | W     | XYW   | -ZXW  | 0    | 0     | 0     | 0     | -XYZW | -WZ   | WY    | 0    | 0    | 0    | YZW   | 0    |
-//! | WY   | WY   | -XYW  | W     | YZW   | 0    | 0     | 0     | 0     | WZ    | -XYZW | -WX   | 0    | 0    | 0    | ZXW   | 0    |
-//! | WZ   | WZ   | ZXW   | -YZW  | W     | 0    | 0     | 0     | 0     | -WY   | WX    | -XYZW | 0    | 0    | 0    | XYW   | 0    |
-//! | YZ   | YZ   | XYZ   | -Z    | Y     | YZW  | -XYZW | -WZ   | WY    | -1    | -XY   | ZX    | -W   | -XYW | ZXW  | -X    | WX   |
-//! | ZX   | ZX   | Z     | XYZ   | -X    | ZXW  | WZ    | -XYZW | -WX   | XY    | -1    | -YZ   | XYW  | -W   | -YZW | -Y    | WY   |
-//! | XY   | XY   | -Y    | X     | XYZ   | XYW  | -WY   | WX    | -XYZW | -ZX   | YZ    | -1    | -ZXW | YZW  | -W   | -Z    | WZ   |
-//! | YZW  | YZW  | -XYZW | -WZ   | WY    | 0    | 0     | 0     | 0     | -W    | -XYW  | ZXW   | 0    | 0    | 0    | -WX   | 0    |
-//! | ZXW  | ZXW  | WZ    | -XYZW | -WX   | 0    | 0     | 0     | 0     | XYW   | -W    | -YZW  | 0    | 0    | 0    | -WY   | 0    |
-//! | XYW  | XYW  | -WY   | WX    | -XYZW | 0    | 0     | 0     | 0     | -ZXW  | YZW   | -W    | 0    | 0    | 0    | -WZ   | 0    |
-//! | XYZ  | XYZ  | YZ    | ZX    | XY    | XYZW | -YZW  | -ZXW  | -XYW  | -X    | -Y    | -Z    | WX   | WY   | WZ   | -1    | -W   |
-//! | XYZW | XYZW | -YZW  | -ZXW  | -XYW  | 0    | 0     | 0     | 0     | WX    | WY    | WZ    | 0    | 0    | 0    | W     | 0    |
+//! |      | 1    | X    | Y    | Z    | W | WX | WY | WZ | YZ | ZX | XY | YZW | ZXW | XYW | XYZ | XYZW |
+//! | ---- | ---- | ---- | ---- | ---- | - | -- | -- | -- | -- | -- | -- | --- | --- | --- | --- | ---- |
+//! | 1    | 1    | X    | Y    | Z    | W | WX | WY | WZ | YZ | ZX | XY | YZW | ZXW | XYW | XYZ | XYZW |
+//! | X    | X    | 1    | 0    | 0    | 0 | -W | 0  | 0  | 0  | -Z | Y  | 0   | WZ  | -WY | YZ  | YZW  |
+//! | Y    | Y    | 0    | 1    | 0    | 0 | 0  | -W | 0  | Z  | 0  | -X | -WZ | 0   | WX  | ZX  | ZXW  |
+//! | Z    | Z    | 0    | 0    | 1    | 0 | 0  | 0  | -W | -Y | X  | 0  | WY  | -WX | 0   | XY  | XYW  |
+//! | W    | W    | 0    | 0    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WX   | WX   | W    | 0    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WY   | WY   | 0    | W    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WZ   | WZ   | 0    | 0    | W    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | YZ   | YZ   | 0    | -Z   | Y    | 0 | 0  | 0  | 0  | -1 | 0  | 0  | -W  | 0   | 0   | -X  | WX   |
+//! | ZX   | ZX   | Z    | 0    | -X   | 0 | 0  | 0  | 0  | 0  | -1 | 0  | 0   | -W  | 0   | -Y  | WY   |
+//! | XY   | XY   | -Y   | X    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | -1 | 0   | 0   | -W  | -Z  | WZ   |
+//! | YZW  | YZW  | 0    | -WZ  | WY   | 0 | 0  | 0  | 0  | -W | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | ZXW  | ZXW  | WZ   | 0    | -WX  | 0 | 0  | 0  | 0  | 0  | -W | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | XYW  | XYW  | -WY  | WX   | 0    | 0 | 0  | 0  | 0  | 0  | 0  | -W | 0   | 0   | 0   | 0   | 0    |
+//! | XYZ  | XYZ  | YZ   | ZX   | XY   | 0 | 0  | 0  | 0  | -X | -Y | -Z | 0   | 0   | 0   | -1  | -W   |
+//! | XYZW | XYZW | -YZW | -ZXW | -XYW | 0 | 0  | 0  | 0  | WX | WY | WZ | 0   | 0   | 0   | W   | 0    |
 //!
 //!
 //! ### Wedge multiplication table
@@ -82,9513 +89,27259 @@
 //! |      | 1    | X     | Y     | Z     | W    | WX    | WY    | WZ    | YZ    | ZX    | XY    | YZW  | ZXW  | XYW  | XYZ   | XYZW |
 //! | ---- | ---- | ----- | ----- | ----- | ---- | ----- | ----- | ----- | ----- | ----- | ----- | ---- | ---- | ---- | ----- | ---- |
 //! | 1    | 1    | X     | Y     | Z     | W    | WX    | WY    | WZ    | YZ    | ZX    | XY    | YZW  | ZXW  | XYW  | XYZ   | XYZW |
-//! | X    | X    | 1     | XY    | -ZX   | -WX  | -W    | -XYW  | ZXW   | XYZ   | -Z    | Y     | XYZW | WZ   | -WY  | YZ    | YZW  |
-//! | Y    | Y    | -XY   | 1     | YZ    | -WY  | XYW   | -W    | -YZW  | Z     | XYZ   | -X    | -WZ  | XYZW | WX   | ZX    | ZXW  |
-//! | Z    | Z    | ZX    | -YZ   | 1     | -WZ  | -ZXW  | YZW   | -W    | -Y    | X     | XYZ   | WY   | -WX  | XYZW | XY    | XYW  |
+//! | X    | X    | 0     | XY    | -ZX   | -WX  | 0     | -XYW  | ZXW   | XYZ   | 0     | 0     | XYZW | 0    | 0    | 0     | 0    |
+//! | Y    | Y    | -XY   | 0     | YZ    | -WY  | XYW   | 0     | -YZW  | 0     | XYZ   | 0     | 0    | XYZW | 0    | 0     | 0    |
+//! | Z    | Z    | ZX    | -YZ   | 0     | -WZ  | -ZXW  | YZW   | 0     | 0     | 0     | XYZ   | 0    | 0    | XYZW | 0     | 0    |
 //! | W    | W    | WX    | WY    | WZ    | 0    | 0     | 0     | 0     | YZW   | ZXW   | XYW   | 0    | 0    | 0    | -XYZW | 0    |
-//! | WX   | WX   | W     | XYW   | -ZXW  | 0    | 0     | 0     | 0     | -XYZW | -WZ   | WY    | 0    | 0    | 0    | YZW   | 0    |
-//! | WY   | WY   | -XYW  | W     | YZW   | 0    | 0     | 0     | 0     | WZ    | -XYZW | -WX   | 0    | 0    | 0    | ZXW   | 0    |
-//! | WZ   | WZ   | ZXW   | -YZW  | W     | 0    | 0     | 0     | 0     | -WY   | WX    | -XYZW | 0    | 0    | 0    | XYW   | 0    |
-//! | YZ   | YZ   | XYZ   | -Z    | Y     | YZW  | -XYZW | -WZ   | WY    | -1    | -XY   | ZX    | -W   | -XYW | ZXW  | -X    | WX   |
-//! | ZX   | ZX   | Z     | XYZ   | -X    | ZXW  | WZ    | -XYZW | -WX   | XY    | -1    | -YZ   | XYW  | -W   | -YZW | -Y    | WY   |
-//! | XY   | XY   | -Y    | X     | XYZ   | XYW  | -WY   | WX    | -XYZW | -ZX   | YZ    | -1    | -ZXW | YZW  | -W   | -Z    | WZ   |
-//! | YZW  | YZW  | -XYZW | -WZ   | WY    | 0    | 0     | 0     | 0     | -W    | -XYW  | ZXW   | 0    | 0    | 0    | -WX   | 0    |
-//! | ZXW  | ZXW  | WZ    | -XYZW | -WX   | 0    | 0     | 0     | 0     | XYW   | -W    | -YZW  | 0    | 0    | 0    | -WY   | 0    |
-//! | XYW  | XYW  | -WY   | WX    | -XYZW | 0    | 0     | 0     | 0     | -ZXW  | YZW   | -W    | 0    | 0    | 0    | -WZ   | 0    |
-//! | XYZ  | XYZ  | YZ    | ZX    | XY    | XYZW | -YZW  | -ZXW  | -XYW  | -X    | -Y    | -Z    | WX   | WY   | WZ   | -1    | -W   |
-//! | XYZW | XYZW | -YZW  | -ZXW  | -XYW  | 0    | 0     | 0     | 0     | WX    | WY    | WZ    | 0    | 0    | 0    | W     | 0    |
+//! | WX   | WX   | 0     | XYW   | -ZXW  | 0    | 0     | 0     | 0     | -XYZW | 0     | 0     | 0    | 0    | 0    | 0     | 0    |
+//! | WY   | WY   | -XYW  | 0     | YZW   | 0    | 0     | 0     | 0     | 0     | -XYZW | 0     | 0    | 0    | 0    | 0     | 0    |
+//! | WZ   | WZ   | ZXW   | -YZW  | 0     | 0    | 0     | 0     | 0     | 0     | 0     | -XYZW | 0    | 0    | 0    | 0     | 0    |
+//! | YZ   | YZ   | XYZ   | 0     | 0     | YZW  | -XYZW | 0     | 0     | 0     | 0     | 0     | 0    | 0    | 0    | 0     | 0    |
+//! | ZX   | ZX   | 0     | XYZ   | 0     | ZXW  | 0     | -XYZW | 0     | 0     | 0     | 0     | 0    | 0    | 0    | 0     | 0    |
+//! | XY   | XY   | 0     | 0     | XYZ   | XYW  | 0     | 0     | -XYZW | 0     | 0     | 0     | 0    | 0    | 0    | 0     | 0    |
+//! | YZW  | YZW  | -XYZW | 0     | 0     | 0    | 0     | 0     | 0     | 0     | 0     | 0     | 0    | 0    | 0    | 0     | 0    |
+//! | ZXW  | ZXW  | 0     | -XYZW | 0     | 0    | 0     | 0     | 0     | 0     | 0     | 0     | 0    | 0    | 0    | 0     | 0    |
+//! | XYW  | XYW  | 0     | 0     | -XYZW | 0    | 0     | 0     | 0     | 0     | 0     | 0     | 0    | 0    | 0    | 0     | 0    |
+//! | XYZ  | XYZ  | 0     | 0     | 0     | XYZW | 0     | 0     | 0     | 0     | 0     | 0     | 0    | 0    | 0    | 0     | 0    |
+//! | XYZW | XYZW | 0     | 0     | 0     | 0    | 0     | 0     | 0     | 0     | 0     | 0     | 0    | 0    | 0    | 0     | 0    |
 //!
 //!
 //! ### AntiWedge multiplication table
 //!
-//! |      | 1    | X     | Y     | Z     | W    | WX    | WY    | WZ    | YZ    | ZX    | XY    | YZW  | ZXW  | XYW  | XYZ   | XYZW |
-//! | ---- | ---- | ----- | ----- | ----- | ---- | ----- | ----- | ----- | ----- | ----- | ----- | ---- | ---- | ---- | ----- | ---- |
-//! | 1    | 1    | X     | Y     | Z     | W    | WX    | WY    | WZ    | YZ    | ZX    | XY    | YZW  | ZXW  | XYW  | XYZ   | XYZW |
-//! | X    | X    | 1     | XY    | -ZX   | -WX  | -W    | -XYW  | ZXW   | XYZ   | -Z    | Y     | XYZW | WZ   | -WY  | YZ    | YZW  |
-//! | Y    | Y    | -XY   | 1     | YZ    | -WY  | XYW   | -W    | -YZW  | Z     | XYZ   | -X    | -WZ  | XYZW | WX   | ZX    | ZXW  |
-//! | Z    | Z    | ZX    | -YZ   | 1     | -WZ  | -ZXW  | YZW   | -W    | -Y    | X     | XYZ   | WY   | -WX  | XYZW | XY    | XYW  |
-//! | W    | W    | WX    | WY    | WZ    | 0    | 0     | 0     | 0     | YZW   | ZXW   | XYW   | 0    | 0    | 0    | -XYZW | 0    |
-//! | WX   | WX   | W     | XYW   | -ZXW  | 0    | 0     | 0     | 0     | -XYZW | -WZ   | WY    | 0    | 0    | 0    | YZW   | 0    |
-//! | WY   | WY   | -XYW  | W     | YZW   | 0    | 0     | 0     | 0     | WZ    | -XYZW | -WX   | 0    | 0    | 0    | ZXW   | 0    |
-//! | WZ   | WZ   | ZXW   | -YZW  | W     | 0    | 0     | 0     | 0     | -WY   | WX    | -XYZW | 0    | 0    | 0    | XYW   | 0    |
-//! | YZ   | YZ   | XYZ   | -Z    | Y     | YZW  | -XYZW | -WZ   | WY    | -1    | -XY   | ZX    | -W   | -XYW | ZXW  | -X    | WX   |
-//! | ZX   | ZX   | Z     | XYZ   | -X    | ZXW  | WZ    | -XYZW | -WX   | XY    | -1    | -YZ   | XYW  | -W   | -YZW | -Y    | WY   |
-//! | XY   | XY   | -Y    | X     | XYZ   | XYW  | -WY   | WX    | -XYZW | -ZX   | YZ    | -1    | -ZXW | YZW  | -W   | -Z    | WZ   |
-//! | YZW  | YZW  | -XYZW | -WZ   | WY    | 0    | 0     | 0     | 0     | -W    | -XYW  | ZXW   | 0    | 0    | 0    | -WX   | 0    |
-//! | ZXW  | ZXW  | WZ    | -XYZW | -WX   | 0    | 0     | 0     | 0     | XYW   | -W    | -YZW  | 0    | 0    | 0    | -WY   | 0    |
-//! | XYW  | XYW  | -WY   | WX    | -XYZW | 0    | 0     | 0     | 0     | -ZXW  | YZW   | -W    | 0    | 0    | 0    | -WZ   | 0    |
-//! | XYZ  | XYZ  | YZ    | ZX    | XY    | XYZW | -YZW  | -ZXW  | -XYW  | -X    | -Y    | -Z    | WX   | WY   | WZ   | -1    | -W   |
-//! | XYZW | XYZW | -YZW  | -ZXW  | -XYW  | 0    | 0     | 0     | 0     | WX    | WY    | WZ    | 0    | 0    | 0    | W     | 0    |
-
-use derive_more::{Add, Mul, Neg, Sub};
+//! |      | 1 | X  | Y  | Z  | W | WX | WY | WZ | YZ | ZX | XY | YZW | ZXW | XYW | XYZ | XYZW |
+//! | ---- | - | -- | -- | -- | - | -- | -- | -- | -- | -- | -- | --- | --- | --- | --- | ---- |
+//! | 1    | 0 | 0  | 0  | 0  | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 1    |
+//! | X    | 0 | 0  | 0  | 0  | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 1   | 0   | 0   | 0   | X    |
+//! | Y    | 0 | 0  | 0  | 0  | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 1   | 0   | 0   | Y    |
+//! | Z    | 0 | 0  | 0  | 0  | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 1   | 0   | Z    |
+//! | W    | 0 | 0  | 0  | 0  | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | -1  | W    |
+//! | WX   | 0 | 0  | 0  | 0  | 0 | 0  | 0  | 0  | -1 | 0  | 0  | -W  | 0   | 0   | -X  | WX   |
+//! | WY   | 0 | 0  | 0  | 0  | 0 | 0  | 0  | 0  | 0  | -1 | 0  | 0   | -W  | 0   | -Y  | WY   |
+//! | WZ   | 0 | 0  | 0  | 0  | 0 | 0  | 0  | 0  | 0  | 0  | -1 | 0   | 0   | -W  | -Z  | WZ   |
+//! | YZ   | 0 | 0  | 0  | 0  | 0 | -1 | 0  | 0  | 0  | 0  | 0  | 0   | Z   | -Y  | 0   | YZ   |
+//! | ZX   | 0 | 0  | 0  | 0  | 0 | 0  | -1 | 0  | 0  | 0  | 0  | -Z  | 0   | X   | 0   | ZX   |
+//! | XY   | 0 | 0  | 0  | 0  | 0 | 0  | 0  | -1 | 0  | 0  | 0  | Y   | -X  | 0   | 0   | XY   |
+//! | YZW  | 0 | -1 | 0  | 0  | 0 | -W | 0  | 0  | 0  | -Z | Y  | 0   | -WZ | WY  | -YZ | YZW  |
+//! | ZXW  | 0 | 0  | -1 | 0  | 0 | 0  | -W | 0  | Z  | 0  | -X | WZ  | 0   | -WX | -ZX | ZXW  |
+//! | XYW  | 0 | 0  | 0  | -1 | 0 | 0  | 0  | -W | -Y | X  | 0  | -WY | WX  | 0   | -XY | XYW  |
+//! | XYZ  | 0 | 0  | 0  | 0  | 1 | -X | -Y | -Z | 0  | 0  | 0  | YZ  | ZX  | XY  | 0   | XYZ  |
+//! | XYZW | 1 | X  | Y  | Z  | W | WX | WY | WZ | YZ | ZX | XY | YZW | ZXW | XYW | XYZ | XYZW |
+//!
+//!
+//! ### LeftContraction multiplication table
+//!
+//! |      | 1 | X | Y | Z | W | WX | WY | WZ | YZ | ZX | XY | YZW | ZXW | XYW | XYZ | XYZW |
+//! | ---- | - | - | - | - | - | -- | -- | -- | -- | -- | -- | --- | --- | --- | --- | ---- |
+//! | 1    | 1 | X | Y | Z | W | WX | WY | WZ | YZ | ZX | XY | YZW | ZXW | XYW | XYZ | XYZW |
+//! | X    | 0 | 1 | 0 | 0 | 0 | -W | 0  | 0  | 0  | -Z | Y  | 0   | WZ  | -WY | YZ  | YZW  |
+//! | Y    | 0 | 0 | 1 | 0 | 0 | 0  | -W | 0  | Z  | 0  | -X | -WZ | 0   | WX  | ZX  | ZXW  |
+//! | Z    | 0 | 0 | 0 | 1 | 0 | 0  | 0  | -W | -Y | X  | 0  | WY  | -WX | 0   | XY  | XYW  |
+//! | W    | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WX   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WY   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WZ   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | YZ   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | -1 | 0  | 0  | -W  | 0   | 0   | -X  | WX   |
+//! | ZX   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | -1 | 0  | 0   | -W  | 0   | -Y  | WY   |
+//! | XY   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | -1 | 0   | 0   | -W  | -Z  | WZ   |
+//! | YZW  | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | ZXW  | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | XYW  | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | XYZ  | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | -1  | -W   |
+//! | XYZW | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//!
+//!
+//! ### RightContraction multiplication table
+//!
+//! |      | 1    | X    | Y    | Z    | W | WX | WY | WZ | YZ | ZX | XY | YZW | ZXW | XYW | XYZ | XYZW |
+//! | ---- | ---- | ---- | ---- | ---- | - | -- | -- | -- | -- | -- | -- | --- | --- | --- | --- | ---- |
+//! | 1    | 1    | 0    | 0    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | X    | X    | 1    | 0    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | Y    | Y    | 0    | 1    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | Z    | Z    | 0    | 0    | 1    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | W    | W    | 0    | 0    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WX   | WX   | W    | 0    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WY   | WY   | 0    | W    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WZ   | WZ   | 0    | 0    | W    | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | YZ   | YZ   | 0    | -Z   | Y    | 0 | 0  | 0  | 0  | -1 | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | ZX   | ZX   | Z    | 0    | -X   | 0 | 0  | 0  | 0  | 0  | -1 | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | XY   | XY   | -Y   | X    | 0    | 0 | 0  | 0  | 0  | 0  | 0  | -1 | 0   | 0   | 0   | 0   | 0    |
+//! | YZW  | YZW  | 0    | -WZ  | WY   | 0 | 0  | 0  | 0  | -W | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | ZXW  | ZXW  | WZ   | 0    | -WX  | 0 | 0  | 0  | 0  | 0  | -W | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | XYW  | XYW  | -WY  | WX   | 0    | 0 | 0  | 0  | 0  | 0  | 0  | -W | 0   | 0   | 0   | 0   | 0    |
+//! | XYZ  | XYZ  | YZ   | ZX   | XY   | 0 | 0  | 0  | 0  | -X | -Y | -Z | 0   | 0   | 0   | -1  | 0    |
+//! | XYZW | XYZW | -YZW | -ZXW | -XYW | 0 | 0  | 0  | 0  | WX | WY | WZ | 0   | 0   | 0   | W   | 0    |
+//!
+//!
+//! ### ScalarProduct multiplication table
+//!
+//! |      | 1 | X | Y | Z | W | WX | WY | WZ | YZ | ZX | XY | YZW | ZXW | XYW | XYZ | XYZW |
+//! | ---- | - | - | - | - | - | -- | -- | -- | -- | -- | -- | --- | --- | --- | --- | ---- |
+//! | 1    | 1 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | X    | 0 | 1 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | Y    | 0 | 0 | 1 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | Z    | 0 | 0 | 0 | 1 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | W    | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WX   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WY   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | WZ   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | YZ   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | -1 | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | ZX   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | -1 | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | XY   | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | -1 | 0   | 0   | 0   | 0   | 0    |
+//! | YZW  | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | ZXW  | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | XYW  | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//! | XYZ  | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | -1  | 0    |
+//! | XYZW | 0 | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0    |
+//!
+//!
+//! ### Commutator multiplication table
+//!
+//! |      | 1 | X     | Y     | Z     | W    | WX   | WY   | WZ   | YZ   | ZX   | XY   | YZW  | ZXW  | XYW  | XYZ   | XYZW |
+//! | ---- | - | ----- | ----- | ----- | ---- | ---- | ---- | ---- | ---- | ---- | ---- | ---- | ---- | ---- | ----- | ---- |
+//! | 1    | 0 | 0     | 0     | 0     | 0    | 0    | 0    | 0    | 0    | 0    | 0    | 0    | 0    | 0    | 0     | 0    |
+//! | X    | 0 | 0     | XY    | -ZX   | -WX  | -W   | 0    | 0    | 0    | -Z   | Y    | XYZW | 0    | 0    | 0     | YZW  |
+//! | Y    | 0 | -XY   | 0     | YZ    | -WY  | 0    | -W   | 0    | Z    | 0    | -X   | 0    | XYZW | 0    | 0     | ZXW  |
+//! | Z    | 0 | ZX    | -YZ   | 0     | -WZ  | 0    | 0    | -W   | -Y   | X    | 0    | 0    | 0    | XYZW | 0     | XYW  |
+//! | W    | 0 | WX    | WY    | WZ    | 0    | 0    | 0    | 0    | 0    | 0    | 0    | 0    | 0    | 0    | -XYZW | 0    |
+//! | WX   | 0 | W     | 0     | 0     | 0    | 0    | 0    | 0    | 0    | -WZ  | WY   | 0    | 0    | 0    | YZW   | 0    |
+//! | WY   | 0 | 0     | W     | 0     | 0    | 0    | 0    | 0    | WZ   | 0    | -WX  | 0    | 0    | 0    | ZXW   | 0    |
+//! | WZ   | 0 | 0     | 0     | W     | 0    | 0    | 0    | 0    | -WY  | WX   | 0    | 0    | 0    | 0    | XYW   | 0    |
+//! | YZ   | 0 | 0     | -Z    | Y     | 0    | 0    | -WZ  | WY   | 0    | -XY  | ZX   | 0    | -XYW | ZXW  | 0     | 0    |
+//! | ZX   | 0 | Z     | 0     | -X    | 0    | WZ   | 0    | -WX  | XY   | 0    | -YZ  | XYW  | 0    | -YZW | 0     | 0    |
+//! | XY   | 0 | -Y    | X     | 0     | 0    | -WY  | WX   | 0    | -ZX  | YZ   | 0    | -ZXW | YZW  | 0    | 0     | 0    |
+//! | YZW  | 0 | -XYZW | 0     | 0     | 0    | 0    | 0    | 0    | 0    | -XYW | ZXW  | 0    | 0    | 0    | -WX   | 0    |
+//! | ZXW  | 0 | 0     | -XYZW | 0     | 0    | 0    | 0    | 0    | XYW  | 0    | -YZW | 0    | 0    | 0    | -WY   | 0    |
+//! | XYW  | 0 | 0     | 0     | -XYZW | 0    | 0    | 0    | 0    | -ZXW | YZW  | 0    | 0    | 0    | 0    | -WZ   | 0    |
+//! | XYZ  | 0 | 0     | 0     | 0     | XYZW | -YZW | -ZXW | -XYW | 0    | 0    | 0    | WX   | WY   | WZ   | 0     | -W   |
+//! | XYZW | 0 | -YZW  | -ZXW  | -XYW  | 0    | 0    | 0    | 0    | 0    | 0    | 0    | 0    | 0    | 0    | W     | 0    |
+//!
+//!
+//! ### AntiDot multiplication table
+//!
+//! |      | 1   | X   | Y   | Z   | W     | WX    | WY    | WZ    | YZ   | ZX   | XY   | YZW  | ZXW  | XYW  | XYZ | XYZW |
+//! | ---- | --- | --- | --- | --- | ----- | ----- | ----- | ----- | ---- | ---- | ---- | ---- | ---- | ---- | --- | ---- |
+//! | 1    | 0   | 0   | 0   | 0   | -XYZ  | YZ    | ZX    | XY    | 0    | 0    | 0    | X    | Y    | Z    | 0   | 1    |
+//! | X    | 0   | 0   | 0   | 0   | 0     | XYZ   | 0     | 0     | 0    | 0    | 0    | 0    | -XY  | ZX   | 0   | X    |
+//! | Y    | 0   | 0   | 0   | 0   | 0     | 0     | XYZ   | 0     | 0    | 0    | 0    | XY   | 0    | -YZ  | 0   | Y    |
+//! | Z    | 0   | 0   | 0   | 0   | 0     | 0     | 0     | XYZ   | 0    | 0    | 0    | -ZX  | YZ   | 0    | 0   | Z    |
+//! | W    | XYZ | 0   | 0   | 0   | -XYZW | YZW   | ZXW   | XYW   | 0    | 0    | 0    | -WX  | -WY  | -WZ  | 0   | W    |
+//! | WX   | YZ  | XYZ | 0   | 0   | YZW   | -XYZW | 0     | 0     | 0    | 0    | 0    | 0    | XYW  | -ZXW | 0   | WX   |
+//! | WY   | ZX  | 0   | XYZ | 0   | ZXW   | 0     | -XYZW | 0     | 0    | 0    | 0    | -XYW | 0    | YZW  | 0   | WY   |
+//! | WZ   | XY  | 0   | 0   | XYZ | XYW   | 0     | 0     | -XYZW | 0    | 0    | 0    | ZXW  | -YZW | 0    | 0   | WZ   |
+//! | YZ   | 0   | 0   | 0   | 0   | 0     | 0     | 0     | 0     | 0    | 0    | 0    | XYZ  | 0    | 0    | 0   | YZ   |
+//! | ZX   | 0   | 0   | 0   | 0   | 0     | 0     | 0     | 0     | 0    | 0    | 0    | 0    | XYZ  | 0    | 0   | ZX   |
+//! | XY   | 0   | 0   | 0   | 0   | 0     | 0     | 0     | 0     | 0    | 0    | 0    | 0    | 0    | XYZ  | 0   | XY   |
+//! | YZW  | -X  | 0   | XY  | -ZX | -WX   | 0     | XYW   | -ZXW  | -XYZ | 0    | 0    | XYZW | 0    | 0    | 0   | YZW  |
+//! | ZXW  | -Y  | -XY | 0   | YZ  | -WY   | -XYW  | 0     | YZW   | 0    | -XYZ | 0    | 0    | XYZW | 0    | 0   | ZXW  |
+//! | XYW  | -Z  | ZX  | -YZ | 0   | -WZ   | ZXW   | -YZW  | 0     | 0    | 0    | -XYZ | 0    | 0    | XYZW | 0   | XYW  |
+//! | XYZ  | 0   | 0   | 0   | 0   | 0     | 0     | 0     | 0     | 0    | 0    | 0    | 0    | 0    | 0    | 0   | XYZ  |
+//! | XYZW | 1   | X   | Y   | Z   | W     | WX    | WY    | WZ    | YZ   | ZX   | XY   | YZW  | ZXW  | XYW  | XYZ | XYZW |
+
+use derive_more::{Add, Neg, Sub};
 
 use super::*;
 
 /// The scalar type (real numbers).
 /// Squares to 1.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub, Mul)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct S(pub f64);
 
+impl std::ops::Mul<f64> for S {
+	type Output = S;
+	fn mul(self, rhs: f64) -> Self::Output {
+		S(self.0 * rhs)
+	}
+}
+
+impl Square for S {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for S {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for S {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for S {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for S {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 1.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct X(pub f64);
 
+impl std::ops::Mul<f64> for X {
+	type Output = X;
+	fn mul(self, rhs: f64) -> Self::Output {
+		X(self.0 * rhs)
+	}
+}
+
+impl Square for X {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for X {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for X {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for X {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for X {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 1.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Y(pub f64);
 
+impl std::ops::Mul<f64> for Y {
+	type Output = Y;
+	fn mul(self, rhs: f64) -> Self::Output {
+		Y(self.0 * rhs)
+	}
+}
+
+impl Square for Y {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Y {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Y {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Y {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Y {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 1.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Z(pub f64);
 
+impl std::ops::Mul<f64> for Z {
+	type Output = Z;
+	fn mul(self, rhs: f64) -> Self::Output {
+		Z(self.0 * rhs)
+	}
+}
+
+impl Square for Z {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Z {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Z {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Z {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Z {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 0.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct W(pub f64);
 
+impl std::ops::Mul<f64> for W {
+	type Output = W;
+	fn mul(self, rhs: f64) -> Self::Output {
+		W(self.0 * rhs)
+	}
+}
+
+impl Square for W {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for W {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for W {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for W {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for W {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 0.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WX(pub f64);
 
+impl std::ops::Mul<f64> for WX {
+	type Output = WX;
+	fn mul(self, rhs: f64) -> Self::Output {
+		WX(self.0 * rhs)
+	}
+}
+
+impl Square for WX {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for WX {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for WX {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for WX {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for WX {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 0.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WY(pub f64);
 
+impl std::ops::Mul<f64> for WY {
+	type Output = WY;
+	fn mul(self, rhs: f64) -> Self::Output {
+		WY(self.0 * rhs)
+	}
+}
+
+impl Square for WY {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for WY {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for WY {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for WY {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for WY {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to 0.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WZ(pub f64);
 
+impl std::ops::Mul<f64> for WZ {
+	type Output = WZ;
+	fn mul(self, rhs: f64) -> Self::Output {
+		WZ(self.0 * rhs)
+	}
+}
+
+impl Square for WZ {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for WZ {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for WZ {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for WZ {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for WZ {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
 /// Squares to -1.
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct YZ(pub f64);
 
-/// Squares to -1.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
-pub struct ZX(pub f64);
+impl std::ops::Mul<f64> for YZ {
+	type Output = YZ;
+	fn mul(self, rhs: f64) -> Self::Output {
+		YZ(self.0 * rhs)
+	}
+}
+
+impl Square for YZ {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for YZ {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for YZ {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for YZ {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for YZ {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZX(pub f64);
+
+impl std::ops::Mul<f64> for ZX {
+	type Output = ZX;
+	fn mul(self, rhs: f64) -> Self::Output {
+		ZX(self.0 * rhs)
+	}
+}
+
+impl Square for ZX {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for ZX {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for ZX {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for ZX {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for ZX {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XY(pub f64);
+
+impl std::ops::Mul<f64> for XY {
+	type Output = XY;
+	fn mul(self, rhs: f64) -> Self::Output {
+		XY(self.0 * rhs)
+	}
+}
+
+impl Square for XY {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for XY {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for XY {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for XY {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for XY {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 0.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YZW(pub f64);
+
+impl std::ops::Mul<f64> for YZW {
+	type Output = YZW;
+	fn mul(self, rhs: f64) -> Self::Output {
+		YZW(self.0 * rhs)
+	}
+}
+
+impl Square for YZW {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for YZW {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for YZW {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for YZW {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for YZW {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 0.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZXW(pub f64);
+
+impl std::ops::Mul<f64> for ZXW {
+	type Output = ZXW;
+	fn mul(self, rhs: f64) -> Self::Output {
+		ZXW(self.0 * rhs)
+	}
+}
+
+impl Square for ZXW {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for ZXW {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for ZXW {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for ZXW {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for ZXW {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 0.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XYW(pub f64);
+
+impl std::ops::Mul<f64> for XYW {
+	type Output = XYW;
+	fn mul(self, rhs: f64) -> Self::Output {
+		XYW(self.0 * rhs)
+	}
+}
+
+impl Square for XYW {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for XYW {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for XYW {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for XYW {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for XYW {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XYZ(pub f64);
+
+impl std::ops::Mul<f64> for XYZ {
+	type Output = XYZ;
+	fn mul(self, rhs: f64) -> Self::Output {
+		XYZ(self.0 * rhs)
+	}
+}
+
+impl Square for XYZ {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for XYZ {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for XYZ {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for XYZ {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for XYZ {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// The pseudo-scalar.
+/// Squares to 0.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XYZW(pub f64);
+
+impl std::ops::Mul<f64> for XYZW {
+	type Output = XYZW;
+	fn mul(self, rhs: f64) -> Self::Output {
+		XYZW(self.0 * rhs)
+	}
+}
+
+impl Square for XYZW {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		0.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for XYZW {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for XYZW {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for XYZW {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for XYZW {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl RCompl for blades:
+
+impl RCompl for S {
+	type Output = XYZW;
+	fn rcompl(self) -> Self::Output {
+		XYZW(self.0)
+	}
+}
+
+impl std::ops::Not for S {
+	type Output = XYZW;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for X {
+	type Output = YZW;
+	fn rcompl(self) -> Self::Output {
+		YZW(self.0)
+	}
+}
+
+impl std::ops::Not for X {
+	type Output = YZW;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for Y {
+	type Output = ZXW;
+	fn rcompl(self) -> Self::Output {
+		ZXW(self.0)
+	}
+}
+
+impl std::ops::Not for Y {
+	type Output = ZXW;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for Z {
+	type Output = XYW;
+	fn rcompl(self) -> Self::Output {
+		XYW(self.0)
+	}
+}
+
+impl std::ops::Not for Z {
+	type Output = XYW;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for W {
+	type Output = XYZ;
+	fn rcompl(self) -> Self::Output {
+		XYZ(-self.0)
+	}
+}
+
+impl std::ops::Not for W {
+	type Output = XYZ;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for WX {
+	type Output = YZ;
+	fn rcompl(self) -> Self::Output {
+		YZ(-self.0)
+	}
+}
+
+impl std::ops::Not for WX {
+	type Output = YZ;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for WY {
+	type Output = ZX;
+	fn rcompl(self) -> Self::Output {
+		ZX(-self.0)
+	}
+}
+
+impl std::ops::Not for WY {
+	type Output = ZX;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for WZ {
+	type Output = XY;
+	fn rcompl(self) -> Self::Output {
+		XY(-self.0)
+	}
+}
+
+impl std::ops::Not for WZ {
+	type Output = XY;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for YZ {
+	type Output = WX;
+	fn rcompl(self) -> Self::Output {
+		WX(-self.0)
+	}
+}
+
+impl std::ops::Not for YZ {
+	type Output = WX;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for ZX {
+	type Output = WY;
+	fn rcompl(self) -> Self::Output {
+		WY(-self.0)
+	}
+}
+
+impl std::ops::Not for ZX {
+	type Output = WY;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for XY {
+	type Output = WZ;
+	fn rcompl(self) -> Self::Output {
+		WZ(-self.0)
+	}
+}
+
+impl std::ops::Not for XY {
+	type Output = WZ;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for YZW {
+	type Output = X;
+	fn rcompl(self) -> Self::Output {
+		X(-self.0)
+	}
+}
+
+impl std::ops::Not for YZW {
+	type Output = X;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for ZXW {
+	type Output = Y;
+	fn rcompl(self) -> Self::Output {
+		Y(-self.0)
+	}
+}
+
+impl std::ops::Not for ZXW {
+	type Output = Y;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for XYW {
+	type Output = Z;
+	fn rcompl(self) -> Self::Output {
+		Z(-self.0)
+	}
+}
+
+impl std::ops::Not for XYW {
+	type Output = Z;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for XYZ {
+	type Output = W;
+	fn rcompl(self) -> Self::Output {
+		W(self.0)
+	}
+}
+
+impl std::ops::Not for XYZ {
+	type Output = W;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for XYZW {
+	type Output = S;
+	fn rcompl(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+impl std::ops::Not for XYZW {
+	type Output = S;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl LCompl for blades:
+
+impl LCompl for S {
+	type Output = XYZW;
+	fn lcompl(self) -> Self::Output {
+		XYZW(self.0)
+	}
+}
+
+impl LCompl for X {
+	type Output = YZW;
+	fn lcompl(self) -> Self::Output {
+		YZW(-self.0)
+	}
+}
+
+impl LCompl for Y {
+	type Output = ZXW;
+	fn lcompl(self) -> Self::Output {
+		ZXW(-self.0)
+	}
+}
+
+impl LCompl for Z {
+	type Output = XYW;
+	fn lcompl(self) -> Self::Output {
+		XYW(-self.0)
+	}
+}
+
+impl LCompl for W {
+	type Output = XYZ;
+	fn lcompl(self) -> Self::Output {
+		XYZ(self.0)
+	}
+}
+
+impl LCompl for WX {
+	type Output = YZ;
+	fn lcompl(self) -> Self::Output {
+		YZ(-self.0)
+	}
+}
+
+impl LCompl for WY {
+	type Output = ZX;
+	fn lcompl(self) -> Self::Output {
+		ZX(-self.0)
+	}
+}
+
+impl LCompl for WZ {
+	type Output = XY;
+	fn lcompl(self) -> Self::Output {
+		XY(-self.0)
+	}
+}
+
+impl LCompl for YZ {
+	type Output = WX;
+	fn lcompl(self) -> Self::Output {
+		WX(-self.0)
+	}
+}
+
+impl LCompl for ZX {
+	type Output = WY;
+	fn lcompl(self) -> Self::Output {
+		WY(-self.0)
+	}
+}
+
+impl LCompl for XY {
+	type Output = WZ;
+	fn lcompl(self) -> Self::Output {
+		WZ(-self.0)
+	}
+}
+
+impl LCompl for YZW {
+	type Output = X;
+	fn lcompl(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl LCompl for ZXW {
+	type Output = Y;
+	fn lcompl(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl LCompl for XYW {
+	type Output = Z;
+	fn lcompl(self) -> Self::Output {
+		Z(self.0)
+	}
+}
+
+impl LCompl for XYZ {
+	type Output = W;
+	fn lcompl(self) -> Self::Output {
+		W(-self.0)
+	}
+}
+
+impl LCompl for XYZW {
+	type Output = S;
+	fn lcompl(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Reverse for blades:
+
+impl Reverse for S {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for X {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for Y {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for Z {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for W {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for WX {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for WY {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for WZ {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for YZ {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for ZX {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for XY {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for YZW {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for ZXW {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for XYW {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for XYZ {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for XYZW {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiReverse for blades:
+
+impl AntiReverse for S {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for X {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for Y {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for Z {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for W {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for WX {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for WY {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for WZ {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for YZ {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for ZX {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for XY {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for YZW {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for ZXW {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for XYW {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for XYZ {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for XYZW {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Involute for blades:
+
+impl Involute for S {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for X {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for Y {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for Z {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for W {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for WX {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for WY {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for WZ {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for YZ {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for ZX {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for XY {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for YZW {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for ZXW {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for XYW {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for XYZ {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for XYZW {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Conjugate for blades:
+
+impl Conjugate for S {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for X {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for Y {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for Z {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for W {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for WX {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for WY {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for WZ {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for YZ {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for ZX {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for XY {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for YZW {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for ZXW {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for XYW {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for XYZ {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for XYZW {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Dual for blades:
+
+impl Dual for S {
+	type Output = XYZW;
+	fn dual(self) -> Self::Output {
+		XYZW(self.0)
+	}
+}
+
+impl Dual for X {
+	type Output = YZW;
+	fn dual(self) -> Self::Output {
+		YZW(self.0)
+	}
+}
+
+impl Dual for Y {
+	type Output = ZXW;
+	fn dual(self) -> Self::Output {
+		ZXW(self.0)
+	}
+}
+
+impl Dual for Z {
+	type Output = XYW;
+	fn dual(self) -> Self::Output {
+		XYW(self.0)
+	}
+}
+
+impl Dual for W {
+	type Output = XYZ;
+	fn dual(self) -> Self::Output {
+		XYZ(-self.0)
+	}
+}
+
+impl Dual for WX {
+	type Output = YZ;
+	fn dual(self) -> Self::Output {
+		YZ(-self.0)
+	}
+}
+
+impl Dual for WY {
+	type Output = ZX;
+	fn dual(self) -> Self::Output {
+		ZX(-self.0)
+	}
+}
+
+impl Dual for WZ {
+	type Output = XY;
+	fn dual(self) -> Self::Output {
+		XY(-self.0)
+	}
+}
+
+impl Dual for YZ {
+	type Output = WX;
+	fn dual(self) -> Self::Output {
+		WX(-self.0)
+	}
+}
+
+impl Dual for ZX {
+	type Output = WY;
+	fn dual(self) -> Self::Output {
+		WY(-self.0)
+	}
+}
+
+impl Dual for XY {
+	type Output = WZ;
+	fn dual(self) -> Self::Output {
+		WZ(-self.0)
+	}
+}
+
+impl Dual for YZW {
+	type Output = X;
+	fn dual(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl Dual for ZXW {
+	type Output = Y;
+	fn dual(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl Dual for XYW {
+	type Output = Z;
+	fn dual(self) -> Self::Output {
+		Z(self.0)
+	}
+}
+
+impl Dual for XYZ {
+	type Output = W;
+	fn dual(self) -> Self::Output {
+		W(-self.0)
+	}
+}
+
+impl Dual for XYZW {
+	type Output = S;
+	fn dual(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Bulk for blades:
+
+impl Bulk for S {
+	type Output = S;
+	fn bulk(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+impl Bulk for X {
+	type Output = X;
+	fn bulk(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl Bulk for Y {
+	type Output = Y;
+	fn bulk(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl Bulk for Z {
+	type Output = Z;
+	fn bulk(self) -> Self::Output {
+		Z(self.0)
+	}
+}
+
+// Omitted: W.bulk() -> 0
+
+// Omitted: WX.bulk() -> 0
+
+// Omitted: WY.bulk() -> 0
+
+// Omitted: WZ.bulk() -> 0
+
+impl Bulk for YZ {
+	type Output = YZ;
+	fn bulk(self) -> Self::Output {
+		YZ(self.0)
+	}
+}
+
+impl Bulk for ZX {
+	type Output = ZX;
+	fn bulk(self) -> Self::Output {
+		ZX(self.0)
+	}
+}
+
+impl Bulk for XY {
+	type Output = XY;
+	fn bulk(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+// Omitted: YZW.bulk() -> 0
+
+// Omitted: ZXW.bulk() -> 0
+
+// Omitted: XYW.bulk() -> 0
+
+impl Bulk for XYZ {
+	type Output = XYZ;
+	fn bulk(self) -> Self::Output {
+		XYZ(self.0)
+	}
+}
+
+// Omitted: XYZW.bulk() -> 0
+
+// ---------------------------------------------------------------------
+// impl Weight for blades:
+
+// Omitted: S.weight() -> 0
+
+// Omitted: X.weight() -> 0
+
+// Omitted: Y.weight() -> 0
+
+// Omitted: Z.weight() -> 0
+
+impl Weight for W {
+	type Output = W;
+	fn weight(self) -> Self::Output {
+		W(self.0)
+	}
+}
+
+impl Weight for WX {
+	type Output = WX;
+	fn weight(self) -> Self::Output {
+		WX(self.0)
+	}
+}
+
+impl Weight for WY {
+	type Output = WY;
+	fn weight(self) -> Self::Output {
+		WY(self.0)
+	}
+}
+
+impl Weight for WZ {
+	type Output = WZ;
+	fn weight(self) -> Self::Output {
+		WZ(self.0)
+	}
+}
+
+// Omitted: YZ.weight() -> 0
+
+// Omitted: ZX.weight() -> 0
+
+// Omitted: XY.weight() -> 0
+
+impl Weight for YZW {
+	type Output = YZW;
+	fn weight(self) -> Self::Output {
+		YZW(self.0)
+	}
+}
+
+impl Weight for ZXW {
+	type Output = ZXW;
+	fn weight(self) -> Self::Output {
+		ZXW(self.0)
+	}
+}
+
+impl Weight for XYW {
+	type Output = XYW;
+	fn weight(self) -> Self::Output {
+		XYW(self.0)
+	}
+}
+
+// Omitted: XYZ.weight() -> 0
+
+impl Weight for XYZW {
+	type Output = XYZW;
+	fn weight(self) -> Self::Output {
+		XYZW(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl BulkDual for blades:
+
+impl BulkDual for S {
+	type Output = XYZW;
+	fn bulk_dual(self) -> Self::Output {
+		XYZW(self.0)
+	}
+}
+
+impl BulkDual for X {
+	type Output = YZW;
+	fn bulk_dual(self) -> Self::Output {
+		YZW(self.0)
+	}
+}
+
+impl BulkDual for Y {
+	type Output = ZXW;
+	fn bulk_dual(self) -> Self::Output {
+		ZXW(self.0)
+	}
+}
+
+impl BulkDual for Z {
+	type Output = XYW;
+	fn bulk_dual(self) -> Self::Output {
+		XYW(self.0)
+	}
+}
+
+// Omitted: W.bulk_dual() -> 0
+
+// Omitted: WX.bulk_dual() -> 0
+
+// Omitted: WY.bulk_dual() -> 0
+
+// Omitted: WZ.bulk_dual() -> 0
+
+impl BulkDual for YZ {
+	type Output = WX;
+	fn bulk_dual(self) -> Self::Output {
+		WX(-self.0)
+	}
+}
+
+impl BulkDual for ZX {
+	type Output = WY;
+	fn bulk_dual(self) -> Self::Output {
+		WY(-self.0)
+	}
+}
+
+impl BulkDual for XY {
+	type Output = WZ;
+	fn bulk_dual(self) -> Self::Output {
+		WZ(-self.0)
+	}
+}
+
+// Omitted: YZW.bulk_dual() -> 0
+
+// Omitted: ZXW.bulk_dual() -> 0
+
+// Omitted: XYW.bulk_dual() -> 0
+
+impl BulkDual for XYZ {
+	type Output = W;
+	fn bulk_dual(self) -> Self::Output {
+		W(self.0)
+	}
+}
+
+// Omitted: XYZW.bulk_dual() -> 0
+
+// ---------------------------------------------------------------------
+// impl WeightDual for blades:
+
+// Omitted: S.weight_dual() -> 0
+
+// Omitted: X.weight_dual() -> 0
+
+// Omitted: Y.weight_dual() -> 0
+
+// Omitted: Z.weight_dual() -> 0
+
+impl WeightDual for W {
+	type Output = XYZ;
+	fn weight_dual(self) -> Self::Output {
+		XYZ(-self.0)
+	}
+}
+
+impl WeightDual for WX {
+	type Output = YZ;
+	fn weight_dual(self) -> Self::Output {
+		YZ(-self.0)
+	}
+}
+
+impl WeightDual for WY {
+	type Output = ZX;
+	fn weight_dual(self) -> Self::Output {
+		ZX(-self.0)
+	}
+}
+
+impl WeightDual for WZ {
+	type Output = XY;
+	fn weight_dual(self) -> Self::Output {
+		XY(-self.0)
+	}
+}
+
+// Omitted: YZ.weight_dual() -> 0
+
+// Omitted: ZX.weight_dual() -> 0
+
+// Omitted: XY.weight_dual() -> 0
+
+impl WeightDual for YZW {
+	type Output = X;
+	fn weight_dual(self) -> Self::Output {
+		X(-self.0)
+	}
+}
+
+impl WeightDual for ZXW {
+	type Output = Y;
+	fn weight_dual(self) -> Self::Output {
+		Y(-self.0)
+	}
+}
+
+impl WeightDual for XYW {
+	type Output = Z;
+	fn weight_dual(self) -> Self::Output {
+		Z(-self.0)
+	}
+}
+
+// Omitted: XYZ.weight_dual() -> 0
+
+impl WeightDual for XYZW {
+	type Output = S;
+	fn weight_dual(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Geometric for blades:
+
+impl Geometric<S> for S {
+	type Output = S;
+	fn geometric(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for S {
+	type Output = S;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for S {
+	type Output = X;
+	fn geometric(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for S {
+	type Output = X;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for S {
+	type Output = Y;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for S {
+	type Output = Y;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for S {
+	type Output = Z;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for S {
+	type Output = Z;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for S {
+	type Output = W;
+	fn geometric(self, rhs: W) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for S {
+	type Output = W;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for S {
+	type Output = WX;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for S {
+	type Output = WX;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for S {
+	type Output = WY;
+	fn geometric(self, rhs: WY) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WY> for S {
+	type Output = WY;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for S {
+	type Output = WZ;
+	fn geometric(self, rhs: WZ) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WZ> for S {
+	type Output = WZ;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for S {
+	type Output = YZ;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for S {
+	type Output = YZ;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for S {
+	type Output = ZX;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for S {
+	type Output = ZX;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for S {
+	type Output = XY;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for S {
+	type Output = XY;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for S {
+	type Output = YZW;
+	fn geometric(self, rhs: YZW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZW> for S {
+	type Output = YZW;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for S {
+	type Output = ZXW;
+	fn geometric(self, rhs: ZXW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZXW> for S {
+	type Output = ZXW;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for S {
+	type Output = XYW;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for S {
+	type Output = XYW;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for S {
+	type Output = XYZ;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for S {
+	type Output = XYZ;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for S {
+	type Output = XYZW;
+	fn geometric(self, rhs: XYZW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZW> for S {
+	type Output = XYZW;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for X {
+	type Output = X;
+	fn geometric(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for X {
+	type Output = X;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for X {
+	type Output = S;
+	fn geometric(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for X {
+	type Output = S;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for X {
+	type Output = XY;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for X {
+	type Output = XY;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for X {
+	type Output = ZX;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for X {
+	type Output = ZX;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for X {
+	type Output = WX;
+	fn geometric(self, rhs: W) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for X {
+	type Output = WX;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for X {
+	type Output = W;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for X {
+	type Output = W;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for X {
+	type Output = XYW;
+	fn geometric(self, rhs: WY) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WY> for X {
+	type Output = XYW;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for X {
+	type Output = ZXW;
+	fn geometric(self, rhs: WZ) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WZ> for X {
+	type Output = ZXW;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for X {
+	type Output = XYZ;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for X {
+	type Output = XYZ;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for X {
+	type Output = Z;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for X {
+	type Output = Z;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for X {
+	type Output = Y;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for X {
+	type Output = Y;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for X {
+	type Output = XYZW;
+	fn geometric(self, rhs: YZW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZW> for X {
+	type Output = XYZW;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for X {
+	type Output = WZ;
+	fn geometric(self, rhs: ZXW) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZXW> for X {
+	type Output = WZ;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for X {
+	type Output = WY;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for X {
+	type Output = WY;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for X {
+	type Output = YZ;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for X {
+	type Output = YZ;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for X {
+	type Output = YZW;
+	fn geometric(self, rhs: XYZW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZW> for X {
+	type Output = YZW;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for Y {
+	type Output = Y;
+	fn geometric(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for Y {
+	type Output = Y;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for Y {
+	type Output = XY;
+	fn geometric(self, rhs: X) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for Y {
+	type Output = XY;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for Y {
+	type Output = S;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for Y {
+	type Output = S;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for Y {
+	type Output = YZ;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for Y {
+	type Output = YZ;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for Y {
+	type Output = WY;
+	fn geometric(self, rhs: W) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for Y {
+	type Output = WY;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for Y {
+	type Output = XYW;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for Y {
+	type Output = XYW;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for Y {
+	type Output = W;
+	fn geometric(self, rhs: WY) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WY> for Y {
+	type Output = W;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for Y {
+	type Output = YZW;
+	fn geometric(self, rhs: WZ) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WZ> for Y {
+	type Output = YZW;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for Y {
+	type Output = Z;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for Y {
+	type Output = Z;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for Y {
+	type Output = XYZ;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for Y {
+	type Output = XYZ;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for Y {
+	type Output = X;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for Y {
+	type Output = X;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for Y {
+	type Output = WZ;
+	fn geometric(self, rhs: YZW) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZW> for Y {
+	type Output = WZ;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for Y {
+	type Output = XYZW;
+	fn geometric(self, rhs: ZXW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZXW> for Y {
+	type Output = XYZW;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for Y {
+	type Output = WX;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for Y {
+	type Output = WX;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for Y {
+	type Output = ZX;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for Y {
+	type Output = ZX;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for Y {
+	type Output = ZXW;
+	fn geometric(self, rhs: XYZW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZW> for Y {
+	type Output = ZXW;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for Z {
+	type Output = Z;
+	fn geometric(self, rhs: S) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for Z {
+	type Output = Z;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for Z {
+	type Output = ZX;
+	fn geometric(self, rhs: X) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for Z {
+	type Output = ZX;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for Z {
+	type Output = YZ;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for Z {
+	type Output = YZ;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for Z {
+	type Output = S;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for Z {
+	type Output = S;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for Z {
+	type Output = WZ;
+	fn geometric(self, rhs: W) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for Z {
+	type Output = WZ;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for Z {
+	type Output = ZXW;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for Z {
+	type Output = ZXW;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for Z {
+	type Output = YZW;
+	fn geometric(self, rhs: WY) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WY> for Z {
+	type Output = YZW;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for Z {
+	type Output = W;
+	fn geometric(self, rhs: WZ) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WZ> for Z {
+	type Output = W;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for Z {
+	type Output = Y;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for Z {
+	type Output = Y;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for Z {
+	type Output = X;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for Z {
+	type Output = X;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for Z {
+	type Output = XYZ;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for Z {
+	type Output = XYZ;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for Z {
+	type Output = WY;
+	fn geometric(self, rhs: YZW) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZW> for Z {
+	type Output = WY;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for Z {
+	type Output = WX;
+	fn geometric(self, rhs: ZXW) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZXW> for Z {
+	type Output = WX;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for Z {
+	type Output = XYZW;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for Z {
+	type Output = XYZW;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for Z {
+	type Output = XY;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for Z {
+	type Output = XY;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for Z {
+	type Output = XYW;
+	fn geometric(self, rhs: XYZW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZW> for Z {
+	type Output = XYW;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for W {
+	type Output = W;
+	fn geometric(self, rhs: S) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for W {
+	type Output = W;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for W {
+	type Output = WX;
+	fn geometric(self, rhs: X) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for W {
+	type Output = WX;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for W {
+	type Output = WY;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for W {
+	type Output = WY;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for W {
+	type Output = WZ;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for W {
+	type Output = WZ;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for W {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for W {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WY> for W {
+	type Output = Zero;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WZ> for W {
+	type Output = Zero;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for W {
+	type Output = YZW;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for W {
+	type Output = YZW;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for W {
+	type Output = ZXW;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for W {
+	type Output = ZXW;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for W {
+	type Output = XYW;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for W {
+	type Output = XYW;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YZW> for W {
+	type Output = Zero;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<ZXW> for W {
+	type Output = Zero;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for W {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for W {
+	type Output = XYZW;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for W {
+	type Output = XYZW;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for W {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYZW> for W {
+	type Output = Zero;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for WX {
+	type Output = WX;
+	fn geometric(self, rhs: S) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for WX {
+	type Output = WX;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for WX {
+	type Output = W;
+	fn geometric(self, rhs: X) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for WX {
+	type Output = W;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for WX {
+	type Output = XYW;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for WX {
+	type Output = XYW;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for WX {
+	type Output = ZXW;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for WX {
+	type Output = ZXW;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WY> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WZ> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for WX {
+	type Output = XYZW;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for WX {
+	type Output = XYZW;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for WX {
+	type Output = WZ;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for WX {
+	type Output = WZ;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for WX {
+	type Output = WY;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for WX {
+	type Output = WY;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YZW> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<ZXW> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for WX {
+	type Output = YZW;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for WX {
+	type Output = YZW;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for WX {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYZW> for WX {
+	type Output = Zero;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for WY {
+	type Output = WY;
+	fn geometric(self, rhs: S) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for WY {
+	type Output = WY;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for WY {
+	type Output = XYW;
+	fn geometric(self, rhs: X) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for WY {
+	type Output = XYW;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for WY {
+	type Output = W;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for WY {
+	type Output = W;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for WY {
+	type Output = YZW;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for WY {
+	type Output = YZW;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for WY {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for WY {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for WY {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for WY {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for WY {
+	type Output = Zero;
+	fn geometric(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WY> for WY {
+	type Output = Zero;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for WY {
+	type Output = Zero;
+	fn geometric(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WZ> for WY {
+	type Output = Zero;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for WY {
+	type Output = WZ;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for WY {
+	type Output = WZ;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for WY {
+	type Output = XYZW;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for WY {
+	type Output = XYZW;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for WY {
+	type Output = WX;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for WY {
+	type Output = WX;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for WY {
+	type Output = Zero;
+	fn geometric(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YZW> for WY {
+	type Output = Zero;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for WY {
+	type Output = Zero;
+	fn geometric(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<ZXW> for WY {
+	type Output = Zero;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for WY {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for WY {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for WY {
+	type Output = ZXW;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for WY {
+	type Output = ZXW;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for WY {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYZW> for WY {
+	type Output = Zero;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for WZ {
+	type Output = WZ;
+	fn geometric(self, rhs: S) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for WZ {
+	type Output = WZ;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for WZ {
+	type Output = ZXW;
+	fn geometric(self, rhs: X) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for WZ {
+	type Output = ZXW;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for WZ {
+	type Output = YZW;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for WZ {
+	type Output = YZW;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for WZ {
+	type Output = W;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for WZ {
+	type Output = W;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for WZ {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for WZ {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for WZ {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for WZ {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for WZ {
+	type Output = Zero;
+	fn geometric(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WY> for WZ {
+	type Output = Zero;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for WZ {
+	type Output = Zero;
+	fn geometric(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WZ> for WZ {
+	type Output = Zero;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for WZ {
+	type Output = WY;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for WZ {
+	type Output = WY;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for WZ {
+	type Output = WX;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for WZ {
+	type Output = WX;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for WZ {
+	type Output = XYZW;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for WZ {
+	type Output = XYZW;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for WZ {
+	type Output = Zero;
+	fn geometric(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YZW> for WZ {
+	type Output = Zero;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for WZ {
+	type Output = Zero;
+	fn geometric(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<ZXW> for WZ {
+	type Output = Zero;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for WZ {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for WZ {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for WZ {
+	type Output = XYW;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for WZ {
+	type Output = XYW;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for WZ {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYZW> for WZ {
+	type Output = Zero;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for YZ {
+	type Output = YZ;
+	fn geometric(self, rhs: S) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for YZ {
+	type Output = YZ;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for YZ {
+	type Output = XYZ;
+	fn geometric(self, rhs: X) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for YZ {
+	type Output = XYZ;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for YZ {
+	type Output = Z;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for YZ {
+	type Output = Z;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for YZ {
+	type Output = Y;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for YZ {
+	type Output = Y;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for YZ {
+	type Output = YZW;
+	fn geometric(self, rhs: W) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for YZ {
+	type Output = YZW;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for YZ {
+	type Output = XYZW;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for YZ {
+	type Output = XYZW;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for YZ {
+	type Output = WZ;
+	fn geometric(self, rhs: WY) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WY> for YZ {
+	type Output = WZ;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for YZ {
+	type Output = WY;
+	fn geometric(self, rhs: WZ) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WZ> for YZ {
+	type Output = WY;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for YZ {
+	type Output = S;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for YZ {
+	type Output = S;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for YZ {
+	type Output = XY;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for YZ {
+	type Output = XY;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for YZ {
+	type Output = ZX;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for YZ {
+	type Output = ZX;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for YZ {
+	type Output = W;
+	fn geometric(self, rhs: YZW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZW> for YZ {
+	type Output = W;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for YZ {
+	type Output = XYW;
+	fn geometric(self, rhs: ZXW) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZXW> for YZ {
+	type Output = XYW;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for YZ {
+	type Output = ZXW;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for YZ {
+	type Output = ZXW;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for YZ {
+	type Output = X;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for YZ {
+	type Output = X;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for YZ {
+	type Output = WX;
+	fn geometric(self, rhs: XYZW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZW> for YZ {
+	type Output = WX;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for ZX {
+	type Output = ZX;
+	fn geometric(self, rhs: S) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for ZX {
+	type Output = ZX;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for ZX {
+	type Output = Z;
+	fn geometric(self, rhs: X) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for ZX {
+	type Output = Z;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for ZX {
+	type Output = XYZ;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for ZX {
+	type Output = XYZ;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for ZX {
+	type Output = X;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for ZX {
+	type Output = X;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for ZX {
+	type Output = ZXW;
+	fn geometric(self, rhs: W) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for ZX {
+	type Output = ZXW;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for ZX {
+	type Output = WZ;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for ZX {
+	type Output = WZ;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for ZX {
+	type Output = XYZW;
+	fn geometric(self, rhs: WY) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WY> for ZX {
+	type Output = XYZW;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for ZX {
+	type Output = WX;
+	fn geometric(self, rhs: WZ) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WZ> for ZX {
+	type Output = WX;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for ZX {
+	type Output = XY;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for ZX {
+	type Output = XY;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for ZX {
+	type Output = S;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for ZX {
+	type Output = S;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for ZX {
+	type Output = YZ;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for ZX {
+	type Output = YZ;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for ZX {
+	type Output = XYW;
+	fn geometric(self, rhs: YZW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZW> for ZX {
+	type Output = XYW;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for ZX {
+	type Output = W;
+	fn geometric(self, rhs: ZXW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZXW> for ZX {
+	type Output = W;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for ZX {
+	type Output = YZW;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for ZX {
+	type Output = YZW;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for ZX {
+	type Output = Y;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for ZX {
+	type Output = Y;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for ZX {
+	type Output = WY;
+	fn geometric(self, rhs: XYZW) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZW> for ZX {
+	type Output = WY;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for XY {
+	type Output = XY;
+	fn geometric(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for XY {
+	type Output = XY;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for XY {
+	type Output = Y;
+	fn geometric(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for XY {
+	type Output = Y;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for XY {
+	type Output = X;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for XY {
+	type Output = X;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for XY {
+	type Output = XYZ;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for XY {
+	type Output = XYZ;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for XY {
+	type Output = XYW;
+	fn geometric(self, rhs: W) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for XY {
+	type Output = XYW;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for XY {
+	type Output = WY;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for XY {
+	type Output = WY;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for XY {
+	type Output = WX;
+	fn geometric(self, rhs: WY) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WY> for XY {
+	type Output = WX;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for XY {
+	type Output = XYZW;
+	fn geometric(self, rhs: WZ) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WZ> for XY {
+	type Output = XYZW;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for XY {
+	type Output = ZX;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for XY {
+	type Output = ZX;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for XY {
+	type Output = YZ;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for XY {
+	type Output = YZ;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for XY {
+	type Output = S;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for XY {
+	type Output = S;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for XY {
+	type Output = ZXW;
+	fn geometric(self, rhs: YZW) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZW> for XY {
+	type Output = ZXW;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for XY {
+	type Output = YZW;
+	fn geometric(self, rhs: ZXW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZXW> for XY {
+	type Output = YZW;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for XY {
+	type Output = W;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for XY {
+	type Output = W;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for XY {
+	type Output = Z;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for XY {
+	type Output = Z;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for XY {
+	type Output = WZ;
+	fn geometric(self, rhs: XYZW) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZW> for XY {
+	type Output = WZ;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for YZW {
+	type Output = YZW;
+	fn geometric(self, rhs: S) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for YZW {
+	type Output = YZW;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for YZW {
+	type Output = XYZW;
+	fn geometric(self, rhs: X) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for YZW {
+	type Output = XYZW;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for YZW {
+	type Output = WZ;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for YZW {
+	type Output = WZ;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for YZW {
+	type Output = WY;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for YZW {
+	type Output = WY;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for YZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for YZW {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for YZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for YZW {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for YZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WY> for YZW {
+	type Output = Zero;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for YZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WZ> for YZW {
+	type Output = Zero;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for YZW {
+	type Output = W;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for YZW {
+	type Output = W;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for YZW {
+	type Output = XYW;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for YZW {
+	type Output = XYW;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for YZW {
+	type Output = ZXW;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for YZW {
+	type Output = ZXW;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for YZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YZW> for YZW {
+	type Output = Zero;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for YZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<ZXW> for YZW {
+	type Output = Zero;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for YZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for YZW {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for YZW {
+	type Output = WX;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for YZW {
+	type Output = WX;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for YZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYZW> for YZW {
+	type Output = Zero;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for ZXW {
+	type Output = ZXW;
+	fn geometric(self, rhs: S) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for ZXW {
+	type Output = ZXW;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for ZXW {
+	type Output = WZ;
+	fn geometric(self, rhs: X) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for ZXW {
+	type Output = WZ;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for ZXW {
+	type Output = XYZW;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for ZXW {
+	type Output = XYZW;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for ZXW {
+	type Output = WX;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for ZXW {
+	type Output = WX;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for ZXW {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for ZXW {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for ZXW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for ZXW {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for ZXW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WY> for ZXW {
+	type Output = Zero;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for ZXW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WZ> for ZXW {
+	type Output = Zero;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for ZXW {
+	type Output = XYW;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for ZXW {
+	type Output = XYW;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for ZXW {
+	type Output = W;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for ZXW {
+	type Output = W;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for ZXW {
+	type Output = YZW;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for ZXW {
+	type Output = YZW;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for ZXW {
+	type Output = Zero;
+	fn geometric(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YZW> for ZXW {
+	type Output = Zero;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for ZXW {
+	type Output = Zero;
+	fn geometric(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<ZXW> for ZXW {
+	type Output = Zero;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for ZXW {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for ZXW {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for ZXW {
+	type Output = WY;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for ZXW {
+	type Output = WY;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for ZXW {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYZW> for ZXW {
+	type Output = Zero;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for XYW {
+	type Output = XYW;
+	fn geometric(self, rhs: S) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for XYW {
+	type Output = XYW;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for XYW {
+	type Output = WY;
+	fn geometric(self, rhs: X) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for XYW {
+	type Output = WY;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for XYW {
+	type Output = WX;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for XYW {
+	type Output = WX;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for XYW {
+	type Output = XYZW;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for XYW {
+	type Output = XYZW;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WY> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WZ> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for XYW {
+	type Output = ZXW;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for XYW {
+	type Output = ZXW;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for XYW {
+	type Output = YZW;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for XYW {
+	type Output = YZW;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for XYW {
+	type Output = W;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for XYW {
+	type Output = W;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YZW> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<ZXW> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for XYW {
+	type Output = WZ;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for XYW {
+	type Output = WZ;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for XYW {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYZW> for XYW {
+	type Output = Zero;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for XYZ {
+	type Output = XYZ;
+	fn geometric(self, rhs: S) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for XYZ {
+	type Output = XYZ;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for XYZ {
+	type Output = YZ;
+	fn geometric(self, rhs: X) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for XYZ {
+	type Output = YZ;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for XYZ {
+	type Output = ZX;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for XYZ {
+	type Output = ZX;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for XYZ {
+	type Output = XY;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for XYZ {
+	type Output = XY;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for XYZ {
+	type Output = XYZW;
+	fn geometric(self, rhs: W) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<W> for XYZ {
+	type Output = XYZW;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for XYZ {
+	type Output = YZW;
+	fn geometric(self, rhs: WX) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WX> for XYZ {
+	type Output = YZW;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for XYZ {
+	type Output = ZXW;
+	fn geometric(self, rhs: WY) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WY> for XYZ {
+	type Output = ZXW;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for XYZ {
+	type Output = XYW;
+	fn geometric(self, rhs: WZ) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<WZ> for XYZ {
+	type Output = XYW;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for XYZ {
+	type Output = X;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for XYZ {
+	type Output = X;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for XYZ {
+	type Output = Y;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for XYZ {
+	type Output = Y;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for XYZ {
+	type Output = Z;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for XYZ {
+	type Output = Z;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for XYZ {
+	type Output = WX;
+	fn geometric(self, rhs: YZW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZW> for XYZ {
+	type Output = WX;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for XYZ {
+	type Output = WY;
+	fn geometric(self, rhs: ZXW) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZXW> for XYZ {
+	type Output = WY;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for XYZ {
+	type Output = WZ;
+	fn geometric(self, rhs: XYW) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYW> for XYZ {
+	type Output = WZ;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for XYZ {
+	type Output = S;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for XYZ {
+	type Output = S;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for XYZ {
+	type Output = W;
+	fn geometric(self, rhs: XYZW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZW> for XYZ {
+	type Output = W;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for XYZW {
+	type Output = XYZW;
+	fn geometric(self, rhs: S) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for XYZW {
+	type Output = XYZW;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for XYZW {
+	type Output = YZW;
+	fn geometric(self, rhs: X) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for XYZW {
+	type Output = YZW;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for XYZW {
+	type Output = ZXW;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for XYZW {
+	type Output = ZXW;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for XYZW {
+	type Output = XYW;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for XYZW {
+	type Output = XYW;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<W> for XYZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<W> for XYZW {
+	type Output = Zero;
+	fn mul(self, rhs: W) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WX> for XYZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WX> for XYZW {
+	type Output = Zero;
+	fn mul(self, rhs: WX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WY> for XYZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WY> for XYZW {
+	type Output = Zero;
+	fn mul(self, rhs: WY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<WZ> for XYZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<WZ> for XYZW {
+	type Output = Zero;
+	fn mul(self, rhs: WZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for XYZW {
+	type Output = WX;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for XYZW {
+	type Output = WX;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for XYZW {
+	type Output = WY;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for XYZW {
+	type Output = WY;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for XYZW {
+	type Output = WZ;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for XYZW {
+	type Output = WZ;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZW> for XYZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<YZW> for XYZW {
+	type Output = Zero;
+	fn mul(self, rhs: YZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZXW> for XYZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<ZXW> for XYZW {
+	type Output = Zero;
+	fn mul(self, rhs: ZXW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYW> for XYZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYW> for XYZW {
+	type Output = Zero;
+	fn mul(self, rhs: XYW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for XYZW {
+	type Output = W;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for XYZW {
+	type Output = W;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZW> for XYZW {
+	type Output = Zero;
+	fn geometric(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<XYZW> for XYZW {
+	type Output = Zero;
+	fn mul(self, rhs: XYZW) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiGeometric for blades:
+
+impl AntiGeometric<S> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Z> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for S {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for S {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for S {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for S {
+	type Output = XY;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<ZX> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XY> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<YZW> for S {
+	type Output = X;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for S {
+	type Output = Y;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for S {
+	type Output = Z;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for S {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYZW> for S {
+	type Output = S;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Z> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for X {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for X {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for X {
+	type Output = Z;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for X {
+	type Output = Y;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<ZX> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XY> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<YZW> for X {
+	type Output = S;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for X {
+	type Output = XY;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for X {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for X {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYZW> for X {
+	type Output = X;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Z> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for Y {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for Y {
+	type Output = Z;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for Y {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for Y {
+	type Output = X;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<ZX> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XY> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<YZW> for Y {
+	type Output = XY;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for Y {
+	type Output = S;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for Y {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for Y {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYZW> for Y {
+	type Output = Y;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for Z {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for Z {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for Z {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Z> for Z {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for Z {
+	type Output = XY;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for Z {
+	type Output = Y;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for Z {
+	type Output = X;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for Z {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for Z {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<ZX> for Z {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XY> for Z {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<YZW> for Z {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for Z {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for Z {
+	type Output = S;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for Z {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYZW> for Z {
+	type Output = Z;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for W {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for W {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for W {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for W {
+	type Output = XY;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for W {
+	type Output = XYZW;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for W {
+	type Output = YZW;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for W {
+	type Output = ZXW;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for W {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for W {
+	type Output = X;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for W {
+	type Output = Y;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for W {
+	type Output = Z;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZW> for W {
+	type Output = WX;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for W {
+	type Output = WY;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for W {
+	type Output = WZ;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for W {
+	type Output = S;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZW> for W {
+	type Output = W;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for WX {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for WX {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for WX {
+	type Output = Z;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for WX {
+	type Output = Y;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for WX {
+	type Output = YZW;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for WX {
+	type Output = XYZW;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for WX {
+	type Output = WZ;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for WX {
+	type Output = WY;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for WX {
+	type Output = S;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for WX {
+	type Output = XY;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for WX {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZW> for WX {
+	type Output = W;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for WX {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for WX {
+	type Output = ZXW;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for WX {
+	type Output = X;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZW> for WX {
+	type Output = WX;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for WY {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for WY {
+	type Output = Z;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for WY {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for WY {
+	type Output = X;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for WY {
+	type Output = ZXW;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for WY {
+	type Output = WZ;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for WY {
+	type Output = XYZW;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for WY {
+	type Output = WX;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for WY {
+	type Output = XY;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for WY {
+	type Output = S;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for WY {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZW> for WY {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for WY {
+	type Output = W;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for WY {
+	type Output = YZW;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for WY {
+	type Output = Y;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZW> for WY {
+	type Output = WY;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for WZ {
+	type Output = XY;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for WZ {
+	type Output = Y;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for WZ {
+	type Output = X;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for WZ {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for WZ {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for WZ {
+	type Output = WY;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for WZ {
+	type Output = WX;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for WZ {
+	type Output = XYZW;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for WZ {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for WZ {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for WZ {
+	type Output = S;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZW> for WZ {
+	type Output = ZXW;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for WZ {
+	type Output = YZW;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for WZ {
+	type Output = W;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for WZ {
+	type Output = Z;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZW> for WZ {
+	type Output = WZ;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for YZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for YZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for YZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Z> for YZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for YZ {
+	type Output = X;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for YZ {
+	type Output = S;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for YZ {
+	type Output = XY;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for YZ {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for YZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<ZX> for YZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XY> for YZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<YZW> for YZ {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for YZ {
+	type Output = Z;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for YZ {
+	type Output = Y;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for YZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYZW> for YZ {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for ZX {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for ZX {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for ZX {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Z> for ZX {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for ZX {
+	type Output = Y;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for ZX {
+	type Output = XY;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for ZX {
+	type Output = S;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for ZX {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for ZX {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<ZX> for ZX {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XY> for ZX {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<YZW> for ZX {
+	type Output = Z;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for ZX {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for ZX {
+	type Output = X;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for ZX {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYZW> for ZX {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Z> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for XY {
+	type Output = Z;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for XY {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for XY {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for XY {
+	type Output = S;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<ZX> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XY> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<YZW> for XY {
+	type Output = Y;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for XY {
+	type Output = X;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for XY {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for XY {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYZW> for XY {
+	type Output = XY;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for YZW {
+	type Output = X;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for YZW {
+	type Output = S;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for YZW {
+	type Output = XY;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for YZW {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for YZW {
+	type Output = WX;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for YZW {
+	type Output = W;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for YZW {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for YZW {
+	type Output = ZXW;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for YZW {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for YZW {
+	type Output = Z;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for YZW {
+	type Output = Y;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZW> for YZW {
+	type Output = XYZW;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for YZW {
+	type Output = WZ;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for YZW {
+	type Output = WY;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for YZW {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZW> for YZW {
+	type Output = YZW;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for ZXW {
+	type Output = Y;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for ZXW {
+	type Output = XY;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for ZXW {
+	type Output = S;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for ZXW {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for ZXW {
+	type Output = WY;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for ZXW {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for ZXW {
+	type Output = W;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for ZXW {
+	type Output = YZW;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for ZXW {
+	type Output = Z;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for ZXW {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for ZXW {
+	type Output = X;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZW> for ZXW {
+	type Output = WZ;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for ZXW {
+	type Output = XYZW;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for ZXW {
+	type Output = WX;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for ZXW {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZW> for ZXW {
+	type Output = ZXW;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for XYW {
+	type Output = Z;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for XYW {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for XYW {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for XYW {
+	type Output = S;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for XYW {
+	type Output = WZ;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for XYW {
+	type Output = ZXW;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for XYW {
+	type Output = YZW;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for XYW {
+	type Output = W;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for XYW {
+	type Output = Y;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for XYW {
+	type Output = X;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for XYW {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZW> for XYW {
+	type Output = WY;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for XYW {
+	type Output = WX;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for XYW {
+	type Output = XYZW;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for XYW {
+	type Output = XY;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZW> for XYW {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for XYZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<X> for XYZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Y> for XYZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<Z> for XYZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<W> for XYZ {
+	type Output = S;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for XYZ {
+	type Output = X;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for XYZ {
+	type Output = Y;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for XYZ {
+	type Output = Z;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for XYZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<ZX> for XYZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XY> for XYZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<YZW> for XYZ {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for XYZ {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for XYZ {
+	type Output = XY;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for XYZ {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiGeometric<XYZW> for XYZ {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for XYZW {
+	type Output = S;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for XYZW {
+	type Output = X;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for XYZW {
+	type Output = Y;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for XYZW {
+	type Output = Z;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<W> for XYZW {
+	type Output = W;
+	fn anti_geometric(self, rhs: W) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WX> for XYZW {
+	type Output = WX;
+	fn anti_geometric(self, rhs: WX) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WY> for XYZW {
+	type Output = WY;
+	fn anti_geometric(self, rhs: WY) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<WZ> for XYZW {
+	type Output = WZ;
+	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for XYZW {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for XYZW {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for XYZW {
+	type Output = XY;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZW> for XYZW {
+	type Output = YZW;
+	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZXW> for XYZW {
+	type Output = ZXW;
+	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYW> for XYZW {
+	type Output = XYW;
+	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for XYZW {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZW> for XYZW {
+	type Output = XYZW;
+	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Dot for blades:
+
+impl Dot<S> for S {
+	type Output = S;
+	fn dot(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for S {
+	type Output = S;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for S {
+	type Output = X;
+	fn dot(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for S {
+	type Output = X;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for S {
+	type Output = Y;
+	fn dot(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for S {
+	type Output = Y;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for S {
+	type Output = Z;
+	fn dot(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for S {
+	type Output = Z;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for S {
+	type Output = W;
+	fn dot(self, rhs: W) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<W> for S {
+	type Output = W;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for S {
+	type Output = WX;
+	fn dot(self, rhs: WX) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<WX> for S {
+	type Output = WX;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for S {
+	type Output = WY;
+	fn dot(self, rhs: WY) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<WY> for S {
+	type Output = WY;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for S {
+	type Output = WZ;
+	fn dot(self, rhs: WZ) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<WZ> for S {
+	type Output = WZ;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for S {
+	type Output = YZ;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for S {
+	type Output = YZ;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for S {
+	type Output = ZX;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for S {
+	type Output = ZX;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for S {
+	type Output = XY;
+	fn dot(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for S {
+	type Output = XY;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for S {
+	type Output = YZW;
+	fn dot(self, rhs: YZW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZW> for S {
+	type Output = YZW;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for S {
+	type Output = ZXW;
+	fn dot(self, rhs: ZXW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZXW> for S {
+	type Output = ZXW;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for S {
+	type Output = XYW;
+	fn dot(self, rhs: XYW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYW> for S {
+	type Output = XYW;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for S {
+	type Output = XYZ;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for S {
+	type Output = XYZ;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for S {
+	type Output = XYZW;
+	fn dot(self, rhs: XYZW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZW> for S {
+	type Output = XYZW;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for X {
+	type Output = X;
+	fn dot(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for X {
+	type Output = X;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for X {
+	type Output = S;
+	fn dot(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for X {
+	type Output = S;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Z> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for X {
+	type Output = W;
+	fn dot(self, rhs: WX) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<WX> for X {
+	type Output = W;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for X {
+	type Output = Z;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for X {
+	type Output = Z;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for X {
+	type Output = Y;
+	fn dot(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for X {
+	type Output = Y;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for X {
+	type Output = WZ;
+	fn dot(self, rhs: ZXW) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZXW> for X {
+	type Output = WZ;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for X {
+	type Output = WY;
+	fn dot(self, rhs: XYW) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYW> for X {
+	type Output = WY;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for X {
+	type Output = YZ;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for X {
+	type Output = YZ;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for X {
+	type Output = YZW;
+	fn dot(self, rhs: XYZW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZW> for X {
+	type Output = YZW;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for Y {
+	type Output = Y;
+	fn dot(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for Y {
+	type Output = Y;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for Y {
+	type Output = S;
+	fn dot(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for Y {
+	type Output = S;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Z> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for Y {
+	type Output = W;
+	fn dot(self, rhs: WY) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<WY> for Y {
+	type Output = W;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for Y {
+	type Output = Z;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for Y {
+	type Output = Z;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for Y {
+	type Output = X;
+	fn dot(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for Y {
+	type Output = X;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for Y {
+	type Output = WZ;
+	fn dot(self, rhs: YZW) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZW> for Y {
+	type Output = WZ;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for Y {
+	type Output = WX;
+	fn dot(self, rhs: XYW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYW> for Y {
+	type Output = WX;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for Y {
+	type Output = ZX;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for Y {
+	type Output = ZX;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for Y {
+	type Output = ZXW;
+	fn dot(self, rhs: XYZW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZW> for Y {
+	type Output = ZXW;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for Z {
+	type Output = Z;
+	fn dot(self, rhs: S) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for Z {
+	type Output = Z;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for Z {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for Z {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for Z {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for Z {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for Z {
+	type Output = S;
+	fn dot(self, rhs: Z) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for Z {
+	type Output = S;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for Z {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for Z {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for Z {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for Z {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for Z {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for Z {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for Z {
+	type Output = W;
+	fn dot(self, rhs: WZ) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<WZ> for Z {
+	type Output = W;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for Z {
+	type Output = Y;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for Z {
+	type Output = Y;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for Z {
+	type Output = X;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for Z {
+	type Output = X;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for Z {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for Z {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for Z {
+	type Output = WY;
+	fn dot(self, rhs: YZW) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZW> for Z {
+	type Output = WY;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for Z {
+	type Output = WX;
+	fn dot(self, rhs: ZXW) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZXW> for Z {
+	type Output = WX;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for Z {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for Z {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for Z {
+	type Output = XY;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for Z {
+	type Output = XY;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for Z {
+	type Output = XYW;
+	fn dot(self, rhs: XYZW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZW> for Z {
+	type Output = XYW;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for W {
+	type Output = W;
+	fn dot(self, rhs: S) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for W {
+	type Output = W;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Z> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZ> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for W {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZW> for W {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for WX {
+	type Output = WX;
+	fn dot(self, rhs: S) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for WX {
+	type Output = WX;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for WX {
+	type Output = W;
+	fn dot(self, rhs: X) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for WX {
+	type Output = W;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Z> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZ> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for WX {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZW> for WX {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for WY {
+	type Output = WY;
+	fn dot(self, rhs: S) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for WY {
+	type Output = WY;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for WY {
+	type Output = W;
+	fn dot(self, rhs: Y) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for WY {
+	type Output = W;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Z> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZ> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for WY {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZW> for WY {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for WZ {
+	type Output = WZ;
+	fn dot(self, rhs: S) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for WZ {
+	type Output = WZ;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for WZ {
+	type Output = W;
+	fn dot(self, rhs: Z) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for WZ {
+	type Output = W;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZ> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for WZ {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZW> for WZ {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for YZ {
+	type Output = YZ;
+	fn dot(self, rhs: S) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for YZ {
+	type Output = YZ;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for YZ {
+	type Output = Z;
+	fn dot(self, rhs: Y) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for YZ {
+	type Output = Z;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for YZ {
+	type Output = Y;
+	fn dot(self, rhs: Z) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for YZ {
+	type Output = Y;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for YZ {
+	type Output = S;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for YZ {
+	type Output = S;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for YZ {
+	type Output = W;
+	fn dot(self, rhs: YZW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZW> for YZ {
+	type Output = W;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for YZ {
+	type Output = X;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for YZ {
+	type Output = X;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for YZ {
+	type Output = WX;
+	fn dot(self, rhs: XYZW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZW> for YZ {
+	type Output = WX;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for ZX {
+	type Output = ZX;
+	fn dot(self, rhs: S) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for ZX {
+	type Output = ZX;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for ZX {
+	type Output = Z;
+	fn dot(self, rhs: X) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for ZX {
+	type Output = Z;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for ZX {
+	type Output = X;
+	fn dot(self, rhs: Z) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for ZX {
+	type Output = X;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for ZX {
+	type Output = S;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for ZX {
+	type Output = S;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for ZX {
+	type Output = W;
+	fn dot(self, rhs: ZXW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZXW> for ZX {
+	type Output = W;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for ZX {
+	type Output = Y;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for ZX {
+	type Output = Y;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for ZX {
+	type Output = WY;
+	fn dot(self, rhs: XYZW) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZW> for ZX {
+	type Output = WY;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for XY {
+	type Output = XY;
+	fn dot(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for XY {
+	type Output = XY;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for XY {
+	type Output = Y;
+	fn dot(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for XY {
+	type Output = Y;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for XY {
+	type Output = X;
+	fn dot(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for XY {
+	type Output = X;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Z> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for XY {
+	type Output = S;
+	fn dot(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for XY {
+	type Output = S;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for XY {
+	type Output = W;
+	fn dot(self, rhs: XYW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYW> for XY {
+	type Output = W;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for XY {
+	type Output = Z;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for XY {
+	type Output = Z;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for XY {
+	type Output = WZ;
+	fn dot(self, rhs: XYZW) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZW> for XY {
+	type Output = WZ;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for YZW {
+	type Output = YZW;
+	fn dot(self, rhs: S) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for YZW {
+	type Output = YZW;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for YZW {
+	type Output = WZ;
+	fn dot(self, rhs: Y) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for YZW {
+	type Output = WZ;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for YZW {
+	type Output = WY;
+	fn dot(self, rhs: Z) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for YZW {
+	type Output = WY;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for YZW {
+	type Output = W;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for YZW {
+	type Output = W;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZ> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for YZW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZW> for YZW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for ZXW {
+	type Output = ZXW;
+	fn dot(self, rhs: S) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for ZXW {
+	type Output = ZXW;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for ZXW {
+	type Output = WZ;
+	fn dot(self, rhs: X) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for ZXW {
+	type Output = WZ;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for ZXW {
+	type Output = WX;
+	fn dot(self, rhs: Z) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for ZXW {
+	type Output = WX;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for ZXW {
+	type Output = W;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for ZXW {
+	type Output = W;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZ> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for ZXW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZW> for ZXW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for XYW {
+	type Output = XYW;
+	fn dot(self, rhs: S) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for XYW {
+	type Output = XYW;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for XYW {
+	type Output = WY;
+	fn dot(self, rhs: X) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for XYW {
+	type Output = WY;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for XYW {
+	type Output = WX;
+	fn dot(self, rhs: Y) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for XYW {
+	type Output = WX;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Z> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for XYW {
+	type Output = W;
+	fn dot(self, rhs: XY) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for XYW {
+	type Output = W;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZ> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for XYW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZW> for XYW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for XYZ {
+	type Output = XYZ;
+	fn dot(self, rhs: S) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for XYZ {
+	type Output = XYZ;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for XYZ {
+	type Output = YZ;
+	fn dot(self, rhs: X) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for XYZ {
+	type Output = YZ;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for XYZ {
+	type Output = ZX;
+	fn dot(self, rhs: Y) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for XYZ {
+	type Output = ZX;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for XYZ {
+	type Output = XY;
+	fn dot(self, rhs: Z) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for XYZ {
+	type Output = XY;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for XYZ {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for XYZ {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for XYZ {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for XYZ {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for XYZ {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for XYZ {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for XYZ {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for XYZ {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for XYZ {
+	type Output = X;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for XYZ {
+	type Output = X;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for XYZ {
+	type Output = Y;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for XYZ {
+	type Output = Y;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for XYZ {
+	type Output = Z;
+	fn dot(self, rhs: XY) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for XYZ {
+	type Output = Z;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for XYZ {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for XYZ {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for XYZ {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for XYZ {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for XYZ {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for XYZ {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for XYZ {
+	type Output = S;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for XYZ {
+	type Output = S;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for XYZ {
+	type Output = W;
+	fn dot(self, rhs: XYZW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZW> for XYZ {
+	type Output = W;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for XYZW {
+	type Output = XYZW;
+	fn dot(self, rhs: S) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for XYZW {
+	type Output = XYZW;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for XYZW {
+	type Output = YZW;
+	fn dot(self, rhs: X) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for XYZW {
+	type Output = YZW;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for XYZW {
+	type Output = ZXW;
+	fn dot(self, rhs: Y) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for XYZW {
+	type Output = ZXW;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for XYZW {
+	type Output = XYW;
+	fn dot(self, rhs: Z) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for XYZW {
+	type Output = XYW;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<W> for XYZW {
+	type Output = Zero;
+	fn dot(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<W> for XYZW {
+	type Output = Zero;
+	fn bitor(self, rhs: W) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WX> for XYZW {
+	type Output = Zero;
+	fn dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WX> for XYZW {
+	type Output = Zero;
+	fn bitor(self, rhs: WX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WY> for XYZW {
+	type Output = Zero;
+	fn dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WY> for XYZW {
+	type Output = Zero;
+	fn bitor(self, rhs: WY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<WZ> for XYZW {
+	type Output = Zero;
+	fn dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<WZ> for XYZW {
+	type Output = Zero;
+	fn bitor(self, rhs: WZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for XYZW {
+	type Output = WX;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for XYZW {
+	type Output = WX;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for XYZW {
+	type Output = WY;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for XYZW {
+	type Output = WY;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for XYZW {
+	type Output = WZ;
+	fn dot(self, rhs: XY) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for XYZW {
+	type Output = WZ;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZW> for XYZW {
+	type Output = Zero;
+	fn dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZW> for XYZW {
+	type Output = Zero;
+	fn bitor(self, rhs: YZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZXW> for XYZW {
+	type Output = Zero;
+	fn dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZXW> for XYZW {
+	type Output = Zero;
+	fn bitor(self, rhs: ZXW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYW> for XYZW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYW> for XYZW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for XYZW {
+	type Output = W;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for XYZW {
+	type Output = W;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZW> for XYZW {
+	type Output = Zero;
+	fn dot(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XYZW> for XYZW {
+	type Output = Zero;
+	fn bitor(self, rhs: XYZW) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Wedge for blades:
+
+impl Wedge<S> for S {
+	type Output = S;
+	fn wedge(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for S {
+	type Output = S;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for S {
+	type Output = X;
+	fn wedge(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for S {
+	type Output = X;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for S {
+	type Output = Y;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for S {
+	type Output = Y;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for S {
+	type Output = Z;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for S {
+	type Output = Z;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for S {
+	type Output = W;
+	fn wedge(self, rhs: W) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for S {
+	type Output = W;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for S {
+	type Output = WX;
+	fn wedge(self, rhs: WX) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WX> for S {
+	type Output = WX;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for S {
+	type Output = WY;
+	fn wedge(self, rhs: WY) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WY> for S {
+	type Output = WY;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for S {
+	type Output = WZ;
+	fn wedge(self, rhs: WZ) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WZ> for S {
+	type Output = WZ;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for S {
+	type Output = YZ;
+	fn wedge(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<YZ> for S {
+	type Output = YZ;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for S {
+	type Output = ZX;
+	fn wedge(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<ZX> for S {
+	type Output = ZX;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for S {
+	type Output = XY;
+	fn wedge(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XY> for S {
+	type Output = XY;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for S {
+	type Output = YZW;
+	fn wedge(self, rhs: YZW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<YZW> for S {
+	type Output = YZW;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for S {
+	type Output = ZXW;
+	fn wedge(self, rhs: ZXW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<ZXW> for S {
+	type Output = ZXW;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for S {
+	type Output = XYW;
+	fn wedge(self, rhs: XYW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XYW> for S {
+	type Output = XYW;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for S {
+	type Output = XYZ;
+	fn wedge(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XYZ> for S {
+	type Output = XYZ;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for S {
+	type Output = XYZW;
+	fn wedge(self, rhs: XYZW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XYZW> for S {
+	type Output = XYZW;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for X {
+	type Output = X;
+	fn wedge(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for X {
+	type Output = X;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for X {
+	type Output = XY;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for X {
+	type Output = XY;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for X {
+	type Output = ZX;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for X {
+	type Output = ZX;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for X {
+	type Output = WX;
+	fn wedge(self, rhs: W) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for X {
+	type Output = WX;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for X {
+	type Output = XYW;
+	fn wedge(self, rhs: WY) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WY> for X {
+	type Output = XYW;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for X {
+	type Output = ZXW;
+	fn wedge(self, rhs: WZ) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WZ> for X {
+	type Output = ZXW;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for X {
+	type Output = XYZ;
+	fn wedge(self, rhs: YZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<YZ> for X {
+	type Output = XYZ;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for X {
+	type Output = XYZW;
+	fn wedge(self, rhs: YZW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<YZW> for X {
+	type Output = XYZW;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for Y {
+	type Output = Y;
+	fn wedge(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for Y {
+	type Output = Y;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for Y {
+	type Output = XY;
+	fn wedge(self, rhs: X) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for Y {
+	type Output = XY;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for Y {
+	type Output = YZ;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for Y {
+	type Output = YZ;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for Y {
+	type Output = WY;
+	fn wedge(self, rhs: W) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for Y {
+	type Output = WY;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for Y {
+	type Output = XYW;
+	fn wedge(self, rhs: WX) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WX> for Y {
+	type Output = XYW;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for Y {
+	type Output = YZW;
+	fn wedge(self, rhs: WZ) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WZ> for Y {
+	type Output = YZW;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for Y {
+	type Output = XYZ;
+	fn wedge(self, rhs: ZX) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<ZX> for Y {
+	type Output = XYZ;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for Y {
+	type Output = XYZW;
+	fn wedge(self, rhs: ZXW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<ZXW> for Y {
+	type Output = XYZW;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for Z {
+	type Output = Z;
+	fn wedge(self, rhs: S) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for Z {
+	type Output = Z;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for Z {
+	type Output = ZX;
+	fn wedge(self, rhs: X) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for Z {
+	type Output = ZX;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for Z {
+	type Output = YZ;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for Z {
+	type Output = YZ;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for Z {
+	type Output = WZ;
+	fn wedge(self, rhs: W) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for Z {
+	type Output = WZ;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for Z {
+	type Output = ZXW;
+	fn wedge(self, rhs: WX) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WX> for Z {
+	type Output = ZXW;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for Z {
+	type Output = YZW;
+	fn wedge(self, rhs: WY) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WY> for Z {
+	type Output = YZW;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for Z {
+	type Output = XYZ;
+	fn wedge(self, rhs: XY) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XY> for Z {
+	type Output = XYZ;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for Z {
+	type Output = XYZW;
+	fn wedge(self, rhs: XYW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XYW> for Z {
+	type Output = XYZW;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for W {
+	type Output = W;
+	fn wedge(self, rhs: S) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for W {
+	type Output = W;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for W {
+	type Output = WX;
+	fn wedge(self, rhs: X) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for W {
+	type Output = WX;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for W {
+	type Output = WY;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for W {
+	type Output = WY;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for W {
+	type Output = WZ;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for W {
+	type Output = WZ;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for W {
+	type Output = YZW;
+	fn wedge(self, rhs: YZ) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<YZ> for W {
+	type Output = YZW;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for W {
+	type Output = ZXW;
+	fn wedge(self, rhs: ZX) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<ZX> for W {
+	type Output = ZXW;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for W {
+	type Output = XYW;
+	fn wedge(self, rhs: XY) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XY> for W {
+	type Output = XYW;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for W {
+	type Output = XYZW;
+	fn wedge(self, rhs: XYZ) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XYZ> for W {
+	type Output = XYZW;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for W {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for W {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for WX {
+	type Output = WX;
+	fn wedge(self, rhs: S) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for WX {
+	type Output = WX;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for WX {
+	type Output = XYW;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for WX {
+	type Output = XYW;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for WX {
+	type Output = ZXW;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for WX {
+	type Output = ZXW;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for WX {
+	type Output = XYZW;
+	fn wedge(self, rhs: YZ) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<YZ> for WX {
+	type Output = XYZW;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for WX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for WX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for WY {
+	type Output = WY;
+	fn wedge(self, rhs: S) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for WY {
+	type Output = WY;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for WY {
+	type Output = XYW;
+	fn wedge(self, rhs: X) -> Self::Output {
+		XYW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for WY {
+	type Output = XYW;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for WY {
+	type Output = YZW;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for WY {
+	type Output = YZW;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for WY {
+	type Output = XYZW;
+	fn wedge(self, rhs: ZX) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<ZX> for WY {
+	type Output = XYZW;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for WY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for WY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for WZ {
+	type Output = WZ;
+	fn wedge(self, rhs: S) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for WZ {
+	type Output = WZ;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for WZ {
+	type Output = ZXW;
+	fn wedge(self, rhs: X) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for WZ {
+	type Output = ZXW;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for WZ {
+	type Output = YZW;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for WZ {
+	type Output = YZW;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for WZ {
+	type Output = XYZW;
+	fn wedge(self, rhs: XY) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XY> for WZ {
+	type Output = XYZW;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for WZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for WZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for YZ {
+	type Output = YZ;
+	fn wedge(self, rhs: S) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for YZ {
+	type Output = YZ;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for YZ {
+	type Output = XYZ;
+	fn wedge(self, rhs: X) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for YZ {
+	type Output = XYZ;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for YZ {
+	type Output = YZW;
+	fn wedge(self, rhs: W) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for YZ {
+	type Output = YZW;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for YZ {
+	type Output = XYZW;
+	fn wedge(self, rhs: WX) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WX> for YZ {
+	type Output = XYZW;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for ZX {
+	type Output = ZX;
+	fn wedge(self, rhs: S) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for ZX {
+	type Output = ZX;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for ZX {
+	type Output = XYZ;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for ZX {
+	type Output = XYZ;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for ZX {
+	type Output = ZXW;
+	fn wedge(self, rhs: W) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for ZX {
+	type Output = ZXW;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for ZX {
+	type Output = XYZW;
+	fn wedge(self, rhs: WY) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WY> for ZX {
+	type Output = XYZW;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for XY {
+	type Output = XY;
+	fn wedge(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for XY {
+	type Output = XY;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for XY {
+	type Output = XYZ;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for XY {
+	type Output = XYZ;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for XY {
+	type Output = XYW;
+	fn wedge(self, rhs: W) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for XY {
+	type Output = XYW;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for XY {
+	type Output = XYZW;
+	fn wedge(self, rhs: WZ) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<WZ> for XY {
+	type Output = XYZW;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for YZW {
+	type Output = YZW;
+	fn wedge(self, rhs: S) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for YZW {
+	type Output = YZW;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for YZW {
+	type Output = XYZW;
+	fn wedge(self, rhs: X) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for YZW {
+	type Output = XYZW;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for YZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for YZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for ZXW {
+	type Output = ZXW;
+	fn wedge(self, rhs: S) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for ZXW {
+	type Output = ZXW;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for ZXW {
+	type Output = XYZW;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for ZXW {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for ZXW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for ZXW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for XYW {
+	type Output = XYW;
+	fn wedge(self, rhs: S) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for XYW {
+	type Output = XYW;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for XYW {
+	type Output = XYZW;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for XYW {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for XYW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for XYW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for XYZ {
+	type Output = XYZ;
+	fn wedge(self, rhs: S) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for XYZ {
+	type Output = XYZ;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for XYZ {
+	type Output = XYZW;
+	fn wedge(self, rhs: W) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<W> for XYZ {
+	type Output = XYZW;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for XYZW {
+	type Output = XYZW;
+	fn wedge(self, rhs: S) -> Self::Output {
+		XYZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for XYZW {
+	type Output = XYZW;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<W> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<W> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: W) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WX> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WX> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WY> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WY> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<WZ> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<WZ> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: WZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZW> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZW> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZXW> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZXW> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZXW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYW> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYW> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZW> for XYZW {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZW> for XYZW {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZW) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiWedge for blades:
+
+impl AntiWedge<S> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZW> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYW> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for S {
+	type Output = S;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for S {
+	type Output = S;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for X {
+	type Output = S;
+	fn anti_wedge(self, rhs: YZW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZW> for X {
+	type Output = S;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYW> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for X {
+	type Output = X;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for X {
+	type Output = X;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZW> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for Y {
+	type Output = S;
+	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for Y {
+	type Output = S;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYW> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for Y {
+	type Output = Y;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for Y {
+	type Output = Y;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZW> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for Z {
+	type Output = S;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for Z {
+	type Output = S;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for Z {
+	type Output = Z;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for Z {
+	type Output = Z;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZW> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for W {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYW> for W {
+	type Output = Zero;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for W {
+	type Output = S;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for W {
+	type Output = S;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for W {
+	type Output = W;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for W {
+	type Output = W;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for WX {
+	type Output = S;
+	fn anti_wedge(self, rhs: YZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZ> for WX {
+	type Output = S;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for WX {
+	type Output = W;
+	fn anti_wedge(self, rhs: YZW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZW> for WX {
+	type Output = W;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for WX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYW> for WX {
+	type Output = Zero;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for WX {
+	type Output = X;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for WX {
+	type Output = X;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for WX {
+	type Output = WX;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for WX {
+	type Output = WX;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for WY {
+	type Output = S;
+	fn anti_wedge(self, rhs: ZX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZX> for WY {
+	type Output = S;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZW> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for WY {
+	type Output = W;
+	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for WY {
+	type Output = W;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for WY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYW> for WY {
+	type Output = Zero;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for WY {
+	type Output = Y;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for WY {
+	type Output = Y;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for WY {
+	type Output = WY;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for WY {
+	type Output = WY;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for WZ {
+	type Output = S;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for WZ {
+	type Output = S;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZW> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for WZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for WZ {
+	type Output = Zero;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for WZ {
+	type Output = W;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for WZ {
+	type Output = W;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for WZ {
+	type Output = Z;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for WZ {
+	type Output = Z;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for WZ {
+	type Output = WZ;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for WZ {
+	type Output = WZ;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for YZ {
+	type Output = S;
+	fn anti_wedge(self, rhs: WX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<WX> for YZ {
+	type Output = S;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZW> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for YZ {
+	type Output = Z;
+	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for YZ {
+	type Output = Z;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for YZ {
+	type Output = Y;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for YZ {
+	type Output = Y;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for YZ {
+	type Output = YZ;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for YZ {
+	type Output = YZ;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for ZX {
+	type Output = S;
+	fn anti_wedge(self, rhs: WY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<WY> for ZX {
+	type Output = S;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for ZX {
+	type Output = Z;
+	fn anti_wedge(self, rhs: YZW) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZW> for ZX {
+	type Output = Z;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for ZX {
+	type Output = X;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for ZX {
+	type Output = X;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for ZX {
+	type Output = ZX;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for ZX {
+	type Output = ZX;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for XY {
+	type Output = S;
+	fn anti_wedge(self, rhs: WZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<WZ> for XY {
+	type Output = S;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for XY {
+	type Output = Y;
+	fn anti_wedge(self, rhs: YZW) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZW> for XY {
+	type Output = Y;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for XY {
+	type Output = X;
+	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for XY {
+	type Output = X;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYW> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for XY {
+	type Output = XY;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for XY {
+	type Output = XY;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for YZW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for YZW {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for YZW {
+	type Output = S;
+	fn anti_wedge(self, rhs: X) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<X> for YZW {
+	type Output = S;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for YZW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for YZW {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for YZW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for YZW {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for YZW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for YZW {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for YZW {
+	type Output = W;
+	fn anti_wedge(self, rhs: WX) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<WX> for YZW {
+	type Output = W;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for YZW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for YZW {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for YZW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for YZW {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for YZW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for YZW {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for YZW {
+	type Output = Z;
+	fn anti_wedge(self, rhs: ZX) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZX> for YZW {
+	type Output = Z;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for YZW {
+	type Output = Y;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for YZW {
+	type Output = Y;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for YZW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZW> for YZW {
+	type Output = Zero;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for YZW {
+	type Output = WZ;
+	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
+		WZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for YZW {
+	type Output = WZ;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for YZW {
+	type Output = WY;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		WY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for YZW {
+	type Output = WY;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for YZW {
+	type Output = YZ;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for YZW {
+	type Output = YZ;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for YZW {
+	type Output = YZW;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for YZW {
+	type Output = YZW;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for ZXW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for ZXW {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for ZXW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for ZXW {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for ZXW {
+	type Output = S;
+	fn anti_wedge(self, rhs: Y) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<Y> for ZXW {
+	type Output = S;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for ZXW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for ZXW {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for ZXW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for ZXW {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for ZXW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for ZXW {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for ZXW {
+	type Output = W;
+	fn anti_wedge(self, rhs: WY) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<WY> for ZXW {
+	type Output = W;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for ZXW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WZ> for ZXW {
+	type Output = Zero;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for ZXW {
+	type Output = Z;
+	fn anti_wedge(self, rhs: YZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZ> for ZXW {
+	type Output = Z;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for ZXW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for ZXW {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for ZXW {
+	type Output = X;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for ZXW {
+	type Output = X;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for ZXW {
+	type Output = WZ;
+	fn anti_wedge(self, rhs: YZW) -> Self::Output {
+		WZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZW> for ZXW {
+	type Output = WZ;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZXW> for ZXW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZXW> for ZXW {
+	type Output = Zero;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYW> for ZXW {
+	type Output = WX;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		WX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYW> for ZXW {
+	type Output = WX;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for ZXW {
+	type Output = ZX;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for ZXW {
+	type Output = ZX;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZW> for ZXW {
+	type Output = ZXW;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZW> for ZXW {
+	type Output = ZXW;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for XYW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for XYW {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for XYW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for XYW {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for XYW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for XYW {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for XYW {
+	type Output = S;
+	fn anti_wedge(self, rhs: Z) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<Z> for XYW {
+	type Output = S;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<W> for XYW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<W> for XYW {
+	type Output = Zero;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WX> for XYW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WX> for XYW {
+	type Output = Zero;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WY> for XYW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<WY> for XYW {
+	type Output = Zero;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<WZ> for XYW {
+	type Output = W;
+	fn anti_wedge(self, rhs: WZ) -> Self::Output {
+		W(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<WZ> for XYW {
+	type Output = W;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for XYW {
+	type Output = Y;
+	fn anti_wedge(self, rhs: YZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZ> for XYW {
+	type Output = Y;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for XYW {
+	type Output = X;
+	fn anti_wedge(self, rhs: ZX) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZX> for XYW {
+	type Output = X;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for XYW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for XYW {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZW> for XYW {
+	type Output = WY;
+	fn anti_wedge(self, rhs: YZW) -> Self::Output {
+		WY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZW> for XYW {
+	type Output = WY;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-/// Squares to -1.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
-pub struct XY(pub f64);
+impl AntiWedge<ZXW> for XYW {
+	type Output = WX;
+	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
+		WX(self.0 * rhs.0)
+	}
+}
 
-/// Squares to 0.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
-pub struct YZW(pub f64);
+impl std::ops::BitAnd<ZXW> for XYW {
+	type Output = WX;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-/// Squares to 0.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
-pub struct ZXW(pub f64);
+impl AntiWedge<XYW> for XYW {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
 
-/// Squares to 0.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
-pub struct XYW(pub f64);
+impl std::ops::BitAnd<XYW> for XYW {
+	type Output = Zero;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-/// Squares to -1.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
-pub struct XYZ(pub f64);
+impl AntiWedge<XYZ> for XYW {
+	type Output = XY;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
 
-/// The pseudo-scalar.
-/// Squares to 0.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
-pub struct XYZW(pub f64);
+impl std::ops::BitAnd<XYZ> for XYW {
+	type Output = XY;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-// ---------------------------------------------------------------------
-// impl RCompl for blades:
+impl AntiWedge<XYZW> for XYW {
+	type Output = XYW;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
 
-impl RCompl for S {
-	type Output = XYZW;
-	fn rcompl(self) -> Self::Output {
-		XYZW(self.0)
+impl std::ops::BitAnd<XYZW> for XYW {
+	type Output = XYW;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl RCompl for X {
-	type Output = YZW;
-	fn rcompl(self) -> Self::Output {
-		YZW(self.0)
+impl AntiWedge<S> for XYZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl RCompl for Y {
-	type Output = ZXW;
-	fn rcompl(self) -> Self::Output {
-		ZXW(self.0)
+impl std::ops::BitAnd<S> for XYZ {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl RCompl for Z {
-	type Output = XYW;
-	fn rcompl(self) -> Self::Output {
-		XYW(self.0)
+impl AntiWedge<X> for XYZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl RCompl for W {
-	type Output = XYZ;
-	fn rcompl(self) -> Self::Output {
-		XYZ(-self.0)
+impl std::ops::BitAnd<X> for XYZ {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl RCompl for WX {
-	type Output = YZ;
-	fn rcompl(self) -> Self::Output {
-		YZ(-self.0)
+impl AntiWedge<Y> for XYZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl RCompl for WY {
-	type Output = ZX;
-	fn rcompl(self) -> Self::Output {
-		ZX(-self.0)
+impl std::ops::BitAnd<Y> for XYZ {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl RCompl for WZ {
-	type Output = XY;
-	fn rcompl(self) -> Self::Output {
-		XY(-self.0)
+impl AntiWedge<Z> for XYZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl RCompl for YZ {
-	type Output = WX;
-	fn rcompl(self) -> Self::Output {
-		WX(-self.0)
+impl std::ops::BitAnd<Z> for XYZ {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl RCompl for ZX {
-	type Output = WY;
-	fn rcompl(self) -> Self::Output {
-		WY(-self.0)
+impl AntiWedge<W> for XYZ {
+	type Output = S;
+	fn anti_wedge(self, rhs: W) -> Self::Output {
+		S(self.0 * rhs.0)
 	}
 }
 
-impl RCompl for XY {
-	type Output = WZ;
-	fn rcompl(self) -> Self::Output {
-		WZ(-self.0)
+impl std::ops::BitAnd<W> for XYZ {
+	type Output = S;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl RCompl for YZW {
+impl AntiWedge<WX> for XYZ {
 	type Output = X;
-	fn rcompl(self) -> Self::Output {
-		X(-self.0)
+	fn anti_wedge(self, rhs: WX) -> Self::Output {
+		X(-self.0 * rhs.0)
 	}
 }
 
-impl RCompl for ZXW {
-	type Output = Y;
-	fn rcompl(self) -> Self::Output {
-		Y(-self.0)
+impl std::ops::BitAnd<WX> for XYZ {
+	type Output = X;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl RCompl for XYW {
-	type Output = Z;
-	fn rcompl(self) -> Self::Output {
-		Z(-self.0)
+impl AntiWedge<WY> for XYZ {
+	type Output = Y;
+	fn anti_wedge(self, rhs: WY) -> Self::Output {
+		Y(-self.0 * rhs.0)
 	}
 }
 
-impl RCompl for XYZ {
-	type Output = W;
-	fn rcompl(self) -> Self::Output {
-		W(self.0)
+impl std::ops::BitAnd<WY> for XYZ {
+	type Output = Y;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl RCompl for XYZW {
-	type Output = S;
-	fn rcompl(self) -> Self::Output {
-		S(self.0)
+impl AntiWedge<WZ> for XYZ {
+	type Output = Z;
+	fn anti_wedge(self, rhs: WZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
 	}
 }
 
-// ---------------------------------------------------------------------
-// impl LCompl for blades:
-
-impl LCompl for S {
-	type Output = XYZW;
-	fn lcompl(self) -> Self::Output {
-		XYZW(self.0)
+impl std::ops::BitAnd<WZ> for XYZ {
+	type Output = Z;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl LCompl for X {
-	type Output = YZW;
-	fn lcompl(self) -> Self::Output {
-		YZW(-self.0)
+impl AntiWedge<YZ> for XYZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl LCompl for Y {
-	type Output = ZXW;
-	fn lcompl(self) -> Self::Output {
-		ZXW(-self.0)
+impl std::ops::BitAnd<YZ> for XYZ {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl LCompl for Z {
-	type Output = XYW;
-	fn lcompl(self) -> Self::Output {
-		XYW(-self.0)
+impl AntiWedge<ZX> for XYZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl LCompl for W {
-	type Output = XYZ;
-	fn lcompl(self) -> Self::Output {
-		XYZ(self.0)
+impl std::ops::BitAnd<ZX> for XYZ {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl LCompl for WX {
-	type Output = YZ;
-	fn lcompl(self) -> Self::Output {
-		YZ(-self.0)
+impl AntiWedge<XY> for XYZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl LCompl for WY {
-	type Output = ZX;
-	fn lcompl(self) -> Self::Output {
-		ZX(-self.0)
+impl std::ops::BitAnd<XY> for XYZ {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl LCompl for WZ {
-	type Output = XY;
-	fn lcompl(self) -> Self::Output {
-		XY(-self.0)
+impl AntiWedge<YZW> for XYZ {
+	type Output = YZ;
+	fn anti_wedge(self, rhs: YZW) -> Self::Output {
+		YZ(self.0 * rhs.0)
 	}
 }
 
-impl LCompl for YZ {
-	type Output = WX;
-	fn lcompl(self) -> Self::Output {
-		WX(-self.0)
+impl std::ops::BitAnd<YZW> for XYZ {
+	type Output = YZ;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl LCompl for ZX {
-	type Output = WY;
-	fn lcompl(self) -> Self::Output {
-		WY(-self.0)
+impl AntiWedge<ZXW> for XYZ {
+	type Output = ZX;
+	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
+		ZX(self.0 * rhs.0)
 	}
 }
 
-impl LCompl for XY {
-	type Output = WZ;
-	fn lcompl(self) -> Self::Output {
-		WZ(-self.0)
+impl std::ops::BitAnd<ZXW> for XYZ {
+	type Output = ZX;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl LCompl for YZW {
-	type Output = X;
-	fn lcompl(self) -> Self::Output {
-		X(self.0)
+impl AntiWedge<XYW> for XYZ {
+	type Output = XY;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl LCompl for ZXW {
-	type Output = Y;
-	fn lcompl(self) -> Self::Output {
-		Y(self.0)
+impl std::ops::BitAnd<XYW> for XYZ {
+	type Output = XY;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl LCompl for XYW {
-	type Output = Z;
-	fn lcompl(self) -> Self::Output {
-		Z(self.0)
+impl AntiWedge<XYZ> for XYZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl LCompl for XYZ {
-	type Output = W;
-	fn lcompl(self) -> Self::Output {
-		W(-self.0)
+impl std::ops::BitAnd<XYZ> for XYZ {
+	type Output = Zero;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl LCompl for XYZW {
-	type Output = S;
-	fn lcompl(self) -> Self::Output {
-		S(self.0)
+impl AntiWedge<XYZW> for XYZ {
+	type Output = XYZ;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-// ---------------------------------------------------------------------
-// impl Reverse for blades:
-
-impl Reverse for S {
-	fn rev(self) -> Self {
-		self
+impl std::ops::BitAnd<XYZW> for XYZ {
+	type Output = XYZ;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl Reverse for X {
-	fn rev(self) -> Self {
-		self
+impl AntiWedge<S> for XYZW {
+	type Output = S;
+	fn anti_wedge(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
 	}
 }
 
-impl Reverse for Y {
-	fn rev(self) -> Self {
-		self
+impl std::ops::BitAnd<S> for XYZW {
+	type Output = S;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl Reverse for Z {
-	fn rev(self) -> Self {
-		self
+impl AntiWedge<X> for XYZW {
+	type Output = X;
+	fn anti_wedge(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
 	}
 }
 
-impl Reverse for W {
-	fn rev(self) -> Self {
-		self
+impl std::ops::BitAnd<X> for XYZW {
+	type Output = X;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl Reverse for WX {
-	fn rev(self) -> Self {
-		-self
+impl AntiWedge<Y> for XYZW {
+	type Output = Y;
+	fn anti_wedge(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
 	}
 }
 
-impl Reverse for WY {
-	fn rev(self) -> Self {
-		-self
+impl std::ops::BitAnd<Y> for XYZW {
+	type Output = Y;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl Reverse for WZ {
-	fn rev(self) -> Self {
-		-self
+impl AntiWedge<Z> for XYZW {
+	type Output = Z;
+	fn anti_wedge(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
 	}
 }
 
-impl Reverse for YZ {
-	fn rev(self) -> Self {
-		-self
+impl std::ops::BitAnd<Z> for XYZW {
+	type Output = Z;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl Reverse for ZX {
-	fn rev(self) -> Self {
-		-self
+impl AntiWedge<W> for XYZW {
+	type Output = W;
+	fn anti_wedge(self, rhs: W) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl Reverse for XY {
-	fn rev(self) -> Self {
-		-self
+impl std::ops::BitAnd<W> for XYZW {
+	type Output = W;
+	fn bitand(self, rhs: W) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl Reverse for YZW {
-	fn rev(self) -> Self {
-		-self
+impl AntiWedge<WX> for XYZW {
+	type Output = WX;
+	fn anti_wedge(self, rhs: WX) -> Self::Output {
+		WX(self.0 * rhs.0)
 	}
 }
 
-impl Reverse for ZXW {
-	fn rev(self) -> Self {
-		-self
+impl std::ops::BitAnd<WX> for XYZW {
+	type Output = WX;
+	fn bitand(self, rhs: WX) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl Reverse for XYW {
-	fn rev(self) -> Self {
-		-self
+impl AntiWedge<WY> for XYZW {
+	type Output = WY;
+	fn anti_wedge(self, rhs: WY) -> Self::Output {
+		WY(self.0 * rhs.0)
 	}
 }
 
-impl Reverse for XYZ {
-	fn rev(self) -> Self {
-		-self
+impl std::ops::BitAnd<WY> for XYZW {
+	type Output = WY;
+	fn bitand(self, rhs: WY) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl Reverse for XYZW {
-	fn rev(self) -> Self {
-		self
+impl AntiWedge<WZ> for XYZW {
+	type Output = WZ;
+	fn anti_wedge(self, rhs: WZ) -> Self::Output {
+		WZ(self.0 * rhs.0)
 	}
 }
 
-// ---------------------------------------------------------------------
-// impl AntiReverse for blades:
+impl std::ops::BitAnd<WZ> for XYZW {
+	type Output = WZ;
+	fn bitand(self, rhs: WZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-impl AntiReverse for S {
-	fn arev(self) -> Self {
-		self
+impl AntiWedge<YZ> for XYZW {
+	type Output = YZ;
+	fn anti_wedge(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiReverse for X {
-	fn arev(self) -> Self {
-		-self
+impl std::ops::BitAnd<YZ> for XYZW {
+	type Output = YZ;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl AntiReverse for Y {
-	fn arev(self) -> Self {
-		-self
+impl AntiWedge<ZX> for XYZW {
+	type Output = ZX;
+	fn anti_wedge(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
 	}
 }
 
-impl AntiReverse for Z {
-	fn arev(self) -> Self {
-		-self
+impl std::ops::BitAnd<ZX> for XYZW {
+	type Output = ZX;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl AntiReverse for W {
-	fn arev(self) -> Self {
-		-self
+impl AntiWedge<XY> for XYZW {
+	type Output = XY;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiReverse for WX {
-	fn arev(self) -> Self {
-		-self
+impl std::ops::BitAnd<XY> for XYZW {
+	type Output = XY;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl AntiReverse for WY {
-	fn arev(self) -> Self {
-		-self
+impl AntiWedge<YZW> for XYZW {
+	type Output = YZW;
+	fn anti_wedge(self, rhs: YZW) -> Self::Output {
+		YZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiReverse for WZ {
-	fn arev(self) -> Self {
-		-self
+impl std::ops::BitAnd<YZW> for XYZW {
+	type Output = YZW;
+	fn bitand(self, rhs: YZW) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl AntiReverse for YZ {
-	fn arev(self) -> Self {
-		-self
+impl AntiWedge<ZXW> for XYZW {
+	type Output = ZXW;
+	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl AntiReverse for ZX {
-	fn arev(self) -> Self {
-		-self
+impl std::ops::BitAnd<ZXW> for XYZW {
+	type Output = ZXW;
+	fn bitand(self, rhs: ZXW) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl AntiReverse for XY {
-	fn arev(self) -> Self {
-		-self
+impl AntiWedge<XYW> for XYZW {
+	type Output = XYW;
+	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiReverse for YZW {
-	fn arev(self) -> Self {
-		self
+impl std::ops::BitAnd<XYW> for XYZW {
+	type Output = XYW;
+	fn bitand(self, rhs: XYW) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl AntiReverse for ZXW {
-	fn arev(self) -> Self {
-		self
+impl AntiWedge<XYZ> for XYZW {
+	type Output = XYZ;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiReverse for XYW {
-	fn arev(self) -> Self {
-		self
+impl std::ops::BitAnd<XYZ> for XYZW {
+	type Output = XYZ;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-impl AntiReverse for XYZ {
-	fn arev(self) -> Self {
-		self
+impl AntiWedge<XYZW> for XYZW {
+	type Output = XYZW;
+	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiReverse for XYZW {
-	fn arev(self) -> Self {
-		self
+impl std::ops::BitAnd<XYZW> for XYZW {
+	type Output = XYZW;
+	fn bitand(self, rhs: XYZW) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
 // ---------------------------------------------------------------------
-// impl Geometric for blades:
+// impl LeftContraction for blades:
 
-impl Geometric<S> for S {
+impl LeftContraction<S> for S {
 	type Output = S;
-	fn geometric(self, rhs: S) -> Self::Output {
+	fn left_contraction(self, rhs: S) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<X> for S {
+impl LeftContraction<X> for S {
 	type Output = X;
-	fn geometric(self, rhs: X) -> Self::Output {
+	fn left_contraction(self, rhs: X) -> Self::Output {
 		X(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<Y> for S {
+impl LeftContraction<Y> for S {
 	type Output = Y;
-	fn geometric(self, rhs: Y) -> Self::Output {
+	fn left_contraction(self, rhs: Y) -> Self::Output {
 		Y(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<Z> for S {
+impl LeftContraction<Z> for S {
 	type Output = Z;
-	fn geometric(self, rhs: Z) -> Self::Output {
+	fn left_contraction(self, rhs: Z) -> Self::Output {
 		Z(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<W> for S {
+impl LeftContraction<W> for S {
 	type Output = W;
-	fn geometric(self, rhs: W) -> Self::Output {
+	fn left_contraction(self, rhs: W) -> Self::Output {
 		W(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<WX> for S {
+impl LeftContraction<WX> for S {
 	type Output = WX;
-	fn geometric(self, rhs: WX) -> Self::Output {
+	fn left_contraction(self, rhs: WX) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<WY> for S {
+impl LeftContraction<WY> for S {
 	type Output = WY;
-	fn geometric(self, rhs: WY) -> Self::Output {
+	fn left_contraction(self, rhs: WY) -> Self::Output {
 		WY(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<WZ> for S {
+impl LeftContraction<WZ> for S {
 	type Output = WZ;
-	fn geometric(self, rhs: WZ) -> Self::Output {
+	fn left_contraction(self, rhs: WZ) -> Self::Output {
 		WZ(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<YZ> for S {
+impl LeftContraction<YZ> for S {
 	type Output = YZ;
-	fn geometric(self, rhs: YZ) -> Self::Output {
+	fn left_contraction(self, rhs: YZ) -> Self::Output {
 		YZ(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<ZX> for S {
+impl LeftContraction<ZX> for S {
 	type Output = ZX;
-	fn geometric(self, rhs: ZX) -> Self::Output {
+	fn left_contraction(self, rhs: ZX) -> Self::Output {
 		ZX(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XY> for S {
+impl LeftContraction<XY> for S {
 	type Output = XY;
-	fn geometric(self, rhs: XY) -> Self::Output {
+	fn left_contraction(self, rhs: XY) -> Self::Output {
 		XY(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<YZW> for S {
+impl LeftContraction<YZW> for S {
 	type Output = YZW;
-	fn geometric(self, rhs: YZW) -> Self::Output {
+	fn left_contraction(self, rhs: YZW) -> Self::Output {
 		YZW(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<ZXW> for S {
+impl LeftContraction<ZXW> for S {
 	type Output = ZXW;
-	fn geometric(self, rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, rhs: ZXW) -> Self::Output {
 		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYW> for S {
+impl LeftContraction<XYW> for S {
 	type Output = XYW;
-	fn geometric(self, rhs: XYW) -> Self::Output {
+	fn left_contraction(self, rhs: XYW) -> Self::Output {
 		XYW(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZ> for S {
+impl LeftContraction<XYZ> for S {
 	type Output = XYZ;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
 		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZW> for S {
+impl LeftContraction<XYZW> for S {
 	type Output = XYZW;
-	fn geometric(self, rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, rhs: XYZW) -> Self::Output {
 		XYZW(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for X {
-	type Output = X;
-	fn geometric(self, rhs: S) -> Self::Output {
-		X(self.0 * rhs.0)
+impl LeftContraction<S> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for X {
+impl LeftContraction<X> for X {
 	type Output = S;
-	fn geometric(self, rhs: X) -> Self::Output {
+	fn left_contraction(self, rhs: X) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<Y> for X {
-	type Output = XY;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl LeftContraction<Y> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for X {
-	type Output = ZX;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		ZX(-self.0 * rhs.0)
+impl LeftContraction<Z> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for X {
-	type Output = WX;
-	fn geometric(self, rhs: W) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl LeftContraction<W> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WX> for X {
+impl LeftContraction<WX> for X {
 	type Output = W;
-	fn geometric(self, rhs: WX) -> Self::Output {
+	fn left_contraction(self, rhs: WX) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<WY> for X {
-	type Output = XYW;
-	fn geometric(self, rhs: WY) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl LeftContraction<WY> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WZ> for X {
-	type Output = ZXW;
-	fn geometric(self, rhs: WZ) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl LeftContraction<WZ> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZ> for X {
-	type Output = XYZ;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl LeftContraction<YZ> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for X {
+impl LeftContraction<ZX> for X {
 	type Output = Z;
-	fn geometric(self, rhs: ZX) -> Self::Output {
+	fn left_contraction(self, rhs: ZX) -> Self::Output {
 		Z(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XY> for X {
+impl LeftContraction<XY> for X {
 	type Output = Y;
-	fn geometric(self, rhs: XY) -> Self::Output {
+	fn left_contraction(self, rhs: XY) -> Self::Output {
 		Y(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<YZW> for X {
-	type Output = XYZW;
-	fn geometric(self, rhs: YZW) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl LeftContraction<YZW> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for X {
+impl LeftContraction<ZXW> for X {
 	type Output = WZ;
-	fn geometric(self, rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, rhs: ZXW) -> Self::Output {
 		WZ(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYW> for X {
+impl LeftContraction<XYW> for X {
 	type Output = WY;
-	fn geometric(self, rhs: XYW) -> Self::Output {
+	fn left_contraction(self, rhs: XYW) -> Self::Output {
 		WY(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZ> for X {
+impl LeftContraction<XYZ> for X {
 	type Output = YZ;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
 		YZ(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZW> for X {
+impl LeftContraction<XYZW> for X {
 	type Output = YZW;
-	fn geometric(self, rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, rhs: XYZW) -> Self::Output {
 		YZW(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for Y {
-	type Output = Y;
-	fn geometric(self, rhs: S) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl LeftContraction<S> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for Y {
-	type Output = XY;
-	fn geometric(self, rhs: X) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl LeftContraction<X> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for Y {
+impl LeftContraction<Y> for Y {
 	type Output = S;
-	fn geometric(self, rhs: Y) -> Self::Output {
+	fn left_contraction(self, rhs: Y) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<Z> for Y {
-	type Output = YZ;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl LeftContraction<Z> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for Y {
-	type Output = WY;
-	fn geometric(self, rhs: W) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl LeftContraction<W> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WX> for Y {
-	type Output = XYW;
-	fn geometric(self, rhs: WX) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<WX> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WY> for Y {
+impl LeftContraction<WY> for Y {
 	type Output = W;
-	fn geometric(self, rhs: WY) -> Self::Output {
+	fn left_contraction(self, rhs: WY) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<WZ> for Y {
-	type Output = YZW;
-	fn geometric(self, rhs: WZ) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl LeftContraction<WZ> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZ> for Y {
+impl LeftContraction<YZ> for Y {
 	type Output = Z;
-	fn geometric(self, rhs: YZ) -> Self::Output {
+	fn left_contraction(self, rhs: YZ) -> Self::Output {
 		Z(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<ZX> for Y {
-	type Output = XYZ;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl LeftContraction<ZX> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for Y {
+impl LeftContraction<XY> for Y {
 	type Output = X;
-	fn geometric(self, rhs: XY) -> Self::Output {
+	fn left_contraction(self, rhs: XY) -> Self::Output {
 		X(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<YZW> for Y {
+impl LeftContraction<YZW> for Y {
 	type Output = WZ;
-	fn geometric(self, rhs: YZW) -> Self::Output {
+	fn left_contraction(self, rhs: YZW) -> Self::Output {
 		WZ(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<ZXW> for Y {
-	type Output = XYZW;
-	fn geometric(self, rhs: ZXW) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl LeftContraction<ZXW> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYW> for Y {
+impl LeftContraction<XYW> for Y {
 	type Output = WX;
-	fn geometric(self, rhs: XYW) -> Self::Output {
+	fn left_contraction(self, rhs: XYW) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZ> for Y {
+impl LeftContraction<XYZ> for Y {
 	type Output = ZX;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
 		ZX(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZW> for Y {
+impl LeftContraction<XYZW> for Y {
 	type Output = ZXW;
-	fn geometric(self, rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, rhs: XYZW) -> Self::Output {
 		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for Z {
-	type Output = Z;
-	fn geometric(self, rhs: S) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl LeftContraction<S> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for Z {
-	type Output = ZX;
-	fn geometric(self, rhs: X) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl LeftContraction<X> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for Z {
-	type Output = YZ;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		YZ(-self.0 * rhs.0)
+impl LeftContraction<Y> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for Z {
+impl LeftContraction<Z> for Z {
 	type Output = S;
-	fn geometric(self, rhs: Z) -> Self::Output {
+	fn left_contraction(self, rhs: Z) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<W> for Z {
-	type Output = WZ;
-	fn geometric(self, rhs: W) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl LeftContraction<W> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WX> for Z {
-	type Output = ZXW;
-	fn geometric(self, rhs: WX) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
+impl LeftContraction<WX> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WY> for Z {
-	type Output = YZW;
-	fn geometric(self, rhs: WY) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl LeftContraction<WY> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WZ> for Z {
+impl LeftContraction<WZ> for Z {
 	type Output = W;
-	fn geometric(self, rhs: WZ) -> Self::Output {
+	fn left_contraction(self, rhs: WZ) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<YZ> for Z {
+impl LeftContraction<YZ> for Z {
 	type Output = Y;
-	fn geometric(self, rhs: YZ) -> Self::Output {
+	fn left_contraction(self, rhs: YZ) -> Self::Output {
 		Y(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<ZX> for Z {
+impl LeftContraction<ZX> for Z {
 	type Output = X;
-	fn geometric(self, rhs: ZX) -> Self::Output {
+	fn left_contraction(self, rhs: ZX) -> Self::Output {
 		X(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XY> for Z {
-	type Output = XYZ;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl LeftContraction<XY> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for Z {
+impl LeftContraction<YZW> for Z {
 	type Output = WY;
-	fn geometric(self, rhs: YZW) -> Self::Output {
+	fn left_contraction(self, rhs: YZW) -> Self::Output {
 		WY(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<ZXW> for Z {
+impl LeftContraction<ZXW> for Z {
 	type Output = WX;
-	fn geometric(self, rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, rhs: ZXW) -> Self::Output {
 		WX(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYW> for Z {
-	type Output = XYZW;
-	fn geometric(self, rhs: XYW) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl LeftContraction<XYW> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for Z {
+impl LeftContraction<XYZ> for Z {
 	type Output = XY;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
 		XY(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZW> for Z {
+impl LeftContraction<XYZW> for Z {
 	type Output = XYW;
-	fn geometric(self, rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, rhs: XYZW) -> Self::Output {
 		XYW(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for W {
-	type Output = W;
-	fn geometric(self, rhs: S) -> Self::Output {
-		W(self.0 * rhs.0)
+impl LeftContraction<S> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for W {
-	type Output = WX;
-	fn geometric(self, rhs: X) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl LeftContraction<X> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for W {
-	type Output = WY;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl LeftContraction<Y> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for W {
-	type Output = WZ;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl LeftContraction<Z> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for W {
+impl LeftContraction<W> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for W {
+impl LeftContraction<WX> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WY> for W {
+impl LeftContraction<WY> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: WY) -> Self::Output {
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WZ> for W {
+impl LeftContraction<WZ> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: WZ) -> Self::Output {
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YZ> for W {
-	type Output = YZW;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl LeftContraction<YZ> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for W {
-	type Output = ZXW;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl LeftContraction<ZX> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for W {
-	type Output = XYW;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<XY> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for W {
+impl LeftContraction<YZW> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: YZW) -> Self::Output {
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for W {
+impl LeftContraction<ZXW> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYW> for W {
+impl LeftContraction<XYW> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for W {
-	type Output = XYZW;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl LeftContraction<XYZ> for W {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZW> for W {
+impl LeftContraction<XYZW> for W {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<S> for WX {
-	type Output = WX;
-	fn geometric(self, rhs: S) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl LeftContraction<S> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for WX {
-	type Output = W;
-	fn geometric(self, rhs: X) -> Self::Output {
-		W(self.0 * rhs.0)
+impl LeftContraction<X> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for WX {
-	type Output = XYW;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<Y> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for WX {
-	type Output = ZXW;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
+impl LeftContraction<Z> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for WX {
+impl LeftContraction<W> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for WX {
+impl LeftContraction<WX> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WY> for WX {
+impl LeftContraction<WY> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: WY) -> Self::Output {
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WZ> for WX {
+impl LeftContraction<WZ> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: WZ) -> Self::Output {
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YZ> for WX {
-	type Output = XYZW;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl LeftContraction<YZ> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for WX {
-	type Output = WZ;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl LeftContraction<ZX> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for WX {
-	type Output = WY;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl LeftContraction<XY> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for WX {
+impl LeftContraction<YZW> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: YZW) -> Self::Output {
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for WX {
+impl LeftContraction<ZXW> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYW> for WX {
+impl LeftContraction<XYW> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for WX {
-	type Output = YZW;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl LeftContraction<XYZ> for WX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZW> for WX {
+impl LeftContraction<XYZW> for WX {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<S> for WY {
-	type Output = WY;
-	fn geometric(self, rhs: S) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl LeftContraction<S> for WY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for WY {
-	type Output = XYW;
-	fn geometric(self, rhs: X) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl LeftContraction<X> for WY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for WY {
-	type Output = W;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		W(self.0 * rhs.0)
+impl LeftContraction<Y> for WY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for WY {
-	type Output = YZW;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl LeftContraction<Z> for WY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for WY {
+impl LeftContraction<W> for WY {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for WY {
+impl LeftContraction<WX> for WY {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WY> for WY {
+impl LeftContraction<WY> for WY {
 	type Output = Zero;
-	fn geometric(self, _rhs: WY) -> Self::Output {
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WZ> for WY {
+impl LeftContraction<WZ> for WY {
 	type Output = Zero;
-	fn geometric(self, _rhs: WZ) -> Self::Output {
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YZ> for WY {
-	type Output = WZ;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl LeftContraction<YZ> for WY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for WY {
-	type Output = XYZW;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl LeftContraction<ZX> for WY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for WY {
-	type Output = WX;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl LeftContraction<XY> for WY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for WY {
+impl LeftContraction<YZW> for WY {
 	type Output = Zero;
-	fn geometric(self, _rhs: YZW) -> Self::Output {
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for WY {
+impl LeftContraction<ZXW> for WY {
 	type Output = Zero;
-	fn geometric(self, _rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYW> for WY {
+impl LeftContraction<XYW> for WY {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for WY {
-	type Output = ZXW;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl LeftContraction<XYZ> for WY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZW> for WY {
+impl LeftContraction<XYZW> for WY {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<S> for WZ {
-	type Output = WZ;
-	fn geometric(self, rhs: S) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl LeftContraction<S> for WZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for WZ {
-	type Output = ZXW;
-	fn geometric(self, rhs: X) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl LeftContraction<X> for WZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for WZ {
-	type Output = YZW;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl LeftContraction<Y> for WZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for WZ {
-	type Output = W;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		W(self.0 * rhs.0)
+impl LeftContraction<Z> for WZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for WZ {
+impl LeftContraction<W> for WZ {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for WZ {
+impl LeftContraction<WX> for WZ {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WY> for WZ {
+impl LeftContraction<WY> for WZ {
 	type Output = Zero;
-	fn geometric(self, _rhs: WY) -> Self::Output {
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WZ> for WZ {
+impl LeftContraction<WZ> for WZ {
 	type Output = Zero;
-	fn geometric(self, _rhs: WZ) -> Self::Output {
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YZ> for WZ {
-	type Output = WY;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl LeftContraction<YZ> for WZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for WZ {
-	type Output = WX;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl LeftContraction<ZX> for WZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for WZ {
-	type Output = XYZW;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl LeftContraction<XY> for WZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for WZ {
+impl LeftContraction<YZW> for WZ {
 	type Output = Zero;
-	fn geometric(self, _rhs: YZW) -> Self::Output {
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for WZ {
+impl LeftContraction<ZXW> for WZ {
 	type Output = Zero;
-	fn geometric(self, _rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYW> for WZ {
+impl LeftContraction<XYW> for WZ {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for WZ {
-	type Output = XYW;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<XYZ> for WZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZW> for WZ {
+impl LeftContraction<XYZW> for WZ {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<S> for YZ {
-	type Output = YZ;
-	fn geometric(self, rhs: S) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl LeftContraction<S> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for YZ {
-	type Output = XYZ;
-	fn geometric(self, rhs: X) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl LeftContraction<X> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for YZ {
-	type Output = Z;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl LeftContraction<Y> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for YZ {
-	type Output = Y;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl LeftContraction<Z> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for YZ {
-	type Output = YZW;
-	fn geometric(self, rhs: W) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl LeftContraction<W> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WX> for YZ {
-	type Output = XYZW;
-	fn geometric(self, rhs: WX) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl LeftContraction<WX> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WY> for YZ {
-	type Output = WZ;
-	fn geometric(self, rhs: WY) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl LeftContraction<WY> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WZ> for YZ {
-	type Output = WY;
-	fn geometric(self, rhs: WZ) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl LeftContraction<WZ> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZ> for YZ {
+impl LeftContraction<YZ> for YZ {
 	type Output = S;
-	fn geometric(self, rhs: YZ) -> Self::Output {
+	fn left_contraction(self, rhs: YZ) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<ZX> for YZ {
-	type Output = XY;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl LeftContraction<ZX> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for YZ {
-	type Output = ZX;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl LeftContraction<XY> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for YZ {
+impl LeftContraction<YZW> for YZ {
 	type Output = W;
-	fn geometric(self, rhs: YZW) -> Self::Output {
+	fn left_contraction(self, rhs: YZW) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<ZXW> for YZ {
-	type Output = XYW;
-	fn geometric(self, rhs: ZXW) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl LeftContraction<ZXW> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYW> for YZ {
-	type Output = ZXW;
-	fn geometric(self, rhs: XYW) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl LeftContraction<XYW> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for YZ {
+impl LeftContraction<XYZ> for YZ {
 	type Output = X;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
 		X(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZW> for YZ {
+impl LeftContraction<XYZW> for YZ {
 	type Output = WX;
-	fn geometric(self, rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, rhs: XYZW) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for ZX {
-	type Output = ZX;
-	fn geometric(self, rhs: S) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl LeftContraction<S> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for ZX {
-	type Output = Z;
-	fn geometric(self, rhs: X) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl LeftContraction<X> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for ZX {
-	type Output = XYZ;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl LeftContraction<Y> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for ZX {
-	type Output = X;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl LeftContraction<Z> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for ZX {
-	type Output = ZXW;
-	fn geometric(self, rhs: W) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl LeftContraction<W> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WX> for ZX {
-	type Output = WZ;
-	fn geometric(self, rhs: WX) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl LeftContraction<WX> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WY> for ZX {
-	type Output = XYZW;
-	fn geometric(self, rhs: WY) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl LeftContraction<WY> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WZ> for ZX {
-	type Output = WX;
-	fn geometric(self, rhs: WZ) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl LeftContraction<WZ> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZ> for ZX {
-	type Output = XY;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl LeftContraction<YZ> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for ZX {
+impl LeftContraction<ZX> for ZX {
 	type Output = S;
-	fn geometric(self, rhs: ZX) -> Self::Output {
+	fn left_contraction(self, rhs: ZX) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XY> for ZX {
-	type Output = YZ;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		YZ(-self.0 * rhs.0)
+impl LeftContraction<XY> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for ZX {
-	type Output = XYW;
-	fn geometric(self, rhs: YZW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<YZW> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for ZX {
+impl LeftContraction<ZXW> for ZX {
 	type Output = W;
-	fn geometric(self, rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, rhs: ZXW) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYW> for ZX {
-	type Output = YZW;
-	fn geometric(self, rhs: XYW) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl LeftContraction<XYW> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for ZX {
+impl LeftContraction<XYZ> for ZX {
 	type Output = Y;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
 		Y(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZW> for ZX {
+impl LeftContraction<XYZW> for ZX {
 	type Output = WY;
-	fn geometric(self, rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, rhs: XYZW) -> Self::Output {
 		WY(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for XY {
-	type Output = XY;
-	fn geometric(self, rhs: S) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl LeftContraction<S> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for XY {
-	type Output = Y;
-	fn geometric(self, rhs: X) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl LeftContraction<X> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for XY {
-	type Output = X;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		X(self.0 * rhs.0)
+impl LeftContraction<Y> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for XY {
-	type Output = XYZ;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl LeftContraction<Z> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for XY {
-	type Output = XYW;
-	fn geometric(self, rhs: W) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<W> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WX> for XY {
-	type Output = WY;
-	fn geometric(self, rhs: WX) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl LeftContraction<WX> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WY> for XY {
-	type Output = WX;
-	fn geometric(self, rhs: WY) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl LeftContraction<WY> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WZ> for XY {
-	type Output = XYZW;
-	fn geometric(self, rhs: WZ) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl LeftContraction<WZ> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZ> for XY {
-	type Output = ZX;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		ZX(-self.0 * rhs.0)
+impl LeftContraction<YZ> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for XY {
-	type Output = YZ;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl LeftContraction<ZX> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for XY {
+impl LeftContraction<XY> for XY {
 	type Output = S;
-	fn geometric(self, rhs: XY) -> Self::Output {
+	fn left_contraction(self, rhs: XY) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<YZW> for XY {
-	type Output = ZXW;
-	fn geometric(self, rhs: YZW) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
+impl LeftContraction<YZW> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for XY {
-	type Output = YZW;
-	fn geometric(self, rhs: ZXW) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl LeftContraction<ZXW> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYW> for XY {
+impl LeftContraction<XYW> for XY {
 	type Output = W;
-	fn geometric(self, rhs: XYW) -> Self::Output {
+	fn left_contraction(self, rhs: XYW) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZ> for XY {
+impl LeftContraction<XYZ> for XY {
 	type Output = Z;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
 		Z(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZW> for XY {
+impl LeftContraction<XYZW> for XY {
 	type Output = WZ;
-	fn geometric(self, rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, rhs: XYZW) -> Self::Output {
 		WZ(self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for YZW {
-	type Output = YZW;
-	fn geometric(self, rhs: S) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl LeftContraction<S> for YZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for YZW {
-	type Output = XYZW;
-	fn geometric(self, rhs: X) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl LeftContraction<X> for YZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for YZW {
-	type Output = WZ;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl LeftContraction<Y> for YZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for YZW {
-	type Output = WY;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl LeftContraction<Z> for YZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for YZW {
+impl LeftContraction<W> for YZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for YZW {
+impl LeftContraction<WX> for YZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WY> for YZW {
+impl LeftContraction<WY> for YZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WY) -> Self::Output {
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WZ> for YZW {
+impl LeftContraction<WZ> for YZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WZ) -> Self::Output {
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YZ> for YZW {
-	type Output = W;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl LeftContraction<YZ> for YZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for YZW {
-	type Output = XYW;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl LeftContraction<ZX> for YZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for YZW {
-	type Output = ZXW;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl LeftContraction<XY> for YZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for YZW {
+impl LeftContraction<YZW> for YZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: YZW) -> Self::Output {
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for YZW {
+impl LeftContraction<ZXW> for YZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYW> for YZW {
+impl LeftContraction<XYW> for YZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for YZW {
-	type Output = WX;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl LeftContraction<XYZ> for YZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZW> for YZW {
+impl LeftContraction<XYZW> for YZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<S> for ZXW {
-	type Output = ZXW;
-	fn geometric(self, rhs: S) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl LeftContraction<S> for ZXW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for ZXW {
-	type Output = WZ;
-	fn geometric(self, rhs: X) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl LeftContraction<X> for ZXW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for ZXW {
-	type Output = XYZW;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl LeftContraction<Y> for ZXW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for ZXW {
-	type Output = WX;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl LeftContraction<Z> for ZXW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for ZXW {
+impl LeftContraction<W> for ZXW {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for ZXW {
+impl LeftContraction<WX> for ZXW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WY> for ZXW {
+impl LeftContraction<WY> for ZXW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WY) -> Self::Output {
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WZ> for ZXW {
+impl LeftContraction<WZ> for ZXW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WZ) -> Self::Output {
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YZ> for ZXW {
-	type Output = XYW;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<YZ> for ZXW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for ZXW {
-	type Output = W;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl LeftContraction<ZX> for ZXW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for ZXW {
-	type Output = YZW;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl LeftContraction<XY> for ZXW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for ZXW {
+impl LeftContraction<YZW> for ZXW {
 	type Output = Zero;
-	fn geometric(self, _rhs: YZW) -> Self::Output {
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for ZXW {
+impl LeftContraction<ZXW> for ZXW {
 	type Output = Zero;
-	fn geometric(self, _rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYW> for ZXW {
+impl LeftContraction<XYW> for ZXW {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for ZXW {
-	type Output = WY;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl LeftContraction<XYZ> for ZXW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZW> for ZXW {
+impl LeftContraction<XYZW> for ZXW {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<S> for XYW {
-	type Output = XYW;
-	fn geometric(self, rhs: S) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl LeftContraction<S> for XYW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for XYW {
-	type Output = WY;
-	fn geometric(self, rhs: X) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl LeftContraction<X> for XYW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for XYW {
-	type Output = WX;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl LeftContraction<Y> for XYW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for XYW {
-	type Output = XYZW;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl LeftContraction<Z> for XYW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for XYW {
+impl LeftContraction<W> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for XYW {
+impl LeftContraction<WX> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WY> for XYW {
+impl LeftContraction<WY> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WY) -> Self::Output {
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WZ> for XYW {
+impl LeftContraction<WZ> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WZ) -> Self::Output {
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YZ> for XYW {
-	type Output = ZXW;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
+impl LeftContraction<YZ> for XYW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for XYW {
-	type Output = YZW;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl LeftContraction<ZX> for XYW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for XYW {
-	type Output = W;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl LeftContraction<XY> for XYW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for XYW {
+impl LeftContraction<YZW> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: YZW) -> Self::Output {
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for XYW {
+impl LeftContraction<ZXW> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYW> for XYW {
+impl LeftContraction<XYW> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for XYW {
-	type Output = WZ;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl LeftContraction<XYZ> for XYW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZW> for XYW {
+impl LeftContraction<XYZW> for XYW {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<S> for XYZ {
-	type Output = XYZ;
-	fn geometric(self, rhs: S) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl LeftContraction<S> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for XYZ {
-	type Output = YZ;
-	fn geometric(self, rhs: X) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl LeftContraction<X> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for XYZ {
-	type Output = ZX;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl LeftContraction<Y> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for XYZ {
-	type Output = XY;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl LeftContraction<Z> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for XYZ {
-	type Output = XYZW;
-	fn geometric(self, rhs: W) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl LeftContraction<W> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WX> for XYZ {
-	type Output = YZW;
-	fn geometric(self, rhs: WX) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl LeftContraction<WX> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WY> for XYZ {
-	type Output = ZXW;
-	fn geometric(self, rhs: WY) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
+impl LeftContraction<WY> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<WZ> for XYZ {
-	type Output = XYW;
-	fn geometric(self, rhs: WZ) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl LeftContraction<WZ> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZ> for XYZ {
-	type Output = X;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl LeftContraction<YZ> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for XYZ {
-	type Output = Y;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl LeftContraction<ZX> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for XYZ {
-	type Output = Z;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl LeftContraction<XY> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for XYZ {
-	type Output = WX;
-	fn geometric(self, rhs: YZW) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl LeftContraction<YZW> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for XYZ {
-	type Output = WY;
-	fn geometric(self, rhs: ZXW) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl LeftContraction<ZXW> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYW> for XYZ {
-	type Output = WZ;
-	fn geometric(self, rhs: XYW) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl LeftContraction<XYW> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for XYZ {
+impl LeftContraction<XYZ> for XYZ {
 	type Output = S;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<XYZW> for XYZ {
+impl LeftContraction<XYZW> for XYZ {
 	type Output = W;
-	fn geometric(self, rhs: XYZW) -> Self::Output {
+	fn left_contraction(self, rhs: XYZW) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl Geometric<S> for XYZW {
-	type Output = XYZW;
-	fn geometric(self, rhs: S) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl LeftContraction<S> for XYZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<X> for XYZW {
-	type Output = YZW;
-	fn geometric(self, rhs: X) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl LeftContraction<X> for XYZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Y> for XYZW {
-	type Output = ZXW;
-	fn geometric(self, rhs: Y) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
+impl LeftContraction<Y> for XYZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<Z> for XYZW {
-	type Output = XYW;
-	fn geometric(self, rhs: Z) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl LeftContraction<Z> for XYZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<W> for XYZW {
+impl LeftContraction<W> for XYZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: W) -> Self::Output {
+	fn left_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WX> for XYZW {
+impl LeftContraction<WX> for XYZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WX) -> Self::Output {
+	fn left_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WY> for XYZW {
+impl LeftContraction<WY> for XYZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WY) -> Self::Output {
+	fn left_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<WZ> for XYZW {
+impl LeftContraction<WZ> for XYZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: WZ) -> Self::Output {
+	fn left_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<YZ> for XYZW {
-	type Output = WX;
-	fn geometric(self, rhs: YZ) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl LeftContraction<YZ> for XYZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<ZX> for XYZW {
-	type Output = WY;
-	fn geometric(self, rhs: ZX) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl LeftContraction<ZX> for XYZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XY> for XYZW {
-	type Output = WZ;
-	fn geometric(self, rhs: XY) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl LeftContraction<XY> for XYZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<YZW> for XYZW {
+impl LeftContraction<YZW> for XYZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: YZW) -> Self::Output {
+	fn left_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<ZXW> for XYZW {
+impl LeftContraction<ZXW> for XYZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: ZXW) -> Self::Output {
+	fn left_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYW> for XYZW {
+impl LeftContraction<XYW> for XYZW {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYW) -> Self::Output {
+	fn left_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Geometric<XYZ> for XYZW {
-	type Output = W;
-	fn geometric(self, rhs: XYZ) -> Self::Output {
-		W(self.0 * rhs.0)
+impl LeftContraction<XYZ> for XYZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<XYZW> for XYZW {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl RightContraction for blades:
+
+impl RightContraction<S> for S {
+	type Output = S;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<X> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<Y> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Geometric<XYZW> for XYZW {
+impl RightContraction<Z> for S {
 	type Output = Zero;
-	fn geometric(self, _rhs: XYZW) -> Self::Output {
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-// ---------------------------------------------------------------------
-// impl AntiGeometric for blades:
+impl RightContraction<W> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
+	}
+}
 
-impl AntiGeometric<S> for S {
+impl RightContraction<WX> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for S {
+impl RightContraction<WY> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for S {
+impl RightContraction<WZ> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for S {
+impl RightContraction<YZ> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for S {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		XYZ(-self.0 * rhs.0)
+impl RightContraction<ZX> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for S {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl RightContraction<XY> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for S {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl RightContraction<YZW> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for S {
-	type Output = XY;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<ZXW> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for S {
+impl RightContraction<XYW> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for S {
+impl RightContraction<XYZ> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for S {
+impl RightContraction<XYZW> for S {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for S {
+impl RightContraction<S> for X {
 	type Output = X;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
+	fn right_contraction(self, rhs: S) -> Self::Output {
 		X(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<ZXW> for S {
-	type Output = Y;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<X> for X {
+	type Output = S;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYW> for S {
-	type Output = Z;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl RightContraction<Y> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZ> for S {
+impl RightContraction<Z> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZW> for S {
-	type Output = S;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<W> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for X {
+impl RightContraction<WX> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for X {
+impl RightContraction<WY> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for X {
+impl RightContraction<WZ> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for X {
+impl RightContraction<YZ> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for X {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		YZ(-self.0 * rhs.0)
+impl RightContraction<ZX> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for X {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<XY> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for X {
-	type Output = Z;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl RightContraction<YZW> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for X {
-	type Output = Y;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl RightContraction<ZXW> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for X {
+impl RightContraction<XYW> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for X {
+impl RightContraction<XYZ> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for X {
+impl RightContraction<XYZW> for X {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for X {
-	type Output = S;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<S> for Y {
+	type Output = Y;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<ZXW> for X {
-	type Output = XY;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl RightContraction<X> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for X {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl RightContraction<Y> for Y {
+	type Output = S;
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for X {
+impl RightContraction<Z> for Y {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZW> for X {
-	type Output = X;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		X(self.0 * rhs.0)
-	}
-}
-
-impl AntiGeometric<S> for Y {
+impl RightContraction<W> for Y {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn right_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for Y {
+impl RightContraction<WX> for Y {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for Y {
+impl RightContraction<WY> for Y {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for Y {
+impl RightContraction<WZ> for Y {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for Y {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		ZX(-self.0 * rhs.0)
+impl RightContraction<YZ> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for Y {
-	type Output = Z;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl RightContraction<ZX> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for Y {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<XY> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for Y {
-	type Output = X;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<YZW> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for Y {
+impl RightContraction<ZXW> for Y {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for Y {
+impl RightContraction<XYW> for Y {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for Y {
+impl RightContraction<XYZ> for Y {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for Y {
-	type Output = XY;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<XYZW> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for Y {
-	type Output = S;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<S> for Z {
+	type Output = Z;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		Z(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYW> for Y {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		YZ(-self.0 * rhs.0)
+impl RightContraction<X> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZ> for Y {
+impl RightContraction<Y> for Z {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZW> for Y {
-	type Output = Y;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<Z> for Z {
+	type Output = S;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		S(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<S> for Z {
+impl RightContraction<W> for Z {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn right_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for Z {
+impl RightContraction<WX> for Z {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for Z {
+impl RightContraction<WY> for Z {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for Z {
+impl RightContraction<WZ> for Z {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for Z {
-	type Output = XY;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		XY(-self.0 * rhs.0)
-	}
-}
-
-impl AntiGeometric<WX> for Z {
-	type Output = Y;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<YZ> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for Z {
-	type Output = X;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl RightContraction<ZX> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for Z {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<XY> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for Z {
+impl RightContraction<YZW> for Z {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for Z {
+impl RightContraction<ZXW> for Z {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for Z {
+impl RightContraction<XYW> for Z {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for Z {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		ZX(-self.0 * rhs.0)
+impl RightContraction<XYZ> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for Z {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl RightContraction<XYZW> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for Z {
-	type Output = S;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<S> for W {
+	type Output = W;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for Z {
+impl RightContraction<X> for W {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+	fn right_contraction(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZW> for Z {
-	type Output = Z;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl RightContraction<Y> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for W {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<Z> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for W {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl RightContraction<W> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for W {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl RightContraction<WX> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for W {
-	type Output = XY;
-	fn anti_geometric(self, rhs: Z) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<WY> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for W {
-	type Output = XYZW;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl RightContraction<WZ> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for W {
-	type Output = YZW;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl RightContraction<YZ> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for W {
-	type Output = ZXW;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl RightContraction<ZX> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for W {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl RightContraction<XY> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for W {
-	type Output = X;
-	fn anti_geometric(self, rhs: YZ) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl RightContraction<YZW> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for W {
-	type Output = Y;
-	fn anti_geometric(self, rhs: ZX) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl RightContraction<ZXW> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for W {
-	type Output = Z;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl RightContraction<XYW> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for W {
-	type Output = WX;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl RightContraction<XYZ> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for W {
-	type Output = WY;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl RightContraction<XYZW> for W {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for W {
-	type Output = WZ;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl RightContraction<S> for WX {
+	type Output = WX;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		WX(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for W {
-	type Output = S;
-	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl RightContraction<X> for WX {
+	type Output = W;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZW> for W {
-	type Output = W;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		W(self.0 * rhs.0)
+impl RightContraction<Y> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for WX {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl RightContraction<Z> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for WX {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<W> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for WX {
-	type Output = Z;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl RightContraction<WX> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for WX {
-	type Output = Y;
-	fn anti_geometric(self, rhs: Z) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl RightContraction<WY> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for WX {
-	type Output = YZW;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl RightContraction<WZ> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for WX {
-	type Output = XYZW;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl RightContraction<YZ> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for WX {
-	type Output = WZ;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl RightContraction<ZX> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for WX {
-	type Output = WY;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl RightContraction<XY> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for WX {
-	type Output = S;
-	fn anti_geometric(self, rhs: YZ) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl RightContraction<YZW> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for WX {
-	type Output = XY;
-	fn anti_geometric(self, rhs: ZX) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<ZXW> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for WX {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		ZX(-self.0 * rhs.0)
+impl RightContraction<XYW> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for WX {
-	type Output = W;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl RightContraction<XYZ> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for WX {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl RightContraction<XYZW> for WX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for WX {
-	type Output = ZXW;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
+impl RightContraction<S> for WY {
+	type Output = WY;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		WY(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for WX {
-	type Output = X;
-	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl RightContraction<X> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZW> for WX {
-	type Output = WX;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl RightContraction<Y> for WY {
+	type Output = W;
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<S> for WY {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl RightContraction<Z> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for WY {
-	type Output = Z;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl RightContraction<W> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for WY {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<WX> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for WY {
-	type Output = X;
-	fn anti_geometric(self, rhs: Z) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<WY> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for WY {
-	type Output = ZXW;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl RightContraction<WZ> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for WY {
-	type Output = WZ;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl RightContraction<YZ> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for WY {
-	type Output = XYZW;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl RightContraction<ZX> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for WY {
-	type Output = WX;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl RightContraction<XY> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for WY {
-	type Output = XY;
-	fn anti_geometric(self, rhs: YZ) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl RightContraction<YZW> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<ZXW> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for WY {
-	type Output = S;
-	fn anti_geometric(self, rhs: ZX) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl RightContraction<XYW> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for WY {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl RightContraction<XYZ> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for WY {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl RightContraction<XYZW> for WY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for WY {
-	type Output = W;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl RightContraction<S> for WZ {
+	type Output = WZ;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		WZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYW> for WY {
-	type Output = YZW;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl RightContraction<X> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZ> for WY {
-	type Output = Y;
-	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl RightContraction<Y> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZW> for WY {
-	type Output = WY;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl RightContraction<Z> for WZ {
+	type Output = W;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<S> for WZ {
-	type Output = XY;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<W> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for WZ {
-	type Output = Y;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<WX> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for WZ {
-	type Output = X;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl RightContraction<WY> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for WZ {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: Z) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<WZ> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for WZ {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl RightContraction<YZ> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for WZ {
-	type Output = WY;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl RightContraction<ZX> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for WZ {
-	type Output = WX;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl RightContraction<XY> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for WZ {
-	type Output = XYZW;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl RightContraction<YZW> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for WZ {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: YZ) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl RightContraction<ZXW> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for WZ {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: ZX) -> Self::Output {
-		YZ(-self.0 * rhs.0)
+impl RightContraction<XYW> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for WZ {
-	type Output = S;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl RightContraction<XYZ> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for WZ {
-	type Output = ZXW;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl RightContraction<XYZW> for WZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for WZ {
-	type Output = YZW;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl RightContraction<S> for YZ {
+	type Output = YZ;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		YZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYW> for WZ {
-	type Output = W;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl RightContraction<X> for YZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZ> for WZ {
+impl RightContraction<Y> for YZ {
 	type Output = Z;
-	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+	fn right_contraction(self, rhs: Y) -> Self::Output {
 		Z(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZW> for WZ {
-	type Output = WZ;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl RightContraction<Z> for YZ {
+	type Output = Y;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		Y(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<S> for YZ {
+impl RightContraction<W> for YZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn right_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for YZ {
+impl RightContraction<WX> for YZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for YZ {
+impl RightContraction<WY> for YZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for YZ {
+impl RightContraction<WZ> for YZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for YZ {
-	type Output = X;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<YZ> for YZ {
+	type Output = S;
+	fn right_contraction(self, rhs: YZ) -> Self::Output {
+		S(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WX> for YZ {
-	type Output = S;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl RightContraction<ZX> for YZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for YZ {
-	type Output = XY;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<XY> for YZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for YZ {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		ZX(-self.0 * rhs.0)
+impl RightContraction<YZW> for YZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for YZ {
+impl RightContraction<ZXW> for YZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for YZ {
+impl RightContraction<XYW> for YZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for YZ {
+impl RightContraction<XYZ> for YZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for YZ {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<XYZW> for YZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for YZ {
-	type Output = Z;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl RightContraction<S> for ZX {
+	type Output = ZX;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		ZX(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYW> for YZ {
-	type Output = Y;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl RightContraction<X> for ZX {
+	type Output = Z;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		Z(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for YZ {
+impl RightContraction<Y> for ZX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZW> for YZ {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl RightContraction<Z> for ZX {
+	type Output = X;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		X(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<S> for ZX {
+impl RightContraction<W> for ZX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn right_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for ZX {
+impl RightContraction<WX> for ZX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for ZX {
+impl RightContraction<WY> for ZX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for ZX {
+impl RightContraction<WZ> for ZX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for ZX {
-	type Output = Y;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<YZ> for ZX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for ZX {
-	type Output = XY;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl RightContraction<ZX> for ZX {
+	type Output = S;
+	fn right_contraction(self, rhs: ZX) -> Self::Output {
+		S(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WY> for ZX {
-	type Output = S;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl RightContraction<XY> for ZX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for ZX {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl RightContraction<YZW> for ZX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for ZX {
+impl RightContraction<ZXW> for ZX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for ZX {
+impl RightContraction<XYW> for ZX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for ZX {
+impl RightContraction<XYZ> for ZX {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for ZX {
-	type Output = Z;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl RightContraction<XYZW> for ZX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for ZX {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<S> for XY {
+	type Output = XY;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<X> for XY {
+	type Output = Y;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYW> for ZX {
+impl RightContraction<Y> for XY {
 	type Output = X;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
+	fn right_contraction(self, rhs: Y) -> Self::Output {
 		X(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for ZX {
+impl RightContraction<Z> for XY {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZW> for ZX {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		ZX(self.0 * rhs.0)
-	}
-}
-
-impl AntiGeometric<S> for XY {
+impl RightContraction<W> for XY {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
+	fn right_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for XY {
+impl RightContraction<WX> for XY {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for XY {
+impl RightContraction<WY> for XY {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for XY {
+impl RightContraction<WZ> for XY {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for XY {
-	type Output = Z;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		Z(self.0 * rhs.0)
-	}
-}
-
-impl AntiGeometric<WX> for XY {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl RightContraction<YZ> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for XY {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		YZ(-self.0 * rhs.0)
+impl RightContraction<ZX> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for XY {
+impl RightContraction<XY> for XY {
 	type Output = S;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+	fn right_contraction(self, rhs: XY) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YZ> for XY {
+impl RightContraction<YZW> for XY {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for XY {
+impl RightContraction<ZXW> for XY {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for XY {
+impl RightContraction<XYW> for XY {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for XY {
-	type Output = Y;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<XYZ> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for XY {
-	type Output = X;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl RightContraction<XYZW> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for XY {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<S> for YZW {
+	type Output = YZW;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		YZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for XY {
+impl RightContraction<X> for YZW {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
+	fn right_contraction(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZW> for XY {
-	type Output = XY;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<Y> for YZW {
+	type Output = WZ;
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		WZ(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<S> for YZW {
-	type Output = X;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl RightContraction<Z> for YZW {
+	type Output = WY;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		WY(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<X> for YZW {
-	type Output = S;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl RightContraction<W> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for YZW {
-	type Output = XY;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<WX> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for YZW {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: Z) -> Self::Output {
-		ZX(-self.0 * rhs.0)
+impl RightContraction<WY> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for YZW {
-	type Output = WX;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl RightContraction<WZ> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for YZW {
+impl RightContraction<YZ> for YZW {
 	type Output = W;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
+	fn right_contraction(self, rhs: YZ) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WY> for YZW {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl RightContraction<ZX> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for YZW {
-	type Output = ZXW;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
+impl RightContraction<XY> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for YZW {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: YZ) -> Self::Output {
-		XYZ(-self.0 * rhs.0)
+impl RightContraction<YZW> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for YZW {
-	type Output = Z;
-	fn anti_geometric(self, rhs: ZX) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl RightContraction<ZXW> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for YZW {
-	type Output = Y;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<XYW> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for YZW {
-	type Output = XYZW;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl RightContraction<XYZ> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for YZW {
-	type Output = WZ;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl RightContraction<XYZW> for YZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for YZW {
-	type Output = WY;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl RightContraction<S> for ZXW {
+	type Output = ZXW;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for YZW {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
-		YZ(-self.0 * rhs.0)
+impl RightContraction<X> for ZXW {
+	type Output = WZ;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		WZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZW> for YZW {
-	type Output = YZW;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl RightContraction<Y> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<S> for ZXW {
-	type Output = Y;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl RightContraction<Z> for ZXW {
+	type Output = WX;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		WX(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<X> for ZXW {
-	type Output = XY;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl RightContraction<W> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for ZXW {
-	type Output = S;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl RightContraction<WX> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for ZXW {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: Z) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl RightContraction<WY> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for ZXW {
-	type Output = WY;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl RightContraction<WZ> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for ZXW {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl RightContraction<YZ> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for ZXW {
+impl RightContraction<ZX> for ZXW {
 	type Output = W;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
+	fn right_contraction(self, rhs: ZX) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WZ> for ZXW {
-	type Output = YZW;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl RightContraction<XY> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZ> for ZXW {
-	type Output = Z;
-	fn anti_geometric(self, rhs: YZ) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl RightContraction<YZW> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for ZXW {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: ZX) -> Self::Output {
-		XYZ(-self.0 * rhs.0)
+impl RightContraction<ZXW> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for ZXW {
-	type Output = X;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl RightContraction<XYW> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for ZXW {
-	type Output = WZ;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl RightContraction<XYZ> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for ZXW {
-	type Output = XYZW;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl RightContraction<XYZW> for ZXW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for ZXW {
-	type Output = WX;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl RightContraction<S> for XYW {
+	type Output = XYW;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for ZXW {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
-		ZX(-self.0 * rhs.0)
+impl RightContraction<X> for XYW {
+	type Output = WY;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		WY(-self.0 * rhs.0)
 	}
-}
-
-impl AntiGeometric<XYZW> for ZXW {
-	type Output = ZXW;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+}
+
+impl RightContraction<Y> for XYW {
+	type Output = WX;
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		WX(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<S> for XYW {
-	type Output = Z;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl RightContraction<Z> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<X> for XYW {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl RightContraction<W> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for XYW {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		YZ(-self.0 * rhs.0)
+impl RightContraction<WX> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for XYW {
-	type Output = S;
-	fn anti_geometric(self, rhs: Z) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl RightContraction<WY> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for XYW {
-	type Output = WZ;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl RightContraction<WZ> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for XYW {
-	type Output = ZXW;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl RightContraction<YZ> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WY> for XYW {
-	type Output = YZW;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl RightContraction<ZX> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WZ> for XYW {
+impl RightContraction<XY> for XYW {
 	type Output = W;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+	fn right_contraction(self, rhs: XY) -> Self::Output {
 		W(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YZ> for XYW {
-	type Output = Y;
-	fn anti_geometric(self, rhs: YZ) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl RightContraction<YZW> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for XYW {
-	type Output = X;
-	fn anti_geometric(self, rhs: ZX) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<ZXW> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for XYW {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		XYZ(-self.0 * rhs.0)
+impl RightContraction<XYW> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for XYW {
-	type Output = WY;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl RightContraction<XYZ> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for XYW {
-	type Output = WX;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl RightContraction<XYZW> for XYW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for XYW {
-	type Output = XYZW;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl RightContraction<S> for XYZ {
+	type Output = XYZ;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for XYW {
-	type Output = XY;
-	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
-		XY(-self.0 * rhs.0)
+impl RightContraction<X> for XYZ {
+	type Output = YZ;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		YZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZW> for XYW {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl RightContraction<Y> for XYZ {
+	type Output = ZX;
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		ZX(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<S> for XYZ {
-	type Output = Zero;
-	fn anti_geometric(self, _rhs: S) -> Self::Output {
-		Zero {}
+impl RightContraction<Z> for XYZ {
+	type Output = XY;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<X> for XYZ {
+impl RightContraction<W> for XYZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: X) -> Self::Output {
+	fn right_contraction(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for XYZ {
+impl RightContraction<WX> for XYZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Y) -> Self::Output {
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for XYZ {
+impl RightContraction<WY> for XYZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: Z) -> Self::Output {
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for XYZ {
-	type Output = S;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<WZ> for XYZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for XYZ {
+impl RightContraction<YZ> for XYZ {
 	type Output = X;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
+	fn right_contraction(self, rhs: YZ) -> Self::Output {
 		X(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WY> for XYZ {
+impl RightContraction<ZX> for XYZ {
 	type Output = Y;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
+	fn right_contraction(self, rhs: ZX) -> Self::Output {
 		Y(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WZ> for XYZ {
+impl RightContraction<XY> for XYZ {
 	type Output = Z;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+	fn right_contraction(self, rhs: XY) -> Self::Output {
 		Z(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YZ> for XYZ {
+impl RightContraction<YZW> for XYZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: YZ) -> Self::Output {
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<ZX> for XYZ {
+impl RightContraction<ZXW> for XYZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: ZX) -> Self::Output {
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<XY> for XYZ {
+impl RightContraction<XYW> for XYZ {
 	type Output = Zero;
-	fn anti_geometric(self, _rhs: XY) -> Self::Output {
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiGeometric<YZW> for XYZ {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl RightContraction<XYZ> for XYZ {
+	type Output = S;
+	fn right_contraction(self, rhs: XYZ) -> Self::Output {
+		S(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<ZXW> for XYZ {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl RightContraction<XYZW> for XYZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for XYZ {
-	type Output = XY;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl RightContraction<S> for XYZW {
+	type Output = XYZW;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		XYZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZ> for XYZ {
-	type Output = Zero;
-	fn anti_geometric(self, _rhs: XYZ) -> Self::Output {
-		Zero {}
+impl RightContraction<X> for XYZW {
+	type Output = YZW;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		YZW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZW> for XYZ {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<Y> for XYZW {
+	type Output = ZXW;
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<S> for XYZW {
-	type Output = S;
-	fn anti_geometric(self, rhs: S) -> Self::Output {
-		S(self.0 * rhs.0)
+impl RightContraction<Z> for XYZW {
+	type Output = XYW;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		XYW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<X> for XYZW {
-	type Output = X;
-	fn anti_geometric(self, rhs: X) -> Self::Output {
-		X(self.0 * rhs.0)
+impl RightContraction<W> for XYZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Y> for XYZW {
-	type Output = Y;
-	fn anti_geometric(self, rhs: Y) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl RightContraction<WX> for XYZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<Z> for XYZW {
-	type Output = Z;
-	fn anti_geometric(self, rhs: Z) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl RightContraction<WY> for XYZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<W> for XYZW {
-	type Output = W;
-	fn anti_geometric(self, rhs: W) -> Self::Output {
-		W(self.0 * rhs.0)
+impl RightContraction<WZ> for XYZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<WX> for XYZW {
+impl RightContraction<YZ> for XYZW {
 	type Output = WX;
-	fn anti_geometric(self, rhs: WX) -> Self::Output {
+	fn right_contraction(self, rhs: YZ) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WY> for XYZW {
+impl RightContraction<ZX> for XYZW {
 	type Output = WY;
-	fn anti_geometric(self, rhs: WY) -> Self::Output {
+	fn right_contraction(self, rhs: ZX) -> Self::Output {
 		WY(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<WZ> for XYZW {
+impl RightContraction<XY> for XYZW {
 	type Output = WZ;
-	fn anti_geometric(self, rhs: WZ) -> Self::Output {
+	fn right_contraction(self, rhs: XY) -> Self::Output {
 		WZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<YZ> for XYZW {
-	type Output = YZ;
-	fn anti_geometric(self, rhs: YZ) -> Self::Output {
-		YZ(self.0 * rhs.0)
-	}
-}
-
-impl AntiGeometric<ZX> for XYZW {
-	type Output = ZX;
-	fn anti_geometric(self, rhs: ZX) -> Self::Output {
-		ZX(self.0 * rhs.0)
-	}
-}
-
-impl AntiGeometric<XY> for XYZW {
-	type Output = XY;
-	fn anti_geometric(self, rhs: XY) -> Self::Output {
-		XY(self.0 * rhs.0)
-	}
-}
-
-impl AntiGeometric<YZW> for XYZW {
-	type Output = YZW;
-	fn anti_geometric(self, rhs: YZW) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl RightContraction<YZW> for XYZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<ZXW> for XYZW {
-	type Output = ZXW;
-	fn anti_geometric(self, rhs: ZXW) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl RightContraction<ZXW> for XYZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYW> for XYZW {
-	type Output = XYW;
-	fn anti_geometric(self, rhs: XYW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl RightContraction<XYW> for XYZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiGeometric<XYZ> for XYZW {
-	type Output = XYZ;
-	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl RightContraction<XYZ> for XYZW {
+	type Output = W;
+	fn right_contraction(self, rhs: XYZ) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiGeometric<XYZW> for XYZW {
-	type Output = XYZW;
-	fn anti_geometric(self, rhs: XYZW) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl RightContraction<XYZW> for XYZW {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
 // ---------------------------------------------------------------------
-// impl Dot for blades:
+// impl ScalarProduct for blades:
 
-impl Dot<S> for S {
+impl ScalarProduct<S> for S {
 	type Output = S;
-	fn dot(self, rhs: S) -> Self::Output {
+	fn scalar_product(self, rhs: S) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Dot<X> for S {
-	type Output = X;
-	fn dot(self, rhs: X) -> Self::Output {
-		X(self.0 * rhs.0)
+impl ScalarProduct<X> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for S {
-	type Output = Y;
-	fn dot(self, rhs: Y) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl ScalarProduct<Y> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Z> for S {
-	type Output = Z;
-	fn dot(self, rhs: Z) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl ScalarProduct<Z> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for S {
-	type Output = W;
-	fn dot(self, rhs: W) -> Self::Output {
-		W(self.0 * rhs.0)
+impl ScalarProduct<W> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<WX> for S {
-	type Output = WX;
-	fn dot(self, rhs: WX) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl ScalarProduct<WX> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<WY> for S {
-	type Output = WY;
-	fn dot(self, rhs: WY) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl ScalarProduct<WY> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<WZ> for S {
-	type Output = WZ;
-	fn dot(self, rhs: WZ) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl ScalarProduct<WZ> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<YZ> for S {
-	type Output = YZ;
-	fn dot(self, rhs: YZ) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl ScalarProduct<YZ> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<ZX> for S {
-	type Output = ZX;
-	fn dot(self, rhs: ZX) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl ScalarProduct<ZX> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XY> for S {
-	type Output = XY;
-	fn dot(self, rhs: XY) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl ScalarProduct<XY> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<YZW> for S {
-	type Output = YZW;
-	fn dot(self, rhs: YZW) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl ScalarProduct<YZW> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<ZXW> for S {
-	type Output = ZXW;
-	fn dot(self, rhs: ZXW) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl ScalarProduct<ZXW> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYW> for S {
-	type Output = XYW;
-	fn dot(self, rhs: XYW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl ScalarProduct<XYW> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZ> for S {
-	type Output = XYZ;
-	fn dot(self, rhs: XYZ) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl ScalarProduct<XYZ> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZW> for S {
-	type Output = XYZW;
-	fn dot(self, rhs: XYZW) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl ScalarProduct<XYZW> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for X {
-	type Output = X;
-	fn dot(self, rhs: S) -> Self::Output {
-		X(self.0 * rhs.0)
+impl ScalarProduct<S> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for X {
+impl ScalarProduct<X> for X {
 	type Output = S;
-	fn dot(self, rhs: X) -> Self::Output {
+	fn scalar_product(self, rhs: X) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Dot<Y> for X {
+impl ScalarProduct<Y> for X {
 	type Output = Zero;
-	fn dot(self, _rhs: Y) -> Self::Output {
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Z> for X {
+impl ScalarProduct<Z> for X {
 	type Output = Zero;
-	fn dot(self, _rhs: Z) -> Self::Output {
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<W> for X {
+impl ScalarProduct<W> for X {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for X {
-	type Output = W;
-	fn dot(self, rhs: WX) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<WX> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<WY> for X {
+impl ScalarProduct<WY> for X {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for X {
+impl ScalarProduct<WZ> for X {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for X {
+impl ScalarProduct<YZ> for X {
 	type Output = Zero;
-	fn dot(self, _rhs: YZ) -> Self::Output {
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZX> for X {
-	type Output = Z;
-	fn dot(self, rhs: ZX) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl ScalarProduct<ZX> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XY> for X {
-	type Output = Y;
-	fn dot(self, rhs: XY) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl ScalarProduct<XY> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<YZW> for X {
+impl ScalarProduct<YZW> for X {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for X {
-	type Output = WZ;
-	fn dot(self, rhs: ZXW) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl ScalarProduct<ZXW> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYW> for X {
-	type Output = WY;
-	fn dot(self, rhs: XYW) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl ScalarProduct<XYW> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZ> for X {
-	type Output = YZ;
-	fn dot(self, rhs: XYZ) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl ScalarProduct<XYZ> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZW> for X {
-	type Output = YZW;
-	fn dot(self, rhs: XYZW) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl ScalarProduct<XYZW> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for Y {
-	type Output = Y;
-	fn dot(self, rhs: S) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl ScalarProduct<S> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for Y {
+impl ScalarProduct<X> for Y {
 	type Output = Zero;
-	fn dot(self, _rhs: X) -> Self::Output {
+	fn scalar_product(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Y> for Y {
+impl ScalarProduct<Y> for Y {
 	type Output = S;
-	fn dot(self, rhs: Y) -> Self::Output {
+	fn scalar_product(self, rhs: Y) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Dot<Z> for Y {
+impl ScalarProduct<Z> for Y {
 	type Output = Zero;
-	fn dot(self, _rhs: Z) -> Self::Output {
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<W> for Y {
+impl ScalarProduct<W> for Y {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for Y {
+impl ScalarProduct<WX> for Y {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for Y {
-	type Output = W;
-	fn dot(self, rhs: WY) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<WY> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<WZ> for Y {
+impl ScalarProduct<WZ> for Y {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for Y {
-	type Output = Z;
-	fn dot(self, rhs: YZ) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl ScalarProduct<YZ> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<ZX> for Y {
+impl ScalarProduct<ZX> for Y {
 	type Output = Zero;
-	fn dot(self, _rhs: ZX) -> Self::Output {
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for Y {
-	type Output = X;
-	fn dot(self, rhs: XY) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl ScalarProduct<XY> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<YZW> for Y {
-	type Output = WZ;
-	fn dot(self, rhs: YZW) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl ScalarProduct<YZW> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<ZXW> for Y {
+impl ScalarProduct<ZXW> for Y {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for Y {
-	type Output = WX;
-	fn dot(self, rhs: XYW) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl ScalarProduct<XYW> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZ> for Y {
-	type Output = ZX;
-	fn dot(self, rhs: XYZ) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl ScalarProduct<XYZ> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZW> for Y {
-	type Output = ZXW;
-	fn dot(self, rhs: XYZW) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl ScalarProduct<XYZW> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for Z {
-	type Output = Z;
-	fn dot(self, rhs: S) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl ScalarProduct<S> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for Z {
+impl ScalarProduct<X> for Z {
 	type Output = Zero;
-	fn dot(self, _rhs: X) -> Self::Output {
+	fn scalar_product(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Y> for Z {
+impl ScalarProduct<Y> for Z {
 	type Output = Zero;
-	fn dot(self, _rhs: Y) -> Self::Output {
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Z> for Z {
+impl ScalarProduct<Z> for Z {
 	type Output = S;
-	fn dot(self, rhs: Z) -> Self::Output {
+	fn scalar_product(self, rhs: Z) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl Dot<W> for Z {
+impl ScalarProduct<W> for Z {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for Z {
+impl ScalarProduct<WX> for Z {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for Z {
+impl ScalarProduct<WY> for Z {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for Z {
-	type Output = W;
-	fn dot(self, rhs: WZ) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<WZ> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<YZ> for Z {
-	type Output = Y;
-	fn dot(self, rhs: YZ) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl ScalarProduct<YZ> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<ZX> for Z {
-	type Output = X;
-	fn dot(self, rhs: ZX) -> Self::Output {
-		X(self.0 * rhs.0)
+impl ScalarProduct<ZX> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XY> for Z {
+impl ScalarProduct<XY> for Z {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZW> for Z {
-	type Output = WY;
-	fn dot(self, rhs: YZW) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl ScalarProduct<YZW> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<ZXW> for Z {
-	type Output = WX;
-	fn dot(self, rhs: ZXW) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl ScalarProduct<ZXW> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYW> for Z {
+impl ScalarProduct<XYW> for Z {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for Z {
-	type Output = XY;
-	fn dot(self, rhs: XYZ) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl ScalarProduct<XYZ> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZW> for Z {
-	type Output = XYW;
-	fn dot(self, rhs: XYZW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl ScalarProduct<XYZW> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for W {
-	type Output = W;
-	fn dot(self, rhs: S) -> Self::Output {
-		W(self.0 * rhs.0)
+impl ScalarProduct<S> for W {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for W {
+impl ScalarProduct<X> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: X) -> Self::Output {
+	fn scalar_product(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Y> for W {
+impl ScalarProduct<Y> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: Y) -> Self::Output {
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Z> for W {
+impl ScalarProduct<Z> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: Z) -> Self::Output {
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<W> for W {
+impl ScalarProduct<W> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for W {
+impl ScalarProduct<WX> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for W {
+impl ScalarProduct<WY> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for W {
+impl ScalarProduct<WZ> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for W {
+impl ScalarProduct<YZ> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: YZ) -> Self::Output {
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZX> for W {
+impl ScalarProduct<ZX> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: ZX) -> Self::Output {
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for W {
+impl ScalarProduct<XY> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZW> for W {
+impl ScalarProduct<YZW> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for W {
+impl ScalarProduct<ZXW> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for W {
+impl ScalarProduct<XYW> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for W {
+impl ScalarProduct<XYZ> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZ) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZW> for W {
+impl ScalarProduct<XYZW> for W {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<S> for WX {
-	type Output = WX;
-	fn dot(self, rhs: S) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl ScalarProduct<S> for WX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for WX {
-	type Output = W;
-	fn dot(self, rhs: X) -> Self::Output {
-		W(self.0 * rhs.0)
+impl ScalarProduct<X> for WX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for WX {
+impl ScalarProduct<Y> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: Y) -> Self::Output {
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Z> for WX {
+impl ScalarProduct<Z> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: Z) -> Self::Output {
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<W> for WX {
+impl ScalarProduct<W> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for WX {
+impl ScalarProduct<WX> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for WX {
+impl ScalarProduct<WY> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for WX {
+impl ScalarProduct<WZ> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for WX {
+impl ScalarProduct<YZ> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: YZ) -> Self::Output {
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZX> for WX {
+impl ScalarProduct<ZX> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: ZX) -> Self::Output {
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for WX {
+impl ScalarProduct<XY> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZW> for WX {
+impl ScalarProduct<YZW> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for WX {
+impl ScalarProduct<ZXW> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for WX {
+impl ScalarProduct<XYW> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for WX {
+impl ScalarProduct<XYZ> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZ) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZW> for WX {
+impl ScalarProduct<XYZW> for WX {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<S> for WY {
-	type Output = WY;
-	fn dot(self, rhs: S) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl ScalarProduct<S> for WY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for WY {
+impl ScalarProduct<X> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: X) -> Self::Output {
+	fn scalar_product(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Y> for WY {
-	type Output = W;
-	fn dot(self, rhs: Y) -> Self::Output {
-		W(self.0 * rhs.0)
+impl ScalarProduct<Y> for WY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Z> for WY {
+impl ScalarProduct<Z> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: Z) -> Self::Output {
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<W> for WY {
+impl ScalarProduct<W> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for WY {
+impl ScalarProduct<WX> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for WY {
+impl ScalarProduct<WY> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for WY {
+impl ScalarProduct<WZ> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for WY {
+impl ScalarProduct<YZ> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: YZ) -> Self::Output {
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZX> for WY {
+impl ScalarProduct<ZX> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: ZX) -> Self::Output {
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for WY {
+impl ScalarProduct<XY> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZW> for WY {
+impl ScalarProduct<YZW> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for WY {
+impl ScalarProduct<ZXW> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for WY {
+impl ScalarProduct<XYW> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for WY {
+impl ScalarProduct<XYZ> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZ) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZW> for WY {
+impl ScalarProduct<XYZW> for WY {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<S> for WZ {
-	type Output = WZ;
-	fn dot(self, rhs: S) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl ScalarProduct<S> for WZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for WZ {
+impl ScalarProduct<X> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: X) -> Self::Output {
+	fn scalar_product(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Y> for WZ {
+impl ScalarProduct<Y> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: Y) -> Self::Output {
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Z> for WZ {
-	type Output = W;
-	fn dot(self, rhs: Z) -> Self::Output {
-		W(self.0 * rhs.0)
+impl ScalarProduct<Z> for WZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for WZ {
+impl ScalarProduct<W> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for WZ {
+impl ScalarProduct<WX> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for WZ {
+impl ScalarProduct<WY> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for WZ {
+impl ScalarProduct<WZ> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for WZ {
+impl ScalarProduct<YZ> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: YZ) -> Self::Output {
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZX> for WZ {
+impl ScalarProduct<ZX> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: ZX) -> Self::Output {
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for WZ {
+impl ScalarProduct<XY> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZW> for WZ {
+impl ScalarProduct<YZW> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for WZ {
+impl ScalarProduct<ZXW> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for WZ {
+impl ScalarProduct<XYW> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for WZ {
+impl ScalarProduct<XYZ> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZ) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZW> for WZ {
+impl ScalarProduct<XYZW> for WZ {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<S> for YZ {
-	type Output = YZ;
-	fn dot(self, rhs: S) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl ScalarProduct<S> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for YZ {
+impl ScalarProduct<X> for YZ {
 	type Output = Zero;
-	fn dot(self, _rhs: X) -> Self::Output {
+	fn scalar_product(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Y> for YZ {
-	type Output = Z;
-	fn dot(self, rhs: Y) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl ScalarProduct<Y> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Z> for YZ {
-	type Output = Y;
-	fn dot(self, rhs: Z) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl ScalarProduct<Z> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for YZ {
+impl ScalarProduct<W> for YZ {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for YZ {
+impl ScalarProduct<WX> for YZ {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for YZ {
+impl ScalarProduct<WY> for YZ {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for YZ {
+impl ScalarProduct<WZ> for YZ {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for YZ {
+impl ScalarProduct<YZ> for YZ {
 	type Output = S;
-	fn dot(self, rhs: YZ) -> Self::Output {
+	fn scalar_product(self, rhs: YZ) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl Dot<ZX> for YZ {
+impl ScalarProduct<ZX> for YZ {
 	type Output = Zero;
-	fn dot(self, _rhs: ZX) -> Self::Output {
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for YZ {
+impl ScalarProduct<XY> for YZ {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZW> for YZ {
-	type Output = W;
-	fn dot(self, rhs: YZW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<YZW> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<ZXW> for YZ {
+impl ScalarProduct<ZXW> for YZ {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for YZ {
+impl ScalarProduct<XYW> for YZ {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for YZ {
-	type Output = X;
-	fn dot(self, rhs: XYZ) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl ScalarProduct<XYZ> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZW> for YZ {
-	type Output = WX;
-	fn dot(self, rhs: XYZW) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl ScalarProduct<XYZW> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for ZX {
-	type Output = ZX;
-	fn dot(self, rhs: S) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl ScalarProduct<S> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for ZX {
-	type Output = Z;
-	fn dot(self, rhs: X) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl ScalarProduct<X> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for ZX {
+impl ScalarProduct<Y> for ZX {
 	type Output = Zero;
-	fn dot(self, _rhs: Y) -> Self::Output {
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Z> for ZX {
-	type Output = X;
-	fn dot(self, rhs: Z) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl ScalarProduct<Z> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for ZX {
+impl ScalarProduct<W> for ZX {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for ZX {
+impl ScalarProduct<WX> for ZX {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for ZX {
+impl ScalarProduct<WY> for ZX {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for ZX {
+impl ScalarProduct<WZ> for ZX {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for ZX {
+impl ScalarProduct<YZ> for ZX {
 	type Output = Zero;
-	fn dot(self, _rhs: YZ) -> Self::Output {
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZX> for ZX {
+impl ScalarProduct<ZX> for ZX {
 	type Output = S;
-	fn dot(self, rhs: ZX) -> Self::Output {
+	fn scalar_product(self, rhs: ZX) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl Dot<XY> for ZX {
+impl ScalarProduct<XY> for ZX {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZW> for ZX {
+impl ScalarProduct<YZW> for ZX {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for ZX {
-	type Output = W;
-	fn dot(self, rhs: ZXW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<ZXW> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYW> for ZX {
+impl ScalarProduct<XYW> for ZX {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for ZX {
-	type Output = Y;
-	fn dot(self, rhs: XYZ) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl ScalarProduct<XYZ> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZW> for ZX {
-	type Output = WY;
-	fn dot(self, rhs: XYZW) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl ScalarProduct<XYZW> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for XY {
-	type Output = XY;
-	fn dot(self, rhs: S) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl ScalarProduct<S> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for XY {
-	type Output = Y;
-	fn dot(self, rhs: X) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl ScalarProduct<X> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for XY {
-	type Output = X;
-	fn dot(self, rhs: Y) -> Self::Output {
-		X(self.0 * rhs.0)
+impl ScalarProduct<Y> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Z> for XY {
+impl ScalarProduct<Z> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: Z) -> Self::Output {
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<W> for XY {
+impl ScalarProduct<W> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for XY {
+impl ScalarProduct<WX> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for XY {
+impl ScalarProduct<WY> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for XY {
+impl ScalarProduct<WZ> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for XY {
+impl ScalarProduct<YZ> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: YZ) -> Self::Output {
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZX> for XY {
+impl ScalarProduct<ZX> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: ZX) -> Self::Output {
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for XY {
+impl ScalarProduct<XY> for XY {
 	type Output = S;
-	fn dot(self, rhs: XY) -> Self::Output {
+	fn scalar_product(self, rhs: XY) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl Dot<YZW> for XY {
+impl ScalarProduct<YZW> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for XY {
+impl ScalarProduct<ZXW> for XY {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for XY {
-	type Output = W;
-	fn dot(self, rhs: XYW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<XYW> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZ> for XY {
-	type Output = Z;
-	fn dot(self, rhs: XYZ) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl ScalarProduct<XYZ> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZW> for XY {
-	type Output = WZ;
-	fn dot(self, rhs: XYZW) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl ScalarProduct<XYZW> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for YZW {
-	type Output = YZW;
-	fn dot(self, rhs: S) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl ScalarProduct<S> for YZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for YZW {
+impl ScalarProduct<X> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: X) -> Self::Output {
+	fn scalar_product(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Y> for YZW {
-	type Output = WZ;
-	fn dot(self, rhs: Y) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl ScalarProduct<Y> for YZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Z> for YZW {
-	type Output = WY;
-	fn dot(self, rhs: Z) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl ScalarProduct<Z> for YZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for YZW {
+impl ScalarProduct<W> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for YZW {
+impl ScalarProduct<WX> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for YZW {
+impl ScalarProduct<WY> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for YZW {
+impl ScalarProduct<WZ> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for YZW {
-	type Output = W;
-	fn dot(self, rhs: YZ) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<YZ> for YZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<ZX> for YZW {
+impl ScalarProduct<ZX> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: ZX) -> Self::Output {
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for YZW {
+impl ScalarProduct<XY> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZW> for YZW {
+impl ScalarProduct<YZW> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for YZW {
+impl ScalarProduct<ZXW> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for YZW {
+impl ScalarProduct<XYW> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for YZW {
+impl ScalarProduct<XYZ> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZ) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZW> for YZW {
+impl ScalarProduct<XYZW> for YZW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<S> for ZXW {
-	type Output = ZXW;
-	fn dot(self, rhs: S) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl ScalarProduct<S> for ZXW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for ZXW {
-	type Output = WZ;
-	fn dot(self, rhs: X) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl ScalarProduct<X> for ZXW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for ZXW {
+impl ScalarProduct<Y> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: Y) -> Self::Output {
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<Z> for ZXW {
-	type Output = WX;
-	fn dot(self, rhs: Z) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl ScalarProduct<Z> for ZXW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for ZXW {
+impl ScalarProduct<W> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for ZXW {
+impl ScalarProduct<WX> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for ZXW {
+impl ScalarProduct<WY> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for ZXW {
+impl ScalarProduct<WZ> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for ZXW {
+impl ScalarProduct<YZ> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: YZ) -> Self::Output {
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZX> for ZXW {
-	type Output = W;
-	fn dot(self, rhs: ZX) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<ZX> for ZXW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XY> for ZXW {
+impl ScalarProduct<XY> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: XY) -> Self::Output {
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZW> for ZXW {
+impl ScalarProduct<YZW> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for ZXW {
+impl ScalarProduct<ZXW> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for ZXW {
+impl ScalarProduct<XYW> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for ZXW {
+impl ScalarProduct<XYZ> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZ) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZW> for ZXW {
+impl ScalarProduct<XYZW> for ZXW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<S> for XYW {
-	type Output = XYW;
-	fn dot(self, rhs: S) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl ScalarProduct<S> for XYW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for XYW {
-	type Output = WY;
-	fn dot(self, rhs: X) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl ScalarProduct<X> for XYW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for XYW {
-	type Output = WX;
-	fn dot(self, rhs: Y) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl ScalarProduct<Y> for XYW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Z> for XYW {
+impl ScalarProduct<Z> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: Z) -> Self::Output {
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<W> for XYW {
+impl ScalarProduct<W> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for XYW {
+impl ScalarProduct<WX> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for XYW {
+impl ScalarProduct<WY> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for XYW {
+impl ScalarProduct<WZ> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for XYW {
+impl ScalarProduct<YZ> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: YZ) -> Self::Output {
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZX> for XYW {
+impl ScalarProduct<ZX> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: ZX) -> Self::Output {
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XY> for XYW {
-	type Output = W;
-	fn dot(self, rhs: XY) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<XY> for XYW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<YZW> for XYW {
+impl ScalarProduct<YZW> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for XYW {
+impl ScalarProduct<ZXW> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for XYW {
+impl ScalarProduct<XYW> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for XYW {
+impl ScalarProduct<XYZ> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZ) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZW> for XYW {
+impl ScalarProduct<XYZW> for XYW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<S> for XYZ {
-	type Output = XYZ;
-	fn dot(self, rhs: S) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl ScalarProduct<S> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for XYZ {
-	type Output = YZ;
-	fn dot(self, rhs: X) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl ScalarProduct<X> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for XYZ {
-	type Output = ZX;
-	fn dot(self, rhs: Y) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl ScalarProduct<Y> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Z> for XYZ {
-	type Output = XY;
-	fn dot(self, rhs: Z) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl ScalarProduct<Z> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for XYZ {
+impl ScalarProduct<W> for XYZ {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for XYZ {
+impl ScalarProduct<WX> for XYZ {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for XYZ {
+impl ScalarProduct<WY> for XYZ {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for XYZ {
+impl ScalarProduct<WZ> for XYZ {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for XYZ {
-	type Output = X;
-	fn dot(self, rhs: YZ) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl ScalarProduct<YZ> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<ZX> for XYZ {
-	type Output = Y;
-	fn dot(self, rhs: ZX) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl ScalarProduct<ZX> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XY> for XYZ {
-	type Output = Z;
-	fn dot(self, rhs: XY) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl ScalarProduct<XY> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<YZW> for XYZ {
+impl ScalarProduct<YZW> for XYZ {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for XYZ {
+impl ScalarProduct<ZXW> for XYZ {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for XYZ {
+impl ScalarProduct<XYW> for XYZ {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for XYZ {
+impl ScalarProduct<XYZ> for XYZ {
 	type Output = S;
-	fn dot(self, rhs: XYZ) -> Self::Output {
+	fn scalar_product(self, rhs: XYZ) -> Self::Output {
 		S(-self.0 * rhs.0)
 	}
 }
 
-impl Dot<XYZW> for XYZ {
-	type Output = W;
-	fn dot(self, rhs: XYZW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl ScalarProduct<XYZW> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<S> for XYZW {
-	type Output = XYZW;
-	fn dot(self, rhs: S) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl ScalarProduct<S> for XYZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<X> for XYZW {
-	type Output = YZW;
-	fn dot(self, rhs: X) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl ScalarProduct<X> for XYZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Y> for XYZW {
-	type Output = ZXW;
-	fn dot(self, rhs: Y) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
+impl ScalarProduct<Y> for XYZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<Z> for XYZW {
-	type Output = XYW;
-	fn dot(self, rhs: Z) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl ScalarProduct<Z> for XYZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<W> for XYZW {
+impl ScalarProduct<W> for XYZW {
 	type Output = Zero;
-	fn dot(self, _rhs: W) -> Self::Output {
+	fn scalar_product(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WX> for XYZW {
+impl ScalarProduct<WX> for XYZW {
 	type Output = Zero;
-	fn dot(self, _rhs: WX) -> Self::Output {
+	fn scalar_product(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WY> for XYZW {
+impl ScalarProduct<WY> for XYZW {
 	type Output = Zero;
-	fn dot(self, _rhs: WY) -> Self::Output {
+	fn scalar_product(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<WZ> for XYZW {
+impl ScalarProduct<WZ> for XYZW {
 	type Output = Zero;
-	fn dot(self, _rhs: WZ) -> Self::Output {
+	fn scalar_product(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<YZ> for XYZW {
-	type Output = WX;
-	fn dot(self, rhs: YZ) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl ScalarProduct<YZ> for XYZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<ZX> for XYZW {
-	type Output = WY;
-	fn dot(self, rhs: ZX) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl ScalarProduct<ZX> for XYZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XY> for XYZW {
-	type Output = WZ;
-	fn dot(self, rhs: XY) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl ScalarProduct<XY> for XYZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<YZW> for XYZW {
+impl ScalarProduct<YZW> for XYZW {
 	type Output = Zero;
-	fn dot(self, _rhs: YZW) -> Self::Output {
+	fn scalar_product(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<ZXW> for XYZW {
+impl ScalarProduct<ZXW> for XYZW {
 	type Output = Zero;
-	fn dot(self, _rhs: ZXW) -> Self::Output {
+	fn scalar_product(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYW> for XYZW {
+impl ScalarProduct<XYW> for XYZW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Dot<XYZ> for XYZW {
-	type Output = W;
-	fn dot(self, rhs: XYZ) -> Self::Output {
-		W(self.0 * rhs.0)
+impl ScalarProduct<XYZ> for XYZW {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Dot<XYZW> for XYZW {
+impl ScalarProduct<XYZW> for XYZW {
 	type Output = Zero;
-	fn dot(self, _rhs: XYZW) -> Self::Output {
+	fn scalar_product(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
 // ---------------------------------------------------------------------
-// impl Wedge for blades:
+// impl Commutator for blades:
 
-impl Wedge<S> for S {
-	type Output = S;
-	fn wedge(self, rhs: S) -> Self::Output {
-		S(self.0 * rhs.0)
+impl Commutator<S> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for S {
-	type Output = X;
-	fn wedge(self, rhs: X) -> Self::Output {
-		X(self.0 * rhs.0)
+impl Commutator<X> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: X) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<Y> for S {
-	type Output = Y;
-	fn wedge(self, rhs: Y) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl Commutator<Y> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: Y) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<Z> for S {
-	type Output = Z;
-	fn wedge(self, rhs: Z) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl Commutator<Z> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: Z) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<W> for S {
-	type Output = W;
-	fn wedge(self, rhs: W) -> Self::Output {
-		W(self.0 * rhs.0)
+impl Commutator<W> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<WX> for S {
-	type Output = WX;
-	fn wedge(self, rhs: WX) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl Commutator<WX> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<WY> for S {
-	type Output = WY;
-	fn wedge(self, rhs: WY) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl Commutator<WY> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<WZ> for S {
-	type Output = WZ;
-	fn wedge(self, rhs: WZ) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl Commutator<WZ> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<YZ> for S {
-	type Output = YZ;
-	fn wedge(self, rhs: YZ) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl Commutator<YZ> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<ZX> for S {
-	type Output = ZX;
-	fn wedge(self, rhs: ZX) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl Commutator<ZX> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<XY> for S {
-	type Output = XY;
-	fn wedge(self, rhs: XY) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl Commutator<XY> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<YZW> for S {
-	type Output = YZW;
-	fn wedge(self, rhs: YZW) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl Commutator<YZW> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for S {
-	type Output = ZXW;
-	fn wedge(self, rhs: ZXW) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl Commutator<ZXW> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<XYW> for S {
-	type Output = XYW;
-	fn wedge(self, rhs: XYW) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl Commutator<XYW> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for S {
-	type Output = XYZ;
-	fn wedge(self, rhs: XYZ) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl Commutator<XYZ> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<XYZW> for S {
-	type Output = XYZW;
-	fn wedge(self, rhs: XYZW) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl Commutator<XYZW> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<S> for X {
-	type Output = X;
-	fn wedge(self, rhs: S) -> Self::Output {
-		X(self.0 * rhs.0)
+impl Commutator<S> for X {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for X {
+impl Commutator<X> for X {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for X {
+impl Commutator<Y> for X {
 	type Output = XY;
-	fn wedge(self, rhs: Y) -> Self::Output {
+	fn commutator(self, rhs: Y) -> Self::Output {
 		XY(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Z> for X {
+impl Commutator<Z> for X {
 	type Output = ZX;
-	fn wedge(self, rhs: Z) -> Self::Output {
+	fn commutator(self, rhs: Z) -> Self::Output {
 		ZX(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<W> for X {
+impl Commutator<W> for X {
 	type Output = WX;
-	fn wedge(self, rhs: W) -> Self::Output {
+	fn commutator(self, rhs: W) -> Self::Output {
 		WX(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WX> for X {
-	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
-	}
-}
-
-impl Wedge<WY> for X {
-	type Output = XYW;
-	fn wedge(self, rhs: WY) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl Commutator<WX> for X {
+	type Output = W;
+	fn commutator(self, rhs: WX) -> Self::Output {
+		W(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WZ> for X {
-	type Output = ZXW;
-	fn wedge(self, rhs: WZ) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl Commutator<WY> for X {
+	type Output = Zero;
+	fn commutator(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<YZ> for X {
-	type Output = XYZ;
-	fn wedge(self, rhs: YZ) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl Commutator<WZ> for X {
+	type Output = Zero;
+	fn commutator(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<ZX> for X {
+impl Commutator<YZ> for X {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
+	fn commutator(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for X {
-	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
-		Zero {}
+impl Commutator<ZX> for X {
+	type Output = Z;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		Z(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZW> for X {
-	type Output = XYZW;
-	fn wedge(self, rhs: YZW) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl Commutator<XY> for X {
+	type Output = Y;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<ZXW> for X {
-	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
-		Zero {}
+impl Commutator<YZW> for X {
+	type Output = XYZW;
+	fn commutator(self, rhs: YZW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYW> for X {
+impl Commutator<ZXW> for X {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for X {
+impl Commutator<XYW> for X {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZW> for X {
+impl Commutator<XYZ> for X {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for Y {
-	type Output = Y;
-	fn wedge(self, rhs: S) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl Commutator<XYZW> for X {
+	type Output = YZW;
+	fn commutator(self, rhs: XYZW) -> Self::Output {
+		YZW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<X> for Y {
+impl Commutator<S> for Y {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<X> for Y {
 	type Output = XY;
-	fn wedge(self, rhs: X) -> Self::Output {
+	fn commutator(self, rhs: X) -> Self::Output {
 		XY(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Y> for Y {
+impl Commutator<Y> for Y {
 	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
+	fn commutator(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Z> for Y {
+impl Commutator<Z> for Y {
 	type Output = YZ;
-	fn wedge(self, rhs: Z) -> Self::Output {
+	fn commutator(self, rhs: Z) -> Self::Output {
 		YZ(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<W> for Y {
+impl Commutator<W> for Y {
 	type Output = WY;
-	fn wedge(self, rhs: W) -> Self::Output {
+	fn commutator(self, rhs: W) -> Self::Output {
 		WY(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WX> for Y {
-	type Output = XYW;
-	fn wedge(self, rhs: WX) -> Self::Output {
-		XYW(self.0 * rhs.0)
-	}
-}
-
-impl Wedge<WY> for Y {
+impl Commutator<WX> for Y {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for Y {
-	type Output = YZW;
-	fn wedge(self, rhs: WZ) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl Commutator<WY> for Y {
+	type Output = W;
+	fn commutator(self, rhs: WY) -> Self::Output {
+		W(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZ> for Y {
+impl Commutator<WZ> for Y {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
+	fn commutator(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZX> for Y {
-	type Output = XYZ;
-	fn wedge(self, rhs: ZX) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl Commutator<YZ> for Y {
+	type Output = Z;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		Z(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XY> for Y {
+impl Commutator<ZX> for Y {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZW> for Y {
+impl Commutator<XY> for Y {
+	type Output = X;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<YZW> for Y {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for Y {
+impl Commutator<ZXW> for Y {
 	type Output = XYZW;
-	fn wedge(self, rhs: ZXW) -> Self::Output {
+	fn commutator(self, rhs: ZXW) -> Self::Output {
 		XYZW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYW> for Y {
+impl Commutator<XYW> for Y {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for Y {
+impl Commutator<XYZ> for Y {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZW> for Y {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
-		Zero {}
+impl Commutator<XYZW> for Y {
+	type Output = ZXW;
+	fn commutator(self, rhs: XYZW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<S> for Z {
-	type Output = Z;
-	fn wedge(self, rhs: S) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl Commutator<S> for Z {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for Z {
+impl Commutator<X> for Z {
 	type Output = ZX;
-	fn wedge(self, rhs: X) -> Self::Output {
+	fn commutator(self, rhs: X) -> Self::Output {
 		ZX(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Y> for Z {
+impl Commutator<Y> for Z {
 	type Output = YZ;
-	fn wedge(self, rhs: Y) -> Self::Output {
+	fn commutator(self, rhs: Y) -> Self::Output {
 		YZ(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Z> for Z {
+impl Commutator<Z> for Z {
 	type Output = Zero;
-	fn wedge(self, _rhs: Z) -> Self::Output {
+	fn commutator(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for Z {
+impl Commutator<W> for Z {
 	type Output = WZ;
-	fn wedge(self, rhs: W) -> Self::Output {
+	fn commutator(self, rhs: W) -> Self::Output {
 		WZ(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WX> for Z {
-	type Output = ZXW;
-	fn wedge(self, rhs: WX) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
+impl Commutator<WX> for Z {
+	type Output = Zero;
+	fn commutator(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<WY> for Z {
-	type Output = YZW;
-	fn wedge(self, rhs: WY) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl Commutator<WY> for Z {
+	type Output = Zero;
+	fn commutator(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<WZ> for Z {
-	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
-		Zero {}
+impl Commutator<WZ> for Z {
+	type Output = W;
+	fn commutator(self, rhs: WZ) -> Self::Output {
+		W(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZ> for Z {
-	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
-		Zero {}
+impl Commutator<YZ> for Z {
+	type Output = Y;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<ZX> for Z {
-	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
-		Zero {}
+impl Commutator<ZX> for Z {
+	type Output = X;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		X(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XY> for Z {
-	type Output = XYZ;
-	fn wedge(self, rhs: XY) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl Commutator<XY> for Z {
+	type Output = Zero;
+	fn commutator(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<YZW> for Z {
+impl Commutator<YZW> for Z {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for Z {
+impl Commutator<ZXW> for Z {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for Z {
+impl Commutator<XYW> for Z {
 	type Output = XYZW;
-	fn wedge(self, rhs: XYW) -> Self::Output {
+	fn commutator(self, rhs: XYW) -> Self::Output {
 		XYZW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYZ> for Z {
+impl Commutator<XYZ> for Z {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZW> for Z {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
-		Zero {}
+impl Commutator<XYZW> for Z {
+	type Output = XYW;
+	fn commutator(self, rhs: XYZW) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<S> for W {
-	type Output = W;
-	fn wedge(self, rhs: S) -> Self::Output {
-		W(self.0 * rhs.0)
+impl Commutator<S> for W {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for W {
+impl Commutator<X> for W {
 	type Output = WX;
-	fn wedge(self, rhs: X) -> Self::Output {
+	fn commutator(self, rhs: X) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Y> for W {
+impl Commutator<Y> for W {
 	type Output = WY;
-	fn wedge(self, rhs: Y) -> Self::Output {
+	fn commutator(self, rhs: Y) -> Self::Output {
 		WY(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Z> for W {
+impl Commutator<Z> for W {
 	type Output = WZ;
-	fn wedge(self, rhs: Z) -> Self::Output {
+	fn commutator(self, rhs: Z) -> Self::Output {
 		WZ(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<W> for W {
+impl Commutator<W> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for W {
+impl Commutator<WX> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WY> for W {
+impl Commutator<WY> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for W {
+impl Commutator<WZ> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
+	fn commutator(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZ> for W {
-	type Output = YZW;
-	fn wedge(self, rhs: YZ) -> Self::Output {
-		YZW(self.0 * rhs.0)
-	}
-}
-
-impl Wedge<ZX> for W {
-	type Output = ZXW;
-	fn wedge(self, rhs: ZX) -> Self::Output {
-		ZXW(self.0 * rhs.0)
-	}
-}
-
-impl Wedge<XY> for W {
-	type Output = XYW;
-	fn wedge(self, rhs: XY) -> Self::Output {
-		XYW(self.0 * rhs.0)
-	}
-}
-
-impl Wedge<YZW> for W {
+impl Commutator<YZ> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for W {
+impl Commutator<ZX> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for W {
+impl Commutator<XY> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for W {
-	type Output = XYZW;
-	fn wedge(self, rhs: XYZ) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
-	}
-}
-
-impl Wedge<XYZW> for W {
+impl Commutator<YZW> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for WX {
-	type Output = WX;
-	fn wedge(self, rhs: S) -> Self::Output {
-		WX(self.0 * rhs.0)
-	}
-}
-
-impl Wedge<X> for WX {
+impl Commutator<ZXW> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for WX {
-	type Output = XYW;
-	fn wedge(self, rhs: Y) -> Self::Output {
-		XYW(self.0 * rhs.0)
-	}
-}
-
-impl Wedge<Z> for WX {
-	type Output = ZXW;
-	fn wedge(self, rhs: Z) -> Self::Output {
-		ZXW(-self.0 * rhs.0)
-	}
-}
-
-impl Wedge<W> for WX {
+impl Commutator<XYW> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for WX {
-	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
+impl Commutator<XYZ> for W {
+	type Output = XYZW;
+	fn commutator(self, rhs: XYZ) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WY> for WX {
+impl Commutator<XYZW> for W {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for WX {
+impl Commutator<S> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
+	fn commutator(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZ> for WX {
-	type Output = XYZW;
-	fn wedge(self, rhs: YZ) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
-	}
-}
-
-impl Wedge<ZX> for WX {
-	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
-		Zero {}
+impl Commutator<X> for WX {
+	type Output = W;
+	fn commutator(self, rhs: X) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XY> for WX {
+impl Commutator<Y> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZW> for WX {
+impl Commutator<Z> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for WX {
+impl Commutator<W> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for WX {
+impl Commutator<WX> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for WX {
+impl Commutator<WY> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
+	fn commutator(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZW> for WX {
+impl Commutator<WZ> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for WY {
-	type Output = WY;
-	fn wedge(self, rhs: S) -> Self::Output {
-		WY(self.0 * rhs.0)
-	}
-}
-
-impl Wedge<X> for WY {
-	type Output = XYW;
-	fn wedge(self, rhs: X) -> Self::Output {
-		XYW(-self.0 * rhs.0)
+impl Commutator<YZ> for WX {
+	type Output = Zero;
+	fn commutator(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<Y> for WY {
-	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
-		Zero {}
+impl Commutator<ZX> for WX {
+	type Output = WZ;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		WZ(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Z> for WY {
-	type Output = YZW;
-	fn wedge(self, rhs: Z) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl Commutator<XY> for WX {
+	type Output = WY;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		WY(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<W> for WY {
+impl Commutator<YZW> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for WY {
+impl Commutator<ZXW> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WY> for WY {
+impl Commutator<XYW> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for WY {
-	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
-		Zero {}
+impl Commutator<XYZ> for WX {
+	type Output = YZW;
+	fn commutator(self, rhs: XYZ) -> Self::Output {
+		YZW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZ> for WY {
+impl Commutator<XYZW> for WX {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZX> for WY {
-	type Output = XYZW;
-	fn wedge(self, rhs: ZX) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl Commutator<S> for WY {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<XY> for WY {
+impl Commutator<X> for WY {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZW> for WY {
+impl Commutator<Y> for WY {
+	type Output = W;
+	fn commutator(self, rhs: Y) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<Z> for WY {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for WY {
+impl Commutator<W> for WY {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for WY {
+impl Commutator<WX> for WY {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for WY {
+impl Commutator<WY> for WY {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
+	fn commutator(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZW> for WY {
+impl Commutator<WZ> for WY {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for WZ {
+impl Commutator<YZ> for WY {
 	type Output = WZ;
-	fn wedge(self, rhs: S) -> Self::Output {
+	fn commutator(self, rhs: YZ) -> Self::Output {
 		WZ(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<X> for WZ {
-	type Output = ZXW;
-	fn wedge(self, rhs: X) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl Commutator<ZX> for WY {
+	type Output = Zero;
+	fn commutator(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<Y> for WZ {
-	type Output = YZW;
-	fn wedge(self, rhs: Y) -> Self::Output {
-		YZW(-self.0 * rhs.0)
+impl Commutator<XY> for WY {
+	type Output = WX;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		WX(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Z> for WZ {
+impl Commutator<YZW> for WY {
 	type Output = Zero;
-	fn wedge(self, _rhs: Z) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for WZ {
+impl Commutator<ZXW> for WY {
 	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for WZ {
+impl Commutator<XYW> for WY {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WY> for WZ {
+impl Commutator<XYZ> for WY {
+	type Output = ZXW;
+	fn commutator(self, rhs: XYZ) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XYZW> for WY {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for WZ {
+impl Commutator<S> for WZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
+	fn commutator(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZ> for WZ {
+impl Commutator<X> for WZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
+	fn commutator(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZX> for WZ {
+impl Commutator<Y> for WZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
+	fn commutator(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for WZ {
-	type Output = XYZW;
-	fn wedge(self, rhs: XY) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl Commutator<Z> for WZ {
+	type Output = W;
+	fn commutator(self, rhs: Z) -> Self::Output {
+		W(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZW> for WZ {
+impl Commutator<W> for WZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for WZ {
+impl Commutator<WX> for WZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for WZ {
+impl Commutator<WY> for WZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for WZ {
+impl Commutator<WZ> for WZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
+	fn commutator(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZW> for WZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
-		Zero {}
+impl Commutator<YZ> for WZ {
+	type Output = WY;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		WY(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<S> for YZ {
-	type Output = YZ;
-	fn wedge(self, rhs: S) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl Commutator<ZX> for WZ {
+	type Output = WX;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		WX(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<X> for YZ {
-	type Output = XYZ;
-	fn wedge(self, rhs: X) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl Commutator<XY> for WZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<Y> for YZ {
+impl Commutator<YZW> for WZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Z> for YZ {
+impl Commutator<ZXW> for WZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: Z) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for YZ {
-	type Output = YZW;
-	fn wedge(self, rhs: W) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl Commutator<XYW> for WZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<WX> for YZ {
-	type Output = XYZW;
-	fn wedge(self, rhs: WX) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl Commutator<XYZ> for WZ {
+	type Output = XYW;
+	fn commutator(self, rhs: XYZ) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WY> for YZ {
+impl Commutator<XYZW> for WZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for YZ {
+impl Commutator<S> for YZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
+	fn commutator(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZ> for YZ {
+impl Commutator<X> for YZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
+	fn commutator(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZX> for YZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
-		Zero {}
+impl Commutator<Y> for YZ {
+	type Output = Z;
+	fn commutator(self, rhs: Y) -> Self::Output {
+		Z(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XY> for YZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
-		Zero {}
+impl Commutator<Z> for YZ {
+	type Output = Y;
+	fn commutator(self, rhs: Z) -> Self::Output {
+		Y(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZW> for YZ {
+impl Commutator<W> for YZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for YZ {
+impl Commutator<WX> for YZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for YZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
-		Zero {}
+impl Commutator<WY> for YZ {
+	type Output = WZ;
+	fn commutator(self, rhs: WY) -> Self::Output {
+		WZ(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYZ> for YZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
-		Zero {}
+impl Commutator<WZ> for YZ {
+	type Output = WY;
+	fn commutator(self, rhs: WZ) -> Self::Output {
+		WY(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYZW> for YZ {
+impl Commutator<YZ> for YZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for ZX {
+impl Commutator<ZX> for YZ {
+	type Output = XY;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XY> for YZ {
 	type Output = ZX;
-	fn wedge(self, rhs: S) -> Self::Output {
+	fn commutator(self, rhs: XY) -> Self::Output {
 		ZX(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<X> for ZX {
+impl Commutator<YZW> for YZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for ZX {
-	type Output = XYZ;
-	fn wedge(self, rhs: Y) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl Commutator<ZXW> for YZ {
+	type Output = XYW;
+	fn commutator(self, rhs: ZXW) -> Self::Output {
+		XYW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Z> for ZX {
+impl Commutator<XYW> for YZ {
+	type Output = ZXW;
+	fn commutator(self, rhs: XYW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XYZ> for YZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: Z) -> Self::Output {
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for ZX {
-	type Output = ZXW;
-	fn wedge(self, rhs: W) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl Commutator<XYZW> for YZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<WX> for ZX {
+impl Commutator<S> for ZX {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WY> for ZX {
-	type Output = XYZW;
-	fn wedge(self, rhs: WY) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl Commutator<X> for ZX {
+	type Output = Z;
+	fn commutator(self, rhs: X) -> Self::Output {
+		Z(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WZ> for ZX {
+impl Commutator<Y> for ZX {
 	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
+	fn commutator(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZ> for ZX {
-	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
-		Zero {}
+impl Commutator<Z> for ZX {
+	type Output = X;
+	fn commutator(self, rhs: Z) -> Self::Output {
+		X(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<ZX> for ZX {
+impl Commutator<W> for ZX {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for ZX {
-	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
-		Zero {}
+impl Commutator<WX> for ZX {
+	type Output = WZ;
+	fn commutator(self, rhs: WX) -> Self::Output {
+		WZ(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZW> for ZX {
+impl Commutator<WY> for ZX {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for ZX {
-	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
-		Zero {}
+impl Commutator<WZ> for ZX {
+	type Output = WX;
+	fn commutator(self, rhs: WZ) -> Self::Output {
+		WX(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYW> for ZX {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
-		Zero {}
+impl Commutator<YZ> for ZX {
+	type Output = XY;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYZ> for ZX {
+impl Commutator<ZX> for ZX {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
+	fn commutator(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZW> for ZX {
+impl Commutator<XY> for ZX {
+	type Output = YZ;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<YZW> for ZX {
+	type Output = XYW;
+	fn commutator(self, rhs: YZW) -> Self::Output {
+		XYW(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<ZXW> for ZX {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for XY {
-	type Output = XY;
-	fn wedge(self, rhs: S) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl Commutator<XYW> for ZX {
+	type Output = YZW;
+	fn commutator(self, rhs: XYW) -> Self::Output {
+		YZW(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XYZ> for ZX {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for XY {
+impl Commutator<XYZW> for ZX {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for XY {
+impl Commutator<S> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
+	fn commutator(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Z> for XY {
-	type Output = XYZ;
-	fn wedge(self, rhs: Z) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl Commutator<X> for XY {
+	type Output = Y;
+	fn commutator(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<W> for XY {
-	type Output = XYW;
-	fn wedge(self, rhs: W) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl Commutator<Y> for XY {
+	type Output = X;
+	fn commutator(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WX> for XY {
+impl Commutator<Z> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WY> for XY {
+impl Commutator<W> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for XY {
-	type Output = XYZW;
-	fn wedge(self, rhs: WZ) -> Self::Output {
-		XYZW(-self.0 * rhs.0)
+impl Commutator<WX> for XY {
+	type Output = WY;
+	fn commutator(self, rhs: WX) -> Self::Output {
+		WY(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZ> for XY {
-	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
-		Zero {}
+impl Commutator<WY> for XY {
+	type Output = WX;
+	fn commutator(self, rhs: WY) -> Self::Output {
+		WX(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<ZX> for XY {
+impl Commutator<WZ> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
+	fn commutator(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for XY {
-	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
-		Zero {}
+impl Commutator<YZ> for XY {
+	type Output = ZX;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		ZX(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZW> for XY {
-	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
-		Zero {}
+impl Commutator<ZX> for XY {
+	type Output = YZ;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		YZ(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<ZXW> for XY {
+impl Commutator<XY> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for XY {
+impl Commutator<YZW> for XY {
+	type Output = ZXW;
+	fn commutator(self, rhs: YZW) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<ZXW> for XY {
+	type Output = YZW;
+	fn commutator(self, rhs: ZXW) -> Self::Output {
+		YZW(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XYW> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for XY {
+impl Commutator<XYZ> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZW> for XY {
+impl Commutator<XYZW> for XY {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for YZW {
-	type Output = YZW;
-	fn wedge(self, rhs: S) -> Self::Output {
-		YZW(self.0 * rhs.0)
+impl Commutator<S> for YZW {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for YZW {
+impl Commutator<X> for YZW {
 	type Output = XYZW;
-	fn wedge(self, rhs: X) -> Self::Output {
+	fn commutator(self, rhs: X) -> Self::Output {
 		XYZW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Y> for YZW {
+impl Commutator<Y> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
+	fn commutator(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Z> for YZW {
+impl Commutator<Z> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: Z) -> Self::Output {
+	fn commutator(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for YZW {
+impl Commutator<W> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for YZW {
+impl Commutator<WX> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WY> for YZW {
+impl Commutator<WY> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for YZW {
+impl Commutator<WZ> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
+	fn commutator(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZ> for YZW {
+impl Commutator<YZ> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
+	fn commutator(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZX> for YZW {
-	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
-		Zero {}
+impl Commutator<ZX> for YZW {
+	type Output = XYW;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		XYW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XY> for YZW {
-	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
-		Zero {}
+impl Commutator<XY> for YZW {
+	type Output = ZXW;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZW> for YZW {
+impl Commutator<YZW> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for YZW {
+impl Commutator<ZXW> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for YZW {
+impl Commutator<XYW> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for YZW {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
-		Zero {}
+impl Commutator<XYZ> for YZW {
+	type Output = WX;
+	fn commutator(self, rhs: XYZ) -> Self::Output {
+		WX(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYZW> for YZW {
+impl Commutator<XYZW> for YZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for ZXW {
-	type Output = ZXW;
-	fn wedge(self, rhs: S) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl Commutator<S> for ZXW {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for ZXW {
+impl Commutator<X> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for ZXW {
+impl Commutator<Y> for ZXW {
 	type Output = XYZW;
-	fn wedge(self, rhs: Y) -> Self::Output {
+	fn commutator(self, rhs: Y) -> Self::Output {
 		XYZW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Z> for ZXW {
+impl Commutator<Z> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: Z) -> Self::Output {
+	fn commutator(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for ZXW {
+impl Commutator<W> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for ZXW {
+impl Commutator<WX> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WY> for ZXW {
+impl Commutator<WY> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for ZXW {
+impl Commutator<WZ> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
+	fn commutator(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZ> for ZXW {
-	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
-		Zero {}
+impl Commutator<YZ> for ZXW {
+	type Output = XYW;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<ZX> for ZXW {
+impl Commutator<ZX> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
+	fn commutator(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for ZXW {
-	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
-		Zero {}
+impl Commutator<XY> for ZXW {
+	type Output = YZW;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		YZW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZW> for ZXW {
+impl Commutator<YZW> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for ZXW {
+impl Commutator<ZXW> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for ZXW {
+impl Commutator<XYW> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for ZXW {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
-		Zero {}
+impl Commutator<XYZ> for ZXW {
+	type Output = WY;
+	fn commutator(self, rhs: XYZ) -> Self::Output {
+		WY(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYZW> for ZXW {
+impl Commutator<XYZW> for ZXW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for XYW {
-	type Output = XYW;
-	fn wedge(self, rhs: S) -> Self::Output {
-		XYW(self.0 * rhs.0)
+impl Commutator<S> for XYW {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for XYW {
+impl Commutator<X> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for XYW {
+impl Commutator<Y> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
+	fn commutator(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Z> for XYW {
+impl Commutator<Z> for XYW {
 	type Output = XYZW;
-	fn wedge(self, rhs: Z) -> Self::Output {
+	fn commutator(self, rhs: Z) -> Self::Output {
 		XYZW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<W> for XYW {
+impl Commutator<W> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WX> for XYW {
+impl Commutator<WX> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WY> for XYW {
+impl Commutator<WY> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for XYW {
+impl Commutator<WZ> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
+	fn commutator(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZ> for XYW {
-	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
-		Zero {}
+impl Commutator<YZ> for XYW {
+	type Output = ZXW;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<ZX> for XYW {
-	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
-		Zero {}
+impl Commutator<ZX> for XYW {
+	type Output = YZW;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		YZW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XY> for XYW {
+impl Commutator<XY> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZW> for XYW {
+impl Commutator<YZW> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for XYW {
+impl Commutator<ZXW> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for XYW {
+impl Commutator<XYW> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for XYW {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
-		Zero {}
+impl Commutator<XYZ> for XYW {
+	type Output = WZ;
+	fn commutator(self, rhs: XYZ) -> Self::Output {
+		WZ(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYZW> for XYW {
+impl Commutator<XYZW> for XYW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for XYZ {
-	type Output = XYZ;
-	fn wedge(self, rhs: S) -> Self::Output {
-		XYZ(self.0 * rhs.0)
+impl Commutator<S> for XYZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl Wedge<X> for XYZ {
+impl Commutator<X> for XYZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for XYZ {
+impl Commutator<Y> for XYZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
+	fn commutator(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Z> for XYZ {
+impl Commutator<Z> for XYZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: Z) -> Self::Output {
+	fn commutator(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<W> for XYZ {
+impl Commutator<W> for XYZ {
 	type Output = XYZW;
-	fn wedge(self, rhs: W) -> Self::Output {
+	fn commutator(self, rhs: W) -> Self::Output {
 		XYZW(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WX> for XYZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
-	}
-}
-
-impl Wedge<WY> for XYZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
-		Zero {}
+impl Commutator<WX> for XYZ {
+	type Output = YZW;
+	fn commutator(self, rhs: WX) -> Self::Output {
+		YZW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WZ> for XYZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
-		Zero {}
+impl Commutator<WY> for XYZ {
+	type Output = ZXW;
+	fn commutator(self, rhs: WY) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<YZ> for XYZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
-		Zero {}
+impl Commutator<WZ> for XYZ {
+	type Output = XYW;
+	fn commutator(self, rhs: WZ) -> Self::Output {
+		XYW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<ZX> for XYZ {
+impl Commutator<YZ> for XYZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
+	fn commutator(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for XYZ {
+impl Commutator<ZX> for XYZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZW> for XYZ {
+impl Commutator<XY> for XYZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for XYZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
-		Zero {}
+impl Commutator<YZW> for XYZ {
+	type Output = WX;
+	fn commutator(self, rhs: YZW) -> Self::Output {
+		WX(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYW> for XYZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
-		Zero {}
+impl Commutator<ZXW> for XYZ {
+	type Output = WY;
+	fn commutator(self, rhs: ZXW) -> Self::Output {
+		WY(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYZ> for XYZ {
-	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
-		Zero {}
+impl Commutator<XYW> for XYZ {
+	type Output = WZ;
+	fn commutator(self, rhs: XYW) -> Self::Output {
+		WZ(self.0 * rhs.0)
 	}
 }
 
-impl Wedge<XYZW> for XYZ {
+impl Commutator<XYZ> for XYZ {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<S> for XYZW {
-	type Output = XYZW;
-	fn wedge(self, rhs: S) -> Self::Output {
-		XYZW(self.0 * rhs.0)
+impl Commutator<XYZW> for XYZ {
+	type Output = W;
+	fn commutator(self, rhs: XYZW) -> Self::Output {
+		W(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<X> for XYZW {
+impl Commutator<S> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: X) -> Self::Output {
+	fn commutator(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<Y> for XYZW {
-	type Output = Zero;
-	fn wedge(self, _rhs: Y) -> Self::Output {
-		Zero {}
+impl Commutator<X> for XYZW {
+	type Output = YZW;
+	fn commutator(self, rhs: X) -> Self::Output {
+		YZW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<Z> for XYZW {
-	type Output = Zero;
-	fn wedge(self, _rhs: Z) -> Self::Output {
-		Zero {}
+impl Commutator<Y> for XYZW {
+	type Output = ZXW;
+	fn commutator(self, rhs: Y) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<W> for XYZW {
-	type Output = Zero;
-	fn wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl Commutator<Z> for XYZW {
+	type Output = XYW;
+	fn commutator(self, rhs: Z) -> Self::Output {
+		XYW(-self.0 * rhs.0)
 	}
 }
 
-impl Wedge<WX> for XYZW {
+impl Commutator<W> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WX) -> Self::Output {
+	fn commutator(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WY> for XYZW {
+impl Commutator<WX> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WY) -> Self::Output {
+	fn commutator(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<WZ> for XYZW {
+impl Commutator<WY> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: WZ) -> Self::Output {
+	fn commutator(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZ> for XYZW {
+impl Commutator<WZ> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZ) -> Self::Output {
+	fn commutator(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZX> for XYZW {
+impl Commutator<YZ> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZX) -> Self::Output {
+	fn commutator(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XY> for XYZW {
+impl Commutator<ZX> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XY) -> Self::Output {
+	fn commutator(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<YZW> for XYZW {
+impl Commutator<XY> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: YZW) -> Self::Output {
+	fn commutator(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<ZXW> for XYZW {
+impl Commutator<YZW> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: ZXW) -> Self::Output {
+	fn commutator(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYW> for XYZW {
+impl Commutator<ZXW> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYW) -> Self::Output {
+	fn commutator(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZ> for XYZW {
+impl Commutator<XYW> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZ) -> Self::Output {
+	fn commutator(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl Wedge<XYZW> for XYZW {
+impl Commutator<XYZ> for XYZW {
+	type Output = W;
+	fn commutator(self, rhs: XYZ) -> Self::Output {
+		W(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XYZW> for XYZW {
 	type Output = Zero;
-	fn wedge(self, _rhs: XYZW) -> Self::Output {
+	fn commutator(self, _rhs: XYZW) -> Self::Output {
 		Zero {}
 	}
 }
 
 // ---------------------------------------------------------------------
-// impl AntiWedge for blades:
+// impl AntiDot for blades:
 
-impl AntiWedge<S> for S {
+impl AntiDot<S> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for S {
+impl AntiDot<X> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for S {
+impl AntiDot<Y> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for S {
+impl AntiDot<Z> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for S {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl AntiDot<W> for S {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for S {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
+impl AntiDot<WX> for S {
+	type Output = YZ;
+	fn anti_dot(self, rhs: WX) -> Self::Output {
+		YZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WY> for S {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
-		Zero {}
+impl AntiDot<WY> for S {
+	type Output = ZX;
+	fn anti_dot(self, rhs: WY) -> Self::Output {
+		ZX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WZ> for S {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
-		Zero {}
+impl AntiDot<WZ> for S {
+	type Output = XY;
+	fn anti_dot(self, rhs: WZ) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YZ> for S {
+impl AntiDot<YZ> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for S {
+impl AntiDot<ZX> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for S {
+impl AntiDot<XY> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for S {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
-		Zero {}
+impl AntiDot<YZW> for S {
+	type Output = X;
+	fn anti_dot(self, rhs: YZW) -> Self::Output {
+		X(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZXW> for S {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
-		Zero {}
+impl AntiDot<ZXW> for S {
+	type Output = Y;
+	fn anti_dot(self, rhs: ZXW) -> Self::Output {
+		Y(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for S {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
-		Zero {}
+impl AntiDot<XYW> for S {
+	type Output = Z;
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
+		Z(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYZ> for S {
+impl AntiDot<XYZ> for S {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for S {
+impl AntiDot<XYZW> for S {
 	type Output = S;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for X {
+impl AntiDot<S> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for X {
+impl AntiDot<X> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for X {
+impl AntiDot<Y> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for X {
+impl AntiDot<Z> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for X {
+impl AntiDot<W> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for X {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
+impl AntiDot<WX> for X {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: WX) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WY> for X {
+impl AntiDot<WY> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+	fn anti_dot(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WZ> for X {
+impl AntiDot<WZ> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+	fn anti_dot(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for X {
+impl AntiDot<YZ> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for X {
+impl AntiDot<ZX> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for X {
+impl AntiDot<XY> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for X {
-	type Output = S;
-	fn anti_wedge(self, rhs: YZW) -> Self::Output {
-		S(self.0 * rhs.0)
+impl AntiDot<YZW> for X {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<ZXW> for X {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
-		Zero {}
+impl AntiDot<ZXW> for X {
+	type Output = XY;
+	fn anti_dot(self, rhs: ZXW) -> Self::Output {
+		XY(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for X {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
-		Zero {}
+impl AntiDot<XYW> for X {
+	type Output = ZX;
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
+		ZX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYZ> for X {
+impl AntiDot<XYZ> for X {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for X {
+impl AntiDot<XYZW> for X {
 	type Output = X;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		X(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for Y {
+impl AntiDot<S> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for Y {
+impl AntiDot<X> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for Y {
+impl AntiDot<Y> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for Y {
+impl AntiDot<Z> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for Y {
+impl AntiDot<W> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for Y {
+impl AntiDot<WX> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WY> for Y {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
-		Zero {}
+impl AntiDot<WY> for Y {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: WY) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WZ> for Y {
+impl AntiDot<WZ> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+	fn anti_dot(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for Y {
+impl AntiDot<YZ> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for Y {
+impl AntiDot<ZX> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for Y {
+impl AntiDot<XY> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for Y {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
-		Zero {}
+impl AntiDot<YZW> for Y {
+	type Output = XY;
+	fn anti_dot(self, rhs: YZW) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZXW> for Y {
-	type Output = S;
-	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
-		S(self.0 * rhs.0)
+impl AntiDot<ZXW> for Y {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for Y {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
-		Zero {}
+impl AntiDot<XYW> for Y {
+	type Output = YZ;
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
+		YZ(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYZ> for Y {
+impl AntiDot<XYZ> for Y {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for Y {
+impl AntiDot<XYZW> for Y {
 	type Output = Y;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		Y(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for Z {
+impl AntiDot<S> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for Z {
+impl AntiDot<X> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for Z {
+impl AntiDot<Y> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for Z {
+impl AntiDot<Z> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for Z {
+impl AntiDot<W> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for Z {
+impl AntiDot<WX> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WY> for Z {
+impl AntiDot<WY> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+	fn anti_dot(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WZ> for Z {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
-		Zero {}
+impl AntiDot<WZ> for Z {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: WZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YZ> for Z {
+impl AntiDot<YZ> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for Z {
+impl AntiDot<ZX> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for Z {
+impl AntiDot<XY> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for Z {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
-		Zero {}
+impl AntiDot<YZW> for Z {
+	type Output = ZX;
+	fn anti_dot(self, rhs: YZW) -> Self::Output {
+		ZX(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZXW> for Z {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
-		Zero {}
+impl AntiDot<ZXW> for Z {
+	type Output = YZ;
+	fn anti_dot(self, rhs: ZXW) -> Self::Output {
+		YZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for Z {
-	type Output = S;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
-		S(self.0 * rhs.0)
+impl AntiDot<XYW> for Z {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYZ> for Z {
+impl AntiDot<XYZ> for Z {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for Z {
+impl AntiDot<XYZW> for Z {
 	type Output = Z;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		Z(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
-		Zero {}
+impl AntiDot<S> for W {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<X> for W {
+impl AntiDot<X> for W {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for W {
+impl AntiDot<Y> for W {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for W {
+impl AntiDot<Z> for W {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl AntiDot<W> for W {
+	type Output = XYZW;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
+impl AntiDot<WX> for W {
+	type Output = YZW;
+	fn anti_dot(self, rhs: WX) -> Self::Output {
+		YZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WY> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
-		Zero {}
+impl AntiDot<WY> for W {
+	type Output = ZXW;
+	fn anti_dot(self, rhs: WY) -> Self::Output {
+		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WZ> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
-		Zero {}
+impl AntiDot<WZ> for W {
+	type Output = XYW;
+	fn anti_dot(self, rhs: WZ) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YZ> for W {
+impl AntiDot<YZ> for W {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for W {
+impl AntiDot<ZX> for W {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for W {
+impl AntiDot<XY> for W {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
-		Zero {}
+impl AntiDot<YZW> for W {
+	type Output = WX;
+	fn anti_dot(self, rhs: YZW) -> Self::Output {
+		WX(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZXW> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
-		Zero {}
+impl AntiDot<ZXW> for W {
+	type Output = WY;
+	fn anti_dot(self, rhs: ZXW) -> Self::Output {
+		WY(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for W {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
-		Zero {}
+impl AntiDot<XYW> for W {
+	type Output = WZ;
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
+		WZ(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYZ> for W {
-	type Output = S;
-	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl AntiDot<XYZ> for W {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for W {
+impl AntiDot<XYZW> for W {
 	type Output = W;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for WX {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
-		Zero {}
+impl AntiDot<S> for WX {
+	type Output = YZ;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		YZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<X> for WX {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
-		Zero {}
+impl AntiDot<X> for WX {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: X) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<Y> for WX {
+impl AntiDot<Y> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for WX {
+impl AntiDot<Z> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for WX {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl AntiDot<W> for WX {
+	type Output = YZW;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		YZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for WX {
+impl AntiDot<WX> for WX {
+	type Output = XYZW;
+	fn anti_dot(self, rhs: WX) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<WY> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+	fn anti_dot(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WY> for WX {
+impl AntiDot<WZ> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+	fn anti_dot(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WZ> for WX {
+impl AntiDot<YZ> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for WX {
-	type Output = S;
-	fn anti_wedge(self, rhs: YZ) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl AntiDot<ZX> for WX {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for WX {
+impl AntiDot<XY> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for WX {
+impl AntiDot<YZW> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for WX {
-	type Output = W;
-	fn anti_wedge(self, rhs: YZW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl AntiDot<ZXW> for WX {
+	type Output = XYW;
+	fn anti_dot(self, rhs: ZXW) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZXW> for WX {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
-		Zero {}
+impl AntiDot<XYW> for WX {
+	type Output = ZXW;
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for WX {
+impl AntiDot<XYZ> for WX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZ> for WX {
-	type Output = X;
-	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl AntiDot<XYZW> for WX {
+	type Output = WX;
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
+		WX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYZW> for WX {
-	type Output = WX;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl AntiDot<S> for WY {
+	type Output = ZX;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		ZX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for WY {
+impl AntiDot<X> for WY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for WY {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
-		Zero {}
+impl AntiDot<Y> for WY {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: Y) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<Y> for WY {
+impl AntiDot<Z> for WY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for WY {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
-		Zero {}
+impl AntiDot<W> for WY {
+	type Output = ZXW;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<W> for WY {
+impl AntiDot<WX> for WY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for WY {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
+impl AntiDot<WY> for WY {
+	type Output = XYZW;
+	fn anti_dot(self, rhs: WY) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WY> for WY {
+impl AntiDot<WZ> for WY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+	fn anti_dot(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WZ> for WY {
+impl AntiDot<YZ> for WY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for WY {
+impl AntiDot<ZX> for WY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for WY {
-	type Output = S;
-	fn anti_wedge(self, rhs: ZX) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl AntiDot<XY> for WY {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for WY {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
-		Zero {}
+impl AntiDot<YZW> for WY {
+	type Output = XYW;
+	fn anti_dot(self, rhs: YZW) -> Self::Output {
+		XYW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YZW> for WY {
+impl AntiDot<ZXW> for WY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
+	fn anti_dot(self, _rhs: ZXW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZXW> for WY {
-	type Output = W;
-	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl AntiDot<XYW> for WY {
+	type Output = YZW;
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
+		YZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for WY {
+impl AntiDot<XYZ> for WY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZ> for WY {
-	type Output = Y;
-	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl AntiDot<XYZW> for WY {
+	type Output = WY;
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
+		WY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYZW> for WY {
-	type Output = WY;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl AntiDot<S> for WZ {
+	type Output = XY;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for WZ {
+impl AntiDot<X> for WZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for WZ {
+impl AntiDot<Y> for WZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for WZ {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
-		Zero {}
+impl AntiDot<Z> for WZ {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: Z) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<Z> for WZ {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
-		Zero {}
+impl AntiDot<W> for WZ {
+	type Output = XYW;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<W> for WZ {
+impl AntiDot<WX> for WZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for WZ {
+impl AntiDot<WY> for WZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+	fn anti_dot(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WY> for WZ {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
-		Zero {}
+impl AntiDot<WZ> for WZ {
+	type Output = XYZW;
+	fn anti_dot(self, rhs: WZ) -> Self::Output {
+		XYZW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WZ> for WZ {
+impl AntiDot<YZ> for WZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for WZ {
+impl AntiDot<ZX> for WZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for WZ {
+impl AntiDot<XY> for WZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for WZ {
-	type Output = S;
-	fn anti_wedge(self, rhs: XY) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl AntiDot<YZW> for WZ {
+	type Output = ZXW;
+	fn anti_dot(self, rhs: YZW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YZW> for WZ {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
-		Zero {}
+impl AntiDot<ZXW> for WZ {
+	type Output = YZW;
+	fn anti_dot(self, rhs: ZXW) -> Self::Output {
+		YZW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZXW> for WZ {
+impl AntiDot<XYW> for WZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
+	fn anti_dot(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for WZ {
-	type Output = W;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
-		W(-self.0 * rhs.0)
-	}
-}
-
-impl AntiWedge<XYZ> for WZ {
-	type Output = Z;
-	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl AntiDot<XYZ> for WZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for WZ {
+impl AntiDot<XYZW> for WZ {
 	type Output = WZ;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		WZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for YZ {
+impl AntiDot<S> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for YZ {
+impl AntiDot<X> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for YZ {
+impl AntiDot<Y> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for YZ {
+impl AntiDot<Z> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for YZ {
+impl AntiDot<W> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for YZ {
-	type Output = S;
-	fn anti_wedge(self, rhs: WX) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl AntiDot<WX> for YZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<WY> for YZ {
+impl AntiDot<WY> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+	fn anti_dot(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WZ> for YZ {
+impl AntiDot<WZ> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+	fn anti_dot(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for YZ {
+impl AntiDot<YZ> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for YZ {
+impl AntiDot<ZX> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for YZ {
+impl AntiDot<XY> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for YZ {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
-		Zero {}
+impl AntiDot<YZW> for YZ {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: YZW) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZXW> for YZ {
-	type Output = Z;
-	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl AntiDot<ZXW> for YZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for YZ {
-	type Output = Y;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl AntiDot<XYW> for YZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYZ> for YZ {
+impl AntiDot<XYZ> for YZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for YZ {
+impl AntiDot<XYZW> for YZ {
 	type Output = YZ;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		YZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for ZX {
+impl AntiDot<S> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for ZX {
+impl AntiDot<X> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for ZX {
+impl AntiDot<Y> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for ZX {
+impl AntiDot<Z> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for ZX {
+impl AntiDot<W> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for ZX {
+impl AntiDot<WX> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WY> for ZX {
-	type Output = S;
-	fn anti_wedge(self, rhs: WY) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl AntiDot<WY> for ZX {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<WZ> for ZX {
+impl AntiDot<WZ> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+	fn anti_dot(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for ZX {
+impl AntiDot<YZ> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for ZX {
+impl AntiDot<ZX> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for ZX {
+impl AntiDot<XY> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for ZX {
-	type Output = Z;
-	fn anti_wedge(self, rhs: YZW) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl AntiDot<YZW> for ZX {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<ZXW> for ZX {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
-		Zero {}
+impl AntiDot<ZXW> for ZX {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: ZXW) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for ZX {
-	type Output = X;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
-		X(self.0 * rhs.0)
+impl AntiDot<XYW> for ZX {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYZ> for ZX {
+impl AntiDot<XYZ> for ZX {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for ZX {
+impl AntiDot<XYZW> for ZX {
 	type Output = ZX;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		ZX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for XY {
+impl AntiDot<S> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for XY {
+impl AntiDot<X> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for XY {
+impl AntiDot<Y> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for XY {
+impl AntiDot<Z> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for XY {
+impl AntiDot<W> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
+	fn anti_dot(self, _rhs: W) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for XY {
+impl AntiDot<WX> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WY> for XY {
+impl AntiDot<WY> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+	fn anti_dot(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WZ> for XY {
-	type Output = S;
-	fn anti_wedge(self, rhs: WZ) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl AntiDot<WZ> for XY {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for XY {
+impl AntiDot<YZ> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for XY {
+impl AntiDot<ZX> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for XY {
+impl AntiDot<XY> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for XY {
-	type Output = Y;
-	fn anti_wedge(self, rhs: YZW) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl AntiDot<YZW> for XY {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<ZXW> for XY {
-	type Output = X;
-	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl AntiDot<ZXW> for XY {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for XY {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
-		Zero {}
+impl AntiDot<XYW> for XY {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
+		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYZ> for XY {
+impl AntiDot<XYZ> for XY {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for XY {
+impl AntiDot<XYZW> for XY {
 	type Output = XY;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for YZW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
-		Zero {}
-	}
-}
-
-impl AntiWedge<X> for YZW {
-	type Output = S;
-	fn anti_wedge(self, rhs: X) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl AntiDot<S> for YZW {
+	type Output = X;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		X(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<Y> for YZW {
+impl AntiDot<X> for YZW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for YZW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
-		Zero {}
+impl AntiDot<Y> for YZW {
+	type Output = XY;
+	fn anti_dot(self, rhs: Y) -> Self::Output {
+		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<W> for YZW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl AntiDot<Z> for YZW {
+	type Output = ZX;
+	fn anti_dot(self, rhs: Z) -> Self::Output {
+		ZX(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for YZW {
-	type Output = W;
-	fn anti_wedge(self, rhs: WX) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl AntiDot<W> for YZW {
+	type Output = WX;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		WX(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WY> for YZW {
+impl AntiDot<WX> for YZW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WZ> for YZW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
-		Zero {}
+impl AntiDot<WY> for YZW {
+	type Output = XYW;
+	fn anti_dot(self, rhs: WY) -> Self::Output {
+		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YZ> for YZW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
-		Zero {}
+impl AntiDot<WZ> for YZW {
+	type Output = ZXW;
+	fn anti_dot(self, rhs: WZ) -> Self::Output {
+		ZXW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZX> for YZW {
-	type Output = Z;
-	fn anti_wedge(self, rhs: ZX) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl AntiDot<YZ> for YZW {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: YZ) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XY> for YZW {
-	type Output = Y;
-	fn anti_wedge(self, rhs: XY) -> Self::Output {
-		Y(self.0 * rhs.0)
+impl AntiDot<ZX> for YZW {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for YZW {
+impl AntiDot<XY> for YZW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZW) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZXW> for YZW {
-	type Output = WZ;
-	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
-		WZ(-self.0 * rhs.0)
+impl AntiDot<YZW> for YZW {
+	type Output = XYZW;
+	fn anti_dot(self, rhs: YZW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for YZW {
-	type Output = WY;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
-		WY(self.0 * rhs.0)
+impl AntiDot<ZXW> for YZW {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYZ> for YZW {
-	type Output = YZ;
-	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
-		YZ(-self.0 * rhs.0)
+impl AntiDot<XYW> for YZW {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<XYZ> for YZW {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for YZW {
+impl AntiDot<XYZW> for YZW {
 	type Output = YZW;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		YZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for ZXW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
-		Zero {}
+impl AntiDot<S> for ZXW {
+	type Output = Y;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		Y(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<X> for ZXW {
+impl AntiDot<X> for ZXW {
+	type Output = XY;
+	fn anti_dot(self, rhs: X) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Y> for ZXW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for ZXW {
-	type Output = S;
-	fn anti_wedge(self, rhs: Y) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl AntiDot<Z> for ZXW {
+	type Output = YZ;
+	fn anti_dot(self, rhs: Z) -> Self::Output {
+		YZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<Z> for ZXW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
-		Zero {}
+impl AntiDot<W> for ZXW {
+	type Output = WY;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		WY(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<W> for ZXW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl AntiDot<WX> for ZXW {
+	type Output = XYW;
+	fn anti_dot(self, rhs: WX) -> Self::Output {
+		XYW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for ZXW {
+impl AntiDot<WY> for ZXW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
+	fn anti_dot(self, _rhs: WY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WY> for ZXW {
-	type Output = W;
-	fn anti_wedge(self, rhs: WY) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl AntiDot<WZ> for ZXW {
+	type Output = YZW;
+	fn anti_dot(self, rhs: WZ) -> Self::Output {
+		YZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WZ> for ZXW {
+impl AntiDot<YZ> for ZXW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for ZXW {
-	type Output = Z;
-	fn anti_wedge(self, rhs: YZ) -> Self::Output {
-		Z(self.0 * rhs.0)
+impl AntiDot<ZX> for ZXW {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: ZX) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZX> for ZXW {
+impl AntiDot<XY> for ZXW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for ZXW {
-	type Output = X;
-	fn anti_wedge(self, rhs: XY) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl AntiDot<YZW> for ZXW {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for ZXW {
-	type Output = WZ;
-	fn anti_wedge(self, rhs: YZW) -> Self::Output {
-		WZ(self.0 * rhs.0)
+impl AntiDot<ZXW> for ZXW {
+	type Output = XYZW;
+	fn anti_dot(self, rhs: ZXW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZXW> for ZXW {
+impl AntiDot<XYW> for ZXW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZXW) -> Self::Output {
+	fn anti_dot(self, _rhs: XYW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for ZXW {
-	type Output = WX;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
-		WX(-self.0 * rhs.0)
+impl AntiDot<XYZ> for ZXW {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYZ> for ZXW {
-	type Output = ZX;
-	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
-		ZX(-self.0 * rhs.0)
+impl AntiDot<XYZW> for ZXW {
+	type Output = ZXW;
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
+		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYZW> for ZXW {
-	type Output = ZXW;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
-		ZXW(self.0 * rhs.0)
+impl AntiDot<S> for XYW {
+	type Output = Z;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		Z(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for XYW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
-		Zero {}
+impl AntiDot<X> for XYW {
+	type Output = ZX;
+	fn anti_dot(self, rhs: X) -> Self::Output {
+		ZX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<X> for XYW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
-		Zero {}
+impl AntiDot<Y> for XYW {
+	type Output = YZ;
+	fn anti_dot(self, rhs: Y) -> Self::Output {
+		YZ(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<Y> for XYW {
+impl AntiDot<Z> for XYW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for XYW {
-	type Output = S;
-	fn anti_wedge(self, rhs: Z) -> Self::Output {
-		S(-self.0 * rhs.0)
+impl AntiDot<W> for XYW {
+	type Output = WZ;
+	fn anti_dot(self, rhs: W) -> Self::Output {
+		WZ(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<W> for XYW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: W) -> Self::Output {
-		Zero {}
+impl AntiDot<WX> for XYW {
+	type Output = ZXW;
+	fn anti_dot(self, rhs: WX) -> Self::Output {
+		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for XYW {
-	type Output = Zero;
-	fn anti_wedge(self, _rhs: WX) -> Self::Output {
-		Zero {}
+impl AntiDot<WY> for XYW {
+	type Output = YZW;
+	fn anti_dot(self, rhs: WY) -> Self::Output {
+		YZW(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WY> for XYW {
+impl AntiDot<WZ> for XYW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: WY) -> Self::Output {
+	fn anti_dot(self, _rhs: WZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<WZ> for XYW {
-	type Output = W;
-	fn anti_wedge(self, rhs: WZ) -> Self::Output {
-		W(-self.0 * rhs.0)
+impl AntiDot<YZ> for XYW {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for XYW {
-	type Output = Y;
-	fn anti_wedge(self, rhs: YZ) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl AntiDot<ZX> for XYW {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for XYW {
-	type Output = X;
-	fn anti_wedge(self, rhs: ZX) -> Self::Output {
-		X(self.0 * rhs.0)
+impl AntiDot<XY> for XYW {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: XY) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XY> for XYW {
+impl AntiDot<YZW> for XYW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: YZW) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for XYW {
-	type Output = WY;
-	fn anti_wedge(self, rhs: YZW) -> Self::Output {
-		WY(-self.0 * rhs.0)
+impl AntiDot<ZXW> for XYW {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<ZXW> for XYW {
-	type Output = WX;
-	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
-		WX(self.0 * rhs.0)
+impl AntiDot<XYW> for XYW {
+	type Output = XYZW;
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
+		XYZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for XYW {
+impl AntiDot<XYZ> for XYW {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYW) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZ> for XYW {
-	type Output = XY;
-	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
-		XY(-self.0 * rhs.0)
-	}
-}
-
-impl AntiWedge<XYZW> for XYW {
+impl AntiDot<XYZW> for XYW {
 	type Output = XYW;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for XYZ {
+impl AntiDot<S> for XYZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: S) -> Self::Output {
+	fn anti_dot(self, _rhs: S) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<X> for XYZ {
+impl AntiDot<X> for XYZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: X) -> Self::Output {
+	fn anti_dot(self, _rhs: X) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Y> for XYZ {
+impl AntiDot<Y> for XYZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<Z> for XYZ {
+impl AntiDot<Z> for XYZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<W> for XYZ {
-	type Output = S;
-	fn anti_wedge(self, rhs: W) -> Self::Output {
-		S(self.0 * rhs.0)
+impl AntiDot<W> for XYZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: W) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<WX> for XYZ {
-	type Output = X;
-	fn anti_wedge(self, rhs: WX) -> Self::Output {
-		X(-self.0 * rhs.0)
+impl AntiDot<WX> for XYZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: WX) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<WY> for XYZ {
-	type Output = Y;
-	fn anti_wedge(self, rhs: WY) -> Self::Output {
-		Y(-self.0 * rhs.0)
+impl AntiDot<WY> for XYZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: WY) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<WZ> for XYZ {
-	type Output = Z;
-	fn anti_wedge(self, rhs: WZ) -> Self::Output {
-		Z(-self.0 * rhs.0)
+impl AntiDot<WZ> for XYZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: WZ) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<YZ> for XYZ {
+impl AntiDot<YZ> for XYZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<ZX> for XYZ {
+impl AntiDot<ZX> for XYZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XY> for XYZ {
+impl AntiDot<XY> for XYZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<YZW> for XYZ {
-	type Output = YZ;
-	fn anti_wedge(self, rhs: YZW) -> Self::Output {
-		YZ(self.0 * rhs.0)
+impl AntiDot<YZW> for XYZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YZW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<ZXW> for XYZ {
-	type Output = ZX;
-	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
-		ZX(self.0 * rhs.0)
+impl AntiDot<ZXW> for XYZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZXW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYW> for XYZ {
-	type Output = XY;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
-		XY(self.0 * rhs.0)
+impl AntiDot<XYW> for XYZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XYW) -> Self::Output {
+		Zero {}
 	}
 }
 
-impl AntiWedge<XYZ> for XYZ {
+impl AntiDot<XYZ> for XYZ {
 	type Output = Zero;
-	fn anti_wedge(self, _rhs: XYZ) -> Self::Output {
+	fn anti_dot(self, _rhs: XYZ) -> Self::Output {
 		Zero {}
 	}
 }
 
-impl AntiWedge<XYZW> for XYZ {
+impl AntiDot<XYZW> for XYZ {
 	type Output = XYZ;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<S> for XYZW {
+impl AntiDot<S> for XYZW {
 	type Output = S;
-	fn anti_wedge(self, rhs: S) -> Self::Output {
+	fn anti_dot(self, rhs: S) -> Self::Output {
 		S(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<X> for XYZW {
+impl AntiDot<X> for XYZW {
 	type Output = X;
-	fn anti_wedge(self, rhs: X) -> Self::Output {
+	fn anti_dot(self, rhs: X) -> Self::Output {
 		X(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<Y> for XYZW {
+impl AntiDot<Y> for XYZW {
 	type Output = Y;
-	fn anti_wedge(self, rhs: Y) -> Self::Output {
+	fn anti_dot(self, rhs: Y) -> Self::Output {
 		Y(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<Z> for XYZW {
+impl AntiDot<Z> for XYZW {
 	type Output = Z;
-	fn anti_wedge(self, rhs: Z) -> Self::Output {
+	fn anti_dot(self, rhs: Z) -> Self::Output {
 		Z(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<W> for XYZW {
+impl AntiDot<W> for XYZW {
 	type Output = W;
-	fn anti_wedge(self, rhs: W) -> Self::Output {
+	fn anti_dot(self, rhs: W) -> Self::Output {
 		W(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WX> for XYZW {
+impl AntiDot<WX> for XYZW {
 	type Output = WX;
-	fn anti_wedge(self, rhs: WX) -> Self::Output {
+	fn anti_dot(self, rhs: WX) -> Self::Output {
 		WX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WY> for XYZW {
+impl AntiDot<WY> for XYZW {
 	type Output = WY;
-	fn anti_wedge(self, rhs: WY) -> Self::Output {
+	fn anti_dot(self, rhs: WY) -> Self::Output {
 		WY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<WZ> for XYZW {
+impl AntiDot<WZ> for XYZW {
 	type Output = WZ;
-	fn anti_wedge(self, rhs: WZ) -> Self::Output {
+	fn anti_dot(self, rhs: WZ) -> Self::Output {
 		WZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YZ> for XYZW {
+impl AntiDot<YZ> for XYZW {
 	type Output = YZ;
-	fn anti_wedge(self, rhs: YZ) -> Self::Output {
+	fn anti_dot(self, rhs: YZ) -> Self::Output {
 		YZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZX> for XYZW {
+impl AntiDot<ZX> for XYZW {
 	type Output = ZX;
-	fn anti_wedge(self, rhs: ZX) -> Self::Output {
+	fn anti_dot(self, rhs: ZX) -> Self::Output {
 		ZX(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XY> for XYZW {
+impl AntiDot<XY> for XYZW {
 	type Output = XY;
-	fn anti_wedge(self, rhs: XY) -> Self::Output {
+	fn anti_dot(self, rhs: XY) -> Self::Output {
 		XY(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<YZW> for XYZW {
+impl AntiDot<YZW> for XYZW {
 	type Output = YZW;
-	fn anti_wedge(self, rhs: YZW) -> Self::Output {
+	fn anti_dot(self, rhs: YZW) -> Self::Output {
 		YZW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<ZXW> for XYZW {
+impl AntiDot<ZXW> for XYZW {
 	type Output = ZXW;
-	fn anti_wedge(self, rhs: ZXW) -> Self::Output {
+	fn anti_dot(self, rhs: ZXW) -> Self::Output {
 		ZXW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYW> for XYZW {
+impl AntiDot<XYW> for XYZW {
 	type Output = XYW;
-	fn anti_wedge(self, rhs: XYW) -> Self::Output {
+	fn anti_dot(self, rhs: XYW) -> Self::Output {
 		XYW(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYZ> for XYZW {
+impl AntiDot<XYZ> for XYZW {
 	type Output = XYZ;
-	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+	fn anti_dot(self, rhs: XYZ) -> Self::Output {
 		XYZ(self.0 * rhs.0)
 	}
 }
 
-impl AntiWedge<XYZW> for XYZW {
+impl AntiDot<XYZW> for XYZW {
 	type Output = XYZW;
-	fn anti_wedge(self, rhs: XYZW) -> Self::Output {
+	fn anti_dot(self, rhs: XYZW) -> Self::Output {
 		XYZW(self.0 * rhs.0)
 	}
 }
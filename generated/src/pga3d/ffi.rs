@@ -0,0 +1,4798 @@
+//! The C ABI of this algebra, declared in `pga3d.h`.
+
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_rcompl(value: Vec3) -> Plane {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_lcompl(value: Vec3) -> Plane {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_rev(value: Vec3) -> Vec3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_arev(value: Vec3) -> Vec3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_involute(value: Vec3) -> Vec3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_conjugate(value: Vec3) -> Vec3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dual(value: Vec3) -> Plane {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_bulk(value: Vec3) -> Vec3 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_bulk_dual(value: Vec3) -> Plane {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_geometric_vec3(lhs: Vec3, rhs: Vec3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dot_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_wedge_vec3(lhs: Vec3, rhs: Vec3) -> Moment3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_left_contraction_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_right_contraction_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_scalar_product_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_commutator_vec3(lhs: Vec3, rhs: Vec3) -> Moment3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_geometric_vec4(lhs: Vec3, rhs: Vec4) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_geometric_vec4(lhs: Vec3, rhs: Vec4) -> Moment3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dot_vec4(lhs: Vec3, rhs: Vec4) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_wedge_vec4(lhs: Vec3, rhs: Vec4) -> Line3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_left_contraction_vec4(lhs: Vec3, rhs: Vec4) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_right_contraction_vec4(lhs: Vec3, rhs: Vec4) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_scalar_product_vec4(lhs: Vec3, rhs: Vec4) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_commutator_vec4(lhs: Vec3, rhs: Vec4) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_geometric_point3(lhs: Vec3, rhs: Point3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_geometric_point3(lhs: Vec3, rhs: Point3) -> Moment3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dot_point3(lhs: Vec3, rhs: Point3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_wedge_point3(lhs: Vec3, rhs: Point3) -> Line3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_left_contraction_point3(lhs: Vec3, rhs: Point3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_right_contraction_point3(lhs: Vec3, rhs: Point3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_scalar_product_point3(lhs: Vec3, rhs: Point3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_commutator_point3(lhs: Vec3, rhs: Point3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_geometric_moment3(lhs: Vec3, rhs: Moment3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dot_moment3(lhs: Vec3, rhs: Moment3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_wedge_moment3(lhs: Vec3, rhs: Moment3) -> XYZ {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_left_contraction_moment3(lhs: Vec3, rhs: Moment3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_commutator_moment3(lhs: Vec3, rhs: Moment3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_geometric_line3(lhs: Vec3, rhs: Line3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_geometric_line3(lhs: Vec3, rhs: Line3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dot_line3(lhs: Vec3, rhs: Line3) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_wedge_line3(lhs: Vec3, rhs: Line3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_left_contraction_line3(lhs: Vec3, rhs: Line3) -> Vec4 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_commutator_line3(lhs: Vec3, rhs: Line3) -> Vec4 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_dot_line3(lhs: Vec3, rhs: Line3) -> XYZ {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_geometric_plane(lhs: Vec3, rhs: Plane) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_geometric_plane(lhs: Vec3, rhs: Plane) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dot_plane(lhs: Vec3, rhs: Plane) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_wedge_plane(lhs: Vec3, rhs: Plane) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_wedge_plane(lhs: Vec3, rhs: Plane) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_left_contraction_plane(lhs: Vec3, rhs: Plane) -> Line3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_commutator_plane(lhs: Vec3, rhs: Plane) -> XYZW {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_dot_plane(lhs: Vec3, rhs: Plane) -> Moment3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_geometric_translator3(lhs: Vec3, rhs: Translator3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_geometric_translator3(lhs: Vec3, rhs: Translator3) -> Vec3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dot_translator3(lhs: Vec3, rhs: Translator3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_wedge_translator3(lhs: Vec3, rhs: Translator3) -> XYZ {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_wedge_translator3(lhs: Vec3, rhs: Translator3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_left_contraction_translator3(lhs: Vec3, rhs: Translator3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_commutator_translator3(lhs: Vec3, rhs: Translator3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_dot_translator3(lhs: Vec3, rhs: Translator3) -> Vec3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_geometric_rotor3(lhs: Vec3, rhs: Rotor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_geometric_rotor3(lhs: Vec3, rhs: Rotor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dot_rotor3(lhs: Vec3, rhs: Rotor3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_wedge_rotor3(lhs: Vec3, rhs: Rotor3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_wedge_rotor3(lhs: Vec3, rhs: Rotor3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_left_contraction_rotor3(lhs: Vec3, rhs: Rotor3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_commutator_rotor3(lhs: Vec3, rhs: Rotor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_dot_rotor3(lhs: Vec3, rhs: Rotor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_geometric_motor3(lhs: Vec3, rhs: Motor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_geometric_motor3(lhs: Vec3, rhs: Motor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dot_motor3(lhs: Vec3, rhs: Motor3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_wedge_motor3(lhs: Vec3, rhs: Motor3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_wedge_motor3(lhs: Vec3, rhs: Motor3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_left_contraction_motor3(lhs: Vec3, rhs: Motor3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_right_contraction_motor3(lhs: Vec3, rhs: Motor3) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_commutator_motor3(lhs: Vec3, rhs: Motor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_dot_motor3(lhs: Vec3, rhs: Motor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_geometric_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_geometric_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_dot_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_wedge_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_wedge_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_left_contraction_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_right_contraction_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_scalar_product_multivector(lhs: Vec3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_commutator_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec3_anti_dot_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_rcompl(value: Vec4) -> Plane {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_lcompl(value: Vec4) -> Plane {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_rev(value: Vec4) -> Vec4 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_arev(value: Vec4) -> Vec4 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_involute(value: Vec4) -> Vec4 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_conjugate(value: Vec4) -> Vec4 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dual(value: Vec4) -> Plane {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_bulk(value: Vec4) -> Vec3 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_weight(value: Vec4) -> W {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_bulk_dual(value: Vec4) -> Plane {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_weight_dual(value: Vec4) -> XYZ {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_geometric_vec3(lhs: Vec4, rhs: Vec3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_geometric_vec3(lhs: Vec4, rhs: Vec3) -> Moment3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dot_vec3(lhs: Vec4, rhs: Vec3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_wedge_vec3(lhs: Vec4, rhs: Vec3) -> Line3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_left_contraction_vec3(lhs: Vec4, rhs: Vec3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_right_contraction_vec3(lhs: Vec4, rhs: Vec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_scalar_product_vec3(lhs: Vec4, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_commutator_vec3(lhs: Vec4, rhs: Vec3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_geometric_vec4(lhs: Vec4, rhs: Vec4) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_geometric_vec4(lhs: Vec4, rhs: Vec4) -> Translator3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dot_vec4(lhs: Vec4, rhs: Vec4) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_wedge_vec4(lhs: Vec4, rhs: Vec4) -> Line3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_left_contraction_vec4(lhs: Vec4, rhs: Vec4) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_right_contraction_vec4(lhs: Vec4, rhs: Vec4) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_scalar_product_vec4(lhs: Vec4, rhs: Vec4) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_commutator_vec4(lhs: Vec4, rhs: Vec4) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_dot_vec4(lhs: Vec4, rhs: Vec4) -> XYZW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_geometric_point3(lhs: Vec4, rhs: Point3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_geometric_point3(lhs: Vec4, rhs: Point3) -> Translator3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dot_point3(lhs: Vec4, rhs: Point3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_wedge_point3(lhs: Vec4, rhs: Point3) -> Line3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_left_contraction_point3(lhs: Vec4, rhs: Point3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_right_contraction_point3(lhs: Vec4, rhs: Point3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_scalar_product_point3(lhs: Vec4, rhs: Point3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_commutator_point3(lhs: Vec4, rhs: Point3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_dot_point3(lhs: Vec4, rhs: Point3) -> XYZW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_geometric_moment3(lhs: Vec4, rhs: Moment3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_geometric_moment3(lhs: Vec4, rhs: Moment3) -> Vec3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dot_moment3(lhs: Vec4, rhs: Moment3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_wedge_moment3(lhs: Vec4, rhs: Moment3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_left_contraction_moment3(lhs: Vec4, rhs: Moment3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_commutator_moment3(lhs: Vec4, rhs: Moment3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_geometric_line3(lhs: Vec4, rhs: Line3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_geometric_line3(lhs: Vec4, rhs: Line3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dot_line3(lhs: Vec4, rhs: Line3) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_wedge_line3(lhs: Vec4, rhs: Line3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_left_contraction_line3(lhs: Vec4, rhs: Line3) -> Vec4 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_commutator_line3(lhs: Vec4, rhs: Line3) -> Vec4 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_dot_line3(lhs: Vec4, rhs: Line3) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_geometric_plane(lhs: Vec4, rhs: Plane) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_geometric_plane(lhs: Vec4, rhs: Plane) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dot_plane(lhs: Vec4, rhs: Plane) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_wedge_plane(lhs: Vec4, rhs: Plane) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_wedge_plane(lhs: Vec4, rhs: Plane) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_left_contraction_plane(lhs: Vec4, rhs: Plane) -> Line3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_commutator_plane(lhs: Vec4, rhs: Plane) -> XYZW {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_dot_plane(lhs: Vec4, rhs: Plane) -> Line3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_geometric_translator3(lhs: Vec4, rhs: Translator3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_geometric_translator3(lhs: Vec4, rhs: Translator3) -> Vec4 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dot_translator3(lhs: Vec4, rhs: Translator3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_wedge_translator3(lhs: Vec4, rhs: Translator3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_wedge_translator3(lhs: Vec4, rhs: Translator3) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_left_contraction_translator3(lhs: Vec4, rhs: Translator3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_commutator_translator3(lhs: Vec4, rhs: Translator3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_dot_translator3(lhs: Vec4, rhs: Translator3) -> Vec4 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_geometric_rotor3(lhs: Vec4, rhs: Rotor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_geometric_rotor3(lhs: Vec4, rhs: Rotor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dot_rotor3(lhs: Vec4, rhs: Rotor3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_wedge_rotor3(lhs: Vec4, rhs: Rotor3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_wedge_rotor3(lhs: Vec4, rhs: Rotor3) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_left_contraction_rotor3(lhs: Vec4, rhs: Rotor3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_commutator_rotor3(lhs: Vec4, rhs: Rotor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_dot_rotor3(lhs: Vec4, rhs: Rotor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_geometric_motor3(lhs: Vec4, rhs: Motor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_geometric_motor3(lhs: Vec4, rhs: Motor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dot_motor3(lhs: Vec4, rhs: Motor3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_wedge_motor3(lhs: Vec4, rhs: Motor3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_wedge_motor3(lhs: Vec4, rhs: Motor3) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_left_contraction_motor3(lhs: Vec4, rhs: Motor3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_right_contraction_motor3(lhs: Vec4, rhs: Motor3) -> Vec4 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_commutator_motor3(lhs: Vec4, rhs: Motor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_dot_motor3(lhs: Vec4, rhs: Motor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_geometric_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_geometric_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_dot_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_wedge_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_wedge_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_left_contraction_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_right_contraction_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_scalar_product_multivector(lhs: Vec4, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_commutator_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_vec4_anti_dot_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_rcompl(value: Point3) -> Plane {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_lcompl(value: Point3) -> Plane {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dual(value: Point3) -> Plane {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_bulk(value: Point3) -> Vec3 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_weight(value: Point3) -> W {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_bulk_dual(value: Point3) -> Plane {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_weight_dual(value: Point3) -> XYZ {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_geometric_vec3(lhs: Point3, rhs: Vec3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_geometric_vec3(lhs: Point3, rhs: Vec3) -> Moment3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dot_vec3(lhs: Point3, rhs: Vec3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_wedge_vec3(lhs: Point3, rhs: Vec3) -> Line3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_left_contraction_vec3(lhs: Point3, rhs: Vec3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_right_contraction_vec3(lhs: Point3, rhs: Vec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_scalar_product_vec3(lhs: Point3, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_commutator_vec3(lhs: Point3, rhs: Vec3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_geometric_vec4(lhs: Point3, rhs: Vec4) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_geometric_vec4(lhs: Point3, rhs: Vec4) -> Translator3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dot_vec4(lhs: Point3, rhs: Vec4) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_wedge_vec4(lhs: Point3, rhs: Vec4) -> Line3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_left_contraction_vec4(lhs: Point3, rhs: Vec4) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_right_contraction_vec4(lhs: Point3, rhs: Vec4) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_scalar_product_vec4(lhs: Point3, rhs: Vec4) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_commutator_vec4(lhs: Point3, rhs: Vec4) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_dot_vec4(lhs: Point3, rhs: Vec4) -> XYZW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_geometric_point3(lhs: Point3, rhs: Point3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_geometric_point3(lhs: Point3, rhs: Point3) -> Translator3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dot_point3(lhs: Point3, rhs: Point3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_wedge_point3(lhs: Point3, rhs: Point3) -> Line3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_left_contraction_point3(lhs: Point3, rhs: Point3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_right_contraction_point3(lhs: Point3, rhs: Point3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_scalar_product_point3(lhs: Point3, rhs: Point3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_commutator_point3(lhs: Point3, rhs: Point3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_dot_point3(lhs: Point3, rhs: Point3) -> XYZW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_geometric_moment3(lhs: Point3, rhs: Moment3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_geometric_moment3(lhs: Point3, rhs: Moment3) -> Vec3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dot_moment3(lhs: Point3, rhs: Moment3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_wedge_moment3(lhs: Point3, rhs: Moment3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_left_contraction_moment3(lhs: Point3, rhs: Moment3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_commutator_moment3(lhs: Point3, rhs: Moment3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_geometric_line3(lhs: Point3, rhs: Line3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_geometric_line3(lhs: Point3, rhs: Line3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dot_line3(lhs: Point3, rhs: Line3) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_wedge_line3(lhs: Point3, rhs: Line3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_left_contraction_line3(lhs: Point3, rhs: Line3) -> Vec4 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_commutator_line3(lhs: Point3, rhs: Line3) -> Vec4 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_dot_line3(lhs: Point3, rhs: Line3) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_geometric_plane(lhs: Point3, rhs: Plane) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_geometric_plane(lhs: Point3, rhs: Plane) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dot_plane(lhs: Point3, rhs: Plane) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_wedge_plane(lhs: Point3, rhs: Plane) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_wedge_plane(lhs: Point3, rhs: Plane) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_left_contraction_plane(lhs: Point3, rhs: Plane) -> Line3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_commutator_plane(lhs: Point3, rhs: Plane) -> XYZW {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_dot_plane(lhs: Point3, rhs: Plane) -> Line3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_geometric_translator3(lhs: Point3, rhs: Translator3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_geometric_translator3(lhs: Point3, rhs: Translator3) -> Vec4 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dot_translator3(lhs: Point3, rhs: Translator3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_wedge_translator3(lhs: Point3, rhs: Translator3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_wedge_translator3(lhs: Point3, rhs: Translator3) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_left_contraction_translator3(lhs: Point3, rhs: Translator3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_commutator_translator3(lhs: Point3, rhs: Translator3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_dot_translator3(lhs: Point3, rhs: Translator3) -> Vec4 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_geometric_rotor3(lhs: Point3, rhs: Rotor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_geometric_rotor3(lhs: Point3, rhs: Rotor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dot_rotor3(lhs: Point3, rhs: Rotor3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_wedge_rotor3(lhs: Point3, rhs: Rotor3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_wedge_rotor3(lhs: Point3, rhs: Rotor3) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_left_contraction_rotor3(lhs: Point3, rhs: Rotor3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_commutator_rotor3(lhs: Point3, rhs: Rotor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_dot_rotor3(lhs: Point3, rhs: Rotor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_geometric_motor3(lhs: Point3, rhs: Motor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_geometric_motor3(lhs: Point3, rhs: Motor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dot_motor3(lhs: Point3, rhs: Motor3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_wedge_motor3(lhs: Point3, rhs: Motor3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_wedge_motor3(lhs: Point3, rhs: Motor3) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_left_contraction_motor3(lhs: Point3, rhs: Motor3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_right_contraction_motor3(lhs: Point3, rhs: Motor3) -> Vec4 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_commutator_motor3(lhs: Point3, rhs: Motor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_dot_motor3(lhs: Point3, rhs: Motor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_geometric_multivector(lhs: Point3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_geometric_multivector(lhs: Point3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_dot_multivector(lhs: Point3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_wedge_multivector(lhs: Point3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_wedge_multivector(lhs: Point3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_left_contraction_multivector(lhs: Point3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_right_contraction_multivector(lhs: Point3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_scalar_product_multivector(lhs: Point3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_commutator_multivector(lhs: Point3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_point3_anti_dot_multivector(lhs: Point3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_rcompl(value: Moment3) -> Line3 {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_lcompl(value: Moment3) -> Line3 {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_rev(value: Moment3) -> Moment3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_arev(value: Moment3) -> Moment3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_involute(value: Moment3) -> Moment3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_conjugate(value: Moment3) -> Moment3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dual(value: Moment3) -> Line3 {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_bulk(value: Moment3) -> Moment3 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_bulk_dual(value: Moment3) -> Line3 {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_geometric_vec3(lhs: Moment3, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dot_vec3(lhs: Moment3, rhs: Vec3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_wedge_vec3(lhs: Moment3, rhs: Vec3) -> XYZ {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_right_contraction_vec3(lhs: Moment3, rhs: Vec3) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_commutator_vec3(lhs: Moment3, rhs: Vec3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_geometric_vec4(lhs: Moment3, rhs: Vec4) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_geometric_vec4(lhs: Moment3, rhs: Vec4) -> Vec3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dot_vec4(lhs: Moment3, rhs: Vec4) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_wedge_vec4(lhs: Moment3, rhs: Vec4) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_right_contraction_vec4(lhs: Moment3, rhs: Vec4) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_commutator_vec4(lhs: Moment3, rhs: Vec4) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_geometric_point3(lhs: Moment3, rhs: Point3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_geometric_point3(lhs: Moment3, rhs: Point3) -> Vec3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dot_point3(lhs: Moment3, rhs: Point3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_wedge_point3(lhs: Moment3, rhs: Point3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_right_contraction_point3(lhs: Moment3, rhs: Point3) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_commutator_point3(lhs: Moment3, rhs: Point3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_geometric_moment3(lhs: Moment3, rhs: Moment3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dot_moment3(lhs: Moment3, rhs: Moment3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_left_contraction_moment3(lhs: Moment3, rhs: Moment3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_right_contraction_moment3(lhs: Moment3, rhs: Moment3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_scalar_product_moment3(lhs: Moment3, rhs: Moment3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_commutator_moment3(lhs: Moment3, rhs: Moment3) -> Moment3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_geometric_line3(lhs: Moment3, rhs: Line3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_geometric_line3(lhs: Moment3, rhs: Line3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dot_line3(lhs: Moment3, rhs: Line3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_wedge_line3(lhs: Moment3, rhs: Line3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_wedge_line3(lhs: Moment3, rhs: Line3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_left_contraction_line3(lhs: Moment3, rhs: Line3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_right_contraction_line3(lhs: Moment3, rhs: Line3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_scalar_product_line3(lhs: Moment3, rhs: Line3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_commutator_line3(lhs: Moment3, rhs: Line3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_geometric_plane(lhs: Moment3, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_geometric_plane(lhs: Moment3, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dot_plane(lhs: Moment3, rhs: Plane) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_wedge_plane(lhs: Moment3, rhs: Plane) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_left_contraction_plane(lhs: Moment3, rhs: Plane) -> Vec4 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_commutator_plane(lhs: Moment3, rhs: Plane) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_dot_plane(lhs: Moment3, rhs: Plane) -> XYZ {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_geometric_translator3(lhs: Moment3, rhs: Translator3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_geometric_translator3(lhs: Moment3, rhs: Translator3) -> Moment3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dot_translator3(lhs: Moment3, rhs: Translator3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_wedge_translator3(lhs: Moment3, rhs: Translator3) -> Moment3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_left_contraction_translator3(lhs: Moment3, rhs: Translator3) -> Motor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_right_contraction_translator3(lhs: Moment3, rhs: Translator3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_scalar_product_translator3(lhs: Moment3, rhs: Translator3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_commutator_translator3(lhs: Moment3, rhs: Translator3) -> Moment3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_dot_translator3(lhs: Moment3, rhs: Translator3) -> Moment3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_geometric_rotor3(lhs: Moment3, rhs: Rotor3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_geometric_rotor3(lhs: Moment3, rhs: Rotor3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dot_rotor3(lhs: Moment3, rhs: Rotor3) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_wedge_rotor3(lhs: Moment3, rhs: Rotor3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_wedge_rotor3(lhs: Moment3, rhs: Rotor3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_left_contraction_rotor3(lhs: Moment3, rhs: Rotor3) -> Line3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_commutator_rotor3(lhs: Moment3, rhs: Rotor3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_dot_rotor3(lhs: Moment3, rhs: Rotor3) -> Moment3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_geometric_motor3(lhs: Moment3, rhs: Motor3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_geometric_motor3(lhs: Moment3, rhs: Motor3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dot_motor3(lhs: Moment3, rhs: Motor3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_wedge_motor3(lhs: Moment3, rhs: Motor3) -> Translator3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_wedge_motor3(lhs: Moment3, rhs: Motor3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_left_contraction_motor3(lhs: Moment3, rhs: Motor3) -> Motor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_right_contraction_motor3(lhs: Moment3, rhs: Motor3) -> Motor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_scalar_product_motor3(lhs: Moment3, rhs: Motor3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_commutator_motor3(lhs: Moment3, rhs: Motor3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_dot_motor3(lhs: Moment3, rhs: Motor3) -> Moment3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_geometric_multivector(lhs: Moment3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_geometric_multivector(lhs: Moment3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_dot_multivector(lhs: Moment3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_wedge_multivector(lhs: Moment3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_wedge_multivector(lhs: Moment3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_left_contraction_multivector(lhs: Moment3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_right_contraction_multivector(lhs: Moment3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_scalar_product_multivector(lhs: Moment3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_commutator_multivector(lhs: Moment3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_moment3_anti_dot_multivector(lhs: Moment3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_rcompl(value: Line3) -> Line3 {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_lcompl(value: Line3) -> Line3 {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_rev(value: Line3) -> Line3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_arev(value: Line3) -> Line3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_involute(value: Line3) -> Line3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_conjugate(value: Line3) -> Line3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dual(value: Line3) -> Line3 {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_bulk(value: Line3) -> Moment3 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_weight(value: Line3) -> Line3 {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_bulk_dual(value: Line3) -> Line3 {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_weight_dual(value: Line3) -> Moment3 {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_geometric_vec3(lhs: Line3, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_geometric_vec3(lhs: Line3, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dot_vec3(lhs: Line3, rhs: Vec3) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_wedge_vec3(lhs: Line3, rhs: Vec3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_right_contraction_vec3(lhs: Line3, rhs: Vec3) -> Vec4 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_commutator_vec3(lhs: Line3, rhs: Vec3) -> Vec4 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_dot_vec3(lhs: Line3, rhs: Vec3) -> XYZ {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_geometric_vec4(lhs: Line3, rhs: Vec4) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_geometric_vec4(lhs: Line3, rhs: Vec4) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dot_vec4(lhs: Line3, rhs: Vec4) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_wedge_vec4(lhs: Line3, rhs: Vec4) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_right_contraction_vec4(lhs: Line3, rhs: Vec4) -> Vec4 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_commutator_vec4(lhs: Line3, rhs: Vec4) -> Vec4 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_dot_vec4(lhs: Line3, rhs: Vec4) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_geometric_point3(lhs: Line3, rhs: Point3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_geometric_point3(lhs: Line3, rhs: Point3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dot_point3(lhs: Line3, rhs: Point3) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_wedge_point3(lhs: Line3, rhs: Point3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_right_contraction_point3(lhs: Line3, rhs: Point3) -> Vec4 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_commutator_point3(lhs: Line3, rhs: Point3) -> Vec4 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_dot_point3(lhs: Line3, rhs: Point3) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_geometric_moment3(lhs: Line3, rhs: Moment3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_geometric_moment3(lhs: Line3, rhs: Moment3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dot_moment3(lhs: Line3, rhs: Moment3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_wedge_moment3(lhs: Line3, rhs: Moment3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_wedge_moment3(lhs: Line3, rhs: Moment3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_left_contraction_moment3(lhs: Line3, rhs: Moment3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_right_contraction_moment3(lhs: Line3, rhs: Moment3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_scalar_product_moment3(lhs: Line3, rhs: Moment3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_commutator_moment3(lhs: Line3, rhs: Moment3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_geometric_line3(lhs: Line3, rhs: Line3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_geometric_line3(lhs: Line3, rhs: Line3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dot_line3(lhs: Line3, rhs: Line3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_wedge_line3(lhs: Line3, rhs: Line3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_wedge_line3(lhs: Line3, rhs: Line3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_left_contraction_line3(lhs: Line3, rhs: Line3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_right_contraction_line3(lhs: Line3, rhs: Line3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_scalar_product_line3(lhs: Line3, rhs: Line3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_commutator_line3(lhs: Line3, rhs: Line3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_dot_line3(lhs: Line3, rhs: Line3) -> XYZW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_geometric_plane(lhs: Line3, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_geometric_plane(lhs: Line3, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dot_plane(lhs: Line3, rhs: Plane) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_wedge_plane(lhs: Line3, rhs: Plane) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_left_contraction_plane(lhs: Line3, rhs: Plane) -> Vec4 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_commutator_plane(lhs: Line3, rhs: Plane) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_dot_plane(lhs: Line3, rhs: Plane) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_geometric_translator3(lhs: Line3, rhs: Translator3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_geometric_translator3(lhs: Line3, rhs: Translator3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dot_translator3(lhs: Line3, rhs: Translator3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_wedge_translator3(lhs: Line3, rhs: Translator3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_wedge_translator3(lhs: Line3, rhs: Translator3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_left_contraction_translator3(lhs: Line3, rhs: Translator3) -> Motor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_right_contraction_translator3(lhs: Line3, rhs: Translator3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_scalar_product_translator3(lhs: Line3, rhs: Translator3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_commutator_translator3(lhs: Line3, rhs: Translator3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_dot_translator3(lhs: Line3, rhs: Translator3) -> Line3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_geometric_rotor3(lhs: Line3, rhs: Rotor3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_geometric_rotor3(lhs: Line3, rhs: Rotor3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dot_rotor3(lhs: Line3, rhs: Rotor3) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_wedge_rotor3(lhs: Line3, rhs: Rotor3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_wedge_rotor3(lhs: Line3, rhs: Rotor3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_left_contraction_rotor3(lhs: Line3, rhs: Rotor3) -> Line3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_commutator_rotor3(lhs: Line3, rhs: Rotor3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_dot_rotor3(lhs: Line3, rhs: Rotor3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_geometric_motor3(lhs: Line3, rhs: Motor3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_geometric_motor3(lhs: Line3, rhs: Motor3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dot_motor3(lhs: Line3, rhs: Motor3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_wedge_motor3(lhs: Line3, rhs: Motor3) -> Motor3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_wedge_motor3(lhs: Line3, rhs: Motor3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_left_contraction_motor3(lhs: Line3, rhs: Motor3) -> Motor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_right_contraction_motor3(lhs: Line3, rhs: Motor3) -> Motor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_scalar_product_motor3(lhs: Line3, rhs: Motor3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_commutator_motor3(lhs: Line3, rhs: Motor3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_dot_motor3(lhs: Line3, rhs: Motor3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_geometric_multivector(lhs: Line3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_geometric_multivector(lhs: Line3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_dot_multivector(lhs: Line3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_wedge_multivector(lhs: Line3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_wedge_multivector(lhs: Line3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_left_contraction_multivector(lhs: Line3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_right_contraction_multivector(lhs: Line3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_scalar_product_multivector(lhs: Line3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_commutator_multivector(lhs: Line3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_line3_anti_dot_multivector(lhs: Line3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_rcompl(value: Plane) -> Vec4 {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_lcompl(value: Plane) -> Vec4 {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_rev(value: Plane) -> Plane {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_arev(value: Plane) -> Plane {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_involute(value: Plane) -> Plane {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_conjugate(value: Plane) -> Plane {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dual(value: Plane) -> Vec4 {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_bulk(value: Plane) -> XYZ {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_weight(value: Plane) -> Plane {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_bulk_dual(value: Plane) -> W {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_weight_dual(value: Plane) -> Vec3 {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_geometric_vec3(lhs: Plane, rhs: Vec3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_geometric_vec3(lhs: Plane, rhs: Vec3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dot_vec3(lhs: Plane, rhs: Vec3) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_wedge_vec3(lhs: Plane, rhs: Vec3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_wedge_vec3(lhs: Plane, rhs: Vec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_right_contraction_vec3(lhs: Plane, rhs: Vec3) -> Line3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_commutator_vec3(lhs: Plane, rhs: Vec3) -> XYZW {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_dot_vec3(lhs: Plane, rhs: Vec3) -> Moment3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_geometric_vec4(lhs: Plane, rhs: Vec4) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_geometric_vec4(lhs: Plane, rhs: Vec4) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dot_vec4(lhs: Plane, rhs: Vec4) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_wedge_vec4(lhs: Plane, rhs: Vec4) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_wedge_vec4(lhs: Plane, rhs: Vec4) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_right_contraction_vec4(lhs: Plane, rhs: Vec4) -> Line3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_commutator_vec4(lhs: Plane, rhs: Vec4) -> XYZW {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_dot_vec4(lhs: Plane, rhs: Vec4) -> Line3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_geometric_point3(lhs: Plane, rhs: Point3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_geometric_point3(lhs: Plane, rhs: Point3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dot_point3(lhs: Plane, rhs: Point3) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_wedge_point3(lhs: Plane, rhs: Point3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_wedge_point3(lhs: Plane, rhs: Point3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_right_contraction_point3(lhs: Plane, rhs: Point3) -> Line3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_commutator_point3(lhs: Plane, rhs: Point3) -> XYZW {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_dot_point3(lhs: Plane, rhs: Point3) -> Line3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_geometric_moment3(lhs: Plane, rhs: Moment3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_geometric_moment3(lhs: Plane, rhs: Moment3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dot_moment3(lhs: Plane, rhs: Moment3) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_wedge_moment3(lhs: Plane, rhs: Moment3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_right_contraction_moment3(lhs: Plane, rhs: Moment3) -> Vec4 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_commutator_moment3(lhs: Plane, rhs: Moment3) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_dot_moment3(lhs: Plane, rhs: Moment3) -> XYZ {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_geometric_line3(lhs: Plane, rhs: Line3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_geometric_line3(lhs: Plane, rhs: Line3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dot_line3(lhs: Plane, rhs: Line3) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_wedge_line3(lhs: Plane, rhs: Line3) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_right_contraction_line3(lhs: Plane, rhs: Line3) -> Vec4 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_commutator_line3(lhs: Plane, rhs: Line3) -> Plane {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_dot_line3(lhs: Plane, rhs: Line3) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_geometric_plane(lhs: Plane, rhs: Plane) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_geometric_plane(lhs: Plane, rhs: Plane) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dot_plane(lhs: Plane, rhs: Plane) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_wedge_plane(lhs: Plane, rhs: Plane) -> Line3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_left_contraction_plane(lhs: Plane, rhs: Plane) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_right_contraction_plane(lhs: Plane, rhs: Plane) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_scalar_product_plane(lhs: Plane, rhs: Plane) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_commutator_plane(lhs: Plane, rhs: Plane) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_dot_plane(lhs: Plane, rhs: Plane) -> XYZW {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_geometric_translator3(lhs: Plane, rhs: Translator3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_geometric_translator3(lhs: Plane, rhs: Translator3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dot_translator3(lhs: Plane, rhs: Translator3) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_wedge_translator3(lhs: Plane, rhs: Translator3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_left_contraction_translator3(lhs: Plane, rhs: Translator3) -> W {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_right_contraction_translator3(lhs: Plane, rhs: Translator3) -> Vec4 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_commutator_translator3(lhs: Plane, rhs: Translator3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_dot_translator3(lhs: Plane, rhs: Translator3) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_geometric_rotor3(lhs: Plane, rhs: Rotor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_geometric_rotor3(lhs: Plane, rhs: Rotor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dot_rotor3(lhs: Plane, rhs: Rotor3) -> W {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_wedge_rotor3(lhs: Plane, rhs: Rotor3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_left_contraction_rotor3(lhs: Plane, rhs: Rotor3) -> W {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_commutator_rotor3(lhs: Plane, rhs: Rotor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_dot_rotor3(lhs: Plane, rhs: Rotor3) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_geometric_motor3(lhs: Plane, rhs: Motor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_geometric_motor3(lhs: Plane, rhs: Motor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dot_motor3(lhs: Plane, rhs: Motor3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_wedge_motor3(lhs: Plane, rhs: Motor3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_wedge_motor3(lhs: Plane, rhs: Motor3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_left_contraction_motor3(lhs: Plane, rhs: Motor3) -> W {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_right_contraction_motor3(lhs: Plane, rhs: Motor3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_commutator_motor3(lhs: Plane, rhs: Motor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_dot_motor3(lhs: Plane, rhs: Motor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_geometric_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_geometric_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_dot_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_wedge_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_wedge_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_left_contraction_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_right_contraction_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_scalar_product_multivector(lhs: Plane, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_commutator_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_plane_anti_dot_multivector(lhs: Plane, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_rcompl(value: Translator3) -> Motor3 {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_lcompl(value: Translator3) -> Motor3 {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_rev(value: Translator3) -> Translator3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_arev(value: Translator3) -> Translator3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_involute(value: Translator3) -> Translator3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_conjugate(value: Translator3) -> Translator3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dual(value: Translator3) -> Motor3 {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_bulk(value: Translator3) -> Moment3 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_weight(value: Translator3) -> XYZW {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_bulk_dual(value: Translator3) -> Line3 {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_weight_dual(value: Translator3) -> S {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_geometric_vec3(lhs: Translator3, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_geometric_vec3(lhs: Translator3, rhs: Vec3) -> Vec3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dot_vec3(lhs: Translator3, rhs: Vec3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_wedge_vec3(lhs: Translator3, rhs: Vec3) -> XYZ {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_wedge_vec3(lhs: Translator3, rhs: Vec3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_right_contraction_vec3(lhs: Translator3, rhs: Vec3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_commutator_vec3(lhs: Translator3, rhs: Vec3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_dot_vec3(lhs: Translator3, rhs: Vec3) -> Vec3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_geometric_vec4(lhs: Translator3, rhs: Vec4) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_geometric_vec4(lhs: Translator3, rhs: Vec4) -> Vec4 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dot_vec4(lhs: Translator3, rhs: Vec4) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_wedge_vec4(lhs: Translator3, rhs: Vec4) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_wedge_vec4(lhs: Translator3, rhs: Vec4) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_right_contraction_vec4(lhs: Translator3, rhs: Vec4) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_commutator_vec4(lhs: Translator3, rhs: Vec4) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_dot_vec4(lhs: Translator3, rhs: Vec4) -> Vec4 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_geometric_point3(lhs: Translator3, rhs: Point3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_geometric_point3(lhs: Translator3, rhs: Point3) -> Vec4 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dot_point3(lhs: Translator3, rhs: Point3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_wedge_point3(lhs: Translator3, rhs: Point3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_wedge_point3(lhs: Translator3, rhs: Point3) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_right_contraction_point3(lhs: Translator3, rhs: Point3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_commutator_point3(lhs: Translator3, rhs: Point3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_dot_point3(lhs: Translator3, rhs: Point3) -> Vec4 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_geometric_moment3(lhs: Translator3, rhs: Moment3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_geometric_moment3(lhs: Translator3, rhs: Moment3) -> Moment3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dot_moment3(lhs: Translator3, rhs: Moment3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_wedge_moment3(lhs: Translator3, rhs: Moment3) -> Moment3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_left_contraction_moment3(lhs: Translator3, rhs: Moment3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_right_contraction_moment3(lhs: Translator3, rhs: Moment3) -> Motor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_scalar_product_moment3(lhs: Translator3, rhs: Moment3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_commutator_moment3(lhs: Translator3, rhs: Moment3) -> Moment3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_dot_moment3(lhs: Translator3, rhs: Moment3) -> Moment3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_geometric_line3(lhs: Translator3, rhs: Line3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_geometric_line3(lhs: Translator3, rhs: Line3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dot_line3(lhs: Translator3, rhs: Line3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_wedge_line3(lhs: Translator3, rhs: Line3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_wedge_line3(lhs: Translator3, rhs: Line3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_left_contraction_line3(lhs: Translator3, rhs: Line3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_right_contraction_line3(lhs: Translator3, rhs: Line3) -> Motor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_scalar_product_line3(lhs: Translator3, rhs: Line3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_commutator_line3(lhs: Translator3, rhs: Line3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_dot_line3(lhs: Translator3, rhs: Line3) -> Line3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_geometric_plane(lhs: Translator3, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_geometric_plane(lhs: Translator3, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dot_plane(lhs: Translator3, rhs: Plane) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_wedge_plane(lhs: Translator3, rhs: Plane) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_left_contraction_plane(lhs: Translator3, rhs: Plane) -> Vec4 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_right_contraction_plane(lhs: Translator3, rhs: Plane) -> W {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_commutator_plane(lhs: Translator3, rhs: Plane) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_dot_plane(lhs: Translator3, rhs: Plane) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_geometric_translator3(lhs: Translator3, rhs: Translator3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_geometric_translator3(lhs: Translator3, rhs: Translator3) -> Translator3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dot_translator3(lhs: Translator3, rhs: Translator3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_wedge_translator3(lhs: Translator3, rhs: Translator3) -> Translator3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_left_contraction_translator3(lhs: Translator3, rhs: Translator3) -> Motor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_right_contraction_translator3(lhs: Translator3, rhs: Translator3) -> Motor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_scalar_product_translator3(lhs: Translator3, rhs: Translator3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_commutator_translator3(lhs: Translator3, rhs: Translator3) -> Moment3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_dot_translator3(lhs: Translator3, rhs: Translator3) -> Translator3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_geometric_rotor3(lhs: Translator3, rhs: Rotor3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_geometric_rotor3(lhs: Translator3, rhs: Rotor3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dot_rotor3(lhs: Translator3, rhs: Rotor3) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_wedge_rotor3(lhs: Translator3, rhs: Rotor3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_wedge_rotor3(lhs: Translator3, rhs: Rotor3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_left_contraction_rotor3(lhs: Translator3, rhs: Rotor3) -> Line3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_commutator_rotor3(lhs: Translator3, rhs: Rotor3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_dot_rotor3(lhs: Translator3, rhs: Rotor3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_geometric_motor3(lhs: Translator3, rhs: Motor3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_geometric_motor3(lhs: Translator3, rhs: Motor3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dot_motor3(lhs: Translator3, rhs: Motor3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_wedge_motor3(lhs: Translator3, rhs: Motor3) -> Translator3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_wedge_motor3(lhs: Translator3, rhs: Motor3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_left_contraction_motor3(lhs: Translator3, rhs: Motor3) -> Motor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_right_contraction_motor3(lhs: Translator3, rhs: Motor3) -> Motor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_scalar_product_motor3(lhs: Translator3, rhs: Motor3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_commutator_motor3(lhs: Translator3, rhs: Motor3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_dot_motor3(lhs: Translator3, rhs: Motor3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_geometric_multivector(lhs: Translator3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_geometric_multivector(lhs: Translator3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_dot_multivector(lhs: Translator3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_wedge_multivector(lhs: Translator3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_wedge_multivector(lhs: Translator3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_left_contraction_multivector(lhs: Translator3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_right_contraction_multivector(lhs: Translator3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_scalar_product_multivector(lhs: Translator3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_commutator_multivector(lhs: Translator3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_dot_multivector(lhs: Translator3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_sandwich_vec3(lhs: Translator3, rhs: Vec3) -> Vec3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_sandwich_vec4(lhs: Translator3, rhs: Vec4) -> Vec4 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_sandwich_vec4(lhs: Translator3, rhs: Vec4) -> Vec4 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_sandwich_moment3(lhs: Translator3, rhs: Moment3) -> Moment3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_sandwich_line3(lhs: Translator3, rhs: Line3) -> Line3 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_sandwich_line3(lhs: Translator3, rhs: Line3) -> Line3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_sandwich_plane(lhs: Translator3, rhs: Plane) -> Plane {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_translator3_anti_sandwich_plane(lhs: Translator3, rhs: Plane) -> Plane {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_rcompl(value: Rotor3) -> Motor3 {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_lcompl(value: Rotor3) -> Motor3 {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_rev(value: Rotor3) -> Rotor3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_arev(value: Rotor3) -> Rotor3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_involute(value: Rotor3) -> Rotor3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_conjugate(value: Rotor3) -> Rotor3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_dual(value: Rotor3) -> Motor3 {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_weight(value: Rotor3) -> Rotor3 {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_weight_dual(value: Rotor3) -> Motor3 {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_geometric_vec3(lhs: Rotor3, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_geometric_vec3(lhs: Rotor3, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_dot_vec3(lhs: Rotor3, rhs: Vec3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_wedge_vec3(lhs: Rotor3, rhs: Vec3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_wedge_vec3(lhs: Rotor3, rhs: Vec3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_right_contraction_vec3(lhs: Rotor3, rhs: Vec3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_commutator_vec3(lhs: Rotor3, rhs: Vec3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_dot_vec3(lhs: Rotor3, rhs: Vec3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_geometric_vec4(lhs: Rotor3, rhs: Vec4) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_geometric_vec4(lhs: Rotor3, rhs: Vec4) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_dot_vec4(lhs: Rotor3, rhs: Vec4) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_wedge_vec4(lhs: Rotor3, rhs: Vec4) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_wedge_vec4(lhs: Rotor3, rhs: Vec4) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_right_contraction_vec4(lhs: Rotor3, rhs: Vec4) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_commutator_vec4(lhs: Rotor3, rhs: Vec4) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_dot_vec4(lhs: Rotor3, rhs: Vec4) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_geometric_point3(lhs: Rotor3, rhs: Point3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_geometric_point3(lhs: Rotor3, rhs: Point3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_dot_point3(lhs: Rotor3, rhs: Point3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_wedge_point3(lhs: Rotor3, rhs: Point3) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_wedge_point3(lhs: Rotor3, rhs: Point3) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_right_contraction_point3(lhs: Rotor3, rhs: Point3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_commutator_point3(lhs: Rotor3, rhs: Point3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_dot_point3(lhs: Rotor3, rhs: Point3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_geometric_moment3(lhs: Rotor3, rhs: Moment3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_geometric_moment3(lhs: Rotor3, rhs: Moment3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_dot_moment3(lhs: Rotor3, rhs: Moment3) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_wedge_moment3(lhs: Rotor3, rhs: Moment3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_wedge_moment3(lhs: Rotor3, rhs: Moment3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_right_contraction_moment3(lhs: Rotor3, rhs: Moment3) -> Line3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_commutator_moment3(lhs: Rotor3, rhs: Moment3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_dot_moment3(lhs: Rotor3, rhs: Moment3) -> Moment3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_geometric_line3(lhs: Rotor3, rhs: Line3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_geometric_line3(lhs: Rotor3, rhs: Line3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_dot_line3(lhs: Rotor3, rhs: Line3) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_wedge_line3(lhs: Rotor3, rhs: Line3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_wedge_line3(lhs: Rotor3, rhs: Line3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_right_contraction_line3(lhs: Rotor3, rhs: Line3) -> Line3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_commutator_line3(lhs: Rotor3, rhs: Line3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_dot_line3(lhs: Rotor3, rhs: Line3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_geometric_plane(lhs: Rotor3, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_geometric_plane(lhs: Rotor3, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_dot_plane(lhs: Rotor3, rhs: Plane) -> W {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_wedge_plane(lhs: Rotor3, rhs: Plane) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_right_contraction_plane(lhs: Rotor3, rhs: Plane) -> W {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_commutator_plane(lhs: Rotor3, rhs: Plane) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_dot_plane(lhs: Rotor3, rhs: Plane) -> Plane {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_geometric_translator3(lhs: Rotor3, rhs: Translator3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_geometric_translator3(lhs: Rotor3, rhs: Translator3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_dot_translator3(lhs: Rotor3, rhs: Translator3) -> Line3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_wedge_translator3(lhs: Rotor3, rhs: Translator3) -> XYZW {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_wedge_translator3(lhs: Rotor3, rhs: Translator3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_right_contraction_translator3(lhs: Rotor3, rhs: Translator3) -> Line3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_commutator_translator3(lhs: Rotor3, rhs: Translator3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_dot_translator3(lhs: Rotor3, rhs: Translator3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_geometric_rotor3(lhs: Rotor3, rhs: Rotor3) -> Rotor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_wedge_rotor3(lhs: Rotor3, rhs: Rotor3) -> Rotor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_dot_rotor3(lhs: Rotor3, rhs: Rotor3) -> Rotor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_geometric_motor3(lhs: Rotor3, rhs: Motor3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_geometric_motor3(lhs: Rotor3, rhs: Motor3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_dot_motor3(lhs: Rotor3, rhs: Motor3) -> Rotor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_wedge_motor3(lhs: Rotor3, rhs: Motor3) -> Rotor3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_wedge_motor3(lhs: Rotor3, rhs: Motor3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_right_contraction_motor3(lhs: Rotor3, rhs: Motor3) -> Rotor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_commutator_motor3(lhs: Rotor3, rhs: Motor3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_dot_motor3(lhs: Rotor3, rhs: Motor3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_geometric_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_geometric_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_dot_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_wedge_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_wedge_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_right_contraction_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_commutator_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_dot_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_sandwich_vec3(lhs: Rotor3, rhs: Vec3) -> Vec3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_sandwich_vec4(lhs: Rotor3, rhs: Vec4) -> Vec4 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_sandwich_moment3(lhs: Rotor3, rhs: Moment3) -> Moment3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_sandwich_line3(lhs: Rotor3, rhs: Line3) -> Line3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_rotor3_anti_sandwich_plane(lhs: Rotor3, rhs: Plane) -> Plane {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_rcompl(value: Motor3) -> Motor3 {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_lcompl(value: Motor3) -> Motor3 {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_rev(value: Motor3) -> Motor3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_arev(value: Motor3) -> Motor3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_involute(value: Motor3) -> Motor3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_conjugate(value: Motor3) -> Motor3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dual(value: Motor3) -> Motor3 {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_bulk(value: Motor3) -> Motor3 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_weight(value: Motor3) -> Rotor3 {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_bulk_dual(value: Motor3) -> Rotor3 {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_weight_dual(value: Motor3) -> Motor3 {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_geometric_vec3(lhs: Motor3, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_geometric_vec3(lhs: Motor3, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dot_vec3(lhs: Motor3, rhs: Vec3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_wedge_vec3(lhs: Motor3, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_wedge_vec3(lhs: Motor3, rhs: Vec3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_left_contraction_vec3(lhs: Motor3, rhs: Vec3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_right_contraction_vec3(lhs: Motor3, rhs: Vec3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_commutator_vec3(lhs: Motor3, rhs: Vec3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_dot_vec3(lhs: Motor3, rhs: Vec3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_geometric_vec4(lhs: Motor3, rhs: Vec4) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_geometric_vec4(lhs: Motor3, rhs: Vec4) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dot_vec4(lhs: Motor3, rhs: Vec4) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_wedge_vec4(lhs: Motor3, rhs: Vec4) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_wedge_vec4(lhs: Motor3, rhs: Vec4) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_left_contraction_vec4(lhs: Motor3, rhs: Vec4) -> Vec4 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_right_contraction_vec4(lhs: Motor3, rhs: Vec4) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_commutator_vec4(lhs: Motor3, rhs: Vec4) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_dot_vec4(lhs: Motor3, rhs: Vec4) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_geometric_point3(lhs: Motor3, rhs: Point3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_geometric_point3(lhs: Motor3, rhs: Point3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dot_point3(lhs: Motor3, rhs: Point3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_wedge_point3(lhs: Motor3, rhs: Point3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_wedge_point3(lhs: Motor3, rhs: Point3) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_left_contraction_point3(lhs: Motor3, rhs: Point3) -> Vec4 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_right_contraction_point3(lhs: Motor3, rhs: Point3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_commutator_point3(lhs: Motor3, rhs: Point3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_dot_point3(lhs: Motor3, rhs: Point3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_geometric_moment3(lhs: Motor3, rhs: Moment3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_geometric_moment3(lhs: Motor3, rhs: Moment3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dot_moment3(lhs: Motor3, rhs: Moment3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_wedge_moment3(lhs: Motor3, rhs: Moment3) -> Translator3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_wedge_moment3(lhs: Motor3, rhs: Moment3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_left_contraction_moment3(lhs: Motor3, rhs: Moment3) -> Motor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_right_contraction_moment3(lhs: Motor3, rhs: Moment3) -> Motor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_scalar_product_moment3(lhs: Motor3, rhs: Moment3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_commutator_moment3(lhs: Motor3, rhs: Moment3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_dot_moment3(lhs: Motor3, rhs: Moment3) -> Moment3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_geometric_line3(lhs: Motor3, rhs: Line3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_geometric_line3(lhs: Motor3, rhs: Line3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dot_line3(lhs: Motor3, rhs: Line3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_wedge_line3(lhs: Motor3, rhs: Line3) -> Motor3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_wedge_line3(lhs: Motor3, rhs: Line3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_left_contraction_line3(lhs: Motor3, rhs: Line3) -> Motor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_right_contraction_line3(lhs: Motor3, rhs: Line3) -> Motor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_scalar_product_line3(lhs: Motor3, rhs: Line3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_commutator_line3(lhs: Motor3, rhs: Line3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_dot_line3(lhs: Motor3, rhs: Line3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_geometric_plane(lhs: Motor3, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_geometric_plane(lhs: Motor3, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dot_plane(lhs: Motor3, rhs: Plane) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_wedge_plane(lhs: Motor3, rhs: Plane) -> Plane {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_wedge_plane(lhs: Motor3, rhs: Plane) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_left_contraction_plane(lhs: Motor3, rhs: Plane) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_right_contraction_plane(lhs: Motor3, rhs: Plane) -> W {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_commutator_plane(lhs: Motor3, rhs: Plane) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_dot_plane(lhs: Motor3, rhs: Plane) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_geometric_translator3(lhs: Motor3, rhs: Translator3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_geometric_translator3(lhs: Motor3, rhs: Translator3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dot_translator3(lhs: Motor3, rhs: Translator3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_wedge_translator3(lhs: Motor3, rhs: Translator3) -> Translator3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_wedge_translator3(lhs: Motor3, rhs: Translator3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_left_contraction_translator3(lhs: Motor3, rhs: Translator3) -> Motor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_right_contraction_translator3(lhs: Motor3, rhs: Translator3) -> Motor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_scalar_product_translator3(lhs: Motor3, rhs: Translator3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_commutator_translator3(lhs: Motor3, rhs: Translator3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_dot_translator3(lhs: Motor3, rhs: Translator3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_geometric_rotor3(lhs: Motor3, rhs: Rotor3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_geometric_rotor3(lhs: Motor3, rhs: Rotor3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dot_rotor3(lhs: Motor3, rhs: Rotor3) -> Rotor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_wedge_rotor3(lhs: Motor3, rhs: Rotor3) -> Rotor3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_wedge_rotor3(lhs: Motor3, rhs: Rotor3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_left_contraction_rotor3(lhs: Motor3, rhs: Rotor3) -> Rotor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_commutator_rotor3(lhs: Motor3, rhs: Rotor3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_dot_rotor3(lhs: Motor3, rhs: Rotor3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_geometric_motor3(lhs: Motor3, rhs: Motor3) -> Motor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_geometric_motor3(lhs: Motor3, rhs: Motor3) -> Motor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dot_motor3(lhs: Motor3, rhs: Motor3) -> Motor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_wedge_motor3(lhs: Motor3, rhs: Motor3) -> Motor3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_wedge_motor3(lhs: Motor3, rhs: Motor3) -> Motor3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_left_contraction_motor3(lhs: Motor3, rhs: Motor3) -> Motor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_right_contraction_motor3(lhs: Motor3, rhs: Motor3) -> Motor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_scalar_product_motor3(lhs: Motor3, rhs: Motor3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_commutator_motor3(lhs: Motor3, rhs: Motor3) -> Line3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_dot_motor3(lhs: Motor3, rhs: Motor3) -> Motor3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_geometric_multivector(lhs: Motor3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_geometric_multivector(lhs: Motor3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_dot_multivector(lhs: Motor3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_wedge_multivector(lhs: Motor3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_wedge_multivector(lhs: Motor3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_left_contraction_multivector(lhs: Motor3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_right_contraction_multivector(lhs: Motor3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_scalar_product_multivector(lhs: Motor3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_commutator_multivector(lhs: Motor3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_dot_multivector(lhs: Motor3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_sandwich_vec3(lhs: Motor3, rhs: Vec3) -> Vec3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_sandwich_vec4(lhs: Motor3, rhs: Vec4) -> Vec4 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_sandwich_vec4(lhs: Motor3, rhs: Vec4) -> Vec4 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_sandwich_moment3(lhs: Motor3, rhs: Moment3) -> Moment3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_sandwich_line3(lhs: Motor3, rhs: Line3) -> Line3 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_sandwich_line3(lhs: Motor3, rhs: Line3) -> Line3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_sandwich_plane(lhs: Motor3, rhs: Plane) -> Plane {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_motor3_anti_sandwich_plane(lhs: Motor3, rhs: Plane) -> Plane {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_rcompl(value: Multivector) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_lcompl(value: Multivector) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_rev(value: Multivector) -> Multivector {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_arev(value: Multivector) -> Multivector {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_involute(value: Multivector) -> Multivector {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_conjugate(value: Multivector) -> Multivector {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dual(value: Multivector) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_bulk(value: Multivector) -> Multivector {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_weight(value: Multivector) -> Multivector {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_bulk_dual(value: Multivector) -> Multivector {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_weight_dual(value: Multivector) -> Multivector {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_geometric_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_geometric_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dot_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_wedge_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_wedge_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_left_contraction_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_right_contraction_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_scalar_product_vec3(lhs: Multivector, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_commutator_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_dot_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_geometric_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_geometric_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dot_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_wedge_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_wedge_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_left_contraction_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_right_contraction_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_scalar_product_vec4(lhs: Multivector, rhs: Vec4) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_commutator_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_dot_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_geometric_point3(lhs: Multivector, rhs: Point3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_geometric_point3(lhs: Multivector, rhs: Point3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dot_point3(lhs: Multivector, rhs: Point3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_wedge_point3(lhs: Multivector, rhs: Point3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_wedge_point3(lhs: Multivector, rhs: Point3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_left_contraction_point3(lhs: Multivector, rhs: Point3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_right_contraction_point3(lhs: Multivector, rhs: Point3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_scalar_product_point3(lhs: Multivector, rhs: Point3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_commutator_point3(lhs: Multivector, rhs: Point3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_dot_point3(lhs: Multivector, rhs: Point3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_geometric_moment3(lhs: Multivector, rhs: Moment3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_geometric_moment3(lhs: Multivector, rhs: Moment3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dot_moment3(lhs: Multivector, rhs: Moment3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_wedge_moment3(lhs: Multivector, rhs: Moment3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_wedge_moment3(lhs: Multivector, rhs: Moment3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_left_contraction_moment3(lhs: Multivector, rhs: Moment3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_right_contraction_moment3(lhs: Multivector, rhs: Moment3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_scalar_product_moment3(lhs: Multivector, rhs: Moment3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_commutator_moment3(lhs: Multivector, rhs: Moment3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_dot_moment3(lhs: Multivector, rhs: Moment3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_geometric_line3(lhs: Multivector, rhs: Line3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_geometric_line3(lhs: Multivector, rhs: Line3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dot_line3(lhs: Multivector, rhs: Line3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_wedge_line3(lhs: Multivector, rhs: Line3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_wedge_line3(lhs: Multivector, rhs: Line3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_left_contraction_line3(lhs: Multivector, rhs: Line3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_right_contraction_line3(lhs: Multivector, rhs: Line3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_scalar_product_line3(lhs: Multivector, rhs: Line3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_commutator_line3(lhs: Multivector, rhs: Line3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_dot_line3(lhs: Multivector, rhs: Line3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_geometric_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_geometric_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dot_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_wedge_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_wedge_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_left_contraction_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_right_contraction_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_scalar_product_plane(lhs: Multivector, rhs: Plane) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_commutator_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_dot_plane(lhs: Multivector, rhs: Plane) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_geometric_translator3(lhs: Multivector, rhs: Translator3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_geometric_translator3(lhs: Multivector, rhs: Translator3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dot_translator3(lhs: Multivector, rhs: Translator3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_wedge_translator3(lhs: Multivector, rhs: Translator3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_wedge_translator3(lhs: Multivector, rhs: Translator3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_left_contraction_translator3(lhs: Multivector, rhs: Translator3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_right_contraction_translator3(lhs: Multivector, rhs: Translator3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_scalar_product_translator3(lhs: Multivector, rhs: Translator3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_commutator_translator3(lhs: Multivector, rhs: Translator3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_dot_translator3(lhs: Multivector, rhs: Translator3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_geometric_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_geometric_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dot_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_wedge_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_wedge_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_left_contraction_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_commutator_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_dot_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_geometric_motor3(lhs: Multivector, rhs: Motor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_geometric_motor3(lhs: Multivector, rhs: Motor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dot_motor3(lhs: Multivector, rhs: Motor3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_wedge_motor3(lhs: Multivector, rhs: Motor3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_wedge_motor3(lhs: Multivector, rhs: Motor3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_left_contraction_motor3(lhs: Multivector, rhs: Motor3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_right_contraction_motor3(lhs: Multivector, rhs: Motor3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_scalar_product_motor3(lhs: Multivector, rhs: Motor3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_commutator_motor3(lhs: Multivector, rhs: Motor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_dot_motor3(lhs: Multivector, rhs: Motor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_geometric_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_geometric_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_dot_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_wedge_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_wedge_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_left_contraction_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_right_contraction_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_scalar_product_multivector(lhs: Multivector, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_commutator_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn pga3d_multivector_anti_dot_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
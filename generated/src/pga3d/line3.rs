@@ -2,39 +2,173 @@
 //!
 //! ## Operations
 //! ```text
+//! Line3.geometric(Line3) -> Motor3
+//! Line3.anti_geometric(Line3) -> Motor3
 //! Line3.dot(Line3) -> S
 //! Line3.wedge(Line3) -> XYZW
 //! Line3.anti_wedge(Line3) -> S
+//! Line3.left_contraction(Line3) -> S
+//! Line3.right_contraction(Line3) -> S
+//! Line3.scalar_product(Line3) -> S
+//! Line3.commutator(Line3) -> Line3
+//! Line3.anti_dot(Line3) -> XYZW
+//! Line3.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Line3) -> Multivector
+//! Line3.anti_geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Line3) -> Multivector
 //! Line3.dot(Vec3) -> Vec4
 //! Vec3.dot(Line3) -> Vec4
 //! Line3.wedge(Vec3) -> Plane
 //! Vec3.wedge(Line3) -> Plane
+//! Vec3.left_contraction(Line3) -> Vec4
+//! Line3.right_contraction(Vec3) -> Vec4
+//! Line3.commutator(Vec3) -> Vec4
+//! Vec3.commutator(Line3) -> Vec4
+//! Line3.anti_dot(Vec3) -> XYZ
+//! Vec3.anti_dot(Line3) -> XYZ
+//! Line3.geometric(Vec4) -> Multivector
+//! Vec4.geometric(Line3) -> Multivector
+//! Line3.anti_geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Line3) -> Multivector
 //! Line3.dot(Vec4) -> Vec4
 //! Vec4.dot(Line3) -> Vec4
 //! Line3.wedge(Vec4) -> Plane
 //! Vec4.wedge(Line3) -> Plane
+//! Vec4.left_contraction(Line3) -> Vec4
+//! Line3.right_contraction(Vec4) -> Vec4
+//! Line3.commutator(Vec4) -> Vec4
+//! Vec4.commutator(Line3) -> Vec4
+//! Line3.anti_dot(Vec4) -> Plane
+//! Vec4.anti_dot(Line3) -> Plane
+//! Line3.geometric(Point3) -> Multivector
+//! Point3.geometric(Line3) -> Multivector
+//! Line3.anti_geometric(Point3) -> Multivector
+//! Point3.anti_geometric(Line3) -> Multivector
+//! Line3.dot(Point3) -> Vec4
+//! Point3.dot(Line3) -> Vec4
+//! Line3.wedge(Point3) -> Plane
+//! Point3.wedge(Line3) -> Plane
+//! Point3.left_contraction(Line3) -> Vec4
+//! Line3.right_contraction(Point3) -> Vec4
+//! Line3.commutator(Point3) -> Vec4
+//! Point3.commutator(Line3) -> Vec4
+//! Line3.anti_dot(Point3) -> Plane
+//! Point3.anti_dot(Line3) -> Plane
+//! Line3.geometric(Moment3) -> Motor3
+//! Moment3.geometric(Line3) -> Motor3
+//! Line3.anti_geometric(Moment3) -> Motor3
+//! Moment3.anti_geometric(Line3) -> Motor3
 //! Line3.dot(Moment3) -> S
 //! Moment3.dot(Line3) -> S
 //! Line3.wedge(Moment3) -> XYZW
 //! Moment3.wedge(Line3) -> XYZW
 //! Line3.anti_wedge(Moment3) -> S
 //! Moment3.anti_wedge(Line3) -> S
+//! Line3.left_contraction(Moment3) -> S
+//! Moment3.left_contraction(Line3) -> S
+//! Line3.right_contraction(Moment3) -> S
+//! Moment3.right_contraction(Line3) -> S
+//! Line3.scalar_product(Moment3) -> S
+//! Moment3.scalar_product(Line3) -> S
+//! Line3.commutator(Moment3) -> Line3
+//! Moment3.commutator(Line3) -> Line3
+//! Line3.geometric(Plane) -> Multivector
+//! Plane.geometric(Line3) -> Multivector
+//! Line3.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Line3) -> Multivector
 //! Line3.dot(Plane) -> Vec4
 //! Plane.dot(Line3) -> Vec4
 //! Line3.anti_wedge(Plane) -> Vec4
 //! Plane.anti_wedge(Line3) -> Vec4
+//! Line3.left_contraction(Plane) -> Vec4
+//! Plane.right_contraction(Line3) -> Vec4
+//! Line3.commutator(Plane) -> Plane
+//! Plane.commutator(Line3) -> Plane
+//! Line3.anti_dot(Plane) -> Plane
+//! Plane.anti_dot(Line3) -> Plane
+//! Line3.geometric(Translator3) -> Motor3
+//! Translator3.geometric(Line3) -> Motor3
+//! Line3.anti_geometric(Translator3) -> Motor3
+//! Translator3.anti_geometric(Line3) -> Motor3
+//! Line3.dot(Translator3) -> Motor3
+//! Translator3.dot(Line3) -> Motor3
+//! Line3.wedge(Translator3) -> XYZW
+//! Translator3.wedge(Line3) -> XYZW
+//! Line3.anti_wedge(Translator3) -> Motor3
+//! Translator3.anti_wedge(Line3) -> Motor3
+//! Line3.left_contraction(Translator3) -> Motor3
+//! Translator3.left_contraction(Line3) -> S
+//! Line3.right_contraction(Translator3) -> S
+//! Translator3.right_contraction(Line3) -> Motor3
+//! Line3.scalar_product(Translator3) -> S
+//! Translator3.scalar_product(Line3) -> S
+//! Line3.commutator(Translator3) -> Line3
+//! Translator3.commutator(Line3) -> Line3
+//! Line3.anti_dot(Translator3) -> Line3
+//! Translator3.anti_dot(Line3) -> Line3
 //! Line3.geometric(Rotor3) -> Rotor3
 //! Rotor3.geometric(Line3) -> Rotor3
+//! Line3.anti_geometric(Rotor3) -> Motor3
+//! Rotor3.anti_geometric(Line3) -> Motor3
 //! Line3.dot(Rotor3) -> Line3
 //! Rotor3.dot(Line3) -> Line3
 //! Line3.wedge(Rotor3) -> XYZW
 //! Rotor3.wedge(Line3) -> XYZW
-//!
+//! Line3.anti_wedge(Rotor3) -> Motor3
+//! Rotor3.anti_wedge(Line3) -> Motor3
+//! Line3.left_contraction(Rotor3) -> Line3
+//! Rotor3.right_contraction(Line3) -> Line3
+//! Line3.commutator(Rotor3) -> Line3
+//! Rotor3.commutator(Line3) -> Line3
+//! Line3.anti_dot(Rotor3) -> Motor3
+//! Rotor3.anti_dot(Line3) -> Motor3
+//! Line3.geometric(Motor3) -> Motor3
+//! Motor3.geometric(Line3) -> Motor3
+//! Line3.anti_geometric(Motor3) -> Motor3
+//! Motor3.anti_geometric(Line3) -> Motor3
+//! Line3.dot(Motor3) -> Motor3
+//! Motor3.dot(Line3) -> Motor3
+//! Line3.wedge(Motor3) -> Motor3
+//! Motor3.wedge(Line3) -> Motor3
+//! Line3.anti_wedge(Motor3) -> Motor3
+//! Motor3.anti_wedge(Line3) -> Motor3
+//! Line3.left_contraction(Motor3) -> Motor3
+//! Motor3.left_contraction(Line3) -> Motor3
+//! Line3.right_contraction(Motor3) -> Motor3
+//! Motor3.right_contraction(Line3) -> Motor3
+//! Line3.scalar_product(Motor3) -> S
+//! Motor3.scalar_product(Line3) -> S
+//! Line3.commutator(Motor3) -> Line3
+//! Motor3.commutator(Line3) -> Line3
+//! Line3.anti_dot(Motor3) -> Motor3
+//! Motor3.anti_dot(Line3) -> Motor3
+//! Line3.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Line3) -> Multivector
+//! Line3.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Line3) -> Multivector
+//! Line3.dot(Multivector) -> Multivector
+//! Multivector.dot(Line3) -> Multivector
+//! Line3.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Line3) -> Multivector
+//! Line3.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Line3) -> Multivector
+//! Line3.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Line3) -> Multivector
+//! Line3.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Line3) -> Multivector
+//! Line3.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Line3) -> S
+//! Line3.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Line3) -> Multivector
+//! Line3.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Line3) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line3 {
 	pub vx: WX,
 	pub vy: WY,
@@ -44,32 +178,81 @@ pub struct Line3 {
 	pub mz: XY,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Line3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Line3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Line3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.vx.abs_diff_eq(&other.vx, epsilon)
+			&& self.vy.abs_diff_eq(&other.vy, epsilon)
+			&& self.vz.abs_diff_eq(&other.vz, epsilon)
+			&& self.mx.abs_diff_eq(&other.mx, epsilon)
+			&& self.my.abs_diff_eq(&other.my, epsilon)
+			&& self.mz.abs_diff_eq(&other.mz, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Line3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.vx.relative_eq(&other.vx, epsilon, max_relative)
+			&& self.vy.relative_eq(&other.vy, epsilon, max_relative)
+			&& self.vz.relative_eq(&other.vz, epsilon, max_relative)
+			&& self.mx.relative_eq(&other.mx, epsilon, max_relative)
+			&& self.my.relative_eq(&other.my, epsilon, max_relative)
+			&& self.mz.relative_eq(&other.mz, epsilon, max_relative)
+	}
+}
+
 // ---------------------------------------------------------------------
 
 impl RCompl for Line3 {
 	type Output = Line3;
 	fn rcompl(self) -> Self::Output {
 		Line3 {
-			vx: -self.mx.rcompl(),
-			vy: -self.my.rcompl(),
-			vz: -self.mz.rcompl(),
-			mx: -self.vx.rcompl(),
-			my: -self.vy.rcompl(),
-			mz: -self.vz.rcompl(),
+			vx: self.mx.rcompl(),
+			vy: self.my.rcompl(),
+			vz: self.mz.rcompl(),
+			mx: self.vx.rcompl(),
+			my: self.vy.rcompl(),
+			mz: self.vz.rcompl(),
 		}
 	}
 }
 
+impl std::ops::Not for Line3 {
+	type Output = Line3;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl LCompl for Line3 {
 	type Output = Line3;
 	fn lcompl(self) -> Self::Output {
 		Line3 {
-			vx: -self.mx.lcompl(),
-			vy: -self.my.lcompl(),
-			vz: -self.mz.lcompl(),
-			mx: -self.vx.lcompl(),
-			my: -self.vy.lcompl(),
-			mz: -self.vz.lcompl(),
+			vx: self.mx.lcompl(),
+			vy: self.my.lcompl(),
+			vz: self.mz.lcompl(),
+			mx: self.vx.lcompl(),
+			my: self.vy.lcompl(),
+			mz: self.vz.lcompl(),
 		}
 	}
 }
@@ -100,216 +283,1821 @@ impl AntiReverse for Line3 {
 	}
 }
 
+impl Involute for Line3 {
+	fn involute(self) -> Self {
+		Line3 {
+			vx: self.vx,
+			vy: self.vy,
+			vz: self.vz,
+			mx: self.mx,
+			my: self.my,
+			mz: self.mz,
+		}
+	}
+}
+
+impl Conjugate for Line3 {
+	fn conjugate(self) -> Self {
+		Line3 {
+			vx: -self.vx,
+			vy: -self.vy,
+			vz: -self.vz,
+			mx: -self.mx,
+			my: -self.my,
+			mz: -self.mz,
+		}
+	}
+}
+
+impl Dual for Line3 {
+	type Output = Line3;
+	fn dual(self) -> Self::Output {
+		Line3 {
+			vx: self.mx.dual(),
+			vy: self.my.dual(),
+			vz: self.mz.dual(),
+			mx: self.vx.dual(),
+			my: self.vy.dual(),
+			mz: self.vz.dual(),
+		}
+	}
+}
+
+impl Bulk for Line3 {
+	type Output = Moment3;
+	fn bulk(self) -> Self::Output {
+		Moment3 {
+			mx: self.mx,
+			my: self.my,
+			mz: self.mz,
+		}
+	}
+}
+
+impl Weight for Line3 {
+	type Output = Line3;
+	fn weight(self) -> Self::Output {
+		Line3 {
+			vx: self.vx,
+			vy: self.vy,
+			vz: self.vz,
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl BulkDual for Line3 {
+	type Output = Line3;
+	fn bulk_dual(self) -> Self::Output {
+		Line3 {
+			vx: self.mx.bulk_dual(),
+			vy: self.my.bulk_dual(),
+			vz: self.mz.bulk_dual(),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl WeightDual for Line3 {
+	type Output = Moment3;
+	fn weight_dual(self) -> Self::Output {
+		Moment3 {
+			mx: self.vx.weight_dual(),
+			my: self.vy.weight_dual(),
+			mz: self.vz.weight_dual(),
+		}
+	}
+}
+
+// Omitted: Line3.square()  (not a scalar)
+
+impl Norm for Line3 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.vx.0 * self.vx.0
+			+ self.vy.0 * self.vy.0
+			+ self.vz.0 * self.vz.0
+			+ self.mx.0 * self.mx.0
+			+ self.my.0 * self.my.0
+			+ self.mz.0 * self.mz.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.mx.0 * self.mx.0 + self.my.0 * self.my.0 + self.mz.0 * self.mz.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.vx.0 * self.vx.0 + self.vy.0 * self.vy.0 + self.vz.0 * self.vz.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.weight_norm();
+		Line3 {
+			vx: self.vx * scale,
+			vy: self.vy * scale,
+			vz: self.vz * scale,
+			mx: self.mx * scale,
+			my: self.my * scale,
+			mz: self.mz * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Line3 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 6] {
+		[self.vx.0, self.vy.0, self.vz.0, self.mx.0, self.my.0, self.mz.0]
+	}
+}
+
+impl From<[f64; 6]> for Line3 {
+	fn from(a: [f64; 6]) -> Self {
+		Line3 {
+			vx: WX(a[0]),
+			vy: WY(a[1]),
+			vz: WZ(a[2]),
+			mx: YZ(a[3]),
+			my: ZX(a[4]),
+			mz: XY(a[5]),
+		}
+	}
+}
+
+impl From<Line3> for [f64; 6] {
+	fn from(v: Line3) -> Self {
+		v.to_array()
+	}
+}
+
+impl Line3 {
+	/// The line through `a` and `b`, directed from `a` to `b`.
+	/// Same as `Point3::from(a) ^ Point3::from(b)`.
+	pub fn from_points(a: [f64; 3], b: [f64; 3]) -> Self {
+		let dir = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+		Self::from_point_and_direction(a, dir)
+	}
+
+	/// The line through `point` with the given direction.
+	pub fn from_point_and_direction(point: [f64; 3], dir: [f64; 3]) -> Self {
+		let [x, y, z] = point;
+		Line3 {
+			vx: WX(dir[0]),
+			vy: WY(dir[1]),
+			vz: WZ(dir[2]),
+			mx: YZ(y * dir[2] - z * dir[1]),
+			my: ZX(z * dir[0] - x * dir[2]),
+			mz: XY(x * dir[1] - y * dir[0]),
+		}
+	}
+}
+
+impl From<Line3> for Multivector {
+	fn from(v: Line3) -> Self {
+		Multivector {
+			wx: v.vx,
+			wy: v.vy,
+			wz: v.vz,
+			yz: v.mx,
+			zx: v.my,
+			xy: v.mz,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Line3 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Line3` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.x.0 == 0.0
+			&& mv.y.0 == 0.0
+			&& mv.z.0 == 0.0
+			&& mv.w.0 == 0.0
+			&& mv.yzw.0 == 0.0
+			&& mv.zxw.0 == 0.0
+			&& mv.xyw.0 == 0.0
+			&& mv.xyz.0 == 0.0
+			&& mv.xyzw.0 == 0.0
+		{
+			Ok(Line3 {
+				vx: mv.wx,
+				vy: mv.wy,
+				vz: mv.wz,
+				mx: mv.yz,
+				my: mv.zx,
+				mz: mv.xy,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Exp for Line3 {
+	type Output = Motor3;
+
+	/// The screw motion along the line: rotating by twice the weight norm around it,
+	/// and translating by twice the moment along it.
+	/// A line with zero weight (a line at infinity) gives a pure translation.
+	fn exp(self) -> Self::Output {
+		let vv = self.vx.0 * self.vx.0 + self.vy.0 * self.vy.0 + self.vz.0 * self.vz.0;
+		if vv == 0.0 {
+			return Motor3 {
+				rx: WX(0.0),
+				ry: WY(0.0),
+				rz: WZ(0.0),
+				rw: XYZW(1.0),
+				ux: self.mx,
+				uy: self.my,
+				uz: self.mz,
+				uw: S(0.0),
+			};
+		}
+		let angle = vv.sqrt();
+		let (sin, cos) = (angle.sin(), angle.cos());
+		let vm = self.vx.0 * self.mx.0 + self.vy.0 * self.my.0 + self.vz.0 * self.mz.0;
+		// The distance along the axis:
+		let dist = vm / angle;
+		// The moment is `perp + (vm / vv) * v`, where `perp` is orthogonal to `v`:
+		let parallel = vm / vv;
+		let perp_scale = sin / angle;
+		let v_scale = dist * cos / angle;
+		Motor3 {
+			rx: self.vx * perp_scale,
+			ry: self.vy * perp_scale,
+			rz: self.vz * perp_scale,
+			rw: XYZW(cos),
+			ux: YZ(perp_scale * (self.mx.0 - parallel * self.vx.0) + v_scale * self.vx.0),
+			uy: ZX(perp_scale * (self.my.0 - parallel * self.vy.0) + v_scale * self.vy.0),
+			uz: XY(perp_scale * (self.mz.0 - parallel * self.vz.0) + v_scale * self.vz.0),
+			uw: S(-dist * sin),
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Line3 OP Vec3:
 
-// Omitted: Line3 geometric Vec3 = self.mx * rhs.x + self.mx * rhs.y + self.mx * rhs.z + self.my * rhs.x + self.my * rhs.y + self.my * rhs.z + self.mz * rhs.x + self.mz * rhs.y + self.mz * rhs.z + self.vx * rhs.x + self.vx * rhs.y + self.vx * rhs.z + self.vy * rhs.x + self.vy * rhs.y + self.vy * rhs.z + self.vz * rhs.x + self.vz * rhs.y + self.vz * rhs.z  (unnamed type)
-// Omitted: Line3 anti_geometric Vec3 = self.vx !* rhs.x + self.vx !* rhs.y + self.vx !* rhs.z + self.vy !* rhs.x + self.vy !* rhs.y + self.vy !* rhs.z + self.vz !* rhs.x + self.vz !* rhs.y + self.vz !* rhs.z  (unnamed type)
+// Line3.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Line3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w   : W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.vy.0 * rhs.z.0) - YZW(self.vz.0 * rhs.y.0),
+		//     zxw : -ZXW(self.vx.0 * rhs.z.0) + ZXW(self.vz.0 * rhs.x.0),
+		//     xyw : XYW(self.vx.0 * rhs.y.0) - XYW(self.vy.0 * rhs.x.0),
+		//     xyz : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			y: self.mx.geometric(rhs.z) + self.mz.geometric(rhs.x),
+			z: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x),
+			w: self.vx.geometric(rhs.x) + self.vy.geometric(rhs.y) + self.vz.geometric(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.vy.geometric(rhs.z) + self.vz.geometric(rhs.y),
+			zxw: self.vx.geometric(rhs.z) + self.vz.geometric(rhs.x),
+			xyw: self.vx.geometric(rhs.y) + self.vy.geometric(rhs.x),
+			xyz: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Line3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line3.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Line3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.vy.0 * rhs.z.0) - X(self.vz.0 * rhs.y.0),
+		//     y   : -Y(self.vx.0 * rhs.z.0) + Y(self.vz.0 * rhs.x.0),
+		//     z   : Z(self.vx.0 * rhs.y.0) - Z(self.vy.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.vx.0 * rhs.x.0) + XYZ(self.vy.0 * rhs.y.0) + XYZ(self.vz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.vy.anti_geometric(rhs.z) + self.vz.anti_geometric(rhs.y),
+			y: self.vx.anti_geometric(rhs.z) + self.vz.anti_geometric(rhs.x),
+			z: self.vx.anti_geometric(rhs.y) + self.vy.anti_geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.vx.anti_geometric(rhs.x) + self.vy.anti_geometric(rhs.y) + self.vz.anti_geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
 
 // Line3.dot(Vec3) -> Vec4
 impl Dot<Vec3> for Line3 {
 	type Output = Vec4;
 	fn dot(self, rhs: Vec3) -> Self::Output {
 		// Vec4 {
-		//     x: X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
-		//     y: Y(self.mx.0 * rhs.z.0) + Y(self.mz.0 * rhs.x.0),
-		//     z: Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
 		//     w: W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
 		// }
 		Vec4 {
-			x: -self.my.dot(rhs.z) + self.mz.dot(rhs.y),
-			y: self.mx.dot(rhs.z) - self.mz.dot(rhs.x),
-			z: -self.mx.dot(rhs.y) + self.my.dot(rhs.x),
+			x: self.my.dot(rhs.z) + self.mz.dot(rhs.y),
+			y: self.mx.dot(rhs.z) + self.mz.dot(rhs.x),
+			z: self.mx.dot(rhs.y) + self.my.dot(rhs.x),
 			w: self.vx.dot(rhs.x) + self.vy.dot(rhs.y) + self.vz.dot(rhs.z),
 		}
 	}
 }
 
+impl std::ops::BitOr<Vec3> for Line3 {
+	type Output = Vec4;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Line3.wedge(Vec3) -> Plane
 impl Wedge<Vec3> for Line3 {
 	type Output = Plane;
 	fn wedge(self, rhs: Vec3) -> Self::Output {
 		// Plane {
-		//     nx: YZW(self.vy.0 * rhs.z.0) + YZW(self.vz.0 * rhs.y.0),
-		//     ny: ZXW(self.vx.0 * rhs.z.0) + ZXW(self.vz.0 * rhs.x.0),
-		//     nz: XYW(self.vx.0 * rhs.y.0) + XYW(self.vy.0 * rhs.x.0),
+		//     nx: YZW(self.vy.0 * rhs.z.0) - YZW(self.vz.0 * rhs.y.0),
+		//     ny: -ZXW(self.vx.0 * rhs.z.0) + ZXW(self.vz.0 * rhs.x.0),
+		//     nz: XYW(self.vx.0 * rhs.y.0) - XYW(self.vy.0 * rhs.x.0),
 		//     d : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
 		// }
 		Plane {
-			nx: self.vy.wedge(rhs.z) - self.vz.wedge(rhs.y),
-			ny: -self.vx.wedge(rhs.z) + self.vz.wedge(rhs.x),
-			nz: self.vx.wedge(rhs.y) - self.vy.wedge(rhs.x),
+			nx: self.vy.wedge(rhs.z) + self.vz.wedge(rhs.y),
+			ny: self.vx.wedge(rhs.z) + self.vz.wedge(rhs.x),
+			nz: self.vx.wedge(rhs.y) + self.vy.wedge(rhs.x),
 			d: self.mx.wedge(rhs.x) + self.my.wedge(rhs.y) + self.mz.wedge(rhs.z),
 		}
 	}
 }
 
-// Omitted: Line3 anti_wedge Vec3 = 0  (unnamed type)
+impl std::ops::BitXor<Vec3> for Line3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for Line3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Line3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for Line3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line3.right_contraction(Vec3) -> Vec4
+impl RightContraction<Vec3> for Line3 {
+	type Output = Vec4;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w: W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		// }
+		Vec4 {
+			x: self.my.right_contraction(rhs.z) + self.mz.right_contraction(rhs.y),
+			y: self.mx.right_contraction(rhs.z) + self.mz.right_contraction(rhs.x),
+			z: self.mx.right_contraction(rhs.y) + self.my.right_contraction(rhs.x),
+			w: self.vx.right_contraction(rhs.x) + self.vy.right_contraction(rhs.y) + self.vz.right_contraction(rhs.z),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Line3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line3.commutator(Vec3) -> Vec4
+impl Commutator<Vec3> for Line3 {
+	type Output = Vec4;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w: W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		// }
+		Vec4 {
+			x: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			y: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			z: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+			w: self.vx.commutator(rhs.x) + self.vy.commutator(rhs.y) + self.vz.commutator(rhs.z),
+		}
+	}
+}
+
+// Line3.anti_dot(Vec3) -> XYZ
+impl AntiDot<Vec3> for Line3 {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// XYZ(self.vx.0 * rhs.x.0) + XYZ(self.vy.0 * rhs.y.0) + XYZ(self.vz.0 * rhs.z.0)
+		self.vx.anti_dot(rhs.x) + self.vy.anti_dot(rhs.y) + self.vz.anti_dot(rhs.z)
+	}
+}
 
 // ---------------------------------------------------------------------
 // Line3 OP Vec4:
 
-// Omitted: Line3 geometric Vec4 = self.mx * rhs.w + self.mx * rhs.x + self.mx * rhs.y + self.mx * rhs.z + self.my * rhs.w + self.my * rhs.x + self.my * rhs.y + self.my * rhs.z + self.mz * rhs.w + self.mz * rhs.x + self.mz * rhs.y + self.mz * rhs.z + self.vx * rhs.x + self.vx * rhs.y + self.vx * rhs.z + self.vy * rhs.x + self.vy * rhs.y + self.vy * rhs.z + self.vz * rhs.x + self.vz * rhs.y + self.vz * rhs.z  (unnamed type)
-// Omitted: Line3 anti_geometric Vec4 = self.mx !* rhs.w + self.my !* rhs.w + self.mz !* rhs.w + self.vx !* rhs.w + self.vx !* rhs.x + self.vx !* rhs.y + self.vx !* rhs.z + self.vy !* rhs.w + self.vy !* rhs.x + self.vy !* rhs.y + self.vy !* rhs.z + self.vz !* rhs.w + self.vz !* rhs.x + self.vz !* rhs.y + self.vz !* rhs.z  (unnamed type)
+// Line3.geometric(Vec4) -> Multivector
+impl Geometric<Vec4> for Line3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w   : W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.mx.0 * rhs.w.0) + YZW(self.vy.0 * rhs.z.0) - YZW(self.vz.0 * rhs.y.0),
+		//     zxw : ZXW(self.my.0 * rhs.w.0) - ZXW(self.vx.0 * rhs.z.0) + ZXW(self.vz.0 * rhs.x.0),
+		//     xyw : XYW(self.mz.0 * rhs.w.0) + XYW(self.vx.0 * rhs.y.0) - XYW(self.vy.0 * rhs.x.0),
+		//     xyz : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			y: self.mx.geometric(rhs.z) + self.mz.geometric(rhs.x),
+			z: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x),
+			w: self.vx.geometric(rhs.x) + self.vy.geometric(rhs.y) + self.vz.geometric(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.mx.geometric(rhs.w) + self.vy.geometric(rhs.z) + self.vz.geometric(rhs.y),
+			zxw: self.my.geometric(rhs.w) + self.vx.geometric(rhs.z) + self.vz.geometric(rhs.x),
+			xyw: self.mz.geometric(rhs.w) + self.vx.geometric(rhs.y) + self.vy.geometric(rhs.x),
+			xyz: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec4> for Line3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line3.anti_geometric(Vec4) -> Multivector
+impl AntiGeometric<Vec4> for Line3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.mx.0 * rhs.w.0) + X(self.vy.0 * rhs.z.0) - X(self.vz.0 * rhs.y.0),
+		//     y   : Y(self.my.0 * rhs.w.0) - Y(self.vx.0 * rhs.z.0) + Y(self.vz.0 * rhs.x.0),
+		//     z   : Z(self.mz.0 * rhs.w.0) + Z(self.vx.0 * rhs.y.0) - Z(self.vy.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.vx.0 * rhs.w.0),
+		//     zxw : ZXW(self.vy.0 * rhs.w.0),
+		//     xyw : XYW(self.vz.0 * rhs.w.0),
+		//     xyz : XYZ(self.vx.0 * rhs.x.0) + XYZ(self.vy.0 * rhs.y.0) + XYZ(self.vz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.mx.anti_geometric(rhs.w) + self.vy.anti_geometric(rhs.z) + self.vz.anti_geometric(rhs.y),
+			y: self.my.anti_geometric(rhs.w) + self.vx.anti_geometric(rhs.z) + self.vz.anti_geometric(rhs.x),
+			z: self.mz.anti_geometric(rhs.w) + self.vx.anti_geometric(rhs.y) + self.vy.anti_geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.vx.anti_geometric(rhs.w),
+			zxw: self.vy.anti_geometric(rhs.w),
+			xyw: self.vz.anti_geometric(rhs.w),
+			xyz: self.vx.anti_geometric(rhs.x) + self.vy.anti_geometric(rhs.y) + self.vz.anti_geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
 
 // Line3.dot(Vec4) -> Vec4
 impl Dot<Vec4> for Line3 {
 	type Output = Vec4;
 	fn dot(self, rhs: Vec4) -> Self::Output {
 		// Vec4 {
-		//     x: X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
-		//     y: Y(self.mx.0 * rhs.z.0) + Y(self.mz.0 * rhs.x.0),
-		//     z: Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
 		//     w: W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
 		// }
 		Vec4 {
-			x: -self.my.dot(rhs.z) + self.mz.dot(rhs.y),
-			y: self.mx.dot(rhs.z) - self.mz.dot(rhs.x),
-			z: -self.mx.dot(rhs.y) + self.my.dot(rhs.x),
+			x: self.my.dot(rhs.z) + self.mz.dot(rhs.y),
+			y: self.mx.dot(rhs.z) + self.mz.dot(rhs.x),
+			z: self.mx.dot(rhs.y) + self.my.dot(rhs.x),
 			w: self.vx.dot(rhs.x) + self.vy.dot(rhs.y) + self.vz.dot(rhs.z),
 		}
 	}
 }
 
+impl std::ops::BitOr<Vec4> for Line3 {
+	type Output = Vec4;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Line3.wedge(Vec4) -> Plane
 impl Wedge<Vec4> for Line3 {
 	type Output = Plane;
 	fn wedge(self, rhs: Vec4) -> Self::Output {
 		// Plane {
-		//     nx: YZW(self.mx.0 * rhs.w.0) + YZW(self.vy.0 * rhs.z.0) + YZW(self.vz.0 * rhs.y.0),
-		//     ny: ZXW(self.my.0 * rhs.w.0) + ZXW(self.vx.0 * rhs.z.0) + ZXW(self.vz.0 * rhs.x.0),
-		//     nz: XYW(self.mz.0 * rhs.w.0) + XYW(self.vx.0 * rhs.y.0) + XYW(self.vy.0 * rhs.x.0),
+		//     nx: YZW(self.mx.0 * rhs.w.0) + YZW(self.vy.0 * rhs.z.0) - YZW(self.vz.0 * rhs.y.0),
+		//     ny: ZXW(self.my.0 * rhs.w.0) - ZXW(self.vx.0 * rhs.z.0) + ZXW(self.vz.0 * rhs.x.0),
+		//     nz: XYW(self.mz.0 * rhs.w.0) + XYW(self.vx.0 * rhs.y.0) - XYW(self.vy.0 * rhs.x.0),
 		//     d : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
 		// }
 		Plane {
-			nx: self.mx.wedge(rhs.w) + self.vy.wedge(rhs.z) - self.vz.wedge(rhs.y),
-			ny: self.my.wedge(rhs.w) - self.vx.wedge(rhs.z) + self.vz.wedge(rhs.x),
-			nz: self.mz.wedge(rhs.w) + self.vx.wedge(rhs.y) - self.vy.wedge(rhs.x),
+			nx: self.mx.wedge(rhs.w) + self.vy.wedge(rhs.z) + self.vz.wedge(rhs.y),
+			ny: self.my.wedge(rhs.w) + self.vx.wedge(rhs.z) + self.vz.wedge(rhs.x),
+			nz: self.mz.wedge(rhs.w) + self.vx.wedge(rhs.y) + self.vy.wedge(rhs.x),
 			d: self.mx.wedge(rhs.x) + self.my.wedge(rhs.y) + self.mz.wedge(rhs.z),
 		}
 	}
 }
 
-// Omitted: Line3 anti_wedge Vec4 = 0  (unnamed type)
-
-// ---------------------------------------------------------------------
-// Line3 OP Moment3:
-
-// Omitted: Line3 geometric Moment3 = self.mx * rhs.mx + self.mx * rhs.my + self.mx * rhs.mz + self.my * rhs.mx + self.my * rhs.my + self.my * rhs.mz + self.mz * rhs.mx + self.mz * rhs.my + self.mz * rhs.mz + self.vx * rhs.mx + self.vx * rhs.my + self.vx * rhs.mz + self.vy * rhs.mx + self.vy * rhs.my + self.vy * rhs.mz + self.vz * rhs.mx + self.vz * rhs.my + self.vz * rhs.mz  (unnamed type)
-// Omitted: Line3 anti_geometric Moment3 = self.vx !* rhs.mx + self.vx !* rhs.my + self.vx !* rhs.mz + self.vy !* rhs.mx + self.vy !* rhs.my + self.vy !* rhs.mz + self.vz !* rhs.mx + self.vz !* rhs.my + self.vz !* rhs.mz  (unnamed type)
-
-// Line3.dot(Moment3) -> S
-impl Dot<Moment3> for Line3 {
-	type Output = S;
-	fn dot(self, rhs: Moment3) -> Self::Output {
-		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
-		self.mx.dot(rhs.mx) + self.my.dot(rhs.my) + self.mz.dot(rhs.mz)
-	}
-}
-
-// Line3.wedge(Moment3) -> XYZW
-impl Wedge<Moment3> for Line3 {
-	type Output = XYZW;
-	fn wedge(self, rhs: Moment3) -> Self::Output {
-		// -XYZW(self.vx.0 * rhs.mx.0) - XYZW(self.vy.0 * rhs.my.0) - XYZW(self.vz.0 * rhs.mz.0)
-		self.vx.wedge(rhs.mx) + self.vy.wedge(rhs.my) + self.vz.wedge(rhs.mz)
-	}
-}
-
-// Line3.anti_wedge(Moment3) -> S
-impl AntiWedge<Moment3> for Line3 {
-	type Output = S;
-	fn anti_wedge(self, rhs: Moment3) -> Self::Output {
-		// -S(self.vx.0 * rhs.mx.0) - S(self.vy.0 * rhs.my.0) - S(self.vz.0 * rhs.mz.0)
-		self.vx.anti_wedge(rhs.mx) + self.vy.anti_wedge(rhs.my) + self.vz.anti_wedge(rhs.mz)
+impl std::ops::BitXor<Vec4> for Line3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
 	}
 }
 
-// ---------------------------------------------------------------------
-// Line3 OP Line3:
-
-// Omitted: Line3 geometric Line3 = self.mx * rhs.mx + self.mx * rhs.my + self.mx * rhs.mz + self.mx * rhs.vx + self.mx * rhs.vy + self.mx * rhs.vz + self.my * rhs.mx + self.my * rhs.my + self.my * rhs.mz + self.my * rhs.vx + self.my * rhs.vy + self.my * rhs.vz + self.mz * rhs.mx + self.mz * rhs.my + self.mz * rhs.mz + self.mz * rhs.vx + self.mz * rhs.vy + self.mz * rhs.vz + self.vx * rhs.mx + self.vx * rhs.my + self.vx * rhs.mz + self.vy * rhs.mx + self.vy * rhs.my + self.vy * rhs.mz + self.vz * rhs.mx + self.vz * rhs.my + self.vz * rhs.mz  (unnamed type)
-// Omitted: Line3 anti_geometric Line3 = self.mx !* rhs.vx + self.mx !* rhs.vy + self.mx !* rhs.vz + self.my !* rhs.vx + self.my !* rhs.vy + self.my !* rhs.vz + self.mz !* rhs.vx + self.mz !* rhs.vy + self.mz !* rhs.vz + self.vx !* rhs.mx + self.vx !* rhs.my + self.vx !* rhs.mz + self.vx !* rhs.vx + self.vx !* rhs.vy + self.vx !* rhs.vz + self.vy !* rhs.mx + self.vy !* rhs.my + self.vy !* rhs.mz + self.vy !* rhs.vx + self.vy !* rhs.vy + self.vy !* rhs.vz + self.vz !* rhs.mx + self.vz !* rhs.my + self.vz !* rhs.mz + self.vz !* rhs.vx + self.vz !* rhs.vy + self.vz !* rhs.vz  (unnamed type)
-
-// Line3.dot(Line3) -> S
-impl Dot<Line3> for Line3 {
-	type Output = S;
-	fn dot(self, rhs: Line3) -> Self::Output {
-		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
-		self.mx.dot(rhs.mx) + self.my.dot(rhs.my) + self.mz.dot(rhs.mz)
+impl AntiWedge<Vec4> for Line3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
 	}
 }
 
-// Line3.wedge(Line3) -> XYZW
-impl Wedge<Line3> for Line3 {
-	type Output = XYZW;
-	fn wedge(self, rhs: Line3) -> Self::Output {
-		// -XYZW(self.mx.0 * rhs.vx.0) - XYZW(self.my.0 * rhs.vy.0) - XYZW(self.mz.0 * rhs.vz.0) - XYZW(self.vx.0 * rhs.mx.0) - XYZW(self.vy.0 * rhs.my.0) - XYZW(self.vz.0 * rhs.mz.0)
-		self.mx.wedge(rhs.vx)
-			+ self.my.wedge(rhs.vy)
-			+ self.mz.wedge(rhs.vz)
-			+ self.vx.wedge(rhs.mx)
-			+ self.vy.wedge(rhs.my)
-			+ self.vz.wedge(rhs.mz)
+impl std::ops::BitAnd<Vec4> for Line3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-// Line3.anti_wedge(Line3) -> S
-impl AntiWedge<Line3> for Line3 {
-	type Output = S;
-	fn anti_wedge(self, rhs: Line3) -> Self::Output {
-		// -S(self.mx.0 * rhs.vx.0) - S(self.my.0 * rhs.vy.0) - S(self.mz.0 * rhs.vz.0) - S(self.vx.0 * rhs.mx.0) - S(self.vy.0 * rhs.my.0) - S(self.vz.0 * rhs.mz.0)
-		self.mx.anti_wedge(rhs.vx)
-			+ self.my.anti_wedge(rhs.vy)
-			+ self.mz.anti_wedge(rhs.vz)
-			+ self.vx.anti_wedge(rhs.mx)
-			+ self.vy.anti_wedge(rhs.my)
-			+ self.vz.anti_wedge(rhs.mz)
+impl LeftContraction<Vec4> for Line3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
 	}
 }
 
-// ---------------------------------------------------------------------
-// Line3 OP Plane:
-
-// Omitted: Line3 geometric Plane = self.mx * rhs.d + self.mx * rhs.nx + self.mx * rhs.ny + self.mx * rhs.nz + self.my * rhs.d + self.my * rhs.nx + self.my * rhs.ny + self.my * rhs.nz + self.mz * rhs.d + self.mz * rhs.nx + self.mz * rhs.ny + self.mz * rhs.nz + self.vx * rhs.d + self.vy * rhs.d + self.vz * rhs.d  (unnamed type)
-// Omitted: Line3 anti_geometric Plane = self.mx !* rhs.nx + self.mx !* rhs.ny + self.mx !* rhs.nz + self.my !* rhs.nx + self.my !* rhs.ny + self.my !* rhs.nz + self.mz !* rhs.nx + self.mz !* rhs.ny + self.mz !* rhs.nz + self.vx !* rhs.d + self.vx !* rhs.nx + self.vx !* rhs.ny + self.vx !* rhs.nz + self.vy !* rhs.d + self.vy !* rhs.nx + self.vy !* rhs.ny + self.vy !* rhs.nz + self.vz !* rhs.d + self.vz !* rhs.nx + self.vz !* rhs.ny + self.vz !* rhs.nz  (unnamed type)
-
-// Line3.dot(Plane) -> Vec4
-impl Dot<Plane> for Line3 {
+// Line3.right_contraction(Vec4) -> Vec4
+impl RightContraction<Vec4> for Line3 {
 	type Output = Vec4;
-	fn dot(self, rhs: Plane) -> Self::Output {
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
 		// Vec4 {
-		//     x: X(self.mx.0 * rhs.d.0),
-		//     y: Y(self.my.0 * rhs.d.0),
-		//     z: Z(self.mz.0 * rhs.d.0),
-		//     w: W(self.mx.0 * rhs.nx.0) + W(self.my.0 * rhs.ny.0) + W(self.mz.0 * rhs.nz.0),
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w: W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
 		// }
 		Vec4 {
-			x: -self.mx.dot(rhs.d),
-			y: -self.my.dot(rhs.d),
-			z: -self.mz.dot(rhs.d),
-			w: -self.mx.dot(rhs.nx) - self.my.dot(rhs.ny) - self.mz.dot(rhs.nz),
+			x: self.my.right_contraction(rhs.z) + self.mz.right_contraction(rhs.y),
+			y: self.mx.right_contraction(rhs.z) + self.mz.right_contraction(rhs.x),
+			z: self.mx.right_contraction(rhs.y) + self.my.right_contraction(rhs.x),
+			w: self.vx.right_contraction(rhs.x) + self.vy.right_contraction(rhs.y) + self.vz.right_contraction(rhs.z),
 		}
 	}
 }
 
-// Omitted: Line3 wedge Plane = 0  (unnamed type)
+impl ScalarProduct<Vec4> for Line3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
 
-// Line3.anti_wedge(Plane) -> Vec4
-impl AntiWedge<Plane> for Line3 {
+// Line3.commutator(Vec4) -> Vec4
+impl Commutator<Vec4> for Line3 {
 	type Output = Vec4;
-	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+	fn commutator(self, rhs: Vec4) -> Self::Output {
 		// Vec4 {
-		//     x: X(self.my.0 * rhs.nz.0) + X(self.mz.0 * rhs.ny.0) + X(self.vx.0 * rhs.d.0),
-		//     y: Y(self.mx.0 * rhs.nz.0) + Y(self.mz.0 * rhs.nx.0) + Y(self.vy.0 * rhs.d.0),
-		//     z: Z(self.mx.0 * rhs.ny.0) + Z(self.my.0 * rhs.nx.0) + Z(self.vz.0 * rhs.d.0),
-		//     w: W(self.vx.0 * rhs.nx.0) + W(self.vy.0 * rhs.ny.0) + W(self.vz.0 * rhs.nz.0),
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w: W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
 		// }
 		Vec4 {
-			x: self.my.anti_wedge(rhs.nz) - self.mz.anti_wedge(rhs.ny) - self.vx.anti_wedge(rhs.d),
-			y: -self.mx.anti_wedge(rhs.nz) + self.mz.anti_wedge(rhs.nx) - self.vy.anti_wedge(rhs.d),
-			z: self.mx.anti_wedge(rhs.ny) - self.my.anti_wedge(rhs.nx) - self.vz.anti_wedge(rhs.d),
-			w: -self.vx.anti_wedge(rhs.nx) - self.vy.anti_wedge(rhs.ny) - self.vz.anti_wedge(rhs.nz),
+			x: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			y: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			z: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+			w: self.vx.commutator(rhs.x) + self.vy.commutator(rhs.y) + self.vz.commutator(rhs.z),
+		}
+	}
+}
+
+// Line3.anti_dot(Vec4) -> Plane
+impl AntiDot<Vec4> for Line3 {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.vx.0 * rhs.w.0),
+		//     ny: ZXW(self.vy.0 * rhs.w.0),
+		//     nz: XYW(self.vz.0 * rhs.w.0),
+		//     d : XYZ(self.vx.0 * rhs.x.0) + XYZ(self.vy.0 * rhs.y.0) + XYZ(self.vz.0 * rhs.z.0),
+		// }
+		Plane {
+			nx: self.vx.anti_dot(rhs.w),
+			ny: self.vy.anti_dot(rhs.w),
+			nz: self.vz.anti_dot(rhs.w),
+			d: self.vx.anti_dot(rhs.x) + self.vy.anti_dot(rhs.y) + self.vz.anti_dot(rhs.z),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line3 OP Point3:
+
+// Line3.geometric(Point3) -> Multivector
+impl Geometric<Point3> for Line3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w   : W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.mx.0) + YZW(self.vy.0 * rhs.z.0) - YZW(self.vz.0 * rhs.y.0),
+		//     zxw : ZXW(self.my.0) - ZXW(self.vx.0 * rhs.z.0) + ZXW(self.vz.0 * rhs.x.0),
+		//     xyw : XYW(self.mz.0) + XYW(self.vx.0 * rhs.y.0) - XYW(self.vy.0 * rhs.x.0),
+		//     xyz : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			y: self.mx.geometric(rhs.z) + self.mz.geometric(rhs.x),
+			z: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x),
+			w: self.vx.geometric(rhs.x) + self.vy.geometric(rhs.y) + self.vz.geometric(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.mx.geometric(W(1.0)) + self.vy.geometric(rhs.z) + self.vz.geometric(rhs.y),
+			zxw: self.my.geometric(W(1.0)) + self.vx.geometric(rhs.z) + self.vz.geometric(rhs.x),
+			xyw: self.mz.geometric(W(1.0)) + self.vx.geometric(rhs.y) + self.vy.geometric(rhs.x),
+			xyz: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point3> for Line3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Point3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line3.anti_geometric(Point3) -> Multivector
+impl AntiGeometric<Point3> for Line3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.mx.0) + X(self.vy.0 * rhs.z.0) - X(self.vz.0 * rhs.y.0),
+		//     y   : Y(self.my.0) - Y(self.vx.0 * rhs.z.0) + Y(self.vz.0 * rhs.x.0),
+		//     z   : Z(self.mz.0) + Z(self.vx.0 * rhs.y.0) - Z(self.vy.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.vx.0),
+		//     zxw : ZXW(self.vy.0),
+		//     xyw : XYW(self.vz.0),
+		//     xyz : XYZ(self.vx.0 * rhs.x.0) + XYZ(self.vy.0 * rhs.y.0) + XYZ(self.vz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.mx.anti_geometric(W(1.0)) + self.vy.anti_geometric(rhs.z) + self.vz.anti_geometric(rhs.y),
+			y: self.my.anti_geometric(W(1.0)) + self.vx.anti_geometric(rhs.z) + self.vz.anti_geometric(rhs.x),
+			z: self.mz.anti_geometric(W(1.0)) + self.vx.anti_geometric(rhs.y) + self.vy.anti_geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.vx.anti_geometric(W(1.0)),
+			zxw: self.vy.anti_geometric(W(1.0)),
+			xyw: self.vz.anti_geometric(W(1.0)),
+			xyz: self.vx.anti_geometric(rhs.x) + self.vy.anti_geometric(rhs.y) + self.vz.anti_geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Line3.dot(Point3) -> Vec4
+impl Dot<Point3> for Line3 {
+	type Output = Vec4;
+	fn dot(self, rhs: Point3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w: W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		// }
+		Vec4 {
+			x: self.my.dot(rhs.z) + self.mz.dot(rhs.y),
+			y: self.mx.dot(rhs.z) + self.mz.dot(rhs.x),
+			z: self.mx.dot(rhs.y) + self.my.dot(rhs.x),
+			w: self.vx.dot(rhs.x) + self.vy.dot(rhs.y) + self.vz.dot(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point3> for Line3 {
+	type Output = Vec4;
+	fn bitor(self, rhs: Point3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line3.wedge(Point3) -> Plane
+impl Wedge<Point3> for Line3 {
+	type Output = Plane;
+	fn wedge(self, rhs: Point3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.mx.0) + YZW(self.vy.0 * rhs.z.0) - YZW(self.vz.0 * rhs.y.0),
+		//     ny: ZXW(self.my.0) - ZXW(self.vx.0 * rhs.z.0) + ZXW(self.vz.0 * rhs.x.0),
+		//     nz: XYW(self.mz.0) + XYW(self.vx.0 * rhs.y.0) - XYW(self.vy.0 * rhs.x.0),
+		//     d : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		// }
+		Plane {
+			nx: self.mx.wedge(W(1.0)) + self.vy.wedge(rhs.z) + self.vz.wedge(rhs.y),
+			ny: self.my.wedge(W(1.0)) + self.vx.wedge(rhs.z) + self.vz.wedge(rhs.x),
+			nz: self.mz.wedge(W(1.0)) + self.vx.wedge(rhs.y) + self.vy.wedge(rhs.x),
+			d: self.mx.wedge(rhs.x) + self.my.wedge(rhs.y) + self.mz.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point3> for Line3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Point3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point3> for Line3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point3> for Line3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Point3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point3> for Line3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line3.right_contraction(Point3) -> Vec4
+impl RightContraction<Point3> for Line3 {
+	type Output = Vec4;
+	fn right_contraction(self, rhs: Point3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w: W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		// }
+		Vec4 {
+			x: self.my.right_contraction(rhs.z) + self.mz.right_contraction(rhs.y),
+			y: self.mx.right_contraction(rhs.z) + self.mz.right_contraction(rhs.x),
+			z: self.mx.right_contraction(rhs.y) + self.my.right_contraction(rhs.x),
+			w: self.vx.right_contraction(rhs.x) + self.vy.right_contraction(rhs.y) + self.vz.right_contraction(rhs.z),
+		}
+	}
+}
+
+impl ScalarProduct<Point3> for Line3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line3.commutator(Point3) -> Vec4
+impl Commutator<Point3> for Line3 {
+	type Output = Vec4;
+	fn commutator(self, rhs: Point3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w: W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		// }
+		Vec4 {
+			x: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			y: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			z: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+			w: self.vx.commutator(rhs.x) + self.vy.commutator(rhs.y) + self.vz.commutator(rhs.z),
+		}
+	}
+}
+
+// Line3.anti_dot(Point3) -> Plane
+impl AntiDot<Point3> for Line3 {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Point3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.vx.0),
+		//     ny: ZXW(self.vy.0),
+		//     nz: XYW(self.vz.0),
+		//     d : XYZ(self.vx.0 * rhs.x.0) + XYZ(self.vy.0 * rhs.y.0) + XYZ(self.vz.0 * rhs.z.0),
+		// }
+		Plane {
+			nx: self.vx.anti_dot(W(1.0)),
+			ny: self.vy.anti_dot(W(1.0)),
+			nz: self.vz.anti_dot(W(1.0)),
+			d: self.vx.anti_dot(rhs.x) + self.vy.anti_dot(rhs.y) + self.vz.anti_dot(rhs.z),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line3 OP Moment3:
+
+// Line3.geometric(Moment3) -> Motor3
+impl Geometric<Moment3> for Line3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: -WX(self.vy.0 * rhs.mz.0) + WX(self.vz.0 * rhs.my.0),
+		//     ry: WY(self.vx.0 * rhs.mz.0) - WY(self.vz.0 * rhs.mx.0),
+		//     rz: -WZ(self.vx.0 * rhs.my.0) + WZ(self.vy.0 * rhs.mx.0),
+		//     rw: -XYZW(self.vx.0 * rhs.mx.0) - XYZW(self.vy.0 * rhs.my.0) - XYZW(self.vz.0 * rhs.mz.0),
+		//     ux: -YZ(self.my.0 * rhs.mz.0) + YZ(self.mz.0 * rhs.my.0),
+		//     uy: ZX(self.mx.0 * rhs.mz.0) - ZX(self.mz.0 * rhs.mx.0),
+		//     uz: -XY(self.mx.0 * rhs.my.0) + XY(self.my.0 * rhs.mx.0),
+		//     uw: -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.vy.geometric(rhs.mz) + self.vz.geometric(rhs.my),
+			ry: self.vx.geometric(rhs.mz) + self.vz.geometric(rhs.mx),
+			rz: self.vx.geometric(rhs.my) + self.vy.geometric(rhs.mx),
+			rw: self.vx.geometric(rhs.mx) + self.vy.geometric(rhs.my) + self.vz.geometric(rhs.mz),
+			ux: self.my.geometric(rhs.mz) + self.mz.geometric(rhs.my),
+			uy: self.mx.geometric(rhs.mz) + self.mz.geometric(rhs.mx),
+			uz: self.mx.geometric(rhs.my) + self.my.geometric(rhs.mx),
+			uw: self.mx.geometric(rhs.mx) + self.my.geometric(rhs.my) + self.mz.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Moment3> for Line3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Moment3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line3.anti_geometric(Moment3) -> Motor3
+impl AntiGeometric<Moment3> for Line3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.vy.0 * rhs.mz.0) - YZ(self.vz.0 * rhs.my.0),
+		//     uy: -ZX(self.vx.0 * rhs.mz.0) + ZX(self.vz.0 * rhs.mx.0),
+		//     uz: XY(self.vx.0 * rhs.my.0) - XY(self.vy.0 * rhs.mx.0),
+		//     uw: -S(self.vx.0 * rhs.mx.0) - S(self.vy.0 * rhs.my.0) - S(self.vz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.vy.anti_geometric(rhs.mz) + self.vz.anti_geometric(rhs.my),
+			uy: self.vx.anti_geometric(rhs.mz) + self.vz.anti_geometric(rhs.mx),
+			uz: self.vx.anti_geometric(rhs.my) + self.vy.anti_geometric(rhs.mx),
+			uw: self.vx.anti_geometric(rhs.mx) + self.vy.anti_geometric(rhs.my) + self.vz.anti_geometric(rhs.mz),
+		}
+	}
+}
+
+// Line3.dot(Moment3) -> S
+impl Dot<Moment3> for Line3 {
+	type Output = S;
+	fn dot(self, rhs: Moment3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.dot(rhs.mx) + self.my.dot(rhs.my) + self.mz.dot(rhs.mz)
+	}
+}
+
+impl std::ops::BitOr<Moment3> for Line3 {
+	type Output = S;
+	fn bitor(self, rhs: Moment3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line3.wedge(Moment3) -> XYZW
+impl Wedge<Moment3> for Line3 {
+	type Output = XYZW;
+	fn wedge(self, rhs: Moment3) -> Self::Output {
+		// -XYZW(self.vx.0 * rhs.mx.0) - XYZW(self.vy.0 * rhs.my.0) - XYZW(self.vz.0 * rhs.mz.0)
+		self.vx.wedge(rhs.mx) + self.vy.wedge(rhs.my) + self.vz.wedge(rhs.mz)
+	}
+}
+
+impl std::ops::BitXor<Moment3> for Line3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Moment3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line3.anti_wedge(Moment3) -> S
+impl AntiWedge<Moment3> for Line3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Moment3) -> Self::Output {
+		// -S(self.vx.0 * rhs.mx.0) - S(self.vy.0 * rhs.my.0) - S(self.vz.0 * rhs.mz.0)
+		self.vx.anti_wedge(rhs.mx) + self.vy.anti_wedge(rhs.my) + self.vz.anti_wedge(rhs.mz)
+	}
+}
+
+impl std::ops::BitAnd<Moment3> for Line3 {
+	type Output = S;
+	fn bitand(self, rhs: Moment3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line3.left_contraction(Moment3) -> S
+impl LeftContraction<Moment3> for Line3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Moment3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.left_contraction(rhs.mx) + self.my.left_contraction(rhs.my) + self.mz.left_contraction(rhs.mz)
+	}
+}
+
+// Line3.right_contraction(Moment3) -> S
+impl RightContraction<Moment3> for Line3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Moment3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.right_contraction(rhs.mx) + self.my.right_contraction(rhs.my) + self.mz.right_contraction(rhs.mz)
+	}
+}
+
+// Line3.scalar_product(Moment3) -> S
+impl ScalarProduct<Moment3> for Line3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Moment3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.scalar_product(rhs.mx) + self.my.scalar_product(rhs.my) + self.mz.scalar_product(rhs.mz)
+	}
+}
+
+// Line3.commutator(Moment3) -> Line3
+impl Commutator<Moment3> for Line3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Moment3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.vy.0 * rhs.mz.0) + WX(self.vz.0 * rhs.my.0),
+		//     vy: WY(self.vx.0 * rhs.mz.0) - WY(self.vz.0 * rhs.mx.0),
+		//     vz: -WZ(self.vx.0 * rhs.my.0) + WZ(self.vy.0 * rhs.mx.0),
+		//     mx: -YZ(self.my.0 * rhs.mz.0) + YZ(self.mz.0 * rhs.my.0),
+		//     my: ZX(self.mx.0 * rhs.mz.0) - ZX(self.mz.0 * rhs.mx.0),
+		//     mz: -XY(self.mx.0 * rhs.my.0) + XY(self.my.0 * rhs.mx.0),
+		// }
+		Line3 {
+			vx: self.vy.commutator(rhs.mz) + self.vz.commutator(rhs.my),
+			vy: self.vx.commutator(rhs.mz) + self.vz.commutator(rhs.mx),
+			vz: self.vx.commutator(rhs.my) + self.vy.commutator(rhs.mx),
+			mx: self.my.commutator(rhs.mz) + self.mz.commutator(rhs.my),
+			my: self.mx.commutator(rhs.mz) + self.mz.commutator(rhs.mx),
+			mz: self.mx.commutator(rhs.my) + self.my.commutator(rhs.mx),
+		}
+	}
+}
+
+impl AntiDot<Moment3> for Line3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line3 OP Line3:
+
+// Line3.geometric(Line3) -> Motor3
+impl Geometric<Line3> for Line3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: -WX(self.my.0 * rhs.vz.0) + WX(self.mz.0 * rhs.vy.0) - WX(self.vy.0 * rhs.mz.0) + WX(self.vz.0 * rhs.my.0),
+		//     ry: WY(self.mx.0 * rhs.vz.0) - WY(self.mz.0 * rhs.vx.0) + WY(self.vx.0 * rhs.mz.0) - WY(self.vz.0 * rhs.mx.0),
+		//     rz: -WZ(self.mx.0 * rhs.vy.0) + WZ(self.my.0 * rhs.vx.0) - WZ(self.vx.0 * rhs.my.0) + WZ(self.vy.0 * rhs.mx.0),
+		//     rw: -XYZW(self.mx.0 * rhs.vx.0) - XYZW(self.my.0 * rhs.vy.0) - XYZW(self.mz.0 * rhs.vz.0) - XYZW(self.vx.0 * rhs.mx.0) - XYZW(self.vy.0 * rhs.my.0) - XYZW(self.vz.0 * rhs.mz.0),
+		//     ux: -YZ(self.my.0 * rhs.mz.0) + YZ(self.mz.0 * rhs.my.0),
+		//     uy: ZX(self.mx.0 * rhs.mz.0) - ZX(self.mz.0 * rhs.mx.0),
+		//     uz: -XY(self.mx.0 * rhs.my.0) + XY(self.my.0 * rhs.mx.0),
+		//     uw: -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.my.geometric(rhs.vz)
+				+ self.mz.geometric(rhs.vy)
+				+ self.vy.geometric(rhs.mz)
+				+ self.vz.geometric(rhs.my),
+			ry: self.mx.geometric(rhs.vz)
+				+ self.mz.geometric(rhs.vx)
+				+ self.vx.geometric(rhs.mz)
+				+ self.vz.geometric(rhs.mx),
+			rz: self.mx.geometric(rhs.vy)
+				+ self.my.geometric(rhs.vx)
+				+ self.vx.geometric(rhs.my)
+				+ self.vy.geometric(rhs.mx),
+			rw: self.mx.geometric(rhs.vx)
+				+ self.my.geometric(rhs.vy)
+				+ self.mz.geometric(rhs.vz)
+				+ self.vx.geometric(rhs.mx)
+				+ self.vy.geometric(rhs.my)
+				+ self.vz.geometric(rhs.mz),
+			ux: self.my.geometric(rhs.mz) + self.mz.geometric(rhs.my),
+			uy: self.mx.geometric(rhs.mz) + self.mz.geometric(rhs.mx),
+			uz: self.mx.geometric(rhs.my) + self.my.geometric(rhs.mx),
+			uw: self.mx.geometric(rhs.mx) + self.my.geometric(rhs.my) + self.mz.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Line3> for Line3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Line3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line3.anti_geometric(Line3) -> Motor3
+impl AntiGeometric<Line3> for Line3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vy.0 * rhs.vz.0) - WX(self.vz.0 * rhs.vy.0),
+		//     ry: -WY(self.vx.0 * rhs.vz.0) + WY(self.vz.0 * rhs.vx.0),
+		//     rz: WZ(self.vx.0 * rhs.vy.0) - WZ(self.vy.0 * rhs.vx.0),
+		//     rw: -XYZW(self.vx.0 * rhs.vx.0) - XYZW(self.vy.0 * rhs.vy.0) - XYZW(self.vz.0 * rhs.vz.0),
+		//     ux: YZ(self.my.0 * rhs.vz.0) - YZ(self.mz.0 * rhs.vy.0) + YZ(self.vy.0 * rhs.mz.0) - YZ(self.vz.0 * rhs.my.0),
+		//     uy: -ZX(self.mx.0 * rhs.vz.0) + ZX(self.mz.0 * rhs.vx.0) - ZX(self.vx.0 * rhs.mz.0) + ZX(self.vz.0 * rhs.mx.0),
+		//     uz: XY(self.mx.0 * rhs.vy.0) - XY(self.my.0 * rhs.vx.0) + XY(self.vx.0 * rhs.my.0) - XY(self.vy.0 * rhs.mx.0),
+		//     uw: -S(self.mx.0 * rhs.vx.0) - S(self.my.0 * rhs.vy.0) - S(self.mz.0 * rhs.vz.0) - S(self.vx.0 * rhs.mx.0) - S(self.vy.0 * rhs.my.0) - S(self.vz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.vy.anti_geometric(rhs.vz) + self.vz.anti_geometric(rhs.vy),
+			ry: self.vx.anti_geometric(rhs.vz) + self.vz.anti_geometric(rhs.vx),
+			rz: self.vx.anti_geometric(rhs.vy) + self.vy.anti_geometric(rhs.vx),
+			rw: self.vx.anti_geometric(rhs.vx) + self.vy.anti_geometric(rhs.vy) + self.vz.anti_geometric(rhs.vz),
+			ux: self.my.anti_geometric(rhs.vz)
+				+ self.mz.anti_geometric(rhs.vy)
+				+ self.vy.anti_geometric(rhs.mz)
+				+ self.vz.anti_geometric(rhs.my),
+			uy: self.mx.anti_geometric(rhs.vz)
+				+ self.mz.anti_geometric(rhs.vx)
+				+ self.vx.anti_geometric(rhs.mz)
+				+ self.vz.anti_geometric(rhs.mx),
+			uz: self.mx.anti_geometric(rhs.vy)
+				+ self.my.anti_geometric(rhs.vx)
+				+ self.vx.anti_geometric(rhs.my)
+				+ self.vy.anti_geometric(rhs.mx),
+			uw: self.mx.anti_geometric(rhs.vx)
+				+ self.my.anti_geometric(rhs.vy)
+				+ self.mz.anti_geometric(rhs.vz)
+				+ self.vx.anti_geometric(rhs.mx)
+				+ self.vy.anti_geometric(rhs.my)
+				+ self.vz.anti_geometric(rhs.mz),
+		}
+	}
+}
+
+// Line3.dot(Line3) -> S
+impl Dot<Line3> for Line3 {
+	type Output = S;
+	fn dot(self, rhs: Line3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.dot(rhs.mx) + self.my.dot(rhs.my) + self.mz.dot(rhs.mz)
+	}
+}
+
+impl std::ops::BitOr<Line3> for Line3 {
+	type Output = S;
+	fn bitor(self, rhs: Line3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line3.wedge(Line3) -> XYZW
+impl Wedge<Line3> for Line3 {
+	type Output = XYZW;
+	fn wedge(self, rhs: Line3) -> Self::Output {
+		// -XYZW(self.mx.0 * rhs.vx.0) - XYZW(self.my.0 * rhs.vy.0) - XYZW(self.mz.0 * rhs.vz.0) - XYZW(self.vx.0 * rhs.mx.0) - XYZW(self.vy.0 * rhs.my.0) - XYZW(self.vz.0 * rhs.mz.0)
+		self.mx.wedge(rhs.vx)
+			+ self.my.wedge(rhs.vy)
+			+ self.mz.wedge(rhs.vz)
+			+ self.vx.wedge(rhs.mx)
+			+ self.vy.wedge(rhs.my)
+			+ self.vz.wedge(rhs.mz)
+	}
+}
+
+impl std::ops::BitXor<Line3> for Line3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Line3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line3.anti_wedge(Line3) -> S
+impl AntiWedge<Line3> for Line3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Line3) -> Self::Output {
+		// -S(self.mx.0 * rhs.vx.0) - S(self.my.0 * rhs.vy.0) - S(self.mz.0 * rhs.vz.0) - S(self.vx.0 * rhs.mx.0) - S(self.vy.0 * rhs.my.0) - S(self.vz.0 * rhs.mz.0)
+		self.mx.anti_wedge(rhs.vx)
+			+ self.my.anti_wedge(rhs.vy)
+			+ self.mz.anti_wedge(rhs.vz)
+			+ self.vx.anti_wedge(rhs.mx)
+			+ self.vy.anti_wedge(rhs.my)
+			+ self.vz.anti_wedge(rhs.mz)
+	}
+}
+
+impl std::ops::BitAnd<Line3> for Line3 {
+	type Output = S;
+	fn bitand(self, rhs: Line3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line3.left_contraction(Line3) -> S
+impl LeftContraction<Line3> for Line3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Line3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.left_contraction(rhs.mx) + self.my.left_contraction(rhs.my) + self.mz.left_contraction(rhs.mz)
+	}
+}
+
+// Line3.right_contraction(Line3) -> S
+impl RightContraction<Line3> for Line3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Line3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.right_contraction(rhs.mx) + self.my.right_contraction(rhs.my) + self.mz.right_contraction(rhs.mz)
+	}
+}
+
+// Line3.scalar_product(Line3) -> S
+impl ScalarProduct<Line3> for Line3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Line3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.scalar_product(rhs.mx) + self.my.scalar_product(rhs.my) + self.mz.scalar_product(rhs.mz)
+	}
+}
+
+// Line3.commutator(Line3) -> Line3
+impl Commutator<Line3> for Line3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Line3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.my.0 * rhs.vz.0) + WX(self.mz.0 * rhs.vy.0) - WX(self.vy.0 * rhs.mz.0) + WX(self.vz.0 * rhs.my.0),
+		//     vy: WY(self.mx.0 * rhs.vz.0) - WY(self.mz.0 * rhs.vx.0) + WY(self.vx.0 * rhs.mz.0) - WY(self.vz.0 * rhs.mx.0),
+		//     vz: -WZ(self.mx.0 * rhs.vy.0) + WZ(self.my.0 * rhs.vx.0) - WZ(self.vx.0 * rhs.my.0) + WZ(self.vy.0 * rhs.mx.0),
+		//     mx: -YZ(self.my.0 * rhs.mz.0) + YZ(self.mz.0 * rhs.my.0),
+		//     my: ZX(self.mx.0 * rhs.mz.0) - ZX(self.mz.0 * rhs.mx.0),
+		//     mz: -XY(self.mx.0 * rhs.my.0) + XY(self.my.0 * rhs.mx.0),
+		// }
+		Line3 {
+			vx: self.my.commutator(rhs.vz)
+				+ self.mz.commutator(rhs.vy)
+				+ self.vy.commutator(rhs.mz)
+				+ self.vz.commutator(rhs.my),
+			vy: self.mx.commutator(rhs.vz)
+				+ self.mz.commutator(rhs.vx)
+				+ self.vx.commutator(rhs.mz)
+				+ self.vz.commutator(rhs.mx),
+			vz: self.mx.commutator(rhs.vy)
+				+ self.my.commutator(rhs.vx)
+				+ self.vx.commutator(rhs.my)
+				+ self.vy.commutator(rhs.mx),
+			mx: self.my.commutator(rhs.mz) + self.mz.commutator(rhs.my),
+			my: self.mx.commutator(rhs.mz) + self.mz.commutator(rhs.mx),
+			mz: self.mx.commutator(rhs.my) + self.my.commutator(rhs.mx),
+		}
+	}
+}
+
+// Line3.anti_dot(Line3) -> XYZW
+impl AntiDot<Line3> for Line3 {
+	type Output = XYZW;
+	fn anti_dot(self, rhs: Line3) -> Self::Output {
+		// -XYZW(self.vx.0 * rhs.vx.0) - XYZW(self.vy.0 * rhs.vy.0) - XYZW(self.vz.0 * rhs.vz.0)
+		self.vx.anti_dot(rhs.vx) + self.vy.anti_dot(rhs.vy) + self.vz.anti_dot(rhs.vz)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line3 OP Plane:
+
+// Line3.geometric(Plane) -> Multivector
+impl Geometric<Plane> for Line3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.mx.0 * rhs.d.0),
+		//     y   : -Y(self.my.0 * rhs.d.0),
+		//     z   : -Z(self.mz.0 * rhs.d.0),
+		//     w   : -W(self.mx.0 * rhs.nx.0) - W(self.my.0 * rhs.ny.0) - W(self.mz.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.my.0 * rhs.nz.0) + YZW(self.mz.0 * rhs.ny.0) + YZW(self.vx.0 * rhs.d.0),
+		//     zxw : ZXW(self.mx.0 * rhs.nz.0) - ZXW(self.mz.0 * rhs.nx.0) + ZXW(self.vy.0 * rhs.d.0),
+		//     xyw : -XYW(self.mx.0 * rhs.ny.0) + XYW(self.my.0 * rhs.nx.0) + XYW(self.vz.0 * rhs.d.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.mx.geometric(rhs.d),
+			y: self.my.geometric(rhs.d),
+			z: self.mz.geometric(rhs.d),
+			w: self.mx.geometric(rhs.nx) + self.my.geometric(rhs.ny) + self.mz.geometric(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.my.geometric(rhs.nz) + self.mz.geometric(rhs.ny) + self.vx.geometric(rhs.d),
+			zxw: self.mx.geometric(rhs.nz) + self.mz.geometric(rhs.nx) + self.vy.geometric(rhs.d),
+			xyw: self.mx.geometric(rhs.ny) + self.my.geometric(rhs.nx) + self.vz.geometric(rhs.d),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Line3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line3.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Line3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.my.0 * rhs.nz.0) - X(self.mz.0 * rhs.ny.0) - X(self.vx.0 * rhs.d.0),
+		//     y   : -Y(self.mx.0 * rhs.nz.0) + Y(self.mz.0 * rhs.nx.0) - Y(self.vy.0 * rhs.d.0),
+		//     z   : Z(self.mx.0 * rhs.ny.0) - Z(self.my.0 * rhs.nx.0) - Z(self.vz.0 * rhs.d.0),
+		//     w   : -W(self.vx.0 * rhs.nx.0) - W(self.vy.0 * rhs.ny.0) - W(self.vz.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.vy.0 * rhs.nz.0) - YZW(self.vz.0 * rhs.ny.0),
+		//     zxw : -ZXW(self.vx.0 * rhs.nz.0) + ZXW(self.vz.0 * rhs.nx.0),
+		//     xyw : XYW(self.vx.0 * rhs.ny.0) - XYW(self.vy.0 * rhs.nx.0),
+		//     xyz : XYZ(self.mx.0 * rhs.nx.0) + XYZ(self.my.0 * rhs.ny.0) + XYZ(self.mz.0 * rhs.nz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.my.anti_geometric(rhs.nz) + self.mz.anti_geometric(rhs.ny) + self.vx.anti_geometric(rhs.d),
+			y: self.mx.anti_geometric(rhs.nz) + self.mz.anti_geometric(rhs.nx) + self.vy.anti_geometric(rhs.d),
+			z: self.mx.anti_geometric(rhs.ny) + self.my.anti_geometric(rhs.nx) + self.vz.anti_geometric(rhs.d),
+			w: self.vx.anti_geometric(rhs.nx) + self.vy.anti_geometric(rhs.ny) + self.vz.anti_geometric(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.vy.anti_geometric(rhs.nz) + self.vz.anti_geometric(rhs.ny),
+			zxw: self.vx.anti_geometric(rhs.nz) + self.vz.anti_geometric(rhs.nx),
+			xyw: self.vx.anti_geometric(rhs.ny) + self.vy.anti_geometric(rhs.nx),
+			xyz: self.mx.anti_geometric(rhs.nx) + self.my.anti_geometric(rhs.ny) + self.mz.anti_geometric(rhs.nz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Line3.dot(Plane) -> Vec4
+impl Dot<Plane> for Line3 {
+	type Output = Vec4;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.mx.0 * rhs.d.0),
+		//     y: -Y(self.my.0 * rhs.d.0),
+		//     z: -Z(self.mz.0 * rhs.d.0),
+		//     w: -W(self.mx.0 * rhs.nx.0) - W(self.my.0 * rhs.ny.0) - W(self.mz.0 * rhs.nz.0),
+		// }
+		Vec4 {
+			x: self.mx.dot(rhs.d),
+			y: self.my.dot(rhs.d),
+			z: self.mz.dot(rhs.d),
+			w: self.mx.dot(rhs.nx) + self.my.dot(rhs.ny) + self.mz.dot(rhs.nz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Line3 {
+	type Output = Vec4;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for Line3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Line3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line3.anti_wedge(Plane) -> Vec4
+impl AntiWedge<Plane> for Line3 {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.my.0 * rhs.nz.0) - X(self.mz.0 * rhs.ny.0) - X(self.vx.0 * rhs.d.0),
+		//     y: -Y(self.mx.0 * rhs.nz.0) + Y(self.mz.0 * rhs.nx.0) - Y(self.vy.0 * rhs.d.0),
+		//     z: Z(self.mx.0 * rhs.ny.0) - Z(self.my.0 * rhs.nx.0) - Z(self.vz.0 * rhs.d.0),
+		//     w: -W(self.vx.0 * rhs.nx.0) - W(self.vy.0 * rhs.ny.0) - W(self.vz.0 * rhs.nz.0),
+		// }
+		Vec4 {
+			x: self.my.anti_wedge(rhs.nz) + self.mz.anti_wedge(rhs.ny) + self.vx.anti_wedge(rhs.d),
+			y: self.mx.anti_wedge(rhs.nz) + self.mz.anti_wedge(rhs.nx) + self.vy.anti_wedge(rhs.d),
+			z: self.mx.anti_wedge(rhs.ny) + self.my.anti_wedge(rhs.nx) + self.vz.anti_wedge(rhs.d),
+			w: self.vx.anti_wedge(rhs.nx) + self.vy.anti_wedge(rhs.ny) + self.vz.anti_wedge(rhs.nz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Line3 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line3.left_contraction(Plane) -> Vec4
+impl LeftContraction<Plane> for Line3 {
+	type Output = Vec4;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.mx.0 * rhs.d.0),
+		//     y: -Y(self.my.0 * rhs.d.0),
+		//     z: -Z(self.mz.0 * rhs.d.0),
+		//     w: -W(self.mx.0 * rhs.nx.0) - W(self.my.0 * rhs.ny.0) - W(self.mz.0 * rhs.nz.0),
+		// }
+		Vec4 {
+			x: self.mx.left_contraction(rhs.d),
+			y: self.my.left_contraction(rhs.d),
+			z: self.mz.left_contraction(rhs.d),
+			w: self.mx.left_contraction(rhs.nx) + self.my.left_contraction(rhs.ny) + self.mz.left_contraction(rhs.nz),
+		}
+	}
+}
+
+impl RightContraction<Plane> for Line3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for Line3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line3.commutator(Plane) -> Plane
+impl Commutator<Plane> for Line3 {
+	type Output = Plane;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     nx: -YZW(self.my.0 * rhs.nz.0) + YZW(self.mz.0 * rhs.ny.0) + YZW(self.vx.0 * rhs.d.0),
+		//     ny: ZXW(self.mx.0 * rhs.nz.0) - ZXW(self.mz.0 * rhs.nx.0) + ZXW(self.vy.0 * rhs.d.0),
+		//     nz: -XYW(self.mx.0 * rhs.ny.0) + XYW(self.my.0 * rhs.nx.0) + XYW(self.vz.0 * rhs.d.0),
+		//     d : Default::default(),
+		// }
+		Plane {
+			nx: self.my.commutator(rhs.nz) + self.mz.commutator(rhs.ny) + self.vx.commutator(rhs.d),
+			ny: self.mx.commutator(rhs.nz) + self.mz.commutator(rhs.nx) + self.vy.commutator(rhs.d),
+			nz: self.mx.commutator(rhs.ny) + self.my.commutator(rhs.nx) + self.vz.commutator(rhs.d),
+			d: Default::default(),
+		}
+	}
+}
+
+// Line3.anti_dot(Plane) -> Plane
+impl AntiDot<Plane> for Line3 {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.vy.0 * rhs.nz.0) - YZW(self.vz.0 * rhs.ny.0),
+		//     ny: -ZXW(self.vx.0 * rhs.nz.0) + ZXW(self.vz.0 * rhs.nx.0),
+		//     nz: XYW(self.vx.0 * rhs.ny.0) - XYW(self.vy.0 * rhs.nx.0),
+		//     d : XYZ(self.mx.0 * rhs.nx.0) + XYZ(self.my.0 * rhs.ny.0) + XYZ(self.mz.0 * rhs.nz.0),
+		// }
+		Plane {
+			nx: self.vy.anti_dot(rhs.nz) + self.vz.anti_dot(rhs.ny),
+			ny: self.vx.anti_dot(rhs.nz) + self.vz.anti_dot(rhs.nx),
+			nz: self.vx.anti_dot(rhs.ny) + self.vy.anti_dot(rhs.nx),
+			d: self.mx.anti_dot(rhs.nx) + self.my.anti_dot(rhs.ny) + self.mz.anti_dot(rhs.nz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line3 OP Translator3:
+
+// Line3.geometric(Translator3) -> Motor3
+impl Geometric<Translator3> for Line3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.w.0) - WX(self.vy.0 * rhs.z.0) + WX(self.vz.0 * rhs.y.0),
+		//     ry: WY(self.my.0 * rhs.w.0) + WY(self.vx.0 * rhs.z.0) - WY(self.vz.0 * rhs.x.0),
+		//     rz: WZ(self.mz.0 * rhs.w.0) - WZ(self.vx.0 * rhs.y.0) + WZ(self.vy.0 * rhs.x.0),
+		//     rw: -XYZW(self.vx.0 * rhs.x.0) - XYZW(self.vy.0 * rhs.y.0) - XYZW(self.vz.0 * rhs.z.0),
+		//     ux: -YZ(self.my.0 * rhs.z.0) + YZ(self.mz.0 * rhs.y.0),
+		//     uy: ZX(self.mx.0 * rhs.z.0) - ZX(self.mz.0 * rhs.x.0),
+		//     uz: -XY(self.mx.0 * rhs.y.0) + XY(self.my.0 * rhs.x.0),
+		//     uw: -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.mx.geometric(rhs.w) + self.vy.geometric(rhs.z) + self.vz.geometric(rhs.y),
+			ry: self.my.geometric(rhs.w) + self.vx.geometric(rhs.z) + self.vz.geometric(rhs.x),
+			rz: self.mz.geometric(rhs.w) + self.vx.geometric(rhs.y) + self.vy.geometric(rhs.x),
+			rw: self.vx.geometric(rhs.x) + self.vy.geometric(rhs.y) + self.vz.geometric(rhs.z),
+			ux: self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			uy: self.mx.geometric(rhs.z) + self.mz.geometric(rhs.x),
+			uz: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x),
+			uw: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator3> for Line3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Translator3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line3.anti_geometric(Translator3) -> Motor3
+impl AntiGeometric<Translator3> for Line3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vx.0 * rhs.w.0),
+		//     ry: WY(self.vy.0 * rhs.w.0),
+		//     rz: WZ(self.vz.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.w.0) + YZ(self.vy.0 * rhs.z.0) - YZ(self.vz.0 * rhs.y.0),
+		//     uy: ZX(self.my.0 * rhs.w.0) - ZX(self.vx.0 * rhs.z.0) + ZX(self.vz.0 * rhs.x.0),
+		//     uz: XY(self.mz.0 * rhs.w.0) + XY(self.vx.0 * rhs.y.0) - XY(self.vy.0 * rhs.x.0),
+		//     uw: -S(self.vx.0 * rhs.x.0) - S(self.vy.0 * rhs.y.0) - S(self.vz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.vx.anti_geometric(rhs.w),
+			ry: self.vy.anti_geometric(rhs.w),
+			rz: self.vz.anti_geometric(rhs.w),
+			rw: Default::default(),
+			ux: self.mx.anti_geometric(rhs.w) + self.vy.anti_geometric(rhs.z) + self.vz.anti_geometric(rhs.y),
+			uy: self.my.anti_geometric(rhs.w) + self.vx.anti_geometric(rhs.z) + self.vz.anti_geometric(rhs.x),
+			uz: self.mz.anti_geometric(rhs.w) + self.vx.anti_geometric(rhs.y) + self.vy.anti_geometric(rhs.x),
+			uw: self.vx.anti_geometric(rhs.x) + self.vy.anti_geometric(rhs.y) + self.vz.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Line3.dot(Translator3) -> Motor3
+impl Dot<Translator3> for Line3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.w.0),
+		//     ry: WY(self.my.0 * rhs.w.0),
+		//     rz: WZ(self.mz.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.mx.dot(rhs.w),
+			ry: self.my.dot(rhs.w),
+			rz: self.mz.dot(rhs.w),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.mx.dot(rhs.x) + self.my.dot(rhs.y) + self.mz.dot(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator3> for Line3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Translator3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line3.wedge(Translator3) -> XYZW
+impl Wedge<Translator3> for Line3 {
+	type Output = XYZW;
+	fn wedge(self, rhs: Translator3) -> Self::Output {
+		// -XYZW(self.vx.0 * rhs.x.0) - XYZW(self.vy.0 * rhs.y.0) - XYZW(self.vz.0 * rhs.z.0)
+		self.vx.wedge(rhs.x) + self.vy.wedge(rhs.y) + self.vz.wedge(rhs.z)
+	}
+}
+
+impl std::ops::BitXor<Translator3> for Line3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Translator3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line3.anti_wedge(Translator3) -> Motor3
+impl AntiWedge<Translator3> for Line3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vx.0 * rhs.w.0),
+		//     ry: WY(self.vy.0 * rhs.w.0),
+		//     rz: WZ(self.vz.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.w.0),
+		//     uy: ZX(self.my.0 * rhs.w.0),
+		//     uz: XY(self.mz.0 * rhs.w.0),
+		//     uw: -S(self.vx.0 * rhs.x.0) - S(self.vy.0 * rhs.y.0) - S(self.vz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.vx.anti_wedge(rhs.w),
+			ry: self.vy.anti_wedge(rhs.w),
+			rz: self.vz.anti_wedge(rhs.w),
+			rw: Default::default(),
+			ux: self.mx.anti_wedge(rhs.w),
+			uy: self.my.anti_wedge(rhs.w),
+			uz: self.mz.anti_wedge(rhs.w),
+			uw: self.vx.anti_wedge(rhs.x) + self.vy.anti_wedge(rhs.y) + self.vz.anti_wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Translator3> for Line3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Translator3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line3.left_contraction(Translator3) -> Motor3
+impl LeftContraction<Translator3> for Line3 {
+	type Output = Motor3;
+	fn left_contraction(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.w.0),
+		//     ry: WY(self.my.0 * rhs.w.0),
+		//     rz: WZ(self.mz.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.mx.left_contraction(rhs.w),
+			ry: self.my.left_contraction(rhs.w),
+			rz: self.mz.left_contraction(rhs.w),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.mx.left_contraction(rhs.x) + self.my.left_contraction(rhs.y) + self.mz.left_contraction(rhs.z),
+		}
+	}
+}
+
+// Line3.right_contraction(Translator3) -> S
+impl RightContraction<Translator3> for Line3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Translator3) -> Self::Output {
+		// -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0)
+		self.mx.right_contraction(rhs.x) + self.my.right_contraction(rhs.y) + self.mz.right_contraction(rhs.z)
+	}
+}
+
+// Line3.scalar_product(Translator3) -> S
+impl ScalarProduct<Translator3> for Line3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator3) -> Self::Output {
+		// -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0)
+		self.mx.scalar_product(rhs.x) + self.my.scalar_product(rhs.y) + self.mz.scalar_product(rhs.z)
+	}
+}
+
+// Line3.commutator(Translator3) -> Line3
+impl Commutator<Translator3> for Line3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Translator3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.vy.0 * rhs.z.0) + WX(self.vz.0 * rhs.y.0),
+		//     vy: WY(self.vx.0 * rhs.z.0) - WY(self.vz.0 * rhs.x.0),
+		//     vz: -WZ(self.vx.0 * rhs.y.0) + WZ(self.vy.0 * rhs.x.0),
+		//     mx: -YZ(self.my.0 * rhs.z.0) + YZ(self.mz.0 * rhs.y.0),
+		//     my: ZX(self.mx.0 * rhs.z.0) - ZX(self.mz.0 * rhs.x.0),
+		//     mz: -XY(self.mx.0 * rhs.y.0) + XY(self.my.0 * rhs.x.0),
+		// }
+		Line3 {
+			vx: self.vy.commutator(rhs.z) + self.vz.commutator(rhs.y),
+			vy: self.vx.commutator(rhs.z) + self.vz.commutator(rhs.x),
+			vz: self.vx.commutator(rhs.y) + self.vy.commutator(rhs.x),
+			mx: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			my: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			mz: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+		}
+	}
+}
+
+// Line3.anti_dot(Translator3) -> Line3
+impl AntiDot<Translator3> for Line3 {
+	type Output = Line3;
+	fn anti_dot(self, rhs: Translator3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.vx.0 * rhs.w.0),
+		//     vy: WY(self.vy.0 * rhs.w.0),
+		//     vz: WZ(self.vz.0 * rhs.w.0),
+		//     mx: YZ(self.mx.0 * rhs.w.0),
+		//     my: ZX(self.my.0 * rhs.w.0),
+		//     mz: XY(self.mz.0 * rhs.w.0),
+		// }
+		Line3 {
+			vx: self.vx.anti_dot(rhs.w),
+			vy: self.vy.anti_dot(rhs.w),
+			vz: self.vz.anti_dot(rhs.w),
+			mx: self.mx.anti_dot(rhs.w),
+			my: self.my.anti_dot(rhs.w),
+			mz: self.mz.anti_dot(rhs.w),
 		}
 	}
 }
@@ -322,22 +2110,83 @@ impl Geometric<Rotor3> for Line3 {
 	type Output = Rotor3;
 	fn geometric(self, rhs: Rotor3) -> Self::Output {
 		// Rotor3 {
-		//     x: WX(self.mx.0 * rhs.w.0) + WX(self.my.0 * rhs.z.0) + WX(self.mz.0 * rhs.y.0),
-		//     y: WY(self.mx.0 * rhs.z.0) + WY(self.my.0 * rhs.w.0) + WY(self.mz.0 * rhs.x.0),
-		//     z: WZ(self.mx.0 * rhs.y.0) + WZ(self.my.0 * rhs.x.0) + WZ(self.mz.0 * rhs.w.0),
-		//     w: XYZW(self.mx.0 * rhs.x.0) + XYZW(self.my.0 * rhs.y.0) + XYZW(self.mz.0 * rhs.z.0),
+		//     x: WX(self.mx.0 * rhs.w.0) - WX(self.my.0 * rhs.z.0) + WX(self.mz.0 * rhs.y.0),
+		//     y: WY(self.mx.0 * rhs.z.0) + WY(self.my.0 * rhs.w.0) - WY(self.mz.0 * rhs.x.0),
+		//     z: -WZ(self.mx.0 * rhs.y.0) + WZ(self.my.0 * rhs.x.0) + WZ(self.mz.0 * rhs.w.0),
+		//     w: -XYZW(self.mx.0 * rhs.x.0) - XYZW(self.my.0 * rhs.y.0) - XYZW(self.mz.0 * rhs.z.0),
 		// }
 		Rotor3 {
-			x: self.mx.geometric(rhs.w) - self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
-			y: self.mx.geometric(rhs.z) + self.my.geometric(rhs.w) - self.mz.geometric(rhs.x),
-			z: -self.mx.geometric(rhs.y) + self.my.geometric(rhs.x) + self.mz.geometric(rhs.w),
-			w: -self.mx.geometric(rhs.x) - self.my.geometric(rhs.y) - self.mz.geometric(rhs.z),
+			x: self.mx.geometric(rhs.w) + self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			y: self.mx.geometric(rhs.z) + self.my.geometric(rhs.w) + self.mz.geometric(rhs.x),
+			z: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x) + self.mz.geometric(rhs.w),
+			w: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Line3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line3.anti_geometric(Rotor3) -> Motor3
+impl AntiGeometric<Rotor3> for Line3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vx.0 * rhs.w.0) + WX(self.vy.0 * rhs.z.0) - WX(self.vz.0 * rhs.y.0),
+		//     ry: -WY(self.vx.0 * rhs.z.0) + WY(self.vy.0 * rhs.w.0) + WY(self.vz.0 * rhs.x.0),
+		//     rz: WZ(self.vx.0 * rhs.y.0) - WZ(self.vy.0 * rhs.x.0) + WZ(self.vz.0 * rhs.w.0),
+		//     rw: -XYZW(self.vx.0 * rhs.x.0) - XYZW(self.vy.0 * rhs.y.0) - XYZW(self.vz.0 * rhs.z.0),
+		//     ux: YZ(self.mx.0 * rhs.w.0) + YZ(self.my.0 * rhs.z.0) - YZ(self.mz.0 * rhs.y.0),
+		//     uy: -ZX(self.mx.0 * rhs.z.0) + ZX(self.my.0 * rhs.w.0) + ZX(self.mz.0 * rhs.x.0),
+		//     uz: XY(self.mx.0 * rhs.y.0) - XY(self.my.0 * rhs.x.0) + XY(self.mz.0 * rhs.w.0),
+		//     uw: -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.vx.anti_geometric(rhs.w) + self.vy.anti_geometric(rhs.z) + self.vz.anti_geometric(rhs.y),
+			ry: self.vx.anti_geometric(rhs.z) + self.vy.anti_geometric(rhs.w) + self.vz.anti_geometric(rhs.x),
+			rz: self.vx.anti_geometric(rhs.y) + self.vy.anti_geometric(rhs.x) + self.vz.anti_geometric(rhs.w),
+			rw: self.vx.anti_geometric(rhs.x) + self.vy.anti_geometric(rhs.y) + self.vz.anti_geometric(rhs.z),
+			ux: self.mx.anti_geometric(rhs.w) + self.my.anti_geometric(rhs.z) + self.mz.anti_geometric(rhs.y),
+			uy: self.mx.anti_geometric(rhs.z) + self.my.anti_geometric(rhs.w) + self.mz.anti_geometric(rhs.x),
+			uz: self.mx.anti_geometric(rhs.y) + self.my.anti_geometric(rhs.x) + self.mz.anti_geometric(rhs.w),
+			uw: self.mx.anti_geometric(rhs.x) + self.my.anti_geometric(rhs.y) + self.mz.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Line3.dot(Rotor3) -> Line3
+impl Dot<Rotor3> for Line3 {
+	type Output = Line3;
+	fn dot(self, rhs: Rotor3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.mx.0 * rhs.w.0),
+		//     vy: WY(self.my.0 * rhs.w.0),
+		//     vz: WZ(self.mz.0 * rhs.w.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.mx.dot(rhs.w),
+			vy: self.my.dot(rhs.w),
+			vz: self.mz.dot(rhs.w),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
 		}
 	}
 }
 
-// Omitted: Line3 anti_geometric Rotor3 = self.mx !* rhs.w + self.mx !* rhs.x + self.mx !* rhs.y + self.mx !* rhs.z + self.my !* rhs.w + self.my !* rhs.x + self.my !* rhs.y + self.my !* rhs.z + self.mz !* rhs.w + self.mz !* rhs.x + self.mz !* rhs.y + self.mz !* rhs.z + self.vx !* rhs.w + self.vx !* rhs.x + self.vx !* rhs.y + self.vx !* rhs.z + self.vy !* rhs.w + self.vy !* rhs.x + self.vy !* rhs.y + self.vy !* rhs.z + self.vz !* rhs.w + self.vz !* rhs.x + self.vz !* rhs.y + self.vz !* rhs.z  (unnamed type)
-// Omitted: Line3 dot Rotor3 = Line3 {     vx: self.mx | rhs.w,     vy: self.my | rhs.w,     vz: self.mz | rhs.w,     mx: 0,     my: 0,     mz: 0, }  (too many zeros)
+impl std::ops::BitOr<Rotor3> for Line3 {
+	type Output = Line3;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
 
 // Line3.wedge(Rotor3) -> XYZW
 impl Wedge<Rotor3> for Line3 {
@@ -348,13 +2197,1010 @@ impl Wedge<Rotor3> for Line3 {
 	}
 }
 
-// Omitted: Line3 anti_wedge Rotor3 = self.mx & rhs.w + self.mx & rhs.x + self.my & rhs.w + self.my & rhs.y + self.mz & rhs.w + self.mz & rhs.z + self.vx & rhs.w + self.vy & rhs.w + self.vz & rhs.w  (unnamed type)
+impl std::ops::BitXor<Rotor3> for Line3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line3.anti_wedge(Rotor3) -> Motor3
+impl AntiWedge<Rotor3> for Line3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vx.0 * rhs.w.0),
+		//     ry: WY(self.vy.0 * rhs.w.0),
+		//     rz: WZ(self.vz.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.w.0),
+		//     uy: ZX(self.my.0 * rhs.w.0),
+		//     uz: XY(self.mz.0 * rhs.w.0),
+		//     uw: -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.vx.anti_wedge(rhs.w),
+			ry: self.vy.anti_wedge(rhs.w),
+			rz: self.vz.anti_wedge(rhs.w),
+			rw: Default::default(),
+			ux: self.mx.anti_wedge(rhs.w),
+			uy: self.my.anti_wedge(rhs.w),
+			uz: self.mz.anti_wedge(rhs.w),
+			uw: self.mx.anti_wedge(rhs.x) + self.my.anti_wedge(rhs.y) + self.mz.anti_wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor3> for Line3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line3.left_contraction(Rotor3) -> Line3
+impl LeftContraction<Rotor3> for Line3 {
+	type Output = Line3;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.mx.0 * rhs.w.0),
+		//     vy: WY(self.my.0 * rhs.w.0),
+		//     vz: WZ(self.mz.0 * rhs.w.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.mx.left_contraction(rhs.w),
+			vy: self.my.left_contraction(rhs.w),
+			vz: self.mz.left_contraction(rhs.w),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Rotor3> for Line3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Rotor3> for Line3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Line3.commutator(Rotor3) -> Line3
+impl Commutator<Rotor3> for Line3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.my.0 * rhs.z.0) + WX(self.mz.0 * rhs.y.0),
+		//     vy: WY(self.mx.0 * rhs.z.0) - WY(self.mz.0 * rhs.x.0),
+		//     vz: -WZ(self.mx.0 * rhs.y.0) + WZ(self.my.0 * rhs.x.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			vy: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			vz: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+// Line3.anti_dot(Rotor3) -> Motor3
+impl AntiDot<Rotor3> for Line3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vx.0 * rhs.w.0),
+		//     ry: WY(self.vy.0 * rhs.w.0),
+		//     rz: WZ(self.vz.0 * rhs.w.0),
+		//     rw: -XYZW(self.vx.0 * rhs.x.0) - XYZW(self.vy.0 * rhs.y.0) - XYZW(self.vz.0 * rhs.z.0),
+		//     ux: YZ(self.mx.0 * rhs.w.0),
+		//     uy: ZX(self.my.0 * rhs.w.0),
+		//     uz: XY(self.mz.0 * rhs.w.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.vx.anti_dot(rhs.w),
+			ry: self.vy.anti_dot(rhs.w),
+			rz: self.vz.anti_dot(rhs.w),
+			rw: self.vx.anti_dot(rhs.x) + self.vy.anti_dot(rhs.y) + self.vz.anti_dot(rhs.z),
+			ux: self.mx.anti_dot(rhs.w),
+			uy: self.my.anti_dot(rhs.w),
+			uz: self.mz.anti_dot(rhs.w),
+			uw: Default::default(),
+		}
+	}
+}
 
 // ---------------------------------------------------------------------
 // Line3 OP Motor3:
 
-// Omitted: Line3 geometric Motor3 = self.mx * rhs.rw + self.mx * rhs.rx + self.mx * rhs.ry + self.mx * rhs.rz + self.mx * rhs.uw + self.mx * rhs.ux + self.mx * rhs.uy + self.mx * rhs.uz + self.my * rhs.rw + self.my * rhs.rx + self.my * rhs.ry + self.my * rhs.rz + self.my * rhs.uw + self.my * rhs.ux + self.my * rhs.uy + self.my * rhs.uz + self.mz * rhs.rw + self.mz * rhs.rx + self.mz * rhs.ry + self.mz * rhs.rz + self.mz * rhs.uw + self.mz * rhs.ux + self.mz * rhs.uy + self.mz * rhs.uz + self.vx * rhs.uw + self.vy * rhs.uw + self.vz * rhs.uw  (unnamed type)
-// Omitted: Line3 anti_geometric Motor3 = self.mx !* rhs.rw + self.mx !* rhs.rx + self.mx !* rhs.ry + self.mx !* rhs.rz + self.mx !* rhs.ux + self.mx !* rhs.uy + self.mx !* rhs.uz + self.my !* rhs.rw + self.my !* rhs.rx + self.my !* rhs.ry + self.my !* rhs.rz + self.my !* rhs.ux + self.my !* rhs.uy + self.my !* rhs.uz + self.mz !* rhs.rw + self.mz !* rhs.rx + self.mz !* rhs.ry + self.mz !* rhs.rz + self.mz !* rhs.ux + self.mz !* rhs.uy + self.mz !* rhs.uz + self.vx !* rhs.rw + self.vx !* rhs.rx + self.vx !* rhs.ry + self.vx !* rhs.rz + self.vx !* rhs.uw + self.vx !* rhs.ux + self.vx !* rhs.uy + self.vx !* rhs.uz + self.vy !* rhs.rw + self.vy !* rhs.rx + self.vy !* rhs.ry + self.vy !* rhs.rz + self.vy !* rhs.uw + self.vy !* rhs.ux + self.vy !* rhs.uy + self.vy !* rhs.uz + self.vz !* rhs.rw + self.vz !* rhs.rx + self.vz !* rhs.ry + self.vz !* rhs.rz + self.vz !* rhs.uw + self.vz !* rhs.ux + self.vz !* rhs.uy + self.vz !* rhs.uz  (unnamed type)
-// Omitted: Line3 dot Motor3 = self.mx | rhs.rw + self.mx | rhs.uw + self.mx | rhs.ux + self.my | rhs.rw + self.my | rhs.uw + self.my | rhs.uy + self.mz | rhs.rw + self.mz | rhs.uw + self.mz | rhs.uz + self.vx | rhs.uw + self.vy | rhs.uw + self.vz | rhs.uw  (unnamed type)
-// Omitted: Line3 wedge Motor3 = self.mx ^ rhs.rx + self.mx ^ rhs.uw + self.my ^ rhs.ry + self.my ^ rhs.uw + self.mz ^ rhs.rz + self.mz ^ rhs.uw + self.vx ^ rhs.uw + self.vy ^ rhs.uw + self.vz ^ rhs.uw  (unnamed type)
-// Omitted: Line3 anti_wedge Motor3 = self.mx & rhs.rw + self.mx & rhs.rx + self.mx & rhs.uy + self.mx & rhs.uz + self.my & rhs.rw + self.my & rhs.ry + self.my & rhs.ux + self.my & rhs.uz + self.mz & rhs.rw + self.mz & rhs.rz + self.mz & rhs.ux + self.mz & rhs.uy + self.vx & rhs.rw + self.vx & rhs.ux + self.vy & rhs.rw + self.vy & rhs.uy + self.vz & rhs.rw + self.vz & rhs.uz  (unnamed type)
+// Line3.geometric(Motor3) -> Motor3
+impl Geometric<Motor3> for Line3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.rw.0) - WX(self.my.0 * rhs.rz.0) + WX(self.mz.0 * rhs.ry.0) + WX(self.vx.0 * rhs.uw.0) - WX(self.vy.0 * rhs.uz.0) + WX(self.vz.0 * rhs.uy.0),
+		//     ry: WY(self.mx.0 * rhs.rz.0) + WY(self.my.0 * rhs.rw.0) - WY(self.mz.0 * rhs.rx.0) + WY(self.vx.0 * rhs.uz.0) + WY(self.vy.0 * rhs.uw.0) - WY(self.vz.0 * rhs.ux.0),
+		//     rz: -WZ(self.mx.0 * rhs.ry.0) + WZ(self.my.0 * rhs.rx.0) + WZ(self.mz.0 * rhs.rw.0) - WZ(self.vx.0 * rhs.uy.0) + WZ(self.vy.0 * rhs.ux.0) + WZ(self.vz.0 * rhs.uw.0),
+		//     rw: -XYZW(self.mx.0 * rhs.rx.0) - XYZW(self.my.0 * rhs.ry.0) - XYZW(self.mz.0 * rhs.rz.0) - XYZW(self.vx.0 * rhs.ux.0) - XYZW(self.vy.0 * rhs.uy.0) - XYZW(self.vz.0 * rhs.uz.0),
+		//     ux: YZ(self.mx.0 * rhs.uw.0) - YZ(self.my.0 * rhs.uz.0) + YZ(self.mz.0 * rhs.uy.0),
+		//     uy: ZX(self.mx.0 * rhs.uz.0) + ZX(self.my.0 * rhs.uw.0) - ZX(self.mz.0 * rhs.ux.0),
+		//     uz: -XY(self.mx.0 * rhs.uy.0) + XY(self.my.0 * rhs.ux.0) + XY(self.mz.0 * rhs.uw.0),
+		//     uw: -S(self.mx.0 * rhs.ux.0) - S(self.my.0 * rhs.uy.0) - S(self.mz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.mx.geometric(rhs.rw)
+				+ self.my.geometric(rhs.rz)
+				+ self.mz.geometric(rhs.ry)
+				+ self.vx.geometric(rhs.uw)
+				+ self.vy.geometric(rhs.uz)
+				+ self.vz.geometric(rhs.uy),
+			ry: self.mx.geometric(rhs.rz)
+				+ self.my.geometric(rhs.rw)
+				+ self.mz.geometric(rhs.rx)
+				+ self.vx.geometric(rhs.uz)
+				+ self.vy.geometric(rhs.uw)
+				+ self.vz.geometric(rhs.ux),
+			rz: self.mx.geometric(rhs.ry)
+				+ self.my.geometric(rhs.rx)
+				+ self.mz.geometric(rhs.rw)
+				+ self.vx.geometric(rhs.uy)
+				+ self.vy.geometric(rhs.ux)
+				+ self.vz.geometric(rhs.uw),
+			rw: self.mx.geometric(rhs.rx)
+				+ self.my.geometric(rhs.ry)
+				+ self.mz.geometric(rhs.rz)
+				+ self.vx.geometric(rhs.ux)
+				+ self.vy.geometric(rhs.uy)
+				+ self.vz.geometric(rhs.uz),
+			ux: self.mx.geometric(rhs.uw) + self.my.geometric(rhs.uz) + self.mz.geometric(rhs.uy),
+			uy: self.mx.geometric(rhs.uz) + self.my.geometric(rhs.uw) + self.mz.geometric(rhs.ux),
+			uz: self.mx.geometric(rhs.uy) + self.my.geometric(rhs.ux) + self.mz.geometric(rhs.uw),
+			uw: self.mx.geometric(rhs.ux) + self.my.geometric(rhs.uy) + self.mz.geometric(rhs.uz),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor3> for Line3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Motor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line3.anti_geometric(Motor3) -> Motor3
+impl AntiGeometric<Motor3> for Line3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vx.0 * rhs.rw.0) + WX(self.vy.0 * rhs.rz.0) - WX(self.vz.0 * rhs.ry.0),
+		//     ry: -WY(self.vx.0 * rhs.rz.0) + WY(self.vy.0 * rhs.rw.0) + WY(self.vz.0 * rhs.rx.0),
+		//     rz: WZ(self.vx.0 * rhs.ry.0) - WZ(self.vy.0 * rhs.rx.0) + WZ(self.vz.0 * rhs.rw.0),
+		//     rw: -XYZW(self.vx.0 * rhs.rx.0) - XYZW(self.vy.0 * rhs.ry.0) - XYZW(self.vz.0 * rhs.rz.0),
+		//     ux: YZ(self.mx.0 * rhs.rw.0) + YZ(self.my.0 * rhs.rz.0) - YZ(self.mz.0 * rhs.ry.0) + YZ(self.vx.0 * rhs.uw.0) + YZ(self.vy.0 * rhs.uz.0) - YZ(self.vz.0 * rhs.uy.0),
+		//     uy: -ZX(self.mx.0 * rhs.rz.0) + ZX(self.my.0 * rhs.rw.0) + ZX(self.mz.0 * rhs.rx.0) - ZX(self.vx.0 * rhs.uz.0) + ZX(self.vy.0 * rhs.uw.0) + ZX(self.vz.0 * rhs.ux.0),
+		//     uz: XY(self.mx.0 * rhs.ry.0) - XY(self.my.0 * rhs.rx.0) + XY(self.mz.0 * rhs.rw.0) + XY(self.vx.0 * rhs.uy.0) - XY(self.vy.0 * rhs.ux.0) + XY(self.vz.0 * rhs.uw.0),
+		//     uw: -S(self.mx.0 * rhs.rx.0) - S(self.my.0 * rhs.ry.0) - S(self.mz.0 * rhs.rz.0) - S(self.vx.0 * rhs.ux.0) - S(self.vy.0 * rhs.uy.0) - S(self.vz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.vx.anti_geometric(rhs.rw) + self.vy.anti_geometric(rhs.rz) + self.vz.anti_geometric(rhs.ry),
+			ry: self.vx.anti_geometric(rhs.rz) + self.vy.anti_geometric(rhs.rw) + self.vz.anti_geometric(rhs.rx),
+			rz: self.vx.anti_geometric(rhs.ry) + self.vy.anti_geometric(rhs.rx) + self.vz.anti_geometric(rhs.rw),
+			rw: self.vx.anti_geometric(rhs.rx) + self.vy.anti_geometric(rhs.ry) + self.vz.anti_geometric(rhs.rz),
+			ux: self.mx.anti_geometric(rhs.rw)
+				+ self.my.anti_geometric(rhs.rz)
+				+ self.mz.anti_geometric(rhs.ry)
+				+ self.vx.anti_geometric(rhs.uw)
+				+ self.vy.anti_geometric(rhs.uz)
+				+ self.vz.anti_geometric(rhs.uy),
+			uy: self.mx.anti_geometric(rhs.rz)
+				+ self.my.anti_geometric(rhs.rw)
+				+ self.mz.anti_geometric(rhs.rx)
+				+ self.vx.anti_geometric(rhs.uz)
+				+ self.vy.anti_geometric(rhs.uw)
+				+ self.vz.anti_geometric(rhs.ux),
+			uz: self.mx.anti_geometric(rhs.ry)
+				+ self.my.anti_geometric(rhs.rx)
+				+ self.mz.anti_geometric(rhs.rw)
+				+ self.vx.anti_geometric(rhs.uy)
+				+ self.vy.anti_geometric(rhs.ux)
+				+ self.vz.anti_geometric(rhs.uw),
+			uw: self.mx.anti_geometric(rhs.rx)
+				+ self.my.anti_geometric(rhs.ry)
+				+ self.mz.anti_geometric(rhs.rz)
+				+ self.vx.anti_geometric(rhs.ux)
+				+ self.vy.anti_geometric(rhs.uy)
+				+ self.vz.anti_geometric(rhs.uz),
+		}
+	}
+}
+
+// Line3.dot(Motor3) -> Motor3
+impl Dot<Motor3> for Line3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.rw.0) + WX(self.vx.0 * rhs.uw.0),
+		//     ry: WY(self.my.0 * rhs.rw.0) + WY(self.vy.0 * rhs.uw.0),
+		//     rz: WZ(self.mz.0 * rhs.rw.0) + WZ(self.vz.0 * rhs.uw.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.uw.0),
+		//     uy: ZX(self.my.0 * rhs.uw.0),
+		//     uz: XY(self.mz.0 * rhs.uw.0),
+		//     uw: -S(self.mx.0 * rhs.ux.0) - S(self.my.0 * rhs.uy.0) - S(self.mz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.mx.dot(rhs.rw) + self.vx.dot(rhs.uw),
+			ry: self.my.dot(rhs.rw) + self.vy.dot(rhs.uw),
+			rz: self.mz.dot(rhs.rw) + self.vz.dot(rhs.uw),
+			rw: Default::default(),
+			ux: self.mx.dot(rhs.uw),
+			uy: self.my.dot(rhs.uw),
+			uz: self.mz.dot(rhs.uw),
+			uw: self.mx.dot(rhs.ux) + self.my.dot(rhs.uy) + self.mz.dot(rhs.uz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor3> for Line3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Motor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line3.wedge(Motor3) -> Motor3
+impl Wedge<Motor3> for Line3 {
+	type Output = Motor3;
+	fn wedge(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vx.0 * rhs.uw.0),
+		//     ry: WY(self.vy.0 * rhs.uw.0),
+		//     rz: WZ(self.vz.0 * rhs.uw.0),
+		//     rw: -XYZW(self.mx.0 * rhs.rx.0) - XYZW(self.my.0 * rhs.ry.0) - XYZW(self.mz.0 * rhs.rz.0) - XYZW(self.vx.0 * rhs.ux.0) - XYZW(self.vy.0 * rhs.uy.0) - XYZW(self.vz.0 * rhs.uz.0),
+		//     ux: YZ(self.mx.0 * rhs.uw.0),
+		//     uy: ZX(self.my.0 * rhs.uw.0),
+		//     uz: XY(self.mz.0 * rhs.uw.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.vx.wedge(rhs.uw),
+			ry: self.vy.wedge(rhs.uw),
+			rz: self.vz.wedge(rhs.uw),
+			rw: self.mx.wedge(rhs.rx)
+				+ self.my.wedge(rhs.ry)
+				+ self.mz.wedge(rhs.rz)
+				+ self.vx.wedge(rhs.ux)
+				+ self.vy.wedge(rhs.uy)
+				+ self.vz.wedge(rhs.uz),
+			ux: self.mx.wedge(rhs.uw),
+			uy: self.my.wedge(rhs.uw),
+			uz: self.mz.wedge(rhs.uw),
+			uw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Motor3> for Line3 {
+	type Output = Motor3;
+	fn bitxor(self, rhs: Motor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line3.anti_wedge(Motor3) -> Motor3
+impl AntiWedge<Motor3> for Line3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vx.0 * rhs.rw.0),
+		//     ry: WY(self.vy.0 * rhs.rw.0),
+		//     rz: WZ(self.vz.0 * rhs.rw.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.rw.0),
+		//     uy: ZX(self.my.0 * rhs.rw.0),
+		//     uz: XY(self.mz.0 * rhs.rw.0),
+		//     uw: -S(self.mx.0 * rhs.rx.0) - S(self.my.0 * rhs.ry.0) - S(self.mz.0 * rhs.rz.0) - S(self.vx.0 * rhs.ux.0) - S(self.vy.0 * rhs.uy.0) - S(self.vz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.vx.anti_wedge(rhs.rw),
+			ry: self.vy.anti_wedge(rhs.rw),
+			rz: self.vz.anti_wedge(rhs.rw),
+			rw: Default::default(),
+			ux: self.mx.anti_wedge(rhs.rw),
+			uy: self.my.anti_wedge(rhs.rw),
+			uz: self.mz.anti_wedge(rhs.rw),
+			uw: self.mx.anti_wedge(rhs.rx)
+				+ self.my.anti_wedge(rhs.ry)
+				+ self.mz.anti_wedge(rhs.rz)
+				+ self.vx.anti_wedge(rhs.ux)
+				+ self.vy.anti_wedge(rhs.uy)
+				+ self.vz.anti_wedge(rhs.uz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor3> for Line3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Motor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line3.left_contraction(Motor3) -> Motor3
+impl LeftContraction<Motor3> for Line3 {
+	type Output = Motor3;
+	fn left_contraction(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.rw.0),
+		//     ry: WY(self.my.0 * rhs.rw.0),
+		//     rz: WZ(self.mz.0 * rhs.rw.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.mx.0 * rhs.ux.0) - S(self.my.0 * rhs.uy.0) - S(self.mz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.mx.left_contraction(rhs.rw),
+			ry: self.my.left_contraction(rhs.rw),
+			rz: self.mz.left_contraction(rhs.rw),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.mx.left_contraction(rhs.ux) + self.my.left_contraction(rhs.uy) + self.mz.left_contraction(rhs.uz),
+		}
+	}
+}
+
+// Line3.right_contraction(Motor3) -> Motor3
+impl RightContraction<Motor3> for Line3 {
+	type Output = Motor3;
+	fn right_contraction(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vx.0 * rhs.uw.0),
+		//     ry: WY(self.vy.0 * rhs.uw.0),
+		//     rz: WZ(self.vz.0 * rhs.uw.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.uw.0),
+		//     uy: ZX(self.my.0 * rhs.uw.0),
+		//     uz: XY(self.mz.0 * rhs.uw.0),
+		//     uw: -S(self.mx.0 * rhs.ux.0) - S(self.my.0 * rhs.uy.0) - S(self.mz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.vx.right_contraction(rhs.uw),
+			ry: self.vy.right_contraction(rhs.uw),
+			rz: self.vz.right_contraction(rhs.uw),
+			rw: Default::default(),
+			ux: self.mx.right_contraction(rhs.uw),
+			uy: self.my.right_contraction(rhs.uw),
+			uz: self.mz.right_contraction(rhs.uw),
+			uw: self.mx.right_contraction(rhs.ux)
+				+ self.my.right_contraction(rhs.uy)
+				+ self.mz.right_contraction(rhs.uz),
+		}
+	}
+}
+
+// Line3.scalar_product(Motor3) -> S
+impl ScalarProduct<Motor3> for Line3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Motor3) -> Self::Output {
+		// -S(self.mx.0 * rhs.ux.0) - S(self.my.0 * rhs.uy.0) - S(self.mz.0 * rhs.uz.0)
+		self.mx.scalar_product(rhs.ux) + self.my.scalar_product(rhs.uy) + self.mz.scalar_product(rhs.uz)
+	}
+}
+
+// Line3.commutator(Motor3) -> Line3
+impl Commutator<Motor3> for Line3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Motor3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.my.0 * rhs.rz.0) + WX(self.mz.0 * rhs.ry.0) - WX(self.vy.0 * rhs.uz.0) + WX(self.vz.0 * rhs.uy.0),
+		//     vy: WY(self.mx.0 * rhs.rz.0) - WY(self.mz.0 * rhs.rx.0) + WY(self.vx.0 * rhs.uz.0) - WY(self.vz.0 * rhs.ux.0),
+		//     vz: -WZ(self.mx.0 * rhs.ry.0) + WZ(self.my.0 * rhs.rx.0) - WZ(self.vx.0 * rhs.uy.0) + WZ(self.vy.0 * rhs.ux.0),
+		//     mx: -YZ(self.my.0 * rhs.uz.0) + YZ(self.mz.0 * rhs.uy.0),
+		//     my: ZX(self.mx.0 * rhs.uz.0) - ZX(self.mz.0 * rhs.ux.0),
+		//     mz: -XY(self.mx.0 * rhs.uy.0) + XY(self.my.0 * rhs.ux.0),
+		// }
+		Line3 {
+			vx: self.my.commutator(rhs.rz)
+				+ self.mz.commutator(rhs.ry)
+				+ self.vy.commutator(rhs.uz)
+				+ self.vz.commutator(rhs.uy),
+			vy: self.mx.commutator(rhs.rz)
+				+ self.mz.commutator(rhs.rx)
+				+ self.vx.commutator(rhs.uz)
+				+ self.vz.commutator(rhs.ux),
+			vz: self.mx.commutator(rhs.ry)
+				+ self.my.commutator(rhs.rx)
+				+ self.vx.commutator(rhs.uy)
+				+ self.vy.commutator(rhs.ux),
+			mx: self.my.commutator(rhs.uz) + self.mz.commutator(rhs.uy),
+			my: self.mx.commutator(rhs.uz) + self.mz.commutator(rhs.ux),
+			mz: self.mx.commutator(rhs.uy) + self.my.commutator(rhs.ux),
+		}
+	}
+}
+
+// Line3.anti_dot(Motor3) -> Motor3
+impl AntiDot<Motor3> for Line3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.vx.0 * rhs.rw.0),
+		//     ry: WY(self.vy.0 * rhs.rw.0),
+		//     rz: WZ(self.vz.0 * rhs.rw.0),
+		//     rw: -XYZW(self.vx.0 * rhs.rx.0) - XYZW(self.vy.0 * rhs.ry.0) - XYZW(self.vz.0 * rhs.rz.0),
+		//     ux: YZ(self.mx.0 * rhs.rw.0) + YZ(self.vx.0 * rhs.uw.0),
+		//     uy: ZX(self.my.0 * rhs.rw.0) + ZX(self.vy.0 * rhs.uw.0),
+		//     uz: XY(self.mz.0 * rhs.rw.0) + XY(self.vz.0 * rhs.uw.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.vx.anti_dot(rhs.rw),
+			ry: self.vy.anti_dot(rhs.rw),
+			rz: self.vz.anti_dot(rhs.rw),
+			rw: self.vx.anti_dot(rhs.rx) + self.vy.anti_dot(rhs.ry) + self.vz.anti_dot(rhs.rz),
+			ux: self.mx.anti_dot(rhs.rw) + self.vx.anti_dot(rhs.uw),
+			uy: self.my.anti_dot(rhs.rw) + self.vy.anti_dot(rhs.uw),
+			uz: self.mz.anti_dot(rhs.rw) + self.vz.anti_dot(rhs.uw),
+			uw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Line3 OP Multivector:
+
+// Line3.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Line3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.yz.0) - S(self.my.0 * rhs.zx.0) - S(self.mz.0 * rhs.xy.0),
+		//     x   : -X(self.mx.0 * rhs.xyz.0) - X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.my.0 * rhs.xyz.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0) - Z(self.mz.0 * rhs.xyz.0),
+		//     w   : -W(self.mx.0 * rhs.yzw.0) - W(self.my.0 * rhs.zxw.0) - W(self.mz.0 * rhs.xyw.0) + W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		//     wx  : WX(self.mx.0 * rhs.xyzw.0) - WX(self.my.0 * rhs.wz.0) + WX(self.mz.0 * rhs.wy.0) + WX(self.vx.0 * rhs.s.0) - WX(self.vy.0 * rhs.xy.0) + WX(self.vz.0 * rhs.zx.0),
+		//     wy  : WY(self.mx.0 * rhs.wz.0) + WY(self.my.0 * rhs.xyzw.0) - WY(self.mz.0 * rhs.wx.0) + WY(self.vx.0 * rhs.xy.0) + WY(self.vy.0 * rhs.s.0) - WY(self.vz.0 * rhs.yz.0),
+		//     wz  : -WZ(self.mx.0 * rhs.wy.0) + WZ(self.my.0 * rhs.wx.0) + WZ(self.mz.0 * rhs.xyzw.0) - WZ(self.vx.0 * rhs.zx.0) + WZ(self.vy.0 * rhs.yz.0) + WZ(self.vz.0 * rhs.s.0),
+		//     yz  : YZ(self.mx.0 * rhs.s.0) - YZ(self.my.0 * rhs.xy.0) + YZ(self.mz.0 * rhs.zx.0),
+		//     zx  : ZX(self.mx.0 * rhs.xy.0) + ZX(self.my.0 * rhs.s.0) - ZX(self.mz.0 * rhs.yz.0),
+		//     xy  : -XY(self.mx.0 * rhs.zx.0) + XY(self.my.0 * rhs.yz.0) + XY(self.mz.0 * rhs.s.0),
+		//     yzw : YZW(self.mx.0 * rhs.w.0) - YZW(self.my.0 * rhs.xyw.0) + YZW(self.mz.0 * rhs.zxw.0) + YZW(self.vx.0 * rhs.xyz.0) + YZW(self.vy.0 * rhs.z.0) - YZW(self.vz.0 * rhs.y.0),
+		//     zxw : ZXW(self.mx.0 * rhs.xyw.0) + ZXW(self.my.0 * rhs.w.0) - ZXW(self.mz.0 * rhs.yzw.0) - ZXW(self.vx.0 * rhs.z.0) + ZXW(self.vy.0 * rhs.xyz.0) + ZXW(self.vz.0 * rhs.x.0),
+		//     xyw : -XYW(self.mx.0 * rhs.zxw.0) + XYW(self.my.0 * rhs.yzw.0) + XYW(self.mz.0 * rhs.w.0) + XYW(self.vx.0 * rhs.y.0) - XYW(self.vy.0 * rhs.x.0) + XYW(self.vz.0 * rhs.xyz.0),
+		//     xyz : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.mx.0 * rhs.wx.0) - XYZW(self.my.0 * rhs.wy.0) - XYZW(self.mz.0 * rhs.wz.0) - XYZW(self.vx.0 * rhs.yz.0) - XYZW(self.vy.0 * rhs.zx.0) - XYZW(self.vz.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: self.mx.geometric(rhs.yz) + self.my.geometric(rhs.zx) + self.mz.geometric(rhs.xy),
+			x: self.mx.geometric(rhs.xyz) + self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			y: self.mx.geometric(rhs.z) + self.my.geometric(rhs.xyz) + self.mz.geometric(rhs.x),
+			z: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x) + self.mz.geometric(rhs.xyz),
+			w: self.mx.geometric(rhs.yzw)
+				+ self.my.geometric(rhs.zxw)
+				+ self.mz.geometric(rhs.xyw)
+				+ self.vx.geometric(rhs.x)
+				+ self.vy.geometric(rhs.y)
+				+ self.vz.geometric(rhs.z),
+			wx: self.mx.geometric(rhs.xyzw)
+				+ self.my.geometric(rhs.wz)
+				+ self.mz.geometric(rhs.wy)
+				+ self.vx.geometric(rhs.s)
+				+ self.vy.geometric(rhs.xy)
+				+ self.vz.geometric(rhs.zx),
+			wy: self.mx.geometric(rhs.wz)
+				+ self.my.geometric(rhs.xyzw)
+				+ self.mz.geometric(rhs.wx)
+				+ self.vx.geometric(rhs.xy)
+				+ self.vy.geometric(rhs.s)
+				+ self.vz.geometric(rhs.yz),
+			wz: self.mx.geometric(rhs.wy)
+				+ self.my.geometric(rhs.wx)
+				+ self.mz.geometric(rhs.xyzw)
+				+ self.vx.geometric(rhs.zx)
+				+ self.vy.geometric(rhs.yz)
+				+ self.vz.geometric(rhs.s),
+			yz: self.mx.geometric(rhs.s) + self.my.geometric(rhs.xy) + self.mz.geometric(rhs.zx),
+			zx: self.mx.geometric(rhs.xy) + self.my.geometric(rhs.s) + self.mz.geometric(rhs.yz),
+			xy: self.mx.geometric(rhs.zx) + self.my.geometric(rhs.yz) + self.mz.geometric(rhs.s),
+			yzw: self.mx.geometric(rhs.w)
+				+ self.my.geometric(rhs.xyw)
+				+ self.mz.geometric(rhs.zxw)
+				+ self.vx.geometric(rhs.xyz)
+				+ self.vy.geometric(rhs.z)
+				+ self.vz.geometric(rhs.y),
+			zxw: self.mx.geometric(rhs.xyw)
+				+ self.my.geometric(rhs.w)
+				+ self.mz.geometric(rhs.yzw)
+				+ self.vx.geometric(rhs.z)
+				+ self.vy.geometric(rhs.xyz)
+				+ self.vz.geometric(rhs.x),
+			xyw: self.mx.geometric(rhs.zxw)
+				+ self.my.geometric(rhs.yzw)
+				+ self.mz.geometric(rhs.w)
+				+ self.vx.geometric(rhs.y)
+				+ self.vy.geometric(rhs.x)
+				+ self.vz.geometric(rhs.xyz),
+			xyz: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+			xyzw: self.mx.geometric(rhs.wx)
+				+ self.my.geometric(rhs.wy)
+				+ self.mz.geometric(rhs.wz)
+				+ self.vx.geometric(rhs.yz)
+				+ self.vy.geometric(rhs.zx)
+				+ self.vz.geometric(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Line3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Line3.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Line3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.wx.0) - S(self.my.0 * rhs.wy.0) - S(self.mz.0 * rhs.wz.0) - S(self.vx.0 * rhs.yz.0) - S(self.vy.0 * rhs.zx.0) - S(self.vz.0 * rhs.xy.0),
+		//     x   : X(self.mx.0 * rhs.w.0) + X(self.my.0 * rhs.xyw.0) - X(self.mz.0 * rhs.zxw.0) - X(self.vx.0 * rhs.xyz.0) + X(self.vy.0 * rhs.z.0) - X(self.vz.0 * rhs.y.0),
+		//     y   : -Y(self.mx.0 * rhs.xyw.0) + Y(self.my.0 * rhs.w.0) + Y(self.mz.0 * rhs.yzw.0) - Y(self.vx.0 * rhs.z.0) - Y(self.vy.0 * rhs.xyz.0) + Y(self.vz.0 * rhs.x.0),
+		//     z   : Z(self.mx.0 * rhs.zxw.0) - Z(self.my.0 * rhs.yzw.0) + Z(self.mz.0 * rhs.w.0) + Z(self.vx.0 * rhs.y.0) - Z(self.vy.0 * rhs.x.0) - Z(self.vz.0 * rhs.xyz.0),
+		//     w   : -W(self.vx.0 * rhs.yzw.0) - W(self.vy.0 * rhs.zxw.0) - W(self.vz.0 * rhs.xyw.0),
+		//     wx  : WX(self.vx.0 * rhs.xyzw.0) + WX(self.vy.0 * rhs.wz.0) - WX(self.vz.0 * rhs.wy.0),
+		//     wy  : -WY(self.vx.0 * rhs.wz.0) + WY(self.vy.0 * rhs.xyzw.0) + WY(self.vz.0 * rhs.wx.0),
+		//     wz  : WZ(self.vx.0 * rhs.wy.0) - WZ(self.vy.0 * rhs.wx.0) + WZ(self.vz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.mx.0 * rhs.xyzw.0) + YZ(self.my.0 * rhs.wz.0) - YZ(self.mz.0 * rhs.wy.0) + YZ(self.vx.0 * rhs.s.0) + YZ(self.vy.0 * rhs.xy.0) - YZ(self.vz.0 * rhs.zx.0),
+		//     zx  : -ZX(self.mx.0 * rhs.wz.0) + ZX(self.my.0 * rhs.xyzw.0) + ZX(self.mz.0 * rhs.wx.0) - ZX(self.vx.0 * rhs.xy.0) + ZX(self.vy.0 * rhs.s.0) + ZX(self.vz.0 * rhs.yz.0),
+		//     xy  : XY(self.mx.0 * rhs.wy.0) - XY(self.my.0 * rhs.wx.0) + XY(self.mz.0 * rhs.xyzw.0) + XY(self.vx.0 * rhs.zx.0) - XY(self.vy.0 * rhs.yz.0) + XY(self.vz.0 * rhs.s.0),
+		//     yzw : YZW(self.vx.0 * rhs.w.0) + YZW(self.vy.0 * rhs.xyw.0) - YZW(self.vz.0 * rhs.zxw.0),
+		//     zxw : -ZXW(self.vx.0 * rhs.xyw.0) + ZXW(self.vy.0 * rhs.w.0) + ZXW(self.vz.0 * rhs.yzw.0),
+		//     xyw : XYW(self.vx.0 * rhs.zxw.0) - XYW(self.vy.0 * rhs.yzw.0) + XYW(self.vz.0 * rhs.w.0),
+		//     xyz : XYZ(self.mx.0 * rhs.yzw.0) + XYZ(self.my.0 * rhs.zxw.0) + XYZ(self.mz.0 * rhs.xyw.0) + XYZ(self.vx.0 * rhs.x.0) + XYZ(self.vy.0 * rhs.y.0) + XYZ(self.vz.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.vx.0 * rhs.wx.0) - XYZW(self.vy.0 * rhs.wy.0) - XYZW(self.vz.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: self.mx.anti_geometric(rhs.wx)
+				+ self.my.anti_geometric(rhs.wy)
+				+ self.mz.anti_geometric(rhs.wz)
+				+ self.vx.anti_geometric(rhs.yz)
+				+ self.vy.anti_geometric(rhs.zx)
+				+ self.vz.anti_geometric(rhs.xy),
+			x: self.mx.anti_geometric(rhs.w)
+				+ self.my.anti_geometric(rhs.xyw)
+				+ self.mz.anti_geometric(rhs.zxw)
+				+ self.vx.anti_geometric(rhs.xyz)
+				+ self.vy.anti_geometric(rhs.z)
+				+ self.vz.anti_geometric(rhs.y),
+			y: self.mx.anti_geometric(rhs.xyw)
+				+ self.my.anti_geometric(rhs.w)
+				+ self.mz.anti_geometric(rhs.yzw)
+				+ self.vx.anti_geometric(rhs.z)
+				+ self.vy.anti_geometric(rhs.xyz)
+				+ self.vz.anti_geometric(rhs.x),
+			z: self.mx.anti_geometric(rhs.zxw)
+				+ self.my.anti_geometric(rhs.yzw)
+				+ self.mz.anti_geometric(rhs.w)
+				+ self.vx.anti_geometric(rhs.y)
+				+ self.vy.anti_geometric(rhs.x)
+				+ self.vz.anti_geometric(rhs.xyz),
+			w: self.vx.anti_geometric(rhs.yzw) + self.vy.anti_geometric(rhs.zxw) + self.vz.anti_geometric(rhs.xyw),
+			wx: self.vx.anti_geometric(rhs.xyzw) + self.vy.anti_geometric(rhs.wz) + self.vz.anti_geometric(rhs.wy),
+			wy: self.vx.anti_geometric(rhs.wz) + self.vy.anti_geometric(rhs.xyzw) + self.vz.anti_geometric(rhs.wx),
+			wz: self.vx.anti_geometric(rhs.wy) + self.vy.anti_geometric(rhs.wx) + self.vz.anti_geometric(rhs.xyzw),
+			yz: self.mx.anti_geometric(rhs.xyzw)
+				+ self.my.anti_geometric(rhs.wz)
+				+ self.mz.anti_geometric(rhs.wy)
+				+ self.vx.anti_geometric(rhs.s)
+				+ self.vy.anti_geometric(rhs.xy)
+				+ self.vz.anti_geometric(rhs.zx),
+			zx: self.mx.anti_geometric(rhs.wz)
+				+ self.my.anti_geometric(rhs.xyzw)
+				+ self.mz.anti_geometric(rhs.wx)
+				+ self.vx.anti_geometric(rhs.xy)
+				+ self.vy.anti_geometric(rhs.s)
+				+ self.vz.anti_geometric(rhs.yz),
+			xy: self.mx.anti_geometric(rhs.wy)
+				+ self.my.anti_geometric(rhs.wx)
+				+ self.mz.anti_geometric(rhs.xyzw)
+				+ self.vx.anti_geometric(rhs.zx)
+				+ self.vy.anti_geometric(rhs.yz)
+				+ self.vz.anti_geometric(rhs.s),
+			yzw: self.vx.anti_geometric(rhs.w) + self.vy.anti_geometric(rhs.xyw) + self.vz.anti_geometric(rhs.zxw),
+			zxw: self.vx.anti_geometric(rhs.xyw) + self.vy.anti_geometric(rhs.w) + self.vz.anti_geometric(rhs.yzw),
+			xyw: self.vx.anti_geometric(rhs.zxw) + self.vy.anti_geometric(rhs.yzw) + self.vz.anti_geometric(rhs.w),
+			xyz: self.mx.anti_geometric(rhs.yzw)
+				+ self.my.anti_geometric(rhs.zxw)
+				+ self.mz.anti_geometric(rhs.xyw)
+				+ self.vx.anti_geometric(rhs.x)
+				+ self.vy.anti_geometric(rhs.y)
+				+ self.vz.anti_geometric(rhs.z),
+			xyzw: self.vx.anti_geometric(rhs.wx) + self.vy.anti_geometric(rhs.wy) + self.vz.anti_geometric(rhs.wz),
+		}
+	}
+}
+
+// Line3.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Line3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.yz.0) - S(self.my.0 * rhs.zx.0) - S(self.mz.0 * rhs.xy.0),
+		//     x   : -X(self.mx.0 * rhs.xyz.0) - X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.my.0 * rhs.xyz.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0) - Z(self.mz.0 * rhs.xyz.0),
+		//     w   : -W(self.mx.0 * rhs.yzw.0) - W(self.my.0 * rhs.zxw.0) - W(self.mz.0 * rhs.xyw.0) + W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		//     wx  : WX(self.mx.0 * rhs.xyzw.0) + WX(self.vx.0 * rhs.s.0),
+		//     wy  : WY(self.my.0 * rhs.xyzw.0) + WY(self.vy.0 * rhs.s.0),
+		//     wz  : WZ(self.mz.0 * rhs.xyzw.0) + WZ(self.vz.0 * rhs.s.0),
+		//     yz  : YZ(self.mx.0 * rhs.s.0),
+		//     zx  : ZX(self.my.0 * rhs.s.0),
+		//     xy  : XY(self.mz.0 * rhs.s.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.mx.dot(rhs.yz) + self.my.dot(rhs.zx) + self.mz.dot(rhs.xy),
+			x: self.mx.dot(rhs.xyz) + self.my.dot(rhs.z) + self.mz.dot(rhs.y),
+			y: self.mx.dot(rhs.z) + self.my.dot(rhs.xyz) + self.mz.dot(rhs.x),
+			z: self.mx.dot(rhs.y) + self.my.dot(rhs.x) + self.mz.dot(rhs.xyz),
+			w: self.mx.dot(rhs.yzw)
+				+ self.my.dot(rhs.zxw)
+				+ self.mz.dot(rhs.xyw)
+				+ self.vx.dot(rhs.x)
+				+ self.vy.dot(rhs.y)
+				+ self.vz.dot(rhs.z),
+			wx: self.mx.dot(rhs.xyzw) + self.vx.dot(rhs.s),
+			wy: self.my.dot(rhs.xyzw) + self.vy.dot(rhs.s),
+			wz: self.mz.dot(rhs.xyzw) + self.vz.dot(rhs.s),
+			yz: self.mx.dot(rhs.s),
+			zx: self.my.dot(rhs.s),
+			xy: self.mz.dot(rhs.s),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Line3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Line3.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Line3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : WX(self.vx.0 * rhs.s.0),
+		//     wy  : WY(self.vy.0 * rhs.s.0),
+		//     wz  : WZ(self.vz.0 * rhs.s.0),
+		//     yz  : YZ(self.mx.0 * rhs.s.0),
+		//     zx  : ZX(self.my.0 * rhs.s.0),
+		//     xy  : XY(self.mz.0 * rhs.s.0),
+		//     yzw : YZW(self.mx.0 * rhs.w.0) + YZW(self.vy.0 * rhs.z.0) - YZW(self.vz.0 * rhs.y.0),
+		//     zxw : ZXW(self.my.0 * rhs.w.0) - ZXW(self.vx.0 * rhs.z.0) + ZXW(self.vz.0 * rhs.x.0),
+		//     xyw : XYW(self.mz.0 * rhs.w.0) + XYW(self.vx.0 * rhs.y.0) - XYW(self.vy.0 * rhs.x.0),
+		//     xyz : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.mx.0 * rhs.wx.0) - XYZW(self.my.0 * rhs.wy.0) - XYZW(self.mz.0 * rhs.wz.0) - XYZW(self.vx.0 * rhs.yz.0) - XYZW(self.vy.0 * rhs.zx.0) - XYZW(self.vz.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: self.vx.wedge(rhs.s),
+			wy: self.vy.wedge(rhs.s),
+			wz: self.vz.wedge(rhs.s),
+			yz: self.mx.wedge(rhs.s),
+			zx: self.my.wedge(rhs.s),
+			xy: self.mz.wedge(rhs.s),
+			yzw: self.mx.wedge(rhs.w) + self.vy.wedge(rhs.z) + self.vz.wedge(rhs.y),
+			zxw: self.my.wedge(rhs.w) + self.vx.wedge(rhs.z) + self.vz.wedge(rhs.x),
+			xyw: self.mz.wedge(rhs.w) + self.vx.wedge(rhs.y) + self.vy.wedge(rhs.x),
+			xyz: self.mx.wedge(rhs.x) + self.my.wedge(rhs.y) + self.mz.wedge(rhs.z),
+			xyzw: self.mx.wedge(rhs.wx)
+				+ self.my.wedge(rhs.wy)
+				+ self.mz.wedge(rhs.wz)
+				+ self.vx.wedge(rhs.yz)
+				+ self.vy.wedge(rhs.zx)
+				+ self.vz.wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Line3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Line3.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Line3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.wx.0) - S(self.my.0 * rhs.wy.0) - S(self.mz.0 * rhs.wz.0) - S(self.vx.0 * rhs.yz.0) - S(self.vy.0 * rhs.zx.0) - S(self.vz.0 * rhs.xy.0),
+		//     x   : X(self.my.0 * rhs.xyw.0) - X(self.mz.0 * rhs.zxw.0) - X(self.vx.0 * rhs.xyz.0),
+		//     y   : -Y(self.mx.0 * rhs.xyw.0) + Y(self.mz.0 * rhs.yzw.0) - Y(self.vy.0 * rhs.xyz.0),
+		//     z   : Z(self.mx.0 * rhs.zxw.0) - Z(self.my.0 * rhs.yzw.0) - Z(self.vz.0 * rhs.xyz.0),
+		//     w   : -W(self.vx.0 * rhs.yzw.0) - W(self.vy.0 * rhs.zxw.0) - W(self.vz.0 * rhs.xyw.0),
+		//     wx  : WX(self.vx.0 * rhs.xyzw.0),
+		//     wy  : WY(self.vy.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.vz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.mx.0 * rhs.xyzw.0),
+		//     zx  : ZX(self.my.0 * rhs.xyzw.0),
+		//     xy  : XY(self.mz.0 * rhs.xyzw.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.mx.anti_wedge(rhs.wx)
+				+ self.my.anti_wedge(rhs.wy)
+				+ self.mz.anti_wedge(rhs.wz)
+				+ self.vx.anti_wedge(rhs.yz)
+				+ self.vy.anti_wedge(rhs.zx)
+				+ self.vz.anti_wedge(rhs.xy),
+			x: self.my.anti_wedge(rhs.xyw) + self.mz.anti_wedge(rhs.zxw) + self.vx.anti_wedge(rhs.xyz),
+			y: self.mx.anti_wedge(rhs.xyw) + self.mz.anti_wedge(rhs.yzw) + self.vy.anti_wedge(rhs.xyz),
+			z: self.mx.anti_wedge(rhs.zxw) + self.my.anti_wedge(rhs.yzw) + self.vz.anti_wedge(rhs.xyz),
+			w: self.vx.anti_wedge(rhs.yzw) + self.vy.anti_wedge(rhs.zxw) + self.vz.anti_wedge(rhs.xyw),
+			wx: self.vx.anti_wedge(rhs.xyzw),
+			wy: self.vy.anti_wedge(rhs.xyzw),
+			wz: self.vz.anti_wedge(rhs.xyzw),
+			yz: self.mx.anti_wedge(rhs.xyzw),
+			zx: self.my.anti_wedge(rhs.xyzw),
+			xy: self.mz.anti_wedge(rhs.xyzw),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Line3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Line3.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Line3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.yz.0) - S(self.my.0 * rhs.zx.0) - S(self.mz.0 * rhs.xy.0),
+		//     x   : -X(self.mx.0 * rhs.xyz.0),
+		//     y   : -Y(self.my.0 * rhs.xyz.0),
+		//     z   : -Z(self.mz.0 * rhs.xyz.0),
+		//     w   : -W(self.mx.0 * rhs.yzw.0) - W(self.my.0 * rhs.zxw.0) - W(self.mz.0 * rhs.xyw.0),
+		//     wx  : WX(self.mx.0 * rhs.xyzw.0),
+		//     wy  : WY(self.my.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.mz.0 * rhs.xyzw.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.mx.left_contraction(rhs.yz) + self.my.left_contraction(rhs.zx) + self.mz.left_contraction(rhs.xy),
+			x: self.mx.left_contraction(rhs.xyz),
+			y: self.my.left_contraction(rhs.xyz),
+			z: self.mz.left_contraction(rhs.xyz),
+			w: self.mx.left_contraction(rhs.yzw)
+				+ self.my.left_contraction(rhs.zxw)
+				+ self.mz.left_contraction(rhs.xyw),
+			wx: self.mx.left_contraction(rhs.xyzw),
+			wy: self.my.left_contraction(rhs.xyzw),
+			wz: self.mz.left_contraction(rhs.xyzw),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Line3.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Line3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.yz.0) - S(self.my.0 * rhs.zx.0) - S(self.mz.0 * rhs.xy.0),
+		//     x   : -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w   : W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		//     wx  : WX(self.vx.0 * rhs.s.0),
+		//     wy  : WY(self.vy.0 * rhs.s.0),
+		//     wz  : WZ(self.vz.0 * rhs.s.0),
+		//     yz  : YZ(self.mx.0 * rhs.s.0),
+		//     zx  : ZX(self.my.0 * rhs.s.0),
+		//     xy  : XY(self.mz.0 * rhs.s.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.mx.right_contraction(rhs.yz)
+				+ self.my.right_contraction(rhs.zx)
+				+ self.mz.right_contraction(rhs.xy),
+			x: self.my.right_contraction(rhs.z) + self.mz.right_contraction(rhs.y),
+			y: self.mx.right_contraction(rhs.z) + self.mz.right_contraction(rhs.x),
+			z: self.mx.right_contraction(rhs.y) + self.my.right_contraction(rhs.x),
+			w: self.vx.right_contraction(rhs.x) + self.vy.right_contraction(rhs.y) + self.vz.right_contraction(rhs.z),
+			wx: self.vx.right_contraction(rhs.s),
+			wy: self.vy.right_contraction(rhs.s),
+			wz: self.vz.right_contraction(rhs.s),
+			yz: self.mx.right_contraction(rhs.s),
+			zx: self.my.right_contraction(rhs.s),
+			xy: self.mz.right_contraction(rhs.s),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Line3.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Line3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.mx.0 * rhs.yz.0) - S(self.my.0 * rhs.zx.0) - S(self.mz.0 * rhs.xy.0)
+		self.mx.scalar_product(rhs.yz) + self.my.scalar_product(rhs.zx) + self.mz.scalar_product(rhs.xy)
+	}
+}
+
+// Line3.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Line3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w   : W(self.vx.0 * rhs.x.0) + W(self.vy.0 * rhs.y.0) + W(self.vz.0 * rhs.z.0),
+		//     wx  : -WX(self.my.0 * rhs.wz.0) + WX(self.mz.0 * rhs.wy.0) - WX(self.vy.0 * rhs.xy.0) + WX(self.vz.0 * rhs.zx.0),
+		//     wy  : WY(self.mx.0 * rhs.wz.0) - WY(self.mz.0 * rhs.wx.0) + WY(self.vx.0 * rhs.xy.0) - WY(self.vz.0 * rhs.yz.0),
+		//     wz  : -WZ(self.mx.0 * rhs.wy.0) + WZ(self.my.0 * rhs.wx.0) - WZ(self.vx.0 * rhs.zx.0) + WZ(self.vy.0 * rhs.yz.0),
+		//     yz  : -YZ(self.my.0 * rhs.xy.0) + YZ(self.mz.0 * rhs.zx.0),
+		//     zx  : ZX(self.mx.0 * rhs.xy.0) - ZX(self.mz.0 * rhs.yz.0),
+		//     xy  : -XY(self.mx.0 * rhs.zx.0) + XY(self.my.0 * rhs.yz.0),
+		//     yzw : -YZW(self.my.0 * rhs.xyw.0) + YZW(self.mz.0 * rhs.zxw.0) + YZW(self.vx.0 * rhs.xyz.0),
+		//     zxw : ZXW(self.mx.0 * rhs.xyw.0) - ZXW(self.mz.0 * rhs.yzw.0) + ZXW(self.vy.0 * rhs.xyz.0),
+		//     xyw : -XYW(self.mx.0 * rhs.zxw.0) + XYW(self.my.0 * rhs.yzw.0) + XYW(self.vz.0 * rhs.xyz.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			y: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			z: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+			w: self.vx.commutator(rhs.x) + self.vy.commutator(rhs.y) + self.vz.commutator(rhs.z),
+			wx: self.my.commutator(rhs.wz)
+				+ self.mz.commutator(rhs.wy)
+				+ self.vy.commutator(rhs.xy)
+				+ self.vz.commutator(rhs.zx),
+			wy: self.mx.commutator(rhs.wz)
+				+ self.mz.commutator(rhs.wx)
+				+ self.vx.commutator(rhs.xy)
+				+ self.vz.commutator(rhs.yz),
+			wz: self.mx.commutator(rhs.wy)
+				+ self.my.commutator(rhs.wx)
+				+ self.vx.commutator(rhs.zx)
+				+ self.vy.commutator(rhs.yz),
+			yz: self.my.commutator(rhs.xy) + self.mz.commutator(rhs.zx),
+			zx: self.mx.commutator(rhs.xy) + self.mz.commutator(rhs.yz),
+			xy: self.mx.commutator(rhs.zx) + self.my.commutator(rhs.yz),
+			yzw: self.my.commutator(rhs.xyw) + self.mz.commutator(rhs.zxw) + self.vx.commutator(rhs.xyz),
+			zxw: self.mx.commutator(rhs.xyw) + self.mz.commutator(rhs.yzw) + self.vy.commutator(rhs.xyz),
+			xyw: self.mx.commutator(rhs.zxw) + self.my.commutator(rhs.yzw) + self.vz.commutator(rhs.xyz),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Line3.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Line3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : WX(self.vx.0 * rhs.xyzw.0),
+		//     wy  : WY(self.vy.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.vz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.mx.0 * rhs.xyzw.0) + YZ(self.vx.0 * rhs.s.0),
+		//     zx  : ZX(self.my.0 * rhs.xyzw.0) + ZX(self.vy.0 * rhs.s.0),
+		//     xy  : XY(self.mz.0 * rhs.xyzw.0) + XY(self.vz.0 * rhs.s.0),
+		//     yzw : YZW(self.vx.0 * rhs.w.0) + YZW(self.vy.0 * rhs.xyw.0) - YZW(self.vz.0 * rhs.zxw.0),
+		//     zxw : -ZXW(self.vx.0 * rhs.xyw.0) + ZXW(self.vy.0 * rhs.w.0) + ZXW(self.vz.0 * rhs.yzw.0),
+		//     xyw : XYW(self.vx.0 * rhs.zxw.0) - XYW(self.vy.0 * rhs.yzw.0) + XYW(self.vz.0 * rhs.w.0),
+		//     xyz : XYZ(self.mx.0 * rhs.yzw.0) + XYZ(self.my.0 * rhs.zxw.0) + XYZ(self.mz.0 * rhs.xyw.0) + XYZ(self.vx.0 * rhs.x.0) + XYZ(self.vy.0 * rhs.y.0) + XYZ(self.vz.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.vx.0 * rhs.wx.0) - XYZW(self.vy.0 * rhs.wy.0) - XYZW(self.vz.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: self.vx.anti_dot(rhs.xyzw),
+			wy: self.vy.anti_dot(rhs.xyzw),
+			wz: self.vz.anti_dot(rhs.xyzw),
+			yz: self.mx.anti_dot(rhs.xyzw) + self.vx.anti_dot(rhs.s),
+			zx: self.my.anti_dot(rhs.xyzw) + self.vy.anti_dot(rhs.s),
+			xy: self.mz.anti_dot(rhs.xyzw) + self.vz.anti_dot(rhs.s),
+			yzw: self.vx.anti_dot(rhs.w) + self.vy.anti_dot(rhs.xyw) + self.vz.anti_dot(rhs.zxw),
+			zxw: self.vx.anti_dot(rhs.xyw) + self.vy.anti_dot(rhs.w) + self.vz.anti_dot(rhs.yzw),
+			xyw: self.vx.anti_dot(rhs.zxw) + self.vy.anti_dot(rhs.yzw) + self.vz.anti_dot(rhs.w),
+			xyz: self.mx.anti_dot(rhs.yzw)
+				+ self.my.anti_dot(rhs.zxw)
+				+ self.mz.anti_dot(rhs.xyw)
+				+ self.vx.anti_dot(rhs.x)
+				+ self.vy.anti_dot(rhs.y)
+				+ self.vz.anti_dot(rhs.z),
+			xyzw: self.vx.anti_dot(rhs.wx) + self.vy.anti_dot(rhs.wy) + self.vz.anti_dot(rhs.wz),
+		}
+	}
+}
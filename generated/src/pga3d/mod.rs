@@ -7,7 +7,6 @@ pub mod traits;
 /// Special zero type for completeness, and better error messages.
 /// If you get this in an error message, it is because you multiplied
 /// two dimensions that always results in zero.
-
 pub struct Zero {}
 
 // ----------------------------------------------------------------------------
@@ -16,9 +15,19 @@ pub struct Zero {}
 pub mod line3;
 pub mod moment3;
 pub mod motor3;
+pub mod multivector;
 pub mod plane;
+pub mod point3;
 pub mod rotor3;
+pub mod translator3;
 pub mod vec3;
 pub mod vec4;
 
-pub use self::{blades::*, line3::*, moment3::*, motor3::*, plane::*, rotor3::*, traits::*, vec3::*, vec4::*};
+pub mod queries;
+
+pub mod ffi;
+
+pub use self::{
+	blades::*, line3::*, moment3::*, motor3::*, multivector::*, plane::*, point3::*, rotor3::*, traits::*,
+	translator3::*, vec3::*, vec4::*,
+};
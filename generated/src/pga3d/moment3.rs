@@ -2,54 +2,210 @@
 //!
 //! ## Operations
 //! ```text
+//! Moment3.geometric(Moment3) -> Motor3
 //! Moment3.dot(Moment3) -> S
+//! Moment3.left_contraction(Moment3) -> S
+//! Moment3.right_contraction(Moment3) -> S
+//! Moment3.scalar_product(Moment3) -> S
+//! Moment3.commutator(Moment3) -> Moment3
+//! Moment3.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Moment3) -> Multivector
 //! Moment3.dot(Vec3) -> Vec3
 //! Vec3.dot(Moment3) -> Vec3
 //! Moment3.wedge(Vec3) -> XYZ
 //! Vec3.wedge(Moment3) -> XYZ
+//! Vec3.left_contraction(Moment3) -> Vec3
+//! Moment3.right_contraction(Vec3) -> Vec3
+//! Moment3.commutator(Vec3) -> Vec3
+//! Vec3.commutator(Moment3) -> Vec3
+//! Moment3.geometric(Vec4) -> Multivector
+//! Vec4.geometric(Moment3) -> Multivector
 //! Moment3.anti_geometric(Vec4) -> Vec3
 //! Vec4.anti_geometric(Moment3) -> Vec3
 //! Moment3.dot(Vec4) -> Vec3
 //! Vec4.dot(Moment3) -> Vec3
 //! Moment3.wedge(Vec4) -> Plane
 //! Vec4.wedge(Moment3) -> Plane
+//! Vec4.left_contraction(Moment3) -> Vec3
+//! Moment3.right_contraction(Vec4) -> Vec3
+//! Moment3.commutator(Vec4) -> Vec3
+//! Vec4.commutator(Moment3) -> Vec3
+//! Moment3.geometric(Point3) -> Multivector
+//! Point3.geometric(Moment3) -> Multivector
+//! Moment3.anti_geometric(Point3) -> Vec3
+//! Point3.anti_geometric(Moment3) -> Vec3
+//! Moment3.dot(Point3) -> Vec3
+//! Point3.dot(Moment3) -> Vec3
+//! Moment3.wedge(Point3) -> Plane
+//! Point3.wedge(Moment3) -> Plane
+//! Point3.left_contraction(Moment3) -> Vec3
+//! Moment3.right_contraction(Point3) -> Vec3
+//! Moment3.commutator(Point3) -> Vec3
+//! Point3.commutator(Moment3) -> Vec3
+//! Moment3.geometric(Line3) -> Motor3
+//! Line3.geometric(Moment3) -> Motor3
+//! Moment3.anti_geometric(Line3) -> Motor3
+//! Line3.anti_geometric(Moment3) -> Motor3
 //! Moment3.dot(Line3) -> S
 //! Line3.dot(Moment3) -> S
 //! Moment3.wedge(Line3) -> XYZW
 //! Line3.wedge(Moment3) -> XYZW
 //! Moment3.anti_wedge(Line3) -> S
 //! Line3.anti_wedge(Moment3) -> S
+//! Moment3.left_contraction(Line3) -> S
+//! Line3.left_contraction(Moment3) -> S
+//! Moment3.right_contraction(Line3) -> S
+//! Line3.right_contraction(Moment3) -> S
+//! Moment3.scalar_product(Line3) -> S
+//! Line3.scalar_product(Moment3) -> S
+//! Moment3.commutator(Line3) -> Line3
+//! Line3.commutator(Moment3) -> Line3
+//! Moment3.geometric(Plane) -> Multivector
+//! Plane.geometric(Moment3) -> Multivector
+//! Moment3.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Moment3) -> Multivector
 //! Moment3.dot(Plane) -> Vec4
 //! Plane.dot(Moment3) -> Vec4
 //! Moment3.anti_wedge(Plane) -> Vec3
 //! Plane.anti_wedge(Moment3) -> Vec3
+//! Moment3.left_contraction(Plane) -> Vec4
+//! Plane.right_contraction(Moment3) -> Vec4
+//! Moment3.commutator(Plane) -> Plane
+//! Plane.commutator(Moment3) -> Plane
+//! Moment3.anti_dot(Plane) -> XYZ
+//! Plane.anti_dot(Moment3) -> XYZ
+//! Moment3.geometric(Translator3) -> Motor3
+//! Translator3.geometric(Moment3) -> Motor3
+//! Moment3.anti_geometric(Translator3) -> Moment3
+//! Translator3.anti_geometric(Moment3) -> Moment3
+//! Moment3.dot(Translator3) -> Motor3
+//! Translator3.dot(Moment3) -> Motor3
+//! Moment3.anti_wedge(Translator3) -> Moment3
+//! Translator3.anti_wedge(Moment3) -> Moment3
+//! Moment3.left_contraction(Translator3) -> Motor3
+//! Translator3.left_contraction(Moment3) -> S
+//! Moment3.right_contraction(Translator3) -> S
+//! Translator3.right_contraction(Moment3) -> Motor3
+//! Moment3.scalar_product(Translator3) -> S
+//! Translator3.scalar_product(Moment3) -> S
+//! Moment3.commutator(Translator3) -> Moment3
+//! Translator3.commutator(Moment3) -> Moment3
+//! Moment3.anti_dot(Translator3) -> Moment3
+//! Translator3.anti_dot(Moment3) -> Moment3
 //! Moment3.geometric(Rotor3) -> Rotor3
 //! Rotor3.geometric(Moment3) -> Rotor3
+//! Moment3.anti_geometric(Rotor3) -> Motor3
+//! Rotor3.anti_geometric(Moment3) -> Motor3
 //! Moment3.dot(Rotor3) -> Line3
 //! Rotor3.dot(Moment3) -> Line3
 //! Moment3.wedge(Rotor3) -> XYZW
 //! Rotor3.wedge(Moment3) -> XYZW
-//!
+//! Moment3.anti_wedge(Rotor3) -> Motor3
+//! Rotor3.anti_wedge(Moment3) -> Motor3
+//! Moment3.left_contraction(Rotor3) -> Line3
+//! Rotor3.right_contraction(Moment3) -> Line3
+//! Moment3.commutator(Rotor3) -> Line3
+//! Rotor3.commutator(Moment3) -> Line3
+//! Moment3.anti_dot(Rotor3) -> Moment3
+//! Rotor3.anti_dot(Moment3) -> Moment3
+//! Moment3.geometric(Motor3) -> Motor3
+//! Motor3.geometric(Moment3) -> Motor3
+//! Moment3.anti_geometric(Motor3) -> Motor3
+//! Motor3.anti_geometric(Moment3) -> Motor3
+//! Moment3.dot(Motor3) -> Motor3
+//! Motor3.dot(Moment3) -> Motor3
+//! Moment3.wedge(Motor3) -> Translator3
+//! Motor3.wedge(Moment3) -> Translator3
+//! Moment3.anti_wedge(Motor3) -> Motor3
+//! Motor3.anti_wedge(Moment3) -> Motor3
+//! Moment3.left_contraction(Motor3) -> Motor3
+//! Motor3.left_contraction(Moment3) -> Motor3
+//! Moment3.right_contraction(Motor3) -> Motor3
+//! Motor3.right_contraction(Moment3) -> Motor3
+//! Moment3.scalar_product(Motor3) -> S
+//! Motor3.scalar_product(Moment3) -> S
+//! Moment3.commutator(Motor3) -> Line3
+//! Motor3.commutator(Moment3) -> Line3
+//! Moment3.anti_dot(Motor3) -> Moment3
+//! Motor3.anti_dot(Moment3) -> Moment3
+//! Moment3.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Moment3) -> Multivector
+//! Moment3.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Moment3) -> Multivector
+//! Moment3.dot(Multivector) -> Multivector
+//! Multivector.dot(Moment3) -> Multivector
+//! Moment3.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Moment3) -> Multivector
+//! Moment3.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Moment3) -> Multivector
+//! Moment3.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Moment3) -> Multivector
+//! Moment3.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Moment3) -> Multivector
+//! Moment3.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Moment3) -> S
+//! Moment3.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Moment3) -> Multivector
+//! Moment3.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Moment3) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Moment3 {
 	pub mx: YZ,
 	pub my: ZX,
 	pub mz: XY,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Moment3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Moment3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Moment3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.mx.abs_diff_eq(&other.mx, epsilon)
+			&& self.my.abs_diff_eq(&other.my, epsilon)
+			&& self.mz.abs_diff_eq(&other.mz, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Moment3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.mx.relative_eq(&other.mx, epsilon, max_relative)
+			&& self.my.relative_eq(&other.my, epsilon, max_relative)
+			&& self.mz.relative_eq(&other.mz, epsilon, max_relative)
+	}
+}
+
 // ---------------------------------------------------------------------
 
 impl RCompl for Moment3 {
 	type Output = Line3;
 	fn rcompl(self) -> Self::Output {
 		Line3 {
-			vx: -self.mx.rcompl(),
-			vy: -self.my.rcompl(),
-			vz: -self.mz.rcompl(),
+			vx: self.mx.rcompl(),
+			vy: self.my.rcompl(),
+			vz: self.mz.rcompl(),
 			mx: Default::default(),
 			my: Default::default(),
 			mz: Default::default(),
@@ -57,13 +213,20 @@ impl RCompl for Moment3 {
 	}
 }
 
+impl std::ops::Not for Moment3 {
+	type Output = Line3;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl LCompl for Moment3 {
 	type Output = Line3;
 	fn lcompl(self) -> Self::Output {
 		Line3 {
-			vx: -self.mx.lcompl(),
-			vy: -self.my.lcompl(),
-			vz: -self.mz.lcompl(),
+			vx: self.mx.lcompl(),
+			vy: self.my.lcompl(),
+			vz: self.mz.lcompl(),
 			mx: Default::default(),
 			my: Default::default(),
 			mz: Default::default(),
@@ -91,29 +254,261 @@ impl AntiReverse for Moment3 {
 	}
 }
 
+impl Involute for Moment3 {
+	fn involute(self) -> Self {
+		Moment3 {
+			mx: self.mx,
+			my: self.my,
+			mz: self.mz,
+		}
+	}
+}
+
+impl Conjugate for Moment3 {
+	fn conjugate(self) -> Self {
+		Moment3 {
+			mx: -self.mx,
+			my: -self.my,
+			mz: -self.mz,
+		}
+	}
+}
+
+impl Dual for Moment3 {
+	type Output = Line3;
+	fn dual(self) -> Self::Output {
+		Line3 {
+			vx: self.mx.dual(),
+			vy: self.my.dual(),
+			vz: self.mz.dual(),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl Bulk for Moment3 {
+	type Output = Moment3;
+	fn bulk(self) -> Self::Output {
+		Moment3 {
+			mx: self.mx,
+			my: self.my,
+			mz: self.mz,
+		}
+	}
+}
+
+// Omitted: Moment3.weight() -> 0
+
+impl BulkDual for Moment3 {
+	type Output = Line3;
+	fn bulk_dual(self) -> Self::Output {
+		Line3 {
+			vx: self.mx.bulk_dual(),
+			vy: self.my.bulk_dual(),
+			vz: self.mz.bulk_dual(),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+// Omitted: Moment3.weight_dual() -> 0
+
+impl Square for Moment3 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(self.mx.geometric(self.mx) + self.my.geometric(self.my) + self.mz.geometric(self.mz)).0
+	}
+}
+
+impl Norm for Moment3 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.mx.0 * self.mx.0 + self.my.0 * self.my.0 + self.mz.0 * self.mz.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.mx.0 * self.mx.0 + self.my.0 * self.my.0 + self.mz.0 * self.mz.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Moment3 {
+			mx: self.mx * scale,
+			my: self.my * scale,
+			mz: self.mz * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Moment3 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 3] {
+		[self.mx.0, self.my.0, self.mz.0]
+	}
+}
+
+impl From<[f64; 3]> for Moment3 {
+	fn from(a: [f64; 3]) -> Self {
+		Moment3 {
+			mx: YZ(a[0]),
+			my: ZX(a[1]),
+			mz: XY(a[2]),
+		}
+	}
+}
+
+impl From<Moment3> for [f64; 3] {
+	fn from(v: Moment3) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Moment3> for Multivector {
+	fn from(v: Moment3) -> Self {
+		Multivector {
+			yz: v.mx,
+			zx: v.my,
+			xy: v.mz,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Moment3 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Moment3` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.x.0 == 0.0
+			&& mv.y.0 == 0.0
+			&& mv.z.0 == 0.0
+			&& mv.w.0 == 0.0
+			&& mv.wx.0 == 0.0
+			&& mv.wy.0 == 0.0
+			&& mv.wz.0 == 0.0
+			&& mv.yzw.0 == 0.0
+			&& mv.zxw.0 == 0.0
+			&& mv.xyw.0 == 0.0
+			&& mv.xyz.0 == 0.0
+			&& mv.xyzw.0 == 0.0
+		{
+			Ok(Moment3 {
+				mx: mv.yz,
+				my: mv.zx,
+				mz: mv.xy,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Moment3 OP Vec3:
 
-// Omitted: Moment3 geometric Vec3 = self.mx * rhs.x + self.mx * rhs.y + self.mx * rhs.z + self.my * rhs.x + self.my * rhs.y + self.my * rhs.z + self.mz * rhs.x + self.mz * rhs.y + self.mz * rhs.z  (unnamed type)
-// Omitted: Moment3 anti_geometric Vec3 = 0  (unnamed type)
+// Moment3.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Moment3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			y: self.mx.geometric(rhs.z) + self.mz.geometric(rhs.x),
+			z: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Moment3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl AntiGeometric<Vec3> for Moment3 {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
 
 // Moment3.dot(Vec3) -> Vec3
 impl Dot<Vec3> for Moment3 {
 	type Output = Vec3;
 	fn dot(self, rhs: Vec3) -> Self::Output {
 		// Vec3 {
-		//     x: X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
-		//     y: Y(self.mx.0 * rhs.z.0) + Y(self.mz.0 * rhs.x.0),
-		//     z: Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
 		// }
 		Vec3 {
-			x: -self.my.dot(rhs.z) + self.mz.dot(rhs.y),
-			y: self.mx.dot(rhs.z) - self.mz.dot(rhs.x),
-			z: -self.mx.dot(rhs.y) + self.my.dot(rhs.x),
+			x: self.my.dot(rhs.z) + self.mz.dot(rhs.y),
+			y: self.mx.dot(rhs.z) + self.mz.dot(rhs.x),
+			z: self.mx.dot(rhs.y) + self.my.dot(rhs.x),
 		}
 	}
 }
 
+impl std::ops::BitOr<Vec3> for Moment3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Moment3.wedge(Vec3) -> XYZ
 impl Wedge<Vec3> for Moment3 {
 	type Output = XYZ;
@@ -123,12 +518,134 @@ impl Wedge<Vec3> for Moment3 {
 	}
 }
 
-// Omitted: Moment3 anti_wedge Vec3 = 0  (unnamed type)
+impl std::ops::BitXor<Vec3> for Moment3 {
+	type Output = XYZ;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for Moment3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Moment3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for Moment3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Moment3.right_contraction(Vec3) -> Vec3
+impl RightContraction<Vec3> for Moment3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.my.right_contraction(rhs.z) + self.mz.right_contraction(rhs.y),
+			y: self.mx.right_contraction(rhs.z) + self.mz.right_contraction(rhs.x),
+			z: self.mx.right_contraction(rhs.y) + self.my.right_contraction(rhs.x),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Moment3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Moment3.commutator(Vec3) -> Vec3
+impl Commutator<Vec3> for Moment3 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			y: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			z: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+		}
+	}
+}
+
+impl AntiDot<Vec3> for Moment3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
 
 // ---------------------------------------------------------------------
 // Moment3 OP Vec4:
 
-// Omitted: Moment3 geometric Vec4 = self.mx * rhs.w + self.mx * rhs.x + self.mx * rhs.y + self.mx * rhs.z + self.my * rhs.w + self.my * rhs.x + self.my * rhs.y + self.my * rhs.z + self.mz * rhs.w + self.mz * rhs.x + self.mz * rhs.y + self.mz * rhs.z  (unnamed type)
+// Moment3.geometric(Vec4) -> Multivector
+impl Geometric<Vec4> for Moment3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.mx.0 * rhs.w.0),
+		//     zxw : ZXW(self.my.0 * rhs.w.0),
+		//     xyw : XYW(self.mz.0 * rhs.w.0),
+		//     xyz : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			y: self.mx.geometric(rhs.z) + self.mz.geometric(rhs.x),
+			z: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.mx.geometric(rhs.w),
+			zxw: self.my.geometric(rhs.w),
+			xyw: self.mz.geometric(rhs.w),
+			xyz: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec4> for Moment3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
 // Moment3.anti_geometric(Vec4) -> Vec3
 impl AntiGeometric<Vec4> for Moment3 {
@@ -152,18 +669,25 @@ impl Dot<Vec4> for Moment3 {
 	type Output = Vec3;
 	fn dot(self, rhs: Vec4) -> Self::Output {
 		// Vec3 {
-		//     x: X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
-		//     y: Y(self.mx.0 * rhs.z.0) + Y(self.mz.0 * rhs.x.0),
-		//     z: Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
 		// }
 		Vec3 {
-			x: -self.my.dot(rhs.z) + self.mz.dot(rhs.y),
-			y: self.mx.dot(rhs.z) - self.mz.dot(rhs.x),
-			z: -self.mx.dot(rhs.y) + self.my.dot(rhs.x),
+			x: self.my.dot(rhs.z) + self.mz.dot(rhs.y),
+			y: self.mx.dot(rhs.z) + self.mz.dot(rhs.x),
+			z: self.mx.dot(rhs.y) + self.my.dot(rhs.x),
 		}
 	}
 }
 
+impl std::ops::BitOr<Vec4> for Moment3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Moment3.wedge(Vec4) -> Plane
 impl Wedge<Vec4> for Moment3 {
 	type Output = Plane;
@@ -183,144 +707,1908 @@ impl Wedge<Vec4> for Moment3 {
 	}
 }
 
-// Omitted: Moment3 anti_wedge Vec4 = 0  (unnamed type)
-
-// ---------------------------------------------------------------------
-// Moment3 OP Moment3:
-
-// Omitted: Moment3 geometric Moment3 = self.mx * rhs.mx + self.mx * rhs.my + self.mx * rhs.mz + self.my * rhs.mx + self.my * rhs.my + self.my * rhs.mz + self.mz * rhs.mx + self.mz * rhs.my + self.mz * rhs.mz  (unnamed type)
-// Omitted: Moment3 anti_geometric Moment3 = 0  (unnamed type)
+impl std::ops::BitXor<Vec4> for Moment3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
-// Moment3.dot(Moment3) -> S
-impl Dot<Moment3> for Moment3 {
-	type Output = S;
-	fn dot(self, rhs: Moment3) -> Self::Output {
-		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
-		self.mx.dot(rhs.mx) + self.my.dot(rhs.my) + self.mz.dot(rhs.mz)
+impl AntiWedge<Vec4> for Moment3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
 	}
 }
 
-// Omitted: Moment3 wedge Moment3 = 0  (unnamed type)
-// Omitted: Moment3 anti_wedge Moment3 = 0  (unnamed type)
+impl std::ops::BitAnd<Vec4> for Moment3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-// ---------------------------------------------------------------------
-// Moment3 OP Line3:
+impl LeftContraction<Vec4> for Moment3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
 
-// Omitted: Moment3 geometric Line3 = self.mx * rhs.mx + self.mx * rhs.my + self.mx * rhs.mz + self.mx * rhs.vx + self.mx * rhs.vy + self.mx * rhs.vz + self.my * rhs.mx + self.my * rhs.my + self.my * rhs.mz + self.my * rhs.vx + self.my * rhs.vy + self.my * rhs.vz + self.mz * rhs.mx + self.mz * rhs.my + self.mz * rhs.mz + self.mz * rhs.vx + self.mz * rhs.vy + self.mz * rhs.vz  (unnamed type)
-// Omitted: Moment3 anti_geometric Line3 = self.mx !* rhs.vx + self.mx !* rhs.vy + self.mx !* rhs.vz + self.my !* rhs.vx + self.my !* rhs.vy + self.my !* rhs.vz + self.mz !* rhs.vx + self.mz !* rhs.vy + self.mz !* rhs.vz  (unnamed type)
+// Moment3.right_contraction(Vec4) -> Vec3
+impl RightContraction<Vec4> for Moment3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.my.right_contraction(rhs.z) + self.mz.right_contraction(rhs.y),
+			y: self.mx.right_contraction(rhs.z) + self.mz.right_contraction(rhs.x),
+			z: self.mx.right_contraction(rhs.y) + self.my.right_contraction(rhs.x),
+		}
+	}
+}
 
-// Moment3.dot(Line3) -> S
-impl Dot<Line3> for Moment3 {
-	type Output = S;
-	fn dot(self, rhs: Line3) -> Self::Output {
-		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
-		self.mx.dot(rhs.mx) + self.my.dot(rhs.my) + self.mz.dot(rhs.mz)
+impl ScalarProduct<Vec4> for Moment3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
 	}
 }
 
-// Moment3.wedge(Line3) -> XYZW
-impl Wedge<Line3> for Moment3 {
-	type Output = XYZW;
-	fn wedge(self, rhs: Line3) -> Self::Output {
-		// -XYZW(self.mx.0 * rhs.vx.0) - XYZW(self.my.0 * rhs.vy.0) - XYZW(self.mz.0 * rhs.vz.0)
-		self.mx.wedge(rhs.vx) + self.my.wedge(rhs.vy) + self.mz.wedge(rhs.vz)
+// Moment3.commutator(Vec4) -> Vec3
+impl Commutator<Vec4> for Moment3 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			y: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			z: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+		}
 	}
 }
 
-// Moment3.anti_wedge(Line3) -> S
-impl AntiWedge<Line3> for Moment3 {
-	type Output = S;
-	fn anti_wedge(self, rhs: Line3) -> Self::Output {
-		// -S(self.mx.0 * rhs.vx.0) - S(self.my.0 * rhs.vy.0) - S(self.mz.0 * rhs.vz.0)
-		self.mx.anti_wedge(rhs.vx) + self.my.anti_wedge(rhs.vy) + self.mz.anti_wedge(rhs.vz)
+impl AntiDot<Vec4> for Moment3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
 	}
 }
 
 // ---------------------------------------------------------------------
-// Moment3 OP Plane:
+// Moment3 OP Point3:
 
-// Omitted: Moment3 geometric Plane = self.mx * rhs.d + self.mx * rhs.nx + self.mx * rhs.ny + self.mx * rhs.nz + self.my * rhs.d + self.my * rhs.nx + self.my * rhs.ny + self.my * rhs.nz + self.mz * rhs.d + self.mz * rhs.nx + self.mz * rhs.ny + self.mz * rhs.nz  (unnamed type)
-// Omitted: Moment3 anti_geometric Plane = self.mx !* rhs.nx + self.mx !* rhs.ny + self.mx !* rhs.nz + self.my !* rhs.nx + self.my !* rhs.ny + self.my !* rhs.nz + self.mz !* rhs.nx + self.mz !* rhs.ny + self.mz !* rhs.nz  (unnamed type)
-
-// Moment3.dot(Plane) -> Vec4
-impl Dot<Plane> for Moment3 {
-	type Output = Vec4;
-	fn dot(self, rhs: Plane) -> Self::Output {
-		// Vec4 {
-		//     x: X(self.mx.0 * rhs.d.0),
-		//     y: Y(self.my.0 * rhs.d.0),
-		//     z: Z(self.mz.0 * rhs.d.0),
-		//     w: W(self.mx.0 * rhs.nx.0) + W(self.my.0 * rhs.ny.0) + W(self.mz.0 * rhs.nz.0),
+// Moment3.geometric(Point3) -> Multivector
+impl Geometric<Point3> for Moment3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.mx.0),
+		//     zxw : ZXW(self.my.0),
+		//     xyw : XYW(self.mz.0),
+		//     xyz : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
 		// }
-		Vec4 {
-			x: -self.mx.dot(rhs.d),
-			y: -self.my.dot(rhs.d),
-			z: -self.mz.dot(rhs.d),
-			w: -self.mx.dot(rhs.nx) - self.my.dot(rhs.ny) - self.mz.dot(rhs.nz),
+		Multivector {
+			s: Default::default(),
+			x: self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			y: self.mx.geometric(rhs.z) + self.mz.geometric(rhs.x),
+			z: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.mx.geometric(W(1.0)),
+			zxw: self.my.geometric(W(1.0)),
+			xyw: self.mz.geometric(W(1.0)),
+			xyz: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+			xyzw: Default::default(),
 		}
 	}
 }
 
-// Omitted: Moment3 wedge Plane = 0  (unnamed type)
+impl std::ops::Mul<Point3> for Moment3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Point3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
-// Moment3.anti_wedge(Plane) -> Vec3
-impl AntiWedge<Plane> for Moment3 {
+// Moment3.anti_geometric(Point3) -> Vec3
+impl AntiGeometric<Point3> for Moment3 {
 	type Output = Vec3;
-	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+	fn anti_geometric(self, _rhs: Point3) -> Self::Output {
 		// Vec3 {
-		//     x: X(self.my.0 * rhs.nz.0) + X(self.mz.0 * rhs.ny.0),
-		//     y: Y(self.mx.0 * rhs.nz.0) + Y(self.mz.0 * rhs.nx.0),
-		//     z: Z(self.mx.0 * rhs.ny.0) + Z(self.my.0 * rhs.nx.0),
+		//     x: X(self.mx.0),
+		//     y: Y(self.my.0),
+		//     z: Z(self.mz.0),
 		// }
 		Vec3 {
-			x: self.my.anti_wedge(rhs.nz) - self.mz.anti_wedge(rhs.ny),
-			y: -self.mx.anti_wedge(rhs.nz) + self.mz.anti_wedge(rhs.nx),
-			z: self.mx.anti_wedge(rhs.ny) - self.my.anti_wedge(rhs.nx),
+			x: self.mx.anti_geometric(W(1.0)),
+			y: self.my.anti_geometric(W(1.0)),
+			z: self.mz.anti_geometric(W(1.0)),
 		}
 	}
 }
 
-// ---------------------------------------------------------------------
-// Moment3 OP Rotor3:
-
-// Moment3.geometric(Rotor3) -> Rotor3
-impl Geometric<Rotor3> for Moment3 {
-	type Output = Rotor3;
-	fn geometric(self, rhs: Rotor3) -> Self::Output {
-		// Rotor3 {
-		//     x: WX(self.mx.0 * rhs.w.0) + WX(self.my.0 * rhs.z.0) + WX(self.mz.0 * rhs.y.0),
-		//     y: WY(self.mx.0 * rhs.z.0) + WY(self.my.0 * rhs.w.0) + WY(self.mz.0 * rhs.x.0),
-		//     z: WZ(self.mx.0 * rhs.y.0) + WZ(self.my.0 * rhs.x.0) + WZ(self.mz.0 * rhs.w.0),
-		//     w: XYZW(self.mx.0 * rhs.x.0) + XYZW(self.my.0 * rhs.y.0) + XYZW(self.mz.0 * rhs.z.0),
+// Moment3.dot(Point3) -> Vec3
+impl Dot<Point3> for Moment3 {
+	type Output = Vec3;
+	fn dot(self, rhs: Point3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
 		// }
-		Rotor3 {
-			x: self.mx.geometric(rhs.w) - self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
-			y: self.mx.geometric(rhs.z) + self.my.geometric(rhs.w) - self.mz.geometric(rhs.x),
-			z: -self.mx.geometric(rhs.y) + self.my.geometric(rhs.x) + self.mz.geometric(rhs.w),
-			w: -self.mx.geometric(rhs.x) - self.my.geometric(rhs.y) - self.mz.geometric(rhs.z),
+		Vec3 {
+			x: self.my.dot(rhs.z) + self.mz.dot(rhs.y),
+			y: self.mx.dot(rhs.z) + self.mz.dot(rhs.x),
+			z: self.mx.dot(rhs.y) + self.my.dot(rhs.x),
 		}
 	}
 }
 
-// Omitted: Moment3 anti_geometric Rotor3 = self.mx !* rhs.w + self.mx !* rhs.x + self.mx !* rhs.y + self.mx !* rhs.z + self.my !* rhs.w + self.my !* rhs.x + self.my !* rhs.y + self.my !* rhs.z + self.mz !* rhs.w + self.mz !* rhs.x + self.mz !* rhs.y + self.mz !* rhs.z  (unnamed type)
-// Omitted: Moment3 dot Rotor3 = Line3 {     vx: self.mx | rhs.w,     vy: self.my | rhs.w,     vz: self.mz | rhs.w,     mx: 0,     my: 0,     mz: 0, }  (too many zeros)
+impl std::ops::BitOr<Point3> for Moment3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Point3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
 
-// Moment3.wedge(Rotor3) -> XYZW
-impl Wedge<Rotor3> for Moment3 {
-	type Output = XYZW;
-	fn wedge(self, rhs: Rotor3) -> Self::Output {
-		// -XYZW(self.mx.0 * rhs.x.0) - XYZW(self.my.0 * rhs.y.0) - XYZW(self.mz.0 * rhs.z.0)
-		self.mx.wedge(rhs.x) + self.my.wedge(rhs.y) + self.mz.wedge(rhs.z)
+// Moment3.wedge(Point3) -> Plane
+impl Wedge<Point3> for Moment3 {
+	type Output = Plane;
+	fn wedge(self, rhs: Point3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.mx.0),
+		//     ny: ZXW(self.my.0),
+		//     nz: XYW(self.mz.0),
+		//     d : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		// }
+		Plane {
+			nx: self.mx.wedge(W(1.0)),
+			ny: self.my.wedge(W(1.0)),
+			nz: self.mz.wedge(W(1.0)),
+			d: self.mx.wedge(rhs.x) + self.my.wedge(rhs.y) + self.mz.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point3> for Moment3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Point3) -> Self::Output {
+		self.wedge(rhs)
 	}
 }
 
-// Omitted: Moment3 anti_wedge Rotor3 = self.mx & rhs.w + self.mx & rhs.x + self.my & rhs.w + self.my & rhs.y + self.mz & rhs.w + self.mz & rhs.z  (unnamed type)
+impl AntiWedge<Point3> for Moment3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
 
-// ---------------------------------------------------------------------
-// Moment3 OP Motor3:
+impl std::ops::BitAnd<Point3> for Moment3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Point3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-// Omitted: Moment3 geometric Motor3 = self.mx * rhs.rw + self.mx * rhs.rx + self.mx * rhs.ry + self.mx * rhs.rz + self.mx * rhs.uw + self.mx * rhs.ux + self.mx * rhs.uy + self.mx * rhs.uz + self.my * rhs.rw + self.my * rhs.rx + self.my * rhs.ry + self.my * rhs.rz + self.my * rhs.uw + self.my * rhs.ux + self.my * rhs.uy + self.my * rhs.uz + self.mz * rhs.rw + self.mz * rhs.rx + self.mz * rhs.ry + self.mz * rhs.rz + self.mz * rhs.uw + self.mz * rhs.ux + self.mz * rhs.uy + self.mz * rhs.uz  (unnamed type)
-// Omitted: Moment3 anti_geometric Motor3 = self.mx !* rhs.rw + self.mx !* rhs.rx + self.mx !* rhs.ry + self.mx !* rhs.rz + self.mx !* rhs.ux + self.mx !* rhs.uy + self.mx !* rhs.uz + self.my !* rhs.rw + self.my !* rhs.rx + self.my !* rhs.ry + self.my !* rhs.rz + self.my !* rhs.ux + self.my !* rhs.uy + self.my !* rhs.uz + self.mz !* rhs.rw + self.mz !* rhs.rx + self.mz !* rhs.ry + self.mz !* rhs.rz + self.mz !* rhs.ux + self.mz !* rhs.uy + self.mz !* rhs.uz  (unnamed type)
-// Omitted: Moment3 dot Motor3 = self.mx | rhs.rw + self.mx | rhs.uw + self.mx | rhs.ux + self.my | rhs.rw + self.my | rhs.uw + self.my | rhs.uy + self.mz | rhs.rw + self.mz | rhs.uw + self.mz | rhs.uz  (unnamed type)
-// Omitted: Moment3 wedge Motor3 = self.mx ^ rhs.rx + self.mx ^ rhs.uw + self.my ^ rhs.ry + self.my ^ rhs.uw + self.mz ^ rhs.rz + self.mz ^ rhs.uw  (unnamed type)
-// Omitted: Moment3 anti_wedge Motor3 = self.mx & rhs.rw + self.mx & rhs.rx + self.mx & rhs.uy + self.mx & rhs.uz + self.my & rhs.rw + self.my & rhs.ry + self.my & rhs.ux + self.my & rhs.uz + self.mz & rhs.rw + self.mz & rhs.rz + self.mz & rhs.ux + self.mz & rhs.uy  (unnamed type)
+impl LeftContraction<Point3> for Moment3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Moment3.right_contraction(Point3) -> Vec3
+impl RightContraction<Point3> for Moment3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Point3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.my.right_contraction(rhs.z) + self.mz.right_contraction(rhs.y),
+			y: self.mx.right_contraction(rhs.z) + self.mz.right_contraction(rhs.x),
+			z: self.mx.right_contraction(rhs.y) + self.my.right_contraction(rhs.x),
+		}
+	}
+}
+
+impl ScalarProduct<Point3> for Moment3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Moment3.commutator(Point3) -> Vec3
+impl Commutator<Point3> for Moment3 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Point3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y: Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z: -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			y: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			z: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+		}
+	}
+}
+
+impl AntiDot<Point3> for Moment3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Moment3 OP Moment3:
+
+// Moment3.geometric(Moment3) -> Motor3
+impl Geometric<Moment3> for Moment3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: -YZ(self.my.0 * rhs.mz.0) + YZ(self.mz.0 * rhs.my.0),
+		//     uy: ZX(self.mx.0 * rhs.mz.0) - ZX(self.mz.0 * rhs.mx.0),
+		//     uz: -XY(self.mx.0 * rhs.my.0) + XY(self.my.0 * rhs.mx.0),
+		//     uw: -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.my.geometric(rhs.mz) + self.mz.geometric(rhs.my),
+			uy: self.mx.geometric(rhs.mz) + self.mz.geometric(rhs.mx),
+			uz: self.mx.geometric(rhs.my) + self.my.geometric(rhs.mx),
+			uw: self.mx.geometric(rhs.mx) + self.my.geometric(rhs.my) + self.mz.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Moment3> for Moment3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Moment3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl AntiGeometric<Moment3> for Moment3 {
+	type Output = Zero;
+	fn anti_geometric(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Moment3.dot(Moment3) -> S
+impl Dot<Moment3> for Moment3 {
+	type Output = S;
+	fn dot(self, rhs: Moment3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.dot(rhs.mx) + self.my.dot(rhs.my) + self.mz.dot(rhs.mz)
+	}
+}
+
+impl std::ops::BitOr<Moment3> for Moment3 {
+	type Output = S;
+	fn bitor(self, rhs: Moment3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Moment3> for Moment3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Moment3> for Moment3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: Moment3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Moment3> for Moment3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Moment3> for Moment3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Moment3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Moment3.left_contraction(Moment3) -> S
+impl LeftContraction<Moment3> for Moment3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Moment3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.left_contraction(rhs.mx) + self.my.left_contraction(rhs.my) + self.mz.left_contraction(rhs.mz)
+	}
+}
+
+// Moment3.right_contraction(Moment3) -> S
+impl RightContraction<Moment3> for Moment3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Moment3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.right_contraction(rhs.mx) + self.my.right_contraction(rhs.my) + self.mz.right_contraction(rhs.mz)
+	}
+}
+
+// Moment3.scalar_product(Moment3) -> S
+impl ScalarProduct<Moment3> for Moment3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Moment3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.scalar_product(rhs.mx) + self.my.scalar_product(rhs.my) + self.mz.scalar_product(rhs.mz)
+	}
+}
+
+// Moment3.commutator(Moment3) -> Moment3
+impl Commutator<Moment3> for Moment3 {
+	type Output = Moment3;
+	fn commutator(self, rhs: Moment3) -> Self::Output {
+		// Moment3 {
+		//     mx: -YZ(self.my.0 * rhs.mz.0) + YZ(self.mz.0 * rhs.my.0),
+		//     my: ZX(self.mx.0 * rhs.mz.0) - ZX(self.mz.0 * rhs.mx.0),
+		//     mz: -XY(self.mx.0 * rhs.my.0) + XY(self.my.0 * rhs.mx.0),
+		// }
+		Moment3 {
+			mx: self.my.commutator(rhs.mz) + self.mz.commutator(rhs.my),
+			my: self.mx.commutator(rhs.mz) + self.mz.commutator(rhs.mx),
+			mz: self.mx.commutator(rhs.my) + self.my.commutator(rhs.mx),
+		}
+	}
+}
+
+impl AntiDot<Moment3> for Moment3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Moment3 OP Line3:
+
+// Moment3.geometric(Line3) -> Motor3
+impl Geometric<Line3> for Moment3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: -WX(self.my.0 * rhs.vz.0) + WX(self.mz.0 * rhs.vy.0),
+		//     ry: WY(self.mx.0 * rhs.vz.0) - WY(self.mz.0 * rhs.vx.0),
+		//     rz: -WZ(self.mx.0 * rhs.vy.0) + WZ(self.my.0 * rhs.vx.0),
+		//     rw: -XYZW(self.mx.0 * rhs.vx.0) - XYZW(self.my.0 * rhs.vy.0) - XYZW(self.mz.0 * rhs.vz.0),
+		//     ux: -YZ(self.my.0 * rhs.mz.0) + YZ(self.mz.0 * rhs.my.0),
+		//     uy: ZX(self.mx.0 * rhs.mz.0) - ZX(self.mz.0 * rhs.mx.0),
+		//     uz: -XY(self.mx.0 * rhs.my.0) + XY(self.my.0 * rhs.mx.0),
+		//     uw: -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.my.geometric(rhs.vz) + self.mz.geometric(rhs.vy),
+			ry: self.mx.geometric(rhs.vz) + self.mz.geometric(rhs.vx),
+			rz: self.mx.geometric(rhs.vy) + self.my.geometric(rhs.vx),
+			rw: self.mx.geometric(rhs.vx) + self.my.geometric(rhs.vy) + self.mz.geometric(rhs.vz),
+			ux: self.my.geometric(rhs.mz) + self.mz.geometric(rhs.my),
+			uy: self.mx.geometric(rhs.mz) + self.mz.geometric(rhs.mx),
+			uz: self.mx.geometric(rhs.my) + self.my.geometric(rhs.mx),
+			uw: self.mx.geometric(rhs.mx) + self.my.geometric(rhs.my) + self.mz.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Line3> for Moment3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Line3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Moment3.anti_geometric(Line3) -> Motor3
+impl AntiGeometric<Line3> for Moment3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.my.0 * rhs.vz.0) - YZ(self.mz.0 * rhs.vy.0),
+		//     uy: -ZX(self.mx.0 * rhs.vz.0) + ZX(self.mz.0 * rhs.vx.0),
+		//     uz: XY(self.mx.0 * rhs.vy.0) - XY(self.my.0 * rhs.vx.0),
+		//     uw: -S(self.mx.0 * rhs.vx.0) - S(self.my.0 * rhs.vy.0) - S(self.mz.0 * rhs.vz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.my.anti_geometric(rhs.vz) + self.mz.anti_geometric(rhs.vy),
+			uy: self.mx.anti_geometric(rhs.vz) + self.mz.anti_geometric(rhs.vx),
+			uz: self.mx.anti_geometric(rhs.vy) + self.my.anti_geometric(rhs.vx),
+			uw: self.mx.anti_geometric(rhs.vx) + self.my.anti_geometric(rhs.vy) + self.mz.anti_geometric(rhs.vz),
+		}
+	}
+}
+
+// Moment3.dot(Line3) -> S
+impl Dot<Line3> for Moment3 {
+	type Output = S;
+	fn dot(self, rhs: Line3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.dot(rhs.mx) + self.my.dot(rhs.my) + self.mz.dot(rhs.mz)
+	}
+}
+
+impl std::ops::BitOr<Line3> for Moment3 {
+	type Output = S;
+	fn bitor(self, rhs: Line3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Moment3.wedge(Line3) -> XYZW
+impl Wedge<Line3> for Moment3 {
+	type Output = XYZW;
+	fn wedge(self, rhs: Line3) -> Self::Output {
+		// -XYZW(self.mx.0 * rhs.vx.0) - XYZW(self.my.0 * rhs.vy.0) - XYZW(self.mz.0 * rhs.vz.0)
+		self.mx.wedge(rhs.vx) + self.my.wedge(rhs.vy) + self.mz.wedge(rhs.vz)
+	}
+}
+
+impl std::ops::BitXor<Line3> for Moment3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Line3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Moment3.anti_wedge(Line3) -> S
+impl AntiWedge<Line3> for Moment3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Line3) -> Self::Output {
+		// -S(self.mx.0 * rhs.vx.0) - S(self.my.0 * rhs.vy.0) - S(self.mz.0 * rhs.vz.0)
+		self.mx.anti_wedge(rhs.vx) + self.my.anti_wedge(rhs.vy) + self.mz.anti_wedge(rhs.vz)
+	}
+}
+
+impl std::ops::BitAnd<Line3> for Moment3 {
+	type Output = S;
+	fn bitand(self, rhs: Line3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Moment3.left_contraction(Line3) -> S
+impl LeftContraction<Line3> for Moment3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Line3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.left_contraction(rhs.mx) + self.my.left_contraction(rhs.my) + self.mz.left_contraction(rhs.mz)
+	}
+}
+
+// Moment3.right_contraction(Line3) -> S
+impl RightContraction<Line3> for Moment3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Line3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.right_contraction(rhs.mx) + self.my.right_contraction(rhs.my) + self.mz.right_contraction(rhs.mz)
+	}
+}
+
+// Moment3.scalar_product(Line3) -> S
+impl ScalarProduct<Line3> for Moment3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Line3) -> Self::Output {
+		// -S(self.mx.0 * rhs.mx.0) - S(self.my.0 * rhs.my.0) - S(self.mz.0 * rhs.mz.0)
+		self.mx.scalar_product(rhs.mx) + self.my.scalar_product(rhs.my) + self.mz.scalar_product(rhs.mz)
+	}
+}
+
+// Moment3.commutator(Line3) -> Line3
+impl Commutator<Line3> for Moment3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Line3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.my.0 * rhs.vz.0) + WX(self.mz.0 * rhs.vy.0),
+		//     vy: WY(self.mx.0 * rhs.vz.0) - WY(self.mz.0 * rhs.vx.0),
+		//     vz: -WZ(self.mx.0 * rhs.vy.0) + WZ(self.my.0 * rhs.vx.0),
+		//     mx: -YZ(self.my.0 * rhs.mz.0) + YZ(self.mz.0 * rhs.my.0),
+		//     my: ZX(self.mx.0 * rhs.mz.0) - ZX(self.mz.0 * rhs.mx.0),
+		//     mz: -XY(self.mx.0 * rhs.my.0) + XY(self.my.0 * rhs.mx.0),
+		// }
+		Line3 {
+			vx: self.my.commutator(rhs.vz) + self.mz.commutator(rhs.vy),
+			vy: self.mx.commutator(rhs.vz) + self.mz.commutator(rhs.vx),
+			vz: self.mx.commutator(rhs.vy) + self.my.commutator(rhs.vx),
+			mx: self.my.commutator(rhs.mz) + self.mz.commutator(rhs.my),
+			my: self.mx.commutator(rhs.mz) + self.mz.commutator(rhs.mx),
+			mz: self.mx.commutator(rhs.my) + self.my.commutator(rhs.mx),
+		}
+	}
+}
+
+impl AntiDot<Line3> for Moment3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Line3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Moment3 OP Plane:
+
+// Moment3.geometric(Plane) -> Multivector
+impl Geometric<Plane> for Moment3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.mx.0 * rhs.d.0),
+		//     y   : -Y(self.my.0 * rhs.d.0),
+		//     z   : -Z(self.mz.0 * rhs.d.0),
+		//     w   : -W(self.mx.0 * rhs.nx.0) - W(self.my.0 * rhs.ny.0) - W(self.mz.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.my.0 * rhs.nz.0) + YZW(self.mz.0 * rhs.ny.0),
+		//     zxw : ZXW(self.mx.0 * rhs.nz.0) - ZXW(self.mz.0 * rhs.nx.0),
+		//     xyw : -XYW(self.mx.0 * rhs.ny.0) + XYW(self.my.0 * rhs.nx.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.mx.geometric(rhs.d),
+			y: self.my.geometric(rhs.d),
+			z: self.mz.geometric(rhs.d),
+			w: self.mx.geometric(rhs.nx) + self.my.geometric(rhs.ny) + self.mz.geometric(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.my.geometric(rhs.nz) + self.mz.geometric(rhs.ny),
+			zxw: self.mx.geometric(rhs.nz) + self.mz.geometric(rhs.nx),
+			xyw: self.mx.geometric(rhs.ny) + self.my.geometric(rhs.nx),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Moment3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Moment3.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Moment3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.my.0 * rhs.nz.0) - X(self.mz.0 * rhs.ny.0),
+		//     y   : -Y(self.mx.0 * rhs.nz.0) + Y(self.mz.0 * rhs.nx.0),
+		//     z   : Z(self.mx.0 * rhs.ny.0) - Z(self.my.0 * rhs.nx.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.mx.0 * rhs.nx.0) + XYZ(self.my.0 * rhs.ny.0) + XYZ(self.mz.0 * rhs.nz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.my.anti_geometric(rhs.nz) + self.mz.anti_geometric(rhs.ny),
+			y: self.mx.anti_geometric(rhs.nz) + self.mz.anti_geometric(rhs.nx),
+			z: self.mx.anti_geometric(rhs.ny) + self.my.anti_geometric(rhs.nx),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.mx.anti_geometric(rhs.nx) + self.my.anti_geometric(rhs.ny) + self.mz.anti_geometric(rhs.nz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Moment3.dot(Plane) -> Vec4
+impl Dot<Plane> for Moment3 {
+	type Output = Vec4;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.mx.0 * rhs.d.0),
+		//     y: -Y(self.my.0 * rhs.d.0),
+		//     z: -Z(self.mz.0 * rhs.d.0),
+		//     w: -W(self.mx.0 * rhs.nx.0) - W(self.my.0 * rhs.ny.0) - W(self.mz.0 * rhs.nz.0),
+		// }
+		Vec4 {
+			x: self.mx.dot(rhs.d),
+			y: self.my.dot(rhs.d),
+			z: self.mz.dot(rhs.d),
+			w: self.mx.dot(rhs.nx) + self.my.dot(rhs.ny) + self.mz.dot(rhs.nz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Moment3 {
+	type Output = Vec4;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for Moment3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Moment3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Moment3.anti_wedge(Plane) -> Vec3
+impl AntiWedge<Plane> for Moment3 {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.my.0 * rhs.nz.0) - X(self.mz.0 * rhs.ny.0),
+		//     y: -Y(self.mx.0 * rhs.nz.0) + Y(self.mz.0 * rhs.nx.0),
+		//     z: Z(self.mx.0 * rhs.ny.0) - Z(self.my.0 * rhs.nx.0),
+		// }
+		Vec3 {
+			x: self.my.anti_wedge(rhs.nz) + self.mz.anti_wedge(rhs.ny),
+			y: self.mx.anti_wedge(rhs.nz) + self.mz.anti_wedge(rhs.nx),
+			z: self.mx.anti_wedge(rhs.ny) + self.my.anti_wedge(rhs.nx),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Moment3 {
+	type Output = Vec3;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Moment3.left_contraction(Plane) -> Vec4
+impl LeftContraction<Plane> for Moment3 {
+	type Output = Vec4;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.mx.0 * rhs.d.0),
+		//     y: -Y(self.my.0 * rhs.d.0),
+		//     z: -Z(self.mz.0 * rhs.d.0),
+		//     w: -W(self.mx.0 * rhs.nx.0) - W(self.my.0 * rhs.ny.0) - W(self.mz.0 * rhs.nz.0),
+		// }
+		Vec4 {
+			x: self.mx.left_contraction(rhs.d),
+			y: self.my.left_contraction(rhs.d),
+			z: self.mz.left_contraction(rhs.d),
+			w: self.mx.left_contraction(rhs.nx) + self.my.left_contraction(rhs.ny) + self.mz.left_contraction(rhs.nz),
+		}
+	}
+}
+
+impl RightContraction<Plane> for Moment3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for Moment3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Moment3.commutator(Plane) -> Plane
+impl Commutator<Plane> for Moment3 {
+	type Output = Plane;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     nx: -YZW(self.my.0 * rhs.nz.0) + YZW(self.mz.0 * rhs.ny.0),
+		//     ny: ZXW(self.mx.0 * rhs.nz.0) - ZXW(self.mz.0 * rhs.nx.0),
+		//     nz: -XYW(self.mx.0 * rhs.ny.0) + XYW(self.my.0 * rhs.nx.0),
+		//     d : Default::default(),
+		// }
+		Plane {
+			nx: self.my.commutator(rhs.nz) + self.mz.commutator(rhs.ny),
+			ny: self.mx.commutator(rhs.nz) + self.mz.commutator(rhs.nx),
+			nz: self.mx.commutator(rhs.ny) + self.my.commutator(rhs.nx),
+			d: Default::default(),
+		}
+	}
+}
+
+// Moment3.anti_dot(Plane) -> XYZ
+impl AntiDot<Plane> for Moment3 {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// XYZ(self.mx.0 * rhs.nx.0) + XYZ(self.my.0 * rhs.ny.0) + XYZ(self.mz.0 * rhs.nz.0)
+		self.mx.anti_dot(rhs.nx) + self.my.anti_dot(rhs.ny) + self.mz.anti_dot(rhs.nz)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Moment3 OP Translator3:
+
+// Moment3.geometric(Translator3) -> Motor3
+impl Geometric<Translator3> for Moment3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.w.0),
+		//     ry: WY(self.my.0 * rhs.w.0),
+		//     rz: WZ(self.mz.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: -YZ(self.my.0 * rhs.z.0) + YZ(self.mz.0 * rhs.y.0),
+		//     uy: ZX(self.mx.0 * rhs.z.0) - ZX(self.mz.0 * rhs.x.0),
+		//     uz: -XY(self.mx.0 * rhs.y.0) + XY(self.my.0 * rhs.x.0),
+		//     uw: -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.mx.geometric(rhs.w),
+			ry: self.my.geometric(rhs.w),
+			rz: self.mz.geometric(rhs.w),
+			rw: Default::default(),
+			ux: self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			uy: self.mx.geometric(rhs.z) + self.mz.geometric(rhs.x),
+			uz: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x),
+			uw: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator3> for Moment3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Translator3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Moment3.anti_geometric(Translator3) -> Moment3
+impl AntiGeometric<Translator3> for Moment3 {
+	type Output = Moment3;
+	fn anti_geometric(self, rhs: Translator3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.mx.0 * rhs.w.0),
+		//     my: ZX(self.my.0 * rhs.w.0),
+		//     mz: XY(self.mz.0 * rhs.w.0),
+		// }
+		Moment3 {
+			mx: self.mx.anti_geometric(rhs.w),
+			my: self.my.anti_geometric(rhs.w),
+			mz: self.mz.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Moment3.dot(Translator3) -> Motor3
+impl Dot<Translator3> for Moment3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.w.0),
+		//     ry: WY(self.my.0 * rhs.w.0),
+		//     rz: WZ(self.mz.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.mx.dot(rhs.w),
+			ry: self.my.dot(rhs.w),
+			rz: self.mz.dot(rhs.w),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.mx.dot(rhs.x) + self.my.dot(rhs.y) + self.mz.dot(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator3> for Moment3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Translator3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Translator3> for Moment3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: Translator3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Translator3> for Moment3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: Translator3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Moment3.anti_wedge(Translator3) -> Moment3
+impl AntiWedge<Translator3> for Moment3 {
+	type Output = Moment3;
+	fn anti_wedge(self, rhs: Translator3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.mx.0 * rhs.w.0),
+		//     my: ZX(self.my.0 * rhs.w.0),
+		//     mz: XY(self.mz.0 * rhs.w.0),
+		// }
+		Moment3 {
+			mx: self.mx.anti_wedge(rhs.w),
+			my: self.my.anti_wedge(rhs.w),
+			mz: self.mz.anti_wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Translator3> for Moment3 {
+	type Output = Moment3;
+	fn bitand(self, rhs: Translator3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Moment3.left_contraction(Translator3) -> Motor3
+impl LeftContraction<Translator3> for Moment3 {
+	type Output = Motor3;
+	fn left_contraction(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.w.0),
+		//     ry: WY(self.my.0 * rhs.w.0),
+		//     rz: WZ(self.mz.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.mx.left_contraction(rhs.w),
+			ry: self.my.left_contraction(rhs.w),
+			rz: self.mz.left_contraction(rhs.w),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.mx.left_contraction(rhs.x) + self.my.left_contraction(rhs.y) + self.mz.left_contraction(rhs.z),
+		}
+	}
+}
+
+// Moment3.right_contraction(Translator3) -> S
+impl RightContraction<Translator3> for Moment3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Translator3) -> Self::Output {
+		// -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0)
+		self.mx.right_contraction(rhs.x) + self.my.right_contraction(rhs.y) + self.mz.right_contraction(rhs.z)
+	}
+}
+
+// Moment3.scalar_product(Translator3) -> S
+impl ScalarProduct<Translator3> for Moment3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator3) -> Self::Output {
+		// -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0)
+		self.mx.scalar_product(rhs.x) + self.my.scalar_product(rhs.y) + self.mz.scalar_product(rhs.z)
+	}
+}
+
+// Moment3.commutator(Translator3) -> Moment3
+impl Commutator<Translator3> for Moment3 {
+	type Output = Moment3;
+	fn commutator(self, rhs: Translator3) -> Self::Output {
+		// Moment3 {
+		//     mx: -YZ(self.my.0 * rhs.z.0) + YZ(self.mz.0 * rhs.y.0),
+		//     my: ZX(self.mx.0 * rhs.z.0) - ZX(self.mz.0 * rhs.x.0),
+		//     mz: -XY(self.mx.0 * rhs.y.0) + XY(self.my.0 * rhs.x.0),
+		// }
+		Moment3 {
+			mx: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			my: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			mz: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+		}
+	}
+}
+
+// Moment3.anti_dot(Translator3) -> Moment3
+impl AntiDot<Translator3> for Moment3 {
+	type Output = Moment3;
+	fn anti_dot(self, rhs: Translator3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.mx.0 * rhs.w.0),
+		//     my: ZX(self.my.0 * rhs.w.0),
+		//     mz: XY(self.mz.0 * rhs.w.0),
+		// }
+		Moment3 {
+			mx: self.mx.anti_dot(rhs.w),
+			my: self.my.anti_dot(rhs.w),
+			mz: self.mz.anti_dot(rhs.w),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Moment3 OP Rotor3:
+
+// Moment3.geometric(Rotor3) -> Rotor3
+impl Geometric<Rotor3> for Moment3 {
+	type Output = Rotor3;
+	fn geometric(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     x: WX(self.mx.0 * rhs.w.0) - WX(self.my.0 * rhs.z.0) + WX(self.mz.0 * rhs.y.0),
+		//     y: WY(self.mx.0 * rhs.z.0) + WY(self.my.0 * rhs.w.0) - WY(self.mz.0 * rhs.x.0),
+		//     z: -WZ(self.mx.0 * rhs.y.0) + WZ(self.my.0 * rhs.x.0) + WZ(self.mz.0 * rhs.w.0),
+		//     w: -XYZW(self.mx.0 * rhs.x.0) - XYZW(self.my.0 * rhs.y.0) - XYZW(self.mz.0 * rhs.z.0),
+		// }
+		Rotor3 {
+			x: self.mx.geometric(rhs.w) + self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			y: self.mx.geometric(rhs.z) + self.my.geometric(rhs.w) + self.mz.geometric(rhs.x),
+			z: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x) + self.mz.geometric(rhs.w),
+			w: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Moment3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Moment3.anti_geometric(Rotor3) -> Motor3
+impl AntiGeometric<Rotor3> for Moment3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.w.0) + YZ(self.my.0 * rhs.z.0) - YZ(self.mz.0 * rhs.y.0),
+		//     uy: -ZX(self.mx.0 * rhs.z.0) + ZX(self.my.0 * rhs.w.0) + ZX(self.mz.0 * rhs.x.0),
+		//     uz: XY(self.mx.0 * rhs.y.0) - XY(self.my.0 * rhs.x.0) + XY(self.mz.0 * rhs.w.0),
+		//     uw: -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.mx.anti_geometric(rhs.w) + self.my.anti_geometric(rhs.z) + self.mz.anti_geometric(rhs.y),
+			uy: self.mx.anti_geometric(rhs.z) + self.my.anti_geometric(rhs.w) + self.mz.anti_geometric(rhs.x),
+			uz: self.mx.anti_geometric(rhs.y) + self.my.anti_geometric(rhs.x) + self.mz.anti_geometric(rhs.w),
+			uw: self.mx.anti_geometric(rhs.x) + self.my.anti_geometric(rhs.y) + self.mz.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Moment3.dot(Rotor3) -> Line3
+impl Dot<Rotor3> for Moment3 {
+	type Output = Line3;
+	fn dot(self, rhs: Rotor3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.mx.0 * rhs.w.0),
+		//     vy: WY(self.my.0 * rhs.w.0),
+		//     vz: WZ(self.mz.0 * rhs.w.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.mx.dot(rhs.w),
+			vy: self.my.dot(rhs.w),
+			vz: self.mz.dot(rhs.w),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor3> for Moment3 {
+	type Output = Line3;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Moment3.wedge(Rotor3) -> XYZW
+impl Wedge<Rotor3> for Moment3 {
+	type Output = XYZW;
+	fn wedge(self, rhs: Rotor3) -> Self::Output {
+		// -XYZW(self.mx.0 * rhs.x.0) - XYZW(self.my.0 * rhs.y.0) - XYZW(self.mz.0 * rhs.z.0)
+		self.mx.wedge(rhs.x) + self.my.wedge(rhs.y) + self.mz.wedge(rhs.z)
+	}
+}
+
+impl std::ops::BitXor<Rotor3> for Moment3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Moment3.anti_wedge(Rotor3) -> Motor3
+impl AntiWedge<Rotor3> for Moment3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.w.0),
+		//     uy: ZX(self.my.0 * rhs.w.0),
+		//     uz: XY(self.mz.0 * rhs.w.0),
+		//     uw: -S(self.mx.0 * rhs.x.0) - S(self.my.0 * rhs.y.0) - S(self.mz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.mx.anti_wedge(rhs.w),
+			uy: self.my.anti_wedge(rhs.w),
+			uz: self.mz.anti_wedge(rhs.w),
+			uw: self.mx.anti_wedge(rhs.x) + self.my.anti_wedge(rhs.y) + self.mz.anti_wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor3> for Moment3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Moment3.left_contraction(Rotor3) -> Line3
+impl LeftContraction<Rotor3> for Moment3 {
+	type Output = Line3;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.mx.0 * rhs.w.0),
+		//     vy: WY(self.my.0 * rhs.w.0),
+		//     vz: WZ(self.mz.0 * rhs.w.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.mx.left_contraction(rhs.w),
+			vy: self.my.left_contraction(rhs.w),
+			vz: self.mz.left_contraction(rhs.w),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Rotor3> for Moment3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Rotor3> for Moment3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Moment3.commutator(Rotor3) -> Line3
+impl Commutator<Rotor3> for Moment3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.my.0 * rhs.z.0) + WX(self.mz.0 * rhs.y.0),
+		//     vy: WY(self.mx.0 * rhs.z.0) - WY(self.mz.0 * rhs.x.0),
+		//     vz: -WZ(self.mx.0 * rhs.y.0) + WZ(self.my.0 * rhs.x.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			vy: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			vz: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+// Moment3.anti_dot(Rotor3) -> Moment3
+impl AntiDot<Rotor3> for Moment3 {
+	type Output = Moment3;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.mx.0 * rhs.w.0),
+		//     my: ZX(self.my.0 * rhs.w.0),
+		//     mz: XY(self.mz.0 * rhs.w.0),
+		// }
+		Moment3 {
+			mx: self.mx.anti_dot(rhs.w),
+			my: self.my.anti_dot(rhs.w),
+			mz: self.mz.anti_dot(rhs.w),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Moment3 OP Motor3:
+
+// Moment3.geometric(Motor3) -> Motor3
+impl Geometric<Motor3> for Moment3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.rw.0) - WX(self.my.0 * rhs.rz.0) + WX(self.mz.0 * rhs.ry.0),
+		//     ry: WY(self.mx.0 * rhs.rz.0) + WY(self.my.0 * rhs.rw.0) - WY(self.mz.0 * rhs.rx.0),
+		//     rz: -WZ(self.mx.0 * rhs.ry.0) + WZ(self.my.0 * rhs.rx.0) + WZ(self.mz.0 * rhs.rw.0),
+		//     rw: -XYZW(self.mx.0 * rhs.rx.0) - XYZW(self.my.0 * rhs.ry.0) - XYZW(self.mz.0 * rhs.rz.0),
+		//     ux: YZ(self.mx.0 * rhs.uw.0) - YZ(self.my.0 * rhs.uz.0) + YZ(self.mz.0 * rhs.uy.0),
+		//     uy: ZX(self.mx.0 * rhs.uz.0) + ZX(self.my.0 * rhs.uw.0) - ZX(self.mz.0 * rhs.ux.0),
+		//     uz: -XY(self.mx.0 * rhs.uy.0) + XY(self.my.0 * rhs.ux.0) + XY(self.mz.0 * rhs.uw.0),
+		//     uw: -S(self.mx.0 * rhs.ux.0) - S(self.my.0 * rhs.uy.0) - S(self.mz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.mx.geometric(rhs.rw) + self.my.geometric(rhs.rz) + self.mz.geometric(rhs.ry),
+			ry: self.mx.geometric(rhs.rz) + self.my.geometric(rhs.rw) + self.mz.geometric(rhs.rx),
+			rz: self.mx.geometric(rhs.ry) + self.my.geometric(rhs.rx) + self.mz.geometric(rhs.rw),
+			rw: self.mx.geometric(rhs.rx) + self.my.geometric(rhs.ry) + self.mz.geometric(rhs.rz),
+			ux: self.mx.geometric(rhs.uw) + self.my.geometric(rhs.uz) + self.mz.geometric(rhs.uy),
+			uy: self.mx.geometric(rhs.uz) + self.my.geometric(rhs.uw) + self.mz.geometric(rhs.ux),
+			uz: self.mx.geometric(rhs.uy) + self.my.geometric(rhs.ux) + self.mz.geometric(rhs.uw),
+			uw: self.mx.geometric(rhs.ux) + self.my.geometric(rhs.uy) + self.mz.geometric(rhs.uz),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor3> for Moment3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Motor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Moment3.anti_geometric(Motor3) -> Motor3
+impl AntiGeometric<Motor3> for Moment3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.rw.0) + YZ(self.my.0 * rhs.rz.0) - YZ(self.mz.0 * rhs.ry.0),
+		//     uy: -ZX(self.mx.0 * rhs.rz.0) + ZX(self.my.0 * rhs.rw.0) + ZX(self.mz.0 * rhs.rx.0),
+		//     uz: XY(self.mx.0 * rhs.ry.0) - XY(self.my.0 * rhs.rx.0) + XY(self.mz.0 * rhs.rw.0),
+		//     uw: -S(self.mx.0 * rhs.rx.0) - S(self.my.0 * rhs.ry.0) - S(self.mz.0 * rhs.rz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.mx.anti_geometric(rhs.rw) + self.my.anti_geometric(rhs.rz) + self.mz.anti_geometric(rhs.ry),
+			uy: self.mx.anti_geometric(rhs.rz) + self.my.anti_geometric(rhs.rw) + self.mz.anti_geometric(rhs.rx),
+			uz: self.mx.anti_geometric(rhs.ry) + self.my.anti_geometric(rhs.rx) + self.mz.anti_geometric(rhs.rw),
+			uw: self.mx.anti_geometric(rhs.rx) + self.my.anti_geometric(rhs.ry) + self.mz.anti_geometric(rhs.rz),
+		}
+	}
+}
+
+// Moment3.dot(Motor3) -> Motor3
+impl Dot<Motor3> for Moment3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.rw.0),
+		//     ry: WY(self.my.0 * rhs.rw.0),
+		//     rz: WZ(self.mz.0 * rhs.rw.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.uw.0),
+		//     uy: ZX(self.my.0 * rhs.uw.0),
+		//     uz: XY(self.mz.0 * rhs.uw.0),
+		//     uw: -S(self.mx.0 * rhs.ux.0) - S(self.my.0 * rhs.uy.0) - S(self.mz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.mx.dot(rhs.rw),
+			ry: self.my.dot(rhs.rw),
+			rz: self.mz.dot(rhs.rw),
+			rw: Default::default(),
+			ux: self.mx.dot(rhs.uw),
+			uy: self.my.dot(rhs.uw),
+			uz: self.mz.dot(rhs.uw),
+			uw: self.mx.dot(rhs.ux) + self.my.dot(rhs.uy) + self.mz.dot(rhs.uz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor3> for Moment3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Motor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Moment3.wedge(Motor3) -> Translator3
+impl Wedge<Motor3> for Moment3 {
+	type Output = Translator3;
+	fn wedge(self, rhs: Motor3) -> Self::Output {
+		// Translator3 {
+		//     x: YZ(self.mx.0 * rhs.uw.0),
+		//     y: ZX(self.my.0 * rhs.uw.0),
+		//     z: XY(self.mz.0 * rhs.uw.0),
+		//     w: -XYZW(self.mx.0 * rhs.rx.0) - XYZW(self.my.0 * rhs.ry.0) - XYZW(self.mz.0 * rhs.rz.0),
+		// }
+		Translator3 {
+			x: self.mx.wedge(rhs.uw),
+			y: self.my.wedge(rhs.uw),
+			z: self.mz.wedge(rhs.uw),
+			w: self.mx.wedge(rhs.rx) + self.my.wedge(rhs.ry) + self.mz.wedge(rhs.rz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Motor3> for Moment3 {
+	type Output = Translator3;
+	fn bitxor(self, rhs: Motor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Moment3.anti_wedge(Motor3) -> Motor3
+impl AntiWedge<Motor3> for Moment3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.rw.0),
+		//     uy: ZX(self.my.0 * rhs.rw.0),
+		//     uz: XY(self.mz.0 * rhs.rw.0),
+		//     uw: -S(self.mx.0 * rhs.rx.0) - S(self.my.0 * rhs.ry.0) - S(self.mz.0 * rhs.rz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.mx.anti_wedge(rhs.rw),
+			uy: self.my.anti_wedge(rhs.rw),
+			uz: self.mz.anti_wedge(rhs.rw),
+			uw: self.mx.anti_wedge(rhs.rx) + self.my.anti_wedge(rhs.ry) + self.mz.anti_wedge(rhs.rz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor3> for Moment3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Motor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Moment3.left_contraction(Motor3) -> Motor3
+impl LeftContraction<Motor3> for Moment3 {
+	type Output = Motor3;
+	fn left_contraction(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.mx.0 * rhs.rw.0),
+		//     ry: WY(self.my.0 * rhs.rw.0),
+		//     rz: WZ(self.mz.0 * rhs.rw.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.mx.0 * rhs.ux.0) - S(self.my.0 * rhs.uy.0) - S(self.mz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.mx.left_contraction(rhs.rw),
+			ry: self.my.left_contraction(rhs.rw),
+			rz: self.mz.left_contraction(rhs.rw),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.mx.left_contraction(rhs.ux) + self.my.left_contraction(rhs.uy) + self.mz.left_contraction(rhs.uz),
+		}
+	}
+}
+
+// Moment3.right_contraction(Motor3) -> Motor3
+impl RightContraction<Motor3> for Moment3 {
+	type Output = Motor3;
+	fn right_contraction(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.mx.0 * rhs.uw.0),
+		//     uy: ZX(self.my.0 * rhs.uw.0),
+		//     uz: XY(self.mz.0 * rhs.uw.0),
+		//     uw: -S(self.mx.0 * rhs.ux.0) - S(self.my.0 * rhs.uy.0) - S(self.mz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.mx.right_contraction(rhs.uw),
+			uy: self.my.right_contraction(rhs.uw),
+			uz: self.mz.right_contraction(rhs.uw),
+			uw: self.mx.right_contraction(rhs.ux)
+				+ self.my.right_contraction(rhs.uy)
+				+ self.mz.right_contraction(rhs.uz),
+		}
+	}
+}
+
+// Moment3.scalar_product(Motor3) -> S
+impl ScalarProduct<Motor3> for Moment3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Motor3) -> Self::Output {
+		// -S(self.mx.0 * rhs.ux.0) - S(self.my.0 * rhs.uy.0) - S(self.mz.0 * rhs.uz.0)
+		self.mx.scalar_product(rhs.ux) + self.my.scalar_product(rhs.uy) + self.mz.scalar_product(rhs.uz)
+	}
+}
+
+// Moment3.commutator(Motor3) -> Line3
+impl Commutator<Motor3> for Moment3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Motor3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.my.0 * rhs.rz.0) + WX(self.mz.0 * rhs.ry.0),
+		//     vy: WY(self.mx.0 * rhs.rz.0) - WY(self.mz.0 * rhs.rx.0),
+		//     vz: -WZ(self.mx.0 * rhs.ry.0) + WZ(self.my.0 * rhs.rx.0),
+		//     mx: -YZ(self.my.0 * rhs.uz.0) + YZ(self.mz.0 * rhs.uy.0),
+		//     my: ZX(self.mx.0 * rhs.uz.0) - ZX(self.mz.0 * rhs.ux.0),
+		//     mz: -XY(self.mx.0 * rhs.uy.0) + XY(self.my.0 * rhs.ux.0),
+		// }
+		Line3 {
+			vx: self.my.commutator(rhs.rz) + self.mz.commutator(rhs.ry),
+			vy: self.mx.commutator(rhs.rz) + self.mz.commutator(rhs.rx),
+			vz: self.mx.commutator(rhs.ry) + self.my.commutator(rhs.rx),
+			mx: self.my.commutator(rhs.uz) + self.mz.commutator(rhs.uy),
+			my: self.mx.commutator(rhs.uz) + self.mz.commutator(rhs.ux),
+			mz: self.mx.commutator(rhs.uy) + self.my.commutator(rhs.ux),
+		}
+	}
+}
+
+// Moment3.anti_dot(Motor3) -> Moment3
+impl AntiDot<Motor3> for Moment3 {
+	type Output = Moment3;
+	fn anti_dot(self, rhs: Motor3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.mx.0 * rhs.rw.0),
+		//     my: ZX(self.my.0 * rhs.rw.0),
+		//     mz: XY(self.mz.0 * rhs.rw.0),
+		// }
+		Moment3 {
+			mx: self.mx.anti_dot(rhs.rw),
+			my: self.my.anti_dot(rhs.rw),
+			mz: self.mz.anti_dot(rhs.rw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Moment3 OP Multivector:
+
+// Moment3.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.yz.0) - S(self.my.0 * rhs.zx.0) - S(self.mz.0 * rhs.xy.0),
+		//     x   : -X(self.mx.0 * rhs.xyz.0) - X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.my.0 * rhs.xyz.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0) - Z(self.mz.0 * rhs.xyz.0),
+		//     w   : -W(self.mx.0 * rhs.yzw.0) - W(self.my.0 * rhs.zxw.0) - W(self.mz.0 * rhs.xyw.0),
+		//     wx  : WX(self.mx.0 * rhs.xyzw.0) - WX(self.my.0 * rhs.wz.0) + WX(self.mz.0 * rhs.wy.0),
+		//     wy  : WY(self.mx.0 * rhs.wz.0) + WY(self.my.0 * rhs.xyzw.0) - WY(self.mz.0 * rhs.wx.0),
+		//     wz  : -WZ(self.mx.0 * rhs.wy.0) + WZ(self.my.0 * rhs.wx.0) + WZ(self.mz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.mx.0 * rhs.s.0) - YZ(self.my.0 * rhs.xy.0) + YZ(self.mz.0 * rhs.zx.0),
+		//     zx  : ZX(self.mx.0 * rhs.xy.0) + ZX(self.my.0 * rhs.s.0) - ZX(self.mz.0 * rhs.yz.0),
+		//     xy  : -XY(self.mx.0 * rhs.zx.0) + XY(self.my.0 * rhs.yz.0) + XY(self.mz.0 * rhs.s.0),
+		//     yzw : YZW(self.mx.0 * rhs.w.0) - YZW(self.my.0 * rhs.xyw.0) + YZW(self.mz.0 * rhs.zxw.0),
+		//     zxw : ZXW(self.mx.0 * rhs.xyw.0) + ZXW(self.my.0 * rhs.w.0) - ZXW(self.mz.0 * rhs.yzw.0),
+		//     xyw : -XYW(self.mx.0 * rhs.zxw.0) + XYW(self.my.0 * rhs.yzw.0) + XYW(self.mz.0 * rhs.w.0),
+		//     xyz : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.mx.0 * rhs.wx.0) - XYZW(self.my.0 * rhs.wy.0) - XYZW(self.mz.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: self.mx.geometric(rhs.yz) + self.my.geometric(rhs.zx) + self.mz.geometric(rhs.xy),
+			x: self.mx.geometric(rhs.xyz) + self.my.geometric(rhs.z) + self.mz.geometric(rhs.y),
+			y: self.mx.geometric(rhs.z) + self.my.geometric(rhs.xyz) + self.mz.geometric(rhs.x),
+			z: self.mx.geometric(rhs.y) + self.my.geometric(rhs.x) + self.mz.geometric(rhs.xyz),
+			w: self.mx.geometric(rhs.yzw) + self.my.geometric(rhs.zxw) + self.mz.geometric(rhs.xyw),
+			wx: self.mx.geometric(rhs.xyzw) + self.my.geometric(rhs.wz) + self.mz.geometric(rhs.wy),
+			wy: self.mx.geometric(rhs.wz) + self.my.geometric(rhs.xyzw) + self.mz.geometric(rhs.wx),
+			wz: self.mx.geometric(rhs.wy) + self.my.geometric(rhs.wx) + self.mz.geometric(rhs.xyzw),
+			yz: self.mx.geometric(rhs.s) + self.my.geometric(rhs.xy) + self.mz.geometric(rhs.zx),
+			zx: self.mx.geometric(rhs.xy) + self.my.geometric(rhs.s) + self.mz.geometric(rhs.yz),
+			xy: self.mx.geometric(rhs.zx) + self.my.geometric(rhs.yz) + self.mz.geometric(rhs.s),
+			yzw: self.mx.geometric(rhs.w) + self.my.geometric(rhs.xyw) + self.mz.geometric(rhs.zxw),
+			zxw: self.mx.geometric(rhs.xyw) + self.my.geometric(rhs.w) + self.mz.geometric(rhs.yzw),
+			xyw: self.mx.geometric(rhs.zxw) + self.my.geometric(rhs.yzw) + self.mz.geometric(rhs.w),
+			xyz: self.mx.geometric(rhs.x) + self.my.geometric(rhs.y) + self.mz.geometric(rhs.z),
+			xyzw: self.mx.geometric(rhs.wx) + self.my.geometric(rhs.wy) + self.mz.geometric(rhs.wz),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Moment3.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.wx.0) - S(self.my.0 * rhs.wy.0) - S(self.mz.0 * rhs.wz.0),
+		//     x   : X(self.mx.0 * rhs.w.0) + X(self.my.0 * rhs.xyw.0) - X(self.mz.0 * rhs.zxw.0),
+		//     y   : -Y(self.mx.0 * rhs.xyw.0) + Y(self.my.0 * rhs.w.0) + Y(self.mz.0 * rhs.yzw.0),
+		//     z   : Z(self.mx.0 * rhs.zxw.0) - Z(self.my.0 * rhs.yzw.0) + Z(self.mz.0 * rhs.w.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.mx.0 * rhs.xyzw.0) + YZ(self.my.0 * rhs.wz.0) - YZ(self.mz.0 * rhs.wy.0),
+		//     zx  : -ZX(self.mx.0 * rhs.wz.0) + ZX(self.my.0 * rhs.xyzw.0) + ZX(self.mz.0 * rhs.wx.0),
+		//     xy  : XY(self.mx.0 * rhs.wy.0) - XY(self.my.0 * rhs.wx.0) + XY(self.mz.0 * rhs.xyzw.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.mx.0 * rhs.yzw.0) + XYZ(self.my.0 * rhs.zxw.0) + XYZ(self.mz.0 * rhs.xyw.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.mx.anti_geometric(rhs.wx) + self.my.anti_geometric(rhs.wy) + self.mz.anti_geometric(rhs.wz),
+			x: self.mx.anti_geometric(rhs.w) + self.my.anti_geometric(rhs.xyw) + self.mz.anti_geometric(rhs.zxw),
+			y: self.mx.anti_geometric(rhs.xyw) + self.my.anti_geometric(rhs.w) + self.mz.anti_geometric(rhs.yzw),
+			z: self.mx.anti_geometric(rhs.zxw) + self.my.anti_geometric(rhs.yzw) + self.mz.anti_geometric(rhs.w),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.mx.anti_geometric(rhs.xyzw) + self.my.anti_geometric(rhs.wz) + self.mz.anti_geometric(rhs.wy),
+			zx: self.mx.anti_geometric(rhs.wz) + self.my.anti_geometric(rhs.xyzw) + self.mz.anti_geometric(rhs.wx),
+			xy: self.mx.anti_geometric(rhs.wy) + self.my.anti_geometric(rhs.wx) + self.mz.anti_geometric(rhs.xyzw),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.mx.anti_geometric(rhs.yzw) + self.my.anti_geometric(rhs.zxw) + self.mz.anti_geometric(rhs.xyw),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Moment3.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.yz.0) - S(self.my.0 * rhs.zx.0) - S(self.mz.0 * rhs.xy.0),
+		//     x   : -X(self.mx.0 * rhs.xyz.0) - X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.my.0 * rhs.xyz.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0) - Z(self.mz.0 * rhs.xyz.0),
+		//     w   : -W(self.mx.0 * rhs.yzw.0) - W(self.my.0 * rhs.zxw.0) - W(self.mz.0 * rhs.xyw.0),
+		//     wx  : WX(self.mx.0 * rhs.xyzw.0),
+		//     wy  : WY(self.my.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.mz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.mx.0 * rhs.s.0),
+		//     zx  : ZX(self.my.0 * rhs.s.0),
+		//     xy  : XY(self.mz.0 * rhs.s.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.mx.dot(rhs.yz) + self.my.dot(rhs.zx) + self.mz.dot(rhs.xy),
+			x: self.mx.dot(rhs.xyz) + self.my.dot(rhs.z) + self.mz.dot(rhs.y),
+			y: self.mx.dot(rhs.z) + self.my.dot(rhs.xyz) + self.mz.dot(rhs.x),
+			z: self.mx.dot(rhs.y) + self.my.dot(rhs.x) + self.mz.dot(rhs.xyz),
+			w: self.mx.dot(rhs.yzw) + self.my.dot(rhs.zxw) + self.mz.dot(rhs.xyw),
+			wx: self.mx.dot(rhs.xyzw),
+			wy: self.my.dot(rhs.xyzw),
+			wz: self.mz.dot(rhs.xyzw),
+			yz: self.mx.dot(rhs.s),
+			zx: self.my.dot(rhs.s),
+			xy: self.mz.dot(rhs.s),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Moment3.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.mx.0 * rhs.s.0),
+		//     zx  : ZX(self.my.0 * rhs.s.0),
+		//     xy  : XY(self.mz.0 * rhs.s.0),
+		//     yzw : YZW(self.mx.0 * rhs.w.0),
+		//     zxw : ZXW(self.my.0 * rhs.w.0),
+		//     xyw : XYW(self.mz.0 * rhs.w.0),
+		//     xyz : XYZ(self.mx.0 * rhs.x.0) + XYZ(self.my.0 * rhs.y.0) + XYZ(self.mz.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.mx.0 * rhs.wx.0) - XYZW(self.my.0 * rhs.wy.0) - XYZW(self.mz.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.mx.wedge(rhs.s),
+			zx: self.my.wedge(rhs.s),
+			xy: self.mz.wedge(rhs.s),
+			yzw: self.mx.wedge(rhs.w),
+			zxw: self.my.wedge(rhs.w),
+			xyw: self.mz.wedge(rhs.w),
+			xyz: self.mx.wedge(rhs.x) + self.my.wedge(rhs.y) + self.mz.wedge(rhs.z),
+			xyzw: self.mx.wedge(rhs.wx) + self.my.wedge(rhs.wy) + self.mz.wedge(rhs.wz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Moment3.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.wx.0) - S(self.my.0 * rhs.wy.0) - S(self.mz.0 * rhs.wz.0),
+		//     x   : X(self.my.0 * rhs.xyw.0) - X(self.mz.0 * rhs.zxw.0),
+		//     y   : -Y(self.mx.0 * rhs.xyw.0) + Y(self.mz.0 * rhs.yzw.0),
+		//     z   : Z(self.mx.0 * rhs.zxw.0) - Z(self.my.0 * rhs.yzw.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.mx.0 * rhs.xyzw.0),
+		//     zx  : ZX(self.my.0 * rhs.xyzw.0),
+		//     xy  : XY(self.mz.0 * rhs.xyzw.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.mx.anti_wedge(rhs.wx) + self.my.anti_wedge(rhs.wy) + self.mz.anti_wedge(rhs.wz),
+			x: self.my.anti_wedge(rhs.xyw) + self.mz.anti_wedge(rhs.zxw),
+			y: self.mx.anti_wedge(rhs.xyw) + self.mz.anti_wedge(rhs.yzw),
+			z: self.mx.anti_wedge(rhs.zxw) + self.my.anti_wedge(rhs.yzw),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.mx.anti_wedge(rhs.xyzw),
+			zx: self.my.anti_wedge(rhs.xyzw),
+			xy: self.mz.anti_wedge(rhs.xyzw),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Moment3.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.yz.0) - S(self.my.0 * rhs.zx.0) - S(self.mz.0 * rhs.xy.0),
+		//     x   : -X(self.mx.0 * rhs.xyz.0),
+		//     y   : -Y(self.my.0 * rhs.xyz.0),
+		//     z   : -Z(self.mz.0 * rhs.xyz.0),
+		//     w   : -W(self.mx.0 * rhs.yzw.0) - W(self.my.0 * rhs.zxw.0) - W(self.mz.0 * rhs.xyw.0),
+		//     wx  : WX(self.mx.0 * rhs.xyzw.0),
+		//     wy  : WY(self.my.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.mz.0 * rhs.xyzw.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.mx.left_contraction(rhs.yz) + self.my.left_contraction(rhs.zx) + self.mz.left_contraction(rhs.xy),
+			x: self.mx.left_contraction(rhs.xyz),
+			y: self.my.left_contraction(rhs.xyz),
+			z: self.mz.left_contraction(rhs.xyz),
+			w: self.mx.left_contraction(rhs.yzw)
+				+ self.my.left_contraction(rhs.zxw)
+				+ self.mz.left_contraction(rhs.xyw),
+			wx: self.mx.left_contraction(rhs.xyzw),
+			wy: self.my.left_contraction(rhs.xyzw),
+			wz: self.mz.left_contraction(rhs.xyzw),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Moment3.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.mx.0 * rhs.yz.0) - S(self.my.0 * rhs.zx.0) - S(self.mz.0 * rhs.xy.0),
+		//     x   : -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.mx.0 * rhs.s.0),
+		//     zx  : ZX(self.my.0 * rhs.s.0),
+		//     xy  : XY(self.mz.0 * rhs.s.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.mx.right_contraction(rhs.yz)
+				+ self.my.right_contraction(rhs.zx)
+				+ self.mz.right_contraction(rhs.xy),
+			x: self.my.right_contraction(rhs.z) + self.mz.right_contraction(rhs.y),
+			y: self.mx.right_contraction(rhs.z) + self.mz.right_contraction(rhs.x),
+			z: self.mx.right_contraction(rhs.y) + self.my.right_contraction(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.mx.right_contraction(rhs.s),
+			zx: self.my.right_contraction(rhs.s),
+			xy: self.mz.right_contraction(rhs.s),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Moment3.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Moment3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.mx.0 * rhs.yz.0) - S(self.my.0 * rhs.zx.0) - S(self.mz.0 * rhs.xy.0)
+		self.mx.scalar_product(rhs.yz) + self.my.scalar_product(rhs.zx) + self.mz.scalar_product(rhs.xy)
+	}
+}
+
+// Moment3.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.my.0 * rhs.z.0) + X(self.mz.0 * rhs.y.0),
+		//     y   : Y(self.mx.0 * rhs.z.0) - Y(self.mz.0 * rhs.x.0),
+		//     z   : -Z(self.mx.0 * rhs.y.0) + Z(self.my.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : -WX(self.my.0 * rhs.wz.0) + WX(self.mz.0 * rhs.wy.0),
+		//     wy  : WY(self.mx.0 * rhs.wz.0) - WY(self.mz.0 * rhs.wx.0),
+		//     wz  : -WZ(self.mx.0 * rhs.wy.0) + WZ(self.my.0 * rhs.wx.0),
+		//     yz  : -YZ(self.my.0 * rhs.xy.0) + YZ(self.mz.0 * rhs.zx.0),
+		//     zx  : ZX(self.mx.0 * rhs.xy.0) - ZX(self.mz.0 * rhs.yz.0),
+		//     xy  : -XY(self.mx.0 * rhs.zx.0) + XY(self.my.0 * rhs.yz.0),
+		//     yzw : -YZW(self.my.0 * rhs.xyw.0) + YZW(self.mz.0 * rhs.zxw.0),
+		//     zxw : ZXW(self.mx.0 * rhs.xyw.0) - ZXW(self.mz.0 * rhs.yzw.0),
+		//     xyw : -XYW(self.mx.0 * rhs.zxw.0) + XYW(self.my.0 * rhs.yzw.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.my.commutator(rhs.z) + self.mz.commutator(rhs.y),
+			y: self.mx.commutator(rhs.z) + self.mz.commutator(rhs.x),
+			z: self.mx.commutator(rhs.y) + self.my.commutator(rhs.x),
+			w: Default::default(),
+			wx: self.my.commutator(rhs.wz) + self.mz.commutator(rhs.wy),
+			wy: self.mx.commutator(rhs.wz) + self.mz.commutator(rhs.wx),
+			wz: self.mx.commutator(rhs.wy) + self.my.commutator(rhs.wx),
+			yz: self.my.commutator(rhs.xy) + self.mz.commutator(rhs.zx),
+			zx: self.mx.commutator(rhs.xy) + self.mz.commutator(rhs.yz),
+			xy: self.mx.commutator(rhs.zx) + self.my.commutator(rhs.yz),
+			yzw: self.my.commutator(rhs.xyw) + self.mz.commutator(rhs.zxw),
+			zxw: self.mx.commutator(rhs.xyw) + self.mz.commutator(rhs.yzw),
+			xyw: self.mx.commutator(rhs.zxw) + self.my.commutator(rhs.yzw),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Moment3.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Moment3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.mx.0 * rhs.xyzw.0),
+		//     zx  : ZX(self.my.0 * rhs.xyzw.0),
+		//     xy  : XY(self.mz.0 * rhs.xyzw.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.mx.0 * rhs.yzw.0) + XYZ(self.my.0 * rhs.zxw.0) + XYZ(self.mz.0 * rhs.xyw.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.mx.anti_dot(rhs.xyzw),
+			zx: self.my.anti_dot(rhs.xyzw),
+			xy: self.mz.anti_dot(rhs.xyzw),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.mx.anti_dot(rhs.yzw) + self.my.anti_dot(rhs.zxw) + self.mz.anti_dot(rhs.xyw),
+			xyzw: Default::default(),
+		}
+	}
+}
@@ -3,39 +3,287 @@
 //! ## Operations
 //! ```text
 //! Motor3.geometric(Motor3) -> Motor3
+//! Motor3.anti_geometric(Motor3) -> Motor3
 //! Motor3.dot(Motor3) -> Motor3
 //! Motor3.wedge(Motor3) -> Motor3
-//!
-//!
-//!
-//!
+//! Motor3.anti_wedge(Motor3) -> Motor3
+//! Motor3.left_contraction(Motor3) -> Motor3
+//! Motor3.right_contraction(Motor3) -> Motor3
+//! Motor3.scalar_product(Motor3) -> S
+//! Motor3.commutator(Motor3) -> Line3
+//! Motor3.anti_dot(Motor3) -> Motor3
+//! Motor3.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Motor3) -> Multivector
+//! Motor3.anti_geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Motor3) -> Multivector
+//! Motor3.dot(Vec3) -> Multivector
+//! Vec3.dot(Motor3) -> Multivector
+//! Motor3.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Motor3) -> Multivector
+//! Motor3.anti_wedge(Vec3) -> Vec3
+//! Vec3.anti_wedge(Motor3) -> Vec3
+//! Motor3.left_contraction(Vec3) -> Vec3
+//! Vec3.left_contraction(Motor3) -> Multivector
+//! Motor3.right_contraction(Vec3) -> Multivector
+//! Vec3.right_contraction(Motor3) -> Vec3
+//! Motor3.commutator(Vec3) -> Multivector
+//! Vec3.commutator(Motor3) -> Multivector
+//! Motor3.anti_dot(Vec3) -> Multivector
+//! Vec3.anti_dot(Motor3) -> Multivector
+//! Motor3.geometric(Vec4) -> Multivector
+//! Vec4.geometric(Motor3) -> Multivector
+//! Motor3.anti_geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Motor3) -> Multivector
+//! Motor3.dot(Vec4) -> Multivector
+//! Vec4.dot(Motor3) -> Multivector
+//! Motor3.wedge(Vec4) -> Multivector
+//! Vec4.wedge(Motor3) -> Multivector
+//! Motor3.anti_wedge(Vec4) -> Vec4
+//! Vec4.anti_wedge(Motor3) -> Vec4
+//! Motor3.left_contraction(Vec4) -> Vec4
+//! Vec4.left_contraction(Motor3) -> Multivector
+//! Motor3.right_contraction(Vec4) -> Multivector
+//! Vec4.right_contraction(Motor3) -> Vec4
+//! Motor3.commutator(Vec4) -> Multivector
+//! Vec4.commutator(Motor3) -> Multivector
+//! Motor3.anti_dot(Vec4) -> Multivector
+//! Vec4.anti_dot(Motor3) -> Multivector
+//! Motor3.geometric(Point3) -> Multivector
+//! Point3.geometric(Motor3) -> Multivector
+//! Motor3.anti_geometric(Point3) -> Multivector
+//! Point3.anti_geometric(Motor3) -> Multivector
+//! Motor3.dot(Point3) -> Multivector
+//! Point3.dot(Motor3) -> Multivector
+//! Motor3.wedge(Point3) -> Multivector
+//! Point3.wedge(Motor3) -> Multivector
+//! Motor3.anti_wedge(Point3) -> Vec4
+//! Point3.anti_wedge(Motor3) -> Vec4
+//! Motor3.left_contraction(Point3) -> Vec4
+//! Point3.left_contraction(Motor3) -> Multivector
+//! Motor3.right_contraction(Point3) -> Multivector
+//! Point3.right_contraction(Motor3) -> Vec4
+//! Motor3.commutator(Point3) -> Multivector
+//! Point3.commutator(Motor3) -> Multivector
+//! Motor3.anti_dot(Point3) -> Multivector
+//! Point3.anti_dot(Motor3) -> Multivector
+//! Motor3.geometric(Moment3) -> Motor3
+//! Moment3.geometric(Motor3) -> Motor3
+//! Motor3.anti_geometric(Moment3) -> Motor3
+//! Moment3.anti_geometric(Motor3) -> Motor3
+//! Motor3.dot(Moment3) -> Motor3
+//! Moment3.dot(Motor3) -> Motor3
+//! Motor3.wedge(Moment3) -> Translator3
+//! Moment3.wedge(Motor3) -> Translator3
+//! Motor3.anti_wedge(Moment3) -> Motor3
+//! Moment3.anti_wedge(Motor3) -> Motor3
+//! Motor3.left_contraction(Moment3) -> Motor3
+//! Moment3.left_contraction(Motor3) -> Motor3
+//! Motor3.right_contraction(Moment3) -> Motor3
+//! Moment3.right_contraction(Motor3) -> Motor3
+//! Motor3.scalar_product(Moment3) -> S
+//! Moment3.scalar_product(Motor3) -> S
+//! Motor3.commutator(Moment3) -> Line3
+//! Moment3.commutator(Motor3) -> Line3
+//! Motor3.anti_dot(Moment3) -> Moment3
+//! Moment3.anti_dot(Motor3) -> Moment3
+//! Motor3.geometric(Line3) -> Motor3
+//! Line3.geometric(Motor3) -> Motor3
+//! Motor3.anti_geometric(Line3) -> Motor3
+//! Line3.anti_geometric(Motor3) -> Motor3
+//! Motor3.dot(Line3) -> Motor3
+//! Line3.dot(Motor3) -> Motor3
+//! Motor3.wedge(Line3) -> Motor3
+//! Line3.wedge(Motor3) -> Motor3
+//! Motor3.anti_wedge(Line3) -> Motor3
+//! Line3.anti_wedge(Motor3) -> Motor3
+//! Motor3.left_contraction(Line3) -> Motor3
+//! Line3.left_contraction(Motor3) -> Motor3
+//! Motor3.right_contraction(Line3) -> Motor3
+//! Line3.right_contraction(Motor3) -> Motor3
+//! Motor3.scalar_product(Line3) -> S
+//! Line3.scalar_product(Motor3) -> S
+//! Motor3.commutator(Line3) -> Line3
+//! Line3.commutator(Motor3) -> Line3
+//! Motor3.anti_dot(Line3) -> Motor3
+//! Line3.anti_dot(Motor3) -> Motor3
+//! Motor3.geometric(Plane) -> Multivector
+//! Plane.geometric(Motor3) -> Multivector
+//! Motor3.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Motor3) -> Multivector
+//! Motor3.dot(Plane) -> Multivector
+//! Plane.dot(Motor3) -> Multivector
 //! Motor3.wedge(Plane) -> Plane
 //! Plane.wedge(Motor3) -> Plane
+//! Motor3.anti_wedge(Plane) -> Multivector
+//! Plane.anti_wedge(Motor3) -> Multivector
+//! Motor3.left_contraction(Plane) -> Multivector
+//! Plane.left_contraction(Motor3) -> W
+//! Motor3.right_contraction(Plane) -> W
+//! Plane.right_contraction(Motor3) -> Multivector
+//! Motor3.commutator(Plane) -> Multivector
+//! Plane.commutator(Motor3) -> Multivector
+//! Motor3.anti_dot(Plane) -> Multivector
+//! Plane.anti_dot(Motor3) -> Multivector
+//! Motor3.geometric(Translator3) -> Motor3
+//! Translator3.geometric(Motor3) -> Motor3
+//! Motor3.anti_geometric(Translator3) -> Motor3
+//! Translator3.anti_geometric(Motor3) -> Motor3
+//! Motor3.dot(Translator3) -> Motor3
+//! Translator3.dot(Motor3) -> Motor3
+//! Motor3.wedge(Translator3) -> Translator3
+//! Translator3.wedge(Motor3) -> Translator3
+//! Motor3.anti_wedge(Translator3) -> Motor3
+//! Translator3.anti_wedge(Motor3) -> Motor3
+//! Motor3.left_contraction(Translator3) -> Motor3
+//! Translator3.left_contraction(Motor3) -> Motor3
+//! Motor3.right_contraction(Translator3) -> Motor3
+//! Translator3.right_contraction(Motor3) -> Motor3
+//! Motor3.scalar_product(Translator3) -> S
+//! Translator3.scalar_product(Motor3) -> S
+//! Motor3.commutator(Translator3) -> Line3
+//! Translator3.commutator(Motor3) -> Line3
+//! Motor3.anti_dot(Translator3) -> Motor3
+//! Translator3.anti_dot(Motor3) -> Motor3
 //! Motor3.geometric(Rotor3) -> Rotor3
 //! Rotor3.geometric(Motor3) -> Rotor3
+//! Motor3.anti_geometric(Rotor3) -> Motor3
+//! Rotor3.anti_geometric(Motor3) -> Motor3
 //! Motor3.dot(Rotor3) -> Rotor3
 //! Rotor3.dot(Motor3) -> Rotor3
 //! Motor3.wedge(Rotor3) -> Rotor3
 //! Rotor3.wedge(Motor3) -> Rotor3
+//! Motor3.anti_wedge(Rotor3) -> Motor3
+//! Rotor3.anti_wedge(Motor3) -> Motor3
+//! Motor3.left_contraction(Rotor3) -> Rotor3
+//! Rotor3.right_contraction(Motor3) -> Rotor3
+//! Motor3.commutator(Rotor3) -> Line3
+//! Rotor3.commutator(Motor3) -> Line3
+//! Motor3.anti_dot(Rotor3) -> Motor3
+//! Rotor3.anti_dot(Motor3) -> Motor3
+//! Motor3.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Motor3) -> Multivector
+//! Motor3.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Motor3) -> Multivector
+//! Motor3.dot(Multivector) -> Multivector
+//! Multivector.dot(Motor3) -> Multivector
+//! Motor3.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Motor3) -> Multivector
+//! Motor3.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Motor3) -> Multivector
+//! Motor3.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Motor3) -> Multivector
+//! Motor3.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Motor3) -> Multivector
+//! Motor3.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Motor3) -> S
+//! Motor3.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Motor3) -> Multivector
+//! Motor3.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Motor3) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Motor3 {
 	pub rx: WX,
 	pub ry: WY,
 	pub rz: WZ,
 	pub rw: XYZW,
-	pub ux: YZW,
-	pub uy: ZXW,
-	pub uz: XYW,
+	pub ux: YZ,
+	pub uy: ZX,
+	pub uz: XY,
 	pub uw: S,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Motor3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Motor3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Motor3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.rx.abs_diff_eq(&other.rx, epsilon)
+			&& self.ry.abs_diff_eq(&other.ry, epsilon)
+			&& self.rz.abs_diff_eq(&other.rz, epsilon)
+			&& self.rw.abs_diff_eq(&other.rw, epsilon)
+			&& self.ux.abs_diff_eq(&other.ux, epsilon)
+			&& self.uy.abs_diff_eq(&other.uy, epsilon)
+			&& self.uz.abs_diff_eq(&other.uz, epsilon)
+			&& self.uw.abs_diff_eq(&other.uw, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Motor3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.rx.relative_eq(&other.rx, epsilon, max_relative)
+			&& self.ry.relative_eq(&other.ry, epsilon, max_relative)
+			&& self.rz.relative_eq(&other.rz, epsilon, max_relative)
+			&& self.rw.relative_eq(&other.rw, epsilon, max_relative)
+			&& self.ux.relative_eq(&other.ux, epsilon, max_relative)
+			&& self.uy.relative_eq(&other.uy, epsilon, max_relative)
+			&& self.uz.relative_eq(&other.uz, epsilon, max_relative)
+			&& self.uw.relative_eq(&other.uw, epsilon, max_relative)
+	}
+}
+
 // ---------------------------------------------------------------------
-// Omitted: Motor3.rcompl() -> self.rw.rcompl() + self.rx.rcompl() + self.ry.rcompl() + self.rz.rcompl() + self.uw.rcompl() + self.ux.rcompl() + self.uy.rcompl() + self.uz.rcompl()
-// Omitted: Motor3.lcompl() -> self.rw.lcompl() + self.rx.lcompl() + self.ry.lcompl() + self.rz.lcompl() + self.uw.lcompl() + self.ux.lcompl() + self.uy.lcompl() + self.uz.lcompl()
+
+impl RCompl for Motor3 {
+	type Output = Motor3;
+	fn rcompl(self) -> Self::Output {
+		Motor3 {
+			rx: self.ux.rcompl(),
+			ry: self.uy.rcompl(),
+			rz: self.uz.rcompl(),
+			rw: self.uw.rcompl(),
+			ux: self.rx.rcompl(),
+			uy: self.ry.rcompl(),
+			uz: self.rz.rcompl(),
+			uw: self.rw.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Motor3 {
+	type Output = Motor3;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Motor3 {
+	type Output = Motor3;
+	fn lcompl(self) -> Self::Output {
+		Motor3 {
+			rx: self.ux.lcompl(),
+			ry: self.uy.lcompl(),
+			rz: self.uz.lcompl(),
+			rw: self.uw.lcompl(),
+			ux: self.rx.lcompl(),
+			uy: self.ry.lcompl(),
+			uz: self.rz.lcompl(),
+			uw: self.rw.lcompl(),
+		}
+	}
+}
 
 impl Reverse for Motor3 {
 	fn rev(self) -> Self {
@@ -59,6 +307,68 @@ impl AntiReverse for Motor3 {
 			ry: -self.ry,
 			rz: -self.rz,
 			rw: self.rw,
+			ux: -self.ux,
+			uy: -self.uy,
+			uz: -self.uz,
+			uw: self.uw,
+		}
+	}
+}
+
+impl Involute for Motor3 {
+	fn involute(self) -> Self {
+		Motor3 {
+			rx: self.rx,
+			ry: self.ry,
+			rz: self.rz,
+			rw: self.rw,
+			ux: self.ux,
+			uy: self.uy,
+			uz: self.uz,
+			uw: self.uw,
+		}
+	}
+}
+
+impl Conjugate for Motor3 {
+	fn conjugate(self) -> Self {
+		Motor3 {
+			rx: -self.rx,
+			ry: -self.ry,
+			rz: -self.rz,
+			rw: self.rw,
+			ux: -self.ux,
+			uy: -self.uy,
+			uz: -self.uz,
+			uw: self.uw,
+		}
+	}
+}
+
+impl Dual for Motor3 {
+	type Output = Motor3;
+	fn dual(self) -> Self::Output {
+		Motor3 {
+			rx: self.ux.dual(),
+			ry: self.uy.dual(),
+			rz: self.uz.dual(),
+			rw: self.uw.dual(),
+			ux: self.rx.dual(),
+			uy: self.ry.dual(),
+			uz: self.rz.dual(),
+			uw: self.rw.dual(),
+		}
+	}
+}
+
+impl Bulk for Motor3 {
+	type Output = Motor3;
+	fn bulk(self) -> Self::Output {
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
 			ux: self.ux,
 			uy: self.uy,
 			uz: self.uz,
@@ -67,69 +377,2894 @@ impl AntiReverse for Motor3 {
 	}
 }
 
+impl Weight for Motor3 {
+	type Output = Rotor3;
+	fn weight(self) -> Self::Output {
+		Rotor3 {
+			x: self.rx,
+			y: self.ry,
+			z: self.rz,
+			w: self.rw,
+		}
+	}
+}
+
+impl BulkDual for Motor3 {
+	type Output = Rotor3;
+	fn bulk_dual(self) -> Self::Output {
+		Rotor3 {
+			x: self.ux.bulk_dual(),
+			y: self.uy.bulk_dual(),
+			z: self.uz.bulk_dual(),
+			w: self.uw.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Motor3 {
+	type Output = Motor3;
+	fn weight_dual(self) -> Self::Output {
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.rx.weight_dual(),
+			uy: self.ry.weight_dual(),
+			uz: self.rz.weight_dual(),
+			uw: self.rw.weight_dual(),
+		}
+	}
+}
+
+// Omitted: Motor3.square()  (not a scalar)
+
+impl Norm for Motor3 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.rx.0 * self.rx.0
+			+ self.ry.0 * self.ry.0
+			+ self.rz.0 * self.rz.0
+			+ self.rw.0 * self.rw.0
+			+ self.ux.0 * self.ux.0
+			+ self.uy.0 * self.uy.0
+			+ self.uz.0 * self.uz.0
+			+ self.uw.0 * self.uw.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.ux.0 * self.ux.0 + self.uy.0 * self.uy.0 + self.uz.0 * self.uz.0 + self.uw.0 * self.uw.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.rx.0 * self.rx.0 + self.ry.0 * self.ry.0 + self.rz.0 * self.rz.0 + self.rw.0 * self.rw.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.weight_norm();
+		Motor3 {
+			rx: self.rx * scale,
+			ry: self.ry * scale,
+			rz: self.rz * scale,
+			rw: self.rw * scale,
+			ux: self.ux * scale,
+			uy: self.uy * scale,
+			uz: self.uz * scale,
+			uw: self.uw * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Motor3 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 8] {
+		[
+			self.rx.0, self.ry.0, self.rz.0, self.rw.0, self.ux.0, self.uy.0, self.uz.0, self.uw.0,
+		]
+	}
+}
+
+impl From<[f64; 8]> for Motor3 {
+	fn from(a: [f64; 8]) -> Self {
+		Motor3 {
+			rx: WX(a[0]),
+			ry: WY(a[1]),
+			rz: WZ(a[2]),
+			rw: XYZW(a[3]),
+			ux: YZ(a[4]),
+			uy: ZX(a[5]),
+			uz: XY(a[6]),
+			uw: S(a[7]),
+		}
+	}
+}
+
+impl From<Motor3> for [f64; 8] {
+	fn from(v: Motor3) -> Self {
+		v.to_array()
+	}
+}
+
+impl Motor3 {
+	/// A pure translation.
+	pub fn from_translation(t: [f64; 3]) -> Self {
+		let half = 1.0 / 2.0;
+		Motor3 {
+			rx: WX(0.0),
+			ry: WY(0.0),
+			rz: WZ(0.0),
+			rw: XYZW(1.0),
+			ux: YZ(half * t[0]),
+			uy: ZX(half * t[1]),
+			uz: XY(half * t[2]),
+			uw: S(0.0),
+		}
+	}
+}
+
+impl From<Motor3> for Multivector {
+	fn from(v: Motor3) -> Self {
+		Multivector {
+			wx: v.rx,
+			wy: v.ry,
+			wz: v.rz,
+			xyzw: v.rw,
+			yz: v.ux,
+			zx: v.uy,
+			xy: v.uz,
+			s: v.uw,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Motor3 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Motor3` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.x.0 == 0.0
+			&& mv.y.0 == 0.0
+			&& mv.z.0 == 0.0
+			&& mv.w.0 == 0.0
+			&& mv.yzw.0 == 0.0
+			&& mv.zxw.0 == 0.0
+			&& mv.xyw.0 == 0.0
+			&& mv.xyz.0 == 0.0
+		{
+			Ok(Motor3 {
+				rx: mv.wx,
+				ry: mv.wy,
+				rz: mv.wz,
+				rw: mv.xyzw,
+				ux: mv.yz,
+				uy: mv.zx,
+				uz: mv.xy,
+				uw: mv.s,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Ln for Motor3 {
+	type Output = Line3;
+
+	/// The inverse of [`Exp`] for a normalized motor.
+	fn ln(self) -> Self::Output {
+		// `self` and `-self` are the same motion, so pick the shortest path:
+		let motor = if self.rw.0 < 0.0 { -self } else { self };
+		let rr = motor.rx.0 * motor.rx.0 + motor.ry.0 * motor.ry.0 + motor.rz.0 * motor.rz.0;
+		if rr == 0.0 {
+			// Pure translation
+			let scale = 1.0 / motor.rw.0;
+			return Line3 {
+				vx: WX(0.0),
+				vy: WY(0.0),
+				vz: WZ(0.0),
+				mx: motor.ux * scale,
+				my: motor.uy * scale,
+				mz: motor.uz * scale,
+			};
+		}
+		let sin = rr.sqrt();
+		let cos = motor.rw.0;
+		let angle = sin.atan2(cos);
+		let ur = motor.ux.0 * motor.rx.0 + motor.uy.0 * motor.ry.0 + motor.uz.0 * motor.rz.0;
+		// The distance along the axis:
+		let dist = cos * ur / sin - sin * motor.uw.0;
+		let parallel = dist / sin;
+		let scale = angle / sin;
+		Line3 {
+			vx: motor.rx * scale,
+			vy: motor.ry * scale,
+			vz: motor.rz * scale,
+			mx: YZ(scale * (motor.ux.0 - cos * parallel * motor.rx.0) + parallel * motor.rx.0),
+			my: ZX(scale * (motor.uy.0 - cos * parallel * motor.ry.0) + parallel * motor.ry.0),
+			mz: XY(scale * (motor.uz.0 - cos * parallel * motor.rz.0) + parallel * motor.rz.0),
+		}
+	}
+}
+
+impl Motor3 {
+	/// Interpolate between two normalized motors along the screw motion between them,
+	/// so that `t=0` gives `self` and `t=1` gives `other`.
+	pub fn lerp_log(self, other: Motor3, t: f64) -> Motor3 {
+		let delta = other.anti_geometric(self.arev()).ln();
+		let delta = Line3 {
+			vx: delta.vx * t,
+			vy: delta.vy * t,
+			vz: delta.vz * t,
+			mx: delta.mx * t,
+			my: delta.my * t,
+			mz: delta.mz * t,
+		};
+		delta.exp().anti_geometric(self)
+	}
+}
+
+impl Motor3 {
+	/// The matrix that transforms `[x, y, z, w]` like `self.anti_sandwich(v)` for a `Vec4` `v`,
+	/// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+	pub fn to_matrix(self) -> [[f64; 4]; 4] {
+		[
+			[
+				self.rw.0 * self.rw.0 + self.rx.0 * self.rx.0 - self.ry.0 * self.ry.0 - self.rz.0 * self.rz.0,
+				-2.0 * self.rw.0 * self.rz.0 + 2.0 * self.rx.0 * self.ry.0,
+				2.0 * self.rw.0 * self.ry.0 + 2.0 * self.rx.0 * self.rz.0,
+				2.0 * self.rw.0 * self.ux.0 - 2.0 * self.rx.0 * self.uw.0 + 2.0 * self.ry.0 * self.uz.0
+					- 2.0 * self.rz.0 * self.uy.0,
+			],
+			[
+				2.0 * self.rw.0 * self.rz.0 + 2.0 * self.rx.0 * self.ry.0,
+				self.rw.0 * self.rw.0 - self.rx.0 * self.rx.0 + self.ry.0 * self.ry.0 - self.rz.0 * self.rz.0,
+				-2.0 * self.rw.0 * self.rx.0 + 2.0 * self.ry.0 * self.rz.0,
+				2.0 * self.rw.0 * self.uy.0 - 2.0 * self.rx.0 * self.uz.0 - 2.0 * self.ry.0 * self.uw.0
+					+ 2.0 * self.rz.0 * self.ux.0,
+			],
+			[
+				-2.0 * self.rw.0 * self.ry.0 + 2.0 * self.rx.0 * self.rz.0,
+				2.0 * self.rw.0 * self.rx.0 + 2.0 * self.ry.0 * self.rz.0,
+				self.rw.0 * self.rw.0 - self.rx.0 * self.rx.0 - self.ry.0 * self.ry.0 + self.rz.0 * self.rz.0,
+				2.0 * self.rw.0 * self.uz.0 + 2.0 * self.rx.0 * self.uy.0
+					- 2.0 * self.ry.0 * self.ux.0
+					- 2.0 * self.rz.0 * self.uw.0,
+			],
+			[
+				0.0,
+				0.0,
+				0.0,
+				self.rw.0 * self.rw.0 + self.rx.0 * self.rx.0 + self.ry.0 * self.ry.0 + self.rz.0 * self.rz.0,
+			],
+		]
+	}
+
+	/// The first 3 rows of [`Self::to_matrix`], i.e. without the last row (which is `[0, …, 0, 1]` when normalized).
+	pub fn to_matrix_3x4(self) -> [[f64; 4]; 3] {
+		let m = self.to_matrix();
+		[m[0], m[1], m[2]]
+	}
+}
+
+impl Motor3 {
+	/// The versor that moves nothing, whose matrix is the identity matrix.
+	pub fn identity() -> Self {
+		Motor3 {
+			rx: WX(0.0),
+			ry: WY(0.0),
+			rz: WZ(0.0),
+			rw: XYZW(1.0),
+			ux: YZ(0.0),
+			uy: ZX(0.0),
+			uz: XY(0.0),
+			uw: S(0.0),
+		}
+	}
+}
+
+impl Motor3 {
+	/// Rotate by `rotor` and then translate by `translation`.
+	pub fn from_rotor_and_translation(rotor: Rotor3, translation: [f64; 3]) -> Self {
+		let translator = Translator3 {
+			x: YZ(translation[0] / 2.0),
+			y: ZX(translation[1] / 2.0),
+			z: XY(translation[2] / 2.0),
+			w: XYZW(1.0),
+		};
+		translator.anti_geometric(rotor)
+	}
+
+	/// The rotation of a normalized motor, i.e. `self` without the translation.
+	pub fn rotor(self) -> Rotor3 {
+		Rotor3 {
+			x: WX(self.rx.0),
+			y: WY(self.ry.0),
+			z: WZ(self.rz.0),
+			w: XYZW(self.rw.0),
+		}
+	}
+
+	/// Where a normalized motor moves the origin.
+	pub fn translation(self) -> [f64; 3] {
+		[
+			2.0 * self.rw.0 * self.ux.0 - 2.0 * self.rx.0 * self.uw.0 + 2.0 * self.ry.0 * self.uz.0
+				- 2.0 * self.rz.0 * self.uy.0,
+			2.0 * self.rw.0 * self.uy.0 - 2.0 * self.rx.0 * self.uz.0 - 2.0 * self.ry.0 * self.uw.0
+				+ 2.0 * self.rz.0 * self.ux.0,
+			2.0 * self.rw.0 * self.uz.0 + 2.0 * self.rx.0 * self.uy.0
+				- 2.0 * self.ry.0 * self.ux.0
+				- 2.0 * self.rz.0 * self.uw.0,
+		]
+	}
+}
+
+impl Motor3 {
+	/// The motor of a rigid transform, e.g. from [`Self::to_matrix`]:
+	/// the rotation of [`Rotor3::from_matrix`], then the translation of [`Translator3::from_matrix`].
+	pub fn from_matrix(m: [[f64; 4]; 4]) -> Self {
+		let rotor = Rotor3::from_matrix(m);
+		// Undo the rotation, whose inverse is its transpose:
+		let r = rotor.to_matrix();
+		let t = m.map(|row| r.map(|r_row| row.iter().zip(r_row).fold(0.0, |sum, (a, b)| sum + *a * b)));
+		Translator3::from_matrix(t).anti_geometric(rotor)
+	}
+}
+
+/// The matrix of a normalized motor, which transforms `[x, y, z, 1]` like `motor.anti_sandwich(point)`.
+#[cfg(feature = "glam")]
+impl From<Motor3> for glam::DMat4 {
+	fn from(m: Motor3) -> Self {
+		glam::DMat4::from_rotation_translation(glam::DQuat::from(m.rotor()), glam::DVec3::from(m.translation()))
+	}
+}
+
+/// The motor of a rigid transform. Any scaling is ignored.
+#[cfg(feature = "glam")]
+impl From<glam::DMat4> for Motor3 {
+	fn from(m: glam::DMat4) -> Self {
+		let (_scale, rotation, translation) = m.to_scale_rotation_translation();
+		Motor3::from_rotor_and_translation(Rotor3::from(rotation), translation.to_array())
+	}
+}
+
+/// The rotation and translation of a normalized motor.
+#[cfg(feature = "nalgebra")]
+impl From<Motor3> for nalgebra::Isometry3<f64> {
+	fn from(m: Motor3) -> Self {
+		nalgebra::Isometry3::from_parts(nalgebra::Translation3::from(m.translation()), m.rotor().into())
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Isometry3<f64>> for Motor3 {
+	fn from(m: nalgebra::Isometry3<f64>) -> Self {
+		Motor3::from_rotor_and_translation(Rotor3::from(m.rotation), m.translation.vector.into())
+	}
+}
+
+/// The matrix of a normalized motor, which transforms `[x, y, z, 1]` like `motor.anti_sandwich(point)`.
+#[cfg(feature = "nalgebra")]
+impl From<Motor3> for nalgebra::Matrix4<f64> {
+	fn from(m: Motor3) -> Self {
+		nalgebra::Isometry3::from(m).to_homogeneous()
+	}
+}
+
+/// The motor of a rigid transform, i.e. a rotation and a translation.
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Matrix4<f64>> for Motor3 {
+	fn from(m: nalgebra::Matrix4<f64>) -> Self {
+		let rotation = nalgebra::Rotation3::from_matrix_unchecked(m.fixed_view::<3, 3>(0, 0).into_owned());
+		let rotor = Rotor3::from(nalgebra::UnitQuaternion::from_rotation_matrix(&rotation));
+		Motor3::from_rotor_and_translation(rotor, [m[(0, 3)], m[(1, 3)], m[(2, 3)]])
+	}
+}
+
+/// The matrix of a normalized motor, which transforms `[x, y, z, 1]` like `motor.anti_sandwich(point)`.
+#[cfg(feature = "cgmath")]
+impl From<Motor3> for cgmath::Matrix4<f64> {
+	fn from(m: Motor3) -> Self {
+		cgmath::Matrix4::from(cgmath::Decomposed {
+			scale: 1.0,
+			rot: cgmath::Quaternion::from(m.rotor()),
+			disp: cgmath::Vector3::from(m.translation()),
+		})
+	}
+}
+
+/// The motor of a rigid transform, i.e. a rotation and a translation.
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Matrix4<f64>> for Motor3 {
+	fn from(m: cgmath::Matrix4<f64>) -> Self {
+		let rotation = cgmath::Matrix3::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate());
+		let rotor = Rotor3::from(cgmath::Quaternion::from(rotation));
+		Motor3::from_rotor_and_translation(rotor, [m.w.x, m.w.y, m.w.z])
+	}
+}
+
 // ---------------------------------------------------------------------
 // Motor3 OP Vec3:
 
-// Omitted: Motor3 geometric Vec3 = self.rw * rhs.x + self.rw * rhs.y + self.rw * rhs.z + self.rx * rhs.x + self.rx * rhs.y + self.rx * rhs.z + self.ry * rhs.x + self.ry * rhs.y + self.ry * rhs.z + self.rz * rhs.x + self.rz * rhs.y + self.rz * rhs.z + self.uw * rhs.x + self.uw * rhs.y + self.uw * rhs.z + self.ux * rhs.x + self.ux * rhs.y + self.ux * rhs.z + self.uy * rhs.x + self.uy * rhs.y + self.uy * rhs.z + self.uz * rhs.x + self.uz * rhs.y + self.uz * rhs.z  (unnamed type)
-// Omitted: Motor3 anti_geometric Vec3 = self.rw !* rhs.x + self.rw !* rhs.y + self.rw !* rhs.z + self.rx !* rhs.x + self.rx !* rhs.y + self.rx !* rhs.z + self.ry !* rhs.x + self.ry !* rhs.y + self.ry !* rhs.z + self.rz !* rhs.x + self.rz !* rhs.y + self.rz !* rhs.z + self.ux !* rhs.x + self.ux !* rhs.y + self.ux !* rhs.z + self.uy !* rhs.x + self.uy !* rhs.y + self.uy !* rhs.z + self.uz !* rhs.x + self.uz !* rhs.y + self.uz !* rhs.z  (unnamed type)
-// Omitted: Motor3 dot Vec3 = self.rw | rhs.x + self.rw | rhs.y + self.rw | rhs.z + self.rx | rhs.x + self.ry | rhs.y + self.rz | rhs.z + self.uw | rhs.x + self.uw | rhs.y + self.uw | rhs.z + self.ux | rhs.y + self.ux | rhs.z + self.uy | rhs.x + self.uy | rhs.z + self.uz | rhs.x + self.uz | rhs.y  (unnamed type)
-// Omitted: Motor3 wedge Vec3 = self.rx ^ rhs.y + self.rx ^ rhs.z + self.ry ^ rhs.x + self.ry ^ rhs.z + self.rz ^ rhs.x + self.rz ^ rhs.y + self.uw ^ rhs.x + self.uw ^ rhs.y + self.uw ^ rhs.z + self.ux ^ rhs.x + self.uy ^ rhs.y + self.uz ^ rhs.z  (unnamed type)
-// Omitted: Motor3 anti_wedge Vec3 = self.rw & rhs.x + self.rw & rhs.y + self.rw & rhs.z + self.ux & rhs.x + self.uy & rhs.y + self.uz & rhs.z  (unnamed type)
+// Motor3.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Motor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.uw.0 * rhs.x.0) - X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.uw.0 * rhs.y.0) + Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : Z(self.uw.0 * rhs.z.0) - Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0) + YZW(self.ry.0 * rhs.z.0) - YZW(self.rz.0 * rhs.y.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0) - ZXW(self.rx.0 * rhs.z.0) + ZXW(self.rz.0 * rhs.x.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0) + XYW(self.rx.0 * rhs.y.0) - XYW(self.ry.0 * rhs.x.0),
+		//     xyz : XYZ(self.ux.0 * rhs.x.0) + XYZ(self.uy.0 * rhs.y.0) + XYZ(self.uz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uw.geometric(rhs.x) + self.uy.geometric(rhs.z) + self.uz.geometric(rhs.y),
+			y: self.uw.geometric(rhs.y) + self.ux.geometric(rhs.z) + self.uz.geometric(rhs.x),
+			z: self.uw.geometric(rhs.z) + self.ux.geometric(rhs.y) + self.uy.geometric(rhs.x),
+			w: self.rx.geometric(rhs.x) + self.ry.geometric(rhs.y) + self.rz.geometric(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.geometric(rhs.x) + self.ry.geometric(rhs.z) + self.rz.geometric(rhs.y),
+			zxw: self.rw.geometric(rhs.y) + self.rx.geometric(rhs.z) + self.rz.geometric(rhs.x),
+			xyw: self.rw.geometric(rhs.z) + self.rx.geometric(rhs.y) + self.ry.geometric(rhs.x),
+			xyz: self.ux.geometric(rhs.x) + self.uy.geometric(rhs.y) + self.uz.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Motor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
-// ---------------------------------------------------------------------
-// Motor3 OP Vec4:
+// Motor3.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Motor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.rw.0 * rhs.x.0) + X(self.ry.0 * rhs.z.0) - X(self.rz.0 * rhs.y.0),
+		//     y   : Y(self.rw.0 * rhs.y.0) - Y(self.rx.0 * rhs.z.0) + Y(self.rz.0 * rhs.x.0),
+		//     z   : Z(self.rw.0 * rhs.z.0) + Z(self.rx.0 * rhs.y.0) - Z(self.ry.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.rx.0 * rhs.x.0) + XYZ(self.ry.0 * rhs.y.0) + XYZ(self.rz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.rw.anti_geometric(rhs.x) + self.ry.anti_geometric(rhs.z) + self.rz.anti_geometric(rhs.y),
+			y: self.rw.anti_geometric(rhs.y) + self.rx.anti_geometric(rhs.z) + self.rz.anti_geometric(rhs.x),
+			z: self.rw.anti_geometric(rhs.z) + self.rx.anti_geometric(rhs.y) + self.ry.anti_geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.rx.anti_geometric(rhs.x) + self.ry.anti_geometric(rhs.y) + self.rz.anti_geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
 
-// Omitted: Motor3 geometric Vec4 = self.rw * rhs.x + self.rw * rhs.y + self.rw * rhs.z + self.rx * rhs.x + self.rx * rhs.y + self.rx * rhs.z + self.ry * rhs.x + self.ry * rhs.y + self.ry * rhs.z + self.rz * rhs.x + self.rz * rhs.y + self.rz * rhs.z + self.uw * rhs.w + self.uw * rhs.x + self.uw * rhs.y + self.uw * rhs.z + self.ux * rhs.x + self.ux * rhs.y + self.ux * rhs.z + self.uy * rhs.x + self.uy * rhs.y + self.uy * rhs.z + self.uz * rhs.x + self.uz * rhs.y + self.uz * rhs.z  (unnamed type)
-// Omitted: Motor3 anti_geometric Vec4 = self.rw !* rhs.w + self.rw !* rhs.x + self.rw !* rhs.y + self.rw !* rhs.z + self.rx !* rhs.w + self.rx !* rhs.x + self.rx !* rhs.y + self.rx !* rhs.z + self.ry !* rhs.w + self.ry !* rhs.x + self.ry !* rhs.y + self.ry !* rhs.z + self.rz !* rhs.w + self.rz !* rhs.x + self.rz !* rhs.y + self.rz !* rhs.z + self.uw !* rhs.w + self.ux !* rhs.w + self.ux !* rhs.x + self.ux !* rhs.y + self.ux !* rhs.z + self.uy !* rhs.w + self.uy !* rhs.x + self.uy !* rhs.y + self.uy !* rhs.z + self.uz !* rhs.w + self.uz !* rhs.x + self.uz !* rhs.y + self.uz !* rhs.z  (unnamed type)
-// Omitted: Motor3 dot Vec4 = self.rw | rhs.x + self.rw | rhs.y + self.rw | rhs.z + self.rx | rhs.x + self.ry | rhs.y + self.rz | rhs.z + self.uw | rhs.w + self.uw | rhs.x + self.uw | rhs.y + self.uw | rhs.z + self.ux | rhs.y + self.ux | rhs.z + self.uy | rhs.x + self.uy | rhs.z + self.uz | rhs.x + self.uz | rhs.y  (unnamed type)
-// Omitted: Motor3 wedge Vec4 = self.rx ^ rhs.y + self.rx ^ rhs.z + self.ry ^ rhs.x + self.ry ^ rhs.z + self.rz ^ rhs.x + self.rz ^ rhs.y + self.uw ^ rhs.w + self.uw ^ rhs.x + self.uw ^ rhs.y + self.uw ^ rhs.z + self.ux ^ rhs.x + self.uy ^ rhs.y + self.uz ^ rhs.z  (unnamed type)
-// Omitted: Motor3 anti_wedge Vec4 = self.rw & rhs.w + self.rw & rhs.x + self.rw & rhs.y + self.rw & rhs.z + self.ux & rhs.x + self.uy & rhs.y + self.uz & rhs.z  (unnamed type)
+// Motor3.dot(Vec3) -> Multivector
+impl Dot<Vec3> for Motor3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.uw.0 * rhs.x.0) - X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.uw.0 * rhs.y.0) + Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : Z(self.uw.0 * rhs.z.0) - Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uw.dot(rhs.x) + self.uy.dot(rhs.z) + self.uz.dot(rhs.y),
+			y: self.uw.dot(rhs.y) + self.ux.dot(rhs.z) + self.uz.dot(rhs.x),
+			z: self.uw.dot(rhs.z) + self.ux.dot(rhs.y) + self.uy.dot(rhs.x),
+			w: self.rx.dot(rhs.x) + self.ry.dot(rhs.y) + self.rz.dot(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.dot(rhs.x),
+			zxw: self.rw.dot(rhs.y),
+			xyw: self.rw.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
 
-// ---------------------------------------------------------------------
-// Motor3 OP Moment3:
+impl std::ops::BitOr<Vec3> for Motor3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor3.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Motor3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.uw.0 * rhs.x.0),
+		//     y   : Y(self.uw.0 * rhs.y.0),
+		//     z   : Z(self.uw.0 * rhs.z.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.ry.0 * rhs.z.0) - YZW(self.rz.0 * rhs.y.0),
+		//     zxw : -ZXW(self.rx.0 * rhs.z.0) + ZXW(self.rz.0 * rhs.x.0),
+		//     xyw : XYW(self.rx.0 * rhs.y.0) - XYW(self.ry.0 * rhs.x.0),
+		//     xyz : XYZ(self.ux.0 * rhs.x.0) + XYZ(self.uy.0 * rhs.y.0) + XYZ(self.uz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uw.wedge(rhs.x),
+			y: self.uw.wedge(rhs.y),
+			z: self.uw.wedge(rhs.z),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.ry.wedge(rhs.z) + self.rz.wedge(rhs.y),
+			zxw: self.rx.wedge(rhs.z) + self.rz.wedge(rhs.x),
+			xyw: self.rx.wedge(rhs.y) + self.ry.wedge(rhs.x),
+			xyz: self.ux.wedge(rhs.x) + self.uy.wedge(rhs.y) + self.uz.wedge(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
 
-// Omitted: Motor3 geometric Moment3 = self.rw * rhs.mx + self.rw * rhs.my + self.rw * rhs.mz + self.rx * rhs.mx + self.rx * rhs.my + self.rx * rhs.mz + self.ry * rhs.mx + self.ry * rhs.my + self.ry * rhs.mz + self.rz * rhs.mx + self.rz * rhs.my + self.rz * rhs.mz + self.uw * rhs.mx + self.uw * rhs.my + self.uw * rhs.mz + self.ux * rhs.mx + self.ux * rhs.my + self.ux * rhs.mz + self.uy * rhs.mx + self.uy * rhs.my + self.uy * rhs.mz + self.uz * rhs.mx + self.uz * rhs.my + self.uz * rhs.mz  (unnamed type)
-// Omitted: Motor3 anti_geometric Moment3 = self.rw !* rhs.mx + self.rw !* rhs.my + self.rw !* rhs.mz + self.rx !* rhs.mx + self.rx !* rhs.my + self.rx !* rhs.mz + self.ry !* rhs.mx + self.ry !* rhs.my + self.ry !* rhs.mz + self.rz !* rhs.mx + self.rz !* rhs.my + self.rz !* rhs.mz + self.ux !* rhs.mx + self.ux !* rhs.my + self.ux !* rhs.mz + self.uy !* rhs.mx + self.uy !* rhs.my + self.uy !* rhs.mz + self.uz !* rhs.mx + self.uz !* rhs.my + self.uz !* rhs.mz  (unnamed type)
-// Omitted: Motor3 dot Moment3 = self.rw | rhs.mx + self.rw | rhs.my + self.rw | rhs.mz + self.uw | rhs.mx + self.uw | rhs.my + self.uw | rhs.mz + self.ux | rhs.mx + self.uy | rhs.my + self.uz | rhs.mz  (unnamed type)
-// Omitted: Motor3 wedge Moment3 = self.rx ^ rhs.mx + self.ry ^ rhs.my + self.rz ^ rhs.mz + self.uw ^ rhs.mx + self.uw ^ rhs.my + self.uw ^ rhs.mz  (unnamed type)
-// Omitted: Motor3 anti_wedge Moment3 = self.rw & rhs.mx + self.rw & rhs.my + self.rw & rhs.mz + self.rx & rhs.mx + self.ry & rhs.my + self.rz & rhs.mz + self.ux & rhs.my + self.ux & rhs.mz + self.uy & rhs.mx + self.uy & rhs.mz + self.uz & rhs.mx + self.uz & rhs.my  (unnamed type)
+impl std::ops::BitXor<Vec3> for Motor3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
-// ---------------------------------------------------------------------
-// Motor3 OP Line3:
+// Motor3.anti_wedge(Vec3) -> Vec3
+impl AntiWedge<Vec3> for Motor3 {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.rw.0 * rhs.x.0),
+		//     y: Y(self.rw.0 * rhs.y.0),
+		//     z: Z(self.rw.0 * rhs.z.0),
+		// }
+		Vec3 {
+			x: self.rw.anti_wedge(rhs.x),
+			y: self.rw.anti_wedge(rhs.y),
+			z: self.rw.anti_wedge(rhs.z),
+		}
+	}
+}
 
-// Omitted: Motor3 geometric Line3 = self.rw * rhs.mx + self.rw * rhs.my + self.rw * rhs.mz + self.rx * rhs.mx + self.rx * rhs.my + self.rx * rhs.mz + self.ry * rhs.mx + self.ry * rhs.my + self.ry * rhs.mz + self.rz * rhs.mx + self.rz * rhs.my + self.rz * rhs.mz + self.uw * rhs.mx + self.uw * rhs.my + self.uw * rhs.mz + self.uw * rhs.vx + self.uw * rhs.vy + self.uw * rhs.vz + self.ux * rhs.mx + self.ux * rhs.my + self.ux * rhs.mz + self.uy * rhs.mx + self.uy * rhs.my + self.uy * rhs.mz + self.uz * rhs.mx + self.uz * rhs.my + self.uz * rhs.mz  (unnamed type)
-// Omitted: Motor3 anti_geometric Line3 = self.rw !* rhs.mx + self.rw !* rhs.my + self.rw !* rhs.mz + self.rw !* rhs.vx + self.rw !* rhs.vy + self.rw !* rhs.vz + self.rx !* rhs.mx + self.rx !* rhs.my + self.rx !* rhs.mz + self.rx !* rhs.vx + self.rx !* rhs.vy + self.rx !* rhs.vz + self.ry !* rhs.mx + self.ry !* rhs.my + self.ry !* rhs.mz + self.ry !* rhs.vx + self.ry !* rhs.vy + self.ry !* rhs.vz + self.rz !* rhs.mx + self.rz !* rhs.my + self.rz !* rhs.mz + self.rz !* rhs.vx + self.rz !* rhs.vy + self.rz !* rhs.vz + self.uw !* rhs.vx + self.uw !* rhs.vy + self.uw !* rhs.vz + self.ux !* rhs.mx + self.ux !* rhs.my + self.ux !* rhs.mz + self.ux !* rhs.vx + self.ux !* rhs.vy + self.ux !* rhs.vz + self.uy !* rhs.mx + self.uy !* rhs.my + self.uy !* rhs.mz + self.uy !* rhs.vx + self.uy !* rhs.vy + self.uy !* rhs.vz + self.uz !* rhs.mx + self.uz !* rhs.my + self.uz !* rhs.mz + self.uz !* rhs.vx + self.uz !* rhs.vy + self.uz !* rhs.vz  (unnamed type)
-// Omitted: Motor3 dot Line3 = self.rw | rhs.mx + self.rw | rhs.my + self.rw | rhs.mz + self.uw | rhs.mx + self.uw | rhs.my + self.uw | rhs.mz + self.uw | rhs.vx + self.uw | rhs.vy + self.uw | rhs.vz + self.ux | rhs.mx + self.uy | rhs.my + self.uz | rhs.mz  (unnamed type)
-// Omitted: Motor3 wedge Line3 = self.rx ^ rhs.mx + self.ry ^ rhs.my + self.rz ^ rhs.mz + self.uw ^ rhs.mx + self.uw ^ rhs.my + self.uw ^ rhs.mz + self.uw ^ rhs.vx + self.uw ^ rhs.vy + self.uw ^ rhs.vz  (unnamed type)
-// Omitted: Motor3 anti_wedge Line3 = self.rw & rhs.mx + self.rw & rhs.my + self.rw & rhs.mz + self.rw & rhs.vx + self.rw & rhs.vy + self.rw & rhs.vz + self.rx & rhs.mx + self.ry & rhs.my + self.rz & rhs.mz + self.ux & rhs.my + self.ux & rhs.mz + self.ux & rhs.vx + self.uy & rhs.mx + self.uy & rhs.mz + self.uy & rhs.vy + self.uz & rhs.mx + self.uz & rhs.my + self.uz & rhs.vz  (unnamed type)
+impl std::ops::BitAnd<Vec3> for Motor3 {
+	type Output = Vec3;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-// ---------------------------------------------------------------------
-// Motor3 OP Plane:
+// Motor3.left_contraction(Vec3) -> Vec3
+impl LeftContraction<Vec3> for Motor3 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.uw.0 * rhs.x.0),
+		//     y: Y(self.uw.0 * rhs.y.0),
+		//     z: Z(self.uw.0 * rhs.z.0),
+		// }
+		Vec3 {
+			x: self.uw.left_contraction(rhs.x),
+			y: self.uw.left_contraction(rhs.y),
+			z: self.uw.left_contraction(rhs.z),
+		}
+	}
+}
 
-// Omitted: Motor3 geometric Plane = self.rw * rhs.d + self.rx * rhs.d + self.ry * rhs.d + self.rz * rhs.d + self.uw * rhs.d + self.uw * rhs.nx + self.uw * rhs.ny + self.uw * rhs.nz + self.ux * rhs.d + self.uy * rhs.d + self.uz * rhs.d  (unnamed type)
-// Omitted: Motor3 anti_geometric Plane = self.rw !* rhs.d + self.rw !* rhs.nx + self.rw !* rhs.ny + self.rw !* rhs.nz + self.rx !* rhs.d + self.rx !* rhs.nx + self.rx !* rhs.ny + self.rx !* rhs.nz + self.ry !* rhs.d + self.ry !* rhs.nx + self.ry !* rhs.ny + self.ry !* rhs.nz + self.rz !* rhs.d + self.rz !* rhs.nx + self.rz !* rhs.ny + self.rz !* rhs.nz + self.uw !* rhs.nx + self.uw !* rhs.ny + self.uw !* rhs.nz + self.ux !* rhs.d + self.ux !* rhs.nx + self.ux !* rhs.ny + self.ux !* rhs.nz + self.uy !* rhs.d + self.uy !* rhs.nx + self.uy !* rhs.ny + self.uy !* rhs.nz + self.uz !* rhs.d + self.uz !* rhs.nx + self.uz !* rhs.ny + self.uz !* rhs.nz  (unnamed type)
-// Omitted: Motor3 dot Plane = self.rw | rhs.d + self.uw | rhs.d + self.uw | rhs.nx + self.uw | rhs.ny + self.uw | rhs.nz  (unnamed type)
+// Motor3.right_contraction(Vec3) -> Multivector
+impl RightContraction<Vec3> for Motor3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : -Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uy.right_contraction(rhs.z) + self.uz.right_contraction(rhs.y),
+			y: self.ux.right_contraction(rhs.z) + self.uz.right_contraction(rhs.x),
+			z: self.ux.right_contraction(rhs.y) + self.uy.right_contraction(rhs.x),
+			w: self.rx.right_contraction(rhs.x) + self.ry.right_contraction(rhs.y) + self.rz.right_contraction(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.right_contraction(rhs.x),
+			zxw: self.rw.right_contraction(rhs.y),
+			xyw: self.rw.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
 
-// Motor3.wedge(Plane) -> Plane
-impl Wedge<Plane> for Motor3 {
-	type Output = Plane;
-	fn wedge(self, rhs: Plane) -> Self::Output {
-		// Plane {
-		//     nx: YZW(self.uw.0 * rhs.nx.0),
-		//     ny: ZXW(self.uw.0 * rhs.ny.0),
-		//     nz: XYW(self.uw.0 * rhs.nz.0),
-		//     d : XYZ(self.uw.0 * rhs.d.0),
+impl ScalarProduct<Vec3> for Motor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Motor3.commutator(Vec3) -> Multivector
+impl Commutator<Vec3> for Motor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : -Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uy.commutator(rhs.z) + self.uz.commutator(rhs.y),
+			y: self.ux.commutator(rhs.z) + self.uz.commutator(rhs.x),
+			z: self.ux.commutator(rhs.y) + self.uy.commutator(rhs.x),
+			w: self.rx.commutator(rhs.x) + self.ry.commutator(rhs.y) + self.rz.commutator(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.commutator(rhs.x),
+			zxw: self.rw.commutator(rhs.y),
+			xyw: self.rw.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Motor3.anti_dot(Vec3) -> Multivector
+impl AntiDot<Vec3> for Motor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.rw.0 * rhs.x.0),
+		//     y   : Y(self.rw.0 * rhs.y.0),
+		//     z   : Z(self.rw.0 * rhs.z.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.rx.0 * rhs.x.0) + XYZ(self.ry.0 * rhs.y.0) + XYZ(self.rz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.rw.anti_dot(rhs.x),
+			y: self.rw.anti_dot(rhs.y),
+			z: self.rw.anti_dot(rhs.z),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.rx.anti_dot(rhs.x) + self.ry.anti_dot(rhs.y) + self.rz.anti_dot(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Motor3 OP Vec4:
+
+// Motor3.geometric(Vec4) -> Multivector
+impl Geometric<Vec4> for Motor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.uw.0 * rhs.x.0) - X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.uw.0 * rhs.y.0) + Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : Z(self.uw.0 * rhs.z.0) - Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0) + W(self.uw.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0) + YZW(self.ry.0 * rhs.z.0) - YZW(self.rz.0 * rhs.y.0) + YZW(self.ux.0 * rhs.w.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0) - ZXW(self.rx.0 * rhs.z.0) + ZXW(self.rz.0 * rhs.x.0) + ZXW(self.uy.0 * rhs.w.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0) + XYW(self.rx.0 * rhs.y.0) - XYW(self.ry.0 * rhs.x.0) + XYW(self.uz.0 * rhs.w.0),
+		//     xyz : XYZ(self.ux.0 * rhs.x.0) + XYZ(self.uy.0 * rhs.y.0) + XYZ(self.uz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uw.geometric(rhs.x) + self.uy.geometric(rhs.z) + self.uz.geometric(rhs.y),
+			y: self.uw.geometric(rhs.y) + self.ux.geometric(rhs.z) + self.uz.geometric(rhs.x),
+			z: self.uw.geometric(rhs.z) + self.ux.geometric(rhs.y) + self.uy.geometric(rhs.x),
+			w: self.rx.geometric(rhs.x)
+				+ self.ry.geometric(rhs.y)
+				+ self.rz.geometric(rhs.z)
+				+ self.uw.geometric(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.geometric(rhs.x)
+				+ self.ry.geometric(rhs.z)
+				+ self.rz.geometric(rhs.y)
+				+ self.ux.geometric(rhs.w),
+			zxw: self.rw.geometric(rhs.y)
+				+ self.rx.geometric(rhs.z)
+				+ self.rz.geometric(rhs.x)
+				+ self.uy.geometric(rhs.w),
+			xyw: self.rw.geometric(rhs.z)
+				+ self.rx.geometric(rhs.y)
+				+ self.ry.geometric(rhs.x)
+				+ self.uz.geometric(rhs.w),
+			xyz: self.ux.geometric(rhs.x) + self.uy.geometric(rhs.y) + self.uz.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec4> for Motor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor3.anti_geometric(Vec4) -> Multivector
+impl AntiGeometric<Vec4> for Motor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.rw.0 * rhs.x.0) + X(self.ry.0 * rhs.z.0) - X(self.rz.0 * rhs.y.0) + X(self.ux.0 * rhs.w.0),
+		//     y   : Y(self.rw.0 * rhs.y.0) - Y(self.rx.0 * rhs.z.0) + Y(self.rz.0 * rhs.x.0) + Y(self.uy.0 * rhs.w.0),
+		//     z   : Z(self.rw.0 * rhs.z.0) + Z(self.rx.0 * rhs.y.0) - Z(self.ry.0 * rhs.x.0) + Z(self.uz.0 * rhs.w.0),
+		//     w   : W(self.rw.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.rx.0 * rhs.w.0),
+		//     zxw : ZXW(self.ry.0 * rhs.w.0),
+		//     xyw : XYW(self.rz.0 * rhs.w.0),
+		//     xyz : XYZ(self.rx.0 * rhs.x.0) + XYZ(self.ry.0 * rhs.y.0) + XYZ(self.rz.0 * rhs.z.0) - XYZ(self.uw.0 * rhs.w.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.rw.anti_geometric(rhs.x)
+				+ self.ry.anti_geometric(rhs.z)
+				+ self.rz.anti_geometric(rhs.y)
+				+ self.ux.anti_geometric(rhs.w),
+			y: self.rw.anti_geometric(rhs.y)
+				+ self.rx.anti_geometric(rhs.z)
+				+ self.rz.anti_geometric(rhs.x)
+				+ self.uy.anti_geometric(rhs.w),
+			z: self.rw.anti_geometric(rhs.z)
+				+ self.rx.anti_geometric(rhs.y)
+				+ self.ry.anti_geometric(rhs.x)
+				+ self.uz.anti_geometric(rhs.w),
+			w: self.rw.anti_geometric(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rx.anti_geometric(rhs.w),
+			zxw: self.ry.anti_geometric(rhs.w),
+			xyw: self.rz.anti_geometric(rhs.w),
+			xyz: self.rx.anti_geometric(rhs.x)
+				+ self.ry.anti_geometric(rhs.y)
+				+ self.rz.anti_geometric(rhs.z)
+				+ self.uw.anti_geometric(rhs.w),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Motor3.dot(Vec4) -> Multivector
+impl Dot<Vec4> for Motor3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.uw.0 * rhs.x.0) - X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.uw.0 * rhs.y.0) + Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : Z(self.uw.0 * rhs.z.0) - Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0) + W(self.uw.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uw.dot(rhs.x) + self.uy.dot(rhs.z) + self.uz.dot(rhs.y),
+			y: self.uw.dot(rhs.y) + self.ux.dot(rhs.z) + self.uz.dot(rhs.x),
+			z: self.uw.dot(rhs.z) + self.ux.dot(rhs.y) + self.uy.dot(rhs.x),
+			w: self.rx.dot(rhs.x) + self.ry.dot(rhs.y) + self.rz.dot(rhs.z) + self.uw.dot(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.dot(rhs.x),
+			zxw: self.rw.dot(rhs.y),
+			xyw: self.rw.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec4> for Motor3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor3.wedge(Vec4) -> Multivector
+impl Wedge<Vec4> for Motor3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.uw.0 * rhs.x.0),
+		//     y   : Y(self.uw.0 * rhs.y.0),
+		//     z   : Z(self.uw.0 * rhs.z.0),
+		//     w   : W(self.uw.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.ry.0 * rhs.z.0) - YZW(self.rz.0 * rhs.y.0) + YZW(self.ux.0 * rhs.w.0),
+		//     zxw : -ZXW(self.rx.0 * rhs.z.0) + ZXW(self.rz.0 * rhs.x.0) + ZXW(self.uy.0 * rhs.w.0),
+		//     xyw : XYW(self.rx.0 * rhs.y.0) - XYW(self.ry.0 * rhs.x.0) + XYW(self.uz.0 * rhs.w.0),
+		//     xyz : XYZ(self.ux.0 * rhs.x.0) + XYZ(self.uy.0 * rhs.y.0) + XYZ(self.uz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uw.wedge(rhs.x),
+			y: self.uw.wedge(rhs.y),
+			z: self.uw.wedge(rhs.z),
+			w: self.uw.wedge(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.ry.wedge(rhs.z) + self.rz.wedge(rhs.y) + self.ux.wedge(rhs.w),
+			zxw: self.rx.wedge(rhs.z) + self.rz.wedge(rhs.x) + self.uy.wedge(rhs.w),
+			xyw: self.rx.wedge(rhs.y) + self.ry.wedge(rhs.x) + self.uz.wedge(rhs.w),
+			xyz: self.ux.wedge(rhs.x) + self.uy.wedge(rhs.y) + self.uz.wedge(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec4> for Motor3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor3.anti_wedge(Vec4) -> Vec4
+impl AntiWedge<Vec4> for Motor3 {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Vec4) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.rw.0 * rhs.x.0),
+		//     y: Y(self.rw.0 * rhs.y.0),
+		//     z: Z(self.rw.0 * rhs.z.0),
+		//     w: W(self.rw.0 * rhs.w.0),
+		// }
+		Vec4 {
+			x: self.rw.anti_wedge(rhs.x),
+			y: self.rw.anti_wedge(rhs.y),
+			z: self.rw.anti_wedge(rhs.z),
+			w: self.rw.anti_wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec4> for Motor3 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor3.left_contraction(Vec4) -> Vec4
+impl LeftContraction<Vec4> for Motor3 {
+	type Output = Vec4;
+	fn left_contraction(self, rhs: Vec4) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.uw.0 * rhs.x.0),
+		//     y: Y(self.uw.0 * rhs.y.0),
+		//     z: Z(self.uw.0 * rhs.z.0),
+		//     w: W(self.uw.0 * rhs.w.0),
+		// }
+		Vec4 {
+			x: self.uw.left_contraction(rhs.x),
+			y: self.uw.left_contraction(rhs.y),
+			z: self.uw.left_contraction(rhs.z),
+			w: self.uw.left_contraction(rhs.w),
+		}
+	}
+}
+
+// Motor3.right_contraction(Vec4) -> Multivector
+impl RightContraction<Vec4> for Motor3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : -Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uy.right_contraction(rhs.z) + self.uz.right_contraction(rhs.y),
+			y: self.ux.right_contraction(rhs.z) + self.uz.right_contraction(rhs.x),
+			z: self.ux.right_contraction(rhs.y) + self.uy.right_contraction(rhs.x),
+			w: self.rx.right_contraction(rhs.x) + self.ry.right_contraction(rhs.y) + self.rz.right_contraction(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.right_contraction(rhs.x),
+			zxw: self.rw.right_contraction(rhs.y),
+			xyw: self.rw.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Vec4> for Motor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Motor3.commutator(Vec4) -> Multivector
+impl Commutator<Vec4> for Motor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : -Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uy.commutator(rhs.z) + self.uz.commutator(rhs.y),
+			y: self.ux.commutator(rhs.z) + self.uz.commutator(rhs.x),
+			z: self.ux.commutator(rhs.y) + self.uy.commutator(rhs.x),
+			w: self.rx.commutator(rhs.x) + self.ry.commutator(rhs.y) + self.rz.commutator(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.commutator(rhs.x),
+			zxw: self.rw.commutator(rhs.y),
+			xyw: self.rw.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Motor3.anti_dot(Vec4) -> Multivector
+impl AntiDot<Vec4> for Motor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.rw.0 * rhs.x.0),
+		//     y   : Y(self.rw.0 * rhs.y.0),
+		//     z   : Z(self.rw.0 * rhs.z.0),
+		//     w   : W(self.rw.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.rx.0 * rhs.w.0),
+		//     zxw : ZXW(self.ry.0 * rhs.w.0),
+		//     xyw : XYW(self.rz.0 * rhs.w.0),
+		//     xyz : XYZ(self.rx.0 * rhs.x.0) + XYZ(self.ry.0 * rhs.y.0) + XYZ(self.rz.0 * rhs.z.0) - XYZ(self.uw.0 * rhs.w.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.rw.anti_dot(rhs.x),
+			y: self.rw.anti_dot(rhs.y),
+			z: self.rw.anti_dot(rhs.z),
+			w: self.rw.anti_dot(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rx.anti_dot(rhs.w),
+			zxw: self.ry.anti_dot(rhs.w),
+			xyw: self.rz.anti_dot(rhs.w),
+			xyz: self.rx.anti_dot(rhs.x) + self.ry.anti_dot(rhs.y) + self.rz.anti_dot(rhs.z) + self.uw.anti_dot(rhs.w),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Motor3 OP Point3:
+
+// Motor3.geometric(Point3) -> Multivector
+impl Geometric<Point3> for Motor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.uw.0 * rhs.x.0) - X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.uw.0 * rhs.y.0) + Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : Z(self.uw.0 * rhs.z.0) - Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0) + W(self.uw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0) + YZW(self.ry.0 * rhs.z.0) - YZW(self.rz.0 * rhs.y.0) + YZW(self.ux.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0) - ZXW(self.rx.0 * rhs.z.0) + ZXW(self.rz.0 * rhs.x.0) + ZXW(self.uy.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0) + XYW(self.rx.0 * rhs.y.0) - XYW(self.ry.0 * rhs.x.0) + XYW(self.uz.0),
+		//     xyz : XYZ(self.ux.0 * rhs.x.0) + XYZ(self.uy.0 * rhs.y.0) + XYZ(self.uz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uw.geometric(rhs.x) + self.uy.geometric(rhs.z) + self.uz.geometric(rhs.y),
+			y: self.uw.geometric(rhs.y) + self.ux.geometric(rhs.z) + self.uz.geometric(rhs.x),
+			z: self.uw.geometric(rhs.z) + self.ux.geometric(rhs.y) + self.uy.geometric(rhs.x),
+			w: self.rx.geometric(rhs.x)
+				+ self.ry.geometric(rhs.y)
+				+ self.rz.geometric(rhs.z)
+				+ self.uw.geometric(W(1.0)),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.geometric(rhs.x)
+				+ self.ry.geometric(rhs.z)
+				+ self.rz.geometric(rhs.y)
+				+ self.ux.geometric(W(1.0)),
+			zxw: self.rw.geometric(rhs.y)
+				+ self.rx.geometric(rhs.z)
+				+ self.rz.geometric(rhs.x)
+				+ self.uy.geometric(W(1.0)),
+			xyw: self.rw.geometric(rhs.z)
+				+ self.rx.geometric(rhs.y)
+				+ self.ry.geometric(rhs.x)
+				+ self.uz.geometric(W(1.0)),
+			xyz: self.ux.geometric(rhs.x) + self.uy.geometric(rhs.y) + self.uz.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point3> for Motor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Point3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor3.anti_geometric(Point3) -> Multivector
+impl AntiGeometric<Point3> for Motor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.rw.0 * rhs.x.0) + X(self.ry.0 * rhs.z.0) - X(self.rz.0 * rhs.y.0) + X(self.ux.0),
+		//     y   : Y(self.rw.0 * rhs.y.0) - Y(self.rx.0 * rhs.z.0) + Y(self.rz.0 * rhs.x.0) + Y(self.uy.0),
+		//     z   : Z(self.rw.0 * rhs.z.0) + Z(self.rx.0 * rhs.y.0) - Z(self.ry.0 * rhs.x.0) + Z(self.uz.0),
+		//     w   : W(self.rw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.rx.0),
+		//     zxw : ZXW(self.ry.0),
+		//     xyw : XYW(self.rz.0),
+		//     xyz : XYZ(self.rx.0 * rhs.x.0) + XYZ(self.ry.0 * rhs.y.0) + XYZ(self.rz.0 * rhs.z.0) - XYZ(self.uw.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.rw.anti_geometric(rhs.x)
+				+ self.ry.anti_geometric(rhs.z)
+				+ self.rz.anti_geometric(rhs.y)
+				+ self.ux.anti_geometric(W(1.0)),
+			y: self.rw.anti_geometric(rhs.y)
+				+ self.rx.anti_geometric(rhs.z)
+				+ self.rz.anti_geometric(rhs.x)
+				+ self.uy.anti_geometric(W(1.0)),
+			z: self.rw.anti_geometric(rhs.z)
+				+ self.rx.anti_geometric(rhs.y)
+				+ self.ry.anti_geometric(rhs.x)
+				+ self.uz.anti_geometric(W(1.0)),
+			w: self.rw.anti_geometric(W(1.0)),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rx.anti_geometric(W(1.0)),
+			zxw: self.ry.anti_geometric(W(1.0)),
+			xyw: self.rz.anti_geometric(W(1.0)),
+			xyz: self.rx.anti_geometric(rhs.x)
+				+ self.ry.anti_geometric(rhs.y)
+				+ self.rz.anti_geometric(rhs.z)
+				+ self.uw.anti_geometric(W(1.0)),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Motor3.dot(Point3) -> Multivector
+impl Dot<Point3> for Motor3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.uw.0 * rhs.x.0) - X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.uw.0 * rhs.y.0) + Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : Z(self.uw.0 * rhs.z.0) - Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0) + W(self.uw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uw.dot(rhs.x) + self.uy.dot(rhs.z) + self.uz.dot(rhs.y),
+			y: self.uw.dot(rhs.y) + self.ux.dot(rhs.z) + self.uz.dot(rhs.x),
+			z: self.uw.dot(rhs.z) + self.ux.dot(rhs.y) + self.uy.dot(rhs.x),
+			w: self.rx.dot(rhs.x) + self.ry.dot(rhs.y) + self.rz.dot(rhs.z) + self.uw.dot(W(1.0)),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.dot(rhs.x),
+			zxw: self.rw.dot(rhs.y),
+			xyw: self.rw.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point3> for Motor3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Point3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor3.wedge(Point3) -> Multivector
+impl Wedge<Point3> for Motor3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.uw.0 * rhs.x.0),
+		//     y   : Y(self.uw.0 * rhs.y.0),
+		//     z   : Z(self.uw.0 * rhs.z.0),
+		//     w   : W(self.uw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.ry.0 * rhs.z.0) - YZW(self.rz.0 * rhs.y.0) + YZW(self.ux.0),
+		//     zxw : -ZXW(self.rx.0 * rhs.z.0) + ZXW(self.rz.0 * rhs.x.0) + ZXW(self.uy.0),
+		//     xyw : XYW(self.rx.0 * rhs.y.0) - XYW(self.ry.0 * rhs.x.0) + XYW(self.uz.0),
+		//     xyz : XYZ(self.ux.0 * rhs.x.0) + XYZ(self.uy.0 * rhs.y.0) + XYZ(self.uz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uw.wedge(rhs.x),
+			y: self.uw.wedge(rhs.y),
+			z: self.uw.wedge(rhs.z),
+			w: self.uw.wedge(W(1.0)),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.ry.wedge(rhs.z) + self.rz.wedge(rhs.y) + self.ux.wedge(W(1.0)),
+			zxw: self.rx.wedge(rhs.z) + self.rz.wedge(rhs.x) + self.uy.wedge(W(1.0)),
+			xyw: self.rx.wedge(rhs.y) + self.ry.wedge(rhs.x) + self.uz.wedge(W(1.0)),
+			xyz: self.ux.wedge(rhs.x) + self.uy.wedge(rhs.y) + self.uz.wedge(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point3> for Motor3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Point3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor3.anti_wedge(Point3) -> Vec4
+impl AntiWedge<Point3> for Motor3 {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Point3) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.rw.0 * rhs.x.0),
+		//     y: Y(self.rw.0 * rhs.y.0),
+		//     z: Z(self.rw.0 * rhs.z.0),
+		//     w: W(self.rw.0),
+		// }
+		Vec4 {
+			x: self.rw.anti_wedge(rhs.x),
+			y: self.rw.anti_wedge(rhs.y),
+			z: self.rw.anti_wedge(rhs.z),
+			w: self.rw.anti_wedge(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Point3> for Motor3 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Point3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor3.left_contraction(Point3) -> Vec4
+impl LeftContraction<Point3> for Motor3 {
+	type Output = Vec4;
+	fn left_contraction(self, rhs: Point3) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.uw.0 * rhs.x.0),
+		//     y: Y(self.uw.0 * rhs.y.0),
+		//     z: Z(self.uw.0 * rhs.z.0),
+		//     w: W(self.uw.0),
+		// }
+		Vec4 {
+			x: self.uw.left_contraction(rhs.x),
+			y: self.uw.left_contraction(rhs.y),
+			z: self.uw.left_contraction(rhs.z),
+			w: self.uw.left_contraction(W(1.0)),
+		}
+	}
+}
+
+// Motor3.right_contraction(Point3) -> Multivector
+impl RightContraction<Point3> for Motor3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : -Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uy.right_contraction(rhs.z) + self.uz.right_contraction(rhs.y),
+			y: self.ux.right_contraction(rhs.z) + self.uz.right_contraction(rhs.x),
+			z: self.ux.right_contraction(rhs.y) + self.uy.right_contraction(rhs.x),
+			w: self.rx.right_contraction(rhs.x) + self.ry.right_contraction(rhs.y) + self.rz.right_contraction(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.right_contraction(rhs.x),
+			zxw: self.rw.right_contraction(rhs.y),
+			xyw: self.rw.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Point3> for Motor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Motor3.commutator(Point3) -> Multivector
+impl Commutator<Point3> for Motor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : -Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uy.commutator(rhs.z) + self.uz.commutator(rhs.y),
+			y: self.ux.commutator(rhs.z) + self.uz.commutator(rhs.x),
+			z: self.ux.commutator(rhs.y) + self.uy.commutator(rhs.x),
+			w: self.rx.commutator(rhs.x) + self.ry.commutator(rhs.y) + self.rz.commutator(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.commutator(rhs.x),
+			zxw: self.rw.commutator(rhs.y),
+			xyw: self.rw.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Motor3.anti_dot(Point3) -> Multivector
+impl AntiDot<Point3> for Motor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.rw.0 * rhs.x.0),
+		//     y   : Y(self.rw.0 * rhs.y.0),
+		//     z   : Z(self.rw.0 * rhs.z.0),
+		//     w   : W(self.rw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.rx.0),
+		//     zxw : ZXW(self.ry.0),
+		//     xyw : XYW(self.rz.0),
+		//     xyz : XYZ(self.rx.0 * rhs.x.0) + XYZ(self.ry.0 * rhs.y.0) + XYZ(self.rz.0 * rhs.z.0) - XYZ(self.uw.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.rw.anti_dot(rhs.x),
+			y: self.rw.anti_dot(rhs.y),
+			z: self.rw.anti_dot(rhs.z),
+			w: self.rw.anti_dot(W(1.0)),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rx.anti_dot(W(1.0)),
+			zxw: self.ry.anti_dot(W(1.0)),
+			xyw: self.rz.anti_dot(W(1.0)),
+			xyz: self.rx.anti_dot(rhs.x) + self.ry.anti_dot(rhs.y) + self.rz.anti_dot(rhs.z) + self.uw.anti_dot(W(1.0)),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Motor3 OP Moment3:
+
+// Motor3.geometric(Moment3) -> Motor3
+impl Geometric<Moment3> for Motor3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.mx.0) - WX(self.ry.0 * rhs.mz.0) + WX(self.rz.0 * rhs.my.0),
+		//     ry: WY(self.rw.0 * rhs.my.0) + WY(self.rx.0 * rhs.mz.0) - WY(self.rz.0 * rhs.mx.0),
+		//     rz: WZ(self.rw.0 * rhs.mz.0) - WZ(self.rx.0 * rhs.my.0) + WZ(self.ry.0 * rhs.mx.0),
+		//     rw: -XYZW(self.rx.0 * rhs.mx.0) - XYZW(self.ry.0 * rhs.my.0) - XYZW(self.rz.0 * rhs.mz.0),
+		//     ux: YZ(self.uw.0 * rhs.mx.0) - YZ(self.uy.0 * rhs.mz.0) + YZ(self.uz.0 * rhs.my.0),
+		//     uy: ZX(self.uw.0 * rhs.my.0) + ZX(self.ux.0 * rhs.mz.0) - ZX(self.uz.0 * rhs.mx.0),
+		//     uz: XY(self.uw.0 * rhs.mz.0) - XY(self.ux.0 * rhs.my.0) + XY(self.uy.0 * rhs.mx.0),
+		//     uw: -S(self.ux.0 * rhs.mx.0) - S(self.uy.0 * rhs.my.0) - S(self.uz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.rw.geometric(rhs.mx) + self.ry.geometric(rhs.mz) + self.rz.geometric(rhs.my),
+			ry: self.rw.geometric(rhs.my) + self.rx.geometric(rhs.mz) + self.rz.geometric(rhs.mx),
+			rz: self.rw.geometric(rhs.mz) + self.rx.geometric(rhs.my) + self.ry.geometric(rhs.mx),
+			rw: self.rx.geometric(rhs.mx) + self.ry.geometric(rhs.my) + self.rz.geometric(rhs.mz),
+			ux: self.uw.geometric(rhs.mx) + self.uy.geometric(rhs.mz) + self.uz.geometric(rhs.my),
+			uy: self.uw.geometric(rhs.my) + self.ux.geometric(rhs.mz) + self.uz.geometric(rhs.mx),
+			uz: self.uw.geometric(rhs.mz) + self.ux.geometric(rhs.my) + self.uy.geometric(rhs.mx),
+			uw: self.ux.geometric(rhs.mx) + self.uy.geometric(rhs.my) + self.uz.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Moment3> for Motor3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Moment3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor3.anti_geometric(Moment3) -> Motor3
+impl AntiGeometric<Moment3> for Motor3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.rw.0 * rhs.mx.0) + YZ(self.ry.0 * rhs.mz.0) - YZ(self.rz.0 * rhs.my.0),
+		//     uy: ZX(self.rw.0 * rhs.my.0) - ZX(self.rx.0 * rhs.mz.0) + ZX(self.rz.0 * rhs.mx.0),
+		//     uz: XY(self.rw.0 * rhs.mz.0) + XY(self.rx.0 * rhs.my.0) - XY(self.ry.0 * rhs.mx.0),
+		//     uw: -S(self.rx.0 * rhs.mx.0) - S(self.ry.0 * rhs.my.0) - S(self.rz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.rw.anti_geometric(rhs.mx) + self.ry.anti_geometric(rhs.mz) + self.rz.anti_geometric(rhs.my),
+			uy: self.rw.anti_geometric(rhs.my) + self.rx.anti_geometric(rhs.mz) + self.rz.anti_geometric(rhs.mx),
+			uz: self.rw.anti_geometric(rhs.mz) + self.rx.anti_geometric(rhs.my) + self.ry.anti_geometric(rhs.mx),
+			uw: self.rx.anti_geometric(rhs.mx) + self.ry.anti_geometric(rhs.my) + self.rz.anti_geometric(rhs.mz),
+		}
+	}
+}
+
+// Motor3.dot(Moment3) -> Motor3
+impl Dot<Moment3> for Motor3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.mx.0),
+		//     ry: WY(self.rw.0 * rhs.my.0),
+		//     rz: WZ(self.rw.0 * rhs.mz.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.uw.0 * rhs.mx.0),
+		//     uy: ZX(self.uw.0 * rhs.my.0),
+		//     uz: XY(self.uw.0 * rhs.mz.0),
+		//     uw: -S(self.ux.0 * rhs.mx.0) - S(self.uy.0 * rhs.my.0) - S(self.uz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.rw.dot(rhs.mx),
+			ry: self.rw.dot(rhs.my),
+			rz: self.rw.dot(rhs.mz),
+			rw: Default::default(),
+			ux: self.uw.dot(rhs.mx),
+			uy: self.uw.dot(rhs.my),
+			uz: self.uw.dot(rhs.mz),
+			uw: self.ux.dot(rhs.mx) + self.uy.dot(rhs.my) + self.uz.dot(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Moment3> for Motor3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Moment3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor3.wedge(Moment3) -> Translator3
+impl Wedge<Moment3> for Motor3 {
+	type Output = Translator3;
+	fn wedge(self, rhs: Moment3) -> Self::Output {
+		// Translator3 {
+		//     x: YZ(self.uw.0 * rhs.mx.0),
+		//     y: ZX(self.uw.0 * rhs.my.0),
+		//     z: XY(self.uw.0 * rhs.mz.0),
+		//     w: -XYZW(self.rx.0 * rhs.mx.0) - XYZW(self.ry.0 * rhs.my.0) - XYZW(self.rz.0 * rhs.mz.0),
+		// }
+		Translator3 {
+			x: self.uw.wedge(rhs.mx),
+			y: self.uw.wedge(rhs.my),
+			z: self.uw.wedge(rhs.mz),
+			w: self.rx.wedge(rhs.mx) + self.ry.wedge(rhs.my) + self.rz.wedge(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Moment3> for Motor3 {
+	type Output = Translator3;
+	fn bitxor(self, rhs: Moment3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor3.anti_wedge(Moment3) -> Motor3
+impl AntiWedge<Moment3> for Motor3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.rw.0 * rhs.mx.0),
+		//     uy: ZX(self.rw.0 * rhs.my.0),
+		//     uz: XY(self.rw.0 * rhs.mz.0),
+		//     uw: -S(self.rx.0 * rhs.mx.0) - S(self.ry.0 * rhs.my.0) - S(self.rz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.rw.anti_wedge(rhs.mx),
+			uy: self.rw.anti_wedge(rhs.my),
+			uz: self.rw.anti_wedge(rhs.mz),
+			uw: self.rx.anti_wedge(rhs.mx) + self.ry.anti_wedge(rhs.my) + self.rz.anti_wedge(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Moment3> for Motor3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Moment3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor3.left_contraction(Moment3) -> Motor3
+impl LeftContraction<Moment3> for Motor3 {
+	type Output = Motor3;
+	fn left_contraction(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.uw.0 * rhs.mx.0),
+		//     uy: ZX(self.uw.0 * rhs.my.0),
+		//     uz: XY(self.uw.0 * rhs.mz.0),
+		//     uw: -S(self.ux.0 * rhs.mx.0) - S(self.uy.0 * rhs.my.0) - S(self.uz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.uw.left_contraction(rhs.mx),
+			uy: self.uw.left_contraction(rhs.my),
+			uz: self.uw.left_contraction(rhs.mz),
+			uw: self.ux.left_contraction(rhs.mx) + self.uy.left_contraction(rhs.my) + self.uz.left_contraction(rhs.mz),
+		}
+	}
+}
+
+// Motor3.right_contraction(Moment3) -> Motor3
+impl RightContraction<Moment3> for Motor3 {
+	type Output = Motor3;
+	fn right_contraction(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.mx.0),
+		//     ry: WY(self.rw.0 * rhs.my.0),
+		//     rz: WZ(self.rw.0 * rhs.mz.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.ux.0 * rhs.mx.0) - S(self.uy.0 * rhs.my.0) - S(self.uz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.rw.right_contraction(rhs.mx),
+			ry: self.rw.right_contraction(rhs.my),
+			rz: self.rw.right_contraction(rhs.mz),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.ux.right_contraction(rhs.mx)
+				+ self.uy.right_contraction(rhs.my)
+				+ self.uz.right_contraction(rhs.mz),
+		}
+	}
+}
+
+// Motor3.scalar_product(Moment3) -> S
+impl ScalarProduct<Moment3> for Motor3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Moment3) -> Self::Output {
+		// -S(self.ux.0 * rhs.mx.0) - S(self.uy.0 * rhs.my.0) - S(self.uz.0 * rhs.mz.0)
+		self.ux.scalar_product(rhs.mx) + self.uy.scalar_product(rhs.my) + self.uz.scalar_product(rhs.mz)
+	}
+}
+
+// Motor3.commutator(Moment3) -> Line3
+impl Commutator<Moment3> for Motor3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Moment3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.ry.0 * rhs.mz.0) + WX(self.rz.0 * rhs.my.0),
+		//     vy: WY(self.rx.0 * rhs.mz.0) - WY(self.rz.0 * rhs.mx.0),
+		//     vz: -WZ(self.rx.0 * rhs.my.0) + WZ(self.ry.0 * rhs.mx.0),
+		//     mx: -YZ(self.uy.0 * rhs.mz.0) + YZ(self.uz.0 * rhs.my.0),
+		//     my: ZX(self.ux.0 * rhs.mz.0) - ZX(self.uz.0 * rhs.mx.0),
+		//     mz: -XY(self.ux.0 * rhs.my.0) + XY(self.uy.0 * rhs.mx.0),
+		// }
+		Line3 {
+			vx: self.ry.commutator(rhs.mz) + self.rz.commutator(rhs.my),
+			vy: self.rx.commutator(rhs.mz) + self.rz.commutator(rhs.mx),
+			vz: self.rx.commutator(rhs.my) + self.ry.commutator(rhs.mx),
+			mx: self.uy.commutator(rhs.mz) + self.uz.commutator(rhs.my),
+			my: self.ux.commutator(rhs.mz) + self.uz.commutator(rhs.mx),
+			mz: self.ux.commutator(rhs.my) + self.uy.commutator(rhs.mx),
+		}
+	}
+}
+
+// Motor3.anti_dot(Moment3) -> Moment3
+impl AntiDot<Moment3> for Motor3 {
+	type Output = Moment3;
+	fn anti_dot(self, rhs: Moment3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.rw.0 * rhs.mx.0),
+		//     my: ZX(self.rw.0 * rhs.my.0),
+		//     mz: XY(self.rw.0 * rhs.mz.0),
+		// }
+		Moment3 {
+			mx: self.rw.anti_dot(rhs.mx),
+			my: self.rw.anti_dot(rhs.my),
+			mz: self.rw.anti_dot(rhs.mz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Motor3 OP Line3:
+
+// Motor3.geometric(Line3) -> Motor3
+impl Geometric<Line3> for Motor3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.mx.0) - WX(self.ry.0 * rhs.mz.0) + WX(self.rz.0 * rhs.my.0) + WX(self.uw.0 * rhs.vx.0) - WX(self.uy.0 * rhs.vz.0) + WX(self.uz.0 * rhs.vy.0),
+		//     ry: WY(self.rw.0 * rhs.my.0) + WY(self.rx.0 * rhs.mz.0) - WY(self.rz.0 * rhs.mx.0) + WY(self.uw.0 * rhs.vy.0) + WY(self.ux.0 * rhs.vz.0) - WY(self.uz.0 * rhs.vx.0),
+		//     rz: WZ(self.rw.0 * rhs.mz.0) - WZ(self.rx.0 * rhs.my.0) + WZ(self.ry.0 * rhs.mx.0) + WZ(self.uw.0 * rhs.vz.0) - WZ(self.ux.0 * rhs.vy.0) + WZ(self.uy.0 * rhs.vx.0),
+		//     rw: -XYZW(self.rx.0 * rhs.mx.0) - XYZW(self.ry.0 * rhs.my.0) - XYZW(self.rz.0 * rhs.mz.0) - XYZW(self.ux.0 * rhs.vx.0) - XYZW(self.uy.0 * rhs.vy.0) - XYZW(self.uz.0 * rhs.vz.0),
+		//     ux: YZ(self.uw.0 * rhs.mx.0) - YZ(self.uy.0 * rhs.mz.0) + YZ(self.uz.0 * rhs.my.0),
+		//     uy: ZX(self.uw.0 * rhs.my.0) + ZX(self.ux.0 * rhs.mz.0) - ZX(self.uz.0 * rhs.mx.0),
+		//     uz: XY(self.uw.0 * rhs.mz.0) - XY(self.ux.0 * rhs.my.0) + XY(self.uy.0 * rhs.mx.0),
+		//     uw: -S(self.ux.0 * rhs.mx.0) - S(self.uy.0 * rhs.my.0) - S(self.uz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.rw.geometric(rhs.mx)
+				+ self.ry.geometric(rhs.mz)
+				+ self.rz.geometric(rhs.my)
+				+ self.uw.geometric(rhs.vx)
+				+ self.uy.geometric(rhs.vz)
+				+ self.uz.geometric(rhs.vy),
+			ry: self.rw.geometric(rhs.my)
+				+ self.rx.geometric(rhs.mz)
+				+ self.rz.geometric(rhs.mx)
+				+ self.uw.geometric(rhs.vy)
+				+ self.ux.geometric(rhs.vz)
+				+ self.uz.geometric(rhs.vx),
+			rz: self.rw.geometric(rhs.mz)
+				+ self.rx.geometric(rhs.my)
+				+ self.ry.geometric(rhs.mx)
+				+ self.uw.geometric(rhs.vz)
+				+ self.ux.geometric(rhs.vy)
+				+ self.uy.geometric(rhs.vx),
+			rw: self.rx.geometric(rhs.mx)
+				+ self.ry.geometric(rhs.my)
+				+ self.rz.geometric(rhs.mz)
+				+ self.ux.geometric(rhs.vx)
+				+ self.uy.geometric(rhs.vy)
+				+ self.uz.geometric(rhs.vz),
+			ux: self.uw.geometric(rhs.mx) + self.uy.geometric(rhs.mz) + self.uz.geometric(rhs.my),
+			uy: self.uw.geometric(rhs.my) + self.ux.geometric(rhs.mz) + self.uz.geometric(rhs.mx),
+			uz: self.uw.geometric(rhs.mz) + self.ux.geometric(rhs.my) + self.uy.geometric(rhs.mx),
+			uw: self.ux.geometric(rhs.mx) + self.uy.geometric(rhs.my) + self.uz.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Line3> for Motor3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Line3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor3.anti_geometric(Line3) -> Motor3
+impl AntiGeometric<Line3> for Motor3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.vx.0) + WX(self.ry.0 * rhs.vz.0) - WX(self.rz.0 * rhs.vy.0),
+		//     ry: WY(self.rw.0 * rhs.vy.0) - WY(self.rx.0 * rhs.vz.0) + WY(self.rz.0 * rhs.vx.0),
+		//     rz: WZ(self.rw.0 * rhs.vz.0) + WZ(self.rx.0 * rhs.vy.0) - WZ(self.ry.0 * rhs.vx.0),
+		//     rw: -XYZW(self.rx.0 * rhs.vx.0) - XYZW(self.ry.0 * rhs.vy.0) - XYZW(self.rz.0 * rhs.vz.0),
+		//     ux: YZ(self.rw.0 * rhs.mx.0) + YZ(self.ry.0 * rhs.mz.0) - YZ(self.rz.0 * rhs.my.0) + YZ(self.uw.0 * rhs.vx.0) + YZ(self.uy.0 * rhs.vz.0) - YZ(self.uz.0 * rhs.vy.0),
+		//     uy: ZX(self.rw.0 * rhs.my.0) - ZX(self.rx.0 * rhs.mz.0) + ZX(self.rz.0 * rhs.mx.0) + ZX(self.uw.0 * rhs.vy.0) - ZX(self.ux.0 * rhs.vz.0) + ZX(self.uz.0 * rhs.vx.0),
+		//     uz: XY(self.rw.0 * rhs.mz.0) + XY(self.rx.0 * rhs.my.0) - XY(self.ry.0 * rhs.mx.0) + XY(self.uw.0 * rhs.vz.0) + XY(self.ux.0 * rhs.vy.0) - XY(self.uy.0 * rhs.vx.0),
+		//     uw: -S(self.rx.0 * rhs.mx.0) - S(self.ry.0 * rhs.my.0) - S(self.rz.0 * rhs.mz.0) - S(self.ux.0 * rhs.vx.0) - S(self.uy.0 * rhs.vy.0) - S(self.uz.0 * rhs.vz.0),
+		// }
+		Motor3 {
+			rx: self.rw.anti_geometric(rhs.vx) + self.ry.anti_geometric(rhs.vz) + self.rz.anti_geometric(rhs.vy),
+			ry: self.rw.anti_geometric(rhs.vy) + self.rx.anti_geometric(rhs.vz) + self.rz.anti_geometric(rhs.vx),
+			rz: self.rw.anti_geometric(rhs.vz) + self.rx.anti_geometric(rhs.vy) + self.ry.anti_geometric(rhs.vx),
+			rw: self.rx.anti_geometric(rhs.vx) + self.ry.anti_geometric(rhs.vy) + self.rz.anti_geometric(rhs.vz),
+			ux: self.rw.anti_geometric(rhs.mx)
+				+ self.ry.anti_geometric(rhs.mz)
+				+ self.rz.anti_geometric(rhs.my)
+				+ self.uw.anti_geometric(rhs.vx)
+				+ self.uy.anti_geometric(rhs.vz)
+				+ self.uz.anti_geometric(rhs.vy),
+			uy: self.rw.anti_geometric(rhs.my)
+				+ self.rx.anti_geometric(rhs.mz)
+				+ self.rz.anti_geometric(rhs.mx)
+				+ self.uw.anti_geometric(rhs.vy)
+				+ self.ux.anti_geometric(rhs.vz)
+				+ self.uz.anti_geometric(rhs.vx),
+			uz: self.rw.anti_geometric(rhs.mz)
+				+ self.rx.anti_geometric(rhs.my)
+				+ self.ry.anti_geometric(rhs.mx)
+				+ self.uw.anti_geometric(rhs.vz)
+				+ self.ux.anti_geometric(rhs.vy)
+				+ self.uy.anti_geometric(rhs.vx),
+			uw: self.rx.anti_geometric(rhs.mx)
+				+ self.ry.anti_geometric(rhs.my)
+				+ self.rz.anti_geometric(rhs.mz)
+				+ self.ux.anti_geometric(rhs.vx)
+				+ self.uy.anti_geometric(rhs.vy)
+				+ self.uz.anti_geometric(rhs.vz),
+		}
+	}
+}
+
+// Motor3.dot(Line3) -> Motor3
+impl Dot<Line3> for Motor3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.mx.0) + WX(self.uw.0 * rhs.vx.0),
+		//     ry: WY(self.rw.0 * rhs.my.0) + WY(self.uw.0 * rhs.vy.0),
+		//     rz: WZ(self.rw.0 * rhs.mz.0) + WZ(self.uw.0 * rhs.vz.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.uw.0 * rhs.mx.0),
+		//     uy: ZX(self.uw.0 * rhs.my.0),
+		//     uz: XY(self.uw.0 * rhs.mz.0),
+		//     uw: -S(self.ux.0 * rhs.mx.0) - S(self.uy.0 * rhs.my.0) - S(self.uz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.rw.dot(rhs.mx) + self.uw.dot(rhs.vx),
+			ry: self.rw.dot(rhs.my) + self.uw.dot(rhs.vy),
+			rz: self.rw.dot(rhs.mz) + self.uw.dot(rhs.vz),
+			rw: Default::default(),
+			ux: self.uw.dot(rhs.mx),
+			uy: self.uw.dot(rhs.my),
+			uz: self.uw.dot(rhs.mz),
+			uw: self.ux.dot(rhs.mx) + self.uy.dot(rhs.my) + self.uz.dot(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line3> for Motor3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Line3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor3.wedge(Line3) -> Motor3
+impl Wedge<Line3> for Motor3 {
+	type Output = Motor3;
+	fn wedge(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.uw.0 * rhs.vx.0),
+		//     ry: WY(self.uw.0 * rhs.vy.0),
+		//     rz: WZ(self.uw.0 * rhs.vz.0),
+		//     rw: -XYZW(self.rx.0 * rhs.mx.0) - XYZW(self.ry.0 * rhs.my.0) - XYZW(self.rz.0 * rhs.mz.0) - XYZW(self.ux.0 * rhs.vx.0) - XYZW(self.uy.0 * rhs.vy.0) - XYZW(self.uz.0 * rhs.vz.0),
+		//     ux: YZ(self.uw.0 * rhs.mx.0),
+		//     uy: ZX(self.uw.0 * rhs.my.0),
+		//     uz: XY(self.uw.0 * rhs.mz.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.uw.wedge(rhs.vx),
+			ry: self.uw.wedge(rhs.vy),
+			rz: self.uw.wedge(rhs.vz),
+			rw: self.rx.wedge(rhs.mx)
+				+ self.ry.wedge(rhs.my)
+				+ self.rz.wedge(rhs.mz)
+				+ self.ux.wedge(rhs.vx)
+				+ self.uy.wedge(rhs.vy)
+				+ self.uz.wedge(rhs.vz),
+			ux: self.uw.wedge(rhs.mx),
+			uy: self.uw.wedge(rhs.my),
+			uz: self.uw.wedge(rhs.mz),
+			uw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line3> for Motor3 {
+	type Output = Motor3;
+	fn bitxor(self, rhs: Line3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor3.anti_wedge(Line3) -> Motor3
+impl AntiWedge<Line3> for Motor3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.vx.0),
+		//     ry: WY(self.rw.0 * rhs.vy.0),
+		//     rz: WZ(self.rw.0 * rhs.vz.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.rw.0 * rhs.mx.0),
+		//     uy: ZX(self.rw.0 * rhs.my.0),
+		//     uz: XY(self.rw.0 * rhs.mz.0),
+		//     uw: -S(self.rx.0 * rhs.mx.0) - S(self.ry.0 * rhs.my.0) - S(self.rz.0 * rhs.mz.0) - S(self.ux.0 * rhs.vx.0) - S(self.uy.0 * rhs.vy.0) - S(self.uz.0 * rhs.vz.0),
+		// }
+		Motor3 {
+			rx: self.rw.anti_wedge(rhs.vx),
+			ry: self.rw.anti_wedge(rhs.vy),
+			rz: self.rw.anti_wedge(rhs.vz),
+			rw: Default::default(),
+			ux: self.rw.anti_wedge(rhs.mx),
+			uy: self.rw.anti_wedge(rhs.my),
+			uz: self.rw.anti_wedge(rhs.mz),
+			uw: self.rx.anti_wedge(rhs.mx)
+				+ self.ry.anti_wedge(rhs.my)
+				+ self.rz.anti_wedge(rhs.mz)
+				+ self.ux.anti_wedge(rhs.vx)
+				+ self.uy.anti_wedge(rhs.vy)
+				+ self.uz.anti_wedge(rhs.vz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line3> for Motor3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Line3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor3.left_contraction(Line3) -> Motor3
+impl LeftContraction<Line3> for Motor3 {
+	type Output = Motor3;
+	fn left_contraction(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.uw.0 * rhs.vx.0),
+		//     ry: WY(self.uw.0 * rhs.vy.0),
+		//     rz: WZ(self.uw.0 * rhs.vz.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.uw.0 * rhs.mx.0),
+		//     uy: ZX(self.uw.0 * rhs.my.0),
+		//     uz: XY(self.uw.0 * rhs.mz.0),
+		//     uw: -S(self.ux.0 * rhs.mx.0) - S(self.uy.0 * rhs.my.0) - S(self.uz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.uw.left_contraction(rhs.vx),
+			ry: self.uw.left_contraction(rhs.vy),
+			rz: self.uw.left_contraction(rhs.vz),
+			rw: Default::default(),
+			ux: self.uw.left_contraction(rhs.mx),
+			uy: self.uw.left_contraction(rhs.my),
+			uz: self.uw.left_contraction(rhs.mz),
+			uw: self.ux.left_contraction(rhs.mx) + self.uy.left_contraction(rhs.my) + self.uz.left_contraction(rhs.mz),
+		}
+	}
+}
+
+// Motor3.right_contraction(Line3) -> Motor3
+impl RightContraction<Line3> for Motor3 {
+	type Output = Motor3;
+	fn right_contraction(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.mx.0),
+		//     ry: WY(self.rw.0 * rhs.my.0),
+		//     rz: WZ(self.rw.0 * rhs.mz.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.ux.0 * rhs.mx.0) - S(self.uy.0 * rhs.my.0) - S(self.uz.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.rw.right_contraction(rhs.mx),
+			ry: self.rw.right_contraction(rhs.my),
+			rz: self.rw.right_contraction(rhs.mz),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.ux.right_contraction(rhs.mx)
+				+ self.uy.right_contraction(rhs.my)
+				+ self.uz.right_contraction(rhs.mz),
+		}
+	}
+}
+
+// Motor3.scalar_product(Line3) -> S
+impl ScalarProduct<Line3> for Motor3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Line3) -> Self::Output {
+		// -S(self.ux.0 * rhs.mx.0) - S(self.uy.0 * rhs.my.0) - S(self.uz.0 * rhs.mz.0)
+		self.ux.scalar_product(rhs.mx) + self.uy.scalar_product(rhs.my) + self.uz.scalar_product(rhs.mz)
+	}
+}
+
+// Motor3.commutator(Line3) -> Line3
+impl Commutator<Line3> for Motor3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Line3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.ry.0 * rhs.mz.0) + WX(self.rz.0 * rhs.my.0) - WX(self.uy.0 * rhs.vz.0) + WX(self.uz.0 * rhs.vy.0),
+		//     vy: WY(self.rx.0 * rhs.mz.0) - WY(self.rz.0 * rhs.mx.0) + WY(self.ux.0 * rhs.vz.0) - WY(self.uz.0 * rhs.vx.0),
+		//     vz: -WZ(self.rx.0 * rhs.my.0) + WZ(self.ry.0 * rhs.mx.0) - WZ(self.ux.0 * rhs.vy.0) + WZ(self.uy.0 * rhs.vx.0),
+		//     mx: -YZ(self.uy.0 * rhs.mz.0) + YZ(self.uz.0 * rhs.my.0),
+		//     my: ZX(self.ux.0 * rhs.mz.0) - ZX(self.uz.0 * rhs.mx.0),
+		//     mz: -XY(self.ux.0 * rhs.my.0) + XY(self.uy.0 * rhs.mx.0),
+		// }
+		Line3 {
+			vx: self.ry.commutator(rhs.mz)
+				+ self.rz.commutator(rhs.my)
+				+ self.uy.commutator(rhs.vz)
+				+ self.uz.commutator(rhs.vy),
+			vy: self.rx.commutator(rhs.mz)
+				+ self.rz.commutator(rhs.mx)
+				+ self.ux.commutator(rhs.vz)
+				+ self.uz.commutator(rhs.vx),
+			vz: self.rx.commutator(rhs.my)
+				+ self.ry.commutator(rhs.mx)
+				+ self.ux.commutator(rhs.vy)
+				+ self.uy.commutator(rhs.vx),
+			mx: self.uy.commutator(rhs.mz) + self.uz.commutator(rhs.my),
+			my: self.ux.commutator(rhs.mz) + self.uz.commutator(rhs.mx),
+			mz: self.ux.commutator(rhs.my) + self.uy.commutator(rhs.mx),
+		}
+	}
+}
+
+// Motor3.anti_dot(Line3) -> Motor3
+impl AntiDot<Line3> for Motor3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.vx.0),
+		//     ry: WY(self.rw.0 * rhs.vy.0),
+		//     rz: WZ(self.rw.0 * rhs.vz.0),
+		//     rw: -XYZW(self.rx.0 * rhs.vx.0) - XYZW(self.ry.0 * rhs.vy.0) - XYZW(self.rz.0 * rhs.vz.0),
+		//     ux: YZ(self.rw.0 * rhs.mx.0) + YZ(self.uw.0 * rhs.vx.0),
+		//     uy: ZX(self.rw.0 * rhs.my.0) + ZX(self.uw.0 * rhs.vy.0),
+		//     uz: XY(self.rw.0 * rhs.mz.0) + XY(self.uw.0 * rhs.vz.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.rw.anti_dot(rhs.vx),
+			ry: self.rw.anti_dot(rhs.vy),
+			rz: self.rw.anti_dot(rhs.vz),
+			rw: self.rx.anti_dot(rhs.vx) + self.ry.anti_dot(rhs.vy) + self.rz.anti_dot(rhs.vz),
+			ux: self.rw.anti_dot(rhs.mx) + self.uw.anti_dot(rhs.vx),
+			uy: self.rw.anti_dot(rhs.my) + self.uw.anti_dot(rhs.vy),
+			uz: self.rw.anti_dot(rhs.mz) + self.uw.anti_dot(rhs.vz),
+			uw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Motor3 OP Plane:
+
+// Motor3.geometric(Plane) -> Multivector
+impl Geometric<Plane> for Motor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.ux.0 * rhs.d.0),
+		//     y   : -Y(self.uy.0 * rhs.d.0),
+		//     z   : -Z(self.uz.0 * rhs.d.0),
+		//     w   : W(self.rw.0 * rhs.d.0) - W(self.ux.0 * rhs.nx.0) - W(self.uy.0 * rhs.ny.0) - W(self.uz.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.rx.0 * rhs.d.0) + YZW(self.uw.0 * rhs.nx.0) - YZW(self.uy.0 * rhs.nz.0) + YZW(self.uz.0 * rhs.ny.0),
+		//     zxw : ZXW(self.ry.0 * rhs.d.0) + ZXW(self.uw.0 * rhs.ny.0) + ZXW(self.ux.0 * rhs.nz.0) - ZXW(self.uz.0 * rhs.nx.0),
+		//     xyw : XYW(self.rz.0 * rhs.d.0) + XYW(self.uw.0 * rhs.nz.0) - XYW(self.ux.0 * rhs.ny.0) + XYW(self.uy.0 * rhs.nx.0),
+		//     xyz : XYZ(self.uw.0 * rhs.d.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.ux.geometric(rhs.d),
+			y: self.uy.geometric(rhs.d),
+			z: self.uz.geometric(rhs.d),
+			w: self.rw.geometric(rhs.d)
+				+ self.ux.geometric(rhs.nx)
+				+ self.uy.geometric(rhs.ny)
+				+ self.uz.geometric(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rx.geometric(rhs.d)
+				+ self.uw.geometric(rhs.nx)
+				+ self.uy.geometric(rhs.nz)
+				+ self.uz.geometric(rhs.ny),
+			zxw: self.ry.geometric(rhs.d)
+				+ self.uw.geometric(rhs.ny)
+				+ self.ux.geometric(rhs.nz)
+				+ self.uz.geometric(rhs.nx),
+			xyw: self.rz.geometric(rhs.d)
+				+ self.uw.geometric(rhs.nz)
+				+ self.ux.geometric(rhs.ny)
+				+ self.uy.geometric(rhs.nx),
+			xyz: self.uw.geometric(rhs.d),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Motor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor3.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Motor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.rx.0 * rhs.d.0) + X(self.uw.0 * rhs.nx.0) + X(self.uy.0 * rhs.nz.0) - X(self.uz.0 * rhs.ny.0),
+		//     y   : -Y(self.ry.0 * rhs.d.0) + Y(self.uw.0 * rhs.ny.0) - Y(self.ux.0 * rhs.nz.0) + Y(self.uz.0 * rhs.nx.0),
+		//     z   : -Z(self.rz.0 * rhs.d.0) + Z(self.uw.0 * rhs.nz.0) + Z(self.ux.0 * rhs.ny.0) - Z(self.uy.0 * rhs.nx.0),
+		//     w   : -W(self.rx.0 * rhs.nx.0) - W(self.ry.0 * rhs.ny.0) - W(self.rz.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.rw.0 * rhs.nx.0) + YZW(self.ry.0 * rhs.nz.0) - YZW(self.rz.0 * rhs.ny.0),
+		//     zxw : ZXW(self.rw.0 * rhs.ny.0) - ZXW(self.rx.0 * rhs.nz.0) + ZXW(self.rz.0 * rhs.nx.0),
+		//     xyw : XYW(self.rw.0 * rhs.nz.0) + XYW(self.rx.0 * rhs.ny.0) - XYW(self.ry.0 * rhs.nx.0),
+		//     xyz : XYZ(self.rw.0 * rhs.d.0) + XYZ(self.ux.0 * rhs.nx.0) + XYZ(self.uy.0 * rhs.ny.0) + XYZ(self.uz.0 * rhs.nz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.rx.anti_geometric(rhs.d)
+				+ self.uw.anti_geometric(rhs.nx)
+				+ self.uy.anti_geometric(rhs.nz)
+				+ self.uz.anti_geometric(rhs.ny),
+			y: self.ry.anti_geometric(rhs.d)
+				+ self.uw.anti_geometric(rhs.ny)
+				+ self.ux.anti_geometric(rhs.nz)
+				+ self.uz.anti_geometric(rhs.nx),
+			z: self.rz.anti_geometric(rhs.d)
+				+ self.uw.anti_geometric(rhs.nz)
+				+ self.ux.anti_geometric(rhs.ny)
+				+ self.uy.anti_geometric(rhs.nx),
+			w: self.rx.anti_geometric(rhs.nx) + self.ry.anti_geometric(rhs.ny) + self.rz.anti_geometric(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.anti_geometric(rhs.nx) + self.ry.anti_geometric(rhs.nz) + self.rz.anti_geometric(rhs.ny),
+			zxw: self.rw.anti_geometric(rhs.ny) + self.rx.anti_geometric(rhs.nz) + self.rz.anti_geometric(rhs.nx),
+			xyw: self.rw.anti_geometric(rhs.nz) + self.rx.anti_geometric(rhs.ny) + self.ry.anti_geometric(rhs.nx),
+			xyz: self.rw.anti_geometric(rhs.d)
+				+ self.ux.anti_geometric(rhs.nx)
+				+ self.uy.anti_geometric(rhs.ny)
+				+ self.uz.anti_geometric(rhs.nz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Motor3.dot(Plane) -> Multivector
+impl Dot<Plane> for Motor3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.ux.0 * rhs.d.0),
+		//     y   : -Y(self.uy.0 * rhs.d.0),
+		//     z   : -Z(self.uz.0 * rhs.d.0),
+		//     w   : W(self.rw.0 * rhs.d.0) - W(self.ux.0 * rhs.nx.0) - W(self.uy.0 * rhs.ny.0) - W(self.uz.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.uw.0 * rhs.nx.0),
+		//     zxw : ZXW(self.uw.0 * rhs.ny.0),
+		//     xyw : XYW(self.uw.0 * rhs.nz.0),
+		//     xyz : XYZ(self.uw.0 * rhs.d.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.ux.dot(rhs.d),
+			y: self.uy.dot(rhs.d),
+			z: self.uz.dot(rhs.d),
+			w: self.rw.dot(rhs.d) + self.ux.dot(rhs.nx) + self.uy.dot(rhs.ny) + self.uz.dot(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.uw.dot(rhs.nx),
+			zxw: self.uw.dot(rhs.ny),
+			xyw: self.uw.dot(rhs.nz),
+			xyz: self.uw.dot(rhs.d),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Motor3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor3.wedge(Plane) -> Plane
+impl Wedge<Plane> for Motor3 {
+	type Output = Plane;
+	fn wedge(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.uw.0 * rhs.nx.0),
+		//     ny: ZXW(self.uw.0 * rhs.ny.0),
+		//     nz: XYW(self.uw.0 * rhs.nz.0),
+		//     d : XYZ(self.uw.0 * rhs.d.0),
+		// }
+		Plane {
+			nx: self.uw.wedge(rhs.nx),
+			ny: self.uw.wedge(rhs.ny),
+			nz: self.uw.wedge(rhs.nz),
+			d: self.uw.wedge(rhs.d),
+		}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Motor3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor3.anti_wedge(Plane) -> Multivector
+impl AntiWedge<Plane> for Motor3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.rx.0 * rhs.d.0) + X(self.uy.0 * rhs.nz.0) - X(self.uz.0 * rhs.ny.0),
+		//     y   : -Y(self.ry.0 * rhs.d.0) - Y(self.ux.0 * rhs.nz.0) + Y(self.uz.0 * rhs.nx.0),
+		//     z   : -Z(self.rz.0 * rhs.d.0) + Z(self.ux.0 * rhs.ny.0) - Z(self.uy.0 * rhs.nx.0),
+		//     w   : -W(self.rx.0 * rhs.nx.0) - W(self.ry.0 * rhs.ny.0) - W(self.rz.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.rw.0 * rhs.nx.0),
+		//     zxw : ZXW(self.rw.0 * rhs.ny.0),
+		//     xyw : XYW(self.rw.0 * rhs.nz.0),
+		//     xyz : XYZ(self.rw.0 * rhs.d.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.rx.anti_wedge(rhs.d) + self.uy.anti_wedge(rhs.nz) + self.uz.anti_wedge(rhs.ny),
+			y: self.ry.anti_wedge(rhs.d) + self.ux.anti_wedge(rhs.nz) + self.uz.anti_wedge(rhs.nx),
+			z: self.rz.anti_wedge(rhs.d) + self.ux.anti_wedge(rhs.ny) + self.uy.anti_wedge(rhs.nx),
+			w: self.rx.anti_wedge(rhs.nx) + self.ry.anti_wedge(rhs.ny) + self.rz.anti_wedge(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.anti_wedge(rhs.nx),
+			zxw: self.rw.anti_wedge(rhs.ny),
+			xyw: self.rw.anti_wedge(rhs.nz),
+			xyz: self.rw.anti_wedge(rhs.d),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Motor3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor3.left_contraction(Plane) -> Multivector
+impl LeftContraction<Plane> for Motor3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.ux.0 * rhs.d.0),
+		//     y   : -Y(self.uy.0 * rhs.d.0),
+		//     z   : -Z(self.uz.0 * rhs.d.0),
+		//     w   : -W(self.ux.0 * rhs.nx.0) - W(self.uy.0 * rhs.ny.0) - W(self.uz.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.uw.0 * rhs.nx.0),
+		//     zxw : ZXW(self.uw.0 * rhs.ny.0),
+		//     xyw : XYW(self.uw.0 * rhs.nz.0),
+		//     xyz : XYZ(self.uw.0 * rhs.d.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.ux.left_contraction(rhs.d),
+			y: self.uy.left_contraction(rhs.d),
+			z: self.uz.left_contraction(rhs.d),
+			w: self.ux.left_contraction(rhs.nx) + self.uy.left_contraction(rhs.ny) + self.uz.left_contraction(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.uw.left_contraction(rhs.nx),
+			zxw: self.uw.left_contraction(rhs.ny),
+			xyw: self.uw.left_contraction(rhs.nz),
+			xyz: self.uw.left_contraction(rhs.d),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Motor3.right_contraction(Plane) -> W
+impl RightContraction<Plane> for Motor3 {
+	type Output = W;
+	fn right_contraction(self, rhs: Plane) -> Self::Output {
+		// W(self.rw.0 * rhs.d.0)
+		self.rw.right_contraction(rhs.d)
+	}
+}
+
+impl ScalarProduct<Plane> for Motor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Motor3.commutator(Plane) -> Multivector
+impl Commutator<Plane> for Motor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.rw.0 * rhs.d.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.rx.0 * rhs.d.0) - YZW(self.uy.0 * rhs.nz.0) + YZW(self.uz.0 * rhs.ny.0),
+		//     zxw : ZXW(self.ry.0 * rhs.d.0) + ZXW(self.ux.0 * rhs.nz.0) - ZXW(self.uz.0 * rhs.nx.0),
+		//     xyw : XYW(self.rz.0 * rhs.d.0) - XYW(self.ux.0 * rhs.ny.0) + XYW(self.uy.0 * rhs.nx.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.rw.commutator(rhs.d),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rx.commutator(rhs.d) + self.uy.commutator(rhs.nz) + self.uz.commutator(rhs.ny),
+			zxw: self.ry.commutator(rhs.d) + self.ux.commutator(rhs.nz) + self.uz.commutator(rhs.nx),
+			xyw: self.rz.commutator(rhs.d) + self.ux.commutator(rhs.ny) + self.uy.commutator(rhs.nx),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Motor3.anti_dot(Plane) -> Multivector
+impl AntiDot<Plane> for Motor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.uw.0 * rhs.nx.0),
+		//     y   : Y(self.uw.0 * rhs.ny.0),
+		//     z   : Z(self.uw.0 * rhs.nz.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.rw.0 * rhs.nx.0) + YZW(self.ry.0 * rhs.nz.0) - YZW(self.rz.0 * rhs.ny.0),
+		//     zxw : ZXW(self.rw.0 * rhs.ny.0) - ZXW(self.rx.0 * rhs.nz.0) + ZXW(self.rz.0 * rhs.nx.0),
+		//     xyw : XYW(self.rw.0 * rhs.nz.0) + XYW(self.rx.0 * rhs.ny.0) - XYW(self.ry.0 * rhs.nx.0),
+		//     xyz : XYZ(self.rw.0 * rhs.d.0) + XYZ(self.ux.0 * rhs.nx.0) + XYZ(self.uy.0 * rhs.ny.0) + XYZ(self.uz.0 * rhs.nz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uw.anti_dot(rhs.nx),
+			y: self.uw.anti_dot(rhs.ny),
+			z: self.uw.anti_dot(rhs.nz),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.rw.anti_dot(rhs.nx) + self.ry.anti_dot(rhs.nz) + self.rz.anti_dot(rhs.ny),
+			zxw: self.rw.anti_dot(rhs.ny) + self.rx.anti_dot(rhs.nz) + self.rz.anti_dot(rhs.nx),
+			xyw: self.rw.anti_dot(rhs.nz) + self.rx.anti_dot(rhs.ny) + self.ry.anti_dot(rhs.nx),
+			xyz: self.rw.anti_dot(rhs.d)
+				+ self.ux.anti_dot(rhs.nx)
+				+ self.uy.anti_dot(rhs.ny)
+				+ self.uz.anti_dot(rhs.nz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Motor3 OP Translator3:
+
+// Motor3.geometric(Translator3) -> Motor3
+impl Geometric<Translator3> for Motor3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.x.0) - WX(self.ry.0 * rhs.z.0) + WX(self.rz.0 * rhs.y.0) + WX(self.ux.0 * rhs.w.0),
+		//     ry: WY(self.rw.0 * rhs.y.0) + WY(self.rx.0 * rhs.z.0) - WY(self.rz.0 * rhs.x.0) + WY(self.uy.0 * rhs.w.0),
+		//     rz: WZ(self.rw.0 * rhs.z.0) - WZ(self.rx.0 * rhs.y.0) + WZ(self.ry.0 * rhs.x.0) + WZ(self.uz.0 * rhs.w.0),
+		//     rw: -XYZW(self.rx.0 * rhs.x.0) - XYZW(self.ry.0 * rhs.y.0) - XYZW(self.rz.0 * rhs.z.0) + XYZW(self.uw.0 * rhs.w.0),
+		//     ux: YZ(self.uw.0 * rhs.x.0) - YZ(self.uy.0 * rhs.z.0) + YZ(self.uz.0 * rhs.y.0),
+		//     uy: ZX(self.uw.0 * rhs.y.0) + ZX(self.ux.0 * rhs.z.0) - ZX(self.uz.0 * rhs.x.0),
+		//     uz: XY(self.uw.0 * rhs.z.0) - XY(self.ux.0 * rhs.y.0) + XY(self.uy.0 * rhs.x.0),
+		//     uw: -S(self.ux.0 * rhs.x.0) - S(self.uy.0 * rhs.y.0) - S(self.uz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.rw.geometric(rhs.x)
+				+ self.ry.geometric(rhs.z)
+				+ self.rz.geometric(rhs.y)
+				+ self.ux.geometric(rhs.w),
+			ry: self.rw.geometric(rhs.y)
+				+ self.rx.geometric(rhs.z)
+				+ self.rz.geometric(rhs.x)
+				+ self.uy.geometric(rhs.w),
+			rz: self.rw.geometric(rhs.z)
+				+ self.rx.geometric(rhs.y)
+				+ self.ry.geometric(rhs.x)
+				+ self.uz.geometric(rhs.w),
+			rw: self.rx.geometric(rhs.x)
+				+ self.ry.geometric(rhs.y)
+				+ self.rz.geometric(rhs.z)
+				+ self.uw.geometric(rhs.w),
+			ux: self.uw.geometric(rhs.x) + self.uy.geometric(rhs.z) + self.uz.geometric(rhs.y),
+			uy: self.uw.geometric(rhs.y) + self.ux.geometric(rhs.z) + self.uz.geometric(rhs.x),
+			uz: self.uw.geometric(rhs.z) + self.ux.geometric(rhs.y) + self.uy.geometric(rhs.x),
+			uw: self.ux.geometric(rhs.x) + self.uy.geometric(rhs.y) + self.uz.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator3> for Motor3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Translator3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor3.anti_geometric(Translator3) -> Motor3
+impl AntiGeometric<Translator3> for Motor3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rx.0 * rhs.w.0),
+		//     ry: WY(self.ry.0 * rhs.w.0),
+		//     rz: WZ(self.rz.0 * rhs.w.0),
+		//     rw: XYZW(self.rw.0 * rhs.w.0),
+		//     ux: YZ(self.rw.0 * rhs.x.0) + YZ(self.ry.0 * rhs.z.0) - YZ(self.rz.0 * rhs.y.0) + YZ(self.ux.0 * rhs.w.0),
+		//     uy: ZX(self.rw.0 * rhs.y.0) - ZX(self.rx.0 * rhs.z.0) + ZX(self.rz.0 * rhs.x.0) + ZX(self.uy.0 * rhs.w.0),
+		//     uz: XY(self.rw.0 * rhs.z.0) + XY(self.rx.0 * rhs.y.0) - XY(self.ry.0 * rhs.x.0) + XY(self.uz.0 * rhs.w.0),
+		//     uw: -S(self.rx.0 * rhs.x.0) - S(self.ry.0 * rhs.y.0) - S(self.rz.0 * rhs.z.0) + S(self.uw.0 * rhs.w.0),
+		// }
+		Motor3 {
+			rx: self.rx.anti_geometric(rhs.w),
+			ry: self.ry.anti_geometric(rhs.w),
+			rz: self.rz.anti_geometric(rhs.w),
+			rw: self.rw.anti_geometric(rhs.w),
+			ux: self.rw.anti_geometric(rhs.x)
+				+ self.ry.anti_geometric(rhs.z)
+				+ self.rz.anti_geometric(rhs.y)
+				+ self.ux.anti_geometric(rhs.w),
+			uy: self.rw.anti_geometric(rhs.y)
+				+ self.rx.anti_geometric(rhs.z)
+				+ self.rz.anti_geometric(rhs.x)
+				+ self.uy.anti_geometric(rhs.w),
+			uz: self.rw.anti_geometric(rhs.z)
+				+ self.rx.anti_geometric(rhs.y)
+				+ self.ry.anti_geometric(rhs.x)
+				+ self.uz.anti_geometric(rhs.w),
+			uw: self.rx.anti_geometric(rhs.x)
+				+ self.ry.anti_geometric(rhs.y)
+				+ self.rz.anti_geometric(rhs.z)
+				+ self.uw.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Motor3.dot(Translator3) -> Motor3
+impl Dot<Translator3> for Motor3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.x.0) + WX(self.ux.0 * rhs.w.0),
+		//     ry: WY(self.rw.0 * rhs.y.0) + WY(self.uy.0 * rhs.w.0),
+		//     rz: WZ(self.rw.0 * rhs.z.0) + WZ(self.uz.0 * rhs.w.0),
+		//     rw: XYZW(self.uw.0 * rhs.w.0),
+		//     ux: YZ(self.uw.0 * rhs.x.0),
+		//     uy: ZX(self.uw.0 * rhs.y.0),
+		//     uz: XY(self.uw.0 * rhs.z.0),
+		//     uw: -S(self.ux.0 * rhs.x.0) - S(self.uy.0 * rhs.y.0) - S(self.uz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.rw.dot(rhs.x) + self.ux.dot(rhs.w),
+			ry: self.rw.dot(rhs.y) + self.uy.dot(rhs.w),
+			rz: self.rw.dot(rhs.z) + self.uz.dot(rhs.w),
+			rw: self.uw.dot(rhs.w),
+			ux: self.uw.dot(rhs.x),
+			uy: self.uw.dot(rhs.y),
+			uz: self.uw.dot(rhs.z),
+			uw: self.ux.dot(rhs.x) + self.uy.dot(rhs.y) + self.uz.dot(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator3> for Motor3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Translator3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor3.wedge(Translator3) -> Translator3
+impl Wedge<Translator3> for Motor3 {
+	type Output = Translator3;
+	fn wedge(self, rhs: Translator3) -> Self::Output {
+		// Translator3 {
+		//     x: YZ(self.uw.0 * rhs.x.0),
+		//     y: ZX(self.uw.0 * rhs.y.0),
+		//     z: XY(self.uw.0 * rhs.z.0),
+		//     w: -XYZW(self.rx.0 * rhs.x.0) - XYZW(self.ry.0 * rhs.y.0) - XYZW(self.rz.0 * rhs.z.0) + XYZW(self.uw.0 * rhs.w.0),
+		// }
+		Translator3 {
+			x: self.uw.wedge(rhs.x),
+			y: self.uw.wedge(rhs.y),
+			z: self.uw.wedge(rhs.z),
+			w: self.rx.wedge(rhs.x) + self.ry.wedge(rhs.y) + self.rz.wedge(rhs.z) + self.uw.wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator3> for Motor3 {
+	type Output = Translator3;
+	fn bitxor(self, rhs: Translator3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor3.anti_wedge(Translator3) -> Motor3
+impl AntiWedge<Translator3> for Motor3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rx.0 * rhs.w.0),
+		//     ry: WY(self.ry.0 * rhs.w.0),
+		//     rz: WZ(self.rz.0 * rhs.w.0),
+		//     rw: XYZW(self.rw.0 * rhs.w.0),
+		//     ux: YZ(self.rw.0 * rhs.x.0) + YZ(self.ux.0 * rhs.w.0),
+		//     uy: ZX(self.rw.0 * rhs.y.0) + ZX(self.uy.0 * rhs.w.0),
+		//     uz: XY(self.rw.0 * rhs.z.0) + XY(self.uz.0 * rhs.w.0),
+		//     uw: -S(self.rx.0 * rhs.x.0) - S(self.ry.0 * rhs.y.0) - S(self.rz.0 * rhs.z.0) + S(self.uw.0 * rhs.w.0),
+		// }
+		Motor3 {
+			rx: self.rx.anti_wedge(rhs.w),
+			ry: self.ry.anti_wedge(rhs.w),
+			rz: self.rz.anti_wedge(rhs.w),
+			rw: self.rw.anti_wedge(rhs.w),
+			ux: self.rw.anti_wedge(rhs.x) + self.ux.anti_wedge(rhs.w),
+			uy: self.rw.anti_wedge(rhs.y) + self.uy.anti_wedge(rhs.w),
+			uz: self.rw.anti_wedge(rhs.z) + self.uz.anti_wedge(rhs.w),
+			uw: self.rx.anti_wedge(rhs.x)
+				+ self.ry.anti_wedge(rhs.y)
+				+ self.rz.anti_wedge(rhs.z)
+				+ self.uw.anti_wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Translator3> for Motor3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Translator3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor3.left_contraction(Translator3) -> Motor3
+impl LeftContraction<Translator3> for Motor3 {
+	type Output = Motor3;
+	fn left_contraction(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.ux.0 * rhs.w.0),
+		//     ry: WY(self.uy.0 * rhs.w.0),
+		//     rz: WZ(self.uz.0 * rhs.w.0),
+		//     rw: XYZW(self.uw.0 * rhs.w.0),
+		//     ux: YZ(self.uw.0 * rhs.x.0),
+		//     uy: ZX(self.uw.0 * rhs.y.0),
+		//     uz: XY(self.uw.0 * rhs.z.0),
+		//     uw: -S(self.ux.0 * rhs.x.0) - S(self.uy.0 * rhs.y.0) - S(self.uz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.ux.left_contraction(rhs.w),
+			ry: self.uy.left_contraction(rhs.w),
+			rz: self.uz.left_contraction(rhs.w),
+			rw: self.uw.left_contraction(rhs.w),
+			ux: self.uw.left_contraction(rhs.x),
+			uy: self.uw.left_contraction(rhs.y),
+			uz: self.uw.left_contraction(rhs.z),
+			uw: self.ux.left_contraction(rhs.x) + self.uy.left_contraction(rhs.y) + self.uz.left_contraction(rhs.z),
+		}
+	}
+}
+
+// Motor3.right_contraction(Translator3) -> Motor3
+impl RightContraction<Translator3> for Motor3 {
+	type Output = Motor3;
+	fn right_contraction(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.x.0),
+		//     ry: WY(self.rw.0 * rhs.y.0),
+		//     rz: WZ(self.rw.0 * rhs.z.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.ux.0 * rhs.x.0) - S(self.uy.0 * rhs.y.0) - S(self.uz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.rw.right_contraction(rhs.x),
+			ry: self.rw.right_contraction(rhs.y),
+			rz: self.rw.right_contraction(rhs.z),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.ux.right_contraction(rhs.x) + self.uy.right_contraction(rhs.y) + self.uz.right_contraction(rhs.z),
+		}
+	}
+}
+
+// Motor3.scalar_product(Translator3) -> S
+impl ScalarProduct<Translator3> for Motor3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator3) -> Self::Output {
+		// -S(self.ux.0 * rhs.x.0) - S(self.uy.0 * rhs.y.0) - S(self.uz.0 * rhs.z.0)
+		self.ux.scalar_product(rhs.x) + self.uy.scalar_product(rhs.y) + self.uz.scalar_product(rhs.z)
+	}
+}
+
+// Motor3.commutator(Translator3) -> Line3
+impl Commutator<Translator3> for Motor3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Translator3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.ry.0 * rhs.z.0) + WX(self.rz.0 * rhs.y.0),
+		//     vy: WY(self.rx.0 * rhs.z.0) - WY(self.rz.0 * rhs.x.0),
+		//     vz: -WZ(self.rx.0 * rhs.y.0) + WZ(self.ry.0 * rhs.x.0),
+		//     mx: -YZ(self.uy.0 * rhs.z.0) + YZ(self.uz.0 * rhs.y.0),
+		//     my: ZX(self.ux.0 * rhs.z.0) - ZX(self.uz.0 * rhs.x.0),
+		//     mz: -XY(self.ux.0 * rhs.y.0) + XY(self.uy.0 * rhs.x.0),
 		// }
-		Plane {
-			nx: self.uw.wedge(rhs.nx),
-			ny: self.uw.wedge(rhs.ny),
-			nz: self.uw.wedge(rhs.nz),
-			d: self.uw.wedge(rhs.d),
+		Line3 {
+			vx: self.ry.commutator(rhs.z) + self.rz.commutator(rhs.y),
+			vy: self.rx.commutator(rhs.z) + self.rz.commutator(rhs.x),
+			vz: self.rx.commutator(rhs.y) + self.ry.commutator(rhs.x),
+			mx: self.uy.commutator(rhs.z) + self.uz.commutator(rhs.y),
+			my: self.ux.commutator(rhs.z) + self.uz.commutator(rhs.x),
+			mz: self.ux.commutator(rhs.y) + self.uy.commutator(rhs.x),
 		}
 	}
 }
 
-// Omitted: Motor3 anti_wedge Plane = self.rw & rhs.d + self.rw & rhs.nx + self.rw & rhs.ny + self.rw & rhs.nz + self.rx & rhs.d + self.rx & rhs.nx + self.ry & rhs.d + self.ry & rhs.ny + self.rz & rhs.d + self.rz & rhs.nz + self.ux & rhs.d + self.ux & rhs.ny + self.ux & rhs.nz + self.uy & rhs.d + self.uy & rhs.nx + self.uy & rhs.nz + self.uz & rhs.d + self.uz & rhs.nx + self.uz & rhs.ny  (unnamed type)
+// Motor3.anti_dot(Translator3) -> Motor3
+impl AntiDot<Translator3> for Motor3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rx.0 * rhs.w.0),
+		//     ry: WY(self.ry.0 * rhs.w.0),
+		//     rz: WZ(self.rz.0 * rhs.w.0),
+		//     rw: XYZW(self.rw.0 * rhs.w.0),
+		//     ux: YZ(self.rw.0 * rhs.x.0) + YZ(self.ux.0 * rhs.w.0),
+		//     uy: ZX(self.rw.0 * rhs.y.0) + ZX(self.uy.0 * rhs.w.0),
+		//     uz: XY(self.rw.0 * rhs.z.0) + XY(self.uz.0 * rhs.w.0),
+		//     uw: S(self.uw.0 * rhs.w.0),
+		// }
+		Motor3 {
+			rx: self.rx.anti_dot(rhs.w),
+			ry: self.ry.anti_dot(rhs.w),
+			rz: self.rz.anti_dot(rhs.w),
+			rw: self.rw.anti_dot(rhs.w),
+			ux: self.rw.anti_dot(rhs.x) + self.ux.anti_dot(rhs.w),
+			uy: self.rw.anti_dot(rhs.y) + self.uy.anti_dot(rhs.w),
+			uz: self.rw.anti_dot(rhs.z) + self.uz.anti_dot(rhs.w),
+			uw: self.uw.anti_dot(rhs.w),
+		}
+	}
+}
 
 // ---------------------------------------------------------------------
 // Motor3 OP Rotor3:
@@ -139,41 +3274,116 @@ impl Geometric<Rotor3> for Motor3 {
 	type Output = Rotor3;
 	fn geometric(self, rhs: Rotor3) -> Self::Output {
 		// Rotor3 {
-		//     x: WX(self.uw.0 * rhs.x.0),
-		//     y: WY(self.uw.0 * rhs.y.0),
-		//     z: WZ(self.uw.0 * rhs.z.0),
-		//     w: XYZW(self.uw.0 * rhs.w.0),
+		//     x: WX(self.uw.0 * rhs.x.0) + WX(self.ux.0 * rhs.w.0) - WX(self.uy.0 * rhs.z.0) + WX(self.uz.0 * rhs.y.0),
+		//     y: WY(self.uw.0 * rhs.y.0) + WY(self.ux.0 * rhs.z.0) + WY(self.uy.0 * rhs.w.0) - WY(self.uz.0 * rhs.x.0),
+		//     z: WZ(self.uw.0 * rhs.z.0) - WZ(self.ux.0 * rhs.y.0) + WZ(self.uy.0 * rhs.x.0) + WZ(self.uz.0 * rhs.w.0),
+		//     w: XYZW(self.uw.0 * rhs.w.0) - XYZW(self.ux.0 * rhs.x.0) - XYZW(self.uy.0 * rhs.y.0) - XYZW(self.uz.0 * rhs.z.0),
 		// }
 		Rotor3 {
-			x: self.uw.geometric(rhs.x),
-			y: self.uw.geometric(rhs.y),
-			z: self.uw.geometric(rhs.z),
-			w: self.uw.geometric(rhs.w),
+			x: self.uw.geometric(rhs.x)
+				+ self.ux.geometric(rhs.w)
+				+ self.uy.geometric(rhs.z)
+				+ self.uz.geometric(rhs.y),
+			y: self.uw.geometric(rhs.y)
+				+ self.ux.geometric(rhs.z)
+				+ self.uy.geometric(rhs.w)
+				+ self.uz.geometric(rhs.x),
+			z: self.uw.geometric(rhs.z)
+				+ self.ux.geometric(rhs.y)
+				+ self.uy.geometric(rhs.x)
+				+ self.uz.geometric(rhs.w),
+			w: self.uw.geometric(rhs.w)
+				+ self.ux.geometric(rhs.x)
+				+ self.uy.geometric(rhs.y)
+				+ self.uz.geometric(rhs.z),
 		}
 	}
 }
 
-// Omitted: Motor3 anti_geometric Rotor3 = self.rw !* rhs.w + self.rw !* rhs.x + self.rw !* rhs.y + self.rw !* rhs.z + self.rx !* rhs.w + self.rx !* rhs.x + self.rx !* rhs.y + self.rx !* rhs.z + self.ry !* rhs.w + self.ry !* rhs.x + self.ry !* rhs.y + self.ry !* rhs.z + self.rz !* rhs.w + self.rz !* rhs.x + self.rz !* rhs.y + self.rz !* rhs.z + self.uw !* rhs.w + self.uw !* rhs.x + self.uw !* rhs.y + self.uw !* rhs.z + self.ux !* rhs.w + self.ux !* rhs.x + self.ux !* rhs.y + self.ux !* rhs.z + self.uy !* rhs.w + self.uy !* rhs.x + self.uy !* rhs.y + self.uy !* rhs.z + self.uz !* rhs.w + self.uz !* rhs.x + self.uz !* rhs.y + self.uz !* rhs.z  (unnamed type)
+impl std::ops::Mul<Rotor3> for Motor3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor3.anti_geometric(Rotor3) -> Motor3
+impl AntiGeometric<Rotor3> for Motor3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.x.0) + WX(self.rx.0 * rhs.w.0) + WX(self.ry.0 * rhs.z.0) - WX(self.rz.0 * rhs.y.0),
+		//     ry: WY(self.rw.0 * rhs.y.0) - WY(self.rx.0 * rhs.z.0) + WY(self.ry.0 * rhs.w.0) + WY(self.rz.0 * rhs.x.0),
+		//     rz: WZ(self.rw.0 * rhs.z.0) + WZ(self.rx.0 * rhs.y.0) - WZ(self.ry.0 * rhs.x.0) + WZ(self.rz.0 * rhs.w.0),
+		//     rw: XYZW(self.rw.0 * rhs.w.0) - XYZW(self.rx.0 * rhs.x.0) - XYZW(self.ry.0 * rhs.y.0) - XYZW(self.rz.0 * rhs.z.0),
+		//     ux: YZ(self.uw.0 * rhs.x.0) + YZ(self.ux.0 * rhs.w.0) + YZ(self.uy.0 * rhs.z.0) - YZ(self.uz.0 * rhs.y.0),
+		//     uy: ZX(self.uw.0 * rhs.y.0) - ZX(self.ux.0 * rhs.z.0) + ZX(self.uy.0 * rhs.w.0) + ZX(self.uz.0 * rhs.x.0),
+		//     uz: XY(self.uw.0 * rhs.z.0) + XY(self.ux.0 * rhs.y.0) - XY(self.uy.0 * rhs.x.0) + XY(self.uz.0 * rhs.w.0),
+		//     uw: S(self.uw.0 * rhs.w.0) - S(self.ux.0 * rhs.x.0) - S(self.uy.0 * rhs.y.0) - S(self.uz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.rw.anti_geometric(rhs.x)
+				+ self.rx.anti_geometric(rhs.w)
+				+ self.ry.anti_geometric(rhs.z)
+				+ self.rz.anti_geometric(rhs.y),
+			ry: self.rw.anti_geometric(rhs.y)
+				+ self.rx.anti_geometric(rhs.z)
+				+ self.ry.anti_geometric(rhs.w)
+				+ self.rz.anti_geometric(rhs.x),
+			rz: self.rw.anti_geometric(rhs.z)
+				+ self.rx.anti_geometric(rhs.y)
+				+ self.ry.anti_geometric(rhs.x)
+				+ self.rz.anti_geometric(rhs.w),
+			rw: self.rw.anti_geometric(rhs.w)
+				+ self.rx.anti_geometric(rhs.x)
+				+ self.ry.anti_geometric(rhs.y)
+				+ self.rz.anti_geometric(rhs.z),
+			ux: self.uw.anti_geometric(rhs.x)
+				+ self.ux.anti_geometric(rhs.w)
+				+ self.uy.anti_geometric(rhs.z)
+				+ self.uz.anti_geometric(rhs.y),
+			uy: self.uw.anti_geometric(rhs.y)
+				+ self.ux.anti_geometric(rhs.z)
+				+ self.uy.anti_geometric(rhs.w)
+				+ self.uz.anti_geometric(rhs.x),
+			uz: self.uw.anti_geometric(rhs.z)
+				+ self.ux.anti_geometric(rhs.y)
+				+ self.uy.anti_geometric(rhs.x)
+				+ self.uz.anti_geometric(rhs.w),
+			uw: self.uw.anti_geometric(rhs.w)
+				+ self.ux.anti_geometric(rhs.x)
+				+ self.uy.anti_geometric(rhs.y)
+				+ self.uz.anti_geometric(rhs.z),
+		}
+	}
+}
 
 // Motor3.dot(Rotor3) -> Rotor3
 impl Dot<Rotor3> for Motor3 {
 	type Output = Rotor3;
 	fn dot(self, rhs: Rotor3) -> Self::Output {
 		// Rotor3 {
-		//     x: WX(self.uw.0 * rhs.x.0),
-		//     y: WY(self.uw.0 * rhs.y.0),
-		//     z: WZ(self.uw.0 * rhs.z.0),
+		//     x: WX(self.uw.0 * rhs.x.0) + WX(self.ux.0 * rhs.w.0),
+		//     y: WY(self.uw.0 * rhs.y.0) + WY(self.uy.0 * rhs.w.0),
+		//     z: WZ(self.uw.0 * rhs.z.0) + WZ(self.uz.0 * rhs.w.0),
 		//     w: XYZW(self.uw.0 * rhs.w.0),
 		// }
 		Rotor3 {
-			x: self.uw.dot(rhs.x),
-			y: self.uw.dot(rhs.y),
-			z: self.uw.dot(rhs.z),
+			x: self.uw.dot(rhs.x) + self.ux.dot(rhs.w),
+			y: self.uw.dot(rhs.y) + self.uy.dot(rhs.w),
+			z: self.uw.dot(rhs.z) + self.uz.dot(rhs.w),
 			w: self.uw.dot(rhs.w),
 		}
 	}
 }
 
+impl std::ops::BitOr<Rotor3> for Motor3 {
+	type Output = Rotor3;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Motor3.wedge(Rotor3) -> Rotor3
 impl Wedge<Rotor3> for Motor3 {
 	type Output = Rotor3;
@@ -182,18 +3392,143 @@ impl Wedge<Rotor3> for Motor3 {
 		//     x: WX(self.uw.0 * rhs.x.0),
 		//     y: WY(self.uw.0 * rhs.y.0),
 		//     z: WZ(self.uw.0 * rhs.z.0),
-		//     w: XYZW(self.uw.0 * rhs.w.0),
+		//     w: XYZW(self.uw.0 * rhs.w.0) - XYZW(self.ux.0 * rhs.x.0) - XYZW(self.uy.0 * rhs.y.0) - XYZW(self.uz.0 * rhs.z.0),
 		// }
 		Rotor3 {
 			x: self.uw.wedge(rhs.x),
 			y: self.uw.wedge(rhs.y),
 			z: self.uw.wedge(rhs.z),
-			w: self.uw.wedge(rhs.w),
+			w: self.uw.wedge(rhs.w) + self.ux.wedge(rhs.x) + self.uy.wedge(rhs.y) + self.uz.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor3> for Motor3 {
+	type Output = Rotor3;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor3.anti_wedge(Rotor3) -> Motor3
+impl AntiWedge<Rotor3> for Motor3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.x.0) + WX(self.rx.0 * rhs.w.0),
+		//     ry: WY(self.rw.0 * rhs.y.0) + WY(self.ry.0 * rhs.w.0),
+		//     rz: WZ(self.rw.0 * rhs.z.0) + WZ(self.rz.0 * rhs.w.0),
+		//     rw: XYZW(self.rw.0 * rhs.w.0),
+		//     ux: YZ(self.ux.0 * rhs.w.0),
+		//     uy: ZX(self.uy.0 * rhs.w.0),
+		//     uz: XY(self.uz.0 * rhs.w.0),
+		//     uw: S(self.uw.0 * rhs.w.0) - S(self.ux.0 * rhs.x.0) - S(self.uy.0 * rhs.y.0) - S(self.uz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.rw.anti_wedge(rhs.x) + self.rx.anti_wedge(rhs.w),
+			ry: self.rw.anti_wedge(rhs.y) + self.ry.anti_wedge(rhs.w),
+			rz: self.rw.anti_wedge(rhs.z) + self.rz.anti_wedge(rhs.w),
+			rw: self.rw.anti_wedge(rhs.w),
+			ux: self.ux.anti_wedge(rhs.w),
+			uy: self.uy.anti_wedge(rhs.w),
+			uz: self.uz.anti_wedge(rhs.w),
+			uw: self.uw.anti_wedge(rhs.w)
+				+ self.ux.anti_wedge(rhs.x)
+				+ self.uy.anti_wedge(rhs.y)
+				+ self.uz.anti_wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor3> for Motor3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor3.left_contraction(Rotor3) -> Rotor3
+impl LeftContraction<Rotor3> for Motor3 {
+	type Output = Rotor3;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     x: WX(self.uw.0 * rhs.x.0) + WX(self.ux.0 * rhs.w.0),
+		//     y: WY(self.uw.0 * rhs.y.0) + WY(self.uy.0 * rhs.w.0),
+		//     z: WZ(self.uw.0 * rhs.z.0) + WZ(self.uz.0 * rhs.w.0),
+		//     w: XYZW(self.uw.0 * rhs.w.0),
+		// }
+		Rotor3 {
+			x: self.uw.left_contraction(rhs.x) + self.ux.left_contraction(rhs.w),
+			y: self.uw.left_contraction(rhs.y) + self.uy.left_contraction(rhs.w),
+			z: self.uw.left_contraction(rhs.z) + self.uz.left_contraction(rhs.w),
+			w: self.uw.left_contraction(rhs.w),
+		}
+	}
+}
+
+impl RightContraction<Rotor3> for Motor3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Rotor3> for Motor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Motor3.commutator(Rotor3) -> Line3
+impl Commutator<Rotor3> for Motor3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.uy.0 * rhs.z.0) + WX(self.uz.0 * rhs.y.0),
+		//     vy: WY(self.ux.0 * rhs.z.0) - WY(self.uz.0 * rhs.x.0),
+		//     vz: -WZ(self.ux.0 * rhs.y.0) + WZ(self.uy.0 * rhs.x.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.uy.commutator(rhs.z) + self.uz.commutator(rhs.y),
+			vy: self.ux.commutator(rhs.z) + self.uz.commutator(rhs.x),
+			vz: self.ux.commutator(rhs.y) + self.uy.commutator(rhs.x),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
 		}
 	}
 }
 
-// Omitted: Motor3 anti_wedge Rotor3 = self.rw & rhs.w + self.rw & rhs.x + self.rw & rhs.y + self.rw & rhs.z + self.rx & rhs.w + self.ry & rhs.w + self.rz & rhs.w + self.uw & rhs.w + self.ux & rhs.w + self.ux & rhs.x + self.uy & rhs.w + self.uy & rhs.y + self.uz & rhs.w + self.uz & rhs.z  (unnamed type)
+// Motor3.anti_dot(Rotor3) -> Motor3
+impl AntiDot<Rotor3> for Motor3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.x.0) + WX(self.rx.0 * rhs.w.0),
+		//     ry: WY(self.rw.0 * rhs.y.0) + WY(self.ry.0 * rhs.w.0),
+		//     rz: WZ(self.rw.0 * rhs.z.0) + WZ(self.rz.0 * rhs.w.0),
+		//     rw: XYZW(self.rw.0 * rhs.w.0) - XYZW(self.rx.0 * rhs.x.0) - XYZW(self.ry.0 * rhs.y.0) - XYZW(self.rz.0 * rhs.z.0),
+		//     ux: YZ(self.uw.0 * rhs.x.0) + YZ(self.ux.0 * rhs.w.0),
+		//     uy: ZX(self.uw.0 * rhs.y.0) + ZX(self.uy.0 * rhs.w.0),
+		//     uz: XY(self.uw.0 * rhs.z.0) + XY(self.uz.0 * rhs.w.0),
+		//     uw: S(self.uw.0 * rhs.w.0),
+		// }
+		Motor3 {
+			rx: self.rw.anti_dot(rhs.x) + self.rx.anti_dot(rhs.w),
+			ry: self.rw.anti_dot(rhs.y) + self.ry.anti_dot(rhs.w),
+			rz: self.rw.anti_dot(rhs.z) + self.rz.anti_dot(rhs.w),
+			rw: self.rw.anti_dot(rhs.w) + self.rx.anti_dot(rhs.x) + self.ry.anti_dot(rhs.y) + self.rz.anti_dot(rhs.z),
+			ux: self.uw.anti_dot(rhs.x) + self.ux.anti_dot(rhs.w),
+			uy: self.uw.anti_dot(rhs.y) + self.uy.anti_dot(rhs.w),
+			uz: self.uw.anti_dot(rhs.z) + self.uz.anti_dot(rhs.w),
+			uw: self.uw.anti_dot(rhs.w),
+		}
+	}
+}
 
 // ---------------------------------------------------------------------
 // Motor3 OP Motor3:
@@ -203,57 +3538,176 @@ impl Geometric<Motor3> for Motor3 {
 	type Output = Motor3;
 	fn geometric(self, rhs: Motor3) -> Self::Output {
 		// Motor3 {
-		//     rx: WX(self.rx.0 * rhs.uw.0) + WX(self.uw.0 * rhs.rx.0),
-		//     ry: WY(self.ry.0 * rhs.uw.0) + WY(self.uw.0 * rhs.ry.0),
-		//     rz: WZ(self.rz.0 * rhs.uw.0) + WZ(self.uw.0 * rhs.rz.0),
-		//     rw: XYZW(self.rw.0 * rhs.uw.0) + XYZW(self.uw.0 * rhs.rw.0),
-		//     ux: YZW(self.uw.0 * rhs.ux.0) + YZW(self.ux.0 * rhs.uw.0),
-		//     uy: ZXW(self.uw.0 * rhs.uy.0) + ZXW(self.uy.0 * rhs.uw.0),
-		//     uz: XYW(self.uw.0 * rhs.uz.0) + XYW(self.uz.0 * rhs.uw.0),
-		//     uw: S(self.uw.0 * rhs.uw.0),
+		//     rx: WX(self.rw.0 * rhs.ux.0) + WX(self.rx.0 * rhs.uw.0) - WX(self.ry.0 * rhs.uz.0) + WX(self.rz.0 * rhs.uy.0) + WX(self.uw.0 * rhs.rx.0) + WX(self.ux.0 * rhs.rw.0) - WX(self.uy.0 * rhs.rz.0) + WX(self.uz.0 * rhs.ry.0),
+		//     ry: WY(self.rw.0 * rhs.uy.0) + WY(self.rx.0 * rhs.uz.0) + WY(self.ry.0 * rhs.uw.0) - WY(self.rz.0 * rhs.ux.0) + WY(self.uw.0 * rhs.ry.0) + WY(self.ux.0 * rhs.rz.0) + WY(self.uy.0 * rhs.rw.0) - WY(self.uz.0 * rhs.rx.0),
+		//     rz: WZ(self.rw.0 * rhs.uz.0) - WZ(self.rx.0 * rhs.uy.0) + WZ(self.ry.0 * rhs.ux.0) + WZ(self.rz.0 * rhs.uw.0) + WZ(self.uw.0 * rhs.rz.0) - WZ(self.ux.0 * rhs.ry.0) + WZ(self.uy.0 * rhs.rx.0) + WZ(self.uz.0 * rhs.rw.0),
+		//     rw: XYZW(self.rw.0 * rhs.uw.0) - XYZW(self.rx.0 * rhs.ux.0) - XYZW(self.ry.0 * rhs.uy.0) - XYZW(self.rz.0 * rhs.uz.0) + XYZW(self.uw.0 * rhs.rw.0) - XYZW(self.ux.0 * rhs.rx.0) - XYZW(self.uy.0 * rhs.ry.0) - XYZW(self.uz.0 * rhs.rz.0),
+		//     ux: YZ(self.uw.0 * rhs.ux.0) + YZ(self.ux.0 * rhs.uw.0) - YZ(self.uy.0 * rhs.uz.0) + YZ(self.uz.0 * rhs.uy.0),
+		//     uy: ZX(self.uw.0 * rhs.uy.0) + ZX(self.ux.0 * rhs.uz.0) + ZX(self.uy.0 * rhs.uw.0) - ZX(self.uz.0 * rhs.ux.0),
+		//     uz: XY(self.uw.0 * rhs.uz.0) - XY(self.ux.0 * rhs.uy.0) + XY(self.uy.0 * rhs.ux.0) + XY(self.uz.0 * rhs.uw.0),
+		//     uw: S(self.uw.0 * rhs.uw.0) - S(self.ux.0 * rhs.ux.0) - S(self.uy.0 * rhs.uy.0) - S(self.uz.0 * rhs.uz.0),
 		// }
 		Motor3 {
-			rx: self.rx.geometric(rhs.uw) + self.uw.geometric(rhs.rx),
-			ry: self.ry.geometric(rhs.uw) + self.uw.geometric(rhs.ry),
-			rz: self.rz.geometric(rhs.uw) + self.uw.geometric(rhs.rz),
-			rw: self.rw.geometric(rhs.uw) + self.uw.geometric(rhs.rw),
-			ux: self.uw.geometric(rhs.ux) + self.ux.geometric(rhs.uw),
-			uy: self.uw.geometric(rhs.uy) + self.uy.geometric(rhs.uw),
-			uz: self.uw.geometric(rhs.uz) + self.uz.geometric(rhs.uw),
-			uw: self.uw.geometric(rhs.uw),
+			rx: self.rw.geometric(rhs.ux)
+				+ self.rx.geometric(rhs.uw)
+				+ self.ry.geometric(rhs.uz)
+				+ self.rz.geometric(rhs.uy)
+				+ self.uw.geometric(rhs.rx)
+				+ self.ux.geometric(rhs.rw)
+				+ self.uy.geometric(rhs.rz)
+				+ self.uz.geometric(rhs.ry),
+			ry: self.rw.geometric(rhs.uy)
+				+ self.rx.geometric(rhs.uz)
+				+ self.ry.geometric(rhs.uw)
+				+ self.rz.geometric(rhs.ux)
+				+ self.uw.geometric(rhs.ry)
+				+ self.ux.geometric(rhs.rz)
+				+ self.uy.geometric(rhs.rw)
+				+ self.uz.geometric(rhs.rx),
+			rz: self.rw.geometric(rhs.uz)
+				+ self.rx.geometric(rhs.uy)
+				+ self.ry.geometric(rhs.ux)
+				+ self.rz.geometric(rhs.uw)
+				+ self.uw.geometric(rhs.rz)
+				+ self.ux.geometric(rhs.ry)
+				+ self.uy.geometric(rhs.rx)
+				+ self.uz.geometric(rhs.rw),
+			rw: self.rw.geometric(rhs.uw)
+				+ self.rx.geometric(rhs.ux)
+				+ self.ry.geometric(rhs.uy)
+				+ self.rz.geometric(rhs.uz)
+				+ self.uw.geometric(rhs.rw)
+				+ self.ux.geometric(rhs.rx)
+				+ self.uy.geometric(rhs.ry)
+				+ self.uz.geometric(rhs.rz),
+			ux: self.uw.geometric(rhs.ux)
+				+ self.ux.geometric(rhs.uw)
+				+ self.uy.geometric(rhs.uz)
+				+ self.uz.geometric(rhs.uy),
+			uy: self.uw.geometric(rhs.uy)
+				+ self.ux.geometric(rhs.uz)
+				+ self.uy.geometric(rhs.uw)
+				+ self.uz.geometric(rhs.ux),
+			uz: self.uw.geometric(rhs.uz)
+				+ self.ux.geometric(rhs.uy)
+				+ self.uy.geometric(rhs.ux)
+				+ self.uz.geometric(rhs.uw),
+			uw: self.uw.geometric(rhs.uw)
+				+ self.ux.geometric(rhs.ux)
+				+ self.uy.geometric(rhs.uy)
+				+ self.uz.geometric(rhs.uz),
 		}
 	}
 }
 
-// Omitted: Motor3 anti_geometric Motor3 = self.rw !* rhs.rw + self.rw !* rhs.rx + self.rw !* rhs.ry + self.rw !* rhs.rz + self.rw !* rhs.uw + self.rw !* rhs.ux + self.rw !* rhs.uy + self.rw !* rhs.uz + self.rx !* rhs.rw + self.rx !* rhs.rx + self.rx !* rhs.ry + self.rx !* rhs.rz + self.rx !* rhs.uw + self.rx !* rhs.ux + self.rx !* rhs.uy + self.rx !* rhs.uz + self.ry !* rhs.rw + self.ry !* rhs.rx + self.ry !* rhs.ry + self.ry !* rhs.rz + self.ry !* rhs.uw + self.ry !* rhs.ux + self.ry !* rhs.uy + self.ry !* rhs.uz + self.rz !* rhs.rw + self.rz !* rhs.rx + self.rz !* rhs.ry + self.rz !* rhs.rz + self.rz !* rhs.uw + self.rz !* rhs.ux + self.rz !* rhs.uy + self.rz !* rhs.uz + self.uw !* rhs.rw + self.uw !* rhs.rx + self.uw !* rhs.ry + self.uw !* rhs.rz + self.uw !* rhs.ux + self.uw !* rhs.uy + self.uw !* rhs.uz + self.ux !* rhs.rw + self.ux !* rhs.rx + self.ux !* rhs.ry + self.ux !* rhs.rz + self.ux !* rhs.uw + self.ux !* rhs.ux + self.ux !* rhs.uy + self.ux !* rhs.uz + self.uy !* rhs.rw + self.uy !* rhs.rx + self.uy !* rhs.ry + self.uy !* rhs.rz + self.uy !* rhs.uw + self.uy !* rhs.ux + self.uy !* rhs.uy + self.uy !* rhs.uz + self.uz !* rhs.rw + self.uz !* rhs.rx + self.uz !* rhs.ry + self.uz !* rhs.rz + self.uz !* rhs.uw + self.uz !* rhs.ux + self.uz !* rhs.uy + self.uz !* rhs.uz  (unnamed type)
+impl std::ops::Mul<Motor3> for Motor3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Motor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor3.anti_geometric(Motor3) -> Motor3
+impl AntiGeometric<Motor3> for Motor3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.rx.0) + WX(self.rx.0 * rhs.rw.0) + WX(self.ry.0 * rhs.rz.0) - WX(self.rz.0 * rhs.ry.0),
+		//     ry: WY(self.rw.0 * rhs.ry.0) - WY(self.rx.0 * rhs.rz.0) + WY(self.ry.0 * rhs.rw.0) + WY(self.rz.0 * rhs.rx.0),
+		//     rz: WZ(self.rw.0 * rhs.rz.0) + WZ(self.rx.0 * rhs.ry.0) - WZ(self.ry.0 * rhs.rx.0) + WZ(self.rz.0 * rhs.rw.0),
+		//     rw: XYZW(self.rw.0 * rhs.rw.0) - XYZW(self.rx.0 * rhs.rx.0) - XYZW(self.ry.0 * rhs.ry.0) - XYZW(self.rz.0 * rhs.rz.0),
+		//     ux: YZ(self.rw.0 * rhs.ux.0) + YZ(self.rx.0 * rhs.uw.0) + YZ(self.ry.0 * rhs.uz.0) - YZ(self.rz.0 * rhs.uy.0) + YZ(self.uw.0 * rhs.rx.0) + YZ(self.ux.0 * rhs.rw.0) + YZ(self.uy.0 * rhs.rz.0) - YZ(self.uz.0 * rhs.ry.0),
+		//     uy: ZX(self.rw.0 * rhs.uy.0) - ZX(self.rx.0 * rhs.uz.0) + ZX(self.ry.0 * rhs.uw.0) + ZX(self.rz.0 * rhs.ux.0) + ZX(self.uw.0 * rhs.ry.0) - ZX(self.ux.0 * rhs.rz.0) + ZX(self.uy.0 * rhs.rw.0) + ZX(self.uz.0 * rhs.rx.0),
+		//     uz: XY(self.rw.0 * rhs.uz.0) + XY(self.rx.0 * rhs.uy.0) - XY(self.ry.0 * rhs.ux.0) + XY(self.rz.0 * rhs.uw.0) + XY(self.uw.0 * rhs.rz.0) + XY(self.ux.0 * rhs.ry.0) - XY(self.uy.0 * rhs.rx.0) + XY(self.uz.0 * rhs.rw.0),
+		//     uw: S(self.rw.0 * rhs.uw.0) - S(self.rx.0 * rhs.ux.0) - S(self.ry.0 * rhs.uy.0) - S(self.rz.0 * rhs.uz.0) + S(self.uw.0 * rhs.rw.0) - S(self.ux.0 * rhs.rx.0) - S(self.uy.0 * rhs.ry.0) - S(self.uz.0 * rhs.rz.0),
+		// }
+		Motor3 {
+			rx: self.rw.anti_geometric(rhs.rx)
+				+ self.rx.anti_geometric(rhs.rw)
+				+ self.ry.anti_geometric(rhs.rz)
+				+ self.rz.anti_geometric(rhs.ry),
+			ry: self.rw.anti_geometric(rhs.ry)
+				+ self.rx.anti_geometric(rhs.rz)
+				+ self.ry.anti_geometric(rhs.rw)
+				+ self.rz.anti_geometric(rhs.rx),
+			rz: self.rw.anti_geometric(rhs.rz)
+				+ self.rx.anti_geometric(rhs.ry)
+				+ self.ry.anti_geometric(rhs.rx)
+				+ self.rz.anti_geometric(rhs.rw),
+			rw: self.rw.anti_geometric(rhs.rw)
+				+ self.rx.anti_geometric(rhs.rx)
+				+ self.ry.anti_geometric(rhs.ry)
+				+ self.rz.anti_geometric(rhs.rz),
+			ux: self.rw.anti_geometric(rhs.ux)
+				+ self.rx.anti_geometric(rhs.uw)
+				+ self.ry.anti_geometric(rhs.uz)
+				+ self.rz.anti_geometric(rhs.uy)
+				+ self.uw.anti_geometric(rhs.rx)
+				+ self.ux.anti_geometric(rhs.rw)
+				+ self.uy.anti_geometric(rhs.rz)
+				+ self.uz.anti_geometric(rhs.ry),
+			uy: self.rw.anti_geometric(rhs.uy)
+				+ self.rx.anti_geometric(rhs.uz)
+				+ self.ry.anti_geometric(rhs.uw)
+				+ self.rz.anti_geometric(rhs.ux)
+				+ self.uw.anti_geometric(rhs.ry)
+				+ self.ux.anti_geometric(rhs.rz)
+				+ self.uy.anti_geometric(rhs.rw)
+				+ self.uz.anti_geometric(rhs.rx),
+			uz: self.rw.anti_geometric(rhs.uz)
+				+ self.rx.anti_geometric(rhs.uy)
+				+ self.ry.anti_geometric(rhs.ux)
+				+ self.rz.anti_geometric(rhs.uw)
+				+ self.uw.anti_geometric(rhs.rz)
+				+ self.ux.anti_geometric(rhs.ry)
+				+ self.uy.anti_geometric(rhs.rx)
+				+ self.uz.anti_geometric(rhs.rw),
+			uw: self.rw.anti_geometric(rhs.uw)
+				+ self.rx.anti_geometric(rhs.ux)
+				+ self.ry.anti_geometric(rhs.uy)
+				+ self.rz.anti_geometric(rhs.uz)
+				+ self.uw.anti_geometric(rhs.rw)
+				+ self.ux.anti_geometric(rhs.rx)
+				+ self.uy.anti_geometric(rhs.ry)
+				+ self.uz.anti_geometric(rhs.rz),
+		}
+	}
+}
 
 // Motor3.dot(Motor3) -> Motor3
 impl Dot<Motor3> for Motor3 {
 	type Output = Motor3;
 	fn dot(self, rhs: Motor3) -> Self::Output {
 		// Motor3 {
-		//     rx: WX(self.rx.0 * rhs.uw.0) + WX(self.uw.0 * rhs.rx.0),
-		//     ry: WY(self.ry.0 * rhs.uw.0) + WY(self.uw.0 * rhs.ry.0),
-		//     rz: WZ(self.rz.0 * rhs.uw.0) + WZ(self.uw.0 * rhs.rz.0),
+		//     rx: WX(self.rw.0 * rhs.ux.0) + WX(self.rx.0 * rhs.uw.0) + WX(self.uw.0 * rhs.rx.0) + WX(self.ux.0 * rhs.rw.0),
+		//     ry: WY(self.rw.0 * rhs.uy.0) + WY(self.ry.0 * rhs.uw.0) + WY(self.uw.0 * rhs.ry.0) + WY(self.uy.0 * rhs.rw.0),
+		//     rz: WZ(self.rw.0 * rhs.uz.0) + WZ(self.rz.0 * rhs.uw.0) + WZ(self.uw.0 * rhs.rz.0) + WZ(self.uz.0 * rhs.rw.0),
 		//     rw: XYZW(self.rw.0 * rhs.uw.0) + XYZW(self.uw.0 * rhs.rw.0),
-		//     ux: YZW(self.uw.0 * rhs.ux.0) + YZW(self.ux.0 * rhs.uw.0),
-		//     uy: ZXW(self.uw.0 * rhs.uy.0) + ZXW(self.uy.0 * rhs.uw.0),
-		//     uz: XYW(self.uw.0 * rhs.uz.0) + XYW(self.uz.0 * rhs.uw.0),
-		//     uw: S(self.uw.0 * rhs.uw.0),
+		//     ux: YZ(self.uw.0 * rhs.ux.0) + YZ(self.ux.0 * rhs.uw.0),
+		//     uy: ZX(self.uw.0 * rhs.uy.0) + ZX(self.uy.0 * rhs.uw.0),
+		//     uz: XY(self.uw.0 * rhs.uz.0) + XY(self.uz.0 * rhs.uw.0),
+		//     uw: S(self.uw.0 * rhs.uw.0) - S(self.ux.0 * rhs.ux.0) - S(self.uy.0 * rhs.uy.0) - S(self.uz.0 * rhs.uz.0),
 		// }
 		Motor3 {
-			rx: self.rx.dot(rhs.uw) + self.uw.dot(rhs.rx),
-			ry: self.ry.dot(rhs.uw) + self.uw.dot(rhs.ry),
-			rz: self.rz.dot(rhs.uw) + self.uw.dot(rhs.rz),
+			rx: self.rw.dot(rhs.ux) + self.rx.dot(rhs.uw) + self.uw.dot(rhs.rx) + self.ux.dot(rhs.rw),
+			ry: self.rw.dot(rhs.uy) + self.ry.dot(rhs.uw) + self.uw.dot(rhs.ry) + self.uy.dot(rhs.rw),
+			rz: self.rw.dot(rhs.uz) + self.rz.dot(rhs.uw) + self.uw.dot(rhs.rz) + self.uz.dot(rhs.rw),
 			rw: self.rw.dot(rhs.uw) + self.uw.dot(rhs.rw),
 			ux: self.uw.dot(rhs.ux) + self.ux.dot(rhs.uw),
 			uy: self.uw.dot(rhs.uy) + self.uy.dot(rhs.uw),
 			uz: self.uw.dot(rhs.uz) + self.uz.dot(rhs.uw),
-			uw: self.uw.dot(rhs.uw),
+			uw: self.uw.dot(rhs.uw) + self.ux.dot(rhs.ux) + self.uy.dot(rhs.uy) + self.uz.dot(rhs.uz),
 		}
 	}
 }
 
+impl std::ops::BitOr<Motor3> for Motor3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Motor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Motor3.wedge(Motor3) -> Motor3
 impl Wedge<Motor3> for Motor3 {
 	type Output = Motor3;
@@ -262,17 +3716,24 @@ impl Wedge<Motor3> for Motor3 {
 		//     rx: WX(self.rx.0 * rhs.uw.0) + WX(self.uw.0 * rhs.rx.0),
 		//     ry: WY(self.ry.0 * rhs.uw.0) + WY(self.uw.0 * rhs.ry.0),
 		//     rz: WZ(self.rz.0 * rhs.uw.0) + WZ(self.uw.0 * rhs.rz.0),
-		//     rw: XYZW(self.rw.0 * rhs.uw.0) + XYZW(self.uw.0 * rhs.rw.0),
-		//     ux: YZW(self.uw.0 * rhs.ux.0) + YZW(self.ux.0 * rhs.uw.0),
-		//     uy: ZXW(self.uw.0 * rhs.uy.0) + ZXW(self.uy.0 * rhs.uw.0),
-		//     uz: XYW(self.uw.0 * rhs.uz.0) + XYW(self.uz.0 * rhs.uw.0),
+		//     rw: XYZW(self.rw.0 * rhs.uw.0) - XYZW(self.rx.0 * rhs.ux.0) - XYZW(self.ry.0 * rhs.uy.0) - XYZW(self.rz.0 * rhs.uz.0) + XYZW(self.uw.0 * rhs.rw.0) - XYZW(self.ux.0 * rhs.rx.0) - XYZW(self.uy.0 * rhs.ry.0) - XYZW(self.uz.0 * rhs.rz.0),
+		//     ux: YZ(self.uw.0 * rhs.ux.0) + YZ(self.ux.0 * rhs.uw.0),
+		//     uy: ZX(self.uw.0 * rhs.uy.0) + ZX(self.uy.0 * rhs.uw.0),
+		//     uz: XY(self.uw.0 * rhs.uz.0) + XY(self.uz.0 * rhs.uw.0),
 		//     uw: S(self.uw.0 * rhs.uw.0),
 		// }
 		Motor3 {
 			rx: self.rx.wedge(rhs.uw) + self.uw.wedge(rhs.rx),
 			ry: self.ry.wedge(rhs.uw) + self.uw.wedge(rhs.ry),
 			rz: self.rz.wedge(rhs.uw) + self.uw.wedge(rhs.rz),
-			rw: self.rw.wedge(rhs.uw) + self.uw.wedge(rhs.rw),
+			rw: self.rw.wedge(rhs.uw)
+				+ self.rx.wedge(rhs.ux)
+				+ self.ry.wedge(rhs.uy)
+				+ self.rz.wedge(rhs.uz)
+				+ self.uw.wedge(rhs.rw)
+				+ self.ux.wedge(rhs.rx)
+				+ self.uy.wedge(rhs.ry)
+				+ self.uz.wedge(rhs.rz),
 			ux: self.uw.wedge(rhs.ux) + self.ux.wedge(rhs.uw),
 			uy: self.uw.wedge(rhs.uy) + self.uy.wedge(rhs.uw),
 			uz: self.uw.wedge(rhs.uz) + self.uz.wedge(rhs.uw),
@@ -281,4 +3742,1324 @@ impl Wedge<Motor3> for Motor3 {
 	}
 }
 
-// Omitted: Motor3 anti_wedge Motor3 = self.rw & rhs.rw + self.rw & rhs.rx + self.rw & rhs.ry + self.rw & rhs.rz + self.rw & rhs.uw + self.rw & rhs.ux + self.rw & rhs.uy + self.rw & rhs.uz + self.rx & rhs.rw + self.rx & rhs.ux + self.ry & rhs.rw + self.ry & rhs.uy + self.rz & rhs.rw + self.rz & rhs.uz + self.uw & rhs.rw + self.ux & rhs.rw + self.ux & rhs.rx + self.ux & rhs.uy + self.ux & rhs.uz + self.uy & rhs.rw + self.uy & rhs.ry + self.uy & rhs.ux + self.uy & rhs.uz + self.uz & rhs.rw + self.uz & rhs.rz + self.uz & rhs.ux + self.uz & rhs.uy  (unnamed type)
+impl std::ops::BitXor<Motor3> for Motor3 {
+	type Output = Motor3;
+	fn bitxor(self, rhs: Motor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor3.anti_wedge(Motor3) -> Motor3
+impl AntiWedge<Motor3> for Motor3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.rx.0) + WX(self.rx.0 * rhs.rw.0),
+		//     ry: WY(self.rw.0 * rhs.ry.0) + WY(self.ry.0 * rhs.rw.0),
+		//     rz: WZ(self.rw.0 * rhs.rz.0) + WZ(self.rz.0 * rhs.rw.0),
+		//     rw: XYZW(self.rw.0 * rhs.rw.0),
+		//     ux: YZ(self.rw.0 * rhs.ux.0) + YZ(self.ux.0 * rhs.rw.0),
+		//     uy: ZX(self.rw.0 * rhs.uy.0) + ZX(self.uy.0 * rhs.rw.0),
+		//     uz: XY(self.rw.0 * rhs.uz.0) + XY(self.uz.0 * rhs.rw.0),
+		//     uw: S(self.rw.0 * rhs.uw.0) - S(self.rx.0 * rhs.ux.0) - S(self.ry.0 * rhs.uy.0) - S(self.rz.0 * rhs.uz.0) + S(self.uw.0 * rhs.rw.0) - S(self.ux.0 * rhs.rx.0) - S(self.uy.0 * rhs.ry.0) - S(self.uz.0 * rhs.rz.0),
+		// }
+		Motor3 {
+			rx: self.rw.anti_wedge(rhs.rx) + self.rx.anti_wedge(rhs.rw),
+			ry: self.rw.anti_wedge(rhs.ry) + self.ry.anti_wedge(rhs.rw),
+			rz: self.rw.anti_wedge(rhs.rz) + self.rz.anti_wedge(rhs.rw),
+			rw: self.rw.anti_wedge(rhs.rw),
+			ux: self.rw.anti_wedge(rhs.ux) + self.ux.anti_wedge(rhs.rw),
+			uy: self.rw.anti_wedge(rhs.uy) + self.uy.anti_wedge(rhs.rw),
+			uz: self.rw.anti_wedge(rhs.uz) + self.uz.anti_wedge(rhs.rw),
+			uw: self.rw.anti_wedge(rhs.uw)
+				+ self.rx.anti_wedge(rhs.ux)
+				+ self.ry.anti_wedge(rhs.uy)
+				+ self.rz.anti_wedge(rhs.uz)
+				+ self.uw.anti_wedge(rhs.rw)
+				+ self.ux.anti_wedge(rhs.rx)
+				+ self.uy.anti_wedge(rhs.ry)
+				+ self.uz.anti_wedge(rhs.rz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor3> for Motor3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Motor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor3.left_contraction(Motor3) -> Motor3
+impl LeftContraction<Motor3> for Motor3 {
+	type Output = Motor3;
+	fn left_contraction(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.uw.0 * rhs.rx.0) + WX(self.ux.0 * rhs.rw.0),
+		//     ry: WY(self.uw.0 * rhs.ry.0) + WY(self.uy.0 * rhs.rw.0),
+		//     rz: WZ(self.uw.0 * rhs.rz.0) + WZ(self.uz.0 * rhs.rw.0),
+		//     rw: XYZW(self.uw.0 * rhs.rw.0),
+		//     ux: YZ(self.uw.0 * rhs.ux.0),
+		//     uy: ZX(self.uw.0 * rhs.uy.0),
+		//     uz: XY(self.uw.0 * rhs.uz.0),
+		//     uw: S(self.uw.0 * rhs.uw.0) - S(self.ux.0 * rhs.ux.0) - S(self.uy.0 * rhs.uy.0) - S(self.uz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.uw.left_contraction(rhs.rx) + self.ux.left_contraction(rhs.rw),
+			ry: self.uw.left_contraction(rhs.ry) + self.uy.left_contraction(rhs.rw),
+			rz: self.uw.left_contraction(rhs.rz) + self.uz.left_contraction(rhs.rw),
+			rw: self.uw.left_contraction(rhs.rw),
+			ux: self.uw.left_contraction(rhs.ux),
+			uy: self.uw.left_contraction(rhs.uy),
+			uz: self.uw.left_contraction(rhs.uz),
+			uw: self.uw.left_contraction(rhs.uw)
+				+ self.ux.left_contraction(rhs.ux)
+				+ self.uy.left_contraction(rhs.uy)
+				+ self.uz.left_contraction(rhs.uz),
+		}
+	}
+}
+
+// Motor3.right_contraction(Motor3) -> Motor3
+impl RightContraction<Motor3> for Motor3 {
+	type Output = Motor3;
+	fn right_contraction(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.ux.0) + WX(self.rx.0 * rhs.uw.0),
+		//     ry: WY(self.rw.0 * rhs.uy.0) + WY(self.ry.0 * rhs.uw.0),
+		//     rz: WZ(self.rw.0 * rhs.uz.0) + WZ(self.rz.0 * rhs.uw.0),
+		//     rw: XYZW(self.rw.0 * rhs.uw.0),
+		//     ux: YZ(self.ux.0 * rhs.uw.0),
+		//     uy: ZX(self.uy.0 * rhs.uw.0),
+		//     uz: XY(self.uz.0 * rhs.uw.0),
+		//     uw: S(self.uw.0 * rhs.uw.0) - S(self.ux.0 * rhs.ux.0) - S(self.uy.0 * rhs.uy.0) - S(self.uz.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.rw.right_contraction(rhs.ux) + self.rx.right_contraction(rhs.uw),
+			ry: self.rw.right_contraction(rhs.uy) + self.ry.right_contraction(rhs.uw),
+			rz: self.rw.right_contraction(rhs.uz) + self.rz.right_contraction(rhs.uw),
+			rw: self.rw.right_contraction(rhs.uw),
+			ux: self.ux.right_contraction(rhs.uw),
+			uy: self.uy.right_contraction(rhs.uw),
+			uz: self.uz.right_contraction(rhs.uw),
+			uw: self.uw.right_contraction(rhs.uw)
+				+ self.ux.right_contraction(rhs.ux)
+				+ self.uy.right_contraction(rhs.uy)
+				+ self.uz.right_contraction(rhs.uz),
+		}
+	}
+}
+
+// Motor3.scalar_product(Motor3) -> S
+impl ScalarProduct<Motor3> for Motor3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Motor3) -> Self::Output {
+		// S(self.uw.0 * rhs.uw.0) - S(self.ux.0 * rhs.ux.0) - S(self.uy.0 * rhs.uy.0) - S(self.uz.0 * rhs.uz.0)
+		self.uw.scalar_product(rhs.uw)
+			+ self.ux.scalar_product(rhs.ux)
+			+ self.uy.scalar_product(rhs.uy)
+			+ self.uz.scalar_product(rhs.uz)
+	}
+}
+
+// Motor3.commutator(Motor3) -> Line3
+impl Commutator<Motor3> for Motor3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Motor3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.ry.0 * rhs.uz.0) + WX(self.rz.0 * rhs.uy.0) - WX(self.uy.0 * rhs.rz.0) + WX(self.uz.0 * rhs.ry.0),
+		//     vy: WY(self.rx.0 * rhs.uz.0) - WY(self.rz.0 * rhs.ux.0) + WY(self.ux.0 * rhs.rz.0) - WY(self.uz.0 * rhs.rx.0),
+		//     vz: -WZ(self.rx.0 * rhs.uy.0) + WZ(self.ry.0 * rhs.ux.0) - WZ(self.ux.0 * rhs.ry.0) + WZ(self.uy.0 * rhs.rx.0),
+		//     mx: -YZ(self.uy.0 * rhs.uz.0) + YZ(self.uz.0 * rhs.uy.0),
+		//     my: ZX(self.ux.0 * rhs.uz.0) - ZX(self.uz.0 * rhs.ux.0),
+		//     mz: -XY(self.ux.0 * rhs.uy.0) + XY(self.uy.0 * rhs.ux.0),
+		// }
+		Line3 {
+			vx: self.ry.commutator(rhs.uz)
+				+ self.rz.commutator(rhs.uy)
+				+ self.uy.commutator(rhs.rz)
+				+ self.uz.commutator(rhs.ry),
+			vy: self.rx.commutator(rhs.uz)
+				+ self.rz.commutator(rhs.ux)
+				+ self.ux.commutator(rhs.rz)
+				+ self.uz.commutator(rhs.rx),
+			vz: self.rx.commutator(rhs.uy)
+				+ self.ry.commutator(rhs.ux)
+				+ self.ux.commutator(rhs.ry)
+				+ self.uy.commutator(rhs.rx),
+			mx: self.uy.commutator(rhs.uz) + self.uz.commutator(rhs.uy),
+			my: self.ux.commutator(rhs.uz) + self.uz.commutator(rhs.ux),
+			mz: self.ux.commutator(rhs.uy) + self.uy.commutator(rhs.ux),
+		}
+	}
+}
+
+// Motor3.anti_dot(Motor3) -> Motor3
+impl AntiDot<Motor3> for Motor3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.rw.0 * rhs.rx.0) + WX(self.rx.0 * rhs.rw.0),
+		//     ry: WY(self.rw.0 * rhs.ry.0) + WY(self.ry.0 * rhs.rw.0),
+		//     rz: WZ(self.rw.0 * rhs.rz.0) + WZ(self.rz.0 * rhs.rw.0),
+		//     rw: XYZW(self.rw.0 * rhs.rw.0) - XYZW(self.rx.0 * rhs.rx.0) - XYZW(self.ry.0 * rhs.ry.0) - XYZW(self.rz.0 * rhs.rz.0),
+		//     ux: YZ(self.rw.0 * rhs.ux.0) + YZ(self.rx.0 * rhs.uw.0) + YZ(self.uw.0 * rhs.rx.0) + YZ(self.ux.0 * rhs.rw.0),
+		//     uy: ZX(self.rw.0 * rhs.uy.0) + ZX(self.ry.0 * rhs.uw.0) + ZX(self.uw.0 * rhs.ry.0) + ZX(self.uy.0 * rhs.rw.0),
+		//     uz: XY(self.rw.0 * rhs.uz.0) + XY(self.rz.0 * rhs.uw.0) + XY(self.uw.0 * rhs.rz.0) + XY(self.uz.0 * rhs.rw.0),
+		//     uw: S(self.rw.0 * rhs.uw.0) + S(self.uw.0 * rhs.rw.0),
+		// }
+		Motor3 {
+			rx: self.rw.anti_dot(rhs.rx) + self.rx.anti_dot(rhs.rw),
+			ry: self.rw.anti_dot(rhs.ry) + self.ry.anti_dot(rhs.rw),
+			rz: self.rw.anti_dot(rhs.rz) + self.rz.anti_dot(rhs.rw),
+			rw: self.rw.anti_dot(rhs.rw)
+				+ self.rx.anti_dot(rhs.rx)
+				+ self.ry.anti_dot(rhs.ry)
+				+ self.rz.anti_dot(rhs.rz),
+			ux: self.rw.anti_dot(rhs.ux)
+				+ self.rx.anti_dot(rhs.uw)
+				+ self.uw.anti_dot(rhs.rx)
+				+ self.ux.anti_dot(rhs.rw),
+			uy: self.rw.anti_dot(rhs.uy)
+				+ self.ry.anti_dot(rhs.uw)
+				+ self.uw.anti_dot(rhs.ry)
+				+ self.uy.anti_dot(rhs.rw),
+			uz: self.rw.anti_dot(rhs.uz)
+				+ self.rz.anti_dot(rhs.uw)
+				+ self.uw.anti_dot(rhs.rz)
+				+ self.uz.anti_dot(rhs.rw),
+			uw: self.rw.anti_dot(rhs.uw) + self.uw.anti_dot(rhs.rw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Motor3 OP Multivector:
+
+// Motor3.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.uw.0 * rhs.s.0) - S(self.ux.0 * rhs.yz.0) - S(self.uy.0 * rhs.zx.0) - S(self.uz.0 * rhs.xy.0),
+		//     x   : X(self.uw.0 * rhs.x.0) - X(self.ux.0 * rhs.xyz.0) - X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.uw.0 * rhs.y.0) + Y(self.ux.0 * rhs.z.0) - Y(self.uy.0 * rhs.xyz.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : Z(self.uw.0 * rhs.z.0) - Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0) - Z(self.uz.0 * rhs.xyz.0),
+		//     w   : W(self.rw.0 * rhs.xyz.0) + W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0) + W(self.uw.0 * rhs.w.0) - W(self.ux.0 * rhs.yzw.0) - W(self.uy.0 * rhs.zxw.0) - W(self.uz.0 * rhs.xyw.0),
+		//     wx  : WX(self.rw.0 * rhs.yz.0) + WX(self.rx.0 * rhs.s.0) - WX(self.ry.0 * rhs.xy.0) + WX(self.rz.0 * rhs.zx.0) + WX(self.uw.0 * rhs.wx.0) + WX(self.ux.0 * rhs.xyzw.0) - WX(self.uy.0 * rhs.wz.0) + WX(self.uz.0 * rhs.wy.0),
+		//     wy  : WY(self.rw.0 * rhs.zx.0) + WY(self.rx.0 * rhs.xy.0) + WY(self.ry.0 * rhs.s.0) - WY(self.rz.0 * rhs.yz.0) + WY(self.uw.0 * rhs.wy.0) + WY(self.ux.0 * rhs.wz.0) + WY(self.uy.0 * rhs.xyzw.0) - WY(self.uz.0 * rhs.wx.0),
+		//     wz  : WZ(self.rw.0 * rhs.xy.0) - WZ(self.rx.0 * rhs.zx.0) + WZ(self.ry.0 * rhs.yz.0) + WZ(self.rz.0 * rhs.s.0) + WZ(self.uw.0 * rhs.wz.0) - WZ(self.ux.0 * rhs.wy.0) + WZ(self.uy.0 * rhs.wx.0) + WZ(self.uz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.uw.0 * rhs.yz.0) + YZ(self.ux.0 * rhs.s.0) - YZ(self.uy.0 * rhs.xy.0) + YZ(self.uz.0 * rhs.zx.0),
+		//     zx  : ZX(self.uw.0 * rhs.zx.0) + ZX(self.ux.0 * rhs.xy.0) + ZX(self.uy.0 * rhs.s.0) - ZX(self.uz.0 * rhs.yz.0),
+		//     xy  : XY(self.uw.0 * rhs.xy.0) - XY(self.ux.0 * rhs.zx.0) + XY(self.uy.0 * rhs.yz.0) + XY(self.uz.0 * rhs.s.0),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0) + YZW(self.rx.0 * rhs.xyz.0) + YZW(self.ry.0 * rhs.z.0) - YZW(self.rz.0 * rhs.y.0) + YZW(self.uw.0 * rhs.yzw.0) + YZW(self.ux.0 * rhs.w.0) - YZW(self.uy.0 * rhs.xyw.0) + YZW(self.uz.0 * rhs.zxw.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0) - ZXW(self.rx.0 * rhs.z.0) + ZXW(self.ry.0 * rhs.xyz.0) + ZXW(self.rz.0 * rhs.x.0) + ZXW(self.uw.0 * rhs.zxw.0) + ZXW(self.ux.0 * rhs.xyw.0) + ZXW(self.uy.0 * rhs.w.0) - ZXW(self.uz.0 * rhs.yzw.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0) + XYW(self.rx.0 * rhs.y.0) - XYW(self.ry.0 * rhs.x.0) + XYW(self.rz.0 * rhs.xyz.0) + XYW(self.uw.0 * rhs.xyw.0) - XYW(self.ux.0 * rhs.zxw.0) + XYW(self.uy.0 * rhs.yzw.0) + XYW(self.uz.0 * rhs.w.0),
+		//     xyz : XYZ(self.uw.0 * rhs.xyz.0) + XYZ(self.ux.0 * rhs.x.0) + XYZ(self.uy.0 * rhs.y.0) + XYZ(self.uz.0 * rhs.z.0),
+		//     xyzw: XYZW(self.rw.0 * rhs.s.0) - XYZW(self.rx.0 * rhs.yz.0) - XYZW(self.ry.0 * rhs.zx.0) - XYZW(self.rz.0 * rhs.xy.0) + XYZW(self.uw.0 * rhs.xyzw.0) - XYZW(self.ux.0 * rhs.wx.0) - XYZW(self.uy.0 * rhs.wy.0) - XYZW(self.uz.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: self.uw.geometric(rhs.s)
+				+ self.ux.geometric(rhs.yz)
+				+ self.uy.geometric(rhs.zx)
+				+ self.uz.geometric(rhs.xy),
+			x: self.uw.geometric(rhs.x)
+				+ self.ux.geometric(rhs.xyz)
+				+ self.uy.geometric(rhs.z)
+				+ self.uz.geometric(rhs.y),
+			y: self.uw.geometric(rhs.y)
+				+ self.ux.geometric(rhs.z)
+				+ self.uy.geometric(rhs.xyz)
+				+ self.uz.geometric(rhs.x),
+			z: self.uw.geometric(rhs.z)
+				+ self.ux.geometric(rhs.y)
+				+ self.uy.geometric(rhs.x)
+				+ self.uz.geometric(rhs.xyz),
+			w: self.rw.geometric(rhs.xyz)
+				+ self.rx.geometric(rhs.x)
+				+ self.ry.geometric(rhs.y)
+				+ self.rz.geometric(rhs.z)
+				+ self.uw.geometric(rhs.w)
+				+ self.ux.geometric(rhs.yzw)
+				+ self.uy.geometric(rhs.zxw)
+				+ self.uz.geometric(rhs.xyw),
+			wx: self.rw.geometric(rhs.yz)
+				+ self.rx.geometric(rhs.s)
+				+ self.ry.geometric(rhs.xy)
+				+ self.rz.geometric(rhs.zx)
+				+ self.uw.geometric(rhs.wx)
+				+ self.ux.geometric(rhs.xyzw)
+				+ self.uy.geometric(rhs.wz)
+				+ self.uz.geometric(rhs.wy),
+			wy: self.rw.geometric(rhs.zx)
+				+ self.rx.geometric(rhs.xy)
+				+ self.ry.geometric(rhs.s)
+				+ self.rz.geometric(rhs.yz)
+				+ self.uw.geometric(rhs.wy)
+				+ self.ux.geometric(rhs.wz)
+				+ self.uy.geometric(rhs.xyzw)
+				+ self.uz.geometric(rhs.wx),
+			wz: self.rw.geometric(rhs.xy)
+				+ self.rx.geometric(rhs.zx)
+				+ self.ry.geometric(rhs.yz)
+				+ self.rz.geometric(rhs.s)
+				+ self.uw.geometric(rhs.wz)
+				+ self.ux.geometric(rhs.wy)
+				+ self.uy.geometric(rhs.wx)
+				+ self.uz.geometric(rhs.xyzw),
+			yz: self.uw.geometric(rhs.yz)
+				+ self.ux.geometric(rhs.s)
+				+ self.uy.geometric(rhs.xy)
+				+ self.uz.geometric(rhs.zx),
+			zx: self.uw.geometric(rhs.zx)
+				+ self.ux.geometric(rhs.xy)
+				+ self.uy.geometric(rhs.s)
+				+ self.uz.geometric(rhs.yz),
+			xy: self.uw.geometric(rhs.xy)
+				+ self.ux.geometric(rhs.zx)
+				+ self.uy.geometric(rhs.yz)
+				+ self.uz.geometric(rhs.s),
+			yzw: self.rw.geometric(rhs.x)
+				+ self.rx.geometric(rhs.xyz)
+				+ self.ry.geometric(rhs.z)
+				+ self.rz.geometric(rhs.y)
+				+ self.uw.geometric(rhs.yzw)
+				+ self.ux.geometric(rhs.w)
+				+ self.uy.geometric(rhs.xyw)
+				+ self.uz.geometric(rhs.zxw),
+			zxw: self.rw.geometric(rhs.y)
+				+ self.rx.geometric(rhs.z)
+				+ self.ry.geometric(rhs.xyz)
+				+ self.rz.geometric(rhs.x)
+				+ self.uw.geometric(rhs.zxw)
+				+ self.ux.geometric(rhs.xyw)
+				+ self.uy.geometric(rhs.w)
+				+ self.uz.geometric(rhs.yzw),
+			xyw: self.rw.geometric(rhs.z)
+				+ self.rx.geometric(rhs.y)
+				+ self.ry.geometric(rhs.x)
+				+ self.rz.geometric(rhs.xyz)
+				+ self.uw.geometric(rhs.xyw)
+				+ self.ux.geometric(rhs.zxw)
+				+ self.uy.geometric(rhs.yzw)
+				+ self.uz.geometric(rhs.w),
+			xyz: self.uw.geometric(rhs.xyz)
+				+ self.ux.geometric(rhs.x)
+				+ self.uy.geometric(rhs.y)
+				+ self.uz.geometric(rhs.z),
+			xyzw: self.rw.geometric(rhs.s)
+				+ self.rx.geometric(rhs.yz)
+				+ self.ry.geometric(rhs.zx)
+				+ self.rz.geometric(rhs.xy)
+				+ self.uw.geometric(rhs.xyzw)
+				+ self.ux.geometric(rhs.wx)
+				+ self.uy.geometric(rhs.wy)
+				+ self.uz.geometric(rhs.wz),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Motor3.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.rw.0 * rhs.s.0) - S(self.rx.0 * rhs.yz.0) - S(self.ry.0 * rhs.zx.0) - S(self.rz.0 * rhs.xy.0) + S(self.uw.0 * rhs.xyzw.0) - S(self.ux.0 * rhs.wx.0) - S(self.uy.0 * rhs.wy.0) - S(self.uz.0 * rhs.wz.0),
+		//     x   : X(self.rw.0 * rhs.x.0) - X(self.rx.0 * rhs.xyz.0) + X(self.ry.0 * rhs.z.0) - X(self.rz.0 * rhs.y.0) + X(self.uw.0 * rhs.yzw.0) + X(self.ux.0 * rhs.w.0) + X(self.uy.0 * rhs.xyw.0) - X(self.uz.0 * rhs.zxw.0),
+		//     y   : Y(self.rw.0 * rhs.y.0) - Y(self.rx.0 * rhs.z.0) - Y(self.ry.0 * rhs.xyz.0) + Y(self.rz.0 * rhs.x.0) + Y(self.uw.0 * rhs.zxw.0) - Y(self.ux.0 * rhs.xyw.0) + Y(self.uy.0 * rhs.w.0) + Y(self.uz.0 * rhs.yzw.0),
+		//     z   : Z(self.rw.0 * rhs.z.0) + Z(self.rx.0 * rhs.y.0) - Z(self.ry.0 * rhs.x.0) - Z(self.rz.0 * rhs.xyz.0) + Z(self.uw.0 * rhs.xyw.0) + Z(self.ux.0 * rhs.zxw.0) - Z(self.uy.0 * rhs.yzw.0) + Z(self.uz.0 * rhs.w.0),
+		//     w   : W(self.rw.0 * rhs.w.0) - W(self.rx.0 * rhs.yzw.0) - W(self.ry.0 * rhs.zxw.0) - W(self.rz.0 * rhs.xyw.0),
+		//     wx  : WX(self.rw.0 * rhs.wx.0) + WX(self.rx.0 * rhs.xyzw.0) + WX(self.ry.0 * rhs.wz.0) - WX(self.rz.0 * rhs.wy.0),
+		//     wy  : WY(self.rw.0 * rhs.wy.0) - WY(self.rx.0 * rhs.wz.0) + WY(self.ry.0 * rhs.xyzw.0) + WY(self.rz.0 * rhs.wx.0),
+		//     wz  : WZ(self.rw.0 * rhs.wz.0) + WZ(self.rx.0 * rhs.wy.0) - WZ(self.ry.0 * rhs.wx.0) + WZ(self.rz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.rw.0 * rhs.yz.0) + YZ(self.rx.0 * rhs.s.0) + YZ(self.ry.0 * rhs.xy.0) - YZ(self.rz.0 * rhs.zx.0) + YZ(self.uw.0 * rhs.wx.0) + YZ(self.ux.0 * rhs.xyzw.0) + YZ(self.uy.0 * rhs.wz.0) - YZ(self.uz.0 * rhs.wy.0),
+		//     zx  : ZX(self.rw.0 * rhs.zx.0) - ZX(self.rx.0 * rhs.xy.0) + ZX(self.ry.0 * rhs.s.0) + ZX(self.rz.0 * rhs.yz.0) + ZX(self.uw.0 * rhs.wy.0) - ZX(self.ux.0 * rhs.wz.0) + ZX(self.uy.0 * rhs.xyzw.0) + ZX(self.uz.0 * rhs.wx.0),
+		//     xy  : XY(self.rw.0 * rhs.xy.0) + XY(self.rx.0 * rhs.zx.0) - XY(self.ry.0 * rhs.yz.0) + XY(self.rz.0 * rhs.s.0) + XY(self.uw.0 * rhs.wz.0) + XY(self.ux.0 * rhs.wy.0) - XY(self.uy.0 * rhs.wx.0) + XY(self.uz.0 * rhs.xyzw.0),
+		//     yzw : YZW(self.rw.0 * rhs.yzw.0) + YZW(self.rx.0 * rhs.w.0) + YZW(self.ry.0 * rhs.xyw.0) - YZW(self.rz.0 * rhs.zxw.0),
+		//     zxw : ZXW(self.rw.0 * rhs.zxw.0) - ZXW(self.rx.0 * rhs.xyw.0) + ZXW(self.ry.0 * rhs.w.0) + ZXW(self.rz.0 * rhs.yzw.0),
+		//     xyw : XYW(self.rw.0 * rhs.xyw.0) + XYW(self.rx.0 * rhs.zxw.0) - XYW(self.ry.0 * rhs.yzw.0) + XYW(self.rz.0 * rhs.w.0),
+		//     xyz : XYZ(self.rw.0 * rhs.xyz.0) + XYZ(self.rx.0 * rhs.x.0) + XYZ(self.ry.0 * rhs.y.0) + XYZ(self.rz.0 * rhs.z.0) - XYZ(self.uw.0 * rhs.w.0) + XYZ(self.ux.0 * rhs.yzw.0) + XYZ(self.uy.0 * rhs.zxw.0) + XYZ(self.uz.0 * rhs.xyw.0),
+		//     xyzw: XYZW(self.rw.0 * rhs.xyzw.0) - XYZW(self.rx.0 * rhs.wx.0) - XYZW(self.ry.0 * rhs.wy.0) - XYZW(self.rz.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: self.rw.anti_geometric(rhs.s)
+				+ self.rx.anti_geometric(rhs.yz)
+				+ self.ry.anti_geometric(rhs.zx)
+				+ self.rz.anti_geometric(rhs.xy)
+				+ self.uw.anti_geometric(rhs.xyzw)
+				+ self.ux.anti_geometric(rhs.wx)
+				+ self.uy.anti_geometric(rhs.wy)
+				+ self.uz.anti_geometric(rhs.wz),
+			x: self.rw.anti_geometric(rhs.x)
+				+ self.rx.anti_geometric(rhs.xyz)
+				+ self.ry.anti_geometric(rhs.z)
+				+ self.rz.anti_geometric(rhs.y)
+				+ self.uw.anti_geometric(rhs.yzw)
+				+ self.ux.anti_geometric(rhs.w)
+				+ self.uy.anti_geometric(rhs.xyw)
+				+ self.uz.anti_geometric(rhs.zxw),
+			y: self.rw.anti_geometric(rhs.y)
+				+ self.rx.anti_geometric(rhs.z)
+				+ self.ry.anti_geometric(rhs.xyz)
+				+ self.rz.anti_geometric(rhs.x)
+				+ self.uw.anti_geometric(rhs.zxw)
+				+ self.ux.anti_geometric(rhs.xyw)
+				+ self.uy.anti_geometric(rhs.w)
+				+ self.uz.anti_geometric(rhs.yzw),
+			z: self.rw.anti_geometric(rhs.z)
+				+ self.rx.anti_geometric(rhs.y)
+				+ self.ry.anti_geometric(rhs.x)
+				+ self.rz.anti_geometric(rhs.xyz)
+				+ self.uw.anti_geometric(rhs.xyw)
+				+ self.ux.anti_geometric(rhs.zxw)
+				+ self.uy.anti_geometric(rhs.yzw)
+				+ self.uz.anti_geometric(rhs.w),
+			w: self.rw.anti_geometric(rhs.w)
+				+ self.rx.anti_geometric(rhs.yzw)
+				+ self.ry.anti_geometric(rhs.zxw)
+				+ self.rz.anti_geometric(rhs.xyw),
+			wx: self.rw.anti_geometric(rhs.wx)
+				+ self.rx.anti_geometric(rhs.xyzw)
+				+ self.ry.anti_geometric(rhs.wz)
+				+ self.rz.anti_geometric(rhs.wy),
+			wy: self.rw.anti_geometric(rhs.wy)
+				+ self.rx.anti_geometric(rhs.wz)
+				+ self.ry.anti_geometric(rhs.xyzw)
+				+ self.rz.anti_geometric(rhs.wx),
+			wz: self.rw.anti_geometric(rhs.wz)
+				+ self.rx.anti_geometric(rhs.wy)
+				+ self.ry.anti_geometric(rhs.wx)
+				+ self.rz.anti_geometric(rhs.xyzw),
+			yz: self.rw.anti_geometric(rhs.yz)
+				+ self.rx.anti_geometric(rhs.s)
+				+ self.ry.anti_geometric(rhs.xy)
+				+ self.rz.anti_geometric(rhs.zx)
+				+ self.uw.anti_geometric(rhs.wx)
+				+ self.ux.anti_geometric(rhs.xyzw)
+				+ self.uy.anti_geometric(rhs.wz)
+				+ self.uz.anti_geometric(rhs.wy),
+			zx: self.rw.anti_geometric(rhs.zx)
+				+ self.rx.anti_geometric(rhs.xy)
+				+ self.ry.anti_geometric(rhs.s)
+				+ self.rz.anti_geometric(rhs.yz)
+				+ self.uw.anti_geometric(rhs.wy)
+				+ self.ux.anti_geometric(rhs.wz)
+				+ self.uy.anti_geometric(rhs.xyzw)
+				+ self.uz.anti_geometric(rhs.wx),
+			xy: self.rw.anti_geometric(rhs.xy)
+				+ self.rx.anti_geometric(rhs.zx)
+				+ self.ry.anti_geometric(rhs.yz)
+				+ self.rz.anti_geometric(rhs.s)
+				+ self.uw.anti_geometric(rhs.wz)
+				+ self.ux.anti_geometric(rhs.wy)
+				+ self.uy.anti_geometric(rhs.wx)
+				+ self.uz.anti_geometric(rhs.xyzw),
+			yzw: self.rw.anti_geometric(rhs.yzw)
+				+ self.rx.anti_geometric(rhs.w)
+				+ self.ry.anti_geometric(rhs.xyw)
+				+ self.rz.anti_geometric(rhs.zxw),
+			zxw: self.rw.anti_geometric(rhs.zxw)
+				+ self.rx.anti_geometric(rhs.xyw)
+				+ self.ry.anti_geometric(rhs.w)
+				+ self.rz.anti_geometric(rhs.yzw),
+			xyw: self.rw.anti_geometric(rhs.xyw)
+				+ self.rx.anti_geometric(rhs.zxw)
+				+ self.ry.anti_geometric(rhs.yzw)
+				+ self.rz.anti_geometric(rhs.w),
+			xyz: self.rw.anti_geometric(rhs.xyz)
+				+ self.rx.anti_geometric(rhs.x)
+				+ self.ry.anti_geometric(rhs.y)
+				+ self.rz.anti_geometric(rhs.z)
+				+ self.uw.anti_geometric(rhs.w)
+				+ self.ux.anti_geometric(rhs.yzw)
+				+ self.uy.anti_geometric(rhs.zxw)
+				+ self.uz.anti_geometric(rhs.xyw),
+			xyzw: self.rw.anti_geometric(rhs.xyzw)
+				+ self.rx.anti_geometric(rhs.wx)
+				+ self.ry.anti_geometric(rhs.wy)
+				+ self.rz.anti_geometric(rhs.wz),
+		}
+	}
+}
+
+// Motor3.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.uw.0 * rhs.s.0) - S(self.ux.0 * rhs.yz.0) - S(self.uy.0 * rhs.zx.0) - S(self.uz.0 * rhs.xy.0),
+		//     x   : X(self.uw.0 * rhs.x.0) - X(self.ux.0 * rhs.xyz.0) - X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.uw.0 * rhs.y.0) + Y(self.ux.0 * rhs.z.0) - Y(self.uy.0 * rhs.xyz.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : Z(self.uw.0 * rhs.z.0) - Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0) - Z(self.uz.0 * rhs.xyz.0),
+		//     w   : W(self.rw.0 * rhs.xyz.0) + W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0) + W(self.uw.0 * rhs.w.0) - W(self.ux.0 * rhs.yzw.0) - W(self.uy.0 * rhs.zxw.0) - W(self.uz.0 * rhs.xyw.0),
+		//     wx  : WX(self.rw.0 * rhs.yz.0) + WX(self.rx.0 * rhs.s.0) + WX(self.uw.0 * rhs.wx.0) + WX(self.ux.0 * rhs.xyzw.0),
+		//     wy  : WY(self.rw.0 * rhs.zx.0) + WY(self.ry.0 * rhs.s.0) + WY(self.uw.0 * rhs.wy.0) + WY(self.uy.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.rw.0 * rhs.xy.0) + WZ(self.rz.0 * rhs.s.0) + WZ(self.uw.0 * rhs.wz.0) + WZ(self.uz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.uw.0 * rhs.yz.0) + YZ(self.ux.0 * rhs.s.0),
+		//     zx  : ZX(self.uw.0 * rhs.zx.0) + ZX(self.uy.0 * rhs.s.0),
+		//     xy  : XY(self.uw.0 * rhs.xy.0) + XY(self.uz.0 * rhs.s.0),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0) + YZW(self.uw.0 * rhs.yzw.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0) + ZXW(self.uw.0 * rhs.zxw.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0) + XYW(self.uw.0 * rhs.xyw.0),
+		//     xyz : XYZ(self.uw.0 * rhs.xyz.0),
+		//     xyzw: XYZW(self.rw.0 * rhs.s.0) + XYZW(self.uw.0 * rhs.xyzw.0),
+		// }
+		Multivector {
+			s: self.uw.dot(rhs.s) + self.ux.dot(rhs.yz) + self.uy.dot(rhs.zx) + self.uz.dot(rhs.xy),
+			x: self.uw.dot(rhs.x) + self.ux.dot(rhs.xyz) + self.uy.dot(rhs.z) + self.uz.dot(rhs.y),
+			y: self.uw.dot(rhs.y) + self.ux.dot(rhs.z) + self.uy.dot(rhs.xyz) + self.uz.dot(rhs.x),
+			z: self.uw.dot(rhs.z) + self.ux.dot(rhs.y) + self.uy.dot(rhs.x) + self.uz.dot(rhs.xyz),
+			w: self.rw.dot(rhs.xyz)
+				+ self.rx.dot(rhs.x)
+				+ self.ry.dot(rhs.y)
+				+ self.rz.dot(rhs.z)
+				+ self.uw.dot(rhs.w)
+				+ self.ux.dot(rhs.yzw)
+				+ self.uy.dot(rhs.zxw)
+				+ self.uz.dot(rhs.xyw),
+			wx: self.rw.dot(rhs.yz) + self.rx.dot(rhs.s) + self.uw.dot(rhs.wx) + self.ux.dot(rhs.xyzw),
+			wy: self.rw.dot(rhs.zx) + self.ry.dot(rhs.s) + self.uw.dot(rhs.wy) + self.uy.dot(rhs.xyzw),
+			wz: self.rw.dot(rhs.xy) + self.rz.dot(rhs.s) + self.uw.dot(rhs.wz) + self.uz.dot(rhs.xyzw),
+			yz: self.uw.dot(rhs.yz) + self.ux.dot(rhs.s),
+			zx: self.uw.dot(rhs.zx) + self.uy.dot(rhs.s),
+			xy: self.uw.dot(rhs.xy) + self.uz.dot(rhs.s),
+			yzw: self.rw.dot(rhs.x) + self.uw.dot(rhs.yzw),
+			zxw: self.rw.dot(rhs.y) + self.uw.dot(rhs.zxw),
+			xyw: self.rw.dot(rhs.z) + self.uw.dot(rhs.xyw),
+			xyz: self.uw.dot(rhs.xyz),
+			xyzw: self.rw.dot(rhs.s) + self.uw.dot(rhs.xyzw),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Motor3.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.uw.0 * rhs.s.0),
+		//     x   : X(self.uw.0 * rhs.x.0),
+		//     y   : Y(self.uw.0 * rhs.y.0),
+		//     z   : Z(self.uw.0 * rhs.z.0),
+		//     w   : W(self.uw.0 * rhs.w.0),
+		//     wx  : WX(self.rx.0 * rhs.s.0) + WX(self.uw.0 * rhs.wx.0),
+		//     wy  : WY(self.ry.0 * rhs.s.0) + WY(self.uw.0 * rhs.wy.0),
+		//     wz  : WZ(self.rz.0 * rhs.s.0) + WZ(self.uw.0 * rhs.wz.0),
+		//     yz  : YZ(self.uw.0 * rhs.yz.0) + YZ(self.ux.0 * rhs.s.0),
+		//     zx  : ZX(self.uw.0 * rhs.zx.0) + ZX(self.uy.0 * rhs.s.0),
+		//     xy  : XY(self.uw.0 * rhs.xy.0) + XY(self.uz.0 * rhs.s.0),
+		//     yzw : YZW(self.ry.0 * rhs.z.0) - YZW(self.rz.0 * rhs.y.0) + YZW(self.uw.0 * rhs.yzw.0) + YZW(self.ux.0 * rhs.w.0),
+		//     zxw : -ZXW(self.rx.0 * rhs.z.0) + ZXW(self.rz.0 * rhs.x.0) + ZXW(self.uw.0 * rhs.zxw.0) + ZXW(self.uy.0 * rhs.w.0),
+		//     xyw : XYW(self.rx.0 * rhs.y.0) - XYW(self.ry.0 * rhs.x.0) + XYW(self.uw.0 * rhs.xyw.0) + XYW(self.uz.0 * rhs.w.0),
+		//     xyz : XYZ(self.uw.0 * rhs.xyz.0) + XYZ(self.ux.0 * rhs.x.0) + XYZ(self.uy.0 * rhs.y.0) + XYZ(self.uz.0 * rhs.z.0),
+		//     xyzw: XYZW(self.rw.0 * rhs.s.0) - XYZW(self.rx.0 * rhs.yz.0) - XYZW(self.ry.0 * rhs.zx.0) - XYZW(self.rz.0 * rhs.xy.0) + XYZW(self.uw.0 * rhs.xyzw.0) - XYZW(self.ux.0 * rhs.wx.0) - XYZW(self.uy.0 * rhs.wy.0) - XYZW(self.uz.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: self.uw.wedge(rhs.s),
+			x: self.uw.wedge(rhs.x),
+			y: self.uw.wedge(rhs.y),
+			z: self.uw.wedge(rhs.z),
+			w: self.uw.wedge(rhs.w),
+			wx: self.rx.wedge(rhs.s) + self.uw.wedge(rhs.wx),
+			wy: self.ry.wedge(rhs.s) + self.uw.wedge(rhs.wy),
+			wz: self.rz.wedge(rhs.s) + self.uw.wedge(rhs.wz),
+			yz: self.uw.wedge(rhs.yz) + self.ux.wedge(rhs.s),
+			zx: self.uw.wedge(rhs.zx) + self.uy.wedge(rhs.s),
+			xy: self.uw.wedge(rhs.xy) + self.uz.wedge(rhs.s),
+			yzw: self.ry.wedge(rhs.z) + self.rz.wedge(rhs.y) + self.uw.wedge(rhs.yzw) + self.ux.wedge(rhs.w),
+			zxw: self.rx.wedge(rhs.z) + self.rz.wedge(rhs.x) + self.uw.wedge(rhs.zxw) + self.uy.wedge(rhs.w),
+			xyw: self.rx.wedge(rhs.y) + self.ry.wedge(rhs.x) + self.uw.wedge(rhs.xyw) + self.uz.wedge(rhs.w),
+			xyz: self.uw.wedge(rhs.xyz) + self.ux.wedge(rhs.x) + self.uy.wedge(rhs.y) + self.uz.wedge(rhs.z),
+			xyzw: self.rw.wedge(rhs.s)
+				+ self.rx.wedge(rhs.yz)
+				+ self.ry.wedge(rhs.zx)
+				+ self.rz.wedge(rhs.xy)
+				+ self.uw.wedge(rhs.xyzw)
+				+ self.ux.wedge(rhs.wx)
+				+ self.uy.wedge(rhs.wy)
+				+ self.uz.wedge(rhs.wz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Motor3.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.rw.0 * rhs.s.0) - S(self.rx.0 * rhs.yz.0) - S(self.ry.0 * rhs.zx.0) - S(self.rz.0 * rhs.xy.0) + S(self.uw.0 * rhs.xyzw.0) - S(self.ux.0 * rhs.wx.0) - S(self.uy.0 * rhs.wy.0) - S(self.uz.0 * rhs.wz.0),
+		//     x   : X(self.rw.0 * rhs.x.0) - X(self.rx.0 * rhs.xyz.0) + X(self.uy.0 * rhs.xyw.0) - X(self.uz.0 * rhs.zxw.0),
+		//     y   : Y(self.rw.0 * rhs.y.0) - Y(self.ry.0 * rhs.xyz.0) - Y(self.ux.0 * rhs.xyw.0) + Y(self.uz.0 * rhs.yzw.0),
+		//     z   : Z(self.rw.0 * rhs.z.0) - Z(self.rz.0 * rhs.xyz.0) + Z(self.ux.0 * rhs.zxw.0) - Z(self.uy.0 * rhs.yzw.0),
+		//     w   : W(self.rw.0 * rhs.w.0) - W(self.rx.0 * rhs.yzw.0) - W(self.ry.0 * rhs.zxw.0) - W(self.rz.0 * rhs.xyw.0),
+		//     wx  : WX(self.rw.0 * rhs.wx.0) + WX(self.rx.0 * rhs.xyzw.0),
+		//     wy  : WY(self.rw.0 * rhs.wy.0) + WY(self.ry.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.rw.0 * rhs.wz.0) + WZ(self.rz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.rw.0 * rhs.yz.0) + YZ(self.ux.0 * rhs.xyzw.0),
+		//     zx  : ZX(self.rw.0 * rhs.zx.0) + ZX(self.uy.0 * rhs.xyzw.0),
+		//     xy  : XY(self.rw.0 * rhs.xy.0) + XY(self.uz.0 * rhs.xyzw.0),
+		//     yzw : YZW(self.rw.0 * rhs.yzw.0),
+		//     zxw : ZXW(self.rw.0 * rhs.zxw.0),
+		//     xyw : XYW(self.rw.0 * rhs.xyw.0),
+		//     xyz : XYZ(self.rw.0 * rhs.xyz.0),
+		//     xyzw: XYZW(self.rw.0 * rhs.xyzw.0),
+		// }
+		Multivector {
+			s: self.rw.anti_wedge(rhs.s)
+				+ self.rx.anti_wedge(rhs.yz)
+				+ self.ry.anti_wedge(rhs.zx)
+				+ self.rz.anti_wedge(rhs.xy)
+				+ self.uw.anti_wedge(rhs.xyzw)
+				+ self.ux.anti_wedge(rhs.wx)
+				+ self.uy.anti_wedge(rhs.wy)
+				+ self.uz.anti_wedge(rhs.wz),
+			x: self.rw.anti_wedge(rhs.x)
+				+ self.rx.anti_wedge(rhs.xyz)
+				+ self.uy.anti_wedge(rhs.xyw)
+				+ self.uz.anti_wedge(rhs.zxw),
+			y: self.rw.anti_wedge(rhs.y)
+				+ self.ry.anti_wedge(rhs.xyz)
+				+ self.ux.anti_wedge(rhs.xyw)
+				+ self.uz.anti_wedge(rhs.yzw),
+			z: self.rw.anti_wedge(rhs.z)
+				+ self.rz.anti_wedge(rhs.xyz)
+				+ self.ux.anti_wedge(rhs.zxw)
+				+ self.uy.anti_wedge(rhs.yzw),
+			w: self.rw.anti_wedge(rhs.w)
+				+ self.rx.anti_wedge(rhs.yzw)
+				+ self.ry.anti_wedge(rhs.zxw)
+				+ self.rz.anti_wedge(rhs.xyw),
+			wx: self.rw.anti_wedge(rhs.wx) + self.rx.anti_wedge(rhs.xyzw),
+			wy: self.rw.anti_wedge(rhs.wy) + self.ry.anti_wedge(rhs.xyzw),
+			wz: self.rw.anti_wedge(rhs.wz) + self.rz.anti_wedge(rhs.xyzw),
+			yz: self.rw.anti_wedge(rhs.yz) + self.ux.anti_wedge(rhs.xyzw),
+			zx: self.rw.anti_wedge(rhs.zx) + self.uy.anti_wedge(rhs.xyzw),
+			xy: self.rw.anti_wedge(rhs.xy) + self.uz.anti_wedge(rhs.xyzw),
+			yzw: self.rw.anti_wedge(rhs.yzw),
+			zxw: self.rw.anti_wedge(rhs.zxw),
+			xyw: self.rw.anti_wedge(rhs.xyw),
+			xyz: self.rw.anti_wedge(rhs.xyz),
+			xyzw: self.rw.anti_wedge(rhs.xyzw),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Motor3.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.uw.0 * rhs.s.0) - S(self.ux.0 * rhs.yz.0) - S(self.uy.0 * rhs.zx.0) - S(self.uz.0 * rhs.xy.0),
+		//     x   : X(self.uw.0 * rhs.x.0) - X(self.ux.0 * rhs.xyz.0),
+		//     y   : Y(self.uw.0 * rhs.y.0) - Y(self.uy.0 * rhs.xyz.0),
+		//     z   : Z(self.uw.0 * rhs.z.0) - Z(self.uz.0 * rhs.xyz.0),
+		//     w   : W(self.uw.0 * rhs.w.0) - W(self.ux.0 * rhs.yzw.0) - W(self.uy.0 * rhs.zxw.0) - W(self.uz.0 * rhs.xyw.0),
+		//     wx  : WX(self.uw.0 * rhs.wx.0) + WX(self.ux.0 * rhs.xyzw.0),
+		//     wy  : WY(self.uw.0 * rhs.wy.0) + WY(self.uy.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.uw.0 * rhs.wz.0) + WZ(self.uz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.uw.0 * rhs.yz.0),
+		//     zx  : ZX(self.uw.0 * rhs.zx.0),
+		//     xy  : XY(self.uw.0 * rhs.xy.0),
+		//     yzw : YZW(self.uw.0 * rhs.yzw.0),
+		//     zxw : ZXW(self.uw.0 * rhs.zxw.0),
+		//     xyw : XYW(self.uw.0 * rhs.xyw.0),
+		//     xyz : XYZ(self.uw.0 * rhs.xyz.0),
+		//     xyzw: XYZW(self.uw.0 * rhs.xyzw.0),
+		// }
+		Multivector {
+			s: self.uw.left_contraction(rhs.s)
+				+ self.ux.left_contraction(rhs.yz)
+				+ self.uy.left_contraction(rhs.zx)
+				+ self.uz.left_contraction(rhs.xy),
+			x: self.uw.left_contraction(rhs.x) + self.ux.left_contraction(rhs.xyz),
+			y: self.uw.left_contraction(rhs.y) + self.uy.left_contraction(rhs.xyz),
+			z: self.uw.left_contraction(rhs.z) + self.uz.left_contraction(rhs.xyz),
+			w: self.uw.left_contraction(rhs.w)
+				+ self.ux.left_contraction(rhs.yzw)
+				+ self.uy.left_contraction(rhs.zxw)
+				+ self.uz.left_contraction(rhs.xyw),
+			wx: self.uw.left_contraction(rhs.wx) + self.ux.left_contraction(rhs.xyzw),
+			wy: self.uw.left_contraction(rhs.wy) + self.uy.left_contraction(rhs.xyzw),
+			wz: self.uw.left_contraction(rhs.wz) + self.uz.left_contraction(rhs.xyzw),
+			yz: self.uw.left_contraction(rhs.yz),
+			zx: self.uw.left_contraction(rhs.zx),
+			xy: self.uw.left_contraction(rhs.xy),
+			yzw: self.uw.left_contraction(rhs.yzw),
+			zxw: self.uw.left_contraction(rhs.zxw),
+			xyw: self.uw.left_contraction(rhs.xyw),
+			xyz: self.uw.left_contraction(rhs.xyz),
+			xyzw: self.uw.left_contraction(rhs.xyzw),
+		}
+	}
+}
+
+// Motor3.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.uw.0 * rhs.s.0) - S(self.ux.0 * rhs.yz.0) - S(self.uy.0 * rhs.zx.0) - S(self.uz.0 * rhs.xy.0),
+		//     x   : -X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : -Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rw.0 * rhs.xyz.0) + W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0),
+		//     wx  : WX(self.rw.0 * rhs.yz.0) + WX(self.rx.0 * rhs.s.0),
+		//     wy  : WY(self.rw.0 * rhs.zx.0) + WY(self.ry.0 * rhs.s.0),
+		//     wz  : WZ(self.rw.0 * rhs.xy.0) + WZ(self.rz.0 * rhs.s.0),
+		//     yz  : YZ(self.ux.0 * rhs.s.0),
+		//     zx  : ZX(self.uy.0 * rhs.s.0),
+		//     xy  : XY(self.uz.0 * rhs.s.0),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.rw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.uw.right_contraction(rhs.s)
+				+ self.ux.right_contraction(rhs.yz)
+				+ self.uy.right_contraction(rhs.zx)
+				+ self.uz.right_contraction(rhs.xy),
+			x: self.uy.right_contraction(rhs.z) + self.uz.right_contraction(rhs.y),
+			y: self.ux.right_contraction(rhs.z) + self.uz.right_contraction(rhs.x),
+			z: self.ux.right_contraction(rhs.y) + self.uy.right_contraction(rhs.x),
+			w: self.rw.right_contraction(rhs.xyz)
+				+ self.rx.right_contraction(rhs.x)
+				+ self.ry.right_contraction(rhs.y)
+				+ self.rz.right_contraction(rhs.z),
+			wx: self.rw.right_contraction(rhs.yz) + self.rx.right_contraction(rhs.s),
+			wy: self.rw.right_contraction(rhs.zx) + self.ry.right_contraction(rhs.s),
+			wz: self.rw.right_contraction(rhs.xy) + self.rz.right_contraction(rhs.s),
+			yz: self.ux.right_contraction(rhs.s),
+			zx: self.uy.right_contraction(rhs.s),
+			xy: self.uz.right_contraction(rhs.s),
+			yzw: self.rw.right_contraction(rhs.x),
+			zxw: self.rw.right_contraction(rhs.y),
+			xyw: self.rw.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: self.rw.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Motor3.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Motor3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.uw.0 * rhs.s.0) - S(self.ux.0 * rhs.yz.0) - S(self.uy.0 * rhs.zx.0) - S(self.uz.0 * rhs.xy.0)
+		self.uw.scalar_product(rhs.s)
+			+ self.ux.scalar_product(rhs.yz)
+			+ self.uy.scalar_product(rhs.zx)
+			+ self.uz.scalar_product(rhs.xy)
+	}
+}
+
+// Motor3.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.uy.0 * rhs.z.0) + X(self.uz.0 * rhs.y.0),
+		//     y   : Y(self.ux.0 * rhs.z.0) - Y(self.uz.0 * rhs.x.0),
+		//     z   : -Z(self.ux.0 * rhs.y.0) + Z(self.uy.0 * rhs.x.0),
+		//     w   : W(self.rw.0 * rhs.xyz.0) + W(self.rx.0 * rhs.x.0) + W(self.ry.0 * rhs.y.0) + W(self.rz.0 * rhs.z.0),
+		//     wx  : -WX(self.ry.0 * rhs.xy.0) + WX(self.rz.0 * rhs.zx.0) - WX(self.uy.0 * rhs.wz.0) + WX(self.uz.0 * rhs.wy.0),
+		//     wy  : WY(self.rx.0 * rhs.xy.0) - WY(self.rz.0 * rhs.yz.0) + WY(self.ux.0 * rhs.wz.0) - WY(self.uz.0 * rhs.wx.0),
+		//     wz  : -WZ(self.rx.0 * rhs.zx.0) + WZ(self.ry.0 * rhs.yz.0) - WZ(self.ux.0 * rhs.wy.0) + WZ(self.uy.0 * rhs.wx.0),
+		//     yz  : -YZ(self.uy.0 * rhs.xy.0) + YZ(self.uz.0 * rhs.zx.0),
+		//     zx  : ZX(self.ux.0 * rhs.xy.0) - ZX(self.uz.0 * rhs.yz.0),
+		//     xy  : -XY(self.ux.0 * rhs.zx.0) + XY(self.uy.0 * rhs.yz.0),
+		//     yzw : -YZW(self.rw.0 * rhs.x.0) + YZW(self.rx.0 * rhs.xyz.0) - YZW(self.uy.0 * rhs.xyw.0) + YZW(self.uz.0 * rhs.zxw.0),
+		//     zxw : -ZXW(self.rw.0 * rhs.y.0) + ZXW(self.ry.0 * rhs.xyz.0) + ZXW(self.ux.0 * rhs.xyw.0) - ZXW(self.uz.0 * rhs.yzw.0),
+		//     xyw : -XYW(self.rw.0 * rhs.z.0) + XYW(self.rz.0 * rhs.xyz.0) - XYW(self.ux.0 * rhs.zxw.0) + XYW(self.uy.0 * rhs.yzw.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.uy.commutator(rhs.z) + self.uz.commutator(rhs.y),
+			y: self.ux.commutator(rhs.z) + self.uz.commutator(rhs.x),
+			z: self.ux.commutator(rhs.y) + self.uy.commutator(rhs.x),
+			w: self.rw.commutator(rhs.xyz)
+				+ self.rx.commutator(rhs.x)
+				+ self.ry.commutator(rhs.y)
+				+ self.rz.commutator(rhs.z),
+			wx: self.ry.commutator(rhs.xy)
+				+ self.rz.commutator(rhs.zx)
+				+ self.uy.commutator(rhs.wz)
+				+ self.uz.commutator(rhs.wy),
+			wy: self.rx.commutator(rhs.xy)
+				+ self.rz.commutator(rhs.yz)
+				+ self.ux.commutator(rhs.wz)
+				+ self.uz.commutator(rhs.wx),
+			wz: self.rx.commutator(rhs.zx)
+				+ self.ry.commutator(rhs.yz)
+				+ self.ux.commutator(rhs.wy)
+				+ self.uy.commutator(rhs.wx),
+			yz: self.uy.commutator(rhs.xy) + self.uz.commutator(rhs.zx),
+			zx: self.ux.commutator(rhs.xy) + self.uz.commutator(rhs.yz),
+			xy: self.ux.commutator(rhs.zx) + self.uy.commutator(rhs.yz),
+			yzw: self.rw.commutator(rhs.x)
+				+ self.rx.commutator(rhs.xyz)
+				+ self.uy.commutator(rhs.xyw)
+				+ self.uz.commutator(rhs.zxw),
+			zxw: self.rw.commutator(rhs.y)
+				+ self.ry.commutator(rhs.xyz)
+				+ self.ux.commutator(rhs.xyw)
+				+ self.uz.commutator(rhs.yzw),
+			xyw: self.rw.commutator(rhs.z)
+				+ self.rz.commutator(rhs.xyz)
+				+ self.ux.commutator(rhs.zxw)
+				+ self.uy.commutator(rhs.yzw),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Motor3.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Motor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.rw.0 * rhs.s.0) + S(self.uw.0 * rhs.xyzw.0),
+		//     x   : X(self.rw.0 * rhs.x.0) + X(self.uw.0 * rhs.yzw.0),
+		//     y   : Y(self.rw.0 * rhs.y.0) + Y(self.uw.0 * rhs.zxw.0),
+		//     z   : Z(self.rw.0 * rhs.z.0) + Z(self.uw.0 * rhs.xyw.0),
+		//     w   : W(self.rw.0 * rhs.w.0),
+		//     wx  : WX(self.rw.0 * rhs.wx.0) + WX(self.rx.0 * rhs.xyzw.0),
+		//     wy  : WY(self.rw.0 * rhs.wy.0) + WY(self.ry.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.rw.0 * rhs.wz.0) + WZ(self.rz.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.rw.0 * rhs.yz.0) + YZ(self.rx.0 * rhs.s.0) + YZ(self.uw.0 * rhs.wx.0) + YZ(self.ux.0 * rhs.xyzw.0),
+		//     zx  : ZX(self.rw.0 * rhs.zx.0) + ZX(self.ry.0 * rhs.s.0) + ZX(self.uw.0 * rhs.wy.0) + ZX(self.uy.0 * rhs.xyzw.0),
+		//     xy  : XY(self.rw.0 * rhs.xy.0) + XY(self.rz.0 * rhs.s.0) + XY(self.uw.0 * rhs.wz.0) + XY(self.uz.0 * rhs.xyzw.0),
+		//     yzw : YZW(self.rw.0 * rhs.yzw.0) + YZW(self.rx.0 * rhs.w.0) + YZW(self.ry.0 * rhs.xyw.0) - YZW(self.rz.0 * rhs.zxw.0),
+		//     zxw : ZXW(self.rw.0 * rhs.zxw.0) - ZXW(self.rx.0 * rhs.xyw.0) + ZXW(self.ry.0 * rhs.w.0) + ZXW(self.rz.0 * rhs.yzw.0),
+		//     xyw : XYW(self.rw.0 * rhs.xyw.0) + XYW(self.rx.0 * rhs.zxw.0) - XYW(self.ry.0 * rhs.yzw.0) + XYW(self.rz.0 * rhs.w.0),
+		//     xyz : XYZ(self.rw.0 * rhs.xyz.0) + XYZ(self.rx.0 * rhs.x.0) + XYZ(self.ry.0 * rhs.y.0) + XYZ(self.rz.0 * rhs.z.0) - XYZ(self.uw.0 * rhs.w.0) + XYZ(self.ux.0 * rhs.yzw.0) + XYZ(self.uy.0 * rhs.zxw.0) + XYZ(self.uz.0 * rhs.xyw.0),
+		//     xyzw: XYZW(self.rw.0 * rhs.xyzw.0) - XYZW(self.rx.0 * rhs.wx.0) - XYZW(self.ry.0 * rhs.wy.0) - XYZW(self.rz.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: self.rw.anti_dot(rhs.s) + self.uw.anti_dot(rhs.xyzw),
+			x: self.rw.anti_dot(rhs.x) + self.uw.anti_dot(rhs.yzw),
+			y: self.rw.anti_dot(rhs.y) + self.uw.anti_dot(rhs.zxw),
+			z: self.rw.anti_dot(rhs.z) + self.uw.anti_dot(rhs.xyw),
+			w: self.rw.anti_dot(rhs.w),
+			wx: self.rw.anti_dot(rhs.wx) + self.rx.anti_dot(rhs.xyzw),
+			wy: self.rw.anti_dot(rhs.wy) + self.ry.anti_dot(rhs.xyzw),
+			wz: self.rw.anti_dot(rhs.wz) + self.rz.anti_dot(rhs.xyzw),
+			yz: self.rw.anti_dot(rhs.yz)
+				+ self.rx.anti_dot(rhs.s)
+				+ self.uw.anti_dot(rhs.wx)
+				+ self.ux.anti_dot(rhs.xyzw),
+			zx: self.rw.anti_dot(rhs.zx)
+				+ self.ry.anti_dot(rhs.s)
+				+ self.uw.anti_dot(rhs.wy)
+				+ self.uy.anti_dot(rhs.xyzw),
+			xy: self.rw.anti_dot(rhs.xy)
+				+ self.rz.anti_dot(rhs.s)
+				+ self.uw.anti_dot(rhs.wz)
+				+ self.uz.anti_dot(rhs.xyzw),
+			yzw: self.rw.anti_dot(rhs.yzw)
+				+ self.rx.anti_dot(rhs.w)
+				+ self.ry.anti_dot(rhs.xyw)
+				+ self.rz.anti_dot(rhs.zxw),
+			zxw: self.rw.anti_dot(rhs.zxw)
+				+ self.rx.anti_dot(rhs.xyw)
+				+ self.ry.anti_dot(rhs.w)
+				+ self.rz.anti_dot(rhs.yzw),
+			xyw: self.rw.anti_dot(rhs.xyw)
+				+ self.rx.anti_dot(rhs.zxw)
+				+ self.ry.anti_dot(rhs.yzw)
+				+ self.rz.anti_dot(rhs.w),
+			xyz: self.rw.anti_dot(rhs.xyz)
+				+ self.rx.anti_dot(rhs.x)
+				+ self.ry.anti_dot(rhs.y)
+				+ self.rz.anti_dot(rhs.z)
+				+ self.uw.anti_dot(rhs.w)
+				+ self.ux.anti_dot(rhs.yzw)
+				+ self.uy.anti_dot(rhs.zxw)
+				+ self.uz.anti_dot(rhs.xyw),
+			xyzw: self.rw.anti_dot(rhs.xyzw)
+				+ self.rx.anti_dot(rhs.wx)
+				+ self.ry.anti_dot(rhs.wy)
+				+ self.rz.anti_dot(rhs.wz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Motor3.sandwich(Vec3)  (not a Vec3)
+
+// Motor3.anti_sandwich(Vec3) -> Vec3
+impl AntiSandwich<Vec3> for Motor3 {
+	fn anti_sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: self.rw.anti_geometric(self.rw).anti_geometric(rhs.x)
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.z) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.y) * 2.0
+				- self.rx.anti_geometric(self.rx).anti_geometric(rhs.x)
+				- self.rx.anti_geometric(self.ry).anti_geometric(rhs.y) * 2.0
+				- self.rx.anti_geometric(self.rz).anti_geometric(rhs.z) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.x)
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.x),
+			y: self.rw.anti_geometric(self.rw).anti_geometric(rhs.y)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.z) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.x) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.y)
+				+ self.rx.anti_geometric(self.ry).anti_geometric(rhs.x) * 2.0
+				- self.ry.anti_geometric(self.ry).anti_geometric(rhs.y)
+				- self.ry.anti_geometric(self.rz).anti_geometric(rhs.z) * 2.0
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.y),
+			z: self.rw.anti_geometric(self.rw).anti_geometric(rhs.z)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.y) * 2.0
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.x) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.z)
+				+ self.rx.anti_geometric(self.rz).anti_geometric(rhs.x) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.z)
+				+ self.ry.anti_geometric(self.rz).anti_geometric(rhs.y) * 2.0
+				- self.rz.anti_geometric(self.rz).anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Motor3.sandwich(Vec4) -> Vec4
+impl Sandwich<Vec4> for Motor3 {
+	fn sandwich(self, rhs: Vec4) -> Vec4 {
+		Vec4 {
+			x: self.uw.geometric(self.uw).geometric(rhs.x)
+				+ self.uw.geometric(self.uy).geometric(rhs.z) * 2.0
+				+ self.uw.geometric(self.uz).geometric(rhs.y) * 2.0
+				- self.ux.geometric(self.ux).geometric(rhs.x)
+				- self.ux.geometric(self.uy).geometric(rhs.y) * 2.0
+				- self.ux.geometric(self.uz).geometric(rhs.z) * 2.0
+				+ self.uy.geometric(self.uy).geometric(rhs.x)
+				+ self.uz.geometric(self.uz).geometric(rhs.x),
+			y: self.uw.geometric(self.uw).geometric(rhs.y)
+				+ self.uw.geometric(self.ux).geometric(rhs.z) * 2.0
+				+ self.uw.geometric(self.uz).geometric(rhs.x) * 2.0
+				+ self.ux.geometric(self.ux).geometric(rhs.y)
+				+ self.ux.geometric(self.uy).geometric(rhs.x) * 2.0
+				- self.uy.geometric(self.uy).geometric(rhs.y)
+				- self.uy.geometric(self.uz).geometric(rhs.z) * 2.0
+				+ self.uz.geometric(self.uz).geometric(rhs.y),
+			z: self.uw.geometric(self.uw).geometric(rhs.z)
+				+ self.uw.geometric(self.ux).geometric(rhs.y) * 2.0
+				+ self.uw.geometric(self.uy).geometric(rhs.x) * 2.0
+				+ self.ux.geometric(self.ux).geometric(rhs.z)
+				+ self.ux.geometric(self.uz).geometric(rhs.x) * 2.0
+				+ self.uy.geometric(self.uy).geometric(rhs.z)
+				+ self.uy.geometric(self.uz).geometric(rhs.y) * 2.0
+				- self.uz.geometric(self.uz).geometric(rhs.z),
+			w: self.rw.geometric(self.ux).geometric(rhs.x) * -2.0
+				- self.rw.geometric(self.uy).geometric(rhs.y) * 2.0
+				- self.rw.geometric(self.uz).geometric(rhs.z) * 2.0
+				+ self.rx.geometric(self.uw).geometric(rhs.x) * 2.0
+				+ self.rx.geometric(self.uy).geometric(rhs.z) * 2.0
+				+ self.rx.geometric(self.uz).geometric(rhs.y) * 2.0
+				+ self.ry.geometric(self.uw).geometric(rhs.y) * 2.0
+				+ self.ry.geometric(self.ux).geometric(rhs.z) * 2.0
+				+ self.ry.geometric(self.uz).geometric(rhs.x) * 2.0
+				+ self.rz.geometric(self.uw).geometric(rhs.z) * 2.0
+				+ self.rz.geometric(self.ux).geometric(rhs.y) * 2.0
+				+ self.rz.geometric(self.uy).geometric(rhs.x) * 2.0
+				+ self.uw.geometric(self.uw).geometric(rhs.w)
+				- self.ux.geometric(self.ux).geometric(rhs.w)
+				- self.uy.geometric(self.uy).geometric(rhs.w)
+				- self.uz.geometric(self.uz).geometric(rhs.w),
+		}
+	}
+}
+
+// Motor3.anti_sandwich(Vec4) -> Vec4
+impl AntiSandwich<Vec4> for Motor3 {
+	fn anti_sandwich(self, rhs: Vec4) -> Vec4 {
+		Vec4 {
+			x: self.rw.anti_geometric(self.rw).anti_geometric(rhs.x)
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.z) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.y) * 2.0
+				+ self.rw.anti_geometric(self.ux).anti_geometric(rhs.w) * 2.0
+				- self.rx.anti_geometric(self.rx).anti_geometric(rhs.x)
+				- self.rx.anti_geometric(self.ry).anti_geometric(rhs.y) * 2.0
+				- self.rx.anti_geometric(self.rz).anti_geometric(rhs.z) * 2.0
+				- self.rx.anti_geometric(self.uw).anti_geometric(rhs.w) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.x)
+				+ self.ry.anti_geometric(self.uz).anti_geometric(rhs.w) * 2.0
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.x)
+				+ self.rz.anti_geometric(self.uy).anti_geometric(rhs.w) * 2.0,
+			y: self.rw.anti_geometric(self.rw).anti_geometric(rhs.y)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.z) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.x) * 2.0
+				+ self.rw.anti_geometric(self.uy).anti_geometric(rhs.w) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.y)
+				+ self.rx.anti_geometric(self.ry).anti_geometric(rhs.x) * 2.0
+				+ self.rx.anti_geometric(self.uz).anti_geometric(rhs.w) * 2.0
+				- self.ry.anti_geometric(self.ry).anti_geometric(rhs.y)
+				- self.ry.anti_geometric(self.rz).anti_geometric(rhs.z) * 2.0
+				- self.ry.anti_geometric(self.uw).anti_geometric(rhs.w) * 2.0
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.y)
+				+ self.rz.anti_geometric(self.ux).anti_geometric(rhs.w) * 2.0,
+			z: self.rw.anti_geometric(self.rw).anti_geometric(rhs.z)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.y) * 2.0
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.x) * 2.0
+				+ self.rw.anti_geometric(self.uz).anti_geometric(rhs.w) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.z)
+				+ self.rx.anti_geometric(self.rz).anti_geometric(rhs.x) * 2.0
+				+ self.rx.anti_geometric(self.uy).anti_geometric(rhs.w) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.z)
+				+ self.ry.anti_geometric(self.rz).anti_geometric(rhs.y) * 2.0
+				+ self.ry.anti_geometric(self.ux).anti_geometric(rhs.w) * 2.0
+				- self.rz.anti_geometric(self.rz).anti_geometric(rhs.z)
+				- self.rz.anti_geometric(self.uw).anti_geometric(rhs.w) * 2.0,
+			w: self.rw.anti_geometric(self.rw).anti_geometric(rhs.w)
+				- self.rx.anti_geometric(self.rx).anti_geometric(rhs.w)
+				- self.ry.anti_geometric(self.ry).anti_geometric(rhs.w)
+				- self.rz.anti_geometric(self.rz).anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Omitted: Motor3.sandwich(Point3)  (not a Point3)
+// Omitted: Motor3.anti_sandwich(Point3)  (not a Point3)
+// Omitted: Motor3.sandwich(Moment3)  (not a Moment3)
+
+// Motor3.anti_sandwich(Moment3) -> Moment3
+impl AntiSandwich<Moment3> for Motor3 {
+	fn anti_sandwich(self, rhs: Moment3) -> Moment3 {
+		Moment3 {
+			mx: self.rw.anti_geometric(self.rw).anti_geometric(rhs.mx)
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.mz) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.my) * 2.0
+				- self.rx.anti_geometric(self.rx).anti_geometric(rhs.mx)
+				- self.rx.anti_geometric(self.ry).anti_geometric(rhs.my) * 2.0
+				- self.rx.anti_geometric(self.rz).anti_geometric(rhs.mz) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.mx)
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.mx),
+			my: self.rw.anti_geometric(self.rw).anti_geometric(rhs.my)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.mz) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.mx) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.my)
+				+ self.rx.anti_geometric(self.ry).anti_geometric(rhs.mx) * 2.0
+				- self.ry.anti_geometric(self.ry).anti_geometric(rhs.my)
+				- self.ry.anti_geometric(self.rz).anti_geometric(rhs.mz) * 2.0
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.my),
+			mz: self.rw.anti_geometric(self.rw).anti_geometric(rhs.mz)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.my) * 2.0
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.mx) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.mz)
+				+ self.rx.anti_geometric(self.rz).anti_geometric(rhs.mx) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.mz)
+				+ self.ry.anti_geometric(self.rz).anti_geometric(rhs.my) * 2.0
+				- self.rz.anti_geometric(self.rz).anti_geometric(rhs.mz),
+		}
+	}
+}
+
+// Motor3.sandwich(Line3) -> Line3
+impl Sandwich<Line3> for Motor3 {
+	fn sandwich(self, rhs: Line3) -> Line3 {
+		Line3 {
+			vx: self.rw.geometric(self.uw).geometric(rhs.mx) * 2.0
+				+ self.rw.geometric(self.uy).geometric(rhs.mz) * 2.0
+				+ self.rw.geometric(self.uz).geometric(rhs.my) * 2.0
+				- self.rx.geometric(self.ux).geometric(rhs.mx) * 2.0
+				- self.rx.geometric(self.uy).geometric(rhs.my) * 2.0
+				- self.rx.geometric(self.uz).geometric(rhs.mz) * 2.0
+				+ self.ry.geometric(self.uw).geometric(rhs.mz) * 2.0
+				+ self.ry.geometric(self.ux).geometric(rhs.my) * 2.0
+				+ self.ry.geometric(self.uy).geometric(rhs.mx) * 2.0
+				+ self.rz.geometric(self.uw).geometric(rhs.my) * 2.0
+				+ self.rz.geometric(self.ux).geometric(rhs.mz) * 2.0
+				+ self.rz.geometric(self.uz).geometric(rhs.mx) * 2.0
+				+ self.uw.geometric(self.uw).geometric(rhs.vx)
+				+ self.uw.geometric(self.uy).geometric(rhs.vz) * 2.0
+				+ self.uw.geometric(self.uz).geometric(rhs.vy) * 2.0
+				- self.ux.geometric(self.ux).geometric(rhs.vx)
+				- self.ux.geometric(self.uy).geometric(rhs.vy) * 2.0
+				- self.ux.geometric(self.uz).geometric(rhs.vz) * 2.0
+				+ self.uy.geometric(self.uy).geometric(rhs.vx)
+				+ self.uz.geometric(self.uz).geometric(rhs.vx),
+			vy: self.rw.geometric(self.uw).geometric(rhs.my) * 2.0
+				+ self.rw.geometric(self.ux).geometric(rhs.mz) * 2.0
+				+ self.rw.geometric(self.uz).geometric(rhs.mx) * 2.0
+				+ self.rx.geometric(self.uw).geometric(rhs.mz) * 2.0
+				+ self.rx.geometric(self.ux).geometric(rhs.my) * 2.0
+				+ self.rx.geometric(self.uy).geometric(rhs.mx) * 2.0
+				- self.ry.geometric(self.ux).geometric(rhs.mx) * 2.0
+				- self.ry.geometric(self.uy).geometric(rhs.my) * 2.0
+				- self.ry.geometric(self.uz).geometric(rhs.mz) * 2.0
+				+ self.rz.geometric(self.uw).geometric(rhs.mx) * 2.0
+				+ self.rz.geometric(self.uy).geometric(rhs.mz) * 2.0
+				+ self.rz.geometric(self.uz).geometric(rhs.my) * 2.0
+				+ self.uw.geometric(self.uw).geometric(rhs.vy)
+				+ self.uw.geometric(self.ux).geometric(rhs.vz) * 2.0
+				+ self.uw.geometric(self.uz).geometric(rhs.vx) * 2.0
+				+ self.ux.geometric(self.ux).geometric(rhs.vy)
+				+ self.ux.geometric(self.uy).geometric(rhs.vx) * 2.0
+				- self.uy.geometric(self.uy).geometric(rhs.vy)
+				- self.uy.geometric(self.uz).geometric(rhs.vz) * 2.0
+				+ self.uz.geometric(self.uz).geometric(rhs.vy),
+			vz: self.rw.geometric(self.uw).geometric(rhs.mz) * 2.0
+				+ self.rw.geometric(self.ux).geometric(rhs.my) * 2.0
+				+ self.rw.geometric(self.uy).geometric(rhs.mx) * 2.0
+				+ self.rx.geometric(self.uw).geometric(rhs.my) * 2.0
+				+ self.rx.geometric(self.ux).geometric(rhs.mz) * 2.0
+				+ self.rx.geometric(self.uz).geometric(rhs.mx) * 2.0
+				+ self.ry.geometric(self.uw).geometric(rhs.mx) * 2.0
+				+ self.ry.geometric(self.uy).geometric(rhs.mz) * 2.0
+				+ self.ry.geometric(self.uz).geometric(rhs.my) * 2.0
+				- self.rz.geometric(self.ux).geometric(rhs.mx) * 2.0
+				- self.rz.geometric(self.uy).geometric(rhs.my) * 2.0
+				- self.rz.geometric(self.uz).geometric(rhs.mz) * 2.0
+				+ self.uw.geometric(self.uw).geometric(rhs.vz)
+				+ self.uw.geometric(self.ux).geometric(rhs.vy) * 2.0
+				+ self.uw.geometric(self.uy).geometric(rhs.vx) * 2.0
+				+ self.ux.geometric(self.ux).geometric(rhs.vz)
+				+ self.ux.geometric(self.uz).geometric(rhs.vx) * 2.0
+				+ self.uy.geometric(self.uy).geometric(rhs.vz)
+				+ self.uy.geometric(self.uz).geometric(rhs.vy) * 2.0
+				- self.uz.geometric(self.uz).geometric(rhs.vz),
+			mx: self.uw.geometric(self.uw).geometric(rhs.mx)
+				+ self.uw.geometric(self.uy).geometric(rhs.mz) * 2.0
+				+ self.uw.geometric(self.uz).geometric(rhs.my) * 2.0
+				- self.ux.geometric(self.ux).geometric(rhs.mx)
+				- self.ux.geometric(self.uy).geometric(rhs.my) * 2.0
+				- self.ux.geometric(self.uz).geometric(rhs.mz) * 2.0
+				+ self.uy.geometric(self.uy).geometric(rhs.mx)
+				+ self.uz.geometric(self.uz).geometric(rhs.mx),
+			my: self.uw.geometric(self.uw).geometric(rhs.my)
+				+ self.uw.geometric(self.ux).geometric(rhs.mz) * 2.0
+				+ self.uw.geometric(self.uz).geometric(rhs.mx) * 2.0
+				+ self.ux.geometric(self.ux).geometric(rhs.my)
+				+ self.ux.geometric(self.uy).geometric(rhs.mx) * 2.0
+				- self.uy.geometric(self.uy).geometric(rhs.my)
+				- self.uy.geometric(self.uz).geometric(rhs.mz) * 2.0
+				+ self.uz.geometric(self.uz).geometric(rhs.my),
+			mz: self.uw.geometric(self.uw).geometric(rhs.mz)
+				+ self.uw.geometric(self.ux).geometric(rhs.my) * 2.0
+				+ self.uw.geometric(self.uy).geometric(rhs.mx) * 2.0
+				+ self.ux.geometric(self.ux).geometric(rhs.mz)
+				+ self.ux.geometric(self.uz).geometric(rhs.mx) * 2.0
+				+ self.uy.geometric(self.uy).geometric(rhs.mz)
+				+ self.uy.geometric(self.uz).geometric(rhs.my) * 2.0
+				- self.uz.geometric(self.uz).geometric(rhs.mz),
+		}
+	}
+}
+
+// Motor3.anti_sandwich(Line3) -> Line3
+impl AntiSandwich<Line3> for Motor3 {
+	fn anti_sandwich(self, rhs: Line3) -> Line3 {
+		Line3 {
+			vx: self.rw.anti_geometric(self.rw).anti_geometric(rhs.vx)
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.vz) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.vy) * 2.0
+				- self.rx.anti_geometric(self.rx).anti_geometric(rhs.vx)
+				- self.rx.anti_geometric(self.ry).anti_geometric(rhs.vy) * 2.0
+				- self.rx.anti_geometric(self.rz).anti_geometric(rhs.vz) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.vx)
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.vx),
+			vy: self.rw.anti_geometric(self.rw).anti_geometric(rhs.vy)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.vz) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.vx) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.vy)
+				+ self.rx.anti_geometric(self.ry).anti_geometric(rhs.vx) * 2.0
+				- self.ry.anti_geometric(self.ry).anti_geometric(rhs.vy)
+				- self.ry.anti_geometric(self.rz).anti_geometric(rhs.vz) * 2.0
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.vy),
+			vz: self.rw.anti_geometric(self.rw).anti_geometric(rhs.vz)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.vy) * 2.0
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.vx) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.vz)
+				+ self.rx.anti_geometric(self.rz).anti_geometric(rhs.vx) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.vz)
+				+ self.ry.anti_geometric(self.rz).anti_geometric(rhs.vy) * 2.0
+				- self.rz.anti_geometric(self.rz).anti_geometric(rhs.vz),
+			mx: self.rw.anti_geometric(self.rw).anti_geometric(rhs.mx)
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.mz) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.my) * 2.0
+				+ self.rw.anti_geometric(self.uw).anti_geometric(rhs.vx) * 2.0
+				+ self.rw.anti_geometric(self.uy).anti_geometric(rhs.vz) * 2.0
+				+ self.rw.anti_geometric(self.uz).anti_geometric(rhs.vy) * 2.0
+				- self.rx.anti_geometric(self.rx).anti_geometric(rhs.mx)
+				- self.rx.anti_geometric(self.ry).anti_geometric(rhs.my) * 2.0
+				- self.rx.anti_geometric(self.rz).anti_geometric(rhs.mz) * 2.0
+				- self.rx.anti_geometric(self.ux).anti_geometric(rhs.vx) * 2.0
+				- self.rx.anti_geometric(self.uy).anti_geometric(rhs.vy) * 2.0
+				- self.rx.anti_geometric(self.uz).anti_geometric(rhs.vz) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.mx)
+				+ self.ry.anti_geometric(self.uw).anti_geometric(rhs.vz) * 2.0
+				+ self.ry.anti_geometric(self.ux).anti_geometric(rhs.vy) * 2.0
+				+ self.ry.anti_geometric(self.uy).anti_geometric(rhs.vx) * 2.0
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.mx)
+				+ self.rz.anti_geometric(self.uw).anti_geometric(rhs.vy) * 2.0
+				+ self.rz.anti_geometric(self.ux).anti_geometric(rhs.vz) * 2.0
+				+ self.rz.anti_geometric(self.uz).anti_geometric(rhs.vx) * 2.0,
+			my: self.rw.anti_geometric(self.rw).anti_geometric(rhs.my)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.mz) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.mx) * 2.0
+				+ self.rw.anti_geometric(self.uw).anti_geometric(rhs.vy) * 2.0
+				+ self.rw.anti_geometric(self.ux).anti_geometric(rhs.vz) * 2.0
+				+ self.rw.anti_geometric(self.uz).anti_geometric(rhs.vx) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.my)
+				+ self.rx.anti_geometric(self.ry).anti_geometric(rhs.mx) * 2.0
+				+ self.rx.anti_geometric(self.uw).anti_geometric(rhs.vz) * 2.0
+				+ self.rx.anti_geometric(self.ux).anti_geometric(rhs.vy) * 2.0
+				+ self.rx.anti_geometric(self.uy).anti_geometric(rhs.vx) * 2.0
+				- self.ry.anti_geometric(self.ry).anti_geometric(rhs.my)
+				- self.ry.anti_geometric(self.rz).anti_geometric(rhs.mz) * 2.0
+				- self.ry.anti_geometric(self.ux).anti_geometric(rhs.vx) * 2.0
+				- self.ry.anti_geometric(self.uy).anti_geometric(rhs.vy) * 2.0
+				- self.ry.anti_geometric(self.uz).anti_geometric(rhs.vz) * 2.0
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.my)
+				+ self.rz.anti_geometric(self.uw).anti_geometric(rhs.vx) * 2.0
+				+ self.rz.anti_geometric(self.uy).anti_geometric(rhs.vz) * 2.0
+				+ self.rz.anti_geometric(self.uz).anti_geometric(rhs.vy) * 2.0,
+			mz: self.rw.anti_geometric(self.rw).anti_geometric(rhs.mz)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.my) * 2.0
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.mx) * 2.0
+				+ self.rw.anti_geometric(self.uw).anti_geometric(rhs.vz) * 2.0
+				+ self.rw.anti_geometric(self.ux).anti_geometric(rhs.vy) * 2.0
+				+ self.rw.anti_geometric(self.uy).anti_geometric(rhs.vx) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.mz)
+				+ self.rx.anti_geometric(self.rz).anti_geometric(rhs.mx) * 2.0
+				+ self.rx.anti_geometric(self.uw).anti_geometric(rhs.vy) * 2.0
+				+ self.rx.anti_geometric(self.ux).anti_geometric(rhs.vz) * 2.0
+				+ self.rx.anti_geometric(self.uz).anti_geometric(rhs.vx) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.mz)
+				+ self.ry.anti_geometric(self.rz).anti_geometric(rhs.my) * 2.0
+				+ self.ry.anti_geometric(self.uw).anti_geometric(rhs.vx) * 2.0
+				+ self.ry.anti_geometric(self.uy).anti_geometric(rhs.vz) * 2.0
+				+ self.ry.anti_geometric(self.uz).anti_geometric(rhs.vy) * 2.0
+				- self.rz.anti_geometric(self.rz).anti_geometric(rhs.mz)
+				- self.rz.anti_geometric(self.ux).anti_geometric(rhs.vx) * 2.0
+				- self.rz.anti_geometric(self.uy).anti_geometric(rhs.vy) * 2.0
+				- self.rz.anti_geometric(self.uz).anti_geometric(rhs.vz) * 2.0,
+		}
+	}
+}
+
+// Motor3.sandwich(Plane) -> Plane
+impl Sandwich<Plane> for Motor3 {
+	fn sandwich(self, rhs: Plane) -> Plane {
+		Plane {
+			nx: self.rw.geometric(self.ux).geometric(rhs.d) * -2.0 + self.rx.geometric(self.uw).geometric(rhs.d) * 2.0
+				- self.ry.geometric(self.uz).geometric(rhs.d) * 2.0
+				- self.rz.geometric(self.uy).geometric(rhs.d) * 2.0
+				+ self.uw.geometric(self.uw).geometric(rhs.nx)
+				+ self.uw.geometric(self.uy).geometric(rhs.nz) * 2.0
+				+ self.uw.geometric(self.uz).geometric(rhs.ny) * 2.0
+				- self.ux.geometric(self.ux).geometric(rhs.nx)
+				- self.ux.geometric(self.uy).geometric(rhs.ny) * 2.0
+				- self.ux.geometric(self.uz).geometric(rhs.nz) * 2.0
+				+ self.uy.geometric(self.uy).geometric(rhs.nx)
+				+ self.uz.geometric(self.uz).geometric(rhs.nx),
+			ny: self.rw.geometric(self.uy).geometric(rhs.d) * -2.0 - self.rx.geometric(self.uz).geometric(rhs.d) * 2.0
+				+ self.ry.geometric(self.uw).geometric(rhs.d) * 2.0
+				- self.rz.geometric(self.ux).geometric(rhs.d) * 2.0
+				+ self.uw.geometric(self.uw).geometric(rhs.ny)
+				+ self.uw.geometric(self.ux).geometric(rhs.nz) * 2.0
+				+ self.uw.geometric(self.uz).geometric(rhs.nx) * 2.0
+				+ self.ux.geometric(self.ux).geometric(rhs.ny)
+				+ self.ux.geometric(self.uy).geometric(rhs.nx) * 2.0
+				- self.uy.geometric(self.uy).geometric(rhs.ny)
+				- self.uy.geometric(self.uz).geometric(rhs.nz) * 2.0
+				+ self.uz.geometric(self.uz).geometric(rhs.ny),
+			nz: self.rw.geometric(self.uz).geometric(rhs.d) * -2.0
+				- self.rx.geometric(self.uy).geometric(rhs.d) * 2.0
+				- self.ry.geometric(self.ux).geometric(rhs.d) * 2.0
+				+ self.rz.geometric(self.uw).geometric(rhs.d) * 2.0
+				+ self.uw.geometric(self.uw).geometric(rhs.nz)
+				+ self.uw.geometric(self.ux).geometric(rhs.ny) * 2.0
+				+ self.uw.geometric(self.uy).geometric(rhs.nx) * 2.0
+				+ self.ux.geometric(self.ux).geometric(rhs.nz)
+				+ self.ux.geometric(self.uz).geometric(rhs.nx) * 2.0
+				+ self.uy.geometric(self.uy).geometric(rhs.nz)
+				+ self.uy.geometric(self.uz).geometric(rhs.ny) * 2.0
+				- self.uz.geometric(self.uz).geometric(rhs.nz),
+			d: self.uw.geometric(self.uw).geometric(rhs.d)
+				- self.ux.geometric(self.ux).geometric(rhs.d)
+				- self.uy.geometric(self.uy).geometric(rhs.d)
+				- self.uz.geometric(self.uz).geometric(rhs.d),
+		}
+	}
+}
+
+// Motor3.anti_sandwich(Plane) -> Plane
+impl AntiSandwich<Plane> for Motor3 {
+	fn anti_sandwich(self, rhs: Plane) -> Plane {
+		Plane {
+			nx: self.rw.anti_geometric(self.rw).anti_geometric(rhs.nx)
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.nz) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.ny) * 2.0
+				- self.rx.anti_geometric(self.rx).anti_geometric(rhs.nx)
+				- self.rx.anti_geometric(self.ry).anti_geometric(rhs.ny) * 2.0
+				- self.rx.anti_geometric(self.rz).anti_geometric(rhs.nz) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.nx)
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.nx),
+			ny: self.rw.anti_geometric(self.rw).anti_geometric(rhs.ny)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.nz) * 2.0
+				+ self.rw.anti_geometric(self.rz).anti_geometric(rhs.nx) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.ny)
+				+ self.rx.anti_geometric(self.ry).anti_geometric(rhs.nx) * 2.0
+				- self.ry.anti_geometric(self.ry).anti_geometric(rhs.ny)
+				- self.ry.anti_geometric(self.rz).anti_geometric(rhs.nz) * 2.0
+				+ self.rz.anti_geometric(self.rz).anti_geometric(rhs.ny),
+			nz: self.rw.anti_geometric(self.rw).anti_geometric(rhs.nz)
+				+ self.rw.anti_geometric(self.rx).anti_geometric(rhs.ny) * 2.0
+				+ self.rw.anti_geometric(self.ry).anti_geometric(rhs.nx) * 2.0
+				+ self.rx.anti_geometric(self.rx).anti_geometric(rhs.nz)
+				+ self.rx.anti_geometric(self.rz).anti_geometric(rhs.nx) * 2.0
+				+ self.ry.anti_geometric(self.ry).anti_geometric(rhs.nz)
+				+ self.ry.anti_geometric(self.rz).anti_geometric(rhs.ny) * 2.0
+				- self.rz.anti_geometric(self.rz).anti_geometric(rhs.nz),
+			d: self.rw.anti_geometric(self.rw).anti_geometric(rhs.d)
+				+ self.rw.anti_geometric(self.ux).anti_geometric(rhs.nx) * 2.0
+				+ self.rw.anti_geometric(self.uy).anti_geometric(rhs.ny) * 2.0
+				+ self.rw.anti_geometric(self.uz).anti_geometric(rhs.nz) * 2.0
+				- self.rx.anti_geometric(self.rx).anti_geometric(rhs.d)
+				- self.rx.anti_geometric(self.uw).anti_geometric(rhs.nx) * 2.0
+				- self.rx.anti_geometric(self.uy).anti_geometric(rhs.nz) * 2.0
+				- self.rx.anti_geometric(self.uz).anti_geometric(rhs.ny) * 2.0
+				- self.ry.anti_geometric(self.ry).anti_geometric(rhs.d)
+				- self.ry.anti_geometric(self.uw).anti_geometric(rhs.ny) * 2.0
+				- self.ry.anti_geometric(self.ux).anti_geometric(rhs.nz) * 2.0
+				- self.ry.anti_geometric(self.uz).anti_geometric(rhs.nx) * 2.0
+				- self.rz.anti_geometric(self.rz).anti_geometric(rhs.d)
+				- self.rz.anti_geometric(self.uw).anti_geometric(rhs.nz) * 2.0
+				- self.rz.anti_geometric(self.ux).anti_geometric(rhs.ny) * 2.0
+				- self.rz.anti_geometric(self.uy).anti_geometric(rhs.nx) * 2.0,
+		}
+	}
+}
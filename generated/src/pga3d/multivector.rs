@@ -0,0 +1,6360 @@
+//! # Multivector
+//!
+//! ## Operations
+//! ```text
+//! Multivector.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Multivector) -> Multivector
+//! Multivector.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Multivector) -> S
+//! Multivector.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Vec3) -> Multivector
+//! Vec3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec3) -> Multivector
+//! Vec3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec3) -> Multivector
+//! Vec3.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec3) -> Multivector
+//! Vec3.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Vec3) -> S
+//! Vec3.scalar_product(Multivector) -> S
+//! Multivector.commutator(Vec3) -> Multivector
+//! Vec3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec3) -> Multivector
+//! Vec3.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Vec4) -> Multivector
+//! Vec4.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Vec4) -> Multivector
+//! Vec4.dot(Multivector) -> Multivector
+//! Multivector.wedge(Vec4) -> Multivector
+//! Vec4.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec4) -> Multivector
+//! Vec4.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec4) -> Multivector
+//! Vec4.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec4) -> Multivector
+//! Vec4.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Vec4) -> S
+//! Vec4.scalar_product(Multivector) -> S
+//! Multivector.commutator(Vec4) -> Multivector
+//! Vec4.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec4) -> Multivector
+//! Vec4.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Point3) -> Multivector
+//! Point3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Point3) -> Multivector
+//! Point3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Point3) -> Multivector
+//! Point3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Point3) -> Multivector
+//! Point3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Point3) -> Multivector
+//! Point3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Point3) -> Multivector
+//! Point3.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Point3) -> Multivector
+//! Point3.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Point3) -> S
+//! Point3.scalar_product(Multivector) -> S
+//! Multivector.commutator(Point3) -> Multivector
+//! Point3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Point3) -> Multivector
+//! Point3.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Moment3) -> Multivector
+//! Moment3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Moment3) -> Multivector
+//! Moment3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Moment3) -> Multivector
+//! Moment3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Moment3) -> Multivector
+//! Moment3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Moment3) -> Multivector
+//! Moment3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Moment3) -> Multivector
+//! Moment3.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Moment3) -> Multivector
+//! Moment3.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Moment3) -> S
+//! Moment3.scalar_product(Multivector) -> S
+//! Multivector.commutator(Moment3) -> Multivector
+//! Moment3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Moment3) -> Multivector
+//! Moment3.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Line3) -> Multivector
+//! Line3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Line3) -> Multivector
+//! Line3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Line3) -> Multivector
+//! Line3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Line3) -> Multivector
+//! Line3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Line3) -> Multivector
+//! Line3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Line3) -> Multivector
+//! Line3.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Line3) -> Multivector
+//! Line3.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Line3) -> S
+//! Line3.scalar_product(Multivector) -> S
+//! Multivector.commutator(Line3) -> Multivector
+//! Line3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Line3) -> Multivector
+//! Line3.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Plane) -> Multivector
+//! Plane.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Plane) -> Multivector
+//! Plane.dot(Multivector) -> Multivector
+//! Multivector.wedge(Plane) -> Multivector
+//! Plane.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Plane) -> Multivector
+//! Plane.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Plane) -> Multivector
+//! Plane.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Plane) -> Multivector
+//! Plane.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Plane) -> S
+//! Plane.scalar_product(Multivector) -> S
+//! Multivector.commutator(Plane) -> Multivector
+//! Plane.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Plane) -> Multivector
+//! Plane.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Translator3) -> Multivector
+//! Translator3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Translator3) -> Multivector
+//! Translator3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Translator3) -> Multivector
+//! Translator3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Translator3) -> Multivector
+//! Translator3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Translator3) -> Multivector
+//! Translator3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Translator3) -> Multivector
+//! Translator3.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Translator3) -> Multivector
+//! Translator3.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Translator3) -> S
+//! Translator3.scalar_product(Multivector) -> S
+//! Multivector.commutator(Translator3) -> Multivector
+//! Translator3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Translator3) -> Multivector
+//! Translator3.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Rotor3) -> Multivector
+//! Rotor3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Rotor3) -> Multivector
+//! Rotor3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Rotor3) -> Multivector
+//! Rotor3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Rotor3) -> Multivector
+//! Rotor3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Rotor3) -> Multivector
+//! Rotor3.right_contraction(Multivector) -> Multivector
+//! Multivector.commutator(Rotor3) -> Multivector
+//! Rotor3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Rotor3) -> Multivector
+//! Rotor3.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Motor3) -> Multivector
+//! Motor3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Motor3) -> Multivector
+//! Motor3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Motor3) -> Multivector
+//! Motor3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Motor3) -> Multivector
+//! Motor3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Motor3) -> Multivector
+//! Motor3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Motor3) -> Multivector
+//! Motor3.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Motor3) -> Multivector
+//! Motor3.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Motor3) -> S
+//! Motor3.scalar_product(Multivector) -> S
+//! Multivector.commutator(Motor3) -> Multivector
+//! Motor3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Motor3) -> Multivector
+//! Motor3.anti_dot(Multivector) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Multivector {
+	pub s: S,
+	pub x: X,
+	pub y: Y,
+	pub z: Z,
+	pub w: W,
+	pub wx: WX,
+	pub wy: WY,
+	pub wz: WZ,
+	pub yz: YZ,
+	pub zx: ZX,
+	pub xy: XY,
+	pub yzw: YZW,
+	pub zxw: ZXW,
+	pub xyw: XYW,
+	pub xyz: XYZ,
+	pub xyzw: XYZW,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Multivector {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Multivector {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Multivector {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.z.abs_diff_eq(&other.z, epsilon)
+			&& self.w.abs_diff_eq(&other.w, epsilon)
+			&& self.wx.abs_diff_eq(&other.wx, epsilon)
+			&& self.wy.abs_diff_eq(&other.wy, epsilon)
+			&& self.wz.abs_diff_eq(&other.wz, epsilon)
+			&& self.yz.abs_diff_eq(&other.yz, epsilon)
+			&& self.zx.abs_diff_eq(&other.zx, epsilon)
+			&& self.xy.abs_diff_eq(&other.xy, epsilon)
+			&& self.yzw.abs_diff_eq(&other.yzw, epsilon)
+			&& self.zxw.abs_diff_eq(&other.zxw, epsilon)
+			&& self.xyw.abs_diff_eq(&other.xyw, epsilon)
+			&& self.xyz.abs_diff_eq(&other.xyz, epsilon)
+			&& self.xyzw.abs_diff_eq(&other.xyzw, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Multivector {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.z.relative_eq(&other.z, epsilon, max_relative)
+			&& self.w.relative_eq(&other.w, epsilon, max_relative)
+			&& self.wx.relative_eq(&other.wx, epsilon, max_relative)
+			&& self.wy.relative_eq(&other.wy, epsilon, max_relative)
+			&& self.wz.relative_eq(&other.wz, epsilon, max_relative)
+			&& self.yz.relative_eq(&other.yz, epsilon, max_relative)
+			&& self.zx.relative_eq(&other.zx, epsilon, max_relative)
+			&& self.xy.relative_eq(&other.xy, epsilon, max_relative)
+			&& self.yzw.relative_eq(&other.yzw, epsilon, max_relative)
+			&& self.zxw.relative_eq(&other.zxw, epsilon, max_relative)
+			&& self.xyw.relative_eq(&other.xyw, epsilon, max_relative)
+			&& self.xyz.relative_eq(&other.xyz, epsilon, max_relative)
+			&& self.xyzw.relative_eq(&other.xyzw, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Multivector {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: self.xyzw.rcompl(),
+			x: self.yzw.rcompl(),
+			y: self.zxw.rcompl(),
+			z: self.xyw.rcompl(),
+			w: self.xyz.rcompl(),
+			wx: self.yz.rcompl(),
+			wy: self.zx.rcompl(),
+			wz: self.xy.rcompl(),
+			yz: self.wx.rcompl(),
+			zx: self.wy.rcompl(),
+			xy: self.wz.rcompl(),
+			yzw: self.x.rcompl(),
+			zxw: self.y.rcompl(),
+			xyw: self.z.rcompl(),
+			xyz: self.w.rcompl(),
+			xyzw: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Multivector {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Multivector {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: self.xyzw.lcompl(),
+			x: self.yzw.lcompl(),
+			y: self.zxw.lcompl(),
+			z: self.xyw.lcompl(),
+			w: self.xyz.lcompl(),
+			wx: self.yz.lcompl(),
+			wy: self.zx.lcompl(),
+			wz: self.xy.lcompl(),
+			yz: self.wx.lcompl(),
+			zx: self.wy.lcompl(),
+			xy: self.wz.lcompl(),
+			yzw: self.x.lcompl(),
+			zxw: self.y.lcompl(),
+			xyw: self.z.lcompl(),
+			xyz: self.w.lcompl(),
+			xyzw: self.s.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Multivector {
+	fn rev(self) -> Self {
+		Multivector {
+			s: self.s,
+			x: self.x,
+			y: self.y,
+			z: self.z,
+			w: self.w,
+			wx: -self.wx,
+			wy: -self.wy,
+			wz: -self.wz,
+			yz: -self.yz,
+			zx: -self.zx,
+			xy: -self.xy,
+			yzw: -self.yzw,
+			zxw: -self.zxw,
+			xyw: -self.xyw,
+			xyz: -self.xyz,
+			xyzw: self.xyzw,
+		}
+	}
+}
+
+impl AntiReverse for Multivector {
+	fn arev(self) -> Self {
+		Multivector {
+			s: self.s,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			w: -self.w,
+			wx: -self.wx,
+			wy: -self.wy,
+			wz: -self.wz,
+			yz: -self.yz,
+			zx: -self.zx,
+			xy: -self.xy,
+			yzw: self.yzw,
+			zxw: self.zxw,
+			xyw: self.xyw,
+			xyz: self.xyz,
+			xyzw: self.xyzw,
+		}
+	}
+}
+
+impl Involute for Multivector {
+	fn involute(self) -> Self {
+		Multivector {
+			s: self.s,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			w: -self.w,
+			wx: self.wx,
+			wy: self.wy,
+			wz: self.wz,
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+			yzw: -self.yzw,
+			zxw: -self.zxw,
+			xyw: -self.xyw,
+			xyz: -self.xyz,
+			xyzw: self.xyzw,
+		}
+	}
+}
+
+impl Conjugate for Multivector {
+	fn conjugate(self) -> Self {
+		Multivector {
+			s: self.s,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			w: -self.w,
+			wx: -self.wx,
+			wy: -self.wy,
+			wz: -self.wz,
+			yz: -self.yz,
+			zx: -self.zx,
+			xy: -self.xy,
+			yzw: self.yzw,
+			zxw: self.zxw,
+			xyw: self.xyw,
+			xyz: self.xyz,
+			xyzw: self.xyzw,
+		}
+	}
+}
+
+impl Dual for Multivector {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: self.xyzw.dual(),
+			x: self.yzw.dual(),
+			y: self.zxw.dual(),
+			z: self.xyw.dual(),
+			w: self.xyz.dual(),
+			wx: self.yz.dual(),
+			wy: self.zx.dual(),
+			wz: self.xy.dual(),
+			yz: self.wx.dual(),
+			zx: self.wy.dual(),
+			xy: self.wz.dual(),
+			yzw: self.x.dual(),
+			zxw: self.y.dual(),
+			xyw: self.z.dual(),
+			xyz: self.w.dual(),
+			xyzw: self.s.dual(),
+		}
+	}
+}
+
+impl Bulk for Multivector {
+	type Output = Multivector;
+	fn bulk(self) -> Self::Output {
+		Multivector {
+			s: self.s,
+			x: self.x,
+			y: self.y,
+			z: self.z,
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.xyz,
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl Weight for Multivector {
+	type Output = Multivector;
+	fn weight(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.w,
+			wx: self.wx,
+			wy: self.wy,
+			wz: self.wz,
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.yzw,
+			zxw: self.zxw,
+			xyw: self.xyw,
+			xyz: Default::default(),
+			xyzw: self.xyzw,
+		}
+	}
+}
+
+impl BulkDual for Multivector {
+	type Output = Multivector;
+	fn bulk_dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.xyz.bulk_dual(),
+			wx: self.yz.bulk_dual(),
+			wy: self.zx.bulk_dual(),
+			wz: self.xy.bulk_dual(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.bulk_dual(),
+			zxw: self.y.bulk_dual(),
+			xyw: self.z.bulk_dual(),
+			xyz: Default::default(),
+			xyzw: self.s.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Multivector {
+	type Output = Multivector;
+	fn weight_dual(self) -> Self::Output {
+		Multivector {
+			s: self.xyzw.weight_dual(),
+			x: self.yzw.weight_dual(),
+			y: self.zxw.weight_dual(),
+			z: self.xyw.weight_dual(),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.wx.weight_dual(),
+			zx: self.wy.weight_dual(),
+			xy: self.wz.weight_dual(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.w.weight_dual(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Omitted: Multivector.square()  (not a scalar)
+
+impl Norm for Multivector {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0
+			+ self.x.0 * self.x.0
+			+ self.y.0 * self.y.0
+			+ self.z.0 * self.z.0
+			+ self.w.0 * self.w.0
+			+ self.wx.0 * self.wx.0
+			+ self.wy.0 * self.wy.0
+			+ self.wz.0 * self.wz.0
+			+ self.yz.0 * self.yz.0
+			+ self.zx.0 * self.zx.0
+			+ self.xy.0 * self.xy.0
+			+ self.yzw.0 * self.yzw.0
+			+ self.zxw.0 * self.zxw.0
+			+ self.xyw.0 * self.xyw.0
+			+ self.xyz.0 * self.xyz.0
+			+ self.xyzw.0 * self.xyzw.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0
+			+ self.x.0 * self.x.0
+			+ self.y.0 * self.y.0
+			+ self.z.0 * self.z.0
+			+ self.yz.0 * self.yz.0
+			+ self.zx.0 * self.zx.0
+			+ self.xy.0 * self.xy.0
+			+ self.xyz.0 * self.xyz.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.w.0 * self.w.0
+			+ self.wx.0 * self.wx.0
+			+ self.wy.0 * self.wy.0
+			+ self.wz.0 * self.wz.0
+			+ self.yzw.0 * self.yzw.0
+			+ self.zxw.0 * self.zxw.0
+			+ self.xyw.0 * self.xyw.0
+			+ self.xyzw.0 * self.xyzw.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.weight_norm();
+		Multivector {
+			s: self.s * scale,
+			x: self.x * scale,
+			y: self.y * scale,
+			z: self.z * scale,
+			w: self.w * scale,
+			wx: self.wx * scale,
+			wy: self.wy * scale,
+			wz: self.wz * scale,
+			yz: self.yz * scale,
+			zx: self.zx * scale,
+			xy: self.xy * scale,
+			yzw: self.yzw * scale,
+			zxw: self.zxw * scale,
+			xyw: self.xyw * scale,
+			xyz: self.xyz * scale,
+			xyzw: self.xyzw * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Multivector {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 16] {
+		[
+			self.s.0,
+			self.x.0,
+			self.y.0,
+			self.z.0,
+			self.w.0,
+			self.wx.0,
+			self.wy.0,
+			self.wz.0,
+			self.yz.0,
+			self.zx.0,
+			self.xy.0,
+			self.yzw.0,
+			self.zxw.0,
+			self.xyw.0,
+			self.xyz.0,
+			self.xyzw.0,
+		]
+	}
+}
+
+impl From<[f64; 16]> for Multivector {
+	fn from(a: [f64; 16]) -> Self {
+		Multivector {
+			s: S(a[0]),
+			x: X(a[1]),
+			y: Y(a[2]),
+			z: Z(a[3]),
+			w: W(a[4]),
+			wx: WX(a[5]),
+			wy: WY(a[6]),
+			wz: WZ(a[7]),
+			yz: YZ(a[8]),
+			zx: ZX(a[9]),
+			xy: XY(a[10]),
+			yzw: YZW(a[11]),
+			zxw: ZXW(a[12]),
+			xyw: XYW(a[13]),
+			xyz: XYZ(a[14]),
+			xyzw: XYZW(a[15]),
+		}
+	}
+}
+
+impl From<Multivector> for [f64; 16] {
+	fn from(v: Multivector) -> Self {
+		v.to_array()
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Vec3:
+
+// Multivector.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : Z(self.s.0 * rhs.z.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) + WX(self.xyw.0 * rhs.y.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) - WY(self.xyw.0 * rhs.x.0) + WY(self.yzw.0 * rhs.z.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) - WZ(self.yzw.0 * rhs.y.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.xyz.0 * rhs.x.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.xyz.0 * rhs.y.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) + XY(self.xyz.0 * rhs.z.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : YZW(self.wy.0 * rhs.z.0) - YZW(self.wz.0 * rhs.y.0) - YZW(self.xyzw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.wx.0 * rhs.z.0) + ZXW(self.wz.0 * rhs.x.0) - ZXW(self.xyzw.0 * rhs.y.0),
+		//     xyw : XYW(self.wx.0 * rhs.y.0) - XYW(self.wy.0 * rhs.x.0) - XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		//     xyzw: -XYZW(self.xyw.0 * rhs.z.0) - XYZW(self.yzw.0 * rhs.x.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y) + self.zx.geometric(rhs.z),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x) + self.yz.geometric(rhs.z),
+			z: self.s.geometric(rhs.z) + self.yz.geometric(rhs.y) + self.zx.geometric(rhs.x),
+			w: self.wx.geometric(rhs.x) + self.wy.geometric(rhs.y) + self.wz.geometric(rhs.z),
+			wx: self.w.geometric(rhs.x) + self.xyw.geometric(rhs.y) + self.zxw.geometric(rhs.z),
+			wy: self.w.geometric(rhs.y) + self.xyw.geometric(rhs.x) + self.yzw.geometric(rhs.z),
+			wz: self.w.geometric(rhs.z) + self.yzw.geometric(rhs.y) + self.zxw.geometric(rhs.x),
+			yz: self.xyz.geometric(rhs.x) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zx: self.x.geometric(rhs.z) + self.xyz.geometric(rhs.y) + self.z.geometric(rhs.x),
+			xy: self.x.geometric(rhs.y) + self.xyz.geometric(rhs.z) + self.y.geometric(rhs.x),
+			yzw: self.wy.geometric(rhs.z) + self.wz.geometric(rhs.y) + self.xyzw.geometric(rhs.x),
+			zxw: self.wx.geometric(rhs.z) + self.wz.geometric(rhs.x) + self.xyzw.geometric(rhs.y),
+			xyw: self.wx.geometric(rhs.y) + self.wy.geometric(rhs.x) + self.xyzw.geometric(rhs.z),
+			xyz: self.xy.geometric(rhs.z) + self.yz.geometric(rhs.x) + self.zx.geometric(rhs.y),
+			xyzw: self.xyw.geometric(rhs.z) + self.yzw.geometric(rhs.x) + self.zxw.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xyw.0 * rhs.z.0) - S(self.yzw.0 * rhs.x.0) - S(self.zxw.0 * rhs.y.0),
+		//     x   : X(self.wy.0 * rhs.z.0) - X(self.wz.0 * rhs.y.0) + X(self.xyzw.0 * rhs.x.0),
+		//     y   : -Y(self.wx.0 * rhs.z.0) + Y(self.wz.0 * rhs.x.0) + Y(self.xyzw.0 * rhs.y.0),
+		//     z   : Z(self.wx.0 * rhs.y.0) - Z(self.wy.0 * rhs.x.0) + Z(self.xyzw.0 * rhs.z.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.w.0 * rhs.x.0) - YZ(self.xyw.0 * rhs.y.0) + YZ(self.zxw.0 * rhs.z.0),
+		//     zx  : ZX(self.w.0 * rhs.y.0) + ZX(self.xyw.0 * rhs.x.0) - ZX(self.yzw.0 * rhs.z.0),
+		//     xy  : XY(self.w.0 * rhs.z.0) + XY(self.yzw.0 * rhs.y.0) - XY(self.zxw.0 * rhs.x.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.wx.0 * rhs.x.0) + XYZ(self.wy.0 * rhs.y.0) + XYZ(self.wz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xyw.anti_geometric(rhs.z) + self.yzw.anti_geometric(rhs.x) + self.zxw.anti_geometric(rhs.y),
+			x: self.wy.anti_geometric(rhs.z) + self.wz.anti_geometric(rhs.y) + self.xyzw.anti_geometric(rhs.x),
+			y: self.wx.anti_geometric(rhs.z) + self.wz.anti_geometric(rhs.x) + self.xyzw.anti_geometric(rhs.y),
+			z: self.wx.anti_geometric(rhs.y) + self.wy.anti_geometric(rhs.x) + self.xyzw.anti_geometric(rhs.z),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.w.anti_geometric(rhs.x) + self.xyw.anti_geometric(rhs.y) + self.zxw.anti_geometric(rhs.z),
+			zx: self.w.anti_geometric(rhs.y) + self.xyw.anti_geometric(rhs.x) + self.yzw.anti_geometric(rhs.z),
+			xy: self.w.anti_geometric(rhs.z) + self.yzw.anti_geometric(rhs.y) + self.zxw.anti_geometric(rhs.x),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.wx.anti_geometric(rhs.x) + self.wy.anti_geometric(rhs.y) + self.wz.anti_geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.dot(Vec3) -> Multivector
+impl Dot<Vec3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : Z(self.s.0 * rhs.z.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.xyw.0 * rhs.y.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : -WY(self.xyw.0 * rhs.x.0) + WY(self.yzw.0 * rhs.z.0),
+		//     wz  : -WZ(self.yzw.0 * rhs.y.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.xyz.0 * rhs.x.0),
+		//     zx  : ZX(self.xyz.0 * rhs.y.0),
+		//     xy  : XY(self.xyz.0 * rhs.z.0),
+		//     yzw : -YZW(self.xyzw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.xyzw.0 * rhs.y.0),
+		//     xyw : -XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y) + self.zx.dot(rhs.z),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x) + self.yz.dot(rhs.z),
+			z: self.s.dot(rhs.z) + self.yz.dot(rhs.y) + self.zx.dot(rhs.x),
+			w: self.wx.dot(rhs.x) + self.wy.dot(rhs.y) + self.wz.dot(rhs.z),
+			wx: self.xyw.dot(rhs.y) + self.zxw.dot(rhs.z),
+			wy: self.xyw.dot(rhs.x) + self.yzw.dot(rhs.z),
+			wz: self.yzw.dot(rhs.y) + self.zxw.dot(rhs.x),
+			yz: self.xyz.dot(rhs.x),
+			zx: self.xyz.dot(rhs.y),
+			xy: self.xyz.dot(rhs.z),
+			yzw: self.xyzw.dot(rhs.x),
+			zxw: self.xyzw.dot(rhs.y),
+			xyw: self.xyzw.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.s.0 * rhs.x.0),
+		//     y   : Y(self.s.0 * rhs.y.0),
+		//     z   : Z(self.s.0 * rhs.z.0),
+		//     w   : Default::default(),
+		//     wx  : WX(self.w.0 * rhs.x.0),
+		//     wy  : WY(self.w.0 * rhs.y.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0),
+		//     yz  : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : YZW(self.wy.0 * rhs.z.0) - YZW(self.wz.0 * rhs.y.0),
+		//     zxw : -ZXW(self.wx.0 * rhs.z.0) + ZXW(self.wz.0 * rhs.x.0),
+		//     xyw : XYW(self.wx.0 * rhs.y.0) - XYW(self.wy.0 * rhs.x.0),
+		//     xyz : XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		//     xyzw: -XYZW(self.xyw.0 * rhs.z.0) - XYZW(self.yzw.0 * rhs.x.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			z: self.s.wedge(rhs.z),
+			w: Default::default(),
+			wx: self.w.wedge(rhs.x),
+			wy: self.w.wedge(rhs.y),
+			wz: self.w.wedge(rhs.z),
+			yz: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			zx: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			yzw: self.wy.wedge(rhs.z) + self.wz.wedge(rhs.y),
+			zxw: self.wx.wedge(rhs.z) + self.wz.wedge(rhs.x),
+			xyw: self.wx.wedge(rhs.y) + self.wy.wedge(rhs.x),
+			xyz: self.xy.wedge(rhs.z) + self.yz.wedge(rhs.x) + self.zx.wedge(rhs.y),
+			xyzw: self.xyw.wedge(rhs.z) + self.yzw.wedge(rhs.x) + self.zxw.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Vec3) -> Multivector
+impl AntiWedge<Vec3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xyw.0 * rhs.z.0) - S(self.yzw.0 * rhs.x.0) - S(self.zxw.0 * rhs.y.0),
+		//     x   : X(self.xyzw.0 * rhs.x.0),
+		//     y   : Y(self.xyzw.0 * rhs.y.0),
+		//     z   : Z(self.xyzw.0 * rhs.z.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xyw.anti_wedge(rhs.z) + self.yzw.anti_wedge(rhs.x) + self.zxw.anti_wedge(rhs.y),
+			x: self.xyzw.anti_wedge(rhs.x),
+			y: self.xyzw.anti_wedge(rhs.y),
+			z: self.xyzw.anti_wedge(rhs.z),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Vec3) -> Multivector
+impl LeftContraction<Vec3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.s.0 * rhs.x.0),
+		//     y   : Y(self.s.0 * rhs.y.0),
+		//     z   : Z(self.s.0 * rhs.z.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			z: self.s.left_contraction(rhs.z),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Vec3) -> Multivector
+impl RightContraction<Vec3> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.xyw.0 * rhs.y.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : -WY(self.xyw.0 * rhs.x.0) + WY(self.yzw.0 * rhs.z.0),
+		//     wz  : -WZ(self.yzw.0 * rhs.y.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.xyz.0 * rhs.x.0),
+		//     zx  : ZX(self.xyz.0 * rhs.y.0),
+		//     xy  : XY(self.xyz.0 * rhs.z.0),
+		//     yzw : -YZW(self.xyzw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.xyzw.0 * rhs.y.0),
+		//     xyw : -XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z),
+			x: self.xy.right_contraction(rhs.y) + self.zx.right_contraction(rhs.z),
+			y: self.xy.right_contraction(rhs.x) + self.yz.right_contraction(rhs.z),
+			z: self.yz.right_contraction(rhs.y) + self.zx.right_contraction(rhs.x),
+			w: self.wx.right_contraction(rhs.x) + self.wy.right_contraction(rhs.y) + self.wz.right_contraction(rhs.z),
+			wx: self.xyw.right_contraction(rhs.y) + self.zxw.right_contraction(rhs.z),
+			wy: self.xyw.right_contraction(rhs.x) + self.yzw.right_contraction(rhs.z),
+			wz: self.yzw.right_contraction(rhs.y) + self.zxw.right_contraction(rhs.x),
+			yz: self.xyz.right_contraction(rhs.x),
+			zx: self.xyz.right_contraction(rhs.y),
+			xy: self.xyz.right_contraction(rhs.z),
+			yzw: self.xyzw.right_contraction(rhs.x),
+			zxw: self.xyzw.right_contraction(rhs.y),
+			xyw: self.xyzw.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Vec3) -> S
+impl ScalarProduct<Vec3> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Multivector.commutator(Vec3) -> Multivector
+impl Commutator<Vec3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.w.0 * rhs.x.0),
+		//     wy  : WY(self.w.0 * rhs.y.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0),
+		//     yz  : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : -YZW(self.xyzw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.xyzw.0 * rhs.y.0),
+		//     xyw : -XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: -XYZW(self.xyw.0 * rhs.z.0) - XYZW(self.yzw.0 * rhs.x.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y) + self.zx.commutator(rhs.z),
+			y: self.xy.commutator(rhs.x) + self.yz.commutator(rhs.z),
+			z: self.yz.commutator(rhs.y) + self.zx.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x) + self.wy.commutator(rhs.y) + self.wz.commutator(rhs.z),
+			wx: self.w.commutator(rhs.x),
+			wy: self.w.commutator(rhs.y),
+			wz: self.w.commutator(rhs.z),
+			yz: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			zx: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			yzw: self.xyzw.commutator(rhs.x),
+			zxw: self.xyzw.commutator(rhs.y),
+			xyw: self.xyzw.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: self.xyw.commutator(rhs.z) + self.yzw.commutator(rhs.x) + self.zxw.commutator(rhs.y),
+		}
+	}
+}
+
+// Multivector.anti_dot(Vec3) -> Multivector
+impl AntiDot<Vec3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.xyzw.0 * rhs.x.0),
+		//     y   : Y(self.xyzw.0 * rhs.y.0),
+		//     z   : Z(self.xyzw.0 * rhs.z.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : -YZ(self.xyw.0 * rhs.y.0) + YZ(self.zxw.0 * rhs.z.0),
+		//     zx  : ZX(self.xyw.0 * rhs.x.0) - ZX(self.yzw.0 * rhs.z.0),
+		//     xy  : XY(self.yzw.0 * rhs.y.0) - XY(self.zxw.0 * rhs.x.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.wx.0 * rhs.x.0) + XYZ(self.wy.0 * rhs.y.0) + XYZ(self.wz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xyzw.anti_dot(rhs.x),
+			y: self.xyzw.anti_dot(rhs.y),
+			z: self.xyzw.anti_dot(rhs.z),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.xyw.anti_dot(rhs.y) + self.zxw.anti_dot(rhs.z),
+			zx: self.xyw.anti_dot(rhs.x) + self.yzw.anti_dot(rhs.z),
+			xy: self.yzw.anti_dot(rhs.y) + self.zxw.anti_dot(rhs.x),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.wx.anti_dot(rhs.x) + self.wy.anti_dot(rhs.y) + self.wz.anti_dot(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Vec4:
+
+// Multivector.geometric(Vec4) -> Multivector
+impl Geometric<Vec4> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : Z(self.s.0 * rhs.z.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0) + WX(self.xyw.0 * rhs.y.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) - WY(self.xyw.0 * rhs.x.0) - WY(self.y.0 * rhs.w.0) + WY(self.yzw.0 * rhs.z.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) - WZ(self.yzw.0 * rhs.y.0) - WZ(self.z.0 * rhs.w.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.xyz.0 * rhs.x.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.xyz.0 * rhs.y.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) + XY(self.xyz.0 * rhs.z.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : YZW(self.wy.0 * rhs.z.0) - YZW(self.wz.0 * rhs.y.0) - YZW(self.xyzw.0 * rhs.x.0) + YZW(self.yz.0 * rhs.w.0),
+		//     zxw : -ZXW(self.wx.0 * rhs.z.0) + ZXW(self.wz.0 * rhs.x.0) - ZXW(self.xyzw.0 * rhs.y.0) + ZXW(self.zx.0 * rhs.w.0),
+		//     xyw : XYW(self.wx.0 * rhs.y.0) - XYW(self.wy.0 * rhs.x.0) + XYW(self.xy.0 * rhs.w.0) - XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		//     xyzw: -XYZW(self.xyw.0 * rhs.z.0) + XYZW(self.xyz.0 * rhs.w.0) - XYZW(self.yzw.0 * rhs.x.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y) + self.zx.geometric(rhs.z),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x) + self.yz.geometric(rhs.z),
+			z: self.s.geometric(rhs.z) + self.yz.geometric(rhs.y) + self.zx.geometric(rhs.x),
+			w: self.s.geometric(rhs.w) + self.wx.geometric(rhs.x) + self.wy.geometric(rhs.y) + self.wz.geometric(rhs.z),
+			wx: self.w.geometric(rhs.x)
+				+ self.x.geometric(rhs.w)
+				+ self.xyw.geometric(rhs.y)
+				+ self.zxw.geometric(rhs.z),
+			wy: self.w.geometric(rhs.y)
+				+ self.xyw.geometric(rhs.x)
+				+ self.y.geometric(rhs.w)
+				+ self.yzw.geometric(rhs.z),
+			wz: self.w.geometric(rhs.z)
+				+ self.yzw.geometric(rhs.y)
+				+ self.z.geometric(rhs.w)
+				+ self.zxw.geometric(rhs.x),
+			yz: self.xyz.geometric(rhs.x) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zx: self.x.geometric(rhs.z) + self.xyz.geometric(rhs.y) + self.z.geometric(rhs.x),
+			xy: self.x.geometric(rhs.y) + self.xyz.geometric(rhs.z) + self.y.geometric(rhs.x),
+			yzw: self.wy.geometric(rhs.z)
+				+ self.wz.geometric(rhs.y)
+				+ self.xyzw.geometric(rhs.x)
+				+ self.yz.geometric(rhs.w),
+			zxw: self.wx.geometric(rhs.z)
+				+ self.wz.geometric(rhs.x)
+				+ self.xyzw.geometric(rhs.y)
+				+ self.zx.geometric(rhs.w),
+			xyw: self.wx.geometric(rhs.y)
+				+ self.wy.geometric(rhs.x)
+				+ self.xy.geometric(rhs.w)
+				+ self.xyzw.geometric(rhs.z),
+			xyz: self.xy.geometric(rhs.z) + self.yz.geometric(rhs.x) + self.zx.geometric(rhs.y),
+			xyzw: self.xyw.geometric(rhs.z)
+				+ self.xyz.geometric(rhs.w)
+				+ self.yzw.geometric(rhs.x)
+				+ self.zxw.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec4> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Vec4) -> Multivector
+impl AntiGeometric<Vec4> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xyw.0 * rhs.z.0) + S(self.xyz.0 * rhs.w.0) - S(self.yzw.0 * rhs.x.0) - S(self.zxw.0 * rhs.y.0),
+		//     x   : X(self.wy.0 * rhs.z.0) - X(self.wz.0 * rhs.y.0) + X(self.xyzw.0 * rhs.x.0) + X(self.yz.0 * rhs.w.0),
+		//     y   : -Y(self.wx.0 * rhs.z.0) + Y(self.wz.0 * rhs.x.0) + Y(self.xyzw.0 * rhs.y.0) + Y(self.zx.0 * rhs.w.0),
+		//     z   : Z(self.wx.0 * rhs.y.0) - Z(self.wy.0 * rhs.x.0) + Z(self.xy.0 * rhs.w.0) + Z(self.xyzw.0 * rhs.z.0),
+		//     w   : W(self.xyzw.0 * rhs.w.0),
+		//     wx  : -WX(self.yzw.0 * rhs.w.0),
+		//     wy  : -WY(self.zxw.0 * rhs.w.0),
+		//     wz  : -WZ(self.xyw.0 * rhs.w.0),
+		//     yz  : YZ(self.w.0 * rhs.x.0) - YZ(self.x.0 * rhs.w.0) - YZ(self.xyw.0 * rhs.y.0) + YZ(self.zxw.0 * rhs.z.0),
+		//     zx  : ZX(self.w.0 * rhs.y.0) + ZX(self.xyw.0 * rhs.x.0) - ZX(self.y.0 * rhs.w.0) - ZX(self.yzw.0 * rhs.z.0),
+		//     xy  : XY(self.w.0 * rhs.z.0) + XY(self.yzw.0 * rhs.y.0) - XY(self.z.0 * rhs.w.0) - XY(self.zxw.0 * rhs.x.0),
+		//     yzw : YZW(self.wx.0 * rhs.w.0),
+		//     zxw : ZXW(self.wy.0 * rhs.w.0),
+		//     xyw : XYW(self.wz.0 * rhs.w.0),
+		//     xyz : -XYZ(self.s.0 * rhs.w.0) + XYZ(self.wx.0 * rhs.x.0) + XYZ(self.wy.0 * rhs.y.0) + XYZ(self.wz.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.w.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.xyw.anti_geometric(rhs.z)
+				+ self.xyz.anti_geometric(rhs.w)
+				+ self.yzw.anti_geometric(rhs.x)
+				+ self.zxw.anti_geometric(rhs.y),
+			x: self.wy.anti_geometric(rhs.z)
+				+ self.wz.anti_geometric(rhs.y)
+				+ self.xyzw.anti_geometric(rhs.x)
+				+ self.yz.anti_geometric(rhs.w),
+			y: self.wx.anti_geometric(rhs.z)
+				+ self.wz.anti_geometric(rhs.x)
+				+ self.xyzw.anti_geometric(rhs.y)
+				+ self.zx.anti_geometric(rhs.w),
+			z: self.wx.anti_geometric(rhs.y)
+				+ self.wy.anti_geometric(rhs.x)
+				+ self.xy.anti_geometric(rhs.w)
+				+ self.xyzw.anti_geometric(rhs.z),
+			w: self.xyzw.anti_geometric(rhs.w),
+			wx: self.yzw.anti_geometric(rhs.w),
+			wy: self.zxw.anti_geometric(rhs.w),
+			wz: self.xyw.anti_geometric(rhs.w),
+			yz: self.w.anti_geometric(rhs.x)
+				+ self.x.anti_geometric(rhs.w)
+				+ self.xyw.anti_geometric(rhs.y)
+				+ self.zxw.anti_geometric(rhs.z),
+			zx: self.w.anti_geometric(rhs.y)
+				+ self.xyw.anti_geometric(rhs.x)
+				+ self.y.anti_geometric(rhs.w)
+				+ self.yzw.anti_geometric(rhs.z),
+			xy: self.w.anti_geometric(rhs.z)
+				+ self.yzw.anti_geometric(rhs.y)
+				+ self.z.anti_geometric(rhs.w)
+				+ self.zxw.anti_geometric(rhs.x),
+			yzw: self.wx.anti_geometric(rhs.w),
+			zxw: self.wy.anti_geometric(rhs.w),
+			xyw: self.wz.anti_geometric(rhs.w),
+			xyz: self.s.anti_geometric(rhs.w)
+				+ self.wx.anti_geometric(rhs.x)
+				+ self.wy.anti_geometric(rhs.y)
+				+ self.wz.anti_geometric(rhs.z),
+			xyzw: self.w.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Multivector.dot(Vec4) -> Multivector
+impl Dot<Vec4> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : Z(self.s.0 * rhs.z.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.s.0 * rhs.w.0) + W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.xyw.0 * rhs.y.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : -WY(self.xyw.0 * rhs.x.0) + WY(self.yzw.0 * rhs.z.0),
+		//     wz  : -WZ(self.yzw.0 * rhs.y.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.xyz.0 * rhs.x.0),
+		//     zx  : ZX(self.xyz.0 * rhs.y.0),
+		//     xy  : XY(self.xyz.0 * rhs.z.0),
+		//     yzw : -YZW(self.xyzw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.xyzw.0 * rhs.y.0),
+		//     xyw : -XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y) + self.zx.dot(rhs.z),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x) + self.yz.dot(rhs.z),
+			z: self.s.dot(rhs.z) + self.yz.dot(rhs.y) + self.zx.dot(rhs.x),
+			w: self.s.dot(rhs.w) + self.wx.dot(rhs.x) + self.wy.dot(rhs.y) + self.wz.dot(rhs.z),
+			wx: self.xyw.dot(rhs.y) + self.zxw.dot(rhs.z),
+			wy: self.xyw.dot(rhs.x) + self.yzw.dot(rhs.z),
+			wz: self.yzw.dot(rhs.y) + self.zxw.dot(rhs.x),
+			yz: self.xyz.dot(rhs.x),
+			zx: self.xyz.dot(rhs.y),
+			xy: self.xyz.dot(rhs.z),
+			yzw: self.xyzw.dot(rhs.x),
+			zxw: self.xyzw.dot(rhs.y),
+			xyw: self.xyzw.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec4> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Vec4) -> Multivector
+impl Wedge<Vec4> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.s.0 * rhs.x.0),
+		//     y   : Y(self.s.0 * rhs.y.0),
+		//     z   : Z(self.s.0 * rhs.z.0),
+		//     w   : W(self.s.0 * rhs.w.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) - WY(self.y.0 * rhs.w.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) - WZ(self.z.0 * rhs.w.0),
+		//     yz  : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : YZW(self.wy.0 * rhs.z.0) - YZW(self.wz.0 * rhs.y.0) + YZW(self.yz.0 * rhs.w.0),
+		//     zxw : -ZXW(self.wx.0 * rhs.z.0) + ZXW(self.wz.0 * rhs.x.0) + ZXW(self.zx.0 * rhs.w.0),
+		//     xyw : XYW(self.wx.0 * rhs.y.0) - XYW(self.wy.0 * rhs.x.0) + XYW(self.xy.0 * rhs.w.0),
+		//     xyz : XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		//     xyzw: -XYZW(self.xyw.0 * rhs.z.0) + XYZW(self.xyz.0 * rhs.w.0) - XYZW(self.yzw.0 * rhs.x.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			z: self.s.wedge(rhs.z),
+			w: self.s.wedge(rhs.w),
+			wx: self.w.wedge(rhs.x) + self.x.wedge(rhs.w),
+			wy: self.w.wedge(rhs.y) + self.y.wedge(rhs.w),
+			wz: self.w.wedge(rhs.z) + self.z.wedge(rhs.w),
+			yz: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			zx: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			yzw: self.wy.wedge(rhs.z) + self.wz.wedge(rhs.y) + self.yz.wedge(rhs.w),
+			zxw: self.wx.wedge(rhs.z) + self.wz.wedge(rhs.x) + self.zx.wedge(rhs.w),
+			xyw: self.wx.wedge(rhs.y) + self.wy.wedge(rhs.x) + self.xy.wedge(rhs.w),
+			xyz: self.xy.wedge(rhs.z) + self.yz.wedge(rhs.x) + self.zx.wedge(rhs.y),
+			xyzw: self.xyw.wedge(rhs.z) + self.xyz.wedge(rhs.w) + self.yzw.wedge(rhs.x) + self.zxw.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec4> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Vec4) -> Multivector
+impl AntiWedge<Vec4> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xyw.0 * rhs.z.0) + S(self.xyz.0 * rhs.w.0) - S(self.yzw.0 * rhs.x.0) - S(self.zxw.0 * rhs.y.0),
+		//     x   : X(self.xyzw.0 * rhs.x.0),
+		//     y   : Y(self.xyzw.0 * rhs.y.0),
+		//     z   : Z(self.xyzw.0 * rhs.z.0),
+		//     w   : W(self.xyzw.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xyw.anti_wedge(rhs.z)
+				+ self.xyz.anti_wedge(rhs.w)
+				+ self.yzw.anti_wedge(rhs.x)
+				+ self.zxw.anti_wedge(rhs.y),
+			x: self.xyzw.anti_wedge(rhs.x),
+			y: self.xyzw.anti_wedge(rhs.y),
+			z: self.xyzw.anti_wedge(rhs.z),
+			w: self.xyzw.anti_wedge(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec4> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Vec4) -> Multivector
+impl LeftContraction<Vec4> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.s.0 * rhs.x.0),
+		//     y   : Y(self.s.0 * rhs.y.0),
+		//     z   : Z(self.s.0 * rhs.z.0),
+		//     w   : W(self.s.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			z: self.s.left_contraction(rhs.z),
+			w: self.s.left_contraction(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Vec4) -> Multivector
+impl RightContraction<Vec4> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.xyw.0 * rhs.y.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : -WY(self.xyw.0 * rhs.x.0) + WY(self.yzw.0 * rhs.z.0),
+		//     wz  : -WZ(self.yzw.0 * rhs.y.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.xyz.0 * rhs.x.0),
+		//     zx  : ZX(self.xyz.0 * rhs.y.0),
+		//     xy  : XY(self.xyz.0 * rhs.z.0),
+		//     yzw : -YZW(self.xyzw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.xyzw.0 * rhs.y.0),
+		//     xyw : -XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z),
+			x: self.xy.right_contraction(rhs.y) + self.zx.right_contraction(rhs.z),
+			y: self.xy.right_contraction(rhs.x) + self.yz.right_contraction(rhs.z),
+			z: self.yz.right_contraction(rhs.y) + self.zx.right_contraction(rhs.x),
+			w: self.wx.right_contraction(rhs.x) + self.wy.right_contraction(rhs.y) + self.wz.right_contraction(rhs.z),
+			wx: self.xyw.right_contraction(rhs.y) + self.zxw.right_contraction(rhs.z),
+			wy: self.xyw.right_contraction(rhs.x) + self.yzw.right_contraction(rhs.z),
+			wz: self.yzw.right_contraction(rhs.y) + self.zxw.right_contraction(rhs.x),
+			yz: self.xyz.right_contraction(rhs.x),
+			zx: self.xyz.right_contraction(rhs.y),
+			xy: self.xyz.right_contraction(rhs.z),
+			yzw: self.xyzw.right_contraction(rhs.x),
+			zxw: self.xyzw.right_contraction(rhs.y),
+			xyw: self.xyzw.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Vec4) -> S
+impl ScalarProduct<Vec4> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec4) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Multivector.commutator(Vec4) -> Multivector
+impl Commutator<Vec4> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) - WY(self.y.0 * rhs.w.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) - WZ(self.z.0 * rhs.w.0),
+		//     yz  : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : -YZW(self.xyzw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.xyzw.0 * rhs.y.0),
+		//     xyw : -XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: -XYZW(self.xyw.0 * rhs.z.0) + XYZW(self.xyz.0 * rhs.w.0) - XYZW(self.yzw.0 * rhs.x.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y) + self.zx.commutator(rhs.z),
+			y: self.xy.commutator(rhs.x) + self.yz.commutator(rhs.z),
+			z: self.yz.commutator(rhs.y) + self.zx.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x) + self.wy.commutator(rhs.y) + self.wz.commutator(rhs.z),
+			wx: self.w.commutator(rhs.x) + self.x.commutator(rhs.w),
+			wy: self.w.commutator(rhs.y) + self.y.commutator(rhs.w),
+			wz: self.w.commutator(rhs.z) + self.z.commutator(rhs.w),
+			yz: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			zx: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			yzw: self.xyzw.commutator(rhs.x),
+			zxw: self.xyzw.commutator(rhs.y),
+			xyw: self.xyzw.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: self.xyw.commutator(rhs.z)
+				+ self.xyz.commutator(rhs.w)
+				+ self.yzw.commutator(rhs.x)
+				+ self.zxw.commutator(rhs.y),
+		}
+	}
+}
+
+// Multivector.anti_dot(Vec4) -> Multivector
+impl AntiDot<Vec4> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.xyzw.0 * rhs.x.0),
+		//     y   : Y(self.xyzw.0 * rhs.y.0),
+		//     z   : Z(self.xyzw.0 * rhs.z.0),
+		//     w   : W(self.xyzw.0 * rhs.w.0),
+		//     wx  : -WX(self.yzw.0 * rhs.w.0),
+		//     wy  : -WY(self.zxw.0 * rhs.w.0),
+		//     wz  : -WZ(self.xyw.0 * rhs.w.0),
+		//     yz  : -YZ(self.xyw.0 * rhs.y.0) + YZ(self.zxw.0 * rhs.z.0),
+		//     zx  : ZX(self.xyw.0 * rhs.x.0) - ZX(self.yzw.0 * rhs.z.0),
+		//     xy  : XY(self.yzw.0 * rhs.y.0) - XY(self.zxw.0 * rhs.x.0),
+		//     yzw : YZW(self.wx.0 * rhs.w.0),
+		//     zxw : ZXW(self.wy.0 * rhs.w.0),
+		//     xyw : XYW(self.wz.0 * rhs.w.0),
+		//     xyz : -XYZ(self.s.0 * rhs.w.0) + XYZ(self.wx.0 * rhs.x.0) + XYZ(self.wy.0 * rhs.y.0) + XYZ(self.wz.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.w.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xyzw.anti_dot(rhs.x),
+			y: self.xyzw.anti_dot(rhs.y),
+			z: self.xyzw.anti_dot(rhs.z),
+			w: self.xyzw.anti_dot(rhs.w),
+			wx: self.yzw.anti_dot(rhs.w),
+			wy: self.zxw.anti_dot(rhs.w),
+			wz: self.xyw.anti_dot(rhs.w),
+			yz: self.xyw.anti_dot(rhs.y) + self.zxw.anti_dot(rhs.z),
+			zx: self.xyw.anti_dot(rhs.x) + self.yzw.anti_dot(rhs.z),
+			xy: self.yzw.anti_dot(rhs.y) + self.zxw.anti_dot(rhs.x),
+			yzw: self.wx.anti_dot(rhs.w),
+			zxw: self.wy.anti_dot(rhs.w),
+			xyw: self.wz.anti_dot(rhs.w),
+			xyz: self.s.anti_dot(rhs.w) + self.wx.anti_dot(rhs.x) + self.wy.anti_dot(rhs.y) + self.wz.anti_dot(rhs.z),
+			xyzw: self.w.anti_dot(rhs.w),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Point3:
+
+// Multivector.geometric(Point3) -> Multivector
+impl Geometric<Point3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : Z(self.s.0 * rhs.z.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.s.0) + W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) - WX(self.x.0) + WX(self.xyw.0 * rhs.y.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) - WY(self.xyw.0 * rhs.x.0) - WY(self.y.0) + WY(self.yzw.0 * rhs.z.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) - WZ(self.yzw.0 * rhs.y.0) - WZ(self.z.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.xyz.0 * rhs.x.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.xyz.0 * rhs.y.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) + XY(self.xyz.0 * rhs.z.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : YZW(self.wy.0 * rhs.z.0) - YZW(self.wz.0 * rhs.y.0) - YZW(self.xyzw.0 * rhs.x.0) + YZW(self.yz.0),
+		//     zxw : -ZXW(self.wx.0 * rhs.z.0) + ZXW(self.wz.0 * rhs.x.0) - ZXW(self.xyzw.0 * rhs.y.0) + ZXW(self.zx.0),
+		//     xyw : XYW(self.wx.0 * rhs.y.0) - XYW(self.wy.0 * rhs.x.0) + XYW(self.xy.0) - XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		//     xyzw: -XYZW(self.xyw.0 * rhs.z.0) + XYZW(self.xyz.0) - XYZW(self.yzw.0 * rhs.x.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y) + self.zx.geometric(rhs.z),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x) + self.yz.geometric(rhs.z),
+			z: self.s.geometric(rhs.z) + self.yz.geometric(rhs.y) + self.zx.geometric(rhs.x),
+			w: self.s.geometric(W(1.0))
+				+ self.wx.geometric(rhs.x)
+				+ self.wy.geometric(rhs.y)
+				+ self.wz.geometric(rhs.z),
+			wx: self.w.geometric(rhs.x)
+				+ self.x.geometric(W(1.0))
+				+ self.xyw.geometric(rhs.y)
+				+ self.zxw.geometric(rhs.z),
+			wy: self.w.geometric(rhs.y)
+				+ self.xyw.geometric(rhs.x)
+				+ self.y.geometric(W(1.0))
+				+ self.yzw.geometric(rhs.z),
+			wz: self.w.geometric(rhs.z)
+				+ self.yzw.geometric(rhs.y)
+				+ self.z.geometric(W(1.0))
+				+ self.zxw.geometric(rhs.x),
+			yz: self.xyz.geometric(rhs.x) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zx: self.x.geometric(rhs.z) + self.xyz.geometric(rhs.y) + self.z.geometric(rhs.x),
+			xy: self.x.geometric(rhs.y) + self.xyz.geometric(rhs.z) + self.y.geometric(rhs.x),
+			yzw: self.wy.geometric(rhs.z)
+				+ self.wz.geometric(rhs.y)
+				+ self.xyzw.geometric(rhs.x)
+				+ self.yz.geometric(W(1.0)),
+			zxw: self.wx.geometric(rhs.z)
+				+ self.wz.geometric(rhs.x)
+				+ self.xyzw.geometric(rhs.y)
+				+ self.zx.geometric(W(1.0)),
+			xyw: self.wx.geometric(rhs.y)
+				+ self.wy.geometric(rhs.x)
+				+ self.xy.geometric(W(1.0))
+				+ self.xyzw.geometric(rhs.z),
+			xyz: self.xy.geometric(rhs.z) + self.yz.geometric(rhs.x) + self.zx.geometric(rhs.y),
+			xyzw: self.xyw.geometric(rhs.z)
+				+ self.xyz.geometric(W(1.0))
+				+ self.yzw.geometric(rhs.x)
+				+ self.zxw.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Point3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Point3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Point3) -> Multivector
+impl AntiGeometric<Point3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xyw.0 * rhs.z.0) + S(self.xyz.0) - S(self.yzw.0 * rhs.x.0) - S(self.zxw.0 * rhs.y.0),
+		//     x   : X(self.wy.0 * rhs.z.0) - X(self.wz.0 * rhs.y.0) + X(self.xyzw.0 * rhs.x.0) + X(self.yz.0),
+		//     y   : -Y(self.wx.0 * rhs.z.0) + Y(self.wz.0 * rhs.x.0) + Y(self.xyzw.0 * rhs.y.0) + Y(self.zx.0),
+		//     z   : Z(self.wx.0 * rhs.y.0) - Z(self.wy.0 * rhs.x.0) + Z(self.xy.0) + Z(self.xyzw.0 * rhs.z.0),
+		//     w   : W(self.xyzw.0),
+		//     wx  : -WX(self.yzw.0),
+		//     wy  : -WY(self.zxw.0),
+		//     wz  : -WZ(self.xyw.0),
+		//     yz  : YZ(self.w.0 * rhs.x.0) - YZ(self.x.0) - YZ(self.xyw.0 * rhs.y.0) + YZ(self.zxw.0 * rhs.z.0),
+		//     zx  : ZX(self.w.0 * rhs.y.0) + ZX(self.xyw.0 * rhs.x.0) - ZX(self.y.0) - ZX(self.yzw.0 * rhs.z.0),
+		//     xy  : XY(self.w.0 * rhs.z.0) + XY(self.yzw.0 * rhs.y.0) - XY(self.z.0) - XY(self.zxw.0 * rhs.x.0),
+		//     yzw : YZW(self.wx.0),
+		//     zxw : ZXW(self.wy.0),
+		//     xyw : XYW(self.wz.0),
+		//     xyz : -XYZ(self.s.0) + XYZ(self.wx.0 * rhs.x.0) + XYZ(self.wy.0 * rhs.y.0) + XYZ(self.wz.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.w.0),
+		// }
+		Multivector {
+			s: self.xyw.anti_geometric(rhs.z)
+				+ self.xyz.anti_geometric(W(1.0))
+				+ self.yzw.anti_geometric(rhs.x)
+				+ self.zxw.anti_geometric(rhs.y),
+			x: self.wy.anti_geometric(rhs.z)
+				+ self.wz.anti_geometric(rhs.y)
+				+ self.xyzw.anti_geometric(rhs.x)
+				+ self.yz.anti_geometric(W(1.0)),
+			y: self.wx.anti_geometric(rhs.z)
+				+ self.wz.anti_geometric(rhs.x)
+				+ self.xyzw.anti_geometric(rhs.y)
+				+ self.zx.anti_geometric(W(1.0)),
+			z: self.wx.anti_geometric(rhs.y)
+				+ self.wy.anti_geometric(rhs.x)
+				+ self.xy.anti_geometric(W(1.0))
+				+ self.xyzw.anti_geometric(rhs.z),
+			w: self.xyzw.anti_geometric(W(1.0)),
+			wx: self.yzw.anti_geometric(W(1.0)),
+			wy: self.zxw.anti_geometric(W(1.0)),
+			wz: self.xyw.anti_geometric(W(1.0)),
+			yz: self.w.anti_geometric(rhs.x)
+				+ self.x.anti_geometric(W(1.0))
+				+ self.xyw.anti_geometric(rhs.y)
+				+ self.zxw.anti_geometric(rhs.z),
+			zx: self.w.anti_geometric(rhs.y)
+				+ self.xyw.anti_geometric(rhs.x)
+				+ self.y.anti_geometric(W(1.0))
+				+ self.yzw.anti_geometric(rhs.z),
+			xy: self.w.anti_geometric(rhs.z)
+				+ self.yzw.anti_geometric(rhs.y)
+				+ self.z.anti_geometric(W(1.0))
+				+ self.zxw.anti_geometric(rhs.x),
+			yzw: self.wx.anti_geometric(W(1.0)),
+			zxw: self.wy.anti_geometric(W(1.0)),
+			xyw: self.wz.anti_geometric(W(1.0)),
+			xyz: self.s.anti_geometric(W(1.0))
+				+ self.wx.anti_geometric(rhs.x)
+				+ self.wy.anti_geometric(rhs.y)
+				+ self.wz.anti_geometric(rhs.z),
+			xyzw: self.w.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Multivector.dot(Point3) -> Multivector
+impl Dot<Point3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : Z(self.s.0 * rhs.z.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.s.0) + W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.xyw.0 * rhs.y.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : -WY(self.xyw.0 * rhs.x.0) + WY(self.yzw.0 * rhs.z.0),
+		//     wz  : -WZ(self.yzw.0 * rhs.y.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.xyz.0 * rhs.x.0),
+		//     zx  : ZX(self.xyz.0 * rhs.y.0),
+		//     xy  : XY(self.xyz.0 * rhs.z.0),
+		//     yzw : -YZW(self.xyzw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.xyzw.0 * rhs.y.0),
+		//     xyw : -XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y) + self.zx.dot(rhs.z),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x) + self.yz.dot(rhs.z),
+			z: self.s.dot(rhs.z) + self.yz.dot(rhs.y) + self.zx.dot(rhs.x),
+			w: self.s.dot(W(1.0)) + self.wx.dot(rhs.x) + self.wy.dot(rhs.y) + self.wz.dot(rhs.z),
+			wx: self.xyw.dot(rhs.y) + self.zxw.dot(rhs.z),
+			wy: self.xyw.dot(rhs.x) + self.yzw.dot(rhs.z),
+			wz: self.yzw.dot(rhs.y) + self.zxw.dot(rhs.x),
+			yz: self.xyz.dot(rhs.x),
+			zx: self.xyz.dot(rhs.y),
+			xy: self.xyz.dot(rhs.z),
+			yzw: self.xyzw.dot(rhs.x),
+			zxw: self.xyzw.dot(rhs.y),
+			xyw: self.xyzw.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Point3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Point3) -> Multivector
+impl Wedge<Point3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.s.0 * rhs.x.0),
+		//     y   : Y(self.s.0 * rhs.y.0),
+		//     z   : Z(self.s.0 * rhs.z.0),
+		//     w   : W(self.s.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) - WX(self.x.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) - WY(self.y.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) - WZ(self.z.0),
+		//     yz  : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : YZW(self.wy.0 * rhs.z.0) - YZW(self.wz.0 * rhs.y.0) + YZW(self.yz.0),
+		//     zxw : -ZXW(self.wx.0 * rhs.z.0) + ZXW(self.wz.0 * rhs.x.0) + ZXW(self.zx.0),
+		//     xyw : XYW(self.wx.0 * rhs.y.0) - XYW(self.wy.0 * rhs.x.0) + XYW(self.xy.0),
+		//     xyz : XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		//     xyzw: -XYZW(self.xyw.0 * rhs.z.0) + XYZW(self.xyz.0) - XYZW(self.yzw.0 * rhs.x.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			z: self.s.wedge(rhs.z),
+			w: self.s.wedge(W(1.0)),
+			wx: self.w.wedge(rhs.x) + self.x.wedge(W(1.0)),
+			wy: self.w.wedge(rhs.y) + self.y.wedge(W(1.0)),
+			wz: self.w.wedge(rhs.z) + self.z.wedge(W(1.0)),
+			yz: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			zx: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			yzw: self.wy.wedge(rhs.z) + self.wz.wedge(rhs.y) + self.yz.wedge(W(1.0)),
+			zxw: self.wx.wedge(rhs.z) + self.wz.wedge(rhs.x) + self.zx.wedge(W(1.0)),
+			xyw: self.wx.wedge(rhs.y) + self.wy.wedge(rhs.x) + self.xy.wedge(W(1.0)),
+			xyz: self.xy.wedge(rhs.z) + self.yz.wedge(rhs.x) + self.zx.wedge(rhs.y),
+			xyzw: self.xyw.wedge(rhs.z) + self.xyz.wedge(W(1.0)) + self.yzw.wedge(rhs.x) + self.zxw.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Point3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Point3) -> Multivector
+impl AntiWedge<Point3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xyw.0 * rhs.z.0) + S(self.xyz.0) - S(self.yzw.0 * rhs.x.0) - S(self.zxw.0 * rhs.y.0),
+		//     x   : X(self.xyzw.0 * rhs.x.0),
+		//     y   : Y(self.xyzw.0 * rhs.y.0),
+		//     z   : Z(self.xyzw.0 * rhs.z.0),
+		//     w   : W(self.xyzw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xyw.anti_wedge(rhs.z)
+				+ self.xyz.anti_wedge(W(1.0))
+				+ self.yzw.anti_wedge(rhs.x)
+				+ self.zxw.anti_wedge(rhs.y),
+			x: self.xyzw.anti_wedge(rhs.x),
+			y: self.xyzw.anti_wedge(rhs.y),
+			z: self.xyzw.anti_wedge(rhs.z),
+			w: self.xyzw.anti_wedge(W(1.0)),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Point3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Point3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Point3) -> Multivector
+impl LeftContraction<Point3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.s.0 * rhs.x.0),
+		//     y   : Y(self.s.0 * rhs.y.0),
+		//     z   : Z(self.s.0 * rhs.z.0),
+		//     w   : W(self.s.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			z: self.s.left_contraction(rhs.z),
+			w: self.s.left_contraction(W(1.0)),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Point3) -> Multivector
+impl RightContraction<Point3> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.xyw.0 * rhs.y.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : -WY(self.xyw.0 * rhs.x.0) + WY(self.yzw.0 * rhs.z.0),
+		//     wz  : -WZ(self.yzw.0 * rhs.y.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.xyz.0 * rhs.x.0),
+		//     zx  : ZX(self.xyz.0 * rhs.y.0),
+		//     xy  : XY(self.xyz.0 * rhs.z.0),
+		//     yzw : -YZW(self.xyzw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.xyzw.0 * rhs.y.0),
+		//     xyw : -XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z),
+			x: self.xy.right_contraction(rhs.y) + self.zx.right_contraction(rhs.z),
+			y: self.xy.right_contraction(rhs.x) + self.yz.right_contraction(rhs.z),
+			z: self.yz.right_contraction(rhs.y) + self.zx.right_contraction(rhs.x),
+			w: self.wx.right_contraction(rhs.x) + self.wy.right_contraction(rhs.y) + self.wz.right_contraction(rhs.z),
+			wx: self.xyw.right_contraction(rhs.y) + self.zxw.right_contraction(rhs.z),
+			wy: self.xyw.right_contraction(rhs.x) + self.yzw.right_contraction(rhs.z),
+			wz: self.yzw.right_contraction(rhs.y) + self.zxw.right_contraction(rhs.x),
+			yz: self.xyz.right_contraction(rhs.x),
+			zx: self.xyz.right_contraction(rhs.y),
+			xy: self.xyz.right_contraction(rhs.z),
+			yzw: self.xyzw.right_contraction(rhs.x),
+			zxw: self.xyzw.right_contraction(rhs.y),
+			xyw: self.xyzw.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Point3) -> S
+impl ScalarProduct<Point3> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Point3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Multivector.commutator(Point3) -> Multivector
+impl Commutator<Point3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) - WX(self.x.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) - WY(self.y.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) - WZ(self.z.0),
+		//     yz  : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : -YZW(self.xyzw.0 * rhs.x.0),
+		//     zxw : -ZXW(self.xyzw.0 * rhs.y.0),
+		//     xyw : -XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: -XYZW(self.xyw.0 * rhs.z.0) + XYZW(self.xyz.0) - XYZW(self.yzw.0 * rhs.x.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y) + self.zx.commutator(rhs.z),
+			y: self.xy.commutator(rhs.x) + self.yz.commutator(rhs.z),
+			z: self.yz.commutator(rhs.y) + self.zx.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x) + self.wy.commutator(rhs.y) + self.wz.commutator(rhs.z),
+			wx: self.w.commutator(rhs.x) + self.x.commutator(W(1.0)),
+			wy: self.w.commutator(rhs.y) + self.y.commutator(W(1.0)),
+			wz: self.w.commutator(rhs.z) + self.z.commutator(W(1.0)),
+			yz: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			zx: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			yzw: self.xyzw.commutator(rhs.x),
+			zxw: self.xyzw.commutator(rhs.y),
+			xyw: self.xyzw.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: self.xyw.commutator(rhs.z)
+				+ self.xyz.commutator(W(1.0))
+				+ self.yzw.commutator(rhs.x)
+				+ self.zxw.commutator(rhs.y),
+		}
+	}
+}
+
+// Multivector.anti_dot(Point3) -> Multivector
+impl AntiDot<Point3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.xyzw.0 * rhs.x.0),
+		//     y   : Y(self.xyzw.0 * rhs.y.0),
+		//     z   : Z(self.xyzw.0 * rhs.z.0),
+		//     w   : W(self.xyzw.0),
+		//     wx  : -WX(self.yzw.0),
+		//     wy  : -WY(self.zxw.0),
+		//     wz  : -WZ(self.xyw.0),
+		//     yz  : -YZ(self.xyw.0 * rhs.y.0) + YZ(self.zxw.0 * rhs.z.0),
+		//     zx  : ZX(self.xyw.0 * rhs.x.0) - ZX(self.yzw.0 * rhs.z.0),
+		//     xy  : XY(self.yzw.0 * rhs.y.0) - XY(self.zxw.0 * rhs.x.0),
+		//     yzw : YZW(self.wx.0),
+		//     zxw : ZXW(self.wy.0),
+		//     xyw : XYW(self.wz.0),
+		//     xyz : -XYZ(self.s.0) + XYZ(self.wx.0 * rhs.x.0) + XYZ(self.wy.0 * rhs.y.0) + XYZ(self.wz.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xyzw.anti_dot(rhs.x),
+			y: self.xyzw.anti_dot(rhs.y),
+			z: self.xyzw.anti_dot(rhs.z),
+			w: self.xyzw.anti_dot(W(1.0)),
+			wx: self.yzw.anti_dot(W(1.0)),
+			wy: self.zxw.anti_dot(W(1.0)),
+			wz: self.xyw.anti_dot(W(1.0)),
+			yz: self.xyw.anti_dot(rhs.y) + self.zxw.anti_dot(rhs.z),
+			zx: self.xyw.anti_dot(rhs.x) + self.yzw.anti_dot(rhs.z),
+			xy: self.yzw.anti_dot(rhs.y) + self.zxw.anti_dot(rhs.x),
+			yzw: self.wx.anti_dot(W(1.0)),
+			zxw: self.wy.anti_dot(W(1.0)),
+			xyw: self.wz.anti_dot(W(1.0)),
+			xyz: self.s.anti_dot(W(1.0)) + self.wx.anti_dot(rhs.x) + self.wy.anti_dot(rhs.y) + self.wz.anti_dot(rhs.z),
+			xyzw: self.w.anti_dot(W(1.0)),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Moment3:
+
+// Multivector.geometric(Moment3) -> Multivector
+impl Geometric<Moment3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.mz.0) - S(self.yz.0 * rhs.mx.0) - S(self.zx.0 * rhs.my.0),
+		//     x   : -X(self.xyz.0 * rhs.mx.0) - X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y   : Y(self.x.0 * rhs.mz.0) - Y(self.xyz.0 * rhs.my.0) - Y(self.z.0 * rhs.mx.0),
+		//     z   : -Z(self.x.0 * rhs.my.0) - Z(self.xyz.0 * rhs.mz.0) + Z(self.y.0 * rhs.mx.0),
+		//     w   : -W(self.xyw.0 * rhs.mz.0) - W(self.yzw.0 * rhs.mx.0) - W(self.zxw.0 * rhs.my.0),
+		//     wx  : -WX(self.wy.0 * rhs.mz.0) + WX(self.wz.0 * rhs.my.0) + WX(self.xyzw.0 * rhs.mx.0),
+		//     wy  : WY(self.wx.0 * rhs.mz.0) - WY(self.wz.0 * rhs.mx.0) + WY(self.xyzw.0 * rhs.my.0),
+		//     wz  : -WZ(self.wx.0 * rhs.my.0) + WZ(self.wy.0 * rhs.mx.0) + WZ(self.xyzw.0 * rhs.mz.0),
+		//     yz  : YZ(self.s.0 * rhs.mx.0) + YZ(self.xy.0 * rhs.my.0) - YZ(self.zx.0 * rhs.mz.0),
+		//     zx  : ZX(self.s.0 * rhs.my.0) - ZX(self.xy.0 * rhs.mx.0) + ZX(self.yz.0 * rhs.mz.0),
+		//     xy  : XY(self.s.0 * rhs.mz.0) - XY(self.yz.0 * rhs.my.0) + XY(self.zx.0 * rhs.mx.0),
+		//     yzw : YZW(self.w.0 * rhs.mx.0) + YZW(self.xyw.0 * rhs.my.0) - YZW(self.zxw.0 * rhs.mz.0),
+		//     zxw : ZXW(self.w.0 * rhs.my.0) - ZXW(self.xyw.0 * rhs.mx.0) + ZXW(self.yzw.0 * rhs.mz.0),
+		//     xyw : XYW(self.w.0 * rhs.mz.0) - XYW(self.yzw.0 * rhs.my.0) + XYW(self.zxw.0 * rhs.mx.0),
+		//     xyz : XYZ(self.x.0 * rhs.mx.0) + XYZ(self.y.0 * rhs.my.0) + XYZ(self.z.0 * rhs.mz.0),
+		//     xyzw: -XYZW(self.wx.0 * rhs.mx.0) - XYZW(self.wy.0 * rhs.my.0) - XYZW(self.wz.0 * rhs.mz.0),
+		// }
+		Multivector {
+			s: self.xy.geometric(rhs.mz) + self.yz.geometric(rhs.mx) + self.zx.geometric(rhs.my),
+			x: self.xyz.geometric(rhs.mx) + self.y.geometric(rhs.mz) + self.z.geometric(rhs.my),
+			y: self.x.geometric(rhs.mz) + self.xyz.geometric(rhs.my) + self.z.geometric(rhs.mx),
+			z: self.x.geometric(rhs.my) + self.xyz.geometric(rhs.mz) + self.y.geometric(rhs.mx),
+			w: self.xyw.geometric(rhs.mz) + self.yzw.geometric(rhs.mx) + self.zxw.geometric(rhs.my),
+			wx: self.wy.geometric(rhs.mz) + self.wz.geometric(rhs.my) + self.xyzw.geometric(rhs.mx),
+			wy: self.wx.geometric(rhs.mz) + self.wz.geometric(rhs.mx) + self.xyzw.geometric(rhs.my),
+			wz: self.wx.geometric(rhs.my) + self.wy.geometric(rhs.mx) + self.xyzw.geometric(rhs.mz),
+			yz: self.s.geometric(rhs.mx) + self.xy.geometric(rhs.my) + self.zx.geometric(rhs.mz),
+			zx: self.s.geometric(rhs.my) + self.xy.geometric(rhs.mx) + self.yz.geometric(rhs.mz),
+			xy: self.s.geometric(rhs.mz) + self.yz.geometric(rhs.my) + self.zx.geometric(rhs.mx),
+			yzw: self.w.geometric(rhs.mx) + self.xyw.geometric(rhs.my) + self.zxw.geometric(rhs.mz),
+			zxw: self.w.geometric(rhs.my) + self.xyw.geometric(rhs.mx) + self.yzw.geometric(rhs.mz),
+			xyw: self.w.geometric(rhs.mz) + self.yzw.geometric(rhs.my) + self.zxw.geometric(rhs.mx),
+			xyz: self.x.geometric(rhs.mx) + self.y.geometric(rhs.my) + self.z.geometric(rhs.mz),
+			xyzw: self.wx.geometric(rhs.mx) + self.wy.geometric(rhs.my) + self.wz.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Moment3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Moment3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Moment3) -> Multivector
+impl AntiGeometric<Moment3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.wx.0 * rhs.mx.0) - S(self.wy.0 * rhs.my.0) - S(self.wz.0 * rhs.mz.0),
+		//     x   : -X(self.w.0 * rhs.mx.0) + X(self.xyw.0 * rhs.my.0) - X(self.zxw.0 * rhs.mz.0),
+		//     y   : -Y(self.w.0 * rhs.my.0) - Y(self.xyw.0 * rhs.mx.0) + Y(self.yzw.0 * rhs.mz.0),
+		//     z   : -Z(self.w.0 * rhs.mz.0) - Z(self.yzw.0 * rhs.my.0) + Z(self.zxw.0 * rhs.mx.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.wy.0 * rhs.mz.0) - YZ(self.wz.0 * rhs.my.0) + YZ(self.xyzw.0 * rhs.mx.0),
+		//     zx  : -ZX(self.wx.0 * rhs.mz.0) + ZX(self.wz.0 * rhs.mx.0) + ZX(self.xyzw.0 * rhs.my.0),
+		//     xy  : XY(self.wx.0 * rhs.my.0) - XY(self.wy.0 * rhs.mx.0) + XY(self.xyzw.0 * rhs.mz.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : -XYZ(self.xyw.0 * rhs.mz.0) - XYZ(self.yzw.0 * rhs.mx.0) - XYZ(self.zxw.0 * rhs.my.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.wx.anti_geometric(rhs.mx) + self.wy.anti_geometric(rhs.my) + self.wz.anti_geometric(rhs.mz),
+			x: self.w.anti_geometric(rhs.mx) + self.xyw.anti_geometric(rhs.my) + self.zxw.anti_geometric(rhs.mz),
+			y: self.w.anti_geometric(rhs.my) + self.xyw.anti_geometric(rhs.mx) + self.yzw.anti_geometric(rhs.mz),
+			z: self.w.anti_geometric(rhs.mz) + self.yzw.anti_geometric(rhs.my) + self.zxw.anti_geometric(rhs.mx),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.wy.anti_geometric(rhs.mz) + self.wz.anti_geometric(rhs.my) + self.xyzw.anti_geometric(rhs.mx),
+			zx: self.wx.anti_geometric(rhs.mz) + self.wz.anti_geometric(rhs.mx) + self.xyzw.anti_geometric(rhs.my),
+			xy: self.wx.anti_geometric(rhs.my) + self.wy.anti_geometric(rhs.mx) + self.xyzw.anti_geometric(rhs.mz),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.xyw.anti_geometric(rhs.mz) + self.yzw.anti_geometric(rhs.mx) + self.zxw.anti_geometric(rhs.my),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.dot(Moment3) -> Multivector
+impl Dot<Moment3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.mz.0) - S(self.yz.0 * rhs.mx.0) - S(self.zx.0 * rhs.my.0),
+		//     x   : -X(self.xyz.0 * rhs.mx.0) - X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y   : Y(self.x.0 * rhs.mz.0) - Y(self.xyz.0 * rhs.my.0) - Y(self.z.0 * rhs.mx.0),
+		//     z   : -Z(self.x.0 * rhs.my.0) - Z(self.xyz.0 * rhs.mz.0) + Z(self.y.0 * rhs.mx.0),
+		//     w   : -W(self.xyw.0 * rhs.mz.0) - W(self.yzw.0 * rhs.mx.0) - W(self.zxw.0 * rhs.my.0),
+		//     wx  : WX(self.xyzw.0 * rhs.mx.0),
+		//     wy  : WY(self.xyzw.0 * rhs.my.0),
+		//     wz  : WZ(self.xyzw.0 * rhs.mz.0),
+		//     yz  : YZ(self.s.0 * rhs.mx.0),
+		//     zx  : ZX(self.s.0 * rhs.my.0),
+		//     xy  : XY(self.s.0 * rhs.mz.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.dot(rhs.mz) + self.yz.dot(rhs.mx) + self.zx.dot(rhs.my),
+			x: self.xyz.dot(rhs.mx) + self.y.dot(rhs.mz) + self.z.dot(rhs.my),
+			y: self.x.dot(rhs.mz) + self.xyz.dot(rhs.my) + self.z.dot(rhs.mx),
+			z: self.x.dot(rhs.my) + self.xyz.dot(rhs.mz) + self.y.dot(rhs.mx),
+			w: self.xyw.dot(rhs.mz) + self.yzw.dot(rhs.mx) + self.zxw.dot(rhs.my),
+			wx: self.xyzw.dot(rhs.mx),
+			wy: self.xyzw.dot(rhs.my),
+			wz: self.xyzw.dot(rhs.mz),
+			yz: self.s.dot(rhs.mx),
+			zx: self.s.dot(rhs.my),
+			xy: self.s.dot(rhs.mz),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Moment3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Moment3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Moment3) -> Multivector
+impl Wedge<Moment3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.s.0 * rhs.mx.0),
+		//     zx  : ZX(self.s.0 * rhs.my.0),
+		//     xy  : XY(self.s.0 * rhs.mz.0),
+		//     yzw : YZW(self.w.0 * rhs.mx.0),
+		//     zxw : ZXW(self.w.0 * rhs.my.0),
+		//     xyw : XYW(self.w.0 * rhs.mz.0),
+		//     xyz : XYZ(self.x.0 * rhs.mx.0) + XYZ(self.y.0 * rhs.my.0) + XYZ(self.z.0 * rhs.mz.0),
+		//     xyzw: -XYZW(self.wx.0 * rhs.mx.0) - XYZW(self.wy.0 * rhs.my.0) - XYZW(self.wz.0 * rhs.mz.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.s.wedge(rhs.mx),
+			zx: self.s.wedge(rhs.my),
+			xy: self.s.wedge(rhs.mz),
+			yzw: self.w.wedge(rhs.mx),
+			zxw: self.w.wedge(rhs.my),
+			xyw: self.w.wedge(rhs.mz),
+			xyz: self.x.wedge(rhs.mx) + self.y.wedge(rhs.my) + self.z.wedge(rhs.mz),
+			xyzw: self.wx.wedge(rhs.mx) + self.wy.wedge(rhs.my) + self.wz.wedge(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Moment3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Moment3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Moment3) -> Multivector
+impl AntiWedge<Moment3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.wx.0 * rhs.mx.0) - S(self.wy.0 * rhs.my.0) - S(self.wz.0 * rhs.mz.0),
+		//     x   : X(self.xyw.0 * rhs.my.0) - X(self.zxw.0 * rhs.mz.0),
+		//     y   : -Y(self.xyw.0 * rhs.mx.0) + Y(self.yzw.0 * rhs.mz.0),
+		//     z   : -Z(self.yzw.0 * rhs.my.0) + Z(self.zxw.0 * rhs.mx.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.xyzw.0 * rhs.mx.0),
+		//     zx  : ZX(self.xyzw.0 * rhs.my.0),
+		//     xy  : XY(self.xyzw.0 * rhs.mz.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.wx.anti_wedge(rhs.mx) + self.wy.anti_wedge(rhs.my) + self.wz.anti_wedge(rhs.mz),
+			x: self.xyw.anti_wedge(rhs.my) + self.zxw.anti_wedge(rhs.mz),
+			y: self.xyw.anti_wedge(rhs.mx) + self.yzw.anti_wedge(rhs.mz),
+			z: self.yzw.anti_wedge(rhs.my) + self.zxw.anti_wedge(rhs.mx),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.xyzw.anti_wedge(rhs.mx),
+			zx: self.xyzw.anti_wedge(rhs.my),
+			xy: self.xyzw.anti_wedge(rhs.mz),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Moment3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Moment3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Moment3) -> Multivector
+impl LeftContraction<Moment3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.mz.0) - S(self.yz.0 * rhs.mx.0) - S(self.zx.0 * rhs.my.0),
+		//     x   : -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y   : Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z   : -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.s.0 * rhs.mx.0),
+		//     zx  : ZX(self.s.0 * rhs.my.0),
+		//     xy  : XY(self.s.0 * rhs.mz.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.left_contraction(rhs.mz) + self.yz.left_contraction(rhs.mx) + self.zx.left_contraction(rhs.my),
+			x: self.y.left_contraction(rhs.mz) + self.z.left_contraction(rhs.my),
+			y: self.x.left_contraction(rhs.mz) + self.z.left_contraction(rhs.mx),
+			z: self.x.left_contraction(rhs.my) + self.y.left_contraction(rhs.mx),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.s.left_contraction(rhs.mx),
+			zx: self.s.left_contraction(rhs.my),
+			xy: self.s.left_contraction(rhs.mz),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Moment3) -> Multivector
+impl RightContraction<Moment3> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.mz.0) - S(self.yz.0 * rhs.mx.0) - S(self.zx.0 * rhs.my.0),
+		//     x   : -X(self.xyz.0 * rhs.mx.0),
+		//     y   : -Y(self.xyz.0 * rhs.my.0),
+		//     z   : -Z(self.xyz.0 * rhs.mz.0),
+		//     w   : -W(self.xyw.0 * rhs.mz.0) - W(self.yzw.0 * rhs.mx.0) - W(self.zxw.0 * rhs.my.0),
+		//     wx  : WX(self.xyzw.0 * rhs.mx.0),
+		//     wy  : WY(self.xyzw.0 * rhs.my.0),
+		//     wz  : WZ(self.xyzw.0 * rhs.mz.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.right_contraction(rhs.mz)
+				+ self.yz.right_contraction(rhs.mx)
+				+ self.zx.right_contraction(rhs.my),
+			x: self.xyz.right_contraction(rhs.mx),
+			y: self.xyz.right_contraction(rhs.my),
+			z: self.xyz.right_contraction(rhs.mz),
+			w: self.xyw.right_contraction(rhs.mz)
+				+ self.yzw.right_contraction(rhs.mx)
+				+ self.zxw.right_contraction(rhs.my),
+			wx: self.xyzw.right_contraction(rhs.mx),
+			wy: self.xyzw.right_contraction(rhs.my),
+			wz: self.xyzw.right_contraction(rhs.mz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Moment3) -> S
+impl ScalarProduct<Moment3> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Moment3) -> Self::Output {
+		// -S(self.xy.0 * rhs.mz.0) - S(self.yz.0 * rhs.mx.0) - S(self.zx.0 * rhs.my.0)
+		self.xy.scalar_product(rhs.mz) + self.yz.scalar_product(rhs.mx) + self.zx.scalar_product(rhs.my)
+	}
+}
+
+// Multivector.commutator(Moment3) -> Multivector
+impl Commutator<Moment3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y   : Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z   : -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		//     w   : Default::default(),
+		//     wx  : -WX(self.wy.0 * rhs.mz.0) + WX(self.wz.0 * rhs.my.0),
+		//     wy  : WY(self.wx.0 * rhs.mz.0) - WY(self.wz.0 * rhs.mx.0),
+		//     wz  : -WZ(self.wx.0 * rhs.my.0) + WZ(self.wy.0 * rhs.mx.0),
+		//     yz  : YZ(self.xy.0 * rhs.my.0) - YZ(self.zx.0 * rhs.mz.0),
+		//     zx  : -ZX(self.xy.0 * rhs.mx.0) + ZX(self.yz.0 * rhs.mz.0),
+		//     xy  : -XY(self.yz.0 * rhs.my.0) + XY(self.zx.0 * rhs.mx.0),
+		//     yzw : YZW(self.xyw.0 * rhs.my.0) - YZW(self.zxw.0 * rhs.mz.0),
+		//     zxw : -ZXW(self.xyw.0 * rhs.mx.0) + ZXW(self.yzw.0 * rhs.mz.0),
+		//     xyw : -XYW(self.yzw.0 * rhs.my.0) + XYW(self.zxw.0 * rhs.mx.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.mz) + self.z.commutator(rhs.my),
+			y: self.x.commutator(rhs.mz) + self.z.commutator(rhs.mx),
+			z: self.x.commutator(rhs.my) + self.y.commutator(rhs.mx),
+			w: Default::default(),
+			wx: self.wy.commutator(rhs.mz) + self.wz.commutator(rhs.my),
+			wy: self.wx.commutator(rhs.mz) + self.wz.commutator(rhs.mx),
+			wz: self.wx.commutator(rhs.my) + self.wy.commutator(rhs.mx),
+			yz: self.xy.commutator(rhs.my) + self.zx.commutator(rhs.mz),
+			zx: self.xy.commutator(rhs.mx) + self.yz.commutator(rhs.mz),
+			xy: self.yz.commutator(rhs.my) + self.zx.commutator(rhs.mx),
+			yzw: self.xyw.commutator(rhs.my) + self.zxw.commutator(rhs.mz),
+			zxw: self.xyw.commutator(rhs.mx) + self.yzw.commutator(rhs.mz),
+			xyw: self.yzw.commutator(rhs.my) + self.zxw.commutator(rhs.mx),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Moment3) -> Multivector
+impl AntiDot<Moment3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.xyzw.0 * rhs.mx.0),
+		//     zx  : ZX(self.xyzw.0 * rhs.my.0),
+		//     xy  : XY(self.xyzw.0 * rhs.mz.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : -XYZ(self.xyw.0 * rhs.mz.0) - XYZ(self.yzw.0 * rhs.mx.0) - XYZ(self.zxw.0 * rhs.my.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.xyzw.anti_dot(rhs.mx),
+			zx: self.xyzw.anti_dot(rhs.my),
+			xy: self.xyzw.anti_dot(rhs.mz),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.xyw.anti_dot(rhs.mz) + self.yzw.anti_dot(rhs.mx) + self.zxw.anti_dot(rhs.my),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Line3:
+
+// Multivector.geometric(Line3) -> Multivector
+impl Geometric<Line3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.mz.0) - S(self.yz.0 * rhs.mx.0) - S(self.zx.0 * rhs.my.0),
+		//     x   : -X(self.xyz.0 * rhs.mx.0) - X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y   : Y(self.x.0 * rhs.mz.0) - Y(self.xyz.0 * rhs.my.0) - Y(self.z.0 * rhs.mx.0),
+		//     z   : -Z(self.x.0 * rhs.my.0) - Z(self.xyz.0 * rhs.mz.0) + Z(self.y.0 * rhs.mx.0),
+		//     w   : -W(self.x.0 * rhs.vx.0) - W(self.xyw.0 * rhs.mz.0) - W(self.y.0 * rhs.vy.0) - W(self.yzw.0 * rhs.mx.0) - W(self.z.0 * rhs.vz.0) - W(self.zxw.0 * rhs.my.0),
+		//     wx  : WX(self.s.0 * rhs.vx.0) - WX(self.wy.0 * rhs.mz.0) + WX(self.wz.0 * rhs.my.0) + WX(self.xy.0 * rhs.vy.0) + WX(self.xyzw.0 * rhs.mx.0) - WX(self.zx.0 * rhs.vz.0),
+		//     wy  : WY(self.s.0 * rhs.vy.0) + WY(self.wx.0 * rhs.mz.0) - WY(self.wz.0 * rhs.mx.0) - WY(self.xy.0 * rhs.vx.0) + WY(self.xyzw.0 * rhs.my.0) + WY(self.yz.0 * rhs.vz.0),
+		//     wz  : WZ(self.s.0 * rhs.vz.0) - WZ(self.wx.0 * rhs.my.0) + WZ(self.wy.0 * rhs.mx.0) + WZ(self.xyzw.0 * rhs.mz.0) - WZ(self.yz.0 * rhs.vy.0) + WZ(self.zx.0 * rhs.vx.0),
+		//     yz  : YZ(self.s.0 * rhs.mx.0) + YZ(self.xy.0 * rhs.my.0) - YZ(self.zx.0 * rhs.mz.0),
+		//     zx  : ZX(self.s.0 * rhs.my.0) - ZX(self.xy.0 * rhs.mx.0) + ZX(self.yz.0 * rhs.mz.0),
+		//     xy  : XY(self.s.0 * rhs.mz.0) - XY(self.yz.0 * rhs.my.0) + XY(self.zx.0 * rhs.mx.0),
+		//     yzw : YZW(self.w.0 * rhs.mx.0) + YZW(self.xyw.0 * rhs.my.0) - YZW(self.xyz.0 * rhs.vx.0) - YZW(self.y.0 * rhs.vz.0) + YZW(self.z.0 * rhs.vy.0) - YZW(self.zxw.0 * rhs.mz.0),
+		//     zxw : ZXW(self.w.0 * rhs.my.0) + ZXW(self.x.0 * rhs.vz.0) - ZXW(self.xyw.0 * rhs.mx.0) - ZXW(self.xyz.0 * rhs.vy.0) + ZXW(self.yzw.0 * rhs.mz.0) - ZXW(self.z.0 * rhs.vx.0),
+		//     xyw : XYW(self.w.0 * rhs.mz.0) - XYW(self.x.0 * rhs.vy.0) - XYW(self.xyz.0 * rhs.vz.0) + XYW(self.y.0 * rhs.vx.0) - XYW(self.yzw.0 * rhs.my.0) + XYW(self.zxw.0 * rhs.mx.0),
+		//     xyz : XYZ(self.x.0 * rhs.mx.0) + XYZ(self.y.0 * rhs.my.0) + XYZ(self.z.0 * rhs.mz.0),
+		//     xyzw: -XYZW(self.wx.0 * rhs.mx.0) - XYZW(self.wy.0 * rhs.my.0) - XYZW(self.wz.0 * rhs.mz.0) - XYZW(self.xy.0 * rhs.vz.0) - XYZW(self.yz.0 * rhs.vx.0) - XYZW(self.zx.0 * rhs.vy.0),
+		// }
+		Multivector {
+			s: self.xy.geometric(rhs.mz) + self.yz.geometric(rhs.mx) + self.zx.geometric(rhs.my),
+			x: self.xyz.geometric(rhs.mx) + self.y.geometric(rhs.mz) + self.z.geometric(rhs.my),
+			y: self.x.geometric(rhs.mz) + self.xyz.geometric(rhs.my) + self.z.geometric(rhs.mx),
+			z: self.x.geometric(rhs.my) + self.xyz.geometric(rhs.mz) + self.y.geometric(rhs.mx),
+			w: self.x.geometric(rhs.vx)
+				+ self.xyw.geometric(rhs.mz)
+				+ self.y.geometric(rhs.vy)
+				+ self.yzw.geometric(rhs.mx)
+				+ self.z.geometric(rhs.vz)
+				+ self.zxw.geometric(rhs.my),
+			wx: self.s.geometric(rhs.vx)
+				+ self.wy.geometric(rhs.mz)
+				+ self.wz.geometric(rhs.my)
+				+ self.xy.geometric(rhs.vy)
+				+ self.xyzw.geometric(rhs.mx)
+				+ self.zx.geometric(rhs.vz),
+			wy: self.s.geometric(rhs.vy)
+				+ self.wx.geometric(rhs.mz)
+				+ self.wz.geometric(rhs.mx)
+				+ self.xy.geometric(rhs.vx)
+				+ self.xyzw.geometric(rhs.my)
+				+ self.yz.geometric(rhs.vz),
+			wz: self.s.geometric(rhs.vz)
+				+ self.wx.geometric(rhs.my)
+				+ self.wy.geometric(rhs.mx)
+				+ self.xyzw.geometric(rhs.mz)
+				+ self.yz.geometric(rhs.vy)
+				+ self.zx.geometric(rhs.vx),
+			yz: self.s.geometric(rhs.mx) + self.xy.geometric(rhs.my) + self.zx.geometric(rhs.mz),
+			zx: self.s.geometric(rhs.my) + self.xy.geometric(rhs.mx) + self.yz.geometric(rhs.mz),
+			xy: self.s.geometric(rhs.mz) + self.yz.geometric(rhs.my) + self.zx.geometric(rhs.mx),
+			yzw: self.w.geometric(rhs.mx)
+				+ self.xyw.geometric(rhs.my)
+				+ self.xyz.geometric(rhs.vx)
+				+ self.y.geometric(rhs.vz)
+				+ self.z.geometric(rhs.vy)
+				+ self.zxw.geometric(rhs.mz),
+			zxw: self.w.geometric(rhs.my)
+				+ self.x.geometric(rhs.vz)
+				+ self.xyw.geometric(rhs.mx)
+				+ self.xyz.geometric(rhs.vy)
+				+ self.yzw.geometric(rhs.mz)
+				+ self.z.geometric(rhs.vx),
+			xyw: self.w.geometric(rhs.mz)
+				+ self.x.geometric(rhs.vy)
+				+ self.xyz.geometric(rhs.vz)
+				+ self.y.geometric(rhs.vx)
+				+ self.yzw.geometric(rhs.my)
+				+ self.zxw.geometric(rhs.mx),
+			xyz: self.x.geometric(rhs.mx) + self.y.geometric(rhs.my) + self.z.geometric(rhs.mz),
+			xyzw: self.wx.geometric(rhs.mx)
+				+ self.wy.geometric(rhs.my)
+				+ self.wz.geometric(rhs.mz)
+				+ self.xy.geometric(rhs.vz)
+				+ self.yz.geometric(rhs.vx)
+				+ self.zx.geometric(rhs.vy),
+		}
+	}
+}
+
+impl std::ops::Mul<Line3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Line3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Line3) -> Multivector
+impl AntiGeometric<Line3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.wx.0 * rhs.mx.0) - S(self.wy.0 * rhs.my.0) - S(self.wz.0 * rhs.mz.0) - S(self.xy.0 * rhs.vz.0) - S(self.yz.0 * rhs.vx.0) - S(self.zx.0 * rhs.vy.0),
+		//     x   : -X(self.w.0 * rhs.mx.0) + X(self.xyw.0 * rhs.my.0) - X(self.xyz.0 * rhs.vx.0) + X(self.y.0 * rhs.vz.0) - X(self.z.0 * rhs.vy.0) - X(self.zxw.0 * rhs.mz.0),
+		//     y   : -Y(self.w.0 * rhs.my.0) - Y(self.x.0 * rhs.vz.0) - Y(self.xyw.0 * rhs.mx.0) - Y(self.xyz.0 * rhs.vy.0) + Y(self.yzw.0 * rhs.mz.0) + Y(self.z.0 * rhs.vx.0),
+		//     z   : -Z(self.w.0 * rhs.mz.0) + Z(self.x.0 * rhs.vy.0) - Z(self.xyz.0 * rhs.vz.0) - Z(self.y.0 * rhs.vx.0) - Z(self.yzw.0 * rhs.my.0) + Z(self.zxw.0 * rhs.mx.0),
+		//     w   : -W(self.xyw.0 * rhs.vz.0) - W(self.yzw.0 * rhs.vx.0) - W(self.zxw.0 * rhs.vy.0),
+		//     wx  : WX(self.wy.0 * rhs.vz.0) - WX(self.wz.0 * rhs.vy.0) + WX(self.xyzw.0 * rhs.vx.0),
+		//     wy  : -WY(self.wx.0 * rhs.vz.0) + WY(self.wz.0 * rhs.vx.0) + WY(self.xyzw.0 * rhs.vy.0),
+		//     wz  : WZ(self.wx.0 * rhs.vy.0) - WZ(self.wy.0 * rhs.vx.0) + WZ(self.xyzw.0 * rhs.vz.0),
+		//     yz  : YZ(self.s.0 * rhs.vx.0) + YZ(self.wy.0 * rhs.mz.0) - YZ(self.wz.0 * rhs.my.0) - YZ(self.xy.0 * rhs.vy.0) + YZ(self.xyzw.0 * rhs.mx.0) + YZ(self.zx.0 * rhs.vz.0),
+		//     zx  : ZX(self.s.0 * rhs.vy.0) - ZX(self.wx.0 * rhs.mz.0) + ZX(self.wz.0 * rhs.mx.0) + ZX(self.xy.0 * rhs.vx.0) + ZX(self.xyzw.0 * rhs.my.0) - ZX(self.yz.0 * rhs.vz.0),
+		//     xy  : XY(self.s.0 * rhs.vz.0) + XY(self.wx.0 * rhs.my.0) - XY(self.wy.0 * rhs.mx.0) + XY(self.xyzw.0 * rhs.mz.0) + XY(self.yz.0 * rhs.vy.0) - XY(self.zx.0 * rhs.vx.0),
+		//     yzw : YZW(self.w.0 * rhs.vx.0) - YZW(self.xyw.0 * rhs.vy.0) + YZW(self.zxw.0 * rhs.vz.0),
+		//     zxw : ZXW(self.w.0 * rhs.vy.0) + ZXW(self.xyw.0 * rhs.vx.0) - ZXW(self.yzw.0 * rhs.vz.0),
+		//     xyw : XYW(self.w.0 * rhs.vz.0) + XYW(self.yzw.0 * rhs.vy.0) - XYW(self.zxw.0 * rhs.vx.0),
+		//     xyz : XYZ(self.x.0 * rhs.vx.0) - XYZ(self.xyw.0 * rhs.mz.0) + XYZ(self.y.0 * rhs.vy.0) - XYZ(self.yzw.0 * rhs.mx.0) + XYZ(self.z.0 * rhs.vz.0) - XYZ(self.zxw.0 * rhs.my.0),
+		//     xyzw: -XYZW(self.wx.0 * rhs.vx.0) - XYZW(self.wy.0 * rhs.vy.0) - XYZW(self.wz.0 * rhs.vz.0),
+		// }
+		Multivector {
+			s: self.wx.anti_geometric(rhs.mx)
+				+ self.wy.anti_geometric(rhs.my)
+				+ self.wz.anti_geometric(rhs.mz)
+				+ self.xy.anti_geometric(rhs.vz)
+				+ self.yz.anti_geometric(rhs.vx)
+				+ self.zx.anti_geometric(rhs.vy),
+			x: self.w.anti_geometric(rhs.mx)
+				+ self.xyw.anti_geometric(rhs.my)
+				+ self.xyz.anti_geometric(rhs.vx)
+				+ self.y.anti_geometric(rhs.vz)
+				+ self.z.anti_geometric(rhs.vy)
+				+ self.zxw.anti_geometric(rhs.mz),
+			y: self.w.anti_geometric(rhs.my)
+				+ self.x.anti_geometric(rhs.vz)
+				+ self.xyw.anti_geometric(rhs.mx)
+				+ self.xyz.anti_geometric(rhs.vy)
+				+ self.yzw.anti_geometric(rhs.mz)
+				+ self.z.anti_geometric(rhs.vx),
+			z: self.w.anti_geometric(rhs.mz)
+				+ self.x.anti_geometric(rhs.vy)
+				+ self.xyz.anti_geometric(rhs.vz)
+				+ self.y.anti_geometric(rhs.vx)
+				+ self.yzw.anti_geometric(rhs.my)
+				+ self.zxw.anti_geometric(rhs.mx),
+			w: self.xyw.anti_geometric(rhs.vz) + self.yzw.anti_geometric(rhs.vx) + self.zxw.anti_geometric(rhs.vy),
+			wx: self.wy.anti_geometric(rhs.vz) + self.wz.anti_geometric(rhs.vy) + self.xyzw.anti_geometric(rhs.vx),
+			wy: self.wx.anti_geometric(rhs.vz) + self.wz.anti_geometric(rhs.vx) + self.xyzw.anti_geometric(rhs.vy),
+			wz: self.wx.anti_geometric(rhs.vy) + self.wy.anti_geometric(rhs.vx) + self.xyzw.anti_geometric(rhs.vz),
+			yz: self.s.anti_geometric(rhs.vx)
+				+ self.wy.anti_geometric(rhs.mz)
+				+ self.wz.anti_geometric(rhs.my)
+				+ self.xy.anti_geometric(rhs.vy)
+				+ self.xyzw.anti_geometric(rhs.mx)
+				+ self.zx.anti_geometric(rhs.vz),
+			zx: self.s.anti_geometric(rhs.vy)
+				+ self.wx.anti_geometric(rhs.mz)
+				+ self.wz.anti_geometric(rhs.mx)
+				+ self.xy.anti_geometric(rhs.vx)
+				+ self.xyzw.anti_geometric(rhs.my)
+				+ self.yz.anti_geometric(rhs.vz),
+			xy: self.s.anti_geometric(rhs.vz)
+				+ self.wx.anti_geometric(rhs.my)
+				+ self.wy.anti_geometric(rhs.mx)
+				+ self.xyzw.anti_geometric(rhs.mz)
+				+ self.yz.anti_geometric(rhs.vy)
+				+ self.zx.anti_geometric(rhs.vx),
+			yzw: self.w.anti_geometric(rhs.vx) + self.xyw.anti_geometric(rhs.vy) + self.zxw.anti_geometric(rhs.vz),
+			zxw: self.w.anti_geometric(rhs.vy) + self.xyw.anti_geometric(rhs.vx) + self.yzw.anti_geometric(rhs.vz),
+			xyw: self.w.anti_geometric(rhs.vz) + self.yzw.anti_geometric(rhs.vy) + self.zxw.anti_geometric(rhs.vx),
+			xyz: self.x.anti_geometric(rhs.vx)
+				+ self.xyw.anti_geometric(rhs.mz)
+				+ self.y.anti_geometric(rhs.vy)
+				+ self.yzw.anti_geometric(rhs.mx)
+				+ self.z.anti_geometric(rhs.vz)
+				+ self.zxw.anti_geometric(rhs.my),
+			xyzw: self.wx.anti_geometric(rhs.vx) + self.wy.anti_geometric(rhs.vy) + self.wz.anti_geometric(rhs.vz),
+		}
+	}
+}
+
+// Multivector.dot(Line3) -> Multivector
+impl Dot<Line3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.mz.0) - S(self.yz.0 * rhs.mx.0) - S(self.zx.0 * rhs.my.0),
+		//     x   : -X(self.xyz.0 * rhs.mx.0) - X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y   : Y(self.x.0 * rhs.mz.0) - Y(self.xyz.0 * rhs.my.0) - Y(self.z.0 * rhs.mx.0),
+		//     z   : -Z(self.x.0 * rhs.my.0) - Z(self.xyz.0 * rhs.mz.0) + Z(self.y.0 * rhs.mx.0),
+		//     w   : -W(self.x.0 * rhs.vx.0) - W(self.xyw.0 * rhs.mz.0) - W(self.y.0 * rhs.vy.0) - W(self.yzw.0 * rhs.mx.0) - W(self.z.0 * rhs.vz.0) - W(self.zxw.0 * rhs.my.0),
+		//     wx  : WX(self.s.0 * rhs.vx.0) + WX(self.xyzw.0 * rhs.mx.0),
+		//     wy  : WY(self.s.0 * rhs.vy.0) + WY(self.xyzw.0 * rhs.my.0),
+		//     wz  : WZ(self.s.0 * rhs.vz.0) + WZ(self.xyzw.0 * rhs.mz.0),
+		//     yz  : YZ(self.s.0 * rhs.mx.0),
+		//     zx  : ZX(self.s.0 * rhs.my.0),
+		//     xy  : XY(self.s.0 * rhs.mz.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.dot(rhs.mz) + self.yz.dot(rhs.mx) + self.zx.dot(rhs.my),
+			x: self.xyz.dot(rhs.mx) + self.y.dot(rhs.mz) + self.z.dot(rhs.my),
+			y: self.x.dot(rhs.mz) + self.xyz.dot(rhs.my) + self.z.dot(rhs.mx),
+			z: self.x.dot(rhs.my) + self.xyz.dot(rhs.mz) + self.y.dot(rhs.mx),
+			w: self.x.dot(rhs.vx)
+				+ self.xyw.dot(rhs.mz)
+				+ self.y.dot(rhs.vy)
+				+ self.yzw.dot(rhs.mx)
+				+ self.z.dot(rhs.vz)
+				+ self.zxw.dot(rhs.my),
+			wx: self.s.dot(rhs.vx) + self.xyzw.dot(rhs.mx),
+			wy: self.s.dot(rhs.vy) + self.xyzw.dot(rhs.my),
+			wz: self.s.dot(rhs.vz) + self.xyzw.dot(rhs.mz),
+			yz: self.s.dot(rhs.mx),
+			zx: self.s.dot(rhs.my),
+			xy: self.s.dot(rhs.mz),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Line3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Line3) -> Multivector
+impl Wedge<Line3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : WX(self.s.0 * rhs.vx.0),
+		//     wy  : WY(self.s.0 * rhs.vy.0),
+		//     wz  : WZ(self.s.0 * rhs.vz.0),
+		//     yz  : YZ(self.s.0 * rhs.mx.0),
+		//     zx  : ZX(self.s.0 * rhs.my.0),
+		//     xy  : XY(self.s.0 * rhs.mz.0),
+		//     yzw : YZW(self.w.0 * rhs.mx.0) - YZW(self.y.0 * rhs.vz.0) + YZW(self.z.0 * rhs.vy.0),
+		//     zxw : ZXW(self.w.0 * rhs.my.0) + ZXW(self.x.0 * rhs.vz.0) - ZXW(self.z.0 * rhs.vx.0),
+		//     xyw : XYW(self.w.0 * rhs.mz.0) - XYW(self.x.0 * rhs.vy.0) + XYW(self.y.0 * rhs.vx.0),
+		//     xyz : XYZ(self.x.0 * rhs.mx.0) + XYZ(self.y.0 * rhs.my.0) + XYZ(self.z.0 * rhs.mz.0),
+		//     xyzw: -XYZW(self.wx.0 * rhs.mx.0) - XYZW(self.wy.0 * rhs.my.0) - XYZW(self.wz.0 * rhs.mz.0) - XYZW(self.xy.0 * rhs.vz.0) - XYZW(self.yz.0 * rhs.vx.0) - XYZW(self.zx.0 * rhs.vy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: self.s.wedge(rhs.vx),
+			wy: self.s.wedge(rhs.vy),
+			wz: self.s.wedge(rhs.vz),
+			yz: self.s.wedge(rhs.mx),
+			zx: self.s.wedge(rhs.my),
+			xy: self.s.wedge(rhs.mz),
+			yzw: self.w.wedge(rhs.mx) + self.y.wedge(rhs.vz) + self.z.wedge(rhs.vy),
+			zxw: self.w.wedge(rhs.my) + self.x.wedge(rhs.vz) + self.z.wedge(rhs.vx),
+			xyw: self.w.wedge(rhs.mz) + self.x.wedge(rhs.vy) + self.y.wedge(rhs.vx),
+			xyz: self.x.wedge(rhs.mx) + self.y.wedge(rhs.my) + self.z.wedge(rhs.mz),
+			xyzw: self.wx.wedge(rhs.mx)
+				+ self.wy.wedge(rhs.my)
+				+ self.wz.wedge(rhs.mz)
+				+ self.xy.wedge(rhs.vz)
+				+ self.yz.wedge(rhs.vx)
+				+ self.zx.wedge(rhs.vy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Line3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Line3) -> Multivector
+impl AntiWedge<Line3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.wx.0 * rhs.mx.0) - S(self.wy.0 * rhs.my.0) - S(self.wz.0 * rhs.mz.0) - S(self.xy.0 * rhs.vz.0) - S(self.yz.0 * rhs.vx.0) - S(self.zx.0 * rhs.vy.0),
+		//     x   : X(self.xyw.0 * rhs.my.0) - X(self.xyz.0 * rhs.vx.0) - X(self.zxw.0 * rhs.mz.0),
+		//     y   : -Y(self.xyw.0 * rhs.mx.0) - Y(self.xyz.0 * rhs.vy.0) + Y(self.yzw.0 * rhs.mz.0),
+		//     z   : -Z(self.xyz.0 * rhs.vz.0) - Z(self.yzw.0 * rhs.my.0) + Z(self.zxw.0 * rhs.mx.0),
+		//     w   : -W(self.xyw.0 * rhs.vz.0) - W(self.yzw.0 * rhs.vx.0) - W(self.zxw.0 * rhs.vy.0),
+		//     wx  : WX(self.xyzw.0 * rhs.vx.0),
+		//     wy  : WY(self.xyzw.0 * rhs.vy.0),
+		//     wz  : WZ(self.xyzw.0 * rhs.vz.0),
+		//     yz  : YZ(self.xyzw.0 * rhs.mx.0),
+		//     zx  : ZX(self.xyzw.0 * rhs.my.0),
+		//     xy  : XY(self.xyzw.0 * rhs.mz.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.wx.anti_wedge(rhs.mx)
+				+ self.wy.anti_wedge(rhs.my)
+				+ self.wz.anti_wedge(rhs.mz)
+				+ self.xy.anti_wedge(rhs.vz)
+				+ self.yz.anti_wedge(rhs.vx)
+				+ self.zx.anti_wedge(rhs.vy),
+			x: self.xyw.anti_wedge(rhs.my) + self.xyz.anti_wedge(rhs.vx) + self.zxw.anti_wedge(rhs.mz),
+			y: self.xyw.anti_wedge(rhs.mx) + self.xyz.anti_wedge(rhs.vy) + self.yzw.anti_wedge(rhs.mz),
+			z: self.xyz.anti_wedge(rhs.vz) + self.yzw.anti_wedge(rhs.my) + self.zxw.anti_wedge(rhs.mx),
+			w: self.xyw.anti_wedge(rhs.vz) + self.yzw.anti_wedge(rhs.vx) + self.zxw.anti_wedge(rhs.vy),
+			wx: self.xyzw.anti_wedge(rhs.vx),
+			wy: self.xyzw.anti_wedge(rhs.vy),
+			wz: self.xyzw.anti_wedge(rhs.vz),
+			yz: self.xyzw.anti_wedge(rhs.mx),
+			zx: self.xyzw.anti_wedge(rhs.my),
+			xy: self.xyzw.anti_wedge(rhs.mz),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Line3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Line3) -> Multivector
+impl LeftContraction<Line3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.mz.0) - S(self.yz.0 * rhs.mx.0) - S(self.zx.0 * rhs.my.0),
+		//     x   : -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y   : Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z   : -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		//     w   : -W(self.x.0 * rhs.vx.0) - W(self.y.0 * rhs.vy.0) - W(self.z.0 * rhs.vz.0),
+		//     wx  : WX(self.s.0 * rhs.vx.0),
+		//     wy  : WY(self.s.0 * rhs.vy.0),
+		//     wz  : WZ(self.s.0 * rhs.vz.0),
+		//     yz  : YZ(self.s.0 * rhs.mx.0),
+		//     zx  : ZX(self.s.0 * rhs.my.0),
+		//     xy  : XY(self.s.0 * rhs.mz.0),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.left_contraction(rhs.mz) + self.yz.left_contraction(rhs.mx) + self.zx.left_contraction(rhs.my),
+			x: self.y.left_contraction(rhs.mz) + self.z.left_contraction(rhs.my),
+			y: self.x.left_contraction(rhs.mz) + self.z.left_contraction(rhs.mx),
+			z: self.x.left_contraction(rhs.my) + self.y.left_contraction(rhs.mx),
+			w: self.x.left_contraction(rhs.vx) + self.y.left_contraction(rhs.vy) + self.z.left_contraction(rhs.vz),
+			wx: self.s.left_contraction(rhs.vx),
+			wy: self.s.left_contraction(rhs.vy),
+			wz: self.s.left_contraction(rhs.vz),
+			yz: self.s.left_contraction(rhs.mx),
+			zx: self.s.left_contraction(rhs.my),
+			xy: self.s.left_contraction(rhs.mz),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Line3) -> Multivector
+impl RightContraction<Line3> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.mz.0) - S(self.yz.0 * rhs.mx.0) - S(self.zx.0 * rhs.my.0),
+		//     x   : -X(self.xyz.0 * rhs.mx.0),
+		//     y   : -Y(self.xyz.0 * rhs.my.0),
+		//     z   : -Z(self.xyz.0 * rhs.mz.0),
+		//     w   : -W(self.xyw.0 * rhs.mz.0) - W(self.yzw.0 * rhs.mx.0) - W(self.zxw.0 * rhs.my.0),
+		//     wx  : WX(self.xyzw.0 * rhs.mx.0),
+		//     wy  : WY(self.xyzw.0 * rhs.my.0),
+		//     wz  : WZ(self.xyzw.0 * rhs.mz.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.right_contraction(rhs.mz)
+				+ self.yz.right_contraction(rhs.mx)
+				+ self.zx.right_contraction(rhs.my),
+			x: self.xyz.right_contraction(rhs.mx),
+			y: self.xyz.right_contraction(rhs.my),
+			z: self.xyz.right_contraction(rhs.mz),
+			w: self.xyw.right_contraction(rhs.mz)
+				+ self.yzw.right_contraction(rhs.mx)
+				+ self.zxw.right_contraction(rhs.my),
+			wx: self.xyzw.right_contraction(rhs.mx),
+			wy: self.xyzw.right_contraction(rhs.my),
+			wz: self.xyzw.right_contraction(rhs.mz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Line3) -> S
+impl ScalarProduct<Line3> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Line3) -> Self::Output {
+		// -S(self.xy.0 * rhs.mz.0) - S(self.yz.0 * rhs.mx.0) - S(self.zx.0 * rhs.my.0)
+		self.xy.scalar_product(rhs.mz) + self.yz.scalar_product(rhs.mx) + self.zx.scalar_product(rhs.my)
+	}
+}
+
+// Multivector.commutator(Line3) -> Multivector
+impl Commutator<Line3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y   : Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z   : -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		//     w   : -W(self.x.0 * rhs.vx.0) - W(self.y.0 * rhs.vy.0) - W(self.z.0 * rhs.vz.0),
+		//     wx  : -WX(self.wy.0 * rhs.mz.0) + WX(self.wz.0 * rhs.my.0) + WX(self.xy.0 * rhs.vy.0) - WX(self.zx.0 * rhs.vz.0),
+		//     wy  : WY(self.wx.0 * rhs.mz.0) - WY(self.wz.0 * rhs.mx.0) - WY(self.xy.0 * rhs.vx.0) + WY(self.yz.0 * rhs.vz.0),
+		//     wz  : -WZ(self.wx.0 * rhs.my.0) + WZ(self.wy.0 * rhs.mx.0) - WZ(self.yz.0 * rhs.vy.0) + WZ(self.zx.0 * rhs.vx.0),
+		//     yz  : YZ(self.xy.0 * rhs.my.0) - YZ(self.zx.0 * rhs.mz.0),
+		//     zx  : -ZX(self.xy.0 * rhs.mx.0) + ZX(self.yz.0 * rhs.mz.0),
+		//     xy  : -XY(self.yz.0 * rhs.my.0) + XY(self.zx.0 * rhs.mx.0),
+		//     yzw : YZW(self.xyw.0 * rhs.my.0) - YZW(self.xyz.0 * rhs.vx.0) - YZW(self.zxw.0 * rhs.mz.0),
+		//     zxw : -ZXW(self.xyw.0 * rhs.mx.0) - ZXW(self.xyz.0 * rhs.vy.0) + ZXW(self.yzw.0 * rhs.mz.0),
+		//     xyw : -XYW(self.xyz.0 * rhs.vz.0) - XYW(self.yzw.0 * rhs.my.0) + XYW(self.zxw.0 * rhs.mx.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.mz) + self.z.commutator(rhs.my),
+			y: self.x.commutator(rhs.mz) + self.z.commutator(rhs.mx),
+			z: self.x.commutator(rhs.my) + self.y.commutator(rhs.mx),
+			w: self.x.commutator(rhs.vx) + self.y.commutator(rhs.vy) + self.z.commutator(rhs.vz),
+			wx: self.wy.commutator(rhs.mz)
+				+ self.wz.commutator(rhs.my)
+				+ self.xy.commutator(rhs.vy)
+				+ self.zx.commutator(rhs.vz),
+			wy: self.wx.commutator(rhs.mz)
+				+ self.wz.commutator(rhs.mx)
+				+ self.xy.commutator(rhs.vx)
+				+ self.yz.commutator(rhs.vz),
+			wz: self.wx.commutator(rhs.my)
+				+ self.wy.commutator(rhs.mx)
+				+ self.yz.commutator(rhs.vy)
+				+ self.zx.commutator(rhs.vx),
+			yz: self.xy.commutator(rhs.my) + self.zx.commutator(rhs.mz),
+			zx: self.xy.commutator(rhs.mx) + self.yz.commutator(rhs.mz),
+			xy: self.yz.commutator(rhs.my) + self.zx.commutator(rhs.mx),
+			yzw: self.xyw.commutator(rhs.my) + self.xyz.commutator(rhs.vx) + self.zxw.commutator(rhs.mz),
+			zxw: self.xyw.commutator(rhs.mx) + self.xyz.commutator(rhs.vy) + self.yzw.commutator(rhs.mz),
+			xyw: self.xyz.commutator(rhs.vz) + self.yzw.commutator(rhs.my) + self.zxw.commutator(rhs.mx),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Line3) -> Multivector
+impl AntiDot<Line3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : WX(self.xyzw.0 * rhs.vx.0),
+		//     wy  : WY(self.xyzw.0 * rhs.vy.0),
+		//     wz  : WZ(self.xyzw.0 * rhs.vz.0),
+		//     yz  : YZ(self.s.0 * rhs.vx.0) + YZ(self.xyzw.0 * rhs.mx.0),
+		//     zx  : ZX(self.s.0 * rhs.vy.0) + ZX(self.xyzw.0 * rhs.my.0),
+		//     xy  : XY(self.s.0 * rhs.vz.0) + XY(self.xyzw.0 * rhs.mz.0),
+		//     yzw : YZW(self.w.0 * rhs.vx.0) - YZW(self.xyw.0 * rhs.vy.0) + YZW(self.zxw.0 * rhs.vz.0),
+		//     zxw : ZXW(self.w.0 * rhs.vy.0) + ZXW(self.xyw.0 * rhs.vx.0) - ZXW(self.yzw.0 * rhs.vz.0),
+		//     xyw : XYW(self.w.0 * rhs.vz.0) + XYW(self.yzw.0 * rhs.vy.0) - XYW(self.zxw.0 * rhs.vx.0),
+		//     xyz : XYZ(self.x.0 * rhs.vx.0) - XYZ(self.xyw.0 * rhs.mz.0) + XYZ(self.y.0 * rhs.vy.0) - XYZ(self.yzw.0 * rhs.mx.0) + XYZ(self.z.0 * rhs.vz.0) - XYZ(self.zxw.0 * rhs.my.0),
+		//     xyzw: -XYZW(self.wx.0 * rhs.vx.0) - XYZW(self.wy.0 * rhs.vy.0) - XYZW(self.wz.0 * rhs.vz.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: self.xyzw.anti_dot(rhs.vx),
+			wy: self.xyzw.anti_dot(rhs.vy),
+			wz: self.xyzw.anti_dot(rhs.vz),
+			yz: self.s.anti_dot(rhs.vx) + self.xyzw.anti_dot(rhs.mx),
+			zx: self.s.anti_dot(rhs.vy) + self.xyzw.anti_dot(rhs.my),
+			xy: self.s.anti_dot(rhs.vz) + self.xyzw.anti_dot(rhs.mz),
+			yzw: self.w.anti_dot(rhs.vx) + self.xyw.anti_dot(rhs.vy) + self.zxw.anti_dot(rhs.vz),
+			zxw: self.w.anti_dot(rhs.vy) + self.xyw.anti_dot(rhs.vx) + self.yzw.anti_dot(rhs.vz),
+			xyw: self.w.anti_dot(rhs.vz) + self.yzw.anti_dot(rhs.vy) + self.zxw.anti_dot(rhs.vx),
+			xyz: self.x.anti_dot(rhs.vx)
+				+ self.xyw.anti_dot(rhs.mz)
+				+ self.y.anti_dot(rhs.vy)
+				+ self.yzw.anti_dot(rhs.mx)
+				+ self.z.anti_dot(rhs.vz)
+				+ self.zxw.anti_dot(rhs.my),
+			xyzw: self.wx.anti_dot(rhs.vx) + self.wy.anti_dot(rhs.vy) + self.wz.anti_dot(rhs.vz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Plane:
+
+// Multivector.geometric(Plane) -> Multivector
+impl Geometric<Plane> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xyz.0 * rhs.d.0),
+		//     x   : -X(self.yz.0 * rhs.d.0),
+		//     y   : -Y(self.zx.0 * rhs.d.0),
+		//     z   : -Z(self.xy.0 * rhs.d.0),
+		//     w   : -W(self.xy.0 * rhs.nz.0) + W(self.xyzw.0 * rhs.d.0) - W(self.yz.0 * rhs.nx.0) - W(self.zx.0 * rhs.ny.0),
+		//     wx  : WX(self.xyz.0 * rhs.nx.0) + WX(self.y.0 * rhs.nz.0) - WX(self.yzw.0 * rhs.d.0) - WX(self.z.0 * rhs.ny.0),
+		//     wy  : -WY(self.x.0 * rhs.nz.0) + WY(self.xyz.0 * rhs.ny.0) + WY(self.z.0 * rhs.nx.0) - WY(self.zxw.0 * rhs.d.0),
+		//     wz  : WZ(self.x.0 * rhs.ny.0) - WZ(self.xyw.0 * rhs.d.0) + WZ(self.xyz.0 * rhs.nz.0) - WZ(self.y.0 * rhs.nx.0),
+		//     yz  : YZ(self.x.0 * rhs.d.0),
+		//     zx  : ZX(self.y.0 * rhs.d.0),
+		//     xy  : XY(self.z.0 * rhs.d.0),
+		//     yzw : YZW(self.s.0 * rhs.nx.0) + YZW(self.wx.0 * rhs.d.0) + YZW(self.xy.0 * rhs.ny.0) - YZW(self.zx.0 * rhs.nz.0),
+		//     zxw : ZXW(self.s.0 * rhs.ny.0) + ZXW(self.wy.0 * rhs.d.0) - ZXW(self.xy.0 * rhs.nx.0) + ZXW(self.yz.0 * rhs.nz.0),
+		//     xyw : XYW(self.s.0 * rhs.nz.0) + XYW(self.wz.0 * rhs.d.0) - XYW(self.yz.0 * rhs.ny.0) + XYW(self.zx.0 * rhs.nx.0),
+		//     xyz : XYZ(self.s.0 * rhs.d.0),
+		//     xyzw: -XYZW(self.w.0 * rhs.d.0) + XYZW(self.x.0 * rhs.nx.0) + XYZW(self.y.0 * rhs.ny.0) + XYZW(self.z.0 * rhs.nz.0),
+		// }
+		Multivector {
+			s: self.xyz.geometric(rhs.d),
+			x: self.yz.geometric(rhs.d),
+			y: self.zx.geometric(rhs.d),
+			z: self.xy.geometric(rhs.d),
+			w: self.xy.geometric(rhs.nz)
+				+ self.xyzw.geometric(rhs.d)
+				+ self.yz.geometric(rhs.nx)
+				+ self.zx.geometric(rhs.ny),
+			wx: self.xyz.geometric(rhs.nx)
+				+ self.y.geometric(rhs.nz)
+				+ self.yzw.geometric(rhs.d)
+				+ self.z.geometric(rhs.ny),
+			wy: self.x.geometric(rhs.nz)
+				+ self.xyz.geometric(rhs.ny)
+				+ self.z.geometric(rhs.nx)
+				+ self.zxw.geometric(rhs.d),
+			wz: self.x.geometric(rhs.ny)
+				+ self.xyw.geometric(rhs.d)
+				+ self.xyz.geometric(rhs.nz)
+				+ self.y.geometric(rhs.nx),
+			yz: self.x.geometric(rhs.d),
+			zx: self.y.geometric(rhs.d),
+			xy: self.z.geometric(rhs.d),
+			yzw: self.s.geometric(rhs.nx)
+				+ self.wx.geometric(rhs.d)
+				+ self.xy.geometric(rhs.ny)
+				+ self.zx.geometric(rhs.nz),
+			zxw: self.s.geometric(rhs.ny)
+				+ self.wy.geometric(rhs.d)
+				+ self.xy.geometric(rhs.nx)
+				+ self.yz.geometric(rhs.nz),
+			xyw: self.s.geometric(rhs.nz)
+				+ self.wz.geometric(rhs.d)
+				+ self.yz.geometric(rhs.ny)
+				+ self.zx.geometric(rhs.nx),
+			xyz: self.s.geometric(rhs.d),
+			xyzw: self.w.geometric(rhs.d)
+				+ self.x.geometric(rhs.nx)
+				+ self.y.geometric(rhs.ny)
+				+ self.z.geometric(rhs.nz),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.w.0 * rhs.d.0) + S(self.x.0 * rhs.nx.0) + S(self.y.0 * rhs.ny.0) + S(self.z.0 * rhs.nz.0),
+		//     x   : X(self.s.0 * rhs.nx.0) - X(self.wx.0 * rhs.d.0) - X(self.xy.0 * rhs.ny.0) + X(self.zx.0 * rhs.nz.0),
+		//     y   : Y(self.s.0 * rhs.ny.0) - Y(self.wy.0 * rhs.d.0) + Y(self.xy.0 * rhs.nx.0) - Y(self.yz.0 * rhs.nz.0),
+		//     z   : Z(self.s.0 * rhs.nz.0) - Z(self.wz.0 * rhs.d.0) + Z(self.yz.0 * rhs.ny.0) - Z(self.zx.0 * rhs.nx.0),
+		//     w   : -W(self.wx.0 * rhs.nx.0) - W(self.wy.0 * rhs.ny.0) - W(self.wz.0 * rhs.nz.0),
+		//     wx  : -WX(self.w.0 * rhs.nx.0) + WX(self.xyw.0 * rhs.ny.0) - WX(self.zxw.0 * rhs.nz.0),
+		//     wy  : -WY(self.w.0 * rhs.ny.0) - WY(self.xyw.0 * rhs.nx.0) + WY(self.yzw.0 * rhs.nz.0),
+		//     wz  : -WZ(self.w.0 * rhs.nz.0) - WZ(self.yzw.0 * rhs.ny.0) + WZ(self.zxw.0 * rhs.nx.0),
+		//     yz  : YZ(self.xyz.0 * rhs.nx.0) - YZ(self.y.0 * rhs.nz.0) - YZ(self.yzw.0 * rhs.d.0) + YZ(self.z.0 * rhs.ny.0),
+		//     zx  : ZX(self.x.0 * rhs.nz.0) + ZX(self.xyz.0 * rhs.ny.0) - ZX(self.z.0 * rhs.nx.0) - ZX(self.zxw.0 * rhs.d.0),
+		//     xy  : -XY(self.x.0 * rhs.ny.0) - XY(self.xyw.0 * rhs.d.0) + XY(self.xyz.0 * rhs.nz.0) + XY(self.y.0 * rhs.nx.0),
+		//     yzw : YZW(self.wy.0 * rhs.nz.0) - YZW(self.wz.0 * rhs.ny.0) + YZW(self.xyzw.0 * rhs.nx.0),
+		//     zxw : -ZXW(self.wx.0 * rhs.nz.0) + ZXW(self.wz.0 * rhs.nx.0) + ZXW(self.xyzw.0 * rhs.ny.0),
+		//     xyw : XYW(self.wx.0 * rhs.ny.0) - XYW(self.wy.0 * rhs.nx.0) + XYW(self.xyzw.0 * rhs.nz.0),
+		//     xyz : XYZ(self.xy.0 * rhs.nz.0) + XYZ(self.xyzw.0 * rhs.d.0) + XYZ(self.yz.0 * rhs.nx.0) + XYZ(self.zx.0 * rhs.ny.0),
+		//     xyzw: XYZW(self.xyw.0 * rhs.nz.0) + XYZW(self.yzw.0 * rhs.nx.0) + XYZW(self.zxw.0 * rhs.ny.0),
+		// }
+		Multivector {
+			s: self.w.anti_geometric(rhs.d)
+				+ self.x.anti_geometric(rhs.nx)
+				+ self.y.anti_geometric(rhs.ny)
+				+ self.z.anti_geometric(rhs.nz),
+			x: self.s.anti_geometric(rhs.nx)
+				+ self.wx.anti_geometric(rhs.d)
+				+ self.xy.anti_geometric(rhs.ny)
+				+ self.zx.anti_geometric(rhs.nz),
+			y: self.s.anti_geometric(rhs.ny)
+				+ self.wy.anti_geometric(rhs.d)
+				+ self.xy.anti_geometric(rhs.nx)
+				+ self.yz.anti_geometric(rhs.nz),
+			z: self.s.anti_geometric(rhs.nz)
+				+ self.wz.anti_geometric(rhs.d)
+				+ self.yz.anti_geometric(rhs.ny)
+				+ self.zx.anti_geometric(rhs.nx),
+			w: self.wx.anti_geometric(rhs.nx) + self.wy.anti_geometric(rhs.ny) + self.wz.anti_geometric(rhs.nz),
+			wx: self.w.anti_geometric(rhs.nx) + self.xyw.anti_geometric(rhs.ny) + self.zxw.anti_geometric(rhs.nz),
+			wy: self.w.anti_geometric(rhs.ny) + self.xyw.anti_geometric(rhs.nx) + self.yzw.anti_geometric(rhs.nz),
+			wz: self.w.anti_geometric(rhs.nz) + self.yzw.anti_geometric(rhs.ny) + self.zxw.anti_geometric(rhs.nx),
+			yz: self.xyz.anti_geometric(rhs.nx)
+				+ self.y.anti_geometric(rhs.nz)
+				+ self.yzw.anti_geometric(rhs.d)
+				+ self.z.anti_geometric(rhs.ny),
+			zx: self.x.anti_geometric(rhs.nz)
+				+ self.xyz.anti_geometric(rhs.ny)
+				+ self.z.anti_geometric(rhs.nx)
+				+ self.zxw.anti_geometric(rhs.d),
+			xy: self.x.anti_geometric(rhs.ny)
+				+ self.xyw.anti_geometric(rhs.d)
+				+ self.xyz.anti_geometric(rhs.nz)
+				+ self.y.anti_geometric(rhs.nx),
+			yzw: self.wy.anti_geometric(rhs.nz) + self.wz.anti_geometric(rhs.ny) + self.xyzw.anti_geometric(rhs.nx),
+			zxw: self.wx.anti_geometric(rhs.nz) + self.wz.anti_geometric(rhs.nx) + self.xyzw.anti_geometric(rhs.ny),
+			xyw: self.wx.anti_geometric(rhs.ny) + self.wy.anti_geometric(rhs.nx) + self.xyzw.anti_geometric(rhs.nz),
+			xyz: self.xy.anti_geometric(rhs.nz)
+				+ self.xyzw.anti_geometric(rhs.d)
+				+ self.yz.anti_geometric(rhs.nx)
+				+ self.zx.anti_geometric(rhs.ny),
+			xyzw: self.xyw.anti_geometric(rhs.nz) + self.yzw.anti_geometric(rhs.nx) + self.zxw.anti_geometric(rhs.ny),
+		}
+	}
+}
+
+// Multivector.dot(Plane) -> Multivector
+impl Dot<Plane> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xyz.0 * rhs.d.0),
+		//     x   : -X(self.yz.0 * rhs.d.0),
+		//     y   : -Y(self.zx.0 * rhs.d.0),
+		//     z   : -Z(self.xy.0 * rhs.d.0),
+		//     w   : -W(self.xy.0 * rhs.nz.0) + W(self.xyzw.0 * rhs.d.0) - W(self.yz.0 * rhs.nx.0) - W(self.zx.0 * rhs.ny.0),
+		//     wx  : WX(self.y.0 * rhs.nz.0) - WX(self.z.0 * rhs.ny.0),
+		//     wy  : -WY(self.x.0 * rhs.nz.0) + WY(self.z.0 * rhs.nx.0),
+		//     wz  : WZ(self.x.0 * rhs.ny.0) - WZ(self.y.0 * rhs.nx.0),
+		//     yz  : YZ(self.x.0 * rhs.d.0),
+		//     zx  : ZX(self.y.0 * rhs.d.0),
+		//     xy  : XY(self.z.0 * rhs.d.0),
+		//     yzw : YZW(self.s.0 * rhs.nx.0),
+		//     zxw : ZXW(self.s.0 * rhs.ny.0),
+		//     xyw : XYW(self.s.0 * rhs.nz.0),
+		//     xyz : XYZ(self.s.0 * rhs.d.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xyz.dot(rhs.d),
+			x: self.yz.dot(rhs.d),
+			y: self.zx.dot(rhs.d),
+			z: self.xy.dot(rhs.d),
+			w: self.xy.dot(rhs.nz) + self.xyzw.dot(rhs.d) + self.yz.dot(rhs.nx) + self.zx.dot(rhs.ny),
+			wx: self.y.dot(rhs.nz) + self.z.dot(rhs.ny),
+			wy: self.x.dot(rhs.nz) + self.z.dot(rhs.nx),
+			wz: self.x.dot(rhs.ny) + self.y.dot(rhs.nx),
+			yz: self.x.dot(rhs.d),
+			zx: self.y.dot(rhs.d),
+			xy: self.z.dot(rhs.d),
+			yzw: self.s.dot(rhs.nx),
+			zxw: self.s.dot(rhs.ny),
+			xyw: self.s.dot(rhs.nz),
+			xyz: self.s.dot(rhs.d),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Plane) -> Multivector
+impl Wedge<Plane> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.s.0 * rhs.nx.0),
+		//     zxw : ZXW(self.s.0 * rhs.ny.0),
+		//     xyw : XYW(self.s.0 * rhs.nz.0),
+		//     xyz : XYZ(self.s.0 * rhs.d.0),
+		//     xyzw: -XYZW(self.w.0 * rhs.d.0) + XYZW(self.x.0 * rhs.nx.0) + XYZW(self.y.0 * rhs.ny.0) + XYZW(self.z.0 * rhs.nz.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.s.wedge(rhs.nx),
+			zxw: self.s.wedge(rhs.ny),
+			xyw: self.s.wedge(rhs.nz),
+			xyz: self.s.wedge(rhs.d),
+			xyzw: self.w.wedge(rhs.d) + self.x.wedge(rhs.nx) + self.y.wedge(rhs.ny) + self.z.wedge(rhs.nz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Plane) -> Multivector
+impl AntiWedge<Plane> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.w.0 * rhs.d.0) + S(self.x.0 * rhs.nx.0) + S(self.y.0 * rhs.ny.0) + S(self.z.0 * rhs.nz.0),
+		//     x   : -X(self.wx.0 * rhs.d.0) - X(self.xy.0 * rhs.ny.0) + X(self.zx.0 * rhs.nz.0),
+		//     y   : -Y(self.wy.0 * rhs.d.0) + Y(self.xy.0 * rhs.nx.0) - Y(self.yz.0 * rhs.nz.0),
+		//     z   : -Z(self.wz.0 * rhs.d.0) + Z(self.yz.0 * rhs.ny.0) - Z(self.zx.0 * rhs.nx.0),
+		//     w   : -W(self.wx.0 * rhs.nx.0) - W(self.wy.0 * rhs.ny.0) - W(self.wz.0 * rhs.nz.0),
+		//     wx  : WX(self.xyw.0 * rhs.ny.0) - WX(self.zxw.0 * rhs.nz.0),
+		//     wy  : -WY(self.xyw.0 * rhs.nx.0) + WY(self.yzw.0 * rhs.nz.0),
+		//     wz  : -WZ(self.yzw.0 * rhs.ny.0) + WZ(self.zxw.0 * rhs.nx.0),
+		//     yz  : YZ(self.xyz.0 * rhs.nx.0) - YZ(self.yzw.0 * rhs.d.0),
+		//     zx  : ZX(self.xyz.0 * rhs.ny.0) - ZX(self.zxw.0 * rhs.d.0),
+		//     xy  : -XY(self.xyw.0 * rhs.d.0) + XY(self.xyz.0 * rhs.nz.0),
+		//     yzw : YZW(self.xyzw.0 * rhs.nx.0),
+		//     zxw : ZXW(self.xyzw.0 * rhs.ny.0),
+		//     xyw : XYW(self.xyzw.0 * rhs.nz.0),
+		//     xyz : XYZ(self.xyzw.0 * rhs.d.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.w.anti_wedge(rhs.d)
+				+ self.x.anti_wedge(rhs.nx)
+				+ self.y.anti_wedge(rhs.ny)
+				+ self.z.anti_wedge(rhs.nz),
+			x: self.wx.anti_wedge(rhs.d) + self.xy.anti_wedge(rhs.ny) + self.zx.anti_wedge(rhs.nz),
+			y: self.wy.anti_wedge(rhs.d) + self.xy.anti_wedge(rhs.nx) + self.yz.anti_wedge(rhs.nz),
+			z: self.wz.anti_wedge(rhs.d) + self.yz.anti_wedge(rhs.ny) + self.zx.anti_wedge(rhs.nx),
+			w: self.wx.anti_wedge(rhs.nx) + self.wy.anti_wedge(rhs.ny) + self.wz.anti_wedge(rhs.nz),
+			wx: self.xyw.anti_wedge(rhs.ny) + self.zxw.anti_wedge(rhs.nz),
+			wy: self.xyw.anti_wedge(rhs.nx) + self.yzw.anti_wedge(rhs.nz),
+			wz: self.yzw.anti_wedge(rhs.ny) + self.zxw.anti_wedge(rhs.nx),
+			yz: self.xyz.anti_wedge(rhs.nx) + self.yzw.anti_wedge(rhs.d),
+			zx: self.xyz.anti_wedge(rhs.ny) + self.zxw.anti_wedge(rhs.d),
+			xy: self.xyw.anti_wedge(rhs.d) + self.xyz.anti_wedge(rhs.nz),
+			yzw: self.xyzw.anti_wedge(rhs.nx),
+			zxw: self.xyzw.anti_wedge(rhs.ny),
+			xyw: self.xyzw.anti_wedge(rhs.nz),
+			xyz: self.xyzw.anti_wedge(rhs.d),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Plane) -> Multivector
+impl LeftContraction<Plane> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xyz.0 * rhs.d.0),
+		//     x   : -X(self.yz.0 * rhs.d.0),
+		//     y   : -Y(self.zx.0 * rhs.d.0),
+		//     z   : -Z(self.xy.0 * rhs.d.0),
+		//     w   : -W(self.xy.0 * rhs.nz.0) - W(self.yz.0 * rhs.nx.0) - W(self.zx.0 * rhs.ny.0),
+		//     wx  : WX(self.y.0 * rhs.nz.0) - WX(self.z.0 * rhs.ny.0),
+		//     wy  : -WY(self.x.0 * rhs.nz.0) + WY(self.z.0 * rhs.nx.0),
+		//     wz  : WZ(self.x.0 * rhs.ny.0) - WZ(self.y.0 * rhs.nx.0),
+		//     yz  : YZ(self.x.0 * rhs.d.0),
+		//     zx  : ZX(self.y.0 * rhs.d.0),
+		//     xy  : XY(self.z.0 * rhs.d.0),
+		//     yzw : YZW(self.s.0 * rhs.nx.0),
+		//     zxw : ZXW(self.s.0 * rhs.ny.0),
+		//     xyw : XYW(self.s.0 * rhs.nz.0),
+		//     xyz : XYZ(self.s.0 * rhs.d.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xyz.left_contraction(rhs.d),
+			x: self.yz.left_contraction(rhs.d),
+			y: self.zx.left_contraction(rhs.d),
+			z: self.xy.left_contraction(rhs.d),
+			w: self.xy.left_contraction(rhs.nz) + self.yz.left_contraction(rhs.nx) + self.zx.left_contraction(rhs.ny),
+			wx: self.y.left_contraction(rhs.nz) + self.z.left_contraction(rhs.ny),
+			wy: self.x.left_contraction(rhs.nz) + self.z.left_contraction(rhs.nx),
+			wz: self.x.left_contraction(rhs.ny) + self.y.left_contraction(rhs.nx),
+			yz: self.x.left_contraction(rhs.d),
+			zx: self.y.left_contraction(rhs.d),
+			xy: self.z.left_contraction(rhs.d),
+			yzw: self.s.left_contraction(rhs.nx),
+			zxw: self.s.left_contraction(rhs.ny),
+			xyw: self.s.left_contraction(rhs.nz),
+			xyz: self.s.left_contraction(rhs.d),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Plane) -> Multivector
+impl RightContraction<Plane> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xyz.0 * rhs.d.0),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.xyzw.0 * rhs.d.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xyz.right_contraction(rhs.d),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.xyzw.right_contraction(rhs.d),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Plane) -> S
+impl ScalarProduct<Plane> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Plane) -> Self::Output {
+		// -S(self.xyz.0 * rhs.d.0)
+		self.xyz.scalar_product(rhs.d)
+	}
+}
+
+// Multivector.commutator(Plane) -> Multivector
+impl Commutator<Plane> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.xyzw.0 * rhs.d.0),
+		//     wx  : WX(self.xyz.0 * rhs.nx.0) - WX(self.yzw.0 * rhs.d.0),
+		//     wy  : WY(self.xyz.0 * rhs.ny.0) - WY(self.zxw.0 * rhs.d.0),
+		//     wz  : -WZ(self.xyw.0 * rhs.d.0) + WZ(self.xyz.0 * rhs.nz.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.wx.0 * rhs.d.0) + YZW(self.xy.0 * rhs.ny.0) - YZW(self.zx.0 * rhs.nz.0),
+		//     zxw : ZXW(self.wy.0 * rhs.d.0) - ZXW(self.xy.0 * rhs.nx.0) + ZXW(self.yz.0 * rhs.nz.0),
+		//     xyw : XYW(self.wz.0 * rhs.d.0) - XYW(self.yz.0 * rhs.ny.0) + XYW(self.zx.0 * rhs.nx.0),
+		//     xyz : Default::default(),
+		//     xyzw: -XYZW(self.w.0 * rhs.d.0) + XYZW(self.x.0 * rhs.nx.0) + XYZW(self.y.0 * rhs.ny.0) + XYZW(self.z.0 * rhs.nz.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.xyzw.commutator(rhs.d),
+			wx: self.xyz.commutator(rhs.nx) + self.yzw.commutator(rhs.d),
+			wy: self.xyz.commutator(rhs.ny) + self.zxw.commutator(rhs.d),
+			wz: self.xyw.commutator(rhs.d) + self.xyz.commutator(rhs.nz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.wx.commutator(rhs.d) + self.xy.commutator(rhs.ny) + self.zx.commutator(rhs.nz),
+			zxw: self.wy.commutator(rhs.d) + self.xy.commutator(rhs.nx) + self.yz.commutator(rhs.nz),
+			xyw: self.wz.commutator(rhs.d) + self.yz.commutator(rhs.ny) + self.zx.commutator(rhs.nx),
+			xyz: Default::default(),
+			xyzw: self.w.commutator(rhs.d)
+				+ self.x.commutator(rhs.nx)
+				+ self.y.commutator(rhs.ny)
+				+ self.z.commutator(rhs.nz),
+		}
+	}
+}
+
+// Multivector.anti_dot(Plane) -> Multivector
+impl AntiDot<Plane> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.s.0 * rhs.nx.0),
+		//     y   : Y(self.s.0 * rhs.ny.0),
+		//     z   : Z(self.s.0 * rhs.nz.0),
+		//     w   : Default::default(),
+		//     wx  : -WX(self.w.0 * rhs.nx.0),
+		//     wy  : -WY(self.w.0 * rhs.ny.0),
+		//     wz  : -WZ(self.w.0 * rhs.nz.0),
+		//     yz  : -YZ(self.y.0 * rhs.nz.0) + YZ(self.z.0 * rhs.ny.0),
+		//     zx  : ZX(self.x.0 * rhs.nz.0) - ZX(self.z.0 * rhs.nx.0),
+		//     xy  : -XY(self.x.0 * rhs.ny.0) + XY(self.y.0 * rhs.nx.0),
+		//     yzw : YZW(self.wy.0 * rhs.nz.0) - YZW(self.wz.0 * rhs.ny.0) + YZW(self.xyzw.0 * rhs.nx.0),
+		//     zxw : -ZXW(self.wx.0 * rhs.nz.0) + ZXW(self.wz.0 * rhs.nx.0) + ZXW(self.xyzw.0 * rhs.ny.0),
+		//     xyw : XYW(self.wx.0 * rhs.ny.0) - XYW(self.wy.0 * rhs.nx.0) + XYW(self.xyzw.0 * rhs.nz.0),
+		//     xyz : XYZ(self.xy.0 * rhs.nz.0) + XYZ(self.xyzw.0 * rhs.d.0) + XYZ(self.yz.0 * rhs.nx.0) + XYZ(self.zx.0 * rhs.ny.0),
+		//     xyzw: XYZW(self.xyw.0 * rhs.nz.0) + XYZW(self.yzw.0 * rhs.nx.0) + XYZW(self.zxw.0 * rhs.ny.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.nx),
+			y: self.s.anti_dot(rhs.ny),
+			z: self.s.anti_dot(rhs.nz),
+			w: Default::default(),
+			wx: self.w.anti_dot(rhs.nx),
+			wy: self.w.anti_dot(rhs.ny),
+			wz: self.w.anti_dot(rhs.nz),
+			yz: self.y.anti_dot(rhs.nz) + self.z.anti_dot(rhs.ny),
+			zx: self.x.anti_dot(rhs.nz) + self.z.anti_dot(rhs.nx),
+			xy: self.x.anti_dot(rhs.ny) + self.y.anti_dot(rhs.nx),
+			yzw: self.wy.anti_dot(rhs.nz) + self.wz.anti_dot(rhs.ny) + self.xyzw.anti_dot(rhs.nx),
+			zxw: self.wx.anti_dot(rhs.nz) + self.wz.anti_dot(rhs.nx) + self.xyzw.anti_dot(rhs.ny),
+			xyw: self.wx.anti_dot(rhs.ny) + self.wy.anti_dot(rhs.nx) + self.xyzw.anti_dot(rhs.nz),
+			xyz: self.xy.anti_dot(rhs.nz)
+				+ self.xyzw.anti_dot(rhs.d)
+				+ self.yz.anti_dot(rhs.nx)
+				+ self.zx.anti_dot(rhs.ny),
+			xyzw: self.xyw.anti_dot(rhs.nz) + self.yzw.anti_dot(rhs.nx) + self.zxw.anti_dot(rhs.ny),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Translator3:
+
+// Multivector.geometric(Translator3) -> Multivector
+impl Geometric<Translator3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.z.0) - S(self.yz.0 * rhs.x.0) - S(self.zx.0 * rhs.y.0),
+		//     x   : -X(self.xyz.0 * rhs.x.0) - X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.xyz.0 * rhs.y.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) - Z(self.xyz.0 * rhs.z.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : -W(self.xyw.0 * rhs.z.0) - W(self.xyz.0 * rhs.w.0) - W(self.yzw.0 * rhs.x.0) - W(self.zxw.0 * rhs.y.0),
+		//     wx  : -WX(self.wy.0 * rhs.z.0) + WX(self.wz.0 * rhs.y.0) + WX(self.xyzw.0 * rhs.x.0) + WX(self.yz.0 * rhs.w.0),
+		//     wy  : WY(self.wx.0 * rhs.z.0) - WY(self.wz.0 * rhs.x.0) + WY(self.xyzw.0 * rhs.y.0) + WY(self.zx.0 * rhs.w.0),
+		//     wz  : -WZ(self.wx.0 * rhs.y.0) + WZ(self.wy.0 * rhs.x.0) + WZ(self.xy.0 * rhs.w.0) + WZ(self.xyzw.0 * rhs.z.0),
+		//     yz  : YZ(self.s.0 * rhs.x.0) + YZ(self.xy.0 * rhs.y.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx  : ZX(self.s.0 * rhs.y.0) - ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0),
+		//     xy  : XY(self.s.0 * rhs.z.0) - XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		//     yzw : YZW(self.w.0 * rhs.x.0) + YZW(self.x.0 * rhs.w.0) + YZW(self.xyw.0 * rhs.y.0) - YZW(self.zxw.0 * rhs.z.0),
+		//     zxw : ZXW(self.w.0 * rhs.y.0) - ZXW(self.xyw.0 * rhs.x.0) + ZXW(self.y.0 * rhs.w.0) + ZXW(self.yzw.0 * rhs.z.0),
+		//     xyw : XYW(self.w.0 * rhs.z.0) - XYW(self.yzw.0 * rhs.y.0) + XYW(self.z.0 * rhs.w.0) + XYW(self.zxw.0 * rhs.x.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: XYZW(self.s.0 * rhs.w.0) - XYZW(self.wx.0 * rhs.x.0) - XYZW(self.wy.0 * rhs.y.0) - XYZW(self.wz.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: self.xy.geometric(rhs.z) + self.yz.geometric(rhs.x) + self.zx.geometric(rhs.y),
+			x: self.xyz.geometric(rhs.x) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			y: self.x.geometric(rhs.z) + self.xyz.geometric(rhs.y) + self.z.geometric(rhs.x),
+			z: self.x.geometric(rhs.y) + self.xyz.geometric(rhs.z) + self.y.geometric(rhs.x),
+			w: self.xyw.geometric(rhs.z)
+				+ self.xyz.geometric(rhs.w)
+				+ self.yzw.geometric(rhs.x)
+				+ self.zxw.geometric(rhs.y),
+			wx: self.wy.geometric(rhs.z)
+				+ self.wz.geometric(rhs.y)
+				+ self.xyzw.geometric(rhs.x)
+				+ self.yz.geometric(rhs.w),
+			wy: self.wx.geometric(rhs.z)
+				+ self.wz.geometric(rhs.x)
+				+ self.xyzw.geometric(rhs.y)
+				+ self.zx.geometric(rhs.w),
+			wz: self.wx.geometric(rhs.y)
+				+ self.wy.geometric(rhs.x)
+				+ self.xy.geometric(rhs.w)
+				+ self.xyzw.geometric(rhs.z),
+			yz: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y) + self.zx.geometric(rhs.z),
+			zx: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x) + self.yz.geometric(rhs.z),
+			xy: self.s.geometric(rhs.z) + self.yz.geometric(rhs.y) + self.zx.geometric(rhs.x),
+			yzw: self.w.geometric(rhs.x)
+				+ self.x.geometric(rhs.w)
+				+ self.xyw.geometric(rhs.y)
+				+ self.zxw.geometric(rhs.z),
+			zxw: self.w.geometric(rhs.y)
+				+ self.xyw.geometric(rhs.x)
+				+ self.y.geometric(rhs.w)
+				+ self.yzw.geometric(rhs.z),
+			xyw: self.w.geometric(rhs.z)
+				+ self.yzw.geometric(rhs.y)
+				+ self.z.geometric(rhs.w)
+				+ self.zxw.geometric(rhs.x),
+			xyz: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			xyzw: self.s.geometric(rhs.w)
+				+ self.wx.geometric(rhs.x)
+				+ self.wy.geometric(rhs.y)
+				+ self.wz.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Translator3) -> Multivector
+impl AntiGeometric<Translator3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.w.0) - S(self.wx.0 * rhs.x.0) - S(self.wy.0 * rhs.y.0) - S(self.wz.0 * rhs.z.0),
+		//     x   : -X(self.w.0 * rhs.x.0) + X(self.x.0 * rhs.w.0) + X(self.xyw.0 * rhs.y.0) - X(self.zxw.0 * rhs.z.0),
+		//     y   : -Y(self.w.0 * rhs.y.0) - Y(self.xyw.0 * rhs.x.0) + Y(self.y.0 * rhs.w.0) + Y(self.yzw.0 * rhs.z.0),
+		//     z   : -Z(self.w.0 * rhs.z.0) - Z(self.yzw.0 * rhs.y.0) + Z(self.z.0 * rhs.w.0) + Z(self.zxw.0 * rhs.x.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : WX(self.wx.0 * rhs.w.0),
+		//     wy  : WY(self.wy.0 * rhs.w.0),
+		//     wz  : WZ(self.wz.0 * rhs.w.0),
+		//     yz  : YZ(self.wy.0 * rhs.z.0) - YZ(self.wz.0 * rhs.y.0) + YZ(self.xyzw.0 * rhs.x.0) + YZ(self.yz.0 * rhs.w.0),
+		//     zx  : -ZX(self.wx.0 * rhs.z.0) + ZX(self.wz.0 * rhs.x.0) + ZX(self.xyzw.0 * rhs.y.0) + ZX(self.zx.0 * rhs.w.0),
+		//     xy  : XY(self.wx.0 * rhs.y.0) - XY(self.wy.0 * rhs.x.0) + XY(self.xy.0 * rhs.w.0) + XY(self.xyzw.0 * rhs.z.0),
+		//     yzw : YZW(self.yzw.0 * rhs.w.0),
+		//     zxw : ZXW(self.zxw.0 * rhs.w.0),
+		//     xyw : XYW(self.xyw.0 * rhs.w.0),
+		//     xyz : -XYZ(self.xyw.0 * rhs.z.0) + XYZ(self.xyz.0 * rhs.w.0) - XYZ(self.yzw.0 * rhs.x.0) - XYZ(self.zxw.0 * rhs.y.0),
+		//     xyzw: XYZW(self.xyzw.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.s.anti_geometric(rhs.w)
+				+ self.wx.anti_geometric(rhs.x)
+				+ self.wy.anti_geometric(rhs.y)
+				+ self.wz.anti_geometric(rhs.z),
+			x: self.w.anti_geometric(rhs.x)
+				+ self.x.anti_geometric(rhs.w)
+				+ self.xyw.anti_geometric(rhs.y)
+				+ self.zxw.anti_geometric(rhs.z),
+			y: self.w.anti_geometric(rhs.y)
+				+ self.xyw.anti_geometric(rhs.x)
+				+ self.y.anti_geometric(rhs.w)
+				+ self.yzw.anti_geometric(rhs.z),
+			z: self.w.anti_geometric(rhs.z)
+				+ self.yzw.anti_geometric(rhs.y)
+				+ self.z.anti_geometric(rhs.w)
+				+ self.zxw.anti_geometric(rhs.x),
+			w: self.w.anti_geometric(rhs.w),
+			wx: self.wx.anti_geometric(rhs.w),
+			wy: self.wy.anti_geometric(rhs.w),
+			wz: self.wz.anti_geometric(rhs.w),
+			yz: self.wy.anti_geometric(rhs.z)
+				+ self.wz.anti_geometric(rhs.y)
+				+ self.xyzw.anti_geometric(rhs.x)
+				+ self.yz.anti_geometric(rhs.w),
+			zx: self.wx.anti_geometric(rhs.z)
+				+ self.wz.anti_geometric(rhs.x)
+				+ self.xyzw.anti_geometric(rhs.y)
+				+ self.zx.anti_geometric(rhs.w),
+			xy: self.wx.anti_geometric(rhs.y)
+				+ self.wy.anti_geometric(rhs.x)
+				+ self.xy.anti_geometric(rhs.w)
+				+ self.xyzw.anti_geometric(rhs.z),
+			yzw: self.yzw.anti_geometric(rhs.w),
+			zxw: self.zxw.anti_geometric(rhs.w),
+			xyw: self.xyw.anti_geometric(rhs.w),
+			xyz: self.xyw.anti_geometric(rhs.z)
+				+ self.xyz.anti_geometric(rhs.w)
+				+ self.yzw.anti_geometric(rhs.x)
+				+ self.zxw.anti_geometric(rhs.y),
+			xyzw: self.xyzw.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Multivector.dot(Translator3) -> Multivector
+impl Dot<Translator3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.z.0) - S(self.yz.0 * rhs.x.0) - S(self.zx.0 * rhs.y.0),
+		//     x   : -X(self.xyz.0 * rhs.x.0) - X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.xyz.0 * rhs.y.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) - Z(self.xyz.0 * rhs.z.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : -W(self.xyw.0 * rhs.z.0) - W(self.xyz.0 * rhs.w.0) - W(self.yzw.0 * rhs.x.0) - W(self.zxw.0 * rhs.y.0),
+		//     wx  : WX(self.xyzw.0 * rhs.x.0) + WX(self.yz.0 * rhs.w.0),
+		//     wy  : WY(self.xyzw.0 * rhs.y.0) + WY(self.zx.0 * rhs.w.0),
+		//     wz  : WZ(self.xy.0 * rhs.w.0) + WZ(self.xyzw.0 * rhs.z.0),
+		//     yz  : YZ(self.s.0 * rhs.x.0),
+		//     zx  : ZX(self.s.0 * rhs.y.0),
+		//     xy  : XY(self.s.0 * rhs.z.0),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.s.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.xy.dot(rhs.z) + self.yz.dot(rhs.x) + self.zx.dot(rhs.y),
+			x: self.xyz.dot(rhs.x) + self.y.dot(rhs.z) + self.z.dot(rhs.y),
+			y: self.x.dot(rhs.z) + self.xyz.dot(rhs.y) + self.z.dot(rhs.x),
+			z: self.x.dot(rhs.y) + self.xyz.dot(rhs.z) + self.y.dot(rhs.x),
+			w: self.xyw.dot(rhs.z) + self.xyz.dot(rhs.w) + self.yzw.dot(rhs.x) + self.zxw.dot(rhs.y),
+			wx: self.xyzw.dot(rhs.x) + self.yz.dot(rhs.w),
+			wy: self.xyzw.dot(rhs.y) + self.zx.dot(rhs.w),
+			wz: self.xy.dot(rhs.w) + self.xyzw.dot(rhs.z),
+			yz: self.s.dot(rhs.x),
+			zx: self.s.dot(rhs.y),
+			xy: self.s.dot(rhs.z),
+			yzw: self.x.dot(rhs.w),
+			zxw: self.y.dot(rhs.w),
+			xyw: self.z.dot(rhs.w),
+			xyz: Default::default(),
+			xyzw: self.s.dot(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Translator3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Translator3) -> Multivector
+impl Wedge<Translator3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.s.0 * rhs.x.0),
+		//     zx  : ZX(self.s.0 * rhs.y.0),
+		//     xy  : XY(self.s.0 * rhs.z.0),
+		//     yzw : YZW(self.w.0 * rhs.x.0),
+		//     zxw : ZXW(self.w.0 * rhs.y.0),
+		//     xyw : XYW(self.w.0 * rhs.z.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: XYZW(self.s.0 * rhs.w.0) - XYZW(self.wx.0 * rhs.x.0) - XYZW(self.wy.0 * rhs.y.0) - XYZW(self.wz.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.s.wedge(rhs.x),
+			zx: self.s.wedge(rhs.y),
+			xy: self.s.wedge(rhs.z),
+			yzw: self.w.wedge(rhs.x),
+			zxw: self.w.wedge(rhs.y),
+			xyw: self.w.wedge(rhs.z),
+			xyz: self.x.wedge(rhs.x) + self.y.wedge(rhs.y) + self.z.wedge(rhs.z),
+			xyzw: self.s.wedge(rhs.w) + self.wx.wedge(rhs.x) + self.wy.wedge(rhs.y) + self.wz.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Translator3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Translator3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Translator3) -> Multivector
+impl AntiWedge<Translator3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.w.0) - S(self.wx.0 * rhs.x.0) - S(self.wy.0 * rhs.y.0) - S(self.wz.0 * rhs.z.0),
+		//     x   : X(self.x.0 * rhs.w.0) + X(self.xyw.0 * rhs.y.0) - X(self.zxw.0 * rhs.z.0),
+		//     y   : -Y(self.xyw.0 * rhs.x.0) + Y(self.y.0 * rhs.w.0) + Y(self.yzw.0 * rhs.z.0),
+		//     z   : -Z(self.yzw.0 * rhs.y.0) + Z(self.z.0 * rhs.w.0) + Z(self.zxw.0 * rhs.x.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : WX(self.wx.0 * rhs.w.0),
+		//     wy  : WY(self.wy.0 * rhs.w.0),
+		//     wz  : WZ(self.wz.0 * rhs.w.0),
+		//     yz  : YZ(self.xyzw.0 * rhs.x.0) + YZ(self.yz.0 * rhs.w.0),
+		//     zx  : ZX(self.xyzw.0 * rhs.y.0) + ZX(self.zx.0 * rhs.w.0),
+		//     xy  : XY(self.xy.0 * rhs.w.0) + XY(self.xyzw.0 * rhs.z.0),
+		//     yzw : YZW(self.yzw.0 * rhs.w.0),
+		//     zxw : ZXW(self.zxw.0 * rhs.w.0),
+		//     xyw : XYW(self.xyw.0 * rhs.w.0),
+		//     xyz : XYZ(self.xyz.0 * rhs.w.0),
+		//     xyzw: XYZW(self.xyzw.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.s.anti_wedge(rhs.w)
+				+ self.wx.anti_wedge(rhs.x)
+				+ self.wy.anti_wedge(rhs.y)
+				+ self.wz.anti_wedge(rhs.z),
+			x: self.x.anti_wedge(rhs.w) + self.xyw.anti_wedge(rhs.y) + self.zxw.anti_wedge(rhs.z),
+			y: self.xyw.anti_wedge(rhs.x) + self.y.anti_wedge(rhs.w) + self.yzw.anti_wedge(rhs.z),
+			z: self.yzw.anti_wedge(rhs.y) + self.z.anti_wedge(rhs.w) + self.zxw.anti_wedge(rhs.x),
+			w: self.w.anti_wedge(rhs.w),
+			wx: self.wx.anti_wedge(rhs.w),
+			wy: self.wy.anti_wedge(rhs.w),
+			wz: self.wz.anti_wedge(rhs.w),
+			yz: self.xyzw.anti_wedge(rhs.x) + self.yz.anti_wedge(rhs.w),
+			zx: self.xyzw.anti_wedge(rhs.y) + self.zx.anti_wedge(rhs.w),
+			xy: self.xy.anti_wedge(rhs.w) + self.xyzw.anti_wedge(rhs.z),
+			yzw: self.yzw.anti_wedge(rhs.w),
+			zxw: self.zxw.anti_wedge(rhs.w),
+			xyw: self.xyw.anti_wedge(rhs.w),
+			xyz: self.xyz.anti_wedge(rhs.w),
+			xyzw: self.xyzw.anti_wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Translator3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Translator3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Translator3) -> Multivector
+impl LeftContraction<Translator3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.z.0) - S(self.yz.0 * rhs.x.0) - S(self.zx.0 * rhs.y.0),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : -W(self.xyz.0 * rhs.w.0),
+		//     wx  : WX(self.yz.0 * rhs.w.0),
+		//     wy  : WY(self.zx.0 * rhs.w.0),
+		//     wz  : WZ(self.xy.0 * rhs.w.0),
+		//     yz  : YZ(self.s.0 * rhs.x.0),
+		//     zx  : ZX(self.s.0 * rhs.y.0),
+		//     xy  : XY(self.s.0 * rhs.z.0),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.s.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.xy.left_contraction(rhs.z) + self.yz.left_contraction(rhs.x) + self.zx.left_contraction(rhs.y),
+			x: self.y.left_contraction(rhs.z) + self.z.left_contraction(rhs.y),
+			y: self.x.left_contraction(rhs.z) + self.z.left_contraction(rhs.x),
+			z: self.x.left_contraction(rhs.y) + self.y.left_contraction(rhs.x),
+			w: self.xyz.left_contraction(rhs.w),
+			wx: self.yz.left_contraction(rhs.w),
+			wy: self.zx.left_contraction(rhs.w),
+			wz: self.xy.left_contraction(rhs.w),
+			yz: self.s.left_contraction(rhs.x),
+			zx: self.s.left_contraction(rhs.y),
+			xy: self.s.left_contraction(rhs.z),
+			yzw: self.x.left_contraction(rhs.w),
+			zxw: self.y.left_contraction(rhs.w),
+			xyw: self.z.left_contraction(rhs.w),
+			xyz: Default::default(),
+			xyzw: self.s.left_contraction(rhs.w),
+		}
+	}
+}
+
+// Multivector.right_contraction(Translator3) -> Multivector
+impl RightContraction<Translator3> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.xy.0 * rhs.z.0) - S(self.yz.0 * rhs.x.0) - S(self.zx.0 * rhs.y.0),
+		//     x   : -X(self.xyz.0 * rhs.x.0),
+		//     y   : -Y(self.xyz.0 * rhs.y.0),
+		//     z   : -Z(self.xyz.0 * rhs.z.0),
+		//     w   : -W(self.xyw.0 * rhs.z.0) - W(self.yzw.0 * rhs.x.0) - W(self.zxw.0 * rhs.y.0),
+		//     wx  : WX(self.xyzw.0 * rhs.x.0),
+		//     wy  : WY(self.xyzw.0 * rhs.y.0),
+		//     wz  : WZ(self.xyzw.0 * rhs.z.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.right_contraction(rhs.z) + self.yz.right_contraction(rhs.x) + self.zx.right_contraction(rhs.y),
+			x: self.xyz.right_contraction(rhs.x),
+			y: self.xyz.right_contraction(rhs.y),
+			z: self.xyz.right_contraction(rhs.z),
+			w: self.xyw.right_contraction(rhs.z)
+				+ self.yzw.right_contraction(rhs.x)
+				+ self.zxw.right_contraction(rhs.y),
+			wx: self.xyzw.right_contraction(rhs.x),
+			wy: self.xyzw.right_contraction(rhs.y),
+			wz: self.xyzw.right_contraction(rhs.z),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Translator3) -> S
+impl ScalarProduct<Translator3> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator3) -> Self::Output {
+		// -S(self.xy.0 * rhs.z.0) - S(self.yz.0 * rhs.x.0) - S(self.zx.0 * rhs.y.0)
+		self.xy.scalar_product(rhs.z) + self.yz.scalar_product(rhs.x) + self.zx.scalar_product(rhs.y)
+	}
+}
+
+// Multivector.commutator(Translator3) -> Multivector
+impl Commutator<Translator3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : -W(self.xyz.0 * rhs.w.0),
+		//     wx  : -WX(self.wy.0 * rhs.z.0) + WX(self.wz.0 * rhs.y.0),
+		//     wy  : WY(self.wx.0 * rhs.z.0) - WY(self.wz.0 * rhs.x.0),
+		//     wz  : -WZ(self.wx.0 * rhs.y.0) + WZ(self.wy.0 * rhs.x.0),
+		//     yz  : YZ(self.xy.0 * rhs.y.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx  : -ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0),
+		//     xy  : -XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		//     yzw : YZW(self.x.0 * rhs.w.0) + YZW(self.xyw.0 * rhs.y.0) - YZW(self.zxw.0 * rhs.z.0),
+		//     zxw : -ZXW(self.xyw.0 * rhs.x.0) + ZXW(self.y.0 * rhs.w.0) + ZXW(self.yzw.0 * rhs.z.0),
+		//     xyw : -XYW(self.yzw.0 * rhs.y.0) + XYW(self.z.0 * rhs.w.0) + XYW(self.zxw.0 * rhs.x.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			y: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			z: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			w: self.xyz.commutator(rhs.w),
+			wx: self.wy.commutator(rhs.z) + self.wz.commutator(rhs.y),
+			wy: self.wx.commutator(rhs.z) + self.wz.commutator(rhs.x),
+			wz: self.wx.commutator(rhs.y) + self.wy.commutator(rhs.x),
+			yz: self.xy.commutator(rhs.y) + self.zx.commutator(rhs.z),
+			zx: self.xy.commutator(rhs.x) + self.yz.commutator(rhs.z),
+			xy: self.yz.commutator(rhs.y) + self.zx.commutator(rhs.x),
+			yzw: self.x.commutator(rhs.w) + self.xyw.commutator(rhs.y) + self.zxw.commutator(rhs.z),
+			zxw: self.xyw.commutator(rhs.x) + self.y.commutator(rhs.w) + self.yzw.commutator(rhs.z),
+			xyw: self.yzw.commutator(rhs.y) + self.z.commutator(rhs.w) + self.zxw.commutator(rhs.x),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Translator3) -> Multivector
+impl AntiDot<Translator3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.w.0),
+		//     x   : X(self.x.0 * rhs.w.0),
+		//     y   : Y(self.y.0 * rhs.w.0),
+		//     z   : Z(self.z.0 * rhs.w.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : WX(self.wx.0 * rhs.w.0),
+		//     wy  : WY(self.wy.0 * rhs.w.0),
+		//     wz  : WZ(self.wz.0 * rhs.w.0),
+		//     yz  : YZ(self.xyzw.0 * rhs.x.0) + YZ(self.yz.0 * rhs.w.0),
+		//     zx  : ZX(self.xyzw.0 * rhs.y.0) + ZX(self.zx.0 * rhs.w.0),
+		//     xy  : XY(self.xy.0 * rhs.w.0) + XY(self.xyzw.0 * rhs.z.0),
+		//     yzw : YZW(self.yzw.0 * rhs.w.0),
+		//     zxw : ZXW(self.zxw.0 * rhs.w.0),
+		//     xyw : XYW(self.xyw.0 * rhs.w.0),
+		//     xyz : -XYZ(self.xyw.0 * rhs.z.0) + XYZ(self.xyz.0 * rhs.w.0) - XYZ(self.yzw.0 * rhs.x.0) - XYZ(self.zxw.0 * rhs.y.0),
+		//     xyzw: XYZW(self.xyzw.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.s.anti_dot(rhs.w),
+			x: self.x.anti_dot(rhs.w),
+			y: self.y.anti_dot(rhs.w),
+			z: self.z.anti_dot(rhs.w),
+			w: self.w.anti_dot(rhs.w),
+			wx: self.wx.anti_dot(rhs.w),
+			wy: self.wy.anti_dot(rhs.w),
+			wz: self.wz.anti_dot(rhs.w),
+			yz: self.xyzw.anti_dot(rhs.x) + self.yz.anti_dot(rhs.w),
+			zx: self.xyzw.anti_dot(rhs.y) + self.zx.anti_dot(rhs.w),
+			xy: self.xy.anti_dot(rhs.w) + self.xyzw.anti_dot(rhs.z),
+			yzw: self.yzw.anti_dot(rhs.w),
+			zxw: self.zxw.anti_dot(rhs.w),
+			xyw: self.xyw.anti_dot(rhs.w),
+			xyz: self.xyw.anti_dot(rhs.z)
+				+ self.xyz.anti_dot(rhs.w)
+				+ self.yzw.anti_dot(rhs.x)
+				+ self.zxw.anti_dot(rhs.y),
+			xyzw: self.xyzw.anti_dot(rhs.w),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Rotor3:
+
+// Multivector.geometric(Rotor3) -> Multivector
+impl Geometric<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.x.0 * rhs.x.0) - W(self.xyz.0 * rhs.w.0) - W(self.y.0 * rhs.y.0) - W(self.z.0 * rhs.z.0),
+		//     wx  : WX(self.s.0 * rhs.x.0) + WX(self.xy.0 * rhs.y.0) + WX(self.yz.0 * rhs.w.0) - WX(self.zx.0 * rhs.z.0),
+		//     wy  : WY(self.s.0 * rhs.y.0) - WY(self.xy.0 * rhs.x.0) + WY(self.yz.0 * rhs.z.0) + WY(self.zx.0 * rhs.w.0),
+		//     wz  : WZ(self.s.0 * rhs.z.0) + WZ(self.xy.0 * rhs.w.0) - WZ(self.yz.0 * rhs.y.0) + WZ(self.zx.0 * rhs.x.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0) - YZW(self.xyz.0 * rhs.x.0) - YZW(self.y.0 * rhs.z.0) + YZW(self.z.0 * rhs.y.0),
+		//     zxw : ZXW(self.x.0 * rhs.z.0) - ZXW(self.xyz.0 * rhs.y.0) + ZXW(self.y.0 * rhs.w.0) - ZXW(self.z.0 * rhs.x.0),
+		//     xyw : -XYW(self.x.0 * rhs.y.0) - XYW(self.xyz.0 * rhs.z.0) + XYW(self.y.0 * rhs.x.0) + XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.s.0 * rhs.w.0) - XYZW(self.xy.0 * rhs.z.0) - XYZW(self.yz.0 * rhs.x.0) - XYZW(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.geometric(rhs.x) + self.xyz.geometric(rhs.w) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			wx: self.s.geometric(rhs.x)
+				+ self.xy.geometric(rhs.y)
+				+ self.yz.geometric(rhs.w)
+				+ self.zx.geometric(rhs.z),
+			wy: self.s.geometric(rhs.y)
+				+ self.xy.geometric(rhs.x)
+				+ self.yz.geometric(rhs.z)
+				+ self.zx.geometric(rhs.w),
+			wz: self.s.geometric(rhs.z)
+				+ self.xy.geometric(rhs.w)
+				+ self.yz.geometric(rhs.y)
+				+ self.zx.geometric(rhs.x),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.geometric(rhs.w)
+				+ self.xyz.geometric(rhs.x)
+				+ self.y.geometric(rhs.z)
+				+ self.z.geometric(rhs.y),
+			zxw: self.x.geometric(rhs.z)
+				+ self.xyz.geometric(rhs.y)
+				+ self.y.geometric(rhs.w)
+				+ self.z.geometric(rhs.x),
+			xyw: self.x.geometric(rhs.y)
+				+ self.xyz.geometric(rhs.z)
+				+ self.y.geometric(rhs.x)
+				+ self.z.geometric(rhs.w),
+			xyz: Default::default(),
+			xyzw: self.s.geometric(rhs.w)
+				+ self.xy.geometric(rhs.z)
+				+ self.yz.geometric(rhs.x)
+				+ self.zx.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Rotor3) -> Multivector
+impl AntiGeometric<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.w.0) - S(self.xy.0 * rhs.z.0) - S(self.yz.0 * rhs.x.0) - S(self.zx.0 * rhs.y.0),
+		//     x   : X(self.x.0 * rhs.w.0) - X(self.xyz.0 * rhs.x.0) + X(self.y.0 * rhs.z.0) - X(self.z.0 * rhs.y.0),
+		//     y   : -Y(self.x.0 * rhs.z.0) - Y(self.xyz.0 * rhs.y.0) + Y(self.y.0 * rhs.w.0) + Y(self.z.0 * rhs.x.0),
+		//     z   : Z(self.x.0 * rhs.y.0) - Z(self.xyz.0 * rhs.z.0) - Z(self.y.0 * rhs.x.0) + Z(self.z.0 * rhs.w.0),
+		//     w   : W(self.w.0 * rhs.w.0) - W(self.xyw.0 * rhs.z.0) - W(self.yzw.0 * rhs.x.0) - W(self.zxw.0 * rhs.y.0),
+		//     wx  : WX(self.wx.0 * rhs.w.0) + WX(self.wy.0 * rhs.z.0) - WX(self.wz.0 * rhs.y.0) + WX(self.xyzw.0 * rhs.x.0),
+		//     wy  : -WY(self.wx.0 * rhs.z.0) + WY(self.wy.0 * rhs.w.0) + WY(self.wz.0 * rhs.x.0) + WY(self.xyzw.0 * rhs.y.0),
+		//     wz  : WZ(self.wx.0 * rhs.y.0) - WZ(self.wy.0 * rhs.x.0) + WZ(self.wz.0 * rhs.w.0) + WZ(self.xyzw.0 * rhs.z.0),
+		//     yz  : YZ(self.s.0 * rhs.x.0) - YZ(self.xy.0 * rhs.y.0) + YZ(self.yz.0 * rhs.w.0) + YZ(self.zx.0 * rhs.z.0),
+		//     zx  : ZX(self.s.0 * rhs.y.0) + ZX(self.xy.0 * rhs.x.0) - ZX(self.yz.0 * rhs.z.0) + ZX(self.zx.0 * rhs.w.0),
+		//     xy  : XY(self.s.0 * rhs.z.0) + XY(self.xy.0 * rhs.w.0) + XY(self.yz.0 * rhs.y.0) - XY(self.zx.0 * rhs.x.0),
+		//     yzw : YZW(self.w.0 * rhs.x.0) - YZW(self.xyw.0 * rhs.y.0) + YZW(self.yzw.0 * rhs.w.0) + YZW(self.zxw.0 * rhs.z.0),
+		//     zxw : ZXW(self.w.0 * rhs.y.0) + ZXW(self.xyw.0 * rhs.x.0) - ZXW(self.yzw.0 * rhs.z.0) + ZXW(self.zxw.0 * rhs.w.0),
+		//     xyw : XYW(self.w.0 * rhs.z.0) + XYW(self.xyw.0 * rhs.w.0) + XYW(self.yzw.0 * rhs.y.0) - XYW(self.zxw.0 * rhs.x.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.xyz.0 * rhs.w.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.wx.0 * rhs.x.0) - XYZW(self.wy.0 * rhs.y.0) - XYZW(self.wz.0 * rhs.z.0) + XYZW(self.xyzw.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.s.anti_geometric(rhs.w)
+				+ self.xy.anti_geometric(rhs.z)
+				+ self.yz.anti_geometric(rhs.x)
+				+ self.zx.anti_geometric(rhs.y),
+			x: self.x.anti_geometric(rhs.w)
+				+ self.xyz.anti_geometric(rhs.x)
+				+ self.y.anti_geometric(rhs.z)
+				+ self.z.anti_geometric(rhs.y),
+			y: self.x.anti_geometric(rhs.z)
+				+ self.xyz.anti_geometric(rhs.y)
+				+ self.y.anti_geometric(rhs.w)
+				+ self.z.anti_geometric(rhs.x),
+			z: self.x.anti_geometric(rhs.y)
+				+ self.xyz.anti_geometric(rhs.z)
+				+ self.y.anti_geometric(rhs.x)
+				+ self.z.anti_geometric(rhs.w),
+			w: self.w.anti_geometric(rhs.w)
+				+ self.xyw.anti_geometric(rhs.z)
+				+ self.yzw.anti_geometric(rhs.x)
+				+ self.zxw.anti_geometric(rhs.y),
+			wx: self.wx.anti_geometric(rhs.w)
+				+ self.wy.anti_geometric(rhs.z)
+				+ self.wz.anti_geometric(rhs.y)
+				+ self.xyzw.anti_geometric(rhs.x),
+			wy: self.wx.anti_geometric(rhs.z)
+				+ self.wy.anti_geometric(rhs.w)
+				+ self.wz.anti_geometric(rhs.x)
+				+ self.xyzw.anti_geometric(rhs.y),
+			wz: self.wx.anti_geometric(rhs.y)
+				+ self.wy.anti_geometric(rhs.x)
+				+ self.wz.anti_geometric(rhs.w)
+				+ self.xyzw.anti_geometric(rhs.z),
+			yz: self.s.anti_geometric(rhs.x)
+				+ self.xy.anti_geometric(rhs.y)
+				+ self.yz.anti_geometric(rhs.w)
+				+ self.zx.anti_geometric(rhs.z),
+			zx: self.s.anti_geometric(rhs.y)
+				+ self.xy.anti_geometric(rhs.x)
+				+ self.yz.anti_geometric(rhs.z)
+				+ self.zx.anti_geometric(rhs.w),
+			xy: self.s.anti_geometric(rhs.z)
+				+ self.xy.anti_geometric(rhs.w)
+				+ self.yz.anti_geometric(rhs.y)
+				+ self.zx.anti_geometric(rhs.x),
+			yzw: self.w.anti_geometric(rhs.x)
+				+ self.xyw.anti_geometric(rhs.y)
+				+ self.yzw.anti_geometric(rhs.w)
+				+ self.zxw.anti_geometric(rhs.z),
+			zxw: self.w.anti_geometric(rhs.y)
+				+ self.xyw.anti_geometric(rhs.x)
+				+ self.yzw.anti_geometric(rhs.z)
+				+ self.zxw.anti_geometric(rhs.w),
+			xyw: self.w.anti_geometric(rhs.z)
+				+ self.xyw.anti_geometric(rhs.w)
+				+ self.yzw.anti_geometric(rhs.y)
+				+ self.zxw.anti_geometric(rhs.x),
+			xyz: self.x.anti_geometric(rhs.x)
+				+ self.xyz.anti_geometric(rhs.w)
+				+ self.y.anti_geometric(rhs.y)
+				+ self.z.anti_geometric(rhs.z),
+			xyzw: self.wx.anti_geometric(rhs.x)
+				+ self.wy.anti_geometric(rhs.y)
+				+ self.wz.anti_geometric(rhs.z)
+				+ self.xyzw.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Multivector.dot(Rotor3) -> Multivector
+impl Dot<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.x.0 * rhs.x.0) - W(self.xyz.0 * rhs.w.0) - W(self.y.0 * rhs.y.0) - W(self.z.0 * rhs.z.0),
+		//     wx  : WX(self.s.0 * rhs.x.0) + WX(self.yz.0 * rhs.w.0),
+		//     wy  : WY(self.s.0 * rhs.y.0) + WY(self.zx.0 * rhs.w.0),
+		//     wz  : WZ(self.s.0 * rhs.z.0) + WZ(self.xy.0 * rhs.w.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.s.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.dot(rhs.x) + self.xyz.dot(rhs.w) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			wx: self.s.dot(rhs.x) + self.yz.dot(rhs.w),
+			wy: self.s.dot(rhs.y) + self.zx.dot(rhs.w),
+			wz: self.s.dot(rhs.z) + self.xy.dot(rhs.w),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.dot(rhs.w),
+			zxw: self.y.dot(rhs.w),
+			xyw: self.z.dot(rhs.w),
+			xyz: Default::default(),
+			xyzw: self.s.dot(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Rotor3) -> Multivector
+impl Wedge<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : WX(self.s.0 * rhs.x.0),
+		//     wy  : WY(self.s.0 * rhs.y.0),
+		//     wz  : WZ(self.s.0 * rhs.z.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.y.0 * rhs.z.0) + YZW(self.z.0 * rhs.y.0),
+		//     zxw : ZXW(self.x.0 * rhs.z.0) - ZXW(self.z.0 * rhs.x.0),
+		//     xyw : -XYW(self.x.0 * rhs.y.0) + XYW(self.y.0 * rhs.x.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.s.0 * rhs.w.0) - XYZW(self.xy.0 * rhs.z.0) - XYZW(self.yz.0 * rhs.x.0) - XYZW(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: self.s.wedge(rhs.x),
+			wy: self.s.wedge(rhs.y),
+			wz: self.s.wedge(rhs.z),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			zxw: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			xyw: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			xyz: Default::default(),
+			xyzw: self.s.wedge(rhs.w) + self.xy.wedge(rhs.z) + self.yz.wedge(rhs.x) + self.zx.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Rotor3) -> Multivector
+impl AntiWedge<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.w.0) - S(self.xy.0 * rhs.z.0) - S(self.yz.0 * rhs.x.0) - S(self.zx.0 * rhs.y.0),
+		//     x   : X(self.x.0 * rhs.w.0) - X(self.xyz.0 * rhs.x.0),
+		//     y   : -Y(self.xyz.0 * rhs.y.0) + Y(self.y.0 * rhs.w.0),
+		//     z   : -Z(self.xyz.0 * rhs.z.0) + Z(self.z.0 * rhs.w.0),
+		//     w   : W(self.w.0 * rhs.w.0) - W(self.xyw.0 * rhs.z.0) - W(self.yzw.0 * rhs.x.0) - W(self.zxw.0 * rhs.y.0),
+		//     wx  : WX(self.wx.0 * rhs.w.0) + WX(self.xyzw.0 * rhs.x.0),
+		//     wy  : WY(self.wy.0 * rhs.w.0) + WY(self.xyzw.0 * rhs.y.0),
+		//     wz  : WZ(self.wz.0 * rhs.w.0) + WZ(self.xyzw.0 * rhs.z.0),
+		//     yz  : YZ(self.yz.0 * rhs.w.0),
+		//     zx  : ZX(self.zx.0 * rhs.w.0),
+		//     xy  : XY(self.xy.0 * rhs.w.0),
+		//     yzw : YZW(self.yzw.0 * rhs.w.0),
+		//     zxw : ZXW(self.zxw.0 * rhs.w.0),
+		//     xyw : XYW(self.xyw.0 * rhs.w.0),
+		//     xyz : XYZ(self.xyz.0 * rhs.w.0),
+		//     xyzw: XYZW(self.xyzw.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.s.anti_wedge(rhs.w)
+				+ self.xy.anti_wedge(rhs.z)
+				+ self.yz.anti_wedge(rhs.x)
+				+ self.zx.anti_wedge(rhs.y),
+			x: self.x.anti_wedge(rhs.w) + self.xyz.anti_wedge(rhs.x),
+			y: self.xyz.anti_wedge(rhs.y) + self.y.anti_wedge(rhs.w),
+			z: self.xyz.anti_wedge(rhs.z) + self.z.anti_wedge(rhs.w),
+			w: self.w.anti_wedge(rhs.w)
+				+ self.xyw.anti_wedge(rhs.z)
+				+ self.yzw.anti_wedge(rhs.x)
+				+ self.zxw.anti_wedge(rhs.y),
+			wx: self.wx.anti_wedge(rhs.w) + self.xyzw.anti_wedge(rhs.x),
+			wy: self.wy.anti_wedge(rhs.w) + self.xyzw.anti_wedge(rhs.y),
+			wz: self.wz.anti_wedge(rhs.w) + self.xyzw.anti_wedge(rhs.z),
+			yz: self.yz.anti_wedge(rhs.w),
+			zx: self.zx.anti_wedge(rhs.w),
+			xy: self.xy.anti_wedge(rhs.w),
+			yzw: self.yzw.anti_wedge(rhs.w),
+			zxw: self.zxw.anti_wedge(rhs.w),
+			xyw: self.xyw.anti_wedge(rhs.w),
+			xyz: self.xyz.anti_wedge(rhs.w),
+			xyzw: self.xyzw.anti_wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Rotor3) -> Multivector
+impl LeftContraction<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.x.0 * rhs.x.0) - W(self.xyz.0 * rhs.w.0) - W(self.y.0 * rhs.y.0) - W(self.z.0 * rhs.z.0),
+		//     wx  : WX(self.s.0 * rhs.x.0) + WX(self.yz.0 * rhs.w.0),
+		//     wy  : WY(self.s.0 * rhs.y.0) + WY(self.zx.0 * rhs.w.0),
+		//     wz  : WZ(self.s.0 * rhs.z.0) + WZ(self.xy.0 * rhs.w.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.s.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.left_contraction(rhs.x)
+				+ self.xyz.left_contraction(rhs.w)
+				+ self.y.left_contraction(rhs.y)
+				+ self.z.left_contraction(rhs.z),
+			wx: self.s.left_contraction(rhs.x) + self.yz.left_contraction(rhs.w),
+			wy: self.s.left_contraction(rhs.y) + self.zx.left_contraction(rhs.w),
+			wz: self.s.left_contraction(rhs.z) + self.xy.left_contraction(rhs.w),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.left_contraction(rhs.w),
+			zxw: self.y.left_contraction(rhs.w),
+			xyw: self.z.left_contraction(rhs.w),
+			xyz: Default::default(),
+			xyzw: self.s.left_contraction(rhs.w),
+		}
+	}
+}
+
+impl RightContraction<Rotor3> for Multivector {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Rotor3> for Multivector {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Multivector.commutator(Rotor3) -> Multivector
+impl Commutator<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.x.0 * rhs.x.0) - W(self.xyz.0 * rhs.w.0) - W(self.y.0 * rhs.y.0) - W(self.z.0 * rhs.z.0),
+		//     wx  : WX(self.xy.0 * rhs.y.0) - WX(self.zx.0 * rhs.z.0),
+		//     wy  : -WY(self.xy.0 * rhs.x.0) + WY(self.yz.0 * rhs.z.0),
+		//     wz  : -WZ(self.yz.0 * rhs.y.0) + WZ(self.zx.0 * rhs.x.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0) - YZW(self.xyz.0 * rhs.x.0),
+		//     zxw : -ZXW(self.xyz.0 * rhs.y.0) + ZXW(self.y.0 * rhs.w.0),
+		//     xyw : -XYW(self.xyz.0 * rhs.z.0) + XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.commutator(rhs.x)
+				+ self.xyz.commutator(rhs.w)
+				+ self.y.commutator(rhs.y)
+				+ self.z.commutator(rhs.z),
+			wx: self.xy.commutator(rhs.y) + self.zx.commutator(rhs.z),
+			wy: self.xy.commutator(rhs.x) + self.yz.commutator(rhs.z),
+			wz: self.yz.commutator(rhs.y) + self.zx.commutator(rhs.x),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.commutator(rhs.w) + self.xyz.commutator(rhs.x),
+			zxw: self.xyz.commutator(rhs.y) + self.y.commutator(rhs.w),
+			xyw: self.xyz.commutator(rhs.z) + self.z.commutator(rhs.w),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Rotor3) -> Multivector
+impl AntiDot<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.w.0),
+		//     x   : X(self.x.0 * rhs.w.0),
+		//     y   : Y(self.y.0 * rhs.w.0),
+		//     z   : Z(self.z.0 * rhs.w.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : WX(self.wx.0 * rhs.w.0) + WX(self.xyzw.0 * rhs.x.0),
+		//     wy  : WY(self.wy.0 * rhs.w.0) + WY(self.xyzw.0 * rhs.y.0),
+		//     wz  : WZ(self.wz.0 * rhs.w.0) + WZ(self.xyzw.0 * rhs.z.0),
+		//     yz  : YZ(self.s.0 * rhs.x.0) + YZ(self.yz.0 * rhs.w.0),
+		//     zx  : ZX(self.s.0 * rhs.y.0) + ZX(self.zx.0 * rhs.w.0),
+		//     xy  : XY(self.s.0 * rhs.z.0) + XY(self.xy.0 * rhs.w.0),
+		//     yzw : YZW(self.w.0 * rhs.x.0) - YZW(self.xyw.0 * rhs.y.0) + YZW(self.yzw.0 * rhs.w.0) + YZW(self.zxw.0 * rhs.z.0),
+		//     zxw : ZXW(self.w.0 * rhs.y.0) + ZXW(self.xyw.0 * rhs.x.0) - ZXW(self.yzw.0 * rhs.z.0) + ZXW(self.zxw.0 * rhs.w.0),
+		//     xyw : XYW(self.w.0 * rhs.z.0) + XYW(self.xyw.0 * rhs.w.0) + XYW(self.yzw.0 * rhs.y.0) - XYW(self.zxw.0 * rhs.x.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.xyz.0 * rhs.w.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: -XYZW(self.wx.0 * rhs.x.0) - XYZW(self.wy.0 * rhs.y.0) - XYZW(self.wz.0 * rhs.z.0) + XYZW(self.xyzw.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.s.anti_dot(rhs.w),
+			x: self.x.anti_dot(rhs.w),
+			y: self.y.anti_dot(rhs.w),
+			z: self.z.anti_dot(rhs.w),
+			w: self.w.anti_dot(rhs.w),
+			wx: self.wx.anti_dot(rhs.w) + self.xyzw.anti_dot(rhs.x),
+			wy: self.wy.anti_dot(rhs.w) + self.xyzw.anti_dot(rhs.y),
+			wz: self.wz.anti_dot(rhs.w) + self.xyzw.anti_dot(rhs.z),
+			yz: self.s.anti_dot(rhs.x) + self.yz.anti_dot(rhs.w),
+			zx: self.s.anti_dot(rhs.y) + self.zx.anti_dot(rhs.w),
+			xy: self.s.anti_dot(rhs.z) + self.xy.anti_dot(rhs.w),
+			yzw: self.w.anti_dot(rhs.x)
+				+ self.xyw.anti_dot(rhs.y)
+				+ self.yzw.anti_dot(rhs.w)
+				+ self.zxw.anti_dot(rhs.z),
+			zxw: self.w.anti_dot(rhs.y)
+				+ self.xyw.anti_dot(rhs.x)
+				+ self.yzw.anti_dot(rhs.z)
+				+ self.zxw.anti_dot(rhs.w),
+			xyw: self.w.anti_dot(rhs.z)
+				+ self.xyw.anti_dot(rhs.w)
+				+ self.yzw.anti_dot(rhs.y)
+				+ self.zxw.anti_dot(rhs.x),
+			xyz: self.x.anti_dot(rhs.x) + self.xyz.anti_dot(rhs.w) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z),
+			xyzw: self.wx.anti_dot(rhs.x)
+				+ self.wy.anti_dot(rhs.y)
+				+ self.wz.anti_dot(rhs.z)
+				+ self.xyzw.anti_dot(rhs.w),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Motor3:
+
+// Multivector.geometric(Motor3) -> Multivector
+impl Geometric<Motor3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.uw.0) - S(self.xy.0 * rhs.uz.0) - S(self.yz.0 * rhs.ux.0) - S(self.zx.0 * rhs.uy.0),
+		//     x   : X(self.x.0 * rhs.uw.0) - X(self.xyz.0 * rhs.ux.0) - X(self.y.0 * rhs.uz.0) + X(self.z.0 * rhs.uy.0),
+		//     y   : Y(self.x.0 * rhs.uz.0) - Y(self.xyz.0 * rhs.uy.0) + Y(self.y.0 * rhs.uw.0) - Y(self.z.0 * rhs.ux.0),
+		//     z   : -Z(self.x.0 * rhs.uy.0) - Z(self.xyz.0 * rhs.uz.0) + Z(self.y.0 * rhs.ux.0) + Z(self.z.0 * rhs.uw.0),
+		//     w   : W(self.w.0 * rhs.uw.0) - W(self.x.0 * rhs.rx.0) - W(self.xyw.0 * rhs.uz.0) - W(self.xyz.0 * rhs.rw.0) - W(self.y.0 * rhs.ry.0) - W(self.yzw.0 * rhs.ux.0) - W(self.z.0 * rhs.rz.0) - W(self.zxw.0 * rhs.uy.0),
+		//     wx  : WX(self.s.0 * rhs.rx.0) + WX(self.wx.0 * rhs.uw.0) - WX(self.wy.0 * rhs.uz.0) + WX(self.wz.0 * rhs.uy.0) + WX(self.xy.0 * rhs.ry.0) + WX(self.xyzw.0 * rhs.ux.0) + WX(self.yz.0 * rhs.rw.0) - WX(self.zx.0 * rhs.rz.0),
+		//     wy  : WY(self.s.0 * rhs.ry.0) + WY(self.wx.0 * rhs.uz.0) + WY(self.wy.0 * rhs.uw.0) - WY(self.wz.0 * rhs.ux.0) - WY(self.xy.0 * rhs.rx.0) + WY(self.xyzw.0 * rhs.uy.0) + WY(self.yz.0 * rhs.rz.0) + WY(self.zx.0 * rhs.rw.0),
+		//     wz  : WZ(self.s.0 * rhs.rz.0) - WZ(self.wx.0 * rhs.uy.0) + WZ(self.wy.0 * rhs.ux.0) + WZ(self.wz.0 * rhs.uw.0) + WZ(self.xy.0 * rhs.rw.0) + WZ(self.xyzw.0 * rhs.uz.0) - WZ(self.yz.0 * rhs.ry.0) + WZ(self.zx.0 * rhs.rx.0),
+		//     yz  : YZ(self.s.0 * rhs.ux.0) + YZ(self.xy.0 * rhs.uy.0) + YZ(self.yz.0 * rhs.uw.0) - YZ(self.zx.0 * rhs.uz.0),
+		//     zx  : ZX(self.s.0 * rhs.uy.0) - ZX(self.xy.0 * rhs.ux.0) + ZX(self.yz.0 * rhs.uz.0) + ZX(self.zx.0 * rhs.uw.0),
+		//     xy  : XY(self.s.0 * rhs.uz.0) + XY(self.xy.0 * rhs.uw.0) - XY(self.yz.0 * rhs.uy.0) + XY(self.zx.0 * rhs.ux.0),
+		//     yzw : YZW(self.w.0 * rhs.ux.0) + YZW(self.x.0 * rhs.rw.0) + YZW(self.xyw.0 * rhs.uy.0) - YZW(self.xyz.0 * rhs.rx.0) - YZW(self.y.0 * rhs.rz.0) + YZW(self.yzw.0 * rhs.uw.0) + YZW(self.z.0 * rhs.ry.0) - YZW(self.zxw.0 * rhs.uz.0),
+		//     zxw : ZXW(self.w.0 * rhs.uy.0) + ZXW(self.x.0 * rhs.rz.0) - ZXW(self.xyw.0 * rhs.ux.0) - ZXW(self.xyz.0 * rhs.ry.0) + ZXW(self.y.0 * rhs.rw.0) + ZXW(self.yzw.0 * rhs.uz.0) - ZXW(self.z.0 * rhs.rx.0) + ZXW(self.zxw.0 * rhs.uw.0),
+		//     xyw : XYW(self.w.0 * rhs.uz.0) - XYW(self.x.0 * rhs.ry.0) + XYW(self.xyw.0 * rhs.uw.0) - XYW(self.xyz.0 * rhs.rz.0) + XYW(self.y.0 * rhs.rx.0) - XYW(self.yzw.0 * rhs.uy.0) + XYW(self.z.0 * rhs.rw.0) + XYW(self.zxw.0 * rhs.ux.0),
+		//     xyz : XYZ(self.x.0 * rhs.ux.0) + XYZ(self.xyz.0 * rhs.uw.0) + XYZ(self.y.0 * rhs.uy.0) + XYZ(self.z.0 * rhs.uz.0),
+		//     xyzw: XYZW(self.s.0 * rhs.rw.0) - XYZW(self.wx.0 * rhs.ux.0) - XYZW(self.wy.0 * rhs.uy.0) - XYZW(self.wz.0 * rhs.uz.0) - XYZW(self.xy.0 * rhs.rz.0) + XYZW(self.xyzw.0 * rhs.uw.0) - XYZW(self.yz.0 * rhs.rx.0) - XYZW(self.zx.0 * rhs.ry.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.uw)
+				+ self.xy.geometric(rhs.uz)
+				+ self.yz.geometric(rhs.ux)
+				+ self.zx.geometric(rhs.uy),
+			x: self.x.geometric(rhs.uw)
+				+ self.xyz.geometric(rhs.ux)
+				+ self.y.geometric(rhs.uz)
+				+ self.z.geometric(rhs.uy),
+			y: self.x.geometric(rhs.uz)
+				+ self.xyz.geometric(rhs.uy)
+				+ self.y.geometric(rhs.uw)
+				+ self.z.geometric(rhs.ux),
+			z: self.x.geometric(rhs.uy)
+				+ self.xyz.geometric(rhs.uz)
+				+ self.y.geometric(rhs.ux)
+				+ self.z.geometric(rhs.uw),
+			w: self.w.geometric(rhs.uw)
+				+ self.x.geometric(rhs.rx)
+				+ self.xyw.geometric(rhs.uz)
+				+ self.xyz.geometric(rhs.rw)
+				+ self.y.geometric(rhs.ry)
+				+ self.yzw.geometric(rhs.ux)
+				+ self.z.geometric(rhs.rz)
+				+ self.zxw.geometric(rhs.uy),
+			wx: self.s.geometric(rhs.rx)
+				+ self.wx.geometric(rhs.uw)
+				+ self.wy.geometric(rhs.uz)
+				+ self.wz.geometric(rhs.uy)
+				+ self.xy.geometric(rhs.ry)
+				+ self.xyzw.geometric(rhs.ux)
+				+ self.yz.geometric(rhs.rw)
+				+ self.zx.geometric(rhs.rz),
+			wy: self.s.geometric(rhs.ry)
+				+ self.wx.geometric(rhs.uz)
+				+ self.wy.geometric(rhs.uw)
+				+ self.wz.geometric(rhs.ux)
+				+ self.xy.geometric(rhs.rx)
+				+ self.xyzw.geometric(rhs.uy)
+				+ self.yz.geometric(rhs.rz)
+				+ self.zx.geometric(rhs.rw),
+			wz: self.s.geometric(rhs.rz)
+				+ self.wx.geometric(rhs.uy)
+				+ self.wy.geometric(rhs.ux)
+				+ self.wz.geometric(rhs.uw)
+				+ self.xy.geometric(rhs.rw)
+				+ self.xyzw.geometric(rhs.uz)
+				+ self.yz.geometric(rhs.ry)
+				+ self.zx.geometric(rhs.rx),
+			yz: self.s.geometric(rhs.ux)
+				+ self.xy.geometric(rhs.uy)
+				+ self.yz.geometric(rhs.uw)
+				+ self.zx.geometric(rhs.uz),
+			zx: self.s.geometric(rhs.uy)
+				+ self.xy.geometric(rhs.ux)
+				+ self.yz.geometric(rhs.uz)
+				+ self.zx.geometric(rhs.uw),
+			xy: self.s.geometric(rhs.uz)
+				+ self.xy.geometric(rhs.uw)
+				+ self.yz.geometric(rhs.uy)
+				+ self.zx.geometric(rhs.ux),
+			yzw: self.w.geometric(rhs.ux)
+				+ self.x.geometric(rhs.rw)
+				+ self.xyw.geometric(rhs.uy)
+				+ self.xyz.geometric(rhs.rx)
+				+ self.y.geometric(rhs.rz)
+				+ self.yzw.geometric(rhs.uw)
+				+ self.z.geometric(rhs.ry)
+				+ self.zxw.geometric(rhs.uz),
+			zxw: self.w.geometric(rhs.uy)
+				+ self.x.geometric(rhs.rz)
+				+ self.xyw.geometric(rhs.ux)
+				+ self.xyz.geometric(rhs.ry)
+				+ self.y.geometric(rhs.rw)
+				+ self.yzw.geometric(rhs.uz)
+				+ self.z.geometric(rhs.rx)
+				+ self.zxw.geometric(rhs.uw),
+			xyw: self.w.geometric(rhs.uz)
+				+ self.x.geometric(rhs.ry)
+				+ self.xyw.geometric(rhs.uw)
+				+ self.xyz.geometric(rhs.rz)
+				+ self.y.geometric(rhs.rx)
+				+ self.yzw.geometric(rhs.uy)
+				+ self.z.geometric(rhs.rw)
+				+ self.zxw.geometric(rhs.ux),
+			xyz: self.x.geometric(rhs.ux)
+				+ self.xyz.geometric(rhs.uw)
+				+ self.y.geometric(rhs.uy)
+				+ self.z.geometric(rhs.uz),
+			xyzw: self.s.geometric(rhs.rw)
+				+ self.wx.geometric(rhs.ux)
+				+ self.wy.geometric(rhs.uy)
+				+ self.wz.geometric(rhs.uz)
+				+ self.xy.geometric(rhs.rz)
+				+ self.xyzw.geometric(rhs.uw)
+				+ self.yz.geometric(rhs.rx)
+				+ self.zx.geometric(rhs.ry),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Motor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Motor3) -> Multivector
+impl AntiGeometric<Motor3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.rw.0) - S(self.wx.0 * rhs.ux.0) - S(self.wy.0 * rhs.uy.0) - S(self.wz.0 * rhs.uz.0) - S(self.xy.0 * rhs.rz.0) + S(self.xyzw.0 * rhs.uw.0) - S(self.yz.0 * rhs.rx.0) - S(self.zx.0 * rhs.ry.0),
+		//     x   : -X(self.w.0 * rhs.ux.0) + X(self.x.0 * rhs.rw.0) + X(self.xyw.0 * rhs.uy.0) - X(self.xyz.0 * rhs.rx.0) + X(self.y.0 * rhs.rz.0) - X(self.yzw.0 * rhs.uw.0) - X(self.z.0 * rhs.ry.0) - X(self.zxw.0 * rhs.uz.0),
+		//     y   : -Y(self.w.0 * rhs.uy.0) - Y(self.x.0 * rhs.rz.0) - Y(self.xyw.0 * rhs.ux.0) - Y(self.xyz.0 * rhs.ry.0) + Y(self.y.0 * rhs.rw.0) + Y(self.yzw.0 * rhs.uz.0) + Y(self.z.0 * rhs.rx.0) - Y(self.zxw.0 * rhs.uw.0),
+		//     z   : -Z(self.w.0 * rhs.uz.0) + Z(self.x.0 * rhs.ry.0) - Z(self.xyw.0 * rhs.uw.0) - Z(self.xyz.0 * rhs.rz.0) - Z(self.y.0 * rhs.rx.0) - Z(self.yzw.0 * rhs.uy.0) + Z(self.z.0 * rhs.rw.0) + Z(self.zxw.0 * rhs.ux.0),
+		//     w   : W(self.w.0 * rhs.rw.0) - W(self.xyw.0 * rhs.rz.0) - W(self.yzw.0 * rhs.rx.0) - W(self.zxw.0 * rhs.ry.0),
+		//     wx  : WX(self.wx.0 * rhs.rw.0) + WX(self.wy.0 * rhs.rz.0) - WX(self.wz.0 * rhs.ry.0) + WX(self.xyzw.0 * rhs.rx.0),
+		//     wy  : -WY(self.wx.0 * rhs.rz.0) + WY(self.wy.0 * rhs.rw.0) + WY(self.wz.0 * rhs.rx.0) + WY(self.xyzw.0 * rhs.ry.0),
+		//     wz  : WZ(self.wx.0 * rhs.ry.0) - WZ(self.wy.0 * rhs.rx.0) + WZ(self.wz.0 * rhs.rw.0) + WZ(self.xyzw.0 * rhs.rz.0),
+		//     yz  : YZ(self.s.0 * rhs.rx.0) + YZ(self.wx.0 * rhs.uw.0) + YZ(self.wy.0 * rhs.uz.0) - YZ(self.wz.0 * rhs.uy.0) - YZ(self.xy.0 * rhs.ry.0) + YZ(self.xyzw.0 * rhs.ux.0) + YZ(self.yz.0 * rhs.rw.0) + YZ(self.zx.0 * rhs.rz.0),
+		//     zx  : ZX(self.s.0 * rhs.ry.0) - ZX(self.wx.0 * rhs.uz.0) + ZX(self.wy.0 * rhs.uw.0) + ZX(self.wz.0 * rhs.ux.0) + ZX(self.xy.0 * rhs.rx.0) + ZX(self.xyzw.0 * rhs.uy.0) - ZX(self.yz.0 * rhs.rz.0) + ZX(self.zx.0 * rhs.rw.0),
+		//     xy  : XY(self.s.0 * rhs.rz.0) + XY(self.wx.0 * rhs.uy.0) - XY(self.wy.0 * rhs.ux.0) + XY(self.wz.0 * rhs.uw.0) + XY(self.xy.0 * rhs.rw.0) + XY(self.xyzw.0 * rhs.uz.0) + XY(self.yz.0 * rhs.ry.0) - XY(self.zx.0 * rhs.rx.0),
+		//     yzw : YZW(self.w.0 * rhs.rx.0) - YZW(self.xyw.0 * rhs.ry.0) + YZW(self.yzw.0 * rhs.rw.0) + YZW(self.zxw.0 * rhs.rz.0),
+		//     zxw : ZXW(self.w.0 * rhs.ry.0) + ZXW(self.xyw.0 * rhs.rx.0) - ZXW(self.yzw.0 * rhs.rz.0) + ZXW(self.zxw.0 * rhs.rw.0),
+		//     xyw : XYW(self.w.0 * rhs.rz.0) + XYW(self.xyw.0 * rhs.rw.0) + XYW(self.yzw.0 * rhs.ry.0) - XYW(self.zxw.0 * rhs.rx.0),
+		//     xyz : XYZ(self.w.0 * rhs.uw.0) + XYZ(self.x.0 * rhs.rx.0) - XYZ(self.xyw.0 * rhs.uz.0) + XYZ(self.xyz.0 * rhs.rw.0) + XYZ(self.y.0 * rhs.ry.0) - XYZ(self.yzw.0 * rhs.ux.0) + XYZ(self.z.0 * rhs.rz.0) - XYZ(self.zxw.0 * rhs.uy.0),
+		//     xyzw: -XYZW(self.wx.0 * rhs.rx.0) - XYZW(self.wy.0 * rhs.ry.0) - XYZW(self.wz.0 * rhs.rz.0) + XYZW(self.xyzw.0 * rhs.rw.0),
+		// }
+		Multivector {
+			s: self.s.anti_geometric(rhs.rw)
+				+ self.wx.anti_geometric(rhs.ux)
+				+ self.wy.anti_geometric(rhs.uy)
+				+ self.wz.anti_geometric(rhs.uz)
+				+ self.xy.anti_geometric(rhs.rz)
+				+ self.xyzw.anti_geometric(rhs.uw)
+				+ self.yz.anti_geometric(rhs.rx)
+				+ self.zx.anti_geometric(rhs.ry),
+			x: self.w.anti_geometric(rhs.ux)
+				+ self.x.anti_geometric(rhs.rw)
+				+ self.xyw.anti_geometric(rhs.uy)
+				+ self.xyz.anti_geometric(rhs.rx)
+				+ self.y.anti_geometric(rhs.rz)
+				+ self.yzw.anti_geometric(rhs.uw)
+				+ self.z.anti_geometric(rhs.ry)
+				+ self.zxw.anti_geometric(rhs.uz),
+			y: self.w.anti_geometric(rhs.uy)
+				+ self.x.anti_geometric(rhs.rz)
+				+ self.xyw.anti_geometric(rhs.ux)
+				+ self.xyz.anti_geometric(rhs.ry)
+				+ self.y.anti_geometric(rhs.rw)
+				+ self.yzw.anti_geometric(rhs.uz)
+				+ self.z.anti_geometric(rhs.rx)
+				+ self.zxw.anti_geometric(rhs.uw),
+			z: self.w.anti_geometric(rhs.uz)
+				+ self.x.anti_geometric(rhs.ry)
+				+ self.xyw.anti_geometric(rhs.uw)
+				+ self.xyz.anti_geometric(rhs.rz)
+				+ self.y.anti_geometric(rhs.rx)
+				+ self.yzw.anti_geometric(rhs.uy)
+				+ self.z.anti_geometric(rhs.rw)
+				+ self.zxw.anti_geometric(rhs.ux),
+			w: self.w.anti_geometric(rhs.rw)
+				+ self.xyw.anti_geometric(rhs.rz)
+				+ self.yzw.anti_geometric(rhs.rx)
+				+ self.zxw.anti_geometric(rhs.ry),
+			wx: self.wx.anti_geometric(rhs.rw)
+				+ self.wy.anti_geometric(rhs.rz)
+				+ self.wz.anti_geometric(rhs.ry)
+				+ self.xyzw.anti_geometric(rhs.rx),
+			wy: self.wx.anti_geometric(rhs.rz)
+				+ self.wy.anti_geometric(rhs.rw)
+				+ self.wz.anti_geometric(rhs.rx)
+				+ self.xyzw.anti_geometric(rhs.ry),
+			wz: self.wx.anti_geometric(rhs.ry)
+				+ self.wy.anti_geometric(rhs.rx)
+				+ self.wz.anti_geometric(rhs.rw)
+				+ self.xyzw.anti_geometric(rhs.rz),
+			yz: self.s.anti_geometric(rhs.rx)
+				+ self.wx.anti_geometric(rhs.uw)
+				+ self.wy.anti_geometric(rhs.uz)
+				+ self.wz.anti_geometric(rhs.uy)
+				+ self.xy.anti_geometric(rhs.ry)
+				+ self.xyzw.anti_geometric(rhs.ux)
+				+ self.yz.anti_geometric(rhs.rw)
+				+ self.zx.anti_geometric(rhs.rz),
+			zx: self.s.anti_geometric(rhs.ry)
+				+ self.wx.anti_geometric(rhs.uz)
+				+ self.wy.anti_geometric(rhs.uw)
+				+ self.wz.anti_geometric(rhs.ux)
+				+ self.xy.anti_geometric(rhs.rx)
+				+ self.xyzw.anti_geometric(rhs.uy)
+				+ self.yz.anti_geometric(rhs.rz)
+				+ self.zx.anti_geometric(rhs.rw),
+			xy: self.s.anti_geometric(rhs.rz)
+				+ self.wx.anti_geometric(rhs.uy)
+				+ self.wy.anti_geometric(rhs.ux)
+				+ self.wz.anti_geometric(rhs.uw)
+				+ self.xy.anti_geometric(rhs.rw)
+				+ self.xyzw.anti_geometric(rhs.uz)
+				+ self.yz.anti_geometric(rhs.ry)
+				+ self.zx.anti_geometric(rhs.rx),
+			yzw: self.w.anti_geometric(rhs.rx)
+				+ self.xyw.anti_geometric(rhs.ry)
+				+ self.yzw.anti_geometric(rhs.rw)
+				+ self.zxw.anti_geometric(rhs.rz),
+			zxw: self.w.anti_geometric(rhs.ry)
+				+ self.xyw.anti_geometric(rhs.rx)
+				+ self.yzw.anti_geometric(rhs.rz)
+				+ self.zxw.anti_geometric(rhs.rw),
+			xyw: self.w.anti_geometric(rhs.rz)
+				+ self.xyw.anti_geometric(rhs.rw)
+				+ self.yzw.anti_geometric(rhs.ry)
+				+ self.zxw.anti_geometric(rhs.rx),
+			xyz: self.w.anti_geometric(rhs.uw)
+				+ self.x.anti_geometric(rhs.rx)
+				+ self.xyw.anti_geometric(rhs.uz)
+				+ self.xyz.anti_geometric(rhs.rw)
+				+ self.y.anti_geometric(rhs.ry)
+				+ self.yzw.anti_geometric(rhs.ux)
+				+ self.z.anti_geometric(rhs.rz)
+				+ self.zxw.anti_geometric(rhs.uy),
+			xyzw: self.wx.anti_geometric(rhs.rx)
+				+ self.wy.anti_geometric(rhs.ry)
+				+ self.wz.anti_geometric(rhs.rz)
+				+ self.xyzw.anti_geometric(rhs.rw),
+		}
+	}
+}
+
+// Multivector.dot(Motor3) -> Multivector
+impl Dot<Motor3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.uw.0) - S(self.xy.0 * rhs.uz.0) - S(self.yz.0 * rhs.ux.0) - S(self.zx.0 * rhs.uy.0),
+		//     x   : X(self.x.0 * rhs.uw.0) - X(self.xyz.0 * rhs.ux.0) - X(self.y.0 * rhs.uz.0) + X(self.z.0 * rhs.uy.0),
+		//     y   : Y(self.x.0 * rhs.uz.0) - Y(self.xyz.0 * rhs.uy.0) + Y(self.y.0 * rhs.uw.0) - Y(self.z.0 * rhs.ux.0),
+		//     z   : -Z(self.x.0 * rhs.uy.0) - Z(self.xyz.0 * rhs.uz.0) + Z(self.y.0 * rhs.ux.0) + Z(self.z.0 * rhs.uw.0),
+		//     w   : W(self.w.0 * rhs.uw.0) - W(self.x.0 * rhs.rx.0) - W(self.xyw.0 * rhs.uz.0) - W(self.xyz.0 * rhs.rw.0) - W(self.y.0 * rhs.ry.0) - W(self.yzw.0 * rhs.ux.0) - W(self.z.0 * rhs.rz.0) - W(self.zxw.0 * rhs.uy.0),
+		//     wx  : WX(self.s.0 * rhs.rx.0) + WX(self.wx.0 * rhs.uw.0) + WX(self.xyzw.0 * rhs.ux.0) + WX(self.yz.0 * rhs.rw.0),
+		//     wy  : WY(self.s.0 * rhs.ry.0) + WY(self.wy.0 * rhs.uw.0) + WY(self.xyzw.0 * rhs.uy.0) + WY(self.zx.0 * rhs.rw.0),
+		//     wz  : WZ(self.s.0 * rhs.rz.0) + WZ(self.wz.0 * rhs.uw.0) + WZ(self.xy.0 * rhs.rw.0) + WZ(self.xyzw.0 * rhs.uz.0),
+		//     yz  : YZ(self.s.0 * rhs.ux.0) + YZ(self.yz.0 * rhs.uw.0),
+		//     zx  : ZX(self.s.0 * rhs.uy.0) + ZX(self.zx.0 * rhs.uw.0),
+		//     xy  : XY(self.s.0 * rhs.uz.0) + XY(self.xy.0 * rhs.uw.0),
+		//     yzw : YZW(self.x.0 * rhs.rw.0) + YZW(self.yzw.0 * rhs.uw.0),
+		//     zxw : ZXW(self.y.0 * rhs.rw.0) + ZXW(self.zxw.0 * rhs.uw.0),
+		//     xyw : XYW(self.xyw.0 * rhs.uw.0) + XYW(self.z.0 * rhs.rw.0),
+		//     xyz : XYZ(self.xyz.0 * rhs.uw.0),
+		//     xyzw: XYZW(self.s.0 * rhs.rw.0) + XYZW(self.xyzw.0 * rhs.uw.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.uw) + self.xy.dot(rhs.uz) + self.yz.dot(rhs.ux) + self.zx.dot(rhs.uy),
+			x: self.x.dot(rhs.uw) + self.xyz.dot(rhs.ux) + self.y.dot(rhs.uz) + self.z.dot(rhs.uy),
+			y: self.x.dot(rhs.uz) + self.xyz.dot(rhs.uy) + self.y.dot(rhs.uw) + self.z.dot(rhs.ux),
+			z: self.x.dot(rhs.uy) + self.xyz.dot(rhs.uz) + self.y.dot(rhs.ux) + self.z.dot(rhs.uw),
+			w: self.w.dot(rhs.uw)
+				+ self.x.dot(rhs.rx)
+				+ self.xyw.dot(rhs.uz)
+				+ self.xyz.dot(rhs.rw)
+				+ self.y.dot(rhs.ry)
+				+ self.yzw.dot(rhs.ux)
+				+ self.z.dot(rhs.rz)
+				+ self.zxw.dot(rhs.uy),
+			wx: self.s.dot(rhs.rx) + self.wx.dot(rhs.uw) + self.xyzw.dot(rhs.ux) + self.yz.dot(rhs.rw),
+			wy: self.s.dot(rhs.ry) + self.wy.dot(rhs.uw) + self.xyzw.dot(rhs.uy) + self.zx.dot(rhs.rw),
+			wz: self.s.dot(rhs.rz) + self.wz.dot(rhs.uw) + self.xy.dot(rhs.rw) + self.xyzw.dot(rhs.uz),
+			yz: self.s.dot(rhs.ux) + self.yz.dot(rhs.uw),
+			zx: self.s.dot(rhs.uy) + self.zx.dot(rhs.uw),
+			xy: self.s.dot(rhs.uz) + self.xy.dot(rhs.uw),
+			yzw: self.x.dot(rhs.rw) + self.yzw.dot(rhs.uw),
+			zxw: self.y.dot(rhs.rw) + self.zxw.dot(rhs.uw),
+			xyw: self.xyw.dot(rhs.uw) + self.z.dot(rhs.rw),
+			xyz: self.xyz.dot(rhs.uw),
+			xyzw: self.s.dot(rhs.rw) + self.xyzw.dot(rhs.uw),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Motor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Motor3) -> Multivector
+impl Wedge<Motor3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.uw.0),
+		//     x   : X(self.x.0 * rhs.uw.0),
+		//     y   : Y(self.y.0 * rhs.uw.0),
+		//     z   : Z(self.z.0 * rhs.uw.0),
+		//     w   : W(self.w.0 * rhs.uw.0),
+		//     wx  : WX(self.s.0 * rhs.rx.0) + WX(self.wx.0 * rhs.uw.0),
+		//     wy  : WY(self.s.0 * rhs.ry.0) + WY(self.wy.0 * rhs.uw.0),
+		//     wz  : WZ(self.s.0 * rhs.rz.0) + WZ(self.wz.0 * rhs.uw.0),
+		//     yz  : YZ(self.s.0 * rhs.ux.0) + YZ(self.yz.0 * rhs.uw.0),
+		//     zx  : ZX(self.s.0 * rhs.uy.0) + ZX(self.zx.0 * rhs.uw.0),
+		//     xy  : XY(self.s.0 * rhs.uz.0) + XY(self.xy.0 * rhs.uw.0),
+		//     yzw : YZW(self.w.0 * rhs.ux.0) - YZW(self.y.0 * rhs.rz.0) + YZW(self.yzw.0 * rhs.uw.0) + YZW(self.z.0 * rhs.ry.0),
+		//     zxw : ZXW(self.w.0 * rhs.uy.0) + ZXW(self.x.0 * rhs.rz.0) - ZXW(self.z.0 * rhs.rx.0) + ZXW(self.zxw.0 * rhs.uw.0),
+		//     xyw : XYW(self.w.0 * rhs.uz.0) - XYW(self.x.0 * rhs.ry.0) + XYW(self.xyw.0 * rhs.uw.0) + XYW(self.y.0 * rhs.rx.0),
+		//     xyz : XYZ(self.x.0 * rhs.ux.0) + XYZ(self.xyz.0 * rhs.uw.0) + XYZ(self.y.0 * rhs.uy.0) + XYZ(self.z.0 * rhs.uz.0),
+		//     xyzw: XYZW(self.s.0 * rhs.rw.0) - XYZW(self.wx.0 * rhs.ux.0) - XYZW(self.wy.0 * rhs.uy.0) - XYZW(self.wz.0 * rhs.uz.0) - XYZW(self.xy.0 * rhs.rz.0) + XYZW(self.xyzw.0 * rhs.uw.0) - XYZW(self.yz.0 * rhs.rx.0) - XYZW(self.zx.0 * rhs.ry.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.uw),
+			x: self.x.wedge(rhs.uw),
+			y: self.y.wedge(rhs.uw),
+			z: self.z.wedge(rhs.uw),
+			w: self.w.wedge(rhs.uw),
+			wx: self.s.wedge(rhs.rx) + self.wx.wedge(rhs.uw),
+			wy: self.s.wedge(rhs.ry) + self.wy.wedge(rhs.uw),
+			wz: self.s.wedge(rhs.rz) + self.wz.wedge(rhs.uw),
+			yz: self.s.wedge(rhs.ux) + self.yz.wedge(rhs.uw),
+			zx: self.s.wedge(rhs.uy) + self.zx.wedge(rhs.uw),
+			xy: self.s.wedge(rhs.uz) + self.xy.wedge(rhs.uw),
+			yzw: self.w.wedge(rhs.ux) + self.y.wedge(rhs.rz) + self.yzw.wedge(rhs.uw) + self.z.wedge(rhs.ry),
+			zxw: self.w.wedge(rhs.uy) + self.x.wedge(rhs.rz) + self.z.wedge(rhs.rx) + self.zxw.wedge(rhs.uw),
+			xyw: self.w.wedge(rhs.uz) + self.x.wedge(rhs.ry) + self.xyw.wedge(rhs.uw) + self.y.wedge(rhs.rx),
+			xyz: self.x.wedge(rhs.ux) + self.xyz.wedge(rhs.uw) + self.y.wedge(rhs.uy) + self.z.wedge(rhs.uz),
+			xyzw: self.s.wedge(rhs.rw)
+				+ self.wx.wedge(rhs.ux)
+				+ self.wy.wedge(rhs.uy)
+				+ self.wz.wedge(rhs.uz)
+				+ self.xy.wedge(rhs.rz)
+				+ self.xyzw.wedge(rhs.uw)
+				+ self.yz.wedge(rhs.rx)
+				+ self.zx.wedge(rhs.ry),
+		}
+	}
+}
+
+impl std::ops::BitXor<Motor3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Motor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Motor3) -> Multivector
+impl AntiWedge<Motor3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.rw.0) - S(self.wx.0 * rhs.ux.0) - S(self.wy.0 * rhs.uy.0) - S(self.wz.0 * rhs.uz.0) - S(self.xy.0 * rhs.rz.0) + S(self.xyzw.0 * rhs.uw.0) - S(self.yz.0 * rhs.rx.0) - S(self.zx.0 * rhs.ry.0),
+		//     x   : X(self.x.0 * rhs.rw.0) + X(self.xyw.0 * rhs.uy.0) - X(self.xyz.0 * rhs.rx.0) - X(self.zxw.0 * rhs.uz.0),
+		//     y   : -Y(self.xyw.0 * rhs.ux.0) - Y(self.xyz.0 * rhs.ry.0) + Y(self.y.0 * rhs.rw.0) + Y(self.yzw.0 * rhs.uz.0),
+		//     z   : -Z(self.xyz.0 * rhs.rz.0) - Z(self.yzw.0 * rhs.uy.0) + Z(self.z.0 * rhs.rw.0) + Z(self.zxw.0 * rhs.ux.0),
+		//     w   : W(self.w.0 * rhs.rw.0) - W(self.xyw.0 * rhs.rz.0) - W(self.yzw.0 * rhs.rx.0) - W(self.zxw.0 * rhs.ry.0),
+		//     wx  : WX(self.wx.0 * rhs.rw.0) + WX(self.xyzw.0 * rhs.rx.0),
+		//     wy  : WY(self.wy.0 * rhs.rw.0) + WY(self.xyzw.0 * rhs.ry.0),
+		//     wz  : WZ(self.wz.0 * rhs.rw.0) + WZ(self.xyzw.0 * rhs.rz.0),
+		//     yz  : YZ(self.xyzw.0 * rhs.ux.0) + YZ(self.yz.0 * rhs.rw.0),
+		//     zx  : ZX(self.xyzw.0 * rhs.uy.0) + ZX(self.zx.0 * rhs.rw.0),
+		//     xy  : XY(self.xy.0 * rhs.rw.0) + XY(self.xyzw.0 * rhs.uz.0),
+		//     yzw : YZW(self.yzw.0 * rhs.rw.0),
+		//     zxw : ZXW(self.zxw.0 * rhs.rw.0),
+		//     xyw : XYW(self.xyw.0 * rhs.rw.0),
+		//     xyz : XYZ(self.xyz.0 * rhs.rw.0),
+		//     xyzw: XYZW(self.xyzw.0 * rhs.rw.0),
+		// }
+		Multivector {
+			s: self.s.anti_wedge(rhs.rw)
+				+ self.wx.anti_wedge(rhs.ux)
+				+ self.wy.anti_wedge(rhs.uy)
+				+ self.wz.anti_wedge(rhs.uz)
+				+ self.xy.anti_wedge(rhs.rz)
+				+ self.xyzw.anti_wedge(rhs.uw)
+				+ self.yz.anti_wedge(rhs.rx)
+				+ self.zx.anti_wedge(rhs.ry),
+			x: self.x.anti_wedge(rhs.rw)
+				+ self.xyw.anti_wedge(rhs.uy)
+				+ self.xyz.anti_wedge(rhs.rx)
+				+ self.zxw.anti_wedge(rhs.uz),
+			y: self.xyw.anti_wedge(rhs.ux)
+				+ self.xyz.anti_wedge(rhs.ry)
+				+ self.y.anti_wedge(rhs.rw)
+				+ self.yzw.anti_wedge(rhs.uz),
+			z: self.xyz.anti_wedge(rhs.rz)
+				+ self.yzw.anti_wedge(rhs.uy)
+				+ self.z.anti_wedge(rhs.rw)
+				+ self.zxw.anti_wedge(rhs.ux),
+			w: self.w.anti_wedge(rhs.rw)
+				+ self.xyw.anti_wedge(rhs.rz)
+				+ self.yzw.anti_wedge(rhs.rx)
+				+ self.zxw.anti_wedge(rhs.ry),
+			wx: self.wx.anti_wedge(rhs.rw) + self.xyzw.anti_wedge(rhs.rx),
+			wy: self.wy.anti_wedge(rhs.rw) + self.xyzw.anti_wedge(rhs.ry),
+			wz: self.wz.anti_wedge(rhs.rw) + self.xyzw.anti_wedge(rhs.rz),
+			yz: self.xyzw.anti_wedge(rhs.ux) + self.yz.anti_wedge(rhs.rw),
+			zx: self.xyzw.anti_wedge(rhs.uy) + self.zx.anti_wedge(rhs.rw),
+			xy: self.xy.anti_wedge(rhs.rw) + self.xyzw.anti_wedge(rhs.uz),
+			yzw: self.yzw.anti_wedge(rhs.rw),
+			zxw: self.zxw.anti_wedge(rhs.rw),
+			xyw: self.xyw.anti_wedge(rhs.rw),
+			xyz: self.xyz.anti_wedge(rhs.rw),
+			xyzw: self.xyzw.anti_wedge(rhs.rw),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Motor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Motor3) -> Multivector
+impl LeftContraction<Motor3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.uw.0) - S(self.xy.0 * rhs.uz.0) - S(self.yz.0 * rhs.ux.0) - S(self.zx.0 * rhs.uy.0),
+		//     x   : -X(self.y.0 * rhs.uz.0) + X(self.z.0 * rhs.uy.0),
+		//     y   : Y(self.x.0 * rhs.uz.0) - Y(self.z.0 * rhs.ux.0),
+		//     z   : -Z(self.x.0 * rhs.uy.0) + Z(self.y.0 * rhs.ux.0),
+		//     w   : -W(self.x.0 * rhs.rx.0) - W(self.xyz.0 * rhs.rw.0) - W(self.y.0 * rhs.ry.0) - W(self.z.0 * rhs.rz.0),
+		//     wx  : WX(self.s.0 * rhs.rx.0) + WX(self.yz.0 * rhs.rw.0),
+		//     wy  : WY(self.s.0 * rhs.ry.0) + WY(self.zx.0 * rhs.rw.0),
+		//     wz  : WZ(self.s.0 * rhs.rz.0) + WZ(self.xy.0 * rhs.rw.0),
+		//     yz  : YZ(self.s.0 * rhs.ux.0),
+		//     zx  : ZX(self.s.0 * rhs.uy.0),
+		//     xy  : XY(self.s.0 * rhs.uz.0),
+		//     yzw : YZW(self.x.0 * rhs.rw.0),
+		//     zxw : ZXW(self.y.0 * rhs.rw.0),
+		//     xyw : XYW(self.z.0 * rhs.rw.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.s.0 * rhs.rw.0),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.uw)
+				+ self.xy.left_contraction(rhs.uz)
+				+ self.yz.left_contraction(rhs.ux)
+				+ self.zx.left_contraction(rhs.uy),
+			x: self.y.left_contraction(rhs.uz) + self.z.left_contraction(rhs.uy),
+			y: self.x.left_contraction(rhs.uz) + self.z.left_contraction(rhs.ux),
+			z: self.x.left_contraction(rhs.uy) + self.y.left_contraction(rhs.ux),
+			w: self.x.left_contraction(rhs.rx)
+				+ self.xyz.left_contraction(rhs.rw)
+				+ self.y.left_contraction(rhs.ry)
+				+ self.z.left_contraction(rhs.rz),
+			wx: self.s.left_contraction(rhs.rx) + self.yz.left_contraction(rhs.rw),
+			wy: self.s.left_contraction(rhs.ry) + self.zx.left_contraction(rhs.rw),
+			wz: self.s.left_contraction(rhs.rz) + self.xy.left_contraction(rhs.rw),
+			yz: self.s.left_contraction(rhs.ux),
+			zx: self.s.left_contraction(rhs.uy),
+			xy: self.s.left_contraction(rhs.uz),
+			yzw: self.x.left_contraction(rhs.rw),
+			zxw: self.y.left_contraction(rhs.rw),
+			xyw: self.z.left_contraction(rhs.rw),
+			xyz: Default::default(),
+			xyzw: self.s.left_contraction(rhs.rw),
+		}
+	}
+}
+
+// Multivector.right_contraction(Motor3) -> Multivector
+impl RightContraction<Motor3> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.uw.0) - S(self.xy.0 * rhs.uz.0) - S(self.yz.0 * rhs.ux.0) - S(self.zx.0 * rhs.uy.0),
+		//     x   : X(self.x.0 * rhs.uw.0) - X(self.xyz.0 * rhs.ux.0),
+		//     y   : -Y(self.xyz.0 * rhs.uy.0) + Y(self.y.0 * rhs.uw.0),
+		//     z   : -Z(self.xyz.0 * rhs.uz.0) + Z(self.z.0 * rhs.uw.0),
+		//     w   : W(self.w.0 * rhs.uw.0) - W(self.xyw.0 * rhs.uz.0) - W(self.yzw.0 * rhs.ux.0) - W(self.zxw.0 * rhs.uy.0),
+		//     wx  : WX(self.wx.0 * rhs.uw.0) + WX(self.xyzw.0 * rhs.ux.0),
+		//     wy  : WY(self.wy.0 * rhs.uw.0) + WY(self.xyzw.0 * rhs.uy.0),
+		//     wz  : WZ(self.wz.0 * rhs.uw.0) + WZ(self.xyzw.0 * rhs.uz.0),
+		//     yz  : YZ(self.yz.0 * rhs.uw.0),
+		//     zx  : ZX(self.zx.0 * rhs.uw.0),
+		//     xy  : XY(self.xy.0 * rhs.uw.0),
+		//     yzw : YZW(self.yzw.0 * rhs.uw.0),
+		//     zxw : ZXW(self.zxw.0 * rhs.uw.0),
+		//     xyw : XYW(self.xyw.0 * rhs.uw.0),
+		//     xyz : XYZ(self.xyz.0 * rhs.uw.0),
+		//     xyzw: XYZW(self.xyzw.0 * rhs.uw.0),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.uw)
+				+ self.xy.right_contraction(rhs.uz)
+				+ self.yz.right_contraction(rhs.ux)
+				+ self.zx.right_contraction(rhs.uy),
+			x: self.x.right_contraction(rhs.uw) + self.xyz.right_contraction(rhs.ux),
+			y: self.xyz.right_contraction(rhs.uy) + self.y.right_contraction(rhs.uw),
+			z: self.xyz.right_contraction(rhs.uz) + self.z.right_contraction(rhs.uw),
+			w: self.w.right_contraction(rhs.uw)
+				+ self.xyw.right_contraction(rhs.uz)
+				+ self.yzw.right_contraction(rhs.ux)
+				+ self.zxw.right_contraction(rhs.uy),
+			wx: self.wx.right_contraction(rhs.uw) + self.xyzw.right_contraction(rhs.ux),
+			wy: self.wy.right_contraction(rhs.uw) + self.xyzw.right_contraction(rhs.uy),
+			wz: self.wz.right_contraction(rhs.uw) + self.xyzw.right_contraction(rhs.uz),
+			yz: self.yz.right_contraction(rhs.uw),
+			zx: self.zx.right_contraction(rhs.uw),
+			xy: self.xy.right_contraction(rhs.uw),
+			yzw: self.yzw.right_contraction(rhs.uw),
+			zxw: self.zxw.right_contraction(rhs.uw),
+			xyw: self.xyw.right_contraction(rhs.uw),
+			xyz: self.xyz.right_contraction(rhs.uw),
+			xyzw: self.xyzw.right_contraction(rhs.uw),
+		}
+	}
+}
+
+// Multivector.scalar_product(Motor3) -> S
+impl ScalarProduct<Motor3> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Motor3) -> Self::Output {
+		// S(self.s.0 * rhs.uw.0) - S(self.xy.0 * rhs.uz.0) - S(self.yz.0 * rhs.ux.0) - S(self.zx.0 * rhs.uy.0)
+		self.s.scalar_product(rhs.uw)
+			+ self.xy.scalar_product(rhs.uz)
+			+ self.yz.scalar_product(rhs.ux)
+			+ self.zx.scalar_product(rhs.uy)
+	}
+}
+
+// Multivector.commutator(Motor3) -> Multivector
+impl Commutator<Motor3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.uz.0) + X(self.z.0 * rhs.uy.0),
+		//     y   : Y(self.x.0 * rhs.uz.0) - Y(self.z.0 * rhs.ux.0),
+		//     z   : -Z(self.x.0 * rhs.uy.0) + Z(self.y.0 * rhs.ux.0),
+		//     w   : -W(self.x.0 * rhs.rx.0) - W(self.xyz.0 * rhs.rw.0) - W(self.y.0 * rhs.ry.0) - W(self.z.0 * rhs.rz.0),
+		//     wx  : -WX(self.wy.0 * rhs.uz.0) + WX(self.wz.0 * rhs.uy.0) + WX(self.xy.0 * rhs.ry.0) - WX(self.zx.0 * rhs.rz.0),
+		//     wy  : WY(self.wx.0 * rhs.uz.0) - WY(self.wz.0 * rhs.ux.0) - WY(self.xy.0 * rhs.rx.0) + WY(self.yz.0 * rhs.rz.0),
+		//     wz  : -WZ(self.wx.0 * rhs.uy.0) + WZ(self.wy.0 * rhs.ux.0) - WZ(self.yz.0 * rhs.ry.0) + WZ(self.zx.0 * rhs.rx.0),
+		//     yz  : YZ(self.xy.0 * rhs.uy.0) - YZ(self.zx.0 * rhs.uz.0),
+		//     zx  : -ZX(self.xy.0 * rhs.ux.0) + ZX(self.yz.0 * rhs.uz.0),
+		//     xy  : -XY(self.yz.0 * rhs.uy.0) + XY(self.zx.0 * rhs.ux.0),
+		//     yzw : YZW(self.x.0 * rhs.rw.0) + YZW(self.xyw.0 * rhs.uy.0) - YZW(self.xyz.0 * rhs.rx.0) - YZW(self.zxw.0 * rhs.uz.0),
+		//     zxw : -ZXW(self.xyw.0 * rhs.ux.0) - ZXW(self.xyz.0 * rhs.ry.0) + ZXW(self.y.0 * rhs.rw.0) + ZXW(self.yzw.0 * rhs.uz.0),
+		//     xyw : -XYW(self.xyz.0 * rhs.rz.0) - XYW(self.yzw.0 * rhs.uy.0) + XYW(self.z.0 * rhs.rw.0) + XYW(self.zxw.0 * rhs.ux.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.uz) + self.z.commutator(rhs.uy),
+			y: self.x.commutator(rhs.uz) + self.z.commutator(rhs.ux),
+			z: self.x.commutator(rhs.uy) + self.y.commutator(rhs.ux),
+			w: self.x.commutator(rhs.rx)
+				+ self.xyz.commutator(rhs.rw)
+				+ self.y.commutator(rhs.ry)
+				+ self.z.commutator(rhs.rz),
+			wx: self.wy.commutator(rhs.uz)
+				+ self.wz.commutator(rhs.uy)
+				+ self.xy.commutator(rhs.ry)
+				+ self.zx.commutator(rhs.rz),
+			wy: self.wx.commutator(rhs.uz)
+				+ self.wz.commutator(rhs.ux)
+				+ self.xy.commutator(rhs.rx)
+				+ self.yz.commutator(rhs.rz),
+			wz: self.wx.commutator(rhs.uy)
+				+ self.wy.commutator(rhs.ux)
+				+ self.yz.commutator(rhs.ry)
+				+ self.zx.commutator(rhs.rx),
+			yz: self.xy.commutator(rhs.uy) + self.zx.commutator(rhs.uz),
+			zx: self.xy.commutator(rhs.ux) + self.yz.commutator(rhs.uz),
+			xy: self.yz.commutator(rhs.uy) + self.zx.commutator(rhs.ux),
+			yzw: self.x.commutator(rhs.rw)
+				+ self.xyw.commutator(rhs.uy)
+				+ self.xyz.commutator(rhs.rx)
+				+ self.zxw.commutator(rhs.uz),
+			zxw: self.xyw.commutator(rhs.ux)
+				+ self.xyz.commutator(rhs.ry)
+				+ self.y.commutator(rhs.rw)
+				+ self.yzw.commutator(rhs.uz),
+			xyw: self.xyz.commutator(rhs.rz)
+				+ self.yzw.commutator(rhs.uy)
+				+ self.z.commutator(rhs.rw)
+				+ self.zxw.commutator(rhs.ux),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Motor3) -> Multivector
+impl AntiDot<Motor3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.rw.0) + S(self.xyzw.0 * rhs.uw.0),
+		//     x   : X(self.x.0 * rhs.rw.0) - X(self.yzw.0 * rhs.uw.0),
+		//     y   : Y(self.y.0 * rhs.rw.0) - Y(self.zxw.0 * rhs.uw.0),
+		//     z   : -Z(self.xyw.0 * rhs.uw.0) + Z(self.z.0 * rhs.rw.0),
+		//     w   : W(self.w.0 * rhs.rw.0),
+		//     wx  : WX(self.wx.0 * rhs.rw.0) + WX(self.xyzw.0 * rhs.rx.0),
+		//     wy  : WY(self.wy.0 * rhs.rw.0) + WY(self.xyzw.0 * rhs.ry.0),
+		//     wz  : WZ(self.wz.0 * rhs.rw.0) + WZ(self.xyzw.0 * rhs.rz.0),
+		//     yz  : YZ(self.s.0 * rhs.rx.0) + YZ(self.wx.0 * rhs.uw.0) + YZ(self.xyzw.0 * rhs.ux.0) + YZ(self.yz.0 * rhs.rw.0),
+		//     zx  : ZX(self.s.0 * rhs.ry.0) + ZX(self.wy.0 * rhs.uw.0) + ZX(self.xyzw.0 * rhs.uy.0) + ZX(self.zx.0 * rhs.rw.0),
+		//     xy  : XY(self.s.0 * rhs.rz.0) + XY(self.wz.0 * rhs.uw.0) + XY(self.xy.0 * rhs.rw.0) + XY(self.xyzw.0 * rhs.uz.0),
+		//     yzw : YZW(self.w.0 * rhs.rx.0) - YZW(self.xyw.0 * rhs.ry.0) + YZW(self.yzw.0 * rhs.rw.0) + YZW(self.zxw.0 * rhs.rz.0),
+		//     zxw : ZXW(self.w.0 * rhs.ry.0) + ZXW(self.xyw.0 * rhs.rx.0) - ZXW(self.yzw.0 * rhs.rz.0) + ZXW(self.zxw.0 * rhs.rw.0),
+		//     xyw : XYW(self.w.0 * rhs.rz.0) + XYW(self.xyw.0 * rhs.rw.0) + XYW(self.yzw.0 * rhs.ry.0) - XYW(self.zxw.0 * rhs.rx.0),
+		//     xyz : XYZ(self.w.0 * rhs.uw.0) + XYZ(self.x.0 * rhs.rx.0) - XYZ(self.xyw.0 * rhs.uz.0) + XYZ(self.xyz.0 * rhs.rw.0) + XYZ(self.y.0 * rhs.ry.0) - XYZ(self.yzw.0 * rhs.ux.0) + XYZ(self.z.0 * rhs.rz.0) - XYZ(self.zxw.0 * rhs.uy.0),
+		//     xyzw: -XYZW(self.wx.0 * rhs.rx.0) - XYZW(self.wy.0 * rhs.ry.0) - XYZW(self.wz.0 * rhs.rz.0) + XYZW(self.xyzw.0 * rhs.rw.0),
+		// }
+		Multivector {
+			s: self.s.anti_dot(rhs.rw) + self.xyzw.anti_dot(rhs.uw),
+			x: self.x.anti_dot(rhs.rw) + self.yzw.anti_dot(rhs.uw),
+			y: self.y.anti_dot(rhs.rw) + self.zxw.anti_dot(rhs.uw),
+			z: self.xyw.anti_dot(rhs.uw) + self.z.anti_dot(rhs.rw),
+			w: self.w.anti_dot(rhs.rw),
+			wx: self.wx.anti_dot(rhs.rw) + self.xyzw.anti_dot(rhs.rx),
+			wy: self.wy.anti_dot(rhs.rw) + self.xyzw.anti_dot(rhs.ry),
+			wz: self.wz.anti_dot(rhs.rw) + self.xyzw.anti_dot(rhs.rz),
+			yz: self.s.anti_dot(rhs.rx)
+				+ self.wx.anti_dot(rhs.uw)
+				+ self.xyzw.anti_dot(rhs.ux)
+				+ self.yz.anti_dot(rhs.rw),
+			zx: self.s.anti_dot(rhs.ry)
+				+ self.wy.anti_dot(rhs.uw)
+				+ self.xyzw.anti_dot(rhs.uy)
+				+ self.zx.anti_dot(rhs.rw),
+			xy: self.s.anti_dot(rhs.rz)
+				+ self.wz.anti_dot(rhs.uw)
+				+ self.xy.anti_dot(rhs.rw)
+				+ self.xyzw.anti_dot(rhs.uz),
+			yzw: self.w.anti_dot(rhs.rx)
+				+ self.xyw.anti_dot(rhs.ry)
+				+ self.yzw.anti_dot(rhs.rw)
+				+ self.zxw.anti_dot(rhs.rz),
+			zxw: self.w.anti_dot(rhs.ry)
+				+ self.xyw.anti_dot(rhs.rx)
+				+ self.yzw.anti_dot(rhs.rz)
+				+ self.zxw.anti_dot(rhs.rw),
+			xyw: self.w.anti_dot(rhs.rz)
+				+ self.xyw.anti_dot(rhs.rw)
+				+ self.yzw.anti_dot(rhs.ry)
+				+ self.zxw.anti_dot(rhs.rx),
+			xyz: self.w.anti_dot(rhs.uw)
+				+ self.x.anti_dot(rhs.rx)
+				+ self.xyw.anti_dot(rhs.uz)
+				+ self.xyz.anti_dot(rhs.rw)
+				+ self.y.anti_dot(rhs.ry)
+				+ self.yzw.anti_dot(rhs.ux)
+				+ self.z.anti_dot(rhs.rz)
+				+ self.zxw.anti_dot(rhs.uy),
+			xyzw: self.wx.anti_dot(rhs.rx)
+				+ self.wy.anti_dot(rhs.ry)
+				+ self.wz.anti_dot(rhs.rz)
+				+ self.xyzw.anti_dot(rhs.rw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Multivector:
+
+// Multivector.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) - S(self.xyz.0 * rhs.xyz.0) + S(self.y.0 * rhs.y.0) - S(self.yz.0 * rhs.yz.0) + S(self.z.0 * rhs.z.0) - S(self.zx.0 * rhs.zx.0),
+		//     x   : X(self.s.0 * rhs.x.0) + X(self.x.0 * rhs.s.0) + X(self.xy.0 * rhs.y.0) - X(self.xyz.0 * rhs.yz.0) - X(self.y.0 * rhs.xy.0) - X(self.yz.0 * rhs.xyz.0) + X(self.z.0 * rhs.zx.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : Y(self.s.0 * rhs.y.0) + Y(self.x.0 * rhs.xy.0) - Y(self.xy.0 * rhs.x.0) - Y(self.xyz.0 * rhs.zx.0) + Y(self.y.0 * rhs.s.0) + Y(self.yz.0 * rhs.z.0) - Y(self.z.0 * rhs.yz.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z   : Z(self.s.0 * rhs.z.0) - Z(self.x.0 * rhs.zx.0) - Z(self.xy.0 * rhs.xyz.0) - Z(self.xyz.0 * rhs.xy.0) + Z(self.y.0 * rhs.yz.0) - Z(self.yz.0 * rhs.y.0) + Z(self.z.0 * rhs.s.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.s.0 * rhs.w.0) + W(self.w.0 * rhs.s.0) + W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0) - W(self.x.0 * rhs.wx.0) - W(self.xy.0 * rhs.xyw.0) - W(self.xyw.0 * rhs.xy.0) - W(self.xyz.0 * rhs.xyzw.0) + W(self.xyzw.0 * rhs.xyz.0) - W(self.y.0 * rhs.wy.0) - W(self.yz.0 * rhs.yzw.0) - W(self.yzw.0 * rhs.yz.0) - W(self.z.0 * rhs.wz.0) - W(self.zx.0 * rhs.zxw.0) - W(self.zxw.0 * rhs.zx.0),
+		//     wx  : WX(self.s.0 * rhs.wx.0) + WX(self.w.0 * rhs.x.0) + WX(self.wx.0 * rhs.s.0) - WX(self.wy.0 * rhs.xy.0) + WX(self.wz.0 * rhs.zx.0) - WX(self.x.0 * rhs.w.0) + WX(self.xy.0 * rhs.wy.0) + WX(self.xyw.0 * rhs.y.0) + WX(self.xyz.0 * rhs.yzw.0) + WX(self.xyzw.0 * rhs.yz.0) + WX(self.y.0 * rhs.xyw.0) + WX(self.yz.0 * rhs.xyzw.0) - WX(self.yzw.0 * rhs.xyz.0) - WX(self.z.0 * rhs.zxw.0) - WX(self.zx.0 * rhs.wz.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : WY(self.s.0 * rhs.wy.0) + WY(self.w.0 * rhs.y.0) + WY(self.wx.0 * rhs.xy.0) + WY(self.wy.0 * rhs.s.0) - WY(self.wz.0 * rhs.yz.0) - WY(self.x.0 * rhs.xyw.0) - WY(self.xy.0 * rhs.wx.0) - WY(self.xyw.0 * rhs.x.0) + WY(self.xyz.0 * rhs.zxw.0) + WY(self.xyzw.0 * rhs.zx.0) - WY(self.y.0 * rhs.w.0) + WY(self.yz.0 * rhs.wz.0) + WY(self.yzw.0 * rhs.z.0) + WY(self.z.0 * rhs.yzw.0) + WY(self.zx.0 * rhs.xyzw.0) - WY(self.zxw.0 * rhs.xyz.0),
+		//     wz  : WZ(self.s.0 * rhs.wz.0) + WZ(self.w.0 * rhs.z.0) - WZ(self.wx.0 * rhs.zx.0) + WZ(self.wy.0 * rhs.yz.0) + WZ(self.wz.0 * rhs.s.0) + WZ(self.x.0 * rhs.zxw.0) + WZ(self.xy.0 * rhs.xyzw.0) - WZ(self.xyw.0 * rhs.xyz.0) + WZ(self.xyz.0 * rhs.xyw.0) + WZ(self.xyzw.0 * rhs.xy.0) - WZ(self.y.0 * rhs.yzw.0) - WZ(self.yz.0 * rhs.wy.0) - WZ(self.yzw.0 * rhs.y.0) - WZ(self.z.0 * rhs.w.0) + WZ(self.zx.0 * rhs.wx.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.s.0 * rhs.yz.0) + YZ(self.x.0 * rhs.xyz.0) + YZ(self.xy.0 * rhs.zx.0) + YZ(self.xyz.0 * rhs.x.0) + YZ(self.y.0 * rhs.z.0) + YZ(self.yz.0 * rhs.s.0) - YZ(self.z.0 * rhs.y.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx  : ZX(self.s.0 * rhs.zx.0) - ZX(self.x.0 * rhs.z.0) - ZX(self.xy.0 * rhs.yz.0) + ZX(self.xyz.0 * rhs.y.0) + ZX(self.y.0 * rhs.xyz.0) + ZX(self.yz.0 * rhs.xy.0) + ZX(self.z.0 * rhs.x.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy  : XY(self.s.0 * rhs.xy.0) + XY(self.x.0 * rhs.y.0) + XY(self.xy.0 * rhs.s.0) + XY(self.xyz.0 * rhs.z.0) - XY(self.y.0 * rhs.x.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.z.0 * rhs.xyz.0) + XY(self.zx.0 * rhs.yz.0),
+		//     yzw : YZW(self.s.0 * rhs.yzw.0) + YZW(self.w.0 * rhs.yz.0) + YZW(self.wx.0 * rhs.xyz.0) + YZW(self.wy.0 * rhs.z.0) - YZW(self.wz.0 * rhs.y.0) + YZW(self.x.0 * rhs.xyzw.0) + YZW(self.xy.0 * rhs.zxw.0) + YZW(self.xyw.0 * rhs.zx.0) - YZW(self.xyz.0 * rhs.wx.0) - YZW(self.xyzw.0 * rhs.x.0) - YZW(self.y.0 * rhs.wz.0) + YZW(self.yz.0 * rhs.w.0) + YZW(self.yzw.0 * rhs.s.0) + YZW(self.z.0 * rhs.wy.0) - YZW(self.zx.0 * rhs.xyw.0) - YZW(self.zxw.0 * rhs.xy.0),
+		//     zxw : ZXW(self.s.0 * rhs.zxw.0) + ZXW(self.w.0 * rhs.zx.0) - ZXW(self.wx.0 * rhs.z.0) + ZXW(self.wy.0 * rhs.xyz.0) + ZXW(self.wz.0 * rhs.x.0) + ZXW(self.x.0 * rhs.wz.0) - ZXW(self.xy.0 * rhs.yzw.0) - ZXW(self.xyw.0 * rhs.yz.0) - ZXW(self.xyz.0 * rhs.wy.0) - ZXW(self.xyzw.0 * rhs.y.0) + ZXW(self.y.0 * rhs.xyzw.0) + ZXW(self.yz.0 * rhs.xyw.0) + ZXW(self.yzw.0 * rhs.xy.0) - ZXW(self.z.0 * rhs.wx.0) + ZXW(self.zx.0 * rhs.w.0) + ZXW(self.zxw.0 * rhs.s.0),
+		//     xyw : XYW(self.s.0 * rhs.xyw.0) + XYW(self.w.0 * rhs.xy.0) + XYW(self.wx.0 * rhs.y.0) - XYW(self.wy.0 * rhs.x.0) + XYW(self.wz.0 * rhs.xyz.0) - XYW(self.x.0 * rhs.wy.0) + XYW(self.xy.0 * rhs.w.0) + XYW(self.xyw.0 * rhs.s.0) - XYW(self.xyz.0 * rhs.wz.0) - XYW(self.xyzw.0 * rhs.z.0) + XYW(self.y.0 * rhs.wx.0) - XYW(self.yz.0 * rhs.zxw.0) - XYW(self.yzw.0 * rhs.zx.0) + XYW(self.z.0 * rhs.xyzw.0) + XYW(self.zx.0 * rhs.yzw.0) + XYW(self.zxw.0 * rhs.yz.0),
+		//     xyz : XYZ(self.s.0 * rhs.xyz.0) + XYZ(self.x.0 * rhs.yz.0) + XYZ(self.xy.0 * rhs.z.0) + XYZ(self.xyz.0 * rhs.s.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.z.0 * rhs.xy.0) + XYZ(self.zx.0 * rhs.y.0),
+		//     xyzw: XYZW(self.s.0 * rhs.xyzw.0) - XYZW(self.w.0 * rhs.xyz.0) - XYZW(self.wx.0 * rhs.yz.0) - XYZW(self.wy.0 * rhs.zx.0) - XYZW(self.wz.0 * rhs.xy.0) + XYZW(self.x.0 * rhs.yzw.0) - XYZW(self.xy.0 * rhs.wz.0) - XYZW(self.xyw.0 * rhs.z.0) + XYZW(self.xyz.0 * rhs.w.0) + XYZW(self.xyzw.0 * rhs.s.0) + XYZW(self.y.0 * rhs.zxw.0) - XYZW(self.yz.0 * rhs.wx.0) - XYZW(self.yzw.0 * rhs.x.0) + XYZW(self.z.0 * rhs.xyw.0) - XYZW(self.zx.0 * rhs.wy.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s)
+				+ self.x.geometric(rhs.x)
+				+ self.xy.geometric(rhs.xy)
+				+ self.xyz.geometric(rhs.xyz)
+				+ self.y.geometric(rhs.y)
+				+ self.yz.geometric(rhs.yz)
+				+ self.z.geometric(rhs.z)
+				+ self.zx.geometric(rhs.zx),
+			x: self.s.geometric(rhs.x)
+				+ self.x.geometric(rhs.s)
+				+ self.xy.geometric(rhs.y)
+				+ self.xyz.geometric(rhs.yz)
+				+ self.y.geometric(rhs.xy)
+				+ self.yz.geometric(rhs.xyz)
+				+ self.z.geometric(rhs.zx)
+				+ self.zx.geometric(rhs.z),
+			y: self.s.geometric(rhs.y)
+				+ self.x.geometric(rhs.xy)
+				+ self.xy.geometric(rhs.x)
+				+ self.xyz.geometric(rhs.zx)
+				+ self.y.geometric(rhs.s)
+				+ self.yz.geometric(rhs.z)
+				+ self.z.geometric(rhs.yz)
+				+ self.zx.geometric(rhs.xyz),
+			z: self.s.geometric(rhs.z)
+				+ self.x.geometric(rhs.zx)
+				+ self.xy.geometric(rhs.xyz)
+				+ self.xyz.geometric(rhs.xy)
+				+ self.y.geometric(rhs.yz)
+				+ self.yz.geometric(rhs.y)
+				+ self.z.geometric(rhs.s)
+				+ self.zx.geometric(rhs.x),
+			w: self.s.geometric(rhs.w)
+				+ self.w.geometric(rhs.s)
+				+ self.wx.geometric(rhs.x)
+				+ self.wy.geometric(rhs.y)
+				+ self.wz.geometric(rhs.z)
+				+ self.x.geometric(rhs.wx)
+				+ self.xy.geometric(rhs.xyw)
+				+ self.xyw.geometric(rhs.xy)
+				+ self.xyz.geometric(rhs.xyzw)
+				+ self.xyzw.geometric(rhs.xyz)
+				+ self.y.geometric(rhs.wy)
+				+ self.yz.geometric(rhs.yzw)
+				+ self.yzw.geometric(rhs.yz)
+				+ self.z.geometric(rhs.wz)
+				+ self.zx.geometric(rhs.zxw)
+				+ self.zxw.geometric(rhs.zx),
+			wx: self.s.geometric(rhs.wx)
+				+ self.w.geometric(rhs.x)
+				+ self.wx.geometric(rhs.s)
+				+ self.wy.geometric(rhs.xy)
+				+ self.wz.geometric(rhs.zx)
+				+ self.x.geometric(rhs.w)
+				+ self.xy.geometric(rhs.wy)
+				+ self.xyw.geometric(rhs.y)
+				+ self.xyz.geometric(rhs.yzw)
+				+ self.xyzw.geometric(rhs.yz)
+				+ self.y.geometric(rhs.xyw)
+				+ self.yz.geometric(rhs.xyzw)
+				+ self.yzw.geometric(rhs.xyz)
+				+ self.z.geometric(rhs.zxw)
+				+ self.zx.geometric(rhs.wz)
+				+ self.zxw.geometric(rhs.z),
+			wy: self.s.geometric(rhs.wy)
+				+ self.w.geometric(rhs.y)
+				+ self.wx.geometric(rhs.xy)
+				+ self.wy.geometric(rhs.s)
+				+ self.wz.geometric(rhs.yz)
+				+ self.x.geometric(rhs.xyw)
+				+ self.xy.geometric(rhs.wx)
+				+ self.xyw.geometric(rhs.x)
+				+ self.xyz.geometric(rhs.zxw)
+				+ self.xyzw.geometric(rhs.zx)
+				+ self.y.geometric(rhs.w)
+				+ self.yz.geometric(rhs.wz)
+				+ self.yzw.geometric(rhs.z)
+				+ self.z.geometric(rhs.yzw)
+				+ self.zx.geometric(rhs.xyzw)
+				+ self.zxw.geometric(rhs.xyz),
+			wz: self.s.geometric(rhs.wz)
+				+ self.w.geometric(rhs.z)
+				+ self.wx.geometric(rhs.zx)
+				+ self.wy.geometric(rhs.yz)
+				+ self.wz.geometric(rhs.s)
+				+ self.x.geometric(rhs.zxw)
+				+ self.xy.geometric(rhs.xyzw)
+				+ self.xyw.geometric(rhs.xyz)
+				+ self.xyz.geometric(rhs.xyw)
+				+ self.xyzw.geometric(rhs.xy)
+				+ self.y.geometric(rhs.yzw)
+				+ self.yz.geometric(rhs.wy)
+				+ self.yzw.geometric(rhs.y)
+				+ self.z.geometric(rhs.w)
+				+ self.zx.geometric(rhs.wx)
+				+ self.zxw.geometric(rhs.x),
+			yz: self.s.geometric(rhs.yz)
+				+ self.x.geometric(rhs.xyz)
+				+ self.xy.geometric(rhs.zx)
+				+ self.xyz.geometric(rhs.x)
+				+ self.y.geometric(rhs.z)
+				+ self.yz.geometric(rhs.s)
+				+ self.z.geometric(rhs.y)
+				+ self.zx.geometric(rhs.xy),
+			zx: self.s.geometric(rhs.zx)
+				+ self.x.geometric(rhs.z)
+				+ self.xy.geometric(rhs.yz)
+				+ self.xyz.geometric(rhs.y)
+				+ self.y.geometric(rhs.xyz)
+				+ self.yz.geometric(rhs.xy)
+				+ self.z.geometric(rhs.x)
+				+ self.zx.geometric(rhs.s),
+			xy: self.s.geometric(rhs.xy)
+				+ self.x.geometric(rhs.y)
+				+ self.xy.geometric(rhs.s)
+				+ self.xyz.geometric(rhs.z)
+				+ self.y.geometric(rhs.x)
+				+ self.yz.geometric(rhs.zx)
+				+ self.z.geometric(rhs.xyz)
+				+ self.zx.geometric(rhs.yz),
+			yzw: self.s.geometric(rhs.yzw)
+				+ self.w.geometric(rhs.yz)
+				+ self.wx.geometric(rhs.xyz)
+				+ self.wy.geometric(rhs.z)
+				+ self.wz.geometric(rhs.y)
+				+ self.x.geometric(rhs.xyzw)
+				+ self.xy.geometric(rhs.zxw)
+				+ self.xyw.geometric(rhs.zx)
+				+ self.xyz.geometric(rhs.wx)
+				+ self.xyzw.geometric(rhs.x)
+				+ self.y.geometric(rhs.wz)
+				+ self.yz.geometric(rhs.w)
+				+ self.yzw.geometric(rhs.s)
+				+ self.z.geometric(rhs.wy)
+				+ self.zx.geometric(rhs.xyw)
+				+ self.zxw.geometric(rhs.xy),
+			zxw: self.s.geometric(rhs.zxw)
+				+ self.w.geometric(rhs.zx)
+				+ self.wx.geometric(rhs.z)
+				+ self.wy.geometric(rhs.xyz)
+				+ self.wz.geometric(rhs.x)
+				+ self.x.geometric(rhs.wz)
+				+ self.xy.geometric(rhs.yzw)
+				+ self.xyw.geometric(rhs.yz)
+				+ self.xyz.geometric(rhs.wy)
+				+ self.xyzw.geometric(rhs.y)
+				+ self.y.geometric(rhs.xyzw)
+				+ self.yz.geometric(rhs.xyw)
+				+ self.yzw.geometric(rhs.xy)
+				+ self.z.geometric(rhs.wx)
+				+ self.zx.geometric(rhs.w)
+				+ self.zxw.geometric(rhs.s),
+			xyw: self.s.geometric(rhs.xyw)
+				+ self.w.geometric(rhs.xy)
+				+ self.wx.geometric(rhs.y)
+				+ self.wy.geometric(rhs.x)
+				+ self.wz.geometric(rhs.xyz)
+				+ self.x.geometric(rhs.wy)
+				+ self.xy.geometric(rhs.w)
+				+ self.xyw.geometric(rhs.s)
+				+ self.xyz.geometric(rhs.wz)
+				+ self.xyzw.geometric(rhs.z)
+				+ self.y.geometric(rhs.wx)
+				+ self.yz.geometric(rhs.zxw)
+				+ self.yzw.geometric(rhs.zx)
+				+ self.z.geometric(rhs.xyzw)
+				+ self.zx.geometric(rhs.yzw)
+				+ self.zxw.geometric(rhs.yz),
+			xyz: self.s.geometric(rhs.xyz)
+				+ self.x.geometric(rhs.yz)
+				+ self.xy.geometric(rhs.z)
+				+ self.xyz.geometric(rhs.s)
+				+ self.y.geometric(rhs.zx)
+				+ self.yz.geometric(rhs.x)
+				+ self.z.geometric(rhs.xy)
+				+ self.zx.geometric(rhs.y),
+			xyzw: self.s.geometric(rhs.xyzw)
+				+ self.w.geometric(rhs.xyz)
+				+ self.wx.geometric(rhs.yz)
+				+ self.wy.geometric(rhs.zx)
+				+ self.wz.geometric(rhs.xy)
+				+ self.x.geometric(rhs.yzw)
+				+ self.xy.geometric(rhs.wz)
+				+ self.xyw.geometric(rhs.z)
+				+ self.xyz.geometric(rhs.w)
+				+ self.xyzw.geometric(rhs.s)
+				+ self.y.geometric(rhs.zxw)
+				+ self.yz.geometric(rhs.wx)
+				+ self.yzw.geometric(rhs.x)
+				+ self.z.geometric(rhs.xyw)
+				+ self.zx.geometric(rhs.wy)
+				+ self.zxw.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.xyzw.0) - S(self.w.0 * rhs.xyz.0) - S(self.wx.0 * rhs.yz.0) - S(self.wy.0 * rhs.zx.0) - S(self.wz.0 * rhs.xy.0) + S(self.x.0 * rhs.yzw.0) - S(self.xy.0 * rhs.wz.0) - S(self.xyw.0 * rhs.z.0) + S(self.xyz.0 * rhs.w.0) + S(self.xyzw.0 * rhs.s.0) + S(self.y.0 * rhs.zxw.0) - S(self.yz.0 * rhs.wx.0) - S(self.yzw.0 * rhs.x.0) + S(self.z.0 * rhs.xyw.0) - S(self.zx.0 * rhs.wy.0) - S(self.zxw.0 * rhs.y.0),
+		//     x   : X(self.s.0 * rhs.yzw.0) - X(self.w.0 * rhs.yz.0) - X(self.wx.0 * rhs.xyz.0) + X(self.wy.0 * rhs.z.0) - X(self.wz.0 * rhs.y.0) + X(self.x.0 * rhs.xyzw.0) - X(self.xy.0 * rhs.zxw.0) + X(self.xyw.0 * rhs.zx.0) - X(self.xyz.0 * rhs.wx.0) + X(self.xyzw.0 * rhs.x.0) + X(self.y.0 * rhs.wz.0) + X(self.yz.0 * rhs.w.0) - X(self.yzw.0 * rhs.s.0) - X(self.z.0 * rhs.wy.0) + X(self.zx.0 * rhs.xyw.0) - X(self.zxw.0 * rhs.xy.0),
+		//     y   : Y(self.s.0 * rhs.zxw.0) - Y(self.w.0 * rhs.zx.0) - Y(self.wx.0 * rhs.z.0) - Y(self.wy.0 * rhs.xyz.0) + Y(self.wz.0 * rhs.x.0) - Y(self.x.0 * rhs.wz.0) + Y(self.xy.0 * rhs.yzw.0) - Y(self.xyw.0 * rhs.yz.0) - Y(self.xyz.0 * rhs.wy.0) + Y(self.xyzw.0 * rhs.y.0) + Y(self.y.0 * rhs.xyzw.0) - Y(self.yz.0 * rhs.xyw.0) + Y(self.yzw.0 * rhs.xy.0) + Y(self.z.0 * rhs.wx.0) + Y(self.zx.0 * rhs.w.0) - Y(self.zxw.0 * rhs.s.0),
+		//     z   : Z(self.s.0 * rhs.xyw.0) - Z(self.w.0 * rhs.xy.0) + Z(self.wx.0 * rhs.y.0) - Z(self.wy.0 * rhs.x.0) - Z(self.wz.0 * rhs.xyz.0) + Z(self.x.0 * rhs.wy.0) + Z(self.xy.0 * rhs.w.0) - Z(self.xyw.0 * rhs.s.0) - Z(self.xyz.0 * rhs.wz.0) + Z(self.xyzw.0 * rhs.z.0) - Z(self.y.0 * rhs.wx.0) + Z(self.yz.0 * rhs.zxw.0) - Z(self.yzw.0 * rhs.zx.0) + Z(self.z.0 * rhs.xyzw.0) - Z(self.zx.0 * rhs.yzw.0) + Z(self.zxw.0 * rhs.yz.0),
+		//     w   : W(self.w.0 * rhs.xyzw.0) - W(self.wx.0 * rhs.yzw.0) - W(self.wy.0 * rhs.zxw.0) - W(self.wz.0 * rhs.xyw.0) - W(self.xyw.0 * rhs.wz.0) + W(self.xyzw.0 * rhs.w.0) - W(self.yzw.0 * rhs.wx.0) - W(self.zxw.0 * rhs.wy.0),
+		//     wx  : -WX(self.w.0 * rhs.yzw.0) + WX(self.wx.0 * rhs.xyzw.0) + WX(self.wy.0 * rhs.wz.0) - WX(self.wz.0 * rhs.wy.0) + WX(self.xyw.0 * rhs.zxw.0) + WX(self.xyzw.0 * rhs.wx.0) - WX(self.yzw.0 * rhs.w.0) - WX(self.zxw.0 * rhs.xyw.0),
+		//     wy  : -WY(self.w.0 * rhs.zxw.0) - WY(self.wx.0 * rhs.wz.0) + WY(self.wy.0 * rhs.xyzw.0) + WY(self.wz.0 * rhs.wx.0) - WY(self.xyw.0 * rhs.yzw.0) + WY(self.xyzw.0 * rhs.wy.0) + WY(self.yzw.0 * rhs.xyw.0) - WY(self.zxw.0 * rhs.w.0),
+		//     wz  : -WZ(self.w.0 * rhs.xyw.0) + WZ(self.wx.0 * rhs.wy.0) - WZ(self.wy.0 * rhs.wx.0) + WZ(self.wz.0 * rhs.xyzw.0) - WZ(self.xyw.0 * rhs.w.0) + WZ(self.xyzw.0 * rhs.wz.0) - WZ(self.yzw.0 * rhs.zxw.0) + WZ(self.zxw.0 * rhs.yzw.0),
+		//     yz  : YZ(self.s.0 * rhs.wx.0) + YZ(self.w.0 * rhs.x.0) + YZ(self.wx.0 * rhs.s.0) + YZ(self.wy.0 * rhs.xy.0) - YZ(self.wz.0 * rhs.zx.0) - YZ(self.x.0 * rhs.w.0) - YZ(self.xy.0 * rhs.wy.0) - YZ(self.xyw.0 * rhs.y.0) + YZ(self.xyz.0 * rhs.yzw.0) + YZ(self.xyzw.0 * rhs.yz.0) - YZ(self.y.0 * rhs.xyw.0) + YZ(self.yz.0 * rhs.xyzw.0) - YZ(self.yzw.0 * rhs.xyz.0) + YZ(self.z.0 * rhs.zxw.0) + YZ(self.zx.0 * rhs.wz.0) + YZ(self.zxw.0 * rhs.z.0),
+		//     zx  : ZX(self.s.0 * rhs.wy.0) + ZX(self.w.0 * rhs.y.0) - ZX(self.wx.0 * rhs.xy.0) + ZX(self.wy.0 * rhs.s.0) + ZX(self.wz.0 * rhs.yz.0) + ZX(self.x.0 * rhs.xyw.0) + ZX(self.xy.0 * rhs.wx.0) + ZX(self.xyw.0 * rhs.x.0) + ZX(self.xyz.0 * rhs.zxw.0) + ZX(self.xyzw.0 * rhs.zx.0) - ZX(self.y.0 * rhs.w.0) - ZX(self.yz.0 * rhs.wz.0) - ZX(self.yzw.0 * rhs.z.0) - ZX(self.z.0 * rhs.yzw.0) + ZX(self.zx.0 * rhs.xyzw.0) - ZX(self.zxw.0 * rhs.xyz.0),
+		//     xy  : XY(self.s.0 * rhs.wz.0) + XY(self.w.0 * rhs.z.0) + XY(self.wx.0 * rhs.zx.0) - XY(self.wy.0 * rhs.yz.0) + XY(self.wz.0 * rhs.s.0) - XY(self.x.0 * rhs.zxw.0) + XY(self.xy.0 * rhs.xyzw.0) - XY(self.xyw.0 * rhs.xyz.0) + XY(self.xyz.0 * rhs.xyw.0) + XY(self.xyzw.0 * rhs.xy.0) + XY(self.y.0 * rhs.yzw.0) + XY(self.yz.0 * rhs.wy.0) + XY(self.yzw.0 * rhs.y.0) - XY(self.z.0 * rhs.w.0) - XY(self.zx.0 * rhs.wx.0) - XY(self.zxw.0 * rhs.x.0),
+		//     yzw : YZW(self.w.0 * rhs.wx.0) + YZW(self.wx.0 * rhs.w.0) + YZW(self.wy.0 * rhs.xyw.0) - YZW(self.wz.0 * rhs.zxw.0) - YZW(self.xyw.0 * rhs.wy.0) + YZW(self.xyzw.0 * rhs.yzw.0) + YZW(self.yzw.0 * rhs.xyzw.0) + YZW(self.zxw.0 * rhs.wz.0),
+		//     zxw : ZXW(self.w.0 * rhs.wy.0) - ZXW(self.wx.0 * rhs.xyw.0) + ZXW(self.wy.0 * rhs.w.0) + ZXW(self.wz.0 * rhs.yzw.0) + ZXW(self.xyw.0 * rhs.wx.0) + ZXW(self.xyzw.0 * rhs.zxw.0) - ZXW(self.yzw.0 * rhs.wz.0) + ZXW(self.zxw.0 * rhs.xyzw.0),
+		//     xyw : XYW(self.w.0 * rhs.wz.0) + XYW(self.wx.0 * rhs.zxw.0) - XYW(self.wy.0 * rhs.yzw.0) + XYW(self.wz.0 * rhs.w.0) + XYW(self.xyw.0 * rhs.xyzw.0) + XYW(self.xyzw.0 * rhs.xyw.0) + XYW(self.yzw.0 * rhs.wy.0) - XYW(self.zxw.0 * rhs.wx.0),
+		//     xyz : -XYZ(self.s.0 * rhs.w.0) + XYZ(self.w.0 * rhs.s.0) + XYZ(self.wx.0 * rhs.x.0) + XYZ(self.wy.0 * rhs.y.0) + XYZ(self.wz.0 * rhs.z.0) + XYZ(self.x.0 * rhs.wx.0) + XYZ(self.xy.0 * rhs.xyw.0) - XYZ(self.xyw.0 * rhs.xy.0) + XYZ(self.xyz.0 * rhs.xyzw.0) + XYZ(self.xyzw.0 * rhs.xyz.0) + XYZ(self.y.0 * rhs.wy.0) + XYZ(self.yz.0 * rhs.yzw.0) - XYZ(self.yzw.0 * rhs.yz.0) + XYZ(self.z.0 * rhs.wz.0) + XYZ(self.zx.0 * rhs.zxw.0) - XYZ(self.zxw.0 * rhs.zx.0),
+		//     xyzw: -XYZW(self.w.0 * rhs.w.0) - XYZW(self.wx.0 * rhs.wx.0) - XYZW(self.wy.0 * rhs.wy.0) - XYZW(self.wz.0 * rhs.wz.0) + XYZW(self.xyw.0 * rhs.xyw.0) + XYZW(self.xyzw.0 * rhs.xyzw.0) + XYZW(self.yzw.0 * rhs.yzw.0) + XYZW(self.zxw.0 * rhs.zxw.0),
+		// }
+		Multivector {
+			s: self.s.anti_geometric(rhs.xyzw)
+				+ self.w.anti_geometric(rhs.xyz)
+				+ self.wx.anti_geometric(rhs.yz)
+				+ self.wy.anti_geometric(rhs.zx)
+				+ self.wz.anti_geometric(rhs.xy)
+				+ self.x.anti_geometric(rhs.yzw)
+				+ self.xy.anti_geometric(rhs.wz)
+				+ self.xyw.anti_geometric(rhs.z)
+				+ self.xyz.anti_geometric(rhs.w)
+				+ self.xyzw.anti_geometric(rhs.s)
+				+ self.y.anti_geometric(rhs.zxw)
+				+ self.yz.anti_geometric(rhs.wx)
+				+ self.yzw.anti_geometric(rhs.x)
+				+ self.z.anti_geometric(rhs.xyw)
+				+ self.zx.anti_geometric(rhs.wy)
+				+ self.zxw.anti_geometric(rhs.y),
+			x: self.s.anti_geometric(rhs.yzw)
+				+ self.w.anti_geometric(rhs.yz)
+				+ self.wx.anti_geometric(rhs.xyz)
+				+ self.wy.anti_geometric(rhs.z)
+				+ self.wz.anti_geometric(rhs.y)
+				+ self.x.anti_geometric(rhs.xyzw)
+				+ self.xy.anti_geometric(rhs.zxw)
+				+ self.xyw.anti_geometric(rhs.zx)
+				+ self.xyz.anti_geometric(rhs.wx)
+				+ self.xyzw.anti_geometric(rhs.x)
+				+ self.y.anti_geometric(rhs.wz)
+				+ self.yz.anti_geometric(rhs.w)
+				+ self.yzw.anti_geometric(rhs.s)
+				+ self.z.anti_geometric(rhs.wy)
+				+ self.zx.anti_geometric(rhs.xyw)
+				+ self.zxw.anti_geometric(rhs.xy),
+			y: self.s.anti_geometric(rhs.zxw)
+				+ self.w.anti_geometric(rhs.zx)
+				+ self.wx.anti_geometric(rhs.z)
+				+ self.wy.anti_geometric(rhs.xyz)
+				+ self.wz.anti_geometric(rhs.x)
+				+ self.x.anti_geometric(rhs.wz)
+				+ self.xy.anti_geometric(rhs.yzw)
+				+ self.xyw.anti_geometric(rhs.yz)
+				+ self.xyz.anti_geometric(rhs.wy)
+				+ self.xyzw.anti_geometric(rhs.y)
+				+ self.y.anti_geometric(rhs.xyzw)
+				+ self.yz.anti_geometric(rhs.xyw)
+				+ self.yzw.anti_geometric(rhs.xy)
+				+ self.z.anti_geometric(rhs.wx)
+				+ self.zx.anti_geometric(rhs.w)
+				+ self.zxw.anti_geometric(rhs.s),
+			z: self.s.anti_geometric(rhs.xyw)
+				+ self.w.anti_geometric(rhs.xy)
+				+ self.wx.anti_geometric(rhs.y)
+				+ self.wy.anti_geometric(rhs.x)
+				+ self.wz.anti_geometric(rhs.xyz)
+				+ self.x.anti_geometric(rhs.wy)
+				+ self.xy.anti_geometric(rhs.w)
+				+ self.xyw.anti_geometric(rhs.s)
+				+ self.xyz.anti_geometric(rhs.wz)
+				+ self.xyzw.anti_geometric(rhs.z)
+				+ self.y.anti_geometric(rhs.wx)
+				+ self.yz.anti_geometric(rhs.zxw)
+				+ self.yzw.anti_geometric(rhs.zx)
+				+ self.z.anti_geometric(rhs.xyzw)
+				+ self.zx.anti_geometric(rhs.yzw)
+				+ self.zxw.anti_geometric(rhs.yz),
+			w: self.w.anti_geometric(rhs.xyzw)
+				+ self.wx.anti_geometric(rhs.yzw)
+				+ self.wy.anti_geometric(rhs.zxw)
+				+ self.wz.anti_geometric(rhs.xyw)
+				+ self.xyw.anti_geometric(rhs.wz)
+				+ self.xyzw.anti_geometric(rhs.w)
+				+ self.yzw.anti_geometric(rhs.wx)
+				+ self.zxw.anti_geometric(rhs.wy),
+			wx: self.w.anti_geometric(rhs.yzw)
+				+ self.wx.anti_geometric(rhs.xyzw)
+				+ self.wy.anti_geometric(rhs.wz)
+				+ self.wz.anti_geometric(rhs.wy)
+				+ self.xyw.anti_geometric(rhs.zxw)
+				+ self.xyzw.anti_geometric(rhs.wx)
+				+ self.yzw.anti_geometric(rhs.w)
+				+ self.zxw.anti_geometric(rhs.xyw),
+			wy: self.w.anti_geometric(rhs.zxw)
+				+ self.wx.anti_geometric(rhs.wz)
+				+ self.wy.anti_geometric(rhs.xyzw)
+				+ self.wz.anti_geometric(rhs.wx)
+				+ self.xyw.anti_geometric(rhs.yzw)
+				+ self.xyzw.anti_geometric(rhs.wy)
+				+ self.yzw.anti_geometric(rhs.xyw)
+				+ self.zxw.anti_geometric(rhs.w),
+			wz: self.w.anti_geometric(rhs.xyw)
+				+ self.wx.anti_geometric(rhs.wy)
+				+ self.wy.anti_geometric(rhs.wx)
+				+ self.wz.anti_geometric(rhs.xyzw)
+				+ self.xyw.anti_geometric(rhs.w)
+				+ self.xyzw.anti_geometric(rhs.wz)
+				+ self.yzw.anti_geometric(rhs.zxw)
+				+ self.zxw.anti_geometric(rhs.yzw),
+			yz: self.s.anti_geometric(rhs.wx)
+				+ self.w.anti_geometric(rhs.x)
+				+ self.wx.anti_geometric(rhs.s)
+				+ self.wy.anti_geometric(rhs.xy)
+				+ self.wz.anti_geometric(rhs.zx)
+				+ self.x.anti_geometric(rhs.w)
+				+ self.xy.anti_geometric(rhs.wy)
+				+ self.xyw.anti_geometric(rhs.y)
+				+ self.xyz.anti_geometric(rhs.yzw)
+				+ self.xyzw.anti_geometric(rhs.yz)
+				+ self.y.anti_geometric(rhs.xyw)
+				+ self.yz.anti_geometric(rhs.xyzw)
+				+ self.yzw.anti_geometric(rhs.xyz)
+				+ self.z.anti_geometric(rhs.zxw)
+				+ self.zx.anti_geometric(rhs.wz)
+				+ self.zxw.anti_geometric(rhs.z),
+			zx: self.s.anti_geometric(rhs.wy)
+				+ self.w.anti_geometric(rhs.y)
+				+ self.wx.anti_geometric(rhs.xy)
+				+ self.wy.anti_geometric(rhs.s)
+				+ self.wz.anti_geometric(rhs.yz)
+				+ self.x.anti_geometric(rhs.xyw)
+				+ self.xy.anti_geometric(rhs.wx)
+				+ self.xyw.anti_geometric(rhs.x)
+				+ self.xyz.anti_geometric(rhs.zxw)
+				+ self.xyzw.anti_geometric(rhs.zx)
+				+ self.y.anti_geometric(rhs.w)
+				+ self.yz.anti_geometric(rhs.wz)
+				+ self.yzw.anti_geometric(rhs.z)
+				+ self.z.anti_geometric(rhs.yzw)
+				+ self.zx.anti_geometric(rhs.xyzw)
+				+ self.zxw.anti_geometric(rhs.xyz),
+			xy: self.s.anti_geometric(rhs.wz)
+				+ self.w.anti_geometric(rhs.z)
+				+ self.wx.anti_geometric(rhs.zx)
+				+ self.wy.anti_geometric(rhs.yz)
+				+ self.wz.anti_geometric(rhs.s)
+				+ self.x.anti_geometric(rhs.zxw)
+				+ self.xy.anti_geometric(rhs.xyzw)
+				+ self.xyw.anti_geometric(rhs.xyz)
+				+ self.xyz.anti_geometric(rhs.xyw)
+				+ self.xyzw.anti_geometric(rhs.xy)
+				+ self.y.anti_geometric(rhs.yzw)
+				+ self.yz.anti_geometric(rhs.wy)
+				+ self.yzw.anti_geometric(rhs.y)
+				+ self.z.anti_geometric(rhs.w)
+				+ self.zx.anti_geometric(rhs.wx)
+				+ self.zxw.anti_geometric(rhs.x),
+			yzw: self.w.anti_geometric(rhs.wx)
+				+ self.wx.anti_geometric(rhs.w)
+				+ self.wy.anti_geometric(rhs.xyw)
+				+ self.wz.anti_geometric(rhs.zxw)
+				+ self.xyw.anti_geometric(rhs.wy)
+				+ self.xyzw.anti_geometric(rhs.yzw)
+				+ self.yzw.anti_geometric(rhs.xyzw)
+				+ self.zxw.anti_geometric(rhs.wz),
+			zxw: self.w.anti_geometric(rhs.wy)
+				+ self.wx.anti_geometric(rhs.xyw)
+				+ self.wy.anti_geometric(rhs.w)
+				+ self.wz.anti_geometric(rhs.yzw)
+				+ self.xyw.anti_geometric(rhs.wx)
+				+ self.xyzw.anti_geometric(rhs.zxw)
+				+ self.yzw.anti_geometric(rhs.wz)
+				+ self.zxw.anti_geometric(rhs.xyzw),
+			xyw: self.w.anti_geometric(rhs.wz)
+				+ self.wx.anti_geometric(rhs.zxw)
+				+ self.wy.anti_geometric(rhs.yzw)
+				+ self.wz.anti_geometric(rhs.w)
+				+ self.xyw.anti_geometric(rhs.xyzw)
+				+ self.xyzw.anti_geometric(rhs.xyw)
+				+ self.yzw.anti_geometric(rhs.wy)
+				+ self.zxw.anti_geometric(rhs.wx),
+			xyz: self.s.anti_geometric(rhs.w)
+				+ self.w.anti_geometric(rhs.s)
+				+ self.wx.anti_geometric(rhs.x)
+				+ self.wy.anti_geometric(rhs.y)
+				+ self.wz.anti_geometric(rhs.z)
+				+ self.x.anti_geometric(rhs.wx)
+				+ self.xy.anti_geometric(rhs.xyw)
+				+ self.xyw.anti_geometric(rhs.xy)
+				+ self.xyz.anti_geometric(rhs.xyzw)
+				+ self.xyzw.anti_geometric(rhs.xyz)
+				+ self.y.anti_geometric(rhs.wy)
+				+ self.yz.anti_geometric(rhs.yzw)
+				+ self.yzw.anti_geometric(rhs.yz)
+				+ self.z.anti_geometric(rhs.wz)
+				+ self.zx.anti_geometric(rhs.zxw)
+				+ self.zxw.anti_geometric(rhs.zx),
+			xyzw: self.w.anti_geometric(rhs.w)
+				+ self.wx.anti_geometric(rhs.wx)
+				+ self.wy.anti_geometric(rhs.wy)
+				+ self.wz.anti_geometric(rhs.wz)
+				+ self.xyw.anti_geometric(rhs.xyw)
+				+ self.xyzw.anti_geometric(rhs.xyzw)
+				+ self.yzw.anti_geometric(rhs.yzw)
+				+ self.zxw.anti_geometric(rhs.zxw),
+		}
+	}
+}
+
+// Multivector.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) - S(self.xyz.0 * rhs.xyz.0) + S(self.y.0 * rhs.y.0) - S(self.yz.0 * rhs.yz.0) + S(self.z.0 * rhs.z.0) - S(self.zx.0 * rhs.zx.0),
+		//     x   : X(self.s.0 * rhs.x.0) + X(self.x.0 * rhs.s.0) + X(self.xy.0 * rhs.y.0) - X(self.xyz.0 * rhs.yz.0) - X(self.y.0 * rhs.xy.0) - X(self.yz.0 * rhs.xyz.0) + X(self.z.0 * rhs.zx.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : Y(self.s.0 * rhs.y.0) + Y(self.x.0 * rhs.xy.0) - Y(self.xy.0 * rhs.x.0) - Y(self.xyz.0 * rhs.zx.0) + Y(self.y.0 * rhs.s.0) + Y(self.yz.0 * rhs.z.0) - Y(self.z.0 * rhs.yz.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z   : Z(self.s.0 * rhs.z.0) - Z(self.x.0 * rhs.zx.0) - Z(self.xy.0 * rhs.xyz.0) - Z(self.xyz.0 * rhs.xy.0) + Z(self.y.0 * rhs.yz.0) - Z(self.yz.0 * rhs.y.0) + Z(self.z.0 * rhs.s.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.s.0 * rhs.w.0) + W(self.w.0 * rhs.s.0) + W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0) - W(self.x.0 * rhs.wx.0) - W(self.xy.0 * rhs.xyw.0) - W(self.xyw.0 * rhs.xy.0) - W(self.xyz.0 * rhs.xyzw.0) + W(self.xyzw.0 * rhs.xyz.0) - W(self.y.0 * rhs.wy.0) - W(self.yz.0 * rhs.yzw.0) - W(self.yzw.0 * rhs.yz.0) - W(self.z.0 * rhs.wz.0) - W(self.zx.0 * rhs.zxw.0) - W(self.zxw.0 * rhs.zx.0),
+		//     wx  : WX(self.s.0 * rhs.wx.0) + WX(self.wx.0 * rhs.s.0) + WX(self.xyw.0 * rhs.y.0) + WX(self.xyzw.0 * rhs.yz.0) + WX(self.y.0 * rhs.xyw.0) + WX(self.yz.0 * rhs.xyzw.0) - WX(self.z.0 * rhs.zxw.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : WY(self.s.0 * rhs.wy.0) + WY(self.wy.0 * rhs.s.0) - WY(self.x.0 * rhs.xyw.0) - WY(self.xyw.0 * rhs.x.0) + WY(self.xyzw.0 * rhs.zx.0) + WY(self.yzw.0 * rhs.z.0) + WY(self.z.0 * rhs.yzw.0) + WY(self.zx.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.s.0 * rhs.wz.0) + WZ(self.wz.0 * rhs.s.0) + WZ(self.x.0 * rhs.zxw.0) + WZ(self.xy.0 * rhs.xyzw.0) + WZ(self.xyzw.0 * rhs.xy.0) - WZ(self.y.0 * rhs.yzw.0) - WZ(self.yzw.0 * rhs.y.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.s.0 * rhs.yz.0) + YZ(self.x.0 * rhs.xyz.0) + YZ(self.xyz.0 * rhs.x.0) + YZ(self.yz.0 * rhs.s.0),
+		//     zx  : ZX(self.s.0 * rhs.zx.0) + ZX(self.xyz.0 * rhs.y.0) + ZX(self.y.0 * rhs.xyz.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy  : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0) + XY(self.xyz.0 * rhs.z.0) + XY(self.z.0 * rhs.xyz.0),
+		//     yzw : YZW(self.s.0 * rhs.yzw.0) + YZW(self.x.0 * rhs.xyzw.0) - YZW(self.xyzw.0 * rhs.x.0) + YZW(self.yzw.0 * rhs.s.0),
+		//     zxw : ZXW(self.s.0 * rhs.zxw.0) - ZXW(self.xyzw.0 * rhs.y.0) + ZXW(self.y.0 * rhs.xyzw.0) + ZXW(self.zxw.0 * rhs.s.0),
+		//     xyw : XYW(self.s.0 * rhs.xyw.0) + XYW(self.xyw.0 * rhs.s.0) - XYW(self.xyzw.0 * rhs.z.0) + XYW(self.z.0 * rhs.xyzw.0),
+		//     xyz : XYZ(self.s.0 * rhs.xyz.0) + XYZ(self.xyz.0 * rhs.s.0),
+		//     xyzw: XYZW(self.s.0 * rhs.xyzw.0) + XYZW(self.xyzw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s)
+				+ self.x.dot(rhs.x)
+				+ self.xy.dot(rhs.xy)
+				+ self.xyz.dot(rhs.xyz)
+				+ self.y.dot(rhs.y)
+				+ self.yz.dot(rhs.yz)
+				+ self.z.dot(rhs.z)
+				+ self.zx.dot(rhs.zx),
+			x: self.s.dot(rhs.x)
+				+ self.x.dot(rhs.s)
+				+ self.xy.dot(rhs.y)
+				+ self.xyz.dot(rhs.yz)
+				+ self.y.dot(rhs.xy)
+				+ self.yz.dot(rhs.xyz)
+				+ self.z.dot(rhs.zx)
+				+ self.zx.dot(rhs.z),
+			y: self.s.dot(rhs.y)
+				+ self.x.dot(rhs.xy)
+				+ self.xy.dot(rhs.x)
+				+ self.xyz.dot(rhs.zx)
+				+ self.y.dot(rhs.s)
+				+ self.yz.dot(rhs.z)
+				+ self.z.dot(rhs.yz)
+				+ self.zx.dot(rhs.xyz),
+			z: self.s.dot(rhs.z)
+				+ self.x.dot(rhs.zx)
+				+ self.xy.dot(rhs.xyz)
+				+ self.xyz.dot(rhs.xy)
+				+ self.y.dot(rhs.yz)
+				+ self.yz.dot(rhs.y)
+				+ self.z.dot(rhs.s)
+				+ self.zx.dot(rhs.x),
+			w: self.s.dot(rhs.w)
+				+ self.w.dot(rhs.s)
+				+ self.wx.dot(rhs.x)
+				+ self.wy.dot(rhs.y)
+				+ self.wz.dot(rhs.z)
+				+ self.x.dot(rhs.wx)
+				+ self.xy.dot(rhs.xyw)
+				+ self.xyw.dot(rhs.xy)
+				+ self.xyz.dot(rhs.xyzw)
+				+ self.xyzw.dot(rhs.xyz)
+				+ self.y.dot(rhs.wy)
+				+ self.yz.dot(rhs.yzw)
+				+ self.yzw.dot(rhs.yz)
+				+ self.z.dot(rhs.wz)
+				+ self.zx.dot(rhs.zxw)
+				+ self.zxw.dot(rhs.zx),
+			wx: self.s.dot(rhs.wx)
+				+ self.wx.dot(rhs.s)
+				+ self.xyw.dot(rhs.y)
+				+ self.xyzw.dot(rhs.yz)
+				+ self.y.dot(rhs.xyw)
+				+ self.yz.dot(rhs.xyzw)
+				+ self.z.dot(rhs.zxw)
+				+ self.zxw.dot(rhs.z),
+			wy: self.s.dot(rhs.wy)
+				+ self.wy.dot(rhs.s)
+				+ self.x.dot(rhs.xyw)
+				+ self.xyw.dot(rhs.x)
+				+ self.xyzw.dot(rhs.zx)
+				+ self.yzw.dot(rhs.z)
+				+ self.z.dot(rhs.yzw)
+				+ self.zx.dot(rhs.xyzw),
+			wz: self.s.dot(rhs.wz)
+				+ self.wz.dot(rhs.s)
+				+ self.x.dot(rhs.zxw)
+				+ self.xy.dot(rhs.xyzw)
+				+ self.xyzw.dot(rhs.xy)
+				+ self.y.dot(rhs.yzw)
+				+ self.yzw.dot(rhs.y)
+				+ self.zxw.dot(rhs.x),
+			yz: self.s.dot(rhs.yz) + self.x.dot(rhs.xyz) + self.xyz.dot(rhs.x) + self.yz.dot(rhs.s),
+			zx: self.s.dot(rhs.zx) + self.xyz.dot(rhs.y) + self.y.dot(rhs.xyz) + self.zx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s) + self.xyz.dot(rhs.z) + self.z.dot(rhs.xyz),
+			yzw: self.s.dot(rhs.yzw) + self.x.dot(rhs.xyzw) + self.xyzw.dot(rhs.x) + self.yzw.dot(rhs.s),
+			zxw: self.s.dot(rhs.zxw) + self.xyzw.dot(rhs.y) + self.y.dot(rhs.xyzw) + self.zxw.dot(rhs.s),
+			xyw: self.s.dot(rhs.xyw) + self.xyw.dot(rhs.s) + self.xyzw.dot(rhs.z) + self.z.dot(rhs.xyzw),
+			xyz: self.s.dot(rhs.xyz) + self.xyz.dot(rhs.s),
+			xyzw: self.s.dot(rhs.xyzw) + self.xyzw.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.s.0),
+		//     x   : X(self.s.0 * rhs.x.0) + X(self.x.0 * rhs.s.0),
+		//     y   : Y(self.s.0 * rhs.y.0) + Y(self.y.0 * rhs.s.0),
+		//     z   : Z(self.s.0 * rhs.z.0) + Z(self.z.0 * rhs.s.0),
+		//     w   : W(self.s.0 * rhs.w.0) + W(self.w.0 * rhs.s.0),
+		//     wx  : WX(self.s.0 * rhs.wx.0) + WX(self.w.0 * rhs.x.0) + WX(self.wx.0 * rhs.s.0) - WX(self.x.0 * rhs.w.0),
+		//     wy  : WY(self.s.0 * rhs.wy.0) + WY(self.w.0 * rhs.y.0) + WY(self.wy.0 * rhs.s.0) - WY(self.y.0 * rhs.w.0),
+		//     wz  : WZ(self.s.0 * rhs.wz.0) + WZ(self.w.0 * rhs.z.0) + WZ(self.wz.0 * rhs.s.0) - WZ(self.z.0 * rhs.w.0),
+		//     yz  : YZ(self.s.0 * rhs.yz.0) + YZ(self.y.0 * rhs.z.0) + YZ(self.yz.0 * rhs.s.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : ZX(self.s.0 * rhs.zx.0) - ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy  : XY(self.s.0 * rhs.xy.0) + XY(self.x.0 * rhs.y.0) + XY(self.xy.0 * rhs.s.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : YZW(self.s.0 * rhs.yzw.0) + YZW(self.w.0 * rhs.yz.0) + YZW(self.wy.0 * rhs.z.0) - YZW(self.wz.0 * rhs.y.0) - YZW(self.y.0 * rhs.wz.0) + YZW(self.yz.0 * rhs.w.0) + YZW(self.yzw.0 * rhs.s.0) + YZW(self.z.0 * rhs.wy.0),
+		//     zxw : ZXW(self.s.0 * rhs.zxw.0) + ZXW(self.w.0 * rhs.zx.0) - ZXW(self.wx.0 * rhs.z.0) + ZXW(self.wz.0 * rhs.x.0) + ZXW(self.x.0 * rhs.wz.0) - ZXW(self.z.0 * rhs.wx.0) + ZXW(self.zx.0 * rhs.w.0) + ZXW(self.zxw.0 * rhs.s.0),
+		//     xyw : XYW(self.s.0 * rhs.xyw.0) + XYW(self.w.0 * rhs.xy.0) + XYW(self.wx.0 * rhs.y.0) - XYW(self.wy.0 * rhs.x.0) - XYW(self.x.0 * rhs.wy.0) + XYW(self.xy.0 * rhs.w.0) + XYW(self.xyw.0 * rhs.s.0) + XYW(self.y.0 * rhs.wx.0),
+		//     xyz : XYZ(self.s.0 * rhs.xyz.0) + XYZ(self.x.0 * rhs.yz.0) + XYZ(self.xy.0 * rhs.z.0) + XYZ(self.xyz.0 * rhs.s.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.z.0 * rhs.xy.0) + XYZ(self.zx.0 * rhs.y.0),
+		//     xyzw: XYZW(self.s.0 * rhs.xyzw.0) - XYZW(self.w.0 * rhs.xyz.0) - XYZW(self.wx.0 * rhs.yz.0) - XYZW(self.wy.0 * rhs.zx.0) - XYZW(self.wz.0 * rhs.xy.0) + XYZW(self.x.0 * rhs.yzw.0) - XYZW(self.xy.0 * rhs.wz.0) - XYZW(self.xyw.0 * rhs.z.0) + XYZW(self.xyz.0 * rhs.w.0) + XYZW(self.xyzw.0 * rhs.s.0) + XYZW(self.y.0 * rhs.zxw.0) - XYZW(self.yz.0 * rhs.wx.0) - XYZW(self.yzw.0 * rhs.x.0) + XYZW(self.z.0 * rhs.xyw.0) - XYZW(self.zx.0 * rhs.wy.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.s.wedge(rhs.x) + self.x.wedge(rhs.s),
+			y: self.s.wedge(rhs.y) + self.y.wedge(rhs.s),
+			z: self.s.wedge(rhs.z) + self.z.wedge(rhs.s),
+			w: self.s.wedge(rhs.w) + self.w.wedge(rhs.s),
+			wx: self.s.wedge(rhs.wx) + self.w.wedge(rhs.x) + self.wx.wedge(rhs.s) + self.x.wedge(rhs.w),
+			wy: self.s.wedge(rhs.wy) + self.w.wedge(rhs.y) + self.wy.wedge(rhs.s) + self.y.wedge(rhs.w),
+			wz: self.s.wedge(rhs.wz) + self.w.wedge(rhs.z) + self.wz.wedge(rhs.s) + self.z.wedge(rhs.w),
+			yz: self.s.wedge(rhs.yz) + self.y.wedge(rhs.z) + self.yz.wedge(rhs.s) + self.z.wedge(rhs.y),
+			zx: self.s.wedge(rhs.zx) + self.x.wedge(rhs.z) + self.z.wedge(rhs.x) + self.zx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy) + self.x.wedge(rhs.y) + self.xy.wedge(rhs.s) + self.y.wedge(rhs.x),
+			yzw: self.s.wedge(rhs.yzw)
+				+ self.w.wedge(rhs.yz)
+				+ self.wy.wedge(rhs.z)
+				+ self.wz.wedge(rhs.y)
+				+ self.y.wedge(rhs.wz)
+				+ self.yz.wedge(rhs.w)
+				+ self.yzw.wedge(rhs.s)
+				+ self.z.wedge(rhs.wy),
+			zxw: self.s.wedge(rhs.zxw)
+				+ self.w.wedge(rhs.zx)
+				+ self.wx.wedge(rhs.z)
+				+ self.wz.wedge(rhs.x)
+				+ self.x.wedge(rhs.wz)
+				+ self.z.wedge(rhs.wx)
+				+ self.zx.wedge(rhs.w)
+				+ self.zxw.wedge(rhs.s),
+			xyw: self.s.wedge(rhs.xyw)
+				+ self.w.wedge(rhs.xy)
+				+ self.wx.wedge(rhs.y)
+				+ self.wy.wedge(rhs.x)
+				+ self.x.wedge(rhs.wy)
+				+ self.xy.wedge(rhs.w)
+				+ self.xyw.wedge(rhs.s)
+				+ self.y.wedge(rhs.wx),
+			xyz: self.s.wedge(rhs.xyz)
+				+ self.x.wedge(rhs.yz)
+				+ self.xy.wedge(rhs.z)
+				+ self.xyz.wedge(rhs.s)
+				+ self.y.wedge(rhs.zx)
+				+ self.yz.wedge(rhs.x)
+				+ self.z.wedge(rhs.xy)
+				+ self.zx.wedge(rhs.y),
+			xyzw: self.s.wedge(rhs.xyzw)
+				+ self.w.wedge(rhs.xyz)
+				+ self.wx.wedge(rhs.yz)
+				+ self.wy.wedge(rhs.zx)
+				+ self.wz.wedge(rhs.xy)
+				+ self.x.wedge(rhs.yzw)
+				+ self.xy.wedge(rhs.wz)
+				+ self.xyw.wedge(rhs.z)
+				+ self.xyz.wedge(rhs.w)
+				+ self.xyzw.wedge(rhs.s)
+				+ self.y.wedge(rhs.zxw)
+				+ self.yz.wedge(rhs.wx)
+				+ self.yzw.wedge(rhs.x)
+				+ self.z.wedge(rhs.xyw)
+				+ self.zx.wedge(rhs.wy)
+				+ self.zxw.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.xyzw.0) - S(self.w.0 * rhs.xyz.0) - S(self.wx.0 * rhs.yz.0) - S(self.wy.0 * rhs.zx.0) - S(self.wz.0 * rhs.xy.0) + S(self.x.0 * rhs.yzw.0) - S(self.xy.0 * rhs.wz.0) - S(self.xyw.0 * rhs.z.0) + S(self.xyz.0 * rhs.w.0) + S(self.xyzw.0 * rhs.s.0) + S(self.y.0 * rhs.zxw.0) - S(self.yz.0 * rhs.wx.0) - S(self.yzw.0 * rhs.x.0) + S(self.z.0 * rhs.xyw.0) - S(self.zx.0 * rhs.wy.0) - S(self.zxw.0 * rhs.y.0),
+		//     x   : -X(self.wx.0 * rhs.xyz.0) + X(self.x.0 * rhs.xyzw.0) - X(self.xy.0 * rhs.zxw.0) + X(self.xyw.0 * rhs.zx.0) - X(self.xyz.0 * rhs.wx.0) + X(self.xyzw.0 * rhs.x.0) + X(self.zx.0 * rhs.xyw.0) - X(self.zxw.0 * rhs.xy.0),
+		//     y   : -Y(self.wy.0 * rhs.xyz.0) + Y(self.xy.0 * rhs.yzw.0) - Y(self.xyw.0 * rhs.yz.0) - Y(self.xyz.0 * rhs.wy.0) + Y(self.xyzw.0 * rhs.y.0) + Y(self.y.0 * rhs.xyzw.0) - Y(self.yz.0 * rhs.xyw.0) + Y(self.yzw.0 * rhs.xy.0),
+		//     z   : -Z(self.wz.0 * rhs.xyz.0) - Z(self.xyz.0 * rhs.wz.0) + Z(self.xyzw.0 * rhs.z.0) + Z(self.yz.0 * rhs.zxw.0) - Z(self.yzw.0 * rhs.zx.0) + Z(self.z.0 * rhs.xyzw.0) - Z(self.zx.0 * rhs.yzw.0) + Z(self.zxw.0 * rhs.yz.0),
+		//     w   : W(self.w.0 * rhs.xyzw.0) - W(self.wx.0 * rhs.yzw.0) - W(self.wy.0 * rhs.zxw.0) - W(self.wz.0 * rhs.xyw.0) - W(self.xyw.0 * rhs.wz.0) + W(self.xyzw.0 * rhs.w.0) - W(self.yzw.0 * rhs.wx.0) - W(self.zxw.0 * rhs.wy.0),
+		//     wx  : WX(self.wx.0 * rhs.xyzw.0) + WX(self.xyw.0 * rhs.zxw.0) + WX(self.xyzw.0 * rhs.wx.0) - WX(self.zxw.0 * rhs.xyw.0),
+		//     wy  : WY(self.wy.0 * rhs.xyzw.0) - WY(self.xyw.0 * rhs.yzw.0) + WY(self.xyzw.0 * rhs.wy.0) + WY(self.yzw.0 * rhs.xyw.0),
+		//     wz  : WZ(self.wz.0 * rhs.xyzw.0) + WZ(self.xyzw.0 * rhs.wz.0) - WZ(self.yzw.0 * rhs.zxw.0) + WZ(self.zxw.0 * rhs.yzw.0),
+		//     yz  : YZ(self.xyz.0 * rhs.yzw.0) + YZ(self.xyzw.0 * rhs.yz.0) + YZ(self.yz.0 * rhs.xyzw.0) - YZ(self.yzw.0 * rhs.xyz.0),
+		//     zx  : ZX(self.xyz.0 * rhs.zxw.0) + ZX(self.xyzw.0 * rhs.zx.0) + ZX(self.zx.0 * rhs.xyzw.0) - ZX(self.zxw.0 * rhs.xyz.0),
+		//     xy  : XY(self.xy.0 * rhs.xyzw.0) - XY(self.xyw.0 * rhs.xyz.0) + XY(self.xyz.0 * rhs.xyw.0) + XY(self.xyzw.0 * rhs.xy.0),
+		//     yzw : YZW(self.xyzw.0 * rhs.yzw.0) + YZW(self.yzw.0 * rhs.xyzw.0),
+		//     zxw : ZXW(self.xyzw.0 * rhs.zxw.0) + ZXW(self.zxw.0 * rhs.xyzw.0),
+		//     xyw : XYW(self.xyw.0 * rhs.xyzw.0) + XYW(self.xyzw.0 * rhs.xyw.0),
+		//     xyz : XYZ(self.xyz.0 * rhs.xyzw.0) + XYZ(self.xyzw.0 * rhs.xyz.0),
+		//     xyzw: XYZW(self.xyzw.0 * rhs.xyzw.0),
+		// }
+		Multivector {
+			s: self.s.anti_wedge(rhs.xyzw)
+				+ self.w.anti_wedge(rhs.xyz)
+				+ self.wx.anti_wedge(rhs.yz)
+				+ self.wy.anti_wedge(rhs.zx)
+				+ self.wz.anti_wedge(rhs.xy)
+				+ self.x.anti_wedge(rhs.yzw)
+				+ self.xy.anti_wedge(rhs.wz)
+				+ self.xyw.anti_wedge(rhs.z)
+				+ self.xyz.anti_wedge(rhs.w)
+				+ self.xyzw.anti_wedge(rhs.s)
+				+ self.y.anti_wedge(rhs.zxw)
+				+ self.yz.anti_wedge(rhs.wx)
+				+ self.yzw.anti_wedge(rhs.x)
+				+ self.z.anti_wedge(rhs.xyw)
+				+ self.zx.anti_wedge(rhs.wy)
+				+ self.zxw.anti_wedge(rhs.y),
+			x: self.wx.anti_wedge(rhs.xyz)
+				+ self.x.anti_wedge(rhs.xyzw)
+				+ self.xy.anti_wedge(rhs.zxw)
+				+ self.xyw.anti_wedge(rhs.zx)
+				+ self.xyz.anti_wedge(rhs.wx)
+				+ self.xyzw.anti_wedge(rhs.x)
+				+ self.zx.anti_wedge(rhs.xyw)
+				+ self.zxw.anti_wedge(rhs.xy),
+			y: self.wy.anti_wedge(rhs.xyz)
+				+ self.xy.anti_wedge(rhs.yzw)
+				+ self.xyw.anti_wedge(rhs.yz)
+				+ self.xyz.anti_wedge(rhs.wy)
+				+ self.xyzw.anti_wedge(rhs.y)
+				+ self.y.anti_wedge(rhs.xyzw)
+				+ self.yz.anti_wedge(rhs.xyw)
+				+ self.yzw.anti_wedge(rhs.xy),
+			z: self.wz.anti_wedge(rhs.xyz)
+				+ self.xyz.anti_wedge(rhs.wz)
+				+ self.xyzw.anti_wedge(rhs.z)
+				+ self.yz.anti_wedge(rhs.zxw)
+				+ self.yzw.anti_wedge(rhs.zx)
+				+ self.z.anti_wedge(rhs.xyzw)
+				+ self.zx.anti_wedge(rhs.yzw)
+				+ self.zxw.anti_wedge(rhs.yz),
+			w: self.w.anti_wedge(rhs.xyzw)
+				+ self.wx.anti_wedge(rhs.yzw)
+				+ self.wy.anti_wedge(rhs.zxw)
+				+ self.wz.anti_wedge(rhs.xyw)
+				+ self.xyw.anti_wedge(rhs.wz)
+				+ self.xyzw.anti_wedge(rhs.w)
+				+ self.yzw.anti_wedge(rhs.wx)
+				+ self.zxw.anti_wedge(rhs.wy),
+			wx: self.wx.anti_wedge(rhs.xyzw)
+				+ self.xyw.anti_wedge(rhs.zxw)
+				+ self.xyzw.anti_wedge(rhs.wx)
+				+ self.zxw.anti_wedge(rhs.xyw),
+			wy: self.wy.anti_wedge(rhs.xyzw)
+				+ self.xyw.anti_wedge(rhs.yzw)
+				+ self.xyzw.anti_wedge(rhs.wy)
+				+ self.yzw.anti_wedge(rhs.xyw),
+			wz: self.wz.anti_wedge(rhs.xyzw)
+				+ self.xyzw.anti_wedge(rhs.wz)
+				+ self.yzw.anti_wedge(rhs.zxw)
+				+ self.zxw.anti_wedge(rhs.yzw),
+			yz: self.xyz.anti_wedge(rhs.yzw)
+				+ self.xyzw.anti_wedge(rhs.yz)
+				+ self.yz.anti_wedge(rhs.xyzw)
+				+ self.yzw.anti_wedge(rhs.xyz),
+			zx: self.xyz.anti_wedge(rhs.zxw)
+				+ self.xyzw.anti_wedge(rhs.zx)
+				+ self.zx.anti_wedge(rhs.xyzw)
+				+ self.zxw.anti_wedge(rhs.xyz),
+			xy: self.xy.anti_wedge(rhs.xyzw)
+				+ self.xyw.anti_wedge(rhs.xyz)
+				+ self.xyz.anti_wedge(rhs.xyw)
+				+ self.xyzw.anti_wedge(rhs.xy),
+			yzw: self.xyzw.anti_wedge(rhs.yzw) + self.yzw.anti_wedge(rhs.xyzw),
+			zxw: self.xyzw.anti_wedge(rhs.zxw) + self.zxw.anti_wedge(rhs.xyzw),
+			xyw: self.xyw.anti_wedge(rhs.xyzw) + self.xyzw.anti_wedge(rhs.xyw),
+			xyz: self.xyz.anti_wedge(rhs.xyzw) + self.xyzw.anti_wedge(rhs.xyz),
+			xyzw: self.xyzw.anti_wedge(rhs.xyzw),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) - S(self.xyz.0 * rhs.xyz.0) + S(self.y.0 * rhs.y.0) - S(self.yz.0 * rhs.yz.0) + S(self.z.0 * rhs.z.0) - S(self.zx.0 * rhs.zx.0),
+		//     x   : X(self.s.0 * rhs.x.0) - X(self.y.0 * rhs.xy.0) - X(self.yz.0 * rhs.xyz.0) + X(self.z.0 * rhs.zx.0),
+		//     y   : Y(self.s.0 * rhs.y.0) + Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z   : Z(self.s.0 * rhs.z.0) - Z(self.x.0 * rhs.zx.0) - Z(self.xy.0 * rhs.xyz.0) + Z(self.y.0 * rhs.yz.0),
+		//     w   : W(self.s.0 * rhs.w.0) - W(self.x.0 * rhs.wx.0) - W(self.xy.0 * rhs.xyw.0) - W(self.xyz.0 * rhs.xyzw.0) - W(self.y.0 * rhs.wy.0) - W(self.yz.0 * rhs.yzw.0) - W(self.z.0 * rhs.wz.0) - W(self.zx.0 * rhs.zxw.0),
+		//     wx  : WX(self.s.0 * rhs.wx.0) + WX(self.y.0 * rhs.xyw.0) + WX(self.yz.0 * rhs.xyzw.0) - WX(self.z.0 * rhs.zxw.0),
+		//     wy  : WY(self.s.0 * rhs.wy.0) - WY(self.x.0 * rhs.xyw.0) + WY(self.z.0 * rhs.yzw.0) + WY(self.zx.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.s.0 * rhs.wz.0) + WZ(self.x.0 * rhs.zxw.0) + WZ(self.xy.0 * rhs.xyzw.0) - WZ(self.y.0 * rhs.yzw.0),
+		//     yz  : YZ(self.s.0 * rhs.yz.0) + YZ(self.x.0 * rhs.xyz.0),
+		//     zx  : ZX(self.s.0 * rhs.zx.0) + ZX(self.y.0 * rhs.xyz.0),
+		//     xy  : XY(self.s.0 * rhs.xy.0) + XY(self.z.0 * rhs.xyz.0),
+		//     yzw : YZW(self.s.0 * rhs.yzw.0) + YZW(self.x.0 * rhs.xyzw.0),
+		//     zxw : ZXW(self.s.0 * rhs.zxw.0) + ZXW(self.y.0 * rhs.xyzw.0),
+		//     xyw : XYW(self.s.0 * rhs.xyw.0) + XYW(self.z.0 * rhs.xyzw.0),
+		//     xyz : XYZ(self.s.0 * rhs.xyz.0),
+		//     xyzw: XYZW(self.s.0 * rhs.xyzw.0),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s)
+				+ self.x.left_contraction(rhs.x)
+				+ self.xy.left_contraction(rhs.xy)
+				+ self.xyz.left_contraction(rhs.xyz)
+				+ self.y.left_contraction(rhs.y)
+				+ self.yz.left_contraction(rhs.yz)
+				+ self.z.left_contraction(rhs.z)
+				+ self.zx.left_contraction(rhs.zx),
+			x: self.s.left_contraction(rhs.x)
+				+ self.y.left_contraction(rhs.xy)
+				+ self.yz.left_contraction(rhs.xyz)
+				+ self.z.left_contraction(rhs.zx),
+			y: self.s.left_contraction(rhs.y)
+				+ self.x.left_contraction(rhs.xy)
+				+ self.z.left_contraction(rhs.yz)
+				+ self.zx.left_contraction(rhs.xyz),
+			z: self.s.left_contraction(rhs.z)
+				+ self.x.left_contraction(rhs.zx)
+				+ self.xy.left_contraction(rhs.xyz)
+				+ self.y.left_contraction(rhs.yz),
+			w: self.s.left_contraction(rhs.w)
+				+ self.x.left_contraction(rhs.wx)
+				+ self.xy.left_contraction(rhs.xyw)
+				+ self.xyz.left_contraction(rhs.xyzw)
+				+ self.y.left_contraction(rhs.wy)
+				+ self.yz.left_contraction(rhs.yzw)
+				+ self.z.left_contraction(rhs.wz)
+				+ self.zx.left_contraction(rhs.zxw),
+			wx: self.s.left_contraction(rhs.wx)
+				+ self.y.left_contraction(rhs.xyw)
+				+ self.yz.left_contraction(rhs.xyzw)
+				+ self.z.left_contraction(rhs.zxw),
+			wy: self.s.left_contraction(rhs.wy)
+				+ self.x.left_contraction(rhs.xyw)
+				+ self.z.left_contraction(rhs.yzw)
+				+ self.zx.left_contraction(rhs.xyzw),
+			wz: self.s.left_contraction(rhs.wz)
+				+ self.x.left_contraction(rhs.zxw)
+				+ self.xy.left_contraction(rhs.xyzw)
+				+ self.y.left_contraction(rhs.yzw),
+			yz: self.s.left_contraction(rhs.yz) + self.x.left_contraction(rhs.xyz),
+			zx: self.s.left_contraction(rhs.zx) + self.y.left_contraction(rhs.xyz),
+			xy: self.s.left_contraction(rhs.xy) + self.z.left_contraction(rhs.xyz),
+			yzw: self.s.left_contraction(rhs.yzw) + self.x.left_contraction(rhs.xyzw),
+			zxw: self.s.left_contraction(rhs.zxw) + self.y.left_contraction(rhs.xyzw),
+			xyw: self.s.left_contraction(rhs.xyw) + self.z.left_contraction(rhs.xyzw),
+			xyz: self.s.left_contraction(rhs.xyz),
+			xyzw: self.s.left_contraction(rhs.xyzw),
+		}
+	}
+}
+
+// Multivector.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) - S(self.xyz.0 * rhs.xyz.0) + S(self.y.0 * rhs.y.0) - S(self.yz.0 * rhs.yz.0) + S(self.z.0 * rhs.z.0) - S(self.zx.0 * rhs.zx.0),
+		//     x   : X(self.x.0 * rhs.s.0) + X(self.xy.0 * rhs.y.0) - X(self.xyz.0 * rhs.yz.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : -Y(self.xy.0 * rhs.x.0) - Y(self.xyz.0 * rhs.zx.0) + Y(self.y.0 * rhs.s.0) + Y(self.yz.0 * rhs.z.0),
+		//     z   : -Z(self.xyz.0 * rhs.xy.0) - Z(self.yz.0 * rhs.y.0) + Z(self.z.0 * rhs.s.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.w.0 * rhs.s.0) + W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0) - W(self.xyw.0 * rhs.xy.0) + W(self.xyzw.0 * rhs.xyz.0) - W(self.yzw.0 * rhs.yz.0) - W(self.zxw.0 * rhs.zx.0),
+		//     wx  : WX(self.wx.0 * rhs.s.0) + WX(self.xyw.0 * rhs.y.0) + WX(self.xyzw.0 * rhs.yz.0) - WX(self.zxw.0 * rhs.z.0),
+		//     wy  : WY(self.wy.0 * rhs.s.0) - WY(self.xyw.0 * rhs.x.0) + WY(self.xyzw.0 * rhs.zx.0) + WY(self.yzw.0 * rhs.z.0),
+		//     wz  : WZ(self.wz.0 * rhs.s.0) + WZ(self.xyzw.0 * rhs.xy.0) - WZ(self.yzw.0 * rhs.y.0) + WZ(self.zxw.0 * rhs.x.0),
+		//     yz  : YZ(self.xyz.0 * rhs.x.0) + YZ(self.yz.0 * rhs.s.0),
+		//     zx  : ZX(self.xyz.0 * rhs.y.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy  : XY(self.xy.0 * rhs.s.0) + XY(self.xyz.0 * rhs.z.0),
+		//     yzw : -YZW(self.xyzw.0 * rhs.x.0) + YZW(self.yzw.0 * rhs.s.0),
+		//     zxw : -ZXW(self.xyzw.0 * rhs.y.0) + ZXW(self.zxw.0 * rhs.s.0),
+		//     xyw : XYW(self.xyw.0 * rhs.s.0) - XYW(self.xyzw.0 * rhs.z.0),
+		//     xyz : XYZ(self.xyz.0 * rhs.s.0),
+		//     xyzw: XYZW(self.xyzw.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s)
+				+ self.x.right_contraction(rhs.x)
+				+ self.xy.right_contraction(rhs.xy)
+				+ self.xyz.right_contraction(rhs.xyz)
+				+ self.y.right_contraction(rhs.y)
+				+ self.yz.right_contraction(rhs.yz)
+				+ self.z.right_contraction(rhs.z)
+				+ self.zx.right_contraction(rhs.zx),
+			x: self.x.right_contraction(rhs.s)
+				+ self.xy.right_contraction(rhs.y)
+				+ self.xyz.right_contraction(rhs.yz)
+				+ self.zx.right_contraction(rhs.z),
+			y: self.xy.right_contraction(rhs.x)
+				+ self.xyz.right_contraction(rhs.zx)
+				+ self.y.right_contraction(rhs.s)
+				+ self.yz.right_contraction(rhs.z),
+			z: self.xyz.right_contraction(rhs.xy)
+				+ self.yz.right_contraction(rhs.y)
+				+ self.z.right_contraction(rhs.s)
+				+ self.zx.right_contraction(rhs.x),
+			w: self.w.right_contraction(rhs.s)
+				+ self.wx.right_contraction(rhs.x)
+				+ self.wy.right_contraction(rhs.y)
+				+ self.wz.right_contraction(rhs.z)
+				+ self.xyw.right_contraction(rhs.xy)
+				+ self.xyzw.right_contraction(rhs.xyz)
+				+ self.yzw.right_contraction(rhs.yz)
+				+ self.zxw.right_contraction(rhs.zx),
+			wx: self.wx.right_contraction(rhs.s)
+				+ self.xyw.right_contraction(rhs.y)
+				+ self.xyzw.right_contraction(rhs.yz)
+				+ self.zxw.right_contraction(rhs.z),
+			wy: self.wy.right_contraction(rhs.s)
+				+ self.xyw.right_contraction(rhs.x)
+				+ self.xyzw.right_contraction(rhs.zx)
+				+ self.yzw.right_contraction(rhs.z),
+			wz: self.wz.right_contraction(rhs.s)
+				+ self.xyzw.right_contraction(rhs.xy)
+				+ self.yzw.right_contraction(rhs.y)
+				+ self.zxw.right_contraction(rhs.x),
+			yz: self.xyz.right_contraction(rhs.x) + self.yz.right_contraction(rhs.s),
+			zx: self.xyz.right_contraction(rhs.y) + self.zx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s) + self.xyz.right_contraction(rhs.z),
+			yzw: self.xyzw.right_contraction(rhs.x) + self.yzw.right_contraction(rhs.s),
+			zxw: self.xyzw.right_contraction(rhs.y) + self.zxw.right_contraction(rhs.s),
+			xyw: self.xyw.right_contraction(rhs.s) + self.xyzw.right_contraction(rhs.z),
+			xyz: self.xyz.right_contraction(rhs.s),
+			xyzw: self.xyzw.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Multivector.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) - S(self.xyz.0 * rhs.xyz.0) + S(self.y.0 * rhs.y.0) - S(self.yz.0 * rhs.yz.0) + S(self.z.0 * rhs.z.0) - S(self.zx.0 * rhs.zx.0)
+		self.s.scalar_product(rhs.s)
+			+ self.x.scalar_product(rhs.x)
+			+ self.xy.scalar_product(rhs.xy)
+			+ self.xyz.scalar_product(rhs.xyz)
+			+ self.y.scalar_product(rhs.y)
+			+ self.yz.scalar_product(rhs.yz)
+			+ self.z.scalar_product(rhs.z)
+			+ self.zx.scalar_product(rhs.zx)
+	}
+}
+
+// Multivector.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.xy.0 * rhs.y.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0) - X(self.zx.0 * rhs.z.0),
+		//     y   : Y(self.x.0 * rhs.xy.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0) - Y(self.z.0 * rhs.yz.0),
+		//     z   : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     w   : W(self.wx.0 * rhs.x.0) + W(self.wy.0 * rhs.y.0) + W(self.wz.0 * rhs.z.0) - W(self.x.0 * rhs.wx.0) - W(self.xyz.0 * rhs.xyzw.0) + W(self.xyzw.0 * rhs.xyz.0) - W(self.y.0 * rhs.wy.0) - W(self.z.0 * rhs.wz.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) - WX(self.wy.0 * rhs.xy.0) + WX(self.wz.0 * rhs.zx.0) - WX(self.x.0 * rhs.w.0) + WX(self.xy.0 * rhs.wy.0) + WX(self.xyz.0 * rhs.yzw.0) - WX(self.yzw.0 * rhs.xyz.0) - WX(self.zx.0 * rhs.wz.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) + WY(self.wx.0 * rhs.xy.0) - WY(self.wz.0 * rhs.yz.0) - WY(self.xy.0 * rhs.wx.0) + WY(self.xyz.0 * rhs.zxw.0) - WY(self.y.0 * rhs.w.0) + WY(self.yz.0 * rhs.wz.0) - WY(self.zxw.0 * rhs.xyz.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) - WZ(self.wx.0 * rhs.zx.0) + WZ(self.wy.0 * rhs.yz.0) - WZ(self.xyw.0 * rhs.xyz.0) + WZ(self.xyz.0 * rhs.xyw.0) - WZ(self.yz.0 * rhs.wy.0) - WZ(self.z.0 * rhs.w.0) + WZ(self.zx.0 * rhs.wx.0),
+		//     yz  : YZ(self.xy.0 * rhs.zx.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) - ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		//     yzw : YZW(self.wx.0 * rhs.xyz.0) + YZW(self.x.0 * rhs.xyzw.0) + YZW(self.xy.0 * rhs.zxw.0) + YZW(self.xyw.0 * rhs.zx.0) - YZW(self.xyz.0 * rhs.wx.0) - YZW(self.xyzw.0 * rhs.x.0) - YZW(self.zx.0 * rhs.xyw.0) - YZW(self.zxw.0 * rhs.xy.0),
+		//     zxw : ZXW(self.wy.0 * rhs.xyz.0) - ZXW(self.xy.0 * rhs.yzw.0) - ZXW(self.xyw.0 * rhs.yz.0) - ZXW(self.xyz.0 * rhs.wy.0) - ZXW(self.xyzw.0 * rhs.y.0) + ZXW(self.y.0 * rhs.xyzw.0) + ZXW(self.yz.0 * rhs.xyw.0) + ZXW(self.yzw.0 * rhs.xy.0),
+		//     xyw : XYW(self.wz.0 * rhs.xyz.0) - XYW(self.xyz.0 * rhs.wz.0) - XYW(self.xyzw.0 * rhs.z.0) - XYW(self.yz.0 * rhs.zxw.0) - XYW(self.yzw.0 * rhs.zx.0) + XYW(self.z.0 * rhs.xyzw.0) + XYW(self.zx.0 * rhs.yzw.0) + XYW(self.zxw.0 * rhs.yz.0),
+		//     xyz : Default::default(),
+		//     xyzw: -XYZW(self.w.0 * rhs.xyz.0) + XYZW(self.x.0 * rhs.yzw.0) - XYZW(self.xyw.0 * rhs.z.0) + XYZW(self.xyz.0 * rhs.w.0) + XYZW(self.y.0 * rhs.zxw.0) - XYZW(self.yzw.0 * rhs.x.0) + XYZW(self.z.0 * rhs.xyw.0) - XYZW(self.zxw.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y)
+				+ self.y.commutator(rhs.xy)
+				+ self.z.commutator(rhs.zx)
+				+ self.zx.commutator(rhs.z),
+			y: self.x.commutator(rhs.xy)
+				+ self.xy.commutator(rhs.x)
+				+ self.yz.commutator(rhs.z)
+				+ self.z.commutator(rhs.yz),
+			z: self.x.commutator(rhs.zx)
+				+ self.y.commutator(rhs.yz)
+				+ self.yz.commutator(rhs.y)
+				+ self.zx.commutator(rhs.x),
+			w: self.wx.commutator(rhs.x)
+				+ self.wy.commutator(rhs.y)
+				+ self.wz.commutator(rhs.z)
+				+ self.x.commutator(rhs.wx)
+				+ self.xyz.commutator(rhs.xyzw)
+				+ self.xyzw.commutator(rhs.xyz)
+				+ self.y.commutator(rhs.wy)
+				+ self.z.commutator(rhs.wz),
+			wx: self.w.commutator(rhs.x)
+				+ self.wy.commutator(rhs.xy)
+				+ self.wz.commutator(rhs.zx)
+				+ self.x.commutator(rhs.w)
+				+ self.xy.commutator(rhs.wy)
+				+ self.xyz.commutator(rhs.yzw)
+				+ self.yzw.commutator(rhs.xyz)
+				+ self.zx.commutator(rhs.wz),
+			wy: self.w.commutator(rhs.y)
+				+ self.wx.commutator(rhs.xy)
+				+ self.wz.commutator(rhs.yz)
+				+ self.xy.commutator(rhs.wx)
+				+ self.xyz.commutator(rhs.zxw)
+				+ self.y.commutator(rhs.w)
+				+ self.yz.commutator(rhs.wz)
+				+ self.zxw.commutator(rhs.xyz),
+			wz: self.w.commutator(rhs.z)
+				+ self.wx.commutator(rhs.zx)
+				+ self.wy.commutator(rhs.yz)
+				+ self.xyw.commutator(rhs.xyz)
+				+ self.xyz.commutator(rhs.xyw)
+				+ self.yz.commutator(rhs.wy)
+				+ self.z.commutator(rhs.w)
+				+ self.zx.commutator(rhs.wx),
+			yz: self.xy.commutator(rhs.zx)
+				+ self.y.commutator(rhs.z)
+				+ self.z.commutator(rhs.y)
+				+ self.zx.commutator(rhs.xy),
+			zx: self.x.commutator(rhs.z)
+				+ self.xy.commutator(rhs.yz)
+				+ self.yz.commutator(rhs.xy)
+				+ self.z.commutator(rhs.x),
+			xy: self.x.commutator(rhs.y)
+				+ self.y.commutator(rhs.x)
+				+ self.yz.commutator(rhs.zx)
+				+ self.zx.commutator(rhs.yz),
+			yzw: self.wx.commutator(rhs.xyz)
+				+ self.x.commutator(rhs.xyzw)
+				+ self.xy.commutator(rhs.zxw)
+				+ self.xyw.commutator(rhs.zx)
+				+ self.xyz.commutator(rhs.wx)
+				+ self.xyzw.commutator(rhs.x)
+				+ self.zx.commutator(rhs.xyw)
+				+ self.zxw.commutator(rhs.xy),
+			zxw: self.wy.commutator(rhs.xyz)
+				+ self.xy.commutator(rhs.yzw)
+				+ self.xyw.commutator(rhs.yz)
+				+ self.xyz.commutator(rhs.wy)
+				+ self.xyzw.commutator(rhs.y)
+				+ self.y.commutator(rhs.xyzw)
+				+ self.yz.commutator(rhs.xyw)
+				+ self.yzw.commutator(rhs.xy),
+			xyw: self.wz.commutator(rhs.xyz)
+				+ self.xyz.commutator(rhs.wz)
+				+ self.xyzw.commutator(rhs.z)
+				+ self.yz.commutator(rhs.zxw)
+				+ self.yzw.commutator(rhs.zx)
+				+ self.z.commutator(rhs.xyzw)
+				+ self.zx.commutator(rhs.yzw)
+				+ self.zxw.commutator(rhs.yz),
+			xyz: Default::default(),
+			xyzw: self.w.commutator(rhs.xyz)
+				+ self.x.commutator(rhs.yzw)
+				+ self.xyw.commutator(rhs.z)
+				+ self.xyz.commutator(rhs.w)
+				+ self.y.commutator(rhs.zxw)
+				+ self.yzw.commutator(rhs.x)
+				+ self.z.commutator(rhs.xyw)
+				+ self.zxw.commutator(rhs.y),
+		}
+	}
+}
+
+// Multivector.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.s.0 * rhs.xyzw.0) + S(self.xyzw.0 * rhs.s.0),
+		//     x   : X(self.s.0 * rhs.yzw.0) + X(self.x.0 * rhs.xyzw.0) + X(self.xyzw.0 * rhs.x.0) - X(self.yzw.0 * rhs.s.0),
+		//     y   : Y(self.s.0 * rhs.zxw.0) + Y(self.xyzw.0 * rhs.y.0) + Y(self.y.0 * rhs.xyzw.0) - Y(self.zxw.0 * rhs.s.0),
+		//     z   : Z(self.s.0 * rhs.xyw.0) - Z(self.xyw.0 * rhs.s.0) + Z(self.xyzw.0 * rhs.z.0) + Z(self.z.0 * rhs.xyzw.0),
+		//     w   : W(self.w.0 * rhs.xyzw.0) + W(self.xyzw.0 * rhs.w.0),
+		//     wx  : -WX(self.w.0 * rhs.yzw.0) + WX(self.wx.0 * rhs.xyzw.0) + WX(self.xyzw.0 * rhs.wx.0) - WX(self.yzw.0 * rhs.w.0),
+		//     wy  : -WY(self.w.0 * rhs.zxw.0) + WY(self.wy.0 * rhs.xyzw.0) + WY(self.xyzw.0 * rhs.wy.0) - WY(self.zxw.0 * rhs.w.0),
+		//     wz  : -WZ(self.w.0 * rhs.xyw.0) + WZ(self.wz.0 * rhs.xyzw.0) - WZ(self.xyw.0 * rhs.w.0) + WZ(self.xyzw.0 * rhs.wz.0),
+		//     yz  : YZ(self.s.0 * rhs.wx.0) + YZ(self.wx.0 * rhs.s.0) - YZ(self.xyw.0 * rhs.y.0) + YZ(self.xyzw.0 * rhs.yz.0) - YZ(self.y.0 * rhs.xyw.0) + YZ(self.yz.0 * rhs.xyzw.0) + YZ(self.z.0 * rhs.zxw.0) + YZ(self.zxw.0 * rhs.z.0),
+		//     zx  : ZX(self.s.0 * rhs.wy.0) + ZX(self.wy.0 * rhs.s.0) + ZX(self.x.0 * rhs.xyw.0) + ZX(self.xyw.0 * rhs.x.0) + ZX(self.xyzw.0 * rhs.zx.0) - ZX(self.yzw.0 * rhs.z.0) - ZX(self.z.0 * rhs.yzw.0) + ZX(self.zx.0 * rhs.xyzw.0),
+		//     xy  : XY(self.s.0 * rhs.wz.0) + XY(self.wz.0 * rhs.s.0) - XY(self.x.0 * rhs.zxw.0) + XY(self.xy.0 * rhs.xyzw.0) + XY(self.xyzw.0 * rhs.xy.0) + XY(self.y.0 * rhs.yzw.0) + XY(self.yzw.0 * rhs.y.0) - XY(self.zxw.0 * rhs.x.0),
+		//     yzw : YZW(self.w.0 * rhs.wx.0) + YZW(self.wx.0 * rhs.w.0) + YZW(self.wy.0 * rhs.xyw.0) - YZW(self.wz.0 * rhs.zxw.0) - YZW(self.xyw.0 * rhs.wy.0) + YZW(self.xyzw.0 * rhs.yzw.0) + YZW(self.yzw.0 * rhs.xyzw.0) + YZW(self.zxw.0 * rhs.wz.0),
+		//     zxw : ZXW(self.w.0 * rhs.wy.0) - ZXW(self.wx.0 * rhs.xyw.0) + ZXW(self.wy.0 * rhs.w.0) + ZXW(self.wz.0 * rhs.yzw.0) + ZXW(self.xyw.0 * rhs.wx.0) + ZXW(self.xyzw.0 * rhs.zxw.0) - ZXW(self.yzw.0 * rhs.wz.0) + ZXW(self.zxw.0 * rhs.xyzw.0),
+		//     xyw : XYW(self.w.0 * rhs.wz.0) + XYW(self.wx.0 * rhs.zxw.0) - XYW(self.wy.0 * rhs.yzw.0) + XYW(self.wz.0 * rhs.w.0) + XYW(self.xyw.0 * rhs.xyzw.0) + XYW(self.xyzw.0 * rhs.xyw.0) + XYW(self.yzw.0 * rhs.wy.0) - XYW(self.zxw.0 * rhs.wx.0),
+		//     xyz : -XYZ(self.s.0 * rhs.w.0) + XYZ(self.w.0 * rhs.s.0) + XYZ(self.wx.0 * rhs.x.0) + XYZ(self.wy.0 * rhs.y.0) + XYZ(self.wz.0 * rhs.z.0) + XYZ(self.x.0 * rhs.wx.0) + XYZ(self.xy.0 * rhs.xyw.0) - XYZ(self.xyw.0 * rhs.xy.0) + XYZ(self.xyz.0 * rhs.xyzw.0) + XYZ(self.xyzw.0 * rhs.xyz.0) + XYZ(self.y.0 * rhs.wy.0) + XYZ(self.yz.0 * rhs.yzw.0) - XYZ(self.yzw.0 * rhs.yz.0) + XYZ(self.z.0 * rhs.wz.0) + XYZ(self.zx.0 * rhs.zxw.0) - XYZ(self.zxw.0 * rhs.zx.0),
+		//     xyzw: -XYZW(self.w.0 * rhs.w.0) - XYZW(self.wx.0 * rhs.wx.0) - XYZW(self.wy.0 * rhs.wy.0) - XYZW(self.wz.0 * rhs.wz.0) + XYZW(self.xyw.0 * rhs.xyw.0) + XYZW(self.xyzw.0 * rhs.xyzw.0) + XYZW(self.yzw.0 * rhs.yzw.0) + XYZW(self.zxw.0 * rhs.zxw.0),
+		// }
+		Multivector {
+			s: self.s.anti_dot(rhs.xyzw) + self.xyzw.anti_dot(rhs.s),
+			x: self.s.anti_dot(rhs.yzw)
+				+ self.x.anti_dot(rhs.xyzw)
+				+ self.xyzw.anti_dot(rhs.x)
+				+ self.yzw.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.zxw)
+				+ self.xyzw.anti_dot(rhs.y)
+				+ self.y.anti_dot(rhs.xyzw)
+				+ self.zxw.anti_dot(rhs.s),
+			z: self.s.anti_dot(rhs.xyw)
+				+ self.xyw.anti_dot(rhs.s)
+				+ self.xyzw.anti_dot(rhs.z)
+				+ self.z.anti_dot(rhs.xyzw),
+			w: self.w.anti_dot(rhs.xyzw) + self.xyzw.anti_dot(rhs.w),
+			wx: self.w.anti_dot(rhs.yzw)
+				+ self.wx.anti_dot(rhs.xyzw)
+				+ self.xyzw.anti_dot(rhs.wx)
+				+ self.yzw.anti_dot(rhs.w),
+			wy: self.w.anti_dot(rhs.zxw)
+				+ self.wy.anti_dot(rhs.xyzw)
+				+ self.xyzw.anti_dot(rhs.wy)
+				+ self.zxw.anti_dot(rhs.w),
+			wz: self.w.anti_dot(rhs.xyw)
+				+ self.wz.anti_dot(rhs.xyzw)
+				+ self.xyw.anti_dot(rhs.w)
+				+ self.xyzw.anti_dot(rhs.wz),
+			yz: self.s.anti_dot(rhs.wx)
+				+ self.wx.anti_dot(rhs.s)
+				+ self.xyw.anti_dot(rhs.y)
+				+ self.xyzw.anti_dot(rhs.yz)
+				+ self.y.anti_dot(rhs.xyw)
+				+ self.yz.anti_dot(rhs.xyzw)
+				+ self.z.anti_dot(rhs.zxw)
+				+ self.zxw.anti_dot(rhs.z),
+			zx: self.s.anti_dot(rhs.wy)
+				+ self.wy.anti_dot(rhs.s)
+				+ self.x.anti_dot(rhs.xyw)
+				+ self.xyw.anti_dot(rhs.x)
+				+ self.xyzw.anti_dot(rhs.zx)
+				+ self.yzw.anti_dot(rhs.z)
+				+ self.z.anti_dot(rhs.yzw)
+				+ self.zx.anti_dot(rhs.xyzw),
+			xy: self.s.anti_dot(rhs.wz)
+				+ self.wz.anti_dot(rhs.s)
+				+ self.x.anti_dot(rhs.zxw)
+				+ self.xy.anti_dot(rhs.xyzw)
+				+ self.xyzw.anti_dot(rhs.xy)
+				+ self.y.anti_dot(rhs.yzw)
+				+ self.yzw.anti_dot(rhs.y)
+				+ self.zxw.anti_dot(rhs.x),
+			yzw: self.w.anti_dot(rhs.wx)
+				+ self.wx.anti_dot(rhs.w)
+				+ self.wy.anti_dot(rhs.xyw)
+				+ self.wz.anti_dot(rhs.zxw)
+				+ self.xyw.anti_dot(rhs.wy)
+				+ self.xyzw.anti_dot(rhs.yzw)
+				+ self.yzw.anti_dot(rhs.xyzw)
+				+ self.zxw.anti_dot(rhs.wz),
+			zxw: self.w.anti_dot(rhs.wy)
+				+ self.wx.anti_dot(rhs.xyw)
+				+ self.wy.anti_dot(rhs.w)
+				+ self.wz.anti_dot(rhs.yzw)
+				+ self.xyw.anti_dot(rhs.wx)
+				+ self.xyzw.anti_dot(rhs.zxw)
+				+ self.yzw.anti_dot(rhs.wz)
+				+ self.zxw.anti_dot(rhs.xyzw),
+			xyw: self.w.anti_dot(rhs.wz)
+				+ self.wx.anti_dot(rhs.zxw)
+				+ self.wy.anti_dot(rhs.yzw)
+				+ self.wz.anti_dot(rhs.w)
+				+ self.xyw.anti_dot(rhs.xyzw)
+				+ self.xyzw.anti_dot(rhs.xyw)
+				+ self.yzw.anti_dot(rhs.wy)
+				+ self.zxw.anti_dot(rhs.wx),
+			xyz: self.s.anti_dot(rhs.w)
+				+ self.w.anti_dot(rhs.s)
+				+ self.wx.anti_dot(rhs.x)
+				+ self.wy.anti_dot(rhs.y)
+				+ self.wz.anti_dot(rhs.z)
+				+ self.x.anti_dot(rhs.wx)
+				+ self.xy.anti_dot(rhs.xyw)
+				+ self.xyw.anti_dot(rhs.xy)
+				+ self.xyz.anti_dot(rhs.xyzw)
+				+ self.xyzw.anti_dot(rhs.xyz)
+				+ self.y.anti_dot(rhs.wy)
+				+ self.yz.anti_dot(rhs.yzw)
+				+ self.yzw.anti_dot(rhs.yz)
+				+ self.z.anti_dot(rhs.wz)
+				+ self.zx.anti_dot(rhs.zxw)
+				+ self.zxw.anti_dot(rhs.zx),
+			xyzw: self.w.anti_dot(rhs.w)
+				+ self.wx.anti_dot(rhs.wx)
+				+ self.wy.anti_dot(rhs.wy)
+				+ self.wz.anti_dot(rhs.wz)
+				+ self.xyw.anti_dot(rhs.xyw)
+				+ self.xyzw.anti_dot(rhs.xyzw)
+				+ self.yzw.anti_dot(rhs.yzw)
+				+ self.zxw.anti_dot(rhs.zxw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Multivector.sandwich(Vec3)  (not a Vec3)
+// Omitted: Multivector.anti_sandwich(Vec3)  (not a Vec3)
+// Omitted: Multivector.sandwich(Vec4)  (not a Vec4)
+// Omitted: Multivector.anti_sandwich(Vec4)  (not a Vec4)
+// Omitted: Multivector.sandwich(Point3)  (not a Point3)
+// Omitted: Multivector.anti_sandwich(Point3)  (not a Point3)
+// Omitted: Multivector.sandwich(Moment3)  (not a Moment3)
+// Omitted: Multivector.anti_sandwich(Moment3)  (not a Moment3)
+// Omitted: Multivector.sandwich(Line3)  (not a Line3)
+// Omitted: Multivector.anti_sandwich(Line3)  (not a Line3)
+// Omitted: Multivector.sandwich(Plane)  (not a Plane)
+// Omitted: Multivector.anti_sandwich(Plane)  (not a Plane)
@@ -0,0 +1,1061 @@
+/* The C ABI of the `pga3d` geometric algebra, generated together with its Rust code.
+ * All values are passed by value, and the structs have the same layout as in Rust. */
+
+#ifndef PGA3D_H
+#define PGA3D_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct pga3d_Vec3 {
+	double x; /* X */
+	double y; /* Y */
+	double z; /* Z */
+} pga3d_Vec3;
+
+typedef struct pga3d_Vec4 {
+	double x; /* X */
+	double y; /* Y */
+	double z; /* Z */
+	double w; /* W */
+} pga3d_Vec4;
+
+typedef struct pga3d_Point3 {
+	double x; /* X */
+	double y; /* Y */
+	double z; /* Z */
+	/* w: W = 1 */
+} pga3d_Point3;
+
+typedef struct pga3d_Moment3 {
+	double mx; /* YZ */
+	double my; /* ZX */
+	double mz; /* XY */
+} pga3d_Moment3;
+
+typedef struct pga3d_Line3 {
+	double vx; /* WX */
+	double vy; /* WY */
+	double vz; /* WZ */
+	double mx; /* YZ */
+	double my; /* ZX */
+	double mz; /* XY */
+} pga3d_Line3;
+
+typedef struct pga3d_Plane {
+	double nx; /* YZW */
+	double ny; /* ZXW */
+	double nz; /* XYW */
+	double d; /* XYZ */
+} pga3d_Plane;
+
+typedef struct pga3d_Translator3 {
+	double x; /* YZ */
+	double y; /* ZX */
+	double z; /* XY */
+	double w; /* XYZW */
+} pga3d_Translator3;
+
+typedef struct pga3d_Rotor3 {
+	double x; /* WX */
+	double y; /* WY */
+	double z; /* WZ */
+	double w; /* XYZW */
+} pga3d_Rotor3;
+
+typedef struct pga3d_Motor3 {
+	double rx; /* WX */
+	double ry; /* WY */
+	double rz; /* WZ */
+	double rw; /* XYZW */
+	double ux; /* YZ */
+	double uy; /* ZX */
+	double uz; /* XY */
+	double uw; /* S */
+} pga3d_Motor3;
+
+typedef struct pga3d_Multivector {
+	double s; /* S */
+	double x; /* X */
+	double y; /* Y */
+	double z; /* Z */
+	double w; /* W */
+	double wx; /* WX */
+	double wy; /* WY */
+	double wz; /* WZ */
+	double yz; /* YZ */
+	double zx; /* ZX */
+	double xy; /* XY */
+	double yzw; /* YZW */
+	double zxw; /* ZXW */
+	double xyw; /* XYW */
+	double xyz; /* XYZ */
+	double xyzw; /* XYZW */
+} pga3d_Multivector;
+
+pga3d_Plane pga3d_vec3_rcompl(pga3d_Vec3 value);
+pga3d_Plane pga3d_vec3_lcompl(pga3d_Vec3 value);
+pga3d_Vec3 pga3d_vec3_rev(pga3d_Vec3 value);
+pga3d_Vec3 pga3d_vec3_arev(pga3d_Vec3 value);
+pga3d_Vec3 pga3d_vec3_involute(pga3d_Vec3 value);
+pga3d_Vec3 pga3d_vec3_conjugate(pga3d_Vec3 value);
+pga3d_Plane pga3d_vec3_dual(pga3d_Vec3 value);
+pga3d_Vec3 pga3d_vec3_bulk(pga3d_Vec3 value);
+pga3d_Plane pga3d_vec3_bulk_dual(pga3d_Vec3 value);
+pga3d_Motor3 pga3d_vec3_geometric_vec3(pga3d_Vec3 lhs, pga3d_Vec3 rhs);
+double pga3d_vec3_dot_vec3(pga3d_Vec3 lhs, pga3d_Vec3 rhs);
+pga3d_Moment3 pga3d_vec3_wedge_vec3(pga3d_Vec3 lhs, pga3d_Vec3 rhs);
+double pga3d_vec3_left_contraction_vec3(pga3d_Vec3 lhs, pga3d_Vec3 rhs);
+double pga3d_vec3_right_contraction_vec3(pga3d_Vec3 lhs, pga3d_Vec3 rhs);
+double pga3d_vec3_scalar_product_vec3(pga3d_Vec3 lhs, pga3d_Vec3 rhs);
+pga3d_Moment3 pga3d_vec3_commutator_vec3(pga3d_Vec3 lhs, pga3d_Vec3 rhs);
+pga3d_Motor3 pga3d_vec3_geometric_vec4(pga3d_Vec3 lhs, pga3d_Vec4 rhs);
+pga3d_Moment3 pga3d_vec3_anti_geometric_vec4(pga3d_Vec3 lhs, pga3d_Vec4 rhs);
+double pga3d_vec3_dot_vec4(pga3d_Vec3 lhs, pga3d_Vec4 rhs);
+pga3d_Line3 pga3d_vec3_wedge_vec4(pga3d_Vec3 lhs, pga3d_Vec4 rhs);
+double pga3d_vec3_left_contraction_vec4(pga3d_Vec3 lhs, pga3d_Vec4 rhs);
+double pga3d_vec3_right_contraction_vec4(pga3d_Vec3 lhs, pga3d_Vec4 rhs);
+double pga3d_vec3_scalar_product_vec4(pga3d_Vec3 lhs, pga3d_Vec4 rhs);
+pga3d_Line3 pga3d_vec3_commutator_vec4(pga3d_Vec3 lhs, pga3d_Vec4 rhs);
+pga3d_Motor3 pga3d_vec3_geometric_point3(pga3d_Vec3 lhs, pga3d_Point3 rhs);
+pga3d_Moment3 pga3d_vec3_anti_geometric_point3(pga3d_Vec3 lhs, pga3d_Point3 rhs);
+double pga3d_vec3_dot_point3(pga3d_Vec3 lhs, pga3d_Point3 rhs);
+pga3d_Line3 pga3d_vec3_wedge_point3(pga3d_Vec3 lhs, pga3d_Point3 rhs);
+double pga3d_vec3_left_contraction_point3(pga3d_Vec3 lhs, pga3d_Point3 rhs);
+double pga3d_vec3_right_contraction_point3(pga3d_Vec3 lhs, pga3d_Point3 rhs);
+double pga3d_vec3_scalar_product_point3(pga3d_Vec3 lhs, pga3d_Point3 rhs);
+pga3d_Line3 pga3d_vec3_commutator_point3(pga3d_Vec3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_vec3_geometric_moment3(pga3d_Vec3 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_vec3_dot_moment3(pga3d_Vec3 lhs, pga3d_Moment3 rhs);
+double pga3d_vec3_wedge_moment3(pga3d_Vec3 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_vec3_left_contraction_moment3(pga3d_Vec3 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_vec3_commutator_moment3(pga3d_Vec3 lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_vec3_geometric_line3(pga3d_Vec3 lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_vec3_anti_geometric_line3(pga3d_Vec3 lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_vec3_dot_line3(pga3d_Vec3 lhs, pga3d_Line3 rhs);
+pga3d_Plane pga3d_vec3_wedge_line3(pga3d_Vec3 lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_vec3_left_contraction_line3(pga3d_Vec3 lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_vec3_commutator_line3(pga3d_Vec3 lhs, pga3d_Line3 rhs);
+double pga3d_vec3_anti_dot_line3(pga3d_Vec3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_vec3_geometric_plane(pga3d_Vec3 lhs, pga3d_Plane rhs);
+pga3d_Motor3 pga3d_vec3_anti_geometric_plane(pga3d_Vec3 lhs, pga3d_Plane rhs);
+pga3d_Line3 pga3d_vec3_dot_plane(pga3d_Vec3 lhs, pga3d_Plane rhs);
+double pga3d_vec3_wedge_plane(pga3d_Vec3 lhs, pga3d_Plane rhs);
+double pga3d_vec3_anti_wedge_plane(pga3d_Vec3 lhs, pga3d_Plane rhs);
+pga3d_Line3 pga3d_vec3_left_contraction_plane(pga3d_Vec3 lhs, pga3d_Plane rhs);
+double pga3d_vec3_commutator_plane(pga3d_Vec3 lhs, pga3d_Plane rhs);
+pga3d_Moment3 pga3d_vec3_anti_dot_plane(pga3d_Vec3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_vec3_geometric_translator3(pga3d_Vec3 lhs, pga3d_Translator3 rhs);
+pga3d_Vec3 pga3d_vec3_anti_geometric_translator3(pga3d_Vec3 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_vec3_dot_translator3(pga3d_Vec3 lhs, pga3d_Translator3 rhs);
+double pga3d_vec3_wedge_translator3(pga3d_Vec3 lhs, pga3d_Translator3 rhs);
+pga3d_Vec3 pga3d_vec3_anti_wedge_translator3(pga3d_Vec3 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_vec3_left_contraction_translator3(pga3d_Vec3 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_vec3_commutator_translator3(pga3d_Vec3 lhs, pga3d_Translator3 rhs);
+pga3d_Vec3 pga3d_vec3_anti_dot_translator3(pga3d_Vec3 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_vec3_geometric_rotor3(pga3d_Vec3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec3_anti_geometric_rotor3(pga3d_Vec3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec3_dot_rotor3(pga3d_Vec3 lhs, pga3d_Rotor3 rhs);
+pga3d_Plane pga3d_vec3_wedge_rotor3(pga3d_Vec3 lhs, pga3d_Rotor3 rhs);
+pga3d_Vec3 pga3d_vec3_anti_wedge_rotor3(pga3d_Vec3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec3_left_contraction_rotor3(pga3d_Vec3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec3_commutator_rotor3(pga3d_Vec3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec3_anti_dot_rotor3(pga3d_Vec3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec3_geometric_motor3(pga3d_Vec3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec3_anti_geometric_motor3(pga3d_Vec3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec3_dot_motor3(pga3d_Vec3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec3_wedge_motor3(pga3d_Vec3 lhs, pga3d_Motor3 rhs);
+pga3d_Vec3 pga3d_vec3_anti_wedge_motor3(pga3d_Vec3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec3_left_contraction_motor3(pga3d_Vec3 lhs, pga3d_Motor3 rhs);
+pga3d_Vec3 pga3d_vec3_right_contraction_motor3(pga3d_Vec3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec3_commutator_motor3(pga3d_Vec3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec3_anti_dot_motor3(pga3d_Vec3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec3_geometric_multivector(pga3d_Vec3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec3_anti_geometric_multivector(pga3d_Vec3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec3_dot_multivector(pga3d_Vec3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec3_wedge_multivector(pga3d_Vec3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec3_anti_wedge_multivector(pga3d_Vec3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec3_left_contraction_multivector(pga3d_Vec3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec3_right_contraction_multivector(pga3d_Vec3 lhs, pga3d_Multivector rhs);
+double pga3d_vec3_scalar_product_multivector(pga3d_Vec3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec3_commutator_multivector(pga3d_Vec3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec3_anti_dot_multivector(pga3d_Vec3 lhs, pga3d_Multivector rhs);
+pga3d_Plane pga3d_vec4_rcompl(pga3d_Vec4 value);
+pga3d_Plane pga3d_vec4_lcompl(pga3d_Vec4 value);
+pga3d_Vec4 pga3d_vec4_rev(pga3d_Vec4 value);
+pga3d_Vec4 pga3d_vec4_arev(pga3d_Vec4 value);
+pga3d_Vec4 pga3d_vec4_involute(pga3d_Vec4 value);
+pga3d_Vec4 pga3d_vec4_conjugate(pga3d_Vec4 value);
+pga3d_Plane pga3d_vec4_dual(pga3d_Vec4 value);
+pga3d_Vec3 pga3d_vec4_bulk(pga3d_Vec4 value);
+double pga3d_vec4_weight(pga3d_Vec4 value);
+pga3d_Plane pga3d_vec4_bulk_dual(pga3d_Vec4 value);
+double pga3d_vec4_weight_dual(pga3d_Vec4 value);
+pga3d_Motor3 pga3d_vec4_geometric_vec3(pga3d_Vec4 lhs, pga3d_Vec3 rhs);
+pga3d_Moment3 pga3d_vec4_anti_geometric_vec3(pga3d_Vec4 lhs, pga3d_Vec3 rhs);
+double pga3d_vec4_dot_vec3(pga3d_Vec4 lhs, pga3d_Vec3 rhs);
+pga3d_Line3 pga3d_vec4_wedge_vec3(pga3d_Vec4 lhs, pga3d_Vec3 rhs);
+double pga3d_vec4_left_contraction_vec3(pga3d_Vec4 lhs, pga3d_Vec3 rhs);
+double pga3d_vec4_right_contraction_vec3(pga3d_Vec4 lhs, pga3d_Vec3 rhs);
+double pga3d_vec4_scalar_product_vec3(pga3d_Vec4 lhs, pga3d_Vec3 rhs);
+pga3d_Line3 pga3d_vec4_commutator_vec3(pga3d_Vec4 lhs, pga3d_Vec3 rhs);
+pga3d_Motor3 pga3d_vec4_geometric_vec4(pga3d_Vec4 lhs, pga3d_Vec4 rhs);
+pga3d_Translator3 pga3d_vec4_anti_geometric_vec4(pga3d_Vec4 lhs, pga3d_Vec4 rhs);
+double pga3d_vec4_dot_vec4(pga3d_Vec4 lhs, pga3d_Vec4 rhs);
+pga3d_Line3 pga3d_vec4_wedge_vec4(pga3d_Vec4 lhs, pga3d_Vec4 rhs);
+double pga3d_vec4_left_contraction_vec4(pga3d_Vec4 lhs, pga3d_Vec4 rhs);
+double pga3d_vec4_right_contraction_vec4(pga3d_Vec4 lhs, pga3d_Vec4 rhs);
+double pga3d_vec4_scalar_product_vec4(pga3d_Vec4 lhs, pga3d_Vec4 rhs);
+pga3d_Line3 pga3d_vec4_commutator_vec4(pga3d_Vec4 lhs, pga3d_Vec4 rhs);
+double pga3d_vec4_anti_dot_vec4(pga3d_Vec4 lhs, pga3d_Vec4 rhs);
+pga3d_Motor3 pga3d_vec4_geometric_point3(pga3d_Vec4 lhs, pga3d_Point3 rhs);
+pga3d_Translator3 pga3d_vec4_anti_geometric_point3(pga3d_Vec4 lhs, pga3d_Point3 rhs);
+double pga3d_vec4_dot_point3(pga3d_Vec4 lhs, pga3d_Point3 rhs);
+pga3d_Line3 pga3d_vec4_wedge_point3(pga3d_Vec4 lhs, pga3d_Point3 rhs);
+double pga3d_vec4_left_contraction_point3(pga3d_Vec4 lhs, pga3d_Point3 rhs);
+double pga3d_vec4_right_contraction_point3(pga3d_Vec4 lhs, pga3d_Point3 rhs);
+double pga3d_vec4_scalar_product_point3(pga3d_Vec4 lhs, pga3d_Point3 rhs);
+pga3d_Line3 pga3d_vec4_commutator_point3(pga3d_Vec4 lhs, pga3d_Point3 rhs);
+double pga3d_vec4_anti_dot_point3(pga3d_Vec4 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_vec4_geometric_moment3(pga3d_Vec4 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_vec4_anti_geometric_moment3(pga3d_Vec4 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_vec4_dot_moment3(pga3d_Vec4 lhs, pga3d_Moment3 rhs);
+pga3d_Plane pga3d_vec4_wedge_moment3(pga3d_Vec4 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_vec4_left_contraction_moment3(pga3d_Vec4 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_vec4_commutator_moment3(pga3d_Vec4 lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_vec4_geometric_line3(pga3d_Vec4 lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_vec4_anti_geometric_line3(pga3d_Vec4 lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_vec4_dot_line3(pga3d_Vec4 lhs, pga3d_Line3 rhs);
+pga3d_Plane pga3d_vec4_wedge_line3(pga3d_Vec4 lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_vec4_left_contraction_line3(pga3d_Vec4 lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_vec4_commutator_line3(pga3d_Vec4 lhs, pga3d_Line3 rhs);
+pga3d_Plane pga3d_vec4_anti_dot_line3(pga3d_Vec4 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_vec4_geometric_plane(pga3d_Vec4 lhs, pga3d_Plane rhs);
+pga3d_Motor3 pga3d_vec4_anti_geometric_plane(pga3d_Vec4 lhs, pga3d_Plane rhs);
+pga3d_Line3 pga3d_vec4_dot_plane(pga3d_Vec4 lhs, pga3d_Plane rhs);
+double pga3d_vec4_wedge_plane(pga3d_Vec4 lhs, pga3d_Plane rhs);
+double pga3d_vec4_anti_wedge_plane(pga3d_Vec4 lhs, pga3d_Plane rhs);
+pga3d_Line3 pga3d_vec4_left_contraction_plane(pga3d_Vec4 lhs, pga3d_Plane rhs);
+double pga3d_vec4_commutator_plane(pga3d_Vec4 lhs, pga3d_Plane rhs);
+pga3d_Line3 pga3d_vec4_anti_dot_plane(pga3d_Vec4 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_vec4_geometric_translator3(pga3d_Vec4 lhs, pga3d_Translator3 rhs);
+pga3d_Vec4 pga3d_vec4_anti_geometric_translator3(pga3d_Vec4 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_vec4_dot_translator3(pga3d_Vec4 lhs, pga3d_Translator3 rhs);
+pga3d_Plane pga3d_vec4_wedge_translator3(pga3d_Vec4 lhs, pga3d_Translator3 rhs);
+pga3d_Vec4 pga3d_vec4_anti_wedge_translator3(pga3d_Vec4 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_vec4_left_contraction_translator3(pga3d_Vec4 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_vec4_commutator_translator3(pga3d_Vec4 lhs, pga3d_Translator3 rhs);
+pga3d_Vec4 pga3d_vec4_anti_dot_translator3(pga3d_Vec4 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_vec4_geometric_rotor3(pga3d_Vec4 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec4_anti_geometric_rotor3(pga3d_Vec4 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec4_dot_rotor3(pga3d_Vec4 lhs, pga3d_Rotor3 rhs);
+pga3d_Plane pga3d_vec4_wedge_rotor3(pga3d_Vec4 lhs, pga3d_Rotor3 rhs);
+pga3d_Vec4 pga3d_vec4_anti_wedge_rotor3(pga3d_Vec4 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec4_left_contraction_rotor3(pga3d_Vec4 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec4_commutator_rotor3(pga3d_Vec4 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec4_anti_dot_rotor3(pga3d_Vec4 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_vec4_geometric_motor3(pga3d_Vec4 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec4_anti_geometric_motor3(pga3d_Vec4 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec4_dot_motor3(pga3d_Vec4 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec4_wedge_motor3(pga3d_Vec4 lhs, pga3d_Motor3 rhs);
+pga3d_Vec4 pga3d_vec4_anti_wedge_motor3(pga3d_Vec4 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec4_left_contraction_motor3(pga3d_Vec4 lhs, pga3d_Motor3 rhs);
+pga3d_Vec4 pga3d_vec4_right_contraction_motor3(pga3d_Vec4 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec4_commutator_motor3(pga3d_Vec4 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec4_anti_dot_motor3(pga3d_Vec4 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_vec4_geometric_multivector(pga3d_Vec4 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec4_anti_geometric_multivector(pga3d_Vec4 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec4_dot_multivector(pga3d_Vec4 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec4_wedge_multivector(pga3d_Vec4 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec4_anti_wedge_multivector(pga3d_Vec4 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec4_left_contraction_multivector(pga3d_Vec4 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec4_right_contraction_multivector(pga3d_Vec4 lhs, pga3d_Multivector rhs);
+double pga3d_vec4_scalar_product_multivector(pga3d_Vec4 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec4_commutator_multivector(pga3d_Vec4 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_vec4_anti_dot_multivector(pga3d_Vec4 lhs, pga3d_Multivector rhs);
+pga3d_Plane pga3d_point3_rcompl(pga3d_Point3 value);
+pga3d_Plane pga3d_point3_lcompl(pga3d_Point3 value);
+pga3d_Plane pga3d_point3_dual(pga3d_Point3 value);
+pga3d_Vec3 pga3d_point3_bulk(pga3d_Point3 value);
+double pga3d_point3_weight(pga3d_Point3 value);
+pga3d_Plane pga3d_point3_bulk_dual(pga3d_Point3 value);
+double pga3d_point3_weight_dual(pga3d_Point3 value);
+pga3d_Motor3 pga3d_point3_geometric_vec3(pga3d_Point3 lhs, pga3d_Vec3 rhs);
+pga3d_Moment3 pga3d_point3_anti_geometric_vec3(pga3d_Point3 lhs, pga3d_Vec3 rhs);
+double pga3d_point3_dot_vec3(pga3d_Point3 lhs, pga3d_Vec3 rhs);
+pga3d_Line3 pga3d_point3_wedge_vec3(pga3d_Point3 lhs, pga3d_Vec3 rhs);
+double pga3d_point3_left_contraction_vec3(pga3d_Point3 lhs, pga3d_Vec3 rhs);
+double pga3d_point3_right_contraction_vec3(pga3d_Point3 lhs, pga3d_Vec3 rhs);
+double pga3d_point3_scalar_product_vec3(pga3d_Point3 lhs, pga3d_Vec3 rhs);
+pga3d_Line3 pga3d_point3_commutator_vec3(pga3d_Point3 lhs, pga3d_Vec3 rhs);
+pga3d_Motor3 pga3d_point3_geometric_vec4(pga3d_Point3 lhs, pga3d_Vec4 rhs);
+pga3d_Translator3 pga3d_point3_anti_geometric_vec4(pga3d_Point3 lhs, pga3d_Vec4 rhs);
+double pga3d_point3_dot_vec4(pga3d_Point3 lhs, pga3d_Vec4 rhs);
+pga3d_Line3 pga3d_point3_wedge_vec4(pga3d_Point3 lhs, pga3d_Vec4 rhs);
+double pga3d_point3_left_contraction_vec4(pga3d_Point3 lhs, pga3d_Vec4 rhs);
+double pga3d_point3_right_contraction_vec4(pga3d_Point3 lhs, pga3d_Vec4 rhs);
+double pga3d_point3_scalar_product_vec4(pga3d_Point3 lhs, pga3d_Vec4 rhs);
+pga3d_Line3 pga3d_point3_commutator_vec4(pga3d_Point3 lhs, pga3d_Vec4 rhs);
+double pga3d_point3_anti_dot_vec4(pga3d_Point3 lhs, pga3d_Vec4 rhs);
+pga3d_Motor3 pga3d_point3_geometric_point3(pga3d_Point3 lhs, pga3d_Point3 rhs);
+pga3d_Translator3 pga3d_point3_anti_geometric_point3(pga3d_Point3 lhs, pga3d_Point3 rhs);
+double pga3d_point3_dot_point3(pga3d_Point3 lhs, pga3d_Point3 rhs);
+pga3d_Line3 pga3d_point3_wedge_point3(pga3d_Point3 lhs, pga3d_Point3 rhs);
+double pga3d_point3_left_contraction_point3(pga3d_Point3 lhs, pga3d_Point3 rhs);
+double pga3d_point3_right_contraction_point3(pga3d_Point3 lhs, pga3d_Point3 rhs);
+double pga3d_point3_scalar_product_point3(pga3d_Point3 lhs, pga3d_Point3 rhs);
+pga3d_Line3 pga3d_point3_commutator_point3(pga3d_Point3 lhs, pga3d_Point3 rhs);
+double pga3d_point3_anti_dot_point3(pga3d_Point3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_point3_geometric_moment3(pga3d_Point3 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_point3_anti_geometric_moment3(pga3d_Point3 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_point3_dot_moment3(pga3d_Point3 lhs, pga3d_Moment3 rhs);
+pga3d_Plane pga3d_point3_wedge_moment3(pga3d_Point3 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_point3_left_contraction_moment3(pga3d_Point3 lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_point3_commutator_moment3(pga3d_Point3 lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_point3_geometric_line3(pga3d_Point3 lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_point3_anti_geometric_line3(pga3d_Point3 lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_point3_dot_line3(pga3d_Point3 lhs, pga3d_Line3 rhs);
+pga3d_Plane pga3d_point3_wedge_line3(pga3d_Point3 lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_point3_left_contraction_line3(pga3d_Point3 lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_point3_commutator_line3(pga3d_Point3 lhs, pga3d_Line3 rhs);
+pga3d_Plane pga3d_point3_anti_dot_line3(pga3d_Point3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_point3_geometric_plane(pga3d_Point3 lhs, pga3d_Plane rhs);
+pga3d_Motor3 pga3d_point3_anti_geometric_plane(pga3d_Point3 lhs, pga3d_Plane rhs);
+pga3d_Line3 pga3d_point3_dot_plane(pga3d_Point3 lhs, pga3d_Plane rhs);
+double pga3d_point3_wedge_plane(pga3d_Point3 lhs, pga3d_Plane rhs);
+double pga3d_point3_anti_wedge_plane(pga3d_Point3 lhs, pga3d_Plane rhs);
+pga3d_Line3 pga3d_point3_left_contraction_plane(pga3d_Point3 lhs, pga3d_Plane rhs);
+double pga3d_point3_commutator_plane(pga3d_Point3 lhs, pga3d_Plane rhs);
+pga3d_Line3 pga3d_point3_anti_dot_plane(pga3d_Point3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_point3_geometric_translator3(pga3d_Point3 lhs, pga3d_Translator3 rhs);
+pga3d_Vec4 pga3d_point3_anti_geometric_translator3(pga3d_Point3 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_point3_dot_translator3(pga3d_Point3 lhs, pga3d_Translator3 rhs);
+pga3d_Plane pga3d_point3_wedge_translator3(pga3d_Point3 lhs, pga3d_Translator3 rhs);
+pga3d_Vec4 pga3d_point3_anti_wedge_translator3(pga3d_Point3 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_point3_left_contraction_translator3(pga3d_Point3 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_point3_commutator_translator3(pga3d_Point3 lhs, pga3d_Translator3 rhs);
+pga3d_Vec4 pga3d_point3_anti_dot_translator3(pga3d_Point3 lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_point3_geometric_rotor3(pga3d_Point3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_point3_anti_geometric_rotor3(pga3d_Point3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_point3_dot_rotor3(pga3d_Point3 lhs, pga3d_Rotor3 rhs);
+pga3d_Plane pga3d_point3_wedge_rotor3(pga3d_Point3 lhs, pga3d_Rotor3 rhs);
+pga3d_Vec4 pga3d_point3_anti_wedge_rotor3(pga3d_Point3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_point3_left_contraction_rotor3(pga3d_Point3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_point3_commutator_rotor3(pga3d_Point3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_point3_anti_dot_rotor3(pga3d_Point3 lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_point3_geometric_motor3(pga3d_Point3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_point3_anti_geometric_motor3(pga3d_Point3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_point3_dot_motor3(pga3d_Point3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_point3_wedge_motor3(pga3d_Point3 lhs, pga3d_Motor3 rhs);
+pga3d_Vec4 pga3d_point3_anti_wedge_motor3(pga3d_Point3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_point3_left_contraction_motor3(pga3d_Point3 lhs, pga3d_Motor3 rhs);
+pga3d_Vec4 pga3d_point3_right_contraction_motor3(pga3d_Point3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_point3_commutator_motor3(pga3d_Point3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_point3_anti_dot_motor3(pga3d_Point3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_point3_geometric_multivector(pga3d_Point3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_point3_anti_geometric_multivector(pga3d_Point3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_point3_dot_multivector(pga3d_Point3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_point3_wedge_multivector(pga3d_Point3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_point3_anti_wedge_multivector(pga3d_Point3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_point3_left_contraction_multivector(pga3d_Point3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_point3_right_contraction_multivector(pga3d_Point3 lhs, pga3d_Multivector rhs);
+double pga3d_point3_scalar_product_multivector(pga3d_Point3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_point3_commutator_multivector(pga3d_Point3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_point3_anti_dot_multivector(pga3d_Point3 lhs, pga3d_Multivector rhs);
+pga3d_Line3 pga3d_moment3_rcompl(pga3d_Moment3 value);
+pga3d_Line3 pga3d_moment3_lcompl(pga3d_Moment3 value);
+pga3d_Moment3 pga3d_moment3_rev(pga3d_Moment3 value);
+pga3d_Moment3 pga3d_moment3_arev(pga3d_Moment3 value);
+pga3d_Moment3 pga3d_moment3_involute(pga3d_Moment3 value);
+pga3d_Moment3 pga3d_moment3_conjugate(pga3d_Moment3 value);
+pga3d_Line3 pga3d_moment3_dual(pga3d_Moment3 value);
+pga3d_Moment3 pga3d_moment3_bulk(pga3d_Moment3 value);
+pga3d_Line3 pga3d_moment3_bulk_dual(pga3d_Moment3 value);
+pga3d_Multivector pga3d_moment3_geometric_vec3(pga3d_Moment3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec3 pga3d_moment3_dot_vec3(pga3d_Moment3 lhs, pga3d_Vec3 rhs);
+double pga3d_moment3_wedge_vec3(pga3d_Moment3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec3 pga3d_moment3_right_contraction_vec3(pga3d_Moment3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec3 pga3d_moment3_commutator_vec3(pga3d_Moment3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_moment3_geometric_vec4(pga3d_Moment3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec3 pga3d_moment3_anti_geometric_vec4(pga3d_Moment3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec3 pga3d_moment3_dot_vec4(pga3d_Moment3 lhs, pga3d_Vec4 rhs);
+pga3d_Plane pga3d_moment3_wedge_vec4(pga3d_Moment3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec3 pga3d_moment3_right_contraction_vec4(pga3d_Moment3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec3 pga3d_moment3_commutator_vec4(pga3d_Moment3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_moment3_geometric_point3(pga3d_Moment3 lhs, pga3d_Point3 rhs);
+pga3d_Vec3 pga3d_moment3_anti_geometric_point3(pga3d_Moment3 lhs, pga3d_Point3 rhs);
+pga3d_Vec3 pga3d_moment3_dot_point3(pga3d_Moment3 lhs, pga3d_Point3 rhs);
+pga3d_Plane pga3d_moment3_wedge_point3(pga3d_Moment3 lhs, pga3d_Point3 rhs);
+pga3d_Vec3 pga3d_moment3_right_contraction_point3(pga3d_Moment3 lhs, pga3d_Point3 rhs);
+pga3d_Vec3 pga3d_moment3_commutator_point3(pga3d_Moment3 lhs, pga3d_Point3 rhs);
+pga3d_Motor3 pga3d_moment3_geometric_moment3(pga3d_Moment3 lhs, pga3d_Moment3 rhs);
+double pga3d_moment3_dot_moment3(pga3d_Moment3 lhs, pga3d_Moment3 rhs);
+double pga3d_moment3_left_contraction_moment3(pga3d_Moment3 lhs, pga3d_Moment3 rhs);
+double pga3d_moment3_right_contraction_moment3(pga3d_Moment3 lhs, pga3d_Moment3 rhs);
+double pga3d_moment3_scalar_product_moment3(pga3d_Moment3 lhs, pga3d_Moment3 rhs);
+pga3d_Moment3 pga3d_moment3_commutator_moment3(pga3d_Moment3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_moment3_geometric_line3(pga3d_Moment3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_moment3_anti_geometric_line3(pga3d_Moment3 lhs, pga3d_Line3 rhs);
+double pga3d_moment3_dot_line3(pga3d_Moment3 lhs, pga3d_Line3 rhs);
+double pga3d_moment3_wedge_line3(pga3d_Moment3 lhs, pga3d_Line3 rhs);
+double pga3d_moment3_anti_wedge_line3(pga3d_Moment3 lhs, pga3d_Line3 rhs);
+double pga3d_moment3_left_contraction_line3(pga3d_Moment3 lhs, pga3d_Line3 rhs);
+double pga3d_moment3_right_contraction_line3(pga3d_Moment3 lhs, pga3d_Line3 rhs);
+double pga3d_moment3_scalar_product_line3(pga3d_Moment3 lhs, pga3d_Line3 rhs);
+pga3d_Line3 pga3d_moment3_commutator_line3(pga3d_Moment3 lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_moment3_geometric_plane(pga3d_Moment3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_moment3_anti_geometric_plane(pga3d_Moment3 lhs, pga3d_Plane rhs);
+pga3d_Vec4 pga3d_moment3_dot_plane(pga3d_Moment3 lhs, pga3d_Plane rhs);
+pga3d_Vec3 pga3d_moment3_anti_wedge_plane(pga3d_Moment3 lhs, pga3d_Plane rhs);
+pga3d_Vec4 pga3d_moment3_left_contraction_plane(pga3d_Moment3 lhs, pga3d_Plane rhs);
+pga3d_Plane pga3d_moment3_commutator_plane(pga3d_Moment3 lhs, pga3d_Plane rhs);
+double pga3d_moment3_anti_dot_plane(pga3d_Moment3 lhs, pga3d_Plane rhs);
+pga3d_Motor3 pga3d_moment3_geometric_translator3(pga3d_Moment3 lhs, pga3d_Translator3 rhs);
+pga3d_Moment3 pga3d_moment3_anti_geometric_translator3(pga3d_Moment3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_moment3_dot_translator3(pga3d_Moment3 lhs, pga3d_Translator3 rhs);
+pga3d_Moment3 pga3d_moment3_anti_wedge_translator3(pga3d_Moment3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_moment3_left_contraction_translator3(pga3d_Moment3 lhs, pga3d_Translator3 rhs);
+double pga3d_moment3_right_contraction_translator3(pga3d_Moment3 lhs, pga3d_Translator3 rhs);
+double pga3d_moment3_scalar_product_translator3(pga3d_Moment3 lhs, pga3d_Translator3 rhs);
+pga3d_Moment3 pga3d_moment3_commutator_translator3(pga3d_Moment3 lhs, pga3d_Translator3 rhs);
+pga3d_Moment3 pga3d_moment3_anti_dot_translator3(pga3d_Moment3 lhs, pga3d_Translator3 rhs);
+pga3d_Rotor3 pga3d_moment3_geometric_rotor3(pga3d_Moment3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_moment3_anti_geometric_rotor3(pga3d_Moment3 lhs, pga3d_Rotor3 rhs);
+pga3d_Line3 pga3d_moment3_dot_rotor3(pga3d_Moment3 lhs, pga3d_Rotor3 rhs);
+double pga3d_moment3_wedge_rotor3(pga3d_Moment3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_moment3_anti_wedge_rotor3(pga3d_Moment3 lhs, pga3d_Rotor3 rhs);
+pga3d_Line3 pga3d_moment3_left_contraction_rotor3(pga3d_Moment3 lhs, pga3d_Rotor3 rhs);
+pga3d_Line3 pga3d_moment3_commutator_rotor3(pga3d_Moment3 lhs, pga3d_Rotor3 rhs);
+pga3d_Moment3 pga3d_moment3_anti_dot_rotor3(pga3d_Moment3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_moment3_geometric_motor3(pga3d_Moment3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_moment3_anti_geometric_motor3(pga3d_Moment3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_moment3_dot_motor3(pga3d_Moment3 lhs, pga3d_Motor3 rhs);
+pga3d_Translator3 pga3d_moment3_wedge_motor3(pga3d_Moment3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_moment3_anti_wedge_motor3(pga3d_Moment3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_moment3_left_contraction_motor3(pga3d_Moment3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_moment3_right_contraction_motor3(pga3d_Moment3 lhs, pga3d_Motor3 rhs);
+double pga3d_moment3_scalar_product_motor3(pga3d_Moment3 lhs, pga3d_Motor3 rhs);
+pga3d_Line3 pga3d_moment3_commutator_motor3(pga3d_Moment3 lhs, pga3d_Motor3 rhs);
+pga3d_Moment3 pga3d_moment3_anti_dot_motor3(pga3d_Moment3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_moment3_geometric_multivector(pga3d_Moment3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_moment3_anti_geometric_multivector(pga3d_Moment3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_moment3_dot_multivector(pga3d_Moment3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_moment3_wedge_multivector(pga3d_Moment3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_moment3_anti_wedge_multivector(pga3d_Moment3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_moment3_left_contraction_multivector(pga3d_Moment3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_moment3_right_contraction_multivector(pga3d_Moment3 lhs, pga3d_Multivector rhs);
+double pga3d_moment3_scalar_product_multivector(pga3d_Moment3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_moment3_commutator_multivector(pga3d_Moment3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_moment3_anti_dot_multivector(pga3d_Moment3 lhs, pga3d_Multivector rhs);
+pga3d_Line3 pga3d_line3_rcompl(pga3d_Line3 value);
+pga3d_Line3 pga3d_line3_lcompl(pga3d_Line3 value);
+pga3d_Line3 pga3d_line3_rev(pga3d_Line3 value);
+pga3d_Line3 pga3d_line3_arev(pga3d_Line3 value);
+pga3d_Line3 pga3d_line3_involute(pga3d_Line3 value);
+pga3d_Line3 pga3d_line3_conjugate(pga3d_Line3 value);
+pga3d_Line3 pga3d_line3_dual(pga3d_Line3 value);
+pga3d_Moment3 pga3d_line3_bulk(pga3d_Line3 value);
+pga3d_Line3 pga3d_line3_weight(pga3d_Line3 value);
+pga3d_Line3 pga3d_line3_bulk_dual(pga3d_Line3 value);
+pga3d_Moment3 pga3d_line3_weight_dual(pga3d_Line3 value);
+pga3d_Multivector pga3d_line3_geometric_vec3(pga3d_Line3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_line3_anti_geometric_vec3(pga3d_Line3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec4 pga3d_line3_dot_vec3(pga3d_Line3 lhs, pga3d_Vec3 rhs);
+pga3d_Plane pga3d_line3_wedge_vec3(pga3d_Line3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec4 pga3d_line3_right_contraction_vec3(pga3d_Line3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec4 pga3d_line3_commutator_vec3(pga3d_Line3 lhs, pga3d_Vec3 rhs);
+double pga3d_line3_anti_dot_vec3(pga3d_Line3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_line3_geometric_vec4(pga3d_Line3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_line3_anti_geometric_vec4(pga3d_Line3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_line3_dot_vec4(pga3d_Line3 lhs, pga3d_Vec4 rhs);
+pga3d_Plane pga3d_line3_wedge_vec4(pga3d_Line3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_line3_right_contraction_vec4(pga3d_Line3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_line3_commutator_vec4(pga3d_Line3 lhs, pga3d_Vec4 rhs);
+pga3d_Plane pga3d_line3_anti_dot_vec4(pga3d_Line3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_line3_geometric_point3(pga3d_Line3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_line3_anti_geometric_point3(pga3d_Line3 lhs, pga3d_Point3 rhs);
+pga3d_Vec4 pga3d_line3_dot_point3(pga3d_Line3 lhs, pga3d_Point3 rhs);
+pga3d_Plane pga3d_line3_wedge_point3(pga3d_Line3 lhs, pga3d_Point3 rhs);
+pga3d_Vec4 pga3d_line3_right_contraction_point3(pga3d_Line3 lhs, pga3d_Point3 rhs);
+pga3d_Vec4 pga3d_line3_commutator_point3(pga3d_Line3 lhs, pga3d_Point3 rhs);
+pga3d_Plane pga3d_line3_anti_dot_point3(pga3d_Line3 lhs, pga3d_Point3 rhs);
+pga3d_Motor3 pga3d_line3_geometric_moment3(pga3d_Line3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_line3_anti_geometric_moment3(pga3d_Line3 lhs, pga3d_Moment3 rhs);
+double pga3d_line3_dot_moment3(pga3d_Line3 lhs, pga3d_Moment3 rhs);
+double pga3d_line3_wedge_moment3(pga3d_Line3 lhs, pga3d_Moment3 rhs);
+double pga3d_line3_anti_wedge_moment3(pga3d_Line3 lhs, pga3d_Moment3 rhs);
+double pga3d_line3_left_contraction_moment3(pga3d_Line3 lhs, pga3d_Moment3 rhs);
+double pga3d_line3_right_contraction_moment3(pga3d_Line3 lhs, pga3d_Moment3 rhs);
+double pga3d_line3_scalar_product_moment3(pga3d_Line3 lhs, pga3d_Moment3 rhs);
+pga3d_Line3 pga3d_line3_commutator_moment3(pga3d_Line3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_line3_geometric_line3(pga3d_Line3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_line3_anti_geometric_line3(pga3d_Line3 lhs, pga3d_Line3 rhs);
+double pga3d_line3_dot_line3(pga3d_Line3 lhs, pga3d_Line3 rhs);
+double pga3d_line3_wedge_line3(pga3d_Line3 lhs, pga3d_Line3 rhs);
+double pga3d_line3_anti_wedge_line3(pga3d_Line3 lhs, pga3d_Line3 rhs);
+double pga3d_line3_left_contraction_line3(pga3d_Line3 lhs, pga3d_Line3 rhs);
+double pga3d_line3_right_contraction_line3(pga3d_Line3 lhs, pga3d_Line3 rhs);
+double pga3d_line3_scalar_product_line3(pga3d_Line3 lhs, pga3d_Line3 rhs);
+pga3d_Line3 pga3d_line3_commutator_line3(pga3d_Line3 lhs, pga3d_Line3 rhs);
+double pga3d_line3_anti_dot_line3(pga3d_Line3 lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_line3_geometric_plane(pga3d_Line3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_line3_anti_geometric_plane(pga3d_Line3 lhs, pga3d_Plane rhs);
+pga3d_Vec4 pga3d_line3_dot_plane(pga3d_Line3 lhs, pga3d_Plane rhs);
+pga3d_Vec4 pga3d_line3_anti_wedge_plane(pga3d_Line3 lhs, pga3d_Plane rhs);
+pga3d_Vec4 pga3d_line3_left_contraction_plane(pga3d_Line3 lhs, pga3d_Plane rhs);
+pga3d_Plane pga3d_line3_commutator_plane(pga3d_Line3 lhs, pga3d_Plane rhs);
+pga3d_Plane pga3d_line3_anti_dot_plane(pga3d_Line3 lhs, pga3d_Plane rhs);
+pga3d_Motor3 pga3d_line3_geometric_translator3(pga3d_Line3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_line3_anti_geometric_translator3(pga3d_Line3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_line3_dot_translator3(pga3d_Line3 lhs, pga3d_Translator3 rhs);
+double pga3d_line3_wedge_translator3(pga3d_Line3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_line3_anti_wedge_translator3(pga3d_Line3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_line3_left_contraction_translator3(pga3d_Line3 lhs, pga3d_Translator3 rhs);
+double pga3d_line3_right_contraction_translator3(pga3d_Line3 lhs, pga3d_Translator3 rhs);
+double pga3d_line3_scalar_product_translator3(pga3d_Line3 lhs, pga3d_Translator3 rhs);
+pga3d_Line3 pga3d_line3_commutator_translator3(pga3d_Line3 lhs, pga3d_Translator3 rhs);
+pga3d_Line3 pga3d_line3_anti_dot_translator3(pga3d_Line3 lhs, pga3d_Translator3 rhs);
+pga3d_Rotor3 pga3d_line3_geometric_rotor3(pga3d_Line3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_line3_anti_geometric_rotor3(pga3d_Line3 lhs, pga3d_Rotor3 rhs);
+pga3d_Line3 pga3d_line3_dot_rotor3(pga3d_Line3 lhs, pga3d_Rotor3 rhs);
+double pga3d_line3_wedge_rotor3(pga3d_Line3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_line3_anti_wedge_rotor3(pga3d_Line3 lhs, pga3d_Rotor3 rhs);
+pga3d_Line3 pga3d_line3_left_contraction_rotor3(pga3d_Line3 lhs, pga3d_Rotor3 rhs);
+pga3d_Line3 pga3d_line3_commutator_rotor3(pga3d_Line3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_line3_anti_dot_rotor3(pga3d_Line3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_line3_geometric_motor3(pga3d_Line3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_line3_anti_geometric_motor3(pga3d_Line3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_line3_dot_motor3(pga3d_Line3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_line3_wedge_motor3(pga3d_Line3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_line3_anti_wedge_motor3(pga3d_Line3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_line3_left_contraction_motor3(pga3d_Line3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_line3_right_contraction_motor3(pga3d_Line3 lhs, pga3d_Motor3 rhs);
+double pga3d_line3_scalar_product_motor3(pga3d_Line3 lhs, pga3d_Motor3 rhs);
+pga3d_Line3 pga3d_line3_commutator_motor3(pga3d_Line3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_line3_anti_dot_motor3(pga3d_Line3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_line3_geometric_multivector(pga3d_Line3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_line3_anti_geometric_multivector(pga3d_Line3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_line3_dot_multivector(pga3d_Line3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_line3_wedge_multivector(pga3d_Line3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_line3_anti_wedge_multivector(pga3d_Line3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_line3_left_contraction_multivector(pga3d_Line3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_line3_right_contraction_multivector(pga3d_Line3 lhs, pga3d_Multivector rhs);
+double pga3d_line3_scalar_product_multivector(pga3d_Line3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_line3_commutator_multivector(pga3d_Line3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_line3_anti_dot_multivector(pga3d_Line3 lhs, pga3d_Multivector rhs);
+pga3d_Vec4 pga3d_plane_rcompl(pga3d_Plane value);
+pga3d_Vec4 pga3d_plane_lcompl(pga3d_Plane value);
+pga3d_Plane pga3d_plane_rev(pga3d_Plane value);
+pga3d_Plane pga3d_plane_arev(pga3d_Plane value);
+pga3d_Plane pga3d_plane_involute(pga3d_Plane value);
+pga3d_Plane pga3d_plane_conjugate(pga3d_Plane value);
+pga3d_Vec4 pga3d_plane_dual(pga3d_Plane value);
+double pga3d_plane_bulk(pga3d_Plane value);
+pga3d_Plane pga3d_plane_weight(pga3d_Plane value);
+double pga3d_plane_bulk_dual(pga3d_Plane value);
+pga3d_Vec3 pga3d_plane_weight_dual(pga3d_Plane value);
+pga3d_Motor3 pga3d_plane_geometric_vec3(pga3d_Plane lhs, pga3d_Vec3 rhs);
+pga3d_Motor3 pga3d_plane_anti_geometric_vec3(pga3d_Plane lhs, pga3d_Vec3 rhs);
+pga3d_Line3 pga3d_plane_dot_vec3(pga3d_Plane lhs, pga3d_Vec3 rhs);
+double pga3d_plane_wedge_vec3(pga3d_Plane lhs, pga3d_Vec3 rhs);
+double pga3d_plane_anti_wedge_vec3(pga3d_Plane lhs, pga3d_Vec3 rhs);
+pga3d_Line3 pga3d_plane_right_contraction_vec3(pga3d_Plane lhs, pga3d_Vec3 rhs);
+double pga3d_plane_commutator_vec3(pga3d_Plane lhs, pga3d_Vec3 rhs);
+pga3d_Moment3 pga3d_plane_anti_dot_vec3(pga3d_Plane lhs, pga3d_Vec3 rhs);
+pga3d_Motor3 pga3d_plane_geometric_vec4(pga3d_Plane lhs, pga3d_Vec4 rhs);
+pga3d_Motor3 pga3d_plane_anti_geometric_vec4(pga3d_Plane lhs, pga3d_Vec4 rhs);
+pga3d_Line3 pga3d_plane_dot_vec4(pga3d_Plane lhs, pga3d_Vec4 rhs);
+double pga3d_plane_wedge_vec4(pga3d_Plane lhs, pga3d_Vec4 rhs);
+double pga3d_plane_anti_wedge_vec4(pga3d_Plane lhs, pga3d_Vec4 rhs);
+pga3d_Line3 pga3d_plane_right_contraction_vec4(pga3d_Plane lhs, pga3d_Vec4 rhs);
+double pga3d_plane_commutator_vec4(pga3d_Plane lhs, pga3d_Vec4 rhs);
+pga3d_Line3 pga3d_plane_anti_dot_vec4(pga3d_Plane lhs, pga3d_Vec4 rhs);
+pga3d_Motor3 pga3d_plane_geometric_point3(pga3d_Plane lhs, pga3d_Point3 rhs);
+pga3d_Motor3 pga3d_plane_anti_geometric_point3(pga3d_Plane lhs, pga3d_Point3 rhs);
+pga3d_Line3 pga3d_plane_dot_point3(pga3d_Plane lhs, pga3d_Point3 rhs);
+double pga3d_plane_wedge_point3(pga3d_Plane lhs, pga3d_Point3 rhs);
+double pga3d_plane_anti_wedge_point3(pga3d_Plane lhs, pga3d_Point3 rhs);
+pga3d_Line3 pga3d_plane_right_contraction_point3(pga3d_Plane lhs, pga3d_Point3 rhs);
+double pga3d_plane_commutator_point3(pga3d_Plane lhs, pga3d_Point3 rhs);
+pga3d_Line3 pga3d_plane_anti_dot_point3(pga3d_Plane lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_plane_geometric_moment3(pga3d_Plane lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_plane_anti_geometric_moment3(pga3d_Plane lhs, pga3d_Moment3 rhs);
+pga3d_Vec4 pga3d_plane_dot_moment3(pga3d_Plane lhs, pga3d_Moment3 rhs);
+pga3d_Vec3 pga3d_plane_anti_wedge_moment3(pga3d_Plane lhs, pga3d_Moment3 rhs);
+pga3d_Vec4 pga3d_plane_right_contraction_moment3(pga3d_Plane lhs, pga3d_Moment3 rhs);
+pga3d_Plane pga3d_plane_commutator_moment3(pga3d_Plane lhs, pga3d_Moment3 rhs);
+double pga3d_plane_anti_dot_moment3(pga3d_Plane lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_plane_geometric_line3(pga3d_Plane lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_plane_anti_geometric_line3(pga3d_Plane lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_plane_dot_line3(pga3d_Plane lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_plane_anti_wedge_line3(pga3d_Plane lhs, pga3d_Line3 rhs);
+pga3d_Vec4 pga3d_plane_right_contraction_line3(pga3d_Plane lhs, pga3d_Line3 rhs);
+pga3d_Plane pga3d_plane_commutator_line3(pga3d_Plane lhs, pga3d_Line3 rhs);
+pga3d_Plane pga3d_plane_anti_dot_line3(pga3d_Plane lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_plane_geometric_plane(pga3d_Plane lhs, pga3d_Plane rhs);
+pga3d_Motor3 pga3d_plane_anti_geometric_plane(pga3d_Plane lhs, pga3d_Plane rhs);
+double pga3d_plane_dot_plane(pga3d_Plane lhs, pga3d_Plane rhs);
+pga3d_Line3 pga3d_plane_anti_wedge_plane(pga3d_Plane lhs, pga3d_Plane rhs);
+double pga3d_plane_left_contraction_plane(pga3d_Plane lhs, pga3d_Plane rhs);
+double pga3d_plane_right_contraction_plane(pga3d_Plane lhs, pga3d_Plane rhs);
+double pga3d_plane_scalar_product_plane(pga3d_Plane lhs, pga3d_Plane rhs);
+pga3d_Line3 pga3d_plane_commutator_plane(pga3d_Plane lhs, pga3d_Plane rhs);
+double pga3d_plane_anti_dot_plane(pga3d_Plane lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_plane_geometric_translator3(pga3d_Plane lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_plane_anti_geometric_translator3(pga3d_Plane lhs, pga3d_Translator3 rhs);
+pga3d_Vec4 pga3d_plane_dot_translator3(pga3d_Plane lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_plane_anti_wedge_translator3(pga3d_Plane lhs, pga3d_Translator3 rhs);
+double pga3d_plane_left_contraction_translator3(pga3d_Plane lhs, pga3d_Translator3 rhs);
+pga3d_Vec4 pga3d_plane_right_contraction_translator3(pga3d_Plane lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_plane_commutator_translator3(pga3d_Plane lhs, pga3d_Translator3 rhs);
+pga3d_Plane pga3d_plane_anti_dot_translator3(pga3d_Plane lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_plane_geometric_rotor3(pga3d_Plane lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_plane_anti_geometric_rotor3(pga3d_Plane lhs, pga3d_Rotor3 rhs);
+double pga3d_plane_dot_rotor3(pga3d_Plane lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_plane_anti_wedge_rotor3(pga3d_Plane lhs, pga3d_Rotor3 rhs);
+double pga3d_plane_left_contraction_rotor3(pga3d_Plane lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_plane_commutator_rotor3(pga3d_Plane lhs, pga3d_Rotor3 rhs);
+pga3d_Plane pga3d_plane_anti_dot_rotor3(pga3d_Plane lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_plane_geometric_motor3(pga3d_Plane lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_plane_anti_geometric_motor3(pga3d_Plane lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_plane_dot_motor3(pga3d_Plane lhs, pga3d_Motor3 rhs);
+pga3d_Plane pga3d_plane_wedge_motor3(pga3d_Plane lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_plane_anti_wedge_motor3(pga3d_Plane lhs, pga3d_Motor3 rhs);
+double pga3d_plane_left_contraction_motor3(pga3d_Plane lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_plane_right_contraction_motor3(pga3d_Plane lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_plane_commutator_motor3(pga3d_Plane lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_plane_anti_dot_motor3(pga3d_Plane lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_plane_geometric_multivector(pga3d_Plane lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_plane_anti_geometric_multivector(pga3d_Plane lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_plane_dot_multivector(pga3d_Plane lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_plane_wedge_multivector(pga3d_Plane lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_plane_anti_wedge_multivector(pga3d_Plane lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_plane_left_contraction_multivector(pga3d_Plane lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_plane_right_contraction_multivector(pga3d_Plane lhs, pga3d_Multivector rhs);
+double pga3d_plane_scalar_product_multivector(pga3d_Plane lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_plane_commutator_multivector(pga3d_Plane lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_plane_anti_dot_multivector(pga3d_Plane lhs, pga3d_Multivector rhs);
+pga3d_Motor3 pga3d_translator3_rcompl(pga3d_Translator3 value);
+pga3d_Motor3 pga3d_translator3_lcompl(pga3d_Translator3 value);
+pga3d_Translator3 pga3d_translator3_rev(pga3d_Translator3 value);
+pga3d_Translator3 pga3d_translator3_arev(pga3d_Translator3 value);
+pga3d_Translator3 pga3d_translator3_involute(pga3d_Translator3 value);
+pga3d_Translator3 pga3d_translator3_conjugate(pga3d_Translator3 value);
+pga3d_Motor3 pga3d_translator3_dual(pga3d_Translator3 value);
+pga3d_Moment3 pga3d_translator3_bulk(pga3d_Translator3 value);
+double pga3d_translator3_weight(pga3d_Translator3 value);
+pga3d_Line3 pga3d_translator3_bulk_dual(pga3d_Translator3 value);
+double pga3d_translator3_weight_dual(pga3d_Translator3 value);
+pga3d_Multivector pga3d_translator3_geometric_vec3(pga3d_Translator3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec3 pga3d_translator3_anti_geometric_vec3(pga3d_Translator3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_translator3_dot_vec3(pga3d_Translator3 lhs, pga3d_Vec3 rhs);
+double pga3d_translator3_wedge_vec3(pga3d_Translator3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec3 pga3d_translator3_anti_wedge_vec3(pga3d_Translator3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_translator3_right_contraction_vec3(pga3d_Translator3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_translator3_commutator_vec3(pga3d_Translator3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec3 pga3d_translator3_anti_dot_vec3(pga3d_Translator3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_translator3_geometric_vec4(pga3d_Translator3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_translator3_anti_geometric_vec4(pga3d_Translator3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_translator3_dot_vec4(pga3d_Translator3 lhs, pga3d_Vec4 rhs);
+pga3d_Plane pga3d_translator3_wedge_vec4(pga3d_Translator3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_translator3_anti_wedge_vec4(pga3d_Translator3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_translator3_right_contraction_vec4(pga3d_Translator3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_translator3_commutator_vec4(pga3d_Translator3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_translator3_anti_dot_vec4(pga3d_Translator3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_translator3_geometric_point3(pga3d_Translator3 lhs, pga3d_Point3 rhs);
+pga3d_Vec4 pga3d_translator3_anti_geometric_point3(pga3d_Translator3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_translator3_dot_point3(pga3d_Translator3 lhs, pga3d_Point3 rhs);
+pga3d_Plane pga3d_translator3_wedge_point3(pga3d_Translator3 lhs, pga3d_Point3 rhs);
+pga3d_Vec4 pga3d_translator3_anti_wedge_point3(pga3d_Translator3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_translator3_right_contraction_point3(pga3d_Translator3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_translator3_commutator_point3(pga3d_Translator3 lhs, pga3d_Point3 rhs);
+pga3d_Vec4 pga3d_translator3_anti_dot_point3(pga3d_Translator3 lhs, pga3d_Point3 rhs);
+pga3d_Motor3 pga3d_translator3_geometric_moment3(pga3d_Translator3 lhs, pga3d_Moment3 rhs);
+pga3d_Moment3 pga3d_translator3_anti_geometric_moment3(pga3d_Translator3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_translator3_dot_moment3(pga3d_Translator3 lhs, pga3d_Moment3 rhs);
+pga3d_Moment3 pga3d_translator3_anti_wedge_moment3(pga3d_Translator3 lhs, pga3d_Moment3 rhs);
+double pga3d_translator3_left_contraction_moment3(pga3d_Translator3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_translator3_right_contraction_moment3(pga3d_Translator3 lhs, pga3d_Moment3 rhs);
+double pga3d_translator3_scalar_product_moment3(pga3d_Translator3 lhs, pga3d_Moment3 rhs);
+pga3d_Moment3 pga3d_translator3_commutator_moment3(pga3d_Translator3 lhs, pga3d_Moment3 rhs);
+pga3d_Moment3 pga3d_translator3_anti_dot_moment3(pga3d_Translator3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_translator3_geometric_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_translator3_anti_geometric_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_translator3_dot_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+double pga3d_translator3_wedge_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_translator3_anti_wedge_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+double pga3d_translator3_left_contraction_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_translator3_right_contraction_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+double pga3d_translator3_scalar_product_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+pga3d_Line3 pga3d_translator3_commutator_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+pga3d_Line3 pga3d_translator3_anti_dot_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_translator3_geometric_plane(pga3d_Translator3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_translator3_anti_geometric_plane(pga3d_Translator3 lhs, pga3d_Plane rhs);
+pga3d_Vec4 pga3d_translator3_dot_plane(pga3d_Translator3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_translator3_anti_wedge_plane(pga3d_Translator3 lhs, pga3d_Plane rhs);
+pga3d_Vec4 pga3d_translator3_left_contraction_plane(pga3d_Translator3 lhs, pga3d_Plane rhs);
+double pga3d_translator3_right_contraction_plane(pga3d_Translator3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_translator3_commutator_plane(pga3d_Translator3 lhs, pga3d_Plane rhs);
+pga3d_Plane pga3d_translator3_anti_dot_plane(pga3d_Translator3 lhs, pga3d_Plane rhs);
+pga3d_Motor3 pga3d_translator3_geometric_translator3(pga3d_Translator3 lhs, pga3d_Translator3 rhs);
+pga3d_Translator3 pga3d_translator3_anti_geometric_translator3(pga3d_Translator3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_translator3_dot_translator3(pga3d_Translator3 lhs, pga3d_Translator3 rhs);
+pga3d_Translator3 pga3d_translator3_anti_wedge_translator3(pga3d_Translator3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_translator3_left_contraction_translator3(pga3d_Translator3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_translator3_right_contraction_translator3(pga3d_Translator3 lhs, pga3d_Translator3 rhs);
+double pga3d_translator3_scalar_product_translator3(pga3d_Translator3 lhs, pga3d_Translator3 rhs);
+pga3d_Moment3 pga3d_translator3_commutator_translator3(pga3d_Translator3 lhs, pga3d_Translator3 rhs);
+pga3d_Translator3 pga3d_translator3_anti_dot_translator3(pga3d_Translator3 lhs, pga3d_Translator3 rhs);
+pga3d_Rotor3 pga3d_translator3_geometric_rotor3(pga3d_Translator3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_translator3_anti_geometric_rotor3(pga3d_Translator3 lhs, pga3d_Rotor3 rhs);
+pga3d_Line3 pga3d_translator3_dot_rotor3(pga3d_Translator3 lhs, pga3d_Rotor3 rhs);
+double pga3d_translator3_wedge_rotor3(pga3d_Translator3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_translator3_anti_wedge_rotor3(pga3d_Translator3 lhs, pga3d_Rotor3 rhs);
+pga3d_Line3 pga3d_translator3_left_contraction_rotor3(pga3d_Translator3 lhs, pga3d_Rotor3 rhs);
+pga3d_Line3 pga3d_translator3_commutator_rotor3(pga3d_Translator3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_translator3_anti_dot_rotor3(pga3d_Translator3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_translator3_geometric_motor3(pga3d_Translator3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_translator3_anti_geometric_motor3(pga3d_Translator3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_translator3_dot_motor3(pga3d_Translator3 lhs, pga3d_Motor3 rhs);
+pga3d_Translator3 pga3d_translator3_wedge_motor3(pga3d_Translator3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_translator3_anti_wedge_motor3(pga3d_Translator3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_translator3_left_contraction_motor3(pga3d_Translator3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_translator3_right_contraction_motor3(pga3d_Translator3 lhs, pga3d_Motor3 rhs);
+double pga3d_translator3_scalar_product_motor3(pga3d_Translator3 lhs, pga3d_Motor3 rhs);
+pga3d_Line3 pga3d_translator3_commutator_motor3(pga3d_Translator3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_translator3_anti_dot_motor3(pga3d_Translator3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_translator3_geometric_multivector(pga3d_Translator3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_translator3_anti_geometric_multivector(pga3d_Translator3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_translator3_dot_multivector(pga3d_Translator3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_translator3_wedge_multivector(pga3d_Translator3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_translator3_anti_wedge_multivector(pga3d_Translator3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_translator3_left_contraction_multivector(pga3d_Translator3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_translator3_right_contraction_multivector(pga3d_Translator3 lhs, pga3d_Multivector rhs);
+double pga3d_translator3_scalar_product_multivector(pga3d_Translator3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_translator3_commutator_multivector(pga3d_Translator3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_translator3_anti_dot_multivector(pga3d_Translator3 lhs, pga3d_Multivector rhs);
+pga3d_Vec3 pga3d_translator3_anti_sandwich_vec3(pga3d_Translator3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec4 pga3d_translator3_sandwich_vec4(pga3d_Translator3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_translator3_anti_sandwich_vec4(pga3d_Translator3 lhs, pga3d_Vec4 rhs);
+pga3d_Moment3 pga3d_translator3_anti_sandwich_moment3(pga3d_Translator3 lhs, pga3d_Moment3 rhs);
+pga3d_Line3 pga3d_translator3_sandwich_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+pga3d_Line3 pga3d_translator3_anti_sandwich_line3(pga3d_Translator3 lhs, pga3d_Line3 rhs);
+pga3d_Plane pga3d_translator3_sandwich_plane(pga3d_Translator3 lhs, pga3d_Plane rhs);
+pga3d_Plane pga3d_translator3_anti_sandwich_plane(pga3d_Translator3 lhs, pga3d_Plane rhs);
+pga3d_Motor3 pga3d_rotor3_rcompl(pga3d_Rotor3 value);
+pga3d_Motor3 pga3d_rotor3_lcompl(pga3d_Rotor3 value);
+pga3d_Rotor3 pga3d_rotor3_rev(pga3d_Rotor3 value);
+pga3d_Rotor3 pga3d_rotor3_arev(pga3d_Rotor3 value);
+pga3d_Rotor3 pga3d_rotor3_involute(pga3d_Rotor3 value);
+pga3d_Rotor3 pga3d_rotor3_conjugate(pga3d_Rotor3 value);
+pga3d_Motor3 pga3d_rotor3_dual(pga3d_Rotor3 value);
+pga3d_Rotor3 pga3d_rotor3_weight(pga3d_Rotor3 value);
+pga3d_Motor3 pga3d_rotor3_weight_dual(pga3d_Rotor3 value);
+pga3d_Multivector pga3d_rotor3_geometric_vec3(pga3d_Rotor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_rotor3_anti_geometric_vec3(pga3d_Rotor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_rotor3_dot_vec3(pga3d_Rotor3 lhs, pga3d_Vec3 rhs);
+pga3d_Plane pga3d_rotor3_wedge_vec3(pga3d_Rotor3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec3 pga3d_rotor3_anti_wedge_vec3(pga3d_Rotor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_rotor3_right_contraction_vec3(pga3d_Rotor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_rotor3_commutator_vec3(pga3d_Rotor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_rotor3_anti_dot_vec3(pga3d_Rotor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_rotor3_geometric_vec4(pga3d_Rotor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_rotor3_anti_geometric_vec4(pga3d_Rotor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_rotor3_dot_vec4(pga3d_Rotor3 lhs, pga3d_Vec4 rhs);
+pga3d_Plane pga3d_rotor3_wedge_vec4(pga3d_Rotor3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_rotor3_anti_wedge_vec4(pga3d_Rotor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_rotor3_right_contraction_vec4(pga3d_Rotor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_rotor3_commutator_vec4(pga3d_Rotor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_rotor3_anti_dot_vec4(pga3d_Rotor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_rotor3_geometric_point3(pga3d_Rotor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_rotor3_anti_geometric_point3(pga3d_Rotor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_rotor3_dot_point3(pga3d_Rotor3 lhs, pga3d_Point3 rhs);
+pga3d_Plane pga3d_rotor3_wedge_point3(pga3d_Rotor3 lhs, pga3d_Point3 rhs);
+pga3d_Vec4 pga3d_rotor3_anti_wedge_point3(pga3d_Rotor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_rotor3_right_contraction_point3(pga3d_Rotor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_rotor3_commutator_point3(pga3d_Rotor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_rotor3_anti_dot_point3(pga3d_Rotor3 lhs, pga3d_Point3 rhs);
+pga3d_Rotor3 pga3d_rotor3_geometric_moment3(pga3d_Rotor3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_geometric_moment3(pga3d_Rotor3 lhs, pga3d_Moment3 rhs);
+pga3d_Line3 pga3d_rotor3_dot_moment3(pga3d_Rotor3 lhs, pga3d_Moment3 rhs);
+double pga3d_rotor3_wedge_moment3(pga3d_Rotor3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_wedge_moment3(pga3d_Rotor3 lhs, pga3d_Moment3 rhs);
+pga3d_Line3 pga3d_rotor3_right_contraction_moment3(pga3d_Rotor3 lhs, pga3d_Moment3 rhs);
+pga3d_Line3 pga3d_rotor3_commutator_moment3(pga3d_Rotor3 lhs, pga3d_Moment3 rhs);
+pga3d_Moment3 pga3d_rotor3_anti_dot_moment3(pga3d_Rotor3 lhs, pga3d_Moment3 rhs);
+pga3d_Rotor3 pga3d_rotor3_geometric_line3(pga3d_Rotor3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_geometric_line3(pga3d_Rotor3 lhs, pga3d_Line3 rhs);
+pga3d_Line3 pga3d_rotor3_dot_line3(pga3d_Rotor3 lhs, pga3d_Line3 rhs);
+double pga3d_rotor3_wedge_line3(pga3d_Rotor3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_wedge_line3(pga3d_Rotor3 lhs, pga3d_Line3 rhs);
+pga3d_Line3 pga3d_rotor3_right_contraction_line3(pga3d_Rotor3 lhs, pga3d_Line3 rhs);
+pga3d_Line3 pga3d_rotor3_commutator_line3(pga3d_Rotor3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_dot_line3(pga3d_Rotor3 lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_rotor3_geometric_plane(pga3d_Rotor3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_rotor3_anti_geometric_plane(pga3d_Rotor3 lhs, pga3d_Plane rhs);
+double pga3d_rotor3_dot_plane(pga3d_Rotor3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_rotor3_anti_wedge_plane(pga3d_Rotor3 lhs, pga3d_Plane rhs);
+double pga3d_rotor3_right_contraction_plane(pga3d_Rotor3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_rotor3_commutator_plane(pga3d_Rotor3 lhs, pga3d_Plane rhs);
+pga3d_Plane pga3d_rotor3_anti_dot_plane(pga3d_Rotor3 lhs, pga3d_Plane rhs);
+pga3d_Rotor3 pga3d_rotor3_geometric_translator3(pga3d_Rotor3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_geometric_translator3(pga3d_Rotor3 lhs, pga3d_Translator3 rhs);
+pga3d_Line3 pga3d_rotor3_dot_translator3(pga3d_Rotor3 lhs, pga3d_Translator3 rhs);
+double pga3d_rotor3_wedge_translator3(pga3d_Rotor3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_wedge_translator3(pga3d_Rotor3 lhs, pga3d_Translator3 rhs);
+pga3d_Line3 pga3d_rotor3_right_contraction_translator3(pga3d_Rotor3 lhs, pga3d_Translator3 rhs);
+pga3d_Line3 pga3d_rotor3_commutator_translator3(pga3d_Rotor3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_dot_translator3(pga3d_Rotor3 lhs, pga3d_Translator3 rhs);
+pga3d_Rotor3 pga3d_rotor3_anti_geometric_rotor3(pga3d_Rotor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Rotor3 pga3d_rotor3_anti_wedge_rotor3(pga3d_Rotor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Rotor3 pga3d_rotor3_anti_dot_rotor3(pga3d_Rotor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Rotor3 pga3d_rotor3_geometric_motor3(pga3d_Rotor3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_geometric_motor3(pga3d_Rotor3 lhs, pga3d_Motor3 rhs);
+pga3d_Rotor3 pga3d_rotor3_dot_motor3(pga3d_Rotor3 lhs, pga3d_Motor3 rhs);
+pga3d_Rotor3 pga3d_rotor3_wedge_motor3(pga3d_Rotor3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_wedge_motor3(pga3d_Rotor3 lhs, pga3d_Motor3 rhs);
+pga3d_Rotor3 pga3d_rotor3_right_contraction_motor3(pga3d_Rotor3 lhs, pga3d_Motor3 rhs);
+pga3d_Line3 pga3d_rotor3_commutator_motor3(pga3d_Rotor3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_rotor3_anti_dot_motor3(pga3d_Rotor3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_rotor3_geometric_multivector(pga3d_Rotor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_rotor3_anti_geometric_multivector(pga3d_Rotor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_rotor3_dot_multivector(pga3d_Rotor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_rotor3_wedge_multivector(pga3d_Rotor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_rotor3_anti_wedge_multivector(pga3d_Rotor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_rotor3_right_contraction_multivector(pga3d_Rotor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_rotor3_commutator_multivector(pga3d_Rotor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_rotor3_anti_dot_multivector(pga3d_Rotor3 lhs, pga3d_Multivector rhs);
+pga3d_Vec3 pga3d_rotor3_anti_sandwich_vec3(pga3d_Rotor3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec4 pga3d_rotor3_anti_sandwich_vec4(pga3d_Rotor3 lhs, pga3d_Vec4 rhs);
+pga3d_Moment3 pga3d_rotor3_anti_sandwich_moment3(pga3d_Rotor3 lhs, pga3d_Moment3 rhs);
+pga3d_Line3 pga3d_rotor3_anti_sandwich_line3(pga3d_Rotor3 lhs, pga3d_Line3 rhs);
+pga3d_Plane pga3d_rotor3_anti_sandwich_plane(pga3d_Rotor3 lhs, pga3d_Plane rhs);
+pga3d_Motor3 pga3d_motor3_rcompl(pga3d_Motor3 value);
+pga3d_Motor3 pga3d_motor3_lcompl(pga3d_Motor3 value);
+pga3d_Motor3 pga3d_motor3_rev(pga3d_Motor3 value);
+pga3d_Motor3 pga3d_motor3_arev(pga3d_Motor3 value);
+pga3d_Motor3 pga3d_motor3_involute(pga3d_Motor3 value);
+pga3d_Motor3 pga3d_motor3_conjugate(pga3d_Motor3 value);
+pga3d_Motor3 pga3d_motor3_dual(pga3d_Motor3 value);
+pga3d_Motor3 pga3d_motor3_bulk(pga3d_Motor3 value);
+pga3d_Rotor3 pga3d_motor3_weight(pga3d_Motor3 value);
+pga3d_Rotor3 pga3d_motor3_bulk_dual(pga3d_Motor3 value);
+pga3d_Motor3 pga3d_motor3_weight_dual(pga3d_Motor3 value);
+pga3d_Multivector pga3d_motor3_geometric_vec3(pga3d_Motor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_motor3_anti_geometric_vec3(pga3d_Motor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_motor3_dot_vec3(pga3d_Motor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_motor3_wedge_vec3(pga3d_Motor3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec3 pga3d_motor3_anti_wedge_vec3(pga3d_Motor3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec3 pga3d_motor3_left_contraction_vec3(pga3d_Motor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_motor3_right_contraction_vec3(pga3d_Motor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_motor3_commutator_vec3(pga3d_Motor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_motor3_anti_dot_vec3(pga3d_Motor3 lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_motor3_geometric_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_motor3_anti_geometric_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_motor3_dot_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_motor3_wedge_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_motor3_anti_wedge_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_motor3_left_contraction_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_motor3_right_contraction_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_motor3_commutator_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_motor3_anti_dot_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_motor3_geometric_point3(pga3d_Motor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_motor3_anti_geometric_point3(pga3d_Motor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_motor3_dot_point3(pga3d_Motor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_motor3_wedge_point3(pga3d_Motor3 lhs, pga3d_Point3 rhs);
+pga3d_Vec4 pga3d_motor3_anti_wedge_point3(pga3d_Motor3 lhs, pga3d_Point3 rhs);
+pga3d_Vec4 pga3d_motor3_left_contraction_point3(pga3d_Motor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_motor3_right_contraction_point3(pga3d_Motor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_motor3_commutator_point3(pga3d_Motor3 lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_motor3_anti_dot_point3(pga3d_Motor3 lhs, pga3d_Point3 rhs);
+pga3d_Motor3 pga3d_motor3_geometric_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_geometric_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_motor3_dot_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+pga3d_Translator3 pga3d_motor3_wedge_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_wedge_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_motor3_left_contraction_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_motor3_right_contraction_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+double pga3d_motor3_scalar_product_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+pga3d_Line3 pga3d_motor3_commutator_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+pga3d_Moment3 pga3d_motor3_anti_dot_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+pga3d_Motor3 pga3d_motor3_geometric_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_geometric_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_motor3_dot_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_motor3_wedge_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_wedge_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_motor3_left_contraction_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_motor3_right_contraction_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+double pga3d_motor3_scalar_product_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Line3 pga3d_motor3_commutator_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_dot_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_motor3_geometric_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_motor3_anti_geometric_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_motor3_dot_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+pga3d_Plane pga3d_motor3_wedge_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_motor3_anti_wedge_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_motor3_left_contraction_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+double pga3d_motor3_right_contraction_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_motor3_commutator_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_motor3_anti_dot_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+pga3d_Motor3 pga3d_motor3_geometric_translator3(pga3d_Motor3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_geometric_translator3(pga3d_Motor3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_motor3_dot_translator3(pga3d_Motor3 lhs, pga3d_Translator3 rhs);
+pga3d_Translator3 pga3d_motor3_wedge_translator3(pga3d_Motor3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_wedge_translator3(pga3d_Motor3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_motor3_left_contraction_translator3(pga3d_Motor3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_motor3_right_contraction_translator3(pga3d_Motor3 lhs, pga3d_Translator3 rhs);
+double pga3d_motor3_scalar_product_translator3(pga3d_Motor3 lhs, pga3d_Translator3 rhs);
+pga3d_Line3 pga3d_motor3_commutator_translator3(pga3d_Motor3 lhs, pga3d_Translator3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_dot_translator3(pga3d_Motor3 lhs, pga3d_Translator3 rhs);
+pga3d_Rotor3 pga3d_motor3_geometric_rotor3(pga3d_Motor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_geometric_rotor3(pga3d_Motor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Rotor3 pga3d_motor3_dot_rotor3(pga3d_Motor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Rotor3 pga3d_motor3_wedge_rotor3(pga3d_Motor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_wedge_rotor3(pga3d_Motor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Rotor3 pga3d_motor3_left_contraction_rotor3(pga3d_Motor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Line3 pga3d_motor3_commutator_rotor3(pga3d_Motor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_dot_rotor3(pga3d_Motor3 lhs, pga3d_Rotor3 rhs);
+pga3d_Motor3 pga3d_motor3_geometric_motor3(pga3d_Motor3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_geometric_motor3(pga3d_Motor3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_motor3_dot_motor3(pga3d_Motor3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_motor3_wedge_motor3(pga3d_Motor3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_wedge_motor3(pga3d_Motor3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_motor3_left_contraction_motor3(pga3d_Motor3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_motor3_right_contraction_motor3(pga3d_Motor3 lhs, pga3d_Motor3 rhs);
+double pga3d_motor3_scalar_product_motor3(pga3d_Motor3 lhs, pga3d_Motor3 rhs);
+pga3d_Line3 pga3d_motor3_commutator_motor3(pga3d_Motor3 lhs, pga3d_Motor3 rhs);
+pga3d_Motor3 pga3d_motor3_anti_dot_motor3(pga3d_Motor3 lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_motor3_geometric_multivector(pga3d_Motor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_motor3_anti_geometric_multivector(pga3d_Motor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_motor3_dot_multivector(pga3d_Motor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_motor3_wedge_multivector(pga3d_Motor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_motor3_anti_wedge_multivector(pga3d_Motor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_motor3_left_contraction_multivector(pga3d_Motor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_motor3_right_contraction_multivector(pga3d_Motor3 lhs, pga3d_Multivector rhs);
+double pga3d_motor3_scalar_product_multivector(pga3d_Motor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_motor3_commutator_multivector(pga3d_Motor3 lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_motor3_anti_dot_multivector(pga3d_Motor3 lhs, pga3d_Multivector rhs);
+pga3d_Vec3 pga3d_motor3_anti_sandwich_vec3(pga3d_Motor3 lhs, pga3d_Vec3 rhs);
+pga3d_Vec4 pga3d_motor3_sandwich_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Vec4 pga3d_motor3_anti_sandwich_vec4(pga3d_Motor3 lhs, pga3d_Vec4 rhs);
+pga3d_Moment3 pga3d_motor3_anti_sandwich_moment3(pga3d_Motor3 lhs, pga3d_Moment3 rhs);
+pga3d_Line3 pga3d_motor3_sandwich_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Line3 pga3d_motor3_anti_sandwich_line3(pga3d_Motor3 lhs, pga3d_Line3 rhs);
+pga3d_Plane pga3d_motor3_sandwich_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+pga3d_Plane pga3d_motor3_anti_sandwich_plane(pga3d_Motor3 lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_multivector_rcompl(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_lcompl(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_rev(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_arev(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_involute(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_conjugate(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_dual(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_bulk(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_weight(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_bulk_dual(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_weight_dual(pga3d_Multivector value);
+pga3d_Multivector pga3d_multivector_geometric_vec3(pga3d_Multivector lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_multivector_anti_geometric_vec3(pga3d_Multivector lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_multivector_dot_vec3(pga3d_Multivector lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_multivector_wedge_vec3(pga3d_Multivector lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_multivector_anti_wedge_vec3(pga3d_Multivector lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_multivector_left_contraction_vec3(pga3d_Multivector lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_multivector_right_contraction_vec3(pga3d_Multivector lhs, pga3d_Vec3 rhs);
+double pga3d_multivector_scalar_product_vec3(pga3d_Multivector lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_multivector_commutator_vec3(pga3d_Multivector lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_multivector_anti_dot_vec3(pga3d_Multivector lhs, pga3d_Vec3 rhs);
+pga3d_Multivector pga3d_multivector_geometric_vec4(pga3d_Multivector lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_multivector_anti_geometric_vec4(pga3d_Multivector lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_multivector_dot_vec4(pga3d_Multivector lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_multivector_wedge_vec4(pga3d_Multivector lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_multivector_anti_wedge_vec4(pga3d_Multivector lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_multivector_left_contraction_vec4(pga3d_Multivector lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_multivector_right_contraction_vec4(pga3d_Multivector lhs, pga3d_Vec4 rhs);
+double pga3d_multivector_scalar_product_vec4(pga3d_Multivector lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_multivector_commutator_vec4(pga3d_Multivector lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_multivector_anti_dot_vec4(pga3d_Multivector lhs, pga3d_Vec4 rhs);
+pga3d_Multivector pga3d_multivector_geometric_point3(pga3d_Multivector lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_multivector_anti_geometric_point3(pga3d_Multivector lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_multivector_dot_point3(pga3d_Multivector lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_multivector_wedge_point3(pga3d_Multivector lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_multivector_anti_wedge_point3(pga3d_Multivector lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_multivector_left_contraction_point3(pga3d_Multivector lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_multivector_right_contraction_point3(pga3d_Multivector lhs, pga3d_Point3 rhs);
+double pga3d_multivector_scalar_product_point3(pga3d_Multivector lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_multivector_commutator_point3(pga3d_Multivector lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_multivector_anti_dot_point3(pga3d_Multivector lhs, pga3d_Point3 rhs);
+pga3d_Multivector pga3d_multivector_geometric_moment3(pga3d_Multivector lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_multivector_anti_geometric_moment3(pga3d_Multivector lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_multivector_dot_moment3(pga3d_Multivector lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_multivector_wedge_moment3(pga3d_Multivector lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_multivector_anti_wedge_moment3(pga3d_Multivector lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_multivector_left_contraction_moment3(pga3d_Multivector lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_multivector_right_contraction_moment3(pga3d_Multivector lhs, pga3d_Moment3 rhs);
+double pga3d_multivector_scalar_product_moment3(pga3d_Multivector lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_multivector_commutator_moment3(pga3d_Multivector lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_multivector_anti_dot_moment3(pga3d_Multivector lhs, pga3d_Moment3 rhs);
+pga3d_Multivector pga3d_multivector_geometric_line3(pga3d_Multivector lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_multivector_anti_geometric_line3(pga3d_Multivector lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_multivector_dot_line3(pga3d_Multivector lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_multivector_wedge_line3(pga3d_Multivector lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_multivector_anti_wedge_line3(pga3d_Multivector lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_multivector_left_contraction_line3(pga3d_Multivector lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_multivector_right_contraction_line3(pga3d_Multivector lhs, pga3d_Line3 rhs);
+double pga3d_multivector_scalar_product_line3(pga3d_Multivector lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_multivector_commutator_line3(pga3d_Multivector lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_multivector_anti_dot_line3(pga3d_Multivector lhs, pga3d_Line3 rhs);
+pga3d_Multivector pga3d_multivector_geometric_plane(pga3d_Multivector lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_multivector_anti_geometric_plane(pga3d_Multivector lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_multivector_dot_plane(pga3d_Multivector lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_multivector_wedge_plane(pga3d_Multivector lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_multivector_anti_wedge_plane(pga3d_Multivector lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_multivector_left_contraction_plane(pga3d_Multivector lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_multivector_right_contraction_plane(pga3d_Multivector lhs, pga3d_Plane rhs);
+double pga3d_multivector_scalar_product_plane(pga3d_Multivector lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_multivector_commutator_plane(pga3d_Multivector lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_multivector_anti_dot_plane(pga3d_Multivector lhs, pga3d_Plane rhs);
+pga3d_Multivector pga3d_multivector_geometric_translator3(pga3d_Multivector lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_multivector_anti_geometric_translator3(pga3d_Multivector lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_multivector_dot_translator3(pga3d_Multivector lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_multivector_wedge_translator3(pga3d_Multivector lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_multivector_anti_wedge_translator3(pga3d_Multivector lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_multivector_left_contraction_translator3(pga3d_Multivector lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_multivector_right_contraction_translator3(pga3d_Multivector lhs, pga3d_Translator3 rhs);
+double pga3d_multivector_scalar_product_translator3(pga3d_Multivector lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_multivector_commutator_translator3(pga3d_Multivector lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_multivector_anti_dot_translator3(pga3d_Multivector lhs, pga3d_Translator3 rhs);
+pga3d_Multivector pga3d_multivector_geometric_rotor3(pga3d_Multivector lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_multivector_anti_geometric_rotor3(pga3d_Multivector lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_multivector_dot_rotor3(pga3d_Multivector lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_multivector_wedge_rotor3(pga3d_Multivector lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_multivector_anti_wedge_rotor3(pga3d_Multivector lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_multivector_left_contraction_rotor3(pga3d_Multivector lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_multivector_commutator_rotor3(pga3d_Multivector lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_multivector_anti_dot_rotor3(pga3d_Multivector lhs, pga3d_Rotor3 rhs);
+pga3d_Multivector pga3d_multivector_geometric_motor3(pga3d_Multivector lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_multivector_anti_geometric_motor3(pga3d_Multivector lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_multivector_dot_motor3(pga3d_Multivector lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_multivector_wedge_motor3(pga3d_Multivector lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_multivector_anti_wedge_motor3(pga3d_Multivector lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_multivector_left_contraction_motor3(pga3d_Multivector lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_multivector_right_contraction_motor3(pga3d_Multivector lhs, pga3d_Motor3 rhs);
+double pga3d_multivector_scalar_product_motor3(pga3d_Multivector lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_multivector_commutator_motor3(pga3d_Multivector lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_multivector_anti_dot_motor3(pga3d_Multivector lhs, pga3d_Motor3 rhs);
+pga3d_Multivector pga3d_multivector_geometric_multivector(pga3d_Multivector lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_multivector_anti_geometric_multivector(pga3d_Multivector lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_multivector_dot_multivector(pga3d_Multivector lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_multivector_wedge_multivector(pga3d_Multivector lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_multivector_anti_wedge_multivector(pga3d_Multivector lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_multivector_left_contraction_multivector(pga3d_Multivector lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_multivector_right_contraction_multivector(pga3d_Multivector lhs, pga3d_Multivector rhs);
+double pga3d_multivector_scalar_product_multivector(pga3d_Multivector lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_multivector_commutator_multivector(pga3d_Multivector lhs, pga3d_Multivector rhs);
+pga3d_Multivector pga3d_multivector_anti_dot_multivector(pga3d_Multivector lhs, pga3d_Multivector rhs);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PGA3D_H */
@@ -3,33 +3,165 @@
 //! ## Operations
 //! ```text
 //! Plane.geometric(Plane) -> Motor3
+//! Plane.anti_geometric(Plane) -> Motor3
 //! Plane.dot(Plane) -> S
 //! Plane.anti_wedge(Plane) -> Line3
+//! Plane.left_contraction(Plane) -> S
+//! Plane.right_contraction(Plane) -> S
+//! Plane.scalar_product(Plane) -> S
+//! Plane.commutator(Plane) -> Line3
+//! Plane.anti_dot(Plane) -> XYZW
+//! Plane.geometric(Vec3) -> Motor3
+//! Vec3.geometric(Plane) -> Motor3
+//! Plane.anti_geometric(Vec3) -> Motor3
+//! Vec3.anti_geometric(Plane) -> Motor3
 //! Plane.dot(Vec3) -> Line3
 //! Vec3.dot(Plane) -> Line3
 //! Plane.wedge(Vec3) -> XYZW
 //! Vec3.wedge(Plane) -> XYZW
 //! Plane.anti_wedge(Vec3) -> S
 //! Vec3.anti_wedge(Plane) -> S
+//! Vec3.left_contraction(Plane) -> Line3
+//! Plane.right_contraction(Vec3) -> Line3
+//! Plane.commutator(Vec3) -> XYZW
+//! Vec3.commutator(Plane) -> XYZW
+//! Plane.anti_dot(Vec3) -> Moment3
+//! Vec3.anti_dot(Plane) -> Moment3
+//! Plane.geometric(Vec4) -> Motor3
+//! Vec4.geometric(Plane) -> Motor3
+//! Plane.anti_geometric(Vec4) -> Motor3
+//! Vec4.anti_geometric(Plane) -> Motor3
 //! Plane.dot(Vec4) -> Line3
 //! Vec4.dot(Plane) -> Line3
+//! Plane.wedge(Vec4) -> XYZW
+//! Vec4.wedge(Plane) -> XYZW
+//! Plane.anti_wedge(Vec4) -> S
+//! Vec4.anti_wedge(Plane) -> S
+//! Vec4.left_contraction(Plane) -> Line3
+//! Plane.right_contraction(Vec4) -> Line3
+//! Plane.commutator(Vec4) -> XYZW
+//! Vec4.commutator(Plane) -> XYZW
+//! Plane.anti_dot(Vec4) -> Line3
+//! Vec4.anti_dot(Plane) -> Line3
+//! Plane.geometric(Point3) -> Motor3
+//! Point3.geometric(Plane) -> Motor3
+//! Plane.anti_geometric(Point3) -> Motor3
+//! Point3.anti_geometric(Plane) -> Motor3
+//! Plane.dot(Point3) -> Line3
+//! Point3.dot(Plane) -> Line3
+//! Plane.wedge(Point3) -> XYZW
+//! Point3.wedge(Plane) -> XYZW
+//! Plane.anti_wedge(Point3) -> S
+//! Point3.anti_wedge(Plane) -> S
+//! Point3.left_contraction(Plane) -> Line3
+//! Plane.right_contraction(Point3) -> Line3
+//! Plane.commutator(Point3) -> XYZW
+//! Point3.commutator(Plane) -> XYZW
+//! Plane.anti_dot(Point3) -> Line3
+//! Point3.anti_dot(Plane) -> Line3
+//! Plane.geometric(Moment3) -> Multivector
+//! Moment3.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Moment3) -> Multivector
+//! Moment3.anti_geometric(Plane) -> Multivector
 //! Plane.dot(Moment3) -> Vec4
 //! Moment3.dot(Plane) -> Vec4
 //! Plane.anti_wedge(Moment3) -> Vec3
 //! Moment3.anti_wedge(Plane) -> Vec3
+//! Moment3.left_contraction(Plane) -> Vec4
+//! Plane.right_contraction(Moment3) -> Vec4
+//! Plane.commutator(Moment3) -> Plane
+//! Moment3.commutator(Plane) -> Plane
+//! Plane.anti_dot(Moment3) -> XYZ
+//! Moment3.anti_dot(Plane) -> XYZ
+//! Plane.geometric(Line3) -> Multivector
+//! Line3.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Line3) -> Multivector
+//! Line3.anti_geometric(Plane) -> Multivector
 //! Plane.dot(Line3) -> Vec4
 //! Line3.dot(Plane) -> Vec4
 //! Plane.anti_wedge(Line3) -> Vec4
 //! Line3.anti_wedge(Plane) -> Vec4
+//! Line3.left_contraction(Plane) -> Vec4
+//! Plane.right_contraction(Line3) -> Vec4
+//! Plane.commutator(Line3) -> Plane
+//! Line3.commutator(Plane) -> Plane
+//! Plane.anti_dot(Line3) -> Plane
+//! Line3.anti_dot(Plane) -> Plane
+//! Plane.geometric(Translator3) -> Multivector
+//! Translator3.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Translator3) -> Multivector
+//! Translator3.anti_geometric(Plane) -> Multivector
+//! Plane.dot(Translator3) -> Vec4
+//! Translator3.dot(Plane) -> Vec4
+//! Plane.anti_wedge(Translator3) -> Multivector
+//! Translator3.anti_wedge(Plane) -> Multivector
+//! Plane.left_contraction(Translator3) -> W
+//! Translator3.left_contraction(Plane) -> Vec4
+//! Plane.right_contraction(Translator3) -> Vec4
+//! Translator3.right_contraction(Plane) -> W
+//! Plane.commutator(Translator3) -> Multivector
+//! Translator3.commutator(Plane) -> Multivector
+//! Plane.anti_dot(Translator3) -> Plane
+//! Translator3.anti_dot(Plane) -> Plane
+//! Plane.geometric(Rotor3) -> Multivector
+//! Rotor3.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Plane) -> Multivector
 //! Plane.dot(Rotor3) -> W
 //! Rotor3.dot(Plane) -> W
+//! Plane.anti_wedge(Rotor3) -> Multivector
+//! Rotor3.anti_wedge(Plane) -> Multivector
+//! Plane.left_contraction(Rotor3) -> W
+//! Rotor3.right_contraction(Plane) -> W
+//! Plane.commutator(Rotor3) -> Multivector
+//! Rotor3.commutator(Plane) -> Multivector
+//! Plane.anti_dot(Rotor3) -> Plane
+//! Rotor3.anti_dot(Plane) -> Plane
+//! Plane.geometric(Motor3) -> Multivector
+//! Motor3.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Motor3) -> Multivector
+//! Motor3.anti_geometric(Plane) -> Multivector
+//! Plane.dot(Motor3) -> Multivector
+//! Motor3.dot(Plane) -> Multivector
 //! Plane.wedge(Motor3) -> Plane
 //! Motor3.wedge(Plane) -> Plane
+//! Plane.anti_wedge(Motor3) -> Multivector
+//! Motor3.anti_wedge(Plane) -> Multivector
+//! Plane.left_contraction(Motor3) -> W
+//! Motor3.left_contraction(Plane) -> Multivector
+//! Plane.right_contraction(Motor3) -> Multivector
+//! Motor3.right_contraction(Plane) -> W
+//! Plane.commutator(Motor3) -> Multivector
+//! Motor3.commutator(Plane) -> Multivector
+//! Plane.anti_dot(Motor3) -> Multivector
+//! Motor3.anti_dot(Plane) -> Multivector
+//! Plane.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Plane) -> Multivector
+//! Plane.dot(Multivector) -> Multivector
+//! Multivector.dot(Plane) -> Multivector
+//! Plane.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Plane) -> Multivector
+//! Plane.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Plane) -> Multivector
+//! Plane.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Plane) -> Multivector
+//! Plane.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Plane) -> Multivector
+//! Plane.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Plane) -> S
+//! Plane.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Plane) -> Multivector
+//! Plane.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Plane) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Plane {
 	pub nx: YZW,
 	pub ny: ZXW,
@@ -37,20 +169,65 @@ pub struct Plane {
 	pub d: XYZ,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Plane {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Plane {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Plane {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.nx.abs_diff_eq(&other.nx, epsilon)
+			&& self.ny.abs_diff_eq(&other.ny, epsilon)
+			&& self.nz.abs_diff_eq(&other.nz, epsilon)
+			&& self.d.abs_diff_eq(&other.d, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Plane {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.nx.relative_eq(&other.nx, epsilon, max_relative)
+			&& self.ny.relative_eq(&other.ny, epsilon, max_relative)
+			&& self.nz.relative_eq(&other.nz, epsilon, max_relative)
+			&& self.d.relative_eq(&other.d, epsilon, max_relative)
+	}
+}
+
 // ---------------------------------------------------------------------
 
 impl RCompl for Plane {
 	type Output = Vec4;
 	fn rcompl(self) -> Self::Output {
 		Vec4 {
-			x: -self.nx.rcompl(),
-			y: -self.ny.rcompl(),
-			z: -self.nz.rcompl(),
+			x: self.nx.rcompl(),
+			y: self.ny.rcompl(),
+			z: self.nz.rcompl(),
 			w: self.d.rcompl(),
 		}
 	}
 }
 
+impl std::ops::Not for Plane {
+	type Output = Vec4;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl LCompl for Plane {
 	type Output = Vec4;
 	fn lcompl(self) -> Self::Output {
@@ -58,7 +235,7 @@ impl LCompl for Plane {
 			x: self.nx.lcompl(),
 			y: self.ny.lcompl(),
 			z: self.nz.lcompl(),
-			w: -self.d.lcompl(),
+			w: self.d.lcompl(),
 		}
 	}
 }
@@ -85,28 +262,281 @@ impl AntiReverse for Plane {
 	}
 }
 
+impl Involute for Plane {
+	fn involute(self) -> Self {
+		Plane {
+			nx: -self.nx,
+			ny: -self.ny,
+			nz: -self.nz,
+			d: -self.d,
+		}
+	}
+}
+
+impl Conjugate for Plane {
+	fn conjugate(self) -> Self {
+		Plane {
+			nx: self.nx,
+			ny: self.ny,
+			nz: self.nz,
+			d: self.d,
+		}
+	}
+}
+
+impl Dual for Plane {
+	type Output = Vec4;
+	fn dual(self) -> Self::Output {
+		Vec4 {
+			x: self.nx.dual(),
+			y: self.ny.dual(),
+			z: self.nz.dual(),
+			w: self.d.dual(),
+		}
+	}
+}
+
+impl Bulk for Plane {
+	type Output = XYZ;
+	fn bulk(self) -> Self::Output {
+		self.d
+	}
+}
+
+impl Weight for Plane {
+	type Output = Plane;
+	fn weight(self) -> Self::Output {
+		Plane {
+			nx: self.nx,
+			ny: self.ny,
+			nz: self.nz,
+			d: Default::default(),
+		}
+	}
+}
+
+impl BulkDual for Plane {
+	type Output = W;
+	fn bulk_dual(self) -> Self::Output {
+		self.d.bulk_dual()
+	}
+}
+
+impl WeightDual for Plane {
+	type Output = Vec3;
+	fn weight_dual(self) -> Self::Output {
+		Vec3 {
+			x: self.nx.weight_dual(),
+			y: self.ny.weight_dual(),
+			z: self.nz.weight_dual(),
+		}
+	}
+}
+
+impl Square for Plane {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.d.geometric(self.d).0
+	}
+}
+
+impl Norm for Plane {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.nx.0 * self.nx.0 + self.ny.0 * self.ny.0 + self.nz.0 * self.nz.0 + self.d.0 * self.d.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.d.0 * self.d.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.nx.0 * self.nx.0 + self.ny.0 * self.ny.0 + self.nz.0 * self.nz.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.weight_norm();
+		Plane {
+			nx: self.nx * scale,
+			ny: self.ny * scale,
+			nz: self.nz * scale,
+			d: self.d * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Plane {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.nx.0, self.ny.0, self.nz.0, self.d.0]
+	}
+}
+
+impl From<[f64; 4]> for Plane {
+	fn from(a: [f64; 4]) -> Self {
+		Plane {
+			nx: YZW(a[0]),
+			ny: ZXW(a[1]),
+			nz: XYW(a[2]),
+			d: XYZ(a[3]),
+		}
+	}
+}
+
+impl From<Plane> for [f64; 4] {
+	fn from(v: Plane) -> Self {
+		v.to_array()
+	}
+}
+
+impl Plane {
+	/// The plane of all points `p` where `normal · p = distance`.
+	/// `Point3 ^ Plane` then gives the signed distance to the plane (if `normal` is normalized).
+	pub fn from_normal_and_distance(normal: [f64; 3], distance: f64) -> Self {
+		Plane {
+			nx: YZW(normal[0]),
+			ny: ZXW(normal[1]),
+			nz: XYW(normal[2]),
+			d: XYZ(distance),
+		}
+	}
+}
+
+impl From<Plane> for Multivector {
+	fn from(v: Plane) -> Self {
+		Multivector {
+			yzw: v.nx,
+			zxw: v.ny,
+			xyw: v.nz,
+			xyz: v.d,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Plane {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Plane` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.x.0 == 0.0
+			&& mv.y.0 == 0.0
+			&& mv.z.0 == 0.0
+			&& mv.w.0 == 0.0
+			&& mv.wx.0 == 0.0
+			&& mv.wy.0 == 0.0
+			&& mv.wz.0 == 0.0
+			&& mv.yz.0 == 0.0
+			&& mv.zx.0 == 0.0
+			&& mv.xy.0 == 0.0
+			&& mv.xyzw.0 == 0.0
+		{
+			Ok(Plane {
+				nx: mv.yzw,
+				ny: mv.zxw,
+				nz: mv.xyw,
+				d: mv.xyz,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Plane OP Vec3:
 
-// Omitted: Plane geometric Vec3 = self.d * rhs.x + self.d * rhs.y + self.d * rhs.z + self.nx * rhs.x + self.nx * rhs.y + self.nx * rhs.z + self.ny * rhs.x + self.ny * rhs.y + self.ny * rhs.z + self.nz * rhs.x + self.nz * rhs.y + self.nz * rhs.z  (unnamed type)
-// Omitted: Plane anti_geometric Vec3 = self.nx !* rhs.x + self.nx !* rhs.y + self.nx !* rhs.z + self.ny !* rhs.x + self.ny !* rhs.y + self.ny !* rhs.z + self.nz !* rhs.x + self.nz !* rhs.y + self.nz !* rhs.z  (unnamed type)
+// Plane.geometric(Vec3) -> Motor3
+impl Geometric<Vec3> for Plane {
+	type Output = Motor3;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Motor3 {
+		//     rx: -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     ry: WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     rz: -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     rw: -XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0),
+		//     ux: YZ(self.d.0 * rhs.x.0),
+		//     uy: ZX(self.d.0 * rhs.y.0),
+		//     uz: XY(self.d.0 * rhs.z.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.ny.geometric(rhs.z) + self.nz.geometric(rhs.y),
+			ry: self.nx.geometric(rhs.z) + self.nz.geometric(rhs.x),
+			rz: self.nx.geometric(rhs.y) + self.ny.geometric(rhs.x),
+			rw: self.nx.geometric(rhs.x) + self.ny.geometric(rhs.y) + self.nz.geometric(rhs.z),
+			ux: self.d.geometric(rhs.x),
+			uy: self.d.geometric(rhs.y),
+			uz: self.d.geometric(rhs.z),
+			uw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Plane {
+	type Output = Motor3;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Vec3) -> Motor3
+impl AntiGeometric<Vec3> for Plane {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.ny.0 * rhs.z.0) - YZ(self.nz.0 * rhs.y.0),
+		//     uy: -ZX(self.nx.0 * rhs.z.0) + ZX(self.nz.0 * rhs.x.0),
+		//     uz: XY(self.nx.0 * rhs.y.0) - XY(self.ny.0 * rhs.x.0),
+		//     uw: -S(self.nx.0 * rhs.x.0) - S(self.ny.0 * rhs.y.0) - S(self.nz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.ny.anti_geometric(rhs.z) + self.nz.anti_geometric(rhs.y),
+			uy: self.nx.anti_geometric(rhs.z) + self.nz.anti_geometric(rhs.x),
+			uz: self.nx.anti_geometric(rhs.y) + self.ny.anti_geometric(rhs.x),
+			uw: self.nx.anti_geometric(rhs.x) + self.ny.anti_geometric(rhs.y) + self.nz.anti_geometric(rhs.z),
+		}
+	}
+}
 
 // Plane.dot(Vec3) -> Line3
 impl Dot<Vec3> for Plane {
 	type Output = Line3;
 	fn dot(self, rhs: Vec3) -> Self::Output {
 		// Line3 {
-		//     vx: WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
-		//     vy: WY(self.nx.0 * rhs.z.0) + WY(self.nz.0 * rhs.x.0),
-		//     vz: WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     vx: -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     vy: WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     vz: -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
 		//     mx: YZ(self.d.0 * rhs.x.0),
 		//     my: ZX(self.d.0 * rhs.y.0),
 		//     mz: XY(self.d.0 * rhs.z.0),
 		// }
 		Line3 {
-			vx: -self.ny.dot(rhs.z) + self.nz.dot(rhs.y),
-			vy: self.nx.dot(rhs.z) - self.nz.dot(rhs.x),
-			vz: -self.nx.dot(rhs.y) + self.ny.dot(rhs.x),
+			vx: self.ny.dot(rhs.z) + self.nz.dot(rhs.y),
+			vy: self.nx.dot(rhs.z) + self.nz.dot(rhs.x),
+			vz: self.nx.dot(rhs.y) + self.ny.dot(rhs.x),
 			mx: self.d.dot(rhs.x),
 			my: self.d.dot(rhs.y),
 			mz: self.d.dot(rhs.z),
@@ -114,6 +544,13 @@ impl Dot<Vec3> for Plane {
 	}
 }
 
+impl std::ops::BitOr<Vec3> for Plane {
+	type Output = Line3;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Plane.wedge(Vec3) -> XYZW
 impl Wedge<Vec3> for Plane {
 	type Output = XYZW;
@@ -123,6 +560,13 @@ impl Wedge<Vec3> for Plane {
 	}
 }
 
+impl std::ops::BitXor<Vec3> for Plane {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Plane.anti_wedge(Vec3) -> S
 impl AntiWedge<Vec3> for Plane {
 	type Output = S;
@@ -132,205 +576,1848 @@ impl AntiWedge<Vec3> for Plane {
 	}
 }
 
-// ---------------------------------------------------------------------
-// Plane OP Vec4:
+impl std::ops::BitAnd<Vec3> for Plane {
+	type Output = S;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-// Omitted: Plane geometric Vec4 = self.d * rhs.w + self.d * rhs.x + self.d * rhs.y + self.d * rhs.z + self.nx * rhs.x + self.nx * rhs.y + self.nx * rhs.z + self.ny * rhs.x + self.ny * rhs.y + self.ny * rhs.z + self.nz * rhs.x + self.nz * rhs.y + self.nz * rhs.z  (unnamed type)
-// Omitted: Plane anti_geometric Vec4 = self.d !* rhs.w + self.nx !* rhs.w + self.nx !* rhs.x + self.nx !* rhs.y + self.nx !* rhs.z + self.ny !* rhs.w + self.ny !* rhs.x + self.ny !* rhs.y + self.ny !* rhs.z + self.nz !* rhs.w + self.nz !* rhs.x + self.nz !* rhs.y + self.nz !* rhs.z  (unnamed type)
+impl LeftContraction<Vec3> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
 
-// Plane.dot(Vec4) -> Line3
-impl Dot<Vec4> for Plane {
+// Plane.right_contraction(Vec3) -> Line3
+impl RightContraction<Vec3> for Plane {
 	type Output = Line3;
-	fn dot(self, rhs: Vec4) -> Self::Output {
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
 		// Line3 {
-		//     vx: WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
-		//     vy: WY(self.nx.0 * rhs.z.0) + WY(self.nz.0 * rhs.x.0),
-		//     vz: WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     vx: -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     vy: WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     vz: -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
 		//     mx: YZ(self.d.0 * rhs.x.0),
 		//     my: ZX(self.d.0 * rhs.y.0),
 		//     mz: XY(self.d.0 * rhs.z.0),
 		// }
 		Line3 {
-			vx: -self.ny.dot(rhs.z) + self.nz.dot(rhs.y),
-			vy: self.nx.dot(rhs.z) - self.nz.dot(rhs.x),
-			vz: -self.nx.dot(rhs.y) + self.ny.dot(rhs.x),
-			mx: self.d.dot(rhs.x),
-			my: self.d.dot(rhs.y),
-			mz: self.d.dot(rhs.z),
+			vx: self.ny.right_contraction(rhs.z) + self.nz.right_contraction(rhs.y),
+			vy: self.nx.right_contraction(rhs.z) + self.nz.right_contraction(rhs.x),
+			vz: self.nx.right_contraction(rhs.y) + self.ny.right_contraction(rhs.x),
+			mx: self.d.right_contraction(rhs.x),
+			my: self.d.right_contraction(rhs.y),
+			mz: self.d.right_contraction(rhs.z),
 		}
 	}
 }
 
-// Omitted: Plane wedge Vec4 = self.d ^ rhs.w + self.nx ^ rhs.x + self.ny ^ rhs.y + self.nz ^ rhs.z  (unnamed type)
-// Omitted: Plane anti_wedge Vec4 = self.d & rhs.w + self.nx & rhs.x + self.ny & rhs.y + self.nz & rhs.z  (unnamed type)
-
-// ---------------------------------------------------------------------
-// Plane OP Moment3:
+impl ScalarProduct<Vec3> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
 
-// Omitted: Plane geometric Moment3 = self.d * rhs.mx + self.d * rhs.my + self.d * rhs.mz + self.nx * rhs.mx + self.nx * rhs.my + self.nx * rhs.mz + self.ny * rhs.mx + self.ny * rhs.my + self.ny * rhs.mz + self.nz * rhs.mx + self.nz * rhs.my + self.nz * rhs.mz  (unnamed type)
-// Omitted: Plane anti_geometric Moment3 = self.nx !* rhs.mx + self.nx !* rhs.my + self.nx !* rhs.mz + self.ny !* rhs.mx + self.ny !* rhs.my + self.ny !* rhs.mz + self.nz !* rhs.mx + self.nz !* rhs.my + self.nz !* rhs.mz  (unnamed type)
+// Plane.commutator(Vec3) -> XYZW
+impl Commutator<Vec3> for Plane {
+	type Output = XYZW;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// -XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0)
+		self.nx.commutator(rhs.x) + self.ny.commutator(rhs.y) + self.nz.commutator(rhs.z)
+	}
+}
 
-// Plane.dot(Moment3) -> Vec4
-impl Dot<Moment3> for Plane {
-	type Output = Vec4;
-	fn dot(self, rhs: Moment3) -> Self::Output {
-		// Vec4 {
-		//     x: X(self.d.0 * rhs.mx.0),
-		//     y: Y(self.d.0 * rhs.my.0),
-		//     z: Z(self.d.0 * rhs.mz.0),
-		//     w: W(self.nx.0 * rhs.mx.0) + W(self.ny.0 * rhs.my.0) + W(self.nz.0 * rhs.mz.0),
+// Plane.anti_dot(Vec3) -> Moment3
+impl AntiDot<Vec3> for Plane {
+	type Output = Moment3;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.ny.0 * rhs.z.0) - YZ(self.nz.0 * rhs.y.0),
+		//     my: -ZX(self.nx.0 * rhs.z.0) + ZX(self.nz.0 * rhs.x.0),
+		//     mz: XY(self.nx.0 * rhs.y.0) - XY(self.ny.0 * rhs.x.0),
 		// }
-		Vec4 {
-			x: -self.d.dot(rhs.mx),
-			y: -self.d.dot(rhs.my),
-			z: -self.d.dot(rhs.mz),
-			w: -self.nx.dot(rhs.mx) - self.ny.dot(rhs.my) - self.nz.dot(rhs.mz),
+		Moment3 {
+			mx: self.ny.anti_dot(rhs.z) + self.nz.anti_dot(rhs.y),
+			my: self.nx.anti_dot(rhs.z) + self.nz.anti_dot(rhs.x),
+			mz: self.nx.anti_dot(rhs.y) + self.ny.anti_dot(rhs.x),
 		}
 	}
 }
 
-// Omitted: Plane wedge Moment3 = 0  (unnamed type)
+// ---------------------------------------------------------------------
+// Plane OP Vec4:
 
-// Plane.anti_wedge(Moment3) -> Vec3
-impl AntiWedge<Moment3> for Plane {
-	type Output = Vec3;
-	fn anti_wedge(self, rhs: Moment3) -> Self::Output {
-		// Vec3 {
-		//     x: X(self.ny.0 * rhs.mz.0) + X(self.nz.0 * rhs.my.0),
-		//     y: Y(self.nx.0 * rhs.mz.0) + Y(self.nz.0 * rhs.mx.0),
-		//     z: Z(self.nx.0 * rhs.my.0) + Z(self.ny.0 * rhs.mx.0),
+// Plane.geometric(Vec4) -> Motor3
+impl Geometric<Vec4> for Plane {
+	type Output = Motor3;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Motor3 {
+		//     rx: -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     ry: WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     rz: -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     rw: XYZW(self.d.0 * rhs.w.0) - XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0),
+		//     ux: YZ(self.d.0 * rhs.x.0),
+		//     uy: ZX(self.d.0 * rhs.y.0),
+		//     uz: XY(self.d.0 * rhs.z.0),
+		//     uw: Default::default(),
 		// }
-		Vec3 {
-			x: -self.ny.anti_wedge(rhs.mz) + self.nz.anti_wedge(rhs.my),
-			y: self.nx.anti_wedge(rhs.mz) - self.nz.anti_wedge(rhs.mx),
-			z: -self.nx.anti_wedge(rhs.my) + self.ny.anti_wedge(rhs.mx),
+		Motor3 {
+			rx: self.ny.geometric(rhs.z) + self.nz.geometric(rhs.y),
+			ry: self.nx.geometric(rhs.z) + self.nz.geometric(rhs.x),
+			rz: self.nx.geometric(rhs.y) + self.ny.geometric(rhs.x),
+			rw: self.d.geometric(rhs.w)
+				+ self.nx.geometric(rhs.x)
+				+ self.ny.geometric(rhs.y)
+				+ self.nz.geometric(rhs.z),
+			ux: self.d.geometric(rhs.x),
+			uy: self.d.geometric(rhs.y),
+			uz: self.d.geometric(rhs.z),
+			uw: Default::default(),
 		}
 	}
 }
 
-// ---------------------------------------------------------------------
-// Plane OP Line3:
-
-// Omitted: Plane geometric Line3 = self.d * rhs.mx + self.d * rhs.my + self.d * rhs.mz + self.d * rhs.vx + self.d * rhs.vy + self.d * rhs.vz + self.nx * rhs.mx + self.nx * rhs.my + self.nx * rhs.mz + self.ny * rhs.mx + self.ny * rhs.my + self.ny * rhs.mz + self.nz * rhs.mx + self.nz * rhs.my + self.nz * rhs.mz  (unnamed type)
-// Omitted: Plane anti_geometric Line3 = self.d !* rhs.vx + self.d !* rhs.vy + self.d !* rhs.vz + self.nx !* rhs.mx + self.nx !* rhs.my + self.nx !* rhs.mz + self.nx !* rhs.vx + self.nx !* rhs.vy + self.nx !* rhs.vz + self.ny !* rhs.mx + self.ny !* rhs.my + self.ny !* rhs.mz + self.ny !* rhs.vx + self.ny !* rhs.vy + self.ny !* rhs.vz + self.nz !* rhs.mx + self.nz !* rhs.my + self.nz !* rhs.mz + self.nz !* rhs.vx + self.nz !* rhs.vy + self.nz !* rhs.vz  (unnamed type)
+impl std::ops::Mul<Vec4> for Plane {
+	type Output = Motor3;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
-// Plane.dot(Line3) -> Vec4
-impl Dot<Line3> for Plane {
-	type Output = Vec4;
-	fn dot(self, rhs: Line3) -> Self::Output {
-		// Vec4 {
-		//     x: X(self.d.0 * rhs.mx.0),
-		//     y: Y(self.d.0 * rhs.my.0),
-		//     z: Z(self.d.0 * rhs.mz.0),
-		//     w: W(self.nx.0 * rhs.mx.0) + W(self.ny.0 * rhs.my.0) + W(self.nz.0 * rhs.mz.0),
+// Plane.anti_geometric(Vec4) -> Motor3
+impl AntiGeometric<Vec4> for Plane {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Motor3 {
+		//     rx: -WX(self.nx.0 * rhs.w.0),
+		//     ry: -WY(self.ny.0 * rhs.w.0),
+		//     rz: -WZ(self.nz.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.ny.0 * rhs.z.0) - YZ(self.nz.0 * rhs.y.0),
+		//     uy: -ZX(self.nx.0 * rhs.z.0) + ZX(self.nz.0 * rhs.x.0),
+		//     uz: XY(self.nx.0 * rhs.y.0) - XY(self.ny.0 * rhs.x.0),
+		//     uw: S(self.d.0 * rhs.w.0) - S(self.nx.0 * rhs.x.0) - S(self.ny.0 * rhs.y.0) - S(self.nz.0 * rhs.z.0),
 		// }
-		Vec4 {
-			x: -self.d.dot(rhs.mx),
-			y: -self.d.dot(rhs.my),
-			z: -self.d.dot(rhs.mz),
-			w: -self.nx.dot(rhs.mx) - self.ny.dot(rhs.my) - self.nz.dot(rhs.mz),
+		Motor3 {
+			rx: self.nx.anti_geometric(rhs.w),
+			ry: self.ny.anti_geometric(rhs.w),
+			rz: self.nz.anti_geometric(rhs.w),
+			rw: Default::default(),
+			ux: self.ny.anti_geometric(rhs.z) + self.nz.anti_geometric(rhs.y),
+			uy: self.nx.anti_geometric(rhs.z) + self.nz.anti_geometric(rhs.x),
+			uz: self.nx.anti_geometric(rhs.y) + self.ny.anti_geometric(rhs.x),
+			uw: self.d.anti_geometric(rhs.w)
+				+ self.nx.anti_geometric(rhs.x)
+				+ self.ny.anti_geometric(rhs.y)
+				+ self.nz.anti_geometric(rhs.z),
 		}
 	}
 }
 
-// Omitted: Plane wedge Line3 = 0  (unnamed type)
-
-// Plane.anti_wedge(Line3) -> Vec4
-impl AntiWedge<Line3> for Plane {
-	type Output = Vec4;
-	fn anti_wedge(self, rhs: Line3) -> Self::Output {
-		// Vec4 {
-		//     x: X(self.d.0 * rhs.vx.0) + X(self.ny.0 * rhs.mz.0) + X(self.nz.0 * rhs.my.0),
-		//     y: Y(self.d.0 * rhs.vy.0) + Y(self.nx.0 * rhs.mz.0) + Y(self.nz.0 * rhs.mx.0),
-		//     z: Z(self.d.0 * rhs.vz.0) + Z(self.nx.0 * rhs.my.0) + Z(self.ny.0 * rhs.mx.0),
-		//     w: W(self.nx.0 * rhs.vx.0) + W(self.ny.0 * rhs.vy.0) + W(self.nz.0 * rhs.vz.0),
+// Plane.dot(Vec4) -> Line3
+impl Dot<Vec4> for Plane {
+	type Output = Line3;
+	fn dot(self, rhs: Vec4) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     vy: WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     vz: -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     mx: YZ(self.d.0 * rhs.x.0),
+		//     my: ZX(self.d.0 * rhs.y.0),
+		//     mz: XY(self.d.0 * rhs.z.0),
 		// }
-		Vec4 {
-			x: -self.d.anti_wedge(rhs.vx) - self.ny.anti_wedge(rhs.mz) + self.nz.anti_wedge(rhs.my),
-			y: -self.d.anti_wedge(rhs.vy) + self.nx.anti_wedge(rhs.mz) - self.nz.anti_wedge(rhs.mx),
-			z: -self.d.anti_wedge(rhs.vz) - self.nx.anti_wedge(rhs.my) + self.ny.anti_wedge(rhs.mx),
-			w: -self.nx.anti_wedge(rhs.vx) - self.ny.anti_wedge(rhs.vy) - self.nz.anti_wedge(rhs.vz),
+		Line3 {
+			vx: self.ny.dot(rhs.z) + self.nz.dot(rhs.y),
+			vy: self.nx.dot(rhs.z) + self.nz.dot(rhs.x),
+			vz: self.nx.dot(rhs.y) + self.ny.dot(rhs.x),
+			mx: self.d.dot(rhs.x),
+			my: self.d.dot(rhs.y),
+			mz: self.d.dot(rhs.z),
 		}
 	}
 }
 
-// ---------------------------------------------------------------------
-// Plane OP Plane:
+impl std::ops::BitOr<Vec4> for Plane {
+	type Output = Line3;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
 
-// Omitted: Plane geometric Plane = Motor3 {     rx: self.d * rhs.nx - self.nx * rhs.d,     ry: self.d * rhs.ny - self.ny * rhs.d,     rz: self.d * rhs.nz - self.nz * rhs.d,     rw: 0,     ux: 0,     uy: 0,     uz: 0,     uw: -self.d * rhs.d, }  (too many zeros)
-// Omitted: Plane anti_geometric Plane = self.d !* rhs.nx + self.d !* rhs.ny + self.d !* rhs.nz + self.nx !* rhs.d + self.nx !* rhs.nx + self.nx !* rhs.ny + self.nx !* rhs.nz + self.ny !* rhs.d + self.ny !* rhs.nx + self.ny !* rhs.ny + self.ny !* rhs.nz + self.nz !* rhs.d + self.nz !* rhs.nx + self.nz !* rhs.ny + self.nz !* rhs.nz  (unnamed type)
+// Plane.wedge(Vec4) -> XYZW
+impl Wedge<Vec4> for Plane {
+	type Output = XYZW;
+	fn wedge(self, rhs: Vec4) -> Self::Output {
+		// XYZW(self.d.0 * rhs.w.0) - XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0)
+		self.d.wedge(rhs.w) + self.nx.wedge(rhs.x) + self.ny.wedge(rhs.y) + self.nz.wedge(rhs.z)
+	}
+}
 
-// Plane.dot(Plane) -> S
-impl Dot<Plane> for Plane {
+impl std::ops::BitXor<Vec4> for Plane {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Vec4) -> S
+impl AntiWedge<Vec4> for Plane {
 	type Output = S;
-	fn dot(self, rhs: Plane) -> Self::Output {
-		// -S(self.d.0 * rhs.d.0)
-		self.d.dot(rhs.d)
+	fn anti_wedge(self, rhs: Vec4) -> Self::Output {
+		// S(self.d.0 * rhs.w.0) - S(self.nx.0 * rhs.x.0) - S(self.ny.0 * rhs.y.0) - S(self.nz.0 * rhs.z.0)
+		self.d.anti_wedge(rhs.w) + self.nx.anti_wedge(rhs.x) + self.ny.anti_wedge(rhs.y) + self.nz.anti_wedge(rhs.z)
 	}
 }
 
-// Omitted: Plane wedge Plane = 0  (unnamed type)
+impl std::ops::BitAnd<Vec4> for Plane {
+	type Output = S;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-// Plane.anti_wedge(Plane) -> Line3
-impl AntiWedge<Plane> for Plane {
+impl LeftContraction<Vec4> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(Vec4) -> Line3
+impl RightContraction<Vec4> for Plane {
 	type Output = Line3;
-	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
 		// Line3 {
-		//     vx: WX(self.ny.0 * rhs.nz.0) + WX(self.nz.0 * rhs.ny.0),
-		//     vy: WY(self.nx.0 * rhs.nz.0) + WY(self.nz.0 * rhs.nx.0),
-		//     vz: WZ(self.nx.0 * rhs.ny.0) + WZ(self.ny.0 * rhs.nx.0),
-		//     mx: YZ(self.d.0 * rhs.nx.0) + YZ(self.nx.0 * rhs.d.0),
-		//     my: ZX(self.d.0 * rhs.ny.0) + ZX(self.ny.0 * rhs.d.0),
-		//     mz: XY(self.d.0 * rhs.nz.0) + XY(self.nz.0 * rhs.d.0),
+		//     vx: -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     vy: WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     vz: -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     mx: YZ(self.d.0 * rhs.x.0),
+		//     my: ZX(self.d.0 * rhs.y.0),
+		//     mz: XY(self.d.0 * rhs.z.0),
 		// }
 		Line3 {
-			vx: -self.ny.anti_wedge(rhs.nz) + self.nz.anti_wedge(rhs.ny),
-			vy: self.nx.anti_wedge(rhs.nz) - self.nz.anti_wedge(rhs.nx),
-			vz: -self.nx.anti_wedge(rhs.ny) + self.ny.anti_wedge(rhs.nx),
-			mx: self.d.anti_wedge(rhs.nx) - self.nx.anti_wedge(rhs.d),
-			my: self.d.anti_wedge(rhs.ny) - self.ny.anti_wedge(rhs.d),
-			mz: self.d.anti_wedge(rhs.nz) - self.nz.anti_wedge(rhs.d),
+			vx: self.ny.right_contraction(rhs.z) + self.nz.right_contraction(rhs.y),
+			vy: self.nx.right_contraction(rhs.z) + self.nz.right_contraction(rhs.x),
+			vz: self.nx.right_contraction(rhs.y) + self.ny.right_contraction(rhs.x),
+			mx: self.d.right_contraction(rhs.x),
+			my: self.d.right_contraction(rhs.y),
+			mz: self.d.right_contraction(rhs.z),
 		}
 	}
 }
 
-// ---------------------------------------------------------------------
-// Plane OP Rotor3:
-
-// Omitted: Plane geometric Rotor3 = self.d * rhs.w + self.d * rhs.x + self.d * rhs.y + self.d * rhs.z  (unnamed type)
-// Omitted: Plane anti_geometric Rotor3 = self.d !* rhs.w + self.d !* rhs.x + self.d !* rhs.y + self.d !* rhs.z + self.nx !* rhs.w + self.nx !* rhs.x + self.nx !* rhs.y + self.nx !* rhs.z + self.ny !* rhs.w + self.ny !* rhs.x + self.ny !* rhs.y + self.ny !* rhs.z + self.nz !* rhs.w + self.nz !* rhs.x + self.nz !* rhs.y + self.nz !* rhs.z  (unnamed type)
+impl ScalarProduct<Vec4> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
 
-// Plane.dot(Rotor3) -> W
-impl Dot<Rotor3> for Plane {
-	type Output = W;
-	fn dot(self, rhs: Rotor3) -> Self::Output {
-		// -W(self.d.0 * rhs.w.0)
-		self.d.dot(rhs.w)
+// Plane.commutator(Vec4) -> XYZW
+impl Commutator<Vec4> for Plane {
+	type Output = XYZW;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// XYZW(self.d.0 * rhs.w.0) - XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0)
+		self.d.commutator(rhs.w) + self.nx.commutator(rhs.x) + self.ny.commutator(rhs.y) + self.nz.commutator(rhs.z)
 	}
 }
 
-// Omitted: Plane wedge Rotor3 = 0  (unnamed type)
-// Omitted: Plane anti_wedge Rotor3 = self.d & rhs.w + self.d & rhs.x + self.d & rhs.y + self.d & rhs.z + self.nx & rhs.w + self.nx & rhs.x + self.ny & rhs.w + self.ny & rhs.y + self.nz & rhs.w + self.nz & rhs.z  (unnamed type)
+// Plane.anti_dot(Vec4) -> Line3
+impl AntiDot<Vec4> for Plane {
+	type Output = Line3;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.nx.0 * rhs.w.0),
+		//     vy: -WY(self.ny.0 * rhs.w.0),
+		//     vz: -WZ(self.nz.0 * rhs.w.0),
+		//     mx: YZ(self.ny.0 * rhs.z.0) - YZ(self.nz.0 * rhs.y.0),
+		//     my: -ZX(self.nx.0 * rhs.z.0) + ZX(self.nz.0 * rhs.x.0),
+		//     mz: XY(self.nx.0 * rhs.y.0) - XY(self.ny.0 * rhs.x.0),
+		// }
+		Line3 {
+			vx: self.nx.anti_dot(rhs.w),
+			vy: self.ny.anti_dot(rhs.w),
+			vz: self.nz.anti_dot(rhs.w),
+			mx: self.ny.anti_dot(rhs.z) + self.nz.anti_dot(rhs.y),
+			my: self.nx.anti_dot(rhs.z) + self.nz.anti_dot(rhs.x),
+			mz: self.nx.anti_dot(rhs.y) + self.ny.anti_dot(rhs.x),
+		}
+	}
+}
 
 // ---------------------------------------------------------------------
-// Plane OP Motor3:
+// Plane OP Point3:
 
-// Omitted: Plane geometric Motor3 = self.d * rhs.rw + self.d * rhs.rx + self.d * rhs.ry + self.d * rhs.rz + self.d * rhs.uw + self.d * rhs.ux + self.d * rhs.uy + self.d * rhs.uz + self.nx * rhs.uw + self.ny * rhs.uw + self.nz * rhs.uw  (unnamed type)
-// Omitted: Plane anti_geometric Motor3 = self.d !* rhs.rw + self.d !* rhs.rx + self.d !* rhs.ry + self.d !* rhs.rz + self.d !* rhs.ux + self.d !* rhs.uy + self.d !* rhs.uz + self.nx !* rhs.rw + self.nx !* rhs.rx + self.nx !* rhs.ry + self.nx !* rhs.rz + self.nx !* rhs.uw + self.nx !* rhs.ux + self.nx !* rhs.uy + self.nx !* rhs.uz + self.ny !* rhs.rw + self.ny !* rhs.rx + self.ny !* rhs.ry + self.ny !* rhs.rz + self.ny !* rhs.uw + self.ny !* rhs.ux + self.ny !* rhs.uy + self.ny !* rhs.uz + self.nz !* rhs.rw + self.nz !* rhs.rx + self.nz !* rhs.ry + self.nz !* rhs.rz + self.nz !* rhs.uw + self.nz !* rhs.ux + self.nz !* rhs.uy + self.nz !* rhs.uz  (unnamed type)
-// Omitted: Plane dot Motor3 = self.d | rhs.rw + self.d | rhs.uw + self.nx | rhs.uw + self.ny | rhs.uw + self.nz | rhs.uw  (unnamed type)
-
-// Plane.wedge(Motor3) -> Plane
-impl Wedge<Motor3> for Plane {
-	type Output = Plane;
-	fn wedge(self, rhs: Motor3) -> Self::Output {
-		// Plane {
-		//     nx: YZW(self.nx.0 * rhs.uw.0),
-		//     ny: ZXW(self.ny.0 * rhs.uw.0),
-		//     nz: XYW(self.nz.0 * rhs.uw.0),
-		//     d : XYZ(self.d.0 * rhs.uw.0),
+// Plane.geometric(Point3) -> Motor3
+impl Geometric<Point3> for Plane {
+	type Output = Motor3;
+	fn geometric(self, rhs: Point3) -> Self::Output {
+		// Motor3 {
+		//     rx: -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     ry: WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     rz: -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     rw: XYZW(self.d.0) - XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0),
+		//     ux: YZ(self.d.0 * rhs.x.0),
+		//     uy: ZX(self.d.0 * rhs.y.0),
+		//     uz: XY(self.d.0 * rhs.z.0),
+		//     uw: Default::default(),
 		// }
-		Plane {
-			nx: self.nx.wedge(rhs.uw),
+		Motor3 {
+			rx: self.ny.geometric(rhs.z) + self.nz.geometric(rhs.y),
+			ry: self.nx.geometric(rhs.z) + self.nz.geometric(rhs.x),
+			rz: self.nx.geometric(rhs.y) + self.ny.geometric(rhs.x),
+			rw: self.d.geometric(W(1.0))
+				+ self.nx.geometric(rhs.x)
+				+ self.ny.geometric(rhs.y)
+				+ self.nz.geometric(rhs.z),
+			ux: self.d.geometric(rhs.x),
+			uy: self.d.geometric(rhs.y),
+			uz: self.d.geometric(rhs.z),
+			uw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point3> for Plane {
+	type Output = Motor3;
+	fn mul(self, rhs: Point3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Point3) -> Motor3
+impl AntiGeometric<Point3> for Plane {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Point3) -> Self::Output {
+		// Motor3 {
+		//     rx: -WX(self.nx.0),
+		//     ry: -WY(self.ny.0),
+		//     rz: -WZ(self.nz.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.ny.0 * rhs.z.0) - YZ(self.nz.0 * rhs.y.0),
+		//     uy: -ZX(self.nx.0 * rhs.z.0) + ZX(self.nz.0 * rhs.x.0),
+		//     uz: XY(self.nx.0 * rhs.y.0) - XY(self.ny.0 * rhs.x.0),
+		//     uw: S(self.d.0) - S(self.nx.0 * rhs.x.0) - S(self.ny.0 * rhs.y.0) - S(self.nz.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.nx.anti_geometric(W(1.0)),
+			ry: self.ny.anti_geometric(W(1.0)),
+			rz: self.nz.anti_geometric(W(1.0)),
+			rw: Default::default(),
+			ux: self.ny.anti_geometric(rhs.z) + self.nz.anti_geometric(rhs.y),
+			uy: self.nx.anti_geometric(rhs.z) + self.nz.anti_geometric(rhs.x),
+			uz: self.nx.anti_geometric(rhs.y) + self.ny.anti_geometric(rhs.x),
+			uw: self.d.anti_geometric(W(1.0))
+				+ self.nx.anti_geometric(rhs.x)
+				+ self.ny.anti_geometric(rhs.y)
+				+ self.nz.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Plane.dot(Point3) -> Line3
+impl Dot<Point3> for Plane {
+	type Output = Line3;
+	fn dot(self, rhs: Point3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     vy: WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     vz: -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     mx: YZ(self.d.0 * rhs.x.0),
+		//     my: ZX(self.d.0 * rhs.y.0),
+		//     mz: XY(self.d.0 * rhs.z.0),
+		// }
+		Line3 {
+			vx: self.ny.dot(rhs.z) + self.nz.dot(rhs.y),
+			vy: self.nx.dot(rhs.z) + self.nz.dot(rhs.x),
+			vz: self.nx.dot(rhs.y) + self.ny.dot(rhs.x),
+			mx: self.d.dot(rhs.x),
+			my: self.d.dot(rhs.y),
+			mz: self.d.dot(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point3> for Plane {
+	type Output = Line3;
+	fn bitor(self, rhs: Point3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Plane.wedge(Point3) -> XYZW
+impl Wedge<Point3> for Plane {
+	type Output = XYZW;
+	fn wedge(self, rhs: Point3) -> Self::Output {
+		// XYZW(self.d.0) - XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0)
+		self.d.wedge(W(1.0)) + self.nx.wedge(rhs.x) + self.ny.wedge(rhs.y) + self.nz.wedge(rhs.z)
+	}
+}
+
+impl std::ops::BitXor<Point3> for Plane {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Point3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Point3) -> S
+impl AntiWedge<Point3> for Plane {
+	type Output = S;
+	fn anti_wedge(self, rhs: Point3) -> Self::Output {
+		// S(self.d.0) - S(self.nx.0 * rhs.x.0) - S(self.ny.0 * rhs.y.0) - S(self.nz.0 * rhs.z.0)
+		self.d.anti_wedge(W(1.0)) + self.nx.anti_wedge(rhs.x) + self.ny.anti_wedge(rhs.y) + self.nz.anti_wedge(rhs.z)
+	}
+}
+
+impl std::ops::BitAnd<Point3> for Plane {
+	type Output = S;
+	fn bitand(self, rhs: Point3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point3> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(Point3) -> Line3
+impl RightContraction<Point3> for Plane {
+	type Output = Line3;
+	fn right_contraction(self, rhs: Point3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     vy: WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     vz: -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     mx: YZ(self.d.0 * rhs.x.0),
+		//     my: ZX(self.d.0 * rhs.y.0),
+		//     mz: XY(self.d.0 * rhs.z.0),
+		// }
+		Line3 {
+			vx: self.ny.right_contraction(rhs.z) + self.nz.right_contraction(rhs.y),
+			vy: self.nx.right_contraction(rhs.z) + self.nz.right_contraction(rhs.x),
+			vz: self.nx.right_contraction(rhs.y) + self.ny.right_contraction(rhs.x),
+			mx: self.d.right_contraction(rhs.x),
+			my: self.d.right_contraction(rhs.y),
+			mz: self.d.right_contraction(rhs.z),
+		}
+	}
+}
+
+impl ScalarProduct<Point3> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Point3) -> XYZW
+impl Commutator<Point3> for Plane {
+	type Output = XYZW;
+	fn commutator(self, rhs: Point3) -> Self::Output {
+		// XYZW(self.d.0) - XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0)
+		self.d.commutator(W(1.0)) + self.nx.commutator(rhs.x) + self.ny.commutator(rhs.y) + self.nz.commutator(rhs.z)
+	}
+}
+
+// Plane.anti_dot(Point3) -> Line3
+impl AntiDot<Point3> for Plane {
+	type Output = Line3;
+	fn anti_dot(self, rhs: Point3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.nx.0),
+		//     vy: -WY(self.ny.0),
+		//     vz: -WZ(self.nz.0),
+		//     mx: YZ(self.ny.0 * rhs.z.0) - YZ(self.nz.0 * rhs.y.0),
+		//     my: -ZX(self.nx.0 * rhs.z.0) + ZX(self.nz.0 * rhs.x.0),
+		//     mz: XY(self.nx.0 * rhs.y.0) - XY(self.ny.0 * rhs.x.0),
+		// }
+		Line3 {
+			vx: self.nx.anti_dot(W(1.0)),
+			vy: self.ny.anti_dot(W(1.0)),
+			vz: self.nz.anti_dot(W(1.0)),
+			mx: self.ny.anti_dot(rhs.z) + self.nz.anti_dot(rhs.y),
+			my: self.nx.anti_dot(rhs.z) + self.nz.anti_dot(rhs.x),
+			mz: self.nx.anti_dot(rhs.y) + self.ny.anti_dot(rhs.x),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Moment3:
+
+// Plane.geometric(Moment3) -> Multivector
+impl Geometric<Moment3> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.mx.0),
+		//     y   : -Y(self.d.0 * rhs.my.0),
+		//     z   : -Z(self.d.0 * rhs.mz.0),
+		//     w   : -W(self.nx.0 * rhs.mx.0) - W(self.ny.0 * rhs.my.0) - W(self.nz.0 * rhs.mz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.ny.0 * rhs.mz.0) + YZW(self.nz.0 * rhs.my.0),
+		//     zxw : ZXW(self.nx.0 * rhs.mz.0) - ZXW(self.nz.0 * rhs.mx.0),
+		//     xyw : -XYW(self.nx.0 * rhs.my.0) + XYW(self.ny.0 * rhs.mx.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.geometric(rhs.mx),
+			y: self.d.geometric(rhs.my),
+			z: self.d.geometric(rhs.mz),
+			w: self.nx.geometric(rhs.mx) + self.ny.geometric(rhs.my) + self.nz.geometric(rhs.mz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.ny.geometric(rhs.mz) + self.nz.geometric(rhs.my),
+			zxw: self.nx.geometric(rhs.mz) + self.nz.geometric(rhs.mx),
+			xyw: self.nx.geometric(rhs.my) + self.ny.geometric(rhs.mx),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Moment3> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Moment3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Moment3) -> Multivector
+impl AntiGeometric<Moment3> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.ny.0 * rhs.mz.0) + X(self.nz.0 * rhs.my.0),
+		//     y   : Y(self.nx.0 * rhs.mz.0) - Y(self.nz.0 * rhs.mx.0),
+		//     z   : -Z(self.nx.0 * rhs.my.0) + Z(self.ny.0 * rhs.mx.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : -XYZ(self.nx.0 * rhs.mx.0) - XYZ(self.ny.0 * rhs.my.0) - XYZ(self.nz.0 * rhs.mz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.ny.anti_geometric(rhs.mz) + self.nz.anti_geometric(rhs.my),
+			y: self.nx.anti_geometric(rhs.mz) + self.nz.anti_geometric(rhs.mx),
+			z: self.nx.anti_geometric(rhs.my) + self.ny.anti_geometric(rhs.mx),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.nx.anti_geometric(rhs.mx) + self.ny.anti_geometric(rhs.my) + self.nz.anti_geometric(rhs.mz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(Moment3) -> Vec4
+impl Dot<Moment3> for Plane {
+	type Output = Vec4;
+	fn dot(self, rhs: Moment3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.d.0 * rhs.mx.0),
+		//     y: -Y(self.d.0 * rhs.my.0),
+		//     z: -Z(self.d.0 * rhs.mz.0),
+		//     w: -W(self.nx.0 * rhs.mx.0) - W(self.ny.0 * rhs.my.0) - W(self.nz.0 * rhs.mz.0),
+		// }
+		Vec4 {
+			x: self.d.dot(rhs.mx),
+			y: self.d.dot(rhs.my),
+			z: self.d.dot(rhs.mz),
+			w: self.nx.dot(rhs.mx) + self.ny.dot(rhs.my) + self.nz.dot(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Moment3> for Plane {
+	type Output = Vec4;
+	fn bitor(self, rhs: Moment3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Moment3> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Moment3> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: Moment3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Moment3) -> Vec3
+impl AntiWedge<Moment3> for Plane {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Moment3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.ny.0 * rhs.mz.0) + X(self.nz.0 * rhs.my.0),
+		//     y: Y(self.nx.0 * rhs.mz.0) - Y(self.nz.0 * rhs.mx.0),
+		//     z: -Z(self.nx.0 * rhs.my.0) + Z(self.ny.0 * rhs.mx.0),
+		// }
+		Vec3 {
+			x: self.ny.anti_wedge(rhs.mz) + self.nz.anti_wedge(rhs.my),
+			y: self.nx.anti_wedge(rhs.mz) + self.nz.anti_wedge(rhs.mx),
+			z: self.nx.anti_wedge(rhs.my) + self.ny.anti_wedge(rhs.mx),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Moment3> for Plane {
+	type Output = Vec3;
+	fn bitand(self, rhs: Moment3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Moment3> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(Moment3) -> Vec4
+impl RightContraction<Moment3> for Plane {
+	type Output = Vec4;
+	fn right_contraction(self, rhs: Moment3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.d.0 * rhs.mx.0),
+		//     y: -Y(self.d.0 * rhs.my.0),
+		//     z: -Z(self.d.0 * rhs.mz.0),
+		//     w: -W(self.nx.0 * rhs.mx.0) - W(self.ny.0 * rhs.my.0) - W(self.nz.0 * rhs.mz.0),
+		// }
+		Vec4 {
+			x: self.d.right_contraction(rhs.mx),
+			y: self.d.right_contraction(rhs.my),
+			z: self.d.right_contraction(rhs.mz),
+			w: self.nx.right_contraction(rhs.mx)
+				+ self.ny.right_contraction(rhs.my)
+				+ self.nz.right_contraction(rhs.mz),
+		}
+	}
+}
+
+impl ScalarProduct<Moment3> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Moment3) -> Plane
+impl Commutator<Moment3> for Plane {
+	type Output = Plane;
+	fn commutator(self, rhs: Moment3) -> Self::Output {
+		// Plane {
+		//     nx: -YZW(self.ny.0 * rhs.mz.0) + YZW(self.nz.0 * rhs.my.0),
+		//     ny: ZXW(self.nx.0 * rhs.mz.0) - ZXW(self.nz.0 * rhs.mx.0),
+		//     nz: -XYW(self.nx.0 * rhs.my.0) + XYW(self.ny.0 * rhs.mx.0),
+		//     d : Default::default(),
+		// }
+		Plane {
+			nx: self.ny.commutator(rhs.mz) + self.nz.commutator(rhs.my),
+			ny: self.nx.commutator(rhs.mz) + self.nz.commutator(rhs.mx),
+			nz: self.nx.commutator(rhs.my) + self.ny.commutator(rhs.mx),
+			d: Default::default(),
+		}
+	}
+}
+
+// Plane.anti_dot(Moment3) -> XYZ
+impl AntiDot<Moment3> for Plane {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: Moment3) -> Self::Output {
+		// -XYZ(self.nx.0 * rhs.mx.0) - XYZ(self.ny.0 * rhs.my.0) - XYZ(self.nz.0 * rhs.mz.0)
+		self.nx.anti_dot(rhs.mx) + self.ny.anti_dot(rhs.my) + self.nz.anti_dot(rhs.mz)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Line3:
+
+// Plane.geometric(Line3) -> Multivector
+impl Geometric<Line3> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.mx.0),
+		//     y   : -Y(self.d.0 * rhs.my.0),
+		//     z   : -Z(self.d.0 * rhs.mz.0),
+		//     w   : -W(self.nx.0 * rhs.mx.0) - W(self.ny.0 * rhs.my.0) - W(self.nz.0 * rhs.mz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.d.0 * rhs.vx.0) - YZW(self.ny.0 * rhs.mz.0) + YZW(self.nz.0 * rhs.my.0),
+		//     zxw : -ZXW(self.d.0 * rhs.vy.0) + ZXW(self.nx.0 * rhs.mz.0) - ZXW(self.nz.0 * rhs.mx.0),
+		//     xyw : -XYW(self.d.0 * rhs.vz.0) - XYW(self.nx.0 * rhs.my.0) + XYW(self.ny.0 * rhs.mx.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.geometric(rhs.mx),
+			y: self.d.geometric(rhs.my),
+			z: self.d.geometric(rhs.mz),
+			w: self.nx.geometric(rhs.mx) + self.ny.geometric(rhs.my) + self.nz.geometric(rhs.mz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.d.geometric(rhs.vx) + self.ny.geometric(rhs.mz) + self.nz.geometric(rhs.my),
+			zxw: self.d.geometric(rhs.vy) + self.nx.geometric(rhs.mz) + self.nz.geometric(rhs.mx),
+			xyw: self.d.geometric(rhs.vz) + self.nx.geometric(rhs.my) + self.ny.geometric(rhs.mx),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Line3> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Line3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Line3) -> Multivector
+impl AntiGeometric<Line3> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.vx.0) - X(self.ny.0 * rhs.mz.0) + X(self.nz.0 * rhs.my.0),
+		//     y   : -Y(self.d.0 * rhs.vy.0) + Y(self.nx.0 * rhs.mz.0) - Y(self.nz.0 * rhs.mx.0),
+		//     z   : -Z(self.d.0 * rhs.vz.0) - Z(self.nx.0 * rhs.my.0) + Z(self.ny.0 * rhs.mx.0),
+		//     w   : -W(self.nx.0 * rhs.vx.0) - W(self.ny.0 * rhs.vy.0) - W(self.nz.0 * rhs.vz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.ny.0 * rhs.vz.0) - YZW(self.nz.0 * rhs.vy.0),
+		//     zxw : -ZXW(self.nx.0 * rhs.vz.0) + ZXW(self.nz.0 * rhs.vx.0),
+		//     xyw : XYW(self.nx.0 * rhs.vy.0) - XYW(self.ny.0 * rhs.vx.0),
+		//     xyz : -XYZ(self.nx.0 * rhs.mx.0) - XYZ(self.ny.0 * rhs.my.0) - XYZ(self.nz.0 * rhs.mz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.anti_geometric(rhs.vx) + self.ny.anti_geometric(rhs.mz) + self.nz.anti_geometric(rhs.my),
+			y: self.d.anti_geometric(rhs.vy) + self.nx.anti_geometric(rhs.mz) + self.nz.anti_geometric(rhs.mx),
+			z: self.d.anti_geometric(rhs.vz) + self.nx.anti_geometric(rhs.my) + self.ny.anti_geometric(rhs.mx),
+			w: self.nx.anti_geometric(rhs.vx) + self.ny.anti_geometric(rhs.vy) + self.nz.anti_geometric(rhs.vz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.ny.anti_geometric(rhs.vz) + self.nz.anti_geometric(rhs.vy),
+			zxw: self.nx.anti_geometric(rhs.vz) + self.nz.anti_geometric(rhs.vx),
+			xyw: self.nx.anti_geometric(rhs.vy) + self.ny.anti_geometric(rhs.vx),
+			xyz: self.nx.anti_geometric(rhs.mx) + self.ny.anti_geometric(rhs.my) + self.nz.anti_geometric(rhs.mz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(Line3) -> Vec4
+impl Dot<Line3> for Plane {
+	type Output = Vec4;
+	fn dot(self, rhs: Line3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.d.0 * rhs.mx.0),
+		//     y: -Y(self.d.0 * rhs.my.0),
+		//     z: -Z(self.d.0 * rhs.mz.0),
+		//     w: -W(self.nx.0 * rhs.mx.0) - W(self.ny.0 * rhs.my.0) - W(self.nz.0 * rhs.mz.0),
+		// }
+		Vec4 {
+			x: self.d.dot(rhs.mx),
+			y: self.d.dot(rhs.my),
+			z: self.d.dot(rhs.mz),
+			w: self.nx.dot(rhs.mx) + self.ny.dot(rhs.my) + self.nz.dot(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line3> for Plane {
+	type Output = Vec4;
+	fn bitor(self, rhs: Line3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Line3> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: Line3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Line3> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: Line3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Line3) -> Vec4
+impl AntiWedge<Line3> for Plane {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Line3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.d.0 * rhs.vx.0) - X(self.ny.0 * rhs.mz.0) + X(self.nz.0 * rhs.my.0),
+		//     y: -Y(self.d.0 * rhs.vy.0) + Y(self.nx.0 * rhs.mz.0) - Y(self.nz.0 * rhs.mx.0),
+		//     z: -Z(self.d.0 * rhs.vz.0) - Z(self.nx.0 * rhs.my.0) + Z(self.ny.0 * rhs.mx.0),
+		//     w: -W(self.nx.0 * rhs.vx.0) - W(self.ny.0 * rhs.vy.0) - W(self.nz.0 * rhs.vz.0),
+		// }
+		Vec4 {
+			x: self.d.anti_wedge(rhs.vx) + self.ny.anti_wedge(rhs.mz) + self.nz.anti_wedge(rhs.my),
+			y: self.d.anti_wedge(rhs.vy) + self.nx.anti_wedge(rhs.mz) + self.nz.anti_wedge(rhs.mx),
+			z: self.d.anti_wedge(rhs.vz) + self.nx.anti_wedge(rhs.my) + self.ny.anti_wedge(rhs.mx),
+			w: self.nx.anti_wedge(rhs.vx) + self.ny.anti_wedge(rhs.vy) + self.nz.anti_wedge(rhs.vz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line3> for Plane {
+	type Output = Vec4;
+	fn bitand(self, rhs: Line3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Line3> for Plane {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Line3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.right_contraction(Line3) -> Vec4
+impl RightContraction<Line3> for Plane {
+	type Output = Vec4;
+	fn right_contraction(self, rhs: Line3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.d.0 * rhs.mx.0),
+		//     y: -Y(self.d.0 * rhs.my.0),
+		//     z: -Z(self.d.0 * rhs.mz.0),
+		//     w: -W(self.nx.0 * rhs.mx.0) - W(self.ny.0 * rhs.my.0) - W(self.nz.0 * rhs.mz.0),
+		// }
+		Vec4 {
+			x: self.d.right_contraction(rhs.mx),
+			y: self.d.right_contraction(rhs.my),
+			z: self.d.right_contraction(rhs.mz),
+			w: self.nx.right_contraction(rhs.mx)
+				+ self.ny.right_contraction(rhs.my)
+				+ self.nz.right_contraction(rhs.mz),
+		}
+	}
+}
+
+impl ScalarProduct<Line3> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Line3) -> Plane
+impl Commutator<Line3> for Plane {
+	type Output = Plane;
+	fn commutator(self, rhs: Line3) -> Self::Output {
+		// Plane {
+		//     nx: -YZW(self.d.0 * rhs.vx.0) - YZW(self.ny.0 * rhs.mz.0) + YZW(self.nz.0 * rhs.my.0),
+		//     ny: -ZXW(self.d.0 * rhs.vy.0) + ZXW(self.nx.0 * rhs.mz.0) - ZXW(self.nz.0 * rhs.mx.0),
+		//     nz: -XYW(self.d.0 * rhs.vz.0) - XYW(self.nx.0 * rhs.my.0) + XYW(self.ny.0 * rhs.mx.0),
+		//     d : Default::default(),
+		// }
+		Plane {
+			nx: self.d.commutator(rhs.vx) + self.ny.commutator(rhs.mz) + self.nz.commutator(rhs.my),
+			ny: self.d.commutator(rhs.vy) + self.nx.commutator(rhs.mz) + self.nz.commutator(rhs.mx),
+			nz: self.d.commutator(rhs.vz) + self.nx.commutator(rhs.my) + self.ny.commutator(rhs.mx),
+			d: Default::default(),
+		}
+	}
+}
+
+// Plane.anti_dot(Line3) -> Plane
+impl AntiDot<Line3> for Plane {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Line3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.ny.0 * rhs.vz.0) - YZW(self.nz.0 * rhs.vy.0),
+		//     ny: -ZXW(self.nx.0 * rhs.vz.0) + ZXW(self.nz.0 * rhs.vx.0),
+		//     nz: XYW(self.nx.0 * rhs.vy.0) - XYW(self.ny.0 * rhs.vx.0),
+		//     d : -XYZ(self.nx.0 * rhs.mx.0) - XYZ(self.ny.0 * rhs.my.0) - XYZ(self.nz.0 * rhs.mz.0),
+		// }
+		Plane {
+			nx: self.ny.anti_dot(rhs.vz) + self.nz.anti_dot(rhs.vy),
+			ny: self.nx.anti_dot(rhs.vz) + self.nz.anti_dot(rhs.vx),
+			nz: self.nx.anti_dot(rhs.vy) + self.ny.anti_dot(rhs.vx),
+			d: self.nx.anti_dot(rhs.mx) + self.ny.anti_dot(rhs.my) + self.nz.anti_dot(rhs.mz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Plane:
+
+// Plane.geometric(Plane) -> Motor3
+impl Geometric<Plane> for Plane {
+	type Output = Motor3;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.d.0 * rhs.nx.0) - WX(self.nx.0 * rhs.d.0),
+		//     ry: WY(self.d.0 * rhs.ny.0) - WY(self.ny.0 * rhs.d.0),
+		//     rz: WZ(self.d.0 * rhs.nz.0) - WZ(self.nz.0 * rhs.d.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.d.0 * rhs.d.0),
+		// }
+		Motor3 {
+			rx: self.d.geometric(rhs.nx) + self.nx.geometric(rhs.d),
+			ry: self.d.geometric(rhs.ny) + self.ny.geometric(rhs.d),
+			rz: self.d.geometric(rhs.nz) + self.nz.geometric(rhs.d),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.d.geometric(rhs.d),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Plane {
+	type Output = Motor3;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Plane) -> Motor3
+impl AntiGeometric<Plane> for Plane {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Motor3 {
+		//     rx: -WX(self.ny.0 * rhs.nz.0) + WX(self.nz.0 * rhs.ny.0),
+		//     ry: WY(self.nx.0 * rhs.nz.0) - WY(self.nz.0 * rhs.nx.0),
+		//     rz: -WZ(self.nx.0 * rhs.ny.0) + WZ(self.ny.0 * rhs.nx.0),
+		//     rw: XYZW(self.nx.0 * rhs.nx.0) + XYZW(self.ny.0 * rhs.ny.0) + XYZW(self.nz.0 * rhs.nz.0),
+		//     ux: YZ(self.d.0 * rhs.nx.0) - YZ(self.nx.0 * rhs.d.0),
+		//     uy: ZX(self.d.0 * rhs.ny.0) - ZX(self.ny.0 * rhs.d.0),
+		//     uz: XY(self.d.0 * rhs.nz.0) - XY(self.nz.0 * rhs.d.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.ny.anti_geometric(rhs.nz) + self.nz.anti_geometric(rhs.ny),
+			ry: self.nx.anti_geometric(rhs.nz) + self.nz.anti_geometric(rhs.nx),
+			rz: self.nx.anti_geometric(rhs.ny) + self.ny.anti_geometric(rhs.nx),
+			rw: self.nx.anti_geometric(rhs.nx) + self.ny.anti_geometric(rhs.ny) + self.nz.anti_geometric(rhs.nz),
+			ux: self.d.anti_geometric(rhs.nx) + self.nx.anti_geometric(rhs.d),
+			uy: self.d.anti_geometric(rhs.ny) + self.ny.anti_geometric(rhs.d),
+			uz: self.d.anti_geometric(rhs.nz) + self.nz.anti_geometric(rhs.d),
+			uw: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(Plane) -> S
+impl Dot<Plane> for Plane {
+	type Output = S;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// -S(self.d.0 * rhs.d.0)
+		self.d.dot(rhs.d)
+	}
+}
+
+impl std::ops::BitOr<Plane> for Plane {
+	type Output = S;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Plane) -> Line3
+impl AntiWedge<Plane> for Plane {
+	type Output = Line3;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.ny.0 * rhs.nz.0) + WX(self.nz.0 * rhs.ny.0),
+		//     vy: WY(self.nx.0 * rhs.nz.0) - WY(self.nz.0 * rhs.nx.0),
+		//     vz: -WZ(self.nx.0 * rhs.ny.0) + WZ(self.ny.0 * rhs.nx.0),
+		//     mx: YZ(self.d.0 * rhs.nx.0) - YZ(self.nx.0 * rhs.d.0),
+		//     my: ZX(self.d.0 * rhs.ny.0) - ZX(self.ny.0 * rhs.d.0),
+		//     mz: XY(self.d.0 * rhs.nz.0) - XY(self.nz.0 * rhs.d.0),
+		// }
+		Line3 {
+			vx: self.ny.anti_wedge(rhs.nz) + self.nz.anti_wedge(rhs.ny),
+			vy: self.nx.anti_wedge(rhs.nz) + self.nz.anti_wedge(rhs.nx),
+			vz: self.nx.anti_wedge(rhs.ny) + self.ny.anti_wedge(rhs.nx),
+			mx: self.d.anti_wedge(rhs.nx) + self.nx.anti_wedge(rhs.d),
+			my: self.d.anti_wedge(rhs.ny) + self.ny.anti_wedge(rhs.d),
+			mz: self.d.anti_wedge(rhs.nz) + self.nz.anti_wedge(rhs.d),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Plane {
+	type Output = Line3;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Plane.left_contraction(Plane) -> S
+impl LeftContraction<Plane> for Plane {
+	type Output = S;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// -S(self.d.0 * rhs.d.0)
+		self.d.left_contraction(rhs.d)
+	}
+}
+
+// Plane.right_contraction(Plane) -> S
+impl RightContraction<Plane> for Plane {
+	type Output = S;
+	fn right_contraction(self, rhs: Plane) -> Self::Output {
+		// -S(self.d.0 * rhs.d.0)
+		self.d.right_contraction(rhs.d)
+	}
+}
+
+// Plane.scalar_product(Plane) -> S
+impl ScalarProduct<Plane> for Plane {
+	type Output = S;
+	fn scalar_product(self, rhs: Plane) -> Self::Output {
+		// -S(self.d.0 * rhs.d.0)
+		self.d.scalar_product(rhs.d)
+	}
+}
+
+// Plane.commutator(Plane) -> Line3
+impl Commutator<Plane> for Plane {
+	type Output = Line3;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.d.0 * rhs.nx.0) - WX(self.nx.0 * rhs.d.0),
+		//     vy: WY(self.d.0 * rhs.ny.0) - WY(self.ny.0 * rhs.d.0),
+		//     vz: WZ(self.d.0 * rhs.nz.0) - WZ(self.nz.0 * rhs.d.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.d.commutator(rhs.nx) + self.nx.commutator(rhs.d),
+			vy: self.d.commutator(rhs.ny) + self.ny.commutator(rhs.d),
+			vz: self.d.commutator(rhs.nz) + self.nz.commutator(rhs.d),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+// Plane.anti_dot(Plane) -> XYZW
+impl AntiDot<Plane> for Plane {
+	type Output = XYZW;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// XYZW(self.nx.0 * rhs.nx.0) + XYZW(self.ny.0 * rhs.ny.0) + XYZW(self.nz.0 * rhs.nz.0)
+		self.nx.anti_dot(rhs.nx) + self.ny.anti_dot(rhs.ny) + self.nz.anti_dot(rhs.nz)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Translator3:
+
+// Plane.geometric(Translator3) -> Multivector
+impl Geometric<Translator3> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.x.0),
+		//     y   : -Y(self.d.0 * rhs.y.0),
+		//     z   : -Z(self.d.0 * rhs.z.0),
+		//     w   : -W(self.d.0 * rhs.w.0) - W(self.nx.0 * rhs.x.0) - W(self.ny.0 * rhs.y.0) - W(self.nz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.ny.0 * rhs.z.0) + YZW(self.nz.0 * rhs.y.0),
+		//     zxw : ZXW(self.nx.0 * rhs.z.0) - ZXW(self.nz.0 * rhs.x.0),
+		//     xyw : -XYW(self.nx.0 * rhs.y.0) + XYW(self.ny.0 * rhs.x.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.geometric(rhs.x),
+			y: self.d.geometric(rhs.y),
+			z: self.d.geometric(rhs.z),
+			w: self.d.geometric(rhs.w) + self.nx.geometric(rhs.x) + self.ny.geometric(rhs.y) + self.nz.geometric(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.ny.geometric(rhs.z) + self.nz.geometric(rhs.y),
+			zxw: self.nx.geometric(rhs.z) + self.nz.geometric(rhs.x),
+			xyw: self.nx.geometric(rhs.y) + self.ny.geometric(rhs.x),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator3> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Translator3) -> Multivector
+impl AntiGeometric<Translator3> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.ny.0 * rhs.z.0) + X(self.nz.0 * rhs.y.0),
+		//     y   : Y(self.nx.0 * rhs.z.0) - Y(self.nz.0 * rhs.x.0),
+		//     z   : -Z(self.nx.0 * rhs.y.0) + Z(self.ny.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.nx.0 * rhs.w.0),
+		//     zxw : ZXW(self.ny.0 * rhs.w.0),
+		//     xyw : XYW(self.nz.0 * rhs.w.0),
+		//     xyz : XYZ(self.d.0 * rhs.w.0) - XYZ(self.nx.0 * rhs.x.0) - XYZ(self.ny.0 * rhs.y.0) - XYZ(self.nz.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.ny.anti_geometric(rhs.z) + self.nz.anti_geometric(rhs.y),
+			y: self.nx.anti_geometric(rhs.z) + self.nz.anti_geometric(rhs.x),
+			z: self.nx.anti_geometric(rhs.y) + self.ny.anti_geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.nx.anti_geometric(rhs.w),
+			zxw: self.ny.anti_geometric(rhs.w),
+			xyw: self.nz.anti_geometric(rhs.w),
+			xyz: self.d.anti_geometric(rhs.w)
+				+ self.nx.anti_geometric(rhs.x)
+				+ self.ny.anti_geometric(rhs.y)
+				+ self.nz.anti_geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(Translator3) -> Vec4
+impl Dot<Translator3> for Plane {
+	type Output = Vec4;
+	fn dot(self, rhs: Translator3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.d.0 * rhs.x.0),
+		//     y: -Y(self.d.0 * rhs.y.0),
+		//     z: -Z(self.d.0 * rhs.z.0),
+		//     w: -W(self.d.0 * rhs.w.0) - W(self.nx.0 * rhs.x.0) - W(self.ny.0 * rhs.y.0) - W(self.nz.0 * rhs.z.0),
+		// }
+		Vec4 {
+			x: self.d.dot(rhs.x),
+			y: self.d.dot(rhs.y),
+			z: self.d.dot(rhs.z),
+			w: self.d.dot(rhs.w) + self.nx.dot(rhs.x) + self.ny.dot(rhs.y) + self.nz.dot(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator3> for Plane {
+	type Output = Vec4;
+	fn bitor(self, rhs: Translator3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Translator3> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: Translator3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Translator3> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: Translator3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Translator3) -> Multivector
+impl AntiWedge<Translator3> for Plane {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.ny.0 * rhs.z.0) + X(self.nz.0 * rhs.y.0),
+		//     y   : Y(self.nx.0 * rhs.z.0) - Y(self.nz.0 * rhs.x.0),
+		//     z   : -Z(self.nx.0 * rhs.y.0) + Z(self.ny.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.nx.0 * rhs.w.0),
+		//     zxw : ZXW(self.ny.0 * rhs.w.0),
+		//     xyw : XYW(self.nz.0 * rhs.w.0),
+		//     xyz : XYZ(self.d.0 * rhs.w.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.ny.anti_wedge(rhs.z) + self.nz.anti_wedge(rhs.y),
+			y: self.nx.anti_wedge(rhs.z) + self.nz.anti_wedge(rhs.x),
+			z: self.nx.anti_wedge(rhs.y) + self.ny.anti_wedge(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.nx.anti_wedge(rhs.w),
+			zxw: self.ny.anti_wedge(rhs.w),
+			xyw: self.nz.anti_wedge(rhs.w),
+			xyz: self.d.anti_wedge(rhs.w),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Translator3> for Plane {
+	type Output = Multivector;
+	fn bitand(self, rhs: Translator3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Plane.left_contraction(Translator3) -> W
+impl LeftContraction<Translator3> for Plane {
+	type Output = W;
+	fn left_contraction(self, rhs: Translator3) -> Self::Output {
+		// -W(self.d.0 * rhs.w.0)
+		self.d.left_contraction(rhs.w)
+	}
+}
+
+// Plane.right_contraction(Translator3) -> Vec4
+impl RightContraction<Translator3> for Plane {
+	type Output = Vec4;
+	fn right_contraction(self, rhs: Translator3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.d.0 * rhs.x.0),
+		//     y: -Y(self.d.0 * rhs.y.0),
+		//     z: -Z(self.d.0 * rhs.z.0),
+		//     w: -W(self.nx.0 * rhs.x.0) - W(self.ny.0 * rhs.y.0) - W(self.nz.0 * rhs.z.0),
+		// }
+		Vec4 {
+			x: self.d.right_contraction(rhs.x),
+			y: self.d.right_contraction(rhs.y),
+			z: self.d.right_contraction(rhs.z),
+			w: self.nx.right_contraction(rhs.x) + self.ny.right_contraction(rhs.y) + self.nz.right_contraction(rhs.z),
+		}
+	}
+}
+
+impl ScalarProduct<Translator3> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Translator3) -> Multivector
+impl Commutator<Translator3> for Plane {
+	type Output = Multivector;
+	fn commutator(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.d.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.ny.0 * rhs.z.0) + YZW(self.nz.0 * rhs.y.0),
+		//     zxw : ZXW(self.nx.0 * rhs.z.0) - ZXW(self.nz.0 * rhs.x.0),
+		//     xyw : -XYW(self.nx.0 * rhs.y.0) + XYW(self.ny.0 * rhs.x.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.d.commutator(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.ny.commutator(rhs.z) + self.nz.commutator(rhs.y),
+			zxw: self.nx.commutator(rhs.z) + self.nz.commutator(rhs.x),
+			xyw: self.nx.commutator(rhs.y) + self.ny.commutator(rhs.x),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Plane.anti_dot(Translator3) -> Plane
+impl AntiDot<Translator3> for Plane {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Translator3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.nx.0 * rhs.w.0),
+		//     ny: ZXW(self.ny.0 * rhs.w.0),
+		//     nz: XYW(self.nz.0 * rhs.w.0),
+		//     d : XYZ(self.d.0 * rhs.w.0) - XYZ(self.nx.0 * rhs.x.0) - XYZ(self.ny.0 * rhs.y.0) - XYZ(self.nz.0 * rhs.z.0),
+		// }
+		Plane {
+			nx: self.nx.anti_dot(rhs.w),
+			ny: self.ny.anti_dot(rhs.w),
+			nz: self.nz.anti_dot(rhs.w),
+			d: self.d.anti_dot(rhs.w) + self.nx.anti_dot(rhs.x) + self.ny.anti_dot(rhs.y) + self.nz.anti_dot(rhs.z),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Rotor3:
+
+// Plane.geometric(Rotor3) -> Multivector
+impl Geometric<Rotor3> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.d.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.d.0 * rhs.x.0),
+		//     zxw : -ZXW(self.d.0 * rhs.y.0),
+		//     xyw : -XYW(self.d.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.d.geometric(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.d.geometric(rhs.x),
+			zxw: self.d.geometric(rhs.y),
+			xyw: self.d.geometric(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Rotor3) -> Multivector
+impl AntiGeometric<Rotor3> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.x.0),
+		//     y   : -Y(self.d.0 * rhs.y.0),
+		//     z   : -Z(self.d.0 * rhs.z.0),
+		//     w   : -W(self.nx.0 * rhs.x.0) - W(self.ny.0 * rhs.y.0) - W(self.nz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.nx.0 * rhs.w.0) + YZW(self.ny.0 * rhs.z.0) - YZW(self.nz.0 * rhs.y.0),
+		//     zxw : -ZXW(self.nx.0 * rhs.z.0) + ZXW(self.ny.0 * rhs.w.0) + ZXW(self.nz.0 * rhs.x.0),
+		//     xyw : XYW(self.nx.0 * rhs.y.0) - XYW(self.ny.0 * rhs.x.0) + XYW(self.nz.0 * rhs.w.0),
+		//     xyz : XYZ(self.d.0 * rhs.w.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.anti_geometric(rhs.x),
+			y: self.d.anti_geometric(rhs.y),
+			z: self.d.anti_geometric(rhs.z),
+			w: self.nx.anti_geometric(rhs.x) + self.ny.anti_geometric(rhs.y) + self.nz.anti_geometric(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.nx.anti_geometric(rhs.w) + self.ny.anti_geometric(rhs.z) + self.nz.anti_geometric(rhs.y),
+			zxw: self.nx.anti_geometric(rhs.z) + self.ny.anti_geometric(rhs.w) + self.nz.anti_geometric(rhs.x),
+			xyw: self.nx.anti_geometric(rhs.y) + self.ny.anti_geometric(rhs.x) + self.nz.anti_geometric(rhs.w),
+			xyz: self.d.anti_geometric(rhs.w),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(Rotor3) -> W
+impl Dot<Rotor3> for Plane {
+	type Output = W;
+	fn dot(self, rhs: Rotor3) -> Self::Output {
+		// -W(self.d.0 * rhs.w.0)
+		self.d.dot(rhs.w)
+	}
+}
+
+impl std::ops::BitOr<Rotor3> for Plane {
+	type Output = W;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Rotor3> for Plane {
+	type Output = Zero;
+	fn wedge(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Rotor3> for Plane {
+	type Output = Zero;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Rotor3) -> Multivector
+impl AntiWedge<Rotor3> for Plane {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.x.0),
+		//     y   : -Y(self.d.0 * rhs.y.0),
+		//     z   : -Z(self.d.0 * rhs.z.0),
+		//     w   : -W(self.nx.0 * rhs.x.0) - W(self.ny.0 * rhs.y.0) - W(self.nz.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.nx.0 * rhs.w.0),
+		//     zxw : ZXW(self.ny.0 * rhs.w.0),
+		//     xyw : XYW(self.nz.0 * rhs.w.0),
+		//     xyz : XYZ(self.d.0 * rhs.w.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.anti_wedge(rhs.x),
+			y: self.d.anti_wedge(rhs.y),
+			z: self.d.anti_wedge(rhs.z),
+			w: self.nx.anti_wedge(rhs.x) + self.ny.anti_wedge(rhs.y) + self.nz.anti_wedge(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.nx.anti_wedge(rhs.w),
+			zxw: self.ny.anti_wedge(rhs.w),
+			xyw: self.nz.anti_wedge(rhs.w),
+			xyz: self.d.anti_wedge(rhs.w),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor3> for Plane {
+	type Output = Multivector;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Plane.left_contraction(Rotor3) -> W
+impl LeftContraction<Rotor3> for Plane {
+	type Output = W;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// -W(self.d.0 * rhs.w.0)
+		self.d.left_contraction(rhs.w)
+	}
+}
+
+impl RightContraction<Rotor3> for Plane {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Rotor3> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Rotor3) -> Multivector
+impl Commutator<Rotor3> for Plane {
+	type Output = Multivector;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.d.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.d.0 * rhs.x.0),
+		//     zxw : -ZXW(self.d.0 * rhs.y.0),
+		//     xyw : -XYW(self.d.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.d.commutator(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.d.commutator(rhs.x),
+			zxw: self.d.commutator(rhs.y),
+			xyw: self.d.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Plane.anti_dot(Rotor3) -> Plane
+impl AntiDot<Rotor3> for Plane {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.nx.0 * rhs.w.0) + YZW(self.ny.0 * rhs.z.0) - YZW(self.nz.0 * rhs.y.0),
+		//     ny: -ZXW(self.nx.0 * rhs.z.0) + ZXW(self.ny.0 * rhs.w.0) + ZXW(self.nz.0 * rhs.x.0),
+		//     nz: XYW(self.nx.0 * rhs.y.0) - XYW(self.ny.0 * rhs.x.0) + XYW(self.nz.0 * rhs.w.0),
+		//     d : XYZ(self.d.0 * rhs.w.0),
+		// }
+		Plane {
+			nx: self.nx.anti_dot(rhs.w) + self.ny.anti_dot(rhs.z) + self.nz.anti_dot(rhs.y),
+			ny: self.nx.anti_dot(rhs.z) + self.ny.anti_dot(rhs.w) + self.nz.anti_dot(rhs.x),
+			nz: self.nx.anti_dot(rhs.y) + self.ny.anti_dot(rhs.x) + self.nz.anti_dot(rhs.w),
+			d: self.d.anti_dot(rhs.w),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Motor3:
+
+// Plane.geometric(Motor3) -> Multivector
+impl Geometric<Motor3> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.ux.0),
+		//     y   : -Y(self.d.0 * rhs.uy.0),
+		//     z   : -Z(self.d.0 * rhs.uz.0),
+		//     w   : -W(self.d.0 * rhs.rw.0) - W(self.nx.0 * rhs.ux.0) - W(self.ny.0 * rhs.uy.0) - W(self.nz.0 * rhs.uz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.d.0 * rhs.rx.0) + YZW(self.nx.0 * rhs.uw.0) - YZW(self.ny.0 * rhs.uz.0) + YZW(self.nz.0 * rhs.uy.0),
+		//     zxw : -ZXW(self.d.0 * rhs.ry.0) + ZXW(self.nx.0 * rhs.uz.0) + ZXW(self.ny.0 * rhs.uw.0) - ZXW(self.nz.0 * rhs.ux.0),
+		//     xyw : -XYW(self.d.0 * rhs.rz.0) - XYW(self.nx.0 * rhs.uy.0) + XYW(self.ny.0 * rhs.ux.0) + XYW(self.nz.0 * rhs.uw.0),
+		//     xyz : XYZ(self.d.0 * rhs.uw.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.geometric(rhs.ux),
+			y: self.d.geometric(rhs.uy),
+			z: self.d.geometric(rhs.uz),
+			w: self.d.geometric(rhs.rw)
+				+ self.nx.geometric(rhs.ux)
+				+ self.ny.geometric(rhs.uy)
+				+ self.nz.geometric(rhs.uz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.d.geometric(rhs.rx)
+				+ self.nx.geometric(rhs.uw)
+				+ self.ny.geometric(rhs.uz)
+				+ self.nz.geometric(rhs.uy),
+			zxw: self.d.geometric(rhs.ry)
+				+ self.nx.geometric(rhs.uz)
+				+ self.ny.geometric(rhs.uw)
+				+ self.nz.geometric(rhs.ux),
+			xyw: self.d.geometric(rhs.rz)
+				+ self.nx.geometric(rhs.uy)
+				+ self.ny.geometric(rhs.ux)
+				+ self.nz.geometric(rhs.uw),
+			xyz: self.d.geometric(rhs.uw),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor3> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Motor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Motor3) -> Multivector
+impl AntiGeometric<Motor3> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.rx.0) - X(self.nx.0 * rhs.uw.0) - X(self.ny.0 * rhs.uz.0) + X(self.nz.0 * rhs.uy.0),
+		//     y   : -Y(self.d.0 * rhs.ry.0) + Y(self.nx.0 * rhs.uz.0) - Y(self.ny.0 * rhs.uw.0) - Y(self.nz.0 * rhs.ux.0),
+		//     z   : -Z(self.d.0 * rhs.rz.0) - Z(self.nx.0 * rhs.uy.0) + Z(self.ny.0 * rhs.ux.0) - Z(self.nz.0 * rhs.uw.0),
+		//     w   : -W(self.nx.0 * rhs.rx.0) - W(self.ny.0 * rhs.ry.0) - W(self.nz.0 * rhs.rz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.nx.0 * rhs.rw.0) + YZW(self.ny.0 * rhs.rz.0) - YZW(self.nz.0 * rhs.ry.0),
+		//     zxw : -ZXW(self.nx.0 * rhs.rz.0) + ZXW(self.ny.0 * rhs.rw.0) + ZXW(self.nz.0 * rhs.rx.0),
+		//     xyw : XYW(self.nx.0 * rhs.ry.0) - XYW(self.ny.0 * rhs.rx.0) + XYW(self.nz.0 * rhs.rw.0),
+		//     xyz : XYZ(self.d.0 * rhs.rw.0) - XYZ(self.nx.0 * rhs.ux.0) - XYZ(self.ny.0 * rhs.uy.0) - XYZ(self.nz.0 * rhs.uz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.anti_geometric(rhs.rx)
+				+ self.nx.anti_geometric(rhs.uw)
+				+ self.ny.anti_geometric(rhs.uz)
+				+ self.nz.anti_geometric(rhs.uy),
+			y: self.d.anti_geometric(rhs.ry)
+				+ self.nx.anti_geometric(rhs.uz)
+				+ self.ny.anti_geometric(rhs.uw)
+				+ self.nz.anti_geometric(rhs.ux),
+			z: self.d.anti_geometric(rhs.rz)
+				+ self.nx.anti_geometric(rhs.uy)
+				+ self.ny.anti_geometric(rhs.ux)
+				+ self.nz.anti_geometric(rhs.uw),
+			w: self.nx.anti_geometric(rhs.rx) + self.ny.anti_geometric(rhs.ry) + self.nz.anti_geometric(rhs.rz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.nx.anti_geometric(rhs.rw) + self.ny.anti_geometric(rhs.rz) + self.nz.anti_geometric(rhs.ry),
+			zxw: self.nx.anti_geometric(rhs.rz) + self.ny.anti_geometric(rhs.rw) + self.nz.anti_geometric(rhs.rx),
+			xyw: self.nx.anti_geometric(rhs.ry) + self.ny.anti_geometric(rhs.rx) + self.nz.anti_geometric(rhs.rw),
+			xyz: self.d.anti_geometric(rhs.rw)
+				+ self.nx.anti_geometric(rhs.ux)
+				+ self.ny.anti_geometric(rhs.uy)
+				+ self.nz.anti_geometric(rhs.uz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Plane.dot(Motor3) -> Multivector
+impl Dot<Motor3> for Plane {
+	type Output = Multivector;
+	fn dot(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.ux.0),
+		//     y   : -Y(self.d.0 * rhs.uy.0),
+		//     z   : -Z(self.d.0 * rhs.uz.0),
+		//     w   : -W(self.d.0 * rhs.rw.0) - W(self.nx.0 * rhs.ux.0) - W(self.ny.0 * rhs.uy.0) - W(self.nz.0 * rhs.uz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.nx.0 * rhs.uw.0),
+		//     zxw : ZXW(self.ny.0 * rhs.uw.0),
+		//     xyw : XYW(self.nz.0 * rhs.uw.0),
+		//     xyz : XYZ(self.d.0 * rhs.uw.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.dot(rhs.ux),
+			y: self.d.dot(rhs.uy),
+			z: self.d.dot(rhs.uz),
+			w: self.d.dot(rhs.rw) + self.nx.dot(rhs.ux) + self.ny.dot(rhs.uy) + self.nz.dot(rhs.uz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.nx.dot(rhs.uw),
+			zxw: self.ny.dot(rhs.uw),
+			xyw: self.nz.dot(rhs.uw),
+			xyz: self.d.dot(rhs.uw),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor3> for Plane {
+	type Output = Multivector;
+	fn bitor(self, rhs: Motor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Plane.wedge(Motor3) -> Plane
+impl Wedge<Motor3> for Plane {
+	type Output = Plane;
+	fn wedge(self, rhs: Motor3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.nx.0 * rhs.uw.0),
+		//     ny: ZXW(self.ny.0 * rhs.uw.0),
+		//     nz: XYW(self.nz.0 * rhs.uw.0),
+		//     d : XYZ(self.d.0 * rhs.uw.0),
+		// }
+		Plane {
+			nx: self.nx.wedge(rhs.uw),
 			ny: self.ny.wedge(rhs.uw),
 			nz: self.nz.wedge(rhs.uw),
 			d: self.d.wedge(rhs.uw),
@@ -338,4 +2425,695 @@ impl Wedge<Motor3> for Plane {
 	}
 }
 
-// Omitted: Plane anti_wedge Motor3 = self.d & rhs.rw + self.d & rhs.rx + self.d & rhs.ry + self.d & rhs.rz + self.d & rhs.ux + self.d & rhs.uy + self.d & rhs.uz + self.nx & rhs.rw + self.nx & rhs.rx + self.nx & rhs.uy + self.nx & rhs.uz + self.ny & rhs.rw + self.ny & rhs.ry + self.ny & rhs.ux + self.ny & rhs.uz + self.nz & rhs.rw + self.nz & rhs.rz + self.nz & rhs.ux + self.nz & rhs.uy  (unnamed type)
+impl std::ops::BitXor<Motor3> for Plane {
+	type Output = Plane;
+	fn bitxor(self, rhs: Motor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Motor3) -> Multivector
+impl AntiWedge<Motor3> for Plane {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.rx.0) - X(self.ny.0 * rhs.uz.0) + X(self.nz.0 * rhs.uy.0),
+		//     y   : -Y(self.d.0 * rhs.ry.0) + Y(self.nx.0 * rhs.uz.0) - Y(self.nz.0 * rhs.ux.0),
+		//     z   : -Z(self.d.0 * rhs.rz.0) - Z(self.nx.0 * rhs.uy.0) + Z(self.ny.0 * rhs.ux.0),
+		//     w   : -W(self.nx.0 * rhs.rx.0) - W(self.ny.0 * rhs.ry.0) - W(self.nz.0 * rhs.rz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.nx.0 * rhs.rw.0),
+		//     zxw : ZXW(self.ny.0 * rhs.rw.0),
+		//     xyw : XYW(self.nz.0 * rhs.rw.0),
+		//     xyz : XYZ(self.d.0 * rhs.rw.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.anti_wedge(rhs.rx) + self.ny.anti_wedge(rhs.uz) + self.nz.anti_wedge(rhs.uy),
+			y: self.d.anti_wedge(rhs.ry) + self.nx.anti_wedge(rhs.uz) + self.nz.anti_wedge(rhs.ux),
+			z: self.d.anti_wedge(rhs.rz) + self.nx.anti_wedge(rhs.uy) + self.ny.anti_wedge(rhs.ux),
+			w: self.nx.anti_wedge(rhs.rx) + self.ny.anti_wedge(rhs.ry) + self.nz.anti_wedge(rhs.rz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.nx.anti_wedge(rhs.rw),
+			zxw: self.ny.anti_wedge(rhs.rw),
+			xyw: self.nz.anti_wedge(rhs.rw),
+			xyz: self.d.anti_wedge(rhs.rw),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor3> for Plane {
+	type Output = Multivector;
+	fn bitand(self, rhs: Motor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Plane.left_contraction(Motor3) -> W
+impl LeftContraction<Motor3> for Plane {
+	type Output = W;
+	fn left_contraction(self, rhs: Motor3) -> Self::Output {
+		// -W(self.d.0 * rhs.rw.0)
+		self.d.left_contraction(rhs.rw)
+	}
+}
+
+// Plane.right_contraction(Motor3) -> Multivector
+impl RightContraction<Motor3> for Plane {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.d.0 * rhs.ux.0),
+		//     y   : -Y(self.d.0 * rhs.uy.0),
+		//     z   : -Z(self.d.0 * rhs.uz.0),
+		//     w   : -W(self.nx.0 * rhs.ux.0) - W(self.ny.0 * rhs.uy.0) - W(self.nz.0 * rhs.uz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.nx.0 * rhs.uw.0),
+		//     zxw : ZXW(self.ny.0 * rhs.uw.0),
+		//     xyw : XYW(self.nz.0 * rhs.uw.0),
+		//     xyz : XYZ(self.d.0 * rhs.uw.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.d.right_contraction(rhs.ux),
+			y: self.d.right_contraction(rhs.uy),
+			z: self.d.right_contraction(rhs.uz),
+			w: self.nx.right_contraction(rhs.ux)
+				+ self.ny.right_contraction(rhs.uy)
+				+ self.nz.right_contraction(rhs.uz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.nx.right_contraction(rhs.uw),
+			zxw: self.ny.right_contraction(rhs.uw),
+			xyw: self.nz.right_contraction(rhs.uw),
+			xyz: self.d.right_contraction(rhs.uw),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Motor3> for Plane {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Motor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Plane.commutator(Motor3) -> Multivector
+impl Commutator<Motor3> for Plane {
+	type Output = Multivector;
+	fn commutator(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.d.0 * rhs.rw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.d.0 * rhs.rx.0) - YZW(self.ny.0 * rhs.uz.0) + YZW(self.nz.0 * rhs.uy.0),
+		//     zxw : -ZXW(self.d.0 * rhs.ry.0) + ZXW(self.nx.0 * rhs.uz.0) - ZXW(self.nz.0 * rhs.ux.0),
+		//     xyw : -XYW(self.d.0 * rhs.rz.0) - XYW(self.nx.0 * rhs.uy.0) + XYW(self.ny.0 * rhs.ux.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.d.commutator(rhs.rw),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.d.commutator(rhs.rx) + self.ny.commutator(rhs.uz) + self.nz.commutator(rhs.uy),
+			zxw: self.d.commutator(rhs.ry) + self.nx.commutator(rhs.uz) + self.nz.commutator(rhs.ux),
+			xyw: self.d.commutator(rhs.rz) + self.nx.commutator(rhs.uy) + self.ny.commutator(rhs.ux),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Plane.anti_dot(Motor3) -> Multivector
+impl AntiDot<Motor3> for Plane {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.nx.0 * rhs.uw.0),
+		//     y   : -Y(self.ny.0 * rhs.uw.0),
+		//     z   : -Z(self.nz.0 * rhs.uw.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.nx.0 * rhs.rw.0) + YZW(self.ny.0 * rhs.rz.0) - YZW(self.nz.0 * rhs.ry.0),
+		//     zxw : -ZXW(self.nx.0 * rhs.rz.0) + ZXW(self.ny.0 * rhs.rw.0) + ZXW(self.nz.0 * rhs.rx.0),
+		//     xyw : XYW(self.nx.0 * rhs.ry.0) - XYW(self.ny.0 * rhs.rx.0) + XYW(self.nz.0 * rhs.rw.0),
+		//     xyz : XYZ(self.d.0 * rhs.rw.0) - XYZ(self.nx.0 * rhs.ux.0) - XYZ(self.ny.0 * rhs.uy.0) - XYZ(self.nz.0 * rhs.uz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.nx.anti_dot(rhs.uw),
+			y: self.ny.anti_dot(rhs.uw),
+			z: self.nz.anti_dot(rhs.uw),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.nx.anti_dot(rhs.rw) + self.ny.anti_dot(rhs.rz) + self.nz.anti_dot(rhs.ry),
+			zxw: self.nx.anti_dot(rhs.rz) + self.ny.anti_dot(rhs.rw) + self.nz.anti_dot(rhs.rx),
+			xyw: self.nx.anti_dot(rhs.ry) + self.ny.anti_dot(rhs.rx) + self.nz.anti_dot(rhs.rw),
+			xyz: self.d.anti_dot(rhs.rw)
+				+ self.nx.anti_dot(rhs.ux)
+				+ self.ny.anti_dot(rhs.uy)
+				+ self.nz.anti_dot(rhs.uz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Plane OP Multivector:
+
+// Plane.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Plane {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.d.0 * rhs.xyz.0),
+		//     x   : -X(self.d.0 * rhs.yz.0),
+		//     y   : -Y(self.d.0 * rhs.zx.0),
+		//     z   : -Z(self.d.0 * rhs.xy.0),
+		//     w   : -W(self.d.0 * rhs.xyzw.0) - W(self.nx.0 * rhs.yz.0) - W(self.ny.0 * rhs.zx.0) - W(self.nz.0 * rhs.xy.0),
+		//     wx  : WX(self.d.0 * rhs.yzw.0) - WX(self.nx.0 * rhs.xyz.0) - WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     wy  : WY(self.d.0 * rhs.zxw.0) + WY(self.nx.0 * rhs.z.0) - WY(self.ny.0 * rhs.xyz.0) - WY(self.nz.0 * rhs.x.0),
+		//     wz  : WZ(self.d.0 * rhs.xyw.0) - WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0) - WZ(self.nz.0 * rhs.xyz.0),
+		//     yz  : YZ(self.d.0 * rhs.x.0),
+		//     zx  : ZX(self.d.0 * rhs.y.0),
+		//     xy  : XY(self.d.0 * rhs.z.0),
+		//     yzw : -YZW(self.d.0 * rhs.wx.0) + YZW(self.nx.0 * rhs.s.0) - YZW(self.ny.0 * rhs.xy.0) + YZW(self.nz.0 * rhs.zx.0),
+		//     zxw : -ZXW(self.d.0 * rhs.wy.0) + ZXW(self.nx.0 * rhs.xy.0) + ZXW(self.ny.0 * rhs.s.0) - ZXW(self.nz.0 * rhs.yz.0),
+		//     xyw : -XYW(self.d.0 * rhs.wz.0) - XYW(self.nx.0 * rhs.zx.0) + XYW(self.ny.0 * rhs.yz.0) + XYW(self.nz.0 * rhs.s.0),
+		//     xyz : XYZ(self.d.0 * rhs.s.0),
+		//     xyzw: XYZW(self.d.0 * rhs.w.0) - XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: self.d.geometric(rhs.xyz),
+			x: self.d.geometric(rhs.yz),
+			y: self.d.geometric(rhs.zx),
+			z: self.d.geometric(rhs.xy),
+			w: self.d.geometric(rhs.xyzw)
+				+ self.nx.geometric(rhs.yz)
+				+ self.ny.geometric(rhs.zx)
+				+ self.nz.geometric(rhs.xy),
+			wx: self.d.geometric(rhs.yzw)
+				+ self.nx.geometric(rhs.xyz)
+				+ self.ny.geometric(rhs.z)
+				+ self.nz.geometric(rhs.y),
+			wy: self.d.geometric(rhs.zxw)
+				+ self.nx.geometric(rhs.z)
+				+ self.ny.geometric(rhs.xyz)
+				+ self.nz.geometric(rhs.x),
+			wz: self.d.geometric(rhs.xyw)
+				+ self.nx.geometric(rhs.y)
+				+ self.ny.geometric(rhs.x)
+				+ self.nz.geometric(rhs.xyz),
+			yz: self.d.geometric(rhs.x),
+			zx: self.d.geometric(rhs.y),
+			xy: self.d.geometric(rhs.z),
+			yzw: self.d.geometric(rhs.wx)
+				+ self.nx.geometric(rhs.s)
+				+ self.ny.geometric(rhs.xy)
+				+ self.nz.geometric(rhs.zx),
+			zxw: self.d.geometric(rhs.wy)
+				+ self.nx.geometric(rhs.xy)
+				+ self.ny.geometric(rhs.s)
+				+ self.nz.geometric(rhs.yz),
+			xyw: self.d.geometric(rhs.wz)
+				+ self.nx.geometric(rhs.zx)
+				+ self.ny.geometric(rhs.yz)
+				+ self.nz.geometric(rhs.s),
+			xyz: self.d.geometric(rhs.s),
+			xyzw: self.d.geometric(rhs.w)
+				+ self.nx.geometric(rhs.x)
+				+ self.ny.geometric(rhs.y)
+				+ self.nz.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Plane {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Plane.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Plane {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.d.0 * rhs.w.0) - S(self.nx.0 * rhs.x.0) - S(self.ny.0 * rhs.y.0) - S(self.nz.0 * rhs.z.0),
+		//     x   : -X(self.d.0 * rhs.wx.0) - X(self.nx.0 * rhs.s.0) - X(self.ny.0 * rhs.xy.0) + X(self.nz.0 * rhs.zx.0),
+		//     y   : -Y(self.d.0 * rhs.wy.0) + Y(self.nx.0 * rhs.xy.0) - Y(self.ny.0 * rhs.s.0) - Y(self.nz.0 * rhs.yz.0),
+		//     z   : -Z(self.d.0 * rhs.wz.0) - Z(self.nx.0 * rhs.zx.0) + Z(self.ny.0 * rhs.yz.0) - Z(self.nz.0 * rhs.s.0),
+		//     w   : -W(self.nx.0 * rhs.wx.0) - W(self.ny.0 * rhs.wy.0) - W(self.nz.0 * rhs.wz.0),
+		//     wx  : -WX(self.nx.0 * rhs.w.0) - WX(self.ny.0 * rhs.xyw.0) + WX(self.nz.0 * rhs.zxw.0),
+		//     wy  : WY(self.nx.0 * rhs.xyw.0) - WY(self.ny.0 * rhs.w.0) - WY(self.nz.0 * rhs.yzw.0),
+		//     wz  : -WZ(self.nx.0 * rhs.zxw.0) + WZ(self.ny.0 * rhs.yzw.0) - WZ(self.nz.0 * rhs.w.0),
+		//     yz  : YZ(self.d.0 * rhs.yzw.0) - YZ(self.nx.0 * rhs.xyz.0) + YZ(self.ny.0 * rhs.z.0) - YZ(self.nz.0 * rhs.y.0),
+		//     zx  : ZX(self.d.0 * rhs.zxw.0) - ZX(self.nx.0 * rhs.z.0) - ZX(self.ny.0 * rhs.xyz.0) + ZX(self.nz.0 * rhs.x.0),
+		//     xy  : XY(self.d.0 * rhs.xyw.0) + XY(self.nx.0 * rhs.y.0) - XY(self.ny.0 * rhs.x.0) - XY(self.nz.0 * rhs.xyz.0),
+		//     yzw : YZW(self.nx.0 * rhs.xyzw.0) + YZW(self.ny.0 * rhs.wz.0) - YZW(self.nz.0 * rhs.wy.0),
+		//     zxw : -ZXW(self.nx.0 * rhs.wz.0) + ZXW(self.ny.0 * rhs.xyzw.0) + ZXW(self.nz.0 * rhs.wx.0),
+		//     xyw : XYW(self.nx.0 * rhs.wy.0) - XYW(self.ny.0 * rhs.wx.0) + XYW(self.nz.0 * rhs.xyzw.0),
+		//     xyz : XYZ(self.d.0 * rhs.xyzw.0) - XYZ(self.nx.0 * rhs.yz.0) - XYZ(self.ny.0 * rhs.zx.0) - XYZ(self.nz.0 * rhs.xy.0),
+		//     xyzw: XYZW(self.nx.0 * rhs.yzw.0) + XYZW(self.ny.0 * rhs.zxw.0) + XYZW(self.nz.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: self.d.anti_geometric(rhs.w)
+				+ self.nx.anti_geometric(rhs.x)
+				+ self.ny.anti_geometric(rhs.y)
+				+ self.nz.anti_geometric(rhs.z),
+			x: self.d.anti_geometric(rhs.wx)
+				+ self.nx.anti_geometric(rhs.s)
+				+ self.ny.anti_geometric(rhs.xy)
+				+ self.nz.anti_geometric(rhs.zx),
+			y: self.d.anti_geometric(rhs.wy)
+				+ self.nx.anti_geometric(rhs.xy)
+				+ self.ny.anti_geometric(rhs.s)
+				+ self.nz.anti_geometric(rhs.yz),
+			z: self.d.anti_geometric(rhs.wz)
+				+ self.nx.anti_geometric(rhs.zx)
+				+ self.ny.anti_geometric(rhs.yz)
+				+ self.nz.anti_geometric(rhs.s),
+			w: self.nx.anti_geometric(rhs.wx) + self.ny.anti_geometric(rhs.wy) + self.nz.anti_geometric(rhs.wz),
+			wx: self.nx.anti_geometric(rhs.w) + self.ny.anti_geometric(rhs.xyw) + self.nz.anti_geometric(rhs.zxw),
+			wy: self.nx.anti_geometric(rhs.xyw) + self.ny.anti_geometric(rhs.w) + self.nz.anti_geometric(rhs.yzw),
+			wz: self.nx.anti_geometric(rhs.zxw) + self.ny.anti_geometric(rhs.yzw) + self.nz.anti_geometric(rhs.w),
+			yz: self.d.anti_geometric(rhs.yzw)
+				+ self.nx.anti_geometric(rhs.xyz)
+				+ self.ny.anti_geometric(rhs.z)
+				+ self.nz.anti_geometric(rhs.y),
+			zx: self.d.anti_geometric(rhs.zxw)
+				+ self.nx.anti_geometric(rhs.z)
+				+ self.ny.anti_geometric(rhs.xyz)
+				+ self.nz.anti_geometric(rhs.x),
+			xy: self.d.anti_geometric(rhs.xyw)
+				+ self.nx.anti_geometric(rhs.y)
+				+ self.ny.anti_geometric(rhs.x)
+				+ self.nz.anti_geometric(rhs.xyz),
+			yzw: self.nx.anti_geometric(rhs.xyzw) + self.ny.anti_geometric(rhs.wz) + self.nz.anti_geometric(rhs.wy),
+			zxw: self.nx.anti_geometric(rhs.wz) + self.ny.anti_geometric(rhs.xyzw) + self.nz.anti_geometric(rhs.wx),
+			xyw: self.nx.anti_geometric(rhs.wy) + self.ny.anti_geometric(rhs.wx) + self.nz.anti_geometric(rhs.xyzw),
+			xyz: self.d.anti_geometric(rhs.xyzw)
+				+ self.nx.anti_geometric(rhs.yz)
+				+ self.ny.anti_geometric(rhs.zx)
+				+ self.nz.anti_geometric(rhs.xy),
+			xyzw: self.nx.anti_geometric(rhs.yzw) + self.ny.anti_geometric(rhs.zxw) + self.nz.anti_geometric(rhs.xyw),
+		}
+	}
+}
+
+// Plane.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Plane {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.d.0 * rhs.xyz.0),
+		//     x   : -X(self.d.0 * rhs.yz.0),
+		//     y   : -Y(self.d.0 * rhs.zx.0),
+		//     z   : -Z(self.d.0 * rhs.xy.0),
+		//     w   : -W(self.d.0 * rhs.xyzw.0) - W(self.nx.0 * rhs.yz.0) - W(self.ny.0 * rhs.zx.0) - W(self.nz.0 * rhs.xy.0),
+		//     wx  : -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     wy  : WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     wz  : -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     yz  : YZ(self.d.0 * rhs.x.0),
+		//     zx  : ZX(self.d.0 * rhs.y.0),
+		//     xy  : XY(self.d.0 * rhs.z.0),
+		//     yzw : YZW(self.nx.0 * rhs.s.0),
+		//     zxw : ZXW(self.ny.0 * rhs.s.0),
+		//     xyw : XYW(self.nz.0 * rhs.s.0),
+		//     xyz : XYZ(self.d.0 * rhs.s.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.d.dot(rhs.xyz),
+			x: self.d.dot(rhs.yz),
+			y: self.d.dot(rhs.zx),
+			z: self.d.dot(rhs.xy),
+			w: self.d.dot(rhs.xyzw) + self.nx.dot(rhs.yz) + self.ny.dot(rhs.zx) + self.nz.dot(rhs.xy),
+			wx: self.ny.dot(rhs.z) + self.nz.dot(rhs.y),
+			wy: self.nx.dot(rhs.z) + self.nz.dot(rhs.x),
+			wz: self.nx.dot(rhs.y) + self.ny.dot(rhs.x),
+			yz: self.d.dot(rhs.x),
+			zx: self.d.dot(rhs.y),
+			xy: self.d.dot(rhs.z),
+			yzw: self.nx.dot(rhs.s),
+			zxw: self.ny.dot(rhs.s),
+			xyw: self.nz.dot(rhs.s),
+			xyz: self.d.dot(rhs.s),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Plane {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Plane.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Plane {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.nx.0 * rhs.s.0),
+		//     zxw : ZXW(self.ny.0 * rhs.s.0),
+		//     xyw : XYW(self.nz.0 * rhs.s.0),
+		//     xyz : XYZ(self.d.0 * rhs.s.0),
+		//     xyzw: XYZW(self.d.0 * rhs.w.0) - XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.nx.wedge(rhs.s),
+			zxw: self.ny.wedge(rhs.s),
+			xyw: self.nz.wedge(rhs.s),
+			xyz: self.d.wedge(rhs.s),
+			xyzw: self.d.wedge(rhs.w) + self.nx.wedge(rhs.x) + self.ny.wedge(rhs.y) + self.nz.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Plane {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Plane.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Plane {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.d.0 * rhs.w.0) - S(self.nx.0 * rhs.x.0) - S(self.ny.0 * rhs.y.0) - S(self.nz.0 * rhs.z.0),
+		//     x   : -X(self.d.0 * rhs.wx.0) - X(self.ny.0 * rhs.xy.0) + X(self.nz.0 * rhs.zx.0),
+		//     y   : -Y(self.d.0 * rhs.wy.0) + Y(self.nx.0 * rhs.xy.0) - Y(self.nz.0 * rhs.yz.0),
+		//     z   : -Z(self.d.0 * rhs.wz.0) - Z(self.nx.0 * rhs.zx.0) + Z(self.ny.0 * rhs.yz.0),
+		//     w   : -W(self.nx.0 * rhs.wx.0) - W(self.ny.0 * rhs.wy.0) - W(self.nz.0 * rhs.wz.0),
+		//     wx  : -WX(self.ny.0 * rhs.xyw.0) + WX(self.nz.0 * rhs.zxw.0),
+		//     wy  : WY(self.nx.0 * rhs.xyw.0) - WY(self.nz.0 * rhs.yzw.0),
+		//     wz  : -WZ(self.nx.0 * rhs.zxw.0) + WZ(self.ny.0 * rhs.yzw.0),
+		//     yz  : YZ(self.d.0 * rhs.yzw.0) - YZ(self.nx.0 * rhs.xyz.0),
+		//     zx  : ZX(self.d.0 * rhs.zxw.0) - ZX(self.ny.0 * rhs.xyz.0),
+		//     xy  : XY(self.d.0 * rhs.xyw.0) - XY(self.nz.0 * rhs.xyz.0),
+		//     yzw : YZW(self.nx.0 * rhs.xyzw.0),
+		//     zxw : ZXW(self.ny.0 * rhs.xyzw.0),
+		//     xyw : XYW(self.nz.0 * rhs.xyzw.0),
+		//     xyz : XYZ(self.d.0 * rhs.xyzw.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.d.anti_wedge(rhs.w)
+				+ self.nx.anti_wedge(rhs.x)
+				+ self.ny.anti_wedge(rhs.y)
+				+ self.nz.anti_wedge(rhs.z),
+			x: self.d.anti_wedge(rhs.wx) + self.ny.anti_wedge(rhs.xy) + self.nz.anti_wedge(rhs.zx),
+			y: self.d.anti_wedge(rhs.wy) + self.nx.anti_wedge(rhs.xy) + self.nz.anti_wedge(rhs.yz),
+			z: self.d.anti_wedge(rhs.wz) + self.nx.anti_wedge(rhs.zx) + self.ny.anti_wedge(rhs.yz),
+			w: self.nx.anti_wedge(rhs.wx) + self.ny.anti_wedge(rhs.wy) + self.nz.anti_wedge(rhs.wz),
+			wx: self.ny.anti_wedge(rhs.xyw) + self.nz.anti_wedge(rhs.zxw),
+			wy: self.nx.anti_wedge(rhs.xyw) + self.nz.anti_wedge(rhs.yzw),
+			wz: self.nx.anti_wedge(rhs.zxw) + self.ny.anti_wedge(rhs.yzw),
+			yz: self.d.anti_wedge(rhs.yzw) + self.nx.anti_wedge(rhs.xyz),
+			zx: self.d.anti_wedge(rhs.zxw) + self.ny.anti_wedge(rhs.xyz),
+			xy: self.d.anti_wedge(rhs.xyw) + self.nz.anti_wedge(rhs.xyz),
+			yzw: self.nx.anti_wedge(rhs.xyzw),
+			zxw: self.ny.anti_wedge(rhs.xyzw),
+			xyw: self.nz.anti_wedge(rhs.xyzw),
+			xyz: self.d.anti_wedge(rhs.xyzw),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Plane {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Plane.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Plane {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.d.0 * rhs.xyz.0),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.d.0 * rhs.xyzw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.d.left_contraction(rhs.xyz),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.d.left_contraction(rhs.xyzw),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Plane.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Plane {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.d.0 * rhs.xyz.0),
+		//     x   : -X(self.d.0 * rhs.yz.0),
+		//     y   : -Y(self.d.0 * rhs.zx.0),
+		//     z   : -Z(self.d.0 * rhs.xy.0),
+		//     w   : -W(self.nx.0 * rhs.yz.0) - W(self.ny.0 * rhs.zx.0) - W(self.nz.0 * rhs.xy.0),
+		//     wx  : -WX(self.ny.0 * rhs.z.0) + WX(self.nz.0 * rhs.y.0),
+		//     wy  : WY(self.nx.0 * rhs.z.0) - WY(self.nz.0 * rhs.x.0),
+		//     wz  : -WZ(self.nx.0 * rhs.y.0) + WZ(self.ny.0 * rhs.x.0),
+		//     yz  : YZ(self.d.0 * rhs.x.0),
+		//     zx  : ZX(self.d.0 * rhs.y.0),
+		//     xy  : XY(self.d.0 * rhs.z.0),
+		//     yzw : YZW(self.nx.0 * rhs.s.0),
+		//     zxw : ZXW(self.ny.0 * rhs.s.0),
+		//     xyw : XYW(self.nz.0 * rhs.s.0),
+		//     xyz : XYZ(self.d.0 * rhs.s.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.d.right_contraction(rhs.xyz),
+			x: self.d.right_contraction(rhs.yz),
+			y: self.d.right_contraction(rhs.zx),
+			z: self.d.right_contraction(rhs.xy),
+			w: self.nx.right_contraction(rhs.yz)
+				+ self.ny.right_contraction(rhs.zx)
+				+ self.nz.right_contraction(rhs.xy),
+			wx: self.ny.right_contraction(rhs.z) + self.nz.right_contraction(rhs.y),
+			wy: self.nx.right_contraction(rhs.z) + self.nz.right_contraction(rhs.x),
+			wz: self.nx.right_contraction(rhs.y) + self.ny.right_contraction(rhs.x),
+			yz: self.d.right_contraction(rhs.x),
+			zx: self.d.right_contraction(rhs.y),
+			xy: self.d.right_contraction(rhs.z),
+			yzw: self.nx.right_contraction(rhs.s),
+			zxw: self.ny.right_contraction(rhs.s),
+			xyw: self.nz.right_contraction(rhs.s),
+			xyz: self.d.right_contraction(rhs.s),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Plane.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Plane {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.d.0 * rhs.xyz.0)
+		self.d.scalar_product(rhs.xyz)
+	}
+}
+
+// Plane.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Plane {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.d.0 * rhs.xyzw.0),
+		//     wx  : WX(self.d.0 * rhs.yzw.0) - WX(self.nx.0 * rhs.xyz.0),
+		//     wy  : WY(self.d.0 * rhs.zxw.0) - WY(self.ny.0 * rhs.xyz.0),
+		//     wz  : WZ(self.d.0 * rhs.xyw.0) - WZ(self.nz.0 * rhs.xyz.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.d.0 * rhs.wx.0) - YZW(self.ny.0 * rhs.xy.0) + YZW(self.nz.0 * rhs.zx.0),
+		//     zxw : -ZXW(self.d.0 * rhs.wy.0) + ZXW(self.nx.0 * rhs.xy.0) - ZXW(self.nz.0 * rhs.yz.0),
+		//     xyw : -XYW(self.d.0 * rhs.wz.0) - XYW(self.nx.0 * rhs.zx.0) + XYW(self.ny.0 * rhs.yz.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.d.0 * rhs.w.0) - XYZW(self.nx.0 * rhs.x.0) - XYZW(self.ny.0 * rhs.y.0) - XYZW(self.nz.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.d.commutator(rhs.xyzw),
+			wx: self.d.commutator(rhs.yzw) + self.nx.commutator(rhs.xyz),
+			wy: self.d.commutator(rhs.zxw) + self.ny.commutator(rhs.xyz),
+			wz: self.d.commutator(rhs.xyw) + self.nz.commutator(rhs.xyz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.d.commutator(rhs.wx) + self.ny.commutator(rhs.xy) + self.nz.commutator(rhs.zx),
+			zxw: self.d.commutator(rhs.wy) + self.nx.commutator(rhs.xy) + self.nz.commutator(rhs.yz),
+			xyw: self.d.commutator(rhs.wz) + self.nx.commutator(rhs.zx) + self.ny.commutator(rhs.yz),
+			xyz: Default::default(),
+			xyzw: self.d.commutator(rhs.w)
+				+ self.nx.commutator(rhs.x)
+				+ self.ny.commutator(rhs.y)
+				+ self.nz.commutator(rhs.z),
+		}
+	}
+}
+
+// Plane.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Plane {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.nx.0 * rhs.s.0),
+		//     y   : -Y(self.ny.0 * rhs.s.0),
+		//     z   : -Z(self.nz.0 * rhs.s.0),
+		//     w   : Default::default(),
+		//     wx  : -WX(self.nx.0 * rhs.w.0),
+		//     wy  : -WY(self.ny.0 * rhs.w.0),
+		//     wz  : -WZ(self.nz.0 * rhs.w.0),
+		//     yz  : YZ(self.ny.0 * rhs.z.0) - YZ(self.nz.0 * rhs.y.0),
+		//     zx  : -ZX(self.nx.0 * rhs.z.0) + ZX(self.nz.0 * rhs.x.0),
+		//     xy  : XY(self.nx.0 * rhs.y.0) - XY(self.ny.0 * rhs.x.0),
+		//     yzw : YZW(self.nx.0 * rhs.xyzw.0) + YZW(self.ny.0 * rhs.wz.0) - YZW(self.nz.0 * rhs.wy.0),
+		//     zxw : -ZXW(self.nx.0 * rhs.wz.0) + ZXW(self.ny.0 * rhs.xyzw.0) + ZXW(self.nz.0 * rhs.wx.0),
+		//     xyw : XYW(self.nx.0 * rhs.wy.0) - XYW(self.ny.0 * rhs.wx.0) + XYW(self.nz.0 * rhs.xyzw.0),
+		//     xyz : XYZ(self.d.0 * rhs.xyzw.0) - XYZ(self.nx.0 * rhs.yz.0) - XYZ(self.ny.0 * rhs.zx.0) - XYZ(self.nz.0 * rhs.xy.0),
+		//     xyzw: XYZW(self.nx.0 * rhs.yzw.0) + XYZW(self.ny.0 * rhs.zxw.0) + XYZW(self.nz.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.nx.anti_dot(rhs.s),
+			y: self.ny.anti_dot(rhs.s),
+			z: self.nz.anti_dot(rhs.s),
+			w: Default::default(),
+			wx: self.nx.anti_dot(rhs.w),
+			wy: self.ny.anti_dot(rhs.w),
+			wz: self.nz.anti_dot(rhs.w),
+			yz: self.ny.anti_dot(rhs.z) + self.nz.anti_dot(rhs.y),
+			zx: self.nx.anti_dot(rhs.z) + self.nz.anti_dot(rhs.x),
+			xy: self.nx.anti_dot(rhs.y) + self.ny.anti_dot(rhs.x),
+			yzw: self.nx.anti_dot(rhs.xyzw) + self.ny.anti_dot(rhs.wz) + self.nz.anti_dot(rhs.wy),
+			zxw: self.nx.anti_dot(rhs.wz) + self.ny.anti_dot(rhs.xyzw) + self.nz.anti_dot(rhs.wx),
+			xyw: self.nx.anti_dot(rhs.wy) + self.ny.anti_dot(rhs.wx) + self.nz.anti_dot(rhs.xyzw),
+			xyz: self.d.anti_dot(rhs.xyzw)
+				+ self.nx.anti_dot(rhs.yz)
+				+ self.ny.anti_dot(rhs.zx)
+				+ self.nz.anti_dot(rhs.xy),
+			xyzw: self.nx.anti_dot(rhs.yzw) + self.ny.anti_dot(rhs.zxw) + self.nz.anti_dot(rhs.xyw),
+		}
+	}
+}
@@ -0,0 +1,69 @@
+//! Distances, angles, projections and intersections.
+//!
+//! Points are homogeneous (any non-zero `w`) unless otherwise noted,
+//! and planes and lines need not be normalized.
+
+use super::*;
+
+/// Signed distance from `point` to `plane`.
+/// Positive on the side that the normal of the plane points to.
+pub fn point_plane_distance(point: Vec4, plane: Plane) -> f64 {
+	point.wedge(plane).0 / (plane.weight_norm() * point.w.0)
+}
+
+/// Distance from `point` to `line` (never negative).
+pub fn point_line_distance(point: Vec4, line: Line3) -> f64 {
+	point.wedge(line).weight_norm() / (line.weight_norm() * point.w.0.abs())
+}
+
+/// Shortest distance between two lines (never negative), including (nearly) parallel lines.
+pub fn line_line_distance(a: Line3, b: Line3) -> f64 {
+	// |a ^ b| is the distance times the sine of the angle between the lines, times their weights:
+	let sin = a.weight_dual().commutator(b.weight_dual()).bulk_norm();
+	let weights = a.weight_norm() * b.weight_norm();
+	if sin > f64::EPSILON.sqrt() * weights {
+		a.wedge(b).0.abs() / sin
+	} else {
+		// Dividing by a smaller sine would magnify the rounding errors of `a ^ b`,
+		// so treat the lines as parallel, where any point on `a` will do:
+		let origin = Vec4 {
+			w: W(1.0),
+			..Default::default()
+		};
+		point_line_distance(project_point_on_line(origin, a), b)
+	}
+}
+
+/// Angle in radians between the normals of two planes, in `[0, π]`.
+pub fn plane_plane_angle(a: Plane, b: Plane) -> f64 {
+	let cos = a.anti_dot(b).0;
+	let sin = a.anti_wedge(b).weight_norm();
+	sin.atan2(cos)
+}
+
+/// The point on `plane` closest to `point`, normalized to `w=±1`.
+pub fn project_point_on_plane(point: Vec4, plane: Plane) -> Vec4 {
+	plane.anti_wedge(point.wedge(plane.weight_dual())).normalized()
+}
+
+/// The point on `line` closest to `point`, normalized to `w=±1`.
+pub fn project_point_on_line(point: Vec4, line: Line3) -> Vec4 {
+	line.anti_wedge(point.wedge(line.weight_dual())).normalized()
+}
+
+/// The point where three planes meet.
+/// Not normalized: `w` is zero if the planes don't meet in a single finite point.
+/// `w` is positive if the normals `a, b, c` are right-handed.
+pub fn meet_planes(a: Plane, b: Plane, c: Plane) -> Vec4 {
+	a.anti_wedge(b).anti_wedge(c)
+}
+
+/// The line through `point` with the same direction as `line`.
+pub fn parallel_line_through(point: Vec4, line: Line3) -> Line3 {
+	// The plane at infinity, oriented so that `line & horizon` is the direction of `line`:
+	let horizon = Plane {
+		d: XYZ(-1.0),
+		..Default::default()
+	};
+	point.wedge(line.anti_wedge(horizon))
+}
@@ -4,39 +4,156 @@
 //! ```text
 //! Rotor3.anti_geometric(Rotor3) -> Rotor3
 //! Rotor3.anti_wedge(Rotor3) -> Rotor3
+//! Rotor3.anti_dot(Rotor3) -> Rotor3
+//! Rotor3.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.dot(Vec3) -> Multivector
+//! Vec3.dot(Rotor3) -> Multivector
 //! Rotor3.wedge(Vec3) -> Plane
 //! Vec3.wedge(Rotor3) -> Plane
 //! Rotor3.anti_wedge(Vec3) -> Vec3
 //! Vec3.anti_wedge(Rotor3) -> Vec3
+//! Vec3.left_contraction(Rotor3) -> Multivector
+//! Rotor3.right_contraction(Vec3) -> Multivector
+//! Rotor3.commutator(Vec3) -> Multivector
+//! Vec3.commutator(Rotor3) -> Multivector
+//! Rotor3.anti_dot(Vec3) -> Multivector
+//! Vec3.anti_dot(Rotor3) -> Multivector
+//! Rotor3.geometric(Vec4) -> Multivector
+//! Vec4.geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.dot(Vec4) -> Multivector
+//! Vec4.dot(Rotor3) -> Multivector
 //! Rotor3.wedge(Vec4) -> Plane
 //! Vec4.wedge(Rotor3) -> Plane
 //! Rotor3.anti_wedge(Vec4) -> Vec4
 //! Vec4.anti_wedge(Rotor3) -> Vec4
+//! Vec4.left_contraction(Rotor3) -> Multivector
+//! Rotor3.right_contraction(Vec4) -> Multivector
+//! Rotor3.commutator(Vec4) -> Multivector
+//! Vec4.commutator(Rotor3) -> Multivector
+//! Rotor3.anti_dot(Vec4) -> Multivector
+//! Vec4.anti_dot(Rotor3) -> Multivector
+//! Rotor3.geometric(Point3) -> Multivector
+//! Point3.geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Point3) -> Multivector
+//! Point3.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.dot(Point3) -> Multivector
+//! Point3.dot(Rotor3) -> Multivector
+//! Rotor3.wedge(Point3) -> Plane
+//! Point3.wedge(Rotor3) -> Plane
+//! Rotor3.anti_wedge(Point3) -> Vec4
+//! Point3.anti_wedge(Rotor3) -> Vec4
+//! Point3.left_contraction(Rotor3) -> Multivector
+//! Rotor3.right_contraction(Point3) -> Multivector
+//! Rotor3.commutator(Point3) -> Multivector
+//! Point3.commutator(Rotor3) -> Multivector
+//! Rotor3.anti_dot(Point3) -> Multivector
+//! Point3.anti_dot(Rotor3) -> Multivector
 //! Rotor3.geometric(Moment3) -> Rotor3
 //! Moment3.geometric(Rotor3) -> Rotor3
+//! Rotor3.anti_geometric(Moment3) -> Motor3
+//! Moment3.anti_geometric(Rotor3) -> Motor3
 //! Rotor3.dot(Moment3) -> Line3
 //! Moment3.dot(Rotor3) -> Line3
 //! Rotor3.wedge(Moment3) -> XYZW
 //! Moment3.wedge(Rotor3) -> XYZW
+//! Rotor3.anti_wedge(Moment3) -> Motor3
+//! Moment3.anti_wedge(Rotor3) -> Motor3
+//! Moment3.left_contraction(Rotor3) -> Line3
+//! Rotor3.right_contraction(Moment3) -> Line3
+//! Rotor3.commutator(Moment3) -> Line3
+//! Moment3.commutator(Rotor3) -> Line3
+//! Rotor3.anti_dot(Moment3) -> Moment3
+//! Moment3.anti_dot(Rotor3) -> Moment3
 //! Rotor3.geometric(Line3) -> Rotor3
 //! Line3.geometric(Rotor3) -> Rotor3
+//! Rotor3.anti_geometric(Line3) -> Motor3
+//! Line3.anti_geometric(Rotor3) -> Motor3
 //! Rotor3.dot(Line3) -> Line3
 //! Line3.dot(Rotor3) -> Line3
 //! Rotor3.wedge(Line3) -> XYZW
 //! Line3.wedge(Rotor3) -> XYZW
+//! Rotor3.anti_wedge(Line3) -> Motor3
+//! Line3.anti_wedge(Rotor3) -> Motor3
+//! Line3.left_contraction(Rotor3) -> Line3
+//! Rotor3.right_contraction(Line3) -> Line3
+//! Rotor3.commutator(Line3) -> Line3
+//! Line3.commutator(Rotor3) -> Line3
+//! Rotor3.anti_dot(Line3) -> Motor3
+//! Line3.anti_dot(Rotor3) -> Motor3
+//! Rotor3.geometric(Plane) -> Multivector
+//! Plane.geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Rotor3) -> Multivector
 //! Rotor3.dot(Plane) -> W
 //! Plane.dot(Rotor3) -> W
+//! Rotor3.anti_wedge(Plane) -> Multivector
+//! Plane.anti_wedge(Rotor3) -> Multivector
+//! Plane.left_contraction(Rotor3) -> W
+//! Rotor3.right_contraction(Plane) -> W
+//! Rotor3.commutator(Plane) -> Multivector
+//! Plane.commutator(Rotor3) -> Multivector
+//! Rotor3.anti_dot(Plane) -> Plane
+//! Plane.anti_dot(Rotor3) -> Plane
+//! Rotor3.geometric(Translator3) -> Rotor3
+//! Translator3.geometric(Rotor3) -> Rotor3
+//! Rotor3.anti_geometric(Translator3) -> Motor3
+//! Translator3.anti_geometric(Rotor3) -> Motor3
+//! Rotor3.dot(Translator3) -> Line3
+//! Translator3.dot(Rotor3) -> Line3
+//! Rotor3.wedge(Translator3) -> XYZW
+//! Translator3.wedge(Rotor3) -> XYZW
+//! Rotor3.anti_wedge(Translator3) -> Motor3
+//! Translator3.anti_wedge(Rotor3) -> Motor3
+//! Translator3.left_contraction(Rotor3) -> Line3
+//! Rotor3.right_contraction(Translator3) -> Line3
+//! Rotor3.commutator(Translator3) -> Line3
+//! Translator3.commutator(Rotor3) -> Line3
+//! Rotor3.anti_dot(Translator3) -> Motor3
+//! Translator3.anti_dot(Rotor3) -> Motor3
 //! Rotor3.geometric(Motor3) -> Rotor3
 //! Motor3.geometric(Rotor3) -> Rotor3
+//! Rotor3.anti_geometric(Motor3) -> Motor3
+//! Motor3.anti_geometric(Rotor3) -> Motor3
 //! Rotor3.dot(Motor3) -> Rotor3
 //! Motor3.dot(Rotor3) -> Rotor3
 //! Rotor3.wedge(Motor3) -> Rotor3
 //! Motor3.wedge(Rotor3) -> Rotor3
+//! Rotor3.anti_wedge(Motor3) -> Motor3
+//! Motor3.anti_wedge(Rotor3) -> Motor3
+//! Motor3.left_contraction(Rotor3) -> Rotor3
+//! Rotor3.right_contraction(Motor3) -> Rotor3
+//! Rotor3.commutator(Motor3) -> Line3
+//! Motor3.commutator(Rotor3) -> Line3
+//! Rotor3.anti_dot(Motor3) -> Motor3
+//! Motor3.anti_dot(Rotor3) -> Motor3
+//! Rotor3.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.dot(Multivector) -> Multivector
+//! Multivector.dot(Rotor3) -> Multivector
+//! Rotor3.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Rotor3) -> Multivector
+//! Rotor3.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Rotor3) -> Multivector
+//! Multivector.left_contraction(Rotor3) -> Multivector
+//! Rotor3.right_contraction(Multivector) -> Multivector
+//! Rotor3.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Rotor3) -> Multivector
+//! Rotor3.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Rotor3) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rotor3 {
 	pub x: WX,
 	pub y: WY,
@@ -44,9 +161,84 @@ pub struct Rotor3 {
 	pub w: XYZW,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Rotor3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Rotor3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Rotor3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.z.abs_diff_eq(&other.z, epsilon)
+			&& self.w.abs_diff_eq(&other.w, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Rotor3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.z.relative_eq(&other.z, epsilon, max_relative)
+			&& self.w.relative_eq(&other.w, epsilon, max_relative)
+	}
+}
+
 // ---------------------------------------------------------------------
-// Omitted: Rotor3.rcompl() -> self.w.rcompl() + self.x.rcompl() + self.y.rcompl() + self.z.rcompl()
-// Omitted: Rotor3.lcompl() -> self.w.lcompl() + self.x.lcompl() + self.y.lcompl() + self.z.lcompl()
+
+impl RCompl for Rotor3 {
+	type Output = Motor3;
+	fn rcompl(self) -> Self::Output {
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.x.rcompl(),
+			uy: self.y.rcompl(),
+			uz: self.z.rcompl(),
+			uw: self.w.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Rotor3 {
+	type Output = Motor3;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Rotor3 {
+	type Output = Motor3;
+	fn lcompl(self) -> Self::Output {
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.x.lcompl(),
+			uy: self.y.lcompl(),
+			uz: self.z.lcompl(),
+			uw: self.w.lcompl(),
+		}
+	}
+}
 
 impl Reverse for Rotor3 {
 	fn rev(self) -> Self {
@@ -70,32 +262,513 @@ impl AntiReverse for Rotor3 {
 	}
 }
 
+impl Involute for Rotor3 {
+	fn involute(self) -> Self {
+		Rotor3 {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+			w: self.w,
+		}
+	}
+}
+
+impl Conjugate for Rotor3 {
+	fn conjugate(self) -> Self {
+		Rotor3 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			w: self.w,
+		}
+	}
+}
+
+impl Dual for Rotor3 {
+	type Output = Motor3;
+	fn dual(self) -> Self::Output {
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.x.dual(),
+			uy: self.y.dual(),
+			uz: self.z.dual(),
+			uw: self.w.dual(),
+		}
+	}
+}
+
+// Omitted: Rotor3.bulk() -> 0
+
+impl Weight for Rotor3 {
+	type Output = Rotor3;
+	fn weight(self) -> Self::Output {
+		Rotor3 {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+			w: self.w,
+		}
+	}
+}
+
+// Omitted: Rotor3.bulk_dual() -> 0
+
+impl WeightDual for Rotor3 {
+	type Output = Motor3;
+	fn weight_dual(self) -> Self::Output {
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.x.weight_dual(),
+			uy: self.y.weight_dual(),
+			uz: self.z.weight_dual(),
+			uw: self.w.weight_dual(),
+		}
+	}
+}
+
+// Omitted: Rotor3.square()  (not a scalar)
+
+impl Norm for Rotor3 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0 + self.w.0 * self.w.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0 + self.w.0 * self.w.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.weight_norm();
+		Rotor3 {
+			x: self.x * scale,
+			y: self.y * scale,
+			z: self.z * scale,
+			w: self.w * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Rotor3 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.x.0, self.y.0, self.z.0, self.w.0]
+	}
+}
+
+impl From<[f64; 4]> for Rotor3 {
+	fn from(a: [f64; 4]) -> Self {
+		Rotor3 {
+			x: WX(a[0]),
+			y: WY(a[1]),
+			z: WZ(a[2]),
+			w: XYZW(a[3]),
+		}
+	}
+}
+
+impl From<Rotor3> for [f64; 4] {
+	fn from(v: Rotor3) -> Self {
+		v.to_array()
+	}
+}
+
+impl Rotor3 {
+	/// Rotate `angle` radians around the normalized `axis` through the origin,
+	/// counter-clockwise when the axis points towards the viewer.
+	pub fn from_axis_angle(axis: [f64; 3], angle: f64) -> Self {
+		let half_angle = angle * (1.0 / 2.0);
+		let (sin, cos) = (half_angle.sin(), half_angle.cos());
+		Rotor3 {
+			x: WX(sin * axis[0]),
+			y: WY(sin * axis[1]),
+			z: WZ(sin * axis[2]),
+			w: XYZW(cos),
+		}
+	}
+}
+
+impl From<Rotor3> for Multivector {
+	fn from(v: Rotor3) -> Self {
+		Multivector {
+			wx: v.x,
+			wy: v.y,
+			wz: v.z,
+			xyzw: v.w,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Rotor3 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Rotor3` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.x.0 == 0.0
+			&& mv.y.0 == 0.0
+			&& mv.z.0 == 0.0
+			&& mv.w.0 == 0.0
+			&& mv.yz.0 == 0.0
+			&& mv.zx.0 == 0.0
+			&& mv.xy.0 == 0.0
+			&& mv.yzw.0 == 0.0
+			&& mv.zxw.0 == 0.0
+			&& mv.xyw.0 == 0.0
+			&& mv.xyz.0 == 0.0
+		{
+			Ok(Rotor3 {
+				x: mv.wx,
+				y: mv.wy,
+				z: mv.wz,
+				w: mv.xyzw,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Rotor3 {
+	/// The matrix that transforms `[x, y, z, w]` like `self.anti_sandwich(v)` for a `Vec4` `v`,
+	/// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+	pub fn to_matrix(self) -> [[f64; 4]; 4] {
+		[
+			[
+				self.w.0 * self.w.0 + self.x.0 * self.x.0 - self.y.0 * self.y.0 - self.z.0 * self.z.0,
+				-2.0 * self.w.0 * self.z.0 + 2.0 * self.x.0 * self.y.0,
+				2.0 * self.w.0 * self.y.0 + 2.0 * self.x.0 * self.z.0,
+				0.0,
+			],
+			[
+				2.0 * self.w.0 * self.z.0 + 2.0 * self.x.0 * self.y.0,
+				self.w.0 * self.w.0 - self.x.0 * self.x.0 + self.y.0 * self.y.0 - self.z.0 * self.z.0,
+				-2.0 * self.w.0 * self.x.0 + 2.0 * self.y.0 * self.z.0,
+				0.0,
+			],
+			[
+				-2.0 * self.w.0 * self.y.0 + 2.0 * self.x.0 * self.z.0,
+				2.0 * self.w.0 * self.x.0 + 2.0 * self.y.0 * self.z.0,
+				self.w.0 * self.w.0 - self.x.0 * self.x.0 - self.y.0 * self.y.0 + self.z.0 * self.z.0,
+				0.0,
+			],
+			[
+				0.0,
+				0.0,
+				0.0,
+				self.w.0 * self.w.0 + self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0,
+			],
+		]
+	}
+
+	/// The first 3 rows of [`Self::to_matrix`], i.e. without the last row (which is `[0, …, 0, 1]` when normalized).
+	pub fn to_matrix_3x4(self) -> [[f64; 4]; 3] {
+		let m = self.to_matrix();
+		[m[0], m[1], m[2]]
+	}
+}
+
+impl Rotor3 {
+	/// The versor that moves nothing, whose matrix is the identity matrix.
+	pub fn identity() -> Self {
+		Rotor3 {
+			x: WX(0.0),
+			y: WY(0.0),
+			z: WZ(0.0),
+			w: XYZW(1.0),
+		}
+	}
+}
+
+impl Rotor3 {
+	/// The normalized versor with this matrix, e.g. from [`Self::to_matrix`].
+	/// Both `v` and `-v` have the same matrix, and this returns the one with a non-negative `w`.
+	pub fn from_matrix(m: [[f64; 4]; 4]) -> Self {
+		let w_w = (m[0][0] + m[1][1] + m[2][2] + m[3][3]) / 4.0;
+		let w_x = (-m[1][2] + m[2][1]) / 4.0;
+		let w_y = (m[0][2] - m[2][0]) / 4.0;
+		let w_z = (-m[0][1] + m[1][0]) / 4.0;
+		let x_x = (m[0][0] - m[1][1] - m[2][2] + m[3][3]) / 4.0;
+		let x_y = (m[0][1] + m[1][0]) / 4.0;
+		let x_z = (m[0][2] + m[2][0]) / 4.0;
+		let y_y = (-m[0][0] + m[1][1] - m[2][2] + m[3][3]) / 4.0;
+		let y_z = (m[1][2] + m[2][1]) / 4.0;
+		let z_z = (-m[0][0] - m[1][1] + m[2][2] + m[3][3]) / 4.0;
+		let w = w_w.max(0.0).sqrt();
+		let x = x_x.max(0.0).sqrt().copysign(w * w_x);
+		let y = y_y.max(0.0).sqrt().copysign(w * w_y + x * x_y);
+		let z = z_z.max(0.0).sqrt().copysign(w * w_z + x * x_z + y * y_z);
+		Rotor3 {
+			x: WX(w * w_x + x * x_x + y * x_y + z * x_z),
+			y: WY(w * w_y + x * x_y + y * y_y + z * y_z),
+			z: WZ(w * w_z + x * x_z + y * y_z + z * z_z),
+			w: XYZW(w * w_w + x * w_x + y * w_y + z * w_z),
+		}
+	}
+}
+
+/// The quaternion `[x, y, z, w] = [rotor.x, rotor.y, rotor.z, rotor.w]`, which rotates vectors like `rotor.anti_sandwich(v)`.
+/// Like the rotor, it should be normalized.
+#[cfg(feature = "glam")]
+impl From<Rotor3> for glam::DQuat {
+	fn from(r: Rotor3) -> Self {
+		glam::DQuat::from_xyzw(r.x.0, r.y.0, r.z.0, r.w.0)
+	}
+}
+
+/// The rotor with `[x, y, z, w] = [rotor.x, rotor.y, rotor.z, rotor.w]` of the quaternion.
+#[cfg(feature = "glam")]
+impl From<glam::DQuat> for Rotor3 {
+	fn from(q: glam::DQuat) -> Self {
+		Rotor3 {
+			x: WX(q.x),
+			y: WY(q.y),
+			z: WZ(q.z),
+			w: XYZW(q.w),
+		}
+	}
+}
+
+/// The quaternion `[x, y, z, w] = [rotor.x, rotor.y, rotor.z, rotor.w]`, which rotates vectors like `rotor.anti_sandwich(v)`.
+/// Like the rotor, it should be normalized.
+#[cfg(feature = "nalgebra")]
+impl From<Rotor3> for nalgebra::UnitQuaternion<f64> {
+	fn from(r: Rotor3) -> Self {
+		nalgebra::UnitQuaternion::new_normalize(nalgebra::Quaternion::new(r.w.0, r.x.0, r.y.0, r.z.0))
+	}
+}
+
+/// The rotor with `[x, y, z, w] = [rotor.x, rotor.y, rotor.z, rotor.w]` of the quaternion.
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::UnitQuaternion<f64>> for Rotor3 {
+	fn from(q: nalgebra::UnitQuaternion<f64>) -> Self {
+		Rotor3 {
+			x: WX(q.i),
+			y: WY(q.j),
+			z: WZ(q.k),
+			w: XYZW(q.w),
+		}
+	}
+}
+
+/// The quaternion `[x, y, z, w] = [rotor.x, rotor.y, rotor.z, rotor.w]`, which rotates vectors like `rotor.anti_sandwich(v)`.
+/// Like the rotor, it should be normalized.
+#[cfg(feature = "cgmath")]
+impl From<Rotor3> for cgmath::Quaternion<f64> {
+	fn from(r: Rotor3) -> Self {
+		cgmath::Quaternion::new(r.w.0, r.x.0, r.y.0, r.z.0)
+	}
+}
+
+/// The rotor with `[x, y, z, w] = [rotor.x, rotor.y, rotor.z, rotor.w]` of the quaternion.
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Quaternion<f64>> for Rotor3 {
+	fn from(q: cgmath::Quaternion<f64>) -> Self {
+		Rotor3 {
+			x: WX(q.v.x),
+			y: WY(q.v.y),
+			z: WZ(q.v.z),
+			w: XYZW(q.s),
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Rotor3 OP Vec3:
 
-// Omitted: Rotor3 geometric Vec3 = self.w * rhs.x + self.w * rhs.y + self.w * rhs.z + self.x * rhs.x + self.x * rhs.y + self.x * rhs.z + self.y * rhs.x + self.y * rhs.y + self.y * rhs.z + self.z * rhs.x + self.z * rhs.y + self.z * rhs.z  (unnamed type)
-// Omitted: Rotor3 anti_geometric Vec3 = self.w !* rhs.x + self.w !* rhs.y + self.w !* rhs.z + self.x !* rhs.x + self.x !* rhs.y + self.x !* rhs.z + self.y !* rhs.x + self.y !* rhs.y + self.y !* rhs.z + self.z !* rhs.x + self.z !* rhs.y + self.z !* rhs.z  (unnamed type)
-// Omitted: Rotor3 dot Vec3 = self.w | rhs.x + self.w | rhs.y + self.w | rhs.z + self.x | rhs.x + self.y | rhs.y + self.z | rhs.z  (unnamed type)
+// Rotor3.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0) + YZW(self.y.0 * rhs.z.0) - YZW(self.z.0 * rhs.y.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0) - ZXW(self.x.0 * rhs.z.0) + ZXW(self.z.0 * rhs.x.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0) + XYW(self.x.0 * rhs.y.0) - XYW(self.y.0 * rhs.x.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.x) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zxw: self.w.geometric(rhs.y) + self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			xyw: self.w.geometric(rhs.z) + self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.w.0 * rhs.x.0) + X(self.y.0 * rhs.z.0) - X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.w.0 * rhs.y.0) - Y(self.x.0 * rhs.z.0) + Y(self.z.0 * rhs.x.0),
+		//     z   : Z(self.w.0 * rhs.z.0) + Z(self.x.0 * rhs.y.0) - Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.w.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+			y: self.w.anti_geometric(rhs.y) + self.x.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.x),
+			z: self.w.anti_geometric(rhs.z) + self.x.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.x.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Rotor3.dot(Vec3) -> Multivector
+impl Dot<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.dot(rhs.x),
+			zxw: self.w.dot(rhs.y),
+			xyw: self.w.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
 
 // Rotor3.wedge(Vec3) -> Plane
 impl Wedge<Vec3> for Rotor3 {
 	type Output = Plane;
 	fn wedge(self, rhs: Vec3) -> Self::Output {
 		// Plane {
-		//     nx: YZW(self.y.0 * rhs.z.0) + YZW(self.z.0 * rhs.y.0),
-		//     ny: ZXW(self.x.0 * rhs.z.0) + ZXW(self.z.0 * rhs.x.0),
-		//     nz: XYW(self.x.0 * rhs.y.0) + XYW(self.y.0 * rhs.x.0),
+		//     nx: YZW(self.y.0 * rhs.z.0) - YZW(self.z.0 * rhs.y.0),
+		//     ny: -ZXW(self.x.0 * rhs.z.0) + ZXW(self.z.0 * rhs.x.0),
+		//     nz: XYW(self.x.0 * rhs.y.0) - XYW(self.y.0 * rhs.x.0),
 		//     d : Default::default(),
 		// }
 		Plane {
-			nx: self.y.wedge(rhs.z) - self.z.wedge(rhs.y),
-			ny: -self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
-			nz: self.x.wedge(rhs.y) - self.y.wedge(rhs.x),
+			nx: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			ny: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			nz: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
 			d: Default::default(),
 		}
 	}
 }
 
+impl std::ops::BitXor<Vec3> for Rotor3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Rotor3.anti_wedge(Vec3) -> Vec3
 impl AntiWedge<Vec3> for Rotor3 {
 	type Output = Vec3;
@@ -113,32 +786,328 @@ impl AntiWedge<Vec3> for Rotor3 {
 	}
 }
 
+impl std::ops::BitAnd<Vec3> for Rotor3 {
+	type Output = Vec3;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for Rotor3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.right_contraction(Vec3) -> Multivector
+impl RightContraction<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.right_contraction(rhs.x),
+			zxw: self.w.right_contraction(rhs.y),
+			xyw: self.w.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.commutator(Vec3) -> Multivector
+impl Commutator<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.commutator(rhs.x) + self.y.commutator(rhs.y) + self.z.commutator(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.commutator(rhs.x),
+			zxw: self.w.commutator(rhs.y),
+			xyw: self.w.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Vec3) -> Multivector
+impl AntiDot<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.w.0 * rhs.x.0),
+		//     y   : Y(self.w.0 * rhs.y.0),
+		//     z   : Z(self.w.0 * rhs.z.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.w.anti_dot(rhs.x),
+			y: self.w.anti_dot(rhs.y),
+			z: self.w.anti_dot(rhs.z),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: self.x.anti_dot(rhs.x) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Rotor3 OP Vec4:
 
-// Omitted: Rotor3 geometric Vec4 = self.w * rhs.x + self.w * rhs.y + self.w * rhs.z + self.x * rhs.x + self.x * rhs.y + self.x * rhs.z + self.y * rhs.x + self.y * rhs.y + self.y * rhs.z + self.z * rhs.x + self.z * rhs.y + self.z * rhs.z  (unnamed type)
-// Omitted: Rotor3 anti_geometric Vec4 = self.w !* rhs.w + self.w !* rhs.x + self.w !* rhs.y + self.w !* rhs.z + self.x !* rhs.w + self.x !* rhs.x + self.x !* rhs.y + self.x !* rhs.z + self.y !* rhs.w + self.y !* rhs.x + self.y !* rhs.y + self.y !* rhs.z + self.z !* rhs.w + self.z !* rhs.x + self.z !* rhs.y + self.z !* rhs.z  (unnamed type)
-// Omitted: Rotor3 dot Vec4 = self.w | rhs.x + self.w | rhs.y + self.w | rhs.z + self.x | rhs.x + self.y | rhs.y + self.z | rhs.z  (unnamed type)
+// Rotor3.geometric(Vec4) -> Multivector
+impl Geometric<Vec4> for Rotor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0) + YZW(self.y.0 * rhs.z.0) - YZW(self.z.0 * rhs.y.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0) - ZXW(self.x.0 * rhs.z.0) + ZXW(self.z.0 * rhs.x.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0) + XYW(self.x.0 * rhs.y.0) - XYW(self.y.0 * rhs.x.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.x) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zxw: self.w.geometric(rhs.y) + self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			xyw: self.w.geometric(rhs.z) + self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec4> for Rotor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Vec4) -> Multivector
+impl AntiGeometric<Vec4> for Rotor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.w.0 * rhs.x.0) + X(self.y.0 * rhs.z.0) - X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.w.0 * rhs.y.0) - Y(self.x.0 * rhs.z.0) + Y(self.z.0 * rhs.x.0),
+		//     z   : Z(self.w.0 * rhs.z.0) + Z(self.x.0 * rhs.y.0) - Z(self.y.0 * rhs.x.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.w.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+			y: self.w.anti_geometric(rhs.y) + self.x.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.x),
+			z: self.w.anti_geometric(rhs.z) + self.x.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.x),
+			w: self.w.anti_geometric(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.anti_geometric(rhs.w),
+			zxw: self.y.anti_geometric(rhs.w),
+			xyw: self.z.anti_geometric(rhs.w),
+			xyz: self.x.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Rotor3.dot(Vec4) -> Multivector
+impl Dot<Vec4> for Rotor3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.dot(rhs.x),
+			zxw: self.w.dot(rhs.y),
+			xyw: self.w.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec4> for Rotor3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
 
 // Rotor3.wedge(Vec4) -> Plane
 impl Wedge<Vec4> for Rotor3 {
 	type Output = Plane;
 	fn wedge(self, rhs: Vec4) -> Self::Output {
 		// Plane {
-		//     nx: YZW(self.y.0 * rhs.z.0) + YZW(self.z.0 * rhs.y.0),
-		//     ny: ZXW(self.x.0 * rhs.z.0) + ZXW(self.z.0 * rhs.x.0),
-		//     nz: XYW(self.x.0 * rhs.y.0) + XYW(self.y.0 * rhs.x.0),
+		//     nx: YZW(self.y.0 * rhs.z.0) - YZW(self.z.0 * rhs.y.0),
+		//     ny: -ZXW(self.x.0 * rhs.z.0) + ZXW(self.z.0 * rhs.x.0),
+		//     nz: XYW(self.x.0 * rhs.y.0) - XYW(self.y.0 * rhs.x.0),
 		//     d : Default::default(),
 		// }
 		Plane {
-			nx: self.y.wedge(rhs.z) - self.z.wedge(rhs.y),
-			ny: -self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
-			nz: self.x.wedge(rhs.y) - self.y.wedge(rhs.x),
+			nx: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			ny: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			nz: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
 			d: Default::default(),
 		}
 	}
 }
 
+impl std::ops::BitXor<Vec4> for Rotor3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Rotor3.anti_wedge(Vec4) -> Vec4
 impl AntiWedge<Vec4> for Rotor3 {
 	type Output = Vec4;
@@ -149,138 +1118,1501 @@ impl AntiWedge<Vec4> for Rotor3 {
 		//     z: Z(self.w.0 * rhs.z.0),
 		//     w: W(self.w.0 * rhs.w.0),
 		// }
-		Vec4 {
-			x: self.w.anti_wedge(rhs.x),
-			y: self.w.anti_wedge(rhs.y),
-			z: self.w.anti_wedge(rhs.z),
-			w: self.w.anti_wedge(rhs.w),
+		Vec4 {
+			x: self.w.anti_wedge(rhs.x),
+			y: self.w.anti_wedge(rhs.y),
+			z: self.w.anti_wedge(rhs.z),
+			w: self.w.anti_wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec4> for Rotor3 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec4> for Rotor3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.right_contraction(Vec4) -> Multivector
+impl RightContraction<Vec4> for Rotor3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.right_contraction(rhs.x),
+			zxw: self.w.right_contraction(rhs.y),
+			xyw: self.w.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Vec4> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.commutator(Vec4) -> Multivector
+impl Commutator<Vec4> for Rotor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.commutator(rhs.x) + self.y.commutator(rhs.y) + self.z.commutator(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.commutator(rhs.x),
+			zxw: self.w.commutator(rhs.y),
+			xyw: self.w.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Vec4) -> Multivector
+impl AntiDot<Vec4> for Rotor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.w.0 * rhs.x.0),
+		//     y   : Y(self.w.0 * rhs.y.0),
+		//     z   : Z(self.w.0 * rhs.z.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.w.anti_dot(rhs.x),
+			y: self.w.anti_dot(rhs.y),
+			z: self.w.anti_dot(rhs.z),
+			w: self.w.anti_dot(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.anti_dot(rhs.w),
+			zxw: self.y.anti_dot(rhs.w),
+			xyw: self.z.anti_dot(rhs.w),
+			xyz: self.x.anti_dot(rhs.x) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor3 OP Point3:
+
+// Rotor3.geometric(Point3) -> Multivector
+impl Geometric<Point3> for Rotor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0) + YZW(self.y.0 * rhs.z.0) - YZW(self.z.0 * rhs.y.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0) - ZXW(self.x.0 * rhs.z.0) + ZXW(self.z.0 * rhs.x.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0) + XYW(self.x.0 * rhs.y.0) - XYW(self.y.0 * rhs.x.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.x) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zxw: self.w.geometric(rhs.y) + self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			xyw: self.w.geometric(rhs.z) + self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point3> for Rotor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Point3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Point3) -> Multivector
+impl AntiGeometric<Point3> for Rotor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.w.0 * rhs.x.0) + X(self.y.0 * rhs.z.0) - X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.w.0 * rhs.y.0) - Y(self.x.0 * rhs.z.0) + Y(self.z.0 * rhs.x.0),
+		//     z   : Z(self.w.0 * rhs.z.0) + Z(self.x.0 * rhs.y.0) - Z(self.y.0 * rhs.x.0),
+		//     w   : W(self.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0),
+		//     zxw : ZXW(self.y.0),
+		//     xyw : XYW(self.z.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.w.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+			y: self.w.anti_geometric(rhs.y) + self.x.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.x),
+			z: self.w.anti_geometric(rhs.z) + self.x.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.x),
+			w: self.w.anti_geometric(W(1.0)),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.anti_geometric(W(1.0)),
+			zxw: self.y.anti_geometric(W(1.0)),
+			xyw: self.z.anti_geometric(W(1.0)),
+			xyz: self.x.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Rotor3.dot(Point3) -> Multivector
+impl Dot<Point3> for Rotor3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.dot(rhs.x),
+			zxw: self.w.dot(rhs.y),
+			xyw: self.w.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point3> for Rotor3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Point3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor3.wedge(Point3) -> Plane
+impl Wedge<Point3> for Rotor3 {
+	type Output = Plane;
+	fn wedge(self, rhs: Point3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.y.0 * rhs.z.0) - YZW(self.z.0 * rhs.y.0),
+		//     ny: -ZXW(self.x.0 * rhs.z.0) + ZXW(self.z.0 * rhs.x.0),
+		//     nz: XYW(self.x.0 * rhs.y.0) - XYW(self.y.0 * rhs.x.0),
+		//     d : Default::default(),
+		// }
+		Plane {
+			nx: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			ny: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			nz: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			d: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point3> for Rotor3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Point3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor3.anti_wedge(Point3) -> Vec4
+impl AntiWedge<Point3> for Rotor3 {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Point3) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.w.0 * rhs.x.0),
+		//     y: Y(self.w.0 * rhs.y.0),
+		//     z: Z(self.w.0 * rhs.z.0),
+		//     w: W(self.w.0),
+		// }
+		Vec4 {
+			x: self.w.anti_wedge(rhs.x),
+			y: self.w.anti_wedge(rhs.y),
+			z: self.w.anti_wedge(rhs.z),
+			w: self.w.anti_wedge(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Point3> for Rotor3 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Point3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point3> for Rotor3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.right_contraction(Point3) -> Multivector
+impl RightContraction<Point3> for Rotor3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.right_contraction(rhs.x),
+			zxw: self.w.right_contraction(rhs.y),
+			xyw: self.w.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Point3> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.commutator(Point3) -> Multivector
+impl Commutator<Point3> for Rotor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.commutator(rhs.x) + self.y.commutator(rhs.y) + self.z.commutator(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.commutator(rhs.x),
+			zxw: self.w.commutator(rhs.y),
+			xyw: self.w.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Point3) -> Multivector
+impl AntiDot<Point3> for Rotor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.w.0 * rhs.x.0),
+		//     y   : Y(self.w.0 * rhs.y.0),
+		//     z   : Z(self.w.0 * rhs.z.0),
+		//     w   : W(self.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0),
+		//     zxw : ZXW(self.y.0),
+		//     xyw : XYW(self.z.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.w.anti_dot(rhs.x),
+			y: self.w.anti_dot(rhs.y),
+			z: self.w.anti_dot(rhs.z),
+			w: self.w.anti_dot(W(1.0)),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.anti_dot(W(1.0)),
+			zxw: self.y.anti_dot(W(1.0)),
+			xyw: self.z.anti_dot(W(1.0)),
+			xyz: self.x.anti_dot(rhs.x) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor3 OP Moment3:
+
+// Rotor3.geometric(Moment3) -> Rotor3
+impl Geometric<Moment3> for Rotor3 {
+	type Output = Rotor3;
+	fn geometric(self, rhs: Moment3) -> Self::Output {
+		// Rotor3 {
+		//     x: WX(self.w.0 * rhs.mx.0) - WX(self.y.0 * rhs.mz.0) + WX(self.z.0 * rhs.my.0),
+		//     y: WY(self.w.0 * rhs.my.0) + WY(self.x.0 * rhs.mz.0) - WY(self.z.0 * rhs.mx.0),
+		//     z: WZ(self.w.0 * rhs.mz.0) - WZ(self.x.0 * rhs.my.0) + WZ(self.y.0 * rhs.mx.0),
+		//     w: -XYZW(self.x.0 * rhs.mx.0) - XYZW(self.y.0 * rhs.my.0) - XYZW(self.z.0 * rhs.mz.0),
+		// }
+		Rotor3 {
+			x: self.w.geometric(rhs.mx) + self.y.geometric(rhs.mz) + self.z.geometric(rhs.my),
+			y: self.w.geometric(rhs.my) + self.x.geometric(rhs.mz) + self.z.geometric(rhs.mx),
+			z: self.w.geometric(rhs.mz) + self.x.geometric(rhs.my) + self.y.geometric(rhs.mx),
+			w: self.x.geometric(rhs.mx) + self.y.geometric(rhs.my) + self.z.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Moment3> for Rotor3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Moment3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Moment3) -> Motor3
+impl AntiGeometric<Moment3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.w.0 * rhs.mx.0) + YZ(self.y.0 * rhs.mz.0) - YZ(self.z.0 * rhs.my.0),
+		//     uy: ZX(self.w.0 * rhs.my.0) - ZX(self.x.0 * rhs.mz.0) + ZX(self.z.0 * rhs.mx.0),
+		//     uz: XY(self.w.0 * rhs.mz.0) + XY(self.x.0 * rhs.my.0) - XY(self.y.0 * rhs.mx.0),
+		//     uw: -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.w.anti_geometric(rhs.mx) + self.y.anti_geometric(rhs.mz) + self.z.anti_geometric(rhs.my),
+			uy: self.w.anti_geometric(rhs.my) + self.x.anti_geometric(rhs.mz) + self.z.anti_geometric(rhs.mx),
+			uz: self.w.anti_geometric(rhs.mz) + self.x.anti_geometric(rhs.my) + self.y.anti_geometric(rhs.mx),
+			uw: self.x.anti_geometric(rhs.mx) + self.y.anti_geometric(rhs.my) + self.z.anti_geometric(rhs.mz),
+		}
+	}
+}
+
+// Rotor3.dot(Moment3) -> Line3
+impl Dot<Moment3> for Rotor3 {
+	type Output = Line3;
+	fn dot(self, rhs: Moment3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.mx.0),
+		//     vy: WY(self.w.0 * rhs.my.0),
+		//     vz: WZ(self.w.0 * rhs.mz.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.w.dot(rhs.mx),
+			vy: self.w.dot(rhs.my),
+			vz: self.w.dot(rhs.mz),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Moment3> for Rotor3 {
+	type Output = Line3;
+	fn bitor(self, rhs: Moment3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor3.wedge(Moment3) -> XYZW
+impl Wedge<Moment3> for Rotor3 {
+	type Output = XYZW;
+	fn wedge(self, rhs: Moment3) -> Self::Output {
+		// -XYZW(self.x.0 * rhs.mx.0) - XYZW(self.y.0 * rhs.my.0) - XYZW(self.z.0 * rhs.mz.0)
+		self.x.wedge(rhs.mx) + self.y.wedge(rhs.my) + self.z.wedge(rhs.mz)
+	}
+}
+
+impl std::ops::BitXor<Moment3> for Rotor3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Moment3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor3.anti_wedge(Moment3) -> Motor3
+impl AntiWedge<Moment3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: Default::default(),
+		//     ry: Default::default(),
+		//     rz: Default::default(),
+		//     rw: Default::default(),
+		//     ux: YZ(self.w.0 * rhs.mx.0),
+		//     uy: ZX(self.w.0 * rhs.my.0),
+		//     uz: XY(self.w.0 * rhs.mz.0),
+		//     uw: -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: Default::default(),
+			ry: Default::default(),
+			rz: Default::default(),
+			rw: Default::default(),
+			ux: self.w.anti_wedge(rhs.mx),
+			uy: self.w.anti_wedge(rhs.my),
+			uz: self.w.anti_wedge(rhs.mz),
+			uw: self.x.anti_wedge(rhs.mx) + self.y.anti_wedge(rhs.my) + self.z.anti_wedge(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Moment3> for Rotor3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Moment3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Moment3> for Rotor3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.right_contraction(Moment3) -> Line3
+impl RightContraction<Moment3> for Rotor3 {
+	type Output = Line3;
+	fn right_contraction(self, rhs: Moment3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.mx.0),
+		//     vy: WY(self.w.0 * rhs.my.0),
+		//     vz: WZ(self.w.0 * rhs.mz.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.w.right_contraction(rhs.mx),
+			vy: self.w.right_contraction(rhs.my),
+			vz: self.w.right_contraction(rhs.mz),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Moment3> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.commutator(Moment3) -> Line3
+impl Commutator<Moment3> for Rotor3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Moment3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.y.0 * rhs.mz.0) + WX(self.z.0 * rhs.my.0),
+		//     vy: WY(self.x.0 * rhs.mz.0) - WY(self.z.0 * rhs.mx.0),
+		//     vz: -WZ(self.x.0 * rhs.my.0) + WZ(self.y.0 * rhs.mx.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.y.commutator(rhs.mz) + self.z.commutator(rhs.my),
+			vy: self.x.commutator(rhs.mz) + self.z.commutator(rhs.mx),
+			vz: self.x.commutator(rhs.my) + self.y.commutator(rhs.mx),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Moment3) -> Moment3
+impl AntiDot<Moment3> for Rotor3 {
+	type Output = Moment3;
+	fn anti_dot(self, rhs: Moment3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.w.0 * rhs.mx.0),
+		//     my: ZX(self.w.0 * rhs.my.0),
+		//     mz: XY(self.w.0 * rhs.mz.0),
+		// }
+		Moment3 {
+			mx: self.w.anti_dot(rhs.mx),
+			my: self.w.anti_dot(rhs.my),
+			mz: self.w.anti_dot(rhs.mz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor3 OP Line3:
+
+// Rotor3.geometric(Line3) -> Rotor3
+impl Geometric<Line3> for Rotor3 {
+	type Output = Rotor3;
+	fn geometric(self, rhs: Line3) -> Self::Output {
+		// Rotor3 {
+		//     x: WX(self.w.0 * rhs.mx.0) - WX(self.y.0 * rhs.mz.0) + WX(self.z.0 * rhs.my.0),
+		//     y: WY(self.w.0 * rhs.my.0) + WY(self.x.0 * rhs.mz.0) - WY(self.z.0 * rhs.mx.0),
+		//     z: WZ(self.w.0 * rhs.mz.0) - WZ(self.x.0 * rhs.my.0) + WZ(self.y.0 * rhs.mx.0),
+		//     w: -XYZW(self.x.0 * rhs.mx.0) - XYZW(self.y.0 * rhs.my.0) - XYZW(self.z.0 * rhs.mz.0),
+		// }
+		Rotor3 {
+			x: self.w.geometric(rhs.mx) + self.y.geometric(rhs.mz) + self.z.geometric(rhs.my),
+			y: self.w.geometric(rhs.my) + self.x.geometric(rhs.mz) + self.z.geometric(rhs.mx),
+			z: self.w.geometric(rhs.mz) + self.x.geometric(rhs.my) + self.y.geometric(rhs.mx),
+			w: self.x.geometric(rhs.mx) + self.y.geometric(rhs.my) + self.z.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Line3> for Rotor3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Line3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Line3) -> Motor3
+impl AntiGeometric<Line3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.vx.0) + WX(self.y.0 * rhs.vz.0) - WX(self.z.0 * rhs.vy.0),
+		//     ry: WY(self.w.0 * rhs.vy.0) - WY(self.x.0 * rhs.vz.0) + WY(self.z.0 * rhs.vx.0),
+		//     rz: WZ(self.w.0 * rhs.vz.0) + WZ(self.x.0 * rhs.vy.0) - WZ(self.y.0 * rhs.vx.0),
+		//     rw: -XYZW(self.x.0 * rhs.vx.0) - XYZW(self.y.0 * rhs.vy.0) - XYZW(self.z.0 * rhs.vz.0),
+		//     ux: YZ(self.w.0 * rhs.mx.0) + YZ(self.y.0 * rhs.mz.0) - YZ(self.z.0 * rhs.my.0),
+		//     uy: ZX(self.w.0 * rhs.my.0) - ZX(self.x.0 * rhs.mz.0) + ZX(self.z.0 * rhs.mx.0),
+		//     uz: XY(self.w.0 * rhs.mz.0) + XY(self.x.0 * rhs.my.0) - XY(self.y.0 * rhs.mx.0),
+		//     uw: -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_geometric(rhs.vx) + self.y.anti_geometric(rhs.vz) + self.z.anti_geometric(rhs.vy),
+			ry: self.w.anti_geometric(rhs.vy) + self.x.anti_geometric(rhs.vz) + self.z.anti_geometric(rhs.vx),
+			rz: self.w.anti_geometric(rhs.vz) + self.x.anti_geometric(rhs.vy) + self.y.anti_geometric(rhs.vx),
+			rw: self.x.anti_geometric(rhs.vx) + self.y.anti_geometric(rhs.vy) + self.z.anti_geometric(rhs.vz),
+			ux: self.w.anti_geometric(rhs.mx) + self.y.anti_geometric(rhs.mz) + self.z.anti_geometric(rhs.my),
+			uy: self.w.anti_geometric(rhs.my) + self.x.anti_geometric(rhs.mz) + self.z.anti_geometric(rhs.mx),
+			uz: self.w.anti_geometric(rhs.mz) + self.x.anti_geometric(rhs.my) + self.y.anti_geometric(rhs.mx),
+			uw: self.x.anti_geometric(rhs.mx) + self.y.anti_geometric(rhs.my) + self.z.anti_geometric(rhs.mz),
+		}
+	}
+}
+
+// Rotor3.dot(Line3) -> Line3
+impl Dot<Line3> for Rotor3 {
+	type Output = Line3;
+	fn dot(self, rhs: Line3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.mx.0),
+		//     vy: WY(self.w.0 * rhs.my.0),
+		//     vz: WZ(self.w.0 * rhs.mz.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.w.dot(rhs.mx),
+			vy: self.w.dot(rhs.my),
+			vz: self.w.dot(rhs.mz),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line3> for Rotor3 {
+	type Output = Line3;
+	fn bitor(self, rhs: Line3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor3.wedge(Line3) -> XYZW
+impl Wedge<Line3> for Rotor3 {
+	type Output = XYZW;
+	fn wedge(self, rhs: Line3) -> Self::Output {
+		// -XYZW(self.x.0 * rhs.mx.0) - XYZW(self.y.0 * rhs.my.0) - XYZW(self.z.0 * rhs.mz.0)
+		self.x.wedge(rhs.mx) + self.y.wedge(rhs.my) + self.z.wedge(rhs.mz)
+	}
+}
+
+impl std::ops::BitXor<Line3> for Rotor3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Line3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor3.anti_wedge(Line3) -> Motor3
+impl AntiWedge<Line3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.vx.0),
+		//     ry: WY(self.w.0 * rhs.vy.0),
+		//     rz: WZ(self.w.0 * rhs.vz.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.w.0 * rhs.mx.0),
+		//     uy: ZX(self.w.0 * rhs.my.0),
+		//     uz: XY(self.w.0 * rhs.mz.0),
+		//     uw: -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_wedge(rhs.vx),
+			ry: self.w.anti_wedge(rhs.vy),
+			rz: self.w.anti_wedge(rhs.vz),
+			rw: Default::default(),
+			ux: self.w.anti_wedge(rhs.mx),
+			uy: self.w.anti_wedge(rhs.my),
+			uz: self.w.anti_wedge(rhs.mz),
+			uw: self.x.anti_wedge(rhs.mx) + self.y.anti_wedge(rhs.my) + self.z.anti_wedge(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line3> for Rotor3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Line3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Line3> for Rotor3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Line3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.right_contraction(Line3) -> Line3
+impl RightContraction<Line3> for Rotor3 {
+	type Output = Line3;
+	fn right_contraction(self, rhs: Line3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.mx.0),
+		//     vy: WY(self.w.0 * rhs.my.0),
+		//     vz: WZ(self.w.0 * rhs.mz.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.w.right_contraction(rhs.mx),
+			vy: self.w.right_contraction(rhs.my),
+			vz: self.w.right_contraction(rhs.mz),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Line3> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.commutator(Line3) -> Line3
+impl Commutator<Line3> for Rotor3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Line3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.y.0 * rhs.mz.0) + WX(self.z.0 * rhs.my.0),
+		//     vy: WY(self.x.0 * rhs.mz.0) - WY(self.z.0 * rhs.mx.0),
+		//     vz: -WZ(self.x.0 * rhs.my.0) + WZ(self.y.0 * rhs.mx.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.y.commutator(rhs.mz) + self.z.commutator(rhs.my),
+			vy: self.x.commutator(rhs.mz) + self.z.commutator(rhs.mx),
+			vz: self.x.commutator(rhs.my) + self.y.commutator(rhs.mx),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Line3) -> Motor3
+impl AntiDot<Line3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.vx.0),
+		//     ry: WY(self.w.0 * rhs.vy.0),
+		//     rz: WZ(self.w.0 * rhs.vz.0),
+		//     rw: -XYZW(self.x.0 * rhs.vx.0) - XYZW(self.y.0 * rhs.vy.0) - XYZW(self.z.0 * rhs.vz.0),
+		//     ux: YZ(self.w.0 * rhs.mx.0),
+		//     uy: ZX(self.w.0 * rhs.my.0),
+		//     uz: XY(self.w.0 * rhs.mz.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.w.anti_dot(rhs.vx),
+			ry: self.w.anti_dot(rhs.vy),
+			rz: self.w.anti_dot(rhs.vz),
+			rw: self.x.anti_dot(rhs.vx) + self.y.anti_dot(rhs.vy) + self.z.anti_dot(rhs.vz),
+			ux: self.w.anti_dot(rhs.mx),
+			uy: self.w.anti_dot(rhs.my),
+			uz: self.w.anti_dot(rhs.mz),
+			uw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor3 OP Plane:
+
+// Rotor3.geometric(Plane) -> Multivector
+impl Geometric<Plane> for Rotor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.w.0 * rhs.d.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.d.0),
+		//     zxw : ZXW(self.y.0 * rhs.d.0),
+		//     xyw : XYW(self.z.0 * rhs.d.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.w.geometric(rhs.d),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.geometric(rhs.d),
+			zxw: self.y.geometric(rhs.d),
+			xyw: self.z.geometric(rhs.d),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Rotor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Rotor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.x.0 * rhs.d.0),
+		//     y   : -Y(self.y.0 * rhs.d.0),
+		//     z   : -Z(self.z.0 * rhs.d.0),
+		//     w   : -W(self.x.0 * rhs.nx.0) - W(self.y.0 * rhs.ny.0) - W(self.z.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.nx.0) + YZW(self.y.0 * rhs.nz.0) - YZW(self.z.0 * rhs.ny.0),
+		//     zxw : ZXW(self.w.0 * rhs.ny.0) - ZXW(self.x.0 * rhs.nz.0) + ZXW(self.z.0 * rhs.nx.0),
+		//     xyw : XYW(self.w.0 * rhs.nz.0) + XYW(self.x.0 * rhs.ny.0) - XYW(self.y.0 * rhs.nx.0),
+		//     xyz : XYZ(self.w.0 * rhs.d.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.anti_geometric(rhs.d),
+			y: self.y.anti_geometric(rhs.d),
+			z: self.z.anti_geometric(rhs.d),
+			w: self.x.anti_geometric(rhs.nx) + self.y.anti_geometric(rhs.ny) + self.z.anti_geometric(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.anti_geometric(rhs.nx) + self.y.anti_geometric(rhs.nz) + self.z.anti_geometric(rhs.ny),
+			zxw: self.w.anti_geometric(rhs.ny) + self.x.anti_geometric(rhs.nz) + self.z.anti_geometric(rhs.nx),
+			xyw: self.w.anti_geometric(rhs.nz) + self.x.anti_geometric(rhs.ny) + self.y.anti_geometric(rhs.nx),
+			xyz: self.w.anti_geometric(rhs.d),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Rotor3.dot(Plane) -> W
+impl Dot<Plane> for Rotor3 {
+	type Output = W;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// W(self.w.0 * rhs.d.0)
+		self.w.dot(rhs.d)
+	}
+}
+
+impl std::ops::BitOr<Plane> for Rotor3 {
+	type Output = W;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for Rotor3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Rotor3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor3.anti_wedge(Plane) -> Multivector
+impl AntiWedge<Plane> for Rotor3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.x.0 * rhs.d.0),
+		//     y   : -Y(self.y.0 * rhs.d.0),
+		//     z   : -Z(self.z.0 * rhs.d.0),
+		//     w   : -W(self.x.0 * rhs.nx.0) - W(self.y.0 * rhs.ny.0) - W(self.z.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.nx.0),
+		//     zxw : ZXW(self.w.0 * rhs.ny.0),
+		//     xyw : XYW(self.w.0 * rhs.nz.0),
+		//     xyz : XYZ(self.w.0 * rhs.d.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.anti_wedge(rhs.d),
+			y: self.y.anti_wedge(rhs.d),
+			z: self.z.anti_wedge(rhs.d),
+			w: self.x.anti_wedge(rhs.nx) + self.y.anti_wedge(rhs.ny) + self.z.anti_wedge(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.anti_wedge(rhs.nx),
+			zxw: self.w.anti_wedge(rhs.ny),
+			xyw: self.w.anti_wedge(rhs.nz),
+			xyz: self.w.anti_wedge(rhs.d),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Rotor3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Plane> for Rotor3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.right_contraction(Plane) -> W
+impl RightContraction<Plane> for Rotor3 {
+	type Output = W;
+	fn right_contraction(self, rhs: Plane) -> Self::Output {
+		// W(self.w.0 * rhs.d.0)
+		self.w.right_contraction(rhs.d)
+	}
+}
+
+impl ScalarProduct<Plane> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.commutator(Plane) -> Multivector
+impl Commutator<Plane> for Rotor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.w.0 * rhs.d.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.d.0),
+		//     zxw : ZXW(self.y.0 * rhs.d.0),
+		//     xyw : XYW(self.z.0 * rhs.d.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.w.commutator(rhs.d),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.commutator(rhs.d),
+			zxw: self.y.commutator(rhs.d),
+			xyw: self.z.commutator(rhs.d),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Plane) -> Plane
+impl AntiDot<Plane> for Rotor3 {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.w.0 * rhs.nx.0) + YZW(self.y.0 * rhs.nz.0) - YZW(self.z.0 * rhs.ny.0),
+		//     ny: ZXW(self.w.0 * rhs.ny.0) - ZXW(self.x.0 * rhs.nz.0) + ZXW(self.z.0 * rhs.nx.0),
+		//     nz: XYW(self.w.0 * rhs.nz.0) + XYW(self.x.0 * rhs.ny.0) - XYW(self.y.0 * rhs.nx.0),
+		//     d : XYZ(self.w.0 * rhs.d.0),
+		// }
+		Plane {
+			nx: self.w.anti_dot(rhs.nx) + self.y.anti_dot(rhs.nz) + self.z.anti_dot(rhs.ny),
+			ny: self.w.anti_dot(rhs.ny) + self.x.anti_dot(rhs.nz) + self.z.anti_dot(rhs.nx),
+			nz: self.w.anti_dot(rhs.nz) + self.x.anti_dot(rhs.ny) + self.y.anti_dot(rhs.nx),
+			d: self.w.anti_dot(rhs.d),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor3 OP Translator3:
+
+// Rotor3.geometric(Translator3) -> Rotor3
+impl Geometric<Translator3> for Rotor3 {
+	type Output = Rotor3;
+	fn geometric(self, rhs: Translator3) -> Self::Output {
+		// Rotor3 {
+		//     x: WX(self.w.0 * rhs.x.0) - WX(self.y.0 * rhs.z.0) + WX(self.z.0 * rhs.y.0),
+		//     y: WY(self.w.0 * rhs.y.0) + WY(self.x.0 * rhs.z.0) - WY(self.z.0 * rhs.x.0),
+		//     z: WZ(self.w.0 * rhs.z.0) - WZ(self.x.0 * rhs.y.0) + WZ(self.y.0 * rhs.x.0),
+		//     w: -XYZW(self.x.0 * rhs.x.0) - XYZW(self.y.0 * rhs.y.0) - XYZW(self.z.0 * rhs.z.0),
+		// }
+		Rotor3 {
+			x: self.w.geometric(rhs.x) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			y: self.w.geometric(rhs.y) + self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			z: self.w.geometric(rhs.z) + self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			w: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator3> for Rotor3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Translator3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Translator3) -> Motor3
+impl AntiGeometric<Translator3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.x.0 * rhs.w.0),
+		//     ry: WY(self.y.0 * rhs.w.0),
+		//     rz: WZ(self.z.0 * rhs.w.0),
+		//     rw: XYZW(self.w.0 * rhs.w.0),
+		//     ux: YZ(self.w.0 * rhs.x.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     uy: ZX(self.w.0 * rhs.y.0) - ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     uz: XY(self.w.0 * rhs.z.0) + XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     uw: -S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.x.anti_geometric(rhs.w),
+			ry: self.y.anti_geometric(rhs.w),
+			rz: self.z.anti_geometric(rhs.w),
+			rw: self.w.anti_geometric(rhs.w),
+			ux: self.w.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+			uy: self.w.anti_geometric(rhs.y) + self.x.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.x),
+			uz: self.w.anti_geometric(rhs.z) + self.x.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.x),
+			uw: self.x.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.z),
 		}
 	}
 }
 
-// ---------------------------------------------------------------------
-// Rotor3 OP Moment3:
-
-// Rotor3.geometric(Moment3) -> Rotor3
-impl Geometric<Moment3> for Rotor3 {
-	type Output = Rotor3;
-	fn geometric(self, rhs: Moment3) -> Self::Output {
-		// Rotor3 {
-		//     x: WX(self.w.0 * rhs.mx.0) + WX(self.y.0 * rhs.mz.0) + WX(self.z.0 * rhs.my.0),
-		//     y: WY(self.w.0 * rhs.my.0) + WY(self.x.0 * rhs.mz.0) + WY(self.z.0 * rhs.mx.0),
-		//     z: WZ(self.w.0 * rhs.mz.0) + WZ(self.x.0 * rhs.my.0) + WZ(self.y.0 * rhs.mx.0),
-		//     w: XYZW(self.x.0 * rhs.mx.0) + XYZW(self.y.0 * rhs.my.0) + XYZW(self.z.0 * rhs.mz.0),
+// Rotor3.dot(Translator3) -> Line3
+impl Dot<Translator3> for Rotor3 {
+	type Output = Line3;
+	fn dot(self, rhs: Translator3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.x.0),
+		//     vy: WY(self.w.0 * rhs.y.0),
+		//     vz: WZ(self.w.0 * rhs.z.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
 		// }
-		Rotor3 {
-			x: self.w.geometric(rhs.mx) - self.y.geometric(rhs.mz) + self.z.geometric(rhs.my),
-			y: self.w.geometric(rhs.my) + self.x.geometric(rhs.mz) - self.z.geometric(rhs.mx),
-			z: self.w.geometric(rhs.mz) - self.x.geometric(rhs.my) + self.y.geometric(rhs.mx),
-			w: -self.x.geometric(rhs.mx) - self.y.geometric(rhs.my) - self.z.geometric(rhs.mz),
+		Line3 {
+			vx: self.w.dot(rhs.x),
+			vy: self.w.dot(rhs.y),
+			vz: self.w.dot(rhs.z),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
 		}
 	}
 }
 
-// Omitted: Rotor3 anti_geometric Moment3 = self.w !* rhs.mx + self.w !* rhs.my + self.w !* rhs.mz + self.x !* rhs.mx + self.x !* rhs.my + self.x !* rhs.mz + self.y !* rhs.mx + self.y !* rhs.my + self.y !* rhs.mz + self.z !* rhs.mx + self.z !* rhs.my + self.z !* rhs.mz  (unnamed type)
-// Omitted: Rotor3 dot Moment3 = Line3 {     vx: self.w | rhs.mx,     vy: self.w | rhs.my,     vz: self.w | rhs.mz,     mx: 0,     my: 0,     mz: 0, }  (too many zeros)
+impl std::ops::BitOr<Translator3> for Rotor3 {
+	type Output = Line3;
+	fn bitor(self, rhs: Translator3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
 
-// Rotor3.wedge(Moment3) -> XYZW
-impl Wedge<Moment3> for Rotor3 {
+// Rotor3.wedge(Translator3) -> XYZW
+impl Wedge<Translator3> for Rotor3 {
 	type Output = XYZW;
-	fn wedge(self, rhs: Moment3) -> Self::Output {
-		// -XYZW(self.x.0 * rhs.mx.0) - XYZW(self.y.0 * rhs.my.0) - XYZW(self.z.0 * rhs.mz.0)
-		self.x.wedge(rhs.mx) + self.y.wedge(rhs.my) + self.z.wedge(rhs.mz)
+	fn wedge(self, rhs: Translator3) -> Self::Output {
+		// -XYZW(self.x.0 * rhs.x.0) - XYZW(self.y.0 * rhs.y.0) - XYZW(self.z.0 * rhs.z.0)
+		self.x.wedge(rhs.x) + self.y.wedge(rhs.y) + self.z.wedge(rhs.z)
 	}
 }
 
-// Omitted: Rotor3 anti_wedge Moment3 = self.w & rhs.mx + self.w & rhs.my + self.w & rhs.mz + self.x & rhs.mx + self.y & rhs.my + self.z & rhs.mz  (unnamed type)
-
-// ---------------------------------------------------------------------
-// Rotor3 OP Line3:
+impl std::ops::BitXor<Translator3> for Rotor3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Translator3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
-// Rotor3.geometric(Line3) -> Rotor3
-impl Geometric<Line3> for Rotor3 {
-	type Output = Rotor3;
-	fn geometric(self, rhs: Line3) -> Self::Output {
-		// Rotor3 {
-		//     x: WX(self.w.0 * rhs.mx.0) + WX(self.y.0 * rhs.mz.0) + WX(self.z.0 * rhs.my.0),
-		//     y: WY(self.w.0 * rhs.my.0) + WY(self.x.0 * rhs.mz.0) + WY(self.z.0 * rhs.mx.0),
-		//     z: WZ(self.w.0 * rhs.mz.0) + WZ(self.x.0 * rhs.my.0) + WZ(self.y.0 * rhs.mx.0),
-		//     w: XYZW(self.x.0 * rhs.mx.0) + XYZW(self.y.0 * rhs.my.0) + XYZW(self.z.0 * rhs.mz.0),
+// Rotor3.anti_wedge(Translator3) -> Motor3
+impl AntiWedge<Translator3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.x.0 * rhs.w.0),
+		//     ry: WY(self.y.0 * rhs.w.0),
+		//     rz: WZ(self.z.0 * rhs.w.0),
+		//     rw: XYZW(self.w.0 * rhs.w.0),
+		//     ux: YZ(self.w.0 * rhs.x.0),
+		//     uy: ZX(self.w.0 * rhs.y.0),
+		//     uz: XY(self.w.0 * rhs.z.0),
+		//     uw: -S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0),
 		// }
-		Rotor3 {
-			x: self.w.geometric(rhs.mx) - self.y.geometric(rhs.mz) + self.z.geometric(rhs.my),
-			y: self.w.geometric(rhs.my) + self.x.geometric(rhs.mz) - self.z.geometric(rhs.mx),
-			z: self.w.geometric(rhs.mz) - self.x.geometric(rhs.my) + self.y.geometric(rhs.mx),
-			w: -self.x.geometric(rhs.mx) - self.y.geometric(rhs.my) - self.z.geometric(rhs.mz),
+		Motor3 {
+			rx: self.x.anti_wedge(rhs.w),
+			ry: self.y.anti_wedge(rhs.w),
+			rz: self.z.anti_wedge(rhs.w),
+			rw: self.w.anti_wedge(rhs.w),
+			ux: self.w.anti_wedge(rhs.x),
+			uy: self.w.anti_wedge(rhs.y),
+			uz: self.w.anti_wedge(rhs.z),
+			uw: self.x.anti_wedge(rhs.x) + self.y.anti_wedge(rhs.y) + self.z.anti_wedge(rhs.z),
 		}
 	}
 }
 
-// Omitted: Rotor3 anti_geometric Line3 = self.w !* rhs.mx + self.w !* rhs.my + self.w !* rhs.mz + self.w !* rhs.vx + self.w !* rhs.vy + self.w !* rhs.vz + self.x !* rhs.mx + self.x !* rhs.my + self.x !* rhs.mz + self.x !* rhs.vx + self.x !* rhs.vy + self.x !* rhs.vz + self.y !* rhs.mx + self.y !* rhs.my + self.y !* rhs.mz + self.y !* rhs.vx + self.y !* rhs.vy + self.y !* rhs.vz + self.z !* rhs.mx + self.z !* rhs.my + self.z !* rhs.mz + self.z !* rhs.vx + self.z !* rhs.vy + self.z !* rhs.vz  (unnamed type)
-// Omitted: Rotor3 dot Line3 = Line3 {     vx: self.w | rhs.mx,     vy: self.w | rhs.my,     vz: self.w | rhs.mz,     mx: 0,     my: 0,     mz: 0, }  (too many zeros)
-
-// Rotor3.wedge(Line3) -> XYZW
-impl Wedge<Line3> for Rotor3 {
-	type Output = XYZW;
-	fn wedge(self, rhs: Line3) -> Self::Output {
-		// -XYZW(self.x.0 * rhs.mx.0) - XYZW(self.y.0 * rhs.my.0) - XYZW(self.z.0 * rhs.mz.0)
-		self.x.wedge(rhs.mx) + self.y.wedge(rhs.my) + self.z.wedge(rhs.mz)
+impl std::ops::BitAnd<Translator3> for Rotor3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Translator3) -> Self::Output {
+		self.anti_wedge(rhs)
 	}
 }
 
-// Omitted: Rotor3 anti_wedge Line3 = self.w & rhs.mx + self.w & rhs.my + self.w & rhs.mz + self.w & rhs.vx + self.w & rhs.vy + self.w & rhs.vz + self.x & rhs.mx + self.y & rhs.my + self.z & rhs.mz  (unnamed type)
+impl LeftContraction<Translator3> for Rotor3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Translator3) -> Self::Output {
+		Zero {}
+	}
+}
 
-// ---------------------------------------------------------------------
-// Rotor3 OP Plane:
+// Rotor3.right_contraction(Translator3) -> Line3
+impl RightContraction<Translator3> for Rotor3 {
+	type Output = Line3;
+	fn right_contraction(self, rhs: Translator3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.x.0),
+		//     vy: WY(self.w.0 * rhs.y.0),
+		//     vz: WZ(self.w.0 * rhs.z.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.w.right_contraction(rhs.x),
+			vy: self.w.right_contraction(rhs.y),
+			vz: self.w.right_contraction(rhs.z),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
 
-// Omitted: Rotor3 geometric Plane = self.w * rhs.d + self.x * rhs.d + self.y * rhs.d + self.z * rhs.d  (unnamed type)
-// Omitted: Rotor3 anti_geometric Plane = self.w !* rhs.d + self.w !* rhs.nx + self.w !* rhs.ny + self.w !* rhs.nz + self.x !* rhs.d + self.x !* rhs.nx + self.x !* rhs.ny + self.x !* rhs.nz + self.y !* rhs.d + self.y !* rhs.nx + self.y !* rhs.ny + self.y !* rhs.nz + self.z !* rhs.d + self.z !* rhs.nx + self.z !* rhs.ny + self.z !* rhs.nz  (unnamed type)
+impl ScalarProduct<Translator3> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator3) -> Self::Output {
+		Zero {}
+	}
+}
 
-// Rotor3.dot(Plane) -> W
-impl Dot<Plane> for Rotor3 {
-	type Output = W;
-	fn dot(self, rhs: Plane) -> Self::Output {
-		// W(self.w.0 * rhs.d.0)
-		self.w.dot(rhs.d)
+// Rotor3.commutator(Translator3) -> Line3
+impl Commutator<Translator3> for Rotor3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Translator3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.y.0 * rhs.z.0) + WX(self.z.0 * rhs.y.0),
+		//     vy: WY(self.x.0 * rhs.z.0) - WY(self.z.0 * rhs.x.0),
+		//     vz: -WZ(self.x.0 * rhs.y.0) + WZ(self.y.0 * rhs.x.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			vy: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			vz: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
 	}
 }
 
-// Omitted: Rotor3 wedge Plane = 0  (unnamed type)
-// Omitted: Rotor3 anti_wedge Plane = self.w & rhs.d + self.w & rhs.nx + self.w & rhs.ny + self.w & rhs.nz + self.x & rhs.d + self.x & rhs.nx + self.y & rhs.d + self.y & rhs.ny + self.z & rhs.d + self.z & rhs.nz  (unnamed type)
+// Rotor3.anti_dot(Translator3) -> Motor3
+impl AntiDot<Translator3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.x.0 * rhs.w.0),
+		//     ry: WY(self.y.0 * rhs.w.0),
+		//     rz: WZ(self.z.0 * rhs.w.0),
+		//     rw: XYZW(self.w.0 * rhs.w.0),
+		//     ux: YZ(self.w.0 * rhs.x.0),
+		//     uy: ZX(self.w.0 * rhs.y.0),
+		//     uz: XY(self.w.0 * rhs.z.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.x.anti_dot(rhs.w),
+			ry: self.y.anti_dot(rhs.w),
+			rz: self.z.anti_dot(rhs.w),
+			rw: self.w.anti_dot(rhs.w),
+			ux: self.w.anti_dot(rhs.x),
+			uy: self.w.anti_dot(rhs.y),
+			uz: self.w.anti_dot(rhs.z),
+			uw: Default::default(),
+		}
+	}
+}
 
 // ---------------------------------------------------------------------
 // Rotor3 OP Rotor3:
 
-// Omitted: Rotor3 geometric Rotor3 = 0  (unnamed type)
+impl Geometric<Rotor3> for Rotor3 {
+	type Output = Zero;
+	fn geometric(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Rotor3 {
+	type Output = Zero;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
 // Rotor3.anti_geometric(Rotor3) -> Rotor3
 impl AntiGeometric<Rotor3> for Rotor3 {
 	type Output = Rotor3;
 	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
 		// Rotor3 {
-		//     x: WX(self.w.0 * rhs.x.0) + WX(self.x.0 * rhs.w.0) + WX(self.y.0 * rhs.z.0) + WX(self.z.0 * rhs.y.0),
-		//     y: WY(self.w.0 * rhs.y.0) + WY(self.x.0 * rhs.z.0) + WY(self.y.0 * rhs.w.0) + WY(self.z.0 * rhs.x.0),
-		//     z: WZ(self.w.0 * rhs.z.0) + WZ(self.x.0 * rhs.y.0) + WZ(self.y.0 * rhs.x.0) + WZ(self.z.0 * rhs.w.0),
-		//     w: XYZW(self.w.0 * rhs.w.0) + XYZW(self.x.0 * rhs.x.0) + XYZW(self.y.0 * rhs.y.0) + XYZW(self.z.0 * rhs.z.0),
+		//     x: WX(self.w.0 * rhs.x.0) + WX(self.x.0 * rhs.w.0) + WX(self.y.0 * rhs.z.0) - WX(self.z.0 * rhs.y.0),
+		//     y: WY(self.w.0 * rhs.y.0) - WY(self.x.0 * rhs.z.0) + WY(self.y.0 * rhs.w.0) + WY(self.z.0 * rhs.x.0),
+		//     z: WZ(self.w.0 * rhs.z.0) + WZ(self.x.0 * rhs.y.0) - WZ(self.y.0 * rhs.x.0) + WZ(self.z.0 * rhs.w.0),
+		//     w: XYZW(self.w.0 * rhs.w.0) - XYZW(self.x.0 * rhs.x.0) - XYZW(self.y.0 * rhs.y.0) - XYZW(self.z.0 * rhs.z.0),
 		// }
 		Rotor3 {
-			x: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(rhs.w) + self.y.anti_geometric(rhs.z)
-				- self.z.anti_geometric(rhs.y),
-			y: self.w.anti_geometric(rhs.y) - self.x.anti_geometric(rhs.z)
+			x: self.w.anti_geometric(rhs.x)
+				+ self.x.anti_geometric(rhs.w)
+				+ self.y.anti_geometric(rhs.z)
+				+ self.z.anti_geometric(rhs.y),
+			y: self.w.anti_geometric(rhs.y)
+				+ self.x.anti_geometric(rhs.z)
 				+ self.y.anti_geometric(rhs.w)
 				+ self.z.anti_geometric(rhs.x),
-			z: self.w.anti_geometric(rhs.z) + self.x.anti_geometric(rhs.y) - self.y.anti_geometric(rhs.x)
+			z: self.w.anti_geometric(rhs.z)
+				+ self.x.anti_geometric(rhs.y)
+				+ self.y.anti_geometric(rhs.x)
 				+ self.z.anti_geometric(rhs.w),
 			w: self.w.anti_geometric(rhs.w)
-				- self.x.anti_geometric(rhs.x)
-				- self.y.anti_geometric(rhs.y)
-				- self.z.anti_geometric(rhs.z),
+				+ self.x.anti_geometric(rhs.x)
+				+ self.y.anti_geometric(rhs.y)
+				+ self.z.anti_geometric(rhs.z),
 		}
 	}
 }
 
-// Omitted: Rotor3 dot Rotor3 = 0  (unnamed type)
-// Omitted: Rotor3 wedge Rotor3 = 0  (unnamed type)
+impl Dot<Rotor3> for Rotor3 {
+	type Output = Zero;
+	fn dot(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Rotor3> for Rotor3 {
+	type Output = Zero;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Rotor3> for Rotor3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Rotor3> for Rotor3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
 // Rotor3.anti_wedge(Rotor3) -> Rotor3
 impl AntiWedge<Rotor3> for Rotor3 {
@@ -301,6 +2633,60 @@ impl AntiWedge<Rotor3> for Rotor3 {
 	}
 }
 
+impl std::ops::BitAnd<Rotor3> for Rotor3 {
+	type Output = Rotor3;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Rotor3> for Rotor3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<Rotor3> for Rotor3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Rotor3> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<Rotor3> for Rotor3 {
+	type Output = Zero;
+	fn commutator(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.anti_dot(Rotor3) -> Rotor3
+impl AntiDot<Rotor3> for Rotor3 {
+	type Output = Rotor3;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     x: WX(self.w.0 * rhs.x.0) + WX(self.x.0 * rhs.w.0),
+		//     y: WY(self.w.0 * rhs.y.0) + WY(self.y.0 * rhs.w.0),
+		//     z: WZ(self.w.0 * rhs.z.0) + WZ(self.z.0 * rhs.w.0),
+		//     w: XYZW(self.w.0 * rhs.w.0) - XYZW(self.x.0 * rhs.x.0) - XYZW(self.y.0 * rhs.y.0) - XYZW(self.z.0 * rhs.z.0),
+		// }
+		Rotor3 {
+			x: self.w.anti_dot(rhs.x) + self.x.anti_dot(rhs.w),
+			y: self.w.anti_dot(rhs.y) + self.y.anti_dot(rhs.w),
+			z: self.w.anti_dot(rhs.z) + self.z.anti_dot(rhs.w),
+			w: self.w.anti_dot(rhs.w) + self.x.anti_dot(rhs.x) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z),
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Rotor3 OP Motor3:
 
@@ -309,41 +2695,116 @@ impl Geometric<Motor3> for Rotor3 {
 	type Output = Rotor3;
 	fn geometric(self, rhs: Motor3) -> Self::Output {
 		// Rotor3 {
-		//     x: WX(self.x.0 * rhs.uw.0),
-		//     y: WY(self.y.0 * rhs.uw.0),
-		//     z: WZ(self.z.0 * rhs.uw.0),
-		//     w: XYZW(self.w.0 * rhs.uw.0),
+		//     x: WX(self.w.0 * rhs.ux.0) + WX(self.x.0 * rhs.uw.0) - WX(self.y.0 * rhs.uz.0) + WX(self.z.0 * rhs.uy.0),
+		//     y: WY(self.w.0 * rhs.uy.0) + WY(self.x.0 * rhs.uz.0) + WY(self.y.0 * rhs.uw.0) - WY(self.z.0 * rhs.ux.0),
+		//     z: WZ(self.w.0 * rhs.uz.0) - WZ(self.x.0 * rhs.uy.0) + WZ(self.y.0 * rhs.ux.0) + WZ(self.z.0 * rhs.uw.0),
+		//     w: XYZW(self.w.0 * rhs.uw.0) - XYZW(self.x.0 * rhs.ux.0) - XYZW(self.y.0 * rhs.uy.0) - XYZW(self.z.0 * rhs.uz.0),
 		// }
 		Rotor3 {
-			x: self.x.geometric(rhs.uw),
-			y: self.y.geometric(rhs.uw),
-			z: self.z.geometric(rhs.uw),
-			w: self.w.geometric(rhs.uw),
+			x: self.w.geometric(rhs.ux)
+				+ self.x.geometric(rhs.uw)
+				+ self.y.geometric(rhs.uz)
+				+ self.z.geometric(rhs.uy),
+			y: self.w.geometric(rhs.uy)
+				+ self.x.geometric(rhs.uz)
+				+ self.y.geometric(rhs.uw)
+				+ self.z.geometric(rhs.ux),
+			z: self.w.geometric(rhs.uz)
+				+ self.x.geometric(rhs.uy)
+				+ self.y.geometric(rhs.ux)
+				+ self.z.geometric(rhs.uw),
+			w: self.w.geometric(rhs.uw)
+				+ self.x.geometric(rhs.ux)
+				+ self.y.geometric(rhs.uy)
+				+ self.z.geometric(rhs.uz),
 		}
 	}
 }
 
-// Omitted: Rotor3 anti_geometric Motor3 = self.w !* rhs.rw + self.w !* rhs.rx + self.w !* rhs.ry + self.w !* rhs.rz + self.w !* rhs.uw + self.w !* rhs.ux + self.w !* rhs.uy + self.w !* rhs.uz + self.x !* rhs.rw + self.x !* rhs.rx + self.x !* rhs.ry + self.x !* rhs.rz + self.x !* rhs.uw + self.x !* rhs.ux + self.x !* rhs.uy + self.x !* rhs.uz + self.y !* rhs.rw + self.y !* rhs.rx + self.y !* rhs.ry + self.y !* rhs.rz + self.y !* rhs.uw + self.y !* rhs.ux + self.y !* rhs.uy + self.y !* rhs.uz + self.z !* rhs.rw + self.z !* rhs.rx + self.z !* rhs.ry + self.z !* rhs.rz + self.z !* rhs.uw + self.z !* rhs.ux + self.z !* rhs.uy + self.z !* rhs.uz  (unnamed type)
+impl std::ops::Mul<Motor3> for Rotor3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Motor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Motor3) -> Motor3
+impl AntiGeometric<Motor3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.rx.0) + WX(self.x.0 * rhs.rw.0) + WX(self.y.0 * rhs.rz.0) - WX(self.z.0 * rhs.ry.0),
+		//     ry: WY(self.w.0 * rhs.ry.0) - WY(self.x.0 * rhs.rz.0) + WY(self.y.0 * rhs.rw.0) + WY(self.z.0 * rhs.rx.0),
+		//     rz: WZ(self.w.0 * rhs.rz.0) + WZ(self.x.0 * rhs.ry.0) - WZ(self.y.0 * rhs.rx.0) + WZ(self.z.0 * rhs.rw.0),
+		//     rw: XYZW(self.w.0 * rhs.rw.0) - XYZW(self.x.0 * rhs.rx.0) - XYZW(self.y.0 * rhs.ry.0) - XYZW(self.z.0 * rhs.rz.0),
+		//     ux: YZ(self.w.0 * rhs.ux.0) + YZ(self.x.0 * rhs.uw.0) + YZ(self.y.0 * rhs.uz.0) - YZ(self.z.0 * rhs.uy.0),
+		//     uy: ZX(self.w.0 * rhs.uy.0) - ZX(self.x.0 * rhs.uz.0) + ZX(self.y.0 * rhs.uw.0) + ZX(self.z.0 * rhs.ux.0),
+		//     uz: XY(self.w.0 * rhs.uz.0) + XY(self.x.0 * rhs.uy.0) - XY(self.y.0 * rhs.ux.0) + XY(self.z.0 * rhs.uw.0),
+		//     uw: S(self.w.0 * rhs.uw.0) - S(self.x.0 * rhs.ux.0) - S(self.y.0 * rhs.uy.0) - S(self.z.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_geometric(rhs.rx)
+				+ self.x.anti_geometric(rhs.rw)
+				+ self.y.anti_geometric(rhs.rz)
+				+ self.z.anti_geometric(rhs.ry),
+			ry: self.w.anti_geometric(rhs.ry)
+				+ self.x.anti_geometric(rhs.rz)
+				+ self.y.anti_geometric(rhs.rw)
+				+ self.z.anti_geometric(rhs.rx),
+			rz: self.w.anti_geometric(rhs.rz)
+				+ self.x.anti_geometric(rhs.ry)
+				+ self.y.anti_geometric(rhs.rx)
+				+ self.z.anti_geometric(rhs.rw),
+			rw: self.w.anti_geometric(rhs.rw)
+				+ self.x.anti_geometric(rhs.rx)
+				+ self.y.anti_geometric(rhs.ry)
+				+ self.z.anti_geometric(rhs.rz),
+			ux: self.w.anti_geometric(rhs.ux)
+				+ self.x.anti_geometric(rhs.uw)
+				+ self.y.anti_geometric(rhs.uz)
+				+ self.z.anti_geometric(rhs.uy),
+			uy: self.w.anti_geometric(rhs.uy)
+				+ self.x.anti_geometric(rhs.uz)
+				+ self.y.anti_geometric(rhs.uw)
+				+ self.z.anti_geometric(rhs.ux),
+			uz: self.w.anti_geometric(rhs.uz)
+				+ self.x.anti_geometric(rhs.uy)
+				+ self.y.anti_geometric(rhs.ux)
+				+ self.z.anti_geometric(rhs.uw),
+			uw: self.w.anti_geometric(rhs.uw)
+				+ self.x.anti_geometric(rhs.ux)
+				+ self.y.anti_geometric(rhs.uy)
+				+ self.z.anti_geometric(rhs.uz),
+		}
+	}
+}
 
 // Rotor3.dot(Motor3) -> Rotor3
 impl Dot<Motor3> for Rotor3 {
 	type Output = Rotor3;
 	fn dot(self, rhs: Motor3) -> Self::Output {
 		// Rotor3 {
-		//     x: WX(self.x.0 * rhs.uw.0),
-		//     y: WY(self.y.0 * rhs.uw.0),
-		//     z: WZ(self.z.0 * rhs.uw.0),
+		//     x: WX(self.w.0 * rhs.ux.0) + WX(self.x.0 * rhs.uw.0),
+		//     y: WY(self.w.0 * rhs.uy.0) + WY(self.y.0 * rhs.uw.0),
+		//     z: WZ(self.w.0 * rhs.uz.0) + WZ(self.z.0 * rhs.uw.0),
 		//     w: XYZW(self.w.0 * rhs.uw.0),
 		// }
 		Rotor3 {
-			x: self.x.dot(rhs.uw),
-			y: self.y.dot(rhs.uw),
-			z: self.z.dot(rhs.uw),
+			x: self.w.dot(rhs.ux) + self.x.dot(rhs.uw),
+			y: self.w.dot(rhs.uy) + self.y.dot(rhs.uw),
+			z: self.w.dot(rhs.uz) + self.z.dot(rhs.uw),
 			w: self.w.dot(rhs.uw),
 		}
 	}
 }
 
+impl std::ops::BitOr<Motor3> for Rotor3 {
+	type Output = Rotor3;
+	fn bitor(self, rhs: Motor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Rotor3.wedge(Motor3) -> Rotor3
 impl Wedge<Motor3> for Rotor3 {
 	type Output = Rotor3;
@@ -352,15 +2813,829 @@ impl Wedge<Motor3> for Rotor3 {
 		//     x: WX(self.x.0 * rhs.uw.0),
 		//     y: WY(self.y.0 * rhs.uw.0),
 		//     z: WZ(self.z.0 * rhs.uw.0),
-		//     w: XYZW(self.w.0 * rhs.uw.0),
+		//     w: XYZW(self.w.0 * rhs.uw.0) - XYZW(self.x.0 * rhs.ux.0) - XYZW(self.y.0 * rhs.uy.0) - XYZW(self.z.0 * rhs.uz.0),
 		// }
 		Rotor3 {
 			x: self.x.wedge(rhs.uw),
 			y: self.y.wedge(rhs.uw),
 			z: self.z.wedge(rhs.uw),
-			w: self.w.wedge(rhs.uw),
+			w: self.w.wedge(rhs.uw) + self.x.wedge(rhs.ux) + self.y.wedge(rhs.uy) + self.z.wedge(rhs.uz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Motor3> for Rotor3 {
+	type Output = Rotor3;
+	fn bitxor(self, rhs: Motor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor3.anti_wedge(Motor3) -> Motor3
+impl AntiWedge<Motor3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.rx.0) + WX(self.x.0 * rhs.rw.0),
+		//     ry: WY(self.w.0 * rhs.ry.0) + WY(self.y.0 * rhs.rw.0),
+		//     rz: WZ(self.w.0 * rhs.rz.0) + WZ(self.z.0 * rhs.rw.0),
+		//     rw: XYZW(self.w.0 * rhs.rw.0),
+		//     ux: YZ(self.w.0 * rhs.ux.0),
+		//     uy: ZX(self.w.0 * rhs.uy.0),
+		//     uz: XY(self.w.0 * rhs.uz.0),
+		//     uw: S(self.w.0 * rhs.uw.0) - S(self.x.0 * rhs.ux.0) - S(self.y.0 * rhs.uy.0) - S(self.z.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_wedge(rhs.rx) + self.x.anti_wedge(rhs.rw),
+			ry: self.w.anti_wedge(rhs.ry) + self.y.anti_wedge(rhs.rw),
+			rz: self.w.anti_wedge(rhs.rz) + self.z.anti_wedge(rhs.rw),
+			rw: self.w.anti_wedge(rhs.rw),
+			ux: self.w.anti_wedge(rhs.ux),
+			uy: self.w.anti_wedge(rhs.uy),
+			uz: self.w.anti_wedge(rhs.uz),
+			uw: self.w.anti_wedge(rhs.uw)
+				+ self.x.anti_wedge(rhs.ux)
+				+ self.y.anti_wedge(rhs.uy)
+				+ self.z.anti_wedge(rhs.uz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor3> for Rotor3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Motor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Motor3> for Rotor3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Motor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.right_contraction(Motor3) -> Rotor3
+impl RightContraction<Motor3> for Rotor3 {
+	type Output = Rotor3;
+	fn right_contraction(self, rhs: Motor3) -> Self::Output {
+		// Rotor3 {
+		//     x: WX(self.w.0 * rhs.ux.0) + WX(self.x.0 * rhs.uw.0),
+		//     y: WY(self.w.0 * rhs.uy.0) + WY(self.y.0 * rhs.uw.0),
+		//     z: WZ(self.w.0 * rhs.uz.0) + WZ(self.z.0 * rhs.uw.0),
+		//     w: XYZW(self.w.0 * rhs.uw.0),
+		// }
+		Rotor3 {
+			x: self.w.right_contraction(rhs.ux) + self.x.right_contraction(rhs.uw),
+			y: self.w.right_contraction(rhs.uy) + self.y.right_contraction(rhs.uw),
+			z: self.w.right_contraction(rhs.uz) + self.z.right_contraction(rhs.uw),
+			w: self.w.right_contraction(rhs.uw),
+		}
+	}
+}
+
+impl ScalarProduct<Motor3> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Motor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.commutator(Motor3) -> Line3
+impl Commutator<Motor3> for Rotor3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Motor3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.y.0 * rhs.uz.0) + WX(self.z.0 * rhs.uy.0),
+		//     vy: WY(self.x.0 * rhs.uz.0) - WY(self.z.0 * rhs.ux.0),
+		//     vz: -WZ(self.x.0 * rhs.uy.0) + WZ(self.y.0 * rhs.ux.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.y.commutator(rhs.uz) + self.z.commutator(rhs.uy),
+			vy: self.x.commutator(rhs.uz) + self.z.commutator(rhs.ux),
+			vz: self.x.commutator(rhs.uy) + self.y.commutator(rhs.ux),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Motor3) -> Motor3
+impl AntiDot<Motor3> for Rotor3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.rx.0) + WX(self.x.0 * rhs.rw.0),
+		//     ry: WY(self.w.0 * rhs.ry.0) + WY(self.y.0 * rhs.rw.0),
+		//     rz: WZ(self.w.0 * rhs.rz.0) + WZ(self.z.0 * rhs.rw.0),
+		//     rw: XYZW(self.w.0 * rhs.rw.0) - XYZW(self.x.0 * rhs.rx.0) - XYZW(self.y.0 * rhs.ry.0) - XYZW(self.z.0 * rhs.rz.0),
+		//     ux: YZ(self.w.0 * rhs.ux.0) + YZ(self.x.0 * rhs.uw.0),
+		//     uy: ZX(self.w.0 * rhs.uy.0) + ZX(self.y.0 * rhs.uw.0),
+		//     uz: XY(self.w.0 * rhs.uz.0) + XY(self.z.0 * rhs.uw.0),
+		//     uw: S(self.w.0 * rhs.uw.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_dot(rhs.rx) + self.x.anti_dot(rhs.rw),
+			ry: self.w.anti_dot(rhs.ry) + self.y.anti_dot(rhs.rw),
+			rz: self.w.anti_dot(rhs.rz) + self.z.anti_dot(rhs.rw),
+			rw: self.w.anti_dot(rhs.rw) + self.x.anti_dot(rhs.rx) + self.y.anti_dot(rhs.ry) + self.z.anti_dot(rhs.rz),
+			ux: self.w.anti_dot(rhs.ux) + self.x.anti_dot(rhs.uw),
+			uy: self.w.anti_dot(rhs.uy) + self.y.anti_dot(rhs.uw),
+			uz: self.w.anti_dot(rhs.uz) + self.z.anti_dot(rhs.uw),
+			uw: self.w.anti_dot(rhs.uw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor3 OP Multivector:
+
+// Rotor3.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.w.0 * rhs.xyz.0) + W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : WX(self.w.0 * rhs.yz.0) + WX(self.x.0 * rhs.s.0) - WX(self.y.0 * rhs.xy.0) + WX(self.z.0 * rhs.zx.0),
+		//     wy  : WY(self.w.0 * rhs.zx.0) + WY(self.x.0 * rhs.xy.0) + WY(self.y.0 * rhs.s.0) - WY(self.z.0 * rhs.yz.0),
+		//     wz  : WZ(self.w.0 * rhs.xy.0) - WZ(self.x.0 * rhs.zx.0) + WZ(self.y.0 * rhs.yz.0) + WZ(self.z.0 * rhs.s.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0) + YZW(self.x.0 * rhs.xyz.0) + YZW(self.y.0 * rhs.z.0) - YZW(self.z.0 * rhs.y.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0) - ZXW(self.x.0 * rhs.z.0) + ZXW(self.y.0 * rhs.xyz.0) + ZXW(self.z.0 * rhs.x.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0) + XYW(self.x.0 * rhs.y.0) - XYW(self.y.0 * rhs.x.0) + XYW(self.z.0 * rhs.xyz.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.w.0 * rhs.s.0) - XYZW(self.x.0 * rhs.yz.0) - XYZW(self.y.0 * rhs.zx.0) - XYZW(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.w.geometric(rhs.xyz) + self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			wx: self.w.geometric(rhs.yz)
+				+ self.x.geometric(rhs.s)
+				+ self.y.geometric(rhs.xy)
+				+ self.z.geometric(rhs.zx),
+			wy: self.w.geometric(rhs.zx)
+				+ self.x.geometric(rhs.xy)
+				+ self.y.geometric(rhs.s)
+				+ self.z.geometric(rhs.yz),
+			wz: self.w.geometric(rhs.xy)
+				+ self.x.geometric(rhs.zx)
+				+ self.y.geometric(rhs.yz)
+				+ self.z.geometric(rhs.s),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.x)
+				+ self.x.geometric(rhs.xyz)
+				+ self.y.geometric(rhs.z)
+				+ self.z.geometric(rhs.y),
+			zxw: self.w.geometric(rhs.y)
+				+ self.x.geometric(rhs.z)
+				+ self.y.geometric(rhs.xyz)
+				+ self.z.geometric(rhs.x),
+			xyw: self.w.geometric(rhs.z)
+				+ self.x.geometric(rhs.y)
+				+ self.y.geometric(rhs.x)
+				+ self.z.geometric(rhs.xyz),
+			xyz: Default::default(),
+			xyzw: self.w.geometric(rhs.s)
+				+ self.x.geometric(rhs.yz)
+				+ self.y.geometric(rhs.zx)
+				+ self.z.geometric(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.w.0 * rhs.s.0) - S(self.x.0 * rhs.yz.0) - S(self.y.0 * rhs.zx.0) - S(self.z.0 * rhs.xy.0),
+		//     x   : X(self.w.0 * rhs.x.0) - X(self.x.0 * rhs.xyz.0) + X(self.y.0 * rhs.z.0) - X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.w.0 * rhs.y.0) - Y(self.x.0 * rhs.z.0) - Y(self.y.0 * rhs.xyz.0) + Y(self.z.0 * rhs.x.0),
+		//     z   : Z(self.w.0 * rhs.z.0) + Z(self.x.0 * rhs.y.0) - Z(self.y.0 * rhs.x.0) - Z(self.z.0 * rhs.xyz.0),
+		//     w   : W(self.w.0 * rhs.w.0) - W(self.x.0 * rhs.yzw.0) - W(self.y.0 * rhs.zxw.0) - W(self.z.0 * rhs.xyw.0),
+		//     wx  : WX(self.w.0 * rhs.wx.0) + WX(self.x.0 * rhs.xyzw.0) + WX(self.y.0 * rhs.wz.0) - WX(self.z.0 * rhs.wy.0),
+		//     wy  : WY(self.w.0 * rhs.wy.0) - WY(self.x.0 * rhs.wz.0) + WY(self.y.0 * rhs.xyzw.0) + WY(self.z.0 * rhs.wx.0),
+		//     wz  : WZ(self.w.0 * rhs.wz.0) + WZ(self.x.0 * rhs.wy.0) - WZ(self.y.0 * rhs.wx.0) + WZ(self.z.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.w.0 * rhs.yz.0) + YZ(self.x.0 * rhs.s.0) + YZ(self.y.0 * rhs.xy.0) - YZ(self.z.0 * rhs.zx.0),
+		//     zx  : ZX(self.w.0 * rhs.zx.0) - ZX(self.x.0 * rhs.xy.0) + ZX(self.y.0 * rhs.s.0) + ZX(self.z.0 * rhs.yz.0),
+		//     xy  : XY(self.w.0 * rhs.xy.0) + XY(self.x.0 * rhs.zx.0) - XY(self.y.0 * rhs.yz.0) + XY(self.z.0 * rhs.s.0),
+		//     yzw : YZW(self.w.0 * rhs.yzw.0) + YZW(self.x.0 * rhs.w.0) + YZW(self.y.0 * rhs.xyw.0) - YZW(self.z.0 * rhs.zxw.0),
+		//     zxw : ZXW(self.w.0 * rhs.zxw.0) - ZXW(self.x.0 * rhs.xyw.0) + ZXW(self.y.0 * rhs.w.0) + ZXW(self.z.0 * rhs.yzw.0),
+		//     xyw : XYW(self.w.0 * rhs.xyw.0) + XYW(self.x.0 * rhs.zxw.0) - XYW(self.y.0 * rhs.yzw.0) + XYW(self.z.0 * rhs.w.0),
+		//     xyz : XYZ(self.w.0 * rhs.xyz.0) + XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: XYZW(self.w.0 * rhs.xyzw.0) - XYZW(self.x.0 * rhs.wx.0) - XYZW(self.y.0 * rhs.wy.0) - XYZW(self.z.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: self.w.anti_geometric(rhs.s)
+				+ self.x.anti_geometric(rhs.yz)
+				+ self.y.anti_geometric(rhs.zx)
+				+ self.z.anti_geometric(rhs.xy),
+			x: self.w.anti_geometric(rhs.x)
+				+ self.x.anti_geometric(rhs.xyz)
+				+ self.y.anti_geometric(rhs.z)
+				+ self.z.anti_geometric(rhs.y),
+			y: self.w.anti_geometric(rhs.y)
+				+ self.x.anti_geometric(rhs.z)
+				+ self.y.anti_geometric(rhs.xyz)
+				+ self.z.anti_geometric(rhs.x),
+			z: self.w.anti_geometric(rhs.z)
+				+ self.x.anti_geometric(rhs.y)
+				+ self.y.anti_geometric(rhs.x)
+				+ self.z.anti_geometric(rhs.xyz),
+			w: self.w.anti_geometric(rhs.w)
+				+ self.x.anti_geometric(rhs.yzw)
+				+ self.y.anti_geometric(rhs.zxw)
+				+ self.z.anti_geometric(rhs.xyw),
+			wx: self.w.anti_geometric(rhs.wx)
+				+ self.x.anti_geometric(rhs.xyzw)
+				+ self.y.anti_geometric(rhs.wz)
+				+ self.z.anti_geometric(rhs.wy),
+			wy: self.w.anti_geometric(rhs.wy)
+				+ self.x.anti_geometric(rhs.wz)
+				+ self.y.anti_geometric(rhs.xyzw)
+				+ self.z.anti_geometric(rhs.wx),
+			wz: self.w.anti_geometric(rhs.wz)
+				+ self.x.anti_geometric(rhs.wy)
+				+ self.y.anti_geometric(rhs.wx)
+				+ self.z.anti_geometric(rhs.xyzw),
+			yz: self.w.anti_geometric(rhs.yz)
+				+ self.x.anti_geometric(rhs.s)
+				+ self.y.anti_geometric(rhs.xy)
+				+ self.z.anti_geometric(rhs.zx),
+			zx: self.w.anti_geometric(rhs.zx)
+				+ self.x.anti_geometric(rhs.xy)
+				+ self.y.anti_geometric(rhs.s)
+				+ self.z.anti_geometric(rhs.yz),
+			xy: self.w.anti_geometric(rhs.xy)
+				+ self.x.anti_geometric(rhs.zx)
+				+ self.y.anti_geometric(rhs.yz)
+				+ self.z.anti_geometric(rhs.s),
+			yzw: self.w.anti_geometric(rhs.yzw)
+				+ self.x.anti_geometric(rhs.w)
+				+ self.y.anti_geometric(rhs.xyw)
+				+ self.z.anti_geometric(rhs.zxw),
+			zxw: self.w.anti_geometric(rhs.zxw)
+				+ self.x.anti_geometric(rhs.xyw)
+				+ self.y.anti_geometric(rhs.w)
+				+ self.z.anti_geometric(rhs.yzw),
+			xyw: self.w.anti_geometric(rhs.xyw)
+				+ self.x.anti_geometric(rhs.zxw)
+				+ self.y.anti_geometric(rhs.yzw)
+				+ self.z.anti_geometric(rhs.w),
+			xyz: self.w.anti_geometric(rhs.xyz)
+				+ self.x.anti_geometric(rhs.x)
+				+ self.y.anti_geometric(rhs.y)
+				+ self.z.anti_geometric(rhs.z),
+			xyzw: self.w.anti_geometric(rhs.xyzw)
+				+ self.x.anti_geometric(rhs.wx)
+				+ self.y.anti_geometric(rhs.wy)
+				+ self.z.anti_geometric(rhs.wz),
+		}
+	}
+}
+
+// Rotor3.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.w.0 * rhs.xyz.0) + W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : WX(self.w.0 * rhs.yz.0) + WX(self.x.0 * rhs.s.0),
+		//     wy  : WY(self.w.0 * rhs.zx.0) + WY(self.y.0 * rhs.s.0),
+		//     wz  : WZ(self.w.0 * rhs.xy.0) + WZ(self.z.0 * rhs.s.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.w.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.w.dot(rhs.xyz) + self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			wx: self.w.dot(rhs.yz) + self.x.dot(rhs.s),
+			wy: self.w.dot(rhs.zx) + self.y.dot(rhs.s),
+			wz: self.w.dot(rhs.xy) + self.z.dot(rhs.s),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.dot(rhs.x),
+			zxw: self.w.dot(rhs.y),
+			xyw: self.w.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: self.w.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor3.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : WX(self.x.0 * rhs.s.0),
+		//     wy  : WY(self.y.0 * rhs.s.0),
+		//     wz  : WZ(self.z.0 * rhs.s.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.y.0 * rhs.z.0) - YZW(self.z.0 * rhs.y.0),
+		//     zxw : -ZXW(self.x.0 * rhs.z.0) + ZXW(self.z.0 * rhs.x.0),
+		//     xyw : XYW(self.x.0 * rhs.y.0) - XYW(self.y.0 * rhs.x.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.w.0 * rhs.s.0) - XYZW(self.x.0 * rhs.yz.0) - XYZW(self.y.0 * rhs.zx.0) - XYZW(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: self.x.wedge(rhs.s),
+			wy: self.y.wedge(rhs.s),
+			wz: self.z.wedge(rhs.s),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			zxw: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			xyw: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			xyz: Default::default(),
+			xyzw: self.w.wedge(rhs.s) + self.x.wedge(rhs.yz) + self.y.wedge(rhs.zx) + self.z.wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor3.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.w.0 * rhs.s.0) - S(self.x.0 * rhs.yz.0) - S(self.y.0 * rhs.zx.0) - S(self.z.0 * rhs.xy.0),
+		//     x   : X(self.w.0 * rhs.x.0) - X(self.x.0 * rhs.xyz.0),
+		//     y   : Y(self.w.0 * rhs.y.0) - Y(self.y.0 * rhs.xyz.0),
+		//     z   : Z(self.w.0 * rhs.z.0) - Z(self.z.0 * rhs.xyz.0),
+		//     w   : W(self.w.0 * rhs.w.0) - W(self.x.0 * rhs.yzw.0) - W(self.y.0 * rhs.zxw.0) - W(self.z.0 * rhs.xyw.0),
+		//     wx  : WX(self.w.0 * rhs.wx.0) + WX(self.x.0 * rhs.xyzw.0),
+		//     wy  : WY(self.w.0 * rhs.wy.0) + WY(self.y.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.w.0 * rhs.wz.0) + WZ(self.z.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.w.0 * rhs.yz.0),
+		//     zx  : ZX(self.w.0 * rhs.zx.0),
+		//     xy  : XY(self.w.0 * rhs.xy.0),
+		//     yzw : YZW(self.w.0 * rhs.yzw.0),
+		//     zxw : ZXW(self.w.0 * rhs.zxw.0),
+		//     xyw : XYW(self.w.0 * rhs.xyw.0),
+		//     xyz : XYZ(self.w.0 * rhs.xyz.0),
+		//     xyzw: XYZW(self.w.0 * rhs.xyzw.0),
+		// }
+		Multivector {
+			s: self.w.anti_wedge(rhs.s)
+				+ self.x.anti_wedge(rhs.yz)
+				+ self.y.anti_wedge(rhs.zx)
+				+ self.z.anti_wedge(rhs.xy),
+			x: self.w.anti_wedge(rhs.x) + self.x.anti_wedge(rhs.xyz),
+			y: self.w.anti_wedge(rhs.y) + self.y.anti_wedge(rhs.xyz),
+			z: self.w.anti_wedge(rhs.z) + self.z.anti_wedge(rhs.xyz),
+			w: self.w.anti_wedge(rhs.w)
+				+ self.x.anti_wedge(rhs.yzw)
+				+ self.y.anti_wedge(rhs.zxw)
+				+ self.z.anti_wedge(rhs.xyw),
+			wx: self.w.anti_wedge(rhs.wx) + self.x.anti_wedge(rhs.xyzw),
+			wy: self.w.anti_wedge(rhs.wy) + self.y.anti_wedge(rhs.xyzw),
+			wz: self.w.anti_wedge(rhs.wz) + self.z.anti_wedge(rhs.xyzw),
+			yz: self.w.anti_wedge(rhs.yz),
+			zx: self.w.anti_wedge(rhs.zx),
+			xy: self.w.anti_wedge(rhs.xy),
+			yzw: self.w.anti_wedge(rhs.yzw),
+			zxw: self.w.anti_wedge(rhs.zxw),
+			xyw: self.w.anti_wedge(rhs.xyw),
+			xyz: self.w.anti_wedge(rhs.xyz),
+			xyzw: self.w.anti_wedge(rhs.xyzw),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Multivector> for Rotor3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Multivector) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.w.0 * rhs.xyz.0) + W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : WX(self.w.0 * rhs.yz.0) + WX(self.x.0 * rhs.s.0),
+		//     wy  : WY(self.w.0 * rhs.zx.0) + WY(self.y.0 * rhs.s.0),
+		//     wz  : WZ(self.w.0 * rhs.xy.0) + WZ(self.z.0 * rhs.s.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.w.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.w.right_contraction(rhs.xyz)
+				+ self.x.right_contraction(rhs.x)
+				+ self.y.right_contraction(rhs.y)
+				+ self.z.right_contraction(rhs.z),
+			wx: self.w.right_contraction(rhs.yz) + self.x.right_contraction(rhs.s),
+			wy: self.w.right_contraction(rhs.zx) + self.y.right_contraction(rhs.s),
+			wz: self.w.right_contraction(rhs.xy) + self.z.right_contraction(rhs.s),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.right_contraction(rhs.x),
+			zxw: self.w.right_contraction(rhs.y),
+			xyw: self.w.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: self.w.right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Multivector> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Multivector) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.w.0 * rhs.xyz.0) + W(self.x.0 * rhs.x.0) + W(self.y.0 * rhs.y.0) + W(self.z.0 * rhs.z.0),
+		//     wx  : -WX(self.y.0 * rhs.xy.0) + WX(self.z.0 * rhs.zx.0),
+		//     wy  : WY(self.x.0 * rhs.xy.0) - WY(self.z.0 * rhs.yz.0),
+		//     wz  : -WZ(self.x.0 * rhs.zx.0) + WZ(self.y.0 * rhs.yz.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0) + YZW(self.x.0 * rhs.xyz.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0) + ZXW(self.y.0 * rhs.xyz.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0) + XYW(self.z.0 * rhs.xyz.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.w.commutator(rhs.xyz)
+				+ self.x.commutator(rhs.x)
+				+ self.y.commutator(rhs.y)
+				+ self.z.commutator(rhs.z),
+			wx: self.y.commutator(rhs.xy) + self.z.commutator(rhs.zx),
+			wy: self.x.commutator(rhs.xy) + self.z.commutator(rhs.yz),
+			wz: self.x.commutator(rhs.zx) + self.y.commutator(rhs.yz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.commutator(rhs.x) + self.x.commutator(rhs.xyz),
+			zxw: self.w.commutator(rhs.y) + self.y.commutator(rhs.xyz),
+			xyw: self.w.commutator(rhs.z) + self.z.commutator(rhs.xyz),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.w.0 * rhs.s.0),
+		//     x   : X(self.w.0 * rhs.x.0),
+		//     y   : Y(self.w.0 * rhs.y.0),
+		//     z   : Z(self.w.0 * rhs.z.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : WX(self.w.0 * rhs.wx.0) + WX(self.x.0 * rhs.xyzw.0),
+		//     wy  : WY(self.w.0 * rhs.wy.0) + WY(self.y.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.w.0 * rhs.wz.0) + WZ(self.z.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.w.0 * rhs.yz.0) + YZ(self.x.0 * rhs.s.0),
+		//     zx  : ZX(self.w.0 * rhs.zx.0) + ZX(self.y.0 * rhs.s.0),
+		//     xy  : XY(self.w.0 * rhs.xy.0) + XY(self.z.0 * rhs.s.0),
+		//     yzw : YZW(self.w.0 * rhs.yzw.0) + YZW(self.x.0 * rhs.w.0) + YZW(self.y.0 * rhs.xyw.0) - YZW(self.z.0 * rhs.zxw.0),
+		//     zxw : ZXW(self.w.0 * rhs.zxw.0) - ZXW(self.x.0 * rhs.xyw.0) + ZXW(self.y.0 * rhs.w.0) + ZXW(self.z.0 * rhs.yzw.0),
+		//     xyw : XYW(self.w.0 * rhs.xyw.0) + XYW(self.x.0 * rhs.zxw.0) - XYW(self.y.0 * rhs.yzw.0) + XYW(self.z.0 * rhs.w.0),
+		//     xyz : XYZ(self.w.0 * rhs.xyz.0) + XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: XYZW(self.w.0 * rhs.xyzw.0) - XYZW(self.x.0 * rhs.wx.0) - XYZW(self.y.0 * rhs.wy.0) - XYZW(self.z.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: self.w.anti_dot(rhs.s),
+			x: self.w.anti_dot(rhs.x),
+			y: self.w.anti_dot(rhs.y),
+			z: self.w.anti_dot(rhs.z),
+			w: self.w.anti_dot(rhs.w),
+			wx: self.w.anti_dot(rhs.wx) + self.x.anti_dot(rhs.xyzw),
+			wy: self.w.anti_dot(rhs.wy) + self.y.anti_dot(rhs.xyzw),
+			wz: self.w.anti_dot(rhs.wz) + self.z.anti_dot(rhs.xyzw),
+			yz: self.w.anti_dot(rhs.yz) + self.x.anti_dot(rhs.s),
+			zx: self.w.anti_dot(rhs.zx) + self.y.anti_dot(rhs.s),
+			xy: self.w.anti_dot(rhs.xy) + self.z.anti_dot(rhs.s),
+			yzw: self.w.anti_dot(rhs.yzw)
+				+ self.x.anti_dot(rhs.w)
+				+ self.y.anti_dot(rhs.xyw)
+				+ self.z.anti_dot(rhs.zxw),
+			zxw: self.w.anti_dot(rhs.zxw)
+				+ self.x.anti_dot(rhs.xyw)
+				+ self.y.anti_dot(rhs.w)
+				+ self.z.anti_dot(rhs.yzw),
+			xyw: self.w.anti_dot(rhs.xyw)
+				+ self.x.anti_dot(rhs.zxw)
+				+ self.y.anti_dot(rhs.yzw)
+				+ self.z.anti_dot(rhs.w),
+			xyz: self.w.anti_dot(rhs.xyz) + self.x.anti_dot(rhs.x) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z),
+			xyzw: self.w.anti_dot(rhs.xyzw)
+				+ self.x.anti_dot(rhs.wx)
+				+ self.y.anti_dot(rhs.wy)
+				+ self.z.anti_dot(rhs.wz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Rotor3.sandwich(Vec3)  (zero)
+
+// Rotor3.anti_sandwich(Vec3) -> Vec3
+impl AntiSandwich<Vec3> for Rotor3 {
+	fn anti_sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: self.w.anti_geometric(self.w).anti_geometric(rhs.x)
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.z) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.y) * 2.0
+				- self.x.anti_geometric(self.x).anti_geometric(rhs.x)
+				- self.x.anti_geometric(self.y).anti_geometric(rhs.y) * 2.0
+				- self.x.anti_geometric(self.z).anti_geometric(rhs.z) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.x)
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.x),
+			y: self.w.anti_geometric(self.w).anti_geometric(rhs.y)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.z) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.x) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.y)
+				+ self.x.anti_geometric(self.y).anti_geometric(rhs.x) * 2.0
+				- self.y.anti_geometric(self.y).anti_geometric(rhs.y)
+				- self.y.anti_geometric(self.z).anti_geometric(rhs.z) * 2.0
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.y),
+			z: self.w.anti_geometric(self.w).anti_geometric(rhs.z)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.y) * 2.0
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.x) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.z)
+				+ self.x.anti_geometric(self.z).anti_geometric(rhs.x) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.z)
+				+ self.y.anti_geometric(self.z).anti_geometric(rhs.y) * 2.0
+				- self.z.anti_geometric(self.z).anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Omitted: Rotor3.sandwich(Vec4)  (zero)
+
+// Rotor3.anti_sandwich(Vec4) -> Vec4
+impl AntiSandwich<Vec4> for Rotor3 {
+	fn anti_sandwich(self, rhs: Vec4) -> Vec4 {
+		Vec4 {
+			x: self.w.anti_geometric(self.w).anti_geometric(rhs.x)
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.z) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.y) * 2.0
+				- self.x.anti_geometric(self.x).anti_geometric(rhs.x)
+				- self.x.anti_geometric(self.y).anti_geometric(rhs.y) * 2.0
+				- self.x.anti_geometric(self.z).anti_geometric(rhs.z) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.x)
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.x),
+			y: self.w.anti_geometric(self.w).anti_geometric(rhs.y)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.z) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.x) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.y)
+				+ self.x.anti_geometric(self.y).anti_geometric(rhs.x) * 2.0
+				- self.y.anti_geometric(self.y).anti_geometric(rhs.y)
+				- self.y.anti_geometric(self.z).anti_geometric(rhs.z) * 2.0
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.y),
+			z: self.w.anti_geometric(self.w).anti_geometric(rhs.z)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.y) * 2.0
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.x) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.z)
+				+ self.x.anti_geometric(self.z).anti_geometric(rhs.x) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.z)
+				+ self.y.anti_geometric(self.z).anti_geometric(rhs.y) * 2.0
+				- self.z.anti_geometric(self.z).anti_geometric(rhs.z),
+			w: self.w.anti_geometric(self.w).anti_geometric(rhs.w)
+				- self.x.anti_geometric(self.x).anti_geometric(rhs.w)
+				- self.y.anti_geometric(self.y).anti_geometric(rhs.w)
+				- self.z.anti_geometric(self.z).anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Omitted: Rotor3.sandwich(Point3)  (zero)
+// Omitted: Rotor3.anti_sandwich(Point3)  (not a Point3)
+// Omitted: Rotor3.sandwich(Moment3)  (zero)
+
+// Rotor3.anti_sandwich(Moment3) -> Moment3
+impl AntiSandwich<Moment3> for Rotor3 {
+	fn anti_sandwich(self, rhs: Moment3) -> Moment3 {
+		Moment3 {
+			mx: self.w.anti_geometric(self.w).anti_geometric(rhs.mx)
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.mz) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.my) * 2.0
+				- self.x.anti_geometric(self.x).anti_geometric(rhs.mx)
+				- self.x.anti_geometric(self.y).anti_geometric(rhs.my) * 2.0
+				- self.x.anti_geometric(self.z).anti_geometric(rhs.mz) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.mx)
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.mx),
+			my: self.w.anti_geometric(self.w).anti_geometric(rhs.my)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.mz) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.mx) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.my)
+				+ self.x.anti_geometric(self.y).anti_geometric(rhs.mx) * 2.0
+				- self.y.anti_geometric(self.y).anti_geometric(rhs.my)
+				- self.y.anti_geometric(self.z).anti_geometric(rhs.mz) * 2.0
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.my),
+			mz: self.w.anti_geometric(self.w).anti_geometric(rhs.mz)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.my) * 2.0
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.mx) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.mz)
+				+ self.x.anti_geometric(self.z).anti_geometric(rhs.mx) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.mz)
+				+ self.y.anti_geometric(self.z).anti_geometric(rhs.my) * 2.0
+				- self.z.anti_geometric(self.z).anti_geometric(rhs.mz),
+		}
+	}
+}
+
+// Omitted: Rotor3.sandwich(Line3)  (zero)
+
+// Rotor3.anti_sandwich(Line3) -> Line3
+impl AntiSandwich<Line3> for Rotor3 {
+	fn anti_sandwich(self, rhs: Line3) -> Line3 {
+		Line3 {
+			vx: self.w.anti_geometric(self.w).anti_geometric(rhs.vx)
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.vz) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.vy) * 2.0
+				- self.x.anti_geometric(self.x).anti_geometric(rhs.vx)
+				- self.x.anti_geometric(self.y).anti_geometric(rhs.vy) * 2.0
+				- self.x.anti_geometric(self.z).anti_geometric(rhs.vz) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.vx)
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.vx),
+			vy: self.w.anti_geometric(self.w).anti_geometric(rhs.vy)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.vz) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.vx) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.vy)
+				+ self.x.anti_geometric(self.y).anti_geometric(rhs.vx) * 2.0
+				- self.y.anti_geometric(self.y).anti_geometric(rhs.vy)
+				- self.y.anti_geometric(self.z).anti_geometric(rhs.vz) * 2.0
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.vy),
+			vz: self.w.anti_geometric(self.w).anti_geometric(rhs.vz)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.vy) * 2.0
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.vx) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.vz)
+				+ self.x.anti_geometric(self.z).anti_geometric(rhs.vx) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.vz)
+				+ self.y.anti_geometric(self.z).anti_geometric(rhs.vy) * 2.0
+				- self.z.anti_geometric(self.z).anti_geometric(rhs.vz),
+			mx: self.w.anti_geometric(self.w).anti_geometric(rhs.mx)
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.mz) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.my) * 2.0
+				- self.x.anti_geometric(self.x).anti_geometric(rhs.mx)
+				- self.x.anti_geometric(self.y).anti_geometric(rhs.my) * 2.0
+				- self.x.anti_geometric(self.z).anti_geometric(rhs.mz) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.mx)
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.mx),
+			my: self.w.anti_geometric(self.w).anti_geometric(rhs.my)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.mz) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.mx) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.my)
+				+ self.x.anti_geometric(self.y).anti_geometric(rhs.mx) * 2.0
+				- self.y.anti_geometric(self.y).anti_geometric(rhs.my)
+				- self.y.anti_geometric(self.z).anti_geometric(rhs.mz) * 2.0
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.my),
+			mz: self.w.anti_geometric(self.w).anti_geometric(rhs.mz)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.my) * 2.0
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.mx) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.mz)
+				+ self.x.anti_geometric(self.z).anti_geometric(rhs.mx) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.mz)
+				+ self.y.anti_geometric(self.z).anti_geometric(rhs.my) * 2.0
+				- self.z.anti_geometric(self.z).anti_geometric(rhs.mz),
 		}
 	}
 }
 
-// Omitted: Rotor3 anti_wedge Motor3 = self.w & rhs.rw + self.w & rhs.rx + self.w & rhs.ry + self.w & rhs.rz + self.w & rhs.uw + self.w & rhs.ux + self.w & rhs.uy + self.w & rhs.uz + self.x & rhs.rw + self.x & rhs.ux + self.y & rhs.rw + self.y & rhs.uy + self.z & rhs.rw + self.z & rhs.uz  (unnamed type)
+// Omitted: Rotor3.sandwich(Plane)  (zero)
+
+// Rotor3.anti_sandwich(Plane) -> Plane
+impl AntiSandwich<Plane> for Rotor3 {
+	fn anti_sandwich(self, rhs: Plane) -> Plane {
+		Plane {
+			nx: self.w.anti_geometric(self.w).anti_geometric(rhs.nx)
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.nz) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.ny) * 2.0
+				- self.x.anti_geometric(self.x).anti_geometric(rhs.nx)
+				- self.x.anti_geometric(self.y).anti_geometric(rhs.ny) * 2.0
+				- self.x.anti_geometric(self.z).anti_geometric(rhs.nz) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.nx)
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.nx),
+			ny: self.w.anti_geometric(self.w).anti_geometric(rhs.ny)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.nz) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.nx) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.ny)
+				+ self.x.anti_geometric(self.y).anti_geometric(rhs.nx) * 2.0
+				- self.y.anti_geometric(self.y).anti_geometric(rhs.ny)
+				- self.y.anti_geometric(self.z).anti_geometric(rhs.nz) * 2.0
+				+ self.z.anti_geometric(self.z).anti_geometric(rhs.ny),
+			nz: self.w.anti_geometric(self.w).anti_geometric(rhs.nz)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.ny) * 2.0
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.nx) * 2.0
+				+ self.x.anti_geometric(self.x).anti_geometric(rhs.nz)
+				+ self.x.anti_geometric(self.z).anti_geometric(rhs.nx) * 2.0
+				+ self.y.anti_geometric(self.y).anti_geometric(rhs.nz)
+				+ self.y.anti_geometric(self.z).anti_geometric(rhs.ny) * 2.0
+				- self.z.anti_geometric(self.z).anti_geometric(rhs.nz),
+			d: self.w.anti_geometric(self.w).anti_geometric(rhs.d)
+				- self.x.anti_geometric(self.x).anti_geometric(rhs.d)
+				- self.y.anti_geometric(self.y).anti_geometric(rhs.d)
+				- self.z.anti_geometric(self.z).anti_geometric(rhs.d),
+		}
+	}
+}
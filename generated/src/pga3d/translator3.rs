@@ -0,0 +1,3521 @@
+//! # Translator3
+//!
+//! ## Operations
+//! ```text
+//! Translator3.geometric(Translator3) -> Motor3
+//! Translator3.anti_geometric(Translator3) -> Translator3
+//! Translator3.dot(Translator3) -> Motor3
+//! Translator3.anti_wedge(Translator3) -> Translator3
+//! Translator3.left_contraction(Translator3) -> Motor3
+//! Translator3.right_contraction(Translator3) -> Motor3
+//! Translator3.scalar_product(Translator3) -> S
+//! Translator3.commutator(Translator3) -> Moment3
+//! Translator3.anti_dot(Translator3) -> Translator3
+//! Translator3.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Translator3) -> Multivector
+//! Translator3.anti_geometric(Vec3) -> Vec3
+//! Vec3.anti_geometric(Translator3) -> Vec3
+//! Translator3.dot(Vec3) -> Multivector
+//! Vec3.dot(Translator3) -> Multivector
+//! Translator3.wedge(Vec3) -> XYZ
+//! Vec3.wedge(Translator3) -> XYZ
+//! Translator3.anti_wedge(Vec3) -> Vec3
+//! Vec3.anti_wedge(Translator3) -> Vec3
+//! Vec3.left_contraction(Translator3) -> Multivector
+//! Translator3.right_contraction(Vec3) -> Multivector
+//! Translator3.commutator(Vec3) -> Multivector
+//! Vec3.commutator(Translator3) -> Multivector
+//! Translator3.anti_dot(Vec3) -> Vec3
+//! Vec3.anti_dot(Translator3) -> Vec3
+//! Translator3.geometric(Vec4) -> Multivector
+//! Vec4.geometric(Translator3) -> Multivector
+//! Translator3.anti_geometric(Vec4) -> Vec4
+//! Vec4.anti_geometric(Translator3) -> Vec4
+//! Translator3.dot(Vec4) -> Multivector
+//! Vec4.dot(Translator3) -> Multivector
+//! Translator3.wedge(Vec4) -> Plane
+//! Vec4.wedge(Translator3) -> Plane
+//! Translator3.anti_wedge(Vec4) -> Vec4
+//! Vec4.anti_wedge(Translator3) -> Vec4
+//! Vec4.left_contraction(Translator3) -> Multivector
+//! Translator3.right_contraction(Vec4) -> Multivector
+//! Translator3.commutator(Vec4) -> Multivector
+//! Vec4.commutator(Translator3) -> Multivector
+//! Translator3.anti_dot(Vec4) -> Vec4
+//! Vec4.anti_dot(Translator3) -> Vec4
+//! Translator3.geometric(Point3) -> Multivector
+//! Point3.geometric(Translator3) -> Multivector
+//! Translator3.anti_geometric(Point3) -> Vec4
+//! Point3.anti_geometric(Translator3) -> Vec4
+//! Translator3.dot(Point3) -> Multivector
+//! Point3.dot(Translator3) -> Multivector
+//! Translator3.wedge(Point3) -> Plane
+//! Point3.wedge(Translator3) -> Plane
+//! Translator3.anti_wedge(Point3) -> Vec4
+//! Point3.anti_wedge(Translator3) -> Vec4
+//! Point3.left_contraction(Translator3) -> Multivector
+//! Translator3.right_contraction(Point3) -> Multivector
+//! Translator3.commutator(Point3) -> Multivector
+//! Point3.commutator(Translator3) -> Multivector
+//! Translator3.anti_dot(Point3) -> Vec4
+//! Point3.anti_dot(Translator3) -> Vec4
+//! Translator3.geometric(Moment3) -> Motor3
+//! Moment3.geometric(Translator3) -> Motor3
+//! Translator3.anti_geometric(Moment3) -> Moment3
+//! Moment3.anti_geometric(Translator3) -> Moment3
+//! Translator3.dot(Moment3) -> Motor3
+//! Moment3.dot(Translator3) -> Motor3
+//! Translator3.anti_wedge(Moment3) -> Moment3
+//! Moment3.anti_wedge(Translator3) -> Moment3
+//! Translator3.left_contraction(Moment3) -> S
+//! Moment3.left_contraction(Translator3) -> Motor3
+//! Translator3.right_contraction(Moment3) -> Motor3
+//! Moment3.right_contraction(Translator3) -> S
+//! Translator3.scalar_product(Moment3) -> S
+//! Moment3.scalar_product(Translator3) -> S
+//! Translator3.commutator(Moment3) -> Moment3
+//! Moment3.commutator(Translator3) -> Moment3
+//! Translator3.anti_dot(Moment3) -> Moment3
+//! Moment3.anti_dot(Translator3) -> Moment3
+//! Translator3.geometric(Line3) -> Motor3
+//! Line3.geometric(Translator3) -> Motor3
+//! Translator3.anti_geometric(Line3) -> Motor3
+//! Line3.anti_geometric(Translator3) -> Motor3
+//! Translator3.dot(Line3) -> Motor3
+//! Line3.dot(Translator3) -> Motor3
+//! Translator3.wedge(Line3) -> XYZW
+//! Line3.wedge(Translator3) -> XYZW
+//! Translator3.anti_wedge(Line3) -> Motor3
+//! Line3.anti_wedge(Translator3) -> Motor3
+//! Translator3.left_contraction(Line3) -> S
+//! Line3.left_contraction(Translator3) -> Motor3
+//! Translator3.right_contraction(Line3) -> Motor3
+//! Line3.right_contraction(Translator3) -> S
+//! Translator3.scalar_product(Line3) -> S
+//! Line3.scalar_product(Translator3) -> S
+//! Translator3.commutator(Line3) -> Line3
+//! Line3.commutator(Translator3) -> Line3
+//! Translator3.anti_dot(Line3) -> Line3
+//! Line3.anti_dot(Translator3) -> Line3
+//! Translator3.geometric(Plane) -> Multivector
+//! Plane.geometric(Translator3) -> Multivector
+//! Translator3.anti_geometric(Plane) -> Multivector
+//! Plane.anti_geometric(Translator3) -> Multivector
+//! Translator3.dot(Plane) -> Vec4
+//! Plane.dot(Translator3) -> Vec4
+//! Translator3.anti_wedge(Plane) -> Multivector
+//! Plane.anti_wedge(Translator3) -> Multivector
+//! Translator3.left_contraction(Plane) -> Vec4
+//! Plane.left_contraction(Translator3) -> W
+//! Translator3.right_contraction(Plane) -> W
+//! Plane.right_contraction(Translator3) -> Vec4
+//! Translator3.commutator(Plane) -> Multivector
+//! Plane.commutator(Translator3) -> Multivector
+//! Translator3.anti_dot(Plane) -> Plane
+//! Plane.anti_dot(Translator3) -> Plane
+//! Translator3.geometric(Rotor3) -> Rotor3
+//! Rotor3.geometric(Translator3) -> Rotor3
+//! Translator3.anti_geometric(Rotor3) -> Motor3
+//! Rotor3.anti_geometric(Translator3) -> Motor3
+//! Translator3.dot(Rotor3) -> Line3
+//! Rotor3.dot(Translator3) -> Line3
+//! Translator3.wedge(Rotor3) -> XYZW
+//! Rotor3.wedge(Translator3) -> XYZW
+//! Translator3.anti_wedge(Rotor3) -> Motor3
+//! Rotor3.anti_wedge(Translator3) -> Motor3
+//! Translator3.left_contraction(Rotor3) -> Line3
+//! Rotor3.right_contraction(Translator3) -> Line3
+//! Translator3.commutator(Rotor3) -> Line3
+//! Rotor3.commutator(Translator3) -> Line3
+//! Translator3.anti_dot(Rotor3) -> Motor3
+//! Rotor3.anti_dot(Translator3) -> Motor3
+//! Translator3.geometric(Motor3) -> Motor3
+//! Motor3.geometric(Translator3) -> Motor3
+//! Translator3.anti_geometric(Motor3) -> Motor3
+//! Motor3.anti_geometric(Translator3) -> Motor3
+//! Translator3.dot(Motor3) -> Motor3
+//! Motor3.dot(Translator3) -> Motor3
+//! Translator3.wedge(Motor3) -> Translator3
+//! Motor3.wedge(Translator3) -> Translator3
+//! Translator3.anti_wedge(Motor3) -> Motor3
+//! Motor3.anti_wedge(Translator3) -> Motor3
+//! Translator3.left_contraction(Motor3) -> Motor3
+//! Motor3.left_contraction(Translator3) -> Motor3
+//! Translator3.right_contraction(Motor3) -> Motor3
+//! Motor3.right_contraction(Translator3) -> Motor3
+//! Translator3.scalar_product(Motor3) -> S
+//! Motor3.scalar_product(Translator3) -> S
+//! Translator3.commutator(Motor3) -> Line3
+//! Motor3.commutator(Translator3) -> Line3
+//! Translator3.anti_dot(Motor3) -> Motor3
+//! Motor3.anti_dot(Translator3) -> Motor3
+//! Translator3.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Translator3) -> Multivector
+//! Translator3.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Translator3) -> Multivector
+//! Translator3.dot(Multivector) -> Multivector
+//! Multivector.dot(Translator3) -> Multivector
+//! Translator3.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Translator3) -> Multivector
+//! Translator3.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Translator3) -> Multivector
+//! Translator3.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Translator3) -> Multivector
+//! Translator3.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Translator3) -> Multivector
+//! Translator3.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Translator3) -> S
+//! Translator3.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Translator3) -> Multivector
+//! Translator3.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Translator3) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Translator3 {
+	pub x: YZ,
+	pub y: ZX,
+	pub z: XY,
+	pub w: XYZW,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Translator3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Translator3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Translator3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.z.abs_diff_eq(&other.z, epsilon)
+			&& self.w.abs_diff_eq(&other.w, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Translator3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.z.relative_eq(&other.z, epsilon, max_relative)
+			&& self.w.relative_eq(&other.w, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Translator3 {
+	type Output = Motor3;
+	fn rcompl(self) -> Self::Output {
+		Motor3 {
+			rx: self.x.rcompl(),
+			ry: self.y.rcompl(),
+			rz: self.z.rcompl(),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.w.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Translator3 {
+	type Output = Motor3;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Translator3 {
+	type Output = Motor3;
+	fn lcompl(self) -> Self::Output {
+		Motor3 {
+			rx: self.x.lcompl(),
+			ry: self.y.lcompl(),
+			rz: self.z.lcompl(),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.w.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Translator3 {
+	fn rev(self) -> Self {
+		Translator3 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			w: self.w,
+		}
+	}
+}
+
+impl AntiReverse for Translator3 {
+	fn arev(self) -> Self {
+		Translator3 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			w: self.w,
+		}
+	}
+}
+
+impl Involute for Translator3 {
+	fn involute(self) -> Self {
+		Translator3 {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+			w: self.w,
+		}
+	}
+}
+
+impl Conjugate for Translator3 {
+	fn conjugate(self) -> Self {
+		Translator3 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			w: self.w,
+		}
+	}
+}
+
+impl Dual for Translator3 {
+	type Output = Motor3;
+	fn dual(self) -> Self::Output {
+		Motor3 {
+			rx: self.x.dual(),
+			ry: self.y.dual(),
+			rz: self.z.dual(),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.w.dual(),
+		}
+	}
+}
+
+impl Bulk for Translator3 {
+	type Output = Moment3;
+	fn bulk(self) -> Self::Output {
+		Moment3 {
+			mx: self.x,
+			my: self.y,
+			mz: self.z,
+		}
+	}
+}
+
+impl Weight for Translator3 {
+	type Output = XYZW;
+	fn weight(self) -> Self::Output {
+		self.w
+	}
+}
+
+impl BulkDual for Translator3 {
+	type Output = Line3;
+	fn bulk_dual(self) -> Self::Output {
+		Line3 {
+			vx: self.x.bulk_dual(),
+			vy: self.y.bulk_dual(),
+			vz: self.z.bulk_dual(),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl WeightDual for Translator3 {
+	type Output = S;
+	fn weight_dual(self) -> Self::Output {
+		self.w.weight_dual()
+	}
+}
+
+// Omitted: Translator3.square()  (not a scalar)
+
+impl Norm for Translator3 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0 + self.w.0 * self.w.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.w.0 * self.w.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.w.0;
+		Translator3 {
+			x: self.x * scale,
+			y: self.y * scale,
+			z: self.z * scale,
+			w: self.w * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Translator3 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.x.0, self.y.0, self.z.0, self.w.0]
+	}
+}
+
+impl From<[f64; 4]> for Translator3 {
+	fn from(a: [f64; 4]) -> Self {
+		Translator3 {
+			x: YZ(a[0]),
+			y: ZX(a[1]),
+			z: XY(a[2]),
+			w: XYZW(a[3]),
+		}
+	}
+}
+
+impl From<Translator3> for [f64; 4] {
+	fn from(v: Translator3) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Translator3> for Multivector {
+	fn from(v: Translator3) -> Self {
+		Multivector {
+			yz: v.x,
+			zx: v.y,
+			xy: v.z,
+			xyzw: v.w,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Translator3 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Translator3` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.x.0 == 0.0
+			&& mv.y.0 == 0.0
+			&& mv.z.0 == 0.0
+			&& mv.w.0 == 0.0
+			&& mv.wx.0 == 0.0
+			&& mv.wy.0 == 0.0
+			&& mv.wz.0 == 0.0
+			&& mv.yzw.0 == 0.0
+			&& mv.zxw.0 == 0.0
+			&& mv.xyw.0 == 0.0
+			&& mv.xyz.0 == 0.0
+		{
+			Ok(Translator3 {
+				x: mv.yz,
+				y: mv.zx,
+				z: mv.xy,
+				w: mv.xyzw,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Translator3 {
+	/// The matrix that transforms `[x, y, z, w]` like `self.anti_sandwich(v)` for a `Vec4` `v`,
+	/// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+	pub fn to_matrix(self) -> [[f64; 4]; 4] {
+		[
+			[self.w.0 * self.w.0, 0.0, 0.0, 2.0 * self.w.0 * self.x.0],
+			[0.0, self.w.0 * self.w.0, 0.0, 2.0 * self.w.0 * self.y.0],
+			[0.0, 0.0, self.w.0 * self.w.0, 2.0 * self.w.0 * self.z.0],
+			[0.0, 0.0, 0.0, self.w.0 * self.w.0],
+		]
+	}
+
+	/// The first 3 rows of [`Self::to_matrix`], i.e. without the last row (which is `[0, …, 0, 1]` when normalized).
+	pub fn to_matrix_3x4(self) -> [[f64; 4]; 3] {
+		let m = self.to_matrix();
+		[m[0], m[1], m[2]]
+	}
+}
+
+impl Translator3 {
+	/// The versor that moves nothing, whose matrix is the identity matrix.
+	pub fn identity() -> Self {
+		Translator3 {
+			x: YZ(0.0),
+			y: ZX(0.0),
+			z: XY(0.0),
+			w: XYZW(1.0),
+		}
+	}
+}
+
+impl Translator3 {
+	/// The normalized versor with this matrix, e.g. from [`Self::to_matrix`].
+	/// Both `v` and `-v` have the same matrix, and this returns the one with a positive `w`.
+	pub fn from_matrix(m: [[f64; 4]; 4]) -> Self {
+		let w_w = (m[0][0] + m[1][1] + m[2][2] + m[3][3]) / 4.0;
+		let w_x = m[0][3] / 2.0;
+		let w_y = m[1][3] / 2.0;
+		let w_z = m[2][3] / 2.0;
+		let pivot = w_w.sqrt();
+		Translator3 {
+			x: YZ(w_x / pivot),
+			y: ZX(w_y / pivot),
+			z: XY(w_z / pivot),
+			w: XYZW(pivot),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator3 OP Vec3:
+
+// Translator3.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Translator3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			y: self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			z: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.x),
+			zxw: self.w.geometric(rhs.y),
+			xyw: self.w.geometric(rhs.z),
+			xyz: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Translator3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator3.anti_geometric(Vec3) -> Vec3
+impl AntiGeometric<Vec3> for Translator3 {
+	type Output = Vec3;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.w.0 * rhs.x.0),
+		//     y: Y(self.w.0 * rhs.y.0),
+		//     z: Z(self.w.0 * rhs.z.0),
+		// }
+		Vec3 {
+			x: self.w.anti_geometric(rhs.x),
+			y: self.w.anti_geometric(rhs.y),
+			z: self.w.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Translator3.dot(Vec3) -> Multivector
+impl Dot<Vec3> for Translator3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.dot(rhs.z) + self.z.dot(rhs.y),
+			y: self.x.dot(rhs.z) + self.z.dot(rhs.x),
+			z: self.x.dot(rhs.y) + self.y.dot(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.dot(rhs.x),
+			zxw: self.w.dot(rhs.y),
+			xyw: self.w.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Translator3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator3.wedge(Vec3) -> XYZ
+impl Wedge<Vec3> for Translator3 {
+	type Output = XYZ;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0)
+		self.x.wedge(rhs.x) + self.y.wedge(rhs.y) + self.z.wedge(rhs.z)
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Translator3 {
+	type Output = XYZ;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator3.anti_wedge(Vec3) -> Vec3
+impl AntiWedge<Vec3> for Translator3 {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.w.0 * rhs.x.0),
+		//     y: Y(self.w.0 * rhs.y.0),
+		//     z: Z(self.w.0 * rhs.z.0),
+		// }
+		Vec3 {
+			x: self.w.anti_wedge(rhs.x),
+			y: self.w.anti_wedge(rhs.y),
+			z: self.w.anti_wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Translator3 {
+	type Output = Vec3;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for Translator3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator3.right_contraction(Vec3) -> Multivector
+impl RightContraction<Vec3> for Translator3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.right_contraction(rhs.z) + self.z.right_contraction(rhs.y),
+			y: self.x.right_contraction(rhs.z) + self.z.right_contraction(rhs.x),
+			z: self.x.right_contraction(rhs.y) + self.y.right_contraction(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.right_contraction(rhs.x),
+			zxw: self.w.right_contraction(rhs.y),
+			xyw: self.w.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Translator3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator3.commutator(Vec3) -> Multivector
+impl Commutator<Vec3> for Translator3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			y: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			z: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.commutator(rhs.x),
+			zxw: self.w.commutator(rhs.y),
+			xyw: self.w.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Translator3.anti_dot(Vec3) -> Vec3
+impl AntiDot<Vec3> for Translator3 {
+	type Output = Vec3;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.w.0 * rhs.x.0),
+		//     y: Y(self.w.0 * rhs.y.0),
+		//     z: Z(self.w.0 * rhs.z.0),
+		// }
+		Vec3 {
+			x: self.w.anti_dot(rhs.x),
+			y: self.w.anti_dot(rhs.y),
+			z: self.w.anti_dot(rhs.z),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator3 OP Vec4:
+
+// Translator3.geometric(Vec4) -> Multivector
+impl Geometric<Vec4> for Translator3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0) + YZW(self.x.0 * rhs.w.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0) + ZXW(self.y.0 * rhs.w.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0) + XYW(self.z.0 * rhs.w.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			y: self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			z: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.x) + self.x.geometric(rhs.w),
+			zxw: self.w.geometric(rhs.y) + self.y.geometric(rhs.w),
+			xyw: self.w.geometric(rhs.z) + self.z.geometric(rhs.w),
+			xyz: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec4> for Translator3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator3.anti_geometric(Vec4) -> Vec4
+impl AntiGeometric<Vec4> for Translator3 {
+	type Output = Vec4;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.w.0 * rhs.x.0) + X(self.x.0 * rhs.w.0),
+		//     y: Y(self.w.0 * rhs.y.0) + Y(self.y.0 * rhs.w.0),
+		//     z: Z(self.w.0 * rhs.z.0) + Z(self.z.0 * rhs.w.0),
+		//     w: W(self.w.0 * rhs.w.0),
+		// }
+		Vec4 {
+			x: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(rhs.w),
+			y: self.w.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.w),
+			z: self.w.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.w),
+			w: self.w.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Translator3.dot(Vec4) -> Multivector
+impl Dot<Vec4> for Translator3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.dot(rhs.z) + self.z.dot(rhs.y),
+			y: self.x.dot(rhs.z) + self.z.dot(rhs.x),
+			z: self.x.dot(rhs.y) + self.y.dot(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.dot(rhs.x),
+			zxw: self.w.dot(rhs.y),
+			xyw: self.w.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec4> for Translator3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator3.wedge(Vec4) -> Plane
+impl Wedge<Vec4> for Translator3 {
+	type Output = Plane;
+	fn wedge(self, rhs: Vec4) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.x.0 * rhs.w.0),
+		//     ny: ZXW(self.y.0 * rhs.w.0),
+		//     nz: XYW(self.z.0 * rhs.w.0),
+		//     d : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		// }
+		Plane {
+			nx: self.x.wedge(rhs.w),
+			ny: self.y.wedge(rhs.w),
+			nz: self.z.wedge(rhs.w),
+			d: self.x.wedge(rhs.x) + self.y.wedge(rhs.y) + self.z.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec4> for Translator3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator3.anti_wedge(Vec4) -> Vec4
+impl AntiWedge<Vec4> for Translator3 {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Vec4) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.w.0 * rhs.x.0),
+		//     y: Y(self.w.0 * rhs.y.0),
+		//     z: Z(self.w.0 * rhs.z.0),
+		//     w: W(self.w.0 * rhs.w.0),
+		// }
+		Vec4 {
+			x: self.w.anti_wedge(rhs.x),
+			y: self.w.anti_wedge(rhs.y),
+			z: self.w.anti_wedge(rhs.z),
+			w: self.w.anti_wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec4> for Translator3 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec4> for Translator3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator3.right_contraction(Vec4) -> Multivector
+impl RightContraction<Vec4> for Translator3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.right_contraction(rhs.z) + self.z.right_contraction(rhs.y),
+			y: self.x.right_contraction(rhs.z) + self.z.right_contraction(rhs.x),
+			z: self.x.right_contraction(rhs.y) + self.y.right_contraction(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.right_contraction(rhs.x),
+			zxw: self.w.right_contraction(rhs.y),
+			xyw: self.w.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Vec4> for Translator3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator3.commutator(Vec4) -> Multivector
+impl Commutator<Vec4> for Translator3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			y: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			z: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.commutator(rhs.x),
+			zxw: self.w.commutator(rhs.y),
+			xyw: self.w.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Translator3.anti_dot(Vec4) -> Vec4
+impl AntiDot<Vec4> for Translator3 {
+	type Output = Vec4;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.w.0 * rhs.x.0),
+		//     y: Y(self.w.0 * rhs.y.0),
+		//     z: Z(self.w.0 * rhs.z.0),
+		//     w: W(self.w.0 * rhs.w.0),
+		// }
+		Vec4 {
+			x: self.w.anti_dot(rhs.x),
+			y: self.w.anti_dot(rhs.y),
+			z: self.w.anti_dot(rhs.z),
+			w: self.w.anti_dot(rhs.w),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator3 OP Point3:
+
+// Translator3.geometric(Point3) -> Multivector
+impl Geometric<Point3> for Translator3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0) + YZW(self.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0) + ZXW(self.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0) + XYW(self.z.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			y: self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			z: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.x) + self.x.geometric(W(1.0)),
+			zxw: self.w.geometric(rhs.y) + self.y.geometric(W(1.0)),
+			xyw: self.w.geometric(rhs.z) + self.z.geometric(W(1.0)),
+			xyz: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Point3> for Translator3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Point3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator3.anti_geometric(Point3) -> Vec4
+impl AntiGeometric<Point3> for Translator3 {
+	type Output = Vec4;
+	fn anti_geometric(self, rhs: Point3) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.w.0 * rhs.x.0) + X(self.x.0),
+		//     y: Y(self.w.0 * rhs.y.0) + Y(self.y.0),
+		//     z: Z(self.w.0 * rhs.z.0) + Z(self.z.0),
+		//     w: W(self.w.0),
+		// }
+		Vec4 {
+			x: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(W(1.0)),
+			y: self.w.anti_geometric(rhs.y) + self.y.anti_geometric(W(1.0)),
+			z: self.w.anti_geometric(rhs.z) + self.z.anti_geometric(W(1.0)),
+			w: self.w.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Translator3.dot(Point3) -> Multivector
+impl Dot<Point3> for Translator3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.dot(rhs.z) + self.z.dot(rhs.y),
+			y: self.x.dot(rhs.z) + self.z.dot(rhs.x),
+			z: self.x.dot(rhs.y) + self.y.dot(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.dot(rhs.x),
+			zxw: self.w.dot(rhs.y),
+			xyw: self.w.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Point3> for Translator3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Point3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator3.wedge(Point3) -> Plane
+impl Wedge<Point3> for Translator3 {
+	type Output = Plane;
+	fn wedge(self, rhs: Point3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.x.0),
+		//     ny: ZXW(self.y.0),
+		//     nz: XYW(self.z.0),
+		//     d : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		// }
+		Plane {
+			nx: self.x.wedge(W(1.0)),
+			ny: self.y.wedge(W(1.0)),
+			nz: self.z.wedge(W(1.0)),
+			d: self.x.wedge(rhs.x) + self.y.wedge(rhs.y) + self.z.wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point3> for Translator3 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Point3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator3.anti_wedge(Point3) -> Vec4
+impl AntiWedge<Point3> for Translator3 {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Point3) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.w.0 * rhs.x.0),
+		//     y: Y(self.w.0 * rhs.y.0),
+		//     z: Z(self.w.0 * rhs.z.0),
+		//     w: W(self.w.0),
+		// }
+		Vec4 {
+			x: self.w.anti_wedge(rhs.x),
+			y: self.w.anti_wedge(rhs.y),
+			z: self.w.anti_wedge(rhs.z),
+			w: self.w.anti_wedge(W(1.0)),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Point3> for Translator3 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Point3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Point3> for Translator3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator3.right_contraction(Point3) -> Multivector
+impl RightContraction<Point3> for Translator3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.right_contraction(rhs.z) + self.z.right_contraction(rhs.y),
+			y: self.x.right_contraction(rhs.z) + self.z.right_contraction(rhs.x),
+			z: self.x.right_contraction(rhs.y) + self.y.right_contraction(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.right_contraction(rhs.x),
+			zxw: self.w.right_contraction(rhs.y),
+			xyw: self.w.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Point3> for Translator3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator3.commutator(Point3) -> Multivector
+impl Commutator<Point3> for Translator3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Point3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			y: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			z: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.commutator(rhs.x),
+			zxw: self.w.commutator(rhs.y),
+			xyw: self.w.commutator(rhs.z),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Translator3.anti_dot(Point3) -> Vec4
+impl AntiDot<Point3> for Translator3 {
+	type Output = Vec4;
+	fn anti_dot(self, rhs: Point3) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.w.0 * rhs.x.0),
+		//     y: Y(self.w.0 * rhs.y.0),
+		//     z: Z(self.w.0 * rhs.z.0),
+		//     w: W(self.w.0),
+		// }
+		Vec4 {
+			x: self.w.anti_dot(rhs.x),
+			y: self.w.anti_dot(rhs.y),
+			z: self.w.anti_dot(rhs.z),
+			w: self.w.anti_dot(W(1.0)),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator3 OP Moment3:
+
+// Translator3.geometric(Moment3) -> Motor3
+impl Geometric<Moment3> for Translator3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.mx.0),
+		//     ry: WY(self.w.0 * rhs.my.0),
+		//     rz: WZ(self.w.0 * rhs.mz.0),
+		//     rw: Default::default(),
+		//     ux: -YZ(self.y.0 * rhs.mz.0) + YZ(self.z.0 * rhs.my.0),
+		//     uy: ZX(self.x.0 * rhs.mz.0) - ZX(self.z.0 * rhs.mx.0),
+		//     uz: -XY(self.x.0 * rhs.my.0) + XY(self.y.0 * rhs.mx.0),
+		//     uw: -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.w.geometric(rhs.mx),
+			ry: self.w.geometric(rhs.my),
+			rz: self.w.geometric(rhs.mz),
+			rw: Default::default(),
+			ux: self.y.geometric(rhs.mz) + self.z.geometric(rhs.my),
+			uy: self.x.geometric(rhs.mz) + self.z.geometric(rhs.mx),
+			uz: self.x.geometric(rhs.my) + self.y.geometric(rhs.mx),
+			uw: self.x.geometric(rhs.mx) + self.y.geometric(rhs.my) + self.z.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Moment3> for Translator3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Moment3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator3.anti_geometric(Moment3) -> Moment3
+impl AntiGeometric<Moment3> for Translator3 {
+	type Output = Moment3;
+	fn anti_geometric(self, rhs: Moment3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.w.0 * rhs.mx.0),
+		//     my: ZX(self.w.0 * rhs.my.0),
+		//     mz: XY(self.w.0 * rhs.mz.0),
+		// }
+		Moment3 {
+			mx: self.w.anti_geometric(rhs.mx),
+			my: self.w.anti_geometric(rhs.my),
+			mz: self.w.anti_geometric(rhs.mz),
+		}
+	}
+}
+
+// Translator3.dot(Moment3) -> Motor3
+impl Dot<Moment3> for Translator3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.mx.0),
+		//     ry: WY(self.w.0 * rhs.my.0),
+		//     rz: WZ(self.w.0 * rhs.mz.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.w.dot(rhs.mx),
+			ry: self.w.dot(rhs.my),
+			rz: self.w.dot(rhs.mz),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.x.dot(rhs.mx) + self.y.dot(rhs.my) + self.z.dot(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Moment3> for Translator3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Moment3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Moment3> for Translator3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Moment3> for Translator3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: Moment3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator3.anti_wedge(Moment3) -> Moment3
+impl AntiWedge<Moment3> for Translator3 {
+	type Output = Moment3;
+	fn anti_wedge(self, rhs: Moment3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.w.0 * rhs.mx.0),
+		//     my: ZX(self.w.0 * rhs.my.0),
+		//     mz: XY(self.w.0 * rhs.mz.0),
+		// }
+		Moment3 {
+			mx: self.w.anti_wedge(rhs.mx),
+			my: self.w.anti_wedge(rhs.my),
+			mz: self.w.anti_wedge(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Moment3> for Translator3 {
+	type Output = Moment3;
+	fn bitand(self, rhs: Moment3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator3.left_contraction(Moment3) -> S
+impl LeftContraction<Moment3> for Translator3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Moment3) -> Self::Output {
+		// -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0)
+		self.x.left_contraction(rhs.mx) + self.y.left_contraction(rhs.my) + self.z.left_contraction(rhs.mz)
+	}
+}
+
+// Translator3.right_contraction(Moment3) -> Motor3
+impl RightContraction<Moment3> for Translator3 {
+	type Output = Motor3;
+	fn right_contraction(self, rhs: Moment3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.mx.0),
+		//     ry: WY(self.w.0 * rhs.my.0),
+		//     rz: WZ(self.w.0 * rhs.mz.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.w.right_contraction(rhs.mx),
+			ry: self.w.right_contraction(rhs.my),
+			rz: self.w.right_contraction(rhs.mz),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.x.right_contraction(rhs.mx) + self.y.right_contraction(rhs.my) + self.z.right_contraction(rhs.mz),
+		}
+	}
+}
+
+// Translator3.scalar_product(Moment3) -> S
+impl ScalarProduct<Moment3> for Translator3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Moment3) -> Self::Output {
+		// -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0)
+		self.x.scalar_product(rhs.mx) + self.y.scalar_product(rhs.my) + self.z.scalar_product(rhs.mz)
+	}
+}
+
+// Translator3.commutator(Moment3) -> Moment3
+impl Commutator<Moment3> for Translator3 {
+	type Output = Moment3;
+	fn commutator(self, rhs: Moment3) -> Self::Output {
+		// Moment3 {
+		//     mx: -YZ(self.y.0 * rhs.mz.0) + YZ(self.z.0 * rhs.my.0),
+		//     my: ZX(self.x.0 * rhs.mz.0) - ZX(self.z.0 * rhs.mx.0),
+		//     mz: -XY(self.x.0 * rhs.my.0) + XY(self.y.0 * rhs.mx.0),
+		// }
+		Moment3 {
+			mx: self.y.commutator(rhs.mz) + self.z.commutator(rhs.my),
+			my: self.x.commutator(rhs.mz) + self.z.commutator(rhs.mx),
+			mz: self.x.commutator(rhs.my) + self.y.commutator(rhs.mx),
+		}
+	}
+}
+
+// Translator3.anti_dot(Moment3) -> Moment3
+impl AntiDot<Moment3> for Translator3 {
+	type Output = Moment3;
+	fn anti_dot(self, rhs: Moment3) -> Self::Output {
+		// Moment3 {
+		//     mx: YZ(self.w.0 * rhs.mx.0),
+		//     my: ZX(self.w.0 * rhs.my.0),
+		//     mz: XY(self.w.0 * rhs.mz.0),
+		// }
+		Moment3 {
+			mx: self.w.anti_dot(rhs.mx),
+			my: self.w.anti_dot(rhs.my),
+			mz: self.w.anti_dot(rhs.mz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator3 OP Line3:
+
+// Translator3.geometric(Line3) -> Motor3
+impl Geometric<Line3> for Translator3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.mx.0) - WX(self.y.0 * rhs.vz.0) + WX(self.z.0 * rhs.vy.0),
+		//     ry: WY(self.w.0 * rhs.my.0) + WY(self.x.0 * rhs.vz.0) - WY(self.z.0 * rhs.vx.0),
+		//     rz: WZ(self.w.0 * rhs.mz.0) - WZ(self.x.0 * rhs.vy.0) + WZ(self.y.0 * rhs.vx.0),
+		//     rw: -XYZW(self.x.0 * rhs.vx.0) - XYZW(self.y.0 * rhs.vy.0) - XYZW(self.z.0 * rhs.vz.0),
+		//     ux: -YZ(self.y.0 * rhs.mz.0) + YZ(self.z.0 * rhs.my.0),
+		//     uy: ZX(self.x.0 * rhs.mz.0) - ZX(self.z.0 * rhs.mx.0),
+		//     uz: -XY(self.x.0 * rhs.my.0) + XY(self.y.0 * rhs.mx.0),
+		//     uw: -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.w.geometric(rhs.mx) + self.y.geometric(rhs.vz) + self.z.geometric(rhs.vy),
+			ry: self.w.geometric(rhs.my) + self.x.geometric(rhs.vz) + self.z.geometric(rhs.vx),
+			rz: self.w.geometric(rhs.mz) + self.x.geometric(rhs.vy) + self.y.geometric(rhs.vx),
+			rw: self.x.geometric(rhs.vx) + self.y.geometric(rhs.vy) + self.z.geometric(rhs.vz),
+			ux: self.y.geometric(rhs.mz) + self.z.geometric(rhs.my),
+			uy: self.x.geometric(rhs.mz) + self.z.geometric(rhs.mx),
+			uz: self.x.geometric(rhs.my) + self.y.geometric(rhs.mx),
+			uw: self.x.geometric(rhs.mx) + self.y.geometric(rhs.my) + self.z.geometric(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::Mul<Line3> for Translator3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Line3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator3.anti_geometric(Line3) -> Motor3
+impl AntiGeometric<Line3> for Translator3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.vx.0),
+		//     ry: WY(self.w.0 * rhs.vy.0),
+		//     rz: WZ(self.w.0 * rhs.vz.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.w.0 * rhs.mx.0) + YZ(self.y.0 * rhs.vz.0) - YZ(self.z.0 * rhs.vy.0),
+		//     uy: ZX(self.w.0 * rhs.my.0) - ZX(self.x.0 * rhs.vz.0) + ZX(self.z.0 * rhs.vx.0),
+		//     uz: XY(self.w.0 * rhs.mz.0) + XY(self.x.0 * rhs.vy.0) - XY(self.y.0 * rhs.vx.0),
+		//     uw: -S(self.x.0 * rhs.vx.0) - S(self.y.0 * rhs.vy.0) - S(self.z.0 * rhs.vz.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_geometric(rhs.vx),
+			ry: self.w.anti_geometric(rhs.vy),
+			rz: self.w.anti_geometric(rhs.vz),
+			rw: Default::default(),
+			ux: self.w.anti_geometric(rhs.mx) + self.y.anti_geometric(rhs.vz) + self.z.anti_geometric(rhs.vy),
+			uy: self.w.anti_geometric(rhs.my) + self.x.anti_geometric(rhs.vz) + self.z.anti_geometric(rhs.vx),
+			uz: self.w.anti_geometric(rhs.mz) + self.x.anti_geometric(rhs.vy) + self.y.anti_geometric(rhs.vx),
+			uw: self.x.anti_geometric(rhs.vx) + self.y.anti_geometric(rhs.vy) + self.z.anti_geometric(rhs.vz),
+		}
+	}
+}
+
+// Translator3.dot(Line3) -> Motor3
+impl Dot<Line3> for Translator3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.mx.0),
+		//     ry: WY(self.w.0 * rhs.my.0),
+		//     rz: WZ(self.w.0 * rhs.mz.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.w.dot(rhs.mx),
+			ry: self.w.dot(rhs.my),
+			rz: self.w.dot(rhs.mz),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.x.dot(rhs.mx) + self.y.dot(rhs.my) + self.z.dot(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line3> for Translator3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Line3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator3.wedge(Line3) -> XYZW
+impl Wedge<Line3> for Translator3 {
+	type Output = XYZW;
+	fn wedge(self, rhs: Line3) -> Self::Output {
+		// -XYZW(self.x.0 * rhs.vx.0) - XYZW(self.y.0 * rhs.vy.0) - XYZW(self.z.0 * rhs.vz.0)
+		self.x.wedge(rhs.vx) + self.y.wedge(rhs.vy) + self.z.wedge(rhs.vz)
+	}
+}
+
+impl std::ops::BitXor<Line3> for Translator3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Line3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator3.anti_wedge(Line3) -> Motor3
+impl AntiWedge<Line3> for Translator3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.vx.0),
+		//     ry: WY(self.w.0 * rhs.vy.0),
+		//     rz: WZ(self.w.0 * rhs.vz.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.w.0 * rhs.mx.0),
+		//     uy: ZX(self.w.0 * rhs.my.0),
+		//     uz: XY(self.w.0 * rhs.mz.0),
+		//     uw: -S(self.x.0 * rhs.vx.0) - S(self.y.0 * rhs.vy.0) - S(self.z.0 * rhs.vz.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_wedge(rhs.vx),
+			ry: self.w.anti_wedge(rhs.vy),
+			rz: self.w.anti_wedge(rhs.vz),
+			rw: Default::default(),
+			ux: self.w.anti_wedge(rhs.mx),
+			uy: self.w.anti_wedge(rhs.my),
+			uz: self.w.anti_wedge(rhs.mz),
+			uw: self.x.anti_wedge(rhs.vx) + self.y.anti_wedge(rhs.vy) + self.z.anti_wedge(rhs.vz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Line3> for Translator3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Line3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator3.left_contraction(Line3) -> S
+impl LeftContraction<Line3> for Translator3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Line3) -> Self::Output {
+		// -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0)
+		self.x.left_contraction(rhs.mx) + self.y.left_contraction(rhs.my) + self.z.left_contraction(rhs.mz)
+	}
+}
+
+// Translator3.right_contraction(Line3) -> Motor3
+impl RightContraction<Line3> for Translator3 {
+	type Output = Motor3;
+	fn right_contraction(self, rhs: Line3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.mx.0),
+		//     ry: WY(self.w.0 * rhs.my.0),
+		//     rz: WZ(self.w.0 * rhs.mz.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0),
+		// }
+		Motor3 {
+			rx: self.w.right_contraction(rhs.mx),
+			ry: self.w.right_contraction(rhs.my),
+			rz: self.w.right_contraction(rhs.mz),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.x.right_contraction(rhs.mx) + self.y.right_contraction(rhs.my) + self.z.right_contraction(rhs.mz),
+		}
+	}
+}
+
+// Translator3.scalar_product(Line3) -> S
+impl ScalarProduct<Line3> for Translator3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Line3) -> Self::Output {
+		// -S(self.x.0 * rhs.mx.0) - S(self.y.0 * rhs.my.0) - S(self.z.0 * rhs.mz.0)
+		self.x.scalar_product(rhs.mx) + self.y.scalar_product(rhs.my) + self.z.scalar_product(rhs.mz)
+	}
+}
+
+// Translator3.commutator(Line3) -> Line3
+impl Commutator<Line3> for Translator3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Line3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.y.0 * rhs.vz.0) + WX(self.z.0 * rhs.vy.0),
+		//     vy: WY(self.x.0 * rhs.vz.0) - WY(self.z.0 * rhs.vx.0),
+		//     vz: -WZ(self.x.0 * rhs.vy.0) + WZ(self.y.0 * rhs.vx.0),
+		//     mx: -YZ(self.y.0 * rhs.mz.0) + YZ(self.z.0 * rhs.my.0),
+		//     my: ZX(self.x.0 * rhs.mz.0) - ZX(self.z.0 * rhs.mx.0),
+		//     mz: -XY(self.x.0 * rhs.my.0) + XY(self.y.0 * rhs.mx.0),
+		// }
+		Line3 {
+			vx: self.y.commutator(rhs.vz) + self.z.commutator(rhs.vy),
+			vy: self.x.commutator(rhs.vz) + self.z.commutator(rhs.vx),
+			vz: self.x.commutator(rhs.vy) + self.y.commutator(rhs.vx),
+			mx: self.y.commutator(rhs.mz) + self.z.commutator(rhs.my),
+			my: self.x.commutator(rhs.mz) + self.z.commutator(rhs.mx),
+			mz: self.x.commutator(rhs.my) + self.y.commutator(rhs.mx),
+		}
+	}
+}
+
+// Translator3.anti_dot(Line3) -> Line3
+impl AntiDot<Line3> for Translator3 {
+	type Output = Line3;
+	fn anti_dot(self, rhs: Line3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.vx.0),
+		//     vy: WY(self.w.0 * rhs.vy.0),
+		//     vz: WZ(self.w.0 * rhs.vz.0),
+		//     mx: YZ(self.w.0 * rhs.mx.0),
+		//     my: ZX(self.w.0 * rhs.my.0),
+		//     mz: XY(self.w.0 * rhs.mz.0),
+		// }
+		Line3 {
+			vx: self.w.anti_dot(rhs.vx),
+			vy: self.w.anti_dot(rhs.vy),
+			vz: self.w.anti_dot(rhs.vz),
+			mx: self.w.anti_dot(rhs.mx),
+			my: self.w.anti_dot(rhs.my),
+			mz: self.w.anti_dot(rhs.mz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator3 OP Plane:
+
+// Translator3.geometric(Plane) -> Multivector
+impl Geometric<Plane> for Translator3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.x.0 * rhs.d.0),
+		//     y   : -Y(self.y.0 * rhs.d.0),
+		//     z   : -Z(self.z.0 * rhs.d.0),
+		//     w   : W(self.w.0 * rhs.d.0) - W(self.x.0 * rhs.nx.0) - W(self.y.0 * rhs.ny.0) - W(self.z.0 * rhs.nz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.y.0 * rhs.nz.0) + YZW(self.z.0 * rhs.ny.0),
+		//     zxw : ZXW(self.x.0 * rhs.nz.0) - ZXW(self.z.0 * rhs.nx.0),
+		//     xyw : -XYW(self.x.0 * rhs.ny.0) + XYW(self.y.0 * rhs.nx.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.d),
+			y: self.y.geometric(rhs.d),
+			z: self.z.geometric(rhs.d),
+			w: self.w.geometric(rhs.d) + self.x.geometric(rhs.nx) + self.y.geometric(rhs.ny) + self.z.geometric(rhs.nz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.y.geometric(rhs.nz) + self.z.geometric(rhs.ny),
+			zxw: self.x.geometric(rhs.nz) + self.z.geometric(rhs.nx),
+			xyw: self.x.geometric(rhs.ny) + self.y.geometric(rhs.nx),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Translator3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator3.anti_geometric(Plane) -> Multivector
+impl AntiGeometric<Plane> for Translator3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.y.0 * rhs.nz.0) - X(self.z.0 * rhs.ny.0),
+		//     y   : -Y(self.x.0 * rhs.nz.0) + Y(self.z.0 * rhs.nx.0),
+		//     z   : Z(self.x.0 * rhs.ny.0) - Z(self.y.0 * rhs.nx.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.nx.0),
+		//     zxw : ZXW(self.w.0 * rhs.ny.0),
+		//     xyw : XYW(self.w.0 * rhs.nz.0),
+		//     xyz : XYZ(self.w.0 * rhs.d.0) + XYZ(self.x.0 * rhs.nx.0) + XYZ(self.y.0 * rhs.ny.0) + XYZ(self.z.0 * rhs.nz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.anti_geometric(rhs.nz) + self.z.anti_geometric(rhs.ny),
+			y: self.x.anti_geometric(rhs.nz) + self.z.anti_geometric(rhs.nx),
+			z: self.x.anti_geometric(rhs.ny) + self.y.anti_geometric(rhs.nx),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.anti_geometric(rhs.nx),
+			zxw: self.w.anti_geometric(rhs.ny),
+			xyw: self.w.anti_geometric(rhs.nz),
+			xyz: self.w.anti_geometric(rhs.d)
+				+ self.x.anti_geometric(rhs.nx)
+				+ self.y.anti_geometric(rhs.ny)
+				+ self.z.anti_geometric(rhs.nz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Translator3.dot(Plane) -> Vec4
+impl Dot<Plane> for Translator3 {
+	type Output = Vec4;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.x.0 * rhs.d.0),
+		//     y: -Y(self.y.0 * rhs.d.0),
+		//     z: -Z(self.z.0 * rhs.d.0),
+		//     w: W(self.w.0 * rhs.d.0) - W(self.x.0 * rhs.nx.0) - W(self.y.0 * rhs.ny.0) - W(self.z.0 * rhs.nz.0),
+		// }
+		Vec4 {
+			x: self.x.dot(rhs.d),
+			y: self.y.dot(rhs.d),
+			z: self.z.dot(rhs.d),
+			w: self.w.dot(rhs.d) + self.x.dot(rhs.nx) + self.y.dot(rhs.ny) + self.z.dot(rhs.nz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Translator3 {
+	type Output = Vec4;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Plane> for Translator3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Plane> for Translator3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator3.anti_wedge(Plane) -> Multivector
+impl AntiWedge<Plane> for Translator3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.y.0 * rhs.nz.0) - X(self.z.0 * rhs.ny.0),
+		//     y   : -Y(self.x.0 * rhs.nz.0) + Y(self.z.0 * rhs.nx.0),
+		//     z   : Z(self.x.0 * rhs.ny.0) - Z(self.y.0 * rhs.nx.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.nx.0),
+		//     zxw : ZXW(self.w.0 * rhs.ny.0),
+		//     xyw : XYW(self.w.0 * rhs.nz.0),
+		//     xyz : XYZ(self.w.0 * rhs.d.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.anti_wedge(rhs.nz) + self.z.anti_wedge(rhs.ny),
+			y: self.x.anti_wedge(rhs.nz) + self.z.anti_wedge(rhs.nx),
+			z: self.x.anti_wedge(rhs.ny) + self.y.anti_wedge(rhs.nx),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.anti_wedge(rhs.nx),
+			zxw: self.w.anti_wedge(rhs.ny),
+			xyw: self.w.anti_wedge(rhs.nz),
+			xyz: self.w.anti_wedge(rhs.d),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Translator3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator3.left_contraction(Plane) -> Vec4
+impl LeftContraction<Plane> for Translator3 {
+	type Output = Vec4;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.x.0 * rhs.d.0),
+		//     y: -Y(self.y.0 * rhs.d.0),
+		//     z: -Z(self.z.0 * rhs.d.0),
+		//     w: -W(self.x.0 * rhs.nx.0) - W(self.y.0 * rhs.ny.0) - W(self.z.0 * rhs.nz.0),
+		// }
+		Vec4 {
+			x: self.x.left_contraction(rhs.d),
+			y: self.y.left_contraction(rhs.d),
+			z: self.z.left_contraction(rhs.d),
+			w: self.x.left_contraction(rhs.nx) + self.y.left_contraction(rhs.ny) + self.z.left_contraction(rhs.nz),
+		}
+	}
+}
+
+// Translator3.right_contraction(Plane) -> W
+impl RightContraction<Plane> for Translator3 {
+	type Output = W;
+	fn right_contraction(self, rhs: Plane) -> Self::Output {
+		// W(self.w.0 * rhs.d.0)
+		self.w.right_contraction(rhs.d)
+	}
+}
+
+impl ScalarProduct<Plane> for Translator3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator3.commutator(Plane) -> Multivector
+impl Commutator<Plane> for Translator3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : W(self.w.0 * rhs.d.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : -YZW(self.y.0 * rhs.nz.0) + YZW(self.z.0 * rhs.ny.0),
+		//     zxw : ZXW(self.x.0 * rhs.nz.0) - ZXW(self.z.0 * rhs.nx.0),
+		//     xyw : -XYW(self.x.0 * rhs.ny.0) + XYW(self.y.0 * rhs.nx.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.w.commutator(rhs.d),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.y.commutator(rhs.nz) + self.z.commutator(rhs.ny),
+			zxw: self.x.commutator(rhs.nz) + self.z.commutator(rhs.nx),
+			xyw: self.x.commutator(rhs.ny) + self.y.commutator(rhs.nx),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Translator3.anti_dot(Plane) -> Plane
+impl AntiDot<Plane> for Translator3 {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.w.0 * rhs.nx.0),
+		//     ny: ZXW(self.w.0 * rhs.ny.0),
+		//     nz: XYW(self.w.0 * rhs.nz.0),
+		//     d : XYZ(self.w.0 * rhs.d.0) + XYZ(self.x.0 * rhs.nx.0) + XYZ(self.y.0 * rhs.ny.0) + XYZ(self.z.0 * rhs.nz.0),
+		// }
+		Plane {
+			nx: self.w.anti_dot(rhs.nx),
+			ny: self.w.anti_dot(rhs.ny),
+			nz: self.w.anti_dot(rhs.nz),
+			d: self.w.anti_dot(rhs.d) + self.x.anti_dot(rhs.nx) + self.y.anti_dot(rhs.ny) + self.z.anti_dot(rhs.nz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator3 OP Translator3:
+
+// Translator3.geometric(Translator3) -> Motor3
+impl Geometric<Translator3> for Translator3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.x.0) + WX(self.x.0 * rhs.w.0),
+		//     ry: WY(self.w.0 * rhs.y.0) + WY(self.y.0 * rhs.w.0),
+		//     rz: WZ(self.w.0 * rhs.z.0) + WZ(self.z.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: -YZ(self.y.0 * rhs.z.0) + YZ(self.z.0 * rhs.y.0),
+		//     uy: ZX(self.x.0 * rhs.z.0) - ZX(self.z.0 * rhs.x.0),
+		//     uz: -XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		//     uw: -S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.w.geometric(rhs.x) + self.x.geometric(rhs.w),
+			ry: self.w.geometric(rhs.y) + self.y.geometric(rhs.w),
+			rz: self.w.geometric(rhs.z) + self.z.geometric(rhs.w),
+			rw: Default::default(),
+			ux: self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			uy: self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			uz: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			uw: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator3> for Translator3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Translator3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator3.anti_geometric(Translator3) -> Translator3
+impl AntiGeometric<Translator3> for Translator3 {
+	type Output = Translator3;
+	fn anti_geometric(self, rhs: Translator3) -> Self::Output {
+		// Translator3 {
+		//     x: YZ(self.w.0 * rhs.x.0) + YZ(self.x.0 * rhs.w.0),
+		//     y: ZX(self.w.0 * rhs.y.0) + ZX(self.y.0 * rhs.w.0),
+		//     z: XY(self.w.0 * rhs.z.0) + XY(self.z.0 * rhs.w.0),
+		//     w: XYZW(self.w.0 * rhs.w.0),
+		// }
+		Translator3 {
+			x: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(rhs.w),
+			y: self.w.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.w),
+			z: self.w.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.w),
+			w: self.w.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Translator3.dot(Translator3) -> Motor3
+impl Dot<Translator3> for Translator3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.x.0) + WX(self.x.0 * rhs.w.0),
+		//     ry: WY(self.w.0 * rhs.y.0) + WY(self.y.0 * rhs.w.0),
+		//     rz: WZ(self.w.0 * rhs.z.0) + WZ(self.z.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.w.dot(rhs.x) + self.x.dot(rhs.w),
+			ry: self.w.dot(rhs.y) + self.y.dot(rhs.w),
+			rz: self.w.dot(rhs.z) + self.z.dot(rhs.w),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator3> for Translator3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Translator3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Translator3> for Translator3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: Translator3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Translator3> for Translator3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: Translator3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator3.anti_wedge(Translator3) -> Translator3
+impl AntiWedge<Translator3> for Translator3 {
+	type Output = Translator3;
+	fn anti_wedge(self, rhs: Translator3) -> Self::Output {
+		// Translator3 {
+		//     x: YZ(self.w.0 * rhs.x.0) + YZ(self.x.0 * rhs.w.0),
+		//     y: ZX(self.w.0 * rhs.y.0) + ZX(self.y.0 * rhs.w.0),
+		//     z: XY(self.w.0 * rhs.z.0) + XY(self.z.0 * rhs.w.0),
+		//     w: XYZW(self.w.0 * rhs.w.0),
+		// }
+		Translator3 {
+			x: self.w.anti_wedge(rhs.x) + self.x.anti_wedge(rhs.w),
+			y: self.w.anti_wedge(rhs.y) + self.y.anti_wedge(rhs.w),
+			z: self.w.anti_wedge(rhs.z) + self.z.anti_wedge(rhs.w),
+			w: self.w.anti_wedge(rhs.w),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Translator3> for Translator3 {
+	type Output = Translator3;
+	fn bitand(self, rhs: Translator3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator3.left_contraction(Translator3) -> Motor3
+impl LeftContraction<Translator3> for Translator3 {
+	type Output = Motor3;
+	fn left_contraction(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.x.0 * rhs.w.0),
+		//     ry: WY(self.y.0 * rhs.w.0),
+		//     rz: WZ(self.z.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.x.left_contraction(rhs.w),
+			ry: self.y.left_contraction(rhs.w),
+			rz: self.z.left_contraction(rhs.w),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z),
+		}
+	}
+}
+
+// Translator3.right_contraction(Translator3) -> Motor3
+impl RightContraction<Translator3> for Translator3 {
+	type Output = Motor3;
+	fn right_contraction(self, rhs: Translator3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.x.0),
+		//     ry: WY(self.w.0 * rhs.y.0),
+		//     rz: WZ(self.w.0 * rhs.z.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.w.right_contraction(rhs.x),
+			ry: self.w.right_contraction(rhs.y),
+			rz: self.w.right_contraction(rhs.z),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z),
+		}
+	}
+}
+
+// Translator3.scalar_product(Translator3) -> S
+impl ScalarProduct<Translator3> for Translator3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Translator3) -> Self::Output {
+		// -S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Translator3.commutator(Translator3) -> Moment3
+impl Commutator<Translator3> for Translator3 {
+	type Output = Moment3;
+	fn commutator(self, rhs: Translator3) -> Self::Output {
+		// Moment3 {
+		//     mx: -YZ(self.y.0 * rhs.z.0) + YZ(self.z.0 * rhs.y.0),
+		//     my: ZX(self.x.0 * rhs.z.0) - ZX(self.z.0 * rhs.x.0),
+		//     mz: -XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		// }
+		Moment3 {
+			mx: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			my: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			mz: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+		}
+	}
+}
+
+// Translator3.anti_dot(Translator3) -> Translator3
+impl AntiDot<Translator3> for Translator3 {
+	type Output = Translator3;
+	fn anti_dot(self, rhs: Translator3) -> Self::Output {
+		// Translator3 {
+		//     x: YZ(self.w.0 * rhs.x.0) + YZ(self.x.0 * rhs.w.0),
+		//     y: ZX(self.w.0 * rhs.y.0) + ZX(self.y.0 * rhs.w.0),
+		//     z: XY(self.w.0 * rhs.z.0) + XY(self.z.0 * rhs.w.0),
+		//     w: XYZW(self.w.0 * rhs.w.0),
+		// }
+		Translator3 {
+			x: self.w.anti_dot(rhs.x) + self.x.anti_dot(rhs.w),
+			y: self.w.anti_dot(rhs.y) + self.y.anti_dot(rhs.w),
+			z: self.w.anti_dot(rhs.z) + self.z.anti_dot(rhs.w),
+			w: self.w.anti_dot(rhs.w),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator3 OP Rotor3:
+
+// Translator3.geometric(Rotor3) -> Rotor3
+impl Geometric<Rotor3> for Translator3 {
+	type Output = Rotor3;
+	fn geometric(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     x: WX(self.x.0 * rhs.w.0) - WX(self.y.0 * rhs.z.0) + WX(self.z.0 * rhs.y.0),
+		//     y: WY(self.x.0 * rhs.z.0) + WY(self.y.0 * rhs.w.0) - WY(self.z.0 * rhs.x.0),
+		//     z: -WZ(self.x.0 * rhs.y.0) + WZ(self.y.0 * rhs.x.0) + WZ(self.z.0 * rhs.w.0),
+		//     w: -XYZW(self.x.0 * rhs.x.0) - XYZW(self.y.0 * rhs.y.0) - XYZW(self.z.0 * rhs.z.0),
+		// }
+		Rotor3 {
+			x: self.x.geometric(rhs.w) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			y: self.x.geometric(rhs.z) + self.y.geometric(rhs.w) + self.z.geometric(rhs.x),
+			z: self.x.geometric(rhs.y) + self.y.geometric(rhs.x) + self.z.geometric(rhs.w),
+			w: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Translator3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator3.anti_geometric(Rotor3) -> Motor3
+impl AntiGeometric<Rotor3> for Translator3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.x.0),
+		//     ry: WY(self.w.0 * rhs.y.0),
+		//     rz: WZ(self.w.0 * rhs.z.0),
+		//     rw: XYZW(self.w.0 * rhs.w.0),
+		//     ux: YZ(self.x.0 * rhs.w.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     uy: -ZX(self.x.0 * rhs.z.0) + ZX(self.y.0 * rhs.w.0) + ZX(self.z.0 * rhs.x.0),
+		//     uz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0) + XY(self.z.0 * rhs.w.0),
+		//     uw: -S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_geometric(rhs.x),
+			ry: self.w.anti_geometric(rhs.y),
+			rz: self.w.anti_geometric(rhs.z),
+			rw: self.w.anti_geometric(rhs.w),
+			ux: self.x.anti_geometric(rhs.w) + self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+			uy: self.x.anti_geometric(rhs.z) + self.y.anti_geometric(rhs.w) + self.z.anti_geometric(rhs.x),
+			uz: self.x.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.x) + self.z.anti_geometric(rhs.w),
+			uw: self.x.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Translator3.dot(Rotor3) -> Line3
+impl Dot<Rotor3> for Translator3 {
+	type Output = Line3;
+	fn dot(self, rhs: Rotor3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.x.0 * rhs.w.0),
+		//     vy: WY(self.y.0 * rhs.w.0),
+		//     vz: WZ(self.z.0 * rhs.w.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.x.dot(rhs.w),
+			vy: self.y.dot(rhs.w),
+			vz: self.z.dot(rhs.w),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor3> for Translator3 {
+	type Output = Line3;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator3.wedge(Rotor3) -> XYZW
+impl Wedge<Rotor3> for Translator3 {
+	type Output = XYZW;
+	fn wedge(self, rhs: Rotor3) -> Self::Output {
+		// -XYZW(self.x.0 * rhs.x.0) - XYZW(self.y.0 * rhs.y.0) - XYZW(self.z.0 * rhs.z.0)
+		self.x.wedge(rhs.x) + self.y.wedge(rhs.y) + self.z.wedge(rhs.z)
+	}
+}
+
+impl std::ops::BitXor<Rotor3> for Translator3 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator3.anti_wedge(Rotor3) -> Motor3
+impl AntiWedge<Rotor3> for Translator3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.x.0),
+		//     ry: WY(self.w.0 * rhs.y.0),
+		//     rz: WZ(self.w.0 * rhs.z.0),
+		//     rw: XYZW(self.w.0 * rhs.w.0),
+		//     ux: YZ(self.x.0 * rhs.w.0),
+		//     uy: ZX(self.y.0 * rhs.w.0),
+		//     uz: XY(self.z.0 * rhs.w.0),
+		//     uw: -S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_wedge(rhs.x),
+			ry: self.w.anti_wedge(rhs.y),
+			rz: self.w.anti_wedge(rhs.z),
+			rw: self.w.anti_wedge(rhs.w),
+			ux: self.x.anti_wedge(rhs.w),
+			uy: self.y.anti_wedge(rhs.w),
+			uz: self.z.anti_wedge(rhs.w),
+			uw: self.x.anti_wedge(rhs.x) + self.y.anti_wedge(rhs.y) + self.z.anti_wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor3> for Translator3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator3.left_contraction(Rotor3) -> Line3
+impl LeftContraction<Rotor3> for Translator3 {
+	type Output = Line3;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.x.0 * rhs.w.0),
+		//     vy: WY(self.y.0 * rhs.w.0),
+		//     vz: WZ(self.z.0 * rhs.w.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.x.left_contraction(rhs.w),
+			vy: self.y.left_contraction(rhs.w),
+			vz: self.z.left_contraction(rhs.w),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Rotor3> for Translator3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Rotor3> for Translator3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Translator3.commutator(Rotor3) -> Line3
+impl Commutator<Rotor3> for Translator3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.y.0 * rhs.z.0) + WX(self.z.0 * rhs.y.0),
+		//     vy: WY(self.x.0 * rhs.z.0) - WY(self.z.0 * rhs.x.0),
+		//     vz: -WZ(self.x.0 * rhs.y.0) + WZ(self.y.0 * rhs.x.0),
+		//     mx: Default::default(),
+		//     my: Default::default(),
+		//     mz: Default::default(),
+		// }
+		Line3 {
+			vx: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			vy: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			vz: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			mx: Default::default(),
+			my: Default::default(),
+			mz: Default::default(),
+		}
+	}
+}
+
+// Translator3.anti_dot(Rotor3) -> Motor3
+impl AntiDot<Rotor3> for Translator3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.x.0),
+		//     ry: WY(self.w.0 * rhs.y.0),
+		//     rz: WZ(self.w.0 * rhs.z.0),
+		//     rw: XYZW(self.w.0 * rhs.w.0),
+		//     ux: YZ(self.x.0 * rhs.w.0),
+		//     uy: ZX(self.y.0 * rhs.w.0),
+		//     uz: XY(self.z.0 * rhs.w.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.w.anti_dot(rhs.x),
+			ry: self.w.anti_dot(rhs.y),
+			rz: self.w.anti_dot(rhs.z),
+			rw: self.w.anti_dot(rhs.w),
+			ux: self.x.anti_dot(rhs.w),
+			uy: self.y.anti_dot(rhs.w),
+			uz: self.z.anti_dot(rhs.w),
+			uw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator3 OP Motor3:
+
+// Translator3.geometric(Motor3) -> Motor3
+impl Geometric<Motor3> for Translator3 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.ux.0) + WX(self.x.0 * rhs.rw.0) - WX(self.y.0 * rhs.rz.0) + WX(self.z.0 * rhs.ry.0),
+		//     ry: WY(self.w.0 * rhs.uy.0) + WY(self.x.0 * rhs.rz.0) + WY(self.y.0 * rhs.rw.0) - WY(self.z.0 * rhs.rx.0),
+		//     rz: WZ(self.w.0 * rhs.uz.0) - WZ(self.x.0 * rhs.ry.0) + WZ(self.y.0 * rhs.rx.0) + WZ(self.z.0 * rhs.rw.0),
+		//     rw: XYZW(self.w.0 * rhs.uw.0) - XYZW(self.x.0 * rhs.rx.0) - XYZW(self.y.0 * rhs.ry.0) - XYZW(self.z.0 * rhs.rz.0),
+		//     ux: YZ(self.x.0 * rhs.uw.0) - YZ(self.y.0 * rhs.uz.0) + YZ(self.z.0 * rhs.uy.0),
+		//     uy: ZX(self.x.0 * rhs.uz.0) + ZX(self.y.0 * rhs.uw.0) - ZX(self.z.0 * rhs.ux.0),
+		//     uz: -XY(self.x.0 * rhs.uy.0) + XY(self.y.0 * rhs.ux.0) + XY(self.z.0 * rhs.uw.0),
+		//     uw: -S(self.x.0 * rhs.ux.0) - S(self.y.0 * rhs.uy.0) - S(self.z.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.w.geometric(rhs.ux)
+				+ self.x.geometric(rhs.rw)
+				+ self.y.geometric(rhs.rz)
+				+ self.z.geometric(rhs.ry),
+			ry: self.w.geometric(rhs.uy)
+				+ self.x.geometric(rhs.rz)
+				+ self.y.geometric(rhs.rw)
+				+ self.z.geometric(rhs.rx),
+			rz: self.w.geometric(rhs.uz)
+				+ self.x.geometric(rhs.ry)
+				+ self.y.geometric(rhs.rx)
+				+ self.z.geometric(rhs.rw),
+			rw: self.w.geometric(rhs.uw)
+				+ self.x.geometric(rhs.rx)
+				+ self.y.geometric(rhs.ry)
+				+ self.z.geometric(rhs.rz),
+			ux: self.x.geometric(rhs.uw) + self.y.geometric(rhs.uz) + self.z.geometric(rhs.uy),
+			uy: self.x.geometric(rhs.uz) + self.y.geometric(rhs.uw) + self.z.geometric(rhs.ux),
+			uz: self.x.geometric(rhs.uy) + self.y.geometric(rhs.ux) + self.z.geometric(rhs.uw),
+			uw: self.x.geometric(rhs.ux) + self.y.geometric(rhs.uy) + self.z.geometric(rhs.uz),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor3> for Translator3 {
+	type Output = Motor3;
+	fn mul(self, rhs: Motor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator3.anti_geometric(Motor3) -> Motor3
+impl AntiGeometric<Motor3> for Translator3 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.rx.0),
+		//     ry: WY(self.w.0 * rhs.ry.0),
+		//     rz: WZ(self.w.0 * rhs.rz.0),
+		//     rw: XYZW(self.w.0 * rhs.rw.0),
+		//     ux: YZ(self.w.0 * rhs.ux.0) + YZ(self.x.0 * rhs.rw.0) + YZ(self.y.0 * rhs.rz.0) - YZ(self.z.0 * rhs.ry.0),
+		//     uy: ZX(self.w.0 * rhs.uy.0) - ZX(self.x.0 * rhs.rz.0) + ZX(self.y.0 * rhs.rw.0) + ZX(self.z.0 * rhs.rx.0),
+		//     uz: XY(self.w.0 * rhs.uz.0) + XY(self.x.0 * rhs.ry.0) - XY(self.y.0 * rhs.rx.0) + XY(self.z.0 * rhs.rw.0),
+		//     uw: S(self.w.0 * rhs.uw.0) - S(self.x.0 * rhs.rx.0) - S(self.y.0 * rhs.ry.0) - S(self.z.0 * rhs.rz.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_geometric(rhs.rx),
+			ry: self.w.anti_geometric(rhs.ry),
+			rz: self.w.anti_geometric(rhs.rz),
+			rw: self.w.anti_geometric(rhs.rw),
+			ux: self.w.anti_geometric(rhs.ux)
+				+ self.x.anti_geometric(rhs.rw)
+				+ self.y.anti_geometric(rhs.rz)
+				+ self.z.anti_geometric(rhs.ry),
+			uy: self.w.anti_geometric(rhs.uy)
+				+ self.x.anti_geometric(rhs.rz)
+				+ self.y.anti_geometric(rhs.rw)
+				+ self.z.anti_geometric(rhs.rx),
+			uz: self.w.anti_geometric(rhs.uz)
+				+ self.x.anti_geometric(rhs.ry)
+				+ self.y.anti_geometric(rhs.rx)
+				+ self.z.anti_geometric(rhs.rw),
+			uw: self.w.anti_geometric(rhs.uw)
+				+ self.x.anti_geometric(rhs.rx)
+				+ self.y.anti_geometric(rhs.ry)
+				+ self.z.anti_geometric(rhs.rz),
+		}
+	}
+}
+
+// Translator3.dot(Motor3) -> Motor3
+impl Dot<Motor3> for Translator3 {
+	type Output = Motor3;
+	fn dot(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.ux.0) + WX(self.x.0 * rhs.rw.0),
+		//     ry: WY(self.w.0 * rhs.uy.0) + WY(self.y.0 * rhs.rw.0),
+		//     rz: WZ(self.w.0 * rhs.uz.0) + WZ(self.z.0 * rhs.rw.0),
+		//     rw: XYZW(self.w.0 * rhs.uw.0),
+		//     ux: YZ(self.x.0 * rhs.uw.0),
+		//     uy: ZX(self.y.0 * rhs.uw.0),
+		//     uz: XY(self.z.0 * rhs.uw.0),
+		//     uw: -S(self.x.0 * rhs.ux.0) - S(self.y.0 * rhs.uy.0) - S(self.z.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.w.dot(rhs.ux) + self.x.dot(rhs.rw),
+			ry: self.w.dot(rhs.uy) + self.y.dot(rhs.rw),
+			rz: self.w.dot(rhs.uz) + self.z.dot(rhs.rw),
+			rw: self.w.dot(rhs.uw),
+			ux: self.x.dot(rhs.uw),
+			uy: self.y.dot(rhs.uw),
+			uz: self.z.dot(rhs.uw),
+			uw: self.x.dot(rhs.ux) + self.y.dot(rhs.uy) + self.z.dot(rhs.uz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor3> for Translator3 {
+	type Output = Motor3;
+	fn bitor(self, rhs: Motor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator3.wedge(Motor3) -> Translator3
+impl Wedge<Motor3> for Translator3 {
+	type Output = Translator3;
+	fn wedge(self, rhs: Motor3) -> Self::Output {
+		// Translator3 {
+		//     x: YZ(self.x.0 * rhs.uw.0),
+		//     y: ZX(self.y.0 * rhs.uw.0),
+		//     z: XY(self.z.0 * rhs.uw.0),
+		//     w: XYZW(self.w.0 * rhs.uw.0) - XYZW(self.x.0 * rhs.rx.0) - XYZW(self.y.0 * rhs.ry.0) - XYZW(self.z.0 * rhs.rz.0),
+		// }
+		Translator3 {
+			x: self.x.wedge(rhs.uw),
+			y: self.y.wedge(rhs.uw),
+			z: self.z.wedge(rhs.uw),
+			w: self.w.wedge(rhs.uw) + self.x.wedge(rhs.rx) + self.y.wedge(rhs.ry) + self.z.wedge(rhs.rz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Motor3> for Translator3 {
+	type Output = Translator3;
+	fn bitxor(self, rhs: Motor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator3.anti_wedge(Motor3) -> Motor3
+impl AntiWedge<Motor3> for Translator3 {
+	type Output = Motor3;
+	fn anti_wedge(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.rx.0),
+		//     ry: WY(self.w.0 * rhs.ry.0),
+		//     rz: WZ(self.w.0 * rhs.rz.0),
+		//     rw: XYZW(self.w.0 * rhs.rw.0),
+		//     ux: YZ(self.w.0 * rhs.ux.0) + YZ(self.x.0 * rhs.rw.0),
+		//     uy: ZX(self.w.0 * rhs.uy.0) + ZX(self.y.0 * rhs.rw.0),
+		//     uz: XY(self.w.0 * rhs.uz.0) + XY(self.z.0 * rhs.rw.0),
+		//     uw: S(self.w.0 * rhs.uw.0) - S(self.x.0 * rhs.rx.0) - S(self.y.0 * rhs.ry.0) - S(self.z.0 * rhs.rz.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_wedge(rhs.rx),
+			ry: self.w.anti_wedge(rhs.ry),
+			rz: self.w.anti_wedge(rhs.rz),
+			rw: self.w.anti_wedge(rhs.rw),
+			ux: self.w.anti_wedge(rhs.ux) + self.x.anti_wedge(rhs.rw),
+			uy: self.w.anti_wedge(rhs.uy) + self.y.anti_wedge(rhs.rw),
+			uz: self.w.anti_wedge(rhs.uz) + self.z.anti_wedge(rhs.rw),
+			uw: self.w.anti_wedge(rhs.uw)
+				+ self.x.anti_wedge(rhs.rx)
+				+ self.y.anti_wedge(rhs.ry)
+				+ self.z.anti_wedge(rhs.rz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor3> for Translator3 {
+	type Output = Motor3;
+	fn bitand(self, rhs: Motor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator3.left_contraction(Motor3) -> Motor3
+impl LeftContraction<Motor3> for Translator3 {
+	type Output = Motor3;
+	fn left_contraction(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.x.0 * rhs.rw.0),
+		//     ry: WY(self.y.0 * rhs.rw.0),
+		//     rz: WZ(self.z.0 * rhs.rw.0),
+		//     rw: Default::default(),
+		//     ux: Default::default(),
+		//     uy: Default::default(),
+		//     uz: Default::default(),
+		//     uw: -S(self.x.0 * rhs.ux.0) - S(self.y.0 * rhs.uy.0) - S(self.z.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.x.left_contraction(rhs.rw),
+			ry: self.y.left_contraction(rhs.rw),
+			rz: self.z.left_contraction(rhs.rw),
+			rw: Default::default(),
+			ux: Default::default(),
+			uy: Default::default(),
+			uz: Default::default(),
+			uw: self.x.left_contraction(rhs.ux) + self.y.left_contraction(rhs.uy) + self.z.left_contraction(rhs.uz),
+		}
+	}
+}
+
+// Translator3.right_contraction(Motor3) -> Motor3
+impl RightContraction<Motor3> for Translator3 {
+	type Output = Motor3;
+	fn right_contraction(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.ux.0),
+		//     ry: WY(self.w.0 * rhs.uy.0),
+		//     rz: WZ(self.w.0 * rhs.uz.0),
+		//     rw: XYZW(self.w.0 * rhs.uw.0),
+		//     ux: YZ(self.x.0 * rhs.uw.0),
+		//     uy: ZX(self.y.0 * rhs.uw.0),
+		//     uz: XY(self.z.0 * rhs.uw.0),
+		//     uw: -S(self.x.0 * rhs.ux.0) - S(self.y.0 * rhs.uy.0) - S(self.z.0 * rhs.uz.0),
+		// }
+		Motor3 {
+			rx: self.w.right_contraction(rhs.ux),
+			ry: self.w.right_contraction(rhs.uy),
+			rz: self.w.right_contraction(rhs.uz),
+			rw: self.w.right_contraction(rhs.uw),
+			ux: self.x.right_contraction(rhs.uw),
+			uy: self.y.right_contraction(rhs.uw),
+			uz: self.z.right_contraction(rhs.uw),
+			uw: self.x.right_contraction(rhs.ux) + self.y.right_contraction(rhs.uy) + self.z.right_contraction(rhs.uz),
+		}
+	}
+}
+
+// Translator3.scalar_product(Motor3) -> S
+impl ScalarProduct<Motor3> for Translator3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Motor3) -> Self::Output {
+		// -S(self.x.0 * rhs.ux.0) - S(self.y.0 * rhs.uy.0) - S(self.z.0 * rhs.uz.0)
+		self.x.scalar_product(rhs.ux) + self.y.scalar_product(rhs.uy) + self.z.scalar_product(rhs.uz)
+	}
+}
+
+// Translator3.commutator(Motor3) -> Line3
+impl Commutator<Motor3> for Translator3 {
+	type Output = Line3;
+	fn commutator(self, rhs: Motor3) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.y.0 * rhs.rz.0) + WX(self.z.0 * rhs.ry.0),
+		//     vy: WY(self.x.0 * rhs.rz.0) - WY(self.z.0 * rhs.rx.0),
+		//     vz: -WZ(self.x.0 * rhs.ry.0) + WZ(self.y.0 * rhs.rx.0),
+		//     mx: -YZ(self.y.0 * rhs.uz.0) + YZ(self.z.0 * rhs.uy.0),
+		//     my: ZX(self.x.0 * rhs.uz.0) - ZX(self.z.0 * rhs.ux.0),
+		//     mz: -XY(self.x.0 * rhs.uy.0) + XY(self.y.0 * rhs.ux.0),
+		// }
+		Line3 {
+			vx: self.y.commutator(rhs.rz) + self.z.commutator(rhs.ry),
+			vy: self.x.commutator(rhs.rz) + self.z.commutator(rhs.rx),
+			vz: self.x.commutator(rhs.ry) + self.y.commutator(rhs.rx),
+			mx: self.y.commutator(rhs.uz) + self.z.commutator(rhs.uy),
+			my: self.x.commutator(rhs.uz) + self.z.commutator(rhs.ux),
+			mz: self.x.commutator(rhs.uy) + self.y.commutator(rhs.ux),
+		}
+	}
+}
+
+// Translator3.anti_dot(Motor3) -> Motor3
+impl AntiDot<Motor3> for Translator3 {
+	type Output = Motor3;
+	fn anti_dot(self, rhs: Motor3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.rx.0),
+		//     ry: WY(self.w.0 * rhs.ry.0),
+		//     rz: WZ(self.w.0 * rhs.rz.0),
+		//     rw: XYZW(self.w.0 * rhs.rw.0),
+		//     ux: YZ(self.w.0 * rhs.ux.0) + YZ(self.x.0 * rhs.rw.0),
+		//     uy: ZX(self.w.0 * rhs.uy.0) + ZX(self.y.0 * rhs.rw.0),
+		//     uz: XY(self.w.0 * rhs.uz.0) + XY(self.z.0 * rhs.rw.0),
+		//     uw: S(self.w.0 * rhs.uw.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_dot(rhs.rx),
+			ry: self.w.anti_dot(rhs.ry),
+			rz: self.w.anti_dot(rhs.rz),
+			rw: self.w.anti_dot(rhs.rw),
+			ux: self.w.anti_dot(rhs.ux) + self.x.anti_dot(rhs.rw),
+			uy: self.w.anti_dot(rhs.uy) + self.y.anti_dot(rhs.rw),
+			uz: self.w.anti_dot(rhs.uz) + self.z.anti_dot(rhs.rw),
+			uw: self.w.anti_dot(rhs.uw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Translator3 OP Multivector:
+
+// Translator3.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.x.0 * rhs.yz.0) - S(self.y.0 * rhs.zx.0) - S(self.z.0 * rhs.xy.0),
+		//     x   : -X(self.x.0 * rhs.xyz.0) - X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.y.0 * rhs.xyz.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0) - Z(self.z.0 * rhs.xyz.0),
+		//     w   : W(self.w.0 * rhs.xyz.0) - W(self.x.0 * rhs.yzw.0) - W(self.y.0 * rhs.zxw.0) - W(self.z.0 * rhs.xyw.0),
+		//     wx  : WX(self.w.0 * rhs.yz.0) + WX(self.x.0 * rhs.xyzw.0) - WX(self.y.0 * rhs.wz.0) + WX(self.z.0 * rhs.wy.0),
+		//     wy  : WY(self.w.0 * rhs.zx.0) + WY(self.x.0 * rhs.wz.0) + WY(self.y.0 * rhs.xyzw.0) - WY(self.z.0 * rhs.wx.0),
+		//     wz  : WZ(self.w.0 * rhs.xy.0) - WZ(self.x.0 * rhs.wy.0) + WZ(self.y.0 * rhs.wx.0) + WZ(self.z.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.x.0 * rhs.s.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx  : ZX(self.x.0 * rhs.xy.0) + ZX(self.y.0 * rhs.s.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy  : -XY(self.x.0 * rhs.zx.0) + XY(self.y.0 * rhs.yz.0) + XY(self.z.0 * rhs.s.0),
+		//     yzw : -YZW(self.w.0 * rhs.x.0) + YZW(self.x.0 * rhs.w.0) - YZW(self.y.0 * rhs.xyw.0) + YZW(self.z.0 * rhs.zxw.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0) + ZXW(self.x.0 * rhs.xyw.0) + ZXW(self.y.0 * rhs.w.0) - ZXW(self.z.0 * rhs.yzw.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0) - XYW(self.x.0 * rhs.zxw.0) + XYW(self.y.0 * rhs.yzw.0) + XYW(self.z.0 * rhs.w.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: XYZW(self.w.0 * rhs.s.0) - XYZW(self.x.0 * rhs.wx.0) - XYZW(self.y.0 * rhs.wy.0) - XYZW(self.z.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.yz) + self.y.geometric(rhs.zx) + self.z.geometric(rhs.xy),
+			x: self.x.geometric(rhs.xyz) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			y: self.x.geometric(rhs.z) + self.y.geometric(rhs.xyz) + self.z.geometric(rhs.x),
+			z: self.x.geometric(rhs.y) + self.y.geometric(rhs.x) + self.z.geometric(rhs.xyz),
+			w: self.w.geometric(rhs.xyz)
+				+ self.x.geometric(rhs.yzw)
+				+ self.y.geometric(rhs.zxw)
+				+ self.z.geometric(rhs.xyw),
+			wx: self.w.geometric(rhs.yz)
+				+ self.x.geometric(rhs.xyzw)
+				+ self.y.geometric(rhs.wz)
+				+ self.z.geometric(rhs.wy),
+			wy: self.w.geometric(rhs.zx)
+				+ self.x.geometric(rhs.wz)
+				+ self.y.geometric(rhs.xyzw)
+				+ self.z.geometric(rhs.wx),
+			wz: self.w.geometric(rhs.xy)
+				+ self.x.geometric(rhs.wy)
+				+ self.y.geometric(rhs.wx)
+				+ self.z.geometric(rhs.xyzw),
+			yz: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy) + self.z.geometric(rhs.zx),
+			zx: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s) + self.z.geometric(rhs.yz),
+			xy: self.x.geometric(rhs.zx) + self.y.geometric(rhs.yz) + self.z.geometric(rhs.s),
+			yzw: self.w.geometric(rhs.x)
+				+ self.x.geometric(rhs.w)
+				+ self.y.geometric(rhs.xyw)
+				+ self.z.geometric(rhs.zxw),
+			zxw: self.w.geometric(rhs.y)
+				+ self.x.geometric(rhs.xyw)
+				+ self.y.geometric(rhs.w)
+				+ self.z.geometric(rhs.yzw),
+			xyw: self.w.geometric(rhs.z)
+				+ self.x.geometric(rhs.zxw)
+				+ self.y.geometric(rhs.yzw)
+				+ self.z.geometric(rhs.w),
+			xyz: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			xyzw: self.w.geometric(rhs.s)
+				+ self.x.geometric(rhs.wx)
+				+ self.y.geometric(rhs.wy)
+				+ self.z.geometric(rhs.wz),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Translator3.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.w.0 * rhs.s.0) - S(self.x.0 * rhs.wx.0) - S(self.y.0 * rhs.wy.0) - S(self.z.0 * rhs.wz.0),
+		//     x   : X(self.w.0 * rhs.x.0) + X(self.x.0 * rhs.w.0) + X(self.y.0 * rhs.xyw.0) - X(self.z.0 * rhs.zxw.0),
+		//     y   : Y(self.w.0 * rhs.y.0) - Y(self.x.0 * rhs.xyw.0) + Y(self.y.0 * rhs.w.0) + Y(self.z.0 * rhs.yzw.0),
+		//     z   : Z(self.w.0 * rhs.z.0) + Z(self.x.0 * rhs.zxw.0) - Z(self.y.0 * rhs.yzw.0) + Z(self.z.0 * rhs.w.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : WX(self.w.0 * rhs.wx.0),
+		//     wy  : WY(self.w.0 * rhs.wy.0),
+		//     wz  : WZ(self.w.0 * rhs.wz.0),
+		//     yz  : YZ(self.w.0 * rhs.yz.0) + YZ(self.x.0 * rhs.xyzw.0) + YZ(self.y.0 * rhs.wz.0) - YZ(self.z.0 * rhs.wy.0),
+		//     zx  : ZX(self.w.0 * rhs.zx.0) - ZX(self.x.0 * rhs.wz.0) + ZX(self.y.0 * rhs.xyzw.0) + ZX(self.z.0 * rhs.wx.0),
+		//     xy  : XY(self.w.0 * rhs.xy.0) + XY(self.x.0 * rhs.wy.0) - XY(self.y.0 * rhs.wx.0) + XY(self.z.0 * rhs.xyzw.0),
+		//     yzw : YZW(self.w.0 * rhs.yzw.0),
+		//     zxw : ZXW(self.w.0 * rhs.zxw.0),
+		//     xyw : XYW(self.w.0 * rhs.xyw.0),
+		//     xyz : XYZ(self.w.0 * rhs.xyz.0) + XYZ(self.x.0 * rhs.yzw.0) + XYZ(self.y.0 * rhs.zxw.0) + XYZ(self.z.0 * rhs.xyw.0),
+		//     xyzw: XYZW(self.w.0 * rhs.xyzw.0),
+		// }
+		Multivector {
+			s: self.w.anti_geometric(rhs.s)
+				+ self.x.anti_geometric(rhs.wx)
+				+ self.y.anti_geometric(rhs.wy)
+				+ self.z.anti_geometric(rhs.wz),
+			x: self.w.anti_geometric(rhs.x)
+				+ self.x.anti_geometric(rhs.w)
+				+ self.y.anti_geometric(rhs.xyw)
+				+ self.z.anti_geometric(rhs.zxw),
+			y: self.w.anti_geometric(rhs.y)
+				+ self.x.anti_geometric(rhs.xyw)
+				+ self.y.anti_geometric(rhs.w)
+				+ self.z.anti_geometric(rhs.yzw),
+			z: self.w.anti_geometric(rhs.z)
+				+ self.x.anti_geometric(rhs.zxw)
+				+ self.y.anti_geometric(rhs.yzw)
+				+ self.z.anti_geometric(rhs.w),
+			w: self.w.anti_geometric(rhs.w),
+			wx: self.w.anti_geometric(rhs.wx),
+			wy: self.w.anti_geometric(rhs.wy),
+			wz: self.w.anti_geometric(rhs.wz),
+			yz: self.w.anti_geometric(rhs.yz)
+				+ self.x.anti_geometric(rhs.xyzw)
+				+ self.y.anti_geometric(rhs.wz)
+				+ self.z.anti_geometric(rhs.wy),
+			zx: self.w.anti_geometric(rhs.zx)
+				+ self.x.anti_geometric(rhs.wz)
+				+ self.y.anti_geometric(rhs.xyzw)
+				+ self.z.anti_geometric(rhs.wx),
+			xy: self.w.anti_geometric(rhs.xy)
+				+ self.x.anti_geometric(rhs.wy)
+				+ self.y.anti_geometric(rhs.wx)
+				+ self.z.anti_geometric(rhs.xyzw),
+			yzw: self.w.anti_geometric(rhs.yzw),
+			zxw: self.w.anti_geometric(rhs.zxw),
+			xyw: self.w.anti_geometric(rhs.xyw),
+			xyz: self.w.anti_geometric(rhs.xyz)
+				+ self.x.anti_geometric(rhs.yzw)
+				+ self.y.anti_geometric(rhs.zxw)
+				+ self.z.anti_geometric(rhs.xyw),
+			xyzw: self.w.anti_geometric(rhs.xyzw),
+		}
+	}
+}
+
+// Translator3.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.x.0 * rhs.yz.0) - S(self.y.0 * rhs.zx.0) - S(self.z.0 * rhs.xy.0),
+		//     x   : -X(self.x.0 * rhs.xyz.0) - X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.y.0 * rhs.xyz.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0) - Z(self.z.0 * rhs.xyz.0),
+		//     w   : W(self.w.0 * rhs.xyz.0) - W(self.x.0 * rhs.yzw.0) - W(self.y.0 * rhs.zxw.0) - W(self.z.0 * rhs.xyw.0),
+		//     wx  : WX(self.w.0 * rhs.yz.0) + WX(self.x.0 * rhs.xyzw.0),
+		//     wy  : WY(self.w.0 * rhs.zx.0) + WY(self.y.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.w.0 * rhs.xy.0) + WZ(self.z.0 * rhs.xyzw.0),
+		//     yz  : YZ(self.x.0 * rhs.s.0),
+		//     zx  : ZX(self.y.0 * rhs.s.0),
+		//     xy  : XY(self.z.0 * rhs.s.0),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.w.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.yz) + self.y.dot(rhs.zx) + self.z.dot(rhs.xy),
+			x: self.x.dot(rhs.xyz) + self.y.dot(rhs.z) + self.z.dot(rhs.y),
+			y: self.x.dot(rhs.z) + self.y.dot(rhs.xyz) + self.z.dot(rhs.x),
+			z: self.x.dot(rhs.y) + self.y.dot(rhs.x) + self.z.dot(rhs.xyz),
+			w: self.w.dot(rhs.xyz) + self.x.dot(rhs.yzw) + self.y.dot(rhs.zxw) + self.z.dot(rhs.xyw),
+			wx: self.w.dot(rhs.yz) + self.x.dot(rhs.xyzw),
+			wy: self.w.dot(rhs.zx) + self.y.dot(rhs.xyzw),
+			wz: self.w.dot(rhs.xy) + self.z.dot(rhs.xyzw),
+			yz: self.x.dot(rhs.s),
+			zx: self.y.dot(rhs.s),
+			xy: self.z.dot(rhs.s),
+			yzw: self.w.dot(rhs.x),
+			zxw: self.w.dot(rhs.y),
+			xyw: self.w.dot(rhs.z),
+			xyz: Default::default(),
+			xyzw: self.w.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Translator3.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : YZ(self.x.0 * rhs.s.0),
+		//     zx  : ZX(self.y.0 * rhs.s.0),
+		//     xy  : XY(self.z.0 * rhs.s.0),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: XYZW(self.w.0 * rhs.s.0) - XYZW(self.x.0 * rhs.wx.0) - XYZW(self.y.0 * rhs.wy.0) - XYZW(self.z.0 * rhs.wz.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: self.x.wedge(rhs.s),
+			zx: self.y.wedge(rhs.s),
+			xy: self.z.wedge(rhs.s),
+			yzw: self.x.wedge(rhs.w),
+			zxw: self.y.wedge(rhs.w),
+			xyw: self.z.wedge(rhs.w),
+			xyz: self.x.wedge(rhs.x) + self.y.wedge(rhs.y) + self.z.wedge(rhs.z),
+			xyzw: self.w.wedge(rhs.s) + self.x.wedge(rhs.wx) + self.y.wedge(rhs.wy) + self.z.wedge(rhs.wz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Translator3.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.w.0 * rhs.s.0) - S(self.x.0 * rhs.wx.0) - S(self.y.0 * rhs.wy.0) - S(self.z.0 * rhs.wz.0),
+		//     x   : X(self.w.0 * rhs.x.0) + X(self.y.0 * rhs.xyw.0) - X(self.z.0 * rhs.zxw.0),
+		//     y   : Y(self.w.0 * rhs.y.0) - Y(self.x.0 * rhs.xyw.0) + Y(self.z.0 * rhs.yzw.0),
+		//     z   : Z(self.w.0 * rhs.z.0) + Z(self.x.0 * rhs.zxw.0) - Z(self.y.0 * rhs.yzw.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : WX(self.w.0 * rhs.wx.0),
+		//     wy  : WY(self.w.0 * rhs.wy.0),
+		//     wz  : WZ(self.w.0 * rhs.wz.0),
+		//     yz  : YZ(self.w.0 * rhs.yz.0) + YZ(self.x.0 * rhs.xyzw.0),
+		//     zx  : ZX(self.w.0 * rhs.zx.0) + ZX(self.y.0 * rhs.xyzw.0),
+		//     xy  : XY(self.w.0 * rhs.xy.0) + XY(self.z.0 * rhs.xyzw.0),
+		//     yzw : YZW(self.w.0 * rhs.yzw.0),
+		//     zxw : ZXW(self.w.0 * rhs.zxw.0),
+		//     xyw : XYW(self.w.0 * rhs.xyw.0),
+		//     xyz : XYZ(self.w.0 * rhs.xyz.0),
+		//     xyzw: XYZW(self.w.0 * rhs.xyzw.0),
+		// }
+		Multivector {
+			s: self.w.anti_wedge(rhs.s)
+				+ self.x.anti_wedge(rhs.wx)
+				+ self.y.anti_wedge(rhs.wy)
+				+ self.z.anti_wedge(rhs.wz),
+			x: self.w.anti_wedge(rhs.x) + self.y.anti_wedge(rhs.xyw) + self.z.anti_wedge(rhs.zxw),
+			y: self.w.anti_wedge(rhs.y) + self.x.anti_wedge(rhs.xyw) + self.z.anti_wedge(rhs.yzw),
+			z: self.w.anti_wedge(rhs.z) + self.x.anti_wedge(rhs.zxw) + self.y.anti_wedge(rhs.yzw),
+			w: self.w.anti_wedge(rhs.w),
+			wx: self.w.anti_wedge(rhs.wx),
+			wy: self.w.anti_wedge(rhs.wy),
+			wz: self.w.anti_wedge(rhs.wz),
+			yz: self.w.anti_wedge(rhs.yz) + self.x.anti_wedge(rhs.xyzw),
+			zx: self.w.anti_wedge(rhs.zx) + self.y.anti_wedge(rhs.xyzw),
+			xy: self.w.anti_wedge(rhs.xy) + self.z.anti_wedge(rhs.xyzw),
+			yzw: self.w.anti_wedge(rhs.yzw),
+			zxw: self.w.anti_wedge(rhs.zxw),
+			xyw: self.w.anti_wedge(rhs.xyw),
+			xyz: self.w.anti_wedge(rhs.xyz),
+			xyzw: self.w.anti_wedge(rhs.xyzw),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Translator3.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.x.0 * rhs.yz.0) - S(self.y.0 * rhs.zx.0) - S(self.z.0 * rhs.xy.0),
+		//     x   : -X(self.x.0 * rhs.xyz.0),
+		//     y   : -Y(self.y.0 * rhs.xyz.0),
+		//     z   : -Z(self.z.0 * rhs.xyz.0),
+		//     w   : -W(self.x.0 * rhs.yzw.0) - W(self.y.0 * rhs.zxw.0) - W(self.z.0 * rhs.xyw.0),
+		//     wx  : WX(self.x.0 * rhs.xyzw.0),
+		//     wy  : WY(self.y.0 * rhs.xyzw.0),
+		//     wz  : WZ(self.z.0 * rhs.xyzw.0),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.yz) + self.y.left_contraction(rhs.zx) + self.z.left_contraction(rhs.xy),
+			x: self.x.left_contraction(rhs.xyz),
+			y: self.y.left_contraction(rhs.xyz),
+			z: self.z.left_contraction(rhs.xyz),
+			w: self.x.left_contraction(rhs.yzw) + self.y.left_contraction(rhs.zxw) + self.z.left_contraction(rhs.xyw),
+			wx: self.x.left_contraction(rhs.xyzw),
+			wy: self.y.left_contraction(rhs.xyzw),
+			wz: self.z.left_contraction(rhs.xyzw),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Translator3.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.x.0 * rhs.yz.0) - S(self.y.0 * rhs.zx.0) - S(self.z.0 * rhs.xy.0),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : W(self.w.0 * rhs.xyz.0),
+		//     wx  : WX(self.w.0 * rhs.yz.0),
+		//     wy  : WY(self.w.0 * rhs.zx.0),
+		//     wz  : WZ(self.w.0 * rhs.xy.0),
+		//     yz  : YZ(self.x.0 * rhs.s.0),
+		//     zx  : ZX(self.y.0 * rhs.s.0),
+		//     xy  : XY(self.z.0 * rhs.s.0),
+		//     yzw : -YZW(self.w.0 * rhs.x.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0),
+		//     xyz : Default::default(),
+		//     xyzw: XYZW(self.w.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.yz) + self.y.right_contraction(rhs.zx) + self.z.right_contraction(rhs.xy),
+			x: self.y.right_contraction(rhs.z) + self.z.right_contraction(rhs.y),
+			y: self.x.right_contraction(rhs.z) + self.z.right_contraction(rhs.x),
+			z: self.x.right_contraction(rhs.y) + self.y.right_contraction(rhs.x),
+			w: self.w.right_contraction(rhs.xyz),
+			wx: self.w.right_contraction(rhs.yz),
+			wy: self.w.right_contraction(rhs.zx),
+			wz: self.w.right_contraction(rhs.xy),
+			yz: self.x.right_contraction(rhs.s),
+			zx: self.y.right_contraction(rhs.s),
+			xy: self.z.right_contraction(rhs.s),
+			yzw: self.w.right_contraction(rhs.x),
+			zxw: self.w.right_contraction(rhs.y),
+			xyw: self.w.right_contraction(rhs.z),
+			xyz: Default::default(),
+			xyzw: self.w.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Translator3.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Translator3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.x.0 * rhs.yz.0) - S(self.y.0 * rhs.zx.0) - S(self.z.0 * rhs.xy.0)
+		self.x.scalar_product(rhs.yz) + self.y.scalar_product(rhs.zx) + self.z.scalar_product(rhs.xy)
+	}
+}
+
+// Translator3.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : W(self.w.0 * rhs.xyz.0),
+		//     wx  : -WX(self.y.0 * rhs.wz.0) + WX(self.z.0 * rhs.wy.0),
+		//     wy  : WY(self.x.0 * rhs.wz.0) - WY(self.z.0 * rhs.wx.0),
+		//     wz  : -WZ(self.x.0 * rhs.wy.0) + WZ(self.y.0 * rhs.wx.0),
+		//     yz  : -YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx  : ZX(self.x.0 * rhs.xy.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy  : -XY(self.x.0 * rhs.zx.0) + XY(self.y.0 * rhs.yz.0),
+		//     yzw : -YZW(self.w.0 * rhs.x.0) - YZW(self.y.0 * rhs.xyw.0) + YZW(self.z.0 * rhs.zxw.0),
+		//     zxw : -ZXW(self.w.0 * rhs.y.0) + ZXW(self.x.0 * rhs.xyw.0) - ZXW(self.z.0 * rhs.yzw.0),
+		//     xyw : -XYW(self.w.0 * rhs.z.0) - XYW(self.x.0 * rhs.zxw.0) + XYW(self.y.0 * rhs.yzw.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			y: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			z: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			w: self.w.commutator(rhs.xyz),
+			wx: self.y.commutator(rhs.wz) + self.z.commutator(rhs.wy),
+			wy: self.x.commutator(rhs.wz) + self.z.commutator(rhs.wx),
+			wz: self.x.commutator(rhs.wy) + self.y.commutator(rhs.wx),
+			yz: self.y.commutator(rhs.xy) + self.z.commutator(rhs.zx),
+			zx: self.x.commutator(rhs.xy) + self.z.commutator(rhs.yz),
+			xy: self.x.commutator(rhs.zx) + self.y.commutator(rhs.yz),
+			yzw: self.w.commutator(rhs.x) + self.y.commutator(rhs.xyw) + self.z.commutator(rhs.zxw),
+			zxw: self.w.commutator(rhs.y) + self.x.commutator(rhs.xyw) + self.z.commutator(rhs.yzw),
+			xyw: self.w.commutator(rhs.z) + self.x.commutator(rhs.zxw) + self.y.commutator(rhs.yzw),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Translator3.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Translator3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.w.0 * rhs.s.0),
+		//     x   : X(self.w.0 * rhs.x.0),
+		//     y   : Y(self.w.0 * rhs.y.0),
+		//     z   : Z(self.w.0 * rhs.z.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : WX(self.w.0 * rhs.wx.0),
+		//     wy  : WY(self.w.0 * rhs.wy.0),
+		//     wz  : WZ(self.w.0 * rhs.wz.0),
+		//     yz  : YZ(self.w.0 * rhs.yz.0) + YZ(self.x.0 * rhs.xyzw.0),
+		//     zx  : ZX(self.w.0 * rhs.zx.0) + ZX(self.y.0 * rhs.xyzw.0),
+		//     xy  : XY(self.w.0 * rhs.xy.0) + XY(self.z.0 * rhs.xyzw.0),
+		//     yzw : YZW(self.w.0 * rhs.yzw.0),
+		//     zxw : ZXW(self.w.0 * rhs.zxw.0),
+		//     xyw : XYW(self.w.0 * rhs.xyw.0),
+		//     xyz : XYZ(self.w.0 * rhs.xyz.0) + XYZ(self.x.0 * rhs.yzw.0) + XYZ(self.y.0 * rhs.zxw.0) + XYZ(self.z.0 * rhs.xyw.0),
+		//     xyzw: XYZW(self.w.0 * rhs.xyzw.0),
+		// }
+		Multivector {
+			s: self.w.anti_dot(rhs.s),
+			x: self.w.anti_dot(rhs.x),
+			y: self.w.anti_dot(rhs.y),
+			z: self.w.anti_dot(rhs.z),
+			w: self.w.anti_dot(rhs.w),
+			wx: self.w.anti_dot(rhs.wx),
+			wy: self.w.anti_dot(rhs.wy),
+			wz: self.w.anti_dot(rhs.wz),
+			yz: self.w.anti_dot(rhs.yz) + self.x.anti_dot(rhs.xyzw),
+			zx: self.w.anti_dot(rhs.zx) + self.y.anti_dot(rhs.xyzw),
+			xy: self.w.anti_dot(rhs.xy) + self.z.anti_dot(rhs.xyzw),
+			yzw: self.w.anti_dot(rhs.yzw),
+			zxw: self.w.anti_dot(rhs.zxw),
+			xyw: self.w.anti_dot(rhs.xyw),
+			xyz: self.w.anti_dot(rhs.xyz)
+				+ self.x.anti_dot(rhs.yzw)
+				+ self.y.anti_dot(rhs.zxw)
+				+ self.z.anti_dot(rhs.xyw),
+			xyzw: self.w.anti_dot(rhs.xyzw),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Translator3.sandwich(Vec3)  (not a Vec3)
+
+// Translator3.anti_sandwich(Vec3) -> Vec3
+impl AntiSandwich<Vec3> for Translator3 {
+	fn anti_sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: self.w.anti_geometric(self.w).anti_geometric(rhs.x),
+			y: self.w.anti_geometric(self.w).anti_geometric(rhs.y),
+			z: self.w.anti_geometric(self.w).anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Translator3.sandwich(Vec4) -> Vec4
+impl Sandwich<Vec4> for Translator3 {
+	fn sandwich(self, rhs: Vec4) -> Vec4 {
+		Vec4 {
+			x: -self.x.geometric(self.x).geometric(rhs.x)
+				- self.x.geometric(self.y).geometric(rhs.y) * 2.0
+				- self.x.geometric(self.z).geometric(rhs.z) * 2.0
+				+ self.y.geometric(self.y).geometric(rhs.x)
+				+ self.z.geometric(self.z).geometric(rhs.x),
+			y: self.x.geometric(self.x).geometric(rhs.y) + self.x.geometric(self.y).geometric(rhs.x) * 2.0
+				- self.y.geometric(self.y).geometric(rhs.y)
+				- self.y.geometric(self.z).geometric(rhs.z) * 2.0
+				+ self.z.geometric(self.z).geometric(rhs.y),
+			z: self.x.geometric(self.x).geometric(rhs.z)
+				+ self.x.geometric(self.z).geometric(rhs.x) * 2.0
+				+ self.y.geometric(self.y).geometric(rhs.z)
+				+ self.y.geometric(self.z).geometric(rhs.y) * 2.0
+				- self.z.geometric(self.z).geometric(rhs.z),
+			w: self.w.geometric(self.x).geometric(rhs.x) * -2.0
+				- self.w.geometric(self.y).geometric(rhs.y) * 2.0
+				- self.w.geometric(self.z).geometric(rhs.z) * 2.0
+				- self.x.geometric(self.x).geometric(rhs.w)
+				- self.y.geometric(self.y).geometric(rhs.w)
+				- self.z.geometric(self.z).geometric(rhs.w),
+		}
+	}
+}
+
+// Translator3.anti_sandwich(Vec4) -> Vec4
+impl AntiSandwich<Vec4> for Translator3 {
+	fn anti_sandwich(self, rhs: Vec4) -> Vec4 {
+		Vec4 {
+			x: self.w.anti_geometric(self.w).anti_geometric(rhs.x)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.w) * 2.0,
+			y: self.w.anti_geometric(self.w).anti_geometric(rhs.y)
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.w) * 2.0,
+			z: self.w.anti_geometric(self.w).anti_geometric(rhs.z)
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.w) * 2.0,
+			w: self.w.anti_geometric(self.w).anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Omitted: Translator3.sandwich(Point3)  (not a Point3)
+// Omitted: Translator3.anti_sandwich(Point3)  (not a Point3)
+// Omitted: Translator3.sandwich(Moment3)  (not a Moment3)
+
+// Translator3.anti_sandwich(Moment3) -> Moment3
+impl AntiSandwich<Moment3> for Translator3 {
+	fn anti_sandwich(self, rhs: Moment3) -> Moment3 {
+		Moment3 {
+			mx: self.w.anti_geometric(self.w).anti_geometric(rhs.mx),
+			my: self.w.anti_geometric(self.w).anti_geometric(rhs.my),
+			mz: self.w.anti_geometric(self.w).anti_geometric(rhs.mz),
+		}
+	}
+}
+
+// Translator3.sandwich(Line3) -> Line3
+impl Sandwich<Line3> for Translator3 {
+	fn sandwich(self, rhs: Line3) -> Line3 {
+		Line3 {
+			vx: self.w.geometric(self.y).geometric(rhs.mz) * 2.0 + self.w.geometric(self.z).geometric(rhs.my) * 2.0
+				- self.x.geometric(self.x).geometric(rhs.vx)
+				- self.x.geometric(self.y).geometric(rhs.vy) * 2.0
+				- self.x.geometric(self.z).geometric(rhs.vz) * 2.0
+				+ self.y.geometric(self.y).geometric(rhs.vx)
+				+ self.z.geometric(self.z).geometric(rhs.vx),
+			vy: self.w.geometric(self.x).geometric(rhs.mz) * 2.0
+				+ self.w.geometric(self.z).geometric(rhs.mx) * 2.0
+				+ self.x.geometric(self.x).geometric(rhs.vy)
+				+ self.x.geometric(self.y).geometric(rhs.vx) * 2.0
+				- self.y.geometric(self.y).geometric(rhs.vy)
+				- self.y.geometric(self.z).geometric(rhs.vz) * 2.0
+				+ self.z.geometric(self.z).geometric(rhs.vy),
+			vz: self.w.geometric(self.x).geometric(rhs.my) * 2.0
+				+ self.w.geometric(self.y).geometric(rhs.mx) * 2.0
+				+ self.x.geometric(self.x).geometric(rhs.vz)
+				+ self.x.geometric(self.z).geometric(rhs.vx) * 2.0
+				+ self.y.geometric(self.y).geometric(rhs.vz)
+				+ self.y.geometric(self.z).geometric(rhs.vy) * 2.0
+				- self.z.geometric(self.z).geometric(rhs.vz),
+			mx: -self.x.geometric(self.x).geometric(rhs.mx)
+				- self.x.geometric(self.y).geometric(rhs.my) * 2.0
+				- self.x.geometric(self.z).geometric(rhs.mz) * 2.0
+				+ self.y.geometric(self.y).geometric(rhs.mx)
+				+ self.z.geometric(self.z).geometric(rhs.mx),
+			my: self.x.geometric(self.x).geometric(rhs.my) + self.x.geometric(self.y).geometric(rhs.mx) * 2.0
+				- self.y.geometric(self.y).geometric(rhs.my)
+				- self.y.geometric(self.z).geometric(rhs.mz) * 2.0
+				+ self.z.geometric(self.z).geometric(rhs.my),
+			mz: self.x.geometric(self.x).geometric(rhs.mz)
+				+ self.x.geometric(self.z).geometric(rhs.mx) * 2.0
+				+ self.y.geometric(self.y).geometric(rhs.mz)
+				+ self.y.geometric(self.z).geometric(rhs.my) * 2.0
+				- self.z.geometric(self.z).geometric(rhs.mz),
+		}
+	}
+}
+
+// Translator3.anti_sandwich(Line3) -> Line3
+impl AntiSandwich<Line3> for Translator3 {
+	fn anti_sandwich(self, rhs: Line3) -> Line3 {
+		Line3 {
+			vx: self.w.anti_geometric(self.w).anti_geometric(rhs.vx),
+			vy: self.w.anti_geometric(self.w).anti_geometric(rhs.vy),
+			vz: self.w.anti_geometric(self.w).anti_geometric(rhs.vz),
+			mx: self.w.anti_geometric(self.w).anti_geometric(rhs.mx)
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.vz) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.vy) * 2.0,
+			my: self.w.anti_geometric(self.w).anti_geometric(rhs.my)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.vz) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.vx) * 2.0,
+			mz: self.w.anti_geometric(self.w).anti_geometric(rhs.mz)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.vy) * 2.0
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.vx) * 2.0,
+		}
+	}
+}
+
+// Translator3.sandwich(Plane) -> Plane
+impl Sandwich<Plane> for Translator3 {
+	fn sandwich(self, rhs: Plane) -> Plane {
+		Plane {
+			nx: self.w.geometric(self.x).geometric(rhs.d) * -2.0
+				- self.x.geometric(self.x).geometric(rhs.nx)
+				- self.x.geometric(self.y).geometric(rhs.ny) * 2.0
+				- self.x.geometric(self.z).geometric(rhs.nz) * 2.0
+				+ self.y.geometric(self.y).geometric(rhs.nx)
+				+ self.z.geometric(self.z).geometric(rhs.nx),
+			ny: self.w.geometric(self.y).geometric(rhs.d) * -2.0
+				+ self.x.geometric(self.x).geometric(rhs.ny)
+				+ self.x.geometric(self.y).geometric(rhs.nx) * 2.0
+				- self.y.geometric(self.y).geometric(rhs.ny)
+				- self.y.geometric(self.z).geometric(rhs.nz) * 2.0
+				+ self.z.geometric(self.z).geometric(rhs.ny),
+			nz: self.w.geometric(self.z).geometric(rhs.d) * -2.0
+				+ self.x.geometric(self.x).geometric(rhs.nz)
+				+ self.x.geometric(self.z).geometric(rhs.nx) * 2.0
+				+ self.y.geometric(self.y).geometric(rhs.nz)
+				+ self.y.geometric(self.z).geometric(rhs.ny) * 2.0
+				- self.z.geometric(self.z).geometric(rhs.nz),
+			d: -self.x.geometric(self.x).geometric(rhs.d)
+				- self.y.geometric(self.y).geometric(rhs.d)
+				- self.z.geometric(self.z).geometric(rhs.d),
+		}
+	}
+}
+
+// Translator3.anti_sandwich(Plane) -> Plane
+impl AntiSandwich<Plane> for Translator3 {
+	fn anti_sandwich(self, rhs: Plane) -> Plane {
+		Plane {
+			nx: self.w.anti_geometric(self.w).anti_geometric(rhs.nx),
+			ny: self.w.anti_geometric(self.w).anti_geometric(rhs.ny),
+			nz: self.w.anti_geometric(self.w).anti_geometric(rhs.nz),
+			d: self.w.anti_geometric(self.w).anti_geometric(rhs.d)
+				+ self.w.anti_geometric(self.x).anti_geometric(rhs.nx) * 2.0
+				+ self.w.anti_geometric(self.y).anti_geometric(rhs.ny) * 2.0
+				+ self.w.anti_geometric(self.z).anti_geometric(rhs.nz) * 2.0,
+		}
+	}
+}
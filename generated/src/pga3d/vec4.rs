@@ -2,36 +2,168 @@
 //!
 //! ## Operations
 //! ```text
+//! Vec4.geometric(Vec4) -> Motor3
+//! Vec4.anti_geometric(Vec4) -> Translator3
 //! Vec4.dot(Vec4) -> S
 //! Vec4.wedge(Vec4) -> Line3
+//! Vec4.left_contraction(Vec4) -> S
+//! Vec4.right_contraction(Vec4) -> S
+//! Vec4.scalar_product(Vec4) -> S
+//! Vec4.commutator(Vec4) -> Line3
+//! Vec4.anti_dot(Vec4) -> XYZW
+//! Vec4.geometric(Vec3) -> Motor3
+//! Vec3.geometric(Vec4) -> Motor3
 //! Vec4.anti_geometric(Vec3) -> Moment3
 //! Vec3.anti_geometric(Vec4) -> Moment3
 //! Vec4.dot(Vec3) -> S
 //! Vec3.dot(Vec4) -> S
 //! Vec4.wedge(Vec3) -> Line3
 //! Vec3.wedge(Vec4) -> Line3
+//! Vec4.left_contraction(Vec3) -> S
+//! Vec3.left_contraction(Vec4) -> S
+//! Vec4.right_contraction(Vec3) -> S
+//! Vec3.right_contraction(Vec4) -> S
+//! Vec4.scalar_product(Vec3) -> S
+//! Vec3.scalar_product(Vec4) -> S
+//! Vec4.commutator(Vec3) -> Line3
+//! Vec3.commutator(Vec4) -> Line3
+//! Vec4.geometric(Point3) -> Motor3
+//! Point3.geometric(Vec4) -> Motor3
+//! Vec4.anti_geometric(Point3) -> Translator3
+//! Point3.anti_geometric(Vec4) -> Translator3
+//! Vec4.dot(Point3) -> S
+//! Point3.dot(Vec4) -> S
+//! Vec4.wedge(Point3) -> Line3
+//! Point3.wedge(Vec4) -> Line3
+//! Vec4.left_contraction(Point3) -> S
+//! Point3.left_contraction(Vec4) -> S
+//! Vec4.right_contraction(Point3) -> S
+//! Point3.right_contraction(Vec4) -> S
+//! Vec4.scalar_product(Point3) -> S
+//! Point3.scalar_product(Vec4) -> S
+//! Vec4.commutator(Point3) -> Line3
+//! Point3.commutator(Vec4) -> Line3
+//! Vec4.anti_dot(Point3) -> XYZW
+//! Point3.anti_dot(Vec4) -> XYZW
+//! Vec4.geometric(Moment3) -> Multivector
+//! Moment3.geometric(Vec4) -> Multivector
 //! Vec4.anti_geometric(Moment3) -> Vec3
 //! Moment3.anti_geometric(Vec4) -> Vec3
 //! Vec4.dot(Moment3) -> Vec3
 //! Moment3.dot(Vec4) -> Vec3
 //! Vec4.wedge(Moment3) -> Plane
 //! Moment3.wedge(Vec4) -> Plane
+//! Vec4.left_contraction(Moment3) -> Vec3
+//! Moment3.right_contraction(Vec4) -> Vec3
+//! Vec4.commutator(Moment3) -> Vec3
+//! Moment3.commutator(Vec4) -> Vec3
+//! Vec4.geometric(Line3) -> Multivector
+//! Line3.geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Line3) -> Multivector
+//! Line3.anti_geometric(Vec4) -> Multivector
 //! Vec4.dot(Line3) -> Vec4
 //! Line3.dot(Vec4) -> Vec4
 //! Vec4.wedge(Line3) -> Plane
 //! Line3.wedge(Vec4) -> Plane
+//! Vec4.left_contraction(Line3) -> Vec4
+//! Line3.right_contraction(Vec4) -> Vec4
+//! Vec4.commutator(Line3) -> Vec4
+//! Line3.commutator(Vec4) -> Vec4
+//! Vec4.anti_dot(Line3) -> Plane
+//! Line3.anti_dot(Vec4) -> Plane
+//! Vec4.geometric(Plane) -> Motor3
+//! Plane.geometric(Vec4) -> Motor3
+//! Vec4.anti_geometric(Plane) -> Motor3
+//! Plane.anti_geometric(Vec4) -> Motor3
 //! Vec4.dot(Plane) -> Line3
 //! Plane.dot(Vec4) -> Line3
+//! Vec4.wedge(Plane) -> XYZW
+//! Plane.wedge(Vec4) -> XYZW
+//! Vec4.anti_wedge(Plane) -> S
+//! Plane.anti_wedge(Vec4) -> S
+//! Vec4.left_contraction(Plane) -> Line3
+//! Plane.right_contraction(Vec4) -> Line3
+//! Vec4.commutator(Plane) -> XYZW
+//! Plane.commutator(Vec4) -> XYZW
+//! Vec4.anti_dot(Plane) -> Line3
+//! Plane.anti_dot(Vec4) -> Line3
+//! Vec4.geometric(Translator3) -> Multivector
+//! Translator3.geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Translator3) -> Vec4
+//! Translator3.anti_geometric(Vec4) -> Vec4
+//! Vec4.dot(Translator3) -> Multivector
+//! Translator3.dot(Vec4) -> Multivector
+//! Vec4.wedge(Translator3) -> Plane
+//! Translator3.wedge(Vec4) -> Plane
+//! Vec4.anti_wedge(Translator3) -> Vec4
+//! Translator3.anti_wedge(Vec4) -> Vec4
+//! Vec4.left_contraction(Translator3) -> Multivector
+//! Translator3.right_contraction(Vec4) -> Multivector
+//! Vec4.commutator(Translator3) -> Multivector
+//! Translator3.commutator(Vec4) -> Multivector
+//! Vec4.anti_dot(Translator3) -> Vec4
+//! Translator3.anti_dot(Vec4) -> Vec4
+//! Vec4.geometric(Rotor3) -> Multivector
+//! Rotor3.geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Vec4) -> Multivector
+//! Vec4.dot(Rotor3) -> Multivector
+//! Rotor3.dot(Vec4) -> Multivector
 //! Vec4.wedge(Rotor3) -> Plane
 //! Rotor3.wedge(Vec4) -> Plane
 //! Vec4.anti_wedge(Rotor3) -> Vec4
 //! Rotor3.anti_wedge(Vec4) -> Vec4
-//!
+//! Vec4.left_contraction(Rotor3) -> Multivector
+//! Rotor3.right_contraction(Vec4) -> Multivector
+//! Vec4.commutator(Rotor3) -> Multivector
+//! Rotor3.commutator(Vec4) -> Multivector
+//! Vec4.anti_dot(Rotor3) -> Multivector
+//! Rotor3.anti_dot(Vec4) -> Multivector
+//! Vec4.geometric(Motor3) -> Multivector
+//! Motor3.geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Motor3) -> Multivector
+//! Motor3.anti_geometric(Vec4) -> Multivector
+//! Vec4.dot(Motor3) -> Multivector
+//! Motor3.dot(Vec4) -> Multivector
+//! Vec4.wedge(Motor3) -> Multivector
+//! Motor3.wedge(Vec4) -> Multivector
+//! Vec4.anti_wedge(Motor3) -> Vec4
+//! Motor3.anti_wedge(Vec4) -> Vec4
+//! Vec4.left_contraction(Motor3) -> Multivector
+//! Motor3.left_contraction(Vec4) -> Vec4
+//! Vec4.right_contraction(Motor3) -> Vec4
+//! Motor3.right_contraction(Vec4) -> Multivector
+//! Vec4.commutator(Motor3) -> Multivector
+//! Motor3.commutator(Vec4) -> Multivector
+//! Vec4.anti_dot(Motor3) -> Multivector
+//! Motor3.anti_dot(Vec4) -> Multivector
+//! Vec4.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec4) -> Multivector
+//! Vec4.dot(Multivector) -> Multivector
+//! Multivector.dot(Vec4) -> Multivector
+//! Vec4.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Vec4) -> Multivector
+//! Vec4.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec4) -> Multivector
+//! Vec4.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec4) -> Multivector
+//! Vec4.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec4) -> Multivector
+//! Vec4.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Vec4) -> S
+//! Vec4.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Vec4) -> Multivector
+//! Vec4.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec4) -> Multivector
 //! ```
 
 use super::*;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec4 {
 	pub x: X,
 	pub y: Y,
@@ -39,6 +171,73 @@ pub struct Vec4 {
 	pub w: W,
 }
 
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec4 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec4 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Vec4 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.z.abs_diff_eq(&other.z, epsilon)
+			&& self.w.abs_diff_eq(&other.w, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Vec4 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.z.relative_eq(&other.z, epsilon, max_relative)
+			&& self.w.relative_eq(&other.w, epsilon, max_relative)
+	}
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector4<f64>> for Vec4 {
+	fn from(v: mint::Vector4<f64>) -> Self {
+		Vec4 {
+			x: X(v.x),
+			y: Y(v.y),
+			z: Z(v.z),
+			w: W(v.w),
+		}
+	}
+}
+
+#[cfg(feature = "mint")]
+impl From<Vec4> for mint::Vector4<f64> {
+	fn from(v: Vec4) -> Self {
+		mint::Vector4 {
+			x: v.x.0,
+			y: v.y.0,
+			z: v.z.0,
+			w: v.w.0,
+		}
+	}
+}
+
+#[cfg(feature = "mint")]
+impl mint::IntoMint for Vec4 {
+	type MintType = mint::Vector4<f64>;
+}
+
 // ---------------------------------------------------------------------
 
 impl RCompl for Vec4 {
@@ -48,18 +247,25 @@ impl RCompl for Vec4 {
 			nx: self.x.rcompl(),
 			ny: self.y.rcompl(),
 			nz: self.z.rcompl(),
-			d: -self.w.rcompl(),
+			d: self.w.rcompl(),
 		}
 	}
 }
 
+impl std::ops::Not for Vec4 {
+	type Output = Plane;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
 impl LCompl for Vec4 {
 	type Output = Plane;
 	fn lcompl(self) -> Self::Output {
 		Plane {
-			nx: -self.x.lcompl(),
-			ny: -self.y.lcompl(),
-			nz: -self.z.lcompl(),
+			nx: self.x.lcompl(),
+			ny: self.y.lcompl(),
+			nz: self.z.lcompl(),
 			d: self.w.lcompl(),
 		}
 	}
@@ -87,10 +293,324 @@ impl AntiReverse for Vec4 {
 	}
 }
 
+impl Involute for Vec4 {
+	fn involute(self) -> Self {
+		Vec4 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			w: -self.w,
+		}
+	}
+}
+
+impl Conjugate for Vec4 {
+	fn conjugate(self) -> Self {
+		Vec4 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			w: -self.w,
+		}
+	}
+}
+
+impl Dual for Vec4 {
+	type Output = Plane;
+	fn dual(self) -> Self::Output {
+		Plane {
+			nx: self.x.dual(),
+			ny: self.y.dual(),
+			nz: self.z.dual(),
+			d: self.w.dual(),
+		}
+	}
+}
+
+impl Bulk for Vec4 {
+	type Output = Vec3;
+	fn bulk(self) -> Self::Output {
+		Vec3 {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+		}
+	}
+}
+
+impl Weight for Vec4 {
+	type Output = W;
+	fn weight(self) -> Self::Output {
+		self.w
+	}
+}
+
+impl BulkDual for Vec4 {
+	type Output = Plane;
+	fn bulk_dual(self) -> Self::Output {
+		Plane {
+			nx: self.x.bulk_dual(),
+			ny: self.y.bulk_dual(),
+			nz: self.z.bulk_dual(),
+			d: Default::default(),
+		}
+	}
+}
+
+impl WeightDual for Vec4 {
+	type Output = XYZ;
+	fn weight_dual(self) -> Self::Output {
+		self.w.weight_dual()
+	}
+}
+
+impl Square for Vec4 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(self.x.geometric(self.x) + self.y.geometric(self.y) + self.z.geometric(self.z)).0
+	}
+}
+
+impl Norm for Vec4 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0 + self.w.0 * self.w.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		self.w.0 * self.w.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.w.0;
+		Vec4 {
+			x: self.x * scale,
+			y: self.y * scale,
+			z: self.z * scale,
+			w: self.w * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.weight_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Vec4 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.x.0, self.y.0, self.z.0, self.w.0]
+	}
+}
+
+impl From<[f64; 4]> for Vec4 {
+	fn from(a: [f64; 4]) -> Self {
+		Vec4 {
+			x: X(a[0]),
+			y: Y(a[1]),
+			z: Z(a[2]),
+			w: W(a[3]),
+		}
+	}
+}
+
+impl From<Vec4> for [f64; 4] {
+	fn from(v: Vec4) -> Self {
+		v.to_array()
+	}
+}
+
+impl Vec4 {
+	/// A normalized point, i.e. with `w=1`.
+	pub fn point(x: f64, y: f64, z: f64) -> Self {
+		Vec4 {
+			x: X(x),
+			y: Y(y),
+			z: Z(z),
+			w: W(1.0),
+		}
+	}
+
+	/// A direction, a.k.a. a point at infinity, i.e. with `w=0`.
+	pub fn direction(x: f64, y: f64, z: f64) -> Self {
+		Vec4 {
+			x: X(x),
+			y: Y(y),
+			z: Z(z),
+			w: W(0.0),
+		}
+	}
+}
+
+impl From<Vec4> for Multivector {
+	fn from(v: Vec4) -> Self {
+		Multivector {
+			x: v.x,
+			y: v.y,
+			z: v.z,
+			w: v.w,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Vec4 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Vec4` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.wx.0 == 0.0
+			&& mv.wy.0 == 0.0
+			&& mv.wz.0 == 0.0
+			&& mv.yz.0 == 0.0
+			&& mv.zx.0 == 0.0
+			&& mv.xy.0 == 0.0
+			&& mv.yzw.0 == 0.0
+			&& mv.zxw.0 == 0.0
+			&& mv.xyw.0 == 0.0
+			&& mv.xyz.0 == 0.0
+			&& mv.xyzw.0 == 0.0
+		{
+			Ok(Vec4 {
+				x: mv.x,
+				y: mv.y,
+				z: mv.z,
+				w: mv.w,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec4> for glam::DVec4 {
+	fn from(v: Vec4) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0, v.w.0)
+	}
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec4> for Vec4 {
+	fn from(v: glam::DVec4) -> Self {
+		Vec4 {
+			x: X(v.x),
+			y: Y(v.y),
+			z: Z(v.z),
+			w: W(v.w),
+		}
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec4> for nalgebra::Vector4<f64> {
+	fn from(v: Vec4) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0, v.w.0)
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector4<f64>> for Vec4 {
+	fn from(v: nalgebra::Vector4<f64>) -> Self {
+		Vec4 {
+			x: X(v.x),
+			y: Y(v.y),
+			z: Z(v.z),
+			w: W(v.w),
+		}
+	}
+}
+
+#[cfg(feature = "cgmath")]
+impl From<Vec4> for cgmath::Vector4<f64> {
+	fn from(v: Vec4) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0, v.w.0)
+	}
+}
+
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Vector4<f64>> for Vec4 {
+	fn from(v: cgmath::Vector4<f64>) -> Self {
+		Vec4 {
+			x: X(v.x),
+			y: Y(v.y),
+			z: Z(v.z),
+			w: W(v.w),
+		}
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec4> for nalgebra::Point3<f64> {
+	/// The point at `[x / w, y / w, z / w]`.
+	fn from(v: Vec4) -> Self {
+		Self::new(v.x.0 / v.w.0, v.y.0 / v.w.0, v.z.0 / v.w.0)
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Point3<f64>> for Vec4 {
+	/// With `w = 1`.
+	fn from(v: nalgebra::Point3<f64>) -> Self {
+		Vec4 {
+			x: X(v.x),
+			y: Y(v.y),
+			z: Z(v.z),
+			w: W(1.0),
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Vec4 OP Vec3:
 
-// Omitted: Vec4 geometric Vec3 = self.w * rhs.x + self.w * rhs.y + self.w * rhs.z + self.x * rhs.x + self.x * rhs.y + self.x * rhs.z + self.y * rhs.x + self.y * rhs.y + self.y * rhs.z + self.z * rhs.x + self.z * rhs.y + self.z * rhs.z  (unnamed type)
+// Vec4.geometric(Vec3) -> Motor3
+impl Geometric<Vec3> for Vec4 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.x.0),
+		//     ry: WY(self.w.0 * rhs.y.0),
+		//     rz: WZ(self.w.0 * rhs.z.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     uy: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     uz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     uw: S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.w.geometric(rhs.x),
+			ry: self.w.geometric(rhs.y),
+			rz: self.w.geometric(rhs.z),
+			rw: Default::default(),
+			ux: self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			uy: self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			uz: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			uw: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Vec4 {
+	type Output = Motor3;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
 
 // Vec4.anti_geometric(Vec3) -> Moment3
 impl AntiGeometric<Vec3> for Vec4 {
@@ -118,6 +638,13 @@ impl Dot<Vec3> for Vec4 {
 	}
 }
 
+impl std::ops::BitOr<Vec3> for Vec4 {
+	type Output = S;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Vec4.wedge(Vec3) -> Line3
 impl Wedge<Vec3> for Vec4 {
 	type Output = Line3;
@@ -126,227 +653,1537 @@ impl Wedge<Vec3> for Vec4 {
 		//     vx: WX(self.w.0 * rhs.x.0),
 		//     vy: WY(self.w.0 * rhs.y.0),
 		//     vz: WZ(self.w.0 * rhs.z.0),
-		//     mx: YZ(self.y.0 * rhs.z.0) + YZ(self.z.0 * rhs.y.0),
-		//     my: ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
-		//     mz: XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		//     mx: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     my: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     mz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
 		Line3 {
 			vx: self.w.wedge(rhs.x),
 			vy: self.w.wedge(rhs.y),
 			vz: self.w.wedge(rhs.z),
-			mx: self.y.wedge(rhs.z) - self.z.wedge(rhs.y),
-			my: -self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
-			mz: self.x.wedge(rhs.y) - self.y.wedge(rhs.x),
+			mx: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			my: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			mz: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
 		}
 	}
 }
 
-// Omitted: Vec4 anti_wedge Vec3 = 0  (unnamed type)
+impl std::ops::BitXor<Vec3> for Vec4 {
+	type Output = Line3;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
-// ---------------------------------------------------------------------
-// Vec4 OP Vec4:
+impl AntiWedge<Vec3> for Vec4 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Vec4 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-// Omitted: Vec4 geometric Vec4 = self.w * rhs.x + self.w * rhs.y + self.w * rhs.z + self.x * rhs.w + self.x * rhs.x + self.x * rhs.y + self.x * rhs.z + self.y * rhs.w + self.y * rhs.x + self.y * rhs.y + self.y * rhs.z + self.z * rhs.w + self.z * rhs.x + self.z * rhs.y + self.z * rhs.z  (unnamed type)
-// Omitted: Vec4 anti_geometric Vec4 = self.w !* rhs.w + self.w !* rhs.x + self.w !* rhs.y + self.w !* rhs.z + self.x !* rhs.w + self.y !* rhs.w + self.z !* rhs.w  (unnamed type)
+// Vec4.left_contraction(Vec3) -> S
+impl LeftContraction<Vec3> for Vec4 {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z)
+	}
+}
 
-// Vec4.dot(Vec4) -> S
-impl Dot<Vec4> for Vec4 {
+// Vec4.right_contraction(Vec3) -> S
+impl RightContraction<Vec3> for Vec4 {
 	type Output = S;
-	fn dot(self, rhs: Vec4) -> Self::Output {
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
 		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
-		self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z)
 	}
 }
 
-// Vec4.wedge(Vec4) -> Line3
-impl Wedge<Vec4> for Vec4 {
+// Vec4.scalar_product(Vec3) -> S
+impl ScalarProduct<Vec3> for Vec4 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Vec4.commutator(Vec3) -> Line3
+impl Commutator<Vec3> for Vec4 {
 	type Output = Line3;
-	fn wedge(self, rhs: Vec4) -> Self::Output {
+	fn commutator(self, rhs: Vec3) -> Self::Output {
 		// Line3 {
-		//     vx: WX(self.w.0 * rhs.x.0) + WX(self.x.0 * rhs.w.0),
-		//     vy: WY(self.w.0 * rhs.y.0) + WY(self.y.0 * rhs.w.0),
-		//     vz: WZ(self.w.0 * rhs.z.0) + WZ(self.z.0 * rhs.w.0),
-		//     mx: YZ(self.y.0 * rhs.z.0) + YZ(self.z.0 * rhs.y.0),
-		//     my: ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
-		//     mz: XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+		//     vx: WX(self.w.0 * rhs.x.0),
+		//     vy: WY(self.w.0 * rhs.y.0),
+		//     vz: WZ(self.w.0 * rhs.z.0),
+		//     mx: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     my: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     mz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
 		Line3 {
-			vx: self.w.wedge(rhs.x) - self.x.wedge(rhs.w),
-			vy: self.w.wedge(rhs.y) - self.y.wedge(rhs.w),
-			vz: self.w.wedge(rhs.z) - self.z.wedge(rhs.w),
-			mx: self.y.wedge(rhs.z) - self.z.wedge(rhs.y),
-			my: -self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
-			mz: self.x.wedge(rhs.y) - self.y.wedge(rhs.x),
+			vx: self.w.commutator(rhs.x),
+			vy: self.w.commutator(rhs.y),
+			vz: self.w.commutator(rhs.z),
+			mx: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			my: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			mz: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
 		}
 	}
 }
 
-// Omitted: Vec4 anti_wedge Vec4 = 0  (unnamed type)
+impl AntiDot<Vec3> for Vec4 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
 
 // ---------------------------------------------------------------------
-// Vec4 OP Moment3:
-
-// Omitted: Vec4 geometric Moment3 = self.w * rhs.mx + self.w * rhs.my + self.w * rhs.mz + self.x * rhs.mx + self.x * rhs.my + self.x * rhs.mz + self.y * rhs.mx + self.y * rhs.my + self.y * rhs.mz + self.z * rhs.mx + self.z * rhs.my + self.z * rhs.mz  (unnamed type)
+// Vec4 OP Vec4:
 
-// Vec4.anti_geometric(Moment3) -> Vec3
-impl AntiGeometric<Moment3> for Vec4 {
-	type Output = Vec3;
-	fn anti_geometric(self, rhs: Moment3) -> Self::Output {
-		// Vec3 {
-		//     x: X(self.w.0 * rhs.mx.0),
-		//     y: Y(self.w.0 * rhs.my.0),
-		//     z: Z(self.w.0 * rhs.mz.0),
+// Vec4.geometric(Vec4) -> Motor3
+impl Geometric<Vec4> for Vec4 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     ry: WY(self.w.0 * rhs.y.0) - WY(self.y.0 * rhs.w.0),
+		//     rz: WZ(self.w.0 * rhs.z.0) - WZ(self.z.0 * rhs.w.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     uy: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     uz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     uw: S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
 		// }
-		Vec3 {
-			x: -self.w.anti_geometric(rhs.mx),
-			y: -self.w.anti_geometric(rhs.my),
-			z: -self.w.anti_geometric(rhs.mz),
+		Motor3 {
+			rx: self.w.geometric(rhs.x) + self.x.geometric(rhs.w),
+			ry: self.w.geometric(rhs.y) + self.y.geometric(rhs.w),
+			rz: self.w.geometric(rhs.z) + self.z.geometric(rhs.w),
+			rw: Default::default(),
+			ux: self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			uy: self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			uz: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			uw: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
 		}
 	}
 }
 
-// Vec4.dot(Moment3) -> Vec3
-impl Dot<Moment3> for Vec4 {
-	type Output = Vec3;
-	fn dot(self, rhs: Moment3) -> Self::Output {
-		// Vec3 {
-		//     x: X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
-		//     y: Y(self.x.0 * rhs.mz.0) + Y(self.z.0 * rhs.mx.0),
-		//     z: Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
-		// }
-		Vec3 {
-			x: -self.y.dot(rhs.mz) + self.z.dot(rhs.my),
-			y: self.x.dot(rhs.mz) - self.z.dot(rhs.mx),
-			z: -self.x.dot(rhs.my) + self.y.dot(rhs.mx),
-		}
+impl std::ops::Mul<Vec4> for Vec4 {
+	type Output = Motor3;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
 	}
 }
 
-// Vec4.wedge(Moment3) -> Plane
-impl Wedge<Moment3> for Vec4 {
-	type Output = Plane;
-	fn wedge(self, rhs: Moment3) -> Self::Output {
-		// Plane {
-		//     nx: YZW(self.w.0 * rhs.mx.0),
-		//     ny: ZXW(self.w.0 * rhs.my.0),
-		//     nz: XYW(self.w.0 * rhs.mz.0),
-		//     d : XYZ(self.x.0 * rhs.mx.0) + XYZ(self.y.0 * rhs.my.0) + XYZ(self.z.0 * rhs.mz.0),
+// Vec4.anti_geometric(Vec4) -> Translator3
+impl AntiGeometric<Vec4> for Vec4 {
+	type Output = Translator3;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Translator3 {
+		//     x: YZ(self.w.0 * rhs.x.0) - YZ(self.x.0 * rhs.w.0),
+		//     y: ZX(self.w.0 * rhs.y.0) - ZX(self.y.0 * rhs.w.0),
+		//     z: XY(self.w.0 * rhs.z.0) - XY(self.z.0 * rhs.w.0),
+		//     w: -XYZW(self.w.0 * rhs.w.0),
 		// }
-		Plane {
-			nx: self.w.wedge(rhs.mx),
-			ny: self.w.wedge(rhs.my),
-			nz: self.w.wedge(rhs.mz),
-			d: self.x.wedge(rhs.mx) + self.y.wedge(rhs.my) + self.z.wedge(rhs.mz),
+		Translator3 {
+			x: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(rhs.w),
+			y: self.w.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.w),
+			z: self.w.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.w),
+			w: self.w.anti_geometric(rhs.w),
 		}
 	}
 }
 
-// Omitted: Vec4 anti_wedge Moment3 = 0  (unnamed type)
-
-// ---------------------------------------------------------------------
-// Vec4 OP Line3:
+// Vec4.dot(Vec4) -> S
+impl Dot<Vec4> for Vec4 {
+	type Output = S;
+	fn dot(self, rhs: Vec4) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z)
+	}
+}
 
-// Omitted: Vec4 geometric Line3 = self.w * rhs.mx + self.w * rhs.my + self.w * rhs.mz + self.x * rhs.mx + self.x * rhs.my + self.x * rhs.mz + self.x * rhs.vx + self.x * rhs.vy + self.x * rhs.vz + self.y * rhs.mx + self.y * rhs.my + self.y * rhs.mz + self.y * rhs.vx + self.y * rhs.vy + self.y * rhs.vz + self.z * rhs.mx + self.z * rhs.my + self.z * rhs.mz + self.z * rhs.vx + self.z * rhs.vy + self.z * rhs.vz  (unnamed type)
-// Omitted: Vec4 anti_geometric Line3 = self.w !* rhs.mx + self.w !* rhs.my + self.w !* rhs.mz + self.w !* rhs.vx + self.w !* rhs.vy + self.w !* rhs.vz + self.x !* rhs.vx + self.x !* rhs.vy + self.x !* rhs.vz + self.y !* rhs.vx + self.y !* rhs.vy + self.y !* rhs.vz + self.z !* rhs.vx + self.z !* rhs.vy + self.z !* rhs.vz  (unnamed type)
+impl std::ops::BitOr<Vec4> for Vec4 {
+	type Output = S;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
 
-// Vec4.dot(Line3) -> Vec4
-impl Dot<Line3> for Vec4 {
-	type Output = Vec4;
-	fn dot(self, rhs: Line3) -> Self::Output {
-		// Vec4 {
-		//     x: X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
-		//     y: Y(self.x.0 * rhs.mz.0) + Y(self.z.0 * rhs.mx.0),
-		//     z: Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
-		//     w: W(self.x.0 * rhs.vx.0) + W(self.y.0 * rhs.vy.0) + W(self.z.0 * rhs.vz.0),
+// Vec4.wedge(Vec4) -> Line3
+impl Wedge<Vec4> for Vec4 {
+	type Output = Line3;
+	fn wedge(self, rhs: Vec4) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     vy: WY(self.w.0 * rhs.y.0) - WY(self.y.0 * rhs.w.0),
+		//     vz: WZ(self.w.0 * rhs.z.0) - WZ(self.z.0 * rhs.w.0),
+		//     mx: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     my: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     mz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
 		// }
-		Vec4 {
-			x: -self.y.dot(rhs.mz) + self.z.dot(rhs.my),
-			y: self.x.dot(rhs.mz) - self.z.dot(rhs.mx),
-			z: -self.x.dot(rhs.my) + self.y.dot(rhs.mx),
-			w: -self.x.dot(rhs.vx) - self.y.dot(rhs.vy) - self.z.dot(rhs.vz),
+		Line3 {
+			vx: self.w.wedge(rhs.x) + self.x.wedge(rhs.w),
+			vy: self.w.wedge(rhs.y) + self.y.wedge(rhs.w),
+			vz: self.w.wedge(rhs.z) + self.z.wedge(rhs.w),
+			mx: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			my: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			mz: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
 		}
 	}
 }
 
+impl std::ops::BitXor<Vec4> for Vec4 {
+	type Output = Line3;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec4> for Vec4 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec4> for Vec4 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Vec4) -> S
+impl LeftContraction<Vec4> for Vec4 {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec4) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z)
+	}
+}
+
+// Vec4.right_contraction(Vec4) -> S
+impl RightContraction<Vec4> for Vec4 {
+	type Output = S;
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z)
+	}
+}
+
+// Vec4.scalar_product(Vec4) -> S
+impl ScalarProduct<Vec4> for Vec4 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec4) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Vec4.commutator(Vec4) -> Line3
+impl Commutator<Vec4> for Vec4 {
+	type Output = Line3;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     vy: WY(self.w.0 * rhs.y.0) - WY(self.y.0 * rhs.w.0),
+		//     vz: WZ(self.w.0 * rhs.z.0) - WZ(self.z.0 * rhs.w.0),
+		//     mx: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     my: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     mz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line3 {
+			vx: self.w.commutator(rhs.x) + self.x.commutator(rhs.w),
+			vy: self.w.commutator(rhs.y) + self.y.commutator(rhs.w),
+			vz: self.w.commutator(rhs.z) + self.z.commutator(rhs.w),
+			mx: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			my: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			mz: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+		}
+	}
+}
+
+// Vec4.anti_dot(Vec4) -> XYZW
+impl AntiDot<Vec4> for Vec4 {
+	type Output = XYZW;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// -XYZW(self.w.0 * rhs.w.0)
+		self.w.anti_dot(rhs.w)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec4 OP Point3:
+
+// Vec4.geometric(Point3) -> Motor3
+impl Geometric<Point3> for Vec4 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Point3) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.w.0 * rhs.x.0) - WX(self.x.0),
+		//     ry: WY(self.w.0 * rhs.y.0) - WY(self.y.0),
+		//     rz: WZ(self.w.0 * rhs.z.0) - WZ(self.z.0),
+		//     rw: Default::default(),
+		//     ux: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     uy: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     uz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     uw: S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		// }
+		Motor3 {
+			rx: self.w.geometric(rhs.x) + self.x.geometric(W(1.0)),
+			ry: self.w.geometric(rhs.y) + self.y.geometric(W(1.0)),
+			rz: self.w.geometric(rhs.z) + self.z.geometric(W(1.0)),
+			rw: Default::default(),
+			ux: self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			uy: self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			uz: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			uw: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+		}
+	}
+}
+
+impl std::ops::Mul<Point3> for Vec4 {
+	type Output = Motor3;
+	fn mul(self, rhs: Point3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Point3) -> Translator3
+impl AntiGeometric<Point3> for Vec4 {
+	type Output = Translator3;
+	fn anti_geometric(self, rhs: Point3) -> Self::Output {
+		// Translator3 {
+		//     x: YZ(self.w.0 * rhs.x.0) - YZ(self.x.0),
+		//     y: ZX(self.w.0 * rhs.y.0) - ZX(self.y.0),
+		//     z: XY(self.w.0 * rhs.z.0) - XY(self.z.0),
+		//     w: -XYZW(self.w.0),
+		// }
+		Translator3 {
+			x: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(W(1.0)),
+			y: self.w.anti_geometric(rhs.y) + self.y.anti_geometric(W(1.0)),
+			z: self.w.anti_geometric(rhs.z) + self.z.anti_geometric(W(1.0)),
+			w: self.w.anti_geometric(W(1.0)),
+		}
+	}
+}
+
+// Vec4.dot(Point3) -> S
+impl Dot<Point3> for Vec4 {
+	type Output = S;
+	fn dot(self, rhs: Point3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z)
+	}
+}
+
+impl std::ops::BitOr<Point3> for Vec4 {
+	type Output = S;
+	fn bitor(self, rhs: Point3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec4.wedge(Point3) -> Line3
+impl Wedge<Point3> for Vec4 {
+	type Output = Line3;
+	fn wedge(self, rhs: Point3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.x.0) - WX(self.x.0),
+		//     vy: WY(self.w.0 * rhs.y.0) - WY(self.y.0),
+		//     vz: WZ(self.w.0 * rhs.z.0) - WZ(self.z.0),
+		//     mx: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     my: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     mz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line3 {
+			vx: self.w.wedge(rhs.x) + self.x.wedge(W(1.0)),
+			vy: self.w.wedge(rhs.y) + self.y.wedge(W(1.0)),
+			vz: self.w.wedge(rhs.z) + self.z.wedge(W(1.0)),
+			mx: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			my: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			mz: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Point3> for Vec4 {
+	type Output = Line3;
+	fn bitxor(self, rhs: Point3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Point3> for Vec4 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Point3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Point3> for Vec4 {
+	type Output = Zero;
+	fn bitand(self, rhs: Point3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Point3) -> S
+impl LeftContraction<Point3> for Vec4 {
+	type Output = S;
+	fn left_contraction(self, rhs: Point3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z)
+	}
+}
+
+// Vec4.right_contraction(Point3) -> S
+impl RightContraction<Point3> for Vec4 {
+	type Output = S;
+	fn right_contraction(self, rhs: Point3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z)
+	}
+}
+
+// Vec4.scalar_product(Point3) -> S
+impl ScalarProduct<Point3> for Vec4 {
+	type Output = S;
+	fn scalar_product(self, rhs: Point3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Vec4.commutator(Point3) -> Line3
+impl Commutator<Point3> for Vec4 {
+	type Output = Line3;
+	fn commutator(self, rhs: Point3) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.w.0 * rhs.x.0) - WX(self.x.0),
+		//     vy: WY(self.w.0 * rhs.y.0) - WY(self.y.0),
+		//     vz: WZ(self.w.0 * rhs.z.0) - WZ(self.z.0),
+		//     mx: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     my: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     mz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Line3 {
+			vx: self.w.commutator(rhs.x) + self.x.commutator(W(1.0)),
+			vy: self.w.commutator(rhs.y) + self.y.commutator(W(1.0)),
+			vz: self.w.commutator(rhs.z) + self.z.commutator(W(1.0)),
+			mx: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			my: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			mz: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+		}
+	}
+}
+
+// Vec4.anti_dot(Point3) -> XYZW
+impl AntiDot<Point3> for Vec4 {
+	type Output = XYZW;
+	fn anti_dot(self, _rhs: Point3) -> Self::Output {
+		// -XYZW(self.w.0)
+		self.w.anti_dot(W(1.0))
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec4 OP Moment3:
+
+// Vec4.geometric(Moment3) -> Multivector
+impl Geometric<Moment3> for Vec4 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Moment3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y   : Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z   : -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.mx.0),
+		//     zxw : ZXW(self.w.0 * rhs.my.0),
+		//     xyw : XYW(self.w.0 * rhs.mz.0),
+		//     xyz : XYZ(self.x.0 * rhs.mx.0) + XYZ(self.y.0 * rhs.my.0) + XYZ(self.z.0 * rhs.mz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.geometric(rhs.mz) + self.z.geometric(rhs.my),
+			y: self.x.geometric(rhs.mz) + self.z.geometric(rhs.mx),
+			z: self.x.geometric(rhs.my) + self.y.geometric(rhs.mx),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.mx),
+			zxw: self.w.geometric(rhs.my),
+			xyw: self.w.geometric(rhs.mz),
+			xyz: self.x.geometric(rhs.mx) + self.y.geometric(rhs.my) + self.z.geometric(rhs.mz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Moment3> for Vec4 {
+	type Output = Multivector;
+	fn mul(self, rhs: Moment3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Moment3) -> Vec3
+impl AntiGeometric<Moment3> for Vec4 {
+	type Output = Vec3;
+	fn anti_geometric(self, rhs: Moment3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.w.0 * rhs.mx.0),
+		//     y: -Y(self.w.0 * rhs.my.0),
+		//     z: -Z(self.w.0 * rhs.mz.0),
+		// }
+		Vec3 {
+			x: self.w.anti_geometric(rhs.mx),
+			y: self.w.anti_geometric(rhs.my),
+			z: self.w.anti_geometric(rhs.mz),
+		}
+	}
+}
+
+// Vec4.dot(Moment3) -> Vec3
+impl Dot<Moment3> for Vec4 {
+	type Output = Vec3;
+	fn dot(self, rhs: Moment3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y: Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z: -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		// }
+		Vec3 {
+			x: self.y.dot(rhs.mz) + self.z.dot(rhs.my),
+			y: self.x.dot(rhs.mz) + self.z.dot(rhs.mx),
+			z: self.x.dot(rhs.my) + self.y.dot(rhs.mx),
+		}
+	}
+}
+
+impl std::ops::BitOr<Moment3> for Vec4 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Moment3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec4.wedge(Moment3) -> Plane
+impl Wedge<Moment3> for Vec4 {
+	type Output = Plane;
+	fn wedge(self, rhs: Moment3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.w.0 * rhs.mx.0),
+		//     ny: ZXW(self.w.0 * rhs.my.0),
+		//     nz: XYW(self.w.0 * rhs.mz.0),
+		//     d : XYZ(self.x.0 * rhs.mx.0) + XYZ(self.y.0 * rhs.my.0) + XYZ(self.z.0 * rhs.mz.0),
+		// }
+		Plane {
+			nx: self.w.wedge(rhs.mx),
+			ny: self.w.wedge(rhs.my),
+			nz: self.w.wedge(rhs.mz),
+			d: self.x.wedge(rhs.mx) + self.y.wedge(rhs.my) + self.z.wedge(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Moment3> for Vec4 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Moment3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Moment3> for Vec4 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Moment3> for Vec4 {
+	type Output = Zero;
+	fn bitand(self, rhs: Moment3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Moment3) -> Vec3
+impl LeftContraction<Moment3> for Vec4 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Moment3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y: Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z: -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		// }
+		Vec3 {
+			x: self.y.left_contraction(rhs.mz) + self.z.left_contraction(rhs.my),
+			y: self.x.left_contraction(rhs.mz) + self.z.left_contraction(rhs.mx),
+			z: self.x.left_contraction(rhs.my) + self.y.left_contraction(rhs.mx),
+		}
+	}
+}
+
+impl RightContraction<Moment3> for Vec4 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Moment3> for Vec4 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec4.commutator(Moment3) -> Vec3
+impl Commutator<Moment3> for Vec4 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Moment3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y: Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z: -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		// }
+		Vec3 {
+			x: self.y.commutator(rhs.mz) + self.z.commutator(rhs.my),
+			y: self.x.commutator(rhs.mz) + self.z.commutator(rhs.mx),
+			z: self.x.commutator(rhs.my) + self.y.commutator(rhs.mx),
+		}
+	}
+}
+
+impl AntiDot<Moment3> for Vec4 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Moment3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec4 OP Line3:
+
+// Vec4.geometric(Line3) -> Multivector
+impl Geometric<Line3> for Vec4 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y   : Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z   : -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		//     w   : -W(self.x.0 * rhs.vx.0) - W(self.y.0 * rhs.vy.0) - W(self.z.0 * rhs.vz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.mx.0) - YZW(self.y.0 * rhs.vz.0) + YZW(self.z.0 * rhs.vy.0),
+		//     zxw : ZXW(self.w.0 * rhs.my.0) + ZXW(self.x.0 * rhs.vz.0) - ZXW(self.z.0 * rhs.vx.0),
+		//     xyw : XYW(self.w.0 * rhs.mz.0) - XYW(self.x.0 * rhs.vy.0) + XYW(self.y.0 * rhs.vx.0),
+		//     xyz : XYZ(self.x.0 * rhs.mx.0) + XYZ(self.y.0 * rhs.my.0) + XYZ(self.z.0 * rhs.mz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.geometric(rhs.mz) + self.z.geometric(rhs.my),
+			y: self.x.geometric(rhs.mz) + self.z.geometric(rhs.mx),
+			z: self.x.geometric(rhs.my) + self.y.geometric(rhs.mx),
+			w: self.x.geometric(rhs.vx) + self.y.geometric(rhs.vy) + self.z.geometric(rhs.vz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.mx) + self.y.geometric(rhs.vz) + self.z.geometric(rhs.vy),
+			zxw: self.w.geometric(rhs.my) + self.x.geometric(rhs.vz) + self.z.geometric(rhs.vx),
+			xyw: self.w.geometric(rhs.mz) + self.x.geometric(rhs.vy) + self.y.geometric(rhs.vx),
+			xyz: self.x.geometric(rhs.mx) + self.y.geometric(rhs.my) + self.z.geometric(rhs.mz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Line3> for Vec4 {
+	type Output = Multivector;
+	fn mul(self, rhs: Line3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Line3) -> Multivector
+impl AntiGeometric<Line3> for Vec4 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Line3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.w.0 * rhs.mx.0) + X(self.y.0 * rhs.vz.0) - X(self.z.0 * rhs.vy.0),
+		//     y   : -Y(self.w.0 * rhs.my.0) - Y(self.x.0 * rhs.vz.0) + Y(self.z.0 * rhs.vx.0),
+		//     z   : -Z(self.w.0 * rhs.mz.0) + Z(self.x.0 * rhs.vy.0) - Z(self.y.0 * rhs.vx.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.vx.0),
+		//     zxw : ZXW(self.w.0 * rhs.vy.0),
+		//     xyw : XYW(self.w.0 * rhs.vz.0),
+		//     xyz : XYZ(self.x.0 * rhs.vx.0) + XYZ(self.y.0 * rhs.vy.0) + XYZ(self.z.0 * rhs.vz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.w.anti_geometric(rhs.mx) + self.y.anti_geometric(rhs.vz) + self.z.anti_geometric(rhs.vy),
+			y: self.w.anti_geometric(rhs.my) + self.x.anti_geometric(rhs.vz) + self.z.anti_geometric(rhs.vx),
+			z: self.w.anti_geometric(rhs.mz) + self.x.anti_geometric(rhs.vy) + self.y.anti_geometric(rhs.vx),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.anti_geometric(rhs.vx),
+			zxw: self.w.anti_geometric(rhs.vy),
+			xyw: self.w.anti_geometric(rhs.vz),
+			xyz: self.x.anti_geometric(rhs.vx) + self.y.anti_geometric(rhs.vy) + self.z.anti_geometric(rhs.vz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Vec4.dot(Line3) -> Vec4
+impl Dot<Line3> for Vec4 {
+	type Output = Vec4;
+	fn dot(self, rhs: Line3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y: Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z: -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		//     w: -W(self.x.0 * rhs.vx.0) - W(self.y.0 * rhs.vy.0) - W(self.z.0 * rhs.vz.0),
+		// }
+		Vec4 {
+			x: self.y.dot(rhs.mz) + self.z.dot(rhs.my),
+			y: self.x.dot(rhs.mz) + self.z.dot(rhs.mx),
+			z: self.x.dot(rhs.my) + self.y.dot(rhs.mx),
+			w: self.x.dot(rhs.vx) + self.y.dot(rhs.vy) + self.z.dot(rhs.vz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Line3> for Vec4 {
+	type Output = Vec4;
+	fn bitor(self, rhs: Line3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
 // Vec4.wedge(Line3) -> Plane
 impl Wedge<Line3> for Vec4 {
 	type Output = Plane;
-	fn wedge(self, rhs: Line3) -> Self::Output {
+	fn wedge(self, rhs: Line3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.w.0 * rhs.mx.0) - YZW(self.y.0 * rhs.vz.0) + YZW(self.z.0 * rhs.vy.0),
+		//     ny: ZXW(self.w.0 * rhs.my.0) + ZXW(self.x.0 * rhs.vz.0) - ZXW(self.z.0 * rhs.vx.0),
+		//     nz: XYW(self.w.0 * rhs.mz.0) - XYW(self.x.0 * rhs.vy.0) + XYW(self.y.0 * rhs.vx.0),
+		//     d : XYZ(self.x.0 * rhs.mx.0) + XYZ(self.y.0 * rhs.my.0) + XYZ(self.z.0 * rhs.mz.0),
+		// }
+		Plane {
+			nx: self.w.wedge(rhs.mx) + self.y.wedge(rhs.vz) + self.z.wedge(rhs.vy),
+			ny: self.w.wedge(rhs.my) + self.x.wedge(rhs.vz) + self.z.wedge(rhs.vx),
+			nz: self.w.wedge(rhs.mz) + self.x.wedge(rhs.vy) + self.y.wedge(rhs.vx),
+			d: self.x.wedge(rhs.mx) + self.y.wedge(rhs.my) + self.z.wedge(rhs.mz),
+		}
+	}
+}
+
+impl std::ops::BitXor<Line3> for Vec4 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Line3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Line3> for Vec4 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Line3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Line3> for Vec4 {
+	type Output = Zero;
+	fn bitand(self, rhs: Line3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Line3) -> Vec4
+impl LeftContraction<Line3> for Vec4 {
+	type Output = Vec4;
+	fn left_contraction(self, rhs: Line3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y: Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z: -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		//     w: -W(self.x.0 * rhs.vx.0) - W(self.y.0 * rhs.vy.0) - W(self.z.0 * rhs.vz.0),
+		// }
+		Vec4 {
+			x: self.y.left_contraction(rhs.mz) + self.z.left_contraction(rhs.my),
+			y: self.x.left_contraction(rhs.mz) + self.z.left_contraction(rhs.mx),
+			z: self.x.left_contraction(rhs.my) + self.y.left_contraction(rhs.mx),
+			w: self.x.left_contraction(rhs.vx) + self.y.left_contraction(rhs.vy) + self.z.left_contraction(rhs.vz),
+		}
+	}
+}
+
+impl RightContraction<Line3> for Vec4 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Line3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Line3> for Vec4 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Line3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec4.commutator(Line3) -> Vec4
+impl Commutator<Line3> for Vec4 {
+	type Output = Vec4;
+	fn commutator(self, rhs: Line3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.y.0 * rhs.mz.0) + X(self.z.0 * rhs.my.0),
+		//     y: Y(self.x.0 * rhs.mz.0) - Y(self.z.0 * rhs.mx.0),
+		//     z: -Z(self.x.0 * rhs.my.0) + Z(self.y.0 * rhs.mx.0),
+		//     w: -W(self.x.0 * rhs.vx.0) - W(self.y.0 * rhs.vy.0) - W(self.z.0 * rhs.vz.0),
+		// }
+		Vec4 {
+			x: self.y.commutator(rhs.mz) + self.z.commutator(rhs.my),
+			y: self.x.commutator(rhs.mz) + self.z.commutator(rhs.mx),
+			z: self.x.commutator(rhs.my) + self.y.commutator(rhs.mx),
+			w: self.x.commutator(rhs.vx) + self.y.commutator(rhs.vy) + self.z.commutator(rhs.vz),
+		}
+	}
+}
+
+// Vec4.anti_dot(Line3) -> Plane
+impl AntiDot<Line3> for Vec4 {
+	type Output = Plane;
+	fn anti_dot(self, rhs: Line3) -> Self::Output {
+		// Plane {
+		//     nx: YZW(self.w.0 * rhs.vx.0),
+		//     ny: ZXW(self.w.0 * rhs.vy.0),
+		//     nz: XYW(self.w.0 * rhs.vz.0),
+		//     d : XYZ(self.x.0 * rhs.vx.0) + XYZ(self.y.0 * rhs.vy.0) + XYZ(self.z.0 * rhs.vz.0),
+		// }
+		Plane {
+			nx: self.w.anti_dot(rhs.vx),
+			ny: self.w.anti_dot(rhs.vy),
+			nz: self.w.anti_dot(rhs.vz),
+			d: self.x.anti_dot(rhs.vx) + self.y.anti_dot(rhs.vy) + self.z.anti_dot(rhs.vz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec4 OP Plane:
+
+// Vec4.geometric(Plane) -> Motor3
+impl Geometric<Plane> for Vec4 {
+	type Output = Motor3;
+	fn geometric(self, rhs: Plane) -> Self::Output {
+		// Motor3 {
+		//     rx: WX(self.y.0 * rhs.nz.0) - WX(self.z.0 * rhs.ny.0),
+		//     ry: -WY(self.x.0 * rhs.nz.0) + WY(self.z.0 * rhs.nx.0),
+		//     rz: WZ(self.x.0 * rhs.ny.0) - WZ(self.y.0 * rhs.nx.0),
+		//     rw: -XYZW(self.w.0 * rhs.d.0) + XYZW(self.x.0 * rhs.nx.0) + XYZW(self.y.0 * rhs.ny.0) + XYZW(self.z.0 * rhs.nz.0),
+		//     ux: YZ(self.x.0 * rhs.d.0),
+		//     uy: ZX(self.y.0 * rhs.d.0),
+		//     uz: XY(self.z.0 * rhs.d.0),
+		//     uw: Default::default(),
+		// }
+		Motor3 {
+			rx: self.y.geometric(rhs.nz) + self.z.geometric(rhs.ny),
+			ry: self.x.geometric(rhs.nz) + self.z.geometric(rhs.nx),
+			rz: self.x.geometric(rhs.ny) + self.y.geometric(rhs.nx),
+			rw: self.w.geometric(rhs.d)
+				+ self.x.geometric(rhs.nx)
+				+ self.y.geometric(rhs.ny)
+				+ self.z.geometric(rhs.nz),
+			ux: self.x.geometric(rhs.d),
+			uy: self.y.geometric(rhs.d),
+			uz: self.z.geometric(rhs.d),
+			uw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Plane> for Vec4 {
+	type Output = Motor3;
+	fn mul(self, rhs: Plane) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Plane) -> Motor3
+impl AntiGeometric<Plane> for Vec4 {
+	type Output = Motor3;
+	fn anti_geometric(self, rhs: Plane) -> Self::Output {
+		// Motor3 {
+		//     rx: -WX(self.w.0 * rhs.nx.0),
+		//     ry: -WY(self.w.0 * rhs.ny.0),
+		//     rz: -WZ(self.w.0 * rhs.nz.0),
+		//     rw: Default::default(),
+		//     ux: -YZ(self.y.0 * rhs.nz.0) + YZ(self.z.0 * rhs.ny.0),
+		//     uy: ZX(self.x.0 * rhs.nz.0) - ZX(self.z.0 * rhs.nx.0),
+		//     uz: -XY(self.x.0 * rhs.ny.0) + XY(self.y.0 * rhs.nx.0),
+		//     uw: -S(self.w.0 * rhs.d.0) + S(self.x.0 * rhs.nx.0) + S(self.y.0 * rhs.ny.0) + S(self.z.0 * rhs.nz.0),
+		// }
+		Motor3 {
+			rx: self.w.anti_geometric(rhs.nx),
+			ry: self.w.anti_geometric(rhs.ny),
+			rz: self.w.anti_geometric(rhs.nz),
+			rw: Default::default(),
+			ux: self.y.anti_geometric(rhs.nz) + self.z.anti_geometric(rhs.ny),
+			uy: self.x.anti_geometric(rhs.nz) + self.z.anti_geometric(rhs.nx),
+			uz: self.x.anti_geometric(rhs.ny) + self.y.anti_geometric(rhs.nx),
+			uw: self.w.anti_geometric(rhs.d)
+				+ self.x.anti_geometric(rhs.nx)
+				+ self.y.anti_geometric(rhs.ny)
+				+ self.z.anti_geometric(rhs.nz),
+		}
+	}
+}
+
+// Vec4.dot(Plane) -> Line3
+impl Dot<Plane> for Vec4 {
+	type Output = Line3;
+	fn dot(self, rhs: Plane) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.y.0 * rhs.nz.0) - WX(self.z.0 * rhs.ny.0),
+		//     vy: -WY(self.x.0 * rhs.nz.0) + WY(self.z.0 * rhs.nx.0),
+		//     vz: WZ(self.x.0 * rhs.ny.0) - WZ(self.y.0 * rhs.nx.0),
+		//     mx: YZ(self.x.0 * rhs.d.0),
+		//     my: ZX(self.y.0 * rhs.d.0),
+		//     mz: XY(self.z.0 * rhs.d.0),
+		// }
+		Line3 {
+			vx: self.y.dot(rhs.nz) + self.z.dot(rhs.ny),
+			vy: self.x.dot(rhs.nz) + self.z.dot(rhs.nx),
+			vz: self.x.dot(rhs.ny) + self.y.dot(rhs.nx),
+			mx: self.x.dot(rhs.d),
+			my: self.y.dot(rhs.d),
+			mz: self.z.dot(rhs.d),
+		}
+	}
+}
+
+impl std::ops::BitOr<Plane> for Vec4 {
+	type Output = Line3;
+	fn bitor(self, rhs: Plane) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec4.wedge(Plane) -> XYZW
+impl Wedge<Plane> for Vec4 {
+	type Output = XYZW;
+	fn wedge(self, rhs: Plane) -> Self::Output {
+		// -XYZW(self.w.0 * rhs.d.0) + XYZW(self.x.0 * rhs.nx.0) + XYZW(self.y.0 * rhs.ny.0) + XYZW(self.z.0 * rhs.nz.0)
+		self.w.wedge(rhs.d) + self.x.wedge(rhs.nx) + self.y.wedge(rhs.ny) + self.z.wedge(rhs.nz)
+	}
+}
+
+impl std::ops::BitXor<Plane> for Vec4 {
+	type Output = XYZW;
+	fn bitxor(self, rhs: Plane) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec4.anti_wedge(Plane) -> S
+impl AntiWedge<Plane> for Vec4 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Plane) -> Self::Output {
+		// -S(self.w.0 * rhs.d.0) + S(self.x.0 * rhs.nx.0) + S(self.y.0 * rhs.ny.0) + S(self.z.0 * rhs.nz.0)
+		self.w.anti_wedge(rhs.d) + self.x.anti_wedge(rhs.nx) + self.y.anti_wedge(rhs.ny) + self.z.anti_wedge(rhs.nz)
+	}
+}
+
+impl std::ops::BitAnd<Plane> for Vec4 {
+	type Output = S;
+	fn bitand(self, rhs: Plane) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Plane) -> Line3
+impl LeftContraction<Plane> for Vec4 {
+	type Output = Line3;
+	fn left_contraction(self, rhs: Plane) -> Self::Output {
+		// Line3 {
+		//     vx: WX(self.y.0 * rhs.nz.0) - WX(self.z.0 * rhs.ny.0),
+		//     vy: -WY(self.x.0 * rhs.nz.0) + WY(self.z.0 * rhs.nx.0),
+		//     vz: WZ(self.x.0 * rhs.ny.0) - WZ(self.y.0 * rhs.nx.0),
+		//     mx: YZ(self.x.0 * rhs.d.0),
+		//     my: ZX(self.y.0 * rhs.d.0),
+		//     mz: XY(self.z.0 * rhs.d.0),
+		// }
+		Line3 {
+			vx: self.y.left_contraction(rhs.nz) + self.z.left_contraction(rhs.ny),
+			vy: self.x.left_contraction(rhs.nz) + self.z.left_contraction(rhs.nx),
+			vz: self.x.left_contraction(rhs.ny) + self.y.left_contraction(rhs.nx),
+			mx: self.x.left_contraction(rhs.d),
+			my: self.y.left_contraction(rhs.d),
+			mz: self.z.left_contraction(rhs.d),
+		}
+	}
+}
+
+impl RightContraction<Plane> for Vec4 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Plane> for Vec4 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Plane) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec4.commutator(Plane) -> XYZW
+impl Commutator<Plane> for Vec4 {
+	type Output = XYZW;
+	fn commutator(self, rhs: Plane) -> Self::Output {
+		// -XYZW(self.w.0 * rhs.d.0) + XYZW(self.x.0 * rhs.nx.0) + XYZW(self.y.0 * rhs.ny.0) + XYZW(self.z.0 * rhs.nz.0)
+		self.w.commutator(rhs.d) + self.x.commutator(rhs.nx) + self.y.commutator(rhs.ny) + self.z.commutator(rhs.nz)
+	}
+}
+
+// Vec4.anti_dot(Plane) -> Line3
+impl AntiDot<Plane> for Vec4 {
+	type Output = Line3;
+	fn anti_dot(self, rhs: Plane) -> Self::Output {
+		// Line3 {
+		//     vx: -WX(self.w.0 * rhs.nx.0),
+		//     vy: -WY(self.w.0 * rhs.ny.0),
+		//     vz: -WZ(self.w.0 * rhs.nz.0),
+		//     mx: -YZ(self.y.0 * rhs.nz.0) + YZ(self.z.0 * rhs.ny.0),
+		//     my: ZX(self.x.0 * rhs.nz.0) - ZX(self.z.0 * rhs.nx.0),
+		//     mz: -XY(self.x.0 * rhs.ny.0) + XY(self.y.0 * rhs.nx.0),
+		// }
+		Line3 {
+			vx: self.w.anti_dot(rhs.nx),
+			vy: self.w.anti_dot(rhs.ny),
+			vz: self.w.anti_dot(rhs.nz),
+			mx: self.y.anti_dot(rhs.nz) + self.z.anti_dot(rhs.ny),
+			my: self.x.anti_dot(rhs.nz) + self.z.anti_dot(rhs.nx),
+			mz: self.x.anti_dot(rhs.ny) + self.y.anti_dot(rhs.nx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec4 OP Translator3:
+
+// Vec4.geometric(Translator3) -> Multivector
+impl Geometric<Translator3> for Vec4 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.x.0) + YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.w.0 * rhs.y.0) + ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.w.0 * rhs.z.0) + XYW(self.z.0 * rhs.w.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			y: self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			z: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.x) + self.x.geometric(rhs.w),
+			zxw: self.w.geometric(rhs.y) + self.y.geometric(rhs.w),
+			xyw: self.w.geometric(rhs.z) + self.z.geometric(rhs.w),
+			xyz: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Translator3> for Vec4 {
+	type Output = Multivector;
+	fn mul(self, rhs: Translator3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Translator3) -> Vec4
+impl AntiGeometric<Translator3> for Vec4 {
+	type Output = Vec4;
+	fn anti_geometric(self, rhs: Translator3) -> Self::Output {
+		// Vec4 {
+		//     x: -X(self.w.0 * rhs.x.0) + X(self.x.0 * rhs.w.0),
+		//     y: -Y(self.w.0 * rhs.y.0) + Y(self.y.0 * rhs.w.0),
+		//     z: -Z(self.w.0 * rhs.z.0) + Z(self.z.0 * rhs.w.0),
+		//     w: W(self.w.0 * rhs.w.0),
+		// }
+		Vec4 {
+			x: self.w.anti_geometric(rhs.x) + self.x.anti_geometric(rhs.w),
+			y: self.w.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.w),
+			z: self.w.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.w),
+			w: self.w.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Vec4.dot(Translator3) -> Multivector
+impl Dot<Translator3> for Vec4 {
+	type Output = Multivector;
+	fn dot(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.dot(rhs.z) + self.z.dot(rhs.y),
+			y: self.x.dot(rhs.z) + self.z.dot(rhs.x),
+			z: self.x.dot(rhs.y) + self.y.dot(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.dot(rhs.w),
+			zxw: self.y.dot(rhs.w),
+			xyw: self.z.dot(rhs.w),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Translator3> for Vec4 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Translator3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec4.wedge(Translator3) -> Plane
+impl Wedge<Translator3> for Vec4 {
+	type Output = Plane;
+	fn wedge(self, rhs: Translator3) -> Self::Output {
 		// Plane {
-		//     nx: YZW(self.w.0 * rhs.mx.0) + YZW(self.y.0 * rhs.vz.0) + YZW(self.z.0 * rhs.vy.0),
-		//     ny: ZXW(self.w.0 * rhs.my.0) + ZXW(self.x.0 * rhs.vz.0) + ZXW(self.z.0 * rhs.vx.0),
-		//     nz: XYW(self.w.0 * rhs.mz.0) + XYW(self.x.0 * rhs.vy.0) + XYW(self.y.0 * rhs.vx.0),
-		//     d : XYZ(self.x.0 * rhs.mx.0) + XYZ(self.y.0 * rhs.my.0) + XYZ(self.z.0 * rhs.mz.0),
+		//     nx: YZW(self.w.0 * rhs.x.0),
+		//     ny: ZXW(self.w.0 * rhs.y.0),
+		//     nz: XYW(self.w.0 * rhs.z.0),
+		//     d : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
 		// }
 		Plane {
-			nx: self.w.wedge(rhs.mx) - self.y.wedge(rhs.vz) + self.z.wedge(rhs.vy),
-			ny: self.w.wedge(rhs.my) + self.x.wedge(rhs.vz) - self.z.wedge(rhs.vx),
-			nz: self.w.wedge(rhs.mz) - self.x.wedge(rhs.vy) + self.y.wedge(rhs.vx),
-			d: self.x.wedge(rhs.mx) + self.y.wedge(rhs.my) + self.z.wedge(rhs.mz),
+			nx: self.w.wedge(rhs.x),
+			ny: self.w.wedge(rhs.y),
+			nz: self.w.wedge(rhs.z),
+			d: self.x.wedge(rhs.x) + self.y.wedge(rhs.y) + self.z.wedge(rhs.z),
 		}
 	}
 }
 
-// Omitted: Vec4 anti_wedge Line3 = 0  (unnamed type)
+impl std::ops::BitXor<Translator3> for Vec4 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Translator3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
 
-// ---------------------------------------------------------------------
-// Vec4 OP Plane:
+// Vec4.anti_wedge(Translator3) -> Vec4
+impl AntiWedge<Translator3> for Vec4 {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Translator3) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.x.0 * rhs.w.0),
+		//     y: Y(self.y.0 * rhs.w.0),
+		//     z: Z(self.z.0 * rhs.w.0),
+		//     w: W(self.w.0 * rhs.w.0),
+		// }
+		Vec4 {
+			x: self.x.anti_wedge(rhs.w),
+			y: self.y.anti_wedge(rhs.w),
+			z: self.z.anti_wedge(rhs.w),
+			w: self.w.anti_wedge(rhs.w),
+		}
+	}
+}
 
-// Omitted: Vec4 geometric Plane = self.w * rhs.d + self.x * rhs.d + self.x * rhs.nx + self.x * rhs.ny + self.x * rhs.nz + self.y * rhs.d + self.y * rhs.nx + self.y * rhs.ny + self.y * rhs.nz + self.z * rhs.d + self.z * rhs.nx + self.z * rhs.ny + self.z * rhs.nz  (unnamed type)
-// Omitted: Vec4 anti_geometric Plane = self.w !* rhs.d + self.w !* rhs.nx + self.w !* rhs.ny + self.w !* rhs.nz + self.x !* rhs.nx + self.x !* rhs.ny + self.x !* rhs.nz + self.y !* rhs.nx + self.y !* rhs.ny + self.y !* rhs.nz + self.z !* rhs.nx + self.z !* rhs.ny + self.z !* rhs.nz  (unnamed type)
+impl std::ops::BitAnd<Translator3> for Vec4 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Translator3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
 
-// Vec4.dot(Plane) -> Line3
-impl Dot<Plane> for Vec4 {
-	type Output = Line3;
-	fn dot(self, rhs: Plane) -> Self::Output {
-		// Line3 {
-		//     vx: WX(self.y.0 * rhs.nz.0) + WX(self.z.0 * rhs.ny.0),
-		//     vy: WY(self.x.0 * rhs.nz.0) + WY(self.z.0 * rhs.nx.0),
-		//     vz: WZ(self.x.0 * rhs.ny.0) + WZ(self.y.0 * rhs.nx.0),
-		//     mx: YZ(self.x.0 * rhs.d.0),
-		//     my: ZX(self.y.0 * rhs.d.0),
-		//     mz: XY(self.z.0 * rhs.d.0),
+// Vec4.left_contraction(Translator3) -> Multivector
+impl LeftContraction<Translator3> for Vec4 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
 		// }
-		Line3 {
-			vx: self.y.dot(rhs.nz) - self.z.dot(rhs.ny),
-			vy: -self.x.dot(rhs.nz) + self.z.dot(rhs.nx),
-			vz: self.x.dot(rhs.ny) - self.y.dot(rhs.nx),
-			mx: self.x.dot(rhs.d),
-			my: self.y.dot(rhs.d),
-			mz: self.z.dot(rhs.d),
+		Multivector {
+			s: Default::default(),
+			x: self.y.left_contraction(rhs.z) + self.z.left_contraction(rhs.y),
+			y: self.x.left_contraction(rhs.z) + self.z.left_contraction(rhs.x),
+			z: self.x.left_contraction(rhs.y) + self.y.left_contraction(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.left_contraction(rhs.w),
+			zxw: self.y.left_contraction(rhs.w),
+			xyw: self.z.left_contraction(rhs.w),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Translator3> for Vec4 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Translator3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Translator3> for Vec4 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Translator3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec4.commutator(Translator3) -> Multivector
+impl Commutator<Translator3> for Vec4 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Translator3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y   : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z   : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     w   : Default::default(),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			y: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			z: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			w: Default::default(),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.commutator(rhs.w),
+			zxw: self.y.commutator(rhs.w),
+			xyw: self.z.commutator(rhs.w),
+			xyz: Default::default(),
+			xyzw: Default::default(),
 		}
 	}
 }
 
-// Omitted: Vec4 wedge Plane = self.w ^ rhs.d + self.x ^ rhs.nx + self.y ^ rhs.ny + self.z ^ rhs.nz  (unnamed type)
-// Omitted: Vec4 anti_wedge Plane = self.w & rhs.d + self.x & rhs.nx + self.y & rhs.ny + self.z & rhs.nz  (unnamed type)
+// Vec4.anti_dot(Translator3) -> Vec4
+impl AntiDot<Translator3> for Vec4 {
+	type Output = Vec4;
+	fn anti_dot(self, rhs: Translator3) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.x.0 * rhs.w.0),
+		//     y: Y(self.y.0 * rhs.w.0),
+		//     z: Z(self.z.0 * rhs.w.0),
+		//     w: W(self.w.0 * rhs.w.0),
+		// }
+		Vec4 {
+			x: self.x.anti_dot(rhs.w),
+			y: self.y.anti_dot(rhs.w),
+			z: self.z.anti_dot(rhs.w),
+			w: self.w.anti_dot(rhs.w),
+		}
+	}
+}
 
 // ---------------------------------------------------------------------
 // Vec4 OP Rotor3:
 
-// Omitted: Vec4 geometric Rotor3 = self.x * rhs.w + self.x * rhs.x + self.x * rhs.y + self.x * rhs.z + self.y * rhs.w + self.y * rhs.x + self.y * rhs.y + self.y * rhs.z + self.z * rhs.w + self.z * rhs.x + self.z * rhs.y + self.z * rhs.z  (unnamed type)
-// Omitted: Vec4 anti_geometric Rotor3 = self.w !* rhs.w + self.w !* rhs.x + self.w !* rhs.y + self.w !* rhs.z + self.x !* rhs.w + self.x !* rhs.x + self.x !* rhs.y + self.x !* rhs.z + self.y !* rhs.w + self.y !* rhs.x + self.y !* rhs.y + self.y !* rhs.z + self.z !* rhs.w + self.z !* rhs.x + self.z !* rhs.y + self.z !* rhs.z  (unnamed type)
-// Omitted: Vec4 dot Rotor3 = self.x | rhs.w + self.x | rhs.x + self.y | rhs.w + self.y | rhs.y + self.z | rhs.w + self.z | rhs.z  (unnamed type)
+// Vec4.geometric(Rotor3) -> Multivector
+impl Geometric<Rotor3> for Vec4 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.x.0 * rhs.x.0) - W(self.y.0 * rhs.y.0) - W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0) - YZW(self.y.0 * rhs.z.0) + YZW(self.z.0 * rhs.y.0),
+		//     zxw : ZXW(self.x.0 * rhs.z.0) + ZXW(self.y.0 * rhs.w.0) - ZXW(self.z.0 * rhs.x.0),
+		//     xyw : -XYW(self.x.0 * rhs.y.0) + XYW(self.y.0 * rhs.x.0) + XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.geometric(rhs.w) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zxw: self.x.geometric(rhs.z) + self.y.geometric(rhs.w) + self.z.geometric(rhs.x),
+			xyw: self.x.geometric(rhs.y) + self.y.geometric(rhs.x) + self.z.geometric(rhs.w),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Vec4 {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Rotor3) -> Multivector
+impl AntiGeometric<Rotor3> for Vec4 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.x.0 * rhs.w.0) + X(self.y.0 * rhs.z.0) - X(self.z.0 * rhs.y.0),
+		//     y   : -Y(self.x.0 * rhs.z.0) + Y(self.y.0 * rhs.w.0) + Y(self.z.0 * rhs.x.0),
+		//     z   : Z(self.x.0 * rhs.y.0) - Z(self.y.0 * rhs.x.0) + Z(self.z.0 * rhs.w.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.x.0),
+		//     zxw : ZXW(self.w.0 * rhs.y.0),
+		//     xyw : XYW(self.w.0 * rhs.z.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.anti_geometric(rhs.w) + self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+			y: self.x.anti_geometric(rhs.z) + self.y.anti_geometric(rhs.w) + self.z.anti_geometric(rhs.x),
+			z: self.x.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.x) + self.z.anti_geometric(rhs.w),
+			w: self.w.anti_geometric(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.anti_geometric(rhs.x),
+			zxw: self.w.anti_geometric(rhs.y),
+			xyw: self.w.anti_geometric(rhs.z),
+			xyz: self.x.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Vec4.dot(Rotor3) -> Multivector
+impl Dot<Rotor3> for Vec4 {
+	type Output = Multivector;
+	fn dot(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.x.0 * rhs.x.0) - W(self.y.0 * rhs.y.0) - W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.dot(rhs.w),
+			zxw: self.y.dot(rhs.w),
+			xyw: self.z.dot(rhs.w),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor3> for Vec4 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
 
 // Vec4.wedge(Rotor3) -> Plane
 impl Wedge<Rotor3> for Vec4 {
 	type Output = Plane;
 	fn wedge(self, rhs: Rotor3) -> Self::Output {
 		// Plane {
-		//     nx: YZW(self.y.0 * rhs.z.0) + YZW(self.z.0 * rhs.y.0),
-		//     ny: ZXW(self.x.0 * rhs.z.0) + ZXW(self.z.0 * rhs.x.0),
-		//     nz: XYW(self.x.0 * rhs.y.0) + XYW(self.y.0 * rhs.x.0),
+		//     nx: -YZW(self.y.0 * rhs.z.0) + YZW(self.z.0 * rhs.y.0),
+		//     ny: ZXW(self.x.0 * rhs.z.0) - ZXW(self.z.0 * rhs.x.0),
+		//     nz: -XYW(self.x.0 * rhs.y.0) + XYW(self.y.0 * rhs.x.0),
 		//     d : Default::default(),
 		// }
 		Plane {
-			nx: -self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
-			ny: self.x.wedge(rhs.z) - self.z.wedge(rhs.x),
-			nz: -self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			nx: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			ny: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			nz: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
 			d: Default::default(),
 		}
 	}
 }
 
+impl std::ops::BitXor<Rotor3> for Vec4 {
+	type Output = Plane;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
 // Vec4.anti_wedge(Rotor3) -> Vec4
 impl AntiWedge<Rotor3> for Vec4 {
 	type Output = Vec4;
@@ -366,11 +2203,1031 @@ impl AntiWedge<Rotor3> for Vec4 {
 	}
 }
 
+impl std::ops::BitAnd<Rotor3> for Vec4 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Rotor3) -> Multivector
+impl LeftContraction<Rotor3> for Vec4 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.x.0 * rhs.x.0) - W(self.y.0 * rhs.y.0) - W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.left_contraction(rhs.w),
+			zxw: self.y.left_contraction(rhs.w),
+			xyw: self.z.left_contraction(rhs.w),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl RightContraction<Rotor3> for Vec4 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Rotor3> for Vec4 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec4.commutator(Rotor3) -> Multivector
+impl Commutator<Rotor3> for Vec4 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : Default::default(),
+		//     y   : Default::default(),
+		//     z   : Default::default(),
+		//     w   : -W(self.x.0 * rhs.x.0) - W(self.y.0 * rhs.y.0) - W(self.z.0 * rhs.z.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.w.0),
+		//     zxw : ZXW(self.y.0 * rhs.w.0),
+		//     xyw : XYW(self.z.0 * rhs.w.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			w: self.x.commutator(rhs.x) + self.y.commutator(rhs.y) + self.z.commutator(rhs.z),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.commutator(rhs.w),
+			zxw: self.y.commutator(rhs.w),
+			xyw: self.z.commutator(rhs.w),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Vec4.anti_dot(Rotor3) -> Multivector
+impl AntiDot<Rotor3> for Vec4 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.x.0 * rhs.w.0),
+		//     y   : Y(self.y.0 * rhs.w.0),
+		//     z   : Z(self.z.0 * rhs.w.0),
+		//     w   : W(self.w.0 * rhs.w.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.x.0),
+		//     zxw : ZXW(self.w.0 * rhs.y.0),
+		//     xyw : XYW(self.w.0 * rhs.z.0),
+		//     xyz : XYZ(self.x.0 * rhs.x.0) + XYZ(self.y.0 * rhs.y.0) + XYZ(self.z.0 * rhs.z.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.anti_dot(rhs.w),
+			y: self.y.anti_dot(rhs.w),
+			z: self.z.anti_dot(rhs.w),
+			w: self.w.anti_dot(rhs.w),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.anti_dot(rhs.x),
+			zxw: self.w.anti_dot(rhs.y),
+			xyw: self.w.anti_dot(rhs.z),
+			xyz: self.x.anti_dot(rhs.x) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z),
+			xyzw: Default::default(),
+		}
+	}
+}
+
 // ---------------------------------------------------------------------
 // Vec4 OP Motor3:
 
-// Omitted: Vec4 geometric Motor3 = self.w * rhs.uw + self.x * rhs.rw + self.x * rhs.rx + self.x * rhs.ry + self.x * rhs.rz + self.x * rhs.uw + self.x * rhs.ux + self.x * rhs.uy + self.x * rhs.uz + self.y * rhs.rw + self.y * rhs.rx + self.y * rhs.ry + self.y * rhs.rz + self.y * rhs.uw + self.y * rhs.ux + self.y * rhs.uy + self.y * rhs.uz + self.z * rhs.rw + self.z * rhs.rx + self.z * rhs.ry + self.z * rhs.rz + self.z * rhs.uw + self.z * rhs.ux + self.z * rhs.uy + self.z * rhs.uz  (unnamed type)
-// Omitted: Vec4 anti_geometric Motor3 = self.w !* rhs.rw + self.w !* rhs.rx + self.w !* rhs.ry + self.w !* rhs.rz + self.w !* rhs.uw + self.w !* rhs.ux + self.w !* rhs.uy + self.w !* rhs.uz + self.x !* rhs.rw + self.x !* rhs.rx + self.x !* rhs.ry + self.x !* rhs.rz + self.x !* rhs.ux + self.x !* rhs.uy + self.x !* rhs.uz + self.y !* rhs.rw + self.y !* rhs.rx + self.y !* rhs.ry + self.y !* rhs.rz + self.y !* rhs.ux + self.y !* rhs.uy + self.y !* rhs.uz + self.z !* rhs.rw + self.z !* rhs.rx + self.z !* rhs.ry + self.z !* rhs.rz + self.z !* rhs.ux + self.z !* rhs.uy + self.z !* rhs.uz  (unnamed type)
-// Omitted: Vec4 dot Motor3 = self.w | rhs.uw + self.x | rhs.rw + self.x | rhs.rx + self.x | rhs.uw + self.x | rhs.uy + self.x | rhs.uz + self.y | rhs.rw + self.y | rhs.ry + self.y | rhs.uw + self.y | rhs.ux + self.y | rhs.uz + self.z | rhs.rw + self.z | rhs.rz + self.z | rhs.uw + self.z | rhs.ux + self.z | rhs.uy  (unnamed type)
-// Omitted: Vec4 wedge Motor3 = self.w ^ rhs.uw + self.x ^ rhs.ry + self.x ^ rhs.rz + self.x ^ rhs.uw + self.x ^ rhs.ux + self.y ^ rhs.rx + self.y ^ rhs.rz + self.y ^ rhs.uw + self.y ^ rhs.uy + self.z ^ rhs.rx + self.z ^ rhs.ry + self.z ^ rhs.uw + self.z ^ rhs.uz  (unnamed type)
-// Omitted: Vec4 anti_wedge Motor3 = self.w & rhs.rw + self.x & rhs.rw + self.x & rhs.ux + self.y & rhs.rw + self.y & rhs.uy + self.z & rhs.rw + self.z & rhs.uz  (unnamed type)
+// Vec4.geometric(Motor3) -> Multivector
+impl Geometric<Motor3> for Vec4 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.x.0 * rhs.uw.0) - X(self.y.0 * rhs.uz.0) + X(self.z.0 * rhs.uy.0),
+		//     y   : Y(self.x.0 * rhs.uz.0) + Y(self.y.0 * rhs.uw.0) - Y(self.z.0 * rhs.ux.0),
+		//     z   : -Z(self.x.0 * rhs.uy.0) + Z(self.y.0 * rhs.ux.0) + Z(self.z.0 * rhs.uw.0),
+		//     w   : W(self.w.0 * rhs.uw.0) - W(self.x.0 * rhs.rx.0) - W(self.y.0 * rhs.ry.0) - W(self.z.0 * rhs.rz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.ux.0) + YZW(self.x.0 * rhs.rw.0) - YZW(self.y.0 * rhs.rz.0) + YZW(self.z.0 * rhs.ry.0),
+		//     zxw : ZXW(self.w.0 * rhs.uy.0) + ZXW(self.x.0 * rhs.rz.0) + ZXW(self.y.0 * rhs.rw.0) - ZXW(self.z.0 * rhs.rx.0),
+		//     xyw : XYW(self.w.0 * rhs.uz.0) - XYW(self.x.0 * rhs.ry.0) + XYW(self.y.0 * rhs.rx.0) + XYW(self.z.0 * rhs.rw.0),
+		//     xyz : XYZ(self.x.0 * rhs.ux.0) + XYZ(self.y.0 * rhs.uy.0) + XYZ(self.z.0 * rhs.uz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.uw) + self.y.geometric(rhs.uz) + self.z.geometric(rhs.uy),
+			y: self.x.geometric(rhs.uz) + self.y.geometric(rhs.uw) + self.z.geometric(rhs.ux),
+			z: self.x.geometric(rhs.uy) + self.y.geometric(rhs.ux) + self.z.geometric(rhs.uw),
+			w: self.w.geometric(rhs.uw)
+				+ self.x.geometric(rhs.rx)
+				+ self.y.geometric(rhs.ry)
+				+ self.z.geometric(rhs.rz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.geometric(rhs.ux)
+				+ self.x.geometric(rhs.rw)
+				+ self.y.geometric(rhs.rz)
+				+ self.z.geometric(rhs.ry),
+			zxw: self.w.geometric(rhs.uy)
+				+ self.x.geometric(rhs.rz)
+				+ self.y.geometric(rhs.rw)
+				+ self.z.geometric(rhs.rx),
+			xyw: self.w.geometric(rhs.uz)
+				+ self.x.geometric(rhs.ry)
+				+ self.y.geometric(rhs.rx)
+				+ self.z.geometric(rhs.rw),
+			xyz: self.x.geometric(rhs.ux) + self.y.geometric(rhs.uy) + self.z.geometric(rhs.uz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Motor3> for Vec4 {
+	type Output = Multivector;
+	fn mul(self, rhs: Motor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Motor3) -> Multivector
+impl AntiGeometric<Motor3> for Vec4 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.w.0 * rhs.ux.0) + X(self.x.0 * rhs.rw.0) + X(self.y.0 * rhs.rz.0) - X(self.z.0 * rhs.ry.0),
+		//     y   : -Y(self.w.0 * rhs.uy.0) - Y(self.x.0 * rhs.rz.0) + Y(self.y.0 * rhs.rw.0) + Y(self.z.0 * rhs.rx.0),
+		//     z   : -Z(self.w.0 * rhs.uz.0) + Z(self.x.0 * rhs.ry.0) - Z(self.y.0 * rhs.rx.0) + Z(self.z.0 * rhs.rw.0),
+		//     w   : W(self.w.0 * rhs.rw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.rx.0),
+		//     zxw : ZXW(self.w.0 * rhs.ry.0),
+		//     xyw : XYW(self.w.0 * rhs.rz.0),
+		//     xyz : XYZ(self.w.0 * rhs.uw.0) + XYZ(self.x.0 * rhs.rx.0) + XYZ(self.y.0 * rhs.ry.0) + XYZ(self.z.0 * rhs.rz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.w.anti_geometric(rhs.ux)
+				+ self.x.anti_geometric(rhs.rw)
+				+ self.y.anti_geometric(rhs.rz)
+				+ self.z.anti_geometric(rhs.ry),
+			y: self.w.anti_geometric(rhs.uy)
+				+ self.x.anti_geometric(rhs.rz)
+				+ self.y.anti_geometric(rhs.rw)
+				+ self.z.anti_geometric(rhs.rx),
+			z: self.w.anti_geometric(rhs.uz)
+				+ self.x.anti_geometric(rhs.ry)
+				+ self.y.anti_geometric(rhs.rx)
+				+ self.z.anti_geometric(rhs.rw),
+			w: self.w.anti_geometric(rhs.rw),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.anti_geometric(rhs.rx),
+			zxw: self.w.anti_geometric(rhs.ry),
+			xyw: self.w.anti_geometric(rhs.rz),
+			xyz: self.w.anti_geometric(rhs.uw)
+				+ self.x.anti_geometric(rhs.rx)
+				+ self.y.anti_geometric(rhs.ry)
+				+ self.z.anti_geometric(rhs.rz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Vec4.dot(Motor3) -> Multivector
+impl Dot<Motor3> for Vec4 {
+	type Output = Multivector;
+	fn dot(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.x.0 * rhs.uw.0) - X(self.y.0 * rhs.uz.0) + X(self.z.0 * rhs.uy.0),
+		//     y   : Y(self.x.0 * rhs.uz.0) + Y(self.y.0 * rhs.uw.0) - Y(self.z.0 * rhs.ux.0),
+		//     z   : -Z(self.x.0 * rhs.uy.0) + Z(self.y.0 * rhs.ux.0) + Z(self.z.0 * rhs.uw.0),
+		//     w   : W(self.w.0 * rhs.uw.0) - W(self.x.0 * rhs.rx.0) - W(self.y.0 * rhs.ry.0) - W(self.z.0 * rhs.rz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.rw.0),
+		//     zxw : ZXW(self.y.0 * rhs.rw.0),
+		//     xyw : XYW(self.z.0 * rhs.rw.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.dot(rhs.uw) + self.y.dot(rhs.uz) + self.z.dot(rhs.uy),
+			y: self.x.dot(rhs.uz) + self.y.dot(rhs.uw) + self.z.dot(rhs.ux),
+			z: self.x.dot(rhs.uy) + self.y.dot(rhs.ux) + self.z.dot(rhs.uw),
+			w: self.w.dot(rhs.uw) + self.x.dot(rhs.rx) + self.y.dot(rhs.ry) + self.z.dot(rhs.rz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.dot(rhs.rw),
+			zxw: self.y.dot(rhs.rw),
+			xyw: self.z.dot(rhs.rw),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Motor3> for Vec4 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Motor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec4.wedge(Motor3) -> Multivector
+impl Wedge<Motor3> for Vec4 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.x.0 * rhs.uw.0),
+		//     y   : Y(self.y.0 * rhs.uw.0),
+		//     z   : Z(self.z.0 * rhs.uw.0),
+		//     w   : W(self.w.0 * rhs.uw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.ux.0) - YZW(self.y.0 * rhs.rz.0) + YZW(self.z.0 * rhs.ry.0),
+		//     zxw : ZXW(self.w.0 * rhs.uy.0) + ZXW(self.x.0 * rhs.rz.0) - ZXW(self.z.0 * rhs.rx.0),
+		//     xyw : XYW(self.w.0 * rhs.uz.0) - XYW(self.x.0 * rhs.ry.0) + XYW(self.y.0 * rhs.rx.0),
+		//     xyz : XYZ(self.x.0 * rhs.ux.0) + XYZ(self.y.0 * rhs.uy.0) + XYZ(self.z.0 * rhs.uz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.uw),
+			y: self.y.wedge(rhs.uw),
+			z: self.z.wedge(rhs.uw),
+			w: self.w.wedge(rhs.uw),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.wedge(rhs.ux) + self.y.wedge(rhs.rz) + self.z.wedge(rhs.ry),
+			zxw: self.w.wedge(rhs.uy) + self.x.wedge(rhs.rz) + self.z.wedge(rhs.rx),
+			xyw: self.w.wedge(rhs.uz) + self.x.wedge(rhs.ry) + self.y.wedge(rhs.rx),
+			xyz: self.x.wedge(rhs.ux) + self.y.wedge(rhs.uy) + self.z.wedge(rhs.uz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Motor3> for Vec4 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Motor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec4.anti_wedge(Motor3) -> Vec4
+impl AntiWedge<Motor3> for Vec4 {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Motor3) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.x.0 * rhs.rw.0),
+		//     y: Y(self.y.0 * rhs.rw.0),
+		//     z: Z(self.z.0 * rhs.rw.0),
+		//     w: W(self.w.0 * rhs.rw.0),
+		// }
+		Vec4 {
+			x: self.x.anti_wedge(rhs.rw),
+			y: self.y.anti_wedge(rhs.rw),
+			z: self.z.anti_wedge(rhs.rw),
+			w: self.w.anti_wedge(rhs.rw),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Motor3> for Vec4 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Motor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Motor3) -> Multivector
+impl LeftContraction<Motor3> for Vec4 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.uz.0) + X(self.z.0 * rhs.uy.0),
+		//     y   : Y(self.x.0 * rhs.uz.0) - Y(self.z.0 * rhs.ux.0),
+		//     z   : -Z(self.x.0 * rhs.uy.0) + Z(self.y.0 * rhs.ux.0),
+		//     w   : -W(self.x.0 * rhs.rx.0) - W(self.y.0 * rhs.ry.0) - W(self.z.0 * rhs.rz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.rw.0),
+		//     zxw : ZXW(self.y.0 * rhs.rw.0),
+		//     xyw : XYW(self.z.0 * rhs.rw.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.left_contraction(rhs.uz) + self.z.left_contraction(rhs.uy),
+			y: self.x.left_contraction(rhs.uz) + self.z.left_contraction(rhs.ux),
+			z: self.x.left_contraction(rhs.uy) + self.y.left_contraction(rhs.ux),
+			w: self.x.left_contraction(rhs.rx) + self.y.left_contraction(rhs.ry) + self.z.left_contraction(rhs.rz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.left_contraction(rhs.rw),
+			zxw: self.y.left_contraction(rhs.rw),
+			xyw: self.z.left_contraction(rhs.rw),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Vec4.right_contraction(Motor3) -> Vec4
+impl RightContraction<Motor3> for Vec4 {
+	type Output = Vec4;
+	fn right_contraction(self, rhs: Motor3) -> Self::Output {
+		// Vec4 {
+		//     x: X(self.x.0 * rhs.uw.0),
+		//     y: Y(self.y.0 * rhs.uw.0),
+		//     z: Z(self.z.0 * rhs.uw.0),
+		//     w: W(self.w.0 * rhs.uw.0),
+		// }
+		Vec4 {
+			x: self.x.right_contraction(rhs.uw),
+			y: self.y.right_contraction(rhs.uw),
+			z: self.z.right_contraction(rhs.uw),
+			w: self.w.right_contraction(rhs.uw),
+		}
+	}
+}
+
+impl ScalarProduct<Motor3> for Vec4 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Motor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec4.commutator(Motor3) -> Multivector
+impl Commutator<Motor3> for Vec4 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.uz.0) + X(self.z.0 * rhs.uy.0),
+		//     y   : Y(self.x.0 * rhs.uz.0) - Y(self.z.0 * rhs.ux.0),
+		//     z   : -Z(self.x.0 * rhs.uy.0) + Z(self.y.0 * rhs.ux.0),
+		//     w   : -W(self.x.0 * rhs.rx.0) - W(self.y.0 * rhs.ry.0) - W(self.z.0 * rhs.rz.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.x.0 * rhs.rw.0),
+		//     zxw : ZXW(self.y.0 * rhs.rw.0),
+		//     xyw : XYW(self.z.0 * rhs.rw.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.uz) + self.z.commutator(rhs.uy),
+			y: self.x.commutator(rhs.uz) + self.z.commutator(rhs.ux),
+			z: self.x.commutator(rhs.uy) + self.y.commutator(rhs.ux),
+			w: self.x.commutator(rhs.rx) + self.y.commutator(rhs.ry) + self.z.commutator(rhs.rz),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.x.commutator(rhs.rw),
+			zxw: self.y.commutator(rhs.rw),
+			xyw: self.z.commutator(rhs.rw),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Vec4.anti_dot(Motor3) -> Multivector
+impl AntiDot<Motor3> for Vec4 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Motor3) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.x.0 * rhs.rw.0),
+		//     y   : Y(self.y.0 * rhs.rw.0),
+		//     z   : Z(self.z.0 * rhs.rw.0),
+		//     w   : W(self.w.0 * rhs.rw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : YZW(self.w.0 * rhs.rx.0),
+		//     zxw : ZXW(self.w.0 * rhs.ry.0),
+		//     xyw : XYW(self.w.0 * rhs.rz.0),
+		//     xyz : XYZ(self.w.0 * rhs.uw.0) + XYZ(self.x.0 * rhs.rx.0) + XYZ(self.y.0 * rhs.ry.0) + XYZ(self.z.0 * rhs.rz.0),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.anti_dot(rhs.rw),
+			y: self.y.anti_dot(rhs.rw),
+			z: self.z.anti_dot(rhs.rw),
+			w: self.w.anti_dot(rhs.rw),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: self.w.anti_dot(rhs.rx),
+			zxw: self.w.anti_dot(rhs.ry),
+			xyw: self.w.anti_dot(rhs.rz),
+			xyz: self.w.anti_dot(rhs.uw) + self.x.anti_dot(rhs.rx) + self.y.anti_dot(rhs.ry) + self.z.anti_dot(rhs.rz),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec4 OP Multivector:
+
+// Vec4.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y   : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0) - Y(self.z.0 * rhs.yz.0),
+		//     z   : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0) + Z(self.z.0 * rhs.s.0),
+		//     w   : W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) - W(self.y.0 * rhs.wy.0) - W(self.z.0 * rhs.wz.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0) + WX(self.y.0 * rhs.xyw.0) - WX(self.z.0 * rhs.zxw.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) - WY(self.x.0 * rhs.xyw.0) - WY(self.y.0 * rhs.w.0) + WY(self.z.0 * rhs.yzw.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) + WZ(self.x.0 * rhs.zxw.0) - WZ(self.y.0 * rhs.yzw.0) - WZ(self.z.0 * rhs.w.0),
+		//     yz  : YZ(self.x.0 * rhs.xyz.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.y.0 * rhs.xyz.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0) + XY(self.z.0 * rhs.xyz.0),
+		//     yzw : YZW(self.w.0 * rhs.yz.0) + YZW(self.x.0 * rhs.xyzw.0) - YZW(self.y.0 * rhs.wz.0) + YZW(self.z.0 * rhs.wy.0),
+		//     zxw : ZXW(self.w.0 * rhs.zx.0) + ZXW(self.x.0 * rhs.wz.0) + ZXW(self.y.0 * rhs.xyzw.0) - ZXW(self.z.0 * rhs.wx.0),
+		//     xyw : XYW(self.w.0 * rhs.xy.0) - XYW(self.x.0 * rhs.wy.0) + XYW(self.y.0 * rhs.wx.0) + XYW(self.z.0 * rhs.xyzw.0),
+		//     xyz : XYZ(self.x.0 * rhs.yz.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		//     xyzw: -XYZW(self.w.0 * rhs.xyz.0) + XYZW(self.x.0 * rhs.yzw.0) + XYZW(self.y.0 * rhs.zxw.0) + XYZW(self.z.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy) + self.z.geometric(rhs.zx),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s) + self.z.geometric(rhs.yz),
+			z: self.x.geometric(rhs.zx) + self.y.geometric(rhs.yz) + self.z.geometric(rhs.s),
+			w: self.w.geometric(rhs.s) + self.x.geometric(rhs.wx) + self.y.geometric(rhs.wy) + self.z.geometric(rhs.wz),
+			wx: self.w.geometric(rhs.x)
+				+ self.x.geometric(rhs.w)
+				+ self.y.geometric(rhs.xyw)
+				+ self.z.geometric(rhs.zxw),
+			wy: self.w.geometric(rhs.y)
+				+ self.x.geometric(rhs.xyw)
+				+ self.y.geometric(rhs.w)
+				+ self.z.geometric(rhs.yzw),
+			wz: self.w.geometric(rhs.z)
+				+ self.x.geometric(rhs.zxw)
+				+ self.y.geometric(rhs.yzw)
+				+ self.z.geometric(rhs.w),
+			yz: self.x.geometric(rhs.xyz) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zx: self.x.geometric(rhs.z) + self.y.geometric(rhs.xyz) + self.z.geometric(rhs.x),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x) + self.z.geometric(rhs.xyz),
+			yzw: self.w.geometric(rhs.yz)
+				+ self.x.geometric(rhs.xyzw)
+				+ self.y.geometric(rhs.wz)
+				+ self.z.geometric(rhs.wy),
+			zxw: self.w.geometric(rhs.zx)
+				+ self.x.geometric(rhs.wz)
+				+ self.y.geometric(rhs.xyzw)
+				+ self.z.geometric(rhs.wx),
+			xyw: self.w.geometric(rhs.xy)
+				+ self.x.geometric(rhs.wy)
+				+ self.y.geometric(rhs.wx)
+				+ self.z.geometric(rhs.xyzw),
+			xyz: self.x.geometric(rhs.yz) + self.y.geometric(rhs.zx) + self.z.geometric(rhs.xy),
+			xyzw: self.w.geometric(rhs.xyz)
+				+ self.x.geometric(rhs.yzw)
+				+ self.y.geometric(rhs.zxw)
+				+ self.z.geometric(rhs.xyw),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.w.0 * rhs.xyz.0) + S(self.x.0 * rhs.yzw.0) + S(self.y.0 * rhs.zxw.0) + S(self.z.0 * rhs.xyw.0),
+		//     x   : -X(self.w.0 * rhs.yz.0) + X(self.x.0 * rhs.xyzw.0) + X(self.y.0 * rhs.wz.0) - X(self.z.0 * rhs.wy.0),
+		//     y   : -Y(self.w.0 * rhs.zx.0) - Y(self.x.0 * rhs.wz.0) + Y(self.y.0 * rhs.xyzw.0) + Y(self.z.0 * rhs.wx.0),
+		//     z   : -Z(self.w.0 * rhs.xy.0) + Z(self.x.0 * rhs.wy.0) - Z(self.y.0 * rhs.wx.0) + Z(self.z.0 * rhs.xyzw.0),
+		//     w   : W(self.w.0 * rhs.xyzw.0),
+		//     wx  : -WX(self.w.0 * rhs.yzw.0),
+		//     wy  : -WY(self.w.0 * rhs.zxw.0),
+		//     wz  : -WZ(self.w.0 * rhs.xyw.0),
+		//     yz  : YZ(self.w.0 * rhs.x.0) - YZ(self.x.0 * rhs.w.0) - YZ(self.y.0 * rhs.xyw.0) + YZ(self.z.0 * rhs.zxw.0),
+		//     zx  : ZX(self.w.0 * rhs.y.0) + ZX(self.x.0 * rhs.xyw.0) - ZX(self.y.0 * rhs.w.0) - ZX(self.z.0 * rhs.yzw.0),
+		//     xy  : XY(self.w.0 * rhs.z.0) - XY(self.x.0 * rhs.zxw.0) + XY(self.y.0 * rhs.yzw.0) - XY(self.z.0 * rhs.w.0),
+		//     yzw : YZW(self.w.0 * rhs.wx.0),
+		//     zxw : ZXW(self.w.0 * rhs.wy.0),
+		//     xyw : XYW(self.w.0 * rhs.wz.0),
+		//     xyz : XYZ(self.w.0 * rhs.s.0) + XYZ(self.x.0 * rhs.wx.0) + XYZ(self.y.0 * rhs.wy.0) + XYZ(self.z.0 * rhs.wz.0),
+		//     xyzw: -XYZW(self.w.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: self.w.anti_geometric(rhs.xyz)
+				+ self.x.anti_geometric(rhs.yzw)
+				+ self.y.anti_geometric(rhs.zxw)
+				+ self.z.anti_geometric(rhs.xyw),
+			x: self.w.anti_geometric(rhs.yz)
+				+ self.x.anti_geometric(rhs.xyzw)
+				+ self.y.anti_geometric(rhs.wz)
+				+ self.z.anti_geometric(rhs.wy),
+			y: self.w.anti_geometric(rhs.zx)
+				+ self.x.anti_geometric(rhs.wz)
+				+ self.y.anti_geometric(rhs.xyzw)
+				+ self.z.anti_geometric(rhs.wx),
+			z: self.w.anti_geometric(rhs.xy)
+				+ self.x.anti_geometric(rhs.wy)
+				+ self.y.anti_geometric(rhs.wx)
+				+ self.z.anti_geometric(rhs.xyzw),
+			w: self.w.anti_geometric(rhs.xyzw),
+			wx: self.w.anti_geometric(rhs.yzw),
+			wy: self.w.anti_geometric(rhs.zxw),
+			wz: self.w.anti_geometric(rhs.xyw),
+			yz: self.w.anti_geometric(rhs.x)
+				+ self.x.anti_geometric(rhs.w)
+				+ self.y.anti_geometric(rhs.xyw)
+				+ self.z.anti_geometric(rhs.zxw),
+			zx: self.w.anti_geometric(rhs.y)
+				+ self.x.anti_geometric(rhs.xyw)
+				+ self.y.anti_geometric(rhs.w)
+				+ self.z.anti_geometric(rhs.yzw),
+			xy: self.w.anti_geometric(rhs.z)
+				+ self.x.anti_geometric(rhs.zxw)
+				+ self.y.anti_geometric(rhs.yzw)
+				+ self.z.anti_geometric(rhs.w),
+			yzw: self.w.anti_geometric(rhs.wx),
+			zxw: self.w.anti_geometric(rhs.wy),
+			xyw: self.w.anti_geometric(rhs.wz),
+			xyz: self.w.anti_geometric(rhs.s)
+				+ self.x.anti_geometric(rhs.wx)
+				+ self.y.anti_geometric(rhs.wy)
+				+ self.z.anti_geometric(rhs.wz),
+			xyzw: self.w.anti_geometric(rhs.w),
+		}
+	}
+}
+
+// Vec4.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y   : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0) - Y(self.z.0 * rhs.yz.0),
+		//     z   : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0) + Z(self.z.0 * rhs.s.0),
+		//     w   : W(self.w.0 * rhs.s.0) - W(self.x.0 * rhs.wx.0) - W(self.y.0 * rhs.wy.0) - W(self.z.0 * rhs.wz.0),
+		//     wx  : WX(self.y.0 * rhs.xyw.0) - WX(self.z.0 * rhs.zxw.0),
+		//     wy  : -WY(self.x.0 * rhs.xyw.0) + WY(self.z.0 * rhs.yzw.0),
+		//     wz  : WZ(self.x.0 * rhs.zxw.0) - WZ(self.y.0 * rhs.yzw.0),
+		//     yz  : YZ(self.x.0 * rhs.xyz.0),
+		//     zx  : ZX(self.y.0 * rhs.xyz.0),
+		//     xy  : XY(self.z.0 * rhs.xyz.0),
+		//     yzw : YZW(self.x.0 * rhs.xyzw.0),
+		//     zxw : ZXW(self.y.0 * rhs.xyzw.0),
+		//     xyw : XYW(self.z.0 * rhs.xyzw.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy) + self.z.dot(rhs.zx),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s) + self.z.dot(rhs.yz),
+			z: self.x.dot(rhs.zx) + self.y.dot(rhs.yz) + self.z.dot(rhs.s),
+			w: self.w.dot(rhs.s) + self.x.dot(rhs.wx) + self.y.dot(rhs.wy) + self.z.dot(rhs.wz),
+			wx: self.y.dot(rhs.xyw) + self.z.dot(rhs.zxw),
+			wy: self.x.dot(rhs.xyw) + self.z.dot(rhs.yzw),
+			wz: self.x.dot(rhs.zxw) + self.y.dot(rhs.yzw),
+			yz: self.x.dot(rhs.xyz),
+			zx: self.y.dot(rhs.xyz),
+			xy: self.z.dot(rhs.xyz),
+			yzw: self.x.dot(rhs.xyzw),
+			zxw: self.y.dot(rhs.xyzw),
+			xyw: self.z.dot(rhs.xyzw),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec4.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.x.0 * rhs.s.0),
+		//     y   : Y(self.y.0 * rhs.s.0),
+		//     z   : Z(self.z.0 * rhs.s.0),
+		//     w   : W(self.w.0 * rhs.s.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) - WY(self.y.0 * rhs.w.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) - WZ(self.z.0 * rhs.w.0),
+		//     yz  : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : YZW(self.w.0 * rhs.yz.0) - YZW(self.y.0 * rhs.wz.0) + YZW(self.z.0 * rhs.wy.0),
+		//     zxw : ZXW(self.w.0 * rhs.zx.0) + ZXW(self.x.0 * rhs.wz.0) - ZXW(self.z.0 * rhs.wx.0),
+		//     xyw : XYW(self.w.0 * rhs.xy.0) - XYW(self.x.0 * rhs.wy.0) + XYW(self.y.0 * rhs.wx.0),
+		//     xyz : XYZ(self.x.0 * rhs.yz.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		//     xyzw: -XYZW(self.w.0 * rhs.xyz.0) + XYZW(self.x.0 * rhs.yzw.0) + XYZW(self.y.0 * rhs.zxw.0) + XYZW(self.z.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			z: self.z.wedge(rhs.s),
+			w: self.w.wedge(rhs.s),
+			wx: self.w.wedge(rhs.x) + self.x.wedge(rhs.w),
+			wy: self.w.wedge(rhs.y) + self.y.wedge(rhs.w),
+			wz: self.w.wedge(rhs.z) + self.z.wedge(rhs.w),
+			yz: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			zx: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			yzw: self.w.wedge(rhs.yz) + self.y.wedge(rhs.wz) + self.z.wedge(rhs.wy),
+			zxw: self.w.wedge(rhs.zx) + self.x.wedge(rhs.wz) + self.z.wedge(rhs.wx),
+			xyw: self.w.wedge(rhs.xy) + self.x.wedge(rhs.wy) + self.y.wedge(rhs.wx),
+			xyz: self.x.wedge(rhs.yz) + self.y.wedge(rhs.zx) + self.z.wedge(rhs.xy),
+			xyzw: self.w.wedge(rhs.xyz) + self.x.wedge(rhs.yzw) + self.y.wedge(rhs.zxw) + self.z.wedge(rhs.xyw),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec4.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : -S(self.w.0 * rhs.xyz.0) + S(self.x.0 * rhs.yzw.0) + S(self.y.0 * rhs.zxw.0) + S(self.z.0 * rhs.xyw.0),
+		//     x   : X(self.x.0 * rhs.xyzw.0),
+		//     y   : Y(self.y.0 * rhs.xyzw.0),
+		//     z   : Z(self.z.0 * rhs.xyzw.0),
+		//     w   : W(self.w.0 * rhs.xyzw.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.w.anti_wedge(rhs.xyz)
+				+ self.x.anti_wedge(rhs.yzw)
+				+ self.y.anti_wedge(rhs.zxw)
+				+ self.z.anti_wedge(rhs.xyw),
+			x: self.x.anti_wedge(rhs.xyzw),
+			y: self.y.anti_wedge(rhs.xyzw),
+			z: self.z.anti_wedge(rhs.xyzw),
+			w: self.w.anti_wedge(rhs.xyzw),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y   : Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z   : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		//     w   : -W(self.x.0 * rhs.wx.0) - W(self.y.0 * rhs.wy.0) - W(self.z.0 * rhs.wz.0),
+		//     wx  : WX(self.y.0 * rhs.xyw.0) - WX(self.z.0 * rhs.zxw.0),
+		//     wy  : -WY(self.x.0 * rhs.xyw.0) + WY(self.z.0 * rhs.yzw.0),
+		//     wz  : WZ(self.x.0 * rhs.zxw.0) - WZ(self.y.0 * rhs.yzw.0),
+		//     yz  : YZ(self.x.0 * rhs.xyz.0),
+		//     zx  : ZX(self.y.0 * rhs.xyz.0),
+		//     xy  : XY(self.z.0 * rhs.xyz.0),
+		//     yzw : YZW(self.x.0 * rhs.xyzw.0),
+		//     zxw : ZXW(self.y.0 * rhs.xyzw.0),
+		//     xyw : XYW(self.z.0 * rhs.xyzw.0),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z),
+			x: self.y.left_contraction(rhs.xy) + self.z.left_contraction(rhs.zx),
+			y: self.x.left_contraction(rhs.xy) + self.z.left_contraction(rhs.yz),
+			z: self.x.left_contraction(rhs.zx) + self.y.left_contraction(rhs.yz),
+			w: self.x.left_contraction(rhs.wx) + self.y.left_contraction(rhs.wy) + self.z.left_contraction(rhs.wz),
+			wx: self.y.left_contraction(rhs.xyw) + self.z.left_contraction(rhs.zxw),
+			wy: self.x.left_contraction(rhs.xyw) + self.z.left_contraction(rhs.yzw),
+			wz: self.x.left_contraction(rhs.zxw) + self.y.left_contraction(rhs.yzw),
+			yz: self.x.left_contraction(rhs.xyz),
+			zx: self.y.left_contraction(rhs.xyz),
+			xy: self.z.left_contraction(rhs.xyz),
+			yzw: self.x.left_contraction(rhs.xyzw),
+			zxw: self.y.left_contraction(rhs.xyzw),
+			xyw: self.z.left_contraction(rhs.xyzw),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Vec4.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x   : X(self.x.0 * rhs.s.0),
+		//     y   : Y(self.y.0 * rhs.s.0),
+		//     z   : Z(self.z.0 * rhs.s.0),
+		//     w   : W(self.w.0 * rhs.s.0),
+		//     wx  : Default::default(),
+		//     wy  : Default::default(),
+		//     wz  : Default::default(),
+		//     yz  : Default::default(),
+		//     zx  : Default::default(),
+		//     xy  : Default::default(),
+		//     yzw : Default::default(),
+		//     zxw : Default::default(),
+		//     xyw : Default::default(),
+		//     xyz : Default::default(),
+		//     xyzw: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z),
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			z: self.z.right_contraction(rhs.s),
+			w: self.w.right_contraction(rhs.s),
+			wx: Default::default(),
+			wy: Default::default(),
+			wz: Default::default(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			yzw: Default::default(),
+			zxw: Default::default(),
+			xyw: Default::default(),
+			xyz: Default::default(),
+			xyzw: Default::default(),
+		}
+	}
+}
+
+// Vec4.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Vec4 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Vec4.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y   : Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z   : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		//     w   : -W(self.x.0 * rhs.wx.0) - W(self.y.0 * rhs.wy.0) - W(self.z.0 * rhs.wz.0),
+		//     wx  : WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+		//     wy  : WY(self.w.0 * rhs.y.0) - WY(self.y.0 * rhs.w.0),
+		//     wz  : WZ(self.w.0 * rhs.z.0) - WZ(self.z.0 * rhs.w.0),
+		//     yz  : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx  : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy  : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     yzw : YZW(self.x.0 * rhs.xyzw.0),
+		//     zxw : ZXW(self.y.0 * rhs.xyzw.0),
+		//     xyw : XYW(self.z.0 * rhs.xyzw.0),
+		//     xyz : Default::default(),
+		//     xyzw: -XYZW(self.w.0 * rhs.xyz.0) + XYZW(self.x.0 * rhs.yzw.0) + XYZW(self.y.0 * rhs.zxw.0) + XYZW(self.z.0 * rhs.xyw.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.xy) + self.z.commutator(rhs.zx),
+			y: self.x.commutator(rhs.xy) + self.z.commutator(rhs.yz),
+			z: self.x.commutator(rhs.zx) + self.y.commutator(rhs.yz),
+			w: self.x.commutator(rhs.wx) + self.y.commutator(rhs.wy) + self.z.commutator(rhs.wz),
+			wx: self.w.commutator(rhs.x) + self.x.commutator(rhs.w),
+			wy: self.w.commutator(rhs.y) + self.y.commutator(rhs.w),
+			wz: self.w.commutator(rhs.z) + self.z.commutator(rhs.w),
+			yz: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			zx: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			yzw: self.x.commutator(rhs.xyzw),
+			zxw: self.y.commutator(rhs.xyzw),
+			xyw: self.z.commutator(rhs.xyzw),
+			xyz: Default::default(),
+			xyzw: self.w.commutator(rhs.xyz)
+				+ self.x.commutator(rhs.yzw)
+				+ self.y.commutator(rhs.zxw)
+				+ self.z.commutator(rhs.xyw),
+		}
+	}
+}
+
+// Vec4.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s   : Default::default(),
+		//     x   : X(self.x.0 * rhs.xyzw.0),
+		//     y   : Y(self.y.0 * rhs.xyzw.0),
+		//     z   : Z(self.z.0 * rhs.xyzw.0),
+		//     w   : W(self.w.0 * rhs.xyzw.0),
+		//     wx  : -WX(self.w.0 * rhs.yzw.0),
+		//     wy  : -WY(self.w.0 * rhs.zxw.0),
+		//     wz  : -WZ(self.w.0 * rhs.xyw.0),
+		//     yz  : -YZ(self.y.0 * rhs.xyw.0) + YZ(self.z.0 * rhs.zxw.0),
+		//     zx  : ZX(self.x.0 * rhs.xyw.0) - ZX(self.z.0 * rhs.yzw.0),
+		//     xy  : -XY(self.x.0 * rhs.zxw.0) + XY(self.y.0 * rhs.yzw.0),
+		//     yzw : YZW(self.w.0 * rhs.wx.0),
+		//     zxw : ZXW(self.w.0 * rhs.wy.0),
+		//     xyw : XYW(self.w.0 * rhs.wz.0),
+		//     xyz : XYZ(self.w.0 * rhs.s.0) + XYZ(self.x.0 * rhs.wx.0) + XYZ(self.y.0 * rhs.wy.0) + XYZ(self.z.0 * rhs.wz.0),
+		//     xyzw: -XYZW(self.w.0 * rhs.w.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.anti_dot(rhs.xyzw),
+			y: self.y.anti_dot(rhs.xyzw),
+			z: self.z.anti_dot(rhs.xyzw),
+			w: self.w.anti_dot(rhs.xyzw),
+			wx: self.w.anti_dot(rhs.yzw),
+			wy: self.w.anti_dot(rhs.zxw),
+			wz: self.w.anti_dot(rhs.xyw),
+			yz: self.y.anti_dot(rhs.xyw) + self.z.anti_dot(rhs.zxw),
+			zx: self.x.anti_dot(rhs.xyw) + self.z.anti_dot(rhs.yzw),
+			xy: self.x.anti_dot(rhs.zxw) + self.y.anti_dot(rhs.yzw),
+			yzw: self.w.anti_dot(rhs.wx),
+			zxw: self.w.anti_dot(rhs.wy),
+			xyw: self.w.anti_dot(rhs.wz),
+			xyz: self.w.anti_dot(rhs.s) + self.x.anti_dot(rhs.wx) + self.y.anti_dot(rhs.wy) + self.z.anti_dot(rhs.wz),
+			xyzw: self.w.anti_dot(rhs.w),
+		}
+	}
+}
@@ -0,0 +1,2083 @@
+//! # Bivector
+//!
+//! ## Operations
+//! ```text
+//! Bivector.geometric(Bivector) -> Rotor
+//! Bivector.anti_geometric(Bivector) -> Rotor
+//! Bivector.dot(Bivector) -> S
+//! Bivector.wedge(Bivector) -> E0123
+//! Bivector.anti_wedge(Bivector) -> S
+//! Bivector.left_contraction(Bivector) -> S
+//! Bivector.right_contraction(Bivector) -> S
+//! Bivector.scalar_product(Bivector) -> S
+//! Bivector.commutator(Bivector) -> Bivector
+//! Bivector.anti_dot(Bivector) -> E0123
+//! Bivector.geometric(Vec4) -> Multivector
+//! Vec4.geometric(Bivector) -> Multivector
+//! Bivector.anti_geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Bivector) -> Multivector
+//! Bivector.dot(Vec4) -> Vec4
+//! Vec4.dot(Bivector) -> Vec4
+//! Bivector.wedge(Vec4) -> Multivector
+//! Vec4.wedge(Bivector) -> Multivector
+//! Vec4.left_contraction(Bivector) -> Vec4
+//! Bivector.right_contraction(Vec4) -> Vec4
+//! Bivector.commutator(Vec4) -> Vec4
+//! Vec4.commutator(Bivector) -> Vec4
+//! Bivector.anti_dot(Vec4) -> Multivector
+//! Vec4.anti_dot(Bivector) -> Multivector
+//! Bivector.geometric(Rotor) -> Rotor
+//! Rotor.geometric(Bivector) -> Rotor
+//! Bivector.anti_geometric(Rotor) -> Rotor
+//! Rotor.anti_geometric(Bivector) -> Rotor
+//! Bivector.dot(Rotor) -> Rotor
+//! Rotor.dot(Bivector) -> Rotor
+//! Bivector.wedge(Rotor) -> Rotor
+//! Rotor.wedge(Bivector) -> Rotor
+//! Bivector.anti_wedge(Rotor) -> Rotor
+//! Rotor.anti_wedge(Bivector) -> Rotor
+//! Bivector.left_contraction(Rotor) -> Rotor
+//! Rotor.left_contraction(Bivector) -> Rotor
+//! Bivector.right_contraction(Rotor) -> Rotor
+//! Rotor.right_contraction(Bivector) -> Rotor
+//! Bivector.scalar_product(Rotor) -> S
+//! Rotor.scalar_product(Bivector) -> S
+//! Bivector.commutator(Rotor) -> Bivector
+//! Rotor.commutator(Bivector) -> Bivector
+//! Bivector.anti_dot(Rotor) -> Rotor
+//! Rotor.anti_dot(Bivector) -> Rotor
+//! Bivector.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Bivector) -> Multivector
+//! Bivector.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Bivector) -> Multivector
+//! Bivector.dot(Multivector) -> Multivector
+//! Multivector.dot(Bivector) -> Multivector
+//! Bivector.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Bivector) -> Multivector
+//! Bivector.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Bivector) -> Multivector
+//! Bivector.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Bivector) -> Multivector
+//! Bivector.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Bivector) -> Multivector
+//! Bivector.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Bivector) -> S
+//! Bivector.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Bivector) -> Multivector
+//! Bivector.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Bivector) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bivector {
+	pub e01: E01,
+	pub e02: E02,
+	pub e03: E03,
+	pub e12: E12,
+	pub e13: E13,
+	pub e23: E23,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Bivector {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Bivector {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Bivector {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.e01.abs_diff_eq(&other.e01, epsilon)
+			&& self.e02.abs_diff_eq(&other.e02, epsilon)
+			&& self.e03.abs_diff_eq(&other.e03, epsilon)
+			&& self.e12.abs_diff_eq(&other.e12, epsilon)
+			&& self.e13.abs_diff_eq(&other.e13, epsilon)
+			&& self.e23.abs_diff_eq(&other.e23, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Bivector {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.e01.relative_eq(&other.e01, epsilon, max_relative)
+			&& self.e02.relative_eq(&other.e02, epsilon, max_relative)
+			&& self.e03.relative_eq(&other.e03, epsilon, max_relative)
+			&& self.e12.relative_eq(&other.e12, epsilon, max_relative)
+			&& self.e13.relative_eq(&other.e13, epsilon, max_relative)
+			&& self.e23.relative_eq(&other.e23, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Bivector {
+	type Output = Bivector;
+	fn rcompl(self) -> Self::Output {
+		Bivector {
+			e01: self.e23.rcompl(),
+			e02: self.e13.rcompl(),
+			e03: self.e12.rcompl(),
+			e12: self.e03.rcompl(),
+			e13: self.e02.rcompl(),
+			e23: self.e01.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Bivector {
+	type Output = Bivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Bivector {
+	type Output = Bivector;
+	fn lcompl(self) -> Self::Output {
+		Bivector {
+			e01: self.e23.lcompl(),
+			e02: self.e13.lcompl(),
+			e03: self.e12.lcompl(),
+			e12: self.e03.lcompl(),
+			e13: self.e02.lcompl(),
+			e23: self.e01.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Bivector {
+	fn rev(self) -> Self {
+		Bivector {
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+		}
+	}
+}
+
+impl AntiReverse for Bivector {
+	fn arev(self) -> Self {
+		Bivector {
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+		}
+	}
+}
+
+impl Involute for Bivector {
+	fn involute(self) -> Self {
+		Bivector {
+			e01: self.e01,
+			e02: self.e02,
+			e03: self.e03,
+			e12: self.e12,
+			e13: self.e13,
+			e23: self.e23,
+		}
+	}
+}
+
+impl Conjugate for Bivector {
+	fn conjugate(self) -> Self {
+		Bivector {
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+		}
+	}
+}
+
+impl Dual for Bivector {
+	type Output = Bivector;
+	fn dual(self) -> Self::Output {
+		Bivector {
+			e01: self.e23.dual(),
+			e02: self.e13.dual(),
+			e03: self.e12.dual(),
+			e12: self.e03.dual(),
+			e13: self.e02.dual(),
+			e23: self.e01.dual(),
+		}
+	}
+}
+
+impl Bulk for Bivector {
+	type Output = Bivector;
+	fn bulk(self) -> Self::Output {
+		Bivector {
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: self.e12,
+			e13: self.e13,
+			e23: self.e23,
+		}
+	}
+}
+
+impl Weight for Bivector {
+	type Output = Bivector;
+	fn weight(self) -> Self::Output {
+		Bivector {
+			e01: self.e01,
+			e02: self.e02,
+			e03: self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+		}
+	}
+}
+
+impl BulkDual for Bivector {
+	type Output = Bivector;
+	fn bulk_dual(self) -> Self::Output {
+		Bivector {
+			e01: self.e23.bulk_dual(),
+			e02: self.e13.bulk_dual(),
+			e03: self.e12.bulk_dual(),
+			e12: self.e03.bulk_dual(),
+			e13: self.e02.bulk_dual(),
+			e23: self.e01.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Bivector {
+	type Output = Bivector;
+	fn weight_dual(self) -> Self::Output {
+		Bivector {
+			e01: self.e23.weight_dual(),
+			e02: self.e13.weight_dual(),
+			e03: self.e12.weight_dual(),
+			e12: self.e03.weight_dual(),
+			e13: self.e02.weight_dual(),
+			e23: self.e01.weight_dual(),
+		}
+	}
+}
+
+// Omitted: Bivector.square()  (not a scalar)
+
+impl Norm for Bivector {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.e01.0 * self.e01.0
+			+ self.e02.0 * self.e02.0
+			+ self.e03.0 * self.e03.0
+			+ self.e12.0 * self.e12.0
+			+ self.e13.0 * self.e13.0
+			+ self.e23.0 * self.e23.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.e01.0 * self.e01.0
+			+ self.e02.0 * self.e02.0
+			+ self.e03.0 * self.e03.0
+			+ self.e12.0 * self.e12.0
+			+ self.e13.0 * self.e13.0
+			+ self.e23.0 * self.e23.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Bivector {
+			e01: self.e01 * scale,
+			e02: self.e02 * scale,
+			e03: self.e03 * scale,
+			e12: self.e12 * scale,
+			e13: self.e13 * scale,
+			e23: self.e23 * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Bivector {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 6] {
+		[self.e01.0, self.e02.0, self.e03.0, self.e12.0, self.e13.0, self.e23.0]
+	}
+}
+
+impl From<[f64; 6]> for Bivector {
+	fn from(a: [f64; 6]) -> Self {
+		Bivector {
+			e01: E01(a[0]),
+			e02: E02(a[1]),
+			e03: E03(a[2]),
+			e12: E12(a[3]),
+			e13: E13(a[4]),
+			e23: E23(a[5]),
+		}
+	}
+}
+
+impl From<Bivector> for [f64; 6] {
+	fn from(v: Bivector) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Bivector> for Multivector {
+	fn from(v: Bivector) -> Self {
+		Multivector {
+			e01: v.e01,
+			e02: v.e02,
+			e03: v.e03,
+			e12: v.e12,
+			e13: v.e13,
+			e23: v.e23,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Bivector {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Bivector` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.e0.0 == 0.0
+			&& mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.e012.0 == 0.0
+			&& mv.e013.0 == 0.0
+			&& mv.e023.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e0123.0 == 0.0
+		{
+			Ok(Bivector {
+				e01: mv.e01,
+				e02: mv.e02,
+				e03: mv.e03,
+				e12: mv.e12,
+				e13: mv.e13,
+				e23: mv.e23,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Bivector OP Vec4:
+
+// Bivector.geometric(Vec4) -> Multivector
+impl Geometric<Vec4> for Bivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0),
+		//     e1   : -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0),
+		//     e2   : -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0),
+		//     e3   : -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.e01.0 * rhs.y.0) - E012(self.e02.0 * rhs.x.0) + E012(self.e12.0 * rhs.t.0),
+		//     e013 : E013(self.e01.0 * rhs.z.0) - E013(self.e03.0 * rhs.x.0) + E013(self.e13.0 * rhs.t.0),
+		//     e023 : E023(self.e02.0 * rhs.z.0) - E023(self.e03.0 * rhs.y.0) + E023(self.e23.0 * rhs.t.0),
+		//     e123 : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e01.geometric(rhs.x) + self.e02.geometric(rhs.y) + self.e03.geometric(rhs.z),
+			e1: self.e01.geometric(rhs.t) + self.e12.geometric(rhs.y) + self.e13.geometric(rhs.z),
+			e2: self.e02.geometric(rhs.t) + self.e12.geometric(rhs.x) + self.e23.geometric(rhs.z),
+			e3: self.e03.geometric(rhs.t) + self.e13.geometric(rhs.x) + self.e23.geometric(rhs.y),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e01.geometric(rhs.y) + self.e02.geometric(rhs.x) + self.e12.geometric(rhs.t),
+			e013: self.e01.geometric(rhs.z) + self.e03.geometric(rhs.x) + self.e13.geometric(rhs.t),
+			e023: self.e02.geometric(rhs.z) + self.e03.geometric(rhs.y) + self.e23.geometric(rhs.t),
+			e123: self.e12.geometric(rhs.z) + self.e13.geometric(rhs.y) + self.e23.geometric(rhs.x),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec4> for Bivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Bivector.anti_geometric(Vec4) -> Multivector
+impl AntiGeometric<Vec4> for Bivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.e12.0 * rhs.z.0) - E0(self.e13.0 * rhs.y.0) + E0(self.e23.0 * rhs.x.0),
+		//     e1   : E1(self.e02.0 * rhs.z.0) - E1(self.e03.0 * rhs.y.0) + E1(self.e23.0 * rhs.t.0),
+		//     e2   : -E2(self.e01.0 * rhs.z.0) + E2(self.e03.0 * rhs.x.0) - E2(self.e13.0 * rhs.t.0),
+		//     e3   : E3(self.e01.0 * rhs.y.0) - E3(self.e02.0 * rhs.x.0) + E3(self.e12.0 * rhs.t.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.e03.0 * rhs.t.0) + E012(self.e13.0 * rhs.x.0) + E012(self.e23.0 * rhs.y.0),
+		//     e013 : E013(self.e02.0 * rhs.t.0) - E013(self.e12.0 * rhs.x.0) + E013(self.e23.0 * rhs.z.0),
+		//     e023 : -E023(self.e01.0 * rhs.t.0) - E023(self.e12.0 * rhs.y.0) - E023(self.e13.0 * rhs.z.0),
+		//     e123 : -E123(self.e01.0 * rhs.x.0) - E123(self.e02.0 * rhs.y.0) - E123(self.e03.0 * rhs.z.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e12.anti_geometric(rhs.z) + self.e13.anti_geometric(rhs.y) + self.e23.anti_geometric(rhs.x),
+			e1: self.e02.anti_geometric(rhs.z) + self.e03.anti_geometric(rhs.y) + self.e23.anti_geometric(rhs.t),
+			e2: self.e01.anti_geometric(rhs.z) + self.e03.anti_geometric(rhs.x) + self.e13.anti_geometric(rhs.t),
+			e3: self.e01.anti_geometric(rhs.y) + self.e02.anti_geometric(rhs.x) + self.e12.anti_geometric(rhs.t),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e03.anti_geometric(rhs.t) + self.e13.anti_geometric(rhs.x) + self.e23.anti_geometric(rhs.y),
+			e013: self.e02.anti_geometric(rhs.t) + self.e12.anti_geometric(rhs.x) + self.e23.anti_geometric(rhs.z),
+			e023: self.e01.anti_geometric(rhs.t) + self.e12.anti_geometric(rhs.y) + self.e13.anti_geometric(rhs.z),
+			e123: self.e01.anti_geometric(rhs.x) + self.e02.anti_geometric(rhs.y) + self.e03.anti_geometric(rhs.z),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Bivector.dot(Vec4) -> Vec4
+impl Dot<Vec4> for Bivector {
+	type Output = Vec4;
+	fn dot(self, rhs: Vec4) -> Self::Output {
+		// Vec4 {
+		//     t: -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0),
+		//     x: -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0),
+		//     y: -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0),
+		//     z: -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0),
+		// }
+		Vec4 {
+			t: self.e01.dot(rhs.x) + self.e02.dot(rhs.y) + self.e03.dot(rhs.z),
+			x: self.e01.dot(rhs.t) + self.e12.dot(rhs.y) + self.e13.dot(rhs.z),
+			y: self.e02.dot(rhs.t) + self.e12.dot(rhs.x) + self.e23.dot(rhs.z),
+			z: self.e03.dot(rhs.t) + self.e13.dot(rhs.x) + self.e23.dot(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec4> for Bivector {
+	type Output = Vec4;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Bivector.wedge(Vec4) -> Multivector
+impl Wedge<Vec4> for Bivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : Default::default(),
+		//     e1   : Default::default(),
+		//     e2   : Default::default(),
+		//     e3   : Default::default(),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.e01.0 * rhs.y.0) - E012(self.e02.0 * rhs.x.0) + E012(self.e12.0 * rhs.t.0),
+		//     e013 : E013(self.e01.0 * rhs.z.0) - E013(self.e03.0 * rhs.x.0) + E013(self.e13.0 * rhs.t.0),
+		//     e023 : E023(self.e02.0 * rhs.z.0) - E023(self.e03.0 * rhs.y.0) + E023(self.e23.0 * rhs.t.0),
+		//     e123 : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e01.wedge(rhs.y) + self.e02.wedge(rhs.x) + self.e12.wedge(rhs.t),
+			e013: self.e01.wedge(rhs.z) + self.e03.wedge(rhs.x) + self.e13.wedge(rhs.t),
+			e023: self.e02.wedge(rhs.z) + self.e03.wedge(rhs.y) + self.e23.wedge(rhs.t),
+			e123: self.e12.wedge(rhs.z) + self.e13.wedge(rhs.y) + self.e23.wedge(rhs.x),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec4> for Bivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec4> for Bivector {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec4> for Bivector {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec4> for Bivector {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Bivector.right_contraction(Vec4) -> Vec4
+impl RightContraction<Vec4> for Bivector {
+	type Output = Vec4;
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
+		// Vec4 {
+		//     t: -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0),
+		//     x: -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0),
+		//     y: -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0),
+		//     z: -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0),
+		// }
+		Vec4 {
+			t: self.e01.right_contraction(rhs.x)
+				+ self.e02.right_contraction(rhs.y)
+				+ self.e03.right_contraction(rhs.z),
+			x: self.e01.right_contraction(rhs.t)
+				+ self.e12.right_contraction(rhs.y)
+				+ self.e13.right_contraction(rhs.z),
+			y: self.e02.right_contraction(rhs.t)
+				+ self.e12.right_contraction(rhs.x)
+				+ self.e23.right_contraction(rhs.z),
+			z: self.e03.right_contraction(rhs.t)
+				+ self.e13.right_contraction(rhs.x)
+				+ self.e23.right_contraction(rhs.y),
+		}
+	}
+}
+
+impl ScalarProduct<Vec4> for Bivector {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Bivector.commutator(Vec4) -> Vec4
+impl Commutator<Vec4> for Bivector {
+	type Output = Vec4;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// Vec4 {
+		//     t: -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0),
+		//     x: -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0),
+		//     y: -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0),
+		//     z: -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0),
+		// }
+		Vec4 {
+			t: self.e01.commutator(rhs.x) + self.e02.commutator(rhs.y) + self.e03.commutator(rhs.z),
+			x: self.e01.commutator(rhs.t) + self.e12.commutator(rhs.y) + self.e13.commutator(rhs.z),
+			y: self.e02.commutator(rhs.t) + self.e12.commutator(rhs.x) + self.e23.commutator(rhs.z),
+			z: self.e03.commutator(rhs.t) + self.e13.commutator(rhs.x) + self.e23.commutator(rhs.y),
+		}
+	}
+}
+
+// Bivector.anti_dot(Vec4) -> Multivector
+impl AntiDot<Vec4> for Bivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : Default::default(),
+		//     e1   : Default::default(),
+		//     e2   : Default::default(),
+		//     e3   : Default::default(),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.e03.0 * rhs.t.0) + E012(self.e13.0 * rhs.x.0) + E012(self.e23.0 * rhs.y.0),
+		//     e013 : E013(self.e02.0 * rhs.t.0) - E013(self.e12.0 * rhs.x.0) + E013(self.e23.0 * rhs.z.0),
+		//     e023 : -E023(self.e01.0 * rhs.t.0) - E023(self.e12.0 * rhs.y.0) - E023(self.e13.0 * rhs.z.0),
+		//     e123 : -E123(self.e01.0 * rhs.x.0) - E123(self.e02.0 * rhs.y.0) - E123(self.e03.0 * rhs.z.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e03.anti_dot(rhs.t) + self.e13.anti_dot(rhs.x) + self.e23.anti_dot(rhs.y),
+			e013: self.e02.anti_dot(rhs.t) + self.e12.anti_dot(rhs.x) + self.e23.anti_dot(rhs.z),
+			e023: self.e01.anti_dot(rhs.t) + self.e12.anti_dot(rhs.y) + self.e13.anti_dot(rhs.z),
+			e123: self.e01.anti_dot(rhs.x) + self.e02.anti_dot(rhs.y) + self.e03.anti_dot(rhs.z),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Bivector OP Bivector:
+
+// Bivector.geometric(Bivector) -> Rotor
+impl Geometric<Bivector> for Bivector {
+	type Output = Rotor;
+	fn geometric(self, rhs: Bivector) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e01  : E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23  : -E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0),
+		// }
+		Rotor {
+			s: self.e01.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e03)
+				+ self.e12.geometric(rhs.e12)
+				+ self.e13.geometric(rhs.e13)
+				+ self.e23.geometric(rhs.e23),
+			e01: self.e02.geometric(rhs.e12)
+				+ self.e03.geometric(rhs.e13)
+				+ self.e12.geometric(rhs.e02)
+				+ self.e13.geometric(rhs.e03),
+			e02: self.e01.geometric(rhs.e12)
+				+ self.e03.geometric(rhs.e23)
+				+ self.e12.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e03),
+			e03: self.e01.geometric(rhs.e13)
+				+ self.e02.geometric(rhs.e23)
+				+ self.e13.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e02),
+			e12: self.e01.geometric(rhs.e02)
+				+ self.e02.geometric(rhs.e01)
+				+ self.e13.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e13),
+			e13: self.e01.geometric(rhs.e03)
+				+ self.e03.geometric(rhs.e01)
+				+ self.e12.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e12),
+			e23: self.e02.geometric(rhs.e03)
+				+ self.e03.geometric(rhs.e02)
+				+ self.e12.geometric(rhs.e13)
+				+ self.e13.geometric(rhs.e12),
+			e0123: self.e01.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e12)
+				+ self.e12.geometric(rhs.e03)
+				+ self.e13.geometric(rhs.e02)
+				+ self.e23.geometric(rhs.e01),
+		}
+	}
+}
+
+impl std::ops::Mul<Bivector> for Bivector {
+	type Output = Rotor;
+	fn mul(self, rhs: Bivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Bivector.anti_geometric(Bivector) -> Rotor
+impl AntiGeometric<Bivector> for Bivector {
+	type Output = Rotor;
+	fn anti_geometric(self, rhs: Bivector) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e23.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0),
+		//     e01  : E01(self.e02.0 * rhs.e03.0) - E01(self.e03.0 * rhs.e02.0) - E01(self.e12.0 * rhs.e13.0) + E01(self.e13.0 * rhs.e12.0),
+		//     e02  : -E02(self.e01.0 * rhs.e03.0) + E02(self.e03.0 * rhs.e01.0) - E02(self.e12.0 * rhs.e23.0) + E02(self.e23.0 * rhs.e12.0),
+		//     e03  : E03(self.e01.0 * rhs.e02.0) - E03(self.e02.0 * rhs.e01.0) - E03(self.e13.0 * rhs.e23.0) + E03(self.e23.0 * rhs.e13.0),
+		//     e12  : -E12(self.e01.0 * rhs.e13.0) - E12(self.e02.0 * rhs.e23.0) + E12(self.e13.0 * rhs.e01.0) + E12(self.e23.0 * rhs.e02.0),
+		//     e13  : E13(self.e01.0 * rhs.e12.0) - E13(self.e03.0 * rhs.e23.0) - E13(self.e12.0 * rhs.e01.0) + E13(self.e23.0 * rhs.e03.0),
+		//     e23  : E23(self.e02.0 * rhs.e12.0) + E23(self.e03.0 * rhs.e13.0) - E23(self.e12.0 * rhs.e02.0) - E23(self.e13.0 * rhs.e03.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: self.e01.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e12)
+				+ self.e12.anti_geometric(rhs.e03)
+				+ self.e13.anti_geometric(rhs.e02)
+				+ self.e23.anti_geometric(rhs.e01),
+			e01: self.e02.anti_geometric(rhs.e03)
+				+ self.e03.anti_geometric(rhs.e02)
+				+ self.e12.anti_geometric(rhs.e13)
+				+ self.e13.anti_geometric(rhs.e12),
+			e02: self.e01.anti_geometric(rhs.e03)
+				+ self.e03.anti_geometric(rhs.e01)
+				+ self.e12.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e12),
+			e03: self.e01.anti_geometric(rhs.e02)
+				+ self.e02.anti_geometric(rhs.e01)
+				+ self.e13.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e13),
+			e12: self.e01.anti_geometric(rhs.e13)
+				+ self.e02.anti_geometric(rhs.e23)
+				+ self.e13.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e02),
+			e13: self.e01.anti_geometric(rhs.e12)
+				+ self.e03.anti_geometric(rhs.e23)
+				+ self.e12.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e03),
+			e23: self.e02.anti_geometric(rhs.e12)
+				+ self.e03.anti_geometric(rhs.e13)
+				+ self.e12.anti_geometric(rhs.e02)
+				+ self.e13.anti_geometric(rhs.e03),
+			e0123: self.e01.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e03)
+				+ self.e12.anti_geometric(rhs.e12)
+				+ self.e13.anti_geometric(rhs.e13)
+				+ self.e23.anti_geometric(rhs.e23),
+		}
+	}
+}
+
+// Bivector.dot(Bivector) -> S
+impl Dot<Bivector> for Bivector {
+	type Output = S;
+	fn dot(self, rhs: Bivector) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		self.e01.dot(rhs.e01)
+			+ self.e02.dot(rhs.e02)
+			+ self.e03.dot(rhs.e03)
+			+ self.e12.dot(rhs.e12)
+			+ self.e13.dot(rhs.e13)
+			+ self.e23.dot(rhs.e23)
+	}
+}
+
+impl std::ops::BitOr<Bivector> for Bivector {
+	type Output = S;
+	fn bitor(self, rhs: Bivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Bivector.wedge(Bivector) -> E0123
+impl Wedge<Bivector> for Bivector {
+	type Output = E0123;
+	fn wedge(self, rhs: Bivector) -> Self::Output {
+		// E0123(self.e01.0 * rhs.e23.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0)
+		self.e01.wedge(rhs.e23)
+			+ self.e02.wedge(rhs.e13)
+			+ self.e03.wedge(rhs.e12)
+			+ self.e12.wedge(rhs.e03)
+			+ self.e13.wedge(rhs.e02)
+			+ self.e23.wedge(rhs.e01)
+	}
+}
+
+impl std::ops::BitXor<Bivector> for Bivector {
+	type Output = E0123;
+	fn bitxor(self, rhs: Bivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Bivector.anti_wedge(Bivector) -> S
+impl AntiWedge<Bivector> for Bivector {
+	type Output = S;
+	fn anti_wedge(self, rhs: Bivector) -> Self::Output {
+		// S(self.e01.0 * rhs.e23.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0)
+		self.e01.anti_wedge(rhs.e23)
+			+ self.e02.anti_wedge(rhs.e13)
+			+ self.e03.anti_wedge(rhs.e12)
+			+ self.e12.anti_wedge(rhs.e03)
+			+ self.e13.anti_wedge(rhs.e02)
+			+ self.e23.anti_wedge(rhs.e01)
+	}
+}
+
+impl std::ops::BitAnd<Bivector> for Bivector {
+	type Output = S;
+	fn bitand(self, rhs: Bivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Bivector.left_contraction(Bivector) -> S
+impl LeftContraction<Bivector> for Bivector {
+	type Output = S;
+	fn left_contraction(self, rhs: Bivector) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		self.e01.left_contraction(rhs.e01)
+			+ self.e02.left_contraction(rhs.e02)
+			+ self.e03.left_contraction(rhs.e03)
+			+ self.e12.left_contraction(rhs.e12)
+			+ self.e13.left_contraction(rhs.e13)
+			+ self.e23.left_contraction(rhs.e23)
+	}
+}
+
+// Bivector.right_contraction(Bivector) -> S
+impl RightContraction<Bivector> for Bivector {
+	type Output = S;
+	fn right_contraction(self, rhs: Bivector) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		self.e01.right_contraction(rhs.e01)
+			+ self.e02.right_contraction(rhs.e02)
+			+ self.e03.right_contraction(rhs.e03)
+			+ self.e12.right_contraction(rhs.e12)
+			+ self.e13.right_contraction(rhs.e13)
+			+ self.e23.right_contraction(rhs.e23)
+	}
+}
+
+// Bivector.scalar_product(Bivector) -> S
+impl ScalarProduct<Bivector> for Bivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Bivector) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		self.e01.scalar_product(rhs.e01)
+			+ self.e02.scalar_product(rhs.e02)
+			+ self.e03.scalar_product(rhs.e03)
+			+ self.e12.scalar_product(rhs.e12)
+			+ self.e13.scalar_product(rhs.e13)
+			+ self.e23.scalar_product(rhs.e23)
+	}
+}
+
+// Bivector.commutator(Bivector) -> Bivector
+impl Commutator<Bivector> for Bivector {
+	type Output = Bivector;
+	fn commutator(self, rhs: Bivector) -> Self::Output {
+		// Bivector {
+		//     e01: E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0),
+		//     e02: -E02(self.e01.0 * rhs.e12.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03: -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12: -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13: -E13(self.e01.0 * rhs.e03.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23: -E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0),
+		// }
+		Bivector {
+			e01: self.e02.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e13)
+				+ self.e12.commutator(rhs.e02)
+				+ self.e13.commutator(rhs.e03),
+			e02: self.e01.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e23)
+				+ self.e12.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e03),
+			e03: self.e01.commutator(rhs.e13)
+				+ self.e02.commutator(rhs.e23)
+				+ self.e13.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e02),
+			e12: self.e01.commutator(rhs.e02)
+				+ self.e02.commutator(rhs.e01)
+				+ self.e13.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e13),
+			e13: self.e01.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e01)
+				+ self.e12.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e12),
+			e23: self.e02.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e02)
+				+ self.e12.commutator(rhs.e13)
+				+ self.e13.commutator(rhs.e12),
+		}
+	}
+}
+
+// Bivector.anti_dot(Bivector) -> E0123
+impl AntiDot<Bivector> for Bivector {
+	type Output = E0123;
+	fn anti_dot(self, rhs: Bivector) -> Self::Output {
+		// -E0123(self.e01.0 * rhs.e01.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0)
+		self.e01.anti_dot(rhs.e01)
+			+ self.e02.anti_dot(rhs.e02)
+			+ self.e03.anti_dot(rhs.e03)
+			+ self.e12.anti_dot(rhs.e12)
+			+ self.e13.anti_dot(rhs.e13)
+			+ self.e23.anti_dot(rhs.e23)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Bivector OP Rotor:
+
+// Bivector.geometric(Rotor) -> Rotor
+impl Geometric<Rotor> for Bivector {
+	type Output = Rotor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) + E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0) - E01(self.e23.0 * rhs.e0123.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e02.0 * rhs.s.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) + E02(self.e13.0 * rhs.e0123.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e03.0 * rhs.s.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) - E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0),
+		// }
+		Rotor {
+			s: self.e01.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e03)
+				+ self.e12.geometric(rhs.e12)
+				+ self.e13.geometric(rhs.e13)
+				+ self.e23.geometric(rhs.e23),
+			e01: self.e01.geometric(rhs.s)
+				+ self.e02.geometric(rhs.e12)
+				+ self.e03.geometric(rhs.e13)
+				+ self.e12.geometric(rhs.e02)
+				+ self.e13.geometric(rhs.e03)
+				+ self.e23.geometric(rhs.e0123),
+			e02: self.e01.geometric(rhs.e12)
+				+ self.e02.geometric(rhs.s)
+				+ self.e03.geometric(rhs.e23)
+				+ self.e12.geometric(rhs.e01)
+				+ self.e13.geometric(rhs.e0123)
+				+ self.e23.geometric(rhs.e03),
+			e03: self.e01.geometric(rhs.e13)
+				+ self.e02.geometric(rhs.e23)
+				+ self.e03.geometric(rhs.s)
+				+ self.e12.geometric(rhs.e0123)
+				+ self.e13.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e02),
+			e12: self.e01.geometric(rhs.e02)
+				+ self.e02.geometric(rhs.e01)
+				+ self.e03.geometric(rhs.e0123)
+				+ self.e12.geometric(rhs.s)
+				+ self.e13.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e13),
+			e13: self.e01.geometric(rhs.e03)
+				+ self.e02.geometric(rhs.e0123)
+				+ self.e03.geometric(rhs.e01)
+				+ self.e12.geometric(rhs.e23)
+				+ self.e13.geometric(rhs.s)
+				+ self.e23.geometric(rhs.e12),
+			e23: self.e01.geometric(rhs.e0123)
+				+ self.e02.geometric(rhs.e03)
+				+ self.e03.geometric(rhs.e02)
+				+ self.e12.geometric(rhs.e13)
+				+ self.e13.geometric(rhs.e12)
+				+ self.e23.geometric(rhs.s),
+			e0123: self.e01.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e12)
+				+ self.e12.geometric(rhs.e03)
+				+ self.e13.geometric(rhs.e02)
+				+ self.e23.geometric(rhs.e01),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Bivector {
+	type Output = Rotor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Bivector.anti_geometric(Rotor) -> Rotor
+impl AntiGeometric<Rotor> for Bivector {
+	type Output = Rotor;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e23.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e02.0 * rhs.e03.0) - E01(self.e03.0 * rhs.e02.0) - E01(self.e12.0 * rhs.e13.0) + E01(self.e13.0 * rhs.e12.0) + E01(self.e23.0 * rhs.s.0),
+		//     e02  : -E02(self.e01.0 * rhs.e03.0) + E02(self.e02.0 * rhs.e0123.0) + E02(self.e03.0 * rhs.e01.0) - E02(self.e12.0 * rhs.e23.0) - E02(self.e13.0 * rhs.s.0) + E02(self.e23.0 * rhs.e12.0),
+		//     e03  : E03(self.e01.0 * rhs.e02.0) - E03(self.e02.0 * rhs.e01.0) + E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0) - E03(self.e13.0 * rhs.e23.0) + E03(self.e23.0 * rhs.e13.0),
+		//     e12  : -E12(self.e01.0 * rhs.e13.0) - E12(self.e02.0 * rhs.e23.0) - E12(self.e03.0 * rhs.s.0) + E12(self.e12.0 * rhs.e0123.0) + E12(self.e13.0 * rhs.e01.0) + E12(self.e23.0 * rhs.e02.0),
+		//     e13  : E13(self.e01.0 * rhs.e12.0) + E13(self.e02.0 * rhs.s.0) - E13(self.e03.0 * rhs.e23.0) - E13(self.e12.0 * rhs.e01.0) + E13(self.e13.0 * rhs.e0123.0) + E13(self.e23.0 * rhs.e03.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) + E23(self.e02.0 * rhs.e12.0) + E23(self.e03.0 * rhs.e13.0) - E23(self.e12.0 * rhs.e02.0) - E23(self.e13.0 * rhs.e03.0) + E23(self.e23.0 * rhs.e0123.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: self.e01.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e12)
+				+ self.e12.anti_geometric(rhs.e03)
+				+ self.e13.anti_geometric(rhs.e02)
+				+ self.e23.anti_geometric(rhs.e01),
+			e01: self.e01.anti_geometric(rhs.e0123)
+				+ self.e02.anti_geometric(rhs.e03)
+				+ self.e03.anti_geometric(rhs.e02)
+				+ self.e12.anti_geometric(rhs.e13)
+				+ self.e13.anti_geometric(rhs.e12)
+				+ self.e23.anti_geometric(rhs.s),
+			e02: self.e01.anti_geometric(rhs.e03)
+				+ self.e02.anti_geometric(rhs.e0123)
+				+ self.e03.anti_geometric(rhs.e01)
+				+ self.e12.anti_geometric(rhs.e23)
+				+ self.e13.anti_geometric(rhs.s)
+				+ self.e23.anti_geometric(rhs.e12),
+			e03: self.e01.anti_geometric(rhs.e02)
+				+ self.e02.anti_geometric(rhs.e01)
+				+ self.e03.anti_geometric(rhs.e0123)
+				+ self.e12.anti_geometric(rhs.s)
+				+ self.e13.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e13),
+			e12: self.e01.anti_geometric(rhs.e13)
+				+ self.e02.anti_geometric(rhs.e23)
+				+ self.e03.anti_geometric(rhs.s)
+				+ self.e12.anti_geometric(rhs.e0123)
+				+ self.e13.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e02),
+			e13: self.e01.anti_geometric(rhs.e12)
+				+ self.e02.anti_geometric(rhs.s)
+				+ self.e03.anti_geometric(rhs.e23)
+				+ self.e12.anti_geometric(rhs.e01)
+				+ self.e13.anti_geometric(rhs.e0123)
+				+ self.e23.anti_geometric(rhs.e03),
+			e23: self.e01.anti_geometric(rhs.s)
+				+ self.e02.anti_geometric(rhs.e12)
+				+ self.e03.anti_geometric(rhs.e13)
+				+ self.e12.anti_geometric(rhs.e02)
+				+ self.e13.anti_geometric(rhs.e03)
+				+ self.e23.anti_geometric(rhs.e0123),
+			e0123: self.e01.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e03)
+				+ self.e12.anti_geometric(rhs.e12)
+				+ self.e13.anti_geometric(rhs.e13)
+				+ self.e23.anti_geometric(rhs.e23),
+		}
+	}
+}
+
+// Bivector.dot(Rotor) -> Rotor
+impl Dot<Rotor> for Bivector {
+	type Output = Rotor;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e23.0 * rhs.e0123.0),
+		//     e02  : E02(self.e02.0 * rhs.s.0) + E02(self.e13.0 * rhs.e0123.0),
+		//     e03  : E03(self.e03.0 * rhs.s.0) - E03(self.e12.0 * rhs.e0123.0),
+		//     e12  : E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0),
+		//     e13  : -E13(self.e02.0 * rhs.e0123.0) + E13(self.e13.0 * rhs.s.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.e23.0 * rhs.s.0),
+		//     e0123: Default::default(),
+		// }
+		Rotor {
+			s: self.e01.dot(rhs.e01)
+				+ self.e02.dot(rhs.e02)
+				+ self.e03.dot(rhs.e03)
+				+ self.e12.dot(rhs.e12)
+				+ self.e13.dot(rhs.e13)
+				+ self.e23.dot(rhs.e23),
+			e01: self.e01.dot(rhs.s) + self.e23.dot(rhs.e0123),
+			e02: self.e02.dot(rhs.s) + self.e13.dot(rhs.e0123),
+			e03: self.e03.dot(rhs.s) + self.e12.dot(rhs.e0123),
+			e12: self.e03.dot(rhs.e0123) + self.e12.dot(rhs.s),
+			e13: self.e02.dot(rhs.e0123) + self.e13.dot(rhs.s),
+			e23: self.e01.dot(rhs.e0123) + self.e23.dot(rhs.s),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Bivector {
+	type Output = Rotor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Bivector.wedge(Rotor) -> Rotor
+impl Wedge<Rotor> for Bivector {
+	type Output = Rotor;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : Default::default(),
+		//     e01  : E01(self.e01.0 * rhs.s.0),
+		//     e02  : E02(self.e02.0 * rhs.s.0),
+		//     e03  : E03(self.e03.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0),
+		// }
+		Rotor {
+			s: Default::default(),
+			e01: self.e01.wedge(rhs.s),
+			e02: self.e02.wedge(rhs.s),
+			e03: self.e03.wedge(rhs.s),
+			e12: self.e12.wedge(rhs.s),
+			e13: self.e13.wedge(rhs.s),
+			e23: self.e23.wedge(rhs.s),
+			e0123: self.e01.wedge(rhs.e23)
+				+ self.e02.wedge(rhs.e13)
+				+ self.e03.wedge(rhs.e12)
+				+ self.e12.wedge(rhs.e03)
+				+ self.e13.wedge(rhs.e02)
+				+ self.e23.wedge(rhs.e01),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Bivector {
+	type Output = Rotor;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Bivector.anti_wedge(Rotor) -> Rotor
+impl AntiWedge<Rotor> for Bivector {
+	type Output = Rotor;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e23.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0),
+		//     e02  : E02(self.e02.0 * rhs.e0123.0),
+		//     e03  : E03(self.e03.0 * rhs.e0123.0),
+		//     e12  : E12(self.e12.0 * rhs.e0123.0),
+		//     e13  : E13(self.e13.0 * rhs.e0123.0),
+		//     e23  : E23(self.e23.0 * rhs.e0123.0),
+		//     e0123: Default::default(),
+		// }
+		Rotor {
+			s: self.e01.anti_wedge(rhs.e23)
+				+ self.e02.anti_wedge(rhs.e13)
+				+ self.e03.anti_wedge(rhs.e12)
+				+ self.e12.anti_wedge(rhs.e03)
+				+ self.e13.anti_wedge(rhs.e02)
+				+ self.e23.anti_wedge(rhs.e01),
+			e01: self.e01.anti_wedge(rhs.e0123),
+			e02: self.e02.anti_wedge(rhs.e0123),
+			e03: self.e03.anti_wedge(rhs.e0123),
+			e12: self.e12.anti_wedge(rhs.e0123),
+			e13: self.e13.anti_wedge(rhs.e0123),
+			e23: self.e23.anti_wedge(rhs.e0123),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Bivector {
+	type Output = Rotor;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Bivector.left_contraction(Rotor) -> Rotor
+impl LeftContraction<Rotor> for Bivector {
+	type Output = Rotor;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e01  : -E01(self.e23.0 * rhs.e0123.0),
+		//     e02  : E02(self.e13.0 * rhs.e0123.0),
+		//     e03  : -E03(self.e12.0 * rhs.e0123.0),
+		//     e12  : E12(self.e03.0 * rhs.e0123.0),
+		//     e13  : -E13(self.e02.0 * rhs.e0123.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0),
+		//     e0123: Default::default(),
+		// }
+		Rotor {
+			s: self.e01.left_contraction(rhs.e01)
+				+ self.e02.left_contraction(rhs.e02)
+				+ self.e03.left_contraction(rhs.e03)
+				+ self.e12.left_contraction(rhs.e12)
+				+ self.e13.left_contraction(rhs.e13)
+				+ self.e23.left_contraction(rhs.e23),
+			e01: self.e23.left_contraction(rhs.e0123),
+			e02: self.e13.left_contraction(rhs.e0123),
+			e03: self.e12.left_contraction(rhs.e0123),
+			e12: self.e03.left_contraction(rhs.e0123),
+			e13: self.e02.left_contraction(rhs.e0123),
+			e23: self.e01.left_contraction(rhs.e0123),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Bivector.right_contraction(Rotor) -> Rotor
+impl RightContraction<Rotor> for Bivector {
+	type Output = Rotor;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0),
+		//     e02  : E02(self.e02.0 * rhs.s.0),
+		//     e03  : E03(self.e03.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e0123: Default::default(),
+		// }
+		Rotor {
+			s: self.e01.right_contraction(rhs.e01)
+				+ self.e02.right_contraction(rhs.e02)
+				+ self.e03.right_contraction(rhs.e03)
+				+ self.e12.right_contraction(rhs.e12)
+				+ self.e13.right_contraction(rhs.e13)
+				+ self.e23.right_contraction(rhs.e23),
+			e01: self.e01.right_contraction(rhs.s),
+			e02: self.e02.right_contraction(rhs.s),
+			e03: self.e03.right_contraction(rhs.s),
+			e12: self.e12.right_contraction(rhs.s),
+			e13: self.e13.right_contraction(rhs.s),
+			e23: self.e23.right_contraction(rhs.s),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Bivector.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Bivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		self.e01.scalar_product(rhs.e01)
+			+ self.e02.scalar_product(rhs.e02)
+			+ self.e03.scalar_product(rhs.e03)
+			+ self.e12.scalar_product(rhs.e12)
+			+ self.e13.scalar_product(rhs.e13)
+			+ self.e23.scalar_product(rhs.e23)
+	}
+}
+
+// Bivector.commutator(Rotor) -> Bivector
+impl Commutator<Rotor> for Bivector {
+	type Output = Bivector;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Bivector {
+		//     e01: E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0),
+		//     e02: -E02(self.e01.0 * rhs.e12.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03: -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12: -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13: -E13(self.e01.0 * rhs.e03.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23: -E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0),
+		// }
+		Bivector {
+			e01: self.e02.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e13)
+				+ self.e12.commutator(rhs.e02)
+				+ self.e13.commutator(rhs.e03),
+			e02: self.e01.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e23)
+				+ self.e12.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e03),
+			e03: self.e01.commutator(rhs.e13)
+				+ self.e02.commutator(rhs.e23)
+				+ self.e13.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e02),
+			e12: self.e01.commutator(rhs.e02)
+				+ self.e02.commutator(rhs.e01)
+				+ self.e13.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e13),
+			e13: self.e01.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e01)
+				+ self.e12.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e12),
+			e23: self.e02.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e02)
+				+ self.e12.commutator(rhs.e13)
+				+ self.e13.commutator(rhs.e12),
+		}
+	}
+}
+
+// Bivector.anti_dot(Rotor) -> Rotor
+impl AntiDot<Rotor> for Bivector {
+	type Output = Rotor;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : Default::default(),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e23.0 * rhs.s.0),
+		//     e02  : E02(self.e02.0 * rhs.e0123.0) - E02(self.e13.0 * rhs.s.0),
+		//     e03  : E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0),
+		//     e12  : -E12(self.e03.0 * rhs.s.0) + E12(self.e12.0 * rhs.e0123.0),
+		//     e13  : E13(self.e02.0 * rhs.s.0) + E13(self.e13.0 * rhs.e0123.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) + E23(self.e23.0 * rhs.e0123.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: Default::default(),
+			e01: self.e01.anti_dot(rhs.e0123) + self.e23.anti_dot(rhs.s),
+			e02: self.e02.anti_dot(rhs.e0123) + self.e13.anti_dot(rhs.s),
+			e03: self.e03.anti_dot(rhs.e0123) + self.e12.anti_dot(rhs.s),
+			e12: self.e03.anti_dot(rhs.s) + self.e12.anti_dot(rhs.e0123),
+			e13: self.e02.anti_dot(rhs.s) + self.e13.anti_dot(rhs.e0123),
+			e23: self.e01.anti_dot(rhs.s) + self.e23.anti_dot(rhs.e0123),
+			e0123: self.e01.anti_dot(rhs.e01)
+				+ self.e02.anti_dot(rhs.e02)
+				+ self.e03.anti_dot(rhs.e03)
+				+ self.e12.anti_dot(rhs.e12)
+				+ self.e13.anti_dot(rhs.e13)
+				+ self.e23.anti_dot(rhs.e23),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Bivector OP Multivector:
+
+// Bivector.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Bivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e0   : -E0(self.e01.0 * rhs.e1.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e03.0 * rhs.e3.0) - E0(self.e12.0 * rhs.e012.0) - E0(self.e13.0 * rhs.e013.0) - E0(self.e23.0 * rhs.e023.0),
+		//     e1   : -E1(self.e01.0 * rhs.e0.0) - E1(self.e02.0 * rhs.e012.0) - E1(self.e03.0 * rhs.e013.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e13.0 * rhs.e3.0) - E1(self.e23.0 * rhs.e123.0),
+		//     e2   : E2(self.e01.0 * rhs.e012.0) - E2(self.e02.0 * rhs.e0.0) - E2(self.e03.0 * rhs.e023.0) + E2(self.e12.0 * rhs.e1.0) + E2(self.e13.0 * rhs.e123.0) - E2(self.e23.0 * rhs.e3.0),
+		//     e3   : E3(self.e01.0 * rhs.e013.0) + E3(self.e02.0 * rhs.e023.0) - E3(self.e03.0 * rhs.e0.0) - E3(self.e12.0 * rhs.e123.0) + E3(self.e13.0 * rhs.e1.0) + E3(self.e23.0 * rhs.e2.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) + E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0) - E01(self.e23.0 * rhs.e0123.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e02.0 * rhs.s.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) + E02(self.e13.0 * rhs.e0123.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e03.0 * rhs.s.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) - E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0),
+		//     e012 : E012(self.e01.0 * rhs.e2.0) - E012(self.e02.0 * rhs.e1.0) - E012(self.e03.0 * rhs.e123.0) + E012(self.e12.0 * rhs.e0.0) + E012(self.e13.0 * rhs.e023.0) - E012(self.e23.0 * rhs.e013.0),
+		//     e013 : E013(self.e01.0 * rhs.e3.0) + E013(self.e02.0 * rhs.e123.0) - E013(self.e03.0 * rhs.e1.0) - E013(self.e12.0 * rhs.e023.0) + E013(self.e13.0 * rhs.e0.0) + E013(self.e23.0 * rhs.e012.0),
+		//     e023 : -E023(self.e01.0 * rhs.e123.0) + E023(self.e02.0 * rhs.e3.0) - E023(self.e03.0 * rhs.e2.0) + E023(self.e12.0 * rhs.e013.0) - E023(self.e13.0 * rhs.e012.0) + E023(self.e23.0 * rhs.e0.0),
+		//     e123 : -E123(self.e01.0 * rhs.e023.0) + E123(self.e02.0 * rhs.e013.0) - E123(self.e03.0 * rhs.e012.0) + E123(self.e12.0 * rhs.e3.0) - E123(self.e13.0 * rhs.e2.0) + E123(self.e23.0 * rhs.e1.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0),
+		// }
+		Multivector {
+			s: self.e01.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e03)
+				+ self.e12.geometric(rhs.e12)
+				+ self.e13.geometric(rhs.e13)
+				+ self.e23.geometric(rhs.e23),
+			e0: self.e01.geometric(rhs.e1)
+				+ self.e02.geometric(rhs.e2)
+				+ self.e03.geometric(rhs.e3)
+				+ self.e12.geometric(rhs.e012)
+				+ self.e13.geometric(rhs.e013)
+				+ self.e23.geometric(rhs.e023),
+			e1: self.e01.geometric(rhs.e0)
+				+ self.e02.geometric(rhs.e012)
+				+ self.e03.geometric(rhs.e013)
+				+ self.e12.geometric(rhs.e2)
+				+ self.e13.geometric(rhs.e3)
+				+ self.e23.geometric(rhs.e123),
+			e2: self.e01.geometric(rhs.e012)
+				+ self.e02.geometric(rhs.e0)
+				+ self.e03.geometric(rhs.e023)
+				+ self.e12.geometric(rhs.e1)
+				+ self.e13.geometric(rhs.e123)
+				+ self.e23.geometric(rhs.e3),
+			e3: self.e01.geometric(rhs.e013)
+				+ self.e02.geometric(rhs.e023)
+				+ self.e03.geometric(rhs.e0)
+				+ self.e12.geometric(rhs.e123)
+				+ self.e13.geometric(rhs.e1)
+				+ self.e23.geometric(rhs.e2),
+			e01: self.e01.geometric(rhs.s)
+				+ self.e02.geometric(rhs.e12)
+				+ self.e03.geometric(rhs.e13)
+				+ self.e12.geometric(rhs.e02)
+				+ self.e13.geometric(rhs.e03)
+				+ self.e23.geometric(rhs.e0123),
+			e02: self.e01.geometric(rhs.e12)
+				+ self.e02.geometric(rhs.s)
+				+ self.e03.geometric(rhs.e23)
+				+ self.e12.geometric(rhs.e01)
+				+ self.e13.geometric(rhs.e0123)
+				+ self.e23.geometric(rhs.e03),
+			e03: self.e01.geometric(rhs.e13)
+				+ self.e02.geometric(rhs.e23)
+				+ self.e03.geometric(rhs.s)
+				+ self.e12.geometric(rhs.e0123)
+				+ self.e13.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e02),
+			e12: self.e01.geometric(rhs.e02)
+				+ self.e02.geometric(rhs.e01)
+				+ self.e03.geometric(rhs.e0123)
+				+ self.e12.geometric(rhs.s)
+				+ self.e13.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e13),
+			e13: self.e01.geometric(rhs.e03)
+				+ self.e02.geometric(rhs.e0123)
+				+ self.e03.geometric(rhs.e01)
+				+ self.e12.geometric(rhs.e23)
+				+ self.e13.geometric(rhs.s)
+				+ self.e23.geometric(rhs.e12),
+			e23: self.e01.geometric(rhs.e0123)
+				+ self.e02.geometric(rhs.e03)
+				+ self.e03.geometric(rhs.e02)
+				+ self.e12.geometric(rhs.e13)
+				+ self.e13.geometric(rhs.e12)
+				+ self.e23.geometric(rhs.s),
+			e012: self.e01.geometric(rhs.e2)
+				+ self.e02.geometric(rhs.e1)
+				+ self.e03.geometric(rhs.e123)
+				+ self.e12.geometric(rhs.e0)
+				+ self.e13.geometric(rhs.e023)
+				+ self.e23.geometric(rhs.e013),
+			e013: self.e01.geometric(rhs.e3)
+				+ self.e02.geometric(rhs.e123)
+				+ self.e03.geometric(rhs.e1)
+				+ self.e12.geometric(rhs.e023)
+				+ self.e13.geometric(rhs.e0)
+				+ self.e23.geometric(rhs.e012),
+			e023: self.e01.geometric(rhs.e123)
+				+ self.e02.geometric(rhs.e3)
+				+ self.e03.geometric(rhs.e2)
+				+ self.e12.geometric(rhs.e013)
+				+ self.e13.geometric(rhs.e012)
+				+ self.e23.geometric(rhs.e0),
+			e123: self.e01.geometric(rhs.e023)
+				+ self.e02.geometric(rhs.e013)
+				+ self.e03.geometric(rhs.e012)
+				+ self.e12.geometric(rhs.e3)
+				+ self.e13.geometric(rhs.e2)
+				+ self.e23.geometric(rhs.e1),
+			e0123: self.e01.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e12)
+				+ self.e12.geometric(rhs.e03)
+				+ self.e13.geometric(rhs.e02)
+				+ self.e23.geometric(rhs.e01),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Bivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Bivector.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Bivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e23.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0),
+		//     e0   : E0(self.e01.0 * rhs.e023.0) - E0(self.e02.0 * rhs.e013.0) + E0(self.e03.0 * rhs.e012.0) + E0(self.e12.0 * rhs.e3.0) - E0(self.e13.0 * rhs.e2.0) + E0(self.e23.0 * rhs.e1.0),
+		//     e1   : E1(self.e01.0 * rhs.e123.0) + E1(self.e02.0 * rhs.e3.0) - E1(self.e03.0 * rhs.e2.0) - E1(self.e12.0 * rhs.e013.0) + E1(self.e13.0 * rhs.e012.0) + E1(self.e23.0 * rhs.e0.0),
+		//     e2   : -E2(self.e01.0 * rhs.e3.0) + E2(self.e02.0 * rhs.e123.0) + E2(self.e03.0 * rhs.e1.0) - E2(self.e12.0 * rhs.e023.0) - E2(self.e13.0 * rhs.e0.0) + E2(self.e23.0 * rhs.e012.0),
+		//     e3   : E3(self.e01.0 * rhs.e2.0) - E3(self.e02.0 * rhs.e1.0) + E3(self.e03.0 * rhs.e123.0) + E3(self.e12.0 * rhs.e0.0) - E3(self.e13.0 * rhs.e023.0) + E3(self.e23.0 * rhs.e013.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e02.0 * rhs.e03.0) - E01(self.e03.0 * rhs.e02.0) - E01(self.e12.0 * rhs.e13.0) + E01(self.e13.0 * rhs.e12.0) + E01(self.e23.0 * rhs.s.0),
+		//     e02  : -E02(self.e01.0 * rhs.e03.0) + E02(self.e02.0 * rhs.e0123.0) + E02(self.e03.0 * rhs.e01.0) - E02(self.e12.0 * rhs.e23.0) - E02(self.e13.0 * rhs.s.0) + E02(self.e23.0 * rhs.e12.0),
+		//     e03  : E03(self.e01.0 * rhs.e02.0) - E03(self.e02.0 * rhs.e01.0) + E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0) - E03(self.e13.0 * rhs.e23.0) + E03(self.e23.0 * rhs.e13.0),
+		//     e12  : -E12(self.e01.0 * rhs.e13.0) - E12(self.e02.0 * rhs.e23.0) - E12(self.e03.0 * rhs.s.0) + E12(self.e12.0 * rhs.e0123.0) + E12(self.e13.0 * rhs.e01.0) + E12(self.e23.0 * rhs.e02.0),
+		//     e13  : E13(self.e01.0 * rhs.e12.0) + E13(self.e02.0 * rhs.s.0) - E13(self.e03.0 * rhs.e23.0) - E13(self.e12.0 * rhs.e01.0) + E13(self.e13.0 * rhs.e0123.0) + E13(self.e23.0 * rhs.e03.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) + E23(self.e02.0 * rhs.e12.0) + E23(self.e03.0 * rhs.e13.0) - E23(self.e12.0 * rhs.e02.0) - E23(self.e13.0 * rhs.e03.0) + E23(self.e23.0 * rhs.e0123.0),
+		//     e012 : -E012(self.e01.0 * rhs.e013.0) - E012(self.e02.0 * rhs.e023.0) - E012(self.e03.0 * rhs.e0.0) + E012(self.e12.0 * rhs.e123.0) + E012(self.e13.0 * rhs.e1.0) + E012(self.e23.0 * rhs.e2.0),
+		//     e013 : E013(self.e01.0 * rhs.e012.0) + E013(self.e02.0 * rhs.e0.0) - E013(self.e03.0 * rhs.e023.0) - E013(self.e12.0 * rhs.e1.0) + E013(self.e13.0 * rhs.e123.0) + E013(self.e23.0 * rhs.e3.0),
+		//     e023 : -E023(self.e01.0 * rhs.e0.0) + E023(self.e02.0 * rhs.e012.0) + E023(self.e03.0 * rhs.e013.0) - E023(self.e12.0 * rhs.e2.0) - E023(self.e13.0 * rhs.e3.0) + E023(self.e23.0 * rhs.e123.0),
+		//     e123 : -E123(self.e01.0 * rhs.e1.0) - E123(self.e02.0 * rhs.e2.0) - E123(self.e03.0 * rhs.e3.0) + E123(self.e12.0 * rhs.e012.0) + E123(self.e13.0 * rhs.e013.0) + E123(self.e23.0 * rhs.e023.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0),
+		// }
+		Multivector {
+			s: self.e01.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e12)
+				+ self.e12.anti_geometric(rhs.e03)
+				+ self.e13.anti_geometric(rhs.e02)
+				+ self.e23.anti_geometric(rhs.e01),
+			e0: self.e01.anti_geometric(rhs.e023)
+				+ self.e02.anti_geometric(rhs.e013)
+				+ self.e03.anti_geometric(rhs.e012)
+				+ self.e12.anti_geometric(rhs.e3)
+				+ self.e13.anti_geometric(rhs.e2)
+				+ self.e23.anti_geometric(rhs.e1),
+			e1: self.e01.anti_geometric(rhs.e123)
+				+ self.e02.anti_geometric(rhs.e3)
+				+ self.e03.anti_geometric(rhs.e2)
+				+ self.e12.anti_geometric(rhs.e013)
+				+ self.e13.anti_geometric(rhs.e012)
+				+ self.e23.anti_geometric(rhs.e0),
+			e2: self.e01.anti_geometric(rhs.e3)
+				+ self.e02.anti_geometric(rhs.e123)
+				+ self.e03.anti_geometric(rhs.e1)
+				+ self.e12.anti_geometric(rhs.e023)
+				+ self.e13.anti_geometric(rhs.e0)
+				+ self.e23.anti_geometric(rhs.e012),
+			e3: self.e01.anti_geometric(rhs.e2)
+				+ self.e02.anti_geometric(rhs.e1)
+				+ self.e03.anti_geometric(rhs.e123)
+				+ self.e12.anti_geometric(rhs.e0)
+				+ self.e13.anti_geometric(rhs.e023)
+				+ self.e23.anti_geometric(rhs.e013),
+			e01: self.e01.anti_geometric(rhs.e0123)
+				+ self.e02.anti_geometric(rhs.e03)
+				+ self.e03.anti_geometric(rhs.e02)
+				+ self.e12.anti_geometric(rhs.e13)
+				+ self.e13.anti_geometric(rhs.e12)
+				+ self.e23.anti_geometric(rhs.s),
+			e02: self.e01.anti_geometric(rhs.e03)
+				+ self.e02.anti_geometric(rhs.e0123)
+				+ self.e03.anti_geometric(rhs.e01)
+				+ self.e12.anti_geometric(rhs.e23)
+				+ self.e13.anti_geometric(rhs.s)
+				+ self.e23.anti_geometric(rhs.e12),
+			e03: self.e01.anti_geometric(rhs.e02)
+				+ self.e02.anti_geometric(rhs.e01)
+				+ self.e03.anti_geometric(rhs.e0123)
+				+ self.e12.anti_geometric(rhs.s)
+				+ self.e13.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e13),
+			e12: self.e01.anti_geometric(rhs.e13)
+				+ self.e02.anti_geometric(rhs.e23)
+				+ self.e03.anti_geometric(rhs.s)
+				+ self.e12.anti_geometric(rhs.e0123)
+				+ self.e13.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e02),
+			e13: self.e01.anti_geometric(rhs.e12)
+				+ self.e02.anti_geometric(rhs.s)
+				+ self.e03.anti_geometric(rhs.e23)
+				+ self.e12.anti_geometric(rhs.e01)
+				+ self.e13.anti_geometric(rhs.e0123)
+				+ self.e23.anti_geometric(rhs.e03),
+			e23: self.e01.anti_geometric(rhs.s)
+				+ self.e02.anti_geometric(rhs.e12)
+				+ self.e03.anti_geometric(rhs.e13)
+				+ self.e12.anti_geometric(rhs.e02)
+				+ self.e13.anti_geometric(rhs.e03)
+				+ self.e23.anti_geometric(rhs.e0123),
+			e012: self.e01.anti_geometric(rhs.e013)
+				+ self.e02.anti_geometric(rhs.e023)
+				+ self.e03.anti_geometric(rhs.e0)
+				+ self.e12.anti_geometric(rhs.e123)
+				+ self.e13.anti_geometric(rhs.e1)
+				+ self.e23.anti_geometric(rhs.e2),
+			e013: self.e01.anti_geometric(rhs.e012)
+				+ self.e02.anti_geometric(rhs.e0)
+				+ self.e03.anti_geometric(rhs.e023)
+				+ self.e12.anti_geometric(rhs.e1)
+				+ self.e13.anti_geometric(rhs.e123)
+				+ self.e23.anti_geometric(rhs.e3),
+			e023: self.e01.anti_geometric(rhs.e0)
+				+ self.e02.anti_geometric(rhs.e012)
+				+ self.e03.anti_geometric(rhs.e013)
+				+ self.e12.anti_geometric(rhs.e2)
+				+ self.e13.anti_geometric(rhs.e3)
+				+ self.e23.anti_geometric(rhs.e123),
+			e123: self.e01.anti_geometric(rhs.e1)
+				+ self.e02.anti_geometric(rhs.e2)
+				+ self.e03.anti_geometric(rhs.e3)
+				+ self.e12.anti_geometric(rhs.e012)
+				+ self.e13.anti_geometric(rhs.e013)
+				+ self.e23.anti_geometric(rhs.e023),
+			e0123: self.e01.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e03)
+				+ self.e12.anti_geometric(rhs.e12)
+				+ self.e13.anti_geometric(rhs.e13)
+				+ self.e23.anti_geometric(rhs.e23),
+		}
+	}
+}
+
+// Bivector.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Bivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e0   : -E0(self.e01.0 * rhs.e1.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e03.0 * rhs.e3.0) - E0(self.e12.0 * rhs.e012.0) - E0(self.e13.0 * rhs.e013.0) - E0(self.e23.0 * rhs.e023.0),
+		//     e1   : -E1(self.e01.0 * rhs.e0.0) - E1(self.e02.0 * rhs.e012.0) - E1(self.e03.0 * rhs.e013.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e13.0 * rhs.e3.0) - E1(self.e23.0 * rhs.e123.0),
+		//     e2   : E2(self.e01.0 * rhs.e012.0) - E2(self.e02.0 * rhs.e0.0) - E2(self.e03.0 * rhs.e023.0) + E2(self.e12.0 * rhs.e1.0) + E2(self.e13.0 * rhs.e123.0) - E2(self.e23.0 * rhs.e3.0),
+		//     e3   : E3(self.e01.0 * rhs.e013.0) + E3(self.e02.0 * rhs.e023.0) - E3(self.e03.0 * rhs.e0.0) - E3(self.e12.0 * rhs.e123.0) + E3(self.e13.0 * rhs.e1.0) + E3(self.e23.0 * rhs.e2.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e23.0 * rhs.e0123.0),
+		//     e02  : E02(self.e02.0 * rhs.s.0) + E02(self.e13.0 * rhs.e0123.0),
+		//     e03  : E03(self.e03.0 * rhs.s.0) - E03(self.e12.0 * rhs.e0123.0),
+		//     e12  : E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0),
+		//     e13  : -E13(self.e02.0 * rhs.e0123.0) + E13(self.e13.0 * rhs.s.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.e23.0 * rhs.s.0),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e01.dot(rhs.e01)
+				+ self.e02.dot(rhs.e02)
+				+ self.e03.dot(rhs.e03)
+				+ self.e12.dot(rhs.e12)
+				+ self.e13.dot(rhs.e13)
+				+ self.e23.dot(rhs.e23),
+			e0: self.e01.dot(rhs.e1)
+				+ self.e02.dot(rhs.e2)
+				+ self.e03.dot(rhs.e3)
+				+ self.e12.dot(rhs.e012)
+				+ self.e13.dot(rhs.e013)
+				+ self.e23.dot(rhs.e023),
+			e1: self.e01.dot(rhs.e0)
+				+ self.e02.dot(rhs.e012)
+				+ self.e03.dot(rhs.e013)
+				+ self.e12.dot(rhs.e2)
+				+ self.e13.dot(rhs.e3)
+				+ self.e23.dot(rhs.e123),
+			e2: self.e01.dot(rhs.e012)
+				+ self.e02.dot(rhs.e0)
+				+ self.e03.dot(rhs.e023)
+				+ self.e12.dot(rhs.e1)
+				+ self.e13.dot(rhs.e123)
+				+ self.e23.dot(rhs.e3),
+			e3: self.e01.dot(rhs.e013)
+				+ self.e02.dot(rhs.e023)
+				+ self.e03.dot(rhs.e0)
+				+ self.e12.dot(rhs.e123)
+				+ self.e13.dot(rhs.e1)
+				+ self.e23.dot(rhs.e2),
+			e01: self.e01.dot(rhs.s) + self.e23.dot(rhs.e0123),
+			e02: self.e02.dot(rhs.s) + self.e13.dot(rhs.e0123),
+			e03: self.e03.dot(rhs.s) + self.e12.dot(rhs.e0123),
+			e12: self.e03.dot(rhs.e0123) + self.e12.dot(rhs.s),
+			e13: self.e02.dot(rhs.e0123) + self.e13.dot(rhs.s),
+			e23: self.e01.dot(rhs.e0123) + self.e23.dot(rhs.s),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Bivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Bivector.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Bivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : Default::default(),
+		//     e1   : Default::default(),
+		//     e2   : Default::default(),
+		//     e3   : Default::default(),
+		//     e01  : E01(self.e01.0 * rhs.s.0),
+		//     e02  : E02(self.e02.0 * rhs.s.0),
+		//     e03  : E03(self.e03.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e012 : E012(self.e01.0 * rhs.e2.0) - E012(self.e02.0 * rhs.e1.0) + E012(self.e12.0 * rhs.e0.0),
+		//     e013 : E013(self.e01.0 * rhs.e3.0) - E013(self.e03.0 * rhs.e1.0) + E013(self.e13.0 * rhs.e0.0),
+		//     e023 : E023(self.e02.0 * rhs.e3.0) - E023(self.e03.0 * rhs.e2.0) + E023(self.e23.0 * rhs.e0.0),
+		//     e123 : E123(self.e12.0 * rhs.e3.0) - E123(self.e13.0 * rhs.e2.0) + E123(self.e23.0 * rhs.e1.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: self.e01.wedge(rhs.s),
+			e02: self.e02.wedge(rhs.s),
+			e03: self.e03.wedge(rhs.s),
+			e12: self.e12.wedge(rhs.s),
+			e13: self.e13.wedge(rhs.s),
+			e23: self.e23.wedge(rhs.s),
+			e012: self.e01.wedge(rhs.e2) + self.e02.wedge(rhs.e1) + self.e12.wedge(rhs.e0),
+			e013: self.e01.wedge(rhs.e3) + self.e03.wedge(rhs.e1) + self.e13.wedge(rhs.e0),
+			e023: self.e02.wedge(rhs.e3) + self.e03.wedge(rhs.e2) + self.e23.wedge(rhs.e0),
+			e123: self.e12.wedge(rhs.e3) + self.e13.wedge(rhs.e2) + self.e23.wedge(rhs.e1),
+			e0123: self.e01.wedge(rhs.e23)
+				+ self.e02.wedge(rhs.e13)
+				+ self.e03.wedge(rhs.e12)
+				+ self.e12.wedge(rhs.e03)
+				+ self.e13.wedge(rhs.e02)
+				+ self.e23.wedge(rhs.e01),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Bivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Bivector.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Bivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e23.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0),
+		//     e0   : E0(self.e01.0 * rhs.e023.0) - E0(self.e02.0 * rhs.e013.0) + E0(self.e03.0 * rhs.e012.0),
+		//     e1   : E1(self.e01.0 * rhs.e123.0) - E1(self.e12.0 * rhs.e013.0) + E1(self.e13.0 * rhs.e012.0),
+		//     e2   : E2(self.e02.0 * rhs.e123.0) - E2(self.e12.0 * rhs.e023.0) + E2(self.e23.0 * rhs.e012.0),
+		//     e3   : E3(self.e03.0 * rhs.e123.0) - E3(self.e13.0 * rhs.e023.0) + E3(self.e23.0 * rhs.e013.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0),
+		//     e02  : E02(self.e02.0 * rhs.e0123.0),
+		//     e03  : E03(self.e03.0 * rhs.e0123.0),
+		//     e12  : E12(self.e12.0 * rhs.e0123.0),
+		//     e13  : E13(self.e13.0 * rhs.e0123.0),
+		//     e23  : E23(self.e23.0 * rhs.e0123.0),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e01.anti_wedge(rhs.e23)
+				+ self.e02.anti_wedge(rhs.e13)
+				+ self.e03.anti_wedge(rhs.e12)
+				+ self.e12.anti_wedge(rhs.e03)
+				+ self.e13.anti_wedge(rhs.e02)
+				+ self.e23.anti_wedge(rhs.e01),
+			e0: self.e01.anti_wedge(rhs.e023) + self.e02.anti_wedge(rhs.e013) + self.e03.anti_wedge(rhs.e012),
+			e1: self.e01.anti_wedge(rhs.e123) + self.e12.anti_wedge(rhs.e013) + self.e13.anti_wedge(rhs.e012),
+			e2: self.e02.anti_wedge(rhs.e123) + self.e12.anti_wedge(rhs.e023) + self.e23.anti_wedge(rhs.e012),
+			e3: self.e03.anti_wedge(rhs.e123) + self.e13.anti_wedge(rhs.e023) + self.e23.anti_wedge(rhs.e013),
+			e01: self.e01.anti_wedge(rhs.e0123),
+			e02: self.e02.anti_wedge(rhs.e0123),
+			e03: self.e03.anti_wedge(rhs.e0123),
+			e12: self.e12.anti_wedge(rhs.e0123),
+			e13: self.e13.anti_wedge(rhs.e0123),
+			e23: self.e23.anti_wedge(rhs.e0123),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Bivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Bivector.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Bivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e0   : -E0(self.e12.0 * rhs.e012.0) - E0(self.e13.0 * rhs.e013.0) - E0(self.e23.0 * rhs.e023.0),
+		//     e1   : -E1(self.e02.0 * rhs.e012.0) - E1(self.e03.0 * rhs.e013.0) - E1(self.e23.0 * rhs.e123.0),
+		//     e2   : E2(self.e01.0 * rhs.e012.0) - E2(self.e03.0 * rhs.e023.0) + E2(self.e13.0 * rhs.e123.0),
+		//     e3   : E3(self.e01.0 * rhs.e013.0) + E3(self.e02.0 * rhs.e023.0) - E3(self.e12.0 * rhs.e123.0),
+		//     e01  : -E01(self.e23.0 * rhs.e0123.0),
+		//     e02  : E02(self.e13.0 * rhs.e0123.0),
+		//     e03  : -E03(self.e12.0 * rhs.e0123.0),
+		//     e12  : E12(self.e03.0 * rhs.e0123.0),
+		//     e13  : -E13(self.e02.0 * rhs.e0123.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e01.left_contraction(rhs.e01)
+				+ self.e02.left_contraction(rhs.e02)
+				+ self.e03.left_contraction(rhs.e03)
+				+ self.e12.left_contraction(rhs.e12)
+				+ self.e13.left_contraction(rhs.e13)
+				+ self.e23.left_contraction(rhs.e23),
+			e0: self.e12.left_contraction(rhs.e012)
+				+ self.e13.left_contraction(rhs.e013)
+				+ self.e23.left_contraction(rhs.e023),
+			e1: self.e02.left_contraction(rhs.e012)
+				+ self.e03.left_contraction(rhs.e013)
+				+ self.e23.left_contraction(rhs.e123),
+			e2: self.e01.left_contraction(rhs.e012)
+				+ self.e03.left_contraction(rhs.e023)
+				+ self.e13.left_contraction(rhs.e123),
+			e3: self.e01.left_contraction(rhs.e013)
+				+ self.e02.left_contraction(rhs.e023)
+				+ self.e12.left_contraction(rhs.e123),
+			e01: self.e23.left_contraction(rhs.e0123),
+			e02: self.e13.left_contraction(rhs.e0123),
+			e03: self.e12.left_contraction(rhs.e0123),
+			e12: self.e03.left_contraction(rhs.e0123),
+			e13: self.e02.left_contraction(rhs.e0123),
+			e23: self.e01.left_contraction(rhs.e0123),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Bivector.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Bivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e0   : -E0(self.e01.0 * rhs.e1.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e03.0 * rhs.e3.0),
+		//     e1   : -E1(self.e01.0 * rhs.e0.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e13.0 * rhs.e3.0),
+		//     e2   : -E2(self.e02.0 * rhs.e0.0) + E2(self.e12.0 * rhs.e1.0) - E2(self.e23.0 * rhs.e3.0),
+		//     e3   : -E3(self.e03.0 * rhs.e0.0) + E3(self.e13.0 * rhs.e1.0) + E3(self.e23.0 * rhs.e2.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0),
+		//     e02  : E02(self.e02.0 * rhs.s.0),
+		//     e03  : E03(self.e03.0 * rhs.s.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e01.right_contraction(rhs.e01)
+				+ self.e02.right_contraction(rhs.e02)
+				+ self.e03.right_contraction(rhs.e03)
+				+ self.e12.right_contraction(rhs.e12)
+				+ self.e13.right_contraction(rhs.e13)
+				+ self.e23.right_contraction(rhs.e23),
+			e0: self.e01.right_contraction(rhs.e1)
+				+ self.e02.right_contraction(rhs.e2)
+				+ self.e03.right_contraction(rhs.e3),
+			e1: self.e01.right_contraction(rhs.e0)
+				+ self.e12.right_contraction(rhs.e2)
+				+ self.e13.right_contraction(rhs.e3),
+			e2: self.e02.right_contraction(rhs.e0)
+				+ self.e12.right_contraction(rhs.e1)
+				+ self.e23.right_contraction(rhs.e3),
+			e3: self.e03.right_contraction(rhs.e0)
+				+ self.e13.right_contraction(rhs.e1)
+				+ self.e23.right_contraction(rhs.e2),
+			e01: self.e01.right_contraction(rhs.s),
+			e02: self.e02.right_contraction(rhs.s),
+			e03: self.e03.right_contraction(rhs.s),
+			e12: self.e12.right_contraction(rhs.s),
+			e13: self.e13.right_contraction(rhs.s),
+			e23: self.e23.right_contraction(rhs.s),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Bivector.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Bivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		self.e01.scalar_product(rhs.e01)
+			+ self.e02.scalar_product(rhs.e02)
+			+ self.e03.scalar_product(rhs.e03)
+			+ self.e12.scalar_product(rhs.e12)
+			+ self.e13.scalar_product(rhs.e13)
+			+ self.e23.scalar_product(rhs.e23)
+	}
+}
+
+// Bivector.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Bivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : -E0(self.e01.0 * rhs.e1.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e03.0 * rhs.e3.0),
+		//     e1   : -E1(self.e01.0 * rhs.e0.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e13.0 * rhs.e3.0),
+		//     e2   : -E2(self.e02.0 * rhs.e0.0) + E2(self.e12.0 * rhs.e1.0) - E2(self.e23.0 * rhs.e3.0),
+		//     e3   : -E3(self.e03.0 * rhs.e0.0) + E3(self.e13.0 * rhs.e1.0) + E3(self.e23.0 * rhs.e2.0),
+		//     e01  : E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23  : -E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0),
+		//     e012 : -E012(self.e03.0 * rhs.e123.0) + E012(self.e13.0 * rhs.e023.0) - E012(self.e23.0 * rhs.e013.0),
+		//     e013 : E013(self.e02.0 * rhs.e123.0) - E013(self.e12.0 * rhs.e023.0) + E013(self.e23.0 * rhs.e012.0),
+		//     e023 : -E023(self.e01.0 * rhs.e123.0) + E023(self.e12.0 * rhs.e013.0) - E023(self.e13.0 * rhs.e012.0),
+		//     e123 : -E123(self.e01.0 * rhs.e023.0) + E123(self.e02.0 * rhs.e013.0) - E123(self.e03.0 * rhs.e012.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e01.commutator(rhs.e1) + self.e02.commutator(rhs.e2) + self.e03.commutator(rhs.e3),
+			e1: self.e01.commutator(rhs.e0) + self.e12.commutator(rhs.e2) + self.e13.commutator(rhs.e3),
+			e2: self.e02.commutator(rhs.e0) + self.e12.commutator(rhs.e1) + self.e23.commutator(rhs.e3),
+			e3: self.e03.commutator(rhs.e0) + self.e13.commutator(rhs.e1) + self.e23.commutator(rhs.e2),
+			e01: self.e02.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e13)
+				+ self.e12.commutator(rhs.e02)
+				+ self.e13.commutator(rhs.e03),
+			e02: self.e01.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e23)
+				+ self.e12.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e03),
+			e03: self.e01.commutator(rhs.e13)
+				+ self.e02.commutator(rhs.e23)
+				+ self.e13.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e02),
+			e12: self.e01.commutator(rhs.e02)
+				+ self.e02.commutator(rhs.e01)
+				+ self.e13.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e13),
+			e13: self.e01.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e01)
+				+ self.e12.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e12),
+			e23: self.e02.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e02)
+				+ self.e12.commutator(rhs.e13)
+				+ self.e13.commutator(rhs.e12),
+			e012: self.e03.commutator(rhs.e123) + self.e13.commutator(rhs.e023) + self.e23.commutator(rhs.e013),
+			e013: self.e02.commutator(rhs.e123) + self.e12.commutator(rhs.e023) + self.e23.commutator(rhs.e012),
+			e023: self.e01.commutator(rhs.e123) + self.e12.commutator(rhs.e013) + self.e13.commutator(rhs.e012),
+			e123: self.e01.commutator(rhs.e023) + self.e02.commutator(rhs.e013) + self.e03.commutator(rhs.e012),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Bivector.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Bivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : Default::default(),
+		//     e1   : Default::default(),
+		//     e2   : Default::default(),
+		//     e3   : Default::default(),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e23.0 * rhs.s.0),
+		//     e02  : E02(self.e02.0 * rhs.e0123.0) - E02(self.e13.0 * rhs.s.0),
+		//     e03  : E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0),
+		//     e12  : -E12(self.e03.0 * rhs.s.0) + E12(self.e12.0 * rhs.e0123.0),
+		//     e13  : E13(self.e02.0 * rhs.s.0) + E13(self.e13.0 * rhs.e0123.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) + E23(self.e23.0 * rhs.e0123.0),
+		//     e012 : -E012(self.e01.0 * rhs.e013.0) - E012(self.e02.0 * rhs.e023.0) - E012(self.e03.0 * rhs.e0.0) + E012(self.e12.0 * rhs.e123.0) + E012(self.e13.0 * rhs.e1.0) + E012(self.e23.0 * rhs.e2.0),
+		//     e013 : E013(self.e01.0 * rhs.e012.0) + E013(self.e02.0 * rhs.e0.0) - E013(self.e03.0 * rhs.e023.0) - E013(self.e12.0 * rhs.e1.0) + E013(self.e13.0 * rhs.e123.0) + E013(self.e23.0 * rhs.e3.0),
+		//     e023 : -E023(self.e01.0 * rhs.e0.0) + E023(self.e02.0 * rhs.e012.0) + E023(self.e03.0 * rhs.e013.0) - E023(self.e12.0 * rhs.e2.0) - E023(self.e13.0 * rhs.e3.0) + E023(self.e23.0 * rhs.e123.0),
+		//     e123 : -E123(self.e01.0 * rhs.e1.0) - E123(self.e02.0 * rhs.e2.0) - E123(self.e03.0 * rhs.e3.0) + E123(self.e12.0 * rhs.e012.0) + E123(self.e13.0 * rhs.e013.0) + E123(self.e23.0 * rhs.e023.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: self.e01.anti_dot(rhs.e0123) + self.e23.anti_dot(rhs.s),
+			e02: self.e02.anti_dot(rhs.e0123) + self.e13.anti_dot(rhs.s),
+			e03: self.e03.anti_dot(rhs.e0123) + self.e12.anti_dot(rhs.s),
+			e12: self.e03.anti_dot(rhs.s) + self.e12.anti_dot(rhs.e0123),
+			e13: self.e02.anti_dot(rhs.s) + self.e13.anti_dot(rhs.e0123),
+			e23: self.e01.anti_dot(rhs.s) + self.e23.anti_dot(rhs.e0123),
+			e012: self.e01.anti_dot(rhs.e013)
+				+ self.e02.anti_dot(rhs.e023)
+				+ self.e03.anti_dot(rhs.e0)
+				+ self.e12.anti_dot(rhs.e123)
+				+ self.e13.anti_dot(rhs.e1)
+				+ self.e23.anti_dot(rhs.e2),
+			e013: self.e01.anti_dot(rhs.e012)
+				+ self.e02.anti_dot(rhs.e0)
+				+ self.e03.anti_dot(rhs.e023)
+				+ self.e12.anti_dot(rhs.e1)
+				+ self.e13.anti_dot(rhs.e123)
+				+ self.e23.anti_dot(rhs.e3),
+			e023: self.e01.anti_dot(rhs.e0)
+				+ self.e02.anti_dot(rhs.e012)
+				+ self.e03.anti_dot(rhs.e013)
+				+ self.e12.anti_dot(rhs.e2)
+				+ self.e13.anti_dot(rhs.e3)
+				+ self.e23.anti_dot(rhs.e123),
+			e123: self.e01.anti_dot(rhs.e1)
+				+ self.e02.anti_dot(rhs.e2)
+				+ self.e03.anti_dot(rhs.e3)
+				+ self.e12.anti_dot(rhs.e012)
+				+ self.e13.anti_dot(rhs.e013)
+				+ self.e23.anti_dot(rhs.e023),
+			e0123: self.e01.anti_dot(rhs.e01)
+				+ self.e02.anti_dot(rhs.e02)
+				+ self.e03.anti_dot(rhs.e03)
+				+ self.e12.anti_dot(rhs.e12)
+				+ self.e13.anti_dot(rhs.e13)
+				+ self.e23.anti_dot(rhs.e23),
+		}
+	}
+}
@@ -0,0 +1,27507 @@
+//! # Blade types
+//! The blades that make up this geometric algebra.
+//!
+//! ## Unary operations
+//! | Op \ Blade       | 1      | E0    | E1    | E2    | E3    | E01  | E02  | E03  | E12  | E13  | E23  | E012  | E013  | E023  | E123  | E0123  |
+//! | ---------------- | ------ | ----- | ----- | ----- | ----- | ---- | ---- | ---- | ---- | ---- | ---- | ----- | ----- | ----- | ----- | ------ |
+//! | Right complement | E0123  | E123  | -E023 | E013  | -E012 | E23  | -E13 | E12  | E03  | -E02 | E01  | E3    | -E2   | E1    | -E0   | 1      |
+//! | Left complement  | E0123  | -E123 | E023  | -E013 | E012  | E23  | -E13 | E12  | E03  | -E02 | E01  | -E3   | E2    | -E1   | E0    | 1      |
+//! | Reverse          | 1      | E0    | E1    | E2    | E3    | -E01 | -E02 | -E03 | -E12 | -E13 | -E23 | -E012 | -E013 | -E023 | -E123 | E0123  |
+//! | Anti-reverse     | 1      | -E0   | -E1   | -E2   | -E3   | -E01 | -E02 | -E03 | -E12 | -E13 | -E23 | E012  | E013  | E023  | E123  | E0123  |
+//! | Involute         | 1      | -E0   | -E1   | -E2   | -E3   | E01  | E02  | E03  | E12  | E13  | E23  | -E012 | -E013 | -E023 | -E123 | E0123  |
+//! | Conjugate        | 1      | -E0   | -E1   | -E2   | -E3   | -E01 | -E02 | -E03 | -E12 | -E13 | -E23 | E012  | E013  | E023  | E123  | E0123  |
+//! | Dual             | E0123  | E123  | -E023 | E013  | -E012 | E23  | -E13 | E12  | E03  | -E02 | E01  | -E3   | E2    | -E1   | E0    | 1      |
+//! | Bulk             | 1      | E0    | -E1   | -E2   | -E3   | -E01 | -E02 | -E03 | E12  | E13  | E23  | E012  | E013  | E023  | -E123 | -E0123 |
+//! | Weight           | -1     | -E0   | E1    | E2    | E3    | E01  | E02  | E03  | -E12 | -E13 | -E23 | -E012 | -E013 | -E023 | E123  | E0123  |
+//! | Bulk dual        | E0123  | E123  | E023  | -E013 | E012  | -E23 | E13  | -E12 | E03  | -E02 | E01  | E3    | -E2   | E1    | E0    | -1     |
+//! | Weight dual      | -E0123 | -E123 | -E023 | E013  | -E012 | E23  | -E13 | E12  | -E03 | E02  | -E01 | -E3   | E2    | -E1   | -E0   | 1      |
+//!
+//!
+//! ## Multiplication tables
+//! ### Geometric multiplication table
+//!
+//! |       | 1     | E0     | E1    | E2     | E3    | E01   | E02    | E03   | E12   | E13    | E23   | E012   | E013  | E023   | E123  | E0123 |
+//! | ----- | ----- | ------ | ----- | ------ | ----- | ----- | ------ | ----- | ----- | ------ | ----- | ------ | ----- | ------ | ----- | ----- |
+//! | 1     | 1     | E0     | E1    | E2     | E3    | E01   | E02    | E03   | E12   | E13    | E23   | E012   | E013  | E023   | E123  | E0123 |
+//! | E0    | E0    | 1      | E01   | E02    | E03   | E1    | E2     | E3    | E012  | E013   | E023  | E12    | E13   | E23    | E0123 | E123  |
+//! | E1    | E1    | -E01   | -1    | E12    | E13   | E0    | -E012  | -E013 | -E2   | -E3    | E123  | E02    | E03   | -E0123 | -E23  | E023  |
+//! | E2    | E2    | -E02   | -E12  | -1     | E23   | E012  | E0     | -E023 | E1    | -E123  | -E3   | -E01   | E0123 | E03    | E13   | -E013 |
+//! | E3    | E3    | -E03   | -E13  | -E23   | -1    | E013  | E023   | E0    | E123  | E1     | E2    | -E0123 | -E01  | -E02   | -E12  | E012  |
+//! | E01   | E01   | -E1    | -E0   | E012   | E013  | 1     | -E12   | -E13  | -E02  | -E03   | E0123 | E2     | E3    | -E123  | -E023 | E23   |
+//! | E02   | E02   | -E2    | -E012 | -E0    | E023  | E12   | 1      | -E23  | E01   | -E0123 | -E03  | -E1    | E123  | E3     | E013  | -E13  |
+//! | E03   | E03   | -E3    | -E013 | -E023  | -E0   | E13   | E23    | 1     | E0123 | E01    | E02   | -E123  | -E1   | -E2    | -E012 | E12   |
+//! | E12   | E12   | E012   | E2    | -E1    | E123  | E02   | -E01   | E0123 | -1    | E23    | -E13  | -E0    | E023  | -E013  | -E3   | -E03  |
+//! | E13   | E13   | E013   | E3    | -E123  | -E1   | E03   | -E0123 | -E01  | -E23  | -1     | E12   | -E023  | -E0   | E012   | E2    | E02   |
+//! | E23   | E23   | E023   | E123  | E3     | -E2   | E0123 | E03    | -E02  | E13   | -E12   | -1    | E013   | -E012 | -E0    | -E1   | -E01  |
+//! | E012  | E012  | E12    | E02   | -E01   | E0123 | E2    | -E1    | E123  | -E0   | E023   | -E013 | -1     | E23   | -E13   | -E03  | -E3   |
+//! | E013  | E013  | E13    | E03   | -E0123 | -E01  | E3    | -E123  | -E1   | -E023 | -E0    | E012  | -E23   | -1    | E12    | E02   | E2    |
+//! | E023  | E023  | E23    | E0123 | E03    | -E02  | E123  | E3     | -E2   | E013  | -E012  | -E0   | E13    | -E12  | -1     | -E01  | -E1   |
+//! | E123  | E123  | -E0123 | -E23  | E13    | -E12  | E023  | -E013  | E012  | -E3   | E2     | -E1   | E03    | -E02  | E01    | 1     | -E0   |
+//! | E0123 | E0123 | -E123  | -E023 | E013   | -E012 | E23   | -E13   | E12   | -E03  | E02    | -E01  | E3     | -E2   | E1     | E0    | -1    |
+//!
+//!
+//! ### AntiGeometric multiplication table
+//!
+//! |       | 1      | E0    | E1     | E2     | E3     | E01    | E02    | E03    | E12   | E13   | E23   | E012   | E013   | E023   | E123  | E0123 |
+//! | ----- | ------ | ----- | ------ | ------ | ------ | ------ | ------ | ------ | ----- | ----- | ----- | ------ | ------ | ------ | ----- | ----- |
+//! | 1     | -E0123 | -E123 | -E023  | E013   | -E012  | -E23   | E13    | -E12   | E03   | -E02  | E01   | E3     | -E2    | E1     | E0    | 1     |
+//! | E0    | E123   | E0123 | -E23   | E13    | -E12   | -E023  | E013   | -E012  | -E3   | E2    | -E1   | -E03   | E02    | -E01   | 1     | E0    |
+//! | E1    | E023   | E23   | -E0123 | E03    | -E02   | -E123  | E3     | -E2    | -E013 | E012  | -E0   | -E13   | E12    | -1     | E01   | E1    |
+//! | E2    | -E013  | -E13  | -E03   | -E0123 | E01    | -E3    | -E123  | E1     | -E023 | E0    | E012  | -E23   | 1      | E12    | E02   | E2    |
+//! | E3    | E012   | E12   | E02    | -E01   | -E0123 | E2     | -E1    | -E123  | -E0   | -E023 | E013  | -1     | -E23   | E13    | E03   | E3    |
+//! | E01   | -E23   | -E023 | -E123  | E3     | -E2    | -E0123 | E03    | -E02   | E13   | -E12  | 1     | E013   | -E012  | E0     | E1    | E01   |
+//! | E02   | E13    | E013  | -E3    | -E123  | E1     | -E03   | -E0123 | E01    | E23   | -1    | -E12  | E023   | -E0    | -E012  | E2    | E02   |
+//! | E03   | -E12   | -E012 | E2     | -E1    | -E123  | E02    | -E01   | -E0123 | 1     | E23   | -E13  | E0     | E023   | -E013  | E3    | E03   |
+//! | E12   | E03    | E3    | -E013  | -E023  | E0     | -E13   | -E23   | 1      | E0123 | -E01  | -E02  | E123   | -E1    | -E2    | E012  | E12   |
+//! | E13   | -E02   | -E2   | E012   | -E0    | -E023  | E12    | -1     | -E23   | E01   | E0123 | -E03  | E1     | E123   | -E3    | E013  | E13   |
+//! | E23   | E01    | E1    | E0     | E012   | E013   | 1      | E12    | E13    | E02   | E03   | E0123 | E2     | E3     | E123   | E023  | E23   |
+//! | E012  | -E3    | -E03  | -E13   | -E23   | 1      | -E013  | -E023  | E0     | -E123 | E1    | E2    | -E0123 | E01    | E02    | E12   | E012  |
+//! | E013  | E2     | E02   | E12    | -1     | -E23   | E012   | -E0    | -E023  | -E1   | -E123 | E3    | -E01   | -E0123 | E03    | E13   | E013  |
+//! | E023  | -E1    | -E01  | 1      | E12    | E13    | E0     | E012   | E013   | -E2   | -E3   | -E123 | -E02   | -E03   | -E0123 | E23   | E023  |
+//! | E123  | -E0    | -1    | E01    | E02    | E03    | E1     | E2     | E3     | -E012 | -E013 | -E023 | -E12   | -E13   | -E23   | E0123 | E123  |
+//! | E0123 | 1      | E0    | E1     | E2     | E3     | E01    | E02    | E03    | E12   | E13   | E23   | E012   | E013   | E023   | E123  | E0123 |
+//!
+//!
+//! ### Dot multiplication table
+//!
+//! |       | 1     | E0    | E1    | E2   | E3    | E01 | E02  | E03 | E12  | E13 | E23  | E012 | E013 | E023 | E123 | E0123 |
+//! | ----- | ----- | ----- | ----- | ---- | ----- | --- | ---- | --- | ---- | --- | ---- | ---- | ---- | ---- | ---- | ----- |
+//! | 1     | 1     | E0    | E1    | E2   | E3    | E01 | E02  | E03 | E12  | E13 | E23  | E012 | E013 | E023 | E123 | E0123 |
+//! | E0    | E0    | 1     | 0     | 0    | 0     | E1  | E2   | E3  | 0    | 0   | 0    | E12  | E13  | E23  | 0    | E123  |
+//! | E1    | E1    | 0     | -1    | 0    | 0     | E0  | 0    | 0   | -E2  | -E3 | 0    | E02  | E03  | 0    | -E23 | E023  |
+//! | E2    | E2    | 0     | 0     | -1   | 0     | 0   | E0   | 0   | E1   | 0   | -E3  | -E01 | 0    | E03  | E13  | -E013 |
+//! | E3    | E3    | 0     | 0     | 0    | -1    | 0   | 0    | E0  | 0    | E1  | E2   | 0    | -E01 | -E02 | -E12 | E012  |
+//! | E01   | E01   | -E1   | -E0   | 0    | 0     | 1   | 0    | 0   | 0    | 0   | 0    | E2   | E3   | 0    | 0    | E23   |
+//! | E02   | E02   | -E2   | 0     | -E0  | 0     | 0   | 1    | 0   | 0    | 0   | 0    | -E1  | 0    | E3   | 0    | -E13  |
+//! | E03   | E03   | -E3   | 0     | 0    | -E0   | 0   | 0    | 1   | 0    | 0   | 0    | 0    | -E1  | -E2  | 0    | E12   |
+//! | E12   | E12   | 0     | E2    | -E1  | 0     | 0   | 0    | 0   | -1   | 0   | 0    | -E0  | 0    | 0    | -E3  | -E03  |
+//! | E13   | E13   | 0     | E3    | 0    | -E1   | 0   | 0    | 0   | 0    | -1  | 0    | 0    | -E0  | 0    | E2   | E02   |
+//! | E23   | E23   | 0     | 0     | E3   | -E2   | 0   | 0    | 0   | 0    | 0   | -1   | 0    | 0    | -E0  | -E1  | -E01  |
+//! | E012  | E012  | E12   | E02   | -E01 | 0     | E2  | -E1  | 0   | -E0  | 0   | 0    | -1   | 0    | 0    | 0    | -E3   |
+//! | E013  | E013  | E13   | E03   | 0    | -E01  | E3  | 0    | -E1 | 0    | -E0 | 0    | 0    | -1   | 0    | 0    | E2    |
+//! | E023  | E023  | E23   | 0     | E03  | -E02  | 0   | E3   | -E2 | 0    | 0   | -E0  | 0    | 0    | -1   | 0    | -E1   |
+//! | E123  | E123  | 0     | -E23  | E13  | -E12  | 0   | 0    | 0   | -E3  | E2  | -E1  | 0    | 0    | 0    | 1    | -E0   |
+//! | E0123 | E0123 | -E123 | -E023 | E013 | -E012 | E23 | -E13 | E12 | -E03 | E02 | -E01 | E3   | -E2  | E1   | E0   | -1    |
+//!
+//!
+//! ### Wedge multiplication table
+//!
+//! |       | 1     | E0     | E1    | E2     | E3    | E01   | E02    | E03   | E12   | E13    | E23   | E012   | E013  | E023   | E123  | E0123 |
+//! | ----- | ----- | ------ | ----- | ------ | ----- | ----- | ------ | ----- | ----- | ------ | ----- | ------ | ----- | ------ | ----- | ----- |
+//! | 1     | 1     | E0     | E1    | E2     | E3    | E01   | E02    | E03   | E12   | E13    | E23   | E012   | E013  | E023   | E123  | E0123 |
+//! | E0    | E0    | 0      | E01   | E02    | E03   | 0     | 0      | 0     | E012  | E013   | E023  | 0      | 0     | 0      | E0123 | 0     |
+//! | E1    | E1    | -E01   | 0     | E12    | E13   | 0     | -E012  | -E013 | 0     | 0      | E123  | 0      | 0     | -E0123 | 0     | 0     |
+//! | E2    | E2    | -E02   | -E12  | 0      | E23   | E012  | 0      | -E023 | 0     | -E123  | 0     | 0      | E0123 | 0      | 0     | 0     |
+//! | E3    | E3    | -E03   | -E13  | -E23   | 0     | E013  | E023   | 0     | E123  | 0      | 0     | -E0123 | 0     | 0      | 0     | 0     |
+//! | E01   | E01   | 0      | 0     | E012   | E013  | 0     | 0      | 0     | 0     | 0      | E0123 | 0      | 0     | 0      | 0     | 0     |
+//! | E02   | E02   | 0      | -E012 | 0      | E023  | 0     | 0      | 0     | 0     | -E0123 | 0     | 0      | 0     | 0      | 0     | 0     |
+//! | E03   | E03   | 0      | -E013 | -E023  | 0     | 0     | 0      | 0     | E0123 | 0      | 0     | 0      | 0     | 0      | 0     | 0     |
+//! | E12   | E12   | E012   | 0     | 0      | E123  | 0     | 0      | E0123 | 0     | 0      | 0     | 0      | 0     | 0      | 0     | 0     |
+//! | E13   | E13   | E013   | 0     | -E123  | 0     | 0     | -E0123 | 0     | 0     | 0      | 0     | 0      | 0     | 0      | 0     | 0     |
+//! | E23   | E23   | E023   | E123  | 0      | 0     | E0123 | 0      | 0     | 0     | 0      | 0     | 0      | 0     | 0      | 0     | 0     |
+//! | E012  | E012  | 0      | 0     | 0      | E0123 | 0     | 0      | 0     | 0     | 0      | 0     | 0      | 0     | 0      | 0     | 0     |
+//! | E013  | E013  | 0      | 0     | -E0123 | 0     | 0     | 0      | 0     | 0     | 0      | 0     | 0      | 0     | 0      | 0     | 0     |
+//! | E023  | E023  | 0      | E0123 | 0      | 0     | 0     | 0      | 0     | 0     | 0      | 0     | 0      | 0     | 0      | 0     | 0     |
+//! | E123  | E123  | -E0123 | 0     | 0      | 0     | 0     | 0      | 0     | 0     | 0      | 0     | 0      | 0     | 0      | 0     | 0     |
+//! | E0123 | E0123 | 0      | 0     | 0      | 0     | 0     | 0      | 0     | 0     | 0      | 0     | 0      | 0     | 0      | 0     | 0     |
+//!
+//!
+//! ### AntiWedge multiplication table
+//!
+//! |       | 1 | E0 | E1 | E2 | E3 | E01 | E02 | E03 | E12 | E13 | E23 | E012 | E013 | E023 | E123 | E0123 |
+//! | ----- | - | -- | -- | -- | -- | --- | --- | --- | --- | --- | --- | ---- | ---- | ---- | ---- | ----- |
+//! | 1     | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | 1     |
+//! | E0    | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 1    | E0    |
+//! | E1    | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | -1   | 0    | E1    |
+//! | E2    | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 1    | 0    | 0    | E2    |
+//! | E3    | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | -1   | 0    | 0    | 0    | E3    |
+//! | E01   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 1   | 0    | 0    | E0   | E1   | E01   |
+//! | E02   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | -1  | 0   | 0    | -E0  | 0    | E2   | E02   |
+//! | E03   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 1   | 0   | 0   | E0   | 0    | 0    | E3   | E03   |
+//! | E12   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 1   | 0   | 0   | 0   | 0    | -E1  | -E2  | 0    | E12   |
+//! | E13   | 0 | 0  | 0  | 0  | 0  | 0   | -1  | 0   | 0   | 0   | 0   | E1   | 0    | -E3  | 0    | E13   |
+//! | E23   | 0 | 0  | 0  | 0  | 0  | 1   | 0   | 0   | 0   | 0   | 0   | E2   | E3   | 0    | 0    | E23   |
+//! | E012  | 0 | 0  | 0  | 0  | 1  | 0   | 0   | E0  | 0   | E1  | E2  | 0    | E01  | E02  | E12  | E012  |
+//! | E013  | 0 | 0  | 0  | -1 | 0  | 0   | -E0 | 0   | -E1 | 0   | E3  | -E01 | 0    | E03  | E13  | E013  |
+//! | E023  | 0 | 0  | 1  | 0  | 0  | E0  | 0   | 0   | -E2 | -E3 | 0   | -E02 | -E03 | 0    | E23  | E023  |
+//! | E123  | 0 | -1 | 0  | 0  | 0  | E1  | E2  | E3  | 0   | 0   | 0   | -E12 | -E13 | -E23 | 0    | E123  |
+//! | E0123 | 1 | E0 | E1 | E2 | E3 | E01 | E02 | E03 | E12 | E13 | E23 | E012 | E013 | E023 | E123 | E0123 |
+//!
+//!
+//! ### LeftContraction multiplication table
+//!
+//! |       | 1 | E0 | E1 | E2 | E3 | E01 | E02 | E03 | E12 | E13 | E23 | E012 | E013 | E023 | E123 | E0123 |
+//! | ----- | - | -- | -- | -- | -- | --- | --- | --- | --- | --- | --- | ---- | ---- | ---- | ---- | ----- |
+//! | 1     | 1 | E0 | E1 | E2 | E3 | E01 | E02 | E03 | E12 | E13 | E23 | E012 | E013 | E023 | E123 | E0123 |
+//! | E0    | 0 | 1  | 0  | 0  | 0  | E1  | E2  | E3  | 0   | 0   | 0   | E12  | E13  | E23  | 0    | E123  |
+//! | E1    | 0 | 0  | -1 | 0  | 0  | E0  | 0   | 0   | -E2 | -E3 | 0   | E02  | E03  | 0    | -E23 | E023  |
+//! | E2    | 0 | 0  | 0  | -1 | 0  | 0   | E0  | 0   | E1  | 0   | -E3 | -E01 | 0    | E03  | E13  | -E013 |
+//! | E3    | 0 | 0  | 0  | 0  | -1 | 0   | 0   | E0  | 0   | E1  | E2  | 0    | -E01 | -E02 | -E12 | E012  |
+//! | E01   | 0 | 0  | 0  | 0  | 0  | 1   | 0   | 0   | 0   | 0   | 0   | E2   | E3   | 0    | 0    | E23   |
+//! | E02   | 0 | 0  | 0  | 0  | 0  | 0   | 1   | 0   | 0   | 0   | 0   | -E1  | 0    | E3   | 0    | -E13  |
+//! | E03   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 1   | 0   | 0   | 0   | 0    | -E1  | -E2  | 0    | E12   |
+//! | E12   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | -1  | 0   | 0   | -E0  | 0    | 0    | -E3  | -E03  |
+//! | E13   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | -1  | 0   | 0    | -E0  | 0    | E2   | E02   |
+//! | E23   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | -1  | 0    | 0    | -E0  | -E1  | -E01  |
+//! | E012  | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | -1   | 0    | 0    | 0    | -E3   |
+//! | E013  | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | -1   | 0    | 0    | E2    |
+//! | E023  | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | -1   | 0    | -E1   |
+//! | E123  | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 1    | -E0   |
+//! | E0123 | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | -1    |
+//!
+//!
+//! ### RightContraction multiplication table
+//!
+//! |       | 1     | E0    | E1    | E2   | E3    | E01 | E02  | E03 | E12  | E13 | E23  | E012 | E013 | E023 | E123 | E0123 |
+//! | ----- | ----- | ----- | ----- | ---- | ----- | --- | ---- | --- | ---- | --- | ---- | ---- | ---- | ---- | ---- | ----- |
+//! | 1     | 1     | 0     | 0     | 0    | 0     | 0   | 0    | 0   | 0    | 0   | 0    | 0    | 0    | 0    | 0    | 0     |
+//! | E0    | E0    | 1     | 0     | 0    | 0     | 0   | 0    | 0   | 0    | 0   | 0    | 0    | 0    | 0    | 0    | 0     |
+//! | E1    | E1    | 0     | -1    | 0    | 0     | 0   | 0    | 0   | 0    | 0   | 0    | 0    | 0    | 0    | 0    | 0     |
+//! | E2    | E2    | 0     | 0     | -1   | 0     | 0   | 0    | 0   | 0    | 0   | 0    | 0    | 0    | 0    | 0    | 0     |
+//! | E3    | E3    | 0     | 0     | 0    | -1    | 0   | 0    | 0   | 0    | 0   | 0    | 0    | 0    | 0    | 0    | 0     |
+//! | E01   | E01   | -E1   | -E0   | 0    | 0     | 1   | 0    | 0   | 0    | 0   | 0    | 0    | 0    | 0    | 0    | 0     |
+//! | E02   | E02   | -E2   | 0     | -E0  | 0     | 0   | 1    | 0   | 0    | 0   | 0    | 0    | 0    | 0    | 0    | 0     |
+//! | E03   | E03   | -E3   | 0     | 0    | -E0   | 0   | 0    | 1   | 0    | 0   | 0    | 0    | 0    | 0    | 0    | 0     |
+//! | E12   | E12   | 0     | E2    | -E1  | 0     | 0   | 0    | 0   | -1   | 0   | 0    | 0    | 0    | 0    | 0    | 0     |
+//! | E13   | E13   | 0     | E3    | 0    | -E1   | 0   | 0    | 0   | 0    | -1  | 0    | 0    | 0    | 0    | 0    | 0     |
+//! | E23   | E23   | 0     | 0     | E3   | -E2   | 0   | 0    | 0   | 0    | 0   | -1   | 0    | 0    | 0    | 0    | 0     |
+//! | E012  | E012  | E12   | E02   | -E01 | 0     | E2  | -E1  | 0   | -E0  | 0   | 0    | -1   | 0    | 0    | 0    | 0     |
+//! | E013  | E013  | E13   | E03   | 0    | -E01  | E3  | 0    | -E1 | 0    | -E0 | 0    | 0    | -1   | 0    | 0    | 0     |
+//! | E023  | E023  | E23   | 0     | E03  | -E02  | 0   | E3   | -E2 | 0    | 0   | -E0  | 0    | 0    | -1   | 0    | 0     |
+//! | E123  | E123  | 0     | -E23  | E13  | -E12  | 0   | 0    | 0   | -E3  | E2  | -E1  | 0    | 0    | 0    | 1    | 0     |
+//! | E0123 | E0123 | -E123 | -E023 | E013 | -E012 | E23 | -E13 | E12 | -E03 | E02 | -E01 | E3   | -E2  | E1   | E0   | -1    |
+//!
+//!
+//! ### ScalarProduct multiplication table
+//!
+//! |       | 1 | E0 | E1 | E2 | E3 | E01 | E02 | E03 | E12 | E13 | E23 | E012 | E013 | E023 | E123 | E0123 |
+//! | ----- | - | -- | -- | -- | -- | --- | --- | --- | --- | --- | --- | ---- | ---- | ---- | ---- | ----- |
+//! | 1     | 1 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | 0     |
+//! | E0    | 0 | 1  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | 0     |
+//! | E1    | 0 | 0  | -1 | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | 0     |
+//! | E2    | 0 | 0  | 0  | -1 | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | 0     |
+//! | E3    | 0 | 0  | 0  | 0  | -1 | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | 0     |
+//! | E01   | 0 | 0  | 0  | 0  | 0  | 1   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | 0     |
+//! | E02   | 0 | 0  | 0  | 0  | 0  | 0   | 1   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | 0     |
+//! | E03   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 1   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | 0     |
+//! | E12   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | -1  | 0   | 0   | 0    | 0    | 0    | 0    | 0     |
+//! | E13   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | -1  | 0   | 0    | 0    | 0    | 0    | 0     |
+//! | E23   | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | -1  | 0    | 0    | 0    | 0    | 0     |
+//! | E012  | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | -1   | 0    | 0    | 0    | 0     |
+//! | E013  | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | -1   | 0    | 0    | 0     |
+//! | E023  | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | -1   | 0    | 0     |
+//! | E123  | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 1    | 0     |
+//! | E0123 | 0 | 0  | 0  | 0  | 0  | 0   | 0   | 0   | 0   | 0   | 0   | 0    | 0    | 0    | 0    | -1    |
+//!
+//!
+//! ### Commutator multiplication table
+//!
+//! |       | 1 | E0     | E1    | E2     | E3    | E01  | E02   | E03  | E12   | E13   | E23   | E012   | E013  | E023   | E123  | E0123 |
+//! | ----- | - | ------ | ----- | ------ | ----- | ---- | ----- | ---- | ----- | ----- | ----- | ------ | ----- | ------ | ----- | ----- |
+//! | 1     | 0 | 0      | 0     | 0      | 0     | 0    | 0     | 0    | 0     | 0     | 0     | 0      | 0     | 0      | 0     | 0     |
+//! | E0    | 0 | 0      | E01   | E02    | E03   | E1   | E2    | E3   | 0     | 0     | 0     | 0      | 0     | 0      | E0123 | E123  |
+//! | E1    | 0 | -E01   | 0     | E12    | E13   | E0   | 0     | 0    | -E2   | -E3   | 0     | 0      | 0     | -E0123 | 0     | E023  |
+//! | E2    | 0 | -E02   | -E12  | 0      | E23   | 0    | E0    | 0    | E1    | 0     | -E3   | 0      | E0123 | 0      | 0     | -E013 |
+//! | E3    | 0 | -E03   | -E13  | -E23   | 0     | 0    | 0     | E0   | 0     | E1    | E2    | -E0123 | 0     | 0      | 0     | E012  |
+//! | E01   | 0 | -E1    | -E0   | 0      | 0     | 0    | -E12  | -E13 | -E02  | -E03  | 0     | 0      | 0     | -E123  | -E023 | 0     |
+//! | E02   | 0 | -E2    | 0     | -E0    | 0     | E12  | 0     | -E23 | E01   | 0     | -E03  | 0      | E123  | 0      | E013  | 0     |
+//! | E03   | 0 | -E3    | 0     | 0      | -E0   | E13  | E23   | 0    | 0     | E01   | E02   | -E123  | 0     | 0      | -E012 | 0     |
+//! | E12   | 0 | 0      | E2    | -E1    | 0     | E02  | -E01  | 0    | 0     | E23   | -E13  | 0      | E023  | -E013  | 0     | 0     |
+//! | E13   | 0 | 0      | E3    | 0      | -E1   | E03  | 0     | -E01 | -E23  | 0     | E12   | -E023  | 0     | E012   | 0     | 0     |
+//! | E23   | 0 | 0      | 0     | E3     | -E2   | 0    | E03   | -E02 | E13   | -E12  | 0     | E013   | -E012 | 0      | 0     | 0     |
+//! | E012  | 0 | 0      | 0     | 0      | E0123 | 0    | 0     | E123 | 0     | E023  | -E013 | 0      | E23   | -E13   | -E03  | -E3   |
+//! | E013  | 0 | 0      | 0     | -E0123 | 0     | 0    | -E123 | 0    | -E023 | 0     | E012  | -E23   | 0     | E12    | E02   | E2    |
+//! | E023  | 0 | 0      | E0123 | 0      | 0     | E123 | 0     | 0    | E013  | -E012 | 0     | E13    | -E12  | 0      | -E01  | -E1   |
+//! | E123  | 0 | -E0123 | 0     | 0      | 0     | E023 | -E013 | E012 | 0     | 0     | 0     | E03    | -E02  | E01    | 0     | -E0   |
+//! | E0123 | 0 | -E123  | -E023 | E013   | -E012 | 0    | 0     | 0    | 0     | 0     | 0     | E3     | -E2   | E1     | E0    | 0     |
+//!
+//!
+//! ### AntiDot multiplication table
+//!
+//! |       | 1      | E0    | E1     | E2     | E3     | E01    | E02    | E03    | E12   | E13   | E23   | E012   | E013   | E023   | E123  | E0123 |
+//! | ----- | ------ | ----- | ------ | ------ | ------ | ------ | ------ | ------ | ----- | ----- | ----- | ------ | ------ | ------ | ----- | ----- |
+//! | 1     | -E0123 | -E123 | -E023  | E013   | -E012  | -E23   | E13    | -E12   | E03   | -E02  | E01   | E3     | -E2    | E1     | E0    | 1     |
+//! | E0    | E123   | E0123 | 0      | 0      | 0      | -E023  | E013   | -E012  | 0     | 0     | 0     | -E03   | E02    | -E01   | 0     | E0    |
+//! | E1    | E023   | 0     | -E0123 | 0      | 0      | -E123  | 0      | 0      | -E013 | E012  | 0     | -E13   | E12    | 0      | E01   | E1    |
+//! | E2    | -E013  | 0     | 0      | -E0123 | 0      | 0      | -E123  | 0      | -E023 | 0     | E012  | -E23   | 0      | E12    | E02   | E2    |
+//! | E3    | E012   | 0     | 0      | 0      | -E0123 | 0      | 0      | -E123  | 0     | -E023 | E013  | 0      | -E23   | E13    | E03   | E3    |
+//! | E01   | -E23   | -E023 | -E123  | 0      | 0      | -E0123 | 0      | 0      | 0     | 0     | 0     | E013   | -E012  | 0      | 0     | E01   |
+//! | E02   | E13    | E013  | 0      | -E123  | 0      | 0      | -E0123 | 0      | 0     | 0     | 0     | E023   | 0      | -E012  | 0     | E02   |
+//! | E03   | -E12   | -E012 | 0      | 0      | -E123  | 0      | 0      | -E0123 | 0     | 0     | 0     | 0      | E023   | -E013  | 0     | E03   |
+//! | E12   | E03    | 0     | -E013  | -E023  | 0      | 0      | 0      | 0      | E0123 | 0     | 0     | E123   | 0      | 0      | E012  | E12   |
+//! | E13   | -E02   | 0     | E012   | 0      | -E023  | 0      | 0      | 0      | 0     | E0123 | 0     | 0      | E123   | 0      | E013  | E13   |
+//! | E23   | E01    | 0     | 0      | E012   | E013   | 0      | 0      | 0      | 0     | 0     | E0123 | 0      | 0      | E123   | E023  | E23   |
+//! | E012  | -E3    | -E03  | -E13   | -E23   | 0      | -E013  | -E023  | 0      | -E123 | 0     | 0     | -E0123 | 0      | 0      | 0     | E012  |
+//! | E013  | E2     | E02   | E12    | 0      | -E23   | E012   | 0      | -E023  | 0     | -E123 | 0     | 0      | -E0123 | 0      | 0     | E013  |
+//! | E023  | -E1    | -E01  | 0      | E12    | E13    | 0      | E012   | E013   | 0     | 0     | -E123 | 0      | 0      | -E0123 | 0     | E023  |
+//! | E123  | -E0    | 0     | E01    | E02    | E03    | 0      | 0      | 0      | -E012 | -E013 | -E023 | 0      | 0      | 0      | E0123 | E123  |
+//! | E0123 | 1      | E0    | E1     | E2     | E3     | E01    | E02    | E03    | E12   | E13   | E23   | E012   | E013   | E023   | E123  | E0123 |
+
+use derive_more::{Add, Neg, Sub};
+
+use super::*;
+
+/// The scalar type (real numbers).
+/// Squares to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct S(pub f64);
+
+impl std::ops::Mul<f64> for S {
+	type Output = S;
+	fn mul(self, rhs: f64) -> Self::Output {
+		S(self.0 * rhs)
+	}
+}
+
+impl Square for S {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for S {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for S {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for S {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for S {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E0(pub f64);
+
+impl std::ops::Mul<f64> for E0 {
+	type Output = E0;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E0(self.0 * rhs)
+	}
+}
+
+impl Square for E0 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E0 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E0 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E0 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E0 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E1(pub f64);
+
+impl std::ops::Mul<f64> for E1 {
+	type Output = E1;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E1(self.0 * rhs)
+	}
+}
+
+impl Square for E1 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E1 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E1 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E1 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E1 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E2(pub f64);
+
+impl std::ops::Mul<f64> for E2 {
+	type Output = E2;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E2(self.0 * rhs)
+	}
+}
+
+impl Square for E2 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E2 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E2 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E2 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E2 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E3(pub f64);
+
+impl std::ops::Mul<f64> for E3 {
+	type Output = E3;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E3(self.0 * rhs)
+	}
+}
+
+impl Square for E3 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E01(pub f64);
+
+impl std::ops::Mul<f64> for E01 {
+	type Output = E01;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E01(self.0 * rhs)
+	}
+}
+
+impl Square for E01 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E01 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E01 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E01 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E01 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E02(pub f64);
+
+impl std::ops::Mul<f64> for E02 {
+	type Output = E02;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E02(self.0 * rhs)
+	}
+}
+
+impl Square for E02 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E02 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E02 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E02 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E02 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E03(pub f64);
+
+impl std::ops::Mul<f64> for E03 {
+	type Output = E03;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E03(self.0 * rhs)
+	}
+}
+
+impl Square for E03 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E03 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E03 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E03 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E03 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E12(pub f64);
+
+impl std::ops::Mul<f64> for E12 {
+	type Output = E12;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E12(self.0 * rhs)
+	}
+}
+
+impl Square for E12 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E12 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E12 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E12 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E12 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E13(pub f64);
+
+impl std::ops::Mul<f64> for E13 {
+	type Output = E13;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E13(self.0 * rhs)
+	}
+}
+
+impl Square for E13 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E13 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E13 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E13 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E13 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E23(pub f64);
+
+impl std::ops::Mul<f64> for E23 {
+	type Output = E23;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E23(self.0 * rhs)
+	}
+}
+
+impl Square for E23 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E23 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E23 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E23 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E23 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E012(pub f64);
+
+impl std::ops::Mul<f64> for E012 {
+	type Output = E012;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E012(self.0 * rhs)
+	}
+}
+
+impl Square for E012 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E012 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E012 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E012 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E012 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E013(pub f64);
+
+impl std::ops::Mul<f64> for E013 {
+	type Output = E013;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E013(self.0 * rhs)
+	}
+}
+
+impl Square for E013 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E013 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E013 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E013 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E013 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E023(pub f64);
+
+impl std::ops::Mul<f64> for E023 {
+	type Output = E023;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E023(self.0 * rhs)
+	}
+}
+
+impl Square for E023 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E023 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E023 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E023 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E023 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E123(pub f64);
+
+impl std::ops::Mul<f64> for E123 {
+	type Output = E123;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E123(self.0 * rhs)
+	}
+}
+
+impl Square for E123 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E123 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E123 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E123 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E123 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// The pseudo-scalar.
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E0123(pub f64);
+
+impl std::ops::Mul<f64> for E0123 {
+	type Output = E0123;
+	fn mul(self, rhs: f64) -> Self::Output {
+		E0123(self.0 * rhs)
+	}
+}
+
+impl Square for E0123 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for E0123 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for E0123 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for E0123 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for E0123 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl RCompl for blades:
+
+impl RCompl for S {
+	type Output = E0123;
+	fn rcompl(self) -> Self::Output {
+		E0123(self.0)
+	}
+}
+
+impl std::ops::Not for S {
+	type Output = E0123;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E0 {
+	type Output = E123;
+	fn rcompl(self) -> Self::Output {
+		E123(self.0)
+	}
+}
+
+impl std::ops::Not for E0 {
+	type Output = E123;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E1 {
+	type Output = E023;
+	fn rcompl(self) -> Self::Output {
+		E023(-self.0)
+	}
+}
+
+impl std::ops::Not for E1 {
+	type Output = E023;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E2 {
+	type Output = E013;
+	fn rcompl(self) -> Self::Output {
+		E013(self.0)
+	}
+}
+
+impl std::ops::Not for E2 {
+	type Output = E013;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E3 {
+	type Output = E012;
+	fn rcompl(self) -> Self::Output {
+		E012(-self.0)
+	}
+}
+
+impl std::ops::Not for E3 {
+	type Output = E012;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E01 {
+	type Output = E23;
+	fn rcompl(self) -> Self::Output {
+		E23(self.0)
+	}
+}
+
+impl std::ops::Not for E01 {
+	type Output = E23;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E02 {
+	type Output = E13;
+	fn rcompl(self) -> Self::Output {
+		E13(-self.0)
+	}
+}
+
+impl std::ops::Not for E02 {
+	type Output = E13;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E03 {
+	type Output = E12;
+	fn rcompl(self) -> Self::Output {
+		E12(self.0)
+	}
+}
+
+impl std::ops::Not for E03 {
+	type Output = E12;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E12 {
+	type Output = E03;
+	fn rcompl(self) -> Self::Output {
+		E03(self.0)
+	}
+}
+
+impl std::ops::Not for E12 {
+	type Output = E03;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E13 {
+	type Output = E02;
+	fn rcompl(self) -> Self::Output {
+		E02(-self.0)
+	}
+}
+
+impl std::ops::Not for E13 {
+	type Output = E02;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E23 {
+	type Output = E01;
+	fn rcompl(self) -> Self::Output {
+		E01(self.0)
+	}
+}
+
+impl std::ops::Not for E23 {
+	type Output = E01;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E012 {
+	type Output = E3;
+	fn rcompl(self) -> Self::Output {
+		E3(self.0)
+	}
+}
+
+impl std::ops::Not for E012 {
+	type Output = E3;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E013 {
+	type Output = E2;
+	fn rcompl(self) -> Self::Output {
+		E2(-self.0)
+	}
+}
+
+impl std::ops::Not for E013 {
+	type Output = E2;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E023 {
+	type Output = E1;
+	fn rcompl(self) -> Self::Output {
+		E1(self.0)
+	}
+}
+
+impl std::ops::Not for E023 {
+	type Output = E1;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E123 {
+	type Output = E0;
+	fn rcompl(self) -> Self::Output {
+		E0(-self.0)
+	}
+}
+
+impl std::ops::Not for E123 {
+	type Output = E0;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for E0123 {
+	type Output = S;
+	fn rcompl(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+impl std::ops::Not for E0123 {
+	type Output = S;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl LCompl for blades:
+
+impl LCompl for S {
+	type Output = E0123;
+	fn lcompl(self) -> Self::Output {
+		E0123(self.0)
+	}
+}
+
+impl LCompl for E0 {
+	type Output = E123;
+	fn lcompl(self) -> Self::Output {
+		E123(-self.0)
+	}
+}
+
+impl LCompl for E1 {
+	type Output = E023;
+	fn lcompl(self) -> Self::Output {
+		E023(self.0)
+	}
+}
+
+impl LCompl for E2 {
+	type Output = E013;
+	fn lcompl(self) -> Self::Output {
+		E013(-self.0)
+	}
+}
+
+impl LCompl for E3 {
+	type Output = E012;
+	fn lcompl(self) -> Self::Output {
+		E012(self.0)
+	}
+}
+
+impl LCompl for E01 {
+	type Output = E23;
+	fn lcompl(self) -> Self::Output {
+		E23(self.0)
+	}
+}
+
+impl LCompl for E02 {
+	type Output = E13;
+	fn lcompl(self) -> Self::Output {
+		E13(-self.0)
+	}
+}
+
+impl LCompl for E03 {
+	type Output = E12;
+	fn lcompl(self) -> Self::Output {
+		E12(self.0)
+	}
+}
+
+impl LCompl for E12 {
+	type Output = E03;
+	fn lcompl(self) -> Self::Output {
+		E03(self.0)
+	}
+}
+
+impl LCompl for E13 {
+	type Output = E02;
+	fn lcompl(self) -> Self::Output {
+		E02(-self.0)
+	}
+}
+
+impl LCompl for E23 {
+	type Output = E01;
+	fn lcompl(self) -> Self::Output {
+		E01(self.0)
+	}
+}
+
+impl LCompl for E012 {
+	type Output = E3;
+	fn lcompl(self) -> Self::Output {
+		E3(-self.0)
+	}
+}
+
+impl LCompl for E013 {
+	type Output = E2;
+	fn lcompl(self) -> Self::Output {
+		E2(self.0)
+	}
+}
+
+impl LCompl for E023 {
+	type Output = E1;
+	fn lcompl(self) -> Self::Output {
+		E1(-self.0)
+	}
+}
+
+impl LCompl for E123 {
+	type Output = E0;
+	fn lcompl(self) -> Self::Output {
+		E0(self.0)
+	}
+}
+
+impl LCompl for E0123 {
+	type Output = S;
+	fn lcompl(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Reverse for blades:
+
+impl Reverse for S {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for E0 {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for E1 {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for E2 {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for E3 {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for E01 {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for E02 {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for E03 {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for E12 {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for E13 {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for E23 {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for E012 {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for E013 {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for E023 {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for E123 {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for E0123 {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiReverse for blades:
+
+impl AntiReverse for S {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for E0 {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for E1 {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for E2 {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for E3 {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for E01 {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for E02 {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for E03 {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for E12 {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for E13 {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for E23 {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for E012 {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for E013 {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for E023 {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for E123 {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for E0123 {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Involute for blades:
+
+impl Involute for S {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for E0 {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for E1 {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for E2 {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for E3 {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for E01 {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for E02 {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for E03 {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for E12 {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for E13 {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for E23 {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for E012 {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for E013 {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for E023 {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for E123 {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for E0123 {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Conjugate for blades:
+
+impl Conjugate for S {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for E0 {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for E1 {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for E2 {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for E3 {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for E01 {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for E02 {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for E03 {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for E12 {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for E13 {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for E23 {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for E012 {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for E013 {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for E023 {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for E123 {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for E0123 {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Dual for blades:
+
+impl Dual for S {
+	type Output = E0123;
+	fn dual(self) -> Self::Output {
+		E0123(self.0)
+	}
+}
+
+impl Dual for E0 {
+	type Output = E123;
+	fn dual(self) -> Self::Output {
+		E123(self.0)
+	}
+}
+
+impl Dual for E1 {
+	type Output = E023;
+	fn dual(self) -> Self::Output {
+		E023(-self.0)
+	}
+}
+
+impl Dual for E2 {
+	type Output = E013;
+	fn dual(self) -> Self::Output {
+		E013(self.0)
+	}
+}
+
+impl Dual for E3 {
+	type Output = E012;
+	fn dual(self) -> Self::Output {
+		E012(-self.0)
+	}
+}
+
+impl Dual for E01 {
+	type Output = E23;
+	fn dual(self) -> Self::Output {
+		E23(self.0)
+	}
+}
+
+impl Dual for E02 {
+	type Output = E13;
+	fn dual(self) -> Self::Output {
+		E13(-self.0)
+	}
+}
+
+impl Dual for E03 {
+	type Output = E12;
+	fn dual(self) -> Self::Output {
+		E12(self.0)
+	}
+}
+
+impl Dual for E12 {
+	type Output = E03;
+	fn dual(self) -> Self::Output {
+		E03(self.0)
+	}
+}
+
+impl Dual for E13 {
+	type Output = E02;
+	fn dual(self) -> Self::Output {
+		E02(-self.0)
+	}
+}
+
+impl Dual for E23 {
+	type Output = E01;
+	fn dual(self) -> Self::Output {
+		E01(self.0)
+	}
+}
+
+impl Dual for E012 {
+	type Output = E3;
+	fn dual(self) -> Self::Output {
+		E3(-self.0)
+	}
+}
+
+impl Dual for E013 {
+	type Output = E2;
+	fn dual(self) -> Self::Output {
+		E2(self.0)
+	}
+}
+
+impl Dual for E023 {
+	type Output = E1;
+	fn dual(self) -> Self::Output {
+		E1(-self.0)
+	}
+}
+
+impl Dual for E123 {
+	type Output = E0;
+	fn dual(self) -> Self::Output {
+		E0(self.0)
+	}
+}
+
+impl Dual for E0123 {
+	type Output = S;
+	fn dual(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Bulk for blades:
+
+impl Bulk for S {
+	type Output = S;
+	fn bulk(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+impl Bulk for E0 {
+	type Output = E0;
+	fn bulk(self) -> Self::Output {
+		E0(self.0)
+	}
+}
+
+impl Bulk for E1 {
+	type Output = E1;
+	fn bulk(self) -> Self::Output {
+		E1(-self.0)
+	}
+}
+
+impl Bulk for E2 {
+	type Output = E2;
+	fn bulk(self) -> Self::Output {
+		E2(-self.0)
+	}
+}
+
+impl Bulk for E3 {
+	type Output = E3;
+	fn bulk(self) -> Self::Output {
+		E3(-self.0)
+	}
+}
+
+impl Bulk for E01 {
+	type Output = E01;
+	fn bulk(self) -> Self::Output {
+		E01(-self.0)
+	}
+}
+
+impl Bulk for E02 {
+	type Output = E02;
+	fn bulk(self) -> Self::Output {
+		E02(-self.0)
+	}
+}
+
+impl Bulk for E03 {
+	type Output = E03;
+	fn bulk(self) -> Self::Output {
+		E03(-self.0)
+	}
+}
+
+impl Bulk for E12 {
+	type Output = E12;
+	fn bulk(self) -> Self::Output {
+		E12(self.0)
+	}
+}
+
+impl Bulk for E13 {
+	type Output = E13;
+	fn bulk(self) -> Self::Output {
+		E13(self.0)
+	}
+}
+
+impl Bulk for E23 {
+	type Output = E23;
+	fn bulk(self) -> Self::Output {
+		E23(self.0)
+	}
+}
+
+impl Bulk for E012 {
+	type Output = E012;
+	fn bulk(self) -> Self::Output {
+		E012(self.0)
+	}
+}
+
+impl Bulk for E013 {
+	type Output = E013;
+	fn bulk(self) -> Self::Output {
+		E013(self.0)
+	}
+}
+
+impl Bulk for E023 {
+	type Output = E023;
+	fn bulk(self) -> Self::Output {
+		E023(self.0)
+	}
+}
+
+impl Bulk for E123 {
+	type Output = E123;
+	fn bulk(self) -> Self::Output {
+		E123(-self.0)
+	}
+}
+
+impl Bulk for E0123 {
+	type Output = E0123;
+	fn bulk(self) -> Self::Output {
+		E0123(-self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Weight for blades:
+
+impl Weight for S {
+	type Output = S;
+	fn weight(self) -> Self::Output {
+		S(-self.0)
+	}
+}
+
+impl Weight for E0 {
+	type Output = E0;
+	fn weight(self) -> Self::Output {
+		E0(-self.0)
+	}
+}
+
+impl Weight for E1 {
+	type Output = E1;
+	fn weight(self) -> Self::Output {
+		E1(self.0)
+	}
+}
+
+impl Weight for E2 {
+	type Output = E2;
+	fn weight(self) -> Self::Output {
+		E2(self.0)
+	}
+}
+
+impl Weight for E3 {
+	type Output = E3;
+	fn weight(self) -> Self::Output {
+		E3(self.0)
+	}
+}
+
+impl Weight for E01 {
+	type Output = E01;
+	fn weight(self) -> Self::Output {
+		E01(self.0)
+	}
+}
+
+impl Weight for E02 {
+	type Output = E02;
+	fn weight(self) -> Self::Output {
+		E02(self.0)
+	}
+}
+
+impl Weight for E03 {
+	type Output = E03;
+	fn weight(self) -> Self::Output {
+		E03(self.0)
+	}
+}
+
+impl Weight for E12 {
+	type Output = E12;
+	fn weight(self) -> Self::Output {
+		E12(-self.0)
+	}
+}
+
+impl Weight for E13 {
+	type Output = E13;
+	fn weight(self) -> Self::Output {
+		E13(-self.0)
+	}
+}
+
+impl Weight for E23 {
+	type Output = E23;
+	fn weight(self) -> Self::Output {
+		E23(-self.0)
+	}
+}
+
+impl Weight for E012 {
+	type Output = E012;
+	fn weight(self) -> Self::Output {
+		E012(-self.0)
+	}
+}
+
+impl Weight for E013 {
+	type Output = E013;
+	fn weight(self) -> Self::Output {
+		E013(-self.0)
+	}
+}
+
+impl Weight for E023 {
+	type Output = E023;
+	fn weight(self) -> Self::Output {
+		E023(-self.0)
+	}
+}
+
+impl Weight for E123 {
+	type Output = E123;
+	fn weight(self) -> Self::Output {
+		E123(self.0)
+	}
+}
+
+impl Weight for E0123 {
+	type Output = E0123;
+	fn weight(self) -> Self::Output {
+		E0123(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl BulkDual for blades:
+
+impl BulkDual for S {
+	type Output = E0123;
+	fn bulk_dual(self) -> Self::Output {
+		E0123(self.0)
+	}
+}
+
+impl BulkDual for E0 {
+	type Output = E123;
+	fn bulk_dual(self) -> Self::Output {
+		E123(self.0)
+	}
+}
+
+impl BulkDual for E1 {
+	type Output = E023;
+	fn bulk_dual(self) -> Self::Output {
+		E023(self.0)
+	}
+}
+
+impl BulkDual for E2 {
+	type Output = E013;
+	fn bulk_dual(self) -> Self::Output {
+		E013(-self.0)
+	}
+}
+
+impl BulkDual for E3 {
+	type Output = E012;
+	fn bulk_dual(self) -> Self::Output {
+		E012(self.0)
+	}
+}
+
+impl BulkDual for E01 {
+	type Output = E23;
+	fn bulk_dual(self) -> Self::Output {
+		E23(-self.0)
+	}
+}
+
+impl BulkDual for E02 {
+	type Output = E13;
+	fn bulk_dual(self) -> Self::Output {
+		E13(self.0)
+	}
+}
+
+impl BulkDual for E03 {
+	type Output = E12;
+	fn bulk_dual(self) -> Self::Output {
+		E12(-self.0)
+	}
+}
+
+impl BulkDual for E12 {
+	type Output = E03;
+	fn bulk_dual(self) -> Self::Output {
+		E03(self.0)
+	}
+}
+
+impl BulkDual for E13 {
+	type Output = E02;
+	fn bulk_dual(self) -> Self::Output {
+		E02(-self.0)
+	}
+}
+
+impl BulkDual for E23 {
+	type Output = E01;
+	fn bulk_dual(self) -> Self::Output {
+		E01(self.0)
+	}
+}
+
+impl BulkDual for E012 {
+	type Output = E3;
+	fn bulk_dual(self) -> Self::Output {
+		E3(self.0)
+	}
+}
+
+impl BulkDual for E013 {
+	type Output = E2;
+	fn bulk_dual(self) -> Self::Output {
+		E2(-self.0)
+	}
+}
+
+impl BulkDual for E023 {
+	type Output = E1;
+	fn bulk_dual(self) -> Self::Output {
+		E1(self.0)
+	}
+}
+
+impl BulkDual for E123 {
+	type Output = E0;
+	fn bulk_dual(self) -> Self::Output {
+		E0(self.0)
+	}
+}
+
+impl BulkDual for E0123 {
+	type Output = S;
+	fn bulk_dual(self) -> Self::Output {
+		S(-self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl WeightDual for blades:
+
+impl WeightDual for S {
+	type Output = E0123;
+	fn weight_dual(self) -> Self::Output {
+		E0123(-self.0)
+	}
+}
+
+impl WeightDual for E0 {
+	type Output = E123;
+	fn weight_dual(self) -> Self::Output {
+		E123(-self.0)
+	}
+}
+
+impl WeightDual for E1 {
+	type Output = E023;
+	fn weight_dual(self) -> Self::Output {
+		E023(-self.0)
+	}
+}
+
+impl WeightDual for E2 {
+	type Output = E013;
+	fn weight_dual(self) -> Self::Output {
+		E013(self.0)
+	}
+}
+
+impl WeightDual for E3 {
+	type Output = E012;
+	fn weight_dual(self) -> Self::Output {
+		E012(-self.0)
+	}
+}
+
+impl WeightDual for E01 {
+	type Output = E23;
+	fn weight_dual(self) -> Self::Output {
+		E23(self.0)
+	}
+}
+
+impl WeightDual for E02 {
+	type Output = E13;
+	fn weight_dual(self) -> Self::Output {
+		E13(-self.0)
+	}
+}
+
+impl WeightDual for E03 {
+	type Output = E12;
+	fn weight_dual(self) -> Self::Output {
+		E12(self.0)
+	}
+}
+
+impl WeightDual for E12 {
+	type Output = E03;
+	fn weight_dual(self) -> Self::Output {
+		E03(-self.0)
+	}
+}
+
+impl WeightDual for E13 {
+	type Output = E02;
+	fn weight_dual(self) -> Self::Output {
+		E02(self.0)
+	}
+}
+
+impl WeightDual for E23 {
+	type Output = E01;
+	fn weight_dual(self) -> Self::Output {
+		E01(-self.0)
+	}
+}
+
+impl WeightDual for E012 {
+	type Output = E3;
+	fn weight_dual(self) -> Self::Output {
+		E3(-self.0)
+	}
+}
+
+impl WeightDual for E013 {
+	type Output = E2;
+	fn weight_dual(self) -> Self::Output {
+		E2(self.0)
+	}
+}
+
+impl WeightDual for E023 {
+	type Output = E1;
+	fn weight_dual(self) -> Self::Output {
+		E1(-self.0)
+	}
+}
+
+impl WeightDual for E123 {
+	type Output = E0;
+	fn weight_dual(self) -> Self::Output {
+		E0(-self.0)
+	}
+}
+
+impl WeightDual for E0123 {
+	type Output = S;
+	fn weight_dual(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Geometric for blades:
+
+impl Geometric<S> for S {
+	type Output = S;
+	fn geometric(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for S {
+	type Output = S;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for S {
+	type Output = E0;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for S {
+	type Output = E0;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for S {
+	type Output = E1;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for S {
+	type Output = E1;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for S {
+	type Output = E2;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for S {
+	type Output = E2;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for S {
+	type Output = E3;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for S {
+	type Output = E3;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for S {
+	type Output = E01;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for S {
+	type Output = E01;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for S {
+	type Output = E02;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for S {
+	type Output = E02;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for S {
+	type Output = E03;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for S {
+	type Output = E03;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for S {
+	type Output = E12;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for S {
+	type Output = E12;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for S {
+	type Output = E13;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for S {
+	type Output = E13;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for S {
+	type Output = E23;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for S {
+	type Output = E23;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for S {
+	type Output = E012;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for S {
+	type Output = E012;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for S {
+	type Output = E013;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for S {
+	type Output = E013;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for S {
+	type Output = E023;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for S {
+	type Output = E023;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for S {
+	type Output = E123;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for S {
+	type Output = E123;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for S {
+	type Output = E0123;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for S {
+	type Output = E0123;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E0 {
+	type Output = E0;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E0 {
+	type Output = E0;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E0 {
+	type Output = S;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E0 {
+	type Output = S;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E0 {
+	type Output = E01;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E0 {
+	type Output = E01;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E0 {
+	type Output = E02;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E0 {
+	type Output = E02;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E0 {
+	type Output = E03;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E0 {
+	type Output = E03;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E0 {
+	type Output = E1;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E0 {
+	type Output = E1;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E0 {
+	type Output = E2;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E0 {
+	type Output = E2;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E0 {
+	type Output = E3;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E0 {
+	type Output = E3;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E0 {
+	type Output = E012;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E0 {
+	type Output = E012;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E0 {
+	type Output = E013;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E0 {
+	type Output = E013;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E0 {
+	type Output = E023;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E0 {
+	type Output = E023;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E0 {
+	type Output = E12;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E0 {
+	type Output = E12;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E0 {
+	type Output = E13;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E0 {
+	type Output = E13;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E0 {
+	type Output = E23;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E0 {
+	type Output = E23;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E0 {
+	type Output = E0123;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E0 {
+	type Output = E0123;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E0 {
+	type Output = E123;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E0 {
+	type Output = E123;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E1 {
+	type Output = E1;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E1 {
+	type Output = E1;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E1 {
+	type Output = E01;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E1 {
+	type Output = E01;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E1 {
+	type Output = S;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E1 {
+	type Output = S;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E1 {
+	type Output = E12;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E1 {
+	type Output = E12;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E1 {
+	type Output = E13;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E1 {
+	type Output = E13;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E1 {
+	type Output = E0;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E1 {
+	type Output = E0;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E1 {
+	type Output = E012;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E1 {
+	type Output = E012;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E1 {
+	type Output = E013;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E1 {
+	type Output = E013;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E1 {
+	type Output = E2;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E1 {
+	type Output = E2;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E1 {
+	type Output = E3;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E1 {
+	type Output = E3;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E1 {
+	type Output = E123;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E1 {
+	type Output = E123;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E1 {
+	type Output = E02;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E1 {
+	type Output = E02;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E1 {
+	type Output = E03;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E1 {
+	type Output = E03;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E1 {
+	type Output = E0123;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E1 {
+	type Output = E0123;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E1 {
+	type Output = E23;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E1 {
+	type Output = E23;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E1 {
+	type Output = E023;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E1 {
+	type Output = E023;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E2 {
+	type Output = E2;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E2 {
+	type Output = E2;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E2 {
+	type Output = E02;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E2 {
+	type Output = E02;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E2 {
+	type Output = E12;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E2 {
+	type Output = E12;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E2 {
+	type Output = S;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E2 {
+	type Output = S;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E2 {
+	type Output = E23;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E2 {
+	type Output = E23;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E2 {
+	type Output = E012;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E2 {
+	type Output = E012;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E2 {
+	type Output = E0;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E2 {
+	type Output = E0;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E2 {
+	type Output = E023;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E2 {
+	type Output = E023;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E2 {
+	type Output = E1;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E2 {
+	type Output = E1;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E2 {
+	type Output = E123;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E2 {
+	type Output = E123;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E2 {
+	type Output = E3;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E2 {
+	type Output = E3;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E2 {
+	type Output = E01;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E2 {
+	type Output = E01;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E2 {
+	type Output = E0123;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E2 {
+	type Output = E0123;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E2 {
+	type Output = E03;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E2 {
+	type Output = E03;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E2 {
+	type Output = E13;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E2 {
+	type Output = E13;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E2 {
+	type Output = E013;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E2 {
+	type Output = E013;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E3 {
+	type Output = E3;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E3 {
+	type Output = E3;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E3 {
+	type Output = E03;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E3 {
+	type Output = E03;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E3 {
+	type Output = E13;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E3 {
+	type Output = E13;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E3 {
+	type Output = E23;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E3 {
+	type Output = E23;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E3 {
+	type Output = S;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E3 {
+	type Output = S;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E3 {
+	type Output = E013;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E3 {
+	type Output = E013;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E3 {
+	type Output = E023;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E3 {
+	type Output = E023;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E3 {
+	type Output = E0;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E3 {
+	type Output = E0;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E3 {
+	type Output = E123;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E3 {
+	type Output = E123;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E3 {
+	type Output = E1;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E3 {
+	type Output = E1;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E3 {
+	type Output = E2;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E3 {
+	type Output = E2;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E3 {
+	type Output = E0123;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E3 {
+	type Output = E0123;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E3 {
+	type Output = E01;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E3 {
+	type Output = E01;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E3 {
+	type Output = E02;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E3 {
+	type Output = E02;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E3 {
+	type Output = E12;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E3 {
+	type Output = E12;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E3 {
+	type Output = E012;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E3 {
+	type Output = E012;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E01 {
+	type Output = E01;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E01 {
+	type Output = E01;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E01 {
+	type Output = E1;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E01 {
+	type Output = E1;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E01 {
+	type Output = E0;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E01 {
+	type Output = E0;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E01 {
+	type Output = E012;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E01 {
+	type Output = E012;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E01 {
+	type Output = E013;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E01 {
+	type Output = E013;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E01 {
+	type Output = S;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E01 {
+	type Output = S;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E01 {
+	type Output = E12;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E01 {
+	type Output = E12;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E01 {
+	type Output = E13;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E01 {
+	type Output = E13;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E01 {
+	type Output = E02;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E01 {
+	type Output = E02;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E01 {
+	type Output = E03;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E01 {
+	type Output = E03;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E01 {
+	type Output = E0123;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E01 {
+	type Output = E0123;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E01 {
+	type Output = E2;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E01 {
+	type Output = E2;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E01 {
+	type Output = E3;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E01 {
+	type Output = E3;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E01 {
+	type Output = E123;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E01 {
+	type Output = E123;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E01 {
+	type Output = E023;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E01 {
+	type Output = E023;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E01 {
+	type Output = E23;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E01 {
+	type Output = E23;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E02 {
+	type Output = E02;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E02 {
+	type Output = E02;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E02 {
+	type Output = E2;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E02 {
+	type Output = E2;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E02 {
+	type Output = E012;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E02 {
+	type Output = E012;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E02 {
+	type Output = E0;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E02 {
+	type Output = E0;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E02 {
+	type Output = E023;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E02 {
+	type Output = E023;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E02 {
+	type Output = E12;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E02 {
+	type Output = E12;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E02 {
+	type Output = S;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E02 {
+	type Output = S;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E02 {
+	type Output = E23;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E02 {
+	type Output = E23;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E02 {
+	type Output = E01;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E02 {
+	type Output = E01;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E02 {
+	type Output = E0123;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E02 {
+	type Output = E0123;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E02 {
+	type Output = E03;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E02 {
+	type Output = E03;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E02 {
+	type Output = E1;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E02 {
+	type Output = E1;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E02 {
+	type Output = E123;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E02 {
+	type Output = E123;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E02 {
+	type Output = E3;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E02 {
+	type Output = E3;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E02 {
+	type Output = E013;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E02 {
+	type Output = E013;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E02 {
+	type Output = E13;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E02 {
+	type Output = E13;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E03 {
+	type Output = E03;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E03 {
+	type Output = E03;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E03 {
+	type Output = E3;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E03 {
+	type Output = E3;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E03 {
+	type Output = E013;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E03 {
+	type Output = E013;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E03 {
+	type Output = E023;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E03 {
+	type Output = E023;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E03 {
+	type Output = E0;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E03 {
+	type Output = E0;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E03 {
+	type Output = E13;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E03 {
+	type Output = E13;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E03 {
+	type Output = E23;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E03 {
+	type Output = E23;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E03 {
+	type Output = S;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E03 {
+	type Output = S;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E03 {
+	type Output = E0123;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E03 {
+	type Output = E0123;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E03 {
+	type Output = E01;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E03 {
+	type Output = E01;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E03 {
+	type Output = E02;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E03 {
+	type Output = E02;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E03 {
+	type Output = E123;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E03 {
+	type Output = E123;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E03 {
+	type Output = E1;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E03 {
+	type Output = E1;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E03 {
+	type Output = E2;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E03 {
+	type Output = E2;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E03 {
+	type Output = E012;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E03 {
+	type Output = E012;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E03 {
+	type Output = E12;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E03 {
+	type Output = E12;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E12 {
+	type Output = E12;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E12 {
+	type Output = E12;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E12 {
+	type Output = E012;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E12 {
+	type Output = E012;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E12 {
+	type Output = E2;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E12 {
+	type Output = E2;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E12 {
+	type Output = E1;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E12 {
+	type Output = E1;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E12 {
+	type Output = E123;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E12 {
+	type Output = E123;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E12 {
+	type Output = E02;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E12 {
+	type Output = E02;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E12 {
+	type Output = E01;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E12 {
+	type Output = E01;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E12 {
+	type Output = E0123;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E12 {
+	type Output = E0123;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E12 {
+	type Output = S;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E12 {
+	type Output = S;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E12 {
+	type Output = E23;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E12 {
+	type Output = E23;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E12 {
+	type Output = E13;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E12 {
+	type Output = E13;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E12 {
+	type Output = E0;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E12 {
+	type Output = E0;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E12 {
+	type Output = E023;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E12 {
+	type Output = E023;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E12 {
+	type Output = E013;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E12 {
+	type Output = E013;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E12 {
+	type Output = E3;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E12 {
+	type Output = E3;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E12 {
+	type Output = E03;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E12 {
+	type Output = E03;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E13 {
+	type Output = E13;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E13 {
+	type Output = E13;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E13 {
+	type Output = E013;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E13 {
+	type Output = E013;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E13 {
+	type Output = E3;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E13 {
+	type Output = E3;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E13 {
+	type Output = E123;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E13 {
+	type Output = E123;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E13 {
+	type Output = E1;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E13 {
+	type Output = E1;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E13 {
+	type Output = E03;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E13 {
+	type Output = E03;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E13 {
+	type Output = E0123;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E13 {
+	type Output = E0123;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E13 {
+	type Output = E01;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E13 {
+	type Output = E01;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E13 {
+	type Output = E23;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E13 {
+	type Output = E23;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E13 {
+	type Output = S;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E13 {
+	type Output = S;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E13 {
+	type Output = E12;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E13 {
+	type Output = E12;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E13 {
+	type Output = E023;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E13 {
+	type Output = E023;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E13 {
+	type Output = E0;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E13 {
+	type Output = E0;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E13 {
+	type Output = E012;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E13 {
+	type Output = E012;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E13 {
+	type Output = E2;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E13 {
+	type Output = E2;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E13 {
+	type Output = E02;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E13 {
+	type Output = E02;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E23 {
+	type Output = E23;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E23 {
+	type Output = E23;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E23 {
+	type Output = E023;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E23 {
+	type Output = E023;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E23 {
+	type Output = E123;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E23 {
+	type Output = E123;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E23 {
+	type Output = E3;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E23 {
+	type Output = E3;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E23 {
+	type Output = E2;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E23 {
+	type Output = E2;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E23 {
+	type Output = E0123;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E23 {
+	type Output = E0123;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E23 {
+	type Output = E03;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E23 {
+	type Output = E03;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E23 {
+	type Output = E02;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E23 {
+	type Output = E02;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E23 {
+	type Output = E13;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E23 {
+	type Output = E13;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E23 {
+	type Output = E12;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E23 {
+	type Output = E12;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E23 {
+	type Output = S;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E23 {
+	type Output = S;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E23 {
+	type Output = E013;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E23 {
+	type Output = E013;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E23 {
+	type Output = E012;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E23 {
+	type Output = E012;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E23 {
+	type Output = E0;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E23 {
+	type Output = E0;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E23 {
+	type Output = E1;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E23 {
+	type Output = E1;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E23 {
+	type Output = E01;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E23 {
+	type Output = E01;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E012 {
+	type Output = E012;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E012 {
+	type Output = E012;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E012 {
+	type Output = E12;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E012 {
+	type Output = E12;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E012 {
+	type Output = E02;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E012 {
+	type Output = E02;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E012 {
+	type Output = E01;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E012 {
+	type Output = E01;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E012 {
+	type Output = E0123;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E012 {
+	type Output = E0123;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E012 {
+	type Output = E2;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E012 {
+	type Output = E2;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E012 {
+	type Output = E1;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E012 {
+	type Output = E1;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E012 {
+	type Output = E123;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E012 {
+	type Output = E123;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E012 {
+	type Output = E0;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E012 {
+	type Output = E0;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E012 {
+	type Output = E023;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E012 {
+	type Output = E023;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E012 {
+	type Output = E013;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E012 {
+	type Output = E013;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E012 {
+	type Output = S;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E012 {
+	type Output = S;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E012 {
+	type Output = E23;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E012 {
+	type Output = E23;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E012 {
+	type Output = E13;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E012 {
+	type Output = E13;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E012 {
+	type Output = E03;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E012 {
+	type Output = E03;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E012 {
+	type Output = E3;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E012 {
+	type Output = E3;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E013 {
+	type Output = E013;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E013 {
+	type Output = E013;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E013 {
+	type Output = E13;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E013 {
+	type Output = E13;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E013 {
+	type Output = E03;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E013 {
+	type Output = E03;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E013 {
+	type Output = E0123;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E013 {
+	type Output = E0123;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E013 {
+	type Output = E01;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E013 {
+	type Output = E01;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E013 {
+	type Output = E3;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E013 {
+	type Output = E3;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E013 {
+	type Output = E123;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E013 {
+	type Output = E123;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E013 {
+	type Output = E1;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E013 {
+	type Output = E1;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E013 {
+	type Output = E023;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E013 {
+	type Output = E023;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E013 {
+	type Output = E0;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E013 {
+	type Output = E0;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E013 {
+	type Output = E012;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E013 {
+	type Output = E012;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E013 {
+	type Output = E23;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E013 {
+	type Output = E23;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E013 {
+	type Output = S;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E013 {
+	type Output = S;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E013 {
+	type Output = E12;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E013 {
+	type Output = E12;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E013 {
+	type Output = E02;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E013 {
+	type Output = E02;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E013 {
+	type Output = E2;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E013 {
+	type Output = E2;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E023 {
+	type Output = E023;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E023 {
+	type Output = E023;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E023 {
+	type Output = E23;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E023 {
+	type Output = E23;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E023 {
+	type Output = E0123;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E023 {
+	type Output = E0123;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E023 {
+	type Output = E03;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E023 {
+	type Output = E03;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E023 {
+	type Output = E02;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E023 {
+	type Output = E02;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E023 {
+	type Output = E123;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E023 {
+	type Output = E123;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E023 {
+	type Output = E3;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E023 {
+	type Output = E3;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E023 {
+	type Output = E2;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E023 {
+	type Output = E2;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E023 {
+	type Output = E013;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E023 {
+	type Output = E013;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E023 {
+	type Output = E012;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E023 {
+	type Output = E012;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E023 {
+	type Output = E0;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E023 {
+	type Output = E0;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E023 {
+	type Output = E13;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E023 {
+	type Output = E13;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E023 {
+	type Output = E12;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E023 {
+	type Output = E12;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E023 {
+	type Output = S;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E023 {
+	type Output = S;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E023 {
+	type Output = E01;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E023 {
+	type Output = E01;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E023 {
+	type Output = E1;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E023 {
+	type Output = E1;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E123 {
+	type Output = E123;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E123 {
+	type Output = E123;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E123 {
+	type Output = E0123;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E123 {
+	type Output = E0123;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E123 {
+	type Output = E23;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E123 {
+	type Output = E23;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E123 {
+	type Output = E13;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E123 {
+	type Output = E13;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E123 {
+	type Output = E12;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E123 {
+	type Output = E12;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E123 {
+	type Output = E023;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E123 {
+	type Output = E023;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E123 {
+	type Output = E013;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E123 {
+	type Output = E013;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E123 {
+	type Output = E012;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E123 {
+	type Output = E012;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E123 {
+	type Output = E3;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E123 {
+	type Output = E3;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E123 {
+	type Output = E2;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E123 {
+	type Output = E2;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E123 {
+	type Output = E1;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E123 {
+	type Output = E1;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E123 {
+	type Output = E03;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E123 {
+	type Output = E03;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E123 {
+	type Output = E02;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E123 {
+	type Output = E02;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E123 {
+	type Output = E01;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E123 {
+	type Output = E01;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E123 {
+	type Output = S;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E123 {
+	type Output = S;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E123 {
+	type Output = E0;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E123 {
+	type Output = E0;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for E0123 {
+	type Output = E0123;
+	fn geometric(self, rhs: S) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for E0123 {
+	type Output = E0123;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0> for E0123 {
+	type Output = E123;
+	fn geometric(self, rhs: E0) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0> for E0123 {
+	type Output = E123;
+	fn mul(self, rhs: E0) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E1> for E0123 {
+	type Output = E023;
+	fn geometric(self, rhs: E1) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E1> for E0123 {
+	type Output = E023;
+	fn mul(self, rhs: E1) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E2> for E0123 {
+	type Output = E013;
+	fn geometric(self, rhs: E2) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E2> for E0123 {
+	type Output = E013;
+	fn mul(self, rhs: E2) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E3> for E0123 {
+	type Output = E012;
+	fn geometric(self, rhs: E3) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E3> for E0123 {
+	type Output = E012;
+	fn mul(self, rhs: E3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E01> for E0123 {
+	type Output = E23;
+	fn geometric(self, rhs: E01) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E01> for E0123 {
+	type Output = E23;
+	fn mul(self, rhs: E01) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E02> for E0123 {
+	type Output = E13;
+	fn geometric(self, rhs: E02) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E02> for E0123 {
+	type Output = E13;
+	fn mul(self, rhs: E02) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E03> for E0123 {
+	type Output = E12;
+	fn geometric(self, rhs: E03) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E03> for E0123 {
+	type Output = E12;
+	fn mul(self, rhs: E03) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E12> for E0123 {
+	type Output = E03;
+	fn geometric(self, rhs: E12) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E12> for E0123 {
+	type Output = E03;
+	fn mul(self, rhs: E12) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E13> for E0123 {
+	type Output = E02;
+	fn geometric(self, rhs: E13) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E13> for E0123 {
+	type Output = E02;
+	fn mul(self, rhs: E13) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E23> for E0123 {
+	type Output = E01;
+	fn geometric(self, rhs: E23) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E23> for E0123 {
+	type Output = E01;
+	fn mul(self, rhs: E23) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E012> for E0123 {
+	type Output = E3;
+	fn geometric(self, rhs: E012) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E012> for E0123 {
+	type Output = E3;
+	fn mul(self, rhs: E012) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E013> for E0123 {
+	type Output = E2;
+	fn geometric(self, rhs: E013) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E013> for E0123 {
+	type Output = E2;
+	fn mul(self, rhs: E013) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E023> for E0123 {
+	type Output = E1;
+	fn geometric(self, rhs: E023) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E023> for E0123 {
+	type Output = E1;
+	fn mul(self, rhs: E023) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E123> for E0123 {
+	type Output = E0;
+	fn geometric(self, rhs: E123) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E123> for E0123 {
+	type Output = E0;
+	fn mul(self, rhs: E123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<E0123> for E0123 {
+	type Output = S;
+	fn geometric(self, rhs: E0123) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<E0123> for E0123 {
+	type Output = S;
+	fn mul(self, rhs: E0123) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiGeometric for blades:
+
+impl AntiGeometric<S> for S {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for S {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for S {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for S {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for S {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for S {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for S {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for S {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for S {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for S {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for S {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for S {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for S {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for S {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for S {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for S {
+	type Output = S;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E0 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E0 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E0 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E0 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E0 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E0 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E0 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E0 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E0 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E0 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E0 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E0 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E0 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E0 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E0 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E0 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E1 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E1 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E1 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E1 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E1 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E1 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E1 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E1 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E1 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E1 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E1 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E1 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E1 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E1 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E1 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E1 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E2 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E2 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E2 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E2 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E2 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E2 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E2 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E2 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E2 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E2 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E2 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E2 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E2 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E2 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E2 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E2 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E3 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E3 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E3 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E3 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E3 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E3 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E3 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E3 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E3 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E3 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E3 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E3 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E3 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E3 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E3 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E3 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E01 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E01 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E01 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E01 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E01 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E01 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E01 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E01 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E01 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E01 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E01 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E01 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E01 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E01 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E01 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E01 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E02 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E02 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E02 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E02 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E02 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E02 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E02 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E02 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E02 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E02 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E02 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E02 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E02 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E02 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E02 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E02 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E03 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E03 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E03 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E03 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E03 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E03 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E03 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E03 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E03 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E03 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E03 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E03 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E03 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E03 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E03 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E03 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E12 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E12 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E12 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E12 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E12 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E12 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E12 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E12 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E12 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E12 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E12 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E12 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E12 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E12 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E12 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E12 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E13 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E13 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E13 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E13 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E13 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E13 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E13 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E13 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E13 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E13 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E13 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E13 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E13 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E13 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E13 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E13 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E23 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E23 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E23 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E23 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E23 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E23 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E23 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E23 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E23 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E23 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E23 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E23 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E23 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E23 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E23 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E23 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E012 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E012 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E012 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E012 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E012 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E012 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E012 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E012 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E012 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E012 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E012 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E012 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E012 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E012 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E012 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E012 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E013 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E013 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E013 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E013 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E013 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E013 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E013 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E013 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E013 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E013 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E013 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E013 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E013 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E013 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E013 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E013 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E023 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E023 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E023 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E023 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E023 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E023 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E023 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E023 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E023 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E023 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E023 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E023 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E023 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E023 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E023 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E023 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E123 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E123 {
+	type Output = S;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E123 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E123 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E123 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E123 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E123 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E123 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E123 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E123 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E123 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E123 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E123 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E123 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E123 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E123 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for E0123 {
+	type Output = S;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0> for E0123 {
+	type Output = E0;
+	fn anti_geometric(self, rhs: E0) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E1> for E0123 {
+	type Output = E1;
+	fn anti_geometric(self, rhs: E1) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E2> for E0123 {
+	type Output = E2;
+	fn anti_geometric(self, rhs: E2) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E3> for E0123 {
+	type Output = E3;
+	fn anti_geometric(self, rhs: E3) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E01> for E0123 {
+	type Output = E01;
+	fn anti_geometric(self, rhs: E01) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E02> for E0123 {
+	type Output = E02;
+	fn anti_geometric(self, rhs: E02) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E03> for E0123 {
+	type Output = E03;
+	fn anti_geometric(self, rhs: E03) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E12> for E0123 {
+	type Output = E12;
+	fn anti_geometric(self, rhs: E12) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E13> for E0123 {
+	type Output = E13;
+	fn anti_geometric(self, rhs: E13) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E23> for E0123 {
+	type Output = E23;
+	fn anti_geometric(self, rhs: E23) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E012> for E0123 {
+	type Output = E012;
+	fn anti_geometric(self, rhs: E012) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E013> for E0123 {
+	type Output = E013;
+	fn anti_geometric(self, rhs: E013) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E023> for E0123 {
+	type Output = E023;
+	fn anti_geometric(self, rhs: E023) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E123> for E0123 {
+	type Output = E123;
+	fn anti_geometric(self, rhs: E123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<E0123> for E0123 {
+	type Output = E0123;
+	fn anti_geometric(self, rhs: E0123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Dot for blades:
+
+impl Dot<S> for S {
+	type Output = S;
+	fn dot(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for S {
+	type Output = S;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for S {
+	type Output = E0;
+	fn dot(self, rhs: E0) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0> for S {
+	type Output = E0;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for S {
+	type Output = E1;
+	fn dot(self, rhs: E1) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E1> for S {
+	type Output = E1;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for S {
+	type Output = E2;
+	fn dot(self, rhs: E2) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E2> for S {
+	type Output = E2;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for S {
+	type Output = E3;
+	fn dot(self, rhs: E3) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E3> for S {
+	type Output = E3;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for S {
+	type Output = E01;
+	fn dot(self, rhs: E01) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E01> for S {
+	type Output = E01;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for S {
+	type Output = E02;
+	fn dot(self, rhs: E02) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E02> for S {
+	type Output = E02;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for S {
+	type Output = E03;
+	fn dot(self, rhs: E03) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E03> for S {
+	type Output = E03;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for S {
+	type Output = E12;
+	fn dot(self, rhs: E12) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E12> for S {
+	type Output = E12;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for S {
+	type Output = E13;
+	fn dot(self, rhs: E13) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E13> for S {
+	type Output = E13;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for S {
+	type Output = E23;
+	fn dot(self, rhs: E23) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E23> for S {
+	type Output = E23;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for S {
+	type Output = E012;
+	fn dot(self, rhs: E012) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E012> for S {
+	type Output = E012;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for S {
+	type Output = E013;
+	fn dot(self, rhs: E013) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E013> for S {
+	type Output = E013;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for S {
+	type Output = E023;
+	fn dot(self, rhs: E023) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E023> for S {
+	type Output = E023;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for S {
+	type Output = E123;
+	fn dot(self, rhs: E123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E123> for S {
+	type Output = E123;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for S {
+	type Output = E0123;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for S {
+	type Output = E0123;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E0 {
+	type Output = E0;
+	fn dot(self, rhs: S) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E0 {
+	type Output = E0;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E0 {
+	type Output = S;
+	fn dot(self, rhs: E0) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0> for E0 {
+	type Output = S;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E0 {
+	type Output = Zero;
+	fn dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E1> for E0 {
+	type Output = Zero;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E0 {
+	type Output = Zero;
+	fn dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E2> for E0 {
+	type Output = Zero;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E0 {
+	type Output = Zero;
+	fn dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E3> for E0 {
+	type Output = Zero;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E0 {
+	type Output = E1;
+	fn dot(self, rhs: E01) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E01> for E0 {
+	type Output = E1;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E0 {
+	type Output = E2;
+	fn dot(self, rhs: E02) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E02> for E0 {
+	type Output = E2;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E0 {
+	type Output = E3;
+	fn dot(self, rhs: E03) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E03> for E0 {
+	type Output = E3;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E0 {
+	type Output = Zero;
+	fn dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E12> for E0 {
+	type Output = Zero;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E0 {
+	type Output = Zero;
+	fn dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E13> for E0 {
+	type Output = Zero;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E0 {
+	type Output = Zero;
+	fn dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E23> for E0 {
+	type Output = Zero;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E0 {
+	type Output = E12;
+	fn dot(self, rhs: E012) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E012> for E0 {
+	type Output = E12;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E0 {
+	type Output = E13;
+	fn dot(self, rhs: E013) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E013> for E0 {
+	type Output = E13;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E0 {
+	type Output = E23;
+	fn dot(self, rhs: E023) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E023> for E0 {
+	type Output = E23;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E0 {
+	type Output = Zero;
+	fn dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E123> for E0 {
+	type Output = Zero;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E0 {
+	type Output = E123;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E0 {
+	type Output = E123;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E1 {
+	type Output = E1;
+	fn dot(self, rhs: S) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E1 {
+	type Output = E1;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E1 {
+	type Output = Zero;
+	fn dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E0> for E1 {
+	type Output = Zero;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E1 {
+	type Output = S;
+	fn dot(self, rhs: E1) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E1> for E1 {
+	type Output = S;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E1 {
+	type Output = Zero;
+	fn dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E2> for E1 {
+	type Output = Zero;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E1 {
+	type Output = Zero;
+	fn dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E3> for E1 {
+	type Output = Zero;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E1 {
+	type Output = E0;
+	fn dot(self, rhs: E01) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E01> for E1 {
+	type Output = E0;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E1 {
+	type Output = Zero;
+	fn dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E02> for E1 {
+	type Output = Zero;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E1 {
+	type Output = Zero;
+	fn dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E03> for E1 {
+	type Output = Zero;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E1 {
+	type Output = E2;
+	fn dot(self, rhs: E12) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E12> for E1 {
+	type Output = E2;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E1 {
+	type Output = E3;
+	fn dot(self, rhs: E13) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E13> for E1 {
+	type Output = E3;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E1 {
+	type Output = Zero;
+	fn dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E23> for E1 {
+	type Output = Zero;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E1 {
+	type Output = E02;
+	fn dot(self, rhs: E012) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E012> for E1 {
+	type Output = E02;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E1 {
+	type Output = E03;
+	fn dot(self, rhs: E013) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E013> for E1 {
+	type Output = E03;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E1 {
+	type Output = Zero;
+	fn dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E023> for E1 {
+	type Output = Zero;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E1 {
+	type Output = E23;
+	fn dot(self, rhs: E123) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E123> for E1 {
+	type Output = E23;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E1 {
+	type Output = E023;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E1 {
+	type Output = E023;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E2 {
+	type Output = E2;
+	fn dot(self, rhs: S) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E2 {
+	type Output = E2;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E2 {
+	type Output = Zero;
+	fn dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E0> for E2 {
+	type Output = Zero;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E2 {
+	type Output = Zero;
+	fn dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E1> for E2 {
+	type Output = Zero;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E2 {
+	type Output = S;
+	fn dot(self, rhs: E2) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E2> for E2 {
+	type Output = S;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E2 {
+	type Output = Zero;
+	fn dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E3> for E2 {
+	type Output = Zero;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E2 {
+	type Output = Zero;
+	fn dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E01> for E2 {
+	type Output = Zero;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E2 {
+	type Output = E0;
+	fn dot(self, rhs: E02) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E02> for E2 {
+	type Output = E0;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E2 {
+	type Output = Zero;
+	fn dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E03> for E2 {
+	type Output = Zero;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E2 {
+	type Output = E1;
+	fn dot(self, rhs: E12) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E12> for E2 {
+	type Output = E1;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E2 {
+	type Output = Zero;
+	fn dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E13> for E2 {
+	type Output = Zero;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E2 {
+	type Output = E3;
+	fn dot(self, rhs: E23) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E23> for E2 {
+	type Output = E3;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E2 {
+	type Output = E01;
+	fn dot(self, rhs: E012) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E012> for E2 {
+	type Output = E01;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E2 {
+	type Output = Zero;
+	fn dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E013> for E2 {
+	type Output = Zero;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E2 {
+	type Output = E03;
+	fn dot(self, rhs: E023) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E023> for E2 {
+	type Output = E03;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E2 {
+	type Output = E13;
+	fn dot(self, rhs: E123) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E123> for E2 {
+	type Output = E13;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E2 {
+	type Output = E013;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E2 {
+	type Output = E013;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E3 {
+	type Output = E3;
+	fn dot(self, rhs: S) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E3 {
+	type Output = E3;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E3 {
+	type Output = Zero;
+	fn dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E0> for E3 {
+	type Output = Zero;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E3 {
+	type Output = Zero;
+	fn dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E1> for E3 {
+	type Output = Zero;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E3 {
+	type Output = Zero;
+	fn dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E2> for E3 {
+	type Output = Zero;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E3 {
+	type Output = S;
+	fn dot(self, rhs: E3) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E3> for E3 {
+	type Output = S;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E3 {
+	type Output = Zero;
+	fn dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E01> for E3 {
+	type Output = Zero;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E3 {
+	type Output = Zero;
+	fn dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E02> for E3 {
+	type Output = Zero;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E3 {
+	type Output = E0;
+	fn dot(self, rhs: E03) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E03> for E3 {
+	type Output = E0;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E3 {
+	type Output = Zero;
+	fn dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E12> for E3 {
+	type Output = Zero;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E3 {
+	type Output = E1;
+	fn dot(self, rhs: E13) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E13> for E3 {
+	type Output = E1;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E3 {
+	type Output = E2;
+	fn dot(self, rhs: E23) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E23> for E3 {
+	type Output = E2;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E3 {
+	type Output = Zero;
+	fn dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E012> for E3 {
+	type Output = Zero;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E3 {
+	type Output = E01;
+	fn dot(self, rhs: E013) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E013> for E3 {
+	type Output = E01;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E3 {
+	type Output = E02;
+	fn dot(self, rhs: E023) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E023> for E3 {
+	type Output = E02;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E3 {
+	type Output = E12;
+	fn dot(self, rhs: E123) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E123> for E3 {
+	type Output = E12;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E3 {
+	type Output = E012;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E3 {
+	type Output = E012;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E01 {
+	type Output = E01;
+	fn dot(self, rhs: S) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E01 {
+	type Output = E01;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E01 {
+	type Output = E1;
+	fn dot(self, rhs: E0) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0> for E01 {
+	type Output = E1;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E01 {
+	type Output = E0;
+	fn dot(self, rhs: E1) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E1> for E01 {
+	type Output = E0;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E01 {
+	type Output = Zero;
+	fn dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E2> for E01 {
+	type Output = Zero;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E01 {
+	type Output = Zero;
+	fn dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E3> for E01 {
+	type Output = Zero;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E01 {
+	type Output = S;
+	fn dot(self, rhs: E01) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E01> for E01 {
+	type Output = S;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E01 {
+	type Output = Zero;
+	fn dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E02> for E01 {
+	type Output = Zero;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E01 {
+	type Output = Zero;
+	fn dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E03> for E01 {
+	type Output = Zero;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E01 {
+	type Output = Zero;
+	fn dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E12> for E01 {
+	type Output = Zero;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E01 {
+	type Output = Zero;
+	fn dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E13> for E01 {
+	type Output = Zero;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E01 {
+	type Output = Zero;
+	fn dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E23> for E01 {
+	type Output = Zero;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E01 {
+	type Output = E2;
+	fn dot(self, rhs: E012) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E012> for E01 {
+	type Output = E2;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E01 {
+	type Output = E3;
+	fn dot(self, rhs: E013) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E013> for E01 {
+	type Output = E3;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E01 {
+	type Output = Zero;
+	fn dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E023> for E01 {
+	type Output = Zero;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E01 {
+	type Output = Zero;
+	fn dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E123> for E01 {
+	type Output = Zero;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E01 {
+	type Output = E23;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E01 {
+	type Output = E23;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E02 {
+	type Output = E02;
+	fn dot(self, rhs: S) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E02 {
+	type Output = E02;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E02 {
+	type Output = E2;
+	fn dot(self, rhs: E0) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0> for E02 {
+	type Output = E2;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E02 {
+	type Output = Zero;
+	fn dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E1> for E02 {
+	type Output = Zero;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E02 {
+	type Output = E0;
+	fn dot(self, rhs: E2) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E2> for E02 {
+	type Output = E0;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E02 {
+	type Output = Zero;
+	fn dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E3> for E02 {
+	type Output = Zero;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E02 {
+	type Output = Zero;
+	fn dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E01> for E02 {
+	type Output = Zero;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E02 {
+	type Output = S;
+	fn dot(self, rhs: E02) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E02> for E02 {
+	type Output = S;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E02 {
+	type Output = Zero;
+	fn dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E03> for E02 {
+	type Output = Zero;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E02 {
+	type Output = Zero;
+	fn dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E12> for E02 {
+	type Output = Zero;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E02 {
+	type Output = Zero;
+	fn dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E13> for E02 {
+	type Output = Zero;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E02 {
+	type Output = Zero;
+	fn dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E23> for E02 {
+	type Output = Zero;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E02 {
+	type Output = E1;
+	fn dot(self, rhs: E012) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E012> for E02 {
+	type Output = E1;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E02 {
+	type Output = Zero;
+	fn dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E013> for E02 {
+	type Output = Zero;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E02 {
+	type Output = E3;
+	fn dot(self, rhs: E023) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E023> for E02 {
+	type Output = E3;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E02 {
+	type Output = Zero;
+	fn dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E123> for E02 {
+	type Output = Zero;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E02 {
+	type Output = E13;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E02 {
+	type Output = E13;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E03 {
+	type Output = E03;
+	fn dot(self, rhs: S) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E03 {
+	type Output = E03;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E03 {
+	type Output = E3;
+	fn dot(self, rhs: E0) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0> for E03 {
+	type Output = E3;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E03 {
+	type Output = Zero;
+	fn dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E1> for E03 {
+	type Output = Zero;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E03 {
+	type Output = Zero;
+	fn dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E2> for E03 {
+	type Output = Zero;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E03 {
+	type Output = E0;
+	fn dot(self, rhs: E3) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E3> for E03 {
+	type Output = E0;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E03 {
+	type Output = Zero;
+	fn dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E01> for E03 {
+	type Output = Zero;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E03 {
+	type Output = Zero;
+	fn dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E02> for E03 {
+	type Output = Zero;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E03 {
+	type Output = S;
+	fn dot(self, rhs: E03) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E03> for E03 {
+	type Output = S;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E03 {
+	type Output = Zero;
+	fn dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E12> for E03 {
+	type Output = Zero;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E03 {
+	type Output = Zero;
+	fn dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E13> for E03 {
+	type Output = Zero;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E03 {
+	type Output = Zero;
+	fn dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E23> for E03 {
+	type Output = Zero;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E03 {
+	type Output = Zero;
+	fn dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E012> for E03 {
+	type Output = Zero;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E03 {
+	type Output = E1;
+	fn dot(self, rhs: E013) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E013> for E03 {
+	type Output = E1;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E03 {
+	type Output = E2;
+	fn dot(self, rhs: E023) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E023> for E03 {
+	type Output = E2;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E03 {
+	type Output = Zero;
+	fn dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E123> for E03 {
+	type Output = Zero;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E03 {
+	type Output = E12;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E03 {
+	type Output = E12;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E12 {
+	type Output = E12;
+	fn dot(self, rhs: S) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E12 {
+	type Output = E12;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E12 {
+	type Output = Zero;
+	fn dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E0> for E12 {
+	type Output = Zero;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E12 {
+	type Output = E2;
+	fn dot(self, rhs: E1) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E1> for E12 {
+	type Output = E2;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E12 {
+	type Output = E1;
+	fn dot(self, rhs: E2) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E2> for E12 {
+	type Output = E1;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E12 {
+	type Output = Zero;
+	fn dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E3> for E12 {
+	type Output = Zero;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E12 {
+	type Output = Zero;
+	fn dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E01> for E12 {
+	type Output = Zero;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E12 {
+	type Output = Zero;
+	fn dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E02> for E12 {
+	type Output = Zero;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E12 {
+	type Output = Zero;
+	fn dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E03> for E12 {
+	type Output = Zero;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E12 {
+	type Output = S;
+	fn dot(self, rhs: E12) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E12> for E12 {
+	type Output = S;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E12 {
+	type Output = Zero;
+	fn dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E13> for E12 {
+	type Output = Zero;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E12 {
+	type Output = Zero;
+	fn dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E23> for E12 {
+	type Output = Zero;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E12 {
+	type Output = E0;
+	fn dot(self, rhs: E012) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E012> for E12 {
+	type Output = E0;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E12 {
+	type Output = Zero;
+	fn dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E013> for E12 {
+	type Output = Zero;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E12 {
+	type Output = Zero;
+	fn dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E023> for E12 {
+	type Output = Zero;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E12 {
+	type Output = E3;
+	fn dot(self, rhs: E123) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E123> for E12 {
+	type Output = E3;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E12 {
+	type Output = E03;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E12 {
+	type Output = E03;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E13 {
+	type Output = E13;
+	fn dot(self, rhs: S) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E13 {
+	type Output = E13;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E13 {
+	type Output = Zero;
+	fn dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E0> for E13 {
+	type Output = Zero;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E13 {
+	type Output = E3;
+	fn dot(self, rhs: E1) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E1> for E13 {
+	type Output = E3;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E13 {
+	type Output = Zero;
+	fn dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E2> for E13 {
+	type Output = Zero;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E13 {
+	type Output = E1;
+	fn dot(self, rhs: E3) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E3> for E13 {
+	type Output = E1;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E13 {
+	type Output = Zero;
+	fn dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E01> for E13 {
+	type Output = Zero;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E13 {
+	type Output = Zero;
+	fn dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E02> for E13 {
+	type Output = Zero;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E13 {
+	type Output = Zero;
+	fn dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E03> for E13 {
+	type Output = Zero;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E13 {
+	type Output = Zero;
+	fn dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E12> for E13 {
+	type Output = Zero;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E13 {
+	type Output = S;
+	fn dot(self, rhs: E13) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E13> for E13 {
+	type Output = S;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E13 {
+	type Output = Zero;
+	fn dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E23> for E13 {
+	type Output = Zero;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E13 {
+	type Output = Zero;
+	fn dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E012> for E13 {
+	type Output = Zero;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E13 {
+	type Output = E0;
+	fn dot(self, rhs: E013) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E013> for E13 {
+	type Output = E0;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E13 {
+	type Output = Zero;
+	fn dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E023> for E13 {
+	type Output = Zero;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E13 {
+	type Output = E2;
+	fn dot(self, rhs: E123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E123> for E13 {
+	type Output = E2;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E13 {
+	type Output = E02;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E13 {
+	type Output = E02;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E23 {
+	type Output = E23;
+	fn dot(self, rhs: S) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E23 {
+	type Output = E23;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E23 {
+	type Output = Zero;
+	fn dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E0> for E23 {
+	type Output = Zero;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E23 {
+	type Output = Zero;
+	fn dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E1> for E23 {
+	type Output = Zero;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E23 {
+	type Output = E3;
+	fn dot(self, rhs: E2) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E2> for E23 {
+	type Output = E3;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E23 {
+	type Output = E2;
+	fn dot(self, rhs: E3) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E3> for E23 {
+	type Output = E2;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E23 {
+	type Output = Zero;
+	fn dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E01> for E23 {
+	type Output = Zero;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E23 {
+	type Output = Zero;
+	fn dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E02> for E23 {
+	type Output = Zero;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E23 {
+	type Output = Zero;
+	fn dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E03> for E23 {
+	type Output = Zero;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E23 {
+	type Output = Zero;
+	fn dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E12> for E23 {
+	type Output = Zero;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E23 {
+	type Output = Zero;
+	fn dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E13> for E23 {
+	type Output = Zero;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E23 {
+	type Output = S;
+	fn dot(self, rhs: E23) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E23> for E23 {
+	type Output = S;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E23 {
+	type Output = Zero;
+	fn dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E012> for E23 {
+	type Output = Zero;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E23 {
+	type Output = Zero;
+	fn dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E013> for E23 {
+	type Output = Zero;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E23 {
+	type Output = E0;
+	fn dot(self, rhs: E023) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E023> for E23 {
+	type Output = E0;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E23 {
+	type Output = E1;
+	fn dot(self, rhs: E123) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E123> for E23 {
+	type Output = E1;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E23 {
+	type Output = E01;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E23 {
+	type Output = E01;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E012 {
+	type Output = E012;
+	fn dot(self, rhs: S) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E012 {
+	type Output = E012;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E012 {
+	type Output = E12;
+	fn dot(self, rhs: E0) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0> for E012 {
+	type Output = E12;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E012 {
+	type Output = E02;
+	fn dot(self, rhs: E1) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E1> for E012 {
+	type Output = E02;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E012 {
+	type Output = E01;
+	fn dot(self, rhs: E2) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E2> for E012 {
+	type Output = E01;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E012 {
+	type Output = Zero;
+	fn dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E3> for E012 {
+	type Output = Zero;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E012 {
+	type Output = E2;
+	fn dot(self, rhs: E01) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E01> for E012 {
+	type Output = E2;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E012 {
+	type Output = E1;
+	fn dot(self, rhs: E02) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E02> for E012 {
+	type Output = E1;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E012 {
+	type Output = Zero;
+	fn dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E03> for E012 {
+	type Output = Zero;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E012 {
+	type Output = E0;
+	fn dot(self, rhs: E12) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E12> for E012 {
+	type Output = E0;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E012 {
+	type Output = Zero;
+	fn dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E13> for E012 {
+	type Output = Zero;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E012 {
+	type Output = Zero;
+	fn dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E23> for E012 {
+	type Output = Zero;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E012 {
+	type Output = S;
+	fn dot(self, rhs: E012) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E012> for E012 {
+	type Output = S;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E012 {
+	type Output = Zero;
+	fn dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E013> for E012 {
+	type Output = Zero;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E012 {
+	type Output = Zero;
+	fn dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E023> for E012 {
+	type Output = Zero;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E012 {
+	type Output = Zero;
+	fn dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E123> for E012 {
+	type Output = Zero;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E012 {
+	type Output = E3;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E012 {
+	type Output = E3;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E013 {
+	type Output = E013;
+	fn dot(self, rhs: S) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E013 {
+	type Output = E013;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E013 {
+	type Output = E13;
+	fn dot(self, rhs: E0) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0> for E013 {
+	type Output = E13;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E013 {
+	type Output = E03;
+	fn dot(self, rhs: E1) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E1> for E013 {
+	type Output = E03;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E013 {
+	type Output = Zero;
+	fn dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E2> for E013 {
+	type Output = Zero;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E013 {
+	type Output = E01;
+	fn dot(self, rhs: E3) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E3> for E013 {
+	type Output = E01;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E013 {
+	type Output = E3;
+	fn dot(self, rhs: E01) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E01> for E013 {
+	type Output = E3;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E013 {
+	type Output = Zero;
+	fn dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E02> for E013 {
+	type Output = Zero;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E013 {
+	type Output = E1;
+	fn dot(self, rhs: E03) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E03> for E013 {
+	type Output = E1;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E013 {
+	type Output = Zero;
+	fn dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E12> for E013 {
+	type Output = Zero;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E013 {
+	type Output = E0;
+	fn dot(self, rhs: E13) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E13> for E013 {
+	type Output = E0;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E013 {
+	type Output = Zero;
+	fn dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E23> for E013 {
+	type Output = Zero;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E013 {
+	type Output = Zero;
+	fn dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E012> for E013 {
+	type Output = Zero;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E013 {
+	type Output = S;
+	fn dot(self, rhs: E013) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E013> for E013 {
+	type Output = S;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E013 {
+	type Output = Zero;
+	fn dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E023> for E013 {
+	type Output = Zero;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E013 {
+	type Output = Zero;
+	fn dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E123> for E013 {
+	type Output = Zero;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E013 {
+	type Output = E2;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E013 {
+	type Output = E2;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E023 {
+	type Output = E023;
+	fn dot(self, rhs: S) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E023 {
+	type Output = E023;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E023 {
+	type Output = E23;
+	fn dot(self, rhs: E0) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0> for E023 {
+	type Output = E23;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E023 {
+	type Output = Zero;
+	fn dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E1> for E023 {
+	type Output = Zero;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E023 {
+	type Output = E03;
+	fn dot(self, rhs: E2) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E2> for E023 {
+	type Output = E03;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E023 {
+	type Output = E02;
+	fn dot(self, rhs: E3) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E3> for E023 {
+	type Output = E02;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E023 {
+	type Output = Zero;
+	fn dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E01> for E023 {
+	type Output = Zero;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E023 {
+	type Output = E3;
+	fn dot(self, rhs: E02) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E02> for E023 {
+	type Output = E3;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E023 {
+	type Output = E2;
+	fn dot(self, rhs: E03) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E03> for E023 {
+	type Output = E2;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E023 {
+	type Output = Zero;
+	fn dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E12> for E023 {
+	type Output = Zero;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E023 {
+	type Output = Zero;
+	fn dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E13> for E023 {
+	type Output = Zero;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E023 {
+	type Output = E0;
+	fn dot(self, rhs: E23) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E23> for E023 {
+	type Output = E0;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E023 {
+	type Output = Zero;
+	fn dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E012> for E023 {
+	type Output = Zero;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E023 {
+	type Output = Zero;
+	fn dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E013> for E023 {
+	type Output = Zero;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E023 {
+	type Output = S;
+	fn dot(self, rhs: E023) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E023> for E023 {
+	type Output = S;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E023 {
+	type Output = Zero;
+	fn dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E123> for E023 {
+	type Output = Zero;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E023 {
+	type Output = E1;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E023 {
+	type Output = E1;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E123 {
+	type Output = E123;
+	fn dot(self, rhs: S) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E123 {
+	type Output = E123;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E123 {
+	type Output = Zero;
+	fn dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E0> for E123 {
+	type Output = Zero;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E123 {
+	type Output = E23;
+	fn dot(self, rhs: E1) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E1> for E123 {
+	type Output = E23;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E123 {
+	type Output = E13;
+	fn dot(self, rhs: E2) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E2> for E123 {
+	type Output = E13;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E123 {
+	type Output = E12;
+	fn dot(self, rhs: E3) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E3> for E123 {
+	type Output = E12;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E123 {
+	type Output = Zero;
+	fn dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E01> for E123 {
+	type Output = Zero;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E123 {
+	type Output = Zero;
+	fn dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E02> for E123 {
+	type Output = Zero;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E123 {
+	type Output = Zero;
+	fn dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E03> for E123 {
+	type Output = Zero;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E123 {
+	type Output = E3;
+	fn dot(self, rhs: E12) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E12> for E123 {
+	type Output = E3;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E123 {
+	type Output = E2;
+	fn dot(self, rhs: E13) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E13> for E123 {
+	type Output = E2;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E123 {
+	type Output = E1;
+	fn dot(self, rhs: E23) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E23> for E123 {
+	type Output = E1;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E123 {
+	type Output = Zero;
+	fn dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E012> for E123 {
+	type Output = Zero;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E123 {
+	type Output = Zero;
+	fn dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E013> for E123 {
+	type Output = Zero;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E123 {
+	type Output = Zero;
+	fn dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<E023> for E123 {
+	type Output = Zero;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E123 {
+	type Output = S;
+	fn dot(self, rhs: E123) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E123> for E123 {
+	type Output = S;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E123 {
+	type Output = E0;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E123 {
+	type Output = E0;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for E0123 {
+	type Output = E0123;
+	fn dot(self, rhs: S) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for E0123 {
+	type Output = E0123;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0> for E0123 {
+	type Output = E123;
+	fn dot(self, rhs: E0) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0> for E0123 {
+	type Output = E123;
+	fn bitor(self, rhs: E0) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E1> for E0123 {
+	type Output = E023;
+	fn dot(self, rhs: E1) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E1> for E0123 {
+	type Output = E023;
+	fn bitor(self, rhs: E1) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E2> for E0123 {
+	type Output = E013;
+	fn dot(self, rhs: E2) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E2> for E0123 {
+	type Output = E013;
+	fn bitor(self, rhs: E2) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E3> for E0123 {
+	type Output = E012;
+	fn dot(self, rhs: E3) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E3> for E0123 {
+	type Output = E012;
+	fn bitor(self, rhs: E3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E01> for E0123 {
+	type Output = E23;
+	fn dot(self, rhs: E01) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E01> for E0123 {
+	type Output = E23;
+	fn bitor(self, rhs: E01) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E02> for E0123 {
+	type Output = E13;
+	fn dot(self, rhs: E02) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E02> for E0123 {
+	type Output = E13;
+	fn bitor(self, rhs: E02) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E03> for E0123 {
+	type Output = E12;
+	fn dot(self, rhs: E03) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E03> for E0123 {
+	type Output = E12;
+	fn bitor(self, rhs: E03) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E12> for E0123 {
+	type Output = E03;
+	fn dot(self, rhs: E12) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E12> for E0123 {
+	type Output = E03;
+	fn bitor(self, rhs: E12) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E13> for E0123 {
+	type Output = E02;
+	fn dot(self, rhs: E13) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E13> for E0123 {
+	type Output = E02;
+	fn bitor(self, rhs: E13) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E23> for E0123 {
+	type Output = E01;
+	fn dot(self, rhs: E23) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E23> for E0123 {
+	type Output = E01;
+	fn bitor(self, rhs: E23) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E012> for E0123 {
+	type Output = E3;
+	fn dot(self, rhs: E012) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E012> for E0123 {
+	type Output = E3;
+	fn bitor(self, rhs: E012) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E013> for E0123 {
+	type Output = E2;
+	fn dot(self, rhs: E013) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E013> for E0123 {
+	type Output = E2;
+	fn bitor(self, rhs: E013) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E023> for E0123 {
+	type Output = E1;
+	fn dot(self, rhs: E023) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E023> for E0123 {
+	type Output = E1;
+	fn bitor(self, rhs: E023) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E123> for E0123 {
+	type Output = E0;
+	fn dot(self, rhs: E123) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E123> for E0123 {
+	type Output = E0;
+	fn bitor(self, rhs: E123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<E0123> for E0123 {
+	type Output = S;
+	fn dot(self, rhs: E0123) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<E0123> for E0123 {
+	type Output = S;
+	fn bitor(self, rhs: E0123) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Wedge for blades:
+
+impl Wedge<S> for S {
+	type Output = S;
+	fn wedge(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for S {
+	type Output = S;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for S {
+	type Output = E0;
+	fn wedge(self, rhs: E0) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E0> for S {
+	type Output = E0;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for S {
+	type Output = E1;
+	fn wedge(self, rhs: E1) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E1> for S {
+	type Output = E1;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for S {
+	type Output = E2;
+	fn wedge(self, rhs: E2) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E2> for S {
+	type Output = E2;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for S {
+	type Output = E3;
+	fn wedge(self, rhs: E3) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E3> for S {
+	type Output = E3;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for S {
+	type Output = E01;
+	fn wedge(self, rhs: E01) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E01> for S {
+	type Output = E01;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for S {
+	type Output = E02;
+	fn wedge(self, rhs: E02) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E02> for S {
+	type Output = E02;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for S {
+	type Output = E03;
+	fn wedge(self, rhs: E03) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E03> for S {
+	type Output = E03;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for S {
+	type Output = E12;
+	fn wedge(self, rhs: E12) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E12> for S {
+	type Output = E12;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for S {
+	type Output = E13;
+	fn wedge(self, rhs: E13) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E13> for S {
+	type Output = E13;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for S {
+	type Output = E23;
+	fn wedge(self, rhs: E23) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E23> for S {
+	type Output = E23;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for S {
+	type Output = E012;
+	fn wedge(self, rhs: E012) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E012> for S {
+	type Output = E012;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for S {
+	type Output = E013;
+	fn wedge(self, rhs: E013) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E013> for S {
+	type Output = E013;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for S {
+	type Output = E023;
+	fn wedge(self, rhs: E023) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E023> for S {
+	type Output = E023;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for S {
+	type Output = E123;
+	fn wedge(self, rhs: E123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E123> for S {
+	type Output = E123;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for S {
+	type Output = E0123;
+	fn wedge(self, rhs: E0123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E0123> for S {
+	type Output = E0123;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E0 {
+	type Output = E0;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E0 {
+	type Output = E0;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E0 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0> for E0 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E0 {
+	type Output = E01;
+	fn wedge(self, rhs: E1) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E1> for E0 {
+	type Output = E01;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E0 {
+	type Output = E02;
+	fn wedge(self, rhs: E2) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E2> for E0 {
+	type Output = E02;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E0 {
+	type Output = E03;
+	fn wedge(self, rhs: E3) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E3> for E0 {
+	type Output = E03;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E0 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E0 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E0 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E0 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E0 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E0 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E0 {
+	type Output = E012;
+	fn wedge(self, rhs: E12) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E12> for E0 {
+	type Output = E012;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E0 {
+	type Output = E013;
+	fn wedge(self, rhs: E13) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E13> for E0 {
+	type Output = E013;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E0 {
+	type Output = E023;
+	fn wedge(self, rhs: E23) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E23> for E0 {
+	type Output = E023;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E0 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E0 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E0 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E0 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E0 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E0 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E0 {
+	type Output = E0123;
+	fn wedge(self, rhs: E123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E123> for E0 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E0 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E0 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E1 {
+	type Output = E1;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E1 {
+	type Output = E1;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E1 {
+	type Output = E01;
+	fn wedge(self, rhs: E0) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E0> for E1 {
+	type Output = E01;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E1 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E1> for E1 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E1 {
+	type Output = E12;
+	fn wedge(self, rhs: E2) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E2> for E1 {
+	type Output = E12;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E1 {
+	type Output = E13;
+	fn wedge(self, rhs: E3) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E3> for E1 {
+	type Output = E13;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E1 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E1 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E1 {
+	type Output = E012;
+	fn wedge(self, rhs: E02) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E02> for E1 {
+	type Output = E012;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E1 {
+	type Output = E013;
+	fn wedge(self, rhs: E03) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E03> for E1 {
+	type Output = E013;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E1 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E1 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E1 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E1 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E1 {
+	type Output = E123;
+	fn wedge(self, rhs: E23) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E23> for E1 {
+	type Output = E123;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E1 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E1 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E1 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E1 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E1 {
+	type Output = E0123;
+	fn wedge(self, rhs: E023) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E023> for E1 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E1 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E1 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E1 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E1 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E2 {
+	type Output = E2;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E2 {
+	type Output = E2;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E2 {
+	type Output = E02;
+	fn wedge(self, rhs: E0) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E0> for E2 {
+	type Output = E02;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E2 {
+	type Output = E12;
+	fn wedge(self, rhs: E1) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E1> for E2 {
+	type Output = E12;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E2 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E2> for E2 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E2 {
+	type Output = E23;
+	fn wedge(self, rhs: E3) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E3> for E2 {
+	type Output = E23;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E2 {
+	type Output = E012;
+	fn wedge(self, rhs: E01) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E01> for E2 {
+	type Output = E012;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E2 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E2 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E2 {
+	type Output = E023;
+	fn wedge(self, rhs: E03) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E03> for E2 {
+	type Output = E023;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E2 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E2 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E2 {
+	type Output = E123;
+	fn wedge(self, rhs: E13) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E13> for E2 {
+	type Output = E123;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E2 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E2 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E2 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E2 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E2 {
+	type Output = E0123;
+	fn wedge(self, rhs: E013) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E013> for E2 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E2 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E2 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E2 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E2 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E2 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E2 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E3 {
+	type Output = E3;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E3 {
+	type Output = E3;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E3 {
+	type Output = E03;
+	fn wedge(self, rhs: E0) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E0> for E3 {
+	type Output = E03;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E3 {
+	type Output = E13;
+	fn wedge(self, rhs: E1) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E1> for E3 {
+	type Output = E13;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E3 {
+	type Output = E23;
+	fn wedge(self, rhs: E2) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E2> for E3 {
+	type Output = E23;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E3> for E3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E3 {
+	type Output = E013;
+	fn wedge(self, rhs: E01) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E01> for E3 {
+	type Output = E013;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E3 {
+	type Output = E023;
+	fn wedge(self, rhs: E02) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E02> for E3 {
+	type Output = E023;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E3 {
+	type Output = E123;
+	fn wedge(self, rhs: E12) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E12> for E3 {
+	type Output = E123;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E3 {
+	type Output = E0123;
+	fn wedge(self, rhs: E012) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E012> for E3 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E01 {
+	type Output = E01;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E01 {
+	type Output = E01;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E1> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E01 {
+	type Output = E012;
+	fn wedge(self, rhs: E2) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E2> for E01 {
+	type Output = E012;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E01 {
+	type Output = E013;
+	fn wedge(self, rhs: E3) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E3> for E01 {
+	type Output = E013;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E01 {
+	type Output = E0123;
+	fn wedge(self, rhs: E23) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E23> for E01 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E01 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E01 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E02 {
+	type Output = E02;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E02 {
+	type Output = E02;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E02 {
+	type Output = E012;
+	fn wedge(self, rhs: E1) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E1> for E02 {
+	type Output = E012;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E2> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E02 {
+	type Output = E023;
+	fn wedge(self, rhs: E3) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E3> for E02 {
+	type Output = E023;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E02 {
+	type Output = E0123;
+	fn wedge(self, rhs: E13) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E13> for E02 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E02 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E02 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E03 {
+	type Output = E03;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E03 {
+	type Output = E03;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E03 {
+	type Output = E013;
+	fn wedge(self, rhs: E1) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E1> for E03 {
+	type Output = E013;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E03 {
+	type Output = E023;
+	fn wedge(self, rhs: E2) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E2> for E03 {
+	type Output = E023;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E3> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E03 {
+	type Output = E0123;
+	fn wedge(self, rhs: E12) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E12> for E03 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E03 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E03 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E12 {
+	type Output = E12;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E12 {
+	type Output = E12;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E12 {
+	type Output = E012;
+	fn wedge(self, rhs: E0) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E0> for E12 {
+	type Output = E012;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E1> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E2> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E12 {
+	type Output = E123;
+	fn wedge(self, rhs: E3) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E3> for E12 {
+	type Output = E123;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E12 {
+	type Output = E0123;
+	fn wedge(self, rhs: E03) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E03> for E12 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E12 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E12 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E13 {
+	type Output = E13;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E13 {
+	type Output = E13;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E13 {
+	type Output = E013;
+	fn wedge(self, rhs: E0) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E0> for E13 {
+	type Output = E013;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E1> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E13 {
+	type Output = E123;
+	fn wedge(self, rhs: E2) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E2> for E13 {
+	type Output = E123;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E3> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E13 {
+	type Output = E0123;
+	fn wedge(self, rhs: E02) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E02> for E13 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E13 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E13 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E23 {
+	type Output = E23;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E23 {
+	type Output = E23;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E23 {
+	type Output = E023;
+	fn wedge(self, rhs: E0) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E0> for E23 {
+	type Output = E023;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E23 {
+	type Output = E123;
+	fn wedge(self, rhs: E1) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E1> for E23 {
+	type Output = E123;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E2> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E3> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E23 {
+	type Output = E0123;
+	fn wedge(self, rhs: E01) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E01> for E23 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E23 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E23 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E012 {
+	type Output = E012;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E012 {
+	type Output = E012;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E1> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E2> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E012 {
+	type Output = E0123;
+	fn wedge(self, rhs: E3) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E3> for E012 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E012 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E012 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E013 {
+	type Output = E013;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E013 {
+	type Output = E013;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E1> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E013 {
+	type Output = E0123;
+	fn wedge(self, rhs: E2) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E2> for E013 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E3> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E013 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E013 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E023 {
+	type Output = E023;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E023 {
+	type Output = E023;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E023 {
+	type Output = E0123;
+	fn wedge(self, rhs: E1) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E1> for E023 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E2> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E3> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E023 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E023 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E123 {
+	type Output = E123;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E123 {
+	type Output = E123;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E123 {
+	type Output = E0123;
+	fn wedge(self, rhs: E0) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<E0> for E123 {
+	type Output = E0123;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E1> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E2> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E3> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for E0123 {
+	type Output = E0123;
+	fn wedge(self, rhs: S) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for E0123 {
+	type Output = E0123;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E1> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E1> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E1) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E2> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E2> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E2) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E3> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E3> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E01> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E01> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E01) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E02> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E02> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E02) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E03> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E03> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E03) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E12> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E12> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E12) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E13> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E13> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E13) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E23> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E23> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E23) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E012> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E012> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E012) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E013> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E013> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E013) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E023> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E023> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E023) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E123> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E123> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<E0123> for E0123 {
+	type Output = Zero;
+	fn wedge(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<E0123> for E0123 {
+	type Output = Zero;
+	fn bitxor(self, rhs: E0123) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiWedge for blades:
+
+impl AntiWedge<S> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E012> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E013> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E023> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E123> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for S {
+	type Output = S;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for S {
+	type Output = S;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E012> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E013> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E0 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E023> for E0 {
+	type Output = Zero;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E0 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E123) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E123> for E0 {
+	type Output = S;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E0 {
+	type Output = E0;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E0 {
+	type Output = E0;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E012> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E013> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E1 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E023) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E023> for E1 {
+	type Output = S;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E1 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E123> for E1 {
+	type Output = Zero;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E1 {
+	type Output = E1;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E1 {
+	type Output = E1;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E012> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E2 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E013) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E013> for E2 {
+	type Output = S;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E023> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E2 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E123> for E2 {
+	type Output = Zero;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E2 {
+	type Output = E2;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E2 {
+	type Output = E2;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E012) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E012> for E3 {
+	type Output = S;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E013> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E023> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E123> for E3 {
+	type Output = Zero;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E3 {
+	type Output = E3;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E3 {
+	type Output = E3;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E01 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E23) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E23> for E01 {
+	type Output = S;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E012> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E01 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E013> for E01 {
+	type Output = Zero;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E01 {
+	type Output = E0;
+	fn anti_wedge(self, rhs: E023) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E023> for E01 {
+	type Output = E0;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E01 {
+	type Output = E1;
+	fn anti_wedge(self, rhs: E123) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E123> for E01 {
+	type Output = E1;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E01 {
+	type Output = E01;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E01 {
+	type Output = E01;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E02 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E13) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E13> for E02 {
+	type Output = S;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E012> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E02 {
+	type Output = E0;
+	fn anti_wedge(self, rhs: E013) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E013> for E02 {
+	type Output = E0;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E02 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E023> for E02 {
+	type Output = Zero;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E02 {
+	type Output = E2;
+	fn anti_wedge(self, rhs: E123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E123> for E02 {
+	type Output = E2;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E02 {
+	type Output = E02;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E02 {
+	type Output = E02;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E03 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E12) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E12> for E03 {
+	type Output = S;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E03 {
+	type Output = E0;
+	fn anti_wedge(self, rhs: E012) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E012> for E03 {
+	type Output = E0;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E013> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E03 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E023> for E03 {
+	type Output = Zero;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E03 {
+	type Output = E3;
+	fn anti_wedge(self, rhs: E123) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E123> for E03 {
+	type Output = E3;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E03 {
+	type Output = E03;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E03 {
+	type Output = E03;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E12 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E03) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E03> for E12 {
+	type Output = S;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E012> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E12 {
+	type Output = E1;
+	fn anti_wedge(self, rhs: E013) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E013> for E12 {
+	type Output = E1;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E12 {
+	type Output = E2;
+	fn anti_wedge(self, rhs: E023) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E023> for E12 {
+	type Output = E2;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E12 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E123> for E12 {
+	type Output = Zero;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E12 {
+	type Output = E12;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E12 {
+	type Output = E12;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E13 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E02) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E02> for E13 {
+	type Output = S;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E13 {
+	type Output = E1;
+	fn anti_wedge(self, rhs: E012) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E012> for E13 {
+	type Output = E1;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E013> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E13 {
+	type Output = E3;
+	fn anti_wedge(self, rhs: E023) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E023> for E13 {
+	type Output = E3;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E13 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E123> for E13 {
+	type Output = Zero;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E13 {
+	type Output = E13;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E13 {
+	type Output = E13;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E23 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E01) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E01> for E23 {
+	type Output = S;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E23 {
+	type Output = E2;
+	fn anti_wedge(self, rhs: E012) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E012> for E23 {
+	type Output = E2;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E23 {
+	type Output = E3;
+	fn anti_wedge(self, rhs: E013) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E013> for E23 {
+	type Output = E3;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E023> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E23 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E123> for E23 {
+	type Output = Zero;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E23 {
+	type Output = E23;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E23 {
+	type Output = E23;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E012 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E012 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E012 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E012 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E012 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E012 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E012 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E012 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E012 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E3) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E3> for E012 {
+	type Output = S;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E012 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E012 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E012 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E012 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E012 {
+	type Output = E0;
+	fn anti_wedge(self, rhs: E03) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E03> for E012 {
+	type Output = E0;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E012 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E012 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E012 {
+	type Output = E1;
+	fn anti_wedge(self, rhs: E13) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E13> for E012 {
+	type Output = E1;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E012 {
+	type Output = E2;
+	fn anti_wedge(self, rhs: E23) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E23> for E012 {
+	type Output = E2;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E012 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E012> for E012 {
+	type Output = Zero;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E012 {
+	type Output = E01;
+	fn anti_wedge(self, rhs: E013) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E013> for E012 {
+	type Output = E01;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E012 {
+	type Output = E02;
+	fn anti_wedge(self, rhs: E023) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E023> for E012 {
+	type Output = E02;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E012 {
+	type Output = E12;
+	fn anti_wedge(self, rhs: E123) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E123> for E012 {
+	type Output = E12;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E012 {
+	type Output = E012;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E012 {
+	type Output = E012;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E013 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E013 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E013 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E013 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E013 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E013 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E013 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E2) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E2> for E013 {
+	type Output = S;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E013 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E013 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E013 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E01> for E013 {
+	type Output = Zero;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E013 {
+	type Output = E0;
+	fn anti_wedge(self, rhs: E02) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E02> for E013 {
+	type Output = E0;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E013 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E013 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E013 {
+	type Output = E1;
+	fn anti_wedge(self, rhs: E12) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E12> for E013 {
+	type Output = E1;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E013 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E013 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E013 {
+	type Output = E3;
+	fn anti_wedge(self, rhs: E23) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E23> for E013 {
+	type Output = E3;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E013 {
+	type Output = E01;
+	fn anti_wedge(self, rhs: E012) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E012> for E013 {
+	type Output = E01;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E013 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E013> for E013 {
+	type Output = Zero;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E013 {
+	type Output = E03;
+	fn anti_wedge(self, rhs: E023) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E023> for E013 {
+	type Output = E03;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E013 {
+	type Output = E13;
+	fn anti_wedge(self, rhs: E123) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E123> for E013 {
+	type Output = E13;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E013 {
+	type Output = E013;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E013 {
+	type Output = E013;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E023 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E023 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E023 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E0> for E023 {
+	type Output = Zero;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E023 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E1) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E1> for E023 {
+	type Output = S;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E023 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E023 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E023 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E023 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E023 {
+	type Output = E0;
+	fn anti_wedge(self, rhs: E01) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E01> for E023 {
+	type Output = E0;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E023 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E02> for E023 {
+	type Output = Zero;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E023 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E03> for E023 {
+	type Output = Zero;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E023 {
+	type Output = E2;
+	fn anti_wedge(self, rhs: E12) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E12> for E023 {
+	type Output = E2;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E023 {
+	type Output = E3;
+	fn anti_wedge(self, rhs: E13) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E13> for E023 {
+	type Output = E3;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E023 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E023 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E023 {
+	type Output = E02;
+	fn anti_wedge(self, rhs: E012) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E012> for E023 {
+	type Output = E02;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E023 {
+	type Output = E03;
+	fn anti_wedge(self, rhs: E013) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E013> for E023 {
+	type Output = E03;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E023 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E023> for E023 {
+	type Output = Zero;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E023 {
+	type Output = E23;
+	fn anti_wedge(self, rhs: E123) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E123> for E023 {
+	type Output = E23;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E023 {
+	type Output = E023;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E023 {
+	type Output = E023;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E123 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for E123 {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E123 {
+	type Output = S;
+	fn anti_wedge(self, rhs: E0) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0> for E123 {
+	type Output = S;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E123 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E1> for E123 {
+	type Output = Zero;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E123 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E2> for E123 {
+	type Output = Zero;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E123 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E3> for E123 {
+	type Output = Zero;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E123 {
+	type Output = E1;
+	fn anti_wedge(self, rhs: E01) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E01> for E123 {
+	type Output = E1;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E123 {
+	type Output = E2;
+	fn anti_wedge(self, rhs: E02) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E02> for E123 {
+	type Output = E2;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E123 {
+	type Output = E3;
+	fn anti_wedge(self, rhs: E03) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E03> for E123 {
+	type Output = E3;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E123 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E12> for E123 {
+	type Output = Zero;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E123 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E13> for E123 {
+	type Output = Zero;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E123 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E23> for E123 {
+	type Output = Zero;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E123 {
+	type Output = E12;
+	fn anti_wedge(self, rhs: E012) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E012> for E123 {
+	type Output = E12;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E123 {
+	type Output = E13;
+	fn anti_wedge(self, rhs: E013) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E013> for E123 {
+	type Output = E13;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E123 {
+	type Output = E23;
+	fn anti_wedge(self, rhs: E023) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E023> for E123 {
+	type Output = E23;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E123 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<E123> for E123 {
+	type Output = Zero;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E123 {
+	type Output = E123;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E123 {
+	type Output = E123;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for E0123 {
+	type Output = S;
+	fn anti_wedge(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<S> for E0123 {
+	type Output = S;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0> for E0123 {
+	type Output = E0;
+	fn anti_wedge(self, rhs: E0) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0> for E0123 {
+	type Output = E0;
+	fn bitand(self, rhs: E0) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E1> for E0123 {
+	type Output = E1;
+	fn anti_wedge(self, rhs: E1) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E1> for E0123 {
+	type Output = E1;
+	fn bitand(self, rhs: E1) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E2> for E0123 {
+	type Output = E2;
+	fn anti_wedge(self, rhs: E2) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E2> for E0123 {
+	type Output = E2;
+	fn bitand(self, rhs: E2) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E3> for E0123 {
+	type Output = E3;
+	fn anti_wedge(self, rhs: E3) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E3> for E0123 {
+	type Output = E3;
+	fn bitand(self, rhs: E3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E01> for E0123 {
+	type Output = E01;
+	fn anti_wedge(self, rhs: E01) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E01> for E0123 {
+	type Output = E01;
+	fn bitand(self, rhs: E01) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E02> for E0123 {
+	type Output = E02;
+	fn anti_wedge(self, rhs: E02) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E02> for E0123 {
+	type Output = E02;
+	fn bitand(self, rhs: E02) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E03> for E0123 {
+	type Output = E03;
+	fn anti_wedge(self, rhs: E03) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E03> for E0123 {
+	type Output = E03;
+	fn bitand(self, rhs: E03) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E12> for E0123 {
+	type Output = E12;
+	fn anti_wedge(self, rhs: E12) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E12> for E0123 {
+	type Output = E12;
+	fn bitand(self, rhs: E12) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E13> for E0123 {
+	type Output = E13;
+	fn anti_wedge(self, rhs: E13) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E13> for E0123 {
+	type Output = E13;
+	fn bitand(self, rhs: E13) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E23> for E0123 {
+	type Output = E23;
+	fn anti_wedge(self, rhs: E23) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E23> for E0123 {
+	type Output = E23;
+	fn bitand(self, rhs: E23) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E012> for E0123 {
+	type Output = E012;
+	fn anti_wedge(self, rhs: E012) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E012> for E0123 {
+	type Output = E012;
+	fn bitand(self, rhs: E012) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E013> for E0123 {
+	type Output = E013;
+	fn anti_wedge(self, rhs: E013) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E013> for E0123 {
+	type Output = E013;
+	fn bitand(self, rhs: E013) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E023> for E0123 {
+	type Output = E023;
+	fn anti_wedge(self, rhs: E023) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E023> for E0123 {
+	type Output = E023;
+	fn bitand(self, rhs: E023) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E123> for E0123 {
+	type Output = E123;
+	fn anti_wedge(self, rhs: E123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E123> for E0123 {
+	type Output = E123;
+	fn bitand(self, rhs: E123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<E0123> for E0123 {
+	type Output = E0123;
+	fn anti_wedge(self, rhs: E0123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<E0123> for E0123 {
+	type Output = E0123;
+	fn bitand(self, rhs: E0123) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl LeftContraction for blades:
+
+impl LeftContraction<S> for S {
+	type Output = S;
+	fn left_contraction(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E0> for S {
+	type Output = E0;
+	fn left_contraction(self, rhs: E0) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E1> for S {
+	type Output = E1;
+	fn left_contraction(self, rhs: E1) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E2> for S {
+	type Output = E2;
+	fn left_contraction(self, rhs: E2) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E3> for S {
+	type Output = E3;
+	fn left_contraction(self, rhs: E3) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E01> for S {
+	type Output = E01;
+	fn left_contraction(self, rhs: E01) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E02> for S {
+	type Output = E02;
+	fn left_contraction(self, rhs: E02) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E03> for S {
+	type Output = E03;
+	fn left_contraction(self, rhs: E03) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E12> for S {
+	type Output = E12;
+	fn left_contraction(self, rhs: E12) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E13> for S {
+	type Output = E13;
+	fn left_contraction(self, rhs: E13) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E23> for S {
+	type Output = E23;
+	fn left_contraction(self, rhs: E23) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E012> for S {
+	type Output = E012;
+	fn left_contraction(self, rhs: E012) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E013> for S {
+	type Output = E013;
+	fn left_contraction(self, rhs: E013) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E023> for S {
+	type Output = E023;
+	fn left_contraction(self, rhs: E023) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E123> for S {
+	type Output = E123;
+	fn left_contraction(self, rhs: E123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E0123> for S {
+	type Output = E0123;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E0 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E0 {
+	type Output = S;
+	fn left_contraction(self, rhs: E0) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E1> for E0 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E0 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E0 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E0 {
+	type Output = E1;
+	fn left_contraction(self, rhs: E01) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E02> for E0 {
+	type Output = E2;
+	fn left_contraction(self, rhs: E02) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E03> for E0 {
+	type Output = E3;
+	fn left_contraction(self, rhs: E03) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E12> for E0 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E0 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E0 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E0 {
+	type Output = E12;
+	fn left_contraction(self, rhs: E012) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E013> for E0 {
+	type Output = E13;
+	fn left_contraction(self, rhs: E013) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E023> for E0 {
+	type Output = E23;
+	fn left_contraction(self, rhs: E023) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E123> for E0 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0123> for E0 {
+	type Output = E123;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E1 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E1 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E1 {
+	type Output = S;
+	fn left_contraction(self, rhs: E1) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E2> for E1 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E1 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E1 {
+	type Output = E0;
+	fn left_contraction(self, rhs: E01) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E02> for E1 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E1 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E1 {
+	type Output = E2;
+	fn left_contraction(self, rhs: E12) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E13> for E1 {
+	type Output = E3;
+	fn left_contraction(self, rhs: E13) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E23> for E1 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E1 {
+	type Output = E02;
+	fn left_contraction(self, rhs: E012) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E013> for E1 {
+	type Output = E03;
+	fn left_contraction(self, rhs: E013) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E023> for E1 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E123> for E1 {
+	type Output = E23;
+	fn left_contraction(self, rhs: E123) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E0123> for E1 {
+	type Output = E023;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E2 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E2 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E2 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E2 {
+	type Output = S;
+	fn left_contraction(self, rhs: E2) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E3> for E2 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E2 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E2 {
+	type Output = E0;
+	fn left_contraction(self, rhs: E02) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E03> for E2 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E2 {
+	type Output = E1;
+	fn left_contraction(self, rhs: E12) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E13> for E2 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E2 {
+	type Output = E3;
+	fn left_contraction(self, rhs: E23) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E012> for E2 {
+	type Output = E01;
+	fn left_contraction(self, rhs: E012) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E013> for E2 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E023> for E2 {
+	type Output = E03;
+	fn left_contraction(self, rhs: E023) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E123> for E2 {
+	type Output = E13;
+	fn left_contraction(self, rhs: E123) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E0123> for E2 {
+	type Output = E013;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E3 {
+	type Output = S;
+	fn left_contraction(self, rhs: E3) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E01> for E3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E3 {
+	type Output = E0;
+	fn left_contraction(self, rhs: E03) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E12> for E3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E3 {
+	type Output = E1;
+	fn left_contraction(self, rhs: E13) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E23> for E3 {
+	type Output = E2;
+	fn left_contraction(self, rhs: E23) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E012> for E3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E013> for E3 {
+	type Output = E01;
+	fn left_contraction(self, rhs: E013) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E023> for E3 {
+	type Output = E02;
+	fn left_contraction(self, rhs: E023) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E123> for E3 {
+	type Output = E12;
+	fn left_contraction(self, rhs: E123) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E0123> for E3 {
+	type Output = E012;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E01 {
+	type Output = S;
+	fn left_contraction(self, rhs: E01) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E02> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E01 {
+	type Output = E2;
+	fn left_contraction(self, rhs: E012) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E013> for E01 {
+	type Output = E3;
+	fn left_contraction(self, rhs: E013) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E023> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E123> for E01 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0123> for E01 {
+	type Output = E23;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E02 {
+	type Output = S;
+	fn left_contraction(self, rhs: E02) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E03> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E02 {
+	type Output = E1;
+	fn left_contraction(self, rhs: E012) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E013> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E023> for E02 {
+	type Output = E3;
+	fn left_contraction(self, rhs: E023) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E123> for E02 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0123> for E02 {
+	type Output = E13;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E03 {
+	type Output = S;
+	fn left_contraction(self, rhs: E03) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E12> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E013> for E03 {
+	type Output = E1;
+	fn left_contraction(self, rhs: E013) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E023> for E03 {
+	type Output = E2;
+	fn left_contraction(self, rhs: E023) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E123> for E03 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0123> for E03 {
+	type Output = E12;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E12 {
+	type Output = S;
+	fn left_contraction(self, rhs: E12) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E13> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E12 {
+	type Output = E0;
+	fn left_contraction(self, rhs: E012) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E013> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E023> for E12 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E123> for E12 {
+	type Output = E3;
+	fn left_contraction(self, rhs: E123) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E0123> for E12 {
+	type Output = E03;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E13 {
+	type Output = S;
+	fn left_contraction(self, rhs: E13) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E23> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E013> for E13 {
+	type Output = E0;
+	fn left_contraction(self, rhs: E013) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E023> for E13 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E123> for E13 {
+	type Output = E2;
+	fn left_contraction(self, rhs: E123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E0123> for E13 {
+	type Output = E02;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E23 {
+	type Output = S;
+	fn left_contraction(self, rhs: E23) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E012> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E013> for E23 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E023> for E23 {
+	type Output = E0;
+	fn left_contraction(self, rhs: E023) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E123> for E23 {
+	type Output = E1;
+	fn left_contraction(self, rhs: E123) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E0123> for E23 {
+	type Output = E01;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E012 {
+	type Output = S;
+	fn left_contraction(self, rhs: E012) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E013> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E023> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E123> for E012 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0123> for E012 {
+	type Output = E3;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E013> for E013 {
+	type Output = S;
+	fn left_contraction(self, rhs: E013) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E023> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E123> for E013 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0123> for E013 {
+	type Output = E2;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E013> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E023> for E023 {
+	type Output = S;
+	fn left_contraction(self, rhs: E023) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E123> for E023 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0123> for E023 {
+	type Output = E1;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E013> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E023> for E123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E123> for E123 {
+	type Output = S;
+	fn left_contraction(self, rhs: E123) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<E0123> for E123 {
+	type Output = E0;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E1> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E2> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E3> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E01> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E02> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E03> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E12> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E13> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E23> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E012> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E013> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E023> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E123> for E0123 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<E0123> for E0123 {
+	type Output = S;
+	fn left_contraction(self, rhs: E0123) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl RightContraction for blades:
+
+impl RightContraction<S> for S {
+	type Output = S;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E1> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E2> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E3> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E01> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E0 {
+	type Output = E0;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E0 {
+	type Output = S;
+	fn right_contraction(self, rhs: E0) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E1> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E2> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E3> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E01> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E0 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E1 {
+	type Output = E1;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E1> for E1 {
+	type Output = S;
+	fn right_contraction(self, rhs: E1) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E2> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E3> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E01> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E1 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E2 {
+	type Output = E2;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E1> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E2> for E2 {
+	type Output = S;
+	fn right_contraction(self, rhs: E2) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E3> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E01> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E2 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E3 {
+	type Output = E3;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E1> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E2> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E3> for E3 {
+	type Output = S;
+	fn right_contraction(self, rhs: E3) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E01> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E01 {
+	type Output = E01;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E01 {
+	type Output = E1;
+	fn right_contraction(self, rhs: E0) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E1> for E01 {
+	type Output = E0;
+	fn right_contraction(self, rhs: E1) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E2> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E3> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E01> for E01 {
+	type Output = S;
+	fn right_contraction(self, rhs: E01) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E02> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E01 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E02 {
+	type Output = E02;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E02 {
+	type Output = E2;
+	fn right_contraction(self, rhs: E0) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E1> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E2> for E02 {
+	type Output = E0;
+	fn right_contraction(self, rhs: E2) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E3> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E01> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E02 {
+	type Output = S;
+	fn right_contraction(self, rhs: E02) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E03> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E02 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E03 {
+	type Output = E03;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E03 {
+	type Output = E3;
+	fn right_contraction(self, rhs: E0) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E1> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E2> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E3> for E03 {
+	type Output = E0;
+	fn right_contraction(self, rhs: E3) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E01> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E03 {
+	type Output = S;
+	fn right_contraction(self, rhs: E03) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E12> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E03 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E12 {
+	type Output = E12;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E1> for E12 {
+	type Output = E2;
+	fn right_contraction(self, rhs: E1) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E2> for E12 {
+	type Output = E1;
+	fn right_contraction(self, rhs: E2) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E3> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E01> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E12 {
+	type Output = S;
+	fn right_contraction(self, rhs: E12) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E13> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E12 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E13 {
+	type Output = E13;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E1> for E13 {
+	type Output = E3;
+	fn right_contraction(self, rhs: E1) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E2> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E3> for E13 {
+	type Output = E1;
+	fn right_contraction(self, rhs: E3) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E01> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E13 {
+	type Output = S;
+	fn right_contraction(self, rhs: E13) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E23> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E13 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E23 {
+	type Output = E23;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E1> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E2> for E23 {
+	type Output = E3;
+	fn right_contraction(self, rhs: E2) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E3> for E23 {
+	type Output = E2;
+	fn right_contraction(self, rhs: E3) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E01> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E23 {
+	type Output = S;
+	fn right_contraction(self, rhs: E23) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E012> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E23 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E012 {
+	type Output = E012;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E012 {
+	type Output = E12;
+	fn right_contraction(self, rhs: E0) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E1> for E012 {
+	type Output = E02;
+	fn right_contraction(self, rhs: E1) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E2> for E012 {
+	type Output = E01;
+	fn right_contraction(self, rhs: E2) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E3> for E012 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E01> for E012 {
+	type Output = E2;
+	fn right_contraction(self, rhs: E01) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E02> for E012 {
+	type Output = E1;
+	fn right_contraction(self, rhs: E02) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E03> for E012 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E012 {
+	type Output = E0;
+	fn right_contraction(self, rhs: E12) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E13> for E012 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E012 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E012 {
+	type Output = S;
+	fn right_contraction(self, rhs: E012) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E013> for E012 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E012 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E012 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E012 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E013 {
+	type Output = E013;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E013 {
+	type Output = E13;
+	fn right_contraction(self, rhs: E0) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E1> for E013 {
+	type Output = E03;
+	fn right_contraction(self, rhs: E1) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E2> for E013 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E3> for E013 {
+	type Output = E01;
+	fn right_contraction(self, rhs: E3) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E01> for E013 {
+	type Output = E3;
+	fn right_contraction(self, rhs: E01) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E02> for E013 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E013 {
+	type Output = E1;
+	fn right_contraction(self, rhs: E03) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E12> for E013 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E013 {
+	type Output = E0;
+	fn right_contraction(self, rhs: E13) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E23> for E013 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E012> for E013 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E013 {
+	type Output = S;
+	fn right_contraction(self, rhs: E013) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E023> for E013 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E013 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E013 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E023 {
+	type Output = E023;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E023 {
+	type Output = E23;
+	fn right_contraction(self, rhs: E0) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E1> for E023 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E2> for E023 {
+	type Output = E03;
+	fn right_contraction(self, rhs: E2) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E3> for E023 {
+	type Output = E02;
+	fn right_contraction(self, rhs: E3) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E01> for E023 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E023 {
+	type Output = E3;
+	fn right_contraction(self, rhs: E02) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E03> for E023 {
+	type Output = E2;
+	fn right_contraction(self, rhs: E03) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E12> for E023 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E13> for E023 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E23> for E023 {
+	type Output = E0;
+	fn right_contraction(self, rhs: E23) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E012> for E023 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E023 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E023 {
+	type Output = S;
+	fn right_contraction(self, rhs: E023) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E123> for E023 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E0123> for E023 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E123 {
+	type Output = E123;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E123 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E1> for E123 {
+	type Output = E23;
+	fn right_contraction(self, rhs: E1) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E2> for E123 {
+	type Output = E13;
+	fn right_contraction(self, rhs: E2) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E3> for E123 {
+	type Output = E12;
+	fn right_contraction(self, rhs: E3) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E01> for E123 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E02> for E123 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E03> for E123 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E12> for E123 {
+	type Output = E3;
+	fn right_contraction(self, rhs: E12) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E13> for E123 {
+	type Output = E2;
+	fn right_contraction(self, rhs: E13) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E23> for E123 {
+	type Output = E1;
+	fn right_contraction(self, rhs: E23) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E012> for E123 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E013> for E123 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E023> for E123 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<E123> for E123 {
+	type Output = S;
+	fn right_contraction(self, rhs: E123) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0123> for E123 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for E0123 {
+	type Output = E0123;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0> for E0123 {
+	type Output = E123;
+	fn right_contraction(self, rhs: E0) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E1> for E0123 {
+	type Output = E023;
+	fn right_contraction(self, rhs: E1) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E2> for E0123 {
+	type Output = E013;
+	fn right_contraction(self, rhs: E2) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E3> for E0123 {
+	type Output = E012;
+	fn right_contraction(self, rhs: E3) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E01> for E0123 {
+	type Output = E23;
+	fn right_contraction(self, rhs: E01) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E02> for E0123 {
+	type Output = E13;
+	fn right_contraction(self, rhs: E02) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E03> for E0123 {
+	type Output = E12;
+	fn right_contraction(self, rhs: E03) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E12> for E0123 {
+	type Output = E03;
+	fn right_contraction(self, rhs: E12) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E13> for E0123 {
+	type Output = E02;
+	fn right_contraction(self, rhs: E13) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E23> for E0123 {
+	type Output = E01;
+	fn right_contraction(self, rhs: E23) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E012> for E0123 {
+	type Output = E3;
+	fn right_contraction(self, rhs: E012) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E013> for E0123 {
+	type Output = E2;
+	fn right_contraction(self, rhs: E013) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E023> for E0123 {
+	type Output = E1;
+	fn right_contraction(self, rhs: E023) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E123> for E0123 {
+	type Output = E0;
+	fn right_contraction(self, rhs: E123) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<E0123> for E0123 {
+	type Output = S;
+	fn right_contraction(self, rhs: E0123) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl ScalarProduct for blades:
+
+impl ScalarProduct<S> for S {
+	type Output = S;
+	fn scalar_product(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E0> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E0 {
+	type Output = S;
+	fn scalar_product(self, rhs: E0) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E1> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E0 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E1 {
+	type Output = S;
+	fn scalar_product(self, rhs: E1) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E2> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E1 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E2 {
+	type Output = S;
+	fn scalar_product(self, rhs: E2) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E3> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E2 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E3 {
+	type Output = S;
+	fn scalar_product(self, rhs: E3) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E01> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E01 {
+	type Output = S;
+	fn scalar_product(self, rhs: E01) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E02> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E01 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E02 {
+	type Output = S;
+	fn scalar_product(self, rhs: E02) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E03> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E02 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E03 {
+	type Output = S;
+	fn scalar_product(self, rhs: E03) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E12> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E03 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E12 {
+	type Output = S;
+	fn scalar_product(self, rhs: E12) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E13> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E12 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E13 {
+	type Output = S;
+	fn scalar_product(self, rhs: E13) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E23> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E13 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E23 {
+	type Output = S;
+	fn scalar_product(self, rhs: E23) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E012> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E23 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E012 {
+	type Output = S;
+	fn scalar_product(self, rhs: E012) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E013> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E012 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E013 {
+	type Output = S;
+	fn scalar_product(self, rhs: E013) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E023> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E013 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E023 {
+	type Output = S;
+	fn scalar_product(self, rhs: E023) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E123> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E023 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E123 {
+	type Output = S;
+	fn scalar_product(self, rhs: E123) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<E0123> for E123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E1> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E2> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E3> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E01> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E02> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E03> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E12> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E13> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E23> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E012> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E013> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E023> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E123> for E0123 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<E0123> for E0123 {
+	type Output = S;
+	fn scalar_product(self, rhs: E0123) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Commutator for blades:
+
+impl Commutator<S> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E1> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E2> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E3> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E01> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E02> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E03> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E12> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E13> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E23> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E012> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E013> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E023> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E123> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0123> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for E0 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E0 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E1> for E0 {
+	type Output = E01;
+	fn commutator(self, rhs: E1) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E2> for E0 {
+	type Output = E02;
+	fn commutator(self, rhs: E2) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E3> for E0 {
+	type Output = E03;
+	fn commutator(self, rhs: E3) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E01> for E0 {
+	type Output = E1;
+	fn commutator(self, rhs: E01) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E02> for E0 {
+	type Output = E2;
+	fn commutator(self, rhs: E02) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E03> for E0 {
+	type Output = E3;
+	fn commutator(self, rhs: E03) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E12> for E0 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E13> for E0 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E23> for E0 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E012> for E0 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E013> for E0 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E023> for E0 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E123> for E0 {
+	type Output = E0123;
+	fn commutator(self, rhs: E123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E0123> for E0 {
+	type Output = E123;
+	fn commutator(self, rhs: E0123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<S> for E1 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E1 {
+	type Output = E01;
+	fn commutator(self, rhs: E0) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E1> for E1 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E2> for E1 {
+	type Output = E12;
+	fn commutator(self, rhs: E2) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E3> for E1 {
+	type Output = E13;
+	fn commutator(self, rhs: E3) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E01> for E1 {
+	type Output = E0;
+	fn commutator(self, rhs: E01) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E02> for E1 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E03> for E1 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E12> for E1 {
+	type Output = E2;
+	fn commutator(self, rhs: E12) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E13> for E1 {
+	type Output = E3;
+	fn commutator(self, rhs: E13) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E23> for E1 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E012> for E1 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E013> for E1 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E023> for E1 {
+	type Output = E0123;
+	fn commutator(self, rhs: E023) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E123> for E1 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0123> for E1 {
+	type Output = E023;
+	fn commutator(self, rhs: E0123) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<S> for E2 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E2 {
+	type Output = E02;
+	fn commutator(self, rhs: E0) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E1> for E2 {
+	type Output = E12;
+	fn commutator(self, rhs: E1) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E2> for E2 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E3> for E2 {
+	type Output = E23;
+	fn commutator(self, rhs: E3) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E01> for E2 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E02> for E2 {
+	type Output = E0;
+	fn commutator(self, rhs: E02) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E03> for E2 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E12> for E2 {
+	type Output = E1;
+	fn commutator(self, rhs: E12) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E13> for E2 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E23> for E2 {
+	type Output = E3;
+	fn commutator(self, rhs: E23) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E012> for E2 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E013> for E2 {
+	type Output = E0123;
+	fn commutator(self, rhs: E013) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E023> for E2 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E123> for E2 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0123> for E2 {
+	type Output = E013;
+	fn commutator(self, rhs: E0123) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<S> for E3 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E3 {
+	type Output = E03;
+	fn commutator(self, rhs: E0) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E1> for E3 {
+	type Output = E13;
+	fn commutator(self, rhs: E1) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E2> for E3 {
+	type Output = E23;
+	fn commutator(self, rhs: E2) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E3> for E3 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E01> for E3 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E02> for E3 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E03> for E3 {
+	type Output = E0;
+	fn commutator(self, rhs: E03) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E12> for E3 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E13> for E3 {
+	type Output = E1;
+	fn commutator(self, rhs: E13) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E23> for E3 {
+	type Output = E2;
+	fn commutator(self, rhs: E23) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E012> for E3 {
+	type Output = E0123;
+	fn commutator(self, rhs: E012) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E013> for E3 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E023> for E3 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E123> for E3 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0123> for E3 {
+	type Output = E012;
+	fn commutator(self, rhs: E0123) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<S> for E01 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E01 {
+	type Output = E1;
+	fn commutator(self, rhs: E0) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E1> for E01 {
+	type Output = E0;
+	fn commutator(self, rhs: E1) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E2> for E01 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E3> for E01 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E01> for E01 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E02> for E01 {
+	type Output = E12;
+	fn commutator(self, rhs: E02) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E03> for E01 {
+	type Output = E13;
+	fn commutator(self, rhs: E03) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E12> for E01 {
+	type Output = E02;
+	fn commutator(self, rhs: E12) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E13> for E01 {
+	type Output = E03;
+	fn commutator(self, rhs: E13) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E23> for E01 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E012> for E01 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E013> for E01 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E023> for E01 {
+	type Output = E123;
+	fn commutator(self, rhs: E023) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E123> for E01 {
+	type Output = E023;
+	fn commutator(self, rhs: E123) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E0123> for E01 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for E02 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E02 {
+	type Output = E2;
+	fn commutator(self, rhs: E0) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E1> for E02 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E2> for E02 {
+	type Output = E0;
+	fn commutator(self, rhs: E2) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E3> for E02 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E01> for E02 {
+	type Output = E12;
+	fn commutator(self, rhs: E01) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E02> for E02 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E03> for E02 {
+	type Output = E23;
+	fn commutator(self, rhs: E03) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E12> for E02 {
+	type Output = E01;
+	fn commutator(self, rhs: E12) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E13> for E02 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E23> for E02 {
+	type Output = E03;
+	fn commutator(self, rhs: E23) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E012> for E02 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E013> for E02 {
+	type Output = E123;
+	fn commutator(self, rhs: E013) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E023> for E02 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E123> for E02 {
+	type Output = E013;
+	fn commutator(self, rhs: E123) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E0123> for E02 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for E03 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E03 {
+	type Output = E3;
+	fn commutator(self, rhs: E0) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E1> for E03 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E2> for E03 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E3> for E03 {
+	type Output = E0;
+	fn commutator(self, rhs: E3) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E01> for E03 {
+	type Output = E13;
+	fn commutator(self, rhs: E01) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E02> for E03 {
+	type Output = E23;
+	fn commutator(self, rhs: E02) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E03> for E03 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E12> for E03 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E13> for E03 {
+	type Output = E01;
+	fn commutator(self, rhs: E13) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E23> for E03 {
+	type Output = E02;
+	fn commutator(self, rhs: E23) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E012> for E03 {
+	type Output = E123;
+	fn commutator(self, rhs: E012) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E013> for E03 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E023> for E03 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E123> for E03 {
+	type Output = E012;
+	fn commutator(self, rhs: E123) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E0123> for E03 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for E12 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E12 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E1> for E12 {
+	type Output = E2;
+	fn commutator(self, rhs: E1) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E2> for E12 {
+	type Output = E1;
+	fn commutator(self, rhs: E2) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E3> for E12 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E01> for E12 {
+	type Output = E02;
+	fn commutator(self, rhs: E01) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E02> for E12 {
+	type Output = E01;
+	fn commutator(self, rhs: E02) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E03> for E12 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E12> for E12 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E13> for E12 {
+	type Output = E23;
+	fn commutator(self, rhs: E13) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E23> for E12 {
+	type Output = E13;
+	fn commutator(self, rhs: E23) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E012> for E12 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E013> for E12 {
+	type Output = E023;
+	fn commutator(self, rhs: E013) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E023> for E12 {
+	type Output = E013;
+	fn commutator(self, rhs: E023) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E123> for E12 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0123> for E12 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for E13 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E13 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E1> for E13 {
+	type Output = E3;
+	fn commutator(self, rhs: E1) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E2> for E13 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E3> for E13 {
+	type Output = E1;
+	fn commutator(self, rhs: E3) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E01> for E13 {
+	type Output = E03;
+	fn commutator(self, rhs: E01) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E02> for E13 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E03> for E13 {
+	type Output = E01;
+	fn commutator(self, rhs: E03) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E12> for E13 {
+	type Output = E23;
+	fn commutator(self, rhs: E12) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E13> for E13 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E23> for E13 {
+	type Output = E12;
+	fn commutator(self, rhs: E23) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E012> for E13 {
+	type Output = E023;
+	fn commutator(self, rhs: E012) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E013> for E13 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E023> for E13 {
+	type Output = E012;
+	fn commutator(self, rhs: E023) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E123> for E13 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0123> for E13 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for E23 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E23 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E1> for E23 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E2> for E23 {
+	type Output = E3;
+	fn commutator(self, rhs: E2) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E3> for E23 {
+	type Output = E2;
+	fn commutator(self, rhs: E3) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E01> for E23 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E02> for E23 {
+	type Output = E03;
+	fn commutator(self, rhs: E02) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E03> for E23 {
+	type Output = E02;
+	fn commutator(self, rhs: E03) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E12> for E23 {
+	type Output = E13;
+	fn commutator(self, rhs: E12) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E13> for E23 {
+	type Output = E12;
+	fn commutator(self, rhs: E13) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E23> for E23 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E012> for E23 {
+	type Output = E013;
+	fn commutator(self, rhs: E012) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E013> for E23 {
+	type Output = E012;
+	fn commutator(self, rhs: E013) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E023> for E23 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E123> for E23 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0123> for E23 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for E012 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E012 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E1> for E012 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E2> for E012 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E3> for E012 {
+	type Output = E0123;
+	fn commutator(self, rhs: E3) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E01> for E012 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E02> for E012 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E03> for E012 {
+	type Output = E123;
+	fn commutator(self, rhs: E03) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E12> for E012 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E13> for E012 {
+	type Output = E023;
+	fn commutator(self, rhs: E13) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E23> for E012 {
+	type Output = E013;
+	fn commutator(self, rhs: E23) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E012> for E012 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E013> for E012 {
+	type Output = E23;
+	fn commutator(self, rhs: E013) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E023> for E012 {
+	type Output = E13;
+	fn commutator(self, rhs: E023) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E123> for E012 {
+	type Output = E03;
+	fn commutator(self, rhs: E123) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E0123> for E012 {
+	type Output = E3;
+	fn commutator(self, rhs: E0123) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<S> for E013 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E013 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E1> for E013 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E2> for E013 {
+	type Output = E0123;
+	fn commutator(self, rhs: E2) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E3> for E013 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E01> for E013 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E02> for E013 {
+	type Output = E123;
+	fn commutator(self, rhs: E02) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E03> for E013 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E12> for E013 {
+	type Output = E023;
+	fn commutator(self, rhs: E12) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E13> for E013 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E23> for E013 {
+	type Output = E012;
+	fn commutator(self, rhs: E23) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E012> for E013 {
+	type Output = E23;
+	fn commutator(self, rhs: E012) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E013> for E013 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E023> for E013 {
+	type Output = E12;
+	fn commutator(self, rhs: E023) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E123> for E013 {
+	type Output = E02;
+	fn commutator(self, rhs: E123) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E0123> for E013 {
+	type Output = E2;
+	fn commutator(self, rhs: E0123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<S> for E023 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E023 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E1> for E023 {
+	type Output = E0123;
+	fn commutator(self, rhs: E1) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E2> for E023 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E3> for E023 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E01> for E023 {
+	type Output = E123;
+	fn commutator(self, rhs: E01) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E02> for E023 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E03> for E023 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E12> for E023 {
+	type Output = E013;
+	fn commutator(self, rhs: E12) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E13> for E023 {
+	type Output = E012;
+	fn commutator(self, rhs: E13) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E23> for E023 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E012> for E023 {
+	type Output = E13;
+	fn commutator(self, rhs: E012) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E013> for E023 {
+	type Output = E12;
+	fn commutator(self, rhs: E013) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E023> for E023 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E123> for E023 {
+	type Output = E01;
+	fn commutator(self, rhs: E123) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E0123> for E023 {
+	type Output = E1;
+	fn commutator(self, rhs: E0123) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<S> for E123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E123 {
+	type Output = E0123;
+	fn commutator(self, rhs: E0) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E1> for E123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E2> for E123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E3> for E123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E01> for E123 {
+	type Output = E023;
+	fn commutator(self, rhs: E01) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E02> for E123 {
+	type Output = E013;
+	fn commutator(self, rhs: E02) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E03> for E123 {
+	type Output = E012;
+	fn commutator(self, rhs: E03) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E12> for E123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E13> for E123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E23> for E123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E012> for E123 {
+	type Output = E03;
+	fn commutator(self, rhs: E012) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E013> for E123 {
+	type Output = E02;
+	fn commutator(self, rhs: E013) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E023> for E123 {
+	type Output = E01;
+	fn commutator(self, rhs: E023) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E123> for E123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0123> for E123 {
+	type Output = E0;
+	fn commutator(self, rhs: E0123) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<S> for E0123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E0> for E0123 {
+	type Output = E123;
+	fn commutator(self, rhs: E0) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E1> for E0123 {
+	type Output = E023;
+	fn commutator(self, rhs: E1) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E2> for E0123 {
+	type Output = E013;
+	fn commutator(self, rhs: E2) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E3> for E0123 {
+	type Output = E012;
+	fn commutator(self, rhs: E3) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E01> for E0123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E02> for E0123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E03> for E0123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E12> for E0123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E13> for E0123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E23> for E0123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<E012> for E0123 {
+	type Output = E3;
+	fn commutator(self, rhs: E012) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E013> for E0123 {
+	type Output = E2;
+	fn commutator(self, rhs: E013) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E023> for E0123 {
+	type Output = E1;
+	fn commutator(self, rhs: E023) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E123> for E0123 {
+	type Output = E0;
+	fn commutator(self, rhs: E123) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<E0123> for E0123 {
+	type Output = Zero;
+	fn commutator(self, _rhs: E0123) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiDot for blades:
+
+impl AntiDot<S> for S {
+	type Output = E0123;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for S {
+	type Output = E123;
+	fn anti_dot(self, rhs: E0) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E1> for S {
+	type Output = E023;
+	fn anti_dot(self, rhs: E1) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E2> for S {
+	type Output = E013;
+	fn anti_dot(self, rhs: E2) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E3> for S {
+	type Output = E012;
+	fn anti_dot(self, rhs: E3) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E01> for S {
+	type Output = E23;
+	fn anti_dot(self, rhs: E01) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E02> for S {
+	type Output = E13;
+	fn anti_dot(self, rhs: E02) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E03> for S {
+	type Output = E12;
+	fn anti_dot(self, rhs: E03) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E12> for S {
+	type Output = E03;
+	fn anti_dot(self, rhs: E12) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E13> for S {
+	type Output = E02;
+	fn anti_dot(self, rhs: E13) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E23> for S {
+	type Output = E01;
+	fn anti_dot(self, rhs: E23) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E012> for S {
+	type Output = E3;
+	fn anti_dot(self, rhs: E012) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E013> for S {
+	type Output = E2;
+	fn anti_dot(self, rhs: E013) -> Self::Output {
+		E2(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E023> for S {
+	type Output = E1;
+	fn anti_dot(self, rhs: E023) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E123> for S {
+	type Output = E0;
+	fn anti_dot(self, rhs: E123) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0123> for S {
+	type Output = S;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E0 {
+	type Output = E123;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E0 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E0) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E1> for E0 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E2> for E0 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E3> for E0 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E01> for E0 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E01) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E02> for E0 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E02) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E03> for E0 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E03) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E12> for E0 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E13> for E0 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E23> for E0 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E012> for E0 {
+	type Output = E03;
+	fn anti_dot(self, rhs: E012) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E013> for E0 {
+	type Output = E02;
+	fn anti_dot(self, rhs: E013) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E023> for E0 {
+	type Output = E01;
+	fn anti_dot(self, rhs: E023) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E123> for E0 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E0123> for E0 {
+	type Output = E0;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E1 {
+	type Output = E023;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E1 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E1> for E1 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E1) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E2> for E1 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E3> for E1 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E01> for E1 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E01) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E02> for E1 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E03> for E1 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E12> for E1 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E12) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E13> for E1 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E13) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E23> for E1 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E012> for E1 {
+	type Output = E13;
+	fn anti_dot(self, rhs: E012) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E013> for E1 {
+	type Output = E12;
+	fn anti_dot(self, rhs: E013) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E023> for E1 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E123> for E1 {
+	type Output = E01;
+	fn anti_dot(self, rhs: E123) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0123> for E1 {
+	type Output = E1;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E2 {
+	type Output = E013;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E1> for E2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E2> for E2 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E2) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E3> for E2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E01> for E2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E02> for E2 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E02) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E03> for E2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E12> for E2 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E12) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E13> for E2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E23> for E2 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E23) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E012> for E2 {
+	type Output = E23;
+	fn anti_dot(self, rhs: E012) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E013> for E2 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E023> for E2 {
+	type Output = E12;
+	fn anti_dot(self, rhs: E023) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E123> for E2 {
+	type Output = E02;
+	fn anti_dot(self, rhs: E123) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0123> for E2 {
+	type Output = E2;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E3 {
+	type Output = E012;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E1> for E3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E2> for E3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E3> for E3 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E3) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E01> for E3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E02> for E3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E03> for E3 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E03) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E12> for E3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E13> for E3 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E13) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E23> for E3 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E23) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E012> for E3 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E013> for E3 {
+	type Output = E23;
+	fn anti_dot(self, rhs: E013) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E023> for E3 {
+	type Output = E13;
+	fn anti_dot(self, rhs: E023) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E123> for E3 {
+	type Output = E03;
+	fn anti_dot(self, rhs: E123) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0123> for E3 {
+	type Output = E3;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E01 {
+	type Output = E23;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E01 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E0) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E1> for E01 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E1) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E2> for E01 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E3> for E01 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E01> for E01 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E01) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E02> for E01 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E03> for E01 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E12> for E01 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E13> for E01 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E23> for E01 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E012> for E01 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E012) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E013> for E01 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E013) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E023> for E01 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E123> for E01 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E0123> for E01 {
+	type Output = E01;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E02 {
+	type Output = E13;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E02 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E0) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E1> for E02 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E2> for E02 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E2) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E3> for E02 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E01> for E02 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E02> for E02 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E02) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E03> for E02 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E12> for E02 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E13> for E02 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E23> for E02 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E012> for E02 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E012) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E013> for E02 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E023> for E02 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E023) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E123> for E02 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E0123> for E02 {
+	type Output = E02;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E03 {
+	type Output = E12;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E12(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E03 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E0) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E1> for E03 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E2> for E03 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E3> for E03 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E3) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E01> for E03 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E02> for E03 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E03> for E03 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E03) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E12> for E03 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E13> for E03 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E23> for E03 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E012> for E03 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E013> for E03 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E013) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E023> for E03 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E023) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E123> for E03 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E0123> for E03 {
+	type Output = E03;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E12 {
+	type Output = E03;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E12 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E1> for E12 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E1) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E2> for E12 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E2) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E3> for E12 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E01> for E12 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E02> for E12 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E03> for E12 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E12> for E12 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E12) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E13> for E12 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E23> for E12 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E012> for E12 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E012) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E013> for E12 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E023> for E12 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E123> for E12 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E123) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0123> for E12 {
+	type Output = E12;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E13 {
+	type Output = E02;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E02(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E13 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E1> for E13 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E1) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E2> for E13 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E3> for E13 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E3) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E01> for E13 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E02> for E13 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E03> for E13 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E12> for E13 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E13> for E13 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E13) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E23> for E13 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E012> for E13 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E013> for E13 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E013) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E023> for E13 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E123> for E13 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E123) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0123> for E13 {
+	type Output = E13;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E23 {
+	type Output = E01;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E23 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E1> for E23 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E2> for E23 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E2) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E3> for E23 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E3) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E01> for E23 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E02> for E23 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E03> for E23 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E12> for E23 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E13> for E23 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E23> for E23 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E23) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E012> for E23 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E013> for E23 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E023> for E23 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E023) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E123> for E23 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E123) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0123> for E23 {
+	type Output = E23;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E012 {
+	type Output = E3;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E3(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E012 {
+	type Output = E03;
+	fn anti_dot(self, rhs: E0) -> Self::Output {
+		E03(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E1> for E012 {
+	type Output = E13;
+	fn anti_dot(self, rhs: E1) -> Self::Output {
+		E13(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E2> for E012 {
+	type Output = E23;
+	fn anti_dot(self, rhs: E2) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E3> for E012 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E01> for E012 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E01) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E02> for E012 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E02) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E03> for E012 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E12> for E012 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E12) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E13> for E012 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E23> for E012 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E012> for E012 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E012) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E013> for E012 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E023> for E012 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E123> for E012 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E0123> for E012 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E013 {
+	type Output = E2;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E013 {
+	type Output = E02;
+	fn anti_dot(self, rhs: E0) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E1> for E013 {
+	type Output = E12;
+	fn anti_dot(self, rhs: E1) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E2> for E013 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E2) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E3> for E013 {
+	type Output = E23;
+	fn anti_dot(self, rhs: E3) -> Self::Output {
+		E23(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E01> for E013 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E01) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E02> for E013 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E03> for E013 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E03) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E12> for E013 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E13> for E013 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E13) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E23> for E013 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E23) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E012> for E013 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E013> for E013 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E013) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E023> for E013 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E123> for E013 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E0123> for E013 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E023 {
+	type Output = E1;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E1(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E023 {
+	type Output = E01;
+	fn anti_dot(self, rhs: E0) -> Self::Output {
+		E01(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E1> for E023 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E1) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E2> for E023 {
+	type Output = E12;
+	fn anti_dot(self, rhs: E2) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E3> for E023 {
+	type Output = E13;
+	fn anti_dot(self, rhs: E3) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E01> for E023 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E02> for E023 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E02) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E03> for E023 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E03) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E12> for E023 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E12) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E13> for E023 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E13) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E23> for E023 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E23) -> Self::Output {
+		E123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E012> for E023 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E013> for E023 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E023> for E023 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E023) -> Self::Output {
+		E0123(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E123> for E023 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E123) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E0123> for E023 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E123 {
+	type Output = E0;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		E0(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E123 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E0) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E1> for E123 {
+	type Output = E01;
+	fn anti_dot(self, rhs: E1) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E2> for E123 {
+	type Output = E02;
+	fn anti_dot(self, rhs: E2) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E3> for E123 {
+	type Output = E03;
+	fn anti_dot(self, rhs: E3) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E01> for E123 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E01) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E02> for E123 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E02) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E03> for E123 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E03) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E12> for E123 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E12) -> Self::Output {
+		E012(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E13> for E123 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E13) -> Self::Output {
+		E013(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E23> for E123 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E23) -> Self::Output {
+		E023(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E012> for E123 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E012) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E013> for E123 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E013) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E023> for E123 {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: E023) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<E123> for E123 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0123> for E123 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for E0123 {
+	type Output = S;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0> for E0123 {
+	type Output = E0;
+	fn anti_dot(self, rhs: E0) -> Self::Output {
+		E0(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E1> for E0123 {
+	type Output = E1;
+	fn anti_dot(self, rhs: E1) -> Self::Output {
+		E1(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E2> for E0123 {
+	type Output = E2;
+	fn anti_dot(self, rhs: E2) -> Self::Output {
+		E2(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E3> for E0123 {
+	type Output = E3;
+	fn anti_dot(self, rhs: E3) -> Self::Output {
+		E3(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E01> for E0123 {
+	type Output = E01;
+	fn anti_dot(self, rhs: E01) -> Self::Output {
+		E01(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E02> for E0123 {
+	type Output = E02;
+	fn anti_dot(self, rhs: E02) -> Self::Output {
+		E02(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E03> for E0123 {
+	type Output = E03;
+	fn anti_dot(self, rhs: E03) -> Self::Output {
+		E03(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E12> for E0123 {
+	type Output = E12;
+	fn anti_dot(self, rhs: E12) -> Self::Output {
+		E12(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E13> for E0123 {
+	type Output = E13;
+	fn anti_dot(self, rhs: E13) -> Self::Output {
+		E13(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E23> for E0123 {
+	type Output = E23;
+	fn anti_dot(self, rhs: E23) -> Self::Output {
+		E23(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E012> for E0123 {
+	type Output = E012;
+	fn anti_dot(self, rhs: E012) -> Self::Output {
+		E012(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E013> for E0123 {
+	type Output = E013;
+	fn anti_dot(self, rhs: E013) -> Self::Output {
+		E013(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E023> for E0123 {
+	type Output = E023;
+	fn anti_dot(self, rhs: E023) -> Self::Output {
+		E023(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E123> for E0123 {
+	type Output = E123;
+	fn anti_dot(self, rhs: E123) -> Self::Output {
+		E123(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<E0123> for E0123 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: E0123) -> Self::Output {
+		E0123(self.0 * rhs.0)
+	}
+}
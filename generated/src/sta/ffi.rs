@@ -0,0 +1,998 @@
+//! The C ABI of this algebra, declared in `sta.h`.
+
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_rcompl(value: Vec4) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_lcompl(value: Vec4) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_rev(value: Vec4) -> Vec4 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_arev(value: Vec4) -> Vec4 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_involute(value: Vec4) -> Vec4 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_conjugate(value: Vec4) -> Vec4 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_dual(value: Vec4) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_bulk(value: Vec4) -> Vec4 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_weight(value: Vec4) -> Vec4 {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_bulk_dual(value: Vec4) -> Multivector {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_weight_dual(value: Vec4) -> Multivector {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_geometric_vec4(lhs: Vec4, rhs: Vec4) -> Rotor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_anti_geometric_vec4(lhs: Vec4, rhs: Vec4) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_dot_vec4(lhs: Vec4, rhs: Vec4) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_wedge_vec4(lhs: Vec4, rhs: Vec4) -> Bivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_left_contraction_vec4(lhs: Vec4, rhs: Vec4) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_right_contraction_vec4(lhs: Vec4, rhs: Vec4) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_scalar_product_vec4(lhs: Vec4, rhs: Vec4) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_commutator_vec4(lhs: Vec4, rhs: Vec4) -> Bivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_anti_dot_vec4(lhs: Vec4, rhs: Vec4) -> E0123 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_geometric_bivector(lhs: Vec4, rhs: Bivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_anti_geometric_bivector(lhs: Vec4, rhs: Bivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_dot_bivector(lhs: Vec4, rhs: Bivector) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_wedge_bivector(lhs: Vec4, rhs: Bivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_left_contraction_bivector(lhs: Vec4, rhs: Bivector) -> Vec4 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_commutator_bivector(lhs: Vec4, rhs: Bivector) -> Vec4 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_anti_dot_bivector(lhs: Vec4, rhs: Bivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_geometric_rotor(lhs: Vec4, rhs: Rotor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_anti_geometric_rotor(lhs: Vec4, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_dot_rotor(lhs: Vec4, rhs: Rotor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_wedge_rotor(lhs: Vec4, rhs: Rotor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_anti_wedge_rotor(lhs: Vec4, rhs: Rotor) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_left_contraction_rotor(lhs: Vec4, rhs: Rotor) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_right_contraction_rotor(lhs: Vec4, rhs: Rotor) -> Vec4 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_commutator_rotor(lhs: Vec4, rhs: Rotor) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_anti_dot_rotor(lhs: Vec4, rhs: Rotor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_geometric_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_anti_geometric_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_dot_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_wedge_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_anti_wedge_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_left_contraction_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_right_contraction_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_scalar_product_multivector(lhs: Vec4, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_commutator_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_vec4_anti_dot_multivector(lhs: Vec4, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_rcompl(value: Bivector) -> Bivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_lcompl(value: Bivector) -> Bivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_rev(value: Bivector) -> Bivector {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_arev(value: Bivector) -> Bivector {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_involute(value: Bivector) -> Bivector {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_conjugate(value: Bivector) -> Bivector {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_dual(value: Bivector) -> Bivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_bulk(value: Bivector) -> Bivector {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_weight(value: Bivector) -> Bivector {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_bulk_dual(value: Bivector) -> Bivector {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_weight_dual(value: Bivector) -> Bivector {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_geometric_vec4(lhs: Bivector, rhs: Vec4) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_geometric_vec4(lhs: Bivector, rhs: Vec4) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_dot_vec4(lhs: Bivector, rhs: Vec4) -> Vec4 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_wedge_vec4(lhs: Bivector, rhs: Vec4) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_right_contraction_vec4(lhs: Bivector, rhs: Vec4) -> Vec4 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_commutator_vec4(lhs: Bivector, rhs: Vec4) -> Vec4 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_dot_vec4(lhs: Bivector, rhs: Vec4) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_geometric_bivector(lhs: Bivector, rhs: Bivector) -> Rotor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_geometric_bivector(lhs: Bivector, rhs: Bivector) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_dot_bivector(lhs: Bivector, rhs: Bivector) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_wedge_bivector(lhs: Bivector, rhs: Bivector) -> E0123 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_wedge_bivector(lhs: Bivector, rhs: Bivector) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_left_contraction_bivector(lhs: Bivector, rhs: Bivector) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_right_contraction_bivector(lhs: Bivector, rhs: Bivector) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_scalar_product_bivector(lhs: Bivector, rhs: Bivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_commutator_bivector(lhs: Bivector, rhs: Bivector) -> Bivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_dot_bivector(lhs: Bivector, rhs: Bivector) -> E0123 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_geometric_rotor(lhs: Bivector, rhs: Rotor) -> Rotor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_geometric_rotor(lhs: Bivector, rhs: Rotor) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_dot_rotor(lhs: Bivector, rhs: Rotor) -> Rotor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_wedge_rotor(lhs: Bivector, rhs: Rotor) -> Rotor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_wedge_rotor(lhs: Bivector, rhs: Rotor) -> Rotor {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_left_contraction_rotor(lhs: Bivector, rhs: Rotor) -> Rotor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_right_contraction_rotor(lhs: Bivector, rhs: Rotor) -> Rotor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_scalar_product_rotor(lhs: Bivector, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_commutator_rotor(lhs: Bivector, rhs: Rotor) -> Bivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_dot_rotor(lhs: Bivector, rhs: Rotor) -> Rotor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_geometric_multivector(lhs: Bivector, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_geometric_multivector(lhs: Bivector, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_dot_multivector(lhs: Bivector, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_wedge_multivector(lhs: Bivector, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_wedge_multivector(lhs: Bivector, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_left_contraction_multivector(lhs: Bivector, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_right_contraction_multivector(lhs: Bivector, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_scalar_product_multivector(lhs: Bivector, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_commutator_multivector(lhs: Bivector, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_bivector_anti_dot_multivector(lhs: Bivector, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_rcompl(value: Rotor) -> Rotor {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_lcompl(value: Rotor) -> Rotor {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_rev(value: Rotor) -> Rotor {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_arev(value: Rotor) -> Rotor {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_involute(value: Rotor) -> Rotor {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_conjugate(value: Rotor) -> Rotor {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_dual(value: Rotor) -> Rotor {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_bulk(value: Rotor) -> Rotor {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_weight(value: Rotor) -> Rotor {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_bulk_dual(value: Rotor) -> Rotor {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_weight_dual(value: Rotor) -> Rotor {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_geometric_vec4(lhs: Rotor, rhs: Vec4) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_geometric_vec4(lhs: Rotor, rhs: Vec4) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_dot_vec4(lhs: Rotor, rhs: Vec4) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_wedge_vec4(lhs: Rotor, rhs: Vec4) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_wedge_vec4(lhs: Rotor, rhs: Vec4) -> Vec4 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_left_contraction_vec4(lhs: Rotor, rhs: Vec4) -> Vec4 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_right_contraction_vec4(lhs: Rotor, rhs: Vec4) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_commutator_vec4(lhs: Rotor, rhs: Vec4) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_dot_vec4(lhs: Rotor, rhs: Vec4) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_geometric_bivector(lhs: Rotor, rhs: Bivector) -> Rotor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_geometric_bivector(lhs: Rotor, rhs: Bivector) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_dot_bivector(lhs: Rotor, rhs: Bivector) -> Rotor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_wedge_bivector(lhs: Rotor, rhs: Bivector) -> Rotor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_wedge_bivector(lhs: Rotor, rhs: Bivector) -> Rotor {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_left_contraction_bivector(lhs: Rotor, rhs: Bivector) -> Rotor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_right_contraction_bivector(lhs: Rotor, rhs: Bivector) -> Rotor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_scalar_product_bivector(lhs: Rotor, rhs: Bivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_commutator_bivector(lhs: Rotor, rhs: Bivector) -> Bivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_dot_bivector(lhs: Rotor, rhs: Bivector) -> Rotor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_geometric_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_geometric_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_dot_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_wedge_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_wedge_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_left_contraction_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_right_contraction_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_scalar_product_rotor(lhs: Rotor, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_commutator_rotor(lhs: Rotor, rhs: Rotor) -> Bivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_dot_rotor(lhs: Rotor, rhs: Rotor) -> Rotor {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_geometric_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_geometric_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_dot_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_wedge_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_wedge_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_left_contraction_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_right_contraction_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_scalar_product_multivector(lhs: Rotor, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_commutator_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_dot_multivector(lhs: Rotor, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_sandwich_vec4(lhs: Rotor, rhs: Vec4) -> Vec4 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_sandwich_vec4(lhs: Rotor, rhs: Vec4) -> Vec4 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_sandwich_bivector(lhs: Rotor, rhs: Bivector) -> Bivector {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_rotor_anti_sandwich_bivector(lhs: Rotor, rhs: Bivector) -> Bivector {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_rcompl(value: Multivector) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_lcompl(value: Multivector) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_rev(value: Multivector) -> Multivector {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_arev(value: Multivector) -> Multivector {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_involute(value: Multivector) -> Multivector {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_conjugate(value: Multivector) -> Multivector {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_dual(value: Multivector) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_bulk(value: Multivector) -> Multivector {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_weight(value: Multivector) -> Multivector {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_bulk_dual(value: Multivector) -> Multivector {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_weight_dual(value: Multivector) -> Multivector {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_geometric_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_geometric_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_dot_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_wedge_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_wedge_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_left_contraction_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_right_contraction_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_scalar_product_vec4(lhs: Multivector, rhs: Vec4) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_commutator_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_dot_vec4(lhs: Multivector, rhs: Vec4) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_geometric_bivector(lhs: Multivector, rhs: Bivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_geometric_bivector(lhs: Multivector, rhs: Bivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_dot_bivector(lhs: Multivector, rhs: Bivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_wedge_bivector(lhs: Multivector, rhs: Bivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_wedge_bivector(lhs: Multivector, rhs: Bivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_left_contraction_bivector(lhs: Multivector, rhs: Bivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_right_contraction_bivector(lhs: Multivector, rhs: Bivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_scalar_product_bivector(lhs: Multivector, rhs: Bivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_commutator_bivector(lhs: Multivector, rhs: Bivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_dot_bivector(lhs: Multivector, rhs: Bivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_geometric_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_geometric_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_dot_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_wedge_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_wedge_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_left_contraction_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_right_contraction_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_scalar_product_rotor(lhs: Multivector, rhs: Rotor) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_commutator_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_dot_rotor(lhs: Multivector, rhs: Rotor) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_geometric_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_geometric_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_dot_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_wedge_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_wedge_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_left_contraction_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_right_contraction_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_scalar_product_multivector(lhs: Multivector, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_commutator_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn sta_multivector_anti_dot_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
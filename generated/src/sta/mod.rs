@@ -0,0 +1,22 @@
+pub mod blades;
+pub mod traits;
+
+// ----------------------------------------------------------------------------
+// Geometric Algebra definition helpers:
+
+/// Special zero type for completeness, and better error messages.
+/// If you get this in an error message, it is because you multiplied
+/// two dimensions that always results in zero.
+pub struct Zero {}
+
+// ----------------------------------------------------------------------------
+
+// Types:
+pub mod bivector;
+pub mod multivector;
+pub mod rotor;
+pub mod vec4;
+
+pub mod ffi;
+
+pub use self::{bivector::*, blades::*, multivector::*, rotor::*, traits::*, vec4::*};
@@ -0,0 +1,4181 @@
+//! # Multivector
+//!
+//! ## Operations
+//! ```text
+//! Multivector.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Multivector) -> Multivector
+//! Multivector.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Multivector) -> S
+//! Multivector.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Vec4) -> Multivector
+//! Vec4.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Vec4) -> Multivector
+//! Vec4.dot(Multivector) -> Multivector
+//! Multivector.wedge(Vec4) -> Multivector
+//! Vec4.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec4) -> Multivector
+//! Vec4.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec4) -> Multivector
+//! Vec4.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec4) -> Multivector
+//! Vec4.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Vec4) -> S
+//! Vec4.scalar_product(Multivector) -> S
+//! Multivector.commutator(Vec4) -> Multivector
+//! Vec4.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec4) -> Multivector
+//! Vec4.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Bivector) -> Multivector
+//! Bivector.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Bivector) -> Multivector
+//! Bivector.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Bivector) -> Multivector
+//! Bivector.dot(Multivector) -> Multivector
+//! Multivector.wedge(Bivector) -> Multivector
+//! Bivector.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Bivector) -> Multivector
+//! Bivector.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Bivector) -> Multivector
+//! Bivector.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Bivector) -> Multivector
+//! Bivector.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Bivector) -> S
+//! Bivector.scalar_product(Multivector) -> S
+//! Multivector.commutator(Bivector) -> Multivector
+//! Bivector.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Bivector) -> Multivector
+//! Bivector.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Rotor) -> Multivector
+//! Rotor.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Rotor) -> Multivector
+//! Rotor.dot(Multivector) -> Multivector
+//! Multivector.wedge(Rotor) -> Multivector
+//! Rotor.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Rotor) -> Multivector
+//! Rotor.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Rotor) -> Multivector
+//! Rotor.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Rotor) -> Multivector
+//! Rotor.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Rotor) -> S
+//! Rotor.scalar_product(Multivector) -> S
+//! Multivector.commutator(Rotor) -> Multivector
+//! Rotor.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Rotor) -> Multivector
+//! Rotor.anti_dot(Multivector) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Multivector {
+	pub s: S,
+	pub e0: E0,
+	pub e1: E1,
+	pub e2: E2,
+	pub e3: E3,
+	pub e01: E01,
+	pub e02: E02,
+	pub e03: E03,
+	pub e12: E12,
+	pub e13: E13,
+	pub e23: E23,
+	pub e012: E012,
+	pub e013: E013,
+	pub e023: E023,
+	pub e123: E123,
+	pub e0123: E0123,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Multivector {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Multivector {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Multivector {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.e0.abs_diff_eq(&other.e0, epsilon)
+			&& self.e1.abs_diff_eq(&other.e1, epsilon)
+			&& self.e2.abs_diff_eq(&other.e2, epsilon)
+			&& self.e3.abs_diff_eq(&other.e3, epsilon)
+			&& self.e01.abs_diff_eq(&other.e01, epsilon)
+			&& self.e02.abs_diff_eq(&other.e02, epsilon)
+			&& self.e03.abs_diff_eq(&other.e03, epsilon)
+			&& self.e12.abs_diff_eq(&other.e12, epsilon)
+			&& self.e13.abs_diff_eq(&other.e13, epsilon)
+			&& self.e23.abs_diff_eq(&other.e23, epsilon)
+			&& self.e012.abs_diff_eq(&other.e012, epsilon)
+			&& self.e013.abs_diff_eq(&other.e013, epsilon)
+			&& self.e023.abs_diff_eq(&other.e023, epsilon)
+			&& self.e123.abs_diff_eq(&other.e123, epsilon)
+			&& self.e0123.abs_diff_eq(&other.e0123, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Multivector {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.e0.relative_eq(&other.e0, epsilon, max_relative)
+			&& self.e1.relative_eq(&other.e1, epsilon, max_relative)
+			&& self.e2.relative_eq(&other.e2, epsilon, max_relative)
+			&& self.e3.relative_eq(&other.e3, epsilon, max_relative)
+			&& self.e01.relative_eq(&other.e01, epsilon, max_relative)
+			&& self.e02.relative_eq(&other.e02, epsilon, max_relative)
+			&& self.e03.relative_eq(&other.e03, epsilon, max_relative)
+			&& self.e12.relative_eq(&other.e12, epsilon, max_relative)
+			&& self.e13.relative_eq(&other.e13, epsilon, max_relative)
+			&& self.e23.relative_eq(&other.e23, epsilon, max_relative)
+			&& self.e012.relative_eq(&other.e012, epsilon, max_relative)
+			&& self.e013.relative_eq(&other.e013, epsilon, max_relative)
+			&& self.e023.relative_eq(&other.e023, epsilon, max_relative)
+			&& self.e123.relative_eq(&other.e123, epsilon, max_relative)
+			&& self.e0123.relative_eq(&other.e0123, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Multivector {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: self.e0123.rcompl(),
+			e0: self.e123.rcompl(),
+			e1: self.e023.rcompl(),
+			e2: self.e013.rcompl(),
+			e3: self.e012.rcompl(),
+			e01: self.e23.rcompl(),
+			e02: self.e13.rcompl(),
+			e03: self.e12.rcompl(),
+			e12: self.e03.rcompl(),
+			e13: self.e02.rcompl(),
+			e23: self.e01.rcompl(),
+			e012: self.e3.rcompl(),
+			e013: self.e2.rcompl(),
+			e023: self.e1.rcompl(),
+			e123: self.e0.rcompl(),
+			e0123: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Multivector {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Multivector {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: self.e0123.lcompl(),
+			e0: self.e123.lcompl(),
+			e1: self.e023.lcompl(),
+			e2: self.e013.lcompl(),
+			e3: self.e012.lcompl(),
+			e01: self.e23.lcompl(),
+			e02: self.e13.lcompl(),
+			e03: self.e12.lcompl(),
+			e12: self.e03.lcompl(),
+			e13: self.e02.lcompl(),
+			e23: self.e01.lcompl(),
+			e012: self.e3.lcompl(),
+			e013: self.e2.lcompl(),
+			e023: self.e1.lcompl(),
+			e123: self.e0.lcompl(),
+			e0123: self.s.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Multivector {
+	fn rev(self) -> Self {
+		Multivector {
+			s: self.s,
+			e0: self.e0,
+			e1: self.e1,
+			e2: self.e2,
+			e3: self.e3,
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+			e012: -self.e012,
+			e013: -self.e013,
+			e023: -self.e023,
+			e123: -self.e123,
+			e0123: self.e0123,
+		}
+	}
+}
+
+impl AntiReverse for Multivector {
+	fn arev(self) -> Self {
+		Multivector {
+			s: self.s,
+			e0: -self.e0,
+			e1: -self.e1,
+			e2: -self.e2,
+			e3: -self.e3,
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+			e012: self.e012,
+			e013: self.e013,
+			e023: self.e023,
+			e123: self.e123,
+			e0123: self.e0123,
+		}
+	}
+}
+
+impl Involute for Multivector {
+	fn involute(self) -> Self {
+		Multivector {
+			s: self.s,
+			e0: -self.e0,
+			e1: -self.e1,
+			e2: -self.e2,
+			e3: -self.e3,
+			e01: self.e01,
+			e02: self.e02,
+			e03: self.e03,
+			e12: self.e12,
+			e13: self.e13,
+			e23: self.e23,
+			e012: -self.e012,
+			e013: -self.e013,
+			e023: -self.e023,
+			e123: -self.e123,
+			e0123: self.e0123,
+		}
+	}
+}
+
+impl Conjugate for Multivector {
+	fn conjugate(self) -> Self {
+		Multivector {
+			s: self.s,
+			e0: -self.e0,
+			e1: -self.e1,
+			e2: -self.e2,
+			e3: -self.e3,
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+			e012: self.e012,
+			e013: self.e013,
+			e023: self.e023,
+			e123: self.e123,
+			e0123: self.e0123,
+		}
+	}
+}
+
+impl Dual for Multivector {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: self.e0123.dual(),
+			e0: self.e123.dual(),
+			e1: self.e023.dual(),
+			e2: self.e013.dual(),
+			e3: self.e012.dual(),
+			e01: self.e23.dual(),
+			e02: self.e13.dual(),
+			e03: self.e12.dual(),
+			e12: self.e03.dual(),
+			e13: self.e02.dual(),
+			e23: self.e01.dual(),
+			e012: self.e3.dual(),
+			e013: self.e2.dual(),
+			e023: self.e1.dual(),
+			e123: self.e0.dual(),
+			e0123: self.s.dual(),
+		}
+	}
+}
+
+impl Bulk for Multivector {
+	type Output = Multivector;
+	fn bulk(self) -> Self::Output {
+		Multivector {
+			s: self.s,
+			e0: self.e0,
+			e1: -self.e1,
+			e2: -self.e2,
+			e3: -self.e3,
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: self.e12,
+			e13: self.e13,
+			e23: self.e23,
+			e012: self.e012,
+			e013: self.e013,
+			e023: self.e023,
+			e123: -self.e123,
+			e0123: -self.e0123,
+		}
+	}
+}
+
+impl Weight for Multivector {
+	type Output = Multivector;
+	fn weight(self) -> Self::Output {
+		Multivector {
+			s: -self.s,
+			e0: -self.e0,
+			e1: self.e1,
+			e2: self.e2,
+			e3: self.e3,
+			e01: self.e01,
+			e02: self.e02,
+			e03: self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+			e012: -self.e012,
+			e013: -self.e013,
+			e023: -self.e023,
+			e123: self.e123,
+			e0123: self.e0123,
+		}
+	}
+}
+
+impl BulkDual for Multivector {
+	type Output = Multivector;
+	fn bulk_dual(self) -> Self::Output {
+		Multivector {
+			s: self.e0123.bulk_dual(),
+			e0: self.e123.bulk_dual(),
+			e1: self.e023.bulk_dual(),
+			e2: self.e013.bulk_dual(),
+			e3: self.e012.bulk_dual(),
+			e01: self.e23.bulk_dual(),
+			e02: self.e13.bulk_dual(),
+			e03: self.e12.bulk_dual(),
+			e12: self.e03.bulk_dual(),
+			e13: self.e02.bulk_dual(),
+			e23: self.e01.bulk_dual(),
+			e012: self.e3.bulk_dual(),
+			e013: self.e2.bulk_dual(),
+			e023: self.e1.bulk_dual(),
+			e123: self.e0.bulk_dual(),
+			e0123: self.s.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Multivector {
+	type Output = Multivector;
+	fn weight_dual(self) -> Self::Output {
+		Multivector {
+			s: self.e0123.weight_dual(),
+			e0: self.e123.weight_dual(),
+			e1: self.e023.weight_dual(),
+			e2: self.e013.weight_dual(),
+			e3: self.e012.weight_dual(),
+			e01: self.e23.weight_dual(),
+			e02: self.e13.weight_dual(),
+			e03: self.e12.weight_dual(),
+			e12: self.e03.weight_dual(),
+			e13: self.e02.weight_dual(),
+			e23: self.e01.weight_dual(),
+			e012: self.e3.weight_dual(),
+			e013: self.e2.weight_dual(),
+			e023: self.e1.weight_dual(),
+			e123: self.e0.weight_dual(),
+			e0123: self.s.weight_dual(),
+		}
+	}
+}
+
+// Omitted: Multivector.square()  (not a scalar)
+
+impl Norm for Multivector {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0
+			+ self.e0.0 * self.e0.0
+			+ self.e1.0 * self.e1.0
+			+ self.e2.0 * self.e2.0
+			+ self.e3.0 * self.e3.0
+			+ self.e01.0 * self.e01.0
+			+ self.e02.0 * self.e02.0
+			+ self.e03.0 * self.e03.0
+			+ self.e12.0 * self.e12.0
+			+ self.e13.0 * self.e13.0
+			+ self.e23.0 * self.e23.0
+			+ self.e012.0 * self.e012.0
+			+ self.e013.0 * self.e013.0
+			+ self.e023.0 * self.e023.0
+			+ self.e123.0 * self.e123.0
+			+ self.e0123.0 * self.e0123.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0
+			+ self.e0.0 * self.e0.0
+			+ self.e1.0 * self.e1.0
+			+ self.e2.0 * self.e2.0
+			+ self.e3.0 * self.e3.0
+			+ self.e01.0 * self.e01.0
+			+ self.e02.0 * self.e02.0
+			+ self.e03.0 * self.e03.0
+			+ self.e12.0 * self.e12.0
+			+ self.e13.0 * self.e13.0
+			+ self.e23.0 * self.e23.0
+			+ self.e012.0 * self.e012.0
+			+ self.e013.0 * self.e013.0
+			+ self.e023.0 * self.e023.0
+			+ self.e123.0 * self.e123.0
+			+ self.e0123.0 * self.e0123.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Multivector {
+			s: self.s * scale,
+			e0: self.e0 * scale,
+			e1: self.e1 * scale,
+			e2: self.e2 * scale,
+			e3: self.e3 * scale,
+			e01: self.e01 * scale,
+			e02: self.e02 * scale,
+			e03: self.e03 * scale,
+			e12: self.e12 * scale,
+			e13: self.e13 * scale,
+			e23: self.e23 * scale,
+			e012: self.e012 * scale,
+			e013: self.e013 * scale,
+			e023: self.e023 * scale,
+			e123: self.e123 * scale,
+			e0123: self.e0123 * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Multivector {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 16] {
+		[
+			self.s.0,
+			self.e0.0,
+			self.e1.0,
+			self.e2.0,
+			self.e3.0,
+			self.e01.0,
+			self.e02.0,
+			self.e03.0,
+			self.e12.0,
+			self.e13.0,
+			self.e23.0,
+			self.e012.0,
+			self.e013.0,
+			self.e023.0,
+			self.e123.0,
+			self.e0123.0,
+		]
+	}
+}
+
+impl From<[f64; 16]> for Multivector {
+	fn from(a: [f64; 16]) -> Self {
+		Multivector {
+			s: S(a[0]),
+			e0: E0(a[1]),
+			e1: E1(a[2]),
+			e2: E2(a[3]),
+			e3: E3(a[4]),
+			e01: E01(a[5]),
+			e02: E02(a[6]),
+			e03: E03(a[7]),
+			e12: E12(a[8]),
+			e13: E13(a[9]),
+			e23: E23(a[10]),
+			e012: E012(a[11]),
+			e013: E013(a[12]),
+			e023: E023(a[13]),
+			e123: E123(a[14]),
+			e0123: E0123(a[15]),
+		}
+	}
+}
+
+impl From<Multivector> for [f64; 16] {
+	fn from(v: Multivector) -> Self {
+		v.to_array()
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Vec4:
+
+// Multivector.geometric(Vec4) -> Multivector
+impl Geometric<Vec4> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0.0 * rhs.t.0) - S(self.e1.0 * rhs.x.0) - S(self.e2.0 * rhs.y.0) - S(self.e3.0 * rhs.z.0),
+		//     e0   : -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0) + E0(self.s.0 * rhs.t.0),
+		//     e1   : -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+		//     e2   : -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+		//     e3   : -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		//     e01  : E01(self.e0.0 * rhs.x.0) - E01(self.e012.0 * rhs.y.0) - E01(self.e013.0 * rhs.z.0) - E01(self.e1.0 * rhs.t.0),
+		//     e02  : E02(self.e0.0 * rhs.y.0) + E02(self.e012.0 * rhs.x.0) - E02(self.e023.0 * rhs.z.0) - E02(self.e2.0 * rhs.t.0),
+		//     e03  : E03(self.e0.0 * rhs.z.0) + E03(self.e013.0 * rhs.x.0) + E03(self.e023.0 * rhs.y.0) - E03(self.e3.0 * rhs.t.0),
+		//     e12  : E12(self.e012.0 * rhs.t.0) + E12(self.e1.0 * rhs.y.0) - E12(self.e123.0 * rhs.z.0) - E12(self.e2.0 * rhs.x.0),
+		//     e13  : E13(self.e013.0 * rhs.t.0) + E13(self.e1.0 * rhs.z.0) + E13(self.e123.0 * rhs.y.0) - E13(self.e3.0 * rhs.x.0),
+		//     e23  : E23(self.e023.0 * rhs.t.0) - E23(self.e123.0 * rhs.x.0) + E23(self.e2.0 * rhs.z.0) - E23(self.e3.0 * rhs.y.0),
+		//     e012 : E012(self.e01.0 * rhs.y.0) - E012(self.e0123.0 * rhs.z.0) - E012(self.e02.0 * rhs.x.0) + E012(self.e12.0 * rhs.t.0),
+		//     e013 : E013(self.e01.0 * rhs.z.0) + E013(self.e0123.0 * rhs.y.0) - E013(self.e03.0 * rhs.x.0) + E013(self.e13.0 * rhs.t.0),
+		//     e023 : -E023(self.e0123.0 * rhs.x.0) + E023(self.e02.0 * rhs.z.0) - E023(self.e03.0 * rhs.y.0) + E023(self.e23.0 * rhs.t.0),
+		//     e123 : -E123(self.e0123.0 * rhs.t.0) + E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e0123: E0123(self.e012.0 * rhs.z.0) - E0123(self.e013.0 * rhs.y.0) + E0123(self.e023.0 * rhs.x.0) - E0123(self.e123.0 * rhs.t.0),
+		// }
+		Multivector {
+			s: self.e0.geometric(rhs.t)
+				+ self.e1.geometric(rhs.x)
+				+ self.e2.geometric(rhs.y)
+				+ self.e3.geometric(rhs.z),
+			e0: self.e01.geometric(rhs.x)
+				+ self.e02.geometric(rhs.y)
+				+ self.e03.geometric(rhs.z)
+				+ self.s.geometric(rhs.t),
+			e1: self.e01.geometric(rhs.t)
+				+ self.e12.geometric(rhs.y)
+				+ self.e13.geometric(rhs.z)
+				+ self.s.geometric(rhs.x),
+			e2: self.e02.geometric(rhs.t)
+				+ self.e12.geometric(rhs.x)
+				+ self.e23.geometric(rhs.z)
+				+ self.s.geometric(rhs.y),
+			e3: self.e03.geometric(rhs.t)
+				+ self.e13.geometric(rhs.x)
+				+ self.e23.geometric(rhs.y)
+				+ self.s.geometric(rhs.z),
+			e01: self.e0.geometric(rhs.x)
+				+ self.e012.geometric(rhs.y)
+				+ self.e013.geometric(rhs.z)
+				+ self.e1.geometric(rhs.t),
+			e02: self.e0.geometric(rhs.y)
+				+ self.e012.geometric(rhs.x)
+				+ self.e023.geometric(rhs.z)
+				+ self.e2.geometric(rhs.t),
+			e03: self.e0.geometric(rhs.z)
+				+ self.e013.geometric(rhs.x)
+				+ self.e023.geometric(rhs.y)
+				+ self.e3.geometric(rhs.t),
+			e12: self.e012.geometric(rhs.t)
+				+ self.e1.geometric(rhs.y)
+				+ self.e123.geometric(rhs.z)
+				+ self.e2.geometric(rhs.x),
+			e13: self.e013.geometric(rhs.t)
+				+ self.e1.geometric(rhs.z)
+				+ self.e123.geometric(rhs.y)
+				+ self.e3.geometric(rhs.x),
+			e23: self.e023.geometric(rhs.t)
+				+ self.e123.geometric(rhs.x)
+				+ self.e2.geometric(rhs.z)
+				+ self.e3.geometric(rhs.y),
+			e012: self.e01.geometric(rhs.y)
+				+ self.e0123.geometric(rhs.z)
+				+ self.e02.geometric(rhs.x)
+				+ self.e12.geometric(rhs.t),
+			e013: self.e01.geometric(rhs.z)
+				+ self.e0123.geometric(rhs.y)
+				+ self.e03.geometric(rhs.x)
+				+ self.e13.geometric(rhs.t),
+			e023: self.e0123.geometric(rhs.x)
+				+ self.e02.geometric(rhs.z)
+				+ self.e03.geometric(rhs.y)
+				+ self.e23.geometric(rhs.t),
+			e123: self.e0123.geometric(rhs.t)
+				+ self.e12.geometric(rhs.z)
+				+ self.e13.geometric(rhs.y)
+				+ self.e23.geometric(rhs.x),
+			e0123: self.e012.geometric(rhs.z)
+				+ self.e013.geometric(rhs.y)
+				+ self.e023.geometric(rhs.x)
+				+ self.e123.geometric(rhs.t),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec4> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Vec4) -> Multivector
+impl AntiGeometric<Vec4> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e012.0 * rhs.z.0) - S(self.e013.0 * rhs.y.0) + S(self.e023.0 * rhs.x.0) - S(self.e123.0 * rhs.t.0),
+		//     e0   : E0(self.e0123.0 * rhs.t.0) + E0(self.e12.0 * rhs.z.0) - E0(self.e13.0 * rhs.y.0) + E0(self.e23.0 * rhs.x.0),
+		//     e1   : E1(self.e0123.0 * rhs.x.0) + E1(self.e02.0 * rhs.z.0) - E1(self.e03.0 * rhs.y.0) + E1(self.e23.0 * rhs.t.0),
+		//     e2   : -E2(self.e01.0 * rhs.z.0) + E2(self.e0123.0 * rhs.y.0) + E2(self.e03.0 * rhs.x.0) - E2(self.e13.0 * rhs.t.0),
+		//     e3   : E3(self.e01.0 * rhs.y.0) + E3(self.e0123.0 * rhs.z.0) - E3(self.e02.0 * rhs.x.0) + E3(self.e12.0 * rhs.t.0),
+		//     e01  : -E01(self.e023.0 * rhs.t.0) + E01(self.e123.0 * rhs.x.0) + E01(self.e2.0 * rhs.z.0) - E01(self.e3.0 * rhs.y.0),
+		//     e02  : E02(self.e013.0 * rhs.t.0) - E02(self.e1.0 * rhs.z.0) + E02(self.e123.0 * rhs.y.0) + E02(self.e3.0 * rhs.x.0),
+		//     e03  : -E03(self.e012.0 * rhs.t.0) + E03(self.e1.0 * rhs.y.0) + E03(self.e123.0 * rhs.z.0) - E03(self.e2.0 * rhs.x.0),
+		//     e12  : -E12(self.e0.0 * rhs.z.0) + E12(self.e013.0 * rhs.x.0) + E12(self.e023.0 * rhs.y.0) + E12(self.e3.0 * rhs.t.0),
+		//     e13  : E13(self.e0.0 * rhs.y.0) - E13(self.e012.0 * rhs.x.0) + E13(self.e023.0 * rhs.z.0) - E13(self.e2.0 * rhs.t.0),
+		//     e23  : -E23(self.e0.0 * rhs.x.0) - E23(self.e012.0 * rhs.y.0) - E23(self.e013.0 * rhs.z.0) + E23(self.e1.0 * rhs.t.0),
+		//     e012 : -E012(self.e03.0 * rhs.t.0) + E012(self.e13.0 * rhs.x.0) + E012(self.e23.0 * rhs.y.0) - E012(self.s.0 * rhs.z.0),
+		//     e013 : E013(self.e02.0 * rhs.t.0) - E013(self.e12.0 * rhs.x.0) + E013(self.e23.0 * rhs.z.0) + E013(self.s.0 * rhs.y.0),
+		//     e023 : -E023(self.e01.0 * rhs.t.0) - E023(self.e12.0 * rhs.y.0) - E023(self.e13.0 * rhs.z.0) - E023(self.s.0 * rhs.x.0),
+		//     e123 : -E123(self.e01.0 * rhs.x.0) - E123(self.e02.0 * rhs.y.0) - E123(self.e03.0 * rhs.z.0) - E123(self.s.0 * rhs.t.0),
+		//     e0123: E0123(self.e0.0 * rhs.t.0) - E0123(self.e1.0 * rhs.x.0) - E0123(self.e2.0 * rhs.y.0) - E0123(self.e3.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: self.e012.anti_geometric(rhs.z)
+				+ self.e013.anti_geometric(rhs.y)
+				+ self.e023.anti_geometric(rhs.x)
+				+ self.e123.anti_geometric(rhs.t),
+			e0: self.e0123.anti_geometric(rhs.t)
+				+ self.e12.anti_geometric(rhs.z)
+				+ self.e13.anti_geometric(rhs.y)
+				+ self.e23.anti_geometric(rhs.x),
+			e1: self.e0123.anti_geometric(rhs.x)
+				+ self.e02.anti_geometric(rhs.z)
+				+ self.e03.anti_geometric(rhs.y)
+				+ self.e23.anti_geometric(rhs.t),
+			e2: self.e01.anti_geometric(rhs.z)
+				+ self.e0123.anti_geometric(rhs.y)
+				+ self.e03.anti_geometric(rhs.x)
+				+ self.e13.anti_geometric(rhs.t),
+			e3: self.e01.anti_geometric(rhs.y)
+				+ self.e0123.anti_geometric(rhs.z)
+				+ self.e02.anti_geometric(rhs.x)
+				+ self.e12.anti_geometric(rhs.t),
+			e01: self.e023.anti_geometric(rhs.t)
+				+ self.e123.anti_geometric(rhs.x)
+				+ self.e2.anti_geometric(rhs.z)
+				+ self.e3.anti_geometric(rhs.y),
+			e02: self.e013.anti_geometric(rhs.t)
+				+ self.e1.anti_geometric(rhs.z)
+				+ self.e123.anti_geometric(rhs.y)
+				+ self.e3.anti_geometric(rhs.x),
+			e03: self.e012.anti_geometric(rhs.t)
+				+ self.e1.anti_geometric(rhs.y)
+				+ self.e123.anti_geometric(rhs.z)
+				+ self.e2.anti_geometric(rhs.x),
+			e12: self.e0.anti_geometric(rhs.z)
+				+ self.e013.anti_geometric(rhs.x)
+				+ self.e023.anti_geometric(rhs.y)
+				+ self.e3.anti_geometric(rhs.t),
+			e13: self.e0.anti_geometric(rhs.y)
+				+ self.e012.anti_geometric(rhs.x)
+				+ self.e023.anti_geometric(rhs.z)
+				+ self.e2.anti_geometric(rhs.t),
+			e23: self.e0.anti_geometric(rhs.x)
+				+ self.e012.anti_geometric(rhs.y)
+				+ self.e013.anti_geometric(rhs.z)
+				+ self.e1.anti_geometric(rhs.t),
+			e012: self.e03.anti_geometric(rhs.t)
+				+ self.e13.anti_geometric(rhs.x)
+				+ self.e23.anti_geometric(rhs.y)
+				+ self.s.anti_geometric(rhs.z),
+			e013: self.e02.anti_geometric(rhs.t)
+				+ self.e12.anti_geometric(rhs.x)
+				+ self.e23.anti_geometric(rhs.z)
+				+ self.s.anti_geometric(rhs.y),
+			e023: self.e01.anti_geometric(rhs.t)
+				+ self.e12.anti_geometric(rhs.y)
+				+ self.e13.anti_geometric(rhs.z)
+				+ self.s.anti_geometric(rhs.x),
+			e123: self.e01.anti_geometric(rhs.x)
+				+ self.e02.anti_geometric(rhs.y)
+				+ self.e03.anti_geometric(rhs.z)
+				+ self.s.anti_geometric(rhs.t),
+			e0123: self.e0.anti_geometric(rhs.t)
+				+ self.e1.anti_geometric(rhs.x)
+				+ self.e2.anti_geometric(rhs.y)
+				+ self.e3.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Multivector.dot(Vec4) -> Multivector
+impl Dot<Vec4> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0.0 * rhs.t.0) - S(self.e1.0 * rhs.x.0) - S(self.e2.0 * rhs.y.0) - S(self.e3.0 * rhs.z.0),
+		//     e0   : -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0) + E0(self.s.0 * rhs.t.0),
+		//     e1   : -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+		//     e2   : -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+		//     e3   : -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		//     e01  : -E01(self.e012.0 * rhs.y.0) - E01(self.e013.0 * rhs.z.0),
+		//     e02  : E02(self.e012.0 * rhs.x.0) - E02(self.e023.0 * rhs.z.0),
+		//     e03  : E03(self.e013.0 * rhs.x.0) + E03(self.e023.0 * rhs.y.0),
+		//     e12  : E12(self.e012.0 * rhs.t.0) - E12(self.e123.0 * rhs.z.0),
+		//     e13  : E13(self.e013.0 * rhs.t.0) + E13(self.e123.0 * rhs.y.0),
+		//     e23  : E23(self.e023.0 * rhs.t.0) - E23(self.e123.0 * rhs.x.0),
+		//     e012 : -E012(self.e0123.0 * rhs.z.0),
+		//     e013 : E013(self.e0123.0 * rhs.y.0),
+		//     e023 : -E023(self.e0123.0 * rhs.x.0),
+		//     e123 : -E123(self.e0123.0 * rhs.t.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e0.dot(rhs.t) + self.e1.dot(rhs.x) + self.e2.dot(rhs.y) + self.e3.dot(rhs.z),
+			e0: self.e01.dot(rhs.x) + self.e02.dot(rhs.y) + self.e03.dot(rhs.z) + self.s.dot(rhs.t),
+			e1: self.e01.dot(rhs.t) + self.e12.dot(rhs.y) + self.e13.dot(rhs.z) + self.s.dot(rhs.x),
+			e2: self.e02.dot(rhs.t) + self.e12.dot(rhs.x) + self.e23.dot(rhs.z) + self.s.dot(rhs.y),
+			e3: self.e03.dot(rhs.t) + self.e13.dot(rhs.x) + self.e23.dot(rhs.y) + self.s.dot(rhs.z),
+			e01: self.e012.dot(rhs.y) + self.e013.dot(rhs.z),
+			e02: self.e012.dot(rhs.x) + self.e023.dot(rhs.z),
+			e03: self.e013.dot(rhs.x) + self.e023.dot(rhs.y),
+			e12: self.e012.dot(rhs.t) + self.e123.dot(rhs.z),
+			e13: self.e013.dot(rhs.t) + self.e123.dot(rhs.y),
+			e23: self.e023.dot(rhs.t) + self.e123.dot(rhs.x),
+			e012: self.e0123.dot(rhs.z),
+			e013: self.e0123.dot(rhs.y),
+			e023: self.e0123.dot(rhs.x),
+			e123: self.e0123.dot(rhs.t),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec4> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Vec4) -> Multivector
+impl Wedge<Vec4> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.s.0 * rhs.t.0),
+		//     e1   : E1(self.s.0 * rhs.x.0),
+		//     e2   : E2(self.s.0 * rhs.y.0),
+		//     e3   : E3(self.s.0 * rhs.z.0),
+		//     e01  : E01(self.e0.0 * rhs.x.0) - E01(self.e1.0 * rhs.t.0),
+		//     e02  : E02(self.e0.0 * rhs.y.0) - E02(self.e2.0 * rhs.t.0),
+		//     e03  : E03(self.e0.0 * rhs.z.0) - E03(self.e3.0 * rhs.t.0),
+		//     e12  : E12(self.e1.0 * rhs.y.0) - E12(self.e2.0 * rhs.x.0),
+		//     e13  : E13(self.e1.0 * rhs.z.0) - E13(self.e3.0 * rhs.x.0),
+		//     e23  : E23(self.e2.0 * rhs.z.0) - E23(self.e3.0 * rhs.y.0),
+		//     e012 : E012(self.e01.0 * rhs.y.0) - E012(self.e02.0 * rhs.x.0) + E012(self.e12.0 * rhs.t.0),
+		//     e013 : E013(self.e01.0 * rhs.z.0) - E013(self.e03.0 * rhs.x.0) + E013(self.e13.0 * rhs.t.0),
+		//     e023 : E023(self.e02.0 * rhs.z.0) - E023(self.e03.0 * rhs.y.0) + E023(self.e23.0 * rhs.t.0),
+		//     e123 : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e0123: E0123(self.e012.0 * rhs.z.0) - E0123(self.e013.0 * rhs.y.0) + E0123(self.e023.0 * rhs.x.0) - E0123(self.e123.0 * rhs.t.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.s.wedge(rhs.t),
+			e1: self.s.wedge(rhs.x),
+			e2: self.s.wedge(rhs.y),
+			e3: self.s.wedge(rhs.z),
+			e01: self.e0.wedge(rhs.x) + self.e1.wedge(rhs.t),
+			e02: self.e0.wedge(rhs.y) + self.e2.wedge(rhs.t),
+			e03: self.e0.wedge(rhs.z) + self.e3.wedge(rhs.t),
+			e12: self.e1.wedge(rhs.y) + self.e2.wedge(rhs.x),
+			e13: self.e1.wedge(rhs.z) + self.e3.wedge(rhs.x),
+			e23: self.e2.wedge(rhs.z) + self.e3.wedge(rhs.y),
+			e012: self.e01.wedge(rhs.y) + self.e02.wedge(rhs.x) + self.e12.wedge(rhs.t),
+			e013: self.e01.wedge(rhs.z) + self.e03.wedge(rhs.x) + self.e13.wedge(rhs.t),
+			e023: self.e02.wedge(rhs.z) + self.e03.wedge(rhs.y) + self.e23.wedge(rhs.t),
+			e123: self.e12.wedge(rhs.z) + self.e13.wedge(rhs.y) + self.e23.wedge(rhs.x),
+			e0123: self.e012.wedge(rhs.z) + self.e013.wedge(rhs.y) + self.e023.wedge(rhs.x) + self.e123.wedge(rhs.t),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec4> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Vec4) -> Multivector
+impl AntiWedge<Vec4> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e012.0 * rhs.z.0) - S(self.e013.0 * rhs.y.0) + S(self.e023.0 * rhs.x.0) - S(self.e123.0 * rhs.t.0),
+		//     e0   : E0(self.e0123.0 * rhs.t.0),
+		//     e1   : E1(self.e0123.0 * rhs.x.0),
+		//     e2   : E2(self.e0123.0 * rhs.y.0),
+		//     e3   : E3(self.e0123.0 * rhs.z.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e012.anti_wedge(rhs.z)
+				+ self.e013.anti_wedge(rhs.y)
+				+ self.e023.anti_wedge(rhs.x)
+				+ self.e123.anti_wedge(rhs.t),
+			e0: self.e0123.anti_wedge(rhs.t),
+			e1: self.e0123.anti_wedge(rhs.x),
+			e2: self.e0123.anti_wedge(rhs.y),
+			e3: self.e0123.anti_wedge(rhs.z),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec4> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Vec4) -> Multivector
+impl LeftContraction<Vec4> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0.0 * rhs.t.0) - S(self.e1.0 * rhs.x.0) - S(self.e2.0 * rhs.y.0) - S(self.e3.0 * rhs.z.0),
+		//     e0   : E0(self.s.0 * rhs.t.0),
+		//     e1   : E1(self.s.0 * rhs.x.0),
+		//     e2   : E2(self.s.0 * rhs.y.0),
+		//     e3   : E3(self.s.0 * rhs.z.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e0.left_contraction(rhs.t)
+				+ self.e1.left_contraction(rhs.x)
+				+ self.e2.left_contraction(rhs.y)
+				+ self.e3.left_contraction(rhs.z),
+			e0: self.s.left_contraction(rhs.t),
+			e1: self.s.left_contraction(rhs.x),
+			e2: self.s.left_contraction(rhs.y),
+			e3: self.s.left_contraction(rhs.z),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Vec4) -> Multivector
+impl RightContraction<Vec4> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0.0 * rhs.t.0) - S(self.e1.0 * rhs.x.0) - S(self.e2.0 * rhs.y.0) - S(self.e3.0 * rhs.z.0),
+		//     e0   : -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0),
+		//     e1   : -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0),
+		//     e2   : -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0),
+		//     e3   : -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0),
+		//     e01  : -E01(self.e012.0 * rhs.y.0) - E01(self.e013.0 * rhs.z.0),
+		//     e02  : E02(self.e012.0 * rhs.x.0) - E02(self.e023.0 * rhs.z.0),
+		//     e03  : E03(self.e013.0 * rhs.x.0) + E03(self.e023.0 * rhs.y.0),
+		//     e12  : E12(self.e012.0 * rhs.t.0) - E12(self.e123.0 * rhs.z.0),
+		//     e13  : E13(self.e013.0 * rhs.t.0) + E13(self.e123.0 * rhs.y.0),
+		//     e23  : E23(self.e023.0 * rhs.t.0) - E23(self.e123.0 * rhs.x.0),
+		//     e012 : -E012(self.e0123.0 * rhs.z.0),
+		//     e013 : E013(self.e0123.0 * rhs.y.0),
+		//     e023 : -E023(self.e0123.0 * rhs.x.0),
+		//     e123 : -E123(self.e0123.0 * rhs.t.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e0.right_contraction(rhs.t)
+				+ self.e1.right_contraction(rhs.x)
+				+ self.e2.right_contraction(rhs.y)
+				+ self.e3.right_contraction(rhs.z),
+			e0: self.e01.right_contraction(rhs.x)
+				+ self.e02.right_contraction(rhs.y)
+				+ self.e03.right_contraction(rhs.z),
+			e1: self.e01.right_contraction(rhs.t)
+				+ self.e12.right_contraction(rhs.y)
+				+ self.e13.right_contraction(rhs.z),
+			e2: self.e02.right_contraction(rhs.t)
+				+ self.e12.right_contraction(rhs.x)
+				+ self.e23.right_contraction(rhs.z),
+			e3: self.e03.right_contraction(rhs.t)
+				+ self.e13.right_contraction(rhs.x)
+				+ self.e23.right_contraction(rhs.y),
+			e01: self.e012.right_contraction(rhs.y) + self.e013.right_contraction(rhs.z),
+			e02: self.e012.right_contraction(rhs.x) + self.e023.right_contraction(rhs.z),
+			e03: self.e013.right_contraction(rhs.x) + self.e023.right_contraction(rhs.y),
+			e12: self.e012.right_contraction(rhs.t) + self.e123.right_contraction(rhs.z),
+			e13: self.e013.right_contraction(rhs.t) + self.e123.right_contraction(rhs.y),
+			e23: self.e023.right_contraction(rhs.t) + self.e123.right_contraction(rhs.x),
+			e012: self.e0123.right_contraction(rhs.z),
+			e013: self.e0123.right_contraction(rhs.y),
+			e023: self.e0123.right_contraction(rhs.x),
+			e123: self.e0123.right_contraction(rhs.t),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Vec4) -> S
+impl ScalarProduct<Vec4> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec4) -> Self::Output {
+		// S(self.e0.0 * rhs.t.0) - S(self.e1.0 * rhs.x.0) - S(self.e2.0 * rhs.y.0) - S(self.e3.0 * rhs.z.0)
+		self.e0.scalar_product(rhs.t)
+			+ self.e1.scalar_product(rhs.x)
+			+ self.e2.scalar_product(rhs.y)
+			+ self.e3.scalar_product(rhs.z)
+	}
+}
+
+// Multivector.commutator(Vec4) -> Multivector
+impl Commutator<Vec4> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0),
+		//     e1   : -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0),
+		//     e2   : -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0),
+		//     e3   : -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0),
+		//     e01  : E01(self.e0.0 * rhs.x.0) - E01(self.e1.0 * rhs.t.0),
+		//     e02  : E02(self.e0.0 * rhs.y.0) - E02(self.e2.0 * rhs.t.0),
+		//     e03  : E03(self.e0.0 * rhs.z.0) - E03(self.e3.0 * rhs.t.0),
+		//     e12  : E12(self.e1.0 * rhs.y.0) - E12(self.e2.0 * rhs.x.0),
+		//     e13  : E13(self.e1.0 * rhs.z.0) - E13(self.e3.0 * rhs.x.0),
+		//     e23  : E23(self.e2.0 * rhs.z.0) - E23(self.e3.0 * rhs.y.0),
+		//     e012 : -E012(self.e0123.0 * rhs.z.0),
+		//     e013 : E013(self.e0123.0 * rhs.y.0),
+		//     e023 : -E023(self.e0123.0 * rhs.x.0),
+		//     e123 : -E123(self.e0123.0 * rhs.t.0),
+		//     e0123: E0123(self.e012.0 * rhs.z.0) - E0123(self.e013.0 * rhs.y.0) + E0123(self.e023.0 * rhs.x.0) - E0123(self.e123.0 * rhs.t.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e01.commutator(rhs.x) + self.e02.commutator(rhs.y) + self.e03.commutator(rhs.z),
+			e1: self.e01.commutator(rhs.t) + self.e12.commutator(rhs.y) + self.e13.commutator(rhs.z),
+			e2: self.e02.commutator(rhs.t) + self.e12.commutator(rhs.x) + self.e23.commutator(rhs.z),
+			e3: self.e03.commutator(rhs.t) + self.e13.commutator(rhs.x) + self.e23.commutator(rhs.y),
+			e01: self.e0.commutator(rhs.x) + self.e1.commutator(rhs.t),
+			e02: self.e0.commutator(rhs.y) + self.e2.commutator(rhs.t),
+			e03: self.e0.commutator(rhs.z) + self.e3.commutator(rhs.t),
+			e12: self.e1.commutator(rhs.y) + self.e2.commutator(rhs.x),
+			e13: self.e1.commutator(rhs.z) + self.e3.commutator(rhs.x),
+			e23: self.e2.commutator(rhs.z) + self.e3.commutator(rhs.y),
+			e012: self.e0123.commutator(rhs.z),
+			e013: self.e0123.commutator(rhs.y),
+			e023: self.e0123.commutator(rhs.x),
+			e123: self.e0123.commutator(rhs.t),
+			e0123: self.e012.commutator(rhs.z)
+				+ self.e013.commutator(rhs.y)
+				+ self.e023.commutator(rhs.x)
+				+ self.e123.commutator(rhs.t),
+		}
+	}
+}
+
+// Multivector.anti_dot(Vec4) -> Multivector
+impl AntiDot<Vec4> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.e0123.0 * rhs.t.0),
+		//     e1   : E1(self.e0123.0 * rhs.x.0),
+		//     e2   : E2(self.e0123.0 * rhs.y.0),
+		//     e3   : E3(self.e0123.0 * rhs.z.0),
+		//     e01  : -E01(self.e023.0 * rhs.t.0) + E01(self.e123.0 * rhs.x.0),
+		//     e02  : E02(self.e013.0 * rhs.t.0) + E02(self.e123.0 * rhs.y.0),
+		//     e03  : -E03(self.e012.0 * rhs.t.0) + E03(self.e123.0 * rhs.z.0),
+		//     e12  : E12(self.e013.0 * rhs.x.0) + E12(self.e023.0 * rhs.y.0),
+		//     e13  : -E13(self.e012.0 * rhs.x.0) + E13(self.e023.0 * rhs.z.0),
+		//     e23  : -E23(self.e012.0 * rhs.y.0) - E23(self.e013.0 * rhs.z.0),
+		//     e012 : -E012(self.e03.0 * rhs.t.0) + E012(self.e13.0 * rhs.x.0) + E012(self.e23.0 * rhs.y.0) - E012(self.s.0 * rhs.z.0),
+		//     e013 : E013(self.e02.0 * rhs.t.0) - E013(self.e12.0 * rhs.x.0) + E013(self.e23.0 * rhs.z.0) + E013(self.s.0 * rhs.y.0),
+		//     e023 : -E023(self.e01.0 * rhs.t.0) - E023(self.e12.0 * rhs.y.0) - E023(self.e13.0 * rhs.z.0) - E023(self.s.0 * rhs.x.0),
+		//     e123 : -E123(self.e01.0 * rhs.x.0) - E123(self.e02.0 * rhs.y.0) - E123(self.e03.0 * rhs.z.0) - E123(self.s.0 * rhs.t.0),
+		//     e0123: E0123(self.e0.0 * rhs.t.0) - E0123(self.e1.0 * rhs.x.0) - E0123(self.e2.0 * rhs.y.0) - E0123(self.e3.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e0123.anti_dot(rhs.t),
+			e1: self.e0123.anti_dot(rhs.x),
+			e2: self.e0123.anti_dot(rhs.y),
+			e3: self.e0123.anti_dot(rhs.z),
+			e01: self.e023.anti_dot(rhs.t) + self.e123.anti_dot(rhs.x),
+			e02: self.e013.anti_dot(rhs.t) + self.e123.anti_dot(rhs.y),
+			e03: self.e012.anti_dot(rhs.t) + self.e123.anti_dot(rhs.z),
+			e12: self.e013.anti_dot(rhs.x) + self.e023.anti_dot(rhs.y),
+			e13: self.e012.anti_dot(rhs.x) + self.e023.anti_dot(rhs.z),
+			e23: self.e012.anti_dot(rhs.y) + self.e013.anti_dot(rhs.z),
+			e012: self.e03.anti_dot(rhs.t)
+				+ self.e13.anti_dot(rhs.x)
+				+ self.e23.anti_dot(rhs.y)
+				+ self.s.anti_dot(rhs.z),
+			e013: self.e02.anti_dot(rhs.t)
+				+ self.e12.anti_dot(rhs.x)
+				+ self.e23.anti_dot(rhs.z)
+				+ self.s.anti_dot(rhs.y),
+			e023: self.e01.anti_dot(rhs.t)
+				+ self.e12.anti_dot(rhs.y)
+				+ self.e13.anti_dot(rhs.z)
+				+ self.s.anti_dot(rhs.x),
+			e123: self.e01.anti_dot(rhs.x)
+				+ self.e02.anti_dot(rhs.y)
+				+ self.e03.anti_dot(rhs.z)
+				+ self.s.anti_dot(rhs.t),
+			e0123: self.e0.anti_dot(rhs.t)
+				+ self.e1.anti_dot(rhs.x)
+				+ self.e2.anti_dot(rhs.y)
+				+ self.e3.anti_dot(rhs.z),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Bivector:
+
+// Multivector.geometric(Bivector) -> Multivector
+impl Geometric<Bivector> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e0   : -E0(self.e012.0 * rhs.e12.0) - E0(self.e013.0 * rhs.e13.0) - E0(self.e023.0 * rhs.e23.0) + E0(self.e1.0 * rhs.e01.0) + E0(self.e2.0 * rhs.e02.0) + E0(self.e3.0 * rhs.e03.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) - E1(self.e012.0 * rhs.e02.0) - E1(self.e013.0 * rhs.e03.0) - E1(self.e123.0 * rhs.e23.0) + E1(self.e2.0 * rhs.e12.0) + E1(self.e3.0 * rhs.e13.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) + E2(self.e012.0 * rhs.e01.0) - E2(self.e023.0 * rhs.e03.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e123.0 * rhs.e13.0) + E2(self.e3.0 * rhs.e23.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) + E3(self.e013.0 * rhs.e01.0) + E3(self.e023.0 * rhs.e02.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e123.0 * rhs.e12.0) - E3(self.e2.0 * rhs.e23.0),
+		//     e01  : -E01(self.e0123.0 * rhs.e23.0) + E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e0123.0 * rhs.e13.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e0123.0 * rhs.e12.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e0123.0 * rhs.e03.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) - E13(self.e0123.0 * rhs.e02.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e0123.0 * rhs.e01.0) - E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e0.0 * rhs.e12.0) + E012(self.e013.0 * rhs.e23.0) - E012(self.e023.0 * rhs.e13.0) - E012(self.e1.0 * rhs.e02.0) + E012(self.e123.0 * rhs.e03.0) + E012(self.e2.0 * rhs.e01.0),
+		//     e013 : E013(self.e0.0 * rhs.e13.0) - E013(self.e012.0 * rhs.e23.0) + E013(self.e023.0 * rhs.e12.0) - E013(self.e1.0 * rhs.e03.0) - E013(self.e123.0 * rhs.e02.0) + E013(self.e3.0 * rhs.e01.0),
+		//     e023 : E023(self.e0.0 * rhs.e23.0) + E023(self.e012.0 * rhs.e13.0) - E023(self.e013.0 * rhs.e12.0) + E023(self.e123.0 * rhs.e01.0) - E023(self.e2.0 * rhs.e03.0) + E023(self.e3.0 * rhs.e02.0),
+		//     e123 : E123(self.e012.0 * rhs.e03.0) - E123(self.e013.0 * rhs.e02.0) + E123(self.e023.0 * rhs.e01.0) + E123(self.e1.0 * rhs.e23.0) - E123(self.e2.0 * rhs.e13.0) + E123(self.e3.0 * rhs.e12.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0),
+		// }
+		Multivector {
+			s: self.e01.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e03)
+				+ self.e12.geometric(rhs.e12)
+				+ self.e13.geometric(rhs.e13)
+				+ self.e23.geometric(rhs.e23),
+			e0: self.e012.geometric(rhs.e12)
+				+ self.e013.geometric(rhs.e13)
+				+ self.e023.geometric(rhs.e23)
+				+ self.e1.geometric(rhs.e01)
+				+ self.e2.geometric(rhs.e02)
+				+ self.e3.geometric(rhs.e03),
+			e1: self.e0.geometric(rhs.e01)
+				+ self.e012.geometric(rhs.e02)
+				+ self.e013.geometric(rhs.e03)
+				+ self.e123.geometric(rhs.e23)
+				+ self.e2.geometric(rhs.e12)
+				+ self.e3.geometric(rhs.e13),
+			e2: self.e0.geometric(rhs.e02)
+				+ self.e012.geometric(rhs.e01)
+				+ self.e023.geometric(rhs.e03)
+				+ self.e1.geometric(rhs.e12)
+				+ self.e123.geometric(rhs.e13)
+				+ self.e3.geometric(rhs.e23),
+			e3: self.e0.geometric(rhs.e03)
+				+ self.e013.geometric(rhs.e01)
+				+ self.e023.geometric(rhs.e02)
+				+ self.e1.geometric(rhs.e13)
+				+ self.e123.geometric(rhs.e12)
+				+ self.e2.geometric(rhs.e23),
+			e01: self.e0123.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e12)
+				+ self.e03.geometric(rhs.e13)
+				+ self.e12.geometric(rhs.e02)
+				+ self.e13.geometric(rhs.e03)
+				+ self.s.geometric(rhs.e01),
+			e02: self.e01.geometric(rhs.e12)
+				+ self.e0123.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e23)
+				+ self.e12.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e03)
+				+ self.s.geometric(rhs.e02),
+			e03: self.e01.geometric(rhs.e13)
+				+ self.e0123.geometric(rhs.e12)
+				+ self.e02.geometric(rhs.e23)
+				+ self.e13.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e02)
+				+ self.s.geometric(rhs.e03),
+			e12: self.e01.geometric(rhs.e02)
+				+ self.e0123.geometric(rhs.e03)
+				+ self.e02.geometric(rhs.e01)
+				+ self.e13.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e13)
+				+ self.s.geometric(rhs.e12),
+			e13: self.e01.geometric(rhs.e03)
+				+ self.e0123.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e01)
+				+ self.e12.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e12)
+				+ self.s.geometric(rhs.e13),
+			e23: self.e0123.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e03)
+				+ self.e03.geometric(rhs.e02)
+				+ self.e12.geometric(rhs.e13)
+				+ self.e13.geometric(rhs.e12)
+				+ self.s.geometric(rhs.e23),
+			e012: self.e0.geometric(rhs.e12)
+				+ self.e013.geometric(rhs.e23)
+				+ self.e023.geometric(rhs.e13)
+				+ self.e1.geometric(rhs.e02)
+				+ self.e123.geometric(rhs.e03)
+				+ self.e2.geometric(rhs.e01),
+			e013: self.e0.geometric(rhs.e13)
+				+ self.e012.geometric(rhs.e23)
+				+ self.e023.geometric(rhs.e12)
+				+ self.e1.geometric(rhs.e03)
+				+ self.e123.geometric(rhs.e02)
+				+ self.e3.geometric(rhs.e01),
+			e023: self.e0.geometric(rhs.e23)
+				+ self.e012.geometric(rhs.e13)
+				+ self.e013.geometric(rhs.e12)
+				+ self.e123.geometric(rhs.e01)
+				+ self.e2.geometric(rhs.e03)
+				+ self.e3.geometric(rhs.e02),
+			e123: self.e012.geometric(rhs.e03)
+				+ self.e013.geometric(rhs.e02)
+				+ self.e023.geometric(rhs.e01)
+				+ self.e1.geometric(rhs.e23)
+				+ self.e2.geometric(rhs.e13)
+				+ self.e3.geometric(rhs.e12),
+			e0123: self.e01.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e12)
+				+ self.e12.geometric(rhs.e03)
+				+ self.e13.geometric(rhs.e02)
+				+ self.e23.geometric(rhs.e01),
+		}
+	}
+}
+
+impl std::ops::Mul<Bivector> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Bivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Bivector) -> Multivector
+impl AntiGeometric<Bivector> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e23.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0),
+		//     e0   : E0(self.e012.0 * rhs.e03.0) - E0(self.e013.0 * rhs.e02.0) + E0(self.e023.0 * rhs.e01.0) - E0(self.e1.0 * rhs.e23.0) + E0(self.e2.0 * rhs.e13.0) - E0(self.e3.0 * rhs.e12.0),
+		//     e1   : -E1(self.e0.0 * rhs.e23.0) + E1(self.e012.0 * rhs.e13.0) - E1(self.e013.0 * rhs.e12.0) + E1(self.e123.0 * rhs.e01.0) + E1(self.e2.0 * rhs.e03.0) - E1(self.e3.0 * rhs.e02.0),
+		//     e2   : E2(self.e0.0 * rhs.e13.0) + E2(self.e012.0 * rhs.e23.0) - E2(self.e023.0 * rhs.e12.0) - E2(self.e1.0 * rhs.e03.0) + E2(self.e123.0 * rhs.e02.0) + E2(self.e3.0 * rhs.e01.0),
+		//     e3   : -E3(self.e0.0 * rhs.e12.0) + E3(self.e013.0 * rhs.e23.0) - E3(self.e023.0 * rhs.e13.0) + E3(self.e1.0 * rhs.e02.0) + E3(self.e123.0 * rhs.e03.0) - E3(self.e2.0 * rhs.e01.0),
+		//     e01  : E01(self.e0123.0 * rhs.e01.0) + E01(self.e02.0 * rhs.e03.0) - E01(self.e03.0 * rhs.e02.0) - E01(self.e12.0 * rhs.e13.0) + E01(self.e13.0 * rhs.e12.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : -E02(self.e01.0 * rhs.e03.0) + E02(self.e0123.0 * rhs.e02.0) + E02(self.e03.0 * rhs.e01.0) - E02(self.e12.0 * rhs.e23.0) + E02(self.e23.0 * rhs.e12.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : E03(self.e01.0 * rhs.e02.0) + E03(self.e0123.0 * rhs.e03.0) - E03(self.e02.0 * rhs.e01.0) - E03(self.e13.0 * rhs.e23.0) + E03(self.e23.0 * rhs.e13.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : -E12(self.e01.0 * rhs.e13.0) + E12(self.e0123.0 * rhs.e12.0) - E12(self.e02.0 * rhs.e23.0) + E12(self.e13.0 * rhs.e01.0) + E12(self.e23.0 * rhs.e02.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e01.0 * rhs.e12.0) + E13(self.e0123.0 * rhs.e13.0) - E13(self.e03.0 * rhs.e23.0) - E13(self.e12.0 * rhs.e01.0) + E13(self.e23.0 * rhs.e03.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : E23(self.e0123.0 * rhs.e23.0) + E23(self.e02.0 * rhs.e12.0) + E23(self.e03.0 * rhs.e13.0) - E23(self.e12.0 * rhs.e02.0) - E23(self.e13.0 * rhs.e03.0) - E23(self.s.0 * rhs.e01.0),
+		//     e012 : -E012(self.e0.0 * rhs.e03.0) + E012(self.e013.0 * rhs.e01.0) + E012(self.e023.0 * rhs.e02.0) + E012(self.e1.0 * rhs.e13.0) - E012(self.e123.0 * rhs.e12.0) + E012(self.e2.0 * rhs.e23.0),
+		//     e013 : E013(self.e0.0 * rhs.e02.0) - E013(self.e012.0 * rhs.e01.0) + E013(self.e023.0 * rhs.e03.0) - E013(self.e1.0 * rhs.e12.0) - E013(self.e123.0 * rhs.e13.0) + E013(self.e3.0 * rhs.e23.0),
+		//     e023 : -E023(self.e0.0 * rhs.e01.0) - E023(self.e012.0 * rhs.e02.0) - E023(self.e013.0 * rhs.e03.0) - E023(self.e123.0 * rhs.e23.0) - E023(self.e2.0 * rhs.e12.0) - E023(self.e3.0 * rhs.e13.0),
+		//     e123 : -E123(self.e012.0 * rhs.e12.0) - E123(self.e013.0 * rhs.e13.0) - E123(self.e023.0 * rhs.e23.0) - E123(self.e1.0 * rhs.e01.0) - E123(self.e2.0 * rhs.e02.0) - E123(self.e3.0 * rhs.e03.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0),
+		// }
+		Multivector {
+			s: self.e01.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e12)
+				+ self.e12.anti_geometric(rhs.e03)
+				+ self.e13.anti_geometric(rhs.e02)
+				+ self.e23.anti_geometric(rhs.e01),
+			e0: self.e012.anti_geometric(rhs.e03)
+				+ self.e013.anti_geometric(rhs.e02)
+				+ self.e023.anti_geometric(rhs.e01)
+				+ self.e1.anti_geometric(rhs.e23)
+				+ self.e2.anti_geometric(rhs.e13)
+				+ self.e3.anti_geometric(rhs.e12),
+			e1: self.e0.anti_geometric(rhs.e23)
+				+ self.e012.anti_geometric(rhs.e13)
+				+ self.e013.anti_geometric(rhs.e12)
+				+ self.e123.anti_geometric(rhs.e01)
+				+ self.e2.anti_geometric(rhs.e03)
+				+ self.e3.anti_geometric(rhs.e02),
+			e2: self.e0.anti_geometric(rhs.e13)
+				+ self.e012.anti_geometric(rhs.e23)
+				+ self.e023.anti_geometric(rhs.e12)
+				+ self.e1.anti_geometric(rhs.e03)
+				+ self.e123.anti_geometric(rhs.e02)
+				+ self.e3.anti_geometric(rhs.e01),
+			e3: self.e0.anti_geometric(rhs.e12)
+				+ self.e013.anti_geometric(rhs.e23)
+				+ self.e023.anti_geometric(rhs.e13)
+				+ self.e1.anti_geometric(rhs.e02)
+				+ self.e123.anti_geometric(rhs.e03)
+				+ self.e2.anti_geometric(rhs.e01),
+			e01: self.e0123.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e03)
+				+ self.e03.anti_geometric(rhs.e02)
+				+ self.e12.anti_geometric(rhs.e13)
+				+ self.e13.anti_geometric(rhs.e12)
+				+ self.s.anti_geometric(rhs.e23),
+			e02: self.e01.anti_geometric(rhs.e03)
+				+ self.e0123.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e01)
+				+ self.e12.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e12)
+				+ self.s.anti_geometric(rhs.e13),
+			e03: self.e01.anti_geometric(rhs.e02)
+				+ self.e0123.anti_geometric(rhs.e03)
+				+ self.e02.anti_geometric(rhs.e01)
+				+ self.e13.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e13)
+				+ self.s.anti_geometric(rhs.e12),
+			e12: self.e01.anti_geometric(rhs.e13)
+				+ self.e0123.anti_geometric(rhs.e12)
+				+ self.e02.anti_geometric(rhs.e23)
+				+ self.e13.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e02)
+				+ self.s.anti_geometric(rhs.e03),
+			e13: self.e01.anti_geometric(rhs.e12)
+				+ self.e0123.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e23)
+				+ self.e12.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e03)
+				+ self.s.anti_geometric(rhs.e02),
+			e23: self.e0123.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e12)
+				+ self.e03.anti_geometric(rhs.e13)
+				+ self.e12.anti_geometric(rhs.e02)
+				+ self.e13.anti_geometric(rhs.e03)
+				+ self.s.anti_geometric(rhs.e01),
+			e012: self.e0.anti_geometric(rhs.e03)
+				+ self.e013.anti_geometric(rhs.e01)
+				+ self.e023.anti_geometric(rhs.e02)
+				+ self.e1.anti_geometric(rhs.e13)
+				+ self.e123.anti_geometric(rhs.e12)
+				+ self.e2.anti_geometric(rhs.e23),
+			e013: self.e0.anti_geometric(rhs.e02)
+				+ self.e012.anti_geometric(rhs.e01)
+				+ self.e023.anti_geometric(rhs.e03)
+				+ self.e1.anti_geometric(rhs.e12)
+				+ self.e123.anti_geometric(rhs.e13)
+				+ self.e3.anti_geometric(rhs.e23),
+			e023: self.e0.anti_geometric(rhs.e01)
+				+ self.e012.anti_geometric(rhs.e02)
+				+ self.e013.anti_geometric(rhs.e03)
+				+ self.e123.anti_geometric(rhs.e23)
+				+ self.e2.anti_geometric(rhs.e12)
+				+ self.e3.anti_geometric(rhs.e13),
+			e123: self.e012.anti_geometric(rhs.e12)
+				+ self.e013.anti_geometric(rhs.e13)
+				+ self.e023.anti_geometric(rhs.e23)
+				+ self.e1.anti_geometric(rhs.e01)
+				+ self.e2.anti_geometric(rhs.e02)
+				+ self.e3.anti_geometric(rhs.e03),
+			e0123: self.e01.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e03)
+				+ self.e12.anti_geometric(rhs.e12)
+				+ self.e13.anti_geometric(rhs.e13)
+				+ self.e23.anti_geometric(rhs.e23),
+		}
+	}
+}
+
+// Multivector.dot(Bivector) -> Multivector
+impl Dot<Bivector> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e0   : -E0(self.e012.0 * rhs.e12.0) - E0(self.e013.0 * rhs.e13.0) - E0(self.e023.0 * rhs.e23.0) + E0(self.e1.0 * rhs.e01.0) + E0(self.e2.0 * rhs.e02.0) + E0(self.e3.0 * rhs.e03.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) - E1(self.e012.0 * rhs.e02.0) - E1(self.e013.0 * rhs.e03.0) - E1(self.e123.0 * rhs.e23.0) + E1(self.e2.0 * rhs.e12.0) + E1(self.e3.0 * rhs.e13.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) + E2(self.e012.0 * rhs.e01.0) - E2(self.e023.0 * rhs.e03.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e123.0 * rhs.e13.0) + E2(self.e3.0 * rhs.e23.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) + E3(self.e013.0 * rhs.e01.0) + E3(self.e023.0 * rhs.e02.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e123.0 * rhs.e12.0) - E3(self.e2.0 * rhs.e23.0),
+		//     e01  : -E01(self.e0123.0 * rhs.e23.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e0123.0 * rhs.e13.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e0123.0 * rhs.e03.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e0123.0 * rhs.e01.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e01.dot(rhs.e01)
+				+ self.e02.dot(rhs.e02)
+				+ self.e03.dot(rhs.e03)
+				+ self.e12.dot(rhs.e12)
+				+ self.e13.dot(rhs.e13)
+				+ self.e23.dot(rhs.e23),
+			e0: self.e012.dot(rhs.e12)
+				+ self.e013.dot(rhs.e13)
+				+ self.e023.dot(rhs.e23)
+				+ self.e1.dot(rhs.e01)
+				+ self.e2.dot(rhs.e02)
+				+ self.e3.dot(rhs.e03),
+			e1: self.e0.dot(rhs.e01)
+				+ self.e012.dot(rhs.e02)
+				+ self.e013.dot(rhs.e03)
+				+ self.e123.dot(rhs.e23)
+				+ self.e2.dot(rhs.e12)
+				+ self.e3.dot(rhs.e13),
+			e2: self.e0.dot(rhs.e02)
+				+ self.e012.dot(rhs.e01)
+				+ self.e023.dot(rhs.e03)
+				+ self.e1.dot(rhs.e12)
+				+ self.e123.dot(rhs.e13)
+				+ self.e3.dot(rhs.e23),
+			e3: self.e0.dot(rhs.e03)
+				+ self.e013.dot(rhs.e01)
+				+ self.e023.dot(rhs.e02)
+				+ self.e1.dot(rhs.e13)
+				+ self.e123.dot(rhs.e12)
+				+ self.e2.dot(rhs.e23),
+			e01: self.e0123.dot(rhs.e23) + self.s.dot(rhs.e01),
+			e02: self.e0123.dot(rhs.e13) + self.s.dot(rhs.e02),
+			e03: self.e0123.dot(rhs.e12) + self.s.dot(rhs.e03),
+			e12: self.e0123.dot(rhs.e03) + self.s.dot(rhs.e12),
+			e13: self.e0123.dot(rhs.e02) + self.s.dot(rhs.e13),
+			e23: self.e0123.dot(rhs.e01) + self.s.dot(rhs.e23),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Bivector> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Bivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Bivector) -> Multivector
+impl Wedge<Bivector> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : Default::default(),
+		//     e1   : Default::default(),
+		//     e2   : Default::default(),
+		//     e3   : Default::default(),
+		//     e01  : E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.s.0 * rhs.e02.0),
+		//     e03  : E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e0.0 * rhs.e12.0) - E012(self.e1.0 * rhs.e02.0) + E012(self.e2.0 * rhs.e01.0),
+		//     e013 : E013(self.e0.0 * rhs.e13.0) - E013(self.e1.0 * rhs.e03.0) + E013(self.e3.0 * rhs.e01.0),
+		//     e023 : E023(self.e0.0 * rhs.e23.0) - E023(self.e2.0 * rhs.e03.0) + E023(self.e3.0 * rhs.e02.0),
+		//     e123 : E123(self.e1.0 * rhs.e23.0) - E123(self.e2.0 * rhs.e13.0) + E123(self.e3.0 * rhs.e12.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: self.s.wedge(rhs.e01),
+			e02: self.s.wedge(rhs.e02),
+			e03: self.s.wedge(rhs.e03),
+			e12: self.s.wedge(rhs.e12),
+			e13: self.s.wedge(rhs.e13),
+			e23: self.s.wedge(rhs.e23),
+			e012: self.e0.wedge(rhs.e12) + self.e1.wedge(rhs.e02) + self.e2.wedge(rhs.e01),
+			e013: self.e0.wedge(rhs.e13) + self.e1.wedge(rhs.e03) + self.e3.wedge(rhs.e01),
+			e023: self.e0.wedge(rhs.e23) + self.e2.wedge(rhs.e03) + self.e3.wedge(rhs.e02),
+			e123: self.e1.wedge(rhs.e23) + self.e2.wedge(rhs.e13) + self.e3.wedge(rhs.e12),
+			e0123: self.e01.wedge(rhs.e23)
+				+ self.e02.wedge(rhs.e13)
+				+ self.e03.wedge(rhs.e12)
+				+ self.e12.wedge(rhs.e03)
+				+ self.e13.wedge(rhs.e02)
+				+ self.e23.wedge(rhs.e01),
+		}
+	}
+}
+
+impl std::ops::BitXor<Bivector> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Bivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Bivector) -> Multivector
+impl AntiWedge<Bivector> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e23.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0),
+		//     e0   : E0(self.e012.0 * rhs.e03.0) - E0(self.e013.0 * rhs.e02.0) + E0(self.e023.0 * rhs.e01.0),
+		//     e1   : E1(self.e012.0 * rhs.e13.0) - E1(self.e013.0 * rhs.e12.0) + E1(self.e123.0 * rhs.e01.0),
+		//     e2   : E2(self.e012.0 * rhs.e23.0) - E2(self.e023.0 * rhs.e12.0) + E2(self.e123.0 * rhs.e02.0),
+		//     e3   : E3(self.e013.0 * rhs.e23.0) - E3(self.e023.0 * rhs.e13.0) + E3(self.e123.0 * rhs.e03.0),
+		//     e01  : E01(self.e0123.0 * rhs.e01.0),
+		//     e02  : E02(self.e0123.0 * rhs.e02.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0),
+		//     e23  : E23(self.e0123.0 * rhs.e23.0),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e01.anti_wedge(rhs.e23)
+				+ self.e02.anti_wedge(rhs.e13)
+				+ self.e03.anti_wedge(rhs.e12)
+				+ self.e12.anti_wedge(rhs.e03)
+				+ self.e13.anti_wedge(rhs.e02)
+				+ self.e23.anti_wedge(rhs.e01),
+			e0: self.e012.anti_wedge(rhs.e03) + self.e013.anti_wedge(rhs.e02) + self.e023.anti_wedge(rhs.e01),
+			e1: self.e012.anti_wedge(rhs.e13) + self.e013.anti_wedge(rhs.e12) + self.e123.anti_wedge(rhs.e01),
+			e2: self.e012.anti_wedge(rhs.e23) + self.e023.anti_wedge(rhs.e12) + self.e123.anti_wedge(rhs.e02),
+			e3: self.e013.anti_wedge(rhs.e23) + self.e023.anti_wedge(rhs.e13) + self.e123.anti_wedge(rhs.e03),
+			e01: self.e0123.anti_wedge(rhs.e01),
+			e02: self.e0123.anti_wedge(rhs.e02),
+			e03: self.e0123.anti_wedge(rhs.e03),
+			e12: self.e0123.anti_wedge(rhs.e12),
+			e13: self.e0123.anti_wedge(rhs.e13),
+			e23: self.e0123.anti_wedge(rhs.e23),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Bivector> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Bivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Bivector) -> Multivector
+impl LeftContraction<Bivector> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e0   : E0(self.e1.0 * rhs.e01.0) + E0(self.e2.0 * rhs.e02.0) + E0(self.e3.0 * rhs.e03.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) + E1(self.e2.0 * rhs.e12.0) + E1(self.e3.0 * rhs.e13.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e3.0 * rhs.e23.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e2.0 * rhs.e23.0),
+		//     e01  : E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.s.0 * rhs.e02.0),
+		//     e03  : E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e01.left_contraction(rhs.e01)
+				+ self.e02.left_contraction(rhs.e02)
+				+ self.e03.left_contraction(rhs.e03)
+				+ self.e12.left_contraction(rhs.e12)
+				+ self.e13.left_contraction(rhs.e13)
+				+ self.e23.left_contraction(rhs.e23),
+			e0: self.e1.left_contraction(rhs.e01)
+				+ self.e2.left_contraction(rhs.e02)
+				+ self.e3.left_contraction(rhs.e03),
+			e1: self.e0.left_contraction(rhs.e01)
+				+ self.e2.left_contraction(rhs.e12)
+				+ self.e3.left_contraction(rhs.e13),
+			e2: self.e0.left_contraction(rhs.e02)
+				+ self.e1.left_contraction(rhs.e12)
+				+ self.e3.left_contraction(rhs.e23),
+			e3: self.e0.left_contraction(rhs.e03)
+				+ self.e1.left_contraction(rhs.e13)
+				+ self.e2.left_contraction(rhs.e23),
+			e01: self.s.left_contraction(rhs.e01),
+			e02: self.s.left_contraction(rhs.e02),
+			e03: self.s.left_contraction(rhs.e03),
+			e12: self.s.left_contraction(rhs.e12),
+			e13: self.s.left_contraction(rhs.e13),
+			e23: self.s.left_contraction(rhs.e23),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Bivector) -> Multivector
+impl RightContraction<Bivector> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e0   : -E0(self.e012.0 * rhs.e12.0) - E0(self.e013.0 * rhs.e13.0) - E0(self.e023.0 * rhs.e23.0),
+		//     e1   : -E1(self.e012.0 * rhs.e02.0) - E1(self.e013.0 * rhs.e03.0) - E1(self.e123.0 * rhs.e23.0),
+		//     e2   : E2(self.e012.0 * rhs.e01.0) - E2(self.e023.0 * rhs.e03.0) + E2(self.e123.0 * rhs.e13.0),
+		//     e3   : E3(self.e013.0 * rhs.e01.0) + E3(self.e023.0 * rhs.e02.0) - E3(self.e123.0 * rhs.e12.0),
+		//     e01  : -E01(self.e0123.0 * rhs.e23.0),
+		//     e02  : E02(self.e0123.0 * rhs.e13.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0),
+		//     e12  : E12(self.e0123.0 * rhs.e03.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0),
+		//     e23  : E23(self.e0123.0 * rhs.e01.0),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.e01.right_contraction(rhs.e01)
+				+ self.e02.right_contraction(rhs.e02)
+				+ self.e03.right_contraction(rhs.e03)
+				+ self.e12.right_contraction(rhs.e12)
+				+ self.e13.right_contraction(rhs.e13)
+				+ self.e23.right_contraction(rhs.e23),
+			e0: self.e012.right_contraction(rhs.e12)
+				+ self.e013.right_contraction(rhs.e13)
+				+ self.e023.right_contraction(rhs.e23),
+			e1: self.e012.right_contraction(rhs.e02)
+				+ self.e013.right_contraction(rhs.e03)
+				+ self.e123.right_contraction(rhs.e23),
+			e2: self.e012.right_contraction(rhs.e01)
+				+ self.e023.right_contraction(rhs.e03)
+				+ self.e123.right_contraction(rhs.e13),
+			e3: self.e013.right_contraction(rhs.e01)
+				+ self.e023.right_contraction(rhs.e02)
+				+ self.e123.right_contraction(rhs.e12),
+			e01: self.e0123.right_contraction(rhs.e23),
+			e02: self.e0123.right_contraction(rhs.e13),
+			e03: self.e0123.right_contraction(rhs.e12),
+			e12: self.e0123.right_contraction(rhs.e03),
+			e13: self.e0123.right_contraction(rhs.e02),
+			e23: self.e0123.right_contraction(rhs.e01),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Bivector) -> S
+impl ScalarProduct<Bivector> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Bivector) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		self.e01.scalar_product(rhs.e01)
+			+ self.e02.scalar_product(rhs.e02)
+			+ self.e03.scalar_product(rhs.e03)
+			+ self.e12.scalar_product(rhs.e12)
+			+ self.e13.scalar_product(rhs.e13)
+			+ self.e23.scalar_product(rhs.e23)
+	}
+}
+
+// Multivector.commutator(Bivector) -> Multivector
+impl Commutator<Bivector> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.e1.0 * rhs.e01.0) + E0(self.e2.0 * rhs.e02.0) + E0(self.e3.0 * rhs.e03.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) + E1(self.e2.0 * rhs.e12.0) + E1(self.e3.0 * rhs.e13.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e3.0 * rhs.e23.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e2.0 * rhs.e23.0),
+		//     e01  : E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23  : -E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0),
+		//     e012 : E012(self.e013.0 * rhs.e23.0) - E012(self.e023.0 * rhs.e13.0) + E012(self.e123.0 * rhs.e03.0),
+		//     e013 : -E013(self.e012.0 * rhs.e23.0) + E013(self.e023.0 * rhs.e12.0) - E013(self.e123.0 * rhs.e02.0),
+		//     e023 : E023(self.e012.0 * rhs.e13.0) - E023(self.e013.0 * rhs.e12.0) + E023(self.e123.0 * rhs.e01.0),
+		//     e123 : E123(self.e012.0 * rhs.e03.0) - E123(self.e013.0 * rhs.e02.0) + E123(self.e023.0 * rhs.e01.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e1.commutator(rhs.e01) + self.e2.commutator(rhs.e02) + self.e3.commutator(rhs.e03),
+			e1: self.e0.commutator(rhs.e01) + self.e2.commutator(rhs.e12) + self.e3.commutator(rhs.e13),
+			e2: self.e0.commutator(rhs.e02) + self.e1.commutator(rhs.e12) + self.e3.commutator(rhs.e23),
+			e3: self.e0.commutator(rhs.e03) + self.e1.commutator(rhs.e13) + self.e2.commutator(rhs.e23),
+			e01: self.e02.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e13)
+				+ self.e12.commutator(rhs.e02)
+				+ self.e13.commutator(rhs.e03),
+			e02: self.e01.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e23)
+				+ self.e12.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e03),
+			e03: self.e01.commutator(rhs.e13)
+				+ self.e02.commutator(rhs.e23)
+				+ self.e13.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e02),
+			e12: self.e01.commutator(rhs.e02)
+				+ self.e02.commutator(rhs.e01)
+				+ self.e13.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e13),
+			e13: self.e01.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e01)
+				+ self.e12.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e12),
+			e23: self.e02.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e02)
+				+ self.e12.commutator(rhs.e13)
+				+ self.e13.commutator(rhs.e12),
+			e012: self.e013.commutator(rhs.e23) + self.e023.commutator(rhs.e13) + self.e123.commutator(rhs.e03),
+			e013: self.e012.commutator(rhs.e23) + self.e023.commutator(rhs.e12) + self.e123.commutator(rhs.e02),
+			e023: self.e012.commutator(rhs.e13) + self.e013.commutator(rhs.e12) + self.e123.commutator(rhs.e01),
+			e123: self.e012.commutator(rhs.e03) + self.e013.commutator(rhs.e02) + self.e023.commutator(rhs.e01),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Bivector) -> Multivector
+impl AntiDot<Bivector> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : Default::default(),
+		//     e1   : Default::default(),
+		//     e2   : Default::default(),
+		//     e3   : Default::default(),
+		//     e01  : E01(self.e0123.0 * rhs.e01.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : E02(self.e0123.0 * rhs.e02.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : E23(self.e0123.0 * rhs.e23.0) - E23(self.s.0 * rhs.e01.0),
+		//     e012 : -E012(self.e0.0 * rhs.e03.0) + E012(self.e013.0 * rhs.e01.0) + E012(self.e023.0 * rhs.e02.0) + E012(self.e1.0 * rhs.e13.0) - E012(self.e123.0 * rhs.e12.0) + E012(self.e2.0 * rhs.e23.0),
+		//     e013 : E013(self.e0.0 * rhs.e02.0) - E013(self.e012.0 * rhs.e01.0) + E013(self.e023.0 * rhs.e03.0) - E013(self.e1.0 * rhs.e12.0) - E013(self.e123.0 * rhs.e13.0) + E013(self.e3.0 * rhs.e23.0),
+		//     e023 : -E023(self.e0.0 * rhs.e01.0) - E023(self.e012.0 * rhs.e02.0) - E023(self.e013.0 * rhs.e03.0) - E023(self.e123.0 * rhs.e23.0) - E023(self.e2.0 * rhs.e12.0) - E023(self.e3.0 * rhs.e13.0),
+		//     e123 : -E123(self.e012.0 * rhs.e12.0) - E123(self.e013.0 * rhs.e13.0) - E123(self.e023.0 * rhs.e23.0) - E123(self.e1.0 * rhs.e01.0) - E123(self.e2.0 * rhs.e02.0) - E123(self.e3.0 * rhs.e03.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: self.e0123.anti_dot(rhs.e01) + self.s.anti_dot(rhs.e23),
+			e02: self.e0123.anti_dot(rhs.e02) + self.s.anti_dot(rhs.e13),
+			e03: self.e0123.anti_dot(rhs.e03) + self.s.anti_dot(rhs.e12),
+			e12: self.e0123.anti_dot(rhs.e12) + self.s.anti_dot(rhs.e03),
+			e13: self.e0123.anti_dot(rhs.e13) + self.s.anti_dot(rhs.e02),
+			e23: self.e0123.anti_dot(rhs.e23) + self.s.anti_dot(rhs.e01),
+			e012: self.e0.anti_dot(rhs.e03)
+				+ self.e013.anti_dot(rhs.e01)
+				+ self.e023.anti_dot(rhs.e02)
+				+ self.e1.anti_dot(rhs.e13)
+				+ self.e123.anti_dot(rhs.e12)
+				+ self.e2.anti_dot(rhs.e23),
+			e013: self.e0.anti_dot(rhs.e02)
+				+ self.e012.anti_dot(rhs.e01)
+				+ self.e023.anti_dot(rhs.e03)
+				+ self.e1.anti_dot(rhs.e12)
+				+ self.e123.anti_dot(rhs.e13)
+				+ self.e3.anti_dot(rhs.e23),
+			e023: self.e0.anti_dot(rhs.e01)
+				+ self.e012.anti_dot(rhs.e02)
+				+ self.e013.anti_dot(rhs.e03)
+				+ self.e123.anti_dot(rhs.e23)
+				+ self.e2.anti_dot(rhs.e12)
+				+ self.e3.anti_dot(rhs.e13),
+			e123: self.e012.anti_dot(rhs.e12)
+				+ self.e013.anti_dot(rhs.e13)
+				+ self.e023.anti_dot(rhs.e23)
+				+ self.e1.anti_dot(rhs.e01)
+				+ self.e2.anti_dot(rhs.e02)
+				+ self.e3.anti_dot(rhs.e03),
+			e0123: self.e01.anti_dot(rhs.e01)
+				+ self.e02.anti_dot(rhs.e02)
+				+ self.e03.anti_dot(rhs.e03)
+				+ self.e12.anti_dot(rhs.e12)
+				+ self.e13.anti_dot(rhs.e13)
+				+ self.e23.anti_dot(rhs.e23),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Rotor:
+
+// Multivector.geometric(Rotor) -> Multivector
+impl Geometric<Rotor> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.e0.0 * rhs.s.0) - E0(self.e012.0 * rhs.e12.0) - E0(self.e013.0 * rhs.e13.0) - E0(self.e023.0 * rhs.e23.0) + E0(self.e1.0 * rhs.e01.0) - E0(self.e123.0 * rhs.e0123.0) + E0(self.e2.0 * rhs.e02.0) + E0(self.e3.0 * rhs.e03.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) - E1(self.e012.0 * rhs.e02.0) - E1(self.e013.0 * rhs.e03.0) - E1(self.e023.0 * rhs.e0123.0) + E1(self.e1.0 * rhs.s.0) - E1(self.e123.0 * rhs.e23.0) + E1(self.e2.0 * rhs.e12.0) + E1(self.e3.0 * rhs.e13.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) + E2(self.e012.0 * rhs.e01.0) + E2(self.e013.0 * rhs.e0123.0) - E2(self.e023.0 * rhs.e03.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e123.0 * rhs.e13.0) + E2(self.e2.0 * rhs.s.0) + E2(self.e3.0 * rhs.e23.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) - E3(self.e012.0 * rhs.e0123.0) + E3(self.e013.0 * rhs.e01.0) + E3(self.e023.0 * rhs.e02.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e123.0 * rhs.e12.0) - E3(self.e2.0 * rhs.e23.0) + E3(self.e3.0 * rhs.s.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e0123.0 * rhs.e23.0) + E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0) - E01(self.e23.0 * rhs.e0123.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) + E02(self.e13.0 * rhs.e0123.0) - E02(self.e23.0 * rhs.e03.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e0123.0 * rhs.e12.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e03.0 * rhs.s.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e0123.0 * rhs.e03.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) - E13(self.e0123.0 * rhs.e02.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.e0123.0 * rhs.e01.0) - E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e0.0 * rhs.e12.0) + E012(self.e012.0 * rhs.s.0) + E012(self.e013.0 * rhs.e23.0) - E012(self.e023.0 * rhs.e13.0) - E012(self.e1.0 * rhs.e02.0) + E012(self.e123.0 * rhs.e03.0) + E012(self.e2.0 * rhs.e01.0) + E012(self.e3.0 * rhs.e0123.0),
+		//     e013 : E013(self.e0.0 * rhs.e13.0) - E013(self.e012.0 * rhs.e23.0) + E013(self.e013.0 * rhs.s.0) + E013(self.e023.0 * rhs.e12.0) - E013(self.e1.0 * rhs.e03.0) - E013(self.e123.0 * rhs.e02.0) - E013(self.e2.0 * rhs.e0123.0) + E013(self.e3.0 * rhs.e01.0),
+		//     e023 : E023(self.e0.0 * rhs.e23.0) + E023(self.e012.0 * rhs.e13.0) - E023(self.e013.0 * rhs.e12.0) + E023(self.e023.0 * rhs.s.0) + E023(self.e1.0 * rhs.e0123.0) + E023(self.e123.0 * rhs.e01.0) - E023(self.e2.0 * rhs.e03.0) + E023(self.e3.0 * rhs.e02.0),
+		//     e123 : E123(self.e0.0 * rhs.e0123.0) + E123(self.e012.0 * rhs.e03.0) - E123(self.e013.0 * rhs.e02.0) + E123(self.e023.0 * rhs.e01.0) + E123(self.e1.0 * rhs.e23.0) + E123(self.e123.0 * rhs.s.0) - E123(self.e2.0 * rhs.e13.0) + E123(self.e3.0 * rhs.e12.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) + E0123(self.e0123.0 * rhs.s.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e01.geometric(rhs.e01)
+				+ self.e0123.geometric(rhs.e0123)
+				+ self.e02.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e03)
+				+ self.e12.geometric(rhs.e12)
+				+ self.e13.geometric(rhs.e13)
+				+ self.e23.geometric(rhs.e23)
+				+ self.s.geometric(rhs.s),
+			e0: self.e0.geometric(rhs.s)
+				+ self.e012.geometric(rhs.e12)
+				+ self.e013.geometric(rhs.e13)
+				+ self.e023.geometric(rhs.e23)
+				+ self.e1.geometric(rhs.e01)
+				+ self.e123.geometric(rhs.e0123)
+				+ self.e2.geometric(rhs.e02)
+				+ self.e3.geometric(rhs.e03),
+			e1: self.e0.geometric(rhs.e01)
+				+ self.e012.geometric(rhs.e02)
+				+ self.e013.geometric(rhs.e03)
+				+ self.e023.geometric(rhs.e0123)
+				+ self.e1.geometric(rhs.s)
+				+ self.e123.geometric(rhs.e23)
+				+ self.e2.geometric(rhs.e12)
+				+ self.e3.geometric(rhs.e13),
+			e2: self.e0.geometric(rhs.e02)
+				+ self.e012.geometric(rhs.e01)
+				+ self.e013.geometric(rhs.e0123)
+				+ self.e023.geometric(rhs.e03)
+				+ self.e1.geometric(rhs.e12)
+				+ self.e123.geometric(rhs.e13)
+				+ self.e2.geometric(rhs.s)
+				+ self.e3.geometric(rhs.e23),
+			e3: self.e0.geometric(rhs.e03)
+				+ self.e012.geometric(rhs.e0123)
+				+ self.e013.geometric(rhs.e01)
+				+ self.e023.geometric(rhs.e02)
+				+ self.e1.geometric(rhs.e13)
+				+ self.e123.geometric(rhs.e12)
+				+ self.e2.geometric(rhs.e23)
+				+ self.e3.geometric(rhs.s),
+			e01: self.e01.geometric(rhs.s)
+				+ self.e0123.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e12)
+				+ self.e03.geometric(rhs.e13)
+				+ self.e12.geometric(rhs.e02)
+				+ self.e13.geometric(rhs.e03)
+				+ self.e23.geometric(rhs.e0123)
+				+ self.s.geometric(rhs.e01),
+			e02: self.e01.geometric(rhs.e12)
+				+ self.e0123.geometric(rhs.e13)
+				+ self.e02.geometric(rhs.s)
+				+ self.e03.geometric(rhs.e23)
+				+ self.e12.geometric(rhs.e01)
+				+ self.e13.geometric(rhs.e0123)
+				+ self.e23.geometric(rhs.e03)
+				+ self.s.geometric(rhs.e02),
+			e03: self.e01.geometric(rhs.e13)
+				+ self.e0123.geometric(rhs.e12)
+				+ self.e02.geometric(rhs.e23)
+				+ self.e03.geometric(rhs.s)
+				+ self.e12.geometric(rhs.e0123)
+				+ self.e13.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e02)
+				+ self.s.geometric(rhs.e03),
+			e12: self.e01.geometric(rhs.e02)
+				+ self.e0123.geometric(rhs.e03)
+				+ self.e02.geometric(rhs.e01)
+				+ self.e03.geometric(rhs.e0123)
+				+ self.e12.geometric(rhs.s)
+				+ self.e13.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e13)
+				+ self.s.geometric(rhs.e12),
+			e13: self.e01.geometric(rhs.e03)
+				+ self.e0123.geometric(rhs.e02)
+				+ self.e02.geometric(rhs.e0123)
+				+ self.e03.geometric(rhs.e01)
+				+ self.e12.geometric(rhs.e23)
+				+ self.e13.geometric(rhs.s)
+				+ self.e23.geometric(rhs.e12)
+				+ self.s.geometric(rhs.e13),
+			e23: self.e01.geometric(rhs.e0123)
+				+ self.e0123.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e03)
+				+ self.e03.geometric(rhs.e02)
+				+ self.e12.geometric(rhs.e13)
+				+ self.e13.geometric(rhs.e12)
+				+ self.e23.geometric(rhs.s)
+				+ self.s.geometric(rhs.e23),
+			e012: self.e0.geometric(rhs.e12)
+				+ self.e012.geometric(rhs.s)
+				+ self.e013.geometric(rhs.e23)
+				+ self.e023.geometric(rhs.e13)
+				+ self.e1.geometric(rhs.e02)
+				+ self.e123.geometric(rhs.e03)
+				+ self.e2.geometric(rhs.e01)
+				+ self.e3.geometric(rhs.e0123),
+			e013: self.e0.geometric(rhs.e13)
+				+ self.e012.geometric(rhs.e23)
+				+ self.e013.geometric(rhs.s)
+				+ self.e023.geometric(rhs.e12)
+				+ self.e1.geometric(rhs.e03)
+				+ self.e123.geometric(rhs.e02)
+				+ self.e2.geometric(rhs.e0123)
+				+ self.e3.geometric(rhs.e01),
+			e023: self.e0.geometric(rhs.e23)
+				+ self.e012.geometric(rhs.e13)
+				+ self.e013.geometric(rhs.e12)
+				+ self.e023.geometric(rhs.s)
+				+ self.e1.geometric(rhs.e0123)
+				+ self.e123.geometric(rhs.e01)
+				+ self.e2.geometric(rhs.e03)
+				+ self.e3.geometric(rhs.e02),
+			e123: self.e0.geometric(rhs.e0123)
+				+ self.e012.geometric(rhs.e03)
+				+ self.e013.geometric(rhs.e02)
+				+ self.e023.geometric(rhs.e01)
+				+ self.e1.geometric(rhs.e23)
+				+ self.e123.geometric(rhs.s)
+				+ self.e2.geometric(rhs.e13)
+				+ self.e3.geometric(rhs.e12),
+			e0123: self.e01.geometric(rhs.e23)
+				+ self.e0123.geometric(rhs.s)
+				+ self.e02.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e12)
+				+ self.e12.geometric(rhs.e03)
+				+ self.e13.geometric(rhs.e02)
+				+ self.e23.geometric(rhs.e01)
+				+ self.s.geometric(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Rotor) -> Multivector
+impl AntiGeometric<Rotor> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e23.0) + S(self.e0123.0 * rhs.s.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0) + S(self.s.0 * rhs.e0123.0),
+		//     e0   : E0(self.e0.0 * rhs.e0123.0) + E0(self.e012.0 * rhs.e03.0) - E0(self.e013.0 * rhs.e02.0) + E0(self.e023.0 * rhs.e01.0) - E0(self.e1.0 * rhs.e23.0) - E0(self.e123.0 * rhs.s.0) + E0(self.e2.0 * rhs.e13.0) - E0(self.e3.0 * rhs.e12.0),
+		//     e1   : -E1(self.e0.0 * rhs.e23.0) + E1(self.e012.0 * rhs.e13.0) - E1(self.e013.0 * rhs.e12.0) - E1(self.e023.0 * rhs.s.0) + E1(self.e1.0 * rhs.e0123.0) + E1(self.e123.0 * rhs.e01.0) + E1(self.e2.0 * rhs.e03.0) - E1(self.e3.0 * rhs.e02.0),
+		//     e2   : E2(self.e0.0 * rhs.e13.0) + E2(self.e012.0 * rhs.e23.0) + E2(self.e013.0 * rhs.s.0) - E2(self.e023.0 * rhs.e12.0) - E2(self.e1.0 * rhs.e03.0) + E2(self.e123.0 * rhs.e02.0) + E2(self.e2.0 * rhs.e0123.0) + E2(self.e3.0 * rhs.e01.0),
+		//     e3   : -E3(self.e0.0 * rhs.e12.0) - E3(self.e012.0 * rhs.s.0) + E3(self.e013.0 * rhs.e23.0) - E3(self.e023.0 * rhs.e13.0) + E3(self.e1.0 * rhs.e02.0) + E3(self.e123.0 * rhs.e03.0) - E3(self.e2.0 * rhs.e01.0) + E3(self.e3.0 * rhs.e0123.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e0123.0 * rhs.e01.0) + E01(self.e02.0 * rhs.e03.0) - E01(self.e03.0 * rhs.e02.0) - E01(self.e12.0 * rhs.e13.0) + E01(self.e13.0 * rhs.e12.0) + E01(self.e23.0 * rhs.s.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : -E02(self.e01.0 * rhs.e03.0) + E02(self.e0123.0 * rhs.e02.0) + E02(self.e02.0 * rhs.e0123.0) + E02(self.e03.0 * rhs.e01.0) - E02(self.e12.0 * rhs.e23.0) - E02(self.e13.0 * rhs.s.0) + E02(self.e23.0 * rhs.e12.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : E03(self.e01.0 * rhs.e02.0) + E03(self.e0123.0 * rhs.e03.0) - E03(self.e02.0 * rhs.e01.0) + E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0) - E03(self.e13.0 * rhs.e23.0) + E03(self.e23.0 * rhs.e13.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : -E12(self.e01.0 * rhs.e13.0) + E12(self.e0123.0 * rhs.e12.0) - E12(self.e02.0 * rhs.e23.0) - E12(self.e03.0 * rhs.s.0) + E12(self.e12.0 * rhs.e0123.0) + E12(self.e13.0 * rhs.e01.0) + E12(self.e23.0 * rhs.e02.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e01.0 * rhs.e12.0) + E13(self.e0123.0 * rhs.e13.0) + E13(self.e02.0 * rhs.s.0) - E13(self.e03.0 * rhs.e23.0) - E13(self.e12.0 * rhs.e01.0) + E13(self.e13.0 * rhs.e0123.0) + E13(self.e23.0 * rhs.e03.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) + E23(self.e0123.0 * rhs.e23.0) + E23(self.e02.0 * rhs.e12.0) + E23(self.e03.0 * rhs.e13.0) - E23(self.e12.0 * rhs.e02.0) - E23(self.e13.0 * rhs.e03.0) + E23(self.e23.0 * rhs.e0123.0) - E23(self.s.0 * rhs.e01.0),
+		//     e012 : -E012(self.e0.0 * rhs.e03.0) + E012(self.e012.0 * rhs.e0123.0) + E012(self.e013.0 * rhs.e01.0) + E012(self.e023.0 * rhs.e02.0) + E012(self.e1.0 * rhs.e13.0) - E012(self.e123.0 * rhs.e12.0) + E012(self.e2.0 * rhs.e23.0) + E012(self.e3.0 * rhs.s.0),
+		//     e013 : E013(self.e0.0 * rhs.e02.0) - E013(self.e012.0 * rhs.e01.0) + E013(self.e013.0 * rhs.e0123.0) + E013(self.e023.0 * rhs.e03.0) - E013(self.e1.0 * rhs.e12.0) - E013(self.e123.0 * rhs.e13.0) - E013(self.e2.0 * rhs.s.0) + E013(self.e3.0 * rhs.e23.0),
+		//     e023 : -E023(self.e0.0 * rhs.e01.0) - E023(self.e012.0 * rhs.e02.0) - E023(self.e013.0 * rhs.e03.0) + E023(self.e023.0 * rhs.e0123.0) + E023(self.e1.0 * rhs.s.0) - E023(self.e123.0 * rhs.e23.0) - E023(self.e2.0 * rhs.e12.0) - E023(self.e3.0 * rhs.e13.0),
+		//     e123 : E123(self.e0.0 * rhs.s.0) - E123(self.e012.0 * rhs.e12.0) - E123(self.e013.0 * rhs.e13.0) - E123(self.e023.0 * rhs.e23.0) - E123(self.e1.0 * rhs.e01.0) + E123(self.e123.0 * rhs.e0123.0) - E123(self.e2.0 * rhs.e02.0) - E123(self.e3.0 * rhs.e03.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) + E0123(self.e0123.0 * rhs.e0123.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0) - E0123(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.e01.anti_geometric(rhs.e23)
+				+ self.e0123.anti_geometric(rhs.s)
+				+ self.e02.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e12)
+				+ self.e12.anti_geometric(rhs.e03)
+				+ self.e13.anti_geometric(rhs.e02)
+				+ self.e23.anti_geometric(rhs.e01)
+				+ self.s.anti_geometric(rhs.e0123),
+			e0: self.e0.anti_geometric(rhs.e0123)
+				+ self.e012.anti_geometric(rhs.e03)
+				+ self.e013.anti_geometric(rhs.e02)
+				+ self.e023.anti_geometric(rhs.e01)
+				+ self.e1.anti_geometric(rhs.e23)
+				+ self.e123.anti_geometric(rhs.s)
+				+ self.e2.anti_geometric(rhs.e13)
+				+ self.e3.anti_geometric(rhs.e12),
+			e1: self.e0.anti_geometric(rhs.e23)
+				+ self.e012.anti_geometric(rhs.e13)
+				+ self.e013.anti_geometric(rhs.e12)
+				+ self.e023.anti_geometric(rhs.s)
+				+ self.e1.anti_geometric(rhs.e0123)
+				+ self.e123.anti_geometric(rhs.e01)
+				+ self.e2.anti_geometric(rhs.e03)
+				+ self.e3.anti_geometric(rhs.e02),
+			e2: self.e0.anti_geometric(rhs.e13)
+				+ self.e012.anti_geometric(rhs.e23)
+				+ self.e013.anti_geometric(rhs.s)
+				+ self.e023.anti_geometric(rhs.e12)
+				+ self.e1.anti_geometric(rhs.e03)
+				+ self.e123.anti_geometric(rhs.e02)
+				+ self.e2.anti_geometric(rhs.e0123)
+				+ self.e3.anti_geometric(rhs.e01),
+			e3: self.e0.anti_geometric(rhs.e12)
+				+ self.e012.anti_geometric(rhs.s)
+				+ self.e013.anti_geometric(rhs.e23)
+				+ self.e023.anti_geometric(rhs.e13)
+				+ self.e1.anti_geometric(rhs.e02)
+				+ self.e123.anti_geometric(rhs.e03)
+				+ self.e2.anti_geometric(rhs.e01)
+				+ self.e3.anti_geometric(rhs.e0123),
+			e01: self.e01.anti_geometric(rhs.e0123)
+				+ self.e0123.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e03)
+				+ self.e03.anti_geometric(rhs.e02)
+				+ self.e12.anti_geometric(rhs.e13)
+				+ self.e13.anti_geometric(rhs.e12)
+				+ self.e23.anti_geometric(rhs.s)
+				+ self.s.anti_geometric(rhs.e23),
+			e02: self.e01.anti_geometric(rhs.e03)
+				+ self.e0123.anti_geometric(rhs.e02)
+				+ self.e02.anti_geometric(rhs.e0123)
+				+ self.e03.anti_geometric(rhs.e01)
+				+ self.e12.anti_geometric(rhs.e23)
+				+ self.e13.anti_geometric(rhs.s)
+				+ self.e23.anti_geometric(rhs.e12)
+				+ self.s.anti_geometric(rhs.e13),
+			e03: self.e01.anti_geometric(rhs.e02)
+				+ self.e0123.anti_geometric(rhs.e03)
+				+ self.e02.anti_geometric(rhs.e01)
+				+ self.e03.anti_geometric(rhs.e0123)
+				+ self.e12.anti_geometric(rhs.s)
+				+ self.e13.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e13)
+				+ self.s.anti_geometric(rhs.e12),
+			e12: self.e01.anti_geometric(rhs.e13)
+				+ self.e0123.anti_geometric(rhs.e12)
+				+ self.e02.anti_geometric(rhs.e23)
+				+ self.e03.anti_geometric(rhs.s)
+				+ self.e12.anti_geometric(rhs.e0123)
+				+ self.e13.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e02)
+				+ self.s.anti_geometric(rhs.e03),
+			e13: self.e01.anti_geometric(rhs.e12)
+				+ self.e0123.anti_geometric(rhs.e13)
+				+ self.e02.anti_geometric(rhs.s)
+				+ self.e03.anti_geometric(rhs.e23)
+				+ self.e12.anti_geometric(rhs.e01)
+				+ self.e13.anti_geometric(rhs.e0123)
+				+ self.e23.anti_geometric(rhs.e03)
+				+ self.s.anti_geometric(rhs.e02),
+			e23: self.e01.anti_geometric(rhs.s)
+				+ self.e0123.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e12)
+				+ self.e03.anti_geometric(rhs.e13)
+				+ self.e12.anti_geometric(rhs.e02)
+				+ self.e13.anti_geometric(rhs.e03)
+				+ self.e23.anti_geometric(rhs.e0123)
+				+ self.s.anti_geometric(rhs.e01),
+			e012: self.e0.anti_geometric(rhs.e03)
+				+ self.e012.anti_geometric(rhs.e0123)
+				+ self.e013.anti_geometric(rhs.e01)
+				+ self.e023.anti_geometric(rhs.e02)
+				+ self.e1.anti_geometric(rhs.e13)
+				+ self.e123.anti_geometric(rhs.e12)
+				+ self.e2.anti_geometric(rhs.e23)
+				+ self.e3.anti_geometric(rhs.s),
+			e013: self.e0.anti_geometric(rhs.e02)
+				+ self.e012.anti_geometric(rhs.e01)
+				+ self.e013.anti_geometric(rhs.e0123)
+				+ self.e023.anti_geometric(rhs.e03)
+				+ self.e1.anti_geometric(rhs.e12)
+				+ self.e123.anti_geometric(rhs.e13)
+				+ self.e2.anti_geometric(rhs.s)
+				+ self.e3.anti_geometric(rhs.e23),
+			e023: self.e0.anti_geometric(rhs.e01)
+				+ self.e012.anti_geometric(rhs.e02)
+				+ self.e013.anti_geometric(rhs.e03)
+				+ self.e023.anti_geometric(rhs.e0123)
+				+ self.e1.anti_geometric(rhs.s)
+				+ self.e123.anti_geometric(rhs.e23)
+				+ self.e2.anti_geometric(rhs.e12)
+				+ self.e3.anti_geometric(rhs.e13),
+			e123: self.e0.anti_geometric(rhs.s)
+				+ self.e012.anti_geometric(rhs.e12)
+				+ self.e013.anti_geometric(rhs.e13)
+				+ self.e023.anti_geometric(rhs.e23)
+				+ self.e1.anti_geometric(rhs.e01)
+				+ self.e123.anti_geometric(rhs.e0123)
+				+ self.e2.anti_geometric(rhs.e02)
+				+ self.e3.anti_geometric(rhs.e03),
+			e0123: self.e01.anti_geometric(rhs.e01)
+				+ self.e0123.anti_geometric(rhs.e0123)
+				+ self.e02.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e03)
+				+ self.e12.anti_geometric(rhs.e12)
+				+ self.e13.anti_geometric(rhs.e13)
+				+ self.e23.anti_geometric(rhs.e23)
+				+ self.s.anti_geometric(rhs.s),
+		}
+	}
+}
+
+// Multivector.dot(Rotor) -> Multivector
+impl Dot<Rotor> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.e0.0 * rhs.s.0) - E0(self.e012.0 * rhs.e12.0) - E0(self.e013.0 * rhs.e13.0) - E0(self.e023.0 * rhs.e23.0) + E0(self.e1.0 * rhs.e01.0) - E0(self.e123.0 * rhs.e0123.0) + E0(self.e2.0 * rhs.e02.0) + E0(self.e3.0 * rhs.e03.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) - E1(self.e012.0 * rhs.e02.0) - E1(self.e013.0 * rhs.e03.0) - E1(self.e023.0 * rhs.e0123.0) + E1(self.e1.0 * rhs.s.0) - E1(self.e123.0 * rhs.e23.0) + E1(self.e2.0 * rhs.e12.0) + E1(self.e3.0 * rhs.e13.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) + E2(self.e012.0 * rhs.e01.0) + E2(self.e013.0 * rhs.e0123.0) - E2(self.e023.0 * rhs.e03.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e123.0 * rhs.e13.0) + E2(self.e2.0 * rhs.s.0) + E2(self.e3.0 * rhs.e23.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) - E3(self.e012.0 * rhs.e0123.0) + E3(self.e013.0 * rhs.e01.0) + E3(self.e023.0 * rhs.e02.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e123.0 * rhs.e12.0) - E3(self.e2.0 * rhs.e23.0) + E3(self.e3.0 * rhs.s.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e0123.0 * rhs.e23.0) - E01(self.e23.0 * rhs.e0123.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0) + E02(self.e13.0 * rhs.e0123.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0) + E03(self.e03.0 * rhs.s.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e0123.0 * rhs.e03.0) + E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.e0123.0 * rhs.e01.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e012.0 * rhs.s.0) + E012(self.e3.0 * rhs.e0123.0),
+		//     e013 : E013(self.e013.0 * rhs.s.0) - E013(self.e2.0 * rhs.e0123.0),
+		//     e023 : E023(self.e023.0 * rhs.s.0) + E023(self.e1.0 * rhs.e0123.0),
+		//     e123 : E123(self.e0.0 * rhs.e0123.0) + E123(self.e123.0 * rhs.s.0),
+		//     e0123: E0123(self.e0123.0 * rhs.s.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e01.dot(rhs.e01)
+				+ self.e0123.dot(rhs.e0123)
+				+ self.e02.dot(rhs.e02)
+				+ self.e03.dot(rhs.e03)
+				+ self.e12.dot(rhs.e12)
+				+ self.e13.dot(rhs.e13)
+				+ self.e23.dot(rhs.e23)
+				+ self.s.dot(rhs.s),
+			e0: self.e0.dot(rhs.s)
+				+ self.e012.dot(rhs.e12)
+				+ self.e013.dot(rhs.e13)
+				+ self.e023.dot(rhs.e23)
+				+ self.e1.dot(rhs.e01)
+				+ self.e123.dot(rhs.e0123)
+				+ self.e2.dot(rhs.e02)
+				+ self.e3.dot(rhs.e03),
+			e1: self.e0.dot(rhs.e01)
+				+ self.e012.dot(rhs.e02)
+				+ self.e013.dot(rhs.e03)
+				+ self.e023.dot(rhs.e0123)
+				+ self.e1.dot(rhs.s)
+				+ self.e123.dot(rhs.e23)
+				+ self.e2.dot(rhs.e12)
+				+ self.e3.dot(rhs.e13),
+			e2: self.e0.dot(rhs.e02)
+				+ self.e012.dot(rhs.e01)
+				+ self.e013.dot(rhs.e0123)
+				+ self.e023.dot(rhs.e03)
+				+ self.e1.dot(rhs.e12)
+				+ self.e123.dot(rhs.e13)
+				+ self.e2.dot(rhs.s)
+				+ self.e3.dot(rhs.e23),
+			e3: self.e0.dot(rhs.e03)
+				+ self.e012.dot(rhs.e0123)
+				+ self.e013.dot(rhs.e01)
+				+ self.e023.dot(rhs.e02)
+				+ self.e1.dot(rhs.e13)
+				+ self.e123.dot(rhs.e12)
+				+ self.e2.dot(rhs.e23)
+				+ self.e3.dot(rhs.s),
+			e01: self.e01.dot(rhs.s) + self.e0123.dot(rhs.e23) + self.e23.dot(rhs.e0123) + self.s.dot(rhs.e01),
+			e02: self.e0123.dot(rhs.e13) + self.e02.dot(rhs.s) + self.e13.dot(rhs.e0123) + self.s.dot(rhs.e02),
+			e03: self.e0123.dot(rhs.e12) + self.e03.dot(rhs.s) + self.e12.dot(rhs.e0123) + self.s.dot(rhs.e03),
+			e12: self.e0123.dot(rhs.e03) + self.e03.dot(rhs.e0123) + self.e12.dot(rhs.s) + self.s.dot(rhs.e12),
+			e13: self.e0123.dot(rhs.e02) + self.e02.dot(rhs.e0123) + self.e13.dot(rhs.s) + self.s.dot(rhs.e13),
+			e23: self.e01.dot(rhs.e0123) + self.e0123.dot(rhs.e01) + self.e23.dot(rhs.s) + self.s.dot(rhs.e23),
+			e012: self.e012.dot(rhs.s) + self.e3.dot(rhs.e0123),
+			e013: self.e013.dot(rhs.s) + self.e2.dot(rhs.e0123),
+			e023: self.e023.dot(rhs.s) + self.e1.dot(rhs.e0123),
+			e123: self.e0.dot(rhs.e0123) + self.e123.dot(rhs.s),
+			e0123: self.e0123.dot(rhs.s) + self.s.dot(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Rotor) -> Multivector
+impl Wedge<Rotor> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.e0.0 * rhs.s.0),
+		//     e1   : E1(self.e1.0 * rhs.s.0),
+		//     e2   : E2(self.e2.0 * rhs.s.0),
+		//     e3   : E3(self.e3.0 * rhs.s.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e02.0 * rhs.s.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : E03(self.e03.0 * rhs.s.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e0.0 * rhs.e12.0) + E012(self.e012.0 * rhs.s.0) - E012(self.e1.0 * rhs.e02.0) + E012(self.e2.0 * rhs.e01.0),
+		//     e013 : E013(self.e0.0 * rhs.e13.0) + E013(self.e013.0 * rhs.s.0) - E013(self.e1.0 * rhs.e03.0) + E013(self.e3.0 * rhs.e01.0),
+		//     e023 : E023(self.e0.0 * rhs.e23.0) + E023(self.e023.0 * rhs.s.0) - E023(self.e2.0 * rhs.e03.0) + E023(self.e3.0 * rhs.e02.0),
+		//     e123 : E123(self.e1.0 * rhs.e23.0) + E123(self.e123.0 * rhs.s.0) - E123(self.e2.0 * rhs.e13.0) + E123(self.e3.0 * rhs.e12.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) + E0123(self.e0123.0 * rhs.s.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			e0: self.e0.wedge(rhs.s),
+			e1: self.e1.wedge(rhs.s),
+			e2: self.e2.wedge(rhs.s),
+			e3: self.e3.wedge(rhs.s),
+			e01: self.e01.wedge(rhs.s) + self.s.wedge(rhs.e01),
+			e02: self.e02.wedge(rhs.s) + self.s.wedge(rhs.e02),
+			e03: self.e03.wedge(rhs.s) + self.s.wedge(rhs.e03),
+			e12: self.e12.wedge(rhs.s) + self.s.wedge(rhs.e12),
+			e13: self.e13.wedge(rhs.s) + self.s.wedge(rhs.e13),
+			e23: self.e23.wedge(rhs.s) + self.s.wedge(rhs.e23),
+			e012: self.e0.wedge(rhs.e12) + self.e012.wedge(rhs.s) + self.e1.wedge(rhs.e02) + self.e2.wedge(rhs.e01),
+			e013: self.e0.wedge(rhs.e13) + self.e013.wedge(rhs.s) + self.e1.wedge(rhs.e03) + self.e3.wedge(rhs.e01),
+			e023: self.e0.wedge(rhs.e23) + self.e023.wedge(rhs.s) + self.e2.wedge(rhs.e03) + self.e3.wedge(rhs.e02),
+			e123: self.e1.wedge(rhs.e23) + self.e123.wedge(rhs.s) + self.e2.wedge(rhs.e13) + self.e3.wedge(rhs.e12),
+			e0123: self.e01.wedge(rhs.e23)
+				+ self.e0123.wedge(rhs.s)
+				+ self.e02.wedge(rhs.e13)
+				+ self.e03.wedge(rhs.e12)
+				+ self.e12.wedge(rhs.e03)
+				+ self.e13.wedge(rhs.e02)
+				+ self.e23.wedge(rhs.e01)
+				+ self.s.wedge(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Rotor) -> Multivector
+impl AntiWedge<Rotor> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e23.0) + S(self.e0123.0 * rhs.s.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0) + S(self.s.0 * rhs.e0123.0),
+		//     e0   : E0(self.e0.0 * rhs.e0123.0) + E0(self.e012.0 * rhs.e03.0) - E0(self.e013.0 * rhs.e02.0) + E0(self.e023.0 * rhs.e01.0),
+		//     e1   : E1(self.e012.0 * rhs.e13.0) - E1(self.e013.0 * rhs.e12.0) + E1(self.e1.0 * rhs.e0123.0) + E1(self.e123.0 * rhs.e01.0),
+		//     e2   : E2(self.e012.0 * rhs.e23.0) - E2(self.e023.0 * rhs.e12.0) + E2(self.e123.0 * rhs.e02.0) + E2(self.e2.0 * rhs.e0123.0),
+		//     e3   : E3(self.e013.0 * rhs.e23.0) - E3(self.e023.0 * rhs.e13.0) + E3(self.e123.0 * rhs.e03.0) + E3(self.e3.0 * rhs.e0123.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e0123.0 * rhs.e01.0),
+		//     e02  : E02(self.e0123.0 * rhs.e02.0) + E02(self.e02.0 * rhs.e0123.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0) + E03(self.e03.0 * rhs.e0123.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0) + E12(self.e12.0 * rhs.e0123.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0) + E13(self.e13.0 * rhs.e0123.0),
+		//     e23  : E23(self.e0123.0 * rhs.e23.0) + E23(self.e23.0 * rhs.e0123.0),
+		//     e012 : E012(self.e012.0 * rhs.e0123.0),
+		//     e013 : E013(self.e013.0 * rhs.e0123.0),
+		//     e023 : E023(self.e023.0 * rhs.e0123.0),
+		//     e123 : E123(self.e123.0 * rhs.e0123.0),
+		//     e0123: E0123(self.e0123.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e01.anti_wedge(rhs.e23)
+				+ self.e0123.anti_wedge(rhs.s)
+				+ self.e02.anti_wedge(rhs.e13)
+				+ self.e03.anti_wedge(rhs.e12)
+				+ self.e12.anti_wedge(rhs.e03)
+				+ self.e13.anti_wedge(rhs.e02)
+				+ self.e23.anti_wedge(rhs.e01)
+				+ self.s.anti_wedge(rhs.e0123),
+			e0: self.e0.anti_wedge(rhs.e0123)
+				+ self.e012.anti_wedge(rhs.e03)
+				+ self.e013.anti_wedge(rhs.e02)
+				+ self.e023.anti_wedge(rhs.e01),
+			e1: self.e012.anti_wedge(rhs.e13)
+				+ self.e013.anti_wedge(rhs.e12)
+				+ self.e1.anti_wedge(rhs.e0123)
+				+ self.e123.anti_wedge(rhs.e01),
+			e2: self.e012.anti_wedge(rhs.e23)
+				+ self.e023.anti_wedge(rhs.e12)
+				+ self.e123.anti_wedge(rhs.e02)
+				+ self.e2.anti_wedge(rhs.e0123),
+			e3: self.e013.anti_wedge(rhs.e23)
+				+ self.e023.anti_wedge(rhs.e13)
+				+ self.e123.anti_wedge(rhs.e03)
+				+ self.e3.anti_wedge(rhs.e0123),
+			e01: self.e01.anti_wedge(rhs.e0123) + self.e0123.anti_wedge(rhs.e01),
+			e02: self.e0123.anti_wedge(rhs.e02) + self.e02.anti_wedge(rhs.e0123),
+			e03: self.e0123.anti_wedge(rhs.e03) + self.e03.anti_wedge(rhs.e0123),
+			e12: self.e0123.anti_wedge(rhs.e12) + self.e12.anti_wedge(rhs.e0123),
+			e13: self.e0123.anti_wedge(rhs.e13) + self.e13.anti_wedge(rhs.e0123),
+			e23: self.e0123.anti_wedge(rhs.e23) + self.e23.anti_wedge(rhs.e0123),
+			e012: self.e012.anti_wedge(rhs.e0123),
+			e013: self.e013.anti_wedge(rhs.e0123),
+			e023: self.e023.anti_wedge(rhs.e0123),
+			e123: self.e123.anti_wedge(rhs.e0123),
+			e0123: self.e0123.anti_wedge(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Rotor) -> Multivector
+impl LeftContraction<Rotor> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.e1.0 * rhs.e01.0) - E0(self.e123.0 * rhs.e0123.0) + E0(self.e2.0 * rhs.e02.0) + E0(self.e3.0 * rhs.e03.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) - E1(self.e023.0 * rhs.e0123.0) + E1(self.e2.0 * rhs.e12.0) + E1(self.e3.0 * rhs.e13.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) + E2(self.e013.0 * rhs.e0123.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e3.0 * rhs.e23.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) - E3(self.e012.0 * rhs.e0123.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e2.0 * rhs.e23.0),
+		//     e01  : -E01(self.e23.0 * rhs.e0123.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e13.0 * rhs.e0123.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e12.0 * rhs.e0123.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e03.0 * rhs.e0123.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e02.0 * rhs.e0123.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e3.0 * rhs.e0123.0),
+		//     e013 : -E013(self.e2.0 * rhs.e0123.0),
+		//     e023 : E023(self.e1.0 * rhs.e0123.0),
+		//     e123 : E123(self.e0.0 * rhs.e0123.0),
+		//     e0123: E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e01.left_contraction(rhs.e01)
+				+ self.e0123.left_contraction(rhs.e0123)
+				+ self.e02.left_contraction(rhs.e02)
+				+ self.e03.left_contraction(rhs.e03)
+				+ self.e12.left_contraction(rhs.e12)
+				+ self.e13.left_contraction(rhs.e13)
+				+ self.e23.left_contraction(rhs.e23)
+				+ self.s.left_contraction(rhs.s),
+			e0: self.e1.left_contraction(rhs.e01)
+				+ self.e123.left_contraction(rhs.e0123)
+				+ self.e2.left_contraction(rhs.e02)
+				+ self.e3.left_contraction(rhs.e03),
+			e1: self.e0.left_contraction(rhs.e01)
+				+ self.e023.left_contraction(rhs.e0123)
+				+ self.e2.left_contraction(rhs.e12)
+				+ self.e3.left_contraction(rhs.e13),
+			e2: self.e0.left_contraction(rhs.e02)
+				+ self.e013.left_contraction(rhs.e0123)
+				+ self.e1.left_contraction(rhs.e12)
+				+ self.e3.left_contraction(rhs.e23),
+			e3: self.e0.left_contraction(rhs.e03)
+				+ self.e012.left_contraction(rhs.e0123)
+				+ self.e1.left_contraction(rhs.e13)
+				+ self.e2.left_contraction(rhs.e23),
+			e01: self.e23.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e01),
+			e02: self.e13.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e02),
+			e03: self.e12.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e03),
+			e12: self.e03.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e12),
+			e13: self.e02.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e13),
+			e23: self.e01.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e23),
+			e012: self.e3.left_contraction(rhs.e0123),
+			e013: self.e2.left_contraction(rhs.e0123),
+			e023: self.e1.left_contraction(rhs.e0123),
+			e123: self.e0.left_contraction(rhs.e0123),
+			e0123: self.s.left_contraction(rhs.e0123),
+		}
+	}
+}
+
+// Multivector.right_contraction(Rotor) -> Multivector
+impl RightContraction<Rotor> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.e0.0 * rhs.s.0) - E0(self.e012.0 * rhs.e12.0) - E0(self.e013.0 * rhs.e13.0) - E0(self.e023.0 * rhs.e23.0),
+		//     e1   : -E1(self.e012.0 * rhs.e02.0) - E1(self.e013.0 * rhs.e03.0) + E1(self.e1.0 * rhs.s.0) - E1(self.e123.0 * rhs.e23.0),
+		//     e2   : E2(self.e012.0 * rhs.e01.0) - E2(self.e023.0 * rhs.e03.0) + E2(self.e123.0 * rhs.e13.0) + E2(self.e2.0 * rhs.s.0),
+		//     e3   : E3(self.e013.0 * rhs.e01.0) + E3(self.e023.0 * rhs.e02.0) - E3(self.e123.0 * rhs.e12.0) + E3(self.e3.0 * rhs.s.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e0123.0 * rhs.e23.0),
+		//     e02  : E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0) + E03(self.e03.0 * rhs.s.0),
+		//     e12  : E12(self.e0123.0 * rhs.e03.0) + E12(self.e12.0 * rhs.s.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0) + E13(self.e13.0 * rhs.s.0),
+		//     e23  : E23(self.e0123.0 * rhs.e01.0) + E23(self.e23.0 * rhs.s.0),
+		//     e012 : E012(self.e012.0 * rhs.s.0),
+		//     e013 : E013(self.e013.0 * rhs.s.0),
+		//     e023 : E023(self.e023.0 * rhs.s.0),
+		//     e123 : E123(self.e123.0 * rhs.s.0),
+		//     e0123: E0123(self.e0123.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.e01.right_contraction(rhs.e01)
+				+ self.e0123.right_contraction(rhs.e0123)
+				+ self.e02.right_contraction(rhs.e02)
+				+ self.e03.right_contraction(rhs.e03)
+				+ self.e12.right_contraction(rhs.e12)
+				+ self.e13.right_contraction(rhs.e13)
+				+ self.e23.right_contraction(rhs.e23)
+				+ self.s.right_contraction(rhs.s),
+			e0: self.e0.right_contraction(rhs.s)
+				+ self.e012.right_contraction(rhs.e12)
+				+ self.e013.right_contraction(rhs.e13)
+				+ self.e023.right_contraction(rhs.e23),
+			e1: self.e012.right_contraction(rhs.e02)
+				+ self.e013.right_contraction(rhs.e03)
+				+ self.e1.right_contraction(rhs.s)
+				+ self.e123.right_contraction(rhs.e23),
+			e2: self.e012.right_contraction(rhs.e01)
+				+ self.e023.right_contraction(rhs.e03)
+				+ self.e123.right_contraction(rhs.e13)
+				+ self.e2.right_contraction(rhs.s),
+			e3: self.e013.right_contraction(rhs.e01)
+				+ self.e023.right_contraction(rhs.e02)
+				+ self.e123.right_contraction(rhs.e12)
+				+ self.e3.right_contraction(rhs.s),
+			e01: self.e01.right_contraction(rhs.s) + self.e0123.right_contraction(rhs.e23),
+			e02: self.e0123.right_contraction(rhs.e13) + self.e02.right_contraction(rhs.s),
+			e03: self.e0123.right_contraction(rhs.e12) + self.e03.right_contraction(rhs.s),
+			e12: self.e0123.right_contraction(rhs.e03) + self.e12.right_contraction(rhs.s),
+			e13: self.e0123.right_contraction(rhs.e02) + self.e13.right_contraction(rhs.s),
+			e23: self.e0123.right_contraction(rhs.e01) + self.e23.right_contraction(rhs.s),
+			e012: self.e012.right_contraction(rhs.s),
+			e013: self.e013.right_contraction(rhs.s),
+			e023: self.e023.right_contraction(rhs.s),
+			e123: self.e123.right_contraction(rhs.s),
+			e0123: self.e0123.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Multivector.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+		self.e01.scalar_product(rhs.e01)
+			+ self.e0123.scalar_product(rhs.e0123)
+			+ self.e02.scalar_product(rhs.e02)
+			+ self.e03.scalar_product(rhs.e03)
+			+ self.e12.scalar_product(rhs.e12)
+			+ self.e13.scalar_product(rhs.e13)
+			+ self.e23.scalar_product(rhs.e23)
+			+ self.s.scalar_product(rhs.s)
+	}
+}
+
+// Multivector.commutator(Rotor) -> Multivector
+impl Commutator<Rotor> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.e1.0 * rhs.e01.0) - E0(self.e123.0 * rhs.e0123.0) + E0(self.e2.0 * rhs.e02.0) + E0(self.e3.0 * rhs.e03.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) - E1(self.e023.0 * rhs.e0123.0) + E1(self.e2.0 * rhs.e12.0) + E1(self.e3.0 * rhs.e13.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) + E2(self.e013.0 * rhs.e0123.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e3.0 * rhs.e23.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) - E3(self.e012.0 * rhs.e0123.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e2.0 * rhs.e23.0),
+		//     e01  : E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23  : -E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0),
+		//     e012 : E012(self.e013.0 * rhs.e23.0) - E012(self.e023.0 * rhs.e13.0) + E012(self.e123.0 * rhs.e03.0) + E012(self.e3.0 * rhs.e0123.0),
+		//     e013 : -E013(self.e012.0 * rhs.e23.0) + E013(self.e023.0 * rhs.e12.0) - E013(self.e123.0 * rhs.e02.0) - E013(self.e2.0 * rhs.e0123.0),
+		//     e023 : E023(self.e012.0 * rhs.e13.0) - E023(self.e013.0 * rhs.e12.0) + E023(self.e1.0 * rhs.e0123.0) + E023(self.e123.0 * rhs.e01.0),
+		//     e123 : E123(self.e0.0 * rhs.e0123.0) + E123(self.e012.0 * rhs.e03.0) - E123(self.e013.0 * rhs.e02.0) + E123(self.e023.0 * rhs.e01.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e1.commutator(rhs.e01)
+				+ self.e123.commutator(rhs.e0123)
+				+ self.e2.commutator(rhs.e02)
+				+ self.e3.commutator(rhs.e03),
+			e1: self.e0.commutator(rhs.e01)
+				+ self.e023.commutator(rhs.e0123)
+				+ self.e2.commutator(rhs.e12)
+				+ self.e3.commutator(rhs.e13),
+			e2: self.e0.commutator(rhs.e02)
+				+ self.e013.commutator(rhs.e0123)
+				+ self.e1.commutator(rhs.e12)
+				+ self.e3.commutator(rhs.e23),
+			e3: self.e0.commutator(rhs.e03)
+				+ self.e012.commutator(rhs.e0123)
+				+ self.e1.commutator(rhs.e13)
+				+ self.e2.commutator(rhs.e23),
+			e01: self.e02.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e13)
+				+ self.e12.commutator(rhs.e02)
+				+ self.e13.commutator(rhs.e03),
+			e02: self.e01.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e23)
+				+ self.e12.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e03),
+			e03: self.e01.commutator(rhs.e13)
+				+ self.e02.commutator(rhs.e23)
+				+ self.e13.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e02),
+			e12: self.e01.commutator(rhs.e02)
+				+ self.e02.commutator(rhs.e01)
+				+ self.e13.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e13),
+			e13: self.e01.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e01)
+				+ self.e12.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e12),
+			e23: self.e02.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e02)
+				+ self.e12.commutator(rhs.e13)
+				+ self.e13.commutator(rhs.e12),
+			e012: self.e013.commutator(rhs.e23)
+				+ self.e023.commutator(rhs.e13)
+				+ self.e123.commutator(rhs.e03)
+				+ self.e3.commutator(rhs.e0123),
+			e013: self.e012.commutator(rhs.e23)
+				+ self.e023.commutator(rhs.e12)
+				+ self.e123.commutator(rhs.e02)
+				+ self.e2.commutator(rhs.e0123),
+			e023: self.e012.commutator(rhs.e13)
+				+ self.e013.commutator(rhs.e12)
+				+ self.e1.commutator(rhs.e0123)
+				+ self.e123.commutator(rhs.e01),
+			e123: self.e0.commutator(rhs.e0123)
+				+ self.e012.commutator(rhs.e03)
+				+ self.e013.commutator(rhs.e02)
+				+ self.e023.commutator(rhs.e01),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Rotor) -> Multivector
+impl AntiDot<Rotor> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0123.0 * rhs.s.0) + S(self.s.0 * rhs.e0123.0),
+		//     e0   : E0(self.e0.0 * rhs.e0123.0) - E0(self.e123.0 * rhs.s.0),
+		//     e1   : -E1(self.e023.0 * rhs.s.0) + E1(self.e1.0 * rhs.e0123.0),
+		//     e2   : E2(self.e013.0 * rhs.s.0) + E2(self.e2.0 * rhs.e0123.0),
+		//     e3   : -E3(self.e012.0 * rhs.s.0) + E3(self.e3.0 * rhs.e0123.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e0123.0 * rhs.e01.0) + E01(self.e23.0 * rhs.s.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : E02(self.e0123.0 * rhs.e02.0) + E02(self.e02.0 * rhs.e0123.0) - E02(self.e13.0 * rhs.s.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0) + E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0) - E12(self.e03.0 * rhs.s.0) + E12(self.e12.0 * rhs.e0123.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0) + E13(self.e02.0 * rhs.s.0) + E13(self.e13.0 * rhs.e0123.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) + E23(self.e0123.0 * rhs.e23.0) + E23(self.e23.0 * rhs.e0123.0) - E23(self.s.0 * rhs.e01.0),
+		//     e012 : -E012(self.e0.0 * rhs.e03.0) + E012(self.e012.0 * rhs.e0123.0) + E012(self.e013.0 * rhs.e01.0) + E012(self.e023.0 * rhs.e02.0) + E012(self.e1.0 * rhs.e13.0) - E012(self.e123.0 * rhs.e12.0) + E012(self.e2.0 * rhs.e23.0) + E012(self.e3.0 * rhs.s.0),
+		//     e013 : E013(self.e0.0 * rhs.e02.0) - E013(self.e012.0 * rhs.e01.0) + E013(self.e013.0 * rhs.e0123.0) + E013(self.e023.0 * rhs.e03.0) - E013(self.e1.0 * rhs.e12.0) - E013(self.e123.0 * rhs.e13.0) - E013(self.e2.0 * rhs.s.0) + E013(self.e3.0 * rhs.e23.0),
+		//     e023 : -E023(self.e0.0 * rhs.e01.0) - E023(self.e012.0 * rhs.e02.0) - E023(self.e013.0 * rhs.e03.0) + E023(self.e023.0 * rhs.e0123.0) + E023(self.e1.0 * rhs.s.0) - E023(self.e123.0 * rhs.e23.0) - E023(self.e2.0 * rhs.e12.0) - E023(self.e3.0 * rhs.e13.0),
+		//     e123 : E123(self.e0.0 * rhs.s.0) - E123(self.e012.0 * rhs.e12.0) - E123(self.e013.0 * rhs.e13.0) - E123(self.e023.0 * rhs.e23.0) - E123(self.e1.0 * rhs.e01.0) + E123(self.e123.0 * rhs.e0123.0) - E123(self.e2.0 * rhs.e02.0) - E123(self.e3.0 * rhs.e03.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) + E0123(self.e0123.0 * rhs.e0123.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0) - E0123(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.e0123.anti_dot(rhs.s) + self.s.anti_dot(rhs.e0123),
+			e0: self.e0.anti_dot(rhs.e0123) + self.e123.anti_dot(rhs.s),
+			e1: self.e023.anti_dot(rhs.s) + self.e1.anti_dot(rhs.e0123),
+			e2: self.e013.anti_dot(rhs.s) + self.e2.anti_dot(rhs.e0123),
+			e3: self.e012.anti_dot(rhs.s) + self.e3.anti_dot(rhs.e0123),
+			e01: self.e01.anti_dot(rhs.e0123)
+				+ self.e0123.anti_dot(rhs.e01)
+				+ self.e23.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e23),
+			e02: self.e0123.anti_dot(rhs.e02)
+				+ self.e02.anti_dot(rhs.e0123)
+				+ self.e13.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e13),
+			e03: self.e0123.anti_dot(rhs.e03)
+				+ self.e03.anti_dot(rhs.e0123)
+				+ self.e12.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e12),
+			e12: self.e0123.anti_dot(rhs.e12)
+				+ self.e03.anti_dot(rhs.s)
+				+ self.e12.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e03),
+			e13: self.e0123.anti_dot(rhs.e13)
+				+ self.e02.anti_dot(rhs.s)
+				+ self.e13.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e02),
+			e23: self.e01.anti_dot(rhs.s)
+				+ self.e0123.anti_dot(rhs.e23)
+				+ self.e23.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e01),
+			e012: self.e0.anti_dot(rhs.e03)
+				+ self.e012.anti_dot(rhs.e0123)
+				+ self.e013.anti_dot(rhs.e01)
+				+ self.e023.anti_dot(rhs.e02)
+				+ self.e1.anti_dot(rhs.e13)
+				+ self.e123.anti_dot(rhs.e12)
+				+ self.e2.anti_dot(rhs.e23)
+				+ self.e3.anti_dot(rhs.s),
+			e013: self.e0.anti_dot(rhs.e02)
+				+ self.e012.anti_dot(rhs.e01)
+				+ self.e013.anti_dot(rhs.e0123)
+				+ self.e023.anti_dot(rhs.e03)
+				+ self.e1.anti_dot(rhs.e12)
+				+ self.e123.anti_dot(rhs.e13)
+				+ self.e2.anti_dot(rhs.s)
+				+ self.e3.anti_dot(rhs.e23),
+			e023: self.e0.anti_dot(rhs.e01)
+				+ self.e012.anti_dot(rhs.e02)
+				+ self.e013.anti_dot(rhs.e03)
+				+ self.e023.anti_dot(rhs.e0123)
+				+ self.e1.anti_dot(rhs.s)
+				+ self.e123.anti_dot(rhs.e23)
+				+ self.e2.anti_dot(rhs.e12)
+				+ self.e3.anti_dot(rhs.e13),
+			e123: self.e0.anti_dot(rhs.s)
+				+ self.e012.anti_dot(rhs.e12)
+				+ self.e013.anti_dot(rhs.e13)
+				+ self.e023.anti_dot(rhs.e23)
+				+ self.e1.anti_dot(rhs.e01)
+				+ self.e123.anti_dot(rhs.e0123)
+				+ self.e2.anti_dot(rhs.e02)
+				+ self.e3.anti_dot(rhs.e03),
+			e0123: self.e01.anti_dot(rhs.e01)
+				+ self.e0123.anti_dot(rhs.e0123)
+				+ self.e02.anti_dot(rhs.e02)
+				+ self.e03.anti_dot(rhs.e03)
+				+ self.e12.anti_dot(rhs.e12)
+				+ self.e13.anti_dot(rhs.e13)
+				+ self.e23.anti_dot(rhs.e23)
+				+ self.s.anti_dot(rhs.s),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Multivector:
+
+// Multivector.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0.0 * rhs.e0.0) + S(self.e01.0 * rhs.e01.0) - S(self.e012.0 * rhs.e012.0) - S(self.e0123.0 * rhs.e0123.0) - S(self.e013.0 * rhs.e013.0) + S(self.e02.0 * rhs.e02.0) - S(self.e023.0 * rhs.e023.0) + S(self.e03.0 * rhs.e03.0) - S(self.e1.0 * rhs.e1.0) - S(self.e12.0 * rhs.e12.0) + S(self.e123.0 * rhs.e123.0) - S(self.e13.0 * rhs.e13.0) - S(self.e2.0 * rhs.e2.0) - S(self.e23.0 * rhs.e23.0) - S(self.e3.0 * rhs.e3.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.e0.0 * rhs.s.0) - E0(self.e01.0 * rhs.e1.0) - E0(self.e012.0 * rhs.e12.0) + E0(self.e0123.0 * rhs.e123.0) - E0(self.e013.0 * rhs.e13.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e023.0 * rhs.e23.0) - E0(self.e03.0 * rhs.e3.0) + E0(self.e1.0 * rhs.e01.0) - E0(self.e12.0 * rhs.e012.0) - E0(self.e123.0 * rhs.e0123.0) - E0(self.e13.0 * rhs.e013.0) + E0(self.e2.0 * rhs.e02.0) - E0(self.e23.0 * rhs.e023.0) + E0(self.e3.0 * rhs.e03.0) + E0(self.s.0 * rhs.e0.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) - E1(self.e01.0 * rhs.e0.0) - E1(self.e012.0 * rhs.e02.0) + E1(self.e0123.0 * rhs.e023.0) - E1(self.e013.0 * rhs.e03.0) - E1(self.e02.0 * rhs.e012.0) - E1(self.e023.0 * rhs.e0123.0) - E1(self.e03.0 * rhs.e013.0) + E1(self.e1.0 * rhs.s.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e123.0 * rhs.e23.0) - E1(self.e13.0 * rhs.e3.0) + E1(self.e2.0 * rhs.e12.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.e3.0 * rhs.e13.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) + E2(self.e01.0 * rhs.e012.0) + E2(self.e012.0 * rhs.e01.0) - E2(self.e0123.0 * rhs.e013.0) + E2(self.e013.0 * rhs.e0123.0) - E2(self.e02.0 * rhs.e0.0) - E2(self.e023.0 * rhs.e03.0) - E2(self.e03.0 * rhs.e023.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e12.0 * rhs.e1.0) + E2(self.e123.0 * rhs.e13.0) + E2(self.e13.0 * rhs.e123.0) + E2(self.e2.0 * rhs.s.0) - E2(self.e23.0 * rhs.e3.0) + E2(self.e3.0 * rhs.e23.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) + E3(self.e01.0 * rhs.e013.0) - E3(self.e012.0 * rhs.e0123.0) + E3(self.e0123.0 * rhs.e012.0) + E3(self.e013.0 * rhs.e01.0) + E3(self.e02.0 * rhs.e023.0) + E3(self.e023.0 * rhs.e02.0) - E3(self.e03.0 * rhs.e0.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e12.0 * rhs.e123.0) - E3(self.e123.0 * rhs.e12.0) + E3(self.e13.0 * rhs.e1.0) - E3(self.e2.0 * rhs.e23.0) + E3(self.e23.0 * rhs.e2.0) + E3(self.e3.0 * rhs.s.0) + E3(self.s.0 * rhs.e3.0),
+		//     e01  : E01(self.e0.0 * rhs.e1.0) + E01(self.e01.0 * rhs.s.0) - E01(self.e012.0 * rhs.e2.0) - E01(self.e0123.0 * rhs.e23.0) - E01(self.e013.0 * rhs.e3.0) + E01(self.e02.0 * rhs.e12.0) - E01(self.e023.0 * rhs.e123.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e1.0 * rhs.e0.0) - E01(self.e12.0 * rhs.e02.0) + E01(self.e123.0 * rhs.e023.0) - E01(self.e13.0 * rhs.e03.0) - E01(self.e2.0 * rhs.e012.0) - E01(self.e23.0 * rhs.e0123.0) - E01(self.e3.0 * rhs.e013.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e0.0 * rhs.e2.0) - E02(self.e01.0 * rhs.e12.0) + E02(self.e012.0 * rhs.e1.0) + E02(self.e0123.0 * rhs.e13.0) + E02(self.e013.0 * rhs.e123.0) + E02(self.e02.0 * rhs.s.0) - E02(self.e023.0 * rhs.e3.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e1.0 * rhs.e012.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e123.0 * rhs.e013.0) + E02(self.e13.0 * rhs.e0123.0) - E02(self.e2.0 * rhs.e0.0) - E02(self.e23.0 * rhs.e03.0) - E02(self.e3.0 * rhs.e023.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : E03(self.e0.0 * rhs.e3.0) - E03(self.e01.0 * rhs.e13.0) - E03(self.e012.0 * rhs.e123.0) - E03(self.e0123.0 * rhs.e12.0) + E03(self.e013.0 * rhs.e1.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e023.0 * rhs.e2.0) + E03(self.e03.0 * rhs.s.0) + E03(self.e1.0 * rhs.e013.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.e123.0 * rhs.e012.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e2.0 * rhs.e023.0) + E03(self.e23.0 * rhs.e02.0) - E03(self.e3.0 * rhs.e0.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e0.0 * rhs.e012.0) - E12(self.e01.0 * rhs.e02.0) + E12(self.e012.0 * rhs.e0.0) + E12(self.e0123.0 * rhs.e03.0) + E12(self.e013.0 * rhs.e023.0) + E12(self.e02.0 * rhs.e01.0) - E12(self.e023.0 * rhs.e013.0) + E12(self.e03.0 * rhs.e0123.0) + E12(self.e1.0 * rhs.e2.0) + E12(self.e12.0 * rhs.s.0) - E12(self.e123.0 * rhs.e3.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e2.0 * rhs.e1.0) - E12(self.e23.0 * rhs.e13.0) - E12(self.e3.0 * rhs.e123.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e0.0 * rhs.e013.0) - E13(self.e01.0 * rhs.e03.0) - E13(self.e012.0 * rhs.e023.0) - E13(self.e0123.0 * rhs.e02.0) + E13(self.e013.0 * rhs.e0.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e023.0 * rhs.e012.0) + E13(self.e03.0 * rhs.e01.0) + E13(self.e1.0 * rhs.e3.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e123.0 * rhs.e2.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e2.0 * rhs.e123.0) + E13(self.e23.0 * rhs.e12.0) - E13(self.e3.0 * rhs.e1.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e0.0 * rhs.e023.0) + E23(self.e01.0 * rhs.e0123.0) + E23(self.e012.0 * rhs.e013.0) + E23(self.e0123.0 * rhs.e01.0) - E23(self.e013.0 * rhs.e012.0) - E23(self.e02.0 * rhs.e03.0) + E23(self.e023.0 * rhs.e0.0) + E23(self.e03.0 * rhs.e02.0) - E23(self.e1.0 * rhs.e123.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e123.0 * rhs.e1.0) - E23(self.e13.0 * rhs.e12.0) + E23(self.e2.0 * rhs.e3.0) + E23(self.e23.0 * rhs.s.0) - E23(self.e3.0 * rhs.e2.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e0.0 * rhs.e12.0) + E012(self.e01.0 * rhs.e2.0) + E012(self.e012.0 * rhs.s.0) - E012(self.e0123.0 * rhs.e3.0) + E012(self.e013.0 * rhs.e23.0) - E012(self.e02.0 * rhs.e1.0) - E012(self.e023.0 * rhs.e13.0) - E012(self.e03.0 * rhs.e123.0) - E012(self.e1.0 * rhs.e02.0) + E012(self.e12.0 * rhs.e0.0) + E012(self.e123.0 * rhs.e03.0) + E012(self.e13.0 * rhs.e023.0) + E012(self.e2.0 * rhs.e01.0) - E012(self.e23.0 * rhs.e013.0) + E012(self.e3.0 * rhs.e0123.0) + E012(self.s.0 * rhs.e012.0),
+		//     e013 : E013(self.e0.0 * rhs.e13.0) + E013(self.e01.0 * rhs.e3.0) - E013(self.e012.0 * rhs.e23.0) + E013(self.e0123.0 * rhs.e2.0) + E013(self.e013.0 * rhs.s.0) + E013(self.e02.0 * rhs.e123.0) + E013(self.e023.0 * rhs.e12.0) - E013(self.e03.0 * rhs.e1.0) - E013(self.e1.0 * rhs.e03.0) - E013(self.e12.0 * rhs.e023.0) - E013(self.e123.0 * rhs.e02.0) + E013(self.e13.0 * rhs.e0.0) - E013(self.e2.0 * rhs.e0123.0) + E013(self.e23.0 * rhs.e012.0) + E013(self.e3.0 * rhs.e01.0) + E013(self.s.0 * rhs.e013.0),
+		//     e023 : E023(self.e0.0 * rhs.e23.0) - E023(self.e01.0 * rhs.e123.0) + E023(self.e012.0 * rhs.e13.0) - E023(self.e0123.0 * rhs.e1.0) - E023(self.e013.0 * rhs.e12.0) + E023(self.e02.0 * rhs.e3.0) + E023(self.e023.0 * rhs.s.0) - E023(self.e03.0 * rhs.e2.0) + E023(self.e1.0 * rhs.e0123.0) + E023(self.e12.0 * rhs.e013.0) + E023(self.e123.0 * rhs.e01.0) - E023(self.e13.0 * rhs.e012.0) - E023(self.e2.0 * rhs.e03.0) + E023(self.e23.0 * rhs.e0.0) + E023(self.e3.0 * rhs.e02.0) + E023(self.s.0 * rhs.e023.0),
+		//     e123 : E123(self.e0.0 * rhs.e0123.0) - E123(self.e01.0 * rhs.e023.0) + E123(self.e012.0 * rhs.e03.0) - E123(self.e0123.0 * rhs.e0.0) - E123(self.e013.0 * rhs.e02.0) + E123(self.e02.0 * rhs.e013.0) + E123(self.e023.0 * rhs.e01.0) - E123(self.e03.0 * rhs.e012.0) + E123(self.e1.0 * rhs.e23.0) + E123(self.e12.0 * rhs.e3.0) + E123(self.e123.0 * rhs.s.0) - E123(self.e13.0 * rhs.e2.0) - E123(self.e2.0 * rhs.e13.0) + E123(self.e23.0 * rhs.e1.0) + E123(self.e3.0 * rhs.e12.0) + E123(self.s.0 * rhs.e123.0),
+		//     e0123: E0123(self.e0.0 * rhs.e123.0) + E0123(self.e01.0 * rhs.e23.0) + E0123(self.e012.0 * rhs.e3.0) + E0123(self.e0123.0 * rhs.s.0) - E0123(self.e013.0 * rhs.e2.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e023.0 * rhs.e1.0) + E0123(self.e03.0 * rhs.e12.0) - E0123(self.e1.0 * rhs.e023.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e123.0 * rhs.e0.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e2.0 * rhs.e013.0) + E0123(self.e23.0 * rhs.e01.0) - E0123(self.e3.0 * rhs.e012.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e0.geometric(rhs.e0)
+				+ self.e01.geometric(rhs.e01)
+				+ self.e012.geometric(rhs.e012)
+				+ self.e0123.geometric(rhs.e0123)
+				+ self.e013.geometric(rhs.e013)
+				+ self.e02.geometric(rhs.e02)
+				+ self.e023.geometric(rhs.e023)
+				+ self.e03.geometric(rhs.e03)
+				+ self.e1.geometric(rhs.e1)
+				+ self.e12.geometric(rhs.e12)
+				+ self.e123.geometric(rhs.e123)
+				+ self.e13.geometric(rhs.e13)
+				+ self.e2.geometric(rhs.e2)
+				+ self.e23.geometric(rhs.e23)
+				+ self.e3.geometric(rhs.e3)
+				+ self.s.geometric(rhs.s),
+			e0: self.e0.geometric(rhs.s)
+				+ self.e01.geometric(rhs.e1)
+				+ self.e012.geometric(rhs.e12)
+				+ self.e0123.geometric(rhs.e123)
+				+ self.e013.geometric(rhs.e13)
+				+ self.e02.geometric(rhs.e2)
+				+ self.e023.geometric(rhs.e23)
+				+ self.e03.geometric(rhs.e3)
+				+ self.e1.geometric(rhs.e01)
+				+ self.e12.geometric(rhs.e012)
+				+ self.e123.geometric(rhs.e0123)
+				+ self.e13.geometric(rhs.e013)
+				+ self.e2.geometric(rhs.e02)
+				+ self.e23.geometric(rhs.e023)
+				+ self.e3.geometric(rhs.e03)
+				+ self.s.geometric(rhs.e0),
+			e1: self.e0.geometric(rhs.e01)
+				+ self.e01.geometric(rhs.e0)
+				+ self.e012.geometric(rhs.e02)
+				+ self.e0123.geometric(rhs.e023)
+				+ self.e013.geometric(rhs.e03)
+				+ self.e02.geometric(rhs.e012)
+				+ self.e023.geometric(rhs.e0123)
+				+ self.e03.geometric(rhs.e013)
+				+ self.e1.geometric(rhs.s)
+				+ self.e12.geometric(rhs.e2)
+				+ self.e123.geometric(rhs.e23)
+				+ self.e13.geometric(rhs.e3)
+				+ self.e2.geometric(rhs.e12)
+				+ self.e23.geometric(rhs.e123)
+				+ self.e3.geometric(rhs.e13)
+				+ self.s.geometric(rhs.e1),
+			e2: self.e0.geometric(rhs.e02)
+				+ self.e01.geometric(rhs.e012)
+				+ self.e012.geometric(rhs.e01)
+				+ self.e0123.geometric(rhs.e013)
+				+ self.e013.geometric(rhs.e0123)
+				+ self.e02.geometric(rhs.e0)
+				+ self.e023.geometric(rhs.e03)
+				+ self.e03.geometric(rhs.e023)
+				+ self.e1.geometric(rhs.e12)
+				+ self.e12.geometric(rhs.e1)
+				+ self.e123.geometric(rhs.e13)
+				+ self.e13.geometric(rhs.e123)
+				+ self.e2.geometric(rhs.s)
+				+ self.e23.geometric(rhs.e3)
+				+ self.e3.geometric(rhs.e23)
+				+ self.s.geometric(rhs.e2),
+			e3: self.e0.geometric(rhs.e03)
+				+ self.e01.geometric(rhs.e013)
+				+ self.e012.geometric(rhs.e0123)
+				+ self.e0123.geometric(rhs.e012)
+				+ self.e013.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e023)
+				+ self.e023.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e0)
+				+ self.e1.geometric(rhs.e13)
+				+ self.e12.geometric(rhs.e123)
+				+ self.e123.geometric(rhs.e12)
+				+ self.e13.geometric(rhs.e1)
+				+ self.e2.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e2)
+				+ self.e3.geometric(rhs.s)
+				+ self.s.geometric(rhs.e3),
+			e01: self.e0.geometric(rhs.e1)
+				+ self.e01.geometric(rhs.s)
+				+ self.e012.geometric(rhs.e2)
+				+ self.e0123.geometric(rhs.e23)
+				+ self.e013.geometric(rhs.e3)
+				+ self.e02.geometric(rhs.e12)
+				+ self.e023.geometric(rhs.e123)
+				+ self.e03.geometric(rhs.e13)
+				+ self.e1.geometric(rhs.e0)
+				+ self.e12.geometric(rhs.e02)
+				+ self.e123.geometric(rhs.e023)
+				+ self.e13.geometric(rhs.e03)
+				+ self.e2.geometric(rhs.e012)
+				+ self.e23.geometric(rhs.e0123)
+				+ self.e3.geometric(rhs.e013)
+				+ self.s.geometric(rhs.e01),
+			e02: self.e0.geometric(rhs.e2)
+				+ self.e01.geometric(rhs.e12)
+				+ self.e012.geometric(rhs.e1)
+				+ self.e0123.geometric(rhs.e13)
+				+ self.e013.geometric(rhs.e123)
+				+ self.e02.geometric(rhs.s)
+				+ self.e023.geometric(rhs.e3)
+				+ self.e03.geometric(rhs.e23)
+				+ self.e1.geometric(rhs.e012)
+				+ self.e12.geometric(rhs.e01)
+				+ self.e123.geometric(rhs.e013)
+				+ self.e13.geometric(rhs.e0123)
+				+ self.e2.geometric(rhs.e0)
+				+ self.e23.geometric(rhs.e03)
+				+ self.e3.geometric(rhs.e023)
+				+ self.s.geometric(rhs.e02),
+			e03: self.e0.geometric(rhs.e3)
+				+ self.e01.geometric(rhs.e13)
+				+ self.e012.geometric(rhs.e123)
+				+ self.e0123.geometric(rhs.e12)
+				+ self.e013.geometric(rhs.e1)
+				+ self.e02.geometric(rhs.e23)
+				+ self.e023.geometric(rhs.e2)
+				+ self.e03.geometric(rhs.s)
+				+ self.e1.geometric(rhs.e013)
+				+ self.e12.geometric(rhs.e0123)
+				+ self.e123.geometric(rhs.e012)
+				+ self.e13.geometric(rhs.e01)
+				+ self.e2.geometric(rhs.e023)
+				+ self.e23.geometric(rhs.e02)
+				+ self.e3.geometric(rhs.e0)
+				+ self.s.geometric(rhs.e03),
+			e12: self.e0.geometric(rhs.e012)
+				+ self.e01.geometric(rhs.e02)
+				+ self.e012.geometric(rhs.e0)
+				+ self.e0123.geometric(rhs.e03)
+				+ self.e013.geometric(rhs.e023)
+				+ self.e02.geometric(rhs.e01)
+				+ self.e023.geometric(rhs.e013)
+				+ self.e03.geometric(rhs.e0123)
+				+ self.e1.geometric(rhs.e2)
+				+ self.e12.geometric(rhs.s)
+				+ self.e123.geometric(rhs.e3)
+				+ self.e13.geometric(rhs.e23)
+				+ self.e2.geometric(rhs.e1)
+				+ self.e23.geometric(rhs.e13)
+				+ self.e3.geometric(rhs.e123)
+				+ self.s.geometric(rhs.e12),
+			e13: self.e0.geometric(rhs.e013)
+				+ self.e01.geometric(rhs.e03)
+				+ self.e012.geometric(rhs.e023)
+				+ self.e0123.geometric(rhs.e02)
+				+ self.e013.geometric(rhs.e0)
+				+ self.e02.geometric(rhs.e0123)
+				+ self.e023.geometric(rhs.e012)
+				+ self.e03.geometric(rhs.e01)
+				+ self.e1.geometric(rhs.e3)
+				+ self.e12.geometric(rhs.e23)
+				+ self.e123.geometric(rhs.e2)
+				+ self.e13.geometric(rhs.s)
+				+ self.e2.geometric(rhs.e123)
+				+ self.e23.geometric(rhs.e12)
+				+ self.e3.geometric(rhs.e1)
+				+ self.s.geometric(rhs.e13),
+			e23: self.e0.geometric(rhs.e023)
+				+ self.e01.geometric(rhs.e0123)
+				+ self.e012.geometric(rhs.e013)
+				+ self.e0123.geometric(rhs.e01)
+				+ self.e013.geometric(rhs.e012)
+				+ self.e02.geometric(rhs.e03)
+				+ self.e023.geometric(rhs.e0)
+				+ self.e03.geometric(rhs.e02)
+				+ self.e1.geometric(rhs.e123)
+				+ self.e12.geometric(rhs.e13)
+				+ self.e123.geometric(rhs.e1)
+				+ self.e13.geometric(rhs.e12)
+				+ self.e2.geometric(rhs.e3)
+				+ self.e23.geometric(rhs.s)
+				+ self.e3.geometric(rhs.e2)
+				+ self.s.geometric(rhs.e23),
+			e012: self.e0.geometric(rhs.e12)
+				+ self.e01.geometric(rhs.e2)
+				+ self.e012.geometric(rhs.s)
+				+ self.e0123.geometric(rhs.e3)
+				+ self.e013.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e1)
+				+ self.e023.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e123)
+				+ self.e1.geometric(rhs.e02)
+				+ self.e12.geometric(rhs.e0)
+				+ self.e123.geometric(rhs.e03)
+				+ self.e13.geometric(rhs.e023)
+				+ self.e2.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e013)
+				+ self.e3.geometric(rhs.e0123)
+				+ self.s.geometric(rhs.e012),
+			e013: self.e0.geometric(rhs.e13)
+				+ self.e01.geometric(rhs.e3)
+				+ self.e012.geometric(rhs.e23)
+				+ self.e0123.geometric(rhs.e2)
+				+ self.e013.geometric(rhs.s)
+				+ self.e02.geometric(rhs.e123)
+				+ self.e023.geometric(rhs.e12)
+				+ self.e03.geometric(rhs.e1)
+				+ self.e1.geometric(rhs.e03)
+				+ self.e12.geometric(rhs.e023)
+				+ self.e123.geometric(rhs.e02)
+				+ self.e13.geometric(rhs.e0)
+				+ self.e2.geometric(rhs.e0123)
+				+ self.e23.geometric(rhs.e012)
+				+ self.e3.geometric(rhs.e01)
+				+ self.s.geometric(rhs.e013),
+			e023: self.e0.geometric(rhs.e23)
+				+ self.e01.geometric(rhs.e123)
+				+ self.e012.geometric(rhs.e13)
+				+ self.e0123.geometric(rhs.e1)
+				+ self.e013.geometric(rhs.e12)
+				+ self.e02.geometric(rhs.e3)
+				+ self.e023.geometric(rhs.s)
+				+ self.e03.geometric(rhs.e2)
+				+ self.e1.geometric(rhs.e0123)
+				+ self.e12.geometric(rhs.e013)
+				+ self.e123.geometric(rhs.e01)
+				+ self.e13.geometric(rhs.e012)
+				+ self.e2.geometric(rhs.e03)
+				+ self.e23.geometric(rhs.e0)
+				+ self.e3.geometric(rhs.e02)
+				+ self.s.geometric(rhs.e023),
+			e123: self.e0.geometric(rhs.e0123)
+				+ self.e01.geometric(rhs.e023)
+				+ self.e012.geometric(rhs.e03)
+				+ self.e0123.geometric(rhs.e0)
+				+ self.e013.geometric(rhs.e02)
+				+ self.e02.geometric(rhs.e013)
+				+ self.e023.geometric(rhs.e01)
+				+ self.e03.geometric(rhs.e012)
+				+ self.e1.geometric(rhs.e23)
+				+ self.e12.geometric(rhs.e3)
+				+ self.e123.geometric(rhs.s)
+				+ self.e13.geometric(rhs.e2)
+				+ self.e2.geometric(rhs.e13)
+				+ self.e23.geometric(rhs.e1)
+				+ self.e3.geometric(rhs.e12)
+				+ self.s.geometric(rhs.e123),
+			e0123: self.e0.geometric(rhs.e123)
+				+ self.e01.geometric(rhs.e23)
+				+ self.e012.geometric(rhs.e3)
+				+ self.e0123.geometric(rhs.s)
+				+ self.e013.geometric(rhs.e2)
+				+ self.e02.geometric(rhs.e13)
+				+ self.e023.geometric(rhs.e1)
+				+ self.e03.geometric(rhs.e12)
+				+ self.e1.geometric(rhs.e023)
+				+ self.e12.geometric(rhs.e03)
+				+ self.e123.geometric(rhs.e0)
+				+ self.e13.geometric(rhs.e02)
+				+ self.e2.geometric(rhs.e013)
+				+ self.e23.geometric(rhs.e01)
+				+ self.e3.geometric(rhs.e012)
+				+ self.s.geometric(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0.0 * rhs.e123.0) + S(self.e01.0 * rhs.e23.0) + S(self.e012.0 * rhs.e3.0) + S(self.e0123.0 * rhs.s.0) - S(self.e013.0 * rhs.e2.0) - S(self.e02.0 * rhs.e13.0) + S(self.e023.0 * rhs.e1.0) + S(self.e03.0 * rhs.e12.0) - S(self.e1.0 * rhs.e023.0) + S(self.e12.0 * rhs.e03.0) - S(self.e123.0 * rhs.e0.0) - S(self.e13.0 * rhs.e02.0) + S(self.e2.0 * rhs.e013.0) + S(self.e23.0 * rhs.e01.0) - S(self.e3.0 * rhs.e012.0) + S(self.s.0 * rhs.e0123.0),
+		//     e0   : E0(self.e0.0 * rhs.e0123.0) + E0(self.e01.0 * rhs.e023.0) + E0(self.e012.0 * rhs.e03.0) + E0(self.e0123.0 * rhs.e0.0) - E0(self.e013.0 * rhs.e02.0) - E0(self.e02.0 * rhs.e013.0) + E0(self.e023.0 * rhs.e01.0) + E0(self.e03.0 * rhs.e012.0) - E0(self.e1.0 * rhs.e23.0) + E0(self.e12.0 * rhs.e3.0) - E0(self.e123.0 * rhs.s.0) - E0(self.e13.0 * rhs.e2.0) + E0(self.e2.0 * rhs.e13.0) + E0(self.e23.0 * rhs.e1.0) - E0(self.e3.0 * rhs.e12.0) + E0(self.s.0 * rhs.e123.0),
+		//     e1   : -E1(self.e0.0 * rhs.e23.0) + E1(self.e01.0 * rhs.e123.0) + E1(self.e012.0 * rhs.e13.0) + E1(self.e0123.0 * rhs.e1.0) - E1(self.e013.0 * rhs.e12.0) + E1(self.e02.0 * rhs.e3.0) - E1(self.e023.0 * rhs.s.0) - E1(self.e03.0 * rhs.e2.0) + E1(self.e1.0 * rhs.e0123.0) - E1(self.e12.0 * rhs.e013.0) + E1(self.e123.0 * rhs.e01.0) + E1(self.e13.0 * rhs.e012.0) + E1(self.e2.0 * rhs.e03.0) + E1(self.e23.0 * rhs.e0.0) - E1(self.e3.0 * rhs.e02.0) + E1(self.s.0 * rhs.e023.0),
+		//     e2   : E2(self.e0.0 * rhs.e13.0) - E2(self.e01.0 * rhs.e3.0) + E2(self.e012.0 * rhs.e23.0) + E2(self.e0123.0 * rhs.e2.0) + E2(self.e013.0 * rhs.s.0) + E2(self.e02.0 * rhs.e123.0) - E2(self.e023.0 * rhs.e12.0) + E2(self.e03.0 * rhs.e1.0) - E2(self.e1.0 * rhs.e03.0) - E2(self.e12.0 * rhs.e023.0) + E2(self.e123.0 * rhs.e02.0) - E2(self.e13.0 * rhs.e0.0) + E2(self.e2.0 * rhs.e0123.0) + E2(self.e23.0 * rhs.e012.0) + E2(self.e3.0 * rhs.e01.0) - E2(self.s.0 * rhs.e013.0),
+		//     e3   : -E3(self.e0.0 * rhs.e12.0) + E3(self.e01.0 * rhs.e2.0) - E3(self.e012.0 * rhs.s.0) + E3(self.e0123.0 * rhs.e3.0) + E3(self.e013.0 * rhs.e23.0) - E3(self.e02.0 * rhs.e1.0) - E3(self.e023.0 * rhs.e13.0) + E3(self.e03.0 * rhs.e123.0) + E3(self.e1.0 * rhs.e02.0) + E3(self.e12.0 * rhs.e0.0) + E3(self.e123.0 * rhs.e03.0) - E3(self.e13.0 * rhs.e023.0) - E3(self.e2.0 * rhs.e01.0) + E3(self.e23.0 * rhs.e013.0) + E3(self.e3.0 * rhs.e0123.0) + E3(self.s.0 * rhs.e012.0),
+		//     e01  : -E01(self.e0.0 * rhs.e023.0) + E01(self.e01.0 * rhs.e0123.0) + E01(self.e012.0 * rhs.e013.0) + E01(self.e0123.0 * rhs.e01.0) - E01(self.e013.0 * rhs.e012.0) + E01(self.e02.0 * rhs.e03.0) - E01(self.e023.0 * rhs.e0.0) - E01(self.e03.0 * rhs.e02.0) + E01(self.e1.0 * rhs.e123.0) - E01(self.e12.0 * rhs.e13.0) + E01(self.e123.0 * rhs.e1.0) + E01(self.e13.0 * rhs.e12.0) + E01(self.e2.0 * rhs.e3.0) + E01(self.e23.0 * rhs.s.0) - E01(self.e3.0 * rhs.e2.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : E02(self.e0.0 * rhs.e013.0) - E02(self.e01.0 * rhs.e03.0) + E02(self.e012.0 * rhs.e023.0) + E02(self.e0123.0 * rhs.e02.0) + E02(self.e013.0 * rhs.e0.0) + E02(self.e02.0 * rhs.e0123.0) - E02(self.e023.0 * rhs.e012.0) + E02(self.e03.0 * rhs.e01.0) - E02(self.e1.0 * rhs.e3.0) - E02(self.e12.0 * rhs.e23.0) + E02(self.e123.0 * rhs.e2.0) - E02(self.e13.0 * rhs.s.0) + E02(self.e2.0 * rhs.e123.0) + E02(self.e23.0 * rhs.e12.0) + E02(self.e3.0 * rhs.e1.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : -E03(self.e0.0 * rhs.e012.0) + E03(self.e01.0 * rhs.e02.0) - E03(self.e012.0 * rhs.e0.0) + E03(self.e0123.0 * rhs.e03.0) + E03(self.e013.0 * rhs.e023.0) - E03(self.e02.0 * rhs.e01.0) - E03(self.e023.0 * rhs.e013.0) + E03(self.e03.0 * rhs.e0123.0) + E03(self.e1.0 * rhs.e2.0) + E03(self.e12.0 * rhs.s.0) + E03(self.e123.0 * rhs.e3.0) - E03(self.e13.0 * rhs.e23.0) - E03(self.e2.0 * rhs.e1.0) + E03(self.e23.0 * rhs.e13.0) + E03(self.e3.0 * rhs.e123.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : -E12(self.e0.0 * rhs.e3.0) - E12(self.e01.0 * rhs.e13.0) + E12(self.e012.0 * rhs.e123.0) + E12(self.e0123.0 * rhs.e12.0) + E12(self.e013.0 * rhs.e1.0) - E12(self.e02.0 * rhs.e23.0) + E12(self.e023.0 * rhs.e2.0) - E12(self.e03.0 * rhs.s.0) + E12(self.e1.0 * rhs.e013.0) + E12(self.e12.0 * rhs.e0123.0) - E12(self.e123.0 * rhs.e012.0) + E12(self.e13.0 * rhs.e01.0) + E12(self.e2.0 * rhs.e023.0) + E12(self.e23.0 * rhs.e02.0) + E12(self.e3.0 * rhs.e0.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e0.0 * rhs.e2.0) + E13(self.e01.0 * rhs.e12.0) - E13(self.e012.0 * rhs.e1.0) + E13(self.e0123.0 * rhs.e13.0) + E13(self.e013.0 * rhs.e123.0) + E13(self.e02.0 * rhs.s.0) + E13(self.e023.0 * rhs.e3.0) - E13(self.e03.0 * rhs.e23.0) - E13(self.e1.0 * rhs.e012.0) - E13(self.e12.0 * rhs.e01.0) - E13(self.e123.0 * rhs.e013.0) + E13(self.e13.0 * rhs.e0123.0) - E13(self.e2.0 * rhs.e0.0) + E13(self.e23.0 * rhs.e03.0) + E13(self.e3.0 * rhs.e023.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : -E23(self.e0.0 * rhs.e1.0) - E23(self.e01.0 * rhs.s.0) - E23(self.e012.0 * rhs.e2.0) + E23(self.e0123.0 * rhs.e23.0) - E23(self.e013.0 * rhs.e3.0) + E23(self.e02.0 * rhs.e12.0) + E23(self.e023.0 * rhs.e123.0) + E23(self.e03.0 * rhs.e13.0) + E23(self.e1.0 * rhs.e0.0) - E23(self.e12.0 * rhs.e02.0) - E23(self.e123.0 * rhs.e023.0) - E23(self.e13.0 * rhs.e03.0) - E23(self.e2.0 * rhs.e012.0) + E23(self.e23.0 * rhs.e0123.0) - E23(self.e3.0 * rhs.e013.0) - E23(self.s.0 * rhs.e01.0),
+		//     e012 : -E012(self.e0.0 * rhs.e03.0) - E012(self.e01.0 * rhs.e013.0) + E012(self.e012.0 * rhs.e0123.0) + E012(self.e0123.0 * rhs.e012.0) + E012(self.e013.0 * rhs.e01.0) - E012(self.e02.0 * rhs.e023.0) + E012(self.e023.0 * rhs.e02.0) - E012(self.e03.0 * rhs.e0.0) + E012(self.e1.0 * rhs.e13.0) + E012(self.e12.0 * rhs.e123.0) - E012(self.e123.0 * rhs.e12.0) + E012(self.e13.0 * rhs.e1.0) + E012(self.e2.0 * rhs.e23.0) + E012(self.e23.0 * rhs.e2.0) + E012(self.e3.0 * rhs.s.0) - E012(self.s.0 * rhs.e3.0),
+		//     e013 : E013(self.e0.0 * rhs.e02.0) + E013(self.e01.0 * rhs.e012.0) - E013(self.e012.0 * rhs.e01.0) + E013(self.e0123.0 * rhs.e013.0) + E013(self.e013.0 * rhs.e0123.0) + E013(self.e02.0 * rhs.e0.0) + E013(self.e023.0 * rhs.e03.0) - E013(self.e03.0 * rhs.e023.0) - E013(self.e1.0 * rhs.e12.0) - E013(self.e12.0 * rhs.e1.0) - E013(self.e123.0 * rhs.e13.0) + E013(self.e13.0 * rhs.e123.0) - E013(self.e2.0 * rhs.s.0) + E013(self.e23.0 * rhs.e3.0) + E013(self.e3.0 * rhs.e23.0) + E013(self.s.0 * rhs.e2.0),
+		//     e023 : -E023(self.e0.0 * rhs.e01.0) - E023(self.e01.0 * rhs.e0.0) - E023(self.e012.0 * rhs.e02.0) + E023(self.e0123.0 * rhs.e023.0) - E023(self.e013.0 * rhs.e03.0) + E023(self.e02.0 * rhs.e012.0) + E023(self.e023.0 * rhs.e0123.0) + E023(self.e03.0 * rhs.e013.0) + E023(self.e1.0 * rhs.s.0) - E023(self.e12.0 * rhs.e2.0) - E023(self.e123.0 * rhs.e23.0) - E023(self.e13.0 * rhs.e3.0) - E023(self.e2.0 * rhs.e12.0) + E023(self.e23.0 * rhs.e123.0) - E023(self.e3.0 * rhs.e13.0) - E023(self.s.0 * rhs.e1.0),
+		//     e123 : E123(self.e0.0 * rhs.s.0) - E123(self.e01.0 * rhs.e1.0) - E123(self.e012.0 * rhs.e12.0) + E123(self.e0123.0 * rhs.e123.0) - E123(self.e013.0 * rhs.e13.0) - E123(self.e02.0 * rhs.e2.0) - E123(self.e023.0 * rhs.e23.0) - E123(self.e03.0 * rhs.e3.0) - E123(self.e1.0 * rhs.e01.0) + E123(self.e12.0 * rhs.e012.0) + E123(self.e123.0 * rhs.e0123.0) + E123(self.e13.0 * rhs.e013.0) - E123(self.e2.0 * rhs.e02.0) + E123(self.e23.0 * rhs.e023.0) - E123(self.e3.0 * rhs.e03.0) - E123(self.s.0 * rhs.e0.0),
+		//     e0123: E0123(self.e0.0 * rhs.e0.0) - E0123(self.e01.0 * rhs.e01.0) - E0123(self.e012.0 * rhs.e012.0) + E0123(self.e0123.0 * rhs.e0123.0) - E0123(self.e013.0 * rhs.e013.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e023.0 * rhs.e023.0) - E0123(self.e03.0 * rhs.e03.0) - E0123(self.e1.0 * rhs.e1.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e123.0 * rhs.e123.0) + E0123(self.e13.0 * rhs.e13.0) - E0123(self.e2.0 * rhs.e2.0) + E0123(self.e23.0 * rhs.e23.0) - E0123(self.e3.0 * rhs.e3.0) - E0123(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.e0.anti_geometric(rhs.e123)
+				+ self.e01.anti_geometric(rhs.e23)
+				+ self.e012.anti_geometric(rhs.e3)
+				+ self.e0123.anti_geometric(rhs.s)
+				+ self.e013.anti_geometric(rhs.e2)
+				+ self.e02.anti_geometric(rhs.e13)
+				+ self.e023.anti_geometric(rhs.e1)
+				+ self.e03.anti_geometric(rhs.e12)
+				+ self.e1.anti_geometric(rhs.e023)
+				+ self.e12.anti_geometric(rhs.e03)
+				+ self.e123.anti_geometric(rhs.e0)
+				+ self.e13.anti_geometric(rhs.e02)
+				+ self.e2.anti_geometric(rhs.e013)
+				+ self.e23.anti_geometric(rhs.e01)
+				+ self.e3.anti_geometric(rhs.e012)
+				+ self.s.anti_geometric(rhs.e0123),
+			e0: self.e0.anti_geometric(rhs.e0123)
+				+ self.e01.anti_geometric(rhs.e023)
+				+ self.e012.anti_geometric(rhs.e03)
+				+ self.e0123.anti_geometric(rhs.e0)
+				+ self.e013.anti_geometric(rhs.e02)
+				+ self.e02.anti_geometric(rhs.e013)
+				+ self.e023.anti_geometric(rhs.e01)
+				+ self.e03.anti_geometric(rhs.e012)
+				+ self.e1.anti_geometric(rhs.e23)
+				+ self.e12.anti_geometric(rhs.e3)
+				+ self.e123.anti_geometric(rhs.s)
+				+ self.e13.anti_geometric(rhs.e2)
+				+ self.e2.anti_geometric(rhs.e13)
+				+ self.e23.anti_geometric(rhs.e1)
+				+ self.e3.anti_geometric(rhs.e12)
+				+ self.s.anti_geometric(rhs.e123),
+			e1: self.e0.anti_geometric(rhs.e23)
+				+ self.e01.anti_geometric(rhs.e123)
+				+ self.e012.anti_geometric(rhs.e13)
+				+ self.e0123.anti_geometric(rhs.e1)
+				+ self.e013.anti_geometric(rhs.e12)
+				+ self.e02.anti_geometric(rhs.e3)
+				+ self.e023.anti_geometric(rhs.s)
+				+ self.e03.anti_geometric(rhs.e2)
+				+ self.e1.anti_geometric(rhs.e0123)
+				+ self.e12.anti_geometric(rhs.e013)
+				+ self.e123.anti_geometric(rhs.e01)
+				+ self.e13.anti_geometric(rhs.e012)
+				+ self.e2.anti_geometric(rhs.e03)
+				+ self.e23.anti_geometric(rhs.e0)
+				+ self.e3.anti_geometric(rhs.e02)
+				+ self.s.anti_geometric(rhs.e023),
+			e2: self.e0.anti_geometric(rhs.e13)
+				+ self.e01.anti_geometric(rhs.e3)
+				+ self.e012.anti_geometric(rhs.e23)
+				+ self.e0123.anti_geometric(rhs.e2)
+				+ self.e013.anti_geometric(rhs.s)
+				+ self.e02.anti_geometric(rhs.e123)
+				+ self.e023.anti_geometric(rhs.e12)
+				+ self.e03.anti_geometric(rhs.e1)
+				+ self.e1.anti_geometric(rhs.e03)
+				+ self.e12.anti_geometric(rhs.e023)
+				+ self.e123.anti_geometric(rhs.e02)
+				+ self.e13.anti_geometric(rhs.e0)
+				+ self.e2.anti_geometric(rhs.e0123)
+				+ self.e23.anti_geometric(rhs.e012)
+				+ self.e3.anti_geometric(rhs.e01)
+				+ self.s.anti_geometric(rhs.e013),
+			e3: self.e0.anti_geometric(rhs.e12)
+				+ self.e01.anti_geometric(rhs.e2)
+				+ self.e012.anti_geometric(rhs.s)
+				+ self.e0123.anti_geometric(rhs.e3)
+				+ self.e013.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e1)
+				+ self.e023.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e123)
+				+ self.e1.anti_geometric(rhs.e02)
+				+ self.e12.anti_geometric(rhs.e0)
+				+ self.e123.anti_geometric(rhs.e03)
+				+ self.e13.anti_geometric(rhs.e023)
+				+ self.e2.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e013)
+				+ self.e3.anti_geometric(rhs.e0123)
+				+ self.s.anti_geometric(rhs.e012),
+			e01: self.e0.anti_geometric(rhs.e023)
+				+ self.e01.anti_geometric(rhs.e0123)
+				+ self.e012.anti_geometric(rhs.e013)
+				+ self.e0123.anti_geometric(rhs.e01)
+				+ self.e013.anti_geometric(rhs.e012)
+				+ self.e02.anti_geometric(rhs.e03)
+				+ self.e023.anti_geometric(rhs.e0)
+				+ self.e03.anti_geometric(rhs.e02)
+				+ self.e1.anti_geometric(rhs.e123)
+				+ self.e12.anti_geometric(rhs.e13)
+				+ self.e123.anti_geometric(rhs.e1)
+				+ self.e13.anti_geometric(rhs.e12)
+				+ self.e2.anti_geometric(rhs.e3)
+				+ self.e23.anti_geometric(rhs.s)
+				+ self.e3.anti_geometric(rhs.e2)
+				+ self.s.anti_geometric(rhs.e23),
+			e02: self.e0.anti_geometric(rhs.e013)
+				+ self.e01.anti_geometric(rhs.e03)
+				+ self.e012.anti_geometric(rhs.e023)
+				+ self.e0123.anti_geometric(rhs.e02)
+				+ self.e013.anti_geometric(rhs.e0)
+				+ self.e02.anti_geometric(rhs.e0123)
+				+ self.e023.anti_geometric(rhs.e012)
+				+ self.e03.anti_geometric(rhs.e01)
+				+ self.e1.anti_geometric(rhs.e3)
+				+ self.e12.anti_geometric(rhs.e23)
+				+ self.e123.anti_geometric(rhs.e2)
+				+ self.e13.anti_geometric(rhs.s)
+				+ self.e2.anti_geometric(rhs.e123)
+				+ self.e23.anti_geometric(rhs.e12)
+				+ self.e3.anti_geometric(rhs.e1)
+				+ self.s.anti_geometric(rhs.e13),
+			e03: self.e0.anti_geometric(rhs.e012)
+				+ self.e01.anti_geometric(rhs.e02)
+				+ self.e012.anti_geometric(rhs.e0)
+				+ self.e0123.anti_geometric(rhs.e03)
+				+ self.e013.anti_geometric(rhs.e023)
+				+ self.e02.anti_geometric(rhs.e01)
+				+ self.e023.anti_geometric(rhs.e013)
+				+ self.e03.anti_geometric(rhs.e0123)
+				+ self.e1.anti_geometric(rhs.e2)
+				+ self.e12.anti_geometric(rhs.s)
+				+ self.e123.anti_geometric(rhs.e3)
+				+ self.e13.anti_geometric(rhs.e23)
+				+ self.e2.anti_geometric(rhs.e1)
+				+ self.e23.anti_geometric(rhs.e13)
+				+ self.e3.anti_geometric(rhs.e123)
+				+ self.s.anti_geometric(rhs.e12),
+			e12: self.e0.anti_geometric(rhs.e3)
+				+ self.e01.anti_geometric(rhs.e13)
+				+ self.e012.anti_geometric(rhs.e123)
+				+ self.e0123.anti_geometric(rhs.e12)
+				+ self.e013.anti_geometric(rhs.e1)
+				+ self.e02.anti_geometric(rhs.e23)
+				+ self.e023.anti_geometric(rhs.e2)
+				+ self.e03.anti_geometric(rhs.s)
+				+ self.e1.anti_geometric(rhs.e013)
+				+ self.e12.anti_geometric(rhs.e0123)
+				+ self.e123.anti_geometric(rhs.e012)
+				+ self.e13.anti_geometric(rhs.e01)
+				+ self.e2.anti_geometric(rhs.e023)
+				+ self.e23.anti_geometric(rhs.e02)
+				+ self.e3.anti_geometric(rhs.e0)
+				+ self.s.anti_geometric(rhs.e03),
+			e13: self.e0.anti_geometric(rhs.e2)
+				+ self.e01.anti_geometric(rhs.e12)
+				+ self.e012.anti_geometric(rhs.e1)
+				+ self.e0123.anti_geometric(rhs.e13)
+				+ self.e013.anti_geometric(rhs.e123)
+				+ self.e02.anti_geometric(rhs.s)
+				+ self.e023.anti_geometric(rhs.e3)
+				+ self.e03.anti_geometric(rhs.e23)
+				+ self.e1.anti_geometric(rhs.e012)
+				+ self.e12.anti_geometric(rhs.e01)
+				+ self.e123.anti_geometric(rhs.e013)
+				+ self.e13.anti_geometric(rhs.e0123)
+				+ self.e2.anti_geometric(rhs.e0)
+				+ self.e23.anti_geometric(rhs.e03)
+				+ self.e3.anti_geometric(rhs.e023)
+				+ self.s.anti_geometric(rhs.e02),
+			e23: self.e0.anti_geometric(rhs.e1)
+				+ self.e01.anti_geometric(rhs.s)
+				+ self.e012.anti_geometric(rhs.e2)
+				+ self.e0123.anti_geometric(rhs.e23)
+				+ self.e013.anti_geometric(rhs.e3)
+				+ self.e02.anti_geometric(rhs.e12)
+				+ self.e023.anti_geometric(rhs.e123)
+				+ self.e03.anti_geometric(rhs.e13)
+				+ self.e1.anti_geometric(rhs.e0)
+				+ self.e12.anti_geometric(rhs.e02)
+				+ self.e123.anti_geometric(rhs.e023)
+				+ self.e13.anti_geometric(rhs.e03)
+				+ self.e2.anti_geometric(rhs.e012)
+				+ self.e23.anti_geometric(rhs.e0123)
+				+ self.e3.anti_geometric(rhs.e013)
+				+ self.s.anti_geometric(rhs.e01),
+			e012: self.e0.anti_geometric(rhs.e03)
+				+ self.e01.anti_geometric(rhs.e013)
+				+ self.e012.anti_geometric(rhs.e0123)
+				+ self.e0123.anti_geometric(rhs.e012)
+				+ self.e013.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e023)
+				+ self.e023.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e0)
+				+ self.e1.anti_geometric(rhs.e13)
+				+ self.e12.anti_geometric(rhs.e123)
+				+ self.e123.anti_geometric(rhs.e12)
+				+ self.e13.anti_geometric(rhs.e1)
+				+ self.e2.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e2)
+				+ self.e3.anti_geometric(rhs.s)
+				+ self.s.anti_geometric(rhs.e3),
+			e013: self.e0.anti_geometric(rhs.e02)
+				+ self.e01.anti_geometric(rhs.e012)
+				+ self.e012.anti_geometric(rhs.e01)
+				+ self.e0123.anti_geometric(rhs.e013)
+				+ self.e013.anti_geometric(rhs.e0123)
+				+ self.e02.anti_geometric(rhs.e0)
+				+ self.e023.anti_geometric(rhs.e03)
+				+ self.e03.anti_geometric(rhs.e023)
+				+ self.e1.anti_geometric(rhs.e12)
+				+ self.e12.anti_geometric(rhs.e1)
+				+ self.e123.anti_geometric(rhs.e13)
+				+ self.e13.anti_geometric(rhs.e123)
+				+ self.e2.anti_geometric(rhs.s)
+				+ self.e23.anti_geometric(rhs.e3)
+				+ self.e3.anti_geometric(rhs.e23)
+				+ self.s.anti_geometric(rhs.e2),
+			e023: self.e0.anti_geometric(rhs.e01)
+				+ self.e01.anti_geometric(rhs.e0)
+				+ self.e012.anti_geometric(rhs.e02)
+				+ self.e0123.anti_geometric(rhs.e023)
+				+ self.e013.anti_geometric(rhs.e03)
+				+ self.e02.anti_geometric(rhs.e012)
+				+ self.e023.anti_geometric(rhs.e0123)
+				+ self.e03.anti_geometric(rhs.e013)
+				+ self.e1.anti_geometric(rhs.s)
+				+ self.e12.anti_geometric(rhs.e2)
+				+ self.e123.anti_geometric(rhs.e23)
+				+ self.e13.anti_geometric(rhs.e3)
+				+ self.e2.anti_geometric(rhs.e12)
+				+ self.e23.anti_geometric(rhs.e123)
+				+ self.e3.anti_geometric(rhs.e13)
+				+ self.s.anti_geometric(rhs.e1),
+			e123: self.e0.anti_geometric(rhs.s)
+				+ self.e01.anti_geometric(rhs.e1)
+				+ self.e012.anti_geometric(rhs.e12)
+				+ self.e0123.anti_geometric(rhs.e123)
+				+ self.e013.anti_geometric(rhs.e13)
+				+ self.e02.anti_geometric(rhs.e2)
+				+ self.e023.anti_geometric(rhs.e23)
+				+ self.e03.anti_geometric(rhs.e3)
+				+ self.e1.anti_geometric(rhs.e01)
+				+ self.e12.anti_geometric(rhs.e012)
+				+ self.e123.anti_geometric(rhs.e0123)
+				+ self.e13.anti_geometric(rhs.e013)
+				+ self.e2.anti_geometric(rhs.e02)
+				+ self.e23.anti_geometric(rhs.e023)
+				+ self.e3.anti_geometric(rhs.e03)
+				+ self.s.anti_geometric(rhs.e0),
+			e0123: self.e0.anti_geometric(rhs.e0)
+				+ self.e01.anti_geometric(rhs.e01)
+				+ self.e012.anti_geometric(rhs.e012)
+				+ self.e0123.anti_geometric(rhs.e0123)
+				+ self.e013.anti_geometric(rhs.e013)
+				+ self.e02.anti_geometric(rhs.e02)
+				+ self.e023.anti_geometric(rhs.e023)
+				+ self.e03.anti_geometric(rhs.e03)
+				+ self.e1.anti_geometric(rhs.e1)
+				+ self.e12.anti_geometric(rhs.e12)
+				+ self.e123.anti_geometric(rhs.e123)
+				+ self.e13.anti_geometric(rhs.e13)
+				+ self.e2.anti_geometric(rhs.e2)
+				+ self.e23.anti_geometric(rhs.e23)
+				+ self.e3.anti_geometric(rhs.e3)
+				+ self.s.anti_geometric(rhs.s),
+		}
+	}
+}
+
+// Multivector.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0.0 * rhs.e0.0) + S(self.e01.0 * rhs.e01.0) - S(self.e012.0 * rhs.e012.0) - S(self.e0123.0 * rhs.e0123.0) - S(self.e013.0 * rhs.e013.0) + S(self.e02.0 * rhs.e02.0) - S(self.e023.0 * rhs.e023.0) + S(self.e03.0 * rhs.e03.0) - S(self.e1.0 * rhs.e1.0) - S(self.e12.0 * rhs.e12.0) + S(self.e123.0 * rhs.e123.0) - S(self.e13.0 * rhs.e13.0) - S(self.e2.0 * rhs.e2.0) - S(self.e23.0 * rhs.e23.0) - S(self.e3.0 * rhs.e3.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.e0.0 * rhs.s.0) - E0(self.e01.0 * rhs.e1.0) - E0(self.e012.0 * rhs.e12.0) + E0(self.e0123.0 * rhs.e123.0) - E0(self.e013.0 * rhs.e13.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e023.0 * rhs.e23.0) - E0(self.e03.0 * rhs.e3.0) + E0(self.e1.0 * rhs.e01.0) - E0(self.e12.0 * rhs.e012.0) - E0(self.e123.0 * rhs.e0123.0) - E0(self.e13.0 * rhs.e013.0) + E0(self.e2.0 * rhs.e02.0) - E0(self.e23.0 * rhs.e023.0) + E0(self.e3.0 * rhs.e03.0) + E0(self.s.0 * rhs.e0.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) - E1(self.e01.0 * rhs.e0.0) - E1(self.e012.0 * rhs.e02.0) + E1(self.e0123.0 * rhs.e023.0) - E1(self.e013.0 * rhs.e03.0) - E1(self.e02.0 * rhs.e012.0) - E1(self.e023.0 * rhs.e0123.0) - E1(self.e03.0 * rhs.e013.0) + E1(self.e1.0 * rhs.s.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e123.0 * rhs.e23.0) - E1(self.e13.0 * rhs.e3.0) + E1(self.e2.0 * rhs.e12.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.e3.0 * rhs.e13.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) + E2(self.e01.0 * rhs.e012.0) + E2(self.e012.0 * rhs.e01.0) - E2(self.e0123.0 * rhs.e013.0) + E2(self.e013.0 * rhs.e0123.0) - E2(self.e02.0 * rhs.e0.0) - E2(self.e023.0 * rhs.e03.0) - E2(self.e03.0 * rhs.e023.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e12.0 * rhs.e1.0) + E2(self.e123.0 * rhs.e13.0) + E2(self.e13.0 * rhs.e123.0) + E2(self.e2.0 * rhs.s.0) - E2(self.e23.0 * rhs.e3.0) + E2(self.e3.0 * rhs.e23.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) + E3(self.e01.0 * rhs.e013.0) - E3(self.e012.0 * rhs.e0123.0) + E3(self.e0123.0 * rhs.e012.0) + E3(self.e013.0 * rhs.e01.0) + E3(self.e02.0 * rhs.e023.0) + E3(self.e023.0 * rhs.e02.0) - E3(self.e03.0 * rhs.e0.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e12.0 * rhs.e123.0) - E3(self.e123.0 * rhs.e12.0) + E3(self.e13.0 * rhs.e1.0) - E3(self.e2.0 * rhs.e23.0) + E3(self.e23.0 * rhs.e2.0) + E3(self.e3.0 * rhs.s.0) + E3(self.s.0 * rhs.e3.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e012.0 * rhs.e2.0) - E01(self.e0123.0 * rhs.e23.0) - E01(self.e013.0 * rhs.e3.0) - E01(self.e2.0 * rhs.e012.0) - E01(self.e23.0 * rhs.e0123.0) - E01(self.e3.0 * rhs.e013.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e012.0 * rhs.e1.0) + E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0) - E02(self.e023.0 * rhs.e3.0) + E02(self.e1.0 * rhs.e012.0) + E02(self.e13.0 * rhs.e0123.0) - E02(self.e3.0 * rhs.e023.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0) + E03(self.e013.0 * rhs.e1.0) + E03(self.e023.0 * rhs.e2.0) + E03(self.e03.0 * rhs.s.0) + E03(self.e1.0 * rhs.e013.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.e2.0 * rhs.e023.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e0.0 * rhs.e012.0) + E12(self.e012.0 * rhs.e0.0) + E12(self.e0123.0 * rhs.e03.0) + E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0) - E12(self.e123.0 * rhs.e3.0) - E12(self.e3.0 * rhs.e123.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e0.0 * rhs.e013.0) - E13(self.e0123.0 * rhs.e02.0) + E13(self.e013.0 * rhs.e0.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e123.0 * rhs.e2.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e2.0 * rhs.e123.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e0.0 * rhs.e023.0) + E23(self.e01.0 * rhs.e0123.0) + E23(self.e0123.0 * rhs.e01.0) + E23(self.e023.0 * rhs.e0.0) - E23(self.e1.0 * rhs.e123.0) - E23(self.e123.0 * rhs.e1.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e012.0 * rhs.s.0) - E012(self.e0123.0 * rhs.e3.0) + E012(self.e3.0 * rhs.e0123.0) + E012(self.s.0 * rhs.e012.0),
+		//     e013 : E013(self.e0123.0 * rhs.e2.0) + E013(self.e013.0 * rhs.s.0) - E013(self.e2.0 * rhs.e0123.0) + E013(self.s.0 * rhs.e013.0),
+		//     e023 : -E023(self.e0123.0 * rhs.e1.0) + E023(self.e023.0 * rhs.s.0) + E023(self.e1.0 * rhs.e0123.0) + E023(self.s.0 * rhs.e023.0),
+		//     e123 : E123(self.e0.0 * rhs.e0123.0) - E123(self.e0123.0 * rhs.e0.0) + E123(self.e123.0 * rhs.s.0) + E123(self.s.0 * rhs.e123.0),
+		//     e0123: E0123(self.e0123.0 * rhs.s.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e0.dot(rhs.e0)
+				+ self.e01.dot(rhs.e01)
+				+ self.e012.dot(rhs.e012)
+				+ self.e0123.dot(rhs.e0123)
+				+ self.e013.dot(rhs.e013)
+				+ self.e02.dot(rhs.e02)
+				+ self.e023.dot(rhs.e023)
+				+ self.e03.dot(rhs.e03)
+				+ self.e1.dot(rhs.e1)
+				+ self.e12.dot(rhs.e12)
+				+ self.e123.dot(rhs.e123)
+				+ self.e13.dot(rhs.e13)
+				+ self.e2.dot(rhs.e2)
+				+ self.e23.dot(rhs.e23)
+				+ self.e3.dot(rhs.e3)
+				+ self.s.dot(rhs.s),
+			e0: self.e0.dot(rhs.s)
+				+ self.e01.dot(rhs.e1)
+				+ self.e012.dot(rhs.e12)
+				+ self.e0123.dot(rhs.e123)
+				+ self.e013.dot(rhs.e13)
+				+ self.e02.dot(rhs.e2)
+				+ self.e023.dot(rhs.e23)
+				+ self.e03.dot(rhs.e3)
+				+ self.e1.dot(rhs.e01)
+				+ self.e12.dot(rhs.e012)
+				+ self.e123.dot(rhs.e0123)
+				+ self.e13.dot(rhs.e013)
+				+ self.e2.dot(rhs.e02)
+				+ self.e23.dot(rhs.e023)
+				+ self.e3.dot(rhs.e03)
+				+ self.s.dot(rhs.e0),
+			e1: self.e0.dot(rhs.e01)
+				+ self.e01.dot(rhs.e0)
+				+ self.e012.dot(rhs.e02)
+				+ self.e0123.dot(rhs.e023)
+				+ self.e013.dot(rhs.e03)
+				+ self.e02.dot(rhs.e012)
+				+ self.e023.dot(rhs.e0123)
+				+ self.e03.dot(rhs.e013)
+				+ self.e1.dot(rhs.s)
+				+ self.e12.dot(rhs.e2)
+				+ self.e123.dot(rhs.e23)
+				+ self.e13.dot(rhs.e3)
+				+ self.e2.dot(rhs.e12)
+				+ self.e23.dot(rhs.e123)
+				+ self.e3.dot(rhs.e13)
+				+ self.s.dot(rhs.e1),
+			e2: self.e0.dot(rhs.e02)
+				+ self.e01.dot(rhs.e012)
+				+ self.e012.dot(rhs.e01)
+				+ self.e0123.dot(rhs.e013)
+				+ self.e013.dot(rhs.e0123)
+				+ self.e02.dot(rhs.e0)
+				+ self.e023.dot(rhs.e03)
+				+ self.e03.dot(rhs.e023)
+				+ self.e1.dot(rhs.e12)
+				+ self.e12.dot(rhs.e1)
+				+ self.e123.dot(rhs.e13)
+				+ self.e13.dot(rhs.e123)
+				+ self.e2.dot(rhs.s)
+				+ self.e23.dot(rhs.e3)
+				+ self.e3.dot(rhs.e23)
+				+ self.s.dot(rhs.e2),
+			e3: self.e0.dot(rhs.e03)
+				+ self.e01.dot(rhs.e013)
+				+ self.e012.dot(rhs.e0123)
+				+ self.e0123.dot(rhs.e012)
+				+ self.e013.dot(rhs.e01)
+				+ self.e02.dot(rhs.e023)
+				+ self.e023.dot(rhs.e02)
+				+ self.e03.dot(rhs.e0)
+				+ self.e1.dot(rhs.e13)
+				+ self.e12.dot(rhs.e123)
+				+ self.e123.dot(rhs.e12)
+				+ self.e13.dot(rhs.e1)
+				+ self.e2.dot(rhs.e23)
+				+ self.e23.dot(rhs.e2)
+				+ self.e3.dot(rhs.s)
+				+ self.s.dot(rhs.e3),
+			e01: self.e01.dot(rhs.s)
+				+ self.e012.dot(rhs.e2)
+				+ self.e0123.dot(rhs.e23)
+				+ self.e013.dot(rhs.e3)
+				+ self.e2.dot(rhs.e012)
+				+ self.e23.dot(rhs.e0123)
+				+ self.e3.dot(rhs.e013)
+				+ self.s.dot(rhs.e01),
+			e02: self.e012.dot(rhs.e1)
+				+ self.e0123.dot(rhs.e13)
+				+ self.e02.dot(rhs.s)
+				+ self.e023.dot(rhs.e3)
+				+ self.e1.dot(rhs.e012)
+				+ self.e13.dot(rhs.e0123)
+				+ self.e3.dot(rhs.e023)
+				+ self.s.dot(rhs.e02),
+			e03: self.e0123.dot(rhs.e12)
+				+ self.e013.dot(rhs.e1)
+				+ self.e023.dot(rhs.e2)
+				+ self.e03.dot(rhs.s)
+				+ self.e1.dot(rhs.e013)
+				+ self.e12.dot(rhs.e0123)
+				+ self.e2.dot(rhs.e023)
+				+ self.s.dot(rhs.e03),
+			e12: self.e0.dot(rhs.e012)
+				+ self.e012.dot(rhs.e0)
+				+ self.e0123.dot(rhs.e03)
+				+ self.e03.dot(rhs.e0123)
+				+ self.e12.dot(rhs.s)
+				+ self.e123.dot(rhs.e3)
+				+ self.e3.dot(rhs.e123)
+				+ self.s.dot(rhs.e12),
+			e13: self.e0.dot(rhs.e013)
+				+ self.e0123.dot(rhs.e02)
+				+ self.e013.dot(rhs.e0)
+				+ self.e02.dot(rhs.e0123)
+				+ self.e123.dot(rhs.e2)
+				+ self.e13.dot(rhs.s)
+				+ self.e2.dot(rhs.e123)
+				+ self.s.dot(rhs.e13),
+			e23: self.e0.dot(rhs.e023)
+				+ self.e01.dot(rhs.e0123)
+				+ self.e0123.dot(rhs.e01)
+				+ self.e023.dot(rhs.e0)
+				+ self.e1.dot(rhs.e123)
+				+ self.e123.dot(rhs.e1)
+				+ self.e23.dot(rhs.s)
+				+ self.s.dot(rhs.e23),
+			e012: self.e012.dot(rhs.s) + self.e0123.dot(rhs.e3) + self.e3.dot(rhs.e0123) + self.s.dot(rhs.e012),
+			e013: self.e0123.dot(rhs.e2) + self.e013.dot(rhs.s) + self.e2.dot(rhs.e0123) + self.s.dot(rhs.e013),
+			e023: self.e0123.dot(rhs.e1) + self.e023.dot(rhs.s) + self.e1.dot(rhs.e0123) + self.s.dot(rhs.e023),
+			e123: self.e0.dot(rhs.e0123) + self.e0123.dot(rhs.e0) + self.e123.dot(rhs.s) + self.s.dot(rhs.e123),
+			e0123: self.e0123.dot(rhs.s) + self.s.dot(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.e0.0 * rhs.s.0) + E0(self.s.0 * rhs.e0.0),
+		//     e1   : E1(self.e1.0 * rhs.s.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2   : E2(self.e2.0 * rhs.s.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3   : E3(self.e3.0 * rhs.s.0) + E3(self.s.0 * rhs.e3.0),
+		//     e01  : E01(self.e0.0 * rhs.e1.0) + E01(self.e01.0 * rhs.s.0) - E01(self.e1.0 * rhs.e0.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e0.0 * rhs.e2.0) + E02(self.e02.0 * rhs.s.0) - E02(self.e2.0 * rhs.e0.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : E03(self.e0.0 * rhs.e3.0) + E03(self.e03.0 * rhs.s.0) - E03(self.e3.0 * rhs.e0.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e1.0 * rhs.e2.0) + E12(self.e12.0 * rhs.s.0) - E12(self.e2.0 * rhs.e1.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e1.0 * rhs.e3.0) + E13(self.e13.0 * rhs.s.0) - E13(self.e3.0 * rhs.e1.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e2.0 * rhs.e3.0) + E23(self.e23.0 * rhs.s.0) - E23(self.e3.0 * rhs.e2.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e0.0 * rhs.e12.0) + E012(self.e01.0 * rhs.e2.0) + E012(self.e012.0 * rhs.s.0) - E012(self.e02.0 * rhs.e1.0) - E012(self.e1.0 * rhs.e02.0) + E012(self.e12.0 * rhs.e0.0) + E012(self.e2.0 * rhs.e01.0) + E012(self.s.0 * rhs.e012.0),
+		//     e013 : E013(self.e0.0 * rhs.e13.0) + E013(self.e01.0 * rhs.e3.0) + E013(self.e013.0 * rhs.s.0) - E013(self.e03.0 * rhs.e1.0) - E013(self.e1.0 * rhs.e03.0) + E013(self.e13.0 * rhs.e0.0) + E013(self.e3.0 * rhs.e01.0) + E013(self.s.0 * rhs.e013.0),
+		//     e023 : E023(self.e0.0 * rhs.e23.0) + E023(self.e02.0 * rhs.e3.0) + E023(self.e023.0 * rhs.s.0) - E023(self.e03.0 * rhs.e2.0) - E023(self.e2.0 * rhs.e03.0) + E023(self.e23.0 * rhs.e0.0) + E023(self.e3.0 * rhs.e02.0) + E023(self.s.0 * rhs.e023.0),
+		//     e123 : E123(self.e1.0 * rhs.e23.0) + E123(self.e12.0 * rhs.e3.0) + E123(self.e123.0 * rhs.s.0) - E123(self.e13.0 * rhs.e2.0) - E123(self.e2.0 * rhs.e13.0) + E123(self.e23.0 * rhs.e1.0) + E123(self.e3.0 * rhs.e12.0) + E123(self.s.0 * rhs.e123.0),
+		//     e0123: E0123(self.e0.0 * rhs.e123.0) + E0123(self.e01.0 * rhs.e23.0) + E0123(self.e012.0 * rhs.e3.0) + E0123(self.e0123.0 * rhs.s.0) - E0123(self.e013.0 * rhs.e2.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e023.0 * rhs.e1.0) + E0123(self.e03.0 * rhs.e12.0) - E0123(self.e1.0 * rhs.e023.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e123.0 * rhs.e0.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e2.0 * rhs.e013.0) + E0123(self.e23.0 * rhs.e01.0) - E0123(self.e3.0 * rhs.e012.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			e0: self.e0.wedge(rhs.s) + self.s.wedge(rhs.e0),
+			e1: self.e1.wedge(rhs.s) + self.s.wedge(rhs.e1),
+			e2: self.e2.wedge(rhs.s) + self.s.wedge(rhs.e2),
+			e3: self.e3.wedge(rhs.s) + self.s.wedge(rhs.e3),
+			e01: self.e0.wedge(rhs.e1) + self.e01.wedge(rhs.s) + self.e1.wedge(rhs.e0) + self.s.wedge(rhs.e01),
+			e02: self.e0.wedge(rhs.e2) + self.e02.wedge(rhs.s) + self.e2.wedge(rhs.e0) + self.s.wedge(rhs.e02),
+			e03: self.e0.wedge(rhs.e3) + self.e03.wedge(rhs.s) + self.e3.wedge(rhs.e0) + self.s.wedge(rhs.e03),
+			e12: self.e1.wedge(rhs.e2) + self.e12.wedge(rhs.s) + self.e2.wedge(rhs.e1) + self.s.wedge(rhs.e12),
+			e13: self.e1.wedge(rhs.e3) + self.e13.wedge(rhs.s) + self.e3.wedge(rhs.e1) + self.s.wedge(rhs.e13),
+			e23: self.e2.wedge(rhs.e3) + self.e23.wedge(rhs.s) + self.e3.wedge(rhs.e2) + self.s.wedge(rhs.e23),
+			e012: self.e0.wedge(rhs.e12)
+				+ self.e01.wedge(rhs.e2)
+				+ self.e012.wedge(rhs.s)
+				+ self.e02.wedge(rhs.e1)
+				+ self.e1.wedge(rhs.e02)
+				+ self.e12.wedge(rhs.e0)
+				+ self.e2.wedge(rhs.e01)
+				+ self.s.wedge(rhs.e012),
+			e013: self.e0.wedge(rhs.e13)
+				+ self.e01.wedge(rhs.e3)
+				+ self.e013.wedge(rhs.s)
+				+ self.e03.wedge(rhs.e1)
+				+ self.e1.wedge(rhs.e03)
+				+ self.e13.wedge(rhs.e0)
+				+ self.e3.wedge(rhs.e01)
+				+ self.s.wedge(rhs.e013),
+			e023: self.e0.wedge(rhs.e23)
+				+ self.e02.wedge(rhs.e3)
+				+ self.e023.wedge(rhs.s)
+				+ self.e03.wedge(rhs.e2)
+				+ self.e2.wedge(rhs.e03)
+				+ self.e23.wedge(rhs.e0)
+				+ self.e3.wedge(rhs.e02)
+				+ self.s.wedge(rhs.e023),
+			e123: self.e1.wedge(rhs.e23)
+				+ self.e12.wedge(rhs.e3)
+				+ self.e123.wedge(rhs.s)
+				+ self.e13.wedge(rhs.e2)
+				+ self.e2.wedge(rhs.e13)
+				+ self.e23.wedge(rhs.e1)
+				+ self.e3.wedge(rhs.e12)
+				+ self.s.wedge(rhs.e123),
+			e0123: self.e0.wedge(rhs.e123)
+				+ self.e01.wedge(rhs.e23)
+				+ self.e012.wedge(rhs.e3)
+				+ self.e0123.wedge(rhs.s)
+				+ self.e013.wedge(rhs.e2)
+				+ self.e02.wedge(rhs.e13)
+				+ self.e023.wedge(rhs.e1)
+				+ self.e03.wedge(rhs.e12)
+				+ self.e1.wedge(rhs.e023)
+				+ self.e12.wedge(rhs.e03)
+				+ self.e123.wedge(rhs.e0)
+				+ self.e13.wedge(rhs.e02)
+				+ self.e2.wedge(rhs.e013)
+				+ self.e23.wedge(rhs.e01)
+				+ self.e3.wedge(rhs.e012)
+				+ self.s.wedge(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0.0 * rhs.e123.0) + S(self.e01.0 * rhs.e23.0) + S(self.e012.0 * rhs.e3.0) + S(self.e0123.0 * rhs.s.0) - S(self.e013.0 * rhs.e2.0) - S(self.e02.0 * rhs.e13.0) + S(self.e023.0 * rhs.e1.0) + S(self.e03.0 * rhs.e12.0) - S(self.e1.0 * rhs.e023.0) + S(self.e12.0 * rhs.e03.0) - S(self.e123.0 * rhs.e0.0) - S(self.e13.0 * rhs.e02.0) + S(self.e2.0 * rhs.e013.0) + S(self.e23.0 * rhs.e01.0) - S(self.e3.0 * rhs.e012.0) + S(self.s.0 * rhs.e0123.0),
+		//     e0   : E0(self.e0.0 * rhs.e0123.0) + E0(self.e01.0 * rhs.e023.0) + E0(self.e012.0 * rhs.e03.0) + E0(self.e0123.0 * rhs.e0.0) - E0(self.e013.0 * rhs.e02.0) - E0(self.e02.0 * rhs.e013.0) + E0(self.e023.0 * rhs.e01.0) + E0(self.e03.0 * rhs.e012.0),
+		//     e1   : E1(self.e01.0 * rhs.e123.0) + E1(self.e012.0 * rhs.e13.0) + E1(self.e0123.0 * rhs.e1.0) - E1(self.e013.0 * rhs.e12.0) + E1(self.e1.0 * rhs.e0123.0) - E1(self.e12.0 * rhs.e013.0) + E1(self.e123.0 * rhs.e01.0) + E1(self.e13.0 * rhs.e012.0),
+		//     e2   : E2(self.e012.0 * rhs.e23.0) + E2(self.e0123.0 * rhs.e2.0) + E2(self.e02.0 * rhs.e123.0) - E2(self.e023.0 * rhs.e12.0) - E2(self.e12.0 * rhs.e023.0) + E2(self.e123.0 * rhs.e02.0) + E2(self.e2.0 * rhs.e0123.0) + E2(self.e23.0 * rhs.e012.0),
+		//     e3   : E3(self.e0123.0 * rhs.e3.0) + E3(self.e013.0 * rhs.e23.0) - E3(self.e023.0 * rhs.e13.0) + E3(self.e03.0 * rhs.e123.0) + E3(self.e123.0 * rhs.e03.0) - E3(self.e13.0 * rhs.e023.0) + E3(self.e23.0 * rhs.e013.0) + E3(self.e3.0 * rhs.e0123.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e012.0 * rhs.e013.0) + E01(self.e0123.0 * rhs.e01.0) - E01(self.e013.0 * rhs.e012.0),
+		//     e02  : E02(self.e012.0 * rhs.e023.0) + E02(self.e0123.0 * rhs.e02.0) + E02(self.e02.0 * rhs.e0123.0) - E02(self.e023.0 * rhs.e012.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0) + E03(self.e013.0 * rhs.e023.0) - E03(self.e023.0 * rhs.e013.0) + E03(self.e03.0 * rhs.e0123.0),
+		//     e12  : E12(self.e012.0 * rhs.e123.0) + E12(self.e0123.0 * rhs.e12.0) + E12(self.e12.0 * rhs.e0123.0) - E12(self.e123.0 * rhs.e012.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0) + E13(self.e013.0 * rhs.e123.0) - E13(self.e123.0 * rhs.e013.0) + E13(self.e13.0 * rhs.e0123.0),
+		//     e23  : E23(self.e0123.0 * rhs.e23.0) + E23(self.e023.0 * rhs.e123.0) - E23(self.e123.0 * rhs.e023.0) + E23(self.e23.0 * rhs.e0123.0),
+		//     e012 : E012(self.e012.0 * rhs.e0123.0) + E012(self.e0123.0 * rhs.e012.0),
+		//     e013 : E013(self.e0123.0 * rhs.e013.0) + E013(self.e013.0 * rhs.e0123.0),
+		//     e023 : E023(self.e0123.0 * rhs.e023.0) + E023(self.e023.0 * rhs.e0123.0),
+		//     e123 : E123(self.e0123.0 * rhs.e123.0) + E123(self.e123.0 * rhs.e0123.0),
+		//     e0123: E0123(self.e0123.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e0.anti_wedge(rhs.e123)
+				+ self.e01.anti_wedge(rhs.e23)
+				+ self.e012.anti_wedge(rhs.e3)
+				+ self.e0123.anti_wedge(rhs.s)
+				+ self.e013.anti_wedge(rhs.e2)
+				+ self.e02.anti_wedge(rhs.e13)
+				+ self.e023.anti_wedge(rhs.e1)
+				+ self.e03.anti_wedge(rhs.e12)
+				+ self.e1.anti_wedge(rhs.e023)
+				+ self.e12.anti_wedge(rhs.e03)
+				+ self.e123.anti_wedge(rhs.e0)
+				+ self.e13.anti_wedge(rhs.e02)
+				+ self.e2.anti_wedge(rhs.e013)
+				+ self.e23.anti_wedge(rhs.e01)
+				+ self.e3.anti_wedge(rhs.e012)
+				+ self.s.anti_wedge(rhs.e0123),
+			e0: self.e0.anti_wedge(rhs.e0123)
+				+ self.e01.anti_wedge(rhs.e023)
+				+ self.e012.anti_wedge(rhs.e03)
+				+ self.e0123.anti_wedge(rhs.e0)
+				+ self.e013.anti_wedge(rhs.e02)
+				+ self.e02.anti_wedge(rhs.e013)
+				+ self.e023.anti_wedge(rhs.e01)
+				+ self.e03.anti_wedge(rhs.e012),
+			e1: self.e01.anti_wedge(rhs.e123)
+				+ self.e012.anti_wedge(rhs.e13)
+				+ self.e0123.anti_wedge(rhs.e1)
+				+ self.e013.anti_wedge(rhs.e12)
+				+ self.e1.anti_wedge(rhs.e0123)
+				+ self.e12.anti_wedge(rhs.e013)
+				+ self.e123.anti_wedge(rhs.e01)
+				+ self.e13.anti_wedge(rhs.e012),
+			e2: self.e012.anti_wedge(rhs.e23)
+				+ self.e0123.anti_wedge(rhs.e2)
+				+ self.e02.anti_wedge(rhs.e123)
+				+ self.e023.anti_wedge(rhs.e12)
+				+ self.e12.anti_wedge(rhs.e023)
+				+ self.e123.anti_wedge(rhs.e02)
+				+ self.e2.anti_wedge(rhs.e0123)
+				+ self.e23.anti_wedge(rhs.e012),
+			e3: self.e0123.anti_wedge(rhs.e3)
+				+ self.e013.anti_wedge(rhs.e23)
+				+ self.e023.anti_wedge(rhs.e13)
+				+ self.e03.anti_wedge(rhs.e123)
+				+ self.e123.anti_wedge(rhs.e03)
+				+ self.e13.anti_wedge(rhs.e023)
+				+ self.e23.anti_wedge(rhs.e013)
+				+ self.e3.anti_wedge(rhs.e0123),
+			e01: self.e01.anti_wedge(rhs.e0123)
+				+ self.e012.anti_wedge(rhs.e013)
+				+ self.e0123.anti_wedge(rhs.e01)
+				+ self.e013.anti_wedge(rhs.e012),
+			e02: self.e012.anti_wedge(rhs.e023)
+				+ self.e0123.anti_wedge(rhs.e02)
+				+ self.e02.anti_wedge(rhs.e0123)
+				+ self.e023.anti_wedge(rhs.e012),
+			e03: self.e0123.anti_wedge(rhs.e03)
+				+ self.e013.anti_wedge(rhs.e023)
+				+ self.e023.anti_wedge(rhs.e013)
+				+ self.e03.anti_wedge(rhs.e0123),
+			e12: self.e012.anti_wedge(rhs.e123)
+				+ self.e0123.anti_wedge(rhs.e12)
+				+ self.e12.anti_wedge(rhs.e0123)
+				+ self.e123.anti_wedge(rhs.e012),
+			e13: self.e0123.anti_wedge(rhs.e13)
+				+ self.e013.anti_wedge(rhs.e123)
+				+ self.e123.anti_wedge(rhs.e013)
+				+ self.e13.anti_wedge(rhs.e0123),
+			e23: self.e0123.anti_wedge(rhs.e23)
+				+ self.e023.anti_wedge(rhs.e123)
+				+ self.e123.anti_wedge(rhs.e023)
+				+ self.e23.anti_wedge(rhs.e0123),
+			e012: self.e012.anti_wedge(rhs.e0123) + self.e0123.anti_wedge(rhs.e012),
+			e013: self.e0123.anti_wedge(rhs.e013) + self.e013.anti_wedge(rhs.e0123),
+			e023: self.e0123.anti_wedge(rhs.e023) + self.e023.anti_wedge(rhs.e0123),
+			e123: self.e0123.anti_wedge(rhs.e123) + self.e123.anti_wedge(rhs.e0123),
+			e0123: self.e0123.anti_wedge(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0.0 * rhs.e0.0) + S(self.e01.0 * rhs.e01.0) - S(self.e012.0 * rhs.e012.0) - S(self.e0123.0 * rhs.e0123.0) - S(self.e013.0 * rhs.e013.0) + S(self.e02.0 * rhs.e02.0) - S(self.e023.0 * rhs.e023.0) + S(self.e03.0 * rhs.e03.0) - S(self.e1.0 * rhs.e1.0) - S(self.e12.0 * rhs.e12.0) + S(self.e123.0 * rhs.e123.0) - S(self.e13.0 * rhs.e13.0) - S(self.e2.0 * rhs.e2.0) - S(self.e23.0 * rhs.e23.0) - S(self.e3.0 * rhs.e3.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.e1.0 * rhs.e01.0) - E0(self.e12.0 * rhs.e012.0) - E0(self.e123.0 * rhs.e0123.0) - E0(self.e13.0 * rhs.e013.0) + E0(self.e2.0 * rhs.e02.0) - E0(self.e23.0 * rhs.e023.0) + E0(self.e3.0 * rhs.e03.0) + E0(self.s.0 * rhs.e0.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) - E1(self.e02.0 * rhs.e012.0) - E1(self.e023.0 * rhs.e0123.0) - E1(self.e03.0 * rhs.e013.0) + E1(self.e2.0 * rhs.e12.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.e3.0 * rhs.e13.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) + E2(self.e01.0 * rhs.e012.0) + E2(self.e013.0 * rhs.e0123.0) - E2(self.e03.0 * rhs.e023.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e13.0 * rhs.e123.0) + E2(self.e3.0 * rhs.e23.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) + E3(self.e01.0 * rhs.e013.0) - E3(self.e012.0 * rhs.e0123.0) + E3(self.e02.0 * rhs.e023.0) - E3(self.e1.0 * rhs.e13.0) - E3(self.e12.0 * rhs.e123.0) - E3(self.e2.0 * rhs.e23.0) + E3(self.s.0 * rhs.e3.0),
+		//     e01  : -E01(self.e2.0 * rhs.e012.0) - E01(self.e23.0 * rhs.e0123.0) - E01(self.e3.0 * rhs.e013.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e1.0 * rhs.e012.0) + E02(self.e13.0 * rhs.e0123.0) - E02(self.e3.0 * rhs.e023.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : E03(self.e1.0 * rhs.e013.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.e2.0 * rhs.e023.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e0.0 * rhs.e012.0) + E12(self.e03.0 * rhs.e0123.0) - E12(self.e3.0 * rhs.e123.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e0.0 * rhs.e013.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e2.0 * rhs.e123.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e0.0 * rhs.e023.0) + E23(self.e01.0 * rhs.e0123.0) - E23(self.e1.0 * rhs.e123.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e3.0 * rhs.e0123.0) + E012(self.s.0 * rhs.e012.0),
+		//     e013 : -E013(self.e2.0 * rhs.e0123.0) + E013(self.s.0 * rhs.e013.0),
+		//     e023 : E023(self.e1.0 * rhs.e0123.0) + E023(self.s.0 * rhs.e023.0),
+		//     e123 : E123(self.e0.0 * rhs.e0123.0) + E123(self.s.0 * rhs.e123.0),
+		//     e0123: E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e0.left_contraction(rhs.e0)
+				+ self.e01.left_contraction(rhs.e01)
+				+ self.e012.left_contraction(rhs.e012)
+				+ self.e0123.left_contraction(rhs.e0123)
+				+ self.e013.left_contraction(rhs.e013)
+				+ self.e02.left_contraction(rhs.e02)
+				+ self.e023.left_contraction(rhs.e023)
+				+ self.e03.left_contraction(rhs.e03)
+				+ self.e1.left_contraction(rhs.e1)
+				+ self.e12.left_contraction(rhs.e12)
+				+ self.e123.left_contraction(rhs.e123)
+				+ self.e13.left_contraction(rhs.e13)
+				+ self.e2.left_contraction(rhs.e2)
+				+ self.e23.left_contraction(rhs.e23)
+				+ self.e3.left_contraction(rhs.e3)
+				+ self.s.left_contraction(rhs.s),
+			e0: self.e1.left_contraction(rhs.e01)
+				+ self.e12.left_contraction(rhs.e012)
+				+ self.e123.left_contraction(rhs.e0123)
+				+ self.e13.left_contraction(rhs.e013)
+				+ self.e2.left_contraction(rhs.e02)
+				+ self.e23.left_contraction(rhs.e023)
+				+ self.e3.left_contraction(rhs.e03)
+				+ self.s.left_contraction(rhs.e0),
+			e1: self.e0.left_contraction(rhs.e01)
+				+ self.e02.left_contraction(rhs.e012)
+				+ self.e023.left_contraction(rhs.e0123)
+				+ self.e03.left_contraction(rhs.e013)
+				+ self.e2.left_contraction(rhs.e12)
+				+ self.e23.left_contraction(rhs.e123)
+				+ self.e3.left_contraction(rhs.e13)
+				+ self.s.left_contraction(rhs.e1),
+			e2: self.e0.left_contraction(rhs.e02)
+				+ self.e01.left_contraction(rhs.e012)
+				+ self.e013.left_contraction(rhs.e0123)
+				+ self.e03.left_contraction(rhs.e023)
+				+ self.e1.left_contraction(rhs.e12)
+				+ self.e13.left_contraction(rhs.e123)
+				+ self.e3.left_contraction(rhs.e23)
+				+ self.s.left_contraction(rhs.e2),
+			e3: self.e0.left_contraction(rhs.e03)
+				+ self.e01.left_contraction(rhs.e013)
+				+ self.e012.left_contraction(rhs.e0123)
+				+ self.e02.left_contraction(rhs.e023)
+				+ self.e1.left_contraction(rhs.e13)
+				+ self.e12.left_contraction(rhs.e123)
+				+ self.e2.left_contraction(rhs.e23)
+				+ self.s.left_contraction(rhs.e3),
+			e01: self.e2.left_contraction(rhs.e012)
+				+ self.e23.left_contraction(rhs.e0123)
+				+ self.e3.left_contraction(rhs.e013)
+				+ self.s.left_contraction(rhs.e01),
+			e02: self.e1.left_contraction(rhs.e012)
+				+ self.e13.left_contraction(rhs.e0123)
+				+ self.e3.left_contraction(rhs.e023)
+				+ self.s.left_contraction(rhs.e02),
+			e03: self.e1.left_contraction(rhs.e013)
+				+ self.e12.left_contraction(rhs.e0123)
+				+ self.e2.left_contraction(rhs.e023)
+				+ self.s.left_contraction(rhs.e03),
+			e12: self.e0.left_contraction(rhs.e012)
+				+ self.e03.left_contraction(rhs.e0123)
+				+ self.e3.left_contraction(rhs.e123)
+				+ self.s.left_contraction(rhs.e12),
+			e13: self.e0.left_contraction(rhs.e013)
+				+ self.e02.left_contraction(rhs.e0123)
+				+ self.e2.left_contraction(rhs.e123)
+				+ self.s.left_contraction(rhs.e13),
+			e23: self.e0.left_contraction(rhs.e023)
+				+ self.e01.left_contraction(rhs.e0123)
+				+ self.e1.left_contraction(rhs.e123)
+				+ self.s.left_contraction(rhs.e23),
+			e012: self.e3.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e012),
+			e013: self.e2.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e013),
+			e023: self.e1.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e023),
+			e123: self.e0.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e123),
+			e0123: self.s.left_contraction(rhs.e0123),
+		}
+	}
+}
+
+// Multivector.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0.0 * rhs.e0.0) + S(self.e01.0 * rhs.e01.0) - S(self.e012.0 * rhs.e012.0) - S(self.e0123.0 * rhs.e0123.0) - S(self.e013.0 * rhs.e013.0) + S(self.e02.0 * rhs.e02.0) - S(self.e023.0 * rhs.e023.0) + S(self.e03.0 * rhs.e03.0) - S(self.e1.0 * rhs.e1.0) - S(self.e12.0 * rhs.e12.0) + S(self.e123.0 * rhs.e123.0) - S(self.e13.0 * rhs.e13.0) - S(self.e2.0 * rhs.e2.0) - S(self.e23.0 * rhs.e23.0) - S(self.e3.0 * rhs.e3.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.e0.0 * rhs.s.0) - E0(self.e01.0 * rhs.e1.0) - E0(self.e012.0 * rhs.e12.0) + E0(self.e0123.0 * rhs.e123.0) - E0(self.e013.0 * rhs.e13.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e023.0 * rhs.e23.0) - E0(self.e03.0 * rhs.e3.0),
+		//     e1   : -E1(self.e01.0 * rhs.e0.0) - E1(self.e012.0 * rhs.e02.0) + E1(self.e0123.0 * rhs.e023.0) - E1(self.e013.0 * rhs.e03.0) + E1(self.e1.0 * rhs.s.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e123.0 * rhs.e23.0) - E1(self.e13.0 * rhs.e3.0),
+		//     e2   : E2(self.e012.0 * rhs.e01.0) - E2(self.e0123.0 * rhs.e013.0) - E2(self.e02.0 * rhs.e0.0) - E2(self.e023.0 * rhs.e03.0) + E2(self.e12.0 * rhs.e1.0) + E2(self.e123.0 * rhs.e13.0) + E2(self.e2.0 * rhs.s.0) - E2(self.e23.0 * rhs.e3.0),
+		//     e3   : E3(self.e0123.0 * rhs.e012.0) + E3(self.e013.0 * rhs.e01.0) + E3(self.e023.0 * rhs.e02.0) - E3(self.e03.0 * rhs.e0.0) - E3(self.e123.0 * rhs.e12.0) + E3(self.e13.0 * rhs.e1.0) + E3(self.e23.0 * rhs.e2.0) + E3(self.e3.0 * rhs.s.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e012.0 * rhs.e2.0) - E01(self.e0123.0 * rhs.e23.0) - E01(self.e013.0 * rhs.e3.0),
+		//     e02  : E02(self.e012.0 * rhs.e1.0) + E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0) - E02(self.e023.0 * rhs.e3.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0) + E03(self.e013.0 * rhs.e1.0) + E03(self.e023.0 * rhs.e2.0) + E03(self.e03.0 * rhs.s.0),
+		//     e12  : E12(self.e012.0 * rhs.e0.0) + E12(self.e0123.0 * rhs.e03.0) + E12(self.e12.0 * rhs.s.0) - E12(self.e123.0 * rhs.e3.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0) + E13(self.e013.0 * rhs.e0.0) + E13(self.e123.0 * rhs.e2.0) + E13(self.e13.0 * rhs.s.0),
+		//     e23  : E23(self.e0123.0 * rhs.e01.0) + E23(self.e023.0 * rhs.e0.0) - E23(self.e123.0 * rhs.e1.0) + E23(self.e23.0 * rhs.s.0),
+		//     e012 : E012(self.e012.0 * rhs.s.0) - E012(self.e0123.0 * rhs.e3.0),
+		//     e013 : E013(self.e0123.0 * rhs.e2.0) + E013(self.e013.0 * rhs.s.0),
+		//     e023 : -E023(self.e0123.0 * rhs.e1.0) + E023(self.e023.0 * rhs.s.0),
+		//     e123 : -E123(self.e0123.0 * rhs.e0.0) + E123(self.e123.0 * rhs.s.0),
+		//     e0123: E0123(self.e0123.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.e0.right_contraction(rhs.e0)
+				+ self.e01.right_contraction(rhs.e01)
+				+ self.e012.right_contraction(rhs.e012)
+				+ self.e0123.right_contraction(rhs.e0123)
+				+ self.e013.right_contraction(rhs.e013)
+				+ self.e02.right_contraction(rhs.e02)
+				+ self.e023.right_contraction(rhs.e023)
+				+ self.e03.right_contraction(rhs.e03)
+				+ self.e1.right_contraction(rhs.e1)
+				+ self.e12.right_contraction(rhs.e12)
+				+ self.e123.right_contraction(rhs.e123)
+				+ self.e13.right_contraction(rhs.e13)
+				+ self.e2.right_contraction(rhs.e2)
+				+ self.e23.right_contraction(rhs.e23)
+				+ self.e3.right_contraction(rhs.e3)
+				+ self.s.right_contraction(rhs.s),
+			e0: self.e0.right_contraction(rhs.s)
+				+ self.e01.right_contraction(rhs.e1)
+				+ self.e012.right_contraction(rhs.e12)
+				+ self.e0123.right_contraction(rhs.e123)
+				+ self.e013.right_contraction(rhs.e13)
+				+ self.e02.right_contraction(rhs.e2)
+				+ self.e023.right_contraction(rhs.e23)
+				+ self.e03.right_contraction(rhs.e3),
+			e1: self.e01.right_contraction(rhs.e0)
+				+ self.e012.right_contraction(rhs.e02)
+				+ self.e0123.right_contraction(rhs.e023)
+				+ self.e013.right_contraction(rhs.e03)
+				+ self.e1.right_contraction(rhs.s)
+				+ self.e12.right_contraction(rhs.e2)
+				+ self.e123.right_contraction(rhs.e23)
+				+ self.e13.right_contraction(rhs.e3),
+			e2: self.e012.right_contraction(rhs.e01)
+				+ self.e0123.right_contraction(rhs.e013)
+				+ self.e02.right_contraction(rhs.e0)
+				+ self.e023.right_contraction(rhs.e03)
+				+ self.e12.right_contraction(rhs.e1)
+				+ self.e123.right_contraction(rhs.e13)
+				+ self.e2.right_contraction(rhs.s)
+				+ self.e23.right_contraction(rhs.e3),
+			e3: self.e0123.right_contraction(rhs.e012)
+				+ self.e013.right_contraction(rhs.e01)
+				+ self.e023.right_contraction(rhs.e02)
+				+ self.e03.right_contraction(rhs.e0)
+				+ self.e123.right_contraction(rhs.e12)
+				+ self.e13.right_contraction(rhs.e1)
+				+ self.e23.right_contraction(rhs.e2)
+				+ self.e3.right_contraction(rhs.s),
+			e01: self.e01.right_contraction(rhs.s)
+				+ self.e012.right_contraction(rhs.e2)
+				+ self.e0123.right_contraction(rhs.e23)
+				+ self.e013.right_contraction(rhs.e3),
+			e02: self.e012.right_contraction(rhs.e1)
+				+ self.e0123.right_contraction(rhs.e13)
+				+ self.e02.right_contraction(rhs.s)
+				+ self.e023.right_contraction(rhs.e3),
+			e03: self.e0123.right_contraction(rhs.e12)
+				+ self.e013.right_contraction(rhs.e1)
+				+ self.e023.right_contraction(rhs.e2)
+				+ self.e03.right_contraction(rhs.s),
+			e12: self.e012.right_contraction(rhs.e0)
+				+ self.e0123.right_contraction(rhs.e03)
+				+ self.e12.right_contraction(rhs.s)
+				+ self.e123.right_contraction(rhs.e3),
+			e13: self.e0123.right_contraction(rhs.e02)
+				+ self.e013.right_contraction(rhs.e0)
+				+ self.e123.right_contraction(rhs.e2)
+				+ self.e13.right_contraction(rhs.s),
+			e23: self.e0123.right_contraction(rhs.e01)
+				+ self.e023.right_contraction(rhs.e0)
+				+ self.e123.right_contraction(rhs.e1)
+				+ self.e23.right_contraction(rhs.s),
+			e012: self.e012.right_contraction(rhs.s) + self.e0123.right_contraction(rhs.e3),
+			e013: self.e0123.right_contraction(rhs.e2) + self.e013.right_contraction(rhs.s),
+			e023: self.e0123.right_contraction(rhs.e1) + self.e023.right_contraction(rhs.s),
+			e123: self.e0123.right_contraction(rhs.e0) + self.e123.right_contraction(rhs.s),
+			e0123: self.e0123.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Multivector.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.e0.0 * rhs.e0.0) + S(self.e01.0 * rhs.e01.0) - S(self.e012.0 * rhs.e012.0) - S(self.e0123.0 * rhs.e0123.0) - S(self.e013.0 * rhs.e013.0) + S(self.e02.0 * rhs.e02.0) - S(self.e023.0 * rhs.e023.0) + S(self.e03.0 * rhs.e03.0) - S(self.e1.0 * rhs.e1.0) - S(self.e12.0 * rhs.e12.0) + S(self.e123.0 * rhs.e123.0) - S(self.e13.0 * rhs.e13.0) - S(self.e2.0 * rhs.e2.0) - S(self.e23.0 * rhs.e23.0) - S(self.e3.0 * rhs.e3.0) + S(self.s.0 * rhs.s.0)
+		self.e0.scalar_product(rhs.e0)
+			+ self.e01.scalar_product(rhs.e01)
+			+ self.e012.scalar_product(rhs.e012)
+			+ self.e0123.scalar_product(rhs.e0123)
+			+ self.e013.scalar_product(rhs.e013)
+			+ self.e02.scalar_product(rhs.e02)
+			+ self.e023.scalar_product(rhs.e023)
+			+ self.e03.scalar_product(rhs.e03)
+			+ self.e1.scalar_product(rhs.e1)
+			+ self.e12.scalar_product(rhs.e12)
+			+ self.e123.scalar_product(rhs.e123)
+			+ self.e13.scalar_product(rhs.e13)
+			+ self.e2.scalar_product(rhs.e2)
+			+ self.e23.scalar_product(rhs.e23)
+			+ self.e3.scalar_product(rhs.e3)
+			+ self.s.scalar_product(rhs.s)
+	}
+}
+
+// Multivector.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : -E0(self.e01.0 * rhs.e1.0) + E0(self.e0123.0 * rhs.e123.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e03.0 * rhs.e3.0) + E0(self.e1.0 * rhs.e01.0) - E0(self.e123.0 * rhs.e0123.0) + E0(self.e2.0 * rhs.e02.0) + E0(self.e3.0 * rhs.e03.0),
+		//     e1   : E1(self.e0.0 * rhs.e01.0) - E1(self.e01.0 * rhs.e0.0) + E1(self.e0123.0 * rhs.e023.0) - E1(self.e023.0 * rhs.e0123.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e13.0 * rhs.e3.0) + E1(self.e2.0 * rhs.e12.0) + E1(self.e3.0 * rhs.e13.0),
+		//     e2   : E2(self.e0.0 * rhs.e02.0) - E2(self.e0123.0 * rhs.e013.0) + E2(self.e013.0 * rhs.e0123.0) - E2(self.e02.0 * rhs.e0.0) - E2(self.e1.0 * rhs.e12.0) + E2(self.e12.0 * rhs.e1.0) - E2(self.e23.0 * rhs.e3.0) + E2(self.e3.0 * rhs.e23.0),
+		//     e3   : E3(self.e0.0 * rhs.e03.0) - E3(self.e012.0 * rhs.e0123.0) + E3(self.e0123.0 * rhs.e012.0) - E3(self.e03.0 * rhs.e0.0) - E3(self.e1.0 * rhs.e13.0) + E3(self.e13.0 * rhs.e1.0) - E3(self.e2.0 * rhs.e23.0) + E3(self.e23.0 * rhs.e2.0),
+		//     e01  : E01(self.e0.0 * rhs.e1.0) + E01(self.e02.0 * rhs.e12.0) - E01(self.e023.0 * rhs.e123.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e1.0 * rhs.e0.0) - E01(self.e12.0 * rhs.e02.0) + E01(self.e123.0 * rhs.e023.0) - E01(self.e13.0 * rhs.e03.0),
+		//     e02  : E02(self.e0.0 * rhs.e2.0) - E02(self.e01.0 * rhs.e12.0) + E02(self.e013.0 * rhs.e123.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e123.0 * rhs.e013.0) - E02(self.e2.0 * rhs.e0.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03  : E03(self.e0.0 * rhs.e3.0) - E03(self.e01.0 * rhs.e13.0) - E03(self.e012.0 * rhs.e123.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e123.0 * rhs.e012.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0) - E03(self.e3.0 * rhs.e0.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e013.0 * rhs.e023.0) + E12(self.e02.0 * rhs.e01.0) - E12(self.e023.0 * rhs.e013.0) + E12(self.e1.0 * rhs.e2.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e2.0 * rhs.e1.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) - E13(self.e012.0 * rhs.e023.0) + E13(self.e023.0 * rhs.e012.0) + E13(self.e03.0 * rhs.e01.0) + E13(self.e1.0 * rhs.e3.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0) - E13(self.e3.0 * rhs.e1.0),
+		//     e23  : E23(self.e012.0 * rhs.e013.0) - E23(self.e013.0 * rhs.e012.0) - E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0) + E23(self.e2.0 * rhs.e3.0) - E23(self.e3.0 * rhs.e2.0),
+		//     e012 : -E012(self.e0123.0 * rhs.e3.0) + E012(self.e013.0 * rhs.e23.0) - E012(self.e023.0 * rhs.e13.0) - E012(self.e03.0 * rhs.e123.0) + E012(self.e123.0 * rhs.e03.0) + E012(self.e13.0 * rhs.e023.0) - E012(self.e23.0 * rhs.e013.0) + E012(self.e3.0 * rhs.e0123.0),
+		//     e013 : -E013(self.e012.0 * rhs.e23.0) + E013(self.e0123.0 * rhs.e2.0) + E013(self.e02.0 * rhs.e123.0) + E013(self.e023.0 * rhs.e12.0) - E013(self.e12.0 * rhs.e023.0) - E013(self.e123.0 * rhs.e02.0) - E013(self.e2.0 * rhs.e0123.0) + E013(self.e23.0 * rhs.e012.0),
+		//     e023 : -E023(self.e01.0 * rhs.e123.0) + E023(self.e012.0 * rhs.e13.0) - E023(self.e0123.0 * rhs.e1.0) - E023(self.e013.0 * rhs.e12.0) + E023(self.e1.0 * rhs.e0123.0) + E023(self.e12.0 * rhs.e013.0) + E023(self.e123.0 * rhs.e01.0) - E023(self.e13.0 * rhs.e012.0),
+		//     e123 : E123(self.e0.0 * rhs.e0123.0) - E123(self.e01.0 * rhs.e023.0) + E123(self.e012.0 * rhs.e03.0) - E123(self.e0123.0 * rhs.e0.0) - E123(self.e013.0 * rhs.e02.0) + E123(self.e02.0 * rhs.e013.0) + E123(self.e023.0 * rhs.e01.0) - E123(self.e03.0 * rhs.e012.0),
+		//     e0123: E0123(self.e0.0 * rhs.e123.0) + E0123(self.e012.0 * rhs.e3.0) - E0123(self.e013.0 * rhs.e2.0) + E0123(self.e023.0 * rhs.e1.0) - E0123(self.e1.0 * rhs.e023.0) - E0123(self.e123.0 * rhs.e0.0) + E0123(self.e2.0 * rhs.e013.0) - E0123(self.e3.0 * rhs.e012.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e01.commutator(rhs.e1)
+				+ self.e0123.commutator(rhs.e123)
+				+ self.e02.commutator(rhs.e2)
+				+ self.e03.commutator(rhs.e3)
+				+ self.e1.commutator(rhs.e01)
+				+ self.e123.commutator(rhs.e0123)
+				+ self.e2.commutator(rhs.e02)
+				+ self.e3.commutator(rhs.e03),
+			e1: self.e0.commutator(rhs.e01)
+				+ self.e01.commutator(rhs.e0)
+				+ self.e0123.commutator(rhs.e023)
+				+ self.e023.commutator(rhs.e0123)
+				+ self.e12.commutator(rhs.e2)
+				+ self.e13.commutator(rhs.e3)
+				+ self.e2.commutator(rhs.e12)
+				+ self.e3.commutator(rhs.e13),
+			e2: self.e0.commutator(rhs.e02)
+				+ self.e0123.commutator(rhs.e013)
+				+ self.e013.commutator(rhs.e0123)
+				+ self.e02.commutator(rhs.e0)
+				+ self.e1.commutator(rhs.e12)
+				+ self.e12.commutator(rhs.e1)
+				+ self.e23.commutator(rhs.e3)
+				+ self.e3.commutator(rhs.e23),
+			e3: self.e0.commutator(rhs.e03)
+				+ self.e012.commutator(rhs.e0123)
+				+ self.e0123.commutator(rhs.e012)
+				+ self.e03.commutator(rhs.e0)
+				+ self.e1.commutator(rhs.e13)
+				+ self.e13.commutator(rhs.e1)
+				+ self.e2.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e2),
+			e01: self.e0.commutator(rhs.e1)
+				+ self.e02.commutator(rhs.e12)
+				+ self.e023.commutator(rhs.e123)
+				+ self.e03.commutator(rhs.e13)
+				+ self.e1.commutator(rhs.e0)
+				+ self.e12.commutator(rhs.e02)
+				+ self.e123.commutator(rhs.e023)
+				+ self.e13.commutator(rhs.e03),
+			e02: self.e0.commutator(rhs.e2)
+				+ self.e01.commutator(rhs.e12)
+				+ self.e013.commutator(rhs.e123)
+				+ self.e03.commutator(rhs.e23)
+				+ self.e12.commutator(rhs.e01)
+				+ self.e123.commutator(rhs.e013)
+				+ self.e2.commutator(rhs.e0)
+				+ self.e23.commutator(rhs.e03),
+			e03: self.e0.commutator(rhs.e3)
+				+ self.e01.commutator(rhs.e13)
+				+ self.e012.commutator(rhs.e123)
+				+ self.e02.commutator(rhs.e23)
+				+ self.e123.commutator(rhs.e012)
+				+ self.e13.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e02)
+				+ self.e3.commutator(rhs.e0),
+			e12: self.e01.commutator(rhs.e02)
+				+ self.e013.commutator(rhs.e023)
+				+ self.e02.commutator(rhs.e01)
+				+ self.e023.commutator(rhs.e013)
+				+ self.e1.commutator(rhs.e2)
+				+ self.e13.commutator(rhs.e23)
+				+ self.e2.commutator(rhs.e1)
+				+ self.e23.commutator(rhs.e13),
+			e13: self.e01.commutator(rhs.e03)
+				+ self.e012.commutator(rhs.e023)
+				+ self.e023.commutator(rhs.e012)
+				+ self.e03.commutator(rhs.e01)
+				+ self.e1.commutator(rhs.e3)
+				+ self.e12.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e12)
+				+ self.e3.commutator(rhs.e1),
+			e23: self.e012.commutator(rhs.e013)
+				+ self.e013.commutator(rhs.e012)
+				+ self.e02.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e02)
+				+ self.e12.commutator(rhs.e13)
+				+ self.e13.commutator(rhs.e12)
+				+ self.e2.commutator(rhs.e3)
+				+ self.e3.commutator(rhs.e2),
+			e012: self.e0123.commutator(rhs.e3)
+				+ self.e013.commutator(rhs.e23)
+				+ self.e023.commutator(rhs.e13)
+				+ self.e03.commutator(rhs.e123)
+				+ self.e123.commutator(rhs.e03)
+				+ self.e13.commutator(rhs.e023)
+				+ self.e23.commutator(rhs.e013)
+				+ self.e3.commutator(rhs.e0123),
+			e013: self.e012.commutator(rhs.e23)
+				+ self.e0123.commutator(rhs.e2)
+				+ self.e02.commutator(rhs.e123)
+				+ self.e023.commutator(rhs.e12)
+				+ self.e12.commutator(rhs.e023)
+				+ self.e123.commutator(rhs.e02)
+				+ self.e2.commutator(rhs.e0123)
+				+ self.e23.commutator(rhs.e012),
+			e023: self.e01.commutator(rhs.e123)
+				+ self.e012.commutator(rhs.e13)
+				+ self.e0123.commutator(rhs.e1)
+				+ self.e013.commutator(rhs.e12)
+				+ self.e1.commutator(rhs.e0123)
+				+ self.e12.commutator(rhs.e013)
+				+ self.e123.commutator(rhs.e01)
+				+ self.e13.commutator(rhs.e012),
+			e123: self.e0.commutator(rhs.e0123)
+				+ self.e01.commutator(rhs.e023)
+				+ self.e012.commutator(rhs.e03)
+				+ self.e0123.commutator(rhs.e0)
+				+ self.e013.commutator(rhs.e02)
+				+ self.e02.commutator(rhs.e013)
+				+ self.e023.commutator(rhs.e01)
+				+ self.e03.commutator(rhs.e012),
+			e0123: self.e0.commutator(rhs.e123)
+				+ self.e012.commutator(rhs.e3)
+				+ self.e013.commutator(rhs.e2)
+				+ self.e023.commutator(rhs.e1)
+				+ self.e1.commutator(rhs.e023)
+				+ self.e123.commutator(rhs.e0)
+				+ self.e2.commutator(rhs.e013)
+				+ self.e3.commutator(rhs.e012),
+		}
+	}
+}
+
+// Multivector.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0123.0 * rhs.s.0) + S(self.s.0 * rhs.e0123.0),
+		//     e0   : E0(self.e0.0 * rhs.e0123.0) + E0(self.e0123.0 * rhs.e0.0) - E0(self.e123.0 * rhs.s.0) + E0(self.s.0 * rhs.e123.0),
+		//     e1   : E1(self.e0123.0 * rhs.e1.0) - E1(self.e023.0 * rhs.s.0) + E1(self.e1.0 * rhs.e0123.0) + E1(self.s.0 * rhs.e023.0),
+		//     e2   : E2(self.e0123.0 * rhs.e2.0) + E2(self.e013.0 * rhs.s.0) + E2(self.e2.0 * rhs.e0123.0) - E2(self.s.0 * rhs.e013.0),
+		//     e3   : -E3(self.e012.0 * rhs.s.0) + E3(self.e0123.0 * rhs.e3.0) + E3(self.e3.0 * rhs.e0123.0) + E3(self.s.0 * rhs.e012.0),
+		//     e01  : -E01(self.e0.0 * rhs.e023.0) + E01(self.e01.0 * rhs.e0123.0) + E01(self.e0123.0 * rhs.e01.0) - E01(self.e023.0 * rhs.e0.0) + E01(self.e1.0 * rhs.e123.0) + E01(self.e123.0 * rhs.e1.0) + E01(self.e23.0 * rhs.s.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : E02(self.e0.0 * rhs.e013.0) + E02(self.e0123.0 * rhs.e02.0) + E02(self.e013.0 * rhs.e0.0) + E02(self.e02.0 * rhs.e0123.0) + E02(self.e123.0 * rhs.e2.0) - E02(self.e13.0 * rhs.s.0) + E02(self.e2.0 * rhs.e123.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : -E03(self.e0.0 * rhs.e012.0) - E03(self.e012.0 * rhs.e0.0) + E03(self.e0123.0 * rhs.e03.0) + E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0) + E03(self.e123.0 * rhs.e3.0) + E03(self.e3.0 * rhs.e123.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0) + E12(self.e013.0 * rhs.e1.0) + E12(self.e023.0 * rhs.e2.0) - E12(self.e03.0 * rhs.s.0) + E12(self.e1.0 * rhs.e013.0) + E12(self.e12.0 * rhs.e0123.0) + E12(self.e2.0 * rhs.e023.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : -E13(self.e012.0 * rhs.e1.0) + E13(self.e0123.0 * rhs.e13.0) + E13(self.e02.0 * rhs.s.0) + E13(self.e023.0 * rhs.e3.0) - E13(self.e1.0 * rhs.e012.0) + E13(self.e13.0 * rhs.e0123.0) + E13(self.e3.0 * rhs.e023.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) - E23(self.e012.0 * rhs.e2.0) + E23(self.e0123.0 * rhs.e23.0) - E23(self.e013.0 * rhs.e3.0) - E23(self.e2.0 * rhs.e012.0) + E23(self.e23.0 * rhs.e0123.0) - E23(self.e3.0 * rhs.e013.0) - E23(self.s.0 * rhs.e01.0),
+		//     e012 : -E012(self.e0.0 * rhs.e03.0) - E012(self.e01.0 * rhs.e013.0) + E012(self.e012.0 * rhs.e0123.0) + E012(self.e0123.0 * rhs.e012.0) + E012(self.e013.0 * rhs.e01.0) - E012(self.e02.0 * rhs.e023.0) + E012(self.e023.0 * rhs.e02.0) - E012(self.e03.0 * rhs.e0.0) + E012(self.e1.0 * rhs.e13.0) + E012(self.e12.0 * rhs.e123.0) - E012(self.e123.0 * rhs.e12.0) + E012(self.e13.0 * rhs.e1.0) + E012(self.e2.0 * rhs.e23.0) + E012(self.e23.0 * rhs.e2.0) + E012(self.e3.0 * rhs.s.0) - E012(self.s.0 * rhs.e3.0),
+		//     e013 : E013(self.e0.0 * rhs.e02.0) + E013(self.e01.0 * rhs.e012.0) - E013(self.e012.0 * rhs.e01.0) + E013(self.e0123.0 * rhs.e013.0) + E013(self.e013.0 * rhs.e0123.0) + E013(self.e02.0 * rhs.e0.0) + E013(self.e023.0 * rhs.e03.0) - E013(self.e03.0 * rhs.e023.0) - E013(self.e1.0 * rhs.e12.0) - E013(self.e12.0 * rhs.e1.0) - E013(self.e123.0 * rhs.e13.0) + E013(self.e13.0 * rhs.e123.0) - E013(self.e2.0 * rhs.s.0) + E013(self.e23.0 * rhs.e3.0) + E013(self.e3.0 * rhs.e23.0) + E013(self.s.0 * rhs.e2.0),
+		//     e023 : -E023(self.e0.0 * rhs.e01.0) - E023(self.e01.0 * rhs.e0.0) - E023(self.e012.0 * rhs.e02.0) + E023(self.e0123.0 * rhs.e023.0) - E023(self.e013.0 * rhs.e03.0) + E023(self.e02.0 * rhs.e012.0) + E023(self.e023.0 * rhs.e0123.0) + E023(self.e03.0 * rhs.e013.0) + E023(self.e1.0 * rhs.s.0) - E023(self.e12.0 * rhs.e2.0) - E023(self.e123.0 * rhs.e23.0) - E023(self.e13.0 * rhs.e3.0) - E023(self.e2.0 * rhs.e12.0) + E023(self.e23.0 * rhs.e123.0) - E023(self.e3.0 * rhs.e13.0) - E023(self.s.0 * rhs.e1.0),
+		//     e123 : E123(self.e0.0 * rhs.s.0) - E123(self.e01.0 * rhs.e1.0) - E123(self.e012.0 * rhs.e12.0) + E123(self.e0123.0 * rhs.e123.0) - E123(self.e013.0 * rhs.e13.0) - E123(self.e02.0 * rhs.e2.0) - E123(self.e023.0 * rhs.e23.0) - E123(self.e03.0 * rhs.e3.0) - E123(self.e1.0 * rhs.e01.0) + E123(self.e12.0 * rhs.e012.0) + E123(self.e123.0 * rhs.e0123.0) + E123(self.e13.0 * rhs.e013.0) - E123(self.e2.0 * rhs.e02.0) + E123(self.e23.0 * rhs.e023.0) - E123(self.e3.0 * rhs.e03.0) - E123(self.s.0 * rhs.e0.0),
+		//     e0123: E0123(self.e0.0 * rhs.e0.0) - E0123(self.e01.0 * rhs.e01.0) - E0123(self.e012.0 * rhs.e012.0) + E0123(self.e0123.0 * rhs.e0123.0) - E0123(self.e013.0 * rhs.e013.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e023.0 * rhs.e023.0) - E0123(self.e03.0 * rhs.e03.0) - E0123(self.e1.0 * rhs.e1.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e123.0 * rhs.e123.0) + E0123(self.e13.0 * rhs.e13.0) - E0123(self.e2.0 * rhs.e2.0) + E0123(self.e23.0 * rhs.e23.0) - E0123(self.e3.0 * rhs.e3.0) - E0123(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.e0123.anti_dot(rhs.s) + self.s.anti_dot(rhs.e0123),
+			e0: self.e0.anti_dot(rhs.e0123)
+				+ self.e0123.anti_dot(rhs.e0)
+				+ self.e123.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e123),
+			e1: self.e0123.anti_dot(rhs.e1)
+				+ self.e023.anti_dot(rhs.s)
+				+ self.e1.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e023),
+			e2: self.e0123.anti_dot(rhs.e2)
+				+ self.e013.anti_dot(rhs.s)
+				+ self.e2.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e013),
+			e3: self.e012.anti_dot(rhs.s)
+				+ self.e0123.anti_dot(rhs.e3)
+				+ self.e3.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e012),
+			e01: self.e0.anti_dot(rhs.e023)
+				+ self.e01.anti_dot(rhs.e0123)
+				+ self.e0123.anti_dot(rhs.e01)
+				+ self.e023.anti_dot(rhs.e0)
+				+ self.e1.anti_dot(rhs.e123)
+				+ self.e123.anti_dot(rhs.e1)
+				+ self.e23.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e23),
+			e02: self.e0.anti_dot(rhs.e013)
+				+ self.e0123.anti_dot(rhs.e02)
+				+ self.e013.anti_dot(rhs.e0)
+				+ self.e02.anti_dot(rhs.e0123)
+				+ self.e123.anti_dot(rhs.e2)
+				+ self.e13.anti_dot(rhs.s)
+				+ self.e2.anti_dot(rhs.e123)
+				+ self.s.anti_dot(rhs.e13),
+			e03: self.e0.anti_dot(rhs.e012)
+				+ self.e012.anti_dot(rhs.e0)
+				+ self.e0123.anti_dot(rhs.e03)
+				+ self.e03.anti_dot(rhs.e0123)
+				+ self.e12.anti_dot(rhs.s)
+				+ self.e123.anti_dot(rhs.e3)
+				+ self.e3.anti_dot(rhs.e123)
+				+ self.s.anti_dot(rhs.e12),
+			e12: self.e0123.anti_dot(rhs.e12)
+				+ self.e013.anti_dot(rhs.e1)
+				+ self.e023.anti_dot(rhs.e2)
+				+ self.e03.anti_dot(rhs.s)
+				+ self.e1.anti_dot(rhs.e013)
+				+ self.e12.anti_dot(rhs.e0123)
+				+ self.e2.anti_dot(rhs.e023)
+				+ self.s.anti_dot(rhs.e03),
+			e13: self.e012.anti_dot(rhs.e1)
+				+ self.e0123.anti_dot(rhs.e13)
+				+ self.e02.anti_dot(rhs.s)
+				+ self.e023.anti_dot(rhs.e3)
+				+ self.e1.anti_dot(rhs.e012)
+				+ self.e13.anti_dot(rhs.e0123)
+				+ self.e3.anti_dot(rhs.e023)
+				+ self.s.anti_dot(rhs.e02),
+			e23: self.e01.anti_dot(rhs.s)
+				+ self.e012.anti_dot(rhs.e2)
+				+ self.e0123.anti_dot(rhs.e23)
+				+ self.e013.anti_dot(rhs.e3)
+				+ self.e2.anti_dot(rhs.e012)
+				+ self.e23.anti_dot(rhs.e0123)
+				+ self.e3.anti_dot(rhs.e013)
+				+ self.s.anti_dot(rhs.e01),
+			e012: self.e0.anti_dot(rhs.e03)
+				+ self.e01.anti_dot(rhs.e013)
+				+ self.e012.anti_dot(rhs.e0123)
+				+ self.e0123.anti_dot(rhs.e012)
+				+ self.e013.anti_dot(rhs.e01)
+				+ self.e02.anti_dot(rhs.e023)
+				+ self.e023.anti_dot(rhs.e02)
+				+ self.e03.anti_dot(rhs.e0)
+				+ self.e1.anti_dot(rhs.e13)
+				+ self.e12.anti_dot(rhs.e123)
+				+ self.e123.anti_dot(rhs.e12)
+				+ self.e13.anti_dot(rhs.e1)
+				+ self.e2.anti_dot(rhs.e23)
+				+ self.e23.anti_dot(rhs.e2)
+				+ self.e3.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e3),
+			e013: self.e0.anti_dot(rhs.e02)
+				+ self.e01.anti_dot(rhs.e012)
+				+ self.e012.anti_dot(rhs.e01)
+				+ self.e0123.anti_dot(rhs.e013)
+				+ self.e013.anti_dot(rhs.e0123)
+				+ self.e02.anti_dot(rhs.e0)
+				+ self.e023.anti_dot(rhs.e03)
+				+ self.e03.anti_dot(rhs.e023)
+				+ self.e1.anti_dot(rhs.e12)
+				+ self.e12.anti_dot(rhs.e1)
+				+ self.e123.anti_dot(rhs.e13)
+				+ self.e13.anti_dot(rhs.e123)
+				+ self.e2.anti_dot(rhs.s)
+				+ self.e23.anti_dot(rhs.e3)
+				+ self.e3.anti_dot(rhs.e23)
+				+ self.s.anti_dot(rhs.e2),
+			e023: self.e0.anti_dot(rhs.e01)
+				+ self.e01.anti_dot(rhs.e0)
+				+ self.e012.anti_dot(rhs.e02)
+				+ self.e0123.anti_dot(rhs.e023)
+				+ self.e013.anti_dot(rhs.e03)
+				+ self.e02.anti_dot(rhs.e012)
+				+ self.e023.anti_dot(rhs.e0123)
+				+ self.e03.anti_dot(rhs.e013)
+				+ self.e1.anti_dot(rhs.s)
+				+ self.e12.anti_dot(rhs.e2)
+				+ self.e123.anti_dot(rhs.e23)
+				+ self.e13.anti_dot(rhs.e3)
+				+ self.e2.anti_dot(rhs.e12)
+				+ self.e23.anti_dot(rhs.e123)
+				+ self.e3.anti_dot(rhs.e13)
+				+ self.s.anti_dot(rhs.e1),
+			e123: self.e0.anti_dot(rhs.s)
+				+ self.e01.anti_dot(rhs.e1)
+				+ self.e012.anti_dot(rhs.e12)
+				+ self.e0123.anti_dot(rhs.e123)
+				+ self.e013.anti_dot(rhs.e13)
+				+ self.e02.anti_dot(rhs.e2)
+				+ self.e023.anti_dot(rhs.e23)
+				+ self.e03.anti_dot(rhs.e3)
+				+ self.e1.anti_dot(rhs.e01)
+				+ self.e12.anti_dot(rhs.e012)
+				+ self.e123.anti_dot(rhs.e0123)
+				+ self.e13.anti_dot(rhs.e013)
+				+ self.e2.anti_dot(rhs.e02)
+				+ self.e23.anti_dot(rhs.e023)
+				+ self.e3.anti_dot(rhs.e03)
+				+ self.s.anti_dot(rhs.e0),
+			e0123: self.e0.anti_dot(rhs.e0)
+				+ self.e01.anti_dot(rhs.e01)
+				+ self.e012.anti_dot(rhs.e012)
+				+ self.e0123.anti_dot(rhs.e0123)
+				+ self.e013.anti_dot(rhs.e013)
+				+ self.e02.anti_dot(rhs.e02)
+				+ self.e023.anti_dot(rhs.e023)
+				+ self.e03.anti_dot(rhs.e03)
+				+ self.e1.anti_dot(rhs.e1)
+				+ self.e12.anti_dot(rhs.e12)
+				+ self.e123.anti_dot(rhs.e123)
+				+ self.e13.anti_dot(rhs.e13)
+				+ self.e2.anti_dot(rhs.e2)
+				+ self.e23.anti_dot(rhs.e23)
+				+ self.e3.anti_dot(rhs.e3)
+				+ self.s.anti_dot(rhs.s),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Multivector.sandwich(Vec4)  (not a Vec4)
+// Omitted: Multivector.anti_sandwich(Vec4)  (not a Vec4)
+// Omitted: Multivector.sandwich(Bivector)  (not a Bivector)
+// Omitted: Multivector.anti_sandwich(Bivector)  (not a Bivector)
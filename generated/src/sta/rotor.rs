@@ -0,0 +1,3267 @@
+//! # Rotor
+//!
+//! ## Operations
+//! ```text
+//! Rotor.geometric(Rotor) -> Rotor
+//! Rotor.anti_geometric(Rotor) -> Rotor
+//! Rotor.dot(Rotor) -> Rotor
+//! Rotor.wedge(Rotor) -> Rotor
+//! Rotor.anti_wedge(Rotor) -> Rotor
+//! Rotor.left_contraction(Rotor) -> Rotor
+//! Rotor.right_contraction(Rotor) -> Rotor
+//! Rotor.scalar_product(Rotor) -> S
+//! Rotor.commutator(Rotor) -> Bivector
+//! Rotor.anti_dot(Rotor) -> Rotor
+//! Rotor.geometric(Vec4) -> Multivector
+//! Vec4.geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(Vec4) -> Multivector
+//! Vec4.dot(Rotor) -> Multivector
+//! Rotor.wedge(Vec4) -> Multivector
+//! Vec4.wedge(Rotor) -> Multivector
+//! Rotor.anti_wedge(Vec4) -> Vec4
+//! Vec4.anti_wedge(Rotor) -> Vec4
+//! Rotor.left_contraction(Vec4) -> Vec4
+//! Vec4.left_contraction(Rotor) -> Multivector
+//! Rotor.right_contraction(Vec4) -> Multivector
+//! Vec4.right_contraction(Rotor) -> Vec4
+//! Rotor.commutator(Vec4) -> Multivector
+//! Vec4.commutator(Rotor) -> Multivector
+//! Rotor.anti_dot(Vec4) -> Multivector
+//! Vec4.anti_dot(Rotor) -> Multivector
+//! Rotor.geometric(Bivector) -> Rotor
+//! Bivector.geometric(Rotor) -> Rotor
+//! Rotor.anti_geometric(Bivector) -> Rotor
+//! Bivector.anti_geometric(Rotor) -> Rotor
+//! Rotor.dot(Bivector) -> Rotor
+//! Bivector.dot(Rotor) -> Rotor
+//! Rotor.wedge(Bivector) -> Rotor
+//! Bivector.wedge(Rotor) -> Rotor
+//! Rotor.anti_wedge(Bivector) -> Rotor
+//! Bivector.anti_wedge(Rotor) -> Rotor
+//! Rotor.left_contraction(Bivector) -> Rotor
+//! Bivector.left_contraction(Rotor) -> Rotor
+//! Rotor.right_contraction(Bivector) -> Rotor
+//! Bivector.right_contraction(Rotor) -> Rotor
+//! Rotor.scalar_product(Bivector) -> S
+//! Bivector.scalar_product(Rotor) -> S
+//! Rotor.commutator(Bivector) -> Bivector
+//! Bivector.commutator(Rotor) -> Bivector
+//! Rotor.anti_dot(Bivector) -> Rotor
+//! Bivector.anti_dot(Rotor) -> Rotor
+//! Rotor.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Rotor) -> Multivector
+//! Rotor.dot(Multivector) -> Multivector
+//! Multivector.dot(Rotor) -> Multivector
+//! Rotor.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Rotor) -> Multivector
+//! Rotor.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Rotor) -> Multivector
+//! Rotor.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Rotor) -> Multivector
+//! Rotor.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Rotor) -> Multivector
+//! Rotor.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Rotor) -> S
+//! Rotor.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Rotor) -> Multivector
+//! Rotor.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Rotor) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rotor {
+	pub s: S,
+	pub e01: E01,
+	pub e02: E02,
+	pub e03: E03,
+	pub e12: E12,
+	pub e13: E13,
+	pub e23: E23,
+	pub e0123: E0123,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Rotor {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Rotor {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Rotor {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.e01.abs_diff_eq(&other.e01, epsilon)
+			&& self.e02.abs_diff_eq(&other.e02, epsilon)
+			&& self.e03.abs_diff_eq(&other.e03, epsilon)
+			&& self.e12.abs_diff_eq(&other.e12, epsilon)
+			&& self.e13.abs_diff_eq(&other.e13, epsilon)
+			&& self.e23.abs_diff_eq(&other.e23, epsilon)
+			&& self.e0123.abs_diff_eq(&other.e0123, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Rotor {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.e01.relative_eq(&other.e01, epsilon, max_relative)
+			&& self.e02.relative_eq(&other.e02, epsilon, max_relative)
+			&& self.e03.relative_eq(&other.e03, epsilon, max_relative)
+			&& self.e12.relative_eq(&other.e12, epsilon, max_relative)
+			&& self.e13.relative_eq(&other.e13, epsilon, max_relative)
+			&& self.e23.relative_eq(&other.e23, epsilon, max_relative)
+			&& self.e0123.relative_eq(&other.e0123, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Rotor {
+	type Output = Rotor;
+	fn rcompl(self) -> Self::Output {
+		Rotor {
+			s: self.e0123.rcompl(),
+			e01: self.e23.rcompl(),
+			e02: self.e13.rcompl(),
+			e03: self.e12.rcompl(),
+			e12: self.e03.rcompl(),
+			e13: self.e02.rcompl(),
+			e23: self.e01.rcompl(),
+			e0123: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Rotor {
+	type Output = Rotor;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Rotor {
+	type Output = Rotor;
+	fn lcompl(self) -> Self::Output {
+		Rotor {
+			s: self.e0123.lcompl(),
+			e01: self.e23.lcompl(),
+			e02: self.e13.lcompl(),
+			e03: self.e12.lcompl(),
+			e12: self.e03.lcompl(),
+			e13: self.e02.lcompl(),
+			e23: self.e01.lcompl(),
+			e0123: self.s.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Rotor {
+	fn rev(self) -> Self {
+		Rotor {
+			s: self.s,
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+			e0123: self.e0123,
+		}
+	}
+}
+
+impl AntiReverse for Rotor {
+	fn arev(self) -> Self {
+		Rotor {
+			s: self.s,
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+			e0123: self.e0123,
+		}
+	}
+}
+
+impl Involute for Rotor {
+	fn involute(self) -> Self {
+		Rotor {
+			s: self.s,
+			e01: self.e01,
+			e02: self.e02,
+			e03: self.e03,
+			e12: self.e12,
+			e13: self.e13,
+			e23: self.e23,
+			e0123: self.e0123,
+		}
+	}
+}
+
+impl Conjugate for Rotor {
+	fn conjugate(self) -> Self {
+		Rotor {
+			s: self.s,
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+			e0123: self.e0123,
+		}
+	}
+}
+
+impl Dual for Rotor {
+	type Output = Rotor;
+	fn dual(self) -> Self::Output {
+		Rotor {
+			s: self.e0123.dual(),
+			e01: self.e23.dual(),
+			e02: self.e13.dual(),
+			e03: self.e12.dual(),
+			e12: self.e03.dual(),
+			e13: self.e02.dual(),
+			e23: self.e01.dual(),
+			e0123: self.s.dual(),
+		}
+	}
+}
+
+impl Bulk for Rotor {
+	type Output = Rotor;
+	fn bulk(self) -> Self::Output {
+		Rotor {
+			s: self.s,
+			e01: -self.e01,
+			e02: -self.e02,
+			e03: -self.e03,
+			e12: self.e12,
+			e13: self.e13,
+			e23: self.e23,
+			e0123: -self.e0123,
+		}
+	}
+}
+
+impl Weight for Rotor {
+	type Output = Rotor;
+	fn weight(self) -> Self::Output {
+		Rotor {
+			s: -self.s,
+			e01: self.e01,
+			e02: self.e02,
+			e03: self.e03,
+			e12: -self.e12,
+			e13: -self.e13,
+			e23: -self.e23,
+			e0123: self.e0123,
+		}
+	}
+}
+
+impl BulkDual for Rotor {
+	type Output = Rotor;
+	fn bulk_dual(self) -> Self::Output {
+		Rotor {
+			s: self.e0123.bulk_dual(),
+			e01: self.e23.bulk_dual(),
+			e02: self.e13.bulk_dual(),
+			e03: self.e12.bulk_dual(),
+			e12: self.e03.bulk_dual(),
+			e13: self.e02.bulk_dual(),
+			e23: self.e01.bulk_dual(),
+			e0123: self.s.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Rotor {
+	type Output = Rotor;
+	fn weight_dual(self) -> Self::Output {
+		Rotor {
+			s: self.e0123.weight_dual(),
+			e01: self.e23.weight_dual(),
+			e02: self.e13.weight_dual(),
+			e03: self.e12.weight_dual(),
+			e12: self.e03.weight_dual(),
+			e13: self.e02.weight_dual(),
+			e23: self.e01.weight_dual(),
+			e0123: self.s.weight_dual(),
+		}
+	}
+}
+
+// Omitted: Rotor.square()  (not a scalar)
+
+impl Norm for Rotor {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0
+			+ self.e01.0 * self.e01.0
+			+ self.e02.0 * self.e02.0
+			+ self.e03.0 * self.e03.0
+			+ self.e12.0 * self.e12.0
+			+ self.e13.0 * self.e13.0
+			+ self.e23.0 * self.e23.0
+			+ self.e0123.0 * self.e0123.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0
+			+ self.e01.0 * self.e01.0
+			+ self.e02.0 * self.e02.0
+			+ self.e03.0 * self.e03.0
+			+ self.e12.0 * self.e12.0
+			+ self.e13.0 * self.e13.0
+			+ self.e23.0 * self.e23.0
+			+ self.e0123.0 * self.e0123.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Rotor {
+			s: self.s * scale,
+			e01: self.e01 * scale,
+			e02: self.e02 * scale,
+			e03: self.e03 * scale,
+			e12: self.e12 * scale,
+			e13: self.e13 * scale,
+			e23: self.e23 * scale,
+			e0123: self.e0123 * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Rotor {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 8] {
+		[
+			self.s.0,
+			self.e01.0,
+			self.e02.0,
+			self.e03.0,
+			self.e12.0,
+			self.e13.0,
+			self.e23.0,
+			self.e0123.0,
+		]
+	}
+}
+
+impl From<[f64; 8]> for Rotor {
+	fn from(a: [f64; 8]) -> Self {
+		Rotor {
+			s: S(a[0]),
+			e01: E01(a[1]),
+			e02: E02(a[2]),
+			e03: E03(a[3]),
+			e12: E12(a[4]),
+			e13: E13(a[5]),
+			e23: E23(a[6]),
+			e0123: E0123(a[7]),
+		}
+	}
+}
+
+impl From<Rotor> for [f64; 8] {
+	fn from(v: Rotor) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Rotor> for Multivector {
+	fn from(v: Rotor) -> Self {
+		Multivector {
+			s: v.s,
+			e01: v.e01,
+			e02: v.e02,
+			e03: v.e03,
+			e12: v.e12,
+			e13: v.e13,
+			e23: v.e23,
+			e0123: v.e0123,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Rotor {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Rotor` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.e0.0 == 0.0
+			&& mv.e1.0 == 0.0
+			&& mv.e2.0 == 0.0
+			&& mv.e3.0 == 0.0
+			&& mv.e012.0 == 0.0
+			&& mv.e013.0 == 0.0
+			&& mv.e023.0 == 0.0
+			&& mv.e123.0 == 0.0
+		{
+			Ok(Rotor {
+				s: mv.s,
+				e01: mv.e01,
+				e02: mv.e02,
+				e03: mv.e03,
+				e12: mv.e12,
+				e13: mv.e13,
+				e23: mv.e23,
+				e0123: mv.e0123,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Rotor {
+	/// The matrix that transforms `[t, x, y, z]` like `self.sandwich(v)` for a `Vec4` `v`,
+	/// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+	pub fn to_matrix(self) -> [[f64; 4]; 4] {
+		[
+			[
+				self.e01.0 * self.e01.0
+					+ self.e0123.0 * self.e0123.0
+					+ self.e02.0 * self.e02.0
+					+ self.e03.0 * self.e03.0
+					+ self.e12.0 * self.e12.0
+					+ self.e13.0 * self.e13.0
+					+ self.e23.0 * self.e23.0
+					+ self.s.0 * self.s.0,
+				-2.0 * self.e01.0 * self.s.0
+					- 2.0 * self.e0123.0 * self.e23.0
+					- 2.0 * self.e02.0 * self.e12.0
+					- 2.0 * self.e03.0 * self.e13.0,
+				2.0 * self.e01.0 * self.e12.0 + 2.0 * self.e0123.0 * self.e13.0
+					- 2.0 * self.e02.0 * self.s.0
+					- 2.0 * self.e03.0 * self.e23.0,
+				2.0 * self.e01.0 * self.e13.0 - 2.0 * self.e0123.0 * self.e12.0 + 2.0 * self.e02.0 * self.e23.0
+					- 2.0 * self.e03.0 * self.s.0,
+			],
+			[
+				-2.0 * self.e01.0 * self.s.0 - 2.0 * self.e0123.0 * self.e23.0
+					+ 2.0 * self.e02.0 * self.e12.0
+					+ 2.0 * self.e03.0 * self.e13.0,
+				self.e01.0 * self.e01.0 + self.e0123.0 * self.e0123.0
+					- self.e02.0 * self.e02.0
+					- self.e03.0 * self.e03.0
+					- self.e12.0 * self.e12.0
+					- self.e13.0 * self.e13.0
+					+ self.e23.0 * self.e23.0
+					+ self.s.0 * self.s.0,
+				2.0 * self.e01.0 * self.e02.0 + 2.0 * self.e0123.0 * self.e03.0
+					- 2.0 * self.e12.0 * self.s.0
+					- 2.0 * self.e13.0 * self.e23.0,
+				2.0 * self.e01.0 * self.e03.0 - 2.0 * self.e0123.0 * self.e02.0 + 2.0 * self.e12.0 * self.e23.0
+					- 2.0 * self.e13.0 * self.s.0,
+			],
+			[
+				-2.0 * self.e01.0 * self.e12.0 + 2.0 * self.e0123.0 * self.e13.0 - 2.0 * self.e02.0 * self.s.0
+					+ 2.0 * self.e03.0 * self.e23.0,
+				2.0 * self.e01.0 * self.e02.0 - 2.0 * self.e0123.0 * self.e03.0 + 2.0 * self.e12.0 * self.s.0
+					- 2.0 * self.e13.0 * self.e23.0,
+				-self.e01.0 * self.e01.0 + self.e0123.0 * self.e0123.0 + self.e02.0 * self.e02.0
+					- self.e03.0 * self.e03.0
+					- self.e12.0 * self.e12.0
+					+ self.e13.0 * self.e13.0
+					- self.e23.0 * self.e23.0
+					+ self.s.0 * self.s.0,
+				2.0 * self.e01.0 * self.e0123.0 + 2.0 * self.e02.0 * self.e03.0
+					- 2.0 * self.e12.0 * self.e13.0
+					- 2.0 * self.e23.0 * self.s.0,
+			],
+			[
+				-2.0 * self.e01.0 * self.e13.0
+					- 2.0 * self.e0123.0 * self.e12.0
+					- 2.0 * self.e02.0 * self.e23.0
+					- 2.0 * self.e03.0 * self.s.0,
+				2.0 * self.e01.0 * self.e03.0
+					+ 2.0 * self.e0123.0 * self.e02.0
+					+ 2.0 * self.e12.0 * self.e23.0
+					+ 2.0 * self.e13.0 * self.s.0,
+				-2.0 * self.e01.0 * self.e0123.0 + 2.0 * self.e02.0 * self.e03.0 - 2.0 * self.e12.0 * self.e13.0
+					+ 2.0 * self.e23.0 * self.s.0,
+				-self.e01.0 * self.e01.0 + self.e0123.0 * self.e0123.0 - self.e02.0 * self.e02.0
+					+ self.e03.0 * self.e03.0
+					+ self.e12.0 * self.e12.0
+					- self.e13.0 * self.e13.0
+					- self.e23.0 * self.e23.0
+					+ self.s.0 * self.s.0,
+			],
+		]
+	}
+}
+
+impl Rotor {
+	/// The versor that moves nothing, whose matrix is the identity matrix.
+	pub fn identity() -> Self {
+		Rotor {
+			s: S(1.0),
+			e01: E01(0.0),
+			e02: E02(0.0),
+			e03: E03(0.0),
+			e12: E12(0.0),
+			e13: E13(0.0),
+			e23: E23(0.0),
+			e0123: E0123(0.0),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Vec4:
+
+// Rotor.geometric(Vec4) -> Multivector
+impl Geometric<Vec4> for Rotor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0) + E0(self.s.0 * rhs.t.0),
+		//     e1   : -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+		//     e2   : -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+		//     e3   : -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.e01.0 * rhs.y.0) - E012(self.e0123.0 * rhs.z.0) - E012(self.e02.0 * rhs.x.0) + E012(self.e12.0 * rhs.t.0),
+		//     e013 : E013(self.e01.0 * rhs.z.0) + E013(self.e0123.0 * rhs.y.0) - E013(self.e03.0 * rhs.x.0) + E013(self.e13.0 * rhs.t.0),
+		//     e023 : -E023(self.e0123.0 * rhs.x.0) + E023(self.e02.0 * rhs.z.0) - E023(self.e03.0 * rhs.y.0) + E023(self.e23.0 * rhs.t.0),
+		//     e123 : -E123(self.e0123.0 * rhs.t.0) + E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e01.geometric(rhs.x)
+				+ self.e02.geometric(rhs.y)
+				+ self.e03.geometric(rhs.z)
+				+ self.s.geometric(rhs.t),
+			e1: self.e01.geometric(rhs.t)
+				+ self.e12.geometric(rhs.y)
+				+ self.e13.geometric(rhs.z)
+				+ self.s.geometric(rhs.x),
+			e2: self.e02.geometric(rhs.t)
+				+ self.e12.geometric(rhs.x)
+				+ self.e23.geometric(rhs.z)
+				+ self.s.geometric(rhs.y),
+			e3: self.e03.geometric(rhs.t)
+				+ self.e13.geometric(rhs.x)
+				+ self.e23.geometric(rhs.y)
+				+ self.s.geometric(rhs.z),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e01.geometric(rhs.y)
+				+ self.e0123.geometric(rhs.z)
+				+ self.e02.geometric(rhs.x)
+				+ self.e12.geometric(rhs.t),
+			e013: self.e01.geometric(rhs.z)
+				+ self.e0123.geometric(rhs.y)
+				+ self.e03.geometric(rhs.x)
+				+ self.e13.geometric(rhs.t),
+			e023: self.e0123.geometric(rhs.x)
+				+ self.e02.geometric(rhs.z)
+				+ self.e03.geometric(rhs.y)
+				+ self.e23.geometric(rhs.t),
+			e123: self.e0123.geometric(rhs.t)
+				+ self.e12.geometric(rhs.z)
+				+ self.e13.geometric(rhs.y)
+				+ self.e23.geometric(rhs.x),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec4> for Rotor {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Vec4) -> Multivector
+impl AntiGeometric<Vec4> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.e0123.0 * rhs.t.0) + E0(self.e12.0 * rhs.z.0) - E0(self.e13.0 * rhs.y.0) + E0(self.e23.0 * rhs.x.0),
+		//     e1   : E1(self.e0123.0 * rhs.x.0) + E1(self.e02.0 * rhs.z.0) - E1(self.e03.0 * rhs.y.0) + E1(self.e23.0 * rhs.t.0),
+		//     e2   : -E2(self.e01.0 * rhs.z.0) + E2(self.e0123.0 * rhs.y.0) + E2(self.e03.0 * rhs.x.0) - E2(self.e13.0 * rhs.t.0),
+		//     e3   : E3(self.e01.0 * rhs.y.0) + E3(self.e0123.0 * rhs.z.0) - E3(self.e02.0 * rhs.x.0) + E3(self.e12.0 * rhs.t.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.e03.0 * rhs.t.0) + E012(self.e13.0 * rhs.x.0) + E012(self.e23.0 * rhs.y.0) - E012(self.s.0 * rhs.z.0),
+		//     e013 : E013(self.e02.0 * rhs.t.0) - E013(self.e12.0 * rhs.x.0) + E013(self.e23.0 * rhs.z.0) + E013(self.s.0 * rhs.y.0),
+		//     e023 : -E023(self.e01.0 * rhs.t.0) - E023(self.e12.0 * rhs.y.0) - E023(self.e13.0 * rhs.z.0) - E023(self.s.0 * rhs.x.0),
+		//     e123 : -E123(self.e01.0 * rhs.x.0) - E123(self.e02.0 * rhs.y.0) - E123(self.e03.0 * rhs.z.0) - E123(self.s.0 * rhs.t.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e0123.anti_geometric(rhs.t)
+				+ self.e12.anti_geometric(rhs.z)
+				+ self.e13.anti_geometric(rhs.y)
+				+ self.e23.anti_geometric(rhs.x),
+			e1: self.e0123.anti_geometric(rhs.x)
+				+ self.e02.anti_geometric(rhs.z)
+				+ self.e03.anti_geometric(rhs.y)
+				+ self.e23.anti_geometric(rhs.t),
+			e2: self.e01.anti_geometric(rhs.z)
+				+ self.e0123.anti_geometric(rhs.y)
+				+ self.e03.anti_geometric(rhs.x)
+				+ self.e13.anti_geometric(rhs.t),
+			e3: self.e01.anti_geometric(rhs.y)
+				+ self.e0123.anti_geometric(rhs.z)
+				+ self.e02.anti_geometric(rhs.x)
+				+ self.e12.anti_geometric(rhs.t),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e03.anti_geometric(rhs.t)
+				+ self.e13.anti_geometric(rhs.x)
+				+ self.e23.anti_geometric(rhs.y)
+				+ self.s.anti_geometric(rhs.z),
+			e013: self.e02.anti_geometric(rhs.t)
+				+ self.e12.anti_geometric(rhs.x)
+				+ self.e23.anti_geometric(rhs.z)
+				+ self.s.anti_geometric(rhs.y),
+			e023: self.e01.anti_geometric(rhs.t)
+				+ self.e12.anti_geometric(rhs.y)
+				+ self.e13.anti_geometric(rhs.z)
+				+ self.s.anti_geometric(rhs.x),
+			e123: self.e01.anti_geometric(rhs.x)
+				+ self.e02.anti_geometric(rhs.y)
+				+ self.e03.anti_geometric(rhs.z)
+				+ self.s.anti_geometric(rhs.t),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Rotor.dot(Vec4) -> Multivector
+impl Dot<Vec4> for Rotor {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0) + E0(self.s.0 * rhs.t.0),
+		//     e1   : -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0) + E1(self.s.0 * rhs.x.0),
+		//     e2   : -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0) + E2(self.s.0 * rhs.y.0),
+		//     e3   : -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0) + E3(self.s.0 * rhs.z.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.e0123.0 * rhs.z.0),
+		//     e013 : E013(self.e0123.0 * rhs.y.0),
+		//     e023 : -E023(self.e0123.0 * rhs.x.0),
+		//     e123 : -E123(self.e0123.0 * rhs.t.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e01.dot(rhs.x) + self.e02.dot(rhs.y) + self.e03.dot(rhs.z) + self.s.dot(rhs.t),
+			e1: self.e01.dot(rhs.t) + self.e12.dot(rhs.y) + self.e13.dot(rhs.z) + self.s.dot(rhs.x),
+			e2: self.e02.dot(rhs.t) + self.e12.dot(rhs.x) + self.e23.dot(rhs.z) + self.s.dot(rhs.y),
+			e3: self.e03.dot(rhs.t) + self.e13.dot(rhs.x) + self.e23.dot(rhs.y) + self.s.dot(rhs.z),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e0123.dot(rhs.z),
+			e013: self.e0123.dot(rhs.y),
+			e023: self.e0123.dot(rhs.x),
+			e123: self.e0123.dot(rhs.t),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec4> for Rotor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Vec4) -> Multivector
+impl Wedge<Vec4> for Rotor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.s.0 * rhs.t.0),
+		//     e1   : E1(self.s.0 * rhs.x.0),
+		//     e2   : E2(self.s.0 * rhs.y.0),
+		//     e3   : E3(self.s.0 * rhs.z.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.e01.0 * rhs.y.0) - E012(self.e02.0 * rhs.x.0) + E012(self.e12.0 * rhs.t.0),
+		//     e013 : E013(self.e01.0 * rhs.z.0) - E013(self.e03.0 * rhs.x.0) + E013(self.e13.0 * rhs.t.0),
+		//     e023 : E023(self.e02.0 * rhs.z.0) - E023(self.e03.0 * rhs.y.0) + E023(self.e23.0 * rhs.t.0),
+		//     e123 : E123(self.e12.0 * rhs.z.0) - E123(self.e13.0 * rhs.y.0) + E123(self.e23.0 * rhs.x.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.s.wedge(rhs.t),
+			e1: self.s.wedge(rhs.x),
+			e2: self.s.wedge(rhs.y),
+			e3: self.s.wedge(rhs.z),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e01.wedge(rhs.y) + self.e02.wedge(rhs.x) + self.e12.wedge(rhs.t),
+			e013: self.e01.wedge(rhs.z) + self.e03.wedge(rhs.x) + self.e13.wedge(rhs.t),
+			e023: self.e02.wedge(rhs.z) + self.e03.wedge(rhs.y) + self.e23.wedge(rhs.t),
+			e123: self.e12.wedge(rhs.z) + self.e13.wedge(rhs.y) + self.e23.wedge(rhs.x),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec4> for Rotor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Vec4) -> Vec4
+impl AntiWedge<Vec4> for Rotor {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Vec4) -> Self::Output {
+		// Vec4 {
+		//     t: E0(self.e0123.0 * rhs.t.0),
+		//     x: E1(self.e0123.0 * rhs.x.0),
+		//     y: E2(self.e0123.0 * rhs.y.0),
+		//     z: E3(self.e0123.0 * rhs.z.0),
+		// }
+		Vec4 {
+			t: self.e0123.anti_wedge(rhs.t),
+			x: self.e0123.anti_wedge(rhs.x),
+			y: self.e0123.anti_wedge(rhs.y),
+			z: self.e0123.anti_wedge(rhs.z),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec4> for Rotor {
+	type Output = Vec4;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Vec4) -> Vec4
+impl LeftContraction<Vec4> for Rotor {
+	type Output = Vec4;
+	fn left_contraction(self, rhs: Vec4) -> Self::Output {
+		// Vec4 {
+		//     t: E0(self.s.0 * rhs.t.0),
+		//     x: E1(self.s.0 * rhs.x.0),
+		//     y: E2(self.s.0 * rhs.y.0),
+		//     z: E3(self.s.0 * rhs.z.0),
+		// }
+		Vec4 {
+			t: self.s.left_contraction(rhs.t),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			z: self.s.left_contraction(rhs.z),
+		}
+	}
+}
+
+// Rotor.right_contraction(Vec4) -> Multivector
+impl RightContraction<Vec4> for Rotor {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0),
+		//     e1   : -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0),
+		//     e2   : -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0),
+		//     e3   : -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.e0123.0 * rhs.z.0),
+		//     e013 : E013(self.e0123.0 * rhs.y.0),
+		//     e023 : -E023(self.e0123.0 * rhs.x.0),
+		//     e123 : -E123(self.e0123.0 * rhs.t.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e01.right_contraction(rhs.x)
+				+ self.e02.right_contraction(rhs.y)
+				+ self.e03.right_contraction(rhs.z),
+			e1: self.e01.right_contraction(rhs.t)
+				+ self.e12.right_contraction(rhs.y)
+				+ self.e13.right_contraction(rhs.z),
+			e2: self.e02.right_contraction(rhs.t)
+				+ self.e12.right_contraction(rhs.x)
+				+ self.e23.right_contraction(rhs.z),
+			e3: self.e03.right_contraction(rhs.t)
+				+ self.e13.right_contraction(rhs.x)
+				+ self.e23.right_contraction(rhs.y),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e0123.right_contraction(rhs.z),
+			e013: self.e0123.right_contraction(rhs.y),
+			e023: self.e0123.right_contraction(rhs.x),
+			e123: self.e0123.right_contraction(rhs.t),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl ScalarProduct<Vec4> for Rotor {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor.commutator(Vec4) -> Multivector
+impl Commutator<Vec4> for Rotor {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : -E0(self.e01.0 * rhs.x.0) - E0(self.e02.0 * rhs.y.0) - E0(self.e03.0 * rhs.z.0),
+		//     e1   : -E1(self.e01.0 * rhs.t.0) - E1(self.e12.0 * rhs.y.0) - E1(self.e13.0 * rhs.z.0),
+		//     e2   : -E2(self.e02.0 * rhs.t.0) + E2(self.e12.0 * rhs.x.0) - E2(self.e23.0 * rhs.z.0),
+		//     e3   : -E3(self.e03.0 * rhs.t.0) + E3(self.e13.0 * rhs.x.0) + E3(self.e23.0 * rhs.y.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.e0123.0 * rhs.z.0),
+		//     e013 : E013(self.e0123.0 * rhs.y.0),
+		//     e023 : -E023(self.e0123.0 * rhs.x.0),
+		//     e123 : -E123(self.e0123.0 * rhs.t.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e01.commutator(rhs.x) + self.e02.commutator(rhs.y) + self.e03.commutator(rhs.z),
+			e1: self.e01.commutator(rhs.t) + self.e12.commutator(rhs.y) + self.e13.commutator(rhs.z),
+			e2: self.e02.commutator(rhs.t) + self.e12.commutator(rhs.x) + self.e23.commutator(rhs.z),
+			e3: self.e03.commutator(rhs.t) + self.e13.commutator(rhs.x) + self.e23.commutator(rhs.y),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e0123.commutator(rhs.z),
+			e013: self.e0123.commutator(rhs.y),
+			e023: self.e0123.commutator(rhs.x),
+			e123: self.e0123.commutator(rhs.t),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Rotor.anti_dot(Vec4) -> Multivector
+impl AntiDot<Vec4> for Rotor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.e0123.0 * rhs.t.0),
+		//     e1   : E1(self.e0123.0 * rhs.x.0),
+		//     e2   : E2(self.e0123.0 * rhs.y.0),
+		//     e3   : E3(self.e0123.0 * rhs.z.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.e03.0 * rhs.t.0) + E012(self.e13.0 * rhs.x.0) + E012(self.e23.0 * rhs.y.0) - E012(self.s.0 * rhs.z.0),
+		//     e013 : E013(self.e02.0 * rhs.t.0) - E013(self.e12.0 * rhs.x.0) + E013(self.e23.0 * rhs.z.0) + E013(self.s.0 * rhs.y.0),
+		//     e023 : -E023(self.e01.0 * rhs.t.0) - E023(self.e12.0 * rhs.y.0) - E023(self.e13.0 * rhs.z.0) - E023(self.s.0 * rhs.x.0),
+		//     e123 : -E123(self.e01.0 * rhs.x.0) - E123(self.e02.0 * rhs.y.0) - E123(self.e03.0 * rhs.z.0) - E123(self.s.0 * rhs.t.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e0123.anti_dot(rhs.t),
+			e1: self.e0123.anti_dot(rhs.x),
+			e2: self.e0123.anti_dot(rhs.y),
+			e3: self.e0123.anti_dot(rhs.z),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.e03.anti_dot(rhs.t)
+				+ self.e13.anti_dot(rhs.x)
+				+ self.e23.anti_dot(rhs.y)
+				+ self.s.anti_dot(rhs.z),
+			e013: self.e02.anti_dot(rhs.t)
+				+ self.e12.anti_dot(rhs.x)
+				+ self.e23.anti_dot(rhs.z)
+				+ self.s.anti_dot(rhs.y),
+			e023: self.e01.anti_dot(rhs.t)
+				+ self.e12.anti_dot(rhs.y)
+				+ self.e13.anti_dot(rhs.z)
+				+ self.s.anti_dot(rhs.x),
+			e123: self.e01.anti_dot(rhs.x)
+				+ self.e02.anti_dot(rhs.y)
+				+ self.e03.anti_dot(rhs.z)
+				+ self.s.anti_dot(rhs.t),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Bivector:
+
+// Rotor.geometric(Bivector) -> Rotor
+impl Geometric<Bivector> for Rotor {
+	type Output = Rotor;
+	fn geometric(self, rhs: Bivector) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e01  : -E01(self.e0123.0 * rhs.e23.0) + E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e0123.0 * rhs.e13.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e0123.0 * rhs.e12.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e0123.0 * rhs.e03.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) - E13(self.e0123.0 * rhs.e02.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e0123.0 * rhs.e01.0) - E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0) + E23(self.s.0 * rhs.e23.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0),
+		// }
+		Rotor {
+			s: self.e01.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e03)
+				+ self.e12.geometric(rhs.e12)
+				+ self.e13.geometric(rhs.e13)
+				+ self.e23.geometric(rhs.e23),
+			e01: self.e0123.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e12)
+				+ self.e03.geometric(rhs.e13)
+				+ self.e12.geometric(rhs.e02)
+				+ self.e13.geometric(rhs.e03)
+				+ self.s.geometric(rhs.e01),
+			e02: self.e01.geometric(rhs.e12)
+				+ self.e0123.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e23)
+				+ self.e12.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e03)
+				+ self.s.geometric(rhs.e02),
+			e03: self.e01.geometric(rhs.e13)
+				+ self.e0123.geometric(rhs.e12)
+				+ self.e02.geometric(rhs.e23)
+				+ self.e13.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e02)
+				+ self.s.geometric(rhs.e03),
+			e12: self.e01.geometric(rhs.e02)
+				+ self.e0123.geometric(rhs.e03)
+				+ self.e02.geometric(rhs.e01)
+				+ self.e13.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e13)
+				+ self.s.geometric(rhs.e12),
+			e13: self.e01.geometric(rhs.e03)
+				+ self.e0123.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e01)
+				+ self.e12.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e12)
+				+ self.s.geometric(rhs.e13),
+			e23: self.e0123.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e03)
+				+ self.e03.geometric(rhs.e02)
+				+ self.e12.geometric(rhs.e13)
+				+ self.e13.geometric(rhs.e12)
+				+ self.s.geometric(rhs.e23),
+			e0123: self.e01.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e12)
+				+ self.e12.geometric(rhs.e03)
+				+ self.e13.geometric(rhs.e02)
+				+ self.e23.geometric(rhs.e01),
+		}
+	}
+}
+
+impl std::ops::Mul<Bivector> for Rotor {
+	type Output = Rotor;
+	fn mul(self, rhs: Bivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Bivector) -> Rotor
+impl AntiGeometric<Bivector> for Rotor {
+	type Output = Rotor;
+	fn anti_geometric(self, rhs: Bivector) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e23.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0),
+		//     e01  : E01(self.e0123.0 * rhs.e01.0) + E01(self.e02.0 * rhs.e03.0) - E01(self.e03.0 * rhs.e02.0) - E01(self.e12.0 * rhs.e13.0) + E01(self.e13.0 * rhs.e12.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : -E02(self.e01.0 * rhs.e03.0) + E02(self.e0123.0 * rhs.e02.0) + E02(self.e03.0 * rhs.e01.0) - E02(self.e12.0 * rhs.e23.0) + E02(self.e23.0 * rhs.e12.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : E03(self.e01.0 * rhs.e02.0) + E03(self.e0123.0 * rhs.e03.0) - E03(self.e02.0 * rhs.e01.0) - E03(self.e13.0 * rhs.e23.0) + E03(self.e23.0 * rhs.e13.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : -E12(self.e01.0 * rhs.e13.0) + E12(self.e0123.0 * rhs.e12.0) - E12(self.e02.0 * rhs.e23.0) + E12(self.e13.0 * rhs.e01.0) + E12(self.e23.0 * rhs.e02.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e01.0 * rhs.e12.0) + E13(self.e0123.0 * rhs.e13.0) - E13(self.e03.0 * rhs.e23.0) - E13(self.e12.0 * rhs.e01.0) + E13(self.e23.0 * rhs.e03.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : E23(self.e0123.0 * rhs.e23.0) + E23(self.e02.0 * rhs.e12.0) + E23(self.e03.0 * rhs.e13.0) - E23(self.e12.0 * rhs.e02.0) - E23(self.e13.0 * rhs.e03.0) - E23(self.s.0 * rhs.e01.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: self.e01.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e12)
+				+ self.e12.anti_geometric(rhs.e03)
+				+ self.e13.anti_geometric(rhs.e02)
+				+ self.e23.anti_geometric(rhs.e01),
+			e01: self.e0123.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e03)
+				+ self.e03.anti_geometric(rhs.e02)
+				+ self.e12.anti_geometric(rhs.e13)
+				+ self.e13.anti_geometric(rhs.e12)
+				+ self.s.anti_geometric(rhs.e23),
+			e02: self.e01.anti_geometric(rhs.e03)
+				+ self.e0123.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e01)
+				+ self.e12.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e12)
+				+ self.s.anti_geometric(rhs.e13),
+			e03: self.e01.anti_geometric(rhs.e02)
+				+ self.e0123.anti_geometric(rhs.e03)
+				+ self.e02.anti_geometric(rhs.e01)
+				+ self.e13.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e13)
+				+ self.s.anti_geometric(rhs.e12),
+			e12: self.e01.anti_geometric(rhs.e13)
+				+ self.e0123.anti_geometric(rhs.e12)
+				+ self.e02.anti_geometric(rhs.e23)
+				+ self.e13.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e02)
+				+ self.s.anti_geometric(rhs.e03),
+			e13: self.e01.anti_geometric(rhs.e12)
+				+ self.e0123.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e23)
+				+ self.e12.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e03)
+				+ self.s.anti_geometric(rhs.e02),
+			e23: self.e0123.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e12)
+				+ self.e03.anti_geometric(rhs.e13)
+				+ self.e12.anti_geometric(rhs.e02)
+				+ self.e13.anti_geometric(rhs.e03)
+				+ self.s.anti_geometric(rhs.e01),
+			e0123: self.e01.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e03)
+				+ self.e12.anti_geometric(rhs.e12)
+				+ self.e13.anti_geometric(rhs.e13)
+				+ self.e23.anti_geometric(rhs.e23),
+		}
+	}
+}
+
+// Rotor.dot(Bivector) -> Rotor
+impl Dot<Bivector> for Rotor {
+	type Output = Rotor;
+	fn dot(self, rhs: Bivector) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e01  : -E01(self.e0123.0 * rhs.e23.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e0123.0 * rhs.e13.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e0123.0 * rhs.e03.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e0123.0 * rhs.e01.0) + E23(self.s.0 * rhs.e23.0),
+		//     e0123: Default::default(),
+		// }
+		Rotor {
+			s: self.e01.dot(rhs.e01)
+				+ self.e02.dot(rhs.e02)
+				+ self.e03.dot(rhs.e03)
+				+ self.e12.dot(rhs.e12)
+				+ self.e13.dot(rhs.e13)
+				+ self.e23.dot(rhs.e23),
+			e01: self.e0123.dot(rhs.e23) + self.s.dot(rhs.e01),
+			e02: self.e0123.dot(rhs.e13) + self.s.dot(rhs.e02),
+			e03: self.e0123.dot(rhs.e12) + self.s.dot(rhs.e03),
+			e12: self.e0123.dot(rhs.e03) + self.s.dot(rhs.e12),
+			e13: self.e0123.dot(rhs.e02) + self.s.dot(rhs.e13),
+			e23: self.e0123.dot(rhs.e01) + self.s.dot(rhs.e23),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Bivector> for Rotor {
+	type Output = Rotor;
+	fn bitor(self, rhs: Bivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Bivector) -> Rotor
+impl Wedge<Bivector> for Rotor {
+	type Output = Rotor;
+	fn wedge(self, rhs: Bivector) -> Self::Output {
+		// Rotor {
+		//     s    : Default::default(),
+		//     e01  : E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.s.0 * rhs.e02.0),
+		//     e03  : E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0),
+		// }
+		Rotor {
+			s: Default::default(),
+			e01: self.s.wedge(rhs.e01),
+			e02: self.s.wedge(rhs.e02),
+			e03: self.s.wedge(rhs.e03),
+			e12: self.s.wedge(rhs.e12),
+			e13: self.s.wedge(rhs.e13),
+			e23: self.s.wedge(rhs.e23),
+			e0123: self.e01.wedge(rhs.e23)
+				+ self.e02.wedge(rhs.e13)
+				+ self.e03.wedge(rhs.e12)
+				+ self.e12.wedge(rhs.e03)
+				+ self.e13.wedge(rhs.e02)
+				+ self.e23.wedge(rhs.e01),
+		}
+	}
+}
+
+impl std::ops::BitXor<Bivector> for Rotor {
+	type Output = Rotor;
+	fn bitxor(self, rhs: Bivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Bivector) -> Rotor
+impl AntiWedge<Bivector> for Rotor {
+	type Output = Rotor;
+	fn anti_wedge(self, rhs: Bivector) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e23.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0),
+		//     e01  : E01(self.e0123.0 * rhs.e01.0),
+		//     e02  : E02(self.e0123.0 * rhs.e02.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0),
+		//     e23  : E23(self.e0123.0 * rhs.e23.0),
+		//     e0123: Default::default(),
+		// }
+		Rotor {
+			s: self.e01.anti_wedge(rhs.e23)
+				+ self.e02.anti_wedge(rhs.e13)
+				+ self.e03.anti_wedge(rhs.e12)
+				+ self.e12.anti_wedge(rhs.e03)
+				+ self.e13.anti_wedge(rhs.e02)
+				+ self.e23.anti_wedge(rhs.e01),
+			e01: self.e0123.anti_wedge(rhs.e01),
+			e02: self.e0123.anti_wedge(rhs.e02),
+			e03: self.e0123.anti_wedge(rhs.e03),
+			e12: self.e0123.anti_wedge(rhs.e12),
+			e13: self.e0123.anti_wedge(rhs.e13),
+			e23: self.e0123.anti_wedge(rhs.e23),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Bivector> for Rotor {
+	type Output = Rotor;
+	fn bitand(self, rhs: Bivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Bivector) -> Rotor
+impl LeftContraction<Bivector> for Rotor {
+	type Output = Rotor;
+	fn left_contraction(self, rhs: Bivector) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e01  : E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.s.0 * rhs.e02.0),
+		//     e03  : E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.s.0 * rhs.e23.0),
+		//     e0123: Default::default(),
+		// }
+		Rotor {
+			s: self.e01.left_contraction(rhs.e01)
+				+ self.e02.left_contraction(rhs.e02)
+				+ self.e03.left_contraction(rhs.e03)
+				+ self.e12.left_contraction(rhs.e12)
+				+ self.e13.left_contraction(rhs.e13)
+				+ self.e23.left_contraction(rhs.e23),
+			e01: self.s.left_contraction(rhs.e01),
+			e02: self.s.left_contraction(rhs.e02),
+			e03: self.s.left_contraction(rhs.e03),
+			e12: self.s.left_contraction(rhs.e12),
+			e13: self.s.left_contraction(rhs.e13),
+			e23: self.s.left_contraction(rhs.e23),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Rotor.right_contraction(Bivector) -> Rotor
+impl RightContraction<Bivector> for Rotor {
+	type Output = Rotor;
+	fn right_contraction(self, rhs: Bivector) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0),
+		//     e01  : -E01(self.e0123.0 * rhs.e23.0),
+		//     e02  : E02(self.e0123.0 * rhs.e13.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0),
+		//     e12  : E12(self.e0123.0 * rhs.e03.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0),
+		//     e23  : E23(self.e0123.0 * rhs.e01.0),
+		//     e0123: Default::default(),
+		// }
+		Rotor {
+			s: self.e01.right_contraction(rhs.e01)
+				+ self.e02.right_contraction(rhs.e02)
+				+ self.e03.right_contraction(rhs.e03)
+				+ self.e12.right_contraction(rhs.e12)
+				+ self.e13.right_contraction(rhs.e13)
+				+ self.e23.right_contraction(rhs.e23),
+			e01: self.e0123.right_contraction(rhs.e23),
+			e02: self.e0123.right_contraction(rhs.e13),
+			e03: self.e0123.right_contraction(rhs.e12),
+			e12: self.e0123.right_contraction(rhs.e03),
+			e13: self.e0123.right_contraction(rhs.e02),
+			e23: self.e0123.right_contraction(rhs.e01),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Rotor.scalar_product(Bivector) -> S
+impl ScalarProduct<Bivector> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Bivector) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0)
+		self.e01.scalar_product(rhs.e01)
+			+ self.e02.scalar_product(rhs.e02)
+			+ self.e03.scalar_product(rhs.e03)
+			+ self.e12.scalar_product(rhs.e12)
+			+ self.e13.scalar_product(rhs.e13)
+			+ self.e23.scalar_product(rhs.e23)
+	}
+}
+
+// Rotor.commutator(Bivector) -> Bivector
+impl Commutator<Bivector> for Rotor {
+	type Output = Bivector;
+	fn commutator(self, rhs: Bivector) -> Self::Output {
+		// Bivector {
+		//     e01: E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0),
+		//     e02: -E02(self.e01.0 * rhs.e12.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03: -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12: -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13: -E13(self.e01.0 * rhs.e03.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23: -E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0),
+		// }
+		Bivector {
+			e01: self.e02.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e13)
+				+ self.e12.commutator(rhs.e02)
+				+ self.e13.commutator(rhs.e03),
+			e02: self.e01.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e23)
+				+ self.e12.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e03),
+			e03: self.e01.commutator(rhs.e13)
+				+ self.e02.commutator(rhs.e23)
+				+ self.e13.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e02),
+			e12: self.e01.commutator(rhs.e02)
+				+ self.e02.commutator(rhs.e01)
+				+ self.e13.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e13),
+			e13: self.e01.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e01)
+				+ self.e12.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e12),
+			e23: self.e02.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e02)
+				+ self.e12.commutator(rhs.e13)
+				+ self.e13.commutator(rhs.e12),
+		}
+	}
+}
+
+// Rotor.anti_dot(Bivector) -> Rotor
+impl AntiDot<Bivector> for Rotor {
+	type Output = Rotor;
+	fn anti_dot(self, rhs: Bivector) -> Self::Output {
+		// Rotor {
+		//     s    : Default::default(),
+		//     e01  : E01(self.e0123.0 * rhs.e01.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : E02(self.e0123.0 * rhs.e02.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : E23(self.e0123.0 * rhs.e23.0) - E23(self.s.0 * rhs.e01.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0),
+		// }
+		Rotor {
+			s: Default::default(),
+			e01: self.e0123.anti_dot(rhs.e01) + self.s.anti_dot(rhs.e23),
+			e02: self.e0123.anti_dot(rhs.e02) + self.s.anti_dot(rhs.e13),
+			e03: self.e0123.anti_dot(rhs.e03) + self.s.anti_dot(rhs.e12),
+			e12: self.e0123.anti_dot(rhs.e12) + self.s.anti_dot(rhs.e03),
+			e13: self.e0123.anti_dot(rhs.e13) + self.s.anti_dot(rhs.e02),
+			e23: self.e0123.anti_dot(rhs.e23) + self.s.anti_dot(rhs.e01),
+			e0123: self.e01.anti_dot(rhs.e01)
+				+ self.e02.anti_dot(rhs.e02)
+				+ self.e03.anti_dot(rhs.e03)
+				+ self.e12.anti_dot(rhs.e12)
+				+ self.e13.anti_dot(rhs.e13)
+				+ self.e23.anti_dot(rhs.e23),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Rotor:
+
+// Rotor.geometric(Rotor) -> Rotor
+impl Geometric<Rotor> for Rotor {
+	type Output = Rotor;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e0123.0 * rhs.e23.0) + E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0) - E01(self.e23.0 * rhs.e0123.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) + E02(self.e13.0 * rhs.e0123.0) - E02(self.e23.0 * rhs.e03.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e0123.0 * rhs.e12.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e03.0 * rhs.s.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e0123.0 * rhs.e03.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) - E13(self.e0123.0 * rhs.e02.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.e0123.0 * rhs.e01.0) - E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) + E0123(self.e0123.0 * rhs.s.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Rotor {
+			s: self.e01.geometric(rhs.e01)
+				+ self.e0123.geometric(rhs.e0123)
+				+ self.e02.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e03)
+				+ self.e12.geometric(rhs.e12)
+				+ self.e13.geometric(rhs.e13)
+				+ self.e23.geometric(rhs.e23)
+				+ self.s.geometric(rhs.s),
+			e01: self.e01.geometric(rhs.s)
+				+ self.e0123.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e12)
+				+ self.e03.geometric(rhs.e13)
+				+ self.e12.geometric(rhs.e02)
+				+ self.e13.geometric(rhs.e03)
+				+ self.e23.geometric(rhs.e0123)
+				+ self.s.geometric(rhs.e01),
+			e02: self.e01.geometric(rhs.e12)
+				+ self.e0123.geometric(rhs.e13)
+				+ self.e02.geometric(rhs.s)
+				+ self.e03.geometric(rhs.e23)
+				+ self.e12.geometric(rhs.e01)
+				+ self.e13.geometric(rhs.e0123)
+				+ self.e23.geometric(rhs.e03)
+				+ self.s.geometric(rhs.e02),
+			e03: self.e01.geometric(rhs.e13)
+				+ self.e0123.geometric(rhs.e12)
+				+ self.e02.geometric(rhs.e23)
+				+ self.e03.geometric(rhs.s)
+				+ self.e12.geometric(rhs.e0123)
+				+ self.e13.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e02)
+				+ self.s.geometric(rhs.e03),
+			e12: self.e01.geometric(rhs.e02)
+				+ self.e0123.geometric(rhs.e03)
+				+ self.e02.geometric(rhs.e01)
+				+ self.e03.geometric(rhs.e0123)
+				+ self.e12.geometric(rhs.s)
+				+ self.e13.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e13)
+				+ self.s.geometric(rhs.e12),
+			e13: self.e01.geometric(rhs.e03)
+				+ self.e0123.geometric(rhs.e02)
+				+ self.e02.geometric(rhs.e0123)
+				+ self.e03.geometric(rhs.e01)
+				+ self.e12.geometric(rhs.e23)
+				+ self.e13.geometric(rhs.s)
+				+ self.e23.geometric(rhs.e12)
+				+ self.s.geometric(rhs.e13),
+			e23: self.e01.geometric(rhs.e0123)
+				+ self.e0123.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e03)
+				+ self.e03.geometric(rhs.e02)
+				+ self.e12.geometric(rhs.e13)
+				+ self.e13.geometric(rhs.e12)
+				+ self.e23.geometric(rhs.s)
+				+ self.s.geometric(rhs.e23),
+			e0123: self.e01.geometric(rhs.e23)
+				+ self.e0123.geometric(rhs.s)
+				+ self.e02.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e12)
+				+ self.e12.geometric(rhs.e03)
+				+ self.e13.geometric(rhs.e02)
+				+ self.e23.geometric(rhs.e01)
+				+ self.s.geometric(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Rotor {
+	type Output = Rotor;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Rotor) -> Rotor
+impl AntiGeometric<Rotor> for Rotor {
+	type Output = Rotor;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e23.0) + S(self.e0123.0 * rhs.s.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0) + S(self.s.0 * rhs.e0123.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e0123.0 * rhs.e01.0) + E01(self.e02.0 * rhs.e03.0) - E01(self.e03.0 * rhs.e02.0) - E01(self.e12.0 * rhs.e13.0) + E01(self.e13.0 * rhs.e12.0) + E01(self.e23.0 * rhs.s.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : -E02(self.e01.0 * rhs.e03.0) + E02(self.e0123.0 * rhs.e02.0) + E02(self.e02.0 * rhs.e0123.0) + E02(self.e03.0 * rhs.e01.0) - E02(self.e12.0 * rhs.e23.0) - E02(self.e13.0 * rhs.s.0) + E02(self.e23.0 * rhs.e12.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : E03(self.e01.0 * rhs.e02.0) + E03(self.e0123.0 * rhs.e03.0) - E03(self.e02.0 * rhs.e01.0) + E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0) - E03(self.e13.0 * rhs.e23.0) + E03(self.e23.0 * rhs.e13.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : -E12(self.e01.0 * rhs.e13.0) + E12(self.e0123.0 * rhs.e12.0) - E12(self.e02.0 * rhs.e23.0) - E12(self.e03.0 * rhs.s.0) + E12(self.e12.0 * rhs.e0123.0) + E12(self.e13.0 * rhs.e01.0) + E12(self.e23.0 * rhs.e02.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e01.0 * rhs.e12.0) + E13(self.e0123.0 * rhs.e13.0) + E13(self.e02.0 * rhs.s.0) - E13(self.e03.0 * rhs.e23.0) - E13(self.e12.0 * rhs.e01.0) + E13(self.e13.0 * rhs.e0123.0) + E13(self.e23.0 * rhs.e03.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) + E23(self.e0123.0 * rhs.e23.0) + E23(self.e02.0 * rhs.e12.0) + E23(self.e03.0 * rhs.e13.0) - E23(self.e12.0 * rhs.e02.0) - E23(self.e13.0 * rhs.e03.0) + E23(self.e23.0 * rhs.e0123.0) - E23(self.s.0 * rhs.e01.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) + E0123(self.e0123.0 * rhs.e0123.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0) - E0123(self.s.0 * rhs.s.0),
+		// }
+		Rotor {
+			s: self.e01.anti_geometric(rhs.e23)
+				+ self.e0123.anti_geometric(rhs.s)
+				+ self.e02.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e12)
+				+ self.e12.anti_geometric(rhs.e03)
+				+ self.e13.anti_geometric(rhs.e02)
+				+ self.e23.anti_geometric(rhs.e01)
+				+ self.s.anti_geometric(rhs.e0123),
+			e01: self.e01.anti_geometric(rhs.e0123)
+				+ self.e0123.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e03)
+				+ self.e03.anti_geometric(rhs.e02)
+				+ self.e12.anti_geometric(rhs.e13)
+				+ self.e13.anti_geometric(rhs.e12)
+				+ self.e23.anti_geometric(rhs.s)
+				+ self.s.anti_geometric(rhs.e23),
+			e02: self.e01.anti_geometric(rhs.e03)
+				+ self.e0123.anti_geometric(rhs.e02)
+				+ self.e02.anti_geometric(rhs.e0123)
+				+ self.e03.anti_geometric(rhs.e01)
+				+ self.e12.anti_geometric(rhs.e23)
+				+ self.e13.anti_geometric(rhs.s)
+				+ self.e23.anti_geometric(rhs.e12)
+				+ self.s.anti_geometric(rhs.e13),
+			e03: self.e01.anti_geometric(rhs.e02)
+				+ self.e0123.anti_geometric(rhs.e03)
+				+ self.e02.anti_geometric(rhs.e01)
+				+ self.e03.anti_geometric(rhs.e0123)
+				+ self.e12.anti_geometric(rhs.s)
+				+ self.e13.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e13)
+				+ self.s.anti_geometric(rhs.e12),
+			e12: self.e01.anti_geometric(rhs.e13)
+				+ self.e0123.anti_geometric(rhs.e12)
+				+ self.e02.anti_geometric(rhs.e23)
+				+ self.e03.anti_geometric(rhs.s)
+				+ self.e12.anti_geometric(rhs.e0123)
+				+ self.e13.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e02)
+				+ self.s.anti_geometric(rhs.e03),
+			e13: self.e01.anti_geometric(rhs.e12)
+				+ self.e0123.anti_geometric(rhs.e13)
+				+ self.e02.anti_geometric(rhs.s)
+				+ self.e03.anti_geometric(rhs.e23)
+				+ self.e12.anti_geometric(rhs.e01)
+				+ self.e13.anti_geometric(rhs.e0123)
+				+ self.e23.anti_geometric(rhs.e03)
+				+ self.s.anti_geometric(rhs.e02),
+			e23: self.e01.anti_geometric(rhs.s)
+				+ self.e0123.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e12)
+				+ self.e03.anti_geometric(rhs.e13)
+				+ self.e12.anti_geometric(rhs.e02)
+				+ self.e13.anti_geometric(rhs.e03)
+				+ self.e23.anti_geometric(rhs.e0123)
+				+ self.s.anti_geometric(rhs.e01),
+			e0123: self.e01.anti_geometric(rhs.e01)
+				+ self.e0123.anti_geometric(rhs.e0123)
+				+ self.e02.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e03)
+				+ self.e12.anti_geometric(rhs.e12)
+				+ self.e13.anti_geometric(rhs.e13)
+				+ self.e23.anti_geometric(rhs.e23)
+				+ self.s.anti_geometric(rhs.s),
+		}
+	}
+}
+
+// Rotor.dot(Rotor) -> Rotor
+impl Dot<Rotor> for Rotor {
+	type Output = Rotor;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e0123.0 * rhs.e23.0) - E01(self.e23.0 * rhs.e0123.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0) + E02(self.e13.0 * rhs.e0123.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0) + E03(self.e03.0 * rhs.s.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e0123.0 * rhs.e03.0) + E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.e0123.0 * rhs.e01.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e0123: E0123(self.e0123.0 * rhs.s.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Rotor {
+			s: self.e01.dot(rhs.e01)
+				+ self.e0123.dot(rhs.e0123)
+				+ self.e02.dot(rhs.e02)
+				+ self.e03.dot(rhs.e03)
+				+ self.e12.dot(rhs.e12)
+				+ self.e13.dot(rhs.e13)
+				+ self.e23.dot(rhs.e23)
+				+ self.s.dot(rhs.s),
+			e01: self.e01.dot(rhs.s) + self.e0123.dot(rhs.e23) + self.e23.dot(rhs.e0123) + self.s.dot(rhs.e01),
+			e02: self.e0123.dot(rhs.e13) + self.e02.dot(rhs.s) + self.e13.dot(rhs.e0123) + self.s.dot(rhs.e02),
+			e03: self.e0123.dot(rhs.e12) + self.e03.dot(rhs.s) + self.e12.dot(rhs.e0123) + self.s.dot(rhs.e03),
+			e12: self.e0123.dot(rhs.e03) + self.e03.dot(rhs.e0123) + self.e12.dot(rhs.s) + self.s.dot(rhs.e12),
+			e13: self.e0123.dot(rhs.e02) + self.e02.dot(rhs.e0123) + self.e13.dot(rhs.s) + self.s.dot(rhs.e13),
+			e23: self.e01.dot(rhs.e0123) + self.e0123.dot(rhs.e01) + self.e23.dot(rhs.s) + self.s.dot(rhs.e23),
+			e0123: self.e0123.dot(rhs.s) + self.s.dot(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Rotor {
+	type Output = Rotor;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Rotor) -> Rotor
+impl Wedge<Rotor> for Rotor {
+	type Output = Rotor;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e02.0 * rhs.s.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : E03(self.e03.0 * rhs.s.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) + E0123(self.e0123.0 * rhs.s.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Rotor {
+			s: self.s.wedge(rhs.s),
+			e01: self.e01.wedge(rhs.s) + self.s.wedge(rhs.e01),
+			e02: self.e02.wedge(rhs.s) + self.s.wedge(rhs.e02),
+			e03: self.e03.wedge(rhs.s) + self.s.wedge(rhs.e03),
+			e12: self.e12.wedge(rhs.s) + self.s.wedge(rhs.e12),
+			e13: self.e13.wedge(rhs.s) + self.s.wedge(rhs.e13),
+			e23: self.e23.wedge(rhs.s) + self.s.wedge(rhs.e23),
+			e0123: self.e01.wedge(rhs.e23)
+				+ self.e0123.wedge(rhs.s)
+				+ self.e02.wedge(rhs.e13)
+				+ self.e03.wedge(rhs.e12)
+				+ self.e12.wedge(rhs.e03)
+				+ self.e13.wedge(rhs.e02)
+				+ self.e23.wedge(rhs.e01)
+				+ self.s.wedge(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Rotor {
+	type Output = Rotor;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Rotor) -> Rotor
+impl AntiWedge<Rotor> for Rotor {
+	type Output = Rotor;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e23.0) + S(self.e0123.0 * rhs.s.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0) + S(self.s.0 * rhs.e0123.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e0123.0 * rhs.e01.0),
+		//     e02  : E02(self.e0123.0 * rhs.e02.0) + E02(self.e02.0 * rhs.e0123.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0) + E03(self.e03.0 * rhs.e0123.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0) + E12(self.e12.0 * rhs.e0123.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0) + E13(self.e13.0 * rhs.e0123.0),
+		//     e23  : E23(self.e0123.0 * rhs.e23.0) + E23(self.e23.0 * rhs.e0123.0),
+		//     e0123: E0123(self.e0123.0 * rhs.e0123.0),
+		// }
+		Rotor {
+			s: self.e01.anti_wedge(rhs.e23)
+				+ self.e0123.anti_wedge(rhs.s)
+				+ self.e02.anti_wedge(rhs.e13)
+				+ self.e03.anti_wedge(rhs.e12)
+				+ self.e12.anti_wedge(rhs.e03)
+				+ self.e13.anti_wedge(rhs.e02)
+				+ self.e23.anti_wedge(rhs.e01)
+				+ self.s.anti_wedge(rhs.e0123),
+			e01: self.e01.anti_wedge(rhs.e0123) + self.e0123.anti_wedge(rhs.e01),
+			e02: self.e0123.anti_wedge(rhs.e02) + self.e02.anti_wedge(rhs.e0123),
+			e03: self.e0123.anti_wedge(rhs.e03) + self.e03.anti_wedge(rhs.e0123),
+			e12: self.e0123.anti_wedge(rhs.e12) + self.e12.anti_wedge(rhs.e0123),
+			e13: self.e0123.anti_wedge(rhs.e13) + self.e13.anti_wedge(rhs.e0123),
+			e23: self.e0123.anti_wedge(rhs.e23) + self.e23.anti_wedge(rhs.e0123),
+			e0123: self.e0123.anti_wedge(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Rotor {
+	type Output = Rotor;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Rotor) -> Rotor
+impl LeftContraction<Rotor> for Rotor {
+	type Output = Rotor;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e01  : -E01(self.e23.0 * rhs.e0123.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e13.0 * rhs.e0123.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e12.0 * rhs.e0123.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e03.0 * rhs.e0123.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e02.0 * rhs.e0123.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.s.0 * rhs.e23.0),
+		//     e0123: E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Rotor {
+			s: self.e01.left_contraction(rhs.e01)
+				+ self.e0123.left_contraction(rhs.e0123)
+				+ self.e02.left_contraction(rhs.e02)
+				+ self.e03.left_contraction(rhs.e03)
+				+ self.e12.left_contraction(rhs.e12)
+				+ self.e13.left_contraction(rhs.e13)
+				+ self.e23.left_contraction(rhs.e23)
+				+ self.s.left_contraction(rhs.s),
+			e01: self.e23.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e01),
+			e02: self.e13.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e02),
+			e03: self.e12.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e03),
+			e12: self.e03.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e12),
+			e13: self.e02.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e13),
+			e23: self.e01.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e23),
+			e0123: self.s.left_contraction(rhs.e0123),
+		}
+	}
+}
+
+// Rotor.right_contraction(Rotor) -> Rotor
+impl RightContraction<Rotor> for Rotor {
+	type Output = Rotor;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e0123.0 * rhs.e23.0),
+		//     e02  : E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0) + E03(self.e03.0 * rhs.s.0),
+		//     e12  : E12(self.e0123.0 * rhs.e03.0) + E12(self.e12.0 * rhs.s.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0) + E13(self.e13.0 * rhs.s.0),
+		//     e23  : E23(self.e0123.0 * rhs.e01.0) + E23(self.e23.0 * rhs.s.0),
+		//     e0123: E0123(self.e0123.0 * rhs.s.0),
+		// }
+		Rotor {
+			s: self.e01.right_contraction(rhs.e01)
+				+ self.e0123.right_contraction(rhs.e0123)
+				+ self.e02.right_contraction(rhs.e02)
+				+ self.e03.right_contraction(rhs.e03)
+				+ self.e12.right_contraction(rhs.e12)
+				+ self.e13.right_contraction(rhs.e13)
+				+ self.e23.right_contraction(rhs.e23)
+				+ self.s.right_contraction(rhs.s),
+			e01: self.e01.right_contraction(rhs.s) + self.e0123.right_contraction(rhs.e23),
+			e02: self.e0123.right_contraction(rhs.e13) + self.e02.right_contraction(rhs.s),
+			e03: self.e0123.right_contraction(rhs.e12) + self.e03.right_contraction(rhs.s),
+			e12: self.e0123.right_contraction(rhs.e03) + self.e12.right_contraction(rhs.s),
+			e13: self.e0123.right_contraction(rhs.e02) + self.e13.right_contraction(rhs.s),
+			e23: self.e0123.right_contraction(rhs.e01) + self.e23.right_contraction(rhs.s),
+			e0123: self.e0123.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Rotor.scalar_product(Rotor) -> S
+impl ScalarProduct<Rotor> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+		self.e01.scalar_product(rhs.e01)
+			+ self.e0123.scalar_product(rhs.e0123)
+			+ self.e02.scalar_product(rhs.e02)
+			+ self.e03.scalar_product(rhs.e03)
+			+ self.e12.scalar_product(rhs.e12)
+			+ self.e13.scalar_product(rhs.e13)
+			+ self.e23.scalar_product(rhs.e23)
+			+ self.s.scalar_product(rhs.s)
+	}
+}
+
+// Rotor.commutator(Rotor) -> Bivector
+impl Commutator<Rotor> for Rotor {
+	type Output = Bivector;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Bivector {
+		//     e01: E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0),
+		//     e02: -E02(self.e01.0 * rhs.e12.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03: -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12: -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13: -E13(self.e01.0 * rhs.e03.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23: -E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0),
+		// }
+		Bivector {
+			e01: self.e02.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e13)
+				+ self.e12.commutator(rhs.e02)
+				+ self.e13.commutator(rhs.e03),
+			e02: self.e01.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e23)
+				+ self.e12.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e03),
+			e03: self.e01.commutator(rhs.e13)
+				+ self.e02.commutator(rhs.e23)
+				+ self.e13.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e02),
+			e12: self.e01.commutator(rhs.e02)
+				+ self.e02.commutator(rhs.e01)
+				+ self.e13.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e13),
+			e13: self.e01.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e01)
+				+ self.e12.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e12),
+			e23: self.e02.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e02)
+				+ self.e12.commutator(rhs.e13)
+				+ self.e13.commutator(rhs.e12),
+		}
+	}
+}
+
+// Rotor.anti_dot(Rotor) -> Rotor
+impl AntiDot<Rotor> for Rotor {
+	type Output = Rotor;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.e0123.0 * rhs.s.0) + S(self.s.0 * rhs.e0123.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e0123.0 * rhs.e01.0) + E01(self.e23.0 * rhs.s.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : E02(self.e0123.0 * rhs.e02.0) + E02(self.e02.0 * rhs.e0123.0) - E02(self.e13.0 * rhs.s.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0) + E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0) - E12(self.e03.0 * rhs.s.0) + E12(self.e12.0 * rhs.e0123.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0) + E13(self.e02.0 * rhs.s.0) + E13(self.e13.0 * rhs.e0123.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) + E23(self.e0123.0 * rhs.e23.0) + E23(self.e23.0 * rhs.e0123.0) - E23(self.s.0 * rhs.e01.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) + E0123(self.e0123.0 * rhs.e0123.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0) - E0123(self.s.0 * rhs.s.0),
+		// }
+		Rotor {
+			s: self.e0123.anti_dot(rhs.s) + self.s.anti_dot(rhs.e0123),
+			e01: self.e01.anti_dot(rhs.e0123)
+				+ self.e0123.anti_dot(rhs.e01)
+				+ self.e23.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e23),
+			e02: self.e0123.anti_dot(rhs.e02)
+				+ self.e02.anti_dot(rhs.e0123)
+				+ self.e13.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e13),
+			e03: self.e0123.anti_dot(rhs.e03)
+				+ self.e03.anti_dot(rhs.e0123)
+				+ self.e12.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e12),
+			e12: self.e0123.anti_dot(rhs.e12)
+				+ self.e03.anti_dot(rhs.s)
+				+ self.e12.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e03),
+			e13: self.e0123.anti_dot(rhs.e13)
+				+ self.e02.anti_dot(rhs.s)
+				+ self.e13.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e02),
+			e23: self.e01.anti_dot(rhs.s)
+				+ self.e0123.anti_dot(rhs.e23)
+				+ self.e23.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e01),
+			e0123: self.e01.anti_dot(rhs.e01)
+				+ self.e0123.anti_dot(rhs.e0123)
+				+ self.e02.anti_dot(rhs.e02)
+				+ self.e03.anti_dot(rhs.e03)
+				+ self.e12.anti_dot(rhs.e12)
+				+ self.e13.anti_dot(rhs.e13)
+				+ self.e23.anti_dot(rhs.e23)
+				+ self.s.anti_dot(rhs.s),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor OP Multivector:
+
+// Rotor.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Rotor {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : -E0(self.e01.0 * rhs.e1.0) + E0(self.e0123.0 * rhs.e123.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e03.0 * rhs.e3.0) - E0(self.e12.0 * rhs.e012.0) - E0(self.e13.0 * rhs.e013.0) - E0(self.e23.0 * rhs.e023.0) + E0(self.s.0 * rhs.e0.0),
+		//     e1   : -E1(self.e01.0 * rhs.e0.0) + E1(self.e0123.0 * rhs.e023.0) - E1(self.e02.0 * rhs.e012.0) - E1(self.e03.0 * rhs.e013.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e13.0 * rhs.e3.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2   : E2(self.e01.0 * rhs.e012.0) - E2(self.e0123.0 * rhs.e013.0) - E2(self.e02.0 * rhs.e0.0) - E2(self.e03.0 * rhs.e023.0) + E2(self.e12.0 * rhs.e1.0) + E2(self.e13.0 * rhs.e123.0) - E2(self.e23.0 * rhs.e3.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3   : E3(self.e01.0 * rhs.e013.0) + E3(self.e0123.0 * rhs.e012.0) + E3(self.e02.0 * rhs.e023.0) - E3(self.e03.0 * rhs.e0.0) - E3(self.e12.0 * rhs.e123.0) + E3(self.e13.0 * rhs.e1.0) + E3(self.e23.0 * rhs.e2.0) + E3(self.s.0 * rhs.e3.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e0123.0 * rhs.e23.0) + E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0) - E01(self.e23.0 * rhs.e0123.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) + E02(self.e13.0 * rhs.e0123.0) - E02(self.e23.0 * rhs.e03.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e0123.0 * rhs.e12.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e03.0 * rhs.s.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e0123.0 * rhs.e03.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) - E13(self.e0123.0 * rhs.e02.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e13.0 * rhs.s.0) + E13(self.e23.0 * rhs.e12.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.e0123.0 * rhs.e01.0) - E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e01.0 * rhs.e2.0) - E012(self.e0123.0 * rhs.e3.0) - E012(self.e02.0 * rhs.e1.0) - E012(self.e03.0 * rhs.e123.0) + E012(self.e12.0 * rhs.e0.0) + E012(self.e13.0 * rhs.e023.0) - E012(self.e23.0 * rhs.e013.0) + E012(self.s.0 * rhs.e012.0),
+		//     e013 : E013(self.e01.0 * rhs.e3.0) + E013(self.e0123.0 * rhs.e2.0) + E013(self.e02.0 * rhs.e123.0) - E013(self.e03.0 * rhs.e1.0) - E013(self.e12.0 * rhs.e023.0) + E013(self.e13.0 * rhs.e0.0) + E013(self.e23.0 * rhs.e012.0) + E013(self.s.0 * rhs.e013.0),
+		//     e023 : -E023(self.e01.0 * rhs.e123.0) - E023(self.e0123.0 * rhs.e1.0) + E023(self.e02.0 * rhs.e3.0) - E023(self.e03.0 * rhs.e2.0) + E023(self.e12.0 * rhs.e013.0) - E023(self.e13.0 * rhs.e012.0) + E023(self.e23.0 * rhs.e0.0) + E023(self.s.0 * rhs.e023.0),
+		//     e123 : -E123(self.e01.0 * rhs.e023.0) - E123(self.e0123.0 * rhs.e0.0) + E123(self.e02.0 * rhs.e013.0) - E123(self.e03.0 * rhs.e012.0) + E123(self.e12.0 * rhs.e3.0) - E123(self.e13.0 * rhs.e2.0) + E123(self.e23.0 * rhs.e1.0) + E123(self.s.0 * rhs.e123.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) + E0123(self.e0123.0 * rhs.s.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e01.geometric(rhs.e01)
+				+ self.e0123.geometric(rhs.e0123)
+				+ self.e02.geometric(rhs.e02)
+				+ self.e03.geometric(rhs.e03)
+				+ self.e12.geometric(rhs.e12)
+				+ self.e13.geometric(rhs.e13)
+				+ self.e23.geometric(rhs.e23)
+				+ self.s.geometric(rhs.s),
+			e0: self.e01.geometric(rhs.e1)
+				+ self.e0123.geometric(rhs.e123)
+				+ self.e02.geometric(rhs.e2)
+				+ self.e03.geometric(rhs.e3)
+				+ self.e12.geometric(rhs.e012)
+				+ self.e13.geometric(rhs.e013)
+				+ self.e23.geometric(rhs.e023)
+				+ self.s.geometric(rhs.e0),
+			e1: self.e01.geometric(rhs.e0)
+				+ self.e0123.geometric(rhs.e023)
+				+ self.e02.geometric(rhs.e012)
+				+ self.e03.geometric(rhs.e013)
+				+ self.e12.geometric(rhs.e2)
+				+ self.e13.geometric(rhs.e3)
+				+ self.e23.geometric(rhs.e123)
+				+ self.s.geometric(rhs.e1),
+			e2: self.e01.geometric(rhs.e012)
+				+ self.e0123.geometric(rhs.e013)
+				+ self.e02.geometric(rhs.e0)
+				+ self.e03.geometric(rhs.e023)
+				+ self.e12.geometric(rhs.e1)
+				+ self.e13.geometric(rhs.e123)
+				+ self.e23.geometric(rhs.e3)
+				+ self.s.geometric(rhs.e2),
+			e3: self.e01.geometric(rhs.e013)
+				+ self.e0123.geometric(rhs.e012)
+				+ self.e02.geometric(rhs.e023)
+				+ self.e03.geometric(rhs.e0)
+				+ self.e12.geometric(rhs.e123)
+				+ self.e13.geometric(rhs.e1)
+				+ self.e23.geometric(rhs.e2)
+				+ self.s.geometric(rhs.e3),
+			e01: self.e01.geometric(rhs.s)
+				+ self.e0123.geometric(rhs.e23)
+				+ self.e02.geometric(rhs.e12)
+				+ self.e03.geometric(rhs.e13)
+				+ self.e12.geometric(rhs.e02)
+				+ self.e13.geometric(rhs.e03)
+				+ self.e23.geometric(rhs.e0123)
+				+ self.s.geometric(rhs.e01),
+			e02: self.e01.geometric(rhs.e12)
+				+ self.e0123.geometric(rhs.e13)
+				+ self.e02.geometric(rhs.s)
+				+ self.e03.geometric(rhs.e23)
+				+ self.e12.geometric(rhs.e01)
+				+ self.e13.geometric(rhs.e0123)
+				+ self.e23.geometric(rhs.e03)
+				+ self.s.geometric(rhs.e02),
+			e03: self.e01.geometric(rhs.e13)
+				+ self.e0123.geometric(rhs.e12)
+				+ self.e02.geometric(rhs.e23)
+				+ self.e03.geometric(rhs.s)
+				+ self.e12.geometric(rhs.e0123)
+				+ self.e13.geometric(rhs.e01)
+				+ self.e23.geometric(rhs.e02)
+				+ self.s.geometric(rhs.e03),
+			e12: self.e01.geometric(rhs.e02)
+				+ self.e0123.geometric(rhs.e03)
+				+ self.e02.geometric(rhs.e01)
+				+ self.e03.geometric(rhs.e0123)
+				+ self.e12.geometric(rhs.s)
+				+ self.e13.geometric(rhs.e23)
+				+ self.e23.geometric(rhs.e13)
+				+ self.s.geometric(rhs.e12),
+			e13: self.e01.geometric(rhs.e03)
+				+ self.e0123.geometric(rhs.e02)
+				+ self.e02.geometric(rhs.e0123)
+				+ self.e03.geometric(rhs.e01)
+				+ self.e12.geometric(rhs.e23)
+				+ self.e13.geometric(rhs.s)
+				+ self.e23.geometric(rhs.e12)
+				+ self.s.geometric(rhs.e13),
+			e23: self.e01.geometric(rhs.e0123)
+				+ self.e0123.geometric(rhs.e01)
+				+ self.e02.geometric(rhs.e03)
+				+ self.e03.geometric(rhs.e02)
+				+ self.e12.geometric(rhs.e13)
+				+ self.e13.geometric(rhs.e12)
+				+ self.e23.geometric(rhs.s)
+				+ self.s.geometric(rhs.e23),
+			e012: self.e01.geometric(rhs.e2)
+				+ self.e0123.geometric(rhs.e3)
+				+ self.e02.geometric(rhs.e1)
+				+ self.e03.geometric(rhs.e123)
+				+ self.e12.geometric(rhs.e0)
+				+ self.e13.geometric(rhs.e023)
+				+ self.e23.geometric(rhs.e013)
+				+ self.s.geometric(rhs.e012),
+			e013: self.e01.geometric(rhs.e3)
+				+ self.e0123.geometric(rhs.e2)
+				+ self.e02.geometric(rhs.e123)
+				+ self.e03.geometric(rhs.e1)
+				+ self.e12.geometric(rhs.e023)
+				+ self.e13.geometric(rhs.e0)
+				+ self.e23.geometric(rhs.e012)
+				+ self.s.geometric(rhs.e013),
+			e023: self.e01.geometric(rhs.e123)
+				+ self.e0123.geometric(rhs.e1)
+				+ self.e02.geometric(rhs.e3)
+				+ self.e03.geometric(rhs.e2)
+				+ self.e12.geometric(rhs.e013)
+				+ self.e13.geometric(rhs.e012)
+				+ self.e23.geometric(rhs.e0)
+				+ self.s.geometric(rhs.e023),
+			e123: self.e01.geometric(rhs.e023)
+				+ self.e0123.geometric(rhs.e0)
+				+ self.e02.geometric(rhs.e013)
+				+ self.e03.geometric(rhs.e012)
+				+ self.e12.geometric(rhs.e3)
+				+ self.e13.geometric(rhs.e2)
+				+ self.e23.geometric(rhs.e1)
+				+ self.s.geometric(rhs.e123),
+			e0123: self.e01.geometric(rhs.e23)
+				+ self.e0123.geometric(rhs.s)
+				+ self.e02.geometric(rhs.e13)
+				+ self.e03.geometric(rhs.e12)
+				+ self.e12.geometric(rhs.e03)
+				+ self.e13.geometric(rhs.e02)
+				+ self.e23.geometric(rhs.e01)
+				+ self.s.geometric(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Rotor {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Rotor {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e23.0) + S(self.e0123.0 * rhs.s.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0) + S(self.s.0 * rhs.e0123.0),
+		//     e0   : E0(self.e01.0 * rhs.e023.0) + E0(self.e0123.0 * rhs.e0.0) - E0(self.e02.0 * rhs.e013.0) + E0(self.e03.0 * rhs.e012.0) + E0(self.e12.0 * rhs.e3.0) - E0(self.e13.0 * rhs.e2.0) + E0(self.e23.0 * rhs.e1.0) + E0(self.s.0 * rhs.e123.0),
+		//     e1   : E1(self.e01.0 * rhs.e123.0) + E1(self.e0123.0 * rhs.e1.0) + E1(self.e02.0 * rhs.e3.0) - E1(self.e03.0 * rhs.e2.0) - E1(self.e12.0 * rhs.e013.0) + E1(self.e13.0 * rhs.e012.0) + E1(self.e23.0 * rhs.e0.0) + E1(self.s.0 * rhs.e023.0),
+		//     e2   : -E2(self.e01.0 * rhs.e3.0) + E2(self.e0123.0 * rhs.e2.0) + E2(self.e02.0 * rhs.e123.0) + E2(self.e03.0 * rhs.e1.0) - E2(self.e12.0 * rhs.e023.0) - E2(self.e13.0 * rhs.e0.0) + E2(self.e23.0 * rhs.e012.0) - E2(self.s.0 * rhs.e013.0),
+		//     e3   : E3(self.e01.0 * rhs.e2.0) + E3(self.e0123.0 * rhs.e3.0) - E3(self.e02.0 * rhs.e1.0) + E3(self.e03.0 * rhs.e123.0) + E3(self.e12.0 * rhs.e0.0) - E3(self.e13.0 * rhs.e023.0) + E3(self.e23.0 * rhs.e013.0) + E3(self.s.0 * rhs.e012.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e0123.0 * rhs.e01.0) + E01(self.e02.0 * rhs.e03.0) - E01(self.e03.0 * rhs.e02.0) - E01(self.e12.0 * rhs.e13.0) + E01(self.e13.0 * rhs.e12.0) + E01(self.e23.0 * rhs.s.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : -E02(self.e01.0 * rhs.e03.0) + E02(self.e0123.0 * rhs.e02.0) + E02(self.e02.0 * rhs.e0123.0) + E02(self.e03.0 * rhs.e01.0) - E02(self.e12.0 * rhs.e23.0) - E02(self.e13.0 * rhs.s.0) + E02(self.e23.0 * rhs.e12.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : E03(self.e01.0 * rhs.e02.0) + E03(self.e0123.0 * rhs.e03.0) - E03(self.e02.0 * rhs.e01.0) + E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0) - E03(self.e13.0 * rhs.e23.0) + E03(self.e23.0 * rhs.e13.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : -E12(self.e01.0 * rhs.e13.0) + E12(self.e0123.0 * rhs.e12.0) - E12(self.e02.0 * rhs.e23.0) - E12(self.e03.0 * rhs.s.0) + E12(self.e12.0 * rhs.e0123.0) + E12(self.e13.0 * rhs.e01.0) + E12(self.e23.0 * rhs.e02.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e01.0 * rhs.e12.0) + E13(self.e0123.0 * rhs.e13.0) + E13(self.e02.0 * rhs.s.0) - E13(self.e03.0 * rhs.e23.0) - E13(self.e12.0 * rhs.e01.0) + E13(self.e13.0 * rhs.e0123.0) + E13(self.e23.0 * rhs.e03.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) + E23(self.e0123.0 * rhs.e23.0) + E23(self.e02.0 * rhs.e12.0) + E23(self.e03.0 * rhs.e13.0) - E23(self.e12.0 * rhs.e02.0) - E23(self.e13.0 * rhs.e03.0) + E23(self.e23.0 * rhs.e0123.0) - E23(self.s.0 * rhs.e01.0),
+		//     e012 : -E012(self.e01.0 * rhs.e013.0) + E012(self.e0123.0 * rhs.e012.0) - E012(self.e02.0 * rhs.e023.0) - E012(self.e03.0 * rhs.e0.0) + E012(self.e12.0 * rhs.e123.0) + E012(self.e13.0 * rhs.e1.0) + E012(self.e23.0 * rhs.e2.0) - E012(self.s.0 * rhs.e3.0),
+		//     e013 : E013(self.e01.0 * rhs.e012.0) + E013(self.e0123.0 * rhs.e013.0) + E013(self.e02.0 * rhs.e0.0) - E013(self.e03.0 * rhs.e023.0) - E013(self.e12.0 * rhs.e1.0) + E013(self.e13.0 * rhs.e123.0) + E013(self.e23.0 * rhs.e3.0) + E013(self.s.0 * rhs.e2.0),
+		//     e023 : -E023(self.e01.0 * rhs.e0.0) + E023(self.e0123.0 * rhs.e023.0) + E023(self.e02.0 * rhs.e012.0) + E023(self.e03.0 * rhs.e013.0) - E023(self.e12.0 * rhs.e2.0) - E023(self.e13.0 * rhs.e3.0) + E023(self.e23.0 * rhs.e123.0) - E023(self.s.0 * rhs.e1.0),
+		//     e123 : -E123(self.e01.0 * rhs.e1.0) + E123(self.e0123.0 * rhs.e123.0) - E123(self.e02.0 * rhs.e2.0) - E123(self.e03.0 * rhs.e3.0) + E123(self.e12.0 * rhs.e012.0) + E123(self.e13.0 * rhs.e013.0) + E123(self.e23.0 * rhs.e023.0) - E123(self.s.0 * rhs.e0.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) + E0123(self.e0123.0 * rhs.e0123.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0) - E0123(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.e01.anti_geometric(rhs.e23)
+				+ self.e0123.anti_geometric(rhs.s)
+				+ self.e02.anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(rhs.e12)
+				+ self.e12.anti_geometric(rhs.e03)
+				+ self.e13.anti_geometric(rhs.e02)
+				+ self.e23.anti_geometric(rhs.e01)
+				+ self.s.anti_geometric(rhs.e0123),
+			e0: self.e01.anti_geometric(rhs.e023)
+				+ self.e0123.anti_geometric(rhs.e0)
+				+ self.e02.anti_geometric(rhs.e013)
+				+ self.e03.anti_geometric(rhs.e012)
+				+ self.e12.anti_geometric(rhs.e3)
+				+ self.e13.anti_geometric(rhs.e2)
+				+ self.e23.anti_geometric(rhs.e1)
+				+ self.s.anti_geometric(rhs.e123),
+			e1: self.e01.anti_geometric(rhs.e123)
+				+ self.e0123.anti_geometric(rhs.e1)
+				+ self.e02.anti_geometric(rhs.e3)
+				+ self.e03.anti_geometric(rhs.e2)
+				+ self.e12.anti_geometric(rhs.e013)
+				+ self.e13.anti_geometric(rhs.e012)
+				+ self.e23.anti_geometric(rhs.e0)
+				+ self.s.anti_geometric(rhs.e023),
+			e2: self.e01.anti_geometric(rhs.e3)
+				+ self.e0123.anti_geometric(rhs.e2)
+				+ self.e02.anti_geometric(rhs.e123)
+				+ self.e03.anti_geometric(rhs.e1)
+				+ self.e12.anti_geometric(rhs.e023)
+				+ self.e13.anti_geometric(rhs.e0)
+				+ self.e23.anti_geometric(rhs.e012)
+				+ self.s.anti_geometric(rhs.e013),
+			e3: self.e01.anti_geometric(rhs.e2)
+				+ self.e0123.anti_geometric(rhs.e3)
+				+ self.e02.anti_geometric(rhs.e1)
+				+ self.e03.anti_geometric(rhs.e123)
+				+ self.e12.anti_geometric(rhs.e0)
+				+ self.e13.anti_geometric(rhs.e023)
+				+ self.e23.anti_geometric(rhs.e013)
+				+ self.s.anti_geometric(rhs.e012),
+			e01: self.e01.anti_geometric(rhs.e0123)
+				+ self.e0123.anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(rhs.e03)
+				+ self.e03.anti_geometric(rhs.e02)
+				+ self.e12.anti_geometric(rhs.e13)
+				+ self.e13.anti_geometric(rhs.e12)
+				+ self.e23.anti_geometric(rhs.s)
+				+ self.s.anti_geometric(rhs.e23),
+			e02: self.e01.anti_geometric(rhs.e03)
+				+ self.e0123.anti_geometric(rhs.e02)
+				+ self.e02.anti_geometric(rhs.e0123)
+				+ self.e03.anti_geometric(rhs.e01)
+				+ self.e12.anti_geometric(rhs.e23)
+				+ self.e13.anti_geometric(rhs.s)
+				+ self.e23.anti_geometric(rhs.e12)
+				+ self.s.anti_geometric(rhs.e13),
+			e03: self.e01.anti_geometric(rhs.e02)
+				+ self.e0123.anti_geometric(rhs.e03)
+				+ self.e02.anti_geometric(rhs.e01)
+				+ self.e03.anti_geometric(rhs.e0123)
+				+ self.e12.anti_geometric(rhs.s)
+				+ self.e13.anti_geometric(rhs.e23)
+				+ self.e23.anti_geometric(rhs.e13)
+				+ self.s.anti_geometric(rhs.e12),
+			e12: self.e01.anti_geometric(rhs.e13)
+				+ self.e0123.anti_geometric(rhs.e12)
+				+ self.e02.anti_geometric(rhs.e23)
+				+ self.e03.anti_geometric(rhs.s)
+				+ self.e12.anti_geometric(rhs.e0123)
+				+ self.e13.anti_geometric(rhs.e01)
+				+ self.e23.anti_geometric(rhs.e02)
+				+ self.s.anti_geometric(rhs.e03),
+			e13: self.e01.anti_geometric(rhs.e12)
+				+ self.e0123.anti_geometric(rhs.e13)
+				+ self.e02.anti_geometric(rhs.s)
+				+ self.e03.anti_geometric(rhs.e23)
+				+ self.e12.anti_geometric(rhs.e01)
+				+ self.e13.anti_geometric(rhs.e0123)
+				+ self.e23.anti_geometric(rhs.e03)
+				+ self.s.anti_geometric(rhs.e02),
+			e23: self.e01.anti_geometric(rhs.s)
+				+ self.e0123.anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(rhs.e12)
+				+ self.e03.anti_geometric(rhs.e13)
+				+ self.e12.anti_geometric(rhs.e02)
+				+ self.e13.anti_geometric(rhs.e03)
+				+ self.e23.anti_geometric(rhs.e0123)
+				+ self.s.anti_geometric(rhs.e01),
+			e012: self.e01.anti_geometric(rhs.e013)
+				+ self.e0123.anti_geometric(rhs.e012)
+				+ self.e02.anti_geometric(rhs.e023)
+				+ self.e03.anti_geometric(rhs.e0)
+				+ self.e12.anti_geometric(rhs.e123)
+				+ self.e13.anti_geometric(rhs.e1)
+				+ self.e23.anti_geometric(rhs.e2)
+				+ self.s.anti_geometric(rhs.e3),
+			e013: self.e01.anti_geometric(rhs.e012)
+				+ self.e0123.anti_geometric(rhs.e013)
+				+ self.e02.anti_geometric(rhs.e0)
+				+ self.e03.anti_geometric(rhs.e023)
+				+ self.e12.anti_geometric(rhs.e1)
+				+ self.e13.anti_geometric(rhs.e123)
+				+ self.e23.anti_geometric(rhs.e3)
+				+ self.s.anti_geometric(rhs.e2),
+			e023: self.e01.anti_geometric(rhs.e0)
+				+ self.e0123.anti_geometric(rhs.e023)
+				+ self.e02.anti_geometric(rhs.e012)
+				+ self.e03.anti_geometric(rhs.e013)
+				+ self.e12.anti_geometric(rhs.e2)
+				+ self.e13.anti_geometric(rhs.e3)
+				+ self.e23.anti_geometric(rhs.e123)
+				+ self.s.anti_geometric(rhs.e1),
+			e123: self.e01.anti_geometric(rhs.e1)
+				+ self.e0123.anti_geometric(rhs.e123)
+				+ self.e02.anti_geometric(rhs.e2)
+				+ self.e03.anti_geometric(rhs.e3)
+				+ self.e12.anti_geometric(rhs.e012)
+				+ self.e13.anti_geometric(rhs.e013)
+				+ self.e23.anti_geometric(rhs.e023)
+				+ self.s.anti_geometric(rhs.e0),
+			e0123: self.e01.anti_geometric(rhs.e01)
+				+ self.e0123.anti_geometric(rhs.e0123)
+				+ self.e02.anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(rhs.e03)
+				+ self.e12.anti_geometric(rhs.e12)
+				+ self.e13.anti_geometric(rhs.e13)
+				+ self.e23.anti_geometric(rhs.e23)
+				+ self.s.anti_geometric(rhs.s),
+		}
+	}
+}
+
+// Rotor.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Rotor {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : -E0(self.e01.0 * rhs.e1.0) + E0(self.e0123.0 * rhs.e123.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e03.0 * rhs.e3.0) - E0(self.e12.0 * rhs.e012.0) - E0(self.e13.0 * rhs.e013.0) - E0(self.e23.0 * rhs.e023.0) + E0(self.s.0 * rhs.e0.0),
+		//     e1   : -E1(self.e01.0 * rhs.e0.0) + E1(self.e0123.0 * rhs.e023.0) - E1(self.e02.0 * rhs.e012.0) - E1(self.e03.0 * rhs.e013.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e13.0 * rhs.e3.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2   : E2(self.e01.0 * rhs.e012.0) - E2(self.e0123.0 * rhs.e013.0) - E2(self.e02.0 * rhs.e0.0) - E2(self.e03.0 * rhs.e023.0) + E2(self.e12.0 * rhs.e1.0) + E2(self.e13.0 * rhs.e123.0) - E2(self.e23.0 * rhs.e3.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3   : E3(self.e01.0 * rhs.e013.0) + E3(self.e0123.0 * rhs.e012.0) + E3(self.e02.0 * rhs.e023.0) - E3(self.e03.0 * rhs.e0.0) - E3(self.e12.0 * rhs.e123.0) + E3(self.e13.0 * rhs.e1.0) + E3(self.e23.0 * rhs.e2.0) + E3(self.s.0 * rhs.e3.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e0123.0 * rhs.e23.0) - E01(self.e23.0 * rhs.e0123.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0) + E02(self.e13.0 * rhs.e0123.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0) + E03(self.e03.0 * rhs.s.0) - E03(self.e12.0 * rhs.e0123.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e0123.0 * rhs.e03.0) + E12(self.e03.0 * rhs.e0123.0) + E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0) - E13(self.e02.0 * rhs.e0123.0) + E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.e0123.0 * rhs.e01.0) + E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : -E012(self.e0123.0 * rhs.e3.0) + E012(self.s.0 * rhs.e012.0),
+		//     e013 : E013(self.e0123.0 * rhs.e2.0) + E013(self.s.0 * rhs.e013.0),
+		//     e023 : -E023(self.e0123.0 * rhs.e1.0) + E023(self.s.0 * rhs.e023.0),
+		//     e123 : -E123(self.e0123.0 * rhs.e0.0) + E123(self.s.0 * rhs.e123.0),
+		//     e0123: E0123(self.e0123.0 * rhs.s.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e01.dot(rhs.e01)
+				+ self.e0123.dot(rhs.e0123)
+				+ self.e02.dot(rhs.e02)
+				+ self.e03.dot(rhs.e03)
+				+ self.e12.dot(rhs.e12)
+				+ self.e13.dot(rhs.e13)
+				+ self.e23.dot(rhs.e23)
+				+ self.s.dot(rhs.s),
+			e0: self.e01.dot(rhs.e1)
+				+ self.e0123.dot(rhs.e123)
+				+ self.e02.dot(rhs.e2)
+				+ self.e03.dot(rhs.e3)
+				+ self.e12.dot(rhs.e012)
+				+ self.e13.dot(rhs.e013)
+				+ self.e23.dot(rhs.e023)
+				+ self.s.dot(rhs.e0),
+			e1: self.e01.dot(rhs.e0)
+				+ self.e0123.dot(rhs.e023)
+				+ self.e02.dot(rhs.e012)
+				+ self.e03.dot(rhs.e013)
+				+ self.e12.dot(rhs.e2)
+				+ self.e13.dot(rhs.e3)
+				+ self.e23.dot(rhs.e123)
+				+ self.s.dot(rhs.e1),
+			e2: self.e01.dot(rhs.e012)
+				+ self.e0123.dot(rhs.e013)
+				+ self.e02.dot(rhs.e0)
+				+ self.e03.dot(rhs.e023)
+				+ self.e12.dot(rhs.e1)
+				+ self.e13.dot(rhs.e123)
+				+ self.e23.dot(rhs.e3)
+				+ self.s.dot(rhs.e2),
+			e3: self.e01.dot(rhs.e013)
+				+ self.e0123.dot(rhs.e012)
+				+ self.e02.dot(rhs.e023)
+				+ self.e03.dot(rhs.e0)
+				+ self.e12.dot(rhs.e123)
+				+ self.e13.dot(rhs.e1)
+				+ self.e23.dot(rhs.e2)
+				+ self.s.dot(rhs.e3),
+			e01: self.e01.dot(rhs.s) + self.e0123.dot(rhs.e23) + self.e23.dot(rhs.e0123) + self.s.dot(rhs.e01),
+			e02: self.e0123.dot(rhs.e13) + self.e02.dot(rhs.s) + self.e13.dot(rhs.e0123) + self.s.dot(rhs.e02),
+			e03: self.e0123.dot(rhs.e12) + self.e03.dot(rhs.s) + self.e12.dot(rhs.e0123) + self.s.dot(rhs.e03),
+			e12: self.e0123.dot(rhs.e03) + self.e03.dot(rhs.e0123) + self.e12.dot(rhs.s) + self.s.dot(rhs.e12),
+			e13: self.e0123.dot(rhs.e02) + self.e02.dot(rhs.e0123) + self.e13.dot(rhs.s) + self.s.dot(rhs.e13),
+			e23: self.e01.dot(rhs.e0123) + self.e0123.dot(rhs.e01) + self.e23.dot(rhs.s) + self.s.dot(rhs.e23),
+			e012: self.e0123.dot(rhs.e3) + self.s.dot(rhs.e012),
+			e013: self.e0123.dot(rhs.e2) + self.s.dot(rhs.e013),
+			e023: self.e0123.dot(rhs.e1) + self.s.dot(rhs.e023),
+			e123: self.e0123.dot(rhs.e0) + self.s.dot(rhs.e123),
+			e0123: self.e0123.dot(rhs.s) + self.s.dot(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Rotor {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Rotor {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.s.0 * rhs.s.0),
+		//     e0   : E0(self.s.0 * rhs.e0.0),
+		//     e1   : E1(self.s.0 * rhs.e1.0),
+		//     e2   : E2(self.s.0 * rhs.e2.0),
+		//     e3   : E3(self.s.0 * rhs.e3.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e02.0 * rhs.s.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : E03(self.e03.0 * rhs.s.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e12.0 * rhs.s.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : E13(self.e13.0 * rhs.s.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e23.0 * rhs.s.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.e01.0 * rhs.e2.0) - E012(self.e02.0 * rhs.e1.0) + E012(self.e12.0 * rhs.e0.0) + E012(self.s.0 * rhs.e012.0),
+		//     e013 : E013(self.e01.0 * rhs.e3.0) - E013(self.e03.0 * rhs.e1.0) + E013(self.e13.0 * rhs.e0.0) + E013(self.s.0 * rhs.e013.0),
+		//     e023 : E023(self.e02.0 * rhs.e3.0) - E023(self.e03.0 * rhs.e2.0) + E023(self.e23.0 * rhs.e0.0) + E023(self.s.0 * rhs.e023.0),
+		//     e123 : E123(self.e12.0 * rhs.e3.0) - E123(self.e13.0 * rhs.e2.0) + E123(self.e23.0 * rhs.e1.0) + E123(self.s.0 * rhs.e123.0),
+		//     e0123: E0123(self.e01.0 * rhs.e23.0) + E0123(self.e0123.0 * rhs.s.0) - E0123(self.e02.0 * rhs.e13.0) + E0123(self.e03.0 * rhs.e12.0) + E0123(self.e12.0 * rhs.e03.0) - E0123(self.e13.0 * rhs.e02.0) + E0123(self.e23.0 * rhs.e01.0) + E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			e0: self.s.wedge(rhs.e0),
+			e1: self.s.wedge(rhs.e1),
+			e2: self.s.wedge(rhs.e2),
+			e3: self.s.wedge(rhs.e3),
+			e01: self.e01.wedge(rhs.s) + self.s.wedge(rhs.e01),
+			e02: self.e02.wedge(rhs.s) + self.s.wedge(rhs.e02),
+			e03: self.e03.wedge(rhs.s) + self.s.wedge(rhs.e03),
+			e12: self.e12.wedge(rhs.s) + self.s.wedge(rhs.e12),
+			e13: self.e13.wedge(rhs.s) + self.s.wedge(rhs.e13),
+			e23: self.e23.wedge(rhs.s) + self.s.wedge(rhs.e23),
+			e012: self.e01.wedge(rhs.e2) + self.e02.wedge(rhs.e1) + self.e12.wedge(rhs.e0) + self.s.wedge(rhs.e012),
+			e013: self.e01.wedge(rhs.e3) + self.e03.wedge(rhs.e1) + self.e13.wedge(rhs.e0) + self.s.wedge(rhs.e013),
+			e023: self.e02.wedge(rhs.e3) + self.e03.wedge(rhs.e2) + self.e23.wedge(rhs.e0) + self.s.wedge(rhs.e023),
+			e123: self.e12.wedge(rhs.e3) + self.e13.wedge(rhs.e2) + self.e23.wedge(rhs.e1) + self.s.wedge(rhs.e123),
+			e0123: self.e01.wedge(rhs.e23)
+				+ self.e0123.wedge(rhs.s)
+				+ self.e02.wedge(rhs.e13)
+				+ self.e03.wedge(rhs.e12)
+				+ self.e12.wedge(rhs.e03)
+				+ self.e13.wedge(rhs.e02)
+				+ self.e23.wedge(rhs.e01)
+				+ self.s.wedge(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Rotor {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Rotor {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e23.0) + S(self.e0123.0 * rhs.s.0) - S(self.e02.0 * rhs.e13.0) + S(self.e03.0 * rhs.e12.0) + S(self.e12.0 * rhs.e03.0) - S(self.e13.0 * rhs.e02.0) + S(self.e23.0 * rhs.e01.0) + S(self.s.0 * rhs.e0123.0),
+		//     e0   : E0(self.e01.0 * rhs.e023.0) + E0(self.e0123.0 * rhs.e0.0) - E0(self.e02.0 * rhs.e013.0) + E0(self.e03.0 * rhs.e012.0),
+		//     e1   : E1(self.e01.0 * rhs.e123.0) + E1(self.e0123.0 * rhs.e1.0) - E1(self.e12.0 * rhs.e013.0) + E1(self.e13.0 * rhs.e012.0),
+		//     e2   : E2(self.e0123.0 * rhs.e2.0) + E2(self.e02.0 * rhs.e123.0) - E2(self.e12.0 * rhs.e023.0) + E2(self.e23.0 * rhs.e012.0),
+		//     e3   : E3(self.e0123.0 * rhs.e3.0) + E3(self.e03.0 * rhs.e123.0) - E3(self.e13.0 * rhs.e023.0) + E3(self.e23.0 * rhs.e013.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e0123.0 * rhs.e01.0),
+		//     e02  : E02(self.e0123.0 * rhs.e02.0) + E02(self.e02.0 * rhs.e0123.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0) + E03(self.e03.0 * rhs.e0123.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0) + E12(self.e12.0 * rhs.e0123.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0) + E13(self.e13.0 * rhs.e0123.0),
+		//     e23  : E23(self.e0123.0 * rhs.e23.0) + E23(self.e23.0 * rhs.e0123.0),
+		//     e012 : E012(self.e0123.0 * rhs.e012.0),
+		//     e013 : E013(self.e0123.0 * rhs.e013.0),
+		//     e023 : E023(self.e0123.0 * rhs.e023.0),
+		//     e123 : E123(self.e0123.0 * rhs.e123.0),
+		//     e0123: E0123(self.e0123.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e01.anti_wedge(rhs.e23)
+				+ self.e0123.anti_wedge(rhs.s)
+				+ self.e02.anti_wedge(rhs.e13)
+				+ self.e03.anti_wedge(rhs.e12)
+				+ self.e12.anti_wedge(rhs.e03)
+				+ self.e13.anti_wedge(rhs.e02)
+				+ self.e23.anti_wedge(rhs.e01)
+				+ self.s.anti_wedge(rhs.e0123),
+			e0: self.e01.anti_wedge(rhs.e023)
+				+ self.e0123.anti_wedge(rhs.e0)
+				+ self.e02.anti_wedge(rhs.e013)
+				+ self.e03.anti_wedge(rhs.e012),
+			e1: self.e01.anti_wedge(rhs.e123)
+				+ self.e0123.anti_wedge(rhs.e1)
+				+ self.e12.anti_wedge(rhs.e013)
+				+ self.e13.anti_wedge(rhs.e012),
+			e2: self.e0123.anti_wedge(rhs.e2)
+				+ self.e02.anti_wedge(rhs.e123)
+				+ self.e12.anti_wedge(rhs.e023)
+				+ self.e23.anti_wedge(rhs.e012),
+			e3: self.e0123.anti_wedge(rhs.e3)
+				+ self.e03.anti_wedge(rhs.e123)
+				+ self.e13.anti_wedge(rhs.e023)
+				+ self.e23.anti_wedge(rhs.e013),
+			e01: self.e01.anti_wedge(rhs.e0123) + self.e0123.anti_wedge(rhs.e01),
+			e02: self.e0123.anti_wedge(rhs.e02) + self.e02.anti_wedge(rhs.e0123),
+			e03: self.e0123.anti_wedge(rhs.e03) + self.e03.anti_wedge(rhs.e0123),
+			e12: self.e0123.anti_wedge(rhs.e12) + self.e12.anti_wedge(rhs.e0123),
+			e13: self.e0123.anti_wedge(rhs.e13) + self.e13.anti_wedge(rhs.e0123),
+			e23: self.e0123.anti_wedge(rhs.e23) + self.e23.anti_wedge(rhs.e0123),
+			e012: self.e0123.anti_wedge(rhs.e012),
+			e013: self.e0123.anti_wedge(rhs.e013),
+			e023: self.e0123.anti_wedge(rhs.e023),
+			e123: self.e0123.anti_wedge(rhs.e123),
+			e0123: self.e0123.anti_wedge(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Rotor {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Rotor {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : -E0(self.e12.0 * rhs.e012.0) - E0(self.e13.0 * rhs.e013.0) - E0(self.e23.0 * rhs.e023.0) + E0(self.s.0 * rhs.e0.0),
+		//     e1   : -E1(self.e02.0 * rhs.e012.0) - E1(self.e03.0 * rhs.e013.0) - E1(self.e23.0 * rhs.e123.0) + E1(self.s.0 * rhs.e1.0),
+		//     e2   : E2(self.e01.0 * rhs.e012.0) - E2(self.e03.0 * rhs.e023.0) + E2(self.e13.0 * rhs.e123.0) + E2(self.s.0 * rhs.e2.0),
+		//     e3   : E3(self.e01.0 * rhs.e013.0) + E3(self.e02.0 * rhs.e023.0) - E3(self.e12.0 * rhs.e123.0) + E3(self.s.0 * rhs.e3.0),
+		//     e01  : -E01(self.e23.0 * rhs.e0123.0) + E01(self.s.0 * rhs.e01.0),
+		//     e02  : E02(self.e13.0 * rhs.e0123.0) + E02(self.s.0 * rhs.e02.0),
+		//     e03  : -E03(self.e12.0 * rhs.e0123.0) + E03(self.s.0 * rhs.e03.0),
+		//     e12  : E12(self.e03.0 * rhs.e0123.0) + E12(self.s.0 * rhs.e12.0),
+		//     e13  : -E13(self.e02.0 * rhs.e0123.0) + E13(self.s.0 * rhs.e13.0),
+		//     e23  : E23(self.e01.0 * rhs.e0123.0) + E23(self.s.0 * rhs.e23.0),
+		//     e012 : E012(self.s.0 * rhs.e012.0),
+		//     e013 : E013(self.s.0 * rhs.e013.0),
+		//     e023 : E023(self.s.0 * rhs.e023.0),
+		//     e123 : E123(self.s.0 * rhs.e123.0),
+		//     e0123: E0123(self.s.0 * rhs.e0123.0),
+		// }
+		Multivector {
+			s: self.e01.left_contraction(rhs.e01)
+				+ self.e0123.left_contraction(rhs.e0123)
+				+ self.e02.left_contraction(rhs.e02)
+				+ self.e03.left_contraction(rhs.e03)
+				+ self.e12.left_contraction(rhs.e12)
+				+ self.e13.left_contraction(rhs.e13)
+				+ self.e23.left_contraction(rhs.e23)
+				+ self.s.left_contraction(rhs.s),
+			e0: self.e12.left_contraction(rhs.e012)
+				+ self.e13.left_contraction(rhs.e013)
+				+ self.e23.left_contraction(rhs.e023)
+				+ self.s.left_contraction(rhs.e0),
+			e1: self.e02.left_contraction(rhs.e012)
+				+ self.e03.left_contraction(rhs.e013)
+				+ self.e23.left_contraction(rhs.e123)
+				+ self.s.left_contraction(rhs.e1),
+			e2: self.e01.left_contraction(rhs.e012)
+				+ self.e03.left_contraction(rhs.e023)
+				+ self.e13.left_contraction(rhs.e123)
+				+ self.s.left_contraction(rhs.e2),
+			e3: self.e01.left_contraction(rhs.e013)
+				+ self.e02.left_contraction(rhs.e023)
+				+ self.e12.left_contraction(rhs.e123)
+				+ self.s.left_contraction(rhs.e3),
+			e01: self.e23.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e01),
+			e02: self.e13.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e02),
+			e03: self.e12.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e03),
+			e12: self.e03.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e12),
+			e13: self.e02.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e13),
+			e23: self.e01.left_contraction(rhs.e0123) + self.s.left_contraction(rhs.e23),
+			e012: self.s.left_contraction(rhs.e012),
+			e013: self.s.left_contraction(rhs.e013),
+			e023: self.s.left_contraction(rhs.e023),
+			e123: self.s.left_contraction(rhs.e123),
+			e0123: self.s.left_contraction(rhs.e0123),
+		}
+	}
+}
+
+// Rotor.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Rotor {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0),
+		//     e0   : -E0(self.e01.0 * rhs.e1.0) + E0(self.e0123.0 * rhs.e123.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e03.0 * rhs.e3.0),
+		//     e1   : -E1(self.e01.0 * rhs.e0.0) + E1(self.e0123.0 * rhs.e023.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e13.0 * rhs.e3.0),
+		//     e2   : -E2(self.e0123.0 * rhs.e013.0) - E2(self.e02.0 * rhs.e0.0) + E2(self.e12.0 * rhs.e1.0) - E2(self.e23.0 * rhs.e3.0),
+		//     e3   : E3(self.e0123.0 * rhs.e012.0) - E3(self.e03.0 * rhs.e0.0) + E3(self.e13.0 * rhs.e1.0) + E3(self.e23.0 * rhs.e2.0),
+		//     e01  : E01(self.e01.0 * rhs.s.0) - E01(self.e0123.0 * rhs.e23.0),
+		//     e02  : E02(self.e0123.0 * rhs.e13.0) + E02(self.e02.0 * rhs.s.0),
+		//     e03  : -E03(self.e0123.0 * rhs.e12.0) + E03(self.e03.0 * rhs.s.0),
+		//     e12  : E12(self.e0123.0 * rhs.e03.0) + E12(self.e12.0 * rhs.s.0),
+		//     e13  : -E13(self.e0123.0 * rhs.e02.0) + E13(self.e13.0 * rhs.s.0),
+		//     e23  : E23(self.e0123.0 * rhs.e01.0) + E23(self.e23.0 * rhs.s.0),
+		//     e012 : -E012(self.e0123.0 * rhs.e3.0),
+		//     e013 : E013(self.e0123.0 * rhs.e2.0),
+		//     e023 : -E023(self.e0123.0 * rhs.e1.0),
+		//     e123 : -E123(self.e0123.0 * rhs.e0.0),
+		//     e0123: E0123(self.e0123.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.e01.right_contraction(rhs.e01)
+				+ self.e0123.right_contraction(rhs.e0123)
+				+ self.e02.right_contraction(rhs.e02)
+				+ self.e03.right_contraction(rhs.e03)
+				+ self.e12.right_contraction(rhs.e12)
+				+ self.e13.right_contraction(rhs.e13)
+				+ self.e23.right_contraction(rhs.e23)
+				+ self.s.right_contraction(rhs.s),
+			e0: self.e01.right_contraction(rhs.e1)
+				+ self.e0123.right_contraction(rhs.e123)
+				+ self.e02.right_contraction(rhs.e2)
+				+ self.e03.right_contraction(rhs.e3),
+			e1: self.e01.right_contraction(rhs.e0)
+				+ self.e0123.right_contraction(rhs.e023)
+				+ self.e12.right_contraction(rhs.e2)
+				+ self.e13.right_contraction(rhs.e3),
+			e2: self.e0123.right_contraction(rhs.e013)
+				+ self.e02.right_contraction(rhs.e0)
+				+ self.e12.right_contraction(rhs.e1)
+				+ self.e23.right_contraction(rhs.e3),
+			e3: self.e0123.right_contraction(rhs.e012)
+				+ self.e03.right_contraction(rhs.e0)
+				+ self.e13.right_contraction(rhs.e1)
+				+ self.e23.right_contraction(rhs.e2),
+			e01: self.e01.right_contraction(rhs.s) + self.e0123.right_contraction(rhs.e23),
+			e02: self.e0123.right_contraction(rhs.e13) + self.e02.right_contraction(rhs.s),
+			e03: self.e0123.right_contraction(rhs.e12) + self.e03.right_contraction(rhs.s),
+			e12: self.e0123.right_contraction(rhs.e03) + self.e12.right_contraction(rhs.s),
+			e13: self.e0123.right_contraction(rhs.e02) + self.e13.right_contraction(rhs.s),
+			e23: self.e0123.right_contraction(rhs.e01) + self.e23.right_contraction(rhs.s),
+			e012: self.e0123.right_contraction(rhs.e3),
+			e013: self.e0123.right_contraction(rhs.e2),
+			e023: self.e0123.right_contraction(rhs.e1),
+			e123: self.e0123.right_contraction(rhs.e0),
+			e0123: self.e0123.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Rotor.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Rotor {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.e01.0 * rhs.e01.0) - S(self.e0123.0 * rhs.e0123.0) + S(self.e02.0 * rhs.e02.0) + S(self.e03.0 * rhs.e03.0) - S(self.e12.0 * rhs.e12.0) - S(self.e13.0 * rhs.e13.0) - S(self.e23.0 * rhs.e23.0) + S(self.s.0 * rhs.s.0)
+		self.e01.scalar_product(rhs.e01)
+			+ self.e0123.scalar_product(rhs.e0123)
+			+ self.e02.scalar_product(rhs.e02)
+			+ self.e03.scalar_product(rhs.e03)
+			+ self.e12.scalar_product(rhs.e12)
+			+ self.e13.scalar_product(rhs.e13)
+			+ self.e23.scalar_product(rhs.e23)
+			+ self.s.scalar_product(rhs.s)
+	}
+}
+
+// Rotor.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Rotor {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : -E0(self.e01.0 * rhs.e1.0) + E0(self.e0123.0 * rhs.e123.0) - E0(self.e02.0 * rhs.e2.0) - E0(self.e03.0 * rhs.e3.0),
+		//     e1   : -E1(self.e01.0 * rhs.e0.0) + E1(self.e0123.0 * rhs.e023.0) - E1(self.e12.0 * rhs.e2.0) - E1(self.e13.0 * rhs.e3.0),
+		//     e2   : -E2(self.e0123.0 * rhs.e013.0) - E2(self.e02.0 * rhs.e0.0) + E2(self.e12.0 * rhs.e1.0) - E2(self.e23.0 * rhs.e3.0),
+		//     e3   : E3(self.e0123.0 * rhs.e012.0) - E3(self.e03.0 * rhs.e0.0) + E3(self.e13.0 * rhs.e1.0) + E3(self.e23.0 * rhs.e2.0),
+		//     e01  : E01(self.e02.0 * rhs.e12.0) + E01(self.e03.0 * rhs.e13.0) - E01(self.e12.0 * rhs.e02.0) - E01(self.e13.0 * rhs.e03.0),
+		//     e02  : -E02(self.e01.0 * rhs.e12.0) + E02(self.e03.0 * rhs.e23.0) + E02(self.e12.0 * rhs.e01.0) - E02(self.e23.0 * rhs.e03.0),
+		//     e03  : -E03(self.e01.0 * rhs.e13.0) - E03(self.e02.0 * rhs.e23.0) + E03(self.e13.0 * rhs.e01.0) + E03(self.e23.0 * rhs.e02.0),
+		//     e12  : -E12(self.e01.0 * rhs.e02.0) + E12(self.e02.0 * rhs.e01.0) + E12(self.e13.0 * rhs.e23.0) - E12(self.e23.0 * rhs.e13.0),
+		//     e13  : -E13(self.e01.0 * rhs.e03.0) + E13(self.e03.0 * rhs.e01.0) - E13(self.e12.0 * rhs.e23.0) + E13(self.e23.0 * rhs.e12.0),
+		//     e23  : -E23(self.e02.0 * rhs.e03.0) + E23(self.e03.0 * rhs.e02.0) + E23(self.e12.0 * rhs.e13.0) - E23(self.e13.0 * rhs.e12.0),
+		//     e012 : -E012(self.e0123.0 * rhs.e3.0) - E012(self.e03.0 * rhs.e123.0) + E012(self.e13.0 * rhs.e023.0) - E012(self.e23.0 * rhs.e013.0),
+		//     e013 : E013(self.e0123.0 * rhs.e2.0) + E013(self.e02.0 * rhs.e123.0) - E013(self.e12.0 * rhs.e023.0) + E013(self.e23.0 * rhs.e012.0),
+		//     e023 : -E023(self.e01.0 * rhs.e123.0) - E023(self.e0123.0 * rhs.e1.0) + E023(self.e12.0 * rhs.e013.0) - E023(self.e13.0 * rhs.e012.0),
+		//     e123 : -E123(self.e01.0 * rhs.e023.0) - E123(self.e0123.0 * rhs.e0.0) + E123(self.e02.0 * rhs.e013.0) - E123(self.e03.0 * rhs.e012.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.e01.commutator(rhs.e1)
+				+ self.e0123.commutator(rhs.e123)
+				+ self.e02.commutator(rhs.e2)
+				+ self.e03.commutator(rhs.e3),
+			e1: self.e01.commutator(rhs.e0)
+				+ self.e0123.commutator(rhs.e023)
+				+ self.e12.commutator(rhs.e2)
+				+ self.e13.commutator(rhs.e3),
+			e2: self.e0123.commutator(rhs.e013)
+				+ self.e02.commutator(rhs.e0)
+				+ self.e12.commutator(rhs.e1)
+				+ self.e23.commutator(rhs.e3),
+			e3: self.e0123.commutator(rhs.e012)
+				+ self.e03.commutator(rhs.e0)
+				+ self.e13.commutator(rhs.e1)
+				+ self.e23.commutator(rhs.e2),
+			e01: self.e02.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e13)
+				+ self.e12.commutator(rhs.e02)
+				+ self.e13.commutator(rhs.e03),
+			e02: self.e01.commutator(rhs.e12)
+				+ self.e03.commutator(rhs.e23)
+				+ self.e12.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e03),
+			e03: self.e01.commutator(rhs.e13)
+				+ self.e02.commutator(rhs.e23)
+				+ self.e13.commutator(rhs.e01)
+				+ self.e23.commutator(rhs.e02),
+			e12: self.e01.commutator(rhs.e02)
+				+ self.e02.commutator(rhs.e01)
+				+ self.e13.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e13),
+			e13: self.e01.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e01)
+				+ self.e12.commutator(rhs.e23)
+				+ self.e23.commutator(rhs.e12),
+			e23: self.e02.commutator(rhs.e03)
+				+ self.e03.commutator(rhs.e02)
+				+ self.e12.commutator(rhs.e13)
+				+ self.e13.commutator(rhs.e12),
+			e012: self.e0123.commutator(rhs.e3)
+				+ self.e03.commutator(rhs.e123)
+				+ self.e13.commutator(rhs.e023)
+				+ self.e23.commutator(rhs.e013),
+			e013: self.e0123.commutator(rhs.e2)
+				+ self.e02.commutator(rhs.e123)
+				+ self.e12.commutator(rhs.e023)
+				+ self.e23.commutator(rhs.e012),
+			e023: self.e01.commutator(rhs.e123)
+				+ self.e0123.commutator(rhs.e1)
+				+ self.e12.commutator(rhs.e013)
+				+ self.e13.commutator(rhs.e012),
+			e123: self.e01.commutator(rhs.e023)
+				+ self.e0123.commutator(rhs.e0)
+				+ self.e02.commutator(rhs.e013)
+				+ self.e03.commutator(rhs.e012),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Rotor.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Rotor {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.e0123.0 * rhs.s.0) + S(self.s.0 * rhs.e0123.0),
+		//     e0   : E0(self.e0123.0 * rhs.e0.0) + E0(self.s.0 * rhs.e123.0),
+		//     e1   : E1(self.e0123.0 * rhs.e1.0) + E1(self.s.0 * rhs.e023.0),
+		//     e2   : E2(self.e0123.0 * rhs.e2.0) - E2(self.s.0 * rhs.e013.0),
+		//     e3   : E3(self.e0123.0 * rhs.e3.0) + E3(self.s.0 * rhs.e012.0),
+		//     e01  : E01(self.e01.0 * rhs.e0123.0) + E01(self.e0123.0 * rhs.e01.0) + E01(self.e23.0 * rhs.s.0) + E01(self.s.0 * rhs.e23.0),
+		//     e02  : E02(self.e0123.0 * rhs.e02.0) + E02(self.e02.0 * rhs.e0123.0) - E02(self.e13.0 * rhs.s.0) - E02(self.s.0 * rhs.e13.0),
+		//     e03  : E03(self.e0123.0 * rhs.e03.0) + E03(self.e03.0 * rhs.e0123.0) + E03(self.e12.0 * rhs.s.0) + E03(self.s.0 * rhs.e12.0),
+		//     e12  : E12(self.e0123.0 * rhs.e12.0) - E12(self.e03.0 * rhs.s.0) + E12(self.e12.0 * rhs.e0123.0) - E12(self.s.0 * rhs.e03.0),
+		//     e13  : E13(self.e0123.0 * rhs.e13.0) + E13(self.e02.0 * rhs.s.0) + E13(self.e13.0 * rhs.e0123.0) + E13(self.s.0 * rhs.e02.0),
+		//     e23  : -E23(self.e01.0 * rhs.s.0) + E23(self.e0123.0 * rhs.e23.0) + E23(self.e23.0 * rhs.e0123.0) - E23(self.s.0 * rhs.e01.0),
+		//     e012 : -E012(self.e01.0 * rhs.e013.0) + E012(self.e0123.0 * rhs.e012.0) - E012(self.e02.0 * rhs.e023.0) - E012(self.e03.0 * rhs.e0.0) + E012(self.e12.0 * rhs.e123.0) + E012(self.e13.0 * rhs.e1.0) + E012(self.e23.0 * rhs.e2.0) - E012(self.s.0 * rhs.e3.0),
+		//     e013 : E013(self.e01.0 * rhs.e012.0) + E013(self.e0123.0 * rhs.e013.0) + E013(self.e02.0 * rhs.e0.0) - E013(self.e03.0 * rhs.e023.0) - E013(self.e12.0 * rhs.e1.0) + E013(self.e13.0 * rhs.e123.0) + E013(self.e23.0 * rhs.e3.0) + E013(self.s.0 * rhs.e2.0),
+		//     e023 : -E023(self.e01.0 * rhs.e0.0) + E023(self.e0123.0 * rhs.e023.0) + E023(self.e02.0 * rhs.e012.0) + E023(self.e03.0 * rhs.e013.0) - E023(self.e12.0 * rhs.e2.0) - E023(self.e13.0 * rhs.e3.0) + E023(self.e23.0 * rhs.e123.0) - E023(self.s.0 * rhs.e1.0),
+		//     e123 : -E123(self.e01.0 * rhs.e1.0) + E123(self.e0123.0 * rhs.e123.0) - E123(self.e02.0 * rhs.e2.0) - E123(self.e03.0 * rhs.e3.0) + E123(self.e12.0 * rhs.e012.0) + E123(self.e13.0 * rhs.e013.0) + E123(self.e23.0 * rhs.e023.0) - E123(self.s.0 * rhs.e0.0),
+		//     e0123: -E0123(self.e01.0 * rhs.e01.0) + E0123(self.e0123.0 * rhs.e0123.0) - E0123(self.e02.0 * rhs.e02.0) - E0123(self.e03.0 * rhs.e03.0) + E0123(self.e12.0 * rhs.e12.0) + E0123(self.e13.0 * rhs.e13.0) + E0123(self.e23.0 * rhs.e23.0) - E0123(self.s.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.e0123.anti_dot(rhs.s) + self.s.anti_dot(rhs.e0123),
+			e0: self.e0123.anti_dot(rhs.e0) + self.s.anti_dot(rhs.e123),
+			e1: self.e0123.anti_dot(rhs.e1) + self.s.anti_dot(rhs.e023),
+			e2: self.e0123.anti_dot(rhs.e2) + self.s.anti_dot(rhs.e013),
+			e3: self.e0123.anti_dot(rhs.e3) + self.s.anti_dot(rhs.e012),
+			e01: self.e01.anti_dot(rhs.e0123)
+				+ self.e0123.anti_dot(rhs.e01)
+				+ self.e23.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e23),
+			e02: self.e0123.anti_dot(rhs.e02)
+				+ self.e02.anti_dot(rhs.e0123)
+				+ self.e13.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e13),
+			e03: self.e0123.anti_dot(rhs.e03)
+				+ self.e03.anti_dot(rhs.e0123)
+				+ self.e12.anti_dot(rhs.s)
+				+ self.s.anti_dot(rhs.e12),
+			e12: self.e0123.anti_dot(rhs.e12)
+				+ self.e03.anti_dot(rhs.s)
+				+ self.e12.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e03),
+			e13: self.e0123.anti_dot(rhs.e13)
+				+ self.e02.anti_dot(rhs.s)
+				+ self.e13.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e02),
+			e23: self.e01.anti_dot(rhs.s)
+				+ self.e0123.anti_dot(rhs.e23)
+				+ self.e23.anti_dot(rhs.e0123)
+				+ self.s.anti_dot(rhs.e01),
+			e012: self.e01.anti_dot(rhs.e013)
+				+ self.e0123.anti_dot(rhs.e012)
+				+ self.e02.anti_dot(rhs.e023)
+				+ self.e03.anti_dot(rhs.e0)
+				+ self.e12.anti_dot(rhs.e123)
+				+ self.e13.anti_dot(rhs.e1)
+				+ self.e23.anti_dot(rhs.e2)
+				+ self.s.anti_dot(rhs.e3),
+			e013: self.e01.anti_dot(rhs.e012)
+				+ self.e0123.anti_dot(rhs.e013)
+				+ self.e02.anti_dot(rhs.e0)
+				+ self.e03.anti_dot(rhs.e023)
+				+ self.e12.anti_dot(rhs.e1)
+				+ self.e13.anti_dot(rhs.e123)
+				+ self.e23.anti_dot(rhs.e3)
+				+ self.s.anti_dot(rhs.e2),
+			e023: self.e01.anti_dot(rhs.e0)
+				+ self.e0123.anti_dot(rhs.e023)
+				+ self.e02.anti_dot(rhs.e012)
+				+ self.e03.anti_dot(rhs.e013)
+				+ self.e12.anti_dot(rhs.e2)
+				+ self.e13.anti_dot(rhs.e3)
+				+ self.e23.anti_dot(rhs.e123)
+				+ self.s.anti_dot(rhs.e1),
+			e123: self.e01.anti_dot(rhs.e1)
+				+ self.e0123.anti_dot(rhs.e123)
+				+ self.e02.anti_dot(rhs.e2)
+				+ self.e03.anti_dot(rhs.e3)
+				+ self.e12.anti_dot(rhs.e012)
+				+ self.e13.anti_dot(rhs.e013)
+				+ self.e23.anti_dot(rhs.e023)
+				+ self.s.anti_dot(rhs.e0),
+			e0123: self.e01.anti_dot(rhs.e01)
+				+ self.e0123.anti_dot(rhs.e0123)
+				+ self.e02.anti_dot(rhs.e02)
+				+ self.e03.anti_dot(rhs.e03)
+				+ self.e12.anti_dot(rhs.e12)
+				+ self.e13.anti_dot(rhs.e13)
+				+ self.e23.anti_dot(rhs.e23)
+				+ self.s.anti_dot(rhs.s),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Rotor.sandwich(Vec4) -> Vec4
+impl Sandwich<Vec4> for Rotor {
+	fn sandwich(self, rhs: Vec4) -> Vec4 {
+		Vec4 {
+			t: self.e01.geometric(self.e01).geometric(rhs.t)
+				+ self.e01.geometric(self.e12).geometric(rhs.y) * 2.0
+				+ self.e01.geometric(self.e13).geometric(rhs.z) * 2.0
+				+ self.e01.geometric(self.s).geometric(rhs.x) * 2.0
+				- self.e0123.geometric(self.e0123).geometric(rhs.t)
+				- self.e0123.geometric(self.e12).geometric(rhs.z) * 2.0
+				- self.e0123.geometric(self.e13).geometric(rhs.y) * 2.0
+				- self.e0123.geometric(self.e23).geometric(rhs.x) * 2.0
+				+ self.e02.geometric(self.e02).geometric(rhs.t)
+				+ self.e02.geometric(self.e12).geometric(rhs.x) * 2.0
+				+ self.e02.geometric(self.e23).geometric(rhs.z) * 2.0
+				+ self.e02.geometric(self.s).geometric(rhs.y) * 2.0
+				+ self.e03.geometric(self.e03).geometric(rhs.t)
+				+ self.e03.geometric(self.e13).geometric(rhs.x) * 2.0
+				+ self.e03.geometric(self.e23).geometric(rhs.y) * 2.0
+				+ self.e03.geometric(self.s).geometric(rhs.z) * 2.0
+				- self.e12.geometric(self.e12).geometric(rhs.t)
+				- self.e13.geometric(self.e13).geometric(rhs.t)
+				- self.e23.geometric(self.e23).geometric(rhs.t)
+				+ self.s.geometric(self.s).geometric(rhs.t),
+			x: self.e01.geometric(self.e01).geometric(rhs.x)
+				+ self.e01.geometric(self.e02).geometric(rhs.y) * 2.0
+				+ self.e01.geometric(self.e03).geometric(rhs.z) * 2.0
+				+ self.e01.geometric(self.s).geometric(rhs.t) * 2.0
+				- self.e0123.geometric(self.e0123).geometric(rhs.x)
+				- self.e0123.geometric(self.e02).geometric(rhs.z) * 2.0
+				- self.e0123.geometric(self.e03).geometric(rhs.y) * 2.0
+				- self.e0123.geometric(self.e23).geometric(rhs.t) * 2.0
+				- self.e02.geometric(self.e02).geometric(rhs.x)
+				- self.e02.geometric(self.e12).geometric(rhs.t) * 2.0
+				- self.e03.geometric(self.e03).geometric(rhs.x)
+				- self.e03.geometric(self.e13).geometric(rhs.t) * 2.0
+				+ self.e12.geometric(self.e12).geometric(rhs.x)
+				+ self.e12.geometric(self.e23).geometric(rhs.z) * 2.0
+				+ self.e12.geometric(self.s).geometric(rhs.y) * 2.0
+				+ self.e13.geometric(self.e13).geometric(rhs.x)
+				+ self.e13.geometric(self.e23).geometric(rhs.y) * 2.0
+				+ self.e13.geometric(self.s).geometric(rhs.z) * 2.0
+				- self.e23.geometric(self.e23).geometric(rhs.x)
+				+ self.s.geometric(self.s).geometric(rhs.x),
+			y: -self.e01.geometric(self.e01).geometric(rhs.y)
+				- self.e01.geometric(self.e0123).geometric(rhs.z) * 2.0
+				- self.e01.geometric(self.e02).geometric(rhs.x) * 2.0
+				- self.e01.geometric(self.e12).geometric(rhs.t) * 2.0
+				- self.e0123.geometric(self.e0123).geometric(rhs.y)
+				- self.e0123.geometric(self.e03).geometric(rhs.x) * 2.0
+				- self.e0123.geometric(self.e13).geometric(rhs.t) * 2.0
+				+ self.e02.geometric(self.e02).geometric(rhs.y)
+				+ self.e02.geometric(self.e03).geometric(rhs.z) * 2.0
+				+ self.e02.geometric(self.s).geometric(rhs.t) * 2.0
+				- self.e03.geometric(self.e03).geometric(rhs.y)
+				- self.e03.geometric(self.e23).geometric(rhs.t) * 2.0
+				+ self.e12.geometric(self.e12).geometric(rhs.y)
+				+ self.e12.geometric(self.e13).geometric(rhs.z) * 2.0
+				+ self.e12.geometric(self.s).geometric(rhs.x) * 2.0
+				- self.e13.geometric(self.e13).geometric(rhs.y)
+				- self.e13.geometric(self.e23).geometric(rhs.x) * 2.0
+				+ self.e23.geometric(self.e23).geometric(rhs.y)
+				+ self.e23.geometric(self.s).geometric(rhs.z) * 2.0
+				+ self.s.geometric(self.s).geometric(rhs.y),
+			z: -self.e01.geometric(self.e01).geometric(rhs.z)
+				- self.e01.geometric(self.e0123).geometric(rhs.y) * 2.0
+				- self.e01.geometric(self.e03).geometric(rhs.x) * 2.0
+				- self.e01.geometric(self.e13).geometric(rhs.t) * 2.0
+				- self.e0123.geometric(self.e0123).geometric(rhs.z)
+				- self.e0123.geometric(self.e02).geometric(rhs.x) * 2.0
+				- self.e0123.geometric(self.e12).geometric(rhs.t) * 2.0
+				- self.e02.geometric(self.e02).geometric(rhs.z)
+				- self.e02.geometric(self.e03).geometric(rhs.y) * 2.0
+				- self.e02.geometric(self.e23).geometric(rhs.t) * 2.0
+				+ self.e03.geometric(self.e03).geometric(rhs.z)
+				+ self.e03.geometric(self.s).geometric(rhs.t) * 2.0
+				- self.e12.geometric(self.e12).geometric(rhs.z)
+				- self.e12.geometric(self.e13).geometric(rhs.y) * 2.0
+				- self.e12.geometric(self.e23).geometric(rhs.x) * 2.0
+				+ self.e13.geometric(self.e13).geometric(rhs.z)
+				+ self.e13.geometric(self.s).geometric(rhs.x) * 2.0
+				+ self.e23.geometric(self.e23).geometric(rhs.z)
+				+ self.e23.geometric(self.s).geometric(rhs.y) * 2.0
+				+ self.s.geometric(self.s).geometric(rhs.z),
+		}
+	}
+}
+
+// Rotor.anti_sandwich(Vec4) -> Vec4
+impl AntiSandwich<Vec4> for Rotor {
+	fn anti_sandwich(self, rhs: Vec4) -> Vec4 {
+		Vec4 {
+			t: -self.e01.anti_geometric(self.e01).anti_geometric(rhs.t)
+				- self.e01.anti_geometric(self.e12).anti_geometric(rhs.y) * 2.0
+				- self.e01.anti_geometric(self.e13).anti_geometric(rhs.z) * 2.0
+				- self.e01.anti_geometric(self.s).anti_geometric(rhs.x) * 2.0
+				+ self.e0123.anti_geometric(self.e0123).anti_geometric(rhs.t)
+				+ self.e0123.anti_geometric(self.e12).anti_geometric(rhs.z) * 2.0
+				+ self.e0123.anti_geometric(self.e13).anti_geometric(rhs.y) * 2.0
+				+ self.e0123.anti_geometric(self.e23).anti_geometric(rhs.x) * 2.0
+				- self.e02.anti_geometric(self.e02).anti_geometric(rhs.t)
+				- self.e02.anti_geometric(self.e12).anti_geometric(rhs.x) * 2.0
+				- self.e02.anti_geometric(self.e23).anti_geometric(rhs.z) * 2.0
+				- self.e02.anti_geometric(self.s).anti_geometric(rhs.y) * 2.0
+				- self.e03.anti_geometric(self.e03).anti_geometric(rhs.t)
+				- self.e03.anti_geometric(self.e13).anti_geometric(rhs.x) * 2.0
+				- self.e03.anti_geometric(self.e23).anti_geometric(rhs.y) * 2.0
+				- self.e03.anti_geometric(self.s).anti_geometric(rhs.z) * 2.0
+				+ self.e12.anti_geometric(self.e12).anti_geometric(rhs.t)
+				+ self.e13.anti_geometric(self.e13).anti_geometric(rhs.t)
+				+ self.e23.anti_geometric(self.e23).anti_geometric(rhs.t)
+				- self.s.anti_geometric(self.s).anti_geometric(rhs.t),
+			x: -self.e01.anti_geometric(self.e01).anti_geometric(rhs.x)
+				- self.e01.anti_geometric(self.e02).anti_geometric(rhs.y) * 2.0
+				- self.e01.anti_geometric(self.e03).anti_geometric(rhs.z) * 2.0
+				- self.e01.anti_geometric(self.s).anti_geometric(rhs.t) * 2.0
+				+ self.e0123.anti_geometric(self.e0123).anti_geometric(rhs.x)
+				+ self.e0123.anti_geometric(self.e02).anti_geometric(rhs.z) * 2.0
+				+ self.e0123.anti_geometric(self.e03).anti_geometric(rhs.y) * 2.0
+				+ self.e0123.anti_geometric(self.e23).anti_geometric(rhs.t) * 2.0
+				+ self.e02.anti_geometric(self.e02).anti_geometric(rhs.x)
+				+ self.e02.anti_geometric(self.e12).anti_geometric(rhs.t) * 2.0
+				+ self.e03.anti_geometric(self.e03).anti_geometric(rhs.x)
+				+ self.e03.anti_geometric(self.e13).anti_geometric(rhs.t) * 2.0
+				- self.e12.anti_geometric(self.e12).anti_geometric(rhs.x)
+				- self.e12.anti_geometric(self.e23).anti_geometric(rhs.z) * 2.0
+				- self.e12.anti_geometric(self.s).anti_geometric(rhs.y) * 2.0
+				- self.e13.anti_geometric(self.e13).anti_geometric(rhs.x)
+				- self.e13.anti_geometric(self.e23).anti_geometric(rhs.y) * 2.0
+				- self.e13.anti_geometric(self.s).anti_geometric(rhs.z) * 2.0
+				+ self.e23.anti_geometric(self.e23).anti_geometric(rhs.x)
+				- self.s.anti_geometric(self.s).anti_geometric(rhs.x),
+			y: self.e01.anti_geometric(self.e01).anti_geometric(rhs.y)
+				+ self.e01.anti_geometric(self.e0123).anti_geometric(rhs.z) * 2.0
+				+ self.e01.anti_geometric(self.e02).anti_geometric(rhs.x) * 2.0
+				+ self.e01.anti_geometric(self.e12).anti_geometric(rhs.t) * 2.0
+				+ self.e0123.anti_geometric(self.e0123).anti_geometric(rhs.y)
+				+ self.e0123.anti_geometric(self.e03).anti_geometric(rhs.x) * 2.0
+				+ self.e0123.anti_geometric(self.e13).anti_geometric(rhs.t) * 2.0
+				- self.e02.anti_geometric(self.e02).anti_geometric(rhs.y)
+				- self.e02.anti_geometric(self.e03).anti_geometric(rhs.z) * 2.0
+				- self.e02.anti_geometric(self.s).anti_geometric(rhs.t) * 2.0
+				+ self.e03.anti_geometric(self.e03).anti_geometric(rhs.y)
+				+ self.e03.anti_geometric(self.e23).anti_geometric(rhs.t) * 2.0
+				- self.e12.anti_geometric(self.e12).anti_geometric(rhs.y)
+				- self.e12.anti_geometric(self.e13).anti_geometric(rhs.z) * 2.0
+				- self.e12.anti_geometric(self.s).anti_geometric(rhs.x) * 2.0
+				+ self.e13.anti_geometric(self.e13).anti_geometric(rhs.y)
+				+ self.e13.anti_geometric(self.e23).anti_geometric(rhs.x) * 2.0
+				- self.e23.anti_geometric(self.e23).anti_geometric(rhs.y)
+				- self.e23.anti_geometric(self.s).anti_geometric(rhs.z) * 2.0
+				- self.s.anti_geometric(self.s).anti_geometric(rhs.y),
+			z: self.e01.anti_geometric(self.e01).anti_geometric(rhs.z)
+				+ self.e01.anti_geometric(self.e0123).anti_geometric(rhs.y) * 2.0
+				+ self.e01.anti_geometric(self.e03).anti_geometric(rhs.x) * 2.0
+				+ self.e01.anti_geometric(self.e13).anti_geometric(rhs.t) * 2.0
+				+ self.e0123.anti_geometric(self.e0123).anti_geometric(rhs.z)
+				+ self.e0123.anti_geometric(self.e02).anti_geometric(rhs.x) * 2.0
+				+ self.e0123.anti_geometric(self.e12).anti_geometric(rhs.t) * 2.0
+				+ self.e02.anti_geometric(self.e02).anti_geometric(rhs.z)
+				+ self.e02.anti_geometric(self.e03).anti_geometric(rhs.y) * 2.0
+				+ self.e02.anti_geometric(self.e23).anti_geometric(rhs.t) * 2.0
+				- self.e03.anti_geometric(self.e03).anti_geometric(rhs.z)
+				- self.e03.anti_geometric(self.s).anti_geometric(rhs.t) * 2.0
+				+ self.e12.anti_geometric(self.e12).anti_geometric(rhs.z)
+				+ self.e12.anti_geometric(self.e13).anti_geometric(rhs.y) * 2.0
+				+ self.e12.anti_geometric(self.e23).anti_geometric(rhs.x) * 2.0
+				- self.e13.anti_geometric(self.e13).anti_geometric(rhs.z)
+				- self.e13.anti_geometric(self.s).anti_geometric(rhs.x) * 2.0
+				- self.e23.anti_geometric(self.e23).anti_geometric(rhs.z)
+				- self.e23.anti_geometric(self.s).anti_geometric(rhs.y) * 2.0
+				- self.s.anti_geometric(self.s).anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Rotor.sandwich(Bivector) -> Bivector
+impl Sandwich<Bivector> for Rotor {
+	fn sandwich(self, rhs: Bivector) -> Bivector {
+		Bivector {
+			e01: -self.e01.geometric(self.e01).geometric(rhs.e01)
+				- self.e01.geometric(self.e02).geometric(rhs.e02) * 2.0
+				- self.e01.geometric(self.e03).geometric(rhs.e03) * 2.0
+				- self.e01.geometric(self.e12).geometric(rhs.e12) * 2.0
+				- self.e01.geometric(self.e13).geometric(rhs.e13) * 2.0
+				- self.e01.geometric(self.e23).geometric(rhs.e23) * 2.0
+				+ self.e0123.geometric(self.e0123).geometric(rhs.e01)
+				+ self.e0123.geometric(self.e02).geometric(rhs.e03) * 2.0
+				+ self.e0123.geometric(self.e03).geometric(rhs.e02) * 2.0
+				+ self.e0123.geometric(self.e12).geometric(rhs.e13) * 2.0
+				+ self.e0123.geometric(self.e13).geometric(rhs.e12) * 2.0
+				+ self.e0123.geometric(self.s).geometric(rhs.e23) * 2.0
+				+ self.e02.geometric(self.e02).geometric(rhs.e01)
+				+ self.e02.geometric(self.e13).geometric(rhs.e23) * 2.0
+				+ self.e02.geometric(self.e23).geometric(rhs.e13) * 2.0
+				+ self.e02.geometric(self.s).geometric(rhs.e12) * 2.0
+				+ self.e03.geometric(self.e03).geometric(rhs.e01)
+				+ self.e03.geometric(self.e12).geometric(rhs.e23) * 2.0
+				+ self.e03.geometric(self.e23).geometric(rhs.e12) * 2.0
+				+ self.e03.geometric(self.s).geometric(rhs.e13) * 2.0
+				+ self.e12.geometric(self.e12).geometric(rhs.e01)
+				+ self.e12.geometric(self.e23).geometric(rhs.e03) * 2.0
+				+ self.e12.geometric(self.s).geometric(rhs.e02) * 2.0
+				+ self.e13.geometric(self.e13).geometric(rhs.e01)
+				+ self.e13.geometric(self.e23).geometric(rhs.e02) * 2.0
+				+ self.e13.geometric(self.s).geometric(rhs.e03) * 2.0
+				- self.e23.geometric(self.e23).geometric(rhs.e01)
+				+ self.s.geometric(self.s).geometric(rhs.e01),
+			e02: self.e01.geometric(self.e01).geometric(rhs.e02)
+				+ self.e01.geometric(self.e0123).geometric(rhs.e03) * 2.0
+				+ self.e01.geometric(self.e02).geometric(rhs.e01) * 2.0
+				+ self.e01.geometric(self.e13).geometric(rhs.e23) * 2.0
+				+ self.e01.geometric(self.e23).geometric(rhs.e13) * 2.0
+				+ self.e01.geometric(self.s).geometric(rhs.e12) * 2.0
+				+ self.e0123.geometric(self.e0123).geometric(rhs.e02)
+				+ self.e0123.geometric(self.e03).geometric(rhs.e01) * 2.0
+				+ self.e0123.geometric(self.e12).geometric(rhs.e23) * 2.0
+				+ self.e0123.geometric(self.e23).geometric(rhs.e12) * 2.0
+				+ self.e0123.geometric(self.s).geometric(rhs.e13) * 2.0
+				- self.e02.geometric(self.e02).geometric(rhs.e02)
+				- self.e02.geometric(self.e03).geometric(rhs.e03) * 2.0
+				- self.e02.geometric(self.e12).geometric(rhs.e12) * 2.0
+				- self.e02.geometric(self.e13).geometric(rhs.e13) * 2.0
+				- self.e02.geometric(self.e23).geometric(rhs.e23) * 2.0
+				+ self.e03.geometric(self.e03).geometric(rhs.e02)
+				+ self.e03.geometric(self.e12).geometric(rhs.e13) * 2.0
+				+ self.e03.geometric(self.e13).geometric(rhs.e12) * 2.0
+				+ self.e03.geometric(self.s).geometric(rhs.e23) * 2.0
+				+ self.e12.geometric(self.e12).geometric(rhs.e02)
+				+ self.e12.geometric(self.e13).geometric(rhs.e03) * 2.0
+				+ self.e12.geometric(self.s).geometric(rhs.e01) * 2.0
+				- self.e13.geometric(self.e13).geometric(rhs.e02)
+				- self.e13.geometric(self.e23).geometric(rhs.e01) * 2.0
+				+ self.e23.geometric(self.e23).geometric(rhs.e02)
+				+ self.e23.geometric(self.s).geometric(rhs.e03) * 2.0
+				+ self.s.geometric(self.s).geometric(rhs.e02),
+			e03: self.e01.geometric(self.e01).geometric(rhs.e03)
+				+ self.e01.geometric(self.e0123).geometric(rhs.e02) * 2.0
+				+ self.e01.geometric(self.e03).geometric(rhs.e01) * 2.0
+				+ self.e01.geometric(self.e12).geometric(rhs.e23) * 2.0
+				+ self.e01.geometric(self.e23).geometric(rhs.e12) * 2.0
+				+ self.e01.geometric(self.s).geometric(rhs.e13) * 2.0
+				+ self.e0123.geometric(self.e0123).geometric(rhs.e03)
+				+ self.e0123.geometric(self.e02).geometric(rhs.e01) * 2.0
+				+ self.e0123.geometric(self.e13).geometric(rhs.e23) * 2.0
+				+ self.e0123.geometric(self.e23).geometric(rhs.e13) * 2.0
+				+ self.e0123.geometric(self.s).geometric(rhs.e12) * 2.0
+				+ self.e02.geometric(self.e02).geometric(rhs.e03)
+				+ self.e02.geometric(self.e03).geometric(rhs.e02) * 2.0
+				+ self.e02.geometric(self.e12).geometric(rhs.e13) * 2.0
+				+ self.e02.geometric(self.e13).geometric(rhs.e12) * 2.0
+				+ self.e02.geometric(self.s).geometric(rhs.e23) * 2.0
+				- self.e03.geometric(self.e03).geometric(rhs.e03)
+				- self.e03.geometric(self.e12).geometric(rhs.e12) * 2.0
+				- self.e03.geometric(self.e13).geometric(rhs.e13) * 2.0
+				- self.e03.geometric(self.e23).geometric(rhs.e23) * 2.0
+				- self.e12.geometric(self.e12).geometric(rhs.e03)
+				- self.e12.geometric(self.e13).geometric(rhs.e02) * 2.0
+				- self.e12.geometric(self.e23).geometric(rhs.e01) * 2.0
+				+ self.e13.geometric(self.e13).geometric(rhs.e03)
+				+ self.e13.geometric(self.s).geometric(rhs.e01) * 2.0
+				+ self.e23.geometric(self.e23).geometric(rhs.e03)
+				+ self.e23.geometric(self.s).geometric(rhs.e02) * 2.0
+				+ self.s.geometric(self.s).geometric(rhs.e03),
+			e12: self.e01.geometric(self.e01).geometric(rhs.e12)
+				+ self.e01.geometric(self.e0123).geometric(rhs.e13) * 2.0
+				+ self.e01.geometric(self.e03).geometric(rhs.e23) * 2.0
+				+ self.e01.geometric(self.e12).geometric(rhs.e01) * 2.0
+				+ self.e01.geometric(self.e23).geometric(rhs.e03) * 2.0
+				+ self.e01.geometric(self.s).geometric(rhs.e02) * 2.0
+				+ self.e0123.geometric(self.e0123).geometric(rhs.e12)
+				+ self.e0123.geometric(self.e02).geometric(rhs.e23) * 2.0
+				+ self.e0123.geometric(self.e13).geometric(rhs.e01) * 2.0
+				+ self.e0123.geometric(self.e23).geometric(rhs.e02) * 2.0
+				+ self.e0123.geometric(self.s).geometric(rhs.e03) * 2.0
+				+ self.e02.geometric(self.e02).geometric(rhs.e12)
+				+ self.e02.geometric(self.e03).geometric(rhs.e13) * 2.0
+				+ self.e02.geometric(self.e12).geometric(rhs.e02) * 2.0
+				+ self.e02.geometric(self.e13).geometric(rhs.e03) * 2.0
+				+ self.e02.geometric(self.s).geometric(rhs.e01) * 2.0
+				- self.e03.geometric(self.e03).geometric(rhs.e12)
+				- self.e03.geometric(self.e12).geometric(rhs.e03) * 2.0
+				- self.e03.geometric(self.e13).geometric(rhs.e02) * 2.0
+				- self.e03.geometric(self.e23).geometric(rhs.e01) * 2.0
+				- self.e12.geometric(self.e12).geometric(rhs.e12)
+				- self.e12.geometric(self.e13).geometric(rhs.e13) * 2.0
+				- self.e12.geometric(self.e23).geometric(rhs.e23) * 2.0
+				+ self.e13.geometric(self.e13).geometric(rhs.e12)
+				+ self.e13.geometric(self.s).geometric(rhs.e23) * 2.0
+				+ self.e23.geometric(self.e23).geometric(rhs.e12)
+				+ self.e23.geometric(self.s).geometric(rhs.e13) * 2.0
+				+ self.s.geometric(self.s).geometric(rhs.e12),
+			e13: self.e01.geometric(self.e01).geometric(rhs.e13)
+				+ self.e01.geometric(self.e0123).geometric(rhs.e12) * 2.0
+				+ self.e01.geometric(self.e02).geometric(rhs.e23) * 2.0
+				+ self.e01.geometric(self.e13).geometric(rhs.e01) * 2.0
+				+ self.e01.geometric(self.e23).geometric(rhs.e02) * 2.0
+				+ self.e01.geometric(self.s).geometric(rhs.e03) * 2.0
+				+ self.e0123.geometric(self.e0123).geometric(rhs.e13)
+				+ self.e0123.geometric(self.e03).geometric(rhs.e23) * 2.0
+				+ self.e0123.geometric(self.e12).geometric(rhs.e01) * 2.0
+				+ self.e0123.geometric(self.e23).geometric(rhs.e03) * 2.0
+				+ self.e0123.geometric(self.s).geometric(rhs.e02) * 2.0
+				- self.e02.geometric(self.e02).geometric(rhs.e13)
+				- self.e02.geometric(self.e03).geometric(rhs.e12) * 2.0
+				- self.e02.geometric(self.e12).geometric(rhs.e03) * 2.0
+				- self.e02.geometric(self.e13).geometric(rhs.e02) * 2.0
+				- self.e02.geometric(self.e23).geometric(rhs.e01) * 2.0
+				+ self.e03.geometric(self.e03).geometric(rhs.e13)
+				+ self.e03.geometric(self.e12).geometric(rhs.e02) * 2.0
+				+ self.e03.geometric(self.e13).geometric(rhs.e03) * 2.0
+				+ self.e03.geometric(self.s).geometric(rhs.e01) * 2.0
+				+ self.e12.geometric(self.e12).geometric(rhs.e13)
+				+ self.e12.geometric(self.e13).geometric(rhs.e12) * 2.0
+				+ self.e12.geometric(self.s).geometric(rhs.e23) * 2.0
+				- self.e13.geometric(self.e13).geometric(rhs.e13)
+				- self.e13.geometric(self.e23).geometric(rhs.e23) * 2.0
+				+ self.e23.geometric(self.e23).geometric(rhs.e13)
+				+ self.e23.geometric(self.s).geometric(rhs.e12) * 2.0
+				+ self.s.geometric(self.s).geometric(rhs.e13),
+			e23: -self.e01.geometric(self.e01).geometric(rhs.e23)
+				- self.e01.geometric(self.e02).geometric(rhs.e13) * 2.0
+				- self.e01.geometric(self.e03).geometric(rhs.e12) * 2.0
+				- self.e01.geometric(self.e12).geometric(rhs.e03) * 2.0
+				- self.e01.geometric(self.e13).geometric(rhs.e02) * 2.0
+				- self.e01.geometric(self.e23).geometric(rhs.e01) * 2.0
+				+ self.e0123.geometric(self.e0123).geometric(rhs.e23)
+				+ self.e0123.geometric(self.e02).geometric(rhs.e12) * 2.0
+				+ self.e0123.geometric(self.e03).geometric(rhs.e13) * 2.0
+				+ self.e0123.geometric(self.e12).geometric(rhs.e02) * 2.0
+				+ self.e0123.geometric(self.e13).geometric(rhs.e03) * 2.0
+				+ self.e0123.geometric(self.s).geometric(rhs.e01) * 2.0
+				+ self.e02.geometric(self.e02).geometric(rhs.e23)
+				+ self.e02.geometric(self.e13).geometric(rhs.e01) * 2.0
+				+ self.e02.geometric(self.e23).geometric(rhs.e02) * 2.0
+				+ self.e02.geometric(self.s).geometric(rhs.e03) * 2.0
+				+ self.e03.geometric(self.e03).geometric(rhs.e23)
+				+ self.e03.geometric(self.e12).geometric(rhs.e01) * 2.0
+				+ self.e03.geometric(self.e23).geometric(rhs.e03) * 2.0
+				+ self.e03.geometric(self.s).geometric(rhs.e02) * 2.0
+				+ self.e12.geometric(self.e12).geometric(rhs.e23)
+				+ self.e12.geometric(self.e23).geometric(rhs.e12) * 2.0
+				+ self.e12.geometric(self.s).geometric(rhs.e13) * 2.0
+				+ self.e13.geometric(self.e13).geometric(rhs.e23)
+				+ self.e13.geometric(self.e23).geometric(rhs.e13) * 2.0
+				+ self.e13.geometric(self.s).geometric(rhs.e12) * 2.0
+				- self.e23.geometric(self.e23).geometric(rhs.e23)
+				+ self.s.geometric(self.s).geometric(rhs.e23),
+		}
+	}
+}
+
+// Rotor.anti_sandwich(Bivector) -> Bivector
+impl AntiSandwich<Bivector> for Rotor {
+	fn anti_sandwich(self, rhs: Bivector) -> Bivector {
+		Bivector {
+			e01: -self.e01.anti_geometric(self.e01).anti_geometric(rhs.e01)
+				- self.e01.anti_geometric(self.e02).anti_geometric(rhs.e02) * 2.0
+				- self.e01.anti_geometric(self.e03).anti_geometric(rhs.e03) * 2.0
+				- self.e01.anti_geometric(self.e12).anti_geometric(rhs.e12) * 2.0
+				- self.e01.anti_geometric(self.e13).anti_geometric(rhs.e13) * 2.0
+				- self.e01.anti_geometric(self.e23).anti_geometric(rhs.e23) * 2.0
+				+ self.e0123.anti_geometric(self.e0123).anti_geometric(rhs.e01)
+				+ self.e0123.anti_geometric(self.e02).anti_geometric(rhs.e03) * 2.0
+				+ self.e0123.anti_geometric(self.e03).anti_geometric(rhs.e02) * 2.0
+				+ self.e0123.anti_geometric(self.e12).anti_geometric(rhs.e13) * 2.0
+				+ self.e0123.anti_geometric(self.e13).anti_geometric(rhs.e12) * 2.0
+				+ self.e0123.anti_geometric(self.s).anti_geometric(rhs.e23) * 2.0
+				+ self.e02.anti_geometric(self.e02).anti_geometric(rhs.e01)
+				+ self.e02.anti_geometric(self.e13).anti_geometric(rhs.e23) * 2.0
+				+ self.e02.anti_geometric(self.e23).anti_geometric(rhs.e13) * 2.0
+				+ self.e02.anti_geometric(self.s).anti_geometric(rhs.e12) * 2.0
+				+ self.e03.anti_geometric(self.e03).anti_geometric(rhs.e01)
+				+ self.e03.anti_geometric(self.e12).anti_geometric(rhs.e23) * 2.0
+				+ self.e03.anti_geometric(self.e23).anti_geometric(rhs.e12) * 2.0
+				+ self.e03.anti_geometric(self.s).anti_geometric(rhs.e13) * 2.0
+				+ self.e12.anti_geometric(self.e12).anti_geometric(rhs.e01)
+				+ self.e12.anti_geometric(self.e23).anti_geometric(rhs.e03) * 2.0
+				+ self.e12.anti_geometric(self.s).anti_geometric(rhs.e02) * 2.0
+				+ self.e13.anti_geometric(self.e13).anti_geometric(rhs.e01)
+				+ self.e13.anti_geometric(self.e23).anti_geometric(rhs.e02) * 2.0
+				+ self.e13.anti_geometric(self.s).anti_geometric(rhs.e03) * 2.0
+				- self.e23.anti_geometric(self.e23).anti_geometric(rhs.e01)
+				+ self.s.anti_geometric(self.s).anti_geometric(rhs.e01),
+			e02: self.e01.anti_geometric(self.e01).anti_geometric(rhs.e02)
+				+ self.e01.anti_geometric(self.e0123).anti_geometric(rhs.e03) * 2.0
+				+ self.e01.anti_geometric(self.e02).anti_geometric(rhs.e01) * 2.0
+				+ self.e01.anti_geometric(self.e13).anti_geometric(rhs.e23) * 2.0
+				+ self.e01.anti_geometric(self.e23).anti_geometric(rhs.e13) * 2.0
+				+ self.e01.anti_geometric(self.s).anti_geometric(rhs.e12) * 2.0
+				+ self.e0123.anti_geometric(self.e0123).anti_geometric(rhs.e02)
+				+ self.e0123.anti_geometric(self.e03).anti_geometric(rhs.e01) * 2.0
+				+ self.e0123.anti_geometric(self.e12).anti_geometric(rhs.e23) * 2.0
+				+ self.e0123.anti_geometric(self.e23).anti_geometric(rhs.e12) * 2.0
+				+ self.e0123.anti_geometric(self.s).anti_geometric(rhs.e13) * 2.0
+				- self.e02.anti_geometric(self.e02).anti_geometric(rhs.e02)
+				- self.e02.anti_geometric(self.e03).anti_geometric(rhs.e03) * 2.0
+				- self.e02.anti_geometric(self.e12).anti_geometric(rhs.e12) * 2.0
+				- self.e02.anti_geometric(self.e13).anti_geometric(rhs.e13) * 2.0
+				- self.e02.anti_geometric(self.e23).anti_geometric(rhs.e23) * 2.0
+				+ self.e03.anti_geometric(self.e03).anti_geometric(rhs.e02)
+				+ self.e03.anti_geometric(self.e12).anti_geometric(rhs.e13) * 2.0
+				+ self.e03.anti_geometric(self.e13).anti_geometric(rhs.e12) * 2.0
+				+ self.e03.anti_geometric(self.s).anti_geometric(rhs.e23) * 2.0
+				+ self.e12.anti_geometric(self.e12).anti_geometric(rhs.e02)
+				+ self.e12.anti_geometric(self.e13).anti_geometric(rhs.e03) * 2.0
+				+ self.e12.anti_geometric(self.s).anti_geometric(rhs.e01) * 2.0
+				- self.e13.anti_geometric(self.e13).anti_geometric(rhs.e02)
+				- self.e13.anti_geometric(self.e23).anti_geometric(rhs.e01) * 2.0
+				+ self.e23.anti_geometric(self.e23).anti_geometric(rhs.e02)
+				+ self.e23.anti_geometric(self.s).anti_geometric(rhs.e03) * 2.0
+				+ self.s.anti_geometric(self.s).anti_geometric(rhs.e02),
+			e03: self.e01.anti_geometric(self.e01).anti_geometric(rhs.e03)
+				+ self.e01.anti_geometric(self.e0123).anti_geometric(rhs.e02) * 2.0
+				+ self.e01.anti_geometric(self.e03).anti_geometric(rhs.e01) * 2.0
+				+ self.e01.anti_geometric(self.e12).anti_geometric(rhs.e23) * 2.0
+				+ self.e01.anti_geometric(self.e23).anti_geometric(rhs.e12) * 2.0
+				+ self.e01.anti_geometric(self.s).anti_geometric(rhs.e13) * 2.0
+				+ self.e0123.anti_geometric(self.e0123).anti_geometric(rhs.e03)
+				+ self.e0123.anti_geometric(self.e02).anti_geometric(rhs.e01) * 2.0
+				+ self.e0123.anti_geometric(self.e13).anti_geometric(rhs.e23) * 2.0
+				+ self.e0123.anti_geometric(self.e23).anti_geometric(rhs.e13) * 2.0
+				+ self.e0123.anti_geometric(self.s).anti_geometric(rhs.e12) * 2.0
+				+ self.e02.anti_geometric(self.e02).anti_geometric(rhs.e03)
+				+ self.e02.anti_geometric(self.e03).anti_geometric(rhs.e02) * 2.0
+				+ self.e02.anti_geometric(self.e12).anti_geometric(rhs.e13) * 2.0
+				+ self.e02.anti_geometric(self.e13).anti_geometric(rhs.e12) * 2.0
+				+ self.e02.anti_geometric(self.s).anti_geometric(rhs.e23) * 2.0
+				- self.e03.anti_geometric(self.e03).anti_geometric(rhs.e03)
+				- self.e03.anti_geometric(self.e12).anti_geometric(rhs.e12) * 2.0
+				- self.e03.anti_geometric(self.e13).anti_geometric(rhs.e13) * 2.0
+				- self.e03.anti_geometric(self.e23).anti_geometric(rhs.e23) * 2.0
+				- self.e12.anti_geometric(self.e12).anti_geometric(rhs.e03)
+				- self.e12.anti_geometric(self.e13).anti_geometric(rhs.e02) * 2.0
+				- self.e12.anti_geometric(self.e23).anti_geometric(rhs.e01) * 2.0
+				+ self.e13.anti_geometric(self.e13).anti_geometric(rhs.e03)
+				+ self.e13.anti_geometric(self.s).anti_geometric(rhs.e01) * 2.0
+				+ self.e23.anti_geometric(self.e23).anti_geometric(rhs.e03)
+				+ self.e23.anti_geometric(self.s).anti_geometric(rhs.e02) * 2.0
+				+ self.s.anti_geometric(self.s).anti_geometric(rhs.e03),
+			e12: self.e01.anti_geometric(self.e01).anti_geometric(rhs.e12)
+				+ self.e01.anti_geometric(self.e0123).anti_geometric(rhs.e13) * 2.0
+				+ self.e01.anti_geometric(self.e03).anti_geometric(rhs.e23) * 2.0
+				+ self.e01.anti_geometric(self.e12).anti_geometric(rhs.e01) * 2.0
+				+ self.e01.anti_geometric(self.e23).anti_geometric(rhs.e03) * 2.0
+				+ self.e01.anti_geometric(self.s).anti_geometric(rhs.e02) * 2.0
+				+ self.e0123.anti_geometric(self.e0123).anti_geometric(rhs.e12)
+				+ self.e0123.anti_geometric(self.e02).anti_geometric(rhs.e23) * 2.0
+				+ self.e0123.anti_geometric(self.e13).anti_geometric(rhs.e01) * 2.0
+				+ self.e0123.anti_geometric(self.e23).anti_geometric(rhs.e02) * 2.0
+				+ self.e0123.anti_geometric(self.s).anti_geometric(rhs.e03) * 2.0
+				+ self.e02.anti_geometric(self.e02).anti_geometric(rhs.e12)
+				+ self.e02.anti_geometric(self.e03).anti_geometric(rhs.e13) * 2.0
+				+ self.e02.anti_geometric(self.e12).anti_geometric(rhs.e02) * 2.0
+				+ self.e02.anti_geometric(self.e13).anti_geometric(rhs.e03) * 2.0
+				+ self.e02.anti_geometric(self.s).anti_geometric(rhs.e01) * 2.0
+				- self.e03.anti_geometric(self.e03).anti_geometric(rhs.e12)
+				- self.e03.anti_geometric(self.e12).anti_geometric(rhs.e03) * 2.0
+				- self.e03.anti_geometric(self.e13).anti_geometric(rhs.e02) * 2.0
+				- self.e03.anti_geometric(self.e23).anti_geometric(rhs.e01) * 2.0
+				- self.e12.anti_geometric(self.e12).anti_geometric(rhs.e12)
+				- self.e12.anti_geometric(self.e13).anti_geometric(rhs.e13) * 2.0
+				- self.e12.anti_geometric(self.e23).anti_geometric(rhs.e23) * 2.0
+				+ self.e13.anti_geometric(self.e13).anti_geometric(rhs.e12)
+				+ self.e13.anti_geometric(self.s).anti_geometric(rhs.e23) * 2.0
+				+ self.e23.anti_geometric(self.e23).anti_geometric(rhs.e12)
+				+ self.e23.anti_geometric(self.s).anti_geometric(rhs.e13) * 2.0
+				+ self.s.anti_geometric(self.s).anti_geometric(rhs.e12),
+			e13: self.e01.anti_geometric(self.e01).anti_geometric(rhs.e13)
+				+ self.e01.anti_geometric(self.e0123).anti_geometric(rhs.e12) * 2.0
+				+ self.e01.anti_geometric(self.e02).anti_geometric(rhs.e23) * 2.0
+				+ self.e01.anti_geometric(self.e13).anti_geometric(rhs.e01) * 2.0
+				+ self.e01.anti_geometric(self.e23).anti_geometric(rhs.e02) * 2.0
+				+ self.e01.anti_geometric(self.s).anti_geometric(rhs.e03) * 2.0
+				+ self.e0123.anti_geometric(self.e0123).anti_geometric(rhs.e13)
+				+ self.e0123.anti_geometric(self.e03).anti_geometric(rhs.e23) * 2.0
+				+ self.e0123.anti_geometric(self.e12).anti_geometric(rhs.e01) * 2.0
+				+ self.e0123.anti_geometric(self.e23).anti_geometric(rhs.e03) * 2.0
+				+ self.e0123.anti_geometric(self.s).anti_geometric(rhs.e02) * 2.0
+				- self.e02.anti_geometric(self.e02).anti_geometric(rhs.e13)
+				- self.e02.anti_geometric(self.e03).anti_geometric(rhs.e12) * 2.0
+				- self.e02.anti_geometric(self.e12).anti_geometric(rhs.e03) * 2.0
+				- self.e02.anti_geometric(self.e13).anti_geometric(rhs.e02) * 2.0
+				- self.e02.anti_geometric(self.e23).anti_geometric(rhs.e01) * 2.0
+				+ self.e03.anti_geometric(self.e03).anti_geometric(rhs.e13)
+				+ self.e03.anti_geometric(self.e12).anti_geometric(rhs.e02) * 2.0
+				+ self.e03.anti_geometric(self.e13).anti_geometric(rhs.e03) * 2.0
+				+ self.e03.anti_geometric(self.s).anti_geometric(rhs.e01) * 2.0
+				+ self.e12.anti_geometric(self.e12).anti_geometric(rhs.e13)
+				+ self.e12.anti_geometric(self.e13).anti_geometric(rhs.e12) * 2.0
+				+ self.e12.anti_geometric(self.s).anti_geometric(rhs.e23) * 2.0
+				- self.e13.anti_geometric(self.e13).anti_geometric(rhs.e13)
+				- self.e13.anti_geometric(self.e23).anti_geometric(rhs.e23) * 2.0
+				+ self.e23.anti_geometric(self.e23).anti_geometric(rhs.e13)
+				+ self.e23.anti_geometric(self.s).anti_geometric(rhs.e12) * 2.0
+				+ self.s.anti_geometric(self.s).anti_geometric(rhs.e13),
+			e23: -self.e01.anti_geometric(self.e01).anti_geometric(rhs.e23)
+				- self.e01.anti_geometric(self.e02).anti_geometric(rhs.e13) * 2.0
+				- self.e01.anti_geometric(self.e03).anti_geometric(rhs.e12) * 2.0
+				- self.e01.anti_geometric(self.e12).anti_geometric(rhs.e03) * 2.0
+				- self.e01.anti_geometric(self.e13).anti_geometric(rhs.e02) * 2.0
+				- self.e01.anti_geometric(self.e23).anti_geometric(rhs.e01) * 2.0
+				+ self.e0123.anti_geometric(self.e0123).anti_geometric(rhs.e23)
+				+ self.e0123.anti_geometric(self.e02).anti_geometric(rhs.e12) * 2.0
+				+ self.e0123.anti_geometric(self.e03).anti_geometric(rhs.e13) * 2.0
+				+ self.e0123.anti_geometric(self.e12).anti_geometric(rhs.e02) * 2.0
+				+ self.e0123.anti_geometric(self.e13).anti_geometric(rhs.e03) * 2.0
+				+ self.e0123.anti_geometric(self.s).anti_geometric(rhs.e01) * 2.0
+				+ self.e02.anti_geometric(self.e02).anti_geometric(rhs.e23)
+				+ self.e02.anti_geometric(self.e13).anti_geometric(rhs.e01) * 2.0
+				+ self.e02.anti_geometric(self.e23).anti_geometric(rhs.e02) * 2.0
+				+ self.e02.anti_geometric(self.s).anti_geometric(rhs.e03) * 2.0
+				+ self.e03.anti_geometric(self.e03).anti_geometric(rhs.e23)
+				+ self.e03.anti_geometric(self.e12).anti_geometric(rhs.e01) * 2.0
+				+ self.e03.anti_geometric(self.e23).anti_geometric(rhs.e03) * 2.0
+				+ self.e03.anti_geometric(self.s).anti_geometric(rhs.e02) * 2.0
+				+ self.e12.anti_geometric(self.e12).anti_geometric(rhs.e23)
+				+ self.e12.anti_geometric(self.e23).anti_geometric(rhs.e12) * 2.0
+				+ self.e12.anti_geometric(self.s).anti_geometric(rhs.e13) * 2.0
+				+ self.e13.anti_geometric(self.e13).anti_geometric(rhs.e23)
+				+ self.e13.anti_geometric(self.e23).anti_geometric(rhs.e13) * 2.0
+				+ self.e13.anti_geometric(self.s).anti_geometric(rhs.e12) * 2.0
+				- self.e23.anti_geometric(self.e23).anti_geometric(rhs.e23)
+				+ self.s.anti_geometric(self.s).anti_geometric(rhs.e23),
+		}
+	}
+}
@@ -0,0 +1,261 @@
+/* The C ABI of the `sta` geometric algebra, generated together with its Rust code.
+ * All values are passed by value, and the structs have the same layout as in Rust. */
+
+#ifndef STA_H
+#define STA_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct sta_Vec4 {
+	double t; /* E0 */
+	double x; /* E1 */
+	double y; /* E2 */
+	double z; /* E3 */
+} sta_Vec4;
+
+typedef struct sta_Bivector {
+	double e01; /* E01 */
+	double e02; /* E02 */
+	double e03; /* E03 */
+	double e12; /* E12 */
+	double e13; /* E13 */
+	double e23; /* E23 */
+} sta_Bivector;
+
+typedef struct sta_Rotor {
+	double s; /* S */
+	double e01; /* E01 */
+	double e02; /* E02 */
+	double e03; /* E03 */
+	double e12; /* E12 */
+	double e13; /* E13 */
+	double e23; /* E23 */
+	double e0123; /* E0123 */
+} sta_Rotor;
+
+typedef struct sta_Multivector {
+	double s; /* S */
+	double e0; /* E0 */
+	double e1; /* E1 */
+	double e2; /* E2 */
+	double e3; /* E3 */
+	double e01; /* E01 */
+	double e02; /* E02 */
+	double e03; /* E03 */
+	double e12; /* E12 */
+	double e13; /* E13 */
+	double e23; /* E23 */
+	double e012; /* E012 */
+	double e013; /* E013 */
+	double e023; /* E023 */
+	double e123; /* E123 */
+	double e0123; /* E0123 */
+} sta_Multivector;
+
+sta_Multivector sta_vec4_rcompl(sta_Vec4 value);
+sta_Multivector sta_vec4_lcompl(sta_Vec4 value);
+sta_Vec4 sta_vec4_rev(sta_Vec4 value);
+sta_Vec4 sta_vec4_arev(sta_Vec4 value);
+sta_Vec4 sta_vec4_involute(sta_Vec4 value);
+sta_Vec4 sta_vec4_conjugate(sta_Vec4 value);
+sta_Multivector sta_vec4_dual(sta_Vec4 value);
+sta_Vec4 sta_vec4_bulk(sta_Vec4 value);
+sta_Vec4 sta_vec4_weight(sta_Vec4 value);
+sta_Multivector sta_vec4_bulk_dual(sta_Vec4 value);
+sta_Multivector sta_vec4_weight_dual(sta_Vec4 value);
+sta_Rotor sta_vec4_geometric_vec4(sta_Vec4 lhs, sta_Vec4 rhs);
+sta_Rotor sta_vec4_anti_geometric_vec4(sta_Vec4 lhs, sta_Vec4 rhs);
+double sta_vec4_dot_vec4(sta_Vec4 lhs, sta_Vec4 rhs);
+sta_Bivector sta_vec4_wedge_vec4(sta_Vec4 lhs, sta_Vec4 rhs);
+double sta_vec4_left_contraction_vec4(sta_Vec4 lhs, sta_Vec4 rhs);
+double sta_vec4_right_contraction_vec4(sta_Vec4 lhs, sta_Vec4 rhs);
+double sta_vec4_scalar_product_vec4(sta_Vec4 lhs, sta_Vec4 rhs);
+sta_Bivector sta_vec4_commutator_vec4(sta_Vec4 lhs, sta_Vec4 rhs);
+double sta_vec4_anti_dot_vec4(sta_Vec4 lhs, sta_Vec4 rhs);
+sta_Multivector sta_vec4_geometric_bivector(sta_Vec4 lhs, sta_Bivector rhs);
+sta_Multivector sta_vec4_anti_geometric_bivector(sta_Vec4 lhs, sta_Bivector rhs);
+sta_Vec4 sta_vec4_dot_bivector(sta_Vec4 lhs, sta_Bivector rhs);
+sta_Multivector sta_vec4_wedge_bivector(sta_Vec4 lhs, sta_Bivector rhs);
+sta_Vec4 sta_vec4_left_contraction_bivector(sta_Vec4 lhs, sta_Bivector rhs);
+sta_Vec4 sta_vec4_commutator_bivector(sta_Vec4 lhs, sta_Bivector rhs);
+sta_Multivector sta_vec4_anti_dot_bivector(sta_Vec4 lhs, sta_Bivector rhs);
+sta_Multivector sta_vec4_geometric_rotor(sta_Vec4 lhs, sta_Rotor rhs);
+sta_Multivector sta_vec4_anti_geometric_rotor(sta_Vec4 lhs, sta_Rotor rhs);
+sta_Multivector sta_vec4_dot_rotor(sta_Vec4 lhs, sta_Rotor rhs);
+sta_Multivector sta_vec4_wedge_rotor(sta_Vec4 lhs, sta_Rotor rhs);
+sta_Vec4 sta_vec4_anti_wedge_rotor(sta_Vec4 lhs, sta_Rotor rhs);
+sta_Multivector sta_vec4_left_contraction_rotor(sta_Vec4 lhs, sta_Rotor rhs);
+sta_Vec4 sta_vec4_right_contraction_rotor(sta_Vec4 lhs, sta_Rotor rhs);
+sta_Multivector sta_vec4_commutator_rotor(sta_Vec4 lhs, sta_Rotor rhs);
+sta_Multivector sta_vec4_anti_dot_rotor(sta_Vec4 lhs, sta_Rotor rhs);
+sta_Multivector sta_vec4_geometric_multivector(sta_Vec4 lhs, sta_Multivector rhs);
+sta_Multivector sta_vec4_anti_geometric_multivector(sta_Vec4 lhs, sta_Multivector rhs);
+sta_Multivector sta_vec4_dot_multivector(sta_Vec4 lhs, sta_Multivector rhs);
+sta_Multivector sta_vec4_wedge_multivector(sta_Vec4 lhs, sta_Multivector rhs);
+sta_Multivector sta_vec4_anti_wedge_multivector(sta_Vec4 lhs, sta_Multivector rhs);
+sta_Multivector sta_vec4_left_contraction_multivector(sta_Vec4 lhs, sta_Multivector rhs);
+sta_Multivector sta_vec4_right_contraction_multivector(sta_Vec4 lhs, sta_Multivector rhs);
+double sta_vec4_scalar_product_multivector(sta_Vec4 lhs, sta_Multivector rhs);
+sta_Multivector sta_vec4_commutator_multivector(sta_Vec4 lhs, sta_Multivector rhs);
+sta_Multivector sta_vec4_anti_dot_multivector(sta_Vec4 lhs, sta_Multivector rhs);
+sta_Bivector sta_bivector_rcompl(sta_Bivector value);
+sta_Bivector sta_bivector_lcompl(sta_Bivector value);
+sta_Bivector sta_bivector_rev(sta_Bivector value);
+sta_Bivector sta_bivector_arev(sta_Bivector value);
+sta_Bivector sta_bivector_involute(sta_Bivector value);
+sta_Bivector sta_bivector_conjugate(sta_Bivector value);
+sta_Bivector sta_bivector_dual(sta_Bivector value);
+sta_Bivector sta_bivector_bulk(sta_Bivector value);
+sta_Bivector sta_bivector_weight(sta_Bivector value);
+sta_Bivector sta_bivector_bulk_dual(sta_Bivector value);
+sta_Bivector sta_bivector_weight_dual(sta_Bivector value);
+sta_Multivector sta_bivector_geometric_vec4(sta_Bivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_bivector_anti_geometric_vec4(sta_Bivector lhs, sta_Vec4 rhs);
+sta_Vec4 sta_bivector_dot_vec4(sta_Bivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_bivector_wedge_vec4(sta_Bivector lhs, sta_Vec4 rhs);
+sta_Vec4 sta_bivector_right_contraction_vec4(sta_Bivector lhs, sta_Vec4 rhs);
+sta_Vec4 sta_bivector_commutator_vec4(sta_Bivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_bivector_anti_dot_vec4(sta_Bivector lhs, sta_Vec4 rhs);
+sta_Rotor sta_bivector_geometric_bivector(sta_Bivector lhs, sta_Bivector rhs);
+sta_Rotor sta_bivector_anti_geometric_bivector(sta_Bivector lhs, sta_Bivector rhs);
+double sta_bivector_dot_bivector(sta_Bivector lhs, sta_Bivector rhs);
+double sta_bivector_wedge_bivector(sta_Bivector lhs, sta_Bivector rhs);
+double sta_bivector_anti_wedge_bivector(sta_Bivector lhs, sta_Bivector rhs);
+double sta_bivector_left_contraction_bivector(sta_Bivector lhs, sta_Bivector rhs);
+double sta_bivector_right_contraction_bivector(sta_Bivector lhs, sta_Bivector rhs);
+double sta_bivector_scalar_product_bivector(sta_Bivector lhs, sta_Bivector rhs);
+sta_Bivector sta_bivector_commutator_bivector(sta_Bivector lhs, sta_Bivector rhs);
+double sta_bivector_anti_dot_bivector(sta_Bivector lhs, sta_Bivector rhs);
+sta_Rotor sta_bivector_geometric_rotor(sta_Bivector lhs, sta_Rotor rhs);
+sta_Rotor sta_bivector_anti_geometric_rotor(sta_Bivector lhs, sta_Rotor rhs);
+sta_Rotor sta_bivector_dot_rotor(sta_Bivector lhs, sta_Rotor rhs);
+sta_Rotor sta_bivector_wedge_rotor(sta_Bivector lhs, sta_Rotor rhs);
+sta_Rotor sta_bivector_anti_wedge_rotor(sta_Bivector lhs, sta_Rotor rhs);
+sta_Rotor sta_bivector_left_contraction_rotor(sta_Bivector lhs, sta_Rotor rhs);
+sta_Rotor sta_bivector_right_contraction_rotor(sta_Bivector lhs, sta_Rotor rhs);
+double sta_bivector_scalar_product_rotor(sta_Bivector lhs, sta_Rotor rhs);
+sta_Bivector sta_bivector_commutator_rotor(sta_Bivector lhs, sta_Rotor rhs);
+sta_Rotor sta_bivector_anti_dot_rotor(sta_Bivector lhs, sta_Rotor rhs);
+sta_Multivector sta_bivector_geometric_multivector(sta_Bivector lhs, sta_Multivector rhs);
+sta_Multivector sta_bivector_anti_geometric_multivector(sta_Bivector lhs, sta_Multivector rhs);
+sta_Multivector sta_bivector_dot_multivector(sta_Bivector lhs, sta_Multivector rhs);
+sta_Multivector sta_bivector_wedge_multivector(sta_Bivector lhs, sta_Multivector rhs);
+sta_Multivector sta_bivector_anti_wedge_multivector(sta_Bivector lhs, sta_Multivector rhs);
+sta_Multivector sta_bivector_left_contraction_multivector(sta_Bivector lhs, sta_Multivector rhs);
+sta_Multivector sta_bivector_right_contraction_multivector(sta_Bivector lhs, sta_Multivector rhs);
+double sta_bivector_scalar_product_multivector(sta_Bivector lhs, sta_Multivector rhs);
+sta_Multivector sta_bivector_commutator_multivector(sta_Bivector lhs, sta_Multivector rhs);
+sta_Multivector sta_bivector_anti_dot_multivector(sta_Bivector lhs, sta_Multivector rhs);
+sta_Rotor sta_rotor_rcompl(sta_Rotor value);
+sta_Rotor sta_rotor_lcompl(sta_Rotor value);
+sta_Rotor sta_rotor_rev(sta_Rotor value);
+sta_Rotor sta_rotor_arev(sta_Rotor value);
+sta_Rotor sta_rotor_involute(sta_Rotor value);
+sta_Rotor sta_rotor_conjugate(sta_Rotor value);
+sta_Rotor sta_rotor_dual(sta_Rotor value);
+sta_Rotor sta_rotor_bulk(sta_Rotor value);
+sta_Rotor sta_rotor_weight(sta_Rotor value);
+sta_Rotor sta_rotor_bulk_dual(sta_Rotor value);
+sta_Rotor sta_rotor_weight_dual(sta_Rotor value);
+sta_Multivector sta_rotor_geometric_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Multivector sta_rotor_anti_geometric_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Multivector sta_rotor_dot_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Multivector sta_rotor_wedge_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Vec4 sta_rotor_anti_wedge_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Vec4 sta_rotor_left_contraction_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Multivector sta_rotor_right_contraction_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Multivector sta_rotor_commutator_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Multivector sta_rotor_anti_dot_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Rotor sta_rotor_geometric_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Rotor sta_rotor_anti_geometric_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Rotor sta_rotor_dot_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Rotor sta_rotor_wedge_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Rotor sta_rotor_anti_wedge_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Rotor sta_rotor_left_contraction_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Rotor sta_rotor_right_contraction_bivector(sta_Rotor lhs, sta_Bivector rhs);
+double sta_rotor_scalar_product_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Bivector sta_rotor_commutator_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Rotor sta_rotor_anti_dot_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Rotor sta_rotor_geometric_rotor(sta_Rotor lhs, sta_Rotor rhs);
+sta_Rotor sta_rotor_anti_geometric_rotor(sta_Rotor lhs, sta_Rotor rhs);
+sta_Rotor sta_rotor_dot_rotor(sta_Rotor lhs, sta_Rotor rhs);
+sta_Rotor sta_rotor_wedge_rotor(sta_Rotor lhs, sta_Rotor rhs);
+sta_Rotor sta_rotor_anti_wedge_rotor(sta_Rotor lhs, sta_Rotor rhs);
+sta_Rotor sta_rotor_left_contraction_rotor(sta_Rotor lhs, sta_Rotor rhs);
+sta_Rotor sta_rotor_right_contraction_rotor(sta_Rotor lhs, sta_Rotor rhs);
+double sta_rotor_scalar_product_rotor(sta_Rotor lhs, sta_Rotor rhs);
+sta_Bivector sta_rotor_commutator_rotor(sta_Rotor lhs, sta_Rotor rhs);
+sta_Rotor sta_rotor_anti_dot_rotor(sta_Rotor lhs, sta_Rotor rhs);
+sta_Multivector sta_rotor_geometric_multivector(sta_Rotor lhs, sta_Multivector rhs);
+sta_Multivector sta_rotor_anti_geometric_multivector(sta_Rotor lhs, sta_Multivector rhs);
+sta_Multivector sta_rotor_dot_multivector(sta_Rotor lhs, sta_Multivector rhs);
+sta_Multivector sta_rotor_wedge_multivector(sta_Rotor lhs, sta_Multivector rhs);
+sta_Multivector sta_rotor_anti_wedge_multivector(sta_Rotor lhs, sta_Multivector rhs);
+sta_Multivector sta_rotor_left_contraction_multivector(sta_Rotor lhs, sta_Multivector rhs);
+sta_Multivector sta_rotor_right_contraction_multivector(sta_Rotor lhs, sta_Multivector rhs);
+double sta_rotor_scalar_product_multivector(sta_Rotor lhs, sta_Multivector rhs);
+sta_Multivector sta_rotor_commutator_multivector(sta_Rotor lhs, sta_Multivector rhs);
+sta_Multivector sta_rotor_anti_dot_multivector(sta_Rotor lhs, sta_Multivector rhs);
+sta_Vec4 sta_rotor_sandwich_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Vec4 sta_rotor_anti_sandwich_vec4(sta_Rotor lhs, sta_Vec4 rhs);
+sta_Bivector sta_rotor_sandwich_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Bivector sta_rotor_anti_sandwich_bivector(sta_Rotor lhs, sta_Bivector rhs);
+sta_Multivector sta_multivector_rcompl(sta_Multivector value);
+sta_Multivector sta_multivector_lcompl(sta_Multivector value);
+sta_Multivector sta_multivector_rev(sta_Multivector value);
+sta_Multivector sta_multivector_arev(sta_Multivector value);
+sta_Multivector sta_multivector_involute(sta_Multivector value);
+sta_Multivector sta_multivector_conjugate(sta_Multivector value);
+sta_Multivector sta_multivector_dual(sta_Multivector value);
+sta_Multivector sta_multivector_bulk(sta_Multivector value);
+sta_Multivector sta_multivector_weight(sta_Multivector value);
+sta_Multivector sta_multivector_bulk_dual(sta_Multivector value);
+sta_Multivector sta_multivector_weight_dual(sta_Multivector value);
+sta_Multivector sta_multivector_geometric_vec4(sta_Multivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_multivector_anti_geometric_vec4(sta_Multivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_multivector_dot_vec4(sta_Multivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_multivector_wedge_vec4(sta_Multivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_multivector_anti_wedge_vec4(sta_Multivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_multivector_left_contraction_vec4(sta_Multivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_multivector_right_contraction_vec4(sta_Multivector lhs, sta_Vec4 rhs);
+double sta_multivector_scalar_product_vec4(sta_Multivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_multivector_commutator_vec4(sta_Multivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_multivector_anti_dot_vec4(sta_Multivector lhs, sta_Vec4 rhs);
+sta_Multivector sta_multivector_geometric_bivector(sta_Multivector lhs, sta_Bivector rhs);
+sta_Multivector sta_multivector_anti_geometric_bivector(sta_Multivector lhs, sta_Bivector rhs);
+sta_Multivector sta_multivector_dot_bivector(sta_Multivector lhs, sta_Bivector rhs);
+sta_Multivector sta_multivector_wedge_bivector(sta_Multivector lhs, sta_Bivector rhs);
+sta_Multivector sta_multivector_anti_wedge_bivector(sta_Multivector lhs, sta_Bivector rhs);
+sta_Multivector sta_multivector_left_contraction_bivector(sta_Multivector lhs, sta_Bivector rhs);
+sta_Multivector sta_multivector_right_contraction_bivector(sta_Multivector lhs, sta_Bivector rhs);
+double sta_multivector_scalar_product_bivector(sta_Multivector lhs, sta_Bivector rhs);
+sta_Multivector sta_multivector_commutator_bivector(sta_Multivector lhs, sta_Bivector rhs);
+sta_Multivector sta_multivector_anti_dot_bivector(sta_Multivector lhs, sta_Bivector rhs);
+sta_Multivector sta_multivector_geometric_rotor(sta_Multivector lhs, sta_Rotor rhs);
+sta_Multivector sta_multivector_anti_geometric_rotor(sta_Multivector lhs, sta_Rotor rhs);
+sta_Multivector sta_multivector_dot_rotor(sta_Multivector lhs, sta_Rotor rhs);
+sta_Multivector sta_multivector_wedge_rotor(sta_Multivector lhs, sta_Rotor rhs);
+sta_Multivector sta_multivector_anti_wedge_rotor(sta_Multivector lhs, sta_Rotor rhs);
+sta_Multivector sta_multivector_left_contraction_rotor(sta_Multivector lhs, sta_Rotor rhs);
+sta_Multivector sta_multivector_right_contraction_rotor(sta_Multivector lhs, sta_Rotor rhs);
+double sta_multivector_scalar_product_rotor(sta_Multivector lhs, sta_Rotor rhs);
+sta_Multivector sta_multivector_commutator_rotor(sta_Multivector lhs, sta_Rotor rhs);
+sta_Multivector sta_multivector_anti_dot_rotor(sta_Multivector lhs, sta_Rotor rhs);
+sta_Multivector sta_multivector_geometric_multivector(sta_Multivector lhs, sta_Multivector rhs);
+sta_Multivector sta_multivector_anti_geometric_multivector(sta_Multivector lhs, sta_Multivector rhs);
+sta_Multivector sta_multivector_dot_multivector(sta_Multivector lhs, sta_Multivector rhs);
+sta_Multivector sta_multivector_wedge_multivector(sta_Multivector lhs, sta_Multivector rhs);
+sta_Multivector sta_multivector_anti_wedge_multivector(sta_Multivector lhs, sta_Multivector rhs);
+sta_Multivector sta_multivector_left_contraction_multivector(sta_Multivector lhs, sta_Multivector rhs);
+sta_Multivector sta_multivector_right_contraction_multivector(sta_Multivector lhs, sta_Multivector rhs);
+double sta_multivector_scalar_product_multivector(sta_Multivector lhs, sta_Multivector rhs);
+sta_Multivector sta_multivector_commutator_multivector(sta_Multivector lhs, sta_Multivector rhs);
+sta_Multivector sta_multivector_anti_dot_multivector(sta_Multivector lhs, sta_Multivector rhs);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* STA_H */
@@ -0,0 +1,1890 @@
+//! # Vec4
+//!
+//! ## Operations
+//! ```text
+//! Vec4.geometric(Vec4) -> Rotor
+//! Vec4.anti_geometric(Vec4) -> Rotor
+//! Vec4.dot(Vec4) -> S
+//! Vec4.wedge(Vec4) -> Bivector
+//! Vec4.left_contraction(Vec4) -> S
+//! Vec4.right_contraction(Vec4) -> S
+//! Vec4.scalar_product(Vec4) -> S
+//! Vec4.commutator(Vec4) -> Bivector
+//! Vec4.anti_dot(Vec4) -> E0123
+//! Vec4.geometric(Bivector) -> Multivector
+//! Bivector.geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Bivector) -> Multivector
+//! Bivector.anti_geometric(Vec4) -> Multivector
+//! Vec4.dot(Bivector) -> Vec4
+//! Bivector.dot(Vec4) -> Vec4
+//! Vec4.wedge(Bivector) -> Multivector
+//! Bivector.wedge(Vec4) -> Multivector
+//! Vec4.left_contraction(Bivector) -> Vec4
+//! Bivector.right_contraction(Vec4) -> Vec4
+//! Vec4.commutator(Bivector) -> Vec4
+//! Bivector.commutator(Vec4) -> Vec4
+//! Vec4.anti_dot(Bivector) -> Multivector
+//! Bivector.anti_dot(Vec4) -> Multivector
+//! Vec4.geometric(Rotor) -> Multivector
+//! Rotor.geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Rotor) -> Multivector
+//! Rotor.anti_geometric(Vec4) -> Multivector
+//! Vec4.dot(Rotor) -> Multivector
+//! Rotor.dot(Vec4) -> Multivector
+//! Vec4.wedge(Rotor) -> Multivector
+//! Rotor.wedge(Vec4) -> Multivector
+//! Vec4.anti_wedge(Rotor) -> Vec4
+//! Rotor.anti_wedge(Vec4) -> Vec4
+//! Vec4.left_contraction(Rotor) -> Multivector
+//! Rotor.left_contraction(Vec4) -> Vec4
+//! Vec4.right_contraction(Rotor) -> Vec4
+//! Rotor.right_contraction(Vec4) -> Multivector
+//! Vec4.commutator(Rotor) -> Multivector
+//! Rotor.commutator(Vec4) -> Multivector
+//! Vec4.anti_dot(Rotor) -> Multivector
+//! Rotor.anti_dot(Vec4) -> Multivector
+//! Vec4.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Vec4) -> Multivector
+//! Vec4.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec4) -> Multivector
+//! Vec4.dot(Multivector) -> Multivector
+//! Multivector.dot(Vec4) -> Multivector
+//! Vec4.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Vec4) -> Multivector
+//! Vec4.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec4) -> Multivector
+//! Vec4.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec4) -> Multivector
+//! Vec4.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec4) -> Multivector
+//! Vec4.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Vec4) -> S
+//! Vec4.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Vec4) -> Multivector
+//! Vec4.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec4) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec4 {
+	pub t: E0,
+	pub x: E1,
+	pub y: E2,
+	pub z: E3,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec4 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec4 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Vec4 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.t.abs_diff_eq(&other.t, epsilon)
+			&& self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.z.abs_diff_eq(&other.z, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Vec4 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.t.relative_eq(&other.t, epsilon, max_relative)
+			&& self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.z.relative_eq(&other.z, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Vec4 {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.z.rcompl(),
+			e013: self.y.rcompl(),
+			e023: self.x.rcompl(),
+			e123: self.t.rcompl(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Not for Vec4 {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Vec4 {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.z.lcompl(),
+			e013: self.y.lcompl(),
+			e023: self.x.lcompl(),
+			e123: self.t.lcompl(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl Reverse for Vec4 {
+	fn rev(self) -> Self {
+		Vec4 {
+			t: self.t,
+			x: self.x,
+			y: self.y,
+			z: self.z,
+		}
+	}
+}
+
+impl AntiReverse for Vec4 {
+	fn arev(self) -> Self {
+		Vec4 {
+			t: -self.t,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+		}
+	}
+}
+
+impl Involute for Vec4 {
+	fn involute(self) -> Self {
+		Vec4 {
+			t: -self.t,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+		}
+	}
+}
+
+impl Conjugate for Vec4 {
+	fn conjugate(self) -> Self {
+		Vec4 {
+			t: -self.t,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+		}
+	}
+}
+
+impl Dual for Vec4 {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.z.dual(),
+			e013: self.y.dual(),
+			e023: self.x.dual(),
+			e123: self.t.dual(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl Bulk for Vec4 {
+	type Output = Vec4;
+	fn bulk(self) -> Self::Output {
+		Vec4 {
+			t: self.t,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+		}
+	}
+}
+
+impl Weight for Vec4 {
+	type Output = Vec4;
+	fn weight(self) -> Self::Output {
+		Vec4 {
+			t: -self.t,
+			x: self.x,
+			y: self.y,
+			z: self.z,
+		}
+	}
+}
+
+impl BulkDual for Vec4 {
+	type Output = Multivector;
+	fn bulk_dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.z.bulk_dual(),
+			e013: self.y.bulk_dual(),
+			e023: self.x.bulk_dual(),
+			e123: self.t.bulk_dual(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl WeightDual for Vec4 {
+	type Output = Multivector;
+	fn weight_dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.z.weight_dual(),
+			e013: self.y.weight_dual(),
+			e023: self.x.weight_dual(),
+			e123: self.t.weight_dual(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl Square for Vec4 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(self.t.geometric(self.t) + self.x.geometric(self.x) + self.y.geometric(self.y) + self.z.geometric(self.z)).0
+	}
+}
+
+impl Norm for Vec4 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.t.0 * self.t.0 + self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.t.0 * self.t.0 + self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Vec4 {
+			t: self.t * scale,
+			x: self.x * scale,
+			y: self.y * scale,
+			z: self.z * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Vec4 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.t.0, self.x.0, self.y.0, self.z.0]
+	}
+}
+
+impl From<[f64; 4]> for Vec4 {
+	fn from(a: [f64; 4]) -> Self {
+		Vec4 {
+			t: E0(a[0]),
+			x: E1(a[1]),
+			y: E2(a[2]),
+			z: E3(a[3]),
+		}
+	}
+}
+
+impl From<Vec4> for [f64; 4] {
+	fn from(v: Vec4) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Vec4> for Multivector {
+	fn from(v: Vec4) -> Self {
+		Multivector {
+			e0: v.t,
+			e1: v.x,
+			e2: v.y,
+			e3: v.z,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Vec4 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Vec4` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0
+			&& mv.e01.0 == 0.0
+			&& mv.e02.0 == 0.0
+			&& mv.e03.0 == 0.0
+			&& mv.e12.0 == 0.0
+			&& mv.e13.0 == 0.0
+			&& mv.e23.0 == 0.0
+			&& mv.e012.0 == 0.0
+			&& mv.e013.0 == 0.0
+			&& mv.e023.0 == 0.0
+			&& mv.e123.0 == 0.0
+			&& mv.e0123.0 == 0.0
+		{
+			Ok(Vec4 {
+				t: mv.e0,
+				x: mv.e1,
+				y: mv.e2,
+				z: mv.e3,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec4 OP Vec4:
+
+// Vec4.geometric(Vec4) -> Rotor
+impl Geometric<Vec4> for Vec4 {
+	type Output = Rotor;
+	fn geometric(self, rhs: Vec4) -> Self::Output {
+		// Rotor {
+		//     s    : S(self.t.0 * rhs.t.0) - S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0),
+		//     e01  : E01(self.t.0 * rhs.x.0) - E01(self.x.0 * rhs.t.0),
+		//     e02  : E02(self.t.0 * rhs.y.0) - E02(self.y.0 * rhs.t.0),
+		//     e03  : E03(self.t.0 * rhs.z.0) - E03(self.z.0 * rhs.t.0),
+		//     e12  : E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13  : E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e23  : E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		//     e0123: Default::default(),
+		// }
+		Rotor {
+			s: self.t.geometric(rhs.t) + self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			e01: self.t.geometric(rhs.x) + self.x.geometric(rhs.t),
+			e02: self.t.geometric(rhs.y) + self.y.geometric(rhs.t),
+			e03: self.t.geometric(rhs.z) + self.z.geometric(rhs.t),
+			e12: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+			e13: self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			e23: self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec4> for Vec4 {
+	type Output = Rotor;
+	fn mul(self, rhs: Vec4) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Vec4) -> Rotor
+impl AntiGeometric<Vec4> for Vec4 {
+	type Output = Rotor;
+	fn anti_geometric(self, rhs: Vec4) -> Self::Output {
+		// Rotor {
+		//     s    : Default::default(),
+		//     e01  : E01(self.y.0 * rhs.z.0) - E01(self.z.0 * rhs.y.0),
+		//     e02  : -E02(self.x.0 * rhs.z.0) + E02(self.z.0 * rhs.x.0),
+		//     e03  : E03(self.x.0 * rhs.y.0) - E03(self.y.0 * rhs.x.0),
+		//     e12  : -E12(self.t.0 * rhs.z.0) + E12(self.z.0 * rhs.t.0),
+		//     e13  : E13(self.t.0 * rhs.y.0) - E13(self.y.0 * rhs.t.0),
+		//     e23  : -E23(self.t.0 * rhs.x.0) + E23(self.x.0 * rhs.t.0),
+		//     e0123: E0123(self.t.0 * rhs.t.0) - E0123(self.x.0 * rhs.x.0) - E0123(self.y.0 * rhs.y.0) - E0123(self.z.0 * rhs.z.0),
+		// }
+		Rotor {
+			s: Default::default(),
+			e01: self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+			e02: self.x.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.x),
+			e03: self.x.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.x),
+			e12: self.t.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.t),
+			e13: self.t.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.t),
+			e23: self.t.anti_geometric(rhs.x) + self.x.anti_geometric(rhs.t),
+			e0123: self.t.anti_geometric(rhs.t)
+				+ self.x.anti_geometric(rhs.x)
+				+ self.y.anti_geometric(rhs.y)
+				+ self.z.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Vec4.dot(Vec4) -> S
+impl Dot<Vec4> for Vec4 {
+	type Output = S;
+	fn dot(self, rhs: Vec4) -> Self::Output {
+		// S(self.t.0 * rhs.t.0) - S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0)
+		self.t.dot(rhs.t) + self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z)
+	}
+}
+
+impl std::ops::BitOr<Vec4> for Vec4 {
+	type Output = S;
+	fn bitor(self, rhs: Vec4) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec4.wedge(Vec4) -> Bivector
+impl Wedge<Vec4> for Vec4 {
+	type Output = Bivector;
+	fn wedge(self, rhs: Vec4) -> Self::Output {
+		// Bivector {
+		//     e01: E01(self.t.0 * rhs.x.0) - E01(self.x.0 * rhs.t.0),
+		//     e02: E02(self.t.0 * rhs.y.0) - E02(self.y.0 * rhs.t.0),
+		//     e03: E03(self.t.0 * rhs.z.0) - E03(self.z.0 * rhs.t.0),
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		// }
+		Bivector {
+			e01: self.t.wedge(rhs.x) + self.x.wedge(rhs.t),
+			e02: self.t.wedge(rhs.y) + self.y.wedge(rhs.t),
+			e03: self.t.wedge(rhs.z) + self.z.wedge(rhs.t),
+			e12: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			e13: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			e23: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec4> for Vec4 {
+	type Output = Bivector;
+	fn bitxor(self, rhs: Vec4) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec4> for Vec4 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec4) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec4> for Vec4 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec4) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Vec4) -> S
+impl LeftContraction<Vec4> for Vec4 {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec4) -> Self::Output {
+		// S(self.t.0 * rhs.t.0) - S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0)
+		self.t.left_contraction(rhs.t)
+			+ self.x.left_contraction(rhs.x)
+			+ self.y.left_contraction(rhs.y)
+			+ self.z.left_contraction(rhs.z)
+	}
+}
+
+// Vec4.right_contraction(Vec4) -> S
+impl RightContraction<Vec4> for Vec4 {
+	type Output = S;
+	fn right_contraction(self, rhs: Vec4) -> Self::Output {
+		// S(self.t.0 * rhs.t.0) - S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0)
+		self.t.right_contraction(rhs.t)
+			+ self.x.right_contraction(rhs.x)
+			+ self.y.right_contraction(rhs.y)
+			+ self.z.right_contraction(rhs.z)
+	}
+}
+
+// Vec4.scalar_product(Vec4) -> S
+impl ScalarProduct<Vec4> for Vec4 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec4) -> Self::Output {
+		// S(self.t.0 * rhs.t.0) - S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0)
+		self.t.scalar_product(rhs.t)
+			+ self.x.scalar_product(rhs.x)
+			+ self.y.scalar_product(rhs.y)
+			+ self.z.scalar_product(rhs.z)
+	}
+}
+
+// Vec4.commutator(Vec4) -> Bivector
+impl Commutator<Vec4> for Vec4 {
+	type Output = Bivector;
+	fn commutator(self, rhs: Vec4) -> Self::Output {
+		// Bivector {
+		//     e01: E01(self.t.0 * rhs.x.0) - E01(self.x.0 * rhs.t.0),
+		//     e02: E02(self.t.0 * rhs.y.0) - E02(self.y.0 * rhs.t.0),
+		//     e03: E03(self.t.0 * rhs.z.0) - E03(self.z.0 * rhs.t.0),
+		//     e12: E12(self.x.0 * rhs.y.0) - E12(self.y.0 * rhs.x.0),
+		//     e13: E13(self.x.0 * rhs.z.0) - E13(self.z.0 * rhs.x.0),
+		//     e23: E23(self.y.0 * rhs.z.0) - E23(self.z.0 * rhs.y.0),
+		// }
+		Bivector {
+			e01: self.t.commutator(rhs.x) + self.x.commutator(rhs.t),
+			e02: self.t.commutator(rhs.y) + self.y.commutator(rhs.t),
+			e03: self.t.commutator(rhs.z) + self.z.commutator(rhs.t),
+			e12: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			e13: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			e23: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+		}
+	}
+}
+
+// Vec4.anti_dot(Vec4) -> E0123
+impl AntiDot<Vec4> for Vec4 {
+	type Output = E0123;
+	fn anti_dot(self, rhs: Vec4) -> Self::Output {
+		// E0123(self.t.0 * rhs.t.0) - E0123(self.x.0 * rhs.x.0) - E0123(self.y.0 * rhs.y.0) - E0123(self.z.0 * rhs.z.0)
+		self.t.anti_dot(rhs.t) + self.x.anti_dot(rhs.x) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec4 OP Bivector:
+
+// Vec4.geometric(Bivector) -> Multivector
+impl Geometric<Bivector> for Vec4 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     e1   : E1(self.t.0 * rhs.e01.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     e2   : E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.z.0 * rhs.e23.0),
+		//     e3   : E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.t.0 * rhs.e12.0) - E012(self.x.0 * rhs.e02.0) + E012(self.y.0 * rhs.e01.0),
+		//     e013 : E013(self.t.0 * rhs.e13.0) - E013(self.x.0 * rhs.e03.0) + E013(self.z.0 * rhs.e01.0),
+		//     e023 : E023(self.t.0 * rhs.e23.0) - E023(self.y.0 * rhs.e03.0) + E023(self.z.0 * rhs.e02.0),
+		//     e123 : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.x.geometric(rhs.e01) + self.y.geometric(rhs.e02) + self.z.geometric(rhs.e03),
+			e1: self.t.geometric(rhs.e01) + self.y.geometric(rhs.e12) + self.z.geometric(rhs.e13),
+			e2: self.t.geometric(rhs.e02) + self.x.geometric(rhs.e12) + self.z.geometric(rhs.e23),
+			e3: self.t.geometric(rhs.e03) + self.x.geometric(rhs.e13) + self.y.geometric(rhs.e23),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.t.geometric(rhs.e12) + self.x.geometric(rhs.e02) + self.y.geometric(rhs.e01),
+			e013: self.t.geometric(rhs.e13) + self.x.geometric(rhs.e03) + self.z.geometric(rhs.e01),
+			e023: self.t.geometric(rhs.e23) + self.y.geometric(rhs.e03) + self.z.geometric(rhs.e02),
+			e123: self.x.geometric(rhs.e23) + self.y.geometric(rhs.e13) + self.z.geometric(rhs.e12),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Bivector> for Vec4 {
+	type Output = Multivector;
+	fn mul(self, rhs: Bivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Bivector) -> Multivector
+impl AntiGeometric<Bivector> for Vec4 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : -E0(self.x.0 * rhs.e23.0) + E0(self.y.0 * rhs.e13.0) - E0(self.z.0 * rhs.e12.0),
+		//     e1   : -E1(self.t.0 * rhs.e23.0) + E1(self.y.0 * rhs.e03.0) - E1(self.z.0 * rhs.e02.0),
+		//     e2   : E2(self.t.0 * rhs.e13.0) - E2(self.x.0 * rhs.e03.0) + E2(self.z.0 * rhs.e01.0),
+		//     e3   : -E3(self.t.0 * rhs.e12.0) + E3(self.x.0 * rhs.e02.0) - E3(self.y.0 * rhs.e01.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.t.0 * rhs.e03.0) + E012(self.x.0 * rhs.e13.0) + E012(self.y.0 * rhs.e23.0),
+		//     e013 : E013(self.t.0 * rhs.e02.0) - E013(self.x.0 * rhs.e12.0) + E013(self.z.0 * rhs.e23.0),
+		//     e023 : -E023(self.t.0 * rhs.e01.0) - E023(self.y.0 * rhs.e12.0) - E023(self.z.0 * rhs.e13.0),
+		//     e123 : -E123(self.x.0 * rhs.e01.0) - E123(self.y.0 * rhs.e02.0) - E123(self.z.0 * rhs.e03.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.x.anti_geometric(rhs.e23) + self.y.anti_geometric(rhs.e13) + self.z.anti_geometric(rhs.e12),
+			e1: self.t.anti_geometric(rhs.e23) + self.y.anti_geometric(rhs.e03) + self.z.anti_geometric(rhs.e02),
+			e2: self.t.anti_geometric(rhs.e13) + self.x.anti_geometric(rhs.e03) + self.z.anti_geometric(rhs.e01),
+			e3: self.t.anti_geometric(rhs.e12) + self.x.anti_geometric(rhs.e02) + self.y.anti_geometric(rhs.e01),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.t.anti_geometric(rhs.e03) + self.x.anti_geometric(rhs.e13) + self.y.anti_geometric(rhs.e23),
+			e013: self.t.anti_geometric(rhs.e02) + self.x.anti_geometric(rhs.e12) + self.z.anti_geometric(rhs.e23),
+			e023: self.t.anti_geometric(rhs.e01) + self.y.anti_geometric(rhs.e12) + self.z.anti_geometric(rhs.e13),
+			e123: self.x.anti_geometric(rhs.e01) + self.y.anti_geometric(rhs.e02) + self.z.anti_geometric(rhs.e03),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Vec4.dot(Bivector) -> Vec4
+impl Dot<Bivector> for Vec4 {
+	type Output = Vec4;
+	fn dot(self, rhs: Bivector) -> Self::Output {
+		// Vec4 {
+		//     t: E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     x: E1(self.t.0 * rhs.e01.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     y: E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.z.0 * rhs.e23.0),
+		//     z: E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0),
+		// }
+		Vec4 {
+			t: self.x.dot(rhs.e01) + self.y.dot(rhs.e02) + self.z.dot(rhs.e03),
+			x: self.t.dot(rhs.e01) + self.y.dot(rhs.e12) + self.z.dot(rhs.e13),
+			y: self.t.dot(rhs.e02) + self.x.dot(rhs.e12) + self.z.dot(rhs.e23),
+			z: self.t.dot(rhs.e03) + self.x.dot(rhs.e13) + self.y.dot(rhs.e23),
+		}
+	}
+}
+
+impl std::ops::BitOr<Bivector> for Vec4 {
+	type Output = Vec4;
+	fn bitor(self, rhs: Bivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec4.wedge(Bivector) -> Multivector
+impl Wedge<Bivector> for Vec4 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : Default::default(),
+		//     e1   : Default::default(),
+		//     e2   : Default::default(),
+		//     e3   : Default::default(),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.t.0 * rhs.e12.0) - E012(self.x.0 * rhs.e02.0) + E012(self.y.0 * rhs.e01.0),
+		//     e013 : E013(self.t.0 * rhs.e13.0) - E013(self.x.0 * rhs.e03.0) + E013(self.z.0 * rhs.e01.0),
+		//     e023 : E023(self.t.0 * rhs.e23.0) - E023(self.y.0 * rhs.e03.0) + E023(self.z.0 * rhs.e02.0),
+		//     e123 : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.t.wedge(rhs.e12) + self.x.wedge(rhs.e02) + self.y.wedge(rhs.e01),
+			e013: self.t.wedge(rhs.e13) + self.x.wedge(rhs.e03) + self.z.wedge(rhs.e01),
+			e023: self.t.wedge(rhs.e23) + self.y.wedge(rhs.e03) + self.z.wedge(rhs.e02),
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Bivector> for Vec4 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Bivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Bivector> for Vec4 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Bivector) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Bivector> for Vec4 {
+	type Output = Zero;
+	fn bitand(self, rhs: Bivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Bivector) -> Vec4
+impl LeftContraction<Bivector> for Vec4 {
+	type Output = Vec4;
+	fn left_contraction(self, rhs: Bivector) -> Self::Output {
+		// Vec4 {
+		//     t: E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     x: E1(self.t.0 * rhs.e01.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     y: E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.z.0 * rhs.e23.0),
+		//     z: E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0),
+		// }
+		Vec4 {
+			t: self.x.left_contraction(rhs.e01) + self.y.left_contraction(rhs.e02) + self.z.left_contraction(rhs.e03),
+			x: self.t.left_contraction(rhs.e01) + self.y.left_contraction(rhs.e12) + self.z.left_contraction(rhs.e13),
+			y: self.t.left_contraction(rhs.e02) + self.x.left_contraction(rhs.e12) + self.z.left_contraction(rhs.e23),
+			z: self.t.left_contraction(rhs.e03) + self.x.left_contraction(rhs.e13) + self.y.left_contraction(rhs.e23),
+		}
+	}
+}
+
+impl RightContraction<Bivector> for Vec4 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Bivector) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Bivector> for Vec4 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Bivector) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec4.commutator(Bivector) -> Vec4
+impl Commutator<Bivector> for Vec4 {
+	type Output = Vec4;
+	fn commutator(self, rhs: Bivector) -> Self::Output {
+		// Vec4 {
+		//     t: E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     x: E1(self.t.0 * rhs.e01.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     y: E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.z.0 * rhs.e23.0),
+		//     z: E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0),
+		// }
+		Vec4 {
+			t: self.x.commutator(rhs.e01) + self.y.commutator(rhs.e02) + self.z.commutator(rhs.e03),
+			x: self.t.commutator(rhs.e01) + self.y.commutator(rhs.e12) + self.z.commutator(rhs.e13),
+			y: self.t.commutator(rhs.e02) + self.x.commutator(rhs.e12) + self.z.commutator(rhs.e23),
+			z: self.t.commutator(rhs.e03) + self.x.commutator(rhs.e13) + self.y.commutator(rhs.e23),
+		}
+	}
+}
+
+// Vec4.anti_dot(Bivector) -> Multivector
+impl AntiDot<Bivector> for Vec4 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Bivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : Default::default(),
+		//     e1   : Default::default(),
+		//     e2   : Default::default(),
+		//     e3   : Default::default(),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.t.0 * rhs.e03.0) + E012(self.x.0 * rhs.e13.0) + E012(self.y.0 * rhs.e23.0),
+		//     e013 : E013(self.t.0 * rhs.e02.0) - E013(self.x.0 * rhs.e12.0) + E013(self.z.0 * rhs.e23.0),
+		//     e023 : -E023(self.t.0 * rhs.e01.0) - E023(self.y.0 * rhs.e12.0) - E023(self.z.0 * rhs.e13.0),
+		//     e123 : -E123(self.x.0 * rhs.e01.0) - E123(self.y.0 * rhs.e02.0) - E123(self.z.0 * rhs.e03.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: Default::default(),
+			e1: Default::default(),
+			e2: Default::default(),
+			e3: Default::default(),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.t.anti_dot(rhs.e03) + self.x.anti_dot(rhs.e13) + self.y.anti_dot(rhs.e23),
+			e013: self.t.anti_dot(rhs.e02) + self.x.anti_dot(rhs.e12) + self.z.anti_dot(rhs.e23),
+			e023: self.t.anti_dot(rhs.e01) + self.y.anti_dot(rhs.e12) + self.z.anti_dot(rhs.e13),
+			e123: self.x.anti_dot(rhs.e01) + self.y.anti_dot(rhs.e02) + self.z.anti_dot(rhs.e03),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec4 OP Rotor:
+
+// Vec4.geometric(Rotor) -> Multivector
+impl Geometric<Rotor> for Vec4 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.t.0 * rhs.s.0) + E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     e1   : E1(self.t.0 * rhs.e01.0) + E1(self.x.0 * rhs.s.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     e2   : E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) + E2(self.z.0 * rhs.e23.0),
+		//     e3   : E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.t.0 * rhs.e12.0) - E012(self.x.0 * rhs.e02.0) + E012(self.y.0 * rhs.e01.0) + E012(self.z.0 * rhs.e0123.0),
+		//     e013 : E013(self.t.0 * rhs.e13.0) - E013(self.x.0 * rhs.e03.0) - E013(self.y.0 * rhs.e0123.0) + E013(self.z.0 * rhs.e01.0),
+		//     e023 : E023(self.t.0 * rhs.e23.0) + E023(self.x.0 * rhs.e0123.0) - E023(self.y.0 * rhs.e03.0) + E023(self.z.0 * rhs.e02.0),
+		//     e123 : E123(self.t.0 * rhs.e0123.0) + E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.t.geometric(rhs.s)
+				+ self.x.geometric(rhs.e01)
+				+ self.y.geometric(rhs.e02)
+				+ self.z.geometric(rhs.e03),
+			e1: self.t.geometric(rhs.e01)
+				+ self.x.geometric(rhs.s)
+				+ self.y.geometric(rhs.e12)
+				+ self.z.geometric(rhs.e13),
+			e2: self.t.geometric(rhs.e02)
+				+ self.x.geometric(rhs.e12)
+				+ self.y.geometric(rhs.s)
+				+ self.z.geometric(rhs.e23),
+			e3: self.t.geometric(rhs.e03)
+				+ self.x.geometric(rhs.e13)
+				+ self.y.geometric(rhs.e23)
+				+ self.z.geometric(rhs.s),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.t.geometric(rhs.e12)
+				+ self.x.geometric(rhs.e02)
+				+ self.y.geometric(rhs.e01)
+				+ self.z.geometric(rhs.e0123),
+			e013: self.t.geometric(rhs.e13)
+				+ self.x.geometric(rhs.e03)
+				+ self.y.geometric(rhs.e0123)
+				+ self.z.geometric(rhs.e01),
+			e023: self.t.geometric(rhs.e23)
+				+ self.x.geometric(rhs.e0123)
+				+ self.y.geometric(rhs.e03)
+				+ self.z.geometric(rhs.e02),
+			e123: self.t.geometric(rhs.e0123)
+				+ self.x.geometric(rhs.e23)
+				+ self.y.geometric(rhs.e13)
+				+ self.z.geometric(rhs.e12),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor> for Vec4 {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Rotor) -> Multivector
+impl AntiGeometric<Rotor> for Vec4 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.t.0 * rhs.e0123.0) - E0(self.x.0 * rhs.e23.0) + E0(self.y.0 * rhs.e13.0) - E0(self.z.0 * rhs.e12.0),
+		//     e1   : -E1(self.t.0 * rhs.e23.0) + E1(self.x.0 * rhs.e0123.0) + E1(self.y.0 * rhs.e03.0) - E1(self.z.0 * rhs.e02.0),
+		//     e2   : E2(self.t.0 * rhs.e13.0) - E2(self.x.0 * rhs.e03.0) + E2(self.y.0 * rhs.e0123.0) + E2(self.z.0 * rhs.e01.0),
+		//     e3   : -E3(self.t.0 * rhs.e12.0) + E3(self.x.0 * rhs.e02.0) - E3(self.y.0 * rhs.e01.0) + E3(self.z.0 * rhs.e0123.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.t.0 * rhs.e03.0) + E012(self.x.0 * rhs.e13.0) + E012(self.y.0 * rhs.e23.0) + E012(self.z.0 * rhs.s.0),
+		//     e013 : E013(self.t.0 * rhs.e02.0) - E013(self.x.0 * rhs.e12.0) - E013(self.y.0 * rhs.s.0) + E013(self.z.0 * rhs.e23.0),
+		//     e023 : -E023(self.t.0 * rhs.e01.0) + E023(self.x.0 * rhs.s.0) - E023(self.y.0 * rhs.e12.0) - E023(self.z.0 * rhs.e13.0),
+		//     e123 : E123(self.t.0 * rhs.s.0) - E123(self.x.0 * rhs.e01.0) - E123(self.y.0 * rhs.e02.0) - E123(self.z.0 * rhs.e03.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.t.anti_geometric(rhs.e0123)
+				+ self.x.anti_geometric(rhs.e23)
+				+ self.y.anti_geometric(rhs.e13)
+				+ self.z.anti_geometric(rhs.e12),
+			e1: self.t.anti_geometric(rhs.e23)
+				+ self.x.anti_geometric(rhs.e0123)
+				+ self.y.anti_geometric(rhs.e03)
+				+ self.z.anti_geometric(rhs.e02),
+			e2: self.t.anti_geometric(rhs.e13)
+				+ self.x.anti_geometric(rhs.e03)
+				+ self.y.anti_geometric(rhs.e0123)
+				+ self.z.anti_geometric(rhs.e01),
+			e3: self.t.anti_geometric(rhs.e12)
+				+ self.x.anti_geometric(rhs.e02)
+				+ self.y.anti_geometric(rhs.e01)
+				+ self.z.anti_geometric(rhs.e0123),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.t.anti_geometric(rhs.e03)
+				+ self.x.anti_geometric(rhs.e13)
+				+ self.y.anti_geometric(rhs.e23)
+				+ self.z.anti_geometric(rhs.s),
+			e013: self.t.anti_geometric(rhs.e02)
+				+ self.x.anti_geometric(rhs.e12)
+				+ self.y.anti_geometric(rhs.s)
+				+ self.z.anti_geometric(rhs.e23),
+			e023: self.t.anti_geometric(rhs.e01)
+				+ self.x.anti_geometric(rhs.s)
+				+ self.y.anti_geometric(rhs.e12)
+				+ self.z.anti_geometric(rhs.e13),
+			e123: self.t.anti_geometric(rhs.s)
+				+ self.x.anti_geometric(rhs.e01)
+				+ self.y.anti_geometric(rhs.e02)
+				+ self.z.anti_geometric(rhs.e03),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Vec4.dot(Rotor) -> Multivector
+impl Dot<Rotor> for Vec4 {
+	type Output = Multivector;
+	fn dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.t.0 * rhs.s.0) + E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     e1   : E1(self.t.0 * rhs.e01.0) + E1(self.x.0 * rhs.s.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     e2   : E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) + E2(self.z.0 * rhs.e23.0),
+		//     e3   : E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.z.0 * rhs.e0123.0),
+		//     e013 : -E013(self.y.0 * rhs.e0123.0),
+		//     e023 : E023(self.x.0 * rhs.e0123.0),
+		//     e123 : E123(self.t.0 * rhs.e0123.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.t.dot(rhs.s) + self.x.dot(rhs.e01) + self.y.dot(rhs.e02) + self.z.dot(rhs.e03),
+			e1: self.t.dot(rhs.e01) + self.x.dot(rhs.s) + self.y.dot(rhs.e12) + self.z.dot(rhs.e13),
+			e2: self.t.dot(rhs.e02) + self.x.dot(rhs.e12) + self.y.dot(rhs.s) + self.z.dot(rhs.e23),
+			e3: self.t.dot(rhs.e03) + self.x.dot(rhs.e13) + self.y.dot(rhs.e23) + self.z.dot(rhs.s),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.z.dot(rhs.e0123),
+			e013: self.y.dot(rhs.e0123),
+			e023: self.x.dot(rhs.e0123),
+			e123: self.t.dot(rhs.e0123),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor> for Vec4 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Rotor) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec4.wedge(Rotor) -> Multivector
+impl Wedge<Rotor> for Vec4 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.t.0 * rhs.s.0),
+		//     e1   : E1(self.x.0 * rhs.s.0),
+		//     e2   : E2(self.y.0 * rhs.s.0),
+		//     e3   : E3(self.z.0 * rhs.s.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.t.0 * rhs.e12.0) - E012(self.x.0 * rhs.e02.0) + E012(self.y.0 * rhs.e01.0),
+		//     e013 : E013(self.t.0 * rhs.e13.0) - E013(self.x.0 * rhs.e03.0) + E013(self.z.0 * rhs.e01.0),
+		//     e023 : E023(self.t.0 * rhs.e23.0) - E023(self.y.0 * rhs.e03.0) + E023(self.z.0 * rhs.e02.0),
+		//     e123 : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.t.wedge(rhs.s),
+			e1: self.x.wedge(rhs.s),
+			e2: self.y.wedge(rhs.s),
+			e3: self.z.wedge(rhs.s),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.t.wedge(rhs.e12) + self.x.wedge(rhs.e02) + self.y.wedge(rhs.e01),
+			e013: self.t.wedge(rhs.e13) + self.x.wedge(rhs.e03) + self.z.wedge(rhs.e01),
+			e023: self.t.wedge(rhs.e23) + self.y.wedge(rhs.e03) + self.z.wedge(rhs.e02),
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor> for Vec4 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec4.anti_wedge(Rotor) -> Vec4
+impl AntiWedge<Rotor> for Vec4 {
+	type Output = Vec4;
+	fn anti_wedge(self, rhs: Rotor) -> Self::Output {
+		// Vec4 {
+		//     t: E0(self.t.0 * rhs.e0123.0),
+		//     x: E1(self.x.0 * rhs.e0123.0),
+		//     y: E2(self.y.0 * rhs.e0123.0),
+		//     z: E3(self.z.0 * rhs.e0123.0),
+		// }
+		Vec4 {
+			t: self.t.anti_wedge(rhs.e0123),
+			x: self.x.anti_wedge(rhs.e0123),
+			y: self.y.anti_wedge(rhs.e0123),
+			z: self.z.anti_wedge(rhs.e0123),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor> for Vec4 {
+	type Output = Vec4;
+	fn bitand(self, rhs: Rotor) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Rotor) -> Multivector
+impl LeftContraction<Rotor> for Vec4 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     e1   : E1(self.t.0 * rhs.e01.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     e2   : E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.z.0 * rhs.e23.0),
+		//     e3   : E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.z.0 * rhs.e0123.0),
+		//     e013 : -E013(self.y.0 * rhs.e0123.0),
+		//     e023 : E023(self.x.0 * rhs.e0123.0),
+		//     e123 : E123(self.t.0 * rhs.e0123.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.x.left_contraction(rhs.e01) + self.y.left_contraction(rhs.e02) + self.z.left_contraction(rhs.e03),
+			e1: self.t.left_contraction(rhs.e01) + self.y.left_contraction(rhs.e12) + self.z.left_contraction(rhs.e13),
+			e2: self.t.left_contraction(rhs.e02) + self.x.left_contraction(rhs.e12) + self.z.left_contraction(rhs.e23),
+			e3: self.t.left_contraction(rhs.e03) + self.x.left_contraction(rhs.e13) + self.y.left_contraction(rhs.e23),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.z.left_contraction(rhs.e0123),
+			e013: self.y.left_contraction(rhs.e0123),
+			e023: self.x.left_contraction(rhs.e0123),
+			e123: self.t.left_contraction(rhs.e0123),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Vec4.right_contraction(Rotor) -> Vec4
+impl RightContraction<Rotor> for Vec4 {
+	type Output = Vec4;
+	fn right_contraction(self, rhs: Rotor) -> Self::Output {
+		// Vec4 {
+		//     t: E0(self.t.0 * rhs.s.0),
+		//     x: E1(self.x.0 * rhs.s.0),
+		//     y: E2(self.y.0 * rhs.s.0),
+		//     z: E3(self.z.0 * rhs.s.0),
+		// }
+		Vec4 {
+			t: self.t.right_contraction(rhs.s),
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			z: self.z.right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor> for Vec4 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec4.commutator(Rotor) -> Multivector
+impl Commutator<Rotor> for Vec4 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     e1   : E1(self.t.0 * rhs.e01.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     e2   : E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.z.0 * rhs.e23.0),
+		//     e3   : E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : E012(self.z.0 * rhs.e0123.0),
+		//     e013 : -E013(self.y.0 * rhs.e0123.0),
+		//     e023 : E023(self.x.0 * rhs.e0123.0),
+		//     e123 : E123(self.t.0 * rhs.e0123.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.x.commutator(rhs.e01) + self.y.commutator(rhs.e02) + self.z.commutator(rhs.e03),
+			e1: self.t.commutator(rhs.e01) + self.y.commutator(rhs.e12) + self.z.commutator(rhs.e13),
+			e2: self.t.commutator(rhs.e02) + self.x.commutator(rhs.e12) + self.z.commutator(rhs.e23),
+			e3: self.t.commutator(rhs.e03) + self.x.commutator(rhs.e13) + self.y.commutator(rhs.e23),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.z.commutator(rhs.e0123),
+			e013: self.y.commutator(rhs.e0123),
+			e023: self.x.commutator(rhs.e0123),
+			e123: self.t.commutator(rhs.e0123),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Vec4.anti_dot(Rotor) -> Multivector
+impl AntiDot<Rotor> for Vec4 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.t.0 * rhs.e0123.0),
+		//     e1   : E1(self.x.0 * rhs.e0123.0),
+		//     e2   : E2(self.y.0 * rhs.e0123.0),
+		//     e3   : E3(self.z.0 * rhs.e0123.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : -E012(self.t.0 * rhs.e03.0) + E012(self.x.0 * rhs.e13.0) + E012(self.y.0 * rhs.e23.0) + E012(self.z.0 * rhs.s.0),
+		//     e013 : E013(self.t.0 * rhs.e02.0) - E013(self.x.0 * rhs.e12.0) - E013(self.y.0 * rhs.s.0) + E013(self.z.0 * rhs.e23.0),
+		//     e023 : -E023(self.t.0 * rhs.e01.0) + E023(self.x.0 * rhs.s.0) - E023(self.y.0 * rhs.e12.0) - E023(self.z.0 * rhs.e13.0),
+		//     e123 : E123(self.t.0 * rhs.s.0) - E123(self.x.0 * rhs.e01.0) - E123(self.y.0 * rhs.e02.0) - E123(self.z.0 * rhs.e03.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.t.anti_dot(rhs.e0123),
+			e1: self.x.anti_dot(rhs.e0123),
+			e2: self.y.anti_dot(rhs.e0123),
+			e3: self.z.anti_dot(rhs.e0123),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: self.t.anti_dot(rhs.e03)
+				+ self.x.anti_dot(rhs.e13)
+				+ self.y.anti_dot(rhs.e23)
+				+ self.z.anti_dot(rhs.s),
+			e013: self.t.anti_dot(rhs.e02)
+				+ self.x.anti_dot(rhs.e12)
+				+ self.y.anti_dot(rhs.s)
+				+ self.z.anti_dot(rhs.e23),
+			e023: self.t.anti_dot(rhs.e01)
+				+ self.x.anti_dot(rhs.s)
+				+ self.y.anti_dot(rhs.e12)
+				+ self.z.anti_dot(rhs.e13),
+			e123: self.t.anti_dot(rhs.s)
+				+ self.x.anti_dot(rhs.e01)
+				+ self.y.anti_dot(rhs.e02)
+				+ self.z.anti_dot(rhs.e03),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec4 OP Multivector:
+
+// Vec4.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.t.0 * rhs.e0.0) - S(self.x.0 * rhs.e1.0) - S(self.y.0 * rhs.e2.0) - S(self.z.0 * rhs.e3.0),
+		//     e0   : E0(self.t.0 * rhs.s.0) + E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     e1   : E1(self.t.0 * rhs.e01.0) + E1(self.x.0 * rhs.s.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     e2   : E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) + E2(self.z.0 * rhs.e23.0),
+		//     e3   : E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     e01  : E01(self.t.0 * rhs.e1.0) - E01(self.x.0 * rhs.e0.0) - E01(self.y.0 * rhs.e012.0) - E01(self.z.0 * rhs.e013.0),
+		//     e02  : E02(self.t.0 * rhs.e2.0) + E02(self.x.0 * rhs.e012.0) - E02(self.y.0 * rhs.e0.0) - E02(self.z.0 * rhs.e023.0),
+		//     e03  : E03(self.t.0 * rhs.e3.0) + E03(self.x.0 * rhs.e013.0) + E03(self.y.0 * rhs.e023.0) - E03(self.z.0 * rhs.e0.0),
+		//     e12  : E12(self.t.0 * rhs.e012.0) + E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0) - E12(self.z.0 * rhs.e123.0),
+		//     e13  : E13(self.t.0 * rhs.e013.0) + E13(self.x.0 * rhs.e3.0) + E13(self.y.0 * rhs.e123.0) - E13(self.z.0 * rhs.e1.0),
+		//     e23  : E23(self.t.0 * rhs.e023.0) - E23(self.x.0 * rhs.e123.0) + E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+		//     e012 : E012(self.t.0 * rhs.e12.0) - E012(self.x.0 * rhs.e02.0) + E012(self.y.0 * rhs.e01.0) + E012(self.z.0 * rhs.e0123.0),
+		//     e013 : E013(self.t.0 * rhs.e13.0) - E013(self.x.0 * rhs.e03.0) - E013(self.y.0 * rhs.e0123.0) + E013(self.z.0 * rhs.e01.0),
+		//     e023 : E023(self.t.0 * rhs.e23.0) + E023(self.x.0 * rhs.e0123.0) - E023(self.y.0 * rhs.e03.0) + E023(self.z.0 * rhs.e02.0),
+		//     e123 : E123(self.t.0 * rhs.e0123.0) + E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e0123: E0123(self.t.0 * rhs.e123.0) - E0123(self.x.0 * rhs.e023.0) + E0123(self.y.0 * rhs.e013.0) - E0123(self.z.0 * rhs.e012.0),
+		// }
+		Multivector {
+			s: self.t.geometric(rhs.e0)
+				+ self.x.geometric(rhs.e1)
+				+ self.y.geometric(rhs.e2)
+				+ self.z.geometric(rhs.e3),
+			e0: self.t.geometric(rhs.s)
+				+ self.x.geometric(rhs.e01)
+				+ self.y.geometric(rhs.e02)
+				+ self.z.geometric(rhs.e03),
+			e1: self.t.geometric(rhs.e01)
+				+ self.x.geometric(rhs.s)
+				+ self.y.geometric(rhs.e12)
+				+ self.z.geometric(rhs.e13),
+			e2: self.t.geometric(rhs.e02)
+				+ self.x.geometric(rhs.e12)
+				+ self.y.geometric(rhs.s)
+				+ self.z.geometric(rhs.e23),
+			e3: self.t.geometric(rhs.e03)
+				+ self.x.geometric(rhs.e13)
+				+ self.y.geometric(rhs.e23)
+				+ self.z.geometric(rhs.s),
+			e01: self.t.geometric(rhs.e1)
+				+ self.x.geometric(rhs.e0)
+				+ self.y.geometric(rhs.e012)
+				+ self.z.geometric(rhs.e013),
+			e02: self.t.geometric(rhs.e2)
+				+ self.x.geometric(rhs.e012)
+				+ self.y.geometric(rhs.e0)
+				+ self.z.geometric(rhs.e023),
+			e03: self.t.geometric(rhs.e3)
+				+ self.x.geometric(rhs.e013)
+				+ self.y.geometric(rhs.e023)
+				+ self.z.geometric(rhs.e0),
+			e12: self.t.geometric(rhs.e012)
+				+ self.x.geometric(rhs.e2)
+				+ self.y.geometric(rhs.e1)
+				+ self.z.geometric(rhs.e123),
+			e13: self.t.geometric(rhs.e013)
+				+ self.x.geometric(rhs.e3)
+				+ self.y.geometric(rhs.e123)
+				+ self.z.geometric(rhs.e1),
+			e23: self.t.geometric(rhs.e023)
+				+ self.x.geometric(rhs.e123)
+				+ self.y.geometric(rhs.e3)
+				+ self.z.geometric(rhs.e2),
+			e012: self.t.geometric(rhs.e12)
+				+ self.x.geometric(rhs.e02)
+				+ self.y.geometric(rhs.e01)
+				+ self.z.geometric(rhs.e0123),
+			e013: self.t.geometric(rhs.e13)
+				+ self.x.geometric(rhs.e03)
+				+ self.y.geometric(rhs.e0123)
+				+ self.z.geometric(rhs.e01),
+			e023: self.t.geometric(rhs.e23)
+				+ self.x.geometric(rhs.e0123)
+				+ self.y.geometric(rhs.e03)
+				+ self.z.geometric(rhs.e02),
+			e123: self.t.geometric(rhs.e0123)
+				+ self.x.geometric(rhs.e23)
+				+ self.y.geometric(rhs.e13)
+				+ self.z.geometric(rhs.e12),
+			e0123: self.t.geometric(rhs.e123)
+				+ self.x.geometric(rhs.e023)
+				+ self.y.geometric(rhs.e013)
+				+ self.z.geometric(rhs.e012),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec4.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.t.0 * rhs.e123.0) - S(self.x.0 * rhs.e023.0) + S(self.y.0 * rhs.e013.0) - S(self.z.0 * rhs.e012.0),
+		//     e0   : E0(self.t.0 * rhs.e0123.0) - E0(self.x.0 * rhs.e23.0) + E0(self.y.0 * rhs.e13.0) - E0(self.z.0 * rhs.e12.0),
+		//     e1   : -E1(self.t.0 * rhs.e23.0) + E1(self.x.0 * rhs.e0123.0) + E1(self.y.0 * rhs.e03.0) - E1(self.z.0 * rhs.e02.0),
+		//     e2   : E2(self.t.0 * rhs.e13.0) - E2(self.x.0 * rhs.e03.0) + E2(self.y.0 * rhs.e0123.0) + E2(self.z.0 * rhs.e01.0),
+		//     e3   : -E3(self.t.0 * rhs.e12.0) + E3(self.x.0 * rhs.e02.0) - E3(self.y.0 * rhs.e01.0) + E3(self.z.0 * rhs.e0123.0),
+		//     e01  : -E01(self.t.0 * rhs.e023.0) + E01(self.x.0 * rhs.e123.0) + E01(self.y.0 * rhs.e3.0) - E01(self.z.0 * rhs.e2.0),
+		//     e02  : E02(self.t.0 * rhs.e013.0) - E02(self.x.0 * rhs.e3.0) + E02(self.y.0 * rhs.e123.0) + E02(self.z.0 * rhs.e1.0),
+		//     e03  : -E03(self.t.0 * rhs.e012.0) + E03(self.x.0 * rhs.e2.0) - E03(self.y.0 * rhs.e1.0) + E03(self.z.0 * rhs.e123.0),
+		//     e12  : -E12(self.t.0 * rhs.e3.0) + E12(self.x.0 * rhs.e013.0) + E12(self.y.0 * rhs.e023.0) + E12(self.z.0 * rhs.e0.0),
+		//     e13  : E13(self.t.0 * rhs.e2.0) - E13(self.x.0 * rhs.e012.0) - E13(self.y.0 * rhs.e0.0) + E13(self.z.0 * rhs.e023.0),
+		//     e23  : -E23(self.t.0 * rhs.e1.0) + E23(self.x.0 * rhs.e0.0) - E23(self.y.0 * rhs.e012.0) - E23(self.z.0 * rhs.e013.0),
+		//     e012 : -E012(self.t.0 * rhs.e03.0) + E012(self.x.0 * rhs.e13.0) + E012(self.y.0 * rhs.e23.0) + E012(self.z.0 * rhs.s.0),
+		//     e013 : E013(self.t.0 * rhs.e02.0) - E013(self.x.0 * rhs.e12.0) - E013(self.y.0 * rhs.s.0) + E013(self.z.0 * rhs.e23.0),
+		//     e023 : -E023(self.t.0 * rhs.e01.0) + E023(self.x.0 * rhs.s.0) - E023(self.y.0 * rhs.e12.0) - E023(self.z.0 * rhs.e13.0),
+		//     e123 : E123(self.t.0 * rhs.s.0) - E123(self.x.0 * rhs.e01.0) - E123(self.y.0 * rhs.e02.0) - E123(self.z.0 * rhs.e03.0),
+		//     e0123: E0123(self.t.0 * rhs.e0.0) - E0123(self.x.0 * rhs.e1.0) - E0123(self.y.0 * rhs.e2.0) - E0123(self.z.0 * rhs.e3.0),
+		// }
+		Multivector {
+			s: self.t.anti_geometric(rhs.e123)
+				+ self.x.anti_geometric(rhs.e023)
+				+ self.y.anti_geometric(rhs.e013)
+				+ self.z.anti_geometric(rhs.e012),
+			e0: self.t.anti_geometric(rhs.e0123)
+				+ self.x.anti_geometric(rhs.e23)
+				+ self.y.anti_geometric(rhs.e13)
+				+ self.z.anti_geometric(rhs.e12),
+			e1: self.t.anti_geometric(rhs.e23)
+				+ self.x.anti_geometric(rhs.e0123)
+				+ self.y.anti_geometric(rhs.e03)
+				+ self.z.anti_geometric(rhs.e02),
+			e2: self.t.anti_geometric(rhs.e13)
+				+ self.x.anti_geometric(rhs.e03)
+				+ self.y.anti_geometric(rhs.e0123)
+				+ self.z.anti_geometric(rhs.e01),
+			e3: self.t.anti_geometric(rhs.e12)
+				+ self.x.anti_geometric(rhs.e02)
+				+ self.y.anti_geometric(rhs.e01)
+				+ self.z.anti_geometric(rhs.e0123),
+			e01: self.t.anti_geometric(rhs.e023)
+				+ self.x.anti_geometric(rhs.e123)
+				+ self.y.anti_geometric(rhs.e3)
+				+ self.z.anti_geometric(rhs.e2),
+			e02: self.t.anti_geometric(rhs.e013)
+				+ self.x.anti_geometric(rhs.e3)
+				+ self.y.anti_geometric(rhs.e123)
+				+ self.z.anti_geometric(rhs.e1),
+			e03: self.t.anti_geometric(rhs.e012)
+				+ self.x.anti_geometric(rhs.e2)
+				+ self.y.anti_geometric(rhs.e1)
+				+ self.z.anti_geometric(rhs.e123),
+			e12: self.t.anti_geometric(rhs.e3)
+				+ self.x.anti_geometric(rhs.e013)
+				+ self.y.anti_geometric(rhs.e023)
+				+ self.z.anti_geometric(rhs.e0),
+			e13: self.t.anti_geometric(rhs.e2)
+				+ self.x.anti_geometric(rhs.e012)
+				+ self.y.anti_geometric(rhs.e0)
+				+ self.z.anti_geometric(rhs.e023),
+			e23: self.t.anti_geometric(rhs.e1)
+				+ self.x.anti_geometric(rhs.e0)
+				+ self.y.anti_geometric(rhs.e012)
+				+ self.z.anti_geometric(rhs.e013),
+			e012: self.t.anti_geometric(rhs.e03)
+				+ self.x.anti_geometric(rhs.e13)
+				+ self.y.anti_geometric(rhs.e23)
+				+ self.z.anti_geometric(rhs.s),
+			e013: self.t.anti_geometric(rhs.e02)
+				+ self.x.anti_geometric(rhs.e12)
+				+ self.y.anti_geometric(rhs.s)
+				+ self.z.anti_geometric(rhs.e23),
+			e023: self.t.anti_geometric(rhs.e01)
+				+ self.x.anti_geometric(rhs.s)
+				+ self.y.anti_geometric(rhs.e12)
+				+ self.z.anti_geometric(rhs.e13),
+			e123: self.t.anti_geometric(rhs.s)
+				+ self.x.anti_geometric(rhs.e01)
+				+ self.y.anti_geometric(rhs.e02)
+				+ self.z.anti_geometric(rhs.e03),
+			e0123: self.t.anti_geometric(rhs.e0)
+				+ self.x.anti_geometric(rhs.e1)
+				+ self.y.anti_geometric(rhs.e2)
+				+ self.z.anti_geometric(rhs.e3),
+		}
+	}
+}
+
+// Vec4.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.t.0 * rhs.e0.0) - S(self.x.0 * rhs.e1.0) - S(self.y.0 * rhs.e2.0) - S(self.z.0 * rhs.e3.0),
+		//     e0   : E0(self.t.0 * rhs.s.0) + E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     e1   : E1(self.t.0 * rhs.e01.0) + E1(self.x.0 * rhs.s.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     e2   : E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.y.0 * rhs.s.0) + E2(self.z.0 * rhs.e23.0),
+		//     e3   : E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0) + E3(self.z.0 * rhs.s.0),
+		//     e01  : -E01(self.y.0 * rhs.e012.0) - E01(self.z.0 * rhs.e013.0),
+		//     e02  : E02(self.x.0 * rhs.e012.0) - E02(self.z.0 * rhs.e023.0),
+		//     e03  : E03(self.x.0 * rhs.e013.0) + E03(self.y.0 * rhs.e023.0),
+		//     e12  : E12(self.t.0 * rhs.e012.0) - E12(self.z.0 * rhs.e123.0),
+		//     e13  : E13(self.t.0 * rhs.e013.0) + E13(self.y.0 * rhs.e123.0),
+		//     e23  : E23(self.t.0 * rhs.e023.0) - E23(self.x.0 * rhs.e123.0),
+		//     e012 : E012(self.z.0 * rhs.e0123.0),
+		//     e013 : -E013(self.y.0 * rhs.e0123.0),
+		//     e023 : E023(self.x.0 * rhs.e0123.0),
+		//     e123 : E123(self.t.0 * rhs.e0123.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.t.dot(rhs.e0) + self.x.dot(rhs.e1) + self.y.dot(rhs.e2) + self.z.dot(rhs.e3),
+			e0: self.t.dot(rhs.s) + self.x.dot(rhs.e01) + self.y.dot(rhs.e02) + self.z.dot(rhs.e03),
+			e1: self.t.dot(rhs.e01) + self.x.dot(rhs.s) + self.y.dot(rhs.e12) + self.z.dot(rhs.e13),
+			e2: self.t.dot(rhs.e02) + self.x.dot(rhs.e12) + self.y.dot(rhs.s) + self.z.dot(rhs.e23),
+			e3: self.t.dot(rhs.e03) + self.x.dot(rhs.e13) + self.y.dot(rhs.e23) + self.z.dot(rhs.s),
+			e01: self.y.dot(rhs.e012) + self.z.dot(rhs.e013),
+			e02: self.x.dot(rhs.e012) + self.z.dot(rhs.e023),
+			e03: self.x.dot(rhs.e013) + self.y.dot(rhs.e023),
+			e12: self.t.dot(rhs.e012) + self.z.dot(rhs.e123),
+			e13: self.t.dot(rhs.e013) + self.y.dot(rhs.e123),
+			e23: self.t.dot(rhs.e023) + self.x.dot(rhs.e123),
+			e012: self.z.dot(rhs.e0123),
+			e013: self.y.dot(rhs.e0123),
+			e023: self.x.dot(rhs.e0123),
+			e123: self.t.dot(rhs.e0123),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec4.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.t.0 * rhs.s.0),
+		//     e1   : E1(self.x.0 * rhs.s.0),
+		//     e2   : E2(self.y.0 * rhs.s.0),
+		//     e3   : E3(self.z.0 * rhs.s.0),
+		//     e01  : E01(self.t.0 * rhs.e1.0) - E01(self.x.0 * rhs.e0.0),
+		//     e02  : E02(self.t.0 * rhs.e2.0) - E02(self.y.0 * rhs.e0.0),
+		//     e03  : E03(self.t.0 * rhs.e3.0) - E03(self.z.0 * rhs.e0.0),
+		//     e12  : E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0),
+		//     e13  : E13(self.x.0 * rhs.e3.0) - E13(self.z.0 * rhs.e1.0),
+		//     e23  : E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+		//     e012 : E012(self.t.0 * rhs.e12.0) - E012(self.x.0 * rhs.e02.0) + E012(self.y.0 * rhs.e01.0),
+		//     e013 : E013(self.t.0 * rhs.e13.0) - E013(self.x.0 * rhs.e03.0) + E013(self.z.0 * rhs.e01.0),
+		//     e023 : E023(self.t.0 * rhs.e23.0) - E023(self.y.0 * rhs.e03.0) + E023(self.z.0 * rhs.e02.0),
+		//     e123 : E123(self.x.0 * rhs.e23.0) - E123(self.y.0 * rhs.e13.0) + E123(self.z.0 * rhs.e12.0),
+		//     e0123: E0123(self.t.0 * rhs.e123.0) - E0123(self.x.0 * rhs.e023.0) + E0123(self.y.0 * rhs.e013.0) - E0123(self.z.0 * rhs.e012.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.t.wedge(rhs.s),
+			e1: self.x.wedge(rhs.s),
+			e2: self.y.wedge(rhs.s),
+			e3: self.z.wedge(rhs.s),
+			e01: self.t.wedge(rhs.e1) + self.x.wedge(rhs.e0),
+			e02: self.t.wedge(rhs.e2) + self.y.wedge(rhs.e0),
+			e03: self.t.wedge(rhs.e3) + self.z.wedge(rhs.e0),
+			e12: self.x.wedge(rhs.e2) + self.y.wedge(rhs.e1),
+			e13: self.x.wedge(rhs.e3) + self.z.wedge(rhs.e1),
+			e23: self.y.wedge(rhs.e3) + self.z.wedge(rhs.e2),
+			e012: self.t.wedge(rhs.e12) + self.x.wedge(rhs.e02) + self.y.wedge(rhs.e01),
+			e013: self.t.wedge(rhs.e13) + self.x.wedge(rhs.e03) + self.z.wedge(rhs.e01),
+			e023: self.t.wedge(rhs.e23) + self.y.wedge(rhs.e03) + self.z.wedge(rhs.e02),
+			e123: self.x.wedge(rhs.e23) + self.y.wedge(rhs.e13) + self.z.wedge(rhs.e12),
+			e0123: self.t.wedge(rhs.e123) + self.x.wedge(rhs.e023) + self.y.wedge(rhs.e013) + self.z.wedge(rhs.e012),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec4.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.t.0 * rhs.e123.0) - S(self.x.0 * rhs.e023.0) + S(self.y.0 * rhs.e013.0) - S(self.z.0 * rhs.e012.0),
+		//     e0   : E0(self.t.0 * rhs.e0123.0),
+		//     e1   : E1(self.x.0 * rhs.e0123.0),
+		//     e2   : E2(self.y.0 * rhs.e0123.0),
+		//     e3   : E3(self.z.0 * rhs.e0123.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.t.anti_wedge(rhs.e123)
+				+ self.x.anti_wedge(rhs.e023)
+				+ self.y.anti_wedge(rhs.e013)
+				+ self.z.anti_wedge(rhs.e012),
+			e0: self.t.anti_wedge(rhs.e0123),
+			e1: self.x.anti_wedge(rhs.e0123),
+			e2: self.y.anti_wedge(rhs.e0123),
+			e3: self.z.anti_wedge(rhs.e0123),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec4.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.t.0 * rhs.e0.0) - S(self.x.0 * rhs.e1.0) - S(self.y.0 * rhs.e2.0) - S(self.z.0 * rhs.e3.0),
+		//     e0   : E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     e1   : E1(self.t.0 * rhs.e01.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     e2   : E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.z.0 * rhs.e23.0),
+		//     e3   : E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0),
+		//     e01  : -E01(self.y.0 * rhs.e012.0) - E01(self.z.0 * rhs.e013.0),
+		//     e02  : E02(self.x.0 * rhs.e012.0) - E02(self.z.0 * rhs.e023.0),
+		//     e03  : E03(self.x.0 * rhs.e013.0) + E03(self.y.0 * rhs.e023.0),
+		//     e12  : E12(self.t.0 * rhs.e012.0) - E12(self.z.0 * rhs.e123.0),
+		//     e13  : E13(self.t.0 * rhs.e013.0) + E13(self.y.0 * rhs.e123.0),
+		//     e23  : E23(self.t.0 * rhs.e023.0) - E23(self.x.0 * rhs.e123.0),
+		//     e012 : E012(self.z.0 * rhs.e0123.0),
+		//     e013 : -E013(self.y.0 * rhs.e0123.0),
+		//     e023 : E023(self.x.0 * rhs.e0123.0),
+		//     e123 : E123(self.t.0 * rhs.e0123.0),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.t.left_contraction(rhs.e0)
+				+ self.x.left_contraction(rhs.e1)
+				+ self.y.left_contraction(rhs.e2)
+				+ self.z.left_contraction(rhs.e3),
+			e0: self.x.left_contraction(rhs.e01) + self.y.left_contraction(rhs.e02) + self.z.left_contraction(rhs.e03),
+			e1: self.t.left_contraction(rhs.e01) + self.y.left_contraction(rhs.e12) + self.z.left_contraction(rhs.e13),
+			e2: self.t.left_contraction(rhs.e02) + self.x.left_contraction(rhs.e12) + self.z.left_contraction(rhs.e23),
+			e3: self.t.left_contraction(rhs.e03) + self.x.left_contraction(rhs.e13) + self.y.left_contraction(rhs.e23),
+			e01: self.y.left_contraction(rhs.e012) + self.z.left_contraction(rhs.e013),
+			e02: self.x.left_contraction(rhs.e012) + self.z.left_contraction(rhs.e023),
+			e03: self.x.left_contraction(rhs.e013) + self.y.left_contraction(rhs.e023),
+			e12: self.t.left_contraction(rhs.e012) + self.z.left_contraction(rhs.e123),
+			e13: self.t.left_contraction(rhs.e013) + self.y.left_contraction(rhs.e123),
+			e23: self.t.left_contraction(rhs.e023) + self.x.left_contraction(rhs.e123),
+			e012: self.z.left_contraction(rhs.e0123),
+			e013: self.y.left_contraction(rhs.e0123),
+			e023: self.x.left_contraction(rhs.e0123),
+			e123: self.t.left_contraction(rhs.e0123),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Vec4.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : S(self.t.0 * rhs.e0.0) - S(self.x.0 * rhs.e1.0) - S(self.y.0 * rhs.e2.0) - S(self.z.0 * rhs.e3.0),
+		//     e0   : E0(self.t.0 * rhs.s.0),
+		//     e1   : E1(self.x.0 * rhs.s.0),
+		//     e2   : E2(self.y.0 * rhs.s.0),
+		//     e3   : E3(self.z.0 * rhs.s.0),
+		//     e01  : Default::default(),
+		//     e02  : Default::default(),
+		//     e03  : Default::default(),
+		//     e12  : Default::default(),
+		//     e13  : Default::default(),
+		//     e23  : Default::default(),
+		//     e012 : Default::default(),
+		//     e013 : Default::default(),
+		//     e023 : Default::default(),
+		//     e123 : Default::default(),
+		//     e0123: Default::default(),
+		// }
+		Multivector {
+			s: self.t.right_contraction(rhs.e0)
+				+ self.x.right_contraction(rhs.e1)
+				+ self.y.right_contraction(rhs.e2)
+				+ self.z.right_contraction(rhs.e3),
+			e0: self.t.right_contraction(rhs.s),
+			e1: self.x.right_contraction(rhs.s),
+			e2: self.y.right_contraction(rhs.s),
+			e3: self.z.right_contraction(rhs.s),
+			e01: Default::default(),
+			e02: Default::default(),
+			e03: Default::default(),
+			e12: Default::default(),
+			e13: Default::default(),
+			e23: Default::default(),
+			e012: Default::default(),
+			e013: Default::default(),
+			e023: Default::default(),
+			e123: Default::default(),
+			e0123: Default::default(),
+		}
+	}
+}
+
+// Vec4.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Vec4 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.t.0 * rhs.e0.0) - S(self.x.0 * rhs.e1.0) - S(self.y.0 * rhs.e2.0) - S(self.z.0 * rhs.e3.0)
+		self.t.scalar_product(rhs.e0)
+			+ self.x.scalar_product(rhs.e1)
+			+ self.y.scalar_product(rhs.e2)
+			+ self.z.scalar_product(rhs.e3)
+	}
+}
+
+// Vec4.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.x.0 * rhs.e01.0) + E0(self.y.0 * rhs.e02.0) + E0(self.z.0 * rhs.e03.0),
+		//     e1   : E1(self.t.0 * rhs.e01.0) + E1(self.y.0 * rhs.e12.0) + E1(self.z.0 * rhs.e13.0),
+		//     e2   : E2(self.t.0 * rhs.e02.0) - E2(self.x.0 * rhs.e12.0) + E2(self.z.0 * rhs.e23.0),
+		//     e3   : E3(self.t.0 * rhs.e03.0) - E3(self.x.0 * rhs.e13.0) - E3(self.y.0 * rhs.e23.0),
+		//     e01  : E01(self.t.0 * rhs.e1.0) - E01(self.x.0 * rhs.e0.0),
+		//     e02  : E02(self.t.0 * rhs.e2.0) - E02(self.y.0 * rhs.e0.0),
+		//     e03  : E03(self.t.0 * rhs.e3.0) - E03(self.z.0 * rhs.e0.0),
+		//     e12  : E12(self.x.0 * rhs.e2.0) - E12(self.y.0 * rhs.e1.0),
+		//     e13  : E13(self.x.0 * rhs.e3.0) - E13(self.z.0 * rhs.e1.0),
+		//     e23  : E23(self.y.0 * rhs.e3.0) - E23(self.z.0 * rhs.e2.0),
+		//     e012 : E012(self.z.0 * rhs.e0123.0),
+		//     e013 : -E013(self.y.0 * rhs.e0123.0),
+		//     e023 : E023(self.x.0 * rhs.e0123.0),
+		//     e123 : E123(self.t.0 * rhs.e0123.0),
+		//     e0123: E0123(self.t.0 * rhs.e123.0) - E0123(self.x.0 * rhs.e023.0) + E0123(self.y.0 * rhs.e013.0) - E0123(self.z.0 * rhs.e012.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.x.commutator(rhs.e01) + self.y.commutator(rhs.e02) + self.z.commutator(rhs.e03),
+			e1: self.t.commutator(rhs.e01) + self.y.commutator(rhs.e12) + self.z.commutator(rhs.e13),
+			e2: self.t.commutator(rhs.e02) + self.x.commutator(rhs.e12) + self.z.commutator(rhs.e23),
+			e3: self.t.commutator(rhs.e03) + self.x.commutator(rhs.e13) + self.y.commutator(rhs.e23),
+			e01: self.t.commutator(rhs.e1) + self.x.commutator(rhs.e0),
+			e02: self.t.commutator(rhs.e2) + self.y.commutator(rhs.e0),
+			e03: self.t.commutator(rhs.e3) + self.z.commutator(rhs.e0),
+			e12: self.x.commutator(rhs.e2) + self.y.commutator(rhs.e1),
+			e13: self.x.commutator(rhs.e3) + self.z.commutator(rhs.e1),
+			e23: self.y.commutator(rhs.e3) + self.z.commutator(rhs.e2),
+			e012: self.z.commutator(rhs.e0123),
+			e013: self.y.commutator(rhs.e0123),
+			e023: self.x.commutator(rhs.e0123),
+			e123: self.t.commutator(rhs.e0123),
+			e0123: self.t.commutator(rhs.e123)
+				+ self.x.commutator(rhs.e023)
+				+ self.y.commutator(rhs.e013)
+				+ self.z.commutator(rhs.e012),
+		}
+	}
+}
+
+// Vec4.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Vec4 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s    : Default::default(),
+		//     e0   : E0(self.t.0 * rhs.e0123.0),
+		//     e1   : E1(self.x.0 * rhs.e0123.0),
+		//     e2   : E2(self.y.0 * rhs.e0123.0),
+		//     e3   : E3(self.z.0 * rhs.e0123.0),
+		//     e01  : -E01(self.t.0 * rhs.e023.0) + E01(self.x.0 * rhs.e123.0),
+		//     e02  : E02(self.t.0 * rhs.e013.0) + E02(self.y.0 * rhs.e123.0),
+		//     e03  : -E03(self.t.0 * rhs.e012.0) + E03(self.z.0 * rhs.e123.0),
+		//     e12  : E12(self.x.0 * rhs.e013.0) + E12(self.y.0 * rhs.e023.0),
+		//     e13  : -E13(self.x.0 * rhs.e012.0) + E13(self.z.0 * rhs.e023.0),
+		//     e23  : -E23(self.y.0 * rhs.e012.0) - E23(self.z.0 * rhs.e013.0),
+		//     e012 : -E012(self.t.0 * rhs.e03.0) + E012(self.x.0 * rhs.e13.0) + E012(self.y.0 * rhs.e23.0) + E012(self.z.0 * rhs.s.0),
+		//     e013 : E013(self.t.0 * rhs.e02.0) - E013(self.x.0 * rhs.e12.0) - E013(self.y.0 * rhs.s.0) + E013(self.z.0 * rhs.e23.0),
+		//     e023 : -E023(self.t.0 * rhs.e01.0) + E023(self.x.0 * rhs.s.0) - E023(self.y.0 * rhs.e12.0) - E023(self.z.0 * rhs.e13.0),
+		//     e123 : E123(self.t.0 * rhs.s.0) - E123(self.x.0 * rhs.e01.0) - E123(self.y.0 * rhs.e02.0) - E123(self.z.0 * rhs.e03.0),
+		//     e0123: E0123(self.t.0 * rhs.e0.0) - E0123(self.x.0 * rhs.e1.0) - E0123(self.y.0 * rhs.e2.0) - E0123(self.z.0 * rhs.e3.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			e0: self.t.anti_dot(rhs.e0123),
+			e1: self.x.anti_dot(rhs.e0123),
+			e2: self.y.anti_dot(rhs.e0123),
+			e3: self.z.anti_dot(rhs.e0123),
+			e01: self.t.anti_dot(rhs.e023) + self.x.anti_dot(rhs.e123),
+			e02: self.t.anti_dot(rhs.e013) + self.y.anti_dot(rhs.e123),
+			e03: self.t.anti_dot(rhs.e012) + self.z.anti_dot(rhs.e123),
+			e12: self.x.anti_dot(rhs.e013) + self.y.anti_dot(rhs.e023),
+			e13: self.x.anti_dot(rhs.e012) + self.z.anti_dot(rhs.e023),
+			e23: self.y.anti_dot(rhs.e012) + self.z.anti_dot(rhs.e013),
+			e012: self.t.anti_dot(rhs.e03)
+				+ self.x.anti_dot(rhs.e13)
+				+ self.y.anti_dot(rhs.e23)
+				+ self.z.anti_dot(rhs.s),
+			e013: self.t.anti_dot(rhs.e02)
+				+ self.x.anti_dot(rhs.e12)
+				+ self.y.anti_dot(rhs.s)
+				+ self.z.anti_dot(rhs.e23),
+			e023: self.t.anti_dot(rhs.e01)
+				+ self.x.anti_dot(rhs.s)
+				+ self.y.anti_dot(rhs.e12)
+				+ self.z.anti_dot(rhs.e13),
+			e123: self.t.anti_dot(rhs.s)
+				+ self.x.anti_dot(rhs.e01)
+				+ self.y.anti_dot(rhs.e02)
+				+ self.z.anti_dot(rhs.e03),
+			e0123: self.t.anti_dot(rhs.e0)
+				+ self.x.anti_dot(rhs.e1)
+				+ self.y.anti_dot(rhs.e2)
+				+ self.z.anti_dot(rhs.e3),
+		}
+	}
+}
@@ -0,0 +1,1176 @@
+//! # Bivec3
+//!
+//! ## Operations
+//! ```text
+//! Bivec3.geometric(Bivec3) -> Rotor3
+//! Bivec3.anti_geometric(Bivec3) -> Multivector
+//! Bivec3.dot(Bivec3) -> S
+//! Bivec3.anti_wedge(Bivec3) -> Vec3
+//! Bivec3.left_contraction(Bivec3) -> S
+//! Bivec3.right_contraction(Bivec3) -> S
+//! Bivec3.scalar_product(Bivec3) -> S
+//! Bivec3.commutator(Bivec3) -> Bivec3
+//! Bivec3.anti_dot(Bivec3) -> XYZ
+//! Bivec3.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Bivec3) -> Multivector
+//! Bivec3.anti_geometric(Vec3) -> Rotor3
+//! Vec3.anti_geometric(Bivec3) -> Rotor3
+//! Bivec3.dot(Vec3) -> Vec3
+//! Vec3.dot(Bivec3) -> Vec3
+//! Bivec3.wedge(Vec3) -> XYZ
+//! Vec3.wedge(Bivec3) -> XYZ
+//! Bivec3.anti_wedge(Vec3) -> S
+//! Vec3.anti_wedge(Bivec3) -> S
+//! Vec3.left_contraction(Bivec3) -> Vec3
+//! Bivec3.right_contraction(Vec3) -> Vec3
+//! Bivec3.commutator(Vec3) -> Vec3
+//! Vec3.commutator(Bivec3) -> Vec3
+//! Bivec3.anti_dot(Vec3) -> Bivec3
+//! Vec3.anti_dot(Bivec3) -> Bivec3
+//! Bivec3.geometric(Rotor3) -> Rotor3
+//! Rotor3.geometric(Bivec3) -> Rotor3
+//! Bivec3.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Bivec3) -> Multivector
+//! Bivec3.dot(Rotor3) -> Rotor3
+//! Rotor3.dot(Bivec3) -> Rotor3
+//! Bivec3.wedge(Rotor3) -> Bivec3
+//! Rotor3.wedge(Bivec3) -> Bivec3
+//! Bivec3.anti_wedge(Rotor3) -> Vec3
+//! Rotor3.anti_wedge(Bivec3) -> Vec3
+//! Bivec3.left_contraction(Rotor3) -> S
+//! Rotor3.left_contraction(Bivec3) -> Rotor3
+//! Bivec3.right_contraction(Rotor3) -> Rotor3
+//! Rotor3.right_contraction(Bivec3) -> S
+//! Bivec3.scalar_product(Rotor3) -> S
+//! Rotor3.scalar_product(Bivec3) -> S
+//! Bivec3.commutator(Rotor3) -> Bivec3
+//! Rotor3.commutator(Bivec3) -> Bivec3
+//! Bivec3.anti_dot(Rotor3) -> Multivector
+//! Rotor3.anti_dot(Bivec3) -> Multivector
+//! Bivec3.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Bivec3) -> Multivector
+//! Bivec3.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Bivec3) -> Multivector
+//! Bivec3.dot(Multivector) -> Multivector
+//! Multivector.dot(Bivec3) -> Multivector
+//! Bivec3.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Bivec3) -> Multivector
+//! Bivec3.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Bivec3) -> Multivector
+//! Bivec3.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Bivec3) -> Multivector
+//! Bivec3.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Bivec3) -> Multivector
+//! Bivec3.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Bivec3) -> S
+//! Bivec3.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Bivec3) -> Multivector
+//! Bivec3.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Bivec3) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bivec3 {
+	pub yz: YZ,
+	pub zx: ZX,
+	pub xy: XY,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Bivec3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Bivec3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Bivec3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.yz.abs_diff_eq(&other.yz, epsilon)
+			&& self.zx.abs_diff_eq(&other.zx, epsilon)
+			&& self.xy.abs_diff_eq(&other.xy, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Bivec3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.yz.relative_eq(&other.yz, epsilon, max_relative)
+			&& self.zx.relative_eq(&other.zx, epsilon, max_relative)
+			&& self.xy.relative_eq(&other.xy, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Bivec3 {
+	type Output = Vec3;
+	fn rcompl(self) -> Self::Output {
+		Vec3 {
+			x: self.yz.rcompl(),
+			y: self.zx.rcompl(),
+			z: self.xy.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Bivec3 {
+	type Output = Vec3;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Bivec3 {
+	type Output = Vec3;
+	fn lcompl(self) -> Self::Output {
+		Vec3 {
+			x: self.yz.lcompl(),
+			y: self.zx.lcompl(),
+			z: self.xy.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Bivec3 {
+	fn rev(self) -> Self {
+		Bivec3 {
+			yz: -self.yz,
+			zx: -self.zx,
+			xy: -self.xy,
+		}
+	}
+}
+
+impl AntiReverse for Bivec3 {
+	fn arev(self) -> Self {
+		Bivec3 {
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+		}
+	}
+}
+
+impl Involute for Bivec3 {
+	fn involute(self) -> Self {
+		Bivec3 {
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+		}
+	}
+}
+
+impl Conjugate for Bivec3 {
+	fn conjugate(self) -> Self {
+		Bivec3 {
+			yz: -self.yz,
+			zx: -self.zx,
+			xy: -self.xy,
+		}
+	}
+}
+
+impl Dual for Bivec3 {
+	type Output = Vec3;
+	fn dual(self) -> Self::Output {
+		Vec3 {
+			x: self.yz.dual(),
+			y: self.zx.dual(),
+			z: self.xy.dual(),
+		}
+	}
+}
+
+impl Bulk for Bivec3 {
+	type Output = Bivec3;
+	fn bulk(self) -> Self::Output {
+		Bivec3 {
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+		}
+	}
+}
+
+impl Weight for Bivec3 {
+	type Output = Bivec3;
+	fn weight(self) -> Self::Output {
+		Bivec3 {
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+		}
+	}
+}
+
+impl BulkDual for Bivec3 {
+	type Output = Vec3;
+	fn bulk_dual(self) -> Self::Output {
+		Vec3 {
+			x: self.yz.bulk_dual(),
+			y: self.zx.bulk_dual(),
+			z: self.xy.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Bivec3 {
+	type Output = Vec3;
+	fn weight_dual(self) -> Self::Output {
+		Vec3 {
+			x: self.yz.weight_dual(),
+			y: self.zx.weight_dual(),
+			z: self.xy.weight_dual(),
+		}
+	}
+}
+
+impl Square for Bivec3 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(self.xy.geometric(self.xy) + self.yz.geometric(self.yz) + self.zx.geometric(self.zx)).0
+	}
+}
+
+impl Norm for Bivec3 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.yz.0 * self.yz.0 + self.zx.0 * self.zx.0 + self.xy.0 * self.xy.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.yz.0 * self.yz.0 + self.zx.0 * self.zx.0 + self.xy.0 * self.xy.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Bivec3 {
+			yz: self.yz * scale,
+			zx: self.zx * scale,
+			xy: self.xy * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Bivec3 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 3] {
+		[self.yz.0, self.zx.0, self.xy.0]
+	}
+}
+
+impl From<[f64; 3]> for Bivec3 {
+	fn from(a: [f64; 3]) -> Self {
+		Bivec3 {
+			yz: YZ(a[0]),
+			zx: ZX(a[1]),
+			xy: XY(a[2]),
+		}
+	}
+}
+
+impl From<Bivec3> for [f64; 3] {
+	fn from(v: Bivec3) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Bivec3> for Multivector {
+	fn from(v: Bivec3) -> Self {
+		Multivector {
+			yz: v.yz,
+			zx: v.zx,
+			xy: v.xy,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Bivec3 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Bivec3` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0 && mv.x.0 == 0.0 && mv.y.0 == 0.0 && mv.z.0 == 0.0 && mv.xyz.0 == 0.0 {
+			Ok(Bivec3 {
+				yz: mv.yz,
+				zx: mv.zx,
+				xy: mv.xy,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Bivec3 OP Vec3:
+
+// Bivec3.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Bivec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.geometric(rhs.y) + self.zx.geometric(rhs.z),
+			y: self.xy.geometric(rhs.x) + self.yz.geometric(rhs.z),
+			z: self.yz.geometric(rhs.y) + self.zx.geometric(rhs.x),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.xy.geometric(rhs.z) + self.yz.geometric(rhs.x) + self.zx.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Bivec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Bivec3.anti_geometric(Vec3) -> Rotor3
+impl AntiGeometric<Vec3> for Bivec3 {
+	type Output = Rotor3;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Rotor3 {
+		//     s : S(self.xy.0 * rhs.z.0) + S(self.yz.0 * rhs.x.0) + S(self.zx.0 * rhs.y.0),
+		//     yz: YZ(self.xy.0 * rhs.y.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx: -ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0),
+		//     xy: -XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		// }
+		Rotor3 {
+			s: self.xy.anti_geometric(rhs.z) + self.yz.anti_geometric(rhs.x) + self.zx.anti_geometric(rhs.y),
+			yz: self.xy.anti_geometric(rhs.y) + self.zx.anti_geometric(rhs.z),
+			zx: self.xy.anti_geometric(rhs.x) + self.yz.anti_geometric(rhs.z),
+			xy: self.yz.anti_geometric(rhs.y) + self.zx.anti_geometric(rhs.x),
+		}
+	}
+}
+
+// Bivec3.dot(Vec3) -> Vec3
+impl Dot<Vec3> for Bivec3 {
+	type Output = Vec3;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y: -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z: -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.xy.dot(rhs.y) + self.zx.dot(rhs.z),
+			y: self.xy.dot(rhs.x) + self.yz.dot(rhs.z),
+			z: self.yz.dot(rhs.y) + self.zx.dot(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Bivec3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Bivec3.wedge(Vec3) -> XYZ
+impl Wedge<Vec3> for Bivec3 {
+	type Output = XYZ;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0)
+		self.xy.wedge(rhs.z) + self.yz.wedge(rhs.x) + self.zx.wedge(rhs.y)
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Bivec3 {
+	type Output = XYZ;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Bivec3.anti_wedge(Vec3) -> S
+impl AntiWedge<Vec3> for Bivec3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// S(self.xy.0 * rhs.z.0) + S(self.yz.0 * rhs.x.0) + S(self.zx.0 * rhs.y.0)
+		self.xy.anti_wedge(rhs.z) + self.yz.anti_wedge(rhs.x) + self.zx.anti_wedge(rhs.y)
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Bivec3 {
+	type Output = S;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl LeftContraction<Vec3> for Bivec3 {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Bivec3.right_contraction(Vec3) -> Vec3
+impl RightContraction<Vec3> for Bivec3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y: -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z: -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.xy.right_contraction(rhs.y) + self.zx.right_contraction(rhs.z),
+			y: self.xy.right_contraction(rhs.x) + self.yz.right_contraction(rhs.z),
+			z: self.yz.right_contraction(rhs.y) + self.zx.right_contraction(rhs.x),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Bivec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Bivec3.commutator(Vec3) -> Vec3
+impl Commutator<Vec3> for Bivec3 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y: -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z: -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.xy.commutator(rhs.y) + self.zx.commutator(rhs.z),
+			y: self.xy.commutator(rhs.x) + self.yz.commutator(rhs.z),
+			z: self.yz.commutator(rhs.y) + self.zx.commutator(rhs.x),
+		}
+	}
+}
+
+// Bivec3.anti_dot(Vec3) -> Bivec3
+impl AntiDot<Vec3> for Bivec3 {
+	type Output = Bivec3;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Bivec3 {
+		//     yz: YZ(self.xy.0 * rhs.y.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx: -ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0),
+		//     xy: -XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		// }
+		Bivec3 {
+			yz: self.xy.anti_dot(rhs.y) + self.zx.anti_dot(rhs.z),
+			zx: self.xy.anti_dot(rhs.x) + self.yz.anti_dot(rhs.z),
+			xy: self.yz.anti_dot(rhs.y) + self.zx.anti_dot(rhs.x),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Bivec3 OP Bivec3:
+
+// Bivec3.geometric(Bivec3) -> Rotor3
+impl Geometric<Bivec3> for Bivec3 {
+	type Output = Rotor3;
+	fn geometric(self, rhs: Bivec3) -> Self::Output {
+		// Rotor3 {
+		//     s : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx: -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy: -XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		// }
+		Rotor3 {
+			s: self.xy.geometric(rhs.xy) + self.yz.geometric(rhs.yz) + self.zx.geometric(rhs.zx),
+			yz: self.xy.geometric(rhs.zx) + self.zx.geometric(rhs.xy),
+			zx: self.xy.geometric(rhs.yz) + self.yz.geometric(rhs.xy),
+			xy: self.yz.geometric(rhs.zx) + self.zx.geometric(rhs.yz),
+		}
+	}
+}
+
+impl std::ops::Mul<Bivec3> for Bivec3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Bivec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Bivec3.anti_geometric(Bivec3) -> Multivector
+impl AntiGeometric<Bivec3> for Bivec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z  : Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.anti_geometric(rhs.zx) + self.zx.anti_geometric(rhs.xy),
+			y: self.xy.anti_geometric(rhs.yz) + self.yz.anti_geometric(rhs.xy),
+			z: self.yz.anti_geometric(rhs.zx) + self.zx.anti_geometric(rhs.yz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.xy.anti_geometric(rhs.xy) + self.yz.anti_geometric(rhs.yz) + self.zx.anti_geometric(rhs.zx),
+		}
+	}
+}
+
+// Bivec3.dot(Bivec3) -> S
+impl Dot<Bivec3> for Bivec3 {
+	type Output = S;
+	fn dot(self, rhs: Bivec3) -> Self::Output {
+		// -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.xy.dot(rhs.xy) + self.yz.dot(rhs.yz) + self.zx.dot(rhs.zx)
+	}
+}
+
+impl std::ops::BitOr<Bivec3> for Bivec3 {
+	type Output = S;
+	fn bitor(self, rhs: Bivec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Wedge<Bivec3> for Bivec3 {
+	type Output = Zero;
+	fn wedge(self, _rhs: Bivec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Bivec3> for Bivec3 {
+	type Output = Zero;
+	fn bitxor(self, rhs: Bivec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Bivec3.anti_wedge(Bivec3) -> Vec3
+impl AntiWedge<Bivec3> for Bivec3 {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Bivec3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y: Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z: Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		// }
+		Vec3 {
+			x: self.xy.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.xy),
+			y: self.xy.anti_wedge(rhs.yz) + self.yz.anti_wedge(rhs.xy),
+			z: self.yz.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.yz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Bivec3> for Bivec3 {
+	type Output = Vec3;
+	fn bitand(self, rhs: Bivec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Bivec3.left_contraction(Bivec3) -> S
+impl LeftContraction<Bivec3> for Bivec3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Bivec3) -> Self::Output {
+		// -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.xy.left_contraction(rhs.xy) + self.yz.left_contraction(rhs.yz) + self.zx.left_contraction(rhs.zx)
+	}
+}
+
+// Bivec3.right_contraction(Bivec3) -> S
+impl RightContraction<Bivec3> for Bivec3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Bivec3) -> Self::Output {
+		// -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.xy.right_contraction(rhs.xy) + self.yz.right_contraction(rhs.yz) + self.zx.right_contraction(rhs.zx)
+	}
+}
+
+// Bivec3.scalar_product(Bivec3) -> S
+impl ScalarProduct<Bivec3> for Bivec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Bivec3) -> Self::Output {
+		// -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.xy.scalar_product(rhs.xy) + self.yz.scalar_product(rhs.yz) + self.zx.scalar_product(rhs.zx)
+	}
+}
+
+// Bivec3.commutator(Bivec3) -> Bivec3
+impl Commutator<Bivec3> for Bivec3 {
+	type Output = Bivec3;
+	fn commutator(self, rhs: Bivec3) -> Self::Output {
+		// Bivec3 {
+		//     yz: YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx: -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy: -XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		// }
+		Bivec3 {
+			yz: self.xy.commutator(rhs.zx) + self.zx.commutator(rhs.xy),
+			zx: self.xy.commutator(rhs.yz) + self.yz.commutator(rhs.xy),
+			xy: self.yz.commutator(rhs.zx) + self.zx.commutator(rhs.yz),
+		}
+	}
+}
+
+// Bivec3.anti_dot(Bivec3) -> XYZ
+impl AntiDot<Bivec3> for Bivec3 {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: Bivec3) -> Self::Output {
+		// XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0)
+		self.xy.anti_dot(rhs.xy) + self.yz.anti_dot(rhs.yz) + self.zx.anti_dot(rhs.zx)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Bivec3 OP Rotor3:
+
+// Bivec3.geometric(Rotor3) -> Rotor3
+impl Geometric<Rotor3> for Bivec3 {
+	type Output = Rotor3;
+	fn geometric(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     s : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.xy.0 * rhs.zx.0) + YZ(self.yz.0 * rhs.s.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx: -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		// }
+		Rotor3 {
+			s: self.xy.geometric(rhs.xy) + self.yz.geometric(rhs.yz) + self.zx.geometric(rhs.zx),
+			yz: self.xy.geometric(rhs.zx) + self.yz.geometric(rhs.s) + self.zx.geometric(rhs.xy),
+			zx: self.xy.geometric(rhs.yz) + self.yz.geometric(rhs.xy) + self.zx.geometric(rhs.s),
+			xy: self.xy.geometric(rhs.s) + self.yz.geometric(rhs.zx) + self.zx.geometric(rhs.yz),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Bivec3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Bivec3.anti_geometric(Rotor3) -> Multivector
+impl AntiGeometric<Rotor3> for Bivec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.xy.0 * rhs.zx.0) + X(self.yz.0 * rhs.s.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0) + Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.xy.0 * rhs.s.0) + Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.anti_geometric(rhs.zx) + self.yz.anti_geometric(rhs.s) + self.zx.anti_geometric(rhs.xy),
+			y: self.xy.anti_geometric(rhs.yz) + self.yz.anti_geometric(rhs.xy) + self.zx.anti_geometric(rhs.s),
+			z: self.xy.anti_geometric(rhs.s) + self.yz.anti_geometric(rhs.zx) + self.zx.anti_geometric(rhs.yz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.xy.anti_geometric(rhs.xy) + self.yz.anti_geometric(rhs.yz) + self.zx.anti_geometric(rhs.zx),
+		}
+	}
+}
+
+// Bivec3.dot(Rotor3) -> Rotor3
+impl Dot<Rotor3> for Bivec3 {
+	type Output = Rotor3;
+	fn dot(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     s : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.yz.0 * rhs.s.0),
+		//     zx: ZX(self.zx.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Rotor3 {
+			s: self.xy.dot(rhs.xy) + self.yz.dot(rhs.yz) + self.zx.dot(rhs.zx),
+			yz: self.yz.dot(rhs.s),
+			zx: self.zx.dot(rhs.s),
+			xy: self.xy.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor3> for Bivec3 {
+	type Output = Rotor3;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Bivec3.wedge(Rotor3) -> Bivec3
+impl Wedge<Rotor3> for Bivec3 {
+	type Output = Bivec3;
+	fn wedge(self, rhs: Rotor3) -> Self::Output {
+		// Bivec3 {
+		//     yz: YZ(self.yz.0 * rhs.s.0),
+		//     zx: ZX(self.zx.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Bivec3 {
+			yz: self.yz.wedge(rhs.s),
+			zx: self.zx.wedge(rhs.s),
+			xy: self.xy.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor3> for Bivec3 {
+	type Output = Bivec3;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Bivec3.anti_wedge(Rotor3) -> Vec3
+impl AntiWedge<Rotor3> for Bivec3 {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Rotor3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y: Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z: Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		// }
+		Vec3 {
+			x: self.xy.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.xy),
+			y: self.xy.anti_wedge(rhs.yz) + self.yz.anti_wedge(rhs.xy),
+			z: self.yz.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.yz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor3> for Bivec3 {
+	type Output = Vec3;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Bivec3.left_contraction(Rotor3) -> S
+impl LeftContraction<Rotor3> for Bivec3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.xy.left_contraction(rhs.xy) + self.yz.left_contraction(rhs.yz) + self.zx.left_contraction(rhs.zx)
+	}
+}
+
+// Bivec3.right_contraction(Rotor3) -> Rotor3
+impl RightContraction<Rotor3> for Bivec3 {
+	type Output = Rotor3;
+	fn right_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     s : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.yz.0 * rhs.s.0),
+		//     zx: ZX(self.zx.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Rotor3 {
+			s: self.xy.right_contraction(rhs.xy)
+				+ self.yz.right_contraction(rhs.yz)
+				+ self.zx.right_contraction(rhs.zx),
+			yz: self.yz.right_contraction(rhs.s),
+			zx: self.zx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Bivec3.scalar_product(Rotor3) -> S
+impl ScalarProduct<Rotor3> for Bivec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor3) -> Self::Output {
+		// -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.xy.scalar_product(rhs.xy) + self.yz.scalar_product(rhs.yz) + self.zx.scalar_product(rhs.zx)
+	}
+}
+
+// Bivec3.commutator(Rotor3) -> Bivec3
+impl Commutator<Rotor3> for Bivec3 {
+	type Output = Bivec3;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Bivec3 {
+		//     yz: YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx: -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy: -XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		// }
+		Bivec3 {
+			yz: self.xy.commutator(rhs.zx) + self.zx.commutator(rhs.xy),
+			zx: self.xy.commutator(rhs.yz) + self.yz.commutator(rhs.xy),
+			xy: self.yz.commutator(rhs.zx) + self.zx.commutator(rhs.yz),
+		}
+	}
+}
+
+// Bivec3.anti_dot(Rotor3) -> Multivector
+impl AntiDot<Rotor3> for Bivec3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.yz.0 * rhs.s.0),
+		//     y  : Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.xy.0 * rhs.s.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.yz.anti_dot(rhs.s),
+			y: self.zx.anti_dot(rhs.s),
+			z: self.xy.anti_dot(rhs.s),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.xy.anti_dot(rhs.xy) + self.yz.anti_dot(rhs.yz) + self.zx.anti_dot(rhs.zx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Bivec3 OP Multivector:
+
+// Bivec3.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.yz.0 * rhs.xyz.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z  : -Z(self.xy.0 * rhs.xyz.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.xy.0 * rhs.zx.0) + YZ(self.yz.0 * rhs.s.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx : -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		//     xyz: XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.xy.geometric(rhs.xy) + self.yz.geometric(rhs.yz) + self.zx.geometric(rhs.zx),
+			x: self.xy.geometric(rhs.y) + self.yz.geometric(rhs.xyz) + self.zx.geometric(rhs.z),
+			y: self.xy.geometric(rhs.x) + self.yz.geometric(rhs.z) + self.zx.geometric(rhs.xyz),
+			z: self.xy.geometric(rhs.xyz) + self.yz.geometric(rhs.y) + self.zx.geometric(rhs.x),
+			yz: self.xy.geometric(rhs.zx) + self.yz.geometric(rhs.s) + self.zx.geometric(rhs.xy),
+			zx: self.xy.geometric(rhs.yz) + self.yz.geometric(rhs.xy) + self.zx.geometric(rhs.s),
+			xy: self.xy.geometric(rhs.s) + self.yz.geometric(rhs.zx) + self.zx.geometric(rhs.yz),
+			xyz: self.xy.geometric(rhs.z) + self.yz.geometric(rhs.x) + self.zx.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Bivec3.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.xy.0 * rhs.z.0) + S(self.yz.0 * rhs.x.0) + S(self.zx.0 * rhs.y.0),
+		//     x  : -X(self.xy.0 * rhs.zx.0) + X(self.yz.0 * rhs.s.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0) + Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.xy.0 * rhs.s.0) + Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : YZ(self.xy.0 * rhs.y.0) + YZ(self.yz.0 * rhs.xyz.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx : -ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0) + ZX(self.zx.0 * rhs.xyz.0),
+		//     xy : XY(self.xy.0 * rhs.xyz.0) - XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		//     xyz: XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: self.xy.anti_geometric(rhs.z) + self.yz.anti_geometric(rhs.x) + self.zx.anti_geometric(rhs.y),
+			x: self.xy.anti_geometric(rhs.zx) + self.yz.anti_geometric(rhs.s) + self.zx.anti_geometric(rhs.xy),
+			y: self.xy.anti_geometric(rhs.yz) + self.yz.anti_geometric(rhs.xy) + self.zx.anti_geometric(rhs.s),
+			z: self.xy.anti_geometric(rhs.s) + self.yz.anti_geometric(rhs.zx) + self.zx.anti_geometric(rhs.yz),
+			yz: self.xy.anti_geometric(rhs.y) + self.yz.anti_geometric(rhs.xyz) + self.zx.anti_geometric(rhs.z),
+			zx: self.xy.anti_geometric(rhs.x) + self.yz.anti_geometric(rhs.z) + self.zx.anti_geometric(rhs.xyz),
+			xy: self.xy.anti_geometric(rhs.xyz) + self.yz.anti_geometric(rhs.y) + self.zx.anti_geometric(rhs.x),
+			xyz: self.xy.anti_geometric(rhs.xy) + self.yz.anti_geometric(rhs.yz) + self.zx.anti_geometric(rhs.zx),
+		}
+	}
+}
+
+// Bivec3.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.yz.0 * rhs.xyz.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z  : -Z(self.xy.0 * rhs.xyz.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.dot(rhs.xy) + self.yz.dot(rhs.yz) + self.zx.dot(rhs.zx),
+			x: self.xy.dot(rhs.y) + self.yz.dot(rhs.xyz) + self.zx.dot(rhs.z),
+			y: self.xy.dot(rhs.x) + self.yz.dot(rhs.z) + self.zx.dot(rhs.xyz),
+			z: self.xy.dot(rhs.xyz) + self.yz.dot(rhs.y) + self.zx.dot(rhs.x),
+			yz: self.yz.dot(rhs.s),
+			zx: self.zx.dot(rhs.s),
+			xy: self.xy.dot(rhs.s),
+			xyz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Bivec3.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : Default::default(),
+		//     y  : Default::default(),
+		//     z  : Default::default(),
+		//     yz : YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyz: XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			yz: self.yz.wedge(rhs.s),
+			zx: self.zx.wedge(rhs.s),
+			xy: self.xy.wedge(rhs.s),
+			xyz: self.xy.wedge(rhs.z) + self.yz.wedge(rhs.x) + self.zx.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Bivec3.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.xy.0 * rhs.z.0) + S(self.yz.0 * rhs.x.0) + S(self.zx.0 * rhs.y.0),
+		//     x  : -X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z  : Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : YZ(self.yz.0 * rhs.xyz.0),
+		//     zx : ZX(self.zx.0 * rhs.xyz.0),
+		//     xy : XY(self.xy.0 * rhs.xyz.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.anti_wedge(rhs.z) + self.yz.anti_wedge(rhs.x) + self.zx.anti_wedge(rhs.y),
+			x: self.xy.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.xy),
+			y: self.xy.anti_wedge(rhs.yz) + self.yz.anti_wedge(rhs.xy),
+			z: self.yz.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.yz),
+			yz: self.yz.anti_wedge(rhs.xyz),
+			zx: self.zx.anti_wedge(rhs.xyz),
+			xy: self.xy.anti_wedge(rhs.xyz),
+			xyz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Bivec3.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : -X(self.yz.0 * rhs.xyz.0),
+		//     y  : -Y(self.zx.0 * rhs.xyz.0),
+		//     z  : -Z(self.xy.0 * rhs.xyz.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.left_contraction(rhs.xy) + self.yz.left_contraction(rhs.yz) + self.zx.left_contraction(rhs.zx),
+			x: self.yz.left_contraction(rhs.xyz),
+			y: self.zx.left_contraction(rhs.xyz),
+			z: self.xy.left_contraction(rhs.xyz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Bivec3.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.right_contraction(rhs.xy)
+				+ self.yz.right_contraction(rhs.yz)
+				+ self.zx.right_contraction(rhs.zx),
+			x: self.xy.right_contraction(rhs.y) + self.zx.right_contraction(rhs.z),
+			y: self.xy.right_contraction(rhs.x) + self.yz.right_contraction(rhs.z),
+			z: self.yz.right_contraction(rhs.y) + self.zx.right_contraction(rhs.x),
+			yz: self.yz.right_contraction(rhs.s),
+			zx: self.zx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Bivec3.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Bivec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.xy.scalar_product(rhs.xy) + self.yz.scalar_product(rhs.yz) + self.zx.scalar_product(rhs.zx)
+	}
+}
+
+// Bivec3.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx : -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy : -XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y) + self.zx.commutator(rhs.z),
+			y: self.xy.commutator(rhs.x) + self.yz.commutator(rhs.z),
+			z: self.yz.commutator(rhs.y) + self.zx.commutator(rhs.x),
+			yz: self.xy.commutator(rhs.zx) + self.zx.commutator(rhs.xy),
+			zx: self.xy.commutator(rhs.yz) + self.yz.commutator(rhs.xy),
+			xy: self.yz.commutator(rhs.zx) + self.zx.commutator(rhs.yz),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Bivec3.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Bivec3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.yz.0 * rhs.s.0),
+		//     y  : Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.xy.0 * rhs.s.0),
+		//     yz : YZ(self.xy.0 * rhs.y.0) + YZ(self.yz.0 * rhs.xyz.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx : -ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0) + ZX(self.zx.0 * rhs.xyz.0),
+		//     xy : XY(self.xy.0 * rhs.xyz.0) - XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		//     xyz: XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.yz.anti_dot(rhs.s),
+			y: self.zx.anti_dot(rhs.s),
+			z: self.xy.anti_dot(rhs.s),
+			yz: self.xy.anti_dot(rhs.y) + self.yz.anti_dot(rhs.xyz) + self.zx.anti_dot(rhs.z),
+			zx: self.xy.anti_dot(rhs.x) + self.yz.anti_dot(rhs.z) + self.zx.anti_dot(rhs.xyz),
+			xy: self.xy.anti_dot(rhs.xyz) + self.yz.anti_dot(rhs.y) + self.zx.anti_dot(rhs.x),
+			xyz: self.xy.anti_dot(rhs.xy) + self.yz.anti_dot(rhs.yz) + self.zx.anti_dot(rhs.zx),
+		}
+	}
+}
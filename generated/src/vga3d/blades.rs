@@ -0,0 +1,7555 @@
+//! # Blade types
+//! The blades that make up this geometric algebra.
+//!
+//! ## Unary operations
+//! | Op \ Blade       | 1   | X  | Y  | Z  | YZ  | ZX  | XY  | XYZ  |
+//! | ---------------- | --- | -- | -- | -- | --- | --- | --- | ---- |
+//! | Right complement | XYZ | YZ | ZX | XY | X   | Y   | Z   | 1    |
+//! | Left complement  | XYZ | YZ | ZX | XY | X   | Y   | Z   | 1    |
+//! | Reverse          | 1   | X  | Y  | Z  | -YZ | -ZX | -XY | -XYZ |
+//! | Anti-reverse     | -1  | -X | -Y | -Z | YZ  | ZX  | XY  | XYZ  |
+//! | Involute         | 1   | -X | -Y | -Z | YZ  | ZX  | XY  | -XYZ |
+//! | Conjugate        | 1   | -X | -Y | -Z | -YZ | -ZX | -XY | XYZ  |
+//! | Dual             | XYZ | YZ | ZX | XY | X   | Y   | Z   | 1    |
+//! | Bulk             | 1   | X  | Y  | Z  | YZ  | ZX  | XY  | XYZ  |
+//! | Weight           | 1   | X  | Y  | Z  | YZ  | ZX  | XY  | XYZ  |
+//! | Bulk dual        | XYZ | YZ | ZX | XY | X   | Y   | Z   | 1    |
+//! | Weight dual      | XYZ | YZ | ZX | XY | X   | Y   | Z   | 1    |
+//!
+//!
+//! ## Multiplication tables
+//! ### Geometric multiplication table
+//!
+//! |     | 1   | X   | Y   | Z   | YZ  | ZX  | XY  | XYZ |
+//! | --- | --- | --- | --- | --- | --- | --- | --- | --- |
+//! | 1   | 1   | X   | Y   | Z   | YZ  | ZX  | XY  | XYZ |
+//! | X   | X   | 1   | XY  | -ZX | XYZ | -Z  | Y   | YZ  |
+//! | Y   | Y   | -XY | 1   | YZ  | Z   | XYZ | -X  | ZX  |
+//! | Z   | Z   | ZX  | -YZ | 1   | -Y  | X   | XYZ | XY  |
+//! | YZ  | YZ  | XYZ | -Z  | Y   | -1  | -XY | ZX  | -X  |
+//! | ZX  | ZX  | Z   | XYZ | -X  | XY  | -1  | -YZ | -Y  |
+//! | XY  | XY  | -Y  | X   | XYZ | -ZX | YZ  | -1  | -Z  |
+//! | XYZ | XYZ | YZ  | ZX  | XY  | -X  | -Y  | -Z  | -1  |
+//!
+//!
+//! ### AntiGeometric multiplication table
+//!
+//! |     | 1    | X    | Y    | Z    | YZ  | ZX  | XY  | XYZ |
+//! | --- | ---- | ---- | ---- | ---- | --- | --- | --- | --- |
+//! | 1   | -XYZ | -YZ  | -ZX  | -XY  | X   | Y   | Z   | 1   |
+//! | X   | -YZ  | -XYZ | -Z   | Y    | 1   | -XY | ZX  | X   |
+//! | Y   | -ZX  | Z    | -XYZ | -X   | XY  | 1   | -YZ | Y   |
+//! | Z   | -XY  | -Y   | X    | -XYZ | -ZX | YZ  | 1   | Z   |
+//! | YZ  | X    | 1    | -XY  | ZX   | XYZ | Z   | -Y  | YZ  |
+//! | ZX  | Y    | XY   | 1    | -YZ  | -Z  | XYZ | X   | ZX  |
+//! | XY  | Z    | -ZX  | YZ   | 1    | Y   | -X  | XYZ | XY  |
+//! | XYZ | 1    | X    | Y    | Z    | YZ  | ZX  | XY  | XYZ |
+//!
+//!
+//! ### Dot multiplication table
+//!
+//! |     | 1   | X  | Y  | Z  | YZ | ZX | XY | XYZ |
+//! | --- | --- | -- | -- | -- | -- | -- | -- | --- |
+//! | 1   | 1   | X  | Y  | Z  | YZ | ZX | XY | XYZ |
+//! | X   | X   | 1  | 0  | 0  | 0  | -Z | Y  | YZ  |
+//! | Y   | Y   | 0  | 1  | 0  | Z  | 0  | -X | ZX  |
+//! | Z   | Z   | 0  | 0  | 1  | -Y | X  | 0  | XY  |
+//! | YZ  | YZ  | 0  | -Z | Y  | -1 | 0  | 0  | -X  |
+//! | ZX  | ZX  | Z  | 0  | -X | 0  | -1 | 0  | -Y  |
+//! | XY  | XY  | -Y | X  | 0  | 0  | 0  | -1 | -Z  |
+//! | XYZ | XYZ | YZ | ZX | XY | -X | -Y | -Z | -1  |
+//!
+//!
+//! ### Wedge multiplication table
+//!
+//! |     | 1   | X   | Y   | Z   | YZ  | ZX  | XY  | XYZ |
+//! | --- | --- | --- | --- | --- | --- | --- | --- | --- |
+//! | 1   | 1   | X   | Y   | Z   | YZ  | ZX  | XY  | XYZ |
+//! | X   | X   | 0   | XY  | -ZX | XYZ | 0   | 0   | 0   |
+//! | Y   | Y   | -XY | 0   | YZ  | 0   | XYZ | 0   | 0   |
+//! | Z   | Z   | ZX  | -YZ | 0   | 0   | 0   | XYZ | 0   |
+//! | YZ  | YZ  | XYZ | 0   | 0   | 0   | 0   | 0   | 0   |
+//! | ZX  | ZX  | 0   | XYZ | 0   | 0   | 0   | 0   | 0   |
+//! | XY  | XY  | 0   | 0   | XYZ | 0   | 0   | 0   | 0   |
+//! | XYZ | XYZ | 0   | 0   | 0   | 0   | 0   | 0   | 0   |
+//!
+//!
+//! ### AntiWedge multiplication table
+//!
+//! |     | 1 | X | Y | Z | YZ | ZX | XY | XYZ |
+//! | --- | - | - | - | - | -- | -- | -- | --- |
+//! | 1   | 0 | 0 | 0 | 0 | 0  | 0  | 0  | 1   |
+//! | X   | 0 | 0 | 0 | 0 | 1  | 0  | 0  | X   |
+//! | Y   | 0 | 0 | 0 | 0 | 0  | 1  | 0  | Y   |
+//! | Z   | 0 | 0 | 0 | 0 | 0  | 0  | 1  | Z   |
+//! | YZ  | 0 | 1 | 0 | 0 | 0  | Z  | -Y | YZ  |
+//! | ZX  | 0 | 0 | 1 | 0 | -Z | 0  | X  | ZX  |
+//! | XY  | 0 | 0 | 0 | 1 | Y  | -X | 0  | XY  |
+//! | XYZ | 1 | X | Y | Z | YZ | ZX | XY | XYZ |
+//!
+//!
+//! ### LeftContraction multiplication table
+//!
+//! |     | 1 | X | Y | Z | YZ | ZX | XY | XYZ |
+//! | --- | - | - | - | - | -- | -- | -- | --- |
+//! | 1   | 1 | X | Y | Z | YZ | ZX | XY | XYZ |
+//! | X   | 0 | 1 | 0 | 0 | 0  | -Z | Y  | YZ  |
+//! | Y   | 0 | 0 | 1 | 0 | Z  | 0  | -X | ZX  |
+//! | Z   | 0 | 0 | 0 | 1 | -Y | X  | 0  | XY  |
+//! | YZ  | 0 | 0 | 0 | 0 | -1 | 0  | 0  | -X  |
+//! | ZX  | 0 | 0 | 0 | 0 | 0  | -1 | 0  | -Y  |
+//! | XY  | 0 | 0 | 0 | 0 | 0  | 0  | -1 | -Z  |
+//! | XYZ | 0 | 0 | 0 | 0 | 0  | 0  | 0  | -1  |
+//!
+//!
+//! ### RightContraction multiplication table
+//!
+//! |     | 1   | X  | Y  | Z  | YZ | ZX | XY | XYZ |
+//! | --- | --- | -- | -- | -- | -- | -- | -- | --- |
+//! | 1   | 1   | 0  | 0  | 0  | 0  | 0  | 0  | 0   |
+//! | X   | X   | 1  | 0  | 0  | 0  | 0  | 0  | 0   |
+//! | Y   | Y   | 0  | 1  | 0  | 0  | 0  | 0  | 0   |
+//! | Z   | Z   | 0  | 0  | 1  | 0  | 0  | 0  | 0   |
+//! | YZ  | YZ  | 0  | -Z | Y  | -1 | 0  | 0  | 0   |
+//! | ZX  | ZX  | Z  | 0  | -X | 0  | -1 | 0  | 0   |
+//! | XY  | XY  | -Y | X  | 0  | 0  | 0  | -1 | 0   |
+//! | XYZ | XYZ | YZ | ZX | XY | -X | -Y | -Z | -1  |
+//!
+//!
+//! ### ScalarProduct multiplication table
+//!
+//! |     | 1 | X | Y | Z | YZ | ZX | XY | XYZ |
+//! | --- | - | - | - | - | -- | -- | -- | --- |
+//! | 1   | 1 | 0 | 0 | 0 | 0  | 0  | 0  | 0   |
+//! | X   | 0 | 1 | 0 | 0 | 0  | 0  | 0  | 0   |
+//! | Y   | 0 | 0 | 1 | 0 | 0  | 0  | 0  | 0   |
+//! | Z   | 0 | 0 | 0 | 1 | 0  | 0  | 0  | 0   |
+//! | YZ  | 0 | 0 | 0 | 0 | -1 | 0  | 0  | 0   |
+//! | ZX  | 0 | 0 | 0 | 0 | 0  | -1 | 0  | 0   |
+//! | XY  | 0 | 0 | 0 | 0 | 0  | 0  | -1 | 0   |
+//! | XYZ | 0 | 0 | 0 | 0 | 0  | 0  | 0  | -1  |
+//!
+//!
+//! ### Commutator multiplication table
+//!
+//! |     | 1 | X   | Y   | Z   | YZ  | ZX  | XY  | XYZ |
+//! | --- | - | --- | --- | --- | --- | --- | --- | --- |
+//! | 1   | 0 | 0   | 0   | 0   | 0   | 0   | 0   | 0   |
+//! | X   | 0 | 0   | XY  | -ZX | 0   | -Z  | Y   | 0   |
+//! | Y   | 0 | -XY | 0   | YZ  | Z   | 0   | -X  | 0   |
+//! | Z   | 0 | ZX  | -YZ | 0   | -Y  | X   | 0   | 0   |
+//! | YZ  | 0 | 0   | -Z  | Y   | 0   | -XY | ZX  | 0   |
+//! | ZX  | 0 | Z   | 0   | -X  | XY  | 0   | -YZ | 0   |
+//! | XY  | 0 | -Y  | X   | 0   | -ZX | YZ  | 0   | 0   |
+//! | XYZ | 0 | 0   | 0   | 0   | 0   | 0   | 0   | 0   |
+//!
+//!
+//! ### AntiDot multiplication table
+//!
+//! |     | 1    | X    | Y    | Z    | YZ  | ZX  | XY  | XYZ |
+//! | --- | ---- | ---- | ---- | ---- | --- | --- | --- | --- |
+//! | 1   | -XYZ | -YZ  | -ZX  | -XY  | X   | Y   | Z   | 1   |
+//! | X   | -YZ  | -XYZ | 0    | 0    | 0   | -XY | ZX  | X   |
+//! | Y   | -ZX  | 0    | -XYZ | 0    | XY  | 0   | -YZ | Y   |
+//! | Z   | -XY  | 0    | 0    | -XYZ | -ZX | YZ  | 0   | Z   |
+//! | YZ  | X    | 0    | -XY  | ZX   | XYZ | 0   | 0   | YZ  |
+//! | ZX  | Y    | XY   | 0    | -YZ  | 0   | XYZ | 0   | ZX  |
+//! | XY  | Z    | -ZX  | YZ   | 0    | 0   | 0   | XYZ | XY  |
+//! | XYZ | 1    | X    | Y    | Z    | YZ  | ZX  | XY  | XYZ |
+
+use derive_more::{Add, Neg, Sub};
+
+use super::*;
+
+/// The scalar type (real numbers).
+/// Squares to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct S(pub f64);
+
+impl std::ops::Mul<f64> for S {
+	type Output = S;
+	fn mul(self, rhs: f64) -> Self::Output {
+		S(self.0 * rhs)
+	}
+}
+
+impl Square for S {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for S {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for S {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for S {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for S {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct X(pub f64);
+
+impl std::ops::Mul<f64> for X {
+	type Output = X;
+	fn mul(self, rhs: f64) -> Self::Output {
+		X(self.0 * rhs)
+	}
+}
+
+impl Square for X {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for X {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for X {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for X {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for X {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Y(pub f64);
+
+impl std::ops::Mul<f64> for Y {
+	type Output = Y;
+	fn mul(self, rhs: f64) -> Self::Output {
+		Y(self.0 * rhs)
+	}
+}
+
+impl Square for Y {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Y {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Y {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Y {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Y {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Z(pub f64);
+
+impl std::ops::Mul<f64> for Z {
+	type Output = Z;
+	fn mul(self, rhs: f64) -> Self::Output {
+		Z(self.0 * rhs)
+	}
+}
+
+impl Square for Z {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Z {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Z {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Z {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Z {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YZ(pub f64);
+
+impl std::ops::Mul<f64> for YZ {
+	type Output = YZ;
+	fn mul(self, rhs: f64) -> Self::Output {
+		YZ(self.0 * rhs)
+	}
+}
+
+impl Square for YZ {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for YZ {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for YZ {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for YZ {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for YZ {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZX(pub f64);
+
+impl std::ops::Mul<f64> for ZX {
+	type Output = ZX;
+	fn mul(self, rhs: f64) -> Self::Output {
+		ZX(self.0 * rhs)
+	}
+}
+
+impl Square for ZX {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for ZX {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for ZX {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for ZX {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for ZX {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XY(pub f64);
+
+impl std::ops::Mul<f64> for XY {
+	type Output = XY;
+	fn mul(self, rhs: f64) -> Self::Output {
+		XY(self.0 * rhs)
+	}
+}
+
+impl Square for XY {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for XY {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for XY {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for XY {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for XY {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+/// The pseudo-scalar.
+/// Squares to -1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XYZ(pub f64);
+
+impl std::ops::Mul<f64> for XYZ {
+	type Output = XYZ;
+	fn mul(self, rhs: f64) -> Self::Output {
+		XYZ(self.0 * rhs)
+	}
+}
+
+impl Square for XYZ {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		-self.0 * self.0
+	}
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for XYZ {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for XYZ {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for XYZ {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.0.abs_diff_eq(&other.0, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for XYZ {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.0.relative_eq(&other.0, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl RCompl for blades:
+
+impl RCompl for S {
+	type Output = XYZ;
+	fn rcompl(self) -> Self::Output {
+		XYZ(self.0)
+	}
+}
+
+impl std::ops::Not for S {
+	type Output = XYZ;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for X {
+	type Output = YZ;
+	fn rcompl(self) -> Self::Output {
+		YZ(self.0)
+	}
+}
+
+impl std::ops::Not for X {
+	type Output = YZ;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for Y {
+	type Output = ZX;
+	fn rcompl(self) -> Self::Output {
+		ZX(self.0)
+	}
+}
+
+impl std::ops::Not for Y {
+	type Output = ZX;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for Z {
+	type Output = XY;
+	fn rcompl(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+impl std::ops::Not for Z {
+	type Output = XY;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for YZ {
+	type Output = X;
+	fn rcompl(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl std::ops::Not for YZ {
+	type Output = X;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for ZX {
+	type Output = Y;
+	fn rcompl(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl std::ops::Not for ZX {
+	type Output = Y;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for XY {
+	type Output = Z;
+	fn rcompl(self) -> Self::Output {
+		Z(self.0)
+	}
+}
+
+impl std::ops::Not for XY {
+	type Output = Z;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl RCompl for XYZ {
+	type Output = S;
+	fn rcompl(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+impl std::ops::Not for XYZ {
+	type Output = S;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl LCompl for blades:
+
+impl LCompl for S {
+	type Output = XYZ;
+	fn lcompl(self) -> Self::Output {
+		XYZ(self.0)
+	}
+}
+
+impl LCompl for X {
+	type Output = YZ;
+	fn lcompl(self) -> Self::Output {
+		YZ(self.0)
+	}
+}
+
+impl LCompl for Y {
+	type Output = ZX;
+	fn lcompl(self) -> Self::Output {
+		ZX(self.0)
+	}
+}
+
+impl LCompl for Z {
+	type Output = XY;
+	fn lcompl(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+impl LCompl for YZ {
+	type Output = X;
+	fn lcompl(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl LCompl for ZX {
+	type Output = Y;
+	fn lcompl(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl LCompl for XY {
+	type Output = Z;
+	fn lcompl(self) -> Self::Output {
+		Z(self.0)
+	}
+}
+
+impl LCompl for XYZ {
+	type Output = S;
+	fn lcompl(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Reverse for blades:
+
+impl Reverse for S {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for X {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for Y {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for Z {
+	fn rev(self) -> Self {
+		self
+	}
+}
+
+impl Reverse for YZ {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for ZX {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for XY {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+impl Reverse for XYZ {
+	fn rev(self) -> Self {
+		-self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiReverse for blades:
+
+impl AntiReverse for S {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for X {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for Y {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for Z {
+	fn arev(self) -> Self {
+		-self
+	}
+}
+
+impl AntiReverse for YZ {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for ZX {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for XY {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+impl AntiReverse for XYZ {
+	fn arev(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Involute for blades:
+
+impl Involute for S {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for X {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for Y {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for Z {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+impl Involute for YZ {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for ZX {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for XY {
+	fn involute(self) -> Self {
+		self
+	}
+}
+
+impl Involute for XYZ {
+	fn involute(self) -> Self {
+		-self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Conjugate for blades:
+
+impl Conjugate for S {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+impl Conjugate for X {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for Y {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for Z {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for YZ {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for ZX {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for XY {
+	fn conjugate(self) -> Self {
+		-self
+	}
+}
+
+impl Conjugate for XYZ {
+	fn conjugate(self) -> Self {
+		self
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Dual for blades:
+
+impl Dual for S {
+	type Output = XYZ;
+	fn dual(self) -> Self::Output {
+		XYZ(self.0)
+	}
+}
+
+impl Dual for X {
+	type Output = YZ;
+	fn dual(self) -> Self::Output {
+		YZ(self.0)
+	}
+}
+
+impl Dual for Y {
+	type Output = ZX;
+	fn dual(self) -> Self::Output {
+		ZX(self.0)
+	}
+}
+
+impl Dual for Z {
+	type Output = XY;
+	fn dual(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+impl Dual for YZ {
+	type Output = X;
+	fn dual(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl Dual for ZX {
+	type Output = Y;
+	fn dual(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl Dual for XY {
+	type Output = Z;
+	fn dual(self) -> Self::Output {
+		Z(self.0)
+	}
+}
+
+impl Dual for XYZ {
+	type Output = S;
+	fn dual(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Bulk for blades:
+
+impl Bulk for S {
+	type Output = S;
+	fn bulk(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+impl Bulk for X {
+	type Output = X;
+	fn bulk(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl Bulk for Y {
+	type Output = Y;
+	fn bulk(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl Bulk for Z {
+	type Output = Z;
+	fn bulk(self) -> Self::Output {
+		Z(self.0)
+	}
+}
+
+impl Bulk for YZ {
+	type Output = YZ;
+	fn bulk(self) -> Self::Output {
+		YZ(self.0)
+	}
+}
+
+impl Bulk for ZX {
+	type Output = ZX;
+	fn bulk(self) -> Self::Output {
+		ZX(self.0)
+	}
+}
+
+impl Bulk for XY {
+	type Output = XY;
+	fn bulk(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+impl Bulk for XYZ {
+	type Output = XYZ;
+	fn bulk(self) -> Self::Output {
+		XYZ(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Weight for blades:
+
+impl Weight for S {
+	type Output = S;
+	fn weight(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+impl Weight for X {
+	type Output = X;
+	fn weight(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl Weight for Y {
+	type Output = Y;
+	fn weight(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl Weight for Z {
+	type Output = Z;
+	fn weight(self) -> Self::Output {
+		Z(self.0)
+	}
+}
+
+impl Weight for YZ {
+	type Output = YZ;
+	fn weight(self) -> Self::Output {
+		YZ(self.0)
+	}
+}
+
+impl Weight for ZX {
+	type Output = ZX;
+	fn weight(self) -> Self::Output {
+		ZX(self.0)
+	}
+}
+
+impl Weight for XY {
+	type Output = XY;
+	fn weight(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+impl Weight for XYZ {
+	type Output = XYZ;
+	fn weight(self) -> Self::Output {
+		XYZ(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl BulkDual for blades:
+
+impl BulkDual for S {
+	type Output = XYZ;
+	fn bulk_dual(self) -> Self::Output {
+		XYZ(self.0)
+	}
+}
+
+impl BulkDual for X {
+	type Output = YZ;
+	fn bulk_dual(self) -> Self::Output {
+		YZ(self.0)
+	}
+}
+
+impl BulkDual for Y {
+	type Output = ZX;
+	fn bulk_dual(self) -> Self::Output {
+		ZX(self.0)
+	}
+}
+
+impl BulkDual for Z {
+	type Output = XY;
+	fn bulk_dual(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+impl BulkDual for YZ {
+	type Output = X;
+	fn bulk_dual(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl BulkDual for ZX {
+	type Output = Y;
+	fn bulk_dual(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl BulkDual for XY {
+	type Output = Z;
+	fn bulk_dual(self) -> Self::Output {
+		Z(self.0)
+	}
+}
+
+impl BulkDual for XYZ {
+	type Output = S;
+	fn bulk_dual(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl WeightDual for blades:
+
+impl WeightDual for S {
+	type Output = XYZ;
+	fn weight_dual(self) -> Self::Output {
+		XYZ(self.0)
+	}
+}
+
+impl WeightDual for X {
+	type Output = YZ;
+	fn weight_dual(self) -> Self::Output {
+		YZ(self.0)
+	}
+}
+
+impl WeightDual for Y {
+	type Output = ZX;
+	fn weight_dual(self) -> Self::Output {
+		ZX(self.0)
+	}
+}
+
+impl WeightDual for Z {
+	type Output = XY;
+	fn weight_dual(self) -> Self::Output {
+		XY(self.0)
+	}
+}
+
+impl WeightDual for YZ {
+	type Output = X;
+	fn weight_dual(self) -> Self::Output {
+		X(self.0)
+	}
+}
+
+impl WeightDual for ZX {
+	type Output = Y;
+	fn weight_dual(self) -> Self::Output {
+		Y(self.0)
+	}
+}
+
+impl WeightDual for XY {
+	type Output = Z;
+	fn weight_dual(self) -> Self::Output {
+		Z(self.0)
+	}
+}
+
+impl WeightDual for XYZ {
+	type Output = S;
+	fn weight_dual(self) -> Self::Output {
+		S(self.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Geometric for blades:
+
+impl Geometric<S> for S {
+	type Output = S;
+	fn geometric(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for S {
+	type Output = S;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for S {
+	type Output = X;
+	fn geometric(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for S {
+	type Output = X;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for S {
+	type Output = Y;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for S {
+	type Output = Y;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for S {
+	type Output = Z;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for S {
+	type Output = Z;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for S {
+	type Output = YZ;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for S {
+	type Output = YZ;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for S {
+	type Output = ZX;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for S {
+	type Output = ZX;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for S {
+	type Output = XY;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for S {
+	type Output = XY;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for S {
+	type Output = XYZ;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for S {
+	type Output = XYZ;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for X {
+	type Output = X;
+	fn geometric(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for X {
+	type Output = X;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for X {
+	type Output = S;
+	fn geometric(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for X {
+	type Output = S;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for X {
+	type Output = XY;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for X {
+	type Output = XY;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for X {
+	type Output = ZX;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for X {
+	type Output = ZX;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for X {
+	type Output = XYZ;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for X {
+	type Output = XYZ;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for X {
+	type Output = Z;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for X {
+	type Output = Z;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for X {
+	type Output = Y;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for X {
+	type Output = Y;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for X {
+	type Output = YZ;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for X {
+	type Output = YZ;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for Y {
+	type Output = Y;
+	fn geometric(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for Y {
+	type Output = Y;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for Y {
+	type Output = XY;
+	fn geometric(self, rhs: X) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for Y {
+	type Output = XY;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for Y {
+	type Output = S;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for Y {
+	type Output = S;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for Y {
+	type Output = YZ;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for Y {
+	type Output = YZ;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for Y {
+	type Output = Z;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for Y {
+	type Output = Z;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for Y {
+	type Output = XYZ;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for Y {
+	type Output = XYZ;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for Y {
+	type Output = X;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for Y {
+	type Output = X;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for Y {
+	type Output = ZX;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for Y {
+	type Output = ZX;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for Z {
+	type Output = Z;
+	fn geometric(self, rhs: S) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for Z {
+	type Output = Z;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for Z {
+	type Output = ZX;
+	fn geometric(self, rhs: X) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for Z {
+	type Output = ZX;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for Z {
+	type Output = YZ;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for Z {
+	type Output = YZ;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for Z {
+	type Output = S;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for Z {
+	type Output = S;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for Z {
+	type Output = Y;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for Z {
+	type Output = Y;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for Z {
+	type Output = X;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for Z {
+	type Output = X;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for Z {
+	type Output = XYZ;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for Z {
+	type Output = XYZ;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for Z {
+	type Output = XY;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for Z {
+	type Output = XY;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for YZ {
+	type Output = YZ;
+	fn geometric(self, rhs: S) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for YZ {
+	type Output = YZ;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for YZ {
+	type Output = XYZ;
+	fn geometric(self, rhs: X) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for YZ {
+	type Output = XYZ;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for YZ {
+	type Output = Z;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for YZ {
+	type Output = Z;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for YZ {
+	type Output = Y;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for YZ {
+	type Output = Y;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for YZ {
+	type Output = S;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for YZ {
+	type Output = S;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for YZ {
+	type Output = XY;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for YZ {
+	type Output = XY;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for YZ {
+	type Output = ZX;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for YZ {
+	type Output = ZX;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for YZ {
+	type Output = X;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for YZ {
+	type Output = X;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for ZX {
+	type Output = ZX;
+	fn geometric(self, rhs: S) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for ZX {
+	type Output = ZX;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for ZX {
+	type Output = Z;
+	fn geometric(self, rhs: X) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for ZX {
+	type Output = Z;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for ZX {
+	type Output = XYZ;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for ZX {
+	type Output = XYZ;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for ZX {
+	type Output = X;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for ZX {
+	type Output = X;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for ZX {
+	type Output = XY;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for ZX {
+	type Output = XY;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for ZX {
+	type Output = S;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for ZX {
+	type Output = S;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for ZX {
+	type Output = YZ;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for ZX {
+	type Output = YZ;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for ZX {
+	type Output = Y;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for ZX {
+	type Output = Y;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for XY {
+	type Output = XY;
+	fn geometric(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for XY {
+	type Output = XY;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for XY {
+	type Output = Y;
+	fn geometric(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for XY {
+	type Output = Y;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for XY {
+	type Output = X;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for XY {
+	type Output = X;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for XY {
+	type Output = XYZ;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for XY {
+	type Output = XYZ;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for XY {
+	type Output = ZX;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for XY {
+	type Output = ZX;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for XY {
+	type Output = YZ;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for XY {
+	type Output = YZ;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for XY {
+	type Output = S;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for XY {
+	type Output = S;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for XY {
+	type Output = Z;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for XY {
+	type Output = Z;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<S> for XYZ {
+	type Output = XYZ;
+	fn geometric(self, rhs: S) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<S> for XYZ {
+	type Output = XYZ;
+	fn mul(self, rhs: S) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<X> for XYZ {
+	type Output = YZ;
+	fn geometric(self, rhs: X) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<X> for XYZ {
+	type Output = YZ;
+	fn mul(self, rhs: X) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Y> for XYZ {
+	type Output = ZX;
+	fn geometric(self, rhs: Y) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Y> for XYZ {
+	type Output = ZX;
+	fn mul(self, rhs: Y) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<Z> for XYZ {
+	type Output = XY;
+	fn geometric(self, rhs: Z) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<Z> for XYZ {
+	type Output = XY;
+	fn mul(self, rhs: Z) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<YZ> for XYZ {
+	type Output = X;
+	fn geometric(self, rhs: YZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<YZ> for XYZ {
+	type Output = X;
+	fn mul(self, rhs: YZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<ZX> for XYZ {
+	type Output = Y;
+	fn geometric(self, rhs: ZX) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<ZX> for XYZ {
+	type Output = Y;
+	fn mul(self, rhs: ZX) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XY> for XYZ {
+	type Output = Z;
+	fn geometric(self, rhs: XY) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XY> for XYZ {
+	type Output = Z;
+	fn mul(self, rhs: XY) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+impl Geometric<XYZ> for XYZ {
+	type Output = S;
+	fn geometric(self, rhs: XYZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::Mul<XYZ> for XYZ {
+	type Output = S;
+	fn mul(self, rhs: XYZ) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiGeometric for blades:
+
+impl AntiGeometric<S> for S {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for S {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for S {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for S {
+	type Output = XY;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for S {
+	type Output = X;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for S {
+	type Output = Y;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for S {
+	type Output = Z;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for S {
+	type Output = S;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for X {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for X {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for X {
+	type Output = Z;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for X {
+	type Output = Y;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for X {
+	type Output = S;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for X {
+	type Output = XY;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for X {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for X {
+	type Output = X;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for Y {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for Y {
+	type Output = Z;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for Y {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for Y {
+	type Output = X;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for Y {
+	type Output = XY;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for Y {
+	type Output = S;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for Y {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for Y {
+	type Output = Y;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for Z {
+	type Output = XY;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for Z {
+	type Output = Y;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for Z {
+	type Output = X;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for Z {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for Z {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for Z {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for Z {
+	type Output = S;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for Z {
+	type Output = Z;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for YZ {
+	type Output = X;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for YZ {
+	type Output = S;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for YZ {
+	type Output = XY;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for YZ {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for YZ {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for YZ {
+	type Output = Z;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for YZ {
+	type Output = Y;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for YZ {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for ZX {
+	type Output = Y;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for ZX {
+	type Output = XY;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for ZX {
+	type Output = S;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for ZX {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for ZX {
+	type Output = Z;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for ZX {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for ZX {
+	type Output = X;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for ZX {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for XY {
+	type Output = Z;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for XY {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for XY {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for XY {
+	type Output = S;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for XY {
+	type Output = Y;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for XY {
+	type Output = X;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for XY {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for XY {
+	type Output = XY;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<S> for XYZ {
+	type Output = S;
+	fn anti_geometric(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<X> for XYZ {
+	type Output = X;
+	fn anti_geometric(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Y> for XYZ {
+	type Output = Y;
+	fn anti_geometric(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<Z> for XYZ {
+	type Output = Z;
+	fn anti_geometric(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<YZ> for XYZ {
+	type Output = YZ;
+	fn anti_geometric(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<ZX> for XYZ {
+	type Output = ZX;
+	fn anti_geometric(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XY> for XYZ {
+	type Output = XY;
+	fn anti_geometric(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiGeometric<XYZ> for XYZ {
+	type Output = XYZ;
+	fn anti_geometric(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Dot for blades:
+
+impl Dot<S> for S {
+	type Output = S;
+	fn dot(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for S {
+	type Output = S;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for S {
+	type Output = X;
+	fn dot(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for S {
+	type Output = X;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for S {
+	type Output = Y;
+	fn dot(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for S {
+	type Output = Y;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for S {
+	type Output = Z;
+	fn dot(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for S {
+	type Output = Z;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for S {
+	type Output = YZ;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for S {
+	type Output = YZ;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for S {
+	type Output = ZX;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for S {
+	type Output = ZX;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for S {
+	type Output = XY;
+	fn dot(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for S {
+	type Output = XY;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for S {
+	type Output = XYZ;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for S {
+	type Output = XYZ;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for X {
+	type Output = X;
+	fn dot(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for X {
+	type Output = X;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for X {
+	type Output = S;
+	fn dot(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for X {
+	type Output = S;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Z> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for X {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for X {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for X {
+	type Output = Z;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for X {
+	type Output = Z;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for X {
+	type Output = Y;
+	fn dot(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for X {
+	type Output = Y;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for X {
+	type Output = YZ;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for X {
+	type Output = YZ;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for Y {
+	type Output = Y;
+	fn dot(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for Y {
+	type Output = Y;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for Y {
+	type Output = S;
+	fn dot(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for Y {
+	type Output = S;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Z> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for Y {
+	type Output = Z;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for Y {
+	type Output = Z;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for Y {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for Y {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for Y {
+	type Output = X;
+	fn dot(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for Y {
+	type Output = X;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for Y {
+	type Output = ZX;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for Y {
+	type Output = ZX;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for Z {
+	type Output = Z;
+	fn dot(self, rhs: S) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for Z {
+	type Output = Z;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for Z {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for Z {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for Z {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for Z {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for Z {
+	type Output = S;
+	fn dot(self, rhs: Z) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for Z {
+	type Output = S;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for Z {
+	type Output = Y;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for Z {
+	type Output = Y;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for Z {
+	type Output = X;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for Z {
+	type Output = X;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for Z {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for Z {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for Z {
+	type Output = XY;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for Z {
+	type Output = XY;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for YZ {
+	type Output = YZ;
+	fn dot(self, rhs: S) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for YZ {
+	type Output = YZ;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<X> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for YZ {
+	type Output = Z;
+	fn dot(self, rhs: Y) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for YZ {
+	type Output = Z;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for YZ {
+	type Output = Y;
+	fn dot(self, rhs: Z) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for YZ {
+	type Output = Y;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for YZ {
+	type Output = S;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for YZ {
+	type Output = S;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for YZ {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for YZ {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for YZ {
+	type Output = X;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for YZ {
+	type Output = X;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for ZX {
+	type Output = ZX;
+	fn dot(self, rhs: S) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for ZX {
+	type Output = ZX;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for ZX {
+	type Output = Z;
+	fn dot(self, rhs: X) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for ZX {
+	type Output = Z;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Y> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for ZX {
+	type Output = X;
+	fn dot(self, rhs: Z) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for ZX {
+	type Output = X;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for ZX {
+	type Output = S;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for ZX {
+	type Output = S;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for ZX {
+	type Output = Zero;
+	fn dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<XY> for ZX {
+	type Output = Zero;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for ZX {
+	type Output = Y;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for ZX {
+	type Output = Y;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for XY {
+	type Output = XY;
+	fn dot(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for XY {
+	type Output = XY;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for XY {
+	type Output = Y;
+	fn dot(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for XY {
+	type Output = Y;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for XY {
+	type Output = X;
+	fn dot(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for XY {
+	type Output = X;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<Z> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<YZ> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for XY {
+	type Output = Zero;
+	fn dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitOr<ZX> for XY {
+	type Output = Zero;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for XY {
+	type Output = S;
+	fn dot(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for XY {
+	type Output = S;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for XY {
+	type Output = Z;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for XY {
+	type Output = Z;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<S> for XYZ {
+	type Output = XYZ;
+	fn dot(self, rhs: S) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<S> for XYZ {
+	type Output = XYZ;
+	fn bitor(self, rhs: S) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<X> for XYZ {
+	type Output = YZ;
+	fn dot(self, rhs: X) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<X> for XYZ {
+	type Output = YZ;
+	fn bitor(self, rhs: X) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Y> for XYZ {
+	type Output = ZX;
+	fn dot(self, rhs: Y) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Y> for XYZ {
+	type Output = ZX;
+	fn bitor(self, rhs: Y) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<Z> for XYZ {
+	type Output = XY;
+	fn dot(self, rhs: Z) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<Z> for XYZ {
+	type Output = XY;
+	fn bitor(self, rhs: Z) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<YZ> for XYZ {
+	type Output = X;
+	fn dot(self, rhs: YZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<YZ> for XYZ {
+	type Output = X;
+	fn bitor(self, rhs: YZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<ZX> for XYZ {
+	type Output = Y;
+	fn dot(self, rhs: ZX) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<ZX> for XYZ {
+	type Output = Y;
+	fn bitor(self, rhs: ZX) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XY> for XYZ {
+	type Output = Z;
+	fn dot(self, rhs: XY) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XY> for XYZ {
+	type Output = Z;
+	fn bitor(self, rhs: XY) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+impl Dot<XYZ> for XYZ {
+	type Output = S;
+	fn dot(self, rhs: XYZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitOr<XYZ> for XYZ {
+	type Output = S;
+	fn bitor(self, rhs: XYZ) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Wedge for blades:
+
+impl Wedge<S> for S {
+	type Output = S;
+	fn wedge(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for S {
+	type Output = S;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for S {
+	type Output = X;
+	fn wedge(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for S {
+	type Output = X;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for S {
+	type Output = Y;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for S {
+	type Output = Y;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for S {
+	type Output = Z;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for S {
+	type Output = Z;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for S {
+	type Output = YZ;
+	fn wedge(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<YZ> for S {
+	type Output = YZ;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for S {
+	type Output = ZX;
+	fn wedge(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<ZX> for S {
+	type Output = ZX;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for S {
+	type Output = XY;
+	fn wedge(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XY> for S {
+	type Output = XY;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for S {
+	type Output = XYZ;
+	fn wedge(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XYZ> for S {
+	type Output = XYZ;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for X {
+	type Output = X;
+	fn wedge(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for X {
+	type Output = X;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for X {
+	type Output = XY;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for X {
+	type Output = XY;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for X {
+	type Output = ZX;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for X {
+	type Output = ZX;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for X {
+	type Output = XYZ;
+	fn wedge(self, rhs: YZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<YZ> for X {
+	type Output = XYZ;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for X {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for X {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for Y {
+	type Output = Y;
+	fn wedge(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for Y {
+	type Output = Y;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for Y {
+	type Output = XY;
+	fn wedge(self, rhs: X) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for Y {
+	type Output = XY;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for Y {
+	type Output = YZ;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for Y {
+	type Output = YZ;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for Y {
+	type Output = XYZ;
+	fn wedge(self, rhs: ZX) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<ZX> for Y {
+	type Output = XYZ;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for Y {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for Y {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for Z {
+	type Output = Z;
+	fn wedge(self, rhs: S) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for Z {
+	type Output = Z;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for Z {
+	type Output = ZX;
+	fn wedge(self, rhs: X) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for Z {
+	type Output = ZX;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for Z {
+	type Output = YZ;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for Z {
+	type Output = YZ;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for Z {
+	type Output = XYZ;
+	fn wedge(self, rhs: XY) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<XY> for Z {
+	type Output = XYZ;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for Z {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for Z {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for YZ {
+	type Output = YZ;
+	fn wedge(self, rhs: S) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for YZ {
+	type Output = YZ;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for YZ {
+	type Output = XYZ;
+	fn wedge(self, rhs: X) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<X> for YZ {
+	type Output = XYZ;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for YZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for YZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for ZX {
+	type Output = ZX;
+	fn wedge(self, rhs: S) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for ZX {
+	type Output = ZX;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for ZX {
+	type Output = XYZ;
+	fn wedge(self, rhs: Y) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Y> for ZX {
+	type Output = XYZ;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for ZX {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for ZX {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for XY {
+	type Output = XY;
+	fn wedge(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for XY {
+	type Output = XY;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for XY {
+	type Output = XYZ;
+	fn wedge(self, rhs: Z) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<Z> for XY {
+	type Output = XYZ;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for XY {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for XY {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<S> for XYZ {
+	type Output = XYZ;
+	fn wedge(self, rhs: S) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitXor<S> for XYZ {
+	type Output = XYZ;
+	fn bitxor(self, rhs: S) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<X> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<X> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: X) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Y> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Y> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: Y) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<Z> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<Z> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: Z) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<YZ> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<YZ> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: YZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<ZX> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<ZX> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: ZX) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XY> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XY> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XY) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl Wedge<XYZ> for XYZ {
+	type Output = Zero;
+	fn wedge(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitXor<XYZ> for XYZ {
+	type Output = Zero;
+	fn bitxor(self, rhs: XYZ) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiWedge for blades:
+
+impl AntiWedge<S> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for S {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for S {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for S {
+	type Output = S;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for S {
+	type Output = S;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for X {
+	type Output = S;
+	fn anti_wedge(self, rhs: YZ) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZ> for X {
+	type Output = S;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for X {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for X {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for X {
+	type Output = X;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for X {
+	type Output = X;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for Y {
+	type Output = S;
+	fn anti_wedge(self, rhs: ZX) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZX> for Y {
+	type Output = S;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for Y {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for Y {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for Y {
+	type Output = Y;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for Y {
+	type Output = Y;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for Z {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for Z {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for Z {
+	type Output = S;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for Z {
+	type Output = S;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for Z {
+	type Output = Z;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for Z {
+	type Output = Z;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for YZ {
+	type Output = S;
+	fn anti_wedge(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<X> for YZ {
+	type Output = S;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for YZ {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<YZ> for YZ {
+	type Output = Zero;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for YZ {
+	type Output = Z;
+	fn anti_wedge(self, rhs: ZX) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZX> for YZ {
+	type Output = Z;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for YZ {
+	type Output = Y;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for YZ {
+	type Output = Y;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for YZ {
+	type Output = YZ;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for YZ {
+	type Output = YZ;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for ZX {
+	type Output = S;
+	fn anti_wedge(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<Y> for ZX {
+	type Output = S;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Z> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for ZX {
+	type Output = Z;
+	fn anti_wedge(self, rhs: YZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZ> for ZX {
+	type Output = Z;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for ZX {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<ZX> for ZX {
+	type Output = Zero;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for ZX {
+	type Output = X;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for ZX {
+	type Output = X;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for ZX {
+	type Output = ZX;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for ZX {
+	type Output = ZX;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<S> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<X> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Y> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for XY {
+	type Output = S;
+	fn anti_wedge(self, rhs: Z) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<Z> for XY {
+	type Output = S;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for XY {
+	type Output = Y;
+	fn anti_wedge(self, rhs: YZ) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZ> for XY {
+	type Output = Y;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for XY {
+	type Output = X;
+	fn anti_wedge(self, rhs: ZX) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZX> for XY {
+	type Output = X;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for XY {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<XY> for XY {
+	type Output = Zero;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for XY {
+	type Output = XY;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for XY {
+	type Output = XY;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<S> for XYZ {
+	type Output = S;
+	fn anti_wedge(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<S> for XYZ {
+	type Output = S;
+	fn bitand(self, rhs: S) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<X> for XYZ {
+	type Output = X;
+	fn anti_wedge(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<X> for XYZ {
+	type Output = X;
+	fn bitand(self, rhs: X) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Y> for XYZ {
+	type Output = Y;
+	fn anti_wedge(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<Y> for XYZ {
+	type Output = Y;
+	fn bitand(self, rhs: Y) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<Z> for XYZ {
+	type Output = Z;
+	fn anti_wedge(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<Z> for XYZ {
+	type Output = Z;
+	fn bitand(self, rhs: Z) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<YZ> for XYZ {
+	type Output = YZ;
+	fn anti_wedge(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<YZ> for XYZ {
+	type Output = YZ;
+	fn bitand(self, rhs: YZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<ZX> for XYZ {
+	type Output = ZX;
+	fn anti_wedge(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<ZX> for XYZ {
+	type Output = ZX;
+	fn bitand(self, rhs: ZX) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XY> for XYZ {
+	type Output = XY;
+	fn anti_wedge(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XY> for XYZ {
+	type Output = XY;
+	fn bitand(self, rhs: XY) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+impl AntiWedge<XYZ> for XYZ {
+	type Output = XYZ;
+	fn anti_wedge(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl std::ops::BitAnd<XYZ> for XYZ {
+	type Output = XYZ;
+	fn bitand(self, rhs: XYZ) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl LeftContraction for blades:
+
+impl LeftContraction<S> for S {
+	type Output = S;
+	fn left_contraction(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<X> for S {
+	type Output = X;
+	fn left_contraction(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<Y> for S {
+	type Output = Y;
+	fn left_contraction(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<Z> for S {
+	type Output = Z;
+	fn left_contraction(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<YZ> for S {
+	type Output = YZ;
+	fn left_contraction(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<ZX> for S {
+	type Output = ZX;
+	fn left_contraction(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<XY> for S {
+	type Output = XY;
+	fn left_contraction(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<XYZ> for S {
+	type Output = XYZ;
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<X> for X {
+	type Output = S;
+	fn left_contraction(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<Y> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Z> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<YZ> for X {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<ZX> for X {
+	type Output = Z;
+	fn left_contraction(self, rhs: ZX) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<XY> for X {
+	type Output = Y;
+	fn left_contraction(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<XYZ> for X {
+	type Output = YZ;
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<X> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Y> for Y {
+	type Output = S;
+	fn left_contraction(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<Z> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<YZ> for Y {
+	type Output = Z;
+	fn left_contraction(self, rhs: YZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<ZX> for Y {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<XY> for Y {
+	type Output = X;
+	fn left_contraction(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<XYZ> for Y {
+	type Output = ZX;
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<X> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Y> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Z> for Z {
+	type Output = S;
+	fn left_contraction(self, rhs: Z) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<YZ> for Z {
+	type Output = Y;
+	fn left_contraction(self, rhs: YZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<ZX> for Z {
+	type Output = X;
+	fn left_contraction(self, rhs: ZX) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<XY> for Z {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<XYZ> for Z {
+	type Output = XY;
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<X> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Y> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Z> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<YZ> for YZ {
+	type Output = S;
+	fn left_contraction(self, rhs: YZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<ZX> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<XY> for YZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<XYZ> for YZ {
+	type Output = X;
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<X> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Y> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Z> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<YZ> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<ZX> for ZX {
+	type Output = S;
+	fn left_contraction(self, rhs: ZX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<XY> for ZX {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<XYZ> for ZX {
+	type Output = Y;
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<X> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Y> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Z> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<YZ> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<ZX> for XY {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<XY> for XY {
+	type Output = S;
+	fn left_contraction(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<XYZ> for XY {
+	type Output = Z;
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl LeftContraction<S> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<X> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Y> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<Z> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<YZ> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<ZX> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<XY> for XYZ {
+	type Output = Zero;
+	fn left_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl LeftContraction<XYZ> for XYZ {
+	type Output = S;
+	fn left_contraction(self, rhs: XYZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl RightContraction for blades:
+
+impl RightContraction<S> for S {
+	type Output = S;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<X> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<Y> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<Z> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<YZ> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<ZX> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XY> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XYZ> for S {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for X {
+	type Output = X;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<X> for X {
+	type Output = S;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<Y> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<Z> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<YZ> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<ZX> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XY> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XYZ> for X {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for Y {
+	type Output = Y;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<X> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<Y> for Y {
+	type Output = S;
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<Z> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<YZ> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<ZX> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XY> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XYZ> for Y {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for Z {
+	type Output = Z;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<X> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<Y> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<Z> for Z {
+	type Output = S;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<YZ> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<ZX> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XY> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XYZ> for Z {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for YZ {
+	type Output = YZ;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<X> for YZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<Y> for YZ {
+	type Output = Z;
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<Z> for YZ {
+	type Output = Y;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<YZ> for YZ {
+	type Output = S;
+	fn right_contraction(self, rhs: YZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<ZX> for YZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XY> for YZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XYZ> for YZ {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for ZX {
+	type Output = ZX;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<X> for ZX {
+	type Output = Z;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<Y> for ZX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<Z> for ZX {
+	type Output = X;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<YZ> for ZX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<ZX> for ZX {
+	type Output = S;
+	fn right_contraction(self, rhs: ZX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<XY> for ZX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XYZ> for ZX {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for XY {
+	type Output = XY;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<X> for XY {
+	type Output = Y;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<Y> for XY {
+	type Output = X;
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<Z> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<YZ> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<ZX> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<XY> for XY {
+	type Output = S;
+	fn right_contraction(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<XYZ> for XY {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl RightContraction<S> for XYZ {
+	type Output = XYZ;
+	fn right_contraction(self, rhs: S) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<X> for XYZ {
+	type Output = YZ;
+	fn right_contraction(self, rhs: X) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<Y> for XYZ {
+	type Output = ZX;
+	fn right_contraction(self, rhs: Y) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<Z> for XYZ {
+	type Output = XY;
+	fn right_contraction(self, rhs: Z) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<YZ> for XYZ {
+	type Output = X;
+	fn right_contraction(self, rhs: YZ) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<ZX> for XYZ {
+	type Output = Y;
+	fn right_contraction(self, rhs: ZX) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<XY> for XYZ {
+	type Output = Z;
+	fn right_contraction(self, rhs: XY) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl RightContraction<XYZ> for XYZ {
+	type Output = S;
+	fn right_contraction(self, rhs: XYZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl ScalarProduct for blades:
+
+impl ScalarProduct<S> for S {
+	type Output = S;
+	fn scalar_product(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<X> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Y> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Z> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<YZ> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<ZX> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XY> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XYZ> for S {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<X> for X {
+	type Output = S;
+	fn scalar_product(self, rhs: X) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<Y> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Z> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<YZ> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<ZX> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XY> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XYZ> for X {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<X> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Y> for Y {
+	type Output = S;
+	fn scalar_product(self, rhs: Y) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<Z> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<YZ> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<ZX> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XY> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XYZ> for Y {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<X> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Y> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Z> for Z {
+	type Output = S;
+	fn scalar_product(self, rhs: Z) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<YZ> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<ZX> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XY> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XYZ> for Z {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<X> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Y> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Z> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<YZ> for YZ {
+	type Output = S;
+	fn scalar_product(self, rhs: YZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<ZX> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XY> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XYZ> for YZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<X> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Y> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Z> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<YZ> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<ZX> for ZX {
+	type Output = S;
+	fn scalar_product(self, rhs: ZX) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<XY> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XYZ> for ZX {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<X> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Y> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Z> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<YZ> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<ZX> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XY> for XY {
+	type Output = S;
+	fn scalar_product(self, rhs: XY) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+impl ScalarProduct<XYZ> for XY {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<S> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<X> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Y> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Z> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<YZ> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<ZX> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XY> for XYZ {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<XYZ> for XYZ {
+	type Output = S;
+	fn scalar_product(self, rhs: XYZ) -> Self::Output {
+		S(-self.0 * rhs.0)
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl Commutator for blades:
+
+impl Commutator<S> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<X> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<Y> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<Z> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<YZ> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<ZX> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<XY> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<XYZ> for S {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for X {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<X> for X {
+	type Output = Zero;
+	fn commutator(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<Y> for X {
+	type Output = XY;
+	fn commutator(self, rhs: Y) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<Z> for X {
+	type Output = ZX;
+	fn commutator(self, rhs: Z) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<YZ> for X {
+	type Output = Zero;
+	fn commutator(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<ZX> for X {
+	type Output = Z;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XY> for X {
+	type Output = Y;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XYZ> for X {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for Y {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<X> for Y {
+	type Output = XY;
+	fn commutator(self, rhs: X) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<Y> for Y {
+	type Output = Zero;
+	fn commutator(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<Z> for Y {
+	type Output = YZ;
+	fn commutator(self, rhs: Z) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<YZ> for Y {
+	type Output = Z;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<ZX> for Y {
+	type Output = Zero;
+	fn commutator(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<XY> for Y {
+	type Output = X;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XYZ> for Y {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for Z {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<X> for Z {
+	type Output = ZX;
+	fn commutator(self, rhs: X) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<Y> for Z {
+	type Output = YZ;
+	fn commutator(self, rhs: Y) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<Z> for Z {
+	type Output = Zero;
+	fn commutator(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<YZ> for Z {
+	type Output = Y;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<ZX> for Z {
+	type Output = X;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XY> for Z {
+	type Output = Zero;
+	fn commutator(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<XYZ> for Z {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for YZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<X> for YZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<Y> for YZ {
+	type Output = Z;
+	fn commutator(self, rhs: Y) -> Self::Output {
+		Z(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<Z> for YZ {
+	type Output = Y;
+	fn commutator(self, rhs: Z) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<YZ> for YZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<ZX> for YZ {
+	type Output = XY;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XY> for YZ {
+	type Output = ZX;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XYZ> for YZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for ZX {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<X> for ZX {
+	type Output = Z;
+	fn commutator(self, rhs: X) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<Y> for ZX {
+	type Output = Zero;
+	fn commutator(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<Z> for ZX {
+	type Output = X;
+	fn commutator(self, rhs: Z) -> Self::Output {
+		X(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<YZ> for ZX {
+	type Output = XY;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<ZX> for ZX {
+	type Output = Zero;
+	fn commutator(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<XY> for ZX {
+	type Output = YZ;
+	fn commutator(self, rhs: XY) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XYZ> for ZX {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for XY {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<X> for XY {
+	type Output = Y;
+	fn commutator(self, rhs: X) -> Self::Output {
+		Y(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<Y> for XY {
+	type Output = X;
+	fn commutator(self, rhs: Y) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<Z> for XY {
+	type Output = Zero;
+	fn commutator(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<YZ> for XY {
+	type Output = ZX;
+	fn commutator(self, rhs: YZ) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl Commutator<ZX> for XY {
+	type Output = YZ;
+	fn commutator(self, rhs: ZX) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl Commutator<XY> for XY {
+	type Output = Zero;
+	fn commutator(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<XYZ> for XY {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<S> for XYZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: S) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<X> for XYZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<Y> for XYZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<Z> for XYZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<YZ> for XYZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<ZX> for XYZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<XY> for XYZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl Commutator<XYZ> for XYZ {
+	type Output = Zero;
+	fn commutator(self, _rhs: XYZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+// ---------------------------------------------------------------------
+// impl AntiDot for blades:
+
+impl AntiDot<S> for S {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<X> for S {
+	type Output = YZ;
+	fn anti_dot(self, rhs: X) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Y> for S {
+	type Output = ZX;
+	fn anti_dot(self, rhs: Y) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Z> for S {
+	type Output = XY;
+	fn anti_dot(self, rhs: Z) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<YZ> for S {
+	type Output = X;
+	fn anti_dot(self, rhs: YZ) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<ZX> for S {
+	type Output = Y;
+	fn anti_dot(self, rhs: ZX) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<XY> for S {
+	type Output = Z;
+	fn anti_dot(self, rhs: XY) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<XYZ> for S {
+	type Output = S;
+	fn anti_dot(self, rhs: XYZ) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for X {
+	type Output = YZ;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<X> for X {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: X) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Y> for X {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<Z> for X {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<YZ> for X {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<ZX> for X {
+	type Output = XY;
+	fn anti_dot(self, rhs: ZX) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<XY> for X {
+	type Output = ZX;
+	fn anti_dot(self, rhs: XY) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<XYZ> for X {
+	type Output = X;
+	fn anti_dot(self, rhs: XYZ) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for Y {
+	type Output = ZX;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<X> for Y {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<Y> for Y {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: Y) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Z> for Y {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<YZ> for Y {
+	type Output = XY;
+	fn anti_dot(self, rhs: YZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<ZX> for Y {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<XY> for Y {
+	type Output = YZ;
+	fn anti_dot(self, rhs: XY) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<XYZ> for Y {
+	type Output = Y;
+	fn anti_dot(self, rhs: XYZ) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for Z {
+	type Output = XY;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<X> for Z {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<Y> for Z {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<Z> for Z {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: Z) -> Self::Output {
+		XYZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<YZ> for Z {
+	type Output = ZX;
+	fn anti_dot(self, rhs: YZ) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<ZX> for Z {
+	type Output = YZ;
+	fn anti_dot(self, rhs: ZX) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<XY> for Z {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<XYZ> for Z {
+	type Output = Z;
+	fn anti_dot(self, rhs: XYZ) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for YZ {
+	type Output = X;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<X> for YZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: X) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<Y> for YZ {
+	type Output = XY;
+	fn anti_dot(self, rhs: Y) -> Self::Output {
+		XY(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Z> for YZ {
+	type Output = ZX;
+	fn anti_dot(self, rhs: Z) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<YZ> for YZ {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: YZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<ZX> for YZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<XY> for YZ {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<XYZ> for YZ {
+	type Output = YZ;
+	fn anti_dot(self, rhs: XYZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for ZX {
+	type Output = Y;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<X> for ZX {
+	type Output = XY;
+	fn anti_dot(self, rhs: X) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Y> for ZX {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Y) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<Z> for ZX {
+	type Output = YZ;
+	fn anti_dot(self, rhs: Z) -> Self::Output {
+		YZ(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<YZ> for ZX {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<ZX> for ZX {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: ZX) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<XY> for ZX {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: XY) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<XYZ> for ZX {
+	type Output = ZX;
+	fn anti_dot(self, rhs: XYZ) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for XY {
+	type Output = Z;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<X> for XY {
+	type Output = ZX;
+	fn anti_dot(self, rhs: X) -> Self::Output {
+		ZX(-self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Y> for XY {
+	type Output = YZ;
+	fn anti_dot(self, rhs: Y) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Z> for XY {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: Z) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<YZ> for XY {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: YZ) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<ZX> for XY {
+	type Output = Zero;
+	fn anti_dot(self, _rhs: ZX) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl AntiDot<XY> for XY {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: XY) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<XYZ> for XY {
+	type Output = XY;
+	fn anti_dot(self, rhs: XYZ) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<S> for XYZ {
+	type Output = S;
+	fn anti_dot(self, rhs: S) -> Self::Output {
+		S(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<X> for XYZ {
+	type Output = X;
+	fn anti_dot(self, rhs: X) -> Self::Output {
+		X(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Y> for XYZ {
+	type Output = Y;
+	fn anti_dot(self, rhs: Y) -> Self::Output {
+		Y(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<Z> for XYZ {
+	type Output = Z;
+	fn anti_dot(self, rhs: Z) -> Self::Output {
+		Z(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<YZ> for XYZ {
+	type Output = YZ;
+	fn anti_dot(self, rhs: YZ) -> Self::Output {
+		YZ(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<ZX> for XYZ {
+	type Output = ZX;
+	fn anti_dot(self, rhs: ZX) -> Self::Output {
+		ZX(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<XY> for XYZ {
+	type Output = XY;
+	fn anti_dot(self, rhs: XY) -> Self::Output {
+		XY(self.0 * rhs.0)
+	}
+}
+
+impl AntiDot<XYZ> for XYZ {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: XYZ) -> Self::Output {
+		XYZ(self.0 * rhs.0)
+	}
+}
@@ -0,0 +1,1003 @@
+//! The C ABI of this algebra, declared in `vga3d.h`.
+
+use super::*;
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_rcompl(value: Vec3) -> Bivec3 {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_lcompl(value: Vec3) -> Bivec3 {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_rev(value: Vec3) -> Vec3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_arev(value: Vec3) -> Vec3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_involute(value: Vec3) -> Vec3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_conjugate(value: Vec3) -> Vec3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_dual(value: Vec3) -> Bivec3 {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_bulk(value: Vec3) -> Vec3 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_weight(value: Vec3) -> Vec3 {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_bulk_dual(value: Vec3) -> Bivec3 {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_weight_dual(value: Vec3) -> Bivec3 {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_geometric_vec3(lhs: Vec3, rhs: Vec3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_geometric_vec3(lhs: Vec3, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_dot_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_wedge_vec3(lhs: Vec3, rhs: Vec3) -> Bivec3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_left_contraction_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_right_contraction_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_scalar_product_vec3(lhs: Vec3, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_commutator_vec3(lhs: Vec3, rhs: Vec3) -> Bivec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_dot_vec3(lhs: Vec3, rhs: Vec3) -> XYZ {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_geometric_bivec3(lhs: Vec3, rhs: Bivec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_geometric_bivec3(lhs: Vec3, rhs: Bivec3) -> Rotor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_dot_bivec3(lhs: Vec3, rhs: Bivec3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_wedge_bivec3(lhs: Vec3, rhs: Bivec3) -> XYZ {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_wedge_bivec3(lhs: Vec3, rhs: Bivec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_left_contraction_bivec3(lhs: Vec3, rhs: Bivec3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_commutator_bivec3(lhs: Vec3, rhs: Bivec3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_dot_bivec3(lhs: Vec3, rhs: Bivec3) -> Bivec3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_geometric_rotor3(lhs: Vec3, rhs: Rotor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_geometric_rotor3(lhs: Vec3, rhs: Rotor3) -> Rotor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_dot_rotor3(lhs: Vec3, rhs: Rotor3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_wedge_rotor3(lhs: Vec3, rhs: Rotor3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_wedge_rotor3(lhs: Vec3, rhs: Rotor3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_left_contraction_rotor3(lhs: Vec3, rhs: Rotor3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_right_contraction_rotor3(lhs: Vec3, rhs: Rotor3) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_commutator_rotor3(lhs: Vec3, rhs: Rotor3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_dot_rotor3(lhs: Vec3, rhs: Rotor3) -> Bivec3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_geometric_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_geometric_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_dot_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_wedge_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_wedge_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_left_contraction_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_right_contraction_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_scalar_product_multivector(lhs: Vec3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_commutator_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_vec3_anti_dot_multivector(lhs: Vec3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_rcompl(value: Bivec3) -> Vec3 {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_lcompl(value: Bivec3) -> Vec3 {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_rev(value: Bivec3) -> Bivec3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_arev(value: Bivec3) -> Bivec3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_involute(value: Bivec3) -> Bivec3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_conjugate(value: Bivec3) -> Bivec3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_dual(value: Bivec3) -> Vec3 {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_bulk(value: Bivec3) -> Bivec3 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_weight(value: Bivec3) -> Bivec3 {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_bulk_dual(value: Bivec3) -> Vec3 {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_weight_dual(value: Bivec3) -> Vec3 {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_geometric_vec3(lhs: Bivec3, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_geometric_vec3(lhs: Bivec3, rhs: Vec3) -> Rotor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_dot_vec3(lhs: Bivec3, rhs: Vec3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_wedge_vec3(lhs: Bivec3, rhs: Vec3) -> XYZ {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_wedge_vec3(lhs: Bivec3, rhs: Vec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_right_contraction_vec3(lhs: Bivec3, rhs: Vec3) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_commutator_vec3(lhs: Bivec3, rhs: Vec3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_dot_vec3(lhs: Bivec3, rhs: Vec3) -> Bivec3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_geometric_bivec3(lhs: Bivec3, rhs: Bivec3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_geometric_bivec3(lhs: Bivec3, rhs: Bivec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_dot_bivec3(lhs: Bivec3, rhs: Bivec3) -> S {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_wedge_bivec3(lhs: Bivec3, rhs: Bivec3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_left_contraction_bivec3(lhs: Bivec3, rhs: Bivec3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_right_contraction_bivec3(lhs: Bivec3, rhs: Bivec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_scalar_product_bivec3(lhs: Bivec3, rhs: Bivec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_commutator_bivec3(lhs: Bivec3, rhs: Bivec3) -> Bivec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_dot_bivec3(lhs: Bivec3, rhs: Bivec3) -> XYZ {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_geometric_rotor3(lhs: Bivec3, rhs: Rotor3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_geometric_rotor3(lhs: Bivec3, rhs: Rotor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_dot_rotor3(lhs: Bivec3, rhs: Rotor3) -> Rotor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_wedge_rotor3(lhs: Bivec3, rhs: Rotor3) -> Bivec3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_wedge_rotor3(lhs: Bivec3, rhs: Rotor3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_left_contraction_rotor3(lhs: Bivec3, rhs: Rotor3) -> S {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_right_contraction_rotor3(lhs: Bivec3, rhs: Rotor3) -> Rotor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_scalar_product_rotor3(lhs: Bivec3, rhs: Rotor3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_commutator_rotor3(lhs: Bivec3, rhs: Rotor3) -> Bivec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_dot_rotor3(lhs: Bivec3, rhs: Rotor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_geometric_multivector(lhs: Bivec3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_geometric_multivector(lhs: Bivec3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_dot_multivector(lhs: Bivec3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_wedge_multivector(lhs: Bivec3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_wedge_multivector(lhs: Bivec3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_left_contraction_multivector(lhs: Bivec3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_right_contraction_multivector(lhs: Bivec3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_scalar_product_multivector(lhs: Bivec3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_commutator_multivector(lhs: Bivec3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_bivec3_anti_dot_multivector(lhs: Bivec3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_rcompl(value: Rotor3) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_lcompl(value: Rotor3) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_rev(value: Rotor3) -> Rotor3 {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_arev(value: Rotor3) -> Rotor3 {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_involute(value: Rotor3) -> Rotor3 {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_conjugate(value: Rotor3) -> Rotor3 {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_dual(value: Rotor3) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_bulk(value: Rotor3) -> Rotor3 {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_weight(value: Rotor3) -> Rotor3 {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_bulk_dual(value: Rotor3) -> Multivector {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_weight_dual(value: Rotor3) -> Multivector {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_geometric_vec3(lhs: Rotor3, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_geometric_vec3(lhs: Rotor3, rhs: Vec3) -> Rotor3 {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_dot_vec3(lhs: Rotor3, rhs: Vec3) -> Vec3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_wedge_vec3(lhs: Rotor3, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_wedge_vec3(lhs: Rotor3, rhs: Vec3) -> S {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_left_contraction_vec3(lhs: Rotor3, rhs: Vec3) -> Vec3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_right_contraction_vec3(lhs: Rotor3, rhs: Vec3) -> Vec3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_commutator_vec3(lhs: Rotor3, rhs: Vec3) -> Vec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_dot_vec3(lhs: Rotor3, rhs: Vec3) -> Bivec3 {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_geometric_bivec3(lhs: Rotor3, rhs: Bivec3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_geometric_bivec3(lhs: Rotor3, rhs: Bivec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_dot_bivec3(lhs: Rotor3, rhs: Bivec3) -> Rotor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_wedge_bivec3(lhs: Rotor3, rhs: Bivec3) -> Bivec3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_wedge_bivec3(lhs: Rotor3, rhs: Bivec3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_left_contraction_bivec3(lhs: Rotor3, rhs: Bivec3) -> Rotor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_right_contraction_bivec3(lhs: Rotor3, rhs: Bivec3) -> S {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_scalar_product_bivec3(lhs: Rotor3, rhs: Bivec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_commutator_bivec3(lhs: Rotor3, rhs: Bivec3) -> Bivec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_dot_bivec3(lhs: Rotor3, rhs: Bivec3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_geometric_rotor3(lhs: Rotor3, rhs: Rotor3) -> Rotor3 {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_geometric_rotor3(lhs: Rotor3, rhs: Rotor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_dot_rotor3(lhs: Rotor3, rhs: Rotor3) -> Rotor3 {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_wedge_rotor3(lhs: Rotor3, rhs: Rotor3) -> Rotor3 {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_wedge_rotor3(lhs: Rotor3, rhs: Rotor3) -> Vec3 {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_left_contraction_rotor3(lhs: Rotor3, rhs: Rotor3) -> Rotor3 {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_right_contraction_rotor3(lhs: Rotor3, rhs: Rotor3) -> Rotor3 {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_scalar_product_rotor3(lhs: Rotor3, rhs: Rotor3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_commutator_rotor3(lhs: Rotor3, rhs: Rotor3) -> Bivec3 {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_dot_rotor3(lhs: Rotor3, rhs: Rotor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_geometric_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_geometric_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_dot_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_wedge_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_wedge_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_left_contraction_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_right_contraction_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_scalar_product_multivector(lhs: Rotor3, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_commutator_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_dot_multivector(lhs: Rotor3, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_sandwich_vec3(lhs: Rotor3, rhs: Vec3) -> Vec3 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_sandwich_vec3(lhs: Rotor3, rhs: Vec3) -> Vec3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_sandwich_bivec3(lhs: Rotor3, rhs: Bivec3) -> Bivec3 {
+	Sandwich::sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_rotor3_anti_sandwich_bivec3(lhs: Rotor3, rhs: Bivec3) -> Bivec3 {
+	AntiSandwich::anti_sandwich(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_rcompl(value: Multivector) -> Multivector {
+	RCompl::rcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_lcompl(value: Multivector) -> Multivector {
+	LCompl::lcompl(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_rev(value: Multivector) -> Multivector {
+	Reverse::rev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_arev(value: Multivector) -> Multivector {
+	AntiReverse::arev(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_involute(value: Multivector) -> Multivector {
+	Involute::involute(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_conjugate(value: Multivector) -> Multivector {
+	Conjugate::conjugate(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_dual(value: Multivector) -> Multivector {
+	Dual::dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_bulk(value: Multivector) -> Multivector {
+	Bulk::bulk(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_weight(value: Multivector) -> Multivector {
+	Weight::weight(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_bulk_dual(value: Multivector) -> Multivector {
+	BulkDual::bulk_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_weight_dual(value: Multivector) -> Multivector {
+	WeightDual::weight_dual(value)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_geometric_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_geometric_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_dot_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_wedge_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_wedge_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_left_contraction_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_right_contraction_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_scalar_product_vec3(lhs: Multivector, rhs: Vec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_commutator_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_dot_vec3(lhs: Multivector, rhs: Vec3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_geometric_bivec3(lhs: Multivector, rhs: Bivec3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_geometric_bivec3(lhs: Multivector, rhs: Bivec3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_dot_bivec3(lhs: Multivector, rhs: Bivec3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_wedge_bivec3(lhs: Multivector, rhs: Bivec3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_wedge_bivec3(lhs: Multivector, rhs: Bivec3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_left_contraction_bivec3(lhs: Multivector, rhs: Bivec3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_right_contraction_bivec3(lhs: Multivector, rhs: Bivec3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_scalar_product_bivec3(lhs: Multivector, rhs: Bivec3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_commutator_bivec3(lhs: Multivector, rhs: Bivec3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_dot_bivec3(lhs: Multivector, rhs: Bivec3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_geometric_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_geometric_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_dot_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_wedge_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_wedge_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_left_contraction_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_right_contraction_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_scalar_product_rotor3(lhs: Multivector, rhs: Rotor3) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_commutator_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_dot_rotor3(lhs: Multivector, rhs: Rotor3) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_geometric_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Geometric::geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_geometric_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiGeometric::anti_geometric(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_dot_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Dot::dot(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_wedge_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Wedge::wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_wedge_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiWedge::anti_wedge(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_left_contraction_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	LeftContraction::left_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_right_contraction_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	RightContraction::right_contraction(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_scalar_product_multivector(lhs: Multivector, rhs: Multivector) -> S {
+	ScalarProduct::scalar_product(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_commutator_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	Commutator::commutator(lhs, rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn vga3d_multivector_anti_dot_multivector(lhs: Multivector, rhs: Multivector) -> Multivector {
+	AntiDot::anti_dot(lhs, rhs)
+}
@@ -0,0 +1,22 @@
+pub mod blades;
+pub mod traits;
+
+// ----------------------------------------------------------------------------
+// Geometric Algebra definition helpers:
+
+/// Special zero type for completeness, and better error messages.
+/// If you get this in an error message, it is because you multiplied
+/// two dimensions that always results in zero.
+pub struct Zero {}
+
+// ----------------------------------------------------------------------------
+
+// Types:
+pub mod bivec3;
+pub mod multivector;
+pub mod rotor3;
+pub mod vec3;
+
+pub mod ffi;
+
+pub use self::{bivec3::*, blades::*, multivector::*, rotor3::*, traits::*, vec3::*};
@@ -0,0 +1,1859 @@
+//! # Multivector
+//!
+//! ## Operations
+//! ```text
+//! Multivector.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Multivector) -> Multivector
+//! Multivector.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Multivector) -> S
+//! Multivector.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Vec3) -> Multivector
+//! Vec3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec3) -> Multivector
+//! Vec3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec3) -> Multivector
+//! Vec3.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec3) -> Multivector
+//! Vec3.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Vec3) -> S
+//! Vec3.scalar_product(Multivector) -> S
+//! Multivector.commutator(Vec3) -> Multivector
+//! Vec3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec3) -> Multivector
+//! Vec3.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Bivec3) -> Multivector
+//! Bivec3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Bivec3) -> Multivector
+//! Bivec3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Bivec3) -> Multivector
+//! Bivec3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Bivec3) -> Multivector
+//! Bivec3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Bivec3) -> Multivector
+//! Bivec3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Bivec3) -> Multivector
+//! Bivec3.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Bivec3) -> Multivector
+//! Bivec3.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Bivec3) -> S
+//! Bivec3.scalar_product(Multivector) -> S
+//! Multivector.commutator(Bivec3) -> Multivector
+//! Bivec3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Bivec3) -> Multivector
+//! Bivec3.anti_dot(Multivector) -> Multivector
+//! Multivector.geometric(Rotor3) -> Multivector
+//! Rotor3.geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Multivector) -> Multivector
+//! Multivector.dot(Rotor3) -> Multivector
+//! Rotor3.dot(Multivector) -> Multivector
+//! Multivector.wedge(Rotor3) -> Multivector
+//! Rotor3.wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Rotor3) -> Multivector
+//! Rotor3.anti_wedge(Multivector) -> Multivector
+//! Multivector.left_contraction(Rotor3) -> Multivector
+//! Rotor3.left_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Rotor3) -> Multivector
+//! Rotor3.right_contraction(Multivector) -> Multivector
+//! Multivector.scalar_product(Rotor3) -> S
+//! Rotor3.scalar_product(Multivector) -> S
+//! Multivector.commutator(Rotor3) -> Multivector
+//! Rotor3.commutator(Multivector) -> Multivector
+//! Multivector.anti_dot(Rotor3) -> Multivector
+//! Rotor3.anti_dot(Multivector) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Multivector {
+	pub s: S,
+	pub x: X,
+	pub y: Y,
+	pub z: Z,
+	pub yz: YZ,
+	pub zx: ZX,
+	pub xy: XY,
+	pub xyz: XYZ,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Multivector {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Multivector {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Multivector {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.z.abs_diff_eq(&other.z, epsilon)
+			&& self.yz.abs_diff_eq(&other.yz, epsilon)
+			&& self.zx.abs_diff_eq(&other.zx, epsilon)
+			&& self.xy.abs_diff_eq(&other.xy, epsilon)
+			&& self.xyz.abs_diff_eq(&other.xyz, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Multivector {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.z.relative_eq(&other.z, epsilon, max_relative)
+			&& self.yz.relative_eq(&other.yz, epsilon, max_relative)
+			&& self.zx.relative_eq(&other.zx, epsilon, max_relative)
+			&& self.xy.relative_eq(&other.xy, epsilon, max_relative)
+			&& self.xyz.relative_eq(&other.xyz, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Multivector {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: self.xyz.rcompl(),
+			x: self.yz.rcompl(),
+			y: self.zx.rcompl(),
+			z: self.xy.rcompl(),
+			yz: self.x.rcompl(),
+			zx: self.y.rcompl(),
+			xy: self.z.rcompl(),
+			xyz: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Multivector {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Multivector {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: self.xyz.lcompl(),
+			x: self.yz.lcompl(),
+			y: self.zx.lcompl(),
+			z: self.xy.lcompl(),
+			yz: self.x.lcompl(),
+			zx: self.y.lcompl(),
+			xy: self.z.lcompl(),
+			xyz: self.s.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Multivector {
+	fn rev(self) -> Self {
+		Multivector {
+			s: self.s,
+			x: self.x,
+			y: self.y,
+			z: self.z,
+			yz: -self.yz,
+			zx: -self.zx,
+			xy: -self.xy,
+			xyz: -self.xyz,
+		}
+	}
+}
+
+impl AntiReverse for Multivector {
+	fn arev(self) -> Self {
+		Multivector {
+			s: -self.s,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+			xyz: self.xyz,
+		}
+	}
+}
+
+impl Involute for Multivector {
+	fn involute(self) -> Self {
+		Multivector {
+			s: self.s,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+			xyz: -self.xyz,
+		}
+	}
+}
+
+impl Conjugate for Multivector {
+	fn conjugate(self) -> Self {
+		Multivector {
+			s: self.s,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			yz: -self.yz,
+			zx: -self.zx,
+			xy: -self.xy,
+			xyz: self.xyz,
+		}
+	}
+}
+
+impl Dual for Multivector {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: self.xyz.dual(),
+			x: self.yz.dual(),
+			y: self.zx.dual(),
+			z: self.xy.dual(),
+			yz: self.x.dual(),
+			zx: self.y.dual(),
+			xy: self.z.dual(),
+			xyz: self.s.dual(),
+		}
+	}
+}
+
+impl Bulk for Multivector {
+	type Output = Multivector;
+	fn bulk(self) -> Self::Output {
+		Multivector {
+			s: self.s,
+			x: self.x,
+			y: self.y,
+			z: self.z,
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+			xyz: self.xyz,
+		}
+	}
+}
+
+impl Weight for Multivector {
+	type Output = Multivector;
+	fn weight(self) -> Self::Output {
+		Multivector {
+			s: self.s,
+			x: self.x,
+			y: self.y,
+			z: self.z,
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+			xyz: self.xyz,
+		}
+	}
+}
+
+impl BulkDual for Multivector {
+	type Output = Multivector;
+	fn bulk_dual(self) -> Self::Output {
+		Multivector {
+			s: self.xyz.bulk_dual(),
+			x: self.yz.bulk_dual(),
+			y: self.zx.bulk_dual(),
+			z: self.xy.bulk_dual(),
+			yz: self.x.bulk_dual(),
+			zx: self.y.bulk_dual(),
+			xy: self.z.bulk_dual(),
+			xyz: self.s.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Multivector {
+	type Output = Multivector;
+	fn weight_dual(self) -> Self::Output {
+		Multivector {
+			s: self.xyz.weight_dual(),
+			x: self.yz.weight_dual(),
+			y: self.zx.weight_dual(),
+			z: self.xy.weight_dual(),
+			yz: self.x.weight_dual(),
+			zx: self.y.weight_dual(),
+			xy: self.z.weight_dual(),
+			xyz: self.s.weight_dual(),
+		}
+	}
+}
+
+// Omitted: Multivector.square()  (not a scalar)
+
+impl Norm for Multivector {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0
+			+ self.x.0 * self.x.0
+			+ self.y.0 * self.y.0
+			+ self.z.0 * self.z.0
+			+ self.yz.0 * self.yz.0
+			+ self.zx.0 * self.zx.0
+			+ self.xy.0 * self.xy.0
+			+ self.xyz.0 * self.xyz.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0
+			+ self.x.0 * self.x.0
+			+ self.y.0 * self.y.0
+			+ self.z.0 * self.z.0
+			+ self.yz.0 * self.yz.0
+			+ self.zx.0 * self.zx.0
+			+ self.xy.0 * self.xy.0
+			+ self.xyz.0 * self.xyz.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Multivector {
+			s: self.s * scale,
+			x: self.x * scale,
+			y: self.y * scale,
+			z: self.z * scale,
+			yz: self.yz * scale,
+			zx: self.zx * scale,
+			xy: self.xy * scale,
+			xyz: self.xyz * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Multivector {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 8] {
+		[
+			self.s.0, self.x.0, self.y.0, self.z.0, self.yz.0, self.zx.0, self.xy.0, self.xyz.0,
+		]
+	}
+}
+
+impl From<[f64; 8]> for Multivector {
+	fn from(a: [f64; 8]) -> Self {
+		Multivector {
+			s: S(a[0]),
+			x: X(a[1]),
+			y: Y(a[2]),
+			z: Z(a[3]),
+			yz: YZ(a[4]),
+			zx: ZX(a[5]),
+			xy: XY(a[6]),
+			xyz: XYZ(a[7]),
+		}
+	}
+}
+
+impl From<Multivector> for [f64; 8] {
+	fn from(v: Multivector) -> Self {
+		v.to_array()
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Vec3:
+
+// Multivector.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : Z(self.s.0 * rhs.z.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.xyz.0 * rhs.x.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx : -ZX(self.x.0 * rhs.z.0) + ZX(self.xyz.0 * rhs.y.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) + XY(self.xyz.0 * rhs.z.0) - XY(self.y.0 * rhs.x.0),
+		//     xyz: XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y) + self.zx.geometric(rhs.z),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x) + self.yz.geometric(rhs.z),
+			z: self.s.geometric(rhs.z) + self.yz.geometric(rhs.y) + self.zx.geometric(rhs.x),
+			yz: self.xyz.geometric(rhs.x) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zx: self.x.geometric(rhs.z) + self.xyz.geometric(rhs.y) + self.z.geometric(rhs.x),
+			xy: self.x.geometric(rhs.y) + self.xyz.geometric(rhs.z) + self.y.geometric(rhs.x),
+			xyz: self.xy.geometric(rhs.z) + self.yz.geometric(rhs.x) + self.zx.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.xy.0 * rhs.z.0) + S(self.yz.0 * rhs.x.0) + S(self.zx.0 * rhs.y.0),
+		//     x  : X(self.xyz.0 * rhs.x.0) - X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y  : Y(self.x.0 * rhs.z.0) + Y(self.xyz.0 * rhs.y.0) - Y(self.z.0 * rhs.x.0),
+		//     z  : -Z(self.x.0 * rhs.y.0) + Z(self.xyz.0 * rhs.z.0) + Z(self.y.0 * rhs.x.0),
+		//     yz : -YZ(self.s.0 * rhs.x.0) + YZ(self.xy.0 * rhs.y.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx : -ZX(self.s.0 * rhs.y.0) - ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0),
+		//     xy : -XY(self.s.0 * rhs.z.0) - XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		//     xyz: -XYZ(self.x.0 * rhs.x.0) - XYZ(self.y.0 * rhs.y.0) - XYZ(self.z.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: self.xy.anti_geometric(rhs.z) + self.yz.anti_geometric(rhs.x) + self.zx.anti_geometric(rhs.y),
+			x: self.xyz.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+			y: self.x.anti_geometric(rhs.z) + self.xyz.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.x),
+			z: self.x.anti_geometric(rhs.y) + self.xyz.anti_geometric(rhs.z) + self.y.anti_geometric(rhs.x),
+			yz: self.s.anti_geometric(rhs.x) + self.xy.anti_geometric(rhs.y) + self.zx.anti_geometric(rhs.z),
+			zx: self.s.anti_geometric(rhs.y) + self.xy.anti_geometric(rhs.x) + self.yz.anti_geometric(rhs.z),
+			xy: self.s.anti_geometric(rhs.z) + self.yz.anti_geometric(rhs.y) + self.zx.anti_geometric(rhs.x),
+			xyz: self.x.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Multivector.dot(Vec3) -> Multivector
+impl Dot<Vec3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : Z(self.s.0 * rhs.z.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.xyz.0 * rhs.x.0),
+		//     zx : ZX(self.xyz.0 * rhs.y.0),
+		//     xy : XY(self.xyz.0 * rhs.z.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y) + self.zx.dot(rhs.z),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x) + self.yz.dot(rhs.z),
+			z: self.s.dot(rhs.z) + self.yz.dot(rhs.y) + self.zx.dot(rhs.x),
+			yz: self.xyz.dot(rhs.x),
+			zx: self.xyz.dot(rhs.y),
+			xy: self.xyz.dot(rhs.z),
+			xyz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     z  : Z(self.s.0 * rhs.z.0),
+		//     yz : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyz: XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			z: self.s.wedge(rhs.z),
+			yz: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			zx: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			xyz: self.xy.wedge(rhs.z) + self.yz.wedge(rhs.x) + self.zx.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Vec3) -> Multivector
+impl AntiWedge<Vec3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.xy.0 * rhs.z.0) + S(self.yz.0 * rhs.x.0) + S(self.zx.0 * rhs.y.0),
+		//     x  : X(self.xyz.0 * rhs.x.0),
+		//     y  : Y(self.xyz.0 * rhs.y.0),
+		//     z  : Z(self.xyz.0 * rhs.z.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.anti_wedge(rhs.z) + self.yz.anti_wedge(rhs.x) + self.zx.anti_wedge(rhs.y),
+			x: self.xyz.anti_wedge(rhs.x),
+			y: self.xyz.anti_wedge(rhs.y),
+			z: self.xyz.anti_wedge(rhs.z),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Vec3) -> Multivector
+impl LeftContraction<Vec3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     z  : Z(self.s.0 * rhs.z.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z),
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			z: self.s.left_contraction(rhs.z),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Vec3) -> Multivector
+impl RightContraction<Vec3> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.xyz.0 * rhs.x.0),
+		//     zx : ZX(self.xyz.0 * rhs.y.0),
+		//     xy : XY(self.xyz.0 * rhs.z.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z),
+			x: self.xy.right_contraction(rhs.y) + self.zx.right_contraction(rhs.z),
+			y: self.xy.right_contraction(rhs.x) + self.yz.right_contraction(rhs.z),
+			z: self.yz.right_contraction(rhs.y) + self.zx.right_contraction(rhs.x),
+			yz: self.xyz.right_contraction(rhs.x),
+			zx: self.xyz.right_contraction(rhs.y),
+			xy: self.xyz.right_contraction(rhs.z),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Vec3) -> S
+impl ScalarProduct<Vec3> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Multivector.commutator(Vec3) -> Multivector
+impl Commutator<Vec3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y) + self.zx.commutator(rhs.z),
+			y: self.xy.commutator(rhs.x) + self.yz.commutator(rhs.z),
+			z: self.yz.commutator(rhs.y) + self.zx.commutator(rhs.x),
+			yz: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			zx: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Vec3) -> Multivector
+impl AntiDot<Vec3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xyz.0 * rhs.x.0),
+		//     y  : Y(self.xyz.0 * rhs.y.0),
+		//     z  : Z(self.xyz.0 * rhs.z.0),
+		//     yz : -YZ(self.s.0 * rhs.x.0) + YZ(self.xy.0 * rhs.y.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx : -ZX(self.s.0 * rhs.y.0) - ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0),
+		//     xy : -XY(self.s.0 * rhs.z.0) - XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		//     xyz: -XYZ(self.x.0 * rhs.x.0) - XYZ(self.y.0 * rhs.y.0) - XYZ(self.z.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xyz.anti_dot(rhs.x),
+			y: self.xyz.anti_dot(rhs.y),
+			z: self.xyz.anti_dot(rhs.z),
+			yz: self.s.anti_dot(rhs.x) + self.xy.anti_dot(rhs.y) + self.zx.anti_dot(rhs.z),
+			zx: self.s.anti_dot(rhs.y) + self.xy.anti_dot(rhs.x) + self.yz.anti_dot(rhs.z),
+			xy: self.s.anti_dot(rhs.z) + self.yz.anti_dot(rhs.y) + self.zx.anti_dot(rhs.x),
+			xyz: self.x.anti_dot(rhs.x) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Bivec3:
+
+// Multivector.geometric(Bivec3) -> Multivector
+impl Geometric<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : -X(self.xyz.0 * rhs.yz.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.xyz.0 * rhs.zx.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) - Z(self.xyz.0 * rhs.xy.0) + Z(self.y.0 * rhs.yz.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) - ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		//     xyz: XYZ(self.x.0 * rhs.yz.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: self.xy.geometric(rhs.xy) + self.yz.geometric(rhs.yz) + self.zx.geometric(rhs.zx),
+			x: self.xyz.geometric(rhs.yz) + self.y.geometric(rhs.xy) + self.z.geometric(rhs.zx),
+			y: self.x.geometric(rhs.xy) + self.xyz.geometric(rhs.zx) + self.z.geometric(rhs.yz),
+			z: self.x.geometric(rhs.zx) + self.xyz.geometric(rhs.xy) + self.y.geometric(rhs.yz),
+			yz: self.s.geometric(rhs.yz) + self.xy.geometric(rhs.zx) + self.zx.geometric(rhs.xy),
+			zx: self.s.geometric(rhs.zx) + self.xy.geometric(rhs.yz) + self.yz.geometric(rhs.xy),
+			xy: self.s.geometric(rhs.xy) + self.yz.geometric(rhs.zx) + self.zx.geometric(rhs.yz),
+			xyz: self.x.geometric(rhs.yz) + self.y.geometric(rhs.zx) + self.z.geometric(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::Mul<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Bivec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Bivec3) -> Multivector
+impl AntiGeometric<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.yz.0) + S(self.y.0 * rhs.zx.0) + S(self.z.0 * rhs.xy.0),
+		//     x  : X(self.s.0 * rhs.yz.0) - X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.s.0 * rhs.zx.0) + Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z  : Z(self.s.0 * rhs.xy.0) + Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : YZ(self.xyz.0 * rhs.yz.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx : ZX(self.x.0 * rhs.xy.0) + ZX(self.xyz.0 * rhs.zx.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy : -XY(self.x.0 * rhs.zx.0) + XY(self.xyz.0 * rhs.xy.0) + XY(self.y.0 * rhs.yz.0),
+		//     xyz: XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: self.x.anti_geometric(rhs.yz) + self.y.anti_geometric(rhs.zx) + self.z.anti_geometric(rhs.xy),
+			x: self.s.anti_geometric(rhs.yz) + self.xy.anti_geometric(rhs.zx) + self.zx.anti_geometric(rhs.xy),
+			y: self.s.anti_geometric(rhs.zx) + self.xy.anti_geometric(rhs.yz) + self.yz.anti_geometric(rhs.xy),
+			z: self.s.anti_geometric(rhs.xy) + self.yz.anti_geometric(rhs.zx) + self.zx.anti_geometric(rhs.yz),
+			yz: self.xyz.anti_geometric(rhs.yz) + self.y.anti_geometric(rhs.xy) + self.z.anti_geometric(rhs.zx),
+			zx: self.x.anti_geometric(rhs.xy) + self.xyz.anti_geometric(rhs.zx) + self.z.anti_geometric(rhs.yz),
+			xy: self.x.anti_geometric(rhs.zx) + self.xyz.anti_geometric(rhs.xy) + self.y.anti_geometric(rhs.yz),
+			xyz: self.xy.anti_geometric(rhs.xy) + self.yz.anti_geometric(rhs.yz) + self.zx.anti_geometric(rhs.zx),
+		}
+	}
+}
+
+// Multivector.dot(Bivec3) -> Multivector
+impl Dot<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : -X(self.xyz.0 * rhs.yz.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.xyz.0 * rhs.zx.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) - Z(self.xyz.0 * rhs.xy.0) + Z(self.y.0 * rhs.yz.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.dot(rhs.xy) + self.yz.dot(rhs.yz) + self.zx.dot(rhs.zx),
+			x: self.xyz.dot(rhs.yz) + self.y.dot(rhs.xy) + self.z.dot(rhs.zx),
+			y: self.x.dot(rhs.xy) + self.xyz.dot(rhs.zx) + self.z.dot(rhs.yz),
+			z: self.x.dot(rhs.zx) + self.xyz.dot(rhs.xy) + self.y.dot(rhs.yz),
+			yz: self.s.dot(rhs.yz),
+			zx: self.s.dot(rhs.zx),
+			xy: self.s.dot(rhs.xy),
+			xyz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Bivec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Bivec3) -> Multivector
+impl Wedge<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : Default::default(),
+		//     y  : Default::default(),
+		//     z  : Default::default(),
+		//     yz : YZ(self.s.0 * rhs.yz.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyz: XYZ(self.x.0 * rhs.yz.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: Default::default(),
+			y: Default::default(),
+			z: Default::default(),
+			yz: self.s.wedge(rhs.yz),
+			zx: self.s.wedge(rhs.zx),
+			xy: self.s.wedge(rhs.xy),
+			xyz: self.x.wedge(rhs.yz) + self.y.wedge(rhs.zx) + self.z.wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Bivec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Bivec3) -> Multivector
+impl AntiWedge<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.yz.0) + S(self.y.0 * rhs.zx.0) + S(self.z.0 * rhs.xy.0),
+		//     x  : -X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z  : Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : YZ(self.xyz.0 * rhs.yz.0),
+		//     zx : ZX(self.xyz.0 * rhs.zx.0),
+		//     xy : XY(self.xyz.0 * rhs.xy.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.x.anti_wedge(rhs.yz) + self.y.anti_wedge(rhs.zx) + self.z.anti_wedge(rhs.xy),
+			x: self.xy.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.xy),
+			y: self.xy.anti_wedge(rhs.yz) + self.yz.anti_wedge(rhs.xy),
+			z: self.yz.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.yz),
+			yz: self.xyz.anti_wedge(rhs.yz),
+			zx: self.xyz.anti_wedge(rhs.zx),
+			xy: self.xyz.anti_wedge(rhs.xy),
+			xyz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Bivec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Bivec3) -> Multivector
+impl LeftContraction<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.left_contraction(rhs.xy) + self.yz.left_contraction(rhs.yz) + self.zx.left_contraction(rhs.zx),
+			x: self.y.left_contraction(rhs.xy) + self.z.left_contraction(rhs.zx),
+			y: self.x.left_contraction(rhs.xy) + self.z.left_contraction(rhs.yz),
+			z: self.x.left_contraction(rhs.zx) + self.y.left_contraction(rhs.yz),
+			yz: self.s.left_contraction(rhs.yz),
+			zx: self.s.left_contraction(rhs.zx),
+			xy: self.s.left_contraction(rhs.xy),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Bivec3) -> Multivector
+impl RightContraction<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : -X(self.xyz.0 * rhs.yz.0),
+		//     y  : -Y(self.xyz.0 * rhs.zx.0),
+		//     z  : -Z(self.xyz.0 * rhs.xy.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.xy.right_contraction(rhs.xy)
+				+ self.yz.right_contraction(rhs.yz)
+				+ self.zx.right_contraction(rhs.zx),
+			x: self.xyz.right_contraction(rhs.yz),
+			y: self.xyz.right_contraction(rhs.zx),
+			z: self.xyz.right_contraction(rhs.xy),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Multivector.scalar_product(Bivec3) -> S
+impl ScalarProduct<Bivec3> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Bivec3) -> Self::Output {
+		// -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.xy.scalar_product(rhs.xy) + self.yz.scalar_product(rhs.yz) + self.zx.scalar_product(rhs.zx)
+	}
+}
+
+// Multivector.commutator(Bivec3) -> Multivector
+impl Commutator<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		//     yz : YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx : -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy : -XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.xy) + self.z.commutator(rhs.zx),
+			y: self.x.commutator(rhs.xy) + self.z.commutator(rhs.yz),
+			z: self.x.commutator(rhs.zx) + self.y.commutator(rhs.yz),
+			yz: self.xy.commutator(rhs.zx) + self.zx.commutator(rhs.xy),
+			zx: self.xy.commutator(rhs.yz) + self.yz.commutator(rhs.xy),
+			xy: self.yz.commutator(rhs.zx) + self.zx.commutator(rhs.yz),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Bivec3) -> Multivector
+impl AntiDot<Bivec3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yz.0),
+		//     y  : Y(self.s.0 * rhs.zx.0),
+		//     z  : Z(self.s.0 * rhs.xy.0),
+		//     yz : YZ(self.xyz.0 * rhs.yz.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx : ZX(self.x.0 * rhs.xy.0) + ZX(self.xyz.0 * rhs.zx.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy : -XY(self.x.0 * rhs.zx.0) + XY(self.xyz.0 * rhs.xy.0) + XY(self.y.0 * rhs.yz.0),
+		//     xyz: XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.yz),
+			y: self.s.anti_dot(rhs.zx),
+			z: self.s.anti_dot(rhs.xy),
+			yz: self.xyz.anti_dot(rhs.yz) + self.y.anti_dot(rhs.xy) + self.z.anti_dot(rhs.zx),
+			zx: self.x.anti_dot(rhs.xy) + self.xyz.anti_dot(rhs.zx) + self.z.anti_dot(rhs.yz),
+			xy: self.x.anti_dot(rhs.zx) + self.xyz.anti_dot(rhs.xy) + self.y.anti_dot(rhs.yz),
+			xyz: self.xy.anti_dot(rhs.xy) + self.yz.anti_dot(rhs.yz) + self.zx.anti_dot(rhs.zx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Rotor3:
+
+// Multivector.geometric(Rotor3) -> Multivector
+impl Geometric<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.xyz.0 * rhs.yz.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.xyz.0 * rhs.zx.0) + Y(self.y.0 * rhs.s.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) - Z(self.xyz.0 * rhs.xy.0) + Z(self.y.0 * rhs.yz.0) + Z(self.z.0 * rhs.s.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.xy.0 * rhs.zx.0) + YZ(self.yz.0 * rhs.s.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) - ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		//     xyz: XYZ(self.x.0 * rhs.yz.0) + XYZ(self.xyz.0 * rhs.s.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s)
+				+ self.xy.geometric(rhs.xy)
+				+ self.yz.geometric(rhs.yz)
+				+ self.zx.geometric(rhs.zx),
+			x: self.x.geometric(rhs.s)
+				+ self.xyz.geometric(rhs.yz)
+				+ self.y.geometric(rhs.xy)
+				+ self.z.geometric(rhs.zx),
+			y: self.x.geometric(rhs.xy)
+				+ self.xyz.geometric(rhs.zx)
+				+ self.y.geometric(rhs.s)
+				+ self.z.geometric(rhs.yz),
+			z: self.x.geometric(rhs.zx)
+				+ self.xyz.geometric(rhs.xy)
+				+ self.y.geometric(rhs.yz)
+				+ self.z.geometric(rhs.s),
+			yz: self.s.geometric(rhs.yz)
+				+ self.xy.geometric(rhs.zx)
+				+ self.yz.geometric(rhs.s)
+				+ self.zx.geometric(rhs.xy),
+			zx: self.s.geometric(rhs.zx)
+				+ self.xy.geometric(rhs.yz)
+				+ self.yz.geometric(rhs.xy)
+				+ self.zx.geometric(rhs.s),
+			xy: self.s.geometric(rhs.xy)
+				+ self.xy.geometric(rhs.s)
+				+ self.yz.geometric(rhs.zx)
+				+ self.zx.geometric(rhs.yz),
+			xyz: self.x.geometric(rhs.yz)
+				+ self.xyz.geometric(rhs.s)
+				+ self.y.geometric(rhs.zx)
+				+ self.z.geometric(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Rotor3) -> Multivector
+impl AntiGeometric<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.yz.0) + S(self.xyz.0 * rhs.s.0) + S(self.y.0 * rhs.zx.0) + S(self.z.0 * rhs.xy.0),
+		//     x  : X(self.s.0 * rhs.yz.0) - X(self.xy.0 * rhs.zx.0) + X(self.yz.0 * rhs.s.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.s.0 * rhs.zx.0) + Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0) + Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.s.0 * rhs.xy.0) + Z(self.xy.0 * rhs.s.0) + Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : -YZ(self.x.0 * rhs.s.0) + YZ(self.xyz.0 * rhs.yz.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx : ZX(self.x.0 * rhs.xy.0) + ZX(self.xyz.0 * rhs.zx.0) - ZX(self.y.0 * rhs.s.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy : -XY(self.x.0 * rhs.zx.0) + XY(self.xyz.0 * rhs.xy.0) + XY(self.y.0 * rhs.yz.0) - XY(self.z.0 * rhs.s.0),
+		//     xyz: -XYZ(self.s.0 * rhs.s.0) + XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: self.x.anti_geometric(rhs.yz)
+				+ self.xyz.anti_geometric(rhs.s)
+				+ self.y.anti_geometric(rhs.zx)
+				+ self.z.anti_geometric(rhs.xy),
+			x: self.s.anti_geometric(rhs.yz)
+				+ self.xy.anti_geometric(rhs.zx)
+				+ self.yz.anti_geometric(rhs.s)
+				+ self.zx.anti_geometric(rhs.xy),
+			y: self.s.anti_geometric(rhs.zx)
+				+ self.xy.anti_geometric(rhs.yz)
+				+ self.yz.anti_geometric(rhs.xy)
+				+ self.zx.anti_geometric(rhs.s),
+			z: self.s.anti_geometric(rhs.xy)
+				+ self.xy.anti_geometric(rhs.s)
+				+ self.yz.anti_geometric(rhs.zx)
+				+ self.zx.anti_geometric(rhs.yz),
+			yz: self.x.anti_geometric(rhs.s)
+				+ self.xyz.anti_geometric(rhs.yz)
+				+ self.y.anti_geometric(rhs.xy)
+				+ self.z.anti_geometric(rhs.zx),
+			zx: self.x.anti_geometric(rhs.xy)
+				+ self.xyz.anti_geometric(rhs.zx)
+				+ self.y.anti_geometric(rhs.s)
+				+ self.z.anti_geometric(rhs.yz),
+			xy: self.x.anti_geometric(rhs.zx)
+				+ self.xyz.anti_geometric(rhs.xy)
+				+ self.y.anti_geometric(rhs.yz)
+				+ self.z.anti_geometric(rhs.s),
+			xyz: self.s.anti_geometric(rhs.s)
+				+ self.xy.anti_geometric(rhs.xy)
+				+ self.yz.anti_geometric(rhs.yz)
+				+ self.zx.anti_geometric(rhs.zx),
+		}
+	}
+}
+
+// Multivector.dot(Rotor3) -> Multivector
+impl Dot<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.xyz.0 * rhs.yz.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.xyz.0 * rhs.zx.0) + Y(self.y.0 * rhs.s.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) - Z(self.xyz.0 * rhs.xy.0) + Z(self.y.0 * rhs.yz.0) + Z(self.z.0 * rhs.s.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyz: XYZ(self.xyz.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy) + self.yz.dot(rhs.yz) + self.zx.dot(rhs.zx),
+			x: self.x.dot(rhs.s) + self.xyz.dot(rhs.yz) + self.y.dot(rhs.xy) + self.z.dot(rhs.zx),
+			y: self.x.dot(rhs.xy) + self.xyz.dot(rhs.zx) + self.y.dot(rhs.s) + self.z.dot(rhs.yz),
+			z: self.x.dot(rhs.zx) + self.xyz.dot(rhs.xy) + self.y.dot(rhs.yz) + self.z.dot(rhs.s),
+			yz: self.s.dot(rhs.yz) + self.yz.dot(rhs.s),
+			zx: self.s.dot(rhs.zx) + self.zx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
+			xyz: self.xyz.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Rotor3) -> Multivector
+impl Wedge<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     z  : Z(self.z.0 * rhs.s.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyz: XYZ(self.x.0 * rhs.yz.0) + XYZ(self.xyz.0 * rhs.s.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			z: self.z.wedge(rhs.s),
+			yz: self.s.wedge(rhs.yz) + self.yz.wedge(rhs.s),
+			zx: self.s.wedge(rhs.zx) + self.zx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy) + self.xy.wedge(rhs.s),
+			xyz: self.x.wedge(rhs.yz) + self.xyz.wedge(rhs.s) + self.y.wedge(rhs.zx) + self.z.wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Rotor3) -> Multivector
+impl AntiWedge<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.yz.0) + S(self.xyz.0 * rhs.s.0) + S(self.y.0 * rhs.zx.0) + S(self.z.0 * rhs.xy.0),
+		//     x  : -X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z  : Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : YZ(self.xyz.0 * rhs.yz.0),
+		//     zx : ZX(self.xyz.0 * rhs.zx.0),
+		//     xy : XY(self.xyz.0 * rhs.xy.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.x.anti_wedge(rhs.yz)
+				+ self.xyz.anti_wedge(rhs.s)
+				+ self.y.anti_wedge(rhs.zx)
+				+ self.z.anti_wedge(rhs.xy),
+			x: self.xy.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.xy),
+			y: self.xy.anti_wedge(rhs.yz) + self.yz.anti_wedge(rhs.xy),
+			z: self.yz.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.yz),
+			yz: self.xyz.anti_wedge(rhs.yz),
+			zx: self.xyz.anti_wedge(rhs.zx),
+			xy: self.xyz.anti_wedge(rhs.xy),
+			xyz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Rotor3) -> Multivector
+impl LeftContraction<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s)
+				+ self.xy.left_contraction(rhs.xy)
+				+ self.yz.left_contraction(rhs.yz)
+				+ self.zx.left_contraction(rhs.zx),
+			x: self.y.left_contraction(rhs.xy) + self.z.left_contraction(rhs.zx),
+			y: self.x.left_contraction(rhs.xy) + self.z.left_contraction(rhs.yz),
+			z: self.x.left_contraction(rhs.zx) + self.y.left_contraction(rhs.yz),
+			yz: self.s.left_contraction(rhs.yz),
+			zx: self.s.left_contraction(rhs.zx),
+			xy: self.s.left_contraction(rhs.xy),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Multivector.right_contraction(Rotor3) -> Multivector
+impl RightContraction<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.xyz.0 * rhs.yz.0),
+		//     y  : -Y(self.xyz.0 * rhs.zx.0) + Y(self.y.0 * rhs.s.0),
+		//     z  : -Z(self.xyz.0 * rhs.xy.0) + Z(self.z.0 * rhs.s.0),
+		//     yz : YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyz: XYZ(self.xyz.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s)
+				+ self.xy.right_contraction(rhs.xy)
+				+ self.yz.right_contraction(rhs.yz)
+				+ self.zx.right_contraction(rhs.zx),
+			x: self.x.right_contraction(rhs.s) + self.xyz.right_contraction(rhs.yz),
+			y: self.xyz.right_contraction(rhs.zx) + self.y.right_contraction(rhs.s),
+			z: self.xyz.right_contraction(rhs.xy) + self.z.right_contraction(rhs.s),
+			yz: self.yz.right_contraction(rhs.s),
+			zx: self.zx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+			xyz: self.xyz.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Multivector.scalar_product(Rotor3) -> S
+impl ScalarProduct<Rotor3> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor3) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.s.scalar_product(rhs.s)
+			+ self.xy.scalar_product(rhs.xy)
+			+ self.yz.scalar_product(rhs.yz)
+			+ self.zx.scalar_product(rhs.zx)
+	}
+}
+
+// Multivector.commutator(Rotor3) -> Multivector
+impl Commutator<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		//     yz : YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx : -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy : -XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.xy) + self.z.commutator(rhs.zx),
+			y: self.x.commutator(rhs.xy) + self.z.commutator(rhs.yz),
+			z: self.x.commutator(rhs.zx) + self.y.commutator(rhs.yz),
+			yz: self.xy.commutator(rhs.zx) + self.zx.commutator(rhs.xy),
+			zx: self.xy.commutator(rhs.yz) + self.yz.commutator(rhs.xy),
+			xy: self.yz.commutator(rhs.zx) + self.zx.commutator(rhs.yz),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Rotor3) -> Multivector
+impl AntiDot<Rotor3> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.xyz.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.yz.0) + X(self.yz.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.zx.0) + Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.s.0 * rhs.xy.0) + Z(self.xy.0 * rhs.s.0),
+		//     yz : -YZ(self.x.0 * rhs.s.0) + YZ(self.xyz.0 * rhs.yz.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx : ZX(self.x.0 * rhs.xy.0) + ZX(self.xyz.0 * rhs.zx.0) - ZX(self.y.0 * rhs.s.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy : -XY(self.x.0 * rhs.zx.0) + XY(self.xyz.0 * rhs.xy.0) + XY(self.y.0 * rhs.yz.0) - XY(self.z.0 * rhs.s.0),
+		//     xyz: -XYZ(self.s.0 * rhs.s.0) + XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: self.xyz.anti_dot(rhs.s),
+			x: self.s.anti_dot(rhs.yz) + self.yz.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.zx) + self.zx.anti_dot(rhs.s),
+			z: self.s.anti_dot(rhs.xy) + self.xy.anti_dot(rhs.s),
+			yz: self.x.anti_dot(rhs.s) + self.xyz.anti_dot(rhs.yz) + self.y.anti_dot(rhs.xy) + self.z.anti_dot(rhs.zx),
+			zx: self.x.anti_dot(rhs.xy) + self.xyz.anti_dot(rhs.zx) + self.y.anti_dot(rhs.s) + self.z.anti_dot(rhs.yz),
+			xy: self.x.anti_dot(rhs.zx) + self.xyz.anti_dot(rhs.xy) + self.y.anti_dot(rhs.yz) + self.z.anti_dot(rhs.s),
+			xyz: self.s.anti_dot(rhs.s)
+				+ self.xy.anti_dot(rhs.xy)
+				+ self.yz.anti_dot(rhs.yz)
+				+ self.zx.anti_dot(rhs.zx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Multivector OP Multivector:
+
+// Multivector.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Multivector {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) - S(self.xyz.0 * rhs.xyz.0) + S(self.y.0 * rhs.y.0) - S(self.yz.0 * rhs.yz.0) + S(self.z.0 * rhs.z.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.x.0 * rhs.s.0) + X(self.xy.0 * rhs.y.0) - X(self.xyz.0 * rhs.yz.0) - X(self.y.0 * rhs.xy.0) - X(self.yz.0 * rhs.xyz.0) + X(self.z.0 * rhs.zx.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : Y(self.s.0 * rhs.y.0) + Y(self.x.0 * rhs.xy.0) - Y(self.xy.0 * rhs.x.0) - Y(self.xyz.0 * rhs.zx.0) + Y(self.y.0 * rhs.s.0) + Y(self.yz.0 * rhs.z.0) - Y(self.z.0 * rhs.yz.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z  : Z(self.s.0 * rhs.z.0) - Z(self.x.0 * rhs.zx.0) - Z(self.xy.0 * rhs.xyz.0) - Z(self.xyz.0 * rhs.xy.0) + Z(self.y.0 * rhs.yz.0) - Z(self.yz.0 * rhs.y.0) + Z(self.z.0 * rhs.s.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.x.0 * rhs.xyz.0) + YZ(self.xy.0 * rhs.zx.0) + YZ(self.xyz.0 * rhs.x.0) + YZ(self.y.0 * rhs.z.0) + YZ(self.yz.0 * rhs.s.0) - YZ(self.z.0 * rhs.y.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) - ZX(self.x.0 * rhs.z.0) - ZX(self.xy.0 * rhs.yz.0) + ZX(self.xyz.0 * rhs.y.0) + ZX(self.y.0 * rhs.xyz.0) + ZX(self.yz.0 * rhs.xy.0) + ZX(self.z.0 * rhs.x.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.x.0 * rhs.y.0) + XY(self.xy.0 * rhs.s.0) + XY(self.xyz.0 * rhs.z.0) - XY(self.y.0 * rhs.x.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.z.0 * rhs.xyz.0) + XY(self.zx.0 * rhs.yz.0),
+		//     xyz: XYZ(self.s.0 * rhs.xyz.0) + XYZ(self.x.0 * rhs.yz.0) + XYZ(self.xy.0 * rhs.z.0) + XYZ(self.xyz.0 * rhs.s.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.z.0 * rhs.xy.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s)
+				+ self.x.geometric(rhs.x)
+				+ self.xy.geometric(rhs.xy)
+				+ self.xyz.geometric(rhs.xyz)
+				+ self.y.geometric(rhs.y)
+				+ self.yz.geometric(rhs.yz)
+				+ self.z.geometric(rhs.z)
+				+ self.zx.geometric(rhs.zx),
+			x: self.s.geometric(rhs.x)
+				+ self.x.geometric(rhs.s)
+				+ self.xy.geometric(rhs.y)
+				+ self.xyz.geometric(rhs.yz)
+				+ self.y.geometric(rhs.xy)
+				+ self.yz.geometric(rhs.xyz)
+				+ self.z.geometric(rhs.zx)
+				+ self.zx.geometric(rhs.z),
+			y: self.s.geometric(rhs.y)
+				+ self.x.geometric(rhs.xy)
+				+ self.xy.geometric(rhs.x)
+				+ self.xyz.geometric(rhs.zx)
+				+ self.y.geometric(rhs.s)
+				+ self.yz.geometric(rhs.z)
+				+ self.z.geometric(rhs.yz)
+				+ self.zx.geometric(rhs.xyz),
+			z: self.s.geometric(rhs.z)
+				+ self.x.geometric(rhs.zx)
+				+ self.xy.geometric(rhs.xyz)
+				+ self.xyz.geometric(rhs.xy)
+				+ self.y.geometric(rhs.yz)
+				+ self.yz.geometric(rhs.y)
+				+ self.z.geometric(rhs.s)
+				+ self.zx.geometric(rhs.x),
+			yz: self.s.geometric(rhs.yz)
+				+ self.x.geometric(rhs.xyz)
+				+ self.xy.geometric(rhs.zx)
+				+ self.xyz.geometric(rhs.x)
+				+ self.y.geometric(rhs.z)
+				+ self.yz.geometric(rhs.s)
+				+ self.z.geometric(rhs.y)
+				+ self.zx.geometric(rhs.xy),
+			zx: self.s.geometric(rhs.zx)
+				+ self.x.geometric(rhs.z)
+				+ self.xy.geometric(rhs.yz)
+				+ self.xyz.geometric(rhs.y)
+				+ self.y.geometric(rhs.xyz)
+				+ self.yz.geometric(rhs.xy)
+				+ self.z.geometric(rhs.x)
+				+ self.zx.geometric(rhs.s),
+			xy: self.s.geometric(rhs.xy)
+				+ self.x.geometric(rhs.y)
+				+ self.xy.geometric(rhs.s)
+				+ self.xyz.geometric(rhs.z)
+				+ self.y.geometric(rhs.x)
+				+ self.yz.geometric(rhs.zx)
+				+ self.z.geometric(rhs.xyz)
+				+ self.zx.geometric(rhs.yz),
+			xyz: self.s.geometric(rhs.xyz)
+				+ self.x.geometric(rhs.yz)
+				+ self.xy.geometric(rhs.z)
+				+ self.xyz.geometric(rhs.s)
+				+ self.y.geometric(rhs.zx)
+				+ self.yz.geometric(rhs.x)
+				+ self.z.geometric(rhs.xy)
+				+ self.zx.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Multivector.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyz.0) + S(self.x.0 * rhs.yz.0) + S(self.xy.0 * rhs.z.0) + S(self.xyz.0 * rhs.s.0) + S(self.y.0 * rhs.zx.0) + S(self.yz.0 * rhs.x.0) + S(self.z.0 * rhs.xy.0) + S(self.zx.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.yz.0) + X(self.x.0 * rhs.xyz.0) - X(self.xy.0 * rhs.zx.0) + X(self.xyz.0 * rhs.x.0) - X(self.y.0 * rhs.z.0) + X(self.yz.0 * rhs.s.0) + X(self.z.0 * rhs.y.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.s.0 * rhs.zx.0) + Y(self.x.0 * rhs.z.0) + Y(self.xy.0 * rhs.yz.0) + Y(self.xyz.0 * rhs.y.0) + Y(self.y.0 * rhs.xyz.0) - Y(self.yz.0 * rhs.xy.0) - Y(self.z.0 * rhs.x.0) + Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.s.0 * rhs.xy.0) - Z(self.x.0 * rhs.y.0) + Z(self.xy.0 * rhs.s.0) + Z(self.xyz.0 * rhs.z.0) + Z(self.y.0 * rhs.x.0) + Z(self.yz.0 * rhs.zx.0) + Z(self.z.0 * rhs.xyz.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : -YZ(self.s.0 * rhs.x.0) - YZ(self.x.0 * rhs.s.0) + YZ(self.xy.0 * rhs.y.0) + YZ(self.xyz.0 * rhs.yz.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.yz.0 * rhs.xyz.0) + YZ(self.z.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx : -ZX(self.s.0 * rhs.y.0) + ZX(self.x.0 * rhs.xy.0) - ZX(self.xy.0 * rhs.x.0) + ZX(self.xyz.0 * rhs.zx.0) - ZX(self.y.0 * rhs.s.0) + ZX(self.yz.0 * rhs.z.0) - ZX(self.z.0 * rhs.yz.0) + ZX(self.zx.0 * rhs.xyz.0),
+		//     xy : -XY(self.s.0 * rhs.z.0) - XY(self.x.0 * rhs.zx.0) + XY(self.xy.0 * rhs.xyz.0) + XY(self.xyz.0 * rhs.xy.0) + XY(self.y.0 * rhs.yz.0) - XY(self.yz.0 * rhs.y.0) - XY(self.z.0 * rhs.s.0) + XY(self.zx.0 * rhs.x.0),
+		//     xyz: -XYZ(self.s.0 * rhs.s.0) - XYZ(self.x.0 * rhs.x.0) + XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.xyz.0 * rhs.xyz.0) - XYZ(self.y.0 * rhs.y.0) + XYZ(self.yz.0 * rhs.yz.0) - XYZ(self.z.0 * rhs.z.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: self.s.anti_geometric(rhs.xyz)
+				+ self.x.anti_geometric(rhs.yz)
+				+ self.xy.anti_geometric(rhs.z)
+				+ self.xyz.anti_geometric(rhs.s)
+				+ self.y.anti_geometric(rhs.zx)
+				+ self.yz.anti_geometric(rhs.x)
+				+ self.z.anti_geometric(rhs.xy)
+				+ self.zx.anti_geometric(rhs.y),
+			x: self.s.anti_geometric(rhs.yz)
+				+ self.x.anti_geometric(rhs.xyz)
+				+ self.xy.anti_geometric(rhs.zx)
+				+ self.xyz.anti_geometric(rhs.x)
+				+ self.y.anti_geometric(rhs.z)
+				+ self.yz.anti_geometric(rhs.s)
+				+ self.z.anti_geometric(rhs.y)
+				+ self.zx.anti_geometric(rhs.xy),
+			y: self.s.anti_geometric(rhs.zx)
+				+ self.x.anti_geometric(rhs.z)
+				+ self.xy.anti_geometric(rhs.yz)
+				+ self.xyz.anti_geometric(rhs.y)
+				+ self.y.anti_geometric(rhs.xyz)
+				+ self.yz.anti_geometric(rhs.xy)
+				+ self.z.anti_geometric(rhs.x)
+				+ self.zx.anti_geometric(rhs.s),
+			z: self.s.anti_geometric(rhs.xy)
+				+ self.x.anti_geometric(rhs.y)
+				+ self.xy.anti_geometric(rhs.s)
+				+ self.xyz.anti_geometric(rhs.z)
+				+ self.y.anti_geometric(rhs.x)
+				+ self.yz.anti_geometric(rhs.zx)
+				+ self.z.anti_geometric(rhs.xyz)
+				+ self.zx.anti_geometric(rhs.yz),
+			yz: self.s.anti_geometric(rhs.x)
+				+ self.x.anti_geometric(rhs.s)
+				+ self.xy.anti_geometric(rhs.y)
+				+ self.xyz.anti_geometric(rhs.yz)
+				+ self.y.anti_geometric(rhs.xy)
+				+ self.yz.anti_geometric(rhs.xyz)
+				+ self.z.anti_geometric(rhs.zx)
+				+ self.zx.anti_geometric(rhs.z),
+			zx: self.s.anti_geometric(rhs.y)
+				+ self.x.anti_geometric(rhs.xy)
+				+ self.xy.anti_geometric(rhs.x)
+				+ self.xyz.anti_geometric(rhs.zx)
+				+ self.y.anti_geometric(rhs.s)
+				+ self.yz.anti_geometric(rhs.z)
+				+ self.z.anti_geometric(rhs.yz)
+				+ self.zx.anti_geometric(rhs.xyz),
+			xy: self.s.anti_geometric(rhs.z)
+				+ self.x.anti_geometric(rhs.zx)
+				+ self.xy.anti_geometric(rhs.xyz)
+				+ self.xyz.anti_geometric(rhs.xy)
+				+ self.y.anti_geometric(rhs.yz)
+				+ self.yz.anti_geometric(rhs.y)
+				+ self.z.anti_geometric(rhs.s)
+				+ self.zx.anti_geometric(rhs.x),
+			xyz: self.s.anti_geometric(rhs.s)
+				+ self.x.anti_geometric(rhs.x)
+				+ self.xy.anti_geometric(rhs.xy)
+				+ self.xyz.anti_geometric(rhs.xyz)
+				+ self.y.anti_geometric(rhs.y)
+				+ self.yz.anti_geometric(rhs.yz)
+				+ self.z.anti_geometric(rhs.z)
+				+ self.zx.anti_geometric(rhs.zx),
+		}
+	}
+}
+
+// Multivector.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Multivector {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) - S(self.xyz.0 * rhs.xyz.0) + S(self.y.0 * rhs.y.0) - S(self.yz.0 * rhs.yz.0) + S(self.z.0 * rhs.z.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.x.0 * rhs.s.0) + X(self.xy.0 * rhs.y.0) - X(self.xyz.0 * rhs.yz.0) - X(self.y.0 * rhs.xy.0) - X(self.yz.0 * rhs.xyz.0) + X(self.z.0 * rhs.zx.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : Y(self.s.0 * rhs.y.0) + Y(self.x.0 * rhs.xy.0) - Y(self.xy.0 * rhs.x.0) - Y(self.xyz.0 * rhs.zx.0) + Y(self.y.0 * rhs.s.0) + Y(self.yz.0 * rhs.z.0) - Y(self.z.0 * rhs.yz.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z  : Z(self.s.0 * rhs.z.0) - Z(self.x.0 * rhs.zx.0) - Z(self.xy.0 * rhs.xyz.0) - Z(self.xyz.0 * rhs.xy.0) + Z(self.y.0 * rhs.yz.0) - Z(self.yz.0 * rhs.y.0) + Z(self.z.0 * rhs.s.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.x.0 * rhs.xyz.0) + YZ(self.xyz.0 * rhs.x.0) + YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) + ZX(self.xyz.0 * rhs.y.0) + ZX(self.y.0 * rhs.xyz.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0) + XY(self.xyz.0 * rhs.z.0) + XY(self.z.0 * rhs.xyz.0),
+		//     xyz: XYZ(self.s.0 * rhs.xyz.0) + XYZ(self.xyz.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s)
+				+ self.x.dot(rhs.x)
+				+ self.xy.dot(rhs.xy)
+				+ self.xyz.dot(rhs.xyz)
+				+ self.y.dot(rhs.y)
+				+ self.yz.dot(rhs.yz)
+				+ self.z.dot(rhs.z)
+				+ self.zx.dot(rhs.zx),
+			x: self.s.dot(rhs.x)
+				+ self.x.dot(rhs.s)
+				+ self.xy.dot(rhs.y)
+				+ self.xyz.dot(rhs.yz)
+				+ self.y.dot(rhs.xy)
+				+ self.yz.dot(rhs.xyz)
+				+ self.z.dot(rhs.zx)
+				+ self.zx.dot(rhs.z),
+			y: self.s.dot(rhs.y)
+				+ self.x.dot(rhs.xy)
+				+ self.xy.dot(rhs.x)
+				+ self.xyz.dot(rhs.zx)
+				+ self.y.dot(rhs.s)
+				+ self.yz.dot(rhs.z)
+				+ self.z.dot(rhs.yz)
+				+ self.zx.dot(rhs.xyz),
+			z: self.s.dot(rhs.z)
+				+ self.x.dot(rhs.zx)
+				+ self.xy.dot(rhs.xyz)
+				+ self.xyz.dot(rhs.xy)
+				+ self.y.dot(rhs.yz)
+				+ self.yz.dot(rhs.y)
+				+ self.z.dot(rhs.s)
+				+ self.zx.dot(rhs.x),
+			yz: self.s.dot(rhs.yz) + self.x.dot(rhs.xyz) + self.xyz.dot(rhs.x) + self.yz.dot(rhs.s),
+			zx: self.s.dot(rhs.zx) + self.xyz.dot(rhs.y) + self.y.dot(rhs.xyz) + self.zx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s) + self.xyz.dot(rhs.z) + self.z.dot(rhs.xyz),
+			xyz: self.s.dot(rhs.xyz) + self.xyz.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Multivector.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.y.0) + Y(self.y.0 * rhs.s.0),
+		//     z  : Z(self.s.0 * rhs.z.0) + Z(self.z.0 * rhs.s.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.y.0 * rhs.z.0) + YZ(self.yz.0 * rhs.s.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) - ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.x.0 * rhs.y.0) + XY(self.xy.0 * rhs.s.0) - XY(self.y.0 * rhs.x.0),
+		//     xyz: XYZ(self.s.0 * rhs.xyz.0) + XYZ(self.x.0 * rhs.yz.0) + XYZ(self.xy.0 * rhs.z.0) + XYZ(self.xyz.0 * rhs.s.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.z.0 * rhs.xy.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.s.wedge(rhs.x) + self.x.wedge(rhs.s),
+			y: self.s.wedge(rhs.y) + self.y.wedge(rhs.s),
+			z: self.s.wedge(rhs.z) + self.z.wedge(rhs.s),
+			yz: self.s.wedge(rhs.yz) + self.y.wedge(rhs.z) + self.yz.wedge(rhs.s) + self.z.wedge(rhs.y),
+			zx: self.s.wedge(rhs.zx) + self.x.wedge(rhs.z) + self.z.wedge(rhs.x) + self.zx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy) + self.x.wedge(rhs.y) + self.xy.wedge(rhs.s) + self.y.wedge(rhs.x),
+			xyz: self.s.wedge(rhs.xyz)
+				+ self.x.wedge(rhs.yz)
+				+ self.xy.wedge(rhs.z)
+				+ self.xyz.wedge(rhs.s)
+				+ self.y.wedge(rhs.zx)
+				+ self.yz.wedge(rhs.x)
+				+ self.z.wedge(rhs.xy)
+				+ self.zx.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Multivector.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyz.0) + S(self.x.0 * rhs.yz.0) + S(self.xy.0 * rhs.z.0) + S(self.xyz.0 * rhs.s.0) + S(self.y.0 * rhs.zx.0) + S(self.yz.0 * rhs.x.0) + S(self.z.0 * rhs.xy.0) + S(self.zx.0 * rhs.y.0),
+		//     x  : X(self.x.0 * rhs.xyz.0) - X(self.xy.0 * rhs.zx.0) + X(self.xyz.0 * rhs.x.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.xy.0 * rhs.yz.0) + Y(self.xyz.0 * rhs.y.0) + Y(self.y.0 * rhs.xyz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z  : Z(self.xyz.0 * rhs.z.0) + Z(self.yz.0 * rhs.zx.0) + Z(self.z.0 * rhs.xyz.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : YZ(self.xyz.0 * rhs.yz.0) + YZ(self.yz.0 * rhs.xyz.0),
+		//     zx : ZX(self.xyz.0 * rhs.zx.0) + ZX(self.zx.0 * rhs.xyz.0),
+		//     xy : XY(self.xy.0 * rhs.xyz.0) + XY(self.xyz.0 * rhs.xy.0),
+		//     xyz: XYZ(self.xyz.0 * rhs.xyz.0),
+		// }
+		Multivector {
+			s: self.s.anti_wedge(rhs.xyz)
+				+ self.x.anti_wedge(rhs.yz)
+				+ self.xy.anti_wedge(rhs.z)
+				+ self.xyz.anti_wedge(rhs.s)
+				+ self.y.anti_wedge(rhs.zx)
+				+ self.yz.anti_wedge(rhs.x)
+				+ self.z.anti_wedge(rhs.xy)
+				+ self.zx.anti_wedge(rhs.y),
+			x: self.x.anti_wedge(rhs.xyz)
+				+ self.xy.anti_wedge(rhs.zx)
+				+ self.xyz.anti_wedge(rhs.x)
+				+ self.zx.anti_wedge(rhs.xy),
+			y: self.xy.anti_wedge(rhs.yz)
+				+ self.xyz.anti_wedge(rhs.y)
+				+ self.y.anti_wedge(rhs.xyz)
+				+ self.yz.anti_wedge(rhs.xy),
+			z: self.xyz.anti_wedge(rhs.z)
+				+ self.yz.anti_wedge(rhs.zx)
+				+ self.z.anti_wedge(rhs.xyz)
+				+ self.zx.anti_wedge(rhs.yz),
+			yz: self.xyz.anti_wedge(rhs.yz) + self.yz.anti_wedge(rhs.xyz),
+			zx: self.xyz.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.xyz),
+			xy: self.xy.anti_wedge(rhs.xyz) + self.xyz.anti_wedge(rhs.xy),
+			xyz: self.xyz.anti_wedge(rhs.xyz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Multivector {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Multivector.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Multivector {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) - S(self.xyz.0 * rhs.xyz.0) + S(self.y.0 * rhs.y.0) - S(self.yz.0 * rhs.yz.0) + S(self.z.0 * rhs.z.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.s.0 * rhs.x.0) - X(self.y.0 * rhs.xy.0) - X(self.yz.0 * rhs.xyz.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.s.0 * rhs.y.0) + Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z  : Z(self.s.0 * rhs.z.0) - Z(self.x.0 * rhs.zx.0) - Z(self.xy.0 * rhs.xyz.0) + Z(self.y.0 * rhs.yz.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.x.0 * rhs.xyz.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) + ZX(self.y.0 * rhs.xyz.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.z.0 * rhs.xyz.0),
+		//     xyz: XYZ(self.s.0 * rhs.xyz.0),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s)
+				+ self.x.left_contraction(rhs.x)
+				+ self.xy.left_contraction(rhs.xy)
+				+ self.xyz.left_contraction(rhs.xyz)
+				+ self.y.left_contraction(rhs.y)
+				+ self.yz.left_contraction(rhs.yz)
+				+ self.z.left_contraction(rhs.z)
+				+ self.zx.left_contraction(rhs.zx),
+			x: self.s.left_contraction(rhs.x)
+				+ self.y.left_contraction(rhs.xy)
+				+ self.yz.left_contraction(rhs.xyz)
+				+ self.z.left_contraction(rhs.zx),
+			y: self.s.left_contraction(rhs.y)
+				+ self.x.left_contraction(rhs.xy)
+				+ self.z.left_contraction(rhs.yz)
+				+ self.zx.left_contraction(rhs.xyz),
+			z: self.s.left_contraction(rhs.z)
+				+ self.x.left_contraction(rhs.zx)
+				+ self.xy.left_contraction(rhs.xyz)
+				+ self.y.left_contraction(rhs.yz),
+			yz: self.s.left_contraction(rhs.yz) + self.x.left_contraction(rhs.xyz),
+			zx: self.s.left_contraction(rhs.zx) + self.y.left_contraction(rhs.xyz),
+			xy: self.s.left_contraction(rhs.xy) + self.z.left_contraction(rhs.xyz),
+			xyz: self.s.left_contraction(rhs.xyz),
+		}
+	}
+}
+
+// Multivector.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Multivector {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) - S(self.xyz.0 * rhs.xyz.0) + S(self.y.0 * rhs.y.0) - S(self.yz.0 * rhs.yz.0) + S(self.z.0 * rhs.z.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.x.0 * rhs.s.0) + X(self.xy.0 * rhs.y.0) - X(self.xyz.0 * rhs.yz.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) - Y(self.xyz.0 * rhs.zx.0) + Y(self.y.0 * rhs.s.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : -Z(self.xyz.0 * rhs.xy.0) - Z(self.yz.0 * rhs.y.0) + Z(self.z.0 * rhs.s.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.xyz.0 * rhs.x.0) + YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.xyz.0 * rhs.y.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0) + XY(self.xyz.0 * rhs.z.0),
+		//     xyz: XYZ(self.xyz.0 * rhs.s.0),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s)
+				+ self.x.right_contraction(rhs.x)
+				+ self.xy.right_contraction(rhs.xy)
+				+ self.xyz.right_contraction(rhs.xyz)
+				+ self.y.right_contraction(rhs.y)
+				+ self.yz.right_contraction(rhs.yz)
+				+ self.z.right_contraction(rhs.z)
+				+ self.zx.right_contraction(rhs.zx),
+			x: self.x.right_contraction(rhs.s)
+				+ self.xy.right_contraction(rhs.y)
+				+ self.xyz.right_contraction(rhs.yz)
+				+ self.zx.right_contraction(rhs.z),
+			y: self.xy.right_contraction(rhs.x)
+				+ self.xyz.right_contraction(rhs.zx)
+				+ self.y.right_contraction(rhs.s)
+				+ self.yz.right_contraction(rhs.z),
+			z: self.xyz.right_contraction(rhs.xy)
+				+ self.yz.right_contraction(rhs.y)
+				+ self.z.right_contraction(rhs.s)
+				+ self.zx.right_contraction(rhs.x),
+			yz: self.xyz.right_contraction(rhs.x) + self.yz.right_contraction(rhs.s),
+			zx: self.xyz.right_contraction(rhs.y) + self.zx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s) + self.xyz.right_contraction(rhs.z),
+			xyz: self.xyz.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Multivector.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Multivector {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) + S(self.x.0 * rhs.x.0) - S(self.xy.0 * rhs.xy.0) - S(self.xyz.0 * rhs.xyz.0) + S(self.y.0 * rhs.y.0) - S(self.yz.0 * rhs.yz.0) + S(self.z.0 * rhs.z.0) - S(self.zx.0 * rhs.zx.0)
+		self.s.scalar_product(rhs.s)
+			+ self.x.scalar_product(rhs.x)
+			+ self.xy.scalar_product(rhs.xy)
+			+ self.xyz.scalar_product(rhs.xyz)
+			+ self.y.scalar_product(rhs.y)
+			+ self.yz.scalar_product(rhs.yz)
+			+ self.z.scalar_product(rhs.z)
+			+ self.zx.scalar_product(rhs.zx)
+	}
+}
+
+// Multivector.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Multivector {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.xy.0 * rhs.zx.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx : -ZX(self.x.0 * rhs.z.0) - ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y)
+				+ self.y.commutator(rhs.xy)
+				+ self.z.commutator(rhs.zx)
+				+ self.zx.commutator(rhs.z),
+			y: self.x.commutator(rhs.xy)
+				+ self.xy.commutator(rhs.x)
+				+ self.yz.commutator(rhs.z)
+				+ self.z.commutator(rhs.yz),
+			z: self.x.commutator(rhs.zx)
+				+ self.y.commutator(rhs.yz)
+				+ self.yz.commutator(rhs.y)
+				+ self.zx.commutator(rhs.x),
+			yz: self.xy.commutator(rhs.zx)
+				+ self.y.commutator(rhs.z)
+				+ self.z.commutator(rhs.y)
+				+ self.zx.commutator(rhs.xy),
+			zx: self.x.commutator(rhs.z)
+				+ self.xy.commutator(rhs.yz)
+				+ self.yz.commutator(rhs.xy)
+				+ self.z.commutator(rhs.x),
+			xy: self.x.commutator(rhs.y)
+				+ self.y.commutator(rhs.x)
+				+ self.yz.commutator(rhs.zx)
+				+ self.zx.commutator(rhs.yz),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Multivector.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Multivector {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyz.0) + S(self.xyz.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.yz.0) + X(self.x.0 * rhs.xyz.0) + X(self.xyz.0 * rhs.x.0) + X(self.yz.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.zx.0) + Y(self.xyz.0 * rhs.y.0) + Y(self.y.0 * rhs.xyz.0) + Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.s.0 * rhs.xy.0) + Z(self.xy.0 * rhs.s.0) + Z(self.xyz.0 * rhs.z.0) + Z(self.z.0 * rhs.xyz.0),
+		//     yz : -YZ(self.s.0 * rhs.x.0) - YZ(self.x.0 * rhs.s.0) + YZ(self.xy.0 * rhs.y.0) + YZ(self.xyz.0 * rhs.yz.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.yz.0 * rhs.xyz.0) + YZ(self.z.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx : -ZX(self.s.0 * rhs.y.0) + ZX(self.x.0 * rhs.xy.0) - ZX(self.xy.0 * rhs.x.0) + ZX(self.xyz.0 * rhs.zx.0) - ZX(self.y.0 * rhs.s.0) + ZX(self.yz.0 * rhs.z.0) - ZX(self.z.0 * rhs.yz.0) + ZX(self.zx.0 * rhs.xyz.0),
+		//     xy : -XY(self.s.0 * rhs.z.0) - XY(self.x.0 * rhs.zx.0) + XY(self.xy.0 * rhs.xyz.0) + XY(self.xyz.0 * rhs.xy.0) + XY(self.y.0 * rhs.yz.0) - XY(self.yz.0 * rhs.y.0) - XY(self.z.0 * rhs.s.0) + XY(self.zx.0 * rhs.x.0),
+		//     xyz: -XYZ(self.s.0 * rhs.s.0) - XYZ(self.x.0 * rhs.x.0) + XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.xyz.0 * rhs.xyz.0) - XYZ(self.y.0 * rhs.y.0) + XYZ(self.yz.0 * rhs.yz.0) - XYZ(self.z.0 * rhs.z.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: self.s.anti_dot(rhs.xyz) + self.xyz.anti_dot(rhs.s),
+			x: self.s.anti_dot(rhs.yz) + self.x.anti_dot(rhs.xyz) + self.xyz.anti_dot(rhs.x) + self.yz.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.zx) + self.xyz.anti_dot(rhs.y) + self.y.anti_dot(rhs.xyz) + self.zx.anti_dot(rhs.s),
+			z: self.s.anti_dot(rhs.xy) + self.xy.anti_dot(rhs.s) + self.xyz.anti_dot(rhs.z) + self.z.anti_dot(rhs.xyz),
+			yz: self.s.anti_dot(rhs.x)
+				+ self.x.anti_dot(rhs.s)
+				+ self.xy.anti_dot(rhs.y)
+				+ self.xyz.anti_dot(rhs.yz)
+				+ self.y.anti_dot(rhs.xy)
+				+ self.yz.anti_dot(rhs.xyz)
+				+ self.z.anti_dot(rhs.zx)
+				+ self.zx.anti_dot(rhs.z),
+			zx: self.s.anti_dot(rhs.y)
+				+ self.x.anti_dot(rhs.xy)
+				+ self.xy.anti_dot(rhs.x)
+				+ self.xyz.anti_dot(rhs.zx)
+				+ self.y.anti_dot(rhs.s)
+				+ self.yz.anti_dot(rhs.z)
+				+ self.z.anti_dot(rhs.yz)
+				+ self.zx.anti_dot(rhs.xyz),
+			xy: self.s.anti_dot(rhs.z)
+				+ self.x.anti_dot(rhs.zx)
+				+ self.xy.anti_dot(rhs.xyz)
+				+ self.xyz.anti_dot(rhs.xy)
+				+ self.y.anti_dot(rhs.yz)
+				+ self.yz.anti_dot(rhs.y)
+				+ self.z.anti_dot(rhs.s)
+				+ self.zx.anti_dot(rhs.x),
+			xyz: self.s.anti_dot(rhs.s)
+				+ self.x.anti_dot(rhs.x)
+				+ self.xy.anti_dot(rhs.xy)
+				+ self.xyz.anti_dot(rhs.xyz)
+				+ self.y.anti_dot(rhs.y)
+				+ self.yz.anti_dot(rhs.yz)
+				+ self.z.anti_dot(rhs.z)
+				+ self.zx.anti_dot(rhs.zx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Omitted: Multivector.sandwich(Vec3)  (not a Vec3)
+// Omitted: Multivector.anti_sandwich(Vec3)  (not a Vec3)
+// Omitted: Multivector.sandwich(Bivec3)  (not a Bivec3)
+// Omitted: Multivector.anti_sandwich(Bivec3)  (not a Bivec3)
@@ -0,0 +1,1589 @@
+//! # Rotor3
+//!
+//! ## Operations
+//! ```text
+//! Rotor3.geometric(Rotor3) -> Rotor3
+//! Rotor3.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.dot(Rotor3) -> Rotor3
+//! Rotor3.wedge(Rotor3) -> Rotor3
+//! Rotor3.anti_wedge(Rotor3) -> Vec3
+//! Rotor3.left_contraction(Rotor3) -> Rotor3
+//! Rotor3.right_contraction(Rotor3) -> Rotor3
+//! Rotor3.scalar_product(Rotor3) -> S
+//! Rotor3.commutator(Rotor3) -> Bivec3
+//! Rotor3.anti_dot(Rotor3) -> Multivector
+//! Rotor3.geometric(Vec3) -> Multivector
+//! Vec3.geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Vec3) -> Rotor3
+//! Vec3.anti_geometric(Rotor3) -> Rotor3
+//! Rotor3.dot(Vec3) -> Vec3
+//! Vec3.dot(Rotor3) -> Vec3
+//! Rotor3.wedge(Vec3) -> Multivector
+//! Vec3.wedge(Rotor3) -> Multivector
+//! Rotor3.anti_wedge(Vec3) -> S
+//! Vec3.anti_wedge(Rotor3) -> S
+//! Rotor3.left_contraction(Vec3) -> Vec3
+//! Vec3.left_contraction(Rotor3) -> Vec3
+//! Rotor3.right_contraction(Vec3) -> Vec3
+//! Vec3.right_contraction(Rotor3) -> Vec3
+//! Rotor3.commutator(Vec3) -> Vec3
+//! Vec3.commutator(Rotor3) -> Vec3
+//! Rotor3.anti_dot(Vec3) -> Bivec3
+//! Vec3.anti_dot(Rotor3) -> Bivec3
+//! Rotor3.geometric(Bivec3) -> Rotor3
+//! Bivec3.geometric(Rotor3) -> Rotor3
+//! Rotor3.anti_geometric(Bivec3) -> Multivector
+//! Bivec3.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.dot(Bivec3) -> Rotor3
+//! Bivec3.dot(Rotor3) -> Rotor3
+//! Rotor3.wedge(Bivec3) -> Bivec3
+//! Bivec3.wedge(Rotor3) -> Bivec3
+//! Rotor3.anti_wedge(Bivec3) -> Vec3
+//! Bivec3.anti_wedge(Rotor3) -> Vec3
+//! Rotor3.left_contraction(Bivec3) -> Rotor3
+//! Bivec3.left_contraction(Rotor3) -> S
+//! Rotor3.right_contraction(Bivec3) -> S
+//! Bivec3.right_contraction(Rotor3) -> Rotor3
+//! Rotor3.scalar_product(Bivec3) -> S
+//! Bivec3.scalar_product(Rotor3) -> S
+//! Rotor3.commutator(Bivec3) -> Bivec3
+//! Bivec3.commutator(Rotor3) -> Bivec3
+//! Rotor3.anti_dot(Bivec3) -> Multivector
+//! Bivec3.anti_dot(Rotor3) -> Multivector
+//! Rotor3.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Rotor3) -> Multivector
+//! Rotor3.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Rotor3) -> Multivector
+//! Rotor3.dot(Multivector) -> Multivector
+//! Multivector.dot(Rotor3) -> Multivector
+//! Rotor3.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Rotor3) -> Multivector
+//! Rotor3.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Rotor3) -> Multivector
+//! Rotor3.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Rotor3) -> Multivector
+//! Rotor3.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Rotor3) -> Multivector
+//! Rotor3.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Rotor3) -> S
+//! Rotor3.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Rotor3) -> Multivector
+//! Rotor3.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Rotor3) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rotor3 {
+	pub s: S,
+	pub yz: YZ,
+	pub zx: ZX,
+	pub xy: XY,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Rotor3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Rotor3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Rotor3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.s.abs_diff_eq(&other.s, epsilon)
+			&& self.yz.abs_diff_eq(&other.yz, epsilon)
+			&& self.zx.abs_diff_eq(&other.zx, epsilon)
+			&& self.xy.abs_diff_eq(&other.xy, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Rotor3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.s.relative_eq(&other.s, epsilon, max_relative)
+			&& self.yz.relative_eq(&other.yz, epsilon, max_relative)
+			&& self.zx.relative_eq(&other.zx, epsilon, max_relative)
+			&& self.xy.relative_eq(&other.xy, epsilon, max_relative)
+	}
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Rotor3 {
+	type Output = Multivector;
+	fn rcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yz.rcompl(),
+			y: self.zx.rcompl(),
+			z: self.xy.rcompl(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.s.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Rotor3 {
+	type Output = Multivector;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Rotor3 {
+	type Output = Multivector;
+	fn lcompl(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yz.lcompl(),
+			y: self.zx.lcompl(),
+			z: self.xy.lcompl(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.s.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Rotor3 {
+	fn rev(self) -> Self {
+		Rotor3 {
+			s: self.s,
+			yz: -self.yz,
+			zx: -self.zx,
+			xy: -self.xy,
+		}
+	}
+}
+
+impl AntiReverse for Rotor3 {
+	fn arev(self) -> Self {
+		Rotor3 {
+			s: -self.s,
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+		}
+	}
+}
+
+impl Involute for Rotor3 {
+	fn involute(self) -> Self {
+		Rotor3 {
+			s: self.s,
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+		}
+	}
+}
+
+impl Conjugate for Rotor3 {
+	fn conjugate(self) -> Self {
+		Rotor3 {
+			s: self.s,
+			yz: -self.yz,
+			zx: -self.zx,
+			xy: -self.xy,
+		}
+	}
+}
+
+impl Dual for Rotor3 {
+	type Output = Multivector;
+	fn dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yz.dual(),
+			y: self.zx.dual(),
+			z: self.xy.dual(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.s.dual(),
+		}
+	}
+}
+
+impl Bulk for Rotor3 {
+	type Output = Rotor3;
+	fn bulk(self) -> Self::Output {
+		Rotor3 {
+			s: self.s,
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+		}
+	}
+}
+
+impl Weight for Rotor3 {
+	type Output = Rotor3;
+	fn weight(self) -> Self::Output {
+		Rotor3 {
+			s: self.s,
+			yz: self.yz,
+			zx: self.zx,
+			xy: self.xy,
+		}
+	}
+}
+
+impl BulkDual for Rotor3 {
+	type Output = Multivector;
+	fn bulk_dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yz.bulk_dual(),
+			y: self.zx.bulk_dual(),
+			z: self.xy.bulk_dual(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.s.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Rotor3 {
+	type Output = Multivector;
+	fn weight_dual(self) -> Self::Output {
+		Multivector {
+			s: Default::default(),
+			x: self.yz.weight_dual(),
+			y: self.zx.weight_dual(),
+			z: self.xy.weight_dual(),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.s.weight_dual(),
+		}
+	}
+}
+
+// Omitted: Rotor3.square()  (not a scalar)
+
+impl Norm for Rotor3 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0 + self.yz.0 * self.yz.0 + self.zx.0 * self.zx.0 + self.xy.0 * self.xy.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.s.0 * self.s.0 + self.yz.0 * self.yz.0 + self.zx.0 * self.zx.0 + self.xy.0 * self.xy.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Rotor3 {
+			s: self.s * scale,
+			yz: self.yz * scale,
+			zx: self.zx * scale,
+			xy: self.xy * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Rotor3 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 4] {
+		[self.s.0, self.yz.0, self.zx.0, self.xy.0]
+	}
+}
+
+impl From<[f64; 4]> for Rotor3 {
+	fn from(a: [f64; 4]) -> Self {
+		Rotor3 {
+			s: S(a[0]),
+			yz: YZ(a[1]),
+			zx: ZX(a[2]),
+			xy: XY(a[3]),
+		}
+	}
+}
+
+impl From<Rotor3> for [f64; 4] {
+	fn from(v: Rotor3) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Rotor3> for Multivector {
+	fn from(v: Rotor3) -> Self {
+		Multivector {
+			s: v.s,
+			yz: v.yz,
+			zx: v.zx,
+			xy: v.xy,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Rotor3 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Rotor3` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.x.0 == 0.0 && mv.y.0 == 0.0 && mv.z.0 == 0.0 && mv.xyz.0 == 0.0 {
+			Ok(Rotor3 {
+				s: mv.s,
+				yz: mv.yz,
+				zx: mv.zx,
+				xy: mv.xy,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+impl Rotor3 {
+	/// The matrix that transforms `[x, y, z]` like `self.sandwich(v)` for a `Vec3` `v`,
+	/// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+	pub fn to_matrix(self) -> [[f64; 3]; 3] {
+		[
+			[
+				self.s.0 * self.s.0 - self.xy.0 * self.xy.0 + self.yz.0 * self.yz.0 - self.zx.0 * self.zx.0,
+				2.0 * self.s.0 * self.xy.0 + 2.0 * self.yz.0 * self.zx.0,
+				-2.0 * self.s.0 * self.zx.0 + 2.0 * self.xy.0 * self.yz.0,
+			],
+			[
+				-2.0 * self.s.0 * self.xy.0 + 2.0 * self.yz.0 * self.zx.0,
+				self.s.0 * self.s.0 - self.xy.0 * self.xy.0 - self.yz.0 * self.yz.0 + self.zx.0 * self.zx.0,
+				2.0 * self.s.0 * self.yz.0 + 2.0 * self.xy.0 * self.zx.0,
+			],
+			[
+				2.0 * self.s.0 * self.zx.0 + 2.0 * self.xy.0 * self.yz.0,
+				-2.0 * self.s.0 * self.yz.0 + 2.0 * self.xy.0 * self.zx.0,
+				self.s.0 * self.s.0 + self.xy.0 * self.xy.0 - self.yz.0 * self.yz.0 - self.zx.0 * self.zx.0,
+			],
+		]
+	}
+}
+
+impl Rotor3 {
+	/// The versor that moves nothing, whose matrix is the identity matrix.
+	pub fn identity() -> Self {
+		Rotor3 {
+			s: S(1.0),
+			yz: YZ(0.0),
+			zx: ZX(0.0),
+			xy: XY(0.0),
+		}
+	}
+}
+
+impl Rotor3 {
+	/// The normalized versor with this matrix, e.g. from [`Self::to_matrix`].
+	/// Both `v` and `-v` have the same matrix, and this returns the one with a non-negative `s`.
+	pub fn from_matrix(m: [[f64; 3]; 3]) -> Self {
+		let s_s = (m[0][0] + m[1][1] + m[2][2] + 1.0) / 4.0;
+		let s_xy = (m[0][1] - m[1][0]) / 4.0;
+		let s_yz = (m[1][2] - m[2][1]) / 4.0;
+		let s_zx = (-m[0][2] + m[2][0]) / 4.0;
+		let xy_xy = (-m[0][0] - m[1][1] + m[2][2] + 1.0) / 4.0;
+		let xy_yz = (m[0][2] + m[2][0]) / 4.0;
+		let xy_zx = (m[1][2] + m[2][1]) / 4.0;
+		let yz_yz = (m[0][0] - m[1][1] - m[2][2] + 1.0) / 4.0;
+		let yz_zx = (m[0][1] + m[1][0]) / 4.0;
+		let zx_zx = (-m[0][0] + m[1][1] - m[2][2] + 1.0) / 4.0;
+		let s = s_s.max(0.0).sqrt();
+		let yz = yz_yz.max(0.0).sqrt().copysign(s * s_yz);
+		let zx = zx_zx.max(0.0).sqrt().copysign(s * s_zx + yz * yz_zx);
+		let xy = xy_xy.max(0.0).sqrt().copysign(s * s_xy + yz * xy_yz + zx * xy_zx);
+		Rotor3 {
+			s: S(s * s_s + yz * s_yz + zx * s_zx + xy * s_xy),
+			yz: YZ(s * s_yz + yz * yz_yz + zx * yz_zx + xy * xy_yz),
+			zx: ZX(s * s_zx + yz * yz_zx + zx * zx_zx + xy * xy_zx),
+			xy: XY(s * s_xy + yz * xy_yz + zx * xy_zx + xy * xy_xy),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor3 OP Vec3:
+
+// Rotor3.geometric(Vec3) -> Multivector
+impl Geometric<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : Z(self.s.0 * rhs.z.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.geometric(rhs.x) + self.xy.geometric(rhs.y) + self.zx.geometric(rhs.z),
+			y: self.s.geometric(rhs.y) + self.xy.geometric(rhs.x) + self.yz.geometric(rhs.z),
+			z: self.s.geometric(rhs.z) + self.yz.geometric(rhs.y) + self.zx.geometric(rhs.x),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.xy.geometric(rhs.z) + self.yz.geometric(rhs.x) + self.zx.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Vec3) -> Rotor3
+impl AntiGeometric<Vec3> for Rotor3 {
+	type Output = Rotor3;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Rotor3 {
+		//     s : S(self.xy.0 * rhs.z.0) + S(self.yz.0 * rhs.x.0) + S(self.zx.0 * rhs.y.0),
+		//     yz: -YZ(self.s.0 * rhs.x.0) + YZ(self.xy.0 * rhs.y.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx: -ZX(self.s.0 * rhs.y.0) - ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0),
+		//     xy: -XY(self.s.0 * rhs.z.0) - XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		// }
+		Rotor3 {
+			s: self.xy.anti_geometric(rhs.z) + self.yz.anti_geometric(rhs.x) + self.zx.anti_geometric(rhs.y),
+			yz: self.s.anti_geometric(rhs.x) + self.xy.anti_geometric(rhs.y) + self.zx.anti_geometric(rhs.z),
+			zx: self.s.anti_geometric(rhs.y) + self.xy.anti_geometric(rhs.x) + self.yz.anti_geometric(rhs.z),
+			xy: self.s.anti_geometric(rhs.z) + self.yz.anti_geometric(rhs.y) + self.zx.anti_geometric(rhs.x),
+		}
+	}
+}
+
+// Rotor3.dot(Vec3) -> Vec3
+impl Dot<Vec3> for Rotor3 {
+	type Output = Vec3;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y: Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z: Z(self.s.0 * rhs.z.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y) + self.zx.dot(rhs.z),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x) + self.yz.dot(rhs.z),
+			z: self.s.dot(rhs.z) + self.yz.dot(rhs.y) + self.zx.dot(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Rotor3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor3.wedge(Vec3) -> Multivector
+impl Wedge<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     z  : Z(self.s.0 * rhs.z.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			z: self.s.wedge(rhs.z),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.xy.wedge(rhs.z) + self.yz.wedge(rhs.x) + self.zx.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Rotor3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor3.anti_wedge(Vec3) -> S
+impl AntiWedge<Vec3> for Rotor3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Vec3) -> Self::Output {
+		// S(self.xy.0 * rhs.z.0) + S(self.yz.0 * rhs.x.0) + S(self.zx.0 * rhs.y.0)
+		self.xy.anti_wedge(rhs.z) + self.yz.anti_wedge(rhs.x) + self.zx.anti_wedge(rhs.y)
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Rotor3 {
+	type Output = S;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor3.left_contraction(Vec3) -> Vec3
+impl LeftContraction<Vec3> for Rotor3 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.s.0 * rhs.x.0),
+		//     y: Y(self.s.0 * rhs.y.0),
+		//     z: Z(self.s.0 * rhs.z.0),
+		// }
+		Vec3 {
+			x: self.s.left_contraction(rhs.x),
+			y: self.s.left_contraction(rhs.y),
+			z: self.s.left_contraction(rhs.z),
+		}
+	}
+}
+
+// Rotor3.right_contraction(Vec3) -> Vec3
+impl RightContraction<Vec3> for Rotor3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y: -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z: -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.xy.right_contraction(rhs.y) + self.zx.right_contraction(rhs.z),
+			y: self.xy.right_contraction(rhs.x) + self.yz.right_contraction(rhs.z),
+			z: self.yz.right_contraction(rhs.y) + self.zx.right_contraction(rhs.x),
+		}
+	}
+}
+
+impl ScalarProduct<Vec3> for Rotor3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Rotor3.commutator(Vec3) -> Vec3
+impl Commutator<Vec3> for Rotor3 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y: -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z: -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		// }
+		Vec3 {
+			x: self.xy.commutator(rhs.y) + self.zx.commutator(rhs.z),
+			y: self.xy.commutator(rhs.x) + self.yz.commutator(rhs.z),
+			z: self.yz.commutator(rhs.y) + self.zx.commutator(rhs.x),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Vec3) -> Bivec3
+impl AntiDot<Vec3> for Rotor3 {
+	type Output = Bivec3;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// Bivec3 {
+		//     yz: -YZ(self.s.0 * rhs.x.0) + YZ(self.xy.0 * rhs.y.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx: -ZX(self.s.0 * rhs.y.0) - ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0),
+		//     xy: -XY(self.s.0 * rhs.z.0) - XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		// }
+		Bivec3 {
+			yz: self.s.anti_dot(rhs.x) + self.xy.anti_dot(rhs.y) + self.zx.anti_dot(rhs.z),
+			zx: self.s.anti_dot(rhs.y) + self.xy.anti_dot(rhs.x) + self.yz.anti_dot(rhs.z),
+			xy: self.s.anti_dot(rhs.z) + self.yz.anti_dot(rhs.y) + self.zx.anti_dot(rhs.x),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor3 OP Bivec3:
+
+// Rotor3.geometric(Bivec3) -> Rotor3
+impl Geometric<Bivec3> for Rotor3 {
+	type Output = Rotor3;
+	fn geometric(self, rhs: Bivec3) -> Self::Output {
+		// Rotor3 {
+		//     s : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.s.0 * rhs.yz.0) + YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx: ZX(self.s.0 * rhs.zx.0) - ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy: XY(self.s.0 * rhs.xy.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		// }
+		Rotor3 {
+			s: self.xy.geometric(rhs.xy) + self.yz.geometric(rhs.yz) + self.zx.geometric(rhs.zx),
+			yz: self.s.geometric(rhs.yz) + self.xy.geometric(rhs.zx) + self.zx.geometric(rhs.xy),
+			zx: self.s.geometric(rhs.zx) + self.xy.geometric(rhs.yz) + self.yz.geometric(rhs.xy),
+			xy: self.s.geometric(rhs.xy) + self.yz.geometric(rhs.zx) + self.zx.geometric(rhs.yz),
+		}
+	}
+}
+
+impl std::ops::Mul<Bivec3> for Rotor3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Bivec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Bivec3) -> Multivector
+impl AntiGeometric<Bivec3> for Rotor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yz.0) - X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.s.0 * rhs.zx.0) + Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z  : Z(self.s.0 * rhs.xy.0) + Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_geometric(rhs.yz) + self.xy.anti_geometric(rhs.zx) + self.zx.anti_geometric(rhs.xy),
+			y: self.s.anti_geometric(rhs.zx) + self.xy.anti_geometric(rhs.yz) + self.yz.anti_geometric(rhs.xy),
+			z: self.s.anti_geometric(rhs.xy) + self.yz.anti_geometric(rhs.zx) + self.zx.anti_geometric(rhs.yz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.xy.anti_geometric(rhs.xy) + self.yz.anti_geometric(rhs.yz) + self.zx.anti_geometric(rhs.zx),
+		}
+	}
+}
+
+// Rotor3.dot(Bivec3) -> Rotor3
+impl Dot<Bivec3> for Rotor3 {
+	type Output = Rotor3;
+	fn dot(self, rhs: Bivec3) -> Self::Output {
+		// Rotor3 {
+		//     s : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.s.0 * rhs.yz.0),
+		//     zx: ZX(self.s.0 * rhs.zx.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Rotor3 {
+			s: self.xy.dot(rhs.xy) + self.yz.dot(rhs.yz) + self.zx.dot(rhs.zx),
+			yz: self.s.dot(rhs.yz),
+			zx: self.s.dot(rhs.zx),
+			xy: self.s.dot(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitOr<Bivec3> for Rotor3 {
+	type Output = Rotor3;
+	fn bitor(self, rhs: Bivec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor3.wedge(Bivec3) -> Bivec3
+impl Wedge<Bivec3> for Rotor3 {
+	type Output = Bivec3;
+	fn wedge(self, rhs: Bivec3) -> Self::Output {
+		// Bivec3 {
+		//     yz: YZ(self.s.0 * rhs.yz.0),
+		//     zx: ZX(self.s.0 * rhs.zx.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Bivec3 {
+			yz: self.s.wedge(rhs.yz),
+			zx: self.s.wedge(rhs.zx),
+			xy: self.s.wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Bivec3> for Rotor3 {
+	type Output = Bivec3;
+	fn bitxor(self, rhs: Bivec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor3.anti_wedge(Bivec3) -> Vec3
+impl AntiWedge<Bivec3> for Rotor3 {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Bivec3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y: Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z: Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		// }
+		Vec3 {
+			x: self.xy.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.xy),
+			y: self.xy.anti_wedge(rhs.yz) + self.yz.anti_wedge(rhs.xy),
+			z: self.yz.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.yz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Bivec3> for Rotor3 {
+	type Output = Vec3;
+	fn bitand(self, rhs: Bivec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor3.left_contraction(Bivec3) -> Rotor3
+impl LeftContraction<Bivec3> for Rotor3 {
+	type Output = Rotor3;
+	fn left_contraction(self, rhs: Bivec3) -> Self::Output {
+		// Rotor3 {
+		//     s : -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.s.0 * rhs.yz.0),
+		//     zx: ZX(self.s.0 * rhs.zx.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Rotor3 {
+			s: self.xy.left_contraction(rhs.xy) + self.yz.left_contraction(rhs.yz) + self.zx.left_contraction(rhs.zx),
+			yz: self.s.left_contraction(rhs.yz),
+			zx: self.s.left_contraction(rhs.zx),
+			xy: self.s.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Rotor3.right_contraction(Bivec3) -> S
+impl RightContraction<Bivec3> for Rotor3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Bivec3) -> Self::Output {
+		// -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.xy.right_contraction(rhs.xy) + self.yz.right_contraction(rhs.yz) + self.zx.right_contraction(rhs.zx)
+	}
+}
+
+// Rotor3.scalar_product(Bivec3) -> S
+impl ScalarProduct<Bivec3> for Rotor3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Bivec3) -> Self::Output {
+		// -S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.xy.scalar_product(rhs.xy) + self.yz.scalar_product(rhs.yz) + self.zx.scalar_product(rhs.zx)
+	}
+}
+
+// Rotor3.commutator(Bivec3) -> Bivec3
+impl Commutator<Bivec3> for Rotor3 {
+	type Output = Bivec3;
+	fn commutator(self, rhs: Bivec3) -> Self::Output {
+		// Bivec3 {
+		//     yz: YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx: -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy: -XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		// }
+		Bivec3 {
+			yz: self.xy.commutator(rhs.zx) + self.zx.commutator(rhs.xy),
+			zx: self.xy.commutator(rhs.yz) + self.yz.commutator(rhs.xy),
+			xy: self.yz.commutator(rhs.zx) + self.zx.commutator(rhs.yz),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Bivec3) -> Multivector
+impl AntiDot<Bivec3> for Rotor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yz.0),
+		//     y  : Y(self.s.0 * rhs.zx.0),
+		//     z  : Z(self.s.0 * rhs.xy.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.yz),
+			y: self.s.anti_dot(rhs.zx),
+			z: self.s.anti_dot(rhs.xy),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.xy.anti_dot(rhs.xy) + self.yz.anti_dot(rhs.yz) + self.zx.anti_dot(rhs.zx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor3 OP Rotor3:
+
+// Rotor3.geometric(Rotor3) -> Rotor3
+impl Geometric<Rotor3> for Rotor3 {
+	type Output = Rotor3;
+	fn geometric(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.s.0 * rhs.yz.0) + YZ(self.xy.0 * rhs.zx.0) + YZ(self.yz.0 * rhs.s.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx: ZX(self.s.0 * rhs.zx.0) - ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		// }
+		Rotor3 {
+			s: self.s.geometric(rhs.s)
+				+ self.xy.geometric(rhs.xy)
+				+ self.yz.geometric(rhs.yz)
+				+ self.zx.geometric(rhs.zx),
+			yz: self.s.geometric(rhs.yz)
+				+ self.xy.geometric(rhs.zx)
+				+ self.yz.geometric(rhs.s)
+				+ self.zx.geometric(rhs.xy),
+			zx: self.s.geometric(rhs.zx)
+				+ self.xy.geometric(rhs.yz)
+				+ self.yz.geometric(rhs.xy)
+				+ self.zx.geometric(rhs.s),
+			xy: self.s.geometric(rhs.xy)
+				+ self.xy.geometric(rhs.s)
+				+ self.yz.geometric(rhs.zx)
+				+ self.zx.geometric(rhs.yz),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Rotor3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Rotor3) -> Multivector
+impl AntiGeometric<Rotor3> for Rotor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yz.0) - X(self.xy.0 * rhs.zx.0) + X(self.yz.0 * rhs.s.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.s.0 * rhs.zx.0) + Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0) + Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.s.0 * rhs.xy.0) + Z(self.xy.0 * rhs.s.0) + Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: -XYZ(self.s.0 * rhs.s.0) + XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_geometric(rhs.yz)
+				+ self.xy.anti_geometric(rhs.zx)
+				+ self.yz.anti_geometric(rhs.s)
+				+ self.zx.anti_geometric(rhs.xy),
+			y: self.s.anti_geometric(rhs.zx)
+				+ self.xy.anti_geometric(rhs.yz)
+				+ self.yz.anti_geometric(rhs.xy)
+				+ self.zx.anti_geometric(rhs.s),
+			z: self.s.anti_geometric(rhs.xy)
+				+ self.xy.anti_geometric(rhs.s)
+				+ self.yz.anti_geometric(rhs.zx)
+				+ self.zx.anti_geometric(rhs.yz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.s.anti_geometric(rhs.s)
+				+ self.xy.anti_geometric(rhs.xy)
+				+ self.yz.anti_geometric(rhs.yz)
+				+ self.zx.anti_geometric(rhs.zx),
+		}
+	}
+}
+
+// Rotor3.dot(Rotor3) -> Rotor3
+impl Dot<Rotor3> for Rotor3 {
+	type Output = Rotor3;
+	fn dot(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.s.0 * rhs.yz.0) + YZ(self.yz.0 * rhs.s.0),
+		//     zx: ZX(self.s.0 * rhs.zx.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		// }
+		Rotor3 {
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy) + self.yz.dot(rhs.yz) + self.zx.dot(rhs.zx),
+			yz: self.s.dot(rhs.yz) + self.yz.dot(rhs.s),
+			zx: self.s.dot(rhs.zx) + self.zx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor3> for Rotor3 {
+	type Output = Rotor3;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor3.wedge(Rotor3) -> Rotor3
+impl Wedge<Rotor3> for Rotor3 {
+	type Output = Rotor3;
+	fn wedge(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     s : S(self.s.0 * rhs.s.0),
+		//     yz: YZ(self.s.0 * rhs.yz.0) + YZ(self.yz.0 * rhs.s.0),
+		//     zx: ZX(self.s.0 * rhs.zx.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy: XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		// }
+		Rotor3 {
+			s: self.s.wedge(rhs.s),
+			yz: self.s.wedge(rhs.yz) + self.yz.wedge(rhs.s),
+			zx: self.s.wedge(rhs.zx) + self.zx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy) + self.xy.wedge(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor3> for Rotor3 {
+	type Output = Rotor3;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor3.anti_wedge(Rotor3) -> Vec3
+impl AntiWedge<Rotor3> for Rotor3 {
+	type Output = Vec3;
+	fn anti_wedge(self, rhs: Rotor3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y: Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z: Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		// }
+		Vec3 {
+			x: self.xy.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.xy),
+			y: self.xy.anti_wedge(rhs.yz) + self.yz.anti_wedge(rhs.xy),
+			z: self.yz.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.yz),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Rotor3> for Rotor3 {
+	type Output = Vec3;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor3.left_contraction(Rotor3) -> Rotor3
+impl LeftContraction<Rotor3> for Rotor3 {
+	type Output = Rotor3;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.s.0 * rhs.yz.0),
+		//     zx: ZX(self.s.0 * rhs.zx.0),
+		//     xy: XY(self.s.0 * rhs.xy.0),
+		// }
+		Rotor3 {
+			s: self.s.left_contraction(rhs.s)
+				+ self.xy.left_contraction(rhs.xy)
+				+ self.yz.left_contraction(rhs.yz)
+				+ self.zx.left_contraction(rhs.zx),
+			yz: self.s.left_contraction(rhs.yz),
+			zx: self.s.left_contraction(rhs.zx),
+			xy: self.s.left_contraction(rhs.xy),
+		}
+	}
+}
+
+// Rotor3.right_contraction(Rotor3) -> Rotor3
+impl RightContraction<Rotor3> for Rotor3 {
+	type Output = Rotor3;
+	fn right_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     s : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     yz: YZ(self.yz.0 * rhs.s.0),
+		//     zx: ZX(self.zx.0 * rhs.s.0),
+		//     xy: XY(self.xy.0 * rhs.s.0),
+		// }
+		Rotor3 {
+			s: self.s.right_contraction(rhs.s)
+				+ self.xy.right_contraction(rhs.xy)
+				+ self.yz.right_contraction(rhs.yz)
+				+ self.zx.right_contraction(rhs.zx),
+			yz: self.yz.right_contraction(rhs.s),
+			zx: self.zx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+		}
+	}
+}
+
+// Rotor3.scalar_product(Rotor3) -> S
+impl ScalarProduct<Rotor3> for Rotor3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Rotor3) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.s.scalar_product(rhs.s)
+			+ self.xy.scalar_product(rhs.xy)
+			+ self.yz.scalar_product(rhs.yz)
+			+ self.zx.scalar_product(rhs.zx)
+	}
+}
+
+// Rotor3.commutator(Rotor3) -> Bivec3
+impl Commutator<Rotor3> for Rotor3 {
+	type Output = Bivec3;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Bivec3 {
+		//     yz: YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx: -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy: -XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		// }
+		Bivec3 {
+			yz: self.xy.commutator(rhs.zx) + self.zx.commutator(rhs.xy),
+			zx: self.xy.commutator(rhs.yz) + self.yz.commutator(rhs.xy),
+			xy: self.yz.commutator(rhs.zx) + self.zx.commutator(rhs.yz),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Rotor3) -> Multivector
+impl AntiDot<Rotor3> for Rotor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.s.0 * rhs.yz.0) + X(self.yz.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.zx.0) + Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.s.0 * rhs.xy.0) + Z(self.xy.0 * rhs.s.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: -XYZ(self.s.0 * rhs.s.0) + XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.s.anti_dot(rhs.yz) + self.yz.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.zx) + self.zx.anti_dot(rhs.s),
+			z: self.s.anti_dot(rhs.xy) + self.xy.anti_dot(rhs.s),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.s.anti_dot(rhs.s)
+				+ self.xy.anti_dot(rhs.xy)
+				+ self.yz.anti_dot(rhs.yz)
+				+ self.zx.anti_dot(rhs.zx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Rotor3 OP Multivector:
+
+// Rotor3.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.yz.0 * rhs.xyz.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z  : Z(self.s.0 * rhs.z.0) - Z(self.xy.0 * rhs.xyz.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.xy.0 * rhs.zx.0) + YZ(self.yz.0 * rhs.s.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) - ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0) - XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		//     xyz: XYZ(self.s.0 * rhs.xyz.0) + XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.s.geometric(rhs.s)
+				+ self.xy.geometric(rhs.xy)
+				+ self.yz.geometric(rhs.yz)
+				+ self.zx.geometric(rhs.zx),
+			x: self.s.geometric(rhs.x)
+				+ self.xy.geometric(rhs.y)
+				+ self.yz.geometric(rhs.xyz)
+				+ self.zx.geometric(rhs.z),
+			y: self.s.geometric(rhs.y)
+				+ self.xy.geometric(rhs.x)
+				+ self.yz.geometric(rhs.z)
+				+ self.zx.geometric(rhs.xyz),
+			z: self.s.geometric(rhs.z)
+				+ self.xy.geometric(rhs.xyz)
+				+ self.yz.geometric(rhs.y)
+				+ self.zx.geometric(rhs.x),
+			yz: self.s.geometric(rhs.yz)
+				+ self.xy.geometric(rhs.zx)
+				+ self.yz.geometric(rhs.s)
+				+ self.zx.geometric(rhs.xy),
+			zx: self.s.geometric(rhs.zx)
+				+ self.xy.geometric(rhs.yz)
+				+ self.yz.geometric(rhs.xy)
+				+ self.zx.geometric(rhs.s),
+			xy: self.s.geometric(rhs.xy)
+				+ self.xy.geometric(rhs.s)
+				+ self.yz.geometric(rhs.zx)
+				+ self.zx.geometric(rhs.yz),
+			xyz: self.s.geometric(rhs.xyz)
+				+ self.xy.geometric(rhs.z)
+				+ self.yz.geometric(rhs.x)
+				+ self.zx.geometric(rhs.y),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Rotor3.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyz.0) + S(self.xy.0 * rhs.z.0) + S(self.yz.0 * rhs.x.0) + S(self.zx.0 * rhs.y.0),
+		//     x  : X(self.s.0 * rhs.yz.0) - X(self.xy.0 * rhs.zx.0) + X(self.yz.0 * rhs.s.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.s.0 * rhs.zx.0) + Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0) + Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.s.0 * rhs.xy.0) + Z(self.xy.0 * rhs.s.0) + Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : -YZ(self.s.0 * rhs.x.0) + YZ(self.xy.0 * rhs.y.0) + YZ(self.yz.0 * rhs.xyz.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx : -ZX(self.s.0 * rhs.y.0) - ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0) + ZX(self.zx.0 * rhs.xyz.0),
+		//     xy : -XY(self.s.0 * rhs.z.0) + XY(self.xy.0 * rhs.xyz.0) - XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		//     xyz: -XYZ(self.s.0 * rhs.s.0) + XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: self.s.anti_geometric(rhs.xyz)
+				+ self.xy.anti_geometric(rhs.z)
+				+ self.yz.anti_geometric(rhs.x)
+				+ self.zx.anti_geometric(rhs.y),
+			x: self.s.anti_geometric(rhs.yz)
+				+ self.xy.anti_geometric(rhs.zx)
+				+ self.yz.anti_geometric(rhs.s)
+				+ self.zx.anti_geometric(rhs.xy),
+			y: self.s.anti_geometric(rhs.zx)
+				+ self.xy.anti_geometric(rhs.yz)
+				+ self.yz.anti_geometric(rhs.xy)
+				+ self.zx.anti_geometric(rhs.s),
+			z: self.s.anti_geometric(rhs.xy)
+				+ self.xy.anti_geometric(rhs.s)
+				+ self.yz.anti_geometric(rhs.zx)
+				+ self.zx.anti_geometric(rhs.yz),
+			yz: self.s.anti_geometric(rhs.x)
+				+ self.xy.anti_geometric(rhs.y)
+				+ self.yz.anti_geometric(rhs.xyz)
+				+ self.zx.anti_geometric(rhs.z),
+			zx: self.s.anti_geometric(rhs.y)
+				+ self.xy.anti_geometric(rhs.x)
+				+ self.yz.anti_geometric(rhs.z)
+				+ self.zx.anti_geometric(rhs.xyz),
+			xy: self.s.anti_geometric(rhs.z)
+				+ self.xy.anti_geometric(rhs.xyz)
+				+ self.yz.anti_geometric(rhs.y)
+				+ self.zx.anti_geometric(rhs.x),
+			xyz: self.s.anti_geometric(rhs.s)
+				+ self.xy.anti_geometric(rhs.xy)
+				+ self.yz.anti_geometric(rhs.yz)
+				+ self.zx.anti_geometric(rhs.zx),
+		}
+	}
+}
+
+// Rotor3.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.s.0 * rhs.x.0) + X(self.xy.0 * rhs.y.0) - X(self.yz.0 * rhs.xyz.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z  : Z(self.s.0 * rhs.z.0) - Z(self.xy.0 * rhs.xyz.0) - Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyz: XYZ(self.s.0 * rhs.xyz.0),
+		// }
+		Multivector {
+			s: self.s.dot(rhs.s) + self.xy.dot(rhs.xy) + self.yz.dot(rhs.yz) + self.zx.dot(rhs.zx),
+			x: self.s.dot(rhs.x) + self.xy.dot(rhs.y) + self.yz.dot(rhs.xyz) + self.zx.dot(rhs.z),
+			y: self.s.dot(rhs.y) + self.xy.dot(rhs.x) + self.yz.dot(rhs.z) + self.zx.dot(rhs.xyz),
+			z: self.s.dot(rhs.z) + self.xy.dot(rhs.xyz) + self.yz.dot(rhs.y) + self.zx.dot(rhs.x),
+			yz: self.s.dot(rhs.yz) + self.yz.dot(rhs.s),
+			zx: self.s.dot(rhs.zx) + self.zx.dot(rhs.s),
+			xy: self.s.dot(rhs.xy) + self.xy.dot(rhs.s),
+			xyz: self.s.dot(rhs.xyz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Rotor3.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0),
+		//     x  : X(self.s.0 * rhs.x.0),
+		//     y  : Y(self.s.0 * rhs.y.0),
+		//     z  : Z(self.s.0 * rhs.z.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0) + YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0) + ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.s.0 * rhs.xy.0) + XY(self.xy.0 * rhs.s.0),
+		//     xyz: XYZ(self.s.0 * rhs.xyz.0) + XYZ(self.xy.0 * rhs.z.0) + XYZ(self.yz.0 * rhs.x.0) + XYZ(self.zx.0 * rhs.y.0),
+		// }
+		Multivector {
+			s: self.s.wedge(rhs.s),
+			x: self.s.wedge(rhs.x),
+			y: self.s.wedge(rhs.y),
+			z: self.s.wedge(rhs.z),
+			yz: self.s.wedge(rhs.yz) + self.yz.wedge(rhs.s),
+			zx: self.s.wedge(rhs.zx) + self.zx.wedge(rhs.s),
+			xy: self.s.wedge(rhs.xy) + self.xy.wedge(rhs.s),
+			xyz: self.s.wedge(rhs.xyz) + self.xy.wedge(rhs.z) + self.yz.wedge(rhs.x) + self.zx.wedge(rhs.y),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Rotor3.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyz.0) + S(self.xy.0 * rhs.z.0) + S(self.yz.0 * rhs.x.0) + S(self.zx.0 * rhs.y.0),
+		//     x  : -X(self.xy.0 * rhs.zx.0) + X(self.zx.0 * rhs.xy.0),
+		//     y  : Y(self.xy.0 * rhs.yz.0) - Y(self.yz.0 * rhs.xy.0),
+		//     z  : Z(self.yz.0 * rhs.zx.0) - Z(self.zx.0 * rhs.yz.0),
+		//     yz : YZ(self.yz.0 * rhs.xyz.0),
+		//     zx : ZX(self.zx.0 * rhs.xyz.0),
+		//     xy : XY(self.xy.0 * rhs.xyz.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.s.anti_wedge(rhs.xyz)
+				+ self.xy.anti_wedge(rhs.z)
+				+ self.yz.anti_wedge(rhs.x)
+				+ self.zx.anti_wedge(rhs.y),
+			x: self.xy.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.xy),
+			y: self.xy.anti_wedge(rhs.yz) + self.yz.anti_wedge(rhs.xy),
+			z: self.yz.anti_wedge(rhs.zx) + self.zx.anti_wedge(rhs.yz),
+			yz: self.yz.anti_wedge(rhs.xyz),
+			zx: self.zx.anti_wedge(rhs.xyz),
+			xy: self.xy.anti_wedge(rhs.xyz),
+			xyz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Rotor3.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.s.0 * rhs.x.0) - X(self.yz.0 * rhs.xyz.0),
+		//     y  : Y(self.s.0 * rhs.y.0) - Y(self.zx.0 * rhs.xyz.0),
+		//     z  : Z(self.s.0 * rhs.z.0) - Z(self.xy.0 * rhs.xyz.0),
+		//     yz : YZ(self.s.0 * rhs.yz.0),
+		//     zx : ZX(self.s.0 * rhs.zx.0),
+		//     xy : XY(self.s.0 * rhs.xy.0),
+		//     xyz: XYZ(self.s.0 * rhs.xyz.0),
+		// }
+		Multivector {
+			s: self.s.left_contraction(rhs.s)
+				+ self.xy.left_contraction(rhs.xy)
+				+ self.yz.left_contraction(rhs.yz)
+				+ self.zx.left_contraction(rhs.zx),
+			x: self.s.left_contraction(rhs.x) + self.yz.left_contraction(rhs.xyz),
+			y: self.s.left_contraction(rhs.y) + self.zx.left_contraction(rhs.xyz),
+			z: self.s.left_contraction(rhs.z) + self.xy.left_contraction(rhs.xyz),
+			yz: self.s.left_contraction(rhs.yz),
+			zx: self.s.left_contraction(rhs.zx),
+			xy: self.s.left_contraction(rhs.xy),
+			xyz: self.s.left_contraction(rhs.xyz),
+		}
+	}
+}
+
+// Rotor3.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.yz.0 * rhs.s.0),
+		//     zx : ZX(self.zx.0 * rhs.s.0),
+		//     xy : XY(self.xy.0 * rhs.s.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.s.right_contraction(rhs.s)
+				+ self.xy.right_contraction(rhs.xy)
+				+ self.yz.right_contraction(rhs.yz)
+				+ self.zx.right_contraction(rhs.zx),
+			x: self.xy.right_contraction(rhs.y) + self.zx.right_contraction(rhs.z),
+			y: self.xy.right_contraction(rhs.x) + self.yz.right_contraction(rhs.z),
+			z: self.yz.right_contraction(rhs.y) + self.zx.right_contraction(rhs.x),
+			yz: self.yz.right_contraction(rhs.s),
+			zx: self.zx.right_contraction(rhs.s),
+			xy: self.xy.right_contraction(rhs.s),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Rotor3.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Rotor3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.s.0 * rhs.s.0) - S(self.xy.0 * rhs.xy.0) - S(self.yz.0 * rhs.yz.0) - S(self.zx.0 * rhs.zx.0)
+		self.s.scalar_product(rhs.s)
+			+ self.xy.scalar_product(rhs.xy)
+			+ self.yz.scalar_product(rhs.yz)
+			+ self.zx.scalar_product(rhs.zx)
+	}
+}
+
+// Rotor3.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.xy.0 * rhs.y.0) - X(self.zx.0 * rhs.z.0),
+		//     y  : -Y(self.xy.0 * rhs.x.0) + Y(self.yz.0 * rhs.z.0),
+		//     z  : -Z(self.yz.0 * rhs.y.0) + Z(self.zx.0 * rhs.x.0),
+		//     yz : YZ(self.xy.0 * rhs.zx.0) - YZ(self.zx.0 * rhs.xy.0),
+		//     zx : -ZX(self.xy.0 * rhs.yz.0) + ZX(self.yz.0 * rhs.xy.0),
+		//     xy : -XY(self.yz.0 * rhs.zx.0) + XY(self.zx.0 * rhs.yz.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.xy.commutator(rhs.y) + self.zx.commutator(rhs.z),
+			y: self.xy.commutator(rhs.x) + self.yz.commutator(rhs.z),
+			z: self.yz.commutator(rhs.y) + self.zx.commutator(rhs.x),
+			yz: self.xy.commutator(rhs.zx) + self.zx.commutator(rhs.xy),
+			zx: self.xy.commutator(rhs.yz) + self.yz.commutator(rhs.xy),
+			xy: self.yz.commutator(rhs.zx) + self.zx.commutator(rhs.yz),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Rotor3.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Rotor3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.s.0 * rhs.xyz.0),
+		//     x  : X(self.s.0 * rhs.yz.0) + X(self.yz.0 * rhs.s.0),
+		//     y  : Y(self.s.0 * rhs.zx.0) + Y(self.zx.0 * rhs.s.0),
+		//     z  : Z(self.s.0 * rhs.xy.0) + Z(self.xy.0 * rhs.s.0),
+		//     yz : -YZ(self.s.0 * rhs.x.0) + YZ(self.xy.0 * rhs.y.0) + YZ(self.yz.0 * rhs.xyz.0) - YZ(self.zx.0 * rhs.z.0),
+		//     zx : -ZX(self.s.0 * rhs.y.0) - ZX(self.xy.0 * rhs.x.0) + ZX(self.yz.0 * rhs.z.0) + ZX(self.zx.0 * rhs.xyz.0),
+		//     xy : -XY(self.s.0 * rhs.z.0) + XY(self.xy.0 * rhs.xyz.0) - XY(self.yz.0 * rhs.y.0) + XY(self.zx.0 * rhs.x.0),
+		//     xyz: -XYZ(self.s.0 * rhs.s.0) + XYZ(self.xy.0 * rhs.xy.0) + XYZ(self.yz.0 * rhs.yz.0) + XYZ(self.zx.0 * rhs.zx.0),
+		// }
+		Multivector {
+			s: self.s.anti_dot(rhs.xyz),
+			x: self.s.anti_dot(rhs.yz) + self.yz.anti_dot(rhs.s),
+			y: self.s.anti_dot(rhs.zx) + self.zx.anti_dot(rhs.s),
+			z: self.s.anti_dot(rhs.xy) + self.xy.anti_dot(rhs.s),
+			yz: self.s.anti_dot(rhs.x) + self.xy.anti_dot(rhs.y) + self.yz.anti_dot(rhs.xyz) + self.zx.anti_dot(rhs.z),
+			zx: self.s.anti_dot(rhs.y) + self.xy.anti_dot(rhs.x) + self.yz.anti_dot(rhs.z) + self.zx.anti_dot(rhs.xyz),
+			xy: self.s.anti_dot(rhs.z) + self.xy.anti_dot(rhs.xyz) + self.yz.anti_dot(rhs.y) + self.zx.anti_dot(rhs.x),
+			xyz: self.s.anti_dot(rhs.s)
+				+ self.xy.anti_dot(rhs.xy)
+				+ self.yz.anti_dot(rhs.yz)
+				+ self.zx.anti_dot(rhs.zx),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Sandwich products:
+
+// Rotor3.sandwich(Vec3) -> Vec3
+impl Sandwich<Vec3> for Rotor3 {
+	fn sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: self.s.geometric(self.s).geometric(rhs.x)
+				+ self.s.geometric(self.xy).geometric(rhs.y) * 2.0
+				+ self.s.geometric(self.zx).geometric(rhs.z) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.x)
+				+ self.xy.geometric(self.yz).geometric(rhs.z) * 2.0
+				- self.yz.geometric(self.yz).geometric(rhs.x)
+				- self.yz.geometric(self.zx).geometric(rhs.y) * 2.0
+				+ self.zx.geometric(self.zx).geometric(rhs.x),
+			y: self.s.geometric(self.s).geometric(rhs.y)
+				+ self.s.geometric(self.xy).geometric(rhs.x) * 2.0
+				+ self.s.geometric(self.yz).geometric(rhs.z) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.y)
+				+ self.xy.geometric(self.zx).geometric(rhs.z) * 2.0
+				+ self.yz.geometric(self.yz).geometric(rhs.y)
+				+ self.yz.geometric(self.zx).geometric(rhs.x) * 2.0
+				- self.zx.geometric(self.zx).geometric(rhs.y),
+			z: self.s.geometric(self.s).geometric(rhs.z)
+				+ self.s.geometric(self.yz).geometric(rhs.y) * 2.0
+				+ self.s.geometric(self.zx).geometric(rhs.x) * 2.0
+				- self.xy.geometric(self.xy).geometric(rhs.z)
+				- self.xy.geometric(self.yz).geometric(rhs.x) * 2.0
+				- self.xy.geometric(self.zx).geometric(rhs.y) * 2.0
+				+ self.yz.geometric(self.yz).geometric(rhs.z)
+				+ self.zx.geometric(self.zx).geometric(rhs.z),
+		}
+	}
+}
+
+// Rotor3.anti_sandwich(Vec3) -> Vec3
+impl AntiSandwich<Vec3> for Rotor3 {
+	fn anti_sandwich(self, rhs: Vec3) -> Vec3 {
+		Vec3 {
+			x: -self.s.anti_geometric(self.s).anti_geometric(rhs.x)
+				- self.s.anti_geometric(self.xy).anti_geometric(rhs.y) * 2.0
+				- self.s.anti_geometric(self.zx).anti_geometric(rhs.z) * 2.0
+				- self.xy.anti_geometric(self.xy).anti_geometric(rhs.x)
+				- self.xy.anti_geometric(self.yz).anti_geometric(rhs.z) * 2.0
+				+ self.yz.anti_geometric(self.yz).anti_geometric(rhs.x)
+				+ self.yz.anti_geometric(self.zx).anti_geometric(rhs.y) * 2.0
+				- self.zx.anti_geometric(self.zx).anti_geometric(rhs.x),
+			y: -self.s.anti_geometric(self.s).anti_geometric(rhs.y)
+				- self.s.anti_geometric(self.xy).anti_geometric(rhs.x) * 2.0
+				- self.s.anti_geometric(self.yz).anti_geometric(rhs.z) * 2.0
+				- self.xy.anti_geometric(self.xy).anti_geometric(rhs.y)
+				- self.xy.anti_geometric(self.zx).anti_geometric(rhs.z) * 2.0
+				- self.yz.anti_geometric(self.yz).anti_geometric(rhs.y)
+				- self.yz.anti_geometric(self.zx).anti_geometric(rhs.x) * 2.0
+				+ self.zx.anti_geometric(self.zx).anti_geometric(rhs.y),
+			z: -self.s.anti_geometric(self.s).anti_geometric(rhs.z)
+				- self.s.anti_geometric(self.yz).anti_geometric(rhs.y) * 2.0
+				- self.s.anti_geometric(self.zx).anti_geometric(rhs.x) * 2.0
+				+ self.xy.anti_geometric(self.xy).anti_geometric(rhs.z)
+				+ self.xy.anti_geometric(self.yz).anti_geometric(rhs.x) * 2.0
+				+ self.xy.anti_geometric(self.zx).anti_geometric(rhs.y) * 2.0
+				- self.yz.anti_geometric(self.yz).anti_geometric(rhs.z)
+				- self.zx.anti_geometric(self.zx).anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Rotor3.sandwich(Bivec3) -> Bivec3
+impl Sandwich<Bivec3> for Rotor3 {
+	fn sandwich(self, rhs: Bivec3) -> Bivec3 {
+		Bivec3 {
+			yz: self.s.geometric(self.s).geometric(rhs.yz)
+				+ self.s.geometric(self.xy).geometric(rhs.zx) * 2.0
+				+ self.s.geometric(self.zx).geometric(rhs.xy) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.yz)
+				+ self.xy.geometric(self.yz).geometric(rhs.xy) * 2.0
+				- self.yz.geometric(self.yz).geometric(rhs.yz)
+				- self.yz.geometric(self.zx).geometric(rhs.zx) * 2.0
+				+ self.zx.geometric(self.zx).geometric(rhs.yz),
+			zx: self.s.geometric(self.s).geometric(rhs.zx)
+				+ self.s.geometric(self.xy).geometric(rhs.yz) * 2.0
+				+ self.s.geometric(self.yz).geometric(rhs.xy) * 2.0
+				+ self.xy.geometric(self.xy).geometric(rhs.zx)
+				+ self.xy.geometric(self.zx).geometric(rhs.xy) * 2.0
+				+ self.yz.geometric(self.yz).geometric(rhs.zx)
+				+ self.yz.geometric(self.zx).geometric(rhs.yz) * 2.0
+				- self.zx.geometric(self.zx).geometric(rhs.zx),
+			xy: self.s.geometric(self.s).geometric(rhs.xy)
+				+ self.s.geometric(self.yz).geometric(rhs.zx) * 2.0
+				+ self.s.geometric(self.zx).geometric(rhs.yz) * 2.0
+				- self.xy.geometric(self.xy).geometric(rhs.xy)
+				- self.xy.geometric(self.yz).geometric(rhs.yz) * 2.0
+				- self.xy.geometric(self.zx).geometric(rhs.zx) * 2.0
+				+ self.yz.geometric(self.yz).geometric(rhs.xy)
+				+ self.zx.geometric(self.zx).geometric(rhs.xy),
+		}
+	}
+}
+
+// Rotor3.anti_sandwich(Bivec3) -> Bivec3
+impl AntiSandwich<Bivec3> for Rotor3 {
+	fn anti_sandwich(self, rhs: Bivec3) -> Bivec3 {
+		Bivec3 {
+			yz: -self.s.anti_geometric(self.s).anti_geometric(rhs.yz)
+				- self.s.anti_geometric(self.xy).anti_geometric(rhs.zx) * 2.0
+				- self.s.anti_geometric(self.zx).anti_geometric(rhs.xy) * 2.0
+				- self.xy.anti_geometric(self.xy).anti_geometric(rhs.yz)
+				- self.xy.anti_geometric(self.yz).anti_geometric(rhs.xy) * 2.0
+				+ self.yz.anti_geometric(self.yz).anti_geometric(rhs.yz)
+				+ self.yz.anti_geometric(self.zx).anti_geometric(rhs.zx) * 2.0
+				- self.zx.anti_geometric(self.zx).anti_geometric(rhs.yz),
+			zx: -self.s.anti_geometric(self.s).anti_geometric(rhs.zx)
+				- self.s.anti_geometric(self.xy).anti_geometric(rhs.yz) * 2.0
+				- self.s.anti_geometric(self.yz).anti_geometric(rhs.xy) * 2.0
+				- self.xy.anti_geometric(self.xy).anti_geometric(rhs.zx)
+				- self.xy.anti_geometric(self.zx).anti_geometric(rhs.xy) * 2.0
+				- self.yz.anti_geometric(self.yz).anti_geometric(rhs.zx)
+				- self.yz.anti_geometric(self.zx).anti_geometric(rhs.yz) * 2.0
+				+ self.zx.anti_geometric(self.zx).anti_geometric(rhs.zx),
+			xy: -self.s.anti_geometric(self.s).anti_geometric(rhs.xy)
+				- self.s.anti_geometric(self.yz).anti_geometric(rhs.zx) * 2.0
+				- self.s.anti_geometric(self.zx).anti_geometric(rhs.yz) * 2.0
+				+ self.xy.anti_geometric(self.xy).anti_geometric(rhs.xy)
+				+ self.xy.anti_geometric(self.yz).anti_geometric(rhs.yz) * 2.0
+				+ self.xy.anti_geometric(self.zx).anti_geometric(rhs.zx) * 2.0
+				- self.yz.anti_geometric(self.yz).anti_geometric(rhs.xy)
+				- self.zx.anti_geometric(self.zx).anti_geometric(rhs.xy),
+		}
+	}
+}
@@ -0,0 +1,1263 @@
+//! # Vec3
+//!
+//! ## Operations
+//! ```text
+//! Vec3.geometric(Vec3) -> Rotor3
+//! Vec3.anti_geometric(Vec3) -> Multivector
+//! Vec3.dot(Vec3) -> S
+//! Vec3.wedge(Vec3) -> Bivec3
+//! Vec3.left_contraction(Vec3) -> S
+//! Vec3.right_contraction(Vec3) -> S
+//! Vec3.scalar_product(Vec3) -> S
+//! Vec3.commutator(Vec3) -> Bivec3
+//! Vec3.anti_dot(Vec3) -> XYZ
+//! Vec3.geometric(Bivec3) -> Multivector
+//! Bivec3.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Bivec3) -> Rotor3
+//! Bivec3.anti_geometric(Vec3) -> Rotor3
+//! Vec3.dot(Bivec3) -> Vec3
+//! Bivec3.dot(Vec3) -> Vec3
+//! Vec3.wedge(Bivec3) -> XYZ
+//! Bivec3.wedge(Vec3) -> XYZ
+//! Vec3.anti_wedge(Bivec3) -> S
+//! Bivec3.anti_wedge(Vec3) -> S
+//! Vec3.left_contraction(Bivec3) -> Vec3
+//! Bivec3.right_contraction(Vec3) -> Vec3
+//! Vec3.commutator(Bivec3) -> Vec3
+//! Bivec3.commutator(Vec3) -> Vec3
+//! Vec3.anti_dot(Bivec3) -> Bivec3
+//! Bivec3.anti_dot(Vec3) -> Bivec3
+//! Vec3.geometric(Rotor3) -> Multivector
+//! Rotor3.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Rotor3) -> Rotor3
+//! Rotor3.anti_geometric(Vec3) -> Rotor3
+//! Vec3.dot(Rotor3) -> Vec3
+//! Rotor3.dot(Vec3) -> Vec3
+//! Vec3.wedge(Rotor3) -> Multivector
+//! Rotor3.wedge(Vec3) -> Multivector
+//! Vec3.anti_wedge(Rotor3) -> S
+//! Rotor3.anti_wedge(Vec3) -> S
+//! Vec3.left_contraction(Rotor3) -> Vec3
+//! Rotor3.left_contraction(Vec3) -> Vec3
+//! Vec3.right_contraction(Rotor3) -> Vec3
+//! Rotor3.right_contraction(Vec3) -> Vec3
+//! Vec3.commutator(Rotor3) -> Vec3
+//! Rotor3.commutator(Vec3) -> Vec3
+//! Vec3.anti_dot(Rotor3) -> Bivec3
+//! Rotor3.anti_dot(Vec3) -> Bivec3
+//! Vec3.geometric(Multivector) -> Multivector
+//! Multivector.geometric(Vec3) -> Multivector
+//! Vec3.anti_geometric(Multivector) -> Multivector
+//! Multivector.anti_geometric(Vec3) -> Multivector
+//! Vec3.dot(Multivector) -> Multivector
+//! Multivector.dot(Vec3) -> Multivector
+//! Vec3.wedge(Multivector) -> Multivector
+//! Multivector.wedge(Vec3) -> Multivector
+//! Vec3.anti_wedge(Multivector) -> Multivector
+//! Multivector.anti_wedge(Vec3) -> Multivector
+//! Vec3.left_contraction(Multivector) -> Multivector
+//! Multivector.left_contraction(Vec3) -> Multivector
+//! Vec3.right_contraction(Multivector) -> Multivector
+//! Multivector.right_contraction(Vec3) -> Multivector
+//! Vec3.scalar_product(Multivector) -> S
+//! Multivector.scalar_product(Vec3) -> S
+//! Vec3.commutator(Multivector) -> Multivector
+//! Multivector.commutator(Vec3) -> Multivector
+//! Vec3.anti_dot(Multivector) -> Multivector
+//! Multivector.anti_dot(Vec3) -> Multivector
+//! ```
+
+use super::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec3 {
+	pub x: X,
+	pub y: Y,
+	pub z: Z,
+}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec3 {}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec3 {}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Vec3 {
+	type Epsilon = <f64 as approx::AbsDiffEq>::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon {
+		<f64 as approx::AbsDiffEq>::default_epsilon()
+	}
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.x.abs_diff_eq(&other.x, epsilon)
+			&& self.y.abs_diff_eq(&other.y, epsilon)
+			&& self.z.abs_diff_eq(&other.z, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Vec3 {
+	fn default_max_relative() -> Self::Epsilon {
+		<f64 as approx::RelativeEq>::default_max_relative()
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.x.relative_eq(&other.x, epsilon, max_relative)
+			&& self.y.relative_eq(&other.y, epsilon, max_relative)
+			&& self.z.relative_eq(&other.z, epsilon, max_relative)
+	}
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector3<f64>> for Vec3 {
+	fn from(v: mint::Vector3<f64>) -> Self {
+		Vec3 {
+			x: X(v.x),
+			y: Y(v.y),
+			z: Z(v.z),
+		}
+	}
+}
+
+#[cfg(feature = "mint")]
+impl From<Vec3> for mint::Vector3<f64> {
+	fn from(v: Vec3) -> Self {
+		mint::Vector3 {
+			x: v.x.0,
+			y: v.y.0,
+			z: v.z.0,
+		}
+	}
+}
+
+#[cfg(feature = "mint")]
+impl mint::IntoMint for Vec3 {
+	type MintType = mint::Vector3<f64>;
+}
+
+// ---------------------------------------------------------------------
+
+impl RCompl for Vec3 {
+	type Output = Bivec3;
+	fn rcompl(self) -> Self::Output {
+		Bivec3 {
+			yz: self.x.rcompl(),
+			zx: self.y.rcompl(),
+			xy: self.z.rcompl(),
+		}
+	}
+}
+
+impl std::ops::Not for Vec3 {
+	type Output = Bivec3;
+	fn not(self) -> Self::Output {
+		self.rcompl()
+	}
+}
+
+impl LCompl for Vec3 {
+	type Output = Bivec3;
+	fn lcompl(self) -> Self::Output {
+		Bivec3 {
+			yz: self.x.lcompl(),
+			zx: self.y.lcompl(),
+			xy: self.z.lcompl(),
+		}
+	}
+}
+
+impl Reverse for Vec3 {
+	fn rev(self) -> Self {
+		Vec3 {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+		}
+	}
+}
+
+impl AntiReverse for Vec3 {
+	fn arev(self) -> Self {
+		Vec3 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+		}
+	}
+}
+
+impl Involute for Vec3 {
+	fn involute(self) -> Self {
+		Vec3 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+		}
+	}
+}
+
+impl Conjugate for Vec3 {
+	fn conjugate(self) -> Self {
+		Vec3 {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+		}
+	}
+}
+
+impl Dual for Vec3 {
+	type Output = Bivec3;
+	fn dual(self) -> Self::Output {
+		Bivec3 {
+			yz: self.x.dual(),
+			zx: self.y.dual(),
+			xy: self.z.dual(),
+		}
+	}
+}
+
+impl Bulk for Vec3 {
+	type Output = Vec3;
+	fn bulk(self) -> Self::Output {
+		Vec3 {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+		}
+	}
+}
+
+impl Weight for Vec3 {
+	type Output = Vec3;
+	fn weight(self) -> Self::Output {
+		Vec3 {
+			x: self.x,
+			y: self.y,
+			z: self.z,
+		}
+	}
+}
+
+impl BulkDual for Vec3 {
+	type Output = Bivec3;
+	fn bulk_dual(self) -> Self::Output {
+		Bivec3 {
+			yz: self.x.bulk_dual(),
+			zx: self.y.bulk_dual(),
+			xy: self.z.bulk_dual(),
+		}
+	}
+}
+
+impl WeightDual for Vec3 {
+	type Output = Bivec3;
+	fn weight_dual(self) -> Self::Output {
+		Bivec3 {
+			yz: self.x.weight_dual(),
+			zx: self.y.weight_dual(),
+			xy: self.z.weight_dual(),
+		}
+	}
+}
+
+impl Square for Vec3 {
+	type Output = f64;
+	fn square(self) -> Self::Output {
+		(self.x.geometric(self.x) + self.y.geometric(self.y) + self.z.geometric(self.z)).0
+	}
+}
+
+impl Norm for Vec3 {
+	type Output = f64;
+
+	fn norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0
+	}
+
+	fn bulk_norm_squared(self) -> Self::Output {
+		self.x.0 * self.x.0 + self.y.0 * self.y.0 + self.z.0 * self.z.0
+	}
+
+	fn bulk_norm(self) -> Self::Output {
+		self.bulk_norm_squared().sqrt()
+	}
+
+	fn weight_norm_squared(self) -> Self::Output {
+		0.0
+	}
+
+	fn weight_norm(self) -> Self::Output {
+		self.weight_norm_squared().sqrt()
+	}
+
+	fn normalized(self) -> Self {
+		let scale = 1.0 / self.bulk_norm();
+		Vec3 {
+			x: self.x * scale,
+			y: self.y * scale,
+			z: self.z * scale,
+		}
+	}
+
+	fn is_normalized(&self, eps: Self::Output) -> bool {
+		(self.bulk_norm() - 1.0).abs() <= eps
+	}
+}
+
+impl Vec3 {
+	/// The members as a plain array, in declaration order.
+	pub fn to_array(self) -> [f64; 3] {
+		[self.x.0, self.y.0, self.z.0]
+	}
+}
+
+impl From<[f64; 3]> for Vec3 {
+	fn from(a: [f64; 3]) -> Self {
+		Vec3 {
+			x: X(a[0]),
+			y: Y(a[1]),
+			z: Z(a[2]),
+		}
+	}
+}
+
+impl From<Vec3> for [f64; 3] {
+	fn from(v: Vec3) -> Self {
+		v.to_array()
+	}
+}
+
+impl From<Vec3> for Multivector {
+	fn from(v: Vec3) -> Self {
+		Multivector {
+			x: v.x,
+			y: v.y,
+			z: v.z,
+			..Default::default()
+		}
+	}
+}
+
+impl std::convert::TryFrom<Multivector> for Vec3 {
+	type Error = Multivector;
+
+	/// Fails (returning the multivector) if it has blades that a `Vec3` can't represent.
+	fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+		if mv.s.0 == 0.0 && mv.yz.0 == 0.0 && mv.zx.0 == 0.0 && mv.xy.0 == 0.0 && mv.xyz.0 == 0.0 {
+			Ok(Vec3 {
+				x: mv.x,
+				y: mv.y,
+				z: mv.z,
+			})
+		} else {
+			Err(mv)
+		}
+	}
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec3> for glam::DVec3 {
+	fn from(v: Vec3) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0)
+	}
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec3> for Vec3 {
+	fn from(v: glam::DVec3) -> Self {
+		Vec3 {
+			x: X(v.x),
+			y: Y(v.y),
+			z: Z(v.z),
+		}
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec3> for nalgebra::Vector3<f64> {
+	fn from(v: Vec3) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0)
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f64>> for Vec3 {
+	fn from(v: nalgebra::Vector3<f64>) -> Self {
+		Vec3 {
+			x: X(v.x),
+			y: Y(v.y),
+			z: Z(v.z),
+		}
+	}
+}
+
+#[cfg(feature = "cgmath")]
+impl From<Vec3> for cgmath::Vector3<f64> {
+	fn from(v: Vec3) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0)
+	}
+}
+
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Vector3<f64>> for Vec3 {
+	fn from(v: cgmath::Vector3<f64>) -> Self {
+		Vec3 {
+			x: X(v.x),
+			y: Y(v.y),
+			z: Z(v.z),
+		}
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec3> for nalgebra::Point3<f64> {
+	fn from(v: Vec3) -> Self {
+		Self::new(v.x.0, v.y.0, v.z.0)
+	}
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Point3<f64>> for Vec3 {
+	fn from(v: nalgebra::Point3<f64>) -> Self {
+		Vec3 {
+			x: X(v.x),
+			y: Y(v.y),
+			z: Z(v.z),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Vec3:
+
+// Vec3.geometric(Vec3) -> Rotor3
+impl Geometric<Vec3> for Vec3 {
+	type Output = Rotor3;
+	fn geometric(self, rhs: Vec3) -> Self::Output {
+		// Rotor3 {
+		//     s : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     yz: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Rotor3 {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			yz: self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zx: self.x.geometric(rhs.z) + self.z.geometric(rhs.x),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x),
+		}
+	}
+}
+
+impl std::ops::Mul<Vec3> for Vec3 {
+	type Output = Rotor3;
+	fn mul(self, rhs: Vec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Vec3) -> Multivector
+impl AntiGeometric<Vec3> for Vec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Vec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y  : Y(self.x.0 * rhs.z.0) - Y(self.z.0 * rhs.x.0),
+		//     z  : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: -XYZ(self.x.0 * rhs.x.0) - XYZ(self.y.0 * rhs.y.0) - XYZ(self.z.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+			y: self.x.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.x),
+			z: self.x.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.x),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.x.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Vec3.dot(Vec3) -> S
+impl Dot<Vec3> for Vec3 {
+	type Output = S;
+	fn dot(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z)
+	}
+}
+
+impl std::ops::BitOr<Vec3> for Vec3 {
+	type Output = S;
+	fn bitor(self, rhs: Vec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Vec3) -> Bivec3
+impl Wedge<Vec3> for Vec3 {
+	type Output = Bivec3;
+	fn wedge(self, rhs: Vec3) -> Self::Output {
+		// Bivec3 {
+		//     yz: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Bivec3 {
+			yz: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			zx: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+		}
+	}
+}
+
+impl std::ops::BitXor<Vec3> for Vec3 {
+	type Output = Bivec3;
+	fn bitxor(self, rhs: Vec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+impl AntiWedge<Vec3> for Vec3 {
+	type Output = Zero;
+	fn anti_wedge(self, _rhs: Vec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl std::ops::BitAnd<Vec3> for Vec3 {
+	type Output = Zero;
+	fn bitand(self, rhs: Vec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Vec3) -> S
+impl LeftContraction<Vec3> for Vec3 {
+	type Output = S;
+	fn left_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z)
+	}
+}
+
+// Vec3.right_contraction(Vec3) -> S
+impl RightContraction<Vec3> for Vec3 {
+	type Output = S;
+	fn right_contraction(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z)
+	}
+}
+
+// Vec3.scalar_product(Vec3) -> S
+impl ScalarProduct<Vec3> for Vec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Vec3) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Vec3.commutator(Vec3) -> Bivec3
+impl Commutator<Vec3> for Vec3 {
+	type Output = Bivec3;
+	fn commutator(self, rhs: Vec3) -> Self::Output {
+		// Bivec3 {
+		//     yz: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		// }
+		Bivec3 {
+			yz: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			zx: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+		}
+	}
+}
+
+// Vec3.anti_dot(Vec3) -> XYZ
+impl AntiDot<Vec3> for Vec3 {
+	type Output = XYZ;
+	fn anti_dot(self, rhs: Vec3) -> Self::Output {
+		// -XYZ(self.x.0 * rhs.x.0) - XYZ(self.y.0 * rhs.y.0) - XYZ(self.z.0 * rhs.z.0)
+		self.x.anti_dot(rhs.x) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z)
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Bivec3:
+
+// Vec3.geometric(Bivec3) -> Multivector
+impl Geometric<Bivec3> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Bivec3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.x.0 * rhs.yz.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.geometric(rhs.xy) + self.z.geometric(rhs.zx),
+			y: self.x.geometric(rhs.xy) + self.z.geometric(rhs.yz),
+			z: self.x.geometric(rhs.zx) + self.y.geometric(rhs.yz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.x.geometric(rhs.yz) + self.y.geometric(rhs.zx) + self.z.geometric(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::Mul<Bivec3> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Bivec3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Bivec3) -> Rotor3
+impl AntiGeometric<Bivec3> for Vec3 {
+	type Output = Rotor3;
+	fn anti_geometric(self, rhs: Bivec3) -> Self::Output {
+		// Rotor3 {
+		//     s : S(self.x.0 * rhs.yz.0) + S(self.y.0 * rhs.zx.0) + S(self.z.0 * rhs.xy.0),
+		//     yz: -YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx: ZX(self.x.0 * rhs.xy.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy: -XY(self.x.0 * rhs.zx.0) + XY(self.y.0 * rhs.yz.0),
+		// }
+		Rotor3 {
+			s: self.x.anti_geometric(rhs.yz) + self.y.anti_geometric(rhs.zx) + self.z.anti_geometric(rhs.xy),
+			yz: self.y.anti_geometric(rhs.xy) + self.z.anti_geometric(rhs.zx),
+			zx: self.x.anti_geometric(rhs.xy) + self.z.anti_geometric(rhs.yz),
+			xy: self.x.anti_geometric(rhs.zx) + self.y.anti_geometric(rhs.yz),
+		}
+	}
+}
+
+// Vec3.dot(Bivec3) -> Vec3
+impl Dot<Bivec3> for Vec3 {
+	type Output = Vec3;
+	fn dot(self, rhs: Bivec3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y: Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z: -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		// }
+		Vec3 {
+			x: self.y.dot(rhs.xy) + self.z.dot(rhs.zx),
+			y: self.x.dot(rhs.xy) + self.z.dot(rhs.yz),
+			z: self.x.dot(rhs.zx) + self.y.dot(rhs.yz),
+		}
+	}
+}
+
+impl std::ops::BitOr<Bivec3> for Vec3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Bivec3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Bivec3) -> XYZ
+impl Wedge<Bivec3> for Vec3 {
+	type Output = XYZ;
+	fn wedge(self, rhs: Bivec3) -> Self::Output {
+		// XYZ(self.x.0 * rhs.yz.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0)
+		self.x.wedge(rhs.yz) + self.y.wedge(rhs.zx) + self.z.wedge(rhs.xy)
+	}
+}
+
+impl std::ops::BitXor<Bivec3> for Vec3 {
+	type Output = XYZ;
+	fn bitxor(self, rhs: Bivec3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Bivec3) -> S
+impl AntiWedge<Bivec3> for Vec3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Bivec3) -> Self::Output {
+		// S(self.x.0 * rhs.yz.0) + S(self.y.0 * rhs.zx.0) + S(self.z.0 * rhs.xy.0)
+		self.x.anti_wedge(rhs.yz) + self.y.anti_wedge(rhs.zx) + self.z.anti_wedge(rhs.xy)
+	}
+}
+
+impl std::ops::BitAnd<Bivec3> for Vec3 {
+	type Output = S;
+	fn bitand(self, rhs: Bivec3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Bivec3) -> Vec3
+impl LeftContraction<Bivec3> for Vec3 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Bivec3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y: Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z: -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		// }
+		Vec3 {
+			x: self.y.left_contraction(rhs.xy) + self.z.left_contraction(rhs.zx),
+			y: self.x.left_contraction(rhs.xy) + self.z.left_contraction(rhs.yz),
+			z: self.x.left_contraction(rhs.zx) + self.y.left_contraction(rhs.yz),
+		}
+	}
+}
+
+impl RightContraction<Bivec3> for Vec3 {
+	type Output = Zero;
+	fn right_contraction(self, _rhs: Bivec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+impl ScalarProduct<Bivec3> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Bivec3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Bivec3) -> Vec3
+impl Commutator<Bivec3> for Vec3 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Bivec3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y: Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z: -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		// }
+		Vec3 {
+			x: self.y.commutator(rhs.xy) + self.z.commutator(rhs.zx),
+			y: self.x.commutator(rhs.xy) + self.z.commutator(rhs.yz),
+			z: self.x.commutator(rhs.zx) + self.y.commutator(rhs.yz),
+		}
+	}
+}
+
+// Vec3.anti_dot(Bivec3) -> Bivec3
+impl AntiDot<Bivec3> for Vec3 {
+	type Output = Bivec3;
+	fn anti_dot(self, rhs: Bivec3) -> Self::Output {
+		// Bivec3 {
+		//     yz: -YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx: ZX(self.x.0 * rhs.xy.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy: -XY(self.x.0 * rhs.zx.0) + XY(self.y.0 * rhs.yz.0),
+		// }
+		Bivec3 {
+			yz: self.y.anti_dot(rhs.xy) + self.z.anti_dot(rhs.zx),
+			zx: self.x.anti_dot(rhs.xy) + self.z.anti_dot(rhs.yz),
+			xy: self.x.anti_dot(rhs.zx) + self.y.anti_dot(rhs.yz),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Rotor3:
+
+// Vec3.geometric(Rotor3) -> Multivector
+impl Geometric<Rotor3> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0) + Z(self.z.0 * rhs.s.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.x.0 * rhs.yz.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy) + self.z.geometric(rhs.zx),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s) + self.z.geometric(rhs.yz),
+			z: self.x.geometric(rhs.zx) + self.y.geometric(rhs.yz) + self.z.geometric(rhs.s),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.x.geometric(rhs.yz) + self.y.geometric(rhs.zx) + self.z.geometric(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::Mul<Rotor3> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Rotor3) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Rotor3) -> Rotor3
+impl AntiGeometric<Rotor3> for Vec3 {
+	type Output = Rotor3;
+	fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+		// Rotor3 {
+		//     s : S(self.x.0 * rhs.yz.0) + S(self.y.0 * rhs.zx.0) + S(self.z.0 * rhs.xy.0),
+		//     yz: -YZ(self.x.0 * rhs.s.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx: ZX(self.x.0 * rhs.xy.0) - ZX(self.y.0 * rhs.s.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy: -XY(self.x.0 * rhs.zx.0) + XY(self.y.0 * rhs.yz.0) - XY(self.z.0 * rhs.s.0),
+		// }
+		Rotor3 {
+			s: self.x.anti_geometric(rhs.yz) + self.y.anti_geometric(rhs.zx) + self.z.anti_geometric(rhs.xy),
+			yz: self.x.anti_geometric(rhs.s) + self.y.anti_geometric(rhs.xy) + self.z.anti_geometric(rhs.zx),
+			zx: self.x.anti_geometric(rhs.xy) + self.y.anti_geometric(rhs.s) + self.z.anti_geometric(rhs.yz),
+			xy: self.x.anti_geometric(rhs.zx) + self.y.anti_geometric(rhs.yz) + self.z.anti_geometric(rhs.s),
+		}
+	}
+}
+
+// Vec3.dot(Rotor3) -> Vec3
+impl Dot<Rotor3> for Vec3 {
+	type Output = Vec3;
+	fn dot(self, rhs: Rotor3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y: Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0) - Y(self.z.0 * rhs.yz.0),
+		//     z: -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0) + Z(self.z.0 * rhs.s.0),
+		// }
+		Vec3 {
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy) + self.z.dot(rhs.zx),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s) + self.z.dot(rhs.yz),
+			z: self.x.dot(rhs.zx) + self.y.dot(rhs.yz) + self.z.dot(rhs.s),
+		}
+	}
+}
+
+impl std::ops::BitOr<Rotor3> for Vec3 {
+	type Output = Vec3;
+	fn bitor(self, rhs: Rotor3) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Rotor3) -> Multivector
+impl Wedge<Rotor3> for Vec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Rotor3) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     z  : Z(self.z.0 * rhs.s.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: XYZ(self.x.0 * rhs.yz.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			z: self.z.wedge(rhs.s),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: self.x.wedge(rhs.yz) + self.y.wedge(rhs.zx) + self.z.wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Rotor3> for Vec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Rotor3) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Rotor3) -> S
+impl AntiWedge<Rotor3> for Vec3 {
+	type Output = S;
+	fn anti_wedge(self, rhs: Rotor3) -> Self::Output {
+		// S(self.x.0 * rhs.yz.0) + S(self.y.0 * rhs.zx.0) + S(self.z.0 * rhs.xy.0)
+		self.x.anti_wedge(rhs.yz) + self.y.anti_wedge(rhs.zx) + self.z.anti_wedge(rhs.xy)
+	}
+}
+
+impl std::ops::BitAnd<Rotor3> for Vec3 {
+	type Output = S;
+	fn bitand(self, rhs: Rotor3) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Rotor3) -> Vec3
+impl LeftContraction<Rotor3> for Vec3 {
+	type Output = Vec3;
+	fn left_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y: Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z: -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		// }
+		Vec3 {
+			x: self.y.left_contraction(rhs.xy) + self.z.left_contraction(rhs.zx),
+			y: self.x.left_contraction(rhs.xy) + self.z.left_contraction(rhs.yz),
+			z: self.x.left_contraction(rhs.zx) + self.y.left_contraction(rhs.yz),
+		}
+	}
+}
+
+// Vec3.right_contraction(Rotor3) -> Vec3
+impl RightContraction<Rotor3> for Vec3 {
+	type Output = Vec3;
+	fn right_contraction(self, rhs: Rotor3) -> Self::Output {
+		// Vec3 {
+		//     x: X(self.x.0 * rhs.s.0),
+		//     y: Y(self.y.0 * rhs.s.0),
+		//     z: Z(self.z.0 * rhs.s.0),
+		// }
+		Vec3 {
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			z: self.z.right_contraction(rhs.s),
+		}
+	}
+}
+
+impl ScalarProduct<Rotor3> for Vec3 {
+	type Output = Zero;
+	fn scalar_product(self, _rhs: Rotor3) -> Self::Output {
+		Zero {}
+	}
+}
+
+// Vec3.commutator(Rotor3) -> Vec3
+impl Commutator<Rotor3> for Vec3 {
+	type Output = Vec3;
+	fn commutator(self, rhs: Rotor3) -> Self::Output {
+		// Vec3 {
+		//     x: -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y: Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z: -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		// }
+		Vec3 {
+			x: self.y.commutator(rhs.xy) + self.z.commutator(rhs.zx),
+			y: self.x.commutator(rhs.xy) + self.z.commutator(rhs.yz),
+			z: self.x.commutator(rhs.zx) + self.y.commutator(rhs.yz),
+		}
+	}
+}
+
+// Vec3.anti_dot(Rotor3) -> Bivec3
+impl AntiDot<Rotor3> for Vec3 {
+	type Output = Bivec3;
+	fn anti_dot(self, rhs: Rotor3) -> Self::Output {
+		// Bivec3 {
+		//     yz: -YZ(self.x.0 * rhs.s.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx: ZX(self.x.0 * rhs.xy.0) - ZX(self.y.0 * rhs.s.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy: -XY(self.x.0 * rhs.zx.0) + XY(self.y.0 * rhs.yz.0) - XY(self.z.0 * rhs.s.0),
+		// }
+		Bivec3 {
+			yz: self.x.anti_dot(rhs.s) + self.y.anti_dot(rhs.xy) + self.z.anti_dot(rhs.zx),
+			zx: self.x.anti_dot(rhs.xy) + self.y.anti_dot(rhs.s) + self.z.anti_dot(rhs.yz),
+			xy: self.x.anti_dot(rhs.zx) + self.y.anti_dot(rhs.yz) + self.z.anti_dot(rhs.s),
+		}
+	}
+}
+
+// ---------------------------------------------------------------------
+// Vec3 OP Multivector:
+
+// Vec3.geometric(Multivector) -> Multivector
+impl Geometric<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0) + Z(self.z.0 * rhs.s.0),
+		//     yz : YZ(self.x.0 * rhs.xyz.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx : -ZX(self.x.0 * rhs.z.0) + ZX(self.y.0 * rhs.xyz.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0) + XY(self.z.0 * rhs.xyz.0),
+		//     xyz: XYZ(self.x.0 * rhs.yz.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: self.x.geometric(rhs.x) + self.y.geometric(rhs.y) + self.z.geometric(rhs.z),
+			x: self.x.geometric(rhs.s) + self.y.geometric(rhs.xy) + self.z.geometric(rhs.zx),
+			y: self.x.geometric(rhs.xy) + self.y.geometric(rhs.s) + self.z.geometric(rhs.yz),
+			z: self.x.geometric(rhs.zx) + self.y.geometric(rhs.yz) + self.z.geometric(rhs.s),
+			yz: self.x.geometric(rhs.xyz) + self.y.geometric(rhs.z) + self.z.geometric(rhs.y),
+			zx: self.x.geometric(rhs.z) + self.y.geometric(rhs.xyz) + self.z.geometric(rhs.x),
+			xy: self.x.geometric(rhs.y) + self.y.geometric(rhs.x) + self.z.geometric(rhs.xyz),
+			xyz: self.x.geometric(rhs.yz) + self.y.geometric(rhs.zx) + self.z.geometric(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::Mul<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Self::Output {
+		self.geometric(rhs)
+	}
+}
+
+// Vec3.anti_geometric(Multivector) -> Multivector
+impl AntiGeometric<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn anti_geometric(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.yz.0) + S(self.y.0 * rhs.zx.0) + S(self.z.0 * rhs.xy.0),
+		//     x  : X(self.x.0 * rhs.xyz.0) - X(self.y.0 * rhs.z.0) + X(self.z.0 * rhs.y.0),
+		//     y  : Y(self.x.0 * rhs.z.0) + Y(self.y.0 * rhs.xyz.0) - Y(self.z.0 * rhs.x.0),
+		//     z  : -Z(self.x.0 * rhs.y.0) + Z(self.y.0 * rhs.x.0) + Z(self.z.0 * rhs.xyz.0),
+		//     yz : -YZ(self.x.0 * rhs.s.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx : ZX(self.x.0 * rhs.xy.0) - ZX(self.y.0 * rhs.s.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy : -XY(self.x.0 * rhs.zx.0) + XY(self.y.0 * rhs.yz.0) - XY(self.z.0 * rhs.s.0),
+		//     xyz: -XYZ(self.x.0 * rhs.x.0) - XYZ(self.y.0 * rhs.y.0) - XYZ(self.z.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: self.x.anti_geometric(rhs.yz) + self.y.anti_geometric(rhs.zx) + self.z.anti_geometric(rhs.xy),
+			x: self.x.anti_geometric(rhs.xyz) + self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+			y: self.x.anti_geometric(rhs.z) + self.y.anti_geometric(rhs.xyz) + self.z.anti_geometric(rhs.x),
+			z: self.x.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.x) + self.z.anti_geometric(rhs.xyz),
+			yz: self.x.anti_geometric(rhs.s) + self.y.anti_geometric(rhs.xy) + self.z.anti_geometric(rhs.zx),
+			zx: self.x.anti_geometric(rhs.xy) + self.y.anti_geometric(rhs.s) + self.z.anti_geometric(rhs.yz),
+			xy: self.x.anti_geometric(rhs.zx) + self.y.anti_geometric(rhs.yz) + self.z.anti_geometric(rhs.s),
+			xyz: self.x.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.z),
+		}
+	}
+}
+
+// Vec3.dot(Multivector) -> Multivector
+impl Dot<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x  : X(self.x.0 * rhs.s.0) - X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) + Y(self.y.0 * rhs.s.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0) + Z(self.z.0 * rhs.s.0),
+		//     yz : YZ(self.x.0 * rhs.xyz.0),
+		//     zx : ZX(self.y.0 * rhs.xyz.0),
+		//     xy : XY(self.z.0 * rhs.xyz.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.x.dot(rhs.x) + self.y.dot(rhs.y) + self.z.dot(rhs.z),
+			x: self.x.dot(rhs.s) + self.y.dot(rhs.xy) + self.z.dot(rhs.zx),
+			y: self.x.dot(rhs.xy) + self.y.dot(rhs.s) + self.z.dot(rhs.yz),
+			z: self.x.dot(rhs.zx) + self.y.dot(rhs.yz) + self.z.dot(rhs.s),
+			yz: self.x.dot(rhs.xyz),
+			zx: self.y.dot(rhs.xyz),
+			xy: self.z.dot(rhs.xyz),
+			xyz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitOr<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn bitor(self, rhs: Multivector) -> Self::Output {
+		self.dot(rhs)
+	}
+}
+
+// Vec3.wedge(Multivector) -> Multivector
+impl Wedge<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     z  : Z(self.z.0 * rhs.s.0),
+		//     yz : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyz: XYZ(self.x.0 * rhs.yz.0) + XYZ(self.y.0 * rhs.zx.0) + XYZ(self.z.0 * rhs.xy.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.wedge(rhs.s),
+			y: self.y.wedge(rhs.s),
+			z: self.z.wedge(rhs.s),
+			yz: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+			zx: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+			xy: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+			xyz: self.x.wedge(rhs.yz) + self.y.wedge(rhs.zx) + self.z.wedge(rhs.xy),
+		}
+	}
+}
+
+impl std::ops::BitXor<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn bitxor(self, rhs: Multivector) -> Self::Output {
+		self.wedge(rhs)
+	}
+}
+
+// Vec3.anti_wedge(Multivector) -> Multivector
+impl AntiWedge<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn anti_wedge(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.yz.0) + S(self.y.0 * rhs.zx.0) + S(self.z.0 * rhs.xy.0),
+		//     x  : X(self.x.0 * rhs.xyz.0),
+		//     y  : Y(self.y.0 * rhs.xyz.0),
+		//     z  : Z(self.z.0 * rhs.xyz.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.x.anti_wedge(rhs.yz) + self.y.anti_wedge(rhs.zx) + self.z.anti_wedge(rhs.xy),
+			x: self.x.anti_wedge(rhs.xyz),
+			y: self.y.anti_wedge(rhs.xyz),
+			z: self.z.anti_wedge(rhs.xyz),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: Default::default(),
+		}
+	}
+}
+
+impl std::ops::BitAnd<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn bitand(self, rhs: Multivector) -> Self::Output {
+		self.anti_wedge(rhs)
+	}
+}
+
+// Vec3.left_contraction(Multivector) -> Multivector
+impl LeftContraction<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn left_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x  : -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		//     yz : YZ(self.x.0 * rhs.xyz.0),
+		//     zx : ZX(self.y.0 * rhs.xyz.0),
+		//     xy : XY(self.z.0 * rhs.xyz.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.x.left_contraction(rhs.x) + self.y.left_contraction(rhs.y) + self.z.left_contraction(rhs.z),
+			x: self.y.left_contraction(rhs.xy) + self.z.left_contraction(rhs.zx),
+			y: self.x.left_contraction(rhs.xy) + self.z.left_contraction(rhs.yz),
+			z: self.x.left_contraction(rhs.zx) + self.y.left_contraction(rhs.yz),
+			yz: self.x.left_contraction(rhs.xyz),
+			zx: self.y.left_contraction(rhs.xyz),
+			xy: self.z.left_contraction(rhs.xyz),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Vec3.right_contraction(Multivector) -> Multivector
+impl RightContraction<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn right_contraction(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0),
+		//     x  : X(self.x.0 * rhs.s.0),
+		//     y  : Y(self.y.0 * rhs.s.0),
+		//     z  : Z(self.z.0 * rhs.s.0),
+		//     yz : Default::default(),
+		//     zx : Default::default(),
+		//     xy : Default::default(),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: self.x.right_contraction(rhs.x) + self.y.right_contraction(rhs.y) + self.z.right_contraction(rhs.z),
+			x: self.x.right_contraction(rhs.s),
+			y: self.y.right_contraction(rhs.s),
+			z: self.z.right_contraction(rhs.s),
+			yz: Default::default(),
+			zx: Default::default(),
+			xy: Default::default(),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Vec3.scalar_product(Multivector) -> S
+impl ScalarProduct<Multivector> for Vec3 {
+	type Output = S;
+	fn scalar_product(self, rhs: Multivector) -> Self::Output {
+		// S(self.x.0 * rhs.x.0) + S(self.y.0 * rhs.y.0) + S(self.z.0 * rhs.z.0)
+		self.x.scalar_product(rhs.x) + self.y.scalar_product(rhs.y) + self.z.scalar_product(rhs.z)
+	}
+}
+
+// Vec3.commutator(Multivector) -> Multivector
+impl Commutator<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn commutator(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : -X(self.y.0 * rhs.xy.0) + X(self.z.0 * rhs.zx.0),
+		//     y  : Y(self.x.0 * rhs.xy.0) - Y(self.z.0 * rhs.yz.0),
+		//     z  : -Z(self.x.0 * rhs.zx.0) + Z(self.y.0 * rhs.yz.0),
+		//     yz : YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+		//     zx : -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+		//     xy : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+		//     xyz: Default::default(),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.y.commutator(rhs.xy) + self.z.commutator(rhs.zx),
+			y: self.x.commutator(rhs.xy) + self.z.commutator(rhs.yz),
+			z: self.x.commutator(rhs.zx) + self.y.commutator(rhs.yz),
+			yz: self.y.commutator(rhs.z) + self.z.commutator(rhs.y),
+			zx: self.x.commutator(rhs.z) + self.z.commutator(rhs.x),
+			xy: self.x.commutator(rhs.y) + self.y.commutator(rhs.x),
+			xyz: Default::default(),
+		}
+	}
+}
+
+// Vec3.anti_dot(Multivector) -> Multivector
+impl AntiDot<Multivector> for Vec3 {
+	type Output = Multivector;
+	fn anti_dot(self, rhs: Multivector) -> Self::Output {
+		// Multivector {
+		//     s  : Default::default(),
+		//     x  : X(self.x.0 * rhs.xyz.0),
+		//     y  : Y(self.y.0 * rhs.xyz.0),
+		//     z  : Z(self.z.0 * rhs.xyz.0),
+		//     yz : -YZ(self.x.0 * rhs.s.0) - YZ(self.y.0 * rhs.xy.0) + YZ(self.z.0 * rhs.zx.0),
+		//     zx : ZX(self.x.0 * rhs.xy.0) - ZX(self.y.0 * rhs.s.0) - ZX(self.z.0 * rhs.yz.0),
+		//     xy : -XY(self.x.0 * rhs.zx.0) + XY(self.y.0 * rhs.yz.0) - XY(self.z.0 * rhs.s.0),
+		//     xyz: -XYZ(self.x.0 * rhs.x.0) - XYZ(self.y.0 * rhs.y.0) - XYZ(self.z.0 * rhs.z.0),
+		// }
+		Multivector {
+			s: Default::default(),
+			x: self.x.anti_dot(rhs.xyz),
+			y: self.y.anti_dot(rhs.xyz),
+			z: self.z.anti_dot(rhs.xyz),
+			yz: self.x.anti_dot(rhs.s) + self.y.anti_dot(rhs.xy) + self.z.anti_dot(rhs.zx),
+			zx: self.x.anti_dot(rhs.xy) + self.y.anti_dot(rhs.s) + self.z.anti_dot(rhs.yz),
+			xy: self.x.anti_dot(rhs.zx) + self.y.anti_dot(rhs.yz) + self.z.anti_dot(rhs.s),
+			xyz: self.x.anti_dot(rhs.x) + self.y.anti_dot(rhs.y) + self.z.anti_dot(rhs.z),
+		}
+	}
+}
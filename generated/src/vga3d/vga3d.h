@@ -0,0 +1,246 @@
+/* The C ABI of the `vga3d` geometric algebra, generated together with its Rust code.
+ * All values are passed by value, and the structs have the same layout as in Rust. */
+
+#ifndef VGA3D_H
+#define VGA3D_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct vga3d_Vec3 {
+	double x; /* X */
+	double y; /* Y */
+	double z; /* Z */
+} vga3d_Vec3;
+
+typedef struct vga3d_Bivec3 {
+	double yz; /* YZ */
+	double zx; /* ZX */
+	double xy; /* XY */
+} vga3d_Bivec3;
+
+typedef struct vga3d_Rotor3 {
+	double s; /* S */
+	double yz; /* YZ */
+	double zx; /* ZX */
+	double xy; /* XY */
+} vga3d_Rotor3;
+
+typedef struct vga3d_Multivector {
+	double s; /* S */
+	double x; /* X */
+	double y; /* Y */
+	double z; /* Z */
+	double yz; /* YZ */
+	double zx; /* ZX */
+	double xy; /* XY */
+	double xyz; /* XYZ */
+} vga3d_Multivector;
+
+vga3d_Bivec3 vga3d_vec3_rcompl(vga3d_Vec3 value);
+vga3d_Bivec3 vga3d_vec3_lcompl(vga3d_Vec3 value);
+vga3d_Vec3 vga3d_vec3_rev(vga3d_Vec3 value);
+vga3d_Vec3 vga3d_vec3_arev(vga3d_Vec3 value);
+vga3d_Vec3 vga3d_vec3_involute(vga3d_Vec3 value);
+vga3d_Vec3 vga3d_vec3_conjugate(vga3d_Vec3 value);
+vga3d_Bivec3 vga3d_vec3_dual(vga3d_Vec3 value);
+vga3d_Vec3 vga3d_vec3_bulk(vga3d_Vec3 value);
+vga3d_Vec3 vga3d_vec3_weight(vga3d_Vec3 value);
+vga3d_Bivec3 vga3d_vec3_bulk_dual(vga3d_Vec3 value);
+vga3d_Bivec3 vga3d_vec3_weight_dual(vga3d_Vec3 value);
+vga3d_Rotor3 vga3d_vec3_geometric_vec3(vga3d_Vec3 lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_vec3_anti_geometric_vec3(vga3d_Vec3 lhs, vga3d_Vec3 rhs);
+double vga3d_vec3_dot_vec3(vga3d_Vec3 lhs, vga3d_Vec3 rhs);
+vga3d_Bivec3 vga3d_vec3_wedge_vec3(vga3d_Vec3 lhs, vga3d_Vec3 rhs);
+double vga3d_vec3_left_contraction_vec3(vga3d_Vec3 lhs, vga3d_Vec3 rhs);
+double vga3d_vec3_right_contraction_vec3(vga3d_Vec3 lhs, vga3d_Vec3 rhs);
+double vga3d_vec3_scalar_product_vec3(vga3d_Vec3 lhs, vga3d_Vec3 rhs);
+vga3d_Bivec3 vga3d_vec3_commutator_vec3(vga3d_Vec3 lhs, vga3d_Vec3 rhs);
+double vga3d_vec3_anti_dot_vec3(vga3d_Vec3 lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_vec3_geometric_bivec3(vga3d_Vec3 lhs, vga3d_Bivec3 rhs);
+vga3d_Rotor3 vga3d_vec3_anti_geometric_bivec3(vga3d_Vec3 lhs, vga3d_Bivec3 rhs);
+vga3d_Vec3 vga3d_vec3_dot_bivec3(vga3d_Vec3 lhs, vga3d_Bivec3 rhs);
+double vga3d_vec3_wedge_bivec3(vga3d_Vec3 lhs, vga3d_Bivec3 rhs);
+double vga3d_vec3_anti_wedge_bivec3(vga3d_Vec3 lhs, vga3d_Bivec3 rhs);
+vga3d_Vec3 vga3d_vec3_left_contraction_bivec3(vga3d_Vec3 lhs, vga3d_Bivec3 rhs);
+vga3d_Vec3 vga3d_vec3_commutator_bivec3(vga3d_Vec3 lhs, vga3d_Bivec3 rhs);
+vga3d_Bivec3 vga3d_vec3_anti_dot_bivec3(vga3d_Vec3 lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_vec3_geometric_rotor3(vga3d_Vec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Rotor3 vga3d_vec3_anti_geometric_rotor3(vga3d_Vec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Vec3 vga3d_vec3_dot_rotor3(vga3d_Vec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_vec3_wedge_rotor3(vga3d_Vec3 lhs, vga3d_Rotor3 rhs);
+double vga3d_vec3_anti_wedge_rotor3(vga3d_Vec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Vec3 vga3d_vec3_left_contraction_rotor3(vga3d_Vec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Vec3 vga3d_vec3_right_contraction_rotor3(vga3d_Vec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Vec3 vga3d_vec3_commutator_rotor3(vga3d_Vec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Bivec3 vga3d_vec3_anti_dot_rotor3(vga3d_Vec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_vec3_geometric_multivector(vga3d_Vec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_vec3_anti_geometric_multivector(vga3d_Vec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_vec3_dot_multivector(vga3d_Vec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_vec3_wedge_multivector(vga3d_Vec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_vec3_anti_wedge_multivector(vga3d_Vec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_vec3_left_contraction_multivector(vga3d_Vec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_vec3_right_contraction_multivector(vga3d_Vec3 lhs, vga3d_Multivector rhs);
+double vga3d_vec3_scalar_product_multivector(vga3d_Vec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_vec3_commutator_multivector(vga3d_Vec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_vec3_anti_dot_multivector(vga3d_Vec3 lhs, vga3d_Multivector rhs);
+vga3d_Vec3 vga3d_bivec3_rcompl(vga3d_Bivec3 value);
+vga3d_Vec3 vga3d_bivec3_lcompl(vga3d_Bivec3 value);
+vga3d_Bivec3 vga3d_bivec3_rev(vga3d_Bivec3 value);
+vga3d_Bivec3 vga3d_bivec3_arev(vga3d_Bivec3 value);
+vga3d_Bivec3 vga3d_bivec3_involute(vga3d_Bivec3 value);
+vga3d_Bivec3 vga3d_bivec3_conjugate(vga3d_Bivec3 value);
+vga3d_Vec3 vga3d_bivec3_dual(vga3d_Bivec3 value);
+vga3d_Bivec3 vga3d_bivec3_bulk(vga3d_Bivec3 value);
+vga3d_Bivec3 vga3d_bivec3_weight(vga3d_Bivec3 value);
+vga3d_Vec3 vga3d_bivec3_bulk_dual(vga3d_Bivec3 value);
+vga3d_Vec3 vga3d_bivec3_weight_dual(vga3d_Bivec3 value);
+vga3d_Multivector vga3d_bivec3_geometric_vec3(vga3d_Bivec3 lhs, vga3d_Vec3 rhs);
+vga3d_Rotor3 vga3d_bivec3_anti_geometric_vec3(vga3d_Bivec3 lhs, vga3d_Vec3 rhs);
+vga3d_Vec3 vga3d_bivec3_dot_vec3(vga3d_Bivec3 lhs, vga3d_Vec3 rhs);
+double vga3d_bivec3_wedge_vec3(vga3d_Bivec3 lhs, vga3d_Vec3 rhs);
+double vga3d_bivec3_anti_wedge_vec3(vga3d_Bivec3 lhs, vga3d_Vec3 rhs);
+vga3d_Vec3 vga3d_bivec3_right_contraction_vec3(vga3d_Bivec3 lhs, vga3d_Vec3 rhs);
+vga3d_Vec3 vga3d_bivec3_commutator_vec3(vga3d_Bivec3 lhs, vga3d_Vec3 rhs);
+vga3d_Bivec3 vga3d_bivec3_anti_dot_vec3(vga3d_Bivec3 lhs, vga3d_Vec3 rhs);
+vga3d_Rotor3 vga3d_bivec3_geometric_bivec3(vga3d_Bivec3 lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_bivec3_anti_geometric_bivec3(vga3d_Bivec3 lhs, vga3d_Bivec3 rhs);
+double vga3d_bivec3_dot_bivec3(vga3d_Bivec3 lhs, vga3d_Bivec3 rhs);
+vga3d_Vec3 vga3d_bivec3_anti_wedge_bivec3(vga3d_Bivec3 lhs, vga3d_Bivec3 rhs);
+double vga3d_bivec3_left_contraction_bivec3(vga3d_Bivec3 lhs, vga3d_Bivec3 rhs);
+double vga3d_bivec3_right_contraction_bivec3(vga3d_Bivec3 lhs, vga3d_Bivec3 rhs);
+double vga3d_bivec3_scalar_product_bivec3(vga3d_Bivec3 lhs, vga3d_Bivec3 rhs);
+vga3d_Bivec3 vga3d_bivec3_commutator_bivec3(vga3d_Bivec3 lhs, vga3d_Bivec3 rhs);
+double vga3d_bivec3_anti_dot_bivec3(vga3d_Bivec3 lhs, vga3d_Bivec3 rhs);
+vga3d_Rotor3 vga3d_bivec3_geometric_rotor3(vga3d_Bivec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_bivec3_anti_geometric_rotor3(vga3d_Bivec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Rotor3 vga3d_bivec3_dot_rotor3(vga3d_Bivec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Bivec3 vga3d_bivec3_wedge_rotor3(vga3d_Bivec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Vec3 vga3d_bivec3_anti_wedge_rotor3(vga3d_Bivec3 lhs, vga3d_Rotor3 rhs);
+double vga3d_bivec3_left_contraction_rotor3(vga3d_Bivec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Rotor3 vga3d_bivec3_right_contraction_rotor3(vga3d_Bivec3 lhs, vga3d_Rotor3 rhs);
+double vga3d_bivec3_scalar_product_rotor3(vga3d_Bivec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Bivec3 vga3d_bivec3_commutator_rotor3(vga3d_Bivec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_bivec3_anti_dot_rotor3(vga3d_Bivec3 lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_bivec3_geometric_multivector(vga3d_Bivec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_bivec3_anti_geometric_multivector(vga3d_Bivec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_bivec3_dot_multivector(vga3d_Bivec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_bivec3_wedge_multivector(vga3d_Bivec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_bivec3_anti_wedge_multivector(vga3d_Bivec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_bivec3_left_contraction_multivector(vga3d_Bivec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_bivec3_right_contraction_multivector(vga3d_Bivec3 lhs, vga3d_Multivector rhs);
+double vga3d_bivec3_scalar_product_multivector(vga3d_Bivec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_bivec3_commutator_multivector(vga3d_Bivec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_bivec3_anti_dot_multivector(vga3d_Bivec3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_rotor3_rcompl(vga3d_Rotor3 value);
+vga3d_Multivector vga3d_rotor3_lcompl(vga3d_Rotor3 value);
+vga3d_Rotor3 vga3d_rotor3_rev(vga3d_Rotor3 value);
+vga3d_Rotor3 vga3d_rotor3_arev(vga3d_Rotor3 value);
+vga3d_Rotor3 vga3d_rotor3_involute(vga3d_Rotor3 value);
+vga3d_Rotor3 vga3d_rotor3_conjugate(vga3d_Rotor3 value);
+vga3d_Multivector vga3d_rotor3_dual(vga3d_Rotor3 value);
+vga3d_Rotor3 vga3d_rotor3_bulk(vga3d_Rotor3 value);
+vga3d_Rotor3 vga3d_rotor3_weight(vga3d_Rotor3 value);
+vga3d_Multivector vga3d_rotor3_bulk_dual(vga3d_Rotor3 value);
+vga3d_Multivector vga3d_rotor3_weight_dual(vga3d_Rotor3 value);
+vga3d_Multivector vga3d_rotor3_geometric_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+vga3d_Rotor3 vga3d_rotor3_anti_geometric_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+vga3d_Vec3 vga3d_rotor3_dot_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_rotor3_wedge_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+double vga3d_rotor3_anti_wedge_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+vga3d_Vec3 vga3d_rotor3_left_contraction_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+vga3d_Vec3 vga3d_rotor3_right_contraction_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+vga3d_Vec3 vga3d_rotor3_commutator_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+vga3d_Bivec3 vga3d_rotor3_anti_dot_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+vga3d_Rotor3 vga3d_rotor3_geometric_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_rotor3_anti_geometric_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+vga3d_Rotor3 vga3d_rotor3_dot_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+vga3d_Bivec3 vga3d_rotor3_wedge_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+vga3d_Vec3 vga3d_rotor3_anti_wedge_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+vga3d_Rotor3 vga3d_rotor3_left_contraction_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+double vga3d_rotor3_right_contraction_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+double vga3d_rotor3_scalar_product_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+vga3d_Bivec3 vga3d_rotor3_commutator_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_rotor3_anti_dot_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+vga3d_Rotor3 vga3d_rotor3_geometric_rotor3(vga3d_Rotor3 lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_rotor3_anti_geometric_rotor3(vga3d_Rotor3 lhs, vga3d_Rotor3 rhs);
+vga3d_Rotor3 vga3d_rotor3_dot_rotor3(vga3d_Rotor3 lhs, vga3d_Rotor3 rhs);
+vga3d_Rotor3 vga3d_rotor3_wedge_rotor3(vga3d_Rotor3 lhs, vga3d_Rotor3 rhs);
+vga3d_Vec3 vga3d_rotor3_anti_wedge_rotor3(vga3d_Rotor3 lhs, vga3d_Rotor3 rhs);
+vga3d_Rotor3 vga3d_rotor3_left_contraction_rotor3(vga3d_Rotor3 lhs, vga3d_Rotor3 rhs);
+vga3d_Rotor3 vga3d_rotor3_right_contraction_rotor3(vga3d_Rotor3 lhs, vga3d_Rotor3 rhs);
+double vga3d_rotor3_scalar_product_rotor3(vga3d_Rotor3 lhs, vga3d_Rotor3 rhs);
+vga3d_Bivec3 vga3d_rotor3_commutator_rotor3(vga3d_Rotor3 lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_rotor3_anti_dot_rotor3(vga3d_Rotor3 lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_rotor3_geometric_multivector(vga3d_Rotor3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_rotor3_anti_geometric_multivector(vga3d_Rotor3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_rotor3_dot_multivector(vga3d_Rotor3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_rotor3_wedge_multivector(vga3d_Rotor3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_rotor3_anti_wedge_multivector(vga3d_Rotor3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_rotor3_left_contraction_multivector(vga3d_Rotor3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_rotor3_right_contraction_multivector(vga3d_Rotor3 lhs, vga3d_Multivector rhs);
+double vga3d_rotor3_scalar_product_multivector(vga3d_Rotor3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_rotor3_commutator_multivector(vga3d_Rotor3 lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_rotor3_anti_dot_multivector(vga3d_Rotor3 lhs, vga3d_Multivector rhs);
+vga3d_Vec3 vga3d_rotor3_sandwich_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+vga3d_Vec3 vga3d_rotor3_anti_sandwich_vec3(vga3d_Rotor3 lhs, vga3d_Vec3 rhs);
+vga3d_Bivec3 vga3d_rotor3_sandwich_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+vga3d_Bivec3 vga3d_rotor3_anti_sandwich_bivec3(vga3d_Rotor3 lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_multivector_rcompl(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_lcompl(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_rev(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_arev(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_involute(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_conjugate(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_dual(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_bulk(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_weight(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_bulk_dual(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_weight_dual(vga3d_Multivector value);
+vga3d_Multivector vga3d_multivector_geometric_vec3(vga3d_Multivector lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_multivector_anti_geometric_vec3(vga3d_Multivector lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_multivector_dot_vec3(vga3d_Multivector lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_multivector_wedge_vec3(vga3d_Multivector lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_multivector_anti_wedge_vec3(vga3d_Multivector lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_multivector_left_contraction_vec3(vga3d_Multivector lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_multivector_right_contraction_vec3(vga3d_Multivector lhs, vga3d_Vec3 rhs);
+double vga3d_multivector_scalar_product_vec3(vga3d_Multivector lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_multivector_commutator_vec3(vga3d_Multivector lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_multivector_anti_dot_vec3(vga3d_Multivector lhs, vga3d_Vec3 rhs);
+vga3d_Multivector vga3d_multivector_geometric_bivec3(vga3d_Multivector lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_multivector_anti_geometric_bivec3(vga3d_Multivector lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_multivector_dot_bivec3(vga3d_Multivector lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_multivector_wedge_bivec3(vga3d_Multivector lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_multivector_anti_wedge_bivec3(vga3d_Multivector lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_multivector_left_contraction_bivec3(vga3d_Multivector lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_multivector_right_contraction_bivec3(vga3d_Multivector lhs, vga3d_Bivec3 rhs);
+double vga3d_multivector_scalar_product_bivec3(vga3d_Multivector lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_multivector_commutator_bivec3(vga3d_Multivector lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_multivector_anti_dot_bivec3(vga3d_Multivector lhs, vga3d_Bivec3 rhs);
+vga3d_Multivector vga3d_multivector_geometric_rotor3(vga3d_Multivector lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_multivector_anti_geometric_rotor3(vga3d_Multivector lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_multivector_dot_rotor3(vga3d_Multivector lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_multivector_wedge_rotor3(vga3d_Multivector lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_multivector_anti_wedge_rotor3(vga3d_Multivector lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_multivector_left_contraction_rotor3(vga3d_Multivector lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_multivector_right_contraction_rotor3(vga3d_Multivector lhs, vga3d_Rotor3 rhs);
+double vga3d_multivector_scalar_product_rotor3(vga3d_Multivector lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_multivector_commutator_rotor3(vga3d_Multivector lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_multivector_anti_dot_rotor3(vga3d_Multivector lhs, vga3d_Rotor3 rhs);
+vga3d_Multivector vga3d_multivector_geometric_multivector(vga3d_Multivector lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_multivector_anti_geometric_multivector(vga3d_Multivector lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_multivector_dot_multivector(vga3d_Multivector lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_multivector_wedge_multivector(vga3d_Multivector lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_multivector_anti_wedge_multivector(vga3d_Multivector lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_multivector_left_contraction_multivector(vga3d_Multivector lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_multivector_right_contraction_multivector(vga3d_Multivector lhs, vga3d_Multivector rhs);
+double vga3d_multivector_scalar_product_multivector(vga3d_Multivector lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_multivector_commutator_multivector(vga3d_Multivector lhs, vga3d_Multivector rhs);
+vga3d_Multivector vga3d_multivector_anti_dot_multivector(vga3d_Multivector lhs, vga3d_Multivector rhs);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* VGA3D_H */
@@ -0,0 +1,119 @@
+//! Sanity checks of the non-projective algebras.
+
+fn assert_approx_eq(a: f64, b: f64) {
+	assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+}
+
+#[test]
+fn test_vga3d() {
+	use generated::vga3d::*;
+
+	let v = Vec3 {
+		x: X(1.0),
+		y: Y(2.0),
+		z: Z(3.0),
+	};
+	assert_approx_eq(v.square(), 14.0);
+
+	// Rotate a quarter turn from X towards Y:
+	let half_angle = std::f64::consts::FRAC_PI_4;
+	let rotor = Rotor3 {
+		s: S(half_angle.cos()),
+		yz: YZ(0.0),
+		zx: ZX(0.0),
+		xy: XY(-half_angle.sin()),
+	};
+	let rotated = rotor.sandwich(v);
+	assert_approx_eq(rotated.x.0, -2.0);
+	assert_approx_eq(rotated.y.0, 1.0);
+	assert_approx_eq(rotated.z.0, 3.0);
+}
+
+#[test]
+fn test_sta() {
+	use generated::sta::*;
+
+	let event = Vec4 {
+		t: E0(3.0),
+		x: E1(1.0),
+		y: E2(0.5),
+		z: E3(-2.0),
+	};
+	let interval = |v: Vec4| v.dot(v).0;
+	assert_approx_eq(interval(event), 9.0 - 1.0 - 0.25 - 4.0);
+
+	// A boost along X:
+	let rapidity: f64 = 0.7;
+	let boost = Rotor {
+		s: S((rapidity / 2.0).cosh()),
+		e01: E01((rapidity / 2.0).sinh()),
+		..Default::default()
+	};
+	let boosted = boost.sandwich(event);
+	let (cosh, sinh) = (rapidity.cosh(), rapidity.sinh());
+	assert_approx_eq(boosted.t.0, event.t.0 * cosh - event.x.0 * sinh);
+	assert_approx_eq(boosted.x.0, event.x.0 * cosh - event.t.0 * sinh);
+	assert_approx_eq(boosted.y.0, event.y.0);
+	assert_approx_eq(boosted.z.0, event.z.0);
+	assert_approx_eq(interval(boosted), interval(event));
+}
+
+#[test]
+fn test_cga3d() {
+	use generated::cga3d::*;
+
+	// x + Eo + x²/2 Ei
+	let up = |[x, y, z]: [f64; 3]| Point {
+		x: E1(x),
+		y: E2(y),
+		z: E3(z),
+		o: Eo(1.0),
+		inf: Ei((x * x + y * y + z * z) / 2.0),
+	};
+
+	// The inner product of two points is minus half their squared distance:
+	let a = up([1.0, 2.0, 3.0]);
+	let b = up([-1.0, 0.0, 5.0]);
+	assert_approx_eq(a.dot(a).0, 0.0);
+	assert_approx_eq(a.dot(b).0, -(4.0 + 4.0 + 4.0) / 2.0);
+
+	// `Eo * Ei = -1 + Eoi`
+	let eo_ei = Eo(1.0).geometric(Ei(1.0));
+	assert_approx_eq(eo_ei.s.0, -1.0);
+	assert_approx_eq(eo_ei.eoi.0, 1.0);
+
+	// A dual sphere `center - r²/2 Ei` is zero on the points of the sphere:
+	let center = up([1.0, 2.0, 3.0]);
+	let radius = 2.0;
+	let dual_sphere = Point {
+		inf: center.inf - Ei(radius * radius / 2.0),
+		..center
+	};
+	assert_approx_eq(up([1.0, 2.0, 5.0]).dot(dual_sphere).0, 0.0);
+	assert_approx_eq(up([1.0, 0.0, 3.0]).dot(dual_sphere).0, 0.0);
+	assert!(up([1.0, 2.0, 3.0]).dot(dual_sphere).0 > 0.1); // inside
+
+	// The sphere through four points contains all points at the same distance from its center:
+	let sphere = up([1.0, 0.0, 0.0])
+		.wedge(up([0.0, 1.0, 0.0]))
+		.wedge(up([0.0, 0.0, 1.0]))
+		.wedge(up([-1.0, 0.0, 0.0]));
+	assert_approx_eq(up([0.0, 0.0, -1.0]).wedge(sphere).0, 0.0);
+	assert_approx_eq(up([0.6, 0.0, 0.8]).wedge(sphere).0, 0.0);
+	assert!(up([0.0, 0.0, 0.0]).wedge(sphere).0.abs() > 0.1);
+
+	// `1 - t Ei / 2` translates by `t`:
+	let translator = Translator {
+		s: S(1.0),
+		e1i: E1i(-1.0 / 2.0),
+		e2i: E2i(-2.0 / 2.0),
+		e3i: E3i(-3.0 / 2.0),
+	};
+	let moved = translator.sandwich(up([4.0, 5.0, 6.0]));
+	let expected = up([5.0, 7.0, 9.0]);
+	assert_approx_eq(moved.x.0, expected.x.0);
+	assert_approx_eq(moved.y.0, expected.y.0);
+	assert_approx_eq(moved.z.0, expected.z.0);
+	assert_approx_eq(moved.o.0, expected.o.0);
+	assert_approx_eq(moved.inf.0, expected.inf.0);
+}
@@ -0,0 +1,68 @@
+//! The constructors of the built-in `pga2d` and `pga3d` structs.
+
+use std::f64::consts::FRAC_PI_2;
+
+fn assert_approx_eq(a: &[f64], b: &[f64]) {
+	assert_eq!(a.len(), b.len());
+	for (a_i, b_i) in a.iter().zip(b) {
+		assert!((a_i - b_i).abs() < 1e-9, "{:?} != {:?}", a, b);
+	}
+}
+
+#[test]
+fn test_pga3d_constructors() {
+	use generated::pga3d::*;
+
+	let point = Vec4::point(1.0, 2.0, 3.0);
+	assert_eq!(point.to_array(), [1.0, 2.0, 3.0, 1.0]);
+	assert_eq!(Vec4::direction(1.0, 2.0, 3.0).to_array(), [1.0, 2.0, 3.0, 0.0]);
+
+	let motor = Motor3::from_translation([4.0, -5.0, 6.0]);
+	assert_approx_eq(&motor.anti_sandwich(point).to_array(), &[5.0, -3.0, 9.0, 1.0]);
+
+	// A quarter turn around Z takes X to Y:
+	let rotor = Rotor3::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_2);
+	assert_approx_eq(
+		&rotor.anti_sandwich(Vec4::point(1.0, 0.0, 0.0)).to_array(),
+		&[0.0, 1.0, 0.0, 1.0],
+	);
+
+	let line = Line3::from_points([1.0, 2.0, 3.0], [2.0, 4.0, 3.0]);
+	assert_approx_eq(
+		&line.to_array(),
+		&Vec4::point(1.0, 2.0, 3.0).wedge(Vec4::point(2.0, 4.0, 3.0)).to_array(),
+	);
+
+	// The signed distance from the plane `z = 2`:
+	let plane = Plane::from_normal_and_distance([0.0, 0.0, 1.0], 2.0);
+	assert!((Point3::from([1.0, 2.0, 5.0]).wedge(plane).0 - 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_pga2d_constructors() {
+	use generated::pga2d::*;
+
+	let point = Vec3::point(1.0, 2.0);
+	assert_eq!(point.to_array(), [1.0, 2.0, 1.0]);
+	assert_eq!(Vec3::direction(1.0, 2.0).to_array(), [1.0, 2.0, 0.0]);
+
+	let motor = Motor::from_translation([3.0, -4.0]);
+	assert_approx_eq(&motor.sandwich(point.rcompl()).rcompl().to_array(), &[4.0, -2.0, 1.0]);
+
+	// A quarter turn takes X to Y:
+	let rotor = Rotor::from_angle(FRAC_PI_2);
+	assert_approx_eq(
+		&rotor.sandwich(Vec3::point(1.0, 0.0).rcompl()).rcompl().to_array(),
+		&[0.0, 1.0, 1.0],
+	);
+
+	let line = Line::from_points([1.0, 2.0], [3.0, 5.0]);
+	assert_approx_eq(
+		&line.to_array(),
+		&Vec3::point(1.0, 2.0).wedge(Vec3::point(3.0, 5.0)).to_array(),
+	);
+	assert_approx_eq(
+		&Line::from_point_and_direction([1.0, 2.0], [2.0, 3.0]).to_array(),
+		&line.to_array(),
+	);
+}
@@ -0,0 +1,117 @@
+//! `exp`, `ln` and `lerp_log` of the PGA bivectors and motors.
+
+fn assert_approx_eq(a: &[f64], b: &[f64]) {
+	assert_eq!(a.len(), b.len());
+	for (a_i, b_i) in a.iter().zip(b) {
+		assert!((a_i - b_i).abs() < 1e-9, "{:?} != {:?}", a, b);
+	}
+}
+
+/// Motors are the same motion up to sign.
+fn assert_same_motor(a: &[f64], b: &[f64]) {
+	let negated: Vec<f64> = b.iter().map(|b_i| -b_i).collect();
+	let distance = |b: &[f64]| a.iter().zip(b).map(|(a_i, b_i)| (a_i - b_i).abs()).fold(0.0, f64::max);
+	assert!(distance(b).min(distance(&negated)) < 1e-9, "{:?} != ±{:?}", a, b);
+}
+
+#[test]
+fn test_pga3d_exp_log() {
+	use generated::pga3d::*;
+
+	let motors = [
+		Motor3::from_rotor_and_translation(Rotor3::from_axis_angle([0.0, 0.0, 1.0], 1.0), [0.0, 0.0, 0.0]),
+		Motor3::from_rotor_and_translation(Rotor3::from_axis_angle([0.6, 0.0, 0.8], 2.5), [1.0, -2.0, 3.0]),
+		Motor3::from_rotor_and_translation(Rotor3::from_axis_angle([0.0, 1.0, 0.0], -0.5), [0.5, 0.0, 0.0]),
+		// A pure translation, where the rotation angle is zero:
+		Motor3::from_translation([1.0, 2.0, 3.0]),
+		// Tiny angles, close to the pure translation:
+		Motor3::from_rotor_and_translation(Rotor3::from_axis_angle([0.0, 0.0, 1.0], 1e-9), [1.0, 2.0, 3.0]),
+		Motor3::from_rotor_and_translation(Rotor3::from_axis_angle([0.0, 0.6, 0.8], 1e-6), [-1.0, 0.0, 2.0]),
+	];
+	let point = Vec4::point(1.0, 2.0, 3.0);
+
+	for motor in motors {
+		let line = motor.ln();
+		assert_same_motor(&line.exp().to_array(), &motor.to_array());
+
+		let identity = Motor3::from_translation([0.0, 0.0, 0.0]);
+		assert_same_motor(&identity.lerp_log(motor, 0.0).to_array(), &identity.to_array());
+		assert_same_motor(&identity.lerp_log(motor, 1.0).to_array(), &motor.to_array());
+
+		// Half of the motion, twice, is the whole motion:
+		let half = identity.lerp_log(motor, 0.5);
+		assert_approx_eq(
+			&half.anti_sandwich(half.anti_sandwich(point)).to_array(),
+			&motor.anti_sandwich(point).to_array(),
+		);
+	}
+
+	// Between two motors:
+	let (a, b) = (motors[1], motors[2]);
+	assert_same_motor(&a.lerp_log(b, 0.0).to_array(), &a.to_array());
+	assert_same_motor(&a.lerp_log(b, 1.0).to_array(), &b.to_array());
+	let mid = a.lerp_log(b, 0.5);
+	let step = mid.anti_geometric(a.arev());
+	assert_same_motor(&step.anti_geometric(mid).to_array(), &b.to_array());
+
+	// A line at infinity (zero weight) is a pure translation, by twice its moment:
+	let translation = Line3 {
+		vx: WX(0.0),
+		vy: WY(0.0),
+		vz: WZ(0.0),
+		mx: YZ(0.5),
+		my: ZX(1.0),
+		mz: XY(1.5),
+	}
+	.exp();
+	assert_approx_eq(&translation.anti_sandwich(point).to_array(), &[2.0, 4.0, 6.0, 1.0]);
+
+	// A tiny weight is close to that:
+	let almost = Line3 {
+		vx: WX(0.0),
+		vy: WY(0.0),
+		vz: WZ(1e-12),
+		mx: YZ(0.5),
+		my: ZX(1.0),
+		mz: XY(1.5),
+	}
+	.exp();
+	assert_approx_eq(&almost.anti_sandwich(point).to_array(), &[2.0, 4.0, 6.0, 1.0]);
+}
+
+#[test]
+fn test_pga2d_exp_log() {
+	use generated::pga2d::*;
+
+	let rotor = |angle: f64| Rotor {
+		s: S((angle / 2.0).cos()),
+		xy: XY((angle / 2.0).sin()),
+	};
+	let translator = Translator {
+		s: S(1.0),
+		yw: YW(0.5),
+		wx: WX(-1.5),
+	};
+	let identity = Motor::from([1.0, 0.0, 0.0, 0.0]);
+	let motors = [
+		translator.geometric(rotor(1.0)),
+		translator.geometric(rotor(-3.0)),
+		// A pure translation, and tiny angles close to it:
+		translator.geometric(rotor(0.0)),
+		translator.geometric(rotor(1e-9)),
+		translator.geometric(rotor(-1e-6)),
+	];
+	let line = Line::from([1.0, 2.0, 1.0]);
+
+	for motor in motors {
+		assert_same_motor(&motor.ln().exp().to_array(), &motor.to_array());
+
+		assert_same_motor(&identity.lerp_log(motor, 0.0).to_array(), &identity.to_array());
+		assert_same_motor(&identity.lerp_log(motor, 1.0).to_array(), &motor.to_array());
+		let half = identity.lerp_log(motor, 0.5);
+		assert_approx_eq(
+			&half.sandwich(half.sandwich(line)).to_array(),
+			&motor.sandwich(line).to_array(),
+		);
+	}
+}
@@ -0,0 +1,64 @@
+//! The optional support for other crates, see `cargo test --all-features`.
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+	use generated::pga3d::*;
+
+	let line = Line3::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+	let json = serde_json::to_string(&line).unwrap();
+	assert_eq!(serde_json::from_str::<Line3>(&json).unwrap(), line);
+
+	// Blades are newtypes, so they are just numbers:
+	assert_eq!(serde_json::to_string(&X(2.5)).unwrap(), "2.5");
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck() {
+	use generated::pga3d::*;
+
+	let lines = [
+		Line3::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+		Line3::from([7.0, 8.0, 9.0, 10.0, 11.0, 12.0]),
+	];
+	let floats: &[f64] = bytemuck::cast_slice(&lines);
+	assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0]);
+	assert_eq!(bytemuck::cast::<f64, S>(3.0), S(3.0));
+	assert_eq!(<Point3 as bytemuck::Zeroable>::zeroed(), Point3::from([0.0, 0.0, 0.0]));
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn test_mint() {
+	use generated::{pga3d::*, vga3d};
+
+	let v = vga3d::Vec3::from([1.0, 2.0, 3.0]);
+	let m: mint::Vector3<f64> = v.into();
+	assert_eq!(m, mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+	assert_eq!(vga3d::Vec3::from(m), v);
+
+	// The constant `w` is left out:
+	let p: mint::Point3<f64> = Point3::from([1.0, 2.0, 3.0]).into();
+	assert_eq!(p, mint::Point3 { x: 1.0, y: 2.0, z: 3.0 });
+	assert_eq!(Point3::from(p), Point3::from([1.0, 2.0, 3.0]));
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn test_approx() {
+	use generated::vga3d::*;
+
+	// Rotate a quarter turn from X towards Y:
+	let half_angle = std::f64::consts::FRAC_PI_4;
+	let rotor = Rotor3 {
+		s: S(half_angle.cos()),
+		yz: YZ(0.0),
+		zx: ZX(0.0),
+		xy: XY(-half_angle.sin()),
+	};
+	let rotated = rotor.sandwich(Vec3::from([1.0, 2.0, 3.0]));
+	approx::assert_relative_eq!(rotated, Vec3::from([-2.0, 1.0, 3.0]));
+	approx::assert_abs_diff_eq!(X(1.0), X(1.0 + 1e-20));
+	approx::assert_relative_ne!(rotated, Vec3::from([2.0, 1.0, 3.0]));
+}
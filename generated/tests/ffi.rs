@@ -0,0 +1,89 @@
+use std::{mem::size_of, process::Command};
+
+#[test]
+fn test_pga3d_ffi() {
+	use generated::pga3d::{ffi::*, *};
+
+	// The C structs are plain arrays of doubles, in the order of the members:
+	assert_eq!(size_of::<S>(), size_of::<f64>());
+	assert_eq!(size_of::<Line3>(), 6 * size_of::<f64>());
+	assert_eq!(size_of::<Point3>(), 3 * size_of::<f64>());
+	assert_eq!(size_of::<Multivector>(), 16 * size_of::<f64>());
+	let line = Line3::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+	let floats: [f64; 6] = unsafe { std::mem::transmute(line) };
+	assert_eq!(floats, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+	let a = Vec4::from([1.0, 0.0, 0.0, 1.0]);
+	let b = Vec4::from([0.0, 1.0, 0.0, 1.0]);
+	assert_eq!(pga3d_vec4_wedge_vec4(a, b), a.wedge(b));
+	assert_eq!(pga3d_vec4_dot_vec4(a, a), a.dot(a));
+	assert_eq!(pga3d_line3_rev(line), line.rev());
+
+	let plane = Plane::from([0.0, 0.0, 1.0, -1.0]);
+	assert_eq!(pga3d_line3_anti_wedge_plane(line, plane), line.anti_wedge(plane));
+}
+
+/// Calls the C ABI from C, through the cdylib of this crate (which is next to the test executable).
+#[test]
+fn test_pga3d_ffi_from_c() {
+	use generated::pga3d::*;
+
+	let motor = Motor3::from_rotor_and_translation(Rotor3::from_axis_angle([0.0, 0.0, 1.0], 1.0), [1.0, 2.0, 3.0]);
+	let point = Vec4::point(4.0, 5.0, 6.0);
+
+	let lib_dir = std::env::current_exe().unwrap().parent().unwrap().to_owned();
+	let dir = std::env::temp_dir().join(format!("generated_test_ffi_{}", std::process::id()));
+	std::fs::create_dir_all(&dir).unwrap();
+	let c_file = dir.join("main.c");
+	let floats = |values: &[f64]| values.iter().map(|v| format!("{:?}", v)).collect::<Vec<_>>().join(", ");
+	let c_code = format!(
+		"#include <stdio.h>\n\
+		 #include \"pga3d.h\"\n\
+		 int main(void) {{\n\
+		 \tpga3d_Motor3 motor = {{{motor}}};\n\
+		 \tpga3d_Vec4 point = {{{point}}};\n\
+		 \tpga3d_Vec4 moved = pga3d_motor3_anti_sandwich_vec4(motor, point);\n\
+		 \tprintf(\"%.17g %.17g %.17g %.17g\", moved.x, moved.y, moved.z, moved.w);\n\
+		 \treturn 0;\n\
+		 }}\n",
+		motor = floats(&motor.to_array()),
+		point = floats(&point.to_array()),
+	);
+	std::fs::write(&c_file, c_code).unwrap();
+
+	let program = dir.join("main");
+	let status = Command::new("cc")
+		.arg(&c_file)
+		.arg("-I")
+		.arg(concat!(env!("CARGO_MANIFEST_DIR"), "/src/pga3d"))
+		.arg("-L")
+		.arg(&lib_dir)
+		.arg("-lgenerated")
+		.arg("-o")
+		.arg(&program)
+		.status()
+		.expect("Failed to run cc");
+	assert!(status.success());
+	let output = Command::new(&program)
+		.env("LD_LIBRARY_PATH", &lib_dir)
+		.output()
+		.unwrap();
+	assert!(output.status.success());
+	let moved: Vec<f64> = String::from_utf8(output.stdout)
+		.unwrap()
+		.split(' ')
+		.map(|v| v.parse().unwrap())
+		.collect();
+	assert_eq!(moved, motor.anti_sandwich(point).to_array());
+	std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_cga3d_ffi() {
+	use generated::cga3d::{ffi::*, *};
+
+	let p = Point::from([1.0, 2.0, 3.0, 1.0, 7.0]);
+	assert_eq!(cga3d_point_dot_point(p, p), S(0.0));
+	let q = Point::from([0.0, 0.0, 0.0, 1.0, 0.0]);
+	assert_eq!(cga3d_point_wedge_point(p, q), p.wedge(q));
+}
@@ -0,0 +1,129 @@
+//! Conversions to and from `glam`, `nalgebra` and `cgmath`, see `cargo test --all-features`.
+
+use generated::pga3d::*;
+
+fn assert_approx_eq(a: &[f64], b: &[f64]) {
+	assert_eq!(a.len(), b.len());
+	for (a_i, b_i) in a.iter().zip(b) {
+		assert!((a_i - b_i).abs() < 1e-9, "{:?} != {:?}", a, b);
+	}
+}
+
+/// A quarter turn around +Z, so +X goes to +Y.
+fn quarter_turn() -> Rotor3 {
+	Rotor3::from_axis_angle([0.0, 0.0, 1.0], std::f64::consts::FRAC_PI_2)
+}
+
+/// A quarter turn around +Z, then a translation of `[1, 2, 3]`.
+fn motor() -> Motor3 {
+	Motor3::from_rotor_and_translation(quarter_turn(), [1.0, 2.0, 3.0])
+}
+
+#[test]
+fn test_motor_helpers() {
+	let motor = motor();
+	assert_approx_eq(&motor.translation(), &[1.0, 2.0, 3.0]);
+	assert_approx_eq(&motor.rotor().to_array(), &quarter_turn().to_array());
+	assert_approx_eq(
+		&motor.anti_sandwich(Vec4::point(1.0, 0.0, 0.0)).to_array(),
+		&[1.0, 3.0, 3.0, 1.0],
+	);
+	assert_approx_eq(
+		&Motor3::from_rotor_and_translation(Rotor3::from_axis_angle([1.0, 0.0, 0.0], 0.0), [4.0, 5.0, 6.0]).to_array(),
+		&Motor3::from_translation([4.0, 5.0, 6.0]).to_array(),
+	);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam() {
+	use glam::{DMat4, DQuat, DVec3};
+
+	let v = Vec3::from([1.0, 2.0, 3.0]);
+	assert_eq!(DVec3::from(v), DVec3::new(1.0, 2.0, 3.0));
+	assert_eq!(Vec3::from(DVec3::from(v)), v);
+	assert_eq!(
+		glam::DVec4::from(Vec4::point(1.0, 2.0, 3.0)),
+		glam::DVec4::new(1.0, 2.0, 3.0, 1.0)
+	);
+
+	let quat = DQuat::from(quarter_turn());
+	let expected = DQuat::from_axis_angle(DVec3::Z, std::f64::consts::FRAC_PI_2);
+	assert_approx_eq(&quat.to_array(), &expected.to_array());
+	assert_approx_eq(
+		&(quat * DVec3::new(1.0, 2.0, 3.0)).to_array(),
+		&quarter_turn().anti_sandwich(v).to_array(),
+	);
+	assert_approx_eq(&Rotor3::from(quat).to_array(), &quarter_turn().to_array());
+
+	let matrix = DMat4::from(motor());
+	assert_approx_eq(&matrix.transform_point3(DVec3::X).to_array(), &[1.0, 3.0, 3.0]);
+	assert_approx_eq(&Motor3::from(matrix).to_array(), &motor().to_array());
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra() {
+	use nalgebra::{Isometry3, Matrix4, UnitQuaternion, Vector3};
+
+	let point = Point3::from([1.0, 2.0, 3.0]);
+	assert_eq!(nalgebra::Point3::from(point), nalgebra::Point3::new(1.0, 2.0, 3.0));
+	assert_eq!(Point3::from(nalgebra::Point3::from(point)), point);
+	assert_eq!(Vector3::from(Vec3::from([1.0, 2.0, 3.0])), Vector3::new(1.0, 2.0, 3.0));
+	assert_eq!(
+		nalgebra::Point3::from(Vec3::from([1.0, 2.0, 3.0])),
+		nalgebra::Point3::new(1.0, 2.0, 3.0)
+	);
+	assert_eq!(
+		Vec3::from(nalgebra::Point3::new(1.0, 2.0, 3.0)),
+		Vec3::from([1.0, 2.0, 3.0])
+	);
+	// A `Vec4` is the homogeneous coordinates of a point:
+	assert_eq!(
+		nalgebra::Point3::from(Vec4::from([2.0, 4.0, 6.0, 2.0])),
+		nalgebra::Point3::new(1.0, 2.0, 3.0)
+	);
+	assert_eq!(
+		Vec4::from(nalgebra::Point3::new(1.0, 2.0, 3.0)),
+		Vec4::point(1.0, 2.0, 3.0)
+	);
+
+	let quat = UnitQuaternion::from(quarter_turn());
+	let expected = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), std::f64::consts::FRAC_PI_2);
+	assert!(quat.angle_to(&expected) < 1e-9);
+	assert_approx_eq(&Rotor3::from(quat).to_array(), &quarter_turn().to_array());
+
+	let isometry = Isometry3::from(motor());
+	let moved = isometry * nalgebra::Point3::new(1.0, 0.0, 0.0);
+	assert_approx_eq(moved.coords.as_slice(), &[1.0, 3.0, 3.0]);
+	assert_approx_eq(&Motor3::from(isometry).to_array(), &motor().to_array());
+
+	let matrix = Matrix4::from(motor());
+	assert_eq!(matrix, isometry.to_homogeneous());
+	assert_approx_eq(&Motor3::from(matrix).to_array(), &motor().to_array());
+}
+
+#[cfg(feature = "cgmath")]
+#[test]
+fn test_cgmath() {
+	use cgmath::{InnerSpace, Matrix4, Quaternion, Rad, Rotation3, Transform, Vector3};
+
+	assert_eq!(
+		cgmath::Point3::from(Point3::from([1.0, 2.0, 3.0])),
+		cgmath::Point3::new(1.0, 2.0, 3.0)
+	);
+	assert_eq!(
+		cgmath::Vector4::from(Vec4::point(1.0, 2.0, 3.0)),
+		cgmath::Vector4::new(1.0, 2.0, 3.0, 1.0)
+	);
+
+	let quat = Quaternion::from(quarter_turn());
+	let expected = Quaternion::from_axis_angle(Vector3::unit_z(), Rad(std::f64::consts::FRAC_PI_2));
+	assert!((quat - expected).magnitude() < 1e-9);
+	assert_approx_eq(&Rotor3::from(quat).to_array(), &quarter_turn().to_array());
+
+	let matrix = Matrix4::from(motor());
+	let moved = matrix.transform_point(cgmath::Point3::new(1.0, 0.0, 0.0));
+	assert_approx_eq(&[moved.x, moved.y, moved.z], &[1.0, 3.0, 3.0]);
+	assert_approx_eq(&Motor3::from(matrix).to_array(), &motor().to_array());
+}
@@ -0,0 +1,137 @@
+//! `to_matrix` and `from_matrix` of the versors.
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+fn assert_approx_eq(a: &[f64], b: &[f64]) {
+	assert_eq!(a.len(), b.len());
+	for (a_i, b_i) in a.iter().zip(b) {
+		assert!((a_i - b_i).abs() < 1e-9, "{:?} != {:?}", a, b);
+	}
+}
+
+/// Versors are the same transform up to sign.
+fn assert_same_versor(a: &[f64], b: &[f64]) {
+	let negated: Vec<f64> = b.iter().map(|b_i| -b_i).collect();
+	let distance = |b: &[f64]| a.iter().zip(b).map(|(a_i, b_i)| (a_i - b_i).abs()).fold(0.0, f64::max);
+	assert!(distance(b).min(distance(&negated)) < 1e-9, "{:?} != ±{:?}", a, b);
+}
+
+/// E.g. the 4x4 identity matrix.
+fn identity_matrix<const N: usize>() -> [[f64; N]; N] {
+	let mut m = [[0.0; N]; N];
+	for (i, row) in m.iter_mut().enumerate() {
+		row[i] = 1.0;
+	}
+	m
+}
+
+fn apply<const N: usize>(m: [[f64; N]; N], v: [f64; N]) -> [f64; N] {
+	m.map(|row| row.iter().zip(v).map(|(m_i, v_i)| m_i * v_i).sum())
+}
+
+#[test]
+fn test_pga3d_matrix() {
+	use generated::pga3d::*;
+
+	// Not `-identity()`, although it has the same matrix:
+	assert_eq!(Rotor3::identity().to_matrix(), identity_matrix());
+	assert_eq!(Rotor3::from_matrix(identity_matrix()), Rotor3::identity());
+	assert_eq!(Translator3::from_matrix(identity_matrix()), Translator3::identity());
+	assert_eq!(Motor3::from_matrix(identity_matrix()), Motor3::identity());
+
+	// The axis should be normalized:
+	let rotors = [
+		Rotor3::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_2),
+		Rotor3::from_axis_angle([0.6, 0.0, 0.8], 1.0),
+		// Half turns, where the `w` of the rotor is zero:
+		Rotor3::from_axis_angle([1.0, 0.0, 0.0], PI),
+		Rotor3::from_axis_angle([0.0, 1.0, 0.0], PI),
+		Rotor3::from_axis_angle([0.0, 0.0, 1.0], PI),
+		Rotor3::from_axis_angle([0.6, -0.8, 0.0], PI),
+		Rotor3::from_axis_angle([2.0 / 3.0, -2.0 / 3.0, 1.0 / 3.0], 3.0),
+		Rotor3::from_axis_angle([0.0, 0.6, 0.8], 1e-9),
+	];
+	let point = Vec4::point(1.0, 2.0, 3.0);
+	for rotor in rotors {
+		let matrix = rotor.to_matrix();
+		assert_approx_eq(&apply(matrix, point.to_array()), &rotor.anti_sandwich(point).to_array());
+		assert_same_versor(&Rotor3::from_matrix(matrix).to_array(), &rotor.to_array());
+
+		let motor = Motor3::from_rotor_and_translation(rotor, [4.0, -5.0, 6.0]);
+		let matrix = motor.to_matrix();
+		assert_approx_eq(&apply(matrix, point.to_array()), &motor.anti_sandwich(point).to_array());
+		assert_approx_eq(&matrix[3], &[0.0, 0.0, 0.0, 1.0]);
+		assert_eq!(motor.to_matrix_3x4(), [matrix[0], matrix[1], matrix[2]]);
+		assert_same_versor(&Motor3::from_matrix(matrix).to_array(), &motor.to_array());
+	}
+
+	let translator = Translator3::from_matrix(Motor3::from_translation([1.0, 2.0, 3.0]).to_matrix());
+	assert_approx_eq(&translator.to_matrix_3x4().map(|row| row[3]), &[1.0, 2.0, 3.0]);
+	assert_approx_eq(
+		&translator.anti_sandwich(Vec4::point(1.0, 1.0, 1.0)).to_array(),
+		&[2.0, 3.0, 4.0, 1.0],
+	);
+}
+
+#[test]
+fn test_pga2d_matrix() {
+	use generated::pga2d::*;
+
+	assert_eq!(Rotor::from_matrix(identity_matrix()), Rotor::identity());
+	assert_eq!(Translator::from_matrix(identity_matrix()), Translator::identity());
+	assert_eq!(Motor::from_matrix(identity_matrix()), Motor::identity());
+
+	// The sandwich moves a `Vec3` like a line, and the matrices move it like a point:
+	let point = Vec3::from([1.0, 2.0, 1.0]);
+	let moved = |line: Line| line.rcompl().to_array();
+
+	for angle in [0.5, -2.0, PI, -PI] {
+		let rotor = Rotor::from_angle(angle);
+		let matrix = rotor.to_matrix();
+		assert_approx_eq(&apply(matrix, point.to_array()), &moved(rotor.sandwich(point.rcompl())));
+		assert_same_versor(&Rotor::from_matrix(matrix).to_array(), &rotor.to_array());
+
+		let motor = Motor::from_rotor_and_translation(rotor, [3.0, -1.0]);
+		let matrix = motor.to_matrix();
+		assert_approx_eq(&apply(matrix, point.to_array()), &moved(motor.sandwich(point.rcompl())));
+		assert_approx_eq(&matrix[2], &[0.0, 0.0, 1.0]);
+		assert_eq!(motor.to_matrix_2x3(), [matrix[0], matrix[1]]);
+		assert_approx_eq(&motor.translation(), &[3.0, -1.0]);
+		assert_same_versor(&motor.rotor().to_array(), &rotor.to_array());
+		assert_same_versor(&Motor::from_matrix(matrix).to_array(), &motor.to_array());
+	}
+
+	// A quarter turn counter-clockwise, then a translation:
+	let motor = Motor::from_matrix([[0.0, -1.0, 3.0], [1.0, 0.0, 4.0], [0.0, 0.0, 1.0]]);
+	assert_approx_eq(&motor.translation(), &[3.0, 4.0]);
+	assert_approx_eq(&moved(motor.sandwich(point.rcompl())), &[1.0, 5.0, 1.0]);
+	assert_same_versor(&motor.rotor().to_array(), &Rotor::from_angle(FRAC_PI_2).to_array());
+
+	let translator = Translator::from_matrix(Motor::from_translation([1.0, 2.0]).to_matrix());
+	assert_approx_eq(&translator.to_matrix_2x3().map(|row| row[2]), &[1.0, 2.0]);
+	assert_approx_eq(&moved(translator.sandwich(point.rcompl())), &[2.0, 4.0, 1.0]);
+}
+
+#[test]
+fn test_vga3d_matrix() {
+	use generated::vga3d::*;
+
+	assert_eq!(Rotor3::from_matrix(identity_matrix()), Rotor3::identity());
+
+	// A quarter turn from X towards Y, and half turns (with exactly zero scalars):
+	let half_angle = FRAC_PI_2 / 2.0;
+	let rotors = [
+		Rotor3::from([half_angle.cos(), 0.0, 0.0, -half_angle.sin()]),
+		Rotor3::from([0.0, 1.0, 0.0, 0.0]),
+		Rotor3::from([0.0, 0.0, 0.6, 0.8]),
+		Rotor3::from([0.0, 0.6, -0.8, 0.0]),
+		Rotor3::from([0.0, 0.48, 0.6, -0.64]),
+	];
+	let v = Vec3::from([1.0, 2.0, 3.0]);
+	for rotor in rotors {
+		let matrix = rotor.to_matrix();
+		assert_approx_eq(&apply(matrix, v.to_array()), &rotor.sandwich(v).to_array());
+		assert_same_versor(&Rotor3::from_matrix(matrix).to_array(), &rotor.to_array());
+	}
+	assert_approx_eq(&apply(rotors[0].to_matrix(), v.to_array()), &[-2.0, 1.0, 3.0]);
+}
@@ -0,0 +1,36 @@
+//! `Norm::normalized` of points, lines and versors.
+
+fn assert_approx_eq(a: &[f64], b: &[f64]) {
+	assert_eq!(a.len(), b.len());
+	for (a_i, b_i) in a.iter().zip(b) {
+		assert!((a_i - b_i).abs() < 1e-9, "{:?} != {:?}", a, b);
+	}
+}
+
+#[test]
+fn test_pga3d_normalized() {
+	use generated::pga3d::*;
+
+	// The weight of a point is its `w`, which becomes one even when it is negative:
+	for w in [2.0, -2.0] {
+		let point = Vec4::from([2.0, -4.0, 6.0, w]).normalized();
+		assert_approx_eq(&point.to_array(), &[2.0 / w, -4.0 / w, 6.0 / w, 1.0]);
+		assert!(point.is_normalized(1e-9));
+	}
+
+	// A line gets a unit direction:
+	let line = Line3::from_points([1.0, 2.0, 3.0], [1.0, 2.0, -1.0]).normalized();
+	assert!(line.is_normalized(1e-9));
+	assert!((line.weight_norm() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_pga2d_normalized() {
+	use generated::pga2d::*;
+
+	let point = Vec3::from([3.0, -6.0, -3.0]).normalized();
+	assert_approx_eq(&point.to_array(), &[-1.0, 2.0, 1.0]);
+
+	let rotor = Rotor::from([3.0, 4.0]).normalized();
+	assert_approx_eq(&rotor.to_array(), &[0.6, 0.8]);
+}
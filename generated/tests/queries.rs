@@ -0,0 +1,87 @@
+fn assert_approx_eq(a: f64, b: f64) {
+	assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+}
+
+#[test]
+fn test_pga2d_queries() {
+	use generated::pga2d::{queries::*, *};
+
+	let point = |x: f64, y: f64, w: f64| Vec3 {
+		x: X(x * w),
+		y: Y(y * w),
+		w: W(w),
+	};
+
+	// y = 1, directed along +x:
+	let line = point(0.0, 1.0, 1.0).wedge(point(2.0, 1.0, 1.0));
+	assert_approx_eq(point_line_distance(point(3.0, 4.0, 1.0), line), 3.0);
+	assert_approx_eq(point_line_distance(point(3.0, 4.0, -2.0), line), 3.0);
+	assert_approx_eq(point_line_distance(point(3.0, -4.0, 1.0), line), -5.0);
+
+	let diagonal = point(0.0, 0.0, 1.0).wedge(point(1.0, 1.0, 1.0));
+	assert_approx_eq(line_line_angle(line, diagonal), std::f64::consts::FRAC_PI_4);
+	assert_approx_eq(line_line_angle(diagonal, line), -std::f64::consts::FRAC_PI_4);
+
+	let projected = project_point_on_line(point(3.0, 4.0, 2.0), line);
+	assert_approx_eq(projected.x.0 / projected.w.0, 3.0);
+	assert_approx_eq(projected.y.0 / projected.w.0, 1.0);
+
+	let meet = meet_lines(line, diagonal);
+	assert_approx_eq(meet.x.0 / meet.w.0, 1.0);
+	assert_approx_eq(meet.y.0 / meet.w.0, 1.0);
+
+	let parallel = parallel_line_through(point(0.0, 5.0, 1.0), line);
+	assert_approx_eq(line_line_angle(line, parallel), 0.0);
+	assert_approx_eq(point_line_distance(point(7.0, 5.0, 1.0), parallel), 0.0);
+}
+
+#[test]
+fn test_pga3d_queries() {
+	use generated::pga3d::{queries::*, *};
+
+	let point = |[x, y, z]: [f64; 3]| Vec4::point(x, y, z);
+
+	// z = 2, normal along +z:
+	let plane = Plane::from_normal_and_distance([0.0, 0.0, 2.0], 4.0);
+	assert_approx_eq(point_plane_distance(point([1.0, 2.0, 5.0]), plane), 3.0);
+	assert_approx_eq(point_plane_distance(point([1.0, 2.0, -1.0]), plane), -3.0);
+	let projected = project_point_on_plane(point([1.0, 2.0, 5.0]), plane);
+	assert_eq!(projected.to_array(), [1.0, 2.0, 2.0, 1.0]);
+
+	// Along +x through (0, 1, 0):
+	let line = Line3::from_point_and_direction([0.0, 1.0, 0.0], [2.0, 0.0, 0.0]);
+	assert_approx_eq(point_line_distance(point([1.0, 2.0, 5.0]), line), 26.0_f64.sqrt());
+	let projected = project_point_on_line(point([1.0, 2.0, 5.0]), line);
+	assert_eq!(projected.to_array(), [1.0, 1.0, 0.0, 1.0]);
+
+	// Along +y through (0, 0, 3):
+	let skew = Line3::from_point_and_direction([0.0, 0.0, 3.0], [0.0, 3.0, 0.0]);
+	assert_approx_eq(line_line_distance(line, skew), 3.0);
+	let parallel = Line3::from_point_and_direction([0.0, 4.0, 4.0], [-1.0, 0.0, 0.0]);
+	assert_approx_eq(line_line_distance(line, parallel), 5.0);
+	assert_approx_eq(line_line_distance(line, line), 0.0);
+	// Nearly parallel, one apart, and far from the origin, where `a ^ b` rounds to zero:
+	let p = [6000.0, -14000.0, 20000.0];
+	let tilt = 1e-12;
+	let a = Line3::from_point_and_direction(p, [0.6, 0.8, 0.0]);
+	let b = Line3::from_point_and_direction([p[0], p[1], p[2] + 1.0], [0.6 + 0.8 * tilt, 0.8 - 0.6 * tilt, 0.0]);
+	assert_approx_eq(line_line_distance(a, b), 1.0);
+
+	let tilted = Plane::from_normal_and_distance([0.0, 1.0, 1.0], 0.0);
+	assert_approx_eq(plane_plane_angle(plane, tilted), std::f64::consts::FRAC_PI_4);
+	assert_approx_eq(plane_plane_angle(plane, -plane), std::f64::consts::PI);
+
+	let meet = meet_planes(
+		Plane::from_normal_and_distance([1.0, 0.0, 0.0], 1.0),
+		Plane::from_normal_and_distance([0.0, 1.0, 0.0], 2.0),
+		Plane::from_normal_and_distance([0.0, 0.0, 1.0], 3.0),
+	);
+	assert_eq!(meet.to_array(), [1.0, 2.0, 3.0, 1.0]);
+
+	let through = parallel_line_through(point([1.0, 2.0, 3.0]), line);
+	assert_eq!(
+		[through.vx.0, through.vy.0, through.vz.0],
+		[line.vx.0, line.vy.0, line.vz.0]
+	);
+	assert_approx_eq(point_line_distance(point([5.0, 2.0, 3.0]), through), 0.0);
+}
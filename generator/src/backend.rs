@@ -0,0 +1,308 @@
+//! The [`Backend`] of each target language: [`crate::output::Rust`], [`crate::shader`], [`crate::cpp`] and [`crate::python`].
+//!
+//! The languages other than Rust have no newtypes for the blades, so a struct is just its (non-constant) members as floats,
+//! and each product, unary operation and sandwich of the structs becomes a function
+//! that computes the members of the output from the members of the inputs.
+//! The values are the same [`Expr`] trees as for the Rust code, after `simplify` and `typify`.
+use itertools::Itertools;
+
+use crate::{
+	gen::{strct, Generator},
+	*,
+};
+
+/// A target language, i.e. the files it generates for an algebra.
+pub trait Backend {
+	/// The names and contents of the files, e.g. `pga3d.wgsl`.
+	fn files(&self, gen: &Generator, algebra_name: &str) -> Vec<(String, String)>;
+}
+
+/// A language other than Rust, which gets a single [`file`] of the structs and a function for each operation.
+pub trait Syntax {
+	/// E.g. `wgsl` or `py`.
+	fn file_extension(&self) -> &'static str;
+
+	/// E.g. `2.0`, or `2.0f` for a C++ `float`.
+	fn float_literal(&self, value: i32) -> String {
+		format!("{}.0", value)
+	}
+
+	/// The declaration of a struct, from the names of its stored members and their blades.
+	fn declare_struct(&self, struct_name: &str, members: &[(&str, &str)]) -> String;
+
+	/// E.g. `a.x`. `index` is the position among the stored members.
+	fn member(&self, arg_name: &str, index: usize, member_name: &str) -> String;
+
+	/// An instance of a struct, from the values of its stored members.
+	fn construct(&self, struct_name: &str, members: &[(&str, String)]) -> String;
+
+	/// The definition of the function, which returns `value`.
+	fn function(&self, function: &Function, value: &str) -> String;
+
+	/// The whole file, from the struct declarations and function definitions.
+	fn file(&self, algebra_name: &str, structs: &[String], functions: &[(Function, String)]) -> String;
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Operation {
+	Unary(Unary),
+	Product(Product),
+	Sandwich(Sandwich),
+}
+
+impl Operation {
+	/// E.g. `rev`, `wedge` or `sandwich`.
+	pub fn name(self) -> &'static str {
+		match self {
+			Operation::Unary(unary) => unary.trait_function_name(),
+			Operation::Product(product) => product.trait_function_name(),
+			Operation::Sandwich(sandwich) => sandwich.trait_function_name(),
+		}
+	}
+}
+
+impl<S: Syntax> Backend for S {
+	fn files(&self, gen: &Generator, algebra_name: &str) -> Vec<(String, String)> {
+		vec![(
+			format!("{}.{}", algebra_name, self.file_extension()),
+			file(gen, self, algebra_name),
+		)]
+	}
+}
+
+/// A unary operation, product or sandwich of structs.
+#[derive(Clone, Debug)]
+pub struct Function {
+	pub operation: Operation,
+	/// The struct names of the arguments, which are called `a` (and `b`).
+	pub args: Vec<String>,
+	/// The name of the output struct, or the blade it returns the coordinate of.
+	pub output: String,
+	/// Is the output a struct (or a single blade)?
+	pub returns_struct: bool,
+}
+
+impl Function {
+	pub fn arg_names(&self) -> &'static [&'static str] {
+		&["a", "b"][..self.args.len()]
+	}
+
+	/// A name for languages without overloading, e.g. `vec4_wedge_vec4` or `line3_rev`.
+	pub fn unique_name(&self) -> String {
+		let mut name = format!("{}_{}", self.args[0].to_ascii_lowercase(), self.operation.name());
+		for arg in &self.args[1..] {
+			name += &format!("_{}", arg.to_ascii_lowercase());
+		}
+		name
+	}
+}
+
+/// The code of all the structs of the generator and the functions on them.
+pub fn file(gen: &Generator, backend: &dyn Syntax, algebra_name: &str) -> String {
+	let structs: Vec<String> = gen
+		.types
+		.structs()
+		.map(|(struct_name, strct)| {
+			let members: Vec<(&str, &str)> = stored_members(strct)
+				.map(|(member_name, member)| (member_name.as_str(), member.name.as_str()))
+				.collect();
+			backend.declare_struct(struct_name, &members)
+		})
+		.collect();
+
+	let functions: Vec<(Function, String)> = functions(gen)
+		.into_iter()
+		.map(|(function, expr)| {
+			let value = value(gen, backend, &function, &expr);
+			let code = backend.function(&function, &value);
+			(function, code)
+		})
+		.collect();
+
+	backend.file(algebra_name, &structs, &functions)
+}
+
+/// All the functions, with their values (of `self` and `rhs`).
+fn functions(gen: &Generator) -> Vec<(Function, Expr)> {
+	let function = |operation: Operation, args: &[&str], output: String| Function {
+		operation,
+		args: args.iter().map(|arg| arg.to_string()).collect(),
+		returns_struct: matches!(gen.types.try_get(&output), Some(Type::Struct(_))),
+		output,
+	};
+
+	let mut functions = vec![];
+	for (struct_name, strct) in gen.types.structs() {
+		let lhs = (struct_name, strct);
+		for unary in gen.unaries() {
+			if let Some((output, expr)) = strct::struct_unary_output(gen, struct_name, strct, unary) {
+				functions.push((function(Operation::Unary(unary), &[struct_name], output), expr));
+			}
+		}
+
+		for rhs in gen.types.structs() {
+			if !gen.settings.selection.has_struct_pair(struct_name, rhs.0) {
+				continue;
+			}
+			for product in gen.products() {
+				if let Some((output, expr)) = strct::struct_product_output(gen, &lhs, &rhs, product) {
+					functions.push((
+						function(Operation::Product(product), &[struct_name, rhs.0], output),
+						expr,
+					));
+				}
+			}
+		}
+
+		if strct::is_versor(gen, strct) {
+			for operand in strct::sandwich_operands(gen) {
+				if !gen.settings.selection.has_struct_pair(struct_name, operand.0) {
+					continue;
+				}
+				for sandwich in gen.sandwiches() {
+					if let Some(expr) = strct::struct_sandwich_output(gen, &lhs, &operand, sandwich) {
+						let output = operand.0.to_owned();
+						functions.push((
+							function(Operation::Sandwich(sandwich), &[struct_name, operand.0], output),
+							expr,
+						));
+					}
+				}
+			}
+		}
+	}
+	functions
+}
+
+/// The members that are stored, i.e. not the constants.
+pub fn stored_members(strct: &Struct) -> impl Iterator<Item = (&String, &StructMember)> {
+	strct.iter().filter(|(_, member)| !member.is_constant())
+}
+
+/// The code of the value of a function.
+fn value(gen: &Generator, backend: &dyn Syntax, function: &Function, expr: &Expr) -> String {
+	let operands: Vec<&Struct> = function.args.iter().map(|arg| gen.types.get_struct(arg)).collect();
+
+	// E.g. `self.vy` -> `a.vy`:
+	let accessor = |var_name: &str| -> String {
+		let (var, member_name) = var_name
+			.split_once('.')
+			.unwrap_or_else(|| panic!("Expected a struct member, got '{}'", var_name));
+		let arg = match var {
+			"self" => 0,
+			"rhs" => 1,
+			_ => panic!("Unknown variable '{}'", var),
+		};
+		let index = stored_members(operands[arg])
+			.position(|(name, _)| name == member_name)
+			.unwrap_or_else(|| panic!("Unknown member '{}'", var_name));
+		backend.member(function.arg_names()[arg], index, member_name)
+	};
+
+	if function.returns_struct {
+		let si = match expr {
+			Expr::StructInstance(si) => si,
+			_ => panic!("Expected a {}, got {}", function.output, expr.rust_concise()),
+		};
+		let members: Vec<(&str, String)> = stored_members(gen.types.get_struct(&function.output))
+			.map(|(member_name, member)| {
+				let sblade = member.typ.clone().into_sblade().unwrap();
+				let (_, expr) = si.members.iter().find(|(name, _)| name == member_name).unwrap();
+				(
+					member_name.as_str(),
+					float_expr(&gen.grammar, backend, expr, &sblade, &accessor),
+				)
+			})
+			.collect();
+		backend.construct(&function.output, &members)
+	} else {
+		let sblade = gen.types.get(&function.output).clone().into_sblade().unwrap();
+		float_expr(&gen.grammar, backend, expr, &sblade, &accessor)
+	}
+}
+
+/// The coordinate of `sblade` in `expr`, e.g. `a.x * b.y - a.y * b.x`.
+fn float_expr(
+	g: &Grammar,
+	backend: &dyn Syntax,
+	expr: &Expr,
+	sblade: &SBlade,
+	accessor: &dyn Fn(&str) -> String,
+) -> String {
+	let terms = match expr {
+		Expr::Sum(terms) => terms.as_slice(),
+		expr => std::slice::from_ref(expr),
+	};
+
+	let mut code = String::new();
+	for term in terms {
+		let (vars, term_sblade) =
+			coordinates(term, g).unwrap_or_else(|| panic!("Not a product of coordinates: {}", term.rust_concise()));
+		if term_sblade.is_zero() {
+			continue;
+		}
+		assert_eq!(
+			term_sblade.blade,
+			sblade.blade,
+			"Wrong blade in {}",
+			expr.rust_concise()
+		);
+		let coefficient = term_sblade.sign * sblade.sign;
+
+		let factors = vars.iter().map(|var| accessor(var)).join(" * ");
+		let magnitude = match (coefficient.abs(), factors.is_empty()) {
+			(c, true) => backend.float_literal(c),
+			(1, false) => factors,
+			(c, false) => format!("{} * {}", backend.float_literal(c), factors),
+		};
+		code += &match (code.is_empty(), coefficient < 0) {
+			(true, false) => magnitude,
+			(true, true) => format!("-{}", magnitude),
+			(false, false) => format!(" + {}", magnitude),
+			(false, true) => format!(" - {}", magnitude),
+		};
+	}
+
+	if code.is_empty() {
+		backend.float_literal(0)
+	} else {
+		code
+	}
+}
+
+/// A term as the names of the coordinates it multiplies, and the blade (with sign) they scale.
+pub fn coordinates(expr: &Expr, g: &Grammar) -> Option<(Vec<String>, SBlade)> {
+	match expr {
+		Expr::Var {
+			name,
+			typ: Type::SBlade(sblade),
+			..
+		} => Some((vec![name.clone()], sblade.clone())),
+		Expr::Var {
+			typ: Type::Constant(sblade),
+			..
+		} => Some((vec![], sblade.clone())),
+		Expr::Coordinates { vars, sblade, .. } => {
+			Some((vars.iter().map(|(_, name)| name.clone()).collect(), sblade.clone()))
+		}
+		Expr::Term(expr, scalar) => {
+			let (vars, sblade) = coordinates(expr, g)?;
+			Some((vars, *scalar * sblade))
+		}
+		Expr::Unary(unary, expr) => {
+			let (vars, sblade) = coordinates(expr, g)?;
+			Some((vars, sblade.unary(*unary, g)))
+		}
+		Expr::Prod(product, factors) => {
+			let mut vars = vec![];
+			let mut sblades = vec![];
+			for factor in factors {
+				let (factor_vars, sblade) = coordinates(factor, g)?;
+				vars.extend(factor_vars);
+				sblades.push(sblade);
+			}
+			Some((vars, single_sblade(SBlade::product_terms(*product, &sblades, g))?))
+		}
+		_ => Some((vec![], expr.as_sblade(g)?)),
+	}
+}
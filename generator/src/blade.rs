@@ -33,7 +33,7 @@ impl Blade {
 	}
 
 	pub fn has_vec(&self, needle: VecIdx) -> bool {
-		self.0.iter().any(|&vi| vi == needle)
+		self.0.contains(&needle)
 	}
 
 	/// 0 for scalar, 1 for vector, 2 for multivector etc.
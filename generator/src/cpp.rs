@@ -0,0 +1,150 @@
+//! A header-only C++ library of the structs, with an overloaded function
+//! for each of their products, unary operations and sandwiches (e.g. `wedge(a, b)`),
+//! and the same operators as the Rust code (e.g. `a ^ b`).
+use itertools::Itertools;
+
+use crate::{backend::*, gen::Generator, *};
+
+pub struct Cpp {
+	/// `double` or `float`.
+	float_type: &'static str,
+	product_operators: Vec<(Product, Operator)>,
+	unary_operators: Vec<(Unary, Operator)>,
+}
+
+impl Cpp {
+	/// `None` if the float type of the generator has no C++ equivalent.
+	pub fn new(gen: &Generator) -> Option<Self> {
+		Some(Self {
+			float_type: gen::ffi::c_float_type(gen)?,
+			product_operators: gen.ro.product_operators.clone(),
+			unary_operators: gen.ro.unary_operators.clone(),
+		})
+	}
+
+	fn operators(&self, operation: Operation) -> Vec<Operator> {
+		match operation {
+			Operation::Unary(unary) => self
+				.unary_operators
+				.iter()
+				.filter(|(u, _)| *u == unary)
+				.map(|(_, op)| *op)
+				.collect(),
+			Operation::Product(product) => self
+				.product_operators
+				.iter()
+				.filter(|(p, _)| *p == product)
+				.map(|(_, op)| *op)
+				.collect(),
+			Operation::Sandwich(_) => vec![],
+		}
+	}
+}
+
+/// Does `value` read any member of `arg`, e.g. `a.x`?
+fn uses_arg(value: &str, arg: &str) -> bool {
+	let prefix = format!("{}.", arg);
+	value
+		.match_indices(&prefix)
+		.any(|(i, _)| !value[..i].ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+}
+
+impl Syntax for Cpp {
+	fn file_extension(&self) -> &'static str {
+		"hpp"
+	}
+
+	fn float_literal(&self, value: i32) -> String {
+		if self.float_type == "float" {
+			format!("{}.0f", value)
+		} else {
+			format!("{}.0", value)
+		}
+	}
+
+	fn declare_struct(&self, struct_name: &str, members: &[(&str, &str)]) -> String {
+		format!(
+			"struct {} {{\n{}\n}};\n",
+			struct_name,
+			members
+				.iter()
+				.map(|(name, blade)| format!("\t{} {} = 0; // {}", self.float_type, name, blade))
+				.join("\n")
+		)
+	}
+
+	fn member(&self, arg_name: &str, _index: usize, member_name: &str) -> String {
+		format!("{}.{}", arg_name, member_name)
+	}
+
+	fn construct(&self, struct_name: &str, members: &[(&str, String)]) -> String {
+		format!(
+			"{}{{\n{}\n\t}}",
+			struct_name,
+			members
+				.iter()
+				.map(|(name, value)| format!("\t\t{}, // {}", value, name))
+				.join("\n")
+		)
+	}
+
+	fn function(&self, function: &Function, value: &str) -> String {
+		let output = if function.returns_struct {
+			function.output.as_str()
+		} else {
+			self.float_type
+		};
+		let params = |unnamed_if_unused: bool| {
+			function
+				.arg_names()
+				.iter()
+				.zip(&function.args)
+				.map(|(arg, typ)| {
+					if unnamed_if_unused && !uses_arg(value, arg) {
+						// Avoid warnings about unused parameters:
+						format!("const {}&", typ)
+					} else {
+						format!("const {}& {}", typ, arg)
+					}
+				})
+				.join(", ")
+		};
+		let name = function.operation.name();
+		let mut code = format!(
+			"inline {} {}({}) {{\n\treturn {};\n}}\n",
+			output,
+			name,
+			params(true),
+			value
+		);
+		for operator in self.operators(function.operation) {
+			code += &format!(
+				"\ninline {} operator{}({}) {{\n\treturn {}({});\n}}\n",
+				output,
+				operator.symbol(),
+				params(false),
+				name,
+				function.arg_names().join(", ")
+			);
+		}
+		code
+	}
+
+	fn file(&self, algebra_name: &str, structs: &[String], functions: &[(Function, String)]) -> String {
+		format!(
+			"\
+// The {algebra_name} geometric algebra: generated types and operations.
+#pragma once
+
+namespace {algebra_name} {{
+
+{structs}
+{functions}
+}} // namespace {algebra_name}
+",
+			algebra_name = algebra_name,
+			structs = structs.join("\n"),
+			functions = functions.iter().map(|(_, code)| code).join("\n"),
+		)
+	}
+}
@@ -6,9 +6,18 @@ use {
 use crate::*;
 
 pub fn unary_table(unit_blades: &[Expr], rust: &impl Fn(Expr) -> String) -> String {
+	unary_table_of(Unary::iter(), unit_blades, rust)
+}
+
+/// Like [`unary_table`], but only with the given unary operations.
+pub fn unary_table_of(
+	unaries: impl Iterator<Item = Unary>,
+	unit_blades: &[Expr],
+	rust: &impl Fn(Expr) -> String,
+) -> String {
 	markdown::table(
 		chain(Some("Op \\ Blade".to_owned()), unit_blades.iter().cloned().map(rust)),
-		Unary::iter().map(|unary| {
+		unaries.map(|unary| {
 			chain(
 				Some(unary.short_description().to_owned()),
 				unit_blades.iter().map(|blade| rust(Expr::unary(unary, blade.clone()))),
@@ -24,7 +33,7 @@ pub fn multiplication_tables(unit_blades: &[Expr], rust: &impl Fn(Expr) -> Strin
 			format!(
 				"### {} multiplication table\n\n{}\n",
 				prod.trait_name(),
-				multiplication_table(unit_blades, Product::Geometric, rust)
+				multiplication_table(unit_blades, prod, rust)
 			)
 		})
 		.join("\n")
@@ -8,6 +8,7 @@ impl Expr {
 		match self {
 			Expr::Var { .. } => self,
 			Expr::Vec(_) => self,
+			Expr::Coordinates { .. } => self,
 			Expr::Unary(unary, expr) => Expr::Unary(unary, expr.explicit(t, g).into()),
 			Expr::Term(expr, scalar) => Expr::Term(expr.explicit(t, g).into(), scalar),
 			Expr::Sum(terms) => Expr::Sum(terms.into_iter().map(|e| e.explicit(t, g)).collect()),
@@ -37,9 +38,10 @@ fn explicit_product(product: Product, factors: &[Expr], t: &Types, g: &Grammar)
 	let mut sblades = Vec::with_capacity(factors.len());
 	for factor in factors {
 		if let Expr::Var { name, typ, .. } = factor {
-			let sblade = typ.clone().into_sblade()?;
-			names.push(format!("{}.0", name));
-			sblades.push(sblade);
+			if !matches!(typ, Type::Constant(_)) {
+				names.push(format!("{}.0", name));
+			}
+			sblades.push(typ.clone().into_sblade()?);
 		} else {
 			return None;
 		}
@@ -53,7 +55,11 @@ fn explicit_product(product: Product, factors: &[Expr], t: &Types, g: &Grammar)
 
 	let var = Expr::Var {
 		order: 0,
-		name: format!("{}({})", type_name, names.iter().join(" * ")),
+		name: if names.is_empty() {
+			format!("{}(1.0)", type_name)
+		} else {
+			format!("{}({})", type_name, names.iter().join(" * "))
+		},
 		typ: Type::SBlade(product_sblade),
 	};
 
@@ -32,6 +32,18 @@ pub enum Expr {
 
 	/// An instance of a struct, e.g. `Point {  x: ..., y: ... }` etc.
 	StructInstance(StructInstance),
+
+	/// The product of the coordinates of some blade variables, times a unit blade,
+	/// e.g. `E12(self.x.0 * rhs.y.0)`.
+	/// Used for grammars with a non-diagonal metric, where the product of two blades can be a sum of blades.
+	Coordinates {
+		/// The blade variables, as `(order, name)`, sorted.
+		vars: Vec<(usize, String)>,
+		/// Always a unit blade (or the canonical sign of `type_name`).
+		sblade: SBlade,
+		/// The name of the blade type, once we know it (see `typify`).
+		type_name: Option<String>,
+	},
 }
 
 /// An instance of a struct, e.g. `Point {  x: ..., y: ... }` etc.
@@ -133,10 +145,7 @@ impl Expr {
 
 	/// Note: self must be simplified
 	pub fn is_one(&self) -> bool {
-		match self {
-			Expr::Prod(Product::Geometric, factors) if factors.is_empty() => true,
-			_ => false,
-		}
+		matches!(self, Expr::Prod(Product::Geometric, factors) if factors.is_empty())
 	}
 
 	pub fn is_negation(&self) -> bool {
@@ -166,13 +175,7 @@ impl Expr {
 		match self {
 			Expr::Var { .. } => None,
 			Expr::Vec(vi) => Some(SBlade::vec(*vi)),
-			Expr::Term(expr, s) => {
-				if let Some(sblade) = expr.as_sblade(g) {
-					Some(*s * sblade)
-				} else {
-					None
-				}
-			}
+			Expr::Term(expr, s) => expr.as_sblade(g).map(|sblade| *s * sblade),
 			Expr::Unary(unary, expr) => Some(expr.as_sblade(g)?.unary(*unary, g)),
 			Expr::Sum(terms) => {
 				if terms.is_empty() {
@@ -186,9 +189,13 @@ impl Expr {
 			Expr::Prod(product, factors) => {
 				let sblades: Option<Vec<SBlade>> = factors.iter().map(|f| f.as_sblade(g)).collect();
 				let sblades = sblades?;
-				Some(SBlade::product(*product, &sblades, g))
+				if g.is_diagonal() {
+					Some(SBlade::product(*product, &sblades, g))
+				} else {
+					single_sblade(SBlade::product_terms(*product, &sblades, g))
+				}
 			}
-			Expr::StructInstance { .. } => None,
+			Expr::StructInstance { .. } | Expr::Coordinates { .. } => None,
 		}
 	}
 }
@@ -201,7 +208,7 @@ mod tests {
 
 	#[test]
 	fn test_as_sblade() {
-		let grammar = Grammar(vec![0, 1, 1, 1]);
+		let grammar = Grammar::new(vec![0, 1, 1, 1]);
 		let g = &grammar;
 		let v0 = VecIdx(0);
 		let v1 = VecIdx(1);
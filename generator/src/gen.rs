@@ -4,18 +4,88 @@ use crate::{documentation::*, *};
 
 const CODE_SEPARATOR: &str = "// ---------------------------------------------------------------------";
 
+/// The name of the struct with all the blades, see [`Types::insert_multivector`].
+pub const MULTIVECTOR: &str = "Multivector";
+
 pub struct Settings {
+	/// The scalar type of the blades, e.g. "f32" or "f64".
+	/// When `generic` is set, this is the default for the type parameter instead.
 	pub float_type: String,
+
+	/// Make all blades and structs generic over `T: Float`, e.g. `Vec3<T>`.
+	pub generic: bool,
+
+	/// Also generate `extern "C"` functions for all operations, and a C header (see [`ffi`]).
+	pub ffi: bool,
+
+	/// Also generate support for `serde`, `bytemuck`, `mint` and `approx` (see [`features`]),
+	/// and conversions for `glam`, `nalgebra` and `cgmath` (see [`interop`]),
+	/// behind cargo features of the same names, which the crate must declare.
+	pub cargo_features: bool,
+
+	/// Which operations to generate.
+	pub selection: Selection,
+
+	/// Leave out the `// Omitted: …` comments for the operations that are not generated
+	/// (e.g. a sandwich whose output is not of the type of the operand).
+	pub prune: bool,
 }
 
 impl Default for Settings {
 	fn default() -> Self {
 		Self {
 			float_type: "f64".to_string(),
+			generic: false,
+			ffi: false,
+			cargo_features: false,
+			selection: Selection::default(),
+			prune: false,
 		}
 	}
 }
 
+/// Which operations to generate. `None` means all of them, which is the default.
+///
+/// The hand-written code (`exp`, `ln`, the queries, …) needs specific products,
+/// so it is only generated when everything is selected.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Selection {
+	pub products: Option<Vec<Product>>,
+	pub unaries: Option<Vec<Unary>>,
+	pub sandwiches: Option<Vec<Sandwich>>,
+	/// The `(lhs, rhs)` pairs of struct names to generate the products of,
+	/// and the `(versor, operand)` pairs for the sandwiches.
+	pub struct_pairs: Option<Vec<(String, String)>>,
+}
+
+impl Selection {
+	pub fn is_all(&self) -> bool {
+		self == &Self::default()
+	}
+
+	pub fn has_product(&self, product: Product) -> bool {
+		self.products
+			.as_ref()
+			.is_none_or(|products| products.contains(&product))
+	}
+
+	pub fn has_unary(&self, unary: Unary) -> bool {
+		self.unaries.as_ref().is_none_or(|unaries| unaries.contains(&unary))
+	}
+
+	pub fn has_sandwich(&self, sandwich: Sandwich) -> bool {
+		self.sandwiches
+			.as_ref()
+			.is_none_or(|sandwiches| sandwiches.contains(&sandwich))
+	}
+
+	pub fn has_struct_pair(&self, lhs: &str, rhs: &str) -> bool {
+		self.struct_pairs
+			.as_ref()
+			.is_none_or(|pairs| pairs.iter().any(|(l, r)| l == lhs && r == rhs))
+	}
+}
+
 pub struct Generator {
 	pub grammar: Grammar,
 	pub types: Types,
@@ -23,11 +93,98 @@ pub struct Generator {
 	pub ro: RustOptions,
 }
 
+/// One of the built-in grammars in [`grammars`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Builtin {
+	Pga2d,
+	Pga3d,
+}
+
 impl Generator {
-	fn rust(&self, expr: Expr) -> String {
-		expr.simplify(Some(&self.grammar))
-			.typify(&self.types, &self.grammar)
-			.rust(&self.ro)
+	/// Is `struct_name` laid out like in one of the built-in grammars?
+	/// Used for the hand-written code (constructors, `exp`, …) that only makes sense for those.
+	pub fn builtin_struct(&self, struct_name: &str) -> Option<Builtin> {
+		let typ = self.types.try_get(struct_name)?;
+		vec![(Builtin::Pga2d, grammars::pga2d()), (Builtin::Pga3d, grammars::pga3d())]
+			.into_iter()
+			.find(|(_, (grammar, types))| &self.grammar == grammar && types.try_get(struct_name) == Some(typ))
+			.map(|(builtin, _)| builtin)
+	}
+
+	/// How to refer to the given blade or struct in a type position, e.g. `Vec3` or `Vec3<T>`.
+	pub fn type_ref(&self, name: &str) -> String {
+		if self.settings.generic && name != "Zero" {
+			format!("{}<T>", name)
+		} else {
+			name.to_owned()
+		}
+	}
+
+	/// The generics of an `impl`, e.g. `<T: Float>`.
+	pub fn impl_generics(&self) -> &'static str {
+		if self.settings.generic {
+			"<T: Float>"
+		} else {
+			""
+		}
+	}
+
+	/// The scalar type, e.g. `f64` or `T`.
+	pub fn float_type(&self) -> &str {
+		if self.settings.generic {
+			"T"
+		} else {
+			&self.settings.float_type
+		}
+	}
+
+	/// The unary operations to generate: the selected ones, except the ones that need a diagonal metric.
+	pub fn unaries(&self) -> impl Iterator<Item = Unary> + '_ {
+		Unary::iter()
+			.filter(move |unary| self.grammar.is_diagonal() || !unary.needs_diagonal_metric())
+			.filter(move |unary| self.settings.selection.has_unary(*unary))
+	}
+
+	/// The selected products of structs.
+	pub fn products(&self) -> impl Iterator<Item = Product> + '_ {
+		Product::iter().filter(move |product| self.settings.selection.has_product(*product))
+	}
+
+	/// The selected sandwich products.
+	pub fn sandwiches(&self) -> impl Iterator<Item = Sandwich> + '_ {
+		Sandwich::iter().filter(move |sandwich| self.settings.selection.has_sandwich(*sandwich))
+	}
+
+	/// The products of blades, which the code of the selected products and sandwiches of structs is made of.
+	pub fn blade_products(&self) -> impl Iterator<Item = Product> + '_ {
+		Product::iter().filter(move |product| {
+			self.settings.selection.has_product(*product) || self.sandwiches().any(|s| s.product() == *product)
+		})
+	}
+
+	/// A comment about an operation that is not generated, or nothing with [`Settings::prune`].
+	fn omitted(&self, what: &str) -> String {
+		if self.settings.prune {
+			String::new()
+		} else {
+			format!("// Omitted: {}", what)
+		}
+	}
+
+	/// Readable code for documentation, e.g. `X ^ Y`.
+	fn rust_concise(&self, expr: Expr) -> String {
+		match expr.simplify(Some(&self.grammar)) {
+			// With a non-diagonal metric the product of two blades can be a sum of blades, e.g. `-1 + Eoi`.
+			// Keep it a sum, instead of turning it into a struct:
+			Expr::Sum(terms) => Expr::Sum(
+				terms
+					.into_iter()
+					.map(|term| term.typify(&self.types, &self.grammar))
+					.collect(),
+			),
+			expr => expr.typify(&self.types, &self.grammar),
+		}
+		.rust_concise()
 	}
 }
 
@@ -35,11 +192,11 @@ pub mod blades {
 	use super::*;
 
 	pub fn file(gen: &Generator) -> String {
-		let documentation = with_line_prefixes("//! ", &documentation(gen).trim());
+		let documentation = with_line_prefixes("//! ", documentation(gen).trim());
 		format!(
 			"\
         {}\n\n\
-        use derive_more::{{Add, Mul, Neg, Sub}};\n\
+        use derive_more::{{Add, Neg, Sub}};\n\
         \n\
         use super::*;\n\
         \n\
@@ -58,7 +215,7 @@ pub mod blades {
 	}
 
 	fn documentation(gen: &Generator) -> String {
-		let rust = |expr| gen.rust(expr);
+		let rust = |expr| gen.rust_concise(expr);
 		let unit_blades = gen.types.unit_blades();
 		format!(
 			"\
@@ -71,7 +228,7 @@ pub mod blades {
     ## Multiplication tables\n\
     {}\n\
     ",
-			unary_table(&unit_blades, &rust),
+			unary_table_of(gen.unaries(), &unit_blades, &rust),
 			multiplication_tables(&unit_blades, &rust)
 		)
 	}
@@ -95,22 +252,67 @@ pub mod blades {
 			code += "/// The pseudo-scalar.\n"
 		};
 
-		let squares_to = sb.geometric_product(&sb, &gen.grammar);
+		let squares_to = SBlade::product_terms(Product::Geometric, &[sb.clone(), sb.clone()], &gen.grammar);
+		let squares_to = single_sblade(squares_to).unwrap();
 		assert!(squares_to.is_scalar());
 		let squares_to = squares_to.sign;
 		code += &format!("/// Squares to {}.\n", squares_to);
 
-		let mut derives = "Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub".to_string();
-		if is_scalar {
-			derives += ", Mul"; // NOTE: NOT the pseudoscalar (it may scale to zero)
-		}
+		let derives = "Copy, Clone, Debug, Default, PartialEq, PartialOrd, Neg, Add, Sub";
 		code += &format!("#[derive({})]\n", derives);
+		// Same layout as the float, so that structs of blades can be passed to C (see `ffi`):
+		code += "#[repr(transparent)]\n";
+		code += features::derive_attributes(gen);
+
+		if gen.settings.generic {
+			code += &format!("pub struct {}<T = {}>(pub T);\n", name, gen.settings.float_type);
+		} else {
+			code += &format!("pub struct {}(pub {});\n", name, gen.settings.float_type);
+		}
+
+		// Scaling by a float, e.g. `X(1.0) * 2.0`, which is needed for coefficients:
+		code += &format!(
+			r"
+impl{Generics} std::ops::Mul<{Float}> for {Blade} {{
+    type Output = {Blade};
+    fn mul(self, rhs: {Float}) -> Self::Output {{
+        {name}(self.0 * rhs)
+    }}
+}}",
+			Generics = gen.impl_generics(),
+			Float = gen.float_type(),
+			Blade = gen.type_ref(name),
+			name = name,
+		);
+
+		let square = match squares_to {
+			0 => gen.ro.float_literal(0),
+			1 => "self.0 * self.0".to_owned(),
+			-1 => "-self.0 * self.0".to_owned(),
+			s => format!("self.0 * self.0 * {}", gen.ro.float_literal(s)),
+		};
+		code += &format!(
+			r"
+
+impl{Generics} Square for {Blade} {{
+    type Output = {Float};
+    fn square(self) -> Self::Output {{
+        {square}
+    }}
+}}",
+			Generics = gen.impl_generics(),
+			Float = gen.float_type(),
+			Blade = gen.type_ref(name),
+			square = square,
+		);
+
+		code += &features::impl_blade(gen, name);
 
-		format!("{}pub struct {}(pub {});", code, name, gen.settings.float_type)
+		code
 	}
 
 	fn impl_blade_unaryops(gen: &Generator) -> String {
-		Unary::iter()
+		gen.unaries()
 			.map(|unary| {
 				format!(
 					"// impl {} for blades:\n\n{}",
@@ -129,7 +331,7 @@ pub mod blades {
 		let result_type = sblade.unary(unary, &gen.grammar);
 
 		if result_type.is_zero() {
-			format!(" // Omitted: {}.{}() -> 0", sblade_name, unary.trait_function_name(),)
+			gen.omitted(&format!("{}.{}() -> 0", sblade_name, unary.trait_function_name()))
 		} else {
 			let (sign, output_sblade_name) = gen
 				.types
@@ -139,42 +341,52 @@ pub mod blades {
 
 			let sign = if sign == -1 { "-" } else { "" };
 
-			if unary.trait_has_output_type() {
+			let trait_impl = if unary.trait_has_output_type() {
 				format!(
 					r"
-        impl {Trait} for {sblade_name} {{
-            type Output = {Output};
+        impl{Generics} {Trait} for {Blade} {{
+            type Output = {OutputType};
             fn {function_name}(self) -> Self::Output {{
                 {Output}({sign} self.0)
             }}
         }}
         ",
-					sblade_name = sblade_name,
+					Generics = gen.impl_generics(),
+					Blade = gen.type_ref(sblade_name),
 					Trait = unary.trait_name(),
 					function_name = unary.trait_function_name(),
 					Output = output_sblade_name,
+					OutputType = gen.type_ref(output_sblade_name),
 					sign = sign,
 				)
 			} else {
 				format!(
 					r"
-        impl {Trait} for {sblade_name} {{
+        impl{Generics} {Trait} for {Blade} {{
             fn {function_name}(self) -> Self {{
                 {sign} self
             }}
         }}
         ",
-					sblade_name = sblade_name,
+					Generics = gen.impl_generics(),
+					Blade = gen.type_ref(sblade_name),
 					Trait = unary.trait_name(),
 					function_name = unary.trait_function_name(),
 					sign = sign,
 				)
-			}
+			};
+
+			let output = if unary.trait_has_output_type() {
+				output_sblade_name
+			} else {
+				sblade_name
+			};
+			trait_impl + &impl_unary_operators(gen, sblade_name, unary, output)
 		}
 	}
 
 	fn impl_blade_products(gen: &Generator) -> String {
-		Product::iter()
+		gen.blade_products()
 			.map(|prod| {
 				format!(
 					"// impl {} for blades:\n\n{}",
@@ -200,23 +412,15 @@ pub mod blades {
 	}
 
 	fn impl_blade_product(gen: &Generator, lhs: &(&str, SBlade), rhs: &(&str, SBlade), product: Product) -> String {
-		let product_type = SBlade::product(product, &[lhs.1.clone(), rhs.1.clone()], &gen.grammar);
+		let product_terms = SBlade::product_terms(product, &[lhs.1.clone(), rhs.1.clone()], &gen.grammar);
+		let product_type = match single_sblade(product_terms) {
+			Some(product_type) if product_type.sign.abs() <= 1 => product_type,
+			// With a non-diagonal metric the product can be a sum of blades, i.e. a struct:
+			_ => return impl_blade_product_as_struct(gen, lhs, rhs, product),
+		};
 
 		if product_type.is_zero() {
-			format!(
-				r"
-    impl {Trait}<{Rhs}> for {Lhs} {{
-        type Output = Zero;
-        fn {function_name}(self, _rhs: {Rhs}) -> Self::Output {{
-            Zero {{}}
-        }}
-    }}
-    ",
-				Lhs = lhs.0,
-				Rhs = rhs.0,
-				Trait = product.trait_name(),
-				function_name = product.trait_function_name(),
-			)
+			impl_zero_product(gen, lhs.0, rhs.0, product)
 		} else {
 			let (sign, output_sblade_name) = gen
 				.types
@@ -226,20 +430,67 @@ pub mod blades {
 
 			format!(
 				r"
-    impl {Trait}<{Rhs}> for {Lhs} {{
-        type Output = {Output};
+    impl{Generics} {Trait}<{Rhs}> for {Lhs} {{
+        type Output = {OutputType};
         fn {function_name}(self, rhs: {Rhs}) -> Self::Output {{
             {Output}({sign} self.0 * rhs.0)
         }}
     }}
     ",
-				Lhs = lhs.0,
-				Rhs = rhs.0,
+				Generics = gen.impl_generics(),
+				Lhs = gen.type_ref(lhs.0),
+				Rhs = gen.type_ref(rhs.0),
 				Trait = product.trait_name(),
 				function_name = product.trait_function_name(),
 				Output = output_sblade_name,
+				OutputType = gen.type_ref(output_sblade_name),
 				sign = if sign == -1 { "-" } else { "" }
-			)
+			) + &impl_product_operators(gen, lhs.0, rhs.0, product, output_sblade_name)
+		}
+	}
+
+	/// A product of two blades that is a sum of blades, e.g. `Eo * Ei = -1 + Eoi`.
+	fn impl_blade_product_as_struct(
+		gen: &Generator,
+		lhs: &(&str, SBlade),
+		rhs: &(&str, SBlade),
+		product: Product,
+	) -> String {
+		let factors = vec![
+			Expr::var(0, "self", &Type::SBlade(lhs.1.clone())),
+			Expr::var(1, "rhs", &Type::SBlade(rhs.1.clone())),
+		];
+		let expr = Expr::Prod(product, factors)
+			.simplify(Some(&gen.grammar))
+			.typify(&gen.types, &gen.grammar);
+
+		match type_name(gen, &expr) {
+			Some(output_type_name) => {
+				format!(
+					r"
+    impl{Generics} {Trait}<{Rhs}> for {Lhs} {{
+        type Output = {OutputType};
+        fn {function_name}(self, rhs: {Rhs}) -> Self::Output {{
+            {code}
+        }}
+    }}
+    ",
+					Generics = gen.impl_generics(),
+					Lhs = gen.type_ref(lhs.0),
+					Rhs = gen.type_ref(rhs.0),
+					Trait = product.trait_name(),
+					function_name = product.trait_function_name(),
+					OutputType = gen.type_ref(&output_type_name),
+					code = expr.rust(&gen.ro),
+				) + &impl_product_operators(gen, lhs.0, rhs.0, product, &output_type_name)
+			}
+			None => gen.omitted(&format!(
+				"{} {} {} = {}  (unnamed type)",
+				lhs.0,
+				product.trait_function_name(),
+				rhs.0,
+				expr.rust(&RustOptions::readable()).replace('\n', " ")
+			)),
 		}
 	}
 }
@@ -248,28 +499,38 @@ pub mod strct {
 	use super::*;
 
 	pub fn file(gen: &Generator, struct_name: &str, strct: &Struct) -> String {
-		let documentation = with_line_prefixes("//! ", &documentation(gen, struct_name, strct).trim());
+		let documentation = with_line_prefixes("//! ", documentation(gen, struct_name, strct).trim());
 
-		let unaryops = Unary::iter()
+		let unaryops = gen
+			.unaries()
 			.map(|unary| impl_struct_unary(gen, struct_name, strct, unary))
+			.chain(std::iter::once(impl_square(gen, struct_name, strct)))
+			.chain(std::iter::once(impl_norm(gen, struct_name, strct)))
+			.chain(std::iter::once(impl_arrays(gen, struct_name, strct)))
+			.chain(constructors::impls(gen, struct_name))
+			.chain(impl_multivector_conversions(gen, struct_name, strct))
+			.chain(exp_log::impls(gen, struct_name))
+			.chain(matrix::impls(gen, struct_name, strct))
+			.chain(interop::impls(gen, struct_name, strct))
 			.join("\n");
 
 		let binops = gen
 			.types
 			.structs()
+			.filter(|(rhs_name, _)| gen.settings.selection.has_struct_pair(struct_name, rhs_name))
 			.map(|(rhs_name, rhs_struct)| {
 				format!(
 					"// {} OP {}:\n\n{}\n",
 					struct_name,
 					rhs_name,
-					Product::iter()
+					gen.products()
 						.map(|prod| impl_struct_product(gen, &(struct_name, strct), &(rhs_name, rhs_struct), prod))
 						.join("\n")
 				)
 			})
 			.join(&format!("\n{}\n", CODE_SEPARATOR));
 
-		format!(
+		let mut code = format!(
 			"\
         {}\n\n\
         use super::*;\n\n\
@@ -284,11 +545,42 @@ pub mod strct {
 			unaryops,
 			CODE_SEPARATOR,
 			binops,
-		)
+		);
+
+		if is_versor(gen, strct) {
+			code += &format!(
+				"{}\n// Sandwich products:\n\n{}\n",
+				CODE_SEPARATOR,
+				sandwich_operands(gen)
+					.filter(|(rhs_name, _)| gen.settings.selection.has_struct_pair(struct_name, rhs_name))
+					.flat_map(|rhs| gen.sandwiches().map(move |sandwich| (rhs, sandwich)))
+					.map(|(rhs, sandwich)| impl_sandwich(gen, &(struct_name, strct), &rhs, sandwich))
+					.join("\n")
+			);
+		}
+
+		code
+	}
+
+	/// Versors (rotors, motors, …) are the structs with a scalar or pseudo-scalar part.
+	/// These are the ones we generate sandwich products for.
+	pub fn is_versor(gen: &Generator, strct: &Struct) -> bool {
+		strct.values().any(|member| match &member.typ {
+			Type::SBlade(sblade) => sblade.grade() == 0 || sblade.grade() == gen.grammar.num_vecs(),
+			_ => false,
+		})
+	}
+
+	/// The structs that can be transformed by a versor (points, lines, planes, …).
+	pub fn sandwich_operands(gen: &Generator) -> impl Iterator<Item = (&str, &Struct)> {
+		gen.types.structs().filter(move |(_, strct)| !is_versor(gen, strct))
 	}
 
 	fn documentation(gen: &Generator, struct_name: &str, strct: &Struct) -> String {
-		let homo_ops = Product::iter()
+		let has_pair = |lhs: &str, rhs: &str| gen.settings.selection.has_struct_pair(lhs, rhs);
+		let homo_ops = gen
+			.products()
+			.filter(|_| has_pair(struct_name, struct_name))
 			.filter_map(|product| {
 				struct_product_type_signature(gen, &(struct_name, strct), &(struct_name, strct), product)
 			})
@@ -298,11 +590,13 @@ pub mod strct {
 			.types
 			.structs()
 			.filter_map(|(other_struct_name, other_strct)| {
-				if struct_name == other_struct_name {
+				if struct_name == other_struct_name
+					|| !has_pair(struct_name, other_struct_name) && !has_pair(other_struct_name, struct_name)
+				{
 					None
 				} else {
 					Some(
-						Product::iter()
+						gen.products()
 							.flat_map(|product| {
 								itertools::chain(
 									struct_product_type_signature(
@@ -310,13 +604,15 @@ pub mod strct {
 										&(struct_name, strct),
 										&(other_struct_name, other_strct),
 										product,
-									),
+									)
+									.filter(|_| has_pair(struct_name, other_struct_name)),
 									struct_product_type_signature(
 										gen,
 										&(other_struct_name, other_strct),
 										&(struct_name, strct),
 										product,
-									),
+									)
+									.filter(|_| has_pair(other_struct_name, struct_name)),
 								)
 							})
 							.join("\n"),
@@ -338,69 +634,478 @@ pub mod strct {
 		)
 	}
 
-	fn declare_struct(_gen: &Generator, struct_name: &str, strct: &Struct) -> String {
-		// TODO: we can only implement Add, Sub if the struct has no Type::Constant
-		let derives =
-            "Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub\n";
+	fn declare_struct(gen: &Generator, struct_name: &str, strct: &Struct) -> String {
+		// Adding or negating e.g. normalized points would not keep the constants at one:
+		let derives = if strct.values().any(StructMember::is_constant) {
+			"Copy, Clone, Debug, Default, PartialEq, PartialOrd\n"
+		} else {
+			"Copy, Clone, Debug, Default, PartialEq, PartialOrd, derive_more::Neg, derive_more::Add, derive_more::Sub\n"
+		};
 		let members = strct
 			.iter()
-			.map(|(member_name, member_type)| format!("    pub {}: {},", member_name, member_type.name))
+			.map(|(member_name, member)| {
+				if member.is_constant() {
+					format!("    // {}: {} = 1", member_name, member.name)
+				} else {
+					format!("    pub {}: {},", member_name, gen.type_ref(&member.name))
+				}
+			})
 			.join("\n");
+		let generics = if gen.settings.generic {
+			format!("<T = {}>", gen.settings.float_type)
+		} else {
+			String::new()
+		};
 		format!(
 			"\
 #[derive({})]\n\
-pub struct {} {{\n\
+#[repr(C)]\n\
+{}\
+pub struct {}{} {{\n\
     {}\n\
-}}\n	",
-			derives, struct_name, members
+}}\n\
+{}\n	",
+			derives,
+			features::derive_attributes(gen),
+			struct_name,
+			generics,
+			members,
+			features::impl_struct(gen, struct_name, strct),
 		)
 	}
 
+	/// The output type and value of the unary operation on `self`, or `None` if it is omitted.
+	pub fn struct_unary_output(
+		gen: &Generator,
+		struct_name: &str,
+		strct: &Struct,
+		unary: Unary,
+	) -> Option<(String, Expr)> {
+		let var = Expr::var(0, "self", &Type::strct(strct));
+		let expr = Expr::unary(unary, var);
+		let expr = expr.simplify(Some(&gen.grammar)).typify(&gen.types, &gen.grammar);
+		let output_type_name = type_name(gen, &expr)?;
+		if unary.trait_has_output_type() || output_type_name == struct_name {
+			Some((output_type_name, expr))
+		} else {
+			None
+		}
+	}
+
 	pub fn impl_struct_unary(gen: &Generator, struct_name: &str, strct: &Struct, unary: Unary) -> String {
 		let var = Expr::var(0, "self", &Type::strct(strct));
 		let expr = Expr::unary(unary, var);
 		let expr = expr.simplify(Some(&gen.grammar)).typify(&gen.types, &gen.grammar);
 		let code = expr.rust(&gen.ro);
 		match type_name(gen, &expr) {
+			Some(output_type_name) if !unary.trait_has_output_type() && output_type_name != struct_name => {
+				// E.g. the reverse of a normalized point is a point, but we can't know it is normalized.
+				gen.omitted(&format!(
+					"{}.{}() -> {}  (not a {})",
+					struct_name,
+					unary.trait_function_name(),
+					output_type_name,
+					struct_name
+				))
+			}
 			Some(output_type_name) => {
-				if unary.trait_has_output_type() {
+				let trait_impl = if unary.trait_has_output_type() {
 					format!(
 						r"
-                impl {Trait} for {struct_name} {{
+                impl{Generics} {Trait} for {Struct} {{
                     type Output = {Output};
                     fn {function_name}(self) -> Self::Output {{
                         {code}
                     }}
                 }}
                 ",
-						struct_name = struct_name,
+						Generics = gen.impl_generics(),
+						Struct = gen.type_ref(struct_name),
 						Trait = unary.trait_name(),
 						function_name = unary.trait_function_name(),
-						Output = output_type_name,
+						Output = gen.type_ref(&output_type_name),
 						code = code,
 					)
 				} else {
 					format!(
 						r"
-                impl {Trait} for {struct_name} {{
+                impl{Generics} {Trait} for {Struct} {{
                     fn {function_name}(self) -> Self {{
                         {code}
                     }}
                 }}
                 ",
-						struct_name = struct_name,
+						Generics = gen.impl_generics(),
+						Struct = gen.type_ref(struct_name),
 						Trait = unary.trait_name(),
 						function_name = unary.trait_function_name(),
 						code = code,
 					)
-				}
+				};
+				trait_impl + &impl_unary_operators(gen, struct_name, unary, &output_type_name)
 			}
-			None => format!(
-				"// Omitted: {}.{}() -> {}",
+			None => gen.omitted(&format!(
+				"{}.{}() -> {}",
 				struct_name,
 				unary.trait_function_name(),
 				code.replace('\n', " ")
-			),
+			)),
+		}
+	}
+
+	/// Conversions to and from an array of the (non-constant) members.
+	pub fn impl_arrays(gen: &Generator, struct_name: &str, strct: &Struct) -> String {
+		let members: Vec<(&String, &StructMember)> = strct.iter().filter(|(_, member)| !member.is_constant()).collect();
+		let array_type = format!("[{}; {}]", gen.float_type(), members.len());
+		format!(
+			r"
+impl{Generics} {Struct} {{
+    /// The members as a plain array, in declaration order.
+    pub fn to_array(self) -> {Array} {{
+        [{to_array}]
+    }}
+}}
+
+impl{Generics} From<{Array}> for {Struct} {{
+    fn from(a: {Array}) -> Self {{
+        {StructName} {{
+            {from_array}
+        }}
+    }}
+}}
+
+impl{Generics} From<{Struct}> for {Array} {{
+    fn from(v: {Struct}) -> Self {{
+        v.to_array()
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Struct = gen.type_ref(struct_name),
+			StructName = struct_name,
+			Array = array_type,
+			to_array = members.iter().map(|(name, _)| format!("self.{}.0", name)).join(", "),
+			from_array = members
+				.iter()
+				.enumerate()
+				.map(|(i, (name, member))| format!("{}: {}(a[{}]),", name, member.name, i))
+				.join("\n"),
+		)
+	}
+
+	/// `From<Struct> for Multivector`, and the fallible `TryFrom<Multivector> for Struct`.
+	pub fn impl_multivector_conversions(gen: &Generator, struct_name: &str, strct: &Struct) -> Option<String> {
+		if struct_name == MULTIVECTOR {
+			return None;
+		}
+		let Some(Type::Struct(_)) = gen.types.try_get(MULTIVECTOR) else {
+			return None;
+		};
+		let mv = gen.types.get_struct(MULTIVECTOR);
+
+		// The multivector member with the same blade as the given member:
+		let mv_member = |member: &StructMember| -> &str {
+			let sblade = member.typ.clone().into_sblade().unwrap();
+			mv.iter()
+				.find(|(_, mv_member)| mv_member.typ.is_blade(&sblade.blade))
+				.map(|(name, _)| name.as_str())
+				.unwrap()
+		};
+
+		let from_struct = strct
+			.iter()
+			.map(|(name, member)| {
+				if member.is_constant() {
+					format!("{}: {}({}),", mv_member(member), member.name, gen.ro.float_literal(1))
+				} else {
+					format!("{}: v.{},", mv_member(member), name)
+				}
+			})
+			.join("\n");
+
+		let conditions = mv
+			.iter()
+			.filter_map(|(mv_name, mv_member)| {
+				let value = match strct
+					.values()
+					.find(|member| mv_member.typ.is_blade(&member.typ.clone().into_sblade().unwrap().blade))
+				{
+					Some(member) if member.is_constant() => Some(gen.ro.float_literal(1)),
+					Some(_) => None, // any value is fine
+					None => Some(gen.ro.float_literal(0)),
+				};
+				value.map(|value| format!("mv.{}.0 == {}", mv_name, value))
+			})
+			.join("\n && ");
+
+		let to_struct = strct
+			.iter()
+			.filter(|(_, member)| !member.is_constant())
+			.map(|(name, member)| format!("{}: mv.{},", name, mv_member(member)))
+			.join("\n");
+
+		Some(format!(
+			r"
+impl{Generics} From<{Struct}> for {Multivector} {{
+    fn from(v: {Struct}) -> Self {{
+        {MultivectorName} {{
+            {from_struct}
+            ..Default::default()
+        }}
+    }}
+}}
+
+impl{Generics} std::convert::TryFrom<{Multivector}> for {Struct} {{
+    type Error = {Multivector};
+
+    /// Fails (returning the multivector) if it has blades that a `{StructName}` can't represent.
+    fn try_from(mv: {Multivector}) -> Result<Self, Self::Error> {{
+        if {conditions} {{
+            Ok({StructName} {{
+                {to_struct}
+            }})
+        }} else {{
+            Err(mv)
+        }}
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Struct = gen.type_ref(struct_name),
+			StructName = struct_name,
+			Multivector = gen.type_ref(MULTIVECTOR),
+			MultivectorName = MULTIVECTOR,
+			from_struct = from_struct,
+			conditions = conditions,
+			to_struct = to_struct,
+		))
+	}
+
+	/// `self * self`, if that is a scalar (e.g. for a `Vec3`).
+	pub fn impl_square(gen: &Generator, struct_name: &str, strct: &Struct) -> String {
+		let var = Expr::var(0, "self", &Type::strct(strct));
+		let expr = Expr::geometric(vec![var.clone(), var]);
+		let expr = expr.simplify(Some(&gen.grammar)).typify(&gen.types, &gen.grammar);
+		match expr.typ(Some(&gen.grammar)) {
+			Some(Type::SBlade(sblade)) if sblade.is_scalar() && !expr.is_zero() => {
+				let code = expr.rust(&gen.ro);
+				let code = if matches!(expr, Expr::Sum(_) | Expr::Term(..)) {
+					format!("({}).0", code)
+				} else {
+					format!("{}.0", code)
+				};
+				format!(
+					r"
+impl{Generics} Square for {Struct} {{
+    type Output = {Float};
+    fn square(self) -> Self::Output {{
+        {code}
+    }}
+}}
+",
+					Generics = gen.impl_generics(),
+					Struct = gen.type_ref(struct_name),
+					Float = gen.float_type(),
+					code = code,
+				)
+			}
+			_ => gen.omitted(&format!("{}.square()  (not a scalar)", struct_name)),
+		}
+	}
+
+	/// Bulk and weight norms, see the `Norm` trait.
+	pub fn impl_norm(gen: &Generator, struct_name: &str, strct: &Struct) -> String {
+		if !gen.grammar.is_diagonal() {
+			return gen.omitted(&format!(
+				"Norm for {}  (the bulk and weight need a diagonal metric)",
+				struct_name
+			));
+		}
+
+		let is_weight = |member: &StructMember| {
+			let sblade = member.typ.clone().into_sblade().unwrap();
+			gen.grammar.is_weight(&sblade.blade)
+		};
+		let sum_of_squares = |members: Vec<(&String, &StructMember)>| {
+			if members.is_empty() {
+				gen.ro.float_literal(0)
+			} else {
+				members
+					.iter()
+					.map(|(name, member)| {
+						if member.is_constant() {
+							gen.ro.float_literal(1)
+						} else {
+							format!("self.{}.0 * self.{}.0", name, name)
+						}
+					})
+					.join(" + ")
+			}
+		};
+
+		let bulk: Vec<_> = strct.iter().filter(|(_, member)| !is_weight(member)).collect();
+		let weight: Vec<_> = strct.iter().filter(|(_, member)| is_weight(member)).collect();
+
+		// Versors with a scalar part (but no pseudo-scalar part) use the geometric product,
+		// and are normalized by their bulk (e.g. `S + XY` in 2D).
+		let has_grade = |grade: usize| {
+			strct
+				.values()
+				.any(|member| member.typ.clone().into_sblade().unwrap().grade() == grade)
+		};
+		let use_weight = !weight.is_empty() && (!has_grade(0) || has_grade(gen.grammar.num_vecs()));
+		let (norm, norm_members) = if use_weight {
+			("weight_norm", &weight)
+		} else {
+			("bulk_norm", &bulk)
+		};
+
+		let normalized = if norm_members.iter().all(|(_, member)| member.is_constant()) {
+			// e.g. the `w` of a normalized point
+			"self".to_owned()
+		} else if strct.values().any(StructMember::is_constant) {
+			return gen.omitted(&format!(
+				"Norm for {}  (scaling would change the constant members)",
+				struct_name
+			));
+		} else {
+			// A single member (e.g. the `w` of a point) is divided by itself, so that it becomes one rather than ±1:
+			let divisor = match norm_members.as_slice() {
+				[(name, _)] => format!("self.{}.0", name),
+				_ => format!("self.{}()", norm),
+			};
+			format!(
+				"let scale = {one} / {divisor};\n{Struct} {{\n{members}\n}}",
+				one = gen.ro.float_literal(1),
+				divisor = divisor,
+				Struct = struct_name,
+				members = strct
+					.keys()
+					.map(|name| format!("    {}: self.{} * scale,", name, name))
+					.join("\n"),
+			)
+		};
+
+		format!(
+			r"
+impl{Generics} Norm for {Struct} {{
+    type Output = {Float};
+
+    fn norm_squared(self) -> Self::Output {{
+        {norm_squared}
+    }}
+
+    fn bulk_norm_squared(self) -> Self::Output {{
+        {bulk_norm_squared}
+    }}
+
+    fn bulk_norm(self) -> Self::Output {{
+        self.bulk_norm_squared().sqrt()
+    }}
+
+    fn weight_norm_squared(self) -> Self::Output {{
+        {weight_norm_squared}
+    }}
+
+    fn weight_norm(self) -> Self::Output {{
+        self.weight_norm_squared().sqrt()
+    }}
+
+    fn normalized(self) -> Self {{
+{normalized}
+    }}
+
+    fn is_normalized(&self, eps: Self::Output) -> bool {{
+        (self.{norm}() - {one}).abs() <= eps
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Struct = gen.type_ref(struct_name),
+			Float = gen.float_type(),
+			norm_squared = sum_of_squares(strct.iter().collect()),
+			bulk_norm_squared = sum_of_squares(bulk),
+			weight_norm_squared = sum_of_squares(weight),
+			normalized = rust::indent_n(2, &normalized),
+			norm = norm,
+			one = gen.ro.float_literal(1),
+		)
+	}
+
+	/// The sandwich product of a versor and a struct, which is always of the same type as the struct.
+	fn struct_sandwich_expr(
+		gen: &Generator,
+		versor: &(&str, &Struct),
+		operand: &(&str, &Struct),
+		sandwich: Sandwich,
+	) -> Expr {
+		let expr = sandwich.expr(
+			Expr::var(0, "self", &Type::strct(versor.1)),
+			Expr::var(1, "rhs", &Type::strct(operand.1)),
+		);
+		expr.simplify(Some(&gen.grammar))
+	}
+
+	/// The value of the sandwich of `rhs` by `self`, or `None` if it is omitted.
+	pub fn struct_sandwich_output(
+		gen: &Generator,
+		versor: &(&str, &Struct),
+		operand: &(&str, &Struct),
+		sandwich: Sandwich,
+	) -> Option<Expr> {
+		let expr = struct_sandwich_expr(gen, versor, operand, sandwich);
+		if expr.is_zero() {
+			None
+		} else {
+			expr.typify_as_struct(operand.0, operand.1, &gen.types, &gen.grammar)
+		}
+	}
+
+	pub fn impl_sandwich(
+		gen: &Generator,
+		versor: &(&str, &Struct),
+		operand: &(&str, &Struct),
+		sandwich: Sandwich,
+	) -> String {
+		let expr = struct_sandwich_expr(gen, versor, operand, sandwich);
+
+		let omitted = |reason: &str| {
+			gen.omitted(&format!(
+				"{}.{}({})  ({})",
+				versor.0,
+				sandwich.trait_function_name(),
+				operand.0,
+				reason
+			))
+		};
+
+		if expr.is_zero() {
+			return omitted("zero");
+		}
+
+		match expr.typify_as_struct(operand.0, operand.1, &gen.types, &gen.grammar) {
+			Some(expr) => {
+				let code = rust::indent_n(2, &expr.rust(&gen.ro));
+				format!(
+					r"
+// {Versor}.{function_name}({Operand}) -> {Operand}
+impl{Generics} {Trait}<{OperandType}> for {VersorType} {{
+    fn {function_name}(self, rhs: {OperandType}) -> {OperandType} {{
+{code}
+    }}
+}}
+",
+					Generics = gen.impl_generics(),
+					Versor = versor.0,
+					Operand = operand.0,
+					VersorType = gen.type_ref(versor.0),
+					OperandType = gen.type_ref(operand.0),
+					Trait = sandwich.trait_name(),
+					function_name = sandwich.trait_function_name(),
+					code = code,
+				)
+			}
+			None => omitted(&format!("not a {}", operand.0)),
 		}
 	}
 
@@ -421,36 +1126,46 @@ pub struct {} {{\n\
 		Some(format!("{} -> {}", input_code, type_name))
 	}
 
-	pub fn impl_struct_product(
-		gen: &Generator,
-		lhs: &(&str, &Struct),
-		rhs: &(&str, &Struct),
-		product: Product,
-	) -> String {
+	fn struct_product_expr(gen: &Generator, lhs: &(&str, &Struct), rhs: &(&str, &Struct), product: Product) -> Expr {
 		let factors = vec![
 			Expr::var(0, "self", &Type::strct(lhs.1)),
 			Expr::var(1, "rhs", &Type::strct(rhs.1)), // TODO: name this snake_case(rhs.0) iff lhs type != rhs.type
 		];
 		let expr = Expr::Prod(product, factors);
 		let expr = expr.simplify(Some(&gen.grammar));
-		let expr = expr.typify(&gen.types, &gen.grammar);
-
-		if let Expr::StructInstance(si) = &expr {
-			if si.count_zeros() > 1 {
-				// For instance, `Vec3 ^ Vec3 -> Line3`, but the Line has zero direction,
-				// which makes very little sense.
-				return format!(
-					"// Omitted: {} {} {} = {}  (too many zeros)",
-					lhs.0,
-					product.trait_function_name(),
-					rhs.0,
-					expr.rust(&RustOptions::readable()).replace('\n', " ")
-				);
-			}
-		}
+		expr.typify(&gen.types, &gen.grammar)
+	}
 
-		match type_name(gen, &expr) {
-			Some(output_type_name) => {
+	/// The output type and value of the product of `self` and `rhs`, or `None` if it is zero.
+	/// An output that is not one of the named types is returned as a [`MULTIVECTOR`], if there is one.
+	pub fn struct_product_output(
+		gen: &Generator,
+		lhs: &(&str, &Struct),
+		rhs: &(&str, &Struct),
+		product: Product,
+	) -> Option<(String, Expr)> {
+		let expr = struct_product_expr(gen, lhs, rhs, product);
+		if expr.is_zero() {
+			return None;
+		}
+		if let Some(output_type_name) = type_name(gen, &expr) {
+			return Some((output_type_name, expr));
+		}
+		let Some(Type::Struct(_)) = gen.types.try_get(MULTIVECTOR) else {
+			return None;
+		};
+		let expr = expr.typify_as_struct(MULTIVECTOR, gen.types.get_struct(MULTIVECTOR), &gen.types, &gen.grammar)?;
+		Some((MULTIVECTOR.to_owned(), expr))
+	}
+
+	pub fn impl_struct_product(
+		gen: &Generator,
+		lhs: &(&str, &Struct),
+		rhs: &(&str, &Struct),
+		product: Product,
+	) -> String {
+		match struct_product_output(gen, lhs, rhs, product) {
+			Some((output_type_name, expr)) => {
 				let explicit = expr
 					.clone()
 					.typify(&gen.types, &gen.grammar)
@@ -459,6 +1174,8 @@ pub struct {} {{\n\
 					.rust(&gen.ro);
 
 				let code = expr.rust(&gen.ro);
+				// The result may only depend on the constants of `rhs`:
+				let rhs_name = if code.contains("rhs") { "rhs" } else { "_rhs" };
 
 				let code = format!("{}\n{}", with_line_prefixes("// ", &explicit), code);
 				let code = rust::indent_n(2, &code);
@@ -466,33 +1183,115 @@ pub struct {} {{\n\
 				format!(
 					r"
 // {comment}
-impl {Trait}<{Rhs}> for {Lhs} {{
+impl{Generics} {Trait}<{Rhs}> for {Lhs} {{
     type Output = {Output};
-    fn {function_name}(self, rhs: {Rhs}) -> Self::Output {{
+    fn {function_name}(self, {rhs_name}: {Rhs}) -> Self::Output {{
 {code}
     }}
 }}
         ",
 					comment = struct_product_type_signature(gen, lhs, rhs, product).unwrap(),
-					Lhs = lhs.0,
-					Rhs = rhs.0,
+					rhs_name = rhs_name,
+					Generics = gen.impl_generics(),
+					Lhs = gen.type_ref(lhs.0),
+					Rhs = gen.type_ref(rhs.0),
 					Trait = product.trait_name(),
 					function_name = product.trait_function_name(),
-					Output = output_type_name,
+					Output = gen.type_ref(&output_type_name),
 					code = code,
-				)
+				) + &impl_product_operators(gen, lhs.0, rhs.0, product, &output_type_name)
+			}
+			None => {
+				let expr = struct_product_expr(gen, lhs, rhs, product);
+				if expr.is_zero() {
+					impl_zero_product(gen, lhs.0, rhs.0, product)
+				} else {
+					gen.omitted(&format!(
+						"{} {} {} = {}  (unnamed type, and no {})",
+						lhs.0,
+						product.trait_function_name(),
+						rhs.0,
+						expr.rust(&RustOptions::readable()).replace('\n', " "),
+						MULTIVECTOR
+					))
+				}
 			}
-			None => format!(
-				"// Omitted: {} {} {} = {}  (unnamed type)",
-				lhs.0,
-				product.trait_function_name(),
-				rhs.0,
-				expr.rust(&RustOptions::readable()).replace('\n', " ")
-			),
 		}
 	}
 }
 
+/// A product that is always zero, e.g. `X ^ X`.
+fn impl_zero_product(gen: &Generator, lhs: &str, rhs: &str, product: Product) -> String {
+	format!(
+		r"
+impl{Generics} {Trait}<{Rhs}> for {Lhs} {{
+    type Output = Zero;
+    fn {function_name}(self, _rhs: {Rhs}) -> Self::Output {{
+        Zero {{}}
+    }}
+}}
+",
+		Generics = gen.impl_generics(),
+		Lhs = gen.type_ref(lhs),
+		Rhs = gen.type_ref(rhs),
+		Trait = product.trait_name(),
+		function_name = product.trait_function_name(),
+	) + &impl_product_operators(gen, lhs, rhs, product, "Zero")
+}
+
+/// `impl std::ops::Mul<Rhs> for Lhs` etc, forwarding to the product trait,
+/// for each operator that `RustOptions::product_operators` maps to the product.
+fn impl_product_operators(gen: &Generator, lhs: &str, rhs: &str, product: Product, output: &str) -> String {
+	gen.ro
+		.product_operators(product)
+		.map(|operator| {
+			format!(
+				r"
+impl{Generics} std::ops::{Operator}<{Rhs}> for {Lhs} {{
+    type Output = {Output};
+    fn {operator_function}(self, rhs: {Rhs}) -> Self::Output {{
+        self.{function_name}(rhs)
+    }}
+}}
+",
+				Generics = gen.impl_generics(),
+				Operator = operator.trait_name(),
+				operator_function = operator.trait_function_name(),
+				Lhs = gen.type_ref(lhs),
+				Rhs = gen.type_ref(rhs),
+				Output = gen.type_ref(output),
+				function_name = product.trait_function_name(),
+			)
+		})
+		.join("")
+}
+
+/// `impl std::ops::Not for T` etc, forwarding to the unary trait,
+/// for each operator that `RustOptions::unary_operators` maps to the unary operation.
+fn impl_unary_operators(gen: &Generator, type_name: &str, unary: Unary, output: &str) -> String {
+	gen.ro
+		.unary_operators(unary)
+		.map(|operator| {
+			format!(
+				r"
+impl{Generics} std::ops::{Operator} for {Type} {{
+    type Output = {Output};
+    fn {operator_function}(self) -> Self::Output {{
+        self.{function_name}()
+    }}
+}}
+",
+				Generics = gen.impl_generics(),
+				Operator = operator.trait_name(),
+				operator_function = operator.trait_function_name(),
+				Type = gen.type_ref(type_name),
+				Output = gen.type_ref(output),
+				function_name = unary.trait_function_name(),
+			)
+		})
+		.join("")
+}
+
 /// Returns None if the type is Zero or unknown
 fn type_name(gen: &Generator, expr: &Expr) -> Option<String> {
 	// println!("type_name({})", expr.rust(&gen.ro));
@@ -505,3 +1304,2429 @@ fn type_name(gen: &Generator, expr: &Expr) -> Option<String> {
 		Some(gen.types.type_name(&output_type).to_owned())
 	}
 }
+
+/// Closed-form `exp` and `ln` between the bivectors and motors of 2D and 3D PGA.
+/// These are only generated for the layouts of the built-in [`grammars::pga2d`] and [`grammars::pga3d`]:
+/// unlike the products, they are written by hand for those layouts rather than derived from the grammar,
+/// so other grammars (e.g. [`grammars::vga3d`] or [`grammars::cga3d`]) don't get them.
+pub mod exp_log {
+	use super::*;
+
+	/// The names of the bivector and motor structs, if they have the built-in layout
+	/// (and all operations are generated).
+	fn algebra(gen: &Generator) -> Option<(Builtin, &'static str, &'static str)> {
+		if !gen.settings.selection.is_all() {
+			return None;
+		}
+		if gen.builtin_struct("Line") == Some(Builtin::Pga2d) && gen.builtin_struct("Motor") == Some(Builtin::Pga2d) {
+			Some((Builtin::Pga2d, "Line", "Motor"))
+		} else if gen.builtin_struct("Line3") == Some(Builtin::Pga3d)
+			&& gen.builtin_struct("Motor3") == Some(Builtin::Pga3d)
+		{
+			Some((Builtin::Pga3d, "Line3", "Motor3"))
+		} else {
+			None
+		}
+	}
+
+	/// `impl Exp` for the bivector struct, `impl Ln` and `lerp_log` for the motor struct.
+	pub fn impls(gen: &Generator, struct_name: &str) -> Option<String> {
+		let (algebra, line, motor) = algebra(gen)?;
+		let code = if struct_name == line {
+			match algebra {
+				Builtin::Pga2d => exp_2d(gen),
+				Builtin::Pga3d => exp_3d(gen),
+			}
+		} else if struct_name == motor {
+			let ln = match algebra {
+				Builtin::Pga2d => ln_2d(gen),
+				Builtin::Pga3d => ln_3d(gen),
+			};
+			format!("{}\n{}", ln, lerp_log(gen, algebra, line, motor))
+		} else {
+			return None;
+		};
+		Some(code)
+	}
+
+	/// `Line = dx YW + dy WX + m XY` squares to `-m²`, so `exp(Line) = cos(m) + sin(m)/m Line`.
+	fn exp_2d(gen: &Generator) -> String {
+		format!(
+			r"
+impl{Generics} Exp for {Line} {{
+    type Output = {Motor};
+
+    /// The motor that rotates by `2 * self.m` around the line, or translates if `m` is zero.
+    fn exp(self) -> Self::Output {{
+        let angle = self.m.0.abs();
+        let (sin_over_angle, cos) = if angle == {zero} {{
+            ({one}, {one})
+        }} else {{
+            (angle.sin() / angle, angle.cos())
+        }};
+        Motor {{
+            s: S(cos),
+            yw: self.dx * sin_over_angle,
+            wx: self.dy * sin_over_angle,
+            xy: self.m * sin_over_angle,
+        }}
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Line = gen.type_ref("Line"),
+			Motor = gen.type_ref("Motor"),
+			zero = gen.ro.float_literal(0),
+			one = gen.ro.float_literal(1),
+		)
+	}
+
+	fn ln_2d(gen: &Generator) -> String {
+		format!(
+			r"
+impl{Generics} Ln for {Motor} {{
+    type Output = {Line};
+
+    /// The inverse of [`Exp`] for a normalized motor.
+    fn ln(self) -> Self::Output {{
+        // `self` and `-self` are the same motion, so pick the shortest path:
+        let motor = if self.s.0 < {zero} {{ -self }} else {{ self }};
+        let angle = motor.xy.0.atan2(motor.s.0);
+        let scale = if motor.xy.0 == {zero} {{
+            {one} / motor.s.0
+        }} else {{
+            angle / motor.xy.0
+        }};
+        Line {{
+            dx: motor.yw * scale,
+            dy: motor.wx * scale,
+            m: motor.xy * scale,
+        }}
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Line = gen.type_ref("Line"),
+			Motor = gen.type_ref("Motor"),
+			zero = gen.ro.float_literal(0),
+			one = gen.ro.float_literal(1),
+		)
+	}
+
+	/// With the anti-product the identity is `XYZW`, and the weight `v` of the line anti-squares to `-|v|² XYZW`.
+	/// We split the line into a unit line `L` through the same axis and a translation `d` along it,
+	/// which commute, so `exp(a L + d) = (cos(a) + sin(a) L) ⟇ (XYZW + d)`.
+	fn exp_3d(gen: &Generator) -> String {
+		format!(
+			r"
+impl{Generics} Exp for {Line3} {{
+    type Output = {Motor3};
+
+    /// The screw motion along the line: rotating by twice the weight norm around it,
+    /// and translating by twice the moment along it.
+    /// A line with zero weight (a line at infinity) gives a pure translation.
+    fn exp(self) -> Self::Output {{
+        let vv = self.vx.0 * self.vx.0 + self.vy.0 * self.vy.0 + self.vz.0 * self.vz.0;
+        if vv == {zero} {{
+            return Motor3 {{
+                rx: WX({zero}),
+                ry: WY({zero}),
+                rz: WZ({zero}),
+                rw: XYZW({one}),
+                ux: self.mx,
+                uy: self.my,
+                uz: self.mz,
+                uw: S({zero}),
+            }};
+        }}
+        let angle = vv.sqrt();
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let vm = self.vx.0 * self.mx.0 + self.vy.0 * self.my.0 + self.vz.0 * self.mz.0;
+        // The distance along the axis:
+        let dist = vm / angle;
+        // The moment is `perp + (vm / vv) * v`, where `perp` is orthogonal to `v`:
+        let parallel = vm / vv;
+        let perp_scale = sin / angle;
+        let v_scale = dist * cos / angle;
+        Motor3 {{
+            rx: self.vx * perp_scale,
+            ry: self.vy * perp_scale,
+            rz: self.vz * perp_scale,
+            rw: XYZW(cos),
+            ux: YZ(perp_scale * (self.mx.0 - parallel * self.vx.0) + v_scale * self.vx.0),
+            uy: ZX(perp_scale * (self.my.0 - parallel * self.vy.0) + v_scale * self.vy.0),
+            uz: XY(perp_scale * (self.mz.0 - parallel * self.vz.0) + v_scale * self.vz.0),
+            uw: S(-dist * sin),
+        }}
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Line3 = gen.type_ref("Line3"),
+			Motor3 = gen.type_ref("Motor3"),
+			zero = gen.ro.float_literal(0),
+			one = gen.ro.float_literal(1),
+		)
+	}
+
+	fn ln_3d(gen: &Generator) -> String {
+		format!(
+			r"
+impl{Generics} Ln for {Motor3} {{
+    type Output = {Line3};
+
+    /// The inverse of [`Exp`] for a normalized motor.
+    fn ln(self) -> Self::Output {{
+        // `self` and `-self` are the same motion, so pick the shortest path:
+        let motor = if self.rw.0 < {zero} {{ -self }} else {{ self }};
+        let rr = motor.rx.0 * motor.rx.0 + motor.ry.0 * motor.ry.0 + motor.rz.0 * motor.rz.0;
+        if rr == {zero} {{
+            // Pure translation
+            let scale = {one} / motor.rw.0;
+            return Line3 {{
+                vx: WX({zero}),
+                vy: WY({zero}),
+                vz: WZ({zero}),
+                mx: motor.ux * scale,
+                my: motor.uy * scale,
+                mz: motor.uz * scale,
+            }};
+        }}
+        let sin = rr.sqrt();
+        let cos = motor.rw.0;
+        let angle = sin.atan2(cos);
+        let ur = motor.ux.0 * motor.rx.0 + motor.uy.0 * motor.ry.0 + motor.uz.0 * motor.rz.0;
+        // The distance along the axis:
+        let dist = cos * ur / sin - sin * motor.uw.0;
+        let parallel = dist / sin;
+        let scale = angle / sin;
+        Line3 {{
+            vx: motor.rx * scale,
+            vy: motor.ry * scale,
+            vz: motor.rz * scale,
+            mx: YZ(scale * (motor.ux.0 - cos * parallel * motor.rx.0) + parallel * motor.rx.0),
+            my: ZX(scale * (motor.uy.0 - cos * parallel * motor.ry.0) + parallel * motor.ry.0),
+            mz: XY(scale * (motor.uz.0 - cos * parallel * motor.rz.0) + parallel * motor.rz.0),
+        }}
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Line3 = gen.type_ref("Line3"),
+			Motor3 = gen.type_ref("Motor3"),
+			zero = gen.ro.float_literal(0),
+			one = gen.ro.float_literal(1),
+		)
+	}
+
+	fn lerp_log(gen: &Generator, algebra: Builtin, line: &str, motor: &str) -> String {
+		let (compose, inverse) = match algebra {
+			Builtin::Pga2d => ("geometric", "rev"),
+			Builtin::Pga3d => ("anti_geometric", "arev"),
+		};
+		let scaled_line = gen
+			.types
+			.get_struct(line)
+			.keys()
+			.map(|name| format!("{}: delta.{} * t,", name, name))
+			.join("\n");
+		format!(
+			r"
+impl{Generics} {Motor} {{
+    /// Interpolate between two normalized motors along the screw motion between them,
+    /// so that `t=0` gives `self` and `t=1` gives `other`.
+    pub fn lerp_log(self, other: {Motor}, t: {Float}) -> {Motor} {{
+        let delta = other.{compose}(self.{inverse}()).ln();
+        let delta = {LineName} {{
+            {scaled_line}
+        }};
+        delta.exp().{compose}(self)
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Motor = gen.type_ref(motor),
+			Float = gen.float_type(),
+			LineName = line,
+			compose = compose,
+			inverse = inverse,
+			scaled_line = scaled_line,
+		)
+	}
+}
+
+/// Convenience constructors for the structs of the built-in grammars, e.g. `Vec4::point(x, y, z)`.
+pub mod constructors {
+	use super::*;
+
+	pub fn impls(gen: &Generator, struct_name: &str) -> Option<String> {
+		let body = match gen.builtin_struct(struct_name)? {
+			Builtin::Pga2d => pga2d(gen, struct_name)?,
+			Builtin::Pga3d => pga3d(gen, struct_name)?,
+		};
+		Some(format!(
+			"impl{Generics} {Struct} {{{body}}}\n",
+			Generics = gen.impl_generics(),
+			Struct = gen.type_ref(struct_name),
+			body = body,
+		))
+	}
+
+	/// In `pga2d` the versors move a point by sandwiching its bivector, i.e. `motor.sandwich(p.rcompl()).rcompl()`.
+	fn pga2d(gen: &Generator, struct_name: &str) -> Option<String> {
+		let zero = gen.ro.float_literal(0);
+		let one = gen.ro.float_literal(1);
+		let half = format!("{} / {}", one, gen.ro.float_literal(2));
+		let body = match struct_name {
+			"Vec3" => format!(
+				r"
+    /// A normalized point, i.e. with `w=1`.
+    pub fn point(x: {F}, y: {F}) -> Self {{
+        Vec3 {{ x: X(x), y: Y(y), w: W({one}) }}
+    }}
+
+    /// A direction, a.k.a. a point at infinity, i.e. with `w=0`.
+    pub fn direction(x: {F}, y: {F}) -> Self {{
+        Vec3 {{ x: X(x), y: Y(y), w: W({zero}) }}
+    }}
+",
+				F = gen.float_type(),
+				zero = zero,
+				one = one,
+			),
+			"Line" => format!(
+				r"
+    /// The line through `a` and `b`, directed from `a` to `b`.
+    /// Same as `Vec3::point(a[0], a[1]) ^ Vec3::point(b[0], b[1])`.
+    pub fn from_points(a: [{F}; 2], b: [{F}; 2]) -> Self {{
+        let dir = [b[0] - a[0], b[1] - a[1]];
+        Self::from_point_and_direction(a, dir)
+    }}
+
+    /// The line through `point` with the given direction.
+    pub fn from_point_and_direction(point: [{F}; 2], dir: [{F}; 2]) -> Self {{
+        let [x, y] = point;
+        Line {{
+            dx: YW(-dir[1]),
+            dy: WX(dir[0]),
+            m: XY(x * dir[1] - y * dir[0]),
+        }}
+    }}
+",
+				F = gen.float_type(),
+			),
+			"Rotor" => format!(
+				r"
+    /// Rotate counter-clockwise by `angle` (in radians) around the origin.
+    /// A point `p` is rotated by `rotor.sandwich(p.rcompl()).rcompl()`.
+    pub fn from_angle(angle: {F}) -> Self {{
+        let half_angle = angle * ({half});
+        Rotor {{
+            s: S(half_angle.cos()),
+            xy: XY(-half_angle.sin()),
+        }}
+    }}
+",
+				F = gen.float_type(),
+				half = half,
+			),
+			"Motor" => format!(
+				r"
+    /// A pure translation.
+    /// A point `p` is moved by `motor.sandwich(p.rcompl()).rcompl()`.
+    pub fn from_translation(t: [{F}; 2]) -> Self {{
+        let half = {half};
+        Motor {{
+            s: S({one}),
+            yw: YW(half * t[1]),
+            wx: WX(-half * t[0]),
+            xy: XY({zero}),
+        }}
+    }}
+",
+				F = gen.float_type(),
+				zero = zero,
+				one = one,
+				half = half,
+			),
+			_ => return None,
+		};
+		Some(body)
+	}
+
+	fn pga3d(gen: &Generator, struct_name: &str) -> Option<String> {
+		let zero = gen.ro.float_literal(0);
+		let one = gen.ro.float_literal(1);
+		let half = format!("{} / {}", one, gen.ro.float_literal(2));
+		let body = match struct_name {
+			"Vec4" => format!(
+				r"
+    /// A normalized point, i.e. with `w=1`.
+    pub fn point(x: {F}, y: {F}, z: {F}) -> Self {{
+        Vec4 {{ x: X(x), y: Y(y), z: Z(z), w: W({one}) }}
+    }}
+
+    /// A direction, a.k.a. a point at infinity, i.e. with `w=0`.
+    pub fn direction(x: {F}, y: {F}, z: {F}) -> Self {{
+        Vec4 {{ x: X(x), y: Y(y), z: Z(z), w: W({zero}) }}
+    }}
+",
+				F = gen.float_type(),
+				zero = zero,
+				one = one,
+			),
+			"Plane" => format!(
+				r"
+    /// The plane of all points `p` where `normal · p = distance`.
+    /// `Point3 ^ Plane` then gives the signed distance to the plane (if `normal` is normalized).
+    pub fn from_normal_and_distance(normal: [{F}; 3], distance: {F}) -> Self {{
+        Plane {{
+            nx: YZW(normal[0]),
+            ny: ZXW(normal[1]),
+            nz: XYW(normal[2]),
+            d: XYZ(distance),
+        }}
+    }}
+",
+				F = gen.float_type(),
+			),
+			"Line3" => format!(
+				r"
+    /// The line through `a` and `b`, directed from `a` to `b`.
+    /// Same as `Point3::from(a) ^ Point3::from(b)`.
+    pub fn from_points(a: [{F}; 3], b: [{F}; 3]) -> Self {{
+        let dir = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+        Self::from_point_and_direction(a, dir)
+    }}
+
+    /// The line through `point` with the given direction.
+    pub fn from_point_and_direction(point: [{F}; 3], dir: [{F}; 3]) -> Self {{
+        let [x, y, z] = point;
+        Line3 {{
+            vx: WX(dir[0]),
+            vy: WY(dir[1]),
+            vz: WZ(dir[2]),
+            mx: YZ(y * dir[2] - z * dir[1]),
+            my: ZX(z * dir[0] - x * dir[2]),
+            mz: XY(x * dir[1] - y * dir[0]),
+        }}
+    }}
+",
+				F = gen.float_type(),
+			),
+			"Rotor3" => format!(
+				r"
+    /// Rotate `angle` radians around the normalized `axis` through the origin,
+    /// counter-clockwise when the axis points towards the viewer.
+    pub fn from_axis_angle(axis: [{F}; 3], angle: {F}) -> Self {{
+        let half_angle = angle * ({half});
+        let (sin, cos) = (half_angle.sin(), half_angle.cos());
+        Rotor3 {{
+            x: WX(sin * axis[0]),
+            y: WY(sin * axis[1]),
+            z: WZ(sin * axis[2]),
+            w: XYZW(cos),
+        }}
+    }}
+",
+				F = gen.float_type(),
+				half = half,
+			),
+			"Motor3" => format!(
+				r"
+    /// A pure translation.
+    pub fn from_translation(t: [{F}; 3]) -> Self {{
+        let half = {half};
+        Motor3 {{
+            rx: WX({zero}),
+            ry: WY({zero}),
+            rz: WZ({zero}),
+            rw: XYZW({one}),
+            ux: YZ(half * t[0]),
+            uy: ZX(half * t[1]),
+            uz: XY(half * t[2]),
+            uw: S({zero}),
+        }}
+    }}
+",
+				F = gen.float_type(),
+				zero = zero,
+				one = one,
+				half = half,
+			),
+			_ => return None,
+		};
+		Some(body)
+	}
+}
+
+/// Named geometric queries (distances, angles, projections, meets) for the built-in grammars,
+/// composed from the generated products. Written to `queries.rs`.
+pub mod queries {
+	use super::*;
+
+	/// `None` unless the types used by the queries are those of `pga2d` or `pga3d`,
+	/// and all operations are generated.
+	pub fn file(gen: &Generator) -> Option<String> {
+		if !gen.settings.selection.is_all() {
+			return None;
+		}
+		let is = |builtin: Builtin, names: &[&str]| names.iter().all(|name| gen.builtin_struct(name) == Some(builtin));
+		let body = if is(Builtin::Pga2d, &["Vec2", "Vec3", "Line"]) {
+			queries_2d(gen)
+		} else if is(Builtin::Pga3d, &["Vec3", "Vec4", "Moment3", "Line3", "Plane"]) {
+			queries_3d(gen)
+		} else {
+			return None;
+		};
+		Some(format!(
+			"\
+//! Distances, angles, projections and intersections.
+//!
+//! Points are homogeneous (any non-zero `w`) unless otherwise noted,
+//! and planes and lines need not be normalized.
+
+use super::*;
+{}",
+			body
+		))
+	}
+
+	fn queries_2d(gen: &Generator) -> String {
+		format!(
+			r"
+/// Signed distance from `point` to `line`.
+/// Positive to the left of the line, e.g. of `a ^ b` when walking from `a` to `b`.
+pub fn point_line_distance{G}(point: {Vec3}, line: {Line}) -> {F} {{
+    point.wedge(line).0 / (line.weight_norm() * point.w.0)
+}}
+
+/// Signed angle in radians from `a` to `b`, in `[-π, π]`.
+/// Positive if `b` points counter-clockwise from `a`.
+pub fn line_line_angle{G}(a: {Line}, b: {Line}) -> {F} {{
+    let cos = a.anti_dot(b).0;
+    let sin = a.anti_wedge(b).w.0;
+    sin.atan2(cos)
+}}
+
+/// The point on `line` closest to `point`, normalized to `w=±1`.
+pub fn project_point_on_line{G}(point: {Vec3}, line: {Line}) -> {Vec3} {{
+    line.anti_wedge(point.wedge(line.weight_dual())).normalized()
+}}
+
+/// The intersection of two lines.
+/// Not normalized: `w` is zero if the lines are parallel.
+pub fn meet_lines{G}(a: {Line}, b: {Line}) -> {Vec3} {{
+    a.anti_wedge(b)
+}}
+
+/// The line through `point` with the same direction as `line`.
+pub fn parallel_line_through{G}(point: {Vec3}, line: {Line}) -> {Line} {{
+    let horizon = Line {{
+        m: XY({one}),
+        ..Default::default()
+    }};
+    point.wedge(line.anti_wedge(horizon))
+}}
+",
+			G = gen.impl_generics(),
+			F = gen.float_type(),
+			Vec3 = gen.type_ref("Vec3"),
+			Line = gen.type_ref("Line"),
+			one = gen.ro.float_literal(1),
+		)
+	}
+
+	fn queries_3d(gen: &Generator) -> String {
+		format!(
+			r"
+/// Signed distance from `point` to `plane`.
+/// Positive on the side that the normal of the plane points to.
+pub fn point_plane_distance{G}(point: {Vec4}, plane: {Plane}) -> {F} {{
+    point.wedge(plane).0 / (plane.weight_norm() * point.w.0)
+}}
+
+/// Distance from `point` to `line` (never negative).
+pub fn point_line_distance{G}(point: {Vec4}, line: {Line3}) -> {F} {{
+    point.wedge(line).weight_norm() / (line.weight_norm() * point.w.0.abs())
+}}
+
+/// Shortest distance between two lines (never negative), including (nearly) parallel lines.
+pub fn line_line_distance{G}(a: {Line3}, b: {Line3}) -> {F} {{
+    // |a ^ b| is the distance times the sine of the angle between the lines, times their weights:
+    let sin = a.weight_dual().commutator(b.weight_dual()).bulk_norm();
+    let weights = a.weight_norm() * b.weight_norm();
+    if sin > {epsilon}.sqrt() * weights {{
+        a.wedge(b).0.abs() / sin
+    }} else {{
+        // Dividing by a smaller sine would magnify the rounding errors of `a ^ b`,
+        // so treat the lines as parallel, where any point on `a` will do:
+        let origin = Vec4 {{
+            w: W({one}),
+            ..Default::default()
+        }};
+        point_line_distance(project_point_on_line(origin, a), b)
+    }}
+}}
+
+/// Angle in radians between the normals of two planes, in `[0, π]`.
+pub fn plane_plane_angle{G}(a: {Plane}, b: {Plane}) -> {F} {{
+    let cos = a.anti_dot(b).0;
+    let sin = a.anti_wedge(b).weight_norm();
+    sin.atan2(cos)
+}}
+
+/// The point on `plane` closest to `point`, normalized to `w=±1`.
+pub fn project_point_on_plane{G}(point: {Vec4}, plane: {Plane}) -> {Vec4} {{
+    plane.anti_wedge(point.wedge(plane.weight_dual())).normalized()
+}}
+
+/// The point on `line` closest to `point`, normalized to `w=±1`.
+pub fn project_point_on_line{G}(point: {Vec4}, line: {Line3}) -> {Vec4} {{
+    line.anti_wedge(point.wedge(line.weight_dual())).normalized()
+}}
+
+/// The point where three planes meet.
+/// Not normalized: `w` is zero if the planes don't meet in a single finite point.
+/// `w` is positive if the normals `a, b, c` are right-handed.
+pub fn meet_planes{G}(a: {Plane}, b: {Plane}, c: {Plane}) -> {Vec4} {{
+    a.anti_wedge(b).anti_wedge(c)
+}}
+
+/// The line through `point` with the same direction as `line`.
+pub fn parallel_line_through{G}(point: {Vec4}, line: {Line3}) -> {Line3} {{
+    // The plane at infinity, oriented so that `line & horizon` is the direction of `line`:
+    let horizon = Plane {{
+        d: XYZ(-{one}),
+        ..Default::default()
+    }};
+    point.wedge(line.anti_wedge(horizon))
+}}
+",
+			G = gen.impl_generics(),
+			F = gen.float_type(),
+			Vec4 = gen.type_ref("Vec4"),
+			Line3 = gen.type_ref("Line3"),
+			Plane = gen.type_ref("Plane"),
+			one = gen.ro.float_literal(1),
+			epsilon = if gen.settings.generic {
+				"T::epsilon()".to_owned()
+			} else {
+				format!("{}::EPSILON", gen.float_type())
+			},
+		)
+	}
+}
+
+/// `extern "C"` functions for all the products, unary operations and sandwiches of the structs,
+/// and a C header declaring them.
+///
+/// All C names start with a prefix, e.g. `pga3d_line3_geometric_vec3`,
+/// so that several algebras can be linked into the same program.
+/// The structs are `#[repr(C)]` with the members in the order of the [`Types`],
+/// and the blades have the same layout as the float type.
+pub mod ffi {
+	use super::*;
+
+	/// The C type of the float type, if there is one.
+	pub fn c_float_type(gen: &Generator) -> Option<&'static str> {
+		match gen.settings.float_type.as_str() {
+			"f32" => Some("float"),
+			"f64" => Some("double"),
+			_ => None,
+		}
+	}
+
+	/// An `extern "C"` function, forwarding to a trait method.
+	struct Function {
+		name: String,
+		/// The struct names of the arguments.
+		args: Vec<String>,
+		/// The name of a struct or blade.
+		output: String,
+		/// E.g. `Geometric::geometric`.
+		method: String,
+	}
+
+	fn functions(gen: &Generator, prefix: &str) -> Vec<Function> {
+		let mut functions = vec![];
+		for (struct_name, strct) in gen.types.structs() {
+			for unary in gen.unaries() {
+				if let Some((output, _)) = strct::struct_unary_output(gen, struct_name, strct, unary) {
+					functions.push(Function {
+						name: format!(
+							"{}_{}_{}",
+							prefix,
+							struct_name.to_ascii_lowercase(),
+							unary.trait_function_name()
+						),
+						args: vec![struct_name.to_owned()],
+						output,
+						method: format!("{}::{}", unary.trait_name(), unary.trait_function_name()),
+					});
+				}
+			}
+
+			for (rhs_name, rhs_struct) in gen.types.structs() {
+				if !gen.settings.selection.has_struct_pair(struct_name, rhs_name) {
+					continue;
+				}
+				for product in gen.products() {
+					let lhs = (struct_name, strct);
+					let rhs = (rhs_name, rhs_struct);
+					if let Some((output, _)) = strct::struct_product_output(gen, &lhs, &rhs, product) {
+						functions.push(Function {
+							name: format!(
+								"{}_{}_{}_{}",
+								prefix,
+								struct_name.to_ascii_lowercase(),
+								product.trait_function_name(),
+								rhs_name.to_ascii_lowercase()
+							),
+							args: vec![struct_name.to_owned(), rhs_name.to_owned()],
+							output,
+							method: format!("{}::{}", product.trait_name(), product.trait_function_name()),
+						});
+					}
+				}
+			}
+
+			if strct::is_versor(gen, strct) {
+				for operand in strct::sandwich_operands(gen) {
+					if !gen.settings.selection.has_struct_pair(struct_name, operand.0) {
+						continue;
+					}
+					for sandwich in gen.sandwiches() {
+						let versor = (struct_name, strct);
+						if strct::struct_sandwich_output(gen, &versor, &operand, sandwich).is_some() {
+							functions.push(Function {
+								name: format!(
+									"{}_{}_{}_{}",
+									prefix,
+									struct_name.to_ascii_lowercase(),
+									sandwich.trait_function_name(),
+									operand.0.to_ascii_lowercase()
+								),
+								args: vec![struct_name.to_owned(), operand.0.to_owned()],
+								output: operand.0.to_owned(),
+								method: format!("{}::{}", sandwich.trait_name(), sandwich.trait_function_name()),
+							});
+						}
+					}
+				}
+			}
+		}
+		functions
+	}
+
+	fn arg_names(function: &Function) -> &'static [&'static str] {
+		if function.args.len() == 1 {
+			&["value"]
+		} else {
+			&["lhs", "rhs"]
+		}
+	}
+
+	/// The Rust side, e.g. `ffi.rs`.
+	pub fn file(gen: &Generator, prefix: &str) -> String {
+		let functions = functions(gen, prefix)
+			.iter()
+			.map(|function| {
+				let arg_names = arg_names(function);
+				format!(
+					"#[no_mangle]\npub extern \"C\" fn {name}({args}) -> {output} {{\n    {method}({arg_names})\n}}\n",
+					name = function.name,
+					args = arg_names
+						.iter()
+						.zip(&function.args)
+						.map(|(arg_name, arg)| format!("{}: {}", arg_name, arg))
+						.join(", "),
+					output = function.output,
+					method = function.method,
+					arg_names = arg_names.join(", "),
+				)
+			})
+			.join("\n");
+
+		format!(
+			"\
+//! The C ABI of this algebra, declared in `{prefix}.h`.
+
+use super::*;
+
+{functions}",
+			prefix = prefix,
+			functions = functions,
+		)
+	}
+
+	/// The C side, e.g. `pga3d.h`.
+	pub fn header(gen: &Generator, prefix: &str) -> String {
+		let float = c_float_type(gen).expect("The float type has no C equivalent");
+		let c_type = |name: &str| {
+			if matches!(gen.types.try_get(name), Some(Type::Struct(_))) {
+				format!("{}_{}", prefix, name)
+			} else {
+				float.to_owned()
+			}
+		};
+
+		let structs = gen
+			.types
+			.structs()
+			.map(|(struct_name, strct)| {
+				let members = strct
+					.iter()
+					.map(|(member_name, member)| {
+						if member.is_constant() {
+							format!("\t/* {}: {} = 1 */", member_name, member.name)
+						} else {
+							format!("\t{} {}; /* {} */", float, member_name, member.name)
+						}
+					})
+					.join("\n");
+				format!(
+					"typedef struct {Name} {{\n{members}\n}} {Name};\n",
+					Name = c_type(struct_name),
+					members = members
+				)
+			})
+			.join("\n");
+
+		let functions = functions(gen, prefix)
+			.iter()
+			.map(|function| {
+				let args = arg_names(function)
+					.iter()
+					.zip(&function.args)
+					.map(|(arg_name, arg)| format!("{} {}", c_type(arg), arg_name))
+					.join(", ");
+				format!("{} {}({});", c_type(&function.output), function.name, args)
+			})
+			.join("\n");
+
+		let guard = format!("{}_H", prefix.to_ascii_uppercase());
+		format!(
+			"\
+/* The C ABI of the `{prefix}` geometric algebra, generated together with its Rust code.
+ * All values are passed by value, and the structs have the same layout as in Rust. */
+
+#ifndef {guard}
+#define {guard}
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+{structs}
+{functions}
+
+#ifdef __cplusplus
+}}
+#endif
+
+#endif /* {guard} */
+",
+			prefix = prefix,
+			guard = guard,
+			structs = structs,
+			functions = functions,
+		)
+	}
+}
+
+/// Optional support for other crates, behind cargo features of the same names (see [`Settings::cargo_features`]):
+///
+/// * `serde`: `Serialize` and `Deserialize` for all blades and structs.
+/// * `bytemuck`: `Zeroable` and `Pod` for all blades and structs.
+/// * `approx`: `AbsDiffEq` and `RelativeEq` for all blades and structs.
+/// * `mint`: conversions between e.g. `Vec3 { x, y, z }` and `mint::Vector3`,
+///   and between e.g. `Point3 { x, y, z }` (with a constant `w`) and `mint::Point3`.
+pub mod features {
+	use super::*;
+
+	/// Attributes to put after the `#[derive(…)]` of a blade or struct.
+	pub fn derive_attributes(gen: &Generator) -> &'static str {
+		if gen.settings.cargo_features {
+			"#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]\n"
+		} else {
+			""
+		}
+	}
+
+	pub fn impl_blade(gen: &Generator, name: &str) -> String {
+		if gen.settings.cargo_features {
+			format!("\n\n{}\n\n{}", impl_bytemuck(gen, name), impl_approx(gen, name, &["0"]))
+		} else {
+			String::new()
+		}
+	}
+
+	pub fn impl_struct(gen: &Generator, struct_name: &str, strct: &Struct) -> String {
+		if !gen.settings.cargo_features {
+			return String::new();
+		}
+		let members = stored_members(strct);
+		let mut code = format!(
+			"\n{}\n\n{}\n",
+			impl_bytemuck(gen, struct_name),
+			impl_approx(gen, struct_name, &members)
+		);
+		if let Some((kind, dimension)) = vector_kind(gen, strct) {
+			let mint_type = format!("{}{}", kind, dimension);
+			code += &format!("\n{}\n", impl_mint(gen, struct_name, strct, &mint_type));
+		}
+		code
+	}
+
+	fn stored_members(strct: &Struct) -> Vec<&str> {
+		strct
+			.iter()
+			.filter(|(_, member)| !member.is_constant())
+			.map(|(member_name, _)| member_name.as_str())
+			.collect()
+	}
+
+	/// Blades are `#[repr(transparent)]` and structs are `#[repr(C)]` with only floats in them,
+	/// so there is no padding.
+	fn impl_bytemuck(gen: &Generator, name: &str) -> String {
+		let (zeroable_generics, pod_generics) = if gen.settings.generic {
+			("<T: bytemuck::Zeroable>", "<T: bytemuck::Pod>")
+		} else {
+			("", "")
+		};
+		format!(
+			r#"
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl{ZeroableGenerics} bytemuck::Zeroable for {Type} {{}}
+
+// Safety: only floats and no padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl{PodGenerics} bytemuck::Pod for {Type} {{}}"#,
+			ZeroableGenerics = zeroable_generics,
+			PodGenerics = pod_generics,
+			Type = gen.type_ref(name),
+		)
+	}
+
+	/// Compares the members one by one, e.g. `self.x.abs_diff_eq(&other.x, epsilon) && …`.
+	fn impl_approx(gen: &Generator, name: &str, members: &[&str]) -> String {
+		let (generics, where_clause) = if gen.settings.generic {
+			("<T: Float + approx::RelativeEq>", " where T::Epsilon: Copy")
+		} else {
+			("", "")
+		};
+		let compare = |method: &str, args: &str| {
+			if members.is_empty() {
+				"true".to_owned()
+			} else {
+				members
+					.iter()
+					.map(|member| {
+						format!(
+							"self.{m}.{method}(&other.{m}, {args})",
+							m = member,
+							method = method,
+							args = args
+						)
+					})
+					.join(" && ")
+			}
+		};
+		format!(
+			r#"
+#[cfg(feature = "approx")]
+impl{Generics} approx::AbsDiffEq for {Type}{Where} {{
+    type Epsilon = <{Float} as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {{
+        <{Float} as approx::AbsDiffEq>::default_epsilon()
+    }}
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {{
+        {abs_diff_eq}
+    }}
+}}
+
+#[cfg(feature = "approx")]
+impl{Generics} approx::RelativeEq for {Type}{Where} {{
+    fn default_max_relative() -> Self::Epsilon {{
+        <{Float} as approx::RelativeEq>::default_max_relative()
+    }}
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {{
+        {relative_eq}
+    }}
+}}"#,
+			Generics = generics,
+			Type = gen.type_ref(name),
+			Where = where_clause,
+			Float = gen.float_type(),
+			abs_diff_eq = compare("abs_diff_eq", "epsilon"),
+			relative_eq = compare("relative_eq", "epsilon, max_relative"),
+		)
+	}
+
+	/// `("Vector", 3)` for `Vec3 { x, y, z }`, or `("Point", 2)` for `Point2 { x, y }` with a constant `w`,
+	/// as in `mint::Vector3` and `mint::Point2`.
+	/// Only for vectors (grade 1), so e.g. a rotor with members `x, y, z, w` is not a `Vector4`.
+	pub fn vector_kind(gen: &Generator, strct: &Struct) -> Option<(&'static str, usize)> {
+		const COORDINATES: [&str; 4] = ["x", "y", "z", "w"];
+		let members = stored_members(strct);
+		let num_constants = strct.len() - members.len();
+		let is_vector =
+			|member: &StructMember| matches!(gen.types.get(&member.name), Type::SBlade(sb) if sb.grade() == 1);
+		if !COORDINATES.starts_with(&members) || !strct.values().all(is_vector) {
+			return None;
+		}
+		match (members.len(), num_constants) {
+			(2..=4, 0) => Some(("Vector", members.len())),
+			(2..=3, 1) => Some(("Point", members.len())),
+			_ => None,
+		}
+	}
+
+	fn impl_mint(gen: &Generator, struct_name: &str, strct: &Struct, mint_type: &str) -> String {
+		let members = stored_members(strct);
+		let from_mint = members
+			.iter()
+			.map(|member| format!("{m}: {blade}(v.{m}),", m = member, blade = strct[*member].name))
+			.join("\n");
+		let to_mint = members
+			.iter()
+			.map(|member| format!("{m}: v.{m}.0,", m = member))
+			.join("\n");
+		format!(
+			r#"
+#[cfg(feature = "mint")]
+impl{Generics} From<mint::{Mint}<{Float}>> for {Type} {{
+    fn from(v: mint::{Mint}<{Float}>) -> Self {{
+        {name} {{
+            {from_mint}
+        }}
+    }}
+}}
+
+#[cfg(feature = "mint")]
+impl{Generics} From<{Type}> for mint::{Mint}<{Float}> {{
+    fn from(v: {Type}) -> Self {{
+        mint::{Mint} {{
+            {to_mint}
+        }}
+    }}
+}}
+
+#[cfg(feature = "mint")]
+impl{Generics} mint::IntoMint for {Type} {{
+    type MintType = mint::{Mint}<{Float}>;
+}}"#,
+			Generics = gen.impl_generics(),
+			Type = gen.type_ref(struct_name),
+			Float = gen.float_type(),
+			Mint = mint_type,
+			name = struct_name,
+			from_mint = from_mint,
+			to_mint = to_mint,
+		)
+	}
+}
+
+/// Transformation matrices of the versors (`to_matrix` and `from_matrix`),
+/// for the versors that transform the struct of all the base vectors (e.g. `Vec4` in [`grammars::pga3d`]).
+///
+/// None of this is written down per algebra. The matrices come from sandwiching the base vectors
+/// (the simplified sandwich of a versor with the struct of base vectors),
+/// so `to_matrix` is exact and without branches, and it all follows the struct definitions.
+/// `from_matrix` solves for the products of pairs of members (e.g. `x * w`),
+/// and takes square roots of them, also without branches.
+///
+/// With the structs of [`grammars::pga2d`] (`Rotor`, `Translator` and `Motor`)
+/// or [`grammars::pga3d`] (`Rotor3`, `Translator3` and `Motor3`), a motor is split into a rotor and a translator.
+/// When the last base vector is the origin (as in `pga3d`), the motor also gets `rotor` and `translation`.
+pub mod matrix {
+	use std::collections::BTreeMap;
+
+	use super::*;
+
+	/// A polynomial in the members of a struct, e.g. `2 x y - z²` is `{[x, y]: 2, [z, z]: -1}`.
+	pub type Polynomial = BTreeMap<Vec<String>, i32>;
+
+	/// An exact fraction, as `(numerator, denominator)` with a positive denominator.
+	type Fraction = (i128, i128);
+
+	pub fn impls(gen: &Generator, struct_name: &str, strct: &Struct) -> Option<String> {
+		let vector = vector_struct(gen)?;
+		let motors = motors(gen);
+		let motors_of_struct = motors
+			.as_ref()
+			.filter(|motors| [motors.rotor, motors.translator, motors.motor].contains(&struct_name));
+		let identity = Operand::identity(vector);
+		let operand = motors_of_struct.map_or(&identity, |motors| &motors.operand);
+		let sandwich = versor_sandwich(gen, strct)?;
+		let matrix = operand.matrix(gen, struct_name, sandwich)?;
+		let translation = motors_of_struct.and_then(|motors| motors.translation.as_ref());
+
+		let mut code = vec![to_matrix(
+			gen,
+			struct_name,
+			operand,
+			sandwich,
+			&matrix,
+			translation.is_some(),
+		)];
+		code.extend(identity_versor(gen, struct_name, strct, sandwich));
+		match &motors {
+			Some(motors) if struct_name == motors.motor => {
+				if motors.translation.is_some() {
+					code.push(motor_helpers(gen, motors));
+				}
+				code.push(motor_from_matrix(gen, motors, matrix.len()));
+			}
+			_ => code.extend(versor_from_matrix(gen, struct_name, strct, sandwich, &matrix)),
+		}
+		Some(code.join("\n"))
+	}
+
+	/// The struct of all the base vectors (e.g. `Vec4 { x, y, z, w }` in `pga3d`), which the matrices transform.
+	pub fn vector_struct(gen: &Generator) -> Option<&str> {
+		gen.types
+			.structs()
+			.find(|(_, strct)| {
+				let blades: Vec<&Blade> = strct
+					.values()
+					.filter_map(|member| match &member.typ {
+						Type::SBlade(sblade) if sblade.grade() == 1 => Some(&sblade.blade),
+						_ => None,
+					})
+					.unique()
+					.collect();
+				blades.len() == gen.grammar.num_vecs() && blades.len() == strct.len()
+			})
+			.map(|(name, _)| name)
+	}
+
+	/// What the matrices of the versors transform: the struct of all the base vectors (e.g. `Vec4` in `pga3d`),
+	/// either by sandwiching it, or by sandwiching its right complement and taking the right complement of the result.
+	/// The latter is for the motors of `pga2d`, whose geometric sandwich moves a `Vec3` like a line,
+	/// but its right complement (a `Line`) like a point.
+	#[derive(Clone, Debug)]
+	pub struct Operand {
+		/// The struct of all the base vectors.
+		pub vector: String,
+		/// The struct of their right complements, and for each base vector `i` the `(index, sign, back_sign)`
+		/// of `rcompl(vector[i]) = sign * complement[index]` and `rcompl(complement[index]) = back_sign * vector[i]`.
+		complement: Option<(String, Vec<ComplementMember>)>,
+	}
+
+	/// `(index, sign, back_sign)`, see [`Operand`].
+	type ComplementMember = (usize, i32, i32);
+
+	impl Operand {
+		fn identity(vector: &str) -> Self {
+			Self {
+				vector: vector.to_owned(),
+				complement: None,
+			}
+		}
+
+		/// `None` unless there is a struct of exactly the right complements of the base vectors.
+		fn complement(gen: &Generator, vector: &str) -> Option<Self> {
+			let sblades = |strct: &Struct| {
+				strct
+					.values()
+					.map(|member| match &member.typ {
+						Type::SBlade(sblade) => Some(sblade.clone()),
+						_ => None,
+					})
+					.collect::<Option<Vec<SBlade>>>()
+			};
+			let vector_sblades = sblades(gen.types.get_struct(vector))?;
+			gen.types.structs().find_map(|(name, strct)| {
+				let complement_sblades = sblades(strct)?;
+				if name == vector || complement_sblades.len() != vector_sblades.len() {
+					return None;
+				}
+				let map = vector_sblades
+					.iter()
+					.map(|sblade| {
+						let rcompl = sblade.rcompl(&gen.grammar);
+						let index = complement_sblades.iter().position(|c| c.blade == rcompl.blade)?;
+						let back = complement_sblades[index].rcompl(&gen.grammar);
+						(back.blade == sblade.blade).then(|| {
+							(
+								index,
+								rcompl.sign * complement_sblades[index].sign,
+								back.sign * sblade.sign,
+							)
+						})
+					})
+					.collect::<Option<Vec<_>>>()?;
+				Some(Self {
+					vector: vector.to_owned(),
+					complement: Some((name.to_owned(), map)),
+				})
+			})
+		}
+
+		/// The matrix of the sandwich of the versor, indexed by the members of the vector struct.
+		fn matrix(&self, gen: &Generator, versor: &str, sandwich: Sandwich) -> Option<Vec<Vec<Polynomial>>> {
+			let (complement, map) = match &self.complement {
+				Some(complement) => complement,
+				None => return sandwich_matrix(gen, versor, &self.vector, sandwich),
+			};
+			let matrix = sandwich_matrix(gen, versor, complement, sandwich)?;
+			Some(
+				map.iter()
+					.map(|&(row, _, back_sign)| {
+						map.iter()
+							.map(|&(column, sign, _)| {
+								matrix[row][column]
+									.iter()
+									.map(|(vars, coefficient)| (vars.clone(), back_sign * coefficient * sign))
+									.collect()
+							})
+							.collect()
+					})
+					.collect(),
+			)
+		}
+
+		/// How the matrix transforms a `v`, e.g. `self.sandwich(v.rcompl()).rcompl()`.
+		fn transform(&self, sandwich: Sandwich) -> String {
+			let function_name = sandwich.trait_function_name();
+			match &self.complement {
+				None => format!("self.{}(v)", function_name),
+				Some(_) => {
+					let rcompl = Unary::RCompl.trait_function_name();
+					format!("self.{}(v.{}()).{}()", function_name, rcompl, rcompl)
+				}
+			}
+		}
+	}
+
+	/// The sandwich that the versor transforms with: the one whose product has its identity
+	/// (the scalar or the anti-scalar) as a member of the versor.
+	/// If the versor has both (like the `Motor3` of `pga3d`), the one that more structs have the identity of.
+	fn versor_sandwich(gen: &Generator, strct: &Struct) -> Option<Sandwich> {
+		let has_identity = |strct: &Struct, sandwich: Sandwich| {
+			let grade = identity_grade(gen, sandwich.product());
+			strct
+				.values()
+				.any(|member| matches!(&member.typ, Type::SBlade(sblade) if sblade.grade() == grade))
+		};
+		let candidates = gen
+			.sandwiches()
+			.filter(|sandwich| has_identity(strct, *sandwich))
+			.collect_vec();
+		// The first of the most common:
+		candidates
+			.into_iter()
+			.rev()
+			.max_by_key(|sandwich| gen.types.structs().filter(|(_, s)| has_identity(s, *sandwich)).count())
+	}
+
+	/// The grade of the identity of the product, e.g. `0` for the scalar of the geometric product.
+	fn identity_grade(gen: &Generator, product: Product) -> usize {
+		match product {
+			Product::Geometric => 0,
+			_ => gen.grammar.num_vecs(),
+		}
+	}
+
+	/// `versor.sandwich(operand)` as a matrix of polynomials in the members of the versor,
+	/// indexed by the stored members of the output and of the operand.
+	/// `None` if the sandwich is omitted.
+	pub fn sandwich_matrix(
+		gen: &Generator,
+		versor: &str,
+		operand: &str,
+		sandwich: Sandwich,
+	) -> Option<Vec<Vec<Polynomial>>> {
+		let operand_struct = gen.types.get_struct(operand);
+		let expr = strct::struct_sandwich_output(
+			gen,
+			&(versor, gen.types.get_struct(versor)),
+			&(operand, operand_struct),
+			sandwich,
+		)?;
+		let si = match expr {
+			Expr::StructInstance(si) => si,
+			_ => return None,
+		};
+
+		let members: Vec<&String> = backend::stored_members(operand_struct).map(|(name, _)| name).collect();
+		let mut matrix = vec![vec![Polynomial::new(); members.len()]; members.len()];
+		for (row, member_name) in members.iter().enumerate() {
+			let sblade = operand_struct[*member_name].typ.clone().into_sblade()?;
+			let (_, expr) = si.members.iter().find(|(name, _)| &name == member_name)?;
+			let terms = match expr {
+				Expr::Sum(terms) => terms.as_slice(),
+				expr => std::slice::from_ref(expr),
+			};
+			for term in terms {
+				let (vars, term_sblade) = backend::coordinates(term, &gen.grammar)?;
+				if term_sblade.is_zero() {
+					continue;
+				}
+				if term_sblade.blade != sblade.blade {
+					return None;
+				}
+				let mut column = None;
+				let mut versor_vars = vec![];
+				for var in vars {
+					if let Some(member) = var.strip_prefix("self.") {
+						versor_vars.push(member.to_owned());
+					} else {
+						let member = var.strip_prefix("rhs.")?;
+						column = Some(members.iter().position(|name| *name == member)?);
+					}
+				}
+				versor_vars.sort();
+				*matrix[row][column?].entry(versor_vars).or_default() += term_sblade.sign * sblade.sign;
+			}
+		}
+		for poly in matrix.iter_mut().flatten() {
+			poly.retain(|_, coefficient| *coefficient != 0);
+		}
+		Some(matrix)
+	}
+
+	/// The squared norm that a normalized versor has, e.g. `s² + xy²`:
+	/// the scalar part of `v * v.reverse()` (or the anti-scalar part of the anti-product, for anti-sandwiches).
+	fn norm_squared(gen: &Generator, strct: &Struct, sandwich: Sandwich) -> Polynomial {
+		let product = sandwich.product();
+		let members: Vec<(&String, SBlade)> = backend::stored_members(strct)
+			.map(|(name, member)| (name, member.typ.clone().into_sblade().unwrap()))
+			.collect();
+		let mut norm = Polynomial::new();
+		for (a, a_sblade) in &members {
+			for (b, b_sblade) in &members {
+				let reversed = match product {
+					Product::Geometric => b_sblade.clone().reverse(),
+					_ => b_sblade.anti_reverse(&gen.grammar),
+				};
+				let terms = SBlade::product_terms(product, &[a_sblade.clone(), reversed], &gen.grammar);
+				for term in terms {
+					if !term.is_zero() && term.grade() == identity_grade(gen, product) {
+						let mut vars = vec![(*a).clone(), (*b).clone()];
+						vars.sort();
+						*norm.entry(vars).or_default() += term.sign;
+					}
+				}
+			}
+		}
+		norm.retain(|_, coefficient| *coefficient != 0);
+		norm
+	}
+
+	/// A sum of terms with integer coefficients, e.g. `2.0 * x * y - z + 1.0`.
+	/// Terms without factors are constants.
+	fn sum_code(gen: &Generator, terms: impl Iterator<Item = (String, i32)>) -> String {
+		let mut code = String::new();
+		for (factors, coefficient) in terms {
+			let magnitude = match coefficient.abs() {
+				c if factors.is_empty() => gen.ro.float_literal(c),
+				1 => factors,
+				c => format!("{} * {}", gen.ro.float_literal(c), factors),
+			};
+			code += &match (code.is_empty(), coefficient < 0) {
+				(true, false) => magnitude,
+				(true, true) => format!("-{}", magnitude),
+				(false, false) => format!(" + {}", magnitude),
+				(false, true) => format!(" - {}", magnitude),
+			};
+		}
+		if code.is_empty() {
+			gen.ro.float_literal(0)
+		} else {
+			code
+		}
+	}
+
+	/// E.g. `2.0 * self.rw.0 * self.ux.0 - 2.0 * self.rx.0 * self.uw.0` for the variable `self`.
+	pub fn polynomial_code(gen: &Generator, poly: &Polynomial, var: &str) -> String {
+		sum_code(
+			gen,
+			poly.iter().map(|(vars, coefficient)| {
+				let factors = vars.iter().map(|member| format!("{}.{}.0", var, member)).join(" * ");
+				(factors, *coefficient)
+			}),
+		)
+	}
+
+	/// `to_matrix`, and with `affine` also without the last row, e.g. `to_matrix_3x4`.
+	fn to_matrix(
+		gen: &Generator,
+		struct_name: &str,
+		operand: &Operand,
+		sandwich: Sandwich,
+		matrix: &[Vec<Polynomial>],
+		affine: bool,
+	) -> String {
+		let n = matrix.len();
+		let members = gen.types.get_struct(&operand.vector).keys().join(", ");
+		let rows = matrix
+			.iter()
+			.map(|row| {
+				format!(
+					"[{}],",
+					row.iter().map(|poly| polynomial_code(gen, poly, "self")).join(", ")
+				)
+			})
+			.join("\n");
+
+		let affine = if affine {
+			format!(
+				r"
+
+    /// The first {m} rows of [`Self::to_matrix`], i.e. without the last row (which is `[0, …, 0, 1]` when normalized).
+    pub fn to_matrix_{m}x{n}(self) -> [[{F}; {n}]; {m}] {{
+        let m = self.to_matrix();
+        [{first_rows}]
+    }}",
+				m = n - 1,
+				n = n,
+				F = gen.float_type(),
+				first_rows = (0..n - 1).map(|row| format!("m[{}]", row)).join(", "),
+			)
+		} else {
+			String::new()
+		};
+
+		format!(
+			r"
+impl{Generics} {Struct} {{
+    /// The matrix that transforms `[{members}]` like `{transform}` for a `{Vector}` `v`,
+    /// as `m[row][column]` acting on column vectors (transpose it for column-major storage).
+    pub fn to_matrix(self) -> [[{F}; {n}]; {n}] {{
+        [
+            {rows}
+        ]
+    }}{affine}
+}}
+",
+			Generics = gen.impl_generics(),
+			Struct = gen.type_ref(struct_name),
+			members = members,
+			transform = operand.transform(sandwich),
+			Vector = operand.vector,
+			F = gen.float_type(),
+			n = n,
+			rows = rows,
+			affine = affine,
+		)
+	}
+
+	/// `identity()`: one for the member of the identity of the product (e.g. the scalar of a rotor), and zero for the others.
+	/// `None` if the versor has no such member.
+	fn identity_versor(gen: &Generator, struct_name: &str, strct: &Struct, sandwich: Sandwich) -> Option<String> {
+		let identity = SBlade::identity(sandwich.product(), &gen.grammar);
+		let members: Vec<(&String, &StructMember)> = backend::stored_members(strct).collect();
+		let is_identity =
+			|member: &StructMember| matches!(&member.typ, Type::SBlade(sblade) if sblade.blade == identity.blade);
+		let (_, identity_member) = members.iter().find(|(_, member)| is_identity(member))?;
+		let sign = match &identity_member.typ {
+			Type::SBlade(sblade) => sblade.sign * identity.sign,
+			_ => unreachable!(),
+		};
+		let fields = members
+			.iter()
+			.map(|(name, member)| {
+				let value = if is_identity(member) { sign } else { 0 };
+				format!("{}: {}({}),", name, member.name, gen.ro.float_literal(value))
+			})
+			.join("\n");
+		Some(format!(
+			r"
+impl{Generics} {Struct} {{
+    /// The versor that moves nothing, whose matrix is the identity matrix.
+    pub fn identity() -> Self {{
+        {name} {{
+            {fields}
+        }}
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Struct = gen.type_ref(struct_name),
+			name = struct_name,
+			fields = fields,
+		))
+	}
+
+	/// `from_matrix` for versors that are determined by the products of pairs of their members, like rotors are.
+	/// The products are solved for exactly.
+	/// When every member has a product with all the others (as in a rotor), the members are found without branches:
+	/// their magnitudes are the square roots of their squares, with the signs of their products with the members before them
+	/// (starting with the member of the identity, e.g. the scalar of a rotor, which is never negative),
+	/// and then each member is the sum of its products with those (which is exact for all of them, even the tiny ones).
+	/// Otherwise there must be one member (the pivot) with a product with all the others (as the scalar of a translator),
+	/// and the members are their products with it, divided by the square root of its square.
+	fn versor_from_matrix(
+		gen: &Generator,
+		struct_name: &str,
+		strct: &Struct,
+		sandwich: Sandwich,
+		matrix: &[Vec<Polynomial>],
+	) -> Option<String> {
+		let members: Vec<(&String, &StructMember)> = backend::stored_members(strct).collect();
+		if members.is_empty() || members.len() < strct.len() {
+			return None;
+		}
+		let pair = |a: &str, b: &str| {
+			let mut pair = vec![a.to_owned(), b.to_owned()];
+			pair.sort();
+			pair
+		};
+
+		// A matrix is the same for any multiple of the versor (up to a factor),
+		// so unless it is in there already, we assume that the versor is normalized:
+		let mut equations: Vec<Polynomial> = matrix.iter().flatten().cloned().collect();
+		let products = solve_products(&equations).or_else(|| {
+			equations.push(norm_squared(gen, strct, sandwich));
+			solve_products(&equations)
+		})?;
+		if products.iter().any(|(product, _)| product.len() != 2) {
+			return None;
+		}
+		let product = |a: &str, b: &str| products.iter().find(|(product, _)| product == &pair(a, b));
+		let pivots = members
+			.iter()
+			.filter(|(pivot, _)| members.iter().all(|(other, _)| product(pivot, other).is_some()))
+			.collect_vec();
+
+		let n = matrix.len();
+		let variable = |pair: &[String]| pair.join("_");
+		let lets = products
+			.iter()
+			.filter(|(product, _)| pivots.iter().any(|(pivot, _)| product.contains(pivot)))
+			.map(|(product, coefficients)| {
+				format!("let {} = {};", variable(product), linear_code(gen, coefficients, n))
+			})
+			.join("\n");
+		let square = |member: &str| variable(&pair(member, member));
+		let construct = |value: &dyn Fn(&str) -> String| {
+			let fields = members
+				.iter()
+				.map(|(name, member)| format!("{}: {}({}),", name, member.name, value(name)))
+				.join("\n");
+			format!("{} {{\n{}\n}}", struct_name, fields)
+		};
+
+		let (doc, body) = if pivots.len() == members.len() {
+			// The sign follows the member of the identity (e.g. the scalar of a rotor),
+			// so that the identity matrix gives the identity versor:
+			let identity = SBlade::identity(sandwich.product(), &gen.grammar);
+			let mut order: Vec<&str> = members.iter().map(|(name, _)| name.as_str()).collect();
+			if let Some(i) = members
+				.iter()
+				.position(|(_, member)| matches!(&member.typ, Type::SBlade(sblade) if sblade.blade == identity.blade))
+			{
+				order[..=i].rotate_right(1);
+			}
+			let magnitudes = order
+				.iter()
+				.enumerate()
+				.map(|(i, name)| {
+					let magnitude = format!("{}.max({}).sqrt()", square(name), gen.ro.float_literal(0));
+					if i == 0 {
+						format!("let {} = {};", name, magnitude)
+					} else {
+						let sign = order[..i]
+							.iter()
+							.map(|other| format!("{} * {}", other, variable(&pair(other, name))))
+							.join(" + ");
+						format!("let {} = {}.copysign({});", name, magnitude, sign)
+					}
+				})
+				.join("\n");
+			let value = |name: &str| {
+				order
+					.iter()
+					.map(|other| format!("{} * {}", other, variable(&pair(other, name))))
+					.join(" + ")
+			};
+			(
+				format!(
+					"/// Both `v` and `-v` have the same matrix, and this returns the one with a non-negative `{}`.",
+					order[0]
+				),
+				format!("{}\n{}", magnitudes, construct(&value)),
+			)
+		} else if pivots.len() == 1 {
+			let pivot = pivots[0].0;
+			let value = |name: &str| {
+				if name == pivot {
+					"pivot".to_owned()
+				} else {
+					format!("{} / pivot", variable(&pair(pivot, name)))
+				}
+			};
+			(
+				format!(
+					"/// Both `v` and `-v` have the same matrix, and this returns the one with a positive `{}`.",
+					pivot
+				),
+				format!("let pivot = {}.sqrt();\n{}", square(pivot), construct(&value)),
+			)
+		} else {
+			return None;
+		};
+
+		Some(format!(
+			r"
+impl{Generics} {Struct} {{
+    /// The normalized versor with this matrix, e.g. from [`Self::to_matrix`].
+    {doc}
+    pub fn from_matrix(m: [[{F}; {n}]; {n}]) -> Self {{
+        {lets}
+        {body}
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Struct = gen.type_ref(struct_name),
+			doc = doc,
+			F = gen.float_type(),
+			n = n,
+			lets = lets,
+			body = body,
+		))
+	}
+
+	/// The products of pairs of members (e.g. `[x, w]` for `x * w`) that the equations are made of,
+	/// as exact linear combinations of the entries of the matrix (flattened row by row).
+	/// The equations are the entries of the matrix, optionally followed by a polynomial that is one.
+	/// This is the least-squares solution `(AᵀA)⁻¹Aᵀ`, where `A` has the coefficients of the products in each equation.
+	/// `None` if the equations do not determine the products.
+	fn solve_products(equations: &[Polynomial]) -> Option<Vec<(Vec<String>, Vec<Fraction>)>> {
+		let products: Vec<&Vec<String>> = equations
+			.iter()
+			.flat_map(|poly| poly.keys())
+			.unique()
+			.sorted()
+			.collect();
+		if products.len() > equations.len() {
+			return None;
+		}
+		let a = |equation: usize, product: usize| {
+			i128::from(equations[equation].get(products[product]).copied().unwrap_or(0))
+		};
+
+		// Gauss-Jordan elimination of `[AᵀA | Aᵀ]`:
+		let k = products.len();
+		let mut rows: Vec<Vec<Fraction>> = (0..k)
+			.map(|i| {
+				(0..k)
+					.map(|j| ((0..equations.len()).map(|e| a(e, i) * a(e, j)).sum(), 1))
+					.chain((0..equations.len()).map(|e| (a(e, i), 1)))
+					.collect()
+			})
+			.collect();
+		for column in 0..k {
+			let pivot = (column..k).find(|&row| rows[row][column].0 != 0)?;
+			rows.swap(column, pivot);
+			let pivot = rows[column][column];
+			rows[column] = rows[column].iter().map(|&x| divide(x, pivot)).collect();
+			for row in 0..k {
+				let factor = rows[row][column];
+				if row != column && factor.0 != 0 {
+					rows[row] = rows[row]
+						.iter()
+						.zip(&rows[column])
+						.map(|(&x, &y)| subtract(x, multiply(factor, y)))
+						.collect();
+				}
+			}
+		}
+
+		Some(
+			products
+				.into_iter()
+				.cloned()
+				.zip(rows.into_iter().map(|row| row[k..].to_vec()))
+				.collect(),
+		)
+	}
+
+	fn fraction(numerator: i128, denominator: i128) -> Fraction {
+		let gcd = gcd(numerator.abs(), denominator.abs());
+		let sign = denominator.signum();
+		(sign * numerator / gcd, sign * denominator / gcd)
+	}
+
+	fn gcd(a: i128, b: i128) -> i128 {
+		if b == 0 {
+			a
+		} else {
+			gcd(b, a % b)
+		}
+	}
+
+	fn multiply(a: Fraction, b: Fraction) -> Fraction {
+		fraction(a.0 * b.0, a.1 * b.1)
+	}
+
+	fn divide(a: Fraction, b: Fraction) -> Fraction {
+		fraction(a.0 * b.1, a.1 * b.0)
+	}
+
+	fn subtract(a: Fraction, b: Fraction) -> Fraction {
+		fraction(a.0 * b.1 - b.0 * a.1, a.1 * b.1)
+	}
+
+	/// A linear combination of the entries of the `n`x`n` matrix `m` (and one, after those),
+	/// e.g. `(m[0][0] + m[1][1] + 1.0) / 4.0`.
+	fn linear_code(gen: &Generator, coefficients: &[Fraction], n: usize) -> String {
+		let denominator = coefficients
+			.iter()
+			.fold(1, |lcm, (_, denominator)| lcm / gcd(lcm, *denominator) * denominator);
+		let terms = coefficients
+			.iter()
+			.enumerate()
+			.filter(|(_, (numerator, _))| *numerator != 0)
+			.map(|(i, (numerator, d))| {
+				let entry = if i < n * n {
+					format!("m[{}][{}]", i / n, i % n)
+				} else {
+					String::new()
+				};
+				(entry, (numerator * (denominator / d)) as i32)
+			})
+			.collect_vec();
+		let sum = sum_code(gen, terms.iter().cloned());
+		match (denominator, terms.len()) {
+			(1, _) => sum,
+			(_, 1) => format!("{} / {}", sum, gen.ro.float_literal(denominator as i32)),
+			_ => format!("({}) / {}", sum, gen.ro.float_literal(denominator as i32)),
+		}
+	}
+
+	/// The rotor, translator and motor of a projective algebra (e.g. `Rotor3`, `Translator3` and `Motor3` in `pga3d`),
+	/// where a translator times a rotor is a motor, and the motor rotates like its members with the blades of the rotor.
+	pub struct Motors {
+		pub sandwich: Sandwich,
+		/// What their matrices transform.
+		pub operand: Operand,
+		pub rotor: &'static str,
+		pub translator: &'static str,
+		pub motor: &'static str,
+		/// For each member of the rotor, the member of the motor with the same blade.
+		rotor_members: Vec<(String, String)>,
+		/// `None` unless the last base vector is the origin, which is moved by the translation.
+		pub translation: Option<Translation>,
+	}
+
+	/// What a translation is, when the last base vector is the origin.
+	pub struct Translation {
+		/// Where a normalized motor moves the origin, as polynomials in the members of the motor.
+		pub polynomials: Vec<Polynomial>,
+		/// How to make a translator from a translation, as `(member, coefficient)`:
+		/// the member is `translation[i] / coefficient`.
+		translator_members: Vec<(String, i32)>,
+		/// The member of the translator that is one.
+		translator_one: String,
+	}
+
+	/// The names of the rotor, translator and motor in `pga2d` and `pga3d`.
+	const MOTOR_NAMES: [[&str; 3]; 2] = [["Rotor", "Translator", "Motor"], ["Rotor3", "Translator3", "Motor3"]];
+
+	/// `None` unless there are structs with the names of those in `pga2d` or `pga3d` that work like them,
+	/// with `from_matrix`, and all operations are generated.
+	pub fn motors(gen: &Generator) -> Option<Motors> {
+		if !gen.settings.selection.is_all() {
+			return None;
+		}
+		let vector = vector_struct(gen)?;
+		let operands = [Some(Operand::identity(vector)), Operand::complement(gen, vector)];
+
+		MOTOR_NAMES.iter().find_map(|&names| {
+			let candidates = operands
+				.iter()
+				.flatten()
+				.filter_map(|operand| motors_with_operand(gen, names, operand))
+				.collect_vec();
+			// Prefer the operand that the motors translate, e.g. the points of `pga2d` rather than its lines:
+			let best = candidates
+				.iter()
+				.position(|motors| motors.translation.is_some())
+				.unwrap_or(0);
+			candidates.into_iter().nth(best)
+		})
+	}
+
+	fn motors_with_operand(gen: &Generator, names: [&'static str; 3], operand: &Operand) -> Option<Motors> {
+		let [rotor, translator, motor] = names;
+		if !names
+			.iter()
+			.all(|name| matches!(gen.types.try_get(name), Some(Type::Struct(_))))
+		{
+			return None;
+		}
+		let rotor_struct = gen.types.get_struct(rotor);
+		let translator_struct = gen.types.get_struct(translator);
+		let motor_struct = gen.types.get_struct(motor);
+
+		let sandwich = versor_sandwich(gen, rotor_struct)?;
+		if versor_sandwich(gen, translator_struct) != Some(sandwich)
+			|| versor_sandwich(gen, motor_struct) != Some(sandwich)
+		{
+			return None;
+		}
+		let rotor_matrix = operand.matrix(gen, rotor, sandwich)?;
+		let translator_matrix = operand.matrix(gen, translator, sandwich)?;
+		let motor_matrix = operand.matrix(gen, motor, sandwich)?;
+		versor_from_matrix(gen, rotor, rotor_struct, sandwich, &rotor_matrix)?;
+		versor_from_matrix(gen, translator, translator_struct, sandwich, &translator_matrix)?;
+
+		// The translator times the rotor must be a motor:
+		let product = strct::struct_product_output(
+			gen,
+			&(translator, translator_struct),
+			&(rotor, rotor_struct),
+			sandwich.product(),
+		)?;
+		if product.0 != motor {
+			return None;
+		}
+
+		// The motor rotates like a rotor of its members with the same blades,
+		// so that `from_matrix` of the rotor gets the rotation of a motor:
+		let rotor_members: Vec<(String, String)> = rotor_struct
+			.iter()
+			.map(|(name, member)| {
+				let (motor_member, _) = motor_struct.iter().find(|(_, m)| m.name == member.name)?;
+				Some((name.clone(), motor_member.clone()))
+			})
+			.collect::<Option<_>>()?;
+		for (rotor_row, motor_row) in rotor_matrix.iter().zip(&motor_matrix) {
+			for (rotor_poly, motor_poly) in rotor_row.iter().zip(motor_row) {
+				let renamed: Polynomial = rotor_poly
+					.iter()
+					.map(|(vars, coefficient)| {
+						let mut vars = vars
+							.iter()
+							.map(|var| rotor_members.iter().find(|(r, _)| r == var).unwrap().1.clone())
+							.collect_vec();
+						vars.sort();
+						(vars, *coefficient)
+					})
+					.collect();
+				if !rotor_poly.is_empty() && &renamed != motor_poly {
+					return None;
+				}
+			}
+		}
+
+		Some(Motors {
+			sandwich,
+			operand: operand.clone(),
+			rotor,
+			translator,
+			motor,
+			rotor_members,
+			translation: translation(
+				gen,
+				&rotor_matrix,
+				&translator_matrix,
+				&motor_matrix,
+				sandwich,
+				translator,
+			),
+		})
+	}
+
+	/// `None` unless the last base vector is the origin,
+	/// which the rotor leaves where it is, and which the translator moves along each axis.
+	fn translation(
+		gen: &Generator,
+		rotor_matrix: &[Vec<Polynomial>],
+		translator_matrix: &[Vec<Polynomial>],
+		motor_matrix: &[Vec<Polynomial>],
+		sandwich: Sandwich,
+		translator: &str,
+	) -> Option<Translation> {
+		let n = rotor_matrix.len() - 1;
+		if (0..n).any(|i| !rotor_matrix[i][n].is_empty() || !motor_matrix[n][i].is_empty()) {
+			return None;
+		}
+
+		// A translator is one plus a member for each axis, e.g. `t.x / 2`:
+		let one_grade = identity_grade(gen, sandwich.product());
+		let translator_struct = gen.types.get_struct(translator);
+		let translator_one = translator_struct
+			.iter()
+			.find(|(_, member)| matches!(&member.typ, Type::SBlade(sblade) if sblade.grade() == one_grade))?
+			.0
+			.clone();
+		let translator_members: Vec<(String, i32)> = (0..n)
+			.map(|row| {
+				let poly = &translator_matrix[row][n];
+				let (vars, coefficient) = poly.iter().next()?;
+				let member = vars.iter().find(|var| *var != &translator_one)?;
+				let mut expected = vec![translator_one.clone(), member.clone()];
+				expected.sort();
+				(poly.len() == 1 && vars == &expected).then(|| (member.clone(), *coefficient))
+			})
+			.collect::<Option<_>>()?;
+		if translator_struct.len() != n + 1 || translator_members.iter().map(|(member, _)| member).unique().count() != n
+		{
+			return None;
+		}
+
+		Some(Translation {
+			polynomials: (0..n).map(|row| motor_matrix[row][n].clone()).collect(),
+			translator_members,
+			translator_one,
+		})
+	}
+
+	/// `from_rotor_and_translation`, `rotor` and `translation` of the motor, when there is a [`Translation`].
+	fn motor_helpers(gen: &Generator, motors: &Motors) -> String {
+		let translation = motors.translation.as_ref().unwrap();
+		let n = translation.polynomials.len();
+
+		let translator = gen
+			.types
+			.get_struct(motors.translator)
+			.iter()
+			.map(|(member_name, member)| {
+				let value = if member_name == &translation.translator_one {
+					gen.ro.float_literal(1)
+				} else {
+					let (axis, (_, coefficient)) = translation
+						.translator_members
+						.iter()
+						.find_position(|(name, _)| name == member_name)
+						.unwrap();
+					let minus = if *coefficient < 0 { "-" } else { "" };
+					format!(
+						"{}translation[{}] / {}",
+						minus,
+						axis,
+						gen.ro.float_literal(coefficient.abs())
+					)
+				};
+				format!("{}: {}({}),", member_name, member.name, value)
+			})
+			.join("\n");
+		let rotor = motors
+			.rotor_members
+			.iter()
+			.map(|(rotor_member, motor_member)| {
+				let blade = &gen.types.get_struct(motors.rotor)[rotor_member].name;
+				format!("{}: {}(self.{}.0),", rotor_member, blade, motor_member)
+			})
+			.join("\n");
+		let translation = translation
+			.polynomials
+			.iter()
+			.map(|poly| format!("{},", polynomial_code(gen, poly, "self")))
+			.join("\n");
+
+		format!(
+			r"
+impl{Generics} {Motor} {{
+    /// Rotate by `rotor` and then translate by `translation`.
+    pub fn from_rotor_and_translation(rotor: {Rotor}, translation: [{F}; {n}]) -> Self {{
+        let translator = {Translator} {{
+            {translator}
+        }};
+        translator.{product}(rotor)
+    }}
+
+    /// The rotation of a normalized motor, i.e. `self` without the translation.
+    pub fn rotor(self) -> {Rotor} {{
+        {RotorName} {{
+            {rotor}
+        }}
+    }}
+
+    /// Where a normalized motor moves the origin.
+    pub fn translation(self) -> [{F}; {n}] {{
+        [
+            {translation}
+        ]
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Motor = gen.type_ref(motors.motor),
+			Rotor = gen.type_ref(motors.rotor),
+			RotorName = motors.rotor,
+			Translator = motors.translator,
+			F = gen.float_type(),
+			n = n,
+			translator = translator,
+			product = motors.sandwich.product().trait_function_name(),
+			rotor = rotor,
+			translation = translation,
+		)
+	}
+
+	/// The motor of a rigid transform: the rotor of the rotation, times the translator of what is left.
+	fn motor_from_matrix(gen: &Generator, motors: &Motors, n: usize) -> String {
+		format!(
+			r"
+impl{Generics} {Motor} {{
+    /// The motor of a rigid transform, e.g. from [`Self::to_matrix`]:
+    /// the rotation of [`{Rotor}::from_matrix`], then the translation of [`{Translator}::from_matrix`].
+    pub fn from_matrix(m: [[{F}; {n}]; {n}]) -> Self {{
+        let rotor = {Rotor}::from_matrix(m);
+        // Undo the rotation, whose inverse is its transpose:
+        let r = rotor.to_matrix();
+        let t = m.map(|row| r.map(|r_row| row.iter().zip(r_row).fold({zero}, |sum, (a, b)| sum + *a * b)));
+        {Translator}::from_matrix(t).{product}(rotor)
+    }}
+}}
+",
+			Generics = gen.impl_generics(),
+			Motor = gen.type_ref(motors.motor),
+			Rotor = motors.rotor,
+			Translator = motors.translator,
+			F = gen.float_type(),
+			n = n,
+			zero = gen.ro.float_literal(0),
+			product = motors.sandwich.product().trait_function_name(),
+		)
+	}
+}
+
+/// Conversions to and from the types of `glam`, `nalgebra` and `cgmath`,
+/// behind cargo features of the same names (see [`Settings::cargo_features`]),
+/// for a concrete `f32` or `f64` float type.
+///
+/// The vectors and points are converted like for `mint` (see [`features::vector_kind`]).
+/// A `Vec3` also converts to and from `nalgebra::Point3`, and so does a `Vec4` whose `w` squares to zero
+/// (as in [`grammars::pga3d`]), by dividing by `w` and with `w = 1`.
+/// With the structs of [`grammars::pga3d`], `Rotor3` also converts to and from quaternions,
+/// and `Motor3` to and from rigid transforms (e.g. `nalgebra::Isometry3` or a 4x4 matrix).
+/// Which member of the rotor is which component of the quaternion (and with what sign) is not written down anywhere,
+/// but derived by comparing the sandwich of a `Vec3` with the rotation matrix of a quaternion,
+/// so it follows the struct definitions. Likewise for the rotor and translation of a `Motor3` (see [`matrix`]).
+pub mod interop {
+	use matrix::{Motors, Polynomial};
+
+	use super::*;
+
+	/// `(sign, member)` for each of the `[x, y, z, w]` of a quaternion, e.g. `(-1, "x")` for `-self.x`.
+	type Quaternion = [(i32, String); 4];
+
+	pub fn impls(gen: &Generator, struct_name: &str, strct: &Struct) -> Option<String> {
+		if !gen.settings.cargo_features || gen.settings.generic {
+			return None;
+		}
+		let f64 = match gen.settings.float_type.as_str() {
+			"f32" => false,
+			"f64" => true,
+			_ => return None,
+		};
+
+		let mut conversions = vec![];
+		if let Some((kind, dimension)) = features::vector_kind(gen, strct) {
+			conversions.extend(vector_conversions(gen, struct_name, strct, kind, dimension, f64));
+		}
+		match pga3d(gen) {
+			Some(pga3d) if struct_name == pga3d.motors.rotor => conversions.extend(rotor_conversions(gen, &pga3d, f64)),
+			Some(pga3d) if struct_name == pga3d.motors.motor => conversions.extend(motor_conversions(gen, &pga3d, f64)),
+			_ => {}
+		}
+
+		if conversions.is_empty() {
+			None
+		} else {
+			Some(conversions.join("\n"))
+		}
+	}
+
+	/// The `Rotor3`, `Translator3` and `Motor3` of [`grammars::pga3d`] (see [`matrix::motors`]),
+	/// and the quaternion of a `Rotor3`.
+	struct Pga3d {
+		motors: Motors,
+		rotor: Quaternion,
+	}
+
+	/// `None` unless there are structs with the names of those in `pga3d` that work like them,
+	/// and all operations are generated.
+	fn pga3d(gen: &Generator) -> Option<Pga3d> {
+		let motors = matrix::motors(gen)?;
+		if motors.rotor != "Rotor3"
+			|| motors.translation.is_none()
+			|| !matches!(gen.types.try_get("Vec3"), Some(Type::Struct(_)))
+			|| features::vector_kind(gen, gen.types.get_struct("Vec3")) != Some(("Vector", 3))
+		{
+			return None;
+		}
+		let rotor = quaternion(gen, motors.rotor, motors.sandwich)?;
+		Some(Pga3d { motors, rotor })
+	}
+
+	/// The rotation matrix of the quaternion `[x, y, z, w]` (indices into `q`),
+	/// without assuming that it is normalized, e.g. `w² + x² - y² - z²` in the top left corner.
+	fn quaternion_matrix(q: &[(i32, &str); 4]) -> Vec<Vec<Polynomial>> {
+		let mut matrix = vec![vec![Polynomial::new(); 3]; 3];
+		let mut add = |row: usize, column: usize, coefficient: i32, a: usize, b: usize| {
+			let mut vars = vec![q[a].1.to_owned(), q[b].1.to_owned()];
+			vars.sort();
+			*matrix[row][column].entry(vars).or_default() += coefficient * q[a].0 * q[b].0;
+		};
+		for i in 0..3 {
+			for j in 0..3 {
+				if i == j {
+					add(i, i, 1, 3, 3);
+					for k in 0..3 {
+						add(i, i, if k == i { 1 } else { -1 }, k, k);
+					}
+				} else {
+					// E.g. `2 x y - 2 w z` for the first row and second column:
+					let k = 3 - i - j;
+					add(i, j, 2, i, j);
+					add(i, j, if (i + 3 - j) % 3 == 1 { 2 } else { -2 }, 3, k);
+				}
+			}
+		}
+		for poly in matrix.iter_mut().flatten() {
+			poly.retain(|_, coefficient| *coefficient != 0);
+		}
+		matrix
+	}
+
+	/// The quaternion that rotates a `Vec3` like `versor.sandwich(vec3)`, with a positive `w`.
+	fn quaternion(gen: &Generator, versor: &str, sandwich: Sandwich) -> Option<Quaternion> {
+		let matrix = matrix::sandwich_matrix(gen, versor, "Vec3", sandwich)?;
+		let members: Vec<&String> = matrix
+			.iter()
+			.flatten()
+			.flat_map(|poly| poly.keys().flatten())
+			.unique()
+			.collect();
+		if members.len() != 4 {
+			return None;
+		}
+		for permutation in members.iter().permutations(4) {
+			for signs in 0..8 {
+				let sign = |i: usize| if signs & (1 << i) == 0 { 1 } else { -1 };
+				let q = [
+					(sign(0), permutation[0].as_str()),
+					(sign(1), permutation[1].as_str()),
+					(sign(2), permutation[2].as_str()),
+					(1, permutation[3].as_str()),
+				];
+				if quaternion_matrix(&q) == matrix {
+					return Some(q.map(|(sign, member)| (sign, member.to_owned())));
+				}
+			}
+		}
+		None
+	}
+
+	/// E.g. `-self.x.0`, for each of `[x, y, z, w]` of the quaternion.
+	fn quaternion_components(q: &Quaternion, var: &str) -> Vec<String> {
+		q.iter()
+			.map(|(sign, member)| format!("{}{}.{}.0", if *sign < 0 { "-" } else { "" }, var, member))
+			.collect()
+	}
+
+	/// The members of a rotor from the components of a quaternion (e.g. `q.x`), in the order of the struct.
+	fn rotor_from_quaternion(gen: &Generator, q: &Quaternion, components: [&str; 4]) -> String {
+		let members = gen
+			.types
+			.get_struct("Rotor3")
+			.iter()
+			.map(|(member_name, member)| {
+				let (index, (sign, _)) = q.iter().find_position(|(_, name)| name == member_name).unwrap();
+				let minus = if *sign < 0 { "-" } else { "" };
+				format!("{}: {}({}{}),", member_name, member.name, minus, components[index])
+			})
+			.join("\n");
+		format!("Rotor3 {{\n{}\n}}", members)
+	}
+
+	/// A description of the quaternion for the documentation, e.g. `[x, y, z, w] = [-rotor.x, …]`.
+	fn quaternion_doc(q: &Quaternion) -> String {
+		format!(
+			"`[x, y, z, w] = [{}]`",
+			q.iter()
+				.map(|(sign, member)| format!("{}rotor.{}", if *sign < 0 { "-" } else { "" }, member))
+				.join(", ")
+		)
+	}
+
+	/// E.g. `From<Vec3> for glam::DVec3` and back.
+	fn vector_conversions(
+		gen: &Generator,
+		struct_name: &str,
+		strct: &Struct,
+		kind: &str,
+		dimension: usize,
+		f64: bool,
+	) -> Vec<String> {
+		let glam_prefix = if f64 { "D" } else { "" };
+		let mut glam = vec![format!("glam::{}Vec{}", glam_prefix, dimension)];
+		if dimension == 3 && !f64 {
+			glam.push("glam::Vec3A".to_owned());
+		}
+		let others = [
+			(
+				"nalgebra",
+				format!("nalgebra::{}{}<{}>", kind, dimension, gen.float_type()),
+			),
+			("cgmath", format!("cgmath::{}{}<{}>", kind, dimension, gen.float_type())),
+		];
+		// A vector is also the position of a point:
+		let nalgebra_point = (kind == "Vector" && dimension == 3)
+			.then(|| ("nalgebra", format!("nalgebra::Point3<{}>", gen.float_type())));
+
+		let members: Vec<&String> = backend::stored_members(strct).map(|(name, _)| name).collect();
+		let into = members.iter().map(|member| format!("v.{}.0", member)).join(", ");
+		let from = members
+			.iter()
+			.map(|member| format!("{}: {}(v.{}),", member, strct[*member].name, member))
+			.join("\n");
+
+		let mut conversions: Vec<String> = glam
+			.into_iter()
+			.map(|typ| ("glam", typ))
+			.chain(others)
+			.chain(nalgebra_point)
+			.map(|(feature, typ)| {
+				format!(
+					r#"
+#[cfg(feature = "{feature}")]
+impl From<{Struct}> for {Type} {{
+    fn from(v: {Struct}) -> Self {{
+        Self::new({into})
+    }}
+}}
+
+#[cfg(feature = "{feature}")]
+impl From<{Type}> for {Struct} {{
+    fn from(v: {Type}) -> Self {{
+        {Struct} {{
+            {from}
+        }}
+    }}
+}}
+"#,
+					feature = feature,
+					Struct = struct_name,
+					Type = typ,
+					into = into,
+					from = from,
+				)
+			})
+			.collect();
+		if kind == "Vector" && dimension == 4 && is_homogeneous(gen, strct) {
+			conversions.push(homogeneous_point_conversions(gen, struct_name, strct));
+		}
+		conversions
+	}
+
+	/// Whether the `w` of the vector is a base vector that squares to zero, like the origin of `pga3d`,
+	/// so that `[x, y, z, w]` are the homogeneous coordinates of a point.
+	fn is_homogeneous(gen: &Generator, strct: &Struct) -> bool {
+		match gen.types.get(&strct["w"].name) {
+			Type::SBlade(sblade) => sblade.blade.vecs().iter().all(|&v| gen.grammar.square_geom(v) == 0),
+			_ => false,
+		}
+	}
+
+	/// E.g. `Vec4` to `nalgebra::Point3` by dividing by `w`, and back with `w = 1`.
+	fn homogeneous_point_conversions(gen: &Generator, struct_name: &str, strct: &Struct) -> String {
+		let into = ["x", "y", "z"]
+			.iter()
+			.map(|member| format!("v.{}.0 / v.w.0", member))
+			.join(", ");
+		let from = ["x", "y", "z"]
+			.iter()
+			.map(|member| format!("{}: {}(v.{}),", member, strct[*member].name, member))
+			.join("\n");
+		format!(
+			r#"
+#[cfg(feature = "nalgebra")]
+impl From<{Struct}> for nalgebra::Point3<{F}> {{
+    /// The point at `[x / w, y / w, z / w]`.
+    fn from(v: {Struct}) -> Self {{
+        Self::new({into})
+    }}
+}}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Point3<{F}>> for {Struct} {{
+    /// With `w = 1`.
+    fn from(v: nalgebra::Point3<{F}>) -> Self {{
+        {Struct} {{
+            {from}
+            w: {W}({one}),
+        }}
+    }}
+}}
+"#,
+			Struct = struct_name,
+			F = gen.float_type(),
+			into = into,
+			from = from,
+			W = strct["w"].name,
+			one = gen.ro.float_literal(1),
+		)
+	}
+
+	/// `Rotor3` to and from `glam::Quat`, `nalgebra::UnitQuaternion` and `cgmath::Quaternion`.
+	fn rotor_conversions(gen: &Generator, pga3d: &Pga3d, f64: bool) -> Vec<String> {
+		let float = gen.float_type();
+		let glam = if f64 { "glam::DQuat" } else { "glam::Quat" };
+		let nalgebra = format!("nalgebra::UnitQuaternion<{}>", float);
+		let cgmath = format!("cgmath::Quaternion<{}>", float);
+		let libraries = [
+			(
+				"glam",
+				glam.to_owned(),
+				"{}::from_xyzw({x}, {y}, {z}, {w})",
+				["q.x", "q.y", "q.z", "q.w"],
+			),
+			(
+				"nalgebra",
+				nalgebra,
+				"nalgebra::UnitQuaternion::new_normalize(nalgebra::Quaternion::new({w}, {x}, {y}, {z}))",
+				["q.i", "q.j", "q.k", "q.w"],
+			),
+			(
+				"cgmath",
+				cgmath,
+				"cgmath::Quaternion::new({w}, {x}, {y}, {z})",
+				["q.v.x", "q.v.y", "q.v.z", "q.s"],
+			),
+		];
+
+		let components = quaternion_components(&pga3d.rotor, "r");
+		libraries
+			.iter()
+			.map(|(feature, typ, constructor, accessors)| {
+				let construct = constructor
+					.replace("{}", glam)
+					.replace("{x}", &components[0])
+					.replace("{y}", &components[1])
+					.replace("{z}", &components[2])
+					.replace("{w}", &components[3]);
+				format!(
+					r#"
+/// The quaternion {doc}, which rotates vectors like `rotor.{sandwich}(v)`.
+/// Like the rotor, it should be normalized.
+#[cfg(feature = "{feature}")]
+impl From<Rotor3> for {Type} {{
+    fn from(r: Rotor3) -> Self {{
+        {construct}
+    }}
+}}
+
+/// The rotor with {doc} of the quaternion.
+#[cfg(feature = "{feature}")]
+impl From<{Type}> for Rotor3 {{
+    fn from(q: {Type}) -> Self {{
+        {rotor}
+    }}
+}}
+"#,
+					doc = quaternion_doc(&pga3d.rotor),
+					sandwich = pga3d.motors.sandwich.trait_function_name(),
+					feature = feature,
+					Type = typ,
+					construct = construct,
+					rotor = rotor_from_quaternion(gen, &pga3d.rotor, *accessors),
+				)
+			})
+			.collect()
+	}
+
+	/// `Motor3` to and from rigid transforms: 4x4 matrices, and `nalgebra::Isometry3`.
+	/// These go through `Motor3::rotor` and `Motor3::translation`, which are derived.
+	fn motor_conversions(gen: &Generator, pga3d: &Pga3d, f64: bool) -> Vec<String> {
+		let (glam_mat4, glam_quat, glam_vec3) = if f64 {
+			("glam::DMat4", "glam::DQuat", "glam::DVec3")
+		} else {
+			("glam::Mat4", "glam::Quat", "glam::Vec3")
+		};
+		vec![format!(
+			r#"
+/// The matrix of a normalized motor, which transforms `[x, y, z, 1]` like `motor.{sandwich}(point)`.
+#[cfg(feature = "glam")]
+impl From<Motor3> for {glam_mat4} {{
+    fn from(m: Motor3) -> Self {{
+        {glam_mat4}::from_rotation_translation({glam_quat}::from(m.rotor()), {glam_vec3}::from(m.translation()))
+    }}
+}}
+
+/// The motor of a rigid transform. Any scaling is ignored.
+#[cfg(feature = "glam")]
+impl From<{glam_mat4}> for Motor3 {{
+    fn from(m: {glam_mat4}) -> Self {{
+        let (_scale, rotation, translation) = m.to_scale_rotation_translation();
+        Motor3::from_rotor_and_translation(Rotor3::from(rotation), translation.to_array())
+    }}
+}}
+
+/// The rotation and translation of a normalized motor.
+#[cfg(feature = "nalgebra")]
+impl From<Motor3> for nalgebra::Isometry3<{F}> {{
+    fn from(m: Motor3) -> Self {{
+        nalgebra::Isometry3::from_parts(nalgebra::Translation3::from(m.translation()), m.rotor().into())
+    }}
+}}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Isometry3<{F}>> for Motor3 {{
+    fn from(m: nalgebra::Isometry3<{F}>) -> Self {{
+        Motor3::from_rotor_and_translation(Rotor3::from(m.rotation), m.translation.vector.into())
+    }}
+}}
+
+/// The matrix of a normalized motor, which transforms `[x, y, z, 1]` like `motor.{sandwich}(point)`.
+#[cfg(feature = "nalgebra")]
+impl From<Motor3> for nalgebra::Matrix4<{F}> {{
+    fn from(m: Motor3) -> Self {{
+        nalgebra::Isometry3::from(m).to_homogeneous()
+    }}
+}}
+
+/// The motor of a rigid transform, i.e. a rotation and a translation.
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Matrix4<{F}>> for Motor3 {{
+    fn from(m: nalgebra::Matrix4<{F}>) -> Self {{
+        let rotation = nalgebra::Rotation3::from_matrix_unchecked(m.fixed_view::<3, 3>(0, 0).into_owned());
+        let rotor = Rotor3::from(nalgebra::UnitQuaternion::from_rotation_matrix(&rotation));
+        Motor3::from_rotor_and_translation(rotor, [m[(0, 3)], m[(1, 3)], m[(2, 3)]])
+    }}
+}}
+
+/// The matrix of a normalized motor, which transforms `[x, y, z, 1]` like `motor.{sandwich}(point)`.
+#[cfg(feature = "cgmath")]
+impl From<Motor3> for cgmath::Matrix4<{F}> {{
+    fn from(m: Motor3) -> Self {{
+        cgmath::Matrix4::from(cgmath::Decomposed {{
+            scale: {one},
+            rot: cgmath::Quaternion::from(m.rotor()),
+            disp: cgmath::Vector3::from(m.translation()),
+        }})
+    }}
+}}
+
+/// The motor of a rigid transform, i.e. a rotation and a translation.
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Matrix4<{F}>> for Motor3 {{
+    fn from(m: cgmath::Matrix4<{F}>) -> Self {{
+        let rotation = cgmath::Matrix3::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate());
+        let rotor = Rotor3::from(cgmath::Quaternion::from(rotation));
+        Motor3::from_rotor_and_translation(rotor, [m.w.x, m.w.y, m.w.z])
+    }}
+}}
+"#,
+			F = gen.float_type(),
+			one = gen.ro.float_literal(1),
+			sandwich = pga3d.motors.sandwich.trait_function_name(),
+			glam_mat4 = glam_mat4,
+			glam_quat = glam_quat,
+			glam_vec3 = glam_vec3,
+		)]
+	}
+}
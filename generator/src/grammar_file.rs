@@ -0,0 +1,567 @@
+//! Loading an algebra (a `Grammar` and its `Types`) from a TOML file.
+//!
+//! See `generator/grammars/pga3d.toml` for an example.
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+use toml::Spanned;
+
+use crate::{gen::Selection, *};
+
+/// Something went wrong when parsing a grammar file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+	/// 1-based line number, if known.
+	pub line: Option<usize>,
+	pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self.line {
+			Some(line) => write!(f, "line {}: {}", line, self.message),
+			None => self.message.fmt(f),
+		}
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GrammarFile {
+	vectors: Vec<VectorDef>,
+	#[serde(default)]
+	dots: Vec<DotDef>,
+	/// If empty, all blades are named after their base vectors, see [`Types::insert_all_blades`].
+	#[serde(default)]
+	blades: Vec<BladeDef>,
+	#[serde(default)]
+	structs: Vec<StructDef>,
+	#[serde(default)]
+	generate: GenerateDef,
+}
+
+/// A base vector and what it squares to
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VectorDef {
+	name: Spanned<String>,
+	square: Spanned<i32>,
+}
+
+/// The dot product of two different base vectors, for a non-diagonal metric.
+/// Base vectors are orthogonal (zero dot product) unless given here.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DotDef {
+	vecs: Spanned<Vec<Spanned<String>>>,
+	dot: i32,
+}
+
+/// A blade and the (ordered) base vectors it is the product of.
+/// The order determines the sign, e.g. `WX = -XW`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BladeDef {
+	name: Spanned<String>,
+	vecs: Vec<Spanned<String>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StructDef {
+	name: Spanned<String>,
+	members: Vec<MemberDef>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MemberDef {
+	name: Spanned<String>,
+	blade: Spanned<String>,
+	/// Always one, e.g. `w` of a normalized point.
+	#[serde(default)]
+	constant: bool,
+}
+
+/// A file with only a `[generate]` table, for the built-in grammars.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SelectionFile {
+	generate: GenerateDef,
+}
+
+/// Which operations to generate, by name (e.g. `"wedge"`). Everything is generated by default.
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GenerateDef {
+	products: Option<Vec<Spanned<String>>>,
+	unaries: Option<Vec<Spanned<String>>>,
+	sandwiches: Option<Vec<Spanned<String>>>,
+	/// E.g. `[["Motor3", "Point3"]]`.
+	struct_pairs: Option<Vec<Spanned<(String, String)>>>,
+}
+
+/// Parse a grammar file, e.g. `generator/grammars/pga3d.toml`.
+/// The `[generate]` table is ignored, see [`parse_with_selection`].
+pub fn parse(source: &str) -> Result<(Grammar, Types), ParseError> {
+	let (grammar, types, _) = parse_with_selection(source)?;
+	Ok((grammar, types))
+}
+
+/// Parse a grammar file, including which operations to generate (its `[generate]` table).
+pub fn parse_with_selection(source: &str) -> Result<(Grammar, Types, Selection), ParseError> {
+	let file: GrammarFile = from_toml(source)?;
+
+	let error = |spanned_start: usize, message: String| ParseError {
+		line: Some(line_number(source, spanned_start)),
+		message,
+	};
+
+	let mut vec_names: BTreeMap<&str, VecIdx> = Default::default();
+	let mut squares = vec![];
+	for (i, vector) in file.vectors.iter().enumerate() {
+		let name = vector.name.get_ref().as_str();
+		if vec_names.insert(name, VecIdx(i)).is_some() {
+			return Err(error(vector.name.start(), format!("Duplicate base vector '{}'", name)));
+		}
+		let square = *vector.square.get_ref();
+		if !(-1..=1).contains(&square) {
+			return Err(error(
+				vector.square.start(),
+				format!("Base vector '{}' must square to -1, 0 or 1, not {}", name, square),
+			));
+		}
+		squares.push(square);
+	}
+	let mut grammar = Grammar::new(squares);
+
+	let mut dot_pairs = vec![];
+	for dot in &file.dots {
+		let vecs = dot.vecs.get_ref();
+		if vecs.len() != 2 {
+			return Err(error(
+				dot.vecs.start(),
+				format!("A dot product needs two base vectors, got {}", vecs.len()),
+			));
+		}
+		let mut pair = vec![];
+		for vec_name in vecs {
+			pair.push(*vec_names.get(vec_name.get_ref().as_str()).ok_or_else(|| {
+				error(
+					vec_name.start(),
+					format!("Unknown base vector '{}' in dot product", vec_name.get_ref()),
+				)
+			})?);
+		}
+		let (a, b) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+		if a == b {
+			return Err(error(
+				dot.vecs.start(),
+				format!("The dot product of '{}' with itself is its square", vecs[0].get_ref()),
+			));
+		}
+		if dot_pairs.contains(&(a, b)) {
+			return Err(error(
+				dot.vecs.start(),
+				format!(
+					"Duplicate dot product of '{}' and '{}'",
+					vecs[0].get_ref(),
+					vecs[1].get_ref()
+				),
+			));
+		}
+		dot_pairs.push((a, b));
+		grammar = grammar.with_dot(a, b, dot.dot);
+	}
+
+	let mut types = Types::default();
+	if file.blades.is_empty() {
+		let vec_names: Vec<&str> = file.vectors.iter().map(|v| v.name.get_ref().as_str()).collect();
+		for blade in grammar.blades() {
+			let name = blade_name(&vec_names, &blade);
+			if types.try_get(&name).is_some() {
+				return Err(ParseError {
+					line: None,
+					message: format!(
+						"The blade name '{}' is ambiguous. Rename the base vectors, or list the blades.",
+						name
+					),
+				});
+			}
+			types.insert_blade(&name, SBlade::unit(blade));
+		}
+	}
+	for blade in &file.blades {
+		let name = blade.name.get_ref().as_str();
+		if types.try_get(name).is_some() {
+			return Err(error(blade.name.start(), format!("Duplicate blade name '{}'", name)));
+		}
+
+		let mut vecs = vec![];
+		for vec_name in &blade.vecs {
+			let vi = *vec_names.get(vec_name.get_ref().as_str()).ok_or_else(|| {
+				error(
+					vec_name.start(),
+					format!("Unknown base vector '{}' in blade '{}'", vec_name.get_ref(), name),
+				)
+			})?;
+			if vecs.contains(&vi) {
+				return Err(error(
+					vec_name.start(),
+					format!("Base vector '{}' repeated in blade '{}'", vec_name.get_ref(), name),
+				));
+			}
+			vecs.push(vi);
+		}
+
+		let sblade = SBlade::from_unsorted(&vecs);
+		if let Some((_, existing_name)) = types.get_blade(&sblade.blade) {
+			return Err(error(
+				blade.name.start(),
+				format!("Blade '{}' is the same blade as '{}'", name, existing_name),
+			));
+		}
+		types.insert_blade(name, sblade);
+	}
+
+	// Every product of two blades must have a name:
+	let num_blades = 1 << grammar.num_vecs();
+	if types.sblades().len() != num_blades {
+		let missing = grammar
+			.blades()
+			.into_iter()
+			.find(|blade| types.get_blade(blade).is_none())
+			.unwrap();
+		let vec_names_by_idx: BTreeMap<VecIdx, &str> = vec_names.iter().map(|(&name, &vi)| (vi, name)).collect();
+		return Err(ParseError {
+			line: None,
+			message: format!(
+				"Missing a blade for the base vectors [{}]. All {} blades must be named.",
+				missing
+					.vecs()
+					.iter()
+					.map(|vi| vec_names_by_idx[vi])
+					.collect::<Vec<_>>()
+					.join(", "),
+				num_blades
+			),
+		});
+	}
+
+	for strct in &file.structs {
+		let struct_name = strct.name.get_ref().as_str();
+		if types.try_get(struct_name).is_some() {
+			return Err(error(
+				strct.name.start(),
+				format!("Duplicate type name '{}'", struct_name),
+			));
+		}
+
+		let mut members: Vec<(&str, &str)> = vec![];
+		let mut constants: Vec<(&str, &str)> = vec![];
+		for member in &strct.members {
+			let member_name = member.name.get_ref().as_str();
+			let blade_name = member.blade.get_ref().as_str();
+			if members.iter().chain(&constants).any(|(name, _)| *name == member_name) {
+				return Err(error(
+					member.name.start(),
+					format!("Duplicate member '{}' in struct '{}'", member_name, struct_name),
+				));
+			}
+			if !matches!(types.try_get(blade_name), Some(Type::SBlade(_))) {
+				return Err(error(
+					member.blade.start(),
+					format!(
+						"Unknown blade '{}' for member '{}' of struct '{}'",
+						blade_name, member_name, struct_name
+					),
+				));
+			}
+			if member.constant {
+				constants.push((member_name, blade_name));
+			} else {
+				members.push((member_name, blade_name));
+			}
+		}
+		types.insert_struct_with_constants(struct_name, &members, &constants);
+	}
+
+	let selection = selection(&file.generate, &types, &error)?;
+
+	Ok((grammar, types, selection))
+}
+
+/// Parse a file with only a `[generate]` table (see `generator/grammars/pga3d.toml`),
+/// e.g. to select which operations to generate for a built-in grammar.
+pub fn parse_selection(source: &str, types: &Types) -> Result<Selection, ParseError> {
+	let file: SelectionFile = from_toml(source)?;
+	let error = |spanned_start: usize, message: String| ParseError {
+		line: Some(line_number(source, spanned_start)),
+		message,
+	};
+	selection(&file.generate, types, &error)
+}
+
+fn from_toml<T: serde::de::DeserializeOwned>(source: &str) -> Result<T, ParseError> {
+	toml::from_str(source).map_err(|err| {
+		let message = err.to_string();
+		// Strip the location, since we report it separately:
+		let message = match message.rfind(" at line ") {
+			Some(idx) => message[..idx].to_owned(),
+			None => message,
+		};
+		ParseError {
+			line: err.line_col().map(|(line, _col)| line + 1),
+			message,
+		}
+	})
+}
+
+fn selection(
+	generate: &GenerateDef,
+	types: &Types,
+	error: &dyn Fn(usize, String) -> ParseError,
+) -> Result<Selection, ParseError> {
+	// E.g. `"anti_wedge"` -> `Product::AntiWedge`:
+	fn by_name<T: IntoEnumIterator>(
+		kind: &str,
+		names: &Option<Vec<Spanned<String>>>,
+		function_name: impl Fn(&T) -> &'static str,
+		error: &dyn Fn(usize, String) -> ParseError,
+	) -> Result<Option<Vec<T>>, ParseError> {
+		let names = match names {
+			Some(names) => names,
+			None => return Ok(None),
+		};
+		let mut values = vec![];
+		for name in names {
+			let value = T::iter()
+				.find(|value| function_name(value) == name.get_ref())
+				.ok_or_else(|| {
+					error(
+						name.start(),
+						format!(
+							"Unknown {} '{}'. Expected one of: {}",
+							kind,
+							name.get_ref(),
+							T::iter()
+								.map(|value| function_name(&value))
+								.collect::<Vec<_>>()
+								.join(", ")
+						),
+					)
+				})?;
+			values.push(value);
+		}
+		Ok(Some(values))
+	}
+
+	let struct_pairs = match &generate.struct_pairs {
+		Some(struct_pairs) => {
+			let mut pairs = vec![];
+			for pair in struct_pairs {
+				let (lhs, rhs) = pair.get_ref();
+				for name in [lhs, rhs] {
+					// The multivector is added after parsing, unless the file declares one:
+					let is_struct = matches!(types.try_get(name), Some(Type::Struct(_))) || name == gen::MULTIVECTOR;
+					if !is_struct {
+						return Err(error(
+							pair.start(),
+							format!("Unknown struct '{}' in struct_pairs", name),
+						));
+					}
+				}
+				pairs.push((lhs.clone(), rhs.clone()));
+			}
+			Some(pairs)
+		}
+		None => None,
+	};
+	Ok(Selection {
+		products: by_name(
+			"product",
+			&generate.products,
+			|p: &Product| p.trait_function_name(),
+			error,
+		)?,
+		unaries: by_name(
+			"unary operation",
+			&generate.unaries,
+			|u: &Unary| u.trait_function_name(),
+			error,
+		)?,
+		sandwiches: by_name(
+			"sandwich",
+			&generate.sandwiches,
+			|s: &Sandwich| s.trait_function_name(),
+			error,
+		)?,
+		struct_pairs,
+	})
+}
+
+/// 1-based line number of the given byte offset
+fn line_number(source: &str, byte_offset: usize) -> usize {
+	source[..byte_offset].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_builtin_grammar_files() {
+		assert_eq!(
+			parse(include_str!("../grammars/pga2d.toml")).unwrap(),
+			grammars::pga2d()
+		);
+		assert_eq!(
+			parse(include_str!("../grammars/pga3d.toml")).unwrap(),
+			grammars::pga3d()
+		);
+		assert_eq!(
+			parse(include_str!("../grammars/vga3d.toml")).unwrap(),
+			grammars::vga3d()
+		);
+		assert_eq!(parse(include_str!("../grammars/sta.toml")).unwrap(), grammars::sta());
+		assert_eq!(
+			parse(include_str!("../grammars/cga3d.toml")).unwrap(),
+			grammars::cga3d()
+		);
+	}
+
+	#[test]
+	fn test_parse_errors() {
+		let source = r#"
+vectors = [
+	{ name = "X", square = 1 },
+	{ name = "W", square = 0 },
+]
+blades = [
+	{ name = "S", vecs = [] },
+	{ name = "X", vecs = ["X"] },
+	{ name = "W", vecs = ["W"] },
+	{ name = "WX", vecs = ["W", "X"] },
+]
+
+[[structs]]
+name = "Point"
+members = [
+	{ name = "x", blade = "X" },
+	{ name = "w", blade = "XW" },
+]
+"#;
+		assert_eq!(
+			parse(source).unwrap_err(),
+			ParseError {
+				line: Some(17),
+				message: "Unknown blade 'XW' for member 'w' of struct 'Point'".to_owned(),
+			}
+		);
+
+		let source = source.replace(r#"{ name = "WX", vecs = ["W", "X"] },"#, "");
+		assert_eq!(
+			parse(&source).unwrap_err().to_string(),
+			"Missing a blade for the base vectors [X, W]. All 4 blades must be named."
+		);
+
+		let with_dots = source.replace(
+			"blades = [",
+			"dots = [\n\t{ vecs = [\"X\", \"X\"], dot = 1 },\n]\nblades = [",
+		);
+		assert_eq!(
+			parse(&with_dots).unwrap_err(),
+			ParseError {
+				line: Some(7),
+				message: "The dot product of 'X' with itself is its square".to_owned(),
+			}
+		);
+
+		let ambiguous = r#"
+vectors = [
+	{ name = "A", square = 1 },
+	{ name = "B", square = 1 },
+	{ name = "AB", square = 1 },
+]
+"#;
+		assert_eq!(
+			parse(ambiguous).unwrap_err().to_string(),
+			"The blade name 'AB' is ambiguous. Rename the base vectors, or list the blades."
+		);
+
+		let source = source.replace(r#"square = 0 }"#, "square = }");
+		assert_eq!(parse(&source).unwrap_err().line, Some(4));
+	}
+
+	#[test]
+	fn test_selection() {
+		let source = format!(
+			"{}{}",
+			include_str!("../grammars/pga2d.toml"),
+			r#"
+[generate]
+products = ["wedge", "anti_wedge"]
+sandwiches = []
+struct_pairs = [["Vec3", "Vec3"], ["Multivector", "Line"]]
+"#
+		);
+		let (_, _, selection) = parse_with_selection(&source).unwrap();
+		assert_eq!(
+			selection,
+			Selection {
+				products: Some(vec![Product::Wedge, Product::AntiWedge]),
+				unaries: None,
+				sandwiches: Some(vec![]),
+				struct_pairs: Some(vec![
+					("Vec3".to_owned(), "Vec3".to_owned()),
+					("Multivector".to_owned(), "Line".to_owned())
+				]),
+			}
+		);
+		assert!(!selection.has_product(Product::Geometric));
+		assert!(selection.has_unary(Unary::Reverse));
+		assert!(selection.has_struct_pair("Vec3", "Vec3"));
+		assert!(!selection.has_struct_pair("Line", "Multivector"));
+
+		let (_, _, selection) = parse_with_selection(include_str!("../grammars/pga2d.toml")).unwrap();
+		assert!(selection.is_all());
+
+		let unknown_product = source.replace("\"anti_wedge\"", "\"antiwedge\"");
+		assert!(parse_with_selection(&unknown_product)
+			.unwrap_err()
+			.message
+			.starts_with("Unknown product 'antiwedge'. Expected one of: geometric, anti_geometric, dot, wedge,"));
+
+		let unknown_struct = source.replace("[\"Vec3\", \"Vec3\"]", "[\"Vec3\", \"Vec4\"]");
+		assert_eq!(
+			parse_with_selection(&unknown_struct).unwrap_err().message,
+			"Unknown struct 'Vec4' in struct_pairs"
+		);
+
+		// Only the `[generate]` table, for a built-in grammar:
+		let (_, types) = grammars::pga3d();
+		let selection = parse_selection(
+			"[generate]\nproducts = [\"wedge\"]\nstruct_pairs = [[\"Vec4\", \"Vec4\"]]\n",
+			&types,
+		);
+		assert_eq!(
+			selection.unwrap(),
+			Selection {
+				products: Some(vec![Product::Wedge]),
+				unaries: None,
+				sandwiches: None,
+				struct_pairs: Some(vec![("Vec4".to_owned(), "Vec4".to_owned())]),
+			}
+		);
+		assert_eq!(
+			parse_selection("[generate]\nstruct_pairs = [[\"Vec3\", \"Line\"]]\n", &types)
+				.unwrap_err()
+				.message,
+			"Unknown struct 'Line' in struct_pairs"
+		);
+		assert!(parse_selection("[[vectors]]\nname = \"X\"\nsquare = 1\n[generate]\n", &types).is_err());
+	}
+}
@@ -1,7 +1,7 @@
 use crate::*;
 
 pub fn pga2d() -> (Grammar, Types) {
-	let g = Grammar(vec![1, 1, 0]);
+	let g = Grammar::new(vec![1, 1, 0]);
 	let mut t = Types::default();
 	let x = VecIdx(0);
 	let y = VecIdx(1);
@@ -15,9 +15,10 @@ pub fn pga2d() -> (Grammar, Types) {
 	t.insert_blade("XY", SBlade::from_unsorted(&[x, y]));
 	t.insert_blade("XYW", SBlade::from_unsorted(&[x, y, w]));
 
-	// TODO: Point { x: X, y: Y, z: Z, w: 1)
 	t.insert_struct("Vec2", &[("x", "X"), ("y", "Y")]);
 	t.insert_struct("Vec3", &[("x", "X"), ("y", "Y"), ("w", "W")]);
+	// Normalized point:
+	t.insert_struct_with_constants("Point2", &[("x", "X"), ("y", "Y")], &[("w", "W")]);
 
 	// TODO: verify if these are Plücker coordinates
 	t.insert_struct(
@@ -31,8 +32,8 @@ pub fn pga2d() -> (Grammar, Types) {
 		],
 	);
 
-	// TODO: is this correct?
-	// t.insert_struct("Translator", &[("s", "S"), ("yw", "YW"), ("wx", "WX")]);
+	// Translator * Rotor = Motor
+	t.insert_struct("Translator", &[("s", "S"), ("yw", "YW"), ("wx", "WX")]);
 	t.insert_struct("Rotor", &[("s", "S"), ("xy", "XY")]);
 	t.insert_struct("Motor", &[("s", "S"), ("yw", "YW"), ("wx", "WX"), ("xy", "XY")]);
 	// TODO: Is this a Motor? Or a Transform?
@@ -47,7 +48,7 @@ pub fn pga2d() -> (Grammar, Types) {
 /// Using the Eric Lengyel system, but with X,Y,Z,W instead of e1,e2,e3,e4
 /// See http://terathon.com/blog/projective-geometric-algebra-done-right/
 pub fn pga3d() -> (Grammar, Types) {
-	let g = Grammar(vec![1, 1, 1, 0]);
+	let g = Grammar::new(vec![1, 1, 1, 0]);
 	let mut t = Types::default();
 	let x = VecIdx(0);
 	let y = VecIdx(1);
@@ -98,9 +99,10 @@ pub fn pga3d() -> (Grammar, Types) {
 	// -----------------------------------
 	// 3D
 
-	// TODO: Point3 { x: X, y: Y, z: Z, w: 1)
 	t.insert_struct("Vec3", &[("x", "X"), ("y", "Y"), ("z", "Z")]);
 	t.insert_struct("Vec4", &[("x", "X"), ("y", "Y"), ("z", "Z"), ("w", "W")]);
+	// Normalized point:
+	t.insert_struct_with_constants("Point3", &[("x", "X"), ("y", "Y"), ("z", "Z")], &[("w", "W")]);
 
 	// The result of Vec3 ^ Vec3, which is numerically identical to a cross product.
 	// Use this to represent e.g. a normal.
@@ -124,10 +126,11 @@ pub fn pga3d() -> (Grammar, Types) {
 	// t.insert_struct("Plane", &[("nx", "YZW"), ("ny", "ZXW"), ("nz", "XYW"), ("d", "ZYX")]);
 	t.insert_struct("Plane", &[("nx", "YZW"), ("ny", "ZXW"), ("nz", "XYW"), ("d", "XYZ")]);
 
-	// t.insert_struct(
-	// 	"Translator3",
-	// 	&[("x", "YZ"), ("y", "ZX"), ("z", "XY"), ("xyzw", "XYZW")],
-	// );
+	// Like Rotor3 and Motor3, this uses the anti-product convention of Eric Lengyel:
+	// translating by [x, y, z] is XYZW + (x/2) YZ + (y/2) ZX + (z/2) XY,
+	// and Translator3.anti_geometric(Rotor3) is a Motor3.
+	t.insert_struct("Translator3", &[("x", "YZ"), ("y", "ZX"), ("z", "XY"), ("w", "XYZW")]);
+
 	// Quaternion
 	t.insert_struct("Rotor3", &[("x", "WX"), ("y", "WY"), ("z", "WZ"), ("w", "XYZW")]);
 
@@ -139,9 +142,9 @@ pub fn pga3d() -> (Grammar, Types) {
 			("ry", "WY"),
 			("rz", "WZ"),
 			("rw", "XYZW"),
-			("ux", "YZW"),
-			("uy", "ZXW"),
-			("uz", "XYW"),
+			("ux", "YZ"),
+			("uy", "ZX"),
+			("uz", "XY"),
 			("uw", "S"),
 		],
 	);
@@ -154,7 +157,7 @@ pub fn pga3d() -> (Grammar, Types) {
 /// Using the naming convention of Eric Lengyel.
 /// See http://terathon.com/blog/projective-geometric-algebra-done-right/
 pub fn pga3d_lengyel() -> (Grammar, Types) {
-	let g = Grammar(vec![1, 1, 1, 0]);
+	let g = Grammar::new(vec![1, 1, 1, 0]);
 	let mut t = Types::default();
 
 	let e1 = VecIdx(0);
@@ -201,3 +204,203 @@ pub fn pga3d_lengyel() -> (Grammar, Types) {
 
 	(g, t)
 }
+
+/// 3D Euclidean (vector) geometric algebra.
+pub fn vga3d() -> (Grammar, Types) {
+	let g = Grammar::new(vec![1, 1, 1]);
+	let mut t = Types::default();
+	let x = VecIdx(0);
+	let y = VecIdx(1);
+	let z = VecIdx(2);
+
+	t.insert_blade("S", SBlade::scalar());
+	t.insert_blade("X", SBlade::vec(x));
+	t.insert_blade("Y", SBlade::vec(y));
+	t.insert_blade("Z", SBlade::vec(z));
+	t.insert_blade("YZ", SBlade::from_unsorted(&[y, z]));
+	t.insert_blade("ZX", SBlade::from_unsorted(&[z, x]));
+	t.insert_blade("XY", SBlade::from_unsorted(&[x, y]));
+	t.insert_blade("XYZ", SBlade::from_unsorted(&[x, y, z]));
+
+	t.insert_struct("Vec3", &[("x", "X"), ("y", "Y"), ("z", "Z")]);
+
+	// The plane of rotation, and the dual of a `Vec3` (a.k.a. a pseudovector).
+	t.insert_struct("Bivec3", &[("yz", "YZ"), ("zx", "ZX"), ("xy", "XY")]);
+
+	// a.k.a. quaternion
+	t.insert_struct("Rotor3", &[("s", "S"), ("yz", "YZ"), ("zx", "ZX"), ("xy", "XY")]);
+
+	(g, t)
+}
+
+/// Spacetime algebra, with the signature `+---`: the time vector `E0` squares to one,
+/// and the space vectors `E1 E2 E3` to minus one.
+pub fn sta() -> (Grammar, Types) {
+	let g = Grammar::new(vec![1, -1, -1, -1]);
+	let mut t = Types::default();
+	t.insert_all_blades(&g, &["E0", "E1", "E2", "E3"]);
+
+	// An event, or a four-velocity, four-momentum, …
+	t.insert_struct("Vec4", &[("t", "E0"), ("x", "E1"), ("y", "E2"), ("z", "E3")]);
+
+	// E.g. the electromagnetic field: the boosts `E01 E02 E03` (electric) and the rotations `E12 E13 E23` (magnetic).
+	t.insert_struct(
+		"Bivector",
+		&[
+			("e01", "E01"),
+			("e02", "E02"),
+			("e03", "E03"),
+			("e12", "E12"),
+			("e13", "E13"),
+			("e23", "E23"),
+		],
+	);
+
+	// A Lorentz transform (boost + rotation), applied with `sandwich`.
+	t.insert_struct(
+		"Rotor",
+		&[
+			("s", "S"),
+			("e01", "E01"),
+			("e02", "E02"),
+			("e03", "E03"),
+			("e12", "E12"),
+			("e13", "E13"),
+			("e23", "E23"),
+			("e0123", "E0123"),
+		],
+	);
+
+	(g, t)
+}
+
+/// 3D conformal geometric algebra, with the null basis of the origin `Eo` and infinity `Ei`.
+/// Both square to zero, with `Eo·Ei = -1`, so this is the metric `++++-` in another basis.
+/// A point is `x + Eo + x²/2 Ei`, like in "Geometric Algebra for Computer Science" by Dorst, Fontijne and Mann.
+///
+/// Round and flat objects share the same blades, so most structs are named by their grade:
+/// the meaning depends on whether it is built with the wedge (outer) product or its dual.
+pub fn cga3d() -> (Grammar, Types) {
+	let eo = VecIdx(3);
+	let ei = VecIdx(4);
+	let g = Grammar::new(vec![1, 1, 1, 0, 0]).with_dot(eo, ei, -1);
+	let mut t = Types::default();
+	t.insert_all_blades(&g, &["E1", "E2", "E3", "Eo", "Ei"]);
+
+	t.insert_struct("Vec3", &[("x", "E1"), ("y", "E2"), ("z", "E3")]);
+
+	// A point `x + Eo + x²/2 Ei`, or a dual sphere `center - r²/2 Ei`, or a dual plane `normal + distance * Ei`.
+	t.insert_struct(
+		"Point",
+		&[("x", "E1"), ("y", "E2"), ("z", "E3"), ("o", "Eo"), ("inf", "Ei")],
+	);
+
+	// `point ^ Ei`
+	t.insert_struct(
+		"FlatPoint",
+		&[("e1i", "E1i"), ("e2i", "E2i"), ("e3i", "E3i"), ("eoi", "Eoi")],
+	);
+
+	// `point ^ point`, or a dual circle.
+	t.insert_struct(
+		"PointPair",
+		&[
+			("e12", "E12"),
+			("e13", "E13"),
+			("e1o", "E1o"),
+			("e1i", "E1i"),
+			("e23", "E23"),
+			("e2o", "E2o"),
+			("e2i", "E2i"),
+			("e3o", "E3o"),
+			("e3i", "E3i"),
+			("eoi", "Eoi"),
+		],
+	);
+
+	// `point ^ point ^ Ei`
+	t.insert_struct(
+		"Line",
+		&[
+			("e12i", "E12i"),
+			("e13i", "E13i"),
+			("e1oi", "E1oi"),
+			("e23i", "E23i"),
+			("e2oi", "E2oi"),
+			("e3oi", "E3oi"),
+		],
+	);
+
+	// `point ^ point ^ point`
+	t.insert_struct(
+		"Circle",
+		&[
+			("e123", "E123"),
+			("e12o", "E12o"),
+			("e12i", "E12i"),
+			("e13o", "E13o"),
+			("e13i", "E13i"),
+			("e1oi", "E1oi"),
+			("e23o", "E23o"),
+			("e23i", "E23i"),
+			("e2oi", "E2oi"),
+			("e3oi", "E3oi"),
+		],
+	);
+
+	// `point ^ point ^ point ^ Ei`
+	t.insert_struct(
+		"Plane",
+		&[
+			("e123i", "E123i"),
+			("e12oi", "E12oi"),
+			("e13oi", "E13oi"),
+			("e23oi", "E23oi"),
+		],
+	);
+
+	// `point ^ point ^ point ^ point`
+	t.insert_struct(
+		"Sphere",
+		&[
+			("e123o", "E123o"),
+			("e123i", "E123i"),
+			("e12oi", "E12oi"),
+			("e13oi", "E13oi"),
+			("e23oi", "E23oi"),
+		],
+	);
+
+	t.insert_struct("Rotor", &[("s", "S"), ("e12", "E12"), ("e13", "E13"), ("e23", "E23")]);
+
+	// `1 - t Ei / 2` translates by `t`.
+	t.insert_struct(
+		"Translator",
+		&[("s", "S"), ("e1i", "E1i"), ("e2i", "E2i"), ("e3i", "E3i")],
+	);
+
+	// The even blades: rotors, translators, dilators and their products.
+	t.insert_struct(
+		"Versor",
+		&[
+			("s", "S"),
+			("e12", "E12"),
+			("e13", "E13"),
+			("e1o", "E1o"),
+			("e1i", "E1i"),
+			("e23", "E23"),
+			("e2o", "E2o"),
+			("e2i", "E2i"),
+			("e3o", "E3o"),
+			("e3i", "E3i"),
+			("eoi", "Eoi"),
+			("e123o", "E123o"),
+			("e123i", "E123i"),
+			("e12oi", "E12oi"),
+			("e13oi", "E13oi"),
+			("e23oi", "E23oi"),
+		],
+	);
+
+	(g, t)
+}
@@ -1,17 +1,24 @@
+pub mod backend;
 mod blade;
+pub mod cpp;
 pub mod documentation;
 mod explicit;
 mod expr;
 pub mod gen;
+pub mod grammar_file;
 pub mod grammars;
 pub mod markdown;
+pub mod output;
+pub mod python;
 mod rust;
 mod sblade;
+pub mod shader;
 mod simplify;
 mod typ;
 mod types;
 mod typify;
 
+pub use output::generate;
 pub use {blade::*, expr::*, rust::*, sblade::*, typ::*, types::*};
 
 /// Which base vector (e0, e1 or e2?)
@@ -19,7 +26,7 @@ pub use {blade::*, expr::*, rust::*, sblade::*, typ::*, types::*};
 pub struct VecIdx(pub usize);
 
 /// Types of distributative unary operations
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, strum_macros::EnumIter)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, strum_macros::EnumIter)]
 pub enum Unary {
 	/// Right compliment.
 	/// The right compliment of a blade B is defined so that
@@ -45,9 +52,37 @@ pub enum Unary {
 	/// x.rcompl().reverse().lcompl()
 	/// Used for anti-sandwich-products
 	AntiReverse,
+
+	/// Grade involution: negate the odd grades.
+	/// x.involute() == (-1)^grade * x
+	Involute,
+
+	/// Clifford conjugate: the reverse of the grade involution.
+	/// x.conjugate() == x.involute().reverse()
+	Conjugate,
+
+	/// Poincaré dual.
+	/// The right complement for the lower half of the grades and the left complement for the upper half,
+	/// so that x.dual().dual() == x in PGA.
+	Dual,
+
+	/// The part of a value that doesn't contain a degenerate base vector (e.g. `X Y Z` but not `W`).
+	/// More generally: each blade times the product of the squares of its vectors (the metric).
+	Bulk,
+
+	/// The part of a value that contains a degenerate base vector (e.g. `W`).
+	/// x.weight() == x.rcompl().bulk().lcompl()
+	Weight,
+
+	/// x.bulk_dual() == x.bulk().rcompl()
+	BulkDual,
+
+	/// x.weight_dual() == x.weight().rcompl()
+	/// Used for distances, e.g. the attitude of a plane.
+	WeightDual,
 }
 
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, strum_macros::EnumIter)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, strum_macros::EnumIter)]
 pub enum Product {
 	/// Geom = Inner + Outer = Dot + Wedge
 	Geometric,
@@ -66,38 +101,140 @@ pub enum Product {
 
 	/// Regressive. Reduces the dimensionality. Meet.
 	AntiWedge,
+
+	/// Left contraction, a.k.a. left interior product.
+	/// The grade `grade(b) - grade(a)` part of the geometric product `a * b`,
+	/// so it is zero when `a` has a higher grade than `b`.
+	LeftContraction,
+
+	/// Right contraction, a.k.a. right interior product.
+	/// The grade `grade(a) - grade(b)` part of the geometric product `a * b`,
+	/// so it is zero when `b` has a higher grade than `a`.
+	RightContraction,
+
+	/// The scalar (grade 0) part of the geometric product.
+	ScalarProduct,
+
+	/// Commutator product `(a * b - b * a) / 2`, the anti-commutative part of the geometric product.
+	/// For two blades this is either `a * b` or zero.
+	Commutator,
+
+	/// Anti-dot product = rcompl(lcompl(a) | lcompl(b))
+	AntiDot,
+}
+
+/// A sandwich product, used to transform a value with a versor (rotor, motor, …).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, strum_macros::EnumIter)]
+pub enum Sandwich {
+	/// a.sandwich(b) = a * b * a.rev()
+	Geometric,
+
+	/// a.anti_sandwich(b) = a ⟇ b ⟇ a.arev()
+	/// Used with Eric Lengyel's convention, where e.g. a rotor is `WX WY WZ XYZW`.
+	AntiGeometric,
 }
 
-/// what you get when you square the input vectors,
-/// e.g. [0, 1, 1] would specify the 2d gpa of e0^2=0  e1^2=1  e2^2=1
-pub struct Grammar(pub Vec<i32>);
+/// The metric: what you get when you multiply the base vectors with each other.
+/// `Grammar::new(vec![0, 1, 1])` would specify the 2d gpa of e0^2=0  e1^2=1  e2^2=1.
+///
+/// Usually the metric is diagonal (the base vectors are orthogonal),
+/// but a non-diagonal one can be added with [`Grammar::with_dot`],
+/// e.g. for the null basis of conformal geometric algebra.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grammar {
+	/// what you get when you square the input vectors
+	squares: Vec<i32>,
+
+	/// The non-zero dot products of different base vectors, `a·b` with `a < b`.
+	dots: std::collections::BTreeMap<(VecIdx, VecIdx), i32>,
+}
 
 // ----------------------------------------------------------------------------
 
 impl Grammar {
+	/// A diagonal metric, given what the base vectors square to.
+	pub fn new(squares: Vec<i32>) -> Self {
+		Self {
+			squares,
+			dots: Default::default(),
+		}
+	}
+
+	/// Set the dot product of two different base vectors,
+	/// e.g. `no·ni = -1` for the origin and infinity of conformal geometric algebra.
+	pub fn with_dot(mut self, a: VecIdx, b: VecIdx, dot: i32) -> Self {
+		assert!(a != b, "Use the squares for the diagonal of the metric");
+		let key = (a.min(b), a.max(b));
+		if dot == 0 {
+			self.dots.remove(&key);
+		} else {
+			self.dots.insert(key, dot);
+		}
+		self
+	}
+
+	/// Are all the base vectors orthogonal to each other?
+	/// If not, the product of two blades can be a sum of blades,
+	/// see [`SBlade::binary_product_terms`].
+	pub fn is_diagonal(&self) -> bool {
+		self.dots.is_empty()
+	}
+
+	/// The dot product of two base vectors (the metric).
+	pub fn dot(&self, a: VecIdx, b: VecIdx) -> i32 {
+		if a == b {
+			self.square_geom(a)
+		} else {
+			self.dots.get(&(a.min(b), a.max(b))).copied().unwrap_or(0)
+		}
+	}
+
 	pub fn square_geom(&self, v: VecIdx) -> i32 {
-		self.0[v.0]
+		self.squares[v.0]
 	}
 
 	/// What do we get when we multiply the given base vector with itself using the given product?
+	/// For the anti-products this is the factor of the pseudo-scalar (their identity element),
+	/// e.g. `W ⟇ W = -XYZW` in 3D PGA, so `-1`.
 	pub fn square_with(&self, product: Product, v: VecIdx) -> Option<i32> {
 		match product {
-			Product::Geometric => Some(self.0[v.0]),
-			Product::AntiGeometric => None, // TODO
-			Product::Dot => Some(self.0[v.0]),
-			Product::Wedge => Some(0),
-			Product::AntiWedge => Some(0),
+			Product::Geometric
+			| Product::Dot
+			| Product::LeftContraction
+			| Product::RightContraction
+			| Product::ScalarProduct => Some(self.square_geom(v)),
+			Product::AntiGeometric | Product::AntiDot => {
+				let square = SBlade::binary_product(&SBlade::vec(v), product, &SBlade::vec(v), self);
+				debug_assert!(square.is_zero() || square.blade == Blade::pseudo_scalar(self));
+				Some(square.sign)
+			}
+			Product::Wedge | Product::AntiWedge | Product::Commutator => Some(0),
 		}
 	}
 
 	/// Number of base vectors, i.e. the dimensionaltiy of the grammar
 	pub fn num_vecs(&self) -> usize {
-		self.0.len()
+		self.squares.len()
 	}
 
 	pub fn vecs(&self) -> impl Iterator<Item = VecIdx> {
 		(0..self.num_vecs()).map(VecIdx)
 	}
+
+	/// All the `2^n` blades, in order of grade, e.g. `s e0 e1 e2 e01 e02 e12 e012`.
+	pub fn blades(&self) -> Vec<Blade> {
+		let mut blades: Vec<Blade> = (0..1_usize << self.num_vecs())
+			.map(|bits| Blade::from_sorted(self.vecs().filter(|vi| bits & (1 << vi.0) != 0).collect()))
+			.collect();
+		blades.sort_by(|a, b| a.grade().cmp(&b.grade()).then_with(|| a.vecs().cmp(b.vecs())));
+		blades
+	}
+
+	/// Does the blade contain a degenerate base vector (one that squares to zero, e.g. `W`)?
+	/// If so, it is part of the *weight* of a value, otherwise of its *bulk*.
+	pub fn is_weight(&self, blade: &Blade) -> bool {
+		blade.vecs().iter().any(|&vi| self.square_geom(vi) == 0)
+	}
 }
 
 impl Unary {
@@ -107,6 +244,13 @@ impl Unary {
 			Unary::RCompl => "rcompl",
 			Unary::Reverse => "rev",
 			Unary::AntiReverse => "arev",
+			Unary::Involute => "involute",
+			Unary::Conjugate => "conjugate",
+			Unary::Dual => "dual",
+			Unary::Bulk => "bulk",
+			Unary::Weight => "weight",
+			Unary::BulkDual => "bulk_dual",
+			Unary::WeightDual => "weight_dual",
 		}
 	}
 
@@ -116,16 +260,29 @@ impl Unary {
 			Unary::RCompl => "Right complement",
 			Unary::Reverse => "Reverse",
 			Unary::AntiReverse => "Anti-reverse",
+			Unary::Involute => "Involute",
+			Unary::Conjugate => "Conjugate",
+			Unary::Dual => "Dual",
+			Unary::Bulk => "Bulk",
+			Unary::Weight => "Weight",
+			Unary::BulkDual => "Bulk dual",
+			Unary::WeightDual => "Weight dual",
 		}
 	}
 
 	/// whan undoes this operation?
-	pub fn undoer(self) -> Self {
+	/// None if it can't be undone (e.g. the bulk, which throws away the weight).
+	pub fn undoer(self) -> Option<Self> {
 		match self {
-			Unary::LCompl => Unary::RCompl,
-			Unary::RCompl => Unary::LCompl,
-			Unary::Reverse => Unary::Reverse,
-			Unary::AntiReverse => Unary::AntiReverse,
+			Unary::LCompl => Some(Unary::RCompl),
+			Unary::RCompl => Some(Unary::LCompl),
+			Unary::Reverse => Some(Unary::Reverse),
+			Unary::AntiReverse => Some(Unary::AntiReverse),
+			Unary::Involute => Some(Unary::Involute),
+			Unary::Conjugate => Some(Unary::Conjugate),
+			// Only its own inverse in some algebras:
+			Unary::Dual => None,
+			Unary::Bulk | Unary::Weight | Unary::BulkDual | Unary::WeightDual => None,
 		}
 	}
 
@@ -135,6 +292,13 @@ impl Unary {
 			Unary::RCompl => "RCompl",
 			Unary::Reverse => "Reverse",
 			Unary::AntiReverse => "AntiReverse",
+			Unary::Involute => "Involute",
+			Unary::Conjugate => "Conjugate",
+			Unary::Dual => "Dual",
+			Unary::Bulk => "Bulk",
+			Unary::Weight => "Weight",
+			Unary::BulkDual => "BulkDual",
+			Unary::WeightDual => "WeightDual",
 		}
 	}
 
@@ -144,11 +308,67 @@ impl Unary {
 			Unary::RCompl => "rcompl",
 			Unary::Reverse => "rev",
 			Unary::AntiReverse => "arev",
+			Unary::Involute => "involute",
+			Unary::Conjugate => "conjugate",
+			Unary::Dual => "dual",
+			Unary::Bulk => "bulk",
+			Unary::Weight => "weight",
+			Unary::BulkDual => "bulk_dual",
+			Unary::WeightDual => "weight_dual",
 		}
 	}
 
+	/// The bulk and weight are only defined for a diagonal metric,
+	/// where each base vector is either degenerate (part of the weight) or not (part of the bulk).
+	pub fn needs_diagonal_metric(self) -> bool {
+		matches!(self, Unary::Bulk | Unary::Weight | Unary::BulkDual | Unary::WeightDual)
+	}
+
+	/// The ones that only change signs return `Self`,
+	/// the others (e.g. complements, or the bulk which drops blades) may return another type.
 	pub fn trait_has_output_type(self) -> bool {
-		matches!(self, Unary::LCompl | Unary::RCompl)
+		!matches!(
+			self,
+			Unary::Reverse | Unary::AntiReverse | Unary::Involute | Unary::Conjugate
+		)
+	}
+}
+
+impl Sandwich {
+	pub fn product(self) -> Product {
+		match self {
+			Sandwich::Geometric => Product::Geometric,
+			Sandwich::AntiGeometric => Product::AntiGeometric,
+		}
+	}
+
+	pub fn reverse(self) -> Unary {
+		match self {
+			Sandwich::Geometric => Unary::Reverse,
+			Sandwich::AntiGeometric => Unary::AntiReverse,
+		}
+	}
+
+	pub fn trait_name(self) -> &'static str {
+		match self {
+			Sandwich::Geometric => "Sandwich",
+			Sandwich::AntiGeometric => "AntiSandwich",
+		}
+	}
+
+	pub fn trait_function_name(self) -> &'static str {
+		match self {
+			Sandwich::Geometric => "sandwich",
+			Sandwich::AntiGeometric => "anti_sandwich",
+		}
+	}
+
+	/// `versor * operand * versor.rev()`
+	pub fn expr(self, versor: Expr, operand: Expr) -> Expr {
+		Expr::Prod(
+			self.product(),
+			vec![versor.clone(), operand, Expr::unary(self.reverse(), versor)],
+		)
 	}
 }
 
@@ -156,13 +376,38 @@ impl Product {
 	pub fn symbol(self) -> &'static str {
 		match self {
 			Product::Geometric => "*",
-			Product::AntiGeometric => "!*", // TODO
+			Product::AntiGeometric => "⟇",
 			Product::Dot => "|",
 			Product::Wedge => "^",
 			Product::AntiWedge => "&",
+			Product::LeftContraction => "⌋",
+			Product::RightContraction => "⌊",
+			Product::ScalarProduct => "∗",
+			Product::Commutator => "×",
+			Product::AntiDot => "∘",
 		}
 	}
 
+	/// Is `(a ∘ b) ∘ c == a ∘ (b ∘ c)`?
+	/// Only the factors of associative products are flattened when simplifying.
+	pub fn is_associative(self) -> bool {
+		matches!(
+			self,
+			Product::Geometric | Product::AntiGeometric | Product::Wedge | Product::AntiWedge
+		)
+	}
+
+	/// Does this product depend on the metric?
+	/// The wedge and anti-wedge products don't, and so always map two blades to one blade.
+	pub fn uses_metric(self) -> bool {
+		!matches!(self, Product::Wedge | Product::AntiWedge)
+	}
+
+	/// Is the identity element of this product the pseudo-scalar (instead of the scalar)?
+	pub fn is_anti(self) -> bool {
+		matches!(self, Product::AntiGeometric | Product::AntiWedge | Product::AntiDot)
+	}
+
 	pub fn trait_name(self) -> &'static str {
 		match self {
 			Product::Geometric => "Geometric",
@@ -170,6 +415,11 @@ impl Product {
 			Product::Dot => "Dot",
 			Product::Wedge => "Wedge",
 			Product::AntiWedge => "AntiWedge",
+			Product::LeftContraction => "LeftContraction",
+			Product::RightContraction => "RightContraction",
+			Product::ScalarProduct => "ScalarProduct",
+			Product::Commutator => "Commutator",
+			Product::AntiDot => "AntiDot",
 		}
 	}
 
@@ -180,6 +430,11 @@ impl Product {
 			Product::Dot => "dot",
 			Product::Wedge => "wedge",
 			Product::AntiWedge => "anti_wedge",
+			Product::LeftContraction => "left_contraction",
+			Product::RightContraction => "right_contraction",
+			Product::ScalarProduct => "scalar_product",
+			Product::Commutator => "commutator",
+			Product::AntiDot => "anti_dot",
 		}
 	}
 }
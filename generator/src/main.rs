@@ -1,6 +1,4 @@
-use std::{collections::BTreeSet, error::Error, fs, io::prelude::*, path::Path};
-
-use itertools::Itertools;
+use std::{error::Error, fs, path::Path};
 
 use generator::{gen::*, *};
 
@@ -10,21 +8,67 @@ fn main() -> Result<(), Box<dyn Error>> {
 	let grammar: String = args.value_from_str(["-g", "--grammar"])?;
 	let out_dir: String = args.value_from_str(["-o", "--out_dir"])?;
 	let out_dir = Path::new(&out_dir);
+	let float_type: Option<String> = args.opt_value_from_str("--float")?;
+	let generic = args.contains("--generic");
+	let c_ffi = args.contains("--ffi");
+	let prune = args.contains("--prune");
+	let cargo_features = args.contains("--cargo_features");
+	let generate: Option<String> = args.opt_value_from_str("--generate")?;
+	let mut languages: Vec<String> = args.values_from_str("--lang")?;
+	if languages.is_empty() {
+		languages.push("rust".to_owned());
+	}
 
-	let (grammar, types) = match grammar.as_str() {
-		"pga2d" => generator::grammars::pga2d(),
-		"pga3d" => generator::grammars::pga3d(),
-		_ => panic!("Unknown grammar: '{}'", grammar),
+	let grammar_name = grammar;
+	let builtin = match grammar_name.as_str() {
+		"pga2d" => Some(generator::grammars::pga2d()),
+		"pga3d" => Some(generator::grammars::pga3d()),
+		"vga3d" => Some(generator::grammars::vga3d()),
+		"sta" => Some(generator::grammars::sta()),
+		"cga3d" => Some(generator::grammars::cga3d()),
+		_ => None,
+	};
+	let (grammar, types, selection) = match builtin {
+		Some((grammar, types)) => (grammar, types, Selection::default()),
+		None => {
+			let path = &grammar_name;
+			let source = fs::read_to_string(path).map_err(|err| format!("Failed to read '{}': {}", path, err))?;
+			grammar_file::parse_with_selection(&source).map_err(|err| format!("{}: {}", path, err))?
+		}
 	};
 
-	let settings = Settings::default();
-	let gen = Generator {
-		grammar,
-		types,
-		settings,
-		ro: RustOptions::rust(),
+	// Which operations to generate, also for a built-in grammar:
+	let selection = match &generate {
+		Some(path) => {
+			let source = fs::read_to_string(path).map_err(|err| format!("Failed to read '{}': {}", path, err))?;
+			grammar_file::parse_selection(&source, &types).map_err(|err| format!("{}: {}", path, err))?
+		}
+		None => selection,
 	};
 
+	// E.g. `pga3d` for `--grammar pga3d` or `--grammar path/to/pga3d.toml`:
+	let algebra_name: String = Path::new(&grammar_name)
+		.file_stem()
+		.map_or_else(|| grammar_name.clone(), |stem| stem.to_string_lossy().into_owned())
+		.chars()
+		.map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+		.collect();
+
+	let mut settings = Settings::default();
+	if let Some(float_type) = float_type {
+		settings.float_type = float_type;
+	}
+	settings.generic = generic;
+	settings.ffi = c_ffi;
+	settings.cargo_features = cargo_features;
+	settings.selection = selection;
+	settings.prune = prune;
+	let rust = languages.iter().any(|language| language == "rust");
+	if !rust && (c_ffi || generic || cargo_features) {
+		return Err("--ffi, --generic and --cargo_features are only for the Rust code, so add --lang rust".into());
+	}
+	let gen = output::generator(grammar, types, settings)?;
+
 	if false {
 		// Test:
 		let line = gen.types.get_struct("Line");
@@ -40,59 +84,37 @@ fn main() -> Result<(), Box<dyn Error>> {
 
 	fs::create_dir_all(out_dir)?;
 
-	let mut mod_file_contents = include_str!("../templates/lib.rs").to_owned();
-
-	let mut mods = BTreeSet::new();
-	mods.insert("traits".to_string());
-	mods.insert("blades".to_string());
-
-	write_file(include_str!("../templates/traits.rs"), &out_dir.join("traits.rs"))?;
-	write_file(&blades::file(&gen), &out_dir.join("blades.rs"))?;
-
-	mod_file_contents += "\n// Types:\n";
-	for (struct_name, strct) in gen.types.structs() {
-		let mod_name = struct_name.to_ascii_lowercase();
-		let file_name = format!("{}.rs", mod_name);
-		let file_contents = strct::file(&gen, struct_name, strct);
-		write_file(&file_contents, &out_dir.join(file_name))?;
-		mod_file_contents += &format!("pub mod {};\n", mod_name);
-		mods.insert(mod_name);
-	}
-
-	mod_file_contents += &format!(
-		"\npub use self::{{\n{}\n}};\n",
-		mods.iter().map(|mod_name| format!("    {}::*,", mod_name)).join("\n")
-	);
-
-	write_file(&mod_file_contents, &out_dir.join("mod.rs"))?;
-
-	Ok(())
-}
-
-fn write_file(unformatted_contents: &str, final_path: &Path) -> Result<(), Box<dyn Error>> {
-	let temp_file_path = std::env::temp_dir().join("temp.rs");
-	fs::File::create(&temp_file_path)?.write_all(unformatted_contents.as_bytes())?;
-	cargo_fmt(&temp_file_path)?;
-
-	let formatted_contents = fs::read_to_string(temp_file_path)?;
-
-	// Only write file if it has actually changed:
-	if matches!(fs::read_to_string(final_path), Ok(existing_contents) if existing_contents == formatted_contents) {
-		// eprintln!("No change to '{}'", final_path.display());
-		return Ok(());
+	for language in &languages {
+		let backend: Box<dyn backend::Backend> = match language.as_str() {
+			"rust" => Box::new(output::Rust),
+			"cpp" => Box::new(
+				cpp::Cpp::new(&gen)
+					.ok_or_else(|| format!("--lang cpp: '{}' has no C++ equivalent", gen.settings.float_type))?,
+			),
+			"python" => Box::new(python::Python::new(&gen)),
+			"wgsl" => Box::new(shader::Language::Wgsl),
+			"glsl" => Box::new(shader::Language::Glsl),
+			_ => {
+				return Err(format!(
+					"Unknown --lang '{}'. Expected one of: rust, cpp, python, wgsl, glsl",
+					language
+				)
+				.into())
+			}
+		};
+		for (file_name, contents) in backend.files(&gen, &algebra_name) {
+			let contents = if file_name.ends_with(".rs") {
+				output::format_rust(&contents)
+					.map_err(|err| format!("Generated invalid Rust code in {}: {}", file_name, err))?
+			} else {
+				contents
+			};
+			let path = out_dir.join(file_name);
+			if output::write_if_changed(&contents, &path)? {
+				eprintln!("New file written to '{}'", path.display());
+			}
+		}
 	}
 
-	fs::File::create(&final_path)?.write_all(formatted_contents.as_bytes())?;
-	eprintln!("New file written to '{}'", final_path.display());
-
-	Ok(())
-}
-
-fn cargo_fmt(path: &Path) -> Result<(), Box<dyn Error>> {
-	std::process::Command::new("cargo")
-		.arg("fmt")
-		.arg("--")
-		.arg(path)
-		.output()?;
 	Ok(())
 }
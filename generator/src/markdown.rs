@@ -15,17 +15,17 @@ pub fn table(headers: impl IntoIterator<Item = String>, rows: impl IntoIterator<
 	}
 
 	let mut s = vec![];
-	write!(
+	writeln!(
 		&mut s,
-		"| {} |\n",
+		"| {} |",
 		izip!(&headers, &col_widths)
 			.map(|(header, width)| format!("{:<w$}", header, w = width))
 			.format(" | ")
 	)
 	.unwrap();
-	write!(
+	writeln!(
 		&mut s,
-		"| {} |\n",
+		"| {} |",
 		col_widths
 			.iter()
 			.map(|width| format!("{:-<w$}", "", w = width))
@@ -33,9 +33,9 @@ pub fn table(headers: impl IntoIterator<Item = String>, rows: impl IntoIterator<
 	)
 	.unwrap();
 	for row in &rows {
-		write!(
+		writeln!(
 			&mut s,
-			"| {} |\n",
+			"| {} |",
 			izip!(row, &col_widths)
 				.map(|(cell, width)| format!("{:<w$}", cell, w = width))
 				.format(" | ")
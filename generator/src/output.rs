@@ -0,0 +1,221 @@
+//! The Rust code of a whole algebra, as a module with one file per struct.
+//!
+//! This is what the `generator` binary writes, but it can also be called from the `build.rs` of another crate,
+//! so that the generated code doesn't need to be checked in:
+//!
+//! ``` no_run
+//! // build.rs:
+//! let (grammar, types) = generator::grammars::pga3d();
+//! let out_dir = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("pga3d");
+//! generator::generate("pga3d", grammar, types, Default::default(), &out_dir).unwrap();
+//! ```
+//!
+//! ``` ignore
+//! // lib.rs:
+//! pub mod pga3d {
+//!     include!(concat!(env!("OUT_DIR"), "/pga3d/mod.rs"));
+//! }
+//! ```
+use std::{collections::BTreeSet, error::Error, fs, path::Path};
+
+use itertools::Itertools;
+
+use crate::{gen::*, *};
+
+/// Generate the code of an algebra into `out_dir`, e.g. `out_dir/mod.rs`, `out_dir/blades.rs`, `out_dir/line3.rs` etc.
+///
+/// `algebra_name` is the prefix of the C functions and the name of the C header with [`Settings::ffi`].
+/// The code is formatted with [`format_rust`], and files that have not changed are not touched.
+/// This writes the same files as the `generator` binary.
+pub fn generate(
+	algebra_name: &str,
+	grammar: Grammar,
+	types: Types,
+	settings: Settings,
+	out_dir: &Path,
+) -> Result<(), Box<dyn Error>> {
+	let gen = generator(grammar, types, settings)?;
+	fs::create_dir_all(out_dir)?;
+	for (file_name, mut contents) in files(&gen, algebra_name) {
+		if file_name.ends_with(".rs") {
+			contents = format_rust(&contents)
+				.map_err(|err| format!("Generated invalid Rust code in {}: {}", file_name, err))?;
+		}
+		write_if_changed(&contents, &out_dir.join(file_name))?;
+	}
+	Ok(())
+}
+
+/// The Rust [`backend::Backend`]: a module with one file per struct (see [`files`]).
+pub struct Rust;
+
+impl backend::Backend for Rust {
+	fn files(&self, gen: &Generator, algebra_name: &str) -> Vec<(String, String)> {
+		files(gen, algebra_name)
+	}
+}
+
+/// A [`Generator`] of Rust code with the given settings, if they make sense for the types.
+/// Adds the [`MULTIVECTOR`] struct to the types, unless they already have it.
+pub fn generator(grammar: Grammar, mut types: Types, settings: Settings) -> Result<Generator, String> {
+	if settings.generic && types.try_get("T").is_some() {
+		return Err("A blade or struct named 'T' would clash with the generic float type 'T'".into());
+	}
+	if settings.ffi && settings.generic {
+		return Err("ffi needs a concrete float type, so it can't be combined with generic".into());
+	}
+	if types.try_get(MULTIVECTOR).is_none() {
+		types.insert_multivector(MULTIVECTOR);
+	}
+
+	let gen = Generator {
+		grammar,
+		types,
+		ro: RustOptions {
+			generic_float: settings.generic,
+			..RustOptions::rust()
+		},
+		settings,
+	};
+	if gen.settings.ffi && ffi::c_float_type(&gen).is_none() {
+		return Err(format!("ffi: '{}' has no C equivalent", gen.settings.float_type));
+	}
+	Ok(gen)
+}
+
+/// The names and contents of all the files of the algebra. The Rust code is not formatted.
+pub fn files(gen: &Generator, algebra_name: &str) -> Vec<(String, String)> {
+	let mut files = vec![];
+
+	let mut mod_file_contents = include_str!("../templates/lib.rs").to_owned();
+
+	let mut mods = BTreeSet::new();
+	mods.insert("traits".to_string());
+	mods.insert("blades".to_string());
+
+	let mut traits = include_str!("../templates/traits.rs").to_owned();
+	if gen.settings.generic {
+		traits += include_str!("../templates/float.rs");
+	}
+	files.push(("traits.rs".to_owned(), traits));
+	files.push(("blades.rs".to_owned(), blades::file(gen)));
+
+	mod_file_contents += "\n// Types:\n";
+	for (struct_name, strct) in gen.types.structs() {
+		let mod_name = struct_name.to_ascii_lowercase();
+		files.push((format!("{}.rs", mod_name), strct::file(gen, struct_name, strct)));
+		mod_file_contents += &format!("pub mod {};\n", mod_name);
+		mods.insert(mod_name);
+	}
+
+	if let Some(queries) = queries::file(gen) {
+		files.push(("queries.rs".to_owned(), queries));
+		mod_file_contents += "\npub mod queries;\n";
+	}
+
+	if gen.settings.ffi {
+		files.push(("ffi.rs".to_owned(), ffi::file(gen, algebra_name)));
+		files.push((format!("{}.h", algebra_name), ffi::header(gen, algebra_name)));
+		mod_file_contents += "\npub mod ffi;\n";
+	}
+
+	mod_file_contents += &format!(
+		"\npub use self::{{\n{}\n}};\n",
+		mods.iter().map(|mod_name| format!("    {}::*,", mod_name)).join("\n")
+	);
+	files.push(("mod.rs".to_owned(), mod_file_contents));
+
+	files
+}
+
+/// Indent Rust code by the nesting of its brackets, with a tab per level, in-process (without `rustfmt`).
+///
+/// Only the indentation and the blank lines change, so all the comments stay where they are,
+/// but long lines are not wrapped (`cargo fmt` does that for the code that is checked in).
+/// The code is parsed first, so that invalid code is an error.
+pub fn format_rust(code: &str) -> Result<String, syn::Error> {
+	syn::parse_file(code)?;
+
+	let mut formatted = String::new();
+	let mut depth: usize = 0;
+	let mut in_string = false;
+	let mut blank = true;
+	for line in code.lines() {
+		if in_string {
+			// The line is part of a string literal, so it stays exactly as it is:
+			formatted += line;
+			formatted += "\n";
+			in_string = scan_brackets(line, &mut depth, true);
+			blank = false;
+			continue;
+		}
+		let line = line.trim();
+		if line.is_empty() {
+			if !blank {
+				formatted += "\n";
+			}
+			blank = true;
+			continue;
+		}
+		let closing = line.chars().take_while(|c| matches!(c, '}' | ')' | ']')).count();
+		let continuation = line.starts_with('.') && !line.starts_with("..");
+		let indent = depth.saturating_sub(closing) + usize::from(continuation);
+		formatted += &"\t".repeat(indent);
+		formatted += line;
+		formatted += "\n";
+		in_string = scan_brackets(line, &mut depth, false);
+		blank = false;
+	}
+	while formatted.ends_with("\n\n") {
+		formatted.pop();
+	}
+	Ok(formatted)
+}
+
+/// Updates the `depth` by the brackets of the line, skipping comments, strings and characters.
+/// Returns whether the line ends inside a string literal.
+fn scan_brackets(line: &str, depth: &mut usize, mut in_string: bool) -> bool {
+	let chars: Vec<char> = line.chars().collect();
+	let mut i = 0;
+	while i < chars.len() {
+		let c = chars[i];
+		if in_string {
+			match c {
+				'\\' => i += 1,
+				'"' => in_string = false,
+				_ => {}
+			}
+		} else {
+			match c {
+				'/' if chars.get(i + 1) == Some(&'/') => break,
+				'"' => in_string = true,
+				'\'' if chars.get(i + 1) == Some(&'\\') => {
+					// An escaped character, e.g. `'\n'`:
+					i += 2;
+					while i < chars.len() && chars[i] != '\'' {
+						i += 1;
+					}
+				}
+				// A character, e.g. `'{'`, and not a lifetime:
+				'\'' if chars.get(i + 2) == Some(&'\'') => i += 2,
+				'{' | '(' | '[' => *depth += 1,
+				'}' | ')' | ']' => *depth = depth.saturating_sub(1),
+				_ => {}
+			}
+		}
+		i += 1;
+	}
+	in_string
+}
+
+/// Only write the file if it has actually changed, so that `cargo` won't rebuild needlessly.
+/// Returns whether the file was written.
+pub fn write_if_changed(contents: &str, path: &Path) -> std::io::Result<bool> {
+	if matches!(fs::read_to_string(path), Ok(existing_contents) if existing_contents == contents) {
+		return Ok(false);
+	}
+
+	fs::write(path, contents)?;
+
+	Ok(true)
+}
@@ -0,0 +1,185 @@
+//! A Python module with a dataclass for each struct, and a function for each of their products,
+//! unary operations and sandwiches (e.g. `vec4_wedge_vec4(a, b)`).
+//! The classes also have methods and operators that dispatch on the types of the arguments (e.g. `a.wedge(b)` or `a ^ b`).
+//!
+//! The members can be floats, or arrays with element-wise arithmetic (e.g. NumPy arrays) to operate on many values at once.
+use itertools::Itertools;
+
+use crate::{backend::*, gen::Generator, *};
+
+pub struct Python {
+	product_operators: Vec<(Product, Operator)>,
+	unary_operators: Vec<(Unary, Operator)>,
+}
+
+impl Python {
+	pub fn new(gen: &Generator) -> Self {
+		Self {
+			product_operators: gen.ro.product_operators.clone(),
+			unary_operators: gen.ro.unary_operators.clone(),
+		}
+	}
+
+	/// The method and operators of an operation.
+	fn methods(&self, operation: Operation) -> String {
+		let (params, other) = match operation {
+			Operation::Unary(_) => ("self", ""),
+			Operation::Product(_) | Operation::Sandwich(_) => ("self, other", "other"),
+		};
+		let operators: Vec<Operator> = match operation {
+			Operation::Unary(unary) => self
+				.unary_operators
+				.iter()
+				.filter(|(u, _)| *u == unary)
+				.map(|(_, op)| *op)
+				.collect(),
+			Operation::Product(product) => self
+				.product_operators
+				.iter()
+				.filter(|(p, _)| *p == product)
+				.map(|(_, op)| *op)
+				.collect(),
+			Operation::Sandwich(_) => vec![],
+		};
+
+		let mut code = format!(
+			"    def {name}({params}):\n        return _call(\"{name}\", {params})\n",
+			name = operation.name(),
+			params = params,
+		);
+		for operator in operators {
+			code += &format!(
+				"\n    def {}({}):\n        return self.{}({})\n",
+				dunder_name(operator),
+				params,
+				operation.name(),
+				other
+			);
+		}
+		code
+	}
+}
+
+/// E.g. `__mul__` for `a * b`, and `__invert__` for `~a` instead of `!a`.
+fn dunder_name(operator: Operator) -> &'static str {
+	match operator {
+		Operator::Mul => "__mul__",
+		Operator::Div => "__truediv__",
+		Operator::Rem => "__mod__",
+		Operator::BitAnd => "__and__",
+		Operator::BitOr => "__or__",
+		Operator::BitXor => "__xor__",
+		Operator::Shl => "__lshift__",
+		Operator::Shr => "__rshift__",
+		Operator::Not => "__invert__",
+	}
+}
+
+impl Syntax for Python {
+	fn file_extension(&self) -> &'static str {
+		"py"
+	}
+
+	fn declare_struct(&self, struct_name: &str, members: &[(&str, &str)]) -> String {
+		format!(
+			"@dataclass\nclass {}(_Value):\n{}\n",
+			struct_name,
+			members
+				.iter()
+				.map(|(name, blade)| format!("    {}: float = 0.0  # {}", name, blade))
+				.join("\n")
+		)
+	}
+
+	fn member(&self, arg_name: &str, _index: usize, member_name: &str) -> String {
+		format!("{}.{}", arg_name, member_name)
+	}
+
+	fn construct(&self, struct_name: &str, members: &[(&str, String)]) -> String {
+		format!(
+			"{}(\n{}\n    )",
+			struct_name,
+			members
+				.iter()
+				.map(|(name, value)| format!("        {}={},", name, value))
+				.join("\n")
+		)
+	}
+
+	fn function(&self, function: &Function, value: &str) -> String {
+		let output = if function.returns_struct {
+			function.output.as_str()
+		} else {
+			"float"
+		};
+		format!(
+			"def {}({}) -> {}:\n    return {}\n",
+			function.unique_name(),
+			function
+				.arg_names()
+				.iter()
+				.zip(&function.args)
+				.map(|(arg, typ)| format!("{}: {}", arg, typ))
+				.join(", "),
+			output,
+			value
+		)
+	}
+
+	fn file(&self, algebra_name: &str, structs: &[String], functions: &[(Function, String)]) -> String {
+		let methods = functions
+			.iter()
+			.map(|(function, _)| function.operation)
+			.unique()
+			.map(|operation| self.methods(operation))
+			.join("\n");
+
+		let table = functions
+			.iter()
+			.map(|(function, _)| {
+				format!(
+					"    (\"{}\", {}): {},",
+					function.operation.name(),
+					function.args.join(", "),
+					function.unique_name()
+				)
+			})
+			.join("\n");
+
+		format!(
+			r#""""The {algebra_name} geometric algebra: generated types and operations.
+
+The members can be floats, or arrays with element-wise arithmetic (e.g. NumPy arrays) to operate on many values at once.
+"""
+from dataclasses import dataclass
+
+
+class _Value:
+    """The methods and operators of all the types."""
+
+{methods}
+
+{structs}
+
+{functions}
+
+_FUNCTIONS = {{
+{table}
+}}
+
+
+def _call(operation, *args):
+    function = _FUNCTIONS.get((operation,) + tuple(type(arg) for arg in args))
+    if function is None:
+        types = ", ".join(type(arg).__name__ for arg in args)
+        raise TypeError(f"No {{operation}} for {{types}}")
+    return function(*args)
+"#,
+			algebra_name = algebra_name,
+			methods = methods,
+			structs = structs.join("\n\n"),
+			functions = functions.iter().map(|(_, code)| code).join("\n\n"),
+			table = table,
+		)
+	}
+}
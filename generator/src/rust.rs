@@ -1,4 +1,4 @@
-///! Module for formatting as rust code
+//! Module for formatting as rust code
 use itertools::Itertools;
 
 use crate::*;
@@ -10,6 +10,24 @@ pub struct RustOptions {
 
 	/// "Default::default()" or "0"
 	pub zero_expr: String,
+
+	/// Output "x * 2.0" if true, else "2 * x".
+	/// The generated blade types can only be scaled by a float, from the right.
+	pub float_coefficients: bool,
+
+	/// Output a unit blade (e.g. the implicit `w` of a normalized point) as "W(1.0)" if true, else "W".
+	pub unit_blade_values: bool,
+
+	/// The code is generic over `T: Float`, so write "T::from_i32(2)" and "W(T::one())" instead of float literals.
+	pub generic_float: bool,
+
+	/// Which `std::ops` operators to implement for which products,
+	/// e.g. `(Product::Wedge, Operator::BitXor)` makes `a ^ b` call `a.wedge(b)`.
+	pub product_operators: Vec<(Product, Operator)>,
+
+	/// Which `std::ops` operators to implement for which unary operations,
+	/// e.g. `(Unary::RCompl, Operator::Not)` makes `!a` call `a.rcompl()`.
+	pub unary_operators: Vec<(Unary, Operator)>,
 }
 
 impl RustOptions {
@@ -17,14 +35,126 @@ impl RustOptions {
 		Self {
 			operators: false,
 			zero_expr: "Default::default()".to_owned(),
+			float_coefficients: true,
+			unit_blade_values: true,
+			generic_float: false,
+			product_operators: vec![
+				(Product::Geometric, Operator::Mul),
+				(Product::Dot, Operator::BitOr),
+				(Product::Wedge, Operator::BitXor),
+				(Product::AntiWedge, Operator::BitAnd),
+			],
+			unary_operators: vec![(Unary::RCompl, Operator::Not)],
 		}
 	}
 	pub fn readable() -> Self {
 		Self {
 			operators: true,
 			zero_expr: "0".to_owned(),
+			float_coefficients: false,
+			unit_blade_values: false,
+			generic_float: false,
+			product_operators: vec![],
+			unary_operators: vec![],
+		}
+	}
+
+	/// A float literal, e.g. "2.0", or "T::from_i32(2)" for generic code.
+	pub fn float_literal(&self, value: i32) -> String {
+		if !self.generic_float {
+			format!("{}.0", value)
+		} else if value == 1 {
+			"T::one()".to_owned()
+		} else {
+			format!("T::from_i32({})", value)
+		}
+	}
+
+	pub fn product_operators(&self, product: Product) -> impl Iterator<Item = Operator> + '_ {
+		self.product_operators
+			.iter()
+			.filter(move |(p, _)| *p == product)
+			.map(|(_, op)| *op)
+	}
+
+	pub fn unary_operators(&self, unary: Unary) -> impl Iterator<Item = Operator> + '_ {
+		self.unary_operators
+			.iter()
+			.filter(move |(u, _)| *u == unary)
+			.map(|(_, op)| *op)
+	}
+}
+
+/// An overloadable operator in `std::ops`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operator {
+	/// `a * b`
+	Mul,
+	/// `a / b`
+	Div,
+	/// `a % b`
+	Rem,
+	/// `a & b`
+	BitAnd,
+	/// `a | b`
+	BitOr,
+	/// `a ^ b`
+	BitXor,
+	/// `a << b`
+	Shl,
+	/// `a >> b`
+	Shr,
+	/// `!a` (unary)
+	Not,
+}
+
+impl Operator {
+	pub fn trait_name(self) -> &'static str {
+		match self {
+			Operator::Mul => "Mul",
+			Operator::Div => "Div",
+			Operator::Rem => "Rem",
+			Operator::BitAnd => "BitAnd",
+			Operator::BitOr => "BitOr",
+			Operator::BitXor => "BitXor",
+			Operator::Shl => "Shl",
+			Operator::Shr => "Shr",
+			Operator::Not => "Not",
+		}
+	}
+
+	pub fn trait_function_name(self) -> &'static str {
+		match self {
+			Operator::Mul => "mul",
+			Operator::Div => "div",
+			Operator::Rem => "rem",
+			Operator::BitAnd => "bitand",
+			Operator::BitOr => "bitor",
+			Operator::BitXor => "bitxor",
+			Operator::Shl => "shl",
+			Operator::Shr => "shr",
+			Operator::Not => "not",
+		}
+	}
+
+	/// E.g. `*`, the same in Rust and C++.
+	pub fn symbol(self) -> &'static str {
+		match self {
+			Operator::Mul => "*",
+			Operator::Div => "/",
+			Operator::Rem => "%",
+			Operator::BitAnd => "&",
+			Operator::BitOr => "|",
+			Operator::BitXor => "^",
+			Operator::Shl => "<<",
+			Operator::Shr => ">>",
+			Operator::Not => "!",
 		}
 	}
+
+	pub fn is_unary(self) -> bool {
+		matches!(self, Operator::Not)
+	}
 }
 
 struct RustExpr(Precedence, String);
@@ -62,7 +192,40 @@ impl Expr {
 
 	fn rust_expr(&self, ro: &RustOptions) -> RustExpr {
 		match self {
+			Expr::Var {
+				name,
+				typ: Type::Constant(_),
+				..
+			} if ro.unit_blade_values => RustExpr::atom(format!("{}({})", name, ro.float_literal(1))),
 			Expr::Var { name, .. } => RustExpr::atom(name),
+			Expr::Coordinates {
+				vars,
+				type_name: Some(type_name),
+				..
+			} => RustExpr::atom(format!(
+				"{}({})",
+				type_name,
+				vars.iter().map(|(_, name)| format!("{}.0", name)).join(" * ")
+			)),
+			Expr::Coordinates {
+				vars,
+				sblade,
+				type_name: None,
+			} => {
+				// A blade without a name is written as the wedge of its base vectors, like an `Expr::Vec`:
+				let blade = Expr::Prod(
+					Product::Wedge,
+					sblade.blade.vecs().iter().map(|vi| Expr::Vec(*vi)).collect(),
+				);
+				RustExpr(
+					Precedence::Product,
+					format!(
+						"{} * {}",
+						vars.iter().map(|(_, name)| format!("{}.0", name)).join(" * "),
+						blade.rust_expr(ro).enclose_if_less(Precedence::Atom)
+					),
+				)
+			}
 			Expr::Vec(vi) => {
 				//  You should call expr.typify() before .rust(ro) to get more readable vector names
 				RustExpr::atom(format!("_e{}", vi.0))
@@ -75,6 +238,15 @@ impl Expr {
 						Precedence::Product,
 						format!("-{}", expr.rust_expr(ro).enclose_if_less(Precedence::Product)),
 					)
+				} else if ro.float_coefficients {
+					RustExpr(
+						Precedence::Product,
+						format!(
+							"{} * {}",
+							expr.rust_expr(ro).enclose_if_less(Precedence::Product),
+							ro.float_literal(*s)
+						),
+					)
 				} else {
 					RustExpr(
 						Precedence::Product,
@@ -107,9 +279,12 @@ impl Expr {
 			}
 			Expr::Prod(product, factors) => {
 				if factors.is_empty() {
-					match product {
-						Product::Geometric | Product::Wedge | Product::Dot => RustExpr::atom("1"),
-						_ => todo!(),
+					if product.is_anti() {
+						// The identity of the anti-products is the pseudo-scalar, which is the right complement of one.
+						// Without a grammar we don't know its name (`simplify` replaces it with the blade if it can).
+						Expr::unary(Unary::RCompl, Expr::one()).rust_expr(ro)
+					} else {
+						RustExpr::atom("1")
 					}
 				} else if factors.len() == 1 {
 					factors[0].rust_expr(ro)
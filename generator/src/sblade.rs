@@ -120,12 +120,73 @@ impl SBlade {
 		self.lcompl(g).reverse().rcompl(g)
 	}
 
+	/// Grade involution: negate the odd grades.
+	/// e1.involute()   = -e1
+	/// e12.involute()  = e12
+	/// e012.involute() = -e012
+	pub fn involute(mut self) -> Self {
+		if self.grade() % 2 == 1 {
+			self.sign = -self.sign;
+		}
+		self
+	}
+
+	/// Clifford conjugate: the reverse of the grade involution.
+	/// e1.conjugate()   = -e1
+	/// e12.conjugate()  = -e12
+	/// e012.conjugate() = e012
+	pub fn conjugate(self) -> Self {
+		self.involute().reverse()
+	}
+
+	/// Poincaré dual: the right complement for the lower half of the grades,
+	/// and the left complement for the upper half,
+	/// so that e.g. e1.dual() = e032 and e032.dual() = e1 in 3D PGA.
+	pub fn dual(&self, g: &Grammar) -> Self {
+		if 2 * self.grade() <= g.num_vecs() {
+			self.rcompl(g)
+		} else {
+			self.lcompl(g)
+		}
+	}
+
+	/// The blade times the product of the squares of its vectors (the metric),
+	/// e.g. the blades without the degenerate base vector, `X Y Z`, but not `W`.
+	pub fn bulk(&self, g: &Grammar) -> Self {
+		let sign: i32 = self.blade.vecs().iter().map(|&vi| g.square_geom(vi)).product();
+		SBlade {
+			blade: self.blade.clone(),
+			sign: self.sign * sign,
+		}
+	}
+
+	/// The complement of the bulk of the complement (the anti-metric),
+	/// e.g. the blades with the degenerate base vector, `W`, but not `X Y Z`.
+	pub fn weight(&self, g: &Grammar) -> Self {
+		self.rcompl(g).bulk(g).lcompl(g)
+	}
+
+	pub fn bulk_dual(&self, g: &Grammar) -> Self {
+		self.bulk(g).rcompl(g)
+	}
+
+	pub fn weight_dual(&self, g: &Grammar) -> Self {
+		self.weight(g).rcompl(g)
+	}
+
 	pub fn unary(&self, unary: Unary, g: &Grammar) -> Self {
 		match unary {
 			Unary::LCompl => self.lcompl(g),
 			Unary::RCompl => self.rcompl(g),
 			Unary::Reverse => self.clone().reverse(),
 			Unary::AntiReverse => self.anti_reverse(g),
+			Unary::Involute => self.clone().involute(),
+			Unary::Conjugate => self.clone().conjugate(),
+			Unary::Dual => self.dual(g),
+			Unary::Bulk => self.bulk(g),
+			Unary::Weight => self.weight(g),
+			Unary::BulkDual => self.bulk_dual(g),
+			Unary::WeightDual => self.weight_dual(g),
 		}
 	}
 
@@ -153,7 +214,7 @@ impl SBlade {
 	pub fn dot_product(&self, other: &SBlade, g: &Grammar) -> Self {
 		// The dot product is the K grade of the geometric product,
 		// where K is the absolute difference in grades between the operands.
-		let k = ((self.grade() as i64) - (other.grade() as i64)).abs() as usize;
+		let k = ((self.grade() as i64) - (other.grade() as i64)).unsigned_abs() as usize;
 		let prod = self.geometric_product(other, g);
 		if prod.blade.grade() > k {
 			Self::zero()
@@ -178,6 +239,55 @@ impl SBlade {
 		self.lcompl(g).wedge_product(&other.lcompl(g), g).rcompl(g)
 	}
 
+	/// left contraction / left interior product
+	pub fn left_contraction(&self, other: &SBlade, g: &Grammar) -> Self {
+		if self.grade() > other.grade() {
+			return Self::zero();
+		}
+		let prod = self.geometric_product(other, g);
+		if prod.blade.grade() == other.grade() - self.grade() {
+			prod
+		} else {
+			Self::zero()
+		}
+	}
+
+	/// right contraction / right interior product
+	pub fn right_contraction(&self, other: &SBlade, g: &Grammar) -> Self {
+		other
+			.clone()
+			.reverse()
+			.left_contraction(&self.clone().reverse(), g)
+			.reverse()
+	}
+
+	/// The scalar part of the geometric product
+	pub fn scalar_product(&self, other: &SBlade, g: &Grammar) -> Self {
+		let prod = self.geometric_product(other, g);
+		if prod.blade.is_scalar() {
+			prod
+		} else {
+			Self::zero()
+		}
+	}
+
+	/// (a * b - b * a) / 2
+	pub fn commutator_product(&self, other: &SBlade, g: &Grammar) -> Self {
+		let ab = self.geometric_product(other, g);
+		let ba = other.geometric_product(self, g);
+		if ab.sign == -ba.sign {
+			ab
+		} else {
+			Self::zero() // they commute
+		}
+	}
+
+	pub fn anti_dot_product(&self, other: &SBlade, g: &Grammar) -> Self {
+		self.lcompl(g).dot_product(&other.lcompl(g), g).rcompl(g)
+	}
+
+	/// The product of two blades, assuming a diagonal metric (or a product that doesn't use the metric).
+	/// See [`Self::binary_product_terms`] for the general case.
 	pub fn binary_product(a: &SBlade, product: Product, b: &SBlade, g: &Grammar) -> Self {
 		match product {
 			Product::Geometric => a.geometric_product(b, g),
@@ -185,12 +295,26 @@ impl SBlade {
 			Product::Dot => a.dot_product(b, g),
 			Product::Wedge => a.wedge_product(b, g),
 			Product::AntiWedge => a.antiwedge_product(b, g),
+			Product::LeftContraction => a.left_contraction(b, g),
+			Product::RightContraction => a.right_contraction(b, g),
+			Product::ScalarProduct => a.scalar_product(b, g),
+			Product::Commutator => a.commutator_product(b, g),
+			Product::AntiDot => a.anti_dot_product(b, g),
+		}
+	}
+
+	/// The product of no operands: one, or the pseudo-scalar for the anti-products.
+	pub fn identity(product: Product, g: &Grammar) -> Self {
+		if product.is_anti() {
+			Self::pseudo_scalar(g)
+		} else {
+			Self::one()
 		}
 	}
 
 	pub fn product(product: Product, operands: &[SBlade], g: &Grammar) -> Self {
 		if operands.is_empty() {
-			SBlade::one() // TODO: is this correct for antiwedge?
+			Self::identity(product, g)
 		} else {
 			let mut result = operands[0].clone();
 			for operand in operands.iter().skip(1) {
@@ -201,6 +325,137 @@ impl SBlade {
 	}
 }
 
+impl SBlade {
+	/// Like [`Self::binary_product`], but also for grammars with a non-diagonal metric,
+	/// where the product of two blades can be a sum of blades.
+	/// Returns the non-zero terms of that sum, with the coefficients as their `sign`.
+	pub fn binary_product_terms(a: &SBlade, product: Product, b: &SBlade, g: &Grammar) -> Vec<SBlade> {
+		if g.is_diagonal() || !product.uses_metric() {
+			let prod = Self::binary_product(a, product, b, g);
+			return if prod.is_zero() { vec![] } else { vec![prod] };
+		}
+
+		let geometric = || geometric_terms(a, b, g);
+		let grade_part = |grade: usize| geometric().into_iter().filter(|t| t.grade() == grade).collect();
+		let anti = |product: Product| {
+			Self::binary_product_terms(&a.lcompl(g), product, &b.lcompl(g), g)
+				.into_iter()
+				.map(|t| t.rcompl(g))
+				.collect()
+		};
+
+		let (ga, gb) = (a.grade(), b.grade());
+		match product {
+			Product::Geometric => geometric(),
+			Product::AntiGeometric => anti(Product::Geometric),
+			Product::Dot => grade_part(((ga as i64) - (gb as i64)).unsigned_abs() as usize),
+			Product::LeftContraction if ga <= gb => grade_part(gb - ga),
+			Product::RightContraction if ga >= gb => grade_part(ga - gb),
+			Product::LeftContraction | Product::RightContraction => vec![],
+			Product::ScalarProduct => grade_part(0),
+			Product::Commutator => {
+				let ab = geometric();
+				let ba = geometric_terms(b, a, g);
+				let diff = collect_terms(ab.into_iter().chain(ba.into_iter().map(SBlade::negate)));
+				diff.into_iter()
+					.map(|t| {
+						assert!(t.sign % 2 == 0, "Expected an even commutator, got {:?}", t);
+						SBlade {
+							sign: t.sign / 2,
+							blade: t.blade,
+						}
+					})
+					.collect()
+			}
+			Product::AntiDot => anti(Product::Dot),
+			Product::Wedge | Product::AntiWedge => unreachable!(),
+		}
+	}
+
+	/// Like [`Self::product`], but returns a sum of blades. See [`Self::binary_product_terms`].
+	pub fn product_terms(product: Product, operands: &[SBlade], g: &Grammar) -> Vec<SBlade> {
+		if operands.is_empty() {
+			vec![Self::identity(product, g)]
+		} else {
+			let mut terms = vec![operands[0].clone()];
+			for operand in operands.iter().skip(1) {
+				terms = collect_terms(
+					terms
+						.iter()
+						.flat_map(|term| Self::binary_product_terms(term, product, operand, g)),
+				);
+			}
+			terms
+		}
+	}
+}
+
+/// The geometric product of two blades for any symmetric metric, as a sum of blades.
+fn geometric_terms(a: &SBlade, b: &SBlade, g: &Grammar) -> Vec<SBlade> {
+	geometric_terms_sorted(a.blade.vecs(), b.blade.vecs(), g)
+		.into_iter()
+		.map(|t| (a.sign * b.sign) * t)
+		.collect()
+}
+
+fn geometric_terms_sorted(a: &[VecIdx], b: &[VecIdx], g: &Grammar) -> Vec<SBlade> {
+	match a.split_first() {
+		None => vec![SBlade::from_sorted(b)],
+		Some((&first, rest)) => {
+			// a = first ^ rest = first * rest - first ⌋ rest
+			// => a * b = first * (rest * b) - (first ⌋ rest) * b
+			let first_times_rest_b = geometric_terms_sorted(rest, b, g).into_iter().flat_map(|t| {
+				vec_product_terms(first, t.blade.vecs(), g)
+					.into_iter()
+					.map(move |u| t.sign * u)
+			});
+			let contraction_b = vec_contraction_terms(first, rest, g).into_iter().flat_map(|c| {
+				geometric_terms_sorted(c.blade.vecs(), b, g)
+					.into_iter()
+					.map(move |t| -c.sign * t)
+			});
+			collect_terms(first_times_rest_b.chain(contraction_b))
+		}
+	}
+}
+
+/// `v * b = v ⌋ b + v ^ b`
+fn vec_product_terms(v: VecIdx, b: &[VecIdx], g: &Grammar) -> Vec<SBlade> {
+	let mut terms = vec_contraction_terms(v, b, g);
+	if !b.contains(&v) {
+		let vecs: Vec<VecIdx> = std::iter::once(v).chain(b.iter().copied()).collect();
+		terms.push(SBlade::from_unsorted(&vecs));
+	}
+	terms
+}
+
+/// `v ⌋ (b0 ^ b1 ^ …) = (v·b0) (b1 ^ …) - (v·b1) (b0 ^ …) + …`
+fn vec_contraction_terms(v: VecIdx, b: &[VecIdx], g: &Grammar) -> Vec<SBlade> {
+	let mut terms = vec![];
+	for (i, &bi) in b.iter().enumerate() {
+		let dot = g.dot(v, bi);
+		if dot != 0 {
+			let mut rest = b.to_vec();
+			rest.remove(i);
+			let sign = if i % 2 == 0 { dot } else { -dot };
+			terms.push(SBlade::signed_blade(sign, Blade::from_sorted(rest)));
+		}
+	}
+	terms
+}
+
+/// Add up terms of the same blade, and drop the zeros.
+fn collect_terms(terms: impl IntoIterator<Item = SBlade>) -> Vec<SBlade> {
+	let mut sums: std::collections::BTreeMap<Blade, i32> = Default::default();
+	for term in terms {
+		*sums.entry(term.blade).or_default() += term.sign;
+	}
+	sums.into_iter()
+		.filter(|(_, sign)| *sign != 0)
+		.map(|(blade, sign)| SBlade::signed_blade(sign, blade))
+		.collect()
+}
+
 impl std::ops::Mul<SBlade> for i32 {
 	type Output = SBlade;
 	fn mul(self, right: SBlade) -> Self::Output {
@@ -276,8 +531,8 @@ pub mod tests {
 			SBlade::zero()
 		} else if s == "s" {
 			SBlade::scalar()
-		} else if s.starts_with('e') {
-			let vecs: Vec<VecIdx> = s[1..].chars().map(idx_from_char).collect();
+		} else if let Some(digits) = s.strip_prefix('e') {
+			let vecs: Vec<VecIdx> = digits.chars().map(idx_from_char).collect();
 			SBlade::from_unsorted(&vecs)
 		} else {
 			panic!("Expected 'e' followed by digits (e.g. e21), found '{}'", s)
@@ -286,7 +541,7 @@ pub mod tests {
 
 	#[test]
 	fn test_sblade() {
-		let grammar = Grammar(vec![0, 1, 1, 1]);
+		let grammar = Grammar::new(vec![0, 1, 1, 1]);
 		let g = &grammar;
 		let v0 = VecIdx(0);
 		let v1 = VecIdx(1);
@@ -304,5 +559,82 @@ pub mod tests {
 		assert_eq!(sb("e1").rcompl(g), sb("e203"));
 		assert_eq!(sb("e01").rcompl(g), sb("e23"));
 		assert_eq!(SBlade::pseudo_scalar(g).rcompl(g), s);
+
+		let product = |a: &str, product: Product, b: &str| SBlade::binary_product(&sb(a), product, &sb(b), g);
+		assert_eq!(product("e1", Product::LeftContraction, "e12"), sb("e2"));
+		assert_eq!(product("e2", Product::LeftContraction, "e12"), sb("e1").negate());
+		assert!(product("e12", Product::LeftContraction, "e1").is_zero());
+		assert_eq!(product("e12", Product::RightContraction, "e2"), sb("e1"));
+		assert_eq!(product("e12", Product::RightContraction, "e1"), sb("e2").negate());
+		assert!(product("e1", Product::RightContraction, "e12").is_zero());
+		assert_eq!(
+			product("e12", Product::ScalarProduct, "e12"),
+			sb("e12").geometric_product(&sb("e12"), g)
+		);
+		assert!(product("e1", Product::ScalarProduct, "e12").is_zero());
+		assert_eq!(product("e1", Product::Commutator, "e2"), sb("e12"));
+		assert!(product("e1", Product::Commutator, "e1").is_zero());
+		assert!(product("e12", Product::Commutator, "e03").is_zero()); // they commute
+		assert_eq!(product("e12", Product::Commutator, "e23"), sb("e13"));
+		assert_eq!(
+			product("e1", Product::AntiDot, "e1"),
+			sb("e1").lcompl(g).dot_product(&sb("e1").lcompl(g), g).rcompl(g)
+		);
+	}
+
+	#[test]
+	fn test_product_terms() {
+		let all_blades = |g: &Grammar| -> Vec<SBlade> {
+			(0..1 << g.num_vecs())
+				.map(|bits: usize| {
+					SBlade::from_sorted(&g.vecs().filter(|vi| bits & (1 << vi.0) != 0).collect::<Vec<_>>())
+				})
+				.collect()
+		};
+
+		// With a diagonal metric, the general product is the same as the simple one:
+		let g = &Grammar::new(vec![1, -1, 0, 1]);
+		let blades = all_blades(g);
+		for a in &blades {
+			for b in &blades {
+				let prod = a.geometric_product(b, g);
+				let expected = if prod.is_zero() { vec![] } else { vec![prod] };
+				assert_eq!(geometric_terms(a, b, g), expected, "{:?} * {:?}", a, b);
+			}
+		}
+
+		// Two null vectors, like the origin and infinity of conformal geometric algebra:
+		let g = &Grammar::new(vec![0, 0]).with_dot(VecIdx(0), VecIdx(1), -1);
+		let product = |a: &str, product: Product, b: &str| SBlade::binary_product_terms(&sb(a), product, &sb(b), g);
+		assert_eq!(product("e0", Product::Geometric, "e0"), vec![]);
+		assert_eq!(product("e0", Product::Geometric, "e1"), vec![-1 * sb("s"), sb("e01")]);
+		assert_eq!(product("e1", Product::Geometric, "e0"), vec![-1 * sb("s"), sb("e10")]);
+		assert_eq!(product("e0", Product::Dot, "e1"), vec![-1 * sb("s")]);
+		assert_eq!(product("e0", Product::Commutator, "e1"), vec![sb("e01")]);
+		assert_eq!(product("e01", Product::Geometric, "e01"), vec![sb("s")]);
+		assert_eq!(product("e01", Product::Geometric, "e1"), vec![sb("e1")]);
+		assert_eq!(product("e0", Product::LeftContraction, "e01"), vec![sb("e0")]);
+
+		// The general product must be associative:
+		let g = &Grammar::new(vec![1, 1, 0, 0]).with_dot(VecIdx(2), VecIdx(3), -1);
+		let blades = all_blades(g);
+		for a in &blades {
+			for b in &blades {
+				for c in &blades {
+					assert_eq!(
+						SBlade::product_terms(Product::Geometric, &[a.clone(), b.clone(), c.clone()], g),
+						collect_terms(
+							SBlade::product_terms(Product::Geometric, &[b.clone(), c.clone()], g)
+								.iter()
+								.flat_map(|bc| SBlade::binary_product_terms(a, Product::Geometric, bc, g))
+						),
+						"{:?} * {:?} * {:?}",
+						a,
+						b,
+						c
+					);
+				}
+			}
+		}
 	}
 }
@@ -0,0 +1,109 @@
+//! WGSL and GLSL code for the structs, with a function for each of their products,
+//! unary operations and sandwiches, e.g. `motor3_sandwich_vec4(a: Motor3, b: Vec4) -> Vec4`.
+//!
+//! All floats are `f32`, and the members of a struct are packed four at a time into `vec4` fields (`v0`, `v1`, …),
+//! in the order of the [`crate::Types`], padded with zeros.
+use itertools::Itertools;
+
+use crate::backend::*;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Language {
+	Wgsl,
+	Glsl,
+}
+
+impl Language {
+	fn float(self) -> &'static str {
+		match self {
+			Language::Wgsl => "f32",
+			Language::Glsl => "float",
+		}
+	}
+
+	fn vec4(self) -> &'static str {
+		match self {
+			Language::Wgsl => "vec4<f32>",
+			Language::Glsl => "vec4",
+		}
+	}
+}
+
+impl Syntax for Language {
+	fn file_extension(&self) -> &'static str {
+		match self {
+			Language::Wgsl => "wgsl",
+			Language::Glsl => "glsl",
+		}
+	}
+
+	fn declare_struct(&self, struct_name: &str, members: &[(&str, &str)]) -> String {
+		let comment = members
+			.chunks(4)
+			.enumerate()
+			.map(|(i, chunk)| format!("v{} = ({})", i, chunk.iter().map(|(name, _)| name).join(", ")))
+			.join(", ");
+		let fields = (0..members.len().div_ceil(4)).map(|i| match self {
+			Language::Wgsl => format!("\tv{}: {},", i, self.vec4()),
+			Language::Glsl => format!("\t{} v{};", self.vec4(), i),
+		});
+		let end = match self {
+			Language::Wgsl => "}",
+			Language::Glsl => "};",
+		};
+		format!(
+			"// {}\nstruct {} {{\n{}\n{}\n",
+			comment,
+			struct_name,
+			fields.format("\n"),
+			end
+		)
+	}
+
+	fn member(&self, arg_name: &str, index: usize, _member_name: &str) -> String {
+		format!("{}.v{}.{}", arg_name, index / 4, ["x", "y", "z", "w"][index % 4])
+	}
+
+	fn construct(&self, struct_name: &str, members: &[(&str, String)]) -> String {
+		let mut values: Vec<&str> = members.iter().map(|(_, value)| value.as_str()).collect();
+		values.resize(members.len().div_ceil(4) * 4, "0.0");
+		let fields = values
+			.chunks(4)
+			.map(|chunk| format!("{}(\n\t\t\t{}\n\t\t)", self.vec4(), chunk.join(",\n\t\t\t")));
+		format!("{}(\n\t\t{}\n\t)", struct_name, fields.format(",\n\t\t"))
+	}
+
+	fn function(&self, function: &Function, value: &str) -> String {
+		let args = function.arg_names().iter().zip(&function.args);
+		let output = if function.returns_struct {
+			function.output.as_str()
+		} else {
+			self.float()
+		};
+		match self {
+			Language::Wgsl => format!(
+				"fn {}({}) -> {} {{\n\treturn {};\n}}\n",
+				function.unique_name(),
+				args.map(|(arg, typ)| format!("{}: {}", arg, typ)).format(", "),
+				output,
+				value
+			),
+			Language::Glsl => format!(
+				"{} {}({}) {{\n\treturn {};\n}}\n",
+				output,
+				function.unique_name(),
+				args.map(|(arg, typ)| format!("{} {}", typ, arg)).format(", "),
+				value
+			),
+		}
+	}
+
+	fn file(&self, algebra_name: &str, structs: &[String], functions: &[(Function, String)]) -> String {
+		format!(
+			"// The {} geometric algebra: generated types and operations.\n\n{}\n{}",
+			algebra_name,
+			structs.join("\n"),
+			functions.iter().map(|(_, code)| code).join("\n")
+		)
+	}
+}
@@ -48,6 +48,7 @@ impl Expr {
 			.simplify(g),
 			Expr::Var { .. } => self,
 			Expr::Vec(_) => self,
+			Expr::Coordinates { .. } => self,
 			Expr::Unary(unary, expr) => {
 				let expr = expr.simplify(g);
 
@@ -73,8 +74,19 @@ impl Expr {
 						Expr::Term(Expr::Unary(unary, expr).into(), scalar).simplify(g)
 					}
 
+					// Only the blade changes, e.g. `E1(x.0 * y.0).rcompl() = E23(x.0 * y.0)`:
+					Expr::Coordinates { vars, sblade, .. } if g.is_some() => {
+						let sblade = sblade.unary(unary, g.unwrap());
+						let coordinates = Expr::Coordinates {
+							vars,
+							sblade: SBlade::unit(sblade.blade),
+							type_name: None,
+						};
+						Expr::Term(coordinates.into(), sblade.sign).simplify(g)
+					}
+
 					// e.g. x.lcompl().rcompl() => x
-					Expr::Unary(inner_unary, expr) if inner_unary == unary.undoer() => *expr,
+					Expr::Unary(inner_unary, expr) if Some(inner_unary) == unary.undoer() => *expr,
 
 					// distributive property
 					// (a + b).unary() = a.unary() + b.unary()
@@ -177,7 +189,7 @@ impl Expr {
 				factors.push(Expr::scalar(scalar));
 				factors
 			}
-			Expr::Prod(product, factors) if product == desired_product_type => factors,
+			Expr::Prod(product, factors) if product == desired_product_type && product.is_associative() => factors,
 			expr => vec![expr],
 		}
 	}
@@ -226,6 +238,12 @@ fn simplify_product(product: Product, factors: Vec<Expr>, g: Option<&Grammar>) -
 	let mut scalar = new_scalar;
 	let mut factors = new_factors;
 
+	if let Some(g) = g {
+		if !g.is_diagonal() && product.uses_metric() && !factors.is_empty() {
+			return expand_coordinates(product, scalar, factors, g);
+		}
+	}
+
 	// eprintln!("simplify_product {} * {:?} {:?}", scalar, product, factors);
 
 	scalar *= sort_factors(product, &mut factors, g);
@@ -245,9 +263,13 @@ fn simplify_product(product: Product, factors: Vec<Expr>, g: Option<&Grammar>) -
 		Expr::zero()
 	} else if scalar == 1 {
 		if factors.is_empty() {
-			match product {
-				Product::Geometric | Product::Wedge | Product::Dot => Expr::one(),
-				_ => Expr::Prod(product, factors), // TODO
+			if !product.is_anti() {
+				Expr::one()
+			} else if let Some(g) = g {
+				// The pseudo-scalar is the identity of the anti-products:
+				Expr::sblade(&SBlade::pseudo_scalar(g))
+			} else {
+				Expr::Prod(product, factors)
 			}
 		} else if factors.len() == 1 {
 			factors.remove(0)
@@ -259,6 +281,85 @@ fn simplify_product(product: Product, factors: Vec<Expr>, g: Option<&Grammar>) -
 	}
 }
 
+/// With a non-diagonal metric the product of two blades can be a sum of blades,
+/// so we expand the product into one [`Expr::Coordinates`] for each of those blades.
+#[must_use]
+fn expand_coordinates(product: Product, scalar: i32, factors: Vec<Expr>, g: &Grammar) -> Expr {
+	let coordinates = factors
+		.iter()
+		.map(|factor| factor.as_coordinates(g))
+		.collect::<Option<Vec<_>>>();
+	let coordinates = match coordinates {
+		Some(coordinates) => coordinates,
+		None => {
+			// Not a product of blades, so we can't do much:
+			return Expr::Term(Expr::Prod(product, factors).into(), scalar).simplify(None);
+		}
+	};
+
+	let mut vars = vec![];
+	let mut sblades = vec![];
+	for (factor_vars, sblade) in coordinates {
+		vars.extend(factor_vars);
+		sblades.push(sblade);
+	}
+	vars.sort();
+
+	Expr::Sum(
+		SBlade::product_terms(product, &sblades, g)
+			.into_iter()
+			.map(|term| {
+				let unit = SBlade::unit(term.blade);
+				let value = if vars.is_empty() {
+					Expr::sblade(&unit)
+				} else {
+					Expr::Coordinates {
+						vars: vars.clone(),
+						sblade: unit,
+						type_name: None,
+					}
+				};
+				Expr::Term(value.into(), scalar * term.sign)
+			})
+			.collect(),
+	)
+	.simplify(Some(g))
+}
+
+impl Expr {
+	/// This expression as the product of the coordinates of some blade variables times a blade,
+	/// if possible, e.g. `2 * (x ^ y)` is `(x.0 * y.0) * 2 * XY`.
+	fn as_coordinates(&self, g: &Grammar) -> Option<(Vec<(usize, String)>, SBlade)> {
+		match self {
+			Expr::Var {
+				order,
+				name,
+				typ: Type::SBlade(sblade),
+			} => Some((vec![(*order, name.clone())], sblade.clone())),
+			Expr::Coordinates { vars, sblade, .. } => Some((vars.clone(), sblade.clone())),
+			Expr::Term(expr, scalar) => {
+				let (vars, sblade) = expr.as_coordinates(g)?;
+				Some((vars, *scalar * sblade))
+			}
+			Expr::Unary(unary, expr) => {
+				let (vars, sblade) = expr.as_coordinates(g)?;
+				Some((vars, sblade.unary(*unary, g)))
+			}
+			Expr::Prod(product, factors) if !product.uses_metric() => {
+				let mut vars = vec![];
+				let mut sblades = vec![];
+				for factor in factors {
+					let (factor_vars, sblade) = factor.as_coordinates(g)?;
+					vars.extend(factor_vars);
+					sblades.push(sblade);
+				}
+				Some((vars, single_sblade(SBlade::product_terms(*product, &sblades, g))?))
+			}
+			_ => Some((vec![], self.as_sblade(g)?)),
+		}
+	}
+}
+
 #[must_use]
 fn sort_factors(product: Product, factors: &mut [Expr], g: Option<&Grammar>) -> i32 {
 	// Any sign-change due to swapping
@@ -361,8 +462,9 @@ fn commutativeness(product: Product, l: Option<Type>, r: Option<Type>, g: Option
 	let r = r?.into_sblade()?;
 	let lr = SBlade::binary_product(&l, product, &r, g?);
 	let rl = SBlade::binary_product(&r, product, &l, g?);
-	assert_eq!(lr.is_zero(), rl.is_zero());
-	assert_eq!(lr.blade, rl.blade);
+	if lr.is_zero() != rl.is_zero() || (!lr.is_zero() && lr.blade != rl.blade) {
+		return None; // e.g. a contraction
+	}
 	assert_eq!(lr.sign.abs(), rl.sign.abs());
 	if lr.is_zero() {
 		Some(0)
@@ -54,8 +54,7 @@ impl Type {
 
 	pub fn is_blade(&self, blade: &Blade) -> bool {
 		match self {
-			Type::Constant(_) => todo!(),
-			Type::SBlade(sb) => sb.blade == *blade,
+			Type::Constant(sb) | Type::SBlade(sb) => sb.blade == *blade,
 			Type::Struct(_) => false,
 		}
 	}
@@ -97,7 +96,14 @@ impl Expr {
 				} else {
 					let mut types = std::collections::BTreeSet::new();
 					for e in terms {
-						types.insert(e.typ(g)?);
+						// The sum of constants is not a constant,
+						// and the sum of `X` and `-X` is still an `X`:
+						types.insert(match e.typ(g)? {
+							Type::Constant(sblade) | Type::SBlade(sblade) if !sblade.is_zero() => {
+								Type::SBlade(SBlade::unit(sblade.blade))
+							}
+							typ => typ,
+						});
 					}
 					assert!(!types.is_empty());
 					if types.len() == 1 {
@@ -109,6 +115,7 @@ impl Expr {
 			}
 			Expr::Prod(product, factors) => product_type(*product, factors, g),
 			Expr::StructInstance(StructInstance { strct, .. }) => Some(Type::strct(strct)),
+			Expr::Coordinates { sblade, .. } => Some(Type::SBlade(sblade.clone())),
 		}
 	}
 }
@@ -127,10 +134,21 @@ fn product_type(product: Product, factors: &[Expr], g: Option<&Grammar>) -> Opti
 		let sblades: Option<Vec<SBlade>> = types.into_iter().map(Type::into_sblade).collect();
 		let sblades = sblades?;
 
-		if let Some(g) = g {
+		let g = g?;
+		if g.is_diagonal() {
 			Some(Type::SBlade(SBlade::product(product, &sblades, g)))
 		} else {
-			None
+			// The product may be a sum of blades:
+			single_sblade(SBlade::product_terms(product, &sblades, g)).map(Type::SBlade)
 		}
 	}
 }
+
+/// The sum of blades as a single (signed) blade, if possible.
+pub fn single_sblade(mut terms: Vec<SBlade>) -> Option<SBlade> {
+	match terms.len() {
+		0 => Some(SBlade::zero()),
+		1 => terms.pop(),
+		_ => None,
+	}
+}
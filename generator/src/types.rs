@@ -8,10 +8,17 @@ pub struct StructMember {
 	pub typ: Type,
 }
 
+impl StructMember {
+	/// Is this member always one, i.e. not stored? See [`Types::insert_struct_with_constants`].
+	pub fn is_constant(&self) -> bool {
+		matches!(self.typ, Type::Constant(_))
+	}
+}
+
 pub type Struct = IndexMap<String, StructMember>;
 
 /// In order of preference (first match).
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Types {
 	// types: Vec<Typedef>,
 	types: IndexMap<String, Type>,
@@ -31,15 +38,47 @@ impl Types {
 		self.types.insert(name.to_string(), Type::SBlade(sblade));
 	}
 
+	/// Insert all the blades of a grammar with the given base vector names, in order of grade.
+	/// Each blade is named by its (sorted) base vectors,
+	/// with the prefix they all share only written once: `E0 E1 E2` gives `E01`, `E02`, `E12` and `E012`.
+	/// The scalar is named `S`.
+	pub fn insert_all_blades(&mut self, g: &Grammar, vec_names: &[&str]) {
+		assert_eq!(vec_names.len(), g.num_vecs());
+		for blade in g.blades() {
+			let name = blade_name(vec_names, &blade);
+			assert!(self.try_get(&name).is_none(), "Blade name '{}' is ambiguous", name);
+			self.insert_blade(&name, SBlade::unit(blade));
+		}
+	}
+
 	pub fn insert_struct(&mut self, name: &str, members: &[(&str, &str)]) {
+		self.insert_struct_with_constants(name, members, &[])
+	}
+
+	/// Like `insert_struct`, but with additional members that are always one,
+	/// e.g. `w: 1` for a normalized point.
+	/// These are not stored, but still take part in all the products.
+	pub fn insert_struct_with_constants(&mut self, name: &str, members: &[(&str, &str)], constants: &[(&str, &str)]) {
+		let members = members.iter().map(|(member, type_name)| {
+			let typ = self.get(type_name).clone();
+			(member, type_name, typ)
+		});
+		let constants = constants.iter().map(|(member, type_name)| {
+			let sblade = self
+				.get(type_name)
+				.clone()
+				.into_sblade()
+				.unwrap_or_else(|| panic!("Constant member '{}' of '{}' must be a blade", member, name));
+			(member, type_name, Type::constant(sblade))
+		});
 		let strct: Struct = members
-			.iter()
-			.map(|(member, type_name)| {
+			.chain(constants)
+			.map(|(member, type_name, typ)| {
 				(
 					member.to_string(),
 					StructMember {
 						name: type_name.to_string(),
-						typ: self.get(type_name).clone(),
+						typ,
 					},
 				)
 			})
@@ -50,12 +89,30 @@ impl Types {
 		self.types.insert(name.to_string(), Type::Struct(struct_type));
 	}
 
+	/// Add a struct with all the blades, so that every operation has a named output type.
+	/// Since the first matching struct is used, this should be inserted last.
+	pub fn insert_multivector(&mut self, name: &str) {
+		let mut members: Vec<(String, String)> = vec![];
+		for (_, (_, blade_name)) in &self.blades {
+			let mut member = blade_name.to_lowercase();
+			while members.iter().any(|(other, _)| other == &member) {
+				member.push('_');
+			}
+			members.push((member, blade_name.clone()));
+		}
+		let members: Vec<(&str, &str)> = members.iter().map(|(m, b)| (m.as_str(), b.as_str())).collect();
+		self.insert_struct(name, &members);
+	}
+
 	pub fn get(&self, name: &str) -> &Type {
-		self.types
-			.get(name)
+		self.try_get(name)
 			.unwrap_or_else(|| panic!("Failed to find type '{}'", name))
 	}
 
+	pub fn try_get(&self, name: &str) -> Option<&Type> {
+		self.types.get(name)
+	}
+
 	pub fn get_struct(&self, name: &str) -> &Struct {
 		self.structs
 			.get(name)
@@ -76,13 +133,13 @@ impl Types {
 	pub fn type_name(&self, typ: &Type) -> &str {
 		match typ {
 			Type::SBlade(sblade) if sblade.is_zero() => "Zero",
-			Type::SBlade(sblade) => self.get_blade(&sblade.blade).unwrap().1.as_str(),
+			// e.g. the product of the constant `w` of two normalized points:
+			Type::SBlade(sblade) | Type::Constant(sblade) => self.get_blade(&sblade.blade).unwrap().1.as_str(),
 			Type::Struct(_) => self
 				.structs()
 				.find(|(_, strct)| &Type::strct(strct) == typ)
 				.map(|(name, _)| name)
 				.unwrap(),
-			_ => todo!("Get name of type {:?}", typ),
 		}
 	}
 
@@ -104,3 +161,36 @@ impl Types {
 		self.structs.iter().map(|(name, strct)| (name.as_str(), strct))
 	}
 }
+
+/// The name of a blade given the names of the base vectors, see [`Types::insert_all_blades`].
+pub fn blade_name(vec_names: &[&str], blade: &Blade) -> String {
+	if blade.is_scalar() {
+		"S".to_owned()
+	} else {
+		let prefix = common_prefix(vec_names);
+		let suffixes = blade.vecs().iter().map(|vi| &vec_names[vi.0][prefix.len()..]);
+		format!("{}{}", prefix, suffixes.collect::<String>())
+	}
+}
+
+/// The longest prefix of all the names that still leaves something of each,
+/// e.g. `E` for `E0 E1 E2`.
+fn common_prefix<'a>(names: &[&'a str]) -> &'a str {
+	let first = match names.first() {
+		Some(first) => *first,
+		None => return "",
+	};
+	let mut len = 0;
+	for (i, c) in first.char_indices() {
+		let end = i + c.len_utf8();
+		if names
+			.iter()
+			.all(|name| name.len() > end && name.starts_with(&first[..end]))
+		{
+			len = end;
+		} else {
+			break;
+		}
+	}
+	&first[..len]
+}
@@ -20,7 +20,7 @@ impl Expr {
 	pub fn typify(mut self, t: &Types, g: &Grammar) -> Self {
 		if let Expr::Sum(terms) = &self {
 			// eprintln!("typify Sum: {}", self.rust());
-			if let Some(value) = as_value(&terms, Some(g)) {
+			if let Some(value) = as_value(terms, Some(g)) {
 				if let Some(s) = find_struct(&value, t) {
 					// eprintln!("typify Sum {} as struct {}", self.rust(), s.struct_name);
 					self = Expr::StructInstance(s);
@@ -37,12 +37,13 @@ impl Expr {
 				self = Expr::scalar(sblade.sign);
 			} else if let Some((canon_sign, canon_name)) = t.get_blade(&sblade.blade) {
 				let canon_sign = *canon_sign;
-				let canon_type = Type::SBlade(SBlade {
+				// A unit blade, e.g. `X`, is a constant one (`X(1.0)`):
+				let canon_type = Type::Constant(SBlade {
 					sign: canon_sign,
 					blade: sblade.blade.clone(),
 				});
 				let order = sblade.grade(); // TODO
-				let blade_var = Expr::var(order, &canon_name, &canon_type);
+				let blade_var = Expr::var(order, canon_name, &canon_type);
 				let scalar = sblade.sign * canon_sign;
 				self = match scalar {
 					0 => Expr::zero(),
@@ -54,6 +55,31 @@ impl Expr {
 
 		match self {
 			Expr::Var { .. } | Expr::Vec(_) => self,
+			Expr::Coordinates {
+				vars,
+				sblade,
+				type_name: None,
+			} => match t.get_blade(&sblade.blade) {
+				Some((canon_sign, canon_name)) => {
+					// `sblade` is a unit blade, and `canon_sign * canon_name` is that same blade:
+					let coordinates = Expr::Coordinates {
+						vars,
+						sblade: SBlade::signed_blade(*canon_sign, sblade.blade),
+						type_name: Some(canon_name.clone()),
+					};
+					if *canon_sign == 1 {
+						coordinates
+					} else {
+						Expr::Term(coordinates.into(), *canon_sign)
+					}
+				}
+				None => Expr::Coordinates {
+					vars,
+					sblade,
+					type_name: None,
+				},
+			},
+			Expr::Coordinates { .. } => self,
 			Expr::Term(expr, s) => Expr::Term(expr.typify(t, g).into(), s),
 			Expr::Unary(unary, expr) => Expr::Unary(unary, expr.typify(t, g).into()),
 			Expr::Sum(terms) => Expr::Sum(terms.into_iter().map(|e| e.typify(t, g)).collect()),
@@ -71,6 +97,20 @@ impl Expr {
 	}
 }
 
+impl Expr {
+	/// Like `typify`, but the result must be an instance of the given struct (or zero).
+	/// Returns `None` if `self` has blades that are not part of the struct.
+	pub fn typify_as_struct(self, struct_name: &str, strct: &Struct, t: &Types, g: &Grammar) -> Option<Self> {
+		let terms = match self {
+			Expr::Sum(terms) => terms,
+			expr => vec![expr],
+		};
+		let value = as_value(&terms, Some(g))?;
+		let instance = as_struct_instance(struct_name, strct, &value)?;
+		Some(Expr::StructInstance(instance).typify(t, g))
+	}
+}
+
 fn as_value(terms: &[Expr], g: Option<&Grammar>) -> Option<Value> {
 	let mut parts: BTreeMap<Blade, Vec<Expr>> = Default::default();
 	for term in terms {
@@ -79,12 +119,10 @@ fn as_value(terms: &[Expr], g: Option<&Grammar>) -> Option<Value> {
 		if !typ.is_zero() {
 			match typ {
 				Type::Constant(sblade) | Type::SBlade(sblade) => {
-					let term = if sblade.is_negative() {
-						term.clone().negate()
-					} else {
-						term.clone()
-					};
-					parts.entry(sblade.blade).or_default().push(term);
+					// NOTE: no sign change here, even if the sblade is negative.
+					// A term like `y ^ x` is a value of the blade XY (equal to `-x ^ y`),
+					// and will be generated as such (`y.wedge(x)` returns an `XY`).
+					parts.entry(sblade.blade).or_default().push(term.clone());
 				}
 				Type::Struct { .. } => {
 					return None;
@@ -112,7 +150,7 @@ fn find_struct(sum: &Value, t: &Types) -> Option<StructInstance> {
 	// eprintln!("find_struct for {}", show_value(sum));
 
 	for (name, strct) in t.structs() {
-		if let Some(instance) = as_struct_instance(name, strct, &sum) {
+		if let Some(instance) = as_struct_instance(name, strct, sum) {
 			return Some(instance);
 		}
 	}
@@ -120,6 +158,11 @@ fn find_struct(sum: &Value, t: &Types) -> Option<StructInstance> {
 }
 
 fn as_struct_instance(struct_name: &str, strct: &Struct, value: &Value) -> Option<StructInstance> {
+	if strct.values().any(StructMember::is_constant) {
+		// We can never know that the result of an operation is e.g. a normalized point.
+		return None;
+	}
+
 	if value.keys().all(|b| is_blade_in_struct(strct, b)) {
 		Some(StructInstance {
 			struct_name: struct_name.to_owned(),
@@ -139,16 +182,6 @@ fn is_blade_in_struct(strct: &Struct, blade: &Blade) -> bool {
 }
 
 fn find_term(needle: &Type, value: &Value) -> Option<Expr> {
-	if let Some(needle) = needle.clone().into_sblade() {
-		for (blade, expr) in value {
-			if &needle.blade == blade {
-				return Some(match needle.sign {
-					1 => expr.clone().simplify(None),
-					-1 => expr.clone().negate().simplify(None),
-					_ => unreachable!(),
-				});
-			}
-		}
-	}
-	None
+	let needle = needle.clone().into_sblade()?;
+	value.get(&needle.blade).cloned()
 }
@@ -0,0 +1,92 @@
+
+/// The scalar type that the blades and structs are generic over.
+/// Implemented for `f32` and `f64`, but you can also implement it for e.g. a SIMD type
+/// to transform many values at once.
+pub trait Float:
+	Copy
+	+ Default
+	+ PartialOrd
+	+ std::ops::Neg<Output = Self>
+	+ std::ops::Add<Output = Self>
+	+ std::ops::Sub<Output = Self>
+	+ std::ops::Mul<Output = Self>
+	+ std::ops::Div<Output = Self>
+{
+	fn one() -> Self;
+	/// The difference between `1` and the next larger number, e.g. `f64::EPSILON`.
+	fn epsilon() -> Self;
+	fn from_i32(value: i32) -> Self;
+	fn abs(self) -> Self;
+	fn sqrt(self) -> Self;
+	fn max(self, other: Self) -> Self;
+	fn copysign(self, sign: Self) -> Self;
+	fn sin(self) -> Self;
+	fn cos(self) -> Self;
+	fn atan2(self, other: Self) -> Self;
+}
+
+impl Float for f32 {
+	fn one() -> Self {
+		1.0
+	}
+	fn epsilon() -> Self {
+		f32::EPSILON
+	}
+	fn from_i32(value: i32) -> Self {
+		value as f32
+	}
+	fn abs(self) -> Self {
+		f32::abs(self)
+	}
+	fn sqrt(self) -> Self {
+		f32::sqrt(self)
+	}
+	fn max(self, other: Self) -> Self {
+		f32::max(self, other)
+	}
+	fn copysign(self, sign: Self) -> Self {
+		f32::copysign(self, sign)
+	}
+	fn sin(self) -> Self {
+		f32::sin(self)
+	}
+	fn cos(self) -> Self {
+		f32::cos(self)
+	}
+	fn atan2(self, other: Self) -> Self {
+		f32::atan2(self, other)
+	}
+}
+
+impl Float for f64 {
+	fn one() -> Self {
+		1.0
+	}
+	fn epsilon() -> Self {
+		f64::EPSILON
+	}
+	fn from_i32(value: i32) -> Self {
+		value as f64
+	}
+	fn abs(self) -> Self {
+		f64::abs(self)
+	}
+	fn sqrt(self) -> Self {
+		f64::sqrt(self)
+	}
+	fn max(self, other: Self) -> Self {
+		f64::max(self, other)
+	}
+	fn copysign(self, sign: Self) -> Self {
+		f64::copysign(self, sign)
+	}
+	fn sin(self) -> Self {
+		f64::sin(self)
+	}
+	fn cos(self) -> Self {
+		f64::cos(self)
+	}
+	fn atan2(self, other: Self) -> Self {
+		f64::atan2(self, other)
+	}
+}
@@ -7,7 +7,6 @@ pub mod traits;
 /// Special zero type for completeness, and better error messages.
 /// If you get this in an error message, it is because you multiplied
 /// two dimensions that always results in zero.
-
 pub struct Zero {}
 
 // ----------------------------------------------------------------------------
@@ -11,7 +11,7 @@ pub trait LCompl {
 pub trait RCompl {
 	type Output;
 
-	/// Right compliment.
+	/// Right compliment, a.k.a. `!self`.
 	/// self * self.rcompl() == pseudo-scalar
 	/// e0 * e0.rcompl() = e0 * e12 = e012
 	/// e1.rcompl() = e20 = -e02
@@ -32,14 +32,104 @@ pub trait AntiReverse {
 	fn arev(self) -> Self;
 }
 
+/// Grade involution: negate the odd grades.
+/// e1.involute()  = -e1
+/// e12.involute() = e12
+pub trait Involute {
+	fn involute(self) -> Self;
+}
+
+/// Clifford conjugate: the reverse of the grade involution.
+/// e1.conjugate()   = -e1
+/// e12.conjugate()  = -e12
+/// e123.conjugate() = e123
+pub trait Conjugate {
+	fn conjugate(self) -> Self;
+}
+
+/// Poincaré duality: the right complement for the lower half of the grades,
+/// and the left complement for the upper half.
+pub trait Dual {
+	type Output;
+	fn dual(self) -> Self::Output;
+}
+
+/// The part of a value without the degenerate base vector, e.g. the `x y z` of a point.
+/// See <http://terathon.com/pga_lengyel.pdf>
+pub trait Bulk {
+	type Output;
+	fn bulk(self) -> Self::Output;
+}
+
+/// The part of a value with the degenerate base vector, e.g. the `w` of a point.
+/// See <http://terathon.com/pga_lengyel.pdf>
+pub trait Weight {
+	type Output;
+	fn weight(self) -> Self::Output;
+}
+
+/// self.bulk().rcompl()
+pub trait BulkDual {
+	type Output;
+	fn bulk_dual(self) -> Self::Output;
+}
+
+/// self.weight().rcompl()
+/// Used for distances and projections.
+pub trait WeightDual {
+	type Output;
+	fn weight_dual(self) -> Self::Output;
+}
+
 /// x.squared() = x * x
 /// Note that all values square to a scalar (could always be zero though).
+/// The output is the scalar type (e.g. `f32` or `f64`).
 pub trait Square {
-	fn square(self) -> f64;
+	type Output;
+	fn square(self) -> Self::Output;
+}
+
+/// Norms of a value, split into the *bulk* (the blades without the degenerate base vector, e.g. `X Y Z`)
+/// and the *weight* (the blades with it, e.g. `W`). See <http://terathon.com/pga_lengyel.pdf>
+pub trait Norm {
+	/// The scalar type, e.g. `f64`.
+	type Output;
+
+	/// Sum of the squares of all the components.
+	fn norm_squared(self) -> Self::Output;
+
+	fn bulk_norm_squared(self) -> Self::Output;
+	fn bulk_norm(self) -> Self::Output;
+
+	fn weight_norm_squared(self) -> Self::Output;
+	fn weight_norm(self) -> Self::Output;
+
+	/// Scale so that the weight norm is one (a.k.a. unitize), e.g. a point gets `w=1`,
+	/// and a line gets a unit direction.
+	/// Uses the bulk norm instead for types without weight (e.g. `Vec3`),
+	/// and for versors with a scalar part (e.g. a 2D rotor).
+	/// When that norm is of a single member (like the `w` of a point), this divides by the member itself,
+	/// so a point with a negative `w` also gets `w=1`.
+	fn normalized(self) -> Self;
+
+	/// Is the norm that `normalized` uses within `eps` of one?
+	fn is_normalized(&self, eps: Self::Output) -> bool;
+}
+
+/// The exponential map, e.g. from a bivector (`Line3`) to the motor (`Motor3`) that moves along it.
+pub trait Exp {
+	type Output;
+	fn exp(self) -> Self::Output;
+}
+
+/// The natural logarithm, the inverse of [`Exp`], e.g. from a motor (`Motor3`) to a bivector (`Line3`).
+pub trait Ln {
+	type Output;
+	fn ln(self) -> Self::Output;
 }
 
 /// The geometric product, a.k.a. normal multiplication.
-/// a.geometric(b) = a.dot(b) + a.wedge(b)
+/// a.geometric(b) = a * b = a.dot(b) + a.wedge(b)
 pub trait Geometric<Rhs> {
 	type Output;
 	fn geometric(self, e: Rhs) -> Self::Output;
@@ -54,6 +144,7 @@ pub trait AntiGeometric<Rhs> {
 }
 
 /// The dot product, a.k.a. the inner product.
+/// a.dot(b) = a | b
 /// The commutative part of the geometric product.
 /// Signifies a metric of how alike two values are.
 /// Orthogonal values always dot to zero.
@@ -80,3 +171,56 @@ pub trait AntiWedge<Rhs> {
 	type Output;
 	fn anti_wedge(self, e: Rhs) -> Self::Output;
 }
+
+/// The left contraction, a.k.a. the left interior product.
+/// The part of the geometric product with grade `grade(rhs) - grade(self)`,
+/// i.e. what is left of `rhs` after removing `self` from it.
+/// Zero if `self` has a higher grade than `rhs`. Used for projections.
+pub trait LeftContraction<Rhs> {
+	type Output;
+	fn left_contraction(self, e: Rhs) -> Self::Output;
+}
+
+/// The right contraction, a.k.a. the right interior product.
+/// The part of the geometric product with grade `grade(self) - grade(rhs)`.
+/// Zero if `rhs` has a higher grade than `self`.
+pub trait RightContraction<Rhs> {
+	type Output;
+	fn right_contraction(self, e: Rhs) -> Self::Output;
+}
+
+/// The scalar part of the geometric product.
+/// Used for measuring angles and lengths.
+pub trait ScalarProduct<Rhs> {
+	type Output;
+	fn scalar_product(self, e: Rhs) -> Self::Output;
+}
+
+/// The commutator product: (a * b - b * a) / 2
+/// The anti-commutative part of the geometric product.
+/// Used for the algebra of bivectors, e.g. velocities in rigid-body dynamics.
+pub trait Commutator<Rhs> {
+	type Output;
+	fn commutator(self, e: Rhs) -> Self::Output;
+}
+
+/// The anti-dot product, i.e. the dual version of the dot product.
+/// self.anti_dot(other) = self.lcompl().dot(other.lcompl()).rcompl()
+/// Introduced in <http://terathon.com/blog/projective-geometric-algebra-done-right/>
+pub trait AntiDot<Rhs> {
+	type Output;
+	fn anti_dot(self, e: Rhs) -> Self::Output;
+}
+
+/// The sandwich product, used to transform `rhs` with the versor `self` (e.g. a rotor or motor).
+/// self.sandwich(rhs) = self * rhs * self.rev()
+pub trait Sandwich<Rhs> {
+	fn sandwich(self, rhs: Rhs) -> Rhs;
+}
+
+/// The anti-sandwich product, i.e. the dual version of the sandwich product.
+/// self.anti_sandwich(rhs) = self.anti_geometric(rhs).anti_geometric(self.arev())
+/// This is how rotors and motors transform things in Eric Lengyel's convention.
+pub trait AntiSandwich<Rhs> {
+	fn anti_sandwich(self, rhs: Rhs) -> Rhs;
+}
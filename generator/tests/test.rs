@@ -1,4 +1,5 @@
 use generator::{documentation::*, *};
+use strum::IntoEnumIterator;
 
 #[cfg(test)]
 macro_rules! assert_eq_ignoring_whitespace {
@@ -22,6 +23,27 @@ fn tokenize(s: &str) -> Vec<&str> {
 	s.trim().split_ascii_whitespace().collect()
 }
 
+/// The generator the tests use: plain Rust output, with generic floats when the settings ask for them.
+fn generator(grammar: Grammar, types: Types, settings: gen::Settings) -> gen::Generator {
+	gen::Generator {
+		grammar,
+		types,
+		ro: RustOptions {
+			generic_float: settings.generic,
+			..RustOptions::rust()
+		},
+		settings,
+	}
+}
+
+/// An empty directory of its own for a test, so that tests running in parallel don't share files.
+fn temp_dir(test_name: &str) -> std::path::PathBuf {
+	let dir = std::env::temp_dir().join(format!("pga_{}_{}", test_name, std::process::id()));
+	let _ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(&dir).unwrap();
+	dir
+}
+
 #[test]
 fn test_pga3d_lengyel() {
 	let (g, t) = grammars::pga3d_lengyel();
@@ -38,6 +60,13 @@ fn test_pga3d_lengyel() {
 | Left complement  | E4 | -e234 | -e314 | -e124 | -e321 | -e23 | -e31 | -e12 | -e41 | -e42 | -e43 | e1    | e2    | e3    | e4    | 1  |
 | Reverse          | 1  | e1    | e2    | e3    | e4    | -e41 | -e42 | -e43 | -e23 | -e31 | -e12 | -e234 | -e314 | -e124 | -e321 | E4 |
 | Anti-reverse     | 1  | -e1   | -e2   | -e3   | -e4   | -e41 | -e42 | -e43 | -e23 | -e31 | -e12 | e234  | e314  | e124  | e321  | E4 |
+| Involute         | 1  | -e1   | -e2   | -e3   | -e4   | e41  | e42  | e43  | e23  | e31  | e12  | -e234 | -e314 | -e124 | -e321 | E4 |
+| Conjugate        | 1  | -e1   | -e2   | -e3   | -e4   | -e41 | -e42 | -e43 | -e23 | -e31 | -e12 | e234  | e314  | e124  | e321  | E4 |
+| Dual             | E4 | e234  | e314  | e124  | e321  | -e23 | -e31 | -e12 | -e41 | -e42 | -e43 | e1    | e2    | e3    | e4    | 1  |
+| Bulk             | 1  | e1    | e2    | e3    | 0     | 0    | 0    | 0    | e23  | e31  | e12  | 0     | 0     | 0     | e321  | 0  |
+| Weight           | 0  | 0     | 0     | 0     | e4    | e41  | e42  | e43  | 0    | 0    | 0    | e234  | e314  | e124  | 0     | E4 |
+| Bulk dual        | E4 | e234  | e314  | e124  | 0     | 0    | 0    | 0    | -e41 | -e42 | -e43 | 0     | 0     | 0     | -e4   | 0  |
+| Weight dual      | 0  | 0     | 0     | 0     | e321  | -e23 | -e31 | -e12 | 0    | 0    | 0    | -e1   | -e2   | -e3   | 0     | 1  |
 "
 	);
 
@@ -103,12 +132,12 @@ fn test_pga3d_lengyel() {
 		rust(Expr::wedge(vec![Expr::var(0, "p", &point), Expr::var(1, "q", &point)])),
 		"
 Line {
-    vx: -p.x ^ e4 + q.x ^ e4,
-    vy: -p.y ^ e4 + q.y ^ e4,
-    vz: -p.z ^ e4 + q.z ^ e4,
-    mx: p.y ^ q.z - p.z ^ q.y,
-    my: -p.x ^ q.z + p.z ^ q.x,
-    mz: p.x ^ q.y - p.y ^ q.x,
+    vx: p.x ^ e4 - q.x ^ e4,
+    vy: p.y ^ e4 - q.y ^ e4,
+    vz: p.z ^ e4 - q.z ^ e4,
+    mx: p.y ^ q.z + p.z ^ q.y,
+    my: p.x ^ q.z + p.z ^ q.x,
+    mz: p.x ^ q.y + p.y ^ q.x,
 }
     "
 	);
@@ -155,9 +184,9 @@ fn test_pga2d() {
 		rust(Expr::wedge(vec![Expr::var(0, "l", point), Expr::var(1, "r", point)])),
 		r"
 Line {
-    dx: -l.w ^ r.y + l.y ^ r.w,
-    dy: l.w ^ r.x - l.x ^ r.w,
-    m : l.x ^ r.y - l.y ^ r.x,
+    dx: l.w ^ r.y + l.y ^ r.w,
+    dy: l.w ^ r.x + l.x ^ r.w,
+    m : l.x ^ r.y + l.y ^ r.x,
 }"
 		.trim()
 	);
@@ -167,9 +196,9 @@ Line {
 		rust(Expr::antiwedge(vec![Expr::var(0, "l", line), Expr::var(1, "r", line)])),
 		r"
 Vec3 {
-    x: l.dy & r.m - l.m & r.dy,
-    y: -l.dx & r.m + l.m & r.dx,
-    w: l.dx & r.dy - l.dy & r.dx,
+    x: l.dy & r.m + l.m & r.dy,
+    y: l.dx & r.m + l.m & r.dx,
+    w: l.dx & r.dy + l.dy & r.dx,
 }
 "
 		.trim()
@@ -213,15 +242,10 @@ Line {
 fn test_generator() {
 	let (grammar, types) = generator::grammars::pga3d();
 	let settings = gen::Settings::default();
-	let gen = gen::Generator {
-		grammar,
-		types,
-		settings,
-		ro: RustOptions::rust(),
-	};
+	let gen = generator(grammar, types, settings);
 
 	let point = gen.types.get_struct("Vec4");
-	let code = gen::strct::impl_struct_product(&gen, &("Vec4", &point), &("Vec4", &point), Product::Wedge);
+	let code = gen::strct::impl_struct_product(&gen, &("Vec4", point), &("Vec4", point), Product::Wedge);
 	assert_eq_ignoring_whitespace!(
 		code,
 		r"
@@ -230,23 +254,1137 @@ impl Wedge<Vec4> for Vec4 {
     type Output = Line3;
     fn wedge(self, rhs: Vec4) -> Self::Output {
         // Line3 {
-        //     vx: WX(self.w.0 * rhs.x.0) + WX(self.x.0 * rhs.w.0),
-        //     vy: WY(self.w.0 * rhs.y.0) + WY(self.y.0 * rhs.w.0),
-        //     vz: WZ(self.w.0 * rhs.z.0) + WZ(self.z.0 * rhs.w.0),
-        //     mx: YZ(self.y.0 * rhs.z.0) + YZ(self.z.0 * rhs.y.0),
-        //     my: ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
-        //     mz: XY(self.x.0 * rhs.y.0) + XY(self.y.0 * rhs.x.0),
+        //     vx: WX(self.w.0 * rhs.x.0) - WX(self.x.0 * rhs.w.0),
+        //     vy: WY(self.w.0 * rhs.y.0) - WY(self.y.0 * rhs.w.0),
+        //     vz: WZ(self.w.0 * rhs.z.0) - WZ(self.z.0 * rhs.w.0),
+        //     mx: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+        //     my: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+        //     mz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
         // }
         Line3 {
-            vx: self.w.wedge(rhs.x) - self.x.wedge(rhs.w),
-            vy: self.w.wedge(rhs.y) - self.y.wedge(rhs.w),
-            vz: self.w.wedge(rhs.z) - self.z.wedge(rhs.w),
-            mx: self.y.wedge(rhs.z) - self.z.wedge(rhs.y),
-            my: -self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
-            mz: self.x.wedge(rhs.y) - self.y.wedge(rhs.x),
+            vx: self.w.wedge(rhs.x) + self.x.wedge(rhs.w),
+            vy: self.w.wedge(rhs.y) + self.y.wedge(rhs.w),
+            vz: self.w.wedge(rhs.z) + self.z.wedge(rhs.w),
+            mx: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+            my: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+            mz: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
         }
     }
 }
+
+impl std::ops::BitXor<Vec4> for Vec4 {
+    type Output = Line3;
+    fn bitxor(self, rhs: Vec4) -> Self::Output {
+        self.wedge(rhs)
+    }
+}
         "
 	);
 }
+
+#[test]
+fn test_sandwich() {
+	let (grammar, types) = generator::grammars::pga2d();
+	let settings = gen::Settings::default();
+	let gen = generator(grammar, types, settings);
+
+	let rotor = gen.types.get_struct("Rotor");
+	let vec2 = gen.types.get_struct("Vec2");
+	let code = gen::strct::impl_sandwich(&gen, &("Rotor", rotor), &("Vec2", vec2), Sandwich::Geometric);
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+// Rotor.sandwich(Vec2) -> Vec2
+impl Sandwich<Vec2> for Rotor {
+    fn sandwich(self, rhs: Vec2) -> Vec2 {
+        Vec2 {
+            x: self.s.geometric(self.s).geometric(rhs.x)
+                + self.s.geometric(self.xy).geometric(rhs.y) * 2.0
+                + self.xy.geometric(self.xy).geometric(rhs.x),
+            y: self.s.geometric(self.s).geometric(rhs.y)
+                + self.s.geometric(self.xy).geometric(rhs.x) * 2.0
+                + self.xy.geometric(self.xy).geometric(rhs.y),
+        }
+    }
+}
+"
+	);
+
+	let code = gen::strct::impl_sandwich(&gen, &("Rotor", rotor), &("Vec2", vec2), Sandwich::AntiGeometric);
+	assert_eq_ignoring_whitespace!(code, "// Omitted: Rotor.anti_sandwich(Vec2)  (zero)");
+}
+
+#[test]
+fn test_normalized_point() {
+	let (grammar, types) = generator::grammars::pga3d();
+	let settings = gen::Settings::default();
+	let gen = generator(grammar, types, settings);
+
+	let point = gen.types.get_struct("Point3");
+	let code = gen::strct::impl_struct_product(&gen, &("Point3", point), &("Point3", point), Product::Wedge);
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+// Point3.wedge(Point3) -> Line3
+impl Wedge<Point3> for Point3 {
+    type Output = Line3;
+    fn wedge(self, rhs: Point3) -> Self::Output {
+        // Line3 {
+        //     vx: WX(rhs.x.0) - WX(self.x.0),
+        //     vy: WY(rhs.y.0) - WY(self.y.0),
+        //     vz: WZ(rhs.z.0) - WZ(self.z.0),
+        //     mx: YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+        //     my: -ZX(self.x.0 * rhs.z.0) + ZX(self.z.0 * rhs.x.0),
+        //     mz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+        // }
+        Line3 {
+            vx: self.x.wedge(W(1.0)) - rhs.x.wedge(W(1.0)),
+            vy: self.y.wedge(W(1.0)) - rhs.y.wedge(W(1.0)),
+            vz: self.z.wedge(W(1.0)) - rhs.z.wedge(W(1.0)),
+            mx: self.y.wedge(rhs.z) + self.z.wedge(rhs.y),
+            my: self.x.wedge(rhs.z) + self.z.wedge(rhs.x),
+            mz: self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+        }
+    }
+}
+impl std::ops::BitXor<Point3> for Point3 {
+    type Output = Line3;
+    fn bitxor(self, rhs: Point3) -> Self::Output {
+        self.wedge(rhs)
+    }
+}
+"
+	);
+}
+
+#[test]
+fn test_generic_float() {
+	let (grammar, types) = generator::grammars::pga2d();
+	let settings = gen::Settings {
+		generic: true,
+		..Default::default()
+	};
+	let gen = generator(grammar, types, settings);
+
+	let rotor = gen.types.get_struct("Rotor");
+	let vec2 = gen.types.get_struct("Vec2");
+	let point = gen.types.get_struct("Point2");
+	let code = gen::strct::impl_sandwich(&gen, &("Rotor", rotor), &("Vec2", vec2), Sandwich::Geometric);
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+// Rotor.sandwich(Vec2) -> Vec2
+impl<T: Float> Sandwich<Vec2<T>> for Rotor<T> {
+    fn sandwich(self, rhs: Vec2<T>) -> Vec2<T> {
+        Vec2 {
+            x: self.s.geometric(self.s).geometric(rhs.x)
+                + self.s.geometric(self.xy).geometric(rhs.y) * T::from_i32(2)
+                + self.xy.geometric(self.xy).geometric(rhs.x),
+            y: self.s.geometric(self.s).geometric(rhs.y)
+                + self.s.geometric(self.xy).geometric(rhs.x) * T::from_i32(2)
+                + self.xy.geometric(self.xy).geometric(rhs.y),
+        }
+    }
+}
+"
+	);
+
+	let code = gen::strct::impl_struct_product(&gen, &("Point2", point), &("Point2", point), Product::Wedge);
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+// Point2.wedge(Point2) -> Line
+impl<T: Float> Wedge<Point2<T>> for Point2<T> {
+    type Output = Line<T>;
+    fn wedge(self, rhs: Point2<T>) -> Self::Output {
+        // Line {
+        //     dx: -YW(rhs.y.0) + YW(self.y.0),
+        //     dy: WX(rhs.x.0) - WX(self.x.0),
+        //     m : XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0),
+        // }
+        Line {
+            dx: self.y.wedge(W(T::one())) - rhs.y.wedge(W(T::one())),
+            dy: self.x.wedge(W(T::one())) - rhs.x.wedge(W(T::one())),
+            m : self.x.wedge(rhs.y) + self.y.wedge(rhs.x),
+        }
+    }
+}
+impl<T: Float> std::ops::BitXor<Point2<T>> for Point2<T> {
+    type Output = Line<T>;
+    fn bitxor(self, rhs: Point2<T>) -> Self::Output {
+        self.wedge(rhs)
+    }
+}
+"
+	);
+}
+
+#[test]
+fn test_translator() {
+	// pga2d uses the geometric product for composing transforms:
+	let (grammar, types) = generator::grammars::pga2d();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let translator = gen.types.get_struct("Translator");
+	let rotor = gen.types.get_struct("Rotor");
+	let code =
+		gen::strct::impl_struct_product(&gen, &("Translator", translator), &("Rotor", rotor), Product::Geometric);
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+// Translator.geometric(Rotor) -> Motor
+impl Geometric<Rotor> for Translator {
+    type Output = Motor;
+    fn geometric(self, rhs: Rotor) -> Self::Output {
+        // Motor {
+        //     s : S(self.s.0 * rhs.s.0),
+        //     yw: -YW(self.wx.0 * rhs.xy.0) + YW(self.yw.0 * rhs.s.0),
+        //     wx: WX(self.wx.0 * rhs.s.0) + WX(self.yw.0 * rhs.xy.0),
+        //     xy: XY(self.s.0 * rhs.xy.0),
+        // }
+        Motor {
+            s : self.s.geometric(rhs.s),
+            yw: self.wx.geometric(rhs.xy) + self.yw.geometric(rhs.s),
+            wx: self.wx.geometric(rhs.s) + self.yw.geometric(rhs.xy),
+            xy: self.s.geometric(rhs.xy),
+        }
+    }
+}
+impl std::ops::Mul<Rotor> for Translator {
+    type Output = Motor;
+    fn mul(self, rhs: Rotor) -> Self::Output {
+        self.geometric(rhs)
+    }
+}
+"
+	);
+
+	// pga3d uses the anti-geometric product (Eric Lengyel's convention):
+	let (grammar, types) = generator::grammars::pga3d();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let translator = gen.types.get_struct("Translator3");
+	let rotor = gen.types.get_struct("Rotor3");
+	let code = gen::strct::impl_struct_product(
+		&gen,
+		&("Translator3", translator),
+		&("Rotor3", rotor),
+		Product::AntiGeometric,
+	);
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+// Translator3.anti_geometric(Rotor3) -> Motor3
+impl AntiGeometric<Rotor3> for Translator3 {
+    type Output = Motor3;
+    fn anti_geometric(self, rhs: Rotor3) -> Self::Output {
+        // Motor3 {
+        //     rx: WX(self.w.0 * rhs.x.0),
+        //     ry: WY(self.w.0 * rhs.y.0),
+        //     rz: WZ(self.w.0 * rhs.z.0),
+        //     rw: XYZW(self.w.0 * rhs.w.0),
+        //     ux: YZ(self.x.0 * rhs.w.0) + YZ(self.y.0 * rhs.z.0) - YZ(self.z.0 * rhs.y.0),
+        //     uy: -ZX(self.x.0 * rhs.z.0) + ZX(self.y.0 * rhs.w.0) + ZX(self.z.0 * rhs.x.0),
+        //     uz: XY(self.x.0 * rhs.y.0) - XY(self.y.0 * rhs.x.0) + XY(self.z.0 * rhs.w.0),
+        //     uw: -S(self.x.0 * rhs.x.0) - S(self.y.0 * rhs.y.0) - S(self.z.0 * rhs.z.0),
+        // }
+        Motor3 {
+            rx: self.w.anti_geometric(rhs.x),
+            ry: self.w.anti_geometric(rhs.y),
+            rz: self.w.anti_geometric(rhs.z),
+            rw: self.w.anti_geometric(rhs.w),
+            ux: self.x.anti_geometric(rhs.w) + self.y.anti_geometric(rhs.z) + self.z.anti_geometric(rhs.y),
+            uy: self.x.anti_geometric(rhs.z) + self.y.anti_geometric(rhs.w) + self.z.anti_geometric(rhs.x),
+            uz: self.x.anti_geometric(rhs.y) + self.y.anti_geometric(rhs.x) + self.z.anti_geometric(rhs.w),
+            uw: self.x.anti_geometric(rhs.x) + self.y.anti_geometric(rhs.y) + self.z.anti_geometric(rhs.z),
+        }
+    }
+}
+"
+	);
+
+	let vec4 = gen.types.get_struct("Vec4");
+	let code = gen::strct::impl_sandwich(
+		&gen,
+		&("Translator3", translator),
+		&("Vec4", vec4),
+		Sandwich::AntiGeometric,
+	);
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+// Translator3.anti_sandwich(Vec4) -> Vec4
+impl AntiSandwich<Vec4> for Translator3 {
+    fn anti_sandwich(self, rhs: Vec4) -> Vec4 {
+        Vec4 {
+            x: self.w.anti_geometric(self.w).anti_geometric(rhs.x) + self.w.anti_geometric(self.x).anti_geometric(rhs.w) * 2.0,
+            y: self.w.anti_geometric(self.w).anti_geometric(rhs.y) + self.w.anti_geometric(self.y).anti_geometric(rhs.w) * 2.0,
+            z: self.w.anti_geometric(self.w).anti_geometric(rhs.z) + self.w.anti_geometric(self.z).anti_geometric(rhs.w) * 2.0,
+            w: self.w.anti_geometric(self.w).anti_geometric(rhs.w),
+        }
+    }
+}
+"
+	);
+
+	// The plane-based `S + WX WY WZ` is not a translator in this grammar:
+	// its product with a `Rotor3` only has the blades of the `Rotor3`, because `W² = 0`.
+	let (grammar, mut types) = generator::grammars::pga3d();
+	types.insert_struct("PlaneTranslator", &[("s", "S"), ("x", "WX"), ("y", "WY"), ("z", "WZ")]);
+	let gen = generator(grammar, types, gen::Settings::default());
+	let output = gen::strct::struct_product_output(
+		&gen,
+		&("PlaneTranslator", gen.types.get_struct("PlaneTranslator")),
+		&("Rotor3", gen.types.get_struct("Rotor3")),
+		Product::Geometric,
+	);
+	assert_eq!(output.map(|(name, _)| name).as_deref(), Some("Rotor3"));
+}
+
+#[test]
+fn test_norm() {
+	let (grammar, types) = generator::grammars::pga2d();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let rotor = gen.types.get_struct("Rotor");
+	let code = gen::strct::impl_norm(&gen, "Rotor", rotor);
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+impl Norm for Rotor {
+    type Output = f64;
+
+    fn norm_squared(self) -> Self::Output {
+        self.s.0 * self.s.0 + self.xy.0 * self.xy.0
+    }
+
+    fn bulk_norm_squared(self) -> Self::Output {
+        self.s.0 * self.s.0 + self.xy.0 * self.xy.0
+    }
+
+    fn bulk_norm(self) -> Self::Output {
+        self.bulk_norm_squared().sqrt()
+    }
+
+    fn weight_norm_squared(self) -> Self::Output {
+        0.0
+    }
+
+    fn weight_norm(self) -> Self::Output {
+        self.weight_norm_squared().sqrt()
+    }
+
+    fn normalized(self) -> Self {
+        let scale = 1.0 / self.bulk_norm();
+        Rotor {
+            s: self.s * scale,
+            xy: self.xy * scale,
+        }
+    }
+
+    fn is_normalized(&self, eps: Self::Output) -> bool {
+        (self.bulk_norm() - 1.0).abs() <= eps
+    }
+}
+"
+	);
+
+	let vec3 = gen.types.get_struct("Vec3");
+	// A point is divided by its `w`, not by `|w|`, so that it gets `w=1` even if `w` is negative:
+	let code = gen::strct::impl_norm(&gen, "Vec3", vec3);
+	assert!(code.contains("let scale = 1.0 / self.w.0;"), "{}", code);
+
+	let code = gen::strct::impl_square(&gen, "Vec3", vec3);
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+impl Square for Vec3 {
+    type Output = f64;
+    fn square(self) -> Self::Output {
+        (self.x.geometric(self.x) + self.y.geometric(self.y)).0
+    }
+}
+"
+	);
+}
+
+#[test]
+fn test_exp_log() {
+	let (grammar, types) = generator::grammars::pga2d();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let code = gen::exp_log::impls(&gen, "Line").unwrap();
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+impl Exp for Line {
+    type Output = Motor;
+
+    /// The motor that rotates by `2 * self.m` around the line, or translates if `m` is zero.
+    fn exp(self) -> Self::Output {
+        let angle = self.m.0.abs();
+        let (sin_over_angle, cos) = if angle == 0.0 {
+            (1.0, 1.0)
+        } else {
+            (angle.sin() / angle, angle.cos())
+        };
+        Motor {
+            s: S(cos),
+            yw: self.dx * sin_over_angle,
+            wx: self.dy * sin_over_angle,
+            xy: self.m * sin_over_angle,
+        }
+    }
+}
+"
+	);
+	assert!(gen::exp_log::impls(&gen, "Motor").unwrap().contains("pub fn lerp_log"));
+	assert!(gen::exp_log::impls(&gen, "Rotor").is_none());
+}
+
+#[test]
+fn test_arrays_and_constructors() {
+	let (grammar, types) = generator::grammars::pga3d();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let point = gen.types.get_struct("Point3");
+	let code = gen::strct::impl_arrays(&gen, "Point3", point);
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+impl Point3 {
+    /// The members as a plain array, in declaration order.
+    pub fn to_array(self) -> [f64; 3] {
+        [self.x.0, self.y.0, self.z.0]
+    }
+}
+
+impl From<[f64; 3]> for Point3 {
+    fn from(a: [f64; 3]) -> Self {
+        Point3 {
+            x: X(a[0]),
+            y: Y(a[1]),
+            z: Z(a[2]),
+        }
+    }
+}
+
+impl From<Point3> for [f64; 3] {
+    fn from(v: Point3) -> Self {
+        v.to_array()
+    }
+}
+"
+	);
+
+	assert_eq!(gen.builtin_struct("Vec4"), Some(gen::Builtin::Pga3d));
+	let code = gen::constructors::impls(&gen, "Vec4").unwrap();
+	assert!(code.contains("pub fn point(x: f64, y: f64, z: f64) -> Self"));
+	assert!(code.contains("pub fn direction(x: f64, y: f64, z: f64) -> Self"));
+	assert!(gen::constructors::impls(&gen, "Moment3").is_none());
+
+	let (grammar, types) = generator::grammars::pga2d();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let code = gen::constructors::impls(&gen, "Motor").unwrap();
+	assert!(code.contains("pub fn from_translation(t: [f64; 2]) -> Self"));
+	assert!(code.contains("wx: WX(-half * t[0]),"));
+	assert!(gen::constructors::impls(&gen, "Point2").is_none());
+}
+
+#[test]
+fn test_multivector() {
+	let (grammar, mut types) = generator::grammars::pga2d();
+	types.insert_multivector(gen::MULTIVECTOR);
+	let gen = generator(grammar, types, gen::Settings::default());
+	let mv = gen.types.get_struct("Multivector");
+	assert_eq!(
+		mv.keys().map(String::as_str).collect::<Vec<_>>(),
+		vec!["s", "x", "y", "w", "yw", "wx", "xy", "xyw"]
+	);
+
+	let point = gen.types.get_struct("Point2");
+	let code = gen::strct::impl_multivector_conversions(&gen, "Point2", point).unwrap();
+	assert_eq_ignoring_whitespace!(
+		code,
+		r"
+impl From<Point2> for Multivector {
+    fn from(v: Point2) -> Self {
+        Multivector {
+            x: v.x,
+            y: v.y,
+            w: W(1.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl std::convert::TryFrom<Multivector> for Point2 {
+    type Error = Multivector;
+
+    /// Fails (returning the multivector) if it has blades that a `Point2` can't represent.
+    fn try_from(mv: Multivector) -> Result<Self, Self::Error> {
+        if mv.s.0 == 0.0
+            && mv.w.0 == 1.0
+            && mv.yw.0 == 0.0
+            && mv.wx.0 == 0.0
+            && mv.xy.0 == 0.0
+            && mv.xyw.0 == 0.0
+        {
+            Ok(Point2 {
+                x: mv.x,
+                y: mv.y,
+            })
+        } else {
+            Err(mv)
+        }
+    }
+}
+"
+	);
+
+	// Products that don't fit any other struct give a multivector:
+	let vec3 = gen.types.get_struct("Vec3");
+	let code = gen::strct::impl_struct_product(
+		&gen,
+		&("Vec3", vec3),
+		&("Motor", gen.types.get_struct("Motor")),
+		Product::Geometric,
+	);
+	assert!(code.contains("type Output = Multivector;"), "{}", code);
+}
+
+#[test]
+fn test_every_struct_product() {
+	// No product is left out, even if it is zero or has few non-zero members:
+	for (grammar, mut types) in [
+		grammars::pga2d(),
+		grammars::pga3d(),
+		grammars::vga3d(),
+		grammars::cga3d(),
+	] {
+		types.insert_multivector(gen::MULTIVECTOR);
+		let gen = generator(grammar, types, gen::Settings::default());
+		for (lhs_name, lhs) in gen.types.structs() {
+			for (rhs_name, rhs) in gen.types.structs() {
+				for product in Product::iter() {
+					let code = gen::strct::impl_struct_product(&gen, &(lhs_name, lhs), &(rhs_name, rhs), product);
+					let signature = format!("impl {}<{}> for {} {{", product.trait_name(), rhs_name, lhs_name);
+					assert!(code.contains(&signature), "{}", code);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn test_products_of_blades() {
+	// The symbolic simplifier must agree with the blade products,
+	// including for products of three factors (e.g. the anti-sandwich).
+	for (g, t) in [grammars::pga2d(), grammars::pga3d()] {
+		let sblades: Vec<SBlade> = t.sblades().into_iter().map(|(_, sblade)| sblade).collect();
+		for product in [Product::Geometric, Product::AntiGeometric] {
+			for a in &sblades {
+				for b in &sblades {
+					for c in &sblades {
+						let factors = vec![Expr::sblade(a), Expr::sblade(b), Expr::sblade(c)];
+						let expr = Expr::Prod(product, factors).simplify(Some(&g));
+						let expected = SBlade::product(product, &[a.clone(), b.clone(), c.clone()], &g);
+						let actual = if expr.is_zero() {
+							SBlade::zero()
+						} else {
+							expr.as_sblade(&g).unwrap()
+						};
+						if !(actual.is_zero() && expected.is_zero()) {
+							assert_eq!(actual, expected, "{:?}: {:?} {:?} {:?}", product, a, b, c);
+						}
+					}
+				}
+			}
+		}
+	}
+
+	// Non-associative products only ever have two factors:
+	for (g, t) in [grammars::pga2d(), grammars::pga3d()] {
+		let sblades: Vec<SBlade> = t.sblades().into_iter().map(|(_, sblade)| sblade).collect();
+		for product in Product::iter() {
+			for a in &sblades {
+				for b in &sblades {
+					let expr = Expr::Prod(product, vec![Expr::sblade(a), Expr::sblade(b)]).simplify(Some(&g));
+					let expected = SBlade::binary_product(a, product, b, &g);
+					let actual = if expr.is_zero() {
+						SBlade::zero()
+					} else {
+						expr.as_sblade(&g).unwrap()
+					};
+					if !(actual.is_zero() && expected.is_zero()) {
+						assert_eq!(actual, expected, "{:?}: {:?} {:?}", product, a, b);
+					}
+				}
+			}
+		}
+	}
+
+	// The pseudo-scalar is the identity of the anti-geometric product,
+	// and a degenerate base vector anti-squares to minus it:
+	let (g, _) = grammars::pga3d();
+	let w = Expr::Vec(VecIdx(3));
+	assert_eq!(g.square_with(Product::AntiGeometric, VecIdx(3)), Some(-1));
+	assert_eq!(g.square_with(Product::AntiGeometric, VecIdx(0)), Some(0));
+	assert_eq!(
+		Expr::Prod(Product::AntiGeometric, vec![w.clone(), w]).simplify(Some(&g)),
+		Expr::sblade(&SBlade::pseudo_scalar(&g)).negate().simplify(Some(&g))
+	);
+
+	// An empty anti-product is the pseudo-scalar, also before it is simplified:
+	for product in Product::iter().filter(|product| product.is_anti()) {
+		let empty = Expr::Prod(product, vec![]);
+		assert_eq!(empty.rust_concise(), "1.rcompl()");
+		assert_eq!(
+			empty.clone().simplify(Some(&g)),
+			Expr::sblade(&SBlade::pseudo_scalar(&g)),
+			"{:?}",
+			product
+		);
+		assert_eq!(empty.as_sblade(&g), Some(SBlade::pseudo_scalar(&g)));
+		assert_eq!(
+			Expr::unary(Unary::RCompl, Expr::one()).as_sblade(&g),
+			Some(SBlade::pseudo_scalar(&g))
+		);
+	}
+}
+
+#[test]
+fn test_queries() {
+	let (grammar, types) = generator::grammars::pga3d();
+	let pga3d = gen::queries::file(&generator(grammar, types, gen::Settings::default())).unwrap();
+	assert!(pga3d.contains("pub fn point_plane_distance(point: Vec4, plane: Plane) -> f64"));
+	assert!(pga3d.contains("pub fn line_line_distance(a: Line3, b: Line3) -> f64"));
+	let (grammar, types) = generator::grammars::pga2d();
+	let pga2d = gen::queries::file(&generator(grammar, types, gen::Settings::default())).unwrap();
+	assert!(pga2d.contains("pub fn point_line_distance(point: Vec3, line: Line) -> f64"));
+	let (grammar, types) = generator::grammars::pga3d_lengyel();
+	assert!(gen::queries::file(&generator(grammar, types, gen::Settings::default())).is_none());
+}
+
+#[test]
+fn test_ffi() {
+	let (grammar, types) = generator::grammars::pga2d();
+	let gen = generator(
+		grammar,
+		types,
+		gen::Settings {
+			float_type: "f32".to_owned(),
+			..Default::default()
+		},
+	);
+
+	let rust = gen::ffi::file(&gen, "pga2d");
+	assert_eq_ignoring_whitespace!(
+		rust.split("#[no_mangle]")
+			.find(|f| f.contains("fn pga2d_vec3_wedge_vec3("))
+			.unwrap(),
+		"pub extern \"C\" fn pga2d_vec3_wedge_vec3(lhs: Vec3, rhs: Vec3) -> Line { Wedge::wedge(lhs, rhs) }"
+	);
+	// The sandwiches too:
+	assert_eq_ignoring_whitespace!(
+		rust.split("#[no_mangle]")
+			.find(|f| f.contains("fn pga2d_motor_sandwich_line("))
+			.unwrap(),
+		"pub extern \"C\" fn pga2d_motor_sandwich_line(lhs: Motor, rhs: Line) -> Line { Sandwich::sandwich(lhs, rhs) }"
+	);
+
+	// The members in the same order as in Rust, without the constants:
+	let header = gen::ffi::header(&gen, "pga2d");
+	assert_eq_ignoring_whitespace!(
+		header.split("typedef").find(|s| s.contains("pga2d_Point2 {")).unwrap(),
+		"struct pga2d_Point2 { float x; /* X */ float y; /* Y */ /* w: W = 1 */ } pga2d_Point2;"
+	);
+	assert!(header.contains("\npga2d_Line pga2d_vec3_wedge_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);\n"));
+	assert!(header.contains("\nfloat pga2d_vec3_dot_vec3(pga2d_Vec3 lhs, pga2d_Vec3 rhs);\n"));
+	assert!(header.contains("\npga2d_Line pga2d_motor_sandwich_line(pga2d_Motor lhs, pga2d_Line rhs);\n"));
+}
+
+#[test]
+fn test_generate() {
+	let out_dir = temp_dir("generate");
+	let (grammar, types) = generator::grammars::pga2d();
+	let settings = gen::Settings {
+		ffi: true,
+		..Default::default()
+	};
+	generator::generate("pga2d", grammar, types, settings, &out_dir).unwrap();
+
+	let read = |file_name: &str| std::fs::read_to_string(out_dir.join(file_name)).unwrap();
+	let mod_rs = read("mod.rs");
+	assert!(mod_rs.contains("pub mod point2;"));
+	assert!(mod_rs.contains("pub mod ffi;"));
+	// Like the binary, it adds the Multivector and keeps the comments:
+	assert!(mod_rs.contains("pub mod multivector;"));
+	assert!(read("blades.rs").contains("\n// Omitted: W.bulk() -> 0\n"));
+	assert!(read("point2.rs").contains("pub struct Point2 {"));
+	assert!(read("ffi.rs").contains("pub extern \"C\" fn pga2d_vec3_wedge_vec3("));
+	assert!(read("pga2d.h").contains("pga2d_Point2;"));
+	std::fs::remove_dir_all(&out_dir).unwrap();
+
+	let (grammar, types) = generator::grammars::pga2d();
+	let settings = gen::Settings {
+		ffi: true,
+		generic: true,
+		..Default::default()
+	};
+	assert!(output::generator(grammar, types, settings).is_err());
+}
+
+#[test]
+fn test_format_rust() {
+	let code =
+		"fn f() -> char {\n  // A comment {\n    let s = \"(\n[\";\nif s.len() > 1 { '{' } else {\n'}'\n}\n\n\n}\n";
+	assert_eq!(
+		output::format_rust(code).unwrap(),
+		"fn f() -> char {\n\t// A comment {\n\tlet s = \"(\n[\";\n\tif s.len() > 1 { '{' } else {\n\t\t'}'\n\t}\n\n}\n"
+	);
+	assert!(output::format_rust("fn f( {}").is_err());
+}
+
+#[test]
+fn test_cargo_features() {
+	let (grammar, types) = generator::grammars::pga2d();
+	let mut gen = generator(
+		grammar,
+		types,
+		gen::Settings {
+			cargo_features: true,
+			..Default::default()
+		},
+	);
+
+	let blades = gen::blades::file(&gen);
+	assert!(blades.contains("unsafe impl bytemuck::Pod for X {}"));
+	assert!(blades.contains("impl approx::AbsDiffEq for X {"));
+
+	let point2 = gen::strct::file(&gen, "Point2", gen.types.get_struct("Point2"));
+	assert!(point2.contains("derive(serde::Serialize, serde::Deserialize)"));
+	assert!(point2.contains(
+		"self.x.relative_eq(&other.x, epsilon, max_relative) && self.y.relative_eq(&other.y, epsilon, max_relative)"
+	));
+	assert!(point2.contains("impl From<Point2> for mint::Point2<f64> {"));
+	assert!(point2.contains("x: X(v.x),"));
+
+	// A line is not a vector:
+	let line = gen::strct::file(&gen, "Line", gen.types.get_struct("Line"));
+	assert!(!line.contains("mint::"));
+
+	gen.settings.generic = true;
+	let vec2 = gen::strct::file(&gen, "Vec2", gen.types.get_struct("Vec2"));
+	assert!(vec2.contains("unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Vec2<T> {}"));
+	assert!(vec2.contains("impl<T: Float> From<mint::Vector2<T>> for Vec2<T> {"));
+
+	gen.settings.cargo_features = false;
+	let vec2 = gen::strct::file(&gen, "Vec2", gen.types.get_struct("Vec2"));
+	assert!(!vec2.contains("feature = "));
+}
+
+#[test]
+fn test_interop() {
+	let settings = || gen::Settings {
+		cargo_features: true,
+		..Default::default()
+	};
+	let (grammar, types) = generator::grammars::pga3d();
+	let gen = generator(grammar, types, settings());
+	let rotor3 = gen::strct::file(&gen, "Rotor3", gen.types.get_struct("Rotor3"));
+	assert!(rotor3.contains("glam::DQuat::from_xyzw(r.x.0, r.y.0, r.z.0, r.w.0)"));
+	assert!(rotor3
+		.contains("nalgebra::UnitQuaternion::new_normalize(nalgebra::Quaternion::new(r.w.0, r.x.0, r.y.0, r.z.0))"));
+	let motor3 = gen::strct::file(&gen, "Motor3", gen.types.get_struct("Motor3"));
+	assert!(motor3.contains("x: YZ(translation[0] / 2.0),"));
+	assert!(motor3.contains("translator.anti_geometric(rotor)"));
+	assert!(motor3.contains("impl From<nalgebra::Isometry3<f64>> for Motor3 {"));
+	let vec3 = gen::strct::file(&gen, "Vec3", gen.types.get_struct("Vec3"));
+	assert!(vec3.contains("impl From<Vec3> for glam::DVec3 {"));
+	assert!(vec3.contains("impl From<Vec3> for nalgebra::Point3<f64> {"));
+	let vec4 = gen::strct::file(&gen, "Vec4", gen.types.get_struct("Vec4"));
+	assert!(vec4.contains("Self::new(v.x.0 / v.w.0, v.y.0 / v.w.0, v.z.0 / v.w.0)"));
+	assert!(vec4.contains("impl From<nalgebra::Point3<f64>> for Vec4 {"));
+
+	// The quaternion follows the definition of the rotor:
+	let (grammar, mut types) = generator::grammars::pga3d();
+	types.insert_struct("Rotor3", &[("w", "XYZW"), ("a", "WY"), ("b", "WX"), ("c", "WZ")]);
+	let gen = generator(grammar, types, settings());
+	let rotor3 = gen::strct::file(&gen, "Rotor3", gen.types.get_struct("Rotor3"));
+	assert!(rotor3.contains("glam::DQuat::from_xyzw(r.b.0, r.a.0, r.c.0, r.w.0)"));
+
+	// Without a rotor there is nothing to derive the quaternion from:
+	let (grammar, mut types) = generator::grammars::pga3d();
+	types.insert_struct("Rotor3", &[("x", "WX"), ("y", "WY")]);
+	let gen = generator(grammar, types, settings());
+	let rotor3 = gen::strct::file(&gen, "Rotor3", gen.types.get_struct("Rotor3"));
+	assert!(!rotor3.contains("Quat"));
+}
+
+#[test]
+fn test_matrix() {
+	let (grammar, types) = generator::grammars::pga2d();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let code = gen::matrix::impls(&gen, "Rotor", gen.types.get_struct("Rotor")).unwrap();
+	assert!(code.contains("pub fn to_matrix(self) -> [[f64; 3]; 3] {"));
+	assert_eq_ignoring_whitespace!(
+		code.split("impl Rotor {").nth(3).unwrap(),
+		r"
+    /// The normalized versor with this matrix, e.g. from [`Self::to_matrix`].
+    /// Both `v` and `-v` have the same matrix, and this returns the one with a non-negative `s`.
+    pub fn from_matrix(m: [[f64; 3]; 3]) -> Self {
+        let s_s = (m[0][0] + m[1][1] + 2.0 * m[2][2]) / 4.0;
+        let s_xy = (m[0][1] - m[1][0]) / 4.0;
+        let xy_xy = (-m[0][0] - m[1][1] + 2.0 * m[2][2]) / 4.0;
+        let s = s_s.max(0.0).sqrt();
+        let xy = xy_xy.max(0.0).sqrt().copysign(s * s_xy);
+        Rotor {
+            s: S(s * s_s + xy * s_xy),
+            xy: XY(s * s_xy + xy * xy_xy),
+        }
+    }
+}
+"
+	);
+	// In `pga2d` the sandwich moves a `Vec3` like a line, so the matrices move its right complement instead,
+	// which is a point:
+	let code = gen::matrix::impls(&gen, "Motor", gen.types.get_struct("Motor")).unwrap();
+	assert!(code.contains("like `self.sandwich(v.rcompl()).rcompl()` for a `Vec3` `v`"));
+	assert!(code.contains("Translator::from_matrix(t).geometric(rotor)"));
+	assert!(code.contains("pub fn to_matrix_2x3(self) -> [[f64; 3]; 2] {"));
+	assert!(code.contains("wx: WX(-translation[0] / 2.0),"));
+	assert!(code.contains("pub fn translation(self) -> [f64; 2] {"));
+
+	let (grammar, types) = generator::grammars::pga3d();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let code = gen::matrix::impls(&gen, "Translator3", gen.types.get_struct("Translator3")).unwrap();
+	assert!(code.contains("pub fn to_matrix_3x4(self) -> [[f64; 4]; 3] {"));
+	assert!(code.contains("let w_x = m[0][3] / 2.0;"));
+	assert!(!code.contains("if "));
+	// The sign follows the `XYZW` of the rotor (its identity), although it is declared last:
+	let code = gen::matrix::impls(&gen, "Rotor3", gen.types.get_struct("Rotor3")).unwrap();
+	assert!(code.contains("let w = w_w.max(0.0).sqrt();\n"));
+	assert!(code.contains("let x = x_x.max(0.0).sqrt().copysign(w * w_x);\n"));
+	assert_eq_ignoring_whitespace!(
+		code.split("pub fn identity() -> Self {")
+			.nth(1)
+			.unwrap()
+			.split('}')
+			.next()
+			.unwrap(),
+		"Rotor3 { x: WX(0.0), y: WY(0.0), z: WZ(0.0), w: XYZW(1.0),"
+	);
+	let code = gen::matrix::impls(&gen, "Motor3", gen.types.get_struct("Motor3")).unwrap();
+	assert!(code.contains("x: YZ(translation[0] / 2.0),"));
+	assert!(code.contains("Translator3::from_matrix(t).anti_geometric(rotor)"));
+
+	// A 3x3 matrix doesn't have the norm of the rotor, so it is assumed to be one:
+	let (grammar, types) = generator::grammars::vga3d();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let code = gen::matrix::impls(&gen, "Rotor3", gen.types.get_struct("Rotor3")).unwrap();
+	assert!(code.contains("let s_s = (m[0][0] + m[1][1] + m[2][2] + 1.0) / 4.0;"));
+
+	// Too many members to solve for:
+	let (grammar, types) = generator::grammars::sta();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let code = gen::matrix::impls(&gen, "Rotor", gen.types.get_struct("Rotor")).unwrap();
+	assert!(code.contains("pub fn to_matrix(self) -> [[f64; 4]; 4] {"));
+	assert!(!code.contains("from_matrix"));
+	assert!(gen::matrix::impls(&gen, "Bivector", gen.types.get_struct("Bivector")).is_none());
+}
+
+#[test]
+fn test_selection() {
+	let (grammar, types) = generator::grammars::pga2d();
+	let mut gen = generator(
+		grammar,
+		types,
+		gen::Settings {
+			selection: gen::Selection {
+				products: Some(vec![Product::Wedge, Product::AntiWedge]),
+				unaries: Some(vec![Unary::Reverse]),
+				sandwiches: Some(vec![]),
+				struct_pairs: Some(vec![("Vec3".to_owned(), "Vec3".to_owned())]),
+			},
+			..Default::default()
+		},
+	);
+
+	let vec3 = gen.types.get_struct("Vec3");
+	let code = gen::strct::file(&gen, "Vec3", vec3);
+	assert!(code.contains("impl Wedge<Vec3> for Vec3 {"));
+	assert!(code.contains("impl std::ops::BitXor<Vec3> for Vec3 {"));
+	assert!(code.contains("impl Reverse for Vec3 {"));
+	assert!(!code.contains("impl Geometric<Vec3> for Vec3 {"));
+	assert!(!code.contains("impl Dual for Vec3 {"));
+	assert!(!code.contains("<Line> for Vec3"));
+	assert!(!code.contains("Vec3.wedge(Line)"));
+
+	let blades = gen::blades::file(&gen);
+	assert!(blades.contains("impl Wedge<Y> for X {"));
+	assert!(!blades.contains("impl Geometric<Y> for X {"));
+
+	// Neither the queries nor `exp` are generated, since they need other products:
+	assert!(gen::queries::file(&gen).is_none());
+	let line = gen.types.get_struct("Line");
+	assert!(!gen::strct::file(&gen, "Line", line).contains("impl Exp for Line"));
+
+	let point = gen.types.get_struct("Point2");
+	assert!(gen::strct::file(&gen, "Point2", point).contains("// Omitted: Point2.rev()"));
+	gen.settings.prune = true;
+	assert!(!gen::strct::file(&gen, "Point2", point).contains("// Omitted: "));
+}
+
+#[test]
+fn test_cga3d_null_basis() {
+	let (g, t) = grammars::cga3d();
+	assert!(!g.is_diagonal());
+	let rust = |expr: Expr| expr.simplify(Some(&g)).typify(&t, &g).rust_concise();
+
+	// The product of two blades can be a sum of blades, `Eo * Ei = -1 + Eoi`:
+	let eo = t.get("Eo").unit();
+	let ei = t.get("Ei").unit();
+	let eo_ei = Expr::geometric(vec![eo.clone(), ei.clone()]).simplify(Some(&g));
+	assert_eq!(
+		eo_ei,
+		Expr::Sum(vec![Expr::scalar(-1), t.get("Eoi").unit()]).simplify(Some(&g))
+	);
+	assert_eq!(rust(Expr::dot(vec![eo, ei.clone()])), "-1");
+
+	// The textbook inner product of two points, `a·b = a.x * b.x + … - a.o * b.inf - a.inf * b.o`:
+	let point = Type::strct(t.get_struct("Point"));
+	let a = Expr::var(0, "a", &point);
+	let b = Expr::var(1, "b", &point);
+	assert_eq_ignoring_whitespace!(
+		rust(Expr::dot(vec![a.clone(), b])),
+		"-S(a.inf.0 * b.o.0) - S(a.o.0 * b.inf.0) + S(a.x.0 * b.x.0) + S(a.y.0 * b.y.0) + S(a.z.0 * b.z.0)"
+	);
+
+	// The wedge product doesn't use the metric:
+	assert_eq_ignoring_whitespace!(
+		rust(Expr::wedge(vec![a, ei])),
+		"FlatPoint { e1i: a.x ^ Ei, e2i: a.y ^ Ei, e3i: a.z ^ Ei, eoi: a.o ^ Ei, }"
+	);
+
+	// A blade without a name is written as the wedge of its base vectors:
+	let mut unnamed = Types::default();
+	for (name, sblade) in t.sblades() {
+		if name != "Eoi" {
+			unnamed.insert_blade(name, sblade);
+		}
+	}
+	let o = Expr::var(0, "o", t.get("Eo"));
+	let i = Expr::var(1, "i", t.get("Ei"));
+	let expr = Expr::geometric(vec![o, i]).simplify(Some(&g));
+	assert!(expr.clone().typify(&t, &g).rust_concise().contains("Eoi(o.0 * i.0)"));
+	assert_eq!(
+		expr.typify(&unnamed, &g).rust_concise(),
+		"-S(o.0 * i.0) + o.0 * i.0 * (_e3 ^ _e4)"
+	);
+}
+
+#[test]
+fn test_arbitrary_dimensions() {
+	// All blades are named automatically, and all their products are named blades:
+	for n in 1..=6 {
+		let g = Grammar::new((0..n).map(|i| if i % 2 == 0 { 1 } else { -1 }).collect());
+		let vec_names: Vec<String> = (1..=n).map(|i| format!("E{}", i)).collect();
+		let vec_names: Vec<&str> = vec_names.iter().map(String::as_str).collect();
+		let mut t = Types::default();
+		t.insert_all_blades(&g, &vec_names);
+		assert_eq!(t.sblades().len(), 1 << n);
+
+		for a in g.blades() {
+			for b in g.blades() {
+				let ab = SBlade::binary_product(&SBlade::unit(a.clone()), Product::Geometric, &SBlade::unit(b), &g);
+				assert!(t.get_blade(&ab.blade).is_some());
+			}
+		}
+	}
+
+	// Line geometry: lines are the null vectors of the 6D space with signature +++---.
+	let g = Grammar::new(vec![1, 1, 1, -1, -1, -1]);
+	let mut t = Types::default();
+	t.insert_all_blades(&g, &["E1", "E2", "E3", "E4", "E5", "E6"]);
+	assert_eq!(t.get("E123456").unit().as_sblade(&g), Some(SBlade::pseudo_scalar(&g)));
+	t.insert_struct(
+		"Line",
+		&[
+			("e1", "E1"),
+			("e2", "E2"),
+			("e3", "E3"),
+			("e4", "E4"),
+			("e5", "E5"),
+			("e6", "E6"),
+		],
+	);
+	let gen = generator(g, t, gen::Settings::default());
+	assert!(gen::blades::file(&gen).contains("pub struct E123456"));
+	let line = gen.types.get_struct("Line");
+	assert!(gen::strct::file(&gen, "Line", line).contains("pub struct Line"));
+
+	// 4D conformal geometric algebra, with the null basis:
+	let g = Grammar::new(vec![1, 1, 1, 1, 0, 0]).with_dot(VecIdx(4), VecIdx(5), -1);
+	let mut t = Types::default();
+	t.insert_all_blades(&g, &["E1", "E2", "E3", "E4", "Eo", "Ei"]);
+	assert!(t.try_get("E1234oi").is_some());
+	for a in g.blades() {
+		for b in g.blades() {
+			let terms =
+				SBlade::binary_product_terms(&SBlade::unit(a.clone()), Product::Geometric, &SBlade::unit(b), &g);
+			assert!(terms.iter().all(|term| t.get_blade(&term.blade).is_some()));
+		}
+	}
+	t.insert_struct(
+		"Point",
+		&[
+			("x", "E1"),
+			("y", "E2"),
+			("z", "E3"),
+			("w", "E4"),
+			("o", "Eo"),
+			("inf", "Ei"),
+		],
+	);
+	let gen = generator(g, t, gen::Settings::default());
+	let point = gen.types.get_struct("Point");
+	assert!(gen::strct::file(&gen, "Point", point).contains("pub struct Point"));
+}
+
+#[test]
+fn test_shaders() {
+	let validate = |module: &naga::Module| {
+		naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::empty())
+			.validate(module)
+			.unwrap();
+	};
+
+	// Without a `Multivector` for cga3d, which has 32 blades and takes long to generate:
+	let mut pga3d = generator::grammars::pga3d();
+	pga3d.1.insert_multivector(gen::MULTIVECTOR);
+	for (grammar, types) in [pga3d, generator::grammars::cga3d()] {
+		let gen = generator(grammar, types, gen::Settings::default());
+		let wgsl = backend::file(&gen, &shader::Language::Wgsl, "pga3d");
+		let glsl = format!(
+			"#version 450\n{}\nvoid main() {{}}\n",
+			backend::file(&gen, &shader::Language::Glsl, "pga3d")
+		);
+
+		// The parser recurses once per term of a sum, and some sums have hundreds of terms:
+		std::thread::Builder::new()
+			.stack_size(64 * 1024 * 1024)
+			.spawn(move || {
+				let module =
+					naga::front::wgsl::parse_str(&wgsl).unwrap_or_else(|err| panic!("{}", err.emit_to_string(&wgsl)));
+				validate(&module);
+				assert!(module.functions.len() > 100);
+
+				let module = naga::front::glsl::Frontend::default()
+					.parse(&naga::front::glsl::Options::from(naga::ShaderStage::Compute), &glsl)
+					.unwrap_or_else(|err| panic!("{}", err.emit_to_string(&glsl)));
+				validate(&module);
+				assert!(module.functions.len() > 100);
+			})
+			.unwrap()
+			.join()
+			.unwrap();
+	}
+
+	let (grammar, types) = generator::grammars::pga3d();
+	let gen = generator(grammar, types, gen::Settings::default());
+	let wgsl = backend::file(&gen, &shader::Language::Wgsl, "pga3d");
+	assert!(
+		wgsl.contains("// v0 = (vx, vy, vz, mx), v1 = (my, mz)\nstruct Line3 {\n\tv0: vec4<f32>,\n\tv1: vec4<f32>,\n}")
+	);
+	assert!(wgsl.contains("fn line3_anti_wedge_plane(a: Line3, b: Plane) -> Vec4 {"));
+	assert!(wgsl.contains(
+		"fn vec4_dot_vec4(a: Vec4, b: Vec4) -> f32 {\n\treturn a.v0.x * b.v0.x + a.v0.y * b.v0.y + a.v0.z * b.v0.z;\n}"
+	));
+	let glsl = backend::file(&gen, &shader::Language::Glsl, "pga3d");
+	assert!(glsl.contains("Vec4 line3_anti_wedge_plane(Line3 a, Plane b) {"));
+}
+
+#[test]
+fn test_cpp_and_python() {
+	let (grammar, types) = generator::grammars::pga3d();
+	let gen = generator(grammar, types, gen::Settings::default());
+
+	let cpp = backend::file(&gen, &cpp::Cpp::new(&gen).unwrap(), "pga3d");
+	assert!(cpp.contains("namespace pga3d {"));
+	assert!(cpp.contains("struct Vec4 {\n\tdouble x = 0; // X\n\tdouble y = 0; // Y\n"));
+	assert!(cpp.contains(
+		"inline Line3 wedge(const Vec4& a, const Vec4& b) {\n\treturn Line3{\n\t\ta.w * b.x - a.x * b.w, // vx\n"
+	));
+	assert!(cpp.contains("inline Line3 operator^(const Vec4& a, const Vec4& b) {\n\treturn wedge(a, b);\n}"));
+	assert!(cpp.contains("inline double weight(const Point3&) {\n\treturn 1.0;\n}"));
+
+	let python = backend::file(&gen, &python::Python::new(&gen), "pga3d");
+	assert!(python.contains("@dataclass\nclass Vec4(_Value):\n    x: float = 0.0  # X\n"));
+	assert!(python.contains(
+		"def vec4_wedge_vec4(a: Vec4, b: Vec4) -> Line3:\n    return Line3(\n        vx=a.w * b.x - a.x * b.w,\n"
+	));
+	assert!(python.contains("    def __xor__(self, other):\n        return self.wedge(other)\n"));
+	assert!(python.contains("    (\"wedge\", Vec4, Vec4): vec4_wedge_vec4,\n"));
+
+	let dir = temp_dir("cpp_and_python");
+	std::fs::write(dir.join("pga3d.hpp"), &cpp).unwrap();
+	std::fs::write(dir.join("pga3d.py"), &python).unwrap();
+
+	match std::process::Command::new("g++")
+		.args(["-std=c++14", "-fsyntax-only", "-x", "c++"])
+		.arg(dir.join("pga3d.hpp"))
+		.output()
+	{
+		Ok(output) => assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr)),
+		Err(err) => eprintln!("Skipping the C++ compilation: {}", err),
+	}
+
+	// The functions only use arithmetic, so they work on anything with element-wise arithmetic, like NumPy arrays:
+	let script = r#"
+import pga3d
+
+class Array:
+    def __init__(self, values):
+        self.values = list(values)
+
+    def _zip(self, other, f):
+        others = other.values if isinstance(other, Array) else [other] * len(self.values)
+        return Array(f(a, b) for a, b in zip(self.values, others))
+
+    def __add__(self, other): return self._zip(other, lambda a, b: a + b)
+    def __radd__(self, other): return self._zip(other, lambda a, b: b + a)
+    def __sub__(self, other): return self._zip(other, lambda a, b: a - b)
+    def __rsub__(self, other): return self._zip(other, lambda a, b: b - a)
+    def __mul__(self, other): return self._zip(other, lambda a, b: a * b)
+    def __rmul__(self, other): return self._zip(other, lambda a, b: b * a)
+    def __neg__(self): return Array(-a for a in self.values)
+
+a = pga3d.Vec4(x=1.0, y=2.0, z=3.0, w=1.0)
+b = pga3d.Vec4(x=4.0, y=6.0, z=3.0, w=1.0)
+line = a ^ b
+assert line == pga3d.vec4_wedge_vec4(a, b) == a.wedge(b)
+assert (line.vx, line.vy, line.vz, line.mx, line.my, line.mz) == (3.0, 4.0, 0.0, -12.0, 9.0, -2.0), line
+try:
+    a ^ 2.0
+    raise AssertionError("expected a TypeError")
+except TypeError:
+    pass
+
+arrays = [Array]
+try:
+    import numpy
+    arrays.append(numpy.array)
+except ImportError:
+    pass
+for array in arrays:
+    a = pga3d.Vec4(x=array([1.0, 0.0]), y=array([2.0, 1.0]), z=array([3.0, 0.0]), w=array([1.0, 1.0]))
+    line = a ^ b
+    assert list(line.vx.values if array is Array else line.vx) == [3.0, 4.0], array
+"#;
+	std::fs::write(dir.join("test.py"), script).unwrap();
+	match std::process::Command::new("python3")
+		.arg("test.py")
+		.current_dir(&dir)
+		.output()
+	{
+		Ok(output) => assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr)),
+		Err(err) => eprintln!("Skipping the Python test: {}", err),
+	}
+	std::fs::remove_dir_all(&dir).unwrap();
+}
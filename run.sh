@@ -3,24 +3,16 @@ set -o errexit -o nounset -o pipefail
 
 cd "$( dirname "${BASH_SOURCE[0]}" )"
 
-echo "[workspace]
-members = [
-	\"generator\",
-]" > Cargo.toml
+rm -rf generated/src/pga2d generated/src/pga3d generated/src/vga3d generated/src/sta generated/src/cga3d
 
-rm -rf generated/src/pga2d generated/src/pga3d
-
-cargo run -q -- --grammar pga2d --out_dir generated/src/pga2d
-cargo run -q -- --grammar pga3d --out_dir generated/src/pga3d
+cargo run -q -p generator -- --grammar pga2d --out_dir generated/src/pga2d --ffi --cargo_features
+cargo run -q -p generator -- --grammar pga3d --out_dir generated/src/pga3d --ffi --cargo_features
+cargo run -q -p generator -- --grammar vga3d --out_dir generated/src/vga3d --ffi --cargo_features
+cargo run -q -p generator -- --grammar sta --out_dir generated/src/sta --ffi --cargo_features
+cargo run -q -p generator -- --grammar cga3d --out_dir generated/src/cga3d --ffi --cargo_features
 
 echo "Testing generated code:"
 
-echo "[workspace]
-members = [
-	\"generated\",
-	\"generator\",
-]" > Cargo.toml
-
 cargo fmt
 
 cargo check -q --all-features